@@ -31,7 +31,7 @@ async fn should_conditional_forwarders() {
     request.directory_id = "d-11111aaaaa".to_string();
 
     match client.describe_conditional_forwarders(request).await {
-        Err(RusotoError::Service(DescribeConditionalForwardersError::EntityDoesNotExist(msg))) => {
+        Err(RusotoError::Service(DescribeConditionalForwardersError::EntityDoesNotExist(msg), _)) => {
             assert!(msg.contains("does not exist."))
         }
         err @ _ => panic!("Expected EntityDoesNotExist error, got {:#?}", err)
@@ -45,7 +45,7 @@ async fn should_describe_domain_controllers() {
     request.directory_id = "d-11111aaaaa".to_string();
 
     match client.describe_domain_controllers(request).await {
-        Err(RusotoError::Service(DescribeDomainControllersError::EntityDoesNotExist(msg))) => {
+        Err(RusotoError::Service(DescribeDomainControllersError::EntityDoesNotExist(msg), _)) => {
             assert!(msg.contains("does not exist."))
         }
         err @ _ => panic!("Expected EntityDoesNotExist error, got {:#?}", err)
@@ -28,7 +28,7 @@ async fn should_function_not_found() {
         let result = client.invoke(request).await;
 
         assert!(result.is_err());
-        if let Err(RusotoError::Service(InvokeError::ResourceNotFound(resp))) = result {
+        if let Err(RusotoError::Service(InvokeError::ResourceNotFound(resp), _)) = result {
             assert!(resp.contains("Function not found:"));
         } else {
             assert!(
@@ -50,7 +50,7 @@ async fn should_function_not_found() {
         let result = client.invoke(request).await;
 
         assert!(result.is_err());
-        if let Err(RusotoError::Service(InvokeError::ResourceNotFound(resp))) = result {
+        if let Err(RusotoError::Service(InvokeError::ResourceNotFound(resp), _)) = result {
             assert!(resp.contains("Function not found:"));
         } else {
             assert!(
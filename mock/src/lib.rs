@@ -33,7 +33,7 @@
 //! ```
 #![deny(missing_docs)]
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -70,6 +70,7 @@ impl ProvideAwsCredentials for MockCredentialsProvider {
 pub struct MockRequestDispatcher {
     outcome: RequestOutcome,
     body: Vec<u8>,
+    body_interrupted: bool,
     headers: HeaderMap<String>,
     request_checker: Option<Box<dyn Fn(&SignedRequest) + Send + Sync>>,
 }
@@ -110,6 +111,13 @@ impl MockRequestDispatcher {
         self
     }
 
+    /// Mocks the connection being reset after the response body was sent, so reading the body
+    /// fails once all of it was read
+    pub fn with_body_interrupted(mut self) -> MockRequestDispatcher {
+        self.body_interrupted = true;
+        self
+    }
+
     /// Mocks the json serialized response body what would be
     /// returned from AWS
     pub fn with_json_body<B>(mut self, body: B) -> MockRequestDispatcher
@@ -148,12 +156,25 @@ impl DispatchSignedRequest for MockRequestDispatcher {
             self.request_checker.as_ref().unwrap()(&request);
         }
         match self.outcome {
-            RequestOutcome::Performed(ref status) => futures::future::ready(Ok(HttpResponse {
-                status: *status,
-                body: ByteStream::from(self.body.clone()),
-                headers: self.headers.clone(),
-            }))
-            .boxed(),
+            RequestOutcome::Performed(ref status) => {
+                let body = if self.body_interrupted {
+                    ByteStream::new(futures::stream::iter(vec![
+                        Ok(self.body.clone().into()),
+                        Err(io::Error::new(
+                            io::ErrorKind::ConnectionReset,
+                            "connection reset",
+                        )),
+                    ]))
+                } else {
+                    ByteStream::from(self.body.clone())
+                };
+                futures::future::ready(Ok(HttpResponse {
+                    status: *status,
+                    body,
+                    headers: self.headers.clone(),
+                }))
+                .boxed()
+            }
             RequestOutcome::Failed(ref error) => futures::future::ready(Err(error.clone())).boxed(),
         }
     }
@@ -0,0 +1,384 @@
+//! Sending S3 requests to access points, whose ARNs are used in place of bucket names.
+
+use crate::client::ClientConfig;
+use crate::endpoint::Endpoint;
+use crate::region::Region;
+use crate::signature::SignedRequest;
+
+/// An access point of S3, or of S3 on Outposts, named by its ARN, like
+/// `arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point`. The ARNs of Multi-Region
+/// Access Points have no region.
+#[derive(Debug, PartialEq)]
+struct AccessPoint<'a> {
+    partition: &'a str,
+    region: &'a str,
+    account: &'a str,
+    name: &'a str,
+    outpost_id: Option<&'a str>,
+}
+
+/// Splits the path of an S3 request into its bucket, which may be an access point ARN, and
+/// the rest of the path.
+pub(crate) fn split_bucket(path: &str) -> (&str, &str) {
+    let path = path.trim_start_matches('/');
+    if let Ok((_, rest)) = parse(path) {
+        return (&path[..path.len() - rest.len()], rest);
+    }
+    match path.find('/') {
+        Some(index) => (&path[..index], &path[index..]),
+        None => (path, ""),
+    }
+}
+
+/// Sends an S3 request whose bucket is an access point ARN to the endpoint of the access
+/// point, signed for its region, or for all regions with Signature Version 4A if it's a
+/// Multi-Region Access Point. Access points of S3 on Outposts are signed for `s3-outposts`.
+/// Returns whether the request was sent to an access point, whose requests aren't sent to the
+/// endpoint of S3, or an error if the ARN is invalid or in another region than the request,
+/// unless `ClientConfig::s3_use_arn_region` is set.
+///
+/// Clients do this for every request; it's public for the presigned URLs of `rusoto_s3`.
+pub fn use_access_point(
+    config: &ClientConfig,
+    request: &mut SignedRequest,
+) -> Result<bool, String> {
+    if request.service != "s3" {
+        return Ok(false);
+    }
+    let path = request.path.trim_start_matches('/').to_owned();
+    if !path.starts_with("arn:") {
+        return Ok(false);
+    }
+    let (access_point, rest) = parse(&path)?;
+    let arn = &path[..path.len() - rest.len()];
+    let region_name = request.region.name();
+    if access_point.partition != partition(region_name) {
+        return Err(format!(
+            "The access point {} is in another partition than the region {}",
+            arn, region_name
+        ));
+    }
+    let (fips, dualstack) = (config.use_fips_endpoint(), config.use_dualstack_endpoint());
+    if access_point.region.is_empty() {
+        if config.s3_disable_multi_region_access_points() {
+            return Err(format!(
+                "Multi-Region Access Points are disabled, but {} is one",
+                arn
+            ));
+        }
+        if fips || dualstack {
+            return Err(
+                "Multi-Region Access Points have no FIPS or dual-stack endpoints".to_owned(),
+            );
+        }
+        let hostname = match request.region {
+            Region::Custom { ref endpoint, .. } => {
+                let endpoint = Endpoint::new(endpoint);
+                request.scheme = Some(endpoint.scheme().to_owned());
+                format!("{}.{}", access_point.name, endpoint.hostname())
+            }
+            _ => {
+                request.scheme = Some("https".to_owned());
+                format!(
+                    "{}.accesspoint.s3-global.{}",
+                    access_point.name,
+                    dns_suffix(access_point.partition)
+                )
+            }
+        };
+        request.set_region_set(Some("*".to_owned()));
+        request.set_hostname(Some(hostname));
+        request.path = path_of(rest);
+        return Ok(true);
+    }
+    if access_point.region != region_name && !config.s3_use_arn_region() {
+        return Err(format!(
+            "The access point {} is in another region than {}, set \
+             `ClientConfig::set_s3_use_arn_region` to send requests to its region",
+            arn, region_name
+        ));
+    }
+    if access_point.outpost_id.is_some() && (fips || dualstack) {
+        return Err("S3 on Outposts has no FIPS or dual-stack endpoints".to_owned());
+    }
+
+    let prefix = match access_point.outpost_id {
+        Some(outpost_id) => format!(
+            "{}-{}.{}",
+            access_point.name, access_point.account, outpost_id
+        ),
+        None => format!("{}-{}", access_point.name, access_point.account),
+    };
+    let (region, scheme, hostname) = match request.region {
+        // the endpoint of a custom region is the one of the access point, without its prefix
+        Region::Custom { ref endpoint, .. } => {
+            let endpoint = Endpoint::new(endpoint);
+            let region = Region::Custom {
+                name: access_point.region.to_owned(),
+                endpoint: format!("{}://{}", endpoint.scheme(), endpoint.hostname()),
+            };
+            let hostname = format!("{}.{}", prefix, endpoint.hostname());
+            (region, endpoint.scheme().to_owned(), hostname)
+        }
+        _ => {
+            let region = access_point
+                .region
+                .parse::<Region>()
+                .map_err(|_| format!("The region of the access point {} is unknown", arn))?;
+            let service = match access_point.outpost_id {
+                Some(_) => "s3-outposts".to_owned(),
+                None => format!(
+                    "s3-accesspoint{}{}",
+                    if fips { "-fips" } else { "" },
+                    if dualstack { ".dualstack" } else { "" }
+                ),
+            };
+            let hostname = format!(
+                "{}.{}.{}.{}",
+                prefix,
+                service,
+                access_point.region,
+                dns_suffix(access_point.partition)
+            );
+            (region, "https".to_owned(), hostname)
+        }
+    };
+    if access_point.outpost_id.is_some() {
+        request.set_signing_name(Some("s3-outposts".to_owned()));
+    }
+    request.set_region(region);
+    request.scheme = Some(scheme);
+    request.set_hostname(Some(hostname));
+    request.path = path_of(rest);
+    Ok(true)
+}
+
+/// The path of a request to an access point, the rest of the path after its ARN.
+fn path_of(rest: &str) -> String {
+    if rest.is_empty() {
+        "/".to_owned()
+    } else {
+        rest.to_owned()
+    }
+}
+
+/// Parses the access point ARN at the start of the path, returning the rest of the path.
+fn parse(path: &str) -> Result<(AccessPoint<'_>, &str), String> {
+    let invalid = || format!("Invalid access point ARN in {}", path);
+    let parts: Vec<&str> = path.splitn(6, ':').collect();
+    if parts.len() != 6 || parts[0] != "arn" {
+        return Err(invalid());
+    }
+    let (partition, service, region, account, resource) =
+        (parts[1], parts[2], parts[3], parts[4], parts[5]);
+    // only the ARNs of Multi-Region Access Points have no region
+    if partition.is_empty()
+        || (region.is_empty() && service != "s3")
+        || (!region.is_empty() && !is_host_label(region))
+        || account.is_empty()
+        || !account.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let (outpost_id, resource) = match service {
+        "s3" => (None, resource),
+        "s3-outposts" => match next_part(resource) {
+            ("outpost", resource) => {
+                let (outpost_id, resource) = next_part(resource);
+                if !is_host_label(outpost_id) {
+                    return Err(invalid());
+                }
+                (Some(outpost_id), resource)
+            }
+            _ => return Err(invalid()),
+        },
+        _ => return Err(invalid()),
+    };
+    let resource = match next_part(resource) {
+        ("accesspoint", resource) => resource,
+        _ => return Err(invalid()),
+    };
+    // the name ends the ARN, and the key may contain colons
+    let (name, rest) = match resource.find('/') {
+        Some(index) => (&resource[..index], &resource[index..]),
+        None => (resource, ""),
+    };
+    // the aliases of Multi-Region Access Points, like `mfzwi23gnjvgw.mrap`, have several labels
+    if !name.split('.').all(is_host_label) {
+        return Err(invalid());
+    }
+    let access_point = AccessPoint {
+        partition,
+        region,
+        account,
+        name,
+        outpost_id,
+    };
+    Ok((access_point, rest))
+}
+
+/// Splits the next part off the resource of an ARN, whose parts are separated by `/` or `:`.
+fn next_part(resource: &str) -> (&str, &str) {
+    match resource.find(&['/', ':'][..]) {
+        Some(index) => (&resource[..index], &resource[index + 1..]),
+        None => (resource, ""),
+    }
+}
+
+/// Whether the part of an ARN can be a label of the host name of an endpoint, which the ARNs
+/// of users mustn't be able to send requests to other hosts with.
+fn is_host_label(part: &str) -> bool {
+    !part.is_empty()
+        && part.len() <= 63
+        && !part.starts_with('-')
+        && !part.ends_with('-')
+        && part.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// The partition of a region, like `aws-cn` for the regions in China.
+fn partition(region: &str) -> &'static str {
+    if region.starts_with("cn-") {
+        "aws-cn"
+    } else if region.starts_with("us-gov-") {
+        "aws-us-gov"
+    } else {
+        "aws"
+    }
+}
+
+/// The domain of the endpoints of a partition.
+fn dns_suffix(partition: &str) -> &'static str {
+    match partition {
+        "aws-cn" => "amazonaws.com.cn",
+        _ => "amazonaws.com",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(use_arn_region: bool) -> ClientConfig {
+        let mut config = ClientConfig::new();
+        config.set_s3_use_arn_region(use_arn_region);
+        config.set_use_fips_endpoint(false);
+        config.set_use_dualstack_endpoint(false);
+        config
+    }
+
+    fn access_point(path: &str, use_arn_region: bool) -> Result<(String, String), String> {
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, path);
+        assert!(use_access_point(&config(use_arn_region), &mut request)?);
+        assert_eq!(request.region_for_service(), "us-west-2");
+        Ok((request.hostname(), request.path))
+    }
+
+    #[test]
+    fn access_points() {
+        let hostname = "my-ap-123456789012.s3-accesspoint.us-west-2.amazonaws.com";
+        assert_eq!(
+            access_point(
+                "/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap",
+                false
+            ),
+            Ok((hostname.to_owned(), "/".to_owned()))
+        );
+        assert_eq!(
+            access_point(
+                "/arn:aws:s3:us-west-2:123456789012:accesspoint:my-ap/dir/key:1",
+                false
+            ),
+            Ok((hostname.to_owned(), "/dir/key:1".to_owned()))
+        );
+        assert_eq!(
+            split_bucket("/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/key"),
+            (
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap",
+                "/key"
+            )
+        );
+        assert_eq!(split_bucket("/bucket/key"), ("bucket", "/key"));
+
+        let mut request = SignedRequest::new(
+            "GET",
+            "s3",
+            &Region::UsWest2,
+            "/arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01234567890123456/accesspoint/my-ap/key",
+        );
+        assert!(use_access_point(&config(false), &mut request).unwrap());
+        assert_eq!(
+            request.hostname(),
+            "my-ap-123456789012.op-01234567890123456.s3-outposts.us-west-2.amazonaws.com"
+        );
+        assert_eq!(request.signing_name(), "s3-outposts");
+        assert_eq!(request.path, "/key");
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, "/bucket/key");
+        assert!(!use_access_point(&config(false), &mut request).unwrap());
+    }
+
+    #[test]
+    fn access_point_regions() {
+        let arn = "/arn:aws:s3:eu-west-1:123456789012:accesspoint/my-ap/key";
+        assert!(access_point(arn, false).is_err());
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, arn);
+        let mut fips_config = config(true);
+        fips_config.set_use_fips_endpoint(true);
+        fips_config.set_use_dualstack_endpoint(true);
+        assert!(use_access_point(&fips_config, &mut request).unwrap());
+        assert_eq!(
+            request.hostname(),
+            "my-ap-123456789012.s3-accesspoint-fips.dualstack.eu-west-1.amazonaws.com"
+        );
+        assert_eq!(request.region_for_service(), "eu-west-1");
+
+        let arn = "/arn:aws-cn:s3:cn-north-1:123456789012:accesspoint/my-ap/key";
+        assert!(access_point(arn, true).is_err());
+
+        let region = Region::Custom {
+            name: "us-west-2".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        let arn = "/arn:aws:s3:us-west-2:123456789012:accesspoint/my-ap/key";
+        let mut request = SignedRequest::new("GET", "s3", &region, arn);
+        assert!(use_access_point(&config(false), &mut request).unwrap());
+        assert_eq!(request.hostname(), "my-ap-123456789012.localhost:9000");
+        assert_eq!(request.scheme(), "http");
+
+        for arn in &[
+            "/arn:aws:s3:us-west-2:123456789012:bucket_name/key",
+            "/arn:aws:s3:us-west-2::accesspoint/my-ap/key",
+            "/arn:aws:s3:us-west-2:123456789012:accesspoint",
+            "/arn:aws:sqs:us-west-2:123456789012:accesspoint/my-ap",
+            "/arn:aws:s3-outposts::123456789012:outpost/op-01234567890123456/accesspoint/my-ap",
+            "/arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-0123.example.com/accesspoint/my-ap",
+            "/arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01234567890123456/bucket/my-bucket",
+            "/arn:aws:s3:us-west-2.example.com:123456789012:accesspoint/my-ap",
+            "/arn:aws:s3:us-west-2:123456789012:accesspoint/my_ap",
+        ] {
+            assert!(access_point(arn, false).is_err(), "{}", arn);
+        }
+    }
+
+    #[test]
+    fn multi_region_access_points() {
+        let arn = "/arn:aws:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/key";
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, arn);
+        assert!(use_access_point(&config(false), &mut request).unwrap());
+        assert_eq!(
+            request.hostname(),
+            "mfzwi23gnjvgw.mrap.accesspoint.s3-global.amazonaws.com"
+        );
+        assert_eq!(request.region_set.as_deref(), Some("*"));
+        assert_eq!(request.path, "/key");
+
+        let mut disabled = config(false);
+        disabled.set_s3_disable_multi_region_access_points(true);
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, arn);
+        assert!(use_access_point(&disabled, &mut request).is_err());
+
+        let arn = "/arn:aws-cn:s3::123456789012:accesspoint/mfzwi23gnjvgw.mrap/key";
+        assert!(access_point(arn, false).is_err());
+    }
+}
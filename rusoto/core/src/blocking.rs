@@ -0,0 +1,92 @@
+//! Running requests from synchronous code, for the blocking clients of the service crates.
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+/// The runtime the blocking clients of the service crates, like `S3BlockingClient`, make
+/// their requests on.
+///
+/// Each blocking client owns a runtime with a single worker thread, which keeps driving the
+/// connections of its `HttpClient` between requests. Clones share the runtime, so a blocking
+/// client can be cloned and used from several threads. Blocking clients can't be used from
+/// async code, where the requests block the thread of the task making them; use the async
+/// clients there instead.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_core::BlockingRuntime;
+///
+/// let runtime = BlockingRuntime::new();
+/// assert_eq!(runtime.block_on(async { 1 + 1 }), 2);
+/// ```
+#[derive(Clone)]
+pub struct BlockingRuntime {
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingRuntime {
+    /// Create a new `BlockingRuntime`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker thread can't be started.
+    pub fn new() -> BlockingRuntime {
+        let runtime = Builder::new()
+            .threaded_scheduler()
+            .core_threads(1)
+            .thread_name("rusoto-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start the runtime of a blocking client");
+        BlockingRuntime {
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// Runs the future on the runtime, blocking the current thread until it completes.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called from within a tokio runtime, like in async code or on a thread of the
+    /// runtime, since blocking there could stop the runtime from driving the future.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.handle().block_on(future)
+    }
+}
+
+impl Default for BlockingRuntime {
+    fn default() -> BlockingRuntime {
+        BlockingRuntime::new()
+    }
+}
+
+impl fmt::Debug for BlockingRuntime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BlockingRuntime")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn block_on() {
+        let runtime = BlockingRuntime::new();
+        assert_eq!(runtime.block_on(async { 1 }), 1);
+
+        // the timers are driven by the runtime, from any thread
+        let clone = runtime.clone();
+        thread::spawn(move || {
+            clone.block_on(async { tokio::time::delay_for(Duration::from_millis(1)).await })
+        })
+        .join()
+        .unwrap();
+    }
+}
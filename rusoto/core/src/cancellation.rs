@@ -0,0 +1,151 @@
+//! Cancelling requests in flight, including the streaming of their response bodies.
+
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::channel::oneshot;
+use futures::future::{FutureExt, Shared};
+use futures::Stream;
+
+use crate::stream::ByteStream;
+
+/// A token cancelling the requests it was given to, see `RequestConfig::cancellation_token`.
+///
+/// Cancelled requests fail with a `SignAndDispatchError::Dispatch`, and their connections are
+/// closed rather than returned to the pool, so the service stops sending the response. Reading
+/// the body of a response after its request was cancelled fails with an `Interrupted` error,
+/// so large downloads and long polls can be stopped as well.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::signature::SignedRequest;
+/// use rusoto_core::{CancellationToken, Client, Region, RequestConfigExt};
+///
+/// # async fn example() {
+/// let client = Client::shared();
+/// let token = CancellationToken::new();
+/// let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+/// let response = client.sign_and_dispatch(request).with_config(|config| {
+///     config.cancellation_token(token.clone());
+/// });
+/// // e.g. when the user presses Ctrl-C
+/// token.cancel();
+/// assert!(response.await.is_err());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+    sender: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    cancelled: Shared<oneshot::Receiver<()>>,
+}
+
+impl CancellationToken {
+    /// Create a new `CancellationToken`.
+    pub fn new() -> CancellationToken {
+        let (sender, receiver) = oneshot::channel();
+        CancellationToken {
+            sender: Arc::new(Mutex::new(Some(sender))),
+            cancelled: receiver.shared(),
+        }
+    }
+
+    /// Cancel the requests the token was given to, including those which are sent later.
+    pub fn cancel(&self) {
+        // the receivers complete once the sender is dropped
+        self.sender.lock().unwrap().take();
+    }
+
+    /// Whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.sender.lock().unwrap().is_none()
+    }
+
+    /// Returns a future completing once the token is cancelled.
+    pub(crate) fn cancelled(&self) -> impl Future<Output = ()> + Send + Sync + Unpin + 'static {
+        self.cancelled.clone().map(|_| ())
+    }
+
+    /// Wraps the body of a response so reading it fails once the token is cancelled.
+    pub(crate) fn wrap_body(&self, body: ByteStream) -> ByteStream {
+        let size_hint = body.size_hint();
+        let body = CancellableBody {
+            inner: Some(body),
+            cancelled: self.cancelled.clone(),
+        };
+        match size_hint {
+            Some(size_hint) => ByteStream::new_with_size(body, size_hint),
+            None => ByteStream::new(body),
+        }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> CancellationToken {
+        CancellationToken::new()
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// A response body which stops once its request is cancelled.
+struct CancellableBody {
+    /// `None` once the request was cancelled, which drops the connection.
+    inner: Option<ByteStream>,
+    cancelled: Shared<oneshot::Receiver<()>>,
+}
+
+impl Stream for CancellableBody {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let inner = match this.inner {
+            Some(ref mut inner) => inner,
+            None => return Poll::Ready(None),
+        };
+        if this.cancelled.poll_unpin(cx).is_ready() {
+            this.inner = None;
+            return Poll::Ready(Some(Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "Request cancelled",
+            ))));
+        }
+        Pin::new(inner).poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    #[tokio::test]
+    async fn cancelling_bodies() {
+        let token = CancellationToken::new();
+        let chunks = vec![Ok(Bytes::from_static(b"a")), Ok(Bytes::from_static(b"b"))];
+        let mut body = token.wrap_body(ByteStream::new(stream::iter(chunks)));
+        assert_eq!(
+            body.next().await.unwrap().unwrap(),
+            Bytes::from_static(b"a")
+        );
+
+        token.cancel();
+        assert!(token.is_cancelled());
+        let error = body.next().await.unwrap().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Interrupted);
+        assert!(body.next().await.is_none());
+        token.cancelled().await;
+    }
+}
@@ -0,0 +1,219 @@
+//! Extra root certificates and certificate pinning, see `HttpConfig`.
+
+use sha2::{Digest, Sha256};
+
+use crate::request::TlsError;
+
+/// Returns the DER encoded certificates of a PEM file, like a CA bundle.
+pub(crate) fn parse_pem(pem: &[u8]) -> Result<Vec<Vec<u8>>, TlsError> {
+    const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+    const END: &str = "-----END CERTIFICATE-----";
+
+    let pem = std::str::from_utf8(pem).map_err(|_| TlsError::new("Invalid PEM certificates"))?;
+    let mut certificates = Vec::new();
+    let mut rest = pem;
+    while let Some(begin) = rest.find(BEGIN) {
+        rest = &rest[begin + BEGIN.len()..];
+        let end = rest
+            .find(END)
+            .ok_or_else(|| TlsError::new("Unterminated PEM certificate"))?;
+        let encoded: String = rest[..end].split_whitespace().collect();
+        let der = base64::decode(&encoded)
+            .map_err(|e| TlsError::new(format!("Invalid PEM certificate: {}", e)))?;
+        certificates.push(der);
+        rest = &rest[end + END.len()..];
+    }
+    if certificates.is_empty() {
+        return Err(TlsError::new("No PEM certificates found"));
+    }
+    Ok(certificates)
+}
+
+/// The SHA-256 digests of the certificates or public keys a server must present.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CertificatePins {
+    certificates: Vec<[u8; 32]>,
+    public_keys: Vec<[u8; 32]>,
+}
+
+impl CertificatePins {
+    pub(crate) fn pin_certificate(&mut self, sha256: [u8; 32]) {
+        self.certificates.push(sha256);
+    }
+
+    pub(crate) fn pin_public_key(&mut self, sha256: [u8; 32]) {
+        self.public_keys.push(sha256);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.certificates.is_empty() && self.public_keys.is_empty()
+    }
+
+    /// Whether any of the DER encoded certificates matches a pin.
+    pub(crate) fn matches<'a, I>(&self, certificates: I) -> bool
+    where
+        I: IntoIterator<Item = &'a [u8]>,
+    {
+        certificates.into_iter().any(|certificate| {
+            let digest = Sha256::digest(certificate);
+            if self.certificates.iter().any(|pin| pin[..] == digest[..]) {
+                return true;
+            }
+            match subject_public_key_info(certificate) {
+                Some(public_key) => {
+                    let digest = Sha256::digest(public_key);
+                    self.public_keys.iter().any(|pin| pin[..] == digest[..])
+                }
+                None => false,
+            }
+        })
+    }
+}
+
+/// A DER element read from the start of some input.
+struct Der<'a> {
+    tag: u8,
+    content: &'a [u8],
+    /// The whole element, including its tag and length.
+    element: &'a [u8],
+    /// The input following the element.
+    rest: &'a [u8],
+}
+
+/// Reads the DER element at the start of the input.
+fn read_der(input: &[u8]) -> Option<Der<'_>> {
+    let tag = *input.first()?;
+    let first = *input.get(1)? as usize;
+    let (length, header) = if first < 0x80 {
+        (first, 2)
+    } else {
+        let octets = first & 0x7f;
+        if octets == 0 || octets > 4 {
+            return None;
+        }
+        let length = input
+            .get(2..2 + octets)?
+            .iter()
+            .fold(0usize, |length, &octet| length << 8 | octet as usize);
+        (length, 2 + octets)
+    };
+    let end = header.checked_add(length)?;
+    let content = input.get(header..end)?;
+    Some(Der {
+        tag,
+        content,
+        element: &input[..end],
+        rest: &input[end..],
+    })
+}
+
+/// Returns the DER encoded SubjectPublicKeyInfo of a DER encoded X.509 certificate.
+fn subject_public_key_info(certificate: &[u8]) -> Option<&[u8]> {
+    const SEQUENCE: u8 = 0x30;
+    const EXPLICIT_VERSION: u8 = 0xa0;
+
+    let certificate = read_der(certificate).filter(|der| der.tag == SEQUENCE)?;
+    let tbs_certificate = read_der(certificate.content).filter(|der| der.tag == SEQUENCE)?;
+    let mut rest = tbs_certificate.content;
+    if rest.first() == Some(&EXPLICIT_VERSION) {
+        rest = read_der(rest)?.rest;
+    }
+    // skip the serial number, signature algorithm, issuer, validity and subject
+    for _ in 0..5 {
+        rest = read_der(rest)?.rest;
+    }
+    let public_key = read_der(rest).filter(|der| der.tag == SEQUENCE)?;
+    Some(public_key.element)
+}
+
+/// Verifies certificates as usual, and then that they match a pin.
+#[cfg(feature = "rustls")]
+pub(crate) struct PinningVerifier {
+    pub(crate) inner: rustls_crate::WebPKIVerifier,
+    pub(crate) pins: CertificatePins,
+}
+
+#[cfg(feature = "rustls")]
+impl rustls_crate::ServerCertVerifier for PinningVerifier {
+    fn verify_server_cert(
+        &self,
+        roots: &rustls_crate::RootCertStore,
+        presented_certs: &[rustls_crate::Certificate],
+        dns_name: webpki::DNSNameRef<'_>,
+        ocsp_response: &[u8],
+    ) -> Result<rustls_crate::ServerCertVerified, rustls_crate::TLSError> {
+        let verified =
+            self.inner
+                .verify_server_cert(roots, presented_certs, dns_name, ocsp_response)?;
+        if self
+            .pins
+            .matches(presented_certs.iter().map(|c| c.0.as_slice()))
+        {
+            Ok(verified)
+        } else {
+            Err(rustls_crate::TLSError::General(
+                "The server certificate doesn't match any pinned certificate or public key"
+                    .to_owned(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIBgzCCASmgAwIBAgIUHKa2CWjm58+rUsgvzVDFUMxhCbowCgYIKoZIzj0EAwIw
+FjEUMBIGA1UEAwwLcnVzb3RvLnRlc3QwIBcNMjYxMDE2MTEwMDAwWhgPMjEyNjA5
+MjIxMTAwMDBaMBYxFDASBgNVBAMMC3J1c290by50ZXN0MFkwEwYHKoZIzj0CAQYI
+KoZIzj0DAQcDQgAEnBIggYU540OK0YsYhtYyakFJt60dzZlU+zkJ8lr4AWmMsORC
+N+M5QMQnaUjMBkzwm9+5Sh09iG+Qh27iv/aa3qNTMFEwHQYDVR0OBBYEFFbb5xVa
+SbweZXPLBStGNBhTSaJsMB8GA1UdIwQYMBaAFFbb5xVaSbweZXPLBStGNBhTSaJs
+MA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwIDSAAwRQIhAKau8wEid4Afb+o8
+LSSjSayqplHjOu3YvVpOk0hboMycAiAnOgmJImOp1OJWW8mu6MVEEv512HbDIFhp
+birx7fYwyg==
+-----END CERTIFICATE-----
+";
+
+    fn sha256(hex: &str) -> [u8; 32] {
+        let mut digest = [0; 32];
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        digest
+    }
+
+    #[test]
+    fn pem_bundles() {
+        let bundle = format!("# first\n{}\n# second\n{}", CERTIFICATE, CERTIFICATE);
+        let certificates = parse_pem(bundle.as_bytes()).unwrap();
+        assert_eq!(certificates.len(), 2);
+        assert_eq!(certificates[0].len(), 391);
+
+        assert!(parse_pem(b"not a certificate").is_err());
+        assert!(parse_pem(b"-----BEGIN CERTIFICATE-----\nMIIB").is_err());
+    }
+
+    #[test]
+    fn pins() {
+        let certificate = parse_pem(CERTIFICATE.as_bytes()).unwrap().remove(0);
+
+        let mut pins = CertificatePins::default();
+        pins.pin_certificate(sha256(
+            "32da50b9feb4811886bc6f0d2a2cb011c87f7ea8b22bd2dfd4dbd97cf79f4d85",
+        ));
+        assert!(pins.matches(vec![certificate.as_slice()]));
+
+        let mut pins = CertificatePins::default();
+        pins.pin_public_key(sha256(
+            "0949b23a47b9eea6b9dae294a3390d3fc77c7b5e851af7df2bec23d1c4c87bf6",
+        ));
+        assert!(pins.matches(vec![certificate.as_slice()]));
+
+        let mut pins = CertificatePins::default();
+        pins.pin_public_key([0; 32]);
+        assert!(!pins.matches(vec![certificate.as_slice()]));
+        assert!(!pins.matches(vec![&b"not a certificate"[..]]));
+    }
+}
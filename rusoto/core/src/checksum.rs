@@ -0,0 +1,169 @@
+//! Checksums of S3 uploads and downloads, see `ClientConfig::set_checksum_mode`.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::Stream;
+use log::debug;
+
+use crate::access_point;
+use crate::request::HttpResponse;
+use crate::signature::checksum::{Checksum, ChecksumAlgorithm};
+use crate::signature::SignedRequest;
+use crate::stream::ByteStream;
+
+/// The header asking S3 for the checksum of an object.
+const CHECKSUM_MODE: &str = "x-amz-checksum-mode";
+
+/// When the payloads of S3 requests and responses are checksummed, see
+/// `ClientConfig::set_checksum_mode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ChecksumMode {
+    /// Send checksums with the uploads of operations which support them, like `PutObject` and
+    /// `UploadPart`, and validate the checksums S3 returns with downloaded objects.
+    WhenSupported,
+    /// Only send checksums with the requests of operations which require them, which have a
+    /// `Content-MD5` header already, and only validate the checksums of downloaded objects
+    /// when they're asked for with the `x-amz-checksum-mode` header.
+    #[default]
+    WhenRequired,
+}
+
+/// Sets the checksum of S3 uploads, and asks for those of downloaded objects, as the mode
+/// requires. Called before the endpoint is resolved, while the bucket is in the path.
+pub(crate) fn prepare_request(
+    request: &mut SignedRequest,
+    mode: ChecksumMode,
+    algorithm: ChecksumAlgorithm,
+) {
+    if request.service != "s3" || mode == ChecksumMode::WhenRequired {
+        return;
+    }
+    if supports_checksum(request) {
+        request.set_checksum_algorithm(Some(algorithm));
+    } else if is_get_object(request) {
+        request.remove_header(CHECKSUM_MODE);
+        request.add_header(CHECKSUM_MODE, "ENABLED");
+    }
+}
+
+/// Whether the request uploads a payload S3 validates a checksum of: those of `PutObject`
+/// and `UploadPart`, and those sent with a `Content-MD5` header.
+fn supports_checksum(request: &SignedRequest) -> bool {
+    if request.payload.is_none() {
+        return false;
+    }
+    if request.headers().contains_key("content-md5") {
+        return true;
+    }
+    request.method == "PUT"
+        && has_key(request)
+        && !request.headers().contains_key("x-amz-copy-source")
+        && request
+            .params
+            .keys()
+            .all(|name| name == "partNumber" || name == "uploadId")
+}
+
+fn is_get_object(request: &SignedRequest) -> bool {
+    request.method == "GET"
+        && has_key(request)
+        && request.params.keys().all(|name| {
+            name == "versionId" || name == "partNumber" || name.starts_with("response-")
+        })
+}
+
+/// Whether the path of the request names an object, rather than just a bucket.
+fn has_key(request: &SignedRequest) -> bool {
+    !access_point::split_bucket(&request.path).1.is_empty()
+}
+
+/// Whether the checksum of the response to the request is validated.
+pub(crate) fn validates_response(request: &SignedRequest) -> bool {
+    request
+        .headers()
+        .get(CHECKSUM_MODE)
+        .into_iter()
+        .flatten()
+        .any(|value| value.eq_ignore_ascii_case(b"enabled"))
+}
+
+/// Validates the body of a successful response with the checksum S3 returned, if any. The
+/// body fails once it was read if the checksums don't match.
+pub(crate) fn validate_response(response: &mut HttpResponse) {
+    if !response.status.is_success() {
+        return;
+    }
+    let checksum = ChecksumAlgorithm::ALL.iter().find_map(|algorithm| {
+        response
+            .headers
+            .get(algorithm.header_name())
+            .map(|checksum| (*algorithm, checksum.clone()))
+    });
+    let (algorithm, expected) = match checksum {
+        Some(checksum) => checksum,
+        None => return,
+    };
+    // objects uploaded in parts have a checksum of the checksums of their parts
+    if expected.contains('-') {
+        debug!("Not validating the composite checksum {}", expected);
+        return;
+    }
+    let inner = std::mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+    let size_hint = inner.size_hint();
+    let body = ChecksumBody {
+        inner,
+        checksum: Some(Checksum::new(algorithm)),
+        expected,
+    };
+    response.body = match size_hint {
+        Some(size_hint) => ByteStream::new_with_size(body, size_hint),
+        None => ByteStream::new(body),
+    };
+}
+
+/// A body computing its checksum as it's read, failing at its end if the checksum doesn't
+/// match the expected one.
+struct ChecksumBody {
+    inner: ByteStream,
+    checksum: Option<Checksum>,
+    expected: String,
+}
+
+impl Stream for ChecksumBody {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                if let Some(ref mut checksum) = this.checksum {
+                    checksum.update(&chunk);
+                }
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            Poll::Ready(None) => {
+                let checksum = match this.checksum.take() {
+                    Some(checksum) => checksum,
+                    None => return Poll::Ready(None),
+                };
+                let algorithm = checksum.algorithm();
+                let actual = checksum.finalize();
+                if actual == this.expected {
+                    Poll::Ready(None)
+                } else {
+                    Poll::Ready(Some(Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "The {} checksum of the body is {}, but S3 returned {}",
+                            algorithm, actual, this.expected
+                        ),
+                    ))))
+                }
+            }
+            poll => poll,
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Failing fast while the endpoint of a service is failing.
+//!
+//! Every host requests are sent to has a circuit, which is closed while requests succeed. After
+//! a number of consecutive failures the circuit opens, and requests to the host fail without
+//! being sent, or are sent to a fallback region instead. Once the cool-down has passed, a single
+//! request is let through to probe the host; the circuit closes again if it succeeds.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use crate::region::Region;
+
+/// Settings of the circuit breaker of a `Client`, see `ClientConfig::set_circuit_breaker`.
+///
+/// Attempts failing with a dispatch error or a transient error, like a `500` or `503` status,
+/// count as failures. Attempts receiving any other response count as successes, since the
+/// endpoint handled them, even if they failed otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_core::request::HttpClient;
+/// use rusoto_core::credential::DefaultCredentialsProvider;
+/// use rusoto_core::{CircuitBreaker, Client, ClientConfig, Region};
+///
+/// let mut circuit_breaker = CircuitBreaker::new();
+/// circuit_breaker.set_failure_threshold(10);
+/// circuit_breaker.set_cool_down(Duration::from_secs(60));
+/// // send requests to Oregon while Virginia is failing
+/// circuit_breaker.set_fallback_region(Region::UsWest2);
+///
+/// let mut config = ClientConfig::new();
+/// config.set_circuit_breaker(circuit_breaker);
+/// let client = Client::new_with_config(
+///     DefaultCredentialsProvider::new().unwrap(),
+///     HttpClient::new().unwrap(),
+///     config,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cool_down: Duration,
+    fallback_region: Option<Region>,
+}
+
+impl CircuitBreaker {
+    /// Create a `CircuitBreaker` which opens after 5 consecutive failures, for 30 seconds.
+    pub fn new() -> CircuitBreaker {
+        CircuitBreaker {
+            failure_threshold: 5,
+            cool_down: Duration::from_secs(30),
+            fallback_region: None,
+        }
+    }
+
+    /// Set after how many consecutive failed attempts the circuit of a host opens.
+    pub fn set_failure_threshold(&mut self, failure_threshold: u32) {
+        self.failure_threshold = failure_threshold.max(1);
+    }
+
+    /// Set how long the circuit of a host stays open before a request is let through again.
+    pub fn set_cool_down(&mut self, cool_down: Duration) {
+        self.cool_down = cool_down;
+    }
+
+    /// Set the region requests are sent to while the circuit of their host is open, instead of
+    /// failing them. Requests sent to an endpoint set with `RequestConfig::endpoint`, or to a
+    /// hostname which doesn't depend on the region, still fail.
+    pub fn set_fallback_region(&mut self, region: Region) {
+        self.fallback_region = Some(region);
+    }
+
+    /// Get after how many consecutive failed attempts the circuit of a host opens.
+    pub fn failure_threshold(&self) -> u32 {
+        self.failure_threshold
+    }
+
+    /// Get how long the circuit of a host stays open.
+    pub fn cool_down(&self) -> Duration {
+        self.cool_down
+    }
+
+    /// Get the region requests are sent to while the circuit of their host is open.
+    pub fn fallback_region(&self) -> Option<&Region> {
+        self.fallback_region.as_ref()
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> CircuitBreaker {
+        CircuitBreaker::new()
+    }
+}
+
+/// The circuits of the hosts a client sends requests to.
+#[derive(Debug)]
+pub(crate) struct ClientCircuitBreaker {
+    settings: CircuitBreaker,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Circuit {
+    /// Requests are sent, counting the consecutive failures.
+    Closed(u32),
+    /// Requests fail until the given time.
+    Open(Instant),
+    /// A request probing the host was let through at the given time.
+    HalfOpen(Instant),
+}
+
+impl ClientCircuitBreaker {
+    pub(crate) fn new(settings: CircuitBreaker) -> ClientCircuitBreaker {
+        ClientCircuitBreaker {
+            settings,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn fallback_region(&self) -> Option<&Region> {
+        self.settings.fallback_region()
+    }
+
+    /// Whether a request may be sent to the host.
+    pub(crate) fn allow(&self, host: &str) -> bool {
+        self.allow_at(host, Instant::now())
+    }
+
+    /// Whether the circuit of the host is open, without letting a probing request through.
+    pub(crate) fn is_open(&self, host: &str) -> bool {
+        let circuits = self.circuits.lock().unwrap();
+        match circuits.get(host) {
+            Some(Circuit::Open(until)) => *until > Instant::now(),
+            _ => false,
+        }
+    }
+
+    /// Records the outcome of an attempt sent to the host.
+    pub(crate) fn record(&self, host: &str, failed: bool) {
+        self.record_at(host, failed, Instant::now())
+    }
+
+    fn allow_at(&self, host: &str, now: Instant) -> bool {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = match circuits.get_mut(host) {
+            Some(circuit) => circuit,
+            None => return true,
+        };
+        let probe = match *circuit {
+            Circuit::Closed(_) => return true,
+            Circuit::Open(until) => now >= until,
+            // a probe which never finished, e.g. because it was cancelled, doesn't keep the
+            // circuit half open forever
+            Circuit::HalfOpen(since) => now >= since + self.settings.cool_down,
+        };
+        if probe {
+            *circuit = Circuit::HalfOpen(now);
+        }
+        probe
+    }
+
+    fn record_at(&self, host: &str, failed: bool, now: Instant) {
+        let mut circuits = self.circuits.lock().unwrap();
+        if !failed {
+            circuits.remove(host);
+            return;
+        }
+        let circuit = circuits
+            .entry(host.to_owned())
+            .or_insert(Circuit::Closed(0));
+        *circuit = match *circuit {
+            Circuit::Closed(failures) if failures + 1 < self.settings.failure_threshold => {
+                Circuit::Closed(failures + 1)
+            }
+            // requests sent before the circuit opened don't extend the cool-down
+            Circuit::Open(until) => Circuit::Open(until),
+            Circuit::Closed(_) | Circuit::HalfOpen(_) => {
+                debug!(
+                    "Opening the circuit of {} for {:?}",
+                    host, self.settings.cool_down
+                );
+                Circuit::Open(now + self.settings.cool_down)
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> ClientCircuitBreaker {
+        let mut settings = CircuitBreaker::new();
+        settings.set_failure_threshold(2);
+        settings.set_cool_down(Duration::from_secs(10));
+        ClientCircuitBreaker::new(settings)
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let breaker = breaker();
+        let now = Instant::now();
+        breaker.record_at("a", true, now);
+        breaker.record_at("a", false, now);
+        breaker.record_at("a", true, now);
+        assert!(breaker.allow_at("a", now));
+
+        breaker.record_at("a", true, now);
+        assert!(!breaker.allow_at("a", now + Duration::from_secs(9)));
+        // other hosts aren't affected
+        assert!(breaker.allow_at("b", now));
+    }
+
+    #[test]
+    fn probes_after_the_cool_down() {
+        let breaker = breaker();
+        let now = Instant::now();
+        breaker.record_at("a", true, now);
+        breaker.record_at("a", true, now);
+
+        // a single request probes the host
+        let later = now + Duration::from_secs(10);
+        assert!(breaker.allow_at("a", later));
+        assert!(!breaker.allow_at("a", later));
+
+        // and opens the circuit again if it fails
+        breaker.record_at("a", true, later);
+        assert!(!breaker.allow_at("a", later + Duration::from_secs(9)));
+
+        // or closes it if it succeeds
+        let later = later + Duration::from_secs(10);
+        assert!(breaker.allow_at("a", later));
+        breaker.record_at("a", false, later);
+        assert!(breaker.allow_at("a", later));
+        assert!(breaker.allow_at("a", later));
+
+        // probes which never finish are given up after the cool-down
+        breaker.record_at("a", true, later);
+        breaker.record_at("a", true, later);
+        let later = later + Duration::from_secs(10);
+        assert!(breaker.allow_at("a", later));
+        assert!(!breaker.allow_at("a", later + Duration::from_secs(9)));
+        assert!(breaker.allow_at("a", later + Duration::from_secs(10)));
+    }
+}
@@ -1,16 +1,45 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
 use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use crate::access_point;
+use crate::checksum::{self, ChecksumMode};
+use crate::circuit_breaker::{CircuitBreaker, ClientCircuitBreaker};
+use crate::compression;
 use crate::credential::{
-    Anonymous, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
+    clock, Anonymous, AwsCredentials, CredentialsError, DefaultCredentialsProvider,
+    ProvideAwsCredentials, StaticProvider,
 };
 use crate::encoding::ContentEncoding;
-use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
-use crate::signature::SignedRequest;
+use crate::endpoint::{self, AddressingStyle, ConfiguredEndpoints, EndpointResolver};
+use crate::hedging::{ClientHedging, HedgingPolicy};
+use crate::interceptor::{Interceptor, Interceptors};
+use crate::metrics::{AttemptMetrics, ClientMetricsObserver, MetricsObserver};
+use crate::presign::{self, PresignError};
+use crate::rate_limiter::{ClientRateLimit, ClientRateLimiter, RateLimit};
+use crate::redirect::{self, BucketRegions};
+use crate::region::Region;
+use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse, ResponseBuffering,
+};
+use crate::request_config::{self, RequestConfig};
+use crate::retry::{self, Attempt, ErrorClass, RetryMode, RetryPolicy};
+use crate::runtime::{Runtime, RuntimeHandle};
+#[cfg(feature = "tower")]
+use crate::service::ServiceDispatcher;
+use crate::signature::{
+    ChecksumAlgorithm, PayloadSigning, PresignOptions, SignedRequest, SignedRequestPayload,
+};
+use crate::signer::{SignRequest, Signer};
+use crate::stream::ByteStream;
+use crate::trace;
 
 use async_trait::async_trait;
+use futures::future::{self, Either};
 use lazy_static::lazy_static;
-use tokio::time;
+use log::debug;
 
 lazy_static! {
     static ref SHARED_CLIENT: Mutex<Weak<ClientInner<DefaultCredentialsProvider, HttpClient>>> =
@@ -24,7 +53,7 @@ pub struct Client {
 }
 
 impl Client {
-    /// Return the shared default client.
+    /// Return the shared default client, which dispatches requests with `HttpClient::shared`.
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
         if let Some(inner) = lock.upgrade() {
@@ -32,11 +61,17 @@ impl Client {
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
-        let dispatcher = HttpClient::new().expect("failed to create request dispatcher");
+        let dispatcher = HttpClient::shared().expect("failed to create request dispatcher");
         let inner = Arc::new(ClientInner {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            config: Default::default(),
+            rate_limit: None,
+            rate_limiter: None,
+            circuit_breaker: None,
+            hedging: None,
+            bucket_regions: Default::default(),
         });
         *lock = Arc::downgrade(&inner);
         Client { inner }
@@ -48,10 +83,47 @@ impl Client {
         P: ProvideAwsCredentials + Send + Sync + 'static,
         D: DispatchSignedRequest + Send + Sync + 'static,
     {
+        Client::new_with_config(credentials_provider, dispatcher, ClientConfig::default())
+    }
+
+    /// Create a client from a credentials provider, request dispatcher and configuration.
+    ///
+    /// Service clients created with `new_with_client` from clients with different
+    /// configurations can be tuned independently, e.g. to retry the requests of one service
+    /// more often than those of another.
+    pub fn new_with_config<P, D>(
+        credentials_provider: P,
+        dispatcher: D,
+        config: ClientConfig,
+    ) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        let rate_limiter = match config.retry_mode {
+            RetryMode::Standard => None,
+            RetryMode::Adaptive => Some(Arc::new(ClientRateLimiter::new())),
+        };
+        let rate_limit = config
+            .rate_limit
+            .map(|settings| Arc::new(ClientRateLimit::new(settings)));
+        let circuit_breaker = config
+            .circuit_breaker
+            .clone()
+            .map(|settings| Arc::new(ClientCircuitBreaker::new(settings)));
+        let hedging = config
+            .hedging_policy
+            .map(|settings| Arc::new(ClientHedging::new(settings)));
         let inner = ClientInner {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            config,
+            rate_limit,
+            rate_limiter,
+            circuit_breaker,
+            hedging,
+            bucket_regions: Default::default(),
         };
         Client {
             inner: Arc::new(inner),
@@ -71,12 +143,51 @@ impl Client {
             credentials_provider: None,
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            config: Default::default(),
+            rate_limit: None,
+            rate_limiter: None,
+            circuit_breaker: None,
+            hedging: None,
+            bucket_regions: Default::default(),
         };
         Client {
             inner: Arc::new(inner),
         }
     }
 
+    /// Create a client from a credentials provider and a tower service dispatching the signed
+    /// requests, to use tower middleware like timeouts, concurrency limits or load shedding.
+    ///
+    /// See `ServiceDispatcher` for the requirements on the service.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use rusoto_core::credential::DefaultCredentialsProvider;
+    /// use rusoto_core::request::HttpClient;
+    /// use rusoto_core::Client;
+    /// use tower::ServiceBuilder;
+    ///
+    /// let stack = ServiceBuilder::new()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .service(HttpClient::new().unwrap());
+    /// let client = Client::new_with_service(DefaultCredentialsProvider::new().unwrap(), stack);
+    /// ```
+    #[cfg(feature = "tower")]
+    pub fn new_with_service<P, S>(credentials_provider: P, service: S) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        S: tower_service::Service<SignedRequest, Response = HttpResponse>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        S::Future: Send,
+    {
+        Client::new_with(credentials_provider, ServiceDispatcher::new(service))
+    }
+
     #[cfg(feature = "encoding")]
     /// Create a client with content encoding to compress payload before sending requests
     pub fn new_with_encoding<P, D>(
@@ -92,6 +203,12 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding,
+            config: Default::default(),
+            rate_limit: None,
+            rate_limiter: None,
+            circuit_breaker: None,
+            hedging: None,
+            bucket_regions: Default::default(),
         };
         Client {
             inner: Arc::new(inner),
@@ -107,6 +224,553 @@ impl Client {
     }
 }
 
+/// Settings of a `Client`, see `Client::new_with_config`.
+///
+/// The endpoint URLs configured with the `AWS_ENDPOINT_URL` and `AWS_ENDPOINT_URL_<SERVICE>`
+/// environment variables, or with the `endpoint_url` settings of the profile, are loaded when
+/// the configuration is created. See `set_ignore_configured_endpoint_urls`.
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    retry_policy: RetryPolicy,
+    retry_mode: RetryMode,
+    operation_timeout: Option<Duration>,
+    attempt_timeout: Option<Duration>,
+    interceptors: Interceptors,
+    metrics_observer: Option<MetricsObserver>,
+    endpoint_resolver: Option<endpoint::Resolver>,
+    signer: Signer,
+    debug_signing: bool,
+    addressing_style: AddressingStyle,
+    configured_endpoints: ConfiguredEndpoints,
+    use_fips_endpoint: bool,
+    use_dualstack_endpoint: bool,
+    s3_use_arn_region: bool,
+    s3_disable_multi_region_access_points: bool,
+    s3_use_accelerate_endpoint: bool,
+    s3_follow_region_redirects: bool,
+    s3_cache_bucket_regions: bool,
+    requester_pays: bool,
+    rate_limit: Option<RateLimit>,
+    circuit_breaker: Option<CircuitBreaker>,
+    hedging_policy: Option<HedgingPolicy>,
+    disable_request_compression: bool,
+    request_min_compression_size_bytes: u32,
+    response_buffering: ResponseBuffering,
+    payload_signing: PayloadSigning,
+    checksum_mode: ChecksumMode,
+    checksum_algorithm: ChecksumAlgorithm,
+    compute_content_md5: bool,
+    s3_expect_continue_threshold: Option<u64>,
+    runtime: RuntimeHandle,
+    app_id: Option<String>,
+    user_agent_tokens: Vec<String>,
+    default_headers: Vec<(String, String)>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> ClientConfig {
+        ClientConfig {
+            retry_policy: Default::default(),
+            retry_mode: Default::default(),
+            operation_timeout: None,
+            attempt_timeout: None,
+            interceptors: Default::default(),
+            metrics_observer: None,
+            endpoint_resolver: None,
+            signer: Default::default(),
+            debug_signing: false,
+            addressing_style: Default::default(),
+            configured_endpoints: ConfiguredEndpoints::load(),
+            use_fips_endpoint: env_flag("AWS_USE_FIPS_ENDPOINT"),
+            use_dualstack_endpoint: env_flag("AWS_USE_DUALSTACK_ENDPOINT"),
+            s3_use_arn_region: env_flag("AWS_S3_USE_ARN_REGION"),
+            s3_disable_multi_region_access_points: env_flag(
+                "AWS_S3_DISABLE_MULTIREGION_ACCESS_POINTS",
+            ),
+            s3_use_accelerate_endpoint: false,
+            s3_follow_region_redirects: true,
+            s3_cache_bucket_regions: true,
+            requester_pays: false,
+            rate_limit: None,
+            circuit_breaker: None,
+            hedging_policy: None,
+            disable_request_compression: env_flag("AWS_DISABLE_REQUEST_COMPRESSION"),
+            request_min_compression_size_bytes: compression::min_compression_size_from_env(),
+            response_buffering: Default::default(),
+            payload_signing: Default::default(),
+            checksum_mode: Default::default(),
+            checksum_algorithm: Default::default(),
+            compute_content_md5: false,
+            s3_expect_continue_threshold: None,
+            runtime: Default::default(),
+            app_id: env::var("AWS_SDK_UA_APP_ID")
+                .ok()
+                .filter(|app_id| !app_id.is_empty()),
+            user_agent_tokens: Vec::new(),
+            default_headers: Vec::new(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Create a new `ClientConfig` with the default settings.
+    pub fn new() -> ClientConfig {
+        ClientConfig::default()
+    }
+
+    /// Set the policy deciding whether and when failed requests are sent again.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Get the retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Set how requests are paced, `RetryMode::Standard` by default.
+    pub fn set_retry_mode(&mut self, retry_mode: RetryMode) {
+        self.retry_mode = retry_mode;
+    }
+
+    /// Get the retry mode.
+    pub fn retry_mode(&self) -> RetryMode {
+        self.retry_mode
+    }
+
+    /// Set the maximum time a request may take, including all of its attempts and the delays
+    /// between them. Requests taking longer fail with a `SignAndDispatchError::Dispatch`.
+    /// Unlimited by default.
+    pub fn set_operation_timeout(&mut self, timeout: Duration) {
+        self.operation_timeout = Some(timeout);
+    }
+
+    /// Get the maximum time a request may take.
+    pub fn operation_timeout(&self) -> Option<Duration> {
+        self.operation_timeout
+    }
+
+    /// Set the maximum time a single attempt of a request may take, from fetching credentials
+    /// to receiving the response headers. Attempts taking longer are cancelled, and retried
+    /// like attempts which failed with a dispatch error. Unlimited by default.
+    pub fn set_attempt_timeout(&mut self, timeout: Duration) {
+        self.attempt_timeout = Some(timeout);
+    }
+
+    /// Get the maximum time a single attempt of a request may take.
+    pub fn attempt_timeout(&self) -> Option<Duration> {
+        self.attempt_timeout
+    }
+
+    /// Add an interceptor hooking into every request of the client. Interceptors are called in
+    /// the order they were added.
+    pub fn add_interceptor<I>(&mut self, interceptor: I)
+    where
+        I: Interceptor + 'static,
+    {
+        self.interceptors.push(Arc::new(interceptor));
+    }
+
+    /// Set an observer called with the metrics of every attempt of the requests of the client.
+    pub fn set_metrics_observer<O>(&mut self, observer: O)
+    where
+        O: ClientMetricsObserver + 'static,
+    {
+        self.metrics_observer = Some(MetricsObserver(Arc::new(observer)));
+    }
+
+    /// Set the resolver of the endpoints requests are sent to, e.g. to send them to VPC
+    /// endpoints or LocalStack. Requests are sent to the endpoints of their region by default.
+    ///
+    /// The resolver isn't consulted for requests sent to an endpoint set with
+    /// `RequestConfig::endpoint`.
+    pub fn set_endpoint_resolver<R>(&mut self, resolver: R)
+    where
+        R: EndpointResolver + 'static,
+    {
+        self.endpoint_resolver = Some(endpoint::Resolver(Arc::new(resolver)));
+    }
+
+    /// Set the signer of requests, e.g. to authorize them with another scheme than AWS
+    /// Signature Version 4, which `SigV4Signer` signs them with by default.
+    ///
+    /// URLs are always presigned with AWS Signature Version 4, see `presign`.
+    pub fn set_signer<S>(&mut self, signer: S)
+    where
+        S: SignRequest + 'static,
+    {
+        self.signer = Signer(Arc::new(signer));
+    }
+
+    /// Set whether the canonical request and string to sign of requests are logged at the
+    /// debug level when they're signed, `false` by default. They're also available to
+    /// interceptors, see `SignedRequest::signing_details`. Secrets aren't included.
+    ///
+    /// This helps to find out why AWS rejects a signature with a `SignatureDoesNotMatch`
+    /// error, by comparing them with those AWS computed.
+    pub fn set_debug_signing(&mut self, debug_signing: bool) {
+        self.debug_signing = debug_signing;
+    }
+
+    /// Get whether the canonical request and string to sign of requests are logged.
+    pub fn debug_signing(&self) -> bool {
+        self.debug_signing
+    }
+
+    /// Set how the bucket of S3 requests is addressed, `AddressingStyle::Auto` by default,
+    /// which addresses it in the host name where possible, and falls back to addressing it
+    /// in the path, e.g. for bucket names with dots or endpoints like MinIO.
+    pub fn set_addressing_style(&mut self, addressing_style: AddressingStyle) {
+        self.addressing_style = addressing_style;
+    }
+
+    /// Get how the bucket of S3 requests is addressed.
+    pub fn addressing_style(&self) -> AddressingStyle {
+        self.addressing_style
+    }
+
+    /// Set whether to ignore the endpoint URLs configured with the `AWS_ENDPOINT_URL` and
+    /// `AWS_ENDPOINT_URL_<SERVICE>` environment variables and the `endpoint_url` settings of
+    /// the profile. They are ignored by default if `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS` is
+    /// `true`.
+    ///
+    /// Service specific URLs take precedence over global ones, and environment variables over
+    /// the profile. The service of `AWS_ENDPOINT_URL_<SERVICE>` is the endpoint prefix of the
+    /// service in upper case, with dots and dashes replaced by underscores, like
+    /// `AWS_ENDPOINT_URL_DYNAMODB` or `AWS_ENDPOINT_URL_RUNTIME_SAGEMAKER`. Configured URLs
+    /// aren't used for requests to a `Region::Custom`, or if an endpoint resolver is set.
+    pub fn set_ignore_configured_endpoint_urls(&mut self, ignore: bool) {
+        self.configured_endpoints = if ignore {
+            ConfiguredEndpoints::default()
+        } else {
+            ConfiguredEndpoints::load()
+        };
+    }
+
+    /// Set whether requests are sent to the FIPS endpoints of their services, like
+    /// `dynamodb-fips.us-gov-west-1.amazonaws.com`, where they exist. Defaults to whether the
+    /// `AWS_USE_FIPS_ENDPOINT` environment variable is `true`.
+    ///
+    /// Requests sent to endpoint URLs configured otherwise, like with an endpoint resolver or a
+    /// `Region::Custom`, are sent to these endpoints as is.
+    pub fn set_use_fips_endpoint(&mut self, use_fips_endpoint: bool) {
+        self.use_fips_endpoint = use_fips_endpoint;
+    }
+
+    /// Get whether requests are sent to the FIPS endpoints of their services.
+    pub fn use_fips_endpoint(&self) -> bool {
+        self.use_fips_endpoint
+    }
+
+    /// Set whether requests are sent to the dual-stack endpoints of their services, which
+    /// resolve to both IPv4 and IPv6 addresses, like `dynamodb.us-east-1.api.aws` or
+    /// `s3.dualstack.us-east-1.amazonaws.com`, where they exist. Defaults to whether the
+    /// `AWS_USE_DUALSTACK_ENDPOINT` environment variable is `true`.
+    ///
+    /// Combined with `set_use_fips_endpoint`, requests are sent to the dual-stack FIPS
+    /// endpoints of their services, or to their FIPS endpoints if they have no dual-stack ones.
+    pub fn set_use_dualstack_endpoint(&mut self, use_dualstack_endpoint: bool) {
+        self.use_dualstack_endpoint = use_dualstack_endpoint;
+    }
+
+    /// Get whether requests are sent to the dual-stack endpoints of their services.
+    pub fn use_dualstack_endpoint(&self) -> bool {
+        self.use_dualstack_endpoint
+    }
+
+    /// Set whether S3 requests to access points in another region, whose ARNs are used in
+    /// place of bucket names, are sent to the region of the access point. They fail otherwise,
+    /// unless the access point is in the region of the request. Defaults to whether the
+    /// `AWS_S3_USE_ARN_REGION` environment variable is `true`.
+    pub fn set_s3_use_arn_region(&mut self, s3_use_arn_region: bool) {
+        self.s3_use_arn_region = s3_use_arn_region;
+    }
+
+    /// Get whether S3 requests to access points in another region are sent to their region.
+    pub fn s3_use_arn_region(&self) -> bool {
+        self.s3_use_arn_region
+    }
+
+    /// Set whether S3 requests to Multi-Region Access Points, whose ARNs have no region, fail
+    /// rather than being sent to the global endpoint of the access point, signed for all
+    /// regions with Signature Version 4A. Defaults to whether the
+    /// `AWS_S3_DISABLE_MULTIREGION_ACCESS_POINTS` environment variable is `true`.
+    pub fn set_s3_disable_multi_region_access_points(&mut self, disable: bool) {
+        self.s3_disable_multi_region_access_points = disable;
+    }
+
+    /// Get whether S3 requests to Multi-Region Access Points fail.
+    pub fn s3_disable_multi_region_access_points(&self) -> bool {
+        self.s3_disable_multi_region_access_points
+    }
+
+    /// Set whether S3 requests are sent to the S3 Transfer Acceleration endpoint,
+    /// `s3-accelerate.amazonaws.com`, which routes them through the nearest edge location, to
+    /// transfer data faster over long distances. Combined with `set_use_dualstack_endpoint`,
+    /// they're sent to `s3-accelerate.dualstack.amazonaws.com`. `false` by default.
+    ///
+    /// Acceleration has to be enabled for the bucket, whose name has to be a valid host name
+    /// without dots, and it's not available in China, in GovCloud or with FIPS endpoints.
+    /// Requests which don't name a bucket, like `ListBuckets`, and those creating or deleting
+    /// buckets are sent to the regional endpoint.
+    pub fn set_s3_use_accelerate_endpoint(&mut self, s3_use_accelerate_endpoint: bool) {
+        self.s3_use_accelerate_endpoint = s3_use_accelerate_endpoint;
+    }
+
+    /// Get whether S3 requests are sent to the S3 Transfer Acceleration endpoint.
+    pub fn s3_use_accelerate_endpoint(&self) -> bool {
+        self.s3_use_accelerate_endpoint
+    }
+
+    /// Set whether S3 requests for buckets in another region than the one of the client are
+    /// signed for the region of the bucket and sent to its endpoint again, when S3 redirects
+    /// them with a `301` or `307` status or fails them with an `AuthorizationHeaderMalformed`
+    /// error naming the region. `true` by default.
+    ///
+    /// Requests with a streaming body, like `PutObject` requests of a `ByteStream`, can't be
+    /// sent again, and requests to custom endpoints, access points or the S3 Transfer
+    /// Acceleration endpoint aren't redirected.
+    pub fn set_s3_follow_region_redirects(&mut self, follow: bool) {
+        self.s3_follow_region_redirects = follow;
+    }
+
+    /// Get whether S3 requests redirected to the region of their bucket are sent there.
+    pub fn s3_follow_region_redirects(&self) -> bool {
+        self.s3_follow_region_redirects
+    }
+
+    /// Set whether the client remembers the regions S3 redirected requests for buckets to, so
+    /// later requests for the same buckets are sent to their region right away. The regions
+    /// are shared by the requests of a `Client` and its clones. `true` by default.
+    pub fn set_s3_cache_bucket_regions(&mut self, cache: bool) {
+        self.s3_cache_bucket_regions = cache;
+    }
+
+    /// Get whether the client remembers the regions of buckets.
+    pub fn s3_cache_bucket_regions(&self) -> bool {
+        self.s3_cache_bucket_regions
+    }
+
+    /// Set whether requests are charged to the requester, to access objects in requester pays
+    /// buckets. The `x-amz-request-payer: requester` header is added to the requests of every
+    /// operation which accepts it, like `GetObject` and `PutObject`, unless the request sets
+    /// it itself. `false` by default.
+    pub fn set_requester_pays(&mut self, requester_pays: bool) {
+        self.requester_pays = requester_pays;
+    }
+
+    /// Get whether requests are charged to the requester.
+    pub fn requester_pays(&self) -> bool {
+        self.requester_pays
+    }
+
+    /// Set a limit of the rate at which the client sends requests. Every attempt of a request
+    /// waits for its turn, including retries. The limit is shared by all requests of a `Client`
+    /// and its clones, and applies in addition to the adaptive retry mode. Unlimited by default.
+    pub fn set_rate_limit(&mut self, rate_limit: RateLimit) {
+        self.rate_limit = Some(rate_limit);
+    }
+
+    /// Get the limit of the rate at which the client sends requests.
+    pub fn rate_limit(&self) -> Option<&RateLimit> {
+        self.rate_limit.as_ref()
+    }
+
+    /// Set a circuit breaker failing requests to hosts which failed repeatedly without sending
+    /// them, or sending them to a fallback region instead, for a cool-down period. Requests
+    /// failed this way fail with a `SignAndDispatchError::Dispatch` and aren't retried.
+    ///
+    /// The circuits are shared by all requests of a `Client` and its clones.
+    pub fn set_circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) {
+        self.circuit_breaker = Some(circuit_breaker);
+    }
+
+    /// Get the circuit breaker.
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    /// Set a policy sending a second copy of attempts of idempotent reads which didn't receive
+    /// a response within a percentile of the latencies of the client, using whichever response
+    /// arrives first. This cuts the tail latency of reads at the cost of a few more requests.
+    /// Requests aren't hedged by default.
+    pub fn set_hedging_policy(&mut self, hedging_policy: HedgingPolicy) {
+        self.hedging_policy = Some(hedging_policy);
+    }
+
+    /// Get the hedging policy.
+    pub fn hedging_policy(&self) -> Option<&HedgingPolicy> {
+        self.hedging_policy.as_ref()
+    }
+
+    /// Set whether to send the payloads of operations which accept gzip compressed payloads,
+    /// like CloudWatch's `PutMetricData`, uncompressed. Defaults to whether the
+    /// `AWS_DISABLE_REQUEST_COMPRESSION` environment variable is `true`.
+    pub fn set_disable_request_compression(&mut self, disable: bool) {
+        self.disable_request_compression = disable;
+    }
+
+    /// Get whether payloads are sent uncompressed.
+    pub fn disable_request_compression(&self) -> bool {
+        self.disable_request_compression
+    }
+
+    /// Set the size from which the payloads of operations which accept gzip compressed payloads
+    /// are compressed, in bytes, at most 10485760. Defaults to the
+    /// `AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES` environment variable, or 10240.
+    pub fn set_request_min_compression_size_bytes(&mut self, size: u32) {
+        self.request_min_compression_size_bytes = size.min(compression::MAX_MIN_COMPRESSION_SIZE);
+    }
+
+    /// Get the size from which payloads are compressed, in bytes.
+    pub fn request_min_compression_size_bytes(&self) -> u32 {
+        self.request_min_compression_size_bytes
+    }
+
+    /// Set whether the bodies of responses are read into memory before they're returned,
+    /// `ResponseBuffering::Stream` by default. Buffering small responses, e.g. with
+    /// `ResponseBuffering::Threshold(64 * 1024)`, retries the connections which break while
+    /// their bodies are read, while larger responses, like big S3 objects, are still streamed.
+    pub fn set_response_buffering(&mut self, response_buffering: ResponseBuffering) {
+        self.response_buffering = response_buffering;
+    }
+
+    /// Get whether the bodies of responses are buffered.
+    pub fn response_buffering(&self) -> ResponseBuffering {
+        self.response_buffering
+    }
+
+    /// Set how the payloads of S3 requests are signed, `PayloadSigning::Signed` by default.
+    /// With `PayloadSigning::Streaming`, the streamed bodies of uploads like `PutObject` and
+    /// `UploadPart` are signed chunk by chunk as they're sent, rather than sent unsigned, and
+    /// with `PayloadSigning::Unsigned` payloads sent over HTTPS aren't hashed at all, which
+    /// saves a lot of CPU time for uploads of several gigabytes.
+    pub fn set_payload_signing(&mut self, payload_signing: PayloadSigning) {
+        self.payload_signing = payload_signing;
+    }
+
+    /// Get how the payloads of S3 requests are signed.
+    pub fn payload_signing(&self) -> PayloadSigning {
+        self.payload_signing
+    }
+
+    /// Set when the payloads of S3 requests and responses are checksummed,
+    /// `ChecksumMode::WhenRequired` by default. With `ChecksumMode::WhenSupported`, uploads
+    /// like `PutObject` and `UploadPart` are sent with a checksum S3 validates, and the
+    /// checksums of downloaded objects are asked for and validated as their bodies are read.
+    pub fn set_checksum_mode(&mut self, checksum_mode: ChecksumMode) {
+        self.checksum_mode = checksum_mode;
+    }
+
+    /// Get when the payloads of S3 requests and responses are checksummed.
+    pub fn checksum_mode(&self) -> ChecksumMode {
+        self.checksum_mode
+    }
+
+    /// Set the algorithm of the checksums sent with S3 uploads, `ChecksumAlgorithm::Crc32` by
+    /// default. Streamed bodies are checksummed as they're sent, with the checksum in a
+    /// trailer.
+    pub fn set_checksum_algorithm(&mut self, checksum_algorithm: ChecksumAlgorithm) {
+        self.checksum_algorithm = checksum_algorithm;
+    }
+
+    /// Get the algorithm of the checksums sent with S3 uploads.
+    pub fn checksum_algorithm(&self) -> ChecksumAlgorithm {
+        self.checksum_algorithm
+    }
+
+    /// Set whether the `Content-MD5` header of S3 requests with a body is computed, unless
+    /// it's set already, e.g. with `PutObjectRequest::content_md5`. It's always computed for
+    /// operations which require it, like `DeleteObjects` and `PutBucketLifecycleConfiguration`,
+    /// and for other operations it's `false` by default. Streamed bodies, like those of most
+    /// uploads, aren't hashed since they're only read once they're sent.
+    pub fn set_compute_content_md5(&mut self, compute_content_md5: bool) {
+        self.compute_content_md5 = compute_content_md5;
+    }
+
+    /// Get whether the `Content-MD5` header of S3 requests with a body is computed.
+    pub fn compute_content_md5(&self) -> bool {
+        self.compute_content_md5
+    }
+
+    /// Set the size in bytes from which S3 uploads, like `PutObject` and `UploadPart`, are
+    /// sent with `Expect: 100-continue`, so requests S3 rejects, e.g. with a 403 or a redirect,
+    /// fail before their body is sent. Not sent by default.
+    ///
+    /// The body is held back until S3 answers or for the timeout set with
+    /// `HttpConfig::expect_continue_timeout`, which each of these uploads waits for.
+    pub fn set_s3_expect_continue_threshold(&mut self, threshold: u64) {
+        self.s3_expect_continue_threshold = Some(threshold);
+    }
+
+    /// Get the size in bytes from which S3 uploads are sent with `Expect: 100-continue`.
+    pub fn s3_expect_continue_threshold(&self) -> Option<u64> {
+        self.s3_expect_continue_threshold
+    }
+
+    /// Set the runtime whose timers are used for timeouts and to delay retries,
+    /// `TokioRuntime` by default.
+    pub fn set_runtime<R>(&mut self, runtime: R)
+    where
+        R: Runtime + 'static,
+    {
+        self.runtime = RuntimeHandle(Arc::new(runtime));
+    }
+
+    /// Set the id of the application making the requests, like `my-service#1.2.3`, which is
+    /// appended to the `User-Agent` header as `app/my-service#1.2.3`. It shows up in CloudTrail,
+    /// so the requests of an application can be told apart from those of others using the same
+    /// credentials. Defaults to the `AWS_SDK_UA_APP_ID` environment variable.
+    pub fn set_app_id<S>(&mut self, app_id: S)
+    where
+        S: Into<String>,
+    {
+        self.app_id = Some(app_id.into());
+    }
+
+    /// Get the id of the application making the requests.
+    pub fn app_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
+    /// Add a token which is appended to the `User-Agent` header of every request, after the
+    /// application id.
+    pub fn add_user_agent_token<S>(&mut self, token: S)
+    where
+        S: Into<String>,
+    {
+        self.user_agent_tokens.push(token.into());
+    }
+
+    /// Get the tokens appended to the `User-Agent` header.
+    pub fn user_agent_tokens(&self) -> &[String] {
+        &self.user_agent_tokens
+    }
+
+    /// Add a header which is sent and signed with every request of the client, like
+    /// `x-amz-source-arn` or a correlation id for a proxy. Headers the request sets itself take
+    /// precedence over default headers of the same name.
+    pub fn add_default_header<K, V>(&mut self, name: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.default_headers.push((name.into(), value.into()));
+    }
+
+    /// Get the headers sent with every request.
+    pub fn default_headers(&self) -> &[(String, String)] {
+        &self.default_headers
+    }
+}
+
+/// Whether the environment variable is set to `true`.
+fn env_flag(name: &str) -> bool {
+    env::var(name)
+        .map(|value| value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
 /// Error that occurs during `sign_and_dispatch`
 #[derive(Debug, PartialEq)]
 pub enum SignAndDispatchError {
@@ -116,6 +780,24 @@ pub enum SignAndDispatchError {
     Dispatch(HttpDispatchError),
 }
 
+impl fmt::Display for SignAndDispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SignAndDispatchError::Credentials(ref err) => err.fmt(f),
+            SignAndDispatchError::Dispatch(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for SignAndDispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SignAndDispatchError::Credentials(ref err) => Some(err),
+            SignAndDispatchError::Dispatch(ref err) => Some(err),
+        }
+    }
+}
+
 #[async_trait]
 trait SignAndDispatch {
     async fn sign_and_dispatch(
@@ -129,6 +811,13 @@ struct ClientInner<P, D> {
     credentials_provider: Option<Arc<P>>,
     dispatcher: Arc<D>,
     content_encoding: ContentEncoding,
+    config: ClientConfig,
+    rate_limit: Option<Arc<ClientRateLimit>>,
+    /// Set in the adaptive retry mode.
+    rate_limiter: Option<Arc<ClientRateLimiter>>,
+    circuit_breaker: Option<Arc<ClientCircuitBreaker>>,
+    hedging: Option<Arc<ClientHedging>>,
+    bucket_regions: Arc<BucketRegions>,
 }
 
 impl<P, D> Clone for ClientInner<P, D> {
@@ -137,65 +826,2087 @@ impl<P, D> Clone for ClientInner<P, D> {
             credentials_provider: self.credentials_provider.clone(),
             dispatcher: self.dispatcher.clone(),
             content_encoding: self.content_encoding.clone(),
+            config: self.config.clone(),
+            rate_limit: self.rate_limit.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            hedging: self.hedging.clone(),
+            bucket_regions: self.bucket_regions.clone(),
         }
     }
 }
 
 async fn sign_and_dispatch<P, D>(
     client: ClientInner<P, D>,
+    request: SignedRequest,
+    timeout: Option<Duration>,
+) -> Result<HttpResponse, SignAndDispatchError>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    let overrides = request_config::current();
+    if let Some(options) = presign::options() {
+        let url = presign_request(&client, overrides.as_deref(), request, &options).await;
+        presign::record(url);
+        return Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+            "Request presigned rather than sent".to_owned(),
+        )));
+    }
+    let operation_timeout = overrides
+        .as_ref()
+        .and_then(|overrides| overrides.operation_timeout)
+        .or(client.config.operation_timeout);
+    let attempt_timeout = overrides
+        .as_ref()
+        .and_then(|overrides| overrides.attempt_timeout)
+        .or(client.config.attempt_timeout);
+    let span = trace::Span::request(&request);
+    let attempts = dispatch_attempts(
+        &client,
+        overrides.as_deref(),
+        request,
+        timeout,
+        attempt_timeout,
+    );
+    let attempts = async {
+        match operation_timeout {
+            Some(limit) => client
+                .config
+                .runtime
+                .timeout(limit, attempts)
+                .await
+                .unwrap_or_else(|| {
+                    Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                        format!("Operation timed out after {:?}", limit),
+                    )))
+                }),
+            None => attempts.await,
+        }
+    };
+    let cancellation_token = overrides
+        .as_ref()
+        .and_then(|overrides| overrides.cancellation_token.as_ref());
+    let token = match cancellation_token {
+        Some(token) => token,
+        None => return span.instrument(attempts).await,
+    };
+    let cancelled = || {
+        Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+            "Request cancelled".to_owned(),
+        )))
+    };
+    if token.is_cancelled() {
+        return cancelled();
+    }
+    futures::pin_mut!(attempts);
+    // dropping the attempts closes their connections
+    let result = match future::select(span.instrument(attempts), token.cancelled()).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => return cancelled(),
+    };
+    result.map(|mut response| {
+        let body = std::mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+        response.body = token.wrap_body(body);
+        response
+    })
+}
+
+/// Sends the request until it succeeds or the retry policy gives up.
+async fn dispatch_attempts<P, D>(
+    client: &ClientInner<P, D>,
+    overrides: Option<&RequestConfig>,
     mut request: SignedRequest,
     timeout: Option<Duration>,
+    attempt_timeout: Option<Duration>,
 ) -> Result<HttpResponse, SignAndDispatchError>
 where
     P: ProvideAwsCredentials + Send + Sync + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
+    let endpoint_overridden = apply_overrides(overrides, &mut request);
+    checksum::prepare_request(
+        &mut request,
+        overrides
+            .and_then(|overrides| overrides.checksum_mode)
+            .unwrap_or(client.config.checksum_mode),
+        overrides
+            .and_then(|overrides| overrides.checksum_algorithm)
+            .unwrap_or(client.config.checksum_algorithm),
+    );
+    let s3_endpoint = use_s3_endpoint(&client.config, overrides, endpoint_overridden, &mut request)
+        .map_err(|message| SignAndDispatchError::Dispatch(HttpDispatchError::new(message)))?;
+    // requests to access points and accelerated requests aren't sent elsewhere
+    let endpoint_overridden = endpoint_overridden || s3_endpoint;
+    // the hostname before it's resolved, to resolve it again for a fallback region
+    let unresolved_hostname = (
+        request.hostname.clone(),
+        request.endpoint_prefix.clone(),
+        request.path.clone(),
+    );
+    let addressing_style = overrides
+        .and_then(|overrides| overrides.addressing_style)
+        .unwrap_or(client.config.addressing_style);
+    // the bucket of S3 requests which are sent to the region S3 redirects them to
+    let redirected_bucket = match request.region {
+        Region::Custom { .. } => None,
+        _ if request.service == "s3"
+            && client.config.s3_follow_region_redirects
+            && !endpoint_overridden =>
+        {
+            Some(access_point::split_bucket(&request.path).0.to_owned())
+                .filter(|bucket| !bucket.is_empty())
+        }
+        _ => None,
+    };
+    let cache_bucket_regions = client.config.s3_cache_bucket_regions;
+    if let Some(ref bucket) = redirected_bucket {
+        if let Some(region) = client
+            .bucket_regions
+            .get(bucket)
+            .filter(|_| cache_bucket_regions)
+        {
+            request.set_region(region);
+        }
+    }
+    if !endpoint_overridden {
+        resolve_endpoint(&client.config, &mut request);
+    }
+    if !s3_endpoint {
+        endpoint::use_addressing_style(&mut request, addressing_style);
+    }
+    add_default_headers(&client.config, &mut request);
+    add_request_payer(&client.config, overrides, &mut request);
+    for interceptor in interceptors(&client.config, overrides) {
+        interceptor.before_execution(&mut request);
+    }
     client.content_encoding.encode(&mut request);
-    if let Some(provider) = client.credentials_provider {
-        let credentials = if let Some(to) = timeout {
-            time::timeout(to, provider.credentials())
-                .await
-                .map_err(|_| CredentialsError {
-                    message: "Timeout getting credentials".to_owned(),
-                })
-                .and_then(std::convert::identity)
-        } else {
-            provider.credentials().await
+    if !client.config.disable_request_compression {
+        compression::compress_request(
+            &mut request,
+            client.config.request_min_compression_size_bytes,
+        );
+    }
+    if client.config.debug_signing {
+        request.set_debug_signing(true);
+    }
+    let compute_content_md5 = overrides
+        .and_then(|overrides| overrides.compute_content_md5)
+        .unwrap_or(client.config.compute_content_md5);
+    if request.service == "s3"
+        && compute_content_md5
+        && !request.headers().contains_key("content-md5")
+    {
+        request.set_content_md5_header();
+    }
+    if let Some(threshold) = client.config.s3_expect_continue_threshold {
+        add_expect_continue(&mut request, threshold);
+    }
+    if request.service == "s3" {
+        request.set_payload_signing(
+            overrides
+                .and_then(|overrides| overrides.payload_signing)
+                .unwrap_or(client.config.payload_signing),
+        );
+    }
+    if let Some(ref app_id) = client.config.app_id {
+        request.add_user_agent_token(&format!("app/{}", app_id));
+    }
+    for token in &client.config.user_agent_tokens {
+        request.add_user_agent_token(token);
+    }
+    let mut metrics = client
+        .config
+        .metrics_observer
+        .as_ref()
+        .map(|_| attempt_metrics(&request));
+    let retry_policy = overrides
+        .and_then(|overrides| overrides.retry_policy.as_ref())
+        .unwrap_or(&client.config.retry_policy);
+    let idempotent = overrides
+        .and_then(|overrides| overrides.idempotent)
+        .unwrap_or_else(|| retry::is_idempotent(&request));
+    let response_buffering = overrides
+        .and_then(|overrides| overrides.response_buffering)
+        .unwrap_or(client.config.response_buffering);
+    let mut attempt = 1;
+    let mut redirected = false;
+    loop {
+        if let Some(ref circuit_breaker) = client.circuit_breaker {
+            let host = request.hostname();
+            if !circuit_breaker.allow(&host) {
+                let fallback = circuit_breaker
+                    .fallback_region()
+                    .filter(|_| !endpoint_overridden);
+                if let Some(region) = fallback {
+                    move_to_region(
+                        &client.config,
+                        &mut request,
+                        &unresolved_hostname,
+                        region.clone(),
+                        addressing_style,
+                    );
+                }
+                let fallback_host = request.hostname();
+                if fallback_host == host || !circuit_breaker.allow(&fallback_host) {
+                    return Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                        format!("Circuit breaker open for {}", host),
+                    )));
+                }
+                debug!(
+                    "Circuit breaker open for {}, sending to {}",
+                    host, fallback_host
+                );
+            }
         }
-        .map_err(SignAndDispatchError::Credentials)?;
-        if credentials.is_anonymous() {
-            request.complement();
+        let redirectable = redirected_bucket.is_some() && !redirected;
+        // keep a copy of the request in case it has to be sent again
+        let mut retry_request = if attempt < retry_policy.max_attempts() || redirectable {
+            request.try_clone()
         } else {
-            request.sign(&credentials);
+            None
+        };
+        let region = request.region.clone();
+        if let Some(delay) = client.rate_limit.as_ref().and_then(|l| l.acquire()) {
+            debug!("Rate limited, sending in {:?}", delay);
+            client.config.runtime.sleep(delay).await;
         }
-    } else {
-        request.complement();
+        if let Some(delay) = client.rate_limiter.as_ref().and_then(|l| l.acquire()) {
+            debug!("Rate limited after throttling, sending in {:?}", delay);
+            client.config.runtime.sleep(delay).await;
+        }
+        let hedge_request = client
+            .hedging
+            .as_ref()
+            .filter(|_| idempotent && retry::is_read_only(&request))
+            .and_then(|_| request.try_clone());
+        trace::record_retries(attempt - 1);
+        let host = request.hostname();
+        let start = Instant::now();
+        let attempt_future = trace::Span::attempt(attempt).instrument(sign_and_dispatch_hedged(
+            client,
+            overrides,
+            request,
+            hedge_request,
+            timeout,
+        ));
+        let result = match attempt_timeout {
+            Some(limit) => client
+                .config
+                .runtime
+                .timeout(limit, attempt_future)
+                .await
+                .unwrap_or_else(|| {
+                    Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                        format!("Attempt timed out after {:?}", limit),
+                    )))
+                }),
+            None => attempt_future.await,
+        };
+        let latency = start.elapsed();
+        let Attempt {
+            mut result,
+            class,
+            retry_after,
+            bytes_received,
+        } = Attempt::inspect(result, idempotent, response_buffering).await;
+        if let (Some(observer), Some(metrics)) =
+            (client.config.metrics_observer.as_ref(), metrics.as_mut())
+        {
+            metrics.retries = attempt - 1;
+            metrics.latency = latency;
+            metrics.bytes_received = bytes_received;
+            metrics.status = result.as_ref().ok().map(|response| response.status);
+            metrics.error_class = class;
+            observer.0.on_attempt(metrics);
+        }
+        if let Ok(ref response) = result {
+            trace::record_response(response);
+        }
+        for interceptor in interceptors(&client.config, overrides) {
+            match result {
+                Ok(ref mut response) => interceptor.after_response(response),
+                Err(SignAndDispatchError::Dispatch(ref error)) => {
+                    interceptor.after_dispatch_error(error)
+                }
+                Err(SignAndDispatchError::Credentials(_)) => {}
+            }
+        }
+        if let Some(ref rate_limiter) = client.rate_limiter {
+            rate_limiter.update(class == Some(ErrorClass::Throttling));
+        }
+        if let Some(ref circuit_breaker) = client.circuit_breaker {
+            match result {
+                Err(SignAndDispatchError::Dispatch(_)) => circuit_breaker.record(&host, true),
+                // the request wasn't sent
+                Err(SignAndDispatchError::Credentials(_)) => {}
+                Ok(_) => circuit_breaker.record(&host, class == Some(ErrorClass::Transient)),
+            }
+        }
+        let redirect_region = match result {
+            Ok(ref mut response) if redirectable => {
+                redirect::redirect_region(response, &region).await
+            }
+            _ => None,
+        };
+        if let Some(region) = redirect_region {
+            if let (Some(bucket), Some(mut redirect_request)) =
+                (redirected_bucket.as_ref(), retry_request.take())
+            {
+                debug!("S3 redirected the request to {}", region.name());
+                if cache_bucket_regions {
+                    client.bucket_regions.insert(bucket, region.clone());
+                }
+                move_to_region(
+                    &client.config,
+                    &mut redirect_request,
+                    &unresolved_hostname,
+                    region,
+                    addressing_style,
+                );
+                // the redirect doesn't count as an attempt
+                request = redirect_request;
+                redirected = true;
+                continue;
+            }
+        }
+        let retry_request = match retry_request {
+            Some(retry_request) if attempt < retry_policy.max_attempts() => retry_request,
+            _ => return result,
+        };
+        let circuit_open = client
+            .circuit_breaker
+            .as_ref()
+            .filter(|circuit_breaker| {
+                circuit_breaker.fallback_region().is_none() && circuit_breaker.is_open(&host)
+            })
+            .is_some();
+        match class {
+            // the next attempt would fail without being sent
+            Some(_) if circuit_open => return result,
+            Some(class) if retry_policy.retries(class) => {
+                let delay = retry_policy.delay(attempt, retry_after);
+                debug!(
+                    "Attempt {} failed with a {}, retrying in {:?}",
+                    attempt, class, delay
+                );
+                client.config.runtime.sleep(delay).await;
+            }
+            _ => return result,
+        }
+        request = retry_request;
+        attempt += 1;
     }
-    client
-        .dispatcher
-        .dispatch(request, timeout)
-        .await
-        .map_err(SignAndDispatchError::Dispatch)
 }
 
-#[async_trait]
-impl<P, D> SignAndDispatch for ClientInner<P, D>
+/// Resolves the endpoint of the request for another region, from the hostname, endpoint prefix
+/// and path it had before its endpoint was resolved.
+fn move_to_region(
+    config: &ClientConfig,
+    request: &mut SignedRequest,
+    unresolved_hostname: &(Option<String>, Option<String>, String),
+    region: Region,
+    addressing_style: AddressingStyle,
+) {
+    request.hostname = unresolved_hostname.0.clone();
+    request.endpoint_prefix = unresolved_hostname.1.clone();
+    request.path = unresolved_hostname.2.clone();
+    request.set_region(region);
+    resolve_endpoint(config, request);
+    endpoint::use_addressing_style(request, addressing_style);
+}
+
+/// Sets the region and endpoint of the request which are overridden with `with_config`.
+/// Returns whether the endpoint was overridden.
+fn apply_overrides(overrides: Option<&RequestConfig>, request: &mut SignedRequest) -> bool {
+    let overrides = match overrides {
+        Some(overrides) => overrides,
+        None => return false,
+    };
+    if let Some(ref region) = overrides.region {
+        request.set_region(region.clone());
+    }
+    if let Some(ref endpoint) = overrides.endpoint {
+        let name = request.region.name().to_owned();
+        request.set_region(Region::Custom {
+            name,
+            endpoint: endpoint.clone(),
+        });
+    }
+    overrides.endpoint.is_some()
+}
+
+/// The interceptors of the client, then those added with `with_config`.
+fn interceptors<'a>(
+    config: &'a ClientConfig,
+    overrides: Option<&'a RequestConfig>,
+) -> impl Iterator<Item = &'a Arc<dyn Interceptor>> {
+    let overrides = overrides.map(|overrides| &overrides.interceptors);
+    config
+        .interceptors
+        .iter()
+        .chain(overrides.into_iter().flat_map(Interceptors::iter))
+}
+
+/// Presigns the request as a URL for `presign`, with the endpoint and credentials it would be
+/// sent with.
+async fn presign_request<P, D>(
+    client: &ClientInner<P, D>,
+    overrides: Option<&RequestConfig>,
+    mut request: SignedRequest,
+    options: &PresignOptions,
+) -> Result<String, PresignError>
 where
     P: ProvideAwsCredentials + Send + Sync + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
-    async fn sign_and_dispatch(
-        &self,
-        request: SignedRequest,
-        timeout: Option<Duration>,
-    ) -> Result<HttpResponse, SignAndDispatchError> {
-        sign_and_dispatch(self.clone(), request, timeout).await
+    let endpoint_overridden = apply_overrides(overrides, &mut request);
+    let s3_endpoint = use_s3_endpoint(&client.config, overrides, endpoint_overridden, &mut request)
+        .map_err(PresignError::Unsupported)?;
+    if !endpoint_overridden && !s3_endpoint {
+        resolve_endpoint(&client.config, &mut request);
+    }
+    if !s3_endpoint {
+        let addressing_style = overrides
+            .and_then(|overrides| overrides.addressing_style)
+            .unwrap_or(client.config.addressing_style);
+        endpoint::use_addressing_style(&mut request, addressing_style);
+    }
+    add_request_payer(&client.config, overrides, &mut request);
+    presign::prepare(&mut request)?;
+    // the requests of the provider are sent, without the settings of `with_config`
+    let credentials = presign::without_presigning(request_config::without_config(async {
+        if let Some(provider) = overrides.and_then(|o| o.credentials.as_ref()) {
+            provider.credentials().await
+        } else if let Some(provider) = client.credentials_provider.as_ref() {
+            provider.credentials().await
+        } else {
+            Ok(AwsCredentials::default())
+        }
+    }))
+    .await
+    .map_err(PresignError::Credentials)?;
+    if credentials.is_anonymous() {
+        return Err(PresignError::Credentials(CredentialsError::new(
+            "No credentials to presign the request with",
+        )));
+    }
+    let signs_payload = presign::signs_payload(&request);
+    Ok(request.generate_presigned_url_with_options(&credentials, options, signs_payload))
+}
+
+/// Adds the default headers of the client which the request doesn't set itself.
+fn add_default_headers(config: &ClientConfig, request: &mut SignedRequest) {
+    let own_headers: Vec<String> = request.headers().keys().cloned().collect();
+    for (name, value) in &config.default_headers {
+        if !own_headers.contains(&name.to_ascii_lowercase()) {
+            request.add_header(name, value);
+        }
     }
 }
 
-#[test]
-fn client_is_send_and_sync() {
-    fn is_send_and_sync<T: Send + Sync>() {}
+/// Charges the request to the requester if the client or the request is configured to, and
+/// its operation accepts it.
+fn add_request_payer(
+    config: &ClientConfig,
+    overrides: Option<&RequestConfig>,
+    request: &mut SignedRequest,
+) {
+    let requester_pays = overrides
+        .and_then(|overrides| overrides.requester_pays)
+        .unwrap_or(config.requester_pays);
+    if requester_pays
+        && request.request_payer
+        && !request.headers().contains_key("x-amz-request-payer")
+    {
+        request.add_header("x-amz-request-payer", "requester");
+    }
+}
 
-    is_send_and_sync::<Client>();
+/// Asks S3 to accept an upload of at least `threshold` bytes before its body is sent.
+fn add_expect_continue(request: &mut SignedRequest, threshold: u64) {
+    if request.service != "s3" || request.method != "PUT" {
+        return;
+    }
+    let size = match request.payload {
+        None => return,
+        Some(SignedRequestPayload::Buffer(ref payload)) => Some(payload.len() as u64),
+        Some(SignedRequestPayload::Stream(ref stream)) => {
+            stream.size_hint().map(|size| size as u64).or_else(|| {
+                request
+                    .headers()
+                    .get("content-length")
+                    .and_then(|length| std::str::from_utf8(&length[0]).ok())
+                    .and_then(|length| length.parse().ok())
+            })
+        }
+    };
+    // streams of unknown size may well be large
+    let small = matches!(size, Some(size) if size < threshold);
+    if !small && !request.headers().contains_key("expect") {
+        request.add_header("Expect", "100-continue");
+    }
+}
+
+/// Sends an S3 request to the access point its bucket names, or to the S3 Transfer
+/// Acceleration endpoint, returning whether it was, in which case its bucket is addressed in
+/// its host name already.
+fn use_s3_endpoint(
+    config: &ClientConfig,
+    overrides: Option<&RequestConfig>,
+    endpoint_overridden: bool,
+    request: &mut SignedRequest,
+) -> Result<bool, String> {
+    if access_point::use_access_point(config, request)? {
+        return Ok(true);
+    }
+    let accelerate = overrides
+        .and_then(|overrides| overrides.s3_use_accelerate_endpoint)
+        .unwrap_or(config.s3_use_accelerate_endpoint);
+    if !accelerate || endpoint_overridden {
+        return Ok(false);
+    }
+    endpoint::use_accelerate_endpoint(
+        request,
+        config.use_fips_endpoint,
+        config.use_dualstack_endpoint,
+    )
+}
+
+/// Sets the endpoint of a request which isn't sent to an endpoint set with
+/// `RequestConfig::endpoint`.
+fn resolve_endpoint(config: &ClientConfig, request: &mut SignedRequest) {
+    match config.endpoint_resolver {
+        Some(ref resolver) => resolver.resolve(request),
+        None => {
+            if !config.configured_endpoints.resolve(request) {
+                endpoint::use_endpoint_variant(
+                    request,
+                    config.use_fips_endpoint,
+                    config.use_dualstack_endpoint,
+                );
+            }
+        }
+    }
+}
+
+/// Returns the metrics which are the same for every attempt of the request.
+fn attempt_metrics(request: &SignedRequest) -> AttemptMetrics {
+    let bytes_sent = match request.payload {
+        None => Some(0),
+        Some(SignedRequestPayload::Buffer(ref payload)) => Some(payload.len() as u64),
+        Some(SignedRequestPayload::Stream(_)) => request
+            .headers()
+            .get("content-length")
+            .and_then(|length| std::str::from_utf8(&length[0]).ok())
+            .and_then(|length| length.parse().ok()),
+    };
+    AttemptMetrics {
+        service: request.service.clone(),
+        operation: request.operation_name().map(str::to_owned),
+        region: request.region.name().to_owned(),
+        retries: 0,
+        latency: Duration::default(),
+        bytes_sent,
+        bytes_received: None,
+        status: None,
+        error_class: None,
+    }
+}
+
+async fn sign_and_dispatch_attempt<P, D>(
+    client: &ClientInner<P, D>,
+    overrides: Option<&RequestConfig>,
+    mut request: SignedRequest,
+    timeout: Option<Duration>,
+) -> Result<HttpResponse, SignAndDispatchError>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    for interceptor in interceptors(&client.config, overrides) {
+        interceptor.before_signing(&mut request);
+    }
+    let signer = &client.config.signer.0;
+    let unsigned = matches!(overrides, Some(overrides) if overrides.unsigned == Some(true));
+    let credentials = if unsigned || !signer.uses_credentials() {
+        None
+    } else if let Some(provider) = overrides.and_then(|overrides| overrides.credentials.as_ref()) {
+        Some(fetch_credentials(&client.config.runtime, provider, timeout).await?)
+    } else if let Some(provider) = client.credentials_provider.as_ref() {
+        Some(fetch_credentials(&client.config.runtime, &**provider, timeout).await?)
+    } else {
+        None
+    };
+    signer.sign_request(&mut request, credentials.as_ref());
+    if let Some(details) = request.signing_details() {
+        debug!(
+            "Canonical request:\n{}\nString to sign:\n{}",
+            details.canonical_request, details.string_to_sign
+        );
+    }
+    for interceptor in interceptors(&client.config, overrides) {
+        interceptor.before_transmit(&mut request);
+    }
+    let validate_checksum = checksum::validates_response(&request);
+    let mut response = match client.dispatcher.dispatch(request, timeout).await {
+        Ok(response) => response,
+        Err(error) => {
+            trace::record_error(&error);
+            return Err(SignAndDispatchError::Dispatch(error));
+        }
+    };
+    trace::record_response(&response);
+    // learn the time of AWS, so credentials expire according to it rather than the local clock
+    if let Some(date) = response.headers.get("date") {
+        clock::record_date_header(date);
+    }
+    if validate_checksum {
+        checksum::validate_response(&mut response);
+    }
+    Ok(response)
+}
+
+/// Sends an attempt, and a copy of it if no response arrived within the hedging delay, using
+/// whichever succeeds first.
+async fn sign_and_dispatch_hedged<P, D>(
+    client: &ClientInner<P, D>,
+    overrides: Option<&RequestConfig>,
+    request: SignedRequest,
+    hedge_request: Option<SignedRequest>,
+    timeout: Option<Duration>,
+) -> Result<HttpResponse, SignAndDispatchError>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    let (hedging, hedge_request) = match (client.hedging.as_ref(), hedge_request) {
+        (Some(hedging), Some(hedge_request)) => (hedging, hedge_request),
+        _ => return sign_and_dispatch_attempt(client, overrides, request, timeout).await,
+    };
+    let start = Instant::now();
+    let delay = hedging.delay();
+    let primary = sign_and_dispatch_attempt(client, overrides, request, timeout);
+    futures::pin_mut!(primary);
+    let primary = match future::select(primary, client.config.runtime.sleep(delay)).await {
+        Either::Left((result, _)) => {
+            if result.is_ok() {
+                hedging.record(start.elapsed());
+            }
+            return result;
+        }
+        Either::Right((_, primary)) => primary,
+    };
+    debug!("No response after {:?}, hedging the request", delay);
+    let hedge = sign_and_dispatch_attempt(client, overrides, hedge_request, timeout);
+    futures::pin_mut!(hedge);
+    // the attempt which loses is cancelled when it's dropped
+    let result = match future::select(primary, hedge).await {
+        Either::Left((Ok(response), _)) | Either::Right((Ok(response), _)) => Ok(response),
+        Either::Left((Err(_), hedge)) => hedge.await,
+        Either::Right((Err(_), primary)) => primary.await,
+    };
+    if result.is_ok() {
+        hedging.record(start.elapsed());
+    }
+    result
+}
+
+async fn fetch_credentials<P>(
+    runtime: &RuntimeHandle,
+    provider: &P,
+    timeout: Option<Duration>,
+) -> Result<AwsCredentials, SignAndDispatchError>
+where
+    P: ProvideAwsCredentials + ?Sized,
+{
+    // the settings of `with_config` don't apply to the requests of the provider
+    let credentials = request_config::without_config(provider.credentials());
+    if let Some(to) = timeout {
+        runtime
+            .timeout(to, credentials)
+            .await
+            .ok_or_else(|| CredentialsError {
+                message: "Timeout getting credentials".to_owned(),
+            })
+            .and_then(std::convert::identity)
+    } else {
+        credentials.await
+    }
+    .map_err(SignAndDispatchError::Credentials)
+}
+
+#[async_trait]
+impl<P, D> SignAndDispatch for ClientInner<P, D>
+where
+    P: ProvideAwsCredentials + Send + Sync + 'static,
+    D: DispatchSignedRequest + Send + Sync + 'static,
+{
+    async fn sign_and_dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<Duration>,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        sign_and_dispatch(self.clone(), request, timeout).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::AnonymousCredentialsProvider;
+    use crate::request::DispatchSignedRequestFuture;
+    use crate::{AnonymousSigner, DefaultEndpointResolver, Endpoint, Region, RequestConfigExt};
+    use futures::FutureExt;
+    use http::{HeaderMap, StatusCode};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn client_is_send_and_sync() {
+        fn is_send_and_sync<T: Send + Sync>() {}
+
+        is_send_and_sync::<Client>();
+    }
+
+    /// Fails requests which don't have the expected `Authorization` header.
+    struct AuthorizationChecker {
+        signed: bool,
+    }
+
+    impl DispatchSignedRequest for AuthorizationChecker {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let signed = request.headers().contains_key("authorization");
+            let status = if signed == self.signed {
+                StatusCode::OK
+            } else {
+                StatusCode::BAD_REQUEST
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status,
+                body: Vec::new().into(),
+                headers: HeaderMap::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    fn request() -> SignedRequest {
+        SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key")
+    }
+
+    /// Responds with the tokens appended to the user agent of the request.
+    struct UserAgentEcho;
+
+    impl DispatchSignedRequest for UserAgentEcho {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: request.user_agent_tokens.join(" ").into_bytes().into(),
+                headers: HeaderMap::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn user_agent_tokens() {
+        let mut config = ClientConfig::new();
+        config.set_app_id("my-service#1.2.3");
+        config.add_user_agent_token("team/storage");
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, UserAgentEcho, config);
+        let mut response = client.sign_and_dispatch(request()).await.unwrap();
+        let body = response.buffer().await.unwrap().body;
+        assert_eq!(&body[..], b"app/my-service#1.2.3 team/storage");
+    }
+
+    /// Responds with the headers of the request, and the headers its signature covers.
+    struct HeaderEcho;
+
+    impl DispatchSignedRequest for HeaderEcho {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let mut headers = HeaderMap::default();
+            for (name, values) in request.headers() {
+                let value = String::from_utf8(values.join(&b","[..])).unwrap();
+                headers.insert(name.parse::<http::header::HeaderName>().unwrap(), value);
+            }
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers,
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn default_headers() {
+        let mut config = ClientConfig::new();
+        config.add_default_header("X-Correlation-Id", "1234");
+        config.add_default_header("x-amz-source-arn", "arn:aws:s3:::bucket");
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        let mut request = request();
+        request.add_header("x-amz-source-arn", "arn:aws:s3:::other");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.headers["x-correlation-id"], "1234");
+        // the request's own headers take precedence
+        assert_eq!(response.headers["x-amz-source-arn"], "arn:aws:s3:::other");
+        assert!(response.headers["authorization"].contains("x-amz-source-arn;x-correlation-id"));
+    }
+
+    #[tokio::test]
+    async fn payload_signing() {
+        let upload = || {
+            let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+            request.set_payload_stream(ByteStream::from(vec![0; 100]));
+            request
+        };
+        let mut config = ClientConfig::new();
+        config.set_payload_signing(PayloadSigning::Streaming);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        let response = client.sign_and_dispatch(upload()).await.unwrap();
+        assert_eq!(
+            response.headers["x-amz-content-sha256"],
+            "STREAMING-AWS4-HMAC-SHA256-PAYLOAD"
+        );
+        assert_eq!(response.headers["content-encoding"], "aws-chunked");
+        assert_eq!(response.headers["x-amz-decoded-content-length"], "100");
+
+        let response = client
+            .sign_and_dispatch(upload())
+            .with_config(|config| {
+                config.payload_signing(PayloadSigning::Signed);
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.headers["x-amz-content-sha256"], "UNSIGNED-PAYLOAD");
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload(Some(b"body".to_vec()));
+        let response = client
+            .sign_and_dispatch(request)
+            .with_config(|config| {
+                config.payload_signing(PayloadSigning::Unsigned);
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.headers["x-amz-content-sha256"], "UNSIGNED-PAYLOAD");
+    }
+
+    #[tokio::test]
+    async fn checksums() {
+        let mut config = ClientConfig::new();
+        config.set_checksum_mode(ChecksumMode::WhenSupported);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload(Some(b"body".to_vec()));
+        let response = client
+            .sign_and_dispatch(request)
+            .with_config(|config| {
+                config.checksum_algorithm(ChecksumAlgorithm::Sha256);
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            response.headers["x-amz-checksum-sha256"],
+            ChecksumAlgorithm::Sha256.checksum(b"body")
+        );
+
+        // buckets aren't checksummed
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket");
+        request.set_payload(Some(b"configuration".to_vec()));
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert!(!response.headers.contains_key("x-amz-checksum-crc32"));
+
+        // the echoed checksum is that of the empty body
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.add_header("x-amz-checksum-crc32", "AAAAAA==");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.headers["x-amz-checksum-mode"], "ENABLED");
+        assert!(response.buffer().await.is_ok());
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.add_header("x-amz-checksum-crc32", "AAAAAQ==");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+        assert!(response.buffer().await.is_err());
+
+        // checksums aren't validated unless they're asked for
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.add_header("x-amz-checksum-crc32", "AAAAAQ==");
+        let mut response = client
+            .sign_and_dispatch(request)
+            .with_config(|config| {
+                config.checksum_mode(ChecksumMode::WhenRequired);
+            })
+            .await
+            .unwrap();
+        assert!(response.buffer().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn content_md5() {
+        let upload = |body: &[u8]| {
+            let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+            request.set_payload(Some(body.to_vec()));
+            request
+        };
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials.clone(), HeaderEcho);
+        let response = client.sign_and_dispatch(upload(b"body")).await.unwrap();
+        assert!(!response.headers.contains_key("content-md5"));
+
+        let mut config = ClientConfig::new();
+        config.set_compute_content_md5(true);
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        let response = client.sign_and_dispatch(upload(b"body")).await.unwrap();
+        assert_eq!(response.headers["content-md5"], "hBotaJrYa9FhFEdFPCLG/A==");
+
+        // a header set already is kept
+        let mut request = upload(b"body");
+        request.add_header("Content-MD5", "1B2M2Y8AsgTpgAmY7PhCfg==");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.headers["content-md5"], "1B2M2Y8AsgTpgAmY7PhCfg==");
+
+        let response = client
+            .sign_and_dispatch(upload(b"body"))
+            .with_config(|config| {
+                config.compute_content_md5(false);
+            })
+            .await
+            .unwrap();
+        assert!(!response.headers.contains_key("content-md5"));
+    }
+
+    #[tokio::test]
+    async fn requester_pays() {
+        let download = || {
+            let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+            request.set_request_payer(true);
+            request
+        };
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials.clone(), HeaderEcho);
+        let response = client.sign_and_dispatch(download()).await.unwrap();
+        assert!(!response.headers.contains_key("x-amz-request-payer"));
+
+        let response = client
+            .sign_and_dispatch(download())
+            .with_config(|config| {
+                config.requester_pays(true);
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.headers["x-amz-request-payer"], "requester");
+
+        let mut config = ClientConfig::new();
+        config.set_requester_pays(true);
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        let response = client.sign_and_dispatch(download()).await.unwrap();
+        assert_eq!(response.headers["x-amz-request-payer"], "requester");
+        assert!(response.headers["authorization"].contains(";x-amz-request-payer"));
+
+        // operations which don't accept the header are sent without it
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert!(!response.headers.contains_key("x-amz-request-payer"));
+    }
+
+    #[tokio::test]
+    async fn expect_continue() {
+        let request = |method: &str, body: &[u8]| {
+            let mut request = SignedRequest::new(method, "s3", &Region::UsEast1, "/bucket/key");
+            request.set_payload(Some(body.to_vec()));
+            request
+        };
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials.clone(), HeaderEcho);
+        let response = client
+            .sign_and_dispatch(request("PUT", b"large body"))
+            .await
+            .unwrap();
+        assert!(!response.headers.contains_key("expect"));
+
+        let mut config = ClientConfig::new();
+        config.set_s3_expect_continue_threshold(10);
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        let response = client
+            .sign_and_dispatch(request("PUT", b"large body"))
+            .await
+            .unwrap();
+        assert_eq!(response.headers["expect"], "100-continue");
+        assert!(!response.headers["authorization"].contains("expect"));
+
+        let response = client
+            .sign_and_dispatch(request("PUT", b"body"))
+            .await
+            .unwrap();
+        assert!(!response.headers.contains_key("expect"));
+        let response = client
+            .sign_and_dispatch(request("POST", b"large body"))
+            .await
+            .unwrap();
+        assert!(!response.headers.contains_key("expect"));
+    }
+
+    /// Authorizes requests with a bearer token rather than signing them.
+    struct BearerToken;
+
+    impl SignRequest for BearerToken {
+        fn sign_request(&self, request: &mut SignedRequest, credentials: Option<&AwsCredentials>) {
+            assert!(credentials.is_none());
+            request.complement();
+            request.add_header("authorization", "Bearer token");
+        }
+
+        fn uses_credentials(&self) -> bool {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn signers() {
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut config = ClientConfig::new();
+        config.set_signer(BearerToken);
+        let client = Client::new_with_config(credentials.clone(), HeaderEcho, config);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.headers["authorization"], "Bearer token");
+
+        let mut config = ClientConfig::new();
+        config.set_signer(AnonymousSigner);
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert!(!response.headers.contains_key("authorization"));
+        assert!(response.headers.contains_key("host"));
+    }
+
+    /// Records the canonical requests of the requests it sees.
+    #[derive(Clone, Default)]
+    struct CanonicalRequests(Arc<Mutex<Vec<Option<String>>>>);
+
+    impl Interceptor for CanonicalRequests {
+        fn before_transmit(&self, request: &mut SignedRequest) {
+            let canonical_request = request
+                .signing_details()
+                .map(|details| details.canonical_request.clone());
+            self.0.lock().unwrap().push(canonical_request);
+        }
+    }
+
+    #[tokio::test]
+    async fn debug_signing() {
+        let canonical_requests = CanonicalRequests::default();
+        let mut config = ClientConfig::new();
+        config.add_interceptor(canonical_requests.clone());
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials.clone(), HeaderEcho, config.clone());
+        client.sign_and_dispatch(request()).await.unwrap();
+
+        config.set_debug_signing(true);
+        let client = Client::new_with_config(credentials, HeaderEcho, config);
+        client.sign_and_dispatch(request()).await.unwrap();
+
+        let canonical_requests = canonical_requests.0.lock().unwrap();
+        assert_eq!(canonical_requests.len(), 2);
+        assert!(canonical_requests[0].is_none());
+        assert!(canonical_requests[1]
+            .as_ref()
+            .unwrap()
+            .starts_with("GET\n/key\n"));
+    }
+
+    #[tokio::test]
+    async fn unsigned_requests() {
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials.clone(), AuthorizationChecker { signed: true });
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let client = Client::new_with(credentials, AuthorizationChecker { signed: false });
+        let response = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.unsigned(true);
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let client = Client::new_with(
+            AnonymousCredentialsProvider,
+            AuthorizationChecker { signed: false },
+        );
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+    }
+
+    /// Responds with the given statuses and bodies, then with `200 OK`.
+    struct FlakyDispatcher {
+        responses: Mutex<Vec<(StatusCode, &'static str)>>,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl FlakyDispatcher {
+        fn new(statuses: Vec<StatusCode>) -> (FlakyDispatcher, Arc<AtomicUsize>) {
+            FlakyDispatcher::with_bodies(statuses.into_iter().map(|s| (s, "")).collect())
+        }
+
+        fn with_bodies(
+            responses: Vec<(StatusCode, &'static str)>,
+        ) -> (FlakyDispatcher, Arc<AtomicUsize>) {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let dispatcher = FlakyDispatcher {
+                responses: Mutex::new(responses),
+                attempts: attempts.clone(),
+            };
+            (dispatcher, attempts)
+        }
+    }
+
+    impl DispatchSignedRequest for FlakyDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            assert!(request.headers().contains_key("authorization"));
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            let mut responses = self.responses.lock().unwrap();
+            let (status, body) = if responses.is_empty() {
+                (StatusCode::OK, "")
+            } else {
+                responses.remove(0)
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status,
+                body: body.as_bytes().to_vec().into(),
+                headers: HeaderMap::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    fn retrying_client(dispatcher: FlakyDispatcher, retry_policy: RetryPolicy) -> Client {
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(retry_policy);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        Client::new_with_config(credentials, dispatcher, config)
+    }
+
+    fn fast_retries() -> RetryPolicy {
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_millis(1));
+        retry_policy
+    }
+
+    #[tokio::test]
+    async fn retries() {
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![
+            StatusCode::SERVICE_UNAVAILABLE,
+            StatusCode::TOO_MANY_REQUESTS,
+        ]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // the response of the last attempt is returned
+        let (dispatcher, attempts) =
+            FlakyDispatcher::new(vec![StatusCode::INTERNAL_SERVER_ERROR; 5]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn no_retries() {
+        // client errors aren't retried
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![StatusCode::BAD_REQUEST]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // neither are classes of errors the policy doesn't retry
+        let mut retry_policy = fast_retries();
+        retry_policy.set_retry(ErrorClass::Throttling, false);
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![StatusCode::TOO_MANY_REQUESTS]);
+        let client = retrying_client(dispatcher, retry_policy);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // nor requests with a streaming payload
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![StatusCode::SERVICE_UNAVAILABLE]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let mut request = request();
+        request.set_payload_stream(crate::ByteStream::from(b"body".to_vec()));
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn retries_throttling_error_codes() {
+        let (dispatcher, attempts) = FlakyDispatcher::with_bodies(vec![
+            (
+                StatusCode::BAD_REQUEST,
+                r#"{"__type": "com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException"}"#,
+            ),
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "<Error><Code>SlowDown</Code><Message>Reduce your request rate.</Message></Error>",
+            ),
+        ]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // the body of the last error response is still returned
+        let body = r#"{"__type": "ValidationException", "message": "invalid"}"#;
+        let (dispatcher, attempts) =
+            FlakyDispatcher::with_bodies(vec![(StatusCode::BAD_REQUEST, body)]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let mut response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), body);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    fn circuit_breaking_client(dispatcher: FlakyDispatcher) -> Client {
+        let mut circuit_breaker = CircuitBreaker::new();
+        circuit_breaker.set_failure_threshold(2);
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+        config.set_circuit_breaker(circuit_breaker);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        Client::new_with_config(credentials, dispatcher, config)
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker() {
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![StatusCode::SERVICE_UNAVAILABLE; 3]);
+        let client = circuit_breaking_client(dispatcher);
+        // retries stop once the circuit opens
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        let error = client.sign_and_dispatch(request()).await.err().unwrap();
+        assert_eq!(
+            error,
+            SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                "Circuit breaker open for bucket.s3.us-east-1.amazonaws.com".to_owned()
+            ))
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // other endpoints aren't affected
+        let response = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.region(Region::EuWest1);
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_fallback_region() {
+        let dispatcher = RecordingDispatcher {
+            statuses: Mutex::new(vec![StatusCode::SERVICE_UNAVAILABLE; 2]),
+            ..Default::default()
+        };
+        let requests = dispatcher.requests.clone();
+        let mut circuit_breaker = CircuitBreaker::new();
+        circuit_breaker.set_failure_threshold(2);
+        circuit_breaker.set_fallback_region(Region::UsWest2);
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+        config.set_circuit_breaker(circuit_breaker);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[1].0, "bucket.s3.us-east-1.amazonaws.com");
+        assert_eq!(requests[2].0, "bucket.s3.us-west-2.amazonaws.com");
+        assert!(requests[2].1.contains("/us-west-2/s3/"));
+    }
+
+    /// Redirects requests which aren't sent to the region of the bucket.
+    #[derive(Default)]
+    struct BucketRegionDispatcher {
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+        malformed_authorization: bool,
+    }
+
+    impl DispatchSignedRequest for BucketRegionDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let authorization = request
+                .headers()
+                .get("authorization")
+                .map(|value| String::from_utf8(value[0].clone()).unwrap())
+                .unwrap_or_default();
+            let hostname = request.hostname();
+            let mut response = HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers: HeaderMap::default(),
+            };
+            if !hostname.contains(".eu-west-1.") {
+                if self.malformed_authorization {
+                    response.status = StatusCode::BAD_REQUEST;
+                    response.body = "<Error><Code>AuthorizationHeaderMalformed</Code>\
+                                     <Region>eu-west-1</Region></Error>"
+                        .as_bytes()
+                        .to_vec()
+                        .into();
+                } else {
+                    response.status = StatusCode::MOVED_PERMANENTLY;
+                    response
+                        .headers
+                        .insert("x-amz-bucket-region", "eu-west-1".to_owned());
+                }
+            }
+            self.requests
+                .lock()
+                .unwrap()
+                .push((hostname, authorization));
+            futures::future::ready(Ok(response)).boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn region_redirects() {
+        let dispatcher = BucketRegionDispatcher::default();
+        let requests = dispatcher.requests.clone();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, dispatcher);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        // the region of the bucket is remembered
+        client.sign_and_dispatch(request()).await.unwrap();
+        {
+            let requests = requests.lock().unwrap();
+            assert_eq!(requests.len(), 3);
+            assert_eq!(requests[0].0, "bucket.s3.us-east-1.amazonaws.com");
+            assert_eq!(requests[1].0, "bucket.s3.eu-west-1.amazonaws.com");
+            assert!(requests[1].1.contains("/eu-west-1/s3/"));
+            assert_eq!(requests[2].0, "bucket.s3.eu-west-1.amazonaws.com");
+        }
+
+        let dispatcher = BucketRegionDispatcher {
+            malformed_authorization: true,
+            ..Default::default()
+        };
+        let requests = dispatcher.requests.clone();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut config = ClientConfig::new();
+        config.set_s3_cache_bucket_regions(false);
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 4);
+
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut config = ClientConfig::new();
+        config.set_s3_follow_region_redirects(false);
+        let client =
+            Client::new_with_config(credentials, BucketRegionDispatcher::default(), config);
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::MOVED_PERMANENTLY);
+    }
+
+    /// Responds after the given delays, then immediately.
+    struct SlowDispatcher {
+        delays: Mutex<Vec<Duration>>,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl DispatchSignedRequest for SlowDispatcher {
+        fn dispatch(
+            &self,
+            _request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            let mut delays = self.delays.lock().unwrap();
+            let delay = if delays.is_empty() {
+                Duration::from_secs(0)
+            } else {
+                delays.remove(0)
+            };
+            async move {
+                tokio::time::delay_for(delay).await;
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    body: Vec::new().into(),
+                    headers: HeaderMap::default(),
+                })
+            }
+            .boxed()
+        }
+    }
+
+    fn slow_client(delays: Vec<Duration>, config: ClientConfig) -> (Client, Arc<AtomicUsize>) {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let dispatcher = SlowDispatcher {
+            delays: Mutex::new(delays),
+            attempts: attempts.clone(),
+        };
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        (client, attempts)
+    }
+
+    #[tokio::test]
+    async fn hedging() {
+        let hung = Duration::from_secs(60);
+        let mut hedging_policy = HedgingPolicy::new();
+        hedging_policy.set_initial_delay(Duration::from_millis(10));
+        let mut config = ClientConfig::new();
+        config.set_hedging_policy(hedging_policy);
+        config.set_operation_timeout(Duration::from_secs(5));
+        // the hedged attempt responds first
+        let (client, attempts) = slow_client(vec![hung], config.clone());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // requests which don't only read aren't hedged
+        let (client, attempts) = slow_client(vec![Duration::from_millis(50)], config);
+        let request = SignedRequest::new("POST", "lambda", &Region::UsEast1, "/functions/f");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn cancellation() {
+        let hung = Duration::from_secs(60);
+        let (client, attempts) = slow_client(vec![hung], ClientConfig::new());
+        let token = crate::CancellationToken::new();
+        let cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::delay_for(Duration::from_millis(10)).await;
+            cancel.cancel();
+        });
+        let error = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.cancellation_token(token.clone());
+            })
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            error,
+            SignAndDispatchError::Dispatch(HttpDispatchError::new("Request cancelled".to_owned()))
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // requests of a cancelled token aren't sent
+        let error = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.cancellation_token(token);
+            })
+            .await;
+        assert!(error.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    /// Responds with bodies which break after their first chunk, then with complete ones.
+    struct BrokenBodyDispatcher {
+        broken: usize,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl DispatchSignedRequest for BrokenBodyDispatcher {
+        fn dispatch(
+            &self,
+            _request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            let mut chunks = vec![Ok(bytes::Bytes::from_static(b"pay"))];
+            if attempt < self.broken {
+                chunks.push(Err(std::io::Error::new(
+                    std::io::ErrorKind::ConnectionReset,
+                    "reset",
+                )));
+            } else {
+                chunks.push(Ok(bytes::Bytes::from_static(b"load")));
+            }
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::new_with_size(futures::stream::iter(chunks), 7),
+                headers: HeaderMap::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    fn broken_body_client(config: ClientConfig) -> (Client, Arc<AtomicUsize>) {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let dispatcher = BrokenBodyDispatcher {
+            broken: 1,
+            attempts: attempts.clone(),
+        };
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        (client, attempts)
+    }
+
+    #[tokio::test]
+    async fn response_buffering() {
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+
+        // streamed bodies break while the caller reads them
+        let (client, attempts) = broken_body_client(config.clone());
+        let mut response = client.sign_and_dispatch(request()).await.unwrap();
+        assert!(response.buffer().await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // buffered bodies are read, and retried, by the client
+        let mut buffering = config.clone();
+        buffering.set_response_buffering(ResponseBuffering::Buffer);
+        let (client, attempts) = broken_body_client(buffering);
+        let mut response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(&response.buffer().await.unwrap().body[..], b"payload");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+        // bodies longer than the threshold are streamed
+        let mut threshold = config.clone();
+        threshold.set_response_buffering(ResponseBuffering::Threshold(4));
+        let (client, attempts) = broken_body_client(threshold);
+        let mut response = client.sign_and_dispatch(request()).await.unwrap();
+        assert!(response.buffer().await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // the buffering can be set for single requests
+        let (client, attempts) = broken_body_client(config);
+        let mut response = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.response_buffering(ResponseBuffering::Threshold(1024));
+            })
+            .await
+            .unwrap();
+        assert_eq!(&response.buffer().await.unwrap().body[..], b"payload");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn attempt_timeout() {
+        let hung = Duration::from_secs(60);
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+        config.set_attempt_timeout(Duration::from_millis(10));
+        let (client, attempts) = slow_client(vec![hung, hung], config.clone());
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let (client, attempts) = slow_client(vec![hung; 3], config);
+        let error = client.sign_and_dispatch(request()).await.err().unwrap();
+        assert_eq!(
+            error,
+            SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                "Attempt timed out after 10ms".to_owned()
+            ))
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    /// Records the delays it is asked for and completes immediately.
+    #[derive(Clone, Default)]
+    struct RecordingRuntime {
+        sleeps: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl Runtime for RecordingRuntime {
+        fn sleep(&self, duration: Duration) -> crate::Sleep {
+            self.sleeps.lock().unwrap().push(duration);
+            Box::pin(async {})
+        }
+    }
+
+    #[tokio::test]
+    async fn runtime() {
+        let runtime = RecordingRuntime::default();
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_secs(1));
+        retry_policy.set_jitter(false);
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(retry_policy);
+        config.set_runtime(runtime.clone());
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![StatusCode::SERVICE_UNAVAILABLE]);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        // the retry is delayed by the runtime rather than tokio
+        let response = client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            *runtime.sleeps.lock().unwrap(),
+            vec![Duration::from_secs(1)]
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limit() {
+        let runtime = RecordingRuntime::default();
+        let mut rate_limit = RateLimit::new(1.0);
+        rate_limit.set_burst(2);
+        let mut config = ClientConfig::new();
+        config.set_rate_limit(rate_limit);
+        config.set_runtime(runtime.clone());
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![]);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        for _ in 0..3 {
+            client.sign_and_dispatch(request()).await.unwrap();
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        // the request after the burst waits for its turn
+        let sleeps = runtime.sleeps.lock().unwrap();
+        assert_eq!(sleeps.len(), 1);
+        assert!(sleeps[0] > Duration::from_millis(900));
+    }
+
+    #[tokio::test]
+    async fn dispatch_errors_of_non_idempotent_requests() {
+        let hung = Duration::from_secs(60);
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+        config.set_attempt_timeout(Duration::from_millis(10));
+        let post = || SignedRequest::new("POST", "lambda", &Region::UsEast1, "/functions/f");
+
+        // the service may have handled the request before it timed out
+        let (client, attempts) = slow_client(vec![hung, hung], config.clone());
+        assert!(client.sign_and_dispatch(post()).await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        let (client, attempts) = slow_client(vec![hung, hung], config);
+        let response = client
+            .sign_and_dispatch(post())
+            .with_config(|config| {
+                config.idempotent(true);
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn operation_timeout() {
+        let hung = Duration::from_secs(60);
+        let mut config = ClientConfig::new();
+        config.set_operation_timeout(Duration::from_millis(10));
+        let (client, attempts) = slow_client(vec![hung], config);
+        let error = client.sign_and_dispatch(request()).await.err().unwrap();
+        assert_eq!(
+            error,
+            SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                "Operation timed out after 10ms".to_owned()
+            ))
+        );
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // the timeouts can be overridden per request
+        let (client, attempts) = slow_client(vec![hung], ClientConfig::new());
+        let response = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.attempt_timeout(Duration::from_millis(10));
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    /// Records the hostname and authorization header of the requests it sends. Responds with
+    /// the given statuses, then with `200 OK`.
+    #[derive(Default)]
+    struct RecordingDispatcher {
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+        statuses: Mutex<Vec<StatusCode>>,
+    }
+
+    impl DispatchSignedRequest for RecordingDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let authorization = request
+                .headers()
+                .get("authorization")
+                .map(|value| String::from_utf8(value[0].clone()).unwrap())
+                .unwrap_or_default();
+            self.requests
+                .lock()
+                .unwrap()
+                .push((request.hostname(), authorization));
+            let mut statuses = self.statuses.lock().unwrap();
+            let status = if statuses.is_empty() {
+                StatusCode::OK
+            } else {
+                statuses.remove(0)
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status,
+                body: Vec::new().into(),
+                headers: HeaderMap::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn request_overrides() {
+        let dispatcher = RecordingDispatcher::default();
+        let requests = dispatcher.requests.clone();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, dispatcher);
+
+        client.sign_and_dispatch(request()).await.unwrap();
+        client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config
+                    .region(Region::EuWest1)
+                    .credentials(StaticProvider::new_minimal(
+                        "other".to_owned(),
+                        "secret".to_owned(),
+                    ));
+            })
+            .await
+            .unwrap();
+        client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.endpoint("http://localhost:9000");
+            })
+            .await
+            .unwrap();
+        client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.addressing_style(AddressingStyle::Path);
+            })
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].0, "bucket.s3.us-east-1.amazonaws.com");
+        assert!(requests[0].1.contains("Credential=key/"));
+        assert!(requests[0].1.contains("/us-east-1/s3/"));
+        assert_eq!(requests[1].0, "bucket.s3.eu-west-1.amazonaws.com");
+        assert!(requests[1].1.contains("Credential=other/"));
+        assert!(requests[1].1.contains("/eu-west-1/s3/"));
+        assert_eq!(requests[2].0, "localhost:9000");
+        assert!(requests[2].1.contains("/us-east-1/s3/"));
+        assert_eq!(requests[3].0, "s3.us-east-1.amazonaws.com");
+    }
+
+    /// Gets its credentials with a request of its own, like the providers assuming roles.
+    struct NestedRequestProvider {
+        client: Client,
+    }
+
+    #[async_trait]
+    impl ProvideAwsCredentials for NestedRequestProvider {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            let request = SignedRequest::new("POST", "sts", &Region::UsEast1, "/");
+            self.client
+                .sign_and_dispatch(request)
+                .await
+                .map_err(CredentialsError::new)?;
+            Ok(AwsCredentials::new("key", "secret", None, None))
+        }
+    }
+
+    #[tokio::test]
+    async fn request_overrides_dont_apply_to_credentials_providers() {
+        let dispatcher = RecordingDispatcher::default();
+        let requests = dispatcher.requests.clone();
+        let sts = Client::new_with(
+            StaticProvider::new_minimal("sts".to_owned(), "secret".to_owned()),
+            RecordingDispatcher {
+                requests: requests.clone(),
+                ..RecordingDispatcher::default()
+            },
+        );
+        let client = Client::new_with(NestedRequestProvider { client: sts }, dispatcher);
+
+        client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.endpoint("http://localhost:9000");
+            })
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        // the request of the provider is sent to its own endpoint
+        assert_eq!(requests[0].0, "sts.us-east-1.amazonaws.com");
+        assert!(requests[0].1.contains("Credential=sts/"));
+        assert_eq!(requests[1].0, "localhost:9000");
+        assert!(requests[1].1.contains("Credential=key/"));
+    }
+
+    #[tokio::test]
+    async fn presigning_sends_the_requests_of_credentials_providers() {
+        let dispatcher = RecordingDispatcher::default();
+        let requests = dispatcher.requests.clone();
+        let sts = Client::new_with(
+            StaticProvider::new_minimal("sts".to_owned(), "secret".to_owned()),
+            dispatcher,
+        );
+        let client = Client::new_with(
+            NestedRequestProvider { client: sts },
+            RecordingDispatcher::default(),
+        );
+
+        let url = crate::presign(client.sign_and_dispatch(request()), Duration::from_secs(60))
+            .await
+            .unwrap();
+        // the URL is the one of the outer request, presigned with the provider's credentials
+        assert!(url.starts_with("https://bucket.s3.us-east-1.amazonaws.com/"));
+        assert!(url.contains("X-Amz-Credential=key%2F"));
+        assert!(url.contains("%2Fs3%2Faws4_request"));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].0, "sts.us-east-1.amazonaws.com");
+    }
+
+    #[tokio::test]
+    async fn endpoint_resolver() {
+        struct LocalSqs;
+
+        impl EndpointResolver for LocalSqs {
+            fn resolve_endpoint(&self, service: &str, region: &Region) -> Endpoint {
+                match service {
+                    "sqs" => Endpoint::new("http://localhost:9324"),
+                    _ => DefaultEndpointResolver.resolve_endpoint(service, region),
+                }
+            }
+        }
+
+        let dispatcher = RecordingDispatcher::default();
+        let requests = dispatcher.requests.clone();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let mut config = ClientConfig::new();
+        config.set_endpoint_resolver(LocalSqs);
+        let client = Client::new_with_config(credentials, dispatcher, config);
+
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &Region::EuWest1, "/"))
+            .await
+            .unwrap();
+        client.sign_and_dispatch(request()).await.unwrap();
+        let mut explicit = request();
+        explicit.set_hostname(Some("bucket.example.com".to_owned()));
+        client.sign_and_dispatch(explicit).await.unwrap();
+        client
+            .sign_and_dispatch(SignedRequest::new("POST", "sqs", &Region::EuWest1, "/"))
+            .with_config(|config| {
+                config.endpoint("http://localhost:9000");
+            })
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].0, "localhost:9324");
+        // requests are still signed for their region
+        assert!(requests[0].1.contains("/eu-west-1/sqs/"));
+        assert_eq!(requests[1].0, "bucket.s3.us-east-1.amazonaws.com");
+        assert_eq!(requests[2].0, "bucket.example.com");
+        assert_eq!(requests[3].0, "localhost:9000");
+    }
+
+    #[tokio::test]
+    async fn retry_policy_override() {
+        let (dispatcher, attempts) = FlakyDispatcher::new(vec![StatusCode::SERVICE_UNAVAILABLE]);
+        let client = retrying_client(dispatcher, fast_retries());
+        let response = client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.retry_policy(RetryPolicy::no_retries());
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    /// Records the hooks it's called with, and tags requests.
+    #[derive(Clone, Default)]
+    struct LoggingInterceptor {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Interceptor for LoggingInterceptor {
+        fn before_execution(&self, _request: &mut SignedRequest) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push("before_execution".to_owned());
+        }
+
+        fn before_signing(&self, request: &mut SignedRequest) {
+            self.calls.lock().unwrap().push("before_signing".to_owned());
+            request.add_header("x-signed-tag", "tag");
+        }
+
+        fn before_transmit(&self, request: &mut SignedRequest) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push("before_transmit".to_owned());
+            request.add_header("x-unsigned-tag", "tag");
+        }
+
+        fn after_response(&self, response: &mut HttpResponse) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("after_response {}", response.status.as_u16()));
+        }
+    }
+
+    #[tokio::test]
+    async fn interceptors() {
+        let interceptor = LoggingInterceptor::default();
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+        config.add_interceptor(interceptor.clone());
+        let (dispatcher, _) = FlakyDispatcher::new(vec![StatusCode::SERVICE_UNAVAILABLE]);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials.clone(), dispatcher, config.clone());
+        client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(
+            *interceptor.calls.lock().unwrap(),
+            vec![
+                "before_execution",
+                "before_signing",
+                "before_transmit",
+                "after_response 503",
+                "before_signing",
+                "before_transmit",
+                "after_response 200",
+            ]
+        );
+
+        // headers added before signing are signed
+        let dispatcher = RecordingDispatcher::default();
+        let requests = dispatcher.requests.clone();
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        client.sign_and_dispatch(request()).await.unwrap();
+        let authorization = &requests.lock().unwrap()[0].1;
+        assert!(authorization.contains("x-signed-tag"));
+        assert!(!authorization.contains("x-unsigned-tag"));
+    }
+
+    #[tokio::test]
+    async fn request_interceptors() {
+        let interceptor = LoggingInterceptor::default();
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, RecordingDispatcher::default());
+        let request_interceptor = interceptor.clone();
+        client
+            .sign_and_dispatch(request())
+            .with_config(|config| {
+                config.add_interceptor(request_interceptor);
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            *interceptor.calls.lock().unwrap(),
+            vec![
+                "before_execution",
+                "before_signing",
+                "before_transmit",
+                "after_response 200",
+            ]
+        );
+
+        // they only hook into the requests they were added for
+        client.sign_and_dispatch(request()).await.unwrap();
+        assert_eq!(interceptor.calls.lock().unwrap().len(), 4);
+    }
+
+    #[derive(Clone, Default)]
+    struct CollectingObserver {
+        attempts: Arc<Mutex<Vec<AttemptMetrics>>>,
+    }
+
+    impl ClientMetricsObserver for CollectingObserver {
+        fn on_attempt(&self, metrics: &AttemptMetrics) {
+            self.attempts.lock().unwrap().push(metrics.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn metrics_observer() {
+        let observer = CollectingObserver::default();
+        let mut config = ClientConfig::new();
+        config.set_retry_policy(fast_retries());
+        config.set_metrics_observer(observer.clone());
+        let (dispatcher, _) =
+            FlakyDispatcher::with_bodies(vec![(StatusCode::SERVICE_UNAVAILABLE, "unavailable")]);
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        let client = Client::new_with_config(credentials, dispatcher, config);
+        let mut request = request();
+        request.set_operation_name("GetObject");
+        request.set_payload(Some(b"payload".to_vec()));
+        client.sign_and_dispatch(request).await.unwrap();
+
+        let attempts = observer.attempts.lock().unwrap();
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0].service(), "s3");
+        assert_eq!(attempts[0].operation(), Some("GetObject"));
+        assert_eq!(attempts[0].region(), "us-east-1");
+        assert_eq!(attempts[0].retries(), 0);
+        assert_eq!(attempts[0].bytes_sent(), Some(7));
+        assert_eq!(attempts[0].bytes_received(), Some(11));
+        assert_eq!(attempts[0].status(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(attempts[0].error_class(), Some(ErrorClass::Transient));
+        assert_eq!(attempts[1].retries(), 1);
+        assert_eq!(attempts[1].status(), Some(StatusCode::OK));
+        assert_eq!(attempts[1].error_class(), None);
+    }
 }
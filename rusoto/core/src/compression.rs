@@ -0,0 +1,258 @@
+//! Compressing the payloads of requests to operations which accept compressed payloads, like
+//! CloudWatch's `PutMetricData`, and decompressing the bodies of compressed responses.
+
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use flate2::write::{GzDecoder, GzEncoder};
+use flate2::Compression;
+use futures::Stream;
+
+use crate::request::HttpResponse;
+use crate::signature::{SignedRequest, SignedRequestPayload};
+use crate::stream::ByteStream;
+
+/// Services whose responses carry objects as they were stored, including their encoding.
+const RAW_RESPONSE_SERVICES: &[&str] = &["s3", "glacier"];
+
+/// The size from which payloads are compressed by default, in bytes.
+pub(crate) const DEFAULT_MIN_COMPRESSION_SIZE: u32 = 10_240;
+/// The largest size from which payloads may be compressed, in bytes.
+pub(crate) const MAX_MIN_COMPRESSION_SIZE: u32 = 10_485_760;
+
+/// Compresses the payload of the request with gzip if the operation accepts it and the payload
+/// is at least `min_size` bytes. Streaming payloads aren't compressed.
+pub(crate) fn compress_request(request: &mut SignedRequest, min_size: u32) {
+    if !request.request_compression {
+        return;
+    }
+    let encodings = request
+        .headers()
+        .get("content-encoding")
+        .map(|values| String::from_utf8_lossy(&values.join(&b", "[..])).into_owned());
+    if let Some(ref encodings) = encodings {
+        if encodings
+            .split(',')
+            .any(|encoding| encoding.trim() == "gzip")
+        {
+            return;
+        }
+    }
+    let compressed = match request.payload {
+        Some(SignedRequestPayload::Buffer(ref payload)) if payload.len() >= min_size as usize => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            match encoder.write_all(payload).and_then(|_| encoder.finish()) {
+                Ok(compressed) => compressed,
+                Err(_) => return,
+            }
+        }
+        _ => return,
+    };
+    request.payload = Some(SignedRequestPayload::Buffer(Bytes::from(compressed)));
+    // gzip is applied last
+    request.remove_header("content-encoding");
+    match encodings {
+        Some(encodings) => request.add_header("content-encoding", &format!("{}, gzip", encodings)),
+        None => request.add_header("content-encoding", "gzip"),
+    }
+}
+
+/// Reads the size from which payloads are compressed from the
+/// `AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES` environment variable.
+pub(crate) fn min_compression_size_from_env() -> u32 {
+    std::env::var("AWS_REQUEST_MIN_COMPRESSION_SIZE_BYTES")
+        .ok()
+        .and_then(|size| size.trim().parse().ok())
+        .filter(|size| *size <= MAX_MIN_COMPRESSION_SIZE)
+        .unwrap_or(DEFAULT_MIN_COMPRESSION_SIZE)
+}
+
+/// Whether the responses to the request may be compressed, if the service supports it.
+pub(crate) fn accepts_compressed_response(request: &SignedRequest) -> bool {
+    !RAW_RESPONSE_SERVICES.contains(&request.service.as_str())
+        && !request.headers().contains_key("accept-encoding")
+}
+
+/// Decompresses the body of a gzip compressed response, removing the headers describing the
+/// compressed body.
+pub(crate) fn decompress_response(mut response: HttpResponse) -> HttpResponse {
+    let gzip = response
+        .headers
+        .get("content-encoding")
+        .filter(|encoding| encoding.trim().eq_ignore_ascii_case("gzip"))
+        .is_some();
+    if !gzip {
+        return response;
+    }
+    response.headers.remove("content-encoding");
+    response.headers.remove("content-length");
+    let body = std::mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+    response.body = ByteStream::new(GzipDecoder {
+        inner: body,
+        decoder: Some(GzDecoder::new(Vec::new())),
+    });
+    response
+}
+
+/// Decompresses a gzip compressed stream.
+struct GzipDecoder {
+    inner: ByteStream,
+    /// `None` once the stream ended.
+    decoder: Option<GzDecoder<Vec<u8>>>,
+}
+
+impl Stream for GzipDecoder {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let decoder = match this.decoder {
+                Some(ref mut decoder) => decoder,
+                None => return Poll::Ready(None),
+            };
+            if !decoder.get_ref().is_empty() {
+                let decompressed = std::mem::take(decoder.get_mut());
+                return Poll::Ready(Some(Ok(Bytes::from(decompressed))));
+            }
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    if let Err(e) = decoder.write_all(&chunk) {
+                        this.decoder = None;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => {
+                    // fails if the stream was truncated
+                    let decompressed = this.decoder.take().unwrap().finish();
+                    return Poll::Ready(match decompressed {
+                        Ok(ref decompressed) if decompressed.is_empty() => None,
+                        Ok(decompressed) => Some(Ok(Bytes::from(decompressed))),
+                        Err(e) => Some(Err(e)),
+                    });
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Region;
+
+    use flate2::read::GzDecoder as GzReader;
+    use http::{HeaderMap, StatusCode};
+    use std::io::Read;
+
+    fn compressible_request(payload: &[u8]) -> SignedRequest {
+        let mut request = SignedRequest::new("POST", "monitoring", &Region::UsEast1, "/");
+        request.set_payload(Some(payload.to_vec()));
+        request.set_request_compression(true);
+        request
+    }
+
+    fn payload(request: &SignedRequest) -> Vec<u8> {
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref payload)) => payload.to_vec(),
+            _ => panic!("no payload"),
+        }
+    }
+
+    #[test]
+    fn compresses_large_payloads() {
+        let body = vec![b'a'; 2048];
+        let mut request = compressible_request(&body);
+        compress_request(&mut request, 1024);
+        assert_eq!(
+            request.headers()["content-encoding"],
+            vec![b"gzip".to_vec()]
+        );
+        let mut decompressed = Vec::new();
+        GzReader::new(&payload(&request)[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, body);
+
+        // payloads aren't compressed twice
+        let compressed = payload(&request);
+        compress_request(&mut request, 0);
+        assert_eq!(payload(&request), compressed);
+    }
+
+    #[test]
+    fn keeps_small_payloads() {
+        let mut request = compressible_request(b"Action=PutMetricData");
+        compress_request(&mut request, 1024);
+        assert_eq!(payload(&request), b"Action=PutMetricData".to_vec());
+        assert!(!request.headers().contains_key("content-encoding"));
+
+        // nor payloads of operations which don't accept compressed ones
+        let mut request = compressible_request(&[b'a'; 2048]);
+        request.set_request_compression(false);
+        compress_request(&mut request, 1024);
+        assert_eq!(payload(&request), vec![b'a'; 2048]);
+    }
+
+    #[test]
+    fn appends_gzip_to_other_encodings() {
+        let mut request = compressible_request(&[b'a'; 2048]);
+        request.add_header("Content-Encoding", "custom");
+        compress_request(&mut request, 0);
+        assert_eq!(
+            request.headers()["content-encoding"],
+            vec![b"custom, gzip".to_vec()]
+        );
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn encoded_response(encoding: &str, chunks: Vec<Vec<u8>>) -> HttpResponse {
+        let mut headers = HeaderMap::<String>::default();
+        headers.insert("content-encoding", encoding.to_owned());
+        headers.insert("content-length", "100".to_owned());
+        let chunks = chunks.into_iter().map(|chunk| Ok(Bytes::from(chunk)));
+        HttpResponse {
+            status: StatusCode::OK,
+            body: ByteStream::new(futures::stream::iter(chunks)),
+            headers,
+        }
+    }
+
+    #[tokio::test]
+    async fn decompresses_gzip_responses() {
+        let body = br#"{"Reservations": []}"#.repeat(100);
+        let compressed = gzip(&body);
+        let chunks = compressed.chunks(7).map(<[u8]>::to_vec).collect();
+        let mut response = decompress_response(encoded_response("gzip", chunks));
+        assert!(!response.headers.contains_key("content-encoding"));
+        assert!(!response.headers.contains_key("content-length"));
+        assert_eq!(response.buffer().await.unwrap().body, body);
+
+        // other encodings are kept
+        let mut response = decompress_response(encoded_response("br", vec![b"abc".to_vec()]));
+        assert_eq!(response.headers["content-encoding"], "br");
+        assert_eq!(&response.buffer().await.unwrap().body[..], b"abc");
+
+        // truncated bodies fail
+        let truncated = compressed[..compressed.len() / 2].to_vec();
+        let response = decompress_response(encoded_response("gzip", vec![truncated]));
+        assert!(response.body.collect().await.is_err());
+    }
+
+    #[test]
+    fn compressed_responses_are_accepted() {
+        let request = SignedRequest::new("POST", "ec2", &Region::UsEast1, "/");
+        assert!(accepts_compressed_response(&request));
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(!accepts_compressed_response(&request));
+    }
+}
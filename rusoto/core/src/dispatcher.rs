@@ -0,0 +1,314 @@
+//! Sending requests with HTTP clients other than the hyper based `HttpClient`.
+
+use std::time::Duration;
+
+use futures::future;
+use http::{HeaderMap, Method, Uri};
+
+use crate::request::{self, DispatchSignedRequest, DispatchSignedRequestFuture};
+use crate::signature::{SignedRequest, SignedRequestPayload};
+
+/// An HTTP request, translated from a signed request by a `Dispatcher`.
+#[derive(Debug)]
+pub struct HttpRequest {
+    /// The method of the request.
+    pub method: Method,
+    /// The URI of the request, including its query string.
+    pub uri: Uri,
+    /// The headers of the request, including its signature and `User-Agent`.
+    pub headers: HeaderMap,
+    /// The payload of the request, if it has one.
+    pub body: Option<SignedRequestPayload>,
+}
+
+/// An HTTP client requests are sent with, see `Dispatcher`.
+///
+/// Implementations are provided for `HttpClient`, for `reqwest::Client` with the `reqwest`
+/// feature and for `isahc::HttpClient` with the `isahc` feature. Implementing it for another
+/// HTTP client only takes sending an `HttpRequest` and reading the status, headers and body of
+/// the response; requests are signed, and retried, before they reach it.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_core::credential::DefaultCredentialsProvider;
+/// use rusoto_core::request::{DispatchSignedRequestFuture, HttpDispatchError};
+/// use rusoto_core::{Client, Dispatcher, HttpDispatcher, HttpRequest};
+///
+/// struct EmbeddedHttpClient;
+///
+/// impl HttpDispatcher for EmbeddedHttpClient {
+///     fn send(&self, request: HttpRequest, timeout: Option<Duration>) -> DispatchSignedRequestFuture {
+///         // e.g. send the request with the HTTP stack of the platform
+///         # let _ = (request, timeout);
+///         Box::pin(async { Err(HttpDispatchError::new("not connected".to_owned())) })
+///     }
+/// }
+///
+/// let client = Client::new_with(
+///     DefaultCredentialsProvider::new().unwrap(),
+///     Dispatcher::new(EmbeddedHttpClient),
+/// );
+/// ```
+pub trait HttpDispatcher {
+    /// Sends the request and returns its response, failing once the timeout passed if one is
+    /// given.
+    fn send(&self, request: HttpRequest, timeout: Option<Duration>) -> DispatchSignedRequestFuture;
+}
+
+/// Dispatches signed requests with an `HttpDispatcher`.
+///
+/// Requests which don't set a `User-Agent` are sent with the default one of rusoto. Responses
+/// are passed on as they are, so the HTTP client decompresses them if it asks for compressed
+/// responses.
+#[derive(Clone, Debug)]
+pub struct Dispatcher<H> {
+    http: H,
+}
+
+impl<H> Dispatcher<H> {
+    /// Create a `Dispatcher` sending requests with an HTTP client.
+    pub fn new(http: H) -> Dispatcher<H> {
+        Dispatcher { http }
+    }
+}
+
+impl<H: HttpDispatcher> DispatchSignedRequest for Dispatcher<H> {
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let user_agent = request::user_agent(None, &request);
+        match request::http_request(request, user_agent) {
+            Ok(request) => self.http.send(request, timeout),
+            Err(err) => Box::pin(future::ready(Err(err))),
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+mod reqwest_dispatcher {
+    use std::time::Duration;
+
+    use futures::{FutureExt, TryStreamExt};
+    use http::HeaderMap;
+
+    use super::{HttpDispatcher, HttpRequest};
+    use crate::request::{self, DispatchSignedRequestFuture, HttpDispatchError, HttpResponse};
+    use crate::signature::SignedRequestPayload;
+
+    /// Sends requests with reqwest.
+    impl HttpDispatcher for reqwest::Client {
+        fn send(
+            &self,
+            request: HttpRequest,
+            timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let url = request.uri.to_string();
+            let mut builder = self.request(request.method, &url).headers(request.headers);
+            if let Some(timeout) = timeout {
+                builder = builder.timeout(timeout);
+            }
+            builder = match request.body {
+                Some(SignedRequestPayload::Buffer(buffer)) => builder.body(buffer),
+                Some(SignedRequestPayload::Stream(stream)) => {
+                    builder.body(reqwest::Body::wrap_stream(stream))
+                }
+                None => builder,
+            };
+            async move {
+                let response = builder.send().await.map_err(dispatch_error)?;
+                let status = response.status();
+                let headers = response
+                    .headers()
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.clone(), value.to_str().ok()?.to_owned()))
+                    })
+                    .collect::<HeaderMap<String>>();
+                let body = response.bytes_stream().map_err(|err| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("Error obtaining chunk: {}", err),
+                    )
+                });
+                Ok(HttpResponse {
+                    status,
+                    body: request::with_content_length(body, &headers),
+                    headers,
+                })
+            }
+            .boxed()
+        }
+    }
+
+    fn dispatch_error(err: reqwest::Error) -> HttpDispatchError {
+        if err.is_timeout() {
+            HttpDispatchError::new("Timeout while dispatching request".to_owned())
+        } else if err.is_connect() {
+            HttpDispatchError::new_connect(format!("Error during dispatch: {}", err))
+        } else {
+            HttpDispatchError::new(format!("Error during dispatch: {}", err))
+        }
+    }
+}
+
+#[cfg(feature = "isahc")]
+mod isahc_dispatcher {
+    use std::io;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use futures::io::AsyncReadExt;
+    use futures::{stream, FutureExt, TryStreamExt};
+    use http::HeaderMap;
+    use isahc::config::Configurable;
+
+    use super::{HttpDispatcher, HttpRequest};
+    use crate::request::{self, DispatchSignedRequestFuture, HttpDispatchError, HttpResponse};
+    use crate::signature::SignedRequestPayload;
+
+    /// The size of the chunks response bodies are read in.
+    const CHUNK_SIZE: usize = 8 * 1024;
+
+    /// Sends requests with isahc.
+    impl HttpDispatcher for isahc::HttpClient {
+        fn send(
+            &self,
+            request: HttpRequest,
+            timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let client = self.clone();
+            async move {
+                let content_length = request
+                    .headers
+                    .get("content-length")
+                    .and_then(|value| value.to_str().ok()?.parse().ok());
+                let body = match request.body {
+                    Some(SignedRequestPayload::Buffer(buffer)) => isahc::Body::from_bytes(buffer),
+                    Some(SignedRequestPayload::Stream(stream)) => {
+                        // the inherent method returns a tokio reader, isahc reads futures ones
+                        let read = TryStreamExt::into_async_read(stream);
+                        match content_length {
+                            Some(length) => isahc::Body::from_reader_sized(read, length),
+                            None => isahc::Body::from_reader(read),
+                        }
+                    }
+                    None => isahc::Body::empty(),
+                };
+                let mut builder = http::Request::builder()
+                    .method(request.method)
+                    .uri(request.uri);
+                if let Some(timeout) = timeout {
+                    builder = builder.timeout(timeout);
+                }
+                let mut isahc_request = builder.body(body).map_err(|err| {
+                    HttpDispatchError::new(format!("error building request: {}", err))
+                })?;
+                *isahc_request.headers_mut() = request.headers;
+
+                let response = client
+                    .send_async(isahc_request)
+                    .await
+                    .map_err(dispatch_error)?;
+                let status = response.status();
+                let headers = response
+                    .headers()
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        Some((name.clone(), value.to_str().ok()?.to_owned()))
+                    })
+                    .collect::<HeaderMap<String>>();
+                let body = stream::try_unfold(response.into_body(), |mut body| async move {
+                    let mut chunk = vec![0; CHUNK_SIZE];
+                    let read = body.read(&mut chunk).await?;
+                    if read == 0 {
+                        return Ok::<_, io::Error>(None);
+                    }
+                    chunk.truncate(read);
+                    Ok(Some((Bytes::from(chunk), body)))
+                });
+                Ok(HttpResponse {
+                    status,
+                    body: request::with_content_length(body, &headers),
+                    headers,
+                })
+            }
+            .boxed()
+        }
+    }
+
+    fn dispatch_error(err: isahc::Error) -> HttpDispatchError {
+        match err {
+            isahc::Error::Timeout => {
+                HttpDispatchError::new("Timeout while dispatching request".to_owned())
+            }
+            isahc::Error::ConnectFailed | isahc::Error::CouldntResolveHost => {
+                HttpDispatchError::new_connect(format!("Error during dispatch: {}", err))
+            }
+            err => HttpDispatchError::new(format!("Error during dispatch: {}", err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::HttpResponse;
+    use crate::Region;
+    use http::StatusCode;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct RecordingHttpClient {
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl HttpDispatcher for RecordingHttpClient {
+        fn send(
+            &self,
+            request: HttpRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            self.requests.lock().unwrap().push(request);
+            Box::pin(future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: Vec::new().into(),
+                headers: Default::default(),
+            })))
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatcher() {
+        let http = RecordingHttpClient::default();
+        let requests = http.requests.clone();
+        let dispatcher = Dispatcher::new(http);
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/queue");
+        request.add_header("x-amz-test", "value");
+        request.set_payload(Some(b"payload".to_vec()));
+        let response = dispatcher.dispatch(request, None).await.ok().unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let request = requests.lock().unwrap().pop().unwrap();
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.uri, "https://sqs.us-east-1.amazonaws.com/queue");
+        assert_eq!(request.headers["x-amz-test"], "value");
+        assert!(request.headers["user-agent"]
+            .to_str()
+            .unwrap()
+            .starts_with("rusoto/"));
+        match request.body {
+            Some(SignedRequestPayload::Buffer(body)) => assert_eq!(&body[..], b"payload"),
+            body => panic!("unexpected body: {:?}", body),
+        }
+
+        let request = SignedRequest::new("PATCH", "sqs", &Region::UsEast1, "/");
+        let error = dispatcher.dispatch(request, None).await.err().unwrap();
+        assert_eq!(error.to_string(), "Unsupported HTTP verb PATCH");
+    }
+}
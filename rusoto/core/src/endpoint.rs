@@ -0,0 +1,696 @@
+//! Resolving the endpoints requests are sent to.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::access_point;
+use crate::credential::{ProfileEndpointUrls, ProfileProvider};
+use crate::region::Region;
+use crate::signature::{self, SignedRequest};
+
+/// The endpoint requests are sent to, see `EndpointResolver`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    scheme: String,
+    hostname: String,
+}
+
+impl Endpoint {
+    /// Create a new `Endpoint` from its URL, like `http://localhost:4566` or
+    /// `https://vpce-0123-abcd.sqs.us-east-1.vpce.amazonaws.com`. URLs without a scheme are
+    /// HTTPS endpoints, paths are ignored.
+    pub fn new(url: &str) -> Endpoint {
+        let (scheme, rest) = match url.find("://") {
+            Some(index) => (&url[..index], &url[index + 3..]),
+            None => ("https", url),
+        };
+        let hostname = rest.split('/').next().unwrap_or_default();
+        Endpoint {
+            scheme: scheme.to_lowercase(),
+            hostname: hostname.to_owned(),
+        }
+    }
+
+    /// The scheme of the endpoint, `http` or `https`.
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// The host name of the endpoint, including its port if it isn't the default one.
+    pub fn hostname(&self) -> &str {
+        &self.hostname
+    }
+}
+
+/// Decides which endpoint the requests of a client are sent to, see
+/// `ClientConfig::set_endpoint_resolver`.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_core::{DefaultEndpointResolver, Endpoint, EndpointResolver, Region};
+///
+/// /// Sends requests to SQS to a VPC endpoint.
+/// struct VpcEndpoints;
+///
+/// impl EndpointResolver for VpcEndpoints {
+///     fn resolve_endpoint(&self, service: &str, region: &Region) -> Endpoint {
+///         match service {
+///             "sqs" => Endpoint::new(&format!(
+///                 "https://vpce-0123-abcd.sqs.{}.vpce.amazonaws.com",
+///                 region.name()
+///             )),
+///             _ => DefaultEndpointResolver.resolve_endpoint(service, region),
+///         }
+///     }
+/// }
+/// ```
+pub trait EndpointResolver: Send + Sync {
+    /// Returns the endpoint of a service in a region. The service is the endpoint prefix of
+    /// the service, which usually is its signing name, like `sqs` or `runtime.sagemaker`.
+    ///
+    /// Requests are still signed for the region, even if the endpoint is somewhere else.
+    fn resolve_endpoint(&self, service: &str, region: &Region) -> Endpoint;
+}
+
+/// Resolves endpoints like the service crates do, building the host names of AWS endpoints
+/// from the service and region, and using the endpoints of `Region::Custom` as is.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultEndpointResolver;
+
+impl EndpointResolver for DefaultEndpointResolver {
+    fn resolve_endpoint(&self, service: &str, region: &Region) -> Endpoint {
+        let scheme = match *region {
+            Region::Custom { ref endpoint, .. } if endpoint.starts_with("http://") => "http",
+            _ => "https",
+        };
+        Endpoint {
+            scheme: scheme.to_owned(),
+            hostname: signature::build_hostname(service, region),
+        }
+    }
+}
+
+/// The endpoint resolver of a client.
+#[derive(Clone)]
+pub(crate) struct Resolver(pub(crate) Arc<dyn EndpointResolver>);
+
+impl Resolver {
+    /// Sends the request to the endpoint of its service and region, unless it was sent to a
+    /// host name explicitly.
+    pub(crate) fn resolve(&self, request: &mut SignedRequest) {
+        if request.hostname.is_some() && request.endpoint_prefix.is_none() {
+            return;
+        }
+        let service = request
+            .endpoint_prefix
+            .clone()
+            .unwrap_or_else(|| request.service.clone());
+        let endpoint = self.0.resolve_endpoint(&service, &request.region);
+        request.scheme = Some(endpoint.scheme);
+        request.hostname = Some(endpoint.hostname);
+    }
+}
+
+impl fmt::Debug for Resolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EndpointResolver")
+    }
+}
+
+/// The endpoint URLs configured with the `AWS_ENDPOINT_URL` and `AWS_ENDPOINT_URL_<SERVICE>`
+/// environment variables and the `endpoint_url` settings of the profile.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ConfiguredEndpoints {
+    env_services: HashMap<String, String>,
+    env_global: Option<String>,
+    profile: ProfileEndpointUrls,
+}
+
+impl ConfiguredEndpoints {
+    /// Loads the endpoint URLs from the environment and the profile, unless
+    /// `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS` is `true`.
+    pub(crate) fn load() -> ConfiguredEndpoints {
+        let ignore = env::var("AWS_IGNORE_CONFIGURED_ENDPOINT_URLS")
+            .map(|value| value.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if ignore {
+            return ConfiguredEndpoints::default();
+        }
+        ConfiguredEndpoints::new(
+            env::vars(),
+            ProfileProvider::endpoint_urls().unwrap_or_default(),
+        )
+    }
+
+    fn new<I>(vars: I, profile: ProfileEndpointUrls) -> ConfiguredEndpoints
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut endpoints = ConfiguredEndpoints {
+            profile: ProfileEndpointUrls {
+                endpoint_url: profile.endpoint_url,
+                services: profile
+                    .services
+                    .into_iter()
+                    .map(|(service, url)| (service_key(&service), url))
+                    .collect(),
+            },
+            ..ConfiguredEndpoints::default()
+        };
+        for (name, value) in vars {
+            if value.is_empty() {
+                continue;
+            }
+            if name == "AWS_ENDPOINT_URL" {
+                endpoints.env_global = Some(value);
+            } else if let Some(service) = name.strip_prefix("AWS_ENDPOINT_URL_") {
+                endpoints.env_services.insert(service_key(service), value);
+            }
+        }
+        endpoints
+    }
+
+    /// Returns the configured endpoint URL of a service, preferring the environment over the
+    /// profile, and service specific URLs over global ones.
+    fn endpoint_url(&self, service: &str) -> Option<&str> {
+        let service = service_key(service);
+        self.env_services
+            .get(&service)
+            .or(self.env_global.as_ref())
+            .or_else(|| self.profile.services.get(&service))
+            .or(self.profile.endpoint_url.as_ref())
+            .map(String::as_str)
+    }
+
+    /// Sends the request to the configured endpoint of its service, if any, unless it was sent
+    /// to a host name or custom region explicitly. Returns whether the endpoint was set.
+    pub(crate) fn resolve(&self, request: &mut SignedRequest) -> bool {
+        if has_explicit_endpoint(request) {
+            return false;
+        }
+        let url = match self.endpoint_url(service_of(request)) {
+            Some(url) => url,
+            None => return false,
+        };
+        let endpoint = Endpoint::new(url);
+        request.scheme = Some(endpoint.scheme);
+        request.hostname = Some(endpoint.hostname);
+        true
+    }
+}
+
+/// Sends the request to the FIPS and/or dual-stack endpoint of its service and region, where
+/// they exist, unless it was sent to a host name or custom region explicitly.
+pub(crate) fn use_endpoint_variant(request: &mut SignedRequest, fips: bool, dualstack: bool) {
+    if has_explicit_endpoint(request) {
+        return;
+    }
+    let service = service_of(request);
+    let hostname = if dualstack {
+        dualstack_hostname(service, &request.region, fips)
+    } else {
+        None
+    };
+    let hostname = match hostname {
+        Some(hostname) => Some(hostname),
+        None if fips => fips_hostname(service, &request.region),
+        None => None,
+    };
+    if let Some(hostname) = hostname {
+        request.hostname = Some(hostname);
+    }
+}
+
+/// The services with dual-stack endpoints in the `api.aws` domain, like
+/// `dynamodb.us-east-1.api.aws`.
+const DUALSTACK_SERVICES: &[&str] = &[
+    "athena",
+    "dynamodb",
+    "ec2",
+    "ecr",
+    "elasticloadbalancing",
+    "kms",
+    "lambda",
+    "logs",
+    "monitoring",
+    "sns",
+    "sqs",
+    "sts",
+];
+
+/// Returns the host name of the dual-stack endpoint of a service in a region, which resolves to
+/// both IPv4 and IPv6 addresses, using its FIPS variant if requested and available.
+fn dualstack_hostname(service: &str, region: &Region, fips: bool) -> Option<String> {
+    let china = match *region {
+        Region::Custom { .. } => return None,
+        Region::CnNorth1 | Region::CnNorthwest1 => true,
+        _ => false,
+    };
+    let label = if fips && !china {
+        format!("{}-fips", service)
+    } else {
+        service.to_owned()
+    };
+    let hostname = match service {
+        // S3 predates the api.aws domain
+        "s3" if china => format!("s3.dualstack.{}.amazonaws.com.cn", region.name()),
+        "s3" => format!("{}.dualstack.{}.amazonaws.com", label, region.name()),
+        _ if !DUALSTACK_SERVICES.contains(&service) => return None,
+        _ if china => format!("{}.{}.api.amazonwebservices.com.cn", label, region.name()),
+        _ => format!("{}.{}.api.aws", label, region.name()),
+    };
+    Some(hostname)
+}
+
+/// Returns the host name of the FIPS endpoint of a service in a region. The FIPS endpoints of
+/// most services add `-fips` to the service, like `dynamodb-fips.us-gov-west-1.amazonaws.com`.
+fn fips_hostname(service: &str, region: &Region) -> Option<String> {
+    let in_gov_cloud = match *region {
+        Region::Custom { .. } | Region::CnNorth1 | Region::CnNorthwest1 => return None,
+        Region::UsGovEast1 | Region::UsGovWest1 => true,
+        _ => false,
+    };
+    let hostname = match service {
+        "chime" | "cloudfront" | "importexport" | "route53" | "sdb" => return None,
+        // the endpoints of the global services in GovCloud are FIPS endpoints already
+        "iam" if in_gov_cloud => "iam.us-gov.amazonaws.com".to_owned(),
+        "iam" => "iam-fips.amazonaws.com".to_owned(),
+        "organizations" if in_gov_cloud => "organizations.us-gov-west-1.amazonaws.com".to_owned(),
+        "organizations" => "organizations-fips.us-east-1.amazonaws.com".to_owned(),
+        // e.g. runtime-fips.sagemaker.us-east-1.amazonaws.com
+        _ => match service.find('.') {
+            Some(index) => format!(
+                "{}-fips{}.{}.amazonaws.com",
+                &service[..index],
+                &service[index..],
+                region.name()
+            ),
+            None => format!("{}-fips.{}.amazonaws.com", service, region.name()),
+        },
+    };
+    Some(hostname)
+}
+
+/// Whether the request is sent to a host name or custom region explicitly, rather than to the
+/// endpoint of its service.
+fn has_explicit_endpoint(request: &SignedRequest) -> bool {
+    if let Region::Custom { .. } = request.region {
+        return true;
+    }
+    request.hostname.is_some() && request.endpoint_prefix.is_none()
+}
+
+/// How the bucket of S3 requests is addressed, see `ClientConfig::set_addressing_style`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AddressingStyle {
+    /// Address buckets in the host name where they can be, and in the path otherwise, e.g.
+    /// for bucket names which aren't valid host names or for endpoints outside of AWS, like
+    /// MinIO or LocalStack.
+    #[default]
+    Auto,
+    /// Address buckets in the host name, like `https://bucket.s3.us-east-1.amazonaws.com/key`.
+    Virtual,
+    /// Address buckets in the path, like `https://s3.us-east-1.amazonaws.com/bucket/key`.
+    /// Path-style requests are deprecated, and buckets created in some regions don't
+    /// support them.
+    Path,
+}
+
+/// Moves the bucket of an S3 request from its path into its host name, unless the addressing
+/// style doesn't allow it.
+pub(crate) fn use_addressing_style(request: &mut SignedRequest, style: AddressingStyle) {
+    if request.service != "s3" || style == AddressingStyle::Path {
+        return;
+    }
+    let (bucket, rest) = {
+        let path = request.path.trim_start_matches('/');
+        match path.find('/') {
+            Some(index) => (&path[..index], &path[index..]),
+            None => (path, "/"),
+        }
+    };
+    if bucket.is_empty() {
+        return;
+    }
+    let hostname = request.hostname();
+    if style == AddressingStyle::Auto {
+        // certificates of AWS endpoints don't cover bucket names with dots
+        let aws_endpoint =
+            hostname.ends_with(".amazonaws.com") || hostname.ends_with(".amazonaws.com.cn");
+        if !aws_endpoint || !is_dns_compatible(bucket) || bucket.contains('.') {
+            return;
+        }
+    }
+    let hostname = format!("{}.{}", bucket, hostname);
+    request.path = rest.to_owned();
+    request.set_hostname(Some(hostname));
+}
+
+/// Sends an S3 request to the S3 Transfer Acceleration endpoint, with its bucket in the host
+/// name, unless it was sent to a host name or custom region explicitly, or it doesn't name a
+/// bucket or creates or deletes it. Returns whether it was, or an error if the bucket or region
+/// can't be accelerated.
+pub(crate) fn use_accelerate_endpoint(
+    request: &mut SignedRequest,
+    fips: bool,
+    dualstack: bool,
+) -> Result<bool, String> {
+    if request.service != "s3" || has_explicit_endpoint(request) {
+        return Ok(false);
+    }
+    let (bucket, rest) = access_point::split_bucket(&request.path);
+    let bucket_operation = rest.is_empty() && request.params.is_empty();
+    if bucket.is_empty()
+        || (bucket_operation && (request.method == "PUT" || request.method == "DELETE"))
+    {
+        return Ok(false);
+    }
+    if !is_dns_compatible(bucket) || bucket.contains('.') {
+        return Err(format!(
+            "The bucket {} can't be accelerated, since its name isn't a host name without dots",
+            bucket
+        ));
+    }
+    match request.region {
+        Region::CnNorth1 | Region::CnNorthwest1 | Region::UsGovEast1 | Region::UsGovWest1 => {
+            return Err(format!(
+                "S3 Transfer Acceleration isn't available in {}",
+                request.region.name()
+            ));
+        }
+        _ if fips => {
+            return Err("S3 Transfer Acceleration has no FIPS endpoints".to_owned());
+        }
+        _ => {}
+    }
+    let hostname = if dualstack {
+        format!("{}.s3-accelerate.dualstack.amazonaws.com", bucket)
+    } else {
+        format!("{}.s3-accelerate.amazonaws.com", bucket)
+    };
+    request.path = if rest.is_empty() {
+        "/".to_owned()
+    } else {
+        rest.to_owned()
+    };
+    request.set_hostname(Some(hostname));
+    Ok(true)
+}
+
+/// Whether the bucket name can be part of a host name.
+fn is_dns_compatible(bucket: &str) -> bool {
+    let valid_chars = bucket
+        .bytes()
+        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.');
+    let ip_address = bucket.split('.').count() == 4
+        && bucket
+            .split('.')
+            .all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()));
+    (3..=63).contains(&bucket.len())
+        && valid_chars
+        && bucket
+            .split('.')
+            .all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+        && !ip_address
+}
+
+/// The service whose endpoint the request is sent to.
+fn service_of(request: &SignedRequest) -> &str {
+    request.endpoint_prefix.as_ref().unwrap_or(&request.service)
+}
+
+/// Normalizes the service part of `AWS_ENDPOINT_URL_<SERVICE>` variables, the service keys of
+/// the `[services]` profile sections and endpoint prefixes, so that e.g. `RUNTIME_SAGEMAKER`,
+/// `runtime_sagemaker` and `runtime.sagemaker` match.
+fn service_key(service: &str) -> String {
+    service
+        .chars()
+        .map(|c| match c {
+            '-' | '.' | ' ' => '_',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_urls() {
+        let endpoint = Endpoint::new("http://localhost:4566/");
+        assert_eq!(endpoint.scheme(), "http");
+        assert_eq!(endpoint.hostname(), "localhost:4566");
+
+        let endpoint = Endpoint::new("sqs.us-east-1.vpce.amazonaws.com");
+        assert_eq!(endpoint.scheme(), "https");
+        assert_eq!(endpoint.hostname(), "sqs.us-east-1.vpce.amazonaws.com");
+    }
+
+    #[test]
+    fn default_endpoints() {
+        let endpoint = DefaultEndpointResolver.resolve_endpoint("sqs", &Region::EuWest1);
+        assert_eq!(
+            endpoint,
+            Endpoint::new("https://sqs.eu-west-1.amazonaws.com")
+        );
+
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://localhost:9324".to_owned(),
+        };
+        let endpoint = DefaultEndpointResolver.resolve_endpoint("sqs", &region);
+        assert_eq!(endpoint, Endpoint::new("http://localhost:9324"));
+    }
+
+    #[test]
+    fn configured_endpoints() {
+        let vars = vec![
+            (
+                "AWS_ENDPOINT_URL".to_owned(),
+                "http://localhost:4566".to_owned(),
+            ),
+            (
+                "AWS_ENDPOINT_URL_DYNAMODB".to_owned(),
+                "http://localhost:8000".to_owned(),
+            ),
+            (
+                "AWS_ENDPOINT_URL_RUNTIME_SAGEMAKER".to_owned(),
+                "http://localhost:8080".to_owned(),
+            ),
+        ];
+        let mut services = HashMap::new();
+        services.insert("sqs".to_owned(), "http://localhost:9324".to_owned());
+        let profile = ProfileEndpointUrls {
+            endpoint_url: Some("http://localhost:5000".to_owned()),
+            services,
+        };
+
+        let endpoints = ConfiguredEndpoints::new(vars, profile.clone());
+        assert_eq!(
+            endpoints.endpoint_url("dynamodb"),
+            Some("http://localhost:8000")
+        );
+        assert_eq!(
+            endpoints.endpoint_url("runtime.sagemaker"),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(endpoints.endpoint_url("sqs"), Some("http://localhost:4566"));
+
+        let endpoints = ConfiguredEndpoints::new(vec![], profile);
+        assert_eq!(endpoints.endpoint_url("sqs"), Some("http://localhost:9324"));
+        assert_eq!(endpoints.endpoint_url("s3"), Some("http://localhost:5000"));
+
+        let endpoints = ConfiguredEndpoints::new(vec![], ProfileEndpointUrls::default());
+        assert_eq!(endpoints.endpoint_url("s3"), None);
+    }
+
+    #[test]
+    fn configured_endpoints_of_requests() {
+        let vars = vec![(
+            "AWS_ENDPOINT_URL_SQS".to_owned(),
+            "http://localhost:9324".to_owned(),
+        )];
+        let endpoints = ConfiguredEndpoints::new(vars, ProfileEndpointUrls::default());
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        endpoints.resolve(&mut request);
+        assert_eq!(request.scheme.as_deref(), Some("http"));
+        assert_eq!(request.hostname.as_deref(), Some("localhost:9324"));
+
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://localhost:4566".to_owned(),
+        };
+        let mut request = SignedRequest::new("POST", "sqs", &region, "/");
+        endpoints.resolve(&mut request);
+        assert_eq!(request.hostname, None);
+    }
+
+    #[test]
+    fn fips_endpoints() {
+        assert_eq!(
+            fips_hostname("dynamodb", &Region::UsGovWest1).as_deref(),
+            Some("dynamodb-fips.us-gov-west-1.amazonaws.com")
+        );
+        assert_eq!(
+            fips_hostname("runtime.sagemaker", &Region::UsEast1).as_deref(),
+            Some("runtime-fips.sagemaker.us-east-1.amazonaws.com")
+        );
+        assert_eq!(
+            fips_hostname("iam", &Region::UsEast1).as_deref(),
+            Some("iam-fips.amazonaws.com")
+        );
+        assert_eq!(fips_hostname("cloudfront", &Region::UsEast1), None);
+        assert_eq!(fips_hostname("s3", &Region::CnNorth1), None);
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, "/");
+        use_endpoint_variant(&mut request, true, false);
+        assert_eq!(
+            request.hostname.as_deref(),
+            Some("s3-fips.us-west-2.amazonaws.com")
+        );
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, "/");
+        request.set_hostname(Some("bucket.example.com".to_owned()));
+        use_endpoint_variant(&mut request, true, false);
+        assert_eq!(request.hostname.as_deref(), Some("bucket.example.com"));
+    }
+
+    #[test]
+    fn addressing_styles() {
+        let addressed = |path: &str, region: &Region, style| {
+            let mut request = SignedRequest::new("GET", "s3", region, path);
+            use_addressing_style(&mut request, style);
+            (request.hostname(), request.path)
+        };
+        let region = Region::UsWest2;
+        assert_eq!(
+            addressed("/bucket/dir/key", &region, AddressingStyle::Auto),
+            (
+                "bucket.s3.us-west-2.amazonaws.com".to_owned(),
+                "/dir/key".to_owned()
+            )
+        );
+        assert_eq!(
+            addressed("/bucket", &region, AddressingStyle::Auto),
+            (
+                "bucket.s3.us-west-2.amazonaws.com".to_owned(),
+                "/".to_owned()
+            )
+        );
+        assert_eq!(
+            addressed("/", &region, AddressingStyle::Auto),
+            ("s3.us-west-2.amazonaws.com".to_owned(), "/".to_owned())
+        );
+        assert_eq!(
+            addressed("/bucket/key", &region, AddressingStyle::Path),
+            (
+                "s3.us-west-2.amazonaws.com".to_owned(),
+                "/bucket/key".to_owned()
+            )
+        );
+
+        // buckets which can't be part of the host name fall back to the path
+        for bucket in &["My_Bucket", "my.bucket", "ab", "192.168.1.1", "-bucket"] {
+            let path = format!("/{}/key", bucket);
+            assert_eq!(addressed(&path, &region, AddressingStyle::Auto).1, path);
+        }
+        assert_eq!(
+            addressed("/my.bucket/key", &region, AddressingStyle::Virtual).0,
+            "my.bucket.s3.us-west-2.amazonaws.com"
+        );
+
+        // as do endpoints outside of AWS, unless virtual-hosted style is asked for
+        let minio = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        assert_eq!(
+            addressed("/bucket/key", &minio, AddressingStyle::Auto),
+            ("localhost:9000".to_owned(), "/bucket/key".to_owned())
+        );
+        assert_eq!(
+            addressed("/bucket/key", &minio, AddressingStyle::Virtual),
+            ("bucket.localhost:9000".to_owned(), "/key".to_owned())
+        );
+    }
+
+    #[test]
+    fn accelerate_endpoints() {
+        let mut request = SignedRequest::new("PUT", "s3", &Region::EuWest1, "/bucket/key");
+        assert_eq!(
+            use_accelerate_endpoint(&mut request, false, false),
+            Ok(true)
+        );
+        assert_eq!(
+            request.hostname.as_deref(),
+            Some("bucket.s3-accelerate.amazonaws.com")
+        );
+        assert_eq!(request.path, "/key");
+        assert_eq!(request.region_for_service(), "eu-west-1");
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::EuWest1, "/bucket");
+        assert_eq!(use_accelerate_endpoint(&mut request, false, true), Ok(true));
+        assert_eq!(
+            request.hostname.as_deref(),
+            Some("bucket.s3-accelerate.dualstack.amazonaws.com")
+        );
+        assert_eq!(request.path, "/");
+
+        // listing, creating and deleting buckets isn't accelerated
+        for &(method, path) in &[("GET", "/"), ("PUT", "/bucket"), ("DELETE", "/bucket")] {
+            let mut request = SignedRequest::new(method, "s3", &Region::EuWest1, path);
+            assert_eq!(
+                use_accelerate_endpoint(&mut request, false, false),
+                Ok(false)
+            );
+            assert_eq!(request.hostname, None);
+        }
+        let mut request = SignedRequest::new("PUT", "s3", &Region::EuWest1, "/bucket");
+        request.add_param("tagging", "");
+        assert_eq!(
+            use_accelerate_endpoint(&mut request, false, false),
+            Ok(true)
+        );
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::EuWest1, "/my.bucket/key");
+        assert!(use_accelerate_endpoint(&mut request, false, false).is_err());
+        let mut request = SignedRequest::new("GET", "s3", &Region::CnNorth1, "/bucket/key");
+        assert!(use_accelerate_endpoint(&mut request, false, false).is_err());
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(use_accelerate_endpoint(&mut request, true, false).is_err());
+    }
+
+    #[test]
+    fn dualstack_endpoints() {
+        assert_eq!(
+            dualstack_hostname("dynamodb", &Region::EuWest1, false).as_deref(),
+            Some("dynamodb.eu-west-1.api.aws")
+        );
+        assert_eq!(
+            dualstack_hostname("s3", &Region::UsEast1, true).as_deref(),
+            Some("s3-fips.dualstack.us-east-1.amazonaws.com")
+        );
+        assert_eq!(
+            dualstack_hostname("ec2", &Region::CnNorth1, true).as_deref(),
+            Some("ec2.cn-north-1.api.amazonwebservices.com.cn")
+        );
+        assert_eq!(dualstack_hostname("route53", &Region::UsEast1, false), None);
+
+        // services without dual-stack endpoints still use their FIPS endpoints
+        let mut request = SignedRequest::new("POST", "ssm", &Region::UsGovWest1, "/");
+        use_endpoint_variant(&mut request, true, true);
+        assert_eq!(
+            request.hostname.as_deref(),
+            Some("ssm-fips.us-gov-west-1.amazonaws.com")
+        );
+
+        let mut request = SignedRequest::new("POST", "ssm", &Region::UsGovWest1, "/");
+        use_endpoint_variant(&mut request, false, true);
+        assert_eq!(request.hostname, None);
+    }
+}
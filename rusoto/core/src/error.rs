@@ -1,8 +1,12 @@
+use std::borrow::Cow;
 use std::error::Error;
 use std::fmt;
 use std::io;
 
 use crate::credential::CredentialsError;
+use crate::retry::ErrorClass;
+use http::{HeaderMap, StatusCode};
+use serde_json::Value;
 
 use super::proto::xml::util::XmlParseError;
 use super::request::{BufferedHttpResponse, HttpDispatchError};
@@ -11,8 +15,9 @@ use crate::client::SignAndDispatchError;
 /// Generic error type returned by all rusoto requests.
 #[derive(Debug, PartialEq)]
 pub enum RusotoError<E> {
-    /// A service-specific error occurred.
-    Service(E),
+    /// A service-specific error occurred. The metadata of the response, like the ID AWS assigned
+    /// to the request, is provided.
+    Service(E, ErrorMetadata),
     /// An error occurred dispatching the HTTP request
     HttpDispatch(HttpDispatchError),
     /// An error was encountered with AWS credentials.
@@ -33,6 +38,217 @@ pub type RusotoResult<T, E> = Result<T, RusotoError<E>>;
 /// Header used by AWS on responses to identify the request
 pub const AWS_REQUEST_ID_HEADER: &str = "x-amzn-requestid";
 
+/// Header used by S3 on responses to identify the request
+pub const S3_REQUEST_ID_HEADER: &str = "x-amz-request-id";
+
+/// Header used by S3 on responses for the extended request ID, also known as the host ID
+pub const S3_EXTENDED_REQUEST_ID_HEADER: &str = "x-amz-id-2";
+
+/// Returns the request ID of the response with the headers.
+pub(crate) fn request_id(headers: &HeaderMap<String>) -> Option<&str> {
+    headers
+        .get(AWS_REQUEST_ID_HEADER)
+        .or_else(|| headers.get(S3_REQUEST_ID_HEADER))
+        .map(String::as_str)
+}
+
+/// Returns the extended request ID of the response with the headers.
+pub(crate) fn extended_request_id(headers: &HeaderMap<String>) -> Option<&str> {
+    headers
+        .get(S3_EXTENDED_REQUEST_ID_HEADER)
+        .map(String::as_str)
+}
+
+/// Reads the error code and message of a response, in any of the protocols of AWS.
+///
+/// The error code is read from the `x-amzn-ErrorType` header, or from the body of JSON and XML
+/// error responses.
+pub(crate) fn parse_error(response: &BufferedHttpResponse) -> (Option<String>, Option<String>) {
+    let header_code = response
+        .headers
+        .get("x-amzn-errortype")
+        .and_then(|error_type| error_type.split(':').next())
+        .map(str::to_owned);
+    if let Ok(json) = serde_json::from_slice::<Value>(&response.body) {
+        let code = json
+            .get("__type")
+            .or_else(|| json.get("code"))
+            .or_else(|| json.get("Code"))
+            .and_then(Value::as_str)
+            // e.g. "com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException"
+            .and_then(|code| code.rsplit('#').next())
+            .map(str::to_owned);
+        let message = json
+            .get("message")
+            .or_else(|| json.get("Message"))
+            .or_else(|| json.get("errorMessage"))
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+        return (header_code.or(code), message);
+    }
+    let body = std::str::from_utf8(&response.body).unwrap_or_default();
+    let code = header_code.or_else(|| xml_element(body, "Code").map(str::to_owned));
+    (code, xml_element(body, "Message").map(str::to_owned))
+}
+
+/// Returns the text of the first element with the name in an XML body, like the `<RequestId>`
+/// of the error responses of XML protocols.
+pub(crate) fn xml_element<'a>(body: &'a str, name: &str) -> Option<&'a str> {
+    let start_tag = format!("<{}>", name);
+    let start = body.find(&start_tag)? + start_tag.len();
+    let end = start + body[start..].find(&format!("</{}>", name))?;
+    Some(body[start..end].trim())
+}
+
+/// Metadata of the response to a request which failed with a service-specific error, see
+/// `RusotoError::Service`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ErrorMetadata {
+    code: Option<String>,
+    message: Option<String>,
+    request_id: Option<String>,
+    extended_request_id: Option<String>,
+    status: Option<StatusCode>,
+}
+
+impl ErrorMetadata {
+    /// Create the `ErrorMetadata` of an error without a response of its own, like those sent as
+    /// events of event streams.
+    pub(crate) fn new(code: Option<String>, message: Option<String>) -> ErrorMetadata {
+        ErrorMetadata {
+            code,
+            message,
+            ..ErrorMetadata::default()
+        }
+    }
+
+    /// Create the `ErrorMetadata` of a response.
+    pub fn from_response(response: &BufferedHttpResponse) -> ErrorMetadata {
+        let (code, message) = parse_error(response);
+        ErrorMetadata {
+            code,
+            message,
+            request_id: response.request_id().map(ToOwned::to_owned),
+            extended_request_id: response.extended_request_id().map(ToOwned::to_owned),
+            status: Some(response.status),
+        }
+    }
+
+    /// The error code of the response, like `ThrottlingException` or `NoSuchKey`.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The error message of the response.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The ID AWS assigned to the request, from the `x-amzn-RequestId` header of the response,
+    /// the `x-amz-request-id` header of S3 responses or the `<RequestId>` of XML error
+    /// responses.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// The extended request ID S3 assigned to the request, from the `x-amz-id-2` header or the
+    /// `<HostId>` of the response.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        self.extended_request_id.as_deref()
+    }
+
+    /// The HTTP status of the response.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// Classifies the error by its code, falling back to the status of the response, like
+    /// `ErrorClass::from_response`.
+    pub fn error_class(&self) -> Option<ErrorClass> {
+        self.code
+            .as_deref()
+            .and_then(ErrorClass::from_code)
+            .or_else(|| self.status.and_then(ErrorClass::from_status))
+    }
+
+    /// Whether the service throttled the request.
+    pub fn is_throttling(&self) -> bool {
+        self.error_class() == Some(ErrorClass::Throttling)
+    }
+
+    /// Whether the request failed temporarily, and may succeed if it is sent again.
+    pub fn is_retryable(&self) -> bool {
+        self.error_class().is_some()
+    }
+}
+
+impl<E> RusotoError<E> {
+    /// Create a `RusotoError::Service` from a service-specific error and the response it was
+    /// parsed from.
+    pub fn service(response: &BufferedHttpResponse, err: E) -> RusotoError<E> {
+        RusotoError::Service(err, ErrorMetadata::from_response(response))
+    }
+
+    /// The metadata of the response, if the request failed with one. This is the metadata of
+    /// `Service` errors, or read from the response of `Unknown` errors.
+    pub fn metadata(&self) -> Option<Cow<'_, ErrorMetadata>> {
+        match *self {
+            RusotoError::Service(_, ref metadata) => Some(Cow::Borrowed(metadata)),
+            RusotoError::Unknown(ref response) => {
+                Some(Cow::Owned(ErrorMetadata::from_response(response)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The error code of the response, like `ThrottlingException` or `NoSuchKey`, if the
+    /// request failed with one. Prefer matching on the variants of `Service` errors where
+    /// possible.
+    pub fn code(&self) -> Option<String> {
+        self.metadata()
+            .and_then(|metadata| metadata.code().map(ToOwned::to_owned))
+    }
+
+    /// The ID AWS assigned to the request, if it failed with a response. AWS support asks for
+    /// it when investigating failed requests.
+    pub fn request_id(&self) -> Option<&str> {
+        match *self {
+            RusotoError::Service(_, ref metadata) => metadata.request_id(),
+            RusotoError::Unknown(ref response) => response.request_id(),
+            _ => None,
+        }
+    }
+
+    /// The extended request ID S3 assigned to the request, if it failed with a response.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        match *self {
+            RusotoError::Service(_, ref metadata) => metadata.extended_request_id(),
+            RusotoError::Unknown(ref response) => response.extended_request_id(),
+            _ => None,
+        }
+    }
+
+    /// Whether the service throttled the request.
+    pub fn is_throttling(&self) -> bool {
+        self.metadata()
+            .filter(|metadata| metadata.is_throttling())
+            .is_some()
+    }
+
+    /// Whether the request failed temporarily, and may succeed if it is sent again. This
+    /// includes errors dispatching the request, like connection failures, and the errors
+    /// classified as `ErrorClass::Transient` or `ErrorClass::Throttling`.
+    pub fn is_retryable(&self) -> bool {
+        match *self {
+            RusotoError::HttpDispatch(_) => true,
+            _ => self
+                .metadata()
+                .filter(|metadata| metadata.is_retryable())
+                .is_some(),
+        }
+    }
+}
+
 impl<E> From<XmlParseError> for RusotoError<E> {
     fn from(err: XmlParseError) -> Self {
         let XmlParseError(message) = err;
@@ -76,7 +292,7 @@ impl<E> From<io::Error> for RusotoError<E> {
 impl<E: Error + 'static> fmt::Display for RusotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            RusotoError::Service(ref err) => write!(f, "{}", err),
+            RusotoError::Service(ref err, _) => write!(f, "{}", err),
             RusotoError::Validation(ref cause) => write!(f, "{}", cause),
             RusotoError::Credentials(ref err) => write!(f, "{}", err),
             RusotoError::HttpDispatch(ref dispatch_error) => write!(f, "{}", dispatch_error),
@@ -84,7 +300,7 @@ impl<E: Error + 'static> fmt::Display for RusotoError<E> {
             RusotoError::Unknown(ref cause) => write!(
                 f,
                 "Request ID: {:?} Body: {}",
-                cause.headers.get(AWS_REQUEST_ID_HEADER),
+                cause.request_id(),
                 cause.body_as_str()
             ),
             RusotoError::Blocking => write!(f, "Failed to run blocking future"),
@@ -95,10 +311,89 @@ impl<E: Error + 'static> fmt::Display for RusotoError<E> {
 impl<E: Error + 'static> Error for RusotoError<E> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            RusotoError::Service(ref err) => Some(err),
+            RusotoError::Service(ref err, _) => Some(err),
             RusotoError::Credentials(ref err) => Some(err),
             RusotoError::HttpDispatch(ref err) => Some(err),
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use http::StatusCode;
+
+    fn response_with(headers: &[(&'static str, &str)]) -> BufferedHttpResponse {
+        let mut response = BufferedHttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: Bytes::new(),
+            headers: HeaderMap::default(),
+        };
+        for &(name, value) in headers {
+            response.headers.insert(name, value.to_owned());
+        }
+        response
+    }
+
+    #[test]
+    fn request_ids() {
+        let response = response_with(&[("x-amzn-requestid", "abc-123")]);
+        let err = RusotoError::service(&response, "service error");
+        assert_eq!(err.request_id(), Some("abc-123"));
+        assert_eq!(err.extended_request_id(), None);
+        let err = RusotoError::<()>::Unknown(response);
+        assert_eq!(err.request_id(), Some("abc-123"));
+
+        let response = response_with(&[("x-amz-request-id", "ABC"), ("x-amz-id-2", "abc/def=")]);
+        assert_eq!(response.request_id(), Some("ABC"));
+        let err = RusotoError::service(&response, "service error");
+        assert_eq!(err.request_id(), Some("ABC"));
+        assert_eq!(err.extended_request_id(), Some("abc/def="));
+
+        let err = RusotoError::<()>::ParseError("invalid response".to_owned());
+        assert_eq!(err.request_id(), None);
+    }
+
+    #[test]
+    fn error_metadata() {
+        let mut json = response_with(&[]);
+        json.body = Bytes::from_static(
+            br#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException","message":"Rate exceeded"}"#,
+        );
+        let err = RusotoError::service(&json, "service error");
+        let metadata = err.metadata().unwrap();
+        assert_eq!(
+            metadata.code(),
+            Some("ProvisionedThroughputExceededException")
+        );
+        assert_eq!(metadata.message(), Some("Rate exceeded"));
+        assert_eq!(metadata.status(), Some(StatusCode::BAD_REQUEST));
+        assert!(err.is_throttling());
+        assert!(err.is_retryable());
+
+        let mut xml = response_with(&[]);
+        xml.body = Bytes::from_static(
+            b"<Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message>\
+              <RequestId>4442587FB7D0A2F9</RequestId></Error>",
+        );
+        let err = RusotoError::<()>::Unknown(xml);
+        assert_eq!(err.code().as_deref(), Some("NoSuchKey"));
+        assert_eq!(err.request_id(), Some("4442587FB7D0A2F9"));
+        assert_eq!(
+            err.metadata().unwrap().message(),
+            Some("The specified key does not exist.")
+        );
+        assert!(!err.is_throttling());
+        assert!(!err.is_retryable());
+
+        let mut unavailable = response_with(&[]);
+        unavailable.status = StatusCode::SERVICE_UNAVAILABLE;
+        assert!(RusotoError::<()>::Unknown(unavailable).is_retryable());
+
+        let err = RusotoError::<()>::HttpDispatch(HttpDispatchError::new("reset".to_owned()));
+        assert!(err.is_retryable());
+        assert!(err.metadata().is_none());
+    }
+}
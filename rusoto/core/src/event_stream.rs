@@ -9,7 +9,7 @@ use crc32fast::Hasher;
 use futures::task::{Context, Poll};
 use futures::Stream;
 
-use crate::error::RusotoError;
+use crate::error::{ErrorMetadata, RusotoError};
 use crate::request::HttpResponse;
 use crate::stream::ByteStream;
 
@@ -234,6 +234,26 @@ impl<'a> EventStreamMessage<'a> {
     pub fn get_header(&self, name: &str) -> Option<&EventStreamHeader<'a>> {
         self.headers.iter().find(|h| h.name == name)
     }
+
+    fn get_string_header(&self, name: &str) -> Option<&'a str> {
+        match self.get_header(name)?.value {
+            EventStreamHeaderValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The error of an error message, which services like S3 Select send when a request fails
+    /// after the response started.
+    fn error(&self) -> Option<RusotoError<()>> {
+        if self.get_string_header(":message-type") != Some("error") {
+            return None;
+        }
+        let metadata = ErrorMetadata::new(
+            self.get_string_header(":error-code").map(str::to_owned),
+            self.get_string_header(":error-message").map(str::to_owned),
+        );
+        Some(RusotoError::Service((), metadata))
+    }
 }
 
 /// Event Stream decoder
@@ -267,6 +287,12 @@ impl<T: DeserializeEvent + Unpin> EventStream<T> {
             };
             log::trace!("Parsed event stream event: {:?}", event_msg);
 
+            if let Some(err) = event_msg.error() {
+                // the stream ends with the error
+                buf.clear();
+                return Err(err);
+            }
+
             let event_type_header = event_msg
                 .get_header(":event-type")
                 .or_else(|| event_msg.get_header(":exception-type"))
@@ -491,4 +517,65 @@ mod tests {
             Err(EventStreamParseError::InvalidData(_))
         ));
     }
+    #[derive(Debug, PartialEq)]
+    struct TestEvent(String);
+
+    impl DeserializeEvent for TestEvent {
+        fn deserialize_event(event_type: &str, _data: &[u8]) -> Result<Self, RusotoError<()>> {
+            Ok(TestEvent(event_type.to_owned()))
+        }
+    }
+
+    fn encode_message(headers: &[(&str, &str)], payload: &[u8]) -> Vec<u8> {
+        let mut encoded_headers = Vec::new();
+        for (name, value) in headers {
+            encoded_headers.push(name.len() as u8);
+            encoded_headers.extend(name.as_bytes());
+            encoded_headers.push(7);
+            encoded_headers.extend(&(value.len() as u16).to_be_bytes());
+            encoded_headers.extend(value.as_bytes());
+        }
+        let total_length = 16 + encoded_headers.len() + payload.len();
+        let mut message = Vec::with_capacity(total_length);
+        message.extend(&(total_length as u32).to_be_bytes());
+        message.extend(&(encoded_headers.len() as u32).to_be_bytes());
+        message.extend(&crc32fast::hash(&message).to_be_bytes());
+        message.extend(encoded_headers);
+        message.extend(payload);
+        message.extend(&crc32fast::hash(&message).to_be_bytes());
+        message
+    }
+
+    #[test]
+    fn error_message() {
+        let mut buf = encode_message(
+            &[(":message-type", "event"), (":event-type", "Records")],
+            b"a,b\n",
+        );
+        buf.extend(encode_message(
+            &[
+                (":message-type", "error"),
+                (":error-code", "InternalError"),
+                (":error-message", "We encountered an internal error."),
+            ],
+            b"",
+        ));
+
+        assert_eq!(
+            EventStream::<TestEvent>::pop_event(&mut buf),
+            Ok(Some(TestEvent("Records".to_owned())))
+        );
+        assert_eq!(
+            EventStream::<TestEvent>::pop_event(&mut buf),
+            Err(RusotoError::Service(
+                (),
+                ErrorMetadata::new(
+                    Some("InternalError".to_owned()),
+                    Some("We encountered an internal error.".to_owned()),
+                )
+            ))
+        );
+        // the stream ends with the error
+        assert!(buf.is_empty());
+    }
 }
@@ -0,0 +1,154 @@
+//! Hedging read requests whose response is slow in coming.
+//!
+//! A client measures how long its successful requests take. When no response to an attempt of
+//! a request which only reads arrived within a percentile of those latencies, a second copy of
+//! the attempt is sent, and whichever succeeds first is used. The other one is cancelled.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The number of latencies the hedging delay is computed from.
+const WINDOW: usize = 100;
+/// The number of latencies needed before the initial delay is replaced by the percentile.
+const MIN_SAMPLES: usize = 20;
+
+/// Settings of the hedging of the requests of a `Client`, see `ClientConfig::set_hedging_policy`.
+///
+/// Only requests which only read are hedged, like requests of operations the models of the
+/// services mark as read-only, like `GetItem` or `DescribeInstances`, or `GET` requests built
+/// by hand, and not those with a streaming payload, which can only be sent once. Hedged attempts aren't rate limited or subject to the circuit breaker,
+/// and count as a single attempt for the retry policy.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_core::request::HttpClient;
+/// use rusoto_core::credential::DefaultCredentialsProvider;
+/// use rusoto_core::{Client, ClientConfig, HedgingPolicy};
+///
+/// // hedge the slowest 1% of the reads
+/// let mut hedging_policy = HedgingPolicy::new();
+/// hedging_policy.set_percentile(99.0);
+/// hedging_policy.set_initial_delay(Duration::from_millis(50));
+///
+/// let mut config = ClientConfig::new();
+/// config.set_hedging_policy(hedging_policy);
+/// let client = Client::new_with_config(
+///     DefaultCredentialsProvider::new().unwrap(),
+///     HttpClient::new().unwrap(),
+///     config,
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HedgingPolicy {
+    percentile: f64,
+    initial_delay: Duration,
+}
+
+impl HedgingPolicy {
+    /// Create a `HedgingPolicy` hedging attempts which take longer than 95% of the successful
+    /// ones, or 100 milliseconds until enough of them were measured.
+    pub fn new() -> HedgingPolicy {
+        HedgingPolicy {
+            percentile: 95.0,
+            initial_delay: Duration::from_millis(100),
+        }
+    }
+
+    /// Set the percentile of the latencies of the successful requests of the client after
+    /// which an attempt is hedged, between 0 and 100.
+    pub fn set_percentile(&mut self, percentile: f64) {
+        self.percentile = percentile.clamp(0.0, 100.0);
+    }
+
+    /// Set after how long attempts are hedged until the latencies of enough requests were
+    /// measured.
+    pub fn set_initial_delay(&mut self, initial_delay: Duration) {
+        self.initial_delay = initial_delay;
+    }
+
+    /// Get the percentile of the latencies after which an attempt is hedged.
+    pub fn percentile(&self) -> f64 {
+        self.percentile
+    }
+
+    /// Get after how long attempts are hedged until enough latencies were measured.
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+}
+
+impl Default for HedgingPolicy {
+    fn default() -> HedgingPolicy {
+        HedgingPolicy::new()
+    }
+}
+
+/// The latencies of the recent successful requests of a client.
+#[derive(Debug)]
+pub(crate) struct ClientHedging {
+    settings: HedgingPolicy,
+    latencies: Mutex<VecDeque<Duration>>,
+}
+
+impl ClientHedging {
+    pub(crate) fn new(settings: HedgingPolicy) -> ClientHedging {
+        ClientHedging {
+            settings,
+            latencies: Mutex::new(VecDeque::with_capacity(WINDOW)),
+        }
+    }
+
+    /// How long to wait for a response before hedging an attempt.
+    pub(crate) fn delay(&self) -> Duration {
+        let mut latencies: Vec<Duration> = {
+            let latencies = self.latencies.lock().unwrap();
+            if latencies.len() < MIN_SAMPLES {
+                return self.settings.initial_delay;
+            }
+            latencies.iter().cloned().collect()
+        };
+        latencies.sort();
+        let rank = (self.settings.percentile / 100.0 * latencies.len() as f64).ceil() as usize;
+        latencies[rank.max(1) - 1]
+    }
+
+    /// Records the latency of a successful attempt.
+    pub(crate) fn record(&self, latency: Duration) {
+        let mut latencies = self.latencies.lock().unwrap();
+        if latencies.len() == WINDOW {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay() {
+        let mut settings = HedgingPolicy::new();
+        settings.set_percentile(90.0);
+        settings.set_initial_delay(Duration::from_millis(50));
+        let hedging = ClientHedging::new(settings);
+        for millis in 1..MIN_SAMPLES as u64 {
+            hedging.record(Duration::from_millis(millis));
+        }
+        assert_eq!(hedging.delay(), Duration::from_millis(50));
+
+        // the percentile of the latencies once enough were measured
+        hedging.record(Duration::from_millis(20));
+        assert_eq!(hedging.delay(), Duration::from_millis(18));
+
+        // of the recent ones
+        for _ in 0..WINDOW {
+            hedging.record(Duration::from_millis(5));
+        }
+        assert_eq!(hedging.delay(), Duration::from_millis(5));
+    }
+}
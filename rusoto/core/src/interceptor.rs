@@ -0,0 +1,69 @@
+//! Hooks into the requests made by a client.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::request::{HttpDispatchError, HttpResponse};
+use crate::signature::SignedRequest;
+
+/// Hooks into the requests made by a `Client`, see `ClientConfig::add_interceptor`.
+///
+/// Requests are serialized by the service crates, so the first hook runs once a request is
+/// serialized, before it's sent for the first time. Responses are deserialized by the service
+/// crates as well, so the last hook runs with the response of every attempt, before it's
+/// deserialized. All hooks do nothing by default.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_core::signature::SignedRequest;
+/// use rusoto_core::Interceptor;
+///
+/// /// Tags every request with the team making it.
+/// struct TeamHeader;
+///
+/// impl Interceptor for TeamHeader {
+///     fn before_signing(&self, request: &mut SignedRequest) {
+///         request.add_header("x-team", "storage");
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called once per request, before it's sent for the first time.
+    fn before_execution(&self, _request: &mut SignedRequest) {}
+
+    /// Called before every attempt, before the request is signed. Headers added here are
+    /// signed.
+    fn before_signing(&self, _request: &mut SignedRequest) {}
+
+    /// Called before every attempt, after the request is signed. Headers added here aren't
+    /// signed.
+    fn before_transmit(&self, _request: &mut SignedRequest) {}
+
+    /// Called with the response of every attempt, including error responses, before it's
+    /// returned or the attempt is retried.
+    fn after_response(&self, _response: &mut HttpResponse) {}
+
+    /// Called when an attempt failed without a response, e.g. because of a timeout.
+    fn after_dispatch_error(&self, _error: &HttpDispatchError) {}
+}
+
+/// The interceptors of a client, in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn Interceptor>>);
+
+impl Interceptors {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Arc<dyn Interceptor>> {
+        self.0.iter()
+    }
+}
+
+impl fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} interceptors]", self.0.len())
+    }
+}
@@ -21,13 +21,46 @@
 
 #[cfg(feature = "rustls")]
 use hyper_rustls as tls;
-#[cfg(feature = "native-tls")]
+// rustls is preferred when both are enabled, e.g. because another crate in the
+// dependency graph keeps the default features of a service crate
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
 use hyper_tls as tls;
 
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!(
+    "rusoto_core requires a TLS implementation, enable either the `native-tls` or the `rustls` feature"
+);
+
+#[cfg(feature = "blocking")]
+mod blocking;
+mod cancellation;
+mod certificates;
+mod checksum;
+mod circuit_breaker;
 mod client;
+mod compression;
+mod dispatcher;
+mod endpoint;
 mod error;
+mod hedging;
+mod interceptor;
+mod metrics;
+mod presign;
+mod proxy;
+mod rate_limiter;
+mod redirect;
+mod request_config;
+mod resolver;
+mod retry;
+mod runtime;
+#[cfg(feature = "tower")]
+mod service;
+mod signer;
 mod stream;
+mod trace;
 
+#[doc(hidden)]
+pub mod access_point;
 pub mod event_stream;
 pub mod param;
 #[doc(hidden)]
@@ -36,7 +69,12 @@ pub mod request;
 #[doc(hidden)]
 pub mod signature;
 
-pub use crate::client::Client;
+#[cfg(feature = "blocking")]
+pub use crate::blocking::BlockingRuntime;
+pub use crate::cancellation::CancellationToken;
+pub use crate::checksum::ChecksumMode;
+pub use crate::circuit_breaker::CircuitBreaker;
+pub use crate::client::{Client, ClientConfig, SignAndDispatchError};
 #[doc(hidden)]
 pub mod encoding;
 #[doc(hidden)]
@@ -44,8 +82,28 @@ pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
-pub use crate::error::{RusotoError, RusotoResult};
+pub use crate::dispatcher::{Dispatcher, HttpDispatcher, HttpRequest};
+pub use crate::endpoint::{AddressingStyle, DefaultEndpointResolver, Endpoint, EndpointResolver};
+pub use crate::error::{ErrorMetadata, RusotoError, RusotoResult};
+pub use crate::hedging::HedgingPolicy;
+pub use crate::interceptor::Interceptor;
+pub use crate::metrics::{AttemptMetrics, ClientMetricsObserver};
+pub use crate::presign::{presign, presign_with_options, PresignError};
+pub use crate::proxy::{Proxy, ProxyConfig, ProxyConnector, ProxyError, ProxyStream};
+pub use crate::rate_limiter::RateLimit;
 pub use crate::region::Region;
-pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
+pub use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError, ResponseBuffering,
+};
+pub use crate::request_config::{RequestConfig, RequestConfigExt, WithConfig};
+pub use crate::resolver::{Resolve, Resolver, StaticResolver, SystemResolver};
+pub use crate::retry::{ErrorClass, RetryMode, RetryPolicy};
+#[cfg(feature = "async-std")]
+pub use crate::runtime::AsyncStdRuntime;
+pub use crate::runtime::{Runtime, Sleep, TokioRuntime};
+#[cfg(feature = "tower")]
+pub use crate::service::ServiceDispatcher;
+pub use crate::signature::{ChecksumAlgorithm, PayloadSigning};
+pub use crate::signer::{AnonymousSigner, SigV4Signer, SignRequest};
 pub use crate::stream::ByteStream;
 pub use rusoto_credential as credential;
@@ -0,0 +1,111 @@
+//! Reporting metrics of the requests made by a client.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::StatusCode;
+
+use crate::retry::ErrorClass;
+
+/// Observes every attempt of the requests made by a `Client`, e.g. to export metrics to
+/// Prometheus or StatsD. See `ClientConfig::set_metrics_observer`.
+///
+/// Observers are called on the task making the request, so they should return quickly.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use rusoto_core::{AttemptMetrics, ClientMetricsObserver, ErrorClass};
+///
+/// #[derive(Default)]
+/// struct ThrottlingCounter(AtomicUsize);
+///
+/// impl ClientMetricsObserver for ThrottlingCounter {
+///     fn on_attempt(&self, metrics: &AttemptMetrics) {
+///         if metrics.error_class() == Some(ErrorClass::Throttling) {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait ClientMetricsObserver: Send + Sync {
+    /// Called after every attempt of a request, including the ones which are retried.
+    fn on_attempt(&self, metrics: &AttemptMetrics);
+}
+
+/// Metrics of an attempt of a request, see `ClientMetricsObserver`.
+#[derive(Clone, Debug)]
+pub struct AttemptMetrics {
+    pub(crate) service: String,
+    pub(crate) operation: Option<String>,
+    pub(crate) region: String,
+    pub(crate) retries: u32,
+    pub(crate) latency: Duration,
+    pub(crate) bytes_sent: Option<u64>,
+    pub(crate) bytes_received: Option<u64>,
+    pub(crate) status: Option<StatusCode>,
+    pub(crate) error_class: Option<ErrorClass>,
+}
+
+impl AttemptMetrics {
+    /// The service the request was sent to, like `dynamodb`.
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// The operation of the request, like `PutItem`, if it was made by a service client, see
+    /// `SignedRequest::set_operation_name`.
+    pub fn operation(&self) -> Option<&str> {
+        self.operation.as_deref()
+    }
+
+    /// The name of the region the request was sent to.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// How many times the request was sent before this attempt.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// How long the attempt took, from fetching credentials to receiving the response headers.
+    pub fn latency(&self) -> Duration {
+        self.latency
+    }
+
+    /// The size of the request payload, if known. Streaming payloads only have a known size
+    /// if the request has a `Content-Length` header.
+    pub fn bytes_sent(&self) -> Option<u64> {
+        self.bytes_sent
+    }
+
+    /// The size of the response body, if known. Error responses are read completely, other
+    /// responses only have a known size if they have a `Content-Length` header.
+    pub fn bytes_received(&self) -> Option<u64> {
+        self.bytes_received
+    }
+
+    /// The status of the response, unless the attempt failed without a response.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.status
+    }
+
+    /// The class of the error the attempt failed with, if it may be retried.
+    pub fn error_class(&self) -> Option<ErrorClass> {
+        self.error_class
+    }
+}
+
+/// The metrics observer of a client.
+#[derive(Clone)]
+pub(crate) struct MetricsObserver(pub(crate) Arc<dyn ClientMetricsObserver>);
+
+impl fmt::Debug for MetricsObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ClientMetricsObserver")
+    }
+}
@@ -0,0 +1,303 @@
+//! Presigning the requests of operations as URLs, rather than sending them.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::credential::CredentialsError;
+use crate::param::Params;
+use crate::signature::{PresignOptions, SignedRequest};
+use crate::trace;
+
+/// The longest time presigned URLs are valid for.
+const MAX_EXPIRY: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+tokio::task_local! {
+    /// Set while the future passed to `presign` runs, and unset again while credentials
+    /// providers run.
+    static PRESIGNING: Option<Presigning>;
+}
+
+struct Presigning {
+    options: PresignOptions,
+    /// The URL of the first request, or why it couldn't be presigned.
+    url: RefCell<Option<Result<String, PresignError>>>,
+}
+
+/// Runs the future, presigning the first request it makes as a URL instead of sending it.
+///
+/// This works with the futures of any service operation, so users can be given temporary
+/// links to run operations with the credentials of the client, e.g. to download an S3 object
+/// or to send a message to an SQS queue. The URL is signed for the region, endpoint and
+/// credentials the request would be sent with, including those set with `with_config`.
+///
+/// The parameters of query protocol requests, like those of EC2 or SQS, are moved into the
+/// query string of the URL, which is requested with `GET`. Requests of other services are made
+/// with the method of the operation, and with the headers it sets, like S3's `Content-MD5`.
+/// Their bodies, like the content of an S3 object, are sent by whoever uses the URL; bodies of
+/// requests to services other than S3 are signed, so only the body the operation was called
+/// with is accepted. Requests of JSON protocol services, like DynamoDB, can't be presigned
+/// since the operation is picked with a header.
+///
+/// URLs are valid for at most a week, and for no longer than the credentials they're signed
+/// with, e.g. the credentials of an assumed role.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use rusoto_core::signature::SignedRequest;
+/// use rusoto_core::{presign, Client, Region};
+///
+/// # async fn example() {
+/// let client = Client::shared();
+/// // e.g. `s3.get_object(request)` with the S3 client of rusoto_s3
+/// let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+/// let url = presign(client.sign_and_dispatch(request), Duration::from_secs(3600))
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub async fn presign<F>(future: F, expires_in: Duration) -> Result<String, PresignError>
+where
+    F: Future,
+{
+    let options = PresignOptions {
+        expires_in,
+        ..PresignOptions::default()
+    };
+    presign_with_options(future, options).await
+}
+
+/// Like `presign`, with additional signed headers, a start time or an unsigned session token,
+/// see `PresignOptions`.
+///
+/// Signing headers like `x-amz-server-side-encryption` or `x-amz-storage-class` constrains the
+/// uploads made with the URL, since they have to be sent with the same values.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use rusoto_core::signature::{PresignOptions, SignedRequest};
+/// use rusoto_core::{presign_with_options, Client, Region};
+///
+/// # async fn example() {
+/// let client = Client::shared();
+/// // e.g. `s3.put_object(request)` with the S3 client of rusoto_s3
+/// let request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+/// let options = PresignOptions {
+///     expires_in: Duration::from_secs(600),
+///     headers: vec![(
+///         "x-amz-server-side-encryption".to_owned(),
+///         "aws:kms".to_owned(),
+///     )],
+///     ..PresignOptions::default()
+/// };
+/// let url = presign_with_options(client.sign_and_dispatch(request), options)
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+pub async fn presign_with_options<F>(
+    future: F,
+    options: PresignOptions,
+) -> Result<String, PresignError>
+where
+    F: Future,
+{
+    if options.expires_in > MAX_EXPIRY {
+        return Err(PresignError::Unsupported(format!(
+            "URLs can't be valid for more than {:?}",
+            MAX_EXPIRY
+        )));
+    }
+    let presigning = Presigning {
+        options,
+        url: RefCell::new(None),
+    };
+    PRESIGNING
+        .scope(Some(presigning), async {
+            // the operation fails once its request is presigned
+            let _ = future.await;
+            PRESIGNING.with(|presigning| {
+                presigning
+                    .as_ref()
+                    .and_then(|presigning| presigning.url.borrow_mut().take())
+            })
+        })
+        .await
+        .unwrap_or_else(|| {
+            Err(PresignError::Unsupported(
+                "The future completed without making a request".to_owned(),
+            ))
+        })
+}
+
+/// The options of the URL of the current request, if it's made in the scope of `presign`.
+pub(crate) fn options() -> Option<PresignOptions> {
+    PRESIGNING
+        .try_with(|presigning| presigning.as_ref().map(|p| p.options.clone()))
+        .ok()
+        .flatten()
+}
+
+/// Records the URL of the current request, unless an earlier request was presigned already.
+pub(crate) fn record(url: Result<String, PresignError>) {
+    let _ = PRESIGNING.try_with(|presigning| {
+        if let Some(presigning) = presigning {
+            let mut recorded = presigning.url.borrow_mut();
+            if recorded.is_none() {
+                *recorded = Some(url);
+            }
+        }
+    });
+}
+
+/// Runs the future with its requests sent rather than presigned, like the requests credentials
+/// providers make to get the credentials a URL is signed with.
+pub(crate) async fn without_presigning<F: Future>(future: F) -> F::Output {
+    PRESIGNING.scope(None, future).await
+}
+
+/// Prepares the request to be presigned, moving the parameters of query protocol requests from
+/// the body into the query string.
+pub(crate) fn prepare(request: &mut SignedRequest) -> Result<(), PresignError> {
+    if request.headers().contains_key("x-amz-target") {
+        return Err(PresignError::Unsupported(format!(
+            "Requests to {} pick the operation with a header, so they can't be presigned",
+            request.service
+        )));
+    }
+    let form_params = trace::form_params(request);
+    if !form_params.is_empty() {
+        let params: Params = form_params
+            .into_iter()
+            .map(|(name, value)| (name, Some(value)))
+            .collect();
+        request.set_params(params);
+        request.payload = None;
+        request.remove_header("content-type");
+        request.method = "GET".to_owned();
+    }
+    Ok(())
+}
+
+/// Whether the payload of the request is signed, rather than left to whoever uses the URL.
+pub(crate) fn signs_payload(request: &SignedRequest) -> bool {
+    request.service != "s3"
+}
+
+/// Error that occurs during `presign`.
+#[derive(Debug, PartialEq)]
+pub enum PresignError {
+    /// The credentials to sign the URL with couldn't be loaded.
+    Credentials(CredentialsError),
+    /// The request can't be presigned.
+    Unsupported(String),
+}
+
+impl fmt::Display for PresignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PresignError::Credentials(ref err) => err.fmt(f),
+            PresignError::Unsupported(ref message) => f.write_str(message),
+        }
+    }
+}
+
+impl Error for PresignError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            PresignError::Credentials(ref err) => Some(err),
+            PresignError::Unsupported(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::{AnonymousCredentialsProvider, StaticProvider};
+    use crate::request::{DispatchSignedRequest, DispatchSignedRequestFuture};
+    use crate::{Client, Region, RequestConfigExt};
+
+    struct Unreachable;
+
+    impl DispatchSignedRequest for Unreachable {
+        fn dispatch(
+            &self,
+            _request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            panic!("presigned requests aren't sent")
+        }
+    }
+
+    fn client() -> Client {
+        let credentials = StaticProvider::new_minimal("key".to_owned(), "secret".to_owned());
+        Client::new_with(credentials, Unreachable)
+    }
+
+    #[tokio::test]
+    async fn presigning() {
+        let client = client();
+        let expires_in = Duration::from_secs(60);
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        let url = presign(client.sign_and_dispatch(request), expires_in)
+            .await
+            .unwrap();
+        assert!(url.starts_with("https://bucket.s3.us-east-1.amazonaws.com/key?"));
+        assert!(url.contains("X-Amz-Credential=key%2F"));
+        assert!(url.contains("X-Amz-Expires=60"));
+        assert!(url.contains("X-Amz-Signature="));
+
+        // the parameters of query protocol requests are moved into the query string
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/123/queue");
+        request.set_payload(Some("Action=SendMessage&MessageBody=hello+world"));
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+        let url = presign(
+            client.sign_and_dispatch(request).with_config(|config| {
+                config.region(Region::EuWest1);
+            }),
+            expires_in,
+        )
+        .await
+        .unwrap();
+        assert!(url.starts_with("https://sqs.eu-west-1.amazonaws.com/123/queue?"));
+        assert!(url.contains("Action=SendMessage"));
+        assert!(url.contains("MessageBody=hello%20world"));
+
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
+        let error = presign(client.sign_and_dispatch(request), expires_in)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PresignError::Unsupported(_)));
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        let error = presign(client.sign_and_dispatch(request), MAX_EXPIRY * 2)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PresignError::Unsupported(_)));
+
+        let options = PresignOptions {
+            expires_in,
+            headers: vec![("x-amz-storage-class".to_owned(), "GLACIER".to_owned())],
+            ..PresignOptions::default()
+        };
+        let request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        let url = presign_with_options(client.sign_and_dispatch(request), options)
+            .await
+            .unwrap();
+        assert!(url.contains("X-Amz-SignedHeaders=host%3Bx-amz-storage-class"));
+
+        let client = Client::new_with(AnonymousCredentialsProvider, Unreachable);
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        let error = presign(client.sign_and_dispatch(request), expires_in)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, PresignError::Credentials(_)));
+    }
+}
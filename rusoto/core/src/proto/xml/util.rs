@@ -121,10 +121,7 @@ pub fn characters<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError
         }
     }
     match stack.next() {
-        Some(Ok(XmlEvent::Characters(data))) |
-        Some(Ok(XmlEvent::CData(data))) => {
-            Ok(data)
-        },
+        Some(Ok(XmlEvent::Characters(data))) | Some(Ok(XmlEvent::CData(data))) => Ok(data),
         _ => Err(XmlParseError::new("Expected characters")),
     }
 }
@@ -269,17 +266,23 @@ where
     if xml_response.body.is_empty() {
         Ok(T::default())
     } else {
-        let reader = EventReader::new_with_config(
-            xml_response.body.as_ref(),
-            ParserConfig::new().trim_whitespace(false),
-        );
-        let mut stack = XmlResponse::new(reader.into_iter().peekable());
-        let _start_document = stack.next();
-        let actual_tag_name = peek_at_name(&mut stack)?;
-        Ok(deserialize(&actual_tag_name, &mut stack)?)
+        Ok(parse_payload(xml_response.body.as_ref(), deserialize)?)
     }
 }
 
+/// Parses an XML payload, like the body of a response or of an event, starting at its root
+/// element.
+pub fn parse_payload<T>(
+    payload: &[u8],
+    deserialize: fn(&str, &mut XmlResponse<'_>) -> Result<T, XmlParseError>,
+) -> Result<T, XmlParseError> {
+    let reader = EventReader::new_with_config(payload, ParserConfig::new().trim_whitespace(false));
+    let mut stack = XmlResponse::new(reader.into_iter().peekable());
+    let _start_document = stack.next();
+    let actual_tag_name = peek_at_name(&mut stack)?;
+    deserialize(&actual_tag_name, &mut stack)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
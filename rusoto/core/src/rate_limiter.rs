@@ -0,0 +1,308 @@
+//! Client-side rate limiters: the fixed rate limit of a client, and the rate limiter of the
+//! adaptive retry mode.
+//!
+//! The adaptive one follows the algorithm of the AWS SDKs: every request takes a token from a
+//! bucket which is refilled at the allowed sending rate. The bucket is only used once the
+//! service throttled a request; the allowed rate then drops to a fraction of the measured
+//! sending rate, and grows back along a cubic curve while requests succeed.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The minimum allowed rate, in requests per second.
+const MIN_FILL_RATE: f64 = 0.5;
+/// The minimum capacity of the bucket, in tokens.
+const MIN_CAPACITY: f64 = 1.0;
+/// The weight of the latest measurement in the smoothed sending rate.
+const SMOOTH: f64 = 0.8;
+/// The fraction of the sending rate allowed after throttling.
+const BETA: f64 = 0.7;
+/// How fast the allowed rate grows back after throttling.
+const SCALE_CONSTANT: f64 = 0.4;
+
+/// A limit of the rate at which a `Client` sends requests, see `ClientConfig::set_rate_limit`.
+///
+/// Every attempt of a request takes a token from a bucket holding up to `burst` tokens, which
+/// is refilled at `requests_per_second`. Attempts wait until a token is available, so batch jobs
+/// stay under the API rate limits of an account instead of being throttled.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_core::request::HttpClient;
+/// use rusoto_core::credential::DefaultCredentialsProvider;
+/// use rusoto_core::{Client, ClientConfig, RateLimit};
+///
+/// // at most 5 requests per second, with bursts of up to 10 requests
+/// let mut rate_limit = RateLimit::new(5.0);
+/// rate_limit.set_burst(10);
+///
+/// let mut config = ClientConfig::new();
+/// config.set_rate_limit(rate_limit);
+/// let client = Client::new_with_config(
+///     DefaultCredentialsProvider::new().unwrap(),
+///     HttpClient::new().unwrap(),
+///     config,
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    requests_per_second: f64,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Create a `RateLimit` allowing the given number of requests per second, without bursts.
+    pub fn new(requests_per_second: f64) -> RateLimit {
+        RateLimit {
+            requests_per_second: requests_per_second.max(f64::MIN_POSITIVE),
+            burst: 1,
+        }
+    }
+
+    /// Set how many requests may be sent at once after the client was idle.
+    pub fn set_burst(&mut self, burst: u32) {
+        self.burst = burst.max(1);
+    }
+
+    /// Get the number of requests allowed per second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Get how many requests may be sent at once.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+}
+
+/// The token bucket enforcing the `RateLimit` of a client.
+#[derive(Debug)]
+pub(crate) struct ClientRateLimit {
+    settings: RateLimit,
+    start: Instant,
+    /// The tokens in the bucket, negative while requests wait for tokens, and when they were
+    /// last counted, in seconds since `start`.
+    bucket: Mutex<(f64, f64)>,
+}
+
+impl ClientRateLimit {
+    pub(crate) fn new(settings: RateLimit) -> ClientRateLimit {
+        ClientRateLimit {
+            settings,
+            start: Instant::now(),
+            bucket: Mutex::new((f64::from(settings.burst), 0.0)),
+        }
+    }
+
+    /// Takes a token for an attempt, returning how long to wait before sending it.
+    pub(crate) fn acquire(&self) -> Option<Duration> {
+        self.acquire_at(self.start.elapsed().as_secs_f64())
+    }
+
+    fn acquire_at(&self, now: f64) -> Option<Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let (tokens, last) = *bucket;
+        let rate = self.settings.requests_per_second;
+        let tokens = (tokens + (now - last) * rate).min(f64::from(self.settings.burst)) - 1.0;
+        *bucket = (tokens, now);
+        if tokens < 0.0 {
+            Some(Duration::from_secs_f64(-tokens / rate))
+        } else {
+            None
+        }
+    }
+}
+
+/// Limits the rate at which a client sends requests while it's being throttled.
+#[derive(Debug)]
+pub(crate) struct ClientRateLimiter {
+    start: Instant,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    /// Whether the service throttled requests yet.
+    enabled: bool,
+    fill_rate: f64,
+    max_capacity: f64,
+    /// Negative while requests wait for tokens.
+    current_capacity: f64,
+    last_timestamp: Option<f64>,
+    measured_tx_rate: f64,
+    last_tx_rate_bucket: f64,
+    request_count: u64,
+    last_max_rate: f64,
+    last_throttle_time: f64,
+    time_window: f64,
+}
+
+impl ClientRateLimiter {
+    pub(crate) fn new() -> ClientRateLimiter {
+        ClientRateLimiter {
+            start: Instant::now(),
+            state: Mutex::new(State {
+                enabled: false,
+                fill_rate: 0.0,
+                max_capacity: 0.0,
+                current_capacity: 0.0,
+                last_timestamp: None,
+                measured_tx_rate: 0.0,
+                last_tx_rate_bucket: 0.0,
+                request_count: 0,
+                last_max_rate: 0.0,
+                last_throttle_time: 0.0,
+                time_window: 0.0,
+            }),
+        }
+    }
+
+    fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+
+    /// Takes a token for a request, returning how long to wait before sending it.
+    pub(crate) fn acquire(&self) -> Option<Duration> {
+        self.state.lock().unwrap().acquire(self.now())
+    }
+
+    /// Adjusts the allowed rate after a response, which may have been throttled.
+    pub(crate) fn update(&self, throttled: bool) {
+        self.state.lock().unwrap().update(throttled, self.now())
+    }
+}
+
+impl State {
+    fn acquire(&mut self, now: f64) -> Option<Duration> {
+        if !self.enabled {
+            return None;
+        }
+        self.refill(now);
+        self.current_capacity -= 1.0;
+        if self.current_capacity < 0.0 {
+            Some(Duration::from_secs_f64(
+                -self.current_capacity / self.fill_rate,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn refill(&mut self, now: f64) {
+        if let Some(last_timestamp) = self.last_timestamp {
+            let fill_amount = (now - last_timestamp) * self.fill_rate;
+            self.current_capacity = (self.current_capacity + fill_amount).min(self.max_capacity);
+        }
+        self.last_timestamp = Some(now);
+    }
+
+    fn update(&mut self, throttled: bool, now: f64) {
+        self.update_measured_rate(now);
+        let calculated_rate = if throttled {
+            let rate_to_use = if self.enabled {
+                self.measured_tx_rate.min(self.fill_rate)
+            } else {
+                self.measured_tx_rate
+            };
+            self.last_max_rate = rate_to_use;
+            self.calculate_time_window();
+            self.last_throttle_time = now;
+            self.enabled = true;
+            rate_to_use * BETA
+        } else {
+            self.calculate_time_window();
+            SCALE_CONSTANT * (now - self.last_throttle_time - self.time_window).powi(3)
+                + self.last_max_rate
+        };
+        let new_rate = calculated_rate.min(2.0 * self.measured_tx_rate);
+        self.update_fill_rate(new_rate, now);
+    }
+
+    fn calculate_time_window(&mut self) {
+        self.time_window = (self.last_max_rate * (1.0 - BETA) / SCALE_CONSTANT).cbrt();
+    }
+
+    fn update_fill_rate(&mut self, new_rate: f64, now: f64) {
+        self.refill(now);
+        self.fill_rate = new_rate.max(MIN_FILL_RATE);
+        self.max_capacity = new_rate.max(MIN_CAPACITY);
+        self.current_capacity = self.current_capacity.min(self.max_capacity);
+    }
+
+    /// Measures the sending rate in buckets of half a second.
+    fn update_measured_rate(&mut self, now: f64) {
+        let time_bucket = (now * 2.0).floor() / 2.0;
+        self.request_count += 1;
+        if time_bucket > self.last_tx_rate_bucket {
+            let current_rate = self.request_count as f64 / (time_bucket - self.last_tx_rate_bucket);
+            self.measured_tx_rate = current_rate * SMOOTH + self.measured_tx_rate * (1.0 - SMOOTH);
+            self.request_count = 0;
+            self.last_tx_rate_bucket = time_bucket;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_limit() {
+        let mut settings = RateLimit::new(2.0);
+        settings.set_burst(3);
+        let limit = ClientRateLimit::new(settings);
+        // a burst is sent at once
+        for _ in 0..3 {
+            assert_eq!(limit.acquire_at(0.0), None);
+        }
+        // then requests wait in turn
+        assert_eq!(limit.acquire_at(0.0), Some(Duration::from_millis(500)));
+        assert_eq!(limit.acquire_at(0.0), Some(Duration::from_secs(1)));
+        assert_eq!(limit.acquire_at(1.5), None);
+        // and the bucket doesn't hold more than a burst
+        for _ in 0..3 {
+            assert_eq!(limit.acquire_at(10.0), None);
+        }
+        assert!(limit.acquire_at(10.0).is_some());
+    }
+
+    #[test]
+    fn disabled_until_throttled() {
+        let limiter = ClientRateLimiter::new();
+        let mut state = limiter.state.lock().unwrap();
+        for i in 0..100 {
+            assert_eq!(state.acquire(i as f64 / 100.0), None);
+            state.update(false, i as f64 / 100.0);
+        }
+        assert!(!state.enabled);
+    }
+
+    #[test]
+    fn throttling_limits_the_rate() {
+        let limiter = ClientRateLimiter::new();
+        let mut state = limiter.state.lock().unwrap();
+        // 10 requests per second for 5 seconds
+        for i in 0..50 {
+            state.update(false, i as f64 / 10.0);
+        }
+        assert!(state.measured_tx_rate > 8.0 && state.measured_tx_rate < 12.0);
+
+        state.update(true, 5.0);
+        assert!(state.enabled);
+        assert!(state.fill_rate < state.measured_tx_rate);
+        let throttled_rate = state.fill_rate;
+
+        // requests sent in a burst have to wait for tokens
+        let delays: Vec<_> = (0..20).map(|_| state.acquire(5.0)).collect();
+        assert!(delays.iter().any(Option::is_some));
+        let last = delays.last().unwrap().expect("delay");
+        assert!(last > Duration::from_secs(1));
+
+        // the rate grows back while requests succeed
+        for i in 0..100 {
+            state.update(false, 5.0 + i as f64 / 10.0);
+        }
+        assert!(state.fill_rate > throttled_rate);
+    }
+}
@@ -0,0 +1,138 @@
+//! Following the redirects of S3 requests sent to another region than the one of their bucket.
+//!
+//! S3 responds to requests sent to the wrong regional endpoint with a `301 Moved Permanently`,
+//! or a `307 Temporary Redirect` while the DNS records of a new bucket propagate, and to those
+//! signed for the wrong region with an `AuthorizationHeaderMalformed` error. Both name the
+//! region of the bucket, which the request is signed for and sent to again.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::region::Region;
+use crate::request::{BufferedHttpResponse, HttpResponse};
+
+/// The most buckets whose regions a client remembers; they're all forgotten when it's reached.
+const MAX_BUCKET_REGIONS: usize = 1000;
+
+/// The regions of the buckets the requests of a client were redirected to, see
+/// `ClientConfig::set_s3_cache_bucket_regions`.
+#[derive(Debug, Default)]
+pub(crate) struct BucketRegions {
+    regions: Mutex<HashMap<String, Region>>,
+}
+
+impl BucketRegions {
+    /// The region requests were redirected to for the bucket, if any.
+    pub(crate) fn get(&self, bucket: &str) -> Option<Region> {
+        self.regions.lock().unwrap().get(bucket).cloned()
+    }
+
+    /// Remembers the region requests for the bucket were redirected to.
+    pub(crate) fn insert(&self, bucket: &str, region: Region) {
+        let mut regions = self.regions.lock().unwrap();
+        if regions.len() >= MAX_BUCKET_REGIONS && !regions.contains_key(bucket) {
+            regions.clear();
+        }
+        regions.insert(bucket.to_owned(), region);
+    }
+}
+
+/// The region of the bucket an S3 response redirects a request sent to `region` to, from the
+/// `x-amz-bucket-region` header of redirects, or from `AuthorizationHeaderMalformed` errors.
+pub(crate) async fn redirect_region(
+    response: &mut HttpResponse,
+    region: &Region,
+) -> Option<Region> {
+    let name = match response.status.as_u16() {
+        301 | 307 => response.headers.get("x-amz-bucket-region").cloned(),
+        400 => {
+            // the bodies of errors are buffered already
+            let buffered = response.buffer().await.ok()?;
+            let name = malformed_authorization_region(&buffered);
+            *response = buffered.into();
+            name
+        }
+        _ => None,
+    }?;
+    if name == region.name() {
+        return None;
+    }
+    name.parse().ok()
+}
+
+/// The region an `AuthorizationHeaderMalformed` error expects requests to be signed for.
+fn malformed_authorization_region(response: &BufferedHttpResponse) -> Option<String> {
+    let body = std::str::from_utf8(&response.body).ok()?;
+    if !body.contains("<Code>AuthorizationHeaderMalformed</Code>") {
+        return None;
+    }
+    if let Some(name) = response.headers.get("x-amz-bucket-region") {
+        return Some(name.to_owned());
+    }
+    let start = body.find("<Region>")? + "<Region>".len();
+    let end = start + body[start..].find("</Region>")?;
+    Some(body[start..end].trim().to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::{HeaderMap, StatusCode};
+
+    fn response(status: u16, region: Option<&str>, body: &str) -> HttpResponse {
+        let mut headers = HeaderMap::<String>::default();
+        if let Some(region) = region {
+            headers.insert("x-amz-bucket-region", region.to_owned());
+        }
+        HttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: body.as_bytes().to_vec().into(),
+            headers,
+        }
+    }
+
+    #[tokio::test]
+    async fn redirect_regions() {
+        let region = Region::UsEast1;
+        let mut redirect = response(301, Some("eu-west-1"), "");
+        assert_eq!(
+            redirect_region(&mut redirect, &region).await,
+            Some(Region::EuWest1)
+        );
+        let mut redirect = response(307, Some("us-east-1"), "");
+        assert_eq!(redirect_region(&mut redirect, &region).await, None);
+        let mut redirect = response(301, None, "");
+        assert_eq!(redirect_region(&mut redirect, &region).await, None);
+
+        let malformed = "<Error><Code>AuthorizationHeaderMalformed</Code>\
+                         <Message>The authorization header is malformed</Message>\
+                         <Region>ap-south-1</Region></Error>";
+        let mut error = response(400, None, malformed);
+        assert_eq!(
+            redirect_region(&mut error, &region).await,
+            Some(Region::ApSouth1)
+        );
+        // the body is kept to be parsed as the error of the operation
+        let body = error.buffer().await.unwrap().body;
+        assert_eq!(&body[..], malformed.as_bytes());
+
+        let mut error = response(
+            400,
+            Some("eu-west-1"),
+            "<Error><Code>InvalidArgument</Code>",
+        );
+        assert_eq!(redirect_region(&mut error, &region).await, None);
+    }
+
+    #[test]
+    fn bucket_regions() {
+        let regions = BucketRegions::default();
+        regions.insert("bucket", Region::EuWest1);
+        assert_eq!(regions.get("bucket"), Some(Region::EuWest1));
+        assert_eq!(regions.get("other"), None);
+        for index in 0..MAX_BUCKET_REGIONS {
+            regions.insert(&format!("bucket{}", index), Region::UsWest2);
+        }
+        assert_eq!(regions.get("bucket"), None);
+    }
+}
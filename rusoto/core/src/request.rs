@@ -10,11 +10,13 @@ use std::io;
 use std::io::Error as IoError;
 use std::pin::Pin;
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-use bytes::{Bytes, BytesMut};
-use futures::{FutureExt, StreamExt};
+use bytes::Bytes;
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use futures::{stream, FutureExt, Stream, StreamExt};
 use http::header::{HeaderName, HeaderValue};
 use http::{HeaderMap, Method, StatusCode};
 use hyper::client::connect::Connect;
@@ -28,10 +30,19 @@ use tokio::time;
 use log::Level::Debug;
 use log::*;
 
+use crate::certificates::{self, CertificatePins};
+use crate::compression;
+use crate::dispatcher::{HttpDispatcher, HttpRequest};
+use crate::error;
+use crate::proxy::{ProxyConfig, ProxyConnector};
+use crate::resolver::{Resolve, Resolver};
 use crate::signature::SignedRequest;
 use crate::stream::ByteStream;
 use crate::tls::HttpsConnector;
 
+/// How long the bodies of requests sent with `Expect: 100-continue` are held back by default.
+const DEFAULT_EXPECT_CONTINUE_TIMEOUT: Duration = Duration::from_secs(1);
+
 // Pulls in the statically generated rustc version.
 include!(concat!(env!("OUT_DIR"), "/user_agent_vars.rs"));
 
@@ -44,6 +55,8 @@ lazy_static! {
         RUST_VERSION,
         env::consts::OS
     );
+    /// The client returned by `HttpClient::shared`, created on first use.
+    static ref SHARED_HTTP_CLIENT: Mutex<Option<HttpClient>> = Mutex::new(None);
 }
 
 /// Stores the response from a HTTP request.
@@ -56,6 +69,42 @@ pub struct HttpResponse {
     pub headers: HeaderMap<String>,
 }
 
+/// Whether a `Client` reads the bodies of responses into memory before returning them, see
+/// `ClientConfig::set_response_buffering`.
+///
+/// Buffered bodies are read as part of the attempt, so a connection which breaks while the
+/// body is read fails the attempt, and is retried like other dispatch errors, rather than
+/// failing the read of the body later on. The connection is returned to the pool right away
+/// as well. Streamed bodies are read by the caller, so large responses never have to fit in
+/// memory at once; operations whose outputs aren't streams still read them into memory to
+/// deserialize them, without copying bodies which were already buffered.
+///
+/// The bodies of error responses are always buffered, to classify them for retries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResponseBuffering {
+    /// Bodies are streamed to the caller. This is the default.
+    #[default]
+    Stream,
+    /// Bodies are buffered.
+    Buffer,
+    /// Bodies with a `Content-Length` of at most this many bytes are buffered, larger ones and
+    /// those of unknown length are streamed.
+    Threshold(usize),
+}
+
+impl ResponseBuffering {
+    /// Whether the body of the response is buffered.
+    pub(crate) fn buffers(self, response: &HttpResponse) -> bool {
+        match self {
+            ResponseBuffering::Stream => false,
+            ResponseBuffering::Buffer => true,
+            ResponseBuffering::Threshold(threshold) => {
+                matches!(response.body.size_hint(), Some(length) if length <= threshold)
+            }
+        }
+    }
+}
+
 /// Stores the buffered response from a HTTP request.
 #[derive(PartialEq)]
 pub struct BufferedHttpResponse {
@@ -68,6 +117,27 @@ pub struct BufferedHttpResponse {
 }
 
 impl BufferedHttpResponse {
+    /// The ID AWS assigned to the request, from the `x-amzn-RequestId` header of the response,
+    /// the `x-amz-request-id` header of S3 responses or the `<RequestId>` of XML error
+    /// responses.
+    pub fn request_id(&self) -> Option<&str> {
+        error::request_id(&self.headers).or_else(|| self.xml_element("RequestId"))
+    }
+
+    /// The extended request ID S3 assigned to the request, from the `x-amz-id-2` header or the
+    /// `<HostId>` of the response.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        error::extended_request_id(&self.headers).or_else(|| self.xml_element("HostId"))
+    }
+
+    fn xml_element(&self, name: &str) -> Option<&str> {
+        if self.status.is_success() {
+            return None;
+        }
+        let body = std::str::from_utf8(&self.body).ok()?;
+        error::xml_element(body, name)
+    }
+
     ///! Best effort to turn response body into more readable &str.
     pub fn body_as_str(&self) -> &str {
         match std::str::from_utf8(&self.body) {
@@ -95,20 +165,42 @@ impl fmt::Debug for BufferedHttpResponse {
     }
 }
 
+impl From<BufferedHttpResponse> for HttpResponse {
+    fn from(response: BufferedHttpResponse) -> HttpResponse {
+        HttpResponse {
+            status: response.status,
+            body: ByteStream::from(response.body),
+            headers: response.headers,
+        }
+    }
+}
+
 impl HttpResponse {
+    /// The ID AWS assigned to the request, from the `x-amzn-RequestId` header of the response
+    /// or the `x-amz-request-id` header of S3 responses.
+    pub fn request_id(&self) -> Option<&str> {
+        error::request_id(&self.headers)
+    }
+
+    /// The extended request ID S3 assigned to the request, from the `x-amz-id-2` header.
+    pub fn extended_request_id(&self) -> Option<&str> {
+        error::extended_request_id(&self.headers)
+    }
+
     /// Buffer the full response body in memory, resulting in a `BufferedHttpResponse`.
+    ///
+    /// The memory for the body is allocated up front when its length is known, and bodies the
+    /// client already buffered, see `ResponseBuffering`, aren't copied.
     pub async fn buffer(&mut self) -> Result<BufferedHttpResponse, HttpDispatchError> {
-        let mut bytes = BytesMut::new();
-        while let Some(try_chunk) = self.body.next().await {
-            let chunk = try_chunk.map_err(|e| HttpDispatchError {
-                message: format!("Error obtaining body: {}", e),
-            })?;
-            bytes.extend(chunk);
-        }
+        let body = std::mem::replace(&mut self.body, ByteStream::from(Bytes::new()));
+        let body = body.collect().await.map_err(|e| HttpDispatchError {
+            message: format!("Error obtaining body: {}", e),
+            connect: false,
+        })?;
         Ok(BufferedHttpResponse {
             status: self.status,
             headers: self.headers.clone(),
-            body: bytes.freeze(),
+            body,
         })
     }
 
@@ -133,22 +225,54 @@ impl HttpResponse {
 
         HttpResponse {
             status,
+            body: with_content_length(body, &headers),
             headers,
-            body: ByteStream::new(body),
         }
     }
 }
 
+/// Creates the body of a response, with its `Content-Length` as the size hint.
+pub(crate) fn with_content_length<S>(body: S, headers: &HeaderMap<String>) -> ByteStream
+where
+    S: futures::Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static,
+{
+    match headers
+        .get("content-length")
+        .and_then(|length| length.parse().ok())
+    {
+        Some(length) => ByteStream::new_with_size(body, length),
+        None => ByteStream::new(body),
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 /// An error produced when sending the request, such as a timeout error.
 pub struct HttpDispatchError {
     message: String,
+    connect: bool,
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError {
+            message,
+            connect: false,
+        }
+    }
+
+    /// An error establishing the connection to the endpoint, see `is_connect`.
+    pub(crate) fn new_connect(message: String) -> HttpDispatchError {
+        HttpDispatchError {
+            message,
+            connect: true,
+        }
+    }
+
+    /// Whether the connection to the endpoint couldn't be established, e.g. because its
+    /// hostname didn't resolve or it refused the connection, so the request wasn't sent.
+    pub fn is_connect(&self) -> bool {
+        self.connect
     }
 }
 
@@ -164,6 +288,7 @@ impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
         HttpDispatchError {
             message: err.to_string(),
+            connect: err.is_connect(),
         }
     }
 }
@@ -172,6 +297,7 @@ impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
         HttpDispatchError {
             message: err.to_string(),
+            connect: false,
         }
     }
 }
@@ -190,7 +316,7 @@ pub trait DispatchSignedRequest {
     ) -> DispatchSignedRequestFuture;
 }
 
-impl<D: DispatchSignedRequest> DispatchSignedRequest for Rc<D> {
+impl<D: DispatchSignedRequest + ?Sized> DispatchSignedRequest for Rc<D> {
     fn dispatch(
         &self,
         request: SignedRequest,
@@ -200,7 +326,7 @@ impl<D: DispatchSignedRequest> DispatchSignedRequest for Rc<D> {
     }
 }
 
-impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
+impl<D: DispatchSignedRequest + ?Sized> DispatchSignedRequest for Arc<D> {
     fn dispatch(
         &self,
         request: SignedRequest,
@@ -211,32 +337,119 @@ impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
 }
 
 /// Http client for use with AWS services.
-pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
+#[derive(Clone)]
+pub struct HttpClient<C = HttpsConnector<ProxyConnector<HttpConnector<Resolver>>>> {
     inner: HyperClient<C, Body>,
     local_agent: Option<String>,
+    proxy: Option<Arc<ProxyConfig>>,
+    decompress_responses: bool,
+    expect_continue_timeout: Duration,
 }
 
 impl HttpClient {
     /// Create a tls-enabled http client.
+    ///
+    /// Requests are sent through the proxies configured with the `HTTP_PROXY`, `HTTPS_PROXY`
+    /// and `NO_PROXY` environment variables, see `ProxyConfig::from_env`.
     pub fn new() -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new();
+        Self::new_with_config(HttpConfig::new())
+    }
 
-        Ok(Self::from_connector(connector))
+    /// Return the shared tls-enabled http client, creating it on first use.
+    ///
+    /// Creating an `HttpClient` loads the root certificates and sets up TLS, so the shared
+    /// client is used by `Client::shared` and the credentials providers which call STS, unless
+    /// they're given a dispatcher. Its clones share its TLS configuration and connection pool.
+    /// It's configured like the client returned by `new`, with the proxies configured in the
+    /// environment when it's created.
+    ///
+    /// Connections are driven by the runtime they were opened on. Those opened on a runtime
+    /// which was shut down are closed, and discarded rather than reused.
+    pub fn shared() -> Result<Self, TlsError> {
+        let mut shared = SHARED_HTTP_CLIENT.lock().unwrap();
+        if let Some(ref client) = *shared {
+            return Ok(client.clone());
+        }
+        let client = Self::new()?;
+        *shared = Some(client.clone());
+        Ok(client)
     }
 
     /// Create a tls-enabled http client.
-    pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
+    ///
+    /// Requests are sent through the proxies set with `HttpConfig::proxy`, or the ones
+    /// configured in the environment. Host names are resolved with the resolver set with
+    /// `HttpConfig::resolver`, or the one of the system.
+    ///
+    /// Fails if the root certificates added to the config are invalid, or if certificates are
+    /// pinned without the `rustls` feature.
+    pub fn new_with_config(mut config: HttpConfig) -> Result<Self, TlsError> {
+        let mut root_certificates = Vec::new();
+        for pem in &config.root_certificates {
+            root_certificates.extend(certificates::parse_pem(pem)?);
+        }
+        let proxy = config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        let mut http = HttpConnector::new_with_resolver(config.resolver.take().unwrap_or_default());
+        http.set_keepalive(config.tcp_keepalive);
+        // the TLS connector passes on https URIs
+        http.enforce_http(false);
+        let proxy_connector = ProxyConnector::new(http, proxy);
+        let proxy = Arc::new(proxy_connector.config().clone());
+
+        #[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+        let connector = {
+            // native-tls doesn't expose the certificates of the server to verify them
+            if !config.pins.is_empty() {
+                return Err(TlsError::new(
+                    "Pinning certificates requires the rustls feature",
+                ));
+            }
+            let mut tls = native_tls_crate::TlsConnector::builder();
+            for der in &root_certificates {
+                let certificate = native_tls_crate::Certificate::from_der(der)
+                    .map_err(|e| TlsError::new(format!("Invalid root certificate: {}", e)))?;
+                tls.add_root_certificate(certificate);
+            }
+            let tls = tls
+                .build()
+                .map_err(|e| TlsError::new(format!("Couldn't create the TLS connector: {}", e)))?;
+            HttpsConnector::from((proxy_connector, tokio_tls::TlsConnector::from(tls)))
+        };
 
         #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new();
+        let connector = {
+            let mut tls = rustls_crate::ClientConfig::new();
+            // HTTP/2 is used with endpoints supporting it, unless disabled
+            if config.http1_only {
+                tls.set_protocols(&[b"http/1.1".to_vec()]);
+            } else if config.http2_only {
+                tls.set_protocols(&[b"h2".to_vec()]);
+            } else {
+                tls.set_protocols(&[b"h2".to_vec(), b"http/1.1".to_vec()]);
+            }
+            tls.root_store = rustls_native_certs::load_native_certs().map_err(|(_, e)| {
+                TlsError::new(format!("Couldn't load the native root certificates: {}", e))
+            })?;
+            for der in root_certificates {
+                tls.root_store
+                    .add(&rustls_crate::Certificate(der))
+                    .map_err(|e| TlsError::new(format!("Invalid root certificate: {:?}", e)))?;
+            }
+            if !config.pins.is_empty() {
+                tls.dangerous()
+                    .set_certificate_verifier(Arc::new(certificates::PinningVerifier {
+                        inner: rustls_crate::WebPKIVerifier::new(),
+                        pins: config.pins.clone(),
+                    }));
+            }
+            HttpsConnector::from((proxy_connector, tls))
+        };
 
-        Ok(Self::from_connector_with_config(connector, config))
+        let mut client = Self::from_connector_with_config(connector, config);
+        if !proxy.is_empty() {
+            client.proxy = Some(proxy);
+        }
+        Ok(client)
     }
 
     /// Sets a local agent that is prepended to the default HTTP
@@ -256,6 +469,9 @@ where
         HttpClient {
             inner,
             local_agent: None,
+            proxy: None,
+            decompress_responses: true,
+            expect_continue_timeout: DEFAULT_EXPECT_CONTINUE_TIMEOUT,
         }
     }
 
@@ -266,11 +482,23 @@ where
         config
             .read_buf_size
             .map(|sz| builder.http1_read_buf_exact_size(sz));
+        if let Some(timeout) = config.pool_idle_timeout {
+            builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max_idle) = config.pool_max_idle_per_host {
+            builder.pool_max_idle_per_host(max_idle);
+        }
+        if config.http2_only && !config.http1_only {
+            builder.http2_only(true);
+        }
         let inner = builder.build(connector);
 
         HttpClient {
             inner,
             local_agent: None,
+            proxy: None,
+            decompress_responses: config.decompress_responses,
+            expect_continue_timeout: config.expect_continue_timeout,
         }
     }
 
@@ -280,6 +508,9 @@ where
         HttpClient {
             inner,
             local_agent: None,
+            proxy: None,
+            decompress_responses: true,
+            expect_continue_timeout: DEFAULT_EXPECT_CONTINUE_TIMEOUT,
         }
     }
 }
@@ -287,6 +518,17 @@ where
 /// Configuration options for the HTTP Client
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
+    proxy: Option<ProxyConfig>,
+    root_certificates: Vec<Vec<u8>>,
+    pins: CertificatePins,
+    resolver: Option<Resolver>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    pool_max_idle_per_host: Option<usize>,
+    tcp_keepalive: Option<Duration>,
+    http1_only: bool,
+    http2_only: bool,
+    decompress_responses: bool,
+    expect_continue_timeout: Duration,
 }
 
 impl HttpConfig {
@@ -294,6 +536,17 @@ impl HttpConfig {
     pub fn new() -> HttpConfig {
         HttpConfig {
             read_buf_size: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            pins: CertificatePins::default(),
+            resolver: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            http1_only: false,
+            http2_only: false,
+            decompress_responses: true,
+            expect_continue_timeout: DEFAULT_EXPECT_CONTINUE_TIMEOUT,
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -302,6 +555,84 @@ impl HttpConfig {
     pub fn read_buf_size(&mut self, sz: usize) {
         self.read_buf_size = Some(sz);
     }
+    /// Sets the proxies requests are sent through, instead of the ones configured
+    /// in the environment. Only used by `HttpClient::new_with_config`.
+    pub fn proxy(&mut self, proxy: ProxyConfig) {
+        self.proxy = Some(proxy);
+    }
+    /// Trusts the PEM encoded root certificates, like a CA bundle, besides the ones
+    /// of the platform, e.g. for TLS-intercepting proxies or private CAs.
+    /// Only used by `HttpClient::new_with_config`.
+    pub fn add_root_certificate(&mut self, pem: &[u8]) {
+        self.root_certificates.push(pem.to_vec());
+    }
+    /// Pins the certificate with the given SHA-256 digest of its DER encoding.
+    /// Once certificates or public keys are pinned, servers must present a matching
+    /// one in their certificate chain, on top of it being trusted.
+    /// Requires the `rustls` feature.
+    pub fn pin_certificate(&mut self, sha256: [u8; 32]) {
+        self.pins.pin_certificate(sha256);
+    }
+    /// Pins the public key with the given SHA-256 digest of its DER encoded
+    /// SubjectPublicKeyInfo, like HTTP public key pinning. See `pin_certificate`.
+    /// Requires the `rustls` feature.
+    pub fn pin_public_key(&mut self, sha256: [u8; 32]) {
+        self.pins.pin_public_key(sha256);
+    }
+    /// Sets the resolver of the host names of endpoints and HTTP proxies, instead of
+    /// the one of the system, e.g. `StaticResolver` for static host mappings.
+    /// Only used by `HttpClient::new_with_config`.
+    pub fn resolver<R: Resolve + 'static>(&mut self, resolver: R) {
+        self.resolver = Some(Resolver::new(resolver));
+    }
+    /// Sets how long idle connections are kept in the pool, 90 seconds by default.
+    /// `None` keeps them until the server closes them.
+    pub fn pool_idle_timeout(&mut self, timeout: Option<Duration>) {
+        self.pool_idle_timeout = Some(timeout);
+    }
+    /// Sets the maximum number of idle connections kept in the pool per host,
+    /// unlimited by default.
+    pub fn pool_max_idle_per_host(&mut self, max_idle: usize) {
+        self.pool_max_idle_per_host = Some(max_idle);
+    }
+    /// Enables TCP keepalive on connections, probing idle connections after the
+    /// given interval. Disabled by default.
+    /// Only used by `HttpClient::new_with_config`.
+    pub fn tcp_keepalive(&mut self, interval: Duration) {
+        self.tcp_keepalive = Some(interval);
+    }
+    /// Only sends requests with HTTP/1.1, even to endpoints supporting HTTP/2.
+    ///
+    /// By default, HTTP/2 is used with the endpoints negotiating it with ALPN when
+    /// using the `rustls` feature. The `native-tls` feature only supports HTTP/1.1,
+    /// unless `http2_only` is set.
+    pub fn http1_only(&mut self, enabled: bool) {
+        self.http1_only = enabled;
+    }
+    /// Only sends requests with HTTP/2, also to endpoints which don't negotiate it,
+    /// e.g. for event stream APIs with the `native-tls` feature.
+    /// Ignored when `http1_only` is set.
+    pub fn http2_only(&mut self, enabled: bool) {
+        self.http2_only = enabled;
+    }
+    /// Asks services for gzip compressed responses with `Accept-Encoding: gzip`, and
+    /// decompresses them, which saves bandwidth with large responses like those of
+    /// `DescribeInstances`. Enabled by default.
+    ///
+    /// Responses of S3 and Glacier are never decompressed, since they carry objects as
+    /// they were stored, nor responses to requests setting `Accept-Encoding` themselves.
+    pub fn decompress_responses(&mut self, enabled: bool) {
+        self.decompress_responses = enabled;
+    }
+    /// Sets how long the body of a request sent with `Expect: 100-continue` is held back,
+    /// so a server rejecting the request can answer before the body is sent. The body is
+    /// dropped if the response arrives first. 1 second by default, like curl.
+    ///
+    /// hyper doesn't pass on the interim `100 Continue` response, so the body is only sent
+    /// once the timeout passed, even if the server asked for it sooner.
+    pub fn expect_continue_timeout(&mut self, timeout: Duration) {
+        self.expect_continue_timeout = timeout;
+    }
 }
 
 impl Default for HttpConfig {
@@ -311,16 +642,26 @@ impl Default for HttpConfig {
     }
 }
 
-async fn http_client_dispatch<'a, C>(
-    client: HyperClient<C, Body>,
+/// The `User-Agent` of requests which don't set one themselves.
+pub(crate) fn user_agent(local_agent: Option<&str>, request: &SignedRequest) -> HeaderValue {
+    let mut user_agent = local_agent
+        .map(|agent| format!("{} {}", agent, *DEFAULT_USER_AGENT))
+        .unwrap_or_else(|| DEFAULT_USER_AGENT.clone());
+    for token in &request.user_agent_tokens {
+        user_agent.push(' ');
+        user_agent.push_str(token);
+    }
+    user_agent
+        .parse()
+        .expect("failed to parse user-agent string")
+}
+
+/// Translates a signed request into the HTTP request sent for it.
+pub(crate) fn http_request(
     request: SignedRequest,
-    timeout: Option<Duration>,
     user_agent: HeaderValue,
-) -> Result<HttpResponse, HttpDispatchError>
-where
-    C: Connect + Send + Sync + Clone + 'static,
-{
-    let hyper_method = match request.method().as_ref() {
+) -> Result<HttpRequest, HttpDispatchError> {
+    let method = match request.method().as_ref() {
         "POST" => Method::POST,
         "PUT" => Method::PUT,
         "DELETE" => Method::DELETE,
@@ -329,18 +670,19 @@ where
         v => {
             return Err(HttpDispatchError {
                 message: format!("Unsupported HTTP verb {}", v),
+                connect: false,
             });
         }
     };
 
-    // translate the headers map to a format Hyper likes
-    let mut hyper_headers = HeaderMap::new();
+    let mut headers = HeaderMap::new();
     for h in request.headers().iter() {
         let header_name = match h.0.parse::<HeaderName>() {
             Ok(name) => name,
             Err(err) => {
                 return Err(HttpDispatchError {
                     message: format!("error parsing header name: {}", err),
+                    connect: false,
                 });
             }
         };
@@ -350,16 +692,17 @@ where
                 Err(err) => {
                     return Err(HttpDispatchError {
                         message: format!("error parsing header value: {}", err),
+                        connect: false,
                     });
                 }
             };
-            hyper_headers.append(&header_name, header_value);
+            headers.append(&header_name, header_value);
         }
     }
 
     // Add a default user-agent header if one is not already present.
-    if !hyper_headers.contains_key("user-agent") {
-        hyper_headers.insert("user-agent", user_agent);
+    if !headers.contains_key("user-agent") {
+        headers.insert("user-agent", user_agent);
     }
 
     let mut final_uri = format!(
@@ -371,32 +714,130 @@ where
     if !request.canonical_query_string().is_empty() {
         final_uri = final_uri + &format!("?{}", request.canonical_query_string());
     }
+    let uri = final_uri.parse().map_err(|err| HttpDispatchError {
+        message: format!("error building request: {}", err),
+        connect: false,
+    })?;
+
+    Ok(HttpRequest {
+        method,
+        uri,
+        headers,
+        body: request.payload,
+    })
+}
+
+async fn http_client_dispatch<C>(
+    client: HyperClient<C, Body>,
+    request: SignedRequest,
+    timeout: Option<Duration>,
+    user_agent: HeaderValue,
+    proxy: Option<Arc<ProxyConfig>>,
+    decompress_responses: bool,
+    expect_continue_timeout: Duration,
+) -> Result<HttpResponse, HttpDispatchError>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    let decompress = decompress_responses && compression::accepts_compressed_response(&request);
+    let mut http_request = http_request(request, user_agent)?;
+    if decompress {
+        http_request
+            .headers
+            .insert("accept-encoding", HeaderValue::from_static("gzip"));
+    }
+
+    let response = hyper_dispatch(
+        client,
+        http_request,
+        timeout,
+        proxy,
+        expect_continue_timeout,
+    )
+    .await?;
+    if decompress {
+        Ok(compression::decompress_response(response))
+    } else {
+        Ok(response)
+    }
+}
+
+async fn hyper_dispatch<C>(
+    client: HyperClient<C, Body>,
+    request: HttpRequest,
+    timeout: Option<Duration>,
+    proxy: Option<Arc<ProxyConfig>>,
+    expect_continue_timeout: Duration,
+) -> Result<HttpResponse, HttpDispatchError>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    let HttpRequest {
+        method,
+        uri,
+        mut headers,
+        body,
+    } = request;
+
+    // requests sent to a proxy rather than tunneled through it are authenticated per request
+    if let Some(proxy) = proxy {
+        if let Some(authorization) = proxy.forward_authorization(&uri) {
+            headers.insert("proxy-authorization", authorization.clone());
+        }
+    }
 
     if log_enabled!(Debug) {
         debug!(
             "Full request: \n method: {}\n final_uri: {}\nHeaders:\n",
-            hyper_method, final_uri
+            method, uri
         );
-        for (h, v) in hyper_headers.iter() {
+        for (h, v) in headers.iter() {
             debug!("{}:{:?}", h.as_str(), v);
         }
     }
 
-    let http_request_builder = HyperRequest::builder().method(hyper_method).uri(final_uri);
-
-    let try_http_request = if let Some(p) = request.payload {
-        http_request_builder.body(p.into_body())
+    let expects_continue = body.is_some()
+        && matches!(headers.get("expect"), Some(expect)
+            if expect.as_bytes().eq_ignore_ascii_case(b"100-continue"));
+    let (release, held_back) = if expects_continue {
+        let (release, held_back) = oneshot::channel();
+        (Some(release), Some(held_back))
     } else {
-        http_request_builder.body(Body::empty())
+        (None, None)
+    };
+
+    let http_request_builder = HyperRequest::builder().method(method).uri(uri);
+
+    let try_http_request = match (body, held_back) {
+        (Some(p), Some(held_back)) => {
+            http_request_builder.body(Body::wrap_stream(held_back_body(held_back, p.into_body())))
+        }
+        (Some(p), None) => http_request_builder.body(p.into_body()),
+        (None, _) => http_request_builder.body(Body::empty()),
     };
 
     let mut http_request = try_http_request.map_err(|err| HttpDispatchError {
         message: format!("error building request: {}", err),
+        connect: false,
     })?;
 
-    *http_request.headers_mut() = hyper_headers;
+    *http_request.headers_mut() = headers;
 
     let f = client.request(http_request);
+    let f = async move {
+        let release = match release {
+            Some(release) => release,
+            None => return f.await,
+        };
+        // the body is dropped along with `release` if the server answers first
+        match future::select(f, time::delay_for(expect_continue_timeout)).await {
+            Either::Left((response, _)) => response,
+            Either::Right(((), f)) => {
+                let _ = release.send(());
+                f.await
+            }
+        }
+    };
 
     let try_resp = match timeout {
         None => f.await,
@@ -404,6 +845,7 @@ where
             Err(_e) => {
                 return Err(HttpDispatchError {
                     message: "Timeout while dispatching request".to_owned(),
+                    connect: false,
                 })
             }
             Ok(try_req) => try_req,
@@ -411,10 +853,25 @@ where
     };
     let resp = try_resp.map_err(|e| HttpDispatchError {
         message: format!("Error during dispatch: {}", e),
+        connect: e.is_connect(),
     })?;
     Ok(HttpResponse::from_hyper(resp).await)
 }
 
+/// Holds back the body until it's released, or ends it if it's never released.
+fn held_back_body(
+    release: oneshot::Receiver<()>,
+    body: Body,
+) -> impl Stream<Item = Result<Bytes, HyperError>> + Send + 'static {
+    stream::once(async move {
+        match release.await {
+            Ok(()) => body.left_stream(),
+            Err(_) => stream::empty().right_stream(),
+        }
+    })
+    .flatten()
+}
+
 impl<C> DispatchSignedRequest for HttpClient<C>
 where
     C: Connect + Clone + Send + Sync + 'static,
@@ -424,14 +881,34 @@ where
         request: SignedRequest,
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture {
-        let user_agent = self
-            .local_agent
-            .as_ref()
-            .map(|agent| format!("{} {}", agent, *DEFAULT_USER_AGENT).parse())
-            .unwrap_or_else(|| DEFAULT_USER_AGENT.parse())
-            .expect("failed to parse user-agent string");
+        let user_agent = user_agent(self.local_agent.as_deref(), &request);
+        http_client_dispatch::<C>(
+            self.inner.clone(),
+            request,
+            timeout,
+            user_agent,
+            self.proxy.clone(),
+            self.decompress_responses,
+            self.expect_continue_timeout,
+        )
+        .boxed()
+    }
+}
 
-        http_client_dispatch::<C>(self.inner.clone(), request, timeout, user_agent).boxed()
+/// Sends requests with hyper, without translating signed requests or decompressing responses.
+impl<C> HttpDispatcher for HttpClient<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn send(&self, request: HttpRequest, timeout: Option<Duration>) -> DispatchSignedRequestFuture {
+        hyper_dispatch::<C>(
+            self.inner.clone(),
+            request,
+            timeout,
+            self.proxy.clone(),
+            self.expect_continue_timeout,
+        )
+        .boxed()
     }
 }
 
@@ -441,6 +918,14 @@ pub struct TlsError {
     message: String,
 }
 
+impl TlsError {
+    pub(crate) fn new<S: Into<String>>(message: S) -> TlsError {
+        TlsError {
+            message: message.into(),
+        }
+    }
+}
+
 impl Error for TlsError {}
 
 impl fmt::Display for TlsError {
@@ -462,6 +947,322 @@ mod tests {
         is_send_and_sync::<HttpClient>();
     }
 
+    #[tokio::test]
+    async fn http_client_with_resolver() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut listener =
+            tokio::net::TcpListener::bind(std::net::SocketAddr::from(([127, 0, 0, 1], 0)))
+                .await
+                .unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 1024];
+            let read = stream.read(&mut request).await.unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8(request[..read].to_vec()).unwrap()
+        });
+
+        let mut resolver = crate::StaticResolver::new();
+        resolver.insert("sqs.rusoto.test", "127.0.0.1".parse().unwrap());
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.resolver(resolver);
+        let client = HttpClient::new_with_config(config).unwrap();
+
+        let region = Region::Custom {
+            endpoint: format!("http://sqs.rusoto.test:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        let request = SignedRequest::new("GET", "sqs", &region, "/");
+        let response = client.dispatch(request, None).await.ok().unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        let request = server.await.unwrap();
+        assert!(request.contains(&format!("host: sqs.rusoto.test:{}", port)));
+    }
+
+    #[tokio::test]
+    async fn expect_continue() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        /// Reads the head of a request, and whatever part of the body came with it.
+        async fn read_head(stream: &mut tokio::net::TcpStream) -> String {
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+            String::from_utf8(request).unwrap()
+        }
+
+        let mut listener =
+            tokio::net::TcpListener::bind(std::net::SocketAddr::from(([127, 0, 0, 1], 0)))
+                .await
+                .unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            // the first upload is rejected before its body is sent
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let rejected = read_head(&mut stream).await;
+            stream
+                .write_all(
+                    b"HTTP/1.1 403 Forbidden\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                )
+                .await
+                .unwrap();
+
+            // the second one is accepted
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut accepted = read_head(&mut stream).await;
+            stream
+                .write_all(b"HTTP/1.1 100 Continue\r\n\r\n")
+                .await
+                .unwrap();
+            while !accepted.ends_with("body") {
+                let mut buf = [0; 1024];
+                let read = stream.read(&mut buf).await.unwrap();
+                accepted.push_str(std::str::from_utf8(&buf[..read]).unwrap());
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            (rejected, accepted)
+        });
+
+        let region = Region::Custom {
+            endpoint: format!("http://127.0.0.1:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        let upload = || {
+            let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+            request.add_header("Expect", "100-continue");
+            request.add_header("Content-Length", "4");
+            request.set_payload(Some(b"body".to_vec()));
+            request
+        };
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.expect_continue_timeout(Duration::from_secs(60));
+        let client = HttpClient::new_with_config(config).unwrap();
+        let response = time::timeout(Duration::from_secs(10), client.dispatch(upload(), None))
+            .await
+            .unwrap()
+            .ok()
+            .unwrap();
+        assert_eq!(response.status, StatusCode::FORBIDDEN);
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.expect_continue_timeout(Duration::from_millis(10));
+        let client = HttpClient::new_with_config(config).unwrap();
+        let response = client.dispatch(upload(), None).await.ok().unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        let (rejected, accepted) = server.await.unwrap();
+        assert!(rejected.contains("expect: 100-continue"));
+        assert!(rejected.ends_with("\r\n\r\n"));
+        assert!(accepted.ends_with("\r\n\r\nbody"));
+    }
+
+    #[tokio::test]
+    async fn shared_http_client() {
+        use hyper::service::{make_service_fn, service_fn};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = connections.clone();
+        let make_service = make_service_fn(move |_| {
+            accepted.fetch_add(1, Ordering::SeqCst);
+            async {
+                Ok::<_, HyperError>(service_fn(|_: HyperRequest<Body>| async {
+                    Ok::<_, HyperError>(HyperResponse::new(Body::empty()))
+                }))
+            }
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let port = server.local_addr().port();
+        tokio::spawn(server);
+
+        let region = Region::Custom {
+            endpoint: format!("http://127.0.0.1:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        for _ in 0..2 {
+            let client = HttpClient::shared().unwrap();
+            let request = SignedRequest::new("GET", "s3", &region, "/");
+            let mut response = client.dispatch(request, None).await.ok().unwrap();
+            assert_eq!(response.status, StatusCode::OK);
+            response.buffer().await.ok().unwrap();
+        }
+        // the second client reused the connection of the first
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn http_client_with_http2_only() {
+        use hyper::service::{make_service_fn, service_fn};
+
+        let (versions, mut received) = futures::channel::mpsc::unbounded();
+        let make_service = make_service_fn(move |_| {
+            let versions = versions.clone();
+            async move {
+                Ok::<_, HyperError>(service_fn(move |request: HyperRequest<Body>| {
+                    versions.unbounded_send(request.version()).unwrap();
+                    async { Ok::<_, HyperError>(HyperResponse::new(Body::empty())) }
+                }))
+            }
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into())
+            .http2_only(true)
+            .serve(make_service);
+        let port = server.local_addr().port();
+        tokio::spawn(server);
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.http2_only(true);
+        let client = HttpClient::new_with_config(config).unwrap();
+        let region = Region::Custom {
+            endpoint: format!("http://localhost:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        let request = SignedRequest::new("GET", "kinesis", &region, "/");
+        let response = client.dispatch(request, None).await.ok().unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(received.next().await, Some(http::Version::HTTP_2));
+    }
+
+    #[tokio::test]
+    async fn http_client_decompresses_responses() {
+        use flate2::{write::GzEncoder, Compression};
+        use hyper::service::{make_service_fn, service_fn};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"<DescribeInstancesResponse/>").unwrap();
+        let compressed = Bytes::from(encoder.finish().unwrap());
+        let make_service = make_service_fn(move |_| {
+            let compressed = compressed.clone();
+            async move {
+                Ok::<_, HyperError>(service_fn(move |request: HyperRequest<Body>| {
+                    let gzip = request.headers().get("accept-encoding")
+                        == Some(&HeaderValue::from_static("gzip"));
+                    let response = HyperResponse::builder()
+                        .header("content-encoding", if gzip { "gzip" } else { "identity" })
+                        .body(Body::from(compressed.clone()));
+                    async { response }
+                }))
+            }
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let port = server.local_addr().port();
+        tokio::spawn(server);
+
+        let region = Region::Custom {
+            endpoint: format!("http://localhost:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        let client = HttpClient::new_with_config(config).unwrap();
+        let request = SignedRequest::new("POST", "ec2", &region, "/");
+        let mut response = client.dispatch(request, None).await.ok().unwrap();
+        assert!(!response.headers.contains_key("content-encoding"));
+        let body = response.buffer().await.ok().unwrap().body;
+        assert_eq!(&body[..], b"<DescribeInstancesResponse/>");
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.decompress_responses(false);
+        let client = HttpClient::new_with_config(config).unwrap();
+        let request = SignedRequest::new("POST", "ec2", &region, "/");
+        let response = client.dispatch(request, None).await.ok().unwrap();
+        assert_eq!(response.headers["content-encoding"], "identity");
+    }
+
+    #[tokio::test]
+    async fn http_client_user_agent() {
+        use hyper::service::{make_service_fn, service_fn};
+
+        let make_service = make_service_fn(|_| async {
+            Ok::<_, HyperError>(service_fn(|request: HyperRequest<Body>| {
+                let user_agent = request.headers()["user-agent"].clone();
+                async move {
+                    Ok::<_, HyperError>(HyperResponse::new(Body::from(
+                        user_agent.as_bytes().to_vec(),
+                    )))
+                }
+            }))
+        });
+        let server = hyper::Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let port = server.local_addr().port();
+        tokio::spawn(server);
+
+        let region = Region::Custom {
+            endpoint: format!("http://localhost:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        let mut client = HttpClient::new_with_config(config).unwrap();
+        client.local_agent("my-agent".to_owned());
+        let mut request = SignedRequest::new("GET", "s3", &region, "/");
+        request.add_user_agent_token("app/my service#1.2.3");
+        let mut response = client.dispatch(request, None).await.ok().unwrap();
+        let body = response.buffer().await.ok().unwrap().body;
+        assert_eq!(
+            String::from_utf8(body.to_vec()).unwrap(),
+            format!("my-agent {} app/my-service#1.2.3", *DEFAULT_USER_AGENT)
+        );
+    }
+
+    #[tokio::test]
+    async fn http_client_connection_refused() {
+        // nothing listens on the port once the listener is dropped
+        let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        let client = HttpClient::new_with_config(config).unwrap();
+        let region = Region::Custom {
+            endpoint: format!("http://127.0.0.1:{}", port),
+            name: "us-east-1".to_owned(),
+        };
+        let request = SignedRequest::new("POST", "sqs", &region, "/");
+        let error = client.dispatch(request, None).await.err().unwrap();
+        assert!(error.is_connect());
+        assert!(!HttpDispatchError::new("reset".to_owned()).is_connect());
+    }
+
+    #[test]
+    fn http_client_with_invalid_root_certificates() {
+        let mut config = HttpConfig::new();
+        config.add_root_certificate(b"not a certificate");
+        assert!(HttpClient::new_with_config(config).is_err());
+    }
+
+    #[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+    #[test]
+    fn pinning_requires_rustls() {
+        let mut config = HttpConfig::new();
+        config.pin_public_key([0; 32]);
+        assert_eq!(
+            HttpClient::new_with_config(config).err(),
+            Some(TlsError::new(
+                "Pinning certificates requires the rustls feature"
+            ))
+        );
+    }
+
     #[test]
     fn custom_region_http() {
         let a_region = Region::Custom {
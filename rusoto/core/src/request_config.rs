@@ -0,0 +1,364 @@
+//! Overriding the settings of a client for single requests.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use pin_project::pin_project;
+
+use crate::cancellation::CancellationToken;
+use crate::checksum::ChecksumMode;
+use crate::credential::{BoxedCredentialsProvider, ProvideAwsCredentials};
+use crate::endpoint::AddressingStyle;
+use crate::interceptor::{Interceptor, Interceptors};
+use crate::region::Region;
+use crate::request::ResponseBuffering;
+use crate::retry::RetryPolicy;
+use crate::signature::{ChecksumAlgorithm, PayloadSigning};
+
+thread_local! {
+    /// The settings of the `WithConfig` future being polled on this thread.
+    static CURRENT: RefCell<Option<Arc<RequestConfig>>> = const { RefCell::new(None) };
+}
+
+/// Settings overriding those of the client for the requests made by a future, see
+/// [`RequestConfigExt::with_config`](trait.RequestConfigExt.html#method.with_config).
+#[derive(Clone, Debug, Default)]
+pub struct RequestConfig {
+    pub(crate) region: Option<Region>,
+    pub(crate) endpoint: Option<String>,
+    pub(crate) credentials: Option<BoxedCredentialsProvider>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) operation_timeout: Option<Duration>,
+    pub(crate) attempt_timeout: Option<Duration>,
+    pub(crate) idempotent: Option<bool>,
+    pub(crate) cancellation_token: Option<CancellationToken>,
+    pub(crate) response_buffering: Option<ResponseBuffering>,
+    pub(crate) payload_signing: Option<PayloadSigning>,
+    pub(crate) addressing_style: Option<AddressingStyle>,
+    pub(crate) checksum_mode: Option<ChecksumMode>,
+    pub(crate) checksum_algorithm: Option<ChecksumAlgorithm>,
+    pub(crate) compute_content_md5: Option<bool>,
+    pub(crate) s3_use_accelerate_endpoint: Option<bool>,
+    pub(crate) requester_pays: Option<bool>,
+    pub(crate) unsigned: Option<bool>,
+    pub(crate) interceptors: Interceptors,
+}
+
+impl RequestConfig {
+    /// Set the region requests are sent to and signed for.
+    pub fn region(&mut self, region: Region) -> &mut RequestConfig {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the endpoint requests are sent to, like `http://localhost:8000`. Requests are still
+    /// signed for the region of the client, or the one set with `region`.
+    pub fn endpoint<S: Into<String>>(&mut self, endpoint: S) -> &mut RequestConfig {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set the provider of the credentials requests are signed with.
+    pub fn credentials<P>(&mut self, provider: P) -> &mut RequestConfig
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        self.credentials = Some(BoxedCredentialsProvider::new(provider));
+        self
+    }
+
+    /// Set the policy deciding whether and when failed requests are sent again.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut RequestConfig {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Set the maximum time a request may take, including all of its attempts.
+    pub fn operation_timeout(&mut self, timeout: Duration) -> &mut RequestConfig {
+        self.operation_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum time a single attempt of a request may take.
+    pub fn attempt_timeout(&mut self, timeout: Duration) -> &mut RequestConfig {
+        self.attempt_timeout = Some(timeout);
+        self
+    }
+
+    /// Set whether requests may be sent again after dispatch errors which leave it unknown
+    /// whether the service received them, like a reset connection or a timed out attempt.
+    ///
+    /// By default this is decided by the method and operation of each request, see
+    /// [`ErrorClass::Dispatch`](enum.ErrorClass.html#variant.Dispatch).
+    pub fn idempotent(&mut self, idempotent: bool) -> &mut RequestConfig {
+        self.idempotent = Some(idempotent);
+        self
+    }
+
+    /// Set a token cancelling the requests once it's cancelled, including the streaming of
+    /// their response bodies.
+    pub fn cancellation_token(&mut self, token: CancellationToken) -> &mut RequestConfig {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Set whether the bodies of the responses are read into memory before they're returned,
+    /// e.g. to stream the large responses of a single operation.
+    pub fn response_buffering(
+        &mut self,
+        response_buffering: ResponseBuffering,
+    ) -> &mut RequestConfig {
+        self.response_buffering = Some(response_buffering);
+        self
+    }
+
+    /// Set how the payloads of S3 requests are signed, e.g. to sign a single large upload
+    /// chunk by chunk, or not to hash its payload.
+    pub fn payload_signing(&mut self, payload_signing: PayloadSigning) -> &mut RequestConfig {
+        self.payload_signing = Some(payload_signing);
+        self
+    }
+
+    /// Set how the bucket of S3 requests is addressed, e.g. in the path for a bucket whose
+    /// name can't be part of a host name.
+    pub fn addressing_style(&mut self, addressing_style: AddressingStyle) -> &mut RequestConfig {
+        self.addressing_style = Some(addressing_style);
+        self
+    }
+
+    /// Set when the payloads of S3 requests and responses are checksummed, e.g. to validate
+    /// the checksum of a single download.
+    pub fn checksum_mode(&mut self, checksum_mode: ChecksumMode) -> &mut RequestConfig {
+        self.checksum_mode = Some(checksum_mode);
+        self
+    }
+
+    /// Set the algorithm of the checksums sent with S3 uploads.
+    pub fn checksum_algorithm(
+        &mut self,
+        checksum_algorithm: ChecksumAlgorithm,
+    ) -> &mut RequestConfig {
+        self.checksum_algorithm = Some(checksum_algorithm);
+        self
+    }
+
+    /// Set whether the `Content-MD5` header of S3 requests with a body is computed, e.g. for
+    /// a single upload to a bucket with Object Lock.
+    pub fn compute_content_md5(&mut self, compute_content_md5: bool) -> &mut RequestConfig {
+        self.compute_content_md5 = Some(compute_content_md5);
+        self
+    }
+
+    /// Set whether S3 requests are sent to the S3 Transfer Acceleration endpoint, e.g. for a
+    /// single large upload from far away.
+    pub fn s3_use_accelerate_endpoint(
+        &mut self,
+        s3_use_accelerate_endpoint: bool,
+    ) -> &mut RequestConfig {
+        self.s3_use_accelerate_endpoint = Some(s3_use_accelerate_endpoint);
+        self
+    }
+
+    /// Set whether requests are charged to the requester, e.g. to download a single object of
+    /// a requester pays bucket.
+    pub fn requester_pays(&mut self, requester_pays: bool) -> &mut RequestConfig {
+        self.requester_pays = Some(requester_pays);
+        self
+    }
+
+    /// Add an interceptor hooking into the requests, called after the interceptors of the
+    /// client, e.g. to add a parameter to a single request.
+    pub fn add_interceptor<I>(&mut self, interceptor: I) -> &mut RequestConfig
+    where
+        I: Interceptor + 'static,
+    {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Set whether requests are sent without signing them, even by clients which have a
+    /// credentials provider, e.g. to read from a public S3 bucket with the same client as
+    /// authenticated requests. Credentials aren't resolved for these requests either.
+    ///
+    /// Use `Client::new_not_signing`, or a client with an `AnonymousCredentialsProvider`, if
+    /// none of the requests of a client are signed.
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::signature::SignedRequest;
+    /// use rusoto_core::{Client, Region, RequestConfigExt};
+    ///
+    /// # async fn example() {
+    /// let client = Client::shared();
+    /// let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/public-bucket/key");
+    /// let response = client
+    ///     .sign_and_dispatch(request)
+    ///     .with_config(|config| {
+    ///         config.unsigned(true);
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn unsigned(&mut self, unsigned: bool) -> &mut RequestConfig {
+        self.unsigned = Some(unsigned);
+        self
+    }
+}
+
+/// Returns the settings of the future currently being polled, if it was configured.
+pub(crate) fn current() -> Option<Arc<RequestConfig>> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Extends futures, like those of service operations, with per-request settings.
+pub trait RequestConfigExt: Future + Sized {
+    /// Overrides the settings of the client for the requests made while this future runs.
+    ///
+    /// Only the settings of the innermost `with_config` apply when calls are nested. The settings
+    /// belong to the future rather than to the task running it, so they apply on any runtime,
+    /// but not to requests made by tasks the future spawns, or by the credentials providers of
+    /// the requests, like the `AssumeRole` requests of a provider assuming a role.
+    ///
+    /// This works with the futures of service operations as well, so a single client can make
+    /// requests to several regions, e.g.
+    /// `s3.get_object(request).with_config(|c| { c.region(Region::EuWest1); })`.
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// use rusoto_core::signature::SignedRequest;
+    /// use rusoto_core::{Client, Region, RequestConfigExt};
+    ///
+    /// # async fn example() {
+    /// let client = Client::shared();
+    /// let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+    /// let response = client
+    ///     .sign_and_dispatch(request)
+    ///     .with_config(|config| {
+    ///         config
+    ///             .region(Region::EuWest1)
+    ///             .operation_timeout(Duration::from_secs(5));
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    fn with_config<C>(self, configure: C) -> WithConfig<Self>
+    where
+        C: FnOnce(&mut RequestConfig),
+    {
+        let mut config = RequestConfig::default();
+        configure(&mut config);
+        WithConfig {
+            future: self,
+            config: Arc::new(config),
+        }
+    }
+}
+
+impl<F: Future> RequestConfigExt for F {}
+
+/// A future making requests with overridden settings, see
+/// [`RequestConfigExt::with_config`](trait.RequestConfigExt.html#method.with_config).
+#[pin_project]
+#[derive(Debug)]
+pub struct WithConfig<F> {
+    #[pin]
+    future: F,
+    config: Arc<RequestConfig>,
+}
+
+impl<F: Future> Future for WithConfig<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let this = self.project();
+        let outer = CURRENT.with(|current| current.replace(Some(this.config.clone())));
+        // restores the outer settings even if polling panics
+        let _reset = Reset(outer);
+        this.future.poll(cx)
+    }
+}
+
+/// Runs the future without the settings of the `WithConfig` future polling it, so they only
+/// apply to the request they were set for, e.g. and not to the requests a credentials provider
+/// makes to get its credentials.
+pub(crate) fn without_config<F: Future>(future: F) -> WithoutConfig<F> {
+    WithoutConfig { future }
+}
+
+/// A future running without the settings of the future polling it, see `without_config`.
+#[pin_project]
+pub(crate) struct WithoutConfig<F> {
+    #[pin]
+    future: F,
+}
+
+impl<F: Future> Future for WithoutConfig<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let outer = CURRENT.with(|current| current.replace(None));
+        let _reset = Reset(outer);
+        self.project().future.poll(cx)
+    }
+}
+
+struct Reset(Option<Arc<RequestConfig>>);
+
+impl Drop for Reset {
+    fn drop(&mut self) {
+        let outer = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = outer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn settings_apply_while_the_future_runs() {
+        assert!(current().is_none());
+        let timeout = async { current().and_then(|config| config.attempt_timeout) }
+            .with_config(|config| {
+                config.attempt_timeout(Duration::from_secs(1));
+            })
+            .await;
+        assert_eq!(timeout, Some(Duration::from_secs(1)));
+        assert!(current().is_none());
+
+        // the innermost settings apply
+        let timeouts = async {
+            let inner = async { current().unwrap().attempt_timeout }
+                .with_config(|config| {
+                    config.attempt_timeout(Duration::from_secs(2));
+                })
+                .await;
+            (inner, current().unwrap().attempt_timeout)
+        }
+        .with_config(|config| {
+            config.attempt_timeout(Duration::from_secs(1));
+        })
+        .await;
+        assert_eq!(
+            timeouts,
+            (Some(Duration::from_secs(2)), Some(Duration::from_secs(1)))
+        );
+    }
+
+    #[tokio::test]
+    async fn settings_dont_apply_without_config() {
+        let timeouts = async {
+            let inner = without_config(async { current().is_none() }).await;
+            (inner, current().unwrap().attempt_timeout)
+        }
+        .with_config(|config| {
+            config.attempt_timeout(Duration::from_secs(1));
+        })
+        .await;
+        assert_eq!(timeouts, (true, Some(Duration::from_secs(1))));
+    }
+}
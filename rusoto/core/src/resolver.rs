@@ -0,0 +1,203 @@
+//! Resolving the host names of endpoints.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use hyper::client::connect::dns::{GaiResolver, Name};
+use hyper::service::Service;
+
+/// Resolves the host names of the endpoints requests are sent to, see `HttpConfig::resolver`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io;
+/// use std::net::IpAddr;
+///
+/// use async_trait::async_trait;
+/// use rusoto_core::{Resolve, SystemResolver};
+///
+/// /// Sends requests to S3 to a local MinIO.
+/// struct LocalS3(SystemResolver);
+///
+/// #[async_trait]
+/// impl Resolve for LocalS3 {
+///     async fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+///         if host.ends_with(".amazonaws.com") && host.starts_with("s3.") {
+///             Ok(vec!["127.0.0.1".parse().unwrap()])
+///         } else {
+///             self.0.resolve(host).await
+///         }
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Resolve: Send + Sync {
+    /// Returns the addresses of the host.
+    async fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>>;
+}
+
+/// Resolves host names with the resolver of the system, calling `getaddrinfo` on a thread
+/// allowed to block. This is the default resolver.
+#[derive(Clone, Debug)]
+pub struct SystemResolver {
+    inner: GaiResolver,
+}
+
+impl SystemResolver {
+    /// Create a new `SystemResolver`.
+    pub fn new() -> SystemResolver {
+        SystemResolver {
+            inner: GaiResolver::new(),
+        }
+    }
+}
+
+impl Default for SystemResolver {
+    fn default() -> SystemResolver {
+        SystemResolver::new()
+    }
+}
+
+#[async_trait]
+impl Resolve for SystemResolver {
+    async fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        let name = host.parse::<Name>().map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid host name {}: {}", host, e),
+            )
+        })?;
+        Ok(self.inner.clone().call(name).await?.collect())
+    }
+}
+
+/// Resolves host names with static mappings, like `/etc/hosts`, and with the resolver of the
+/// system otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_core::{HttpClient, HttpConfig, StaticResolver};
+///
+/// let mut resolver = StaticResolver::new();
+/// resolver.insert("dynamodb.us-east-1.amazonaws.com", "10.0.0.12".parse().unwrap());
+///
+/// let mut config = HttpConfig::new();
+/// config.resolver(resolver);
+/// let client = HttpClient::new_with_config(config).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StaticResolver {
+    hosts: HashMap<String, Vec<IpAddr>>,
+    fallback: SystemResolver,
+}
+
+impl StaticResolver {
+    /// Create a new `StaticResolver` without mappings.
+    pub fn new() -> StaticResolver {
+        StaticResolver::default()
+    }
+
+    /// Resolve the host to the address. Hosts mapped to several addresses are resolved to all
+    /// of them, in the order they were added.
+    pub fn insert(&mut self, host: &str, address: IpAddr) {
+        self.hosts
+            .entry(host.to_lowercase())
+            .or_default()
+            .push(address);
+    }
+}
+
+#[async_trait]
+impl Resolve for StaticResolver {
+    async fn resolve(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        match self.hosts.get(&host.to_lowercase()) {
+            Some(addresses) => Ok(addresses.clone()),
+            None => self.fallback.resolve(host).await,
+        }
+    }
+}
+
+/// Resolves host names for the connector of an `HttpClient` with a `Resolve` implementation.
+#[derive(Clone)]
+pub struct Resolver {
+    inner: Arc<dyn Resolve>,
+}
+
+impl Resolver {
+    /// Create a new `Resolver` resolving host names with the given implementation.
+    pub fn new<R: Resolve + 'static>(resolver: R) -> Resolver {
+        Resolver {
+            inner: Arc::new(resolver),
+        }
+    }
+}
+
+impl Default for Resolver {
+    fn default() -> Resolver {
+        Resolver::new(SystemResolver::new())
+    }
+}
+
+impl fmt::Debug for Resolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Resolver")
+    }
+}
+
+impl Service<Name> for Resolver {
+    type Response = std::vec::IntoIter<IpAddr>;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<Self::Response>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        let resolver = self.inner.clone();
+        Box::pin(async move {
+            let addresses = resolver.resolve(name.as_str()).await?;
+            if addresses.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("No addresses found for {}", name),
+                ));
+            }
+            Ok(addresses.into_iter())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn static_mappings() {
+        let mut resolver = StaticResolver::new();
+        resolver.insert("S3.amazonaws.com", "10.0.0.1".parse().unwrap());
+        resolver.insert("s3.amazonaws.com", "10.0.0.2".parse().unwrap());
+
+        let addresses: Vec<IpAddr> = Resolver::new(resolver)
+            .call("s3.amazonaws.com".parse().unwrap())
+            .await
+            .unwrap()
+            .collect();
+        assert_eq!(
+            addresses,
+            vec![
+                "10.0.0.1".parse::<IpAddr>().unwrap(),
+                "10.0.0.2".parse().unwrap()
+            ]
+        );
+    }
+}
@@ -0,0 +1,548 @@
+//! Retrying requests which failed temporarily.
+
+use std::cmp;
+use std::fmt;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, StatusCode};
+use rand::Rng;
+
+use crate::client::SignAndDispatchError;
+use crate::credential::clock;
+use crate::error;
+use crate::request::{BufferedHttpResponse, HttpResponse, ResponseBuffering};
+use crate::signature::SignedRequest;
+
+/// Error codes of throttled requests, across services and protocols.
+const THROTTLING_ERROR_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "RequestLimitExceeded",
+    "BandwidthLimitExceeded",
+    "LimitExceededException",
+    "RequestThrottled",
+    "SlowDown",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+];
+
+/// Error codes of requests which failed temporarily, across services and protocols.
+const TRANSIENT_ERROR_CODES: &[&str] = &[
+    "RequestTimeout",
+    "RequestTimeoutException",
+    "InternalError",
+    "InternalFailure",
+    "ServiceUnavailable",
+];
+
+/// The kinds of failed attempts which a [`RetryPolicy`](struct.RetryPolicy.html) may retry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorClass {
+    /// The request couldn't be sent or no response was received, e.g. because the connection
+    /// was reset or timed out.
+    ///
+    /// Only connection failures, like DNS errors or refused connections, are classified this way
+    /// for every request. Other dispatch errors are only classified this way for idempotent
+    /// requests, since the service may have handled the request already. The requests of the
+    /// service clients are idempotent when the model of their operation tells so, when the
+    /// operation only reads, like `DescribeInstances` or `ListTables`, or when they carry an
+    /// idempotency token, like a `ClientToken`. Other requests are idempotent when their
+    /// method is, like `GET` or `PUT`. See `RequestConfig::idempotent` to override this.
+    Dispatch,
+    /// The service failed to handle the request, e.g. with a `500`, `502`, `503` or `504`
+    /// status or a `RequestTimeout` error.
+    Transient,
+    /// The service throttled the request, e.g. with a `429` status or a `ThrottlingException`,
+    /// `SlowDown` or `ProvisionedThroughputExceededException` error.
+    Throttling,
+}
+
+impl ErrorClass {
+    /// Classifies an error response by its error code, falling back to its status. Returns
+    /// `None` for successful responses and for errors which would fail the same way if the
+    /// request was sent again, like invalid requests.
+    ///
+    /// The error code is read from the `x-amzn-ErrorType` header, or from the body of JSON and
+    /// XML error responses.
+    pub fn from_response(response: &BufferedHttpResponse) -> Option<ErrorClass> {
+        error::parse_error(response)
+            .0
+            .and_then(|code| ErrorClass::from_code(&code))
+            .or_else(|| ErrorClass::from_status(response.status))
+    }
+
+    /// Classifies an error code, like `ThrottlingException`.
+    pub fn from_code(code: &str) -> Option<ErrorClass> {
+        if THROTTLING_ERROR_CODES.contains(&code) {
+            Some(ErrorClass::Throttling)
+        } else if TRANSIENT_ERROR_CODES.contains(&code) {
+            Some(ErrorClass::Transient)
+        } else {
+            None
+        }
+    }
+
+    /// Classifies the status of a response.
+    pub fn from_status(status: StatusCode) -> Option<ErrorClass> {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Some(ErrorClass::Throttling),
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => Some(ErrorClass::Transient),
+            _ => None,
+        }
+    }
+}
+
+/// Reads how long the service asks clients to wait before retrying from the
+/// `x-amz-retry-after` header, in milliseconds, or the `Retry-After` header, in seconds or as an
+/// HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap<String>) -> Option<Duration> {
+    if let Some(millis) = headers
+        .get("x-amz-retry-after")
+        .and_then(|millis| millis.trim().parse().ok())
+    {
+        return Some(Duration::from_millis(millis));
+    }
+    let retry_after = headers.get("retry-after")?.trim();
+    if let Ok(seconds) = retry_after.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(retry_after).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - clock::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// The outcome of an attempt, with the body of error responses buffered to classify them.
+pub(crate) struct Attempt {
+    pub(crate) result: Result<HttpResponse, SignAndDispatchError>,
+    pub(crate) class: Option<ErrorClass>,
+    pub(crate) retry_after: Option<Duration>,
+    /// The size of the response body, if known.
+    pub(crate) bytes_received: Option<u64>,
+}
+
+/// Whether sending a request more than once has the same effect as sending it once, so it can be
+/// sent again when it isn't known whether the service received it. The service clients set this
+/// from the models of their operations, other requests are judged by their method.
+pub(crate) fn is_idempotent(request: &SignedRequest) -> bool {
+    request.idempotent.unwrap_or_else(|| {
+        matches!(
+            request.method(),
+            "GET" | "HEAD" | "PUT" | "DELETE" | "OPTIONS"
+        )
+    })
+}
+
+/// Whether a request only reads. The service clients set this from the models of their
+/// operations, other requests are judged by their method.
+pub(crate) fn is_read_only(request: &SignedRequest) -> bool {
+    request
+        .read_only
+        .unwrap_or_else(|| matches!(request.method(), "GET" | "HEAD"))
+}
+
+impl Attempt {
+    /// Inspects the outcome of an attempt, buffering the body of the response if it's an error
+    /// or the `ResponseBuffering` asks for it. Dispatch errors other than connection failures
+    /// are only classified for idempotent requests.
+    pub(crate) async fn inspect(
+        result: Result<HttpResponse, SignAndDispatchError>,
+        idempotent: bool,
+        buffering: ResponseBuffering,
+    ) -> Attempt {
+        let mut attempt = Attempt {
+            result,
+            class: None,
+            retry_after: None,
+            bytes_received: None,
+        };
+        match attempt.result {
+            Ok(ref mut response)
+                if response.status.is_client_error()
+                    || response.status.is_server_error()
+                    || buffering.buffers(response) =>
+            {
+                match response.buffer().await {
+                    Ok(buffered) => {
+                        if buffered.status.is_client_error() || buffered.status.is_server_error() {
+                            attempt.class = ErrorClass::from_response(&buffered);
+                        }
+                        attempt.retry_after = retry_after(&buffered.headers);
+                        attempt.bytes_received = Some(buffered.body.len() as u64);
+                        attempt.result = Ok(buffered.into());
+                    }
+                    Err(e) => {
+                        if idempotent || e.is_connect() {
+                            attempt.class = Some(ErrorClass::Dispatch);
+                        }
+                        attempt.result = Err(SignAndDispatchError::Dispatch(e));
+                    }
+                }
+            }
+            Ok(ref response) => {
+                attempt.bytes_received = response
+                    .headers
+                    .get("content-length")
+                    .and_then(|length| length.parse().ok());
+            }
+            Err(SignAndDispatchError::Dispatch(ref e)) if idempotent || e.is_connect() => {
+                attempt.class = Some(ErrorClass::Dispatch)
+            }
+            // the service may have handled the request already
+            Err(SignAndDispatchError::Dispatch(_)) => {}
+            // would fail the same way again
+            Err(SignAndDispatchError::Credentials(_)) => {}
+        }
+        attempt
+    }
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ErrorClass::Dispatch => f.write_str("dispatch error"),
+            ErrorClass::Transient => f.write_str("transient error"),
+            ErrorClass::Throttling => f.write_str("throttling error"),
+        }
+    }
+}
+
+/// How a `Client` paces the requests it sends, see `ClientConfig::set_retry_mode`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RetryMode {
+    /// Failed requests are retried according to the `RetryPolicy`, requests are sent as soon
+    /// as they are made.
+    #[default]
+    Standard,
+    /// Like `Standard`, but once the service throttled a request, the client limits the rate at
+    /// which it sends requests. The rate drops with every throttled request and slowly grows
+    /// back while requests succeed, so requests wait on the client rather than being throttled
+    /// and retried when the service is throttling the client for a longer time.
+    ///
+    /// The rate is limited for all requests of a `Client` and its clones.
+    Adaptive,
+}
+
+/// Decides whether and when failed requests are sent again by a `Client`.
+///
+/// Requests are attempted up to 3 times by default. Before every retry, the client waits for a
+/// random delay between zero and an exponentially growing backoff ("full jitter"), starting
+/// at 100 milliseconds and capped at 20 seconds, so clients which failed at the same time
+/// don't retry at the same time.
+///
+/// When the service asks clients to wait for some time with a `Retry-After` or
+/// `x-amz-retry-after` header, the client waits for that time instead, up to the maximum
+/// backoff.
+///
+/// Requests with a streaming payload, like S3 uploads from a `ByteStream`, are never retried
+/// since their payload can only be sent once.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_core::request::HttpClient;
+/// use rusoto_core::credential::DefaultCredentialsProvider;
+/// use rusoto_core::{Client, ClientConfig, ErrorClass, RetryPolicy};
+///
+/// let mut retry_policy = RetryPolicy::new();
+/// retry_policy.set_max_attempts(5);
+/// retry_policy.set_max_backoff(Duration::from_secs(5));
+/// // fail fast instead of waiting for throttling to stop
+/// retry_policy.set_retry(ErrorClass::Throttling, false);
+///
+/// let mut config = ClientConfig::new();
+/// config.set_retry_policy(retry_policy);
+/// let client = Client::new_with_config(
+///     DefaultCredentialsProvider::new().unwrap(),
+///     HttpClient::new().unwrap(),
+///     config,
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retried: Vec<ErrorClass>,
+}
+
+impl RetryPolicy {
+    /// The number of attempts of the default policy.
+    pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+    /// Create the default `RetryPolicy`.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: RetryPolicy::DEFAULT_MAX_ATTEMPTS,
+            base_delay: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(20),
+            jitter: true,
+            retried: vec![
+                ErrorClass::Dispatch,
+                ErrorClass::Transient,
+                ErrorClass::Throttling,
+            ],
+        }
+    }
+
+    /// Create a `RetryPolicy` which sends every request only once.
+    pub fn no_retries() -> RetryPolicy {
+        let mut policy = RetryPolicy::new();
+        policy.set_max_attempts(1);
+        policy
+    }
+
+    /// Set how many times a request is sent at most, including the first attempt.
+    pub fn set_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = cmp::max(max_attempts, 1);
+    }
+
+    /// Set the backoff after the first attempt, which doubles with every further attempt.
+    pub fn set_base_delay(&mut self, base_delay: Duration) {
+        self.base_delay = base_delay;
+    }
+
+    /// Set the maximum backoff between two attempts.
+    pub fn set_max_backoff(&mut self, max_backoff: Duration) {
+        self.max_backoff = max_backoff;
+    }
+
+    /// Set whether the client waits for a random delay up to the backoff, rather than the
+    /// backoff itself. Enabled by default.
+    pub fn set_jitter(&mut self, jitter: bool) {
+        self.jitter = jitter;
+    }
+
+    /// Set whether attempts which failed with the given class of errors are retried. All
+    /// classes are retried by default.
+    pub fn set_retry(&mut self, class: ErrorClass, retry: bool) {
+        self.retried.retain(|retried| *retried != class);
+        if retry {
+            self.retried.push(class);
+        }
+    }
+
+    /// Get how many times a request is sent at most.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Whether attempts which failed with the given class of errors are retried.
+    pub fn retries(&self, class: ErrorClass) -> bool {
+        self.retried.contains(&class)
+    }
+
+    /// Returns how long to wait after the given failed attempt, starting at 1, given how long
+    /// the service asked to wait, if it did.
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        match retry_after {
+            Some(retry_after) => cmp::min(retry_after, self.max_backoff),
+            None => self.backoff(attempt),
+        }
+    }
+
+    /// Returns how long to wait after the given failed attempt, starting at 1.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = cmp::min(attempt.saturating_sub(1), 31);
+        let backoff = cmp::min(
+            self.base_delay
+                .checked_mul(1 << exponent)
+                .unwrap_or(self.max_backoff),
+            self.max_backoff,
+        );
+        if self.jitter {
+            backoff.mul_f64(rand::thread_rng().gen::<f64>())
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_cap() {
+        let mut policy = RetryPolicy::new();
+        policy.set_jitter(false);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1600));
+        assert_eq!(policy.backoff(10), Duration::from_secs(20));
+        assert_eq!(policy.backoff(100), Duration::from_secs(20));
+
+        policy.set_jitter(true);
+        for attempt in 1..10 {
+            assert!(policy.backoff(attempt) <= Duration::from_millis(100) * (1 << (attempt - 1)));
+        }
+    }
+
+    #[test]
+    fn error_classes() {
+        assert_eq!(
+            ErrorClass::from_status(StatusCode::TOO_MANY_REQUESTS),
+            Some(ErrorClass::Throttling)
+        );
+        assert_eq!(
+            ErrorClass::from_status(StatusCode::SERVICE_UNAVAILABLE),
+            Some(ErrorClass::Transient)
+        );
+        assert_eq!(ErrorClass::from_status(StatusCode::BAD_REQUEST), None);
+        assert_eq!(ErrorClass::from_status(StatusCode::OK), None);
+
+        let mut policy = RetryPolicy::new();
+        assert!(policy.retries(ErrorClass::Throttling));
+        policy.set_retry(ErrorClass::Throttling, false);
+        assert!(!policy.retries(ErrorClass::Throttling));
+        assert!(policy.retries(ErrorClass::Transient));
+        assert_eq!(RetryPolicy::no_retries().max_attempts(), 1);
+    }
+
+    #[test]
+    fn idempotent_requests() {
+        use crate::Region;
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(is_idempotent(&request));
+
+        let request = SignedRequest::new("POST", "lambda", &Region::UsEast1, "/functions/f");
+        assert!(!is_idempotent(&request));
+
+        let request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(is_idempotent(&request));
+        assert!(!is_read_only(&request));
+
+        // the service clients tell from the models of their operations
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.set_read_only(false);
+        request.set_idempotent(false);
+        assert!(!is_idempotent(&request));
+        assert!(!is_read_only(&request));
+        request.set_read_only(true);
+        request.set_idempotent(true);
+        assert!(is_idempotent(&request));
+        assert!(is_read_only(&request));
+
+        let mut request = SignedRequest::new("GET", "lambda", &Region::UsEast1, "/functions/f");
+        request.set_read_only(false);
+        request.set_idempotent(false);
+        assert!(!is_idempotent(&request));
+        assert!(!is_read_only(&request));
+    }
+
+    fn error_response(status: StatusCode, body: &str) -> BufferedHttpResponse {
+        BufferedHttpResponse {
+            status,
+            body: body.as_bytes().to_vec().into(),
+            headers: HeaderMap::default(),
+        }
+    }
+
+    #[test]
+    fn error_codes() {
+        let json = error_response(
+            StatusCode::BAD_REQUEST,
+            r#"{"__type":"com.amazon.coral.availability#ThrottlingException","message":"Rate exceeded"}"#,
+        );
+        assert_eq!(
+            ErrorClass::from_response(&json),
+            Some(ErrorClass::Throttling)
+        );
+
+        let mut rest_json = error_response(StatusCode::BAD_REQUEST, "{}");
+        rest_json.headers.insert(
+            "x-amzn-errortype",
+            "TooManyRequestsException:http://internal.amazon.com/coral/".to_owned(),
+        );
+        assert_eq!(
+            ErrorClass::from_response(&rest_json),
+            Some(ErrorClass::Throttling)
+        );
+
+        let xml = error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "<Response><Errors><Error><Code>RequestLimitExceeded</Code></Error></Errors></Response>",
+        );
+        assert_eq!(
+            ErrorClass::from_response(&xml),
+            Some(ErrorClass::Throttling)
+        );
+
+        let timeout = error_response(
+            StatusCode::BAD_REQUEST,
+            "<Error><Code>RequestTimeout</Code></Error>",
+        );
+        assert_eq!(
+            ErrorClass::from_response(&timeout),
+            Some(ErrorClass::Transient)
+        );
+
+        // terminal errors
+        let invalid = error_response(
+            StatusCode::BAD_REQUEST,
+            r#"{"__type":"ValidationException"}"#,
+        );
+        assert_eq!(ErrorClass::from_response(&invalid), None);
+
+        // the status is used without a known code
+        let unavailable = error_response(StatusCode::SERVICE_UNAVAILABLE, "unavailable");
+        assert_eq!(
+            ErrorClass::from_response(&unavailable),
+            Some(ErrorClass::Transient)
+        );
+    }
+
+    #[test]
+    fn retry_after_headers() {
+        let mut headers = HeaderMap::default();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert("retry-after", "2".to_owned());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+
+        headers.insert("x-amz-retry-after", "1500".to_owned());
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(1500)));
+
+        let mut headers = HeaderMap::default();
+        let date = clock::now() + chrono::Duration::seconds(30);
+        headers.insert("retry-after", date.to_rfc2822());
+        let delay = retry_after(&headers).unwrap();
+        assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30));
+
+        headers.insert("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT".to_owned());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(0)));
+
+        // the policy waits as long as asked, up to the maximum backoff
+        let mut policy = RetryPolicy::new();
+        policy.set_max_backoff(Duration::from_secs(10));
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(3))),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(60))),
+            Duration::from_secs(10)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Abstracting the timers of the async runtime requests are made on.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::{self, Either};
+
+/// A future completing after a delay, see `Runtime::sleep`.
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// The timers of the async runtime a `Client` runs on, used for the operation, attempt and
+/// credentials timeouts and to delay retries. See `ClientConfig::set_runtime`.
+///
+/// Clients use `TokioRuntime` by default. `AsyncStdRuntime` is available with the `async-std`
+/// feature, and other runtimes can be supported by implementing this trait. Note that
+/// `HttpClient` is built on hyper, which requires tokio; applications on another runtime
+/// dispatch requests with their own `DispatchSignedRequest` implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_core::{Runtime, Sleep};
+///
+/// struct SmolRuntime;
+///
+/// impl Runtime for SmolRuntime {
+///     fn sleep(&self, duration: Duration) -> Sleep {
+///         // e.g. `Box::pin(async move { smol::Timer::after(duration).await; })`
+///         # let _ = duration;
+///         Box::pin(async {})
+///     }
+/// }
+/// ```
+pub trait Runtime: Send + Sync {
+    /// Returns a future completing once the duration has passed.
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// The timers of the tokio runtime.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioRuntime;
+
+impl Runtime for TokioRuntime {
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(tokio::time::delay_for(duration))
+    }
+}
+
+/// The timers of the async-std runtime.
+#[cfg(feature = "async-std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncStdRuntime;
+
+#[cfg(feature = "async-std")]
+impl Runtime for AsyncStdRuntime {
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(async_std::task::sleep(duration))
+    }
+}
+
+/// The runtime of a client.
+#[derive(Clone)]
+pub(crate) struct RuntimeHandle(pub(crate) Arc<dyn Runtime>);
+
+impl RuntimeHandle {
+    pub(crate) fn sleep(&self, duration: Duration) -> Sleep {
+        self.0.sleep(duration)
+    }
+
+    /// Runs the future until it completes, or returns `None` once the duration has passed.
+    pub(crate) async fn timeout<F: Future>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> Option<F::Output> {
+        futures::pin_mut!(future);
+        match future::select(future, self.sleep(duration)).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+}
+
+impl Default for RuntimeHandle {
+    fn default() -> RuntimeHandle {
+        RuntimeHandle(Arc::new(TokioRuntime))
+    }
+}
+
+impl fmt::Debug for RuntimeHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Runtime")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn timeout() {
+        let runtime = RuntimeHandle::default();
+        assert_eq!(
+            runtime.timeout(Duration::from_secs(10), async { 1 }).await,
+            Some(1)
+        );
+        let never = future::pending::<()>();
+        assert_eq!(runtime.timeout(Duration::from_millis(1), never).await, None);
+    }
+}
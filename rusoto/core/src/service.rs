@@ -0,0 +1,150 @@
+//! Integrating clients and request dispatchers with tower services.
+
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::future;
+use tower_service::Service;
+
+use crate::client::{Client, SignAndDispatchError};
+use crate::request::{
+    DispatchSignedRequest, DispatchSignedRequestFuture, HttpClient, HttpDispatchError, HttpResponse,
+};
+use crate::signature::SignedRequest;
+
+/// A `Client` is a service fetching credentials, signing requests and dispatching them, so it
+/// can be wrapped in tower middleware like any other service.
+impl Service<SignedRequest> for Client {
+    type Response = HttpResponse;
+    type Error = SignAndDispatchError;
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, SignAndDispatchError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SignedRequest) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { client.sign_and_dispatch(request).await })
+    }
+}
+
+/// An `HttpClient` is a service dispatching signed requests, to build the tower stacks clients
+/// are created from with `Client::new_with_service`.
+impl<C> Service<SignedRequest> for HttpClient<C>
+where
+    HttpClient<C>: DispatchSignedRequest,
+{
+    type Response = HttpResponse;
+    type Error = HttpDispatchError;
+    type Future = DispatchSignedRequestFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SignedRequest) -> Self::Future {
+        self.dispatch(request, None)
+    }
+}
+
+/// Dispatches requests with a tower service, see `Client::new_with_service`.
+///
+/// The service is cloned for each request, so services which aren't `Clone`, or whose clones
+/// shouldn't be ready independently like those of a concurrency limit, are wrapped in a
+/// `tower::buffer::Buffer`. The timeout passed to `dispatch` is ignored; a
+/// `tower::timeout::Timeout` of the stack, or the timeouts of the `ClientConfig`, limit how long
+/// requests take instead.
+#[derive(Clone, Debug)]
+pub struct ServiceDispatcher<S> {
+    service: S,
+}
+
+impl<S> ServiceDispatcher<S> {
+    /// Create a `ServiceDispatcher` from a service.
+    pub fn new(service: S) -> ServiceDispatcher<S> {
+        ServiceDispatcher { service }
+    }
+}
+
+impl<S> DispatchSignedRequest for ServiceDispatcher<S>
+where
+    S: Service<SignedRequest, Response = HttpResponse> + Clone + Send + 'static,
+    S::Error: Into<Box<dyn Error + Send + Sync>>,
+    S::Future: Send,
+{
+    fn dispatch(
+        &self,
+        request: SignedRequest,
+        _timeout: Option<Duration>,
+    ) -> DispatchSignedRequestFuture {
+        let mut service = self.service.clone();
+        Box::pin(async move {
+            future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(dispatch_error)?;
+            service.call(request).await.map_err(dispatch_error)
+        })
+    }
+}
+
+/// Converts the errors of the middleware of a stack, e.g. of a `tower::timeout::Timeout`.
+fn dispatch_error<E: Into<Box<dyn Error + Send + Sync>>>(error: E) -> HttpDispatchError {
+    match error.into().downcast::<HttpDispatchError>() {
+        Ok(error) => *error,
+        Err(error) => HttpDispatchError::new(error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::StaticProvider;
+    use crate::Region;
+    use http::StatusCode;
+    use tower::{service_fn, ServiceBuilder, ServiceExt};
+
+    fn ok(request: SignedRequest) -> Result<HttpResponse, HttpDispatchError> {
+        assert!(request.headers().contains_key("authorization"));
+        Ok(HttpResponse {
+            status: StatusCode::OK,
+            body: Vec::new().into(),
+            headers: Default::default(),
+        })
+    }
+
+    #[tokio::test]
+    async fn client_from_service() {
+        let stack = ServiceBuilder::new()
+            .timeout(Duration::from_secs(10))
+            .service(service_fn(|request| future::ready(ok(request))));
+        let client = Client::new_with_service(
+            StaticProvider::new_minimal("key".to_owned(), "secret".to_owned()),
+            stack,
+        );
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let response = client.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+
+        // errors of the middleware fail the dispatch
+        let stack = ServiceBuilder::new()
+            .timeout(Duration::from_millis(1))
+            .service(service_fn(|_: SignedRequest| {
+                future::pending::<Result<HttpResponse, HttpDispatchError>>()
+            }));
+        let client = Client::new_with_service(
+            StaticProvider::new_minimal("key".to_owned(), "secret".to_owned()),
+            stack,
+        );
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        match client.sign_and_dispatch(request).await {
+            Err(SignAndDispatchError::Dispatch(error)) => {
+                assert_eq!(error.to_string(), "request timed out")
+            }
+            _ => panic!("the request should time out"),
+        }
+    }
+}
@@ -1,2 +1,4 @@
 // moved to rusoto_signature
+pub use rusoto_signature::checksum::{self, ChecksumAlgorithm};
+pub use rusoto_signature::post_policy;
 pub use rusoto_signature::signature::*;
@@ -0,0 +1,93 @@
+//! Signing the requests made by a client.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::credential::{Anonymous, AwsCredentials};
+use crate::signature::SignedRequest;
+
+/// Signs the requests of a `Client`, see `ClientConfig::set_signer`.
+///
+/// Requests are signed with AWS Signature Version 4 by default, see `SigV4Signer`. Other
+/// schemes, like bearer tokens, can be swapped in by implementing this trait, and signers
+/// wrapping another one can observe requests as they're signed, e.g. their canonical headers.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_core::credential::AwsCredentials;
+/// use rusoto_core::signature::SignedRequest;
+/// use rusoto_core::SignRequest;
+///
+/// /// Authorizes requests with a bearer token rather than signing them.
+/// struct BearerToken(String);
+///
+/// impl SignRequest for BearerToken {
+///     fn sign_request(&self, request: &mut SignedRequest, _credentials: Option<&AwsCredentials>) {
+///         request.complement();
+///         request.add_header("authorization", &format!("Bearer {}", self.0));
+///     }
+///
+///     fn uses_credentials(&self) -> bool {
+///         false
+///     }
+/// }
+/// ```
+pub trait SignRequest: Send + Sync {
+    /// Signs the request before every attempt, after `Interceptor::before_signing`.
+    ///
+    /// The credentials are those of the client, or of `RequestConfig::credentials`. There are
+    /// none for requests configured with `RequestConfig::unsigned`, for clients without a
+    /// credentials provider, and for signers which don't use credentials. Requests which aren't
+    /// signed still have to be completed with `SignedRequest::complement`.
+    fn sign_request(&self, request: &mut SignedRequest, credentials: Option<&AwsCredentials>);
+
+    /// Whether the credentials are loaded before signing requests, `true` by default.
+    fn uses_credentials(&self) -> bool {
+        true
+    }
+}
+
+/// Signs requests with AWS Signature Version 4, the default signer of clients. Requests made
+/// without credentials, or with anonymous ones, aren't signed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SigV4Signer;
+
+impl SignRequest for SigV4Signer {
+    fn sign_request(&self, request: &mut SignedRequest, credentials: Option<&AwsCredentials>) {
+        match credentials {
+            Some(credentials) if !credentials.is_anonymous() => request.sign(credentials),
+            _ => request.complement(),
+        }
+    }
+}
+
+/// Doesn't sign requests, e.g. to access public S3 buckets, without loading credentials.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AnonymousSigner;
+
+impl SignRequest for AnonymousSigner {
+    fn sign_request(&self, request: &mut SignedRequest, _credentials: Option<&AwsCredentials>) {
+        request.complement();
+    }
+
+    fn uses_credentials(&self) -> bool {
+        false
+    }
+}
+
+/// The signer of a client.
+#[derive(Clone)]
+pub(crate) struct Signer(pub(crate) Arc<dyn SignRequest>);
+
+impl Default for Signer {
+    fn default() -> Signer {
+        Signer(Arc::new(SigV4Signer))
+    }
+}
+
+impl fmt::Debug for Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SignRequest")
+    }
+}
@@ -0,0 +1,159 @@
+//! `tracing` spans of requests and their attempts, enabled by the `tracing` feature.
+//!
+//! Every request runs in a `rusoto_request` span with the `service`, `operation` and `region`
+//! of the request, and the `retries`, `status` and `request_id` of its last attempt. Every
+//! attempt runs in a nested `rusoto_attempt` span with the `attempt` number and the `status`
+//! and `request_id` of its response, or the `error` it failed with.
+
+use std::future::Future;
+
+use percent_encoding::percent_decode;
+
+use crate::request::{HttpDispatchError, HttpResponse};
+use crate::signature::{SignedRequest, SignedRequestPayload};
+
+/// Returns the parameters of a request with a form encoded body, like the requests of the
+/// query protocol.
+pub(crate) fn form_params(request: &SignedRequest) -> Vec<(String, String)> {
+    let form = request
+        .headers()
+        .get("content-type")
+        .filter(|content_type| content_type[0].starts_with(b"application/x-www-form-urlencoded"))
+        .is_some();
+    let body = match request.payload {
+        Some(SignedRequestPayload::Buffer(ref body)) if form => body,
+        _ => return Vec::new(),
+    };
+    let decode = |encoded: &[u8]| {
+        let encoded: Vec<u8> = encoded
+            .iter()
+            .map(|&byte| if byte == b'+' { b' ' } else { byte })
+            .collect();
+        percent_decode(&encoded).decode_utf8_lossy().into_owned()
+    };
+    body.split(|&byte| byte == b'&')
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let mut parts = param.splitn(2, |&byte| byte == b'=');
+            let name = decode(parts.next().unwrap_or_default());
+            let value = decode(parts.next().unwrap_or_default());
+            (name, value)
+        })
+        .collect()
+}
+
+/// Returns the id AWS assigned to the request of a response.
+#[cfg(feature = "tracing")]
+fn request_id(response: &HttpResponse) -> Option<&str> {
+    response
+        .headers
+        .get("x-amzn-requestid")
+        .or_else(|| response.headers.get("x-amz-request-id"))
+        .map(String::as_str)
+}
+
+/// The span of a request or attempt, which does nothing without the `tracing` feature.
+pub(crate) struct Span {
+    #[cfg(feature = "tracing")]
+    inner: tracing::Span,
+}
+
+impl Span {
+    /// Creates the span of a request.
+    pub(crate) fn request(_request: &SignedRequest) -> Span {
+        #[cfg(feature = "tracing")]
+        {
+            let inner = tracing::info_span!(
+                "rusoto_request",
+                service = %_request.service,
+                operation = tracing::field::Empty,
+                region = %_request.region.name(),
+                retries = tracing::field::Empty,
+                status = tracing::field::Empty,
+                request_id = tracing::field::Empty,
+            );
+            if let Some(operation) = _request.operation_name() {
+                inner.record("operation", operation);
+            }
+            Span { inner }
+        }
+        #[cfg(not(feature = "tracing"))]
+        Span {}
+    }
+
+    /// Creates the span of an attempt, starting at 1.
+    pub(crate) fn attempt(_attempt: u32) -> Span {
+        #[cfg(feature = "tracing")]
+        {
+            let inner = tracing::info_span!(
+                "rusoto_attempt",
+                attempt = _attempt,
+                status = tracing::field::Empty,
+                request_id = tracing::field::Empty,
+                error = tracing::field::Empty,
+            );
+            Span { inner }
+        }
+        #[cfg(not(feature = "tracing"))]
+        Span {}
+    }
+
+    /// Runs the future in the span.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn instrument<F: Future>(self, future: F) -> tracing::instrument::Instrumented<F> {
+        tracing::Instrument::instrument(future, self.inner)
+    }
+
+    /// Runs the future in the span.
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn instrument<F: Future>(self, future: F) -> F {
+        future
+    }
+}
+
+/// Records the number of retries of a request in the current span.
+pub(crate) fn record_retries(_retries: u32) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("retries", _retries);
+}
+
+/// Records the status and request id of a response in the current span.
+pub(crate) fn record_response(_response: &HttpResponse) {
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("status", _response.status.as_u16());
+        if let Some(request_id) = request_id(_response) {
+            span.record("request_id", request_id);
+        }
+    }
+}
+
+/// Records the error an attempt failed with in the current span.
+pub(crate) fn record_error(_error: &HttpDispatchError) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("error", tracing::field::display(_error));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Region;
+
+    #[test]
+    fn form_params() {
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_content_type("application/x-www-form-urlencoded".to_owned());
+        request.set_payload(Some(&b"Action=SendMessage&MessageBody=hello+world%21"[..]));
+        assert_eq!(
+            super::form_params(&request),
+            vec![
+                ("Action".to_owned(), "SendMessage".to_owned()),
+                ("MessageBody".to_owned(), "hello world!".to_owned()),
+            ]
+        );
+
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        assert!(super::form_params(&request).is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+//! The Credentials Provider for unauthenticated requests.
+
+use async_trait::async_trait;
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// Provides anonymous credentials, so requests are sent without being signed.
+///
+/// Some AWS services, like [s3](https://docs.aws.amazon.com/AmazonS3/latest/API/Welcome.html),
+/// allow anonymous access to public resources, e.g. the objects of a public bucket. Unlike
+/// `StaticProvider::new_minimal` with made-up keys, no credentials are resolved or sent.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_credential::{AnonymousCredentialsProvider, Anonymous, ProvideAwsCredentials};
+///
+/// # async fn example() {
+/// let credentials = AnonymousCredentialsProvider.credentials().await.unwrap();
+/// assert!(credentials.is_anonymous());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AnonymousCredentialsProvider;
+
+#[async_trait]
+impl ProvideAwsCredentials for AnonymousCredentialsProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        Ok(AwsCredentials::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Anonymous;
+
+    #[tokio::test]
+    async fn anonymous_credentials() {
+        let credentials = AnonymousCredentialsProvider.credentials().await.unwrap();
+        assert!(credentials.is_anonymous());
+        assert_eq!(credentials.token(), &None);
+        assert_eq!(credentials.expires_at(), &None);
+    }
+}
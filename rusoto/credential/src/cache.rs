@@ -0,0 +1,269 @@
+//! Storage of the credentials cached by wrapping providers.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::SecondsFormat;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::sync::Mutex;
+
+use crate::sso::parse_expires_at;
+use crate::{AwsCredentials, CredentialsError};
+
+/// A storage for credentials, used by wrapping providers like
+/// [`AutoRefreshingProvider`](struct.AutoRefreshingProvider.html) to keep the credentials of
+/// the wrapped provider until they expire.
+///
+/// Besides the [`MemoryCredentialsCache`](struct.MemoryCredentialsCache.html) used by default and
+/// the [`FileCredentialsCache`](struct.FileCredentialsCache.html), applications can implement
+/// this trait to share credentials between processes in other ways, e.g. in the keyring of the
+/// operating system.
+///
+/// The wrapping provider decides whether cached credentials are still valid; the cache only
+/// stores them.
+#[async_trait]
+pub trait CredentialsCache {
+    /// Returns the cached credentials, or `None` if there aren't any.
+    async fn load(&self) -> Result<Option<AwsCredentials>, CredentialsError>;
+
+    /// Replaces the cached credentials.
+    async fn store(&self, credentials: &AwsCredentials) -> Result<(), CredentialsError>;
+
+    /// Removes the cached credentials.
+    async fn clear(&self) -> Result<(), CredentialsError>;
+}
+
+#[async_trait]
+impl<C: CredentialsCache + Send + Sync> CredentialsCache for Arc<C> {
+    async fn load(&self) -> Result<Option<AwsCredentials>, CredentialsError> {
+        C::load(self).await
+    }
+
+    async fn store(&self, credentials: &AwsCredentials) -> Result<(), CredentialsError> {
+        C::store(self, credentials).await
+    }
+
+    async fn clear(&self) -> Result<(), CredentialsError> {
+        C::clear(self).await
+    }
+}
+
+/// Keeps credentials in memory. Clones of the cache share the same credentials.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCredentialsCache {
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl MemoryCredentialsCache {
+    /// Create a new, empty `MemoryCredentialsCache`.
+    pub fn new() -> MemoryCredentialsCache {
+        MemoryCredentialsCache::default()
+    }
+}
+
+#[async_trait]
+impl CredentialsCache for MemoryCredentialsCache {
+    async fn load(&self) -> Result<Option<AwsCredentials>, CredentialsError> {
+        Ok(self.credentials.lock().await.clone())
+    }
+
+    async fn store(&self, credentials: &AwsCredentials) -> Result<(), CredentialsError> {
+        *self.credentials.lock().await = Some(credentials.clone());
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), CredentialsError> {
+        *self.credentials.lock().await = None;
+        Ok(())
+    }
+}
+
+/// Keeps credentials in a JSON file, so they can be shared by processes running one after the
+/// other or at the same time. The file has the layout of the files of the AWS CLI's cache:
+///
+/// ```json
+/// {
+///   "Credentials": {
+///     "AccessKeyId": "an AWS access key",
+///     "SecretAccessKey": "your AWS secret access key",
+///     "SessionToken": "the AWS session token for temporary credentials",
+///     "Expiration": "ISO8601 timestamp when the credentials expire"
+///   }
+/// }
+/// ```
+///
+/// # Warning
+///
+/// The credentials aren't encrypted. The file is only readable by its owner on Unix systems,
+/// but anyone who can read it can use the credentials until they expire.
+#[derive(Clone, Debug)]
+pub struct FileCredentialsCache {
+    path: PathBuf,
+}
+
+impl FileCredentialsCache {
+    /// Create a new `FileCredentialsCache` storing the credentials in the given file. The
+    /// directories of the path are created when credentials are first stored.
+    pub fn new<P>(path: P) -> FileCredentialsCache
+    where
+        P: Into<PathBuf>,
+    {
+        FileCredentialsCache { path: path.into() }
+    }
+
+    /// Get the path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl CredentialsCache for FileCredentialsCache {
+    async fn load(&self) -> Result<Option<AwsCredentials>, CredentialsError> {
+        read_credentials_file(&self.path)
+    }
+
+    async fn store(&self, credentials: &AwsCredentials) -> Result<(), CredentialsError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        write_credentials_file(&self.path, credentials)
+    }
+
+    async fn clear(&self) -> Result<(), CredentialsError> {
+        match fs::remove_file(&self.path) {
+            Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            result => Ok(result?),
+        }
+    }
+}
+
+/// The parts of a cached STS response used by the file caches.
+#[derive(Deserialize)]
+struct CachedResponse {
+    #[serde(rename = "Credentials")]
+    credentials: CachedCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CachedCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    expiration: Option<String>,
+}
+
+/// Reads credentials in the layout of the AWS CLI's cache. Returns `None` if the file doesn't
+/// exist.
+pub(crate) fn read_credentials_file(
+    path: &Path,
+) -> Result<Option<AwsCredentials>, CredentialsError> {
+    let contents = match fs::read(path) {
+        Ok(contents) => contents,
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let cached: CachedResponse = serde_json::from_slice(&contents)?;
+    let expires_at = match cached.credentials.expiration {
+        Some(ref expiration) => Some(parse_expires_at(expiration)?),
+        None => None,
+    };
+    Ok(Some(AwsCredentials::new(
+        cached.credentials.access_key_id,
+        cached.credentials.secret_access_key,
+        cached.credentials.session_token,
+        expires_at,
+    )))
+}
+
+/// Writes credentials in the layout of the AWS CLI's cache, through a temporary file so other
+/// readers never see partial credentials.
+pub(crate) fn write_credentials_file(
+    path: &Path,
+    credentials: &AwsCredentials,
+) -> Result<(), CredentialsError> {
+    let cached = json!({
+        "Credentials": {
+            "AccessKeyId": credentials.aws_access_key_id(),
+            "SecretAccessKey": credentials.aws_secret_access_key(),
+            "SessionToken": credentials.token(),
+            "Expiration": credentials
+                .expires_at()
+                .map(|expires_at| expires_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        }
+    });
+    let tmp_path = path.with_extension("json.tmp");
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    std::io::Write::write_all(&mut options.open(&tmp_path)?, &serde_json::to_vec(&cached)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn memory_cache() {
+        let cache = MemoryCredentialsCache::new();
+        assert!(cache.load().await.unwrap().is_none());
+        cache
+            .store(&AwsCredentials::new("key", "secret", None, None))
+            .await
+            .unwrap();
+        let loaded = cache.clone().load().await.unwrap().expect("credentials");
+        assert_eq!(loaded.aws_access_key_id(), "key");
+        cache.clear().await.unwrap();
+        assert!(cache.load().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn file_cache() {
+        let dir = tempfile::tempdir().expect("temporary directory");
+        let cache = FileCredentialsCache::new(dir.path().join("nested").join("credentials.json"));
+        assert!(cache.load().await.unwrap().is_none());
+
+        let expires_at = Utc::now() + Duration::hours(1);
+        cache
+            .store(&AwsCredentials::new(
+                "key",
+                "secret",
+                Some("token".to_owned()),
+                Some(expires_at),
+            ))
+            .await
+            .unwrap();
+        let loaded = cache.load().await.unwrap().expect("credentials");
+        assert_eq!(loaded.aws_access_key_id(), "key");
+        assert_eq!(loaded.aws_secret_access_key(), "secret");
+        assert_eq!(loaded.token(), &Some("token".to_owned()));
+        assert_eq!(
+            loaded.expires_at().unwrap().timestamp(),
+            expires_at.timestamp()
+        );
+
+        cache
+            .store(&AwsCredentials::new("static_key", "secret", None, None))
+            .await
+            .unwrap();
+        let loaded = cache.load().await.unwrap().expect("credentials");
+        assert_eq!(loaded.aws_access_key_id(), "static_key");
+        assert_eq!(loaded.expires_at(), &None);
+
+        cache.clear().await.unwrap();
+        assert!(cache.load().await.unwrap().is_none());
+        cache.clear().await.unwrap();
+    }
+}
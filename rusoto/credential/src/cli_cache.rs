@@ -0,0 +1,258 @@
+//! A file cache of assumed role credentials shared with the AWS CLI.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Duration as ChronoDuration;
+use dirs::home_dir;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cache::{read_credentials_file, write_credentials_file};
+use crate::clock;
+use crate::{AwsCredentials, CredentialsError};
+
+/// Cached credentials are ignored when they expire within this many seconds, like the AWS CLI
+/// does.
+const EXPIRY_WINDOW_SECONDS: i64 = 900;
+
+/// A cache of temporary credentials in the format of the AWS CLI's `~/.aws/cli/cache`.
+///
+/// Every set of credentials is stored in its own JSON file, named after the SHA-1 hash of the
+/// arguments used to get them (see [`key`](#method.key)). Credentials cached by the AWS CLI for the
+/// same role can be reused, and the other way around, so short-lived processes don't have to
+/// call STS every time they run.
+///
+/// Cached credentials which expire within the next 15 minutes are ignored. Files which can't be
+/// read are treated like missing ones.
+///
+/// # Warning
+///
+/// The cached credentials aren't encrypted. The files are only readable by their owner on Unix
+/// systems, but anyone who can read them can use the credentials until they expire.
+#[derive(Clone, Debug, Default)]
+pub struct CliCache {
+    dir: Option<PathBuf>,
+}
+
+impl CliCache {
+    /// Create a new `CliCache` in `~/.aws/cli/cache`.
+    pub fn new() -> CliCache {
+        CliCache { dir: None }
+    }
+
+    /// Create a new `CliCache` in the given directory.
+    pub fn with_dir<D>(dir: D) -> CliCache
+    where
+        D: Into<PathBuf>,
+    {
+        CliCache {
+            dir: Some(dir.into()),
+        }
+    }
+
+    /// Get the directory the credentials are cached in.
+    pub fn dir(&self) -> Result<PathBuf, CredentialsError> {
+        match self.dir {
+            Some(ref dir) => Ok(dir.clone()),
+            None => match home_dir() {
+                Some(mut home_path) => {
+                    home_path.push(".aws");
+                    home_path.push("cli");
+                    home_path.push("cache");
+                    Ok(home_path)
+                }
+                None => Err(CredentialsError::new("Failed to determine home directory.")),
+            },
+        }
+    }
+
+    /// Returns the cache key of credentials requested with the given arguments.
+    ///
+    /// The arguments are the parameters of the STS request as a JSON object, using the names of
+    /// the STS API (`RoleArn`, `ExternalId`, `DurationSeconds`...). Like the AWS CLI, callers
+    /// should leave out `RoleSessionName` and the MFA token code, and pass policies as parsed
+    /// JSON. The key is the SHA-1 hash of the arguments serialized the way Python's
+    /// `json.dumps(args, sort_keys=True)` does.
+    pub fn key<A>(args: &A) -> Result<String, CredentialsError>
+    where
+        A: Serialize,
+    {
+        let mut serialized = String::new();
+        write_python_json(&serde_json::to_value(args)?, &mut serialized);
+        Ok(sha1::Sha1::from(serialized).digest().to_string())
+    }
+
+    /// Loads the credentials cached under the given key, unless they are missing or about to
+    /// expire.
+    pub fn load(&self, key: &str) -> Option<AwsCredentials> {
+        let credentials = read_credentials_file(&self.path(key).ok()?).ok()??;
+        match *credentials.expires_at() {
+            Some(expires_at)
+                if expires_at > clock::now() + ChronoDuration::seconds(EXPIRY_WINDOW_SECONDS) =>
+            {
+                Some(credentials)
+            }
+            _ => None,
+        }
+    }
+
+    /// Caches the credentials under the given key. Credentials which don't expire aren't cached.
+    pub fn store(&self, key: &str, credentials: &AwsCredentials) -> Result<(), CredentialsError> {
+        if credentials.expires_at().is_none() {
+            return Ok(());
+        }
+        fs::create_dir_all(self.dir()?)?;
+        write_credentials_file(&self.path(key)?, credentials)
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, CredentialsError> {
+        let mut path = self.dir()?;
+        path.push(format!("{}.json", key));
+        Ok(path)
+    }
+}
+
+/// Serializes the value like Python's `json.dumps(value, sort_keys=True)`, which the AWS CLI
+/// hashes to get cache keys.
+fn write_python_json(value: &Value, out: &mut String) {
+    match *value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if b { "true" } else { "false" }),
+        Value::Number(ref n) => out.push_str(&n.to_string()),
+        Value::String(ref s) => write_python_str(s, out),
+        Value::Array(ref values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_python_json(value, out);
+            }
+            out.push(']');
+        }
+        Value::Object(ref map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_python_str(key, out);
+                out.push_str(": ");
+                write_python_json(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Python escapes every character outside of printable ASCII by default.
+fn write_python_str(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            ' '..='~' => out.push(c),
+            _ => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    let _ = write!(out, "\\u{:04x}", unit);
+                }
+            }
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    #[test]
+    fn key_matches_aws_cli() {
+        assert_eq!(
+            CliCache::key(&json!({ "RoleArn": "arn:aws:iam::123456789012:role/test" })).unwrap(),
+            "9b413f63d088ca513edf4bf108e5aeb99bbc1763"
+        );
+        let args = json!({
+            "RoleArn": "arn:aws:iam::123456789012:role/test",
+            "DurationSeconds": 3600,
+            "SerialNumber": "arn:aws:iam::123456789012:mfa/user",
+            "PolicyArns": [{ "arn": "arn:aws:iam::aws:policy/ReadOnlyAccess" }],
+            "Policy": { "Version": "2012-10-17", "Statement": [] },
+            "ExternalId": "ext\u{e9}",
+        });
+        assert_eq!(
+            CliCache::key(&args).unwrap(),
+            "82082df6dd79c3d85bac8f61d181720275de8845"
+        );
+    }
+
+    #[test]
+    fn store_and_load() {
+        let dir = tempfile::tempdir().expect("temporary directory");
+        let cache = CliCache::with_dir(dir.path().join("cache"));
+        let credentials = AwsCredentials::new(
+            "key",
+            "secret",
+            Some("token".to_owned()),
+            Some(Utc::now() + ChronoDuration::hours(1)),
+        );
+        cache
+            .store("role", &credentials)
+            .expect("stored credentials");
+        let loaded = cache.load("role").expect("cached credentials");
+        assert_eq!(loaded.aws_access_key_id(), "key");
+        assert_eq!(loaded.aws_secret_access_key(), "secret");
+        assert_eq!(loaded.token(), &Some("token".to_owned()));
+        assert!(cache.load("other-role").is_none());
+
+        let expiring = AwsCredentials::new(
+            "key",
+            "secret",
+            None,
+            Some(Utc::now() + ChronoDuration::minutes(5)),
+        );
+        cache.store("role", &expiring).expect("stored credentials");
+        assert!(cache.load("role").is_none());
+    }
+
+    #[test]
+    fn load_aws_cli_file() {
+        let dir = tempfile::tempdir().expect("temporary directory");
+        fs::write(
+            dir.path().join("role.json"),
+            r#"{
+                "Credentials": {
+                    "AccessKeyId": "key",
+                    "SecretAccessKey": "secret",
+                    "SessionToken": "token",
+                    "Expiration": "2100-01-01T00:00:00UTC"
+                },
+                "AssumedRoleUser": {
+                    "AssumedRoleId": "AROAEXAMPLE:session",
+                    "Arn": "arn:aws:sts::123456789012:assumed-role/test/session"
+                }
+            }"#,
+        )
+        .expect("cache file");
+        let loaded = CliCache::with_dir(dir.path())
+            .load("role")
+            .expect("cached credentials");
+        assert_eq!(
+            loaded.expires_at(),
+            &Some("2100-01-01T00:00:00Z".parse().unwrap())
+        );
+    }
+}
@@ -0,0 +1,102 @@
+//! Correction of the local clock for the expiry of credentials.
+//!
+//! Credentials expire according to the clock of AWS. When the local clock is behind, credentials
+//! which already expired look valid and requests signed with them are rejected; when it is ahead,
+//! credentials are refreshed too early, or are even considered expired as soon as they are
+//! received. The time of AWS is learnt from the `Date` header of the responses of AWS services,
+//! including error responses like `RequestTimeTooSkewed`, which the `rusoto_core` client records
+//! with [`record_date_header`](fn.record_date_header.html).
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Differences between the local clock and the time of AWS smaller than this aren't recorded:
+/// `Date` headers only have a precision of one second, and responses take some time to arrive.
+const MIN_CLOCK_SKEW_CHANGE_SECONDS: i64 = 5;
+
+/// The time of AWS minus the local time, in seconds.
+static CLOCK_SKEW_SECONDS: AtomicI64 = AtomicI64::new(0);
+
+/// Returns the estimated difference between the time of AWS and the local clock, which is
+/// positive if the local clock is behind.
+pub fn clock_skew() -> Duration {
+    Duration::seconds(CLOCK_SKEW_SECONDS.load(Ordering::Relaxed))
+}
+
+/// Overrides the estimated difference between the time of AWS and the local clock.
+pub fn set_clock_skew(skew: Duration) {
+    CLOCK_SKEW_SECONDS.store(skew.num_seconds(), Ordering::Relaxed);
+}
+
+/// Returns the current time of AWS, as estimated from the local clock and the clock skew.
+pub fn now() -> DateTime<Utc> {
+    Utc::now() + clock_skew()
+}
+
+/// Records the current time of AWS, as returned by an AWS service.
+pub fn record_server_time(server_time: DateTime<Utc>) {
+    let current = CLOCK_SKEW_SECONDS.load(Ordering::Relaxed);
+    if let Some(skew) = updated_clock_skew(current, Utc::now(), server_time) {
+        CLOCK_SKEW_SECONDS.store(skew, Ordering::Relaxed);
+    }
+}
+
+/// Records the time of AWS from the value of the `Date` header of a response of an AWS service,
+/// e.g. `Wed, 21 Oct 2015 07:28:00 GMT`. Values which can't be parsed are ignored.
+pub fn record_date_header(date: &str) {
+    if let Ok(server_time) = DateTime::parse_from_rfc2822(date) {
+        record_server_time(server_time.with_timezone(&Utc));
+    }
+}
+
+/// Returns the new clock skew in seconds, if it differs enough from the current one.
+fn updated_clock_skew(
+    current: i64,
+    local_time: DateTime<Utc>,
+    server_time: DateTime<Utc>,
+) -> Option<i64> {
+    let skew = (server_time - local_time).num_seconds();
+    if (skew - current).abs() >= MIN_CLOCK_SKEW_CHANGE_SECONDS {
+        Some(skew)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_skew_changes() {
+        let local_time = Utc::now();
+        assert_eq!(
+            updated_clock_skew(0, local_time, local_time + Duration::seconds(2)),
+            None
+        );
+        assert_eq!(
+            updated_clock_skew(0, local_time, local_time + Duration::minutes(10)),
+            Some(600)
+        );
+        assert_eq!(
+            updated_clock_skew(600, local_time, local_time + Duration::seconds(598)),
+            None
+        );
+        assert_eq!(
+            updated_clock_skew(600, local_time, local_time - Duration::minutes(1)),
+            Some(-60)
+        );
+    }
+
+    #[test]
+    fn parse_date_header() {
+        let date = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT").unwrap();
+        assert_eq!(date.timestamp(), 1_445_412_480);
+        // the clock isn't skewed when the header matches the local time
+        let current = clock_skew();
+        record_date_header(&Utc::now().to_rfc2822());
+        record_date_header("not a date");
+        assert_eq!(clock_skew(), current);
+    }
+}
@@ -1,9 +1,10 @@
 //! The Credentials provider to read from a task's IAM Role.
 
+use std::net::IpAddr;
 use std::time::Duration;
 
 use async_trait::async_trait;
-use hyper::{Body, Request};
+use hyper::{Body, Request, Uri};
 
 use crate::request::HttpClient;
 use crate::{
@@ -19,6 +20,9 @@ const AWS_CONTAINER_CREDENTIALS_RELATIVE_URI: &str = "AWS_CONTAINER_CREDENTIALS_
 // environment variables, but they are used by the Java, Go, JavaScript and the Python SDKs.
 const AWS_CONTAINER_CREDENTIALS_FULL_URI: &str = "AWS_CONTAINER_CREDENTIALS_FULL_URI";
 const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN";
+const AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE";
+// Link-local addresses of the ECS and EKS credential endpoints which may be used with plain HTTP.
+const ALLOWED_LINK_LOCAL_HOSTS: &[&str] = &["169.254.170.2", "169.254.170.23", "fd00:ec2::23"];
 
 /// Provides AWS credentials from a task's IAM role.
 ///
@@ -31,8 +35,15 @@ const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOK
 /// URL stored in the environment variable ```AWS_CONTAINER_CREDENTIALS_RELATIVE_URI```, which will
 /// be used to obtain the AWS credentials. If that environment variable is not set, rusoto will use
 /// the URL set in environment variable ```AWS_CONTAINER_CREDENTIALS_FULL_URI``` to obtain AWS
-/// credentials and will (optionally) also set the ```Authorization``` header to the value of
-/// environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```.
+/// credentials and will (optionally) also set the ```Authorization``` header to the contents of
+/// the file named by environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE```, or to the
+/// value of environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```. The token file is read
+/// on every request, so the token may be rotated.
+///
+/// A full URI using plain HTTP has to point to a loopback address or to one of the ECS and EKS
+/// container credential endpoints, so that credentials are never sent to a remote host
+/// unencrypted. HTTPS URIs may point to any host, but require the `native-tls` or `rustls`
+/// feature.
 ///
 /// # Example
 ///
@@ -82,16 +93,16 @@ impl ProvideAwsCredentials for ContainerProvider {
                 err.to_string()
             ),
         })?;
-        let resp = self
-            .client
-            .request(req, self.timeout)
-            .await
-            .map_err(|err| CredentialsError {
-                message: format!(
-                    "Could not get credentials from container: {}",
-                    err.to_string()
-                ),
-            })?;
+        let resp = match req.uri().scheme_str() {
+            Some("https") => request_https(req, self.timeout).await,
+            _ => self.client.request(req, self.timeout).await,
+        };
+        let resp = resp.map_err(|err| CredentialsError {
+            message: format!(
+                "Could not get credentials from container: {}",
+                err.to_string()
+            ),
+        })?;
         parse_credentials_from_aws_service(&resp)
     }
 }
@@ -104,7 +115,8 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
         None => match non_empty_env_var(AWS_CONTAINER_CREDENTIALS_FULL_URI) {
             Some(ref uri) => {
                 let mut request = new_request(uri, AWS_CONTAINER_CREDENTIALS_FULL_URI)?;
-                if let Some(token) = non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN) {
+                validate_full_uri(request.uri())?;
+                if let Some(token) = authorization_token()? {
                     match token.parse() {
                         Ok(parsed_token) => {
                             request.headers_mut().insert("authorization", parsed_token);
@@ -127,6 +139,64 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
     }
 }
 
+/// The token file takes precedence over the token environment variable.
+fn authorization_token() -> Result<Option<String>, CredentialsError> {
+    match non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE) {
+        Some(path) => {
+            let token = std::fs::read_to_string(&path).map_err(|err| {
+                CredentialsError::new(format!(
+                    "failed to read token file '{}' from environment variable '{}': {}",
+                    path, AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, err
+                ))
+            })?;
+            Ok(Some(token.trim().to_owned()).filter(|token| !token.is_empty()))
+        }
+        None => Ok(non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN)),
+    }
+}
+
+/// Plain HTTP is only allowed for loopback addresses and the ECS and EKS credential endpoints.
+fn validate_full_uri(uri: &Uri) -> Result<(), CredentialsError> {
+    if uri.scheme_str() == Some("https") {
+        return Ok(());
+    }
+    let host = uri
+        .host()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let allowed = host == "localhost"
+        || ALLOWED_LINK_LOCAL_HOSTS
+            .iter()
+            .any(|allowed| host.parse::<IpAddr>().ok() == allowed.parse().ok())
+        || host
+            .parse::<IpAddr>()
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false);
+    if allowed {
+        Ok(())
+    } else {
+        Err(CredentialsError::new(format!(
+            "The host of '{}' in environment variable '{}' is not allowed, only loopback addresses \
+             and the ECS and EKS container credential endpoints may be used with plain HTTP",
+            uri, AWS_CONTAINER_CREDENTIALS_FULL_URI
+        )))
+    }
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+async fn request_https(req: Request<Body>, timeout: Duration) -> Result<String, std::io::Error> {
+    HttpClient::new_https().request(req, timeout).await
+}
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+async fn request_https(_req: Request<Body>, _timeout: Duration) -> Result<String, std::io::Error> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        "HTTPS requires the `native-tls` or `rustls` feature",
+    ))
+}
+
 fn new_request(uri: &str, env_var_name: &str) -> Result<Request<Body>, CredentialsError> {
     Request::get(uri).body(Body::empty()).map_err(|error| {
         CredentialsError::new(format!(
@@ -141,6 +211,7 @@ mod tests {
     use super::*;
     use crate::test_utils::lock_env;
     use std::env;
+    use std::io::Write;
 
     #[test]
     fn request_from_relative_uri() {
@@ -227,4 +298,53 @@ mod tests {
         assert_eq!(request.uri().to_string(), url);
         assert_eq!(request.headers().contains_key("authorization"), false);
     }
+
+    #[test]
+    fn request_from_full_uri_with_token_file() {
+        let url = "http://127.0.0.1/xxx";
+        let mut token_file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(token_file, "file-token").unwrap();
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
+        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE, token_file.path());
+        let result = request_from_env_vars();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+        let request = result.unwrap();
+        assert_eq!(request.headers()["authorization"], "file-token");
+    }
+
+    #[test]
+    fn error_from_missing_token_file() {
+        let _guard = lock_env();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
+        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "http://localhost/xxx");
+        env::set_var(
+            AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE,
+            "tests/sample-data/does_not_exist",
+        );
+        let result = request_from_env_vars();
+        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn full_uri_host_validation() {
+        let valid = |uri: &str| validate_full_uri(&uri.parse().unwrap()).is_ok();
+        assert!(valid("http://localhost/xxx"));
+        assert!(valid("http://127.0.0.1:8080/xxx"));
+        assert!(valid("http://127.1.2.3/xxx"));
+        assert!(valid("http://[::1]/xxx"));
+        assert!(valid("http://169.254.170.2/xxx"));
+        assert!(valid("http://169.254.170.23/v1/credentials"));
+        assert!(valid("http://[fd00:ec2::23]/v1/credentials"));
+        assert!(valid("https://credentials.example.com/xxx"));
+        assert!(!valid("http://credentials.example.com/xxx"));
+        assert!(!valid("http://169.254.169.254/xxx"));
+        assert!(!valid("http://10.0.0.1/xxx"));
+    }
 }
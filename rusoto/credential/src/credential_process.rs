@@ -0,0 +1,168 @@
+//! The Credentials Provider for credentials sourced from an external process.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::process::Command;
+
+use crate::profile::{parse_config_file, ProfileProvider};
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+const CREDENTIAL_PROCESS: &str = "credential_process";
+
+/// Provides AWS credentials by running an external command, as configured by the
+/// [`credential_process`][credential_process] setting of a profile.
+///
+/// The command has to print a JSON document like the following to its standard output:
+///
+/// ```json
+/// {
+///   "Version": 1,
+///   "AccessKeyId": "an AWS access key",
+///   "SecretAccessKey": "your AWS secret access key",
+///   "SessionToken": "the AWS session token for temporary credentials",
+///   "Expiration": "ISO8601 timestamp when the credentials expire"
+/// }
+/// ```
+///
+/// `SessionToken` and `Expiration` are optional. The command is split into arguments like a
+/// shell would do, but it isn't run through a shell.
+///
+/// # Warning
+///
+/// Sourcing credentials from an external process can potentially be dangerous, so proceed with
+/// caution. Other credential providers should be preferred if at all possible. If using this
+/// option, you should make sure that the config file is as locked down as possible using
+/// security best practices for your operating system.
+///
+/// [credential_process]: https://docs.aws.amazon.com/cli/latest/topic/config-vars.html#sourcing-credentials-from-external-processes
+#[derive(Clone, Debug)]
+pub struct CredentialProcessProvider {
+    command: String,
+}
+
+impl CredentialProcessProvider {
+    /// Create a new `CredentialProcessProvider` running the given command.
+    pub fn new<C>(command: C) -> CredentialProcessProvider
+    where
+        C: Into<String>,
+    {
+        CredentialProcessProvider {
+            command: command.into(),
+        }
+    }
+
+    /// Create a new `CredentialProcessProvider` from the `credential_process` setting of the
+    /// given profile in the default config file (`~/.aws/config`, or the value of
+    /// `AWS_CONFIG_FILE`). Returns `None` if the profile doesn't set `credential_process`.
+    pub fn from_profile<P>(
+        profile: P,
+    ) -> Result<Option<CredentialProcessProvider>, CredentialsError>
+    where
+        P: AsRef<str>,
+    {
+        let location = ProfileProvider::default_config_location()?;
+        Ok(parse_config_file(&location).and_then(|config| {
+            config
+                .get(profile.as_ref())
+                .and_then(|props| props.get(CREDENTIAL_PROCESS))
+                .map(CredentialProcessProvider::new)
+        }))
+    }
+
+    /// Get a reference to the command.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for CredentialProcessProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut command = parse_command_str(&self.command)?;
+        let output = command
+            .output()
+            .await
+            .map_err(|e| CredentialsError::new(format!("Credential process failed: {:?}", e)))?;
+        if output.status.success() {
+            parse_credential_process_output(&output.stdout)
+        } else {
+            Err(CredentialsError::new(format!(
+                "Credential process failed with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            )))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CredentialProcessOutput {
+    #[serde(flatten)]
+    creds: AwsCredentials,
+    #[serde(rename = "Version")]
+    version: u8,
+}
+
+fn parse_credential_process_output(v: &[u8]) -> Result<AwsCredentials, CredentialsError> {
+    let output: CredentialProcessOutput = serde_json::from_slice(v)?;
+    if output.version == 1 {
+        Ok(output.creds)
+    } else {
+        Err(CredentialsError::new(format!(
+            "Unsupported version '{}' for credential process provider, supported versions: 1",
+            output.version
+        )))
+    }
+}
+
+fn parse_command_str(s: &str) -> Result<Command, CredentialsError> {
+    let args = shlex::split(s)
+        .ok_or_else(|| CredentialsError::new("Unable to parse credential_process value."))?;
+    let mut iter = args.iter();
+    let mut command = Command::new(
+        iter.next()
+            .ok_or_else(|| CredentialsError::new("credential_process value is empty."))?,
+    );
+    command.args(iter);
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn credential_process_provider() {
+        let provider = CredentialProcessProvider::new(
+            "cat tests/sample-data/credential_process_sample_response",
+        );
+        let creds = provider.credentials().await.expect("valid credentials");
+        assert_eq!(creds.aws_access_key_id(), "baz_access_key");
+        assert_eq!(creds.aws_secret_access_key(), "baz_secret_key");
+        assert!(creds.expires_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn credential_process_provider_failure() {
+        let provider = CredentialProcessProvider::new("cat tests/sample-data/does_not_exist");
+        assert!(provider.credentials().await.is_err());
+        assert!(CredentialProcessProvider::new("")
+            .credentials()
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let result = parse_credential_process_output(
+            br#"{"Version": 2, "AccessKeyId": "key", "SecretAccessKey": "secret"}"#,
+        );
+        assert!(result.is_err());
+        let creds = parse_credential_process_output(
+            br#"{"Version": 1, "AccessKeyId": "key", "SecretAccessKey": "secret"}"#,
+        )
+        .expect("valid output");
+        assert_eq!(creds.token(), &None);
+        assert_eq!(creds.expires_at(), &None);
+    }
+}
@@ -1,22 +1,45 @@
 //! The Credentials Provider for an AWS Resource's IAM Role.
 
 use async_trait::async_trait;
-use hyper::Uri;
-use std::time::Duration;
+use hyper::{Body, Request, StatusCode};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::delay_for;
 
 use crate::request::HttpClient;
 use crate::{
-    parse_credentials_from_aws_service, AwsCredentials, CredentialsError, ProvideAwsCredentials,
+    non_empty_env_var, parse_credentials_from_aws_service, AwsCredentials, CredentialsError,
+    ProvideAwsCredentials, Secret,
 };
 
 const AWS_CREDENTIALS_PROVIDER_IP: &str = "169.254.169.254";
+const AWS_CREDENTIALS_PROVIDER_IPV6: &str = "fd00:ec2::254";
+const AWS_EC2_METADATA_SERVICE_ENDPOINT: &str = "AWS_EC2_METADATA_SERVICE_ENDPOINT";
+const AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE: &str = "AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE";
 const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-credentials";
+const AWS_METADATA_TOKEN_PATH: &str = "latest/api/token";
+const METADATA_TOKEN_HEADER: &str = "x-aws-ec2-metadata-token";
+const METADATA_TOKEN_TTL_HEADER: &str = "x-aws-ec2-metadata-token-ttl-seconds";
+const DEFAULT_TOKEN_TTL_SECONDS: u64 = 21600;
+const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+// Tokens are renewed shortly before they expire, so they don't expire in flight.
+const TOKEN_EXPIRY_BUFFER_SECONDS: u64 = 60;
+// The shortest time to live of tokens, so they're reused for a minute before being renewed.
+const MIN_TOKEN_TTL_SECONDS: u64 = 2 * TOKEN_EXPIRY_BUFFER_SECONDS;
+// How long IMDSv1 is used before a token is requested again, when none could be obtained.
+const IMDSV1_FALLBACK_SECONDS: u64 = 300;
 
 /// Provides AWS credentials from a resource's IAM role.
 ///
 /// The provider has a default timeout of 30 seconds. While it should work well for most setups,
 /// you can change the timeout using the `set_timeout` method.
 ///
+/// The provider uses session tokens (IMDSv2) to access the instance metadata service, and falls
+/// back to IMDSv1 if no token can be obtained. Tokens are shared by clones of a provider and
+/// reused until they expire. When no token can be obtained, IMDSv1 is used for five minutes
+/// before a token is requested again, unless the service rejects a request without one.
+///
 /// # Examples
 ///
 /// ```rust
@@ -39,12 +62,50 @@ const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-crede
 /// let mut provider = InstanceMetadataProvider::new();
 /// // you can overwrite the default endpoint like this:
 /// provider.set_ip_addr_with_port("127.0.0.1", "8080");
+/// // or like this, e.g. to use the IPv6 endpoint:
+/// provider.set_endpoint("http://[fd00:ec2::254]");
+/// ```
+///
+/// The default endpoint is also read from the environment: `AWS_EC2_METADATA_SERVICE_ENDPOINT`
+/// sets the endpoint, and `AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE=IPv6` selects the IPv6
+/// endpoint `http://[fd00:ec2::254]`.
+///
+/// Where the instance metadata service is slow or unreachable, e.g. in containers when the hop
+/// limit of the instance doesn't allow them to reach it, a connect timeout and retries can be
+/// configured:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_credential::InstanceMetadataProvider;
+///
+/// let mut provider = InstanceMetadataProvider::new();
+/// provider.set_connect_timeout(Duration::from_secs(1));
+/// provider.set_retries(2);
 /// ```
 #[derive(Clone, Debug)]
 pub struct InstanceMetadataProvider {
     client: HttpClient,
     timeout: Duration,
-    metadata_ip_addr: String,
+    connect_timeout: Option<Duration>,
+    retries: u32,
+    endpoint: String,
+    token_ttl: Duration,
+    token: Arc<Mutex<Option<MetadataToken>>>,
+}
+
+/// A session token of the instance metadata service (IMDSv2), or no token if none could be
+/// obtained, in which case IMDSv1 is used until it expires.
+#[derive(Clone, Debug)]
+struct MetadataToken {
+    value: Option<Secret>,
+    expires_at: Instant,
+}
+
+impl MetadataToken {
+    fn is_valid(&self) -> bool {
+        self.expires_at > Instant::now() + Duration::from_secs(TOKEN_EXPIRY_BUFFER_SECONDS)
+    }
 }
 
 impl InstanceMetadataProvider {
@@ -53,7 +114,11 @@ impl InstanceMetadataProvider {
         InstanceMetadataProvider {
             client: HttpClient::new(),
             timeout: Duration::from_secs(30),
-            metadata_ip_addr: AWS_CREDENTIALS_PROVIDER_IP.to_string(),
+            connect_timeout: None,
+            retries: 0,
+            endpoint: default_endpoint(),
+            token_ttl: Duration::from_secs(DEFAULT_TOKEN_TTL_SECONDS),
+            token: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -62,9 +127,166 @@ impl InstanceMetadataProvider {
         self.timeout = timeout;
     }
 
+    /// Set the timeout for establishing a connection to the instance metadata service. By
+    /// default, only the overall timeout applies.
+    pub fn set_connect_timeout(&mut self, connect_timeout: Duration) {
+        self.connect_timeout = Some(connect_timeout);
+        self.client = HttpClient::with_connect_timeout(self.connect_timeout);
+    }
+
+    /// Set how often failed requests to the instance metadata service are retried. Requests are
+    /// retried on connection errors, timeouts and server errors. Defaults to no retries.
+    pub fn set_retries(&mut self, retries: u32) {
+        self.retries = retries;
+    }
+
     /// Allow overriding host and port of instance metadata service.
     pub fn set_ip_addr_with_port(&mut self, ip: &str, port: &str) {
-        self.metadata_ip_addr = format!("{}:{}", ip, port);
+        if ip.contains(':') && !ip.starts_with('[') {
+            self.endpoint = format!("http://[{}]:{}", ip, port);
+        } else {
+            self.endpoint = format!("http://{}:{}", ip, port);
+        }
+    }
+
+    /// Allow overriding the endpoint of the instance metadata service, e.g.
+    /// `http://[fd00:ec2::254]`. A missing scheme defaults to `http`.
+    pub fn set_endpoint(&mut self, endpoint: &str) {
+        self.endpoint = normalize_endpoint(endpoint);
+    }
+
+    /// Get a reference to the endpoint of the instance metadata service.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Set the time to live of the IMDSv2 session tokens requested by the provider. Tokens are
+    /// reused until a minute before they expire. Defaults to six hours, the maximum allowed.
+    ///
+    /// The time to live is clamped to at least two minutes, so tokens are reused at all, and to
+    /// at most six hours.
+    pub fn set_token_ttl(&mut self, token_ttl: Duration) {
+        self.token_ttl = token_ttl.clamp(
+            Duration::from_secs(MIN_TOKEN_TTL_SECONDS),
+            Duration::from_secs(DEFAULT_TOKEN_TTL_SECONDS),
+        );
+    }
+
+    /// Returns an IMDSv2 session token, or `None` if the instance metadata service doesn't issue
+    /// tokens, in which case IMDSv1 is used.
+    async fn metadata_token(&self, refresh: bool) -> Option<Secret> {
+        let mut token = self.token.lock().await;
+        if let Some(ref token) = *token {
+            if !refresh && token.is_valid() {
+                return token.value.clone();
+            }
+        }
+        let new_token = self.request_metadata_token().await;
+        let value = new_token.value.clone();
+        *token = Some(new_token);
+        value
+    }
+
+    async fn request_metadata_token(&self) -> MetadataToken {
+        let uri = format!("{}/{}", self.endpoint, AWS_METADATA_TOKEN_PATH);
+        let requested_at = Instant::now();
+        let response = self
+            .send(|| {
+                Request::put(&uri)
+                    .header(METADATA_TOKEN_TTL_HEADER, self.token_ttl.as_secs())
+                    .body(Body::empty())
+            })
+            .await;
+        match response {
+            Ok((status, body)) if status.is_success() => MetadataToken {
+                value: Some(Secret::from(body)),
+                expires_at: requested_at + self.token_ttl,
+            },
+            // remember that there's no token, so requests don't wait for the token request to
+            // fail again first
+            _ => MetadataToken {
+                value: None,
+                expires_at: requested_at
+                    + Duration::from_secs(IMDSV1_FALLBACK_SECONDS + TOKEN_EXPIRY_BUFFER_SECONDS),
+            },
+        }
+    }
+
+    /// Gets a path of the instance metadata service, using a session token if possible.
+    async fn get_metadata(&self, path: &str) -> Result<String, CredentialsError> {
+        let uri = format!("{}/{}", self.endpoint, path);
+        let mut token = self.metadata_token(false).await;
+        let mut retried = false;
+        loop {
+            let (status, body) = self
+                .send(|| {
+                    let mut request = Request::get(&uri);
+                    if let Some(ref token) = token {
+                        request = request.header(METADATA_TOKEN_HEADER, token.as_ref());
+                    }
+                    request.body(Body::empty())
+                })
+                .await?;
+            if status == StatusCode::UNAUTHORIZED && !retried {
+                // the session token expired or was never accepted, get a new one
+                token = self.metadata_token(true).await;
+                retried = true;
+                continue;
+            }
+            if !status.is_success() {
+                return Err(CredentialsError::new(format!(
+                    "Instance metadata service responded with {}: {}",
+                    status, body
+                )));
+            }
+            return Ok(body);
+        }
+    }
+}
+
+impl InstanceMetadataProvider {
+    /// Sends the request built by `build_request`, retrying on connection errors, timeouts and
+    /// server errors.
+    async fn send<F>(&self, build_request: F) -> Result<(StatusCode, String), CredentialsError>
+    where
+        F: Fn() -> Result<Request<Body>, hyper::http::Error>,
+    {
+        let mut attempt = 0;
+        loop {
+            let request = build_request().map_err(CredentialsError::new)?;
+            let response = self.client.send(request, self.timeout).await;
+            let retry = match response {
+                Ok((status, _)) => status.is_server_error(),
+                Err(_) => true,
+            };
+            if !retry || attempt >= self.retries {
+                return Ok(response?);
+            }
+            attempt += 1;
+            delay_for(RETRY_BACKOFF * attempt).await;
+        }
+    }
+}
+
+/// Resolves the default endpoint from the environment.
+fn default_endpoint() -> String {
+    match non_empty_env_var(AWS_EC2_METADATA_SERVICE_ENDPOINT) {
+        Some(endpoint) => normalize_endpoint(&endpoint),
+        None => match non_empty_env_var(AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE) {
+            Some(ref mode) if mode.eq_ignore_ascii_case("ipv6") => {
+                format!("http://[{}]", AWS_CREDENTIALS_PROVIDER_IPV6)
+            }
+            _ => format!("http://{}", AWS_CREDENTIALS_PROVIDER_IP),
+        },
+    }
+}
+
+fn normalize_endpoint(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    if endpoint.contains("://") {
+        endpoint.to_owned()
+    } else {
+        format!("http://{}", endpoint)
     }
 }
 
@@ -77,58 +299,133 @@ impl Default for InstanceMetadataProvider {
 #[async_trait]
 impl ProvideAwsCredentials for InstanceMetadataProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let role_name = get_role_name(&self.client, self.timeout, &self.metadata_ip_addr)
+        let role_name = self
+            .get_metadata(&format!("{}/", AWS_CREDENTIALS_PROVIDER_PATH))
             .await
             .map_err(|err| CredentialsError {
                 message: format!("Could not get credentials from iam: {}", err.to_string()),
             })?;
 
-        let cred_str = get_credentials_from_role(
-            &self.client,
-            self.timeout,
-            &role_name,
-            &self.metadata_ip_addr,
-        )
-        .await
-        .map_err(|err| CredentialsError {
-            message: format!("Could not get credentials from iam: {}", err.to_string()),
-        })?;
+        let cred_str = self
+            .get_metadata(&format!("{}/{}", AWS_CREDENTIALS_PROVIDER_PATH, role_name))
+            .await
+            .map_err(|err| CredentialsError {
+                message: format!("Could not get credentials from iam: {}", err.to_string()),
+            })?;
 
         parse_credentials_from_aws_service(&cred_str)
     }
 }
 
-/// Gets the role name to get credentials for using the IAM Metadata Service (169.254.169.254).
-async fn get_role_name(
-    client: &HttpClient,
-    timeout: Duration,
-    ip_addr: &str,
-) -> Result<String, CredentialsError> {
-    let role_name_address = format!("http://{}/{}/", ip_addr, AWS_CREDENTIALS_PROVIDER_PATH);
-    let uri = match role_name_address.parse::<Uri>() {
-        Ok(u) => u,
-        Err(e) => return Err(CredentialsError::new(e)),
-    };
-
-    Ok(client.get(uri, timeout).await?)
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::lock_env;
+    use std::env;
 
-/// Gets the credentials for an EC2 Instances IAM Role.
-async fn get_credentials_from_role(
-    client: &HttpClient,
-    timeout: Duration,
-    role_name: &str,
-    ip_addr: &str,
-) -> Result<String, CredentialsError> {
-    let credentials_provider_url = format!(
-        "http://{}/{}/{}",
-        ip_addr, AWS_CREDENTIALS_PROVIDER_PATH, role_name
-    );
-
-    let uri = match credentials_provider_url.parse::<Uri>() {
-        Ok(u) => u,
-        Err(e) => return Err(CredentialsError::new(e)),
-    };
-
-    Ok(client.get(uri, timeout).await?)
+    #[test]
+    fn endpoint_configuration() {
+        let _guard = lock_env();
+        env::remove_var(AWS_EC2_METADATA_SERVICE_ENDPOINT);
+        env::remove_var(AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE);
+        assert_eq!(default_endpoint(), "http://169.254.169.254");
+
+        env::set_var(AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE, "IPv6");
+        assert_eq!(default_endpoint(), "http://[fd00:ec2::254]");
+
+        env::set_var(AWS_EC2_METADATA_SERVICE_ENDPOINT, "http://127.0.0.1:8080/");
+        assert_eq!(default_endpoint(), "http://127.0.0.1:8080");
+        env::remove_var(AWS_EC2_METADATA_SERVICE_ENDPOINT);
+        env::remove_var(AWS_EC2_METADATA_SERVICE_ENDPOINT_MODE);
+
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_ip_addr_with_port("fd00:ec2::254", "80");
+        assert_eq!(provider.endpoint(), "http://[fd00:ec2::254]:80");
+        provider.set_ip_addr_with_port("127.0.0.1", "8080");
+        assert_eq!(provider.endpoint(), "http://127.0.0.1:8080");
+        provider.set_endpoint("169.254.169.254");
+        assert_eq!(provider.endpoint(), "http://169.254.169.254");
+    }
+
+    #[test]
+    fn metadata_token_validity() {
+        let token = |valid_for: u64| MetadataToken {
+            value: Some(Secret::from("token".to_owned())),
+            expires_at: Instant::now() + Duration::from_secs(valid_for),
+        };
+        assert!(token(DEFAULT_TOKEN_TTL_SECONDS).is_valid());
+        assert!(token(MIN_TOKEN_TTL_SECONDS).is_valid());
+        assert!(!token(TOKEN_EXPIRY_BUFFER_SECONDS / 2).is_valid());
+    }
+
+    #[test]
+    fn token_ttl_bounds() {
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_token_ttl(Duration::from_secs(600));
+        assert_eq!(provider.token_ttl, Duration::from_secs(600));
+        // tokens would be renewed as soon as they're fetched
+        provider.set_token_ttl(Duration::from_secs(TOKEN_EXPIRY_BUFFER_SECONDS));
+        assert_eq!(
+            provider.token_ttl,
+            Duration::from_secs(MIN_TOKEN_TTL_SECONDS)
+        );
+        // the instance metadata service rejects longer times to live
+        provider.set_token_ttl(Duration::from_secs(DEFAULT_TOKEN_TTL_SECONDS));
+        assert_eq!(
+            provider.token_ttl,
+            Duration::from_secs(DEFAULT_TOKEN_TTL_SECONDS)
+        );
+        provider.set_token_ttl(Duration::from_secs(DEFAULT_TOKEN_TTL_SECONDS + 1));
+        assert_eq!(
+            provider.token_ttl,
+            Duration::from_secs(DEFAULT_TOKEN_TTL_SECONDS)
+        );
+    }
+
+    #[tokio::test]
+    async fn imdsv1_fallback() {
+        use hyper::service::{make_service_fn, service_fn};
+        use hyper::{Method, Response, Server};
+        use std::convert::Infallible;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // an instance metadata service which doesn't issue tokens
+        let token_requests = Arc::new(AtomicUsize::new(0));
+        let requests = token_requests.clone();
+        let make_service = make_service_fn(move |_| {
+            let requests = requests.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let response = if request.method() == Method::PUT {
+                        requests.fetch_add(1, Ordering::SeqCst);
+                        Response::builder()
+                            .status(StatusCode::FORBIDDEN)
+                            .body(Body::empty())
+                    } else if request.headers().contains_key(METADATA_TOKEN_HEADER) {
+                        Response::builder()
+                            .status(StatusCode::BAD_REQUEST)
+                            .body(Body::empty())
+                    } else if request.uri().path().ends_with('/') {
+                        Response::builder().body(Body::from("role"))
+                    } else {
+                        Response::builder().body(Body::from(
+                            r#"{"AccessKeyId": "key", "SecretAccessKey": "secret"}"#,
+                        ))
+                    };
+                    async move { response }
+                }))
+            }
+        });
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let port = server.local_addr().port();
+        tokio::spawn(server);
+
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_ip_addr_with_port("127.0.0.1", &port.to_string());
+        for _ in 0..2 {
+            let credentials = provider.credentials().await.unwrap();
+            assert_eq!(credentials.aws_access_key_id(), "key");
+        }
+        assert_eq!(token_requests.load(Ordering::SeqCst), 1);
+    }
 }
@@ -7,21 +7,47 @@
 
 //! Types for loading and managing AWS access credentials for API requests.
 
+#[cfg(feature = "rustls")]
+use hyper_rustls as tls;
+// rustls is preferred when both are enabled, e.g. because another crate in the
+// dependency graph keeps the default features of a service crate
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+use hyper_tls as tls;
+
+pub use crate::anonymous::AnonymousCredentialsProvider;
+pub use crate::cache::{CredentialsCache, FileCredentialsCache, MemoryCredentialsCache};
+pub use crate::cli_cache::CliCache;
 pub use crate::container::ContainerProvider;
+pub use crate::credential_process::CredentialProcessProvider;
 pub use crate::environment::EnvironmentProvider;
 pub use crate::instance_metadata::InstanceMetadataProvider;
-pub use crate::profile::ProfileProvider;
+pub use crate::profile::{
+    ProfileEndpointUrls, ProfileProvider, ProfileRole, RoleChain, RoleChainSource,
+};
+pub use crate::refreshable::RefreshableProvider;
+#[cfg(feature = "roles-anywhere")]
+pub use crate::roles_anywhere::IamRolesAnywhereProvider;
 pub use crate::secrets::Secret;
+pub use crate::sso::SsoProvider;
 pub use crate::static_provider::StaticProvider;
 pub use crate::variable::Variable;
 
+mod anonymous;
+mod cache;
 pub mod claims;
+mod cli_cache;
+pub mod clock;
 mod container;
+mod credential_process;
 mod environment;
 mod instance_metadata;
 mod profile;
+mod refreshable;
 mod request;
+#[cfg(feature = "roles-anywhere")]
+mod roles_anywhere;
 mod secrets;
+mod sso;
 mod static_provider;
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -42,6 +68,12 @@ use hyper::Error as HyperError;
 use serde::Deserialize;
 use tokio::sync::Mutex;
 
+const AWS_EC2_METADATA_DISABLED: &str = "AWS_EC2_METADATA_DISABLED";
+
+/// Default number of seconds before expiry at which `AutoRefreshingProvider` refreshes
+/// credentials.
+const DEFAULT_SKEW_WINDOW_SECONDS: i64 = 20;
+
 /// Representation of anonymity
 pub trait Anonymous {
     /// Return true if a type is anonymous, false otherwise
@@ -74,6 +106,8 @@ pub struct AwsCredentials {
     expires_at: Option<DateTime<Utc>>,
     #[serde(skip)]
     claims: BTreeMap<String, String>,
+    #[serde(skip)]
+    source: Option<CredentialsSource>,
 }
 
 impl AwsCredentials {
@@ -95,6 +129,7 @@ impl AwsCredentials {
             token,
             expires_at,
             claims: BTreeMap::new(),
+            source: None,
         }
     }
 
@@ -118,15 +153,11 @@ impl AwsCredentials {
         &self.token
     }
 
-    /// Determine whether or not the credentials are expired.
-    fn credentials_are_expired(&self) -> bool {
+    /// Determine whether or not the credentials expire within the given window, according to
+    /// the time of AWS (see the [`clock`](clock/index.html) module).
+    pub fn credentials_are_expired(&self, window: ChronoDuration) -> bool {
         match self.expires_at {
-            Some(ref e) =>
-            // This is a rough hack to hopefully avoid someone requesting creds then sitting on them
-            // before issuing the request:
-            {
-                *e < Utc::now() + ChronoDuration::seconds(20)
-            }
+            Some(ref e) => *e < clock::now() + window,
             None => false,
         }
     }
@@ -140,6 +171,80 @@ impl AwsCredentials {
     pub fn claims_mut(&mut self) -> &mut BTreeMap<String, String> {
         &mut self.claims
     }
+
+    /// Get where the credentials were resolved from, if known. `ChainProvider` records the
+    /// provider of the chain which returned them.
+    pub fn source(&self) -> Option<&CredentialsSource> {
+        self.source.as_ref()
+    }
+
+    /// Set where the credentials were resolved from.
+    pub fn set_source(&mut self, source: CredentialsSource) {
+        self.source = Some(source);
+    }
+}
+
+/// Where credentials were resolved from, e.g. to find out which of the sources of a
+/// `ChainProvider` is actually used.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_credential::{ChainProvider, ProvideAwsCredentials};
+///
+/// # async fn example() {
+/// let credentials = ChainProvider::new().credentials().await.unwrap();
+/// if let Some(source) = credentials.source() {
+///     println!("using credentials from {}", source);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CredentialsSource {
+    provider_name: String,
+    profile: Option<String>,
+}
+
+impl CredentialsSource {
+    /// Create a new `CredentialsSource` for the provider of the given name. The built-in
+    /// providers of a `ChainProvider` are named after the constants of `ChainProviderBuilder`.
+    pub fn new<N>(provider_name: N) -> CredentialsSource
+    where
+        N: Into<String>,
+    {
+        CredentialsSource {
+            provider_name: provider_name.into(),
+            profile: None,
+        }
+    }
+
+    /// Set the name of the profile the credentials were loaded for.
+    pub fn set_profile<P>(&mut self, profile: P)
+    where
+        P: Into<String>,
+    {
+        self.profile = Some(profile.into());
+    }
+
+    /// Get the name of the provider, e.g. `environment` or `instance-metadata`.
+    pub fn provider_name(&self) -> &str {
+        &self.provider_name
+    }
+
+    /// Get the name of the profile the credentials were loaded for, if they were loaded from
+    /// the shared credentials or config files.
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+}
+
+impl fmt::Display for CredentialsSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.profile {
+            Some(ref profile) => write!(f, "{} (profile {})", self.provider_name, profile),
+            None => f.write_str(&self.provider_name),
+        }
+    }
 }
 
 impl fmt::Debug for AwsCredentials {
@@ -150,6 +255,7 @@ impl fmt::Debug for AwsCredentials {
             .field("token", &self.token.as_ref().map(|_| "**********"))
             .field("expires_at", &self.expires_at)
             .field("claims", &self.claims)
+            .field("source", &self.source)
             .finish()
     }
 }
@@ -230,31 +336,158 @@ pub trait ProvideAwsCredentials {
 }
 
 #[async_trait]
-impl<P: ProvideAwsCredentials + Send + Sync> ProvideAwsCredentials for Arc<P> {
+impl<P: ProvideAwsCredentials + Send + Sync + ?Sized> ProvideAwsCredentials for Arc<P> {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
         P::credentials(self).await
     }
 }
 
+#[async_trait]
+impl<P: ProvideAwsCredentials + Send + Sync + ?Sized> ProvideAwsCredentials for Box<P> {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        P::credentials(self).await
+    }
+}
+
+/// A `ProvideAwsCredentials` of any type, which can be stored in configuration structs or
+/// passed across crate boundaries without making them generic over the provider type.
+///
+/// `Arc<dyn ProvideAwsCredentials + Send + Sync>` and `Box<dyn ProvideAwsCredentials + Send +
+/// Sync>` are providers as well, and can be converted into a `BoxedCredentialsProvider`.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_credential::{BoxedCredentialsProvider, ChainProvider, StaticProvider};
+///
+/// struct Config {
+///     credentials: BoxedCredentialsProvider,
+/// }
+///
+/// let config = if std::env::var("USE_STATIC_CREDENTIALS").is_ok() {
+///     Config {
+///         credentials: BoxedCredentialsProvider::new(StaticProvider::new_minimal(
+///             "access_key".to_owned(),
+///             "secret_key".to_owned(),
+///         )),
+///     }
+/// } else {
+///     Config {
+///         credentials: BoxedCredentialsProvider::new(ChainProvider::new()),
+///     }
+/// };
+/// ```
+#[derive(Clone)]
+pub struct BoxedCredentialsProvider(Arc<dyn ProvideAwsCredentials + Send + Sync>);
+
+impl BoxedCredentialsProvider {
+    /// Create a new `BoxedCredentialsProvider` around the given provider.
+    pub fn new<P>(provider: P) -> BoxedCredentialsProvider
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        BoxedCredentialsProvider(Arc::new(provider))
+    }
+
+    /// Get a shared reference to the wrapped provider.
+    pub fn get_ref(&self) -> &(dyn ProvideAwsCredentials + Send + Sync) {
+        &*self.0
+    }
+}
+
+impl fmt::Debug for BoxedCredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BoxedCredentialsProvider")
+    }
+}
+
+impl From<Arc<dyn ProvideAwsCredentials + Send + Sync>> for BoxedCredentialsProvider {
+    fn from(provider: Arc<dyn ProvideAwsCredentials + Send + Sync>) -> BoxedCredentialsProvider {
+        BoxedCredentialsProvider(provider)
+    }
+}
+
+impl From<Box<dyn ProvideAwsCredentials + Send + Sync>> for BoxedCredentialsProvider {
+    fn from(provider: Box<dyn ProvideAwsCredentials + Send + Sync>) -> BoxedCredentialsProvider {
+        BoxedCredentialsProvider(provider.into())
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for BoxedCredentialsProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        self.0.credentials().await
+    }
+}
+
 /// Wrapper for `ProvideAwsCredentials` that caches the credentials returned by the
 /// wrapped provider.  Each time the credentials are accessed, they are checked to see if
 /// they have expired, in which case they are retrieved from the wrapped provider again.
 ///
+/// Credentials are kept in memory by default. Use `with_cache` to keep them in a
+/// [`CredentialsCache`](trait.CredentialsCache.html) shared with other processes instead,
+/// like a [`FileCredentialsCache`](struct.FileCredentialsCache.html):
+///
+/// ```rust,no_run
+/// use rusoto_credential::{AutoRefreshingProvider, ChainProvider, FileCredentialsCache};
+///
+/// let provider = AutoRefreshingProvider::with_cache(
+///     ChainProvider::new(),
+///     FileCredentialsCache::new("/var/cache/my-app/credentials.json"),
+/// );
+/// ```
+///
 /// In order to access the wrapped provider, for instance to set a timeout, the `get_ref`
 /// and `get_mut` methods can be used.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct AutoRefreshingProvider<P: ProvideAwsCredentials + 'static> {
     credentials_provider: P,
-    current_credentials: Arc<Mutex<Option<Result<AwsCredentials, CredentialsError>>>>,
+    cache: Arc<dyn CredentialsCache + Send + Sync>,
+    skew_window: ChronoDuration,
+    /// Held while the credentials are refreshed. Keeps the error of a failed refresh, which is
+    /// returned from then on.
+    refresh: Arc<Mutex<Option<CredentialsError>>>,
+}
+
+impl<P: ProvideAwsCredentials + fmt::Debug + 'static> fmt::Debug for AutoRefreshingProvider<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoRefreshingProvider")
+            .field("credentials_provider", &self.credentials_provider)
+            .field("skew_window", &self.skew_window)
+            .finish()
+    }
 }
 
 impl<P: ProvideAwsCredentials + 'static> AutoRefreshingProvider<P> {
     /// Create a new `AutoRefreshingProvider` around the provided base provider.
     pub fn new(provider: P) -> Result<AutoRefreshingProvider<P>, CredentialsError> {
-        Ok(AutoRefreshingProvider {
+        Ok(AutoRefreshingProvider::with_cache(
+            provider,
+            MemoryCredentialsCache::new(),
+        ))
+    }
+
+    /// Create a new `AutoRefreshingProvider` around the provided base provider, keeping the
+    /// credentials in the given cache. Credentials found in the cache are used as long as they
+    /// don't expire, even if they were stored by another process.
+    pub fn with_cache<C>(provider: P, cache: C) -> AutoRefreshingProvider<P>
+    where
+        C: CredentialsCache + Send + Sync + 'static,
+    {
+        AutoRefreshingProvider {
             credentials_provider: provider,
-            current_credentials: Arc::new(Mutex::new(None)),
-        })
+            cache: Arc::new(cache),
+            skew_window: ChronoDuration::seconds(DEFAULT_SKEW_WINDOW_SECONDS),
+            refresh: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Set how long before they expire credentials are refreshed, which also avoids someone
+    /// requesting credentials then sitting on them before issuing the request. Defaults to 20
+    /// seconds. Expiry times are compared to the time of AWS, corrected with the clock skew
+    /// learnt from AWS responses, so the window only has to cover the remaining imprecision.
+    pub fn set_skew_window(&mut self, window: Duration) {
+        self.skew_window = ChronoDuration::seconds(window.as_secs() as i64);
     }
 
     /// Get a shared reference to the wrapped provider.
@@ -276,22 +509,23 @@ impl<P: ProvideAwsCredentials + Send + Sync + 'static> ProvideAwsCredentials
     for AutoRefreshingProvider<P>
 {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        loop {
-            let mut guard = self.current_credentials.lock().await;
-            match guard.as_ref() {
-                // no result from the future yet, let's keep using it
-                None => {
-                    let res = self.credentials_provider.credentials().await;
-                    *guard = Some(res);
-                }
-                Some(Err(e)) => return Err(e.clone()),
-                Some(Ok(creds)) => {
-                    if creds.credentials_are_expired() {
-                        *guard = None;
-                    } else {
-                        return Ok(creds.clone());
-                    };
-                }
+        let mut guard = self.refresh.lock().await;
+        if let Some(ref e) = *guard {
+            return Err(e.clone());
+        }
+        if let Some(creds) = self.cache.load().await? {
+            if !creds.credentials_are_expired(self.skew_window) {
+                return Ok(creds);
+            }
+        }
+        match self.credentials_provider.credentials().await {
+            Ok(creds) => {
+                self.cache.store(&creds).await?;
+                Ok(creds)
+            }
+            Err(e) => {
+                *guard = Some(e.clone());
+                Err(e)
             }
         }
     }
@@ -337,12 +571,17 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
 /// 2. `credential_process` command in the AWS config file, usually located at `~/.aws/config`.
 /// 3. AWS credentials file. Usually located at `~/.aws/credentials`.
 /// 4. IAM instance profile. Will only work if running on an EC2 instance with an instance profile/role.
+///    Skipped if the environment variable `AWS_EC2_METADATA_DISABLED` is set to `true`.
 ///
-/// If the sources are exhausted without finding credentials, an error is returned.
+/// If the sources are exhausted without finding credentials, an error is returned. Otherwise
+/// the credentials record which source they were found in, see `AwsCredentials::source`.
 ///
 /// The provider has a default timeout of 30 seconds. While it should work well for most setups,
 /// you can change the timeout using the `set_timeout` method.
 ///
+/// The order of the sources can be customized with a [`ChainProviderBuilder`], obtained from
+/// `ChainProvider::builder`.
+///
 /// # Example
 ///
 /// ```rust
@@ -366,68 +605,140 @@ impl ProvideAwsCredentials for DefaultCredentialsProvider {
 /// [credential_process]: https://docs.aws.amazon.com/cli/latest/topic/config-vars.html#sourcing-credentials-from-external-processes
 #[derive(Debug, Clone)]
 pub struct ChainProvider {
-    environment_provider: EnvironmentProvider,
-    instance_metadata_provider: InstanceMetadataProvider,
-    container_provider: ContainerProvider,
-    profile_provider: Option<ProfileProvider>,
+    links: Vec<ChainLink>,
 }
 
-impl ChainProvider {
-    /// Set the timeout on the provider to the specified duration.
-    pub fn set_timeout(&mut self, duration: Duration) {
-        self.instance_metadata_provider.set_timeout(duration);
-        self.container_provider.set_timeout(duration);
+/// A provider of a `ChainProvider`, identified by its name.
+#[derive(Clone)]
+struct ChainLink {
+    name: String,
+    source: ChainSource,
+    timeout: Option<Duration>,
+}
+
+impl fmt::Debug for ChainLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainLink")
+            .field("name", &self.name)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// The built-in providers are kept apart from custom ones so `ChainProvider::set_timeout` can
+/// still reach them.
+#[derive(Clone)]
+enum ChainSource {
+    Environment(EnvironmentProvider),
+    Profile(ProfileProvider),
+    Container(ContainerProvider),
+    InstanceMetadata(InstanceMetadataProvider),
+    Custom(Arc<dyn ProvideAwsCredentials + Send + Sync>),
+}
+
+impl ChainSource {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        match *self {
+            ChainSource::Environment(ref provider) => provider.credentials().await,
+            ChainSource::Profile(ref provider) => provider.credentials().await,
+            ChainSource::Container(ref provider) => provider.credentials().await,
+            ChainSource::InstanceMetadata(ref provider) => {
+                if instance_metadata_disabled() {
+                    return Err(CredentialsError::new(format!(
+                        "Instance metadata service disabled by {}",
+                        AWS_EC2_METADATA_DISABLED
+                    )));
+                }
+                provider.credentials().await
+            }
+            ChainSource::Custom(ref provider) => provider.credentials().await,
+        }
     }
 }
 
-async fn chain_provider_credentials(
-    provider: ChainProvider,
-) -> Result<AwsCredentials, CredentialsError> {
-    if let Ok(creds) = provider.environment_provider.credentials().await {
-        return Ok(creds);
+impl ChainLink {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut creds = self.resolve().await?;
+        if creds.source.is_none() {
+            let mut source = CredentialsSource::new(self.name.as_str());
+            if let ChainSource::Profile(ref provider) = self.source {
+                source.set_profile(provider.profile());
+            }
+            creds.source = Some(source);
+        }
+        Ok(creds)
     }
-    if let Some(ref profile_provider) = provider.profile_provider {
-        if let Ok(creds) = profile_provider.credentials().await {
-            return Ok(creds);
+
+    async fn resolve(&self) -> Result<AwsCredentials, CredentialsError> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.source.credentials())
+                .await
+                .unwrap_or_else(|_| {
+                    Err(CredentialsError::new(format!(
+                        "Provider {} timed out after {:?}",
+                        self.name, timeout
+                    )))
+                }),
+            None => self.source.credentials().await,
         }
     }
-    if let Ok(creds) = provider.container_provider.credentials().await {
-        return Ok(creds);
+}
+
+impl ChainProvider {
+    /// Set the timeout on the provider to the specified duration.
+    pub fn set_timeout(&mut self, duration: Duration) {
+        for link in &mut self.links {
+            match link.source {
+                ChainSource::InstanceMetadata(ref mut provider) => provider.set_timeout(duration),
+                ChainSource::Container(ref mut provider) => provider.set_timeout(duration),
+                _ => {}
+            }
+        }
     }
-    if let Ok(creds) = provider.instance_metadata_provider.credentials().await {
-        return Ok(creds);
+
+    /// The names of the providers of the chain, in the order they are checked.
+    pub fn provider_names(&self) -> Vec<&str> {
+        self.links.iter().map(|link| link.name.as_str()).collect()
     }
-    Err(CredentialsError::new(
-        "Couldn't find AWS credentials in environment, credentials file, or IAM role.",
-    ))
 }
 
 #[async_trait]
 impl ProvideAwsCredentials for ChainProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        chain_provider_credentials(self.clone()).await
+        for link in &self.links {
+            if let Ok(creds) = link.credentials().await {
+                return Ok(creds);
+            }
+        }
+        Err(CredentialsError::new(
+            "Couldn't find AWS credentials in environment, credentials file, or IAM role.",
+        ))
     }
 }
 
 impl ChainProvider {
     /// Create a new `ChainProvider` using a `ProfileProvider` with the default settings.
     pub fn new() -> ChainProvider {
-        ChainProvider {
-            environment_provider: EnvironmentProvider::default(),
-            profile_provider: ProfileProvider::new().ok(),
-            instance_metadata_provider: InstanceMetadataProvider::new(),
-            container_provider: ContainerProvider::new(),
-        }
+        ChainProvider::builder().build()
     }
 
     /// Create a new `ChainProvider` using the provided `ProfileProvider`.
     pub fn with_profile_provider(profile_provider: ProfileProvider) -> ChainProvider {
-        ChainProvider {
-            environment_provider: EnvironmentProvider::default(),
-            profile_provider: Some(profile_provider),
-            instance_metadata_provider: InstanceMetadataProvider::new(),
-            container_provider: ContainerProvider::new(),
-        }
+        ChainProvider::builder().profile(profile_provider).build()
+    }
+
+    /// Get a builder, initialized with the default chain of providers.
+    ///
+    /// Use `ChainProviderBuilder::new` to start from an empty chain instead.
+    pub fn builder() -> ChainProviderBuilder {
+        let builder = ChainProviderBuilder::new().environment(EnvironmentProvider::default());
+        let builder = match ProfileProvider::new() {
+            Ok(profile_provider) => builder.profile(profile_provider),
+            Err(_) => builder,
+        };
+        builder
+            .container(ContainerProvider::new())
+            .instance_metadata(InstanceMetadataProvider::new())
     }
 }
 
@@ -437,6 +748,177 @@ impl Default for ChainProvider {
     }
 }
 
+/// A builder for a [`ChainProvider`] with a custom list of providers.
+///
+/// Every provider of the chain is identified by a name. The built-in providers are added under
+/// the names [`ENVIRONMENT`](Self::ENVIRONMENT), [`PROFILE`](Self::PROFILE),
+/// [`CONTAINER`](Self::CONTAINER) and [`INSTANCE_METADATA`](Self::INSTANCE_METADATA); any other
+/// `ProvideAwsCredentials` implementation, for instance the `WebIdentityProvider` of
+/// `rusoto_sts`, can be added under a name of your choice. Adding a provider under a name which
+/// is already part of the chain removes the previous provider of that name.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_credential::{
+///     ChainProvider, ChainProviderBuilder, EnvironmentProvider, InstanceMetadataProvider,
+///     StaticProvider,
+/// };
+///
+/// // start from the default chain, without the container provider
+/// let provider = ChainProvider::builder()
+///     .remove(ChainProviderBuilder::CONTAINER)
+///     .build();
+/// assert!(!provider.provider_names().contains(&"container"));
+///
+/// // or from an empty chain
+/// let provider = ChainProviderBuilder::new()
+///     .environment(EnvironmentProvider::default())
+///     .instance_metadata(InstanceMetadataProvider::new())
+///     .timeout(ChainProviderBuilder::INSTANCE_METADATA, Duration::from_secs(2))
+///     .prepend(
+///         "static",
+///         StaticProvider::new_minimal("access_key".to_owned(), "secret_key".to_owned()),
+///     )
+///     .build();
+/// assert_eq!(
+///     provider.provider_names(),
+///     vec!["static", "environment", "instance-metadata"]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChainProviderBuilder {
+    links: Vec<ChainLink>,
+}
+
+impl ChainProviderBuilder {
+    /// The name of the `EnvironmentProvider`.
+    pub const ENVIRONMENT: &'static str = "environment";
+    /// The name of the `ProfileProvider`.
+    pub const PROFILE: &'static str = "profile";
+    /// The name of the `ContainerProvider`.
+    pub const CONTAINER: &'static str = "container";
+    /// The name of the `InstanceMetadataProvider`.
+    pub const INSTANCE_METADATA: &'static str = "instance-metadata";
+
+    /// Create a builder with an empty chain of providers.
+    pub fn new() -> ChainProviderBuilder {
+        ChainProviderBuilder::default()
+    }
+
+    /// Build the provider.
+    pub fn build(self) -> ChainProvider {
+        ChainProvider { links: self.links }
+    }
+
+    /// Append an `EnvironmentProvider` to the chain.
+    pub fn environment(self, provider: EnvironmentProvider) -> Self {
+        self.push_back(Self::ENVIRONMENT, ChainSource::Environment(provider))
+    }
+
+    /// Append a `ProfileProvider` to the chain.
+    pub fn profile(self, provider: ProfileProvider) -> Self {
+        self.push_back(Self::PROFILE, ChainSource::Profile(provider))
+    }
+
+    /// Append a `ContainerProvider` to the chain.
+    pub fn container(self, provider: ContainerProvider) -> Self {
+        self.push_back(Self::CONTAINER, ChainSource::Container(provider))
+    }
+
+    /// Append an `InstanceMetadataProvider` to the chain.
+    ///
+    /// The provider is skipped if the environment variable `AWS_EC2_METADATA_DISABLED` is set to
+    /// `true`.
+    pub fn instance_metadata(self, provider: InstanceMetadataProvider) -> Self {
+        self.push_back(
+            Self::INSTANCE_METADATA,
+            ChainSource::InstanceMetadata(provider),
+        )
+    }
+
+    /// Append a provider to the end of the chain.
+    pub fn append<N, P>(self, name: N, provider: P) -> Self
+    where
+        N: Into<String>,
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        self.push_back(name, ChainSource::Custom(Arc::new(provider)))
+    }
+
+    /// Prepend a provider to the start of the chain.
+    pub fn prepend<N, P>(mut self, name: N, provider: P) -> Self
+    where
+        N: Into<String>,
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        let name = name.into();
+        self = self.remove(&name);
+        self.links.insert(
+            0,
+            ChainLink {
+                name,
+                source: ChainSource::Custom(Arc::new(provider)),
+                timeout: None,
+            },
+        );
+        self
+    }
+
+    /// Remove the provider of the given name from the chain.
+    pub fn remove(mut self, name: &str) -> Self {
+        self.links.retain(|link| link.name != name);
+        self
+    }
+
+    /// Replace the provider of the given name, keeping its position and timeout in the chain.
+    ///
+    /// Does nothing if the chain has no provider of that name.
+    pub fn replace<P>(mut self, name: &str, provider: P) -> Self
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        if let Some(link) = self.links.iter_mut().find(|link| link.name == name) {
+            link.source = ChainSource::Custom(Arc::new(provider));
+        }
+        self
+    }
+
+    /// Limit the time the provider of the given name may take to return credentials. When the
+    /// timeout elapses, the next provider of the chain is tried.
+    ///
+    /// Does nothing if the chain has no provider of that name.
+    pub fn timeout(mut self, name: &str, timeout: Duration) -> Self {
+        if let Some(link) = self.links.iter_mut().find(|link| link.name == name) {
+            link.timeout = Some(timeout);
+        }
+        self
+    }
+
+    fn push_back<N>(self, name: N, source: ChainSource) -> Self
+    where
+        N: Into<String>,
+    {
+        let name = name.into();
+        let mut builder = self.remove(&name);
+        builder.links.push(ChainLink {
+            name,
+            source,
+            timeout: None,
+        });
+        builder
+    }
+}
+
+/// Whether the instance metadata service was disabled with `AWS_EC2_METADATA_DISABLED=true`.
+fn instance_metadata_disabled() -> bool {
+    non_empty_env_var(AWS_EC2_METADATA_DISABLED)
+        .map(|value| value.trim().eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
 /// This is a helper function as Option<T>::filter is not yet stable (see issue #45860).
 /// <https://github.com/rust-lang/rfcs/issues/2036> also affects the implementation of this.
 fn non_empty_env_var(name: &str) -> Option<String> {
@@ -459,6 +941,7 @@ fn parse_credentials_from_aws_service(response: &str) -> Result<AwsCredentials,
 
 #[cfg(test)]
 mod tests {
+    use std::env;
     use std::fs::{self, File};
     use std::io::Read;
     use std::path::Path;
@@ -485,6 +968,172 @@ mod tests {
         is_send_and_sync::<ChainProvider>();
         is_send_and_sync::<AutoRefreshingProvider<ChainProvider>>();
         is_send_and_sync::<DefaultCredentialsProvider>();
+        is_send_and_sync::<BoxedCredentialsProvider>();
+    }
+
+    #[tokio::test]
+    async fn boxed_providers() {
+        let arc: Arc<dyn ProvideAwsCredentials + Send + Sync> = Arc::new(static_provider("arc"));
+        let boxed: Box<dyn ProvideAwsCredentials + Send + Sync> = Box::new(static_provider("box"));
+        let auto = AutoRefreshingProvider::new(static_provider("auto")).unwrap();
+        let providers = vec![
+            BoxedCredentialsProvider::from(arc),
+            BoxedCredentialsProvider::from(boxed),
+            BoxedCredentialsProvider::new(auto),
+        ];
+        let mut keys = Vec::new();
+        for provider in providers {
+            let creds = provider.credentials().await.unwrap();
+            keys.push(creds.aws_access_key_id().to_owned());
+        }
+        assert_eq!(keys, vec!["arc", "box", "auto"]);
+    }
+
+    #[test]
+    fn instance_metadata_disabled_from_env() {
+        let _guard = lock_env();
+        env::remove_var(AWS_EC2_METADATA_DISABLED);
+        assert!(!instance_metadata_disabled());
+        env::set_var(AWS_EC2_METADATA_DISABLED, "false");
+        assert!(!instance_metadata_disabled());
+        env::set_var(AWS_EC2_METADATA_DISABLED, "TRUE");
+        assert!(instance_metadata_disabled());
+        env::remove_var(AWS_EC2_METADATA_DISABLED);
+    }
+
+    fn static_provider(key: &str) -> StaticProvider {
+        StaticProvider::new_minimal(key.to_owned(), "secret".to_owned())
+    }
+
+    struct SlowProvider;
+
+    #[async_trait]
+    impl ProvideAwsCredentials for SlowProvider {
+        async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+            tokio::time::delay_for(Duration::from_secs(60)).await;
+            Ok(AwsCredentials::new("slow", "secret", None, None))
+        }
+    }
+
+    #[tokio::test]
+    async fn chain_provider_builder_order() {
+        let builder = ChainProviderBuilder::new()
+            .append("first", static_provider("first"))
+            .append("second", static_provider("second"))
+            .prepend("zeroth", static_provider("zeroth"));
+        let provider = builder.clone().build();
+        assert_eq!(provider.provider_names(), vec!["zeroth", "first", "second"]);
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "zeroth");
+        assert_eq!(creds.source(), Some(&CredentialsSource::new("zeroth")));
+
+        let provider = builder
+            .clone()
+            .remove("zeroth")
+            .replace("first", static_provider("replaced"))
+            .build();
+        assert_eq!(provider.provider_names(), vec!["first", "second"]);
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "replaced");
+
+        // adding a provider under an existing name moves it
+        let provider = builder.append("zeroth", static_provider("last")).build();
+        assert_eq!(provider.provider_names(), vec!["first", "second", "zeroth"]);
+    }
+
+    #[tokio::test]
+    async fn chain_provider_records_source() {
+        let _guard = lock_env();
+        env::set_var("AWS_CONFIG_FILE", "tests/sample-data/no_such_config");
+        let provider = ChainProviderBuilder::new()
+            .profile(ProfileProvider::with_configuration(
+                "tests/sample-data/multiple_profile_credentials",
+                "foo",
+            ))
+            .build();
+        let creds = provider.credentials().await.expect("credentials");
+        env::remove_var("AWS_CONFIG_FILE");
+        let source = creds.source().expect("source");
+        assert_eq!(source.provider_name(), ChainProviderBuilder::PROFILE);
+        assert_eq!(source.profile(), Some("foo"));
+        assert_eq!(source.to_string(), "profile (profile foo)");
+
+        // the source recorded by a nested provider is kept
+        let mut creds = AwsCredentials::new("key", "secret", None, None);
+        creds.set_source(CredentialsSource::new("vault"));
+        let provider = ChainProviderBuilder::new()
+            .append("custom", StaticProvider::from(creds))
+            .build();
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.source().map(|s| s.provider_name()), Some("vault"));
+    }
+
+    #[tokio::test]
+    async fn chain_provider_builder_timeout() {
+        let provider = ChainProviderBuilder::new()
+            .append("slow", SlowProvider)
+            .timeout("slow", Duration::from_millis(10))
+            .append("static", static_provider("static"))
+            .build();
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "static");
+    }
+
+    #[tokio::test]
+    async fn empty_chain_provider() {
+        let provider = ChainProviderBuilder::new().build();
+        assert!(provider.credentials().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_with_cache() {
+        let cache = MemoryCredentialsCache::new();
+        let expired = AwsCredentials::new(
+            "expired",
+            "secret",
+            None,
+            Some(Utc::now() - ChronoDuration::minutes(1)),
+        );
+        cache.store(&expired).await.unwrap();
+
+        // expired credentials are replaced
+        let provider =
+            AutoRefreshingProvider::with_cache(static_provider("first"), cache.clone());
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "first");
+        assert_eq!(
+            cache.load().await.unwrap().unwrap().aws_access_key_id(),
+            "first"
+        );
+
+        // a provider sharing the cache uses the cached credentials
+        let provider =
+            AutoRefreshingProvider::with_cache(static_provider("second"), cache.clone());
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "first");
+    }
+
+    #[tokio::test]
+    async fn auto_refreshing_provider_skew_window() {
+        let cache = MemoryCredentialsCache::new();
+        let expiring = AwsCredentials::new(
+            "expiring",
+            "secret",
+            None,
+            Some(Utc::now() + ChronoDuration::minutes(10)),
+        );
+        cache.store(&expiring).await.unwrap();
+
+        let mut provider = AutoRefreshingProvider::with_cache(static_provider("new"), cache);
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "expiring"
+        );
+        provider.set_skew_window(Duration::from_secs(15 * 60));
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "new"
+        );
     }
 
     #[tokio::test]
@@ -1,24 +1,39 @@
 //! The Credentials Provider for Credentials stored in a profile inside of a Credentials file.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
 
 use async_trait::async_trait;
+use chrono::Duration as ChronoDuration;
 use dirs::home_dir;
 use regex::Regex;
-use serde::Deserialize;
-use tokio::process::Command;
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::{
+    clock, non_empty_env_var, AwsCredentials, CredentialProcessProvider, CredentialsError,
+    ProvideAwsCredentials, SsoProvider,
+};
 
 const AWS_CONFIG_FILE: &str = "AWS_CONFIG_FILE";
 const AWS_PROFILE: &str = "AWS_PROFILE";
 const AWS_SHARED_CREDENTIALS_FILE: &str = "AWS_SHARED_CREDENTIALS_FILE";
 const DEFAULT: &str = "default";
 const REGION: &str = "region";
+const ROLE_ARN: &str = "role_arn";
+const SOURCE_PROFILE: &str = "source_profile";
+const CREDENTIAL_SOURCE: &str = "credential_source";
+const ROLE_SESSION_NAME: &str = "role_session_name";
+const DURATION_SECONDS: &str = "duration_seconds";
+const MFA_SERIAL: &str = "mfa_serial";
+const EXTERNAL_ID: &str = "external_id";
+const WEB_IDENTITY_TOKEN_FILE: &str = "web_identity_token_file";
+const ENDPOINT_URL: &str = "endpoint_url";
+const SERVICES: &str = "services";
 
 /// Provides AWS credentials from a profile in a credentials file, or from a credential process.
 ///
@@ -31,12 +46,53 @@ const REGION: &str = "region";
 /// best practices for your operating system.
 ///
 /// [credential_process]: https://docs.aws.amazon.com/cli/latest/topic/config-vars.html#sourcing-credentials-from-external-processes
+///
+/// # Reloading
+///
+/// Credentials read from the files don't expire, so wrapping providers like
+/// `AutoRefreshingProvider` and `DefaultCredentialsProvider` keep using them even after another
+/// tool rotated the keys in the credentials file. With `set_reload_interval`, the provider
+/// gives them an expiry time instead, so they're loaded again from the current files once the
+/// interval elapsed:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use rusoto_credential::{AutoRefreshingProvider, ChainProvider, ProfileProvider};
+///
+/// let mut profile_provider = ProfileProvider::new().unwrap();
+/// profile_provider.set_reload_interval(Duration::from_secs(300));
+/// let provider =
+///     AutoRefreshingProvider::new(ChainProvider::with_profile_provider(profile_provider)).unwrap();
+/// ```
 #[derive(Clone, Debug)]
 pub struct ProfileProvider {
     /// The File Path the Credentials File is located at.
     file_path: PathBuf,
     /// The Profile Path to parse out of the Credentials File.
     profile: String,
+    /// How long credentials without an expiry time are used before the files are read again.
+    reload_interval: Option<Duration>,
+    /// The Credentials File as last parsed, reused while it isn't modified.
+    parsed: ParsedCredentialsFile,
+}
+
+/// The modification time and length of a credentials file, which change when it's rewritten.
+type FileStamp = (SystemTime, u64);
+
+/// A credentials file parsed by a reloading `ProfileProvider`, shared by its clones.
+#[derive(Clone, Default)]
+struct ParsedCredentialsFile(Arc<Mutex<Option<ParsedProfiles>>>);
+
+struct ParsedProfiles {
+    stamp: FileStamp,
+    profiles: HashMap<String, AwsCredentials>,
+}
+
+impl fmt::Debug for ParsedCredentialsFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ParsedCredentialsFile")
+    }
 }
 
 impl ProfileProvider {
@@ -58,6 +114,8 @@ impl ProfileProvider {
         ProfileProvider {
             file_path: file_path.into(),
             profile: profile.into(),
+            reload_interval: None,
+            parsed: ParsedCredentialsFile::default(),
         }
     }
 
@@ -74,13 +132,15 @@ impl ProfileProvider {
     /// Create a new `ProfileProvider` for the default credentials file path using
     /// the given profile.
     pub fn with_default_credentials<P>(profile: P) -> Result<ProfileProvider, CredentialsError>
-        where
-            P: Into<String>,
+    where
+        P: Into<String>,
     {
         let profile_location = ProfileProvider::default_profile_location()?;
         Ok(ProfileProvider {
             file_path: profile_location.into(),
             profile: profile.into(),
+            reload_interval: None,
+            parsed: ParsedCredentialsFile::default(),
         })
     }
 
@@ -105,19 +165,33 @@ impl ProfileProvider {
     /// config file path (`ProfileProvider.file_path`) and profile (`ProfileProvider.profile`).
     /// As these fields do not require a region field to be defined, an `Option` type is returned
     pub fn region_from_profile(&self) -> Result<Option<String>, CredentialsError> {
-        Ok(
-            parse_config_file(&self.file_path).and_then(|config| {
-                config
-                    .get(&self.profile)
-                    .and_then(|props| props.get(REGION))
-                    .map(std::borrow::ToOwned::to_owned)
-            }))
+        Ok(parse_config_file(&self.file_path).and_then(|config| {
+            config
+                .get(&self.profile)
+                .and_then(|props| props.get(REGION))
+                .map(std::borrow::ToOwned::to_owned)
+        }))
+    }
+
+    /// Attempts to resolve the endpoint URLs configured in the current default profile of
+    /// `~/.aws/config` or the file associated with the `AWS_CONFIG_FILE` environment variable:
+    /// its `endpoint_url` setting, and the `endpoint_url` settings of the services in the
+    /// `[services]` section referenced by its `services` setting.
+    ///
+    /// For the full endpoint resolution chain, including the `AWS_ENDPOINT_URL` environment
+    /// variables, see `rusoto_core::ClientConfig`.
+    pub fn endpoint_urls() -> Result<ProfileEndpointUrls, CredentialsError> {
+        let location = ProfileProvider::default_config_location()?;
+        Ok(endpoint_urls(
+            &location,
+            &ProfileProvider::default_profile_name(),
+        ))
     }
 
     /// Default config file location:
     /// 1: if set and not empty, use the value from environment variable ```AWS_CONFIG_FILE```
     /// 2. otherwise return `~/.aws/config` (Linux/Mac) resp. `%USERPROFILE%\.aws\config` (Windows)
-    fn default_config_location() -> Result<PathBuf, CredentialsError> {
+    pub(crate) fn default_config_location() -> Result<PathBuf, CredentialsError> {
         let env = non_empty_env_var(AWS_CONFIG_FILE);
         match env {
             Some(path) => Ok(PathBuf::from(path)),
@@ -162,7 +236,7 @@ impl ProfileProvider {
     /// 1. if set and not empty, use value from environment variable ```AWS_PROFILE```
     /// 2. otherwise return ```"default"```
     /// see https://docs.aws.amazon.com/sdk-for-java/v1/developer-guide/credentials.html.
-    fn default_profile_name() -> String {
+    pub(crate) fn default_profile_name() -> String {
         non_empty_env_var(AWS_PROFILE).unwrap_or_else(|| DEFAULT.to_owned())
     }
 
@@ -182,6 +256,7 @@ impl ProfileProvider {
         F: Into<PathBuf>,
     {
         self.file_path = file_path.into();
+        self.parsed = ParsedCredentialsFile::default();
     }
 
     /// Set the profile name.
@@ -191,65 +266,363 @@ impl ProfileProvider {
     {
         self.profile = profile.into();
     }
-}
 
-#[async_trait]
-impl ProvideAwsCredentials for ProfileProvider {
-    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        match ProfileProvider::default_config_location().map(|location| {
-            parse_config_file(&location).and_then(|config| {
-                config
-                    .get(&ProfileProvider::default_profile_name())
-                    .and_then(|props| props.get("credential_process"))
-                    .map(std::borrow::ToOwned::to_owned)
-            })
-        }) {
-            Ok(Some(command)) => {
-                // credential_process is set, create the future
-                let mut command = parse_command_str(&command)?;
-                let output = command.output().await.map_err(|e| {
-                    CredentialsError::new(format!("Credential process failed: {:?}", e))
-                })?;
-                if output.status.success() {
-                    parse_credential_process_output(&output.stdout)
-                } else {
-                    Err(CredentialsError::new(format!(
-                        "Credential process failed with {}: {}",
-                        output.status,
-                        String::from_utf8_lossy(&output.stderr)
-                    )))
-                }
+    /// Set how long credentials which don't expire by themselves, like the keys of the
+    /// credentials file, are used before the credentials and config files are read again. The
+    /// credentials file is only parsed again if it was modified.
+    ///
+    /// The interval should be longer than the window in which wrapping providers refresh
+    /// credentials before they expire, 20 seconds for `AutoRefreshingProvider`, or the files
+    /// are read every time credentials are requested.
+    pub fn set_reload_interval(&mut self, interval: Duration) {
+        self.reload_interval = Some(interval);
+    }
+
+    /// Get the reload interval, if set.
+    pub fn reload_interval(&self) -> Option<Duration> {
+        self.reload_interval
+    }
+
+    /// Parses the credentials file, unless it wasn't modified since it was last parsed by a
+    /// reloading provider.
+    fn parse_credentials_file(&self) -> Result<HashMap<String, AwsCredentials>, CredentialsError> {
+        if self.reload_interval.is_none() {
+            return parse_credentials_file(self.file_path());
+        }
+        let stamp = fs::metadata(self.file_path())
+            .ok()
+            .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())));
+        let mut parsed = self.parsed.0.lock().unwrap();
+        if let (Some(stamp), Some(ref parsed)) = (stamp, &*parsed) {
+            if stamp == parsed.stamp {
+                return Ok(parsed.profiles.clone());
             }
-            Ok(None) => {
-                // credential_process is not set, parse the credentials file
-                parse_credentials_file(self.file_path()).and_then(|mut profiles| {
-                    profiles
-                        .remove(self.profile())
-                        .ok_or_else(|| CredentialsError::new("profile not found"))
-                })
+        }
+        let profiles = parse_credentials_file(self.file_path())?;
+        *parsed = stamp.map(|stamp| ParsedProfiles {
+            stamp,
+            profiles: profiles.clone(),
+        });
+        Ok(profiles)
+    }
+
+    /// Gives credentials without an expiry time one, if the provider reloads credentials.
+    fn expire_for_reload(&self, mut credentials: AwsCredentials) -> AwsCredentials {
+        if let Some(interval) = self.reload_interval {
+            if credentials.expires_at.is_none() {
+                credentials.expires_at =
+                    Some(clock::now() + ChronoDuration::seconds(interval.as_secs() as i64));
             }
-            Err(err) => Err(err),
         }
+        credentials
     }
+
+    /// Returns an `SsoProvider` if the profile is configured for AWS SSO in the config file.
+    fn sso_provider(&self) -> Result<Option<SsoProvider>, CredentialsError> {
+        let location = ProfileProvider::default_config_location()?;
+        let config = match parse_config_file(&location) {
+            Some(config) => config,
+            None => return Ok(None),
+        };
+        match config.get(self.profile()) {
+            Some(profile) if SsoProvider::is_configured(profile) => {
+                Ok(Some(SsoProvider::from_config(&config, self.profile())?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Resolves the roles to assume for the profile, following the `role_arn`,
+    /// `source_profile`, `credential_source` and `web_identity_token_file` settings of the
+    /// config and credentials files.
+    ///
+    /// Returns `None` if the profile doesn't set `role_arn`. Assuming the roles requires calls
+    /// to STS, which are performed by the `AssumeRoleProfileProvider` of `rusoto_sts`.
+    pub fn role_chain(&self) -> Result<Option<RoleChain>, CredentialsError> {
+        let config = ProfileProvider::default_config_location()
+            .ok()
+            .and_then(|location| parse_config_file(&location))
+            .unwrap_or_default();
+        let credentials = parse_config_file(self.file_path()).unwrap_or_default();
+        resolve_role_chain(self.profile(), self.file_path(), |profile| {
+            // settings of the credentials file take precedence over the config file
+            let mut props = config.get(profile).cloned();
+            if let Some(overrides) = credentials.get(profile) {
+                props
+                    .get_or_insert_with(HashMap::new)
+                    .extend(overrides.clone());
+            }
+            props
+        })
+    }
+}
+
+/// Where the credentials used to assume the first role of a [`RoleChain`] come from.
+#[derive(Clone, Debug)]
+pub enum RoleChainSource {
+    /// The credentials of a profile, named by `source_profile`.
+    Profile(ProfileProvider),
+    /// `credential_source = Environment`: the credentials of the `EnvironmentProvider`.
+    Environment,
+    /// `credential_source = Ec2InstanceMetadata`: the credentials of the
+    /// `InstanceMetadataProvider`.
+    Ec2InstanceMetadata,
+    /// `credential_source = EcsContainer`: the credentials of the `ContainerProvider`.
+    EcsContainer,
+    /// A profile setting `web_identity_token_file`, whose role is assumed with
+    /// `AssumeRoleWithWebIdentity`.
+    WebIdentity {
+        /// The name of the profile.
+        profile: String,
+        /// The ARN of the role to assume.
+        role_arn: String,
+        /// The path of the file containing the web identity token.
+        web_identity_token_file: PathBuf,
+        /// The `role_session_name` setting of the profile.
+        role_session_name: Option<String>,
+    },
 }
 
-#[derive(Deserialize)]
-struct CredentialProcessOutput {
-    #[serde(flatten)]
-    creds: AwsCredentials,
-    #[serde(rename = "Version")]
-    version: u8,
+/// The endpoint URLs configured in a profile, see `ProfileProvider::endpoint_urls`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfileEndpointUrls {
+    /// The `endpoint_url` setting of the profile, used for all services.
+    pub endpoint_url: Option<String>,
+    /// The `endpoint_url` settings of services, by the service keys of the `[services]`
+    /// section, like `dynamodb` or `elastic_load_balancing_v2`.
+    pub services: HashMap<String, String>,
 }
 
-fn parse_credential_process_output(v: &[u8]) -> Result<AwsCredentials, CredentialsError> {
-    let output: CredentialProcessOutput = serde_json::from_slice(v)?;
-    if output.version == 1 {
-        Ok(output.creds)
-    } else {
-        Err(CredentialsError::new(format!(
-            "Unsupported version '{}' for credential process provider, supported versions: 1",
-            output.version
-        )))
+fn endpoint_urls(file_path: &Path, profile: &str) -> ProfileEndpointUrls {
+    let profile = parse_config_file(file_path).and_then(|mut config| config.remove(profile));
+    let profile = match profile {
+        Some(profile) => profile,
+        None => return ProfileEndpointUrls::default(),
+    };
+    let services = profile
+        .get(SERVICES)
+        .and_then(|name| parse_services_section(file_path, name))
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(service, mut settings)| {
+            settings
+                .remove(ENDPOINT_URL)
+                .map(|endpoint_url| (service.to_lowercase(), endpoint_url))
+        })
+        .collect();
+    ProfileEndpointUrls {
+        endpoint_url: profile.get(ENDPOINT_URL).cloned(),
+        services,
+    }
+}
+
+/// Parses the settings of the services in a `[services name]` section, where every service
+/// starts a sub-section of indented settings:
+///
+/// ```ini
+/// [services local]
+/// dynamodb =
+///   endpoint_url = http://localhost:8000
+/// ```
+fn parse_services_section(
+    file_path: &Path,
+    name: &str,
+) -> Option<HashMap<String, HashMap<String, String>>> {
+    let file = File::open(file_path).ok()?;
+    let header = format!("[services {}]", name);
+    let mut services = HashMap::new();
+    let mut in_section = false;
+    let mut service: Option<String> = None;
+    for line in BufReader::new(file).lines() {
+        let line = line.ok()?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') {
+            in_section = line == header;
+            service = None;
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        let mut parts = line.splitn(2, '=').map(str::trim);
+        let key = parts.next().unwrap_or_default();
+        let value = parts.next().unwrap_or_default();
+        if value.is_empty() {
+            // a service starting a sub-section
+            service = Some(key.to_owned());
+        } else if let Some(ref service) = service {
+            services
+                .entry(service.clone())
+                .or_insert_with(HashMap::new)
+                .insert(key.to_owned(), value.to_owned());
+        }
+    }
+    Some(services)
+}
+
+/// A role to assume, as configured by the `role_arn` setting of a profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileRole {
+    /// The name of the profile setting `role_arn`.
+    pub profile: String,
+    /// The ARN of the role to assume.
+    pub role_arn: String,
+    /// The `role_session_name` setting of the profile.
+    pub role_session_name: Option<String>,
+    /// The `duration_seconds` setting of the profile.
+    pub duration_seconds: Option<i64>,
+    /// The `mfa_serial` setting of the profile: the serial number or ARN of the MFA device
+    /// whose code has to be passed to `AssumeRole`.
+    pub mfa_serial: Option<String>,
+    /// The `external_id` setting of the profile, required by the trust policy of some
+    /// cross-account roles.
+    pub external_id: Option<String>,
+}
+
+/// The roles to assume to get the credentials of a profile, see `ProfileProvider::role_chain`.
+#[derive(Clone, Debug)]
+pub struct RoleChain {
+    /// The source of the credentials used to assume the first role.
+    pub source: RoleChainSource,
+    /// The roles in the order they have to be assumed, each one with the credentials of the
+    /// previous one. The credentials of the last role are the credentials of the profile.
+    pub roles: Vec<ProfileRole>,
+}
+
+/// Follows `source_profile` from `profile` until a profile without `role_arn`, a profile
+/// referencing itself, a profile setting `web_identity_token_file`, or a `credential_source`
+/// is found.
+fn resolve_role_chain<F>(
+    profile: &str,
+    credentials_file: &Path,
+    props_of: F,
+) -> Result<Option<RoleChain>, CredentialsError>
+where
+    F: Fn(&str) -> Option<HashMap<String, String>>,
+{
+    let mut roles: Vec<ProfileRole> = Vec::new();
+    let mut current = profile.to_owned();
+    let source = loop {
+        let props = match props_of(&current) {
+            Some(props) => props,
+            None if roles.is_empty() => return Ok(None),
+            None => {
+                return Err(CredentialsError::new(format!(
+                    "Source profile {} not found",
+                    current
+                )))
+            }
+        };
+        let role_arn = match props.get(ROLE_ARN) {
+            Some(role_arn) => role_arn.to_owned(),
+            None if roles.is_empty() => return Ok(None),
+            None => {
+                break RoleChainSource::Profile(ProfileProvider::with_configuration(
+                    credentials_file,
+                    current,
+                ))
+            }
+        };
+        if let Some(token_file) = props.get(WEB_IDENTITY_TOKEN_FILE) {
+            break RoleChainSource::WebIdentity {
+                profile: current,
+                role_arn,
+                web_identity_token_file: PathBuf::from(token_file),
+                role_session_name: props.get(ROLE_SESSION_NAME).cloned(),
+            };
+        }
+        if roles.iter().any(|role| role.profile == current) {
+            return Err(CredentialsError::new(format!(
+                "Infinite loop in the source_profile of profile {}",
+                current
+            )));
+        }
+        let duration_seconds = match props.get(DURATION_SECONDS) {
+            Some(duration) => Some(duration.parse::<i64>().map_err(|_| {
+                CredentialsError::new(format!(
+                    "Invalid duration_seconds in profile {}: {}",
+                    current, duration
+                ))
+            })?),
+            None => None,
+        };
+        roles.push(ProfileRole {
+            profile: current.clone(),
+            role_arn,
+            role_session_name: props.get(ROLE_SESSION_NAME).cloned(),
+            duration_seconds,
+            mfa_serial: props.get(MFA_SERIAL).cloned(),
+            external_id: props.get(EXTERNAL_ID).cloned(),
+        });
+        match (props.get(SOURCE_PROFILE), props.get(CREDENTIAL_SOURCE)) {
+            (Some(_), Some(_)) => {
+                return Err(CredentialsError::new(format!(
+                    "Profile {} sets both source_profile and credential_source",
+                    current
+                )))
+            }
+            // a profile referencing itself assumes its role with its own credentials
+            (Some(source_profile), None) if *source_profile == current => {
+                break RoleChainSource::Profile(ProfileProvider::with_configuration(
+                    credentials_file,
+                    current,
+                ))
+            }
+            (Some(source_profile), None) => current = source_profile.to_owned(),
+            (None, Some(credential_source)) => {
+                break match credential_source.as_str() {
+                    "Environment" => RoleChainSource::Environment,
+                    "Ec2InstanceMetadata" => RoleChainSource::Ec2InstanceMetadata,
+                    "EcsContainer" => RoleChainSource::EcsContainer,
+                    _ => {
+                        return Err(CredentialsError::new(format!(
+                            "Unsupported credential_source in profile {}: {}",
+                            current, credential_source
+                        )))
+                    }
+                }
+            }
+            (None, None) => {
+                return Err(CredentialsError::new(format!(
+                    "Profile {} sets role_arn without source_profile or credential_source",
+                    current
+                )))
+            }
+        }
+    };
+    roles.reverse();
+    Ok(Some(RoleChain { source, roles }))
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for ProfileProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let credentials = match CredentialProcessProvider::from_profile(self.profile()) {
+            Ok(Some(provider)) => provider.credentials().await,
+            Ok(None) => {
+                // credential_process is not set, use SSO if the profile is configured for it
+                if let Some(sso) = self.sso_provider()? {
+                    return sso.credentials().await;
+                }
+                // otherwise parse the credentials file
+                self.parse_credentials_file().and_then(|mut profiles| {
+                    profiles.remove(self.profile()).ok_or_else(|| {
+                        match self.role_chain() {
+                            Ok(Some(_)) => CredentialsError::new(format!(
+                                "profile {} assumes a role, which requires rusoto_sts::AssumeRoleProfileProvider",
+                                self.profile()
+                            )),
+                            _ => CredentialsError::new("profile not found"),
+                        }
+                    })
+                })
+            }
+            Err(err) => Err(err),
+        };
+        credentials.map(|credentials| self.expire_for_reload(credentials))
     }
 }
 
@@ -258,7 +631,9 @@ fn new_profile_regex() -> Regex {
     Regex::new(r"^\[(profile )?([^\]]+)\]$").expect("Failed to compile regex")
 }
 
-fn parse_config_file(file_path: &Path) -> Option<HashMap<String, HashMap<String, String>>> {
+pub(crate) fn parse_config_file(
+    file_path: &Path,
+) -> Option<HashMap<String, HashMap<String, String>>> {
     match fs::metadata(file_path) {
         Err(_) => return None,
         Ok(metadata) => {
@@ -409,18 +784,6 @@ fn parse_credentials_file(
     Ok(profiles)
 }
 
-fn parse_command_str(s: &str) -> Result<Command, CredentialsError> {
-    let args = shlex::split(s)
-        .ok_or_else(|| CredentialsError::new("Unable to parse credential_process value."))?;
-    let mut iter = args.iter();
-    let mut command = Command::new(
-        iter.next()
-            .ok_or_else(|| CredentialsError::new("credential_process value is empty."))?,
-    );
-    command.args(iter);
-    Ok(command)
-}
-
 #[cfg(test)]
 mod tests {
     use std::env;
@@ -443,6 +806,31 @@ mod tests {
         assert_eq!(default_profile.get("output"), Some(&"json".to_string()));
     }
 
+    #[test]
+    fn endpoint_urls_of_profiles() {
+        let path = Path::new("tests/sample-data/endpoint_url_config");
+        let urls = endpoint_urls(path, DEFAULT);
+        assert_eq!(urls.endpoint_url, Some("http://localhost:4566".to_owned()));
+        assert_eq!(urls.services.len(), 2);
+        assert_eq!(
+            urls.services.get("sqs"),
+            Some(&"http://localhost:9324".to_owned())
+        );
+        assert_eq!(
+            urls.services.get("dynamodb"),
+            Some(&"http://localhost:8000".to_owned())
+        );
+
+        let urls = endpoint_urls(path, "other");
+        assert_eq!(urls.endpoint_url, None);
+        assert_eq!(urls.services.len(), 2);
+
+        assert_eq!(
+            endpoint_urls(path, "missing"),
+            ProfileEndpointUrls::default()
+        );
+    }
+
     #[test]
     fn parse_config_file_multiple_profiles() {
         let result =
@@ -622,6 +1010,22 @@ mod tests {
         env::remove_var(AWS_CONFIG_FILE);
     }
 
+    #[tokio::test]
+    async fn profile_provider_credential_process_named_profile() {
+        let _guard = lock_env();
+        env::set_var(
+            AWS_CONFIG_FILE,
+            "tests/sample-data/credential_process_config",
+        );
+        let mut provider = ProfileProvider::new().unwrap();
+        provider.set_profile("foo");
+        let result = provider.credentials().await;
+        env::remove_var(AWS_CONFIG_FILE);
+
+        let creds = result.expect("credentials from the credential process of profile foo");
+        assert_eq!(creds.aws_access_key_id(), "foo_access_key");
+    }
+
     #[test]
     fn profile_provider_profile_name() {
         let _guard = lock_env();
@@ -736,16 +1140,11 @@ mod tests {
 
     #[test]
     fn region_from_profile() {
-        let provider = ProfileProvider::with_configuration(
-            "tests/sample-data/multiple_profile_config",
-            "foo",
-        );
+        let provider =
+            ProfileProvider::with_configuration("tests/sample-data/multiple_profile_config", "foo");
         let maybe_region = provider.region_from_profile().unwrap();
 
-        assert_eq!(
-            maybe_region,
-            Some("us-east-3".to_string())
-        );
+        assert_eq!(maybe_region, Some("us-east-3".to_string()));
     }
 
     #[test]
@@ -756,10 +1155,147 @@ mod tests {
         );
         let maybe_region = provider.region_from_profile().unwrap();
 
+        assert_eq!(maybe_region, None);
+    }
+
+    fn role_chain(profile: &str) -> Result<Option<RoleChain>, CredentialsError> {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/no_such_config");
+        let result = ProfileProvider::with_configuration(
+            "tests/sample-data/role_chain_credentials",
+            profile,
+        )
+        .role_chain();
+        env::remove_var(AWS_CONFIG_FILE);
+        result
+    }
+
+    #[test]
+    fn role_chain_nested_profiles() {
+        let chain = role_chain("role-b").unwrap().expect("role chain");
+        match chain.source {
+            RoleChainSource::Profile(ref provider) => assert_eq!(provider.profile(), "base"),
+            ref source => panic!("unexpected source {:?}", source),
+        }
         assert_eq!(
-            maybe_region,
-            None
+            chain.roles,
+            vec![
+                ProfileRole {
+                    profile: "role-a".to_owned(),
+                    role_arn: "arn:aws:iam::123456789012:role/a".to_owned(),
+                    role_session_name: Some("session-a".to_owned()),
+                    duration_seconds: None,
+                    mfa_serial: None,
+                    external_id: Some("external-a".to_owned()),
+                },
+                ProfileRole {
+                    profile: "role-b".to_owned(),
+                    role_arn: "arn:aws:iam::123456789012:role/b".to_owned(),
+                    role_session_name: None,
+                    duration_seconds: Some(1800),
+                    mfa_serial: Some("arn:aws:iam::123456789012:mfa/user".to_owned()),
+                    external_id: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn role_chain_sources() {
+        let chain = role_chain("self").unwrap().expect("role chain");
+        match chain.source {
+            RoleChainSource::Profile(ref provider) => assert_eq!(provider.profile(), "self"),
+            ref source => panic!("unexpected source {:?}", source),
+        }
+        assert_eq!(chain.roles.len(), 1);
+
+        let chain = role_chain("instance").unwrap().expect("role chain");
+        match chain.source {
+            RoleChainSource::Ec2InstanceMetadata => {}
+            ref source => panic!("unexpected source {:?}", source),
+        }
+
+        let chain = role_chain("web-identity-chain")
+            .unwrap()
+            .expect("role chain");
+        match chain.source {
+            RoleChainSource::WebIdentity {
+                ref profile,
+                ref role_arn,
+                ref web_identity_token_file,
+                ref role_session_name,
+            } => {
+                assert_eq!(profile, "web-identity");
+                assert_eq!(role_arn, "arn:aws:iam::123456789012:role/web-identity");
+                assert_eq!(web_identity_token_file, Path::new("/var/run/token"));
+                assert_eq!(role_session_name.as_deref(), Some("web-session"));
+            }
+            ref source => panic!("unexpected source {:?}", source),
+        }
+        assert_eq!(chain.roles.len(), 1);
+
+        assert!(role_chain("base").unwrap().is_none());
+        assert!(role_chain("does-not-exist").unwrap().is_none());
+    }
+
+    #[test]
+    fn role_chain_errors() {
+        assert!(role_chain("cycle-a").is_err());
+        assert!(role_chain("no-source").is_err());
+        assert!(role_chain("missing-source").is_err());
+    }
+
+    #[tokio::test]
+    async fn profile_provider_role_profile() {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/no_such_config");
+        let provider = ProfileProvider::with_configuration(
+            "tests/sample-data/role_chain_credentials",
+            "role-a",
         );
+        let err = provider.credentials().await.unwrap_err();
+        assert!(err.message.contains("AssumeRoleProfileProvider"));
+        // the credentials of a profile referencing itself are still available
+        let provider =
+            ProfileProvider::with_configuration("tests/sample-data/role_chain_credentials", "self");
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "self_access_key");
+        env::remove_var(AWS_CONFIG_FILE);
     }
 
+    #[tokio::test]
+    async fn profile_provider_reloads_rotated_keys() {
+        let _guard = lock_env();
+        env::set_var(AWS_CONFIG_FILE, "tests/sample-data/no_such_config");
+        let dir = tempfile::tempdir().expect("temporary directory");
+        let path = dir.path().join("credentials");
+        fs::write(
+            &path,
+            "[default]\naws_access_key_id = old_key\naws_secret_access_key = secret\n",
+        )
+        .unwrap();
+
+        let mut provider = ProfileProvider::with_configuration(&path, "default");
+        let provider_without_reload = provider.clone();
+        provider.set_reload_interval(Duration::from_secs(0));
+        let provider = crate::AutoRefreshingProvider::new(provider).unwrap();
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "old_key");
+        assert!(creds.expires_at().is_some());
+        assert!(provider_without_reload
+            .credentials()
+            .await
+            .unwrap()
+            .expires_at()
+            .is_none());
+
+        fs::write(
+            &path,
+            "[default]\naws_access_key_id = rotated_key\naws_secret_access_key = secret\n",
+        )
+        .unwrap();
+        let creds = provider.credentials().await.unwrap();
+        assert_eq!(creds.aws_access_key_id(), "rotated_key");
+        env::remove_var(AWS_CONFIG_FILE);
+    }
 }
@@ -0,0 +1,224 @@
+//! A Credentials Provider refreshing credentials through a user-supplied function.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Duration as ChronoDuration;
+use tokio::sync::Mutex;
+
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// Credentials are refreshed when they expire within this many seconds, by default.
+const DEFAULT_REFRESH_WINDOW_SECONDS: u64 = 300;
+
+type RefreshFuture = Pin<Box<dyn Future<Output = Result<AwsCredentials, CredentialsError>> + Send>>;
+
+/// Provides AWS credentials obtained by calling an async function, and calls it again shortly
+/// before they expire.
+///
+/// This is the easiest way to use a bespoke source of temporary credentials, like an internal
+/// vault or an HSM: the function only has to fetch new credentials, the provider keeps them
+/// until they're about to expire. Concurrent requests for credentials wait for a single call of
+/// the function.
+///
+/// If a refresh fails while the current credentials haven't actually expired yet, the current
+/// credentials are returned and the function is called again on the next request. Otherwise
+/// the error is returned, and the next request tries again. Credentials without an expiry time
+/// are never refreshed.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{Duration, Utc};
+/// use rusoto_credential::{AwsCredentials, ProvideAwsCredentials, RefreshableProvider};
+///
+/// # async fn example() {
+/// let provider = RefreshableProvider::new(|| async {
+///     // fetch the credentials from the vault
+///     Ok(AwsCredentials::new(
+///         "key",
+///         "secret",
+///         Some("token".to_owned()),
+///         Some(Utc::now() + Duration::hours(1)),
+///     ))
+/// });
+/// let credentials = provider.credentials().await.unwrap();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RefreshableProvider {
+    refresh: Arc<dyn Fn() -> RefreshFuture + Send + Sync>,
+    credentials: Arc<Mutex<Option<AwsCredentials>>>,
+    refresh_window: ChronoDuration,
+}
+
+impl RefreshableProvider {
+    /// Create a new `RefreshableProvider` calling the given function for credentials.
+    pub fn new<F, Fut>(refresh: F) -> RefreshableProvider
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AwsCredentials, CredentialsError>> + Send + 'static,
+    {
+        RefreshableProvider {
+            refresh: Arc::new(move || Box::pin(refresh()) as RefreshFuture),
+            credentials: Arc::new(Mutex::new(None)),
+            refresh_window: ChronoDuration::seconds(DEFAULT_REFRESH_WINDOW_SECONDS as i64),
+        }
+    }
+
+    /// Create a new `RefreshableProvider` returning the given credentials until they are about
+    /// to expire, then calling the given function for new ones.
+    pub fn with_credentials<F, Fut>(credentials: AwsCredentials, refresh: F) -> RefreshableProvider
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AwsCredentials, CredentialsError>> + Send + 'static,
+    {
+        let provider = RefreshableProvider::new(refresh);
+        RefreshableProvider {
+            credentials: Arc::new(Mutex::new(Some(credentials))),
+            ..provider
+        }
+    }
+
+    /// Set how long before they expire credentials are refreshed. Defaults to 5 minutes.
+    pub fn set_refresh_window(&mut self, window: Duration) {
+        self.refresh_window = ChronoDuration::seconds(window.as_secs() as i64);
+    }
+}
+
+impl fmt::Debug for RefreshableProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshableProvider")
+            .field("refresh_window", &self.refresh_window)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for RefreshableProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut credentials = self.credentials.lock().await;
+        if let Some(ref current) = *credentials {
+            if !current.credentials_are_expired(self.refresh_window) {
+                return Ok(current.clone());
+            }
+        }
+        match (self.refresh)().await {
+            Ok(refreshed) => {
+                *credentials = Some(refreshed.clone());
+                Ok(refreshed)
+            }
+            Err(e) => match *credentials {
+                Some(ref current) if !current.credentials_are_expired(ChronoDuration::zero()) => {
+                    Ok(current.clone())
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting_provider(
+        calls: Arc<AtomicUsize>,
+        valid_for: ChronoDuration,
+        fail_after: usize,
+    ) -> RefreshableProvider {
+        RefreshableProvider::new(move || {
+            let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if call > fail_after {
+                    return Err(CredentialsError::new("vault unavailable"));
+                }
+                Ok(AwsCredentials::new(
+                    format!("key{}", call),
+                    "secret",
+                    None,
+                    Some(clock::now() + valid_for),
+                ))
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn credentials_are_reused_until_they_expire() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = counting_provider(calls.clone(), ChronoDuration::hours(1), usize::MAX);
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "key1"
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "key1"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // credentials within the refresh window are refreshed
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = counting_provider(calls.clone(), ChronoDuration::minutes(1), usize::MAX);
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "key1"
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "key2"
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_refresh() {
+        // valid credentials are used while the refresh fails
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = counting_provider(calls.clone(), ChronoDuration::minutes(1), 1);
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "key1"
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "key1"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // expired credentials aren't
+        let provider = RefreshableProvider::with_credentials(
+            AwsCredentials::new("initial", "secret", None, Some(clock::now())),
+            || async { Err(CredentialsError::new("vault unavailable")) },
+        );
+        assert!(provider.credentials().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn initial_credentials() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let provider = RefreshableProvider::with_credentials(
+            AwsCredentials::new(
+                "initial",
+                "secret",
+                None,
+                Some(clock::now() + ChronoDuration::hours(1)),
+            ),
+            move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Err(CredentialsError::new("not called")) }
+            },
+        );
+        assert_eq!(
+            provider.credentials().await.unwrap().aws_access_key_id(),
+            "initial"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
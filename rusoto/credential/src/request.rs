@@ -3,14 +3,15 @@ use std::io::ErrorKind;
 use std::time::Duration;
 
 use futures::StreamExt;
+use hyper::client::connect::Connect;
 use hyper::client::HttpConnector;
-use hyper::{Body, Client as HyperClient, Request, Uri};
+use hyper::{Body, Client as HyperClient, Request, StatusCode};
 use tokio::time;
 
 /// Http client for use in a credentials provider.
 #[derive(Debug, Clone)]
-pub struct HttpClient {
-    inner: HyperClient<HttpConnector>,
+pub struct HttpClient<C = HttpConnector> {
+    inner: HyperClient<C>,
 }
 
 impl HttpClient {
@@ -21,23 +22,47 @@ impl HttpClient {
         }
     }
 
-    pub async fn get(&self, uri: Uri, timeout: Duration) -> Result<String, IoError> {
-        match Request::get(uri).body(Body::empty()) {
-            Ok(request) => self.request(request, timeout).await,
-            Err(err) => Err(IoError::new(
-                ErrorKind::Other,
-                format!("Invalid request: {}", err),
-            )),
+    /// Create an http client giving up on connection attempts after the given timeout.
+    pub fn with_connect_timeout(connect_timeout: Option<Duration>) -> HttpClient {
+        let mut connector = HttpConnector::new();
+        connector.set_connect_timeout(connect_timeout);
+        HttpClient {
+            inner: HyperClient::builder().build(connector),
         }
     }
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+impl HttpClient<crate::tls::HttpsConnector<HttpConnector>> {
+    /// Create a tls-enabled http client.
+    pub fn new_https() -> Self {
+        HttpClient {
+            inner: HyperClient::builder().build(crate::tls::HttpsConnector::new()),
+        }
+    }
+}
 
+impl<C> HttpClient<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
     pub async fn request(&self, req: Request<Body>, timeout: Duration) -> Result<String, IoError> {
+        self.send(req, timeout).await.map(|(_status, body)| body)
+    }
+
+    /// Sends the request and returns the status code along with the response body.
+    pub async fn send(
+        &self,
+        req: Request<Body>,
+        timeout: Duration,
+    ) -> Result<(StatusCode, String), IoError> {
         match time::timeout(timeout, self.inner.request(req)).await {
             Err(_elapsed) => Err(IoError::new(ErrorKind::TimedOut, "Request timed out")),
             Ok(try_resp) => {
                 let mut resp = try_resp.map_err(|err| {
                     IoError::new(ErrorKind::Other, format!("Response failed: {}", err))
                 })?;
+                let status = resp.status();
                 let body = resp.body_mut();
                 let mut text = vec![];
                 while let Some(chunk) = body.next().await {
@@ -47,8 +72,28 @@ impl HttpClient {
                     text.extend(chunk.to_vec());
                 }
                 String::from_utf8(text)
+                    .map(|text| (status, text))
                     .map_err(|_| IoError::new(ErrorKind::InvalidData, "Non UTF-8 Data returned"))
             }
         }
     }
 }
+
+/// Sends the request over HTTPS, for providers calling AWS APIs directly.
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub(crate) async fn send_https(
+    request: Request<Body>,
+    timeout: Duration,
+) -> Result<(StatusCode, String), crate::CredentialsError> {
+    Ok(HttpClient::new_https().send(request, timeout).await?)
+}
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+pub(crate) async fn send_https(
+    _request: Request<Body>,
+    _timeout: Duration,
+) -> Result<(StatusCode, String), crate::CredentialsError> {
+    Err(crate::CredentialsError::new(
+        "Calling AWS APIs for credentials requires the `native-tls` or `rustls` feature.",
+    ))
+}
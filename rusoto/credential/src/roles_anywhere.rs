@@ -0,0 +1,614 @@
+//! The Credentials Provider for IAM Roles Anywhere.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use openssl::base64;
+use openssl::error::ErrorStack;
+use openssl::hash::MessageDigest;
+use openssl::pkey::{Id, PKey, Private};
+use openssl::sha::sha256;
+use openssl::sign::Signer;
+use openssl::x509::X509;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::request::send_https;
+use crate::sso::parse_expires_at;
+use crate::{clock, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+const SERVICE: &str = "rolesanywhere";
+
+/// Characters which don't have to be escaped in query string values.
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Provides AWS credentials for a role through IAM Roles Anywhere.
+///
+/// Workloads outside of AWS which hold an X.509 certificate issued by a certificate authority
+/// registered as a trust anchor can exchange it for temporary credentials of a role, instead of
+/// using long-lived access keys. The provider signs a `CreateSession` request with the private
+/// key of the certificate, like the `aws_signing_helper` of AWS does. RSA and EC keys are
+/// supported.
+///
+/// The credentials expire after the session duration, one hour by default. Wrap the provider in
+/// an [`AutoRefreshingProvider`](struct.AutoRefreshingProvider.html) to create a new session
+/// shortly before they expire.
+///
+/// Calling the IAM Roles Anywhere API requires the `native-tls` or `rustls` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_credential::{AutoRefreshingProvider, IamRolesAnywhereProvider};
+///
+/// let provider = IamRolesAnywhereProvider::from_files(
+///     "/etc/pki/workload.crt",
+///     "/etc/pki/workload.key",
+///     "arn:aws:rolesanywhere:us-east-1:123456789012:trust-anchor/a1b2c3d4",
+///     "arn:aws:rolesanywhere:us-east-1:123456789012:profile/e5f6a7b8",
+///     "arn:aws:iam::123456789012:role/workload",
+/// )
+/// .unwrap();
+/// let provider = AutoRefreshingProvider::new(provider).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct IamRolesAnywhereProvider {
+    certificate: X509,
+    certificate_chain: Vec<X509>,
+    private_key: PKey<Private>,
+    trust_anchor_arn: String,
+    profile_arn: String,
+    role_arn: String,
+    region: String,
+    endpoint: Option<String>,
+    session_name: Option<String>,
+    session_duration: Option<Duration>,
+    timeout: Duration,
+}
+
+impl IamRolesAnywhereProvider {
+    /// Create a new `IamRolesAnywhereProvider` from a PEM encoded certificate and private key.
+    /// The region is taken from the trust anchor ARN.
+    pub fn from_pem<T, P, R>(
+        certificate: &[u8],
+        private_key: &[u8],
+        trust_anchor_arn: T,
+        profile_arn: P,
+        role_arn: R,
+    ) -> Result<IamRolesAnywhereProvider, CredentialsError>
+    where
+        T: Into<String>,
+        P: Into<String>,
+        R: Into<String>,
+    {
+        let certificate = X509::from_pem(certificate)
+            .map_err(|e| CredentialsError::new(format!("Couldn't parse the certificate: {}", e)))?;
+        let private_key = PKey::private_key_from_pem(private_key)
+            .map_err(|e| CredentialsError::new(format!("Couldn't parse the private key: {}", e)))?;
+        signing_algorithm(&private_key)?;
+        if !certificate
+            .public_key()
+            .map(|public_key| public_key.public_eq(&private_key))
+            .unwrap_or(false)
+        {
+            return Err(CredentialsError::new(
+                "The private key doesn't belong to the certificate.",
+            ));
+        }
+        let trust_anchor_arn = trust_anchor_arn.into();
+        let region = trust_anchor_arn
+            .split(':')
+            .nth(3)
+            .filter(|region| !region.is_empty())
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                CredentialsError::new(format!(
+                    "Couldn't get the region from the trust anchor ARN '{}'.",
+                    trust_anchor_arn
+                ))
+            })?;
+        Ok(IamRolesAnywhereProvider {
+            certificate,
+            certificate_chain: Vec::new(),
+            private_key,
+            trust_anchor_arn,
+            profile_arn: profile_arn.into(),
+            role_arn: role_arn.into(),
+            region,
+            endpoint: None,
+            session_name: None,
+            session_duration: None,
+            timeout: Duration::from_secs(30),
+        })
+    }
+
+    /// Create a new `IamRolesAnywhereProvider` from the PEM encoded certificate and private key
+    /// in the given files.
+    pub fn from_files<C, K, T, P, R>(
+        certificate_path: C,
+        private_key_path: K,
+        trust_anchor_arn: T,
+        profile_arn: P,
+        role_arn: R,
+    ) -> Result<IamRolesAnywhereProvider, CredentialsError>
+    where
+        C: AsRef<Path>,
+        K: AsRef<Path>,
+        T: Into<String>,
+        P: Into<String>,
+        R: Into<String>,
+    {
+        IamRolesAnywhereProvider::from_pem(
+            &read_pem(certificate_path.as_ref())?,
+            &read_pem(private_key_path.as_ref())?,
+            trust_anchor_arn,
+            profile_arn,
+            role_arn,
+        )
+    }
+
+    /// Set the PEM encoded intermediate certificates between the certificate and the trust
+    /// anchor, if the trust anchor doesn't issue certificates directly.
+    pub fn set_certificate_chain(&mut self, chain: &[u8]) -> Result<(), CredentialsError> {
+        self.certificate_chain = X509::stack_from_pem(chain).map_err(|e| {
+            CredentialsError::new(format!("Couldn't parse the certificate chain: {}", e))
+        })?;
+        Ok(())
+    }
+
+    /// Set the region of the IAM Roles Anywhere endpoint. Defaults to the region of the trust
+    /// anchor ARN.
+    pub fn set_region<R>(&mut self, region: R)
+    where
+        R: Into<String>,
+    {
+        self.region = region.into();
+    }
+
+    /// Set the endpoint of the IAM Roles Anywhere API, e.g. for the China regions. Defaults to
+    /// `https://rolesanywhere.<region>.amazonaws.com`.
+    pub fn set_endpoint<E>(&mut self, endpoint: E)
+    where
+        E: Into<String>,
+    {
+        self.endpoint = Some(endpoint.into());
+    }
+
+    /// Set the name of the role session.
+    pub fn set_session_name<S>(&mut self, session_name: S)
+    where
+        S: Into<String>,
+    {
+        self.session_name = Some(session_name.into());
+    }
+
+    /// Set how long the credentials are valid, between 15 minutes and 12 hours. Defaults to the
+    /// session duration of the IAM Roles Anywhere profile.
+    pub fn set_session_duration(&mut self, session_duration: Duration) {
+        self.session_duration = Some(session_duration);
+    }
+
+    /// Set the timeout on the provider to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Get a reference to the trust anchor ARN.
+    pub fn trust_anchor_arn(&self) -> &str {
+        &self.trust_anchor_arn
+    }
+
+    /// Get a reference to the profile ARN.
+    pub fn profile_arn(&self) -> &str {
+        &self.profile_arn
+    }
+
+    /// Get a reference to the role ARN.
+    pub fn role_arn(&self) -> &str {
+        &self.role_arn
+    }
+
+    /// Get a reference to the region of the IAM Roles Anywhere endpoint.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    fn endpoint(&self) -> String {
+        match self.endpoint {
+            Some(ref endpoint) => endpoint.trim_end_matches('/').to_owned(),
+            None => format!("https://{}.{}.amazonaws.com", SERVICE, self.region),
+        }
+    }
+
+    /// The query string of the `CreateSession` request, with the parameters in canonical order.
+    fn query(&self) -> String {
+        [
+            ("profileArn", &self.profile_arn),
+            ("roleArn", &self.role_arn),
+            ("trustAnchorArn", &self.trust_anchor_arn),
+        ]
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, utf8_percent_encode(value, QUERY_VALUE)))
+        .collect::<Vec<_>>()
+        .join("&")
+    }
+
+    fn body(&self) -> String {
+        let mut body = Map::new();
+        if let Some(session_duration) = self.session_duration {
+            body.insert(
+                "durationSeconds".to_owned(),
+                Value::from(session_duration.as_secs()),
+            );
+        }
+        if let Some(ref session_name) = self.session_name {
+            body.insert(
+                "roleSessionName".to_owned(),
+                Value::from(session_name.as_str()),
+            );
+        }
+        Value::Object(body).to_string()
+    }
+
+    /// Builds the `CreateSession` request, signed with the private key of the certificate.
+    fn create_session_request(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<hyper::Request<hyper::Body>, CredentialsError> {
+        let endpoint = self.endpoint();
+        let host = endpoint
+            .parse::<hyper::Uri>()
+            .ok()
+            .and_then(|uri| uri.authority().map(|authority| authority.to_string()))
+            .ok_or_else(|| {
+                CredentialsError::new(format!("Invalid IAM Roles Anywhere endpoint: {}", endpoint))
+            })?;
+        let query = self.query();
+        let body = self.body();
+        let date = now.format("%Y%m%dT%H%M%SZ").to_string();
+
+        // the headers in canonical order
+        let mut headers = vec![
+            ("content-type", "application/json".to_owned()),
+            ("host", host),
+            ("x-amz-date", date.clone()),
+            ("x-amz-x509", encode_certificate(&self.certificate)?),
+        ];
+        if !self.certificate_chain.is_empty() {
+            let chain = self
+                .certificate_chain
+                .iter()
+                .map(encode_certificate)
+                .collect::<Result<Vec<_>, _>>()?;
+            headers.push(("x-amz-x509-chain", chain.join(",")));
+        }
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value))
+            .collect();
+        let canonical_request = format!(
+            "POST\n/sessions\n{}\n{}\n{}\n{}",
+            query,
+            canonical_headers,
+            signed_headers,
+            hex(&sha256(body.as_bytes()))
+        );
+
+        let algorithm = signing_algorithm(&self.private_key)?;
+        let scope = format!(
+            "{}/{}/{}/aws4_request",
+            now.format("%Y%m%d"),
+            self.region,
+            SERVICE
+        );
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            algorithm,
+            date,
+            scope,
+            hex(&sha256(canonical_request.as_bytes()))
+        );
+        let signature = self.sign(string_to_sign.as_bytes()).map_err(|e| {
+            CredentialsError::new(format!("Couldn't sign the CreateSession request: {}", e))
+        })?;
+        let serial_number = self
+            .certificate
+            .serial_number()
+            .to_bn()
+            .and_then(|serial_number| serial_number.to_dec_str())
+            .map_err(|e| {
+                CredentialsError::new(format!("Invalid certificate serial number: {}", e))
+            })?;
+        let authorization = format!(
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            algorithm,
+            &*serial_number,
+            scope,
+            signed_headers,
+            hex(&signature)
+        );
+
+        let mut request = hyper::Request::post(format!("{}/sessions?{}", endpoint, query));
+        for (name, value) in headers.into_iter().filter(|(name, _)| *name != "host") {
+            request = request.header(name, value);
+        }
+        request
+            .header("authorization", authorization)
+            .body(hyper::Body::from(body))
+            .map_err(|e| CredentialsError::new(format!("Invalid request: {}", e)))
+    }
+
+    fn sign(&self, data: &[u8]) -> Result<Vec<u8>, ErrorStack> {
+        let mut signer = Signer::new(MessageDigest::sha256(), &self.private_key)?;
+        signer.update(data)?;
+        signer.sign_to_vec()
+    }
+}
+
+impl fmt::Debug for IamRolesAnywhereProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IamRolesAnywhereProvider")
+            .field("certificate", &self.certificate.subject_name())
+            .field("trust_anchor_arn", &self.trust_anchor_arn)
+            .field("profile_arn", &self.profile_arn)
+            .field("role_arn", &self.role_arn)
+            .field("region", &self.region)
+            .field("endpoint", &self.endpoint)
+            .field("session_name", &self.session_name)
+            .field("session_duration", &self.session_duration)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for IamRolesAnywhereProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let request = self.create_session_request(clock::now())?;
+        let (status, body) = send_https(request, self.timeout).await?;
+        if !status.is_success() {
+            return Err(CredentialsError::new(format!(
+                "IAM Roles Anywhere CreateSession failed with {}: {}",
+                status, body
+            )));
+        }
+        parse_create_session_response(&body)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateSessionResponse {
+    credential_set: Vec<CredentialSet>,
+}
+
+#[derive(Deserialize)]
+struct CredentialSet {
+    credentials: SessionCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SessionCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    expiration: String,
+}
+
+fn parse_create_session_response(body: &str) -> Result<AwsCredentials, CredentialsError> {
+    let response: CreateSessionResponse = serde_json::from_str(body)?;
+    let creds = response
+        .credential_set
+        .into_iter()
+        .next()
+        .map(|credential_set| credential_set.credentials)
+        .ok_or_else(|| CredentialsError::new("CreateSession returned no credentials."))?;
+    Ok(AwsCredentials::new(
+        creds.access_key_id,
+        creds.secret_access_key,
+        creds.session_token,
+        Some(parse_expires_at(&creds.expiration)?),
+    ))
+}
+
+fn signing_algorithm(private_key: &PKey<Private>) -> Result<&'static str, CredentialsError> {
+    match private_key.id() {
+        Id::RSA => Ok("AWS4-X509-RSA-SHA256"),
+        Id::EC => Ok("AWS4-X509-ECDSA-SHA256"),
+        _ => Err(CredentialsError::new(
+            "Only RSA and EC private keys are supported by IAM Roles Anywhere.",
+        )),
+    }
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>, CredentialsError> {
+    fs::read(path)
+        .map_err(|e| CredentialsError::new(format!("Couldn't read [ {:?} ]: {}", path, e)))
+}
+
+fn encode_certificate(certificate: &X509) -> Result<String, CredentialsError> {
+    let der = certificate
+        .to_der()
+        .map_err(|e| CredentialsError::new(format!("Couldn't encode the certificate: {}", e)))?;
+    Ok(base64::encode_block(&der))
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::asn1::{Asn1Integer, Asn1Time};
+    use openssl::bn::BigNum;
+    use openssl::ec::{EcGroup, EcKey};
+    use openssl::nid::Nid;
+    use openssl::rsa::Rsa;
+    use openssl::sign::Verifier;
+    use openssl::x509::X509NameBuilder;
+
+    const TRUST_ANCHOR_ARN: &str =
+        "arn:aws:rolesanywhere:eu-west-1:123456789012:trust-anchor/a1b2c3d4";
+    const PROFILE_ARN: &str = "arn:aws:rolesanywhere:eu-west-1:123456789012:profile/e5f6a7b8";
+    const ROLE_ARN: &str = "arn:aws:iam::123456789012:role/workload";
+
+    /// Returns a self-signed certificate with the serial number 1234 and its private key.
+    fn certificate(private_key: PKey<Private>) -> (Vec<u8>, Vec<u8>) {
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "workload").unwrap();
+        let name = name.build();
+        let serial_number = Asn1Integer::from_bn(&BigNum::from_u32(1234).unwrap()).unwrap();
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_serial_number(&serial_number).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&private_key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        builder.sign(&private_key, MessageDigest::sha256()).unwrap();
+        (
+            builder.build().to_pem().unwrap(),
+            private_key.private_key_to_pem_pkcs8().unwrap(),
+        )
+    }
+
+    fn provider(private_key: PKey<Private>) -> IamRolesAnywhereProvider {
+        let (certificate, private_key) = certificate(private_key);
+        IamRolesAnywhereProvider::from_pem(
+            &certificate,
+            &private_key,
+            TRUST_ANCHOR_ARN,
+            PROFILE_ARN,
+            ROLE_ARN,
+        )
+        .expect("valid certificate and key")
+    }
+
+    fn header<'a>(request: &'a hyper::Request<hyper::Body>, name: &str) -> &'a str {
+        request.headers()[name].to_str().unwrap()
+    }
+
+    /// Checks the signature of the request against the public key of the certificate.
+    fn verify(provider: &IamRolesAnywhereProvider, algorithm: &str) {
+        let now = DateTime::parse_from_rfc3339("2023-06-10T10:15:30Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let request = provider.create_session_request(now).unwrap();
+        assert_eq!(
+            request.uri(),
+            "https://rolesanywhere.eu-west-1.amazonaws.com/sessions?profileArn=arn%3Aaws%3Arolesanywhere%3Aeu-west-1%3A123456789012%3Aprofile%2Fe5f6a7b8&roleArn=arn%3Aaws%3Aiam%3A%3A123456789012%3Arole%2Fworkload&trustAnchorArn=arn%3Aaws%3Arolesanywhere%3Aeu-west-1%3A123456789012%3Atrust-anchor%2Fa1b2c3d4"
+        );
+        assert_eq!(header(&request, "x-amz-date"), "20230610T101530Z");
+        assert_eq!(
+            header(&request, "x-amz-x509"),
+            encode_certificate(&provider.certificate).unwrap()
+        );
+
+        let authorization = header(&request, "authorization");
+        let prefix = format!(
+            "{} Credential=1234/20230610/eu-west-1/rolesanywhere/aws4_request, SignedHeaders=content-type;host;x-amz-date;x-amz-x509, Signature=",
+            algorithm
+        );
+        assert!(authorization.starts_with(&prefix), "{}", authorization);
+        let signature: Vec<u8> = (prefix.len()..authorization.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&authorization[i..i + 2], 16).unwrap())
+            .collect();
+
+        let canonical_request = format!(
+            "POST\n/sessions\n{}\ncontent-type:application/json\nhost:rolesanywhere.eu-west-1.amazonaws.com\nx-amz-date:20230610T101530Z\nx-amz-x509:{}\n\ncontent-type;host;x-amz-date;x-amz-x509\n{}",
+            request.uri().query().unwrap(),
+            header(&request, "x-amz-x509"),
+            hex(&sha256(b"{}"))
+        );
+        let string_to_sign = format!(
+            "{}\n20230610T101530Z\n20230610/eu-west-1/rolesanywhere/aws4_request\n{}",
+            algorithm,
+            hex(&sha256(canonical_request.as_bytes()))
+        );
+        let public_key = provider.certificate.public_key().unwrap();
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key).unwrap();
+        verifier.update(string_to_sign.as_bytes()).unwrap();
+        assert!(verifier.verify(&signature).unwrap());
+    }
+
+    #[test]
+    fn sign_with_rsa_key() {
+        let provider = provider(PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap());
+        assert_eq!(provider.region(), "eu-west-1");
+        verify(&provider, "AWS4-X509-RSA-SHA256");
+    }
+
+    #[test]
+    fn sign_with_ec_key() {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let provider = provider(PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap());
+        verify(&provider, "AWS4-X509-ECDSA-SHA256");
+    }
+
+    #[test]
+    fn key_must_match_certificate() {
+        let (certificate, _) = certificate(PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap());
+        let other_key = PKey::from_rsa(Rsa::generate(2048).unwrap())
+            .unwrap()
+            .private_key_to_pem_pkcs8()
+            .unwrap();
+        assert!(IamRolesAnywhereProvider::from_pem(
+            &certificate,
+            &other_key,
+            TRUST_ANCHOR_ARN,
+            PROFILE_ARN,
+            ROLE_ARN
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn session_settings_are_sent_in_the_body() {
+        let mut provider = provider(PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap());
+        assert_eq!(provider.body(), "{}");
+        provider.set_session_duration(Duration::from_secs(900));
+        provider.set_session_name("workload");
+        assert_eq!(
+            provider.body(),
+            r#"{"durationSeconds":900,"roleSessionName":"workload"}"#
+        );
+    }
+
+    #[test]
+    fn parse_create_session() {
+        let creds = parse_create_session_response(
+            r#"{"credentialSet": [{"assumedRoleUser": {"arn": "arn:aws:sts::123456789012:assumed-role/workload/1234", "assumedRoleId": "AROA:1234"}, "credentials": {"accessKeyId": "key", "secretAccessKey": "secret", "sessionToken": "token", "expiration": "2023-06-10T11:15:30Z"}, "packedPolicySize": 0, "roleArn": "arn:aws:iam::123456789012:role/workload", "sourceIdentity": "CN=workload"}], "subjectId": "subject"}"#,
+        )
+        .unwrap();
+        assert_eq!(creds.aws_access_key_id(), "key");
+        assert_eq!(creds.aws_secret_access_key(), "secret");
+        assert_eq!(creds.token(), &Some("token".to_owned()));
+        assert_eq!(
+            creds.expires_at(),
+            &Some(parse_expires_at("2023-06-10T11:15:30Z").unwrap())
+        );
+        assert!(parse_create_session_response(r#"{"credentialSet": []}"#).is_err());
+    }
+}
@@ -0,0 +1,589 @@
+//! The Credentials Provider for AWS Single Sign-On (AWS IAM Identity Center).
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, SecondsFormat, TimeZone, Utc};
+use dirs::home_dir;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+use crate::profile::{parse_config_file, ProfileProvider};
+use crate::request::send_https;
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+const SSO_SESSION: &str = "sso_session";
+const SSO_SESSION_SECTION_PREFIX: &str = "sso-session ";
+const SSO_START_URL: &str = "sso_start_url";
+const SSO_REGION: &str = "sso_region";
+const SSO_ACCOUNT_ID: &str = "sso_account_id";
+const SSO_ROLE_NAME: &str = "sso_role_name";
+const SSO_BEARER_TOKEN_HEADER: &str = "x-amz-sso_bearer_token";
+
+/// Access tokens are refreshed when they expire within this many seconds.
+const TOKEN_REFRESH_WINDOW_SECONDS: i64 = 300;
+
+/// Characters which don't have to be escaped in query string values.
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Provides AWS credentials for a role through AWS Single Sign-On.
+///
+/// The provider uses the access token cached in `~/.aws/sso/cache` by `aws sso login` to call
+/// the SSO `GetRoleCredentials` API. It doesn't log in by itself: once the cached token expires,
+/// `aws sso login` has to be run again.
+///
+/// A profile using SSO looks like this:
+///
+/// ```ini
+/// [profile my-sso-profile]
+/// sso_start_url = https://my-sso-portal.awsapps.com/start
+/// sso_region = us-east-1
+/// sso_account_id = 123456789011
+/// sso_role_name = readOnly
+/// ```
+///
+/// Profiles may also refer to a shared `sso-session` section instead:
+///
+/// ```ini
+/// [profile my-sso-profile]
+/// sso_session = my-sso
+/// sso_account_id = 123456789011
+/// sso_role_name = readOnly
+///
+/// [sso-session my-sso]
+/// sso_start_url = https://my-sso-portal.awsapps.com/start
+/// sso_region = us-east-1
+/// ```
+///
+/// Tokens of an `sso-session` can be refreshed without logging in again. When such a token is
+/// about to expire, the provider refreshes it with the SSO OIDC `CreateToken` API and writes the
+/// new token back to the cache, where the AWS CLI picks it up as well.
+///
+/// `ProfileProvider` uses this provider for profiles which set `sso_start_url`.
+///
+/// Calling the SSO API requires the `native-tls` or `rustls` feature.
+#[derive(Clone, Debug)]
+pub struct SsoProvider {
+    session_name: Option<String>,
+    start_url: String,
+    region: String,
+    account_id: String,
+    role_name: String,
+    cache_dir: Option<PathBuf>,
+    timeout: Duration,
+}
+
+impl SsoProvider {
+    /// Create a new `SsoProvider` for the given start URL, SSO region, account ID and role name.
+    pub fn new<U, R, A, N>(start_url: U, region: R, account_id: A, role_name: N) -> SsoProvider
+    where
+        U: Into<String>,
+        R: Into<String>,
+        A: Into<String>,
+        N: Into<String>,
+    {
+        SsoProvider {
+            session_name: None,
+            start_url: start_url.into(),
+            region: region.into(),
+            account_id: account_id.into(),
+            role_name: role_name.into(),
+            cache_dir: None,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Create a new `SsoProvider` from the SSO settings of the default profile in the default
+    /// config file, see `ProfileProvider`.
+    pub fn from_default_profile() -> Result<SsoProvider, CredentialsError> {
+        SsoProvider::from_profile(ProfileProvider::default_profile_name())
+    }
+
+    /// Create a new `SsoProvider` from the SSO settings of the given profile in the default
+    /// config file (`~/.aws/config`, or the value of `AWS_CONFIG_FILE`).
+    pub fn from_profile<P>(profile: P) -> Result<SsoProvider, CredentialsError>
+    where
+        P: AsRef<str>,
+    {
+        let location = ProfileProvider::default_config_location()?;
+        SsoProvider::from_config_file(location, profile)
+    }
+
+    /// Create a new `SsoProvider` from the SSO settings of the given profile in the given
+    /// config file.
+    pub fn from_config_file<F, P>(file_path: F, profile: P) -> Result<SsoProvider, CredentialsError>
+    where
+        F: AsRef<Path>,
+        P: AsRef<str>,
+    {
+        let config = parse_config_file(file_path.as_ref()).ok_or_else(|| {
+            CredentialsError::new(format!(
+                "Couldn't read config file: [ {:?} ].",
+                file_path.as_ref()
+            ))
+        })?;
+        SsoProvider::from_config(&config, profile.as_ref())
+    }
+
+    /// Builds a provider from the SSO settings of a profile in a parsed config file. The start
+    /// URL and region are taken from the `sso-session` section the profile refers to, if any.
+    pub(crate) fn from_config(
+        config: &HashMap<String, HashMap<String, String>>,
+        profile: &str,
+    ) -> Result<SsoProvider, CredentialsError> {
+        let props = config
+            .get(profile)
+            .ok_or_else(|| CredentialsError::new(format!("Profile '{}' not found.", profile)))?;
+        let session_name = props.get(SSO_SESSION);
+        let session = match session_name {
+            Some(name) => Some(
+                config
+                    .get(&format!("{}{}", SSO_SESSION_SECTION_PREFIX, name))
+                    .ok_or_else(|| {
+                        CredentialsError::new(format!("sso-session '{}' not found.", name))
+                    })?,
+            ),
+            None => None,
+        };
+        let get = |key: &str| {
+            session
+                .and_then(|session| session.get(key))
+                .or_else(|| props.get(key))
+                .cloned()
+                .ok_or_else(|| {
+                    CredentialsError::new(format!(
+                        "Profile '{}' is missing the SSO setting '{}'.",
+                        profile, key
+                    ))
+                })
+        };
+        let mut provider = SsoProvider::new(
+            get(SSO_START_URL)?,
+            get(SSO_REGION)?,
+            get(SSO_ACCOUNT_ID)?,
+            get(SSO_ROLE_NAME)?,
+        );
+        provider.session_name = session_name.cloned();
+        Ok(provider)
+    }
+
+    /// Whether the properties of a profile configure SSO.
+    pub(crate) fn is_configured(props: &HashMap<String, String>) -> bool {
+        props.contains_key(SSO_START_URL) || props.contains_key(SSO_SESSION)
+    }
+
+    /// Set the name of the `sso-session` the cached token belongs to. Tokens of an
+    /// `sso-session` are refreshed automatically.
+    pub fn set_session_name<S>(&mut self, session_name: S)
+    where
+        S: Into<String>,
+    {
+        self.session_name = Some(session_name.into());
+    }
+
+    /// Get a reference to the name of the `sso-session`, if any.
+    pub fn session_name(&self) -> Option<&str> {
+        self.session_name.as_deref()
+    }
+
+    /// Set the directory the SSO access tokens are cached in. Defaults to `~/.aws/sso/cache`.
+    pub fn set_cache_dir<D>(&mut self, cache_dir: D)
+    where
+        D: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+    }
+
+    /// Set the timeout on the provider to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Get a reference to the SSO start URL.
+    pub fn start_url(&self) -> &str {
+        &self.start_url
+    }
+
+    /// Get a reference to the region of the SSO portal.
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// Get a reference to the account ID.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    /// Get a reference to the role name.
+    pub fn role_name(&self) -> &str {
+        &self.role_name
+    }
+
+    fn cache_dir(&self) -> Result<PathBuf, CredentialsError> {
+        match self.cache_dir {
+            Some(ref cache_dir) => Ok(cache_dir.clone()),
+            None => match home_dir() {
+                Some(mut home_path) => {
+                    home_path.push(".aws");
+                    home_path.push("sso");
+                    home_path.push("cache");
+                    Ok(home_path)
+                }
+                None => Err(CredentialsError::new("Failed to determine home directory.")),
+            },
+        }
+    }
+
+    /// The AWS CLI caches the token under the SHA-1 hash of the `sso-session` name, or of the
+    /// start URL for legacy profiles.
+    fn token_file(&self) -> Result<PathBuf, CredentialsError> {
+        let key = self.session_name.as_ref().unwrap_or(&self.start_url);
+        let mut path = self.cache_dir()?;
+        path.push(format!("{}.json", sha1::Sha1::from(key).digest()));
+        Ok(path)
+    }
+
+    fn read_token(&self, path: &Path) -> Result<CachedToken, CredentialsError> {
+        let contents = fs::read(path).map_err(|e| {
+            CredentialsError::new(format!(
+                "Couldn't read the SSO access token from [ {:?} ], run `aws sso login`: {}",
+                path, e
+            ))
+        })?;
+        Ok(serde_json::from_slice(&contents)?)
+    }
+
+    /// Loads the cached access token, refreshing it if it's about to expire and belongs to an
+    /// `sso-session`.
+    async fn load_token(&self) -> Result<String, CredentialsError> {
+        let path = self.token_file()?;
+        let token = self.read_token(&path)?;
+        let expires_at = parse_expires_at(&token.expires_at)?;
+        let refresh_at = Utc::now() + ChronoDuration::seconds(TOKEN_REFRESH_WINDOW_SECONDS);
+        if expires_at > refresh_at {
+            return Ok(token.access_token);
+        }
+        if self.session_name.is_some() && token.can_refresh() {
+            match self.refresh_token(token.clone()).await {
+                Ok(refreshed) => {
+                    write_token(&path, &refreshed)?;
+                    return Ok(refreshed.access_token);
+                }
+                // the current token can still be used until it expires
+                Err(_) if expires_at > Utc::now() => return Ok(token.access_token),
+                Err(e) => return Err(e),
+            }
+        }
+        if expires_at > Utc::now() {
+            Ok(token.access_token)
+        } else {
+            Err(CredentialsError::new(
+                "The SSO access token has expired, run `aws sso login`.",
+            ))
+        }
+    }
+
+    /// Exchanges the refresh token of a cached token for a new access token.
+    async fn refresh_token(&self, mut token: CachedToken) -> Result<CachedToken, CredentialsError> {
+        let body = json!({
+            "clientId": token.client_id,
+            "clientSecret": token.client_secret,
+            "grantType": "refresh_token",
+            "refreshToken": token.refresh_token,
+        });
+        let request =
+            hyper::Request::post(format!("https://oidc.{}.amazonaws.com/token", self.region))
+                .header("content-type", "application/json")
+                .body(hyper::Body::from(body.to_string()))
+                .map_err(|e| CredentialsError::new(format!("Invalid request: {}", e)))?;
+        let (status, body) = send_https(request, self.timeout).await?;
+        if !status.is_success() {
+            return Err(CredentialsError::new(format!(
+                "SSO OIDC CreateToken failed with {}: {}",
+                status, body
+            )));
+        }
+        let response: CreateTokenResponse = serde_json::from_str(&body)?;
+        token.apply(response, Utc::now());
+        Ok(token)
+    }
+
+    fn role_credentials_uri(&self) -> String {
+        format!(
+            "https://portal.sso.{}.amazonaws.com/federation/credentials?account_id={}&role_name={}",
+            self.region,
+            utf8_percent_encode(&self.account_id, QUERY_VALUE),
+            utf8_percent_encode(&self.role_name, QUERY_VALUE),
+        )
+    }
+
+    async fn get_role_credentials(&self, token: &str) -> Result<String, CredentialsError> {
+        let request = hyper::Request::get(self.role_credentials_uri())
+            .header(SSO_BEARER_TOKEN_HEADER, token)
+            .body(hyper::Body::empty())
+            .map_err(|e| CredentialsError::new(format!("Invalid request: {}", e)))?;
+        let (status, body) = send_https(request, self.timeout).await?;
+        if !status.is_success() {
+            return Err(CredentialsError::new(format!(
+                "SSO GetRoleCredentials failed with {}: {}",
+                status, body
+            )));
+        }
+        Ok(body)
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for SsoProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let token = self.load_token().await?;
+        let body = self.get_role_credentials(&token).await?;
+        parse_role_credentials(&body)
+    }
+}
+
+/// An access token as cached by the AWS CLI. Unknown fields are kept when the token is written
+/// back after a refresh.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedToken {
+    access_token: String,
+    expires_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registration_expires_at: Option<String>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl CachedToken {
+    /// Whether the token holds a refresh token and a client registration which hasn't expired.
+    fn can_refresh(&self) -> bool {
+        let registered = match self.registration_expires_at {
+            Some(ref expires_at) => parse_expires_at(expires_at)
+                .map(|expires_at| expires_at > Utc::now())
+                .unwrap_or(false),
+            None => true,
+        };
+        registered
+            && self.refresh_token.is_some()
+            && self.client_id.is_some()
+            && self.client_secret.is_some()
+    }
+
+    fn apply(&mut self, response: CreateTokenResponse, now: DateTime<Utc>) {
+        self.access_token = response.access_token;
+        self.expires_at = (now + ChronoDuration::seconds(response.expires_in))
+            .to_rfc3339_opts(SecondsFormat::Secs, true);
+        if response.refresh_token.is_some() {
+            self.refresh_token = response.refresh_token;
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateTokenResponse {
+    access_token: String,
+    expires_in: i64,
+    refresh_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GetRoleCredentialsResponse {
+    role_credentials: RoleCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RoleCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+    /// Milliseconds since the epoch.
+    expiration: i64,
+}
+
+/// Writes the token to a temporary file first, so other readers never see a partial token.
+fn write_token(path: &Path, token: &CachedToken) -> Result<(), CredentialsError> {
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, serde_json::to_vec(token)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Older versions of the AWS CLI write timestamps like `2020-06-10T10:15:30UTC`.
+pub(crate) fn parse_expires_at(expires_at: &str) -> Result<DateTime<Utc>, CredentialsError> {
+    let expires_at = match expires_at.strip_suffix("UTC") {
+        Some(timestamp) => format!("{}Z", timestamp),
+        None => expires_at.to_owned(),
+    };
+    Ok(DateTime::parse_from_rfc3339(&expires_at)?.with_timezone(&Utc))
+}
+
+fn parse_role_credentials(body: &str) -> Result<AwsCredentials, CredentialsError> {
+    let response: GetRoleCredentialsResponse = serde_json::from_str(body)?;
+    let creds = response.role_credentials;
+    Ok(AwsCredentials::new(
+        creds.access_key_id,
+        creds.secret_access_key,
+        creds.session_token,
+        Utc.timestamp_millis_opt(creds.expiration).single(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::path::Path;
+
+    #[test]
+    fn from_config_file() {
+        let provider =
+            SsoProvider::from_config_file(Path::new("tests/sample-data/sso_config"), "sso")
+                .expect("valid SSO profile");
+        assert_eq!(
+            provider.start_url(),
+            "https://my-sso-portal.awsapps.com/start"
+        );
+        assert_eq!(provider.region(), "us-east-1");
+        assert_eq!(provider.account_id(), "123456789011");
+        assert_eq!(provider.role_name(), "readOnly");
+        assert_eq!(
+            provider.role_credentials_uri(),
+            "https://portal.sso.us-east-1.amazonaws.com/federation/credentials?account_id=123456789011&role_name=readOnly"
+        );
+    }
+
+    #[test]
+    fn from_config_file_missing_setting() {
+        let result =
+            SsoProvider::from_config_file(Path::new("tests/sample-data/sso_config"), "incomplete");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn from_config_file_sso_session() {
+        let provider =
+            SsoProvider::from_config_file(Path::new("tests/sample-data/sso_config"), "session")
+                .expect("valid SSO profile");
+        assert_eq!(provider.session_name(), Some("my-sso"));
+        assert_eq!(
+            provider.start_url(),
+            "https://my-sso-portal.awsapps.com/start"
+        );
+        assert_eq!(provider.region(), "eu-west-1");
+        assert_eq!(provider.account_id(), "123456789011");
+        assert_eq!(provider.role_name(), "admin");
+        assert!(SsoProvider::from_config_file(
+            Path::new("tests/sample-data/sso_config"),
+            "missing-session"
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn load_token() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let mut provider = SsoProvider::new(
+            "https://my-sso-portal.awsapps.com/start",
+            "us-east-1",
+            "123456789011",
+            "readOnly",
+        );
+        provider.set_cache_dir(dir.path());
+        assert!(provider.load_token().await.is_err());
+
+        let token_file = provider.token_file().unwrap();
+        let mut file = fs::File::create(&token_file).unwrap();
+        write!(
+            file,
+            r#"{{"startUrl": "https://my-sso-portal.awsapps.com/start", "region": "us-east-1", "accessToken": "token", "expiresAt": "2100-01-01T00:00:00UTC"}}"#
+        )
+        .unwrap();
+        assert_eq!(provider.load_token().await.unwrap(), "token");
+
+        let mut file = fs::File::create(&token_file).unwrap();
+        write!(
+            file,
+            r#"{{"accessToken": "token", "expiresAt": "2000-01-01T00:00:00Z"}}"#
+        )
+        .unwrap();
+        assert!(provider.load_token().await.is_err());
+    }
+
+    #[test]
+    fn parse_role_credentials_response() {
+        let creds = parse_role_credentials(
+            r#"{"roleCredentials": {"accessKeyId": "key", "secretAccessKey": "secret", "sessionToken": "token", "expiration": 1591784130000}}"#,
+        )
+        .unwrap();
+        assert_eq!(creds.aws_access_key_id(), "key");
+        assert_eq!(creds.aws_secret_access_key(), "secret");
+        assert_eq!(creds.token(), &Some("token".to_owned()));
+        assert_eq!(
+            creds.expires_at(),
+            &Some(parse_expires_at("2020-06-10T10:15:30Z").unwrap())
+        );
+    }
+
+    #[test]
+    fn refreshed_token_is_written_back() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let mut provider = SsoProvider::new(
+            "https://my-sso-portal.awsapps.com/start",
+            "us-east-1",
+            "123456789011",
+            "readOnly",
+        );
+        provider.set_cache_dir(dir.path());
+        provider.set_session_name("my-sso");
+        let token_file = provider.token_file().unwrap();
+        assert_eq!(
+            token_file.file_name().and_then(|name| name.to_str()),
+            Some("0ad374308c5a4e22f723adf10145eafad7c4031c.json")
+        );
+
+        fs::write(
+            &token_file,
+            r#"{"startUrl": "https://my-sso-portal.awsapps.com/start", "region": "us-east-1", "accessToken": "old", "expiresAt": "2000-01-01T00:00:00Z", "refreshToken": "refresh", "clientId": "client", "clientSecret": "secret", "registrationExpiresAt": "2100-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        let mut token = provider.read_token(&token_file).unwrap();
+        assert!(token.can_refresh());
+
+        let now = parse_expires_at("2020-06-10T10:15:30Z").unwrap();
+        token.apply(
+            serde_json::from_str(
+                r#"{"accessToken": "new", "expiresIn": 3600, "tokenType": "Bearer"}"#,
+            )
+            .unwrap(),
+            now,
+        );
+        write_token(&token_file, &token).unwrap();
+
+        let token = provider.read_token(&token_file).unwrap();
+        assert_eq!(token.access_token, "new");
+        assert_eq!(token.expires_at, "2020-06-10T11:15:30Z");
+        assert_eq!(token.refresh_token, Some("refresh".to_owned()));
+        assert_eq!(
+            token.other.get("startUrl"),
+            Some(&Value::from("https://my-sso-portal.awsapps.com/start"))
+        );
+    }
+}
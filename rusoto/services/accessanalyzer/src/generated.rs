@@ -759,19 +759,22 @@ impl CreateAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateAnalyzerError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAnalyzerError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateAnalyzerError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateAnalyzerError::InternalServer(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateAnalyzerError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAnalyzerError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateAnalyzerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -815,24 +818,34 @@ impl CreateArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateArchiveRuleError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateArchiveRuleError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateArchiveRuleError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateArchiveRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateArchiveRuleError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateArchiveRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -873,16 +886,19 @@ impl DeleteAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteAnalyzerError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteAnalyzerError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAnalyzerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteAnalyzerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -921,16 +937,25 @@ impl DeleteArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveRuleError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveRuleError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteArchiveRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -969,18 +994,28 @@ impl GetAnalyzedResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAnalyzedResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAnalyzedResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAnalyzedResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAnalyzedResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1019,16 +1054,16 @@ impl GetAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetAnalyzerError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetAnalyzerError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAnalyzerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetAnalyzerError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetAnalyzerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1067,16 +1102,19 @@ impl GetArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetArchiveRuleError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetArchiveRuleError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetArchiveRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetArchiveRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1115,16 +1153,16 @@ impl GetFindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFindingError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetFindingError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetFindingError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetFindingError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFindingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetFindingError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFindingError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetFindingError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1163,20 +1201,28 @@ impl ListAnalyzedResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAnalyzedResourcesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAnalyzedResourcesError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAnalyzedResourcesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAnalyzedResourcesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1213,13 +1259,13 @@ impl ListAnalyzersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAnalyzersError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListAnalyzersError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListAnalyzersError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListAnalyzersError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalyzersError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListAnalyzersError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1255,13 +1301,16 @@ impl ListArchiveRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListArchiveRulesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListArchiveRulesError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListArchiveRulesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListArchiveRulesError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListArchiveRulesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListArchiveRulesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1299,16 +1348,16 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFindingsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFindingsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1347,18 +1396,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1397,16 +1456,25 @@ impl StartResourceScanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartResourceScanError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartResourceScanError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartResourceScanError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartResourceScanError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartResourceScanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartResourceScanError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartResourceScanError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartResourceScanError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1445,16 +1513,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1493,16 +1561,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1541,16 +1612,25 @@ impl UpdateArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateArchiveRuleError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateArchiveRuleError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateArchiveRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateArchiveRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1589,16 +1669,19 @@ impl UpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateFindingsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFindingsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateFindingsError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1748,6 +1831,7 @@ impl AccessAnalyzerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1763,6 +1847,7 @@ impl AccessAnalyzerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AccessAnalyzerClient {
         AccessAnalyzerClient { client, region }
     }
@@ -1779,6 +1864,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("CreateAnalyzer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1813,6 +1901,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("CreateArchiveRule");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1847,6 +1938,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("DeleteAnalyzer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1885,6 +1979,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("DeleteArchiveRule");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1918,6 +2015,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("GetAnalyzedResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1954,6 +2054,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("GetAnalyzer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1986,6 +2089,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("GetArchiveRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2014,6 +2120,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/finding/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("GetFinding");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2046,6 +2155,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("ListAnalyzedResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2077,6 +2189,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("ListAnalyzers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2120,6 +2235,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("ListArchiveRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2157,6 +2275,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("ListFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2188,6 +2309,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2216,6 +2340,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/resource/scan";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("StartResourceScan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2246,6 +2373,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2278,6 +2408,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2316,6 +2449,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("UpdateArchiveRule");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2346,6 +2482,9 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation_name("UpdateFindings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2367,3 +2506,240 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
     }
 }
+
+/// A blocking client for the Access Analyzer API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AccessAnalyzerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AccessAnalyzerBlockingClient {
+    client: AccessAnalyzerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AccessAnalyzerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AccessAnalyzerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AccessAnalyzerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AccessAnalyzerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AccessAnalyzerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient {
+            client: AccessAnalyzerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an analyzer for your account.</p>
+    ///
+    /// See `AccessAnalyzer::create_analyzer`.
+    pub fn create_analyzer(
+        &self,
+        input: CreateAnalyzerRequest,
+    ) -> Result<CreateAnalyzerResponse, RusotoError<CreateAnalyzerError>> {
+        self.runtime.block_on(self.client.create_analyzer(input))
+    }
+
+    /// <p>Creates an archive rule for the specified analyzer. Archive rules automatically archive findings that meet the criteria you define when you create the rule.</p>
+    ///
+    /// See `AccessAnalyzer::create_archive_rule`.
+    pub fn create_archive_rule(
+        &self,
+        input: CreateArchiveRuleRequest,
+    ) -> Result<(), RusotoError<CreateArchiveRuleError>> {
+        self.runtime
+            .block_on(self.client.create_archive_rule(input))
+    }
+
+    /// <p>Deletes the specified analyzer. When you delete an analyzer, Access Analyzer is disabled for the account in the current or specific Region. All findings that were generated by the analyzer are deleted. You cannot undo this action.</p>
+    ///
+    /// See `AccessAnalyzer::delete_analyzer`.
+    pub fn delete_analyzer(
+        &self,
+        input: DeleteAnalyzerRequest,
+    ) -> Result<(), RusotoError<DeleteAnalyzerError>> {
+        self.runtime.block_on(self.client.delete_analyzer(input))
+    }
+
+    /// <p>Deletes the specified archive rule.</p>
+    ///
+    /// See `AccessAnalyzer::delete_archive_rule`.
+    pub fn delete_archive_rule(
+        &self,
+        input: DeleteArchiveRuleRequest,
+    ) -> Result<(), RusotoError<DeleteArchiveRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_archive_rule(input))
+    }
+
+    /// <p>Retrieves information about a resource that was analyzed.</p>
+    ///
+    /// See `AccessAnalyzer::get_analyzed_resource`.
+    pub fn get_analyzed_resource(
+        &self,
+        input: GetAnalyzedResourceRequest,
+    ) -> Result<GetAnalyzedResourceResponse, RusotoError<GetAnalyzedResourceError>> {
+        self.runtime
+            .block_on(self.client.get_analyzed_resource(input))
+    }
+
+    /// <p>Retrieves information about the specified analyzer.</p>
+    ///
+    /// See `AccessAnalyzer::get_analyzer`.
+    pub fn get_analyzer(
+        &self,
+        input: GetAnalyzerRequest,
+    ) -> Result<GetAnalyzerResponse, RusotoError<GetAnalyzerError>> {
+        self.runtime.block_on(self.client.get_analyzer(input))
+    }
+
+    /// <p>Retrieves information about an archive rule.</p>
+    ///
+    /// See `AccessAnalyzer::get_archive_rule`.
+    pub fn get_archive_rule(
+        &self,
+        input: GetArchiveRuleRequest,
+    ) -> Result<GetArchiveRuleResponse, RusotoError<GetArchiveRuleError>> {
+        self.runtime.block_on(self.client.get_archive_rule(input))
+    }
+
+    /// <p>Retrieves information about the specified finding.</p>
+    ///
+    /// See `AccessAnalyzer::get_finding`.
+    pub fn get_finding(
+        &self,
+        input: GetFindingRequest,
+    ) -> Result<GetFindingResponse, RusotoError<GetFindingError>> {
+        self.runtime.block_on(self.client.get_finding(input))
+    }
+
+    /// <p>Retrieves a list of resources of the specified type that have been analyzed by the specified analyzer..</p>
+    ///
+    /// See `AccessAnalyzer::list_analyzed_resources`.
+    pub fn list_analyzed_resources(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> Result<ListAnalyzedResourcesResponse, RusotoError<ListAnalyzedResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_analyzed_resources(input))
+    }
+
+    /// <p>Retrieves a list of analyzers.</p>
+    ///
+    /// See `AccessAnalyzer::list_analyzers`.
+    pub fn list_analyzers(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> Result<ListAnalyzersResponse, RusotoError<ListAnalyzersError>> {
+        self.runtime.block_on(self.client.list_analyzers(input))
+    }
+
+    /// <p>Retrieves a list of archive rules created for the specified analyzer.</p>
+    ///
+    /// See `AccessAnalyzer::list_archive_rules`.
+    pub fn list_archive_rules(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> Result<ListArchiveRulesResponse, RusotoError<ListArchiveRulesError>> {
+        self.runtime.block_on(self.client.list_archive_rules(input))
+    }
+
+    /// <p>Retrieves a list of findings generated by the specified analyzer.</p>
+    ///
+    /// See `AccessAnalyzer::list_findings`.
+    pub fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.runtime.block_on(self.client.list_findings(input))
+    }
+
+    /// <p>Retrieves a list of tags applied to the specified resource.</p>
+    ///
+    /// See `AccessAnalyzer::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Immediately starts a scan of the policies applied to the specified resource.</p>
+    ///
+    /// See `AccessAnalyzer::start_resource_scan`.
+    pub fn start_resource_scan(
+        &self,
+        input: StartResourceScanRequest,
+    ) -> Result<(), RusotoError<StartResourceScanError>> {
+        self.runtime
+            .block_on(self.client.start_resource_scan(input))
+    }
+
+    /// <p>Adds a tag to the specified resource.</p>
+    ///
+    /// See `AccessAnalyzer::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a tag from the specified resource.</p>
+    ///
+    /// See `AccessAnalyzer::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the criteria and values for the specified archive rule.</p>
+    ///
+    /// See `AccessAnalyzer::update_archive_rule`.
+    pub fn update_archive_rule(
+        &self,
+        input: UpdateArchiveRuleRequest,
+    ) -> Result<(), RusotoError<UpdateArchiveRuleError>> {
+        self.runtime
+            .block_on(self.client.update_archive_rule(input))
+    }
+
+    /// <p>Updates the status for the specified findings.</p>
+    ///
+    /// See `AccessAnalyzer::update_findings`.
+    pub fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<(), RusotoError<UpdateFindingsError>> {
+        self.runtime.block_on(self.client.update_findings(input))
+    }
+}
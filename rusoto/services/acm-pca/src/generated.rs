@@ -705,24 +705,28 @@ impl CreateCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidArgs(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateAuthorityError::InvalidArgs(err.msg),
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateAuthorityError::InvalidPolicy(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateAuthorityError::InvalidTag(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateAuthorityError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -767,32 +771,38 @@ impl CreateCertificateAuthorityAuditReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCertificateAuthorityAuditReportError::InvalidArgs(err.msg),
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCertificateAuthorityAuditReportError::InvalidArn(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCertificateAuthorityAuditReportError::InvalidState(err.msg),
                     )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCertificateAuthorityAuditReportError::RequestFailed(err.msg),
                     )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCertificateAuthorityAuditReportError::RequestInProgress(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
                     )
                 }
@@ -851,24 +861,34 @@ impl CreatePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreatePermissionError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreatePermissionError::InvalidArn(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreatePermissionError::InvalidState(err.msg))
+                    return RusotoError::service(&res, CreatePermissionError::InvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePermissionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePermissionError::LimitExceeded(err.msg),
+                    )
                 }
                 "PermissionAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePermissionError::PermissionAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePermissionError::PermissionAlreadyExists(err.msg),
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(CreatePermissionError::RequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePermissionError::RequestFailed(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -911,24 +931,28 @@ impl DeleteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteCertificateAuthorityError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateAuthorityError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateAuthorityError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateAuthorityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -969,16 +993,22 @@ impl DeletePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeletePermissionError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeletePermissionError::InvalidArn(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePermissionError::InvalidState(err.msg))
+                    return RusotoError::service(&res, DeletePermissionError::InvalidState(err.msg))
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(DeletePermissionError::RequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePermissionError::RequestFailed(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1015,12 +1045,14 @@ impl DescribeCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateAuthorityError::InvalidArn(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1061,17 +1093,20 @@ impl DescribeCertificateAuthorityAuditReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCertificateAuthorityAuditReportError::InvalidArgs(err.msg),
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCertificateAuthorityAuditReportError::InvalidArn(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1119,19 +1154,25 @@ impl GetCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetCertificateError::InvalidArn(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidState(err.msg))
+                    return RusotoError::service(&res, GetCertificateError::InvalidState(err.msg))
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetCertificateError::RequestFailed(err.msg))
+                    return RusotoError::service(&res, GetCertificateError::RequestFailed(err.msg))
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(GetCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateError::RequestInProgress(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1171,17 +1212,20 @@ impl GetCertificateAuthorityCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCertificateAuthorityCertificateError::InvalidArn(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCertificateAuthorityCertificateError::InvalidState(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1231,29 +1275,34 @@ impl GetCertificateAuthorityCsrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateAuthorityCsrError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateAuthorityCsrError::InvalidState(err.msg),
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::RequestFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateAuthorityCsrError::RequestFailed(err.msg),
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCertificateAuthorityCsrError::RequestInProgress(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateAuthorityCsrError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1305,47 +1354,56 @@ impl ImportCertificateAuthorityCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::CertificateMismatch(err.msg),
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::InvalidArn(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::InvalidRequest(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::InvalidState(err.msg),
                     )
                 }
                 "MalformedCertificateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::MalformedCertificate(err.msg),
                     )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::RequestFailed(err.msg),
                     )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::RequestInProgress(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1413,22 +1471,28 @@ impl IssueCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidArgs(err.msg))
+                    return RusotoError::service(&res, IssueCertificateError::InvalidArgs(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, IssueCertificateError::InvalidArn(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidState(err.msg))
+                    return RusotoError::service(&res, IssueCertificateError::InvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(IssueCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        IssueCertificateError::LimitExceeded(err.msg),
+                    )
                 }
                 "MalformedCSRException" => {
-                    return RusotoError::Service(IssueCertificateError::MalformedCSR(err.msg))
+                    return RusotoError::service(&res, IssueCertificateError::MalformedCSR(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(IssueCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        IssueCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1465,9 +1529,10 @@ impl ListCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCertificateAuthoritiesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificateAuthoritiesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1505,19 +1570,25 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListPermissionsError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidState(err.msg))
+                    return RusotoError::service(&res, ListPermissionsError::InvalidState(err.msg))
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(ListPermissionsError::RequestFailed(err.msg))
+                    return RusotoError::service(&res, ListPermissionsError::RequestFailed(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPermissionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1555,13 +1626,13 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListTagsError::InvalidArn(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ListTagsError::InvalidState(err.msg))
+                    return RusotoError::service(&res, ListTagsError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1599,17 +1670,20 @@ impl RestoreCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RestoreCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreCertificateAuthorityError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(RestoreCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreCertificateAuthorityError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RestoreCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1659,35 +1733,55 @@ impl RevokeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokeCertificateError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, RevokeCertificateError::InvalidArn(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::InvalidState(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RevokeCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::LimitExceeded(err.msg),
+                    )
                 }
                 "RequestAlreadyProcessedException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestAlreadyProcessed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::RequestAlreadyProcessed(err.msg),
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::RequestFailed(err.msg),
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::RequestInProgress(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1733,23 +1827,34 @@ impl TagCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagCertificateAuthorityError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagCertificateAuthorityError::InvalidState(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagCertificateAuthorityError::InvalidTag(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagCertificateAuthorityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagCertificateAuthorityError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1789,24 +1894,28 @@ impl UntagCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagCertificateAuthorityError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagCertificateAuthorityError::InvalidState(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagCertificateAuthorityError::InvalidTag(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagCertificateAuthorityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1851,34 +1960,40 @@ impl UpdateCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCertificateAuthorityError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgsException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidArgs(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateAuthorityError::InvalidArgs(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateAuthorityError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateAuthorityError::InvalidPolicy(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateAuthorityError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateAuthorityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2053,6 +2168,7 @@ impl AcmPcaClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2068,6 +2184,7 @@ impl AcmPcaClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaClient {
         AcmPcaClient { client, region }
     }
@@ -2082,6 +2199,9 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.CreateCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2104,6 +2224,9 @@ impl AcmPca for AcmPcaClient {
         RusotoError<CreateCertificateAuthorityAuditReportError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCertificateAuthorityAuditReport");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.CreateCertificateAuthorityAuditReport",
@@ -2129,6 +2252,9 @@ impl AcmPca for AcmPcaClient {
         input: CreatePermissionRequest,
     ) -> Result<(), RusotoError<CreatePermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.CreatePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2146,6 +2272,9 @@ impl AcmPca for AcmPcaClient {
         input: DeleteCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.DeleteCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2163,6 +2292,9 @@ impl AcmPca for AcmPcaClient {
         input: DeletePermissionRequest,
     ) -> Result<(), RusotoError<DeletePermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.DeletePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2181,6 +2313,9 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCertificateAuthority");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.DescribeCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2203,6 +2338,9 @@ impl AcmPca for AcmPcaClient {
         RusotoError<DescribeCertificateAuthorityAuditReportError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCertificateAuthorityAuditReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.DescribeCertificateAuthorityAuditReport",
@@ -2228,6 +2366,9 @@ impl AcmPca for AcmPcaClient {
         input: GetCertificateRequest,
     ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2249,6 +2390,9 @@ impl AcmPca for AcmPcaClient {
         RusotoError<GetCertificateAuthorityCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCertificateAuthorityCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.GetCertificateAuthorityCertificate",
@@ -2275,6 +2419,9 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCertificateAuthorityCsr");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificateAuthorityCsr");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2294,6 +2441,9 @@ impl AcmPca for AcmPcaClient {
         input: ImportCertificateAuthorityCertificateRequest,
     ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportCertificateAuthorityCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.ImportCertificateAuthorityCertificate",
@@ -2317,6 +2467,9 @@ impl AcmPca for AcmPcaClient {
         input: IssueCertificateRequest,
     ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("IssueCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.IssueCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2336,6 +2489,9 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCertificateAuthorities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.ListCertificateAuthorities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2355,6 +2511,9 @@ impl AcmPca for AcmPcaClient {
         input: ListPermissionsRequest,
     ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.ListPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2373,6 +2532,9 @@ impl AcmPca for AcmPcaClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ACMPrivateCA.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2391,6 +2553,9 @@ impl AcmPca for AcmPcaClient {
         input: RestoreCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.RestoreCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2408,6 +2573,9 @@ impl AcmPca for AcmPcaClient {
         input: RevokeCertificateRequest,
     ) -> Result<(), RusotoError<RevokeCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RevokeCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.RevokeCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2425,6 +2593,9 @@ impl AcmPca for AcmPcaClient {
         input: TagCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.TagCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2442,6 +2613,9 @@ impl AcmPca for AcmPcaClient {
         input: UntagCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.UntagCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2459,6 +2633,9 @@ impl AcmPca for AcmPcaClient {
         input: UpdateCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ACMPrivateCA.UpdateCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2470,3 +2647,281 @@ impl AcmPca for AcmPcaClient {
         Ok(())
     }
 }
+
+/// A blocking client for the ACM-PCA API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AcmPcaClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AcmPcaBlockingClient {
+    client: AcmPcaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AcmPcaBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AcmPcaBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AcmPcaBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AcmPcaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AcmPcaBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient {
+            client: AcmPcaClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a root or subordinate private certificate authority (CA). You must specify the CA configuration, the certificate revocation list (CRL) configuration, the CA type, and an optional idempotency token to avoid accidental creation of multiple CAs. The CA configuration specifies the name of the algorithm and key size to be used to create the CA private key, the type of signing algorithm that the CA uses, and X.500 subject information. The CRL configuration specifies the CRL expiration period in days (the validity period of the CRL), the Amazon S3 bucket that will contain the CRL, and a CNAME alias for the S3 bucket that is included in certificates issued by the CA. If successful, this action returns the Amazon Resource Name (ARN) of the CA.</p>
+    ///
+    /// See `AcmPca::create_certificate_authority`.
+    pub fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
+    {
+        self.runtime
+            .block_on(self.client.create_certificate_authority(input))
+    }
+
+    /// <p>Creates an audit report that lists every time that your CA private key is used. The report is saved in the Amazon S3 bucket that you specify on input. The <a>IssueCertificate</a> and <a>RevokeCertificate</a> actions use the private key.</p>
+    ///
+    /// See `AcmPca::create_certificate_authority_audit_report`.
+    pub fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        CreateCertificateAuthorityAuditReportResponse,
+        RusotoError<CreateCertificateAuthorityAuditReportError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_certificate_authority_audit_report(input))
+    }
+
+    /// <p>Assigns permissions from a private CA to a designated AWS service. Services are specified by their service principals and can be given permission to create and retrieve certificates on a private CA. Services can also be given permission to list the active permissions that the private CA has granted. For ACM to automatically renew your private CA's certificates, you must assign all possible permissions from the CA to the ACM service principal.</p> <p>At this time, you can only assign permissions to ACM (<code>acm.amazonaws.com</code>). Permissions can be revoked with the <a>DeletePermission</a> action and listed with the <a>ListPermissions</a> action.</p>
+    ///
+    /// See `AcmPca::create_permission`.
+    pub fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> Result<(), RusotoError<CreatePermissionError>> {
+        self.runtime.block_on(self.client.create_permission(input))
+    }
+
+    /// <p>Deletes a private certificate authority (CA). You must provide the Amazon Resource Name (ARN) of the private CA that you want to delete. You can find the ARN by calling the <a>ListCertificateAuthorities</a> action. </p> <note> <p>Deleting a CA will invalidate other CAs and certificates below it in your CA hierarchy.</p> </note> <p>Before you can delete a CA that you have created and activated, you must disable it. To do this, call the <a>UpdateCertificateAuthority</a> action and set the <b>CertificateAuthorityStatus</b> parameter to <code>DISABLED</code>. </p> <p>Additionally, you can delete a CA if you are waiting for it to be created (that is, the status of the CA is <code>CREATING</code>). You can also delete it if the CA has been created but you haven't yet imported the signed certificate into ACM Private CA (that is, the status of the CA is <code>PENDING_CERTIFICATE</code>). </p> <p>When you successfully call <a>DeleteCertificateAuthority</a>, the CA's status changes to <code>DELETED</code>. However, the CA won't be permanently deleted until the restoration period has passed. By default, if you do not set the <code>PermanentDeletionTimeInDays</code> parameter, the CA remains restorable for 30 days. You can set the parameter from 7 to 30 days. The <a>DescribeCertificateAuthority</a> action returns the time remaining in the restoration window of a private CA in the <code>DELETED</code> state. To restore an eligible CA, call the <a>RestoreCertificateAuthority</a> action.</p>
+    ///
+    /// See `AcmPca::delete_certificate_authority`.
+    pub fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.delete_certificate_authority(input))
+    }
+
+    /// <p>Revokes permissions that a private CA assigned to a designated AWS service. Permissions can be created with the <a>CreatePermission</a> action and listed with the <a>ListPermissions</a> action. </p>
+    ///
+    /// See `AcmPca::delete_permission`.
+    pub fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> Result<(), RusotoError<DeletePermissionError>> {
+        self.runtime.block_on(self.client.delete_permission(input))
+    }
+
+    /// <p><p>Lists information about your private certificate authority (CA). You specify the private CA on input by its ARN (Amazon Resource Name). The output contains the status of your CA. This can be any of the following: </p> <ul> <li> <p> <code>CREATING</code> - ACM Private CA is creating your private certificate authority.</p> </li> <li> <p> <code>PENDING_CERTIFICATE</code> - The certificate is pending. You must use your ACM Private CA-hosted or on-premises root or subordinate CA to sign your private CA CSR and then import it into PCA. </p> </li> <li> <p> <code>ACTIVE</code> - Your private CA is active.</p> </li> <li> <p> <code>DISABLED</code> - Your private CA has been disabled.</p> </li> <li> <p> <code>EXPIRED</code> - Your private CA certificate has expired.</p> </li> <li> <p> <code>FAILED</code> - Your private CA has failed. Your CA can fail because of problems such a network outage or backend AWS failure or other errors. A failed CA can never return to the pending state. You must create a new CA. </p> </li> <li> <p> <code>DELETED</code> - Your private CA is within the restoration period, after which it is permanently deleted. The length of time remaining in the CA&#39;s restoration period is also included in this action&#39;s output.</p> </li> </ul></p>
+    ///
+    /// See `AcmPca::describe_certificate_authority`.
+    pub fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_certificate_authority(input))
+    }
+
+    /// <p>Lists information about a specific audit report created by calling the <a>CreateCertificateAuthorityAuditReport</a> action. Audit information is created every time the certificate authority (CA) private key is used. The private key is used when you call the <a>IssueCertificate</a> action or the <a>RevokeCertificate</a> action. </p>
+    ///
+    /// See `AcmPca::describe_certificate_authority_audit_report`.
+    pub fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        DescribeCertificateAuthorityAuditReportResponse,
+        RusotoError<DescribeCertificateAuthorityAuditReportError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_certificate_authority_audit_report(input),
+        )
+    }
+
+    /// <p>Retrieves a certificate from your private CA. The ARN of the certificate is returned when you call the <a>IssueCertificate</a> action. You must specify both the ARN of your private CA and the ARN of the issued certificate when calling the <b>GetCertificate</b> action. You can retrieve the certificate if it is in the <b>ISSUED</b> state. You can call the <a>CreateCertificateAuthorityAuditReport</a> action to create a report that contains information about all of the certificates issued and revoked by your private CA. </p>
+    ///
+    /// See `AcmPca::get_certificate`.
+    pub fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+        self.runtime.block_on(self.client.get_certificate(input))
+    }
+
+    /// <p>Retrieves the certificate and certificate chain for your private certificate authority (CA). Both the certificate and the chain are base64 PEM-encoded. The chain does not include the CA certificate. Each certificate in the chain signs the one before it. </p>
+    ///
+    /// See `AcmPca::get_certificate_authority_certificate`.
+    pub fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> Result<
+        GetCertificateAuthorityCertificateResponse,
+        RusotoError<GetCertificateAuthorityCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_certificate_authority_certificate(input))
+    }
+
+    /// <p>Retrieves the certificate signing request (CSR) for your private certificate authority (CA). The CSR is created when you call the <a>CreateCertificateAuthority</a> action. Sign the CSR with your ACM Private CA-hosted or on-premises root or subordinate CA. Then import the signed certificate back into ACM Private CA by calling the <a>ImportCertificateAuthorityCertificate</a> action. The CSR is returned as a base64 PEM-encoded string. </p>
+    ///
+    /// See `AcmPca::get_certificate_authority_csr`.
+    pub fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
+    {
+        self.runtime
+            .block_on(self.client.get_certificate_authority_csr(input))
+    }
+
+    /// <p><p>Imports a signed private CA certificate into ACM Private CA. This action is used when you are using a chain of trust whose root is located outside ACM Private CA. Before you can call this action, the following preparations must in place:</p> <ol> <li> <p>In ACM Private CA, call the <a>CreateCertificateAuthority</a> action to create the private CA that that you plan to back with the imported certificate.</p> </li> <li> <p>Call the <a>GetCertificateAuthorityCsr</a> action to generate a certificate signing request (CSR).</p> </li> <li> <p>Sign the CSR using a root or intermediate CA hosted either by an on-premises PKI hierarchy or a commercial CA..</p> </li> <li> <p>Create a certificate chain and copy the signed certificate and the certificate chain to your working directory.</p> </li> </ol> <p>The following requirements apply when you import a CA certificate.</p> <ul> <li> <p>You cannot import a non-self-signed certificate for use as a root CA.</p> </li> <li> <p>You cannot import a self-signed certificate for use as a subordinate CA.</p> </li> <li> <p>Your certificate chain must not include the private CA certificate that you are importing.</p> </li> <li> <p>Your ACM Private CA-hosted or on-premises CA certificate must be the last certificate in your chain. The subordinate certificate, if any, that your root CA signed must be next to last. The subordinate certificate signed by the preceding subordinate CA must come next, and so on until your chain is built. </p> </li> <li> <p>The chain must be PEM-encoded.</p> </li> </ul></p>
+    ///
+    /// See `AcmPca::import_certificate_authority_certificate`.
+    pub fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
+        self.runtime
+            .block_on(self.client.import_certificate_authority_certificate(input))
+    }
+
+    /// <p><p>Uses your private certificate authority (CA) to issue a client certificate. This action returns the Amazon Resource Name (ARN) of the certificate. You can retrieve the certificate by calling the <a>GetCertificate</a> action and specifying the ARN. </p> <note> <p>You cannot use the ACM <b>ListCertificateAuthorities</b> action to retrieve the ARNs of the certificates that you issue by using ACM Private CA.</p> </note></p>
+    ///
+    /// See `AcmPca::issue_certificate`.
+    pub fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
+        self.runtime.block_on(self.client.issue_certificate(input))
+    }
+
+    /// <p>Lists the private certificate authorities that you created by using the <a>CreateCertificateAuthority</a> action.</p>
+    ///
+    /// See `AcmPca::list_certificate_authorities`.
+    pub fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_certificate_authorities(input))
+    }
+
+    /// <p>Lists all the permissions, if any, that have been assigned by a private CA. Permissions can be granted with the <a>CreatePermission</a> action and revoked with the <a>DeletePermission</a> action.</p>
+    ///
+    /// See `AcmPca::list_permissions`.
+    pub fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.runtime.block_on(self.client.list_permissions(input))
+    }
+
+    /// <p>Lists the tags, if any, that are associated with your private CA. Tags are labels that you can use to identify and organize your CAs. Each tag consists of a key and an optional value. Call the <a>TagCertificateAuthority</a> action to add one or more tags to your CA. Call the <a>UntagCertificateAuthority</a> action to remove tags. </p>
+    ///
+    /// See `AcmPca::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Restores a certificate authority (CA) that is in the <code>DELETED</code> state. You can restore a CA during the period that you defined in the <b>PermanentDeletionTimeInDays</b> parameter of the <a>DeleteCertificateAuthority</a> action. Currently, you can specify 7 to 30 days. If you did not specify a <b>PermanentDeletionTimeInDays</b> value, by default you can restore the CA at any time in a 30 day period. You can check the time remaining in the restoration period of a private CA in the <code>DELETED</code> state by calling the <a>DescribeCertificateAuthority</a> or <a>ListCertificateAuthorities</a> actions. The status of a restored CA is set to its pre-deletion status when the <b>RestoreCertificateAuthority</b> action returns. To change its status to <code>ACTIVE</code>, call the <a>UpdateCertificateAuthority</a> action. If the private CA was in the <code>PENDING_CERTIFICATE</code> state at deletion, you must use the <a>ImportCertificateAuthorityCertificate</a> action to import a certificate authority into the private CA before it can be activated. You cannot restore a CA after the restoration period has ended.</p>
+    ///
+    /// See `AcmPca::restore_certificate_authority`.
+    pub fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.restore_certificate_authority(input))
+    }
+
+    /// <p><p>Revokes a certificate that was issued inside ACM Private CA. If you enable a certificate revocation list (CRL) when you create or update your private CA, information about the revoked certificates will be included in the CRL. ACM Private CA writes the CRL to an S3 bucket that you specify. For more information about revocation, see the <a>CrlConfiguration</a> structure. ACM Private CA also writes revocation information to the audit report. For more information, see <a>CreateCertificateAuthorityAuditReport</a>. </p> <note> <p>You cannot revoke a root CA self-signed certificate.</p> </note></p>
+    ///
+    /// See `AcmPca::revoke_certificate`.
+    pub fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> Result<(), RusotoError<RevokeCertificateError>> {
+        self.runtime.block_on(self.client.revoke_certificate(input))
+    }
+
+    /// <p>Adds one or more tags to your private CA. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a key and an optional value. You specify the private CA on input by its Amazon Resource Name (ARN). You specify the tag by using a key-value pair. You can apply a tag to just one private CA if you want to identify a specific characteristic of that CA, or you can apply the same tag to multiple private CAs if you want to filter for a common relationship among those CAs. To remove one or more tags, use the <a>UntagCertificateAuthority</a> action. Call the <a>ListTags</a> action to see what tags are associated with your CA. </p>
+    ///
+    /// See `AcmPca::tag_certificate_authority`.
+    pub fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.tag_certificate_authority(input))
+    }
+
+    /// <p>Remove one or more tags from your private CA. A tag consists of a key-value pair. If you do not specify the value portion of the tag when calling this action, the tag will be removed regardless of value. If you specify a value, the tag is removed only if it is associated with the specified value. To add tags to a private CA, use the <a>TagCertificateAuthority</a>. Call the <a>ListTags</a> action to see what tags are associated with your CA. </p>
+    ///
+    /// See `AcmPca::untag_certificate_authority`.
+    pub fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.untag_certificate_authority(input))
+    }
+
+    /// <p>Updates the status or configuration of a private certificate authority (CA). Your private CA must be in the <code>ACTIVE</code> or <code>DISABLED</code> state before you can update it. You can disable a private CA that is in the <code>ACTIVE</code> state or make a CA that is in the <code>DISABLED</code> state active again.</p>
+    ///
+    /// See `AcmPca::update_certificate_authority`.
+    pub fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.update_certificate_authority(input))
+    }
+}
@@ -611,26 +611,40 @@ impl AddTagsToCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToCertificateError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToCertificateError::InvalidTag(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToCertificateError::TagPolicy(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToCertificateError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -669,13 +683,19 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteCertificateError::InvalidArn(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -709,12 +729,16 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::InvalidArn(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -749,13 +773,19 @@ impl ExportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ExportCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ExportCertificateError::InvalidArn(err.msg))
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(ExportCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportCertificateError::RequestInProgress(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -791,13 +821,19 @@ impl GetCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetCertificateError::InvalidArn(err.msg))
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(GetCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateError::RequestInProgress(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -839,22 +875,31 @@ impl ImportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service(&res, ImportCertificateError::InvalidTag(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportCertificateError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(ImportCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(&res, ImportCertificateError::TagPolicy(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(ImportCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, ImportCertificateError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -889,7 +934,7 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidArgs(err.msg))
+                    return RusotoError::service(&res, ListCertificatesError::InvalidArgs(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -921,12 +966,16 @@ impl ListTagsForCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForCertificateError::InvalidArn(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -965,27 +1014,34 @@ impl RemoveTagsFromCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromCertificateError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromCertificateError::InvalidTag(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromCertificateError::TagPolicy(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1021,10 +1077,13 @@ impl RenewCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RenewCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, RenewCertificateError::InvalidArn(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RenewCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RenewCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1067,27 +1126,37 @@ impl RequestCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, RequestCertificateError::InvalidArn(err.msg))
                 }
                 "InvalidDomainValidationOptionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RequestCertificateError::InvalidDomainValidationOptions(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RequestCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service(&res, RequestCertificateError::InvalidTag(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RequestCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RequestCertificateError::LimitExceeded(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(RequestCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service(&res, RequestCertificateError::TagPolicy(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(RequestCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RequestCertificateError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1131,20 +1200,28 @@ impl ResendValidationEmailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ResendValidationEmailError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResendValidationEmailError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidDomainValidationOptionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendValidationEmailError::InvalidDomainValidationOptions(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ResendValidationEmailError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResendValidationEmailError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResendValidationEmailError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendValidationEmailError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1185,22 +1262,28 @@ impl UpdateCertificateOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateOptionsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateOptionsError::InvalidState(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateOptionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateOptionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1320,6 +1403,7 @@ impl AcmClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1335,6 +1419,7 @@ impl AcmClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AcmClient {
         AcmClient { client, region }
     }
@@ -1348,6 +1433,9 @@ impl Acm for AcmClient {
         input: AddTagsToCertificateRequest,
     ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.AddTagsToCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1365,6 +1453,9 @@ impl Acm for AcmClient {
         input: DeleteCertificateRequest,
     ) -> Result<(), RusotoError<DeleteCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1382,6 +1473,9 @@ impl Acm for AcmClient {
         input: DescribeCertificateRequest,
     ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CertificateManager.DescribeCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1400,6 +1494,9 @@ impl Acm for AcmClient {
         input: ExportCertificateRequest,
     ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExportCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.ExportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1418,6 +1515,9 @@ impl Acm for AcmClient {
         input: GetCertificateRequest,
     ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CertificateManager.GetCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1436,6 +1536,9 @@ impl Acm for AcmClient {
         input: ImportCertificateRequest,
     ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.ImportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1454,6 +1557,9 @@ impl Acm for AcmClient {
         input: ListCertificatesRequest,
     ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CertificateManager.ListCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1472,6 +1578,9 @@ impl Acm for AcmClient {
         input: ListTagsForCertificateRequest,
     ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CertificateManager.ListTagsForCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1491,6 +1600,9 @@ impl Acm for AcmClient {
         input: RemoveTagsFromCertificateRequest,
     ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CertificateManager.RemoveTagsFromCertificate",
@@ -1511,6 +1623,9 @@ impl Acm for AcmClient {
         input: RenewCertificateRequest,
     ) -> Result<(), RusotoError<RenewCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RenewCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.RenewCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1528,6 +1643,9 @@ impl Acm for AcmClient {
         input: RequestCertificateRequest,
     ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RequestCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.RequestCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1546,6 +1664,9 @@ impl Acm for AcmClient {
         input: ResendValidationEmailRequest,
     ) -> Result<(), RusotoError<ResendValidationEmailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResendValidationEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CertificateManager.ResendValidationEmail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1563,6 +1684,9 @@ impl Acm for AcmClient {
         input: UpdateCertificateOptionsRequest,
     ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCertificateOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CertificateManager.UpdateCertificateOptions",
@@ -1577,3 +1701,190 @@ impl Acm for AcmClient {
         Ok(())
     }
 }
+
+/// A blocking client for the ACM API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AcmClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AcmBlockingClient {
+    client: AcmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AcmBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AcmBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AcmBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AcmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AcmBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AcmBlockingClient {
+        AcmBlockingClient {
+            client: AcmClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds one or more tags to an ACM certificate. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a <code>key</code> and an optional <code>value</code>. You specify the certificate on input by its Amazon Resource Name (ARN). You specify the tag by using a key-value pair. </p> <p>You can apply a tag to just one certificate if you want to identify a specific characteristic of that certificate, or you can apply the same tag to multiple certificates if you want to filter for a common relationship among those certificates. Similarly, you can apply the same tag to multiple resources if you want to specify a relationship among those resources. For example, you can add the same tag to an ACM certificate and an Elastic Load Balancing load balancer to indicate that they are both used by the same website. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/tags.html">Tagging ACM certificates</a>. </p> <p>To remove one or more tags, use the <a>RemoveTagsFromCertificate</a> action. To view all of the tags that have been applied to the certificate, use the <a>ListTagsForCertificate</a> action. </p>
+    ///
+    /// See `Acm::add_tags_to_certificate`.
+    pub fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_certificate(input))
+    }
+
+    /// <p><p>Deletes a certificate and its associated private key. If this action succeeds, the certificate no longer appears in the list that can be displayed by calling the <a>ListCertificates</a> action or be retrieved by calling the <a>GetCertificate</a> action. The certificate will not be available for use by AWS services integrated with ACM. </p> <note> <p>You cannot delete an ACM certificate that is being used by another AWS service. To delete a certificate that is in use, the certificate association must first be removed.</p> </note></p>
+    ///
+    /// See `Acm::delete_certificate`.
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// <p>Returns detailed metadata about the specified ACM certificate.</p>
+    ///
+    /// See `Acm::describe_certificate`.
+    pub fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_certificate(input))
+    }
+
+    /// <p>Exports a private certificate issued by a private certificate authority (CA) for use anywhere. The exported file contains the certificate, the certificate chain, and the encrypted private 2048-bit RSA key associated with the public key that is embedded in the certificate. For security, you must assign a passphrase for the private key when exporting it. </p> <p>For information about exporting and formatting a certificate using the ACM console or CLI, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-export-private.html">Export a Private Certificate</a>.</p>
+    ///
+    /// See `Acm::export_certificate`.
+    pub fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
+        self.runtime.block_on(self.client.export_certificate(input))
+    }
+
+    /// <p>Retrieves an Amazon-issued certificate and its certificate chain. The chain consists of the certificate of the issuing CA and the intermediate certificates of any other subordinate CAs. All of the certificates are base64 encoded. You can use <a href="https://wiki.openssl.org/index.php/Command_Line_Utilities">OpenSSL</a> to decode the certificates and inspect individual fields.</p>
+    ///
+    /// See `Acm::get_certificate`.
+    pub fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+        self.runtime.block_on(self.client.get_certificate(input))
+    }
+
+    /// <p>Imports a certificate into AWS Certificate Manager (ACM) to use with services that are integrated with ACM. Note that <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-services.html">integrated services</a> allow only certificate types and keys they support to be associated with their resources. Further, their support differs depending on whether the certificate is imported into IAM or into ACM. For more information, see the documentation for each service. For more information about importing certificates into ACM, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/import-certificate.html">Importing Certificates</a> in the <i>AWS Certificate Manager User Guide</i>. </p> <note> <p>ACM does not provide <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for certificates that you import.</p> </note> <p>Note the following guidelines when importing third party certificates:</p> <ul> <li> <p>You must enter the private key that matches the certificate you are importing.</p> </li> <li> <p>The private key must be unencrypted. You cannot import a private key that is protected by a password or a passphrase.</p> </li> <li> <p>If the certificate you are importing is not self-signed, you must enter its certificate chain.</p> </li> <li> <p>If a certificate chain is included, the issuer must be the subject of one of the certificates in the chain.</p> </li> <li> <p>The certificate, private key, and certificate chain must be PEM-encoded.</p> </li> <li> <p>The current time must be between the <code>Not Before</code> and <code>Not After</code> certificate fields.</p> </li> <li> <p>The <code>Issuer</code> field must not be empty.</p> </li> <li> <p>The OCSP authority URL, if present, must not exceed 1000 characters.</p> </li> <li> <p>To import a new certificate, omit the <code>CertificateArn</code> argument. Include this argument only when you want to replace a previously imported certifica</p> </li> <li> <p>When you import a certificate by using the CLI, you must specify the certificate, the certificate chain, and the private key by their file names preceded by <code>file://</code>. For example, you can specify a certificate saved in the <code>C:\temp</code> folder as <code>file://C:\temp\certificate_to_import.pem</code>. If you are making an HTTP or HTTPS Query request, include these arguments as BLOBs. </p> </li> <li> <p>When you import a certificate by using an SDK, you must specify the certificate, the certificate chain, and the private key files in the manner required by the programming language you're using. </p> </li> <li> <p>The cryptographic algorithm of an imported certificate must match the algorithm of the signing CA. For example, if the signing CA key type is RSA, then the certificate key type must also be RSA.</p> </li> </ul> <p>This operation returns the <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of the imported certificate.</p>
+    ///
+    /// See `Acm::import_certificate`.
+    pub fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+        self.runtime.block_on(self.client.import_certificate(input))
+    }
+
+    /// <p>Retrieves a list of certificate ARNs and domain names. You can request that only certificates that match a specific status be listed. You can also filter by specific attributes of the certificate. Default filtering returns only <code>RSA_2048</code> certificates. For more information, see <a>Filters</a>.</p>
+    ///
+    /// See `Acm::list_certificates`.
+    pub fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
+        self.runtime.block_on(self.client.list_certificates(input))
+    }
+
+    /// <p>Lists the tags that have been applied to the ACM certificate. Use the certificate's Amazon Resource Name (ARN) to specify the certificate. To add a tag to an ACM certificate, use the <a>AddTagsToCertificate</a> action. To delete a tag, use the <a>RemoveTagsFromCertificate</a> action. </p>
+    ///
+    /// See `Acm::list_tags_for_certificate`.
+    pub fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_certificate(input))
+    }
+
+    /// <p>Remove one or more tags from an ACM certificate. A tag consists of a key-value pair. If you do not specify the value portion of the tag when calling this function, the tag will be removed regardless of value. If you specify a value, the tag is removed only if it is associated with the specified value. </p> <p>To add tags to a certificate, use the <a>AddTagsToCertificate</a> action. To view all of the tags that have been applied to a specific ACM certificate, use the <a>ListTagsForCertificate</a> action. </p>
+    ///
+    /// See `Acm::remove_tags_from_certificate`.
+    pub fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_certificate(input))
+    }
+
+    /// <p>Renews an eligable ACM certificate. At this time, only exported private certificates can be renewed with this operation. In order to renew your ACM PCA certificates with ACM, you must first <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/PcaPermissions.html">grant the ACM service principal permission to do so</a>. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/manual-renewal.html">Testing Managed Renewal</a> in the ACM User Guide.</p>
+    ///
+    /// See `Acm::renew_certificate`.
+    pub fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> Result<(), RusotoError<RenewCertificateError>> {
+        self.runtime.block_on(self.client.renew_certificate(input))
+    }
+
+    /// <p>Requests an ACM certificate for use with other AWS services. To request an ACM certificate, you must specify a fully qualified domain name (FQDN) in the <code>DomainName</code> parameter. You can also specify additional FQDNs in the <code>SubjectAlternativeNames</code> parameter. </p> <p>If you are requesting a private certificate, domain validation is not required. If you are requesting a public certificate, each domain name that you specify must be validated to verify that you own or control the domain. You can use <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-dns.html">DNS validation</a> or <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-email.html">email validation</a>. We recommend that you use DNS validation. ACM issues public certificates after receiving approval from the domain owner. </p>
+    ///
+    /// See `Acm::request_certificate`.
+    pub fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
+        self.runtime
+            .block_on(self.client.request_certificate(input))
+    }
+
+    /// <p>Resends the email that requests domain ownership validation. The domain owner or an authorized representative must approve the ACM certificate before it can be issued. The certificate can be approved by clicking a link in the mail to navigate to the Amazon certificate approval website and then clicking <b>I Approve</b>. However, the validation email can be blocked by spam filters. Therefore, if you do not receive the original mail, you can request that the mail be resent within 72 hours of requesting the ACM certificate. If more than 72 hours have elapsed since your original request or since your last attempt to resend validation mail, you must request a new certificate. For more information about setting up your contact email addresses, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/setup-email.html">Configure Email for your Domain</a>. </p>
+    ///
+    /// See `Acm::resend_validation_email`.
+    pub fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> Result<(), RusotoError<ResendValidationEmailError>> {
+        self.runtime
+            .block_on(self.client.resend_validation_email(input))
+    }
+
+    /// <p>Updates a certificate. Currently, you can use this function to specify whether to opt in to or out of recording your certificate in a certificate transparency log. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-bestpractices.html#best-practices-transparency"> Opting Out of Certificate Transparency Logging</a>. </p>
+    ///
+    /// See `Acm::update_certificate_options`.
+    pub fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
+        self.runtime
+            .block_on(self.client.update_certificate_options(input))
+    }
+}
@@ -3555,13 +3555,16 @@ impl ApproveSkillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ApproveSkillError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ApproveSkillError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ApproveSkillError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ApproveSkillError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ApproveSkillError::NotFound(err.msg))
+                    return RusotoError::service(&res, ApproveSkillError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3595,7 +3598,8 @@ impl AssociateContactWithAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateContactWithAddressBookError::LimitExceeded(err.msg),
                     )
                 }
@@ -3635,19 +3639,22 @@ impl AssociateDeviceWithNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDeviceWithNetworkProfileError::ConcurrentModification(err.msg),
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDeviceWithNetworkProfileError::DeviceNotRegistered(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateDeviceWithNetworkProfileError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDeviceWithNetworkProfileError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3687,19 +3694,22 @@ impl AssociateDeviceWithRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDeviceWithRoomError::ConcurrentModification(err.msg),
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(AssociateDeviceWithRoomError::DeviceNotRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDeviceWithRoomError::DeviceNotRegistered(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateDeviceWithRoomError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDeviceWithRoomError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3735,7 +3745,8 @@ impl AssociateSkillGroupWithRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSkillGroupWithRoomError::ConcurrentModification(err.msg),
                     )
                 }
@@ -3775,19 +3786,22 @@ impl AssociateSkillWithSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSkillWithSkillGroupError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateSkillWithSkillGroupError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSkillWithSkillGroupError::NotFound(err.msg),
+                    )
                 }
                 "SkillNotLinkedException" => {
-                    return RusotoError::Service(AssociateSkillWithSkillGroupError::SkillNotLinked(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSkillWithSkillGroupError::SkillNotLinked(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3823,12 +3837,16 @@ impl AssociateSkillWithUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSkillWithUsersError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateSkillWithUsersError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSkillWithUsersError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3863,10 +3881,16 @@ impl CreateAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateAddressBookError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAddressBookError::AlreadyExists(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAddressBookError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAddressBookError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3899,9 +3923,10 @@ impl CreateBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBusinessReportScheduleError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBusinessReportScheduleError::AlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3931,9 +3956,10 @@ impl CreateConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConferenceProviderError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConferenceProviderError::AlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3965,10 +3991,10 @@ impl CreateContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateContactError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateContactError::AlreadyExists(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContactError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateContactError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4001,10 +4027,16 @@ impl CreateGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateGatewayGroupError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGatewayGroupError::AlreadyExists(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGatewayGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGatewayGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4043,25 +4075,34 @@ impl CreateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkProfileError::AlreadyExists(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkProfileError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNetworkProfileError::InvalidCertificateAuthority(err.msg),
                     )
                 }
                 "InvalidServiceLinkedRoleStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNetworkProfileError::InvalidServiceLinkedRoleState(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4103,15 +4144,16 @@ impl CreateProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateProfileError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateProfileError::AlreadyExists(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProfileError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateProfileError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4145,10 +4187,10 @@ impl CreateRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateRoomError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::AlreadyExists(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRoomError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4183,15 +4225,22 @@ impl CreateSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSkillGroupError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSkillGroupError::AlreadyExists(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateSkillGroupError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSkillGroupError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSkillGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSkillGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4227,13 +4276,16 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateUserError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateUserError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateUserError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4267,12 +4319,13 @@ impl DeleteAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteAddressBookError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAddressBookError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAddressBookError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAddressBookError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4307,14 +4360,16 @@ impl DeleteBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBusinessReportScheduleError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBusinessReportScheduleError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBusinessReportScheduleError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4347,7 +4402,10 @@ impl DeleteConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConferenceProviderError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4379,12 +4437,13 @@ impl DeleteContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteContactError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContactError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContactError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteContactError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4419,15 +4478,19 @@ impl DeleteDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDeviceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeviceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(DeleteDeviceError::InvalidCertificateAuthority(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeviceError::InvalidCertificateAuthority(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDeviceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4463,15 +4526,22 @@ impl DeleteDeviceUsageDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::DeviceNotRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeviceUsageDataError::DeviceNotRegistered(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeviceUsageDataError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeviceUsageDataError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4503,9 +4573,10 @@ impl DeleteGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteGatewayGroupError::ResourceAssociated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayGroupError::ResourceAssociated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4539,15 +4610,19 @@ impl DeleteNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNetworkProfileError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteNetworkProfileError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNetworkProfileError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4581,12 +4656,13 @@ impl DeleteProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProfileError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteProfileError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4619,10 +4695,13 @@ impl DeleteRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRoomError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4653,7 +4732,8 @@ impl DeleteRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRoomSkillParameterError::ConcurrentModification(err.msg),
                     )
                 }
@@ -4689,12 +4769,16 @@ impl DeleteSkillAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSkillAuthorizationError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSkillAuthorizationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSkillAuthorizationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4729,12 +4813,13 @@ impl DeleteSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteSkillGroupError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSkillGroupError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSkillGroupError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4767,10 +4852,13 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteUserError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4829,12 +4917,14 @@ impl DisassociateDeviceFromRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDeviceFromRoomError::ConcurrentModification(err.msg),
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDeviceFromRoomError::DeviceNotRegistered(err.msg),
                     )
                 }
@@ -4875,14 +4965,16 @@ impl DisassociateSkillFromSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSkillFromSkillGroupError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateSkillFromSkillGroupError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateSkillFromSkillGroupError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4919,12 +5011,16 @@ impl DisassociateSkillFromUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSkillFromUsersError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateSkillFromUsersError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateSkillFromUsersError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4959,7 +5055,8 @@ impl DisassociateSkillGroupFromRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSkillGroupFromRoomError::ConcurrentModification(err.msg),
                     )
                 }
@@ -4993,7 +5090,10 @@ impl ForgetSmartHomeAppliancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ForgetSmartHomeAppliancesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgetSmartHomeAppliancesError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5023,7 +5123,7 @@ impl GetAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAddressBookError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAddressBookError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5053,7 +5153,10 @@ impl GetConferencePreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConferencePreferenceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConferencePreferenceError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5083,7 +5186,10 @@ impl GetConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConferenceProviderError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5113,7 +5219,7 @@ impl GetContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContactError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetContactError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5143,7 +5249,7 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5173,7 +5279,7 @@ impl GetGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGatewayError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5203,7 +5309,7 @@ impl GetGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGatewayGroupError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5235,7 +5341,10 @@ impl GetInvitationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInvitationConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInvitationConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5267,12 +5376,13 @@ impl GetNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSecretsManagerResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetNetworkProfileError::InvalidSecretsManagerResource(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetNetworkProfileError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5305,7 +5415,7 @@ impl GetProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetProfileError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5335,7 +5445,7 @@ impl GetRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRoomError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5365,7 +5475,10 @@ impl GetRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomSkillParameterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRoomSkillParameterError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5395,7 +5508,7 @@ impl GetSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSkillGroupError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5471,7 +5584,7 @@ impl ListDeviceEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeviceEventsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListDeviceEventsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5613,7 +5726,10 @@ impl ListSmartHomeAppliancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSmartHomeAppliancesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSmartHomeAppliancesError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5643,7 +5759,7 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5673,7 +5789,10 @@ impl PutConferencePreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConferencePreferenceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutConferencePreferenceError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5707,12 +5826,16 @@ impl PutInvitationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInvitationConfigurationError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutInvitationConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInvitationConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5745,7 +5868,8 @@ impl PutRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRoomSkillParameterError::ConcurrentModification(err.msg),
                     )
                 }
@@ -5779,12 +5903,16 @@ impl PutSkillAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutSkillAuthorizationError::ConcurrentModification(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutSkillAuthorizationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSkillAuthorizationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5821,18 +5949,25 @@ impl RegisterAVSDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterAVSDeviceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidDeviceException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::InvalidDevice(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterAVSDeviceError::InvalidDevice(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterAVSDeviceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, RegisterAVSDeviceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5867,10 +6002,13 @@ impl RejectSkillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RejectSkillError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RejectSkillError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RejectSkillError::NotFound(err.msg))
+                    return RusotoError::service(&res, RejectSkillError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5901,7 +6039,7 @@ impl ResolveRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ResolveRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, ResolveRoomError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5933,12 +6071,13 @@ impl RevokeInvitationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokeInvitationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeInvitationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeInvitationError::NotFound(err.msg))
+                    return RusotoError::service(&res, RevokeInvitationError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6147,10 +6286,16 @@ impl SendAnnouncementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(SendAnnouncementError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendAnnouncementError::AlreadyExists(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendAnnouncementError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendAnnouncementError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6185,15 +6330,19 @@ impl SendInvitationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(SendInvitationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendInvitationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidUserStatusException" => {
-                    return RusotoError::Service(SendInvitationError::InvalidUserStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendInvitationError::InvalidUserStatus(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendInvitationError::NotFound(err.msg))
+                    return RusotoError::service(&res, SendInvitationError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6225,7 +6374,10 @@ impl StartDeviceSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(StartDeviceSyncError::DeviceNotRegistered(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDeviceSyncError::DeviceNotRegistered(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6257,9 +6409,10 @@ impl StartSmartHomeApplianceDiscoveryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(StartSmartHomeApplianceDiscoveryError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSmartHomeApplianceDiscoveryError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6289,7 +6442,7 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6319,7 +6472,7 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6353,15 +6506,16 @@ impl UpdateAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateAddressBookError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAddressBookError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateAddressBookError::NameInUse(err.msg))
+                    return RusotoError::service(&res, UpdateAddressBookError::NameInUse(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAddressBookError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAddressBookError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6397,14 +6551,16 @@ impl UpdateBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateBusinessReportScheduleError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBusinessReportScheduleError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBusinessReportScheduleError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6437,7 +6593,10 @@ impl UpdateConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConferenceProviderError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6469,12 +6628,13 @@ impl UpdateContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateContactError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContactError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateContactError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateContactError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6509,13 +6669,19 @@ impl UpdateDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDeviceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(UpdateDeviceError::DeviceNotRegistered(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceError::DeviceNotRegistered(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6549,10 +6715,10 @@ impl UpdateGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateGatewayError::NameInUse(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayError::NameInUse(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6585,10 +6751,10 @@ impl UpdateGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateGatewayGroupError::NameInUse(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayGroupError::NameInUse(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayGroupError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6627,25 +6793,31 @@ impl UpdateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNetworkProfileError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNetworkProfileError::InvalidCertificateAuthority(err.msg),
                     )
                 }
                 "InvalidSecretsManagerResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNetworkProfileError::InvalidSecretsManagerResource(err.msg),
                     )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NameInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNetworkProfileError::NameInUse(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateNetworkProfileError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6687,15 +6859,16 @@ impl UpdateProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateProfileError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProfileError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateProfileError::NameInUse(err.msg))
+                    return RusotoError::service(&res, UpdateProfileError::NameInUse(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateProfileError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6729,10 +6902,10 @@ impl UpdateRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateRoomError::NameInUse(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::NameInUse(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6767,15 +6940,16 @@ impl UpdateSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSkillGroupError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::NameInUse(err.msg))
+                    return RusotoError::service(&res, UpdateSkillGroupError::NameInUse(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateSkillGroupError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7393,6 +7567,7 @@ impl AlexaForBusinessClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7408,6 +7583,7 @@ impl AlexaForBusinessClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
         AlexaForBusinessClient { client, region }
     }
@@ -7421,6 +7597,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ApproveSkillRequest,
     ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ApproveSkill");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.ApproveSkill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7442,6 +7621,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<AssociateContactWithAddressBookError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateContactWithAddressBook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateContactWithAddressBook",
@@ -7467,6 +7649,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<AssociateDeviceWithNetworkProfileError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateDeviceWithNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateDeviceWithNetworkProfile",
@@ -7492,6 +7677,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: AssociateDeviceWithRoomRequest,
     ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateDeviceWithRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateDeviceWithRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7512,6 +7700,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateSkillGroupWithRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateSkillGroupWithRoom",
@@ -7535,6 +7726,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateSkillWithSkillGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateSkillWithSkillGroup",
@@ -7557,6 +7751,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: AssociateSkillWithUsersRequest,
     ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateSkillWithUsers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateSkillWithUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7576,6 +7773,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateAddressBookRequest,
     ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAddressBook");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7595,6 +7795,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBusinessReportSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.CreateBusinessReportSchedule",
@@ -7617,6 +7820,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateConferenceProviderRequest,
     ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateConferenceProvider");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7636,6 +7842,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateContactRequest,
     ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateContact");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7654,6 +7863,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateGatewayGroupRequest,
     ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGatewayGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7672,6 +7884,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7691,6 +7906,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateProfileRequest,
     ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProfile");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7709,6 +7927,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateRoomRequest,
     ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRoom");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7727,6 +7948,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateSkillGroupRequest,
     ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSkillGroup");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7745,6 +7969,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7763,6 +7990,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteAddressBookRequest,
     ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAddressBook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7782,6 +8012,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBusinessReportSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DeleteBusinessReportSchedule",
@@ -7804,6 +8037,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteConferenceProviderRequest,
     ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConferenceProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7823,6 +8059,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteContactRequest,
     ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteContact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7841,6 +8080,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteDeviceRequest,
     ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7859,6 +8101,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteDeviceUsageDataRequest,
     ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDeviceUsageData");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDeviceUsageData");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7878,6 +8123,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteGatewayGroupRequest,
     ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGatewayGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7896,6 +8144,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteNetworkProfileRequest,
     ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7915,6 +8166,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteProfileRequest,
     ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7933,6 +8187,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteRoomRequest,
     ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7951,6 +8208,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteRoomSkillParameterRequest,
     ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRoomSkillParameter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7970,6 +8230,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteSkillAuthorizationRequest,
     ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSkillAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7989,6 +8252,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteSkillGroupRequest,
     ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSkillGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8007,6 +8273,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8028,6 +8297,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateContactFromAddressBookError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateContactFromAddressBook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateContactFromAddressBook",
@@ -8054,6 +8326,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateDeviceFromRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateDeviceFromRoom",
@@ -8079,6 +8354,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateSkillFromSkillGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateSkillFromSkillGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateSkillFromSkillGroup",
@@ -8102,6 +8380,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateSkillFromUsers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateSkillFromUsers",
@@ -8127,6 +8408,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateSkillGroupFromRoomError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateSkillGroupFromRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateSkillGroupFromRoom",
@@ -8150,6 +8434,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ForgetSmartHomeAppliances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.ForgetSmartHomeAppliances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8169,6 +8456,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetAddressBookRequest,
     ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAddressBook");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8186,6 +8476,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
     ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConferencePreference");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferencePreference");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8204,6 +8497,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetConferenceProviderRequest,
     ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConferenceProvider");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8223,6 +8519,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetContactRequest,
     ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetContact");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8241,6 +8540,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevice");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8259,6 +8561,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetGatewayRequest,
     ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetGateway");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8277,6 +8582,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetGatewayGroupRequest,
     ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetGatewayGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8295,6 +8603,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInvitationConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.GetInvitationConfiguration",
@@ -8316,6 +8627,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetNetworkProfileRequest,
     ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetNetworkProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8334,6 +8648,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetProfileRequest,
     ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8352,6 +8669,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetRoomRequest,
     ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRoom");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8370,6 +8690,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetRoomSkillParameterRequest,
     ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRoomSkillParameter");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8389,6 +8712,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetSkillGroupRequest,
     ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSkillGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.GetSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8408,6 +8734,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBusinessReportSchedules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.ListBusinessReportSchedules",
@@ -8430,6 +8759,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListConferenceProvidersRequest,
     ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListConferenceProviders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListConferenceProviders");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8449,6 +8781,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListDeviceEventsRequest,
     ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeviceEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListDeviceEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8467,6 +8802,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListGatewayGroupsRequest,
     ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGatewayGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListGatewayGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8485,6 +8823,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListGatewaysRequest,
     ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListGateways");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8503,6 +8844,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListSkillsRequest,
     ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSkills");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkills");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8522,6 +8866,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSkillsStoreCategories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkillsStoreCategories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8544,6 +8891,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<ListSkillsStoreSkillsByCategoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSkillsStoreSkillsByCategory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.ListSkillsStoreSkillsByCategory",
@@ -8566,6 +8916,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListSmartHomeAppliancesRequest,
     ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSmartHomeAppliances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListSmartHomeAppliances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8585,6 +8938,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8603,6 +8959,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutConferencePreferenceRequest,
     ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutConferencePreference");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.PutConferencePreference");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8623,6 +8982,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutInvitationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.PutInvitationConfiguration",
@@ -8645,6 +9007,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutRoomSkillParameterRequest,
     ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRoomSkillParameter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.PutRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8664,6 +9029,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutSkillAuthorizationRequest,
     ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutSkillAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.PutSkillAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8683,6 +9051,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RegisterAVSDeviceRequest,
     ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterAVSDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.RegisterAVSDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8701,6 +9072,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RejectSkillRequest,
     ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RejectSkill");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.RejectSkill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8719,6 +9093,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ResolveRoomRequest,
     ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResolveRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.ResolveRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8737,6 +9114,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RevokeInvitationRequest,
     ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RevokeInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.RevokeInvitation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8755,6 +9135,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchAddressBooksRequest,
     ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchAddressBooks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchAddressBooks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8773,6 +9156,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchContactsRequest,
     ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchContacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchContacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8791,6 +9177,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchDevicesRequest,
     ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8809,6 +9198,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchNetworkProfilesRequest,
     ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchNetworkProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchNetworkProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8828,6 +9220,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchProfilesRequest,
     ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8846,6 +9241,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchRoomsRequest,
     ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchRooms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchRooms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8864,6 +9262,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchSkillGroupsRequest,
     ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchSkillGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchSkillGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8882,6 +9283,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchUsersRequest,
     ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SearchUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8900,6 +9304,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SendAnnouncementRequest,
     ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendAnnouncement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8918,6 +9325,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SendInvitationRequest,
     ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.SendInvitation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8936,6 +9346,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: StartDeviceSyncRequest,
     ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDeviceSync");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.StartDeviceSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8957,6 +9370,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<StartSmartHomeApplianceDiscoveryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartSmartHomeApplianceDiscovery");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.StartSmartHomeApplianceDiscovery",
@@ -8982,6 +9398,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9000,6 +9419,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9018,6 +9440,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateAddressBookRequest,
     ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAddressBook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9037,6 +9462,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateBusinessReportSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.UpdateBusinessReportSchedule",
@@ -9059,6 +9487,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateConferenceProviderRequest,
     ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateConferenceProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9078,6 +9509,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateContactRequest,
     ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateContact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9096,6 +9530,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateDeviceRequest,
     ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9114,6 +9551,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateGatewayRequest,
     ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9132,6 +9572,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateGatewayGroupRequest,
     ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGatewayGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9150,6 +9593,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateNetworkProfileRequest,
     ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9169,6 +9615,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateProfileRequest,
     ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9187,6 +9636,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateRoomRequest,
     ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9205,6 +9657,9 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateSkillGroupRequest,
     ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSkillGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9217,3 +9672,1067 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSkillGroupResponse, _>()
     }
 }
+
+/// A blocking client for the Alexa For Business API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AlexaForBusinessClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AlexaForBusinessBlockingClient {
+    client: AlexaForBusinessClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AlexaForBusinessBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AlexaForBusinessBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AlexaForBusinessBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AlexaForBusinessBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AlexaForBusinessBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient {
+            client: AlexaForBusinessClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
+    ///
+    /// See `AlexaForBusiness::approve_skill`.
+    pub fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
+        self.runtime.block_on(self.client.approve_skill(input))
+    }
+
+    /// <p>Associates a contact with a given address book.</p>
+    ///
+    /// See `AlexaForBusiness::associate_contact_with_address_book`.
+    pub fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> Result<
+        AssociateContactWithAddressBookResponse,
+        RusotoError<AssociateContactWithAddressBookError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_contact_with_address_book(input))
+    }
+
+    /// <p>Associates a device with the specified network profile.</p>
+    ///
+    /// See `AlexaForBusiness::associate_device_with_network_profile`.
+    pub fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> Result<
+        AssociateDeviceWithNetworkProfileResponse,
+        RusotoError<AssociateDeviceWithNetworkProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_device_with_network_profile(input))
+    }
+
+    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
+    ///
+    /// See `AlexaForBusiness::associate_device_with_room`.
+    pub fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
+        self.runtime
+            .block_on(self.client.associate_device_with_room(input))
+    }
+
+    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
+    ///
+    /// See `AlexaForBusiness::associate_skill_group_with_room`.
+    pub fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_skill_group_with_room(input))
+    }
+
+    /// <p>Associates a skill with a skill group.</p>
+    ///
+    /// See `AlexaForBusiness::associate_skill_with_skill_group`.
+    pub fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_skill_with_skill_group(input))
+    }
+
+    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
+    ///
+    /// See `AlexaForBusiness::associate_skill_with_users`.
+    pub fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
+        self.runtime
+            .block_on(self.client.associate_skill_with_users(input))
+    }
+
+    /// <p>Creates an address book with the specified details.</p>
+    ///
+    /// See `AlexaForBusiness::create_address_book`.
+    pub fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
+        self.runtime
+            .block_on(self.client.create_address_book(input))
+    }
+
+    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
+    ///
+    /// See `AlexaForBusiness::create_business_report_schedule`.
+    pub fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.create_business_report_schedule(input))
+    }
+
+    /// <p>Adds a new conference provider under the user's AWS account.</p>
+    ///
+    /// See `AlexaForBusiness::create_conference_provider`.
+    pub fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.create_conference_provider(input))
+    }
+
+    /// <p>Creates a contact with the specified details.</p>
+    ///
+    /// See `AlexaForBusiness::create_contact`.
+    pub fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
+        self.runtime.block_on(self.client.create_contact(input))
+    }
+
+    /// <p>Creates a gateway group with the specified details.</p>
+    ///
+    /// See `AlexaForBusiness::create_gateway_group`.
+    pub fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
+        self.runtime
+            .block_on(self.client.create_gateway_group(input))
+    }
+
+    /// <p>Creates a network profile with the specified details.</p>
+    ///
+    /// See `AlexaForBusiness::create_network_profile`.
+    pub fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.create_network_profile(input))
+    }
+
+    /// <p>Creates a new room profile with the specified details.</p>
+    ///
+    /// See `AlexaForBusiness::create_profile`.
+    pub fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
+        self.runtime.block_on(self.client.create_profile(input))
+    }
+
+    /// <p>Creates a room with the specified details.</p>
+    ///
+    /// See `AlexaForBusiness::create_room`.
+    pub fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        self.runtime.block_on(self.client.create_room(input))
+    }
+
+    /// <p>Creates a skill group with a specified name and description.</p>
+    ///
+    /// See `AlexaForBusiness::create_skill_group`.
+    pub fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
+        self.runtime.block_on(self.client.create_skill_group(input))
+    }
+
+    /// <p>Creates a user.</p>
+    ///
+    /// See `AlexaForBusiness::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deletes an address book by the address book ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_address_book`.
+    pub fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
+        self.runtime
+            .block_on(self.client.delete_address_book(input))
+    }
+
+    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_business_report_schedule`.
+    pub fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_business_report_schedule(input))
+    }
+
+    /// <p>Deletes a conference provider.</p>
+    ///
+    /// See `AlexaForBusiness::delete_conference_provider`.
+    pub fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_conference_provider(input))
+    }
+
+    /// <p>Deletes a contact by the contact ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_contact`.
+    pub fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
+        self.runtime.block_on(self.client.delete_contact(input))
+    }
+
+    /// <p>Removes a device from Alexa For Business.</p>
+    ///
+    /// See `AlexaForBusiness::delete_device`.
+    pub fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+        self.runtime.block_on(self.client.delete_device(input))
+    }
+
+    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
+    ///
+    /// See `AlexaForBusiness::delete_device_usage_data`.
+    pub fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
+        self.runtime
+            .block_on(self.client.delete_device_usage_data(input))
+    }
+
+    /// <p>Deletes a gateway group.</p>
+    ///
+    /// See `AlexaForBusiness::delete_gateway_group`.
+    pub fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_gateway_group(input))
+    }
+
+    /// <p>Deletes a network profile by the network profile ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_network_profile`.
+    pub fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_network_profile(input))
+    }
+
+    /// <p>Deletes a room profile by the profile ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_profile`.
+    pub fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
+        self.runtime.block_on(self.client.delete_profile(input))
+    }
+
+    /// <p>Deletes a room by the room ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_room`.
+    pub fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
+        self.runtime.block_on(self.client.delete_room(input))
+    }
+
+    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
+    ///
+    /// See `AlexaForBusiness::delete_room_skill_parameter`.
+    pub fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
+        self.runtime
+            .block_on(self.client.delete_room_skill_parameter(input))
+    }
+
+    /// <p>Unlinks a third-party account from a skill.</p>
+    ///
+    /// See `AlexaForBusiness::delete_skill_authorization`.
+    pub fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.delete_skill_authorization(input))
+    }
+
+    /// <p>Deletes a skill group by skill group ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_skill_group`.
+    pub fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
+        self.runtime.block_on(self.client.delete_skill_group(input))
+    }
+
+    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
+    ///
+    /// See `AlexaForBusiness::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Disassociates a contact from a given address book.</p>
+    ///
+    /// See `AlexaForBusiness::disassociate_contact_from_address_book`.
+    pub fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> Result<
+        DisassociateContactFromAddressBookResponse,
+        RusotoError<DisassociateContactFromAddressBookError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_contact_from_address_book(input))
+    }
+
+    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
+    ///
+    /// See `AlexaForBusiness::disassociate_device_from_room`.
+    pub fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_device_from_room(input))
+    }
+
+    /// <p>Disassociates a skill from a skill group.</p>
+    ///
+    /// See `AlexaForBusiness::disassociate_skill_from_skill_group`.
+    pub fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> Result<
+        DisassociateSkillFromSkillGroupResponse,
+        RusotoError<DisassociateSkillFromSkillGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_skill_from_skill_group(input))
+    }
+
+    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
+    ///
+    /// See `AlexaForBusiness::disassociate_skill_from_users`.
+    pub fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_skill_from_users(input))
+    }
+
+    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
+    ///
+    /// See `AlexaForBusiness::disassociate_skill_group_from_room`.
+    pub fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> Result<
+        DisassociateSkillGroupFromRoomResponse,
+        RusotoError<DisassociateSkillGroupFromRoomError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_skill_group_from_room(input))
+    }
+
+    /// <p>Forgets smart home appliances associated to a room.</p>
+    ///
+    /// See `AlexaForBusiness::forget_smart_home_appliances`.
+    pub fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
+    {
+        self.runtime
+            .block_on(self.client.forget_smart_home_appliances(input))
+    }
+
+    /// <p>Gets address the book details by the address book ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_address_book`.
+    pub fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
+        self.runtime.block_on(self.client.get_address_book(input))
+    }
+
+    /// <p>Retrieves the existing conference preferences.</p>
+    ///
+    /// See `AlexaForBusiness::get_conference_preference`.
+    pub fn get_conference_preference(
+        &self,
+    ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
+        self.runtime
+            .block_on(self.client.get_conference_preference())
+    }
+
+    /// <p>Gets details about a specific conference provider.</p>
+    ///
+    /// See `AlexaForBusiness::get_conference_provider`.
+    pub fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.get_conference_provider(input))
+    }
+
+    /// <p>Gets the contact details by the contact ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_contact`.
+    pub fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
+        self.runtime.block_on(self.client.get_contact(input))
+    }
+
+    /// <p>Gets the details of a device by device ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_device`.
+    pub fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+        self.runtime.block_on(self.client.get_device(input))
+    }
+
+    /// <p>Retrieves the details of a gateway.</p>
+    ///
+    /// See `AlexaForBusiness::get_gateway`.
+    pub fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
+        self.runtime.block_on(self.client.get_gateway(input))
+    }
+
+    /// <p>Retrieves the details of a gateway group.</p>
+    ///
+    /// See `AlexaForBusiness::get_gateway_group`.
+    pub fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
+        self.runtime.block_on(self.client.get_gateway_group(input))
+    }
+
+    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
+    ///
+    /// See `AlexaForBusiness::get_invitation_configuration`.
+    pub fn get_invitation_configuration(
+        &self,
+    ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_invitation_configuration())
+    }
+
+    /// <p>Gets the network profile details by the network profile ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_network_profile`.
+    pub fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.get_network_profile(input))
+    }
+
+    /// <p>Gets the details of a room profile by profile ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_profile`.
+    pub fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+        self.runtime.block_on(self.client.get_profile(input))
+    }
+
+    /// <p>Gets room details by room ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_room`.
+    pub fn get_room(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+        self.runtime.block_on(self.client.get_room(input))
+    }
+
+    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_room_skill_parameter`.
+    pub fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
+        self.runtime
+            .block_on(self.client.get_room_skill_parameter(input))
+    }
+
+    /// <p>Gets skill group details by skill group ARN.</p>
+    ///
+    /// See `AlexaForBusiness::get_skill_group`.
+    pub fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
+        self.runtime.block_on(self.client.get_skill_group(input))
+    }
+
+    /// <p>Lists the details of the schedules that a user configured. A download URL of the report associated with each schedule is returned every time this action is called. A new download URL is returned each time, and is valid for 24 hours.</p>
+    ///
+    /// See `AlexaForBusiness::list_business_report_schedules`.
+    pub fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_business_report_schedules(input))
+    }
+
+    /// <p>Lists conference providers under a specific AWS account.</p>
+    ///
+    /// See `AlexaForBusiness::list_conference_providers`.
+    pub fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
+        self.runtime
+            .block_on(self.client.list_conference_providers(input))
+    }
+
+    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
+    ///
+    /// See `AlexaForBusiness::list_device_events`.
+    pub fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+        self.runtime.block_on(self.client.list_device_events(input))
+    }
+
+    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
+    ///
+    /// See `AlexaForBusiness::list_gateway_groups`.
+    pub fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_gateway_groups(input))
+    }
+
+    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
+    ///
+    /// See `AlexaForBusiness::list_gateways`.
+    pub fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
+        self.runtime.block_on(self.client.list_gateways(input))
+    }
+
+    /// <p>Lists all enabled skills in a specific skill group.</p>
+    ///
+    /// See `AlexaForBusiness::list_skills`.
+    pub fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
+        self.runtime.block_on(self.client.list_skills(input))
+    }
+
+    /// <p>Lists all categories in the Alexa skill store.</p>
+    ///
+    /// See `AlexaForBusiness::list_skills_store_categories`.
+    pub fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_skills_store_categories(input))
+    }
+
+    /// <p>Lists all skills in the Alexa skill store by category.</p>
+    ///
+    /// See `AlexaForBusiness::list_skills_store_skills_by_category`.
+    pub fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> Result<
+        ListSkillsStoreSkillsByCategoryResponse,
+        RusotoError<ListSkillsStoreSkillsByCategoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_skills_store_skills_by_category(input))
+    }
+
+    /// <p>Lists all of the smart home appliances associated with a room.</p>
+    ///
+    /// See `AlexaForBusiness::list_smart_home_appliances`.
+    pub fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
+        self.runtime
+            .block_on(self.client.list_smart_home_appliances(input))
+    }
+
+    /// <p>Lists all tags for the specified resource.</p>
+    ///
+    /// See `AlexaForBusiness::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
+    ///
+    /// See `AlexaForBusiness::put_conference_preference`.
+    pub fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
+        self.runtime
+            .block_on(self.client.put_conference_preference(input))
+    }
+
+    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
+    ///
+    /// See `AlexaForBusiness::put_invitation_configuration`.
+    pub fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_invitation_configuration(input))
+    }
+
+    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
+    ///
+    /// See `AlexaForBusiness::put_room_skill_parameter`.
+    pub fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
+        self.runtime
+            .block_on(self.client.put_room_skill_parameter(input))
+    }
+
+    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
+    ///
+    /// See `AlexaForBusiness::put_skill_authorization`.
+    pub fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.put_skill_authorization(input))
+    }
+
+    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
+    ///
+    /// See `AlexaForBusiness::register_avs_device`.
+    pub fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
+        self.runtime
+            .block_on(self.client.register_avs_device(input))
+    }
+
+    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
+    ///
+    /// See `AlexaForBusiness::reject_skill`.
+    pub fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
+        self.runtime.block_on(self.client.reject_skill(input))
+    }
+
+    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
+    ///
+    /// See `AlexaForBusiness::resolve_room`.
+    pub fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
+        self.runtime.block_on(self.client.resolve_room(input))
+    }
+
+    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
+    ///
+    /// See `AlexaForBusiness::revoke_invitation`.
+    pub fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
+        self.runtime.block_on(self.client.revoke_invitation(input))
+    }
+
+    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_address_books`.
+    pub fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
+        self.runtime
+            .block_on(self.client.search_address_books(input))
+    }
+
+    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_contacts`.
+    pub fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
+        self.runtime.block_on(self.client.search_contacts(input))
+    }
+
+    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_devices`.
+    pub fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
+        self.runtime.block_on(self.client.search_devices(input))
+    }
+
+    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_network_profiles`.
+    pub fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
+        self.runtime
+            .block_on(self.client.search_network_profiles(input))
+    }
+
+    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_profiles`.
+    pub fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
+        self.runtime.block_on(self.client.search_profiles(input))
+    }
+
+    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_rooms`.
+    pub fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
+        self.runtime.block_on(self.client.search_rooms(input))
+    }
+
+    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_skill_groups`.
+    pub fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
+        self.runtime
+            .block_on(self.client.search_skill_groups(input))
+    }
+
+    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
+    ///
+    /// See `AlexaForBusiness::search_users`.
+    pub fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
+        self.runtime.block_on(self.client.search_users(input))
+    }
+
+    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
+    ///
+    /// See `AlexaForBusiness::send_announcement`.
+    pub fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
+        self.runtime.block_on(self.client.send_announcement(input))
+    }
+
+    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
+    ///
+    /// See `AlexaForBusiness::send_invitation`.
+    pub fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
+        self.runtime.block_on(self.client.send_invitation(input))
+    }
+
+    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
+    ///
+    /// See `AlexaForBusiness::start_device_sync`.
+    pub fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
+        self.runtime.block_on(self.client.start_device_sync(input))
+    }
+
+    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
+    ///
+    /// See `AlexaForBusiness::start_smart_home_appliance_discovery`.
+    pub fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> Result<
+        StartSmartHomeApplianceDiscoveryResponse,
+        RusotoError<StartSmartHomeApplianceDiscoveryError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_smart_home_appliance_discovery(input))
+    }
+
+    /// <p>Adds metadata tags to a specified resource.</p>
+    ///
+    /// See `AlexaForBusiness::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes metadata tags from a specified resource.</p>
+    ///
+    /// See `AlexaForBusiness::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates address book details by the address book ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_address_book`.
+    pub fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
+        self.runtime
+            .block_on(self.client.update_address_book(input))
+    }
+
+    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_business_report_schedule`.
+    pub fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.update_business_report_schedule(input))
+    }
+
+    /// <p>Updates an existing conference provider's settings.</p>
+    ///
+    /// See `AlexaForBusiness::update_conference_provider`.
+    pub fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.update_conference_provider(input))
+    }
+
+    /// <p>Updates the contact details by the contact ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_contact`.
+    pub fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
+        self.runtime.block_on(self.client.update_contact(input))
+    }
+
+    /// <p>Updates the device name by device ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_device`.
+    pub fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+        self.runtime.block_on(self.client.update_device(input))
+    }
+
+    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    ///
+    /// See `AlexaForBusiness::update_gateway`.
+    pub fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
+        self.runtime.block_on(self.client.update_gateway(input))
+    }
+
+    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    ///
+    /// See `AlexaForBusiness::update_gateway_group`.
+    pub fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_group(input))
+    }
+
+    /// <p>Updates a network profile by the network profile ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_network_profile`.
+    pub fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.update_network_profile(input))
+    }
+
+    /// <p>Updates an existing room profile by room profile ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_profile`.
+    pub fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
+        self.runtime.block_on(self.client.update_profile(input))
+    }
+
+    /// <p>Updates room details by room ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_room`.
+    pub fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+        self.runtime.block_on(self.client.update_room(input))
+    }
+
+    /// <p>Updates skill group details by skill group ARN.</p>
+    ///
+    /// See `AlexaForBusiness::update_skill_group`.
+    pub fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
+        self.runtime.block_on(self.client.update_skill_group(input))
+    }
+}
@@ -1690,19 +1690,22 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAppError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateAppError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateAppError::DependentServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAppError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAppError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateAppError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAppError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateAppError::LimitExceeded(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAppError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateAppError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1744,25 +1747,34 @@ impl CreateBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackendEnvironmentError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackendEnvironmentError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackendEnvironmentError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackendEnvironmentError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackendEnvironmentError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1806,24 +1818,25 @@ impl CreateBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBranchError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateBranchError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBranchError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBranchError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1864,16 +1877,22 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDeploymentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::LimitExceeded(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1916,30 +1935,40 @@ impl CreateDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainAssociationError::BadRequest(err.msg),
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDomainAssociationError::DependentServiceFailure(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainAssociationError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainAssociationError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainAssociationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainAssociationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1986,24 +2015,25 @@ impl CreateWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateWebhookError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebhookError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateWebhookError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2046,19 +2076,22 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAppError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(DeleteAppError::DependentServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAppError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAppError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAppError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAppError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2100,25 +2133,34 @@ impl DeleteBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackendEnvironmentError::BadRequest(err.msg),
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackendEnvironmentError::DependentServiceFailure(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackendEnvironmentError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackendEnvironmentError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackendEnvironmentError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2162,21 +2204,22 @@ impl DeleteBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBranchError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBranchError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(DeleteBranchError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteBranchError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBranchError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBranchError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteBranchError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2218,25 +2261,34 @@ impl DeleteDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainAssociationError::BadRequest(err.msg),
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDomainAssociationError::DependentServiceFailure(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainAssociationError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainAssociationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainAssociationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2280,19 +2332,19 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteJobError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteJobError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2334,19 +2386,19 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteWebhookError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2386,16 +2438,22 @@ impl GenerateAccessLogsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GenerateAccessLogsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateAccessLogsError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GenerateAccessLogsError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateAccessLogsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2434,14 +2492,16 @@ impl GetAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAppError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAppError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetAppError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetAppError::InternalFailure(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetAppError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetAppError::NotFound(err.msg)),
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAppError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetAppError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2482,19 +2542,22 @@ impl GetArtifactUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetArtifactUrlError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetArtifactUrlError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetArtifactUrlError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetArtifactUrlError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetArtifactUrlError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetArtifactUrlError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetArtifactUrlError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetArtifactUrlError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetArtifactUrlError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetArtifactUrlError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2534,18 +2597,28 @@ impl GetBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackendEnvironmentError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackendEnvironmentError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackendEnvironmentError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackendEnvironmentError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2584,16 +2657,16 @@ impl GetBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBranchError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBranchError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetBranchError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBranchError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBranchError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetBranchError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2632,18 +2705,25 @@ impl GetDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainAssociationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainAssociationError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDomainAssociationError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainAssociationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainAssociationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2684,17 +2764,19 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetJobError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetJobError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetJobError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetJobError::LimitExceeded(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetJobError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetJobError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetJobError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2736,19 +2818,19 @@ impl GetWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetWebhookError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetWebhookError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetWebhookError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetWebhookError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetWebhookError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetWebhookError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2786,13 +2868,13 @@ impl ListAppsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAppsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListAppsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAppsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListAppsError::InternalFailure(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAppsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListAppsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2830,16 +2912,16 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListArtifactsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListArtifactsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListArtifactsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::LimitExceeded(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListArtifactsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2876,17 +2958,22 @@ impl ListBackendEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackendEnvironmentsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackendEnvironmentsError::InternalFailure(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackendEnvironmentsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2922,13 +3009,13 @@ impl ListBranchesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBranchesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListBranchesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBranchesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListBranchesError::InternalFailure(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBranchesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListBranchesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2964,15 +3051,22 @@ impl ListDomainAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainAssociationsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainAssociationsError::InternalFailure(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainAssociationsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3010,16 +3104,16 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListJobsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListJobsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListJobsError::LimitExceeded(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListJobsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListJobsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3056,15 +3150,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3102,16 +3203,16 @@ impl ListWebhooksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListWebhooksError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListWebhooksError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListWebhooksError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListWebhooksError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListWebhooksError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListWebhooksError::LimitExceeded(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListWebhooksError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListWebhooksError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3152,19 +3253,22 @@ impl StartDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartDeploymentError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartDeploymentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDeploymentError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartDeploymentError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartDeploymentError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, StartDeploymentError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3206,19 +3310,19 @@ impl StartJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartJobError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartJobError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, StartJobError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartJobError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartJobError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartJobError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, StartJobError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3260,19 +3364,19 @@ impl StopJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopJobError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StopJobError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, StopJobError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StopJobError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopJobError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopJobError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, StopJobError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3310,13 +3414,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3352,13 +3456,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3396,16 +3503,16 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAppError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAppError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAppError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAppError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3446,21 +3553,22 @@ impl UpdateBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBranchError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateBranchError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(UpdateBranchError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBranchError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateBranchError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateBranchError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBranchError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateBranchError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBranchError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateBranchError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3502,25 +3610,34 @@ impl UpdateDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainAssociationError::BadRequest(err.msg),
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDomainAssociationError::DependentServiceFailure(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainAssociationError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainAssociationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainAssociationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3564,21 +3681,22 @@ impl UpdateWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateWebhookError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateWebhookError::BadRequest(err.msg))
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(UpdateWebhookError::DependentServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebhookError::DependentServiceFailure(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateWebhookError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateWebhookError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateWebhookError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateWebhookError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3839,6 +3957,7 @@ impl AmplifyClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3854,6 +3973,7 @@ impl AmplifyClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AmplifyClient {
         AmplifyClient { client, region }
     }
@@ -3870,6 +3990,9 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("CreateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3901,6 +4024,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("CreateBackendEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3932,6 +4058,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("CreateBranch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3967,6 +4096,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("CreateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3998,6 +4130,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("CreateDomainAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4029,6 +4164,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("CreateWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4060,6 +4198,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("DeleteApp");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4092,6 +4233,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("DeleteBackendEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4124,6 +4268,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("DeleteBranch");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4156,6 +4303,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("DeleteDomainAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4189,6 +4339,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("DeleteJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4217,6 +4370,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("DeleteWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4245,6 +4401,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/accesslogs", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GenerateAccessLogs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4276,6 +4435,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetApp");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4304,6 +4466,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/artifacts/{artifact_id}", artifact_id = input.artifact_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetArtifactUrl");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4336,6 +4501,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetBackendEnvironment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4368,6 +4536,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetBranch");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4400,6 +4571,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetDomainAssociation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4433,6 +4607,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4461,6 +4638,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("GetWebhook");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4489,6 +4669,9 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListApps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4531,6 +4714,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListArtifacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4568,6 +4754,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListBackendEnvironments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4608,6 +4797,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListBranches");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4645,6 +4837,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListDomainAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4686,6 +4881,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4723,6 +4921,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4751,6 +4952,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation_name("ListWebhooks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4792,6 +4996,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("StartDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4827,6 +5034,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("StartJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4863,6 +5073,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("StopJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4891,6 +5104,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4922,6 +5138,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4956,6 +5175,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("UpdateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4991,6 +5213,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("UpdateBranch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5026,6 +5251,9 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("UpdateDomainAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5057,6 +5285,9 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation_name("UpdateWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5079,3 +5310,428 @@ impl Amplify for AmplifyClient {
         }
     }
 }
+
+/// A blocking client for the Amplify API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AmplifyClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AmplifyBlockingClient {
+    client: AmplifyClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AmplifyBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AmplifyBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AmplifyBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AmplifyBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AmplifyBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AmplifyBlockingClient {
+        AmplifyBlockingClient {
+            client: AmplifyClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p> Creates a new Amplify app. </p>
+    ///
+    /// See `Amplify::create_app`.
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// <p> Creates a new backend environment for an Amplify app. </p>
+    ///
+    /// See `Amplify::create_backend_environment`.
+    pub fn create_backend_environment(
+        &self,
+        input: CreateBackendEnvironmentRequest,
+    ) -> Result<CreateBackendEnvironmentResult, RusotoError<CreateBackendEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.create_backend_environment(input))
+    }
+
+    /// <p> Creates a new branch for an Amplify app. </p>
+    ///
+    /// See `Amplify::create_branch`.
+    pub fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> Result<CreateBranchResult, RusotoError<CreateBranchError>> {
+        self.runtime.block_on(self.client.create_branch(input))
+    }
+
+    /// <p> Creates a deployment for a manually deployed Amplify app. Manually deployed apps are not connected to a repository. </p>
+    ///
+    /// See `Amplify::create_deployment`.
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// <p> Creates a new domain association for an Amplify app. This action associates a custom domain with the Amplify app </p>
+    ///
+    /// See `Amplify::create_domain_association`.
+    pub fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> Result<CreateDomainAssociationResult, RusotoError<CreateDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.create_domain_association(input))
+    }
+
+    /// <p> Creates a new webhook on an Amplify app. </p>
+    ///
+    /// See `Amplify::create_webhook`.
+    pub fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> Result<CreateWebhookResult, RusotoError<CreateWebhookError>> {
+        self.runtime.block_on(self.client.create_webhook(input))
+    }
+
+    /// <p> Deletes an existing Amplify app specified by an app ID. </p>
+    ///
+    /// See `Amplify::delete_app`.
+    pub fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<DeleteAppResult, RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// <p> Deletes a backend environment for an Amplify app. </p>
+    ///
+    /// See `Amplify::delete_backend_environment`.
+    pub fn delete_backend_environment(
+        &self,
+        input: DeleteBackendEnvironmentRequest,
+    ) -> Result<DeleteBackendEnvironmentResult, RusotoError<DeleteBackendEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.delete_backend_environment(input))
+    }
+
+    /// <p> Deletes a branch for an Amplify app. </p>
+    ///
+    /// See `Amplify::delete_branch`.
+    pub fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> Result<DeleteBranchResult, RusotoError<DeleteBranchError>> {
+        self.runtime.block_on(self.client.delete_branch(input))
+    }
+
+    /// <p> Deletes a domain association for an Amplify app. </p>
+    ///
+    /// See `Amplify::delete_domain_association`.
+    pub fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> Result<DeleteDomainAssociationResult, RusotoError<DeleteDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.delete_domain_association(input))
+    }
+
+    /// <p> Deletes a job for a branch of an Amplify app. </p>
+    ///
+    /// See `Amplify::delete_job`.
+    pub fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<DeleteJobResult, RusotoError<DeleteJobError>> {
+        self.runtime.block_on(self.client.delete_job(input))
+    }
+
+    /// <p> Deletes a webhook. </p>
+    ///
+    /// See `Amplify::delete_webhook`.
+    pub fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> Result<DeleteWebhookResult, RusotoError<DeleteWebhookError>> {
+        self.runtime.block_on(self.client.delete_webhook(input))
+    }
+
+    /// <p> Returns the website access logs for a specific time range using a presigned URL. </p>
+    ///
+    /// See `Amplify::generate_access_logs`.
+    pub fn generate_access_logs(
+        &self,
+        input: GenerateAccessLogsRequest,
+    ) -> Result<GenerateAccessLogsResult, RusotoError<GenerateAccessLogsError>> {
+        self.runtime
+            .block_on(self.client.generate_access_logs(input))
+    }
+
+    /// <p> Returns an existing Amplify app by appID. </p>
+    ///
+    /// See `Amplify::get_app`.
+    pub fn get_app(&self, input: GetAppRequest) -> Result<GetAppResult, RusotoError<GetAppError>> {
+        self.runtime.block_on(self.client.get_app(input))
+    }
+
+    /// <p> Returns the artifact info that corresponds to an artifact id. </p>
+    ///
+    /// See `Amplify::get_artifact_url`.
+    pub fn get_artifact_url(
+        &self,
+        input: GetArtifactUrlRequest,
+    ) -> Result<GetArtifactUrlResult, RusotoError<GetArtifactUrlError>> {
+        self.runtime.block_on(self.client.get_artifact_url(input))
+    }
+
+    /// <p> Returns a backend environment for an Amplify app. </p>
+    ///
+    /// See `Amplify::get_backend_environment`.
+    pub fn get_backend_environment(
+        &self,
+        input: GetBackendEnvironmentRequest,
+    ) -> Result<GetBackendEnvironmentResult, RusotoError<GetBackendEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.get_backend_environment(input))
+    }
+
+    /// <p> Returns a branch for an Amplify app. </p>
+    ///
+    /// See `Amplify::get_branch`.
+    pub fn get_branch(
+        &self,
+        input: GetBranchRequest,
+    ) -> Result<GetBranchResult, RusotoError<GetBranchError>> {
+        self.runtime.block_on(self.client.get_branch(input))
+    }
+
+    /// <p> Returns the domain information for an Amplify app. </p>
+    ///
+    /// See `Amplify::get_domain_association`.
+    pub fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> Result<GetDomainAssociationResult, RusotoError<GetDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.get_domain_association(input))
+    }
+
+    /// <p> Returns a job for a branch of an Amplify app. </p>
+    ///
+    /// See `Amplify::get_job`.
+    pub fn get_job(&self, input: GetJobRequest) -> Result<GetJobResult, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// <p> Returns the webhook information that corresponds to a specified webhook ID. </p>
+    ///
+    /// See `Amplify::get_webhook`.
+    pub fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> Result<GetWebhookResult, RusotoError<GetWebhookError>> {
+        self.runtime.block_on(self.client.get_webhook(input))
+    }
+
+    /// <p> Returns a list of the existing Amplify apps. </p>
+    ///
+    /// See `Amplify::list_apps`.
+    pub fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResult, RusotoError<ListAppsError>> {
+        self.runtime.block_on(self.client.list_apps(input))
+    }
+
+    /// <p> Returns a list of artifacts for a specified app, branch, and job. </p>
+    ///
+    /// See `Amplify::list_artifacts`.
+    pub fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+        self.runtime.block_on(self.client.list_artifacts(input))
+    }
+
+    /// <p> Lists the backend environments for an Amplify app. </p>
+    ///
+    /// See `Amplify::list_backend_environments`.
+    pub fn list_backend_environments(
+        &self,
+        input: ListBackendEnvironmentsRequest,
+    ) -> Result<ListBackendEnvironmentsResult, RusotoError<ListBackendEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.list_backend_environments(input))
+    }
+
+    /// <p> Lists the branches of an Amplify app. </p>
+    ///
+    /// See `Amplify::list_branches`.
+    pub fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> Result<ListBranchesResult, RusotoError<ListBranchesError>> {
+        self.runtime.block_on(self.client.list_branches(input))
+    }
+
+    /// <p> Returns the domain associations for an Amplify app. </p>
+    ///
+    /// See `Amplify::list_domain_associations`.
+    pub fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> Result<ListDomainAssociationsResult, RusotoError<ListDomainAssociationsError>> {
+        self.runtime
+            .block_on(self.client.list_domain_associations(input))
+    }
+
+    /// <p> Lists the jobs for a branch of an Amplify app. </p>
+    ///
+    /// See `Amplify::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p> Returns a list of tags for a specified Amazon Resource Name (ARN). </p>
+    ///
+    /// See `Amplify::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p> Returns a list of webhooks for an Amplify app. </p>
+    ///
+    /// See `Amplify::list_webhooks`.
+    pub fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> Result<ListWebhooksResult, RusotoError<ListWebhooksError>> {
+        self.runtime.block_on(self.client.list_webhooks(input))
+    }
+
+    /// <p> Starts a deployment for a manually deployed app. Manually deployed apps are not connected to a repository. </p>
+    ///
+    /// See `Amplify::start_deployment`.
+    pub fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<StartDeploymentResult, RusotoError<StartDeploymentError>> {
+        self.runtime.block_on(self.client.start_deployment(input))
+    }
+
+    /// <p> Starts a new job for a branch of an Amplify app. </p>
+    ///
+    /// See `Amplify::start_job`.
+    pub fn start_job(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<StartJobResult, RusotoError<StartJobError>> {
+        self.runtime.block_on(self.client.start_job(input))
+    }
+
+    /// <p> Stops a job that is in progress for a branch of an Amplify app. </p>
+    ///
+    /// See `Amplify::stop_job`.
+    pub fn stop_job(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+        self.runtime.block_on(self.client.stop_job(input))
+    }
+
+    /// <p> Tags the resource with a tag key and value. </p>
+    ///
+    /// See `Amplify::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p> Untags a resource with a specified Amazon Resource Name (ARN). </p>
+    ///
+    /// See `Amplify::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p> Updates an existing Amplify app. </p>
+    ///
+    /// See `Amplify::update_app`.
+    pub fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<UpdateAppResult, RusotoError<UpdateAppError>> {
+        self.runtime.block_on(self.client.update_app(input))
+    }
+
+    /// <p> Updates a branch for an Amplify app. </p>
+    ///
+    /// See `Amplify::update_branch`.
+    pub fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> Result<UpdateBranchResult, RusotoError<UpdateBranchError>> {
+        self.runtime.block_on(self.client.update_branch(input))
+    }
+
+    /// <p> Creates a new domain association for an Amplify app.</p>
+    ///
+    /// See `Amplify::update_domain_association`.
+    pub fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> Result<UpdateDomainAssociationResult, RusotoError<UpdateDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.update_domain_association(input))
+    }
+
+    /// <p> Updates a webhook. </p>
+    ///
+    /// See `Amplify::update_webhook`.
+    pub fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> Result<UpdateWebhookResult, RusotoError<UpdateWebhookError>> {
+        self.runtime.block_on(self.client.update_webhook(input))
+    }
+}
@@ -3672,22 +3672,22 @@ impl CreateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiKeyError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3730,19 +3730,25 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::BadRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3784,21 +3790,34 @@ impl CreateBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBasePathMappingError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBasePathMappingError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBasePathMappingError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBasePathMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBasePathMappingError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3844,25 +3863,34 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3908,28 +3936,40 @@ impl CreateDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationPartError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationPartError::Conflict(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationPartError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationPartError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationPartError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationPartError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3976,30 +4016,40 @@ impl CreateDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationVersionError::Conflict(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationVersionError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentationVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4040,16 +4090,19 @@ impl CreateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::Conflict(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainNameError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4092,22 +4145,22 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateModelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateModelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateModelError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateModelError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateModelError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateModelError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateModelError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateModelError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4150,23 +4203,34 @@ impl CreateRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRequestValidatorError::BadRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRequestValidatorError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRequestValidatorError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRequestValidatorError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRequestValidatorError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4210,22 +4274,25 @@ impl CreateResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateResourceError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4266,16 +4333,16 @@ impl CreateRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRestApiError::BadRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateRestApiError::LimitExceeded(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateRestApiError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateRestApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4318,22 +4385,22 @@ impl CreateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateStageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateStageError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateStageError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateStageError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStageError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateStageError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateStageError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateStageError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateStageError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4378,22 +4445,25 @@ impl CreateUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUsagePlanError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUsagePlanError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUsagePlanError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4436,19 +4506,25 @@ impl CreateUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanKeyError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanKeyError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateUsagePlanKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUsagePlanKeyError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUsagePlanKeyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4486,13 +4562,13 @@ impl CreateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateVpcLinkError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateVpcLinkError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateVpcLinkError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4528,13 +4604,13 @@ impl DeleteApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4574,19 +4650,22 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4628,21 +4707,34 @@ impl DeleteBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBasePathMappingError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBasePathMappingError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBasePathMappingError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBasePathMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBasePathMappingError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4682,20 +4774,28 @@ impl DeleteClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClientCertificateError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClientCertificateError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClientCertificateError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClientCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4734,16 +4834,19 @@ impl DeleteDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteDeploymentError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDeploymentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteDeploymentError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4784,23 +4887,34 @@ impl DeleteDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationPartError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationPartError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationPartError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationPartError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationPartError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4844,25 +4958,34 @@ impl DeleteDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationVersionError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationVersionError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentationVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4902,16 +5025,19 @@ impl DeleteDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteDomainNameError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDomainNameError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainNameError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteDomainNameError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4952,21 +5078,34 @@ impl DeleteGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayResponseError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5006,16 +5145,22 @@ impl DeleteIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteIntegrationError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5056,25 +5201,34 @@ impl DeleteIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5114,16 +5268,16 @@ impl DeleteMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMethodError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteMethodError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteMethodError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteMethodError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteMethodError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5164,21 +5318,28 @@ impl DeleteMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMethodResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteMethodResponseError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteMethodResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMethodResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMethodResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5220,19 +5381,19 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteModelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteModelError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteModelError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5274,21 +5435,34 @@ impl DeleteRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRequestValidatorError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRequestValidatorError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRequestValidatorError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRequestValidatorError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRequestValidatorError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5330,19 +5504,22 @@ impl DeleteResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteResourceError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5382,16 +5559,16 @@ impl DeleteRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteRestApiError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRestApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRestApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteRestApiError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteRestApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5430,16 +5607,16 @@ impl DeleteStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteStageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteStageError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteStageError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteStageError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteStageError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5478,16 +5655,19 @@ impl DeleteUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteUsagePlanError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUsagePlanError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUsagePlanError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteUsagePlanError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5528,19 +5708,25 @@ impl DeleteUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteUsagePlanKeyError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteUsagePlanKeyError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUsagePlanKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUsagePlanKeyError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUsagePlanKeyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5580,16 +5766,16 @@ impl DeleteVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteVpcLinkError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVpcLinkError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteVpcLinkError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteVpcLinkError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5630,22 +5816,28 @@ impl FlushStageAuthorizersCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FlushStageAuthorizersCacheError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FlushStageAuthorizersCacheError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FlushStageAuthorizersCacheError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FlushStageAuthorizersCacheError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5684,16 +5876,19 @@ impl FlushStageCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushStageCacheError::BadRequest(err.msg))
+                    return RusotoError::service(&res, FlushStageCacheError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushStageCacheError::NotFound(err.msg))
+                    return RusotoError::service(&res, FlushStageCacheError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(FlushStageCacheError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FlushStageCacheError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushStageCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, FlushStageCacheError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5730,19 +5925,22 @@ impl GenerateClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateClientCertificateError::LimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateClientCertificateError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateClientCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5778,13 +5976,13 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAccountError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetAccountError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAccountError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetAccountError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5820,13 +6018,13 @@ impl GetApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApiKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetApiKeyError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5862,13 +6060,13 @@ impl GetApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApiKeysError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetApiKeysError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetApiKeysError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5904,13 +6102,13 @@ impl GetAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetAuthorizerError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5948,16 +6146,19 @@ impl GetAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAuthorizersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAuthorizersError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizersError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAuthorizersError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAuthorizersError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAuthorizersError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetAuthorizersError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5994,13 +6195,19 @@ impl GetBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBasePathMappingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBasePathMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBasePathMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBasePathMappingError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6036,13 +6243,19 @@ impl GetBasePathMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBasePathMappingsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBasePathMappingsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBasePathMappingsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6078,15 +6291,19 @@ impl GetClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetClientCertificateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetClientCertificateError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetClientCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetClientCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6122,15 +6339,22 @@ impl GetClientCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetClientCertificatesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetClientCertificatesError::BadRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetClientCertificatesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetClientCertificatesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetClientCertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetClientCertificatesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6168,16 +6392,19 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6216,16 +6443,22 @@ impl GetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDeploymentsError::BadRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDeploymentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDeploymentsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetDeploymentsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6262,15 +6495,19 @@ impl GetDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDocumentationPartError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationPartError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationPartError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationPartError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6308,18 +6545,28 @@ impl GetDocumentationPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationPartsError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationPartsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationPartsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationPartsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6356,17 +6603,22 @@ impl GetDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6404,20 +6656,28 @@ impl GetDocumentationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentationVersionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6456,16 +6716,19 @@ impl GetDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDomainNameError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDomainNameError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainNameError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetDomainNameError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetDomainNameError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6502,13 +6765,16 @@ impl GetDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainNamesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDomainNamesError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainNamesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainNamesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetDomainNamesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6548,19 +6814,19 @@ impl GetExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetExportError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetExportError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetExportError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetExportError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetExportError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetExportError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetExportError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetExportError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetExportError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6598,13 +6864,19 @@ impl GetGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGatewayResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGatewayResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGatewayResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGatewayResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6642,16 +6914,25 @@ impl GetGatewayResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGatewayResponsesError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGatewayResponsesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGatewayResponsesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGatewayResponsesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6688,13 +6969,16 @@ impl GetIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetIntegrationError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6730,15 +7014,22 @@ impl GetIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6774,13 +7065,13 @@ impl GetMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetMethodError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetMethodError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetMethodError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6816,13 +7107,19 @@ impl GetMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetMethodResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetMethodResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMethodResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMethodResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6858,13 +7155,13 @@ impl GetModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetModelError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetModelError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6902,16 +7199,19 @@ impl GetModelTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetModelTemplateError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelTemplateError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetModelTemplateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetModelTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelTemplateError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetModelTemplateError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6950,16 +7250,16 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetModelsError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetModelsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetModelsError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetModelsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6996,13 +7296,19 @@ impl GetRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRequestValidatorError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRequestValidatorError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestValidatorError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestValidatorError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7040,18 +7346,25 @@ impl GetRequestValidatorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestValidatorsError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRequestValidatorsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestValidatorsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestValidatorsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7088,13 +7401,13 @@ impl GetResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetResourceError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7132,16 +7445,16 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourcesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourcesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7178,13 +7491,13 @@ impl GetRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRestApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRestApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetRestApiError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetRestApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7220,13 +7533,13 @@ impl GetRestApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRestApisError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetRestApisError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRestApisError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetRestApisError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRestApisError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetRestApisError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7266,15 +7579,19 @@ impl GetSdkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSdkError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetSdkError::BadRequest(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::service(&res, GetSdkError::Conflict(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetSdkError::NotFound(err.msg))
                 }
-                "ConflictException" => return RusotoError::Service(GetSdkError::Conflict(err.msg)),
-                "NotFoundException" => return RusotoError::Service(GetSdkError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetSdkError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetSdkError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7312,13 +7629,13 @@ impl GetSdkTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSdkTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSdkTypeError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetSdkTypeError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetSdkTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7352,10 +7669,10 @@ impl GetSdkTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkTypesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetSdkTypesError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkTypesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetSdkTypesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7390,13 +7707,13 @@ impl GetStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetStageError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStageError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetStageError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7432,13 +7749,13 @@ impl GetStagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStagesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetStagesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetStagesError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStagesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetStagesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7478,19 +7795,19 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetTagsError::BadRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetTagsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTagsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetTagsError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTagsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetTagsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7530,16 +7847,16 @@ impl GetUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUsageError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsageError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUsageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetUsageError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsageError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetUsageError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7578,16 +7895,16 @@ impl GetUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7626,16 +7943,19 @@ impl GetUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanKeyError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUsagePlanKeyError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7674,16 +7994,19 @@ impl GetUsagePlanKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanKeysError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanKeysError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUsagePlanKeysError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetUsagePlanKeysError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7724,19 +8047,19 @@ impl GetUsagePlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlansError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUsagePlansError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetUsagePlansError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetUsagePlansError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlansError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUsagePlansError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlansError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetUsagePlansError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlansError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetUsagePlansError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7774,13 +8097,13 @@ impl GetVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetVpcLinkError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetVpcLinkError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetVpcLinkError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7816,13 +8139,13 @@ impl GetVpcLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVpcLinksError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetVpcLinksError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinksError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetVpcLinksError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetVpcLinksError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetVpcLinksError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7864,22 +8187,22 @@ impl ImportApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ImportApiKeysError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportApiKeysError::Conflict(err.msg))
+                    return RusotoError::service(&res, ImportApiKeysError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportApiKeysError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ImportApiKeysError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportApiKeysError::NotFound(err.msg))
+                    return RusotoError::service(&res, ImportApiKeysError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportApiKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ImportApiKeysError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ImportApiKeysError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7922,25 +8245,34 @@ impl ImportDocumentationPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportDocumentationPartsError::BadRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportDocumentationPartsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportDocumentationPartsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportDocumentationPartsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportDocumentationPartsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7982,19 +8314,19 @@ impl ImportRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ImportRestApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportRestApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, ImportRestApiError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ImportRestApiError::LimitExceeded(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ImportRestApiError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ImportRestApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8036,19 +8368,28 @@ impl PutGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutGatewayResponseError::BadRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutGatewayResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutGatewayResponseError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutGatewayResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutGatewayResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutGatewayResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutGatewayResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8090,19 +8431,22 @@ impl PutIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutIntegrationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutIntegrationError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, PutIntegrationError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8146,26 +8490,40 @@ impl PutIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationResponseError::Conflict(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationResponseError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutIntegrationResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8210,22 +8568,22 @@ impl PutMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutMethodError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutMethodError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutMethodError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutMethodError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMethodError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutMethodError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutMethodError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, PutMethodError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, PutMethodError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8270,22 +8628,31 @@ impl PutMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutMethodResponseError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutMethodResponseError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMethodResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMethodResponseError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutMethodResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutMethodResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMethodResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMethodResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8330,22 +8697,22 @@ impl PutRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutRestApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutRestApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutRestApiError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutRestApiError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutRestApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutRestApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, PutRestApiError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, PutRestApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8390,22 +8757,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8446,18 +8813,25 @@ impl TestInvokeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, TestInvokeAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8496,16 +8870,19 @@ impl TestInvokeMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TestInvokeMethodError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TestInvokeMethodError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestInvokeMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, TestInvokeMethodError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TestInvokeMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeMethodError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, TestInvokeMethodError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8546,19 +8923,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8598,16 +8975,16 @@ impl UpdateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAccountError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8648,19 +9025,19 @@ impl UpdateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8700,16 +9077,19 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8750,21 +9130,34 @@ impl UpdateBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBasePathMappingError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBasePathMappingError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBasePathMappingError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBasePathMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBasePathMappingError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8804,20 +9197,28 @@ impl UpdateClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClientCertificateError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClientCertificateError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClientCertificateError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClientCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8858,19 +9259,25 @@ impl UpdateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDeploymentError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDeploymentError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateDeploymentError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8914,28 +9321,40 @@ impl UpdateDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationPartError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationPartError::Conflict(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationPartError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationPartError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationPartError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationPartError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8980,25 +9399,34 @@ impl UpdateDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationVersionError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationVersionError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentationVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9040,19 +9468,22 @@ impl UpdateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainNameError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9092,18 +9523,28 @@ impl UpdateGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayResponseError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9144,19 +9585,25 @@ impl UpdateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateIntegrationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateIntegrationError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9198,25 +9645,34 @@ impl UpdateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9258,19 +9714,19 @@ impl UpdateMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMethodError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateMethodError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMethodError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateMethodError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateMethodError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateMethodError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMethodError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateMethodError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9314,24 +9770,34 @@ impl UpdateMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMethodResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateMethodResponseError::Conflict(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMethodResponseError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateMethodResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMethodResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMethodResponseError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9374,19 +9840,19 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateModelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateModelError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateModelError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9426,18 +9892,28 @@ impl UpdateRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRequestValidatorError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRequestValidatorError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRequestValidatorError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRequestValidatorError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9478,19 +9954,22 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9532,19 +10011,19 @@ impl UpdateRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRestApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateRestApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRestApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateRestApiError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRestApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRestApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateRestApiError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateRestApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9586,19 +10065,19 @@ impl UpdateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateStageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateStageError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateStageError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9638,16 +10117,16 @@ impl UpdateUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUsageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateUsageError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUsageError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUsageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUsageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateUsageError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateUsageError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateUsageError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9688,19 +10167,22 @@ impl UpdateUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateUsagePlanError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateUsagePlanError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUsagePlanError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUsagePlanError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateUsagePlanError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9742,19 +10224,19 @@ impl UpdateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10486,6 +10968,7 @@ impl ApiGatewayClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -10501,6 +10984,7 @@ impl ApiGatewayClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
         ApiGatewayClient { client, region }
     }
@@ -10517,6 +11001,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateApiKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10550,6 +11037,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10584,6 +11074,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateBasePathMapping");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10618,6 +11111,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10651,6 +11147,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateDocumentationPart");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10684,6 +11183,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateDocumentationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10715,6 +11217,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateDomainName");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10749,6 +11254,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10782,6 +11290,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateRequestValidator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10817,6 +11328,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10848,6 +11362,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateRestApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10882,6 +11399,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateStage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10912,6 +11432,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateUsagePlan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10946,6 +11469,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateUsagePlanKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10977,6 +11503,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateVpcLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11008,6 +11537,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteApiKey");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11039,6 +11571,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11070,6 +11605,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteBasePathMapping");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11100,6 +11638,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteClientCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11131,6 +11672,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11161,6 +11705,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteDocumentationPart");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11191,6 +11738,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteDocumentationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11221,6 +11771,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteDomainName");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11252,6 +11805,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteGatewayResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11284,6 +11840,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteIntegration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11311,6 +11870,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteIntegrationResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11343,6 +11905,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteMethod");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11370,6 +11935,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteMethodResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11401,6 +11969,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteModel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11432,6 +12003,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteRequestValidator");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11463,6 +12037,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11490,6 +12067,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteRestApi");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11521,6 +12101,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteStage");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11551,6 +12134,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteUsagePlan");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11582,6 +12168,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteUsagePlanKey");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11609,6 +12198,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteVpcLink");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11640,6 +12232,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("FlushStageAuthorizersCache");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11671,6 +12266,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("FlushStageCache");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11698,6 +12296,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GenerateClientCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11726,6 +12327,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11754,6 +12358,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetApiKey");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11787,6 +12394,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetApiKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11837,6 +12447,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetAuthorizer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11868,6 +12481,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetAuthorizers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11909,6 +12525,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetBasePathMapping");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11940,6 +12559,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetBasePathMappings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11980,6 +12602,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetClientCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12008,6 +12633,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetClientCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12049,6 +12677,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDeployment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12088,6 +12719,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12128,6 +12762,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDocumentationPart");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12158,6 +12795,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDocumentationParts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12210,6 +12850,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDocumentationVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12240,6 +12883,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDocumentationVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12280,6 +12926,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDomainName");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12308,6 +12957,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDomainNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12350,6 +13002,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetExport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Accept", input.accepts.as_ref());
@@ -12395,6 +13050,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetGatewayResponse");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12426,6 +13084,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetGatewayResponses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12468,6 +13129,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetIntegration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12496,6 +13160,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetIntegrationResponse");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12529,6 +13196,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetMethod");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12556,6 +13226,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetMethodResponse");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12585,6 +13258,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetModel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12622,6 +13298,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetModelTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12653,6 +13332,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12693,6 +13375,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRequestValidator");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12724,6 +13409,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRequestValidators");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12765,6 +13453,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12804,6 +13495,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12846,6 +13540,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRestApi");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12874,6 +13571,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRestApis");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12913,6 +13613,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetSdk");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12952,6 +13655,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetSdkType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12979,6 +13685,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/sdktypes";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetSdkTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13017,6 +13726,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetStage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13047,6 +13759,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetStages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13077,6 +13792,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13113,6 +13831,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13157,6 +13878,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetUsagePlan");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13189,6 +13913,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetUsagePlanKey");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13220,6 +13947,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetUsagePlanKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13260,6 +13990,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetUsagePlans");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13300,6 +14033,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetVpcLink");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13328,6 +14064,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetVpcLinks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13365,6 +14104,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("ImportApiKeys");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13406,6 +14148,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("ImportDocumentationParts");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13446,6 +14191,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("ImportRestApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13493,6 +14241,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("PutGatewayResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13529,6 +14280,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("PutIntegration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13560,6 +14314,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("PutIntegrationResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13596,6 +14353,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("PutMethod");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13626,6 +14386,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("PutMethodResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13657,6 +14420,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("PutRestApi");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13702,6 +14468,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13736,6 +14505,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("TestInvokeAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13772,6 +14544,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("TestInvokeMethod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13803,6 +14578,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13836,6 +14614,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13867,6 +14648,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateApiKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13901,6 +14685,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13936,6 +14723,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateBasePathMapping");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13970,6 +14760,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateClientCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14005,6 +14798,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14039,6 +14835,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateDocumentationPart");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14073,6 +14872,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateDocumentationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14107,6 +14909,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateDomainName");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14142,6 +14947,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateGatewayResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14178,6 +14986,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateIntegration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14209,6 +15020,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateIntegrationResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14245,6 +15059,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateMethod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14275,6 +15092,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateMethodResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14310,6 +15130,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14344,6 +15167,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateRequestValidator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14379,6 +15205,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14410,6 +15239,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateRestApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14445,6 +15277,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateStage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14479,6 +15314,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateUsage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14512,6 +15350,9 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateUsagePlan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14543,6 +15384,9 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateVpcLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14565,3 +15409,1253 @@ impl ApiGateway for ApiGatewayClient {
         }
     }
 }
+
+/// A blocking client for the Amazon API Gateway API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ApiGatewayClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApiGatewayBlockingClient {
+    client: ApiGatewayClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApiGatewayBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ApiGatewayBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApiGatewayBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient {
+            client: ApiGatewayClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Create an <a>ApiKey</a> resource. </p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-api-key.html">AWS CLI</a></div></p>
+    ///
+    /// See `ApiGateway::create_api_key`.
+    pub fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<CreateApiKeyError>> {
+        self.runtime.block_on(self.client.create_api_key(input))
+    }
+
+    /// <p><p>Adds a new <a>Authorizer</a> resource to an existing <a>RestApi</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/create-authorizer.html">AWS CLI</a></div></p>
+    ///
+    /// See `ApiGateway::create_authorizer`.
+    pub fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<CreateAuthorizerError>> {
+        self.runtime.block_on(self.client.create_authorizer(input))
+    }
+
+    /// <p>Creates a new <a>BasePathMapping</a> resource.</p>
+    ///
+    /// See `ApiGateway::create_base_path_mapping`.
+    pub fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<CreateBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.create_base_path_mapping(input))
+    }
+
+    /// <p>Creates a <a>Deployment</a> resource, which makes a specified <a>RestApi</a> callable over the internet.</p>
+    ///
+    /// See `ApiGateway::create_deployment`.
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// See `ApiGateway::create_documentation_part`.
+    pub fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<CreateDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.create_documentation_part(input))
+    }
+
+    /// See `ApiGateway::create_documentation_version`.
+    pub fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<CreateDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.create_documentation_version(input))
+    }
+
+    /// <p>Creates a new domain name.</p>
+    ///
+    /// See `ApiGateway::create_domain_name`.
+    pub fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<CreateDomainNameError>> {
+        self.runtime.block_on(self.client.create_domain_name(input))
+    }
+
+    /// <p>Adds a new <a>Model</a> resource to an existing <a>RestApi</a> resource.</p>
+    ///
+    /// See `ApiGateway::create_model`.
+    pub fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<Model, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// <p>Creates a <a>ReqeustValidator</a> of a given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::create_request_validator`.
+    pub fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<CreateRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.create_request_validator(input))
+    }
+
+    /// <p>Creates a <a>Resource</a> resource.</p>
+    ///
+    /// See `ApiGateway::create_resource`.
+    pub fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> Result<Resource, RusotoError<CreateResourceError>> {
+        self.runtime.block_on(self.client.create_resource(input))
+    }
+
+    /// <p>Creates a new <a>RestApi</a> resource.</p>
+    ///
+    /// See `ApiGateway::create_rest_api`.
+    pub fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> Result<RestApi, RusotoError<CreateRestApiError>> {
+        self.runtime.block_on(self.client.create_rest_api(input))
+    }
+
+    /// <p>Creates a new <a>Stage</a> resource that references a pre-existing <a>Deployment</a> for the API. </p>
+    ///
+    /// See `ApiGateway::create_stage`.
+    pub fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<Stage, RusotoError<CreateStageError>> {
+        self.runtime.block_on(self.client.create_stage(input))
+    }
+
+    /// <p>Creates a usage plan with the throttle and quota limits, as well as the associated API stages, specified in the payload. </p>
+    ///
+    /// See `ApiGateway::create_usage_plan`.
+    pub fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<CreateUsagePlanError>> {
+        self.runtime.block_on(self.client.create_usage_plan(input))
+    }
+
+    /// <p>Creates a usage plan key for adding an existing API key to a usage plan.</p>
+    ///
+    /// See `ApiGateway::create_usage_plan_key`.
+    pub fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> Result<UsagePlanKey, RusotoError<CreateUsagePlanKeyError>> {
+        self.runtime
+            .block_on(self.client.create_usage_plan_key(input))
+    }
+
+    /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
+    ///
+    /// See `ApiGateway::create_vpc_link`.
+    pub fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<CreateVpcLinkError>> {
+        self.runtime.block_on(self.client.create_vpc_link(input))
+    }
+
+    /// <p>Deletes the <a>ApiKey</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_api_key`.
+    pub fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<(), RusotoError<DeleteApiKeyError>> {
+        self.runtime.block_on(self.client.delete_api_key(input))
+    }
+
+    /// <p><p>Deletes an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/delete-authorizer.html">AWS CLI</a></div></p>
+    ///
+    /// See `ApiGateway::delete_authorizer`.
+    pub fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+        self.runtime.block_on(self.client.delete_authorizer(input))
+    }
+
+    /// <p>Deletes the <a>BasePathMapping</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_base_path_mapping`.
+    pub fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> Result<(), RusotoError<DeleteBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.delete_base_path_mapping(input))
+    }
+
+    /// <p>Deletes the <a>ClientCertificate</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_client_certificate`.
+    pub fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_client_certificate(input))
+    }
+
+    /// <p>Deletes a <a>Deployment</a> resource. Deleting a deployment will only succeed if there are no <a>Stage</a> resources associated with it.</p>
+    ///
+    /// See `ApiGateway::delete_deployment`.
+    pub fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+        self.runtime.block_on(self.client.delete_deployment(input))
+    }
+
+    /// See `ApiGateway::delete_documentation_part`.
+    pub fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.delete_documentation_part(input))
+    }
+
+    /// See `ApiGateway::delete_documentation_version`.
+    pub fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_documentation_version(input))
+    }
+
+    /// <p>Deletes the <a>DomainName</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_domain_name`.
+    pub fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+        self.runtime.block_on(self.client.delete_domain_name(input))
+    }
+
+    /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
+    ///
+    /// See `ApiGateway::delete_gateway_response`.
+    pub fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> Result<(), RusotoError<DeleteGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_gateway_response(input))
+    }
+
+    /// <p>Represents a delete integration.</p>
+    ///
+    /// See `ApiGateway::delete_integration`.
+    pub fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+        self.runtime.block_on(self.client.delete_integration(input))
+    }
+
+    /// <p>Represents a delete integration response.</p>
+    ///
+    /// See `ApiGateway::delete_integration_response`.
+    pub fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_integration_response(input))
+    }
+
+    /// <p>Deletes an existing <a>Method</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_method`.
+    pub fn delete_method(
+        &self,
+        input: DeleteMethodRequest,
+    ) -> Result<(), RusotoError<DeleteMethodError>> {
+        self.runtime.block_on(self.client.delete_method(input))
+    }
+
+    /// <p>Deletes an existing <a>MethodResponse</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_method_response`.
+    pub fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> Result<(), RusotoError<DeleteMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_method_response(input))
+    }
+
+    /// <p>Deletes a model.</p>
+    ///
+    /// See `ApiGateway::delete_model`.
+    pub fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// <p>Deletes a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::delete_request_validator`.
+    pub fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> Result<(), RusotoError<DeleteRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.delete_request_validator(input))
+    }
+
+    /// <p>Deletes a <a>Resource</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_resource`.
+    pub fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> Result<(), RusotoError<DeleteResourceError>> {
+        self.runtime.block_on(self.client.delete_resource(input))
+    }
+
+    /// <p>Deletes the specified API.</p>
+    ///
+    /// See `ApiGateway::delete_rest_api`.
+    pub fn delete_rest_api(
+        &self,
+        input: DeleteRestApiRequest,
+    ) -> Result<(), RusotoError<DeleteRestApiError>> {
+        self.runtime.block_on(self.client.delete_rest_api(input))
+    }
+
+    /// <p>Deletes a <a>Stage</a> resource.</p>
+    ///
+    /// See `ApiGateway::delete_stage`.
+    pub fn delete_stage(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<(), RusotoError<DeleteStageError>> {
+        self.runtime.block_on(self.client.delete_stage(input))
+    }
+
+    /// <p>Deletes a usage plan of a given plan Id.</p>
+    ///
+    /// See `ApiGateway::delete_usage_plan`.
+    pub fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> Result<(), RusotoError<DeleteUsagePlanError>> {
+        self.runtime.block_on(self.client.delete_usage_plan(input))
+    }
+
+    /// <p>Deletes a usage plan key and remove the underlying API key from the associated usage plan.</p>
+    ///
+    /// See `ApiGateway::delete_usage_plan_key`.
+    pub fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> Result<(), RusotoError<DeleteUsagePlanKeyError>> {
+        self.runtime
+            .block_on(self.client.delete_usage_plan_key(input))
+    }
+
+    /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
+    ///
+    /// See `ApiGateway::delete_vpc_link`.
+    pub fn delete_vpc_link(
+        &self,
+        input: DeleteVpcLinkRequest,
+    ) -> Result<(), RusotoError<DeleteVpcLinkError>> {
+        self.runtime.block_on(self.client.delete_vpc_link(input))
+    }
+
+    /// <p>Flushes all authorizer cache entries on a stage.</p>
+    ///
+    /// See `ApiGateway::flush_stage_authorizers_cache`.
+    pub fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> Result<(), RusotoError<FlushStageAuthorizersCacheError>> {
+        self.runtime
+            .block_on(self.client.flush_stage_authorizers_cache(input))
+    }
+
+    /// <p>Flushes a stage's cache.</p>
+    ///
+    /// See `ApiGateway::flush_stage_cache`.
+    pub fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> Result<(), RusotoError<FlushStageCacheError>> {
+        self.runtime.block_on(self.client.flush_stage_cache(input))
+    }
+
+    /// <p>Generates a <a>ClientCertificate</a> resource.</p>
+    ///
+    /// See `ApiGateway::generate_client_certificate`.
+    pub fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<GenerateClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.generate_client_certificate(input))
+    }
+
+    /// <p>Gets information about the current <a>Account</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_account`.
+    pub fn get_account(&self) -> Result<Account, RusotoError<GetAccountError>> {
+        self.runtime.block_on(self.client.get_account())
+    }
+
+    /// <p>Gets information about the current <a>ApiKey</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_api_key`.
+    pub fn get_api_key(
+        &self,
+        input: GetApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<GetApiKeyError>> {
+        self.runtime.block_on(self.client.get_api_key(input))
+    }
+
+    /// <p>Gets information about the current <a>ApiKeys</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_api_keys`.
+    pub fn get_api_keys(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> Result<ApiKeys, RusotoError<GetApiKeysError>> {
+        self.runtime.block_on(self.client.get_api_keys(input))
+    }
+
+    /// <p><p>Describe an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizer.html">AWS CLI</a></div></p>
+    ///
+    /// See `ApiGateway::get_authorizer`.
+    pub fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<GetAuthorizerError>> {
+        self.runtime.block_on(self.client.get_authorizer(input))
+    }
+
+    /// <p><p>Describe an existing <a>Authorizers</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-authorizers.html">AWS CLI</a></div></p>
+    ///
+    /// See `ApiGateway::get_authorizers`.
+    pub fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<Authorizers, RusotoError<GetAuthorizersError>> {
+        self.runtime.block_on(self.client.get_authorizers(input))
+    }
+
+    /// <p>Describe a <a>BasePathMapping</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_base_path_mapping`.
+    pub fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<GetBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.get_base_path_mapping(input))
+    }
+
+    /// <p>Represents a collection of <a>BasePathMapping</a> resources.</p>
+    ///
+    /// See `ApiGateway::get_base_path_mappings`.
+    pub fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> Result<BasePathMappings, RusotoError<GetBasePathMappingsError>> {
+        self.runtime
+            .block_on(self.client.get_base_path_mappings(input))
+    }
+
+    /// <p>Gets information about the current <a>ClientCertificate</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_client_certificate`.
+    pub fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<GetClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.get_client_certificate(input))
+    }
+
+    /// <p>Gets a collection of <a>ClientCertificate</a> resources.</p>
+    ///
+    /// See `ApiGateway::get_client_certificates`.
+    pub fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> Result<ClientCertificates, RusotoError<GetClientCertificatesError>> {
+        self.runtime
+            .block_on(self.client.get_client_certificates(input))
+    }
+
+    /// <p>Gets information about a <a>Deployment</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_deployment`.
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// <p>Gets information about a <a>Deployments</a> collection.</p>
+    ///
+    /// See `ApiGateway::get_deployments`.
+    pub fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<Deployments, RusotoError<GetDeploymentsError>> {
+        self.runtime.block_on(self.client.get_deployments(input))
+    }
+
+    /// See `ApiGateway::get_documentation_part`.
+    pub fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<GetDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_part(input))
+    }
+
+    /// See `ApiGateway::get_documentation_parts`.
+    pub fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> Result<DocumentationParts, RusotoError<GetDocumentationPartsError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_parts(input))
+    }
+
+    /// See `ApiGateway::get_documentation_version`.
+    pub fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<GetDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_version(input))
+    }
+
+    /// See `ApiGateway::get_documentation_versions`.
+    pub fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> Result<DocumentationVersions, RusotoError<GetDocumentationVersionsError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_versions(input))
+    }
+
+    /// <p>Represents a domain name that is contained in a simpler, more intuitive URL that can be called.</p>
+    ///
+    /// See `ApiGateway::get_domain_name`.
+    pub fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<GetDomainNameError>> {
+        self.runtime.block_on(self.client.get_domain_name(input))
+    }
+
+    /// <p>Represents a collection of <a>DomainName</a> resources.</p>
+    ///
+    /// See `ApiGateway::get_domain_names`.
+    pub fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<DomainNames, RusotoError<GetDomainNamesError>> {
+        self.runtime.block_on(self.client.get_domain_names(input))
+    }
+
+    /// <p>Exports a deployed version of a <a>RestApi</a> in a specified format.</p>
+    ///
+    /// See `ApiGateway::get_export`.
+    pub fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<ExportResponse, RusotoError<GetExportError>> {
+        self.runtime.block_on(self.client.get_export(input))
+    }
+
+    /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::get_gateway_response`.
+    pub fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<GetGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.get_gateway_response(input))
+    }
+
+    /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
+    ///
+    /// See `ApiGateway::get_gateway_responses`.
+    pub fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> Result<GatewayResponses, RusotoError<GetGatewayResponsesError>> {
+        self.runtime
+            .block_on(self.client.get_gateway_responses(input))
+    }
+
+    /// <p>Get the integration settings.</p>
+    ///
+    /// See `ApiGateway::get_integration`.
+    pub fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<Integration, RusotoError<GetIntegrationError>> {
+        self.runtime.block_on(self.client.get_integration(input))
+    }
+
+    /// <p>Represents a get integration response.</p>
+    ///
+    /// See `ApiGateway::get_integration_response`.
+    pub fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<GetIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.get_integration_response(input))
+    }
+
+    /// <p>Describe an existing <a>Method</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_method`.
+    pub fn get_method(
+        &self,
+        input: GetMethodRequest,
+    ) -> Result<Method, RusotoError<GetMethodError>> {
+        self.runtime.block_on(self.client.get_method(input))
+    }
+
+    /// <p>Describes a <a>MethodResponse</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_method_response`.
+    pub fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<GetMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.get_method_response(input))
+    }
+
+    /// <p>Describes an existing model defined for a <a>RestApi</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_model`.
+    pub fn get_model(&self, input: GetModelRequest) -> Result<Model, RusotoError<GetModelError>> {
+        self.runtime.block_on(self.client.get_model(input))
+    }
+
+    /// <p>Generates a sample mapping template that can be used to transform a payload into the structure of a model.</p>
+    ///
+    /// See `ApiGateway::get_model_template`.
+    pub fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<Template, RusotoError<GetModelTemplateError>> {
+        self.runtime.block_on(self.client.get_model_template(input))
+    }
+
+    /// <p>Describes existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_models`.
+    pub fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<Models, RusotoError<GetModelsError>> {
+        self.runtime.block_on(self.client.get_models(input))
+    }
+
+    /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::get_request_validator`.
+    pub fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<GetRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.get_request_validator(input))
+    }
+
+    /// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::get_request_validators`.
+    pub fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> Result<RequestValidators, RusotoError<GetRequestValidatorsError>> {
+        self.runtime
+            .block_on(self.client.get_request_validators(input))
+    }
+
+    /// <p>Lists information about a resource.</p>
+    ///
+    /// See `ApiGateway::get_resource`.
+    pub fn get_resource(
+        &self,
+        input: GetResourceRequest,
+    ) -> Result<Resource, RusotoError<GetResourceError>> {
+        self.runtime.block_on(self.client.get_resource(input))
+    }
+
+    /// <p>Lists information about a collection of <a>Resource</a> resources.</p>
+    ///
+    /// See `ApiGateway::get_resources`.
+    pub fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> Result<Resources, RusotoError<GetResourcesError>> {
+        self.runtime.block_on(self.client.get_resources(input))
+    }
+
+    /// <p>Lists the <a>RestApi</a> resource in the collection.</p>
+    ///
+    /// See `ApiGateway::get_rest_api`.
+    pub fn get_rest_api(
+        &self,
+        input: GetRestApiRequest,
+    ) -> Result<RestApi, RusotoError<GetRestApiError>> {
+        self.runtime.block_on(self.client.get_rest_api(input))
+    }
+
+    /// <p>Lists the <a>RestApis</a> resources for your collection.</p>
+    ///
+    /// See `ApiGateway::get_rest_apis`.
+    pub fn get_rest_apis(
+        &self,
+        input: GetRestApisRequest,
+    ) -> Result<RestApis, RusotoError<GetRestApisError>> {
+        self.runtime.block_on(self.client.get_rest_apis(input))
+    }
+
+    /// <p>Generates a client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
+    ///
+    /// See `ApiGateway::get_sdk`.
+    pub fn get_sdk(&self, input: GetSdkRequest) -> Result<SdkResponse, RusotoError<GetSdkError>> {
+        self.runtime.block_on(self.client.get_sdk(input))
+    }
+
+    /// See `ApiGateway::get_sdk_type`.
+    pub fn get_sdk_type(
+        &self,
+        input: GetSdkTypeRequest,
+    ) -> Result<SdkType, RusotoError<GetSdkTypeError>> {
+        self.runtime.block_on(self.client.get_sdk_type(input))
+    }
+
+    /// See `ApiGateway::get_sdk_types`.
+    pub fn get_sdk_types(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> Result<SdkTypes, RusotoError<GetSdkTypesError>> {
+        self.runtime.block_on(self.client.get_sdk_types(input))
+    }
+
+    /// <p>Gets information about a <a>Stage</a> resource.</p>
+    ///
+    /// See `ApiGateway::get_stage`.
+    pub fn get_stage(&self, input: GetStageRequest) -> Result<Stage, RusotoError<GetStageError>> {
+        self.runtime.block_on(self.client.get_stage(input))
+    }
+
+    /// <p>Gets information about one or more <a>Stage</a> resources.</p>
+    ///
+    /// See `ApiGateway::get_stages`.
+    pub fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<Stages, RusotoError<GetStagesError>> {
+        self.runtime.block_on(self.client.get_stages(input))
+    }
+
+    /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
+    ///
+    /// See `ApiGateway::get_tags`.
+    pub fn get_tags(&self, input: GetTagsRequest) -> Result<Tags, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// <p>Gets the usage data of a usage plan in a specified time interval.</p>
+    ///
+    /// See `ApiGateway::get_usage`.
+    pub fn get_usage(&self, input: GetUsageRequest) -> Result<Usage, RusotoError<GetUsageError>> {
+        self.runtime.block_on(self.client.get_usage(input))
+    }
+
+    /// <p>Gets a usage plan of a given plan identifier.</p>
+    ///
+    /// See `ApiGateway::get_usage_plan`.
+    pub fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<GetUsagePlanError>> {
+        self.runtime.block_on(self.client.get_usage_plan(input))
+    }
+
+    /// <p>Gets a usage plan key of a given key identifier.</p>
+    ///
+    /// See `ApiGateway::get_usage_plan_key`.
+    pub fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> Result<UsagePlanKey, RusotoError<GetUsagePlanKeyError>> {
+        self.runtime.block_on(self.client.get_usage_plan_key(input))
+    }
+
+    /// <p>Gets all the usage plan keys representing the API keys added to a specified usage plan.</p>
+    ///
+    /// See `ApiGateway::get_usage_plan_keys`.
+    pub fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>> {
+        self.runtime
+            .block_on(self.client.get_usage_plan_keys(input))
+    }
+
+    /// <p>Gets all the usage plans of the caller's account.</p>
+    ///
+    /// See `ApiGateway::get_usage_plans`.
+    pub fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> Result<UsagePlans, RusotoError<GetUsagePlansError>> {
+        self.runtime.block_on(self.client.get_usage_plans(input))
+    }
+
+    /// <p>Gets a specified VPC link under the caller's account in a region.</p>
+    ///
+    /// See `ApiGateway::get_vpc_link`.
+    pub fn get_vpc_link(
+        &self,
+        input: GetVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<GetVpcLinkError>> {
+        self.runtime.block_on(self.client.get_vpc_link(input))
+    }
+
+    /// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
+    ///
+    /// See `ApiGateway::get_vpc_links`.
+    pub fn get_vpc_links(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> Result<VpcLinks, RusotoError<GetVpcLinksError>> {
+        self.runtime.block_on(self.client.get_vpc_links(input))
+    }
+
+    /// <p>Import API keys from an external source, such as a CSV-formatted file.</p>
+    ///
+    /// See `ApiGateway::import_api_keys`.
+    pub fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> Result<ApiKeyIds, RusotoError<ImportApiKeysError>> {
+        self.runtime.block_on(self.client.import_api_keys(input))
+    }
+
+    /// See `ApiGateway::import_documentation_parts`.
+    pub fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> Result<DocumentationPartIds, RusotoError<ImportDocumentationPartsError>> {
+        self.runtime
+            .block_on(self.client.import_documentation_parts(input))
+    }
+
+    /// <p>A feature of the API Gateway control service for creating a new API from an external API definition file.</p>
+    ///
+    /// See `ApiGateway::import_rest_api`.
+    pub fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> Result<RestApi, RusotoError<ImportRestApiError>> {
+        self.runtime.block_on(self.client.import_rest_api(input))
+    }
+
+    /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::put_gateway_response`.
+    pub fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<PutGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.put_gateway_response(input))
+    }
+
+    /// <p>Sets up a method's integration.</p>
+    ///
+    /// See `ApiGateway::put_integration`.
+    pub fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> Result<Integration, RusotoError<PutIntegrationError>> {
+        self.runtime.block_on(self.client.put_integration(input))
+    }
+
+    /// <p>Represents a put integration.</p>
+    ///
+    /// See `ApiGateway::put_integration_response`.
+    pub fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<PutIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.put_integration_response(input))
+    }
+
+    /// <p>Add a method to an existing <a>Resource</a> resource.</p>
+    ///
+    /// See `ApiGateway::put_method`.
+    pub fn put_method(
+        &self,
+        input: PutMethodRequest,
+    ) -> Result<Method, RusotoError<PutMethodError>> {
+        self.runtime.block_on(self.client.put_method(input))
+    }
+
+    /// <p>Adds a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
+    ///
+    /// See `ApiGateway::put_method_response`.
+    pub fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<PutMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.put_method_response(input))
+    }
+
+    /// <p>A feature of the API Gateway control service for updating an existing API with an input of external API definitions. The update can take the form of merging the supplied definition into the existing API or overwriting the existing API.</p>
+    ///
+    /// See `ApiGateway::put_rest_api`.
+    pub fn put_rest_api(
+        &self,
+        input: PutRestApiRequest,
+    ) -> Result<RestApi, RusotoError<PutRestApiError>> {
+        self.runtime.block_on(self.client.put_rest_api(input))
+    }
+
+    /// <p>Adds or updates a tag on a given resource.</p>
+    ///
+    /// See `ApiGateway::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p><p>Simulate the execution of an <a>Authorizer</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
+    ///
+    /// See `ApiGateway::test_invoke_authorizer`.
+    pub fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> Result<TestInvokeAuthorizerResponse, RusotoError<TestInvokeAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.test_invoke_authorizer(input))
+    }
+
+    /// <p>Simulate the execution of a <a>Method</a> in your <a>RestApi</a> with headers, parameters, and an incoming request body.</p>
+    ///
+    /// See `ApiGateway::test_invoke_method`.
+    pub fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> Result<TestInvokeMethodResponse, RusotoError<TestInvokeMethodError>> {
+        self.runtime.block_on(self.client.test_invoke_method(input))
+    }
+
+    /// <p>Removes a tag from a given resource.</p>
+    ///
+    /// See `ApiGateway::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Changes information about the current <a>Account</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_account`.
+    pub fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<Account, RusotoError<UpdateAccountError>> {
+        self.runtime.block_on(self.client.update_account(input))
+    }
+
+    /// <p>Changes information about an <a>ApiKey</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_api_key`.
+    pub fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<UpdateApiKeyError>> {
+        self.runtime.block_on(self.client.update_api_key(input))
+    }
+
+    /// <p><p>Updates an existing <a>Authorizer</a> resource.</p> <div class="seeAlso"><a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/update-authorizer.html">AWS CLI</a></div></p>
+    ///
+    /// See `ApiGateway::update_authorizer`.
+    pub fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<UpdateAuthorizerError>> {
+        self.runtime.block_on(self.client.update_authorizer(input))
+    }
+
+    /// <p>Changes information about the <a>BasePathMapping</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_base_path_mapping`.
+    pub fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<UpdateBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.update_base_path_mapping(input))
+    }
+
+    /// <p>Changes information about an <a>ClientCertificate</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_client_certificate`.
+    pub fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<UpdateClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.update_client_certificate(input))
+    }
+
+    /// <p>Changes information about a <a>Deployment</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_deployment`.
+    pub fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<UpdateDeploymentError>> {
+        self.runtime.block_on(self.client.update_deployment(input))
+    }
+
+    /// See `ApiGateway::update_documentation_part`.
+    pub fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<UpdateDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.update_documentation_part(input))
+    }
+
+    /// See `ApiGateway::update_documentation_version`.
+    pub fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<UpdateDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.update_documentation_version(input))
+    }
+
+    /// <p>Changes information about the <a>DomainName</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_domain_name`.
+    pub fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<UpdateDomainNameError>> {
+        self.runtime.block_on(self.client.update_domain_name(input))
+    }
+
+    /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::update_gateway_response`.
+    pub fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<UpdateGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_response(input))
+    }
+
+    /// <p>Represents an update integration.</p>
+    ///
+    /// See `ApiGateway::update_integration`.
+    pub fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<Integration, RusotoError<UpdateIntegrationError>> {
+        self.runtime.block_on(self.client.update_integration(input))
+    }
+
+    /// <p>Represents an update integration response.</p>
+    ///
+    /// See `ApiGateway::update_integration_response`.
+    pub fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<UpdateIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.update_integration_response(input))
+    }
+
+    /// <p>Updates an existing <a>Method</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_method`.
+    pub fn update_method(
+        &self,
+        input: UpdateMethodRequest,
+    ) -> Result<Method, RusotoError<UpdateMethodError>> {
+        self.runtime.block_on(self.client.update_method(input))
+    }
+
+    /// <p>Updates an existing <a>MethodResponse</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_method_response`.
+    pub fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<UpdateMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.update_method_response(input))
+    }
+
+    /// <p>Changes information about a model.</p>
+    ///
+    /// See `ApiGateway::update_model`.
+    pub fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<Model, RusotoError<UpdateModelError>> {
+        self.runtime.block_on(self.client.update_model(input))
+    }
+
+    /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
+    ///
+    /// See `ApiGateway::update_request_validator`.
+    pub fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<UpdateRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.update_request_validator(input))
+    }
+
+    /// <p>Changes information about a <a>Resource</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_resource`.
+    pub fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<Resource, RusotoError<UpdateResourceError>> {
+        self.runtime.block_on(self.client.update_resource(input))
+    }
+
+    /// <p>Changes information about the specified API.</p>
+    ///
+    /// See `ApiGateway::update_rest_api`.
+    pub fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> Result<RestApi, RusotoError<UpdateRestApiError>> {
+        self.runtime.block_on(self.client.update_rest_api(input))
+    }
+
+    /// <p>Changes information about a <a>Stage</a> resource.</p>
+    ///
+    /// See `ApiGateway::update_stage`.
+    pub fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<Stage, RusotoError<UpdateStageError>> {
+        self.runtime.block_on(self.client.update_stage(input))
+    }
+
+    /// <p>Grants a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
+    ///
+    /// See `ApiGateway::update_usage`.
+    pub fn update_usage(
+        &self,
+        input: UpdateUsageRequest,
+    ) -> Result<Usage, RusotoError<UpdateUsageError>> {
+        self.runtime.block_on(self.client.update_usage(input))
+    }
+
+    /// <p>Updates a usage plan of a given plan Id.</p>
+    ///
+    /// See `ApiGateway::update_usage_plan`.
+    pub fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<UpdateUsagePlanError>> {
+        self.runtime.block_on(self.client.update_usage_plan(input))
+    }
+
+    /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
+    ///
+    /// See `ApiGateway::update_vpc_link`.
+    pub fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<UpdateVpcLinkError>> {
+        self.runtime.block_on(self.client.update_vpc_link(input))
+    }
+}
@@ -96,13 +96,16 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteConnectionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteConnectionError::Forbidden(err.msg))
                 }
                 "GoneException" => {
-                    return RusotoError::Service(DeleteConnectionError::Gone(err.msg))
+                    return RusotoError::service(&res, DeleteConnectionError::Gone(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -138,11 +141,13 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetConnectionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetConnectionError::Forbidden(err.msg))
+                }
+                "GoneException" => {
+                    return RusotoError::service(&res, GetConnectionError::Gone(err.msg))
                 }
-                "GoneException" => return RusotoError::Service(GetConnectionError::Gone(err.msg)),
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetConnectionError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -180,16 +185,22 @@ impl PostToConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(PostToConnectionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, PostToConnectionError::Forbidden(err.msg))
                 }
                 "GoneException" => {
-                    return RusotoError::Service(PostToConnectionError::Gone(err.msg))
+                    return RusotoError::service(&res, PostToConnectionError::Gone(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostToConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostToConnectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "PayloadTooLargeException" => {
-                    return RusotoError::Service(PostToConnectionError::PayloadTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostToConnectionError::PayloadTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -249,6 +260,7 @@ impl ApiGatewayManagementApiClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -264,6 +276,7 @@ impl ApiGatewayManagementApiClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(
         client: Client,
         region: region::Region,
@@ -286,6 +299,9 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteConnection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -316,6 +332,9 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetConnection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -347,6 +366,9 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("PostToConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.data.to_owned());
@@ -368,3 +390,90 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         }
     }
 }
+
+/// A blocking client for the AmazonApiGatewayManagementApi API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ApiGatewayManagementApiClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApiGatewayManagementApiBlockingClient {
+    client: ApiGatewayManagementApiClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApiGatewayManagementApiBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayManagementApiBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ApiGatewayManagementApiBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayManagementApiBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApiGatewayManagementApiBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient {
+            client: ApiGatewayManagementApiClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Delete the connection with the provided id.</p>
+    ///
+    /// See `ApiGatewayManagementApi::delete_connection`.
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<(), RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// <p>Get information about the connection with the provided id.</p>
+    ///
+    /// See `ApiGatewayManagementApi::get_connection`.
+    pub fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        self.runtime.block_on(self.client.get_connection(input))
+    }
+
+    /// <p>Sends the provided data to the specified connection.</p>
+    ///
+    /// See `ApiGatewayManagementApi::post_to_connection`.
+    pub fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> Result<(), RusotoError<PostToConnectionError>> {
+        self.runtime.block_on(self.client.post_to_connection(input))
+    }
+}
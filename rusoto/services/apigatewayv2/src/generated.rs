@@ -3929,16 +3929,16 @@ impl CreateApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateApiError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3977,16 +3977,19 @@ impl CreateApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApiMappingError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiMappingError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateApiMappingError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateApiMappingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApiMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4025,16 +4028,19 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4073,16 +4079,19 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4122,19 +4131,22 @@ impl CreateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainNameError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::AccessDenied(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDomainNameError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainNameError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4174,16 +4186,19 @@ impl CreateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateIntegrationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateIntegrationError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4222,20 +4237,28 @@ impl CreateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntegrationResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntegrationResponseError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4274,16 +4297,16 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateModelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateModelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateModelError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateModelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4322,16 +4345,16 @@ impl CreateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4370,16 +4393,22 @@ impl CreateRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRouteResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteResponseError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateRouteResponseError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateRouteResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRouteResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4418,16 +4447,16 @@ impl CreateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateStageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateStageError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateStageError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateStageError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateStageError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4462,10 +4491,10 @@ impl CreateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateVpcLinkError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateVpcLinkError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4498,12 +4527,16 @@ impl DeleteAccessLogSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAccessLogSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccessLogSettingsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAccessLogSettingsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccessLogSettingsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4536,10 +4569,10 @@ impl DeleteApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4574,13 +4607,16 @@ impl DeleteApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteApiMappingError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteApiMappingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApiMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4614,10 +4650,13 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4650,12 +4689,16 @@ impl DeleteCorsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteCorsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCorsConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteCorsConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCorsConfigurationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4688,10 +4731,13 @@ impl DeleteDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDeploymentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4724,10 +4770,13 @@ impl DeleteDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDomainNameError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainNameError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4760,10 +4809,13 @@ impl DeleteIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4796,12 +4848,16 @@ impl DeleteIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4834,10 +4890,10 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteModelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4870,10 +4926,10 @@ impl DeleteRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4908,14 +4964,16 @@ impl DeleteRouteRequestParameterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteRequestParameterError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRouteRequestParameterError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteRequestParameterError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRouteRequestParameterError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4948,10 +5006,13 @@ impl DeleteRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRouteResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRouteResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4984,10 +5045,13 @@ impl DeleteRouteSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRouteSettingsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteSettingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRouteSettingsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5020,10 +5084,10 @@ impl DeleteStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteStageError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5056,10 +5120,10 @@ impl DeleteVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVpcLinkError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteVpcLinkError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5094,13 +5158,13 @@ impl ExportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExportApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ExportApiError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, ExportApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ExportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ExportApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5133,9 +5197,11 @@ impl GetApiError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetApiError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "NotFoundException" => return RusotoError::Service(GetApiError::NotFound(err.msg)),
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetApiError::NotFound(err.msg))
+                }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5170,13 +5236,13 @@ impl GetApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApiMappingError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApiMappingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetApiMappingError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5212,13 +5278,16 @@ impl GetApiMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiMappingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApiMappingsError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiMappingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApiMappingsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiMappingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApiMappingsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5254,13 +5323,13 @@ impl GetApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApisError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApisError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApisError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApisError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApisError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetApisError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5294,10 +5363,10 @@ impl GetAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetAuthorizerError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5332,13 +5401,16 @@ impl GetAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAuthorizersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAuthorizersError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizersError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAuthorizersError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAuthorizersError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5372,10 +5444,10 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5410,13 +5482,16 @@ impl GetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDeploymentsError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDeploymentsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5450,10 +5525,10 @@ impl GetDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDomainNameError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetDomainNameError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5488,13 +5563,16 @@ impl GetDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainNamesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDomainNamesError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNamesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDomainNamesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainNamesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5528,10 +5606,13 @@ impl GetIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5564,12 +5645,16 @@ impl GetIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5604,15 +5689,22 @@ impl GetIntegrationResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponsesError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponsesError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationResponsesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5648,13 +5740,16 @@ impl GetIntegrationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntegrationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetIntegrationsError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetIntegrationsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntegrationsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5688,10 +5783,10 @@ impl GetModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetModelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5724,10 +5819,13 @@ impl GetModelTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelTemplateError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetModelTemplateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetModelTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5762,13 +5860,13 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetModelsError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetModelsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetModelsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5802,10 +5900,10 @@ impl GetRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRouteError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5838,10 +5936,13 @@ impl GetRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRouteResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRouteResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5876,13 +5977,16 @@ impl GetRouteResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRouteResponsesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetRouteResponsesError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteResponsesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRouteResponsesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteResponsesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRouteResponsesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5918,13 +6022,13 @@ impl GetRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRoutesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetRoutesError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoutesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRoutesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetRoutesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5958,10 +6062,10 @@ impl GetStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetStageError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5996,13 +6100,13 @@ impl GetStagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetStagesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetStagesError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStagesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetStagesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetStagesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6040,16 +6144,16 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetTagsError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetTagsError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetTagsError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTagsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetTagsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6084,10 +6188,10 @@ impl GetVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetVpcLinkError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetVpcLinkError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6120,10 +6224,10 @@ impl GetVpcLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVpcLinksError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetVpcLinksError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinksError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetVpcLinksError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6160,16 +6264,16 @@ impl ImportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ImportApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, ImportApiError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, ImportApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ImportApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6208,16 +6312,16 @@ impl ReimportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ReimportApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ReimportApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ReimportApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, ReimportApiError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReimportApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, ReimportApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ReimportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ReimportApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6256,16 +6360,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6304,16 +6408,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6352,16 +6456,16 @@ impl UpdateApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApiError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateApiError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateApiError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateApiError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6400,16 +6504,19 @@ impl UpdateApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApiMappingError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiMappingError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateApiMappingError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiMappingError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateApiMappingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApiMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6448,16 +6555,19 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6496,16 +6606,19 @@ impl UpdateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDeploymentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDeploymentError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDeploymentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6544,16 +6657,19 @@ impl UpdateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDomainNameError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainNameError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6592,16 +6708,19 @@ impl UpdateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateIntegrationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateIntegrationError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateIntegrationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6640,20 +6759,28 @@ impl UpdateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::Conflict(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIntegrationResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6692,16 +6819,16 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateModelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateModelError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateModelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6740,16 +6867,16 @@ impl UpdateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6788,16 +6915,22 @@ impl UpdateRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRouteResponseError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateRouteResponseError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRouteResponseError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRouteResponseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6836,16 +6969,16 @@ impl UpdateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateStageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateStageError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::Conflict(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateStageError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateStageError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6882,13 +7015,13 @@ impl UpdateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateVpcLinkError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7351,6 +7484,7 @@ impl ApiGatewayV2Client {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7366,6 +7500,7 @@ impl ApiGatewayV2Client {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
         ApiGatewayV2Client { client, region }
     }
@@ -7382,6 +7517,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7416,6 +7554,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateApiMapping");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7447,6 +7588,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7478,6 +7622,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7509,6 +7656,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateDomainName");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7540,6 +7690,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateIntegration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7576,6 +7729,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateIntegrationResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7607,6 +7763,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7638,6 +7797,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7673,6 +7835,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateRouteResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7704,6 +7869,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateStage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7735,6 +7903,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("CreateVpcLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7770,6 +7941,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteAccessLogSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7794,6 +7968,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteApi");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7825,6 +8002,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteApiMapping");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7856,6 +8036,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7883,6 +8066,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/cors", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteCorsConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7914,6 +8100,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7944,6 +8133,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteDomainName");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7975,6 +8167,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteIntegration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8002,6 +8197,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteIntegrationResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8033,6 +8231,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteModel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8064,6 +8265,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8096,6 +8300,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteRouteRequestParameter");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8128,6 +8335,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteRouteResponse");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8160,6 +8370,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteRouteSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8191,6 +8404,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteStage");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8221,6 +8437,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("DeleteVpcLink");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8252,6 +8471,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("ExportApi");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8294,6 +8516,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetApi");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8326,6 +8551,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetApiMapping");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8357,6 +8585,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetApiMappings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8394,6 +8625,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetApis");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8435,6 +8669,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetAuthorizer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8463,6 +8700,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetAuthorizers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8504,6 +8744,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDeployment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8532,6 +8775,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8572,6 +8818,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDomainName");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8600,6 +8849,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetDomainNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8641,6 +8893,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetIntegration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8669,6 +8924,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetIntegrationResponse");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8701,6 +8959,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetIntegrationResponses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8738,6 +8999,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetIntegrations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8779,6 +9043,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetModel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8811,6 +9078,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetModelTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8839,6 +9109,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8880,6 +9153,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRoute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8913,6 +9189,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRouteResponse");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8945,6 +9224,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRouteResponses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8982,6 +9264,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9023,6 +9308,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetStage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9051,6 +9339,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetStages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9088,6 +9379,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9119,6 +9413,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetVpcLink");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9147,6 +9444,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("GetVpcLinks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9184,6 +9484,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("ImportApi");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9224,6 +9527,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("ReimportApi");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9264,6 +9570,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9295,6 +9604,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9328,6 +9640,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9363,6 +9678,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateApiMapping");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9398,6 +9716,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9433,6 +9754,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9467,6 +9791,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateDomainName");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9502,6 +9829,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateIntegration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9534,6 +9864,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateIntegrationResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9569,6 +9902,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9604,6 +9940,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9640,6 +9979,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateRouteResponse");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9675,6 +10017,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateStage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9709,6 +10054,9 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation_name("UpdateVpcLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9731,3 +10079,773 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
     }
 }
+
+/// A blocking client for the AmazonApiGatewayV2 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ApiGatewayV2Client` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApiGatewayV2BlockingClient {
+    client: ApiGatewayV2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApiGatewayV2BlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayV2BlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ApiGatewayV2BlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayV2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApiGatewayV2BlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient {
+            client: ApiGatewayV2Client::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an Api resource.</p>
+    ///
+    /// See `ApiGatewayV2::create_api`.
+    pub fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> Result<CreateApiResponse, RusotoError<CreateApiError>> {
+        self.runtime.block_on(self.client.create_api(input))
+    }
+
+    /// <p>Creates an API mapping.</p>
+    ///
+    /// See `ApiGatewayV2::create_api_mapping`.
+    pub fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> Result<CreateApiMappingResponse, RusotoError<CreateApiMappingError>> {
+        self.runtime.block_on(self.client.create_api_mapping(input))
+    }
+
+    /// <p>Creates an Authorizer for an API.</p>
+    ///
+    /// See `ApiGatewayV2::create_authorizer`.
+    pub fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<CreateAuthorizerResponse, RusotoError<CreateAuthorizerError>> {
+        self.runtime.block_on(self.client.create_authorizer(input))
+    }
+
+    /// <p>Creates a Deployment for an API.</p>
+    ///
+    /// See `ApiGatewayV2::create_deployment`.
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// <p>Creates a domain name.</p>
+    ///
+    /// See `ApiGatewayV2::create_domain_name`.
+    pub fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<CreateDomainNameResponse, RusotoError<CreateDomainNameError>> {
+        self.runtime.block_on(self.client.create_domain_name(input))
+    }
+
+    /// <p>Creates an Integration.</p>
+    ///
+    /// See `ApiGatewayV2::create_integration`.
+    pub fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> Result<CreateIntegrationResult, RusotoError<CreateIntegrationError>> {
+        self.runtime.block_on(self.client.create_integration(input))
+    }
+
+    /// <p>Creates an IntegrationResponses.</p>
+    ///
+    /// See `ApiGatewayV2::create_integration_response`.
+    pub fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> Result<CreateIntegrationResponseResponse, RusotoError<CreateIntegrationResponseError>>
+    {
+        self.runtime
+            .block_on(self.client.create_integration_response(input))
+    }
+
+    /// <p>Creates a Model for an API.</p>
+    ///
+    /// See `ApiGatewayV2::create_model`.
+    pub fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<CreateModelResponse, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// <p>Creates a Route for an API.</p>
+    ///
+    /// See `ApiGatewayV2::create_route`.
+    pub fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
+        self.runtime.block_on(self.client.create_route(input))
+    }
+
+    /// <p>Creates a RouteResponse for a Route.</p>
+    ///
+    /// See `ApiGatewayV2::create_route_response`.
+    pub fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> Result<CreateRouteResponseResponse, RusotoError<CreateRouteResponseError>> {
+        self.runtime
+            .block_on(self.client.create_route_response(input))
+    }
+
+    /// <p>Creates a Stage for an API.</p>
+    ///
+    /// See `ApiGatewayV2::create_stage`.
+    pub fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<CreateStageResponse, RusotoError<CreateStageError>> {
+        self.runtime.block_on(self.client.create_stage(input))
+    }
+
+    /// <p>Creates a VPC link.</p>
+    ///
+    /// See `ApiGatewayV2::create_vpc_link`.
+    pub fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> Result<CreateVpcLinkResponse, RusotoError<CreateVpcLinkError>> {
+        self.runtime.block_on(self.client.create_vpc_link(input))
+    }
+
+    /// <p>Deletes the AccessLogSettings for a Stage. To disable access logging for a Stage, delete its AccessLogSettings.</p>
+    ///
+    /// See `ApiGatewayV2::delete_access_log_settings`.
+    pub fn delete_access_log_settings(
+        &self,
+        input: DeleteAccessLogSettingsRequest,
+    ) -> Result<(), RusotoError<DeleteAccessLogSettingsError>> {
+        self.runtime
+            .block_on(self.client.delete_access_log_settings(input))
+    }
+
+    /// <p>Deletes an Api resource.</p>
+    ///
+    /// See `ApiGatewayV2::delete_api`.
+    pub fn delete_api(&self, input: DeleteApiRequest) -> Result<(), RusotoError<DeleteApiError>> {
+        self.runtime.block_on(self.client.delete_api(input))
+    }
+
+    /// <p>Deletes an API mapping.</p>
+    ///
+    /// See `ApiGatewayV2::delete_api_mapping`.
+    pub fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> Result<(), RusotoError<DeleteApiMappingError>> {
+        self.runtime.block_on(self.client.delete_api_mapping(input))
+    }
+
+    /// <p>Deletes an Authorizer.</p>
+    ///
+    /// See `ApiGatewayV2::delete_authorizer`.
+    pub fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+        self.runtime.block_on(self.client.delete_authorizer(input))
+    }
+
+    /// <p>Deletes a CORS configuration.</p>
+    ///
+    /// See `ApiGatewayV2::delete_cors_configuration`.
+    pub fn delete_cors_configuration(
+        &self,
+        input: DeleteCorsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteCorsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_cors_configuration(input))
+    }
+
+    /// <p>Deletes a Deployment.</p>
+    ///
+    /// See `ApiGatewayV2::delete_deployment`.
+    pub fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+        self.runtime.block_on(self.client.delete_deployment(input))
+    }
+
+    /// <p>Deletes a domain name.</p>
+    ///
+    /// See `ApiGatewayV2::delete_domain_name`.
+    pub fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+        self.runtime.block_on(self.client.delete_domain_name(input))
+    }
+
+    /// <p>Deletes an Integration.</p>
+    ///
+    /// See `ApiGatewayV2::delete_integration`.
+    pub fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+        self.runtime.block_on(self.client.delete_integration(input))
+    }
+
+    /// <p>Deletes an IntegrationResponses.</p>
+    ///
+    /// See `ApiGatewayV2::delete_integration_response`.
+    pub fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_integration_response(input))
+    }
+
+    /// <p>Deletes a Model.</p>
+    ///
+    /// See `ApiGatewayV2::delete_model`.
+    pub fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// <p>Deletes a Route.</p>
+    ///
+    /// See `ApiGatewayV2::delete_route`.
+    pub fn delete_route(
+        &self,
+        input: DeleteRouteRequest,
+    ) -> Result<(), RusotoError<DeleteRouteError>> {
+        self.runtime.block_on(self.client.delete_route(input))
+    }
+
+    /// <p>Deletes a route request parameter.</p>
+    ///
+    /// See `ApiGatewayV2::delete_route_request_parameter`.
+    pub fn delete_route_request_parameter(
+        &self,
+        input: DeleteRouteRequestParameterRequest,
+    ) -> Result<(), RusotoError<DeleteRouteRequestParameterError>> {
+        self.runtime
+            .block_on(self.client.delete_route_request_parameter(input))
+    }
+
+    /// <p>Deletes a RouteResponse.</p>
+    ///
+    /// See `ApiGatewayV2::delete_route_response`.
+    pub fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> Result<(), RusotoError<DeleteRouteResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_route_response(input))
+    }
+
+    /// <p>Deletes the RouteSettings for a stage.</p>
+    ///
+    /// See `ApiGatewayV2::delete_route_settings`.
+    pub fn delete_route_settings(
+        &self,
+        input: DeleteRouteSettingsRequest,
+    ) -> Result<(), RusotoError<DeleteRouteSettingsError>> {
+        self.runtime
+            .block_on(self.client.delete_route_settings(input))
+    }
+
+    /// <p>Deletes a Stage.</p>
+    ///
+    /// See `ApiGatewayV2::delete_stage`.
+    pub fn delete_stage(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<(), RusotoError<DeleteStageError>> {
+        self.runtime.block_on(self.client.delete_stage(input))
+    }
+
+    /// <p>Deletes a VPC link.</p>
+    ///
+    /// See `ApiGatewayV2::delete_vpc_link`.
+    pub fn delete_vpc_link(
+        &self,
+        input: DeleteVpcLinkRequest,
+    ) -> Result<DeleteVpcLinkResponse, RusotoError<DeleteVpcLinkError>> {
+        self.runtime.block_on(self.client.delete_vpc_link(input))
+    }
+
+    /// See `ApiGatewayV2::export_api`.
+    pub fn export_api(
+        &self,
+        input: ExportApiRequest,
+    ) -> Result<ExportApiResponse, RusotoError<ExportApiError>> {
+        self.runtime.block_on(self.client.export_api(input))
+    }
+
+    /// <p>Gets an Api resource.</p>
+    ///
+    /// See `ApiGatewayV2::get_api`.
+    pub fn get_api(
+        &self,
+        input: GetApiRequest,
+    ) -> Result<GetApiResponse, RusotoError<GetApiError>> {
+        self.runtime.block_on(self.client.get_api(input))
+    }
+
+    /// <p>Gets an API mapping.</p>
+    ///
+    /// See `ApiGatewayV2::get_api_mapping`.
+    pub fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> Result<GetApiMappingResponse, RusotoError<GetApiMappingError>> {
+        self.runtime.block_on(self.client.get_api_mapping(input))
+    }
+
+    /// <p>Gets API mappings.</p>
+    ///
+    /// See `ApiGatewayV2::get_api_mappings`.
+    pub fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> Result<GetApiMappingsResponse, RusotoError<GetApiMappingsError>> {
+        self.runtime.block_on(self.client.get_api_mappings(input))
+    }
+
+    /// <p>Gets a collection of Api resources.</p>
+    ///
+    /// See `ApiGatewayV2::get_apis`.
+    pub fn get_apis(
+        &self,
+        input: GetApisRequest,
+    ) -> Result<GetApisResponse, RusotoError<GetApisError>> {
+        self.runtime.block_on(self.client.get_apis(input))
+    }
+
+    /// <p>Gets an Authorizer.</p>
+    ///
+    /// See `ApiGatewayV2::get_authorizer`.
+    pub fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<GetAuthorizerResponse, RusotoError<GetAuthorizerError>> {
+        self.runtime.block_on(self.client.get_authorizer(input))
+    }
+
+    /// <p>Gets the Authorizers for an API.</p>
+    ///
+    /// See `ApiGatewayV2::get_authorizers`.
+    pub fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<GetAuthorizersResponse, RusotoError<GetAuthorizersError>> {
+        self.runtime.block_on(self.client.get_authorizers(input))
+    }
+
+    /// <p>Gets a Deployment.</p>
+    ///
+    /// See `ApiGatewayV2::get_deployment`.
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<GetDeploymentResponse, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// <p>Gets the Deployments for an API.</p>
+    ///
+    /// See `ApiGatewayV2::get_deployments`.
+    pub fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<GetDeploymentsResponse, RusotoError<GetDeploymentsError>> {
+        self.runtime.block_on(self.client.get_deployments(input))
+    }
+
+    /// <p>Gets a domain name.</p>
+    ///
+    /// See `ApiGatewayV2::get_domain_name`.
+    pub fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<GetDomainNameResponse, RusotoError<GetDomainNameError>> {
+        self.runtime.block_on(self.client.get_domain_name(input))
+    }
+
+    /// <p>Gets the domain names for an AWS account.</p>
+    ///
+    /// See `ApiGatewayV2::get_domain_names`.
+    pub fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<GetDomainNamesResponse, RusotoError<GetDomainNamesError>> {
+        self.runtime.block_on(self.client.get_domain_names(input))
+    }
+
+    /// <p>Gets an Integration.</p>
+    ///
+    /// See `ApiGatewayV2::get_integration`.
+    pub fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<GetIntegrationResult, RusotoError<GetIntegrationError>> {
+        self.runtime.block_on(self.client.get_integration(input))
+    }
+
+    /// <p>Gets an IntegrationResponses.</p>
+    ///
+    /// See `ApiGatewayV2::get_integration_response`.
+    pub fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<GetIntegrationResponseResponse, RusotoError<GetIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.get_integration_response(input))
+    }
+
+    /// <p>Gets the IntegrationResponses for an Integration.</p>
+    ///
+    /// See `ApiGatewayV2::get_integration_responses`.
+    pub fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> Result<GetIntegrationResponsesResponse, RusotoError<GetIntegrationResponsesError>> {
+        self.runtime
+            .block_on(self.client.get_integration_responses(input))
+    }
+
+    /// <p>Gets the Integrations for an API.</p>
+    ///
+    /// See `ApiGatewayV2::get_integrations`.
+    pub fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> Result<GetIntegrationsResponse, RusotoError<GetIntegrationsError>> {
+        self.runtime.block_on(self.client.get_integrations(input))
+    }
+
+    /// <p>Gets a Model.</p>
+    ///
+    /// See `ApiGatewayV2::get_model`.
+    pub fn get_model(
+        &self,
+        input: GetModelRequest,
+    ) -> Result<GetModelResponse, RusotoError<GetModelError>> {
+        self.runtime.block_on(self.client.get_model(input))
+    }
+
+    /// <p>Gets a model template.</p>
+    ///
+    /// See `ApiGatewayV2::get_model_template`.
+    pub fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<GetModelTemplateResponse, RusotoError<GetModelTemplateError>> {
+        self.runtime.block_on(self.client.get_model_template(input))
+    }
+
+    /// <p>Gets the Models for an API.</p>
+    ///
+    /// See `ApiGatewayV2::get_models`.
+    pub fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<GetModelsResponse, RusotoError<GetModelsError>> {
+        self.runtime.block_on(self.client.get_models(input))
+    }
+
+    /// <p>Gets a Route.</p>
+    ///
+    /// See `ApiGatewayV2::get_route`.
+    pub fn get_route(
+        &self,
+        input: GetRouteRequest,
+    ) -> Result<GetRouteResult, RusotoError<GetRouteError>> {
+        self.runtime.block_on(self.client.get_route(input))
+    }
+
+    /// <p>Gets a RouteResponse.</p>
+    ///
+    /// See `ApiGatewayV2::get_route_response`.
+    pub fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> Result<GetRouteResponseResponse, RusotoError<GetRouteResponseError>> {
+        self.runtime.block_on(self.client.get_route_response(input))
+    }
+
+    /// <p>Gets the RouteResponses for a Route.</p>
+    ///
+    /// See `ApiGatewayV2::get_route_responses`.
+    pub fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> Result<GetRouteResponsesResponse, RusotoError<GetRouteResponsesError>> {
+        self.runtime
+            .block_on(self.client.get_route_responses(input))
+    }
+
+    /// <p>Gets the Routes for an API.</p>
+    ///
+    /// See `ApiGatewayV2::get_routes`.
+    pub fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> Result<GetRoutesResponse, RusotoError<GetRoutesError>> {
+        self.runtime.block_on(self.client.get_routes(input))
+    }
+
+    /// <p>Gets a Stage.</p>
+    ///
+    /// See `ApiGatewayV2::get_stage`.
+    pub fn get_stage(
+        &self,
+        input: GetStageRequest,
+    ) -> Result<GetStageResponse, RusotoError<GetStageError>> {
+        self.runtime.block_on(self.client.get_stage(input))
+    }
+
+    /// <p>Gets the Stages for an API.</p>
+    ///
+    /// See `ApiGatewayV2::get_stages`.
+    pub fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<GetStagesResponse, RusotoError<GetStagesError>> {
+        self.runtime.block_on(self.client.get_stages(input))
+    }
+
+    /// <p>Gets a collection of Tag resources.</p>
+    ///
+    /// See `ApiGatewayV2::get_tags`.
+    pub fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// <p>Gets a VPC link.</p>
+    ///
+    /// See `ApiGatewayV2::get_vpc_link`.
+    pub fn get_vpc_link(
+        &self,
+        input: GetVpcLinkRequest,
+    ) -> Result<GetVpcLinkResponse, RusotoError<GetVpcLinkError>> {
+        self.runtime.block_on(self.client.get_vpc_link(input))
+    }
+
+    /// <p>Gets a collection of VPC links.</p>
+    ///
+    /// See `ApiGatewayV2::get_vpc_links`.
+    pub fn get_vpc_links(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> Result<GetVpcLinksResponse, RusotoError<GetVpcLinksError>> {
+        self.runtime.block_on(self.client.get_vpc_links(input))
+    }
+
+    /// <p>Imports an API.</p>
+    ///
+    /// See `ApiGatewayV2::import_api`.
+    pub fn import_api(
+        &self,
+        input: ImportApiRequest,
+    ) -> Result<ImportApiResponse, RusotoError<ImportApiError>> {
+        self.runtime.block_on(self.client.import_api(input))
+    }
+
+    /// <p>Puts an Api resource.</p>
+    ///
+    /// See `ApiGatewayV2::reimport_api`.
+    pub fn reimport_api(
+        &self,
+        input: ReimportApiRequest,
+    ) -> Result<ReimportApiResponse, RusotoError<ReimportApiError>> {
+        self.runtime.block_on(self.client.reimport_api(input))
+    }
+
+    /// <p>Creates a new Tag resource to represent a tag.</p>
+    ///
+    /// See `ApiGatewayV2::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes a Tag.</p>
+    ///
+    /// See `ApiGatewayV2::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an Api resource.</p>
+    ///
+    /// See `ApiGatewayV2::update_api`.
+    pub fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> Result<UpdateApiResponse, RusotoError<UpdateApiError>> {
+        self.runtime.block_on(self.client.update_api(input))
+    }
+
+    /// <p>The API mapping.</p>
+    ///
+    /// See `ApiGatewayV2::update_api_mapping`.
+    pub fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> Result<UpdateApiMappingResponse, RusotoError<UpdateApiMappingError>> {
+        self.runtime.block_on(self.client.update_api_mapping(input))
+    }
+
+    /// <p>Updates an Authorizer.</p>
+    ///
+    /// See `ApiGatewayV2::update_authorizer`.
+    pub fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<UpdateAuthorizerResponse, RusotoError<UpdateAuthorizerError>> {
+        self.runtime.block_on(self.client.update_authorizer(input))
+    }
+
+    /// <p>Updates a Deployment.</p>
+    ///
+    /// See `ApiGatewayV2::update_deployment`.
+    pub fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<UpdateDeploymentResponse, RusotoError<UpdateDeploymentError>> {
+        self.runtime.block_on(self.client.update_deployment(input))
+    }
+
+    /// <p>Updates a domain name.</p>
+    ///
+    /// See `ApiGatewayV2::update_domain_name`.
+    pub fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<UpdateDomainNameResponse, RusotoError<UpdateDomainNameError>> {
+        self.runtime.block_on(self.client.update_domain_name(input))
+    }
+
+    /// <p>Updates an Integration.</p>
+    ///
+    /// See `ApiGatewayV2::update_integration`.
+    pub fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<UpdateIntegrationResult, RusotoError<UpdateIntegrationError>> {
+        self.runtime.block_on(self.client.update_integration(input))
+    }
+
+    /// <p>Updates an IntegrationResponses.</p>
+    ///
+    /// See `ApiGatewayV2::update_integration_response`.
+    pub fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<UpdateIntegrationResponseResponse, RusotoError<UpdateIntegrationResponseError>>
+    {
+        self.runtime
+            .block_on(self.client.update_integration_response(input))
+    }
+
+    /// <p>Updates a Model.</p>
+    ///
+    /// See `ApiGatewayV2::update_model`.
+    pub fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<UpdateModelResponse, RusotoError<UpdateModelError>> {
+        self.runtime.block_on(self.client.update_model(input))
+    }
+
+    /// <p>Updates a Route.</p>
+    ///
+    /// See `ApiGatewayV2::update_route`.
+    pub fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> Result<UpdateRouteResult, RusotoError<UpdateRouteError>> {
+        self.runtime.block_on(self.client.update_route(input))
+    }
+
+    /// <p>Updates a RouteResponse.</p>
+    ///
+    /// See `ApiGatewayV2::update_route_response`.
+    pub fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> Result<UpdateRouteResponseResponse, RusotoError<UpdateRouteResponseError>> {
+        self.runtime
+            .block_on(self.client.update_route_response(input))
+    }
+
+    /// <p>Updates a Stage.</p>
+    ///
+    /// See `ApiGatewayV2::update_stage`.
+    pub fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<UpdateStageResponse, RusotoError<UpdateStageError>> {
+        self.runtime.block_on(self.client.update_stage(input))
+    }
+
+    /// <p>Updates a VPC link.</p>
+    ///
+    /// See `ApiGatewayV2::update_vpc_link`.
+    pub fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> Result<UpdateVpcLinkResponse, RusotoError<UpdateVpcLinkError>> {
+        self.runtime.block_on(self.client.update_vpc_link(input))
+    }
+}
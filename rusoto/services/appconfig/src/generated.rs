@@ -1040,10 +1040,13 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApplicationError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1080,19 +1083,22 @@ impl CreateConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationProfileError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationProfileError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1126,12 +1132,16 @@ impl CreateDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentStrategyError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentStrategyError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1166,13 +1176,19 @@ impl CreateEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateEnvironmentError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1216,32 +1232,38 @@ impl CreateHostedConfigurationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateHostedConfigurationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHostedConfigurationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateHostedConfigurationVersionError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHostedConfigurationVersionError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateHostedConfigurationVersionError::InternalServer(err.msg),
                     )
                 }
                 "PayloadTooLargeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateHostedConfigurationVersionError::PayloadTooLarge(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateHostedConfigurationVersionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateHostedConfigurationVersionError::ServiceQuotaExceeded(err.msg),
                     )
                 }
@@ -1290,13 +1312,19 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1336,22 +1364,28 @@ impl DeleteConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationProfileError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationProfileError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationProfileError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1388,17 +1422,22 @@ impl DeleteDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentStrategyError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentStrategyError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentStrategyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1436,16 +1475,22 @@ impl DeleteEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteEnvironmentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteEnvironmentError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1484,17 +1529,20 @@ impl DeleteHostedConfigurationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteHostedConfigurationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHostedConfigurationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteHostedConfigurationVersionError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteHostedConfigurationVersionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1536,13 +1584,16 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApplicationError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetApplicationError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1578,13 +1629,19 @@ impl GetConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetConfigurationError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConfigurationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigurationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1620,17 +1677,22 @@ impl GetConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationProfileError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationProfileError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1666,13 +1728,16 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDeploymentError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1708,17 +1773,22 @@ impl GetDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentStrategyError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentStrategyError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentStrategyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1754,13 +1824,16 @@ impl GetEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetEnvironmentError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetEnvironmentError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnvironmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1798,17 +1871,20 @@ impl GetHostedConfigurationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetHostedConfigurationVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetHostedConfigurationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetHostedConfigurationVersionError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetHostedConfigurationVersionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1846,10 +1922,13 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListApplicationsError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationsError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1884,19 +1963,22 @@ impl ListConfigurationProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationProfilesError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationProfilesError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1930,12 +2012,16 @@ impl ListDeploymentStrategiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentStrategiesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentStrategiesError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentStrategiesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentStrategiesError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1970,13 +2056,19 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListDeploymentsError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeploymentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2012,13 +2104,19 @@ impl ListEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListEnvironmentsError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEnvironmentsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEnvironmentsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEnvironmentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEnvironmentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2056,17 +2154,20 @@ impl ListHostedConfigurationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListHostedConfigurationVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHostedConfigurationVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListHostedConfigurationVersionsError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListHostedConfigurationVersionsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2108,15 +2209,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2154,16 +2262,22 @@ impl StartDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartDeploymentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartDeploymentError::Conflict(err.msg))
+                    return RusotoError::service(&res, StartDeploymentError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDeploymentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDeploymentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2200,13 +2314,16 @@ impl StopDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopDeploymentError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StopDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service(&res, StopDeploymentError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDeploymentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2242,13 +2359,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2284,13 +2401,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2326,13 +2446,19 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApplicationError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2370,19 +2496,22 @@ impl UpdateConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConfigurationProfileError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConfigurationProfileError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConfigurationProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2418,17 +2547,22 @@ impl UpdateDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentStrategyError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentStrategyError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentStrategyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2464,13 +2598,19 @@ impl UpdateEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateEnvironmentError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2506,17 +2646,22 @@ impl ValidateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ValidateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ValidateConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ValidateConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateConfigurationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ValidateConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2755,6 +2900,7 @@ impl AppConfigClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2770,6 +2916,7 @@ impl AppConfigClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AppConfigClient {
         AppConfigClient { client, region }
     }
@@ -2786,6 +2933,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2820,6 +2970,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("CreateConfigurationProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2851,6 +3004,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("CreateDeploymentStrategy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2885,6 +3041,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("CreateEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2917,6 +3076,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("CreateHostedConfigurationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.content.to_owned());
@@ -2969,6 +3131,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3000,6 +3165,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("DeleteConfigurationProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3030,6 +3198,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("DeleteDeploymentStrategy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3061,6 +3232,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("DeleteEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3088,6 +3262,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions/{version_number}", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id, version_number = input.version_number);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("DeleteHostedConfigurationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3120,6 +3297,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3153,6 +3333,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3196,6 +3379,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetConfigurationProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3224,6 +3410,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetDeployment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3255,6 +3444,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetDeploymentStrategy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3287,6 +3479,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetEnvironment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3315,6 +3510,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions/{version_number}", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id, version_number = input.version_number);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("GetHostedConfigurationVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3353,6 +3551,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3393,6 +3594,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListConfigurationProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3430,6 +3634,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListDeploymentStrategies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3471,6 +3678,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3511,6 +3721,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListEnvironments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3549,6 +3762,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListHostedConfigurationVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3588,6 +3804,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3620,6 +3839,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("StartDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3651,6 +3873,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("StopDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3679,6 +3904,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3709,6 +3937,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3745,6 +3976,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3780,6 +4014,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("UpdateConfigurationProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3814,6 +4051,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("UpdateDeploymentStrategy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3849,6 +4089,9 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("UpdateEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3880,6 +4123,9 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/validators", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation_name("ValidateConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3902,3 +4148,401 @@ impl AppConfig for AppConfigClient {
         }
     }
 }
+
+/// A blocking client for the AppConfig API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AppConfigClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppConfigBlockingClient {
+    client: AppConfigClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppConfigBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppConfigBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AppConfigBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppConfigBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppConfigBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppConfigBlockingClient {
+        AppConfigBlockingClient {
+            client: AppConfigClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>An application in AppConfig is a logical unit of code that provides capabilities for your customers. For example, an application can be a microservice that runs on Amazon EC2 instances, a mobile application installed by your users, a serverless application using Amazon API Gateway and AWS Lambda, or any system you run on behalf of others.</p>
+    ///
+    /// See `AppConfig::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<Application, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p>Information that enables AppConfig to access the configuration source. Valid configuration sources include Systems Manager (SSM) documents, SSM Parameter Store parameters, and Amazon S3 objects. A configuration profile includes the following information.</p> <ul> <li> <p>The Uri location of the configuration data.</p> </li> <li> <p>The AWS Identity and Access Management (IAM) role that provides access to the configuration data.</p> </li> <li> <p>A validator for the configuration data. Available validators include either a JSON Schema or an AWS Lambda function.</p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/systems-manager/latest/userguide/appconfig-creating-configuration-and-profile.html">Create a Configuration and a Configuration Profile</a> in the <i>AWS AppConfig User Guide</i>.</p>
+    ///
+    /// See `AppConfig::create_configuration_profile`.
+    pub fn create_configuration_profile(
+        &self,
+        input: CreateConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<CreateConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_profile(input))
+    }
+
+    /// <p>A deployment strategy defines important criteria for rolling out your configuration to the designated targets. A deployment strategy includes: the overall duration required, a percentage of targets to receive the deployment during each interval, an algorithm that defines how percentage grows, and bake time.</p>
+    ///
+    /// See `AppConfig::create_deployment_strategy`.
+    pub fn create_deployment_strategy(
+        &self,
+        input: CreateDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<CreateDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_strategy(input))
+    }
+
+    /// <p>For each application, you define one or more environments. An environment is a logical deployment group of AppConfig targets, such as applications in a <code>Beta</code> or <code>Production</code> environment. You can also define environments for application subcomponents such as the <code>Web</code>, <code>Mobile</code> and <code>Back-end</code> components for your application. You can configure Amazon CloudWatch alarms for each environment. The system monitors alarms during a configuration deployment. If an alarm is triggered, the system rolls back the configuration.</p>
+    ///
+    /// See `AppConfig::create_environment`.
+    pub fn create_environment(
+        &self,
+        input: CreateEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<CreateEnvironmentError>> {
+        self.runtime.block_on(self.client.create_environment(input))
+    }
+
+    /// <p>Create a new configuration in the AppConfig configuration store.</p>
+    ///
+    /// See `AppConfig::create_hosted_configuration_version`.
+    pub fn create_hosted_configuration_version(
+        &self,
+        input: CreateHostedConfigurationVersionRequest,
+    ) -> Result<HostedConfigurationVersion, RusotoError<CreateHostedConfigurationVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_hosted_configuration_version(input))
+    }
+
+    /// <p>Delete an application. Deleting an application does not delete a configuration from a host.</p>
+    ///
+    /// See `AppConfig::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p>Delete a configuration profile. Deleting a configuration profile does not delete a configuration from a host.</p>
+    ///
+    /// See `AppConfig::delete_configuration_profile`.
+    pub fn delete_configuration_profile(
+        &self,
+        input: DeleteConfigurationProfileRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_profile(input))
+    }
+
+    /// <p>Delete a deployment strategy. Deleting a deployment strategy does not delete a configuration from a host.</p>
+    ///
+    /// See `AppConfig::delete_deployment_strategy`.
+    pub fn delete_deployment_strategy(
+        &self,
+        input: DeleteDeploymentStrategyRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.delete_deployment_strategy(input))
+    }
+
+    /// <p>Delete an environment. Deleting an environment does not delete a configuration from a host.</p>
+    ///
+    /// See `AppConfig::delete_environment`.
+    pub fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<(), RusotoError<DeleteEnvironmentError>> {
+        self.runtime.block_on(self.client.delete_environment(input))
+    }
+
+    /// <p>Delete a version of a configuration from the AppConfig configuration store.</p>
+    ///
+    /// See `AppConfig::delete_hosted_configuration_version`.
+    pub fn delete_hosted_configuration_version(
+        &self,
+        input: DeleteHostedConfigurationVersionRequest,
+    ) -> Result<(), RusotoError<DeleteHostedConfigurationVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_hosted_configuration_version(input))
+    }
+
+    /// <p>Retrieve information about an application.</p>
+    ///
+    /// See `AppConfig::get_application`.
+    pub fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<Application, RusotoError<GetApplicationError>> {
+        self.runtime.block_on(self.client.get_application(input))
+    }
+
+    /// <p><p>Receive information about a configuration.</p> <important> <p>AWS AppConfig uses the value of the <code>ClientConfigurationVersion</code> parameter to identify the configuration version on your clients. If you don’t send <code>ClientConfigurationVersion</code> with each call to <code>GetConfiguration</code>, your clients receive the current configuration. You are charged each time your clients receive a configuration.</p> <p>To avoid excess charges, we recommend that you include the <code>ClientConfigurationVersion</code> value with every call to <code>GetConfiguration</code>. This value must be saved on your client. Subsequent calls to <code>GetConfiguration</code> must pass this value by using the <code>ClientConfigurationVersion</code> parameter. </p> </important></p>
+    ///
+    /// See `AppConfig::get_configuration`.
+    pub fn get_configuration(
+        &self,
+        input: GetConfigurationRequest,
+    ) -> Result<Configuration, RusotoError<GetConfigurationError>> {
+        self.runtime.block_on(self.client.get_configuration(input))
+    }
+
+    /// <p>Retrieve information about a configuration profile.</p>
+    ///
+    /// See `AppConfig::get_configuration_profile`.
+    pub fn get_configuration_profile(
+        &self,
+        input: GetConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<GetConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.get_configuration_profile(input))
+    }
+
+    /// <p>Retrieve information about a configuration deployment.</p>
+    ///
+    /// See `AppConfig::get_deployment`.
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// <p>Retrieve information about a deployment strategy. A deployment strategy defines important criteria for rolling out your configuration to the designated targets. A deployment strategy includes: the overall duration required, a percentage of targets to receive the deployment during each interval, an algorithm that defines how percentage grows, and bake time.</p>
+    ///
+    /// See `AppConfig::get_deployment_strategy`.
+    pub fn get_deployment_strategy(
+        &self,
+        input: GetDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<GetDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_strategy(input))
+    }
+
+    /// <p>Retrieve information about an environment. An environment is a logical deployment group of AppConfig applications, such as applications in a <code>Production</code> environment or in an <code>EU_Region</code> environment. Each configuration deployment targets an environment. You can enable one or more Amazon CloudWatch alarms for an environment. If an alarm is triggered during a deployment, AppConfig roles back the configuration.</p>
+    ///
+    /// See `AppConfig::get_environment`.
+    pub fn get_environment(
+        &self,
+        input: GetEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<GetEnvironmentError>> {
+        self.runtime.block_on(self.client.get_environment(input))
+    }
+
+    /// <p>Get information about a specific configuration version.</p>
+    ///
+    /// See `AppConfig::get_hosted_configuration_version`.
+    pub fn get_hosted_configuration_version(
+        &self,
+        input: GetHostedConfigurationVersionRequest,
+    ) -> Result<HostedConfigurationVersion, RusotoError<GetHostedConfigurationVersionError>> {
+        self.runtime
+            .block_on(self.client.get_hosted_configuration_version(input))
+    }
+
+    /// <p>List all applications in your AWS account.</p>
+    ///
+    /// See `AppConfig::list_applications`.
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<Applications, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// <p>Lists the configuration profiles for an application.</p>
+    ///
+    /// See `AppConfig::list_configuration_profiles`.
+    pub fn list_configuration_profiles(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> Result<ConfigurationProfiles, RusotoError<ListConfigurationProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_profiles(input))
+    }
+
+    /// <p>List deployment strategies.</p>
+    ///
+    /// See `AppConfig::list_deployment_strategies`.
+    pub fn list_deployment_strategies(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> Result<DeploymentStrategies, RusotoError<ListDeploymentStrategiesError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_strategies(input))
+    }
+
+    /// <p>Lists the deployments for an environment.</p>
+    ///
+    /// See `AppConfig::list_deployments`.
+    pub fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<Deployments, RusotoError<ListDeploymentsError>> {
+        self.runtime.block_on(self.client.list_deployments(input))
+    }
+
+    /// <p>List the environments for an application.</p>
+    ///
+    /// See `AppConfig::list_environments`.
+    pub fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<Environments, RusotoError<ListEnvironmentsError>> {
+        self.runtime.block_on(self.client.list_environments(input))
+    }
+
+    /// <p>View a list of configurations stored in the AppConfig configuration store by version.</p>
+    ///
+    /// See `AppConfig::list_hosted_configuration_versions`.
+    pub fn list_hosted_configuration_versions(
+        &self,
+        input: ListHostedConfigurationVersionsRequest,
+    ) -> Result<HostedConfigurationVersions, RusotoError<ListHostedConfigurationVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_hosted_configuration_versions(input))
+    }
+
+    /// <p>Retrieves the list of key-value tags assigned to the resource.</p>
+    ///
+    /// See `AppConfig::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ResourceTags, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Starts a deployment.</p>
+    ///
+    /// See `AppConfig::start_deployment`.
+    pub fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<StartDeploymentError>> {
+        self.runtime.block_on(self.client.start_deployment(input))
+    }
+
+    /// <p>Stops a deployment. This API action works only on deployments that have a status of <code>DEPLOYING</code>. This action moves the deployment to a status of <code>ROLLED_BACK</code>.</p>
+    ///
+    /// See `AppConfig::stop_deployment`.
+    pub fn stop_deployment(
+        &self,
+        input: StopDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<StopDeploymentError>> {
+        self.runtime.block_on(self.client.stop_deployment(input))
+    }
+
+    /// <p>Metadata to assign to an AppConfig resource. Tags help organize and categorize your AppConfig resources. Each tag consists of a key and an optional value, both of which you define. You can specify a maximum of 50 tags for a resource.</p>
+    ///
+    /// See `AppConfig::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes a tag key and value from an AppConfig resource.</p>
+    ///
+    /// See `AppConfig::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an application.</p>
+    ///
+    /// See `AppConfig::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<Application, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// <p>Updates a configuration profile.</p>
+    ///
+    /// See `AppConfig::update_configuration_profile`.
+    pub fn update_configuration_profile(
+        &self,
+        input: UpdateConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<UpdateConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.update_configuration_profile(input))
+    }
+
+    /// <p>Updates a deployment strategy.</p>
+    ///
+    /// See `AppConfig::update_deployment_strategy`.
+    pub fn update_deployment_strategy(
+        &self,
+        input: UpdateDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<UpdateDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.update_deployment_strategy(input))
+    }
+
+    /// <p>Updates an environment.</p>
+    ///
+    /// See `AppConfig::update_environment`.
+    pub fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<UpdateEnvironmentError>> {
+        self.runtime.block_on(self.client.update_environment(input))
+    }
+
+    /// <p>Uses the validators in a configuration profile to validate a configuration.</p>
+    ///
+    /// See `AppConfig::validate_configuration`.
+    pub fn validate_configuration(
+        &self,
+        input: ValidateConfigurationRequest,
+    ) -> Result<(), RusotoError<ValidateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.validate_configuration(input))
+    }
+}
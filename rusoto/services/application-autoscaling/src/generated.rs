@@ -708,15 +708,22 @@ impl DeleteScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPolicyError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPolicyError::InternalService(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPolicyError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -752,19 +759,22 @@ impl DeleteScheduledActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledActionError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledActionError::InternalService(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::ObjectNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledActionError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -800,19 +810,22 @@ impl DeregisterScalableTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterScalableTargetError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterScalableTargetError::InternalService(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::ObjectNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterScalableTargetError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -848,19 +861,22 @@ impl DescribeScalableTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalableTargetsError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalableTargetsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalableTargetsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -896,19 +912,22 @@ impl DescribeScalingActivitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingActivitiesError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingActivitiesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingActivitiesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -946,24 +965,28 @@ impl DescribeScalingPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "FailedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeScalingPoliciesError::FailedResourceAccess(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1000,19 +1023,22 @@ impl DescribeScheduledActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledActionsError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledActionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledActionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1052,21 +1078,34 @@ impl PutScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(PutScalingPolicyError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "FailedResourceAccessException" => {
-                    return RusotoError::Service(PutScalingPolicyError::FailedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::FailedResourceAccess(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::InternalService(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutScalingPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::LimitExceeded(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(PutScalingPolicyError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1106,16 +1145,28 @@ impl PutScheduledActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(PutScheduledActionError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScheduledActionError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScheduledActionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScheduledActionError::InternalService(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutScheduledActionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScheduledActionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(PutScheduledActionError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScheduledActionError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1152,19 +1203,22 @@ impl RegisterScalableTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterScalableTargetError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterScalableTargetError::InternalService(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterScalableTargetError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1265,6 +1319,7 @@ impl ApplicationAutoScalingClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1280,6 +1335,7 @@ impl ApplicationAutoScalingClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationAutoScalingClient {
         ApplicationAutoScalingClient { client, region }
     }
@@ -1293,6 +1349,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeleteScalingPolicyRequest,
     ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DeleteScalingPolicy",
@@ -1314,6 +1373,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeleteScheduledActionRequest,
     ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DeleteScheduledAction",
@@ -1336,6 +1398,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeregisterScalableTargetRequest,
     ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterScalableTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DeregisterScalableTarget",
@@ -1358,6 +1423,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScalableTargetsRequest,
     ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScalableTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScalableTargets",
@@ -1381,6 +1449,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
     ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScalingActivities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScalingActivities",
@@ -1403,6 +1474,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScalingPoliciesRequest,
     ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScalingPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScalingPolicies",
@@ -1425,6 +1499,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScheduledActionsRequest,
     ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScheduledActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScheduledActions",
@@ -1447,6 +1524,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: PutScalingPolicyRequest,
     ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1465,6 +1545,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: PutScheduledActionRequest,
     ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScheduledAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1483,6 +1566,9 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: RegisterScalableTargetRequest,
     ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterScalableTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.RegisterScalableTarget",
@@ -1499,3 +1585,170 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .deserialize::<RegisterScalableTargetResponse, _>()
     }
 }
+
+/// A blocking client for the Application Auto Scaling API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ApplicationAutoScalingClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApplicationAutoScalingBlockingClient {
+    client: ApplicationAutoScalingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApplicationAutoScalingBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApplicationAutoScalingBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ApplicationAutoScalingBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApplicationAutoScalingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApplicationAutoScalingBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient {
+            client: ApplicationAutoScalingClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Deletes the specified scaling policy for an Application Auto Scaling scalable target.</p> <p>Deleting a step scaling policy deletes the underlying alarm action, but does not delete the CloudWatch alarm associated with the scaling policy, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html#delete-step-scaling-policy">Delete a Step Scaling Policy</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html#delete-target-tracking-policy">Delete a Target Tracking Scaling Policy</a> in the <i>Application Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `ApplicationAutoScaling::delete_scaling_policy`.
+    pub fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_scaling_policy(input))
+    }
+
+    /// <p>Deletes the specified scheduled action for an Application Auto Scaling scalable target.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-scheduled-scaling.html#delete-scheduled-action">Delete a Scheduled Action</a> in the <i>Application Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `ApplicationAutoScaling::delete_scheduled_action`.
+    pub fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_action(input))
+    }
+
+    /// <p><p>Deregisters an Application Auto Scaling scalable target when you have finished using it. To see which resources have been registered, use <a href="https://docs.aws.amazon.com/autoscaling/application/APIReference/API_DescribeScalableTargets.html">DescribeScalableTargets</a>. </p> <note> <p>Deregistering a scalable target deletes the scaling policies and the scheduled actions that are associated with it.</p> </note></p>
+    ///
+    /// See `ApplicationAutoScaling::deregister_scalable_target`.
+    pub fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
+        self.runtime
+            .block_on(self.client.deregister_scalable_target(input))
+    }
+
+    /// <p>Gets information about the scalable targets in the specified namespace.</p> <p>You can filter the results using <code>ResourceIds</code> and <code>ScalableDimension</code>.</p>
+    ///
+    /// See `ApplicationAutoScaling::describe_scalable_targets`.
+    pub fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_scalable_targets(input))
+    }
+
+    /// <p>Provides descriptive information about the scaling activities in the specified namespace from the previous six weeks.</p> <p>You can filter the results using <code>ResourceId</code> and <code>ScalableDimension</code>.</p>
+    ///
+    /// See `ApplicationAutoScaling::describe_scaling_activities`.
+    pub fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scaling_activities(input))
+    }
+
+    /// <p>Describes the Application Auto Scaling scaling policies for the specified service namespace.</p> <p>You can filter the results using <code>ResourceId</code>, <code>ScalableDimension</code>, and <code>PolicyNames</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html">Target Tracking Scaling Policies</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html">Step Scaling Policies</a> in the <i>Application Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `ApplicationAutoScaling::describe_scaling_policies`.
+    pub fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_policies(input))
+    }
+
+    /// <p>Describes the Application Auto Scaling scheduled actions for the specified service namespace.</p> <p>You can filter the results using the <code>ResourceId</code>, <code>ScalableDimension</code>, and <code>ScheduledActionNames</code> parameters.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-scheduled-scaling.html">Scheduled Scaling</a> in the <i>Application Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `ApplicationAutoScaling::describe_scheduled_actions`.
+    pub fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_actions(input))
+    }
+
+    /// <p><p>Creates or updates a scaling policy for an Application Auto Scaling scalable target.</p> <p>Each scalable target is identified by a service namespace, resource ID, and scalable dimension. A scaling policy applies to the scalable target identified by those three attributes. You cannot create a scaling policy until you have registered the resource as a scalable target.</p> <p>Multiple scaling policies can be in force at the same time for the same scalable target. You can have one or more target tracking scaling policies, one or more step scaling policies, or both. However, there is a chance that multiple policies could conflict, instructing the scalable target to scale out or in at the same time. Application Auto Scaling gives precedence to the policy that provides the largest capacity for both scale out and scale in. For example, if one policy increases capacity by 3, another policy increases capacity by 200 percent, and the current capacity is 10, Application Auto Scaling uses the policy with the highest calculated capacity (200% of 10 = 20) and scales out to 30. </p> <p>We recommend caution, however, when using target tracking scaling policies with step scaling policies because conflicts between these policies can cause undesirable behavior. For example, if the step scaling policy initiates a scale-in activity before the target tracking policy is ready to scale in, the scale-in activity will not be blocked. After the scale-in activity completes, the target tracking policy could instruct the scalable target to scale out again. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html">Target Tracking Scaling Policies</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html">Step Scaling Policies</a> in the <i>Application Auto Scaling User Guide</i>.</p> <note> <p>If a scalable target is deregistered, the scalable target is no longer available to execute scaling policies. Any scaling policies that were specified for the scalable target are deleted.</p> </note></p>
+    ///
+    /// See `ApplicationAutoScaling::put_scaling_policy`.
+    pub fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
+        self.runtime.block_on(self.client.put_scaling_policy(input))
+    }
+
+    /// <p><p>Creates or updates a scheduled action for an Application Auto Scaling scalable target.</p> <p>Each scalable target is identified by a service namespace, resource ID, and scalable dimension. A scheduled action applies to the scalable target identified by those three attributes. You cannot create a scheduled action until you have registered the resource as a scalable target.</p> <p>When start and end times are specified with a recurring schedule using a cron expression or rates, they form the boundaries of when the recurring action starts and stops.</p> <p>To update a scheduled action, specify the parameters that you want to change. If you don&#39;t specify start and end times, the old values are deleted.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-scheduled-scaling.html">Scheduled Scaling</a> in the <i>Application Auto Scaling User Guide</i>.</p> <note> <p>If a scalable target is deregistered, the scalable target is no longer available to run scheduled actions. Any scheduled actions that were specified for the scalable target are deleted.</p> </note></p>
+    ///
+    /// See `ApplicationAutoScaling::put_scheduled_action`.
+    pub fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.put_scheduled_action(input))
+    }
+
+    /// <p>Registers or updates a scalable target. </p> <p>A scalable target is a resource that Application Auto Scaling can scale out and scale in. Scalable targets are uniquely identified by the combination of resource ID, scalable dimension, and namespace. </p> <p>When you register a new scalable target, you must specify values for minimum and maximum capacity. Application Auto Scaling scaling policies will not scale capacity to values that are outside of this range.</p> <p>After you register a scalable target, you do not need to register it again to use other Application Auto Scaling operations. To see which resources have been registered, use <a href="https://docs.aws.amazon.com/autoscaling/application/APIReference/API_DescribeScalableTargets.html">DescribeScalableTargets</a>. You can also view the scaling policies for a service namespace by using <a href="https://docs.aws.amazon.com/autoscaling/application/APIReference/API_DescribeScalableTargets.html">DescribeScalableTargets</a>. If you no longer need a scalable target, you can deregister it by using <a href="https://docs.aws.amazon.com/autoscaling/application/APIReference/API_DeregisterScalableTarget.html">DeregisterScalableTarget</a>.</p> <p>To update a scalable target, specify the parameters that you want to change. Include the parameters that identify the scalable target: resource ID, scalable dimension, and namespace. Any parameters that you don't specify are not changed by this update request. </p>
+    ///
+    /// See `ApplicationAutoScaling::register_scalable_target`.
+    pub fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
+        self.runtime
+            .block_on(self.client.register_scalable_target(input))
+    }
+}
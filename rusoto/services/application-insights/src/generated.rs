@@ -1054,16 +1054,28 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagsAlreadyExistException" => {
-                    return RusotoError::Service(CreateApplicationError::TagsAlreadyExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::TagsAlreadyExist(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1100,13 +1112,19 @@ impl CreateComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateComponentError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1142,13 +1160,22 @@ impl CreateLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogPatternError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateLogPatternError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogPatternError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogPatternError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1184,13 +1211,19 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1224,10 +1257,16 @@ impl DeleteComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComponentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1262,13 +1301,19 @@ impl DeleteLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLogPatternError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteLogPatternError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogPatternError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogPatternError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1302,12 +1347,16 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1340,10 +1389,16 @@ impl DescribeComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeComponentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1378,12 +1433,14 @@ impl DescribeComponentConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComponentConfigurationError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComponentConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1424,12 +1481,14 @@ impl DescribeComponentConfigurationRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComponentConfigurationRecommendationError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComponentConfigurationRecommendationError::ResourceNotFound(
                             err.msg,
                         ),
@@ -1470,10 +1529,16 @@ impl DescribeLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogPatternError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogPatternError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1506,12 +1571,16 @@ impl DescribeObservationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeObservationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeObservationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeObservationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeObservationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1544,10 +1613,16 @@ impl DescribeProblemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProblemError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProblemError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProblemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProblemError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1582,12 +1657,14 @@ impl DescribeProblemObservationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProblemObservationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProblemObservationsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProblemObservationsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1620,7 +1697,10 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationsError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1652,10 +1732,13 @@ impl ListComponentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListComponentsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListComponentsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListComponentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1688,14 +1771,16 @@ impl ListConfigurationHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListConfigurationHistoryError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationHistoryError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationHistoryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationHistoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1728,10 +1813,16 @@ impl ListLogPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListLogPatternSetsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLogPatternSetsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLogPatternSetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLogPatternSetsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1764,10 +1855,16 @@ impl ListLogPatternsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListLogPatternsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLogPatternsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLogPatternsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLogPatternsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1800,10 +1897,10 @@ impl ListProblemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProblemsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListProblemsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProblemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListProblemsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1834,9 +1931,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1868,10 +1966,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1902,7 +2000,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1934,10 +2035,16 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1972,13 +2079,19 @@ impl UpdateComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateComponentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateComponentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateComponentError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2014,12 +2127,14 @@ impl UpdateComponentConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateComponentConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateComponentConfigurationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateComponentConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2058,13 +2173,22 @@ impl UpdateLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLogPatternError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateLogPatternError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLogPatternError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLogPatternError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2273,6 +2397,7 @@ impl ApplicationInsightsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2288,6 +2413,7 @@ impl ApplicationInsightsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationInsightsClient {
         ApplicationInsightsClient { client, region }
     }
@@ -2301,6 +2427,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2319,6 +2448,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateComponentRequest,
     ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2337,6 +2469,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateLogPatternRequest,
     ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLogPattern");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2355,6 +2490,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2373,6 +2511,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteComponentRequest,
     ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2391,6 +2532,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteLogPatternRequest,
     ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLogPattern");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2409,6 +2553,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeApplication",
@@ -2430,6 +2577,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeComponentRequest,
     ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeComponent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2451,6 +2601,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         RusotoError<DescribeComponentConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeComponentConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeComponentConfiguration",
@@ -2476,6 +2629,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         RusotoError<DescribeComponentConfigurationRecommendationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeComponentConfigurationRecommendation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeComponentConfigurationRecommendation",
@@ -2501,6 +2657,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeLogPatternRequest,
     ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLogPattern");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2519,6 +2678,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeObservationRequest,
     ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeObservation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeObservation",
@@ -2540,6 +2702,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeProblemRequest,
     ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProblem");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeProblem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2559,6 +2724,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
     ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProblemObservations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeProblemObservations",
@@ -2581,6 +2749,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2599,6 +2770,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListComponentsRequest,
     ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListComponents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListComponents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2617,6 +2791,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListConfigurationHistoryRequest,
     ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListConfigurationHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.ListConfigurationHistory",
@@ -2639,6 +2816,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListLogPatternSetsRequest,
     ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLogPatternSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatternSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2657,6 +2837,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListLogPatternsRequest,
     ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLogPatterns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatterns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2675,6 +2858,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListProblemsRequest,
     ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProblems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListProblems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2693,6 +2879,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.ListTagsForResource",
@@ -2714,6 +2903,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2732,6 +2924,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2750,6 +2945,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2768,6 +2966,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateComponentRequest,
     ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2787,6 +2988,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
     ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateComponentConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.UpdateComponentConfiguration",
@@ -2809,6 +3013,9 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateLogPatternRequest,
     ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateLogPattern");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2821,3 +3028,350 @@ impl ApplicationInsights for ApplicationInsightsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateLogPatternResponse, _>()
     }
 }
+
+/// A blocking client for the Application Insights API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ApplicationInsightsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApplicationInsightsBlockingClient {
+    client: ApplicationInsightsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApplicationInsightsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApplicationInsightsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ApplicationInsightsBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApplicationInsightsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApplicationInsightsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient {
+            client: ApplicationInsightsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds an application that is created from a resource group.</p>
+    ///
+    /// See `ApplicationInsights::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p>Creates a custom component by grouping similar standalone instances to monitor.</p>
+    ///
+    /// See `ApplicationInsights::create_component`.
+    pub fn create_component(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        self.runtime.block_on(self.client.create_component(input))
+    }
+
+    /// <p>Adds an log pattern to a <code>LogPatternSet</code>.</p>
+    ///
+    /// See `ApplicationInsights::create_log_pattern`.
+    pub fn create_log_pattern(
+        &self,
+        input: CreateLogPatternRequest,
+    ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
+        self.runtime.block_on(self.client.create_log_pattern(input))
+    }
+
+    /// <p>Removes the specified application from monitoring. Does not delete the application.</p>
+    ///
+    /// See `ApplicationInsights::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p>Ungroups a custom component. When you ungroup custom components, all applicable monitors that are set up for the component are removed and the instances revert to their standalone status.</p>
+    ///
+    /// See `ApplicationInsights::delete_component`.
+    pub fn delete_component(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+        self.runtime.block_on(self.client.delete_component(input))
+    }
+
+    /// <p>Removes the specified log pattern from a <code>LogPatternSet</code>.</p>
+    ///
+    /// See `ApplicationInsights::delete_log_pattern`.
+    pub fn delete_log_pattern(
+        &self,
+        input: DeleteLogPatternRequest,
+    ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
+        self.runtime.block_on(self.client.delete_log_pattern(input))
+    }
+
+    /// <p>Describes the application.</p>
+    ///
+    /// See `ApplicationInsights::describe_application`.
+    pub fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_application(input))
+    }
+
+    /// <p>Describes a component and lists the resources that are grouped together in a component.</p>
+    ///
+    /// See `ApplicationInsights::describe_component`.
+    pub fn describe_component(
+        &self,
+        input: DescribeComponentRequest,
+    ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
+        self.runtime.block_on(self.client.describe_component(input))
+    }
+
+    /// <p>Describes the monitoring configuration of the component.</p>
+    ///
+    /// See `ApplicationInsights::describe_component_configuration`.
+    pub fn describe_component_configuration(
+        &self,
+        input: DescribeComponentConfigurationRequest,
+    ) -> Result<
+        DescribeComponentConfigurationResponse,
+        RusotoError<DescribeComponentConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_component_configuration(input))
+    }
+
+    /// <p>Describes the recommended monitoring configuration of the component.</p>
+    ///
+    /// See `ApplicationInsights::describe_component_configuration_recommendation`.
+    pub fn describe_component_configuration_recommendation(
+        &self,
+        input: DescribeComponentConfigurationRecommendationRequest,
+    ) -> Result<
+        DescribeComponentConfigurationRecommendationResponse,
+        RusotoError<DescribeComponentConfigurationRecommendationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_component_configuration_recommendation(input),
+        )
+    }
+
+    /// <p>Describe a specific log pattern from a <code>LogPatternSet</code>.</p>
+    ///
+    /// See `ApplicationInsights::describe_log_pattern`.
+    pub fn describe_log_pattern(
+        &self,
+        input: DescribeLogPatternRequest,
+    ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
+        self.runtime
+            .block_on(self.client.describe_log_pattern(input))
+    }
+
+    /// <p>Describes an anomaly or error with the application.</p>
+    ///
+    /// See `ApplicationInsights::describe_observation`.
+    pub fn describe_observation(
+        &self,
+        input: DescribeObservationRequest,
+    ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
+        self.runtime
+            .block_on(self.client.describe_observation(input))
+    }
+
+    /// <p>Describes an application problem.</p>
+    ///
+    /// See `ApplicationInsights::describe_problem`.
+    pub fn describe_problem(
+        &self,
+        input: DescribeProblemRequest,
+    ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
+        self.runtime.block_on(self.client.describe_problem(input))
+    }
+
+    /// <p>Describes the anomalies or errors associated with the problem.</p>
+    ///
+    /// See `ApplicationInsights::describe_problem_observations`.
+    pub fn describe_problem_observations(
+        &self,
+        input: DescribeProblemObservationsRequest,
+    ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_problem_observations(input))
+    }
+
+    /// <p>Lists the IDs of the applications that you are monitoring. </p>
+    ///
+    /// See `ApplicationInsights::list_applications`.
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// <p>Lists the auto-grouped, standalone, and custom components of the application.</p>
+    ///
+    /// See `ApplicationInsights::list_components`.
+    pub fn list_components(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+        self.runtime.block_on(self.client.list_components(input))
+    }
+
+    /// <p><p> Lists the INFO, WARN, and ERROR events for periodic configuration updates performed by Application Insights. Examples of events represented are: </p> <ul> <li> <p>INFO: creating a new alarm or updating an alarm threshold.</p> </li> <li> <p>WARN: alarm not created due to insufficient data points used to predict thresholds.</p> </li> <li> <p>ERROR: alarm not created due to permission errors or exceeding quotas. </p> </li> </ul></p>
+    ///
+    /// See `ApplicationInsights::list_configuration_history`.
+    pub fn list_configuration_history(
+        &self,
+        input: ListConfigurationHistoryRequest,
+    ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_history(input))
+    }
+
+    /// <p>Lists the log pattern sets in the specific application.</p>
+    ///
+    /// See `ApplicationInsights::list_log_pattern_sets`.
+    pub fn list_log_pattern_sets(
+        &self,
+        input: ListLogPatternSetsRequest,
+    ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
+        self.runtime
+            .block_on(self.client.list_log_pattern_sets(input))
+    }
+
+    /// <p>Lists the log patterns in the specific log <code>LogPatternSet</code>.</p>
+    ///
+    /// See `ApplicationInsights::list_log_patterns`.
+    pub fn list_log_patterns(
+        &self,
+        input: ListLogPatternsRequest,
+    ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
+        self.runtime.block_on(self.client.list_log_patterns(input))
+    }
+
+    /// <p>Lists the problems with your application.</p>
+    ///
+    /// See `ApplicationInsights::list_problems`.
+    pub fn list_problems(
+        &self,
+        input: ListProblemsRequest,
+    ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
+        self.runtime.block_on(self.client.list_problems(input))
+    }
+
+    /// <p>Retrieve a list of the tags (keys and values) that are associated with a specified application. A <i>tag</i> is a label that you optionally define and associate with an application. Each tag consists of a required <i>tag key</i> and an optional associated <i>tag value</i>. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    ///
+    /// See `ApplicationInsights::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Add one or more tags (keys and values) to a specified application. A <i>tag</i> is a label that you optionally define and associate with an application. Tags can help you categorize and manage application in different ways, such as by purpose, owner, environment, or other criteria. </p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    ///
+    /// See `ApplicationInsights::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove one or more tags (keys and values) from a specified application.</p>
+    ///
+    /// See `ApplicationInsights::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the application.</p>
+    ///
+    /// See `ApplicationInsights::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// <p>Updates the custom component name and/or the list of resources that make up the component.</p>
+    ///
+    /// See `ApplicationInsights::update_component`.
+    pub fn update_component(
+        &self,
+        input: UpdateComponentRequest,
+    ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
+        self.runtime.block_on(self.client.update_component(input))
+    }
+
+    /// <p>Updates the monitoring configurations for the component. The configuration input parameter is an escaped JSON of the configuration and should match the schema of what is returned by <code>DescribeComponentConfigurationRecommendation</code>. </p>
+    ///
+    /// See `ApplicationInsights::update_component_configuration`.
+    pub fn update_component_configuration(
+        &self,
+        input: UpdateComponentConfigurationRequest,
+    ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_component_configuration(input))
+    }
+
+    /// <p>Adds a log pattern to a <code>LogPatternSet</code>.</p>
+    ///
+    /// See `ApplicationInsights::update_log_pattern`.
+    pub fn update_log_pattern(
+        &self,
+        input: UpdateLogPatternRequest,
+    ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
+        self.runtime.block_on(self.client.update_log_pattern(input))
+    }
+}
@@ -2951,32 +2951,40 @@ impl CreateGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateGatewayRouteError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateGatewayRouteError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateGatewayRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGatewayRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGatewayRouteError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateGatewayRouteError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGatewayRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGatewayRouteError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3032,28 +3040,31 @@ impl CreateMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeshError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMeshError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeshError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeshError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMeshError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateMeshError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateMeshError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3109,28 +3120,34 @@ impl CreateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3186,34 +3203,46 @@ impl CreateVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualGatewayError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateVirtualGatewayError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualGatewayError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualGatewayError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateVirtualGatewayError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualGatewayError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualGatewayError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3269,32 +3298,40 @@ impl CreateVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateVirtualNodeError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateVirtualNodeError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateVirtualNodeError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualNodeError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualNodeError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateVirtualNodeError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualNodeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualNodeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3350,32 +3387,43 @@ impl CreateVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualRouterError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateVirtualRouterError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateVirtualRouterError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualRouterError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualRouterError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateVirtualRouterError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualRouterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualRouterError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3431,34 +3479,46 @@ impl CreateVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualServiceError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateVirtualServiceError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualServiceError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualServiceError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualServiceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateVirtualServiceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualServiceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVirtualServiceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3511,29 +3571,37 @@ impl DeleteGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteGatewayRouteError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteGatewayRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteGatewayRouteError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayRouteError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayRouteError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3585,25 +3653,28 @@ impl DeleteMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMeshError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteMeshError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMeshError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteMeshError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMeshError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMeshError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteMeshError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteMeshError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteMeshError::ResourceInUse(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DeleteMeshError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteMeshError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3655,25 +3726,31 @@ impl DeleteRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteRouteError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::ResourceInUse(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3725,31 +3802,43 @@ impl DeleteVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualGatewayError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualGatewayError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualGatewayError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualGatewayError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualGatewayError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualGatewayError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3801,29 +3890,37 @@ impl DeleteVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualNodeError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualNodeError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualNodeError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualNodeError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualNodeError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualNodeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualNodeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3875,29 +3972,40 @@ impl DeleteVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualRouterError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualRouterError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualRouterError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualRouterError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualRouterError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualRouterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualRouterError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3949,31 +4057,43 @@ impl DeleteVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualServiceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualServiceError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualServiceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVirtualServiceError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualServiceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualServiceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualServiceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4022,28 +4142,37 @@ impl DescribeGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGatewayRouteError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGatewayRouteError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGatewayRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeGatewayRouteError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGatewayRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGatewayRouteError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4091,22 +4220,28 @@ impl DescribeMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMeshError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeMeshError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMeshError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeMeshError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMeshError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMeshError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeMeshError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMeshError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DescribeMeshError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4154,22 +4289,28 @@ impl DescribeRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeRouteError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeRouteError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DescribeRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4217,28 +4358,40 @@ impl DescribeVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewayError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewayError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewayError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewayError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewayError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4286,26 +4439,34 @@ impl DescribeVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualNodeError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeVirtualNodeError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualNodeError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeVirtualNodeError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualNodeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualNodeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4353,28 +4514,40 @@ impl DescribeVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualRouterError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualRouterError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualRouterError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualRouterError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualRouterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualRouterError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4422,28 +4595,40 @@ impl DescribeVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualServiceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualServiceError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualServiceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualServiceError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualServiceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualServiceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4491,26 +4676,31 @@ impl ListGatewayRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListGatewayRoutesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListGatewayRoutesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGatewayRoutesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListGatewayRoutesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGatewayRoutesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGatewayRoutesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4558,22 +4748,25 @@ impl ListMeshesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeshesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListMeshesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeshesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListMeshesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMeshesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeshesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMeshesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListMeshesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeshesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListMeshesError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMeshesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListMeshesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4621,22 +4814,25 @@ impl ListRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoutesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListRoutesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoutesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListRoutesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListRoutesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoutesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoutesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListRoutesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoutesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListRoutesError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListRoutesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4684,26 +4880,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4751,26 +4955,34 @@ impl ListVirtualGatewaysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualGatewaysError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListVirtualGatewaysError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualGatewaysError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListVirtualGatewaysError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualGatewaysError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualGatewaysError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4818,24 +5030,31 @@ impl ListVirtualNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualNodesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListVirtualNodesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualNodesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListVirtualNodesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualNodesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualNodesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualNodesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListVirtualNodesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualNodesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualNodesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualNodesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualNodesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4883,26 +5102,31 @@ impl ListVirtualRoutersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListVirtualRoutersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListVirtualRoutersError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualRoutersError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListVirtualRoutersError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualRoutersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualRoutersError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4950,26 +5174,34 @@ impl ListVirtualServicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualServicesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualServicesError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualServicesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListVirtualServicesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualServicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualServicesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualServicesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListVirtualServicesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualServicesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualServicesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualServicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVirtualServicesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5021,25 +5253,31 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5088,22 +5326,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5157,32 +5401,40 @@ impl UpdateGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayRouteError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayRouteError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayRouteError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGatewayRouteError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGatewayRouteError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5235,25 +5487,28 @@ impl UpdateMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMeshError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateMeshError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMeshError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateMeshError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMeshError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateMeshError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMeshError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMeshError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMeshError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateMeshError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateMeshError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateMeshError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5308,28 +5563,34 @@ impl UpdateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRouteError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateRouteError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRouteError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRouteError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateRouteError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5385,34 +5646,46 @@ impl UpdateVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualGatewayError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualGatewayError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualGatewayError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualGatewayError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualGatewayError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualGatewayError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualGatewayError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5468,32 +5741,40 @@ impl UpdateVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualNodeError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualNodeError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualNodeError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualNodeError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualNodeError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualNodeError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualNodeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualNodeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5549,32 +5830,43 @@ impl UpdateVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualRouterError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualRouterError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualRouterError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualRouterError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualRouterError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualRouterError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualRouterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualRouterError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5630,34 +5922,46 @@ impl UpdateVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualServiceError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualServiceError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualServiceError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualServiceError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualServiceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVirtualServiceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualServiceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVirtualServiceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6011,6 +6315,7 @@ impl AppMeshClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6026,6 +6331,7 @@ impl AppMeshClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AppMeshClient {
         AppMeshClient { client, region }
     }
@@ -6051,6 +6357,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6095,6 +6404,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateMesh");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6135,6 +6447,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6182,6 +6497,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateVirtualGateway");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6244,6 +6562,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateVirtualNode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6292,6 +6613,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateVirtualRouter");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6340,6 +6664,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("CreateVirtualService");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6377,6 +6704,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6415,6 +6745,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteMesh");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6448,6 +6781,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6487,6 +6823,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteVirtualGateway");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6529,6 +6868,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteVirtualNode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6571,6 +6913,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteVirtualRouter");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6609,6 +6954,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DeleteVirtualService");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6643,6 +6991,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeGatewayRoute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6677,6 +7028,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeMesh");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6716,6 +7070,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeRoute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6754,6 +7111,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeVirtualGateway");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6792,6 +7152,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeVirtualNode");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6830,6 +7193,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeVirtualRouter");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6868,6 +7234,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("DescribeVirtualService");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6907,6 +7276,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListGatewayRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6947,6 +7319,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListMeshes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6988,6 +7363,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7028,6 +7406,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tags";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7069,6 +7450,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListVirtualGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7112,6 +7496,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListVirtualNodes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7155,6 +7542,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListVirtualRouters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7198,6 +7588,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("ListVirtualServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7241,6 +7634,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7276,6 +7672,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/untag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7312,6 +7711,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7349,6 +7751,9 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateMesh");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7385,6 +7790,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateRoute");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7426,6 +7834,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateVirtualGateway");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7467,6 +7878,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateVirtualNode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7508,6 +7922,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateVirtualRouter");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7549,6 +7966,9 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation_name("UpdateVirtualService");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7577,3 +7997,538 @@ impl AppMesh for AppMeshClient {
         }
     }
 }
+
+/// A blocking client for the AWS App Mesh API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AppMeshClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppMeshBlockingClient {
+    client: AppMeshClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppMeshBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppMeshBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AppMeshBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppMeshBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppMeshBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppMeshBlockingClient {
+        AppMeshBlockingClient {
+            client: AppMeshClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a gateway route.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A gateway route is attached to a virtual gateway and routes traffic to an existing
+    /// virtual service. If a route matches a request, it can distribute traffic to a target virtual service.&lt;/p&gt;
+    /// &lt;p&gt;For more information about gateway routes, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/gateway-routes.html&quot;&gt;Gateway routes&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_gateway_route`.
+    pub fn create_gateway_route(
+        &self,
+        input: CreateGatewayRouteInput,
+    ) -> Result<CreateGatewayRouteOutput, RusotoError<CreateGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.create_gateway_route(input))
+    }
+
+    /// <p>Creates a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt; A service mesh is a logical boundary for network traffic between services that are
+    /// represented by resources within the mesh. After you create your service mesh, you can
+    /// create virtual services, virtual nodes, virtual routers, and routes to distribute traffic
+    /// between the applications in your mesh.&lt;/p&gt;
+    /// &lt;p&gt;For more information about service meshes, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/meshes.html&quot;&gt;Service meshes&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_mesh`.
+    pub fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> Result<CreateMeshOutput, RusotoError<CreateMeshError>> {
+        self.runtime.block_on(self.client.create_mesh(input))
+    }
+
+    /// <p>Creates a route that is associated with a virtual router.</p>
+    ///
+    /// <pre><code>     &lt;p&gt; You can route several different protocols and define a retry policy for a route.
+    /// Traffic can be routed to one or more virtual nodes.&lt;/p&gt;
+    /// &lt;p&gt;For more information about routes, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/routes.html&quot;&gt;Routes&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_route`.
+    pub fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> Result<CreateRouteOutput, RusotoError<CreateRouteError>> {
+        self.runtime.block_on(self.client.create_route(input))
+    }
+
+    /// <p>Creates a virtual gateway.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A virtual gateway allows resources outside your mesh to communicate to resources that
+    /// are inside your mesh. The virtual gateway represents an Envoy proxy running in an Amazon ECS
+    /// task, in a Kubernetes service, or on an Amazon EC2 instance. Unlike a virtual node, which
+    /// represents an Envoy running with an application, a virtual gateway represents Envoy deployed by itself.&lt;/p&gt;
+    /// &lt;p&gt;For more information about virtual gateways, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/virtual_gateways.html&quot;&gt;Virtual gateways&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_virtual_gateway`.
+    pub fn create_virtual_gateway(
+        &self,
+        input: CreateVirtualGatewayInput,
+    ) -> Result<CreateVirtualGatewayOutput, RusotoError<CreateVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_gateway(input))
+    }
+
+    /// <p>Creates a virtual node within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt; A virtual node acts as a logical pointer to a particular task group, such as an Amazon ECS
+    /// service or a Kubernetes deployment. When you create a virtual node, you can specify the
+    /// service discovery information for your task group, and whether the proxy running in a task
+    /// group will communicate with other proxies using Transport Layer Security (TLS).&lt;/p&gt;
+    /// &lt;p&gt;You define a &lt;code&gt;listener&lt;/code&gt; for any inbound traffic that your virtual node
+    /// expects. Any virtual service that your virtual node expects to communicate to is specified
+    /// as a &lt;code&gt;backend&lt;/code&gt;.&lt;/p&gt;
+    /// &lt;p&gt;The response metadata for your new virtual node contains the &lt;code&gt;arn&lt;/code&gt; that is
+    /// associated with the virtual node. Set this value (either the full ARN or the truncated
+    /// resource name: for example, &lt;code&gt;mesh/default/virtualNode/simpleapp&lt;/code&gt;) as the
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; environment variable for your task group&#39;s Envoy
+    /// proxy container in your task definition or pod spec. This is then mapped to the
+    /// &lt;code&gt;node.id&lt;/code&gt; and &lt;code&gt;node.cluster&lt;/code&gt; Envoy parameters.&lt;/p&gt;
+    /// &lt;note&gt;
+    /// &lt;p&gt;If you require your Envoy stats or tracing to use a different name, you can override
+    /// the &lt;code&gt;node.cluster&lt;/code&gt; value that is set by
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_NAME&lt;/code&gt; with the
+    /// &lt;code&gt;APPMESH_VIRTUAL_NODE_CLUSTER&lt;/code&gt; environment variable.&lt;/p&gt;
+    /// &lt;/note&gt;
+    /// &lt;p&gt;For more information about virtual nodes, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/virtual_nodes.html&quot;&gt;Virtual nodes&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_virtual_node`.
+    pub fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> Result<CreateVirtualNodeOutput, RusotoError<CreateVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_node(input))
+    }
+
+    /// <p>Creates a virtual router within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;Specify a &lt;code&gt;listener&lt;/code&gt; for any inbound traffic that your virtual router
+    /// receives. Create a virtual router for each protocol and port that you need to route.
+    /// Virtual routers handle traffic for one or more virtual services within your mesh. After you
+    /// create your virtual router, create and associate routes for your virtual router that direct
+    /// incoming requests to different virtual nodes.&lt;/p&gt;
+    /// &lt;p&gt;For more information about virtual routers, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/virtual_routers.html&quot;&gt;Virtual routers&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_virtual_router`.
+    pub fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> Result<CreateVirtualRouterOutput, RusotoError<CreateVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_router(input))
+    }
+
+    /// <p>Creates a virtual service within a service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;A virtual service is an abstraction of a real service that is provided by a virtual node
+    /// directly or indirectly by means of a virtual router. Dependent services call your virtual
+    /// service by its &lt;code&gt;virtualServiceName&lt;/code&gt;, and those requests are routed to the
+    /// virtual node or virtual router that is specified as the provider for the virtual
+    /// service.&lt;/p&gt;
+    /// &lt;p&gt;For more information about virtual services, see &lt;a href=&quot;https://docs.aws.amazon.com/app-mesh/latest/userguide/virtual_services.html&quot;&gt;Virtual services&lt;/a&gt;.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::create_virtual_service`.
+    pub fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> Result<CreateVirtualServiceOutput, RusotoError<CreateVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_service(input))
+    }
+
+    /// <p>Deletes an existing gateway route.</p>
+    ///
+    /// See `AppMesh::delete_gateway_route`.
+    pub fn delete_gateway_route(
+        &self,
+        input: DeleteGatewayRouteInput,
+    ) -> Result<DeleteGatewayRouteOutput, RusotoError<DeleteGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_gateway_route(input))
+    }
+
+    /// <p>Deletes an existing service mesh.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete all resources (virtual services, routes, virtual routers, and virtual
+    /// nodes) in the service mesh before you can delete the mesh itself.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::delete_mesh`.
+    pub fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> Result<DeleteMeshOutput, RusotoError<DeleteMeshError>> {
+        self.runtime.block_on(self.client.delete_mesh(input))
+    }
+
+    /// <p>Deletes an existing route.</p>
+    ///
+    /// See `AppMesh::delete_route`.
+    pub fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> Result<DeleteRouteOutput, RusotoError<DeleteRouteError>> {
+        self.runtime.block_on(self.client.delete_route(input))
+    }
+
+    /// <p>Deletes an existing virtual gateway. You cannot delete a virtual gateway if any gateway
+    /// routes are associated to it.</p>
+    ///
+    /// See `AppMesh::delete_virtual_gateway`.
+    pub fn delete_virtual_gateway(
+        &self,
+        input: DeleteVirtualGatewayInput,
+    ) -> Result<DeleteVirtualGatewayOutput, RusotoError<DeleteVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_gateway(input))
+    }
+
+    /// <p>Deletes an existing virtual node.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete any virtual services that list a virtual node as a service provider
+    /// before you can delete the virtual node itself.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::delete_virtual_node`.
+    pub fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> Result<DeleteVirtualNodeOutput, RusotoError<DeleteVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_node(input))
+    }
+
+    /// <p>Deletes an existing virtual router.</p>
+    ///
+    /// <pre><code>     &lt;p&gt;You must delete any routes associated with the virtual router before you can delete the
+    /// router itself.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `AppMesh::delete_virtual_router`.
+    pub fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> Result<DeleteVirtualRouterOutput, RusotoError<DeleteVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_router(input))
+    }
+
+    /// <p>Deletes an existing virtual service.</p>
+    ///
+    /// See `AppMesh::delete_virtual_service`.
+    pub fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> Result<DeleteVirtualServiceOutput, RusotoError<DeleteVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_service(input))
+    }
+
+    /// <p>Describes an existing gateway route.</p>
+    ///
+    /// See `AppMesh::describe_gateway_route`.
+    pub fn describe_gateway_route(
+        &self,
+        input: DescribeGatewayRouteInput,
+    ) -> Result<DescribeGatewayRouteOutput, RusotoError<DescribeGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.describe_gateway_route(input))
+    }
+
+    /// <p>Describes an existing service mesh.</p>
+    ///
+    /// See `AppMesh::describe_mesh`.
+    pub fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> Result<DescribeMeshOutput, RusotoError<DescribeMeshError>> {
+        self.runtime.block_on(self.client.describe_mesh(input))
+    }
+
+    /// <p>Describes an existing route.</p>
+    ///
+    /// See `AppMesh::describe_route`.
+    pub fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> Result<DescribeRouteOutput, RusotoError<DescribeRouteError>> {
+        self.runtime.block_on(self.client.describe_route(input))
+    }
+
+    /// <p>Describes an existing virtual gateway.</p>
+    ///
+    /// See `AppMesh::describe_virtual_gateway`.
+    pub fn describe_virtual_gateway(
+        &self,
+        input: DescribeVirtualGatewayInput,
+    ) -> Result<DescribeVirtualGatewayOutput, RusotoError<DescribeVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_gateway(input))
+    }
+
+    /// <p>Describes an existing virtual node.</p>
+    ///
+    /// See `AppMesh::describe_virtual_node`.
+    pub fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> Result<DescribeVirtualNodeOutput, RusotoError<DescribeVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_node(input))
+    }
+
+    /// <p>Describes an existing virtual router.</p>
+    ///
+    /// See `AppMesh::describe_virtual_router`.
+    pub fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> Result<DescribeVirtualRouterOutput, RusotoError<DescribeVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_router(input))
+    }
+
+    /// <p>Describes an existing virtual service.</p>
+    ///
+    /// See `AppMesh::describe_virtual_service`.
+    pub fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> Result<DescribeVirtualServiceOutput, RusotoError<DescribeVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_service(input))
+    }
+
+    /// <p>Returns a list of existing gateway routes that are associated to a virtual
+    /// gateway.</p>
+    ///
+    /// See `AppMesh::list_gateway_routes`.
+    pub fn list_gateway_routes(
+        &self,
+        input: ListGatewayRoutesInput,
+    ) -> Result<ListGatewayRoutesOutput, RusotoError<ListGatewayRoutesError>> {
+        self.runtime
+            .block_on(self.client.list_gateway_routes(input))
+    }
+
+    /// <p>Returns a list of existing service meshes.</p>
+    ///
+    /// See `AppMesh::list_meshes`.
+    pub fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> Result<ListMeshesOutput, RusotoError<ListMeshesError>> {
+        self.runtime.block_on(self.client.list_meshes(input))
+    }
+
+    /// <p>Returns a list of existing routes in a service mesh.</p>
+    ///
+    /// See `AppMesh::list_routes`.
+    pub fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> Result<ListRoutesOutput, RusotoError<ListRoutesError>> {
+        self.runtime.block_on(self.client.list_routes(input))
+    }
+
+    /// <p>List the tags for an App Mesh resource.</p>
+    ///
+    /// See `AppMesh::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a list of existing virtual gateways in a service mesh.</p>
+    ///
+    /// See `AppMesh::list_virtual_gateways`.
+    pub fn list_virtual_gateways(
+        &self,
+        input: ListVirtualGatewaysInput,
+    ) -> Result<ListVirtualGatewaysOutput, RusotoError<ListVirtualGatewaysError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_gateways(input))
+    }
+
+    /// <p>Returns a list of existing virtual nodes.</p>
+    ///
+    /// See `AppMesh::list_virtual_nodes`.
+    pub fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> Result<ListVirtualNodesOutput, RusotoError<ListVirtualNodesError>> {
+        self.runtime.block_on(self.client.list_virtual_nodes(input))
+    }
+
+    /// <p>Returns a list of existing virtual routers in a service mesh.</p>
+    ///
+    /// See `AppMesh::list_virtual_routers`.
+    pub fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> Result<ListVirtualRoutersOutput, RusotoError<ListVirtualRoutersError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_routers(input))
+    }
+
+    /// <p>Returns a list of existing virtual services in a service mesh.</p>
+    ///
+    /// See `AppMesh::list_virtual_services`.
+    pub fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> Result<ListVirtualServicesOutput, RusotoError<ListVirtualServicesError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_services(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>.
+    /// If existing tags on a resource aren't specified in the request parameters, they aren't
+    /// changed. When a resource is deleted, the tags associated with that resource are also
+    /// deleted.</p>
+    ///
+    /// See `AppMesh::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    ///
+    /// See `AppMesh::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an existing gateway route that is associated to a specified virtual gateway in a
+    /// service mesh.</p>
+    ///
+    /// See `AppMesh::update_gateway_route`.
+    pub fn update_gateway_route(
+        &self,
+        input: UpdateGatewayRouteInput,
+    ) -> Result<UpdateGatewayRouteOutput, RusotoError<UpdateGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_route(input))
+    }
+
+    /// <p>Updates an existing service mesh.</p>
+    ///
+    /// See `AppMesh::update_mesh`.
+    pub fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> Result<UpdateMeshOutput, RusotoError<UpdateMeshError>> {
+        self.runtime.block_on(self.client.update_mesh(input))
+    }
+
+    /// <p>Updates an existing route for a specified service mesh and virtual router.</p>
+    ///
+    /// See `AppMesh::update_route`.
+    pub fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> Result<UpdateRouteOutput, RusotoError<UpdateRouteError>> {
+        self.runtime.block_on(self.client.update_route(input))
+    }
+
+    /// <p>Updates an existing virtual gateway in a specified service mesh.</p>
+    ///
+    /// See `AppMesh::update_virtual_gateway`.
+    pub fn update_virtual_gateway(
+        &self,
+        input: UpdateVirtualGatewayInput,
+    ) -> Result<UpdateVirtualGatewayOutput, RusotoError<UpdateVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_gateway(input))
+    }
+
+    /// <p>Updates an existing virtual node in a specified service mesh.</p>
+    ///
+    /// See `AppMesh::update_virtual_node`.
+    pub fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> Result<UpdateVirtualNodeOutput, RusotoError<UpdateVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_node(input))
+    }
+
+    /// <p>Updates an existing virtual router in a specified service mesh.</p>
+    ///
+    /// See `AppMesh::update_virtual_router`.
+    pub fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> Result<UpdateVirtualRouterOutput, RusotoError<UpdateVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_router(input))
+    }
+
+    /// <p>Updates an existing virtual service in a specified service mesh.</p>
+    ///
+    /// See `AppMesh::update_virtual_service`.
+    pub fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> Result<UpdateVirtualServiceOutput, RusotoError<UpdateVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_service(input))
+    }
+}
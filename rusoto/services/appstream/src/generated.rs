@@ -2028,26 +2028,37 @@ impl AssociateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AssociateFleetError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(AssociateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateFleetError::IncompatibleImage(err.msg),
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(AssociateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateFleetError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AssociateFleetError::LimitExceeded(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(AssociateFleetError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateFleetError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateFleetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2082,7 +2093,8 @@ impl BatchAssociateUserStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchAssociateUserStackError::OperationNotPermitted(err.msg),
                     )
                 }
@@ -2150,22 +2162,31 @@ impl CopyImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CopyImageError::IncompatibleImage(err.msg))
+                    return RusotoError::service(&res, CopyImageError::IncompatibleImage(err.msg))
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CopyImageError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyImageError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CopyImageError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CopyImageError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CopyImageError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyImageError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CopyImageError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyImageError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CopyImageError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2204,17 +2225,22 @@ impl CreateDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::InvalidAccountStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryConfigError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryConfigError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryConfigError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2264,36 +2290,52 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CreateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::IncompatibleImage(err.msg))
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::InvalidRole(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::LimitExceeded(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2350,48 +2392,64 @@ impl CreateImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CreateImageBuilderError::IncompatibleImage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::IncompatibleImage(err.msg),
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateImageBuilderError::InvalidAccountStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateImageBuilderError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateImageBuilderError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::InvalidRole(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateImageBuilderError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::LimitExceeded(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateImageBuilderError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageBuilderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2436,12 +2494,14 @@ impl CreateImageBuilderStreamingURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateImageBuilderStreamingURLError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateImageBuilderStreamingURLError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2490,27 +2550,37 @@ impl CreateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStackError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateStackError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStackError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateStackError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStackError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateStackError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, CreateStackError::InvalidRole(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStackError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateStackError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateStackError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStackError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2552,22 +2622,28 @@ impl CreateStreamingURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStreamingURLError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateStreamingURLError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamingURLError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateStreamingURLError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamingURLError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStreamingURLError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamingURLError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2608,19 +2684,22 @@ impl CreateUsageReportSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUsageReportSubscriptionError::InvalidAccountStatus(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateUsageReportSubscriptionError::InvalidRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUsageReportSubscriptionError::InvalidRole(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUsageReportSubscriptionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUsageReportSubscriptionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2662,21 +2741,31 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateUserError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateUserError::LimitExceeded(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateUserError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2712,12 +2801,16 @@ impl DeleteDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDirectoryConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryConfigError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDirectoryConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2752,13 +2845,16 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2796,16 +2892,22 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteImageError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageError::ConcurrentModification(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteImageError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteImageError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteImageError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2842,17 +2944,22 @@ impl DeleteImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageBuilderError::ConcurrentModification(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageBuilderError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageBuilderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2886,14 +2993,16 @@ impl DeleteImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(DeleteImagePermissionsError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImagePermissionsError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImagePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImagePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2928,13 +3037,16 @@ impl DeleteStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStackError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteStackError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2970,12 +3082,14 @@ impl DeleteUsageReportSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteUsageReportSubscriptionError::InvalidAccountStatus(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteUsageReportSubscriptionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3012,7 +3126,7 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3042,9 +3156,10 @@ impl DescribeDirectoryConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDirectoryConfigsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDirectoryConfigsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3074,7 +3189,10 @@ impl DescribeFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3104,9 +3222,10 @@ impl DescribeImageBuildersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImageBuildersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImageBuildersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3136,9 +3255,10 @@ impl DescribeImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImagePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImagePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3170,12 +3290,16 @@ impl DescribeImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeImagesError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImagesError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImagesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3206,7 +3330,8 @@ impl DescribeSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSessionsError::InvalidParameterCombination(err.msg),
                     )
                 }
@@ -3238,7 +3363,10 @@ impl DescribeStacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStacksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3272,12 +3400,14 @@ impl DescribeUsageReportSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUsageReportSubscriptionsError::InvalidAccountStatus(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUsageReportSubscriptionsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3316,7 +3446,8 @@ impl DescribeUserStackAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUserStackAssociationsError::InvalidParameterCombination(err.msg),
                     )
                 }
@@ -3352,12 +3483,16 @@ impl DescribeUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeUsersError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3388,7 +3523,7 @@ impl DisableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DisableUserError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3422,15 +3557,22 @@ impl DisassociateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisassociateFleetError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DisassociateFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFleetError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFleetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3464,10 +3606,13 @@ impl EnableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(EnableUserError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableUserError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, EnableUserError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3564,9 +3709,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3608,25 +3754,37 @@ impl StartFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(StartFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(StartFleetError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, StartFleetError::InvalidRole(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartFleetError::LimitExceeded(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(StartFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartFleetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3670,25 +3828,34 @@ impl StartImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImageBuilderError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(StartImageBuilderError::IncompatibleImage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImageBuilderError::IncompatibleImage(err.msg),
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(StartImageBuilderError::InvalidAccountStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImageBuilderError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(StartImageBuilderError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImageBuilderError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImageBuilderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3724,10 +3891,13 @@ impl StopFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopFleetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3762,17 +3932,22 @@ impl StopImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopImageBuilderError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopImageBuilderError::ConcurrentModification(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopImageBuilderError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopImageBuilderError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopImageBuilderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3808,13 +3983,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(TagResourceError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3846,7 +4024,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3880,17 +4061,22 @@ impl UpdateDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDirectoryConfigError::ConcurrentModification(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDirectoryConfigError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDirectoryConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3940,36 +4126,49 @@ impl UpdateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(UpdateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service(&res, UpdateFleetError::IncompatibleImage(err.msg))
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, UpdateFleetError::InvalidRole(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateFleetError::LimitExceeded(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateFleetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFleetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4012,19 +4211,22 @@ impl UpdateImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePermissionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::ResourceNotAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePermissionsError::ResourceNotAvailable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4072,33 +4274,43 @@ impl UpdateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStackError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(UpdateStackError::IncompatibleImage(err.msg))
+                    return RusotoError::service(&res, UpdateStackError::IncompatibleImage(err.msg))
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStackError::InvalidAccountStatus(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStackError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, UpdateStackError::InvalidRole(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateStackError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateStackError::LimitExceeded(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateStackError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStackError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateStackError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4431,6 +4643,7 @@ impl AppStreamClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4446,6 +4659,7 @@ impl AppStreamClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AppStreamClient {
         AppStreamClient { client, region }
     }
@@ -4459,6 +4673,9 @@ impl AppStream for AppStreamClient {
         input: AssociateFleetRequest,
     ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.AssociateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4477,6 +4694,9 @@ impl AppStream for AppStreamClient {
         input: BatchAssociateUserStackRequest,
     ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchAssociateUserStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.BatchAssociateUserStack",
@@ -4500,6 +4720,9 @@ impl AppStream for AppStreamClient {
     ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDisassociateUserStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.BatchDisassociateUserStack",
@@ -4522,6 +4745,9 @@ impl AppStream for AppStreamClient {
         input: CopyImageRequest,
     ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CopyImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.CopyImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4540,6 +4766,9 @@ impl AppStream for AppStreamClient {
         input: CreateDirectoryConfigRequest,
     ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDirectoryConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateDirectoryConfig",
@@ -4561,6 +4790,9 @@ impl AppStream for AppStreamClient {
         input: CreateFleetRequest,
     ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4579,6 +4811,9 @@ impl AppStream for AppStreamClient {
         input: CreateImageBuilderRequest,
     ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateImageBuilder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4600,6 +4835,9 @@ impl AppStream for AppStreamClient {
         RusotoError<CreateImageBuilderStreamingURLError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateImageBuilderStreamingURL");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateImageBuilderStreamingURL",
@@ -4622,6 +4860,9 @@ impl AppStream for AppStreamClient {
         input: CreateStackRequest,
     ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4640,6 +4881,9 @@ impl AppStream for AppStreamClient {
         input: CreateStreamingURLRequest,
     ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStreamingURL");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStreamingURL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4658,6 +4902,9 @@ impl AppStream for AppStreamClient {
     ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUsageReportSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateUsageReportSubscription",
@@ -4679,6 +4926,9 @@ impl AppStream for AppStreamClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4697,6 +4947,9 @@ impl AppStream for AppStreamClient {
         input: DeleteDirectoryConfigRequest,
     ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDirectoryConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DeleteDirectoryConfig",
@@ -4718,6 +4971,9 @@ impl AppStream for AppStreamClient {
         input: DeleteFleetRequest,
     ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4736,6 +4992,9 @@ impl AppStream for AppStreamClient {
         input: DeleteImageRequest,
     ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4754,6 +5013,9 @@ impl AppStream for AppStreamClient {
         input: DeleteImageBuilderRequest,
     ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteImageBuilder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4772,6 +5034,9 @@ impl AppStream for AppStreamClient {
         input: DeleteImagePermissionsRequest,
     ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteImagePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DeleteImagePermissions",
@@ -4794,6 +5059,9 @@ impl AppStream for AppStreamClient {
         input: DeleteStackRequest,
     ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4812,6 +5080,9 @@ impl AppStream for AppStreamClient {
     ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUsageReportSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DeleteUsageReportSubscription",
@@ -4833,6 +5104,9 @@ impl AppStream for AppStreamClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4851,6 +5125,9 @@ impl AppStream for AppStreamClient {
         input: DescribeDirectoryConfigsRequest,
     ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDirectoryConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeDirectoryConfigs",
@@ -4873,6 +5150,9 @@ impl AppStream for AppStreamClient {
         input: DescribeFleetsRequest,
     ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4891,6 +5171,9 @@ impl AppStream for AppStreamClient {
         input: DescribeImageBuildersRequest,
     ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeImageBuilders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeImageBuilders",
@@ -4912,6 +5195,9 @@ impl AppStream for AppStreamClient {
         input: DescribeImagePermissionsRequest,
     ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeImagePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeImagePermissions",
@@ -4934,6 +5220,9 @@ impl AppStream for AppStreamClient {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4952,6 +5241,9 @@ impl AppStream for AppStreamClient {
         input: DescribeSessionsRequest,
     ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4970,6 +5262,9 @@ impl AppStream for AppStreamClient {
         input: DescribeStacksRequest,
     ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeStacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4991,6 +5286,9 @@ impl AppStream for AppStreamClient {
         RusotoError<DescribeUsageReportSubscriptionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUsageReportSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeUsageReportSubscriptions",
@@ -5017,6 +5315,9 @@ impl AppStream for AppStreamClient {
     ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserStackAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeUserStackAssociations",
@@ -5039,6 +5340,9 @@ impl AppStream for AppStreamClient {
         input: DescribeUsersRequest,
     ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5057,6 +5361,9 @@ impl AppStream for AppStreamClient {
         input: DisableUserRequest,
     ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisableUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5075,6 +5382,9 @@ impl AppStream for AppStreamClient {
         input: DisassociateFleetRequest,
     ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisassociateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5093,6 +5403,9 @@ impl AppStream for AppStreamClient {
         input: EnableUserRequest,
     ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.EnableUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5111,6 +5424,9 @@ impl AppStream for AppStreamClient {
         input: ExpireSessionRequest,
     ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExpireSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.ExpireSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5129,6 +5445,9 @@ impl AppStream for AppStreamClient {
         input: ListAssociatedFleetsRequest,
     ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssociatedFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.ListAssociatedFleets",
@@ -5150,6 +5469,9 @@ impl AppStream for AppStreamClient {
         input: ListAssociatedStacksRequest,
     ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssociatedStacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.ListAssociatedStacks",
@@ -5171,6 +5493,9 @@ impl AppStream for AppStreamClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.ListTagsForResource",
@@ -5192,6 +5517,9 @@ impl AppStream for AppStreamClient {
         input: StartFleetRequest,
     ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5210,6 +5538,9 @@ impl AppStream for AppStreamClient {
         input: StartImageBuilderRequest,
     ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartImageBuilder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5228,6 +5559,9 @@ impl AppStream for AppStreamClient {
         input: StopFleetRequest,
     ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5246,6 +5580,9 @@ impl AppStream for AppStreamClient {
         input: StopImageBuilderRequest,
     ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopImageBuilder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5264,6 +5601,9 @@ impl AppStream for AppStreamClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5282,6 +5622,9 @@ impl AppStream for AppStreamClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5300,6 +5643,9 @@ impl AppStream for AppStreamClient {
         input: UpdateDirectoryConfigRequest,
     ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDirectoryConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.UpdateDirectoryConfig",
@@ -5321,6 +5667,9 @@ impl AppStream for AppStreamClient {
         input: UpdateFleetRequest,
     ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5339,6 +5688,9 @@ impl AppStream for AppStreamClient {
         input: UpdateImagePermissionsRequest,
     ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateImagePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.UpdateImagePermissions",
@@ -5361,6 +5713,9 @@ impl AppStream for AppStreamClient {
         input: UpdateStackRequest,
     ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5373,3 +5728,553 @@ impl AppStream for AppStreamClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateStackResult, _>()
     }
 }
+
+/// A blocking client for the Amazon AppStream API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AppStreamClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppStreamBlockingClient {
+    client: AppStreamClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppStreamBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppStreamBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AppStreamBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppStreamBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppStreamBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppStreamBlockingClient {
+        AppStreamBlockingClient {
+            client: AppStreamClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates the specified fleet with the specified stack.</p>
+    ///
+    /// See `AppStream::associate_fleet`.
+    pub fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
+        self.runtime.block_on(self.client.associate_fleet(input))
+    }
+
+    /// <p>Associates the specified users with the specified stacks. Users in a user pool cannot be assigned to stacks with fleets that are joined to an Active Directory domain.</p>
+    ///
+    /// See `AppStream::batch_associate_user_stack`.
+    pub fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
+        self.runtime
+            .block_on(self.client.batch_associate_user_stack(input))
+    }
+
+    /// <p>Disassociates the specified users from the specified stacks.</p>
+    ///
+    /// See `AppStream::batch_disassociate_user_stack`.
+    pub fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_disassociate_user_stack(input))
+    }
+
+    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
+    ///
+    /// See `AppStream::copy_image`.
+    pub fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
+        self.runtime.block_on(self.client.copy_image(input))
+    }
+
+    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    ///
+    /// See `AppStream::create_directory_config`.
+    pub fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
+        self.runtime
+            .block_on(self.client.create_directory_config(input))
+    }
+
+    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
+    ///
+    /// See `AppStream::create_fleet`.
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
+    ///
+    /// See `AppStream::create_image_builder`.
+    pub fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
+        self.runtime
+            .block_on(self.client.create_image_builder(input))
+    }
+
+    /// <p>Creates a URL to start an image builder streaming session.</p>
+    ///
+    /// See `AppStream::create_image_builder_streaming_url`.
+    pub fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> Result<
+        CreateImageBuilderStreamingURLResult,
+        RusotoError<CreateImageBuilderStreamingURLError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_image_builder_streaming_url(input))
+    }
+
+    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
+    ///
+    /// See `AppStream::create_stack`.
+    pub fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+        self.runtime.block_on(self.client.create_stack(input))
+    }
+
+    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
+    ///
+    /// See `AppStream::create_streaming_url`.
+    pub fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
+        self.runtime
+            .block_on(self.client.create_streaming_url(input))
+    }
+
+    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
+    ///
+    /// See `AppStream::create_usage_report_subscription`.
+    pub fn create_usage_report_subscription(
+        &self,
+    ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_usage_report_subscription())
+    }
+
+    /// <p>Creates a new user in the user pool.</p>
+    ///
+    /// See `AppStream::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
+    ///
+    /// See `AppStream::delete_directory_config`.
+    pub fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_directory_config(input))
+    }
+
+    /// <p>Deletes the specified fleet.</p>
+    ///
+    /// See `AppStream::delete_fleet`.
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
+    ///
+    /// See `AppStream::delete_image`.
+    pub fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
+        self.runtime.block_on(self.client.delete_image(input))
+    }
+
+    /// <p>Deletes the specified image builder and releases the capacity.</p>
+    ///
+    /// See `AppStream::delete_image_builder`.
+    pub fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
+        self.runtime
+            .block_on(self.client.delete_image_builder(input))
+    }
+
+    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
+    ///
+    /// See `AppStream::delete_image_permissions`.
+    pub fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
+        self.runtime
+            .block_on(self.client.delete_image_permissions(input))
+    }
+
+    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
+    ///
+    /// See `AppStream::delete_stack`.
+    pub fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
+        self.runtime.block_on(self.client.delete_stack(input))
+    }
+
+    /// <p>Disables usage report generation.</p>
+    ///
+    /// See `AppStream::delete_usage_report_subscription`.
+    pub fn delete_usage_report_subscription(
+        &self,
+    ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_usage_report_subscription())
+    }
+
+    /// <p>Deletes a user from the user pool.</p>
+    ///
+    /// See `AppStream::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
+    ///
+    /// See `AppStream::describe_directory_configs`.
+    pub fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
+        self.runtime
+            .block_on(self.client.describe_directory_configs(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
+    ///
+    /// See `AppStream::describe_fleets`.
+    pub fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
+        self.runtime.block_on(self.client.describe_fleets(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
+    ///
+    /// See `AppStream::describe_image_builders`.
+    pub fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
+        self.runtime
+            .block_on(self.client.describe_image_builders(input))
+    }
+
+    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
+    ///
+    /// See `AppStream::describe_image_permissions`.
+    pub fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
+        self.runtime
+            .block_on(self.client.describe_image_permissions(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
+    ///
+    /// See `AppStream::describe_images`.
+    pub fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
+        self.runtime.block_on(self.client.describe_images(input))
+    }
+
+    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
+    ///
+    /// See `AppStream::describe_sessions`.
+    pub fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
+        self.runtime.block_on(self.client.describe_sessions(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
+    ///
+    /// See `AppStream::describe_stacks`.
+    pub fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+        self.runtime.block_on(self.client.describe_stacks(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
+    ///
+    /// See `AppStream::describe_usage_report_subscriptions`.
+    pub fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> Result<
+        DescribeUsageReportSubscriptionsResult,
+        RusotoError<DescribeUsageReportSubscriptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_usage_report_subscriptions(input))
+    }
+
+    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
+    ///
+    /// See `AppStream::describe_user_stack_associations`.
+    pub fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_user_stack_associations(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
+    ///
+    /// See `AppStream::describe_users`.
+    pub fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
+        self.runtime.block_on(self.client.describe_users(input))
+    }
+
+    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
+    ///
+    /// See `AppStream::disable_user`.
+    pub fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
+        self.runtime.block_on(self.client.disable_user(input))
+    }
+
+    /// <p>Disassociates the specified fleet from the specified stack.</p>
+    ///
+    /// See `AppStream::disassociate_fleet`.
+    pub fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
+        self.runtime.block_on(self.client.disassociate_fleet(input))
+    }
+
+    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
+    ///
+    /// See `AppStream::enable_user`.
+    pub fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
+        self.runtime.block_on(self.client.enable_user(input))
+    }
+
+    /// <p>Immediately stops the specified streaming session.</p>
+    ///
+    /// See `AppStream::expire_session`.
+    pub fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
+        self.runtime.block_on(self.client.expire_session(input))
+    }
+
+    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
+    ///
+    /// See `AppStream::list_associated_fleets`.
+    pub fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
+        self.runtime
+            .block_on(self.client.list_associated_fleets(input))
+    }
+
+    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
+    ///
+    /// See `AppStream::list_associated_stacks`.
+    pub fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
+        self.runtime
+            .block_on(self.client.list_associated_stacks(input))
+    }
+
+    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    ///
+    /// See `AppStream::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Starts the specified fleet.</p>
+    ///
+    /// See `AppStream::start_fleet`.
+    pub fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
+        self.runtime.block_on(self.client.start_fleet(input))
+    }
+
+    /// <p>Starts the specified image builder.</p>
+    ///
+    /// See `AppStream::start_image_builder`.
+    pub fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
+        self.runtime
+            .block_on(self.client.start_image_builder(input))
+    }
+
+    /// <p>Stops the specified fleet.</p>
+    ///
+    /// See `AppStream::stop_fleet`.
+    pub fn stop_fleet(
+        &self,
+        input: StopFleetRequest,
+    ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
+        self.runtime.block_on(self.client.stop_fleet(input))
+    }
+
+    /// <p>Stops the specified image builder.</p>
+    ///
+    /// See `AppStream::stop_image_builder`.
+    pub fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
+        self.runtime.block_on(self.client.stop_image_builder(input))
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    ///
+    /// See `AppStream::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    ///
+    /// See `AppStream::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    ///
+    /// See `AppStream::update_directory_config`.
+    pub fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
+        self.runtime
+            .block_on(self.client.update_directory_config(input))
+    }
+
+    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
+    ///
+    /// See `AppStream::update_fleet`.
+    pub fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
+        self.runtime.block_on(self.client.update_fleet(input))
+    }
+
+    /// <p>Adds or updates permissions for the specified private image. </p>
+    ///
+    /// See `AppStream::update_image_permissions`.
+    pub fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
+        self.runtime
+            .block_on(self.client.update_image_permissions(input))
+    }
+
+    /// <p>Updates the specified fields for the specified stack.</p>
+    ///
+    /// See `AppStream::update_stack`.
+    pub fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
+        self.runtime.block_on(self.client.update_stack(input))
+    }
+}
@@ -1664,21 +1664,25 @@ impl CreateApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApiCacheError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApiCacheError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApiCacheError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateApiCacheError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateApiCacheError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1724,27 +1728,31 @@ impl CreateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiKeyLimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::ApiKeyLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApiKeyError::ApiKeyLimitExceeded(err.msg),
+                    )
                 }
                 "ApiKeyValidityOutOfBoundsException" => {
-                    return RusotoError::Service(CreateApiKeyError::ApiKeyValidityOutOfBounds(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApiKeyError::ApiKeyValidityOutOfBounds(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1788,21 +1796,25 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1842,18 +1854,22 @@ impl CreateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateFunctionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateFunctionError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateFunctionError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1896,24 +1912,34 @@ impl CreateGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiLimitExceededException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::ApiLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGraphqlApiError::ApiLimitExceeded(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateGraphqlApiError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGraphqlApiError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGraphqlApiError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGraphqlApiError::LimitExceeded(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateGraphqlApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1954,18 +1980,22 @@ impl CreateResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateResolverError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateResolverError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateResolverError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateResolverError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateResolverError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2006,19 +2036,22 @@ impl CreateTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateTypeError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateTypeError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateTypeError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2060,21 +2093,25 @@ impl DeleteApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteApiCacheError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApiCacheError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApiCacheError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteApiCacheError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteApiCacheError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2114,16 +2151,16 @@ impl DeleteApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2164,21 +2201,25 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2218,18 +2259,22 @@ impl DeleteFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFunctionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFunctionError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteFunctionError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2272,24 +2317,28 @@ impl DeleteGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteGraphqlApiError::AccessDenied(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteGraphqlApiError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGraphqlApiError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGraphqlApiError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteGraphqlApiError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteGraphqlApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2330,18 +2379,22 @@ impl DeleteResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteResolverError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteResolverError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResolverError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteResolverError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteResolverError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2382,19 +2435,22 @@ impl DeleteTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteTypeError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteTypeError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTypeError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2436,21 +2492,22 @@ impl FlushApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(&res, FlushApiCacheError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(FlushApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FlushApiCacheError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(FlushApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, FlushApiCacheError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(&res, FlushApiCacheError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, FlushApiCacheError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2492,19 +2549,22 @@ impl GetApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApiCacheError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetApiCacheError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApiCacheError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetApiCacheError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApiCacheError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetApiCacheError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2546,21 +2606,22 @@ impl GetDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDataSourceError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDataSourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetDataSourceError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDataSourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetDataSourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2598,13 +2659,16 @@ impl GetFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetFunctionError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetFunctionError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetFunctionError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetFunctionError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2644,19 +2708,19 @@ impl GetGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetGraphqlApiError::AccessDenied(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetGraphqlApiError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetGraphqlApiError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGraphqlApiError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetGraphqlApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2696,20 +2760,28 @@ impl GetIntrospectionSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "GraphQLSchemaException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::GraphQLSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIntrospectionSchemaError::GraphQLSchema(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIntrospectionSchemaError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntrospectionSchemaError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntrospectionSchemaError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2746,13 +2818,16 @@ impl GetResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetResolverError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResolverError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetResolverError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetResolverError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2790,20 +2865,28 @@ impl GetSchemaCreationStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaCreationStatusError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaCreationStatusError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaCreationStatusError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaCreationStatusError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2844,19 +2927,22 @@ impl GetTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetTypeError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTypeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetTypeError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTypeError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2896,16 +2982,16 @@ impl ListApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListApiKeysError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListApiKeysError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListApiKeysError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApiKeysError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListApiKeysError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListApiKeysError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2944,16 +3030,19 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDataSourcesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourcesError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDataSourcesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDataSourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2992,16 +3081,16 @@ impl ListFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFunctionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListFunctionsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListFunctionsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListFunctionsError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListFunctionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListFunctionsError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFunctionsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListFunctionsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3038,13 +3127,16 @@ impl ListGraphqlApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGraphqlApisError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListGraphqlApisError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGraphqlApisError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGraphqlApisError::InternalFailure(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGraphqlApisError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListGraphqlApisError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3082,16 +3174,16 @@ impl ListResolversError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResolversError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListResolversError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListResolversError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListResolversError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResolversError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListResolversError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListResolversError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListResolversError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3130,20 +3222,28 @@ impl ListResolversByFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResolversByFunctionError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolversByFunctionError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResolversByFunctionError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolversByFunctionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3186,22 +3286,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3244,19 +3359,22 @@ impl ListTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTypesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListTypesError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTypesError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTypesError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListTypesError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTypesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTypesError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTypesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListTypesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3298,21 +3416,31 @@ impl StartSchemaCreationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartSchemaCreationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaCreationError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartSchemaCreationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaCreationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartSchemaCreationError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaCreationError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartSchemaCreationError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartSchemaCreationError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartSchemaCreationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaCreationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3356,22 +3484,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3416,22 +3544,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3474,21 +3602,25 @@ impl UpdateApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApiCacheError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApiCacheError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApiCacheError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApiCacheError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiCacheError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateApiCacheError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateApiCacheError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3532,24 +3664,25 @@ impl UpdateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiKeyValidityOutOfBoundsException" => {
-                    return RusotoError::Service(UpdateApiKeyError::ApiKeyValidityOutOfBounds(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApiKeyError::ApiKeyValidityOutOfBounds(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateApiKeyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3592,21 +3725,25 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3646,18 +3783,22 @@ impl UpdateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFunctionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFunctionError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFunctionError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateFunctionError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3700,24 +3841,28 @@ impl UpdateGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateGraphqlApiError::AccessDenied(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateGraphqlApiError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGraphqlApiError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGraphqlApiError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGraphqlApiError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateGraphqlApiError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3758,18 +3903,22 @@ impl UpdateResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateResolverError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateResolverError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateResolverError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateResolverError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateResolverError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateResolverError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3810,19 +3959,22 @@ impl UpdateTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateTypeError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateTypeError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTypeError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4111,6 +4263,7 @@ impl AppSyncClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4126,6 +4279,7 @@ impl AppSyncClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AppSyncClient {
         AppSyncClient { client, region }
     }
@@ -4142,6 +4296,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateApiCache");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4173,6 +4330,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateApiKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4204,6 +4364,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4235,6 +4398,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateFunction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4266,6 +4432,9 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateGraphqlApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4301,6 +4470,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateResolver");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4332,6 +4504,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("CreateType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4363,6 +4538,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteApiCache");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4395,6 +4573,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteApiKey");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4427,6 +4608,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4459,6 +4643,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteFunction");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4487,6 +4674,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteGraphqlApi");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4520,6 +4710,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteResolver");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4552,6 +4745,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("DeleteType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4580,6 +4776,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/FlushCache", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("FlushApiCache");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4608,6 +4807,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetApiCache");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4640,6 +4842,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetDataSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4672,6 +4877,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetFunction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4700,6 +4908,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetGraphqlApi");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4728,6 +4939,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetIntrospectionSchema");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4769,6 +4983,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetResolver");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4797,6 +5014,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetSchemaCreationStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4829,6 +5049,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("GetType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4861,6 +5084,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListApiKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4898,6 +5124,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListDataSources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4935,6 +5164,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListFunctions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4972,6 +5204,9 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListGraphqlApis");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5013,6 +5248,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListResolvers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5054,6 +5292,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListResolversByFunction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5091,6 +5332,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5119,6 +5363,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation_name("ListTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5157,6 +5404,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("StartSchemaCreation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5188,6 +5438,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5219,6 +5472,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5253,6 +5509,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches/update", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateApiCache");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5288,6 +5547,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateApiKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5323,6 +5585,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5358,6 +5623,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateFunction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5389,6 +5657,9 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateGraphqlApi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5425,6 +5696,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateResolver");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5460,6 +5734,9 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation_name("UpdateType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5482,3 +5759,468 @@ impl AppSync for AppSyncClient {
         }
     }
 }
+
+/// A blocking client for the AWSAppSync API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AppSyncClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppSyncBlockingClient {
+    client: AppSyncClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppSyncBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AppSyncBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AppSyncBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppSyncBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppSyncBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppSyncBlockingClient {
+        AppSyncBlockingClient {
+            client: AppSyncClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a cache for the GraphQL API.</p>
+    ///
+    /// See `AppSync::create_api_cache`.
+    pub fn create_api_cache(
+        &self,
+        input: CreateApiCacheRequest,
+    ) -> Result<CreateApiCacheResponse, RusotoError<CreateApiCacheError>> {
+        self.runtime.block_on(self.client.create_api_cache(input))
+    }
+
+    /// <p>Creates a unique key that you can distribute to clients who are executing your API.</p>
+    ///
+    /// See `AppSync::create_api_key`.
+    pub fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<CreateApiKeyResponse, RusotoError<CreateApiKeyError>> {
+        self.runtime.block_on(self.client.create_api_key(input))
+    }
+
+    /// <p>Creates a <code>DataSource</code> object.</p>
+    ///
+    /// See `AppSync::create_data_source`.
+    pub fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.runtime.block_on(self.client.create_data_source(input))
+    }
+
+    /// <p>Creates a <code>Function</code> object.</p> <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
+    ///
+    /// See `AppSync::create_function`.
+    pub fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<CreateFunctionResponse, RusotoError<CreateFunctionError>> {
+        self.runtime.block_on(self.client.create_function(input))
+    }
+
+    /// <p>Creates a <code>GraphqlApi</code> object.</p>
+    ///
+    /// See `AppSync::create_graphql_api`.
+    pub fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> Result<CreateGraphqlApiResponse, RusotoError<CreateGraphqlApiError>> {
+        self.runtime.block_on(self.client.create_graphql_api(input))
+    }
+
+    /// <p>Creates a <code>Resolver</code> object.</p> <p>A resolver converts incoming requests into a format that a data source can understand and converts the data source's responses into GraphQL.</p>
+    ///
+    /// See `AppSync::create_resolver`.
+    pub fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> Result<CreateResolverResponse, RusotoError<CreateResolverError>> {
+        self.runtime.block_on(self.client.create_resolver(input))
+    }
+
+    /// <p>Creates a <code>Type</code> object.</p>
+    ///
+    /// See `AppSync::create_type`.
+    pub fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> Result<CreateTypeResponse, RusotoError<CreateTypeError>> {
+        self.runtime.block_on(self.client.create_type(input))
+    }
+
+    /// <p>Deletes an <code>ApiCache</code> object.</p>
+    ///
+    /// See `AppSync::delete_api_cache`.
+    pub fn delete_api_cache(
+        &self,
+        input: DeleteApiCacheRequest,
+    ) -> Result<DeleteApiCacheResponse, RusotoError<DeleteApiCacheError>> {
+        self.runtime.block_on(self.client.delete_api_cache(input))
+    }
+
+    /// <p>Deletes an API key.</p>
+    ///
+    /// See `AppSync::delete_api_key`.
+    pub fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<DeleteApiKeyResponse, RusotoError<DeleteApiKeyError>> {
+        self.runtime.block_on(self.client.delete_api_key(input))
+    }
+
+    /// <p>Deletes a <code>DataSource</code> object.</p>
+    ///
+    /// See `AppSync::delete_data_source`.
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// <p>Deletes a <code>Function</code>.</p>
+    ///
+    /// See `AppSync::delete_function`.
+    pub fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<DeleteFunctionResponse, RusotoError<DeleteFunctionError>> {
+        self.runtime.block_on(self.client.delete_function(input))
+    }
+
+    /// <p>Deletes a <code>GraphqlApi</code> object.</p>
+    ///
+    /// See `AppSync::delete_graphql_api`.
+    pub fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> Result<DeleteGraphqlApiResponse, RusotoError<DeleteGraphqlApiError>> {
+        self.runtime.block_on(self.client.delete_graphql_api(input))
+    }
+
+    /// <p>Deletes a <code>Resolver</code> object.</p>
+    ///
+    /// See `AppSync::delete_resolver`.
+    pub fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> Result<DeleteResolverResponse, RusotoError<DeleteResolverError>> {
+        self.runtime.block_on(self.client.delete_resolver(input))
+    }
+
+    /// <p>Deletes a <code>Type</code> object.</p>
+    ///
+    /// See `AppSync::delete_type`.
+    pub fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> Result<DeleteTypeResponse, RusotoError<DeleteTypeError>> {
+        self.runtime.block_on(self.client.delete_type(input))
+    }
+
+    /// <p>Flushes an <code>ApiCache</code> object.</p>
+    ///
+    /// See `AppSync::flush_api_cache`.
+    pub fn flush_api_cache(
+        &self,
+        input: FlushApiCacheRequest,
+    ) -> Result<FlushApiCacheResponse, RusotoError<FlushApiCacheError>> {
+        self.runtime.block_on(self.client.flush_api_cache(input))
+    }
+
+    /// <p>Retrieves an <code>ApiCache</code> object.</p>
+    ///
+    /// See `AppSync::get_api_cache`.
+    pub fn get_api_cache(
+        &self,
+        input: GetApiCacheRequest,
+    ) -> Result<GetApiCacheResponse, RusotoError<GetApiCacheError>> {
+        self.runtime.block_on(self.client.get_api_cache(input))
+    }
+
+    /// <p>Retrieves a <code>DataSource</code> object.</p>
+    ///
+    /// See `AppSync::get_data_source`.
+    pub fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> Result<GetDataSourceResponse, RusotoError<GetDataSourceError>> {
+        self.runtime.block_on(self.client.get_data_source(input))
+    }
+
+    /// <p>Get a <code>Function</code>.</p>
+    ///
+    /// See `AppSync::get_function`.
+    pub fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+        self.runtime.block_on(self.client.get_function(input))
+    }
+
+    /// <p>Retrieves a <code>GraphqlApi</code> object.</p>
+    ///
+    /// See `AppSync::get_graphql_api`.
+    pub fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> Result<GetGraphqlApiResponse, RusotoError<GetGraphqlApiError>> {
+        self.runtime.block_on(self.client.get_graphql_api(input))
+    }
+
+    /// <p>Retrieves the introspection schema for a GraphQL API.</p>
+    ///
+    /// See `AppSync::get_introspection_schema`.
+    pub fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> Result<GetIntrospectionSchemaResponse, RusotoError<GetIntrospectionSchemaError>> {
+        self.runtime
+            .block_on(self.client.get_introspection_schema(input))
+    }
+
+    /// <p>Retrieves a <code>Resolver</code> object.</p>
+    ///
+    /// See `AppSync::get_resolver`.
+    pub fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> Result<GetResolverResponse, RusotoError<GetResolverError>> {
+        self.runtime.block_on(self.client.get_resolver(input))
+    }
+
+    /// <p>Retrieves the current status of a schema creation operation.</p>
+    ///
+    /// See `AppSync::get_schema_creation_status`.
+    pub fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> Result<GetSchemaCreationStatusResponse, RusotoError<GetSchemaCreationStatusError>> {
+        self.runtime
+            .block_on(self.client.get_schema_creation_status(input))
+    }
+
+    /// <p>Retrieves a <code>Type</code> object.</p>
+    ///
+    /// See `AppSync::get_type`.
+    pub fn get_type(
+        &self,
+        input: GetTypeRequest,
+    ) -> Result<GetTypeResponse, RusotoError<GetTypeError>> {
+        self.runtime.block_on(self.client.get_type(input))
+    }
+
+    /// <p><p>Lists the API keys for a given API.</p> <note> <p>API keys are deleted automatically sometime after they expire. However, they may still be included in the response until they have actually been deleted. You can safely call <code>DeleteApiKey</code> to manually delete a key before it&#39;s automatically deleted.</p> </note></p>
+    ///
+    /// See `AppSync::list_api_keys`.
+    pub fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> Result<ListApiKeysResponse, RusotoError<ListApiKeysError>> {
+        self.runtime.block_on(self.client.list_api_keys(input))
+    }
+
+    /// <p>Lists the data sources for a given API.</p>
+    ///
+    /// See `AppSync::list_data_sources`.
+    pub fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.runtime.block_on(self.client.list_data_sources(input))
+    }
+
+    /// <p>List multiple functions.</p>
+    ///
+    /// See `AppSync::list_functions`.
+    pub fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+        self.runtime.block_on(self.client.list_functions(input))
+    }
+
+    /// <p>Lists your GraphQL APIs.</p>
+    ///
+    /// See `AppSync::list_graphql_apis`.
+    pub fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> Result<ListGraphqlApisResponse, RusotoError<ListGraphqlApisError>> {
+        self.runtime.block_on(self.client.list_graphql_apis(input))
+    }
+
+    /// <p>Lists the resolvers for a given API and type.</p>
+    ///
+    /// See `AppSync::list_resolvers`.
+    pub fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> Result<ListResolversResponse, RusotoError<ListResolversError>> {
+        self.runtime.block_on(self.client.list_resolvers(input))
+    }
+
+    /// <p>List the resolvers that are associated with a specific function.</p>
+    ///
+    /// See `AppSync::list_resolvers_by_function`.
+    pub fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> Result<ListResolversByFunctionResponse, RusotoError<ListResolversByFunctionError>> {
+        self.runtime
+            .block_on(self.client.list_resolvers_by_function(input))
+    }
+
+    /// <p>Lists the tags for a resource.</p>
+    ///
+    /// See `AppSync::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the types for a given API.</p>
+    ///
+    /// See `AppSync::list_types`.
+    pub fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> Result<ListTypesResponse, RusotoError<ListTypesError>> {
+        self.runtime.block_on(self.client.list_types(input))
+    }
+
+    /// <p>Adds a new schema to your GraphQL API.</p> <p>This operation is asynchronous. Use to determine when it has completed.</p>
+    ///
+    /// See `AppSync::start_schema_creation`.
+    pub fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> Result<StartSchemaCreationResponse, RusotoError<StartSchemaCreationError>> {
+        self.runtime
+            .block_on(self.client.start_schema_creation(input))
+    }
+
+    /// <p>Tags a resource with user-supplied tags.</p>
+    ///
+    /// See `AppSync::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Untags a resource.</p>
+    ///
+    /// See `AppSync::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the cache for the GraphQL API.</p>
+    ///
+    /// See `AppSync::update_api_cache`.
+    pub fn update_api_cache(
+        &self,
+        input: UpdateApiCacheRequest,
+    ) -> Result<UpdateApiCacheResponse, RusotoError<UpdateApiCacheError>> {
+        self.runtime.block_on(self.client.update_api_cache(input))
+    }
+
+    /// <p>Updates an API key.</p>
+    ///
+    /// See `AppSync::update_api_key`.
+    pub fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<UpdateApiKeyResponse, RusotoError<UpdateApiKeyError>> {
+        self.runtime.block_on(self.client.update_api_key(input))
+    }
+
+    /// <p>Updates a <code>DataSource</code> object.</p>
+    ///
+    /// See `AppSync::update_data_source`.
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// <p>Updates a <code>Function</code> object.</p>
+    ///
+    /// See `AppSync::update_function`.
+    pub fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> Result<UpdateFunctionResponse, RusotoError<UpdateFunctionError>> {
+        self.runtime.block_on(self.client.update_function(input))
+    }
+
+    /// <p>Updates a <code>GraphqlApi</code> object.</p>
+    ///
+    /// See `AppSync::update_graphql_api`.
+    pub fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> Result<UpdateGraphqlApiResponse, RusotoError<UpdateGraphqlApiError>> {
+        self.runtime.block_on(self.client.update_graphql_api(input))
+    }
+
+    /// <p>Updates a <code>Resolver</code> object.</p>
+    ///
+    /// See `AppSync::update_resolver`.
+    pub fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> Result<UpdateResolverResponse, RusotoError<UpdateResolverError>> {
+        self.runtime.block_on(self.client.update_resolver(input))
+    }
+
+    /// <p>Updates a <code>Type</code> object.</p>
+    ///
+    /// See `AppSync::update_type`.
+    pub fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> Result<UpdateTypeResponse, RusotoError<UpdateTypeError>> {
+        self.runtime.block_on(self.client.update_type(input))
+    }
+}
@@ -1225,10 +1225,16 @@ impl BatchGetNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetNamedQueryError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetNamedQueryError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1261,14 +1267,16 @@ impl BatchGetQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetQueryExecutionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetQueryExecutionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetQueryExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetQueryExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1301,10 +1309,16 @@ impl CreateDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataCatalogError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataCatalogError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1337,10 +1351,16 @@ impl CreateNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNamedQueryError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNamedQueryError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1373,10 +1393,16 @@ impl CreateWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkGroupError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1409,10 +1435,16 @@ impl DeleteDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataCatalogError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataCatalogError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1445,10 +1477,16 @@ impl DeleteNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNamedQueryError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNamedQueryError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1481,10 +1519,16 @@ impl DeleteWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkGroupError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1517,10 +1561,10 @@ impl GetDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDataCatalogError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetDataCatalogError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1555,13 +1599,13 @@ impl GetDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDatabaseError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDatabaseError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::InvalidRequest(err.msg))
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(GetDatabaseError::Metadata(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::Metadata(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1595,10 +1639,10 @@ impl GetNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetNamedQueryError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetNamedQueryError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1631,10 +1675,16 @@ impl GetQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryExecutionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1667,10 +1717,16 @@ impl GetQueryResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetQueryResultsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryResultsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetQueryResultsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryResultsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1705,13 +1761,19 @@ impl GetTableMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTableMetadataError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableMetadataError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTableMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableMetadataError::InvalidRequest(err.msg),
+                    )
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(GetTableMetadataError::Metadata(err.msg))
+                    return RusotoError::service(&res, GetTableMetadataError::Metadata(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1745,10 +1807,10 @@ impl GetWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetWorkGroupError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetWorkGroupError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1781,10 +1843,16 @@ impl ListDataCatalogsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataCatalogsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataCatalogsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDataCatalogsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataCatalogsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1819,13 +1887,13 @@ impl ListDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDatabasesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListDatabasesError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatabasesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDatabasesError::InvalidRequest(err.msg))
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(ListDatabasesError::Metadata(err.msg))
+                    return RusotoError::service(&res, ListDatabasesError::Metadata(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1859,10 +1927,16 @@ impl ListNamedQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListNamedQueriesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedQueriesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNamedQueriesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedQueriesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1895,10 +1969,16 @@ impl ListQueryExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListQueryExecutionsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListQueryExecutionsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQueryExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListQueryExecutionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1933,13 +2013,19 @@ impl ListTableMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTableMetadataError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTableMetadataError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTableMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTableMetadataError::InvalidRequest(err.msg),
+                    )
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(ListTableMetadataError::Metadata(err.msg))
+                    return RusotoError::service(&res, ListTableMetadataError::Metadata(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1975,15 +2061,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2017,10 +2110,10 @@ impl ListWorkGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorkGroupsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListWorkGroupsError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListWorkGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListWorkGroupsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2055,13 +2148,22 @@ impl StartQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartQueryExecutionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartQueryExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartQueryExecutionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartQueryExecutionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2095,10 +2197,16 @@ impl StopQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopQueryExecutionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopQueryExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2133,13 +2241,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2175,13 +2283,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2215,10 +2326,16 @@ impl UpdateDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataCatalogError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataCatalogError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2251,10 +2368,16 @@ impl UpdateWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWorkGroupError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWorkGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2462,6 +2585,7 @@ impl AthenaClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2477,6 +2601,7 @@ impl AthenaClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AthenaClient {
         AthenaClient { client, region }
     }
@@ -2490,6 +2615,9 @@ impl Athena for AthenaClient {
         input: BatchGetNamedQueryInput,
     ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetNamedQuery");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.BatchGetNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2508,6 +2636,9 @@ impl Athena for AthenaClient {
         input: BatchGetQueryExecutionInput,
     ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetQueryExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.BatchGetQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2527,6 +2658,9 @@ impl Athena for AthenaClient {
         input: CreateDataCatalogInput,
     ) -> Result<CreateDataCatalogOutput, RusotoError<CreateDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataCatalog");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.CreateDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2545,6 +2679,9 @@ impl Athena for AthenaClient {
         input: CreateNamedQueryInput,
     ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNamedQuery");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2563,6 +2700,9 @@ impl Athena for AthenaClient {
         input: CreateWorkGroupInput,
     ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWorkGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.CreateWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2581,6 +2721,9 @@ impl Athena for AthenaClient {
         input: DeleteDataCatalogInput,
     ) -> Result<DeleteDataCatalogOutput, RusotoError<DeleteDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDataCatalog");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.DeleteDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2599,6 +2742,9 @@ impl Athena for AthenaClient {
         input: DeleteNamedQueryInput,
     ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNamedQuery");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2617,6 +2763,9 @@ impl Athena for AthenaClient {
         input: DeleteWorkGroupInput,
     ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWorkGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.DeleteWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2635,6 +2784,9 @@ impl Athena for AthenaClient {
         input: GetDataCatalogInput,
     ) -> Result<GetDataCatalogOutput, RusotoError<GetDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDataCatalog");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2653,6 +2805,9 @@ impl Athena for AthenaClient {
         input: GetDatabaseInput,
     ) -> Result<GetDatabaseOutput, RusotoError<GetDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDatabase");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2671,6 +2826,9 @@ impl Athena for AthenaClient {
         input: GetNamedQueryInput,
     ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetNamedQuery");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2689,6 +2847,9 @@ impl Athena for AthenaClient {
         input: GetQueryExecutionInput,
     ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetQueryExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2707,6 +2868,9 @@ impl Athena for AthenaClient {
         input: GetQueryResultsInput,
     ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetQueryResults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetQueryResults");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2725,6 +2889,9 @@ impl Athena for AthenaClient {
         input: GetTableMetadataInput,
     ) -> Result<GetTableMetadataOutput, RusotoError<GetTableMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTableMetadata");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetTableMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2743,6 +2910,9 @@ impl Athena for AthenaClient {
         input: GetWorkGroupInput,
     ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWorkGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.GetWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2761,6 +2931,9 @@ impl Athena for AthenaClient {
         input: ListDataCatalogsInput,
     ) -> Result<ListDataCatalogsOutput, RusotoError<ListDataCatalogsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDataCatalogs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListDataCatalogs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2779,6 +2952,9 @@ impl Athena for AthenaClient {
         input: ListDatabasesInput,
     ) -> Result<ListDatabasesOutput, RusotoError<ListDatabasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatabases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2797,6 +2973,9 @@ impl Athena for AthenaClient {
         input: ListNamedQueriesInput,
     ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListNamedQueries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListNamedQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2815,6 +2994,9 @@ impl Athena for AthenaClient {
         input: ListQueryExecutionsInput,
     ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListQueryExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListQueryExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2833,6 +3015,9 @@ impl Athena for AthenaClient {
         input: ListTableMetadataInput,
     ) -> Result<ListTableMetadataOutput, RusotoError<ListTableMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTableMetadata");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListTableMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2851,6 +3036,9 @@ impl Athena for AthenaClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2869,6 +3057,9 @@ impl Athena for AthenaClient {
         input: ListWorkGroupsInput,
     ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWorkGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.ListWorkGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2887,6 +3078,9 @@ impl Athena for AthenaClient {
         input: StartQueryExecutionInput,
     ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartQueryExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.StartQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2905,6 +3099,9 @@ impl Athena for AthenaClient {
         input: StopQueryExecutionInput,
     ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopQueryExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonAthena.StopQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2923,6 +3120,9 @@ impl Athena for AthenaClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2941,6 +3141,9 @@ impl Athena for AthenaClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2959,6 +3162,9 @@ impl Athena for AthenaClient {
         input: UpdateDataCatalogInput,
     ) -> Result<UpdateDataCatalogOutput, RusotoError<UpdateDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDataCatalog");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.UpdateDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2977,6 +3183,9 @@ impl Athena for AthenaClient {
         input: UpdateWorkGroupInput,
     ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateWorkGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonAthena.UpdateWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2989,3 +3198,344 @@ impl Athena for AthenaClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkGroupOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon Athena API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AthenaClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AthenaBlockingClient {
+    client: AthenaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AthenaBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AthenaBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AthenaBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AthenaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AthenaBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AthenaBlockingClient {
+        AthenaBlockingClient {
+            client: AthenaClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns the details of a single named query or a list of up to 50 queries, which you provide as an array of query ID strings. Requires you to have access to the workgroup in which the queries were saved. Use <a>ListNamedQueriesInput</a> to get the list of named query IDs in the specified workgroup. If information could not be retrieved for a submitted query ID, information about the query ID submitted is listed under <a>UnprocessedNamedQueryId</a>. Named queries differ from executed queries. Use <a>BatchGetQueryExecutionInput</a> to get details about each unique query execution, and <a>ListQueryExecutionsInput</a> to get a list of query execution IDs.</p>
+    ///
+    /// See `Athena::batch_get_named_query`.
+    pub fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
+        self.runtime
+            .block_on(self.client.batch_get_named_query(input))
+    }
+
+    /// <p>Returns the details of a single query execution or a list of up to 50 query executions, which you provide as an array of query execution ID strings. Requires you to have access to the workgroup in which the queries ran. To get a list of query execution IDs, use <a>ListQueryExecutionsInput$WorkGroup</a>. Query executions differ from named (saved) queries. Use <a>BatchGetNamedQueryInput</a> to get details about named queries.</p>
+    ///
+    /// See `Athena::batch_get_query_execution`.
+    pub fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.batch_get_query_execution(input))
+    }
+
+    /// <p>Creates (registers) a data catalog with the specified name and properties. Catalogs created are visible to all users of the same AWS account.</p>
+    ///
+    /// See `Athena::create_data_catalog`.
+    pub fn create_data_catalog(
+        &self,
+        input: CreateDataCatalogInput,
+    ) -> Result<CreateDataCatalogOutput, RusotoError<CreateDataCatalogError>> {
+        self.runtime
+            .block_on(self.client.create_data_catalog(input))
+    }
+
+    /// <p>Creates a named query in the specified workgroup. Requires that you have access to the workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    ///
+    /// See `Athena::create_named_query`.
+    pub fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
+        self.runtime.block_on(self.client.create_named_query(input))
+    }
+
+    /// <p>Creates a workgroup with the specified name.</p>
+    ///
+    /// See `Athena::create_work_group`.
+    pub fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
+        self.runtime.block_on(self.client.create_work_group(input))
+    }
+
+    /// <p>Deletes a data catalog.</p>
+    ///
+    /// See `Athena::delete_data_catalog`.
+    pub fn delete_data_catalog(
+        &self,
+        input: DeleteDataCatalogInput,
+    ) -> Result<DeleteDataCatalogOutput, RusotoError<DeleteDataCatalogError>> {
+        self.runtime
+            .block_on(self.client.delete_data_catalog(input))
+    }
+
+    /// <p>Deletes the named query if you have access to the workgroup in which the query was saved.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    ///
+    /// See `Athena::delete_named_query`.
+    pub fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
+        self.runtime.block_on(self.client.delete_named_query(input))
+    }
+
+    /// <p>Deletes the workgroup with the specified name. The primary workgroup cannot be deleted.</p>
+    ///
+    /// See `Athena::delete_work_group`.
+    pub fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
+        self.runtime.block_on(self.client.delete_work_group(input))
+    }
+
+    /// <p>Returns the specified data catalog.</p>
+    ///
+    /// See `Athena::get_data_catalog`.
+    pub fn get_data_catalog(
+        &self,
+        input: GetDataCatalogInput,
+    ) -> Result<GetDataCatalogOutput, RusotoError<GetDataCatalogError>> {
+        self.runtime.block_on(self.client.get_data_catalog(input))
+    }
+
+    /// <p>Returns a database object for the specfied database and data catalog.</p>
+    ///
+    /// See `Athena::get_database`.
+    pub fn get_database(
+        &self,
+        input: GetDatabaseInput,
+    ) -> Result<GetDatabaseOutput, RusotoError<GetDatabaseError>> {
+        self.runtime.block_on(self.client.get_database(input))
+    }
+
+    /// <p>Returns information about a single query. Requires that you have access to the workgroup in which the query was saved.</p>
+    ///
+    /// See `Athena::get_named_query`.
+    pub fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
+        self.runtime.block_on(self.client.get_named_query(input))
+    }
+
+    /// <p>Returns information about a single execution of a query if you have access to the workgroup in which the query ran. Each time a query executes, information about the query execution is saved with a unique ID.</p>
+    ///
+    /// See `Athena::get_query_execution`.
+    pub fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.get_query_execution(input))
+    }
+
+    /// <p><p>Streams the results of a single query execution specified by <code>QueryExecutionId</code> from the Athena query results location in Amazon S3. For more information, see <a href="https://docs.aws.amazon.com/athena/latest/ug/querying.html">Query Results</a> in the <i>Amazon Athena User Guide</i>. This request does not execute the query but returns results. Use <a>StartQueryExecution</a> to run a query.</p> <p>To stream query results successfully, the IAM principal with permission to call <code>GetQueryResults</code> also must have permissions to the Amazon S3 <code>GetObject</code> action for the Athena query results location.</p> <important> <p>IAM principals with permission to the Amazon S3 <code>GetObject</code> action for the query results location are able to retrieve query results from Amazon S3 even if permission to the <code>GetQueryResults</code> action is denied. To restrict user or role access, ensure that Amazon S3 permissions to the Athena query location are denied.</p> </important></p>
+    ///
+    /// See `Athena::get_query_results`.
+    pub fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
+        self.runtime.block_on(self.client.get_query_results(input))
+    }
+
+    /// <p>Returns table metadata for the specified catalog, database, and table.</p>
+    ///
+    /// See `Athena::get_table_metadata`.
+    pub fn get_table_metadata(
+        &self,
+        input: GetTableMetadataInput,
+    ) -> Result<GetTableMetadataOutput, RusotoError<GetTableMetadataError>> {
+        self.runtime.block_on(self.client.get_table_metadata(input))
+    }
+
+    /// <p>Returns information about the workgroup with the specified name.</p>
+    ///
+    /// See `Athena::get_work_group`.
+    pub fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
+        self.runtime.block_on(self.client.get_work_group(input))
+    }
+
+    /// <p>Lists the data catalogs in the current AWS account.</p>
+    ///
+    /// See `Athena::list_data_catalogs`.
+    pub fn list_data_catalogs(
+        &self,
+        input: ListDataCatalogsInput,
+    ) -> Result<ListDataCatalogsOutput, RusotoError<ListDataCatalogsError>> {
+        self.runtime.block_on(self.client.list_data_catalogs(input))
+    }
+
+    /// <p>Lists the databases in the specified data catalog.</p>
+    ///
+    /// See `Athena::list_databases`.
+    pub fn list_databases(
+        &self,
+        input: ListDatabasesInput,
+    ) -> Result<ListDatabasesOutput, RusotoError<ListDatabasesError>> {
+        self.runtime.block_on(self.client.list_databases(input))
+    }
+
+    /// <p>Provides a list of available query IDs only for queries saved in the specified workgroup. Requires that you have access to the specified workgroup. If a workgroup is not specified, lists the saved queries for the primary workgroup.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    ///
+    /// See `Athena::list_named_queries`.
+    pub fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
+        self.runtime.block_on(self.client.list_named_queries(input))
+    }
+
+    /// <p>Provides a list of available query execution IDs for the queries in the specified workgroup. If a workgroup is not specified, returns a list of query execution IDs for the primary workgroup. Requires you to have access to the workgroup in which the queries ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    ///
+    /// See `Athena::list_query_executions`.
+    pub fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_query_executions(input))
+    }
+
+    /// <p>Lists the metadata for the tables in the specified data catalog database.</p>
+    ///
+    /// See `Athena::list_table_metadata`.
+    pub fn list_table_metadata(
+        &self,
+        input: ListTableMetadataInput,
+    ) -> Result<ListTableMetadataOutput, RusotoError<ListTableMetadataError>> {
+        self.runtime
+            .block_on(self.client.list_table_metadata(input))
+    }
+
+    /// <p>Lists the tags associated with an Athena workgroup or data catalog resource.</p>
+    ///
+    /// See `Athena::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists available workgroups for the account.</p>
+    ///
+    /// See `Athena::list_work_groups`.
+    pub fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
+        self.runtime.block_on(self.client.list_work_groups(input))
+    }
+
+    /// <p>Runs the SQL query statements contained in the <code>Query</code>. Requires you to have access to the workgroup in which the query ran. Running queries against an external catalog requires <a>GetDataCatalog</a> permission to the catalog. For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    ///
+    /// See `Athena::start_query_execution`.
+    pub fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_query_execution(input))
+    }
+
+    /// <p>Stops a query execution. Requires you to have access to the workgroup in which the query ran.</p> <p>For code samples using the AWS SDK for Java, see <a href="http://docs.aws.amazon.com/athena/latest/ug/code-samples.html">Examples and Code Samples</a> in the <i>Amazon Athena User Guide</i>.</p>
+    ///
+    /// See `Athena::stop_query_execution`.
+    pub fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_query_execution(input))
+    }
+
+    /// <p>Adds one or more tags to an Athena resource. A tag is a label that you assign to a resource. In Athena, a resource can be a workgroup or data catalog. Each tag consists of a key and an optional value, both of which you define. For example, you can use tags to categorize Athena workgroups or data catalogs by purpose, owner, or environment. Use a consistent set of tag keys to make it easier to search and filter workgroups or data catalogs in your account. For best practices, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">Tagging Best Practices</a>. Tag keys can be from 1 to 128 UTF-8 Unicode characters, and tag values can be from 0 to 256 UTF-8 Unicode characters. Tags can use letters and numbers representable in UTF-8, and the following characters: + - = . _ : / @. Tag keys and values are case-sensitive. Tag keys must be unique per resource. If you specify more than one tag, separate them by commas.</p>
+    ///
+    /// See `Athena::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a data catalog or workgroup resource.</p>
+    ///
+    /// See `Athena::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the data catalog that has the specified name.</p>
+    ///
+    /// See `Athena::update_data_catalog`.
+    pub fn update_data_catalog(
+        &self,
+        input: UpdateDataCatalogInput,
+    ) -> Result<UpdateDataCatalogOutput, RusotoError<UpdateDataCatalogError>> {
+        self.runtime
+            .block_on(self.client.update_data_catalog(input))
+    }
+
+    /// <p>Updates the workgroup with the specified name. The workgroup's name cannot be changed.</p>
+    ///
+    /// See `Athena::update_work_group`.
+    pub fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
+        self.runtime.block_on(self.client.update_work_group(input))
+    }
+}
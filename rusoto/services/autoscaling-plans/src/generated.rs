@@ -521,13 +521,22 @@ impl CreateScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(CreateScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateScalingPlanError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateScalingPlanError::InternalService(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateScalingPlanError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateScalingPlanError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -563,13 +572,22 @@ impl DeleteScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPlanError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPlanError::InternalService(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPlanError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -607,17 +625,20 @@ impl DescribeScalingPlanResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeScalingPlanResourcesError::ConcurrentUpdate(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeScalingPlanResourcesError::InternalService(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeScalingPlanResourcesError::InvalidNextToken(err.msg),
                     )
                 }
@@ -659,19 +680,22 @@ impl DescribeScalingPlansError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::ConcurrentUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPlansError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPlansError::InternalService(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPlansError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -705,7 +729,8 @@ impl GetScalingPlanResourceForecastDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetScalingPlanResourceForecastDataError::InternalService(err.msg),
                     )
                 }
@@ -743,13 +768,22 @@ impl UpdateScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScalingPlanError::ConcurrentUpdate(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScalingPlanError::InternalService(err.msg),
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::ObjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScalingPlanError::ObjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -829,6 +863,7 @@ impl AutoscalingPlansClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -844,6 +879,7 @@ impl AutoscalingPlansClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingPlansClient {
         AutoscalingPlansClient { client, region }
     }
@@ -857,6 +893,9 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: CreateScalingPlanRequest,
     ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateScalingPlan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.CreateScalingPlan",
@@ -878,6 +917,9 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: DeleteScalingPlanRequest,
     ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteScalingPlan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.DeleteScalingPlan",
@@ -900,6 +942,9 @@ impl AutoscalingPlans for AutoscalingPlansClient {
     ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScalingPlanResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.DescribeScalingPlanResources",
@@ -922,6 +967,9 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: DescribeScalingPlansRequest,
     ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScalingPlans");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.DescribeScalingPlans",
@@ -947,6 +995,9 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         RusotoError<GetScalingPlanResourceForecastDataError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetScalingPlanResourceForecastData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.GetScalingPlanResourceForecastData",
@@ -972,6 +1023,9 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: UpdateScalingPlanRequest,
     ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateScalingPlan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.UpdateScalingPlan",
@@ -987,3 +1041,126 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateScalingPlanResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Auto Scaling Plans API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AutoscalingPlansClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AutoscalingPlansBlockingClient {
+    client: AutoscalingPlansClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AutoscalingPlansBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AutoscalingPlansBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AutoscalingPlansBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AutoscalingPlansBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AutoscalingPlansBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient {
+            client: AutoscalingPlansClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a scaling plan.</p>
+    ///
+    /// See `AutoscalingPlans::create_scaling_plan`.
+    pub fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
+        self.runtime
+            .block_on(self.client.create_scaling_plan(input))
+    }
+
+    /// <p>Deletes the specified scaling plan.</p> <p>Deleting a scaling plan deletes the underlying <a>ScalingInstruction</a> for all of the scalable resources that are covered by the plan.</p> <p>If the plan has launched resources or has scaling activities in progress, you must delete those resources separately.</p>
+    ///
+    /// See `AutoscalingPlans::delete_scaling_plan`.
+    pub fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
+        self.runtime
+            .block_on(self.client.delete_scaling_plan(input))
+    }
+
+    /// <p>Describes the scalable resources in the specified scaling plan.</p>
+    ///
+    /// See `AutoscalingPlans::describe_scaling_plan_resources`.
+    pub fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scaling_plan_resources(input))
+    }
+
+    /// <p>Describes one or more of your scaling plans.</p>
+    ///
+    /// See `AutoscalingPlans::describe_scaling_plans`.
+    pub fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_plans(input))
+    }
+
+    /// <p>Retrieves the forecast data for a scalable resource.</p> <p>Capacity forecasts are represented as predicted values, or data points, that are calculated using historical data points from a specified CloudWatch load metric. Data points are available for up to 56 days. </p>
+    ///
+    /// See `AutoscalingPlans::get_scaling_plan_resource_forecast_data`.
+    pub fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> Result<
+        GetScalingPlanResourceForecastDataResponse,
+        RusotoError<GetScalingPlanResourceForecastDataError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_scaling_plan_resource_forecast_data(input))
+    }
+
+    /// <p>Updates the specified scaling plan.</p> <p>You cannot update a scaling plan if it is in the process of being created, updated, or deleted.</p>
+    ///
+    /// See `AutoscalingPlans::update_scaling_plan`.
+    pub fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
+        self.runtime
+            .block_on(self.client.update_scaling_plan(input))
+    }
+}
@@ -7475,12 +7475,14 @@ impl AttachInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(AttachInstancesError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachInstancesError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachInstancesError::ServiceLinkedRoleFailure(parsed_error.message),
                         )
                     }
@@ -7529,14 +7531,16 @@ impl AttachLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancerTargetGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
@@ -7589,12 +7593,14 @@ impl AttachLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancersError::ResourceContentionFault(parsed_error.message),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancersError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
@@ -7643,7 +7649,8 @@ impl BatchDeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchDeleteScheduledActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -7697,21 +7704,24 @@ impl BatchPutScheduledUpdateGroupActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutScheduledUpdateGroupActionError::LimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -7769,19 +7779,22 @@ impl CancelInstanceRefreshError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ActiveInstanceRefreshNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CancelInstanceRefreshError::ActiveInstanceRefreshNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CancelInstanceRefreshError::LimitExceededFault(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CancelInstanceRefreshError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -7833,7 +7846,8 @@ impl CompleteLifecycleActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CompleteLifecycleActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -7887,24 +7901,28 @@ impl CreateAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAutoScalingGroupError::AlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAutoScalingGroupError::LimitExceededFault(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAutoScalingGroupError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
@@ -7961,21 +7979,24 @@ impl CreateLaunchConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLaunchConfigurationError::AlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLaunchConfigurationError::LimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLaunchConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8031,24 +8052,28 @@ impl CreateOrUpdateTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::AlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateOrUpdateTagsError::AlreadyExistsFault(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateOrUpdateTagsError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOrUpdateTagsError::ResourceContentionFault(parsed_error.message),
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateOrUpdateTagsError::ResourceInUseFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8097,19 +8122,22 @@ impl DeleteAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAutoScalingGroupError::ResourceInUseFault(parsed_error.message),
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAutoScalingGroupError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
@@ -8163,14 +8191,16 @@ impl DeleteLaunchConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLaunchConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLaunchConfigurationError::ResourceInUseFault(
                                 parsed_error.message,
                             ),
@@ -8219,7 +8249,8 @@ impl DeleteLifecycleHookError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLifecycleHookError::ResourceContentionFault(parsed_error.message),
                         )
                     }
@@ -8265,7 +8296,8 @@ impl DeleteNotificationConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteNotificationConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8315,14 +8347,16 @@ impl DeletePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DeletePolicyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(DeletePolicyError::ServiceLinkedRoleFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::ServiceLinkedRoleFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8365,7 +8399,8 @@ impl DeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteScheduledActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8415,14 +8450,16 @@ impl DeleteTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTagsError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTagsError::ResourceInUseFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8465,7 +8502,8 @@ impl DescribeAccountLimitsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAccountLimitsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8513,7 +8551,8 @@ impl DescribeAdjustmentTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAdjustmentTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8563,12 +8602,14 @@ impl DescribeAutoScalingGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAutoScalingGroupsError::InvalidNextToken(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAutoScalingGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8621,14 +8662,16 @@ impl DescribeAutoScalingInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAutoScalingInstancesError::InvalidNextToken(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAutoScalingInstancesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8681,7 +8724,8 @@ impl DescribeAutoScalingNotificationTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAutoScalingNotificationTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8731,12 +8775,14 @@ impl DescribeInstanceRefreshesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeInstanceRefreshesError::InvalidNextToken(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeInstanceRefreshesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8789,14 +8835,16 @@ impl DescribeLaunchConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLaunchConfigurationsError::InvalidNextToken(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLaunchConfigurationsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8849,7 +8897,8 @@ impl DescribeLifecycleHookTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLifecycleHookTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8897,7 +8946,8 @@ impl DescribeLifecycleHooksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLifecycleHooksError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8947,7 +8997,8 @@ impl DescribeLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -8995,7 +9046,8 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancersError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9045,7 +9097,8 @@ impl DescribeMetricCollectionTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeMetricCollectionTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9097,14 +9150,16 @@ impl DescribeNotificationConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeNotificationConfigurationsError::InvalidNextToken(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeNotificationConfigurationsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9159,17 +9214,20 @@ impl DescribePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribePoliciesError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribePoliciesError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePoliciesError::ResourceContentionFault(parsed_error.message),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePoliciesError::ServiceLinkedRoleFailure(parsed_error.message),
                         )
                     }
@@ -9217,12 +9275,14 @@ impl DescribeScalingActivitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScalingActivitiesError::InvalidNextToken(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScalingActivitiesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9273,7 +9333,8 @@ impl DescribeScalingProcessTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScalingProcessTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9323,12 +9384,14 @@ impl DescribeScheduledActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScheduledActionsError::InvalidNextToken(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScheduledActionsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9379,14 +9442,16 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeTagsError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(DescribeTagsError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9431,7 +9496,8 @@ impl DescribeTerminationPolicyTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTerminationPolicyTypesError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9479,9 +9545,10 @@ impl DetachInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DetachInstancesError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachInstancesError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9525,7 +9592,8 @@ impl DetachLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DetachLoadBalancerTargetGroupsError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9573,7 +9641,8 @@ impl DetachLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DetachLoadBalancersError::ResourceContentionFault(parsed_error.message),
                         )
                     }
@@ -9617,7 +9686,8 @@ impl DisableMetricsCollectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableMetricsCollectionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9665,7 +9735,8 @@ impl EnableMetricsCollectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableMetricsCollectionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -9713,9 +9784,10 @@ impl EnterStandbyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(EnterStandbyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnterStandbyError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9759,12 +9831,14 @@ impl ExecutePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ExecutePolicyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ExecutePolicyError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ExecutePolicyError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
@@ -9811,9 +9885,10 @@ impl ExitStandbyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ExitStandbyError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ExitStandbyError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9857,12 +9932,14 @@ impl PutLifecycleHookError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutLifecycleHookError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutLifecycleHookError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutLifecycleHookError::ResourceContentionFault(parsed_error.message),
                         )
                     }
@@ -9913,21 +9990,24 @@ impl PutNotificationConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutNotificationConfigurationError::LimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutNotificationConfigurationError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutNotificationConfigurationError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
@@ -9985,17 +10065,20 @@ impl PutScalingPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutScalingPolicyError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutScalingPolicyError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutScalingPolicyError::ResourceContentionFault(parsed_error.message),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutScalingPolicyError::ServiceLinkedRoleFailure(parsed_error.message),
                         )
                     }
@@ -10047,21 +10130,24 @@ impl PutScheduledUpdateGroupActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutScheduledUpdateGroupActionError::AlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutScheduledUpdateGroupActionError::LimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutScheduledUpdateGroupActionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -10117,7 +10203,8 @@ impl RecordLifecycleActionHeartbeatError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RecordLifecycleActionHeartbeatError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -10167,14 +10254,16 @@ impl ResumeProcessesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ResumeProcessesError::ResourceContentionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResumeProcessesError::ResourceContentionFault(parsed_error.message),
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(ResumeProcessesError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResumeProcessesError::ResourceInUseFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10219,12 +10308,14 @@ impl SetDesiredCapacityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetDesiredCapacityError::ResourceContentionFault(parsed_error.message),
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetDesiredCapacityError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
@@ -10273,7 +10364,8 @@ impl SetInstanceHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetInstanceHealthError::ResourceContentionFault(parsed_error.message),
                         )
                     }
@@ -10319,12 +10411,14 @@ impl SetInstanceProtectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetInstanceProtectionError::LimitExceededFault(parsed_error.message),
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetInstanceProtectionError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -10377,19 +10471,22 @@ impl StartInstanceRefreshError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InstanceRefreshInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartInstanceRefreshError::InstanceRefreshInProgressFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(StartInstanceRefreshError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartInstanceRefreshError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartInstanceRefreshError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
@@ -10441,14 +10538,16 @@ impl SuspendProcessesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SuspendProcessesError::ResourceContentionFault(parsed_error.message),
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(SuspendProcessesError::ResourceInUseFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SuspendProcessesError::ResourceInUseFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10495,13 +10594,15 @@ impl TerminateInstanceInAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TerminateInstanceInAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "ScalingActivityInProgress" => return RusotoError::Service(
+                    "ScalingActivityInProgress" => return RusotoError::service(
+                        &res,
                         TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(
                             parsed_error.message,
                         ),
@@ -10555,21 +10656,24 @@ impl UpdateAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAutoScalingGroupError::ResourceContentionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAutoScalingGroupError::ScalingActivityInProgressFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(
                                 parsed_error.message,
                             ),
@@ -10984,6 +11088,7 @@ impl AutoscalingClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -10999,6 +11104,7 @@ impl AutoscalingClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingClient {
         AutoscalingClient { client, region }
     }
@@ -11012,6 +11118,9 @@ impl Autoscaling for AutoscalingClient {
         input: AttachInstancesQuery,
     ) -> Result<(), RusotoError<AttachInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("AttachInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachInstances");
         let mut params = params;
         AttachInstancesQuerySerializer::serialize(&mut params, "", &input);
@@ -11035,6 +11144,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<AttachLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("AttachLoadBalancerTargetGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachLoadBalancerTargetGroups");
         let mut params = params;
         AttachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
@@ -11057,6 +11169,9 @@ impl Autoscaling for AutoscalingClient {
         input: AttachLoadBalancersType,
     ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("AttachLoadBalancers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachLoadBalancers");
         let mut params = params;
         AttachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
@@ -11080,6 +11195,9 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("BatchDeleteScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchDeleteScheduledAction");
         let mut params = params;
         BatchDeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11116,6 +11234,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<BatchPutScheduledUpdateGroupActionError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("BatchPutScheduledUpdateGroupAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchPutScheduledUpdateGroupAction");
         let mut params = params;
         BatchPutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11152,6 +11273,9 @@ impl Autoscaling for AutoscalingClient {
         input: CancelInstanceRefreshType,
     ) -> Result<CancelInstanceRefreshAnswer, RusotoError<CancelInstanceRefreshError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("CancelInstanceRefresh");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelInstanceRefresh");
         let mut params = params;
         CancelInstanceRefreshTypeSerializer::serialize(&mut params, "", &input);
@@ -11185,6 +11309,9 @@ impl Autoscaling for AutoscalingClient {
         input: CompleteLifecycleActionType,
     ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("CompleteLifecycleAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CompleteLifecycleAction");
         let mut params = params;
         CompleteLifecycleActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11207,6 +11334,9 @@ impl Autoscaling for AutoscalingClient {
         input: CreateAutoScalingGroupType,
     ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("CreateAutoScalingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateAutoScalingGroup");
         let mut params = params;
         CreateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -11227,6 +11357,9 @@ impl Autoscaling for AutoscalingClient {
         input: CreateLaunchConfigurationType,
     ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("CreateLaunchConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLaunchConfiguration");
         let mut params = params;
         CreateLaunchConfigurationTypeSerializer::serialize(&mut params, "", &input);
@@ -11247,6 +11380,9 @@ impl Autoscaling for AutoscalingClient {
         input: CreateOrUpdateTagsType,
     ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("CreateOrUpdateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateOrUpdateTags");
         let mut params = params;
         CreateOrUpdateTagsTypeSerializer::serialize(&mut params, "", &input);
@@ -11267,6 +11403,9 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteAutoScalingGroupType,
     ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeleteAutoScalingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAutoScalingGroup");
         let mut params = params;
         DeleteAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -11287,6 +11426,9 @@ impl Autoscaling for AutoscalingClient {
         input: LaunchConfigurationNameType,
     ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeleteLaunchConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLaunchConfiguration");
         let mut params = params;
         LaunchConfigurationNameTypeSerializer::serialize(&mut params, "", &input);
@@ -11307,6 +11449,9 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteLifecycleHookType,
     ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeleteLifecycleHook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLifecycleHook");
         let mut params = params;
         DeleteLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
@@ -11329,6 +11474,9 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteNotificationConfigurationType,
     ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeleteNotificationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteNotificationConfiguration");
         let mut params = params;
         DeleteNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
@@ -11349,6 +11497,9 @@ impl Autoscaling for AutoscalingClient {
         input: DeletePolicyType,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeletePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeletePolicy");
         let mut params = params;
         DeletePolicyTypeSerializer::serialize(&mut params, "", &input);
@@ -11369,6 +11520,9 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteScheduledActionType,
     ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeleteScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteScheduledAction");
         let mut params = params;
         DeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11386,6 +11540,9 @@ impl Autoscaling for AutoscalingClient {
     /// <p>Deletes the specified tags.</p>
     async fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTags");
         let mut params = params;
         DeleteTagsTypeSerializer::serialize(&mut params, "", &input);
@@ -11405,6 +11562,9 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeAccountLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountLimits");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11436,6 +11596,9 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeAdjustmentTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAdjustmentTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11468,6 +11631,9 @@ impl Autoscaling for AutoscalingClient {
         input: AutoScalingGroupNamesType,
     ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeAutoScalingGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAutoScalingGroups");
         let mut params = params;
         AutoScalingGroupNamesTypeSerializer::serialize(&mut params, "", &input);
@@ -11501,6 +11667,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeAutoScalingInstancesType,
     ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeAutoScalingInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAutoScalingInstances");
         let mut params = params;
         DescribeAutoScalingInstancesTypeSerializer::serialize(&mut params, "", &input);
@@ -11536,6 +11705,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeAutoScalingNotificationTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeAutoScalingNotificationTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAutoScalingNotificationTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11571,6 +11743,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeInstanceRefreshesType,
     ) -> Result<DescribeInstanceRefreshesAnswer, RusotoError<DescribeInstanceRefreshesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeInstanceRefreshes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceRefreshes");
         let mut params = params;
         DescribeInstanceRefreshesTypeSerializer::serialize(&mut params, "", &input);
@@ -11604,6 +11779,9 @@ impl Autoscaling for AutoscalingClient {
         input: LaunchConfigurationNamesType,
     ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeLaunchConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLaunchConfigurations");
         let mut params = params;
         LaunchConfigurationNamesTypeSerializer::serialize(&mut params, "", &input);
@@ -11637,6 +11815,9 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeLifecycleHookTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLifecycleHookTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11669,6 +11850,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeLifecycleHooksType,
     ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeLifecycleHooks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLifecycleHooks");
         let mut params = params;
         DescribeLifecycleHooksTypeSerializer::serialize(&mut params, "", &input);
@@ -11705,6 +11889,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancerTargetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancerTargetGroups");
         let mut params = params;
         DescribeLoadBalancerTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -11741,6 +11928,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeLoadBalancersRequest,
     ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancers");
         let mut params = params;
         DescribeLoadBalancersRequestSerializer::serialize(&mut params, "", &input);
@@ -11774,6 +11964,9 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeMetricCollectionTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeMetricCollectionTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11809,6 +12002,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeNotificationConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeNotificationConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNotificationConfigurations");
         let mut params = params;
         DescribeNotificationConfigurationsTypeSerializer::serialize(&mut params, "", &input);
@@ -11845,6 +12041,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribePoliciesType,
     ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePolicies");
         let mut params = params;
         DescribePoliciesTypeSerializer::serialize(&mut params, "", &input);
@@ -11875,6 +12074,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeScalingActivitiesType,
     ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeScalingActivities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScalingActivities");
         let mut params = params;
         DescribeScalingActivitiesTypeSerializer::serialize(&mut params, "", &input);
@@ -11905,6 +12107,9 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeScalingProcessTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScalingProcessTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11935,6 +12140,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeScheduledActionsType,
     ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeScheduledActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScheduledActions");
         let mut params = params;
         DescribeScheduledActionsTypeSerializer::serialize(&mut params, "", &input);
@@ -11968,6 +12176,9 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeTagsType,
     ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsTypeSerializer::serialize(&mut params, "", &input);
@@ -12000,6 +12211,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeTerminationPolicyTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DescribeTerminationPolicyTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTerminationPolicyTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -12032,6 +12246,9 @@ impl Autoscaling for AutoscalingClient {
         input: DetachInstancesQuery,
     ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DetachInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachInstances");
         let mut params = params;
         DetachInstancesQuerySerializer::serialize(&mut params, "", &input);
@@ -12066,6 +12283,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DetachLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DetachLoadBalancerTargetGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachLoadBalancerTargetGroups");
         let mut params = params;
         DetachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
@@ -12088,6 +12308,9 @@ impl Autoscaling for AutoscalingClient {
         input: DetachLoadBalancersType,
     ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DetachLoadBalancers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachLoadBalancers");
         let mut params = params;
         DetachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
@@ -12110,6 +12333,9 @@ impl Autoscaling for AutoscalingClient {
         input: DisableMetricsCollectionQuery,
     ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("DisableMetricsCollection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableMetricsCollection");
         let mut params = params;
         DisableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
@@ -12130,6 +12356,9 @@ impl Autoscaling for AutoscalingClient {
         input: EnableMetricsCollectionQuery,
     ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("EnableMetricsCollection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableMetricsCollection");
         let mut params = params;
         EnableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
@@ -12150,6 +12379,9 @@ impl Autoscaling for AutoscalingClient {
         input: EnterStandbyQuery,
     ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("EnterStandby");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnterStandby");
         let mut params = params;
         EnterStandbyQuerySerializer::serialize(&mut params, "", &input);
@@ -12180,6 +12412,9 @@ impl Autoscaling for AutoscalingClient {
         input: ExecutePolicyType,
     ) -> Result<(), RusotoError<ExecutePolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("ExecutePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ExecutePolicy");
         let mut params = params;
         ExecutePolicyTypeSerializer::serialize(&mut params, "", &input);
@@ -12200,6 +12435,9 @@ impl Autoscaling for AutoscalingClient {
         input: ExitStandbyQuery,
     ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("ExitStandby");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ExitStandby");
         let mut params = params;
         ExitStandbyQuerySerializer::serialize(&mut params, "", &input);
@@ -12230,6 +12468,9 @@ impl Autoscaling for AutoscalingClient {
         input: PutLifecycleHookType,
     ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("PutLifecycleHook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutLifecycleHook");
         let mut params = params;
         PutLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
@@ -12252,6 +12493,9 @@ impl Autoscaling for AutoscalingClient {
         input: PutNotificationConfigurationType,
     ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("PutNotificationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutNotificationConfiguration");
         let mut params = params;
         PutNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
@@ -12272,6 +12516,9 @@ impl Autoscaling for AutoscalingClient {
         input: PutScalingPolicyType,
     ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("PutScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutScalingPolicy");
         let mut params = params;
         PutScalingPolicyTypeSerializer::serialize(&mut params, "", &input);
@@ -12302,6 +12549,9 @@ impl Autoscaling for AutoscalingClient {
         input: PutScheduledUpdateGroupActionType,
     ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("PutScheduledUpdateGroupAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutScheduledUpdateGroupAction");
         let mut params = params;
         PutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
@@ -12325,6 +12575,9 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<RecordLifecycleActionHeartbeatError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("RecordLifecycleActionHeartbeat");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RecordLifecycleActionHeartbeat");
         let mut params = params;
         RecordLifecycleActionHeartbeatTypeSerializer::serialize(&mut params, "", &input);
@@ -12347,6 +12600,9 @@ impl Autoscaling for AutoscalingClient {
         input: ScalingProcessQuery,
     ) -> Result<(), RusotoError<ResumeProcessesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("ResumeProcesses");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResumeProcesses");
         let mut params = params;
         ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
@@ -12367,6 +12623,9 @@ impl Autoscaling for AutoscalingClient {
         input: SetDesiredCapacityType,
     ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("SetDesiredCapacity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetDesiredCapacity");
         let mut params = params;
         SetDesiredCapacityTypeSerializer::serialize(&mut params, "", &input);
@@ -12387,6 +12646,9 @@ impl Autoscaling for AutoscalingClient {
         input: SetInstanceHealthQuery,
     ) -> Result<(), RusotoError<SetInstanceHealthError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("SetInstanceHealth");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetInstanceHealth");
         let mut params = params;
         SetInstanceHealthQuerySerializer::serialize(&mut params, "", &input);
@@ -12407,6 +12669,9 @@ impl Autoscaling for AutoscalingClient {
         input: SetInstanceProtectionQuery,
     ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("SetInstanceProtection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetInstanceProtection");
         let mut params = params;
         SetInstanceProtectionQuerySerializer::serialize(&mut params, "", &input);
@@ -12429,6 +12694,9 @@ impl Autoscaling for AutoscalingClient {
         input: StartInstanceRefreshType,
     ) -> Result<StartInstanceRefreshAnswer, RusotoError<StartInstanceRefreshError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("StartInstanceRefresh");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartInstanceRefresh");
         let mut params = params;
         StartInstanceRefreshTypeSerializer::serialize(&mut params, "", &input);
@@ -12462,6 +12730,9 @@ impl Autoscaling for AutoscalingClient {
         input: ScalingProcessQuery,
     ) -> Result<(), RusotoError<SuspendProcessesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("SuspendProcesses");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SuspendProcesses");
         let mut params = params;
         ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
@@ -12482,6 +12753,9 @@ impl Autoscaling for AutoscalingClient {
         input: TerminateInstanceInAutoScalingGroupType,
     ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("TerminateInstanceInAutoScalingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TerminateInstanceInAutoScalingGroup");
         let mut params = params;
         TerminateInstanceInAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -12518,6 +12792,9 @@ impl Autoscaling for AutoscalingClient {
         input: UpdateAutoScalingGroupType,
     ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation_name("UpdateAutoScalingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateAutoScalingGroup");
         let mut params = params;
         UpdateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -12533,6 +12810,686 @@ impl Autoscaling for AutoscalingClient {
     }
 }
 
+/// A blocking client for the Auto Scaling API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AutoscalingClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AutoscalingBlockingClient {
+    client: AutoscalingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AutoscalingBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AutoscalingBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AutoscalingBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AutoscalingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AutoscalingBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient {
+            client: AutoscalingClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Attaches one or more EC2 instances to the specified Auto Scaling group.</p> <p>When you attach instances, Amazon EC2 Auto Scaling increases the desired capacity of the group by the number of instances being attached. If the number of instances being attached plus the desired capacity of the group exceeds the maximum size of the group, the operation fails.</p> <p>If there is a Classic Load Balancer attached to your Auto Scaling group, the instances are also registered with the load balancer. If there are target groups attached to your Auto Scaling group, the instances are also registered with the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-instance-asg.html">Attach EC2 Instances to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::attach_instances`.
+    pub fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> Result<(), RusotoError<AttachInstancesError>> {
+        self.runtime.block_on(self.client.attach_instances(input))
+    }
+
+    /// <p>Attaches one or more target groups to the specified Auto Scaling group.</p> <p>To describe the target groups for an Auto Scaling group, call the <a>DescribeLoadBalancerTargetGroups</a> API. To detach the target group from the Auto Scaling group, call the <a>DetachLoadBalancerTargetGroups</a> API.</p> <p>With Application Load Balancers and Network Load Balancers, instances are registered as targets with a target group. With Classic Load Balancers, instances are registered with the load balancer. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::attach_load_balancer_target_groups`.
+    pub fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        AttachLoadBalancerTargetGroupsResultType,
+        RusotoError<AttachLoadBalancerTargetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.attach_load_balancer_target_groups(input))
+    }
+
+    /// <p><note> <p>To attach an Application Load Balancer or a Network Load Balancer, use the <a>AttachLoadBalancerTargetGroups</a> API operation instead.</p> </note> <p>Attaches one or more Classic Load Balancers to the specified Auto Scaling group. Amazon EC2 Auto Scaling registers the running instances with these Classic Load Balancers.</p> <p>To describe the load balancers for an Auto Scaling group, call the <a>DescribeLoadBalancers</a> API. To detach the load balancer from the Auto Scaling group, call the <a>DetachLoadBalancers</a> API.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/attach-load-balancer-asg.html">Attaching a Load Balancer to Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p></p>
+    ///
+    /// See `Autoscaling::attach_load_balancers`.
+    pub fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.attach_load_balancers(input))
+    }
+
+    /// <p>Deletes one or more scheduled actions for the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::batch_delete_scheduled_action`.
+    pub fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_delete_scheduled_action(input))
+    }
+
+    /// <p>Creates or updates one or more scheduled scaling actions for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p>
+    ///
+    /// See `Autoscaling::batch_put_scheduled_update_group_action`.
+    pub fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> Result<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        RusotoError<BatchPutScheduledUpdateGroupActionError>,
+    > {
+        self.runtime
+            .block_on(self.client.batch_put_scheduled_update_group_action(input))
+    }
+
+    /// <p>Cancels an instance refresh operation in progress. Cancellation does not roll back any replacements that have already been completed, but it prevents new replacements from being started. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-instance-refresh.html">Replacing Auto Scaling Instances Based on an Instance Refresh</a>.</p>
+    ///
+    /// See `Autoscaling::cancel_instance_refresh`.
+    pub fn cancel_instance_refresh(
+        &self,
+        input: CancelInstanceRefreshType,
+    ) -> Result<CancelInstanceRefreshAnswer, RusotoError<CancelInstanceRefreshError>> {
+        self.runtime
+            .block_on(self.client.cancel_instance_refresh(input))
+    }
+
+    /// <p>Completes the lifecycle action for the specified token or instance with the specified result.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</p> </li> <li> <p> <b>If you finish before the timeout period ends, complete the lifecycle action.</b> </p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::complete_lifecycle_action`.
+    pub fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
+        self.runtime
+            .block_on(self.client.complete_lifecycle_action(input))
+    }
+
+    /// <p>Creates an Auto Scaling group with the specified name and attributes. </p> <p>If you exceed your maximum limit of Auto Scaling groups, the call fails. To query this limit, call the <a>DescribeAccountLimits</a> API. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Service Quotas</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>For introductory exercises for creating an Auto Scaling group, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/GettingStartedTutorial.html">Getting Started with Amazon EC2 Auto Scaling</a> and <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-register-lbs-with-asg.html">Tutorial: Set Up a Scaled and Load-Balanced Application</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/AutoScalingGroup.html">Auto Scaling Groups</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::create_auto_scaling_group`.
+    pub fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_auto_scaling_group(input))
+    }
+
+    /// <p>Creates a launch configuration.</p> <p>If you exceed your maximum limit of launch configurations, the call fails. To query this limit, call the <a>DescribeAccountLimits</a> API. For information about updating this limit, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Service Quotas</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/LaunchConfiguration.html">Launch Configurations</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::create_launch_configuration`.
+    pub fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_launch_configuration(input))
+    }
+
+    /// <p>Creates or updates tags for the specified Auto Scaling group.</p> <p>When you specify a tag with a key that already exists, the operation overwrites the previous tag definition, and you do not get an error message.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/autoscaling-tagging.html">Tagging Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::create_or_update_tags`.
+    pub fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
+        self.runtime
+            .block_on(self.client.create_or_update_tags(input))
+    }
+
+    /// <p>Deletes the specified Auto Scaling group.</p> <p>If the group has instances or scaling activities in progress, you must specify the option to force the deletion in order for it to succeed.</p> <p>If the group has policies, deleting the group deletes the policies, the underlying alarm actions, and any alarm that no longer has an associated action.</p> <p>To remove instances from the Auto Scaling group before deleting it, call the <a>DetachInstances</a> API with the list of instances and the option to decrement the desired capacity. This ensures that Amazon EC2 Auto Scaling does not launch replacement instances.</p> <p>To terminate all instances before deleting the Auto Scaling group, call the <a>UpdateAutoScalingGroup</a> API and set the minimum size and desired capacity of the Auto Scaling group to zero.</p>
+    ///
+    /// See `Autoscaling::delete_auto_scaling_group`.
+    pub fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_auto_scaling_group(input))
+    }
+
+    /// <p>Deletes the specified launch configuration.</p> <p>The launch configuration must not be attached to an Auto Scaling group. When this call completes, the launch configuration is no longer available for use.</p>
+    ///
+    /// See `Autoscaling::delete_launch_configuration`.
+    pub fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_launch_configuration(input))
+    }
+
+    /// <p>Deletes the specified lifecycle hook.</p> <p>If there are any outstanding lifecycle actions, they are completed first (<code>ABANDON</code> for launching instances, <code>CONTINUE</code> for terminating instances).</p>
+    ///
+    /// See `Autoscaling::delete_lifecycle_hook`.
+    pub fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
+        self.runtime
+            .block_on(self.client.delete_lifecycle_hook(input))
+    }
+
+    /// <p>Deletes the specified notification.</p>
+    ///
+    /// See `Autoscaling::delete_notification_configuration`.
+    pub fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_configuration(input))
+    }
+
+    /// <p>Deletes the specified scaling policy.</p> <p>Deleting either a step scaling policy or a simple scaling policy deletes the underlying alarm action, but does not delete the alarm, even if it no longer has an associated action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/deleting-scaling-policy.html">Deleting a Scaling Policy</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::delete_policy`.
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyType,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// <p>Deletes the specified scheduled action.</p>
+    ///
+    /// See `Autoscaling::delete_scheduled_action`.
+    pub fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_action(input))
+    }
+
+    /// <p>Deletes the specified tags.</p>
+    ///
+    /// See `Autoscaling::delete_tags`.
+    pub fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Describes the current Amazon EC2 Auto Scaling resource quotas for your AWS account.</p> <p>For information about requesting an increase, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-account-limits.html">Amazon EC2 Auto Scaling Service Quotas</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::describe_account_limits`.
+    pub fn describe_account_limits(
+        &self,
+    ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime.block_on(self.client.describe_account_limits())
+    }
+
+    /// <p><p>Describes the available adjustment types for Amazon EC2 Auto Scaling scaling policies. These settings apply to step scaling policies and simple scaling policies; they do not apply to target tracking scaling policies.</p> <p>The following adjustment types are supported:</p> <ul> <li> <p>ChangeInCapacity</p> </li> <li> <p>ExactCapacity</p> </li> <li> <p>PercentChangeInCapacity</p> </li> </ul></p>
+    ///
+    /// See `Autoscaling::describe_adjustment_types`.
+    pub fn describe_adjustment_types(
+        &self,
+    ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_adjustment_types())
+    }
+
+    /// <p>Describes one or more Auto Scaling groups.</p>
+    ///
+    /// See `Autoscaling::describe_auto_scaling_groups`.
+    pub fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_auto_scaling_groups(input))
+    }
+
+    /// <p>Describes one or more Auto Scaling instances.</p>
+    ///
+    /// See `Autoscaling::describe_auto_scaling_instances`.
+    pub fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_auto_scaling_instances(input))
+    }
+
+    /// <p>Describes the notification types that are supported by Amazon EC2 Auto Scaling.</p>
+    ///
+    /// See `Autoscaling::describe_auto_scaling_notification_types`.
+    pub fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> Result<
+        DescribeAutoScalingNotificationTypesAnswer,
+        RusotoError<DescribeAutoScalingNotificationTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_auto_scaling_notification_types())
+    }
+
+    /// <p>Describes one or more instance refreshes.</p> <p>You can determine the status of a request by looking at the <code>Status</code> parameter. The following are the possible statuses: </p> <ul> <li> <p> <code>Pending</code> - The request was created, but the operation has not started.</p> </li> <li> <p> <code>InProgress</code> - The operation is in progress.</p> </li> <li> <p> <code>Successful</code> - The operation completed successfully.</p> </li> <li> <p> <code>Failed</code> - The operation failed to complete. You can troubleshoot using the status reason and the scaling activities. </p> </li> <li> <p> <code>Cancelling</code> - An ongoing operation is being cancelled. Cancellation does not roll back any replacements that have already been completed, but it prevents new replacements from being started. </p> </li> <li> <p> <code>Cancelled</code> - The operation is cancelled. </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-instance-refresh.html">Replacing Auto Scaling Instances Based on an Instance Refresh</a>.</p>
+    ///
+    /// See `Autoscaling::describe_instance_refreshes`.
+    pub fn describe_instance_refreshes(
+        &self,
+        input: DescribeInstanceRefreshesType,
+    ) -> Result<DescribeInstanceRefreshesAnswer, RusotoError<DescribeInstanceRefreshesError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_refreshes(input))
+    }
+
+    /// <p>Describes one or more launch configurations.</p>
+    ///
+    /// See `Autoscaling::describe_launch_configurations`.
+    pub fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.describe_launch_configurations(input))
+    }
+
+    /// <p><p>Describes the available types of lifecycle hooks.</p> <p>The following hook types are supported:</p> <ul> <li> <p>autoscaling:EC2<em>INSTANCE</em>LAUNCHING</p> </li> <li> <p>autoscaling:EC2<em>INSTANCE</em>TERMINATING</p> </li> </ul></p>
+    ///
+    /// See `Autoscaling::describe_lifecycle_hook_types`.
+    pub fn describe_lifecycle_hook_types(
+        &self,
+    ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_lifecycle_hook_types())
+    }
+
+    /// <p>Describes the lifecycle hooks for the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::describe_lifecycle_hooks`.
+    pub fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
+        self.runtime
+            .block_on(self.client.describe_lifecycle_hooks(input))
+    }
+
+    /// <p>Describes the target groups for the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::describe_load_balancer_target_groups`.
+    pub fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> Result<
+        DescribeLoadBalancerTargetGroupsResponse,
+        RusotoError<DescribeLoadBalancerTargetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_target_groups(input))
+    }
+
+    /// <p>Describes the load balancers for the specified Auto Scaling group.</p> <p>This operation describes only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use the <a>DescribeLoadBalancerTargetGroups</a> API instead.</p>
+    ///
+    /// See `Autoscaling::describe_load_balancers`.
+    pub fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.describe_load_balancers(input))
+    }
+
+    /// <p>Describes the available CloudWatch metrics for Amazon EC2 Auto Scaling.</p> <p>The <code>GroupStandbyInstances</code> metric is not returned by default. You must explicitly request this metric when calling the <a>EnableMetricsCollection</a> API.</p>
+    ///
+    /// See `Autoscaling::describe_metric_collection_types`.
+    pub fn describe_metric_collection_types(
+        &self,
+    ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_metric_collection_types())
+    }
+
+    /// <p>Describes the notification actions associated with the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::describe_notification_configurations`.
+    pub fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> Result<
+        DescribeNotificationConfigurationsAnswer,
+        RusotoError<DescribeNotificationConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_notification_configurations(input))
+    }
+
+    /// <p>Describes the policies for the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::describe_policies`.
+    pub fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
+        self.runtime.block_on(self.client.describe_policies(input))
+    }
+
+    /// <p>Describes one or more scaling activities for the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::describe_scaling_activities`.
+    pub fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_activities(input))
+    }
+
+    /// <p>Describes the scaling process types for use with the <a>ResumeProcesses</a> and <a>SuspendProcesses</a> APIs.</p>
+    ///
+    /// See `Autoscaling::describe_scaling_process_types`.
+    pub fn describe_scaling_process_types(
+        &self,
+    ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_process_types())
+    }
+
+    /// <p>Describes the actions scheduled for your Auto Scaling group that haven't run or that have not reached their end time. To describe the actions that have already run, call the <a>DescribeScalingActivities</a> API.</p>
+    ///
+    /// See `Autoscaling::describe_scheduled_actions`.
+    pub fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_actions(input))
+    }
+
+    /// <p>Describes the specified tags.</p> <p>You can use filters to limit the results. For example, you can query for the tags for a specific Auto Scaling group. You can specify multiple values for a filter. A tag must match at least one of the specified values for it to be included in the results.</p> <p>You can also specify multiple filters. The result includes information for a particular tag only if it matches all the filters. If there's no match, no special message is returned.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/autoscaling-tagging.html">Tagging Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsType,
+    ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Describes the termination policies supported by Amazon EC2 Auto Scaling.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html">Controlling Which Auto Scaling Instances Terminate During Scale In</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::describe_termination_policy_types`.
+    pub fn describe_termination_policy_types(
+        &self,
+    ) -> Result<
+        DescribeTerminationPolicyTypesAnswer,
+        RusotoError<DescribeTerminationPolicyTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_termination_policy_types())
+    }
+
+    /// <p>Removes one or more instances from the specified Auto Scaling group.</p> <p>After the instances are detached, you can manage them independent of the Auto Scaling group.</p> <p>If you do not specify the option to decrement the desired capacity, Amazon EC2 Auto Scaling launches instances to replace the ones that are detached.</p> <p>If there is a Classic Load Balancer attached to the Auto Scaling group, the instances are deregistered from the load balancer. If there are target groups attached to the Auto Scaling group, the instances are deregistered from the target groups.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/detach-instance-asg.html">Detach EC2 Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::detach_instances`.
+    pub fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
+        self.runtime.block_on(self.client.detach_instances(input))
+    }
+
+    /// <p>Detaches one or more target groups from the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::detach_load_balancer_target_groups`.
+    pub fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        DetachLoadBalancerTargetGroupsResultType,
+        RusotoError<DetachLoadBalancerTargetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.detach_load_balancer_target_groups(input))
+    }
+
+    /// <p>Detaches one or more Classic Load Balancers from the specified Auto Scaling group.</p> <p>This operation detaches only Classic Load Balancers. If you have Application Load Balancers or Network Load Balancers, use the <a>DetachLoadBalancerTargetGroups</a> API instead.</p> <p>When you detach a load balancer, it enters the <code>Removing</code> state while deregistering the instances in the group. When all instances are deregistered, then you can no longer describe the load balancer using the <a>DescribeLoadBalancers</a> API call. The instances remain running.</p>
+    ///
+    /// See `Autoscaling::detach_load_balancers`.
+    pub fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.detach_load_balancers(input))
+    }
+
+    /// <p>Disables group metrics for the specified Auto Scaling group.</p>
+    ///
+    /// See `Autoscaling::disable_metrics_collection`.
+    pub fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
+        self.runtime
+            .block_on(self.client.disable_metrics_collection(input))
+    }
+
+    /// <p>Enables group metrics for the specified Auto Scaling group. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-monitoring.html">Monitoring Your Auto Scaling Groups and Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::enable_metrics_collection`.
+    pub fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
+        self.runtime
+            .block_on(self.client.enable_metrics_collection(input))
+    }
+
+    /// <p>Moves the specified instances into the standby state.</p> <p>If you choose to decrement the desired capacity of the Auto Scaling group, the instances can enter standby as long as the desired capacity of the Auto Scaling group after the instances are placed into standby is equal to or greater than the minimum capacity of the group.</p> <p>If you choose not to decrement the desired capacity of the Auto Scaling group, the Auto Scaling group launches new instances to replace the instances on standby.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::enter_standby`.
+    pub fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
+        self.runtime.block_on(self.client.enter_standby(input))
+    }
+
+    /// <p>Executes the specified policy. This can be useful for testing the design of your scaling policy.</p>
+    ///
+    /// See `Autoscaling::execute_policy`.
+    pub fn execute_policy(
+        &self,
+        input: ExecutePolicyType,
+    ) -> Result<(), RusotoError<ExecutePolicyError>> {
+        self.runtime.block_on(self.client.execute_policy(input))
+    }
+
+    /// <p>Moves the specified instances out of the standby state.</p> <p>After you put the instances back in service, the desired capacity is incremented.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-enter-exit-standby.html">Temporarily Removing Instances from Your Auto Scaling Group</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::exit_standby`.
+    pub fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
+        self.runtime.block_on(self.client.exit_standby(input))
+    }
+
+    /// <p>Creates or updates a lifecycle hook for the specified Auto Scaling group.</p> <p>A lifecycle hook tells Amazon EC2 Auto Scaling to perform an action on an instance when the instance launches (before it is put into service) or as the instance terminates (before it is fully terminated).</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p> <b>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</b> </p> </li> <li> <p>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state using the <a>RecordLifecycleActionHeartbeat</a> API call.</p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action using the <a>CompleteLifecycleAction</a> API call.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/lifecycle-hooks.html">Amazon EC2 Auto Scaling Lifecycle Hooks</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>If you exceed your maximum limit of lifecycle hooks, which by default is 50 per Auto Scaling group, the call fails.</p> <p>You can view the lifecycle hooks for an Auto Scaling group using the <a>DescribeLifecycleHooks</a> API call. If you are no longer using a lifecycle hook, you can delete it by calling the <a>DeleteLifecycleHook</a> API.</p>
+    ///
+    /// See `Autoscaling::put_lifecycle_hook`.
+    pub fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
+        self.runtime.block_on(self.client.put_lifecycle_hook(input))
+    }
+
+    /// <p>Configures an Auto Scaling group to send notifications when specified events take place. Subscribers to the specified topic can have messages delivered to an endpoint such as a web server or an email address.</p> <p>This configuration overwrites any existing configuration.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/ASGettingNotifications.html">Getting Amazon SNS Notifications When Your Auto Scaling Group Scales</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::put_notification_configuration`.
+    pub fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_notification_configuration(input))
+    }
+
+    /// <p>Creates or updates a scaling policy for an Auto Scaling group.</p> <p>For more information about using scaling policies to scale your Auto Scaling group, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scaling-target-tracking.html">Target Tracking Scaling Policies</a> and <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-scaling-simple-step.html">Step and Simple Scaling Policies</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::put_scaling_policy`.
+    pub fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
+        self.runtime.block_on(self.client.put_scaling_policy(input))
+    }
+
+    /// <p>Creates or updates a scheduled scaling action for an Auto Scaling group. If you leave a parameter unspecified when updating a scheduled scaling action, the corresponding value remains unchanged.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/schedule_time.html">Scheduled Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::put_scheduled_update_group_action`.
+    pub fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
+        self.runtime
+            .block_on(self.client.put_scheduled_update_group_action(input))
+    }
+
+    /// <p>Records a heartbeat for the lifecycle action associated with the specified token or instance. This extends the timeout by the length of time defined using the <a>PutLifecycleHook</a> API call.</p> <p>This step is a part of the procedure for adding a lifecycle hook to an Auto Scaling group:</p> <ol> <li> <p>(Optional) Create a Lambda function and a rule that allows CloudWatch Events to invoke your Lambda function when Amazon EC2 Auto Scaling launches or terminates instances.</p> </li> <li> <p>(Optional) Create a notification target and an IAM role. The target can be either an Amazon SQS queue or an Amazon SNS topic. The role allows Amazon EC2 Auto Scaling to publish lifecycle notifications to the target.</p> </li> <li> <p>Create the lifecycle hook. Specify whether the hook is used when the instances launch or terminate.</p> </li> <li> <p> <b>If you need more time, record the lifecycle action heartbeat to keep the instance in a pending state.</b> </p> </li> <li> <p>If you finish before the timeout period ends, complete the lifecycle action.</p> </li> </ol> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/AutoScalingGroupLifecycle.html">Auto Scaling Lifecycle</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::record_lifecycle_action_heartbeat`.
+    pub fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> Result<
+        RecordLifecycleActionHeartbeatAnswer,
+        RusotoError<RecordLifecycleActionHeartbeatError>,
+    > {
+        self.runtime
+            .block_on(self.client.record_lifecycle_action_heartbeat(input))
+    }
+
+    /// <p>Resumes the specified suspended automatic scaling processes, or all suspended process, for the specified Auto Scaling group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::resume_processes`.
+    pub fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<(), RusotoError<ResumeProcessesError>> {
+        self.runtime.block_on(self.client.resume_processes(input))
+    }
+
+    /// <p>Sets the size of the specified Auto Scaling group.</p> <p>If a scale-in activity occurs as a result of a new <code>DesiredCapacity</code> value that is lower than the current size of the group, the Auto Scaling group uses its termination policy to determine which instances to terminate. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-manual-scaling.html">Manual Scaling</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::set_desired_capacity`.
+    pub fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
+        self.runtime
+            .block_on(self.client.set_desired_capacity(input))
+    }
+
+    /// <p>Sets the health status of the specified instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/healthcheck.html">Health Checks for Auto Scaling Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::set_instance_health`.
+    pub fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> Result<(), RusotoError<SetInstanceHealthError>> {
+        self.runtime
+            .block_on(self.client.set_instance_health(input))
+    }
+
+    /// <p>Updates the instance protection settings of the specified instances.</p> <p>For more information about preventing instances that are part of an Auto Scaling group from terminating on scale in, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-instance-termination.html#instance-protection">Instance Protection</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::set_instance_protection`.
+    pub fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
+        self.runtime
+            .block_on(self.client.set_instance_protection(input))
+    }
+
+    /// <p>Starts a new instance refresh operation, which triggers a rolling replacement of all previously launched instances in the Auto Scaling group with a new group of instances.</p> <p>If successful, this call creates a new instance refresh request with a unique ID that you can use to track its progress. To query its status, call the <a>DescribeInstanceRefreshes</a> API. To describe the instance refreshes that have already run, call the <a>DescribeInstanceRefreshes</a> API. To cancel an instance refresh operation in progress, use the <a>CancelInstanceRefresh</a> API. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-instance-refresh.html">Replacing Auto Scaling Instances Based on an Instance Refresh</a>.</p>
+    ///
+    /// See `Autoscaling::start_instance_refresh`.
+    pub fn start_instance_refresh(
+        &self,
+        input: StartInstanceRefreshType,
+    ) -> Result<StartInstanceRefreshAnswer, RusotoError<StartInstanceRefreshError>> {
+        self.runtime
+            .block_on(self.client.start_instance_refresh(input))
+    }
+
+    /// <p>Suspends the specified automatic scaling processes, or all processes, for the specified Auto Scaling group.</p> <p>If you suspend either the <code>Launch</code> or <code>Terminate</code> process types, it can prevent other process types from functioning properly. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/as-suspend-resume-processes.html">Suspending and Resuming Scaling Processes</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p> <p>To resume processes that have been suspended, call the <a>ResumeProcesses</a> API.</p>
+    ///
+    /// See `Autoscaling::suspend_processes`.
+    pub fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<(), RusotoError<SuspendProcessesError>> {
+        self.runtime.block_on(self.client.suspend_processes(input))
+    }
+
+    /// <p>Terminates the specified instance and optionally adjusts the desired group size. </p> <p>This call simply makes a termination request. The instance is not terminated immediately. When an instance is terminated, the instance status changes to <code>terminated</code>. You can't connect to or start an instance after you've terminated it.</p> <p>If you do not specify the option to decrement the desired capacity, Amazon EC2 Auto Scaling launches instances to replace the ones that are terminated. </p> <p>By default, Amazon EC2 Auto Scaling balances instances across all Availability Zones. If you decrement the desired capacity, your Auto Scaling group can become unbalanced between Availability Zones. Amazon EC2 Auto Scaling tries to rebalance the group, and rebalancing might terminate instances in other zones. For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/auto-scaling-benefits.html#AutoScalingBehavior.InstanceUsage">Rebalancing Activities</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
+    ///
+    /// See `Autoscaling::terminate_instance_in_auto_scaling_group`.
+    pub fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.terminate_instance_in_auto_scaling_group(input))
+    }
+
+    /// <p>Updates the configuration for the specified Auto Scaling group.</p> <p>To update an Auto Scaling group, specify the name of the group and the parameter that you want to change. Any parameters that you don't specify are not changed by this update request. The new settings take effect on any scaling activities after this call returns. </p> <p>If you associate a new launch configuration or template with an Auto Scaling group, all new instances will get the updated configuration. Existing instances continue to run with the configuration that they were originally launched with. When you update a group to specify a mixed instances policy instead of a launch configuration or template, existing instances may be replaced to match the new purchasing options that you specified in the policy. For example, if the group currently has 100% On-Demand capacity and the policy specifies 50% Spot capacity, this means that half of your instances will be gradually terminated and relaunched as Spot Instances. When replacing instances, Amazon EC2 Auto Scaling launches new instances before terminating the old ones, so that updating your group does not compromise the performance or availability of your application.</p> <p>Note the following about changing <code>DesiredCapacity</code>, <code>MaxSize</code>, or <code>MinSize</code>:</p> <ul> <li> <p>If a scale-in activity occurs as a result of a new <code>DesiredCapacity</code> value that is lower than the current size of the group, the Auto Scaling group uses its termination policy to determine which instances to terminate.</p> </li> <li> <p>If you specify a new value for <code>MinSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MinSize</code> is larger than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MinSize</code> value.</p> </li> <li> <p>If you specify a new value for <code>MaxSize</code> without specifying a value for <code>DesiredCapacity</code>, and the new <code>MaxSize</code> is smaller than the current size of the group, this sets the group's <code>DesiredCapacity</code> to the new <code>MaxSize</code> value.</p> </li> </ul> <p>To see which parameters have been set, call the <a>DescribeAutoScalingGroups</a> API. To view the scaling policies for an Auto Scaling group, call the <a>DescribePolicies</a> API. If the group has scaling policies, you can update them by calling the <a>PutScalingPolicy</a> API.</p>
+    ///
+    /// See `Autoscaling::update_auto_scaling_group`.
+    pub fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_auto_scaling_group(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
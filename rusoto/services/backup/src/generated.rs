@@ -2078,23 +2078,34 @@ impl CreateBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupPlanError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupPlanError::AlreadyExists(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupPlanError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupPlanError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupPlanError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupPlanError::LimitExceeded(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupPlanError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupPlanError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupPlanError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2136,25 +2147,34 @@ impl CreateBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupSelectionError::AlreadyExists(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupSelectionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupSelectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupSelectionError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupSelectionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2196,25 +2216,34 @@ impl CreateBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupVaultError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupVaultError::AlreadyExists(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupVaultError::LimitExceeded(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2256,23 +2285,34 @@ impl DeleteBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupPlanError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupPlanError::InvalidRequest(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupPlanError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupPlanError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupPlanError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2312,24 +2352,28 @@ impl DeleteBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupSelectionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupSelectionError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupSelectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupSelectionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2370,25 +2414,34 @@ impl DeleteBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupVaultError::InvalidRequest(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupVaultError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2430,22 +2483,26 @@ impl DeleteBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2496,22 +2553,26 @@ impl DeleteBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultNotificationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultNotificationsError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultNotificationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBackupVaultNotificationsError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2562,27 +2623,34 @@ impl DeleteRecoveryPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRecoveryPointError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRecoveryPointError::InvalidRequest(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRecoveryPointError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRecoveryPointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRecoveryPointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2624,25 +2692,34 @@ impl DescribeBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyFailureException" => {
-                    return RusotoError::Service(DescribeBackupJobError::DependencyFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupJobError::DependencyFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBackupJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2682,24 +2759,28 @@ impl DescribeBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupVaultError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2738,20 +2819,28 @@ impl DescribeCopyJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeCopyJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCopyJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeCopyJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCopyJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCopyJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCopyJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCopyJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCopyJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2790,22 +2879,26 @@ impl DescribeProtectedResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProtectedResourceError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProtectedResourceError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectedResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProtectedResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProtectedResourceError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2850,24 +2943,28 @@ impl DescribeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecoveryPointError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecoveryPointError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecoveryPointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecoveryPointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2900,9 +2997,10 @@ impl DescribeRegionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRegionSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRegionSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2940,27 +3038,34 @@ impl DescribeRestoreJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyFailureException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::DependencyFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRestoreJobError::DependencyFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRestoreJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRestoreJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRestoreJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRestoreJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3000,24 +3105,28 @@ impl ExportBackupPlanTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportBackupPlanTemplateError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportBackupPlanTemplateError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportBackupPlanTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportBackupPlanTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportBackupPlanTemplateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportBackupPlanTemplateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3060,16 +3169,28 @@ impl GetBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3110,27 +3231,34 @@ impl GetBackupPlanFromJSONError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanFromJSONError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanFromJSONError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanFromJSONError::LimitExceeded(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanFromJSONError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanFromJSONError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3170,22 +3298,26 @@ impl GetBackupPlanFromTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupPlanFromTemplateError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupPlanFromTemplateError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupPlanFromTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupPlanFromTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupPlanFromTemplateError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3230,22 +3362,28 @@ impl GetBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupSelectionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupSelectionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupSelectionError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupSelectionError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupSelectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupSelectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupSelectionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupSelectionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3286,22 +3424,26 @@ impl GetBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3350,22 +3492,26 @@ impl GetBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultNotificationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultNotificationsError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultNotificationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBackupVaultNotificationsError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3414,22 +3560,26 @@ impl GetRecoveryPointRestoreMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecoveryPointRestoreMetadataError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecoveryPointRestoreMetadataError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecoveryPointRestoreMetadataError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecoveryPointRestoreMetadataError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3472,7 +3622,8 @@ impl GetSupportedResourceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSupportedResourceTypesError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3506,12 +3657,16 @@ impl ListBackupJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupJobsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupJobsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupJobsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3548,24 +3703,28 @@ impl ListBackupPlanTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListBackupPlanTemplatesError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListBackupPlanTemplatesError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlanTemplatesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlanTemplatesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlanTemplatesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlanTemplatesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3608,24 +3767,28 @@ impl ListBackupPlanVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListBackupPlanVersionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListBackupPlanVersionsError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlanVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlanVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlanVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlanVersionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3664,20 +3827,28 @@ impl ListBackupPlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupPlansError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlansError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupPlansError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlansError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlansError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlansError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlansError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupPlansError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3716,24 +3887,28 @@ impl ListBackupSelectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupSelectionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupSelectionsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupSelectionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupSelectionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3772,20 +3947,28 @@ impl ListBackupVaultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupVaultsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupVaultsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupVaultsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupVaultsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupVaultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupVaultsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupVaultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupVaultsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3820,10 +4003,16 @@ impl ListCopyJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListCopyJobsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCopyJobsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCopyJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCopyJobsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3856,14 +4045,16 @@ impl ListProtectedResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProtectedResourcesError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProtectedResourcesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProtectedResourcesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3902,22 +4093,26 @@ impl ListRecoveryPointsByBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByBackupVaultError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByBackupVaultError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByBackupVaultError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByBackupVaultError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3968,22 +4163,26 @@ impl ListRecoveryPointsByResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByResourceError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByResourceError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByResourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRecoveryPointsByResourceError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4032,20 +4231,28 @@ impl ListRestoreJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListRestoreJobsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRestoreJobsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListRestoreJobsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRestoreJobsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRestoreJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRestoreJobsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRestoreJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRestoreJobsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4084,16 +4291,22 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4134,22 +4347,26 @@ impl PutBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutBackupVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4198,22 +4415,26 @@ impl PutBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultNotificationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultNotificationsError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultNotificationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupVaultNotificationsError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4262,23 +4483,31 @@ impl StartBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartBackupJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartBackupJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartBackupJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartBackupJobError::LimitExceeded(err.msg))
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartBackupJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartBackupJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartBackupJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartBackupJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartBackupJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4320,19 +4549,28 @@ impl StartCopyJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartCopyJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartCopyJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartCopyJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartCopyJobError::LimitExceeded(err.msg))
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartCopyJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartCopyJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartCopyJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartCopyJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartCopyJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartCopyJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4372,20 +4610,28 @@ impl StartRestoreJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartRestoreJobError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRestoreJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartRestoreJobError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRestoreJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartRestoreJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartRestoreJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartRestoreJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartRestoreJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4426,19 +4672,31 @@ impl StopBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StopBackupJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopBackupJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopBackupJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, StopBackupJobError::InvalidRequest(err.msg))
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StopBackupJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopBackupJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopBackupJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopBackupJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopBackupJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4480,19 +4738,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4532,16 +4799,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4580,20 +4859,28 @@ impl UpdateBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBackupPlanError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBackupPlanError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBackupPlanError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBackupPlanError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4634,22 +4921,26 @@ impl UpdateRecoveryPointLifecycleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRecoveryPointLifecycleError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRecoveryPointLifecycleError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRecoveryPointLifecycleError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRecoveryPointLifecycleError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4696,19 +4987,22 @@ impl UpdateRegionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateRegionSettingsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegionSettingsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UpdateRegionSettingsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegionSettingsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRegionSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegionSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5041,6 +5335,7 @@ impl BackupClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5056,6 +5351,7 @@ impl BackupClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> BackupClient {
         BackupClient { client, region }
     }
@@ -5072,6 +5368,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("CreateBackupPlan");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5106,6 +5405,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("CreateBackupSelection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5140,6 +5442,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("CreateBackupVault");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5174,6 +5479,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation_name("DeleteBackupPlan");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5206,6 +5514,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation_name("DeleteBackupSelection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5236,6 +5547,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation_name("DeleteBackupVault");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5266,6 +5580,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation_name("DeleteBackupVaultAccessPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5296,6 +5613,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation_name("DeleteBackupVaultNotifications");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5327,6 +5647,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation_name("DeleteRecoveryPoint");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5357,6 +5680,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeBackupJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5388,6 +5714,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeBackupVault");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5416,6 +5745,9 @@ impl Backup for BackupClient {
         let request_uri = format!("/copy-jobs/{copy_job_id}", copy_job_id = input.copy_job_id);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeCopyJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5447,6 +5779,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeProtectedResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5479,6 +5814,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeRecoveryPoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5506,6 +5844,9 @@ impl Backup for BackupClient {
         let request_uri = "/account-settings";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeRegionSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5537,6 +5878,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("DescribeRestoreJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5568,6 +5912,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ExportBackupPlanTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5599,6 +5946,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetBackupPlan");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5633,6 +5983,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/json/toPlan";
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetBackupPlanFromJSON");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5667,6 +6020,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetBackupPlanFromTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5699,6 +6055,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetBackupSelection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5731,6 +6090,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetBackupVaultAccessPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5763,6 +6125,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetBackupVaultNotifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5794,6 +6159,9 @@ impl Backup for BackupClient {
         let request_uri = format!("/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}/restore-metadata", backup_vault_name = input.backup_vault_name, recovery_point_arn = input.recovery_point_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetRecoveryPointRestoreMetadata");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5823,6 +6191,9 @@ impl Backup for BackupClient {
         let request_uri = "/supported-resource-types";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("GetSupportedResourceTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5851,6 +6222,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListBackupJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5909,6 +6283,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/plans";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListBackupPlanTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5949,6 +6326,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListBackupPlanVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5986,6 +6366,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListBackupPlans");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6029,6 +6412,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListBackupSelections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6066,6 +6452,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup-vaults/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListBackupVaults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6103,6 +6492,9 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListCopyJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6161,6 +6553,9 @@ impl Backup for BackupClient {
         let request_uri = "/resources/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListProtectedResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6204,6 +6599,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListRecoveryPointsByBackupVault");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6262,6 +6660,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListRecoveryPointsByResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6299,6 +6700,9 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListRestoreJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6348,6 +6752,9 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}/", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6388,6 +6795,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("PutBackupVaultAccessPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6421,6 +6831,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("PutBackupVaultNotifications");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6451,6 +6864,9 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("StartBackupJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6482,6 +6898,9 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("StartCopyJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6513,6 +6932,9 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("StartRestoreJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6547,6 +6969,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation_name("StopBackupJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6574,6 +6999,9 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6604,6 +7032,9 @@ impl Backup for BackupClient {
         let request_uri = format!("/untag/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6637,6 +7068,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation_name("UpdateBackupPlan");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6673,6 +7107,9 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation_name("UpdateRecoveryPointLifecycle");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6704,6 +7141,9 @@ impl Backup for BackupClient {
         let request_uri = "/account-settings";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation_name("UpdateRegionSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6725,3 +7165,572 @@ impl Backup for BackupClient {
         }
     }
 }
+
+/// A blocking client for the AWS Backup API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `BackupClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BackupBlockingClient {
+    client: BackupClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl BackupBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> BackupBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        BackupBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BackupBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        BackupBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> BackupBlockingClient {
+        BackupBlockingClient {
+            client: BackupClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Backup plans are documents that contain information that AWS Backup uses to schedule tasks that create recovery points of resources.</p> <p>If you call <code>CreateBackupPlan</code> with a plan that already exists, an <code>AlreadyExistsException</code> is returned.</p>
+    ///
+    /// See `Backup::create_backup_plan`.
+    pub fn create_backup_plan(
+        &self,
+        input: CreateBackupPlanInput,
+    ) -> Result<CreateBackupPlanOutput, RusotoError<CreateBackupPlanError>> {
+        self.runtime.block_on(self.client.create_backup_plan(input))
+    }
+
+    /// <p>Creates a JSON document that specifies a set of resources to assign to a backup plan. Resources can be included by specifying patterns for a <code>ListOfTags</code> and selected <code>Resources</code>. </p> <p>For example, consider the following patterns:</p> <ul> <li> <p> <code>Resources: "arn:aws:ec2:region:account-id:volume/volume-id"</code> </p> </li> <li> <p> <code>ConditionKey:"department"</code> </p> <p> <code>ConditionValue:"finance"</code> </p> <p> <code>ConditionType:"STRINGEQUALS"</code> </p> </li> <li> <p> <code>ConditionKey:"importance"</code> </p> <p> <code>ConditionValue:"critical"</code> </p> <p> <code>ConditionType:"STRINGEQUALS"</code> </p> </li> </ul> <p>Using these patterns would back up all Amazon Elastic Block Store (Amazon EBS) volumes that are tagged as <code>"department=finance"</code>, <code>"importance=critical"</code>, in addition to an EBS volume with the specified volume Id.</p> <p>Resources and conditions are additive in that all resources that match the pattern are selected. This shouldn't be confused with a logical AND, where all conditions must match. The matching patterns are logically 'put together using the OR operator. In other words, all patterns that match are selected for backup.</p>
+    ///
+    /// See `Backup::create_backup_selection`.
+    pub fn create_backup_selection(
+        &self,
+        input: CreateBackupSelectionInput,
+    ) -> Result<CreateBackupSelectionOutput, RusotoError<CreateBackupSelectionError>> {
+        self.runtime
+            .block_on(self.client.create_backup_selection(input))
+    }
+
+    /// <p><p>Creates a logical container where backups are stored. A <code>CreateBackupVault</code> request includes a name, optionally one or more resource tags, an encryption key, and a request ID.</p> <note> <p>Sensitive data, such as passport numbers, should not be included the name of a backup vault.</p> </note></p>
+    ///
+    /// See `Backup::create_backup_vault`.
+    pub fn create_backup_vault(
+        &self,
+        input: CreateBackupVaultInput,
+    ) -> Result<CreateBackupVaultOutput, RusotoError<CreateBackupVaultError>> {
+        self.runtime
+            .block_on(self.client.create_backup_vault(input))
+    }
+
+    /// <p>Deletes a backup plan. A backup plan can only be deleted after all associated selections of resources have been deleted. Deleting a backup plan deletes the current version of a backup plan. Previous versions, if any, will still exist.</p>
+    ///
+    /// See `Backup::delete_backup_plan`.
+    pub fn delete_backup_plan(
+        &self,
+        input: DeleteBackupPlanInput,
+    ) -> Result<DeleteBackupPlanOutput, RusotoError<DeleteBackupPlanError>> {
+        self.runtime.block_on(self.client.delete_backup_plan(input))
+    }
+
+    /// <p>Deletes the resource selection associated with a backup plan that is specified by the <code>SelectionId</code>.</p>
+    ///
+    /// See `Backup::delete_backup_selection`.
+    pub fn delete_backup_selection(
+        &self,
+        input: DeleteBackupSelectionInput,
+    ) -> Result<(), RusotoError<DeleteBackupSelectionError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_selection(input))
+    }
+
+    /// <p>Deletes the backup vault identified by its name. A vault can be deleted only if it is empty.</p>
+    ///
+    /// See `Backup::delete_backup_vault`.
+    pub fn delete_backup_vault(
+        &self,
+        input: DeleteBackupVaultInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_vault(input))
+    }
+
+    /// <p>Deletes the policy document that manages permissions on a backup vault.</p>
+    ///
+    /// See `Backup::delete_backup_vault_access_policy`.
+    pub fn delete_backup_vault_access_policy(
+        &self,
+        input: DeleteBackupVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_vault_access_policy(input))
+    }
+
+    /// <p>Deletes event notifications for the specified backup vault.</p>
+    ///
+    /// See `Backup::delete_backup_vault_notifications`.
+    pub fn delete_backup_vault_notifications(
+        &self,
+        input: DeleteBackupVaultNotificationsInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_vault_notifications(input))
+    }
+
+    /// <p>Deletes the recovery point specified by a recovery point ID.</p>
+    ///
+    /// See `Backup::delete_recovery_point`.
+    pub fn delete_recovery_point(
+        &self,
+        input: DeleteRecoveryPointInput,
+    ) -> Result<(), RusotoError<DeleteRecoveryPointError>> {
+        self.runtime
+            .block_on(self.client.delete_recovery_point(input))
+    }
+
+    /// <p>Returns metadata associated with creating a backup of a resource.</p>
+    ///
+    /// See `Backup::describe_backup_job`.
+    pub fn describe_backup_job(
+        &self,
+        input: DescribeBackupJobInput,
+    ) -> Result<DescribeBackupJobOutput, RusotoError<DescribeBackupJobError>> {
+        self.runtime
+            .block_on(self.client.describe_backup_job(input))
+    }
+
+    /// <p>Returns metadata about a backup vault specified by its name.</p>
+    ///
+    /// See `Backup::describe_backup_vault`.
+    pub fn describe_backup_vault(
+        &self,
+        input: DescribeBackupVaultInput,
+    ) -> Result<DescribeBackupVaultOutput, RusotoError<DescribeBackupVaultError>> {
+        self.runtime
+            .block_on(self.client.describe_backup_vault(input))
+    }
+
+    /// <p>Returns metadata associated with creating a copy of a resource.</p>
+    ///
+    /// See `Backup::describe_copy_job`.
+    pub fn describe_copy_job(
+        &self,
+        input: DescribeCopyJobInput,
+    ) -> Result<DescribeCopyJobOutput, RusotoError<DescribeCopyJobError>> {
+        self.runtime.block_on(self.client.describe_copy_job(input))
+    }
+
+    /// <p>Returns information about a saved resource, including the last time it was backed up, its Amazon Resource Name (ARN), and the AWS service type of the saved resource.</p>
+    ///
+    /// See `Backup::describe_protected_resource`.
+    pub fn describe_protected_resource(
+        &self,
+        input: DescribeProtectedResourceInput,
+    ) -> Result<DescribeProtectedResourceOutput, RusotoError<DescribeProtectedResourceError>> {
+        self.runtime
+            .block_on(self.client.describe_protected_resource(input))
+    }
+
+    /// <p>Returns metadata associated with a recovery point, including ID, status, encryption, and lifecycle.</p>
+    ///
+    /// See `Backup::describe_recovery_point`.
+    pub fn describe_recovery_point(
+        &self,
+        input: DescribeRecoveryPointInput,
+    ) -> Result<DescribeRecoveryPointOutput, RusotoError<DescribeRecoveryPointError>> {
+        self.runtime
+            .block_on(self.client.describe_recovery_point(input))
+    }
+
+    /// <p>Returns the current service opt-in settings for the Region. If the service has a value set to <code>true</code>, AWS Backup attempts to protect that service's resources in this Region, when included in an on-demand backup or scheduled backup plan. If the value is set to <code>false</code> for a service, AWS Backup does not attempt to protect that service's resources in this Region.</p>
+    ///
+    /// See `Backup::describe_region_settings`.
+    pub fn describe_region_settings(
+        &self,
+    ) -> Result<DescribeRegionSettingsOutput, RusotoError<DescribeRegionSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_region_settings())
+    }
+
+    /// <p>Returns metadata associated with a restore job that is specified by a job ID.</p>
+    ///
+    /// See `Backup::describe_restore_job`.
+    pub fn describe_restore_job(
+        &self,
+        input: DescribeRestoreJobInput,
+    ) -> Result<DescribeRestoreJobOutput, RusotoError<DescribeRestoreJobError>> {
+        self.runtime
+            .block_on(self.client.describe_restore_job(input))
+    }
+
+    /// <p>Returns the backup plan that is specified by the plan ID as a backup template.</p>
+    ///
+    /// See `Backup::export_backup_plan_template`.
+    pub fn export_backup_plan_template(
+        &self,
+        input: ExportBackupPlanTemplateInput,
+    ) -> Result<ExportBackupPlanTemplateOutput, RusotoError<ExportBackupPlanTemplateError>> {
+        self.runtime
+            .block_on(self.client.export_backup_plan_template(input))
+    }
+
+    /// <p>Returns the body of a backup plan in JSON format, in addition to plan metadata.</p>
+    ///
+    /// See `Backup::get_backup_plan`.
+    pub fn get_backup_plan(
+        &self,
+        input: GetBackupPlanInput,
+    ) -> Result<GetBackupPlanOutput, RusotoError<GetBackupPlanError>> {
+        self.runtime.block_on(self.client.get_backup_plan(input))
+    }
+
+    /// <p>Returns a valid JSON document specifying a backup plan or an error.</p>
+    ///
+    /// See `Backup::get_backup_plan_from_json`.
+    pub fn get_backup_plan_from_json(
+        &self,
+        input: GetBackupPlanFromJSONInput,
+    ) -> Result<GetBackupPlanFromJSONOutput, RusotoError<GetBackupPlanFromJSONError>> {
+        self.runtime
+            .block_on(self.client.get_backup_plan_from_json(input))
+    }
+
+    /// <p>Returns the template specified by its <code>templateId</code> as a backup plan.</p>
+    ///
+    /// See `Backup::get_backup_plan_from_template`.
+    pub fn get_backup_plan_from_template(
+        &self,
+        input: GetBackupPlanFromTemplateInput,
+    ) -> Result<GetBackupPlanFromTemplateOutput, RusotoError<GetBackupPlanFromTemplateError>> {
+        self.runtime
+            .block_on(self.client.get_backup_plan_from_template(input))
+    }
+
+    /// <p>Returns selection metadata and a document in JSON format that specifies a list of resources that are associated with a backup plan.</p>
+    ///
+    /// See `Backup::get_backup_selection`.
+    pub fn get_backup_selection(
+        &self,
+        input: GetBackupSelectionInput,
+    ) -> Result<GetBackupSelectionOutput, RusotoError<GetBackupSelectionError>> {
+        self.runtime
+            .block_on(self.client.get_backup_selection(input))
+    }
+
+    /// <p>Returns the access policy document that is associated with the named backup vault.</p>
+    ///
+    /// See `Backup::get_backup_vault_access_policy`.
+    pub fn get_backup_vault_access_policy(
+        &self,
+        input: GetBackupVaultAccessPolicyInput,
+    ) -> Result<GetBackupVaultAccessPolicyOutput, RusotoError<GetBackupVaultAccessPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_backup_vault_access_policy(input))
+    }
+
+    /// <p>Returns event notifications for the specified backup vault.</p>
+    ///
+    /// See `Backup::get_backup_vault_notifications`.
+    pub fn get_backup_vault_notifications(
+        &self,
+        input: GetBackupVaultNotificationsInput,
+    ) -> Result<GetBackupVaultNotificationsOutput, RusotoError<GetBackupVaultNotificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_backup_vault_notifications(input))
+    }
+
+    /// <p>Returns a set of metadata key-value pairs that were used to create the backup.</p>
+    ///
+    /// See `Backup::get_recovery_point_restore_metadata`.
+    pub fn get_recovery_point_restore_metadata(
+        &self,
+        input: GetRecoveryPointRestoreMetadataInput,
+    ) -> Result<
+        GetRecoveryPointRestoreMetadataOutput,
+        RusotoError<GetRecoveryPointRestoreMetadataError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_recovery_point_restore_metadata(input))
+    }
+
+    /// <p>Returns the AWS resource types supported by AWS Backup.</p>
+    ///
+    /// See `Backup::get_supported_resource_types`.
+    pub fn get_supported_resource_types(
+        &self,
+    ) -> Result<GetSupportedResourceTypesOutput, RusotoError<GetSupportedResourceTypesError>> {
+        self.runtime
+            .block_on(self.client.get_supported_resource_types())
+    }
+
+    /// <p>Returns metadata about your backup jobs.</p>
+    ///
+    /// See `Backup::list_backup_jobs`.
+    pub fn list_backup_jobs(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> Result<ListBackupJobsOutput, RusotoError<ListBackupJobsError>> {
+        self.runtime.block_on(self.client.list_backup_jobs(input))
+    }
+
+    /// <p>Returns metadata of your saved backup plan templates, including the template ID, name, and the creation and deletion dates.</p>
+    ///
+    /// See `Backup::list_backup_plan_templates`.
+    pub fn list_backup_plan_templates(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> Result<ListBackupPlanTemplatesOutput, RusotoError<ListBackupPlanTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_backup_plan_templates(input))
+    }
+
+    /// <p>Returns version metadata of your backup plans, including Amazon Resource Names (ARNs), backup plan IDs, creation and deletion dates, plan names, and version IDs.</p>
+    ///
+    /// See `Backup::list_backup_plan_versions`.
+    pub fn list_backup_plan_versions(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> Result<ListBackupPlanVersionsOutput, RusotoError<ListBackupPlanVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_backup_plan_versions(input))
+    }
+
+    /// <p>Returns metadata of your saved backup plans, including Amazon Resource Names (ARNs), plan IDs, creation and deletion dates, version IDs, plan names, and creator request IDs.</p>
+    ///
+    /// See `Backup::list_backup_plans`.
+    pub fn list_backup_plans(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> Result<ListBackupPlansOutput, RusotoError<ListBackupPlansError>> {
+        self.runtime.block_on(self.client.list_backup_plans(input))
+    }
+
+    /// <p>Returns an array containing metadata of the resources associated with the target backup plan.</p>
+    ///
+    /// See `Backup::list_backup_selections`.
+    pub fn list_backup_selections(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> Result<ListBackupSelectionsOutput, RusotoError<ListBackupSelectionsError>> {
+        self.runtime
+            .block_on(self.client.list_backup_selections(input))
+    }
+
+    /// <p>Returns a list of recovery point storage containers along with information about them.</p>
+    ///
+    /// See `Backup::list_backup_vaults`.
+    pub fn list_backup_vaults(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> Result<ListBackupVaultsOutput, RusotoError<ListBackupVaultsError>> {
+        self.runtime.block_on(self.client.list_backup_vaults(input))
+    }
+
+    /// <p>Returns metadata about your copy jobs.</p>
+    ///
+    /// See `Backup::list_copy_jobs`.
+    pub fn list_copy_jobs(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> Result<ListCopyJobsOutput, RusotoError<ListCopyJobsError>> {
+        self.runtime.block_on(self.client.list_copy_jobs(input))
+    }
+
+    /// <p>Returns an array of resources successfully backed up by AWS Backup, including the time the resource was saved, an Amazon Resource Name (ARN) of the resource, and a resource type.</p>
+    ///
+    /// See `Backup::list_protected_resources`.
+    pub fn list_protected_resources(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> Result<ListProtectedResourcesOutput, RusotoError<ListProtectedResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_protected_resources(input))
+    }
+
+    /// <p>Returns detailed information about the recovery points stored in a backup vault.</p>
+    ///
+    /// See `Backup::list_recovery_points_by_backup_vault`.
+    pub fn list_recovery_points_by_backup_vault(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> Result<
+        ListRecoveryPointsByBackupVaultOutput,
+        RusotoError<ListRecoveryPointsByBackupVaultError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_recovery_points_by_backup_vault(input))
+    }
+
+    /// <p>Returns detailed information about recovery points of the type specified by a resource Amazon Resource Name (ARN).</p>
+    ///
+    /// See `Backup::list_recovery_points_by_resource`.
+    pub fn list_recovery_points_by_resource(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> Result<ListRecoveryPointsByResourceOutput, RusotoError<ListRecoveryPointsByResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.list_recovery_points_by_resource(input))
+    }
+
+    /// <p>Returns a list of jobs that AWS Backup initiated to restore a saved resource, including metadata about the recovery process.</p>
+    ///
+    /// See `Backup::list_restore_jobs`.
+    pub fn list_restore_jobs(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> Result<ListRestoreJobsOutput, RusotoError<ListRestoreJobsError>> {
+        self.runtime.block_on(self.client.list_restore_jobs(input))
+    }
+
+    /// <p><p>Returns a list of key-value pairs assigned to a target recovery point, backup plan, or backup vault.</p> <note> <p> <code>ListTags</code> are currently only supported with Amazon EFS backups.</p> </note></p>
+    ///
+    /// See `Backup::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Sets a resource-based policy that is used to manage access permissions on the target backup vault. Requires a backup vault name and an access policy document in JSON format.</p>
+    ///
+    /// See `Backup::put_backup_vault_access_policy`.
+    pub fn put_backup_vault_access_policy(
+        &self,
+        input: PutBackupVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<PutBackupVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_backup_vault_access_policy(input))
+    }
+
+    /// <p>Turns on notifications on a backup vault for the specified topic and events.</p>
+    ///
+    /// See `Backup::put_backup_vault_notifications`.
+    pub fn put_backup_vault_notifications(
+        &self,
+        input: PutBackupVaultNotificationsInput,
+    ) -> Result<(), RusotoError<PutBackupVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.put_backup_vault_notifications(input))
+    }
+
+    /// <p>Starts a job to create a one-time backup of the specified resource.</p>
+    ///
+    /// See `Backup::start_backup_job`.
+    pub fn start_backup_job(
+        &self,
+        input: StartBackupJobInput,
+    ) -> Result<StartBackupJobOutput, RusotoError<StartBackupJobError>> {
+        self.runtime.block_on(self.client.start_backup_job(input))
+    }
+
+    /// <p>Starts a job to create a one-time copy of the specified resource.</p>
+    ///
+    /// See `Backup::start_copy_job`.
+    pub fn start_copy_job(
+        &self,
+        input: StartCopyJobInput,
+    ) -> Result<StartCopyJobOutput, RusotoError<StartCopyJobError>> {
+        self.runtime.block_on(self.client.start_copy_job(input))
+    }
+
+    /// <p>Recovers the saved resource identified by an Amazon Resource Name (ARN). </p> <p>If the resource ARN is included in the request, then the last complete backup of that resource is recovered. If the ARN of a recovery point is supplied, then that recovery point is restored.</p>
+    ///
+    /// See `Backup::start_restore_job`.
+    pub fn start_restore_job(
+        &self,
+        input: StartRestoreJobInput,
+    ) -> Result<StartRestoreJobOutput, RusotoError<StartRestoreJobError>> {
+        self.runtime.block_on(self.client.start_restore_job(input))
+    }
+
+    /// <p>Attempts to cancel a job to create a one-time backup of a resource.</p>
+    ///
+    /// See `Backup::stop_backup_job`.
+    pub fn stop_backup_job(
+        &self,
+        input: StopBackupJobInput,
+    ) -> Result<(), RusotoError<StopBackupJobError>> {
+        self.runtime.block_on(self.client.stop_backup_job(input))
+    }
+
+    /// <p>Assigns a set of key-value pairs to a recovery point, backup plan, or backup vault identified by an Amazon Resource Name (ARN).</p>
+    ///
+    /// See `Backup::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a set of key-value pairs from a recovery point, backup plan, or backup vault identified by an Amazon Resource Name (ARN)</p>
+    ///
+    /// See `Backup::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Replaces the body of a saved backup plan identified by its <code>backupPlanId</code> with the input document in JSON format. The new version is uniquely identified by a <code>VersionId</code>.</p>
+    ///
+    /// See `Backup::update_backup_plan`.
+    pub fn update_backup_plan(
+        &self,
+        input: UpdateBackupPlanInput,
+    ) -> Result<UpdateBackupPlanOutput, RusotoError<UpdateBackupPlanError>> {
+        self.runtime.block_on(self.client.update_backup_plan(input))
+    }
+
+    /// <p>Sets the transition lifecycle of a recovery point.</p> <p>The lifecycle defines when a protected resource is transitioned to cold storage and when it expires. AWS Backup transitions and expires backups automatically according to the lifecycle that you define. </p> <p>Backups transitioned to cold storage must be stored in cold storage for a minimum of 90 days. Therefore, the “expire after days” setting must be 90 days greater than the “transition to cold after days” setting. The “transition to cold after days” setting cannot be changed after a backup has been transitioned to cold. </p>
+    ///
+    /// See `Backup::update_recovery_point_lifecycle`.
+    pub fn update_recovery_point_lifecycle(
+        &self,
+        input: UpdateRecoveryPointLifecycleInput,
+    ) -> Result<UpdateRecoveryPointLifecycleOutput, RusotoError<UpdateRecoveryPointLifecycleError>>
+    {
+        self.runtime
+            .block_on(self.client.update_recovery_point_lifecycle(input))
+    }
+
+    /// <p>Updates the current service opt-in settings for the Region. If the service has a value set to <code>true</code>, AWS Backup attempts to protect that service's resources in this Region, when included in an on-demand backup or scheduled backup plan. If the value is set to <code>false</code> for a service, AWS Backup does not attempt to protect that service's resources in this Region.</p>
+    ///
+    /// See `Backup::update_region_settings`.
+    pub fn update_region_settings(
+        &self,
+        input: UpdateRegionSettingsInput,
+    ) -> Result<(), RusotoError<UpdateRegionSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_region_settings(input))
+    }
+}
@@ -1351,8 +1351,12 @@ impl CancelJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CancelJobError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(CancelJobError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(CancelJobError::Server(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(&res, CancelJobError::Client(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, CancelJobError::Server(err.msg))
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -1384,10 +1388,16 @@ impl CreateComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputeEnvironmentError::Client(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputeEnvironmentError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1420,10 +1430,10 @@ impl CreateJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateJobQueueError::Client(err.msg))
+                    return RusotoError::service(&res, CreateJobQueueError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateJobQueueError::Server(err.msg))
+                    return RusotoError::service(&res, CreateJobQueueError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1456,10 +1466,16 @@ impl DeleteComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComputeEnvironmentError::Client(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComputeEnvironmentError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1492,10 +1508,10 @@ impl DeleteJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteJobQueueError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteJobQueueError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteJobQueueError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteJobQueueError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1528,10 +1544,16 @@ impl DeregisterJobDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterJobDefinitionError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterJobDefinitionError::Client(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterJobDefinitionError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterJobDefinitionError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1566,10 +1588,16 @@ impl DescribeComputeEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeComputeEnvironmentsError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeComputeEnvironmentsError::Client(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeComputeEnvironmentsError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeComputeEnvironmentsError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1602,10 +1630,10 @@ impl DescribeJobDefinitionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobDefinitionsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeJobDefinitionsError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobDefinitionsError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeJobDefinitionsError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1638,10 +1666,10 @@ impl DescribeJobQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobQueuesError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeJobQueuesError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobQueuesError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeJobQueuesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1674,10 +1702,10 @@ impl DescribeJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeJobsError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobsError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeJobsError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1709,8 +1737,12 @@ impl ListJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListJobsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListJobsError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(ListJobsError::Server(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(&res, ListJobsError::Client(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, ListJobsError::Server(err.msg))
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -1742,10 +1774,10 @@ impl RegisterJobDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterJobDefinitionError::Client(err.msg))
+                    return RusotoError::service(&res, RegisterJobDefinitionError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterJobDefinitionError::Server(err.msg))
+                    return RusotoError::service(&res, RegisterJobDefinitionError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1777,8 +1809,12 @@ impl SubmitJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SubmitJobError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(SubmitJobError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(SubmitJobError::Server(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(&res, SubmitJobError::Client(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, SubmitJobError::Server(err.msg))
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -1810,10 +1846,10 @@ impl TerminateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TerminateJobError::Client(err.msg))
+                    return RusotoError::service(&res, TerminateJobError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TerminateJobError::Server(err.msg))
+                    return RusotoError::service(&res, TerminateJobError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1846,10 +1882,16 @@ impl UpdateComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateComputeEnvironmentError::Client(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateComputeEnvironmentError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1882,10 +1924,10 @@ impl UpdateJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateJobQueueError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateJobQueueError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateJobQueueError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateJobQueueError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2021,6 +2063,7 @@ impl BatchClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2036,6 +2079,7 @@ impl BatchClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> BatchClient {
         BatchClient { client, region }
     }
@@ -2052,6 +2096,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/canceljob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2083,6 +2130,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createcomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("CreateComputeEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2114,6 +2164,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createjobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("CreateJobQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2145,6 +2198,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DeleteComputeEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2176,6 +2232,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DeleteJobQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2207,6 +2266,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deregisterjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DeregisterJobDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2239,6 +2301,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describecomputeenvironments";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DescribeComputeEnvironments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2270,6 +2335,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobdefinitions";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DescribeJobDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2301,6 +2369,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobqueues";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DescribeJobQueues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2332,6 +2403,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("DescribeJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2363,6 +2437,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/listjobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2394,6 +2471,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/registerjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("RegisterJobDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2425,6 +2505,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/submitjob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("SubmitJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2456,6 +2539,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/terminatejob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("TerminateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2487,6 +2573,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("UpdateComputeEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2518,6 +2607,9 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation_name("UpdateJobQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2540,3 +2632,222 @@ impl Batch for BatchClient {
         }
     }
 }
+
+/// A blocking client for the AWS Batch API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `BatchClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BatchBlockingClient {
+    client: BatchClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl BatchBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> BatchBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        BatchBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BatchBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        BatchBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> BatchBlockingClient {
+        BatchBlockingClient {
+            client: BatchClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels a job in an AWS Batch job queue. Jobs that are in the <code>SUBMITTED</code>, <code>PENDING</code>, or <code>RUNNABLE</code> state are cancelled. Jobs that have progressed to <code>STARTING</code> or <code>RUNNING</code> are not cancelled (but the API operation still succeeds, even if no job is cancelled); these jobs must be terminated with the <a>TerminateJob</a> operation.</p>
+    ///
+    /// See `Batch::cancel_job`.
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p><p>Creates an AWS Batch compute environment. You can create <code>MANAGED</code> or <code>UNMANAGED</code> compute environments.</p> <p>In a managed compute environment, AWS Batch manages the capacity and instance types of the compute resources within the environment. This is based on the compute resource specification that you define or the <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html">launch template</a> that you specify when you create the compute environment. You can choose to use Amazon EC2 On-Demand Instances or Spot Instances in your managed compute environment. You can optionally set a maximum price so that Spot Instances only launch when the Spot Instance price is below a specified percentage of the On-Demand price.</p> <note> <p>Multi-node parallel jobs are not supported on Spot Instances.</p> </note> <p>In an unmanaged compute environment, you can manage your own compute resources. This provides more compute resource configuration options, such as using a custom AMI, but you must ensure that your AMI meets the Amazon ECS container instance AMI specification. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container_instance_AMIs.html">Container Instance AMIs</a> in the <i>Amazon Elastic Container Service Developer Guide</i>. After you have created your unmanaged compute environment, you can use the <a>DescribeComputeEnvironments</a> operation to find the Amazon ECS cluster that is associated with it. Then, manually launch your container instances into that Amazon ECS cluster. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_container_instance.html">Launching an Amazon ECS Container Instance</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>AWS Batch does not upgrade the AMIs in a compute environment after it is created (for example, when a newer version of the Amazon ECS-optimized AMI is available). You are responsible for the management of the guest operating system (including updates and security patches) and any additional application software or utilities that you install on the compute resources. To use a new AMI for your AWS Batch jobs:</p> <ol> <li> <p>Create a new compute environment with the new AMI.</p> </li> <li> <p>Add the compute environment to an existing job queue.</p> </li> <li> <p>Remove the old compute environment from your job queue.</p> </li> <li> <p>Delete the old compute environment.</p> </li> </ol> </note></p>
+    ///
+    /// See `Batch::create_compute_environment`.
+    pub fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> Result<CreateComputeEnvironmentResponse, RusotoError<CreateComputeEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.create_compute_environment(input))
+    }
+
+    /// <p>Creates an AWS Batch job queue. When you create a job queue, you associate one or more compute environments to the queue and assign an order of preference for the compute environments.</p> <p>You also set a priority to the job queue that determines the order in which the AWS Batch scheduler places jobs onto its associated compute environments. For example, if a compute environment is associated with more than one job queue, the job queue with a higher priority is given preference for scheduling jobs to that compute environment.</p>
+    ///
+    /// See `Batch::create_job_queue`.
+    pub fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> Result<CreateJobQueueResponse, RusotoError<CreateJobQueueError>> {
+        self.runtime.block_on(self.client.create_job_queue(input))
+    }
+
+    /// <p>Deletes an AWS Batch compute environment.</p> <p>Before you can delete a compute environment, you must set its state to <code>DISABLED</code> with the <a>UpdateComputeEnvironment</a> API operation and disassociate it from any job queues with the <a>UpdateJobQueue</a> API operation.</p>
+    ///
+    /// See `Batch::delete_compute_environment`.
+    pub fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> Result<DeleteComputeEnvironmentResponse, RusotoError<DeleteComputeEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.delete_compute_environment(input))
+    }
+
+    /// <p>Deletes the specified job queue. You must first disable submissions for a queue with the <a>UpdateJobQueue</a> operation. All jobs in the queue are terminated when you delete a job queue.</p> <p>It is not necessary to disassociate compute environments from a queue before submitting a <code>DeleteJobQueue</code> request.</p>
+    ///
+    /// See `Batch::delete_job_queue`.
+    pub fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> Result<DeleteJobQueueResponse, RusotoError<DeleteJobQueueError>> {
+        self.runtime.block_on(self.client.delete_job_queue(input))
+    }
+
+    /// <p>Deregisters an AWS Batch job definition. Job definitions will be permanently deleted after 180 days.</p>
+    ///
+    /// See `Batch::deregister_job_definition`.
+    pub fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> Result<DeregisterJobDefinitionResponse, RusotoError<DeregisterJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.deregister_job_definition(input))
+    }
+
+    /// <p>Describes one or more of your compute environments.</p> <p>If you are using an unmanaged compute environment, you can use the <code>DescribeComputeEnvironment</code> operation to determine the <code>ecsClusterArn</code> that you should launch your Amazon ECS container instances into.</p>
+    ///
+    /// See `Batch::describe_compute_environments`.
+    pub fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> Result<DescribeComputeEnvironmentsResponse, RusotoError<DescribeComputeEnvironmentsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_compute_environments(input))
+    }
+
+    /// <p>Describes a list of job definitions. You can specify a <code>status</code> (such as <code>ACTIVE</code>) to only return job definitions that match that status.</p>
+    ///
+    /// See `Batch::describe_job_definitions`.
+    pub fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> Result<DescribeJobDefinitionsResponse, RusotoError<DescribeJobDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.describe_job_definitions(input))
+    }
+
+    /// <p>Describes one or more of your job queues.</p>
+    ///
+    /// See `Batch::describe_job_queues`.
+    pub fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> Result<DescribeJobQueuesResponse, RusotoError<DescribeJobQueuesError>> {
+        self.runtime
+            .block_on(self.client.describe_job_queues(input))
+    }
+
+    /// <p>Describes a list of AWS Batch jobs.</p>
+    ///
+    /// See `Batch::describe_jobs`.
+    pub fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> Result<DescribeJobsResponse, RusotoError<DescribeJobsError>> {
+        self.runtime.block_on(self.client.describe_jobs(input))
+    }
+
+    /// <p>Returns a list of AWS Batch jobs.</p> <p>You must specify only one of the following:</p> <ul> <li> <p>a job queue ID to return a list of jobs in that job queue</p> </li> <li> <p>a multi-node parallel job ID to return a list of that job's nodes</p> </li> <li> <p>an array job ID to return a list of that job's children</p> </li> </ul> <p>You can filter the results by job status with the <code>jobStatus</code> parameter. If you do not specify a status, only <code>RUNNING</code> jobs are returned.</p>
+    ///
+    /// See `Batch::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>Registers an AWS Batch job definition.</p>
+    ///
+    /// See `Batch::register_job_definition`.
+    pub fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> Result<RegisterJobDefinitionResponse, RusotoError<RegisterJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.register_job_definition(input))
+    }
+
+    /// <p>Submits an AWS Batch job from a job definition. Parameters specified during <a>SubmitJob</a> override parameters defined in the job definition.</p>
+    ///
+    /// See `Batch::submit_job`.
+    pub fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> Result<SubmitJobResponse, RusotoError<SubmitJobError>> {
+        self.runtime.block_on(self.client.submit_job(input))
+    }
+
+    /// <p>Terminates a job in a job queue. Jobs that are in the <code>STARTING</code> or <code>RUNNING</code> state are terminated, which causes them to transition to <code>FAILED</code>. Jobs that have not progressed to the <code>STARTING</code> state are cancelled.</p>
+    ///
+    /// See `Batch::terminate_job`.
+    pub fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> Result<TerminateJobResponse, RusotoError<TerminateJobError>> {
+        self.runtime.block_on(self.client.terminate_job(input))
+    }
+
+    /// <p>Updates an AWS Batch compute environment.</p>
+    ///
+    /// See `Batch::update_compute_environment`.
+    pub fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> Result<UpdateComputeEnvironmentResponse, RusotoError<UpdateComputeEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.update_compute_environment(input))
+    }
+
+    /// <p>Updates a job queue.</p>
+    ///
+    /// See `Batch::update_job_queue`.
+    pub fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> Result<UpdateJobQueueResponse, RusotoError<UpdateJobQueueError>> {
+        self.runtime.block_on(self.client.update_job_queue(input))
+    }
+}
@@ -635,19 +635,22 @@ impl CreateBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateBudgetError::AccessDenied(err.msg))
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateBudgetError::CreationLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBudgetError::CreationLimitExceeded(err.msg),
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateBudgetError::DuplicateRecord(err.msg))
+                    return RusotoError::service(&res, CreateBudgetError::DuplicateRecord(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateBudgetError::InternalError(err.msg))
+                    return RusotoError::service(&res, CreateBudgetError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateBudgetError::InvalidParameter(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -691,24 +694,37 @@ impl CreateNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationError::AccessDenied(err.msg),
+                    )
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateNotificationError::CreationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationError::CreationLimitExceeded(err.msg),
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateNotificationError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationError::DuplicateRecord(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateNotificationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateNotificationError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateNotificationError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -753,24 +769,34 @@ impl CreateSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateSubscriberError::AccessDenied(err.msg))
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateSubscriberError::CreationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriberError::CreationLimitExceeded(err.msg),
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateSubscriberError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriberError::DuplicateRecord(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateSubscriberError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriberError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriberError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateSubscriberError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateSubscriberError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -811,16 +837,16 @@ impl DeleteBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteBudgetError::AccessDenied(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteBudgetError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeleteBudgetError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteBudgetError::InvalidParameter(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBudgetError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBudgetError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -859,16 +885,25 @@ impl DeleteNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteNotificationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNotificationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteNotificationError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -907,16 +942,22 @@ impl DeleteSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteSubscriberError::AccessDenied(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteSubscriberError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriberError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriberError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriberError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSubscriberError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -955,16 +996,19 @@ impl DescribeBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeBudgetError::AccessDenied(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetError::InternalError(err.msg))
+                    return RusotoError::service(&res, DescribeBudgetError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBudgetError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeBudgetError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1009,34 +1053,40 @@ impl DescribeBudgetPerformanceHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBudgetPerformanceHistoryError::AccessDenied(err.msg),
                     )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBudgetPerformanceHistoryError::ExpiredNextToken(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBudgetPerformanceHistoryError::InternalError(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBudgetPerformanceHistoryError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBudgetPerformanceHistoryError::InvalidParameter(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetPerformanceHistoryError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBudgetPerformanceHistoryError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1091,22 +1141,31 @@ impl DescribeBudgetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeBudgetsError::AccessDenied(err.msg))
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(DescribeBudgetsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBudgetsError::ExpiredNextToken(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InternalError(err.msg))
+                    return RusotoError::service(&res, DescribeBudgetsError::InternalError(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBudgetsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBudgetsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeBudgetsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1153,34 +1212,40 @@ impl DescribeNotificationsForBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeNotificationsForBudgetError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNotificationsForBudgetError::AccessDenied(err.msg),
+                    )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationsForBudgetError::ExpiredNextToken(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationsForBudgetError::InternalError(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationsForBudgetError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationsForBudgetError::InvalidParameter(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeNotificationsForBudgetError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNotificationsForBudgetError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1233,34 +1298,40 @@ impl DescribeSubscribersForNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscribersForNotificationError::AccessDenied(err.msg),
                     )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscribersForNotificationError::ExpiredNextToken(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscribersForNotificationError::InternalError(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscribersForNotificationError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscribersForNotificationError::InvalidParameter(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeSubscribersForNotificationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSubscribersForNotificationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1311,16 +1382,16 @@ impl UpdateBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateBudgetError::AccessDenied(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateBudgetError::InternalError(err.msg))
+                    return RusotoError::service(&res, UpdateBudgetError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateBudgetError::InvalidParameter(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBudgetError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateBudgetError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1361,19 +1432,31 @@ impl UpdateNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationError::AccessDenied(err.msg),
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(UpdateNotificationError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationError::DuplicateRecord(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateNotificationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNotificationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateNotificationError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1415,19 +1498,28 @@ impl UpdateSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateSubscriberError::AccessDenied(err.msg))
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(UpdateSubscriberError::DuplicateRecord(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriberError::DuplicateRecord(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateSubscriberError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriberError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriberError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSubscriberError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateSubscriberError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1563,6 +1655,7 @@ impl BudgetsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1578,6 +1671,7 @@ impl BudgetsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> BudgetsClient {
         BudgetsClient { client, region }
     }
@@ -1591,6 +1685,9 @@ impl Budgets for BudgetsClient {
         input: CreateBudgetRequest,
     ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBudget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1609,6 +1706,9 @@ impl Budgets for BudgetsClient {
         input: CreateNotificationRequest,
     ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNotification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1627,6 +1727,9 @@ impl Budgets for BudgetsClient {
         input: CreateSubscriberRequest,
     ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSubscriber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1645,6 +1748,9 @@ impl Budgets for BudgetsClient {
         input: DeleteBudgetRequest,
     ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBudget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1663,6 +1769,9 @@ impl Budgets for BudgetsClient {
         input: DeleteNotificationRequest,
     ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNotification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1681,6 +1790,9 @@ impl Budgets for BudgetsClient {
         input: DeleteSubscriberRequest,
     ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSubscriber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1699,6 +1811,9 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetRequest,
     ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBudget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1720,6 +1835,9 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeBudgetPerformanceHistoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBudgetPerformanceHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeBudgetPerformanceHistory",
@@ -1745,6 +1863,9 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetsRequest,
     ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBudgets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudgets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1766,6 +1887,9 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeNotificationsForBudgetError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeNotificationsForBudget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeNotificationsForBudget",
@@ -1791,6 +1915,9 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeSubscribersForNotificationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSubscribersForNotification");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeSubscribersForNotification",
@@ -1816,6 +1943,9 @@ impl Budgets for BudgetsClient {
         input: UpdateBudgetRequest,
     ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateBudget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1834,6 +1964,9 @@ impl Budgets for BudgetsClient {
         input: UpdateNotificationRequest,
     ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNotification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1852,6 +1985,9 @@ impl Budgets for BudgetsClient {
         input: UpdateSubscriberRequest,
     ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSubscriber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1864,3 +2000,208 @@ impl Budgets for BudgetsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubscriberResponse, _>()
     }
 }
+
+/// A blocking client for the AWSBudgets API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `BudgetsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BudgetsBlockingClient {
+    client: BudgetsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl BudgetsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> BudgetsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        BudgetsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BudgetsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        BudgetsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> BudgetsBlockingClient {
+        BudgetsBlockingClient {
+            client: BudgetsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Creates a budget and, if included, notifications and subscribers. </p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_CreateBudget.html#API_CreateBudget_Examples">Examples</a> section. </p> </important></p>
+    ///
+    /// See `Budgets::create_budget`.
+    pub fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
+        self.runtime.block_on(self.client.create_budget(input))
+    }
+
+    /// <p>Creates a notification. You must create the budget before you create the associated notification.</p>
+    ///
+    /// See `Budgets::create_notification`.
+    pub fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
+        self.runtime
+            .block_on(self.client.create_notification(input))
+    }
+
+    /// <p>Creates a subscriber. You must create the associated budget and notification before you create the subscriber.</p>
+    ///
+    /// See `Budgets::create_subscriber`.
+    pub fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
+        self.runtime.block_on(self.client.create_subscriber(input))
+    }
+
+    /// <p><p>Deletes a budget. You can delete your budget at any time.</p> <important> <p>Deleting a budget also deletes the notifications and subscribers that are associated with that budget.</p> </important></p>
+    ///
+    /// See `Budgets::delete_budget`.
+    pub fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
+        self.runtime.block_on(self.client.delete_budget(input))
+    }
+
+    /// <p><p>Deletes a notification.</p> <important> <p>Deleting a notification also deletes the subscribers that are associated with the notification.</p> </important></p>
+    ///
+    /// See `Budgets::delete_notification`.
+    pub fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
+        self.runtime
+            .block_on(self.client.delete_notification(input))
+    }
+
+    /// <p><p>Deletes a subscriber.</p> <important> <p>Deleting the last subscriber to a notification also deletes the notification.</p> </important></p>
+    ///
+    /// See `Budgets::delete_subscriber`.
+    pub fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
+        self.runtime.block_on(self.client.delete_subscriber(input))
+    }
+
+    /// <p><p>Describes a budget.</p> <important> <p>The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_DescribeBudget.html#API_DescribeBudget_Examples">Examples</a> section. </p> </important></p>
+    ///
+    /// See `Budgets::describe_budget`.
+    pub fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
+        self.runtime.block_on(self.client.describe_budget(input))
+    }
+
+    /// <p>Describes the history for <code>DAILY</code>, <code>MONTHLY</code>, and <code>QUARTERLY</code> budgets. Budget history isn't available for <code>ANNUAL</code> budgets.</p>
+    ///
+    /// See `Budgets::describe_budget_performance_history`.
+    pub fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> Result<
+        DescribeBudgetPerformanceHistoryResponse,
+        RusotoError<DescribeBudgetPerformanceHistoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_budget_performance_history(input))
+    }
+
+    /// <p><p>Lists the budgets that are associated with an account.</p> <important> <p>The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_DescribeBudgets.html#API_DescribeBudgets_Examples">Examples</a> section. </p> </important></p>
+    ///
+    /// See `Budgets::describe_budgets`.
+    pub fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
+        self.runtime.block_on(self.client.describe_budgets(input))
+    }
+
+    /// <p>Lists the notifications that are associated with a budget.</p>
+    ///
+    /// See `Budgets::describe_notifications_for_budget`.
+    pub fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> Result<
+        DescribeNotificationsForBudgetResponse,
+        RusotoError<DescribeNotificationsForBudgetError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_notifications_for_budget(input))
+    }
+
+    /// <p>Lists the subscribers that are associated with a notification.</p>
+    ///
+    /// See `Budgets::describe_subscribers_for_notification`.
+    pub fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> Result<
+        DescribeSubscribersForNotificationResponse,
+        RusotoError<DescribeSubscribersForNotificationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_subscribers_for_notification(input))
+    }
+
+    /// <p><p>Updates a budget. You can change every part of a budget except for the <code>budgetName</code> and the <code>calculatedSpend</code>. When you modify a budget, the <code>calculatedSpend</code> drops to zero until AWS has new usage data to use for forecasting.</p> <important> <p>Only one of <code>BudgetLimit</code> or <code>PlannedBudgetLimits</code> can be present in the syntax at one time. Use the syntax that matches your case. The Request Syntax section shows the <code>BudgetLimit</code> syntax. For <code>PlannedBudgetLimits</code>, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_budgets_UpdateBudget.html#API_UpdateBudget_Examples">Examples</a> section. </p> </important></p>
+    ///
+    /// See `Budgets::update_budget`.
+    pub fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
+        self.runtime.block_on(self.client.update_budget(input))
+    }
+
+    /// <p>Updates a notification.</p>
+    ///
+    /// See `Budgets::update_notification`.
+    pub fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
+        self.runtime
+            .block_on(self.client.update_notification(input))
+    }
+
+    /// <p>Updates a subscriber.</p>
+    ///
+    /// See `Budgets::update_subscriber`.
+    pub fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
+        self.runtime.block_on(self.client.update_subscriber(input))
+    }
+}
@@ -2184,12 +2184,14 @@ impl CreateCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCostCategoryDefinitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCostCategoryDefinitionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCostCategoryDefinitionError::ServiceQuotaExceeded(err.msg),
                     )
                 }
@@ -2228,12 +2230,14 @@ impl DeleteCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteCostCategoryDefinitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCostCategoryDefinitionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteCostCategoryDefinitionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2272,12 +2276,14 @@ impl DescribeCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCostCategoryDefinitionError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCostCategoryDefinitionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2320,19 +2326,31 @@ impl GetCostAndUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetCostAndUsageError::BillExpiration(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageError::BillExpiration(err.msg),
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetCostAndUsageError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageError::DataUnavailable(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetCostAndUsageError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostAndUsageError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetCostAndUsageError::LimitExceeded(err.msg))
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetCostAndUsageError::RequestChanged(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageError::RequestChanged(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2376,29 +2394,34 @@ impl GetCostAndUsageWithResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::BillExpiration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageWithResourcesError::BillExpiration(err.msg),
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCostAndUsageWithResourcesError::DataUnavailable(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCostAndUsageWithResourcesError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageWithResourcesError::LimitExceeded(err.msg),
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::RequestChanged(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCostAndUsageWithResourcesError::RequestChanged(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2436,10 +2459,13 @@ impl GetCostForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetCostForecastError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCostForecastError::DataUnavailable(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetCostForecastError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2478,19 +2504,34 @@ impl GetDimensionValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetDimensionValuesError::BillExpiration(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDimensionValuesError::BillExpiration(err.msg),
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetDimensionValuesError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDimensionValuesError::DataUnavailable(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetDimensionValuesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDimensionValuesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDimensionValuesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDimensionValuesError::LimitExceeded(err.msg),
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetDimensionValuesError::RequestChanged(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDimensionValuesError::RequestChanged(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2528,19 +2569,22 @@ impl GetReservationCoverageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetReservationCoverageError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReservationCoverageError::DataUnavailable(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetReservationCoverageError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReservationCoverageError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetReservationCoverageError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReservationCoverageError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2578,17 +2622,20 @@ impl GetReservationPurchaseRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetReservationPurchaseRecommendationError::DataUnavailable(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetReservationPurchaseRecommendationError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetReservationPurchaseRecommendationError::LimitExceeded(err.msg),
                     )
                 }
@@ -2632,19 +2679,22 @@ impl GetReservationUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReservationUtilizationError::DataUnavailable(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReservationUtilizationError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReservationUtilizationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2680,14 +2730,16 @@ impl GetRightsizingRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRightsizingRecommendationError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRightsizingRecommendationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRightsizingRecommendationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2724,19 +2776,22 @@ impl GetSavingsPlansCoverageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSavingsPlansCoverageError::DataUnavailable(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSavingsPlansCoverageError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSavingsPlansCoverageError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2772,12 +2827,14 @@ impl GetSavingsPlansPurchaseRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSavingsPlansPurchaseRecommendationError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSavingsPlansPurchaseRecommendationError::LimitExceeded(err.msg),
                     )
                 }
@@ -2818,14 +2875,16 @@ impl GetSavingsPlansUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetSavingsPlansUtilizationError::DataUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSavingsPlansUtilizationError::DataUnavailable(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSavingsPlansUtilizationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSavingsPlansUtilizationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2862,17 +2921,20 @@ impl GetSavingsPlansUtilizationDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSavingsPlansUtilizationDetailsError::DataUnavailable(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSavingsPlansUtilizationDetailsError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSavingsPlansUtilizationDetailsError::LimitExceeded(err.msg),
                     )
                 }
@@ -2920,19 +2982,19 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetTagsError::BillExpiration(err.msg))
+                    return RusotoError::service(&res, GetTagsError::BillExpiration(err.msg))
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetTagsError::DataUnavailable(err.msg))
+                    return RusotoError::service(&res, GetTagsError::DataUnavailable(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetTagsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, GetTagsError::InvalidNextToken(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetTagsError::LimitExceeded(err.msg))
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetTagsError::RequestChanged(err.msg))
+                    return RusotoError::service(&res, GetTagsError::RequestChanged(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2970,15 +3032,22 @@ impl GetUsageForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetUsageForecastError::DataUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUsageForecastError::DataUnavailable(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUsageForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUsageForecastError::LimitExceeded(err.msg),
+                    )
                 }
                 "UnresolvableUsageUnitException" => {
-                    return RusotoError::Service(GetUsageForecastError::UnresolvableUsageUnit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUsageForecastError::UnresolvableUsageUnit(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3012,9 +3081,10 @@ impl ListCostCategoryDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListCostCategoryDefinitionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCostCategoryDefinitionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3050,17 +3120,20 @@ impl UpdateCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCostCategoryDefinitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCostCategoryDefinitionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCostCategoryDefinitionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCostCategoryDefinitionError::ServiceQuotaExceeded(err.msg),
                     )
                 }
@@ -3233,6 +3306,7 @@ impl CostExplorerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3248,6 +3322,7 @@ impl CostExplorerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CostExplorerClient {
         CostExplorerClient { client, region }
     }
@@ -3262,6 +3337,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCostCategoryDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.CreateCostCategoryDefinition",
@@ -3285,6 +3363,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCostCategoryDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.DeleteCostCategoryDefinition",
@@ -3310,6 +3391,9 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<DescribeCostCategoryDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCostCategoryDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.DescribeCostCategoryDefinition",
@@ -3332,6 +3416,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetCostAndUsageRequest,
     ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCostAndUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostAndUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3351,6 +3438,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCostAndUsageWithResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetCostAndUsageWithResources",
@@ -3373,6 +3463,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetCostForecastRequest,
     ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCostForecast");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3391,6 +3484,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetDimensionValuesRequest,
     ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDimensionValues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetDimensionValues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3409,6 +3505,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetReservationCoverageRequest,
     ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetReservationCoverage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetReservationCoverage",
@@ -3434,6 +3533,9 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetReservationPurchaseRecommendationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetReservationPurchaseRecommendation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetReservationPurchaseRecommendation",
@@ -3460,6 +3562,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetReservationUtilization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetReservationUtilization",
@@ -3483,6 +3588,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRightsizingRecommendation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetRightsizingRecommendation",
@@ -3505,6 +3613,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetSavingsPlansCoverageRequest,
     ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSavingsPlansCoverage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansCoverage",
@@ -3530,6 +3641,9 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetSavingsPlansPurchaseRecommendationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSavingsPlansPurchaseRecommendation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansPurchaseRecommendation",
@@ -3556,6 +3670,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSavingsPlansUtilization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansUtilization",
@@ -3581,6 +3698,9 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetSavingsPlansUtilizationDetailsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSavingsPlansUtilizationDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansUtilizationDetails",
@@ -3606,6 +3726,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetTagsRequest,
     ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3624,6 +3747,9 @@ impl CostExplorer for CostExplorerClient {
         input: GetUsageForecastRequest,
     ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUsageForecast");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetUsageForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3643,6 +3769,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCostCategoryDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.ListCostCategoryDefinitions",
@@ -3666,6 +3795,9 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCostCategoryDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.UpdateCostCategoryDefinition",
@@ -3682,3 +3814,278 @@ impl CostExplorer for CostExplorerClient {
             .deserialize::<UpdateCostCategoryDefinitionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Cost Explorer API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CostExplorerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CostExplorerBlockingClient {
+    client: CostExplorerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CostExplorerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CostExplorerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CostExplorerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CostExplorerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CostExplorerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient {
+            client: CostExplorerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new Cost Category with the requested name and rules.</p>
+    ///
+    /// See `CostExplorer::create_cost_category_definition`.
+    pub fn create_cost_category_definition(
+        &self,
+        input: CreateCostCategoryDefinitionRequest,
+    ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cost_category_definition(input))
+    }
+
+    /// <p>Deletes a Cost Category. Expenses from this month going forward will no longer be categorized with this Cost Category.</p>
+    ///
+    /// See `CostExplorer::delete_cost_category_definition`.
+    pub fn delete_cost_category_definition(
+        &self,
+        input: DeleteCostCategoryDefinitionRequest,
+    ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_cost_category_definition(input))
+    }
+
+    /// <p>Returns the name, ARN, rules, definition, and effective dates of a Cost Category that's defined in the account.</p> <p>You have the option to use <code>EffectiveOn</code> to return a Cost Category that is active on a specific date. If there is no <code>EffectiveOn</code> specified, you’ll see a Cost Category that is effective on the current date. If Cost Category is still effective, <code>EffectiveEnd</code> is omitted in the response. </p>
+    ///
+    /// See `CostExplorer::describe_cost_category_definition`.
+    pub fn describe_cost_category_definition(
+        &self,
+        input: DescribeCostCategoryDefinitionRequest,
+    ) -> Result<
+        DescribeCostCategoryDefinitionResponse,
+        RusotoError<DescribeCostCategoryDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_cost_category_definition(input))
+    }
+
+    /// <p>Retrieves cost and usage metrics for your account. You can specify which cost and usage-related metric, such as <code>BlendedCosts</code> or <code>UsageQuantity</code>, that you want the request to return. You can also filter and group your data by various dimensions, such as <code>SERVICE</code> or <code>AZ</code>, in a specific time range. For a complete list of valid dimensions, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_GetDimensionValues.html">GetDimensionValues</a> operation. Master accounts in an organization in AWS Organizations have access to all member accounts.</p>
+    ///
+    /// See `CostExplorer::get_cost_and_usage`.
+    pub fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
+        self.runtime.block_on(self.client.get_cost_and_usage(input))
+    }
+
+    /// <p><p>Retrieves cost and usage metrics with resources for your account. You can specify which cost and usage-related metric, such as <code>BlendedCosts</code> or <code>UsageQuantity</code>, that you want the request to return. You can also filter and group your data by various dimensions, such as <code>SERVICE</code> or <code>AZ</code>, in a specific time range. For a complete list of valid dimensions, see the <a href="https://docs.aws.amazon.com/aws-cost-management/latest/APIReference/API_GetDimensionValues.html">GetDimensionValues</a> operation. Master accounts in an organization in AWS Organizations have access to all member accounts. This API is currently available for the Amazon Elastic Compute Cloud – Compute service only.</p> <note> <p>This is an opt-in only feature. You can enable this feature from the Cost Explorer Settings page. For information on how to access the Settings page, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/ce-access.html">Controlling Access for Cost Explorer</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> </note></p>
+    ///
+    /// See `CostExplorer::get_cost_and_usage_with_resources`.
+    pub fn get_cost_and_usage_with_resources(
+        &self,
+        input: GetCostAndUsageWithResourcesRequest,
+    ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_cost_and_usage_with_resources(input))
+    }
+
+    /// <p>Retrieves a forecast for how much Amazon Web Services predicts that you will spend over the forecast time period that you select, based on your past costs. </p>
+    ///
+    /// See `CostExplorer::get_cost_forecast`.
+    pub fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
+        self.runtime.block_on(self.client.get_cost_forecast(input))
+    }
+
+    /// <p>Retrieves all available filter values for a specified filter over a period of time. You can search the dimension values for an arbitrary string. </p>
+    ///
+    /// See `CostExplorer::get_dimension_values`.
+    pub fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
+        self.runtime
+            .block_on(self.client.get_dimension_values(input))
+    }
+
+    /// <p>Retrieves the reservation coverage for your account. This enables you to see how much of your Amazon Elastic Compute Cloud, Amazon ElastiCache, Amazon Relational Database Service, or Amazon Redshift usage is covered by a reservation. An organization's master account can see the coverage of the associated member accounts. This supports dimensions, Cost Categories, and nested expressions. For any time period, you can filter data about reservation usage by the following dimensions:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE_ENGINE</p> </li> <li> <p>DATABASE_ENGINE</p> </li> <li> <p>DEPLOYMENT_OPTION</p> </li> <li> <p>INSTANCE_TYPE</p> </li> <li> <p>LINKED_ACCOUNT</p> </li> <li> <p>OPERATING_SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>SERVICE</p> </li> <li> <p>TAG</p> </li> <li> <p>TENANCY</p> </li> </ul> <p>To determine valid values for a dimension, use the <code>GetDimensionValues</code> operation. </p>
+    ///
+    /// See `CostExplorer::get_reservation_coverage`.
+    pub fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
+        self.runtime
+            .block_on(self.client.get_reservation_coverage(input))
+    }
+
+    /// <p>Gets recommendations for which reservations to purchase. These recommendations could help you reduce your costs. Reservations provide a discounted hourly rate (up to 75%) compared to On-Demand pricing.</p> <p>AWS generates your recommendations by identifying your On-Demand usage during a specific time period and collecting your usage into categories that are eligible for a reservation. After AWS has these categories, it simulates every combination of reservations in each category of usage to identify the best number of each type of RI to purchase to maximize your estimated savings. </p> <p>For example, AWS automatically aggregates your Amazon EC2 Linux, shared tenancy, and c4 family usage in the US West (Oregon) Region and recommends that you buy size-flexible regional reservations to apply to the c4 family usage. AWS recommends the smallest size instance in an instance family. This makes it easier to purchase a size-flexible RI. AWS also shows the equal number of normalized units so that you can purchase any instance size that you want. For this example, your RI recommendation would be for <code>c4.large</code> because that is the smallest size instance in the c4 instance family.</p>
+    ///
+    /// See `CostExplorer::get_reservation_purchase_recommendation`.
+    pub fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> Result<
+        GetReservationPurchaseRecommendationResponse,
+        RusotoError<GetReservationPurchaseRecommendationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reservation_purchase_recommendation(input))
+    }
+
+    /// <p>Retrieves the reservation utilization for your account. Master accounts in an organization have access to member accounts. You can filter data by dimensions in a time period. You can use <code>GetDimensionValues</code> to determine the possible dimension values. Currently, you can group only by <code>SUBSCRIPTION_ID</code>. </p>
+    ///
+    /// See `CostExplorer::get_reservation_utilization`.
+    pub fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_reservation_utilization(input))
+    }
+
+    /// <p>Creates recommendations that help you save cost by identifying idle and underutilized Amazon EC2 instances.</p> <p>Recommendations are generated to either downsize or terminate instances, along with providing savings detail and metrics. For details on calculation and function, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/ce-rightsizing.html">Optimizing Your Cost with Rightsizing Recommendations</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    ///
+    /// See `CostExplorer::get_rightsizing_recommendation`.
+    pub fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_rightsizing_recommendation(input))
+    }
+
+    /// <p>Retrieves the Savings Plans covered for your account. This enables you to see how much of your cost is covered by a Savings Plan. An organization’s master account can see the coverage of the associated member accounts. This supports dimensions, Cost Categories, and nested expressions. For any time period, you can filter data for Savings Plans usage with the following dimensions:</p> <ul> <li> <p> <code>LINKED_ACCOUNT</code> </p> </li> <li> <p> <code>REGION</code> </p> </li> <li> <p> <code>SERVICE</code> </p> </li> <li> <p> <code>INSTANCE_FAMILY</code> </p> </li> </ul> <p>To determine valid values for a dimension, use the <code>GetDimensionValues</code> operation.</p>
+    ///
+    /// See `CostExplorer::get_savings_plans_coverage`.
+    pub fn get_savings_plans_coverage(
+        &self,
+        input: GetSavingsPlansCoverageRequest,
+    ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
+        self.runtime
+            .block_on(self.client.get_savings_plans_coverage(input))
+    }
+
+    /// <p>Retrieves your request parameters, Savings Plan Recommendations Summary and Details. </p>
+    ///
+    /// See `CostExplorer::get_savings_plans_purchase_recommendation`.
+    pub fn get_savings_plans_purchase_recommendation(
+        &self,
+        input: GetSavingsPlansPurchaseRecommendationRequest,
+    ) -> Result<
+        GetSavingsPlansPurchaseRecommendationResponse,
+        RusotoError<GetSavingsPlansPurchaseRecommendationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_savings_plans_purchase_recommendation(input))
+    }
+
+    /// <p><p>Retrieves the Savings Plans utilization for your account across date ranges with daily or monthly granularity. Master accounts in an organization have access to member accounts. You can use <code>GetDimensionValues</code> in <code>SAVINGS_PLANS</code> to determine the possible dimension values.</p> <note> <p>You cannot group by any dimension values for <code>GetSavingsPlansUtilization</code>.</p> </note></p>
+    ///
+    /// See `CostExplorer::get_savings_plans_utilization`.
+    pub fn get_savings_plans_utilization(
+        &self,
+        input: GetSavingsPlansUtilizationRequest,
+    ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_savings_plans_utilization(input))
+    }
+
+    /// <p><p>Retrieves attribute data along with aggregate utilization and savings data for a given time period. This doesn&#39;t support granular or grouped data (daily/monthly) in response. You can&#39;t retrieve data by dates in a single response similar to <code>GetSavingsPlanUtilization</code>, but you have the option to make multiple calls to <code>GetSavingsPlanUtilizationDetails</code> by providing individual dates. You can use <code>GetDimensionValues</code> in <code>SAVINGS_PLANS</code> to determine the possible dimension values.</p> <note> <p> <code>GetSavingsPlanUtilizationDetails</code> internally groups data by <code>SavingsPlansArn</code>.</p> </note></p>
+    ///
+    /// See `CostExplorer::get_savings_plans_utilization_details`.
+    pub fn get_savings_plans_utilization_details(
+        &self,
+        input: GetSavingsPlansUtilizationDetailsRequest,
+    ) -> Result<
+        GetSavingsPlansUtilizationDetailsResponse,
+        RusotoError<GetSavingsPlansUtilizationDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_savings_plans_utilization_details(input))
+    }
+
+    /// <p>Queries for available tag keys and tag values for a specified period. You can search the tag values for an arbitrary string. </p>
+    ///
+    /// See `CostExplorer::get_tags`.
+    pub fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// <p>Retrieves a forecast for how much Amazon Web Services predicts that you will use over the forecast time period that you select, based on your past usage. </p>
+    ///
+    /// See `CostExplorer::get_usage_forecast`.
+    pub fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
+        self.runtime.block_on(self.client.get_usage_forecast(input))
+    }
+
+    /// <p>Returns the name, ARN, <code>NumberOfRules</code> and effective dates of all Cost Categories defined in the account. You have the option to use <code>EffectiveOn</code> to return a list of Cost Categories that were active on a specific date. If there is no <code>EffectiveOn</code> specified, you’ll see Cost Categories that are effective on the current date. If Cost Category is still effective, <code>EffectiveEnd</code> is omitted in the response. <code>ListCostCategoryDefinitions</code> supports pagination. The request can have a <code>MaxResults</code> range up to 100.</p>
+    ///
+    /// See `CostExplorer::list_cost_category_definitions`.
+    pub fn list_cost_category_definitions(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_cost_category_definitions(input))
+    }
+
+    /// <p>Updates an existing Cost Category. Changes made to the Cost Category rules will be used to categorize the current month’s expenses and future expenses. This won’t change categorization for the previous months.</p>
+    ///
+    /// See `CostExplorer::update_cost_category_definition`.
+    pub fn update_cost_category_definition(
+        &self,
+        input: UpdateCostCategoryDefinitionRequest,
+    ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_cost_category_definition(input))
+    }
+}
@@ -3483,42 +3483,50 @@ impl AssociatePhoneNumberWithUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociatePhoneNumberWithUserError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociatePhoneNumberWithUserError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociatePhoneNumberWithUserError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociatePhoneNumberWithUserError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociatePhoneNumberWithUserError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumberWithUserError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumberWithUserError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumberWithUserError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -3577,42 +3585,50 @@ impl AssociatePhoneNumbersWithVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::AccessDenied(err.msg),
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -3683,44 +3699,52 @@ impl AssociatePhoneNumbersWithVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::AccessDenied(err.msg),
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePhoneNumbersWithVoiceConnectorGroupError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -3791,37 +3815,44 @@ impl AssociateSigninDelegateGroupsWithAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSigninDelegateGroupsWithAccountError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -3887,34 +3918,46 @@ impl BatchCreateAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateAttendeeError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, BatchCreateAttendeeError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(&res, BatchCreateAttendeeError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateAttendeeError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateAttendeeError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateAttendeeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateAttendeeError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateAttendeeError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3963,33 +4006,44 @@ impl BatchCreateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateRoomMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateRoomMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateRoomMembershipError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateRoomMembershipError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchCreateRoomMembershipError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateRoomMembershipError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchCreateRoomMembershipError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -4039,33 +4093,46 @@ impl BatchDeletePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePhoneNumberError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4113,25 +4180,37 @@ impl BatchSuspendUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchSuspendUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, BatchSuspendUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchSuspendUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, BatchSuspendUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchSuspendUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, BatchSuspendUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchSuspendUserError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchSuspendUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchSuspendUserError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchSuspendUserError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4179,29 +4258,37 @@ impl BatchUnsuspendUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, BatchUnsuspendUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, BatchUnsuspendUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, BatchUnsuspendUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUnsuspendUserError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUnsuspendUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUnsuspendUserError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUnsuspendUserError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4249,33 +4336,46 @@ impl BatchUpdatePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdatePhoneNumberError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4323,25 +4423,37 @@ impl BatchUpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, BatchUpdateUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, BatchUpdateUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, BatchUpdateUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateUserError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateUserError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateUserError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4389,25 +4501,31 @@ impl CreateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAccountError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAccountError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4457,30 +4575,40 @@ impl CreateAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateAttendeeError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateAttendeeError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateAttendeeError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateAttendeeError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAttendeeError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, CreateAttendeeError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAttendeeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAttendeeError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAttendeeError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4531,28 +4659,31 @@ impl CreateBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBotError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateBotError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateBotError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateBotError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBotError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateBotError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateBotError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBotError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateBotError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, CreateBotError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateBotError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateBotError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, CreateBotError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, CreateBotError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4601,25 +4732,34 @@ impl CreateMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateMeetingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateMeetingError::Forbidden(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMeetingError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, CreateMeetingError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, CreateMeetingError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4669,37 +4809,44 @@ impl CreateMeetingWithAttendeesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingWithAttendeesError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingWithAttendeesError::Forbidden(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMeetingWithAttendeesError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingWithAttendeesError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMeetingWithAttendeesError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMeetingWithAttendeesError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMeetingWithAttendeesError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -4757,38 +4904,52 @@ impl CreatePhoneNumberOrderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::Forbidden(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePhoneNumberOrderError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePhoneNumberOrderError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4837,29 +4998,37 @@ impl CreateProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateProxySessionError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateProxySessionError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProxySessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateProxySessionError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProxySessionError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateProxySessionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProxySessionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProxySessionError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateProxySessionError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProxySessionError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4909,28 +5078,31 @@ impl CreateRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRoomError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRoomError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateRoomError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, CreateRoomError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4983,39 +5155,52 @@ impl CreateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateRoomMembershipError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateRoomMembershipError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoomMembershipError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5067,28 +5252,28 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateUserError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUserError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateUserError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, CreateUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5139,36 +5324,52 @@ impl CreateVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::Forbidden(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5219,40 +5420,50 @@ impl CreateVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorGroupError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorGroupError::Forbidden(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateVoiceConnectorGroupError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorGroupError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateVoiceConnectorGroupError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVoiceConnectorGroupError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateVoiceConnectorGroupError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -5307,28 +5518,37 @@ impl DeleteAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAccountError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteAccountError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAccountError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAccountError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, DeleteAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccountError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, DeleteAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccountError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAccountError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccountError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5377,25 +5597,34 @@ impl DeleteAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAttendeeError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteAttendeeError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAttendeeError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, DeleteAttendeeError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAttendeeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAttendeeError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAttendeeError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5441,30 +5670,38 @@ impl DeleteEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventsConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventsConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEventsConfigurationError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventsConfigurationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEventsConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEventsConfigurationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -5515,25 +5752,31 @@ impl DeleteMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteMeetingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteMeetingError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMeetingError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteMeetingError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, DeleteMeetingError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMeetingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, DeleteMeetingError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMeetingError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5581,29 +5824,37 @@ impl DeletePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeletePhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeletePhoneNumberError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeletePhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePhoneNumberError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePhoneNumberError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePhoneNumberError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePhoneNumberError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5651,29 +5902,37 @@ impl DeleteProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteProxySessionError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteProxySessionError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProxySessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteProxySessionError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProxySessionError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteProxySessionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProxySessionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProxySessionError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteProxySessionError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProxySessionError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5721,25 +5980,25 @@ impl DeleteRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRoomError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRoomError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, DeleteRoomError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5787,31 +6046,43 @@ impl DeleteRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRoomMembershipError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomMembershipError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomMembershipError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomMembershipError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoomMembershipError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5861,34 +6132,46 @@ impl DeleteVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteVoiceConnectorError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVoiceConnectorError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5939,43 +6222,50 @@ impl DeleteVoiceConnectorEmergencyCallingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(
                             err.msg,
                         ),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -6043,36 +6333,50 @@ impl DeleteVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorGroupError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorGroupError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorGroupError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorGroupError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorGroupError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorGroupError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorGroupError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -6125,37 +6429,44 @@ impl DeleteVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorOriginationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorOriginationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorOriginationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorOriginationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorOriginationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -6213,33 +6524,44 @@ impl DeleteVoiceConnectorProxyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorProxyError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorProxyError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorProxyError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorProxyError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorProxyError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorProxyError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorProxyError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -6291,39 +6613,46 @@ impl DeleteVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorStreamingConfigurationError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -6391,37 +6720,44 @@ impl DeleteVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorTerminationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorTerminationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVoiceConnectorTerminationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -6481,39 +6817,46 @@ impl DeleteVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -6581,37 +6924,44 @@ impl DisassociatePhoneNumberFromUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociatePhoneNumberFromUserError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociatePhoneNumberFromUserError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociatePhoneNumberFromUserError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumberFromUserError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumberFromUserError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumberFromUserError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumberFromUserError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -6671,39 +7021,46 @@ impl DisassociatePhoneNumbersFromVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -6771,43 +7128,50 @@ impl DisassociatePhoneNumbersFromVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceFailure(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::ThrottledClient(
                             err.msg,
                         ),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePhoneNumbersFromVoiceConnectorGroupError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -6875,39 +7239,46 @@ impl DisassociateSigninDelegateGroupsFromAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateSigninDelegateGroupsFromAccountError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -6973,25 +7344,25 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetAccountError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAccountError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetAccountError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, GetAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, GetAccountError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7039,29 +7410,37 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAccountSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetAccountSettingsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAccountSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7109,25 +7488,31 @@ impl GetAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAttendeeError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetAttendeeError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAttendeeError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetAttendeeError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAttendeeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, GetAttendeeError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAttendeeError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7175,23 +7560,25 @@ impl GetBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBotError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBotError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetBotError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetBotError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetBotError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetBotError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetBotError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetBotError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, GetBotError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, GetBotError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7239,33 +7626,46 @@ impl GetEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEventsConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEventsConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEventsConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEventsConfigurationError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventsConfigurationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventsConfigurationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventsConfigurationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7311,26 +7711,34 @@ impl GetGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetGlobalSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetGlobalSettingsError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGlobalSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetGlobalSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGlobalSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetGlobalSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7377,25 +7785,25 @@ impl GetMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMeetingError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, GetMeetingError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7443,25 +7851,34 @@ impl GetPhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetPhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetPhoneNumberError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetPhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetPhoneNumberError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7509,29 +7926,40 @@ impl GetPhoneNumberOrderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberOrderError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetPhoneNumberOrderError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetPhoneNumberOrderError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberOrderError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberOrderError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberOrderError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberOrderError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7577,30 +8005,40 @@ impl GetPhoneNumberSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberSettingsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberSettingsError::Forbidden(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPhoneNumberSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7647,25 +8085,37 @@ impl GetProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetProxySessionError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetProxySessionError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProxySessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetProxySessionError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProxySessionError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetProxySessionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProxySessionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProxySessionError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetProxySessionError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProxySessionError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7713,31 +8163,43 @@ impl GetRetentionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRetentionSettingsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRetentionSettingsError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRetentionSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRetentionSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRetentionSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRetentionSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRetentionSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7785,25 +8247,25 @@ impl GetRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRoomError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetRoomError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetRoomError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetRoomError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRoomError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetRoomError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetRoomError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, GetRoomError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, GetRoomError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7851,25 +8313,25 @@ impl GetUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, GetUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, GetUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7917,25 +8379,37 @@ impl GetUserSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUserSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetUserSettingsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUserSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7983,29 +8457,37 @@ impl GetVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetVoiceConnectorError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetVoiceConnectorError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetVoiceConnectorError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8055,43 +8537,50 @@ impl GetVoiceConnectorEmergencyCallingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(
                             err.msg,
                         ),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -8157,33 +8646,46 @@ impl GetVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8233,37 +8735,44 @@ impl GetVoiceConnectorLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorLoggingConfigurationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -8329,37 +8838,44 @@ impl GetVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorOriginationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorOriginationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorOriginationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorOriginationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -8413,33 +8929,46 @@ impl GetVoiceConnectorProxyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorProxyError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8489,37 +9018,44 @@ impl GetVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorStreamingConfigurationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -8585,37 +9121,44 @@ impl GetVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorTerminationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorTerminationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorTerminationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -8671,37 +9214,44 @@ impl GetVoiceConnectorTerminationHealthError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationHealthError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationHealthError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationHealthError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVoiceConnectorTerminationHealthError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationHealthError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationHealthError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationHealthError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetVoiceConnectorTerminationHealthError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -8761,25 +9311,31 @@ impl InviteUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteUsersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, InviteUsersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(InviteUsersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, InviteUsersError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(InviteUsersError::NotFound(err.msg))
+                    return RusotoError::service(&res, InviteUsersError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, InviteUsersError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InviteUsersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(InviteUsersError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, InviteUsersError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(InviteUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InviteUsersError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8827,25 +9383,31 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAccountsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAccountsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAccountsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAccountsError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAccountsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8893,25 +9455,37 @@ impl ListAttendeeTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListAttendeeTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListAttendeeTagsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListAttendeeTagsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttendeeTagsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttendeeTagsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttendeeTagsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttendeeTagsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8959,25 +9533,31 @@ impl ListAttendeesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAttendeesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListAttendeesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAttendeesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListAttendeesError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAttendeesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListAttendeesError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAttendeesError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListAttendeesError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttendeesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttendeesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAttendeesError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, ListAttendeesError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAttendeesError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttendeesError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9025,25 +9605,25 @@ impl ListBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBotsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListBotsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListBotsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListBotsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListBotsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListBotsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListBotsError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListBotsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBotsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListBotsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListBotsError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, ListBotsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListBotsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, ListBotsError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9091,25 +9671,37 @@ impl ListMeetingTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeetingTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListMeetingTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeetingTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListMeetingTagsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMeetingTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListMeetingTagsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListMeetingTagsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeetingTagsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeetingTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeetingTagsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListMeetingTagsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeetingTagsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListMeetingTagsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeetingTagsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9155,22 +9747,28 @@ impl ListMeetingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeetingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListMeetingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeetingsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListMeetingsError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListMeetingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListMeetingsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeetingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeetingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListMeetingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, ListMeetingsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListMeetingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMeetingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9215,30 +9813,40 @@ impl ListPhoneNumberOrdersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumberOrdersError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumberOrdersError::Forbidden(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumberOrdersError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumberOrdersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumberOrdersError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumberOrdersError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9283,22 +9891,34 @@ impl ListPhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListPhoneNumbersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListPhoneNumbersError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9345,29 +9965,37 @@ impl ListProxySessionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListProxySessionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListProxySessionsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListProxySessionsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListProxySessionsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListProxySessionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListProxySessionsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListProxySessionsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProxySessionsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProxySessionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProxySessionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListProxySessionsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProxySessionsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListProxySessionsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProxySessionsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9415,29 +10043,40 @@ impl ListRoomMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoomMembershipsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListRoomMembershipsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListRoomMembershipsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoomMembershipsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRoomMembershipsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoomMembershipsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRoomMembershipsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9485,25 +10124,25 @@ impl ListRoomsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoomsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoomsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoomsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListRoomsError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoomsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListRoomsError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListRoomsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, ListRoomsError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9549,26 +10188,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9615,25 +10262,25 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListUsersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListUsersError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListUsersError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListUsersError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, ListUsersError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9679,30 +10326,40 @@ impl ListVoiceConnectorGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorGroupsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorGroupsError::Forbidden(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorGroupsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorGroupsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorGroupsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorGroupsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9751,37 +10408,44 @@ impl ListVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -9843,26 +10507,37 @@ impl ListVoiceConnectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListVoiceConnectorsError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVoiceConnectorsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9909,25 +10584,25 @@ impl LogoutUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(LogoutUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(LogoutUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(LogoutUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(LogoutUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(LogoutUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, LogoutUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9975,33 +10650,46 @@ impl PutEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEventsConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEventsConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEventsConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEventsConfigurationError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEventsConfigurationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEventsConfigurationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEventsConfigurationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10051,34 +10739,46 @@ impl PutRetentionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionSettingsError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutRetentionSettingsError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionSettingsError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutRetentionSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10129,43 +10829,50 @@ impl PutVoiceConnectorEmergencyCallingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(
                             err.msg,
                         ),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(
                             err.msg,
                         ),
@@ -10233,37 +10940,44 @@ impl PutVoiceConnectorLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorLoggingConfigurationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -10329,37 +11043,44 @@ impl PutVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorOriginationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorOriginationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorOriginationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorOriginationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -10415,36 +11136,52 @@ impl PutVoiceConnectorProxyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorProxyError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10495,37 +11232,44 @@ impl PutVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorStreamingConfigurationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -10593,42 +11337,50 @@ impl PutVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorTerminationError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorTerminationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorTerminationError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorTerminationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -10685,37 +11437,44 @@ impl PutVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -10779,33 +11538,44 @@ impl RedactConversationMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RedactConversationMessageError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RedactConversationMessageError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RedactConversationMessageError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RedactConversationMessageError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RedactConversationMessageError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RedactConversationMessageError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RedactConversationMessageError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RedactConversationMessageError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RedactConversationMessageError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RedactConversationMessageError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RedactConversationMessageError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RedactConversationMessageError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -10855,29 +11625,37 @@ impl RedactRoomMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RedactRoomMessageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, RedactRoomMessageError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RedactRoomMessageError::Forbidden(err.msg))
+                    return RusotoError::service(&res, RedactRoomMessageError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RedactRoomMessageError::NotFound(err.msg))
+                    return RusotoError::service(&res, RedactRoomMessageError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RedactRoomMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RedactRoomMessageError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RedactRoomMessageError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RedactRoomMessageError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RedactRoomMessageError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RedactRoomMessageError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RedactRoomMessageError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RedactRoomMessageError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10925,33 +11703,46 @@ impl RegenerateSecurityTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegenerateSecurityTokenError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10999,25 +11790,37 @@ impl ResetPersonalPINError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetPersonalPINError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ResetPersonalPINError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ResetPersonalPINError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ResetPersonalPINError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResetPersonalPINError::NotFound(err.msg))
+                    return RusotoError::service(&res, ResetPersonalPINError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPersonalPINError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPersonalPINError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPersonalPINError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPersonalPINError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11067,34 +11870,43 @@ impl RestorePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(&res, RestorePhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(&res, RestorePhoneNumberError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(&res, RestorePhoneNumberError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestorePhoneNumberError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestorePhoneNumberError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestorePhoneNumberError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestorePhoneNumberError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestorePhoneNumberError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11145,37 +11957,44 @@ impl SearchAvailablePhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchAvailablePhoneNumbersError::AccessDenied(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchAvailablePhoneNumbersError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchAvailablePhoneNumbersError::Forbidden(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchAvailablePhoneNumbersError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SearchAvailablePhoneNumbersError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchAvailablePhoneNumbersError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SearchAvailablePhoneNumbersError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -11231,28 +12050,37 @@ impl TagAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagAttendeeError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, TagAttendeeError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagAttendeeError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(TagAttendeeError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagAttendeeError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(TagAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, TagAttendeeError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagAttendeeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(TagAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, TagAttendeeError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(TagAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagAttendeeError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11303,28 +12131,31 @@ impl TagMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagMeetingError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::NotFound(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(TagMeetingError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagMeetingError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(TagMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(TagMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(TagMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, TagMeetingError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11371,22 +12202,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(TagResourceError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(TagResourceError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11433,25 +12270,31 @@ impl UntagAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagAttendeeError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagAttendeeError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagAttendeeError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagAttendeeError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UntagAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UntagAttendeeError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagAttendeeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UntagAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, UntagAttendeeError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UntagAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagAttendeeError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11499,25 +12342,31 @@ impl UntagMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagMeetingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagMeetingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagMeetingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagMeetingError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagMeetingError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagMeetingError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UntagMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UntagMeetingError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagMeetingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UntagMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, UntagMeetingError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UntagMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagMeetingError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11563,22 +12412,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UntagResourceError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11625,25 +12480,31 @@ impl UpdateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, UpdateAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11693,36 +12554,52 @@ impl UpdateAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11771,25 +12648,25 @@ impl UpdateBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBotError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBotError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBotError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateBotError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, UpdateBotError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11835,28 +12712,40 @@ impl UpdateGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalSettingsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalSettingsError::Forbidden(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11903,29 +12792,37 @@ impl UpdatePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdatePhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdatePhoneNumberError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdatePhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11971,30 +12868,38 @@ impl UpdatePhoneNumberSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberSettingsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberSettingsError::Forbidden(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePhoneNumberSettingsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePhoneNumberSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePhoneNumberSettingsError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -12043,29 +12948,37 @@ impl UpdateProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateProxySessionError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateProxySessionError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProxySessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateProxySessionError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProxySessionError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateProxySessionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProxySessionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProxySessionError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateProxySessionError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProxySessionError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12113,25 +13026,25 @@ impl UpdateRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRoomError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRoomError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, UpdateRoomError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12179,31 +13092,43 @@ impl UpdateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoomMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoomMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRoomMembershipError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoomMembershipError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoomMembershipError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoomMembershipError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoomMembershipError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12251,25 +13176,25 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12317,29 +13242,37 @@ impl UpdateUserSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateUserSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateUserSettingsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUserSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSettingsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSettingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSettingsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSettingsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12387,31 +13320,43 @@ impl UpdateVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVoiceConnectorError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12461,36 +13406,50 @@ impl UpdateVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorGroupError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorGroupError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorGroupError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorGroupError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateVoiceConnectorGroupError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVoiceConnectorGroupError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateVoiceConnectorGroupError::UnauthorizedClient(err.msg),
                     )
                 }
@@ -13295,6 +14254,7 @@ impl ChimeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -13310,6 +14270,7 @@ impl ChimeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ChimeClient {
         ChimeClient { client, region }
     }
@@ -13331,6 +14292,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("AssociatePhoneNumberWithUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13372,6 +14336,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("AssociatePhoneNumbersWithVoiceConnector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13415,6 +14382,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("AssociatePhoneNumbersWithVoiceConnectorGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13454,6 +14424,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("AssociateSigninDelegateGroupsWithAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13492,6 +14465,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchCreateAttendee");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13532,6 +14508,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchCreateRoomMembership");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13567,6 +14546,9 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchDeletePhoneNumber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13605,6 +14587,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchSuspendUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13643,6 +14628,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchUnsuspendUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13678,6 +14666,9 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchUpdatePhoneNumber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13716,6 +14707,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("BatchUpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13747,6 +14741,9 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13781,6 +14778,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateAttendee");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13812,6 +14812,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateBot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13843,6 +14846,9 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateMeeting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13875,6 +14881,9 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateMeetingWithAttendees");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13910,6 +14919,9 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreatePhoneNumberOrder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13944,6 +14956,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateProxySession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13978,6 +14993,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateRoom");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14013,6 +15031,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateRoomMembership");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14047,6 +15068,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14082,6 +15106,9 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateVoiceConnector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14114,6 +15141,9 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("CreateVoiceConnectorGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14145,6 +15175,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteAccount");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14177,6 +15210,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteAttendee");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14208,6 +15244,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteEventsConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14235,6 +15274,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteMeeting");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14265,6 +15307,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeletePhoneNumber");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14296,6 +15341,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteProxySession");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14327,6 +15375,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteRoom");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14359,6 +15410,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteRoomMembership");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14389,6 +15443,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnector");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14419,6 +15476,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorEmergencyCallingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14449,6 +15509,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14479,6 +15542,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorOrigination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14511,6 +15577,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorProxy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14541,6 +15610,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorStreamingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14571,6 +15643,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorTermination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14603,6 +15678,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("DeleteVoiceConnectorTerminationCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14644,6 +15722,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("DisassociatePhoneNumberFromUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14684,6 +15765,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("DisassociatePhoneNumbersFromVoiceConnector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14725,6 +15809,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("DisassociatePhoneNumbersFromVoiceConnectorGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14764,6 +15851,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("DisassociateSigninDelegateGroupsFromAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14799,6 +15889,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14830,6 +15923,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetAccountSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14862,6 +15958,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetAttendee");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14894,6 +15993,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetBot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14926,6 +16028,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetEventsConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14953,6 +16058,9 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetGlobalSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14981,6 +16089,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetMeeting");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15012,6 +16123,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetPhoneNumber");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15043,6 +16157,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetPhoneNumberOrder");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15070,6 +16187,9 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetPhoneNumberSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15102,6 +16222,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetProxySession");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15133,6 +16256,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetRetentionSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15165,6 +16291,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetRoom");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15197,6 +16326,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15229,6 +16361,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetUserSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15260,6 +16395,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnector");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15294,6 +16432,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorEmergencyCallingConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15326,6 +16467,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15360,6 +16504,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorLoggingConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15394,6 +16541,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorOrigination");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15425,6 +16575,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorProxy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15459,6 +16612,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorStreamingConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15493,6 +16649,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorTermination");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15527,6 +16686,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("GetVoiceConnectorTerminationHealth");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15560,6 +16722,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("InviteUsers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -15595,6 +16760,9 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15642,6 +16810,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListAttendeeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15673,6 +16844,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListAttendees");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15710,6 +16884,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListBots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15747,6 +16924,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListMeetingTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15775,6 +16955,9 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListMeetings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15812,6 +16995,9 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListPhoneNumberOrders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15849,6 +17035,9 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListPhoneNumbers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15901,6 +17090,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListProxySessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15945,6 +17137,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListRoomMemberships");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15985,6 +17180,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListRooms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16025,6 +17223,9 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16060,6 +17261,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16103,6 +17307,9 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListVoiceConnectorGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16146,6 +17353,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListVoiceConnectorTerminationCredentials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -16174,6 +17384,9 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("ListVoiceConnectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16215,6 +17428,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("LogoutUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16251,6 +17467,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutEventsConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16285,6 +17504,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutRetentionSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16322,6 +17544,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorEmergencyCallingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16360,6 +17585,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorLoggingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16397,6 +17625,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorOrigination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16431,6 +17662,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorProxy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16468,6 +17702,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorStreamingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16505,6 +17742,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorTermination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16539,6 +17779,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("PutVoiceConnectorTerminationCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16581,6 +17824,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("RedactConversationMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16618,6 +17864,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("RedactRoomMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16654,6 +17903,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("RegenerateSecurityToken");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16690,6 +17942,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("ResetPersonalPIN");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16725,6 +17980,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("RestorePhoneNumber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16758,6 +18016,9 @@ impl Chime for ChimeClient {
         let request_uri = "/search";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation_name("SearchAvailablePhoneNumbers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16815,6 +18076,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("TagAttendee");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16849,6 +18113,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("TagMeeting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16883,6 +18150,9 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16921,6 +18191,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UntagAttendee");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16955,6 +18228,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UntagMeeting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16989,6 +18265,9 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17023,6 +18302,9 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17057,6 +18339,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateAccountSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17092,6 +18377,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateBot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17123,6 +18411,9 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateGlobalSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17156,6 +18447,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdatePhoneNumber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17187,6 +18481,9 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdatePhoneNumberSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17221,6 +18518,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateProxySession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17256,6 +18556,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateRoom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17292,6 +18595,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateRoomMembership");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17327,6 +18633,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17362,6 +18671,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateUserSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17395,6 +18707,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateVoiceConnector");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17430,6 +18745,9 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation_name("UpdateVoiceConnectorGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17452,3 +18770,1402 @@ impl Chime for ChimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Chime API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ChimeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ChimeBlockingClient {
+    client: ChimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ChimeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ChimeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ChimeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ChimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ChimeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ChimeBlockingClient {
+        ChimeBlockingClient {
+            client: ChimeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
+    ///
+    /// See `Chime::associate_phone_number_with_user`.
+    pub fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> Result<AssociatePhoneNumberWithUserResponse, RusotoError<AssociatePhoneNumberWithUserError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_phone_number_with_user(input))
+    }
+
+    /// <p>Associates phone numbers with the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::associate_phone_numbers_with_voice_connector`.
+    pub fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> Result<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_phone_numbers_with_voice_connector(input),
+        )
+    }
+
+    /// <p>Associates phone numbers with the specified Amazon Chime Voice Connector group.</p>
+    ///
+    /// See `Chime::associate_phone_numbers_with_voice_connector_group`.
+    pub fn associate_phone_numbers_with_voice_connector_group(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorGroupRequest,
+    ) -> Result<
+        AssociatePhoneNumbersWithVoiceConnectorGroupResponse,
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_phone_numbers_with_voice_connector_group(input),
+        )
+    }
+
+    /// <p>Associates the specified sign-in delegate groups with the specified Amazon Chime account.</p>
+    ///
+    /// See `Chime::associate_signin_delegate_groups_with_account`.
+    pub fn associate_signin_delegate_groups_with_account(
+        &self,
+        input: AssociateSigninDelegateGroupsWithAccountRequest,
+    ) -> Result<
+        AssociateSigninDelegateGroupsWithAccountResponse,
+        RusotoError<AssociateSigninDelegateGroupsWithAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_signin_delegate_groups_with_account(input),
+        )
+    }
+
+    /// <p>Creates up to 100 new attendees for an active Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>. </p>
+    ///
+    /// See `Chime::batch_create_attendee`.
+    pub fn batch_create_attendee(
+        &self,
+        input: BatchCreateAttendeeRequest,
+    ) -> Result<BatchCreateAttendeeResponse, RusotoError<BatchCreateAttendeeError>> {
+        self.runtime
+            .block_on(self.client.batch_create_attendee(input))
+    }
+
+    /// <p>Adds up to 50 members to a chat room in an Amazon Chime Enterprise account. Members can be either users or bots. The member role designates whether the member is a chat room administrator or a general chat room member.</p>
+    ///
+    /// See `Chime::batch_create_room_membership`.
+    pub fn batch_create_room_membership(
+        &self,
+        input: BatchCreateRoomMembershipRequest,
+    ) -> Result<BatchCreateRoomMembershipResponse, RusotoError<BatchCreateRoomMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_create_room_membership(input))
+    }
+
+    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    ///
+    /// See `Chime::batch_delete_phone_number`.
+    pub fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> Result<BatchDeletePhoneNumberResponse, RusotoError<BatchDeletePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_phone_number(input))
+    }
+
+    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are disassociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
+    ///
+    /// See `Chime::batch_suspend_user`.
+    pub fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> Result<BatchSuspendUserResponse, RusotoError<BatchSuspendUserError>> {
+        self.runtime.block_on(self.client.batch_suspend_user(input))
+    }
+
+    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
+    ///
+    /// See `Chime::batch_unsuspend_user`.
+    pub fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> Result<BatchUnsuspendUserResponse, RusotoError<BatchUnsuspendUserError>> {
+        self.runtime
+            .block_on(self.client.batch_unsuspend_user(input))
+    }
+
+    /// <p>Updates phone number product types or calling names. You can update one attribute at a time for each <code>UpdatePhoneNumberRequestItem</code>. For example, you can update either the product type or the calling name.</p> <p>For product types, choose from Amazon Chime Business Calling and Amazon Chime Voice Connector. For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p> <p>Updates to outbound calling names can take up to 72 hours to complete. Pending updates to outbound calling names must be complete before you can request another update.</p>
+    ///
+    /// See `Chime::batch_update_phone_number`.
+    pub fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> Result<BatchUpdatePhoneNumberResponse, RusotoError<BatchUpdatePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.batch_update_phone_number(input))
+    }
+
+    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    ///
+    /// See `Chime::batch_update_user`.
+    pub fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> Result<BatchUpdateUserResponse, RusotoError<BatchUpdateUserError>> {
+        self.runtime.block_on(self.client.batch_update_user(input))
+    }
+
+    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ///
+    /// See `Chime::create_account`.
+    pub fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+        self.runtime.block_on(self.client.create_account(input))
+    }
+
+    /// <p>Creates a new attendee for an active Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::create_attendee`.
+    pub fn create_attendee(
+        &self,
+        input: CreateAttendeeRequest,
+    ) -> Result<CreateAttendeeResponse, RusotoError<CreateAttendeeError>> {
+        self.runtime.block_on(self.client.create_attendee(input))
+    }
+
+    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::create_bot`.
+    pub fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> Result<CreateBotResponse, RusotoError<CreateBotError>> {
+        self.runtime.block_on(self.client.create_bot(input))
+    }
+
+    /// <p>Creates a new Amazon Chime SDK meeting in the specified media Region with no initial attendees. For more information about specifying media Regions, see <a href="https://docs.aws.amazon.com/chime/latest/dg/chime-sdk-meetings-regions.html">Amazon Chime SDK Media Regions</a> in the <i>Amazon Chime Developer Guide</i>. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::create_meeting`.
+    pub fn create_meeting(
+        &self,
+        input: CreateMeetingRequest,
+    ) -> Result<CreateMeetingResponse, RusotoError<CreateMeetingError>> {
+        self.runtime.block_on(self.client.create_meeting(input))
+    }
+
+    /// <p>Creates a new Amazon Chime SDK meeting in the specified media Region, with attendees. For more information about specifying media Regions, see <a href="https://docs.aws.amazon.com/chime/latest/dg/chime-sdk-meetings-regions.html">Amazon Chime SDK Media Regions</a> in the <i>Amazon Chime Developer Guide</i>. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::create_meeting_with_attendees`.
+    pub fn create_meeting_with_attendees(
+        &self,
+        input: CreateMeetingWithAttendeesRequest,
+    ) -> Result<CreateMeetingWithAttendeesResponse, RusotoError<CreateMeetingWithAttendeesError>>
+    {
+        self.runtime
+            .block_on(self.client.create_meeting_with_attendees(input))
+    }
+
+    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p>
+    ///
+    /// See `Chime::create_phone_number_order`.
+    pub fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> Result<CreatePhoneNumberOrderResponse, RusotoError<CreatePhoneNumberOrderError>> {
+        self.runtime
+            .block_on(self.client.create_phone_number_order(input))
+    }
+
+    /// <p>Creates a proxy session on the specified Amazon Chime Voice Connector for the specified participant phone numbers.</p>
+    ///
+    /// See `Chime::create_proxy_session`.
+    pub fn create_proxy_session(
+        &self,
+        input: CreateProxySessionRequest,
+    ) -> Result<CreateProxySessionResponse, RusotoError<CreateProxySessionError>> {
+        self.runtime
+            .block_on(self.client.create_proxy_session(input))
+    }
+
+    /// <p>Creates a chat room for the specified Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::create_room`.
+    pub fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        self.runtime.block_on(self.client.create_room(input))
+    }
+
+    /// <p>Adds a member to a chat room in an Amazon Chime Enterprise account. A member can be either a user or a bot. The member role designates whether the member is a chat room administrator or a general chat room member.</p>
+    ///
+    /// See `Chime::create_room_membership`.
+    pub fn create_room_membership(
+        &self,
+        input: CreateRoomMembershipRequest,
+    ) -> Result<CreateRoomMembershipResponse, RusotoError<CreateRoomMembershipError>> {
+        self.runtime
+            .block_on(self.client.create_room_membership(input))
+    }
+
+    /// <p>Creates a user under the specified Amazon Chime account.</p>
+    ///
+    /// See `Chime::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. You can choose to create an Amazon Chime Voice Connector in a specific AWS Region.</p> <p>Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
+    ///
+    /// See `Chime::create_voice_connector`.
+    pub fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> Result<CreateVoiceConnectorResponse, RusotoError<CreateVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.create_voice_connector(input))
+    }
+
+    /// <p>Creates an Amazon Chime Voice Connector group under the administrator's AWS account. You can associate Amazon Chime Voice Connectors with the Amazon Chime Voice Connector group by including <code>VoiceConnectorItems</code> in the request.</p> <p>You can include Amazon Chime Voice Connectors from different AWS Regions in your group. This creates a fault tolerant mechanism for fallback in case of availability events.</p>
+    ///
+    /// See `Chime::create_voice_connector_group`.
+    pub fn create_voice_connector_group(
+        &self,
+        input: CreateVoiceConnectorGroupRequest,
+    ) -> Result<CreateVoiceConnectorGroupResponse, RusotoError<CreateVoiceConnectorGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_voice_connector_group(input))
+    }
+
+    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
+    ///
+    /// See `Chime::delete_account`.
+    pub fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> Result<DeleteAccountResponse, RusotoError<DeleteAccountError>> {
+        self.runtime.block_on(self.client.delete_account(input))
+    }
+
+    /// <p>Deletes an attendee from the specified Amazon Chime SDK meeting and deletes their <code>JoinToken</code>. Attendees are automatically deleted when a Amazon Chime SDK meeting is deleted. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::delete_attendee`.
+    pub fn delete_attendee(
+        &self,
+        input: DeleteAttendeeRequest,
+    ) -> Result<(), RusotoError<DeleteAttendeeError>> {
+        self.runtime.block_on(self.client.delete_attendee(input))
+    }
+
+    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
+    ///
+    /// See `Chime::delete_events_configuration`.
+    pub fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteEventsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_events_configuration(input))
+    }
+
+    /// <p>Deletes the specified Amazon Chime SDK meeting. When a meeting is deleted, its attendees are also deleted and clients can no longer join it. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::delete_meeting`.
+    pub fn delete_meeting(
+        &self,
+        input: DeleteMeetingRequest,
+    ) -> Result<(), RusotoError<DeleteMeetingError>> {
+        self.runtime.block_on(self.client.delete_meeting(input))
+    }
+
+    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    ///
+    /// See `Chime::delete_phone_number`.
+    pub fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> Result<(), RusotoError<DeletePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.delete_phone_number(input))
+    }
+
+    /// <p>Deletes the specified proxy session from the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::delete_proxy_session`.
+    pub fn delete_proxy_session(
+        &self,
+        input: DeleteProxySessionRequest,
+    ) -> Result<(), RusotoError<DeleteProxySessionError>> {
+        self.runtime
+            .block_on(self.client.delete_proxy_session(input))
+    }
+
+    /// <p>Deletes a chat room in an Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::delete_room`.
+    pub fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<(), RusotoError<DeleteRoomError>> {
+        self.runtime.block_on(self.client.delete_room(input))
+    }
+
+    /// <p>Removes a member from a chat room in an Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::delete_room_membership`.
+    pub fn delete_room_membership(
+        &self,
+        input: DeleteRoomMembershipRequest,
+    ) -> Result<(), RusotoError<DeleteRoomMembershipError>> {
+        self.runtime
+            .block_on(self.client.delete_room_membership(input))
+    }
+
+    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers associated with the Amazon Chime Voice Connector must be disassociated from it before it can be deleted.</p>
+    ///
+    /// See `Chime::delete_voice_connector`.
+    pub fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector(input))
+    }
+
+    /// <p>Deletes the emergency calling configuration details from the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::delete_voice_connector_emergency_calling_configuration`.
+    pub fn delete_voice_connector_emergency_calling_configuration(
+        &self,
+        input: DeleteVoiceConnectorEmergencyCallingConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorEmergencyCallingConfigurationError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_voice_connector_emergency_calling_configuration(input),
+        )
+    }
+
+    /// <p>Deletes the specified Amazon Chime Voice Connector group. Any <code>VoiceConnectorItems</code> and phone numbers associated with the group must be removed before it can be deleted.</p>
+    ///
+    /// See `Chime::delete_voice_connector_group`.
+    pub fn delete_voice_connector_group(
+        &self,
+        input: DeleteVoiceConnectorGroupRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_group(input))
+    }
+
+    /// <p><p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p> <note> <p>If emergency calling is configured for the Amazon Chime Voice Connector, it must be deleted prior to deleting the origination settings.</p> </note></p>
+    ///
+    /// See `Chime::delete_voice_connector_origination`.
+    pub fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorOriginationError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_origination(input))
+    }
+
+    /// <p>Deletes the proxy configuration from the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::delete_voice_connector_proxy`.
+    pub fn delete_voice_connector_proxy(
+        &self,
+        input: DeleteVoiceConnectorProxyRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorProxyError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_proxy(input))
+    }
+
+    /// <p>Deletes the streaming configuration for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::delete_voice_connector_streaming_configuration`.
+    pub fn delete_voice_connector_streaming_configuration(
+        &self,
+        input: DeleteVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorStreamingConfigurationError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_voice_connector_streaming_configuration(input),
+        )
+    }
+
+    /// <p><p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p> <note> <p>If emergency calling is configured for the Amazon Chime Voice Connector, it must be deleted prior to deleting the termination settings.</p> </note></p>
+    ///
+    /// See `Chime::delete_voice_connector_termination`.
+    pub fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_termination(input))
+    }
+
+    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
+    ///
+    /// See `Chime::delete_voice_connector_termination_credentials`.
+    pub fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationCredentialsError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_voice_connector_termination_credentials(input),
+        )
+    }
+
+    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
+    ///
+    /// See `Chime::disassociate_phone_number_from_user`.
+    pub fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> Result<
+        DisassociatePhoneNumberFromUserResponse,
+        RusotoError<DisassociatePhoneNumberFromUserError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_phone_number_from_user(input))
+    }
+
+    /// <p>Disassociates the specified phone numbers from the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::disassociate_phone_numbers_from_voice_connector`.
+    pub fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> Result<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_phone_numbers_from_voice_connector(input),
+        )
+    }
+
+    /// <p>Disassociates the specified phone numbers from the specified Amazon Chime Voice Connector group.</p>
+    ///
+    /// See `Chime::disassociate_phone_numbers_from_voice_connector_group`.
+    pub fn disassociate_phone_numbers_from_voice_connector_group(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorGroupRequest,
+    ) -> Result<
+        DisassociatePhoneNumbersFromVoiceConnectorGroupResponse,
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_phone_numbers_from_voice_connector_group(input),
+        )
+    }
+
+    /// <p>Disassociates the specified sign-in delegate groups from the specified Amazon Chime account.</p>
+    ///
+    /// See `Chime::disassociate_signin_delegate_groups_from_account`.
+    pub fn disassociate_signin_delegate_groups_from_account(
+        &self,
+        input: DisassociateSigninDelegateGroupsFromAccountRequest,
+    ) -> Result<
+        DisassociateSigninDelegateGroupsFromAccountResponse,
+        RusotoError<DisassociateSigninDelegateGroupsFromAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_signin_delegate_groups_from_account(input),
+        )
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
+    ///
+    /// See `Chime::get_account`.
+    pub fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+        self.runtime.block_on(self.client.get_account(input))
+    }
+
+    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ///
+    /// See `Chime::get_account_settings`.
+    pub fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_account_settings(input))
+    }
+
+    /// <p>Gets the Amazon Chime SDK attendee details for a specified meeting ID and attendee ID. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::get_attendee`.
+    pub fn get_attendee(
+        &self,
+        input: GetAttendeeRequest,
+    ) -> Result<GetAttendeeResponse, RusotoError<GetAttendeeError>> {
+        self.runtime.block_on(self.client.get_attendee(input))
+    }
+
+    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
+    ///
+    /// See `Chime::get_bot`.
+    pub fn get_bot(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+        self.runtime.block_on(self.client.get_bot(input))
+    }
+
+    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
+    ///
+    /// See `Chime::get_events_configuration`.
+    pub fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> Result<GetEventsConfigurationResponse, RusotoError<GetEventsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_events_configuration(input))
+    }
+
+    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    ///
+    /// See `Chime::get_global_settings`.
+    pub fn get_global_settings(
+        &self,
+    ) -> Result<GetGlobalSettingsResponse, RusotoError<GetGlobalSettingsError>> {
+        self.runtime.block_on(self.client.get_global_settings())
+    }
+
+    /// <p>Gets the Amazon Chime SDK meeting details for the specified meeting ID. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::get_meeting`.
+    pub fn get_meeting(
+        &self,
+        input: GetMeetingRequest,
+    ) -> Result<GetMeetingResponse, RusotoError<GetMeetingError>> {
+        self.runtime.block_on(self.client.get_meeting(input))
+    }
+
+    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
+    ///
+    /// See `Chime::get_phone_number`.
+    pub fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> Result<GetPhoneNumberResponse, RusotoError<GetPhoneNumberError>> {
+        self.runtime.block_on(self.client.get_phone_number(input))
+    }
+
+    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
+    ///
+    /// See `Chime::get_phone_number_order`.
+    pub fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> Result<GetPhoneNumberOrderResponse, RusotoError<GetPhoneNumberOrderError>> {
+        self.runtime
+            .block_on(self.client.get_phone_number_order(input))
+    }
+
+    /// <p>Retrieves the phone number settings for the administrator's AWS account, such as the default outbound calling name.</p>
+    ///
+    /// See `Chime::get_phone_number_settings`.
+    pub fn get_phone_number_settings(
+        &self,
+    ) -> Result<GetPhoneNumberSettingsResponse, RusotoError<GetPhoneNumberSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_phone_number_settings())
+    }
+
+    /// <p>Gets the specified proxy session details for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::get_proxy_session`.
+    pub fn get_proxy_session(
+        &self,
+        input: GetProxySessionRequest,
+    ) -> Result<GetProxySessionResponse, RusotoError<GetProxySessionError>> {
+        self.runtime.block_on(self.client.get_proxy_session(input))
+    }
+
+    /// <p>Gets the retention settings for the specified Amazon Chime Enterprise account. For more information about retention settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/chat-retention.html">Managing Chat Retention Policies</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ///
+    /// See `Chime::get_retention_settings`.
+    pub fn get_retention_settings(
+        &self,
+        input: GetRetentionSettingsRequest,
+    ) -> Result<GetRetentionSettingsResponse, RusotoError<GetRetentionSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_retention_settings(input))
+    }
+
+    /// <p>Retrieves room details, such as the room name, for a room in an Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::get_room`.
+    pub fn get_room(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+        self.runtime.block_on(self.client.get_room(input))
+    }
+
+    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
+    ///
+    /// See `Chime::get_user`.
+    pub fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.runtime.block_on(self.client.get_user(input))
+    }
+
+    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
+    ///
+    /// See `Chime::get_user_settings`.
+    pub fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> Result<GetUserSettingsResponse, RusotoError<GetUserSettingsError>> {
+        self.runtime.block_on(self.client.get_user_settings(input))
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
+    ///
+    /// See `Chime::get_voice_connector`.
+    pub fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> Result<GetVoiceConnectorResponse, RusotoError<GetVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.get_voice_connector(input))
+    }
+
+    /// <p>Gets the emergency calling configuration details for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::get_voice_connector_emergency_calling_configuration`.
+    pub fn get_voice_connector_emergency_calling_configuration(
+        &self,
+        input: GetVoiceConnectorEmergencyCallingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorEmergencyCallingConfigurationResponse,
+        RusotoError<GetVoiceConnectorEmergencyCallingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_voice_connector_emergency_calling_configuration(input),
+        )
+    }
+
+    /// <p>Retrieves details for the specified Amazon Chime Voice Connector group, such as timestamps, name, and associated <code>VoiceConnectorItems</code>.</p>
+    ///
+    /// See `Chime::get_voice_connector_group`.
+    pub fn get_voice_connector_group(
+        &self,
+        input: GetVoiceConnectorGroupRequest,
+    ) -> Result<GetVoiceConnectorGroupResponse, RusotoError<GetVoiceConnectorGroupError>> {
+        self.runtime
+            .block_on(self.client.get_voice_connector_group(input))
+    }
+
+    /// <p>Retrieves the logging configuration details for the specified Amazon Chime Voice Connector. Shows whether SIP message logs are enabled for sending to Amazon CloudWatch Logs.</p>
+    ///
+    /// See `Chime::get_voice_connector_logging_configuration`.
+    pub fn get_voice_connector_logging_configuration(
+        &self,
+        input: GetVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorLoggingConfigurationResponse,
+        RusotoError<GetVoiceConnectorLoggingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_voice_connector_logging_configuration(input))
+    }
+
+    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::get_voice_connector_origination`.
+    pub fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> Result<GetVoiceConnectorOriginationResponse, RusotoError<GetVoiceConnectorOriginationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_voice_connector_origination(input))
+    }
+
+    /// <p>Gets the proxy configuration details for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::get_voice_connector_proxy`.
+    pub fn get_voice_connector_proxy(
+        &self,
+        input: GetVoiceConnectorProxyRequest,
+    ) -> Result<GetVoiceConnectorProxyResponse, RusotoError<GetVoiceConnectorProxyError>> {
+        self.runtime
+            .block_on(self.client.get_voice_connector_proxy(input))
+    }
+
+    /// <p>Retrieves the streaming configuration details for the specified Amazon Chime Voice Connector. Shows whether media streaming is enabled for sending to Amazon Kinesis. It also shows the retention period, in hours, for the Amazon Kinesis data.</p>
+    ///
+    /// See `Chime::get_voice_connector_streaming_configuration`.
+    pub fn get_voice_connector_streaming_configuration(
+        &self,
+        input: GetVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorStreamingConfigurationResponse,
+        RusotoError<GetVoiceConnectorStreamingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_voice_connector_streaming_configuration(input),
+        )
+    }
+
+    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::get_voice_connector_termination`.
+    pub fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> Result<GetVoiceConnectorTerminationResponse, RusotoError<GetVoiceConnectorTerminationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_voice_connector_termination(input))
+    }
+
+    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::get_voice_connector_termination_health`.
+    pub fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> Result<
+        GetVoiceConnectorTerminationHealthResponse,
+        RusotoError<GetVoiceConnectorTerminationHealthError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_voice_connector_termination_health(input))
+    }
+
+    /// <p>Sends email to a maximum of 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
+    ///
+    /// See `Chime::invite_users`.
+    pub fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> Result<InviteUsersResponse, RusotoError<InviteUsersError>> {
+        self.runtime.block_on(self.client.invite_users(input))
+    }
+
+    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
+    ///
+    /// See `Chime::list_accounts`.
+    pub fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.runtime.block_on(self.client.list_accounts(input))
+    }
+
+    /// <p>Lists the tags applied to an Amazon Chime SDK attendee resource.</p>
+    ///
+    /// See `Chime::list_attendee_tags`.
+    pub fn list_attendee_tags(
+        &self,
+        input: ListAttendeeTagsRequest,
+    ) -> Result<ListAttendeeTagsResponse, RusotoError<ListAttendeeTagsError>> {
+        self.runtime.block_on(self.client.list_attendee_tags(input))
+    }
+
+    /// <p>Lists the attendees for the specified Amazon Chime SDK meeting. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::list_attendees`.
+    pub fn list_attendees(
+        &self,
+        input: ListAttendeesRequest,
+    ) -> Result<ListAttendeesResponse, RusotoError<ListAttendeesError>> {
+        self.runtime.block_on(self.client.list_attendees(input))
+    }
+
+    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
+    ///
+    /// See `Chime::list_bots`.
+    pub fn list_bots(
+        &self,
+        input: ListBotsRequest,
+    ) -> Result<ListBotsResponse, RusotoError<ListBotsError>> {
+        self.runtime.block_on(self.client.list_bots(input))
+    }
+
+    /// <p>Lists the tags applied to an Amazon Chime SDK meeting resource.</p>
+    ///
+    /// See `Chime::list_meeting_tags`.
+    pub fn list_meeting_tags(
+        &self,
+        input: ListMeetingTagsRequest,
+    ) -> Result<ListMeetingTagsResponse, RusotoError<ListMeetingTagsError>> {
+        self.runtime.block_on(self.client.list_meeting_tags(input))
+    }
+
+    /// <p>Lists up to 100 active Amazon Chime SDK meetings. For more information about the Amazon Chime SDK, see <a href="https://docs.aws.amazon.com/chime/latest/dg/meetings-sdk.html">Using the Amazon Chime SDK</a> in the <i>Amazon Chime Developer Guide</i>.</p>
+    ///
+    /// See `Chime::list_meetings`.
+    pub fn list_meetings(
+        &self,
+        input: ListMeetingsRequest,
+    ) -> Result<ListMeetingsResponse, RusotoError<ListMeetingsError>> {
+        self.runtime.block_on(self.client.list_meetings(input))
+    }
+
+    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
+    ///
+    /// See `Chime::list_phone_number_orders`.
+    pub fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> Result<ListPhoneNumberOrdersResponse, RusotoError<ListPhoneNumberOrdersError>> {
+        self.runtime
+            .block_on(self.client.list_phone_number_orders(input))
+    }
+
+    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, Amazon Chime Voice Connector, or Amazon Chime Voice Connector group.</p>
+    ///
+    /// See `Chime::list_phone_numbers`.
+    pub fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+        self.runtime.block_on(self.client.list_phone_numbers(input))
+    }
+
+    /// <p>Lists the proxy sessions for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::list_proxy_sessions`.
+    pub fn list_proxy_sessions(
+        &self,
+        input: ListProxySessionsRequest,
+    ) -> Result<ListProxySessionsResponse, RusotoError<ListProxySessionsError>> {
+        self.runtime
+            .block_on(self.client.list_proxy_sessions(input))
+    }
+
+    /// <p>Lists the membership details for the specified room in an Amazon Chime Enterprise account, such as the members' IDs, email addresses, and names.</p>
+    ///
+    /// See `Chime::list_room_memberships`.
+    pub fn list_room_memberships(
+        &self,
+        input: ListRoomMembershipsRequest,
+    ) -> Result<ListRoomMembershipsResponse, RusotoError<ListRoomMembershipsError>> {
+        self.runtime
+            .block_on(self.client.list_room_memberships(input))
+    }
+
+    /// <p>Lists the room details for the specified Amazon Chime Enterprise account. Optionally, filter the results by a member ID (user ID or bot ID) to see a list of rooms that the member belongs to.</p>
+    ///
+    /// See `Chime::list_rooms`.
+    pub fn list_rooms(
+        &self,
+        input: ListRoomsRequest,
+    ) -> Result<ListRoomsResponse, RusotoError<ListRoomsError>> {
+        self.runtime.block_on(self.client.list_rooms(input))
+    }
+
+    /// <p>Lists the tags applied to an Amazon Chime SDK meeting resource.</p>
+    ///
+    /// See `Chime::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
+    ///
+    /// See `Chime::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Lists the Amazon Chime Voice Connector groups for the administrator's AWS account.</p>
+    ///
+    /// See `Chime::list_voice_connector_groups`.
+    pub fn list_voice_connector_groups(
+        &self,
+        input: ListVoiceConnectorGroupsRequest,
+    ) -> Result<ListVoiceConnectorGroupsResponse, RusotoError<ListVoiceConnectorGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_voice_connector_groups(input))
+    }
+
+    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::list_voice_connector_termination_credentials`.
+    pub fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        RusotoError<ListVoiceConnectorTerminationCredentialsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_voice_connector_termination_credentials(input),
+        )
+    }
+
+    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
+    ///
+    /// See `Chime::list_voice_connectors`.
+    pub fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> Result<ListVoiceConnectorsResponse, RusotoError<ListVoiceConnectorsError>> {
+        self.runtime
+            .block_on(self.client.list_voice_connectors(input))
+    }
+
+    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
+    ///
+    /// See `Chime::logout_user`.
+    pub fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> Result<LogoutUserResponse, RusotoError<LogoutUserError>> {
+        self.runtime.block_on(self.client.logout_user(input))
+    }
+
+    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
+    ///
+    /// See `Chime::put_events_configuration`.
+    pub fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> Result<PutEventsConfigurationResponse, RusotoError<PutEventsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_events_configuration(input))
+    }
+
+    /// <p>Puts retention settings for the specified Amazon Chime Enterprise account. We recommend using AWS CloudTrail to monitor usage of this API for your account. For more information, see <a href="https://docs.aws.amazon.com/chime/latest/ag/cloudtrail.html">Logging Amazon Chime API Calls with AWS CloudTrail</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>To turn off existing retention settings, remove the number of days from the corresponding <b>RetentionDays</b> field in the <b>RetentionSettings</b> object. For more information about retention settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/chat-retention.html">Managing Chat Retention Policies</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ///
+    /// See `Chime::put_retention_settings`.
+    pub fn put_retention_settings(
+        &self,
+        input: PutRetentionSettingsRequest,
+    ) -> Result<PutRetentionSettingsResponse, RusotoError<PutRetentionSettingsError>> {
+        self.runtime
+            .block_on(self.client.put_retention_settings(input))
+    }
+
+    /// <p>Puts emergency calling configuration details to the specified Amazon Chime Voice Connector, such as emergency phone numbers and calling countries. Origination and termination settings must be enabled for the Amazon Chime Voice Connector before emergency calling can be configured.</p>
+    ///
+    /// See `Chime::put_voice_connector_emergency_calling_configuration`.
+    pub fn put_voice_connector_emergency_calling_configuration(
+        &self,
+        input: PutVoiceConnectorEmergencyCallingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorEmergencyCallingConfigurationResponse,
+        RusotoError<PutVoiceConnectorEmergencyCallingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_voice_connector_emergency_calling_configuration(input),
+        )
+    }
+
+    /// <p>Adds a logging configuration for the specified Amazon Chime Voice Connector. The logging configuration specifies whether SIP message logs are enabled for sending to Amazon CloudWatch Logs.</p>
+    ///
+    /// See `Chime::put_voice_connector_logging_configuration`.
+    pub fn put_voice_connector_logging_configuration(
+        &self,
+        input: PutVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorLoggingConfigurationResponse,
+        RusotoError<PutVoiceConnectorLoggingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_voice_connector_logging_configuration(input))
+    }
+
+    /// <p><p>Adds origination settings for the specified Amazon Chime Voice Connector.</p> <note> <p>If emergency calling is configured for the Amazon Chime Voice Connector, it must be deleted prior to turning off origination settings.</p> </note></p>
+    ///
+    /// See `Chime::put_voice_connector_origination`.
+    pub fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> Result<PutVoiceConnectorOriginationResponse, RusotoError<PutVoiceConnectorOriginationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_voice_connector_origination(input))
+    }
+
+    /// <p>Puts the specified proxy configuration to the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::put_voice_connector_proxy`.
+    pub fn put_voice_connector_proxy(
+        &self,
+        input: PutVoiceConnectorProxyRequest,
+    ) -> Result<PutVoiceConnectorProxyResponse, RusotoError<PutVoiceConnectorProxyError>> {
+        self.runtime
+            .block_on(self.client.put_voice_connector_proxy(input))
+    }
+
+    /// <p>Adds a streaming configuration for the specified Amazon Chime Voice Connector. The streaming configuration specifies whether media streaming is enabled for sending to Amazon Kinesis. It also sets the retention period, in hours, for the Amazon Kinesis data.</p>
+    ///
+    /// See `Chime::put_voice_connector_streaming_configuration`.
+    pub fn put_voice_connector_streaming_configuration(
+        &self,
+        input: PutVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorStreamingConfigurationResponse,
+        RusotoError<PutVoiceConnectorStreamingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_voice_connector_streaming_configuration(input),
+        )
+    }
+
+    /// <p><p>Adds termination settings for the specified Amazon Chime Voice Connector.</p> <note> <p>If emergency calling is configured for the Amazon Chime Voice Connector, it must be deleted prior to turning off termination settings.</p> </note></p>
+    ///
+    /// See `Chime::put_voice_connector_termination`.
+    pub fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> Result<PutVoiceConnectorTerminationResponse, RusotoError<PutVoiceConnectorTerminationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_voice_connector_termination(input))
+    }
+
+    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::put_voice_connector_termination_credentials`.
+    pub fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<(), RusotoError<PutVoiceConnectorTerminationCredentialsError>> {
+        self.runtime.block_on(
+            self.client
+                .put_voice_connector_termination_credentials(input),
+        )
+    }
+
+    /// <p>Redacts the specified message from the specified Amazon Chime conversation.</p>
+    ///
+    /// See `Chime::redact_conversation_message`.
+    pub fn redact_conversation_message(
+        &self,
+        input: RedactConversationMessageRequest,
+    ) -> Result<RedactConversationMessageResponse, RusotoError<RedactConversationMessageError>>
+    {
+        self.runtime
+            .block_on(self.client.redact_conversation_message(input))
+    }
+
+    /// <p>Redacts the specified message from the specified Amazon Chime chat room.</p>
+    ///
+    /// See `Chime::redact_room_message`.
+    pub fn redact_room_message(
+        &self,
+        input: RedactRoomMessageRequest,
+    ) -> Result<RedactRoomMessageResponse, RusotoError<RedactRoomMessageError>> {
+        self.runtime
+            .block_on(self.client.redact_room_message(input))
+    }
+
+    /// <p>Regenerates the security token for a bot.</p>
+    ///
+    /// See `Chime::regenerate_security_token`.
+    pub fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> Result<RegenerateSecurityTokenResponse, RusotoError<RegenerateSecurityTokenError>> {
+        self.runtime
+            .block_on(self.client.regenerate_security_token(input))
+    }
+
+    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
+    ///
+    /// See `Chime::reset_personal_pin`.
+    pub fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> Result<ResetPersonalPINResponse, RusotoError<ResetPersonalPINError>> {
+        self.runtime.block_on(self.client.reset_personal_pin(input))
+    }
+
+    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
+    ///
+    /// See `Chime::restore_phone_number`.
+    pub fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> Result<RestorePhoneNumberResponse, RusotoError<RestorePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.restore_phone_number(input))
+    }
+
+    /// <p>Searches phone numbers that can be ordered.</p>
+    ///
+    /// See `Chime::search_available_phone_numbers`.
+    pub fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> Result<SearchAvailablePhoneNumbersResponse, RusotoError<SearchAvailablePhoneNumbersError>>
+    {
+        self.runtime
+            .block_on(self.client.search_available_phone_numbers(input))
+    }
+
+    /// <p>Applies the specified tags to the specified Amazon Chime SDK attendee.</p>
+    ///
+    /// See `Chime::tag_attendee`.
+    pub fn tag_attendee(
+        &self,
+        input: TagAttendeeRequest,
+    ) -> Result<(), RusotoError<TagAttendeeError>> {
+        self.runtime.block_on(self.client.tag_attendee(input))
+    }
+
+    /// <p>Applies the specified tags to the specified Amazon Chime SDK meeting.</p>
+    ///
+    /// See `Chime::tag_meeting`.
+    pub fn tag_meeting(
+        &self,
+        input: TagMeetingRequest,
+    ) -> Result<(), RusotoError<TagMeetingError>> {
+        self.runtime.block_on(self.client.tag_meeting(input))
+    }
+
+    /// <p>Applies the specified tags to the specified Amazon Chime SDK meeting resource.</p>
+    ///
+    /// See `Chime::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Untags the specified tags from the specified Amazon Chime SDK attendee.</p>
+    ///
+    /// See `Chime::untag_attendee`.
+    pub fn untag_attendee(
+        &self,
+        input: UntagAttendeeRequest,
+    ) -> Result<(), RusotoError<UntagAttendeeError>> {
+        self.runtime.block_on(self.client.untag_attendee(input))
+    }
+
+    /// <p>Untags the specified tags from the specified Amazon Chime SDK meeting.</p>
+    ///
+    /// See `Chime::untag_meeting`.
+    pub fn untag_meeting(
+        &self,
+        input: UntagMeetingRequest,
+    ) -> Result<(), RusotoError<UntagMeetingError>> {
+        self.runtime.block_on(self.client.untag_meeting(input))
+    }
+
+    /// <p>Untags the specified tags from the specified Amazon Chime SDK meeting resource.</p>
+    ///
+    /// See `Chime::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
+    ///
+    /// See `Chime::update_account`.
+    pub fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<UpdateAccountResponse, RusotoError<UpdateAccountError>> {
+        self.runtime.block_on(self.client.update_account(input))
+    }
+
+    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ///
+    /// See `Chime::update_account_settings`.
+    pub fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> Result<UpdateAccountSettingsResponse, RusotoError<UpdateAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_account_settings(input))
+    }
+
+    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::update_bot`.
+    pub fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> Result<UpdateBotResponse, RusotoError<UpdateBotError>> {
+        self.runtime.block_on(self.client.update_bot(input))
+    }
+
+    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    ///
+    /// See `Chime::update_global_settings`.
+    pub fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> Result<(), RusotoError<UpdateGlobalSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_global_settings(input))
+    }
+
+    /// <p>Updates phone number details, such as product type or calling name, for the specified phone number ID. You can update one phone number detail at a time. For example, you can update either the product type or the calling name in one action.</p> <p>For toll-free numbers, you must use the Amazon Chime Voice Connector product type.</p> <p>Updates to outbound calling names can take up to 72 hours to complete. Pending updates to outbound calling names must be complete before you can request another update.</p>
+    ///
+    /// See `Chime::update_phone_number`.
+    pub fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> Result<UpdatePhoneNumberResponse, RusotoError<UpdatePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.update_phone_number(input))
+    }
+
+    /// <p>Updates the phone number settings for the administrator's AWS account, such as the default outbound calling name. You can update the default outbound calling name once every seven days. Outbound calling names can take up to 72 hours to update.</p>
+    ///
+    /// See `Chime::update_phone_number_settings`.
+    pub fn update_phone_number_settings(
+        &self,
+        input: UpdatePhoneNumberSettingsRequest,
+    ) -> Result<(), RusotoError<UpdatePhoneNumberSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_phone_number_settings(input))
+    }
+
+    /// <p>Updates the specified proxy session details, such as voice or SMS capabilities.</p>
+    ///
+    /// See `Chime::update_proxy_session`.
+    pub fn update_proxy_session(
+        &self,
+        input: UpdateProxySessionRequest,
+    ) -> Result<UpdateProxySessionResponse, RusotoError<UpdateProxySessionError>> {
+        self.runtime
+            .block_on(self.client.update_proxy_session(input))
+    }
+
+    /// <p>Updates room details, such as the room name, for a room in an Amazon Chime Enterprise account.</p>
+    ///
+    /// See `Chime::update_room`.
+    pub fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+        self.runtime.block_on(self.client.update_room(input))
+    }
+
+    /// <p>Updates room membership details, such as the member role, for a room in an Amazon Chime Enterprise account. The member role designates whether the member is a chat room administrator or a general chat room member. The member role can be updated only for user IDs.</p>
+    ///
+    /// See `Chime::update_room_membership`.
+    pub fn update_room_membership(
+        &self,
+        input: UpdateRoomMembershipRequest,
+    ) -> Result<UpdateRoomMembershipResponse, RusotoError<UpdateRoomMembershipError>> {
+        self.runtime
+            .block_on(self.client.update_room_membership(input))
+    }
+
+    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    ///
+    /// See `Chime::update_user`.
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+
+    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
+    ///
+    /// See `Chime::update_user_settings`.
+    pub fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> Result<(), RusotoError<UpdateUserSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_user_settings(input))
+    }
+
+    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
+    ///
+    /// See `Chime::update_voice_connector`.
+    pub fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> Result<UpdateVoiceConnectorResponse, RusotoError<UpdateVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.update_voice_connector(input))
+    }
+
+    /// <p>Updates details for the specified Amazon Chime Voice Connector group, such as the name and Amazon Chime Voice Connector priority ranking.</p>
+    ///
+    /// See `Chime::update_voice_connector_group`.
+    pub fn update_voice_connector_group(
+        &self,
+        input: UpdateVoiceConnectorGroupRequest,
+    ) -> Result<UpdateVoiceConnectorGroupResponse, RusotoError<UpdateVoiceConnectorGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.update_voice_connector_group(input))
+    }
+}
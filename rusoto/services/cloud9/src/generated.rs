@@ -449,29 +449,40 @@ impl CreateEnvironmentEC2Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentEC2Error::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateEnvironmentEC2Error::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentEC2Error::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentEC2Error::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentEC2Error::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateEnvironmentEC2Error::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentEC2Error::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -521,39 +532,46 @@ impl CreateEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentMembershipError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEnvironmentMembershipError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentMembershipError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentMembershipError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEnvironmentMembershipError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -603,27 +621,34 @@ impl DeleteEnvironmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteEnvironmentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteEnvironmentError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteEnvironmentError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteEnvironmentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -673,39 +698,46 @@ impl DeleteEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentMembershipError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEnvironmentMembershipError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentMembershipError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentMembershipError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEnvironmentMembershipError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -757,37 +789,44 @@ impl DescribeEnvironmentMembershipsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentMembershipsError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentMembershipsError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentMembershipsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEnvironmentMembershipsError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEnvironmentMembershipsError::LimitExceeded(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentMembershipsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEnvironmentMembershipsError::TooManyRequests(err.msg),
                     )
                 }
@@ -841,33 +880,46 @@ impl DescribeEnvironmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentStatusError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentStatusError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentStatusError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEnvironmentStatusError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentStatusError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentStatusError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentStatusError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -917,29 +969,40 @@ impl DescribeEnvironmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentsError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::Conflict(err.msg))
+                    return RusotoError::service(&res, DescribeEnvironmentsError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentsError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeEnvironmentsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEnvironmentsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -987,27 +1050,34 @@ impl ListEnvironmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListEnvironmentsError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListEnvironmentsError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListEnvironmentsError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListEnvironmentsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListEnvironmentsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListEnvironmentsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEnvironmentsError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListEnvironmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEnvironmentsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListEnvironmentsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListEnvironmentsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEnvironmentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEnvironmentsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1047,15 +1117,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1091,13 +1165,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1133,13 +1210,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1183,27 +1263,34 @@ impl UpdateEnvironmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateEnvironmentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateEnvironmentError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateEnvironmentError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateEnvironmentError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1253,39 +1340,46 @@ impl UpdateEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentMembershipError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentMembershipError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentMembershipError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEnvironmentMembershipError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentMembershipError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentMembershipError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnvironmentMembershipError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1413,6 +1507,7 @@ impl Cloud9Client {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1428,6 +1523,7 @@ impl Cloud9Client {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Cloud9Client {
         Cloud9Client { client, region }
     }
@@ -1441,6 +1537,9 @@ impl Cloud9 for Cloud9Client {
         input: CreateEnvironmentEC2Request,
     ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEnvironmentEC2");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.CreateEnvironmentEC2",
@@ -1463,6 +1562,9 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEnvironmentMembership");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.CreateEnvironmentMembership",
@@ -1485,6 +1587,9 @@ impl Cloud9 for Cloud9Client {
         input: DeleteEnvironmentRequest,
     ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DeleteEnvironment",
@@ -1507,6 +1612,9 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEnvironmentMembership");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DeleteEnvironmentMembership",
@@ -1532,6 +1640,9 @@ impl Cloud9 for Cloud9Client {
         RusotoError<DescribeEnvironmentMembershipsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEnvironmentMemberships");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DescribeEnvironmentMemberships",
@@ -1554,6 +1665,9 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentStatusRequest,
     ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEnvironmentStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DescribeEnvironmentStatus",
@@ -1576,6 +1690,9 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentsRequest,
     ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEnvironments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DescribeEnvironments",
@@ -1597,6 +1714,9 @@ impl Cloud9 for Cloud9Client {
         input: ListEnvironmentsRequest,
     ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEnvironments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.ListEnvironments",
@@ -1618,6 +1738,9 @@ impl Cloud9 for Cloud9Client {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.ListTagsForResource",
@@ -1639,6 +1762,9 @@ impl Cloud9 for Cloud9Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.TagResource",
@@ -1660,6 +1786,9 @@ impl Cloud9 for Cloud9Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.UntagResource",
@@ -1681,6 +1810,9 @@ impl Cloud9 for Cloud9Client {
         input: UpdateEnvironmentRequest,
     ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.UpdateEnvironment",
@@ -1703,6 +1835,9 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEnvironmentMembership");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.UpdateEnvironmentMembership",
@@ -1719,3 +1854,197 @@ impl Cloud9 for Cloud9Client {
             .deserialize::<UpdateEnvironmentMembershipResult, _>()
     }
 }
+
+/// A blocking client for the AWS Cloud9 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Cloud9Client` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Cloud9BlockingClient {
+    client: Cloud9Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Cloud9BlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Cloud9BlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Cloud9BlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Cloud9BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Cloud9BlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> Cloud9BlockingClient {
+        Cloud9BlockingClient {
+            client: Cloud9Client::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an AWS Cloud9 development environment, launches an Amazon Elastic Compute Cloud (Amazon EC2) instance, and then connects from the instance to the environment.</p>
+    ///
+    /// See `Cloud9::create_environment_ec2`.
+    pub fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
+        self.runtime
+            .block_on(self.client.create_environment_ec2(input))
+    }
+
+    /// <p>Adds an environment member to an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::create_environment_membership`.
+    pub fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.create_environment_membership(input))
+    }
+
+    /// <p>Deletes an AWS Cloud9 development environment. If an Amazon EC2 instance is connected to the environment, also terminates the instance.</p>
+    ///
+    /// See `Cloud9::delete_environment`.
+    pub fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
+        self.runtime.block_on(self.client.delete_environment(input))
+    }
+
+    /// <p>Deletes an environment member from an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::delete_environment_membership`.
+    pub fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_environment_membership(input))
+    }
+
+    /// <p>Gets information about environment members for an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::describe_environment_memberships`.
+    pub fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> Result<
+        DescribeEnvironmentMembershipsResult,
+        RusotoError<DescribeEnvironmentMembershipsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_environment_memberships(input))
+    }
+
+    /// <p>Gets status information for an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::describe_environment_status`.
+    pub fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_environment_status(input))
+    }
+
+    /// <p>Gets information about AWS Cloud9 development environments.</p>
+    ///
+    /// See `Cloud9::describe_environments`.
+    pub fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.describe_environments(input))
+    }
+
+    /// <p>Gets a list of AWS Cloud9 development environment identifiers.</p>
+    ///
+    /// See `Cloud9::list_environments`.
+    pub fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
+        self.runtime.block_on(self.client.list_environments(input))
+    }
+
+    /// <p>Gets a list of the tags associated with an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p><p>Adds tags to an AWS Cloud9 development environment.</p> <important> <p>Tags that you add to an AWS Cloud9 environment by using this method will NOT be automatically propagated to underlying resources.</p> </important></p>
+    ///
+    /// See `Cloud9::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Changes the settings of an existing AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::update_environment`.
+    pub fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
+        self.runtime.block_on(self.client.update_environment(input))
+    }
+
+    /// <p>Changes the settings of an existing environment member for an AWS Cloud9 development environment.</p>
+    ///
+    /// See `Cloud9::update_environment_membership`.
+    pub fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.update_environment_membership(input))
+    }
+}
@@ -3241,30 +3241,46 @@ impl AddFacetToObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddFacetToObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AddFacetToObjectError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AddFacetToObjectError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddFacetToObjectError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AddFacetToObjectError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFacetToObjectError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AddFacetToObjectError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFacetToObjectError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AddFacetToObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, AddFacetToObjectError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddFacetToObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFacetToObjectError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddFacetToObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFacetToObjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AddFacetToObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFacetToObjectError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3315,28 +3331,31 @@ impl ApplySchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ApplySchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ApplySchemaError::InternalService(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ApplySchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::InvalidArn(err.msg))
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(ApplySchemaError::InvalidAttachment(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::InvalidAttachment(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ApplySchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ApplySchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ApplySchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, ApplySchemaError::RetryableConflict(err.msg))
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(ApplySchemaError::SchemaAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ApplySchemaError::SchemaAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3391,34 +3410,46 @@ impl AttachObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachObjectError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachObjectError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AttachObjectError::FacetValidation(err.msg))
+                    return RusotoError::service(&res, AttachObjectError::FacetValidation(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachObjectError::InternalService(err.msg))
+                    return RusotoError::service(&res, AttachObjectError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, AttachObjectError::InvalidArn(err.msg))
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachObjectError::InvalidAttachment(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachObjectError::InvalidAttachment(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AttachObjectError::LimitExceeded(err.msg))
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(AttachObjectError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachObjectError::LinkNameAlreadyInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AttachObjectError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachObjectError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3471,28 +3502,34 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachPolicyError::InternalService(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::LimitExceeded(err.msg))
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(AttachPolicyError::NotPolicy(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::NotPolicy(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3549,39 +3586,55 @@ impl AttachToIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachToIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachToIndexError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachToIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachToIndexError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "IndexedAttributeMissingException" => {
-                    return RusotoError::Service(AttachToIndexError::IndexedAttributeMissing(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachToIndexError::IndexedAttributeMissing(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachToIndexError::InternalService(err.msg))
+                    return RusotoError::service(&res, AttachToIndexError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachToIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, AttachToIndexError::InvalidArn(err.msg))
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachToIndexError::InvalidAttachment(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachToIndexError::InvalidAttachment(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachToIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AttachToIndexError::LimitExceeded(err.msg))
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(AttachToIndexError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachToIndexError::LinkNameAlreadyInUse(err.msg),
+                    )
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(AttachToIndexError::NotIndex(err.msg))
+                    return RusotoError::service(&res, AttachToIndexError::NotIndex(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachToIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachToIndexError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachToIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachToIndexError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3637,31 +3690,49 @@ impl AttachTypedLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachTypedLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachTypedLinkError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachTypedLinkError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachTypedLinkError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AttachTypedLinkError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachTypedLinkError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachTypedLinkError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, AttachTypedLinkError::InvalidArn(err.msg))
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InvalidAttachment(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachTypedLinkError::InvalidAttachment(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachTypedLinkError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AttachTypedLinkError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachTypedLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachTypedLinkError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachTypedLinkError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachTypedLinkError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3709,22 +3780,22 @@ impl BatchReadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchReadError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, BatchReadError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(BatchReadError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(&res, BatchReadError::DirectoryNotEnabled(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchReadError::InternalService(err.msg))
+                    return RusotoError::service(&res, BatchReadError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(BatchReadError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, BatchReadError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchReadError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, BatchReadError::LimitExceeded(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(BatchReadError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, BatchReadError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3771,25 +3842,28 @@ impl BatchWriteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchWriteError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, BatchWriteError::AccessDenied(err.msg))
                 }
                 "BatchWriteException" => {
-                    return RusotoError::Service(BatchWriteError::BatchWrite(err.msg))
+                    return RusotoError::service(&res, BatchWriteError::BatchWrite(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(BatchWriteError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchWriteError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchWriteError::InternalService(err.msg))
+                    return RusotoError::service(&res, BatchWriteError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(BatchWriteError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, BatchWriteError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchWriteError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, BatchWriteError::LimitExceeded(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(BatchWriteError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, BatchWriteError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3837,27 +3911,37 @@ impl CreateDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDirectoryError::AccessDenied(err.msg))
                 }
                 "DirectoryAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDirectoryError::DirectoryAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryError::DirectoryAlreadyExists(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateDirectoryError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDirectoryError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3909,31 +3993,34 @@ impl CreateFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::AccessDenied(err.msg))
                 }
                 "FacetAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFacetError::FacetAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFacetError::FacetAlreadyExists(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateFacetError::FacetValidation(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::FacetValidation(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateFacetError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::InvalidArn(err.msg))
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(CreateFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::InvalidRule(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, CreateFacetError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3989,34 +4076,43 @@ impl CreateIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(CreateIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIndexError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateIndexError::FacetValidation(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::FacetValidation(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateIndexError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::LimitExceeded(err.msg))
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(CreateIndexError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIndexError::LinkNameAlreadyInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::RetryableConflict(err.msg))
                 }
                 "UnsupportedIndexTypeException" => {
-                    return RusotoError::Service(CreateIndexError::UnsupportedIndexType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIndexError::UnsupportedIndexType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4073,34 +4169,46 @@ impl CreateObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateObjectError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(CreateObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateObjectError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateObjectError::FacetValidation(err.msg))
+                    return RusotoError::service(&res, CreateObjectError::FacetValidation(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateObjectError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateObjectError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateObjectError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateObjectError::LimitExceeded(err.msg))
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(CreateObjectError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateObjectError::LinkNameAlreadyInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateObjectError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateObjectError::RetryableConflict(err.msg),
+                    )
                 }
                 "UnsupportedIndexTypeException" => {
-                    return RusotoError::Service(CreateObjectError::UnsupportedIndexType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateObjectError::UnsupportedIndexType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4149,22 +4257,28 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSchemaError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::LimitExceeded(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSchemaError::RetryableConflict(err.msg),
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::SchemaAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSchemaError::SchemaAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4215,41 +4329,58 @@ impl CreateTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::AccessDenied(err.msg),
+                    )
                 }
                 "FacetAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::FacetAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::FacetAlreadyExists(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::InvalidRule(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTypedLinkFacetError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4301,30 +4432,43 @@ impl DeleteDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDirectoryError::AccessDenied(err.msg))
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(DeleteDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryError::DirectoryDeleted(err.msg),
+                    )
                 }
                 "DirectoryNotDisabledException" => {
-                    return RusotoError::Service(DeleteDirectoryError::DirectoryNotDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryError::DirectoryNotDisabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteDirectoryError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteDirectoryError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4375,28 +4519,28 @@ impl DeleteFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::AccessDenied(err.msg))
                 }
                 "FacetInUseException" => {
-                    return RusotoError::Service(DeleteFacetError::FacetInUse(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::FacetInUse(err.msg))
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(DeleteFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::FacetNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteFacetError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, DeleteFacetError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4447,28 +4591,37 @@ impl DeleteObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteObjectError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DeleteObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteObjectError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteObjectError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteObjectError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteObjectError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteObjectError::LimitExceeded(err.msg))
                 }
                 "ObjectNotDetachedException" => {
-                    return RusotoError::Service(DeleteObjectError::ObjectNotDetached(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteObjectError::ObjectNotDetached(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteObjectError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteObjectError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4517,25 +4670,31 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteSchemaError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaError::RetryableConflict(err.msg),
+                    )
                 }
                 "StillContainsLinksException" => {
-                    return RusotoError::Service(DeleteSchemaError::StillContainsLinks(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaError::StillContainsLinks(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4583,31 +4742,46 @@ impl DeleteTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::AccessDenied(err.msg),
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::FacetNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTypedLinkFacetError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4659,33 +4833,46 @@ impl DetachFromIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachFromIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachFromIndexError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachFromIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachFromIndexError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachFromIndexError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachFromIndexError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachFromIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DetachFromIndexError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachFromIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DetachFromIndexError::LimitExceeded(err.msg))
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(DetachFromIndexError::NotIndex(err.msg))
+                    return RusotoError::service(&res, DetachFromIndexError::NotIndex(err.msg))
                 }
                 "ObjectAlreadyDetachedException" => {
-                    return RusotoError::Service(DetachFromIndexError::ObjectAlreadyDetached(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachFromIndexError::ObjectAlreadyDetached(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachFromIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachFromIndexError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachFromIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachFromIndexError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4737,28 +4924,34 @@ impl DetachObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachObjectError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachObjectError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachObjectError::InternalService(err.msg))
+                    return RusotoError::service(&res, DetachObjectError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DetachObjectError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DetachObjectError::LimitExceeded(err.msg))
                 }
                 "NotNodeException" => {
-                    return RusotoError::Service(DetachObjectError::NotNode(err.msg))
+                    return RusotoError::service(&res, DetachObjectError::NotNode(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DetachObjectError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachObjectError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4809,28 +5002,34 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachPolicyError::InternalService(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::LimitExceeded(err.msg))
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(DetachPolicyError::NotPolicy(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::NotPolicy(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4881,28 +5080,43 @@ impl DetachTypedLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachTypedLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachTypedLinkError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachTypedLinkError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachTypedLinkError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(DetachTypedLinkError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachTypedLinkError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachTypedLinkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachTypedLinkError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachTypedLinkError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DetachTypedLinkError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachTypedLinkError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DetachTypedLinkError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachTypedLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachTypedLinkError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachTypedLinkError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachTypedLinkError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4951,25 +5165,40 @@ impl DisableDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DisableDirectoryError::AccessDenied(err.msg))
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(DisableDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableDirectoryError::DirectoryDeleted(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisableDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableDirectoryError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DisableDirectoryError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableDirectoryError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableDirectoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DisableDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableDirectoryError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5017,25 +5246,37 @@ impl EnableDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, EnableDirectoryError::AccessDenied(err.msg))
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(EnableDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableDirectoryError::DirectoryDeleted(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(EnableDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableDirectoryError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, EnableDirectoryError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, EnableDirectoryError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableDirectoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(EnableDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableDirectoryError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5081,32 +5322,40 @@ impl GetAppliedSchemaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppliedSchemaVersionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppliedSchemaVersionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAppliedSchemaVersionError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppliedSchemaVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppliedSchemaVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppliedSchemaVersionError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5149,19 +5398,22 @@ impl GetDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDirectoryError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDirectoryError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetDirectoryError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetDirectoryError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetDirectoryError::LimitExceeded(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDirectoryError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5207,25 +5459,25 @@ impl GetFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetFacetError::AccessDenied(err.msg))
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(GetFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(&res, GetFacetError::FacetNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetFacetError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetFacetError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetFacetError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetFacetError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetFacetError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, GetFacetError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5275,30 +5527,49 @@ impl GetLinkAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinkAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetLinkAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(GetLinkAttributesError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetLinkAttributesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetLinkAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetLinkAttributesError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLinkAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinkAttributesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetLinkAttributesError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAttributesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5349,34 +5620,52 @@ impl GetObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetObjectAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(GetObjectAttributesError::FacetValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetObjectAttributesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetObjectAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetObjectAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetObjectAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectAttributesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5425,33 +5714,46 @@ impl GetObjectInformationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetObjectInformationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetObjectInformationError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetObjectInformationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetObjectInformationError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetObjectInformationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetObjectInformationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetObjectInformationError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetObjectInformationError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5497,22 +5799,31 @@ impl GetSchemaAsJsonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetSchemaAsJsonError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaAsJsonError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetSchemaAsJsonError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetSchemaAsJsonError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaAsJsonError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSchemaAsJsonError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5563,42 +5874,50 @@ impl GetTypedLinkFacetInformationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTypedLinkFacetInformationError::AccessDenied(err.msg),
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::FacetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTypedLinkFacetInformationError::FacetNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTypedLinkFacetInformationError::InternalService(err.msg),
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTypedLinkFacetInformationError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTypedLinkFacetInformationError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTypedLinkFacetInformationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTypedLinkFacetInformationError::ResourceNotFound(err.msg),
                     )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTypedLinkFacetInformationError::RetryableConflict(err.msg),
                     )
                 }
@@ -5655,33 +5974,46 @@ impl ListAppliedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAppliedSchemaArnsError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5729,31 +6061,46 @@ impl ListAttachedIndicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedIndicesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5801,39 +6148,46 @@ impl ListDevelopmentSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevelopmentSchemaArnsError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5879,22 +6233,31 @@ impl ListDirectoriesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDirectoriesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListDirectoriesError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDirectoriesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDirectoriesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListDirectoriesError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListDirectoriesError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDirectoriesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDirectoriesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDirectoriesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListDirectoriesError::LimitExceeded(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListDirectoriesError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDirectoriesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5943,34 +6306,52 @@ impl ListFacetAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacetAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(ListFacetAttributesError::FacetNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::FacetNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListFacetAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacetAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListFacetAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetAttributesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6019,25 +6400,37 @@ impl ListFacetNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacetNamesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListFacetNamesError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFacetNamesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetNamesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListFacetNamesError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListFacetNamesError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFacetNamesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetNamesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListFacetNamesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListFacetNamesError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacetNamesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetNamesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListFacetNamesError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacetNamesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6089,45 +6482,58 @@ impl ListIncomingTypedLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIncomingTypedLinksError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6183,34 +6589,34 @@ impl ListIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListIndexError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(&res, ListIndexError::DirectoryNotEnabled(err.msg))
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListIndexError::FacetValidation(err.msg))
+                    return RusotoError::service(&res, ListIndexError::FacetValidation(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIndexError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListIndexError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListIndexError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListIndexError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIndexError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListIndexError::InvalidNextToken(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListIndexError::LimitExceeded(err.msg))
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(ListIndexError::NotIndex(err.msg))
+                    return RusotoError::service(&res, ListIndexError::NotIndex(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListIndexError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, ListIndexError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6257,25 +6663,34 @@ impl ListManagedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListManagedSchemaArnsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListManagedSchemaArnsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListManagedSchemaArnsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListManagedSchemaArnsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListManagedSchemaArnsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6325,43 +6740,58 @@ impl ListObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListObjectAttributesError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectAttributesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6415,35 +6845,52 @@ impl ListObjectChildrenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectChildrenError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectChildrenError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListObjectChildrenError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectChildrenError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotNodeException" => {
-                    return RusotoError::Service(ListObjectChildrenError::NotNode(err.msg))
+                    return RusotoError::service(&res, ListObjectChildrenError::NotNode(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectChildrenError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectChildrenError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectChildrenError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6495,38 +6942,52 @@ impl ListObjectParentPathsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentPathsError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6579,35 +7040,55 @@ impl ListObjectParentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectParentsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::AccessDenied(err.msg),
+                    )
                 }
                 "CannotListParentOfRootException" => {
-                    return RusotoError::Service(ListObjectParentsError::CannotListParentOfRoot(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::CannotListParentOfRoot(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectParentsError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectParentsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectParentsError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListObjectParentsError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectParentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectParentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectParentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectParentsError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectParentsError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6659,32 +7140,49 @@ impl ListObjectPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListObjectPoliciesError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListObjectPoliciesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6737,45 +7235,58 @@ impl ListOutgoingTypedLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingTypedLinksError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6829,41 +7340,58 @@ impl ListPolicyAttachmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::NotPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::NotPolicy(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyAttachmentsError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6913,37 +7441,46 @@ impl ListPublishedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishedSchemaArnsError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6991,31 +7528,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidTaggingRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidTaggingRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7067,42 +7619,50 @@ impl ListTypedLinkFacetAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::FacetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetAttributesError::FacetNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTypedLinkFacetAttributesError::InternalService(err.msg),
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetAttributesError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTypedLinkFacetAttributesError::InvalidNextToken(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTypedLinkFacetAttributesError::ResourceNotFound(err.msg),
                     )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTypedLinkFacetAttributesError::RetryableConflict(err.msg),
                     )
                 }
@@ -7159,37 +7719,46 @@ impl ListTypedLinkFacetNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTypedLinkFacetNamesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7239,28 +7808,34 @@ impl LookupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(LookupPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, LookupPolicyError::AccessDenied(err.msg))
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(LookupPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LookupPolicyError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(LookupPolicyError::InternalService(err.msg))
+                    return RusotoError::service(&res, LookupPolicyError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(LookupPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, LookupPolicyError::InvalidArn(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(LookupPolicyError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, LookupPolicyError::InvalidNextToken(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(LookupPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, LookupPolicyError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(LookupPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, LookupPolicyError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(LookupPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LookupPolicyError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7309,27 +7884,34 @@ impl PublishSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PublishSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, PublishSchemaError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PublishSchemaError::InternalService(err.msg))
+                    return RusotoError::service(&res, PublishSchemaError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PublishSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, PublishSchemaError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PublishSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PublishSchemaError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishSchemaError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(PublishSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishSchemaError::RetryableConflict(err.msg),
+                    )
                 }
                 "SchemaAlreadyPublishedException" => {
-                    return RusotoError::Service(PublishSchemaError::SchemaAlreadyPublished(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PublishSchemaError::SchemaAlreadyPublished(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7377,25 +7959,40 @@ impl PutSchemaFromJsonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSchemaFromJsonError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSchemaFromJsonError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, PutSchemaFromJsonError::InvalidArn(err.msg))
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidRule(err.msg))
+                    return RusotoError::service(&res, PutSchemaFromJsonError::InvalidRule(err.msg))
                 }
                 "InvalidSchemaDocException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidSchemaDoc(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSchemaFromJsonError::InvalidSchemaDoc(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSchemaFromJsonError::LimitExceeded(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSchemaFromJsonError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7445,38 +8042,52 @@ impl RemoveFacetFromObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFacetFromObjectError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7525,25 +8136,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArn(err.msg))
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTaggingRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidTaggingRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(TagResourceError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7591,25 +8205,34 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArn(err.msg))
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTaggingRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidTaggingRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UntagResourceError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7663,34 +8286,37 @@ impl UpdateFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::AccessDenied(err.msg))
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(UpdateFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::FacetNotFound(err.msg))
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateFacetError::FacetValidation(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::FacetValidation(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFacetError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::InvalidArn(err.msg))
                 }
                 "InvalidFacetUpdateException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidFacetUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFacetError::InvalidFacetUpdate(err.msg),
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::InvalidRule(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service(&res, UpdateFacetError::RetryableConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7743,38 +8369,52 @@ impl UpdateLinkAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkAttributesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7827,45 +8467,58 @@ impl UpdateObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::DirectoryNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::DirectoryNotEnabled(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::InvalidArn(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::LinkNameAlreadyInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::LinkNameAlreadyInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateObjectAttributesError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7913,22 +8566,25 @@ impl UpdateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateSchemaError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::InternalService(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::InvalidArn(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::ResourceNotFound(err.msg))
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSchemaError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7981,44 +8637,64 @@ impl UpdateTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::AccessDenied(err.msg),
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::FacetNotFound(err.msg),
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::FacetValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::FacetValidation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidFacetUpdateException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidFacetUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::InvalidFacetUpdate(err.msg),
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidRule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::InvalidRule(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTypedLinkFacetError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8071,40 +8747,52 @@ impl UpgradeAppliedSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::AccessDenied(err.msg),
+                    )
                 }
                 "IncompatibleSchemaException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::IncompatibleSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::IncompatibleSchema(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InvalidAttachment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::InvalidAttachment(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::RetryableConflict(err.msg),
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::SchemaAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeAppliedSchemaError::SchemaAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8155,40 +8843,52 @@ impl UpgradePublishedSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::AccessDenied(err.msg),
+                    )
                 }
                 "IncompatibleSchemaException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::IncompatibleSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::IncompatibleSchema(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::InternalService(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InvalidAttachment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::InvalidAttachment(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::ResourceNotFound(err.msg),
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::RetryableConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradePublishedSchemaError::RetryableConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8630,6 +9330,7 @@ impl CloudDirectoryClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -8645,6 +9346,7 @@ impl CloudDirectoryClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryClient {
         CloudDirectoryClient { client, region }
     }
@@ -8661,6 +9363,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("AddFacetToObject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8693,6 +9398,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/apply";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ApplySchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8725,6 +9433,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("AttachObject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8757,6 +9468,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("AttachPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8789,6 +9503,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("AttachToIndex");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8821,6 +9538,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("AttachTypedLink");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8853,6 +9573,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchread";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("BatchRead");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8886,6 +9609,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchwrite";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("BatchWrite");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8918,6 +9644,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("CreateDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8950,6 +9679,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("CreateFacet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8982,6 +9714,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("CreateIndex");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9014,6 +9749,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("CreateObject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9046,6 +9784,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("CreateSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9077,6 +9818,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("CreateTypedLinkFacet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9109,6 +9853,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DeleteDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9139,6 +9886,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DeleteFacet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9171,6 +9921,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DeleteObject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9203,6 +9956,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DeleteSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn.to_string());
@@ -9233,6 +9989,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DeleteTypedLinkFacet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9265,6 +10024,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DetachFromIndex");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9297,6 +10059,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DetachObject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9329,6 +10094,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DetachPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9361,6 +10129,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DetachTypedLink");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9392,6 +10163,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/disable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("DisableDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9422,6 +10196,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/enable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("EnableDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9452,6 +10229,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/getappliedschema";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetAppliedSchemaVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9483,6 +10263,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetDirectory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9513,6 +10296,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetFacet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9545,6 +10331,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetLinkAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9577,6 +10366,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetObjectAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9610,6 +10402,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/information";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetObjectInformation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9643,6 +10438,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetSchemaAsJson");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn.to_string());
@@ -9674,6 +10472,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("GetTypedLinkFacetInformation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9706,6 +10507,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/applied";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListAppliedSchemaArns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9737,6 +10541,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/indices";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListAttachedIndices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9771,6 +10578,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/development";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListDevelopmentSchemaArns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9802,6 +10612,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListDirectories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9833,6 +10646,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListFacetAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9865,6 +10681,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListFacetNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9897,6 +10716,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/incoming";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListIncomingTypedLinks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9929,6 +10751,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/targets";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListIndex");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9962,6 +10787,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/managed";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListManagedSchemaArns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9993,6 +10821,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListObjectAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10026,6 +10857,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/children";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListObjectChildren");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10059,6 +10893,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parentpaths";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListObjectParentPaths");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10091,6 +10928,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parent";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListObjectParents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10124,6 +10964,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/policy";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListObjectPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10157,6 +11000,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/outgoing";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListOutgoingTypedLinks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10189,6 +11035,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attachment";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListPolicyAttachments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10222,6 +11071,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/published";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListPublishedSchemaArns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10253,6 +11105,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10285,6 +11140,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListTypedLinkFacetAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10317,6 +11175,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("ListTypedLinkFacetNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10349,6 +11210,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/lookup";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("LookupPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10381,6 +11245,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/publish";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("PublishSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10416,6 +11283,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("PutSchemaFromJson");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10448,6 +11318,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("RemoveFacetFromObject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10480,6 +11353,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/add";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10511,6 +11387,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/remove";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10542,6 +11421,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpdateFacet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10574,6 +11456,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/update";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpdateLinkAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10606,6 +11491,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpdateObjectAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10638,6 +11526,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpdateSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10670,6 +11561,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpdateTypedLinkFacet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10702,6 +11596,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradeapplied";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpgradeAppliedSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10733,6 +11630,9 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradepublished";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation_name("UpgradePublishedSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10755,3 +11655,748 @@ impl CloudDirectory for CloudDirectoryClient {
         }
     }
 }
+
+/// A blocking client for the Amazon CloudDirectory API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudDirectoryClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudDirectoryBlockingClient {
+    client: CloudDirectoryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudDirectoryBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudDirectoryBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudDirectoryBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudDirectoryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudDirectoryBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient {
+            client: CloudDirectoryClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds a new <a>Facet</a> to an object. An object can have more than one facet applied on it.</p>
+    ///
+    /// See `CloudDirectory::add_facet_to_object`.
+    pub fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> Result<AddFacetToObjectResponse, RusotoError<AddFacetToObjectError>> {
+        self.runtime
+            .block_on(self.client.add_facet_to_object(input))
+    }
+
+    /// <p>Copies the input published schema, at the specified version, into the <a>Directory</a> with the same name and version as that of the published schema.</p>
+    ///
+    /// See `CloudDirectory::apply_schema`.
+    pub fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> Result<ApplySchemaResponse, RusotoError<ApplySchemaError>> {
+        self.runtime.block_on(self.client.apply_schema(input))
+    }
+
+    /// <p><p>Attaches an existing object to another object. An object can be accessed in two ways:</p> <ol> <li> <p>Using the path</p> </li> <li> <p>Using <code>ObjectIdentifier</code> </p> </li> </ol></p>
+    ///
+    /// See `CloudDirectory::attach_object`.
+    pub fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> Result<AttachObjectResponse, RusotoError<AttachObjectError>> {
+        self.runtime.block_on(self.client.attach_object(input))
+    }
+
+    /// <p>Attaches a policy object to a regular object. An object can have a limited number of attached policies.</p>
+    ///
+    /// See `CloudDirectory::attach_policy`.
+    pub fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<AttachPolicyResponse, RusotoError<AttachPolicyError>> {
+        self.runtime.block_on(self.client.attach_policy(input))
+    }
+
+    /// <p>Attaches the specified object to the specified index.</p>
+    ///
+    /// See `CloudDirectory::attach_to_index`.
+    pub fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> Result<AttachToIndexResponse, RusotoError<AttachToIndexError>> {
+        self.runtime.block_on(self.client.attach_to_index(input))
+    }
+
+    /// <p>Attaches a typed link to a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::attach_typed_link`.
+    pub fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> Result<AttachTypedLinkResponse, RusotoError<AttachTypedLinkError>> {
+        self.runtime.block_on(self.client.attach_typed_link(input))
+    }
+
+    /// <p>Performs all the read operations in a batch. </p>
+    ///
+    /// See `CloudDirectory::batch_read`.
+    pub fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> Result<BatchReadResponse, RusotoError<BatchReadError>> {
+        self.runtime.block_on(self.client.batch_read(input))
+    }
+
+    /// <p>Performs all the write operations in a batch. Either all the operations succeed or none.</p>
+    ///
+    /// See `CloudDirectory::batch_write`.
+    pub fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> Result<BatchWriteResponse, RusotoError<BatchWriteError>> {
+        self.runtime.block_on(self.client.batch_write(input))
+    }
+
+    /// <p>Creates a <a>Directory</a> by copying the published schema into the directory. A directory cannot be created without a schema.</p> <p>You can also quickly create a directory using a managed schema, called the <code>QuickStartSchema</code>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_managed.html">Managed Schema</a> in the <i>Amazon Cloud Directory Developer Guide</i>.</p>
+    ///
+    /// See `CloudDirectory::create_directory`.
+    pub fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<CreateDirectoryResponse, RusotoError<CreateDirectoryError>> {
+        self.runtime.block_on(self.client.create_directory(input))
+    }
+
+    /// <p>Creates a new <a>Facet</a> in a schema. Facet creation is allowed only in development or applied schemas.</p>
+    ///
+    /// See `CloudDirectory::create_facet`.
+    pub fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> Result<CreateFacetResponse, RusotoError<CreateFacetError>> {
+        self.runtime.block_on(self.client.create_facet(input))
+    }
+
+    /// <p>Creates an index object. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/indexing_search.html">Indexing and search</a> for more information.</p>
+    ///
+    /// See `CloudDirectory::create_index`.
+    pub fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        self.runtime.block_on(self.client.create_index(input))
+    }
+
+    /// <p>Creates an object in a <a>Directory</a>. Additionally attaches the object to a parent, if a parent reference and <code>LinkName</code> is specified. An object is simply a collection of <a>Facet</a> attributes. You can also use this API call to create a policy object, if the facet from which you create the object is a policy facet. </p>
+    ///
+    /// See `CloudDirectory::create_object`.
+    pub fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> Result<CreateObjectResponse, RusotoError<CreateObjectError>> {
+        self.runtime.block_on(self.client.create_object(input))
+    }
+
+    /// <p><p>Creates a new schema in a development state. A schema can exist in three phases:</p> <ul> <li> <p> <i>Development:</i> This is a mutable phase of the schema. All new schemas are in the development phase. Once the schema is finalized, it can be published.</p> </li> <li> <p> <i>Published:</i> Published schemas are immutable and have a version associated with them.</p> </li> <li> <p> <i>Applied:</i> Applied schemas are mutable in a way that allows you to add new schema facets. You can also add new, nonrequired attributes to existing schema facets. You can apply only published schemas to directories. </p> </li> </ul></p>
+    ///
+    /// See `CloudDirectory::create_schema`.
+    pub fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.runtime.block_on(self.client.create_schema(input))
+    }
+
+    /// <p>Creates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::create_typed_link_facet`.
+    pub fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> Result<CreateTypedLinkFacetResponse, RusotoError<CreateTypedLinkFacetError>> {
+        self.runtime
+            .block_on(self.client.create_typed_link_facet(input))
+    }
+
+    /// <p>Deletes a directory. Only disabled directories can be deleted. A deleted directory cannot be undone. Exercise extreme caution when deleting directories.</p>
+    ///
+    /// See `CloudDirectory::delete_directory`.
+    pub fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<DeleteDirectoryResponse, RusotoError<DeleteDirectoryError>> {
+        self.runtime.block_on(self.client.delete_directory(input))
+    }
+
+    /// <p>Deletes a given <a>Facet</a>. All attributes and <a>Rule</a>s that are associated with the facet will be deleted. Only development schema facets are allowed deletion.</p>
+    ///
+    /// See `CloudDirectory::delete_facet`.
+    pub fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> Result<DeleteFacetResponse, RusotoError<DeleteFacetError>> {
+        self.runtime.block_on(self.client.delete_facet(input))
+    }
+
+    /// <p>Deletes an object and its associated attributes. Only objects with no children and no parents can be deleted. The maximum number of attributes that can be deleted during an object deletion is 30. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/limits.html">Amazon Cloud Directory Limits</a>.</p>
+    ///
+    /// See `CloudDirectory::delete_object`.
+    pub fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectResponse, RusotoError<DeleteObjectError>> {
+        self.runtime.block_on(self.client.delete_object(input))
+    }
+
+    /// <p>Deletes a given schema. Schemas in a development and published state can only be deleted. </p>
+    ///
+    /// See `CloudDirectory::delete_schema`.
+    pub fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>> {
+        self.runtime.block_on(self.client.delete_schema(input))
+    }
+
+    /// <p>Deletes a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::delete_typed_link_facet`.
+    pub fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> Result<DeleteTypedLinkFacetResponse, RusotoError<DeleteTypedLinkFacetError>> {
+        self.runtime
+            .block_on(self.client.delete_typed_link_facet(input))
+    }
+
+    /// <p>Detaches the specified object from the specified index.</p>
+    ///
+    /// See `CloudDirectory::detach_from_index`.
+    pub fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> Result<DetachFromIndexResponse, RusotoError<DetachFromIndexError>> {
+        self.runtime.block_on(self.client.detach_from_index(input))
+    }
+
+    /// <p>Detaches a given object from the parent object. The object that is to be detached from the parent is specified by the link name.</p>
+    ///
+    /// See `CloudDirectory::detach_object`.
+    pub fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> Result<DetachObjectResponse, RusotoError<DetachObjectError>> {
+        self.runtime.block_on(self.client.detach_object(input))
+    }
+
+    /// <p>Detaches a policy from an object.</p>
+    ///
+    /// See `CloudDirectory::detach_policy`.
+    pub fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<DetachPolicyResponse, RusotoError<DetachPolicyError>> {
+        self.runtime.block_on(self.client.detach_policy(input))
+    }
+
+    /// <p>Detaches a typed link from a specified source and target object. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::detach_typed_link`.
+    pub fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> Result<(), RusotoError<DetachTypedLinkError>> {
+        self.runtime.block_on(self.client.detach_typed_link(input))
+    }
+
+    /// <p>Disables the specified directory. Disabled directories cannot be read or written to. Only enabled directories can be disabled. Disabled directories may be reenabled.</p>
+    ///
+    /// See `CloudDirectory::disable_directory`.
+    pub fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> Result<DisableDirectoryResponse, RusotoError<DisableDirectoryError>> {
+        self.runtime.block_on(self.client.disable_directory(input))
+    }
+
+    /// <p>Enables the specified directory. Only disabled directories can be enabled. Once enabled, the directory can then be read and written to.</p>
+    ///
+    /// See `CloudDirectory::enable_directory`.
+    pub fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> Result<EnableDirectoryResponse, RusotoError<EnableDirectoryError>> {
+        self.runtime.block_on(self.client.enable_directory(input))
+    }
+
+    /// <p>Returns current applied schema version ARN, including the minor version in use.</p>
+    ///
+    /// See `CloudDirectory::get_applied_schema_version`.
+    pub fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> Result<GetAppliedSchemaVersionResponse, RusotoError<GetAppliedSchemaVersionError>> {
+        self.runtime
+            .block_on(self.client.get_applied_schema_version(input))
+    }
+
+    /// <p>Retrieves metadata about a directory.</p>
+    ///
+    /// See `CloudDirectory::get_directory`.
+    pub fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> Result<GetDirectoryResponse, RusotoError<GetDirectoryError>> {
+        self.runtime.block_on(self.client.get_directory(input))
+    }
+
+    /// <p>Gets details of the <a>Facet</a>, such as facet name, attributes, <a>Rule</a>s, or <code>ObjectType</code>. You can call this on all kinds of schema facets -- published, development, or applied.</p>
+    ///
+    /// See `CloudDirectory::get_facet`.
+    pub fn get_facet(
+        &self,
+        input: GetFacetRequest,
+    ) -> Result<GetFacetResponse, RusotoError<GetFacetError>> {
+        self.runtime.block_on(self.client.get_facet(input))
+    }
+
+    /// <p>Retrieves attributes that are associated with a typed link.</p>
+    ///
+    /// See `CloudDirectory::get_link_attributes`.
+    pub fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> Result<GetLinkAttributesResponse, RusotoError<GetLinkAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_link_attributes(input))
+    }
+
+    /// <p>Retrieves attributes within a facet that are associated with an object.</p>
+    ///
+    /// See `CloudDirectory::get_object_attributes`.
+    pub fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> Result<GetObjectAttributesResponse, RusotoError<GetObjectAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_object_attributes(input))
+    }
+
+    /// <p>Retrieves metadata about an object.</p>
+    ///
+    /// See `CloudDirectory::get_object_information`.
+    pub fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> Result<GetObjectInformationResponse, RusotoError<GetObjectInformationError>> {
+        self.runtime
+            .block_on(self.client.get_object_information(input))
+    }
+
+    /// <p>Retrieves a JSON representation of the schema. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
+    ///
+    /// See `CloudDirectory::get_schema_as_json`.
+    pub fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> Result<GetSchemaAsJsonResponse, RusotoError<GetSchemaAsJsonError>> {
+        self.runtime.block_on(self.client.get_schema_as_json(input))
+    }
+
+    /// <p>Returns the identity attribute order for a specific <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::get_typed_link_facet_information`.
+    pub fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> Result<GetTypedLinkFacetInformationResponse, RusotoError<GetTypedLinkFacetInformationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_typed_link_facet_information(input))
+    }
+
+    /// <p>Lists schema major versions applied to a directory. If <code>SchemaArn</code> is provided, lists the minor version.</p>
+    ///
+    /// See `CloudDirectory::list_applied_schema_arns`.
+    pub fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> Result<ListAppliedSchemaArnsResponse, RusotoError<ListAppliedSchemaArnsError>> {
+        self.runtime
+            .block_on(self.client.list_applied_schema_arns(input))
+    }
+
+    /// <p>Lists indices attached to the specified object.</p>
+    ///
+    /// See `CloudDirectory::list_attached_indices`.
+    pub fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> Result<ListAttachedIndicesResponse, RusotoError<ListAttachedIndicesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_indices(input))
+    }
+
+    /// <p>Retrieves each Amazon Resource Name (ARN) of schemas in the development state.</p>
+    ///
+    /// See `CloudDirectory::list_development_schema_arns`.
+    pub fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> Result<ListDevelopmentSchemaArnsResponse, RusotoError<ListDevelopmentSchemaArnsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_development_schema_arns(input))
+    }
+
+    /// <p>Lists directories created within an account.</p>
+    ///
+    /// See `CloudDirectory::list_directories`.
+    pub fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> Result<ListDirectoriesResponse, RusotoError<ListDirectoriesError>> {
+        self.runtime.block_on(self.client.list_directories(input))
+    }
+
+    /// <p>Retrieves attributes attached to the facet.</p>
+    ///
+    /// See `CloudDirectory::list_facet_attributes`.
+    pub fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> Result<ListFacetAttributesResponse, RusotoError<ListFacetAttributesError>> {
+        self.runtime
+            .block_on(self.client.list_facet_attributes(input))
+    }
+
+    /// <p>Retrieves the names of facets that exist in a schema.</p>
+    ///
+    /// See `CloudDirectory::list_facet_names`.
+    pub fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> Result<ListFacetNamesResponse, RusotoError<ListFacetNamesError>> {
+        self.runtime.block_on(self.client.list_facet_names(input))
+    }
+
+    /// <p>Returns a paginated list of all the incoming <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::list_incoming_typed_links`.
+    pub fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> Result<ListIncomingTypedLinksResponse, RusotoError<ListIncomingTypedLinksError>> {
+        self.runtime
+            .block_on(self.client.list_incoming_typed_links(input))
+    }
+
+    /// <p>Lists objects attached to the specified index.</p>
+    ///
+    /// See `CloudDirectory::list_index`.
+    pub fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> Result<ListIndexResponse, RusotoError<ListIndexError>> {
+        self.runtime.block_on(self.client.list_index(input))
+    }
+
+    /// <p>Lists the major version families of each managed schema. If a major version ARN is provided as SchemaArn, the minor version revisions in that family are listed instead.</p>
+    ///
+    /// See `CloudDirectory::list_managed_schema_arns`.
+    pub fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> Result<ListManagedSchemaArnsResponse, RusotoError<ListManagedSchemaArnsError>> {
+        self.runtime
+            .block_on(self.client.list_managed_schema_arns(input))
+    }
+
+    /// <p>Lists all attributes that are associated with an object. </p>
+    ///
+    /// See `CloudDirectory::list_object_attributes`.
+    pub fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> Result<ListObjectAttributesResponse, RusotoError<ListObjectAttributesError>> {
+        self.runtime
+            .block_on(self.client.list_object_attributes(input))
+    }
+
+    /// <p>Returns a paginated list of child objects that are associated with a given object.</p>
+    ///
+    /// See `CloudDirectory::list_object_children`.
+    pub fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> Result<ListObjectChildrenResponse, RusotoError<ListObjectChildrenError>> {
+        self.runtime
+            .block_on(self.client.list_object_children(input))
+    }
+
+    /// <p>Retrieves all available parent paths for any object type such as node, leaf node, policy node, and index node objects. For more information about objects, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directorystructure.html">Directory Structure</a>.</p> <p>Use this API to evaluate all parents for an object. The call returns all objects from the root of the directory up to the requested object. The API returns the number of paths based on user-defined <code>MaxResults</code>, in case there are multiple paths to the parent. The order of the paths and nodes returned is consistent among multiple API calls unless the objects are deleted or moved. Paths not leading to the directory root are ignored from the target object.</p>
+    ///
+    /// See `CloudDirectory::list_object_parent_paths`.
+    pub fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> Result<ListObjectParentPathsResponse, RusotoError<ListObjectParentPathsError>> {
+        self.runtime
+            .block_on(self.client.list_object_parent_paths(input))
+    }
+
+    /// <p>Lists parent objects that are associated with a given object in pagination fashion.</p>
+    ///
+    /// See `CloudDirectory::list_object_parents`.
+    pub fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> Result<ListObjectParentsResponse, RusotoError<ListObjectParentsError>> {
+        self.runtime
+            .block_on(self.client.list_object_parents(input))
+    }
+
+    /// <p>Returns policies attached to an object in pagination fashion.</p>
+    ///
+    /// See `CloudDirectory::list_object_policies`.
+    pub fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> Result<ListObjectPoliciesResponse, RusotoError<ListObjectPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_object_policies(input))
+    }
+
+    /// <p>Returns a paginated list of all the outgoing <a>TypedLinkSpecifier</a> information for an object. It also supports filtering by typed link facet and identity attributes. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::list_outgoing_typed_links`.
+    pub fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> Result<ListOutgoingTypedLinksResponse, RusotoError<ListOutgoingTypedLinksError>> {
+        self.runtime
+            .block_on(self.client.list_outgoing_typed_links(input))
+    }
+
+    /// <p>Returns all of the <code>ObjectIdentifiers</code> to which a given policy is attached.</p>
+    ///
+    /// See `CloudDirectory::list_policy_attachments`.
+    pub fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> Result<ListPolicyAttachmentsResponse, RusotoError<ListPolicyAttachmentsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_attachments(input))
+    }
+
+    /// <p>Lists the major version families of each published schema. If a major version ARN is provided as <code>SchemaArn</code>, the minor version revisions in that family are listed instead.</p>
+    ///
+    /// See `CloudDirectory::list_published_schema_arns`.
+    pub fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> Result<ListPublishedSchemaArnsResponse, RusotoError<ListPublishedSchemaArnsError>> {
+        self.runtime
+            .block_on(self.client.list_published_schema_arns(input))
+    }
+
+    /// <p>Returns tags for a resource. Tagging is currently supported only for directories with a limit of 50 tags per directory. All 50 tags are returned for a given directory with this API call.</p>
+    ///
+    /// See `CloudDirectory::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a paginated list of all attribute definitions for a particular <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::list_typed_link_facet_attributes`.
+    pub fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> Result<ListTypedLinkFacetAttributesResponse, RusotoError<ListTypedLinkFacetAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_typed_link_facet_attributes(input))
+    }
+
+    /// <p>Returns a paginated list of <code>TypedLink</code> facet names for a particular schema. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::list_typed_link_facet_names`.
+    pub fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> Result<ListTypedLinkFacetNamesResponse, RusotoError<ListTypedLinkFacetNamesError>> {
+        self.runtime
+            .block_on(self.client.list_typed_link_facet_names(input))
+    }
+
+    /// <p>Lists all policies from the root of the <a>Directory</a> to the object specified. If there are no policies present, an empty list is returned. If policies are present, and if some objects don't have the policies attached, it returns the <code>ObjectIdentifier</code> for such objects. If policies are present, it returns <code>ObjectIdentifier</code>, <code>policyId</code>, and <code>policyType</code>. Paths that don't lead to the root from the target object are ignored. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p>
+    ///
+    /// See `CloudDirectory::lookup_policy`.
+    pub fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> Result<LookupPolicyResponse, RusotoError<LookupPolicyError>> {
+        self.runtime.block_on(self.client.lookup_policy(input))
+    }
+
+    /// <p>Publishes a development schema with a major version and a recommended minor version.</p>
+    ///
+    /// See `CloudDirectory::publish_schema`.
+    pub fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> Result<PublishSchemaResponse, RusotoError<PublishSchemaError>> {
+        self.runtime.block_on(self.client.publish_schema(input))
+    }
+
+    /// <p>Allows a schema to be updated using JSON upload. Only available for development schemas. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_jsonformat.html#schemas_json">JSON Schema Format</a> for more information.</p>
+    ///
+    /// See `CloudDirectory::put_schema_from_json`.
+    pub fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> Result<PutSchemaFromJsonResponse, RusotoError<PutSchemaFromJsonError>> {
+        self.runtime
+            .block_on(self.client.put_schema_from_json(input))
+    }
+
+    /// <p>Removes the specified facet from the specified object.</p>
+    ///
+    /// See `CloudDirectory::remove_facet_from_object`.
+    pub fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> Result<RemoveFacetFromObjectResponse, RusotoError<RemoveFacetFromObjectError>> {
+        self.runtime
+            .block_on(self.client.remove_facet_from_object(input))
+    }
+
+    /// <p>An API operation for adding tags to a resource.</p>
+    ///
+    /// See `CloudDirectory::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>An API operation for removing tags from a resource.</p>
+    ///
+    /// See `CloudDirectory::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Does the following:</p> <ol> <li> <p>Adds new <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Updates existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> <li> <p>Deletes existing <code>Attributes</code>, <code>Rules</code>, or <code>ObjectTypes</code>.</p> </li> </ol></p>
+    ///
+    /// See `CloudDirectory::update_facet`.
+    pub fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> Result<UpdateFacetResponse, RusotoError<UpdateFacetError>> {
+        self.runtime.block_on(self.client.update_facet(input))
+    }
+
+    /// <p>Updates a given typed link’s attributes. Attributes to be updated must not contribute to the typed link’s identity, as defined by its <code>IdentityAttributeOrder</code>.</p>
+    ///
+    /// See `CloudDirectory::update_link_attributes`.
+    pub fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> Result<UpdateLinkAttributesResponse, RusotoError<UpdateLinkAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_link_attributes(input))
+    }
+
+    /// <p>Updates a given object's attributes.</p>
+    ///
+    /// See `CloudDirectory::update_object_attributes`.
+    pub fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> Result<UpdateObjectAttributesResponse, RusotoError<UpdateObjectAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_object_attributes(input))
+    }
+
+    /// <p>Updates the schema name with a new name. Only development schema names can be updated.</p>
+    ///
+    /// See `CloudDirectory::update_schema`.
+    pub fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        self.runtime.block_on(self.client.update_schema(input))
+    }
+
+    /// <p>Updates a <a>TypedLinkFacet</a>. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
+    ///
+    /// See `CloudDirectory::update_typed_link_facet`.
+    pub fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> Result<UpdateTypedLinkFacetResponse, RusotoError<UpdateTypedLinkFacetError>> {
+        self.runtime
+            .block_on(self.client.update_typed_link_facet(input))
+    }
+
+    /// <p>Upgrades a single directory in-place using the <code>PublishedSchemaArn</code> with schema updates found in <code>MinorVersion</code>. Backwards-compatible minor version upgrades are instantaneously available for readers on all objects in the directory. Note: This is a synchronous API call and upgrades only one schema on a given directory per call. To upgrade multiple directories from one schema, you would need to call this API on each directory.</p>
+    ///
+    /// See `CloudDirectory::upgrade_applied_schema`.
+    pub fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> Result<UpgradeAppliedSchemaResponse, RusotoError<UpgradeAppliedSchemaError>> {
+        self.runtime
+            .block_on(self.client.upgrade_applied_schema(input))
+    }
+
+    /// <p>Upgrades a published schema under a new minor version revision using the current contents of <code>DevelopmentSchemaArn</code>.</p>
+    ///
+    /// See `CloudDirectory::upgrade_published_schema`.
+    pub fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> Result<UpgradePublishedSchemaResponse, RusotoError<UpgradePublishedSchemaError>> {
+        self.runtime
+            .block_on(self.client.upgrade_published_schema(input))
+    }
+}
@@ -9362,9 +9362,10 @@ impl CancelUpdateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(CancelUpdateStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelUpdateStackError::TokenAlreadyExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9406,7 +9407,8 @@ impl ContinueUpdateRollbackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ContinueUpdateRollbackError::TokenAlreadyExists(parsed_error.message),
                         )
                     }
@@ -9454,19 +9456,22 @@ impl CreateChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
-                        return RusotoError::Service(CreateChangeSetError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateChangeSetError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateChangeSetError::InsufficientCapabilities(parsed_error.message),
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateChangeSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateChangeSetError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9516,24 +9521,28 @@ impl CreateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(CreateStackError::InsufficientCapabilities(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackError::InsufficientCapabilities(parsed_error.message),
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackError::TokenAlreadyExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9588,36 +9597,42 @@ impl CreateStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(CreateStackInstancesError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackInstancesError::InvalidOperation(parsed_error.message),
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackInstancesError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackInstancesError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStackInstancesError::OperationIdAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStackInstancesError::OperationInProgress(parsed_error.message),
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(CreateStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackInstancesError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(CreateStackInstancesError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackInstancesError::StaleRequest(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9670,19 +9685,22 @@ impl CreateStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CreatedButModifiedException" => {
-                        return RusotoError::Service(CreateStackSetError::CreatedButModified(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackSetError::CreatedButModified(parsed_error.message),
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackSetError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NameAlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackSetError::NameAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStackSetError::NameAlreadyExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9726,9 +9744,10 @@ impl DeleteChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidChangeSetStatus" => {
-                        return RusotoError::Service(DeleteChangeSetError::InvalidChangeSetStatus(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteChangeSetError::InvalidChangeSetStatus(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9770,9 +9789,10 @@ impl DeleteStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(DeleteStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteStackError::TokenAlreadyExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9822,31 +9842,36 @@ impl DeleteStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteStackInstancesError::InvalidOperation(parsed_error.message),
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStackInstancesError::OperationIdAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStackInstancesError::OperationInProgress(parsed_error.message),
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteStackInstancesError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteStackInstancesError::StaleRequest(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9896,14 +9921,16 @@ impl DeleteStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressException" => {
-                        return RusotoError::Service(DeleteStackSetError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteStackSetError::OperationInProgress(parsed_error.message),
+                        )
                     }
                     "StackSetNotEmptyException" => {
-                        return RusotoError::Service(DeleteStackSetError::StackSetNotEmpty(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteStackSetError::StackSetNotEmpty(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9948,14 +9975,16 @@ impl DeregisterTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DeregisterTypeError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeregisterTypeError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(DeregisterTypeError::TypeNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeregisterTypeError::TypeNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10032,9 +10061,10 @@ impl DescribeChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(DescribeChangeSetError::ChangeSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeChangeSetError::ChangeSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10148,14 +10178,16 @@ impl DescribeStackInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeStackInstanceError::StackInstanceNotFound(parsed_error.message),
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DescribeStackInstanceError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeStackInstanceError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10302,9 +10334,10 @@ impl DescribeStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DescribeStackSetError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeStackSetError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10348,12 +10381,14 @@ impl DescribeStackSetOperationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeStackSetOperationError::OperationNotFound(parsed_error.message),
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeStackSetOperationError::StackSetNotFound(parsed_error.message),
                         )
                     }
@@ -10434,14 +10469,16 @@ impl DescribeTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DescribeTypeError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTypeError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(DescribeTypeError::TypeNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTypeError::TypeNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10484,9 +10521,10 @@ impl DescribeTypeRegistrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DescribeTypeRegistrationError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTypeRegistrationError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10600,19 +10638,22 @@ impl DetectStackSetDriftError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetectStackSetDriftError::InvalidOperation(parsed_error.message),
+                        )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetectStackSetDriftError::OperationInProgress(parsed_error.message),
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetectStackSetDriftError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10696,24 +10737,28 @@ impl ExecuteChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(ExecuteChangeSetError::ChangeSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ExecuteChangeSetError::ChangeSetNotFound(parsed_error.message),
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ExecuteChangeSetError::InsufficientCapabilities(parsed_error.message),
                         )
                     }
                     "InvalidChangeSetStatus" => {
-                        return RusotoError::Service(ExecuteChangeSetError::InvalidChangeSetStatus(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ExecuteChangeSetError::InvalidChangeSetStatus(parsed_error.message),
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(ExecuteChangeSetError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ExecuteChangeSetError::TokenAlreadyExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10792,9 +10837,10 @@ impl GetTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(GetTemplateError::ChangeSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTemplateError::ChangeSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10836,9 +10882,10 @@ impl GetTemplateSummaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(GetTemplateSummaryError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTemplateSummaryError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10982,9 +11029,10 @@ impl ListStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(ListStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListStackInstancesError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11064,14 +11112,16 @@ impl ListStackSetOperationResultsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListStackSetOperationResultsError::OperationNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListStackSetOperationResultsError::StackSetNotFound(
                                 parsed_error.message,
                             ),
@@ -11122,9 +11172,10 @@ impl ListStackSetOperationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(ListStackSetOperationsError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListStackSetOperationsError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11234,9 +11285,10 @@ impl ListTypeRegistrationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypeRegistrationsError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTypeRegistrationsError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11278,9 +11330,10 @@ impl ListTypeVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypeVersionsError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTypeVersionsError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11322,9 +11375,10 @@ impl ListTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypesError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTypesError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11368,14 +11422,16 @@ impl RecordHandlerProgressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidStateTransition" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RecordHandlerProgressError::InvalidStateTransition(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ConditionalCheckFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RecordHandlerProgressError::OperationStatusCheckFailed(
                                 parsed_error.message,
                             ),
@@ -11424,9 +11480,10 @@ impl RegisterTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(RegisterTypeError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RegisterTypeError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11504,14 +11561,16 @@ impl SetTypeDefaultVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(SetTypeDefaultVersionError::CFNRegistry(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTypeDefaultVersionError::CFNRegistry(parsed_error.message),
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(SetTypeDefaultVersionError::TypeNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTypeDefaultVersionError::TypeNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11592,19 +11651,22 @@ impl StopStackSetOperationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(StopStackSetOperationError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopStackSetOperationError::InvalidOperation(parsed_error.message),
+                        )
                     }
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(StopStackSetOperationError::OperationNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopStackSetOperationError::OperationNotFound(parsed_error.message),
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(StopStackSetOperationError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopStackSetOperationError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11650,14 +11712,16 @@ impl UpdateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(UpdateStackError::InsufficientCapabilities(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackError::InsufficientCapabilities(parsed_error.message),
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(UpdateStackError::TokenAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackError::TokenAlreadyExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11710,36 +11774,42 @@ impl UpdateStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackInstancesError::InvalidOperation(parsed_error.message),
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStackInstancesError::OperationIdAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStackInstancesError::OperationInProgress(parsed_error.message),
                         )
                     }
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStackInstancesError::StackInstanceNotFound(parsed_error.message),
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackInstancesError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackInstancesError::StaleRequest(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11798,34 +11868,40 @@ impl UpdateStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(UpdateStackSetError::InvalidOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackSetError::InvalidOperation(parsed_error.message),
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(UpdateStackSetError::OperationIdAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackSetError::OperationIdAlreadyExists(parsed_error.message),
+                        )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(UpdateStackSetError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackSetError::OperationInProgress(parsed_error.message),
+                        )
                     }
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(UpdateStackSetError::StackInstanceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackSetError::StackInstanceNotFound(parsed_error.message),
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(UpdateStackSetError::StackSetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackSetError::StackSetNotFound(parsed_error.message),
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(UpdateStackSetError::StaleRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStackSetError::StaleRequest(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12280,6 +12356,7 @@ impl CloudFormationClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -12295,6 +12372,7 @@ impl CloudFormationClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationClient {
         CloudFormationClient { client, region }
     }
@@ -12308,6 +12386,9 @@ impl CloudFormation for CloudFormationClient {
         input: CancelUpdateStackInput,
     ) -> Result<(), RusotoError<CancelUpdateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("CancelUpdateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelUpdateStack");
         let mut params = params;
         CancelUpdateStackInputSerializer::serialize(&mut params, "", &input);
@@ -12328,6 +12409,9 @@ impl CloudFormation for CloudFormationClient {
         input: ContinueUpdateRollbackInput,
     ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ContinueUpdateRollback");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ContinueUpdateRollback");
         let mut params = params;
         ContinueUpdateRollbackInputSerializer::serialize(&mut params, "", &input);
@@ -12350,6 +12434,9 @@ impl CloudFormation for CloudFormationClient {
         input: CreateChangeSetInput,
     ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("CreateChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateChangeSet");
         let mut params = params;
         CreateChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -12381,6 +12468,9 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackInput,
     ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("CreateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateStack");
         let mut params = params;
         CreateStackInputSerializer::serialize(&mut params, "", &input);
@@ -12411,6 +12501,9 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackInstancesInput,
     ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("CreateStackInstances");
+        request.set_read_only(false);
+        request.set_idempotent(input.operation_id.is_some());
         let params = self.new_params("CreateStackInstances");
         let mut params = params;
         CreateStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -12444,6 +12537,9 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackSetInput,
     ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("CreateStackSet");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         let params = self.new_params("CreateStackSet");
         let mut params = params;
         CreateStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -12475,6 +12571,9 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteChangeSetInput,
     ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DeleteChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteChangeSet");
         let mut params = params;
         DeleteChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -12497,6 +12596,9 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackInput,
     ) -> Result<(), RusotoError<DeleteStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DeleteStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteStack");
         let mut params = params;
         DeleteStackInputSerializer::serialize(&mut params, "", &input);
@@ -12517,6 +12619,9 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackInstancesInput,
     ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DeleteStackInstances");
+        request.set_read_only(false);
+        request.set_idempotent(input.operation_id.is_some());
         let params = self.new_params("DeleteStackInstances");
         let mut params = params;
         DeleteStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -12550,6 +12655,9 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackSetInput,
     ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DeleteStackSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteStackSet");
         let mut params = params;
         DeleteStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -12572,6 +12680,9 @@ impl CloudFormation for CloudFormationClient {
         input: DeregisterTypeInput,
     ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DeregisterType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         let params = self.new_params("DeregisterType");
         let mut params = params;
         DeregisterTypeInputSerializer::serialize(&mut params, "", &input);
@@ -12594,6 +12705,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeAccountLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountLimits");
         let mut params = params;
         DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
@@ -12627,6 +12741,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeChangeSetInput,
     ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeChangeSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeChangeSet");
         let mut params = params;
         DescribeChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -12661,6 +12778,9 @@ impl CloudFormation for CloudFormationClient {
         RusotoError<DescribeStackDriftDetectionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackDriftDetectionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackDriftDetectionStatus");
         let mut params = params;
         DescribeStackDriftDetectionStatusInputSerializer::serialize(&mut params, "", &input);
@@ -12697,6 +12817,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackEventsInput,
     ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackEvents");
         let mut params = params;
         DescribeStackEventsInputSerializer::serialize(&mut params, "", &input);
@@ -12730,6 +12853,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackInstanceInput,
     ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackInstance");
         let mut params = params;
         DescribeStackInstanceInputSerializer::serialize(&mut params, "", &input);
@@ -12763,6 +12889,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackResourceInput,
     ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackResource");
         let mut params = params;
         DescribeStackResourceInputSerializer::serialize(&mut params, "", &input);
@@ -12797,6 +12926,9 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackResourceDrifts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackResourceDrifts");
         let mut params = params;
         DescribeStackResourceDriftsInputSerializer::serialize(&mut params, "", &input);
@@ -12830,6 +12962,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackResourcesInput,
     ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackResources");
         let mut params = params;
         DescribeStackResourcesInputSerializer::serialize(&mut params, "", &input);
@@ -12863,6 +12998,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackSetInput,
     ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackSet");
         let mut params = params;
         DescribeStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -12894,6 +13032,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackSetOperationInput,
     ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStackSetOperation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStackSetOperation");
         let mut params = params;
         DescribeStackSetOperationInputSerializer::serialize(&mut params, "", &input);
@@ -12927,6 +13068,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStacksInput,
     ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeStacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStacks");
         let mut params = params;
         DescribeStacksInputSerializer::serialize(&mut params, "", &input);
@@ -12958,6 +13102,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeTypeInput,
     ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeType");
         let mut params = params;
         DescribeTypeInputSerializer::serialize(&mut params, "", &input);
@@ -12988,6 +13135,9 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeTypeRegistrationInput,
     ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DescribeTypeRegistration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTypeRegistration");
         let mut params = params;
         DescribeTypeRegistrationInputSerializer::serialize(&mut params, "", &input);
@@ -13021,6 +13171,9 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackDriftInput,
     ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DetectStackDrift");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetectStackDrift");
         let mut params = params;
         DetectStackDriftInputSerializer::serialize(&mut params, "", &input);
@@ -13052,6 +13205,9 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackResourceDriftInput,
     ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DetectStackResourceDrift");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetectStackResourceDrift");
         let mut params = params;
         DetectStackResourceDriftInputSerializer::serialize(&mut params, "", &input);
@@ -13085,6 +13241,9 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackSetDriftInput,
     ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("DetectStackSetDrift");
+        request.set_read_only(false);
+        request.set_idempotent(input.operation_id.is_some());
         let params = self.new_params("DetectStackSetDrift");
         let mut params = params;
         DetectStackSetDriftInputSerializer::serialize(&mut params, "", &input);
@@ -13118,6 +13277,9 @@ impl CloudFormation for CloudFormationClient {
         input: EstimateTemplateCostInput,
     ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("EstimateTemplateCost");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EstimateTemplateCost");
         let mut params = params;
         EstimateTemplateCostInputSerializer::serialize(&mut params, "", &input);
@@ -13151,6 +13313,9 @@ impl CloudFormation for CloudFormationClient {
         input: ExecuteChangeSetInput,
     ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ExecuteChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ExecuteChangeSet");
         let mut params = params;
         ExecuteChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -13173,6 +13338,9 @@ impl CloudFormation for CloudFormationClient {
         input: GetStackPolicyInput,
     ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("GetStackPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetStackPolicy");
         let mut params = params;
         GetStackPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -13204,6 +13372,9 @@ impl CloudFormation for CloudFormationClient {
         input: GetTemplateInput,
     ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("GetTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTemplate");
         let mut params = params;
         GetTemplateInputSerializer::serialize(&mut params, "", &input);
@@ -13234,6 +13405,9 @@ impl CloudFormation for CloudFormationClient {
         input: GetTemplateSummaryInput,
     ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("GetTemplateSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTemplateSummary");
         let mut params = params;
         GetTemplateSummaryInputSerializer::serialize(&mut params, "", &input);
@@ -13267,6 +13441,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListChangeSetsInput,
     ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListChangeSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListChangeSets");
         let mut params = params;
         ListChangeSetsInputSerializer::serialize(&mut params, "", &input);
@@ -13298,6 +13475,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListExportsInput,
     ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListExports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListExports");
         let mut params = params;
         ListExportsInputSerializer::serialize(&mut params, "", &input);
@@ -13328,6 +13508,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListImportsInput,
     ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListImports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListImports");
         let mut params = params;
         ListImportsInputSerializer::serialize(&mut params, "", &input);
@@ -13358,6 +13541,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackInstancesInput,
     ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListStackInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListStackInstances");
         let mut params = params;
         ListStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -13391,6 +13577,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackResourcesInput,
     ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListStackResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListStackResources");
         let mut params = params;
         ListStackResourcesInputSerializer::serialize(&mut params, "", &input);
@@ -13425,6 +13614,9 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListStackSetOperationResults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListStackSetOperationResults");
         let mut params = params;
         ListStackSetOperationResultsInputSerializer::serialize(&mut params, "", &input);
@@ -13458,6 +13650,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackSetOperationsInput,
     ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListStackSetOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListStackSetOperations");
         let mut params = params;
         ListStackSetOperationsInputSerializer::serialize(&mut params, "", &input);
@@ -13491,6 +13686,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackSetsInput,
     ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListStackSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListStackSets");
         let mut params = params;
         ListStackSetsInputSerializer::serialize(&mut params, "", &input);
@@ -13522,6 +13720,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListStacksInput,
     ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListStacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListStacks");
         let mut params = params;
         ListStacksInputSerializer::serialize(&mut params, "", &input);
@@ -13552,6 +13753,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypeRegistrationsInput,
     ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListTypeRegistrations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTypeRegistrations");
         let mut params = params;
         ListTypeRegistrationsInputSerializer::serialize(&mut params, "", &input);
@@ -13585,6 +13789,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypeVersionsInput,
     ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListTypeVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTypeVersions");
         let mut params = params;
         ListTypeVersionsInputSerializer::serialize(&mut params, "", &input);
@@ -13616,6 +13823,9 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypesInput,
     ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ListTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTypes");
         let mut params = params;
         ListTypesInputSerializer::serialize(&mut params, "", &input);
@@ -13646,6 +13856,9 @@ impl CloudFormation for CloudFormationClient {
         input: RecordHandlerProgressInput,
     ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("RecordHandlerProgress");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         let params = self.new_params("RecordHandlerProgress");
         let mut params = params;
         RecordHandlerProgressInputSerializer::serialize(&mut params, "", &input);
@@ -13668,6 +13881,9 @@ impl CloudFormation for CloudFormationClient {
         input: RegisterTypeInput,
     ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("RegisterType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         let params = self.new_params("RegisterType");
         let mut params = params;
         RegisterTypeInputSerializer::serialize(&mut params, "", &input);
@@ -13698,6 +13914,9 @@ impl CloudFormation for CloudFormationClient {
         input: SetStackPolicyInput,
     ) -> Result<(), RusotoError<SetStackPolicyError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("SetStackPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetStackPolicy");
         let mut params = params;
         SetStackPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -13718,6 +13937,9 @@ impl CloudFormation for CloudFormationClient {
         input: SetTypeDefaultVersionInput,
     ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("SetTypeDefaultVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         let params = self.new_params("SetTypeDefaultVersion");
         let mut params = params;
         SetTypeDefaultVersionInputSerializer::serialize(&mut params, "", &input);
@@ -13740,6 +13962,9 @@ impl CloudFormation for CloudFormationClient {
         input: SignalResourceInput,
     ) -> Result<(), RusotoError<SignalResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("SignalResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SignalResource");
         let mut params = params;
         SignalResourceInputSerializer::serialize(&mut params, "", &input);
@@ -13760,6 +13985,9 @@ impl CloudFormation for CloudFormationClient {
         input: StopStackSetOperationInput,
     ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("StopStackSetOperation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopStackSetOperation");
         let mut params = params;
         StopStackSetOperationInputSerializer::serialize(&mut params, "", &input);
@@ -13782,6 +14010,9 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackInput,
     ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("UpdateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateStack");
         let mut params = params;
         UpdateStackInputSerializer::serialize(&mut params, "", &input);
@@ -13812,6 +14043,9 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackInstancesInput,
     ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("UpdateStackInstances");
+        request.set_read_only(false);
+        request.set_idempotent(input.operation_id.is_some());
         let params = self.new_params("UpdateStackInstances");
         let mut params = params;
         UpdateStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -13845,6 +14079,9 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackSetInput,
     ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("UpdateStackSet");
+        request.set_read_only(false);
+        request.set_idempotent(input.operation_id.is_some());
         let params = self.new_params("UpdateStackSet");
         let mut params = params;
         UpdateStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -13877,6 +14114,9 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("UpdateTerminationProtection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateTerminationProtection");
         let mut params = params;
         UpdateTerminationProtectionInputSerializer::serialize(&mut params, "", &input);
@@ -13910,6 +14150,9 @@ impl CloudFormation for CloudFormationClient {
         input: ValidateTemplateInput,
     ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation_name("ValidateTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ValidateTemplate");
         let mut params = params;
         ValidateTemplateInputSerializer::serialize(&mut params, "", &input);
@@ -13936,6 +14179,640 @@ impl CloudFormation for CloudFormationClient {
     }
 }
 
+/// A blocking client for the AWS CloudFormation API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudFormationClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudFormationBlockingClient {
+    client: CloudFormationClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudFormationBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudFormationBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudFormationBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudFormationBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudFormationBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient {
+            client: CloudFormationClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Cancels an update on the specified stack. If the call completes successfully, the stack rolls back the update and reverts to the previous stack configuration.</p> <note> <p>You can cancel only stacks that are in the UPDATE<em>IN</em>PROGRESS state.</p> </note></p>
+    ///
+    /// See `CloudFormation::cancel_update_stack`.
+    pub fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> Result<(), RusotoError<CancelUpdateStackError>> {
+        self.runtime
+            .block_on(self.client.cancel_update_stack(input))
+    }
+
+    /// <p>For a specified stack that is in the <code>UPDATE_ROLLBACK_FAILED</code> state, continues rolling it back to the <code>UPDATE_ROLLBACK_COMPLETE</code> state. Depending on the cause of the failure, you can manually <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/troubleshooting.html#troubleshooting-errors-update-rollback-failed"> fix the error</a> and continue the rollback. By continuing the rollback, you can return your stack to a working state (the <code>UPDATE_ROLLBACK_COMPLETE</code> state), and then try to update the stack again.</p> <p>A stack goes into the <code>UPDATE_ROLLBACK_FAILED</code> state when AWS CloudFormation cannot roll back all changes after a failed stack update. For example, you might have a stack that is rolling back to an old database instance that was deleted outside of AWS CloudFormation. Because AWS CloudFormation doesn't know the database was deleted, it assumes that the database instance still exists and attempts to roll back to it, causing the update rollback to fail.</p>
+    ///
+    /// See `CloudFormation::continue_update_rollback`.
+    pub fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
+        self.runtime
+            .block_on(self.client.continue_update_rollback(input))
+    }
+
+    /// <p>Creates a list of changes that will be applied to a stack so that you can review the changes before executing them. You can create a change set for a stack that doesn't exist or an existing stack. If you create a change set for a stack that doesn't exist, the change set shows all of the resources that AWS CloudFormation will create. If you create a change set for an existing stack, AWS CloudFormation compares the stack's information with the information that you submit in the change set and lists the differences. Use change sets to understand which resources AWS CloudFormation will create or change, and how it will change resources in an existing stack, before you create or update a stack.</p> <p>To create a change set for a stack that doesn't exist, for the <code>ChangeSetType</code> parameter, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code> for the <code>ChangeSetType</code> parameter. To create a change set for an import operation, specify <code>IMPORT</code> for the <code>ChangeSetType</code> parameter. After the <code>CreateChangeSet</code> call successfully completes, AWS CloudFormation starts creating the change set. To check the status of the change set or to review it, use the <a>DescribeChangeSet</a> action.</p> <p>When you are satisfied with the changes the change set will make, execute the change set by using the <a>ExecuteChangeSet</a> action. AWS CloudFormation doesn't make changes until you execute the change set.</p>
+    ///
+    /// See `CloudFormation::create_change_set`.
+    pub fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
+        self.runtime.block_on(self.client.create_change_set(input))
+    }
+
+    /// <p>Creates a stack as specified in the template. After the call completes successfully, the stack creation starts. You can check the status of the stack via the <a>DescribeStacks</a> API.</p>
+    ///
+    /// See `CloudFormation::create_stack`.
+    pub fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
+        self.runtime.block_on(self.client.create_stack(input))
+    }
+
+    /// <p>Creates stack instances for the specified accounts, within the specified Regions. A stack instance refers to a stack in a specific account and Region. You must specify at least one value for either <code>Accounts</code> or <code>DeploymentTargets</code>, and you must specify at least one value for <code>Regions</code>.</p>
+    ///
+    /// See `CloudFormation::create_stack_instances`.
+    pub fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.create_stack_instances(input))
+    }
+
+    /// <p>Creates a stack set.</p>
+    ///
+    /// See `CloudFormation::create_stack_set`.
+    pub fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
+        self.runtime.block_on(self.client.create_stack_set(input))
+    }
+
+    /// <p>Deletes the specified change set. Deleting change sets ensures that no one executes the wrong change set.</p> <p>If the call successfully completes, AWS CloudFormation successfully deleted the change set.</p>
+    ///
+    /// See `CloudFormation::delete_change_set`.
+    pub fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
+        self.runtime.block_on(self.client.delete_change_set(input))
+    }
+
+    /// <p>Deletes a specified stack. Once the call completes successfully, stack deletion starts. Deleted stacks do not show up in the <a>DescribeStacks</a> API if the deletion has been completed successfully.</p>
+    ///
+    /// See `CloudFormation::delete_stack`.
+    pub fn delete_stack(
+        &self,
+        input: DeleteStackInput,
+    ) -> Result<(), RusotoError<DeleteStackError>> {
+        self.runtime.block_on(self.client.delete_stack(input))
+    }
+
+    /// <p>Deletes stack instances for the specified accounts, in the specified Regions. </p>
+    ///
+    /// See `CloudFormation::delete_stack_instances`.
+    pub fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.delete_stack_instances(input))
+    }
+
+    /// <p>Deletes a stack set. Before you can delete a stack set, all of its member stack instances must be deleted. For more information about how to do this, see <a>DeleteStackInstances</a>. </p>
+    ///
+    /// See `CloudFormation::delete_stack_set`.
+    pub fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
+        self.runtime.block_on(self.client.delete_stack_set(input))
+    }
+
+    /// <p>Removes a type or type version from active use in the CloudFormation registry. If a type or type version is deregistered, it cannot be used in CloudFormation operations.</p> <p>To deregister a type, you must individually deregister all registered versions of that type. If a type has only a single registered version, deregistering that version results in the type itself being deregistered. </p> <p>You cannot deregister the default version of a type, unless it is the only registered version of that type, in which case the type itself is deregistered as well. </p>
+    ///
+    /// See `CloudFormation::deregister_type`.
+    pub fn deregister_type(
+        &self,
+        input: DeregisterTypeInput,
+    ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
+        self.runtime.block_on(self.client.deregister_type(input))
+    }
+
+    /// <p>Retrieves your account's AWS CloudFormation limits, such as the maximum number of stacks that you can create in your account. For more information about account limits, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/cloudformation-limits.html">AWS CloudFormation Limits</a> in the <i>AWS CloudFormation User Guide</i>.</p>
+    ///
+    /// See `CloudFormation::describe_account_limits`.
+    pub fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_limits(input))
+    }
+
+    /// <p>Returns the inputs for the change set and a list of changes that AWS CloudFormation will make if you execute the change set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-changesets.html">Updating Stacks Using Change Sets</a> in the AWS CloudFormation User Guide.</p>
+    ///
+    /// See `CloudFormation::describe_change_set`.
+    pub fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
+        self.runtime
+            .block_on(self.client.describe_change_set(input))
+    }
+
+    /// <p>Returns information about a stack drift detection operation. A stack drift detection operation detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted. For more information on stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <a>DetectStackDrift</a> to initiate a stack drift detection operation. <code>DetectStackDrift</code> returns a <code>StackDriftDetectionId</code> you can use to monitor the progress of the operation using <code>DescribeStackDriftDetectionStatus</code>. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p>
+    ///
+    /// See `CloudFormation::describe_stack_drift_detection_status`.
+    pub fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> Result<
+        DescribeStackDriftDetectionStatusOutput,
+        RusotoError<DescribeStackDriftDetectionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_stack_drift_detection_status(input))
+    }
+
+    /// <p><p>Returns all stack related events for a specified stack in reverse chronological order. For more information about a stack&#39;s event history, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/concept-stack.html">Stacks</a> in the AWS CloudFormation User Guide.</p> <note> <p>You can list events for stacks that have failed to create or have been deleted by specifying the unique stack identifier (stack ID).</p> </note></p>
+    ///
+    /// See `CloudFormation::describe_stack_events`.
+    pub fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_events(input))
+    }
+
+    /// <p>Returns the stack instance that's associated with the specified stack set, AWS account, and Region.</p> <p>For a list of stack instances that are associated with a specific stack set, use <a>ListStackInstances</a>.</p>
+    ///
+    /// See `CloudFormation::describe_stack_instance`.
+    pub fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_instance(input))
+    }
+
+    /// <p>Returns a description of the specified resource in the specified stack.</p> <p>For deleted stacks, DescribeStackResource returns resource information for up to 90 days after the stack has been deleted.</p>
+    ///
+    /// See `CloudFormation::describe_stack_resource`.
+    pub fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_resource(input))
+    }
+
+    /// <p>Returns drift information for the resources that have been checked for drift in the specified stack. This includes actual and expected configuration values for resources where AWS CloudFormation detects configuration drift.</p> <p>For a given stack, there will be one <code>StackResourceDrift</code> for each stack resource that has been checked for drift. Resources that have not yet been checked for drift are not included. Resources that do not currently support drift detection are not checked, and so not included. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all supported resources for a given stack.</p>
+    ///
+    /// See `CloudFormation::describe_stack_resource_drifts`.
+    pub fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_stack_resource_drifts(input))
+    }
+
+    /// <p><p>Returns AWS resource descriptions for running and deleted stacks. If <code>StackName</code> is specified, all the associated resources that are part of the stack are returned. If <code>PhysicalResourceId</code> is specified, the associated resources of the stack that the resource belongs to are returned.</p> <note> <p>Only the first 100 resources will be returned. If your stack has more resources than this, you should use <code>ListStackResources</code> instead.</p> </note> <p>For deleted stacks, <code>DescribeStackResources</code> returns resource information for up to 90 days after the stack has been deleted.</p> <p>You must specify either <code>StackName</code> or <code>PhysicalResourceId</code>, but not both. In addition, you can specify <code>LogicalResourceId</code> to filter the returned result. For more information about resources, the <code>LogicalResourceId</code> and <code>PhysicalResourceId</code>, go to the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/">AWS CloudFormation User Guide</a>.</p> <note> <p>A <code>ValidationError</code> is returned if you specify both <code>StackName</code> and <code>PhysicalResourceId</code> in the same request.</p> </note></p>
+    ///
+    /// See `CloudFormation::describe_stack_resources`.
+    pub fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_resources(input))
+    }
+
+    /// <p>Returns the description of the specified stack set. </p>
+    ///
+    /// See `CloudFormation::describe_stack_set`.
+    pub fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
+        self.runtime.block_on(self.client.describe_stack_set(input))
+    }
+
+    /// <p>Returns the description of the specified stack set operation. </p>
+    ///
+    /// See `CloudFormation::describe_stack_set_operation`.
+    pub fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_set_operation(input))
+    }
+
+    /// <p><p>Returns the description for the specified stack; if no stack name was specified, then it returns the description for all the stacks created.</p> <note> <p>If the stack does not exist, an <code>AmazonCloudFormationException</code> is returned.</p> </note></p>
+    ///
+    /// See `CloudFormation::describe_stacks`.
+    pub fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
+        self.runtime.block_on(self.client.describe_stacks(input))
+    }
+
+    /// <p>Returns detailed information about a type that has been registered.</p> <p>If you specify a <code>VersionId</code>, <code>DescribeType</code> returns information about that specific type version. Otherwise, it returns information about the default type version.</p>
+    ///
+    /// See `CloudFormation::describe_type`.
+    pub fn describe_type(
+        &self,
+        input: DescribeTypeInput,
+    ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
+        self.runtime.block_on(self.client.describe_type(input))
+    }
+
+    /// <p>Returns information about a type's registration, including its current status and type and version identifiers.</p> <p>When you initiate a registration request using <code> <a>RegisterType</a> </code>, you can then use <code> <a>DescribeTypeRegistration</a> </code> to monitor the progress of that registration request.</p> <p>Once the registration request has completed, use <code> <a>DescribeType</a> </code> to return detailed informaiton about a type.</p>
+    ///
+    /// See `CloudFormation::describe_type_registration`.
+    pub fn describe_type_registration(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
+        self.runtime
+            .block_on(self.client.describe_type_registration(input))
+    }
+
+    /// <p>Detects whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For each resource in the stack that supports drift detection, AWS CloudFormation compares the actual configuration of the resource with its expected template configuration. Only resource properties explicitly defined in the stack template are checked for drift. A stack is considered to have drifted if one or more of its resources differ from their expected template configurations. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackDrift</code> to detect drift on all supported resources for a given stack, or <a>DetectStackResourceDrift</a> to detect drift on individual resources.</p> <p>For a list of stack resources that currently support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p> <code>DetectStackDrift</code> can take up to several minutes, depending on the number of resources contained within the stack. Use <a>DescribeStackDriftDetectionStatus</a> to monitor the progress of a detect stack drift operation. Once the drift detection operation has completed, use <a>DescribeStackResourceDrifts</a> to return drift information about the stack and its resources.</p> <p>When detecting drift on a stack, AWS CloudFormation does not detect drift on any nested stacks belonging to that stack. Perform <code>DetectStackDrift</code> directly on the nested stack itself.</p>
+    ///
+    /// See `CloudFormation::detect_stack_drift`.
+    pub fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
+        self.runtime.block_on(self.client.detect_stack_drift(input))
+    }
+
+    /// <p>Returns information about whether a resource's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. This information includes actual and expected property values for resources in which AWS CloudFormation detects drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information about stack and resource drift, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Use <code>DetectStackResourceDrift</code> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p>
+    ///
+    /// See `CloudFormation::detect_stack_resource_drift`.
+    pub fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
+        self.runtime
+            .block_on(self.client.detect_stack_resource_drift(input))
+    }
+
+    /// <p>Detect drift on a stack set. When CloudFormation performs drift detection on a stack set, it performs drift detection on the stack associated with each stack instance in the stack set. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-drift.html">How CloudFormation Performs Drift Detection on a Stack Set</a>.</p> <p> <code>DetectStackSetDrift</code> returns the <code>OperationId</code> of the stack set drift detection operation. Use this operation id with <code> <a>DescribeStackSetOperation</a> </code> to monitor the progress of the drift detection operation. The drift detection operation may take some time, depending on the number of stack instances included in the stack set, as well as the number of resources included in each stack.</p> <p>Once the operation has completed, use the following actions to return drift information:</p> <ul> <li> <p>Use <code> <a>DescribeStackSet</a> </code> to return detailed informaiton about the stack set, including detailed information about the last <i>completed</i> drift operation performed on the stack set. (Information about drift operations that are in progress is not included.)</p> </li> <li> <p>Use <code> <a>ListStackInstances</a> </code> to return a list of stack instances belonging to the stack set, including the drift status and last drift time checked of each instance.</p> </li> <li> <p>Use <code> <a>DescribeStackInstance</a> </code> to return detailed information about a specific stack instance, including its drift status and last drift time checked.</p> </li> </ul> <p>For more information on performing a drift detection operation on a stack set, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-drift.html">Detecting Unmanaged Changes in Stack Sets</a>. </p> <p>You can only run a single drift detection operation on a given stack set at one time. </p> <p>To stop a drift detection stack set operation, use <code> <a>StopStackSetOperation</a> </code>.</p>
+    ///
+    /// See `CloudFormation::detect_stack_set_drift`.
+    pub fn detect_stack_set_drift(
+        &self,
+        input: DetectStackSetDriftInput,
+    ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
+        self.runtime
+            .block_on(self.client.detect_stack_set_drift(input))
+    }
+
+    /// <p>Returns the estimated monthly cost of a template. The return value is an AWS Simple Monthly Calculator URL with a query string that describes the resources required to run the template.</p>
+    ///
+    /// See `CloudFormation::estimate_template_cost`.
+    pub fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
+        self.runtime
+            .block_on(self.client.estimate_template_cost(input))
+    }
+
+    /// <p>Updates a stack using the input information that was provided when the specified change set was created. After the call successfully completes, AWS CloudFormation starts updating the stack. Use the <a>DescribeStacks</a> action to view the status of the update.</p> <p>When you execute a change set, AWS CloudFormation deletes all other change sets associated with the stack because they aren't valid for the updated stack.</p> <p>If a stack policy is associated with the stack, AWS CloudFormation enforces the policy during the update. You can't specify a temporary stack policy that overrides the current policy.</p>
+    ///
+    /// See `CloudFormation::execute_change_set`.
+    pub fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
+        self.runtime.block_on(self.client.execute_change_set(input))
+    }
+
+    /// <p>Returns the stack policy for a specified stack. If a stack doesn't have a policy, a null value is returned.</p>
+    ///
+    /// See `CloudFormation::get_stack_policy`.
+    pub fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
+        self.runtime.block_on(self.client.get_stack_policy(input))
+    }
+
+    /// <p><p>Returns the template body for a specified stack. You can get the template for running or deleted stacks.</p> <p>For deleted stacks, GetTemplate returns the template for up to 90 days after the stack has been deleted.</p> <note> <p> If the template does not exist, a <code>ValidationError</code> is returned. </p> </note></p>
+    ///
+    /// See `CloudFormation::get_template`.
+    pub fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
+        self.runtime.block_on(self.client.get_template(input))
+    }
+
+    /// <p>Returns information about a new or existing template. The <code>GetTemplateSummary</code> action is useful for viewing parameter information, such as default parameter values and parameter types, before you create or update a stack or stack set.</p> <p>You can use the <code>GetTemplateSummary</code> action when you submit a template, or you can get template information for a stack set, or a running or deleted stack.</p> <p>For deleted stacks, <code>GetTemplateSummary</code> returns the template information for up to 90 days after the stack has been deleted. If the template does not exist, a <code>ValidationError</code> is returned.</p>
+    ///
+    /// See `CloudFormation::get_template_summary`.
+    pub fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
+        self.runtime
+            .block_on(self.client.get_template_summary(input))
+    }
+
+    /// <p>Returns the ID and status of each active change set for a stack. For example, AWS CloudFormation lists change sets that are in the <code>CREATE_IN_PROGRESS</code> or <code>CREATE_PENDING</code> state.</p>
+    ///
+    /// See `CloudFormation::list_change_sets`.
+    pub fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
+        self.runtime.block_on(self.client.list_change_sets(input))
+    }
+
+    /// <p>Lists all exported output values in the account and Region in which you call this action. Use this action to see the exported output values that you can import into other stacks. To import values, use the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-exports.html"> AWS CloudFormation Export Stack Output Values</a>.</p>
+    ///
+    /// See `CloudFormation::list_exports`.
+    pub fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
+        self.runtime.block_on(self.client.list_exports(input))
+    }
+
+    /// <p>Lists all stacks that are importing an exported output value. To modify or remove an exported output value, first use this action to see which stacks are using it. To see the exported output values in your account, see <a>ListExports</a>. </p> <p>For more information about importing an exported output value, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/intrinsic-function-reference-importvalue.html"> <code>Fn::ImportValue</code> </a> function. </p>
+    ///
+    /// See `CloudFormation::list_imports`.
+    pub fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
+        self.runtime.block_on(self.client.list_imports(input))
+    }
+
+    /// <p>Returns summary information about stack instances that are associated with the specified stack set. You can filter for stack instances that are associated with a specific AWS account name or Region, or that have a specific status.</p>
+    ///
+    /// See `CloudFormation::list_stack_instances`.
+    pub fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_stack_instances(input))
+    }
+
+    /// <p>Returns descriptions of all resources of the specified stack.</p> <p>For deleted stacks, ListStackResources returns resource information for up to 90 days after the stack has been deleted.</p>
+    ///
+    /// See `CloudFormation::list_stack_resources`.
+    pub fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_stack_resources(input))
+    }
+
+    /// <p>Returns summary information about the results of a stack set operation. </p>
+    ///
+    /// See `CloudFormation::list_stack_set_operation_results`.
+    pub fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_stack_set_operation_results(input))
+    }
+
+    /// <p>Returns summary information about operations performed on a stack set. </p>
+    ///
+    /// See `CloudFormation::list_stack_set_operations`.
+    pub fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
+        self.runtime
+            .block_on(self.client.list_stack_set_operations(input))
+    }
+
+    /// <p>Returns summary information about stack sets that are associated with the user.</p>
+    ///
+    /// See `CloudFormation::list_stack_sets`.
+    pub fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
+        self.runtime.block_on(self.client.list_stack_sets(input))
+    }
+
+    /// <p>Returns the summary information for stacks whose status matches the specified StackStatusFilter. Summary information for stacks that have been deleted is kept for 90 days after the stack is deleted. If no StackStatusFilter is specified, summary information for all stacks is returned (including existing stacks and stacks that have been deleted).</p>
+    ///
+    /// See `CloudFormation::list_stacks`.
+    pub fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
+        self.runtime.block_on(self.client.list_stacks(input))
+    }
+
+    /// <p>Returns a list of registration tokens for the specified type(s).</p>
+    ///
+    /// See `CloudFormation::list_type_registrations`.
+    pub fn list_type_registrations(
+        &self,
+        input: ListTypeRegistrationsInput,
+    ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
+        self.runtime
+            .block_on(self.client.list_type_registrations(input))
+    }
+
+    /// <p>Returns summary information about the versions of a type.</p>
+    ///
+    /// See `CloudFormation::list_type_versions`.
+    pub fn list_type_versions(
+        &self,
+        input: ListTypeVersionsInput,
+    ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
+        self.runtime.block_on(self.client.list_type_versions(input))
+    }
+
+    /// <p>Returns summary information about types that have been registered with CloudFormation.</p>
+    ///
+    /// See `CloudFormation::list_types`.
+    pub fn list_types(
+        &self,
+        input: ListTypesInput,
+    ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
+        self.runtime.block_on(self.client.list_types(input))
+    }
+
+    /// <p>Reports progress of a resource handler to CloudFormation.</p> <p>Reserved for use by the <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/what-is-cloudformation-cli.html">CloudFormation CLI</a>. Do not use this API in your code.</p>
+    ///
+    /// See `CloudFormation::record_handler_progress`.
+    pub fn record_handler_progress(
+        &self,
+        input: RecordHandlerProgressInput,
+    ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
+        self.runtime
+            .block_on(self.client.record_handler_progress(input))
+    }
+
+    /// <p>Registers a type with the CloudFormation service. Registering a type makes it available for use in CloudFormation templates in your AWS account, and includes:</p> <ul> <li> <p>Validating the resource schema</p> </li> <li> <p>Determining which handlers have been specified for the resource</p> </li> <li> <p>Making the resource type available for use in your account</p> </li> </ul> <p>For more information on how to develop types and ready them for registeration, see <a href="https://docs.aws.amazon.com/cloudformation-cli/latest/userguide/resource-types.html">Creating Resource Providers</a> in the <i>CloudFormation CLI User Guide</i>.</p> <p>You can have a maximum of 50 resource type versions registered at a time. This maximum is per account and per region. Use <a href="AWSCloudFormation/latest/APIReference/API_DeregisterType.html">DeregisterType</a> to deregister specific resource type versions if necessary.</p> <p>Once you have initiated a registration request using <code> <a>RegisterType</a> </code>, you can use <code> <a>DescribeTypeRegistration</a> </code> to monitor the progress of the registration request.</p>
+    ///
+    /// See `CloudFormation::register_type`.
+    pub fn register_type(
+        &self,
+        input: RegisterTypeInput,
+    ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
+        self.runtime.block_on(self.client.register_type(input))
+    }
+
+    /// <p>Sets a stack policy for a specified stack.</p>
+    ///
+    /// See `CloudFormation::set_stack_policy`.
+    pub fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> Result<(), RusotoError<SetStackPolicyError>> {
+        self.runtime.block_on(self.client.set_stack_policy(input))
+    }
+
+    /// <p>Specify the default version of a type. The default version of a type will be used in CloudFormation operations.</p>
+    ///
+    /// See `CloudFormation::set_type_default_version`.
+    pub fn set_type_default_version(
+        &self,
+        input: SetTypeDefaultVersionInput,
+    ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
+        self.runtime
+            .block_on(self.client.set_type_default_version(input))
+    }
+
+    /// <p>Sends a signal to the specified resource with a success or failure status. You can use the SignalResource API in conjunction with a creation policy or update policy. AWS CloudFormation doesn't proceed with a stack creation or update until resources receive the required number of signals or the timeout period is exceeded. The SignalResource API is useful in cases where you want to send signals from anywhere other than an Amazon EC2 instance.</p>
+    ///
+    /// See `CloudFormation::signal_resource`.
+    pub fn signal_resource(
+        &self,
+        input: SignalResourceInput,
+    ) -> Result<(), RusotoError<SignalResourceError>> {
+        self.runtime.block_on(self.client.signal_resource(input))
+    }
+
+    /// <p>Stops an in-progress operation on a stack set and its associated stack instances. </p>
+    ///
+    /// See `CloudFormation::stop_stack_set_operation`.
+    pub fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
+        self.runtime
+            .block_on(self.client.stop_stack_set_operation(input))
+    }
+
+    /// <p>Updates a stack as specified in the template. After the call completes successfully, the stack update starts. You can check the status of the stack via the <a>DescribeStacks</a> action.</p> <p>To get a copy of the template for an existing stack, you can use the <a>GetTemplate</a> action.</p> <p>For more information about creating an update template, updating a stack, and monitoring the progress of the update, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks.html">Updating a Stack</a>.</p>
+    ///
+    /// See `CloudFormation::update_stack`.
+    pub fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
+        self.runtime.block_on(self.client.update_stack(input))
+    }
+
+    /// <p>Updates the parameter values for stack instances for the specified accounts, within the specified Regions. A stack instance refers to a stack in a specific account and Region. </p> <p>You can only update stack instances in Regions and accounts where they already exist; to create additional stack instances, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_CreateStackInstances.html">CreateStackInstances</a>. </p> <p>During stack set updates, any parameters overridden for a stack instance are not updated, but retain their overridden value.</p> <p>You can only update the parameter <i>values</i> that are specified in the stack set; to add or delete a parameter itself, use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update the stack set template. If you add a parameter to a template, before you can override the parameter value specified in the stack set you must first use <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_UpdateStackSet.html">UpdateStackSet</a> to update all stack instances with the updated template and parameter value specified in the stack set. Once a stack instance has been updated with the new parameter, you can then override the parameter value using <code>UpdateStackInstances</code>.</p>
+    ///
+    /// See `CloudFormation::update_stack_instances`.
+    pub fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.update_stack_instances(input))
+    }
+
+    /// <p>Updates the stack set, and associated stack instances in the specified accounts and Regions.</p> <p>Even if the stack set operation created by updating the stack set fails (completely or partially, below or above a specified failure tolerance), the stack set is updated with your changes. Subsequent <a>CreateStackInstances</a> calls on the specified stack set use the updated stack set.</p>
+    ///
+    /// See `CloudFormation::update_stack_set`.
+    pub fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
+        self.runtime.block_on(self.client.update_stack_set(input))
+    }
+
+    /// <p>Updates termination protection for the specified stack. If a user attempts to delete a stack with termination protection enabled, the operation fails and the stack remains unchanged. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p> For <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack.</p>
+    ///
+    /// See `CloudFormation::update_termination_protection`.
+    pub fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_termination_protection(input))
+    }
+
+    /// <p>Validates a specified template. AWS CloudFormation first checks if the template is valid JSON. If it isn't, AWS CloudFormation checks if the template is valid YAML. If both these checks fail, AWS CloudFormation returns a template validation error.</p>
+    ///
+    /// See `CloudFormation::validate_template`.
+    pub fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
+        self.runtime.block_on(self.client.validate_template(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -8190,7 +8190,7 @@ impl CreateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(parsed_error.message)),_ => {}
+                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::service(&res, CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, CreateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"MissingBody" => return RusotoError::service(&res, CreateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::service(&res, CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8319,7 +8319,7 @@ impl CreateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, CreateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::service(&res, CreateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::service(&res, CreateDistributionError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service(&res, CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, CreateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, CreateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::service(&res, CreateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::service(&res, CreateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::service(&res, CreateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::service(&res, CreateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::service(&res, CreateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::service(&res, CreateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::service(&res, CreateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::service(&res, CreateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::service(&res, CreateDistributionError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::service(&res, CreateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::service(&res, CreateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::service(&res, CreateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::service(&res, CreateDistributionError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::service(&res, CreateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::service(&res, CreateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::service(&res, CreateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::service(&res, CreateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::service(&res, CreateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::service(&res, CreateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::service(&res, CreateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::service(&res, CreateDistributionError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(&res, CreateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::service(&res, CreateDistributionError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::service(&res, CreateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::service(&res, CreateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::service(&res, CreateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::service(&res, CreateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::service(&res, CreateDistributionError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service(&res, CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service(&res, CreateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::service(&res, CreateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::service(&res, CreateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::service(&res, CreateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::service(&res, CreateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::service(&res, CreateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::service(&res, CreateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::service(&res, CreateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::service(&res, CreateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8491,7 +8491,7 @@ impl CreateDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTTLOrder(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTagging(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionWithTagsError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, CreateDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::service(&res, CreateDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::service(&res, CreateDistributionWithTagsError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service(&res, CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, CreateDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidTTLOrder(parsed_error.message)),"InvalidTagging" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidTagging(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::service(&res, CreateDistributionWithTagsError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::service(&res, CreateDistributionWithTagsError::MissingBody(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(&res, CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::service(&res, CreateDistributionWithTagsError::NoSuchOrigin(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::service(&res, CreateDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::service(&res, CreateDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8590,7 +8590,7 @@ impl CreateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::service(&res, CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8652,7 +8652,7 @@ impl CreateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::service(&res, CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8711,37 +8711,44 @@ impl CreateInvalidationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(CreateInvalidationError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInvalidationError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "BatchTooLarge" => {
-                        return RusotoError::Service(CreateInvalidationError::BatchTooLarge(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInvalidationError::BatchTooLarge(parsed_error.message),
+                        )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateInvalidationError::InconsistentQuantities(parsed_error.message),
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateInvalidationError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInvalidationError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(CreateInvalidationError::MissingBody(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInvalidationError::MissingBody(parsed_error.message),
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(CreateInvalidationError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInvalidationError::NoSuchDistribution(parsed_error.message),
+                        )
                     }
                     "TooManyInvalidationsInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateInvalidationError::TooManyInvalidationsInProgress(
                                 parsed_error.message,
                             ),
@@ -8799,19 +8806,22 @@ impl CreatePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreatePublicKeyError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePublicKeyError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "PublicKeyAlreadyExists" => {
-                        return RusotoError::Service(CreatePublicKeyError::PublicKeyAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePublicKeyError::PublicKeyAlreadyExists(parsed_error.message),
+                        )
                     }
                     "TooManyPublicKeys" => {
-                        return RusotoError::Service(CreatePublicKeyError::TooManyPublicKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePublicKeyError::TooManyPublicKeys(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8879,76 +8889,88 @@ impl CreateStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::AccessDenied(parsed_error.message),
                         )
                     }
                     "CNAMEAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::CNAMEAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::InconsistentQuantities(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::InvalidArgument(parsed_error.message),
                         )
                     }
                     "InvalidOrigin" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::InvalidOrigin(parsed_error.message),
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::InvalidOriginAccessIdentity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(CreateStreamingDistributionError::MissingBody(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStreamingDistributionError::MissingBody(parsed_error.message),
+                        )
                     }
                     "StreamingDistributionAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::StreamingDistributionAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyStreamingDistributions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::TooManyStreamingDistributions(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTrustedSigners" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::TooManyTrustedSigners(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStreamingDistributionError::TrustedSignerDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -9046,7 +9068,7 @@ impl CreateStreamingDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidTagging(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::MissingBody(parsed_error.message)),"StreamingDistributionAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(parsed_error.message)),"TooManyStreamingDistributionCNAMEs" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(parsed_error.message)),"TooManyStreamingDistributions" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidOrigin" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidTagging" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::InvalidTagging(parsed_error.message)),"MissingBody" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::MissingBody(parsed_error.message)),"StreamingDistributionAlreadyExists" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(parsed_error.message)),"TooManyStreamingDistributionCNAMEs" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(parsed_error.message)),"TooManyStreamingDistributions" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::service(&res, CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -9133,7 +9155,7 @@ impl DeleteCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, DeleteCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::service(&res, DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::service(&res, DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(&res, DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::service(&res, DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -9195,29 +9217,34 @@ impl DeleteDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeleteDistributionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDistributionError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "DistributionNotDisabled" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDistributionError::DistributionNotDisabled(parsed_error.message),
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDistributionError::InvalidIfMatchVersion(parsed_error.message),
                         )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(DeleteDistributionError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDistributionError::NoSuchDistribution(parsed_error.message),
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeleteDistributionError::PreconditionFailed(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDistributionError::PreconditionFailed(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9273,35 +9300,40 @@ impl DeleteFieldLevelEncryptionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionConfigError::AccessDenied(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "FieldLevelEncryptionConfigInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionConfigError::PreconditionFailed(
                                 parsed_error.message,
                             ),
@@ -9371,31 +9403,36 @@ impl DeleteFieldLevelEncryptionProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionProfileError::AccessDenied(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "FieldLevelEncryptionProfileInUse" => return RusotoError::Service(
+                    "FieldLevelEncryptionProfileInUse" => return RusotoError::service(
+                        &res,
                         DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(
+                    "NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(
+                        &res,
                         DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
                             parsed_error.message,
                         ),
                     ),
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteFieldLevelEncryptionProfileError::PreconditionFailed(
                                 parsed_error.message,
                             ),
@@ -9463,29 +9500,34 @@ impl DeletePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeletePublicKeyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePublicKeyError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(DeletePublicKeyError::InvalidIfMatchVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePublicKeyError::InvalidIfMatchVersion(parsed_error.message),
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(DeletePublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePublicKeyError::NoSuchPublicKey(parsed_error.message),
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeletePublicKeyError::PreconditionFailed(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePublicKeyError::PreconditionFailed(parsed_error.message),
+                        )
                     }
                     "PublicKeyInUse" => {
-                        return RusotoError::Service(DeletePublicKeyError::PublicKeyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePublicKeyError::PublicKeyInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9541,33 +9583,38 @@ impl DeleteStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStreamingDistributionError::AccessDenied(parsed_error.message),
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStreamingDistributionError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStreamingDistributionError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStreamingDistributionError::PreconditionFailed(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StreamingDistributionNotDisabled" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteStreamingDistributionError::StreamingDistributionNotDisabled(
                                 parsed_error.message,
                             ),
@@ -9628,7 +9675,7 @@ impl GetCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, GetCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(&res, GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -9676,7 +9723,7 @@ impl GetCloudFrontOriginAccessIdentityConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(&res, GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -9723,14 +9770,16 @@ impl GetDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetDistributionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetDistributionError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(GetDistributionError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetDistributionError::NoSuchDistribution(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9775,12 +9824,14 @@ impl GetDistributionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetDistributionConfigError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetDistributionConfigError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetDistributionConfigError::NoSuchDistribution(parsed_error.message),
                         )
                     }
@@ -9827,12 +9878,14 @@ impl GetFieldLevelEncryptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetFieldLevelEncryptionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetFieldLevelEncryptionError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(
                                 parsed_error.message,
                             ),
@@ -9885,12 +9938,14 @@ impl GetFieldLevelEncryptionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message),
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
                                 parsed_error.message,
                             ),
@@ -9943,12 +9998,14 @@ impl GetFieldLevelEncryptionProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message),
                         )
                     }
                     "NoSuchFieldLevelEncryptionProfile" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
                                 parsed_error.message,
                             ),
@@ -10000,7 +10057,7 @@ impl GetFieldLevelEncryptionProfileConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::AccessDenied(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, GetFieldLevelEncryptionProfileConfigError::AccessDenied(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(&res, GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -10049,19 +10106,22 @@ impl GetInvalidationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetInvalidationError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetInvalidationError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(GetInvalidationError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetInvalidationError::NoSuchDistribution(parsed_error.message),
+                        )
                     }
                     "NoSuchInvalidation" => {
-                        return RusotoError::Service(GetInvalidationError::NoSuchInvalidation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetInvalidationError::NoSuchInvalidation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10107,14 +10167,16 @@ impl GetPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetPublicKeyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPublicKeyError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(GetPublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPublicKeyError::NoSuchPublicKey(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10159,14 +10221,16 @@ impl GetPublicKeyConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetPublicKeyConfigError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPublicKeyConfigError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(GetPublicKeyConfigError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPublicKeyConfigError::NoSuchPublicKey(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10211,12 +10275,14 @@ impl GetStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetStreamingDistributionError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetStreamingDistributionError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetStreamingDistributionError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
@@ -10269,12 +10335,14 @@ impl GetStreamingDistributionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetStreamingDistributionConfigError::AccessDenied(parsed_error.message),
                         )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetStreamingDistributionConfigError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
@@ -10325,7 +10393,8 @@ impl ListCloudFrontOriginAccessIdentitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(
                                 parsed_error.message,
                             ),
@@ -10373,9 +10442,10 @@ impl ListDistributionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListDistributionsError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListDistributionsError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10421,12 +10491,14 @@ impl ListDistributionsByWebACLIdError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListDistributionsByWebACLIdError::InvalidArgument(parsed_error.message),
                         )
                     }
                     "InvalidWebACLId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListDistributionsByWebACLIdError::InvalidWebACLId(parsed_error.message),
                         )
                     }
@@ -10473,7 +10545,8 @@ impl ListFieldLevelEncryptionConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListFieldLevelEncryptionConfigsError::InvalidArgument(
                                 parsed_error.message,
                             ),
@@ -10523,7 +10596,8 @@ impl ListFieldLevelEncryptionProfilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListFieldLevelEncryptionProfilesError::InvalidArgument(
                                 parsed_error.message,
                             ),
@@ -10575,19 +10649,22 @@ impl ListInvalidationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListInvalidationsError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListInvalidationsError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListInvalidationsError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListInvalidationsError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(ListInvalidationsError::NoSuchDistribution(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListInvalidationsError::NoSuchDistribution(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10631,9 +10708,10 @@ impl ListPublicKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListPublicKeysError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPublicKeysError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10677,7 +10755,8 @@ impl ListStreamingDistributionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListStreamingDistributionsError::InvalidArgument(parsed_error.message),
                         )
                     }
@@ -10727,24 +10806,28 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListTagsForResourceError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidTagging(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::InvalidTagging(parsed_error.message),
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchResource(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::NoSuchResource(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10795,24 +10878,28 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(TagResourceError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(TagResourceError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(TagResourceError::InvalidTagging(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::InvalidTagging(parsed_error.message),
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(TagResourceError::NoSuchResource(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::NoSuchResource(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10863,24 +10950,28 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UntagResourceError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UntagResourceError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(UntagResourceError::InvalidTagging(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::InvalidTagging(parsed_error.message),
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(UntagResourceError::NoSuchResource(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::NoSuchResource(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10940,7 +11031,7 @@ impl UpdateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"MissingBody" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::service(&res, UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11088,7 +11179,7 @@ impl UpdateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(UpdateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateDistributionError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(UpdateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(UpdateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(UpdateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(UpdateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(UpdateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateDistributionError::InvalidIfMatchVersion(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(UpdateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(UpdateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(UpdateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(UpdateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(UpdateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(UpdateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(UpdateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(UpdateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(UpdateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(UpdateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateDistributionError::MissingBody(parsed_error.message)),"NoSuchDistribution" => return RusotoError::Service(UpdateDistributionError::NoSuchDistribution(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(UpdateDistributionError::NoSuchOrigin(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateDistributionError::PreconditionFailed(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(UpdateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(UpdateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(UpdateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(UpdateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(UpdateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(UpdateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(UpdateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(UpdateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(UpdateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, UpdateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::service(&res, UpdateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service(&res, UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"IllegalUpdate" => return RusotoError::service(&res, UpdateDistributionError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, UpdateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, UpdateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::service(&res, UpdateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::service(&res, UpdateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::service(&res, UpdateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::service(&res, UpdateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::service(&res, UpdateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::service(&res, UpdateDistributionError::InvalidIfMatchVersion(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::service(&res, UpdateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::service(&res, UpdateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::service(&res, UpdateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::service(&res, UpdateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::service(&res, UpdateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::service(&res, UpdateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::service(&res, UpdateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::service(&res, UpdateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::service(&res, UpdateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::service(&res, UpdateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::service(&res, UpdateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::service(&res, UpdateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::service(&res, UpdateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::service(&res, UpdateDistributionError::MissingBody(parsed_error.message)),"NoSuchDistribution" => return RusotoError::service(&res, UpdateDistributionError::NoSuchDistribution(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(&res, UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::service(&res, UpdateDistributionError::NoSuchOrigin(parsed_error.message)),"PreconditionFailed" => return RusotoError::service(&res, UpdateDistributionError::PreconditionFailed(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::service(&res, UpdateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::service(&res, UpdateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::service(&res, UpdateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::service(&res, UpdateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service(&res, UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service(&res, UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::service(&res, UpdateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::service(&res, UpdateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::service(&res, UpdateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::service(&res, UpdateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::service(&res, UpdateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::service(&res, UpdateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::service(&res, UpdateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::service(&res, UpdateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11192,7 +11283,7 @@ impl UpdateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::PreconditionFailed(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"PreconditionFailed" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::PreconditionFailed(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::service(&res, UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11265,7 +11356,7 @@ impl UpdateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message)),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::PreconditionFailed(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message)),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"IllegalUpdate" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"PreconditionFailed" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::PreconditionFailed(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::service(&res, UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11328,41 +11419,48 @@ impl UpdatePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UpdatePublicKeyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdatePublicKeyError::AccessDenied(parsed_error.message),
+                        )
                     }
                     "CannotChangeImmutablePublicKeyFields" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(UpdatePublicKeyError::IllegalUpdate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdatePublicKeyError::IllegalUpdate(parsed_error.message),
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UpdatePublicKeyError::InvalidArgument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdatePublicKeyError::InvalidArgument(parsed_error.message),
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(UpdatePublicKeyError::InvalidIfMatchVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdatePublicKeyError::InvalidIfMatchVersion(parsed_error.message),
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(UpdatePublicKeyError::NoSuchPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdatePublicKeyError::NoSuchPublicKey(parsed_error.message),
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(UpdatePublicKeyError::PreconditionFailed(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdatePublicKeyError::PreconditionFailed(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11438,83 +11536,96 @@ impl UpdateStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::AccessDenied(parsed_error.message),
                         )
                     }
                     "CNAMEAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::CNAMEAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::IllegalUpdate(parsed_error.message),
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::InconsistentQuantities(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::InvalidArgument(parsed_error.message),
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::InvalidIfMatchVersion(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::InvalidOriginAccessIdentity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(UpdateStreamingDistributionError::MissingBody(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateStreamingDistributionError::MissingBody(parsed_error.message),
+                        )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::NoSuchStreamingDistribution(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::PreconditionFailed(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTrustedSigners" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::TooManyTrustedSigners(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateStreamingDistributionError::TrustedSignerDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -11910,6 +12021,7 @@ impl CloudFrontClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -11925,6 +12037,7 @@ impl CloudFrontClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontClient {
         CloudFrontClient { client, region }
     }
@@ -11944,6 +12057,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateCloudFrontOriginAccessIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CloudFrontOriginAccessIdentityConfigSerializer::serialize(
@@ -11983,6 +12099,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateDistribution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         DistributionConfigSerializer::serialize(
@@ -12017,6 +12136,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateDistributionWithTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -12056,6 +12178,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateFieldLevelEncryptionConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionConfigSerializer::serialize(
@@ -12095,6 +12220,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateFieldLevelEncryptionProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionProfileConfigSerializer::serialize(
@@ -12134,6 +12262,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateInvalidation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         InvalidationBatchSerializer::serialize(
@@ -12166,6 +12297,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreatePublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         PublicKeyConfigSerializer::serialize(
@@ -12200,6 +12334,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateStreamingDistribution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         StreamingDistributionConfigSerializer::serialize(
@@ -12236,6 +12373,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("CreateStreamingDistributionWithTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -12281,6 +12421,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("DeleteCloudFrontOriginAccessIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12304,6 +12447,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("DeleteDistribution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12324,6 +12470,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("DeleteFieldLevelEncryptionConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12350,6 +12499,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("DeleteFieldLevelEncryptionProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12373,6 +12525,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("DeletePublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12393,6 +12548,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("DeleteStreamingDistribution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -12419,6 +12577,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetCloudFrontOriginAccessIdentity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(
@@ -12452,6 +12613,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetCloudFrontOriginAccessIdentityConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(
@@ -12482,6 +12646,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetDistribution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetDistributionError::from_response)
@@ -12506,6 +12673,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetDistributionConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetDistributionConfigError::from_response)
@@ -12530,6 +12700,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetFieldLevelEncryption");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionError::from_response)
@@ -12558,6 +12731,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetFieldLevelEncryptionConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionConfigError::from_response)
@@ -12588,6 +12764,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetFieldLevelEncryptionProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionProfileError::from_response)
@@ -12618,6 +12797,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetFieldLevelEncryptionProfileConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(
@@ -12652,6 +12834,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetInvalidation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetInvalidationError::from_response)
@@ -12676,6 +12861,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetPublicKey");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicKeyError::from_response)
@@ -12700,6 +12888,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetPublicKeyConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicKeyConfigError::from_response)
@@ -12724,6 +12915,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetStreamingDistribution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetStreamingDistributionError::from_response)
@@ -12754,6 +12948,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("GetStreamingDistributionConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetStreamingDistributionConfigError::from_response)
@@ -12781,6 +12978,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListCloudFrontOriginAccessIdentities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12820,6 +13020,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListDistributions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12857,6 +13060,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListDistributionsByWebACLId");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12893,6 +13099,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListFieldLevelEncryptionConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12929,6 +13138,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListFieldLevelEncryptionProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12968,6 +13180,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListInvalidations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13001,6 +13216,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/public-key";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListPublicKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13035,6 +13253,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/streaming-distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListStreamingDistributions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -13068,6 +13289,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -13096,6 +13320,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -13122,6 +13349,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2019-03-26/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -13154,6 +13384,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UpdateCloudFrontOriginAccessIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13194,6 +13427,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UpdateDistribution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13234,6 +13470,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UpdateFieldLevelEncryptionConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13277,6 +13516,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UpdateFieldLevelEncryptionProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13314,6 +13556,9 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2019-03-26/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UpdatePublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13352,6 +13597,9 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation_name("UpdateStreamingDistribution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -13378,6 +13626,593 @@ impl CloudFront for CloudFrontClient {
     }
 }
 
+/// A blocking client for the CloudFront API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudFrontClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudFrontBlockingClient {
+    client: CloudFrontClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudFrontBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudFrontBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudFrontBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudFrontBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudFrontBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient {
+            client: CloudFrontClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new origin access identity. If you're using Amazon S3 for your origin, you can use an origin access identity to require users to access your content using a CloudFront URL instead of the Amazon S3 URL. For more information about how to use origin access identities, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+    ///
+    /// See `CloudFront::create_cloud_front_origin_access_identity`.
+    pub fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        CreateCloudFrontOriginAccessIdentityResult,
+        RusotoError<CreateCloudFrontOriginAccessIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_cloud_front_origin_access_identity(input))
+    }
+
+    /// <p><p>Creates a new web distribution. You create a CloudFront distribution to tell CloudFront where you want content to be delivered from, and the details about how to track and manage content delivery. Send a <code>POST</code> request to the <code>/<i>CloudFront API version</i>/distribution</code>/<code>distribution ID</code> resource.</p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_UpdateDistribution.html">UpdateDistribution</a>, follow the steps included in the documentation to get the current configuration and then make your updates. This helps to make sure that you include all of the required fields. To view a summary, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important></p>
+    ///
+    /// See `CloudFront::create_distribution`.
+    pub fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> Result<CreateDistributionResult, RusotoError<CreateDistributionError>> {
+        self.runtime
+            .block_on(self.client.create_distribution(input))
+    }
+
+    /// <p>Create a new distribution with tags.</p>
+    ///
+    /// See `CloudFront::create_distribution_with_tags`.
+    pub fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> Result<CreateDistributionWithTagsResult, RusotoError<CreateDistributionWithTagsError>>
+    {
+        self.runtime
+            .block_on(self.client.create_distribution_with_tags(input))
+    }
+
+    /// <p>Create a new field-level encryption configuration.</p>
+    ///
+    /// See `CloudFront::create_field_level_encryption_config`.
+    pub fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        CreateFieldLevelEncryptionConfigResult,
+        RusotoError<CreateFieldLevelEncryptionConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_field_level_encryption_config(input))
+    }
+
+    /// <p>Create a field-level encryption profile.</p>
+    ///
+    /// See `CloudFront::create_field_level_encryption_profile`.
+    pub fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        CreateFieldLevelEncryptionProfileResult,
+        RusotoError<CreateFieldLevelEncryptionProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_field_level_encryption_profile(input))
+    }
+
+    /// <p>Create a new invalidation. </p>
+    ///
+    /// See `CloudFront::create_invalidation`.
+    pub fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> Result<CreateInvalidationResult, RusotoError<CreateInvalidationError>> {
+        self.runtime
+            .block_on(self.client.create_invalidation(input))
+    }
+
+    /// <p>Add a new public key to CloudFront to use, for example, for field-level encryption. You can add a maximum of 10 public keys with one AWS account.</p>
+    ///
+    /// See `CloudFront::create_public_key`.
+    pub fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> Result<CreatePublicKeyResult, RusotoError<CreatePublicKeyError>> {
+        self.runtime.block_on(self.client.create_public_key(input))
+    }
+
+    /// <p><p>Creates a new RTMP distribution. An RTMP distribution is similar to a web distribution, but an RTMP distribution streams media files using the Adobe Real-Time Messaging Protocol (RTMP) instead of serving files using HTTP. </p> <p>To create a new distribution, submit a <code>POST</code> request to the <i>CloudFront API version</i>/distribution resource. The request body must include a document with a <i>StreamingDistributionConfig</i> element. The response echoes the <code>StreamingDistributionConfig</code> element and returns other information about the RTMP distribution.</p> <p>To get the status of your request, use the <i>GET StreamingDistribution</i> API action. When the value of <code>Enabled</code> is <code>true</code> and the value of <code>Status</code> is <code>Deployed</code>, your distribution is ready. A distribution usually deploys in less than 15 minutes.</p> <p>For more information about web distributions, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-rtmp.html">Working with RTMP Distributions</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <important> <p>Beginning with the 2012-05-05 version of the CloudFront API, we made substantial changes to the format of the XML document that you include in the request body when you create or update a web distribution or an RTMP distribution, and when you invalidate objects. With previous versions of the API, we discovered that it was too easy to accidentally delete one or more values for an element that accepts multiple values, for example, CNAMEs and trusted signers. Our changes for the 2012-05-05 release are intended to prevent these accidental deletions and to notify you when there&#39;s a mismatch between the number of values you say you&#39;re specifying in the <code>Quantity</code> element and the number of values specified.</p> </important></p>
+    ///
+    /// See `CloudFront::create_streaming_distribution`.
+    pub fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> Result<CreateStreamingDistributionResult, RusotoError<CreateStreamingDistributionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_streaming_distribution(input))
+    }
+
+    /// <p>Create a new streaming distribution with tags.</p>
+    ///
+    /// See `CloudFront::create_streaming_distribution_with_tags`.
+    pub fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> Result<
+        CreateStreamingDistributionWithTagsResult,
+        RusotoError<CreateStreamingDistributionWithTagsError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_streaming_distribution_with_tags(input))
+    }
+
+    /// <p>Delete an origin access identity. </p>
+    ///
+    /// See `CloudFront::delete_cloud_front_origin_access_identity`.
+    pub fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<(), RusotoError<DeleteCloudFrontOriginAccessIdentityError>> {
+        self.runtime
+            .block_on(self.client.delete_cloud_front_origin_access_identity(input))
+    }
+
+    /// <p>Delete a distribution. </p>
+    ///
+    /// See `CloudFront::delete_distribution`.
+    pub fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> Result<(), RusotoError<DeleteDistributionError>> {
+        self.runtime
+            .block_on(self.client.delete_distribution(input))
+    }
+
+    /// <p>Remove a field-level encryption configuration.</p>
+    ///
+    /// See `CloudFront::delete_field_level_encryption_config`.
+    pub fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_field_level_encryption_config(input))
+    }
+
+    /// <p>Remove a field-level encryption profile.</p>
+    ///
+    /// See `CloudFront::delete_field_level_encryption_profile`.
+    pub fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_field_level_encryption_profile(input))
+    }
+
+    /// <p>Remove a public key you previously added to CloudFront.</p>
+    ///
+    /// See `CloudFront::delete_public_key`.
+    pub fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> Result<(), RusotoError<DeletePublicKeyError>> {
+        self.runtime.block_on(self.client.delete_public_key(input))
+    }
+
+    /// <p>Delete a streaming distribution. To delete an RTMP distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete an RTMP distribution using the CloudFront API</b>:</p> <ol> <li> <p>Disable the RTMP distribution.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution. </p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Streaming Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Streaming Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Then set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Streaming Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Streaming Distribution Config</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Streaming Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Streaming Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to your <code>DELETE Streaming Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+    ///
+    /// See `CloudFront::delete_streaming_distribution`.
+    pub fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> Result<(), RusotoError<DeleteStreamingDistributionError>> {
+        self.runtime
+            .block_on(self.client.delete_streaming_distribution(input))
+    }
+
+    /// <p>Get the information about an origin access identity. </p>
+    ///
+    /// See `CloudFront::get_cloud_front_origin_access_identity`.
+    pub fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        GetCloudFrontOriginAccessIdentityResult,
+        RusotoError<GetCloudFrontOriginAccessIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_cloud_front_origin_access_identity(input))
+    }
+
+    /// <p>Get the configuration information about an origin access identity. </p>
+    ///
+    /// See `CloudFront::get_cloud_front_origin_access_identity_config`.
+    pub fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> Result<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_cloud_front_origin_access_identity_config(input),
+        )
+    }
+
+    /// <p>Get the information about a distribution.</p>
+    ///
+    /// See `CloudFront::get_distribution`.
+    pub fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> Result<GetDistributionResult, RusotoError<GetDistributionError>> {
+        self.runtime.block_on(self.client.get_distribution(input))
+    }
+
+    /// <p>Get the configuration information about a distribution. </p>
+    ///
+    /// See `CloudFront::get_distribution_config`.
+    pub fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> Result<GetDistributionConfigResult, RusotoError<GetDistributionConfigError>> {
+        self.runtime
+            .block_on(self.client.get_distribution_config(input))
+    }
+
+    /// <p>Get the field-level encryption configuration information.</p>
+    ///
+    /// See `CloudFront::get_field_level_encryption`.
+    pub fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> Result<GetFieldLevelEncryptionResult, RusotoError<GetFieldLevelEncryptionError>> {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption(input))
+    }
+
+    /// <p>Get the field-level encryption configuration information.</p>
+    ///
+    /// See `CloudFront::get_field_level_encryption_config`.
+    pub fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> Result<GetFieldLevelEncryptionConfigResult, RusotoError<GetFieldLevelEncryptionConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption_config(input))
+    }
+
+    /// <p>Get the field-level encryption profile information.</p>
+    ///
+    /// See `CloudFront::get_field_level_encryption_profile`.
+    pub fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        GetFieldLevelEncryptionProfileResult,
+        RusotoError<GetFieldLevelEncryptionProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption_profile(input))
+    }
+
+    /// <p>Get the field-level encryption profile configuration information.</p>
+    ///
+    /// See `CloudFront::get_field_level_encryption_profile_config`.
+    pub fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> Result<
+        GetFieldLevelEncryptionProfileConfigResult,
+        RusotoError<GetFieldLevelEncryptionProfileConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption_profile_config(input))
+    }
+
+    /// <p>Get the information about an invalidation. </p>
+    ///
+    /// See `CloudFront::get_invalidation`.
+    pub fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> Result<GetInvalidationResult, RusotoError<GetInvalidationError>> {
+        self.runtime.block_on(self.client.get_invalidation(input))
+    }
+
+    /// <p>Get the public key information.</p>
+    ///
+    /// See `CloudFront::get_public_key`.
+    pub fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<GetPublicKeyResult, RusotoError<GetPublicKeyError>> {
+        self.runtime.block_on(self.client.get_public_key(input))
+    }
+
+    /// <p>Return public key configuration informaation</p>
+    ///
+    /// See `CloudFront::get_public_key_config`.
+    pub fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> Result<GetPublicKeyConfigResult, RusotoError<GetPublicKeyConfigError>> {
+        self.runtime
+            .block_on(self.client.get_public_key_config(input))
+    }
+
+    /// <p>Gets information about a specified RTMP distribution, including the distribution configuration.</p>
+    ///
+    /// See `CloudFront::get_streaming_distribution`.
+    pub fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<GetStreamingDistributionResult, RusotoError<GetStreamingDistributionError>> {
+        self.runtime
+            .block_on(self.client.get_streaming_distribution(input))
+    }
+
+    /// <p>Get the configuration information about a streaming distribution. </p>
+    ///
+    /// See `CloudFront::get_streaming_distribution_config`.
+    pub fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> Result<
+        GetStreamingDistributionConfigResult,
+        RusotoError<GetStreamingDistributionConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_streaming_distribution_config(input))
+    }
+
+    /// <p>Lists origin access identities.</p>
+    ///
+    /// See `CloudFront::list_cloud_front_origin_access_identities`.
+    pub fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> Result<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        RusotoError<ListCloudFrontOriginAccessIdentitiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_cloud_front_origin_access_identities(input))
+    }
+
+    /// <p>List CloudFront distributions.</p>
+    ///
+    /// See `CloudFront::list_distributions`.
+    pub fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> Result<ListDistributionsResult, RusotoError<ListDistributionsError>> {
+        self.runtime.block_on(self.client.list_distributions(input))
+    }
+
+    /// <p>List the distributions that are associated with a specified AWS WAF web ACL. </p>
+    ///
+    /// See `CloudFront::list_distributions_by_web_acl_id`.
+    pub fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> Result<ListDistributionsByWebACLIdResult, RusotoError<ListDistributionsByWebACLIdError>>
+    {
+        self.runtime
+            .block_on(self.client.list_distributions_by_web_acl_id(input))
+    }
+
+    /// <p>List all field-level encryption configurations that have been created in CloudFront for this account.</p>
+    ///
+    /// See `CloudFront::list_field_level_encryption_configs`.
+    pub fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> Result<
+        ListFieldLevelEncryptionConfigsResult,
+        RusotoError<ListFieldLevelEncryptionConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_field_level_encryption_configs(input))
+    }
+
+    /// <p>Request a list of field-level encryption profiles that have been created in CloudFront for this account.</p>
+    ///
+    /// See `CloudFront::list_field_level_encryption_profiles`.
+    pub fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> Result<
+        ListFieldLevelEncryptionProfilesResult,
+        RusotoError<ListFieldLevelEncryptionProfilesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_field_level_encryption_profiles(input))
+    }
+
+    /// <p>Lists invalidation batches. </p>
+    ///
+    /// See `CloudFront::list_invalidations`.
+    pub fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> Result<ListInvalidationsResult, RusotoError<ListInvalidationsError>> {
+        self.runtime.block_on(self.client.list_invalidations(input))
+    }
+
+    /// <p>List all public keys that have been added to CloudFront for this account.</p>
+    ///
+    /// See `CloudFront::list_public_keys`.
+    pub fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResult, RusotoError<ListPublicKeysError>> {
+        self.runtime.block_on(self.client.list_public_keys(input))
+    }
+
+    /// <p>List streaming distributions. </p>
+    ///
+    /// See `CloudFront::list_streaming_distributions`.
+    pub fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> Result<ListStreamingDistributionsResult, RusotoError<ListStreamingDistributionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_streaming_distributions(input))
+    }
+
+    /// <p>List tags for a CloudFront resource.</p>
+    ///
+    /// See `CloudFront::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Add tags to a CloudFront resource.</p>
+    ///
+    /// See `CloudFront::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove tags from a CloudFront resource.</p>
+    ///
+    /// See `CloudFront::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Update an origin access identity. </p>
+    ///
+    /// See `CloudFront::update_cloud_front_origin_access_identity`.
+    pub fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_cloud_front_origin_access_identity(input))
+    }
+
+    /// <p><p>Updates the configuration for a web distribution. </p> <important> <p>When you update a distribution, there are more required fields than when you create a distribution. When you update your distribution by using this API action, follow the steps here to get the current configuration and then make your updates, to make sure that you include all of the required fields. To view a summary, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-overview-required-fields.html">Required Fields for Create Distribution and Update Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </important> <p>The update process includes getting the current distribution configuration, updating the XML document that is returned to make your changes, and then submitting an <code>UpdateDistribution</code> request to make the updates.</p> <p>For information about updating a distribution using the CloudFront console instead, see <a href="https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-creating-console.html">Creating a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p> <b>To update a web distribution using the CloudFront API</b> </p> <ol> <li> <p>Submit a <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_GetDistributionConfig.html">GetDistributionConfig</a> request to get the current configuration and an <code>Etag</code> header for the distribution.</p> <note> <p>If you update the distribution again, you must get a new <code>Etag</code> header.</p> </note> </li> <li> <p>Update the XML document that was returned in the response to your <code>GetDistributionConfig</code> request to include your changes. </p> <important> <p>When you edit the XML file, be aware of the following:</p> <ul> <li> <p>You must strip out the ETag parameter that is returned.</p> </li> <li> <p>Additional fields are required when you update a distribution. There may be fields included in the XML file for features that you haven&#39;t configured for your distribution. This is expected and required to successfully update the distribution.</p> </li> <li> <p>You can&#39;t change the value of <code>CallerReference</code>. If you try to change this value, CloudFront returns an <code>IllegalUpdate</code> error. </p> </li> <li> <p>The new configuration replaces the existing configuration; the values that you specify in an <code>UpdateDistribution</code> request are not merged into your existing configuration. When you add, delete, or replace values in an element that allows multiple values (for example, <code>CNAME</code>), you must specify all of the values that you want to appear in the updated distribution. In addition, you must update the corresponding <code>Quantity</code> element.</p> </li> </ul> </important> </li> <li> <p>Submit an <code>UpdateDistribution</code> request to update the configuration for your distribution:</p> <ul> <li> <p>In the request body, include the XML document that you updated in Step 2. The request body must include an XML document with a <code>DistributionConfig</code> element.</p> </li> <li> <p>Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GetDistributionConfig</code> request in Step 1.</p> </li> </ul> </li> <li> <p>Review the response to the <code>UpdateDistribution</code> request to confirm that the configuration was successfully updated.</p> </li> <li> <p>Optional: Submit a <a href="https://docs.aws.amazon.com/cloudfront/latest/APIReference/API_GetDistribution.html">GetDistribution</a> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> </ol></p>
+    ///
+    /// See `CloudFront::update_distribution`.
+    pub fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> Result<UpdateDistributionResult, RusotoError<UpdateDistributionError>> {
+        self.runtime
+            .block_on(self.client.update_distribution(input))
+    }
+
+    /// <p>Update a field-level encryption configuration. </p>
+    ///
+    /// See `CloudFront::update_field_level_encryption_config`.
+    pub fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        UpdateFieldLevelEncryptionConfigResult,
+        RusotoError<UpdateFieldLevelEncryptionConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_field_level_encryption_config(input))
+    }
+
+    /// <p>Update a field-level encryption profile. </p>
+    ///
+    /// See `CloudFront::update_field_level_encryption_profile`.
+    pub fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        UpdateFieldLevelEncryptionProfileResult,
+        RusotoError<UpdateFieldLevelEncryptionProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_field_level_encryption_profile(input))
+    }
+
+    /// <p>Update public key information. Note that the only value you can change is the comment.</p>
+    ///
+    /// See `CloudFront::update_public_key`.
+    pub fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> Result<UpdatePublicKeyResult, RusotoError<UpdatePublicKeyError>> {
+        self.runtime.block_on(self.client.update_public_key(input))
+    }
+
+    /// <p>Update a streaming distribution. </p>
+    ///
+    /// See `CloudFront::update_streaming_distribution`.
+    pub fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> Result<UpdateStreamingDistributionResult, RusotoError<UpdateStreamingDistributionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_streaming_distribution(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
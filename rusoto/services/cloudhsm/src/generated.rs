@@ -654,13 +654,22 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(AddTagsToResourceError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(AddTagsToResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -696,13 +705,13 @@ impl CreateHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, CreateHapgError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, CreateHapgError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateHapgError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -738,13 +747,13 @@ impl CreateHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, CreateHsmError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, CreateHsmError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateHsmError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -780,13 +789,22 @@ impl CreateLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLunaClientError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLunaClientError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLunaClientError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -822,13 +840,13 @@ impl DeleteHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, DeleteHapgError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DeleteHapgError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteHapgError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -864,13 +882,13 @@ impl DeleteHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, DeleteHsmError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DeleteHsmError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteHsmError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -906,13 +924,22 @@ impl DeleteLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLunaClientError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLunaClientError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLunaClientError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -948,13 +975,13 @@ impl DescribeHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, DescribeHapgError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DescribeHapgError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeHapgError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -990,13 +1017,13 @@ impl DescribeHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, DescribeHsmError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DescribeHsmError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeHsmError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1032,13 +1059,22 @@ impl DescribeLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLunaClientError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLunaClientError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLunaClientError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1074,13 +1110,13 @@ impl GetConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(GetConfigError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, GetConfigError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(GetConfigError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, GetConfigError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetConfigError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1116,13 +1152,22 @@ impl ListAvailableZonesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListAvailableZonesError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAvailableZonesError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListAvailableZonesError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAvailableZonesError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAvailableZonesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAvailableZonesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1158,13 +1203,13 @@ impl ListHapgsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListHapgsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, ListHapgsError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListHapgsError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, ListHapgsError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHapgsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListHapgsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1200,13 +1245,13 @@ impl ListHsmsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListHsmsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, ListHsmsError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListHsmsError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, ListHsmsError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHsmsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListHsmsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1242,13 +1287,22 @@ impl ListLunaClientsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListLunaClientsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLunaClientsError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListLunaClientsError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLunaClientsError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLunaClientsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLunaClientsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1284,15 +1338,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::CloudHsmInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1328,13 +1389,13 @@ impl ModifyHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ModifyHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, ModifyHapgError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, ModifyHapgError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ModifyHapgError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1370,13 +1431,13 @@ impl ModifyHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ModifyHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service(&res, ModifyHsmError::CloudHsmInternal(err.msg))
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, ModifyHsmError::CloudHsmService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ModifyHsmError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1408,7 +1469,10 @@ impl ModifyLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyLunaClientError::CloudHsmService(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1442,19 +1506,22 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::CloudHsmInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::CloudHsmInternal(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::CloudHsmService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::CloudHsmService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1614,6 +1681,7 @@ impl CloudHsmClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1629,6 +1697,7 @@ impl CloudHsmClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmClient {
         CloudHsmClient { client, region }
     }
@@ -1642,6 +1711,9 @@ impl CloudHsm for CloudHsmClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1660,6 +1732,9 @@ impl CloudHsm for CloudHsmClient {
         input: CreateHapgRequest,
     ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHapg");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1678,6 +1753,9 @@ impl CloudHsm for CloudHsmClient {
         input: CreateHsmRequest,
     ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHsm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1696,6 +1774,9 @@ impl CloudHsm for CloudHsmClient {
         input: CreateLunaClientRequest,
     ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLunaClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1714,6 +1795,9 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteHapgRequest,
     ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteHapg");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1732,6 +1816,9 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteHsmRequest,
     ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteHsm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1750,6 +1837,9 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteLunaClientRequest,
     ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLunaClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1768,6 +1858,9 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeHapgRequest,
     ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHapg");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1786,6 +1879,9 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeHsmRequest,
     ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHsm");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1804,6 +1900,9 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeLunaClientRequest,
     ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLunaClient");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1822,6 +1921,9 @@ impl CloudHsm for CloudHsmClient {
         input: GetConfigRequest,
     ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.GetConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1839,6 +1941,9 @@ impl CloudHsm for CloudHsmClient {
         &self,
     ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAvailableZones");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListAvailableZones");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -1856,6 +1961,9 @@ impl CloudHsm for CloudHsmClient {
         input: ListHapgsRequest,
     ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHapgs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHapgs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1874,6 +1982,9 @@ impl CloudHsm for CloudHsmClient {
         input: ListHsmsRequest,
     ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHsms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHsms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1892,6 +2003,9 @@ impl CloudHsm for CloudHsmClient {
         input: ListLunaClientsRequest,
     ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLunaClients");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListLunaClients");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1910,6 +2024,9 @@ impl CloudHsm for CloudHsmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CloudHsmFrontendService.ListTagsForResource",
@@ -1931,6 +2048,9 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyHapgRequest,
     ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyHapg");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1949,6 +2069,9 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyHsmRequest,
     ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyHsm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1967,6 +2090,9 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyLunaClientRequest,
     ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyLunaClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1985,6 +2111,9 @@ impl CloudHsm for CloudHsmClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CloudHsmFrontendService.RemoveTagsFromResource",
@@ -2001,3 +2130,256 @@ impl CloudHsm for CloudHsmClient {
             .deserialize::<RemoveTagsFromResourceResponse, _>()
     }
 }
+
+/// A blocking client for the CloudHSM API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudHsmClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudHsmBlockingClient {
+    client: CloudHsmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudHsmBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudHsmBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudHsmBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudHsmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudHsmBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient {
+            client: CloudHsmClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Adds or overwrites one or more tags for the specified AWS CloudHSM resource.</p> <p>Each tag consists of a key and a value. Tag keys must be unique to each resource.</p>
+    ///
+    /// See `CloudHsm::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates a high-availability partition group. A high-availability partition group is a group of partitions that spans multiple physical HSMs.</p>
+    ///
+    /// See `CloudHsm::create_hapg`.
+    pub fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
+        self.runtime.block_on(self.client.create_hapg(input))
+    }
+
+    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an uninitialized HSM instance.</p> <p>There is an upfront fee charged for each HSM instance that you create with the <code>CreateHsm</code> operation. If you accidentally provision an HSM and want to request a refund, delete the instance using the <a>DeleteHsm</a> operation, go to the <a href="https://console.aws.amazon.com/support/home">AWS Support Center</a>, create a new case, and select <b>Account and Billing Support</b>.</p> <important> <p>It can take up to 20 minutes to create and provision an HSM. You can monitor the status of the HSM with the <a>DescribeHsm</a> operation. The HSM is ready to be initialized when the status changes to <code>RUNNING</code>.</p> </important></p>
+    ///
+    /// See `CloudHsm::create_hsm`.
+    pub fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+        self.runtime.block_on(self.client.create_hsm(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Creates an HSM client.</p>
+    ///
+    /// See `CloudHsm::create_luna_client`.
+    pub fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
+        self.runtime.block_on(self.client.create_luna_client(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a high-availability partition group.</p>
+    ///
+    /// See `CloudHsm::delete_hapg`.
+    pub fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
+        self.runtime.block_on(self.client.delete_hapg(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes an HSM. After completion, this operation cannot be undone and your key material cannot be recovered.</p>
+    ///
+    /// See `CloudHsm::delete_hsm`.
+    pub fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+        self.runtime.block_on(self.client.delete_hsm(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Deletes a client.</p>
+    ///
+    /// See `CloudHsm::delete_luna_client`.
+    pub fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
+        self.runtime.block_on(self.client.delete_luna_client(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about a high-availability partition group.</p>
+    ///
+    /// See `CloudHsm::describe_hapg`.
+    pub fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
+        self.runtime.block_on(self.client.describe_hapg(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about an HSM. You can identify the HSM by its ARN or its serial number.</p>
+    ///
+    /// See `CloudHsm::describe_hsm`.
+    pub fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
+        self.runtime.block_on(self.client.describe_hsm(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves information about an HSM client.</p>
+    ///
+    /// See `CloudHsm::describe_luna_client`.
+    pub fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
+        self.runtime
+            .block_on(self.client.describe_luna_client(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Gets the configuration files necessary to connect to all high availability partition groups the client is associated with.</p>
+    ///
+    /// See `CloudHsm::get_config`.
+    pub fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+        self.runtime.block_on(self.client.get_config(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists the Availability Zones that have available AWS CloudHSM capacity.</p>
+    ///
+    /// See `CloudHsm::list_available_zones`.
+    pub fn list_available_zones(
+        &self,
+    ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
+        self.runtime.block_on(self.client.list_available_zones())
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists the high-availability partition groups for the account.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHapgs</code> to retrieve the next set of items.</p>
+    ///
+    /// See `CloudHsm::list_hapgs`.
+    pub fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
+        self.runtime.block_on(self.client.list_hapgs(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Retrieves the identifiers of all of the HSMs provisioned for the current customer.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListHsms</code> to retrieve the next set of items.</p>
+    ///
+    /// See `CloudHsm::list_hsms`.
+    pub fn list_hsms(
+        &self,
+        input: ListHsmsRequest,
+    ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
+        self.runtime.block_on(self.client.list_hsms(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Lists all of the clients.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> member. If more results are available, the <code>NextToken</code> member of the response contains a token that you pass in the next call to <code>ListLunaClients</code> to retrieve the next set of items.</p>
+    ///
+    /// See `CloudHsm::list_luna_clients`.
+    pub fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
+        self.runtime.block_on(self.client.list_luna_clients(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Returns a list of all tags for the specified AWS CloudHSM resource.</p>
+    ///
+    /// See `CloudHsm::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies an existing high-availability partition group.</p>
+    ///
+    /// See `CloudHsm::modify_hapg`.
+    pub fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
+        self.runtime.block_on(self.client.modify_hapg(input))
+    }
+
+    /// <p><p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies an HSM.</p> <important> <p>This operation can result in the HSM being offline for up to 15 minutes while the AWS CloudHSM service is reconfigured. If you are modifying a production HSM, you should ensure that your AWS CloudHSM service is configured for high availability, and consider executing this operation during a maintenance window.</p> </important></p>
+    ///
+    /// See `CloudHsm::modify_hsm`.
+    pub fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
+        self.runtime.block_on(self.client.modify_hsm(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Modifies the certificate used by the client.</p> <p>This action can potentially start a workflow to install the new certificate on the client's HSMs.</p>
+    ///
+    /// See `CloudHsm::modify_luna_client`.
+    pub fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
+        self.runtime.block_on(self.client.modify_luna_client(input))
+    }
+
+    /// <p>This is documentation for <b>AWS CloudHSM Classic</b>. For more information, see <a href="http://aws.amazon.com/cloudhsm/faqs-classic/">AWS CloudHSM Classic FAQs</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/classic/userguide/">AWS CloudHSM Classic User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/classic/APIReference/">AWS CloudHSM Classic API Reference</a>.</p> <p> <b>For information about the current version of AWS CloudHSM</b>, see <a href="http://aws.amazon.com/cloudhsm/">AWS CloudHSM</a>, the <a href="http://docs.aws.amazon.com/cloudhsm/latest/userguide/">AWS CloudHSM User Guide</a>, and the <a href="http://docs.aws.amazon.com/cloudhsm/latest/APIReference/">AWS CloudHSM API Reference</a>.</p> <p>Removes one or more tags from the specified AWS CloudHSM resource.</p> <p>To remove a tag, specify only the tag key to remove (not the value). To overwrite the value for an existing tag, use <a>AddTagsToResource</a>.</p>
+    ///
+    /// See `CloudHsm::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+}
@@ -581,30 +581,40 @@ impl CopyBackupToRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyBackupToRegionError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyBackupToRegionError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyBackupToRegionError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyBackupToRegionError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyBackupToRegionError::CloudHsmService(err.msg),
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmTag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyBackupToRegionError::CloudHsmTag(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -649,28 +659,34 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::CloudHsmService(err.msg))
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -713,19 +729,31 @@ impl CreateHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHsmError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHsmError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHsmError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHsmError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, CreateHsmError::CloudHsmService(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -767,23 +795,31 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::CloudHsmService(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -827,28 +863,34 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::CloudHsmService(err.msg))
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -891,19 +933,31 @@ impl DeleteHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHsmError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHsmError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHsmError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHsmError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, DeleteHsmError::CloudHsmService(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -947,30 +1001,37 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::CloudHsmService(err.msg),
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, DescribeBackupsError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1013,25 +1074,31 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::CloudHsmService(err.msg),
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, DescribeClustersError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1073,27 +1140,34 @@ impl InitializeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitializeClusterError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitializeClusterError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitializeClusterError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitializeClusterError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitializeClusterError::CloudHsmService(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1137,22 +1211,31 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(&res, ListTagsError::CloudHsmAccessDenied(err.msg))
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, ListTagsError::CloudHsmService(err.msg))
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, ListTagsError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1195,25 +1278,31 @@ impl RestoreBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreBackupError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreBackupError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreBackupError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreBackupError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, RestoreBackupError::CloudHsmService(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1257,24 +1346,34 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, TagResourceError::CloudHsmService(err.msg))
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, TagResourceError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1319,28 +1418,34 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::CloudHsmAccessDenied(err.msg),
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmInternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::CloudHsmInternalFailure(err.msg),
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmInvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::CloudHsmInvalidRequest(err.msg),
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::CloudHsmResourceNotFound(err.msg),
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::CloudHsmService(err.msg))
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmTag(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::CloudHsmTag(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1462,6 +1567,7 @@ impl CloudHsmv2Client {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1477,6 +1583,7 @@ impl CloudHsmv2Client {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmv2Client {
         CloudHsmv2Client { client, region }
     }
@@ -1490,6 +1597,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CopyBackupToRegionRequest,
     ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CopyBackupToRegion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.CopyBackupToRegion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1508,6 +1618,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.CreateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1526,6 +1639,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CreateHsmRequest,
     ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHsm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.CreateHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1544,6 +1660,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1562,6 +1681,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.DeleteCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1580,6 +1702,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteHsmRequest,
     ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteHsm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.DeleteHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1598,6 +1723,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBackups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "BaldrApiService.DescribeBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1616,6 +1744,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "BaldrApiService.DescribeClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1634,6 +1765,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: InitializeClusterRequest,
     ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InitializeCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.InitializeCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1652,6 +1786,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "BaldrApiService.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1670,6 +1807,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: RestoreBackupRequest,
     ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.RestoreBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1688,6 +1828,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1706,6 +1849,9 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "BaldrApiService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1718,3 +1864,184 @@ impl CloudHsmv2 for CloudHsmv2Client {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the CloudHSM V2 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudHsmv2Client` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudHsmv2BlockingClient {
+    client: CloudHsmv2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudHsmv2BlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudHsmv2BlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudHsmv2BlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudHsmv2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudHsmv2BlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient {
+            client: CloudHsmv2Client::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Copy an AWS CloudHSM cluster backup to a different region.</p>
+    ///
+    /// See `CloudHsmv2::copy_backup_to_region`.
+    pub fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
+        self.runtime
+            .block_on(self.client.copy_backup_to_region(input))
+    }
+
+    /// <p>Creates a new AWS CloudHSM cluster.</p>
+    ///
+    /// See `CloudHsmv2::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <p>Creates a new hardware security module (HSM) in the specified AWS CloudHSM cluster.</p>
+    ///
+    /// See `CloudHsmv2::create_hsm`.
+    pub fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+        self.runtime.block_on(self.client.create_hsm(input))
+    }
+
+    /// <p>Deletes a specified AWS CloudHSM backup. A backup can be restored up to 7 days after the DeleteBackup request is made. For more information on restoring a backup, see <a>RestoreBackup</a>.</p>
+    ///
+    /// See `CloudHsmv2::delete_backup`.
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// <p>Deletes the specified AWS CloudHSM cluster. Before you can delete a cluster, you must delete all HSMs in the cluster. To see if the cluster contains any HSMs, use <a>DescribeClusters</a>. To delete an HSM, use <a>DeleteHsm</a>.</p>
+    ///
+    /// See `CloudHsmv2::delete_cluster`.
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// <p>Deletes the specified HSM. To specify an HSM, you can use its identifier (ID), the IP address of the HSM's elastic network interface (ENI), or the ID of the HSM's ENI. You need to specify only one of these values. To find these values, use <a>DescribeClusters</a>.</p>
+    ///
+    /// See `CloudHsmv2::delete_hsm`.
+    pub fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+        self.runtime.block_on(self.client.delete_hsm(input))
+    }
+
+    /// <p>Gets information about backups of AWS CloudHSM clusters.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the backups. When the response contains only a subset of backups, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>DescribeBackups</code> request to get more backups. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more backups to get.</p>
+    ///
+    /// See `CloudHsmv2::describe_backups`.
+    pub fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.runtime.block_on(self.client.describe_backups(input))
+    }
+
+    /// <p>Gets information about AWS CloudHSM clusters.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the clusters. When the response contains only a subset of clusters, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>DescribeClusters</code> request to get more clusters. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more clusters to get.</p>
+    ///
+    /// See `CloudHsmv2::describe_clusters`.
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// <p>Claims an AWS CloudHSM cluster by submitting the cluster certificate issued by your issuing certificate authority (CA) and the CA's root certificate. Before you can claim a cluster, you must sign the cluster's certificate signing request (CSR) with your issuing CA. To get the cluster's CSR, use <a>DescribeClusters</a>.</p>
+    ///
+    /// See `CloudHsmv2::initialize_cluster`.
+    pub fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
+        self.runtime.block_on(self.client.initialize_cluster(input))
+    }
+
+    /// <p>Gets a list of tags for the specified AWS CloudHSM cluster.</p> <p>This is a paginated operation, which means that each response might contain only a subset of all the tags. When the response contains only a subset of tags, it includes a <code>NextToken</code> value. Use this value in a subsequent <code>ListTags</code> request to get more tags. When you receive a response with no <code>NextToken</code> (or an empty or null value), that means there are no more tags to get.</p>
+    ///
+    /// See `CloudHsmv2::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Restores a specified AWS CloudHSM backup that is in the <code>PENDING_DELETION</code> state. For mor information on deleting a backup, see <a>DeleteBackup</a>.</p>
+    ///
+    /// See `CloudHsmv2::restore_backup`.
+    pub fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
+        self.runtime.block_on(self.client.restore_backup(input))
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified AWS CloudHSM cluster.</p>
+    ///
+    /// See `CloudHsmv2::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tag or tags from the specified AWS CloudHSM cluster.</p>
+    ///
+    /// See `CloudHsmv2::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
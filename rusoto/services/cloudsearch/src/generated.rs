@@ -4108,19 +4108,22 @@ impl BuildSuggestersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(BuildSuggestersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            BuildSuggestersError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(BuildSuggestersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            BuildSuggestersError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(BuildSuggestersError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            BuildSuggestersError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4168,17 +4171,22 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(CreateDomainError::Base(parsed_error.message))
+                        return RusotoError::service(
+                            &res,
+                            CreateDomainError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(CreateDomainError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDomainError::Internal(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateDomainError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDomainError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4230,29 +4238,34 @@ impl DefineAnalysisSchemeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineAnalysisSchemeError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineAnalysisSchemeError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineAnalysisSchemeError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineAnalysisSchemeError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineAnalysisSchemeError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4306,29 +4319,34 @@ impl DefineExpressionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineExpressionError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineExpressionError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineExpressionError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineExpressionError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineExpressionError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineExpressionError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineExpressionError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineExpressionError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineExpressionError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineExpressionError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4382,29 +4400,34 @@ impl DefineIndexFieldError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineIndexFieldError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineIndexFieldError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineIndexFieldError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineIndexFieldError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineIndexFieldError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineIndexFieldError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineIndexFieldError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineIndexFieldError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineIndexFieldError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineIndexFieldError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4458,29 +4481,34 @@ impl DefineSuggesterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineSuggesterError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineSuggesterError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineSuggesterError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineSuggesterError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineSuggesterError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineSuggesterError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineSuggesterError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineSuggesterError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineSuggesterError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DefineSuggesterError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4532,24 +4560,28 @@ impl DeleteAnalysisSchemeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAnalysisSchemeError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAnalysisSchemeError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAnalysisSchemeError::InvalidType(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAnalysisSchemeError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4596,12 +4628,16 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteDomainError::Base(parsed_error.message))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDomainError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteDomainError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDomainError::Internal(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4650,24 +4686,28 @@ impl DeleteExpressionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteExpressionError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteExpressionError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteExpressionError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteExpressionError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteExpressionError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteExpressionError::InvalidType(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteExpressionError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteExpressionError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4718,24 +4758,28 @@ impl DeleteIndexFieldError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteIndexFieldError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteIndexFieldError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteIndexFieldError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteIndexFieldError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteIndexFieldError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteIndexFieldError::InvalidType(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteIndexFieldError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteIndexFieldError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4786,24 +4830,28 @@ impl DeleteSuggesterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteSuggesterError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSuggesterError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteSuggesterError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSuggesterError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteSuggesterError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSuggesterError::InvalidType(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteSuggesterError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSuggesterError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4852,17 +4900,20 @@ impl DescribeAnalysisSchemesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeAnalysisSchemesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAnalysisSchemesError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeAnalysisSchemesError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAnalysisSchemesError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAnalysisSchemesError::ResourceNotFound(parsed_error.message),
                         )
                     }
@@ -4920,34 +4971,40 @@ impl DescribeAvailabilityOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAvailabilityOptionsError::Base(parsed_error.message),
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAvailabilityOptionsError::DisabledOperation(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAvailabilityOptionsError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAvailabilityOptionsError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAvailabilityOptionsError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAvailabilityOptionsError::ResourceNotFound(
                                 parsed_error.message,
                             ),
@@ -5010,29 +5067,34 @@ impl DescribeDomainEndpointOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeDomainEndpointOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeDomainEndpointOptionsError::Base(parsed_error.message),
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDomainEndpointOptionsError::DisabledOperation(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeDomainEndpointOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeDomainEndpointOptionsError::Internal(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDomainEndpointOptionsError::ResourceNotFound(
                                 parsed_error.message,
                             ),
@@ -5088,14 +5150,16 @@ impl DescribeDomainsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeDomainsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeDomainsError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeDomainsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeDomainsError::Internal(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5142,19 +5206,22 @@ impl DescribeExpressionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeExpressionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeExpressionsError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeExpressionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeExpressionsError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeExpressionsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeExpressionsError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5202,19 +5269,22 @@ impl DescribeIndexFieldsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeIndexFieldsError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeIndexFieldsError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeIndexFieldsError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5262,17 +5332,20 @@ impl DescribeScalingParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeScalingParametersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeScalingParametersError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeScalingParametersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeScalingParametersError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScalingParametersError::ResourceNotFound(parsed_error.message),
                         )
                     }
@@ -5324,17 +5397,20 @@ impl DescribeServiceAccessPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeServiceAccessPoliciesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeServiceAccessPoliciesError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeServiceAccessPoliciesError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeServiceAccessPoliciesError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeServiceAccessPoliciesError::ResourceNotFound(
                                 parsed_error.message,
                             ),
@@ -5388,19 +5464,22 @@ impl DescribeSuggestersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeSuggestersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeSuggestersError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeSuggestersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeSuggestersError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeSuggestersError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeSuggestersError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5448,19 +5527,22 @@ impl IndexDocumentsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(IndexDocumentsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            IndexDocumentsError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(IndexDocumentsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            IndexDocumentsError::Internal(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(IndexDocumentsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            IndexDocumentsError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5504,9 +5586,10 @@ impl ListDomainNamesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(ListDomainNamesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListDomainNamesError::Base(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5560,39 +5643,46 @@ impl UpdateAvailabilityOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAvailabilityOptionsError::Base(parsed_error.message),
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAvailabilityOptionsError::DisabledOperation(parsed_error.message),
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAvailabilityOptionsError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAvailabilityOptionsError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAvailabilityOptionsError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAvailabilityOptionsError::ResourceNotFound(parsed_error.message),
                         )
                     }
                     "ValidationException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Validation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAvailabilityOptionsError::Validation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5654,43 +5744,50 @@ impl UpdateDomainEndpointOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateDomainEndpointOptionsError::Base(parsed_error.message),
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateDomainEndpointOptionsError::DisabledOperation(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateDomainEndpointOptionsError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateDomainEndpointOptionsError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateDomainEndpointOptionsError::ResourceNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ValidationException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Validation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateDomainEndpointOptionsError::Validation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5748,27 +5845,32 @@ impl UpdateScalingParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateScalingParametersError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateScalingParametersError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateScalingParametersError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateScalingParametersError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateScalingParametersError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateScalingParametersError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateScalingParametersError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateScalingParametersError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateScalingParametersError::ResourceNotFound(parsed_error.message),
                         )
                     }
@@ -5826,27 +5928,32 @@ impl UpdateServiceAccessPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::Base(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateServiceAccessPoliciesError::Base(parsed_error.message),
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::Internal(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateServiceAccessPoliciesError::Internal(parsed_error.message),
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::InvalidType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateServiceAccessPoliciesError::InvalidType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateServiceAccessPoliciesError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateServiceAccessPoliciesError::ResourceNotFound(
                                 parsed_error.message,
                             ),
@@ -6062,6 +6169,7 @@ impl CloudSearchClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6077,6 +6185,7 @@ impl CloudSearchClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchClient {
         CloudSearchClient { client, region }
     }
@@ -6090,6 +6199,9 @@ impl CloudSearch for CloudSearchClient {
         input: BuildSuggestersRequest,
     ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("BuildSuggesters");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BuildSuggesters");
         let mut params = params;
         BuildSuggestersRequestSerializer::serialize(&mut params, "", &input);
@@ -6121,6 +6233,9 @@ impl CloudSearch for CloudSearchClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("CreateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDomain");
         let mut params = params;
         CreateDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -6152,6 +6267,9 @@ impl CloudSearch for CloudSearchClient {
         input: DefineAnalysisSchemeRequest,
     ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DefineAnalysisScheme");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DefineAnalysisScheme");
         let mut params = params;
         DefineAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
@@ -6185,6 +6303,9 @@ impl CloudSearch for CloudSearchClient {
         input: DefineExpressionRequest,
     ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DefineExpression");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DefineExpression");
         let mut params = params;
         DefineExpressionRequestSerializer::serialize(&mut params, "", &input);
@@ -6216,6 +6337,9 @@ impl CloudSearch for CloudSearchClient {
         input: DefineIndexFieldRequest,
     ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DefineIndexField");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DefineIndexField");
         let mut params = params;
         DefineIndexFieldRequestSerializer::serialize(&mut params, "", &input);
@@ -6247,6 +6371,9 @@ impl CloudSearch for CloudSearchClient {
         input: DefineSuggesterRequest,
     ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DefineSuggester");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DefineSuggester");
         let mut params = params;
         DefineSuggesterRequestSerializer::serialize(&mut params, "", &input);
@@ -6278,6 +6405,9 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteAnalysisSchemeRequest,
     ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DeleteAnalysisScheme");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAnalysisScheme");
         let mut params = params;
         DeleteAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
@@ -6311,6 +6441,9 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteDomainRequest,
     ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DeleteDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDomain");
         let mut params = params;
         DeleteDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -6342,6 +6475,9 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteExpressionRequest,
     ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DeleteExpression");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteExpression");
         let mut params = params;
         DeleteExpressionRequestSerializer::serialize(&mut params, "", &input);
@@ -6373,6 +6509,9 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteIndexFieldRequest,
     ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DeleteIndexField");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteIndexField");
         let mut params = params;
         DeleteIndexFieldRequestSerializer::serialize(&mut params, "", &input);
@@ -6404,6 +6543,9 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteSuggesterRequest,
     ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DeleteSuggester");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSuggester");
         let mut params = params;
         DeleteSuggesterRequestSerializer::serialize(&mut params, "", &input);
@@ -6435,6 +6577,9 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeAnalysisSchemesRequest,
     ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeAnalysisSchemes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAnalysisSchemes");
         let mut params = params;
         DescribeAnalysisSchemesRequestSerializer::serialize(&mut params, "", &input);
@@ -6469,6 +6614,9 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeAvailabilityOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAvailabilityOptions");
         let mut params = params;
         DescribeAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6505,6 +6653,9 @@ impl CloudSearch for CloudSearchClient {
         RusotoError<DescribeDomainEndpointOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeDomainEndpointOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDomainEndpointOptions");
         let mut params = params;
         DescribeDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6538,6 +6689,9 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeDomainsRequest,
     ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDomains");
         let mut params = params;
         DescribeDomainsRequestSerializer::serialize(&mut params, "", &input);
@@ -6569,6 +6723,9 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeExpressionsRequest,
     ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeExpressions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeExpressions");
         let mut params = params;
         DescribeExpressionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6602,6 +6759,9 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeIndexFieldsRequest,
     ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeIndexFields");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeIndexFields");
         let mut params = params;
         DescribeIndexFieldsRequestSerializer::serialize(&mut params, "", &input);
@@ -6636,6 +6796,9 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeScalingParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScalingParameters");
         let mut params = params;
         DescribeScalingParametersRequestSerializer::serialize(&mut params, "", &input);
@@ -6672,6 +6835,9 @@ impl CloudSearch for CloudSearchClient {
         RusotoError<DescribeServiceAccessPoliciesError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeServiceAccessPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeServiceAccessPolicies");
         let mut params = params;
         DescribeServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -6705,6 +6871,9 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeSuggestersRequest,
     ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("DescribeSuggesters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSuggesters");
         let mut params = params;
         DescribeSuggestersRequestSerializer::serialize(&mut params, "", &input);
@@ -6738,6 +6907,9 @@ impl CloudSearch for CloudSearchClient {
         input: IndexDocumentsRequest,
     ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("IndexDocuments");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("IndexDocuments");
         let mut params = params;
         IndexDocumentsRequestSerializer::serialize(&mut params, "", &input);
@@ -6768,6 +6940,9 @@ impl CloudSearch for CloudSearchClient {
         &self,
     ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("ListDomainNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListDomainNames");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -6799,6 +6974,9 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("UpdateAvailabilityOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateAvailabilityOptions");
         let mut params = params;
         UpdateAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6833,6 +7011,9 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("UpdateDomainEndpointOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateDomainEndpointOptions");
         let mut params = params;
         UpdateDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6866,6 +7047,9 @@ impl CloudSearch for CloudSearchClient {
         input: UpdateScalingParametersRequest,
     ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("UpdateScalingParameters");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateScalingParameters");
         let mut params = params;
         UpdateScalingParametersRequestSerializer::serialize(&mut params, "", &input);
@@ -6900,6 +7084,9 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation_name("UpdateServiceAccessPolicies");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateServiceAccessPolicies");
         let mut params = params;
         UpdateServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -6927,3 +7114,337 @@ impl CloudSearch for CloudSearchClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon CloudSearch API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudSearchClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudSearchBlockingClient {
+    client: CloudSearchClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudSearchBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudSearchBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudSearchBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudSearchBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudSearchBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient {
+            client: CloudSearchClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Indexes the search suggestions. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html#configuring-suggesters">Configuring Suggesters</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::build_suggesters`.
+    pub fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
+        self.runtime.block_on(self.client.build_suggesters(input))
+    }
+
+    /// <p>Creates a new search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/creating-domains.html" target="_blank">Creating a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::create_domain`.
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// <p>Configures an analysis scheme that can be applied to a <code>text</code> or <code>text-array</code> field to define language-specific text processing options. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::define_analysis_scheme`.
+    pub fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
+        self.runtime
+            .block_on(self.client.define_analysis_scheme(input))
+    }
+
+    /// <p>Configures an <code><a>Expression</a></code> for the search domain. Used to create new expressions and modify existing ones. If the expression exists, the new configuration replaces the old one. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::define_expression`.
+    pub fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
+        self.runtime.block_on(self.client.define_expression(input))
+    }
+
+    /// <p>Configures an <code><a>IndexField</a></code> for the search domain. Used to create new fields and modify existing ones. You must specify the name of the domain you are configuring and an index field configuration. The index field configuration specifies a unique name, the index field type, and the options you want to configure for the field. The options you can specify depend on the <code><a>IndexFieldType</a></code>. If the field exists, the new configuration replaces the old one. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    ///
+    /// See `CloudSearch::define_index_field`.
+    pub fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
+        self.runtime.block_on(self.client.define_index_field(input))
+    }
+
+    /// <p>Configures a suggester for a domain. A suggester enables you to display possible matches before users finish typing their queries. When you configure a suggester, you must specify the name of the text field you want to search for possible matches and a unique name for the suggester. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::define_suggester`.
+    pub fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
+        self.runtime.block_on(self.client.define_suggester(input))
+    }
+
+    /// <p>Deletes an analysis scheme. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    ///
+    /// See `CloudSearch::delete_analysis_scheme`.
+    pub fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
+        self.runtime
+            .block_on(self.client.delete_analysis_scheme(input))
+    }
+
+    /// <p>Permanently deletes a search domain and all of its data. Once a domain has been deleted, it cannot be recovered. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/deleting-domains.html" target="_blank">Deleting a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    ///
+    /// See `CloudSearch::delete_domain`.
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// <p>Removes an <code><a>Expression</a></code> from the search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::delete_expression`.
+    pub fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
+        self.runtime.block_on(self.client.delete_expression(input))
+    }
+
+    /// <p>Removes an <code><a>IndexField</a></code> from the search domain. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::delete_index_field`.
+    pub fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
+        self.runtime.block_on(self.client.delete_index_field(input))
+    }
+
+    /// <p>Deletes a suggester. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::delete_suggester`.
+    pub fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
+        self.runtime.block_on(self.client.delete_suggester(input))
+    }
+
+    /// <p>Gets the analysis schemes configured for a domain. An analysis scheme defines language-specific text processing options for a <code>text</code> field. Can be limited to specific analysis schemes by name. By default, shows all analysis schemes and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-analysis-schemes.html" target="_blank">Configuring Analysis Schemes</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_analysis_schemes`.
+    pub fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
+        self.runtime
+            .block_on(self.client.describe_analysis_schemes(input))
+    }
+
+    /// <p>Gets the availability options configured for a domain. By default, shows the configuration with any pending changes. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-availability-options.html" target="_blank">Configuring Availability Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_availability_options`.
+    pub fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_availability_options(input))
+    }
+
+    /// <p>Returns the domain's endpoint options, specifically whether all requests to the domain must arrive over HTTPS. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-domain-endpoint-options.html" target="_blank">Configuring Domain Endpoint Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_domain_endpoint_options`.
+    pub fn describe_domain_endpoint_options(
+        &self,
+        input: DescribeDomainEndpointOptionsRequest,
+    ) -> Result<
+        DescribeDomainEndpointOptionsResponse,
+        RusotoError<DescribeDomainEndpointOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_domain_endpoint_options(input))
+    }
+
+    /// <p>Gets information about the search domains owned by this account. Can be limited to specific domains. Shows all domains by default. To get the number of searchable documents in a domain, use the console or submit a <code>matchall</code> request to your domain's search endpoint: <code>q=matchall&amp;amp;q.parser=structured&amp;amp;size=0</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-domain-info.html" target="_blank">Getting Information about a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_domains`.
+    pub fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
+        self.runtime.block_on(self.client.describe_domains(input))
+    }
+
+    /// <p>Gets the expressions configured for the search domain. Can be limited to specific expressions by name. By default, shows all expressions and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-expressions.html" target="_blank">Configuring Expressions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_expressions`.
+    pub fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
+        self.runtime
+            .block_on(self.client.describe_expressions(input))
+    }
+
+    /// <p>Gets information about the index fields configured for the search domain. Can be limited to specific fields by name. By default, shows all fields and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-domain-info.html" target="_blank">Getting Domain Information</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_index_fields`.
+    pub fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
+        self.runtime
+            .block_on(self.client.describe_index_fields(input))
+    }
+
+    /// <p>Gets the scaling parameters configured for a domain. A domain's scaling parameters specify the desired search instance type and replication count. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-scaling-options.html" target="_blank">Configuring Scaling Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_scaling_parameters`.
+    pub fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scaling_parameters(input))
+    }
+
+    /// <p>Gets information about the access policies that control access to the domain's document and search endpoints. By default, shows the configuration with any pending changes. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-access.html" target="_blank">Configuring Access for a Search Domain</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_service_access_policies`.
+    pub fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> Result<
+        DescribeServiceAccessPoliciesResponse,
+        RusotoError<DescribeServiceAccessPoliciesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_service_access_policies(input))
+    }
+
+    /// <p>Gets the suggesters configured for a domain. A suggester enables you to display possible matches before users finish typing their queries. Can be limited to specific suggesters by name. By default, shows all suggesters and includes any pending changes to the configuration. Set the <code>Deployed</code> option to <code>true</code> to show the active configuration and exclude pending changes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/getting-suggestions.html" target="_blank">Getting Search Suggestions</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::describe_suggesters`.
+    pub fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
+        self.runtime
+            .block_on(self.client.describe_suggesters(input))
+    }
+
+    /// <p>Tells the search domain to start indexing its documents using the latest indexing options. This operation must be invoked to activate options whose <a>OptionStatus</a> is <code>RequiresIndexDocuments</code>.</p>
+    ///
+    /// See `CloudSearch::index_documents`.
+    pub fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
+        self.runtime.block_on(self.client.index_documents(input))
+    }
+
+    /// <p>Lists all search domains owned by an account.</p>
+    ///
+    /// See `CloudSearch::list_domain_names`.
+    pub fn list_domain_names(
+        &self,
+    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        self.runtime.block_on(self.client.list_domain_names())
+    }
+
+    /// <p>Configures the availability options for a domain. Enabling the Multi-AZ option expands an Amazon CloudSearch domain to an additional Availability Zone in the same Region to increase fault tolerance in the event of a service disruption. Changes to the Multi-AZ option can take about half an hour to become active. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-availability-options.html" target="_blank">Configuring Availability Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::update_availability_options`.
+    pub fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_availability_options(input))
+    }
+
+    /// <p>Updates the domain's endpoint options, specifically whether all requests to the domain must arrive over HTTPS. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-domain-endpoint-options.html" target="_blank">Configuring Domain Endpoint Options</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+    ///
+    /// See `CloudSearch::update_domain_endpoint_options`.
+    pub fn update_domain_endpoint_options(
+        &self,
+        input: UpdateDomainEndpointOptionsRequest,
+    ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_domain_endpoint_options(input))
+    }
+
+    /// <p>Configures scaling parameters for a domain. A domain's scaling parameters specify the desired search instance type and replication count. Amazon CloudSearch will still automatically scale your domain based on the volume of data and traffic, but not below the desired instance type and replication count. If the Multi-AZ option is enabled, these values control the resources used per Availability Zone. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-scaling-options.html" target="_blank">Configuring Scaling Options</a> in the <i>Amazon CloudSearch Developer Guide</i>. </p>
+    ///
+    /// See `CloudSearch::update_scaling_parameters`.
+    pub fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
+        self.runtime
+            .block_on(self.client.update_scaling_parameters(input))
+    }
+
+    /// <p>Configures the access rules that control access to the domain's document and search endpoints. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-access.html" target="_blank"> Configuring Access for an Amazon CloudSearch Domain</a>.</p>
+    ///
+    /// See `CloudSearch::update_service_access_policies`.
+    pub fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
+    {
+        self.runtime
+            .block_on(self.client.update_service_access_policies(input))
+    }
+}
@@ -950,33 +950,37 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(AddTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service(&res, AddTagsError::CloudTrailARNInvalid(err.msg))
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InvalidTagParameter(err.msg))
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InvalidTrailName(err.msg))
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(AddTagsError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(AddTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(&res, AddTagsError::OperationNotPermitted(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AddTagsError::ResourceNotFound(err.msg))
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(AddTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsError::ResourceTypeNotSupported(err.msg),
+                    )
                 }
                 "TagsLimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::TagsLimitExceeded(err.msg))
+                    return RusotoError::service(&res, AddTagsError::TagsLimitExceeded(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(&res, AddTagsError::UnsupportedOperation(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1066,107 +1070,145 @@ impl CreateTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailAccessNotEnabledException" => {
-                    return RusotoError::Service(CreateTrailError::CloudTrailAccessNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::CloudTrailAccessNotEnabled(err.msg),
+                    )
                 }
                 "CloudWatchLogsDeliveryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTrailError::CloudWatchLogsDeliveryUnavailable(err.msg),
                     )
                 }
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
                     )
                 }
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientEncryptionPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InsufficientEncryptionPolicy(err.msg),
+                    )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientS3BucketPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InsufficientS3BucketPolicy(err.msg),
+                    )
                 }
                 "InsufficientSnsTopicPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientSnsTopicPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InsufficientSnsTopicPolicy(err.msg),
+                    )
                 }
                 "InvalidCloudWatchLogsLogGroupArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTrailError::InvalidCloudWatchLogsLogGroupArn(err.msg),
                     )
                 }
                 "InvalidCloudWatchLogsRoleArnException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidCloudWatchLogsRoleArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InvalidCloudWatchLogsRoleArn(err.msg),
+                    )
                 }
                 "InvalidKmsKeyIdException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidKmsKeyId(err.msg))
+                    return RusotoError::service(&res, CreateTrailError::InvalidKmsKeyId(err.msg))
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidS3BucketNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidS3BucketName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InvalidS3BucketName(err.msg),
+                    )
                 }
                 "InvalidS3PrefixException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidS3Prefix(err.msg))
+                    return RusotoError::service(&res, CreateTrailError::InvalidS3Prefix(err.msg))
                 }
                 "InvalidSnsTopicNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidSnsTopicName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InvalidSnsTopicName(err.msg),
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::InvalidTagParameter(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, CreateTrailError::InvalidTrailName(err.msg))
+                }
+                "KmsException" => {
+                    return RusotoError::service(&res, CreateTrailError::Kms(err.msg))
                 }
-                "KmsException" => return RusotoError::Service(CreateTrailError::Kms(err.msg)),
                 "KmsKeyDisabledException" => {
-                    return RusotoError::Service(CreateTrailError::KmsKeyDisabled(err.msg))
+                    return RusotoError::service(&res, CreateTrailError::KmsKeyDisabled(err.msg))
                 }
                 "KmsKeyNotFoundException" => {
-                    return RusotoError::Service(CreateTrailError::KmsKeyNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTrailError::KmsKeyNotFound(err.msg))
                 }
                 "MaximumNumberOfTrailsExceededException" => {
-                    return RusotoError::Service(CreateTrailError::MaximumNumberOfTrailsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::MaximumNumberOfTrailsExceeded(err.msg),
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(CreateTrailError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTrailError::OrganizationNotInAllFeaturesMode(err.msg),
                     )
                 }
                 "OrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreateTrailError::OrganizationsNotInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::OrganizationsNotInUse(err.msg),
+                    )
                 }
                 "S3BucketDoesNotExistException" => {
-                    return RusotoError::Service(CreateTrailError::S3BucketDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::S3BucketDoesNotExist(err.msg),
+                    )
                 }
                 "TrailAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTrailError::TrailAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::TrailAlreadyExists(err.msg),
+                    )
                 }
                 "TrailNotProvidedException" => {
-                    return RusotoError::Service(CreateTrailError::TrailNotProvided(err.msg))
+                    return RusotoError::service(&res, CreateTrailError::TrailNotProvided(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrailError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1238,29 +1280,37 @@ impl DeleteTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(DeleteTrailError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(&res, DeleteTrailError::InvalidHomeRegion(err.msg))
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(DeleteTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, DeleteTrailError::InvalidTrailName(err.msg))
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(DeleteTrailError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTrailError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTrailError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(DeleteTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTrailError::TrailNotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTrailError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1302,15 +1352,22 @@ impl DescribeTrailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(DescribeTrailsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrailsError::InvalidTrailName(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DescribeTrailsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrailsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeTrailsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrailsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1348,20 +1405,28 @@ impl GetEventSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetEventSelectorsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSelectorsError::InvalidTrailName(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetEventSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSelectorsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetEventSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSelectorsError::TrailNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetEventSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSelectorsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1402,27 +1467,34 @@ impl GetInsightSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsightNotEnabledException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::InsightNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightSelectorsError::InsightNotEnabled(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::InvalidTrailName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightSelectorsError::InvalidTrailName(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightSelectorsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightSelectorsError::TrailNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightSelectorsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1462,16 +1534,19 @@ impl GetTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, GetTrailError::InvalidTrailName(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTrailError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service(&res, GetTrailError::TrailNotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(&res, GetTrailError::UnsupportedOperation(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1510,18 +1585,25 @@ impl GetTrailStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetTrailStatusError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTrailStatusError::InvalidTrailName(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetTrailStatusError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTrailStatusError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetTrailStatusError::TrailNotFound(err.msg))
+                    return RusotoError::service(&res, GetTrailStatusError::TrailNotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetTrailStatusError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTrailStatusError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1560,18 +1642,25 @@ impl ListPublicKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(ListPublicKeysError::InvalidTimeRange(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPublicKeysError::InvalidTimeRange(err.msg),
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ListPublicKeysError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, ListPublicKeysError::InvalidToken(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListPublicKeysError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublicKeysError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListPublicKeysError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPublicKeysError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1616,25 +1705,31 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(ListTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service(&res, ListTagsError::CloudTrailARNInvalid(err.msg))
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ListTagsError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, ListTagsError::InvalidToken(err.msg))
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(ListTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, ListTagsError::InvalidTrailName(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ResourceNotFound(err.msg))
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(ListTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::ResourceTypeNotSupported(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(&res, ListTagsError::UnsupportedOperation(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1672,10 +1767,16 @@ impl ListTrailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListTrailsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTrailsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListTrailsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTrailsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1718,27 +1819,40 @@ impl LookupEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidEventCategoryException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidEventCategory(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LookupEventsError::InvalidEventCategory(err.msg),
+                    )
                 }
                 "InvalidLookupAttributesException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidLookupAttributes(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupEventsError::InvalidLookupAttributes(err.msg),
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidMaxResults(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LookupEventsError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, LookupEventsError::InvalidNextToken(err.msg))
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidTimeRange(err.msg))
+                    return RusotoError::service(&res, LookupEventsError::InvalidTimeRange(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(LookupEventsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LookupEventsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(LookupEventsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LookupEventsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1788,40 +1902,54 @@ impl PutEventSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEventSelectorsError::InsufficientDependencyServiceAccessPermission(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidEventSelectorsException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidEventSelectors(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEventSelectorsError::InvalidEventSelectors(err.msg),
+                    )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEventSelectorsError::InvalidHomeRegion(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEventSelectorsError::InvalidTrailName(err.msg),
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEventSelectorsError::NotOrganizationMasterAccount(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(PutEventSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEventSelectorsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(PutEventSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEventSelectorsError::TrailNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutEventSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEventSelectorsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1878,47 +2006,58 @@ impl PutInsightSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInsightSelectorsError::InsufficientEncryptionPolicy(err.msg),
                     )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInsightSelectorsError::InsufficientS3BucketPolicy(err.msg),
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidHomeRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInsightSelectorsError::InvalidHomeRegion(err.msg),
+                    )
                 }
                 "InvalidInsightSelectorsException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidInsightSelectors(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInsightSelectorsError::InvalidInsightSelectors(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidTrailName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInsightSelectorsError::InvalidTrailName(err.msg),
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInsightSelectorsError::NotOrganizationMasterAccount(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInsightSelectorsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInsightSelectorsError::TrailNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInsightSelectorsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1976,30 +2115,46 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(RemoveTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::CloudTrailARNInvalid(err.msg),
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::InvalidTagParameter(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::InvalidTrailName(err.msg))
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(RemoveTagsError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(RemoveTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::ResourceNotFound(err.msg))
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(RemoveTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::ResourceTypeNotSupported(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RemoveTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2048,29 +2203,40 @@ impl StartLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLoggingError::InsufficientDependencyServiceAccessPermission(err.msg),
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(StartLoggingError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLoggingError::InvalidHomeRegion(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(StartLoggingError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, StartLoggingError::InvalidTrailName(err.msg))
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(StartLoggingError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartLoggingError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartLoggingError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLoggingError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(StartLoggingError::TrailNotFound(err.msg))
+                    return RusotoError::service(&res, StartLoggingError::TrailNotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StartLoggingError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLoggingError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2120,29 +2286,37 @@ impl StopLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopLoggingError::InsufficientDependencyServiceAccessPermission(err.msg),
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(StopLoggingError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(&res, StopLoggingError::InvalidHomeRegion(err.msg))
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(StopLoggingError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, StopLoggingError::InvalidTrailName(err.msg))
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(StopLoggingError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopLoggingError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopLoggingError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopLoggingError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(StopLoggingError::TrailNotFound(err.msg))
+                    return RusotoError::service(&res, StopLoggingError::TrailNotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StopLoggingError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopLoggingError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2232,105 +2406,139 @@ impl UpdateTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailAccessNotEnabledException" => {
-                    return RusotoError::Service(UpdateTrailError::CloudTrailAccessNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::CloudTrailAccessNotEnabled(err.msg),
+                    )
                 }
                 "CloudWatchLogsDeliveryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTrailError::CloudWatchLogsDeliveryUnavailable(err.msg),
                     )
                 }
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
                     )
                 }
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientEncryptionPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InsufficientEncryptionPolicy(err.msg),
+                    )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientS3BucketPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InsufficientS3BucketPolicy(err.msg),
+                    )
                 }
                 "InsufficientSnsTopicPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientSnsTopicPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InsufficientSnsTopicPolicy(err.msg),
+                    )
                 }
                 "InvalidCloudWatchLogsLogGroupArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTrailError::InvalidCloudWatchLogsLogGroupArn(err.msg),
                     )
                 }
                 "InvalidCloudWatchLogsRoleArnException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidCloudWatchLogsRoleArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InvalidCloudWatchLogsRoleArn(err.msg),
+                    )
                 }
                 "InvalidEventSelectorsException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidEventSelectors(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InvalidEventSelectors(err.msg),
+                    )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::InvalidHomeRegion(err.msg))
                 }
                 "InvalidKmsKeyIdException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidKmsKeyId(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::InvalidKmsKeyId(err.msg))
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidS3BucketNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidS3BucketName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InvalidS3BucketName(err.msg),
+                    )
                 }
                 "InvalidS3PrefixException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidS3Prefix(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::InvalidS3Prefix(err.msg))
                 }
                 "InvalidSnsTopicNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidSnsTopicName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::InvalidSnsTopicName(err.msg),
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::InvalidTrailName(err.msg))
+                }
+                "KmsException" => {
+                    return RusotoError::service(&res, UpdateTrailError::Kms(err.msg))
                 }
-                "KmsException" => return RusotoError::Service(UpdateTrailError::Kms(err.msg)),
                 "KmsKeyDisabledException" => {
-                    return RusotoError::Service(UpdateTrailError::KmsKeyDisabled(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::KmsKeyDisabled(err.msg))
                 }
                 "KmsKeyNotFoundException" => {
-                    return RusotoError::Service(UpdateTrailError::KmsKeyNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::KmsKeyNotFound(err.msg))
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(UpdateTrailError::NotOrganizationMasterAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::NotOrganizationMasterAccount(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTrailError::OrganizationNotInAllFeaturesMode(err.msg),
                     )
                 }
                 "OrganizationsNotInUseException" => {
-                    return RusotoError::Service(UpdateTrailError::OrganizationsNotInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::OrganizationsNotInUse(err.msg),
+                    )
                 }
                 "S3BucketDoesNotExistException" => {
-                    return RusotoError::Service(UpdateTrailError::S3BucketDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::S3BucketDoesNotExist(err.msg),
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(UpdateTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::TrailNotFound(err.msg))
                 }
                 "TrailNotProvidedException" => {
-                    return RusotoError::Service(UpdateTrailError::TrailNotProvided(err.msg))
+                    return RusotoError::service(&res, UpdateTrailError::TrailNotProvided(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrailError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2507,6 +2715,7 @@ impl CloudTrailClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2522,6 +2731,7 @@ impl CloudTrailClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudTrailClient {
         CloudTrailClient { client, region }
     }
@@ -2535,6 +2745,9 @@ impl CloudTrail for CloudTrailClient {
         input: AddTagsRequest,
     ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.AddTags",
@@ -2556,6 +2769,9 @@ impl CloudTrail for CloudTrailClient {
         input: CreateTrailRequest,
     ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTrail");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.CreateTrail",
@@ -2577,6 +2793,9 @@ impl CloudTrail for CloudTrailClient {
         input: DeleteTrailRequest,
     ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTrail");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.DeleteTrail",
@@ -2598,6 +2817,9 @@ impl CloudTrail for CloudTrailClient {
         input: DescribeTrailsRequest,
     ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.DescribeTrails",
@@ -2619,6 +2841,9 @@ impl CloudTrail for CloudTrailClient {
         input: GetEventSelectorsRequest,
     ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEventSelectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetEventSelectors",
@@ -2640,6 +2865,9 @@ impl CloudTrail for CloudTrailClient {
         input: GetInsightSelectorsRequest,
     ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInsightSelectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetInsightSelectors",
@@ -2661,6 +2889,9 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailRequest,
     ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTrail");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetTrail",
@@ -2682,6 +2913,9 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailStatusRequest,
     ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTrailStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetTrailStatus",
@@ -2703,6 +2937,9 @@ impl CloudTrail for CloudTrailClient {
         input: ListPublicKeysRequest,
     ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPublicKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.ListPublicKeys",
@@ -2724,6 +2961,9 @@ impl CloudTrail for CloudTrailClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.ListTags",
@@ -2745,6 +2985,9 @@ impl CloudTrail for CloudTrailClient {
         input: ListTrailsRequest,
     ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTrails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.ListTrails",
@@ -2766,6 +3009,9 @@ impl CloudTrail for CloudTrailClient {
         input: LookupEventsRequest,
     ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("LookupEvents");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.LookupEvents",
@@ -2787,6 +3033,9 @@ impl CloudTrail for CloudTrailClient {
         input: PutEventSelectorsRequest,
     ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutEventSelectors");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.PutEventSelectors",
@@ -2808,6 +3057,9 @@ impl CloudTrail for CloudTrailClient {
         input: PutInsightSelectorsRequest,
     ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutInsightSelectors");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.PutInsightSelectors",
@@ -2829,6 +3081,9 @@ impl CloudTrail for CloudTrailClient {
         input: RemoveTagsRequest,
     ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTags");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.RemoveTags",
@@ -2850,6 +3105,9 @@ impl CloudTrail for CloudTrailClient {
         input: StartLoggingRequest,
     ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartLogging");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.StartLogging",
@@ -2871,6 +3129,9 @@ impl CloudTrail for CloudTrailClient {
         input: StopLoggingRequest,
     ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopLogging");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.StopLogging",
@@ -2892,6 +3153,9 @@ impl CloudTrail for CloudTrailClient {
         input: UpdateTrailRequest,
     ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTrail");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.UpdateTrail",
@@ -2907,3 +3171,237 @@ impl CloudTrail for CloudTrailClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrailResponse, _>()
     }
 }
+
+/// A blocking client for the CloudTrail API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudTrailClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudTrailBlockingClient {
+    client: CloudTrailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudTrailBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudTrailBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudTrailBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudTrailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudTrailBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient {
+            client: CloudTrailClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds one or more tags to a trail, up to a limit of 50. Overwrites an existing tag's value when a new value is specified for an existing tag key. Tag key names must be unique for a trail; you cannot have two keys with the same name but different values. If you specify a key without a value, the tag will be created with the specified key and a value of null. You can tag a trail that applies to all AWS Regions only from the Region in which the trail was created (also known as its home region).</p>
+    ///
+    /// See `CloudTrail::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsRequest,
+    ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Creates a trail that specifies the settings for delivery of log data to an Amazon S3 bucket. </p>
+    ///
+    /// See `CloudTrail::create_trail`.
+    pub fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
+        self.runtime.block_on(self.client.create_trail(input))
+    }
+
+    /// <p>Deletes a trail. This operation must be called from the region in which the trail was created. <code>DeleteTrail</code> cannot be called on the shadow trails (replicated trails in other regions) of a trail that is enabled in all regions.</p>
+    ///
+    /// See `CloudTrail::delete_trail`.
+    pub fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
+        self.runtime.block_on(self.client.delete_trail(input))
+    }
+
+    /// <p>Retrieves settings for one or more trails associated with the current region for your account.</p>
+    ///
+    /// See `CloudTrail::describe_trails`.
+    pub fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
+        self.runtime.block_on(self.client.describe_trails(input))
+    }
+
+    /// <p>Describes the settings for the event selectors that you configured for your trail. The information returned for your event selectors includes the following:</p> <ul> <li> <p>If your event selector includes read-only events, write-only events, or all events. This applies to both management events and data events.</p> </li> <li> <p>If your event selector includes management events.</p> </li> <li> <p>If your event selector includes data events, the Amazon S3 objects or AWS Lambda functions that you are logging for data events.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-management-and-data-events-with-cloudtrail.html">Logging Data and Management Events for Trails </a> in the <i>AWS CloudTrail User Guide</i>.</p>
+    ///
+    /// See `CloudTrail::get_event_selectors`.
+    pub fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
+        self.runtime
+            .block_on(self.client.get_event_selectors(input))
+    }
+
+    /// <p>Describes the settings for the Insights event selectors that you configured for your trail. <code>GetInsightSelectors</code> shows if CloudTrail Insights event logging is enabled on the trail, and if it is, which insight types are enabled. If you run <code>GetInsightSelectors</code> on a trail that does not have Insights events enabled, the operation throws the exception <code>InsightNotEnabledException</code> </p> <p>For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-insights-events-with-cloudtrail.html">Logging CloudTrail Insights Events for Trails </a> in the <i>AWS CloudTrail User Guide</i>.</p>
+    ///
+    /// See `CloudTrail::get_insight_selectors`.
+    pub fn get_insight_selectors(
+        &self,
+        input: GetInsightSelectorsRequest,
+    ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
+        self.runtime
+            .block_on(self.client.get_insight_selectors(input))
+    }
+
+    /// <p>Returns settings information for a specified trail.</p>
+    ///
+    /// See `CloudTrail::get_trail`.
+    pub fn get_trail(
+        &self,
+        input: GetTrailRequest,
+    ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
+        self.runtime.block_on(self.client.get_trail(input))
+    }
+
+    /// <p>Returns a JSON-formatted list of information about the specified trail. Fields include information on delivery errors, Amazon SNS and Amazon S3 errors, and start and stop logging times for each trail. This operation returns trail status from a single region. To return trail status from all regions, you must call the operation on each region.</p>
+    ///
+    /// See `CloudTrail::get_trail_status`.
+    pub fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
+        self.runtime.block_on(self.client.get_trail_status(input))
+    }
+
+    /// <p><p>Returns all public keys whose private keys were used to sign the digest files within the specified time range. The public key is needed to validate digest files that were signed with its corresponding private key.</p> <note> <p>CloudTrail uses different private/public key pairs per region. Each digest file is signed with a private key unique to its region. Therefore, when you validate a digest file from a particular region, you must look in the same region for its corresponding public key.</p> </note></p>
+    ///
+    /// See `CloudTrail::list_public_keys`.
+    pub fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
+        self.runtime.block_on(self.client.list_public_keys(input))
+    }
+
+    /// <p>Lists the tags for the trail in the current region.</p>
+    ///
+    /// See `CloudTrail::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Lists trails that are in the current account.</p>
+    ///
+    /// See `CloudTrail::list_trails`.
+    pub fn list_trails(
+        &self,
+        input: ListTrailsRequest,
+    ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
+        self.runtime.block_on(self.client.list_trails(input))
+    }
+
+    /// <p><p>Looks up <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/cloudtrail-concepts.html#cloudtrail-concepts-management-events">management events</a> or <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/cloudtrail-concepts.html#cloudtrail-concepts-insights-events">CloudTrail Insights events</a> that are captured by CloudTrail. You can look up events that occurred in a region within the last 90 days. Lookup supports the following attributes for management events:</p> <ul> <li> <p>AWS access key</p> </li> <li> <p>Event ID</p> </li> <li> <p>Event name</p> </li> <li> <p>Event source</p> </li> <li> <p>Read only</p> </li> <li> <p>Resource name</p> </li> <li> <p>Resource type</p> </li> <li> <p>User name</p> </li> </ul> <p>Lookup supports the following attributes for Insights events:</p> <ul> <li> <p>Event ID</p> </li> <li> <p>Event name</p> </li> <li> <p>Event source</p> </li> </ul> <p>All attributes are optional. The default number of results returned is 50, with a maximum of 50 possible. The response includes a token that you can use to get the next page of results.</p> <important> <p>The rate of lookup requests is limited to two per second per account. If this limit is exceeded, a throttling error occurs.</p> </important></p>
+    ///
+    /// See `CloudTrail::lookup_events`.
+    pub fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
+        self.runtime.block_on(self.client.lookup_events(input))
+    }
+
+    /// <p>Configures an event selector for your trail. Use event selectors to further specify the management and data event settings for your trail. By default, trails created without specific event selectors will be configured to log all read and write management events, and no data events. </p> <p>When an event occurs in your account, CloudTrail evaluates the event selectors in all trails. For each trail, if the event matches any event selector, the trail processes and logs the event. If the event doesn't match any event selector, the trail doesn't log the event. </p> <p>Example</p> <ol> <li> <p>You create an event selector for a trail and specify that you want write-only events.</p> </li> <li> <p>The EC2 <code>GetConsoleOutput</code> and <code>RunInstances</code> API operations occur in your account.</p> </li> <li> <p>CloudTrail evaluates whether the events match your event selectors.</p> </li> <li> <p>The <code>RunInstances</code> is a write-only event and it matches your event selector. The trail logs the event.</p> </li> <li> <p>The <code>GetConsoleOutput</code> is a read-only event but it doesn't match your event selector. The trail doesn't log the event. </p> </li> </ol> <p>The <code>PutEventSelectors</code> operation must be called from the region in which the trail was created; otherwise, an <code>InvalidHomeRegionException</code> is thrown.</p> <p>You can configure up to five event selectors for each trail. For more information, see <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/logging-management-and-data-events-with-cloudtrail.html">Logging Data and Management Events for Trails </a> and <a href="https://docs.aws.amazon.com/awscloudtrail/latest/userguide/WhatIsCloudTrail-Limits.html">Limits in AWS CloudTrail</a> in the <i>AWS CloudTrail User Guide</i>.</p>
+    ///
+    /// See `CloudTrail::put_event_selectors`.
+    pub fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
+        self.runtime
+            .block_on(self.client.put_event_selectors(input))
+    }
+
+    /// <p>Lets you enable Insights event logging by specifying the Insights selectors that you want to enable on an existing trail. You also use <code>PutInsightSelectors</code> to turn off Insights event logging, by passing an empty list of insight types. In this release, only <code>ApiCallRateInsight</code> is supported as an Insights selector.</p>
+    ///
+    /// See `CloudTrail::put_insight_selectors`.
+    pub fn put_insight_selectors(
+        &self,
+        input: PutInsightSelectorsRequest,
+    ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
+        self.runtime
+            .block_on(self.client.put_insight_selectors(input))
+    }
+
+    /// <p>Removes the specified tags from a trail.</p>
+    ///
+    /// See `CloudTrail::remove_tags`.
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// <p>Starts the recording of AWS API calls and log file delivery for a trail. For a trail that is enabled in all regions, this operation must be called from the region in which the trail was created. This operation cannot be called on the shadow trails (replicated trails in other regions) of a trail that is enabled in all regions.</p>
+    ///
+    /// See `CloudTrail::start_logging`.
+    pub fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
+        self.runtime.block_on(self.client.start_logging(input))
+    }
+
+    /// <p>Suspends the recording of AWS API calls and log file delivery for the specified trail. Under most circumstances, there is no need to use this action. You can update a trail without stopping it first. This action is the only way to stop recording. For a trail enabled in all regions, this operation must be called from the region in which the trail was created, or an <code>InvalidHomeRegionException</code> will occur. This operation cannot be called on the shadow trails (replicated trails in other regions) of a trail enabled in all regions.</p>
+    ///
+    /// See `CloudTrail::stop_logging`.
+    pub fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
+        self.runtime.block_on(self.client.stop_logging(input))
+    }
+
+    /// <p>Updates the settings that specify delivery of log files. Changes to a trail do not require stopping the CloudTrail service. Use this action to designate an existing bucket for log delivery. If the existing bucket has previously been a target for CloudTrail log files, an IAM policy exists for the bucket. <code>UpdateTrail</code> must be called from the region in which the trail was created; otherwise, an <code>InvalidHomeRegionException</code> is thrown.</p>
+    ///
+    /// See `CloudTrail::update_trail`.
+    pub fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
+        self.runtime.block_on(self.client.update_trail(input))
+    }
+}
@@ -4729,9 +4729,10 @@ impl DeleteAlarmsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteAlarmsError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAlarmsError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4779,26 +4780,30 @@ impl DeleteAnomalyDetectorError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAnomalyDetectorError::InternalServiceFault(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAnomalyDetectorError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(DeleteAnomalyDetectorError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAnomalyDetectorError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4849,19 +4854,22 @@ impl DeleteDashboardsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteDashboardsError::DashboardNotFoundError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDashboardsError::DashboardNotFoundError(parsed_error.message),
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(DeleteDashboardsError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDashboardsError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteDashboardsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDashboardsError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4907,12 +4915,14 @@ impl DeleteInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteInsightRulesError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteInsightRulesError::MissingRequiredParameter(parsed_error.message),
                         )
                     }
@@ -4957,9 +4967,10 @@ impl DescribeAlarmHistoryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeAlarmHistoryError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAlarmHistoryError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5001,9 +5012,10 @@ impl DescribeAlarmsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeAlarmsError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeAlarmsError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5083,19 +5095,22 @@ impl DescribeAnomalyDetectorsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAnomalyDetectorsError::InternalServiceFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAnomalyDetectorsError::InvalidNextToken(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAnomalyDetectorsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -5147,9 +5162,10 @@ impl DescribeInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeInsightRulesError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeInsightRulesError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5227,12 +5243,14 @@ impl DisableInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableInsightRulesError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableInsightRulesError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
@@ -5317,17 +5335,20 @@ impl EnableInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableInsightRulesError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(EnableInsightRulesError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableInsightRulesError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableInsightRulesError::MissingRequiredParameter(parsed_error.message),
                         )
                     }
@@ -5377,19 +5398,22 @@ impl GetDashboardError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(GetDashboardError::DashboardNotFoundError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetDashboardError::DashboardNotFoundError(parsed_error.message),
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(GetDashboardError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetDashboardError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(GetDashboardError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetDashboardError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5437,21 +5461,24 @@ impl GetInsightRuleReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetInsightRuleReportError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetInsightRuleReportError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(GetInsightRuleReportError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetInsightRuleReportError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5497,9 +5524,10 @@ impl GetMetricDataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(GetMetricDataError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetMetricDataError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5547,24 +5575,28 @@ impl GetMetricStatisticsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetMetricStatisticsError::InternalServiceFault(parsed_error.message),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetMetricStatisticsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetMetricStatisticsError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetMetricStatisticsError::MissingRequiredParameter(
                                 parsed_error.message,
                             ),
@@ -5651,14 +5683,16 @@ impl ListDashboardsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(ListDashboardsError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListDashboardsError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListDashboardsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListDashboardsError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5703,14 +5737,16 @@ impl ListMetricsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(ListMetricsError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListMetricsError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListMetricsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListMetricsError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5757,19 +5793,22 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::InternalServiceFault(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5819,22 +5858,26 @@ impl PutAnomalyDetectorError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutAnomalyDetectorError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAnomalyDetectorError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(PutAnomalyDetectorError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAnomalyDetectorError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutAnomalyDetectorError::MissingRequiredParameter(parsed_error.message),
                         )
                     }
@@ -5881,9 +5924,10 @@ impl PutCompositeAlarmError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutCompositeAlarmError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutCompositeAlarmError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5927,14 +5971,16 @@ impl PutDashboardError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterInput" => {
-                        return RusotoError::Service(PutDashboardError::DashboardInvalidInputError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutDashboardError::DashboardInvalidInputError(parsed_error.message),
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutDashboardError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutDashboardError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5981,19 +6027,22 @@ impl PutInsightRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutInsightRuleError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutInsightRuleError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(PutInsightRuleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutInsightRuleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutInsightRuleError::MissingRequiredParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutInsightRuleError::MissingRequiredParameter(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6037,9 +6086,10 @@ impl PutMetricAlarmError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutMetricAlarmError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutMetricAlarmError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6087,24 +6137,28 @@ impl PutMetricDataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutMetricDataError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutMetricDataError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutMetricDataError::InvalidParameterCombination(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutMetricDataError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutMetricDataError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutMetricDataError::MissingRequiredParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutMetricDataError::MissingRequiredParameter(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6151,14 +6205,16 @@ impl SetAlarmStateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidFormat" => {
-                        return RusotoError::Service(SetAlarmStateError::InvalidFormatFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetAlarmStateError::InvalidFormatFault(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(SetAlarmStateError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetAlarmStateError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6207,24 +6263,28 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
-                        return RusotoError::Service(TagResourceError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(TagResourceError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(TagResourceError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(TagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6275,24 +6335,28 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
-                        return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(UntagResourceError::InternalServiceFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::InternalServiceFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(UntagResourceError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(UntagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6522,6 +6586,7 @@ impl CloudWatchClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6537,6 +6602,7 @@ impl CloudWatchClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchClient {
         CloudWatchClient { client, region }
     }
@@ -6550,6 +6616,9 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteAlarmsInput,
     ) -> Result<(), RusotoError<DeleteAlarmsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DeleteAlarms");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAlarms");
         let mut params = params;
         DeleteAlarmsInputSerializer::serialize(&mut params, "", &input);
@@ -6570,6 +6639,9 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteAnomalyDetectorInput,
     ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DeleteAnomalyDetector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAnomalyDetector");
         let mut params = params;
         DeleteAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
@@ -6592,6 +6664,9 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteDashboardsInput,
     ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DeleteDashboards");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDashboards");
         let mut params = params;
         DeleteDashboardsInputSerializer::serialize(&mut params, "", &input);
@@ -6614,6 +6689,9 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteInsightRulesInput,
     ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DeleteInsightRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteInsightRules");
         let mut params = params;
         DeleteInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6647,6 +6725,9 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmHistoryInput,
     ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DescribeAlarmHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAlarmHistory");
         let mut params = params;
         DescribeAlarmHistoryInputSerializer::serialize(&mut params, "", &input);
@@ -6680,6 +6761,9 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmsInput,
     ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DescribeAlarms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAlarms");
         let mut params = params;
         DescribeAlarmsInputSerializer::serialize(&mut params, "", &input);
@@ -6711,6 +6795,9 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmsForMetricInput,
     ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DescribeAlarmsForMetric");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAlarmsForMetric");
         let mut params = params;
         DescribeAlarmsForMetricInputSerializer::serialize(&mut params, "", &input);
@@ -6744,6 +6831,9 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAnomalyDetectorsInput,
     ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DescribeAnomalyDetectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAnomalyDetectors");
         let mut params = params;
         DescribeAnomalyDetectorsInputSerializer::serialize(&mut params, "", &input);
@@ -6777,6 +6867,9 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeInsightRulesInput,
     ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DescribeInsightRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInsightRules");
         let mut params = params;
         DescribeInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6810,6 +6903,9 @@ impl CloudWatch for CloudWatchClient {
         input: DisableAlarmActionsInput,
     ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DisableAlarmActions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableAlarmActions");
         let mut params = params;
         DisableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
@@ -6830,6 +6926,9 @@ impl CloudWatch for CloudWatchClient {
         input: DisableInsightRulesInput,
     ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("DisableInsightRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableInsightRules");
         let mut params = params;
         DisableInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6863,6 +6962,9 @@ impl CloudWatch for CloudWatchClient {
         input: EnableAlarmActionsInput,
     ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("EnableAlarmActions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableAlarmActions");
         let mut params = params;
         EnableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
@@ -6883,6 +6985,9 @@ impl CloudWatch for CloudWatchClient {
         input: EnableInsightRulesInput,
     ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("EnableInsightRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableInsightRules");
         let mut params = params;
         EnableInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6916,6 +7021,9 @@ impl CloudWatch for CloudWatchClient {
         input: GetDashboardInput,
     ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("GetDashboard");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetDashboard");
         let mut params = params;
         GetDashboardInputSerializer::serialize(&mut params, "", &input);
@@ -6946,6 +7054,9 @@ impl CloudWatch for CloudWatchClient {
         input: GetInsightRuleReportInput,
     ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("GetInsightRuleReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetInsightRuleReport");
         let mut params = params;
         GetInsightRuleReportInputSerializer::serialize(&mut params, "", &input);
@@ -6979,6 +7090,9 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricDataInput,
     ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("GetMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetMetricData");
         let mut params = params;
         GetMetricDataInputSerializer::serialize(&mut params, "", &input);
@@ -7010,6 +7124,9 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricStatisticsInput,
     ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("GetMetricStatistics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetMetricStatistics");
         let mut params = params;
         GetMetricStatisticsInputSerializer::serialize(&mut params, "", &input);
@@ -7043,6 +7160,9 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricWidgetImageInput,
     ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("GetMetricWidgetImage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetMetricWidgetImage");
         let mut params = params;
         GetMetricWidgetImageInputSerializer::serialize(&mut params, "", &input);
@@ -7076,6 +7196,9 @@ impl CloudWatch for CloudWatchClient {
         input: ListDashboardsInput,
     ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("ListDashboards");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListDashboards");
         let mut params = params;
         ListDashboardsInputSerializer::serialize(&mut params, "", &input);
@@ -7107,6 +7230,9 @@ impl CloudWatch for CloudWatchClient {
         input: ListMetricsInput,
     ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("ListMetrics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListMetrics");
         let mut params = params;
         ListMetricsInputSerializer::serialize(&mut params, "", &input);
@@ -7137,6 +7263,9 @@ impl CloudWatch for CloudWatchClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceInputSerializer::serialize(&mut params, "", &input);
@@ -7170,6 +7299,9 @@ impl CloudWatch for CloudWatchClient {
         input: PutAnomalyDetectorInput,
     ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("PutAnomalyDetector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutAnomalyDetector");
         let mut params = params;
         PutAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
@@ -7192,6 +7324,9 @@ impl CloudWatch for CloudWatchClient {
         input: PutCompositeAlarmInput,
     ) -> Result<(), RusotoError<PutCompositeAlarmError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("PutCompositeAlarm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutCompositeAlarm");
         let mut params = params;
         PutCompositeAlarmInputSerializer::serialize(&mut params, "", &input);
@@ -7212,6 +7347,9 @@ impl CloudWatch for CloudWatchClient {
         input: PutDashboardInput,
     ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("PutDashboard");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutDashboard");
         let mut params = params;
         PutDashboardInputSerializer::serialize(&mut params, "", &input);
@@ -7242,6 +7380,9 @@ impl CloudWatch for CloudWatchClient {
         input: PutInsightRuleInput,
     ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("PutInsightRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutInsightRule");
         let mut params = params;
         PutInsightRuleInputSerializer::serialize(&mut params, "", &input);
@@ -7264,6 +7405,9 @@ impl CloudWatch for CloudWatchClient {
         input: PutMetricAlarmInput,
     ) -> Result<(), RusotoError<PutMetricAlarmError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("PutMetricAlarm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutMetricAlarm");
         let mut params = params;
         PutMetricAlarmInputSerializer::serialize(&mut params, "", &input);
@@ -7284,11 +7428,15 @@ impl CloudWatch for CloudWatchClient {
         input: PutMetricDataInput,
     ) -> Result<(), RusotoError<PutMetricDataError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("PutMetricData");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutMetricData");
         let mut params = params;
         PutMetricDataInputSerializer::serialize(&mut params, "", &input);
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
         request.set_content_type("application/x-www-form-urlencoded".to_owned());
+        request.set_request_compression(true);
 
         let response = self
             .sign_and_dispatch(request, PutMetricDataError::from_response)
@@ -7304,6 +7452,9 @@ impl CloudWatch for CloudWatchClient {
         input: SetAlarmStateInput,
     ) -> Result<(), RusotoError<SetAlarmStateError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("SetAlarmState");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetAlarmState");
         let mut params = params;
         SetAlarmStateInputSerializer::serialize(&mut params, "", &input);
@@ -7324,6 +7475,9 @@ impl CloudWatch for CloudWatchClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TagResource");
         let mut params = params;
         TagResourceInputSerializer::serialize(&mut params, "", &input);
@@ -7346,6 +7500,9 @@ impl CloudWatch for CloudWatchClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UntagResource");
         let mut params = params;
         UntagResourceInputSerializer::serialize(&mut params, "", &input);
@@ -7363,6 +7520,372 @@ impl CloudWatch for CloudWatchClient {
     }
 }
 
+/// A blocking client for the CloudWatch API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudWatchClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudWatchBlockingClient {
+    client: CloudWatchClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudWatchBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudWatchBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudWatchBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudWatchBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudWatchBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient {
+            client: CloudWatchClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Deletes the specified alarms. You can delete up to 100 alarms in one operation. However, this total can include no more than one composite alarm. For example, you could delete 99 metric alarms and one composite alarms with one operation, but you can&#39;t delete two composite alarms with one operation.</p> <p> In the event of an error, no alarms are deleted.</p> <note> <p>It is possible to create a loop or cycle of composite alarms, where composite alarm A depends on composite alarm B, and composite alarm B also depends on composite alarm A. In this scenario, you can&#39;t delete any composite alarm that is part of the cycle because there is always still a composite alarm that depends on that alarm that you want to delete.</p> <p>To get out of such a situation, you must break the cycle by changing the rule of one of the composite alarms in the cycle to remove a dependency that creates the cycle. The simplest change to make to break a cycle is to change the <code>AlarmRule</code> of one of the alarms to <code>False</code>. </p> <p>Additionally, the evaluation of composite alarms stops if CloudWatch detects a cycle in the evaluation path. </p> </note></p>
+    ///
+    /// See `CloudWatch::delete_alarms`.
+    pub fn delete_alarms(
+        &self,
+        input: DeleteAlarmsInput,
+    ) -> Result<(), RusotoError<DeleteAlarmsError>> {
+        self.runtime.block_on(self.client.delete_alarms(input))
+    }
+
+    /// <p>Deletes the specified anomaly detection model from your account.</p>
+    ///
+    /// See `CloudWatch::delete_anomaly_detector`.
+    pub fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
+        self.runtime
+            .block_on(self.client.delete_anomaly_detector(input))
+    }
+
+    /// <p>Deletes all dashboards that you specify. You may specify up to 100 dashboards to delete. If there is an error during this call, no dashboards are deleted.</p>
+    ///
+    /// See `CloudWatch::delete_dashboards`.
+    pub fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
+        self.runtime.block_on(self.client.delete_dashboards(input))
+    }
+
+    /// <p>Permanently deletes the specified Contributor Insights rules.</p> <p>If you create a rule, delete it, and then re-create it with the same name, historical data from the first time the rule was created may or may not be available.</p>
+    ///
+    /// See `CloudWatch::delete_insight_rules`.
+    pub fn delete_insight_rules(
+        &self,
+        input: DeleteInsightRulesInput,
+    ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.delete_insight_rules(input))
+    }
+
+    /// <p>Retrieves the history for the specified alarm. You can filter the results by date range or item type. If an alarm name is not specified, the histories for either all metric alarms or all composite alarms are returned.</p> <p>CloudWatch retains the history of an alarm even if you delete the alarm.</p>
+    ///
+    /// See `CloudWatch::describe_alarm_history`.
+    pub fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
+        self.runtime
+            .block_on(self.client.describe_alarm_history(input))
+    }
+
+    /// <p>Retrieves the specified alarms. You can filter the results by specifying a a prefix for the alarm name, the alarm state, or a prefix for any action.</p>
+    ///
+    /// See `CloudWatch::describe_alarms`.
+    pub fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
+        self.runtime.block_on(self.client.describe_alarms(input))
+    }
+
+    /// <p>Retrieves the alarms for the specified metric. To filter the results, specify a statistic, period, or unit.</p>
+    ///
+    /// See `CloudWatch::describe_alarms_for_metric`.
+    pub fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
+        self.runtime
+            .block_on(self.client.describe_alarms_for_metric(input))
+    }
+
+    /// <p>Lists the anomaly detection models that you have created in your account. You can list all models in your account or filter the results to only the models that are related to a certain namespace, metric name, or metric dimension.</p>
+    ///
+    /// See `CloudWatch::describe_anomaly_detectors`.
+    pub fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
+        self.runtime
+            .block_on(self.client.describe_anomaly_detectors(input))
+    }
+
+    /// <p>Returns a list of all the Contributor Insights rules in your account. All rules in your account are returned with a single operation.</p> <p>For more information about Contributor Insights, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/ContributorInsights.html">Using Contributor Insights to Analyze High-Cardinality Data</a>.</p>
+    ///
+    /// See `CloudWatch::describe_insight_rules`.
+    pub fn describe_insight_rules(
+        &self,
+        input: DescribeInsightRulesInput,
+    ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.describe_insight_rules(input))
+    }
+
+    /// <p>Disables the actions for the specified alarms. When an alarm's actions are disabled, the alarm actions do not execute when the alarm state changes.</p>
+    ///
+    /// See `CloudWatch::disable_alarm_actions`.
+    pub fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
+        self.runtime
+            .block_on(self.client.disable_alarm_actions(input))
+    }
+
+    /// <p>Disables the specified Contributor Insights rules. When rules are disabled, they do not analyze log groups and do not incur costs.</p>
+    ///
+    /// See `CloudWatch::disable_insight_rules`.
+    pub fn disable_insight_rules(
+        &self,
+        input: DisableInsightRulesInput,
+    ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.disable_insight_rules(input))
+    }
+
+    /// <p>Enables the actions for the specified alarms.</p>
+    ///
+    /// See `CloudWatch::enable_alarm_actions`.
+    pub fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
+        self.runtime
+            .block_on(self.client.enable_alarm_actions(input))
+    }
+
+    /// <p>Enables the specified Contributor Insights rules. When rules are enabled, they immediately begin analyzing log data.</p>
+    ///
+    /// See `CloudWatch::enable_insight_rules`.
+    pub fn enable_insight_rules(
+        &self,
+        input: EnableInsightRulesInput,
+    ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.enable_insight_rules(input))
+    }
+
+    /// <p>Displays the details of the dashboard that you specify.</p> <p>To copy an existing dashboard, use <code>GetDashboard</code>, and then use the data returned within <code>DashboardBody</code> as the template for the new dashboard when you call <code>PutDashboard</code> to create the copy.</p>
+    ///
+    /// See `CloudWatch::get_dashboard`.
+    pub fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
+        self.runtime.block_on(self.client.get_dashboard(input))
+    }
+
+    /// <p><p>This operation returns the time series data collected by a Contributor Insights rule. The data includes the identity and number of contributors to the log group.</p> <p>You can also optionally return one or more statistics about each data point in the time series. These statistics can include the following:</p> <ul> <li> <p> <code>UniqueContributors</code> -- the number of unique contributors for each data point.</p> </li> <li> <p> <code>MaxContributorValue</code> -- the value of the top contributor for each data point. The identity of the contributor may change for each data point in the graph.</p> <p>If this rule aggregates by COUNT, the top contributor for each data point is the contributor with the most occurrences in that period. If the rule aggregates by SUM, the top contributor is the contributor with the highest sum in the log field specified by the rule&#39;s <code>Value</code>, during that period.</p> </li> <li> <p> <code>SampleCount</code> -- the number of data points matched by the rule.</p> </li> <li> <p> <code>Sum</code> -- the sum of the values from all contributors during the time period represented by that data point.</p> </li> <li> <p> <code>Minimum</code> -- the minimum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Maximum</code> -- the maximum value from a single observation during the time period represented by that data point.</p> </li> <li> <p> <code>Average</code> -- the average value from all contributors during the time period represented by that data point.</p> </li> </ul></p>
+    ///
+    /// See `CloudWatch::get_insight_rule_report`.
+    pub fn get_insight_rule_report(
+        &self,
+        input: GetInsightRuleReportInput,
+    ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
+        self.runtime
+            .block_on(self.client.get_insight_rule_report(input))
+    }
+
+    /// <p>You can use the <code>GetMetricData</code> API to retrieve as many as 500 different metrics in a single request, with a total of as many as 100,800 data points. You can also optionally perform math expressions on the values of the returned statistics, to create new time series that represent new insights into your data. For example, using Lambda metrics, you could divide the Errors metric by the Invocations metric to get an error rate time series. For more information about metric math expressions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/using-metric-math.html#metric-math-syntax">Metric Math Syntax and Functions</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Calls to the <code>GetMetricData</code> API have a different pricing structure than calls to <code>GetMetricStatistics</code>. For more information about pricing, see <a href="https://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> <p>Amazon CloudWatch retains metric data as follows:</p> <ul> <li> <p>Data points with a period of less than 60 seconds are available for 3 hours. These data points are high-resolution metrics and are available only for custom metrics that have been defined with a <code>StorageResolution</code> of 1.</p> </li> <li> <p>Data points with a period of 60 seconds (1-minute) are available for 15 days.</p> </li> <li> <p>Data points with a period of 300 seconds (5-minute) are available for 63 days.</p> </li> <li> <p>Data points with a period of 3600 seconds (1 hour) are available for 455 days (15 months).</p> </li> </ul> <p>Data points that are initially published with a shorter period are aggregated together for long-term storage. For example, if you collect data using a period of 1 minute, the data remains available for 15 days with 1-minute resolution. After 15 days, this data is still available, but is aggregated and retrievable only with a resolution of 5 minutes. After 63 days, the data is further aggregated and is available with a resolution of 1 hour.</p> <p>If you omit <code>Unit</code> in your request, all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
+    ///
+    /// See `CloudWatch::get_metric_data`.
+    pub fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
+        self.runtime.block_on(self.client.get_metric_data(input))
+    }
+
+    /// <p>Gets statistics for the specified metric.</p> <p>The maximum number of data points returned from a single call is 1,440. If you request more than 1,440 data points, CloudWatch returns an error. To reduce the number of data points, you can narrow the specified time range and make multiple requests across adjacent time ranges, or you can increase the specified period. Data points are not returned in chronological order.</p> <p>CloudWatch aggregates data points based on the length of the period that you specify. For example, if you request statistics with a one-hour period, CloudWatch aggregates all data points with time stamps that fall within each one-hour period. Therefore, the number of values aggregated by CloudWatch is larger than the number of data points returned.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The SampleCount value of the statistic set is 1.</p> </li> <li> <p>The Min and the Max values of the statistic set are equal.</p> </li> </ul> <p>Percentile statistics are not available for metrics when any of the metric values are negative numbers.</p> <p>Amazon CloudWatch retains metric data as follows:</p> <ul> <li> <p>Data points with a period of less than 60 seconds are available for 3 hours. These data points are high-resolution metrics and are available only for custom metrics that have been defined with a <code>StorageResolution</code> of 1.</p> </li> <li> <p>Data points with a period of 60 seconds (1-minute) are available for 15 days.</p> </li> <li> <p>Data points with a period of 300 seconds (5-minute) are available for 63 days.</p> </li> <li> <p>Data points with a period of 3600 seconds (1 hour) are available for 455 days (15 months).</p> </li> </ul> <p>Data points that are initially published with a shorter period are aggregated together for long-term storage. For example, if you collect data using a period of 1 minute, the data remains available for 15 days with 1-minute resolution. After 15 days, this data is still available, but is aggregated and retrievable only with a resolution of 5 minutes. After 63 days, the data is further aggregated and is available with a resolution of 1 hour.</p> <p>CloudWatch started retaining 5-minute and 1-hour metric data as of July 9, 2016.</p> <p>For information about metrics and dimensions supported by AWS services, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CW_Support_For_AWS.html">Amazon CloudWatch Metrics and Dimensions Reference</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
+    ///
+    /// See `CloudWatch::get_metric_statistics`.
+    pub fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
+        self.runtime
+            .block_on(self.client.get_metric_statistics(input))
+    }
+
+    /// <p><p>You can use the <code>GetMetricWidgetImage</code> API to retrieve a snapshot graph of one or more Amazon CloudWatch metrics as a bitmap image. You can then embed this image into your services and products, such as wiki pages, reports, and documents. You could also retrieve images regularly, such as every minute, and create your own custom live dashboard.</p> <p>The graph you retrieve can include all CloudWatch metric graph features, including metric math and horizontal and vertical annotations.</p> <p>There is a limit of 20 transactions per second for this API. Each <code>GetMetricWidgetImage</code> action has the following limits:</p> <ul> <li> <p>As many as 100 metrics in the graph.</p> </li> <li> <p>Up to 100 KB uncompressed payload.</p> </li> </ul></p>
+    ///
+    /// See `CloudWatch::get_metric_widget_image`.
+    pub fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
+        self.runtime
+            .block_on(self.client.get_metric_widget_image(input))
+    }
+
+    /// <p>Returns a list of the dashboards for your account. If you include <code>DashboardNamePrefix</code>, only those dashboards with names starting with the prefix are listed. Otherwise, all dashboards in your account are listed. </p> <p> <code>ListDashboards</code> returns up to 1000 results on one page. If there are more than 1000 dashboards, you can call <code>ListDashboards</code> again and include the value you received for <code>NextToken</code> in the first call, to receive the next 1000 results.</p>
+    ///
+    /// See `CloudWatch::list_dashboards`.
+    pub fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
+        self.runtime.block_on(self.client.list_dashboards(input))
+    }
+
+    /// <p>List the specified metrics. You can use the returned metrics with <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricData.html">GetMetricData</a> or <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricStatistics.html">GetMetricStatistics</a> to obtain statistical data.</p> <p>Up to 500 results are returned for any one call. To retrieve additional results, use the returned token with subsequent calls.</p> <p>After you create a metric, allow up to fifteen minutes before the metric appears. Statistics about the metric, however, are available sooner using <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricData.html">GetMetricData</a> or <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricStatistics.html">GetMetricStatistics</a>.</p>
+    ///
+    /// See `CloudWatch::list_metrics`.
+    pub fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
+        self.runtime.block_on(self.client.list_metrics(input))
+    }
+
+    /// <p>Displays the tags associated with a CloudWatch resource. Currently, alarms and Contributor Insights rules support tagging.</p>
+    ///
+    /// See `CloudWatch::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates an anomaly detection model for a CloudWatch metric. You can use the model to display a band of expected normal values when the metric is graphed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/CloudWatch_Anomaly_Detection.html">CloudWatch Anomaly Detection</a>.</p>
+    ///
+    /// See `CloudWatch::put_anomaly_detector`.
+    pub fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
+        self.runtime
+            .block_on(self.client.put_anomaly_detector(input))
+    }
+
+    /// <p>Creates or updates a <i>composite alarm</i>. When you create a composite alarm, you specify a rule expression for the alarm that takes into account the alarm states of other alarms that you have created. The composite alarm goes into ALARM state only if all conditions of the rule are met.</p> <p>The alarms specified in a composite alarm's rule expression can include metric alarms and other composite alarms.</p> <p>Using composite alarms can reduce alarm noise. You can create multiple metric alarms, and also create a composite alarm and set up alerts only for the composite alarm. For example, you could create a composite alarm that goes into ALARM state only when more than one of the underlying metric alarms are in ALARM state.</p> <p>Currently, the only alarm actions that can be taken by composite alarms are notifying SNS topics.</p> <note> <p>It is possible to create a loop or cycle of composite alarms, where composite alarm A depends on composite alarm B, and composite alarm B also depends on composite alarm A. In this scenario, you can't delete any composite alarm that is part of the cycle because there is always still a composite alarm that depends on that alarm that you want to delete.</p> <p>To get out of such a situation, you must break the cycle by changing the rule of one of the composite alarms in the cycle to remove a dependency that creates the cycle. The simplest change to make to break a cycle is to change the <code>AlarmRule</code> of one of the alarms to <code>False</code>. </p> <p>Additionally, the evaluation of composite alarms stops if CloudWatch detects a cycle in the evaluation path. </p> </note> <p>When this operation creates an alarm, the alarm state is immediately set to <code>INSUFFICIENT_DATA</code>. The alarm is then evaluated and its state is set appropriately. Any actions associated with the new state are then executed. For a composite alarm, this initial time after creation is the only time that the alarm can be in <code>INSUFFICIENT_DATA</code> state.</p> <p>When you update an existing alarm, its state is left unchanged, but the update completely overwrites the previous configuration of the alarm.</p>
+    ///
+    /// See `CloudWatch::put_composite_alarm`.
+    pub fn put_composite_alarm(
+        &self,
+        input: PutCompositeAlarmInput,
+    ) -> Result<(), RusotoError<PutCompositeAlarmError>> {
+        self.runtime
+            .block_on(self.client.put_composite_alarm(input))
+    }
+
+    /// <p>Creates a dashboard if it does not already exist, or updates an existing dashboard. If you update a dashboard, the entire contents are replaced with what you specify here.</p> <p>All dashboards in your account are global, not region-specific.</p> <p>A simple way to create a dashboard using <code>PutDashboard</code> is to copy an existing dashboard. To copy an existing dashboard using the console, you can load the dashboard and then use the View/edit source command in the Actions menu to display the JSON block for that dashboard. Another way to copy a dashboard is to use <code>GetDashboard</code>, and then use the data returned within <code>DashboardBody</code> as the template for the new dashboard when you call <code>PutDashboard</code>.</p> <p>When you create a dashboard with <code>PutDashboard</code>, a good practice is to add a text widget at the top of the dashboard with a message that the dashboard was created by script and should not be changed in the console. This message could also point console users to the location of the <code>DashboardBody</code> script or the CloudFormation template used to create the dashboard.</p>
+    ///
+    /// See `CloudWatch::put_dashboard`.
+    pub fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
+        self.runtime.block_on(self.client.put_dashboard(input))
+    }
+
+    /// <p>Creates a Contributor Insights rule. Rules evaluate log events in a CloudWatch Logs log group, enabling you to find contributor data for the log events in that log group. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/ContributorInsights.html">Using Contributor Insights to Analyze High-Cardinality Data</a>.</p> <p>If you create a rule, delete it, and then re-create it with the same name, historical data from the first time the rule was created may or may not be available.</p>
+    ///
+    /// See `CloudWatch::put_insight_rule`.
+    pub fn put_insight_rule(
+        &self,
+        input: PutInsightRuleInput,
+    ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
+        self.runtime.block_on(self.client.put_insight_rule(input))
+    }
+
+    /// <p>Creates or updates an alarm and associates it with the specified metric, metric math expression, or anomaly detection model.</p> <p>Alarms based on anomaly detection models cannot have Auto Scaling actions.</p> <p>When this operation creates an alarm, the alarm state is immediately set to <code>INSUFFICIENT_DATA</code>. The alarm is then evaluated and its state is set appropriately. Any actions associated with the new state are then executed.</p> <p>When you update an existing alarm, its state is left unchanged, but the update completely overwrites the previous configuration of the alarm.</p> <p>If you are an IAM user, you must have Amazon EC2 permissions for some alarm operations:</p> <ul> <li> <p> <code>iam:CreateServiceLinkedRole</code> for all alarms with EC2 actions</p> </li> <li> <p> <code>ec2:DescribeInstanceStatus</code> and <code>ec2:DescribeInstances</code> for all alarms on EC2 instance status metrics</p> </li> <li> <p> <code>ec2:StopInstances</code> for alarms with stop actions</p> </li> <li> <p> <code>ec2:TerminateInstances</code> for alarms with terminate actions</p> </li> <li> <p>No specific permissions are needed for alarms with recover actions</p> </li> </ul> <p>If you have read/write permissions for Amazon CloudWatch but not for Amazon EC2, you can still create an alarm, but the stop or terminate actions are not performed. However, if you are later granted the required permissions, the alarm actions that you created earlier are performed.</p> <p>If you are using an IAM role (for example, an EC2 instance profile), you cannot stop or terminate the instance using alarm actions. However, you can still see the alarm state and perform any other actions such as Amazon SNS notifications or Auto Scaling policies.</p> <p>If you are using temporary security credentials granted using AWS STS, you cannot stop or terminate an EC2 instance using alarm actions.</p> <p>The first time you create an alarm in the AWS Management Console, the CLI, or by using the PutMetricAlarm API, CloudWatch creates the necessary service-linked role for you. The service-linked role is called <code>AWSServiceRoleForCloudWatchEvents</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_terms-and-concepts.html#iam-term-service-linked-role">AWS service-linked role</a>.</p>
+    ///
+    /// See `CloudWatch::put_metric_alarm`.
+    pub fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> Result<(), RusotoError<PutMetricAlarmError>> {
+        self.runtime.block_on(self.client.put_metric_alarm(input))
+    }
+
+    /// <p><p>Publishes metric data points to Amazon CloudWatch. CloudWatch associates the data points with the specified metric. If the specified metric does not exist, CloudWatch creates the metric. When CloudWatch creates a metric, it can take up to fifteen minutes for the metric to appear in calls to <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_ListMetrics.html">ListMetrics</a>.</p> <p>You can publish either individual data points in the <code>Value</code> field, or arrays of values and the number of times each value occurred during the period by using the <code>Values</code> and <code>Counts</code> fields in the <code>MetricDatum</code> structure. Using the <code>Values</code> and <code>Counts</code> method enables you to publish up to 150 values per metric with one <code>PutMetricData</code> request, and supports retrieving percentile statistics on this data.</p> <p>Each <code>PutMetricData</code> request is limited to 40 KB in size for HTTP POST requests. You can send a payload compressed by gzip. Each request is also limited to no more than 20 different metrics.</p> <p>Although the <code>Value</code> parameter accepts numbers of type <code>Double</code>, CloudWatch rejects values that are either too small or too large. Values must be in the range of -2^360 to 2^360. In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p> <p>You can use up to 10 dimensions per metric to further clarify what data the metric collects. Each dimension consists of a Name and Value pair. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> <p>Data points with time stamps from 24 hours ago or longer can take at least 48 hours to become available for <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricData.html">GetMetricData</a> or <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricStatistics.html">GetMetricStatistics</a> from the time they are submitted. Data points with time stamps between 3 and 24 hours ago can take as much as 2 hours to become available for for <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricData.html">GetMetricData</a> or <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_GetMetricStatistics.html">GetMetricStatistics</a>.</p> <p>CloudWatch needs raw data points to calculate percentile statistics. If you publish data using a statistic set instead, you can only retrieve percentile statistics for this data if one of the following conditions is true:</p> <ul> <li> <p>The <code>SampleCount</code> value of the statistic set is 1 and <code>Min</code>, <code>Max</code>, and <code>Sum</code> are all equal.</p> </li> <li> <p>The <code>Min</code> and <code>Max</code> are equal, and <code>Sum</code> is equal to <code>Min</code> multiplied by <code>SampleCount</code>.</p> </li> </ul></p>
+    ///
+    /// See `CloudWatch::put_metric_data`.
+    pub fn put_metric_data(
+        &self,
+        input: PutMetricDataInput,
+    ) -> Result<(), RusotoError<PutMetricDataError>> {
+        self.runtime.block_on(self.client.put_metric_data(input))
+    }
+
+    /// <p>Temporarily sets the state of an alarm for testing purposes. When the updated state differs from the previous value, the action configured for the appropriate state is invoked. For example, if your alarm is configured to send an Amazon SNS message when an alarm is triggered, temporarily changing the alarm state to <code>ALARM</code> sends an SNS message.</p> <p>Metric alarms returns to their actual state quickly, often within seconds. Because the metric alarm state change happens quickly, it is typically only visible in the alarm's <b>History</b> tab in the Amazon CloudWatch console or through <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/APIReference/API_DescribeAlarmHistory.html">DescribeAlarmHistory</a>.</p> <p>If you use <code>SetAlarmState</code> on a composite alarm, the composite alarm is not guaranteed to return to its actual state. It will return to its actual state only once any of its children alarms change state. It is also re-evaluated if you update its configuration.</p> <p>If an alarm triggers EC2 Auto Scaling policies or application Auto Scaling policies, you must include information in the <code>StateReasonData</code> parameter to enable the policy to take the correct action.</p>
+    ///
+    /// See `CloudWatch::set_alarm_state`.
+    pub fn set_alarm_state(
+        &self,
+        input: SetAlarmStateInput,
+    ) -> Result<(), RusotoError<SetAlarmStateError>> {
+        self.runtime.block_on(self.client.set_alarm_state(input))
+    }
+
+    /// <p>Assigns one or more tags (key-value pairs) to the specified CloudWatch resource. Currently, the only CloudWatch resources that can be tagged are alarms and Contributor Insights rules.</p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with an alarm that already has tags. If you specify a new tag key for the alarm, this tag is appended to the list of tags associated with the alarm. If you specify a tag key that is already associated with the alarm, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a CloudWatch resource.</p>
+    ///
+    /// See `CloudWatch::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from the specified resource.</p>
+    ///
+    /// See `CloudWatch::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -2071,7 +2071,10 @@ impl BatchDeleteBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteBuildsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2101,7 +2104,7 @@ impl BatchGetBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchGetBuildsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2131,7 +2134,7 @@ impl BatchGetProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchGetProjectsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2161,7 +2164,10 @@ impl BatchGetReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetReportGroupsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2191,7 +2197,7 @@ impl BatchGetReportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetReportsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchGetReportsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2225,13 +2231,19 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::AccountLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::InvalidInput(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2267,17 +2279,22 @@ impl CreateReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(CreateReportGroupError::AccountLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReportGroupError::AccountLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateReportGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateReportGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReportGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2315,16 +2332,22 @@ impl CreateWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::InvalidInput(err.msg))
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(CreateWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service(&res, CreateWebhookError::OAuthProvider(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateWebhookError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebhookError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebhookError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2357,7 +2380,7 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2387,7 +2410,7 @@ impl DeleteReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteReportError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteReportError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2417,7 +2440,10 @@ impl DeleteReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReportGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2447,7 +2473,10 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2479,14 +2508,16 @@ impl DeleteSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSourceCredentialsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSourceCredentialsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSourceCredentialsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSourceCredentialsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2521,13 +2552,16 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::InvalidInput(err.msg))
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(DeleteWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service(&res, DeleteWebhookError::OAuthProvider(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebhookError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2561,10 +2595,16 @@ impl DescribeTestCasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeTestCasesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTestCasesError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTestCasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTestCasesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2597,10 +2637,16 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2635,17 +2681,20 @@ impl ImportSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportSourceCredentialsError::AccountLimitExceeded(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportSourceCredentialsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportSourceCredentialsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportSourceCredentialsError::ResourceAlreadyExists(err.msg),
                     )
                 }
@@ -2683,12 +2732,16 @@ impl InvalidateProjectCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(InvalidateProjectCacheError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvalidateProjectCacheError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvalidateProjectCacheError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InvalidateProjectCacheError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2719,7 +2772,7 @@ impl ListBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListBuildsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2751,12 +2804,16 @@ impl ListBuildsForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildsForProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBuildsForProjectError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBuildsForProjectError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBuildsForProjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2811,7 +2868,7 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2841,7 +2898,7 @@ impl ListReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListReportGroupsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2871,7 +2928,7 @@ impl ListReportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListReportsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2903,14 +2960,16 @@ impl ListReportsForReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportsForReportGroupError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListReportsForReportGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListReportsForReportGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListReportsForReportGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2941,7 +3000,10 @@ impl ListSharedProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSharedProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSharedProjectsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2971,7 +3033,10 @@ impl ListSharedReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSharedReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSharedReportGroupsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3025,10 +3090,16 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3063,13 +3134,16 @@ impl StartBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(StartBuildError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartBuildError::AccountLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartBuildError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StartBuildError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartBuildError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3103,10 +3177,10 @@ impl StopBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopBuildError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StopBuildError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopBuildError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3139,10 +3213,13 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateProjectError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3175,10 +3252,16 @@ impl UpdateReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReportGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateReportGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReportGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3213,13 +3296,16 @@ impl UpdateWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateWebhookError::InvalidInput(err.msg))
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(UpdateWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service(&res, UpdateWebhookError::OAuthProvider(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebhookError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3462,6 +3548,7 @@ impl CodeBuildClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3477,6 +3564,7 @@ impl CodeBuildClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildClient {
         CodeBuildClient { client, region }
     }
@@ -3490,6 +3578,9 @@ impl CodeBuild for CodeBuildClient {
         input: BatchDeleteBuildsInput,
     ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteBuilds");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchDeleteBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3508,6 +3599,9 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetBuildsInput,
     ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetBuilds");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3526,6 +3620,9 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetProjectsInput,
     ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3544,6 +3641,9 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetReportGroupsInput,
     ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetReportGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3562,6 +3662,9 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetReportsInput,
     ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3580,6 +3683,9 @@ impl CodeBuild for CodeBuildClient {
         input: CreateProjectInput,
     ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3598,6 +3704,9 @@ impl CodeBuild for CodeBuildClient {
         input: CreateReportGroupInput,
     ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateReportGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3616,6 +3725,9 @@ impl CodeBuild for CodeBuildClient {
         input: CreateWebhookInput,
     ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3634,6 +3746,9 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteProjectInput,
     ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3652,6 +3767,9 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteReportInput,
     ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3670,6 +3788,9 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteReportGroupInput,
     ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReportGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3688,6 +3809,9 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteResourcePolicyInput,
     ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3706,6 +3830,9 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteSourceCredentialsInput,
     ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSourceCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteSourceCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3725,6 +3852,9 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteWebhookInput,
     ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3743,6 +3873,9 @@ impl CodeBuild for CodeBuildClient {
         input: DescribeTestCasesInput,
     ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTestCases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.DescribeTestCases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3761,6 +3894,9 @@ impl CodeBuild for CodeBuildClient {
         input: GetResourcePolicyInput,
     ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResourcePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3779,6 +3915,9 @@ impl CodeBuild for CodeBuildClient {
         input: ImportSourceCredentialsInput,
     ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportSourceCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.ImportSourceCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3798,6 +3937,9 @@ impl CodeBuild for CodeBuildClient {
         input: InvalidateProjectCacheInput,
     ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InvalidateProjectCache");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.InvalidateProjectCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3817,6 +3959,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsInput,
     ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBuilds");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3835,6 +3980,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsForProjectInput,
     ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBuildsForProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuildsForProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3853,6 +4001,9 @@ impl CodeBuild for CodeBuildClient {
     ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCuratedEnvironmentImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeBuild_20161006.ListCuratedEnvironmentImages",
@@ -3874,6 +4025,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListProjectsInput,
     ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3892,6 +4046,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportGroupsInput,
     ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListReportGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3910,6 +4067,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportsInput,
     ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3928,6 +4088,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportsForReportGroupInput,
     ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListReportsForReportGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeBuild_20161006.ListReportsForReportGroup",
@@ -3950,6 +4113,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListSharedProjectsInput,
     ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSharedProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3968,6 +4134,9 @@ impl CodeBuild for CodeBuildClient {
         input: ListSharedReportGroupsInput,
     ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSharedReportGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3986,6 +4155,9 @@ impl CodeBuild for CodeBuildClient {
         &self,
     ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSourceCredentials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSourceCredentials");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -4003,6 +4175,9 @@ impl CodeBuild for CodeBuildClient {
         input: PutResourcePolicyInput,
     ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4021,6 +4196,9 @@ impl CodeBuild for CodeBuildClient {
         input: StartBuildInput,
     ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartBuild");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.StartBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4039,6 +4217,9 @@ impl CodeBuild for CodeBuildClient {
         input: StopBuildInput,
     ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopBuild");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.StopBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4057,6 +4238,9 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateProjectInput,
     ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4075,6 +4259,9 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateReportGroupInput,
     ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateReportGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4093,6 +4280,9 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateWebhookInput,
     ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4105,3 +4295,409 @@ impl CodeBuild for CodeBuildClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebhookOutput, _>()
     }
 }
+
+/// A blocking client for the AWS CodeBuild API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeBuildClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeBuildBlockingClient {
+    client: CodeBuildClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeBuildBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeBuildBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeBuildBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeBuildBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeBuildBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient {
+            client: CodeBuildClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Deletes one or more builds.</p>
+    ///
+    /// See `CodeBuild::batch_delete_builds`.
+    pub fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_builds(input))
+    }
+
+    /// <p>Gets information about one or more builds.</p>
+    ///
+    /// See `CodeBuild::batch_get_builds`.
+    pub fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
+        self.runtime.block_on(self.client.batch_get_builds(input))
+    }
+
+    /// <p>Gets information about one or more build projects.</p>
+    ///
+    /// See `CodeBuild::batch_get_projects`.
+    pub fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
+        self.runtime.block_on(self.client.batch_get_projects(input))
+    }
+
+    /// <p> Returns an array of report groups. </p>
+    ///
+    /// See `CodeBuild::batch_get_report_groups`.
+    pub fn batch_get_report_groups(
+        &self,
+        input: BatchGetReportGroupsInput,
+    ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_report_groups(input))
+    }
+
+    /// <p> Returns an array of reports. </p>
+    ///
+    /// See `CodeBuild::batch_get_reports`.
+    pub fn batch_get_reports(
+        &self,
+        input: BatchGetReportsInput,
+    ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
+        self.runtime.block_on(self.client.batch_get_reports(input))
+    }
+
+    /// <p>Creates a build project.</p>
+    ///
+    /// See `CodeBuild::create_project`.
+    pub fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// <p> Creates a report group. A report group contains a collection of reports. </p>
+    ///
+    /// See `CodeBuild::create_report_group`.
+    pub fn create_report_group(
+        &self,
+        input: CreateReportGroupInput,
+    ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
+        self.runtime
+            .block_on(self.client.create_report_group(input))
+    }
+
+    /// <p><p>For an existing AWS CodeBuild build project that has its source code stored in a GitHub or Bitbucket repository, enables AWS CodeBuild to start rebuilding the source code every time a code change is pushed to the repository.</p> <important> <p>If you enable webhooks for an AWS CodeBuild project, and the project is used as a build step in AWS CodePipeline, then two identical builds are created for each commit. One build is triggered through webhooks, and one through AWS CodePipeline. Because billing is on a per-build basis, you are billed for both builds. Therefore, if you are using AWS CodePipeline, we recommend that you disable webhooks in AWS CodeBuild. In the AWS CodeBuild console, clear the Webhook box. For more information, see step 5 in <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/change-project.html#change-project-console">Change a Build Project&#39;s Settings</a>.</p> </important></p>
+    ///
+    /// See `CodeBuild::create_webhook`.
+    pub fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
+        self.runtime.block_on(self.client.create_webhook(input))
+    }
+
+    /// <p> Deletes a build project. When you delete a project, its builds are not deleted. </p>
+    ///
+    /// See `CodeBuild::delete_project`.
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// <p> Deletes a report. </p>
+    ///
+    /// See `CodeBuild::delete_report`.
+    pub fn delete_report(
+        &self,
+        input: DeleteReportInput,
+    ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
+        self.runtime.block_on(self.client.delete_report(input))
+    }
+
+    /// <p> <code>DeleteReportGroup</code>: Deletes a report group. Before you delete a report group, you must delete its reports. Use <a href="https://docs.aws.amazon.com/codebuild/latest/APIReference/API_ListReportsForReportGroup.html">ListReportsForReportGroup</a> to get the reports in a report group. Use <a href="https://docs.aws.amazon.com/codebuild/latest/APIReference/API_DeleteReport.html">DeleteReport</a> to delete the reports. If you call <code>DeleteReportGroup</code> for a report group that contains one or more reports, an exception is thrown. </p>
+    ///
+    /// See `CodeBuild::delete_report_group`.
+    pub fn delete_report_group(
+        &self,
+        input: DeleteReportGroupInput,
+    ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_report_group(input))
+    }
+
+    /// <p> Deletes a resource policy that is identified by its resource ARN. </p>
+    ///
+    /// See `CodeBuild::delete_resource_policy`.
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyInput,
+    ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// <p> Deletes a set of GitHub, GitHub Enterprise, or Bitbucket source credentials. </p>
+    ///
+    /// See `CodeBuild::delete_source_credentials`.
+    pub fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
+        self.runtime
+            .block_on(self.client.delete_source_credentials(input))
+    }
+
+    /// <p>For an existing AWS CodeBuild build project that has its source code stored in a GitHub or Bitbucket repository, stops AWS CodeBuild from rebuilding the source code every time a code change is pushed to the repository.</p>
+    ///
+    /// See `CodeBuild::delete_webhook`.
+    pub fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+        self.runtime.block_on(self.client.delete_webhook(input))
+    }
+
+    /// <p> Returns a list of details about test cases for a report. </p>
+    ///
+    /// See `CodeBuild::describe_test_cases`.
+    pub fn describe_test_cases(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
+        self.runtime
+            .block_on(self.client.describe_test_cases(input))
+    }
+
+    /// <p> Gets a resource policy that is identified by its resource ARN. </p>
+    ///
+    /// See `CodeBuild::get_resource_policy`.
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyInput,
+    ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// <p> Imports the source repository credentials for an AWS CodeBuild project that has its source code stored in a GitHub, GitHub Enterprise, or Bitbucket repository. </p>
+    ///
+    /// See `CodeBuild::import_source_credentials`.
+    pub fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
+        self.runtime
+            .block_on(self.client.import_source_credentials(input))
+    }
+
+    /// <p>Resets the cache for a project.</p>
+    ///
+    /// See `CodeBuild::invalidate_project_cache`.
+    pub fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
+        self.runtime
+            .block_on(self.client.invalidate_project_cache(input))
+    }
+
+    /// <p>Gets a list of build IDs, with each build ID representing a single build.</p>
+    ///
+    /// See `CodeBuild::list_builds`.
+    pub fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+        self.runtime.block_on(self.client.list_builds(input))
+    }
+
+    /// <p>Gets a list of build IDs for the specified build project, with each build ID representing a single build.</p>
+    ///
+    /// See `CodeBuild::list_builds_for_project`.
+    pub fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
+        self.runtime
+            .block_on(self.client.list_builds_for_project(input))
+    }
+
+    /// <p>Gets information about Docker images that are managed by AWS CodeBuild.</p>
+    ///
+    /// See `CodeBuild::list_curated_environment_images`.
+    pub fn list_curated_environment_images(
+        &self,
+    ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_curated_environment_images())
+    }
+
+    /// <p>Gets a list of build project names, with each build project name representing a single build project.</p>
+    ///
+    /// See `CodeBuild::list_projects`.
+    pub fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// <p> Gets a list ARNs for the report groups in the current AWS account. </p>
+    ///
+    /// See `CodeBuild::list_report_groups`.
+    pub fn list_report_groups(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
+        self.runtime.block_on(self.client.list_report_groups(input))
+    }
+
+    /// <p> Returns a list of ARNs for the reports in the current AWS account. </p>
+    ///
+    /// See `CodeBuild::list_reports`.
+    pub fn list_reports(
+        &self,
+        input: ListReportsInput,
+    ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
+        self.runtime.block_on(self.client.list_reports(input))
+    }
+
+    /// <p> Returns a list of ARNs for the reports that belong to a <code>ReportGroup</code>. </p>
+    ///
+    /// See `CodeBuild::list_reports_for_report_group`.
+    pub fn list_reports_for_report_group(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
+        self.runtime
+            .block_on(self.client.list_reports_for_report_group(input))
+    }
+
+    /// <p> Gets a list of projects that are shared with other AWS accounts or users. </p>
+    ///
+    /// See `CodeBuild::list_shared_projects`.
+    pub fn list_shared_projects(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
+        self.runtime
+            .block_on(self.client.list_shared_projects(input))
+    }
+
+    /// <p> Gets a list of report groups that are shared with other AWS accounts or users. </p>
+    ///
+    /// See `CodeBuild::list_shared_report_groups`.
+    pub fn list_shared_report_groups(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_shared_report_groups(input))
+    }
+
+    /// <p> Returns a list of <code>SourceCredentialsInfo</code> objects. </p>
+    ///
+    /// See `CodeBuild::list_source_credentials`.
+    pub fn list_source_credentials(
+        &self,
+    ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
+        self.runtime.block_on(self.client.list_source_credentials())
+    }
+
+    /// <p> Stores a resource policy for the ARN of a <code>Project</code> or <code>ReportGroup</code> object. </p>
+    ///
+    /// See `CodeBuild::put_resource_policy`.
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyInput,
+    ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// <p>Starts running a build.</p>
+    ///
+    /// See `CodeBuild::start_build`.
+    pub fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
+        self.runtime.block_on(self.client.start_build(input))
+    }
+
+    /// <p>Attempts to stop running a build.</p>
+    ///
+    /// See `CodeBuild::stop_build`.
+    pub fn stop_build(
+        &self,
+        input: StopBuildInput,
+    ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
+        self.runtime.block_on(self.client.stop_build(input))
+    }
+
+    /// <p>Changes the settings of a build project.</p>
+    ///
+    /// See `CodeBuild::update_project`.
+    pub fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+
+    /// <p> Updates a report group. </p>
+    ///
+    /// See `CodeBuild::update_report_group`.
+    pub fn update_report_group(
+        &self,
+        input: UpdateReportGroupInput,
+    ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
+        self.runtime
+            .block_on(self.client.update_report_group(input))
+    }
+
+    /// <p><p> Updates the webhook associated with an AWS CodeBuild build project. </p> <note> <p> If you use Bitbucket for your repository, <code>rotateSecret</code> is ignored. </p> </note></p>
+    ///
+    /// See `CodeBuild::update_webhook`.
+    pub fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
+        self.runtime.block_on(self.client.update_webhook(input))
+    }
+}
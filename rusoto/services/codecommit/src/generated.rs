@@ -3587,18 +3587,18 @@ impl AssociateApprovalRuleTemplateWithRepositoryError {
     ) -> RusotoError<AssociateApprovalRuleTemplateWithRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(err.msg)),
-"MaximumRuleTemplatesAssociatedWithRepositoryException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied(err.msg)),
+"EncryptionKeyDisabledException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled(err.msg)),
+"EncryptionKeyNotFoundException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound(err.msg)),
+"EncryptionKeyUnavailableException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable(err.msg)),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
+"InvalidRepositoryNameException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(err.msg)),
+"MaximumRuleTemplatesAssociatedWithRepositoryException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(err.msg)),
+"RepositoryDoesNotExistException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(err.msg)),
+"RepositoryNameRequiredException" => return RusotoError::service(&res, AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -3657,16 +3657,16 @@ impl BatchAssociateApprovalRuleTemplateWithRepositoriesError {
     ) -> RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
-"MaximumRepositoryNamesExceededException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
-"RepositoryNamesRequiredException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
+"EncryptionKeyDisabledException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled(err.msg)),
+"EncryptionKeyNotFoundException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(err.msg)),
+"EncryptionKeyUnavailableException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(err.msg)),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
+"MaximumRepositoryNamesExceededException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
+"RepositoryNamesRequiredException" => return RusotoError::service(&res, BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -3746,109 +3746,130 @@ impl BatchDescribeMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(BatchDescribeMergeConflictsError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDescribeMergeConflictsError::CommitRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(BatchDescribeMergeConflictsError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDescribeMergeConflictsError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidConflictResolutionStrategy(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxConflictFilesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidMaxConflictFiles(err.msg),
                     )
                 }
                 "InvalidMaxMergeHunksException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidMaxMergeHunks(err.msg),
                     )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidMergeOption(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::MergeOptionRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -3957,16 +3978,16 @@ impl BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
     ) -> RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
-"MaximumRepositoryNamesExceededException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
-"RepositoryNamesRequiredException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
+"EncryptionKeyDisabledException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled(err.msg)),
+"EncryptionKeyNotFoundException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound(err.msg)),
+"EncryptionKeyUnavailableException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(err.msg)),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
+"MaximumRepositoryNamesExceededException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
+"RepositoryNamesRequiredException" => return RusotoError::service(&res, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -4022,54 +4043,64 @@ impl BatchGetCommitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitIdsLimitExceededException" => {
-                    return RusotoError::Service(BatchGetCommitsError::CommitIdsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::CommitIdsLimitExceeded(err.msg),
+                    )
                 }
                 "CommitIdsListRequiredException" => {
-                    return RusotoError::Service(BatchGetCommitsError::CommitIdsListRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::CommitIdsListRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetCommitsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(BatchGetCommitsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(BatchGetCommitsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(BatchGetCommitsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCommitsError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4124,42 +4155,50 @@ impl BatchGetRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetRepositoriesError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetRepositoriesError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetRepositoriesError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetRepositoriesError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetRepositoriesError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetRepositoriesError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "MaximumRepositoryNamesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetRepositoriesError::MaximumRepositoryNamesExceeded(err.msg),
                     )
                 }
                 "RepositoryNamesRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetRepositoriesError::RepositoryNamesRequired(err.msg),
                     )
                 }
@@ -4220,45 +4259,52 @@ impl CreateApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::ApprovalRuleTemplateContentRequired(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleTemplateNameAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameAlreadyExists(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
                     )
                 }
                 "InvalidApprovalRuleTemplateContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateContent(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleTemplateDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateDescription(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
                     )
                 }
                 "NumberOfRuleTemplatesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApprovalRuleTemplateError::NumberOfRuleTemplatesExceeded(err.msg),
                     )
                 }
@@ -4336,52 +4382,79 @@ impl CreateBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchNameExistsException" => {
-                    return RusotoError::Service(CreateBranchError::BranchNameExists(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::BranchNameExists(err.msg))
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::BranchNameRequired(err.msg),
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(CreateBranchError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::CommitIdRequired(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::CommitIdRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateBranchError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::InvalidBranchName(err.msg),
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidCommitId(err.msg))
+                    return RusotoError::service(&res, CreateBranchError::InvalidCommitId(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreateBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBranchError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4498,146 +4571,208 @@ impl CreateCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::BranchDoesNotExist(err.msg),
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(CreateCommitError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::BranchNameIsTagName(err.msg),
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::BranchNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::BranchNameRequired(err.msg),
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(CreateCommitError::CommitMessageLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::CommitMessageLengthExceeded(err.msg),
+                    )
                 }
                 "DirectoryNameConflictsWithFileNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCommitError::DirectoryNameConflictsWithFileName(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCommitError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "FileContentAndSourceFileSpecifiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCommitError::FileContentAndSourceFileSpecified(err.msg),
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(CreateCommitError::FileContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::FileContentSizeLimitExceeded(err.msg),
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::FileDoesNotExist(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::FileDoesNotExist(err.msg))
                 }
                 "FileEntryRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::FileEntryRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::FileEntryRequired(err.msg),
+                    )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::FileModeRequired(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::FileModeRequired(err.msg))
                 }
                 "FileNameConflictsWithDirectoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCommitError::FileNameConflictsWithDirectoryName(err.msg),
                     )
                 }
                 "FilePathConflictsWithSubmodulePathException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCommitError::FilePathConflictsWithSubmodulePath(err.msg),
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(CreateCommitError::FolderContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::FolderContentSizeLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidBranchName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::InvalidBranchName(err.msg),
+                    )
                 }
                 "InvalidDeletionParameterException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidDeletionParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::InvalidDeletionParameter(err.msg),
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidEmail(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::InvalidEmail(err.msg))
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidFileMode(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::InvalidFileMode(err.msg))
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::InvalidParentCommitId(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidPath(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::InvalidPath(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "MaximumFileEntriesExceededException" => {
-                    return RusotoError::Service(CreateCommitError::MaximumFileEntriesExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::MaximumFileEntriesExceeded(err.msg),
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(CreateCommitError::NameLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::NameLengthExceeded(err.msg),
+                    )
                 }
                 "NoChangeException" => {
-                    return RusotoError::Service(CreateCommitError::NoChange(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::NoChange(err.msg))
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::ParentCommitDoesNotExist(err.msg),
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::ParentCommitIdOutdated(err.msg),
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::ParentCommitIdRequired(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::PathRequired(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::PathRequired(err.msg))
                 }
                 "PutFileEntryConflictException" => {
-                    return RusotoError::Service(CreateCommitError::PutFileEntryConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::PutFileEntryConflict(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "RestrictedSourceFileException" => {
-                    return RusotoError::Service(CreateCommitError::RestrictedSourceFile(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::RestrictedSourceFile(err.msg),
+                    )
                 }
                 "SamePathRequestException" => {
-                    return RusotoError::Service(CreateCommitError::SamePathRequest(err.msg))
+                    return RusotoError::service(&res, CreateCommitError::SamePathRequest(err.msg))
                 }
                 "SourceFileOrContentRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::SourceFileOrContentRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommitError::SourceFileOrContentRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4760,117 +4895,154 @@ impl CreatePullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestError::ClientRequestTokenRequired(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestError::IdempotencyParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidClientRequestToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidClientRequestToken(err.msg),
+                    )
                 }
                 "InvalidDescriptionException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidDescription(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidDescription(err.msg),
+                    )
                 }
                 "InvalidReferenceNameException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidReferenceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidReferenceName(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidTarget(err.msg),
+                    )
                 }
                 "InvalidTargetsException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTargets(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidTargets(err.msg),
+                    )
                 }
                 "InvalidTitleException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTitle(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::InvalidTitle(err.msg),
+                    )
                 }
                 "MaximumOpenPullRequestsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestError::MaximumOpenPullRequestsExceeded(err.msg),
                     )
                 }
                 "MultipleRepositoriesInPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestError::MultipleRepositoriesInPullRequest(err.msg),
                     )
                 }
                 "ReferenceDoesNotExistException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::ReferenceDoesNotExist(err.msg),
+                    )
                 }
                 "ReferenceNameRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::ReferenceNameRequired(err.msg),
+                    )
                 }
                 "ReferenceTypeNotSupportedException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceTypeNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::ReferenceTypeNotSupported(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreatePullRequestError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "SourceAndDestinationAreSameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestError::SourceAndDestinationAreSame(err.msg),
                     )
                 }
                 "TargetRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TargetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::TargetRequired(err.msg),
+                    )
                 }
                 "TargetsRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TargetsRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::TargetsRequired(err.msg),
+                    )
                 }
                 "TitleRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TitleRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePullRequestError::TitleRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4964,79 +5136,94 @@ impl CreatePullRequestApprovalRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::ApprovalRuleContentRequired(err.msg),
                     )
                 }
                 "ApprovalRuleNameAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::ApprovalRuleNameAlreadyExists(err.msg),
                     )
                 }
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::ApprovalRuleNameRequired(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidApprovalRuleContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::InvalidApprovalRuleContent(err.msg),
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::InvalidApprovalRuleName(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "NumberOfRulesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::NumberOfRulesExceeded(err.msg),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePullRequestApprovalRuleError::PullRequestIdRequired(err.msg),
                     )
                 }
@@ -5138,68 +5325,82 @@ impl CreateRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidRepositoryDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRepositoryError::InvalidRepositoryDescription(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidSystemTagUsage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::InvalidSystemTagUsage(err.msg),
+                    )
                 }
                 "InvalidTagsMapException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidTagsMap(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::InvalidTagsMap(err.msg),
+                    )
                 }
                 "RepositoryLimitExceededException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::RepositoryLimitExceeded(err.msg),
+                    )
                 }
                 "RepositoryNameExistsException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryNameExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::RepositoryNameExists(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(CreateRepositoryError::TagPolicy(err.msg))
+                    return RusotoError::service(&res, CreateRepositoryError::TagPolicy(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateRepositoryError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5318,190 +5519,226 @@ impl CreateUnreferencedMergeCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::CommitMessageLengthExceeded(err.msg),
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::CommitRequired(err.msg),
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::FileContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::FileModeRequired(err.msg),
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::FolderContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUnreferencedMergeCommitError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidConflictResolution(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidConflictResolutionStrategy(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUnreferencedMergeCommitError::InvalidEmail(err.msg),
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidFileMode(err.msg),
                     )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidMergeOption(err.msg),
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUnreferencedMergeCommitError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidReplacementContent(err.msg),
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidReplacementType(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::ManualMergeRequired(err.msg),
                     )
                 }
-                "MaximumConflictResolutionEntriesExceededException" => return RusotoError::Service(
+                "MaximumConflictResolutionEntriesExceededException" => return RusotoError::service(
+                    &res,
                     CreateUnreferencedMergeCommitError::MaximumConflictResolutionEntriesExceeded(
                         err.msg,
                     ),
                 ),
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::MaximumFileContentToLoadExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::MergeOptionRequired(err.msg),
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::MultipleConflictResolutionEntries(
                             err.msg,
                         ),
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::NameLengthExceeded(err.msg),
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUnreferencedMergeCommitError::PathRequired(err.msg),
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::ReplacementContentRequired(err.msg),
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::ReplacementTypeRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUnreferencedMergeCommitError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -5636,17 +5873,20 @@ impl DeleteApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApprovalRuleTemplateError::ApprovalRuleTemplateInUse(err.msg),
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
                     )
                 }
@@ -5706,45 +5946,70 @@ impl DeleteBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(DeleteBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::BranchNameRequired(err.msg),
+                    )
                 }
                 "DefaultBranchCannotBeDeletedException" => {
-                    return RusotoError::Service(DeleteBranchError::DefaultBranchCannotBeDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::DefaultBranchCannotBeDeleted(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBranchError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(DeleteBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::InvalidBranchName(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(DeleteBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBranchError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5790,22 +6055,28 @@ impl DeleteCommentContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentContentError::CommentDeleted(err.msg),
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentContentError::CommentDoesNotExist(err.msg),
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentContentError::CommentIdRequired(err.msg),
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(DeleteCommentContentError::InvalidCommentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentContentError::InvalidCommentId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5880,76 +6151,112 @@ impl DeleteFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::BranchDoesNotExist(err.msg))
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(DeleteFileError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::BranchNameIsTagName(err.msg),
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::BranchNameRequired(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::BranchNameRequired(err.msg))
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(DeleteFileError::CommitMessageLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::CommitMessageLengthExceeded(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::FileDoesNotExist(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::FileDoesNotExist(err.msg))
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidBranchName(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::InvalidBranchName(err.msg))
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidEmail(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::InvalidEmail(err.msg))
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::InvalidParentCommitId(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidPath(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::InvalidPath(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(DeleteFileError::NameLengthExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::NameLengthExceeded(err.msg))
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::ParentCommitDoesNotExist(err.msg),
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::ParentCommitIdOutdated(err.msg),
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::ParentCommitIdRequired(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::PathRequired(err.msg))
+                    return RusotoError::service(&res, DeleteFileError::PathRequired(err.msg))
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6024,66 +6331,78 @@ impl DeletePullRequestApprovalRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::ApprovalRuleNameRequired(err.msg),
                     )
                 }
                 "CannotDeleteApprovalRuleFromTemplateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::CannotDeleteApprovalRuleFromTemplate(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::InvalidApprovalRuleName(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePullRequestApprovalRuleError::PullRequestIdRequired(err.msg),
                     )
                 }
@@ -6162,39 +6481,46 @@ impl DeleteRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteRepositoryError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6276,113 +6602,140 @@ impl DescribeMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::CommitRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::FileDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::FileDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxMergeHunksException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidMaxMergeHunks(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::InvalidMaxMergeHunks(err.msg),
+                    )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidMergeOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::InvalidMergeOption(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidPath(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::MergeOptionRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::MergeOptionRequired(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::PathRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMergeConflictsError::PathRequired(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -6482,67 +6835,80 @@ impl DescribePullRequestEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActorDoesNotExistException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::ActorDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePullRequestEventsError::ActorDoesNotExist(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidActorArnException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::InvalidActorArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePullRequestEventsError::InvalidActorArn(err.msg),
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePullRequestEventsError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "InvalidPullRequestEventTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::InvalidPullRequestEventType(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePullRequestEventsError::PullRequestIdRequired(err.msg),
                     )
                 }
@@ -6627,17 +6993,17 @@ impl DisassociateApprovalRuleTemplateFromRepositoryError {
     ) -> RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied(err.msg)),
+"EncryptionKeyDisabledException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled(err.msg)),
+"EncryptionKeyNotFoundException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound(err.msg)),
+"EncryptionKeyUnavailableException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(err.msg)),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
+"InvalidRepositoryNameException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(err.msg)),
+"RepositoryDoesNotExistException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(err.msg)),
+"RepositoryNameRequiredException" => return RusotoError::service(&res, DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -6698,59 +7064,70 @@ impl EvaluatePullRequestApprovalRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::InvalidRevisionId(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::RevisionIdRequired(err.msg),
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EvaluatePullRequestApprovalRulesError::RevisionNotCurrent(err.msg),
                     )
                 }
@@ -6818,17 +7195,20 @@ impl GetApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(err.msg),
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
                     )
                 }
@@ -6890,42 +7270,55 @@ impl GetBlobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BlobIdDoesNotExistException" => {
-                    return RusotoError::Service(GetBlobError::BlobIdDoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetBlobError::BlobIdDoesNotExist(err.msg))
                 }
                 "BlobIdRequiredException" => {
-                    return RusotoError::Service(GetBlobError::BlobIdRequired(err.msg))
+                    return RusotoError::service(&res, GetBlobError::BlobIdRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBlobError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlobError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(&res, GetBlobError::EncryptionKeyDisabled(err.msg))
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(&res, GetBlobError::EncryptionKeyNotFound(err.msg))
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlobError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "FileTooLargeException" => {
-                    return RusotoError::Service(GetBlobError::FileTooLarge(err.msg))
+                    return RusotoError::service(&res, GetBlobError::FileTooLarge(err.msg))
                 }
                 "InvalidBlobIdException" => {
-                    return RusotoError::Service(GetBlobError::InvalidBlobId(err.msg))
+                    return RusotoError::service(&res, GetBlobError::InvalidBlobId(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetBlobError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(&res, GetBlobError::InvalidRepositoryName(err.msg))
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetBlobError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlobError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetBlobError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlobError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6986,39 +7379,61 @@ impl GetBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(GetBranchError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetBranchError::BranchDoesNotExist(err.msg))
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(GetBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service(&res, GetBranchError::BranchNameRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(GetBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service(&res, GetBranchError::InvalidBranchName(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBranchError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7074,35 +7489,49 @@ impl GetCommentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(GetCommentError::CommentDeleted(err.msg))
+                    return RusotoError::service(&res, GetCommentError::CommentDeleted(err.msg))
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(GetCommentError::CommentDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentError::CommentDoesNotExist(err.msg),
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(GetCommentError::CommentIdRequired(err.msg))
+                    return RusotoError::service(&res, GetCommentError::CommentIdRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(GetCommentError::InvalidCommentId(err.msg))
+                    return RusotoError::service(&res, GetCommentError::InvalidCommentId(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7152,37 +7581,46 @@ impl GetCommentReactionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(GetCommentReactionsError::CommentDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentReactionsError::CommentDeleted(err.msg),
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(GetCommentReactionsError::CommentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentReactionsError::CommentDoesNotExist(err.msg),
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(GetCommentReactionsError::CommentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentReactionsError::CommentIdRequired(err.msg),
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(GetCommentReactionsError::InvalidCommentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentReactionsError::InvalidCommentId(err.msg),
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentReactionsError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetCommentReactionsError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentReactionsError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "InvalidReactionUserArnException" => {
-                    return RusotoError::Service(GetCommentReactionsError::InvalidReactionUserArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentReactionsError::InvalidReactionUserArn(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7244,67 +7682,80 @@ impl GetCommentsForComparedCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::CommitIdRequired(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::InvalidCommitId(err.msg),
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::InvalidMaxResults(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForComparedCommitError::RepositoryNameRequired(err.msg),
                     )
                 }
@@ -7404,87 +7855,104 @@ impl GetCommentsForPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::CommitIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentsForPullRequestError::CommitIdRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentsForPullRequestError::InvalidCommitId(err.msg),
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommentsForPullRequestError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCommentsForPullRequestError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
@@ -7580,39 +8048,64 @@ impl GetCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitIdDoesNotExistException" => {
-                    return RusotoError::Service(GetCommitError::CommitIdDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::CommitIdDoesNotExist(err.msg),
+                    )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(GetCommitError::CommitIdRequired(err.msg))
+                    return RusotoError::service(&res, GetCommitError::CommitIdRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetCommitError::InvalidCommitId(err.msg))
+                    return RusotoError::service(&res, GetCommitError::InvalidCommitId(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetCommitError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetCommitError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetCommitError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCommitError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7682,70 +8175,91 @@ impl GetDifferencesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetDifferencesError::CommitRequired(err.msg))
+                    return RusotoError::service(&res, GetDifferencesError::CommitRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDifferencesError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidCommit(err.msg))
+                    return RusotoError::service(&res, GetDifferencesError::InvalidCommit(err.msg))
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidCommitId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::InvalidCommitId(err.msg),
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::InvalidContinuationToken(err.msg),
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidMaxResults(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidPath(err.msg))
+                    return RusotoError::service(&res, GetDifferencesError::InvalidPath(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::PathDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::PathDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetDifferencesError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDifferencesError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7818,48 +8332,61 @@ impl GetFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetFileError::CommitDoesNotExist(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetFileError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFileError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFileError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(&res, GetFileError::EncryptionKeyDisabled(err.msg))
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(&res, GetFileError::EncryptionKeyNotFound(err.msg))
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFileError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::FileDoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetFileError::FileDoesNotExist(err.msg))
                 }
                 "FileTooLargeException" => {
-                    return RusotoError::Service(GetFileError::FileTooLarge(err.msg))
+                    return RusotoError::service(&res, GetFileError::FileTooLarge(err.msg))
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetFileError::InvalidCommit(err.msg))
+                    return RusotoError::service(&res, GetFileError::InvalidCommit(err.msg))
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetFileError::InvalidPath(err.msg))
+                    return RusotoError::service(&res, GetFileError::InvalidPath(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(&res, GetFileError::InvalidRepositoryName(err.msg))
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(GetFileError::PathRequired(err.msg))
+                    return RusotoError::service(&res, GetFileError::PathRequired(err.msg))
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFileError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFileError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7926,45 +8453,67 @@ impl GetFolderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetFolderError::CommitDoesNotExist(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "FolderDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::FolderDoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetFolderError::FolderDoesNotExist(err.msg))
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetFolderError::InvalidCommit(err.msg))
+                    return RusotoError::service(&res, GetFolderError::InvalidCommit(err.msg))
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetFolderError::InvalidPath(err.msg))
+                    return RusotoError::service(&res, GetFolderError::InvalidPath(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetFolderError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(GetFolderError::PathRequired(err.msg))
+                    return RusotoError::service(&res, GetFolderError::PathRequired(err.msg))
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetFolderError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8030,63 +8579,76 @@ impl GetMergeCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeCommitError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeCommitError::CommitRequired(err.msg))
+                    return RusotoError::service(&res, GetMergeCommitError::CommitRequired(err.msg))
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeCommitError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidCommit(err.msg))
+                    return RusotoError::service(&res, GetMergeCommitError::InvalidCommit(err.msg))
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidConflictDetailLevel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::InvalidConflictDetailLevel(err.msg),
+                    )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeCommitError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeCommitError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeCommitError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeCommitError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8174,110 +8736,136 @@ impl GetMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeConflictsError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::CommitRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::CommitRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidCommit(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::InvalidContinuationToken(err.msg),
+                    )
                 }
                 "InvalidDestinationCommitSpecifierException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::InvalidDestinationCommitSpecifier(err.msg),
                     )
                 }
                 "InvalidMaxConflictFilesException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidMaxConflictFiles(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::InvalidMaxConflictFiles(err.msg),
+                    )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidMergeOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::InvalidMergeOption(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidSourceCommitSpecifierException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::InvalidSourceCommitSpecifier(err.msg),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::MergeOptionRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::MergeOptionRequired(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeConflictsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(GetMergeConflictsError::TipsDivergenceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeConflictsError::TipsDivergenceExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8370,78 +8958,97 @@ impl GetMergeOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeOptionsError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeOptionsError::CommitRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::CommitRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeOptionsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidCommit(err.msg))
+                    return RusotoError::service(&res, GetMergeOptionsError::InvalidCommit(err.msg))
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidConflictDetailLevel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::InvalidConflictDetailLevel(err.msg),
+                    )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeOptionsError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeOptionsError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMergeOptionsError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeOptionsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeOptionsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(GetMergeOptionsError::TipsDivergenceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMergeOptionsError::TipsDivergenceExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8508,42 +9115,52 @@ impl GetPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(GetPullRequestError::InvalidPullRequestId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::InvalidPullRequestId(err.msg),
+                    )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(GetPullRequestError::PullRequestDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::PullRequestDoesNotExist(err.msg),
+                    )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(GetPullRequestError::PullRequestIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPullRequestError::PullRequestIdRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8602,52 +9219,62 @@ impl GetPullRequestApprovalStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::InvalidRevisionId(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestApprovalStatesError::RevisionIdRequired(err.msg),
                     )
                 }
@@ -8728,52 +9355,62 @@ impl GetPullRequestOverrideStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::InvalidRevisionId(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPullRequestOverrideStateError::RevisionIdRequired(err.msg),
                     )
                 }
@@ -8848,38 +9485,52 @@ impl GetRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetRepositoryError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetRepositoryError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetRepositoryError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8932,42 +9583,50 @@ impl GetRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryTriggersError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryTriggersError::RepositoryNameRequired(err.msg),
                     )
                 }
@@ -9014,14 +9673,16 @@ impl ListApprovalRuleTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApprovalRuleTemplatesError::InvalidContinuationToken(err.msg),
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(ListApprovalRuleTemplatesError::InvalidMaxResults(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApprovalRuleTemplatesError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9073,16 +9734,16 @@ impl ListAssociatedApprovalRuleTemplatesForRepositoryError {
     ) -> RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "EncryptionIntegrityChecksFailedException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidContinuationTokenException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(err.msg)),
-"InvalidMaxResultsException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(err.msg)),
+                                "EncryptionIntegrityChecksFailedException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied(err.msg)),
+"EncryptionKeyDisabledException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled(err.msg)),
+"EncryptionKeyNotFoundException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound(err.msg)),
+"EncryptionKeyUnavailableException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable(err.msg)),
+"InvalidContinuationTokenException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(err.msg)),
+"InvalidMaxResultsException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(err.msg)),
+"InvalidRepositoryNameException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(err.msg)),
+"RepositoryDoesNotExistException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(err.msg)),
+"RepositoryNameRequiredException" => return RusotoError::service(&res, ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -9136,39 +9797,58 @@ impl ListBranchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListBranchesError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListBranchesError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::InvalidContinuationToken(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListBranchesError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListBranchesError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(ListBranchesError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBranchesError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9230,63 +9910,82 @@ impl ListPullRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorDoesNotExistException" => {
-                    return RusotoError::Service(ListPullRequestsError::AuthorDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::AuthorDoesNotExist(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPullRequestsError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "InvalidAuthorArnException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidAuthorArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::InvalidAuthorArn(err.msg),
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::InvalidContinuationToken(err.msg),
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidMaxResults(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::InvalidMaxResults(err.msg),
+                    )
                 }
                 "InvalidPullRequestStatusException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidPullRequestStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::InvalidPullRequestStatus(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListPullRequestsError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(ListPullRequestsError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPullRequestsError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9334,15 +10033,19 @@ impl ListRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidContinuationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRepositoriesError::InvalidContinuationToken(err.msg),
+                    )
                 }
                 "InvalidOrderException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidOrder(err.msg))
+                    return RusotoError::service(&res, ListRepositoriesError::InvalidOrder(err.msg))
                 }
                 "InvalidSortByException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidSortBy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRepositoriesError::InvalidSortBy(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9393,63 +10096,73 @@ impl ListRepositoriesForApprovalRuleTemplateError {
     ) -> RusotoError<ListRepositoriesForApprovalRuleTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(
+                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(
+                    &res,
                     ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(
                         err.msg,
                     ),
                 ),
-                "ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(
+                "ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(
+                    &res,
                     ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(
                         err.msg,
                     ),
                 ),
-                "EncryptionIntegrityChecksFailedException" => return RusotoError::Service(
+                "EncryptionIntegrityChecksFailedException" => return RusotoError::service(
+                    &res,
                     ListRepositoriesForApprovalRuleTemplateError::EncryptionIntegrityChecksFailed(
                         err.msg,
                     ),
                 ),
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyAccessDenied(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyDisabled(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyUnavailable(
                             err.msg,
                         ),
                     )
                 }
-                "InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(
+                "InvalidApprovalRuleTemplateNameException" => return RusotoError::service(
+                    &res,
                     ListRepositoriesForApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(
                         err.msg,
                     ),
                 ),
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRepositoriesForApprovalRuleTemplateError::InvalidContinuationToken(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRepositoriesForApprovalRuleTemplateError::InvalidMaxResults(err.msg),
                     )
                 }
@@ -9516,24 +10229,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidResourceArn(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceArnRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceArnRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9604,97 +10321,116 @@ impl MergeBranchesByFastForwardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::BranchDoesNotExist(err.msg),
                     )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::BranchNameIsTagName(err.msg),
                     )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::BranchNameRequired(err.msg),
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByFastForwardError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByFastForwardError::CommitRequired(err.msg),
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::InvalidBranchName(err.msg),
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesByFastForwardError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByFastForwardError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::InvalidTargetBranch(err.msg),
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::ManualMergeRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByFastForwardError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -9852,191 +10588,238 @@ impl MergeBranchesBySquashError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::BranchDoesNotExist(err.msg),
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchNameIsTagName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::BranchNameIsTagName(err.msg),
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::BranchNameRequired(err.msg),
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::CommitMessageLengthExceeded(err.msg),
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::CommitRequired(err.msg),
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::FileContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::FileModeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::FileModeRequired(err.msg),
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::FolderContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidBranchName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidBranchName(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidCommit(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::InvalidConflictResolution(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidEmail(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidEmail(err.msg),
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidFileMode(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidPath(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::InvalidReplacementContent(err.msg),
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::InvalidReplacementType(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidTargetBranch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::InvalidTargetBranch(err.msg),
+                    )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::ManualMergeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::ManualMergeRequired(err.msg),
+                    )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::MultipleConflictResolutionEntries(err.msg),
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::NameLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::NameLengthExceeded(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::PathRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesBySquashError::PathRequired(err.msg),
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::ReplacementContentRequired(err.msg),
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::ReplacementTypeRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesBySquashError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -10216,197 +10999,238 @@ impl MergeBranchesByThreeWayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::BranchDoesNotExist(err.msg),
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchNameIsTagName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::BranchNameIsTagName(err.msg),
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::BranchNameRequired(err.msg),
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::CommitDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::CommitDoesNotExist(err.msg),
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::CommitMessageLengthExceeded(err.msg),
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::CommitRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::CommitRequired(err.msg),
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::FileContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::FileModeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::FileModeRequired(err.msg),
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::FolderContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidBranchName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::InvalidBranchName(err.msg),
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::InvalidCommit(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::InvalidConflictResolution(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::InvalidEmail(err.msg),
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::InvalidFileMode(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidPath(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::InvalidReplacementContent(err.msg),
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::InvalidReplacementType(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidTargetBranch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::InvalidTargetBranch(err.msg),
+                    )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::ManualMergeRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::ManualMergeRequired(err.msg),
+                    )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::MultipleConflictResolutionEntries(err.msg),
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::NameLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::NameLengthExceeded(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeBranchesByThreeWayError::PathRequired(err.msg),
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::ReplacementContentRequired(err.msg),
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::ReplacementTypeRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergeBranchesByThreeWayError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -10562,103 +11386,122 @@ impl MergePullRequestByFastForwardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::InvalidCommitId(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::ManualMergeRequired(err.msg),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::PullRequestApprovalRulesNotSatisfied(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "ReferenceDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::ReferenceDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByFastForwardError::TipOfSourceReferenceIsDifferent(
                             err.msg,
                         ),
@@ -10822,198 +11665,236 @@ impl MergePullRequestBySquashError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::CommitMessageLengthExceeded(err.msg),
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::FileContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::FolderContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestBySquashError::InvalidCommitId(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidConflictResolution(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestBySquashError::InvalidEmail(err.msg),
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestBySquashError::InvalidFileMode(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestBySquashError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidReplacementContent(err.msg),
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidReplacementType(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::ManualMergeRequired(err.msg),
                     )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::MultipleConflictResolutionEntries(err.msg),
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::NameLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestBySquashError::NameLengthExceeded(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestBySquashError::PathRequired(err.msg),
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::PullRequestApprovalRulesNotSatisfied(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::ReplacementContentRequired(err.msg),
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::ReplacementTypeRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::TipOfSourceReferenceIsDifferent(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestBySquashError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -11220,198 +12101,236 @@ impl MergePullRequestByThreeWayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::CommitMessageLengthExceeded(err.msg),
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::ConcurrentReferenceUpdate(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::FileContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::FolderContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestByThreeWayError::InvalidCommitId(err.msg),
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidConflictDetailLevel(err.msg),
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidConflictResolution(err.msg),
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidConflictResolutionStrategy(err.msg),
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestByThreeWayError::InvalidEmail(err.msg),
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidFileMode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestByThreeWayError::InvalidFileMode(err.msg),
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestByThreeWayError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidReplacementContent(err.msg),
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidReplacementType(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::ManualMergeRequired(err.msg),
                     )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::MaximumConflictResolutionEntriesExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::MaximumFileContentToLoadExceeded(err.msg),
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::MaximumItemsToCompareExceeded(err.msg),
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::MultipleConflictResolutionEntries(err.msg),
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::NameLengthExceeded(err.msg),
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergePullRequestByThreeWayError::PathRequired(err.msg),
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::PullRequestApprovalRulesNotSatisfied(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::ReplacementContentRequired(err.msg),
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::ReplacementTypeRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::TipOfSourceReferenceIsDifferent(err.msg),
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         MergePullRequestByThreeWayError::TipsDivergenceExceeded(err.msg),
                     )
                 }
@@ -11576,79 +12495,94 @@ impl OverridePullRequestApprovalRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidOverrideStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::InvalidOverrideStatus(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::InvalidRevisionId(err.msg),
                     )
                 }
                 "OverrideAlreadySetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::OverrideAlreadySet(err.msg),
                     )
                 }
                 "OverrideStatusRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::OverrideStatusRequired(err.msg),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::RevisionIdRequired(err.msg),
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OverridePullRequestApprovalRulesError::RevisionNotCurrent(err.msg),
                     )
                 }
@@ -11770,119 +12704,142 @@ impl PostCommentForComparedCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BeforeCommitIdAndAfterCommitIdAreSameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::BeforeCommitIdAndAfterCommitIdAreSame(
                             err.msg,
                         ),
                     )
                 }
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::ClientRequestTokenRequired(err.msg),
                     )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::CommentContentRequired(err.msg),
                     )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::CommentContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::CommitIdRequired(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::IdempotencyParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::InvalidClientRequestToken(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::InvalidCommitId(err.msg),
                     )
                 }
                 "InvalidFileLocationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::InvalidFileLocation(err.msg),
                     )
                 }
                 "InvalidFilePositionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::InvalidFilePosition(err.msg),
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PostCommentForComparedCommitError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForComparedCommitError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidRelativeFileVersionEnumException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::InvalidRelativeFileVersionEnum(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::PathDoesNotExist(err.msg),
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PostCommentForComparedCommitError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForComparedCommitError::PathRequired(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForComparedCommitError::RepositoryNameRequired(err.msg),
                     )
                 }
@@ -12028,139 +12985,166 @@ impl PostCommentForPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BeforeCommitIdAndAfterCommitIdAreSameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::BeforeCommitIdAndAfterCommitIdAreSame(
                             err.msg,
                         ),
                     )
                 }
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::ClientRequestTokenRequired(err.msg),
                     )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::CommentContentRequired(err.msg),
                     )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::CommentContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::CommitDoesNotExist(err.msg),
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::CommitIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForPullRequestError::CommitIdRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::IdempotencyParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::InvalidClientRequestToken(err.msg),
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::InvalidCommitId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForPullRequestError::InvalidCommitId(err.msg),
+                    )
                 }
                 "InvalidFileLocationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::InvalidFileLocation(err.msg),
                     )
                 }
                 "InvalidFilePositionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::InvalidFilePosition(err.msg),
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForPullRequestError::InvalidPath(err.msg),
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRelativeFileVersionEnumException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::InvalidRelativeFileVersionEnum(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::PathDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForPullRequestError::PathDoesNotExist(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentForPullRequestError::PathRequired(err.msg),
+                    )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentForPullRequestError::RepositoryNotAssociatedWithPullRequest(
                             err.msg,
                         ),
@@ -12276,40 +13260,52 @@ impl PostCommentReplyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::ClientRequestTokenRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentReplyError::ClientRequestTokenRequired(err.msg),
+                    )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentContentRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentReplyError::CommentContentRequired(err.msg),
+                    )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentReplyError::CommentContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentReplyError::CommentDoesNotExist(err.msg),
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentReplyError::CommentIdRequired(err.msg),
+                    )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PostCommentReplyError::IdempotencyParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(PostCommentReplyError::InvalidClientRequestToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentReplyError::InvalidClientRequestToken(err.msg),
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(PostCommentReplyError::InvalidCommentId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostCommentReplyError::InvalidCommentId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12362,35 +13358,46 @@ impl PutCommentReactionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(PutCommentReactionError::CommentDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::CommentDeleted(err.msg),
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(PutCommentReactionError::CommentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::CommentDoesNotExist(err.msg),
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(PutCommentReactionError::CommentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::CommentIdRequired(err.msg),
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(PutCommentReactionError::InvalidCommentId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::InvalidCommentId(err.msg),
+                    )
                 }
                 "InvalidReactionValueException" => {
-                    return RusotoError::Service(PutCommentReactionError::InvalidReactionValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::InvalidReactionValue(err.msg),
+                    )
                 }
                 "ReactionLimitExceededException" => {
-                    return RusotoError::Service(PutCommentReactionError::ReactionLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::ReactionLimitExceeded(err.msg),
+                    )
                 }
                 "ReactionValueRequiredException" => {
-                    return RusotoError::Service(PutCommentReactionError::ReactionValueRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutCommentReactionError::ReactionValueRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12484,106 +13491,139 @@ impl PutFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service(&res, PutFileError::BranchDoesNotExist(err.msg))
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(PutFileError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service(&res, PutFileError::BranchNameIsTagName(err.msg))
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(PutFileError::BranchNameRequired(err.msg))
+                    return RusotoError::service(&res, PutFileError::BranchNameRequired(err.msg))
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(PutFileError::CommitMessageLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::CommitMessageLengthExceeded(err.msg),
+                    )
                 }
                 "DirectoryNameConflictsWithFileNameException" => {
-                    return RusotoError::Service(PutFileError::DirectoryNameConflictsWithFileName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::DirectoryNameConflictsWithFileName(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(PutFileError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::EncryptionIntegrityChecksFailed(err.msg),
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::EncryptionKeyAccessDenied(err.msg),
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service(&res, PutFileError::EncryptionKeyDisabled(err.msg))
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service(&res, PutFileError::EncryptionKeyNotFound(err.msg))
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::EncryptionKeyUnavailable(err.msg),
+                    )
                 }
                 "FileContentRequiredException" => {
-                    return RusotoError::Service(PutFileError::FileContentRequired(err.msg))
+                    return RusotoError::service(&res, PutFileError::FileContentRequired(err.msg))
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(PutFileError::FileContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::FileContentSizeLimitExceeded(err.msg),
+                    )
                 }
                 "FileNameConflictsWithDirectoryNameException" => {
-                    return RusotoError::Service(PutFileError::FileNameConflictsWithDirectoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::FileNameConflictsWithDirectoryName(err.msg),
+                    )
                 }
                 "FilePathConflictsWithSubmodulePathException" => {
-                    return RusotoError::Service(PutFileError::FilePathConflictsWithSubmodulePath(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::FilePathConflictsWithSubmodulePath(err.msg),
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(PutFileError::FolderContentSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::FolderContentSizeLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(PutFileError::InvalidBranchName(err.msg))
+                    return RusotoError::service(&res, PutFileError::InvalidBranchName(err.msg))
                 }
                 "InvalidDeletionParameterException" => {
-                    return RusotoError::Service(PutFileError::InvalidDeletionParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::InvalidDeletionParameter(err.msg),
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(PutFileError::InvalidEmail(err.msg))
+                    return RusotoError::service(&res, PutFileError::InvalidEmail(err.msg))
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(PutFileError::InvalidFileMode(err.msg))
+                    return RusotoError::service(&res, PutFileError::InvalidFileMode(err.msg))
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(PutFileError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service(&res, PutFileError::InvalidParentCommitId(err.msg))
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PutFileError::InvalidPath(err.msg))
+                    return RusotoError::service(&res, PutFileError::InvalidPath(err.msg))
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(PutFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(&res, PutFileError::InvalidRepositoryName(err.msg))
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(PutFileError::NameLengthExceeded(err.msg))
+                    return RusotoError::service(&res, PutFileError::NameLengthExceeded(err.msg))
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::ParentCommitDoesNotExist(err.msg),
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::ParentCommitIdOutdated(err.msg),
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::ParentCommitIdRequired(err.msg),
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PutFileError::PathRequired(err.msg))
+                    return RusotoError::service(&res, PutFileError::PathRequired(err.msg))
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(PutFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "SameFileContentException" => {
-                    return RusotoError::Service(PutFileError::SameFileContent(err.msg))
+                    return RusotoError::service(&res, PutFileError::SameFileContent(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12682,111 +13722,132 @@ impl PutRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRepositoryTriggersError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidRepositoryTriggerBranchNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::InvalidRepositoryTriggerBranchName(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerCustomDataException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::InvalidRepositoryTriggerCustomData(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerDestinationArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerEventsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::InvalidRepositoryTriggerEvents(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::InvalidRepositoryTriggerName(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::InvalidRepositoryTriggerRegion(err.msg),
                     )
                 }
                 "MaximumBranchesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::MaximumBranchesExceeded(err.msg),
                     )
                 }
                 "MaximumRepositoryTriggersExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::MaximumRepositoryTriggersExceeded(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryTriggerBranchNameListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(
                             err.msg,
                         ),
                     )
                 }
                 "RepositoryTriggerDestinationArnRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(
                             err.msg,
                         ),
                     )
                 }
                 "RepositoryTriggerEventsListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryTriggerEventsListRequired(err.msg),
                     )
                 }
                 "RepositoryTriggerNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryTriggerNameRequired(err.msg),
                     )
                 }
                 "RepositoryTriggersListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
                     )
                 }
@@ -12886,31 +13947,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidResourceArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidResourceArn(err.msg),
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(TagResourceError::InvalidSystemTagUsage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidSystemTagUsage(err.msg),
+                    )
                 }
                 "InvalidTagsMapException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagsMap(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidTagsMap(err.msg))
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ResourceArnRequired(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TagPolicy(err.msg))
                 }
                 "TagsMapRequiredException" => {
-                    return RusotoError::Service(TagResourceError::TagsMapRequired(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TagsMapRequired(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12988,113 +14064,134 @@ impl TestRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerBranchNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryTriggerBranchName(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerCustomDataException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryTriggerCustomData(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerDestinationArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidRepositoryTriggerEventsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryTriggerEvents(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryTriggerName(err.msg),
                     )
                 }
                 "InvalidRepositoryTriggerRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::InvalidRepositoryTriggerRegion(err.msg),
                     )
                 }
                 "MaximumBranchesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::MaximumBranchesExceeded(err.msg),
                     )
                 }
                 "MaximumRepositoryTriggersExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::MaximumRepositoryTriggersExceeded(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryNameRequired(err.msg),
                     )
                 }
                 "RepositoryTriggerBranchNameListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(
                             err.msg,
                         ),
                     )
                 }
                 "RepositoryTriggerDestinationArnRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(
                             err.msg,
                         ),
                     )
                 }
                 "RepositoryTriggerEventsListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryTriggerEventsListRequired(err.msg),
                     )
                 }
                 "RepositoryTriggerNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryTriggerNameRequired(err.msg),
                     )
                 }
                 "RepositoryTriggersListRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TestRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
                     )
                 }
@@ -13198,33 +14295,52 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidResourceArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidResourceArn(err.msg),
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidSystemTagUsage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidSystemTagUsage(err.msg),
+                    )
                 }
                 "InvalidTagKeysListException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagKeysList(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidTagKeysList(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceArnRequired(err.msg),
+                    )
                 }
                 "TagKeysListRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::TagKeysListRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::TagKeysListRequired(err.msg),
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(UntagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TagPolicy(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13274,42 +14390,48 @@ impl UpdateApprovalRuleTemplateContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateContentRequired(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateDoesNotExist(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateNameRequired(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleTemplateContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateContent(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateName(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidRuleContentSha256Exception" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateContentError::InvalidRuleContentSha256(err.msg),
                     )
                 }
@@ -13365,10 +14487,10 @@ impl UpdateApprovalRuleTemplateDescriptionError {
     ) -> RusotoError<UpdateApprovalRuleTemplateDescriptionError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired(err.msg)),
-"InvalidApprovalRuleTemplateDescriptionException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(err.msg)),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service(&res, UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist(err.msg)),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service(&res, UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired(err.msg)),
+"InvalidApprovalRuleTemplateDescriptionException" => return RusotoError::service(&res, UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription(err.msg)),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service(&res, UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -13416,28 +14538,32 @@ impl UpdateApprovalRuleTemplateNameError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateDoesNotExist(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleTemplateNameAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameAlreadyExists(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameRequired(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApprovalRuleTemplateNameError::InvalidApprovalRuleTemplateName(
                             err.msg,
                         ),
@@ -13494,31 +14620,43 @@ impl UpdateCommentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentContentRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCommentError::CommentContentRequired(err.msg),
+                    )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCommentError::CommentContentSizeLimitExceeded(err.msg),
                     )
                 }
                 "CommentDeletedException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentDeleted(err.msg))
+                    return RusotoError::service(&res, UpdateCommentError::CommentDeleted(err.msg))
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCommentError::CommentDoesNotExist(err.msg),
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCommentError::CommentIdRequired(err.msg),
+                    )
                 }
                 "CommentNotCreatedByCallerException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentNotCreatedByCaller(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCommentError::CommentNotCreatedByCaller(err.msg),
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(UpdateCommentError::InvalidCommentId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCommentError::InvalidCommentId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13576,59 +14714,70 @@ impl UpdateDefaultBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::BranchDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::BranchDoesNotExist(err.msg),
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::BranchNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::BranchNameRequired(err.msg),
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDefaultBranchError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDefaultBranchError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::EncryptionKeyDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::EncryptionKeyDisabled(err.msg),
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::EncryptionKeyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::EncryptionKeyNotFound(err.msg),
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDefaultBranchError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::InvalidBranchName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::InvalidBranchName(err.msg),
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDefaultBranchError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13704,100 +14853,116 @@ impl UpdatePullRequestApprovalRuleContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleContentRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::ApprovalRuleContentRequired(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::ApprovalRuleDoesNotExist(
                             err.msg,
                         ),
                     )
                 }
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::ApprovalRuleNameRequired(
                             err.msg,
                         ),
                     )
                 }
-                "CannotModifyApprovalRuleFromTemplateException" => return RusotoError::Service(
+                "CannotModifyApprovalRuleFromTemplateException" => return RusotoError::service(
+                    &res,
                     UpdatePullRequestApprovalRuleContentError::CannotModifyApprovalRuleFromTemplate(
                         err.msg,
                     ),
                 ),
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyAccessDenied(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::EncryptionKeyUnavailable(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleContentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleContent(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleName(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRuleContentSha256Exception" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::InvalidRuleContentSha256(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::PullRequestAlreadyClosed(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalRuleContentError::PullRequestIdRequired(err.msg),
                     )
                 }
@@ -13908,88 +15073,104 @@ impl UpdatePullRequestApprovalStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalStateRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::ApprovalStateRequired(err.msg),
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::EncryptionIntegrityChecksFailed(
                             err.msg,
                         ),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidApprovalStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::InvalidApprovalState(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::InvalidRevisionId(err.msg),
                     )
                 }
                 "MaximumNumberOfApprovalsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::MaximumNumberOfApprovalsExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestCannotBeApprovedByAuthorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::PullRequestCannotBeApprovedByAuthor(
                             err.msg,
                         ),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::RevisionIdRequired(err.msg),
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestApprovalStateError::RevisionNotCurrent(err.msg),
                     )
                 }
@@ -14078,27 +15259,32 @@ impl UpdatePullRequestDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestDescriptionError::InvalidDescription(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestDescriptionError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestDescriptionError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestDescriptionError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestDescriptionError::PullRequestIdRequired(err.msg),
                     )
                 }
@@ -14164,57 +15350,68 @@ impl UpdatePullRequestStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::InvalidPullRequestId(err.msg),
                     )
                 }
                 "InvalidPullRequestStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::InvalidPullRequestStatus(err.msg),
                     )
                 }
                 "InvalidPullRequestStatusUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::InvalidPullRequestStatusUpdate(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "PullRequestStatusRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestStatusError::PullRequestStatusRequired(err.msg),
                     )
                 }
@@ -14286,32 +15483,40 @@ impl UpdatePullRequestTitleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::InvalidPullRequestId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePullRequestTitleError::InvalidPullRequestId(err.msg),
+                    )
                 }
                 "InvalidTitleException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::InvalidTitle(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePullRequestTitleError::InvalidTitle(err.msg),
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestTitleError::PullRequestAlreadyClosed(err.msg),
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestTitleError::PullRequestDoesNotExist(err.msg),
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePullRequestTitleError::PullRequestIdRequired(err.msg),
                     )
                 }
                 "TitleRequiredException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::TitleRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePullRequestTitleError::TitleRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14368,47 +15573,56 @@ impl UpdateRepositoryDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::EncryptionIntegrityChecksFailed(err.msg),
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::EncryptionKeyAccessDenied(err.msg),
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::EncryptionKeyDisabled(err.msg),
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::EncryptionKeyNotFound(err.msg),
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::EncryptionKeyUnavailable(err.msg),
                     )
                 }
                 "InvalidRepositoryDescriptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::InvalidRepositoryDescription(err.msg),
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::InvalidRepositoryName(err.msg),
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::RepositoryDoesNotExist(err.msg),
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRepositoryDescriptionError::RepositoryNameRequired(err.msg),
                     )
                 }
@@ -14472,24 +15686,28 @@ impl UpdateRepositoryNameError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::InvalidRepositoryName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRepositoryNameError::InvalidRepositoryName(err.msg),
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRepositoryNameError::RepositoryDoesNotExist(err.msg),
+                    )
                 }
                 "RepositoryNameExistsException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryNameExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRepositoryNameError::RepositoryNameExists(err.msg),
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRepositoryNameError::RepositoryNameRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15020,6 +16238,7 @@ impl CodeCommitClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -15035,6 +16254,7 @@ impl CodeCommitClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitClient {
         CodeCommitClient { client, region }
     }
@@ -15048,6 +16268,9 @@ impl CodeCommit for CodeCommitClient {
         input: AssociateApprovalRuleTemplateWithRepositoryInput,
     ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateApprovalRuleTemplateWithRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.AssociateApprovalRuleTemplateWithRepository",
@@ -15074,6 +16297,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchAssociateApprovalRuleTemplateWithRepositories");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.BatchAssociateApprovalRuleTemplateWithRepositories",
@@ -15100,6 +16326,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDescribeMergeConflicts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.BatchDescribeMergeConflicts",
@@ -15125,6 +16354,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDisassociateApprovalRuleTemplateFromRepositories");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.BatchDisassociateApprovalRuleTemplateFromRepositories",
@@ -15150,6 +16382,9 @@ impl CodeCommit for CodeCommitClient {
         input: BatchGetCommitsInput,
     ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetCommits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetCommits");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15168,6 +16403,9 @@ impl CodeCommit for CodeCommitClient {
         input: BatchGetRepositoriesInput,
     ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetRepositories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15187,6 +16425,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApprovalRuleTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.CreateApprovalRuleTemplate",
@@ -15209,6 +16450,9 @@ impl CodeCommit for CodeCommitClient {
         input: CreateBranchInput,
     ) -> Result<(), RusotoError<CreateBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBranch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15226,6 +16470,9 @@ impl CodeCommit for CodeCommitClient {
         input: CreateCommitInput,
     ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCommit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15244,6 +16491,9 @@ impl CodeCommit for CodeCommitClient {
         input: CreatePullRequestInput,
     ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePullRequest");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15263,6 +16513,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePullRequestApprovalRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.CreatePullRequestApprovalRule",
@@ -15285,6 +16538,9 @@ impl CodeCommit for CodeCommitClient {
         input: CreateRepositoryInput,
     ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15304,6 +16560,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUnreferencedMergeCommit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.CreateUnreferencedMergeCommit",
@@ -15327,6 +16586,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApprovalRuleTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DeleteApprovalRuleTemplate",
@@ -15349,6 +16611,9 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteBranchInput,
     ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBranch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15367,6 +16632,9 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteCommentContentInput,
     ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCommentContent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteCommentContent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15385,6 +16653,9 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteFileInput,
     ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15404,6 +16675,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePullRequestApprovalRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DeletePullRequestApprovalRule",
@@ -15426,6 +16700,9 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteRepositoryInput,
     ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15444,6 +16721,9 @@ impl CodeCommit for CodeCommitClient {
         input: DescribeMergeConflictsInput,
     ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMergeConflicts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.DescribeMergeConflicts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15463,6 +16743,9 @@ impl CodeCommit for CodeCommitClient {
         input: DescribePullRequestEventsInput,
     ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePullRequestEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DescribePullRequestEvents",
@@ -15485,6 +16768,9 @@ impl CodeCommit for CodeCommitClient {
         input: DisassociateApprovalRuleTemplateFromRepositoryInput,
     ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateApprovalRuleTemplateFromRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DisassociateApprovalRuleTemplateFromRepository",
@@ -15511,6 +16797,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<EvaluatePullRequestApprovalRulesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EvaluatePullRequestApprovalRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.EvaluatePullRequestApprovalRules",
@@ -15536,6 +16825,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetApprovalRuleTemplateInput,
     ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetApprovalRuleTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetApprovalRuleTemplate",
@@ -15558,6 +16850,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetBlobInput,
     ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetBlob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBlob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15576,6 +16871,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetBranchInput,
     ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetBranch");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15594,6 +16892,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentInput,
     ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetComment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15612,6 +16913,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentReactionsInput,
     ) -> Result<GetCommentReactionsOutput, RusotoError<GetCommentReactionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCommentReactions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetCommentReactions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15631,6 +16935,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCommentsForComparedCommit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetCommentsForComparedCommit",
@@ -15653,6 +16960,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentsForPullRequestInput,
     ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCommentsForPullRequest");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetCommentsForPullRequest",
@@ -15675,6 +16985,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommitInput,
     ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCommit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15693,6 +17006,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetDifferencesInput,
     ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDifferences");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetDifferences");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15711,6 +17027,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetFileInput,
     ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15729,6 +17048,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetFolderInput,
     ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFolder");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFolder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15747,6 +17069,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeCommitInput,
     ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMergeCommit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15765,6 +17090,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeConflictsInput,
     ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMergeConflicts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeConflicts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15783,6 +17111,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeOptionsInput,
     ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMergeOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeOptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15801,6 +17132,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetPullRequestInput,
     ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPullRequest");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetPullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15820,6 +17154,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPullRequestApprovalStates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetPullRequestApprovalStates",
@@ -15843,6 +17180,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPullRequestOverrideState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetPullRequestOverrideState",
@@ -15865,6 +17205,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetRepositoryInput,
     ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRepository");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15883,6 +17226,9 @@ impl CodeCommit for CodeCommitClient {
         input: GetRepositoryTriggersInput,
     ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRepositoryTriggers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15901,6 +17247,9 @@ impl CodeCommit for CodeCommitClient {
         input: ListApprovalRuleTemplatesInput,
     ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApprovalRuleTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.ListApprovalRuleTemplates",
@@ -15926,6 +17275,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssociatedApprovalRuleTemplatesForRepository");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.ListAssociatedApprovalRuleTemplatesForRepository",
@@ -15951,6 +17303,9 @@ impl CodeCommit for CodeCommitClient {
         input: ListBranchesInput,
     ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBranches");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.ListBranches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15969,6 +17324,9 @@ impl CodeCommit for CodeCommitClient {
         input: ListPullRequestsInput,
     ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPullRequests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.ListPullRequests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15987,6 +17345,9 @@ impl CodeCommit for CodeCommitClient {
         input: ListRepositoriesInput,
     ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRepositories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.ListRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16008,6 +17369,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRepositoriesForApprovalRuleTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.ListRepositoriesForApprovalRuleTemplate",
@@ -16033,6 +17397,9 @@ impl CodeCommit for CodeCommitClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16052,6 +17419,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergeBranchesByFastForward");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergeBranchesByFastForward",
@@ -16074,6 +17444,9 @@ impl CodeCommit for CodeCommitClient {
         input: MergeBranchesBySquashInput,
     ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergeBranchesBySquash");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.MergeBranchesBySquash");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16092,6 +17465,9 @@ impl CodeCommit for CodeCommitClient {
         input: MergeBranchesByThreeWayInput,
     ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergeBranchesByThreeWay");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergeBranchesByThreeWay",
@@ -16115,6 +17491,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergePullRequestByFastForward");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergePullRequestByFastForward",
@@ -16137,6 +17516,9 @@ impl CodeCommit for CodeCommitClient {
         input: MergePullRequestBySquashInput,
     ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergePullRequestBySquash");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergePullRequestBySquash",
@@ -16160,6 +17542,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergePullRequestByThreeWay");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergePullRequestByThreeWay",
@@ -16182,6 +17567,9 @@ impl CodeCommit for CodeCommitClient {
         input: OverridePullRequestApprovalRulesInput,
     ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("OverridePullRequestApprovalRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.OverridePullRequestApprovalRules",
@@ -16206,6 +17594,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PostCommentForComparedCommit");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.PostCommentForComparedCommit",
@@ -16228,6 +17619,9 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentForPullRequestInput,
     ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PostCommentForPullRequest");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.PostCommentForPullRequest",
@@ -16250,6 +17644,9 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentReplyInput,
     ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PostCommentReply");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16268,6 +17665,9 @@ impl CodeCommit for CodeCommitClient {
         input: PutCommentReactionInput,
     ) -> Result<(), RusotoError<PutCommentReactionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutCommentReaction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.PutCommentReaction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16285,6 +17685,9 @@ impl CodeCommit for CodeCommitClient {
         input: PutFileInput,
     ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutFile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.PutFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16303,6 +17706,9 @@ impl CodeCommit for CodeCommitClient {
         input: PutRepositoryTriggersInput,
     ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRepositoryTriggers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.PutRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16321,6 +17727,9 @@ impl CodeCommit for CodeCommitClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16338,6 +17747,9 @@ impl CodeCommit for CodeCommitClient {
         input: TestRepositoryTriggersInput,
     ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TestRepositoryTriggers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.TestRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16357,6 +17769,9 @@ impl CodeCommit for CodeCommitClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16377,6 +17792,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateContentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApprovalRuleTemplateContent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateApprovalRuleTemplateContent",
@@ -16405,6 +17823,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApprovalRuleTemplateDescription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateApprovalRuleTemplateDescription",
@@ -16433,6 +17854,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateNameError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApprovalRuleTemplateName");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateApprovalRuleTemplateName",
@@ -16455,6 +17879,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateCommentInput,
     ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateComment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateComment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16473,6 +17900,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateDefaultBranchInput,
     ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDefaultBranch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateDefaultBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16493,6 +17923,9 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdatePullRequestApprovalRuleContentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePullRequestApprovalRuleContent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestApprovalRuleContent",
@@ -16518,6 +17951,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestApprovalStateInput,
     ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePullRequestApprovalState");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestApprovalState",
@@ -16539,6 +17975,9 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePullRequestDescription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestDescription",
@@ -16561,6 +18000,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestStatusInput,
     ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePullRequestStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestStatus",
@@ -16583,6 +18025,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestTitleInput,
     ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePullRequestTitle");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdatePullRequestTitle");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16602,6 +18047,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateRepositoryDescriptionInput,
     ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRepositoryDescription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateRepositoryDescription",
@@ -16622,6 +18070,9 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateRepositoryNameInput,
     ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRepositoryName");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateRepositoryName");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16633,3 +18084,927 @@ impl CodeCommit for CodeCommitClient {
         Ok(())
     }
 }
+
+/// A blocking client for the CodeCommit API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeCommitClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeCommitBlockingClient {
+    client: CodeCommitClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeCommitBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeCommitBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeCommitBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeCommitBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeCommitBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient {
+            client: CodeCommitClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an association between an approval rule template and a specified repository. Then, the next time a pull request is created in the repository where the destination reference (if specified) matches the destination reference (branch) for the pull request, an approval rule that matches the template conditions is automatically created for that pull request. If no destination references are specified in the template, an approval rule that matches the template contents is created for all pull requests in that repository.</p>
+    ///
+    /// See `CodeCommit::associate_approval_rule_template_with_repository`.
+    pub fn associate_approval_rule_template_with_repository(
+        &self,
+        input: AssociateApprovalRuleTemplateWithRepositoryInput,
+    ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
+        self.runtime.block_on(
+            self.client
+                .associate_approval_rule_template_with_repository(input),
+        )
+    }
+
+    /// <p>Creates an association between an approval rule template and one or more specified repositories. </p>
+    ///
+    /// See `CodeCommit::batch_associate_approval_rule_template_with_repositories`.
+    pub fn batch_associate_approval_rule_template_with_repositories(
+        &self,
+        input: BatchAssociateApprovalRuleTemplateWithRepositoriesInput,
+    ) -> Result<
+        BatchAssociateApprovalRuleTemplateWithRepositoriesOutput,
+        RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_associate_approval_rule_template_with_repositories(input),
+        )
+    }
+
+    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy.</p>
+    ///
+    /// See `CodeCommit::batch_describe_merge_conflicts`.
+    pub fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_describe_merge_conflicts(input))
+    }
+
+    /// <p>Removes the association between an approval rule template and one or more specified repositories. </p>
+    ///
+    /// See `CodeCommit::batch_disassociate_approval_rule_template_from_repositories`.
+    pub fn batch_disassociate_approval_rule_template_from_repositories(
+        &self,
+        input: BatchDisassociateApprovalRuleTemplateFromRepositoriesInput,
+    ) -> Result<
+        BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput,
+        RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_disassociate_approval_rule_template_from_repositories(input),
+        )
+    }
+
+    /// <p>Returns information about the contents of one or more commits in a repository.</p>
+    ///
+    /// See `CodeCommit::batch_get_commits`.
+    pub fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
+        self.runtime.block_on(self.client.batch_get_commits(input))
+    }
+
+    /// <p><p>Returns information about one or more repositories.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
+    ///
+    /// See `CodeCommit::batch_get_repositories`.
+    pub fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
+        self.runtime
+            .block_on(self.client.batch_get_repositories(input))
+    }
+
+    /// <p>Creates a template for approval rules that can then be associated with one or more repositories in your AWS account. When you associate a template with a repository, AWS CodeCommit creates an approval rule that matches the conditions of the template for all pull requests that meet the conditions of the template. For more information, see <a>AssociateApprovalRuleTemplateWithRepository</a>.</p>
+    ///
+    /// See `CodeCommit::create_approval_rule_template`.
+    pub fn create_approval_rule_template(
+        &self,
+        input: CreateApprovalRuleTemplateInput,
+    ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_approval_rule_template(input))
+    }
+
+    /// <p><p>Creates a branch in a repository and points the branch to a commit.</p> <note> <p>Calling the create branch operation does not set a repository&#39;s default branch. To do this, call the update default branch operation.</p> </note></p>
+    ///
+    /// See `CodeCommit::create_branch`.
+    pub fn create_branch(
+        &self,
+        input: CreateBranchInput,
+    ) -> Result<(), RusotoError<CreateBranchError>> {
+        self.runtime.block_on(self.client.create_branch(input))
+    }
+
+    /// <p>Creates a commit for a repository on the tip of a specified branch.</p>
+    ///
+    /// See `CodeCommit::create_commit`.
+    pub fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
+        self.runtime.block_on(self.client.create_commit(input))
+    }
+
+    /// <p>Creates a pull request in the specified repository.</p>
+    ///
+    /// See `CodeCommit::create_pull_request`.
+    pub fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
+        self.runtime
+            .block_on(self.client.create_pull_request(input))
+    }
+
+    /// <p>Creates an approval rule for a pull request.</p>
+    ///
+    /// See `CodeCommit::create_pull_request_approval_rule`.
+    pub fn create_pull_request_approval_rule(
+        &self,
+        input: CreatePullRequestApprovalRuleInput,
+    ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.create_pull_request_approval_rule(input))
+    }
+
+    /// <p>Creates a new, empty repository.</p>
+    ///
+    /// See `CodeCommit::create_repository`.
+    pub fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
+        self.runtime.block_on(self.client.create_repository(input))
+    }
+
+    /// <p><p>Creates an unreferenced commit that represents the result of merging two branches using a specified merge strategy. This can help you determine the outcome of a potential merge. This API cannot be used with the fast-forward merge strategy because that strategy does not create a merge commit.</p> <note> <p>This unreferenced merge commit can only be accessed using the GetCommit API or through git commands such as git fetch. To retrieve this commit, you must specify its commit ID or otherwise reference it.</p> </note></p>
+    ///
+    /// See `CodeCommit::create_unreferenced_merge_commit`.
+    pub fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
+    {
+        self.runtime
+            .block_on(self.client.create_unreferenced_merge_commit(input))
+    }
+
+    /// <p>Deletes a specified approval rule template. Deleting a template does not remove approval rules on pull requests already created with the template.</p>
+    ///
+    /// See `CodeCommit::delete_approval_rule_template`.
+    pub fn delete_approval_rule_template(
+        &self,
+        input: DeleteApprovalRuleTemplateInput,
+    ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_approval_rule_template(input))
+    }
+
+    /// <p>Deletes a branch from a repository, unless that branch is the default branch for the repository. </p>
+    ///
+    /// See `CodeCommit::delete_branch`.
+    pub fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
+        self.runtime.block_on(self.client.delete_branch(input))
+    }
+
+    /// <p>Deletes the content of a comment made on a change, file, or commit in a repository.</p>
+    ///
+    /// See `CodeCommit::delete_comment_content`.
+    pub fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
+        self.runtime
+            .block_on(self.client.delete_comment_content(input))
+    }
+
+    /// <p>Deletes a specified file from a specified branch. A commit is created on the branch that contains the revision. The file still exists in the commits earlier to the commit that contains the deletion.</p>
+    ///
+    /// See `CodeCommit::delete_file`.
+    pub fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
+        self.runtime.block_on(self.client.delete_file(input))
+    }
+
+    /// <p>Deletes an approval rule from a specified pull request. Approval rules can be deleted from a pull request only if the pull request is open, and if the approval rule was created specifically for a pull request and not generated from an approval rule template associated with the repository where the pull request was created. You cannot delete an approval rule from a merged or closed pull request.</p>
+    ///
+    /// See `CodeCommit::delete_pull_request_approval_rule`.
+    pub fn delete_pull_request_approval_rule(
+        &self,
+        input: DeletePullRequestApprovalRuleInput,
+    ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_pull_request_approval_rule(input))
+    }
+
+    /// <p><p>Deletes a repository. If a specified repository was already deleted, a null repository ID is returned.</p> <important> <p>Deleting a repository also deletes all associated objects and metadata. After a repository is deleted, all future push calls to the deleted repository fail.</p> </important></p>
+    ///
+    /// See `CodeCommit::delete_repository`.
+    pub fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
+        self.runtime.block_on(self.client.delete_repository(input))
+    }
+
+    /// <p>Returns information about one or more merge conflicts in the attempted merge of two commit specifiers using the squash or three-way merge strategy. If the merge option for the attempted merge is specified as FAST_FORWARD_MERGE, an exception is thrown.</p>
+    ///
+    /// See `CodeCommit::describe_merge_conflicts`.
+    pub fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
+        self.runtime
+            .block_on(self.client.describe_merge_conflicts(input))
+    }
+
+    /// <p>Returns information about one or more pull request events.</p>
+    ///
+    /// See `CodeCommit::describe_pull_request_events`.
+    pub fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
+        self.runtime
+            .block_on(self.client.describe_pull_request_events(input))
+    }
+
+    /// <p>Removes the association between a template and a repository so that approval rules based on the template are not automatically created when pull requests are created in the specified repository. This does not delete any approval rules previously created for pull requests through the template association.</p>
+    ///
+    /// See `CodeCommit::disassociate_approval_rule_template_from_repository`.
+    pub fn disassociate_approval_rule_template_from_repository(
+        &self,
+        input: DisassociateApprovalRuleTemplateFromRepositoryInput,
+    ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
+        self.runtime.block_on(
+            self.client
+                .disassociate_approval_rule_template_from_repository(input),
+        )
+    }
+
+    /// <p>Evaluates whether a pull request has met all the conditions specified in its associated approval rules.</p>
+    ///
+    /// See `CodeCommit::evaluate_pull_request_approval_rules`.
+    pub fn evaluate_pull_request_approval_rules(
+        &self,
+        input: EvaluatePullRequestApprovalRulesInput,
+    ) -> Result<
+        EvaluatePullRequestApprovalRulesOutput,
+        RusotoError<EvaluatePullRequestApprovalRulesError>,
+    > {
+        self.runtime
+            .block_on(self.client.evaluate_pull_request_approval_rules(input))
+    }
+
+    /// <p>Returns information about a specified approval rule template.</p>
+    ///
+    /// See `CodeCommit::get_approval_rule_template`.
+    pub fn get_approval_rule_template(
+        &self,
+        input: GetApprovalRuleTemplateInput,
+    ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
+        self.runtime
+            .block_on(self.client.get_approval_rule_template(input))
+    }
+
+    /// <p>Returns the base-64 encoded content of an individual blob in a repository.</p>
+    ///
+    /// See `CodeCommit::get_blob`.
+    pub fn get_blob(
+        &self,
+        input: GetBlobInput,
+    ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
+        self.runtime.block_on(self.client.get_blob(input))
+    }
+
+    /// <p>Returns information about a repository branch, including its name and the last commit ID.</p>
+    ///
+    /// See `CodeCommit::get_branch`.
+    pub fn get_branch(
+        &self,
+        input: GetBranchInput,
+    ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
+        self.runtime.block_on(self.client.get_branch(input))
+    }
+
+    /// <p><p>Returns the content of a comment made on a change, file, or commit in a repository. </p> <note> <p>Reaction counts might include numbers from user identities who were deleted after the reaction was made. For a count of reactions from active identities, use GetCommentReactions.</p> </note></p>
+    ///
+    /// See `CodeCommit::get_comment`.
+    pub fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
+        self.runtime.block_on(self.client.get_comment(input))
+    }
+
+    /// <p>Returns information about reactions to a specified comment ID. Reactions from users who have been deleted will not be included in the count.</p>
+    ///
+    /// See `CodeCommit::get_comment_reactions`.
+    pub fn get_comment_reactions(
+        &self,
+        input: GetCommentReactionsInput,
+    ) -> Result<GetCommentReactionsOutput, RusotoError<GetCommentReactionsError>> {
+        self.runtime
+            .block_on(self.client.get_comment_reactions(input))
+    }
+
+    /// <p><p>Returns information about comments made on the comparison between two commits.</p> <note> <p>Reaction counts might include numbers from user identities who were deleted after the reaction was made. For a count of reactions from active identities, use GetCommentReactions.</p> </note></p>
+    ///
+    /// See `CodeCommit::get_comments_for_compared_commit`.
+    pub fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
+    {
+        self.runtime
+            .block_on(self.client.get_comments_for_compared_commit(input))
+    }
+
+    /// <p><p>Returns comments made on a pull request.</p> <note> <p>Reaction counts might include numbers from user identities who were deleted after the reaction was made. For a count of reactions from active identities, use GetCommentReactions.</p> </note></p>
+    ///
+    /// See `CodeCommit::get_comments_for_pull_request`.
+    pub fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
+        self.runtime
+            .block_on(self.client.get_comments_for_pull_request(input))
+    }
+
+    /// <p>Returns information about a commit, including commit message and committer information.</p>
+    ///
+    /// See `CodeCommit::get_commit`.
+    pub fn get_commit(
+        &self,
+        input: GetCommitInput,
+    ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
+        self.runtime.block_on(self.client.get_commit(input))
+    }
+
+    /// <p>Returns information about the differences in a valid commit specifier (such as a branch, tag, HEAD, commit ID, or other fully qualified reference). Results can be limited to a specified path.</p>
+    ///
+    /// See `CodeCommit::get_differences`.
+    pub fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
+        self.runtime.block_on(self.client.get_differences(input))
+    }
+
+    /// <p>Returns the base-64 encoded contents of a specified file and its metadata.</p>
+    ///
+    /// See `CodeCommit::get_file`.
+    pub fn get_file(
+        &self,
+        input: GetFileInput,
+    ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
+        self.runtime.block_on(self.client.get_file(input))
+    }
+
+    /// <p>Returns the contents of a specified folder in a repository.</p>
+    ///
+    /// See `CodeCommit::get_folder`.
+    pub fn get_folder(
+        &self,
+        input: GetFolderInput,
+    ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
+        self.runtime.block_on(self.client.get_folder(input))
+    }
+
+    /// <p>Returns information about a specified merge commit.</p>
+    ///
+    /// See `CodeCommit::get_merge_commit`.
+    pub fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
+        self.runtime.block_on(self.client.get_merge_commit(input))
+    }
+
+    /// <p>Returns information about merge conflicts between the before and after commit IDs for a pull request in a repository.</p>
+    ///
+    /// See `CodeCommit::get_merge_conflicts`.
+    pub fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
+        self.runtime
+            .block_on(self.client.get_merge_conflicts(input))
+    }
+
+    /// <p>Returns information about the merge options available for merging two specified branches. For details about why a merge option is not available, use GetMergeConflicts or DescribeMergeConflicts.</p>
+    ///
+    /// See `CodeCommit::get_merge_options`.
+    pub fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
+        self.runtime.block_on(self.client.get_merge_options(input))
+    }
+
+    /// <p>Gets information about a pull request in a specified repository.</p>
+    ///
+    /// See `CodeCommit::get_pull_request`.
+    pub fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
+        self.runtime.block_on(self.client.get_pull_request(input))
+    }
+
+    /// <p>Gets information about the approval states for a specified pull request. Approval states only apply to pull requests that have one or more approval rules applied to them.</p>
+    ///
+    /// See `CodeCommit::get_pull_request_approval_states`.
+    pub fn get_pull_request_approval_states(
+        &self,
+        input: GetPullRequestApprovalStatesInput,
+    ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_pull_request_approval_states(input))
+    }
+
+    /// <p>Returns information about whether approval rules have been set aside (overridden) for a pull request, and if so, the Amazon Resource Name (ARN) of the user or identity that overrode the rules and their requirements for the pull request.</p>
+    ///
+    /// See `CodeCommit::get_pull_request_override_state`.
+    pub fn get_pull_request_override_state(
+        &self,
+        input: GetPullRequestOverrideStateInput,
+    ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
+    {
+        self.runtime
+            .block_on(self.client.get_pull_request_override_state(input))
+    }
+
+    /// <p><p>Returns information about a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
+    ///
+    /// See `CodeCommit::get_repository`.
+    pub fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
+        self.runtime.block_on(self.client.get_repository(input))
+    }
+
+    /// <p>Gets information about triggers configured for a repository.</p>
+    ///
+    /// See `CodeCommit::get_repository_triggers`.
+    pub fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
+        self.runtime
+            .block_on(self.client.get_repository_triggers(input))
+    }
+
+    /// <p>Lists all approval rule templates in the specified AWS Region in your AWS account. If an AWS Region is not specified, the AWS Region where you are signed in is used.</p>
+    ///
+    /// See `CodeCommit::list_approval_rule_templates`.
+    pub fn list_approval_rule_templates(
+        &self,
+        input: ListApprovalRuleTemplatesInput,
+    ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_approval_rule_templates(input))
+    }
+
+    /// <p>Lists all approval rule templates that are associated with a specified repository.</p>
+    ///
+    /// See `CodeCommit::list_associated_approval_rule_templates_for_repository`.
+    pub fn list_associated_approval_rule_templates_for_repository(
+        &self,
+        input: ListAssociatedApprovalRuleTemplatesForRepositoryInput,
+    ) -> Result<
+        ListAssociatedApprovalRuleTemplatesForRepositoryOutput,
+        RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_associated_approval_rule_templates_for_repository(input),
+        )
+    }
+
+    /// <p>Gets information about one or more branches in a repository.</p>
+    ///
+    /// See `CodeCommit::list_branches`.
+    pub fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
+        self.runtime.block_on(self.client.list_branches(input))
+    }
+
+    /// <p>Returns a list of pull requests for a specified repository. The return list can be refined by pull request status or pull request author ARN.</p>
+    ///
+    /// See `CodeCommit::list_pull_requests`.
+    pub fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
+        self.runtime.block_on(self.client.list_pull_requests(input))
+    }
+
+    /// <p>Gets information about one or more repositories.</p>
+    ///
+    /// See `CodeCommit::list_repositories`.
+    pub fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
+        self.runtime.block_on(self.client.list_repositories(input))
+    }
+
+    /// <p>Lists all repositories associated with the specified approval rule template.</p>
+    ///
+    /// See `CodeCommit::list_repositories_for_approval_rule_template`.
+    pub fn list_repositories_for_approval_rule_template(
+        &self,
+        input: ListRepositoriesForApprovalRuleTemplateInput,
+    ) -> Result<
+        ListRepositoriesForApprovalRuleTemplateOutput,
+        RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_repositories_for_approval_rule_template(input),
+        )
+    }
+
+    /// <p>Gets information about AWS tags for a specified Amazon Resource Name (ARN) in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the<i> AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `CodeCommit::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Merges two branches using the fast-forward merge strategy.</p>
+    ///
+    /// See `CodeCommit::merge_branches_by_fast_forward`.
+    pub fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
+    {
+        self.runtime
+            .block_on(self.client.merge_branches_by_fast_forward(input))
+    }
+
+    /// <p>Merges two branches using the squash merge strategy.</p>
+    ///
+    /// See `CodeCommit::merge_branches_by_squash`.
+    pub fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
+        self.runtime
+            .block_on(self.client.merge_branches_by_squash(input))
+    }
+
+    /// <p>Merges two specified branches using the three-way merge strategy.</p>
+    ///
+    /// See `CodeCommit::merge_branches_by_three_way`.
+    pub fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
+        self.runtime
+            .block_on(self.client.merge_branches_by_three_way(input))
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the fast-forward merge strategy. If the merge is successful, it closes the pull request.</p>
+    ///
+    /// See `CodeCommit::merge_pull_request_by_fast_forward`.
+    pub fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
+    {
+        self.runtime
+            .block_on(self.client.merge_pull_request_by_fast_forward(input))
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the squash merge strategy. If the merge is successful, it closes the pull request.</p>
+    ///
+    /// See `CodeCommit::merge_pull_request_by_squash`.
+    pub fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
+        self.runtime
+            .block_on(self.client.merge_pull_request_by_squash(input))
+    }
+
+    /// <p>Attempts to merge the source commit of a pull request into the specified destination branch for that pull request at the specified commit using the three-way merge strategy. If the merge is successful, it closes the pull request.</p>
+    ///
+    /// See `CodeCommit::merge_pull_request_by_three_way`.
+    pub fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
+    {
+        self.runtime
+            .block_on(self.client.merge_pull_request_by_three_way(input))
+    }
+
+    /// <p>Sets aside (overrides) all approval rule requirements for a specified pull request.</p>
+    ///
+    /// See `CodeCommit::override_pull_request_approval_rules`.
+    pub fn override_pull_request_approval_rules(
+        &self,
+        input: OverridePullRequestApprovalRulesInput,
+    ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
+        self.runtime
+            .block_on(self.client.override_pull_request_approval_rules(input))
+    }
+
+    /// <p>Posts a comment on the comparison between two commits.</p>
+    ///
+    /// See `CodeCommit::post_comment_for_compared_commit`.
+    pub fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
+    {
+        self.runtime
+            .block_on(self.client.post_comment_for_compared_commit(input))
+    }
+
+    /// <p>Posts a comment on a pull request.</p>
+    ///
+    /// See `CodeCommit::post_comment_for_pull_request`.
+    pub fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
+        self.runtime
+            .block_on(self.client.post_comment_for_pull_request(input))
+    }
+
+    /// <p>Posts a comment in reply to an existing comment on a comparison between commits or a pull request.</p>
+    ///
+    /// See `CodeCommit::post_comment_reply`.
+    pub fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
+        self.runtime.block_on(self.client.post_comment_reply(input))
+    }
+
+    /// <p>Adds or updates a reaction to a specified comment for the user whose identity is used to make the request. You can only add or update a reaction for yourself. You cannot add, modify, or delete a reaction for another user.</p>
+    ///
+    /// See `CodeCommit::put_comment_reaction`.
+    pub fn put_comment_reaction(
+        &self,
+        input: PutCommentReactionInput,
+    ) -> Result<(), RusotoError<PutCommentReactionError>> {
+        self.runtime
+            .block_on(self.client.put_comment_reaction(input))
+    }
+
+    /// <p>Adds or updates a file in a branch in an AWS CodeCommit repository, and generates a commit for the addition in the specified branch.</p>
+    ///
+    /// See `CodeCommit::put_file`.
+    pub fn put_file(
+        &self,
+        input: PutFileInput,
+    ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
+        self.runtime.block_on(self.client.put_file(input))
+    }
+
+    /// <p>Replaces all triggers for a repository. Used to create or delete triggers.</p>
+    ///
+    /// See `CodeCommit::put_repository_triggers`.
+    pub fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
+        self.runtime
+            .block_on(self.client.put_repository_triggers(input))
+    }
+
+    /// <p>Adds or updates tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `CodeCommit::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Tests the functionality of repository triggers by sending information to the trigger target. If real data is available in the repository, the test sends data from the last commit. If no data is available, sample data is generated.</p>
+    ///
+    /// See `CodeCommit::test_repository_triggers`.
+    pub fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
+        self.runtime
+            .block_on(self.client.test_repository_triggers(input))
+    }
+
+    /// <p>Removes tags for a resource in AWS CodeCommit. For a list of valid resources in AWS CodeCommit, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/auth-and-access-control-iam-access-control-identity-based.html#arn-formats">CodeCommit Resources and Operations</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `CodeCommit::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the content of an approval rule template. You can change the number of required approvals, the membership of the approval rule, and whether an approval pool is defined.</p>
+    ///
+    /// See `CodeCommit::update_approval_rule_template_content`.
+    pub fn update_approval_rule_template_content(
+        &self,
+        input: UpdateApprovalRuleTemplateContentInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateContentOutput,
+        RusotoError<UpdateApprovalRuleTemplateContentError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_approval_rule_template_content(input))
+    }
+
+    /// <p>Updates the description for a specified approval rule template.</p>
+    ///
+    /// See `CodeCommit::update_approval_rule_template_description`.
+    pub fn update_approval_rule_template_description(
+        &self,
+        input: UpdateApprovalRuleTemplateDescriptionInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateDescriptionOutput,
+        RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_approval_rule_template_description(input))
+    }
+
+    /// <p>Updates the name of a specified approval rule template.</p>
+    ///
+    /// See `CodeCommit::update_approval_rule_template_name`.
+    pub fn update_approval_rule_template_name(
+        &self,
+        input: UpdateApprovalRuleTemplateNameInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateNameOutput,
+        RusotoError<UpdateApprovalRuleTemplateNameError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_approval_rule_template_name(input))
+    }
+
+    /// <p>Replaces the contents of a comment.</p>
+    ///
+    /// See `CodeCommit::update_comment`.
+    pub fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
+        self.runtime.block_on(self.client.update_comment(input))
+    }
+
+    /// <p><p>Sets or changes the default branch name for the specified repository.</p> <note> <p>If you use this operation to change the default branch name to the current default branch name, a success message is returned even though the default branch did not change.</p> </note></p>
+    ///
+    /// See `CodeCommit::update_default_branch`.
+    pub fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
+        self.runtime
+            .block_on(self.client.update_default_branch(input))
+    }
+
+    /// <p>Updates the structure of an approval rule created specifically for a pull request. For example, you can change the number of required approvers and the approval pool for approvers. </p>
+    ///
+    /// See `CodeCommit::update_pull_request_approval_rule_content`.
+    pub fn update_pull_request_approval_rule_content(
+        &self,
+        input: UpdatePullRequestApprovalRuleContentInput,
+    ) -> Result<
+        UpdatePullRequestApprovalRuleContentOutput,
+        RusotoError<UpdatePullRequestApprovalRuleContentError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_pull_request_approval_rule_content(input))
+    }
+
+    /// <p>Updates the state of a user's approval on a pull request. The user is derived from the signed-in account when the request is made.</p>
+    ///
+    /// See `CodeCommit::update_pull_request_approval_state`.
+    pub fn update_pull_request_approval_state(
+        &self,
+        input: UpdatePullRequestApprovalStateInput,
+    ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
+        self.runtime
+            .block_on(self.client.update_pull_request_approval_state(input))
+    }
+
+    /// <p>Replaces the contents of the description of a pull request.</p>
+    ///
+    /// See `CodeCommit::update_pull_request_description`.
+    pub fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_pull_request_description(input))
+    }
+
+    /// <p>Updates the status of a pull request. </p>
+    ///
+    /// See `CodeCommit::update_pull_request_status`.
+    pub fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
+        self.runtime
+            .block_on(self.client.update_pull_request_status(input))
+    }
+
+    /// <p>Replaces the title of a pull request.</p>
+    ///
+    /// See `CodeCommit::update_pull_request_title`.
+    pub fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
+        self.runtime
+            .block_on(self.client.update_pull_request_title(input))
+    }
+
+    /// <p><p>Sets or changes the comment or description for a repository.</p> <note> <p>The description field for a repository accepts all HTML characters and all valid Unicode characters. Applications that do not HTML-encode the description and display it in a webpage can expose users to potentially malicious code. Make sure that you HTML-encode the description field in any application that uses this API to display the repository description on a webpage.</p> </note></p>
+    ///
+    /// See `CodeCommit::update_repository_description`.
+    pub fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
+        self.runtime
+            .block_on(self.client.update_repository_description(input))
+    }
+
+    /// <p>Renames a repository. The repository name must be unique across the calling AWS account. Repository names are limited to 100 alphanumeric, dash, and underscore characters, and cannot include certain characters. The suffix .git is prohibited. For more information about the limits on repository names, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/limits.html">Limits</a> in the AWS CodeCommit User Guide.</p>
+    ///
+    /// See `CodeCommit::update_repository_name`.
+    pub fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
+        self.runtime
+            .block_on(self.client.update_repository_name(input))
+    }
+}
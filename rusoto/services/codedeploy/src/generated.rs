@@ -2438,39 +2438,46 @@ impl AddTagsToOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddTagsToOnPremisesInstancesError::InstanceLimitExceeded(err.msg),
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddTagsToOnPremisesInstancesError::InstanceNameRequired(err.msg),
                     )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddTagsToOnPremisesInstancesError::InstanceNotRegistered(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddTagsToOnPremisesInstancesError::InvalidInstanceName(err.msg),
                     )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsToOnPremisesInstancesError::InvalidTag(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToOnPremisesInstancesError::InvalidTag(err.msg),
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddTagsToOnPremisesInstancesError::TagLimitExceeded(err.msg),
                     )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(AddTagsToOnPremisesInstancesError::TagRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToOnPremisesInstancesError::TagRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2528,32 +2535,38 @@ impl BatchGetApplicationRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationRevisionsError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationRevisionsError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationRevisionsError::BatchLimitExceeded(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationRevisionsError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationRevisionsError::InvalidRevision(err.msg),
                     )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationRevisionsError::RevisionRequired(err.msg),
                     )
                 }
@@ -2606,24 +2619,28 @@ impl BatchGetApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationsError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetApplicationsError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetApplicationsError::BatchLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetApplicationsError::BatchLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(BatchGetApplicationsError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetApplicationsError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2668,37 +2685,44 @@ impl BatchGetDeploymentGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentGroupsError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentGroupsError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetDeploymentGroupsError::BatchLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDeploymentGroupsError::BatchLimitExceeded(err.msg),
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentGroupsError::DeploymentConfigDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentGroupsError::DeploymentGroupNameRequired(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentGroupsError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentGroupsError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
@@ -2762,37 +2786,44 @@ impl BatchGetDeploymentInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::BatchLimitExceeded(err.msg),
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::DeploymentDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::DeploymentIdRequired(err.msg),
                     )
                 }
                 "InstanceIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::InstanceIdRequired(err.msg),
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::InvalidComputePlatform(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::InvalidDeploymentId(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentInstancesError::InvalidInstanceName(err.msg),
                     )
                 }
@@ -2860,47 +2891,56 @@ impl BatchGetDeploymentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::DeploymentDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::DeploymentIdRequired(err.msg),
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::DeploymentNotStarted(err.msg),
                     )
                 }
                 "DeploymentTargetDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::DeploymentTargetDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentTargetIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::DeploymentTargetIdRequired(err.msg),
                     )
                 }
                 "DeploymentTargetListSizeExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::DeploymentTargetListSizeExceeded(err.msg),
                     )
                 }
                 "InstanceDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::InstanceDoesNotExist(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::InvalidDeploymentId(err.msg),
                     )
                 }
                 "InvalidDeploymentTargetIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetDeploymentTargetsError::InvalidDeploymentTargetId(err.msg),
                     )
                 }
@@ -2962,19 +3002,22 @@ impl BatchGetDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::BatchLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDeploymentsError::BatchLimitExceeded(err.msg),
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDeploymentsError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDeploymentsError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3012,17 +3055,20 @@ impl BatchGetOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetOnPremisesInstancesError::BatchLimitExceeded(err.msg),
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetOnPremisesInstancesError::InstanceNameRequired(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetOnPremisesInstancesError::InvalidInstanceName(err.msg),
                     )
                 }
@@ -3076,42 +3122,50 @@ impl ContinueDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ContinueDeploymentError::DeploymentAlreadyCompleted(err.msg),
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(ContinueDeploymentError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ContinueDeploymentError::DeploymentDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(ContinueDeploymentError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ContinueDeploymentError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "DeploymentIsNotInReadyStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ContinueDeploymentError::DeploymentIsNotInReadyState(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ContinueDeploymentError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ContinueDeploymentError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "InvalidDeploymentStatusException" => {
-                    return RusotoError::Service(ContinueDeploymentError::InvalidDeploymentStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ContinueDeploymentError::InvalidDeploymentStatus(err.msg),
+                    )
                 }
                 "InvalidDeploymentWaitTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ContinueDeploymentError::InvalidDeploymentWaitType(err.msg),
                     )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ContinueDeploymentError::UnsupportedActionForDeploymentType(err.msg),
                     )
                 }
@@ -3166,32 +3220,40 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationAlreadyExistsException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ApplicationAlreadyExists(err.msg),
+                    )
                 }
                 "ApplicationLimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ApplicationLimitExceeded(err.msg),
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidComputePlatform(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidComputePlatform(err.msg),
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidTagsToAdd(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3272,114 +3334,142 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(CreateDeploymentError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::DeploymentConfigDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::DeploymentGroupDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::DeploymentGroupNameRequired(err.msg),
                     )
                 }
                 "DeploymentLimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::DeploymentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::DeploymentLimitExceeded(err.msg),
+                    )
                 }
                 "DescriptionTooLongException" => {
-                    return RusotoError::Service(CreateDeploymentError::DescriptionTooLong(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::DescriptionTooLong(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidAutoRollbackConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidAutoRollbackConfig(err.msg),
+                    )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidAutoScalingGroup(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidAutoScalingGroup(err.msg),
+                    )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidDeploymentGroupName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidDeploymentGroupName(err.msg),
+                    )
                 }
                 "InvalidFileExistsBehaviorException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidFileExistsBehavior(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidFileExistsBehavior(err.msg),
+                    )
                 }
                 "InvalidGitHubAccountTokenException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidGitHubAccountToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidGitHubAccountToken(err.msg),
+                    )
                 }
                 "InvalidIgnoreApplicationStopFailuresValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::InvalidIgnoreApplicationStopFailuresValue(err.msg),
                     )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidLoadBalancerInfo(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidLoadBalancerInfo(err.msg),
+                    )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidRevision(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidRevision(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::InvalidRole(err.msg))
                 }
                 "InvalidTargetInstancesException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidTargetInstances(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::InvalidTargetInstances(err.msg),
+                    )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::InvalidTrafficRoutingConfiguration(err.msg),
                     )
                 }
                 "InvalidUpdateOutdatedInstancesOnlyValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentError::InvalidUpdateOutdatedInstancesOnlyValue(err.msg),
                     )
                 }
                 "RevisionDoesNotExistException" => {
-                    return RusotoError::Service(CreateDeploymentError::RevisionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::RevisionDoesNotExist(err.msg),
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentError::RevisionRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::RevisionRequired(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3452,37 +3542,44 @@ impl CreateDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::DeploymentConfigAlreadyExists(err.msg),
                     )
                 }
                 "DeploymentConfigLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::DeploymentConfigLimitExceeded(err.msg),
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::InvalidComputePlatform(err.msg),
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
                 "InvalidMinimumHealthyHostValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::InvalidMinimumHealthyHostValue(err.msg),
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration(err.msg),
                     )
                 }
@@ -3598,157 +3695,202 @@ impl CreateDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlarmsLimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::AlarmsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::AlarmsLimitExceeded(err.msg),
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::DeploymentGroupAlreadyExists(err.msg),
                     )
                 }
                 "DeploymentGroupLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::DeploymentGroupLimitExceeded(err.msg),
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
                     )
                 }
                 "ECSServiceMappingLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::ECSServiceMappingLimitExceeded(err.msg),
                     )
                 }
                 "InvalidAlarmConfigException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidAlarmConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidAlarmConfig(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidAutoRollbackConfig(err.msg),
                     )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidAutoScalingGroup(err.msg),
                     )
                 }
                 "InvalidBlueGreenDeploymentConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
                 "InvalidDeploymentStyleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidDeploymentStyle(err.msg),
                     )
                 }
                 "InvalidEC2TagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidEC2TagCombination(err.msg),
                     )
                 }
                 "InvalidEC2TagException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidEC2Tag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidEC2Tag(err.msg),
+                    )
                 }
                 "InvalidECSServiceException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidECSService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidECSService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidLoadBalancerInfo(err.msg),
                     )
                 }
                 "InvalidOnPremisesTagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidOnPremisesTagCombination(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidRole(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidTag(err.msg),
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTagsToAdd(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidTagsToAdd(err.msg),
+                    )
                 }
                 "InvalidTargetGroupPairException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidTargetGroupPair(err.msg),
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::InvalidTrafficRoutingConfiguration(err.msg),
                     )
                 }
                 "InvalidTriggerConfigException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTriggerConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::InvalidTriggerConfig(err.msg),
+                    )
                 }
                 "LifecycleHookLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::LifecycleHookLimitExceeded(err.msg),
                     )
                 }
                 "RoleRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::RoleRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::RoleRequired(err.msg),
+                    )
                 }
                 "TagSetListLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::TagSetListLimitExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentGroupError::Throttling(err.msg),
+                    )
                 }
                 "TriggerTargetsLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
                     )
                 }
@@ -3854,17 +3996,19 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(DeleteApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::InvalidRole(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3902,24 +4046,28 @@ impl DeleteDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentConfigError::DeploymentConfigInUse(err.msg),
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteDeploymentConfigError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentConfigError::InvalidOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3964,27 +4112,34 @@ impl DeleteDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentGroupError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentGroupError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(DeleteDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeploymentGroupError::InvalidRole(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4032,29 +4187,34 @@ impl DeleteGitHubAccountTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GitHubAccountTokenDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteGitHubAccountTokenError::GitHubAccountTokenDoesNotExist(err.msg),
                     )
                 }
                 "GitHubAccountTokenNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteGitHubAccountTokenError::GitHubAccountTokenNameRequired(err.msg),
                     )
                 }
                 "InvalidGitHubAccountTokenNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteGitHubAccountTokenError::InvalidGitHubAccountTokenName(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteGitHubAccountTokenError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceValidationException" => {
-                    return RusotoError::Service(DeleteGitHubAccountTokenError::ResourceValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGitHubAccountTokenError::ResourceValidation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4124,12 +4284,14 @@ impl DeregisterOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterOnPremisesInstanceError::InstanceNameRequired(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
                     )
                 }
@@ -4170,19 +4332,22 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(GetApplicationError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(GetApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(GetApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4224,34 +4389,40 @@ impl GetApplicationRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetApplicationRevisionError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetApplicationRevisionError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetApplicationRevisionError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::InvalidRevision(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationRevisionError::InvalidRevision(err.msg),
+                    )
                 }
                 "RevisionDoesNotExistException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::RevisionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationRevisionError::RevisionDoesNotExist(err.msg),
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::RevisionRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationRevisionError::RevisionRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4296,15 +4467,22 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentError::DeploymentDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentError::DeploymentIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentError::InvalidDeploymentId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4342,22 +4520,26 @@ impl GetDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentConfigError::DeploymentConfigDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(GetDeploymentConfigError::InvalidComputePlatform(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentConfigError::InvalidComputePlatform(err.msg),
+                    )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
@@ -4410,37 +4592,44 @@ impl GetDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentGroupError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentGroupError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentGroupError::DeploymentGroupDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentGroupError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
@@ -4498,39 +4687,46 @@ impl GetDeploymentInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentInstanceError::DeploymentDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentInstanceError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "InstanceDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InstanceDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentInstanceError::InstanceDoesNotExist(err.msg),
+                    )
                 }
                 "InstanceIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InstanceIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentInstanceError::InstanceIdRequired(err.msg),
+                    )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentInstanceError::InvalidComputePlatform(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentInstanceError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InvalidInstanceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentInstanceError::InvalidInstanceName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4580,44 +4776,52 @@ impl GetDeploymentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentTargetError::DeploymentDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentTargetError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentNotStarted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentTargetError::DeploymentNotStarted(err.msg),
+                    )
                 }
                 "DeploymentTargetDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentTargetError::DeploymentTargetDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentTargetIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentTargetError::DeploymentTargetIdRequired(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentTargetError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "InvalidDeploymentTargetIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeploymentTargetError::InvalidDeploymentTargetId(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::InvalidInstanceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentTargetError::InvalidInstanceName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4664,19 +4868,22 @@ impl GetOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InstanceNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOnPremisesInstanceError::InstanceNameRequired(err.msg),
+                    )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InstanceNotRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOnPremisesInstanceError::InstanceNotRegistered(err.msg),
+                    )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InvalidInstanceName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOnPremisesInstanceError::InvalidInstanceName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4726,54 +4933,64 @@ impl ListApplicationRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "BucketNameFilterRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::BucketNameFilterRequired(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidBucketNameFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::InvalidBucketNameFilter(err.msg),
                     )
                 }
                 "InvalidDeployedStateFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::InvalidDeployedStateFilter(err.msg),
                     )
                 }
                 "InvalidKeyPrefixFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationRevisionsError::InvalidKeyPrefixFilter(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationRevisionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidSortByException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidSortBy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationRevisionsError::InvalidSortBy(err.msg),
+                    )
                 }
                 "InvalidSortOrderException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidSortOrder(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationRevisionsError::InvalidSortOrder(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4826,7 +5043,10 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListApplicationsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4856,9 +5076,10 @@ impl ListDeploymentConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentConfigsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentConfigsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4894,24 +5115,28 @@ impl ListDeploymentGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentGroupsError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentGroupsError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(ListDeploymentGroupsError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentGroupsError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentGroupsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentGroupsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4962,52 +5187,62 @@ impl ListDeploymentInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::DeploymentDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::DeploymentIdRequired(err.msg),
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::DeploymentNotStarted(err.msg),
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::InvalidComputePlatform(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentInstancesError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "InvalidDeploymentInstanceTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::InvalidDeploymentInstanceType(err.msg),
                     )
                 }
                 "InvalidInstanceStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::InvalidInstanceStatus(err.msg),
                     )
                 }
                 "InvalidInstanceTypeException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidInstanceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentInstancesError::InvalidInstanceType(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentInstancesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidTargetFilterNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentInstancesError::InvalidTargetFilterName(err.msg),
                     )
                 }
@@ -5072,44 +5307,52 @@ impl ListDeploymentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentTargetsError::DeploymentDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::DeploymentIdRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentTargetsError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::DeploymentNotStarted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentTargetsError::DeploymentNotStarted(err.msg),
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidDeploymentId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentTargetsError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "InvalidDeploymentInstanceTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeploymentTargetsError::InvalidDeploymentInstanceType(err.msg),
                     )
                 }
                 "InvalidInstanceStatusException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidInstanceStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentTargetsError::InvalidInstanceStatus(err.msg),
+                    )
                 }
                 "InvalidInstanceTypeException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidInstanceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentTargetsError::InvalidInstanceType(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentTargetsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5168,51 +5411,67 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(ListDeploymentsError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(ListDeploymentsError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(ListDeploymentsError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::DeploymentGroupDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(ListDeploymentsError::DeploymentGroupNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::DeploymentGroupNameRequired(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidDeploymentGroupName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InvalidDeploymentGroupName(err.msg),
+                    )
                 }
                 "InvalidDeploymentStatusException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidDeploymentStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InvalidDeploymentStatus(err.msg),
+                    )
                 }
                 "InvalidExternalIdException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidExternalId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InvalidExternalId(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListDeploymentsError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidTimeRange(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentsError::InvalidTimeRange(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5258,17 +5517,20 @@ impl ListGitHubAccountTokenNamesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListGitHubAccountTokenNamesError::InvalidNextToken(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListGitHubAccountTokenNamesError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListGitHubAccountTokenNamesError::ResourceValidation(err.msg),
                     )
                 }
@@ -5310,19 +5572,22 @@ impl ListOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListOnPremisesInstancesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOnPremisesInstancesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidRegistrationStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOnPremisesInstancesError::InvalidRegistrationStatus(err.msg),
                     )
                 }
                 "InvalidTagFilterException" => {
-                    return RusotoError::Service(ListOnPremisesInstancesError::InvalidTagFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOnPremisesInstancesError::InvalidTagFilter(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5360,15 +5625,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ArnNotSupported(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceArnRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceArnRequired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5413,13 +5685,13 @@ impl PutLifecycleEventHookExecutionStatusError {
     ) -> RusotoError<PutLifecycleEventHookExecutionStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "DeploymentDoesNotExistException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist(err.msg)),
-"DeploymentIdRequiredException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired(err.msg)),
-"InvalidDeploymentIdException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId(err.msg)),
-"InvalidLifecycleEventHookExecutionIdException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId(err.msg)),
-"InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(err.msg)),
-"LifecycleEventAlreadyCompletedException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(err.msg)),
-"UnsupportedActionForDeploymentTypeException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(err.msg)),
+                                "DeploymentDoesNotExistException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist(err.msg)),
+"DeploymentIdRequiredException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired(err.msg)),
+"InvalidDeploymentIdException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId(err.msg)),
+"InvalidLifecycleEventHookExecutionIdException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId(err.msg)),
+"InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(err.msg)),
+"LifecycleEventAlreadyCompletedException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(err.msg)),
+"UnsupportedActionForDeploymentTypeException" => return RusotoError::service(&res, PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -5480,32 +5752,38 @@ impl RegisterApplicationRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterApplicationRevisionError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterApplicationRevisionError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "DescriptionTooLongException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterApplicationRevisionError::DescriptionTooLong(err.msg),
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterApplicationRevisionError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(RegisterApplicationRevisionError::InvalidRevision(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterApplicationRevisionError::InvalidRevision(err.msg),
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterApplicationRevisionError::RevisionRequired(err.msg),
                     )
                 }
@@ -5570,52 +5848,62 @@ impl RegisterOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IamArnRequiredException" => {
-                    return RusotoError::Service(RegisterOnPremisesInstanceError::IamArnRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterOnPremisesInstanceError::IamArnRequired(err.msg),
+                    )
                 }
                 "IamSessionArnAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::IamSessionArnAlreadyRegistered(err.msg),
                     )
                 }
                 "IamUserArnAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::IamUserArnAlreadyRegistered(err.msg),
                     )
                 }
                 "IamUserArnRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::IamUserArnRequired(err.msg),
                     )
                 }
                 "InstanceNameAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::InstanceNameAlreadyRegistered(err.msg),
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::InstanceNameRequired(err.msg),
                     )
                 }
                 "InvalidIamSessionArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::InvalidIamSessionArn(err.msg),
                     )
                 }
                 "InvalidIamUserArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::InvalidIamUserArn(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
                     )
                 }
                 "MultipleIamArnsProvidedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterOnPremisesInstanceError::MultipleIamArnsProvided(err.msg),
                     )
                 }
@@ -5686,37 +5974,44 @@ impl RemoveTagsFromOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::InstanceLimitExceeded(err.msg),
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::InstanceNameRequired(err.msg),
                     )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::InstanceNotRegistered(err.msg),
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::InvalidInstanceName(err.msg),
                     )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::InvalidTag(err.msg),
                     )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::TagLimitExceeded(err.msg),
                     )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromOnPremisesInstancesError::TagRequired(err.msg),
                     )
                 }
@@ -5778,34 +6073,40 @@ impl SkipWaitTimeForInstanceTerminationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SkipWaitTimeForInstanceTerminationError::DeploymentAlreadyCompleted(
                             err.msg,
                         ),
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SkipWaitTimeForInstanceTerminationError::DeploymentDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SkipWaitTimeForInstanceTerminationError::DeploymentIdRequired(err.msg),
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SkipWaitTimeForInstanceTerminationError::DeploymentNotStarted(err.msg),
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SkipWaitTimeForInstanceTerminationError::InvalidDeploymentId(err.msg),
                     )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SkipWaitTimeForInstanceTerminationError::UnsupportedActionForDeploymentType(
                             err.msg,
                         ),
@@ -5866,28 +6167,38 @@ impl StopDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentAlreadyCompleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDeploymentError::DeploymentAlreadyCompleted(err.msg),
+                    )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDeploymentError::DeploymentDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDeploymentError::DeploymentGroupDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentIdRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDeploymentError::DeploymentIdRequired(err.msg),
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(StopDeploymentError::InvalidDeploymentId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDeploymentError::InvalidDeploymentId(err.msg),
+                    )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDeploymentError::UnsupportedActionForDeploymentType(err.msg),
                     )
                 }
@@ -5940,32 +6251,40 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::ApplicationDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(TagResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ArnNotSupported(err.msg))
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::DeploymentConfigDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::DeploymentConfigDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::DeploymentGroupDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArn(err.msg))
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidTagsToAdd(err.msg))
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ResourceArnRequired(err.msg),
+                    )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(TagResourceError::TagRequired(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TagRequired(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6016,34 +6335,43 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(UntagResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ArnNotSupported(err.msg))
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::DeploymentConfigDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::DeploymentConfigDoesNotExist(err.msg),
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::DeploymentGroupDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::DeploymentGroupDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArn(err.msg))
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidTagsToAdd(err.msg),
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceArnRequired(err.msg),
+                    )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::TagRequired(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TagRequired(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6086,24 +6414,28 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ApplicationAlreadyExists(err.msg),
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ApplicationDoesNotExist(err.msg),
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationNameRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ApplicationNameRequired(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidApplicationName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InvalidApplicationName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6196,149 +6528,190 @@ impl UpdateDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlarmsLimitExceededException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::AlarmsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::AlarmsLimitExceeded(err.msg),
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::ApplicationDoesNotExist(err.msg),
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::ApplicationNameRequired(err.msg),
                     )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::DeploymentGroupAlreadyExists(err.msg),
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::DeploymentGroupDoesNotExist(err.msg),
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
                     )
                 }
                 "ECSServiceMappingLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::ECSServiceMappingLimitExceeded(err.msg),
                     )
                 }
                 "InvalidAlarmConfigException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidAlarmConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidAlarmConfig(err.msg),
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidApplicationName(err.msg),
                     )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidAutoRollbackConfig(err.msg),
                     )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidAutoScalingGroup(err.msg),
                     )
                 }
                 "InvalidBlueGreenDeploymentConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidDeploymentConfigName(err.msg),
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
                     )
                 }
                 "InvalidDeploymentStyleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidDeploymentStyle(err.msg),
                     )
                 }
                 "InvalidEC2TagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidEC2TagCombination(err.msg),
                     )
                 }
                 "InvalidEC2TagException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidEC2Tag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidEC2Tag(err.msg),
+                    )
                 }
                 "InvalidECSServiceException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidECSService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidECSService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidLoadBalancerInfo(err.msg),
                     )
                 }
                 "InvalidOnPremisesTagCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidOnPremisesTagCombination(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidRole(err.msg),
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidTag(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidTag(err.msg),
+                    )
                 }
                 "InvalidTargetGroupPairException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidTargetGroupPair(err.msg),
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::InvalidTrafficRoutingConfiguration(err.msg),
                     )
                 }
                 "InvalidTriggerConfigException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidTriggerConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::InvalidTriggerConfig(err.msg),
+                    )
                 }
                 "LifecycleHookLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::LifecycleHookLimitExceeded(err.msg),
                     )
                 }
                 "TagSetListLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::TagSetListLimitExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeploymentGroupError::Throttling(err.msg),
+                    )
                 }
                 "TriggerTargetsLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
                     )
                 }
@@ -6732,6 +7105,7 @@ impl CodeDeployClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6747,6 +7121,7 @@ impl CodeDeployClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployClient {
         CodeDeployClient { client, region }
     }
@@ -6760,6 +7135,9 @@ impl CodeDeploy for CodeDeployClient {
         input: AddTagsToOnPremisesInstancesInput,
     ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToOnPremisesInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.AddTagsToOnPremisesInstances",
@@ -6781,6 +7159,9 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetApplicationRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetApplicationRevisions",
@@ -6803,6 +7184,9 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetApplicationsInput,
     ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6821,6 +7205,9 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentGroupsInput,
     ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetDeploymentGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetDeploymentGroups",
@@ -6844,6 +7231,9 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetDeploymentInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetDeploymentInstances",
@@ -6866,6 +7256,9 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentTargetsInput,
     ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetDeploymentTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetDeploymentTargets",
@@ -6888,6 +7281,9 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentsInput,
     ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6907,6 +7303,9 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetOnPremisesInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetOnPremisesInstances",
@@ -6929,6 +7328,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ContinueDeploymentInput,
     ) -> Result<(), RusotoError<ContinueDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ContinueDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ContinueDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6946,6 +7348,9 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateApplicationInput,
     ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6964,6 +7369,9 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentInput,
     ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6982,6 +7390,9 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentConfigInput,
     ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDeploymentConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7001,6 +7412,9 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentGroupInput,
     ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDeploymentGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7019,6 +7433,9 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteApplicationInput,
     ) -> Result<(), RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7036,6 +7453,9 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteDeploymentConfigInput,
     ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDeploymentConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7053,6 +7473,9 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteDeploymentGroupInput,
     ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDeploymentGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7071,6 +7494,9 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteGitHubAccountTokenInput,
     ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGitHubAccountToken");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.DeleteGitHubAccountToken",
@@ -7094,6 +7520,9 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<DeleteResourcesByExternalIdOutput, RusotoError<DeleteResourcesByExternalIdError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourcesByExternalId");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.DeleteResourcesByExternalId",
@@ -7116,6 +7545,9 @@ impl CodeDeploy for CodeDeployClient {
         input: DeregisterOnPremisesInstanceInput,
     ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterOnPremisesInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.DeregisterOnPremisesInstance",
@@ -7136,6 +7568,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetApplicationInput,
     ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7154,6 +7589,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetApplicationRevisionInput,
     ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetApplicationRevision");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplicationRevision");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7173,6 +7611,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentInput,
     ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeployment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7191,6 +7632,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentConfigInput,
     ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeploymentConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7209,6 +7653,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentGroupInput,
     ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeploymentGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7227,6 +7674,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentInstanceInput,
     ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeploymentInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7245,6 +7695,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentTargetInput,
     ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeploymentTarget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7263,6 +7716,9 @@ impl CodeDeploy for CodeDeployClient {
         input: GetOnPremisesInstanceInput,
     ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOnPremisesInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetOnPremisesInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7281,6 +7737,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListApplicationRevisionsInput,
     ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplicationRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListApplicationRevisions",
@@ -7303,6 +7762,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListApplicationsInput,
     ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7321,6 +7783,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentConfigsInput,
     ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeploymentConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7339,6 +7804,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentGroupsInput,
     ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeploymentGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7357,6 +7825,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentInstancesInput,
     ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeploymentInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListDeploymentInstances",
@@ -7379,6 +7850,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentTargetsInput,
     ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeploymentTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7397,6 +7871,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentsInput,
     ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7416,6 +7893,9 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGitHubAccountTokenNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListGitHubAccountTokenNames",
@@ -7438,6 +7918,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListOnPremisesInstancesInput,
     ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOnPremisesInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListOnPremisesInstances",
@@ -7460,6 +7943,9 @@ impl CodeDeploy for CodeDeployClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7481,6 +7967,9 @@ impl CodeDeploy for CodeDeployClient {
         RusotoError<PutLifecycleEventHookExecutionStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutLifecycleEventHookExecutionStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.PutLifecycleEventHookExecutionStatus",
@@ -7506,6 +7995,9 @@ impl CodeDeploy for CodeDeployClient {
         input: RegisterApplicationRevisionInput,
     ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterApplicationRevision");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.RegisterApplicationRevision",
@@ -7526,6 +8018,9 @@ impl CodeDeploy for CodeDeployClient {
         input: RegisterOnPremisesInstanceInput,
     ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterOnPremisesInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.RegisterOnPremisesInstance",
@@ -7546,6 +8041,9 @@ impl CodeDeploy for CodeDeployClient {
         input: RemoveTagsFromOnPremisesInstancesInput,
     ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromOnPremisesInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.RemoveTagsFromOnPremisesInstances",
@@ -7569,6 +8067,9 @@ impl CodeDeploy for CodeDeployClient {
         input: SkipWaitTimeForInstanceTerminationInput,
     ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SkipWaitTimeForInstanceTermination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.SkipWaitTimeForInstanceTermination",
@@ -7592,6 +8093,9 @@ impl CodeDeploy for CodeDeployClient {
         input: StopDeploymentInput,
     ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7610,6 +8114,9 @@ impl CodeDeploy for CodeDeployClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7628,6 +8135,9 @@ impl CodeDeploy for CodeDeployClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7646,6 +8156,9 @@ impl CodeDeploy for CodeDeployClient {
         input: UpdateApplicationInput,
     ) -> Result<(), RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7663,6 +8176,9 @@ impl CodeDeploy for CodeDeployClient {
         input: UpdateDeploymentGroupInput,
     ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDeploymentGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7675,3 +8191,567 @@ impl CodeDeploy for CodeDeployClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeploymentGroupOutput, _>()
     }
 }
+
+/// A blocking client for the CodeDeploy API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeDeployClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeDeployBlockingClient {
+    client: CodeDeployClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeDeployBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeDeployBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeDeployBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeDeployBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeDeployBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient {
+            client: CodeDeployClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds tags to on-premises instances.</p>
+    ///
+    /// See `CodeDeploy::add_tags_to_on_premises_instances`.
+    pub fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_on_premises_instances(input))
+    }
+
+    /// <p>Gets information about one or more application revisions. The maximum number of application revisions that can be returned is 25.</p>
+    ///
+    /// See `CodeDeploy::batch_get_application_revisions`.
+    pub fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_get_application_revisions(input))
+    }
+
+    /// <p>Gets information about one or more applications. The maximum number of applications that can be returned is 100.</p>
+    ///
+    /// See `CodeDeploy::batch_get_applications`.
+    pub fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_applications(input))
+    }
+
+    /// <p>Gets information about one or more deployment groups.</p>
+    ///
+    /// See `CodeDeploy::batch_get_deployment_groups`.
+    pub fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_deployment_groups(input))
+    }
+
+    /// <p><note> <p> This method works, but is deprecated. Use <code>BatchGetDeploymentTargets</code> instead. </p> </note> <p> Returns an array of one or more instances associated with a deployment. This method works with EC2/On-premises and AWS Lambda compute platforms. The newer <code>BatchGetDeploymentTargets</code> works with all compute platforms. The maximum number of instances that can be returned is 25.</p></p>
+    ///
+    /// See `CodeDeploy::batch_get_deployment_instances`.
+    pub fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_get_deployment_instances(input))
+    }
+
+    /// <p><p> Returns an array of one or more targets associated with a deployment. This method works with all compute types and should be used instead of the deprecated <code>BatchGetDeploymentInstances</code>. The maximum number of targets that can be returned is 25.</p> <p> The type of targets returned depends on the deployment&#39;s compute platform or deployment method: </p> <ul> <li> <p> <b>EC2/On-premises</b>: Information about EC2 instance targets. </p> </li> <li> <p> <b>AWS Lambda</b>: Information about Lambda functions targets. </p> </li> <li> <p> <b>Amazon ECS</b>: Information about Amazon ECS service targets. </p> </li> <li> <p> <b>CloudFormation</b>: Information about targets of blue/green deployments initiated by a CloudFormation stack update.</p> </li> </ul></p>
+    ///
+    /// See `CodeDeploy::batch_get_deployment_targets`.
+    pub fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_deployment_targets(input))
+    }
+
+    /// <p>Gets information about one or more deployments. The maximum number of deployments that can be returned is 25.</p>
+    ///
+    /// See `CodeDeploy::batch_get_deployments`.
+    pub fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_deployments(input))
+    }
+
+    /// <p>Gets information about one or more on-premises instances. The maximum number of on-premises instances that can be returned is 25.</p>
+    ///
+    /// See `CodeDeploy::batch_get_on_premises_instances`.
+    pub fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_get_on_premises_instances(input))
+    }
+
+    /// <p>For a blue/green deployment, starts the process of rerouting traffic from instances in the original environment to instances in the replacement environment without waiting for a specified wait time to elapse. (Traffic rerouting, which is achieved by registering instances in the replacement environment with the load balancer, can start as soon as all instances have a status of Ready.) </p>
+    ///
+    /// See `CodeDeploy::continue_deployment`.
+    pub fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> Result<(), RusotoError<ContinueDeploymentError>> {
+        self.runtime
+            .block_on(self.client.continue_deployment(input))
+    }
+
+    /// <p>Creates an application.</p>
+    ///
+    /// See `CodeDeploy::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p>Deploys an application revision through the specified deployment group.</p>
+    ///
+    /// See `CodeDeploy::create_deployment`.
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// <p> Creates a deployment configuration. </p>
+    ///
+    /// See `CodeDeploy::create_deployment_config`.
+    pub fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_config(input))
+    }
+
+    /// <p>Creates a deployment group to which application revisions are deployed.</p>
+    ///
+    /// See `CodeDeploy::create_deployment_group`.
+    pub fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_group(input))
+    }
+
+    /// <p>Deletes an application.</p>
+    ///
+    /// See `CodeDeploy::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p><p>Deletes a deployment configuration.</p> <note> <p>A deployment configuration cannot be deleted if it is currently in use. Predefined configurations cannot be deleted.</p> </note></p>
+    ///
+    /// See `CodeDeploy::delete_deployment_config`.
+    pub fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_deployment_config(input))
+    }
+
+    /// <p>Deletes a deployment group.</p>
+    ///
+    /// See `CodeDeploy::delete_deployment_group`.
+    pub fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_deployment_group(input))
+    }
+
+    /// <p>Deletes a GitHub account connection.</p>
+    ///
+    /// See `CodeDeploy::delete_git_hub_account_token`.
+    pub fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
+        self.runtime
+            .block_on(self.client.delete_git_hub_account_token(input))
+    }
+
+    /// <p>Deletes resources linked to an external ID.</p>
+    ///
+    /// See `CodeDeploy::delete_resources_by_external_id`.
+    pub fn delete_resources_by_external_id(
+        &self,
+        input: DeleteResourcesByExternalIdInput,
+    ) -> Result<DeleteResourcesByExternalIdOutput, RusotoError<DeleteResourcesByExternalIdError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_resources_by_external_id(input))
+    }
+
+    /// <p>Deregisters an on-premises instance.</p>
+    ///
+    /// See `CodeDeploy::deregister_on_premises_instance`.
+    pub fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_on_premises_instance(input))
+    }
+
+    /// <p>Gets information about an application.</p>
+    ///
+    /// See `CodeDeploy::get_application`.
+    pub fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
+        self.runtime.block_on(self.client.get_application(input))
+    }
+
+    /// <p>Gets information about an application revision.</p>
+    ///
+    /// See `CodeDeploy::get_application_revision`.
+    pub fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
+        self.runtime
+            .block_on(self.client.get_application_revision(input))
+    }
+
+    /// <p><p>Gets information about a deployment.</p> <note> <p> The <code>content</code> property of the <code>appSpecContent</code> object in the returned revision is always null. Use <code>GetApplicationRevision</code> and the <code>sha256</code> property of the returned <code>appSpecContent</code> object to get the content of the deployment’s AppSpec file. </p> </note></p>
+    ///
+    /// See `CodeDeploy::get_deployment`.
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// <p>Gets information about a deployment configuration.</p>
+    ///
+    /// See `CodeDeploy::get_deployment_config`.
+    pub fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_config(input))
+    }
+
+    /// <p>Gets information about a deployment group.</p>
+    ///
+    /// See `CodeDeploy::get_deployment_group`.
+    pub fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_group(input))
+    }
+
+    /// <p>Gets information about an instance as part of a deployment.</p>
+    ///
+    /// See `CodeDeploy::get_deployment_instance`.
+    pub fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_instance(input))
+    }
+
+    /// <p> Returns information about a deployment target. </p>
+    ///
+    /// See `CodeDeploy::get_deployment_target`.
+    pub fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_target(input))
+    }
+
+    /// <p> Gets information about an on-premises instance. </p>
+    ///
+    /// See `CodeDeploy::get_on_premises_instance`.
+    pub fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_on_premises_instance(input))
+    }
+
+    /// <p>Lists information about revisions for an application.</p>
+    ///
+    /// See `CodeDeploy::list_application_revisions`.
+    pub fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
+        self.runtime
+            .block_on(self.client.list_application_revisions(input))
+    }
+
+    /// <p>Lists the applications registered with the IAM user or AWS account.</p>
+    ///
+    /// See `CodeDeploy::list_applications`.
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// <p>Lists the deployment configurations with the IAM user or AWS account.</p>
+    ///
+    /// See `CodeDeploy::list_deployment_configs`.
+    pub fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_configs(input))
+    }
+
+    /// <p>Lists the deployment groups for an application registered with the IAM user or AWS account.</p>
+    ///
+    /// See `CodeDeploy::list_deployment_groups`.
+    pub fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_groups(input))
+    }
+
+    /// <p><note> <p> The newer <code>BatchGetDeploymentTargets</code> should be used instead because it works with all compute types. <code>ListDeploymentInstances</code> throws an exception if it is used with a compute platform other than EC2/On-premises or AWS Lambda. </p> </note> <p> Lists the instance for a deployment associated with the IAM user or AWS account. </p></p>
+    ///
+    /// See `CodeDeploy::list_deployment_instances`.
+    pub fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_instances(input))
+    }
+
+    /// <p> Returns an array of target IDs that are associated a deployment. </p>
+    ///
+    /// See `CodeDeploy::list_deployment_targets`.
+    pub fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_targets(input))
+    }
+
+    /// <p>Lists the deployments in a deployment group for an application registered with the IAM user or AWS account.</p>
+    ///
+    /// See `CodeDeploy::list_deployments`.
+    pub fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
+        self.runtime.block_on(self.client.list_deployments(input))
+    }
+
+    /// <p>Lists the names of stored connections to GitHub accounts.</p>
+    ///
+    /// See `CodeDeploy::list_git_hub_account_token_names`.
+    pub fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_git_hub_account_token_names(input))
+    }
+
+    /// <p>Gets a list of names for one or more on-premises instances.</p> <p>Unless otherwise specified, both registered and deregistered on-premises instance names are listed. To list only registered or deregistered on-premises instance names, use the registration status parameter.</p>
+    ///
+    /// See `CodeDeploy::list_on_premises_instances`.
+    pub fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_on_premises_instances(input))
+    }
+
+    /// <p> Returns a list of tags for the resource identified by a specified Amazon Resource Name (ARN). Tags are used to organize and categorize your CodeDeploy resources. </p>
+    ///
+    /// See `CodeDeploy::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p> Sets the result of a Lambda validation function. The function validates lifecycle hooks during a deployment that uses the AWS Lambda or Amazon ECS compute platform. For AWS Lambda deployments, the available lifecycle hooks are <code>BeforeAllowTraffic</code> and <code>AfterAllowTraffic</code>. For Amazon ECS deployments, the available lifecycle hooks are <code>BeforeInstall</code>, <code>AfterInstall</code>, <code>AfterAllowTestTraffic</code>, <code>BeforeAllowTraffic</code>, and <code>AfterAllowTraffic</code>. Lambda validation functions return <code>Succeeded</code> or <code>Failed</code>. For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/reference-appspec-file-structure-hooks.html#appspec-hooks-lambda">AppSpec 'hooks' Section for an AWS Lambda Deployment </a> and <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/reference-appspec-file-structure-hooks.html#appspec-hooks-ecs">AppSpec 'hooks' Section for an Amazon ECS Deployment</a>.</p>
+    ///
+    /// See `CodeDeploy::put_lifecycle_event_hook_execution_status`.
+    pub fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> Result<
+        PutLifecycleEventHookExecutionStatusOutput,
+        RusotoError<PutLifecycleEventHookExecutionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_lifecycle_event_hook_execution_status(input))
+    }
+
+    /// <p>Registers with AWS CodeDeploy a revision for the specified application.</p>
+    ///
+    /// See `CodeDeploy::register_application_revision`.
+    pub fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
+        self.runtime
+            .block_on(self.client.register_application_revision(input))
+    }
+
+    /// <p><p>Registers an on-premises instance.</p> <note> <p>Only one IAM ARN (an IAM session ARN or IAM user ARN) is supported in the request. You cannot use both.</p> </note></p>
+    ///
+    /// See `CodeDeploy::register_on_premises_instance`.
+    pub fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
+        self.runtime
+            .block_on(self.client.register_on_premises_instance(input))
+    }
+
+    /// <p>Removes one or more tags from one or more on-premises instances.</p>
+    ///
+    /// See `CodeDeploy::remove_tags_from_on_premises_instances`.
+    pub fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_on_premises_instances(input))
+    }
+
+    /// <p>In a blue/green deployment, overrides any specified wait time and starts terminating instances immediately after the traffic routing is complete.</p>
+    ///
+    /// See `CodeDeploy::skip_wait_time_for_instance_termination`.
+    pub fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
+        self.runtime
+            .block_on(self.client.skip_wait_time_for_instance_termination(input))
+    }
+
+    /// <p>Attempts to stop an ongoing deployment.</p>
+    ///
+    /// See `CodeDeploy::stop_deployment`.
+    pub fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
+        self.runtime.block_on(self.client.stop_deployment(input))
+    }
+
+    /// <p> Associates the list of tags in the input <code>Tags</code> parameter with the resource identified by the <code>ResourceArn</code> input parameter. </p>
+    ///
+    /// See `CodeDeploy::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p> Disassociates a resource from a list of tags. The resource is identified by the <code>ResourceArn</code> input parameter. The tags are identified by the list of keys in the <code>TagKeys</code> input parameter. </p>
+    ///
+    /// See `CodeDeploy::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Changes the name of an application.</p>
+    ///
+    /// See `CodeDeploy::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> Result<(), RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// <p>Changes information about a deployment group.</p>
+    ///
+    /// See `CodeDeploy::update_deployment_group`.
+    pub fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.update_deployment_group(input))
+    }
+}
@@ -666,16 +666,25 @@ impl AssociateRepositoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateRepositoryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateRepositoryError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateRepositoryError::Conflict(err.msg))
+                    return RusotoError::service(&res, AssociateRepositoryError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateRepositoryError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateRepositoryError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateRepositoryError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateRepositoryError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -714,16 +723,25 @@ impl DescribeCodeReviewError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeReviewError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeReviewError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeReviewError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeCodeReviewError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -764,24 +782,28 @@ impl DescribeRecommendationFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeRecommendationFeedbackError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecommendationFeedbackError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationFeedbackError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationFeedbackError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRecommendationFeedbackError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecommendationFeedbackError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -826,24 +848,28 @@ impl DescribeRepositoryAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRepositoryAssociationError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRepositoryAssociationError::InternalServer(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRepositoryAssociationError::NotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRepositoryAssociationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -884,21 +910,34 @@ impl DisassociateRepositoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateRepositoryError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateRepositoryError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateRepositoryError::InternalServer(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateRepositoryError::NotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateRepositoryError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -936,13 +975,16 @@ impl ListCodeReviewsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCodeReviewsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListCodeReviewsError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListCodeReviewsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCodeReviewsError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCodeReviewsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListCodeReviewsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -982,24 +1024,28 @@ impl ListRecommendationFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationFeedbackError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationFeedbackError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationFeedbackError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationFeedbackError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1038,18 +1084,28 @@ impl ListRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRecommendationsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRecommendationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRecommendationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRecommendationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRecommendationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1086,14 +1142,16 @@ impl ListRepositoryAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRepositoryAssociationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRepositoryAssociationsError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRepositoryAssociationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRepositoryAssociationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1130,24 +1188,28 @@ impl PutRecommendationFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRecommendationFeedbackError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRecommendationFeedbackError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRecommendationFeedbackError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRecommendationFeedbackError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1255,6 +1317,7 @@ impl CodeGuruReviewerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1270,6 +1333,7 @@ impl CodeGuruReviewerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeGuruReviewerClient {
         CodeGuruReviewerClient { client, region }
     }
@@ -1287,6 +1351,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("AssociateRepository");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1322,6 +1389,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("DescribeCodeReview");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1357,6 +1427,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("DescribeRecommendationFeedback");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1399,6 +1472,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("DescribeRepositoryAssociation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1431,6 +1507,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("DisassociateRepository");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1460,6 +1539,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("ListCodeReviews");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1518,6 +1600,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("ListRecommendationFeedback");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1569,6 +1654,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("ListRecommendations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1608,6 +1696,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("ListRepositoryAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1667,6 +1758,9 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation_name("PutRecommendationFeedback");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1689,3 +1783,174 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
         }
     }
 }
+
+/// A blocking client for the CodeGuruReviewer API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeGuruReviewerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeGuruReviewerBlockingClient {
+    client: CodeGuruReviewerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeGuruReviewerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeGuruReviewerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeGuruReviewerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeGuruReviewerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeGuruReviewerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient {
+            client: CodeGuruReviewerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p> Use to associate an AWS CodeCommit repository or a repostory managed by AWS CodeStar Connections with Amazon CodeGuru Reviewer. When you associate a repository, CodeGuru Reviewer reviews source code changes in the repository&#39;s pull requests and provides automatic recommendations. You can view recommendations using the CodeGuru Reviewer console. For more information, see <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-ug/recommendations.html">Recommendations in Amazon CodeGuru Reviewer</a> in the <i>Amazon CodeGuru Reviewer User Guide.</i> </p> <p>If you associate a CodeCommit repository, it must be in the same AWS Region and AWS account where its CodeGuru Reviewer code reviews are configured.</p> <p> Bitbucket and GitHub Enterprise Server repositories are managed by AWS CodeStar Connections to connect to CodeGuru Reviewer. For more information, see <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-ug/reviewer-ug/step-one.html#select-repository-source-provider">Connect to a repository source provider</a> in the <i>Amazon CodeGuru Reviewer User Guide.</i> </p> <note> <p> You cannot use the CodeGuru Reviewer SDK or the AWS CLI to associate a GitHub repository with Amazon CodeGuru Reviewer. To associate a GitHub repository, use the console. For more information, see <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-ug/getting-started-with-guru.html">Getting started with CodeGuru Reviewer</a> in the <i>CodeGuru Reviewer User Guide.</i> </p> </note></p>
+    ///
+    /// See `CodeGuruReviewer::associate_repository`.
+    pub fn associate_repository(
+        &self,
+        input: AssociateRepositoryRequest,
+    ) -> Result<AssociateRepositoryResponse, RusotoError<AssociateRepositoryError>> {
+        self.runtime
+            .block_on(self.client.associate_repository(input))
+    }
+
+    /// <p> Returns the metadata associated with the code review along with its status.</p>
+    ///
+    /// See `CodeGuruReviewer::describe_code_review`.
+    pub fn describe_code_review(
+        &self,
+        input: DescribeCodeReviewRequest,
+    ) -> Result<DescribeCodeReviewResponse, RusotoError<DescribeCodeReviewError>> {
+        self.runtime
+            .block_on(self.client.describe_code_review(input))
+    }
+
+    /// <p> Describes the customer feedback for a CodeGuru Reviewer recommendation. </p>
+    ///
+    /// See `CodeGuruReviewer::describe_recommendation_feedback`.
+    pub fn describe_recommendation_feedback(
+        &self,
+        input: DescribeRecommendationFeedbackRequest,
+    ) -> Result<
+        DescribeRecommendationFeedbackResponse,
+        RusotoError<DescribeRecommendationFeedbackError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_recommendation_feedback(input))
+    }
+
+    /// <p> Returns a <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RepositoryAssociation.html"> <code>RepositoryAssociation</code> </a> object that contains information about the requested repository association. </p>
+    ///
+    /// See `CodeGuruReviewer::describe_repository_association`.
+    pub fn describe_repository_association(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> Result<
+        DescribeRepositoryAssociationResponse,
+        RusotoError<DescribeRepositoryAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_repository_association(input))
+    }
+
+    /// <p>Removes the association between Amazon CodeGuru Reviewer and a repository.</p>
+    ///
+    /// See `CodeGuruReviewer::disassociate_repository`.
+    pub fn disassociate_repository(
+        &self,
+        input: DisassociateRepositoryRequest,
+    ) -> Result<DisassociateRepositoryResponse, RusotoError<DisassociateRepositoryError>> {
+        self.runtime
+            .block_on(self.client.disassociate_repository(input))
+    }
+
+    /// <p> Lists all the code reviews that the customer has created in the past 90 days. </p>
+    ///
+    /// See `CodeGuruReviewer::list_code_reviews`.
+    pub fn list_code_reviews(
+        &self,
+        input: ListCodeReviewsRequest,
+    ) -> Result<ListCodeReviewsResponse, RusotoError<ListCodeReviewsError>> {
+        self.runtime.block_on(self.client.list_code_reviews(input))
+    }
+
+    /// <p> Returns a list of <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RecommendationFeedbackSummary.html"> <code>RecommendationFeedbackSummary</code> </a> objects that contain customer recommendation feedback for all CodeGuru Reviewer users. </p>
+    ///
+    /// See `CodeGuruReviewer::list_recommendation_feedback`.
+    pub fn list_recommendation_feedback(
+        &self,
+        input: ListRecommendationFeedbackRequest,
+    ) -> Result<ListRecommendationFeedbackResponse, RusotoError<ListRecommendationFeedbackError>>
+    {
+        self.runtime
+            .block_on(self.client.list_recommendation_feedback(input))
+    }
+
+    /// <p> Returns the list of all recommendations for a completed code review. </p>
+    ///
+    /// See `CodeGuruReviewer::list_recommendations`.
+    pub fn list_recommendations(
+        &self,
+        input: ListRecommendationsRequest,
+    ) -> Result<ListRecommendationsResponse, RusotoError<ListRecommendationsError>> {
+        self.runtime
+            .block_on(self.client.list_recommendations(input))
+    }
+
+    /// <p> Returns a list of <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RepositoryAssociationSummary.html"> <code>RepositoryAssociationSummary</code> </a> objects that contain summary information about a repository association. You can filter the returned list by <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RepositoryAssociationSummary.html#reviewer-Type-RepositoryAssociationSummary-ProviderType"> <code>ProviderType</code> </a>, <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RepositoryAssociationSummary.html#reviewer-Type-RepositoryAssociationSummary-Name"> <code>Name</code> </a>, <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RepositoryAssociationSummary.html#reviewer-Type-RepositoryAssociationSummary-State"> <code>State</code> </a>, and <a href="https://docs.aws.amazon.com/codeguru/latest/reviewer-api/API_RepositoryAssociationSummary.html#reviewer-Type-RepositoryAssociationSummary-Owner"> <code>Owner</code> </a>. </p>
+    ///
+    /// See `CodeGuruReviewer::list_repository_associations`.
+    pub fn list_repository_associations(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> Result<ListRepositoryAssociationsResponse, RusotoError<ListRepositoryAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_repository_associations(input))
+    }
+
+    /// <p> Stores customer feedback for a CodeGuru Reviewer recommendation. When this API is called again with different reactions the previous feedback is overwritten. </p>
+    ///
+    /// See `CodeGuruReviewer::put_recommendation_feedback`.
+    pub fn put_recommendation_feedback(
+        &self,
+        input: PutRecommendationFeedbackRequest,
+    ) -> Result<PutRecommendationFeedbackResponse, RusotoError<PutRecommendationFeedbackError>>
+    {
+        self.runtime
+            .block_on(self.client.put_recommendation_feedback(input))
+    }
+}
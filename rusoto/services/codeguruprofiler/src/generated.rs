@@ -949,25 +949,34 @@ impl AddNotificationChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddNotificationChannelsError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddNotificationChannelsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddNotificationChannelsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddNotificationChannelsError::ServiceQuotaExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddNotificationChannelsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1005,17 +1014,22 @@ impl BatchGetFrameMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetFrameMetricDataError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetFrameMetricDataError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchGetFrameMetricDataError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetFrameMetricDataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchGetFrameMetricDataError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetFrameMetricDataError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1051,13 +1065,16 @@ impl ConfigureAgentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ConfigureAgentError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ConfigureAgentError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfigureAgentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfigureAgentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ConfigureAgentError::Throttling(err.msg))
+                    return RusotoError::service(&res, ConfigureAgentError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1095,18 +1112,25 @@ impl CreateProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateProfilingGroupError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProfilingGroupError::InternalServer(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProfilingGroupError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProfilingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1143,15 +1167,22 @@ impl DeleteProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProfilingGroupError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProfilingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProfilingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1187,17 +1218,22 @@ impl DescribeProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProfilingGroupError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProfilingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProfilingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1233,14 +1269,16 @@ impl GetFindingsReportAccountSummaryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFindingsReportAccountSummaryError::InternalServer(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFindingsReportAccountSummaryError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFindingsReportAccountSummaryError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1279,19 +1317,22 @@ impl GetNotificationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetNotificationConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNotificationConfigurationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetNotificationConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNotificationConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNotificationConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1329,13 +1370,13 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetPolicyError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPolicyError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1371,13 +1412,13 @@ impl GetProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetProfileError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetProfileError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetProfileError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetProfileError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetProfileError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1413,13 +1454,19 @@ impl GetRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRecommendationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecommendationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRecommendationsError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetRecommendationsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1455,15 +1502,22 @@ impl ListFindingsReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFindingsReportsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFindingsReportsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFindingsReportsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFindingsReportsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFindingsReportsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFindingsReportsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1499,13 +1553,19 @@ impl ListProfileTimesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProfileTimesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProfileTimesError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProfileTimesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProfileTimesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProfileTimesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListProfileTimesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1539,10 +1599,16 @@ impl ListProfilingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProfilingGroupsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProfilingGroupsError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProfilingGroupsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProfilingGroupsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1577,13 +1643,19 @@ impl PostAgentProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PostAgentProfileError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostAgentProfileError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PostAgentProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostAgentProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PostAgentProfileError::Throttling(err.msg))
+                    return RusotoError::service(&res, PostAgentProfileError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1621,16 +1693,19 @@ impl PutPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(PutPermissionError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutPermissionError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutPermissionError::InternalServer(err.msg))
+                    return RusotoError::service(&res, PutPermissionError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutPermissionError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutPermissionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1667,19 +1742,22 @@ impl RemoveNotificationChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RemoveNotificationChannelError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveNotificationChannelError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveNotificationChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemoveNotificationChannelError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveNotificationChannelError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1717,16 +1795,22 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RemovePermissionError::Conflict(err.msg))
+                    return RusotoError::service(&res, RemovePermissionError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RemovePermissionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemovePermissionError::Throttling(err.msg))
+                    return RusotoError::service(&res, RemovePermissionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1763,13 +1847,16 @@ impl SubmitFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(SubmitFeedbackError::InternalServer(err.msg))
+                    return RusotoError::service(&res, SubmitFeedbackError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitFeedbackError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SubmitFeedbackError::Throttling(err.msg))
+                    return RusotoError::service(&res, SubmitFeedbackError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1807,18 +1894,25 @@ impl UpdateProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateProfilingGroupError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProfilingGroupError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProfilingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProfilingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1983,6 +2077,7 @@ impl CodeGuruProfilerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1998,6 +2093,7 @@ impl CodeGuruProfilerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeGuruProfilerClient {
         CodeGuruProfilerClient { client, region }
     }
@@ -2018,6 +2114,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("AddNotificationChannels");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2053,6 +2152,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("BatchGetFrameMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2103,6 +2205,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("ConfigureAgent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2137,6 +2242,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("CreateProfilingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2178,6 +2286,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("DeleteProfilingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2210,6 +2321,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("DescribeProfilingGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2244,6 +2358,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("GetFindingsReportAccountSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2291,6 +2408,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("GetNotificationConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2323,6 +2443,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("GetPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2355,6 +2478,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("GetProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Accept", input.accept.as_ref());
@@ -2408,6 +2534,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("GetRecommendations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2448,6 +2577,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("ListFindingsReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2494,6 +2626,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("ListProfileTimes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2538,6 +2673,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("ListProfilingGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2582,6 +2720,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("PostAgentProfile");
+        request.set_read_only(false);
+        request.set_idempotent(input.profile_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.agent_profile.to_owned());
@@ -2624,6 +2765,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("PutPermission");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2661,6 +2805,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("RemoveNotificationChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2694,6 +2841,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("RemovePermission");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2727,6 +2877,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("SubmitFeedback");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2762,6 +2915,9 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation_name("UpdateProfilingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2786,3 +2942,273 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
     }
 }
+
+/// A blocking client for the Amazon CodeGuru Profiler API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeGuruProfilerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeGuruProfilerBlockingClient {
+    client: CodeGuruProfilerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeGuruProfilerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeGuruProfilerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeGuruProfilerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeGuruProfilerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeGuruProfilerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient {
+            client: CodeGuruProfilerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Add up to 2 anomaly notifications channels for a profiling group.</p>
+    ///
+    /// See `CodeGuruProfiler::add_notification_channels`.
+    pub fn add_notification_channels(
+        &self,
+        input: AddNotificationChannelsRequest,
+    ) -> Result<AddNotificationChannelsResponse, RusotoError<AddNotificationChannelsError>> {
+        self.runtime
+            .block_on(self.client.add_notification_channels(input))
+    }
+
+    /// <p> Returns the time series of values for a requested list of frame metrics from a time period.</p>
+    ///
+    /// See `CodeGuruProfiler::batch_get_frame_metric_data`.
+    pub fn batch_get_frame_metric_data(
+        &self,
+        input: BatchGetFrameMetricDataRequest,
+    ) -> Result<BatchGetFrameMetricDataResponse, RusotoError<BatchGetFrameMetricDataError>> {
+        self.runtime
+            .block_on(self.client.batch_get_frame_metric_data(input))
+    }
+
+    /// <p> Used by profiler agents to report their current state and to receive remote configuration updates. For example, <code>ConfigureAgent</code> can be used to tell and agent whether to profile or not and for how long to return profiling data. </p>
+    ///
+    /// See `CodeGuruProfiler::configure_agent`.
+    pub fn configure_agent(
+        &self,
+        input: ConfigureAgentRequest,
+    ) -> Result<ConfigureAgentResponse, RusotoError<ConfigureAgentError>> {
+        self.runtime.block_on(self.client.configure_agent(input))
+    }
+
+    /// <p>Creates a profiling group.</p>
+    ///
+    /// See `CodeGuruProfiler::create_profiling_group`.
+    pub fn create_profiling_group(
+        &self,
+        input: CreateProfilingGroupRequest,
+    ) -> Result<CreateProfilingGroupResponse, RusotoError<CreateProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_profiling_group(input))
+    }
+
+    /// <p>Deletes a profiling group.</p>
+    ///
+    /// See `CodeGuruProfiler::delete_profiling_group`.
+    pub fn delete_profiling_group(
+        &self,
+        input: DeleteProfilingGroupRequest,
+    ) -> Result<DeleteProfilingGroupResponse, RusotoError<DeleteProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_profiling_group(input))
+    }
+
+    /// <p> Returns a <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_ProfilingGroupDescription.html"> <code>ProfilingGroupDescription</code> </a> object that contains information about the requested profiling group. </p>
+    ///
+    /// See `CodeGuruProfiler::describe_profiling_group`.
+    pub fn describe_profiling_group(
+        &self,
+        input: DescribeProfilingGroupRequest,
+    ) -> Result<DescribeProfilingGroupResponse, RusotoError<DescribeProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_profiling_group(input))
+    }
+
+    /// <p> Returns a list of <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_FindingsReportSummary.html"> <code>FindingsReportSummary</code> </a> objects that contain analysis results for all profiling groups in your AWS account. </p>
+    ///
+    /// See `CodeGuruProfiler::get_findings_report_account_summary`.
+    pub fn get_findings_report_account_summary(
+        &self,
+        input: GetFindingsReportAccountSummaryRequest,
+    ) -> Result<
+        GetFindingsReportAccountSummaryResponse,
+        RusotoError<GetFindingsReportAccountSummaryError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_findings_report_account_summary(input))
+    }
+
+    /// <p>Get the current configuration for anomaly notifications for a profiling group.</p>
+    ///
+    /// See `CodeGuruProfiler::get_notification_configuration`.
+    pub fn get_notification_configuration(
+        &self,
+        input: GetNotificationConfigurationRequest,
+    ) -> Result<GetNotificationConfigurationResponse, RusotoError<GetNotificationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_notification_configuration(input))
+    }
+
+    /// <p> Returns the JSON-formatted resource-based policy on a profiling group. </p>
+    ///
+    /// See `CodeGuruProfiler::get_policy`.
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// <p><p> Gets the aggregated profile of a profiling group for a specified time range. Amazon CodeGuru Profiler collects posted agent profiles for a profiling group into aggregated profiles. </p> <pre><code> &lt;note&gt; &lt;p&gt; Because aggregated profiles expire over time &lt;code&gt;GetProfile&lt;/code&gt; is not idempotent. &lt;/p&gt; &lt;/note&gt; &lt;p&gt; Specify the time range for the requested aggregated profile using 1 or 2 of the following parameters: &lt;code&gt;startTime&lt;/code&gt;, &lt;code&gt;endTime&lt;/code&gt;, &lt;code&gt;period&lt;/code&gt;. The maximum time range allowed is 7 days. If you specify all 3 parameters, an exception is thrown. If you specify only &lt;code&gt;period&lt;/code&gt;, the latest aggregated profile is returned. &lt;/p&gt; &lt;p&gt; Aggregated profiles are available with aggregation periods of 5 minutes, 1 hour, and 1 day, aligned to UTC. The aggregation period of an aggregated profile determines how long it is retained. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codeguru/latest/profiler-api/API<em>AggregatedProfileTime.html&quot;&gt; &lt;code&gt;AggregatedProfileTime&lt;/code&gt; &lt;/a&gt;. The aggregated profile&#39;s aggregation period determines how long it is retained by CodeGuru Profiler. &lt;/p&gt; &lt;ul&gt; &lt;li&gt; &lt;p&gt; If the aggregation period is 5 minutes, the aggregated profile is retained for 15 days. &lt;/p&gt; &lt;/li&gt; &lt;li&gt; &lt;p&gt; If the aggregation period is 1 hour, the aggregated profile is retained for 60 days. &lt;/p&gt; &lt;/li&gt; &lt;li&gt; &lt;p&gt; If the aggregation period is 1 day, the aggregated profile is retained for 3 years. &lt;/p&gt; &lt;/li&gt; &lt;/ul&gt; &lt;p&gt;There are two use cases for calling &lt;code&gt;GetProfile&lt;/code&gt;.&lt;/p&gt; &lt;ol&gt; &lt;li&gt; &lt;p&gt; If you want to return an aggregated profile that already exists, use &lt;a href=&quot;https://docs.aws.amazon.com/codeguru/latest/profiler-api/API</em>ListProfileTimes.html&quot;&gt; &lt;code&gt;ListProfileTimes&lt;/code&gt; &lt;/a&gt; to view the time ranges of existing aggregated profiles. Use them in a &lt;code&gt;GetProfile&lt;/code&gt; request to return a specific, existing aggregated profile. &lt;/p&gt; &lt;/li&gt; &lt;li&gt; &lt;p&gt; If you want to return an aggregated profile for a time range that doesn&#39;t align with an existing aggregated profile, then CodeGuru Profiler makes a best effort to combine existing aggregated profiles from the requested time range and return them as one aggregated profile. &lt;/p&gt; &lt;p&gt; If aggregated profiles do not exist for the full time range requested, then aggregated profiles for a smaller time range are returned. For example, if the requested time range is from 00:00 to 00:20, and the existing aggregated profiles are from 00:15 and 00:25, then the aggregated profiles from 00:15 to 00:20 are returned. &lt;/p&gt; &lt;/li&gt; &lt;/ol&gt; </code></pre></p>
+    ///
+    /// See `CodeGuruProfiler::get_profile`.
+    pub fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+        self.runtime.block_on(self.client.get_profile(input))
+    }
+
+    /// <p> Returns a list of <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_Recommendation.html"> <code>Recommendation</code> </a> objects that contain recommendations for a profiling group for a given time period. A list of <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_Anomaly.html"> <code>Anomaly</code> </a> objects that contains details about anomalies detected in the profiling group for the same time period is also returned. </p>
+    ///
+    /// See `CodeGuruProfiler::get_recommendations`.
+    pub fn get_recommendations(
+        &self,
+        input: GetRecommendationsRequest,
+    ) -> Result<GetRecommendationsResponse, RusotoError<GetRecommendationsError>> {
+        self.runtime
+            .block_on(self.client.get_recommendations(input))
+    }
+
+    /// <p>List the available reports for a given profiling group and time range.</p>
+    ///
+    /// See `CodeGuruProfiler::list_findings_reports`.
+    pub fn list_findings_reports(
+        &self,
+        input: ListFindingsReportsRequest,
+    ) -> Result<ListFindingsReportsResponse, RusotoError<ListFindingsReportsError>> {
+        self.runtime
+            .block_on(self.client.list_findings_reports(input))
+    }
+
+    /// <p>Lists the start times of the available aggregated profiles of a profiling group for an aggregation period within the specified time range.</p>
+    ///
+    /// See `CodeGuruProfiler::list_profile_times`.
+    pub fn list_profile_times(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> Result<ListProfileTimesResponse, RusotoError<ListProfileTimesError>> {
+        self.runtime.block_on(self.client.list_profile_times(input))
+    }
+
+    /// <p> Returns a list of profiling groups. The profiling groups are returned as <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_ProfilingGroupDescription.html"> <code>ProfilingGroupDescription</code> </a> objects. </p>
+    ///
+    /// See `CodeGuruProfiler::list_profiling_groups`.
+    pub fn list_profiling_groups(
+        &self,
+        input: ListProfilingGroupsRequest,
+    ) -> Result<ListProfilingGroupsResponse, RusotoError<ListProfilingGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_profiling_groups(input))
+    }
+
+    /// <p> Submits profiling data to an aggregated profile of a profiling group. To get an aggregated profile that is created with this profiling data, use <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_GetProfile.html"> <code>GetProfile</code> </a>. </p>
+    ///
+    /// See `CodeGuruProfiler::post_agent_profile`.
+    pub fn post_agent_profile(
+        &self,
+        input: PostAgentProfileRequest,
+    ) -> Result<PostAgentProfileResponse, RusotoError<PostAgentProfileError>> {
+        self.runtime.block_on(self.client.post_agent_profile(input))
+    }
+
+    /// <p><p> Adds permissions to a profiling group&#39;s resource-based policy that are provided using an action group. If a profiling group doesn&#39;t have a resource-based policy, one is created for it using the permissions in the action group and the roles and users in the <code>principals</code> parameter. </p> <pre><code> &lt;p&gt; The one supported action group that can be added is &lt;code&gt;agentPermission&lt;/code&gt; which grants &lt;code&gt;ConfigureAgent&lt;/code&gt; and &lt;code&gt;PostAgent&lt;/code&gt; permissions. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codeguru/latest/profiler-ug/resource-based-policies.html&quot;&gt;Resource-based policies in CodeGuru Profiler&lt;/a&gt; in the &lt;i&gt;Amazon CodeGuru Profiler User Guide&lt;/i&gt;, &lt;a href=&quot;https://docs.aws.amazon.com/codeguru/latest/profiler-api/API<em>ConfigureAgent.html&quot;&gt; &lt;code&gt;ConfigureAgent&lt;/code&gt; &lt;/a&gt;, and &lt;a href=&quot;https://docs.aws.amazon.com/codeguru/latest/profiler-api/API</em>PostAgentProfile.html&quot;&gt; &lt;code&gt;PostAgentProfile&lt;/code&gt; &lt;/a&gt;. &lt;/p&gt; &lt;p&gt; The first time you call &lt;code&gt;PutPermission&lt;/code&gt; on a profiling group, do not specify a &lt;code&gt;revisionId&lt;/code&gt; because it doesn&#39;t have a resource-based policy. Subsequent calls must provide a &lt;code&gt;revisionId&lt;/code&gt; to specify which revision of the resource-based policy to add the permissions to. &lt;/p&gt; &lt;p&gt; The response contains the profiling group&#39;s JSON-formatted resource policy. &lt;/p&gt; </code></pre></p>
+    ///
+    /// See `CodeGuruProfiler::put_permission`.
+    pub fn put_permission(
+        &self,
+        input: PutPermissionRequest,
+    ) -> Result<PutPermissionResponse, RusotoError<PutPermissionError>> {
+        self.runtime.block_on(self.client.put_permission(input))
+    }
+
+    /// <p>Remove one anomaly notifications channel for a profiling group.</p>
+    ///
+    /// See `CodeGuruProfiler::remove_notification_channel`.
+    pub fn remove_notification_channel(
+        &self,
+        input: RemoveNotificationChannelRequest,
+    ) -> Result<RemoveNotificationChannelResponse, RusotoError<RemoveNotificationChannelError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_notification_channel(input))
+    }
+
+    /// <p> Removes permissions from a profiling group's resource-based policy that are provided using an action group. The one supported action group that can be removed is <code>agentPermission</code> which grants <code>ConfigureAgent</code> and <code>PostAgent</code> permissions. For more information, see <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-ug/resource-based-policies.html">Resource-based policies in CodeGuru Profiler</a> in the <i>Amazon CodeGuru Profiler User Guide</i>, <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_ConfigureAgent.html"> <code>ConfigureAgent</code> </a>, and <a href="https://docs.aws.amazon.com/codeguru/latest/profiler-api/API_PostAgentProfile.html"> <code>PostAgentProfile</code> </a>. </p>
+    ///
+    /// See `CodeGuruProfiler::remove_permission`.
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<RemovePermissionResponse, RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// <p>Sends feedback to CodeGuru Profiler about whether the anomaly detected by the analysis is useful or not.</p>
+    ///
+    /// See `CodeGuruProfiler::submit_feedback`.
+    pub fn submit_feedback(
+        &self,
+        input: SubmitFeedbackRequest,
+    ) -> Result<SubmitFeedbackResponse, RusotoError<SubmitFeedbackError>> {
+        self.runtime.block_on(self.client.submit_feedback(input))
+    }
+
+    /// <p>Updates a profiling group.</p>
+    ///
+    /// See `CodeGuruProfiler::update_profiling_group`.
+    pub fn update_profiling_group(
+        &self,
+        input: UpdateProfilingGroupRequest,
+    ) -> Result<UpdateProfilingGroupResponse, RusotoError<UpdateProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_profiling_group(input))
+    }
+}
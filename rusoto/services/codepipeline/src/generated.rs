@@ -2051,10 +2051,10 @@ impl AcknowledgeJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNonceException" => {
-                    return RusotoError::Service(AcknowledgeJobError::InvalidNonce(err.msg))
+                    return RusotoError::service(&res, AcknowledgeJobError::InvalidNonce(err.msg))
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(AcknowledgeJobError::JobNotFound(err.msg))
+                    return RusotoError::service(&res, AcknowledgeJobError::JobNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2089,19 +2089,22 @@ impl AcknowledgeThirdPartyJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcknowledgeThirdPartyJobError::InvalidClientToken(err.msg),
+                    )
                 }
                 "InvalidNonceException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::InvalidNonce(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcknowledgeThirdPartyJobError::InvalidNonce(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcknowledgeThirdPartyJobError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2139,20 +2142,28 @@ impl CreateCustomActionTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomActionTypeError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::InvalidTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomActionTypeError::InvalidTags(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomActionTypeError::LimitExceeded(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomActionTypeError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2203,39 +2214,49 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreatePipelineError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidActionDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidActionDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InvalidActionDeclaration(err.msg),
+                    )
                 }
                 "InvalidBlockerDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidBlockerDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InvalidBlockerDeclaration(err.msg),
+                    )
                 }
                 "InvalidStageDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidStageDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InvalidStageDeclaration(err.msg),
+                    )
                 }
                 "InvalidStructureException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidStructure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InvalidStructure(err.msg),
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidTags(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::InvalidTags(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::LimitExceeded(err.msg))
                 }
                 "PipelineNameInUseException" => {
-                    return RusotoError::Service(CreatePipelineError::PipelineNameInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::PipelineNameInUse(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePipelineError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2273,7 +2294,8 @@ impl DeleteCustomActionTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteCustomActionTypeError::ConcurrentModification(err.msg),
                     )
                 }
@@ -2307,9 +2329,10 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePipelineError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2339,9 +2362,10 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteWebhookError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebhookError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2373,7 +2397,8 @@ impl DeregisterWebhookWithThirdPartyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WebhookNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
                     )
                 }
@@ -2409,14 +2434,16 @@ impl DisableStageTransitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DisableStageTransitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableStageTransitionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(DisableStageTransitionError::StageNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableStageTransitionError::StageNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2449,12 +2476,16 @@ impl EnableStageTransitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(EnableStageTransitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableStageTransitionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(EnableStageTransitionError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableStageTransitionError::StageNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2485,7 +2516,7 @@ impl GetJobDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "JobNotFoundException" => {
-                    return RusotoError::Service(GetJobDetailsError::JobNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobDetailsError::JobNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2517,10 +2548,13 @@ impl GetPipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(&res, GetPipelineError::PipelineNotFound(err.msg))
                 }
                 "PipelineVersionNotFoundException" => {
-                    return RusotoError::Service(GetPipelineError::PipelineVersionNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineError::PipelineVersionNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2553,14 +2587,16 @@ impl GetPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPipelineExecutionError::PipelineExecutionNotFound(err.msg),
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineExecutionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2593,7 +2629,10 @@ impl GetPipelineStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineStateError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineStateError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2627,15 +2666,22 @@ impl GetThirdPartyJobDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetThirdPartyJobDetailsError::InvalidClientToken(err.msg),
+                    )
                 }
                 "InvalidJobException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::InvalidJob(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetThirdPartyJobDetailsError::InvalidJob(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::JobNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetThirdPartyJobDetailsError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2671,19 +2717,22 @@ impl ListActionExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListActionExecutionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListActionExecutionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "PipelineExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListActionExecutionsError::PipelineExecutionNotFound(err.msg),
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ListActionExecutionsError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListActionExecutionsError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2717,7 +2766,10 @@ impl ListActionTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListActionTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListActionTypesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2749,14 +2801,16 @@ impl ListPipelineExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPipelineExecutionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPipelineExecutionsError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2787,7 +2841,10 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPipelinesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2821,17 +2878,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2863,7 +2925,7 @@ impl ListWebhooksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListWebhooksError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListWebhooksError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2893,7 +2955,10 @@ impl PollForJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionTypeNotFoundException" => {
-                    return RusotoError::Service(PollForJobsError::ActionTypeNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PollForJobsError::ActionTypeNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2923,9 +2988,10 @@ impl PollForThirdPartyJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionTypeNotFoundException" => {
-                    return RusotoError::Service(PollForThirdPartyJobsError::ActionTypeNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PollForThirdPartyJobsError::ActionTypeNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2959,13 +3025,22 @@ impl PutActionRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::ActionNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutActionRevisionError::ActionNotFound(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutActionRevisionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutActionRevisionError::StageNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3005,23 +3080,34 @@ impl PutApprovalResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::ActionNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutApprovalResultError::ActionNotFound(err.msg),
+                    )
                 }
                 "ApprovalAlreadyCompletedException" => {
-                    return RusotoError::Service(PutApprovalResultError::ApprovalAlreadyCompleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutApprovalResultError::ApprovalAlreadyCompleted(err.msg),
+                    )
                 }
                 "InvalidApprovalTokenException" => {
-                    return RusotoError::Service(PutApprovalResultError::InvalidApprovalToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutApprovalResultError::InvalidApprovalToken(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutApprovalResultError::PipelineNotFound(err.msg),
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutApprovalResultError::StageNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3057,10 +3143,16 @@ impl PutJobFailureResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(PutJobFailureResultError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutJobFailureResultError::InvalidJobState(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutJobFailureResultError::JobNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutJobFailureResultError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3095,13 +3187,20 @@ impl PutJobSuccessResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(PutJobSuccessResultError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutJobSuccessResultError::InvalidJobState(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutJobSuccessResultError::JobNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutJobSuccessResultError::JobNotFound(err.msg),
+                    )
                 }
                 "OutputVariablesSizeExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutJobSuccessResultError::OutputVariablesSizeExceeded(err.msg),
                     )
                 }
@@ -3143,19 +3242,22 @@ impl PutThirdPartyJobFailureResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutThirdPartyJobFailureResultError::InvalidClientToken(err.msg),
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutThirdPartyJobFailureResultError::InvalidJobState(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutThirdPartyJobFailureResultError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutThirdPartyJobFailureResultError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3197,19 +3299,22 @@ impl PutThirdPartyJobSuccessResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutThirdPartyJobSuccessResultError::InvalidClientToken(err.msg),
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutThirdPartyJobSuccessResultError::InvalidJobState(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutThirdPartyJobSuccessResultError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutThirdPartyJobSuccessResultError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3257,29 +3362,34 @@ impl PutWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutWebhookError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutWebhookError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(PutWebhookError::InvalidTags(err.msg))
+                    return RusotoError::service(&res, PutWebhookError::InvalidTags(err.msg))
                 }
                 "InvalidWebhookAuthenticationParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutWebhookError::InvalidWebhookAuthenticationParameters(err.msg),
                     )
                 }
                 "InvalidWebhookFilterPatternException" => {
-                    return RusotoError::Service(PutWebhookError::InvalidWebhookFilterPattern(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutWebhookError::InvalidWebhookFilterPattern(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutWebhookError::LimitExceeded(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutWebhookError::PipelineNotFound(err.msg))
+                    return RusotoError::service(&res, PutWebhookError::PipelineNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(PutWebhookError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, PutWebhookError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3319,7 +3429,8 @@ impl RegisterWebhookWithThirdPartyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WebhookNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
                     )
                 }
@@ -3359,22 +3470,28 @@ impl RetryStageExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotLatestPipelineExecutionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RetryStageExecutionError::NotLatestPipelineExecution(err.msg),
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(RetryStageExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RetryStageExecutionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(RetryStageExecutionError::StageNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RetryStageExecutionError::StageNotFound(err.msg),
+                    )
                 }
                 "StageNotRetryableException" => {
-                    return RusotoError::Service(RetryStageExecutionError::StageNotRetryable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RetryStageExecutionError::StageNotRetryable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3409,9 +3526,10 @@ impl StartPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(StartPipelineExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPipelineExecutionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3445,19 +3563,22 @@ impl StopPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicatedStopRequestException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::DuplicatedStopRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopPipelineExecutionError::DuplicatedStopRequest(err.msg),
+                    )
                 }
                 "PipelineExecutionNotStoppableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopPipelineExecutionError::PipelineExecutionNotStoppable(err.msg),
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopPipelineExecutionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3499,19 +3620,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArn(err.msg))
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidTags(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3551,18 +3675,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArn(err.msg))
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTags(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidTags(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3603,25 +3731,31 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidActionDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidActionDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::InvalidActionDeclaration(err.msg),
+                    )
                 }
                 "InvalidBlockerDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidBlockerDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::InvalidBlockerDeclaration(err.msg),
+                    )
                 }
                 "InvalidStageDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidStageDeclaration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::InvalidStageDeclaration(err.msg),
+                    )
                 }
                 "InvalidStructureException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidStructure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::InvalidStructure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdatePipelineError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3889,6 +4023,7 @@ impl CodePipelineClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3904,6 +4039,7 @@ impl CodePipelineClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineClient {
         CodePipelineClient { client, region }
     }
@@ -3917,6 +4053,9 @@ impl CodePipeline for CodePipelineClient {
         input: AcknowledgeJobInput,
     ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcknowledgeJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.AcknowledgeJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3935,6 +4074,9 @@ impl CodePipeline for CodePipelineClient {
         input: AcknowledgeThirdPartyJobInput,
     ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcknowledgeThirdPartyJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.AcknowledgeThirdPartyJob",
@@ -3957,6 +4099,9 @@ impl CodePipeline for CodePipelineClient {
         input: CreateCustomActionTypeInput,
     ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCustomActionType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.CreateCustomActionType",
@@ -3979,6 +4124,9 @@ impl CodePipeline for CodePipelineClient {
         input: CreatePipelineInput,
     ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3997,6 +4145,9 @@ impl CodePipeline for CodePipelineClient {
         input: DeleteCustomActionTypeInput,
     ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCustomActionType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.DeleteCustomActionType",
@@ -4017,6 +4168,9 @@ impl CodePipeline for CodePipelineClient {
         input: DeletePipelineInput,
     ) -> Result<(), RusotoError<DeletePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4034,6 +4188,9 @@ impl CodePipeline for CodePipelineClient {
         input: DeleteWebhookInput,
     ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.DeleteWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4055,6 +4212,9 @@ impl CodePipeline for CodePipelineClient {
         RusotoError<DeregisterWebhookWithThirdPartyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterWebhookWithThirdParty");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.DeregisterWebhookWithThirdParty",
@@ -4077,6 +4237,9 @@ impl CodePipeline for CodePipelineClient {
         input: DisableStageTransitionInput,
     ) -> Result<(), RusotoError<DisableStageTransitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableStageTransition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.DisableStageTransition",
@@ -4097,6 +4260,9 @@ impl CodePipeline for CodePipelineClient {
         input: EnableStageTransitionInput,
     ) -> Result<(), RusotoError<EnableStageTransitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableStageTransition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.EnableStageTransition",
@@ -4117,6 +4283,9 @@ impl CodePipeline for CodePipelineClient {
         input: GetJobDetailsInput,
     ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.GetJobDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4135,6 +4304,9 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineInput,
     ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPipeline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4153,6 +4325,9 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineExecutionInput,
     ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPipelineExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4171,6 +4346,9 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineStateInput,
     ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPipelineState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4189,6 +4367,9 @@ impl CodePipeline for CodePipelineClient {
         input: GetThirdPartyJobDetailsInput,
     ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetThirdPartyJobDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.GetThirdPartyJobDetails",
@@ -4211,6 +4392,9 @@ impl CodePipeline for CodePipelineClient {
         input: ListActionExecutionsInput,
     ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListActionExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4229,6 +4413,9 @@ impl CodePipeline for CodePipelineClient {
         input: ListActionTypesInput,
     ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListActionTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4247,6 +4434,9 @@ impl CodePipeline for CodePipelineClient {
         input: ListPipelineExecutionsInput,
     ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPipelineExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.ListPipelineExecutions",
@@ -4269,6 +4459,9 @@ impl CodePipeline for CodePipelineClient {
         input: ListPipelinesInput,
     ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPipelines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4287,6 +4480,9 @@ impl CodePipeline for CodePipelineClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4305,6 +4501,9 @@ impl CodePipeline for CodePipelineClient {
         input: ListWebhooksInput,
     ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWebhooks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodePipeline_20150709.ListWebhooks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4323,6 +4522,9 @@ impl CodePipeline for CodePipelineClient {
         input: PollForJobsInput,
     ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PollForJobs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.PollForJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4341,6 +4543,9 @@ impl CodePipeline for CodePipelineClient {
         input: PollForThirdPartyJobsInput,
     ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PollForThirdPartyJobs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.PollForThirdPartyJobs",
@@ -4362,6 +4567,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutActionRevisionInput,
     ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutActionRevision");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.PutActionRevision");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4380,6 +4588,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutApprovalResultInput,
     ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutApprovalResult");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.PutApprovalResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4398,6 +4609,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutJobFailureResultInput,
     ) -> Result<(), RusotoError<PutJobFailureResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutJobFailureResult");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobFailureResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4415,6 +4629,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutJobSuccessResultInput,
     ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutJobSuccessResult");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobSuccessResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4432,6 +4649,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutThirdPartyJobFailureResultInput,
     ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutThirdPartyJobFailureResult");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.PutThirdPartyJobFailureResult",
@@ -4452,6 +4672,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutThirdPartyJobSuccessResultInput,
     ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutThirdPartyJobSuccessResult");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.PutThirdPartyJobSuccessResult",
@@ -4472,6 +4695,9 @@ impl CodePipeline for CodePipelineClient {
         input: PutWebhookInput,
     ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutWebhook");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.PutWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4491,6 +4717,9 @@ impl CodePipeline for CodePipelineClient {
     ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterWebhookWithThirdParty");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.RegisterWebhookWithThirdParty",
@@ -4513,6 +4742,9 @@ impl CodePipeline for CodePipelineClient {
         input: RetryStageExecutionInput,
     ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RetryStageExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.RetryStageExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4531,6 +4763,9 @@ impl CodePipeline for CodePipelineClient {
         input: StartPipelineExecutionInput,
     ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartPipelineExecution");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.StartPipelineExecution",
@@ -4553,6 +4788,9 @@ impl CodePipeline for CodePipelineClient {
         input: StopPipelineExecutionInput,
     ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopPipelineExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.StopPipelineExecution",
@@ -4574,6 +4812,9 @@ impl CodePipeline for CodePipelineClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4592,6 +4833,9 @@ impl CodePipeline for CodePipelineClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4610,6 +4854,9 @@ impl CodePipeline for CodePipelineClient {
         input: UpdatePipelineInput,
     ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodePipeline_20150709.UpdatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4622,3 +4869,449 @@ impl CodePipeline for CodePipelineClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePipelineOutput, _>()
     }
 }
+
+/// A blocking client for the CodePipeline API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodePipelineClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodePipelineBlockingClient {
+    client: CodePipelineClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodePipelineBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodePipelineBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodePipelineBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodePipelineBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodePipelineBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient {
+            client: CodePipelineClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns information about a specified job and whether that job has been received by the job worker. Used for custom actions only.</p>
+    ///
+    /// See `CodePipeline::acknowledge_job`.
+    pub fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
+        self.runtime.block_on(self.client.acknowledge_job(input))
+    }
+
+    /// <p>Confirms a job worker has received the specified job. Used for partner actions only.</p>
+    ///
+    /// See `CodePipeline::acknowledge_third_party_job`.
+    pub fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
+        self.runtime
+            .block_on(self.client.acknowledge_third_party_job(input))
+    }
+
+    /// <p>Creates a new custom action that can be used in all pipelines associated with the AWS account. Only used for custom actions.</p>
+    ///
+    /// See `CodePipeline::create_custom_action_type`.
+    pub fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
+        self.runtime
+            .block_on(self.client.create_custom_action_type(input))
+    }
+
+    /// <p><p>Creates a pipeline.</p> <note> <p>In the pipeline structure, you must include either <code>artifactStore</code> or <code>artifactStores</code> in your pipeline, but you cannot use both. If you create a cross-region action in your pipeline, you must use <code>artifactStores</code>.</p> </note></p>
+    ///
+    /// See `CodePipeline::create_pipeline`.
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// <p><p>Marks a custom action as deleted. <code>PollForJobs</code> for the custom action fails after the action is marked for deletion. Used for custom actions only.</p> <important> <p>To re-create a custom action after it has been deleted you must use a string in the version field that has never been used before. This string can be an incremented version number, for example. To restore a deleted custom action, use a JSON file that is identical to the deleted action, including the original string in the version field.</p> </important></p>
+    ///
+    /// See `CodePipeline::delete_custom_action_type`.
+    pub fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_action_type(input))
+    }
+
+    /// <p>Deletes the specified pipeline.</p>
+    ///
+    /// See `CodePipeline::delete_pipeline`.
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// <p>Deletes a previously created webhook by name. Deleting the webhook stops AWS CodePipeline from starting a pipeline every time an external event occurs. The API returns successfully when trying to delete a webhook that is already deleted. If a deleted webhook is re-created by calling PutWebhook with the same name, it will have a different URL.</p>
+    ///
+    /// See `CodePipeline::delete_webhook`.
+    pub fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+        self.runtime.block_on(self.client.delete_webhook(input))
+    }
+
+    /// <p>Removes the connection between the webhook that was created by CodePipeline and the external tool with events to be detected. Currently supported only for webhooks that target an action type of GitHub.</p>
+    ///
+    /// See `CodePipeline::deregister_webhook_with_third_party`.
+    pub fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> Result<
+        DeregisterWebhookWithThirdPartyOutput,
+        RusotoError<DeregisterWebhookWithThirdPartyError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_webhook_with_third_party(input))
+    }
+
+    /// <p>Prevents artifacts in a pipeline from transitioning to the next stage in the pipeline.</p>
+    ///
+    /// See `CodePipeline::disable_stage_transition`.
+    pub fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> Result<(), RusotoError<DisableStageTransitionError>> {
+        self.runtime
+            .block_on(self.client.disable_stage_transition(input))
+    }
+
+    /// <p>Enables artifacts in a pipeline to transition to a stage in a pipeline.</p>
+    ///
+    /// See `CodePipeline::enable_stage_transition`.
+    pub fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> Result<(), RusotoError<EnableStageTransitionError>> {
+        self.runtime
+            .block_on(self.client.enable_stage_transition(input))
+    }
+
+    /// <p><p>Returns information about a job. Used for custom actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
+    ///
+    /// See `CodePipeline::get_job_details`.
+    pub fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
+        self.runtime.block_on(self.client.get_job_details(input))
+    }
+
+    /// <p>Returns the metadata, structure, stages, and actions of a pipeline. Can be used to return the entire structure of a pipeline in JSON format, which can then be modified and used to update the pipeline structure with <a>UpdatePipeline</a>.</p>
+    ///
+    /// See `CodePipeline::get_pipeline`.
+    pub fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
+        self.runtime.block_on(self.client.get_pipeline(input))
+    }
+
+    /// <p>Returns information about an execution of a pipeline, including details about artifacts, the pipeline execution ID, and the name, version, and status of the pipeline.</p>
+    ///
+    /// See `CodePipeline::get_pipeline_execution`.
+    pub fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.get_pipeline_execution(input))
+    }
+
+    /// <p><p>Returns information about the state of a pipeline, including the stages and actions.</p> <note> <p>Values returned in the <code>revisionId</code> and <code>revisionUrl</code> fields indicate the source revision information, such as the commit ID, for the current state.</p> </note></p>
+    ///
+    /// See `CodePipeline::get_pipeline_state`.
+    pub fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
+        self.runtime.block_on(self.client.get_pipeline_state(input))
+    }
+
+    /// <p><p>Requests the details of a job for a third party action. Used for partner actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
+    ///
+    /// See `CodePipeline::get_third_party_job_details`.
+    pub fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_third_party_job_details(input))
+    }
+
+    /// <p>Lists the action executions that have occurred in a pipeline.</p>
+    ///
+    /// See `CodePipeline::list_action_executions`.
+    pub fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_action_executions(input))
+    }
+
+    /// <p>Gets a summary of all AWS CodePipeline action types associated with your account.</p>
+    ///
+    /// See `CodePipeline::list_action_types`.
+    pub fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
+        self.runtime.block_on(self.client.list_action_types(input))
+    }
+
+    /// <p>Gets a summary of the most recent executions for a pipeline.</p>
+    ///
+    /// See `CodePipeline::list_pipeline_executions`.
+    pub fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_pipeline_executions(input))
+    }
+
+    /// <p>Gets a summary of all of the pipelines associated with your account.</p>
+    ///
+    /// See `CodePipeline::list_pipelines`.
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// <p>Gets the set of key-value pairs (metadata) that are used to manage the resource.</p>
+    ///
+    /// See `CodePipeline::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Gets a listing of all the webhooks in this AWS Region for this account. The output lists all webhooks and includes the webhook URL and ARN and the configuration for each webhook.</p>
+    ///
+    /// See `CodePipeline::list_webhooks`.
+    pub fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
+        self.runtime.block_on(self.client.list_webhooks(input))
+    }
+
+    /// <p><p>Returns information about any jobs for AWS CodePipeline to act on. <code>PollForJobs</code> is valid only for action types with &quot;Custom&quot; in the owner field. If the action type contains &quot;AWS&quot; or &quot;ThirdParty&quot; in the owner field, the <code>PollForJobs</code> action returns an error.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts. This API also returns any secret values defined for the action.</p> </important></p>
+    ///
+    /// See `CodePipeline::poll_for_jobs`.
+    pub fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
+        self.runtime.block_on(self.client.poll_for_jobs(input))
+    }
+
+    /// <p><p>Determines whether there are any third party jobs for a job worker to act on. Used for partner actions only.</p> <important> <p>When this API is called, AWS CodePipeline returns temporary credentials for the S3 bucket used to store artifacts for the pipeline, if the action requires access to that S3 bucket for input or output artifacts.</p> </important></p>
+    ///
+    /// See `CodePipeline::poll_for_third_party_jobs`.
+    pub fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
+        self.runtime
+            .block_on(self.client.poll_for_third_party_jobs(input))
+    }
+
+    /// <p>Provides information to AWS CodePipeline about new revisions to a source.</p>
+    ///
+    /// See `CodePipeline::put_action_revision`.
+    pub fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
+        self.runtime
+            .block_on(self.client.put_action_revision(input))
+    }
+
+    /// <p>Provides the response to a manual approval request to AWS CodePipeline. Valid responses include Approved and Rejected.</p>
+    ///
+    /// See `CodePipeline::put_approval_result`.
+    pub fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
+        self.runtime
+            .block_on(self.client.put_approval_result(input))
+    }
+
+    /// <p>Represents the failure of a job as returned to the pipeline by a job worker. Used for custom actions only.</p>
+    ///
+    /// See `CodePipeline::put_job_failure_result`.
+    pub fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> Result<(), RusotoError<PutJobFailureResultError>> {
+        self.runtime
+            .block_on(self.client.put_job_failure_result(input))
+    }
+
+    /// <p>Represents the success of a job as returned to the pipeline by a job worker. Used for custom actions only.</p>
+    ///
+    /// See `CodePipeline::put_job_success_result`.
+    pub fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
+        self.runtime
+            .block_on(self.client.put_job_success_result(input))
+    }
+
+    /// <p>Represents the failure of a third party job as returned to the pipeline by a job worker. Used for partner actions only.</p>
+    ///
+    /// See `CodePipeline::put_third_party_job_failure_result`.
+    pub fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
+        self.runtime
+            .block_on(self.client.put_third_party_job_failure_result(input))
+    }
+
+    /// <p>Represents the success of a third party job as returned to the pipeline by a job worker. Used for partner actions only.</p>
+    ///
+    /// See `CodePipeline::put_third_party_job_success_result`.
+    pub fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
+        self.runtime
+            .block_on(self.client.put_third_party_job_success_result(input))
+    }
+
+    /// <p>Defines a webhook and returns a unique webhook URL generated by CodePipeline. This URL can be supplied to third party source hosting providers to call every time there's a code change. When CodePipeline receives a POST request on this URL, the pipeline defined in the webhook is started as long as the POST request satisfied the authentication and filtering requirements supplied when defining the webhook. RegisterWebhookWithThirdParty and DeregisterWebhookWithThirdParty APIs can be used to automatically configure supported third parties to call the generated webhook URL.</p>
+    ///
+    /// See `CodePipeline::put_webhook`.
+    pub fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
+        self.runtime.block_on(self.client.put_webhook(input))
+    }
+
+    /// <p>Configures a connection between the webhook that was created and the external tool with events to be detected.</p>
+    ///
+    /// See `CodePipeline::register_webhook_with_third_party`.
+    pub fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
+    {
+        self.runtime
+            .block_on(self.client.register_webhook_with_third_party(input))
+    }
+
+    /// <p>Resumes the pipeline execution by retrying the last failed actions in a stage. You can retry a stage immediately if any of the actions in the stage fail. When you retry, all actions that are still in progress continue working, and failed actions are triggered again.</p>
+    ///
+    /// See `CodePipeline::retry_stage_execution`.
+    pub fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
+        self.runtime
+            .block_on(self.client.retry_stage_execution(input))
+    }
+
+    /// <p>Starts the specified pipeline. Specifically, it begins processing the latest commit to the source location specified as part of the pipeline.</p>
+    ///
+    /// See `CodePipeline::start_pipeline_execution`.
+    pub fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_pipeline_execution(input))
+    }
+
+    /// <p>Stops the specified pipeline execution. You choose to either stop the pipeline execution by completing in-progress actions without starting subsequent actions, or by abandoning in-progress actions. While completing or abandoning in-progress actions, the pipeline execution is in a <code>Stopping</code> state. After all in-progress actions are completed or abandoned, the pipeline execution is in a <code>Stopped</code> state.</p>
+    ///
+    /// See `CodePipeline::stop_pipeline_execution`.
+    pub fn stop_pipeline_execution(
+        &self,
+        input: StopPipelineExecutionInput,
+    ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_pipeline_execution(input))
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource. </p>
+    ///
+    /// See `CodePipeline::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from an AWS resource.</p>
+    ///
+    /// See `CodePipeline::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a specified pipeline with edits or changes to its structure. Use a JSON file with the pipeline structure and <code>UpdatePipeline</code> to provide the full structure of the pipeline. Updating the pipeline increases the version number of the pipeline by 1.</p>
+    ///
+    /// See `CodePipeline::update_pipeline`.
+    pub fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
+        self.runtime.block_on(self.client.update_pipeline(input))
+    }
+}
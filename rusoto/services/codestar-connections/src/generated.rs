@@ -404,15 +404,22 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateConnectionError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -444,7 +451,7 @@ impl CreateHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateHostError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateHostError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -474,7 +481,10 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -506,10 +516,13 @@ impl DeleteHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHostError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteHostError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteHostError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHostError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -542,10 +555,16 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(GetConnectionError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectionError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -576,7 +595,7 @@ impl GetHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetHostError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetHostError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -650,9 +669,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -684,10 +704,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -718,7 +738,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -823,6 +846,7 @@ impl CodeStarConnectionsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -838,6 +862,7 @@ impl CodeStarConnectionsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarConnectionsClient {
         CodeStarConnectionsClient { client, region }
     }
@@ -851,6 +876,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: CreateConnectionInput,
     ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.CreateConnection",
@@ -872,6 +900,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: CreateHostInput,
     ) -> Result<CreateHostOutput, RusotoError<CreateHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHost");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.CreateHost",
@@ -893,6 +924,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: DeleteConnectionInput,
     ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.DeleteConnection",
@@ -914,6 +948,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: DeleteHostInput,
     ) -> Result<DeleteHostOutput, RusotoError<DeleteHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteHost");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.DeleteHost",
@@ -935,6 +972,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: GetConnectionInput,
     ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConnection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.GetConnection",
@@ -956,6 +996,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: GetHostInput,
     ) -> Result<GetHostOutput, RusotoError<GetHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetHost");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.GetHost",
@@ -977,6 +1020,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListConnectionsInput,
     ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.ListConnections",
@@ -998,6 +1044,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListHostsInput,
     ) -> Result<ListHostsOutput, RusotoError<ListHostsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHosts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.ListHosts",
@@ -1019,6 +1068,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.ListTagsForResource",
@@ -1040,6 +1092,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.TagResource",
@@ -1061,6 +1116,9 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.UntagResource",
@@ -1076,3 +1134,171 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 }
+
+/// A blocking client for the AWS CodeStar connections API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeStarConnectionsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeStarConnectionsBlockingClient {
+    client: CodeStarConnectionsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeStarConnectionsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeStarConnectionsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeStarConnectionsBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarConnectionsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeStarConnectionsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient {
+            client: CodeStarConnectionsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a connection that can then be given to other AWS services like CodePipeline so that it can access third-party code repositories. The connection is in pending status until the third-party connection handshake is completed from the console.</p>
+    ///
+    /// See `CodeStarConnections::create_connection`.
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionInput,
+    ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// <p><p>Creates a resource that represents the infrastructure where a third-party provider is installed. The host is used when you create connections to an installed third-party provider type, such as GitHub Enterprise Server. You create one host for all connections to that provider.</p> <note> <p>A host created through the CLI or the SDK is in <code>PENDING</code> status by default. You can make its status <code>AVAILABLE</code> by setting up the host in the console.</p> </note></p>
+    ///
+    /// See `CodeStarConnections::create_host`.
+    pub fn create_host(
+        &self,
+        input: CreateHostInput,
+    ) -> Result<CreateHostOutput, RusotoError<CreateHostError>> {
+        self.runtime.block_on(self.client.create_host(input))
+    }
+
+    /// <p>The connection to be deleted.</p>
+    ///
+    /// See `CodeStarConnections::delete_connection`.
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionInput,
+    ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// <p><p>The host to be deleted. Before you delete a host, all connections associated to the host must be deleted.</p> <note> <p>A host cannot be deleted if it is in the VPC<em>CONFIG</em>INITIALIZING or VPC<em>CONFIG</em>DELETING state.</p> </note></p>
+    ///
+    /// See `CodeStarConnections::delete_host`.
+    pub fn delete_host(
+        &self,
+        input: DeleteHostInput,
+    ) -> Result<DeleteHostOutput, RusotoError<DeleteHostError>> {
+        self.runtime.block_on(self.client.delete_host(input))
+    }
+
+    /// <p>Returns the connection ARN and details such as status, owner, and provider type.</p>
+    ///
+    /// See `CodeStarConnections::get_connection`.
+    pub fn get_connection(
+        &self,
+        input: GetConnectionInput,
+    ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
+        self.runtime.block_on(self.client.get_connection(input))
+    }
+
+    /// <p>Returns the host ARN and details such as status, provider type, endpoint, and, if applicable, the VPC configuration.</p>
+    ///
+    /// See `CodeStarConnections::get_host`.
+    pub fn get_host(
+        &self,
+        input: GetHostInput,
+    ) -> Result<GetHostOutput, RusotoError<GetHostError>> {
+        self.runtime.block_on(self.client.get_host(input))
+    }
+
+    /// <p>Lists the connections associated with your account.</p>
+    ///
+    /// See `CodeStarConnections::list_connections`.
+    pub fn list_connections(
+        &self,
+        input: ListConnectionsInput,
+    ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
+        self.runtime.block_on(self.client.list_connections(input))
+    }
+
+    /// <p>Lists the hosts associated with your account.</p>
+    ///
+    /// See `CodeStarConnections::list_hosts`.
+    pub fn list_hosts(
+        &self,
+        input: ListHostsInput,
+    ) -> Result<ListHostsOutput, RusotoError<ListHostsError>> {
+        self.runtime.block_on(self.client.list_hosts(input))
+    }
+
+    /// <p>Gets the set of key-value pairs (metadata) that are used to manage the resource.</p>
+    ///
+    /// See `CodeStarConnections::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource.</p>
+    ///
+    /// See `CodeStarConnections::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from an AWS resource.</p>
+    ///
+    /// See `CodeStarConnections::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
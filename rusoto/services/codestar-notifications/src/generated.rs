@@ -494,25 +494,32 @@ impl CreateNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationRuleError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotificationRuleError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConfigurationException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::Configuration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationRuleError::Configuration(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNotificationRuleError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotificationRuleError::ResourceAlreadyExists(err.msg),
                     )
                 }
@@ -552,14 +559,16 @@ impl DeleteNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteNotificationRuleError::ConcurrentModification(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteNotificationRuleError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationRuleError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -614,9 +623,10 @@ impl DescribeNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNotificationRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNotificationRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -646,7 +656,10 @@ impl ListEventTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEventTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventTypesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -676,9 +689,10 @@ impl ListNotificationRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListNotificationRulesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNotificationRulesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -708,9 +722,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -740,7 +755,7 @@ impl ListTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTargetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListTargetsError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -770,7 +785,7 @@ impl SubscribeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SubscribeError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -802,10 +817,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -860,12 +878,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -896,9 +918,10 @@ impl UpdateNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNotificationRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1015,6 +1038,7 @@ impl CodeStarNotificationsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1030,6 +1054,7 @@ impl CodeStarNotificationsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarNotificationsClient {
         CodeStarNotificationsClient { client, region }
     }
@@ -1047,6 +1072,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("CreateNotificationRule");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1079,6 +1107,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("DeleteNotificationRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1111,6 +1142,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("DeleteTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1143,6 +1177,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("DescribeNotificationRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1175,6 +1212,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("ListEventTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1207,6 +1247,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("ListNotificationRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1239,6 +1282,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1271,6 +1317,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("ListTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1303,6 +1352,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("Subscribe");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1335,6 +1387,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1367,6 +1422,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("Unsubscribe");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1399,6 +1457,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1431,6 +1492,9 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation_name("UpdateNotificationRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1453,3 +1517,196 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
         }
     }
 }
+
+/// A blocking client for the AWS CodeStar Notifications API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeStarNotificationsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeStarNotificationsBlockingClient {
+    client: CodeStarNotificationsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeStarNotificationsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeStarNotificationsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeStarNotificationsBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarNotificationsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeStarNotificationsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient {
+            client: CodeStarNotificationsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a notification rule for a resource. The rule specifies the events you want notifications about and the targets (such as SNS topics) where you want to receive them.</p>
+    ///
+    /// See `CodeStarNotifications::create_notification_rule`.
+    pub fn create_notification_rule(
+        &self,
+        input: CreateNotificationRuleRequest,
+    ) -> Result<CreateNotificationRuleResult, RusotoError<CreateNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.create_notification_rule(input))
+    }
+
+    /// <p>Deletes a notification rule for a resource.</p>
+    ///
+    /// See `CodeStarNotifications::delete_notification_rule`.
+    pub fn delete_notification_rule(
+        &self,
+        input: DeleteNotificationRuleRequest,
+    ) -> Result<DeleteNotificationRuleResult, RusotoError<DeleteNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_rule(input))
+    }
+
+    /// <p>Deletes a specified target for notifications.</p>
+    ///
+    /// See `CodeStarNotifications::delete_target`.
+    pub fn delete_target(
+        &self,
+        input: DeleteTargetRequest,
+    ) -> Result<DeleteTargetResult, RusotoError<DeleteTargetError>> {
+        self.runtime.block_on(self.client.delete_target(input))
+    }
+
+    /// <p>Returns information about a specified notification rule.</p>
+    ///
+    /// See `CodeStarNotifications::describe_notification_rule`.
+    pub fn describe_notification_rule(
+        &self,
+        input: DescribeNotificationRuleRequest,
+    ) -> Result<DescribeNotificationRuleResult, RusotoError<DescribeNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.describe_notification_rule(input))
+    }
+
+    /// <p>Returns information about the event types available for configuring notifications.</p>
+    ///
+    /// See `CodeStarNotifications::list_event_types`.
+    pub fn list_event_types(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> Result<ListEventTypesResult, RusotoError<ListEventTypesError>> {
+        self.runtime.block_on(self.client.list_event_types(input))
+    }
+
+    /// <p>Returns a list of the notification rules for an AWS account.</p>
+    ///
+    /// See `CodeStarNotifications::list_notification_rules`.
+    pub fn list_notification_rules(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> Result<ListNotificationRulesResult, RusotoError<ListNotificationRulesError>> {
+        self.runtime
+            .block_on(self.client.list_notification_rules(input))
+    }
+
+    /// <p>Returns a list of the tags associated with a notification rule.</p>
+    ///
+    /// See `CodeStarNotifications::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a list of the notification rule targets for an AWS account.</p>
+    ///
+    /// See `CodeStarNotifications::list_targets`.
+    pub fn list_targets(
+        &self,
+        input: ListTargetsRequest,
+    ) -> Result<ListTargetsResult, RusotoError<ListTargetsError>> {
+        self.runtime.block_on(self.client.list_targets(input))
+    }
+
+    /// <p>Creates an association between a notification rule and an SNS topic so that the associated target can receive notifications when the events described in the rule are triggered.</p>
+    ///
+    /// See `CodeStarNotifications::subscribe`.
+    pub fn subscribe(
+        &self,
+        input: SubscribeRequest,
+    ) -> Result<SubscribeResult, RusotoError<SubscribeError>> {
+        self.runtime.block_on(self.client.subscribe(input))
+    }
+
+    /// <p>Associates a set of provided tags with a notification rule.</p>
+    ///
+    /// See `CodeStarNotifications::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes an association between a notification rule and an Amazon SNS topic so that subscribers to that topic stop receiving notifications when the events described in the rule are triggered.</p>
+    ///
+    /// See `CodeStarNotifications::unsubscribe`.
+    pub fn unsubscribe(
+        &self,
+        input: UnsubscribeRequest,
+    ) -> Result<UnsubscribeResult, RusotoError<UnsubscribeError>> {
+        self.runtime.block_on(self.client.unsubscribe(input))
+    }
+
+    /// <p>Removes the association between one or more provided tags and a notification rule.</p>
+    ///
+    /// See `CodeStarNotifications::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Updates a notification rule for a resource. You can change the events that trigger the notification rule, the status of the rule, and the targets that receive the notifications.</p> <note> <p>To add or remove tags for a notification rule, you must use <a>TagResource</a> and <a>UntagResource</a>.</p> </note></p>
+    ///
+    /// See `CodeStarNotifications::update_notification_rule`.
+    pub fn update_notification_rule(
+        &self,
+        input: UpdateNotificationRuleRequest,
+    ) -> Result<UpdateNotificationRuleResult, RusotoError<UpdateNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.update_notification_rule(input))
+    }
+}
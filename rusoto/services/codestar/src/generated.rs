@@ -803,28 +803,38 @@ impl AssociateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTeamMemberError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::InvalidServiceRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTeamMemberError::InvalidServiceRole(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTeamMemberError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ProjectConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTeamMemberError::ProjectConfiguration(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTeamMemberError::ProjectNotFound(err.msg),
+                    )
                 }
                 "TeamMemberAlreadyAssociatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTeamMemberError::TeamMemberAlreadyAssociated(err.msg),
                     )
                 }
@@ -873,24 +883,37 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateProjectError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::InvalidServiceRole(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::LimitExceeded(err.msg))
                 }
                 "ProjectAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ProjectAlreadyExists(err.msg),
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectConfiguration(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ProjectConfiguration(err.msg),
+                    )
                 }
                 "ProjectCreationFailedException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectCreationFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ProjectCreationFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -925,9 +948,10 @@ impl CreateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserProfileError::UserProfileAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserProfileError::UserProfileAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -959,12 +983,16 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteProjectError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::InvalidServiceRole(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1023,20 +1051,28 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeProjectError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DescribeProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::InvalidServiceRole(err.msg),
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(DescribeProjectError::ProjectConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::ProjectConfiguration(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::ProjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1069,9 +1105,10 @@ impl DescribeUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileNotFoundException" => {
-                    return RusotoError::Service(DescribeUserProfileError::UserProfileNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserProfileError::UserProfileNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1105,19 +1142,22 @@ impl DisassociateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateTeamMemberError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DisassociateTeamMemberError::InvalidServiceRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateTeamMemberError::InvalidServiceRole(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(DisassociateTeamMemberError::ProjectNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateTeamMemberError::ProjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1151,7 +1191,7 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1183,10 +1223,13 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListResourcesError::ProjectNotFound(err.msg))
+                    return RusotoError::service(&res, ListResourcesError::ProjectNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1219,10 +1262,16 @@ impl ListTagsForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForProjectError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForProjectError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListTagsForProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForProjectError::ProjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1255,10 +1304,16 @@ impl ListTeamMembersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTeamMembersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTeamMembersError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListTeamMembersError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTeamMembersError::ProjectNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1289,7 +1344,10 @@ impl ListUserProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUserProfilesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserProfilesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1323,13 +1381,16 @@ impl TagProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagProjectError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagProjectError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagProjectError::LimitExceeded(err.msg))
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(TagProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(&res, TagProjectError::ProjectNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1365,13 +1426,16 @@ impl UntagProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagProjectError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagProjectError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UntagProjectError::LimitExceeded(err.msg))
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UntagProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(&res, UntagProjectError::ProjectNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1403,7 +1467,7 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::ProjectNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1443,26 +1507,40 @@ impl UpdateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTeamMemberError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::InvalidServiceRole(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTeamMemberError::InvalidServiceRole(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTeamMemberError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ProjectConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTeamMemberError::ProjectConfiguration(err.msg),
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ProjectNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTeamMemberError::ProjectNotFound(err.msg),
+                    )
                 }
                 "TeamMemberNotFoundException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::TeamMemberNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTeamMemberError::TeamMemberNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1497,9 +1575,10 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileNotFoundException" => {
-                    return RusotoError::Service(UpdateUserProfileError::UserProfileNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserProfileError::UserProfileNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1646,6 +1725,7 @@ impl CodeStarClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1661,6 +1741,7 @@ impl CodeStarClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarClient {
         CodeStarClient { client, region }
     }
@@ -1674,6 +1755,9 @@ impl CodeStar for CodeStarClient {
         input: AssociateTeamMemberRequest,
     ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateTeamMember");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.AssociateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1692,6 +1776,9 @@ impl CodeStar for CodeStarClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1710,6 +1797,9 @@ impl CodeStar for CodeStarClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1728,6 +1818,9 @@ impl CodeStar for CodeStarClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1746,6 +1839,9 @@ impl CodeStar for CodeStarClient {
         input: DeleteUserProfileRequest,
     ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1764,6 +1860,9 @@ impl CodeStar for CodeStarClient {
         input: DescribeProjectRequest,
     ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1782,6 +1881,9 @@ impl CodeStar for CodeStarClient {
         input: DescribeUserProfileRequest,
     ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1800,6 +1902,9 @@ impl CodeStar for CodeStarClient {
         input: DisassociateTeamMemberRequest,
     ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateTeamMember");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.DisassociateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1819,6 +1924,9 @@ impl CodeStar for CodeStarClient {
         input: ListProjectsRequest,
     ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1837,6 +1945,9 @@ impl CodeStar for CodeStarClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1855,6 +1966,9 @@ impl CodeStar for CodeStarClient {
         input: ListTagsForProjectRequest,
     ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.ListTagsForProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1873,6 +1987,9 @@ impl CodeStar for CodeStarClient {
         input: ListTeamMembersRequest,
     ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTeamMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.ListTeamMembers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1891,6 +2008,9 @@ impl CodeStar for CodeStarClient {
         input: ListUserProfilesRequest,
     ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUserProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "CodeStar_20170419.ListUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1909,6 +2029,9 @@ impl CodeStar for CodeStarClient {
         input: TagProjectRequest,
     ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.TagProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1927,6 +2050,9 @@ impl CodeStar for CodeStarClient {
         input: UntagProjectRequest,
     ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.UntagProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1945,6 +2071,9 @@ impl CodeStar for CodeStarClient {
         input: UpdateProjectRequest,
     ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1963,6 +2092,9 @@ impl CodeStar for CodeStarClient {
         input: UpdateTeamMemberRequest,
     ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTeamMember");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1981,6 +2113,9 @@ impl CodeStar for CodeStarClient {
         input: UpdateUserProfileRequest,
     ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1993,3 +2128,240 @@ impl CodeStar for CodeStarClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserProfileResult, _>()
     }
 }
+
+/// A blocking client for the CodeStar API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CodeStarClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeStarBlockingClient {
+    client: CodeStarClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeStarBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CodeStarBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CodeStarBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeStarBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeStarBlockingClient {
+        CodeStarBlockingClient {
+            client: CodeStarClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds an IAM user to the team for an AWS CodeStar project.</p>
+    ///
+    /// See `CodeStar::associate_team_member`.
+    pub fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
+        self.runtime
+            .block_on(self.client.associate_team_member(input))
+    }
+
+    /// <p>Creates a project, including project resources. This action creates a project based on a submitted project request. A set of source code files and a toolchain template file can be included with the project request. If these are not provided, an empty project is created.</p>
+    ///
+    /// See `CodeStar::create_project`.
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// <p>Creates a profile for a user that includes user preferences, such as the display name and email address assocciated with the user, in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar.</p>
+    ///
+    /// See `CodeStar::create_user_profile`.
+    pub fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.create_user_profile(input))
+    }
+
+    /// <p>Deletes a project, including project resources. Does not delete users associated with the project, but does delete the IAM roles that allowed access to the project.</p>
+    ///
+    /// See `CodeStar::delete_project`.
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// <p>Deletes a user profile in AWS CodeStar, including all personal preference data associated with that profile, such as display name and email address. It does not delete the history of that user, for example the history of commits made by that user.</p>
+    ///
+    /// See `CodeStar::delete_user_profile`.
+    pub fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_user_profile(input))
+    }
+
+    /// <p>Describes a project and its resources.</p>
+    ///
+    /// See `CodeStar::describe_project`.
+    pub fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+        self.runtime.block_on(self.client.describe_project(input))
+    }
+
+    /// <p>Describes a user in AWS CodeStar and the user attributes across all projects.</p>
+    ///
+    /// See `CodeStar::describe_user_profile`.
+    pub fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_user_profile(input))
+    }
+
+    /// <p>Removes a user from a project. Removing a user from a project also removes the IAM policies from that user that allowed access to the project and its resources. Disassociating a team member does not remove that user's profile from AWS CodeStar. It does not remove the user from IAM.</p>
+    ///
+    /// See `CodeStar::disassociate_team_member`.
+    pub fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
+        self.runtime
+            .block_on(self.client.disassociate_team_member(input))
+    }
+
+    /// <p>Lists all projects in AWS CodeStar associated with your AWS account.</p>
+    ///
+    /// See `CodeStar::list_projects`.
+    pub fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// <p>Lists resources associated with a project in AWS CodeStar.</p>
+    ///
+    /// See `CodeStar::list_resources`.
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// <p>Gets the tags for a project.</p>
+    ///
+    /// See `CodeStar::list_tags_for_project`.
+    pub fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_project(input))
+    }
+
+    /// <p>Lists all team members associated with a project.</p>
+    ///
+    /// See `CodeStar::list_team_members`.
+    pub fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
+        self.runtime.block_on(self.client.list_team_members(input))
+    }
+
+    /// <p>Lists all the user profiles configured for your AWS account in AWS CodeStar.</p>
+    ///
+    /// See `CodeStar::list_user_profiles`.
+    pub fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
+        self.runtime.block_on(self.client.list_user_profiles(input))
+    }
+
+    /// <p>Adds tags to a project.</p>
+    ///
+    /// See `CodeStar::tag_project`.
+    pub fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
+        self.runtime.block_on(self.client.tag_project(input))
+    }
+
+    /// <p>Removes tags from a project.</p>
+    ///
+    /// See `CodeStar::untag_project`.
+    pub fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
+        self.runtime.block_on(self.client.untag_project(input))
+    }
+
+    /// <p>Updates a project in AWS CodeStar.</p>
+    ///
+    /// See `CodeStar::update_project`.
+    pub fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+
+    /// <p>Updates a team member's attributes in an AWS CodeStar project. For example, you can change a team member's role in the project, or change whether they have remote access to project resources.</p>
+    ///
+    /// See `CodeStar::update_team_member`.
+    pub fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
+        self.runtime.block_on(self.client.update_team_member(input))
+    }
+
+    /// <p>Updates a user's profile in AWS CodeStar. The user profile is not project-specific. Information in the user profile is displayed wherever the user's information appears to other users in AWS CodeStar. </p>
+    ///
+    /// See `CodeStar::update_user_profile`.
+    pub fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_profile(input))
+    }
+}
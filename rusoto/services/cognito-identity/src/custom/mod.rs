@@ -1,10 +1,16 @@
 
 //! The Credentials provider from Cognito.
 
+use std::fmt;
+use std::sync::Arc;
+
 use rusoto_core::Region;
+use rusoto_core::request::{DispatchSignedRequest, HttpClient};
 use async_trait::async_trait;
+use futures::lock::Mutex;
 
 use rusoto_core::credential::{
+    clock,
     AwsCredentials, 
     CredentialsError,
     ProvideAwsCredentials,
@@ -13,6 +19,7 @@ use rusoto_core::credential::{
 
 use crate::generated::{
     GetCredentialsForIdentityInput,
+    GetIdInput,
     CognitoIdentityClient,
     CognitoIdentity
 };
@@ -20,6 +27,7 @@ use crate::generated::{
 use chrono::{
     offset::Utc,
     DateTime,
+    Duration,
     NaiveDateTime
 };
 
@@ -29,13 +37,17 @@ use std::collections::HashMap;
 ///
 /// For further information about Cognito Identities check https://docs.aws.amazon.com/cognito/index.html
 ///
+/// The provider either uses a known identity, or gets the identity of the user from an identity
+/// pool with `GetId` on the first call. Credentials are cached and reused until they are about to
+/// expire; clones of a provider share the same cache.
+///
 /// # Example
 ///
 /// ```rust
 /// use rusoto_core::Region;
 /// use rusoto_cognito_identity::CognitoProvider;
 /// let provider = CognitoProvider::builder()
-///     .identity_id("IDENTITY_POOL_ID".to_string())
+///     .identity_pool_id("eu-central-1:IDENTITY_POOL_GUID".to_string())
 ///     .region(Region::EuCentral1)
 ///     .login("graph.facebook.com".to_string(), "FBTOKEN".to_string())
 ///     .build();
@@ -43,35 +55,76 @@ use std::collections::HashMap;
 /// ```
 
 /// <p>The Cognito credential provider.</p>
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct CognitoProvider {
     /// <p>A unique identifier in the format REGION:GUID.</p>
-    identity_id: String,
+    identity_id: Option<String>,
+    /// <p>An identity pool ID in the format REGION:GUID.</p>
+    identity_pool_id: Option<String>,
+    /// <p>A standard AWS account ID (9+ digits).</p>
+    account_id: Option<String>,
     /// <p>The region of the identity pool.</p>
     region: Region,
     /// <p>A set of optional name-value pairs that map provider names to provider tokens. The name-value pair will follow the syntax "provider_name": "provider_user_identifier".</p> <p>Logins should not be specified when trying to get credentials for an unauthenticated identity.</p> <p>The Logins parameter is required when using identities associated with external identity providers such as FaceBook. For examples of <code>Logins</code> maps, see the code examples in the <a href="http://docs.aws.amazon.com/cognito/latest/developerguide/external-identity-providers.html">External Identity Providers</a> section of the Amazon Cognito Developer Guide.</p>
     logins: Option<HashMap<String, String>>,
     /// <p>The Amazon Resource Name (ARN) of the role to be assumed when multiple roles were received in the token from the identity provider. For example, a SAML-based identity provider. This parameter is optional for identity providers that do not support role customization.</p>
-    custom_role_arn: Option<String>
+    custom_role_arn: Option<String>,
+    /// <p>Cached credentials are refreshed when they expire within this window.</p>
+    refresh_window: Duration,
+    dispatcher: Option<Arc<dyn DispatchSignedRequest + Send + Sync>>,
+    cache: Arc<Mutex<CognitoCache>>,
+}
+
+/// The identity returned by `GetId` and the last credentials.
+#[derive(Default)]
+struct CognitoCache {
+    identity_id: Option<String>,
+    credentials: Option<AwsCredentials>,
+}
+
+impl fmt::Debug for CognitoProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CognitoProvider")
+            .field("identity_id", &self.identity_id)
+            .field("identity_pool_id", &self.identity_pool_id)
+            .field("account_id", &self.account_id)
+            .field("region", &self.region)
+            .field("logins", &self.logins.as_ref().map(|logins| logins.keys().collect::<Vec<_>>()))
+            .field("custom_role_arn", &self.custom_role_arn)
+            .field("refresh_window", &self.refresh_window)
+            .finish()
+    }
 }
 
 /// <p>A builder for the Cognito credential provider.</p>
 #[derive(Default)]
 pub struct CognitoProviderBuilder {
     identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    account_id: Option<String>,
     region: Option<Region>,
     logins: Option<HashMap<String, String>>,
-    custom_role_arn: Option<String>
+    custom_role_arn: Option<String>,
+    refresh_window: Option<Duration>,
+    dispatcher: Option<Arc<dyn DispatchSignedRequest + Send + Sync>>,
 }
 
 impl CognitoProviderBuilder {
     /// <p>Build the provider.</p>
     pub fn build(self) -> CognitoProvider { 
+        if self.identity_id.is_none() && self.identity_pool_id.is_none() {
+            panic!("no identity id or identity pool id provided");
+        }
         CognitoProvider {
-            identity_id: self.identity_id.expect("no identity id provided"),
+            identity_id: self.identity_id,
+            identity_pool_id: self.identity_pool_id,
+            account_id: self.account_id,
             region: self.region.unwrap_or(Region::default()),
             logins: self.logins,
-            custom_role_arn: self.custom_role_arn
+            custom_role_arn: self.custom_role_arn,
+            refresh_window: self.refresh_window.unwrap_or_else(|| Duration::minutes(5)),
+            dispatcher: self.dispatcher,
+            cache: Arc::new(Mutex::new(CognitoCache::default())),
         }
     }
 
@@ -81,6 +134,33 @@ impl CognitoProviderBuilder {
         self
     }
 
+    /// <p>Set the identity pool id, used to get the identity id with <code>GetId</code> if no identity id is set.</p>
+    pub fn identity_pool_id(mut self, identity_pool_id: String) -> Self {
+        self.identity_pool_id = Some(identity_pool_id);
+        self
+    }
+
+    /// <p>Set the account id passed to <code>GetId</code>.</p>
+    pub fn account_id(mut self, account_id: String) -> Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    /// <p>Set how long before their expiry cached credentials are refreshed. Defaults to 5 minutes.</p>
+    pub fn refresh_window(mut self, refresh_window: Duration) -> Self {
+        self.refresh_window = Some(refresh_window);
+        self
+    }
+
+    /// <p>Send requests to Cognito through the given dispatcher instead of a new <code>HttpClient</code>.</p>
+    pub fn dispatcher<D>(mut self, dispatcher: D) -> Self
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        self.dispatcher = Some(Arc::new(dispatcher));
+        self
+    }
+
     /// <p>Set the region.</p>
     pub fn region(mut self, region: Region)-> Self {
         self.region = Some(region);
@@ -108,19 +188,50 @@ impl CognitoProvider {
     pub fn builder() -> CognitoProviderBuilder {
         CognitoProviderBuilder::default()
     }
-}
 
-#[async_trait]
-impl ProvideAwsCredentials for CognitoProvider {
+    fn client(&self) -> Result<CognitoIdentityClient, CredentialsError> {
+        let credentials = StaticProvider::from(AwsCredentials::default());
+        match self.dispatcher {
+            Some(ref dispatcher) => Ok(CognitoIdentityClient::new_with(
+                dispatcher.clone(),
+                credentials,
+                self.region.clone(),
+            )),
+            None => Ok(CognitoIdentityClient::new_with(
+                HttpClient::new().map_err(|e| CredentialsError::new(format!("{:?}", e)))?,
+                credentials,
+                self.region.clone(),
+            )),
+        }
+    }
 
-    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let client = CognitoIdentityClient::new_with(
-            rusoto_core::request::HttpClient::new().map_err(|e| CredentialsError::new(format!("{:?}", e)))?,
-            StaticProvider::from(AwsCredentials::default()),
-            self.region.clone()
-        );
+    /// Determine whether cached credentials have to be refreshed.
+    fn needs_refresh(&self, credentials: &AwsCredentials) -> bool {
+        match credentials.expires_at() {
+            Some(ref expires_at) => *expires_at <= clock::now() + self.refresh_window,
+            None => false,
+        }
+    }
+
+    /// Calls <code>GetId</code> to get the identity id of the user from the identity pool.
+    async fn get_id(&self, client: &CognitoIdentityClient) -> Result<String, CredentialsError> {
+        let identity_pool_id = self.identity_pool_id.clone().ok_or(CredentialsError::new("no identity pool id provided"))?;
+        let input = GetIdInput {
+            account_id: self.account_id.clone(),
+            identity_pool_id,
+            logins: self.logins.clone(),
+        };
+        let resp = client.get_id(input).await.map_err(|e| CredentialsError::new(format!("{:?}", e)))?;
+        resp.identity_id.ok_or(CredentialsError::new("no identity id was found in the response"))
+    }
+
+    async fn get_credentials_for_identity(
+        &self,
+        client: &CognitoIdentityClient,
+        identity_id: String,
+    ) -> Result<AwsCredentials, CredentialsError> {
         let input = GetCredentialsForIdentityInput {
-            identity_id: self.identity_id.clone(),
+            identity_id,
             logins: self.logins.clone(),
             custom_role_arn: self.custom_role_arn.clone(),
             ..Default::default()
@@ -140,18 +251,43 @@ impl ProvideAwsCredentials for CognitoProvider {
     }
 }
 
+#[async_trait]
+impl ProvideAwsCredentials for CognitoProvider {
+
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cache = self.cache.lock().await;
+        if let Some(ref credentials) = cache.credentials {
+            if !self.needs_refresh(credentials) {
+                return Ok(credentials.clone());
+            }
+        }
+        let client = self.client()?;
+        let identity_id = match self.identity_id.clone().or_else(|| cache.identity_id.clone()) {
+            Some(identity_id) => identity_id,
+            None => {
+                let identity_id = self.get_id(&client).await?;
+                cache.identity_id = Some(identity_id.clone());
+                identity_id
+            }
+        };
+        let credentials = self.get_credentials_for_identity(&client, identity_id).await?;
+        cache.credentials = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
         
     #[test]
-    #[should_panic(expected = "no identity id provided")]
+    #[should_panic(expected = "no identity id or identity pool id provided")]
     fn builder_empty() {
         CognitoProvider::builder().build();
     }
 
     #[test]
-    #[should_panic(expected = "no identity id provided")]
+    #[should_panic(expected = "no identity id or identity pool id provided")]
     fn builder_no_identity_id() {
         CognitoProvider::builder()
         .login("provider".to_string(), "token".to_string())
@@ -161,7 +297,7 @@ mod tests {
     #[test]
     fn builder_simple() {
         let provider = CognitoProvider::builder().identity_id("id_id".to_string()).build();
-        assert_eq!(provider.identity_id, "id_id");
+        assert_eq!(provider.identity_id, Some("id_id".to_string()));
         assert_eq!(provider.region, Region::default());
         assert_eq!(provider.logins, None);
         assert_eq!(provider.custom_role_arn, None);
@@ -175,7 +311,7 @@ mod tests {
             .login("provider".to_string(), "token".to_string())
             .custom_role_arn("arn".to_string())
             .build();
-        assert_eq!(provider.identity_id, "id_id");
+        assert_eq!(provider.identity_id, Some("id_id".to_string()));
         assert_eq!(provider.region, Region::EuCentral1);
         assert!(provider.custom_role_arn.is_some());
         assert_eq!(provider.custom_role_arn.unwrap(), "arn");
@@ -194,7 +330,7 @@ mod tests {
             .login("provider1".to_string(), "token1".to_string())
             .login("provider2".to_string(), "token2".to_string())
             .build();
-        assert_eq!(provider.identity_id, "id_id");
+        assert_eq!(provider.identity_id, Some("id_id".to_string()));
         assert_eq!(provider.region, Region::EuCentral1);
         assert!(provider.logins.is_some());
         let logins = provider.logins.unwrap();
@@ -205,5 +341,43 @@ mod tests {
         assert_eq!(logins.get("provider2").unwrap(), "token2");
         assert_eq!(provider.custom_role_arn, None);
     }
-}
 
+    #[tokio::test]
+    async fn identity_pool_and_cache() {
+        use rusoto_mock::{MockRequestDispatcher, MultipleMockRequestDispatcher};
+
+        // a third request would panic: the identity and the credentials are cached
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body(r#"{"IdentityId": "eu-central-1:identity"}"#)
+                .with_request_checker(|req| {
+                    assert_eq!(req.headers.get("x-amz-target").unwrap()[0], b"AWSCognitoIdentityService.GetId".to_vec());
+                }),
+            MockRequestDispatcher::with_status(200)
+                .with_body(r#"{
+                    "IdentityId": "eu-central-1:identity",
+                    "Credentials": {
+                        "AccessKeyId": "access_key_id",
+                        "SecretKey": "secret_key",
+                        "SessionToken": "session_token",
+                        "Expiration": 4102444800
+                    }
+                }"#)
+                .with_request_checker(|req| {
+                    assert_eq!(req.headers.get("x-amz-target").unwrap()[0], b"AWSCognitoIdentityService.GetCredentialsForIdentity".to_vec());
+                }),
+        ]);
+        let provider = CognitoProvider::builder()
+            .identity_pool_id("eu-central-1:pool".to_string())
+            .region(Region::EuCentral1)
+            .dispatcher(dispatcher)
+            .build();
+
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "access_key_id");
+        assert_eq!(creds.aws_secret_access_key(), "secret_key");
+        assert!(creds.expires_at().is_some());
+        let creds = provider.clone().credentials().await.expect("cached credentials");
+        assert_eq!(creds.aws_access_key_id(), "access_key_id");
+    }
+}
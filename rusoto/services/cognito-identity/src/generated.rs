@@ -709,22 +709,40 @@ impl CreateIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityPoolError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityPoolError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityPoolError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityPoolError::ResourceConflict(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -763,13 +781,22 @@ impl DeleteIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentitiesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentitiesError::InvalidParameter(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentitiesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -809,19 +836,34 @@ impl DeleteIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityPoolError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityPoolError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityPoolError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -863,19 +905,34 @@ impl DescribeIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -917,25 +974,34 @@ impl DescribeIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -983,44 +1049,52 @@ impl GetCredentialsForIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ExternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::ExternalService(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::InternalError(err.msg),
+                    )
                 }
                 "InvalidIdentityPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCredentialsForIdentityError::InvalidIdentityPoolConfiguration(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCredentialsForIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1073,28 +1147,28 @@ impl GetIdError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetIdError::ExternalService(err.msg))
+                    return RusotoError::service(&res, GetIdError::ExternalService(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetIdError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetIdError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetIdError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIdError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetIdError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetIdError::NotAuthorized(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetIdError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, GetIdError::ResourceConflict(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIdError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetIdError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIdError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetIdError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1141,30 +1215,40 @@ impl GetIdentityPoolRolesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolRolesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolRolesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolRolesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolRolesError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolRolesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolRolesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1211,25 +1295,40 @@ impl GetOpenIdTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ExternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpenIdTokenError::ExternalService(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetOpenIdTokenError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpenIdTokenError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetOpenIdTokenError::NotAuthorized(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpenIdTokenError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpenIdTokenError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpenIdTokenError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1279,39 +1378,46 @@ impl GetOpenIdTokenForDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeveloperUserAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::DeveloperUserAlreadyRegistered(
                             err.msg,
                         ),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::InternalError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::InvalidParameter(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::NotAuthorized(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::ResourceConflict(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOpenIdTokenForDeveloperIdentityError::TooManyRequests(err.msg),
                     )
                 }
@@ -1371,19 +1477,28 @@ impl ListIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentitiesError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListIdentitiesError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentitiesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentitiesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentitiesError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListIdentitiesError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentitiesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentitiesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1425,19 +1540,34 @@ impl ListIdentityPoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1479,23 +1609,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1539,34 +1680,40 @@ impl LookupDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupDeveloperIdentityError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupDeveloperIdentityError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupDeveloperIdentityError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupDeveloperIdentityError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupDeveloperIdentityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LookupDeveloperIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1611,34 +1758,40 @@ impl MergeDeveloperIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeDeveloperIdentitiesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeDeveloperIdentitiesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeDeveloperIdentitiesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeDeveloperIdentitiesError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeDeveloperIdentitiesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MergeDeveloperIdentitiesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1685,35 +1838,46 @@ impl SetIdentityPoolRolesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolRolesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1757,19 +1921,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1813,34 +1977,40 @@ impl UnlinkDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkDeveloperIdentityError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkDeveloperIdentityError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkDeveloperIdentityError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkDeveloperIdentityError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkDeveloperIdentityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkDeveloperIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1887,25 +2057,40 @@ impl UnlinkIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ExternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkIdentityError::ExternalService(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnlinkIdentityError::InternalError(err.msg))
+                    return RusotoError::service(&res, UnlinkIdentityError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnlinkIdentityError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkIdentityError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnlinkIdentityError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UnlinkIdentityError::NotAuthorized(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkIdentityError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkIdentityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnlinkIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnlinkIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1949,19 +2134,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2009,30 +2200,52 @@ impl UpdateIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2204,6 +2417,7 @@ impl CognitoIdentityClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2219,6 +2433,7 @@ impl CognitoIdentityClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoIdentityClient {
         CognitoIdentityClient { client, region }
     }
@@ -2232,6 +2447,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: CreateIdentityPoolInput,
     ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateIdentityPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.CreateIdentityPool",
@@ -2253,6 +2471,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DeleteIdentitiesInput,
     ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteIdentities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DeleteIdentities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2271,6 +2492,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DeleteIdentityPoolInput,
     ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteIdentityPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.DeleteIdentityPool",
@@ -2291,6 +2515,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DescribeIdentityInput,
     ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeIdentity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DescribeIdentity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2309,6 +2536,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DescribeIdentityPoolInput,
     ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeIdentityPool");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.DescribeIdentityPool",
@@ -2331,6 +2561,9 @@ impl CognitoIdentity for CognitoIdentityClient {
     ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCredentialsForIdentity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.GetCredentialsForIdentity",
@@ -2350,6 +2583,9 @@ impl CognitoIdentity for CognitoIdentityClient {
     /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
     async fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetId");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetId");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2368,6 +2604,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: GetIdentityPoolRolesInput,
     ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetIdentityPoolRoles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.GetIdentityPoolRoles",
@@ -2390,6 +2629,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: GetOpenIdTokenInput,
     ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOpenIdToken");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetOpenIdToken");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2411,6 +2653,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOpenIdTokenForDeveloperIdentity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.GetOpenIdTokenForDeveloperIdentity",
@@ -2436,6 +2681,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListIdentitiesInput,
     ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListIdentities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.ListIdentities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2454,6 +2702,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListIdentityPoolsInput,
     ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListIdentityPools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.ListIdentityPools",
@@ -2475,6 +2726,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.ListTagsForResource",
@@ -2496,6 +2750,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: LookupDeveloperIdentityInput,
     ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("LookupDeveloperIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.LookupDeveloperIdentity",
@@ -2518,6 +2775,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: MergeDeveloperIdentitiesInput,
     ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergeDeveloperIdentities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.MergeDeveloperIdentities",
@@ -2540,6 +2800,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: SetIdentityPoolRolesInput,
     ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetIdentityPoolRoles");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.SetIdentityPoolRoles",
@@ -2560,6 +2823,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: TagResourceInput,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2578,6 +2844,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UnlinkDeveloperIdentityInput,
     ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnlinkDeveloperIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.UnlinkDeveloperIdentity",
@@ -2598,6 +2867,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UnlinkIdentityInput,
     ) -> Result<(), RusotoError<UnlinkIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnlinkIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UnlinkIdentity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2615,6 +2887,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2633,6 +2908,9 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: IdentityPool,
     ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateIdentityPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.UpdateIdentityPool",
@@ -2648,3 +2926,280 @@ impl CognitoIdentity for CognitoIdentityClient {
         proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
     }
 }
+
+/// A blocking client for the Amazon Cognito Identity API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CognitoIdentityClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CognitoIdentityBlockingClient {
+    client: CognitoIdentityClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CognitoIdentityBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CognitoIdentityBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CognitoIdentityBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoIdentityBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CognitoIdentityBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient {
+            client: CognitoIdentityClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new identity pool. The identity pool is a store of user identity information that is specific to your AWS account. The keys for <code>SupportedLoginProviders</code> are as follows:</p> <ul> <li> <p>Facebook: <code>graph.facebook.com</code> </p> </li> <li> <p>Google: <code>accounts.google.com</code> </p> </li> <li> <p>Amazon: <code>www.amazon.com</code> </p> </li> <li> <p>Twitter: <code>api.twitter.com</code> </p> </li> <li> <p>Digits: <code>www.digits.com</code> </p> </li> </ul> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::create_identity_pool`.
+    pub fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.create_identity_pool(input))
+    }
+
+    /// <p>Deletes identities from an identity pool. You can specify a list of 1-60 identities that you want to delete.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::delete_identities`.
+    pub fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
+        self.runtime.block_on(self.client.delete_identities(input))
+    }
+
+    /// <p>Deletes an identity pool. Once a pool is deleted, users will not be able to authenticate with the pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::delete_identity_pool`.
+    pub fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.delete_identity_pool(input))
+    }
+
+    /// <p>Returns metadata related to the given identity, including when the identity was created and any associated linked logins.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::describe_identity`.
+    pub fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
+        self.runtime.block_on(self.client.describe_identity(input))
+    }
+
+    /// <p>Gets details about a particular identity pool, including the pool name, ID description, creation date, and current number of users.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::describe_identity_pool`.
+    pub fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_pool(input))
+    }
+
+    /// <p>Returns credentials for the provided identity ID. Any provided logins will be validated against supported login providers. If the token is for cognito-identity.amazonaws.com, it will be passed through to AWS Security Token Service with the appropriate role for the token.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::get_credentials_for_identity`.
+    pub fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
+    {
+        self.runtime
+            .block_on(self.client.get_credentials_for_identity(input))
+    }
+
+    /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::get_id`.
+    pub fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
+        self.runtime.block_on(self.client.get_id(input))
+    }
+
+    /// <p>Gets the roles for an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::get_identity_pool_roles`.
+    pub fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
+        self.runtime
+            .block_on(self.client.get_identity_pool_roles(input))
+    }
+
+    /// <p>Gets an OpenID token, using a known Cognito ID. This known Cognito ID is returned by <a>GetId</a>. You can optionally add additional logins for the identity. Supplying multiple logins creates an implicit link.</p> <p>The OpenId token is valid for 10 minutes.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::get_open_id_token`.
+    pub fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
+        self.runtime.block_on(self.client.get_open_id_token(input))
+    }
+
+    /// <p>Registers (or retrieves) a Cognito <code>IdentityId</code> and an OpenID Connect token for a user authenticated by your backend authentication process. Supplying multiple logins will create an implicit linked account. You can only specify one developer provider as part of the <code>Logins</code> map, which is linked to the identity pool. The developer provider is the "domain" by which Cognito will refer to your users.</p> <p>You can use <code>GetOpenIdTokenForDeveloperIdentity</code> to create a new identity and to link new logins (that is, user credentials issued by a public provider or developer provider) to an existing identity. When you want to create a new identity, the <code>IdentityId</code> should be null. When you want to associate a new login with an existing authenticated/unauthenticated identity, you can do so by providing the existing <code>IdentityId</code>. This API will create the identity in the specified <code>IdentityPoolId</code>.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::get_open_id_token_for_developer_identity`.
+    pub fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> Result<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_open_id_token_for_developer_identity(input))
+    }
+
+    /// <p>Lists the identities in an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::list_identities`.
+    pub fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        self.runtime.block_on(self.client.list_identities(input))
+    }
+
+    /// <p>Lists all of the Cognito identity pools registered for your account.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::list_identity_pools`.
+    pub fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
+        self.runtime
+            .block_on(self.client.list_identity_pools(input))
+    }
+
+    /// <p>Lists the tags that are assigned to an Amazon Cognito identity pool.</p> <p>A tag is a label that you can apply to identity pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    ///
+    /// See `CognitoIdentity::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Retrieves the <code>IdentityID</code> associated with a <code>DeveloperUserIdentifier</code> or the list of <code>DeveloperUserIdentifier</code> values associated with an <code>IdentityId</code> for an existing identity. Either <code>IdentityID</code> or <code>DeveloperUserIdentifier</code> must not be null. If you supply only one of these values, the other value will be searched in the database and returned as a part of the response. If you supply both, <code>DeveloperUserIdentifier</code> will be matched against <code>IdentityID</code>. If the values are verified against the database, the response returns both values and is the same as the request. Otherwise a <code>ResourceConflictException</code> is thrown.</p> <p> <code>LookupDeveloperIdentity</code> is intended for low-throughput control plane operations: for example, to enable customer service to locate an identity ID by username. If you are using it for higher-volume operations such as user authentication, your requests are likely to be throttled. <a>GetOpenIdTokenForDeveloperIdentity</a> is a better option for higher-volume operations for user authentication.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::lookup_developer_identity`.
+    pub fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
+        self.runtime
+            .block_on(self.client.lookup_developer_identity(input))
+    }
+
+    /// <p>Merges two users having different <code>IdentityId</code>s, existing in the same identity pool, and identified by the same developer provider. You can use this action to request that discrete users be merged and identified as a single user in the Cognito environment. Cognito associates the given source user (<code>SourceUserIdentifier</code>) with the <code>IdentityId</code> of the <code>DestinationUserIdentifier</code>. Only developer-authenticated users can be merged. If the users to be merged are associated with the same public provider, but as two different users, an exception will be thrown.</p> <p>The number of linked logins is limited to 20. So, the number of linked logins for the source user, <code>SourceUserIdentifier</code>, and the destination user, <code>DestinationUserIdentifier</code>, together should not be larger than 20. Otherwise, an exception will be thrown.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::merge_developer_identities`.
+    pub fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
+        self.runtime
+            .block_on(self.client.merge_developer_identities(input))
+    }
+
+    /// <p>Sets the roles for an identity pool. These roles are used when making calls to <a>GetCredentialsForIdentity</a> action.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::set_identity_pool_roles`.
+    pub fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
+        self.runtime
+            .block_on(self.client.set_identity_pool_roles(input))
+    }
+
+    /// <p>Assigns a set of tags to an Amazon Cognito identity pool. A tag is a label that you can use to categorize and manage identity pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of an identity pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both identity pools. The value of this key might be <code>Test</code> for one identity pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your identity pools. In an IAM policy, you can constrain permissions for identity pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. An identity pool can have as many as 50 tags.</p>
+    ///
+    /// See `CognitoIdentity::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Unlinks a <code>DeveloperUserIdentifier</code> from an existing identity. Unlinked developer users will be considered new identities next time they are seen. If, for a given Cognito identity, you remove all federated identities as well as the developer user identifier, the Cognito identity becomes inaccessible.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::unlink_developer_identity`.
+    pub fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
+        self.runtime
+            .block_on(self.client.unlink_developer_identity(input))
+    }
+
+    /// <p>Unlinks a federated identity from an existing account. Unlinked logins will be considered new identities next time they are seen. Removing the last linked login will make this identity inaccessible.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::unlink_identity`.
+    pub fn unlink_identity(
+        &self,
+        input: UnlinkIdentityInput,
+    ) -> Result<(), RusotoError<UnlinkIdentityError>> {
+        self.runtime.block_on(self.client.unlink_identity(input))
+    }
+
+    /// <p>Removes the specified tags from an Amazon Cognito identity pool. You can use this action up to 5 times per second, per account</p>
+    ///
+    /// See `CognitoIdentity::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an identity pool.</p> <p>You must use AWS Developer credentials to call this API.</p>
+    ///
+    /// See `CognitoIdentity::update_identity_pool`.
+    pub fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.update_identity_pool(input))
+    }
+}
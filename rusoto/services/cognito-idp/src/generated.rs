@@ -4212,28 +4212,40 @@ impl AddCustomAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AddCustomAttributesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddCustomAttributesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddCustomAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddCustomAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AddCustomAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddCustomAttributesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddCustomAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddCustomAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddCustomAttributesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddCustomAttributesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(AddCustomAttributesError::UserImportInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddCustomAttributesError::UserImportInProgress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4278,26 +4290,40 @@ impl AdminAddUserToGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminAddUserToGroupError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminAddUserToGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminAddUserToGroupError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminAddUserToGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminAddUserToGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminAddUserToGroupError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4352,43 +4378,70 @@ impl AdminConfirmSignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::InternalError(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::TooManyFailedAttempts(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::TooManyFailedAttempts(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminConfirmSignUpError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4458,62 +4511,91 @@ impl AdminCreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(AdminCreateUserError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::CodeDeliveryFailure(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminCreateUserError::InternalError(err.msg))
+                    return RusotoError::service(&res, AdminCreateUserError::InternalError(err.msg))
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::InvalidPassword(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::InvalidSmsRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminCreateUserError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminCreateUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, AdminCreateUserError::NotAuthorized(err.msg))
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(AdminCreateUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminCreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminCreateUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminCreateUserError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UnsupportedUserStateException" => {
-                    return RusotoError::Service(AdminCreateUserError::UnsupportedUserState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::UnsupportedUserState(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminCreateUserError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminCreateUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, AdminCreateUserError::UserNotFound(err.msg))
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(AdminCreateUserError::UsernameExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminCreateUserError::UsernameExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4570,22 +4652,31 @@ impl AdminDeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDeleteUserError::InternalError(err.msg))
+                    return RusotoError::service(&res, AdminDeleteUserError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDeleteUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, AdminDeleteUserError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDeleteUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, AdminDeleteUserError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4630,34 +4721,40 @@ impl AdminDeleteUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserAttributesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserAttributesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserAttributesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDeleteUserAttributesError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4706,39 +4803,46 @@ impl AdminDisableProviderForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableProviderForUserError::AliasExists(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableProviderForUserError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminDisableProviderForUserError::InvalidParameter(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableProviderForUserError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminDisableProviderForUserError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableProviderForUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableProviderForUserError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4784,22 +4888,37 @@ impl AdminDisableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDisableUserError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableUserError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDisableUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableUserError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDisableUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableUserError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDisableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableUserError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDisableUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminDisableUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDisableUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, AdminDisableUserError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4844,22 +4963,31 @@ impl AdminEnableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminEnableUserError::InternalError(err.msg))
+                    return RusotoError::service(&res, AdminEnableUserError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminEnableUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminEnableUserError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminEnableUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, AdminEnableUserError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminEnableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminEnableUserError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminEnableUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminEnableUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminEnableUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, AdminEnableUserError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4906,27 +5034,46 @@ impl AdminForgetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminForgetDeviceError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminForgetDeviceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminForgetDeviceError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminForgetDeviceError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminForgetDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminForgetDeviceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminForgetDeviceError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4974,24 +5121,34 @@ impl AdminGetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(&res, AdminGetDeviceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminGetDeviceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminGetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminGetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, AdminGetDeviceError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminGetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminGetDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminGetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminGetDeviceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5036,22 +5193,22 @@ impl AdminGetUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminGetUserError::InternalError(err.msg))
+                    return RusotoError::service(&res, AdminGetUserError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminGetUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, AdminGetUserError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminGetUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, AdminGetUserError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminGetUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AdminGetUserError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminGetUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, AdminGetUserError::TooManyRequests(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminGetUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, AdminGetUserError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5114,61 +5271,94 @@ impl AdminInitiateAuthError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::InternalError(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminInitiateAuthError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminInitiateAuthError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminInitiateAuthError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::MFAMethodNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::MFAMethodNotFound(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminInitiateAuthError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5230,44 +5420,52 @@ impl AdminLinkProviderForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::AliasExists(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminLinkProviderForUserError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5314,24 +5512,40 @@ impl AdminListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListDevicesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminListDevicesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListDevicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminListDevicesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminListDevicesError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListDevicesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminListDevicesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminListDevicesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminListDevicesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5378,32 +5592,40 @@ impl AdminListGroupsForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListGroupsForUserError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListGroupsForUserError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListGroupsForUserError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListGroupsForUserError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListGroupsForUserError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminListGroupsForUserError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5450,37 +5672,44 @@ impl AdminListUserAuthEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListUserAuthEventsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListUserAuthEventsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListUserAuthEventsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListUserAuthEventsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListUserAuthEventsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminListUserAuthEventsError::UserNotFound(err.msg),
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminListUserAuthEventsError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
@@ -5530,34 +5759,40 @@ impl AdminRemoveUserFromGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRemoveUserFromGroupError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRemoveUserFromGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRemoveUserFromGroupError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRemoveUserFromGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRemoveUserFromGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRemoveUserFromGroupError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5616,67 +5851,82 @@ impl AdminResetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::InternalError(err.msg),
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminResetUserPasswordError::InvalidEmailRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminResetUserPasswordError::InvalidLambdaResponse(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminResetUserPasswordError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminResetUserPasswordError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminResetUserPasswordError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5764,104 +6014,124 @@ impl AdminRespondToAuthChallengeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::AliasExists(err.msg),
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::CodeMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::CodeMismatch(err.msg),
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::ExpiredCode(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::ExpiredCode(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::InternalError(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::InvalidLambdaResponse(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::InvalidPassword(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::MFAMethodNotFound(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::PasswordResetRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::ResourceNotFound(err.msg),
                     )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::SoftwareTokenMFANotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::UnexpectedLambda(err.msg),
                     )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::UserLambdaValidation(err.msg),
                     )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminRespondToAuthChallengeError::UserNotConfirmed(err.msg),
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminRespondToAuthChallengeError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5938,39 +6208,46 @@ impl AdminSetUserMFAPreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserMFAPreferenceError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserMFAPreferenceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserMFAPreferenceError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminSetUserMFAPreferenceError::PasswordResetRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserMFAPreferenceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserMFAPreferenceError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserMFAPreferenceError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6020,33 +6297,46 @@ impl AdminSetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::InvalidPassword(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserPasswordError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6090,23 +6380,34 @@ impl AdminSetUserSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserSettingsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserSettingsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserSettingsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserSettingsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminSetUserSettingsError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6154,37 +6455,44 @@ impl AdminUpdateAuthEventFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateAuthEventFeedbackError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateAuthEventFeedbackError::InvalidParameter(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateAuthEventFeedbackError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateAuthEventFeedbackError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateAuthEventFeedbackError::TooManyRequests(err.msg),
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateAuthEventFeedbackError::UserNotFound(err.msg),
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
@@ -6240,39 +6548,46 @@ impl AdminUpdateDeviceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateDeviceStatusError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateDeviceStatusError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateDeviceStatusError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateDeviceStatusError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateDeviceStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateDeviceStatusError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateDeviceStatusError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6334,69 +6649,82 @@ impl AdminUpdateUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::AliasExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::AliasExists(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateUserAttributesError::InvalidEmailRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateUserAttributesError::InvalidLambdaResponse(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateUserAttributesError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateUserAttributesError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AdminUpdateUserAttributesError::UserLambdaValidation(err.msg),
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUpdateUserAttributesError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6458,32 +6786,40 @@ impl AdminUserGlobalSignOutError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUserGlobalSignOutError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUserGlobalSignOutError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUserGlobalSignOutError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUserGlobalSignOutError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdminUserGlobalSignOutError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdminUserGlobalSignOutError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6526,27 +6862,32 @@ impl AssociateSoftwareTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSoftwareTokenError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSoftwareTokenError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSoftwareTokenError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateSoftwareTokenError::ResourceNotFound(err.msg),
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateSoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
                     )
                 }
@@ -6602,36 +6943,52 @@ impl ChangePasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ChangePasswordError::InternalError(err.msg))
+                    return RusotoError::service(&res, ChangePasswordError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ChangePasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ChangePasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ChangePasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ChangePasswordError::InvalidPassword(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ChangePasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ChangePasswordError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ChangePasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ChangePasswordError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ChangePasswordError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ChangePasswordError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ChangePasswordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ChangePasswordError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ChangePasswordError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ChangePasswordError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ChangePasswordError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ChangePasswordError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ChangePasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, ChangePasswordError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6692,42 +7049,58 @@ impl ConfirmDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InternalError(err.msg))
+                    return RusotoError::service(&res, ConfirmDeviceError::InternalError(err.msg))
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmDeviceError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmDeviceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidPassword(err.msg))
+                    return RusotoError::service(&res, ConfirmDeviceError::InvalidPassword(err.msg))
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmDeviceError::InvalidUserPoolConfiguration(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ConfirmDeviceError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ConfirmDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmDeviceError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ConfirmDeviceError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmDeviceError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, ConfirmDeviceError::UserNotFound(err.msg))
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UsernameExists(err.msg))
+                    return RusotoError::service(&res, ConfirmDeviceError::UsernameExists(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6796,67 +7169,94 @@ impl ConfirmForgotPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::CodeMismatch(err.msg),
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::ExpiredCode(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::InternalError(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::InvalidPassword(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::TooManyFailedAttempts(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::TooManyFailedAttempts(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmForgotPasswordError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6926,46 +7326,64 @@ impl ConfirmSignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::AliasExists(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::AliasExists(err.msg))
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(ConfirmSignUpError::CodeMismatch(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::CodeMismatch(err.msg))
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(ConfirmSignUpError::ExpiredCode(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::ExpiredCode(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InternalError(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::InternalError(err.msg))
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmSignUpError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmSignUpError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ConfirmSignUpError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmSignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmSignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmSignUpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::TooManyFailedAttempts(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmSignUpError::TooManyFailedAttempts(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::TooManyRequests(err.msg))
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmSignUpError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmSignUpError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, ConfirmSignUpError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7020,25 +7438,25 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GroupExistsException" => {
-                    return RusotoError::Service(CreateGroupError::GroupExists(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::GroupExists(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateGroupError::InternalError(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7086,39 +7504,46 @@ impl CreateIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateProviderException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::DuplicateProvider(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::DuplicateProvider(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIdentityProviderError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7164,28 +7589,40 @@ impl CreateResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateResourceServerError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceServerError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceServerError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceServerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceServerError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceServerError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceServerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceServerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7232,31 +7669,46 @@ impl CreateUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserImportJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserImportJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::NotAuthorized(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateUserImportJobError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserImportJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7308,37 +7760,49 @@ impl CreateUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(&res, CreateUserPoolError::InternalError(err.msg))
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidEmailRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolError::InvalidEmailRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolError::InvalidSmsRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUserPoolError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateUserPoolError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, CreateUserPoolError::NotAuthorized(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(CreateUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolError::UserPoolTagging(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7392,38 +7856,52 @@ impl CreateUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOAuthFlowException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InvalidOAuthFlow(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::InvalidOAuthFlow(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ScopeDoesNotExistException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::ScopeDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::ScopeDoesNotExist(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolClientError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7468,23 +7946,34 @@ impl CreateUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolDomainError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolDomainError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolDomainError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolDomainError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserPoolDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7526,19 +8015,19 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7582,32 +8071,38 @@ impl DeleteIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityProviderError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityProviderError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityProviderError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityProviderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIdentityProviderError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedIdentityProviderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteIdentityProviderError::UnsupportedIdentityProvider(err.msg),
                     )
                 }
@@ -7654,25 +8149,34 @@ impl DeleteResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteResourceServerError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceServerError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceServerError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceServerError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceServerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceServerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7720,28 +8224,31 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(DeleteUserError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(DeleteUserError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::UserNotConfirmed(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7792,38 +8299,52 @@ impl DeleteUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserAttributesError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7870,22 +8391,34 @@ impl DeleteUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeleteUserPoolError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DeleteUserPoolError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(DeleteUserPoolError::UserImportInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolError::UserImportInProgress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7928,25 +8461,34 @@ impl DeleteUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolClientError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolClientError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolClientError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolClientError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolClientError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7986,20 +8528,28 @@ impl DeleteUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolDomainError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolDomainError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolDomainError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserPoolDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8040,29 +8590,34 @@ impl DescribeIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityProviderError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityProviderError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityProviderError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityProviderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityProviderError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8104,29 +8659,34 @@ impl DescribeResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeResourceServerError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceServerError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceServerError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeResourceServerError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceServerError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceServerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceServerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8170,32 +8730,38 @@ impl DescribeRiskConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRiskConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRiskConfigurationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRiskConfigurationError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRiskConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRiskConfigurationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
@@ -8242,25 +8808,34 @@ impl DescribeUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserImportJobError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserImportJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserImportJobError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserImportJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8304,22 +8879,40 @@ impl DescribeUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(DescribeUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolError::UserPoolTagging(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8362,29 +8955,34 @@ impl DescribeUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolClientError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolClientError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolClientError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolClientError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolClientError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8424,24 +9022,28 @@ impl DescribeUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolDomainError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolDomainError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolDomainError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserPoolDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8490,33 +9092,37 @@ impl ForgetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ForgetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ForgetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::InvalidParameter(err.msg))
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ForgetDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ForgetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ForgetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ForgetDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgetDeviceError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ForgetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ForgetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ForgetDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::UserNotConfirmed(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ForgetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, ForgetDeviceError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8582,57 +9188,82 @@ impl ForgotPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(ForgotPasswordError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::CodeDeliveryFailure(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ForgotPasswordError::InternalError(err.msg))
+                    return RusotoError::service(&res, ForgotPasswordError::InternalError(err.msg))
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidEmailRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::InvalidEmailRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::InvalidSmsRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ForgotPasswordError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ForgotPasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ForgotPasswordError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ForgotPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ForgotPasswordError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ForgotPasswordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ForgotPasswordError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ForgotPasswordError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ForgotPasswordError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, ForgotPasswordError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8686,19 +9317,19 @@ impl GetCSVHeaderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCSVHeaderError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetCSVHeaderError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCSVHeaderError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetCSVHeaderError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCSVHeaderError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetCSVHeaderError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCSVHeaderError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetCSVHeaderError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCSVHeaderError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetCSVHeaderError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8748,33 +9379,37 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetDeviceError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::InvalidParameter(err.msg))
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(GetDeviceError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GetDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GetDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::UserNotConfirmed(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8820,19 +9455,19 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetGroupError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetGroupError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetGroupError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetGroupError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetGroupError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8876,27 +9511,32 @@ impl GetIdentityProviderByIdentifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityProviderByIdentifierError::InternalError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityProviderByIdentifierError::InvalidParameter(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityProviderByIdentifierError::NotAuthorized(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityProviderByIdentifierError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityProviderByIdentifierError::TooManyRequests(err.msg),
                     )
                 }
@@ -8946,17 +9586,22 @@ impl GetSigningCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetSigningCertificateError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningCertificateError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSigningCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8996,19 +9641,34 @@ impl GetUICustomizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUICustomizationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUICustomizationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUICustomizationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUICustomizationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUICustomizationError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUICustomizationError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUICustomizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUICustomizationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUICustomizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUICustomizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9056,28 +9716,28 @@ impl GetUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUserError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetUserError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetUserError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUserError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetUserError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GetUserError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(&res, GetUserError::PasswordResetRequired(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetUserError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetUserError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GetUserError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(&res, GetUserError::UserNotConfirmed(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetUserError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, GetUserError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9146,86 +9806,102 @@ impl GetUserAttributeVerificationCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::CodeDeliveryFailure(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::InternalError(err.msg),
                     )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::InvalidEmailRoleAccessPolicy(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::InvalidLambdaResponse(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::InvalidSmsRoleTrustRelationship(
                             err.msg,
                         ),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::LimitExceeded(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::NotAuthorized(err.msg),
                     )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::PasswordResetRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::TooManyRequests(err.msg),
                     )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::UnexpectedLambda(err.msg),
                     )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::UserLambdaValidation(err.msg),
                     )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::UserNotConfirmed(err.msg),
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetUserAttributeVerificationCodeError::UserNotFound(err.msg),
                     )
                 }
@@ -9312,25 +9988,34 @@ impl GetUserPoolMfaConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserPoolMfaConfigError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserPoolMfaConfigError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserPoolMfaConfigError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserPoolMfaConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserPoolMfaConfigError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9376,25 +10061,37 @@ impl GlobalSignOutError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GlobalSignOutError::InternalError(err.msg))
+                    return RusotoError::service(&res, GlobalSignOutError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GlobalSignOutError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GlobalSignOutError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GlobalSignOutError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GlobalSignOutError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GlobalSignOutError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GlobalSignOutError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GlobalSignOutError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GlobalSignOutError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GlobalSignOutError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GlobalSignOutError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GlobalSignOutError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GlobalSignOutError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9456,52 +10153,64 @@ impl InitiateAuthError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(InitiateAuthError::InternalError(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::InternalError(err.msg))
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateAuthError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::InvalidParameter(err.msg))
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateAuthError::InvalidSmsRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateAuthError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateAuthError::InvalidUserPoolConfiguration(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(InitiateAuthError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(InitiateAuthError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateAuthError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateAuthError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InitiateAuthError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::TooManyRequests(err.msg))
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(InitiateAuthError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::UnexpectedLambda(err.msg))
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(InitiateAuthError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateAuthError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(InitiateAuthError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::UserNotConfirmed(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(InitiateAuthError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, InitiateAuthError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9560,33 +10269,37 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListDevicesError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::InvalidParameter(err.msg))
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidUserPoolConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDevicesError::InvalidUserPoolConfiguration(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListDevicesError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ListDevicesError::PasswordResetRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevicesError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::TooManyRequests(err.msg))
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ListDevicesError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::UserNotConfirmed(err.msg))
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9632,19 +10345,19 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListGroupsError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListGroupsError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9686,25 +10399,34 @@ impl ListIdentityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityProvidersError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityProvidersError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityProvidersError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityProvidersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityProvidersError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9746,23 +10468,34 @@ impl ListResourceServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListResourceServersError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceServersError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceServersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceServersError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListResourceServersError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceServersError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListResourceServersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceServersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListResourceServersError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceServersError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9804,23 +10537,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9862,19 +10606,34 @@ impl ListUserImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserImportJobsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserImportJobsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserImportJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserImportJobsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserImportJobsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserImportJobsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserImportJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserImportJobsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserImportJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserImportJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9916,23 +10675,34 @@ impl ListUserPoolClientsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserPoolClientsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserPoolClientsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserPoolClientsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserPoolClientsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserPoolClientsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9972,16 +10742,19 @@ impl ListUserPoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserPoolsError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListUserPoolsError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserPoolsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserPoolsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserPoolsError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListUserPoolsError::NotAuthorized(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserPoolsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListUserPoolsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10022,19 +10795,19 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUsersError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUsersError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListUsersError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUsersError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListUsersError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10076,19 +10849,34 @@ impl ListUsersInGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUsersInGroupError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersInGroupError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersInGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersInGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUsersInGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersInGroupError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersInGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersInGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUsersInGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersInGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10148,72 +10936,88 @@ impl ResendConfirmationCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::CodeDeliveryFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::CodeDeliveryFailure(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::InternalError(err.msg),
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendConfirmationCodeError::InvalidEmailRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendConfirmationCodeError::InvalidLambdaResponse(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendConfirmationCodeError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendConfirmationCodeError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResendConfirmationCodeError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10300,96 +11104,124 @@ impl RespondToAuthChallengeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::AliasExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::AliasExists(err.msg),
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::CodeMismatch(err.msg),
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::ExpiredCode(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::InternalError(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondToAuthChallengeError::InvalidLambdaResponse(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InvalidPassword(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::InvalidPassword(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondToAuthChallengeError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::MFAMethodNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::MFAMethodNotFound(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondToAuthChallengeError::PasswordResetRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondToAuthChallengeError::SoftwareTokenMFANotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RespondToAuthChallengeError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10460,38 +11292,50 @@ impl SetRiskConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::CodeDeliveryFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetRiskConfigurationError::CodeDeliveryFailure(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetRiskConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetRiskConfigurationError::InvalidEmailRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetRiskConfigurationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetRiskConfigurationError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetRiskConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetRiskConfigurationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
@@ -10540,19 +11384,34 @@ impl SetUICustomizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUICustomizationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUICustomizationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUICustomizationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUICustomizationError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUICustomizationError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUICustomizationError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUICustomizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUICustomizationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetUICustomizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUICustomizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10598,33 +11457,46 @@ impl SetUserMFAPreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserMFAPreferenceError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10672,35 +11544,46 @@ impl SetUserPoolMfaConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserPoolMfaConfigError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserPoolMfaConfigError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetUserPoolMfaConfigError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetUserPoolMfaConfigError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserPoolMfaConfigError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserPoolMfaConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserPoolMfaConfigError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10752,27 +11635,37 @@ impl SetUserSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserSettingsError::InternalError(err.msg))
+                    return RusotoError::service(&res, SetUserSettingsError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserSettingsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserSettingsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserSettingsError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, SetUserSettingsError::NotAuthorized(err.msg))
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(SetUserSettingsError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetUserSettingsError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserSettingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserSettingsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(SetUserSettingsError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetUserSettingsError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(SetUserSettingsError::UserNotFound(err.msg))
+                    return RusotoError::service(&res, SetUserSettingsError::UserNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10834,48 +11727,55 @@ impl SignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(SignUpError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service(&res, SignUpError::CodeDeliveryFailure(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SignUpError::InternalError(err.msg))
+                    return RusotoError::service(&res, SignUpError::InternalError(err.msg))
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(SignUpError::InvalidEmailRoleAccessPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SignUpError::InvalidEmailRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(SignUpError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service(&res, SignUpError::InvalidLambdaResponse(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, SignUpError::InvalidParameter(err.msg))
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(SignUpError::InvalidPassword(err.msg))
+                    return RusotoError::service(&res, SignUpError::InvalidPassword(err.msg))
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(SignUpError::InvalidSmsRoleAccessPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SignUpError::InvalidSmsRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(SignUpError::InvalidSmsRoleTrustRelationship(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SignUpError::InvalidSmsRoleTrustRelationship(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, SignUpError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SignUpError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, SignUpError::TooManyRequests(err.msg))
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(SignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service(&res, SignUpError::UnexpectedLambda(err.msg))
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(SignUpError::UserLambdaValidation(err.msg))
+                    return RusotoError::service(&res, SignUpError::UserLambdaValidation(err.msg))
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(SignUpError::UsernameExists(err.msg))
+                    return RusotoError::service(&res, SignUpError::UsernameExists(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10928,24 +11828,40 @@ impl StartUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(StartUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartUserImportJobError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartUserImportJobError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartUserImportJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(StartUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartUserImportJobError::NotAuthorized(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(StartUserImportJobError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartUserImportJobError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartUserImportJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartUserImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartUserImportJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10990,24 +11906,40 @@ impl StopUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(StopUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopUserImportJobError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopUserImportJobError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopUserImportJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(StopUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopUserImportJobError::NotAuthorized(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(StopUserImportJobError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopUserImportJobError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopUserImportJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopUserImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopUserImportJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11050,19 +11982,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11104,19 +12036,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11162,37 +12100,44 @@ impl UpdateAuthEventFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthEventFeedbackError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthEventFeedbackError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthEventFeedbackError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthEventFeedbackError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthEventFeedbackError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::UserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthEventFeedbackError::UserNotFound(err.msg),
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
                     )
                 }
@@ -11248,35 +12193,58 @@ impl UpdateDeviceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDeviceStatusError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::UserNotConfirmed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStatusError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11324,19 +12292,19 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalError(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11380,32 +12348,38 @@ impl UpdateIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityProviderError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityProviderError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityProviderError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityProviderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIdentityProviderError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedIdentityProviderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIdentityProviderError::UnsupportedIdentityProvider(err.msg),
                     )
                 }
@@ -11452,25 +12426,34 @@ impl UpdateResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateResourceServerError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceServerError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResourceServerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceServerError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceServerError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceServerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceServerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateResourceServerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceServerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11538,82 +12521,112 @@ impl UpdateUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::AliasExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::AliasExists(err.msg),
+                    )
                 }
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::CodeDeliveryFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::CodeDeliveryFailure(err.msg),
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::CodeMismatch(err.msg),
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::ExpiredCode(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateUserAttributesError::InvalidEmailRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InvalidLambdaResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::InvalidLambdaResponse(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateUserAttributesError::InvalidSmsRoleAccessPolicy(err.msg),
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateUserAttributesError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UnexpectedLambda(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::UnexpectedLambda(err.msg),
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserLambdaValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::UserLambdaValidation(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserAttributesError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11686,45 +12699,64 @@ impl UpdateUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateUserPoolError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InternalError(err.msg))
+                    return RusotoError::service(&res, UpdateUserPoolError::InternalError(err.msg))
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidEmailRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::InvalidEmailRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidSmsRoleAccessPolicy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::InvalidSmsRoleAccessPolicy(err.msg),
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateUserPoolError::InvalidSmsRoleTrustRelationship(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UpdateUserPoolError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(UpdateUserPoolError::UserImportInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::UserImportInProgress(err.msg),
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(UpdateUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolError::UserPoolTagging(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11780,40 +12812,52 @@ impl UpdateUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOAuthFlowException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InvalidOAuthFlow(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::InvalidOAuthFlow(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ScopeDoesNotExistException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ScopeDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::ScopeDoesNotExist(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolClientError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11858,25 +12902,34 @@ impl UpdateUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolDomainError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolDomainError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolDomainError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPoolDomainError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11932,54 +12985,76 @@ impl VerifySoftwareTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::CodeMismatch(err.msg),
+                    )
                 }
                 "EnableSoftwareTokenMFAException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::EnableSoftwareTokenMFA(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::EnableSoftwareTokenMFA(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         VerifySoftwareTokenError::InvalidUserPoolConfiguration(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::ResourceNotFound(err.msg),
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         VerifySoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifySoftwareTokenError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12042,45 +13117,70 @@ impl VerifyUserAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::CodeMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::CodeMismatch(err.msg),
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::ExpiredCode(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::ExpiredCode(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::NotAuthorized(err.msg),
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::PasswordResetRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::PasswordResetRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::TooManyRequests(err.msg),
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::UserNotConfirmed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::UserNotConfirmed(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyUserAttributeError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12735,6 +13835,7 @@ impl CognitoIdentityProviderClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -12750,6 +13851,7 @@ impl CognitoIdentityProviderClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(
         client: Client,
         region: region::Region,
@@ -12766,6 +13868,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AddCustomAttributesRequest,
     ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddCustomAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AddCustomAttributes",
@@ -12787,6 +13892,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminAddUserToGroupRequest,
     ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminAddUserToGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminAddUserToGroup",
@@ -12807,6 +13915,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminConfirmSignUpRequest,
     ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminConfirmSignUp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminConfirmSignUp",
@@ -12828,6 +13939,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminCreateUserRequest,
     ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminCreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminCreateUser",
@@ -12849,6 +13963,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminDeleteUserRequest,
     ) -> Result<(), RusotoError<AdminDeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminDeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDeleteUser",
@@ -12870,6 +13987,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminDeleteUserAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDeleteUserAttributes",
@@ -12893,6 +14013,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminDisableProviderForUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDisableProviderForUser",
@@ -12915,6 +14038,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminDisableUserRequest,
     ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminDisableUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDisableUser",
@@ -12936,6 +14062,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminEnableUserRequest,
     ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminEnableUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminEnableUser",
@@ -12957,6 +14086,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminForgetDeviceRequest,
     ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminForgetDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminForgetDevice",
@@ -12977,6 +14109,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminGetDeviceRequest,
     ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminGetDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminGetDevice",
@@ -12998,6 +14133,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminGetUserRequest,
     ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminGetUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminGetUser",
@@ -13019,6 +14157,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminInitiateAuthRequest,
     ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminInitiateAuth");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminInitiateAuth",
@@ -13040,6 +14181,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminLinkProviderForUserRequest,
     ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminLinkProviderForUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminLinkProviderForUser",
@@ -13062,6 +14206,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListDevicesRequest,
     ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminListDevices");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminListDevices",
@@ -13083,6 +14230,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListGroupsForUserRequest,
     ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminListGroupsForUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminListGroupsForUser",
@@ -13105,6 +14255,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListUserAuthEventsRequest,
     ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminListUserAuthEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminListUserAuthEvents",
@@ -13127,6 +14280,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminRemoveUserFromGroupRequest,
     ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminRemoveUserFromGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminRemoveUserFromGroup",
@@ -13147,6 +14303,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminResetUserPasswordRequest,
     ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminResetUserPassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminResetUserPassword",
@@ -13170,6 +14329,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminRespondToAuthChallenge");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminRespondToAuthChallenge",
@@ -13193,6 +14355,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminSetUserMFAPreference");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminSetUserMFAPreference",
@@ -13215,6 +14380,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminSetUserPasswordRequest,
     ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminSetUserPassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminSetUserPassword",
@@ -13237,6 +14405,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminSetUserSettingsRequest,
     ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminSetUserSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminSetUserSettings",
@@ -13260,6 +14431,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminUpdateAuthEventFeedback");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUpdateAuthEventFeedback",
@@ -13282,6 +14456,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminUpdateDeviceStatusRequest,
     ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminUpdateDeviceStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUpdateDeviceStatus",
@@ -13305,6 +14482,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminUpdateUserAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUpdateUserAttributes",
@@ -13327,6 +14507,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminUserGlobalSignOutRequest,
     ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdminUserGlobalSignOut");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUserGlobalSignOut",
@@ -13349,6 +14532,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AssociateSoftwareTokenRequest,
     ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateSoftwareToken");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AssociateSoftwareToken",
@@ -13371,6 +14557,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ChangePasswordRequest,
     ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ChangePassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ChangePassword",
@@ -13392,6 +14581,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmDeviceRequest,
     ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ConfirmDevice",
@@ -13413,6 +14605,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmForgotPasswordRequest,
     ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmForgotPassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ConfirmForgotPassword",
@@ -13435,6 +14630,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmSignUpRequest,
     ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmSignUp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ConfirmSignUp",
@@ -13456,6 +14654,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateGroup",
@@ -13477,6 +14678,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateIdentityProviderRequest,
     ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateIdentityProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateIdentityProvider",
@@ -13499,6 +14703,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateResourceServerRequest,
     ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateResourceServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateResourceServer",
@@ -13521,6 +14728,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserImportJobRequest,
     ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserImportJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserImportJob",
@@ -13542,6 +14752,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolRequest,
     ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserPool",
@@ -13563,6 +14776,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolClientRequest,
     ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserPoolClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserPoolClient",
@@ -13585,6 +14801,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolDomainRequest,
     ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserPoolDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserPoolDomain",
@@ -13607,6 +14826,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteGroupRequest,
     ) -> Result<(), RusotoError<DeleteGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteGroup",
@@ -13627,6 +14849,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteIdentityProviderRequest,
     ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteIdentityProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteIdentityProvider",
@@ -13647,6 +14872,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteResourceServerRequest,
     ) -> Result<(), RusotoError<DeleteResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourceServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteResourceServer",
@@ -13667,6 +14895,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUser",
@@ -13687,6 +14918,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserAttributesRequest,
     ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserAttributes",
@@ -13709,6 +14943,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolRequest,
     ) -> Result<(), RusotoError<DeleteUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserPool",
@@ -13729,6 +14966,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolClientRequest,
     ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserPoolClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserPoolClient",
@@ -13749,6 +14989,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolDomainRequest,
     ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserPoolDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserPoolDomain",
@@ -13771,6 +15014,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeIdentityProviderRequest,
     ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeIdentityProvider");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeIdentityProvider",
@@ -13793,6 +15039,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeResourceServerRequest,
     ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeResourceServer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeResourceServer",
@@ -13816,6 +15065,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRiskConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeRiskConfiguration",
@@ -13838,6 +15090,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserImportJobRequest,
     ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserImportJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserImportJob",
@@ -13860,6 +15115,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolRequest,
     ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserPool");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserPool",
@@ -13881,6 +15139,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolClientRequest,
     ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserPoolClient");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserPoolClient",
@@ -13903,6 +15164,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolDomainRequest,
     ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserPoolDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserPoolDomain",
@@ -13925,6 +15189,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ForgetDeviceRequest,
     ) -> Result<(), RusotoError<ForgetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ForgetDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ForgetDevice",
@@ -13945,6 +15212,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ForgotPasswordRequest,
     ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ForgotPassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ForgotPassword",
@@ -13966,6 +15236,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetCSVHeaderRequest,
     ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCSVHeader");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetCSVHeader",
@@ -13987,6 +15260,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevice");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetDevice",
@@ -14008,6 +15284,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetGroupRequest,
     ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14029,6 +15308,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         RusotoError<GetIdentityProviderByIdentifierError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetIdentityProviderByIdentifier");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetIdentityProviderByIdentifier",
@@ -14051,6 +15333,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetSigningCertificateRequest,
     ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSigningCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetSigningCertificate",
@@ -14073,6 +15358,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUICustomizationRequest,
     ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUICustomization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetUICustomization",
@@ -14094,6 +15382,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUserRequest,
     ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14115,6 +15406,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         RusotoError<GetUserAttributeVerificationCodeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUserAttributeVerificationCode");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
@@ -14140,6 +15434,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUserPoolMfaConfigRequest,
     ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUserPoolMfaConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetUserPoolMfaConfig",
@@ -14162,6 +15459,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GlobalSignOutRequest,
     ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GlobalSignOut");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GlobalSignOut",
@@ -14183,6 +15483,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: InitiateAuthRequest,
     ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InitiateAuth");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.InitiateAuth",
@@ -14204,6 +15507,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListDevices",
@@ -14225,6 +15531,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListGroups",
@@ -14246,6 +15555,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListIdentityProvidersRequest,
     ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListIdentityProviders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListIdentityProviders",
@@ -14268,6 +15580,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListResourceServersRequest,
     ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourceServers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListResourceServers",
@@ -14289,6 +15604,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListTagsForResource",
@@ -14310,6 +15628,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserImportJobsRequest,
     ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUserImportJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUserImportJobs",
@@ -14331,6 +15652,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserPoolClientsRequest,
     ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUserPoolClients");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUserPoolClients",
@@ -14352,6 +15676,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserPoolsRequest,
     ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUserPools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUserPools",
@@ -14373,6 +15700,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUsers",
@@ -14394,6 +15724,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUsersInGroupRequest,
     ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUsersInGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUsersInGroup",
@@ -14415,6 +15748,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ResendConfirmationCodeRequest,
     ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResendConfirmationCode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ResendConfirmationCode",
@@ -14437,6 +15773,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: RespondToAuthChallengeRequest,
     ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RespondToAuthChallenge");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
@@ -14459,6 +15798,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetRiskConfigurationRequest,
     ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetRiskConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetRiskConfiguration",
@@ -14481,6 +15823,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUICustomizationRequest,
     ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetUICustomization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUICustomization",
@@ -14502,6 +15847,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserMFAPreferenceRequest,
     ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetUserMFAPreference");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUserMFAPreference",
@@ -14524,6 +15872,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserPoolMfaConfigRequest,
     ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetUserPoolMfaConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUserPoolMfaConfig",
@@ -14546,6 +15897,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserSettingsRequest,
     ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetUserSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUserSettings",
@@ -14567,6 +15921,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SignUpRequest,
     ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SignUp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14585,6 +15942,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: StartUserImportJobRequest,
     ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartUserImportJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.StartUserImportJob",
@@ -14606,6 +15966,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: StopUserImportJobRequest,
     ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopUserImportJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.StopUserImportJob",
@@ -14627,6 +15990,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.TagResource",
@@ -14648,6 +16014,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UntagResource",
@@ -14669,6 +16038,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateAuthEventFeedbackRequest,
     ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAuthEventFeedback");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateAuthEventFeedback",
@@ -14691,6 +16063,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateDeviceStatusRequest,
     ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDeviceStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateDeviceStatus",
@@ -14712,6 +16087,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateGroupRequest,
     ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateGroup",
@@ -14733,6 +16111,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateIdentityProviderRequest,
     ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateIdentityProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
@@ -14755,6 +16136,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateResourceServerRequest,
     ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateResourceServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateResourceServer",
@@ -14777,6 +16161,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserAttributesRequest,
     ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserAttributes",
@@ -14799,6 +16186,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolRequest,
     ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserPool",
@@ -14820,6 +16210,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolClientRequest,
     ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserPoolClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserPoolClient",
@@ -14842,6 +16235,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolDomainRequest,
     ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserPoolDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserPoolDomain",
@@ -14864,6 +16260,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: VerifySoftwareTokenRequest,
     ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("VerifySoftwareToken");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.VerifySoftwareToken",
@@ -14885,6 +16284,9 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: VerifyUserAttributeRequest,
     ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("VerifyUserAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.VerifyUserAttribute",
@@ -14900,3 +16302,1139 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyUserAttributeResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Cognito Identity Provider API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CognitoIdentityProviderClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CognitoIdentityProviderBlockingClient {
+    client: CognitoIdentityProviderClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CognitoIdentityProviderBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CognitoIdentityProviderBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CognitoIdentityProviderBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoIdentityProviderBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CognitoIdentityProviderBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient {
+            client: CognitoIdentityProviderClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds additional user attributes to the user pool schema.</p>
+    ///
+    /// See `CognitoIdentityProvider::add_custom_attributes`.
+    pub fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
+        self.runtime
+            .block_on(self.client.add_custom_attributes(input))
+    }
+
+    /// <p>Adds the specified user to the specified group.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_add_user_to_group`.
+    pub fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
+        self.runtime
+            .block_on(self.client.admin_add_user_to_group(input))
+    }
+
+    /// <p>Confirms user registration as an admin without using a confirmation code. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_confirm_sign_up`.
+    pub fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
+        self.runtime
+            .block_on(self.client.admin_confirm_sign_up(input))
+    }
+
+    /// <p>Creates a new user in the specified user pool.</p> <p>If <code>MessageAction</code> is not set, the default is to send a welcome message via email or phone (SMS).</p> <note> <p>This message is based on a template that you configured in your call to or . This template includes your custom sign-up instructions and placeholders for user name and temporary password.</p> </note> <p>Alternatively, you can call AdminCreateUser with “SUPPRESS” for the <code>MessageAction</code> parameter, and Amazon Cognito will not send any email. </p> <p>In either case, the user will be in the <code>FORCE_CHANGE_PASSWORD</code> state until they sign in and change their password.</p> <p>AdminCreateUser requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_create_user`.
+    pub fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
+        self.runtime.block_on(self.client.admin_create_user(input))
+    }
+
+    /// <p>Deletes a user as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_delete_user`.
+    pub fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> Result<(), RusotoError<AdminDeleteUserError>> {
+        self.runtime.block_on(self.client.admin_delete_user(input))
+    }
+
+    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_delete_user_attributes`.
+    pub fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_delete_user_attributes(input))
+    }
+
+    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_disable_provider_for_user`.
+    pub fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_disable_provider_for_user(input))
+    }
+
+    /// <p>Disables the specified user.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_disable_user`.
+    pub fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
+        self.runtime.block_on(self.client.admin_disable_user(input))
+    }
+
+    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_enable_user`.
+    pub fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
+        self.runtime.block_on(self.client.admin_enable_user(input))
+    }
+
+    /// <p>Forgets the device, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_forget_device`.
+    pub fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
+        self.runtime
+            .block_on(self.client.admin_forget_device(input))
+    }
+
+    /// <p>Gets the device, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_get_device`.
+    pub fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
+        self.runtime.block_on(self.client.admin_get_device(input))
+    }
+
+    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_get_user`.
+    pub fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
+        self.runtime.block_on(self.client.admin_get_user(input))
+    }
+
+    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_initiate_auth`.
+    pub fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
+        self.runtime
+            .block_on(self.client.admin_initiate_auth(input))
+    }
+
+    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_link_provider_for_user`.
+    pub fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
+        self.runtime
+            .block_on(self.client.admin_link_provider_for_user(input))
+    }
+
+    /// <p>Lists devices, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_list_devices`.
+    pub fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
+        self.runtime.block_on(self.client.admin_list_devices(input))
+    }
+
+    /// <p>Lists the groups that the user belongs to.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_list_groups_for_user`.
+    pub fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
+        self.runtime
+            .block_on(self.client.admin_list_groups_for_user(input))
+    }
+
+    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_list_user_auth_events`.
+    pub fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
+        self.runtime
+            .block_on(self.client.admin_list_user_auth_events(input))
+    }
+
+    /// <p>Removes the specified user from the specified group.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_remove_user_from_group`.
+    pub fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
+        self.runtime
+            .block_on(self.client.admin_remove_user_from_group(input))
+    }
+
+    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_reset_user_password`.
+    pub fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
+        self.runtime
+            .block_on(self.client.admin_reset_user_password(input))
+    }
+
+    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_respond_to_auth_challenge`.
+    pub fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_respond_to_auth_challenge(input))
+    }
+
+    /// <p>Sets the user's multi-factor authentication (MFA) preference, including which MFA options are enabled and if any are preferred. Only one factor can be set as preferred. The preferred MFA factor will be used to authenticate a user if multiple factors are enabled. If multiple options are enabled and no preference is set, a challenge to choose an MFA option will be returned during sign in.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_set_user_mfa_preference`.
+    pub fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_set_user_mfa_preference(input))
+    }
+
+    /// <p>Sets the specified user's password in a user pool as an administrator. Works on any user. </p> <p>The password can be temporary or permanent. If it is temporary, the user status will be placed into the <code>FORCE_CHANGE_PASSWORD</code> state. When the user next tries to sign in, the InitiateAuth/AdminInitiateAuth response will contain the <code>NEW_PASSWORD_REQUIRED</code> challenge. If the user does not sign in before it expires, the user will not be able to sign in and their password will need to be reset by an administrator. </p> <p>Once the user has set a new password, or the password is permanent, the user status will be set to <code>Confirmed</code>.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_set_user_password`.
+    pub fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
+        self.runtime
+            .block_on(self.client.admin_set_user_password(input))
+    }
+
+    /// <p> <i>This action is no longer supported.</i> You can use it to configure only SMS MFA. You can't use it to configure TOTP software token MFA. To configure either type of MFA, use the <a>AdminSetUserMFAPreference</a> action instead.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_set_user_settings`.
+    pub fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
+        self.runtime
+            .block_on(self.client.admin_set_user_settings(input))
+    }
+
+    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_update_auth_event_feedback`.
+    pub fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_update_auth_event_feedback(input))
+    }
+
+    /// <p>Updates the device status as an administrator.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_update_device_status`.
+    pub fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
+        self.runtime
+            .block_on(self.client.admin_update_device_status(input))
+    }
+
+    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_update_user_attributes`.
+    pub fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_update_user_attributes(input))
+    }
+
+    /// <p>Signs out users from all devices, as an administrator. It also invalidates all refresh tokens issued to a user. The user's current access and Id tokens remain valid until their expiry. Access and Id tokens expire one hour after they are issued.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::admin_user_global_sign_out`.
+    pub fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
+        self.runtime
+            .block_on(self.client.admin_user_global_sign_out(input))
+    }
+
+    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
+    ///
+    /// See `CognitoIdentityProvider::associate_software_token`.
+    pub fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
+        self.runtime
+            .block_on(self.client.associate_software_token(input))
+    }
+
+    /// <p>Changes the password for a specified user in a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::change_password`.
+    pub fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
+        self.runtime.block_on(self.client.change_password(input))
+    }
+
+    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
+    ///
+    /// See `CognitoIdentityProvider::confirm_device`.
+    pub fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
+        self.runtime.block_on(self.client.confirm_device(input))
+    }
+
+    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
+    ///
+    /// See `CognitoIdentityProvider::confirm_forgot_password`.
+    pub fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
+        self.runtime
+            .block_on(self.client.confirm_forgot_password(input))
+    }
+
+    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
+    ///
+    /// See `CognitoIdentityProvider::confirm_sign_up`.
+    pub fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
+        self.runtime.block_on(self.client.confirm_sign_up(input))
+    }
+
+    /// <p>Creates a new group in the specified user pool.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p>Creates an identity provider for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_identity_provider`.
+    pub fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.create_identity_provider(input))
+    }
+
+    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_resource_server`.
+    pub fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
+        self.runtime
+            .block_on(self.client.create_resource_server(input))
+    }
+
+    /// <p>Creates the user import job.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_user_import_job`.
+    pub fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.create_user_import_job(input))
+    }
+
+    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_user_pool`.
+    pub fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
+        self.runtime.block_on(self.client.create_user_pool(input))
+    }
+
+    /// <p>Creates the user pool client.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_user_pool_client`.
+    pub fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.create_user_pool_client(input))
+    }
+
+    /// <p>Creates a new domain for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::create_user_pool_domain`.
+    pub fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.create_user_pool_domain(input))
+    }
+
+    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(), RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Deletes an identity provider for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_identity_provider`.
+    pub fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_identity_provider(input))
+    }
+
+    /// <p>Deletes a resource server.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_resource_server`.
+    pub fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> Result<(), RusotoError<DeleteResourceServerError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_server(input))
+    }
+
+    /// <p>Allows a user to delete himself or herself.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Deletes the attributes for a user.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_user_attributes`.
+    pub fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
+        self.runtime
+            .block_on(self.client.delete_user_attributes(input))
+    }
+
+    /// <p>Deletes the specified Amazon Cognito user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_user_pool`.
+    pub fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> Result<(), RusotoError<DeleteUserPoolError>> {
+        self.runtime.block_on(self.client.delete_user_pool(input))
+    }
+
+    /// <p>Allows the developer to delete the user pool client.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_user_pool_client`.
+    pub fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.delete_user_pool_client(input))
+    }
+
+    /// <p>Deletes a domain for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::delete_user_pool_domain`.
+    pub fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.delete_user_pool_domain(input))
+    }
+
+    /// <p>Gets information about a specific identity provider.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_identity_provider`.
+    pub fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_provider(input))
+    }
+
+    /// <p>Describes a resource server.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_resource_server`.
+    pub fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
+        self.runtime
+            .block_on(self.client.describe_resource_server(input))
+    }
+
+    /// <p>Describes the risk configuration.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_risk_configuration`.
+    pub fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_risk_configuration(input))
+    }
+
+    /// <p>Describes the user import job.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_user_import_job`.
+    pub fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_user_import_job(input))
+    }
+
+    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_user_pool`.
+    pub fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
+        self.runtime.block_on(self.client.describe_user_pool(input))
+    }
+
+    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_user_pool_client`.
+    pub fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.describe_user_pool_client(input))
+    }
+
+    /// <p>Gets information about a domain.</p>
+    ///
+    /// See `CognitoIdentityProvider::describe_user_pool_domain`.
+    pub fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.describe_user_pool_domain(input))
+    }
+
+    /// <p>Forgets the specified device.</p>
+    ///
+    /// See `CognitoIdentityProvider::forget_device`.
+    pub fn forget_device(
+        &self,
+        input: ForgetDeviceRequest,
+    ) -> Result<(), RusotoError<ForgetDeviceError>> {
+        self.runtime.block_on(self.client.forget_device(input))
+    }
+
+    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. The method used to send the confirmation code is sent according to the specified AccountRecoverySetting. For more information, see <a href="">Recovering User Accounts</a> in the <i>Amazon Cognito Developer Guide</i>. If neither a verified phone number nor a verified email exists, an <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
+    ///
+    /// See `CognitoIdentityProvider::forgot_password`.
+    pub fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
+        self.runtime.block_on(self.client.forgot_password(input))
+    }
+
+    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_csv_header`.
+    pub fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
+        self.runtime.block_on(self.client.get_csv_header(input))
+    }
+
+    /// <p>Gets the device.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_device`.
+    pub fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+        self.runtime.block_on(self.client.get_device(input))
+    }
+
+    /// <p>Gets a group.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_group`.
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// <p>Gets the specified identity provider.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_identity_provider_by_identifier`.
+    pub fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> Result<
+        GetIdentityProviderByIdentifierResponse,
+        RusotoError<GetIdentityProviderByIdentifierError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_provider_by_identifier(input))
+    }
+
+    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_signing_certificate`.
+    pub fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.get_signing_certificate(input))
+    }
+
+    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_ui_customization`.
+    pub fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
+        self.runtime
+            .block_on(self.client.get_ui_customization(input))
+    }
+
+    /// <p>Gets the user attributes and metadata for a user.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_user`.
+    pub fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.runtime.block_on(self.client.get_user(input))
+    }
+
+    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_user_attribute_verification_code`.
+    pub fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> Result<
+        GetUserAttributeVerificationCodeResponse,
+        RusotoError<GetUserAttributeVerificationCodeError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_user_attribute_verification_code(input))
+    }
+
+    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
+    ///
+    /// See `CognitoIdentityProvider::get_user_pool_mfa_config`.
+    pub fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
+        self.runtime
+            .block_on(self.client.get_user_pool_mfa_config(input))
+    }
+
+    /// <p>Signs out users from all devices. It also invalidates all refresh tokens issued to a user. The user's current access and Id tokens remain valid until their expiry. Access and Id tokens expire one hour after they are issued.</p>
+    ///
+    /// See `CognitoIdentityProvider::global_sign_out`.
+    pub fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
+        self.runtime.block_on(self.client.global_sign_out(input))
+    }
+
+    /// <p>Initiates the authentication flow.</p>
+    ///
+    /// See `CognitoIdentityProvider::initiate_auth`.
+    pub fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
+        self.runtime.block_on(self.client.initiate_auth(input))
+    }
+
+    /// <p>Lists the devices.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_devices`.
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// <p>Lists the groups associated with a user pool.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_groups`.
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// <p>Lists information about all identity providers for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_identity_providers`.
+    pub fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
+        self.runtime
+            .block_on(self.client.list_identity_providers(input))
+    }
+
+    /// <p>Lists the resource servers for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_resource_servers`.
+    pub fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
+        self.runtime
+            .block_on(self.client.list_resource_servers(input))
+    }
+
+    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the user import jobs.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_user_import_jobs`.
+    pub fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_user_import_jobs(input))
+    }
+
+    /// <p>Lists the clients that have been created for the specified user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_user_pool_clients`.
+    pub fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
+        self.runtime
+            .block_on(self.client.list_user_pool_clients(input))
+    }
+
+    /// <p>Lists the user pools associated with an AWS account.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_user_pools`.
+    pub fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
+        self.runtime.block_on(self.client.list_user_pools(input))
+    }
+
+    /// <p>Lists the users in the Amazon Cognito user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Lists the users in the specified group.</p> <p>Calling this action requires developer credentials.</p>
+    ///
+    /// See `CognitoIdentityProvider::list_users_in_group`.
+    pub fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
+        self.runtime
+            .block_on(self.client.list_users_in_group(input))
+    }
+
+    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::resend_confirmation_code`.
+    pub fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
+        self.runtime
+            .block_on(self.client.resend_confirmation_code(input))
+    }
+
+    /// <p>Responds to the authentication challenge.</p>
+    ///
+    /// See `CognitoIdentityProvider::respond_to_auth_challenge`.
+    pub fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
+        self.runtime
+            .block_on(self.client.respond_to_auth_challenge(input))
+    }
+
+    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
+    ///
+    /// See `CognitoIdentityProvider::set_risk_configuration`.
+    pub fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
+        self.runtime
+            .block_on(self.client.set_risk_configuration(input))
+    }
+
+    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
+    ///
+    /// See `CognitoIdentityProvider::set_ui_customization`.
+    pub fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
+        self.runtime
+            .block_on(self.client.set_ui_customization(input))
+    }
+
+    /// <p>Set the user's multi-factor authentication (MFA) method preference, including which MFA factors are enabled and if any are preferred. Only one factor can be set as preferred. The preferred MFA factor will be used to authenticate a user if multiple factors are enabled. If multiple options are enabled and no preference is set, a challenge to choose an MFA option will be returned during sign in.</p>
+    ///
+    /// See `CognitoIdentityProvider::set_user_mfa_preference`.
+    pub fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
+        self.runtime
+            .block_on(self.client.set_user_mfa_preference(input))
+    }
+
+    /// <p>Set the user pool multi-factor authentication (MFA) configuration.</p>
+    ///
+    /// See `CognitoIdentityProvider::set_user_pool_mfa_config`.
+    pub fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
+        self.runtime
+            .block_on(self.client.set_user_pool_mfa_config(input))
+    }
+
+    /// <p> <i>This action is no longer supported.</i> You can use it to configure only SMS MFA. You can't use it to configure TOTP software token MFA. To configure either type of MFA, use the <a>SetUserMFAPreference</a> action instead.</p>
+    ///
+    /// See `CognitoIdentityProvider::set_user_settings`.
+    pub fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
+        self.runtime.block_on(self.client.set_user_settings(input))
+    }
+
+    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
+    ///
+    /// See `CognitoIdentityProvider::sign_up`.
+    pub fn sign_up(
+        &self,
+        input: SignUpRequest,
+    ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
+        self.runtime.block_on(self.client.sign_up(input))
+    }
+
+    /// <p>Starts the user import.</p>
+    ///
+    /// See `CognitoIdentityProvider::start_user_import_job`.
+    pub fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.start_user_import_job(input))
+    }
+
+    /// <p>Stops the user import job.</p>
+    ///
+    /// See `CognitoIdentityProvider::stop_user_import_job`.
+    pub fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.stop_user_import_job(input))
+    }
+
+    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
+    ///
+    /// See `CognitoIdentityProvider::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
+    ///
+    /// See `CognitoIdentityProvider::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    ///
+    /// See `CognitoIdentityProvider::update_auth_event_feedback`.
+    pub fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
+        self.runtime
+            .block_on(self.client.update_auth_event_feedback(input))
+    }
+
+    /// <p>Updates the device status.</p>
+    ///
+    /// See `CognitoIdentityProvider::update_device_status`.
+    pub fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
+        self.runtime
+            .block_on(self.client.update_device_status(input))
+    }
+
+    /// <p><p>Updates the specified group with the specified attributes.</p> <p>Calling this action requires developer credentials.</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    ///
+    /// See `CognitoIdentityProvider::update_group`.
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// <p>Updates identity provider information for a user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::update_identity_provider`.
+    pub fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.update_identity_provider(input))
+    }
+
+    /// <p><p>Updates the name and scopes of resource server. All other fields are read-only.</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    ///
+    /// See `CognitoIdentityProvider::update_resource_server`.
+    pub fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
+        self.runtime
+            .block_on(self.client.update_resource_server(input))
+    }
+
+    /// <p>Allows a user to update a specific attribute (one at a time).</p>
+    ///
+    /// See `CognitoIdentityProvider::update_user_attributes`.
+    pub fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_user_attributes(input))
+    }
+
+    /// <p><p>Updates the specified user pool with the specified attributes. You can get a list of the current user pool settings with .</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    ///
+    /// See `CognitoIdentityProvider::update_user_pool`.
+    pub fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
+        self.runtime.block_on(self.client.update_user_pool(input))
+    }
+
+    /// <p><p>Updates the specified user pool app client with the specified attributes. You can get a list of the current user pool app client settings with .</p> <important> <p>If you don&#39;t provide a value for an attribute, it will be set to the default value.</p> </important></p>
+    ///
+    /// See `CognitoIdentityProvider::update_user_pool_client`.
+    pub fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.update_user_pool_client(input))
+    }
+
+    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
+    ///
+    /// See `CognitoIdentityProvider::update_user_pool_domain`.
+    pub fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.update_user_pool_domain(input))
+    }
+
+    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
+    ///
+    /// See `CognitoIdentityProvider::verify_software_token`.
+    pub fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
+        self.runtime
+            .block_on(self.client.verify_software_token(input))
+    }
+
+    /// <p>Verifies the specified user attributes in the user pool.</p>
+    ///
+    /// See `CognitoIdentityProvider::verify_user_attribute`.
+    pub fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
+        self.runtime
+            .block_on(self.client.verify_user_attribute(input))
+    }
+}
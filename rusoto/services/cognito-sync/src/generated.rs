@@ -711,22 +711,22 @@ impl BulkPublishError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyStreamedException" => {
-                    return RusotoError::Service(BulkPublishError::AlreadyStreamed(err.msg))
+                    return RusotoError::service(&res, BulkPublishError::AlreadyStreamed(err.msg))
                 }
                 "DuplicateRequestException" => {
-                    return RusotoError::Service(BulkPublishError::DuplicateRequest(err.msg))
+                    return RusotoError::service(&res, BulkPublishError::DuplicateRequest(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(BulkPublishError::InternalError(err.msg))
+                    return RusotoError::service(&res, BulkPublishError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BulkPublishError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, BulkPublishError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(BulkPublishError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, BulkPublishError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BulkPublishError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, BulkPublishError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -771,22 +771,31 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteDatasetError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::NotAuthorized(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -829,19 +838,28 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeDatasetError::InternalError(err.msg))
+                    return RusotoError::service(&res, DescribeDatasetError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DescribeDatasetError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -883,29 +901,34 @@ impl DescribeIdentityPoolUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolUsageError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolUsageError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolUsageError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolUsageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityPoolUsageError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -947,25 +970,34 @@ impl DescribeIdentityUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityUsageError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityUsageError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityUsageError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityUsageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIdentityUsageError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1005,20 +1037,28 @@ impl GetBulkPublishDetailsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBulkPublishDetailsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBulkPublishDetailsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBulkPublishDetailsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBulkPublishDetailsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1059,19 +1099,34 @@ impl GetCognitoEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCognitoEventsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCognitoEventsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCognitoEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCognitoEventsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCognitoEventsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCognitoEventsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCognitoEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCognitoEventsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCognitoEventsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCognitoEventsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1115,27 +1170,32 @@ impl GetIdentityPoolConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdentityPoolConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityPoolConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdentityPoolConfigurationError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIdentityPoolConfigurationError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityPoolConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetIdentityPoolConfigurationError::TooManyRequests(err.msg),
                     )
                 }
@@ -1181,16 +1241,16 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListDatasetsError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListDatasetsError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::NotAuthorized(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDatasetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1229,20 +1289,28 @@ impl ListIdentityPoolUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolUsageError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolUsageError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolUsageError::NotAuthorized(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIdentityPoolUsageError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1281,16 +1349,16 @@ impl ListRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListRecordsError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListRecordsError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRecordsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListRecordsError::InvalidParameter(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListRecordsError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, ListRecordsError::NotAuthorized(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRecordsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListRecordsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1333,22 +1401,34 @@ impl RegisterDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(RegisterDeviceError::InternalError(err.msg))
+                    return RusotoError::service(&res, RegisterDeviceError::InternalError(err.msg))
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(RegisterDeviceError::InvalidConfiguration(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDeviceError::InvalidConfiguration(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDeviceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(RegisterDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, RegisterDeviceError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RegisterDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDeviceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1391,19 +1471,34 @@ impl SetCognitoEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetCognitoEventsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetCognitoEventsError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetCognitoEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetCognitoEventsError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetCognitoEventsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetCognitoEventsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetCognitoEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetCognitoEventsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetCognitoEventsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetCognitoEventsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1449,32 +1544,38 @@ impl SetIdentityPoolConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetIdentityPoolConfigurationError::ConcurrentModification(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetIdentityPoolConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetIdentityPoolConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetIdentityPoolConfigurationError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetIdentityPoolConfigurationError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetIdentityPoolConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetIdentityPoolConfigurationError::TooManyRequests(err.msg),
                     )
                 }
@@ -1527,24 +1628,40 @@ impl SubscribeToDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToDatasetError::InternalError(err.msg),
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InvalidConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToDatasetError::InvalidConfiguration(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToDatasetError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToDatasetError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToDatasetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1589,34 +1706,40 @@ impl UnsubscribeFromDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromDatasetError::InternalError(err.msg),
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InvalidConfiguration(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromDatasetError::InvalidConfiguration(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromDatasetError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromDatasetError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromDatasetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1667,33 +1790,43 @@ impl UpdateRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateRecordsError::InternalError(err.msg))
+                    return RusotoError::service(&res, UpdateRecordsError::InternalError(err.msg))
                 }
                 "InvalidLambdaFunctionOutputException" => {
-                    return RusotoError::Service(UpdateRecordsError::InvalidLambdaFunctionOutput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRecordsError::InvalidLambdaFunctionOutput(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRecordsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRecordsError::InvalidParameter(err.msg),
+                    )
                 }
                 "LambdaThrottledException" => {
-                    return RusotoError::Service(UpdateRecordsError::LambdaThrottled(err.msg))
+                    return RusotoError::service(&res, UpdateRecordsError::LambdaThrottled(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRecordsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateRecordsError::LimitExceeded(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateRecordsError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UpdateRecordsError::NotAuthorized(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateRecordsError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRecordsError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRecordsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRecordsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateRecordsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1842,6 +1975,7 @@ impl CognitoSyncClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1857,6 +1991,7 @@ impl CognitoSyncClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncClient {
         CognitoSyncClient { client, region }
     }
@@ -1876,6 +2011,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("BulkPublish");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1909,6 +2047,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1942,6 +2083,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("DescribeDataset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1974,6 +2118,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("DescribeIdentityPoolUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2006,6 +2153,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("DescribeIdentityUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2037,6 +2187,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("GetBulkPublishDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2068,6 +2221,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("GetCognitoEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2100,6 +2256,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("GetIdentityPoolConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2132,6 +2291,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("ListDatasets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2169,6 +2331,9 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = "/identitypools";
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("ListIdentityPoolUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2206,6 +2371,9 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/records", dataset_name = input.dataset_name, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("ListRecords");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2253,6 +2421,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("RegisterDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2287,6 +2458,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("SetCognitoEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2321,6 +2495,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("SetIdentityPoolConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2352,6 +2529,9 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("SubscribeToDataset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2380,6 +2560,9 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("UnsubscribeFromDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2413,6 +2596,9 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation_name("UpdateRecords");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2436,3 +2622,234 @@ impl CognitoSync for CognitoSyncClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Cognito Sync API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CognitoSyncClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CognitoSyncBlockingClient {
+    client: CognitoSyncClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CognitoSyncBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CognitoSyncBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CognitoSyncBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoSyncBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CognitoSyncBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient {
+            client: CognitoSyncClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Initiates a bulk publish of all existing datasets for an Identity Pool to the configured stream. Customers are limited to one successful bulk publish per 24 hours. Bulk publish is an asynchronous request, customers can see the status of the request via the GetBulkPublishDetails operation.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::bulk_publish`.
+    pub fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> Result<BulkPublishResponse, RusotoError<BulkPublishError>> {
+        self.runtime.block_on(self.client.bulk_publish(input))
+    }
+
+    /// <p>Deletes the specific dataset. The dataset will be deleted permanently, and the action can't be undone. Datasets that this dataset was merged with will no longer report the merge. Any subsequent operation on this dataset will result in a ResourceNotFoundException.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    ///
+    /// See `CognitoSync::delete_dataset`.
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<DeleteDatasetResponse, RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// <p>Gets meta data about a dataset by identity and dataset name. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
+    ///
+    /// See `CognitoSync::describe_dataset`.
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// <p>Gets usage details (for example, data storage) about a particular identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::describe_identity_pool_usage`.
+    pub fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> Result<DescribeIdentityPoolUsageResponse, RusotoError<DescribeIdentityPoolUsageError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_identity_pool_usage(input))
+    }
+
+    /// <p>Gets usage information for an identity, including number of datasets and data usage.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    ///
+    /// See `CognitoSync::describe_identity_usage`.
+    pub fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> Result<DescribeIdentityUsageResponse, RusotoError<DescribeIdentityUsageError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_usage(input))
+    }
+
+    /// <p>Get the status of the last BulkPublish operation for an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::get_bulk_publish_details`.
+    pub fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> Result<GetBulkPublishDetailsResponse, RusotoError<GetBulkPublishDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_bulk_publish_details(input))
+    }
+
+    /// <p>Gets the events and the corresponding Lambda functions associated with an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::get_cognito_events`.
+    pub fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> Result<GetCognitoEventsResponse, RusotoError<GetCognitoEventsError>> {
+        self.runtime.block_on(self.client.get_cognito_events(input))
+    }
+
+    /// <p>Gets the configuration settings of an identity pool.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::get_identity_pool_configuration`.
+    pub fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> Result<GetIdentityPoolConfigurationResponse, RusotoError<GetIdentityPoolConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_identity_pool_configuration(input))
+    }
+
+    /// <p>Lists datasets for an identity. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>ListDatasets can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use the Cognito Identity credentials to make this API call.</p>
+    ///
+    /// See `CognitoSync::list_datasets`.
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// <p>Gets a list of identity pools registered with Cognito.</p> <p>ListIdentityPoolUsage can only be called with developer credentials. You cannot make this API call with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::list_identity_pool_usage`.
+    pub fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> Result<ListIdentityPoolUsageResponse, RusotoError<ListIdentityPoolUsageError>> {
+        self.runtime
+            .block_on(self.client.list_identity_pool_usage(input))
+    }
+
+    /// <p>Gets paginated records, optionally changed after a particular sync count for a dataset and identity. With Amazon Cognito Sync, each identity has access only to its own data. Thus, the credentials used to make this API call need to have access to the identity data.</p> <p>ListRecords can be called with temporary user credentials provided by Cognito Identity or with developer credentials. You should use Cognito Identity credentials to make this API call.</p>
+    ///
+    /// See `CognitoSync::list_records`.
+    pub fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> Result<ListRecordsResponse, RusotoError<ListRecordsError>> {
+        self.runtime.block_on(self.client.list_records(input))
+    }
+
+    /// <p>Registers a device to receive push sync notifications.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
+    ///
+    /// See `CognitoSync::register_device`.
+    pub fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> Result<RegisterDeviceResponse, RusotoError<RegisterDeviceError>> {
+        self.runtime.block_on(self.client.register_device(input))
+    }
+
+    /// <p>Sets the AWS Lambda function for a given event type for an identity pool. This request only updates the key/value pair specified. Other key/values pairs are not updated. To remove a key value pair, pass a empty value for the particular key.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::set_cognito_events`.
+    pub fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> Result<(), RusotoError<SetCognitoEventsError>> {
+        self.runtime.block_on(self.client.set_cognito_events(input))
+    }
+
+    /// <p>Sets the necessary configuration for push sync.</p> <p>This API can only be called with developer credentials. You cannot call this API with the temporary user credentials provided by Cognito Identity.</p>
+    ///
+    /// See `CognitoSync::set_identity_pool_configuration`.
+    pub fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> Result<SetIdentityPoolConfigurationResponse, RusotoError<SetIdentityPoolConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.set_identity_pool_configuration(input))
+    }
+
+    /// <p>Subscribes to receive notifications when a dataset is modified by another device.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
+    ///
+    /// See `CognitoSync::subscribe_to_dataset`.
+    pub fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> Result<SubscribeToDatasetResponse, RusotoError<SubscribeToDatasetError>> {
+        self.runtime
+            .block_on(self.client.subscribe_to_dataset(input))
+    }
+
+    /// <p>Unsubscribes from receiving notifications when a dataset is modified by another device.</p> <p>This API can only be called with temporary credentials provided by Cognito Identity. You cannot call this API with developer credentials.</p>
+    ///
+    /// See `CognitoSync::unsubscribe_from_dataset`.
+    pub fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> Result<UnsubscribeFromDatasetResponse, RusotoError<UnsubscribeFromDatasetError>> {
+        self.runtime
+            .block_on(self.client.unsubscribe_from_dataset(input))
+    }
+
+    /// <p>Posts updates to records and adds and deletes records for a dataset and user.</p> <p>The sync count in the record patch is your last known sync count for that record. The server will reject an UpdateRecords request with a ResourceConflictException if you try to patch a record with a new value but a stale sync count.</p> <p>For example, if the sync count on the server is 5 for a key called highScore and you try and submit a new highScore with sync count of 4, the request will be rejected. To obtain the current sync count for a record, call ListRecords. On a successful update of the record, the response returns the new sync count for that record. You should present that sync count the next time you try to update that same record. When the record does not exist, specify the sync count as 0.</p> <p>This API can be called with temporary user credentials provided by Cognito Identity or with developer credentials.</p>
+    ///
+    /// See `CognitoSync::update_records`.
+    pub fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> Result<UpdateRecordsResponse, RusotoError<UpdateRecordsError>> {
+        self.runtime.block_on(self.client.update_records(input))
+    }
+}
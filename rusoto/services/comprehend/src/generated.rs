@@ -2522,22 +2522,26 @@ impl BatchDetectDominantLanguageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDetectDominantLanguageError::BatchSizeLimitExceeded(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectDominantLanguageError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectDominantLanguageError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectDominantLanguageError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectDominantLanguageError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
                     )
                 }
@@ -2584,25 +2588,34 @@ impl BatchDetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::BatchSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectEntitiesError::BatchSizeLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectEntitiesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectEntitiesError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectEntitiesError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectEntitiesError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2644,29 +2657,34 @@ impl BatchDetectKeyPhrasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchDetectKeyPhrasesError::BatchSizeLimitExceeded(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectKeyPhrasesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectKeyPhrasesError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectKeyPhrasesError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectKeyPhrasesError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2708,25 +2726,34 @@ impl BatchDetectSentimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::BatchSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSentimentError::BatchSizeLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSentimentError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSentimentError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSentimentError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSentimentError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2768,25 +2795,34 @@ impl BatchDetectSyntaxError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::BatchSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSyntaxError::BatchSizeLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSyntaxError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSyntaxError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSyntaxError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDetectSyntaxError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2826,20 +2862,28 @@ impl ClassifyDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ClassifyDocumentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClassifyDocumentError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClassifyDocumentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClassifyDocumentError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ClassifyDocumentError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClassifyDocumentError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(ClassifyDocumentError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClassifyDocumentError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2886,42 +2930,50 @@ impl CreateDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentClassifierError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentClassifierError::InvalidRequest(err.msg),
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentClassifierError::KmsKeyValidation(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentClassifierError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDocumentClassifierError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentClassifierError::TooManyRequests(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentClassifierError::TooManyTags(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDocumentClassifierError::UnsupportedLanguage(err.msg),
                     )
                 }
@@ -2976,30 +3028,40 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateEndpointError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateEndpointError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateEndpointError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateEndpointError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateEndpointError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3050,42 +3112,52 @@ impl CreateEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::KmsKeyValidation(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEntityRecognizerError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::TooManyTags(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEntityRecognizerError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3132,34 +3204,40 @@ impl DeleteDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentClassifierError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentClassifierError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentClassifierError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentClassifierError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDocumentClassifierError::ResourceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentClassifierError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3202,19 +3280,25 @@ impl DeleteEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteEndpointError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteEndpointError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteEndpointError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3258,34 +3342,40 @@ impl DeleteEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEntityRecognizerError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEntityRecognizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEntityRecognizerError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEntityRecognizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEntityRecognizerError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEntityRecognizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3328,22 +3418,26 @@ impl DescribeDocumentClassificationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentClassificationJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentClassificationJobError::InvalidRequest(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentClassificationJobError::JobNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentClassificationJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -3394,24 +3488,28 @@ impl DescribeDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentClassifierError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentClassifierError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentClassifierError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentClassifierError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3452,22 +3550,26 @@ impl DescribeDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDominantLanguageDetectionJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDominantLanguageDetectionJobError::InvalidRequest(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDominantLanguageDetectionJobError::JobNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDominantLanguageDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -3516,16 +3618,28 @@ impl DescribeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3566,22 +3680,26 @@ impl DescribeEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntitiesDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntitiesDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntitiesDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEntitiesDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -3622,24 +3740,28 @@ impl DescribeEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityRecognizerError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityRecognizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityRecognizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityRecognizerError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3680,22 +3802,26 @@ impl DescribeKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeKeyPhrasesDetectionJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeKeyPhrasesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeKeyPhrasesDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeKeyPhrasesDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -3744,22 +3870,26 @@ impl DescribeSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSentimentDetectionJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSentimentDetectionJobError::InvalidRequest(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeSentimentDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSentimentDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSentimentDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -3804,24 +3934,28 @@ impl DescribeTopicsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTopicsDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTopicsDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTopicsDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTopicsDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3858,17 +3992,20 @@ impl DetectDominantLanguageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectDominantLanguageError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectDominantLanguageError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectDominantLanguageError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectDominantLanguageError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
                     )
                 }
@@ -3910,21 +4047,28 @@ impl DetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DetectEntitiesError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DetectEntitiesError::InvalidRequest(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectEntitiesError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3964,20 +4108,28 @@ impl DetectKeyPhrasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectKeyPhrasesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectKeyPhrasesError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectKeyPhrasesError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::UnsupportedLanguage(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectKeyPhrasesError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4016,18 +4168,28 @@ impl DetectSentimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectSentimentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectSentimentError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectSentimentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectSentimentError::InvalidRequest(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectSentimentError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectSentimentError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectSentimentError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectSentimentError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4066,16 +4228,22 @@ impl DetectSyntaxError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectSyntaxError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DetectSyntaxError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectSyntaxError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DetectSyntaxError::InvalidRequest(err.msg))
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectSyntaxError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectSyntaxError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectSyntaxError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectSyntaxError::UnsupportedLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4116,22 +4284,26 @@ impl ListDocumentClassificationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDocumentClassificationJobsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDocumentClassificationJobsError::InvalidFilter(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDocumentClassificationJobsError::InvalidRequest(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDocumentClassificationJobsError::TooManyRequests(err.msg),
                     )
                 }
@@ -4178,24 +4350,28 @@ impl ListDocumentClassifiersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentClassifiersError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentClassifiersError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentClassifiersError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentClassifiersError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4236,22 +4412,26 @@ impl ListDominantLanguageDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDominantLanguageDetectionJobsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDominantLanguageDetectionJobsError::InvalidFilter(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDominantLanguageDetectionJobsError::InvalidRequest(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDominantLanguageDetectionJobsError::TooManyRequests(err.msg),
                     )
                 }
@@ -4298,13 +4478,13 @@ impl ListEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEndpointsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListEndpointsError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEndpointsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListEndpointsError::InvalidRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListEndpointsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4342,24 +4522,28 @@ impl ListEntitiesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionJobsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4398,22 +4582,28 @@ impl ListEntityRecognizersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntityRecognizersError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEntityRecognizersError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntityRecognizersError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntityRecognizersError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4454,24 +4644,28 @@ impl ListKeyPhrasesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListKeyPhrasesDetectionJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListKeyPhrasesDetectionJobsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListKeyPhrasesDetectionJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListKeyPhrasesDetectionJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4512,24 +4706,28 @@ impl ListSentimentDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSentimentDetectionJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSentimentDetectionJobsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSentimentDetectionJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSentimentDetectionJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4566,15 +4764,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4612,24 +4817,28 @@ impl ListTopicsDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicsDetectionJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicsDetectionJobsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicsDetectionJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicsDetectionJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4674,32 +4883,38 @@ impl StartDocumentClassificationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentClassificationJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentClassificationJobError::InvalidRequest(err.msg),
                     )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentClassificationJobError::KmsKeyValidation(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentClassificationJobError::ResourceNotFound(err.msg),
                     )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentClassificationJobError::ResourceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentClassificationJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -4756,22 +4971,26 @@ impl StartDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDominantLanguageDetectionJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDominantLanguageDetectionJobError::InvalidRequest(err.msg),
                     )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDominantLanguageDetectionJobError::KmsKeyValidation(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDominantLanguageDetectionJobError::TooManyRequests(err.msg),
                     )
                 }
@@ -4824,34 +5043,40 @@ impl StartEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionJobError::KmsKeyValidation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartEntitiesDetectionJobError::ResourceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4896,24 +5121,28 @@ impl StartKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartKeyPhrasesDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartKeyPhrasesDetectionJobError::KmsKeyValidation(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartKeyPhrasesDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4954,24 +5183,28 @@ impl StartSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSentimentDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSentimentDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSentimentDetectionJobError::KmsKeyValidation(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSentimentDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5010,24 +5243,28 @@ impl StartTopicsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTopicsDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTopicsDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::KmsKeyValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTopicsDetectionJobError::KmsKeyValidation(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTopicsDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5066,17 +5303,20 @@ impl StopDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDominantLanguageDetectionJobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDominantLanguageDetectionJobError::InvalidRequest(err.msg),
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDominantLanguageDetectionJobError::JobNotFound(err.msg),
                     )
                 }
@@ -5118,19 +5358,22 @@ impl StopEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopEntitiesDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopEntitiesDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopEntitiesDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5168,19 +5411,22 @@ impl StopKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopKeyPhrasesDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopKeyPhrasesDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5216,19 +5462,22 @@ impl StopSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopSentimentDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopSentimentDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::JobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopSentimentDetectionJobError::JobNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5268,22 +5517,26 @@ impl StopTrainingDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopTrainingDocumentClassifierError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopTrainingDocumentClassifierError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopTrainingDocumentClassifierError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopTrainingDocumentClassifierError::TooManyRequests(err.msg),
                     )
                 }
@@ -5334,22 +5587,26 @@ impl StopTrainingEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopTrainingEntityRecognizerError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopTrainingEntityRecognizerError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopTrainingEntityRecognizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopTrainingEntityRecognizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopTrainingEntityRecognizerError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopTrainingEntityRecognizerError::TooManyRequests(err.msg),
                     )
                 }
@@ -5394,19 +5651,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5448,21 +5708,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyTagKeysException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTagKeys(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyTagKeys(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5508,27 +5772,37 @@ impl UpdateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEndpointError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateEndpointError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateEndpointError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateEndpointError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5909,6 +6183,7 @@ impl ComprehendClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5924,6 +6199,7 @@ impl ComprehendClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendClient {
         ComprehendClient { client, region }
     }
@@ -5938,6 +6214,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDetectDominantLanguage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.BatchDetectDominantLanguage",
@@ -5960,6 +6239,9 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectEntitiesRequest,
     ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDetectEntities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5978,6 +6260,9 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectKeyPhrasesRequest,
     ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDetectKeyPhrases");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5997,6 +6282,9 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectSentimentRequest,
     ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDetectSentiment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSentiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6016,6 +6304,9 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectSyntaxRequest,
     ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDetectSyntax");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSyntax");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6034,6 +6325,9 @@ impl Comprehend for ComprehendClient {
         input: ClassifyDocumentRequest,
     ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ClassifyDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.ClassifyDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6052,6 +6346,9 @@ impl Comprehend for ComprehendClient {
         input: CreateDocumentClassifierRequest,
     ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDocumentClassifier");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.CreateDocumentClassifier",
@@ -6074,6 +6371,9 @@ impl Comprehend for ComprehendClient {
         input: CreateEndpointRequest,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6092,6 +6392,9 @@ impl Comprehend for ComprehendClient {
         input: CreateEntityRecognizerRequest,
     ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEntityRecognizer");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6111,6 +6414,9 @@ impl Comprehend for ComprehendClient {
         input: DeleteDocumentClassifierRequest,
     ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDocumentClassifier");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DeleteDocumentClassifier",
@@ -6133,6 +6439,9 @@ impl Comprehend for ComprehendClient {
         input: DeleteEndpointRequest,
     ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6151,6 +6460,9 @@ impl Comprehend for ComprehendClient {
         input: DeleteEntityRecognizerRequest,
     ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEntityRecognizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6173,6 +6485,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeDocumentClassificationJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDocumentClassificationJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeDocumentClassificationJob",
@@ -6199,6 +6514,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDocumentClassifier");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeDocumentClassifier",
@@ -6224,6 +6542,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeDominantLanguageDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDominantLanguageDetectionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeDominantLanguageDetectionJob",
@@ -6249,6 +6570,9 @@ impl Comprehend for ComprehendClient {
         input: DescribeEndpointRequest,
     ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Comprehend_20171127.DescribeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6268,6 +6592,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEntitiesDetectionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeEntitiesDetectionJob",
@@ -6290,6 +6617,9 @@ impl Comprehend for ComprehendClient {
         input: DescribeEntityRecognizerRequest,
     ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEntityRecognizer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeEntityRecognizer",
@@ -6315,6 +6645,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeKeyPhrasesDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeKeyPhrasesDetectionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeKeyPhrasesDetectionJob",
@@ -6340,6 +6673,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeSentimentDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSentimentDetectionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeSentimentDetectionJob",
@@ -6363,6 +6699,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTopicsDetectionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeTopicsDetectionJob",
@@ -6385,6 +6724,9 @@ impl Comprehend for ComprehendClient {
         input: DetectDominantLanguageRequest,
     ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectDominantLanguage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DetectDominantLanguage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6404,6 +6746,9 @@ impl Comprehend for ComprehendClient {
         input: DetectEntitiesRequest,
     ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectEntities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6422,6 +6767,9 @@ impl Comprehend for ComprehendClient {
         input: DetectKeyPhrasesRequest,
     ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectKeyPhrases");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DetectKeyPhrases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6440,6 +6788,9 @@ impl Comprehend for ComprehendClient {
         input: DetectSentimentRequest,
     ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectSentiment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSentiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6458,6 +6809,9 @@ impl Comprehend for ComprehendClient {
         input: DetectSyntaxRequest,
     ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectSyntax");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSyntax");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6479,6 +6833,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<ListDocumentClassificationJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDocumentClassificationJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListDocumentClassificationJobs",
@@ -6501,6 +6858,9 @@ impl Comprehend for ComprehendClient {
         input: ListDocumentClassifiersRequest,
     ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDocumentClassifiers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListDocumentClassifiers",
@@ -6526,6 +6886,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<ListDominantLanguageDetectionJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDominantLanguageDetectionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListDominantLanguageDetectionJobs",
@@ -6551,6 +6914,9 @@ impl Comprehend for ComprehendClient {
         input: ListEndpointsRequest,
     ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Comprehend_20171127.ListEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6570,6 +6936,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEntitiesDetectionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListEntitiesDetectionJobs",
@@ -6592,6 +6961,9 @@ impl Comprehend for ComprehendClient {
         input: ListEntityRecognizersRequest,
     ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEntityRecognizers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Comprehend_20171127.ListEntityRecognizers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6612,6 +6984,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListKeyPhrasesDetectionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListKeyPhrasesDetectionJobs",
@@ -6635,6 +7010,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSentimentDetectionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListSentimentDetectionJobs",
@@ -6657,6 +7035,9 @@ impl Comprehend for ComprehendClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Comprehend_20171127.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6675,6 +7056,9 @@ impl Comprehend for ComprehendClient {
         input: ListTopicsDetectionJobsRequest,
     ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTopicsDetectionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListTopicsDetectionJobs",
@@ -6700,6 +7084,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<StartDocumentClassificationJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDocumentClassificationJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartDocumentClassificationJob",
@@ -6725,6 +7112,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<StartDominantLanguageDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDominantLanguageDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartDominantLanguageDetectionJob",
@@ -6751,6 +7141,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartEntitiesDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartEntitiesDetectionJob",
@@ -6774,6 +7167,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartKeyPhrasesDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartKeyPhrasesDetectionJob",
@@ -6797,6 +7193,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartSentimentDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartSentimentDetectionJob",
@@ -6819,6 +7218,9 @@ impl Comprehend for ComprehendClient {
         input: StartTopicsDetectionJobRequest,
     ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTopicsDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartTopicsDetectionJob",
@@ -6844,6 +7246,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<StopDominantLanguageDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopDominantLanguageDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopDominantLanguageDetectionJob",
@@ -6869,6 +7274,9 @@ impl Comprehend for ComprehendClient {
         input: StopEntitiesDetectionJobRequest,
     ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopEntitiesDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopEntitiesDetectionJob",
@@ -6892,6 +7300,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopKeyPhrasesDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopKeyPhrasesDetectionJob",
@@ -6915,6 +7326,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopSentimentDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopSentimentDetectionJob",
@@ -6940,6 +7354,9 @@ impl Comprehend for ComprehendClient {
         RusotoError<StopTrainingDocumentClassifierError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTrainingDocumentClassifier");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopTrainingDocumentClassifier",
@@ -6963,6 +7380,9 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTrainingEntityRecognizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopTrainingEntityRecognizer",
@@ -6985,6 +7405,9 @@ impl Comprehend for ComprehendClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7003,6 +7426,9 @@ impl Comprehend for ComprehendClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7021,6 +7447,9 @@ impl Comprehend for ComprehendClient {
         input: UpdateEndpointRequest,
     ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Comprehend_20171127.UpdateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7033,3 +7462,645 @@ impl Comprehend for ComprehendClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Comprehend API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ComprehendClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ComprehendBlockingClient {
+    client: ComprehendClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ComprehendBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ComprehendBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ComprehendBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComprehendBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ComprehendBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ComprehendBlockingClient {
+        ComprehendBlockingClient {
+            client: ComprehendClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Determines the dominant language of the input text for a batch of documents. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+    ///
+    /// See `Comprehend::batch_detect_dominant_language`.
+    pub fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_detect_dominant_language(input))
+    }
+
+    /// <p>Inspects the text of a batch of documents for named entities and returns information about them. For more information about named entities, see <a>how-entities</a> </p>
+    ///
+    /// See `Comprehend::batch_detect_entities`.
+    pub fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_entities(input))
+    }
+
+    /// <p>Detects the key noun phrases found in a batch of documents.</p>
+    ///
+    /// See `Comprehend::batch_detect_key_phrases`.
+    pub fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_key_phrases(input))
+    }
+
+    /// <p>Inspects a batch of documents and returns an inference of the prevailing sentiment, <code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>, in each one.</p>
+    ///
+    /// See `Comprehend::batch_detect_sentiment`.
+    pub fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_sentiment(input))
+    }
+
+    /// <p>Inspects the text of a batch of documents for the syntax and part of speech of the words in the document and returns information about them. For more information, see <a>how-syntax</a>.</p>
+    ///
+    /// See `Comprehend::batch_detect_syntax`.
+    pub fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_syntax(input))
+    }
+
+    /// <p>Creates a new document classification request to analyze a single document in real-time, using a previously created and trained custom model and an endpoint.</p>
+    ///
+    /// See `Comprehend::classify_document`.
+    pub fn classify_document(
+        &self,
+        input: ClassifyDocumentRequest,
+    ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
+        self.runtime.block_on(self.client.classify_document(input))
+    }
+
+    /// <p>Creates a new document classifier that you can use to categorize documents. To create a classifier, you provide a set of training documents that labeled with the categories that you want to use. After the classifier is trained you can use it to categorize a set of labeled documents into the categories. For more information, see <a>how-document-classification</a>.</p>
+    ///
+    /// See `Comprehend::create_document_classifier`.
+    pub fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
+        self.runtime
+            .block_on(self.client.create_document_classifier(input))
+    }
+
+    /// <p>Creates a model-specific endpoint for synchronous inference for a previously trained custom model </p>
+    ///
+    /// See `Comprehend::create_endpoint`.
+    pub fn create_endpoint(
+        &self,
+        input: CreateEndpointRequest,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        self.runtime.block_on(self.client.create_endpoint(input))
+    }
+
+    /// <p>Creates an entity recognizer using submitted files. After your <code>CreateEntityRecognizer</code> request is submitted, you can check job status using the API. </p>
+    ///
+    /// See `Comprehend::create_entity_recognizer`.
+    pub fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
+        self.runtime
+            .block_on(self.client.create_entity_recognizer(input))
+    }
+
+    /// <p>Deletes a previously created document classifier</p> <p>Only those classifiers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the classifier into a DELETING state, and it is then removed by a background job. Once removed, the classifier disappears from your account and is no longer available for use. </p>
+    ///
+    /// See `Comprehend::delete_document_classifier`.
+    pub fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
+        self.runtime
+            .block_on(self.client.delete_document_classifier(input))
+    }
+
+    /// <p>Deletes a model-specific endpoint for a previously-trained custom model. All endpoints must be deleted in order for the model to be deleted.</p>
+    ///
+    /// See `Comprehend::delete_endpoint`.
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointRequest,
+    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// <p>Deletes an entity recognizer.</p> <p>Only those recognizers that are in terminated states (IN_ERROR, TRAINED) will be deleted. If an active inference job is using the model, a <code>ResourceInUseException</code> will be returned.</p> <p>This is an asynchronous action that puts the recognizer into a DELETING state, and it is then removed by a background job. Once removed, the recognizer disappears from your account and is no longer available for use. </p>
+    ///
+    /// See `Comprehend::delete_entity_recognizer`.
+    pub fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
+        self.runtime
+            .block_on(self.client.delete_entity_recognizer(input))
+    }
+
+    /// <p>Gets the properties associated with a document classification job. Use this operation to get the status of a classification job.</p>
+    ///
+    /// See `Comprehend::describe_document_classification_job`.
+    pub fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> Result<
+        DescribeDocumentClassificationJobResponse,
+        RusotoError<DescribeDocumentClassificationJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_document_classification_job(input))
+    }
+
+    /// <p>Gets the properties associated with a document classifier.</p>
+    ///
+    /// See `Comprehend::describe_document_classifier`.
+    pub fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_document_classifier(input))
+    }
+
+    /// <p>Gets the properties associated with a dominant language detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `Comprehend::describe_dominant_language_detection_job`.
+    pub fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        DescribeDominantLanguageDetectionJobResponse,
+        RusotoError<DescribeDominantLanguageDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_dominant_language_detection_job(input))
+    }
+
+    /// <p>Gets the properties associated with a specific endpoint. Use this operation to get the status of an endpoint.</p>
+    ///
+    /// See `Comprehend::describe_endpoint`.
+    pub fn describe_endpoint(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
+        self.runtime.block_on(self.client.describe_endpoint(input))
+    }
+
+    /// <p>Gets the properties associated with an entities detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `Comprehend::describe_entities_detection_job`.
+    pub fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_entities_detection_job(input))
+    }
+
+    /// <p>Provides details about an entity recognizer including status, S3 buckets containing training data, recognizer metadata, metrics, and so on.</p>
+    ///
+    /// See `Comprehend::describe_entity_recognizer`.
+    pub fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
+        self.runtime
+            .block_on(self.client.describe_entity_recognizer(input))
+    }
+
+    /// <p>Gets the properties associated with a key phrases detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `Comprehend::describe_key_phrases_detection_job`.
+    pub fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> Result<
+        DescribeKeyPhrasesDetectionJobResponse,
+        RusotoError<DescribeKeyPhrasesDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_key_phrases_detection_job(input))
+    }
+
+    /// <p>Gets the properties associated with a sentiment detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `Comprehend::describe_sentiment_detection_job`.
+    pub fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> Result<
+        DescribeSentimentDetectionJobResponse,
+        RusotoError<DescribeSentimentDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_sentiment_detection_job(input))
+    }
+
+    /// <p>Gets the properties associated with a topic detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `Comprehend::describe_topics_detection_job`.
+    pub fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_topics_detection_job(input))
+    }
+
+    /// <p>Determines the dominant language of the input text. For a list of languages that Amazon Comprehend can detect, see <a href="https://docs.aws.amazon.com/comprehend/latest/dg/how-languages.html">Amazon Comprehend Supported Languages</a>. </p>
+    ///
+    /// See `Comprehend::detect_dominant_language`.
+    pub fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
+        self.runtime
+            .block_on(self.client.detect_dominant_language(input))
+    }
+
+    /// <p>Inspects text for named entities, and returns information about them. For more information, about named entities, see <a>how-entities</a>. </p>
+    ///
+    /// See `Comprehend::detect_entities`.
+    pub fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+        self.runtime.block_on(self.client.detect_entities(input))
+    }
+
+    /// <p>Detects the key noun phrases found in the text. </p>
+    ///
+    /// See `Comprehend::detect_key_phrases`.
+    pub fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
+        self.runtime.block_on(self.client.detect_key_phrases(input))
+    }
+
+    /// <p>Inspects text and returns an inference of the prevailing sentiment (<code>POSITIVE</code>, <code>NEUTRAL</code>, <code>MIXED</code>, or <code>NEGATIVE</code>). </p>
+    ///
+    /// See `Comprehend::detect_sentiment`.
+    pub fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
+        self.runtime.block_on(self.client.detect_sentiment(input))
+    }
+
+    /// <p>Inspects text for syntax and the part of speech of words in the document. For more information, <a>how-syntax</a>.</p>
+    ///
+    /// See `Comprehend::detect_syntax`.
+    pub fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
+        self.runtime.block_on(self.client.detect_syntax(input))
+    }
+
+    /// <p>Gets a list of the documentation classification jobs that you have submitted.</p>
+    ///
+    /// See `Comprehend::list_document_classification_jobs`.
+    pub fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> Result<
+        ListDocumentClassificationJobsResponse,
+        RusotoError<ListDocumentClassificationJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_document_classification_jobs(input))
+    }
+
+    /// <p>Gets a list of the document classifiers that you have created.</p>
+    ///
+    /// See `Comprehend::list_document_classifiers`.
+    pub fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
+        self.runtime
+            .block_on(self.client.list_document_classifiers(input))
+    }
+
+    /// <p>Gets a list of the dominant language detection jobs that you have submitted.</p>
+    ///
+    /// See `Comprehend::list_dominant_language_detection_jobs`.
+    pub fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> Result<
+        ListDominantLanguageDetectionJobsResponse,
+        RusotoError<ListDominantLanguageDetectionJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_dominant_language_detection_jobs(input))
+    }
+
+    /// <p>Gets a list of all existing endpoints that you've created.</p>
+    ///
+    /// See `Comprehend::list_endpoints`.
+    pub fn list_endpoints(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
+        self.runtime.block_on(self.client.list_endpoints(input))
+    }
+
+    /// <p>Gets a list of the entity detection jobs that you have submitted.</p>
+    ///
+    /// See `Comprehend::list_entities_detection_jobs`.
+    pub fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_entities_detection_jobs(input))
+    }
+
+    /// <p>Gets a list of the properties of all entity recognizers that you created, including recognizers currently in training. Allows you to filter the list of recognizers based on criteria such as status and submission time. This call returns up to 500 entity recognizers in the list, with a default number of 100 recognizers in the list.</p> <p>The results of this list are not in any particular order. Please get the list and sort locally if needed.</p>
+    ///
+    /// See `Comprehend::list_entity_recognizers`.
+    pub fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
+        self.runtime
+            .block_on(self.client.list_entity_recognizers(input))
+    }
+
+    /// <p>Get a list of key phrase detection jobs that you have submitted.</p>
+    ///
+    /// See `Comprehend::list_key_phrases_detection_jobs`.
+    pub fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_key_phrases_detection_jobs(input))
+    }
+
+    /// <p>Gets a list of sentiment detection jobs that you have submitted.</p>
+    ///
+    /// See `Comprehend::list_sentiment_detection_jobs`.
+    pub fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_sentiment_detection_jobs(input))
+    }
+
+    /// <p>Lists all tags associated with a given Amazon Comprehend resource. </p>
+    ///
+    /// See `Comprehend::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Gets a list of the topic detection jobs that you have submitted.</p>
+    ///
+    /// See `Comprehend::list_topics_detection_jobs`.
+    pub fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_topics_detection_jobs(input))
+    }
+
+    /// <p>Starts an asynchronous document classification job. Use the operation to track the progress of the job.</p>
+    ///
+    /// See `Comprehend::start_document_classification_job`.
+    pub fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> Result<
+        StartDocumentClassificationJobResponse,
+        RusotoError<StartDocumentClassificationJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_document_classification_job(input))
+    }
+
+    /// <p>Starts an asynchronous dominant language detection job for a collection of documents. Use the operation to track the status of a job.</p>
+    ///
+    /// See `Comprehend::start_dominant_language_detection_job`.
+    pub fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        StartDominantLanguageDetectionJobResponse,
+        RusotoError<StartDominantLanguageDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_dominant_language_detection_job(input))
+    }
+
+    /// <p>Starts an asynchronous entity detection job for a collection of documents. Use the operation to track the status of a job.</p> <p>This API can be used for either standard entity detection or custom entity recognition. In order to be used for custom entity recognition, the optional <code>EntityRecognizerArn</code> must be used in order to provide access to the recognizer being used to detect the custom entity.</p>
+    ///
+    /// See `Comprehend::start_entities_detection_job`.
+    pub fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_entities_detection_job(input))
+    }
+
+    /// <p>Starts an asynchronous key phrase detection job for a collection of documents. Use the operation to track the status of a job.</p>
+    ///
+    /// See `Comprehend::start_key_phrases_detection_job`.
+    pub fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_key_phrases_detection_job(input))
+    }
+
+    /// <p>Starts an asynchronous sentiment detection job for a collection of documents. use the operation to track the status of a job.</p>
+    ///
+    /// See `Comprehend::start_sentiment_detection_job`.
+    pub fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_sentiment_detection_job(input))
+    }
+
+    /// <p>Starts an asynchronous topic detection job. Use the <code>DescribeTopicDetectionJob</code> operation to track the status of a job.</p>
+    ///
+    /// See `Comprehend::start_topics_detection_job`.
+    pub fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.start_topics_detection_job(input))
+    }
+
+    /// <p>Stops a dominant language detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    ///
+    /// See `Comprehend::stop_dominant_language_detection_job`.
+    pub fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        StopDominantLanguageDetectionJobResponse,
+        RusotoError<StopDominantLanguageDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.stop_dominant_language_detection_job(input))
+    }
+
+    /// <p>Stops an entities detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    ///
+    /// See `Comprehend::stop_entities_detection_job`.
+    pub fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.stop_entities_detection_job(input))
+    }
+
+    /// <p>Stops a key phrases detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    ///
+    /// See `Comprehend::stop_key_phrases_detection_job`.
+    pub fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_key_phrases_detection_job(input))
+    }
+
+    /// <p>Stops a sentiment detection job in progress.</p> <p>If the job state is <code>IN_PROGRESS</code> the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state; otherwise the job is be stopped and put into the <code>STOPPED</code> state.</p> <p>If the job is in the <code>COMPLETED</code> or <code>FAILED</code> state when you call the <code>StopDominantLanguageDetectionJob</code> operation, the operation returns a 400 Internal Request Exception. </p> <p>When a job is stopped, any documents already processed are written to the output location.</p>
+    ///
+    /// See `Comprehend::stop_sentiment_detection_job`.
+    pub fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_sentiment_detection_job(input))
+    }
+
+    /// <p>Stops a document classifier training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and put into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body. </p>
+    ///
+    /// See `Comprehend::stop_training_document_classifier`.
+    pub fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> Result<
+        StopTrainingDocumentClassifierResponse,
+        RusotoError<StopTrainingDocumentClassifierError>,
+    > {
+        self.runtime
+            .block_on(self.client.stop_training_document_classifier(input))
+    }
+
+    /// <p>Stops an entity recognizer training job while in progress.</p> <p>If the training job state is <code>TRAINING</code>, the job is marked for termination and put into the <code>STOP_REQUESTED</code> state. If the training job completes before it can be stopped, it is put into the <code>TRAINED</code>; otherwise the training job is stopped and putted into the <code>STOPPED</code> state and the service sends back an HTTP 200 response with an empty HTTP body.</p>
+    ///
+    /// See `Comprehend::stop_training_entity_recognizer`.
+    pub fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_training_entity_recognizer(input))
+    }
+
+    /// <p>Associates a specific tag with an Amazon Comprehend resource. A tag is a key-value pair that adds as a metadata to a resource used by Amazon Comprehend. For example, a tag with "Sales" as the key might be added to a resource to indicate its use by the sales department. </p>
+    ///
+    /// See `Comprehend::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a specific tag associated with an Amazon Comprehend resource. </p>
+    ///
+    /// See `Comprehend::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates information about the specified endpoint.</p>
+    ///
+    /// See `Comprehend::update_endpoint`.
+    pub fn update_endpoint(
+        &self,
+        input: UpdateEndpointRequest,
+    ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
+        self.runtime.block_on(self.client.update_endpoint(input))
+    }
+}
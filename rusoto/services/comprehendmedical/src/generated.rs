@@ -1064,22 +1064,26 @@ impl DescribeEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEntitiesDetectionV2JobError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEntitiesDetectionV2JobError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEntitiesDetectionV2JobError::TooManyRequests(err.msg),
                     )
                 }
@@ -1130,24 +1134,28 @@ impl DescribeICD10CMInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeICD10CMInferenceJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeICD10CMInferenceJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeICD10CMInferenceJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeICD10CMInferenceJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeICD10CMInferenceJobError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeICD10CMInferenceJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeICD10CMInferenceJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1186,24 +1194,28 @@ impl DescribePHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePHIDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePHIDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePHIDetectionJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePHIDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1244,24 +1256,28 @@ impl DescribeRxNormInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRxNormInferenceJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRxNormInferenceJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRxNormInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRxNormInferenceJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1304,24 +1320,34 @@ impl DetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DetectEntitiesError::InternalServer(err.msg))
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidEncoding(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::InvalidEncoding(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DetectEntitiesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesError::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectEntitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1366,24 +1392,40 @@ impl DetectEntitiesV2Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesV2Error::InternalServer(err.msg),
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InvalidEncoding(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesV2Error::InvalidEncoding(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesV2Error::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesV2Error::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::TextSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesV2Error::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectEntitiesV2Error::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1428,22 +1470,25 @@ impl DetectPHIError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectPHIError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DetectPHIError::InternalServer(err.msg))
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectPHIError::InvalidEncoding(err.msg))
+                    return RusotoError::service(&res, DetectPHIError::InvalidEncoding(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectPHIError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DetectPHIError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectPHIError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DetectPHIError::ServiceUnavailable(err.msg))
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectPHIError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectPHIError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectPHIError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DetectPHIError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1488,22 +1533,28 @@ impl InferICD10CMError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(InferICD10CMError::InternalServer(err.msg))
+                    return RusotoError::service(&res, InferICD10CMError::InternalServer(err.msg))
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(InferICD10CMError::InvalidEncoding(err.msg))
+                    return RusotoError::service(&res, InferICD10CMError::InvalidEncoding(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InferICD10CMError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, InferICD10CMError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InferICD10CMError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InferICD10CMError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(InferICD10CMError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InferICD10CMError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InferICD10CMError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, InferICD10CMError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1548,22 +1599,28 @@ impl InferRxNormError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(InferRxNormError::InternalServer(err.msg))
+                    return RusotoError::service(&res, InferRxNormError::InternalServer(err.msg))
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(InferRxNormError::InvalidEncoding(err.msg))
+                    return RusotoError::service(&res, InferRxNormError::InvalidEncoding(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InferRxNormError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, InferRxNormError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InferRxNormError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InferRxNormError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(InferRxNormError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InferRxNormError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InferRxNormError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, InferRxNormError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1604,19 +1661,22 @@ impl ListEntitiesDetectionV2JobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionV2JobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionV2JobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitiesDetectionV2JobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1652,19 +1712,22 @@ impl ListICD10CMInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListICD10CMInferenceJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListICD10CMInferenceJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListICD10CMInferenceJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListICD10CMInferenceJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListICD10CMInferenceJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListICD10CMInferenceJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1700,15 +1763,22 @@ impl ListPHIDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPHIDetectionJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPHIDetectionJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPHIDetectionJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1744,19 +1814,22 @@ impl ListRxNormInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRxNormInferenceJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRxNormInferenceJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRxNormInferenceJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRxNormInferenceJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRxNormInferenceJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRxNormInferenceJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1796,24 +1869,28 @@ impl StartEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionV2JobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionV2JobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartEntitiesDetectionV2JobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1852,24 +1929,28 @@ impl StartICD10CMInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartICD10CMInferenceJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartICD10CMInferenceJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartICD10CMInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartICD10CMInferenceJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1908,20 +1989,28 @@ impl StartPHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPHIDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPHIDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPHIDetectionJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPHIDetectionJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1960,24 +2049,28 @@ impl StartRxNormInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRxNormInferenceJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRxNormInferenceJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRxNormInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRxNormInferenceJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2016,19 +2109,22 @@ impl StopEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopEntitiesDetectionV2JobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopEntitiesDetectionV2JobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2064,19 +2160,22 @@ impl StopICD10CMInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopICD10CMInferenceJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopICD10CMInferenceJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopICD10CMInferenceJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopICD10CMInferenceJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopICD10CMInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopICD10CMInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2112,15 +2211,22 @@ impl StopPHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopPHIDetectionJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopPHIDetectionJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopPHIDetectionJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2156,19 +2262,22 @@ impl StopRxNormInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopRxNormInferenceJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRxNormInferenceJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopRxNormInferenceJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRxNormInferenceJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopRxNormInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRxNormInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2338,6 +2447,7 @@ impl ComprehendMedicalClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2353,6 +2463,7 @@ impl ComprehendMedicalClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendMedicalClient {
         ComprehendMedicalClient { client, region }
     }
@@ -2369,6 +2480,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         RusotoError<DescribeEntitiesDetectionV2JobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEntitiesDetectionV2Job");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribeEntitiesDetectionV2Job",
@@ -2392,6 +2506,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<DescribeICD10CMInferenceJobResponse, RusotoError<DescribeICD10CMInferenceJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeICD10CMInferenceJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribeICD10CMInferenceJob",
@@ -2414,6 +2531,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DescribePHIDetectionJobRequest,
     ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePHIDetectionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribePHIDetectionJob",
@@ -2437,6 +2557,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<DescribeRxNormInferenceJobResponse, RusotoError<DescribeRxNormInferenceJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRxNormInferenceJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribeRxNormInferenceJob",
@@ -2459,6 +2582,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectEntitiesRequest,
     ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectEntities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2477,6 +2603,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectEntitiesV2Request,
     ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectEntitiesV2");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DetectEntitiesV2",
@@ -2498,6 +2627,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectPHIRequest,
     ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectPHI");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectPHI");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2516,6 +2648,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: InferICD10CMRequest,
     ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InferICD10CM");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferICD10CM");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2534,6 +2669,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: InferRxNormRequest,
     ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InferRxNorm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferRxNorm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2553,6 +2691,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEntitiesDetectionV2Jobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListEntitiesDetectionV2Jobs",
@@ -2575,6 +2716,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListICD10CMInferenceJobsRequest,
     ) -> Result<ListICD10CMInferenceJobsResponse, RusotoError<ListICD10CMInferenceJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListICD10CMInferenceJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListICD10CMInferenceJobs",
@@ -2597,6 +2741,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListPHIDetectionJobsRequest,
     ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPHIDetectionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListPHIDetectionJobs",
@@ -2619,6 +2766,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListRxNormInferenceJobsRequest,
     ) -> Result<ListRxNormInferenceJobsResponse, RusotoError<ListRxNormInferenceJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRxNormInferenceJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListRxNormInferenceJobs",
@@ -2642,6 +2792,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartEntitiesDetectionV2Job");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartEntitiesDetectionV2Job",
@@ -2664,6 +2817,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartICD10CMInferenceJobRequest,
     ) -> Result<StartICD10CMInferenceJobResponse, RusotoError<StartICD10CMInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartICD10CMInferenceJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartICD10CMInferenceJob",
@@ -2686,6 +2842,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartPHIDetectionJobRequest,
     ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartPHIDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartPHIDetectionJob",
@@ -2708,6 +2867,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartRxNormInferenceJobRequest,
     ) -> Result<StartRxNormInferenceJobResponse, RusotoError<StartRxNormInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartRxNormInferenceJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartRxNormInferenceJob",
@@ -2731,6 +2893,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopEntitiesDetectionV2Job");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopEntitiesDetectionV2Job",
@@ -2753,6 +2918,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopICD10CMInferenceJobRequest,
     ) -> Result<StopICD10CMInferenceJobResponse, RusotoError<StopICD10CMInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopICD10CMInferenceJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopICD10CMInferenceJob",
@@ -2775,6 +2943,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopPHIDetectionJobRequest,
     ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopPHIDetectionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopPHIDetectionJob",
@@ -2796,6 +2967,9 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopRxNormInferenceJobRequest,
     ) -> Result<StopRxNormInferenceJobResponse, RusotoError<StopRxNormInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopRxNormInferenceJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopRxNormInferenceJob",
@@ -2812,3 +2986,290 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .deserialize::<StopRxNormInferenceJobResponse, _>()
     }
 }
+
+/// A blocking client for the ComprehendMedical API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ComprehendMedicalClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ComprehendMedicalBlockingClient {
+    client: ComprehendMedicalClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ComprehendMedicalBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ComprehendMedicalBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ComprehendMedicalBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComprehendMedicalBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ComprehendMedicalBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient {
+            client: ComprehendMedicalClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Gets the properties associated with a medical entities detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `ComprehendMedical::describe_entities_detection_v2_job`.
+    pub fn describe_entities_detection_v2_job(
+        &self,
+        input: DescribeEntitiesDetectionV2JobRequest,
+    ) -> Result<
+        DescribeEntitiesDetectionV2JobResponse,
+        RusotoError<DescribeEntitiesDetectionV2JobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_entities_detection_v2_job(input))
+    }
+
+    /// <p>Gets the properties associated with an InferICD10CM job. Use this operation to get the status of an inference job.</p>
+    ///
+    /// See `ComprehendMedical::describe_icd10cm_inference_job`.
+    pub fn describe_icd10cm_inference_job(
+        &self,
+        input: DescribeICD10CMInferenceJobRequest,
+    ) -> Result<DescribeICD10CMInferenceJobResponse, RusotoError<DescribeICD10CMInferenceJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_icd10cm_inference_job(input))
+    }
+
+    /// <p>Gets the properties associated with a protected health information (PHI) detection job. Use this operation to get the status of a detection job.</p>
+    ///
+    /// See `ComprehendMedical::describe_phi_detection_job`.
+    pub fn describe_phi_detection_job(
+        &self,
+        input: DescribePHIDetectionJobRequest,
+    ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.describe_phi_detection_job(input))
+    }
+
+    /// <p>Gets the properties associated with an InferRxNorm job. Use this operation to get the status of an inference job.</p>
+    ///
+    /// See `ComprehendMedical::describe_rx_norm_inference_job`.
+    pub fn describe_rx_norm_inference_job(
+        &self,
+        input: DescribeRxNormInferenceJobRequest,
+    ) -> Result<DescribeRxNormInferenceJobResponse, RusotoError<DescribeRxNormInferenceJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_rx_norm_inference_job(input))
+    }
+
+    /// <p>The <code>DetectEntities</code> operation is deprecated. You should use the <a>DetectEntitiesV2</a> operation instead.</p> <p> Inspects the clinical text for a variety of medical entities and returns specific information about them such as entity category, location, and confidence score on that information .</p>
+    ///
+    /// See `ComprehendMedical::detect_entities`.
+    pub fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+        self.runtime.block_on(self.client.detect_entities(input))
+    }
+
+    /// <p>Inspects the clinical text for a variety of medical entities and returns specific information about them such as entity category, location, and confidence score on that information. Amazon Comprehend Medical only detects medical entities in English language texts.</p> <p>The <code>DetectEntitiesV2</code> operation replaces the <a>DetectEntities</a> operation. This new action uses a different model for determining the entities in your medical text and changes the way that some entities are returned in the output. You should use the <code>DetectEntitiesV2</code> operation in all new applications.</p> <p>The <code>DetectEntitiesV2</code> operation returns the <code>Acuity</code> and <code>Direction</code> entities as attributes instead of types. </p>
+    ///
+    /// See `ComprehendMedical::detect_entities_v2`.
+    pub fn detect_entities_v2(
+        &self,
+        input: DetectEntitiesV2Request,
+    ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
+        self.runtime.block_on(self.client.detect_entities_v2(input))
+    }
+
+    /// <p> Inspects the clinical text for protected health information (PHI) entities and returns the entity category, location, and confidence score for each entity. Amazon Comprehend Medical only detects entities in English language texts.</p>
+    ///
+    /// See `ComprehendMedical::detect_phi`.
+    pub fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
+        self.runtime.block_on(self.client.detect_phi(input))
+    }
+
+    /// <p>InferICD10CM detects medical conditions as entities listed in a patient record and links those entities to normalized concept identifiers in the ICD-10-CM knowledge base from the Centers for Disease Control. Amazon Comprehend Medical only detects medical entities in English language texts.</p>
+    ///
+    /// See `ComprehendMedical::infer_icd10cm`.
+    pub fn infer_icd10cm(
+        &self,
+        input: InferICD10CMRequest,
+    ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
+        self.runtime.block_on(self.client.infer_icd10cm(input))
+    }
+
+    /// <p>InferRxNorm detects medications as entities listed in a patient record and links to the normalized concept identifiers in the RxNorm database from the National Library of Medicine. Amazon Comprehend Medical only detects medical entities in English language texts.</p>
+    ///
+    /// See `ComprehendMedical::infer_rx_norm`.
+    pub fn infer_rx_norm(
+        &self,
+        input: InferRxNormRequest,
+    ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
+        self.runtime.block_on(self.client.infer_rx_norm(input))
+    }
+
+    /// <p>Gets a list of medical entity detection jobs that you have submitted.</p>
+    ///
+    /// See `ComprehendMedical::list_entities_detection_v2_jobs`.
+    pub fn list_entities_detection_v2_jobs(
+        &self,
+        input: ListEntitiesDetectionV2JobsRequest,
+    ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_entities_detection_v2_jobs(input))
+    }
+
+    /// <p>Gets a list of InferICD10CM jobs that you have submitted.</p>
+    ///
+    /// See `ComprehendMedical::list_icd10cm_inference_jobs`.
+    pub fn list_icd10cm_inference_jobs(
+        &self,
+        input: ListICD10CMInferenceJobsRequest,
+    ) -> Result<ListICD10CMInferenceJobsResponse, RusotoError<ListICD10CMInferenceJobsError>> {
+        self.runtime
+            .block_on(self.client.list_icd10cm_inference_jobs(input))
+    }
+
+    /// <p>Gets a list of protected health information (PHI) detection jobs that you have submitted.</p>
+    ///
+    /// See `ComprehendMedical::list_phi_detection_jobs`.
+    pub fn list_phi_detection_jobs(
+        &self,
+        input: ListPHIDetectionJobsRequest,
+    ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_phi_detection_jobs(input))
+    }
+
+    /// <p>Gets a list of InferRxNorm jobs that you have submitted.</p>
+    ///
+    /// See `ComprehendMedical::list_rx_norm_inference_jobs`.
+    pub fn list_rx_norm_inference_jobs(
+        &self,
+        input: ListRxNormInferenceJobsRequest,
+    ) -> Result<ListRxNormInferenceJobsResponse, RusotoError<ListRxNormInferenceJobsError>> {
+        self.runtime
+            .block_on(self.client.list_rx_norm_inference_jobs(input))
+    }
+
+    /// <p>Starts an asynchronous medical entity detection job for a collection of documents. Use the <code>DescribeEntitiesDetectionV2Job</code> operation to track the status of a job.</p>
+    ///
+    /// See `ComprehendMedical::start_entities_detection_v2_job`.
+    pub fn start_entities_detection_v2_job(
+        &self,
+        input: StartEntitiesDetectionV2JobRequest,
+    ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_entities_detection_v2_job(input))
+    }
+
+    /// <p>Starts an asynchronous job to detect medical conditions and link them to the ICD-10-CM ontology. Use the <code>DescribeICD10CMInferenceJob</code> operation to track the status of a job.</p>
+    ///
+    /// See `ComprehendMedical::start_icd10cm_inference_job`.
+    pub fn start_icd10cm_inference_job(
+        &self,
+        input: StartICD10CMInferenceJobRequest,
+    ) -> Result<StartICD10CMInferenceJobResponse, RusotoError<StartICD10CMInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.start_icd10cm_inference_job(input))
+    }
+
+    /// <p>Starts an asynchronous job to detect protected health information (PHI). Use the <code>DescribePHIDetectionJob</code> operation to track the status of a job.</p>
+    ///
+    /// See `ComprehendMedical::start_phi_detection_job`.
+    pub fn start_phi_detection_job(
+        &self,
+        input: StartPHIDetectionJobRequest,
+    ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.start_phi_detection_job(input))
+    }
+
+    /// <p>Starts an asynchronous job to detect medication entities and link them to the RxNorm ontology. Use the <code>DescribeRxNormInferenceJob</code> operation to track the status of a job.</p>
+    ///
+    /// See `ComprehendMedical::start_rx_norm_inference_job`.
+    pub fn start_rx_norm_inference_job(
+        &self,
+        input: StartRxNormInferenceJobRequest,
+    ) -> Result<StartRxNormInferenceJobResponse, RusotoError<StartRxNormInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.start_rx_norm_inference_job(input))
+    }
+
+    /// <p>Stops a medical entities detection job in progress.</p>
+    ///
+    /// See `ComprehendMedical::stop_entities_detection_v2_job`.
+    pub fn stop_entities_detection_v2_job(
+        &self,
+        input: StopEntitiesDetectionV2JobRequest,
+    ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_entities_detection_v2_job(input))
+    }
+
+    /// <p>Stops an InferICD10CM inference job in progress.</p>
+    ///
+    /// See `ComprehendMedical::stop_icd10cm_inference_job`.
+    pub fn stop_icd10cm_inference_job(
+        &self,
+        input: StopICD10CMInferenceJobRequest,
+    ) -> Result<StopICD10CMInferenceJobResponse, RusotoError<StopICD10CMInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.stop_icd10cm_inference_job(input))
+    }
+
+    /// <p>Stops a protected health information (PHI) detection job in progress.</p>
+    ///
+    /// See `ComprehendMedical::stop_phi_detection_job`.
+    pub fn stop_phi_detection_job(
+        &self,
+        input: StopPHIDetectionJobRequest,
+    ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.stop_phi_detection_job(input))
+    }
+
+    /// <p>Stops an InferRxNorm inference job in progress.</p>
+    ///
+    /// See `ComprehendMedical::stop_rx_norm_inference_job`.
+    pub fn stop_rx_norm_inference_job(
+        &self,
+        input: StopRxNormInferenceJobRequest,
+    ) -> Result<StopRxNormInferenceJobResponse, RusotoError<StopRxNormInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.stop_rx_norm_inference_job(input))
+    }
+}
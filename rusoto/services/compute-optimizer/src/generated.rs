@@ -760,44 +760,52 @@ impl DescribeRecommendationExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::AccessDenied(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::MissingAuthenticationToken(err.msg),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::OptInRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRecommendationExportJobsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRecommendationExportJobsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecommendationExportJobsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -864,44 +872,52 @@ impl ExportAutoScalingGroupRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::AccessDenied(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::LimitExceeded(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::MissingAuthenticationToken(
                             err.msg,
                         ),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::OptInRequired(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportAutoScalingGroupRecommendationsError::Throttling(err.msg),
                     )
                 }
@@ -972,44 +988,52 @@ impl ExportEC2InstanceRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::AccessDenied(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::LimitExceeded(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::MissingAuthenticationToken(err.msg),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::OptInRequired(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportEC2InstanceRecommendationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ExportEC2InstanceRecommendationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportEC2InstanceRecommendationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1076,44 +1100,52 @@ impl GetAutoScalingGroupRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::AccessDenied(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::MissingAuthenticationToken(
                             err.msg,
                         ),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::OptInRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutoScalingGroupRecommendationsError::Throttling(err.msg),
                     )
                 }
@@ -1184,44 +1216,52 @@ impl GetEC2InstanceRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEC2InstanceRecommendationsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2InstanceRecommendationsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2InstanceRecommendationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2InstanceRecommendationsError::MissingAuthenticationToken(err.msg),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::OptInRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEC2InstanceRecommendationsError::OptInRequired(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2InstanceRecommendationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2InstanceRecommendationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEC2InstanceRecommendationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1282,44 +1322,52 @@ impl GetEC2RecommendationProjectedMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::AccessDenied(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::MissingAuthenticationToken(
                             err.msg,
                         ),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::OptInRequired(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEC2RecommendationProjectedMetricsError::Throttling(err.msg),
                     )
                 }
@@ -1384,28 +1432,40 @@ impl GetEnrollmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnrollmentStatusError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnrollmentStatusError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEnrollmentStatusError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEnrollmentStatusError::MissingAuthenticationToken(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEnrollmentStatusError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnrollmentStatusError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1456,39 +1516,46 @@ impl GetRecommendationSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationSummariesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationSummariesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecommendationSummariesError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecommendationSummariesError::MissingAuthenticationToken(err.msg),
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::OptInRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationSummariesError::OptInRequired(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRecommendationSummariesError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationSummariesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1540,30 +1607,40 @@ impl UpdateEnrollmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnrollmentStatusError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnrollmentStatusError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEnrollmentStatusError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEnrollmentStatusError::MissingAuthenticationToken(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnrollmentStatusError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEnrollmentStatusError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1680,6 +1757,7 @@ impl ComputeOptimizerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1695,6 +1773,7 @@ impl ComputeOptimizerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ComputeOptimizerClient {
         ComputeOptimizerClient { client, region }
     }
@@ -1711,6 +1790,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<DescribeRecommendationExportJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRecommendationExportJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.DescribeRecommendationExportJobs",
@@ -1739,6 +1821,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<ExportAutoScalingGroupRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExportAutoScalingGroupRecommendations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.ExportAutoScalingGroupRecommendations",
@@ -1767,6 +1852,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<ExportEC2InstanceRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExportEC2InstanceRecommendations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.ExportEC2InstanceRecommendations",
@@ -1795,6 +1883,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetAutoScalingGroupRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAutoScalingGroupRecommendations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetAutoScalingGroupRecommendations",
@@ -1823,6 +1914,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetEC2InstanceRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEC2InstanceRecommendations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEC2InstanceRecommendations",
@@ -1848,6 +1942,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetEC2RecommendationProjectedMetricsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEC2RecommendationProjectedMetrics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEC2RecommendationProjectedMetrics",
@@ -1872,6 +1969,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         &self,
     ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEnrollmentStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEnrollmentStatus",
@@ -1893,6 +1993,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
     ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRecommendationSummaries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetRecommendationSummaries",
@@ -1915,6 +2018,9 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         input: UpdateEnrollmentStatusRequest,
     ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEnrollmentStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.UpdateEnrollmentStatus",
@@ -1931,3 +2037,172 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             .deserialize::<UpdateEnrollmentStatusResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Compute Optimizer API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ComputeOptimizerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ComputeOptimizerBlockingClient {
+    client: ComputeOptimizerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ComputeOptimizerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ComputeOptimizerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ComputeOptimizerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComputeOptimizerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ComputeOptimizerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient {
+            client: ComputeOptimizerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Describes recommendation export jobs created in the last seven days.</p> <p>Use the <code>ExportAutoScalingGroupRecommendations</code> or <code>ExportEC2InstanceRecommendations</code> actions to request an export of your recommendations. Then use the <code>DescribeRecommendationExportJobs</code> action to view your export jobs.</p>
+    ///
+    /// See `ComputeOptimizer::describe_recommendation_export_jobs`.
+    pub fn describe_recommendation_export_jobs(
+        &self,
+        input: DescribeRecommendationExportJobsRequest,
+    ) -> Result<
+        DescribeRecommendationExportJobsResponse,
+        RusotoError<DescribeRecommendationExportJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_recommendation_export_jobs(input))
+    }
+
+    /// <p>Exports optimization recommendations for Auto Scaling groups.</p> <p>Recommendations are exported in a comma-separated values (.csv) file, and its metadata in a JavaScript Object Notation (.json) file, to an existing Amazon Simple Storage Service (Amazon S3) bucket that you specify. For more information, see <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/exporting-recommendations.html">Exporting Recommendations</a> in the <i>Compute Optimizer User Guide</i>.</p> <p>You can have only one Auto Scaling group export job in progress per AWS Region.</p>
+    ///
+    /// See `ComputeOptimizer::export_auto_scaling_group_recommendations`.
+    pub fn export_auto_scaling_group_recommendations(
+        &self,
+        input: ExportAutoScalingGroupRecommendationsRequest,
+    ) -> Result<
+        ExportAutoScalingGroupRecommendationsResponse,
+        RusotoError<ExportAutoScalingGroupRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.export_auto_scaling_group_recommendations(input))
+    }
+
+    /// <p>Exports optimization recommendations for Amazon EC2 instances.</p> <p>Recommendations are exported in a comma-separated values (.csv) file, and its metadata in a JavaScript Object Notation (.json) file, to an existing Amazon Simple Storage Service (Amazon S3) bucket that you specify. For more information, see <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/exporting-recommendations.html">Exporting Recommendations</a> in the <i>Compute Optimizer User Guide</i>.</p> <p>You can have only one Amazon EC2 instance export job in progress per AWS Region.</p>
+    ///
+    /// See `ComputeOptimizer::export_ec2_instance_recommendations`.
+    pub fn export_ec2_instance_recommendations(
+        &self,
+        input: ExportEC2InstanceRecommendationsRequest,
+    ) -> Result<
+        ExportEC2InstanceRecommendationsResponse,
+        RusotoError<ExportEC2InstanceRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.export_ec2_instance_recommendations(input))
+    }
+
+    /// <p>Returns Auto Scaling group recommendations.</p> <p>AWS Compute Optimizer currently generates recommendations for Auto Scaling groups that are configured to run instances of the M, C, R, T, and X instance families. The service does not generate recommendations for Auto Scaling groups that have a scaling policy attached to them, or that do not have the same values for desired, minimum, and maximum capacity. In order for Compute Optimizer to analyze your Auto Scaling groups, they must be of a fixed size. For more information, see the <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/what-is.html">AWS Compute Optimizer User Guide</a>.</p>
+    ///
+    /// See `ComputeOptimizer::get_auto_scaling_group_recommendations`.
+    pub fn get_auto_scaling_group_recommendations(
+        &self,
+        input: GetAutoScalingGroupRecommendationsRequest,
+    ) -> Result<
+        GetAutoScalingGroupRecommendationsResponse,
+        RusotoError<GetAutoScalingGroupRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_auto_scaling_group_recommendations(input))
+    }
+
+    /// <p>Returns Amazon EC2 instance recommendations.</p> <p>AWS Compute Optimizer currently generates recommendations for Amazon Elastic Compute Cloud (Amazon EC2) and Amazon EC2 Auto Scaling. It generates recommendations for M, C, R, T, and X instance families. For more information, see the <a href="https://docs.aws.amazon.com/compute-optimizer/latest/ug/what-is.html">AWS Compute Optimizer User Guide</a>.</p>
+    ///
+    /// See `ComputeOptimizer::get_ec2_instance_recommendations`.
+    pub fn get_ec2_instance_recommendations(
+        &self,
+        input: GetEC2InstanceRecommendationsRequest,
+    ) -> Result<
+        GetEC2InstanceRecommendationsResponse,
+        RusotoError<GetEC2InstanceRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_ec2_instance_recommendations(input))
+    }
+
+    /// <p>Returns the projected utilization metrics of Amazon EC2 instance recommendations.</p>
+    ///
+    /// See `ComputeOptimizer::get_ec2_recommendation_projected_metrics`.
+    pub fn get_ec2_recommendation_projected_metrics(
+        &self,
+        input: GetEC2RecommendationProjectedMetricsRequest,
+    ) -> Result<
+        GetEC2RecommendationProjectedMetricsResponse,
+        RusotoError<GetEC2RecommendationProjectedMetricsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_ec2_recommendation_projected_metrics(input))
+    }
+
+    /// <p>Returns the enrollment (opt in) status of an account to the AWS Compute Optimizer service.</p> <p>If the account is the master account of an organization, this action also confirms the enrollment status of member accounts within the organization.</p>
+    ///
+    /// See `ComputeOptimizer::get_enrollment_status`.
+    pub fn get_enrollment_status(
+        &self,
+    ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
+        self.runtime.block_on(self.client.get_enrollment_status())
+    }
+
+    /// <p>Returns the optimization findings for an account.</p> <p>For example, it returns the number of Amazon EC2 instances in an account that are under-provisioned, over-provisioned, or optimized. It also returns the number of Auto Scaling groups in an account that are not optimized, or optimized.</p>
+    ///
+    /// See `ComputeOptimizer::get_recommendation_summaries`.
+    pub fn get_recommendation_summaries(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_recommendation_summaries(input))
+    }
+
+    /// <p>Updates the enrollment (opt in) status of an account to the AWS Compute Optimizer service.</p> <p>If the account is a master account of an organization, this action can also be used to enroll member accounts within the organization.</p>
+    ///
+    /// See `ComputeOptimizer::update_enrollment_status`.
+    pub fn update_enrollment_status(
+        &self,
+        input: UpdateEnrollmentStatusRequest,
+    ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
+        self.runtime
+            .block_on(self.client.update_enrollment_status(input))
+    }
+}
@@ -3703,7 +3703,8 @@ impl BatchGetAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetAggregateResourceConfigError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
@@ -3739,7 +3740,8 @@ impl BatchGetResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchGetResourceConfigError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
@@ -3775,7 +3777,8 @@ impl DeleteAggregationAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAggregationAuthorizationError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -3811,10 +3814,16 @@ impl DeleteConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DeleteConfigRuleError::NoSuchConfigRule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigRuleError::NoSuchConfigRule(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteConfigRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigRuleError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3847,7 +3856,8 @@ impl DeleteConfigurationAggregatorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator(err.msg),
                     )
                 }
@@ -3883,7 +3893,8 @@ impl DeleteConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
@@ -3919,12 +3930,16 @@ impl DeleteConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(DeleteConformancePackError::NoSuchConformancePack(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConformancePackError::NoSuchConformancePack(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteConformancePackError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConformancePackError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3957,14 +3972,16 @@ impl DeleteDeliveryChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LastDeliveryChannelDeleteFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDeliveryChannelError::LastDeliveryChannelDeleteFailed(err.msg),
                     )
                 }
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(DeleteDeliveryChannelError::NoSuchDeliveryChannel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeliveryChannelError::NoSuchDeliveryChannel(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3999,14 +4016,16 @@ impl DeleteEvaluationResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DeleteEvaluationResultsError::NoSuchConfigRule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEvaluationResultsError::NoSuchConfigRule(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEvaluationResultsError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEvaluationResultsError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4043,19 +4062,22 @@ impl DeleteOrganizationConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationConfigRuleError::NoSuchOrganizationConfigRule(err.msg),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationConfigRuleError::OrganizationAccessDenied(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteOrganizationConfigRuleError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationConfigRuleError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4097,19 +4119,22 @@ impl DeleteOrganizationConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchOrganizationConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationConformancePackError::NoSuchOrganizationConformancePack(
                             err.msg,
                         ),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationConformancePackError::OrganizationAccessDenied(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationConformancePackError::ResourceInUse(err.msg),
                     )
                 }
@@ -4151,7 +4176,8 @@ impl DeletePendingAggregationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePendingAggregationRequestError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -4191,19 +4217,22 @@ impl DeleteRemediationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRemediationConfigurationError::InsufficientPermissions(err.msg),
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRemediationConfigurationError::NoSuchRemediationConfiguration(
                             err.msg,
                         ),
                     )
                 }
                 "RemediationInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRemediationConfigurationError::RemediationInProgress(err.msg),
                     )
                 }
@@ -4245,7 +4274,8 @@ impl DeleteRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchRemediationExceptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRemediationExceptionsError::NoSuchRemediation(err.msg),
                     )
                 }
@@ -4279,7 +4309,8 @@ impl DeleteResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteResourceConfigError::NoRunningConfigurationRecorder(err.msg),
                     )
                 }
@@ -4317,12 +4348,14 @@ impl DeleteRetentionConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRetentionConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchRetentionConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRetentionConfigurationError::NoSuchRetentionConfiguration(err.msg),
                     )
                 }
@@ -4363,19 +4396,22 @@ impl DeliverConfigSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeliverConfigSnapshotError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeliverConfigSnapshotError::NoRunningConfigurationRecorder(err.msg),
                     )
                 }
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(DeliverConfigSnapshotError::NoSuchDeliveryChannel(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeliverConfigSnapshotError::NoSuchDeliveryChannel(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4417,16 +4453,19 @@ impl DescribeAggregateComplianceByConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAggregateComplianceByConfigRulesError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAggregateComplianceByConfigRulesError::InvalidNextToken(err.msg),
                     )
                 }
-                "NoSuchConfigurationAggregatorException" => return RusotoError::Service(
+                "NoSuchConfigurationAggregatorException" => return RusotoError::service(
+                    &res,
                     DescribeAggregateComplianceByConfigRulesError::NoSuchConfigurationAggregator(
                         err.msg,
                     ),
@@ -4473,17 +4512,20 @@ impl DescribeAggregationAuthorizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAggregationAuthorizationsError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAggregationAuthorizationsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAggregationAuthorizationsError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -4529,17 +4571,20 @@ impl DescribeComplianceByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComplianceByConfigRuleError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComplianceByConfigRuleError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComplianceByConfigRuleError::NoSuchConfigRule(err.msg),
                     )
                 }
@@ -4583,12 +4628,14 @@ impl DescribeComplianceByResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComplianceByResourceError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeComplianceByResourceError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -4631,17 +4678,20 @@ impl DescribeConfigRuleEvaluationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigRuleEvaluationStatusError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigRuleEvaluationStatusError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule(err.msg),
                     )
                 }
@@ -4683,14 +4733,16 @@ impl DescribeConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeConfigRulesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigRulesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DescribeConfigRulesError::NoSuchConfigRule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigRulesError::NoSuchConfigRule(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4728,10 +4780,10 @@ impl DescribeConfigurationAggregatorSourcesStatusError {
     ) -> RusotoError<DescribeConfigurationAggregatorSourcesStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(err.msg)),
-"InvalidParameterValueException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(err.msg)),
-"NoSuchConfigurationAggregatorException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(err.msg)),
+                                "InvalidLimitException" => return RusotoError::service(&res, DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit(err.msg)),
+"InvalidNextTokenException" => return RusotoError::service(&res, DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(err.msg)),
+"InvalidParameterValueException" => return RusotoError::service(&res, DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(err.msg)),
+"NoSuchConfigurationAggregatorException" => return RusotoError::service(&res, DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -4779,22 +4831,26 @@ impl DescribeConfigurationAggregatorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationAggregatorsError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationAggregatorsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationAggregatorsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationAggregatorsError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
@@ -4841,7 +4897,8 @@ impl DescribeConfigurationRecorderStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationRecorderStatusError::NoSuchConfigurationRecorder(
                             err.msg,
                         ),
@@ -4879,7 +4936,8 @@ impl DescribeConfigurationRecordersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationRecordersError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
@@ -4923,29 +4981,34 @@ impl DescribeConformancePackComplianceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePackComplianceError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePackComplianceError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePackComplianceError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchConfigRuleInConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePackComplianceError::NoSuchConfigRuleInConformancePack(
                             err.msg,
                         ),
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePackComplianceError::NoSuchConformancePack(err.msg),
                     )
                 }
@@ -4995,12 +5058,14 @@ impl DescribeConformancePackStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(DescribeConformancePackStatusError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConformancePackStatusError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePackStatusError::InvalidNextToken(err.msg),
                     )
                 }
@@ -5039,17 +5104,20 @@ impl DescribeConformancePacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(DescribeConformancePacksError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConformancePacksError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeConformancePacksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConformancePacksError::InvalidNextToken(err.msg),
+                    )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConformancePacksError::NoSuchConformancePack(err.msg),
                     )
                 }
@@ -5087,7 +5155,8 @@ impl DescribeDeliveryChannelStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel(err.msg),
                     )
                 }
@@ -5121,7 +5190,8 @@ impl DescribeDeliveryChannelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDeliveryChannelsError::NoSuchDeliveryChannel(err.msg),
                     )
                 }
@@ -5163,24 +5233,28 @@ impl DescribeOrganizationConfigRuleStatusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRuleStatusesError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRuleStatusesError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRuleStatusesError::NoSuchOrganizationConfigRule(
                             err.msg,
                         ),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRuleStatusesError::OrganizationAccessDenied(
                             err.msg,
                         ),
@@ -5233,22 +5307,26 @@ impl DescribeOrganizationConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRulesError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRulesError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRulesError::NoSuchOrganizationConfigRule(err.msg),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigRulesError::OrganizationAccessDenied(err.msg),
                     )
                 }
@@ -5296,10 +5374,10 @@ impl DescribeOrganizationConformancePackStatusesError {
     ) -> RusotoError<DescribeOrganizationConformancePackStatusesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::InvalidNextToken(err.msg)),
-"NoSuchOrganizationConformancePackException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(err.msg)),
+                                "InvalidLimitException" => return RusotoError::service(&res, DescribeOrganizationConformancePackStatusesError::InvalidLimit(err.msg)),
+"InvalidNextTokenException" => return RusotoError::service(&res, DescribeOrganizationConformancePackStatusesError::InvalidNextToken(err.msg)),
+"NoSuchOrganizationConformancePackException" => return RusotoError::service(&res, DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack(err.msg)),
+"OrganizationAccessDeniedException" => return RusotoError::service(&res, DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -5347,22 +5425,26 @@ impl DescribeOrganizationConformancePacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConformancePacksError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConformancePacksError::InvalidNextToken(err.msg),
                     )
                 }
-                "NoSuchOrganizationConformancePackException" => return RusotoError::Service(
+                "NoSuchOrganizationConformancePackException" => return RusotoError::service(
+                    &res,
                     DescribeOrganizationConformancePacksError::NoSuchOrganizationConformancePack(
                         err.msg,
                     ),
                 ),
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConformancePacksError::OrganizationAccessDenied(
                             err.msg,
                         ),
@@ -5413,17 +5495,20 @@ impl DescribePendingAggregationRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePendingAggregationRequestsError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePendingAggregationRequestsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePendingAggregationRequestsError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -5491,12 +5576,14 @@ impl DescribeRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRemediationExceptionsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRemediationExceptionsError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -5537,12 +5624,14 @@ impl DescribeRemediationExecutionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRemediationExecutionStatusError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRemediationExecutionStatusError::NoSuchRemediationConfiguration(
                             err.msg,
                         ),
@@ -5587,17 +5676,20 @@ impl DescribeRetentionConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRetentionConfigurationsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRetentionConfigurationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchRetentionConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration(err.msg),
                     )
                 }
@@ -5643,16 +5735,19 @@ impl GetAggregateComplianceDetailsByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateComplianceDetailsByConfigRuleError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateComplianceDetailsByConfigRuleError::InvalidNextToken(err.msg),
                     )
                 }
-                "NoSuchConfigurationAggregatorException" => return RusotoError::Service(
+                "NoSuchConfigurationAggregatorException" => return RusotoError::service(
+                    &res,
                     GetAggregateComplianceDetailsByConfigRuleError::NoSuchConfigurationAggregator(
                         err.msg,
                     ),
@@ -5699,17 +5794,20 @@ impl GetAggregateConfigRuleComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateConfigRuleComplianceSummaryError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateConfigRuleComplianceSummaryError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateConfigRuleComplianceSummaryError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
@@ -5757,17 +5855,20 @@ impl GetAggregateDiscoveredResourceCountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateDiscoveredResourceCountsError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateDiscoveredResourceCountsError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateDiscoveredResourceCountsError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
@@ -5815,17 +5916,20 @@ impl GetAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateResourceConfigError::NoSuchConfigurationAggregator(err.msg),
                     )
                 }
                 "OversizedConfigurationItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateResourceConfigError::OversizedConfigurationItem(err.msg),
                     )
                 }
                 "ResourceNotDiscoveredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAggregateResourceConfigError::ResourceNotDiscovered(err.msg),
                     )
                 }
@@ -5871,17 +5975,20 @@ impl GetComplianceDetailsByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetComplianceDetailsByConfigRuleError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetComplianceDetailsByConfigRuleError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetComplianceDetailsByConfigRuleError::NoSuchConfigRule(err.msg),
                     )
                 }
@@ -5923,7 +6030,8 @@ impl GetComplianceDetailsByResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetComplianceDetailsByResourceError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -5983,7 +6091,8 @@ impl GetComplianceSummaryByResourceTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetComplianceSummaryByResourceTypeError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -6027,29 +6136,34 @@ impl GetConformancePackComplianceDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceDetailsError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceDetailsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceDetailsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchConfigRuleInConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceDetailsError::NoSuchConfigRuleInConformancePack(
                             err.msg,
                         ),
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceDetailsError::NoSuchConformancePack(err.msg),
                     )
                 }
@@ -6101,17 +6215,20 @@ impl GetConformancePackComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceSummaryError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceSummaryError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConformancePackComplianceSummaryError::NoSuchConformancePack(err.msg),
                     )
                 }
@@ -6155,12 +6272,14 @@ impl GetDiscoveredResourceCountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(GetDiscoveredResourceCountsError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoveredResourceCountsError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDiscoveredResourceCountsError::InvalidNextToken(err.msg),
                     )
                 }
@@ -6201,24 +6320,28 @@ impl GetOrganizationConfigRuleDetailedStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOrganizationConfigRuleDetailedStatusError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOrganizationConfigRuleDetailedStatusError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOrganizationConfigRuleDetailedStatusError::NoSuchOrganizationConfigRule(
                             err.msg,
                         ),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOrganizationConfigRuleDetailedStatusError::OrganizationAccessDenied(
                             err.msg,
                         ),
@@ -6270,10 +6393,10 @@ impl GetOrganizationConformancePackDetailedStatusError {
     ) -> RusotoError<GetOrganizationConformancePackDetailedStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(err.msg)),
-"NoSuchOrganizationConformancePackException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(err.msg)),
+                                "InvalidLimitException" => return RusotoError::service(&res, GetOrganizationConformancePackDetailedStatusError::InvalidLimit(err.msg)),
+"InvalidNextTokenException" => return RusotoError::service(&res, GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(err.msg)),
+"NoSuchOrganizationConformancePackException" => return RusotoError::service(&res, GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(err.msg)),
+"OrganizationAccessDeniedException" => return RusotoError::service(&res, GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -6313,27 +6436,32 @@ impl GetResourceConfigHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceConfigHistoryError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceConfigHistoryError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidTimeRange(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceConfigHistoryError::InvalidTimeRange(err.msg),
+                    )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceConfigHistoryError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
                 "ResourceNotDiscoveredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceConfigHistoryError::ResourceNotDiscovered(err.msg),
                     )
                 }
@@ -6379,17 +6507,20 @@ impl ListAggregateDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAggregateDiscoveredResourcesError::InvalidLimit(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAggregateDiscoveredResourcesError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAggregateDiscoveredResourcesError::NoSuchConfigurationAggregator(
                             err.msg,
                         ),
@@ -6435,17 +6566,20 @@ impl ListDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDiscoveredResourcesError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
@@ -6485,17 +6619,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidLimit(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6529,7 +6668,8 @@ impl PutAggregationAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAggregationAuthorizationError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -6571,25 +6711,31 @@ impl PutConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutConfigRuleError::InsufficientPermissions(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigRuleError::InsufficientPermissions(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutConfigRuleError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigRuleError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MaxNumberOfConfigRulesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigRuleError::MaxNumberOfConfigRulesExceeded(err.msg),
                     )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigRuleError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutConfigRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, PutConfigRuleError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6637,32 +6783,38 @@ impl PutConfigurationAggregatorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationAggregatorError::InvalidParameterValue(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(PutConfigurationAggregatorError::InvalidRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigurationAggregatorError::InvalidRole(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutConfigurationAggregatorError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigurationAggregatorError::LimitExceeded(err.msg),
+                    )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationAggregatorError::NoAvailableOrganization(err.msg),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationAggregatorError::OrganizationAccessDenied(err.msg),
                     )
                 }
                 "OrganizationAllFeaturesNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled(err.msg),
                     )
                 }
@@ -6713,22 +6865,26 @@ impl PutConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidConfigurationRecorderNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationRecorderError::InvalidConfigurationRecorderName(err.msg),
                     )
                 }
                 "InvalidRecordingGroupException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationRecorderError::InvalidRecordingGroup(err.msg),
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(PutConfigurationRecorderError::InvalidRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigurationRecorderError::InvalidRole(err.msg),
+                    )
                 }
                 "MaxNumberOfConfigurationRecordersExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationRecorderError::MaxNumberOfConfigurationRecordersExceeded(
                             err.msg,
                         ),
@@ -6779,27 +6935,34 @@ impl PutConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConformancePackTemplateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConformancePackError::ConformancePackTemplateValidation(err.msg),
                     )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutConformancePackError::InsufficientPermissions(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConformancePackError::InsufficientPermissions(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutConformancePackError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConformancePackError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MaxNumberOfConformancePacksExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConformancePackError::MaxNumberOfConformancePacksExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutConformancePackError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutConformancePackError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6849,37 +7012,46 @@ impl PutDeliveryChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDeliveryPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliveryChannelError::InsufficientDeliveryPolicy(err.msg),
                     )
                 }
                 "InvalidDeliveryChannelNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliveryChannelError::InvalidDeliveryChannelName(err.msg),
                     )
                 }
                 "InvalidS3KeyPrefixException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::InvalidS3KeyPrefix(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDeliveryChannelError::InvalidS3KeyPrefix(err.msg),
+                    )
                 }
                 "InvalidSNSTopicARNException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::InvalidSNSTopicARN(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDeliveryChannelError::InvalidSNSTopicARN(err.msg),
+                    )
                 }
                 "MaxNumberOfDeliveryChannelsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliveryChannelError::MaxNumberOfDeliveryChannelsExceeded(err.msg),
                     )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliveryChannelError::NoAvailableConfigurationRecorder(err.msg),
                     )
                 }
                 "NoSuchBucketException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::NoSuchBucket(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDeliveryChannelError::NoSuchBucket(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6927,15 +7099,22 @@ impl PutEvaluationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutEvaluationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEvaluationsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidResultTokenException" => {
-                    return RusotoError::Service(PutEvaluationsError::InvalidResultToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEvaluationsError::InvalidResultToken(err.msg),
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(PutEvaluationsError::NoSuchConfigRule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEvaluationsError::NoSuchConfigRule(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6979,41 +7158,48 @@ impl PutOrganizationConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutOrganizationConfigRuleError::InsufficientPermissions(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutOrganizationConfigRuleError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MaxNumberOfOrganizationConfigRulesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutOrganizationConfigRuleError::MaxNumberOfOrganizationConfigRulesExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutOrganizationConfigRuleError::NoAvailableOrganization(err.msg),
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutOrganizationConfigRuleError::OrganizationAccessDenied(err.msg),
                     )
                 }
                 "OrganizationAllFeaturesNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutOrganizationConfigRuleError::OrganizationAllFeaturesNotEnabled(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutOrganizationConfigRuleError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutOrganizationConfigRuleError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7074,13 +7260,13 @@ impl PutOrganizationConformancePackError {
     ) -> RusotoError<PutOrganizationConformancePackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InsufficientPermissionsException" => return RusotoError::Service(PutOrganizationConformancePackError::InsufficientPermissions(err.msg)),
-"MaxNumberOfOrganizationConformancePacksExceededException" => return RusotoError::Service(PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded(err.msg)),
-"NoAvailableOrganizationException" => return RusotoError::Service(PutOrganizationConformancePackError::NoAvailableOrganization(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationAccessDenied(err.msg)),
-"OrganizationAllFeaturesNotEnabledException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(err.msg)),
-"OrganizationConformancePackTemplateValidationException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(err.msg)),
-"ResourceInUseException" => return RusotoError::Service(PutOrganizationConformancePackError::ResourceInUse(err.msg)),
+                                "InsufficientPermissionsException" => return RusotoError::service(&res, PutOrganizationConformancePackError::InsufficientPermissions(err.msg)),
+"MaxNumberOfOrganizationConformancePacksExceededException" => return RusotoError::service(&res, PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded(err.msg)),
+"NoAvailableOrganizationException" => return RusotoError::service(&res, PutOrganizationConformancePackError::NoAvailableOrganization(err.msg)),
+"OrganizationAccessDeniedException" => return RusotoError::service(&res, PutOrganizationConformancePackError::OrganizationAccessDenied(err.msg)),
+"OrganizationAllFeaturesNotEnabledException" => return RusotoError::service(&res, PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(err.msg)),
+"OrganizationConformancePackTemplateValidationException" => return RusotoError::service(&res, PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(err.msg)),
+"ResourceInUseException" => return RusotoError::service(&res, PutOrganizationConformancePackError::ResourceInUse(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -7119,12 +7305,14 @@ impl PutRemediationConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRemediationConfigurationsError::InsufficientPermissions(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRemediationConfigurationsError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -7161,7 +7349,8 @@ impl PutRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRemediationExceptionsError::InvalidParameterValue(err.msg),
                     )
                 }
@@ -7199,17 +7388,20 @@ impl PutResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutResourceConfigError::InsufficientPermissions(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceConfigError::InsufficientPermissions(err.msg),
+                    )
                 }
                 "MaxActiveResourcesExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutResourceConfigError::MaxActiveResourcesExceeded(err.msg),
                     )
                 }
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutResourceConfigError::NoRunningConfigurationRecorder(err.msg),
                     )
                 }
@@ -7247,12 +7439,14 @@ impl PutRetentionConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRetentionConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MaxNumberOfRetentionConfigurationsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutRetentionConfigurationError::MaxNumberOfRetentionConfigurationsExceeded(
                             err.msg,
                         ),
@@ -7299,22 +7493,26 @@ impl SelectAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidExpressionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SelectAggregateResourceConfigError::InvalidExpression(err.msg),
                     )
                 }
                 "InvalidLimitException" => {
-                    return RusotoError::Service(SelectAggregateResourceConfigError::InvalidLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SelectAggregateResourceConfigError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SelectAggregateResourceConfigError::InvalidNextToken(err.msg),
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SelectAggregateResourceConfigError::NoSuchConfigurationAggregator(err.msg),
                     )
                 }
@@ -7359,17 +7557,22 @@ impl SelectResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidExpressionException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidExpression(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SelectResourceConfigError::InvalidExpression(err.msg),
+                    )
                 }
                 "InvalidLimitException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidLimit(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SelectResourceConfigError::InvalidLimit(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SelectResourceConfigError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7409,24 +7612,28 @@ impl StartConfigRulesEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartConfigRulesEvaluationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartConfigRulesEvaluationError::LimitExceeded(err.msg),
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::NoSuchConfigRule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartConfigRulesEvaluationError::NoSuchConfigRule(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartConfigRulesEvaluationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7465,12 +7672,14 @@ impl StartConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableDeliveryChannelException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartConfigurationRecorderError::NoAvailableDeliveryChannel(err.msg),
                     )
                 }
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
@@ -7511,17 +7720,20 @@ impl StartRemediationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartRemediationExecutionError::InsufficientPermissions(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartRemediationExecutionError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartRemediationExecutionError::NoSuchRemediationConfiguration(err.msg),
                     )
                 }
@@ -7561,7 +7773,8 @@ impl StopConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
                     )
                 }
@@ -7597,10 +7810,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7631,7 +7844,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8251,6 +8467,7 @@ impl ConfigServiceClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -8266,6 +8483,7 @@ impl ConfigServiceClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceClient {
         ConfigServiceClient { client, region }
     }
@@ -8282,6 +8500,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<BatchGetAggregateResourceConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetAggregateResourceConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.BatchGetAggregateResourceConfig",
@@ -8304,6 +8525,9 @@ impl ConfigService for ConfigServiceClient {
         input: BatchGetResourceConfigRequest,
     ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetResourceConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8323,6 +8547,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteAggregationAuthorizationRequest,
     ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAggregationAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteAggregationAuthorization",
@@ -8343,6 +8570,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigRuleRequest,
     ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConfigRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConfigRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8360,6 +8590,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigurationAggregatorRequest,
     ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConfigurationAggregator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteConfigurationAggregator",
@@ -8380,6 +8613,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConfigurationRecorder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteConfigurationRecorder",
@@ -8400,6 +8636,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConformancePackRequest,
     ) -> Result<(), RusotoError<DeleteConformancePackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConformancePack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConformancePack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8417,6 +8656,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteDeliveryChannelRequest,
     ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDeliveryChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.DeleteDeliveryChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8434,6 +8676,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteEvaluationResultsRequest,
     ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEvaluationResults");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteEvaluationResults",
@@ -8456,6 +8701,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteOrganizationConfigRuleRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteOrganizationConfigRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteOrganizationConfigRule",
@@ -8476,6 +8724,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteOrganizationConformancePackRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteOrganizationConformancePack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteOrganizationConformancePack",
@@ -8499,6 +8750,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeletePendingAggregationRequestRequest,
     ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePendingAggregationRequest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeletePendingAggregationRequest",
@@ -8522,6 +8776,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DeleteRemediationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRemediationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteRemediationConfiguration",
@@ -8545,6 +8802,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRemediationExceptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteRemediationExceptions",
@@ -8567,6 +8827,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteResourceConfigRequest,
     ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourceConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.DeleteResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8584,6 +8847,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteRetentionConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRetentionConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteRetentionConfiguration",
@@ -8604,6 +8870,9 @@ impl ConfigService for ConfigServiceClient {
         input: DeliverConfigSnapshotRequest,
     ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeliverConfigSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.DeliverConfigSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8626,6 +8895,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeAggregateComplianceByConfigRulesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAggregateComplianceByConfigRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeAggregateComplianceByConfigRules",
@@ -8654,6 +8926,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeAggregationAuthorizationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAggregationAuthorizations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeAggregationAuthorizations",
@@ -8682,6 +8957,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeComplianceByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeComplianceByConfigRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeComplianceByConfigRule",
@@ -8705,6 +8983,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeComplianceByResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeComplianceByResource",
@@ -8730,6 +9011,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigRuleEvaluationStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigRuleEvaluationStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigRuleEvaluationStatus",
@@ -8755,6 +9039,9 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeConfigRulesRequest,
     ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StarlingDoveService.DescribeConfigRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8776,6 +9063,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigurationAggregatorSourcesStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationAggregatorSourcesStatus",
@@ -8804,6 +9094,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationAggregatorsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigurationAggregators");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationAggregators",
@@ -8832,6 +9125,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationRecorderStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigurationRecorderStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationRecorderStatus",
@@ -8860,6 +9156,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationRecordersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigurationRecorders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationRecorders",
@@ -8885,6 +9184,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConformancePackComplianceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConformancePackCompliance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConformancePackCompliance",
@@ -8913,6 +9215,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConformancePackStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConformancePackStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConformancePackStatus",
@@ -8935,6 +9240,9 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeConformancePacksRequest,
     ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConformancePacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConformancePacks",
@@ -8960,6 +9268,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeDeliveryChannelStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDeliveryChannelStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeDeliveryChannelStatus",
@@ -8982,6 +9293,9 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeDeliveryChannelsRequest,
     ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDeliveryChannels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeDeliveryChannels",
@@ -9007,6 +9321,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConfigRuleStatusesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganizationConfigRuleStatuses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConfigRuleStatuses",
@@ -9035,6 +9352,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConfigRulesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganizationConfigRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConfigRules",
@@ -9060,6 +9380,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConformancePackStatusesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganizationConformancePackStatuses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConformancePackStatuses",
@@ -9088,6 +9411,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConformancePacksError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganizationConformancePacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConformancePacks",
@@ -9116,6 +9442,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribePendingAggregationRequestsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePendingAggregationRequests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribePendingAggregationRequests",
@@ -9144,6 +9473,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationConfigurationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRemediationConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRemediationConfigurations",
@@ -9172,6 +9504,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationExceptionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRemediationExceptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRemediationExceptions",
@@ -9197,6 +9532,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationExecutionStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRemediationExecutionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRemediationExecutionStatus",
@@ -9225,6 +9563,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRetentionConfigurationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRetentionConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRetentionConfigurations",
@@ -9250,6 +9591,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAggregateComplianceDetailsByConfigRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateComplianceDetailsByConfigRule",
@@ -9278,6 +9622,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAggregateConfigRuleComplianceSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateConfigRuleComplianceSummary",
@@ -9306,6 +9653,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateDiscoveredResourceCountsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAggregateDiscoveredResourceCounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateDiscoveredResourceCounts",
@@ -9332,6 +9682,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAggregateResourceConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateResourceConfig",
@@ -9357,6 +9710,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceDetailsByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComplianceDetailsByConfigRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceDetailsByConfigRule",
@@ -9385,6 +9741,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceDetailsByResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComplianceDetailsByResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceDetailsByResource",
@@ -9409,6 +9768,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceSummaryByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComplianceSummaryByConfigRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceSummaryByConfigRule",
@@ -9436,6 +9798,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceSummaryByResourceTypeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComplianceSummaryByResourceType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceSummaryByResourceType",
@@ -9464,6 +9829,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetConformancePackComplianceDetailsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConformancePackComplianceDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetConformancePackComplianceDetails",
@@ -9492,6 +9860,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetConformancePackComplianceSummaryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConformancePackComplianceSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetConformancePackComplianceSummary",
@@ -9518,6 +9889,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDiscoveredResourceCounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetDiscoveredResourceCounts",
@@ -9543,6 +9917,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOrganizationConfigRuleDetailedStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetOrganizationConfigRuleDetailedStatus",
@@ -9571,6 +9948,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetOrganizationConformancePackDetailedStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOrganizationConformancePackDetailedStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetOrganizationConformancePackDetailedStatus",
@@ -9596,6 +9976,9 @@ impl ConfigService for ConfigServiceClient {
         input: GetResourceConfigHistoryRequest,
     ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResourceConfigHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetResourceConfigHistory",
@@ -9621,6 +10004,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<ListAggregateDiscoveredResourcesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAggregateDiscoveredResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.ListAggregateDiscoveredResources",
@@ -9646,6 +10032,9 @@ impl ConfigService for ConfigServiceClient {
         input: ListDiscoveredResourcesRequest,
     ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDiscoveredResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.ListDiscoveredResources",
@@ -9668,6 +10057,9 @@ impl ConfigService for ConfigServiceClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StarlingDoveService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9687,6 +10079,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAggregationAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutAggregationAuthorization",
@@ -9709,6 +10104,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutConfigRuleRequest,
     ) -> Result<(), RusotoError<PutConfigRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutConfigRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.PutConfigRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9727,6 +10125,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutConfigurationAggregator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutConfigurationAggregator",
@@ -9749,6 +10150,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutConfigurationRecorder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutConfigurationRecorder",
@@ -9769,6 +10173,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutConformancePackRequest,
     ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutConformancePack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.PutConformancePack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9787,6 +10194,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutDeliveryChannelRequest,
     ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutDeliveryChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.PutDeliveryChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9804,6 +10214,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutEvaluationsRequest,
     ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutEvaluations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.PutEvaluations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9823,6 +10236,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutOrganizationConfigRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutOrganizationConfigRule",
@@ -9848,6 +10264,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<PutOrganizationConformancePackError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutOrganizationConformancePack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutOrganizationConformancePack",
@@ -9871,6 +10290,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRemediationConfigurations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutRemediationConfigurations",
@@ -9893,6 +10315,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutRemediationExceptionsRequest,
     ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRemediationExceptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutRemediationExceptions",
@@ -9915,6 +10340,9 @@ impl ConfigService for ConfigServiceClient {
         input: PutResourceConfigRequest,
     ) -> Result<(), RusotoError<PutResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResourceConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.PutResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9933,6 +10361,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRetentionConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutRetentionConfiguration",
@@ -9958,6 +10389,9 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<SelectAggregateResourceConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SelectAggregateResourceConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.SelectAggregateResourceConfig",
@@ -9980,6 +10414,9 @@ impl ConfigService for ConfigServiceClient {
         input: SelectResourceConfigRequest,
     ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SelectResourceConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10000,6 +10437,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartConfigRulesEvaluation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StartConfigRulesEvaluation",
@@ -10022,6 +10462,9 @@ impl ConfigService for ConfigServiceClient {
         input: StartConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartConfigurationRecorder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StartConfigurationRecorder",
@@ -10043,6 +10486,9 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartRemediationExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StartRemediationExecution",
@@ -10065,6 +10511,9 @@ impl ConfigService for ConfigServiceClient {
         input: StopConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopConfigurationRecorder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StopConfigurationRecorder",
@@ -10085,6 +10534,9 @@ impl ConfigService for ConfigServiceClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10102,6 +10554,9 @@ impl ConfigService for ConfigServiceClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10113,3 +10568,1051 @@ impl ConfigService for ConfigServiceClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Config Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ConfigServiceClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ConfigServiceBlockingClient {
+    client: ConfigServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ConfigServiceBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ConfigServiceBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ConfigServiceBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConfigServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ConfigServiceBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient {
+            client: ConfigServiceClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Returns the current configuration items for resources that are present in your AWS Config aggregator. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty <code>unprocessedResourceIdentifiers</code> list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return tags and relationships.</p> </li> </ul> </note></p>
+    ///
+    /// See `ConfigService::batch_get_aggregate_resource_config`.
+    pub fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> Result<
+        BatchGetAggregateResourceConfigResponse,
+        RusotoError<BatchGetAggregateResourceConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.batch_get_aggregate_resource_config(input))
+    }
+
+    /// <p><p>Returns the current configuration for one or more requested resources. The operation also returns a list of resources that are not processed in the current request. If there are no unprocessed resources, the operation returns an empty unprocessedResourceKeys list. </p> <note> <ul> <li> <p>The API does not return results for deleted resources.</p> </li> <li> <p> The API does not return any tags for the requested resources. This information is filtered out of the supplementaryConfiguration section of the API response.</p> </li> </ul> </note></p>
+    ///
+    /// See `ConfigService::batch_get_resource_config`.
+    pub fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.batch_get_resource_config(input))
+    }
+
+    /// <p>Deletes the authorization granted to the specified configuration aggregator account in a specified region.</p>
+    ///
+    /// See `ConfigService::delete_aggregation_authorization`.
+    pub fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.delete_aggregation_authorization(input))
+    }
+
+    /// <p>Deletes the specified AWS Config rule and all of its evaluation results.</p> <p>AWS Config sets the state of a rule to <code>DELETING</code> until the deletion is complete. You cannot update a rule while it is in this state. If you make a <code>PutConfigRule</code> or <code>DeleteConfigRule</code> request for the rule, you will receive a <code>ResourceInUseException</code>.</p> <p>You can check the state of a rule by using the <code>DescribeConfigRules</code> request.</p>
+    ///
+    /// See `ConfigService::delete_config_rule`.
+    pub fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
+        self.runtime.block_on(self.client.delete_config_rule(input))
+    }
+
+    /// <p>Deletes the specified configuration aggregator and the aggregated data associated with the aggregator.</p>
+    ///
+    /// See `ConfigService::delete_configuration_aggregator`.
+    pub fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_aggregator(input))
+    }
+
+    /// <p>Deletes the configuration recorder.</p> <p>After the configuration recorder is deleted, AWS Config will not record resource configuration changes until you create a new configuration recorder.</p> <p>This action does not delete the configuration information that was previously recorded. You will be able to access the previously recorded information by using the <code>GetResourceConfigHistory</code> action, but you will not be able to access this information in the AWS Config console until you create a new configuration recorder.</p>
+    ///
+    /// See `ConfigService::delete_configuration_recorder`.
+    pub fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_recorder(input))
+    }
+
+    /// <p>Deletes the specified conformance pack and all the AWS Config rules, remediation actions, and all evaluation results within that conformance pack.</p> <p>AWS Config sets the conformance pack to <code>DELETE_IN_PROGRESS</code> until the deletion is complete. You cannot update a conformance pack while it is in this state.</p>
+    ///
+    /// See `ConfigService::delete_conformance_pack`.
+    pub fn delete_conformance_pack(
+        &self,
+        input: DeleteConformancePackRequest,
+    ) -> Result<(), RusotoError<DeleteConformancePackError>> {
+        self.runtime
+            .block_on(self.client.delete_conformance_pack(input))
+    }
+
+    /// <p>Deletes the delivery channel.</p> <p>Before you can delete the delivery channel, you must stop the configuration recorder by using the <a>StopConfigurationRecorder</a> action.</p>
+    ///
+    /// See `ConfigService::delete_delivery_channel`.
+    pub fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
+        self.runtime
+            .block_on(self.client.delete_delivery_channel(input))
+    }
+
+    /// <p>Deletes the evaluation results for the specified AWS Config rule. You can specify one AWS Config rule per request. After you delete the evaluation results, you can call the <a>StartConfigRulesEvaluation</a> API to start evaluating your AWS resources against the rule.</p>
+    ///
+    /// See `ConfigService::delete_evaluation_results`.
+    pub fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
+        self.runtime
+            .block_on(self.client.delete_evaluation_results(input))
+    }
+
+    /// <p>Deletes the specified organization config rule and all of its evaluation results from all member accounts in that organization. Only a master account can delete an organization config rule.</p> <p>AWS Config sets the state of a rule to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a rule while it is in this state.</p>
+    ///
+    /// See `ConfigService::delete_organization_config_rule`.
+    pub fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_organization_config_rule(input))
+    }
+
+    /// <p>Deletes the specified organization conformance pack and all of the config rules and remediation actions from all member accounts in that organization. Only a master account can delete an organization conformance pack.</p> <p>AWS Config sets the state of a conformance pack to DELETE_IN_PROGRESS until the deletion is complete. You cannot update a conformance pack while it is in this state. </p>
+    ///
+    /// See `ConfigService::delete_organization_conformance_pack`.
+    pub fn delete_organization_conformance_pack(
+        &self,
+        input: DeleteOrganizationConformancePackRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
+        self.runtime
+            .block_on(self.client.delete_organization_conformance_pack(input))
+    }
+
+    /// <p>Deletes pending authorization requests for a specified aggregator account in a specified region.</p>
+    ///
+    /// See `ConfigService::delete_pending_aggregation_request`.
+    pub fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
+        self.runtime
+            .block_on(self.client.delete_pending_aggregation_request(input))
+    }
+
+    /// <p>Deletes the remediation configuration.</p>
+    ///
+    /// See `ConfigService::delete_remediation_configuration`.
+    pub fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> Result<
+        DeleteRemediationConfigurationResponse,
+        RusotoError<DeleteRemediationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_remediation_configuration(input))
+    }
+
+    /// <p>Deletes one or more remediation exceptions mentioned in the resource keys.</p>
+    ///
+    /// See `ConfigService::delete_remediation_exceptions`.
+    pub fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_remediation_exceptions(input))
+    }
+
+    /// <p>Records the configuration state for a custom resource that has been deleted. This API records a new ConfigurationItem with a ResourceDeleted status. You can retrieve the ConfigurationItems recorded for this resource in your AWS Config History. </p>
+    ///
+    /// See `ConfigService::delete_resource_config`.
+    pub fn delete_resource_config(
+        &self,
+        input: DeleteResourceConfigRequest,
+    ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_config(input))
+    }
+
+    /// <p>Deletes the retention configuration.</p>
+    ///
+    /// See `ConfigService::delete_retention_configuration`.
+    pub fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_retention_configuration(input))
+    }
+
+    /// <p><p>Schedules delivery of a configuration snapshot to the Amazon S3 bucket in the specified delivery channel. After the delivery has started, AWS Config sends the following notifications using an Amazon SNS topic that you have specified.</p> <ul> <li> <p>Notification of the start of the delivery.</p> </li> <li> <p>Notification of the completion of the delivery, if the delivery was successfully completed.</p> </li> <li> <p>Notification of delivery failure, if the delivery failed.</p> </li> </ul></p>
+    ///
+    /// See `ConfigService::deliver_config_snapshot`.
+    pub fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
+        self.runtime
+            .block_on(self.client.deliver_config_snapshot(input))
+    }
+
+    /// <p><p>Returns a list of compliant and noncompliant rules with the number of resources for compliant and noncompliant rules. </p> <note> <p>The results can return an empty result page, but if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_aggregate_compliance_by_config_rules`.
+    pub fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> Result<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_aggregate_compliance_by_config_rules(input),
+        )
+    }
+
+    /// <p>Returns a list of authorizations granted to various aggregator accounts and regions.</p>
+    ///
+    /// See `ConfigService::describe_aggregation_authorizations`.
+    pub fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> Result<
+        DescribeAggregationAuthorizationsResponse,
+        RusotoError<DescribeAggregationAuthorizationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_aggregation_authorizations(input))
+    }
+
+    /// <p><p>Indicates whether the specified AWS Config rules are compliant. If a rule is noncompliant, this action returns the number of AWS resources that do not comply with the rule.</p> <p>A rule is compliant if all of the evaluated resources comply with it. It is noncompliant if any of these resources do not comply.</p> <p>If AWS Config has no current evaluation results for the rule, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
+    ///
+    /// See `ConfigService::describe_compliance_by_config_rule`.
+    pub fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> Result<
+        DescribeComplianceByConfigRuleResponse,
+        RusotoError<DescribeComplianceByConfigRuleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_compliance_by_config_rule(input))
+    }
+
+    /// <p><p>Indicates whether the specified AWS resources are compliant. If a resource is noncompliant, this action returns the number of AWS Config rules that the resource does not comply with.</p> <p>A resource is compliant if it complies with all the AWS Config rules that evaluate it. It is noncompliant if it does not comply with one or more of these rules.</p> <p>If AWS Config has no current evaluation results for the resource, it returns <code>INSUFFICIENT<em>DATA</code>. This result might indicate one of the following conditions about the rules that evaluate the resource:</p> <ul> <li> <p>AWS Config has never invoked an evaluation for the rule. To check whether it has, use the <code>DescribeConfigRuleEvaluationStatus</code> action to get the <code>LastSuccessfulInvocationTime</code> and <code>LastFailedInvocationTime</code>.</p> </li> <li> <p>The rule&#39;s AWS Lambda function is failing to send evaluation results to AWS Config. Verify that the role that you assigned to your configuration recorder includes the <code>config:PutEvaluations</code> permission. If the rule is a custom rule, verify that the AWS Lambda execution role includes the <code>config:PutEvaluations</code> permission.</p> </li> <li> <p>The rule&#39;s AWS Lambda function has returned <code>NOT</em>APPLICABLE</code> for all evaluation results. This can occur if the resources were deleted or removed from the rule&#39;s scope.</p> </li> </ul></p>
+    ///
+    /// See `ConfigService::describe_compliance_by_resource`.
+    pub fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_compliance_by_resource(input))
+    }
+
+    /// <p>Returns status information for each of your AWS managed Config rules. The status includes information such as the last time AWS Config invoked the rule, the last time AWS Config failed to invoke the rule, and the related error for the last failure.</p>
+    ///
+    /// See `ConfigService::describe_config_rule_evaluation_status`.
+    pub fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> Result<
+        DescribeConfigRuleEvaluationStatusResponse,
+        RusotoError<DescribeConfigRuleEvaluationStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_config_rule_evaluation_status(input))
+    }
+
+    /// <p>Returns details about your AWS Config rules.</p>
+    ///
+    /// See `ConfigService::describe_config_rules`.
+    pub fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
+        self.runtime
+            .block_on(self.client.describe_config_rules(input))
+    }
+
+    /// <p>Returns status information for sources within an aggregator. The status includes information about the last time AWS Config verified authorization between the source account and an aggregator account. In case of a failure, the status contains the related error code or message. </p>
+    ///
+    /// See `ConfigService::describe_configuration_aggregator_sources_status`.
+    pub fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> Result<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_configuration_aggregator_sources_status(input),
+        )
+    }
+
+    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
+    ///
+    /// See `ConfigService::describe_configuration_aggregators`.
+    pub fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> Result<
+        DescribeConfigurationAggregatorsResponse,
+        RusotoError<DescribeConfigurationAggregatorsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_aggregators(input))
+    }
+
+    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_configuration_recorder_status`.
+    pub fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> Result<
+        DescribeConfigurationRecorderStatusResponse,
+        RusotoError<DescribeConfigurationRecorderStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_recorder_status(input))
+    }
+
+    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_configuration_recorders`.
+    pub fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> Result<
+        DescribeConfigurationRecordersResponse,
+        RusotoError<DescribeConfigurationRecordersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_recorders(input))
+    }
+
+    /// <p><p>Returns compliance details for each rule in that conformance pack.</p> <note> <p>You must provide exact rule names.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_conformance_pack_compliance`.
+    pub fn describe_conformance_pack_compliance(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> Result<
+        DescribeConformancePackComplianceResponse,
+        RusotoError<DescribeConformancePackComplianceError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_conformance_pack_compliance(input))
+    }
+
+    /// <p><p>Provides one or more conformance packs deployment status.</p> <note> <p>If there are no conformance packs then you will see an empty result.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_conformance_pack_status`.
+    pub fn describe_conformance_pack_status(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> Result<
+        DescribeConformancePackStatusResponse,
+        RusotoError<DescribeConformancePackStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_conformance_pack_status(input))
+    }
+
+    /// <p>Returns a list of one or more conformance packs.</p>
+    ///
+    /// See `ConfigService::describe_conformance_packs`.
+    pub fn describe_conformance_packs(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
+        self.runtime
+            .block_on(self.client.describe_conformance_packs(input))
+    }
+
+    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_delivery_channel_status`.
+    pub fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> Result<
+        DescribeDeliveryChannelStatusResponse,
+        RusotoError<DescribeDeliveryChannelStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_delivery_channel_status(input))
+    }
+
+    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_delivery_channels`.
+    pub fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
+        self.runtime
+            .block_on(self.client.describe_delivery_channels(input))
+    }
+
+    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_organization_config_rule_statuses`.
+    pub fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> Result<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_organization_config_rule_statuses(input),
+        )
+    }
+
+    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_organization_config_rules`.
+    pub fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> Result<
+        DescribeOrganizationConfigRulesResponse,
+        RusotoError<DescribeOrganizationConfigRulesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_config_rules(input))
+    }
+
+    /// <p><p>Provides organization conformance pack deployment status for an organization.</p> <note> <p>The status is not considered successful until organization conformance pack is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization conformance pack names. They are only applicable, when you request all the organization conformance packs.</p> <p>Only a master account can call this API.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_organization_conformance_pack_statuses`.
+    pub fn describe_organization_conformance_pack_statuses(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> Result<
+        DescribeOrganizationConformancePackStatusesResponse,
+        RusotoError<DescribeOrganizationConformancePackStatusesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_organization_conformance_pack_statuses(input),
+        )
+    }
+
+    /// <p><p>Returns a list of organization conformance packs.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you specify organization conformance packs names. They are only applicable, when you request all the organization conformance packs. </p> <p>Only a master account can call this API.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_organization_conformance_packs`.
+    pub fn describe_organization_conformance_packs(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> Result<
+        DescribeOrganizationConformancePacksResponse,
+        RusotoError<DescribeOrganizationConformancePacksError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_conformance_packs(input))
+    }
+
+    /// <p>Returns a list of all pending aggregation requests.</p>
+    ///
+    /// See `ConfigService::describe_pending_aggregation_requests`.
+    pub fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> Result<
+        DescribePendingAggregationRequestsResponse,
+        RusotoError<DescribePendingAggregationRequestsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_pending_aggregation_requests(input))
+    }
+
+    /// <p>Returns the details of one or more remediation configurations.</p>
+    ///
+    /// See `ConfigService::describe_remediation_configurations`.
+    pub fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> Result<
+        DescribeRemediationConfigurationsResponse,
+        RusotoError<DescribeRemediationConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_remediation_configurations(input))
+    }
+
+    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_remediation_exceptions`.
+    pub fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> Result<
+        DescribeRemediationExceptionsResponse,
+        RusotoError<DescribeRemediationExceptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_remediation_exceptions(input))
+    }
+
+    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
+    ///
+    /// See `ConfigService::describe_remediation_execution_status`.
+    pub fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> Result<
+        DescribeRemediationExecutionStatusResponse,
+        RusotoError<DescribeRemediationExecutionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_remediation_execution_status(input))
+    }
+
+    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::describe_retention_configurations`.
+    pub fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> Result<
+        DescribeRetentionConfigurationsResponse,
+        RusotoError<DescribeRetentionConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_retention_configurations(input))
+    }
+
+    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    ///
+    /// See `ConfigService::get_aggregate_compliance_details_by_config_rule`.
+    pub fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_aggregate_compliance_details_by_config_rule(input),
+        )
+    }
+
+    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
+    ///
+    /// See `ConfigService::get_aggregate_config_rule_compliance_summary`.
+    pub fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> Result<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_aggregate_config_rule_compliance_summary(input),
+        )
+    }
+
+    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
+    ///
+    /// See `ConfigService::get_aggregate_discovered_resource_counts`.
+    pub fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> Result<
+        GetAggregateDiscoveredResourceCountsResponse,
+        RusotoError<GetAggregateDiscoveredResourceCountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_aggregate_discovered_resource_counts(input))
+    }
+
+    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
+    ///
+    /// See `ConfigService::get_aggregate_resource_config`.
+    pub fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_aggregate_resource_config(input))
+    }
+
+    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
+    ///
+    /// See `ConfigService::get_compliance_details_by_config_rule`.
+    pub fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        GetComplianceDetailsByConfigRuleResponse,
+        RusotoError<GetComplianceDetailsByConfigRuleError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_details_by_config_rule(input))
+    }
+
+    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
+    ///
+    /// See `ConfigService::get_compliance_details_by_resource`.
+    pub fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> Result<
+        GetComplianceDetailsByResourceResponse,
+        RusotoError<GetComplianceDetailsByResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_details_by_resource(input))
+    }
+
+    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
+    ///
+    /// See `ConfigService::get_compliance_summary_by_config_rule`.
+    pub fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> Result<
+        GetComplianceSummaryByConfigRuleResponse,
+        RusotoError<GetComplianceSummaryByConfigRuleError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_summary_by_config_rule())
+    }
+
+    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
+    ///
+    /// See `ConfigService::get_compliance_summary_by_resource_type`.
+    pub fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> Result<
+        GetComplianceSummaryByResourceTypeResponse,
+        RusotoError<GetComplianceSummaryByResourceTypeError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_summary_by_resource_type(input))
+    }
+
+    /// <p>Returns compliance details of a conformance pack for all AWS resources that are monitered by conformance pack.</p>
+    ///
+    /// See `ConfigService::get_conformance_pack_compliance_details`.
+    pub fn get_conformance_pack_compliance_details(
+        &self,
+        input: GetConformancePackComplianceDetailsRequest,
+    ) -> Result<
+        GetConformancePackComplianceDetailsResponse,
+        RusotoError<GetConformancePackComplianceDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_conformance_pack_compliance_details(input))
+    }
+
+    /// <p>Returns compliance details for the conformance pack based on the cumulative compliance results of all the rules in that conformance pack.</p>
+    ///
+    /// See `ConfigService::get_conformance_pack_compliance_summary`.
+    pub fn get_conformance_pack_compliance_summary(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> Result<
+        GetConformancePackComplianceSummaryResponse,
+        RusotoError<GetConformancePackComplianceSummaryError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_conformance_pack_compliance_summary(input))
+    }
+
+    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
+    ///
+    /// See `ConfigService::get_discovered_resource_counts`.
+    pub fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_discovered_resource_counts(input))
+    }
+
+    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
+    ///
+    /// See `ConfigService::get_organization_config_rule_detailed_status`.
+    pub fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> Result<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_organization_config_rule_detailed_status(input),
+        )
+    }
+
+    /// <p>Returns detailed status for each member account within an organization for a given organization conformance pack.</p> <p>Only a master account can call this API.</p>
+    ///
+    /// See `ConfigService::get_organization_conformance_pack_detailed_status`.
+    pub fn get_organization_conformance_pack_detailed_status(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> Result<
+        GetOrganizationConformancePackDetailedStatusResponse,
+        RusotoError<GetOrganizationConformancePackDetailedStatusError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_organization_conformance_pack_detailed_status(input),
+        )
+    }
+
+    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
+    ///
+    /// See `ConfigService::get_resource_config_history`.
+    pub fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_resource_config_history(input))
+    }
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
+    ///
+    /// See `ConfigService::list_aggregate_discovered_resources`.
+    pub fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> Result<
+        ListAggregateDiscoveredResourcesResponse,
+        RusotoError<ListAggregateDiscoveredResourcesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_aggregate_discovered_resources(input))
+    }
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
+    ///
+    /// See `ConfigService::list_discovered_resources`.
+    pub fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_discovered_resources(input))
+    }
+
+    /// <p>List the tags for AWS Config resource.</p>
+    ///
+    /// See `ConfigService::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
+    ///
+    /// See `ConfigService::put_aggregation_authorization`.
+    pub fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_aggregation_authorization(input))
+    }
+
+    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
+    ///
+    /// See `ConfigService::put_config_rule`.
+    pub fn put_config_rule(
+        &self,
+        input: PutConfigRuleRequest,
+    ) -> Result<(), RusotoError<PutConfigRuleError>> {
+        self.runtime.block_on(self.client.put_config_rule(input))
+    }
+
+    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
+    ///
+    /// See `ConfigService::put_configuration_aggregator`.
+    pub fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
+    {
+        self.runtime
+            .block_on(self.client.put_configuration_aggregator(input))
+    }
+
+    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
+    ///
+    /// See `ConfigService::put_configuration_recorder`.
+    pub fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.put_configuration_recorder(input))
+    }
+
+    /// <p><p>Creates or updates a conformance pack. A conformance pack is a collection of AWS Config rules that can be easily deployed in an account and a region and across AWS Organization.</p> <p>This API creates a service linked role <code>AWSServiceRoleForConfigConforms</code> in your account. The service linked role is created only when the role does not exist in your account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <note> <p>You must specify either the <code>TemplateS3Uri</code> or the <code>TemplateBody</code> parameter, but not both. If you provide both AWS Config uses the <code>TemplateS3Uri</code> parameter and ignores the <code>TemplateBody</code> parameter.</p> </note></p>
+    ///
+    /// See `ConfigService::put_conformance_pack`.
+    pub fn put_conformance_pack(
+        &self,
+        input: PutConformancePackRequest,
+    ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
+        self.runtime
+            .block_on(self.client.put_conformance_pack(input))
+    }
+
+    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::put_delivery_channel`.
+    pub fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
+        self.runtime
+            .block_on(self.client.put_delivery_channel(input))
+    }
+
+    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
+    ///
+    /// See `ConfigService::put_evaluations`.
+    pub fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
+        self.runtime.block_on(self.client.put_evaluations(input))
+    }
+
+    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
+    ///
+    /// See `ConfigService::put_organization_config_rule`.
+    pub fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.put_organization_config_rule(input))
+    }
+
+    /// <p><p>Deploys conformance packs across member accounts in an AWS Organization.</p> <p>This API enables organization service access for <code>config-multiaccountsetup.amazonaws.com</code> through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with GetRole action.</p> <note> <p>You must specify either the <code>TemplateS3Uri</code> or the <code>TemplateBody</code> parameter, but not both. If you provide both AWS Config uses the <code>TemplateS3Uri</code> parameter and ignores the <code>TemplateBody</code> parameter.</p> <p>AWS Config sets the state of a conformance pack to CREATE<em>IN</em>PROGRESS and UPDATE<em>IN</em>PROGRESS until the confomance pack is created or updated. You cannot update a conformance pack while it is in this state.</p> <p>You can create 6 conformance packs with 25 AWS Config rules in each pack.</p> </note></p>
+    ///
+    /// See `ConfigService::put_organization_conformance_pack`.
+    pub fn put_organization_conformance_pack(
+        &self,
+        input: PutOrganizationConformancePackRequest,
+    ) -> Result<
+        PutOrganizationConformancePackResponse,
+        RusotoError<PutOrganizationConformancePackError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_organization_conformance_pack(input))
+    }
+
+    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
+    ///
+    /// See `ConfigService::put_remediation_configurations`.
+    pub fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.put_remediation_configurations(input))
+    }
+
+    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
+    ///
+    /// See `ConfigService::put_remediation_exceptions`.
+    pub fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
+        self.runtime
+            .block_on(self.client.put_remediation_exceptions(input))
+    }
+
+    /// <p><p>Records the configuration state for the resource provided in the request. The configuration state of a resource is represented in AWS Config as Configuration Items. Once this API records the configuration item, you can retrieve the list of configuration items for the custom resource type using existing AWS Config APIs. </p> <note> <p>The custom resource type must be registered with AWS CloudFormation. This API accepts the configuration item registered with AWS CloudFormation.</p> <p>When you call this API, AWS Config only stores configuration state of the resource provided in the request. This API does not change or remediate the configuration of the resource. </p> </note></p>
+    ///
+    /// See `ConfigService::put_resource_config`.
+    pub fn put_resource_config(
+        &self,
+        input: PutResourceConfigRequest,
+    ) -> Result<(), RusotoError<PutResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.put_resource_config(input))
+    }
+
+    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    ///
+    /// See `ConfigService::put_retention_configuration`.
+    pub fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_retention_configuration(input))
+    }
+
+    /// <p>Accepts a structured query language (SQL) SELECT command and an aggregator to query configuration state of AWS resources across multiple accounts and regions, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    ///
+    /// See `ConfigService::select_aggregate_resource_config`.
+    pub fn select_aggregate_resource_config(
+        &self,
+        input: SelectAggregateResourceConfigRequest,
+    ) -> Result<
+        SelectAggregateResourceConfigResponse,
+        RusotoError<SelectAggregateResourceConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.select_aggregate_resource_config(input))
+    }
+
+    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    ///
+    /// See `ConfigService::select_resource_config`.
+    pub fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.select_resource_config(input))
+    }
+
+    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
+    ///
+    /// See `ConfigService::start_config_rules_evaluation`.
+    pub fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
+    {
+        self.runtime
+            .block_on(self.client.start_config_rules_evaluation(input))
+    }
+
+    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
+    ///
+    /// See `ConfigService::start_configuration_recorder`.
+    pub fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.start_configuration_recorder(input))
+    }
+
+    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
+    ///
+    /// See `ConfigService::start_remediation_execution`.
+    pub fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_remediation_execution(input))
+    }
+
+    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
+    ///
+    /// See `ConfigService::stop_configuration_recorder`.
+    pub fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.stop_configuration_recorder(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    ///
+    /// See `ConfigService::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    ///
+    /// See `ConfigService::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
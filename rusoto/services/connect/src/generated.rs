@@ -1295,25 +1295,25 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateUserError::DuplicateResource(err.msg))
+                    return RusotoError::service(&res, CreateUserError::DuplicateResource(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateUserError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateUserError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateUserError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateUserError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1357,19 +1357,19 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1411,19 +1411,19 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeUserError::InternalService(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1467,29 +1467,34 @@ impl DescribeUserHierarchyGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserHierarchyGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserHierarchyGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserHierarchyGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserHierarchyGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserHierarchyGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1533,29 +1538,34 @@ impl DescribeUserHierarchyStructureError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUserHierarchyStructureError::InternalService(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUserHierarchyStructureError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUserHierarchyStructureError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeUserHierarchyStructureError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserHierarchyStructureError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserHierarchyStructureError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1601,17 +1611,22 @@ impl GetContactAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetContactAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContactAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetContactAttributesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContactAttributesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContactAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContactAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1651,25 +1666,34 @@ impl GetCurrentMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentMetricDataError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentMetricDataError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentMetricDataError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentMetricDataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentMetricDataError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1713,24 +1737,40 @@ impl GetFederationTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(GetFederationTokenError::DuplicateResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFederationTokenError::DuplicateResource(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetFederationTokenError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFederationTokenError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFederationTokenError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFederationTokenError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFederationTokenError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFederationTokenError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFederationTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFederationTokenError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetFederationTokenError::UserNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFederationTokenError::UserNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1773,19 +1813,25 @@ impl GetMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMetricDataError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetMetricDataError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMetricDataError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMetricDataError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetMetricDataError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetMetricDataError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMetricDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMetricDataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetMetricDataError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetMetricDataError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1827,19 +1873,31 @@ impl ListContactFlowsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListContactFlowsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListContactFlowsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContactFlowsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListContactFlowsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListContactFlowsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListContactFlowsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContactFlowsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListContactFlowsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListContactFlowsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListContactFlowsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1881,27 +1939,34 @@ impl ListHoursOfOperationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHoursOfOperationsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHoursOfOperationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHoursOfOperationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHoursOfOperationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHoursOfOperationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1943,19 +2008,31 @@ impl ListPhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPhoneNumbersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListPhoneNumbersError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1997,19 +2074,19 @@ impl ListQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListQueuesError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListQueuesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQueuesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListQueuesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListQueuesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2051,23 +2128,34 @@ impl ListRoutingProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoutingProfilesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRoutingProfilesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoutingProfilesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRoutingProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoutingProfilesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2109,25 +2197,34 @@ impl ListSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2169,23 +2266,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2227,27 +2335,34 @@ impl ListUserHierarchyGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserHierarchyGroupsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserHierarchyGroupsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserHierarchyGroupsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserHierarchyGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserHierarchyGroupsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2289,19 +2404,19 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUsersError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUsersError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUsersError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListUsersError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2343,19 +2458,34 @@ impl StartChatContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartChatContactError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChatContactError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartChatContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChatContactError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartChatContactError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChatContactError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartChatContactError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChatContactError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartChatContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChatContactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2401,39 +2531,46 @@ impl StartOutboundVoiceContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DestinationNotAllowedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOutboundVoiceContactError::DestinationNotAllowed(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOutboundVoiceContactError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOutboundVoiceContactError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOutboundVoiceContactError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOutboundVoiceContactError::LimitExceeded(err.msg),
+                    )
                 }
                 "OutboundContactNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOutboundVoiceContactError::OutboundContactNotPermitted(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOutboundVoiceContactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2481,19 +2618,19 @@ impl StopContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ContactNotFoundException" => {
-                    return RusotoError::Service(StopContactError::ContactNotFound(err.msg))
+                    return RusotoError::service(&res, StopContactError::ContactNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopContactError::InternalService(err.msg))
+                    return RusotoError::service(&res, StopContactError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, StopContactError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopContactError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, StopContactError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopContactError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2535,19 +2672,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2589,19 +2726,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2641,24 +2784,28 @@ impl UpdateContactAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContactAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContactAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContactAttributesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContactAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2699,23 +2846,34 @@ impl UpdateUserHierarchyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserHierarchyError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserHierarchyError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserHierarchyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserHierarchyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserHierarchyError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2757,27 +2915,34 @@ impl UpdateUserIdentityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserIdentityInfoError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserIdentityInfoError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserIdentityInfoError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserIdentityInfoError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserIdentityInfoError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2819,27 +2984,34 @@ impl UpdateUserPhoneConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPhoneConfigError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPhoneConfigError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPhoneConfigError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPhoneConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserPhoneConfigError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2881,27 +3053,34 @@ impl UpdateUserRoutingProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserRoutingProfileError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserRoutingProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserRoutingProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserRoutingProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserRoutingProfileError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2945,29 +3124,34 @@ impl UpdateUserSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSecurityProfilesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSecurityProfilesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSecurityProfilesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSecurityProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserSecurityProfilesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3187,6 +3371,7 @@ impl ConnectClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3202,6 +3387,7 @@ impl ConnectClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectClient {
         ConnectClient { client, region }
     }
@@ -3218,6 +3404,9 @@ impl Connect for ConnectClient {
         let request_uri = format!("/users/{instance_id}", instance_id = input.instance_id);
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3253,6 +3442,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3284,6 +3476,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("DescribeUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3317,6 +3512,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("DescribeUserHierarchyGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3351,6 +3549,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("DescribeUserHierarchyStructure");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3383,6 +3584,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("GetContactAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3414,6 +3618,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("GetCurrentMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3448,6 +3655,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("GetFederationToken");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3479,6 +3689,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("GetMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3513,6 +3726,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListContactFlows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3558,6 +3774,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListHoursOfOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3598,6 +3817,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListPhoneNumbers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3648,6 +3870,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListQueues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3693,6 +3918,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListRoutingProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3733,6 +3961,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListSecurityProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3770,6 +4001,9 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3801,6 +4035,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListUserHierarchyGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3841,6 +4078,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3878,6 +4118,9 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/chat";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation_name("StartChatContact");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3910,6 +4153,9 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/outbound-voice";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation_name("StartOutboundVoiceContact");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3941,6 +4187,9 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/stop";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("StopContact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3972,6 +4221,9 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4002,6 +4254,9 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4035,6 +4290,9 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/attributes";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("UpdateContactAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4070,6 +4328,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("UpdateUserHierarchy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4104,6 +4365,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("UpdateUserIdentityInfo");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4138,6 +4402,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("UpdateUserPhoneConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4172,6 +4439,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("UpdateUserRoutingProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4206,6 +4476,9 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation_name("UpdateUserSecurityProfiles");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4227,3 +4500,365 @@ impl Connect for ConnectClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Connect API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ConnectClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ConnectBlockingClient {
+    client: ConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ConnectBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ConnectBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ConnectBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ConnectBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ConnectBlockingClient {
+        ConnectBlockingClient {
+            client: ConnectClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a user account for the specified Amazon Connect instance.</p> <p>For information about how to create user accounts using the Amazon Connect console, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/user-management.html">Add Users</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deletes a user account from the specified Amazon Connect instance.</p> <p>For information about what happens to a user's data when their account is deleted, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/delete-users.html">Delete Users from Your Amazon Connect Instance</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Describes the specified user account. You can find the instance ID in the console (it’s the final part of the ARN). The console does not display the user IDs. Instead, list the users and note the IDs provided in the output.</p>
+    ///
+    /// See `Connect::describe_user`.
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// <p>Describes the specified hierarchy group.</p>
+    ///
+    /// See `Connect::describe_user_hierarchy_group`.
+    pub fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> Result<DescribeUserHierarchyGroupResponse, RusotoError<DescribeUserHierarchyGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_user_hierarchy_group(input))
+    }
+
+    /// <p>Describes the hierarchy structure of the specified Amazon Connect instance.</p>
+    ///
+    /// See `Connect::describe_user_hierarchy_structure`.
+    pub fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> Result<
+        DescribeUserHierarchyStructureResponse,
+        RusotoError<DescribeUserHierarchyStructureError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_user_hierarchy_structure(input))
+    }
+
+    /// <p>Retrieves the contact attributes for the specified contact.</p>
+    ///
+    /// See `Connect::get_contact_attributes`.
+    pub fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> Result<GetContactAttributesResponse, RusotoError<GetContactAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_contact_attributes(input))
+    }
+
+    /// <p>Gets the real-time metric data from the specified Amazon Connect instance.</p> <p>For a description of each metric, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/real-time-metrics-definitions.html">Real-time Metrics Definitions</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::get_current_metric_data`.
+    pub fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> Result<GetCurrentMetricDataResponse, RusotoError<GetCurrentMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_current_metric_data(input))
+    }
+
+    /// <p>Retrieves a token for federation.</p>
+    ///
+    /// See `Connect::get_federation_token`.
+    pub fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
+        self.runtime
+            .block_on(self.client.get_federation_token(input))
+    }
+
+    /// <p>Gets historical metric data from the specified Amazon Connect instance.</p> <p>For a description of each historical metric, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/historical-metrics-definitions.html">Historical Metrics Definitions</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::get_metric_data`.
+    pub fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> Result<GetMetricDataResponse, RusotoError<GetMetricDataError>> {
+        self.runtime.block_on(self.client.get_metric_data(input))
+    }
+
+    /// <p>Provides information about the contact flows for the specified Amazon Connect instance.</p> <p>For more information about contact flows, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/concepts-contact-flows.html">Contact Flows</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_contact_flows`.
+    pub fn list_contact_flows(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> Result<ListContactFlowsResponse, RusotoError<ListContactFlowsError>> {
+        self.runtime.block_on(self.client.list_contact_flows(input))
+    }
+
+    /// <p>Provides information about the hours of operation for the specified Amazon Connect instance.</p> <p>For more information about hours of operation, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/set-hours-operation.html">Set the Hours of Operation for a Queue</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_hours_of_operations`.
+    pub fn list_hours_of_operations(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> Result<ListHoursOfOperationsResponse, RusotoError<ListHoursOfOperationsError>> {
+        self.runtime
+            .block_on(self.client.list_hours_of_operations(input))
+    }
+
+    /// <p>Provides information about the phone numbers for the specified Amazon Connect instance. </p> <p>For more information about phone numbers, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/contact-center-phone-number.html">Set Up Phone Numbers for Your Contact Center</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_phone_numbers`.
+    pub fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+        self.runtime.block_on(self.client.list_phone_numbers(input))
+    }
+
+    /// <p>Provides information about the queues for the specified Amazon Connect instance.</p> <p>For more information about queues, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/concepts-queues-standard-and-agent.html">Queues: Standard and Agent</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_queues`.
+    pub fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+        self.runtime.block_on(self.client.list_queues(input))
+    }
+
+    /// <p>Provides summary information about the routing profiles for the specified Amazon Connect instance.</p> <p>For more information about routing profiles, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/concepts-routing.html">Routing Profiles</a> and <a href="https://docs.aws.amazon.com/connect/latest/adminguide/routing-profiles.html">Create a Routing Profile</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_routing_profiles`.
+    pub fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> Result<ListRoutingProfilesResponse, RusotoError<ListRoutingProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_routing_profiles(input))
+    }
+
+    /// <p>Provides summary information about the security profiles for the specified Amazon Connect instance.</p> <p>For more information about security profiles, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/connect-security-profiles.html">Security Profiles</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_security_profiles`.
+    pub fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_security_profiles(input))
+    }
+
+    /// <p>Lists the tags for the specified resource.</p> <p>For sample policies that use tags, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/security_iam_id-based-policy-examples.html">Amazon Connect Identity-Based Policy Examples</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Provides summary information about the hierarchy groups for the specified Amazon Connect instance.</p> <p>For more information about agent hierarchies, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/agent-hierarchy.html">Set Up Agent Hierarchies</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::list_user_hierarchy_groups`.
+    pub fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> Result<ListUserHierarchyGroupsResponse, RusotoError<ListUserHierarchyGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_user_hierarchy_groups(input))
+    }
+
+    /// <p>Provides summary information about the users for the specified Amazon Connect instance.</p>
+    ///
+    /// See `Connect::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Initiates a contact flow to start a new chat for the customer. Response of this API provides a token required to obtain credentials from the <a href="https://docs.aws.amazon.com/connect-participant/latest/APIReference/API_CreateParticipantConnection.html">CreateParticipantConnection</a> API in the Amazon Connect Participant Service.</p> <p>When a new chat contact is successfully created, clients need to subscribe to the participant’s connection for the created chat within 5 minutes. This is achieved by invoking <a href="https://docs.aws.amazon.com/connect-participant/latest/APIReference/API_CreateParticipantConnection.html">CreateParticipantConnection</a> with WEBSOCKET and CONNECTION_CREDENTIALS. </p> <p>A 429 error occurs in two situations:</p> <ul> <li> <p>API rate limit is exceeded. API TPS throttling returns a <code>TooManyRequests</code> exception from the API Gateway.</p> </li> <li> <p>The <a href="https://docs.aws.amazon.com/connect/latest/adminguide/amazon-connect-service-limits.html">quota for concurrent active chats</a> is exceeded. Active chat throttling returns a <code>LimitExceededException</code>.</p> </li> </ul> <p>For more information about how chat works, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/chat.html">Chat</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::start_chat_contact`.
+    pub fn start_chat_contact(
+        &self,
+        input: StartChatContactRequest,
+    ) -> Result<StartChatContactResponse, RusotoError<StartChatContactError>> {
+        self.runtime.block_on(self.client.start_chat_contact(input))
+    }
+
+    /// <p><p>This API places an outbound call to a contact, and then initiates the contact flow. It performs the actions in the contact flow that&#39;s specified (in <code>ContactFlowId</code>).</p> <p>Agents are not involved in initiating the outbound API (that is, dialing the contact). If the contact flow places an outbound call to a contact, and then puts the contact in queue, that&#39;s when the call is routed to the agent, like any other inbound case.</p> <p>There is a 60 second dialing timeout for this operation. If the call is not connected after 60 seconds, it fails.</p> <note> <p>UK numbers with a 447 prefix are not allowed by default. Before you can dial these UK mobile numbers, you must submit a service quota increase request. For more information, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/amazon-connect-service-limits.html">Amazon Connect Service Quotas</a> in the <i>Amazon Connect Administrator Guide</i>. </p> </note></p>
+    ///
+    /// See `Connect::start_outbound_voice_contact`.
+    pub fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> Result<StartOutboundVoiceContactResponse, RusotoError<StartOutboundVoiceContactError>>
+    {
+        self.runtime
+            .block_on(self.client.start_outbound_voice_contact(input))
+    }
+
+    /// <p>Ends the specified contact.</p>
+    ///
+    /// See `Connect::stop_contact`.
+    pub fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> Result<StopContactResponse, RusotoError<StopContactError>> {
+        self.runtime.block_on(self.client.stop_contact(input))
+    }
+
+    /// <p>Adds the specified tags to the specified resource.</p> <p>The supported resource type is users.</p> <p>For sample policies that use tags, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/security_iam_id-based-policy-examples.html">Amazon Connect Identity-Based Policy Examples</a> in the <i>Amazon Connect Administrator Guide</i>.</p>
+    ///
+    /// See `Connect::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from the specified resource.</p>
+    ///
+    /// See `Connect::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Creates or updates the contact attributes associated with the specified contact.</p> <p>You can add or update attributes for both ongoing and completed contacts. For example, you can update the customer's name or the reason the customer called while the call is active, or add notes about steps that the agent took during the call that are displayed to the next agent that takes the call. You can also update attributes for a contact using data from your CRM application and save the data with the contact in Amazon Connect. You could also flag calls for additional analysis, such as legal review or identifying abusive callers.</p> <p>Contact attributes are available in Amazon Connect for 24 months, and are then deleted.</p> <p> <b>Important:</b> You cannot use the operation to update attributes for contacts that occurred prior to the release of the API, September 12, 2018. You can update attributes only for contacts that started after the release of the API. If you attempt to update attributes for a contact that occurred prior to the release of the API, a 400 error is returned. This applies also to queued callbacks that were initiated prior to the release of the API but are still active in your instance.</p>
+    ///
+    /// See `Connect::update_contact_attributes`.
+    pub fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> Result<UpdateContactAttributesResponse, RusotoError<UpdateContactAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_contact_attributes(input))
+    }
+
+    /// <p>Assigns the specified hierarchy group to the specified user.</p>
+    ///
+    /// See `Connect::update_user_hierarchy`.
+    pub fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> Result<(), RusotoError<UpdateUserHierarchyError>> {
+        self.runtime
+            .block_on(self.client.update_user_hierarchy(input))
+    }
+
+    /// <p><p>Updates the identity information for the specified user.</p> <important> <p>Someone with the ability to invoke <code>UpdateUserIndentityInfo</code> can change the login credentials of other users by changing their email address. This poses a security risk to your organization. They can change the email address of a user to the attacker&#39;s email address, and then reset the password through email. We strongly recommend limiting who has the ability to invoke <code>UpdateUserIndentityInfo</code>. For more information, see <a href="https://docs.aws.amazon.com/connect/latest/adminguide/security-profile-best-practices.html">Best Practices for Security Profiles</a> in the <i>Amazon Connect Administrator Guide</i>.</p> </important></p>
+    ///
+    /// See `Connect::update_user_identity_info`.
+    pub fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> Result<(), RusotoError<UpdateUserIdentityInfoError>> {
+        self.runtime
+            .block_on(self.client.update_user_identity_info(input))
+    }
+
+    /// <p>Updates the phone configuration settings for the specified user.</p>
+    ///
+    /// See `Connect::update_user_phone_config`.
+    pub fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> Result<(), RusotoError<UpdateUserPhoneConfigError>> {
+        self.runtime
+            .block_on(self.client.update_user_phone_config(input))
+    }
+
+    /// <p>Assigns the specified routing profile to the specified user.</p>
+    ///
+    /// See `Connect::update_user_routing_profile`.
+    pub fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> Result<(), RusotoError<UpdateUserRoutingProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_routing_profile(input))
+    }
+
+    /// <p>Assigns the specified security profiles to the specified user.</p>
+    ///
+    /// See `Connect::update_user_security_profiles`.
+    pub fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> Result<(), RusotoError<UpdateUserSecurityProfilesError>> {
+        self.runtime
+            .block_on(self.client.update_user_security_profiles(input))
+    }
+}
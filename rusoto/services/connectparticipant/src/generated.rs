@@ -278,19 +278,22 @@ impl CreateParticipantConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateParticipantConnectionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateParticipantConnectionError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateParticipantConnectionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -326,15 +329,22 @@ impl DisconnectParticipantError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisconnectParticipantError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisconnectParticipantError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisconnectParticipantError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisconnectParticipantError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisconnectParticipantError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisconnectParticipantError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -370,13 +380,13 @@ impl GetTranscriptError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTranscriptError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetTranscriptError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTranscriptError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetTranscriptError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTranscriptError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetTranscriptError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -412,13 +422,13 @@ impl SendEventError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendEventError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, SendEventError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SendEventError::InternalServer(err.msg))
+                    return RusotoError::service(&res, SendEventError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendEventError::Throttling(err.msg))
+                    return RusotoError::service(&res, SendEventError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -454,13 +464,13 @@ impl SendMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendMessageError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, SendMessageError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SendMessageError::InternalServer(err.msg))
+                    return RusotoError::service(&res, SendMessageError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendMessageError::Throttling(err.msg))
+                    return RusotoError::service(&res, SendMessageError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -531,6 +541,7 @@ impl ConnectParticipantClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -546,6 +557,7 @@ impl ConnectParticipantClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectParticipantClient {
         ConnectParticipantClient { client, region }
     }
@@ -563,6 +575,9 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/connection";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateParticipantConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -596,6 +611,9 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/disconnect";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DisconnectParticipant");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -629,6 +647,9 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/transcript";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetTranscript");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -662,6 +683,9 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/event";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SendEvent");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -695,6 +719,9 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/message";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SendMessage");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -719,3 +746,109 @@ impl ConnectParticipant for ConnectParticipantClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Connect Participant API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ConnectParticipantClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ConnectParticipantBlockingClient {
+    client: ConnectParticipantClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ConnectParticipantBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ConnectParticipantBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ConnectParticipantBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConnectParticipantBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ConnectParticipantBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient {
+            client: ConnectParticipantClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates the participant's connection. Note that ParticipantToken is used for invoking this API instead of ConnectionToken.</p> <p>The participant token is valid for the lifetime of the participant – until the they are part of a contact.</p> <p>The response URL for <code>WEBSOCKET</code> Type has a connect expiry timeout of 100s. Clients must manually connect to the returned websocket URL and subscribe to the desired topic. </p> <p>For chat, you need to publish the following on the established websocket connection:</p> <p> <code>{"topic":"aws/subscribe","content":{"topics":["aws/chat"]}}</code> </p> <p>Upon websocket URL expiry, as specified in the response ConnectionExpiry parameter, clients need to call this API again to obtain a new websocket URL and perform the same steps as before.</p>
+    ///
+    /// See `ConnectParticipant::create_participant_connection`.
+    pub fn create_participant_connection(
+        &self,
+        input: CreateParticipantConnectionRequest,
+    ) -> Result<CreateParticipantConnectionResponse, RusotoError<CreateParticipantConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_participant_connection(input))
+    }
+
+    /// <p>Disconnects a participant. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    ///
+    /// See `ConnectParticipant::disconnect_participant`.
+    pub fn disconnect_participant(
+        &self,
+        input: DisconnectParticipantRequest,
+    ) -> Result<DisconnectParticipantResponse, RusotoError<DisconnectParticipantError>> {
+        self.runtime
+            .block_on(self.client.disconnect_participant(input))
+    }
+
+    /// <p>Retrieves a transcript of the session. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    ///
+    /// See `ConnectParticipant::get_transcript`.
+    pub fn get_transcript(
+        &self,
+        input: GetTranscriptRequest,
+    ) -> Result<GetTranscriptResponse, RusotoError<GetTranscriptError>> {
+        self.runtime.block_on(self.client.get_transcript(input))
+    }
+
+    /// <p>Sends an event. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    ///
+    /// See `ConnectParticipant::send_event`.
+    pub fn send_event(
+        &self,
+        input: SendEventRequest,
+    ) -> Result<SendEventResponse, RusotoError<SendEventError>> {
+        self.runtime.block_on(self.client.send_event(input))
+    }
+
+    /// <p>Sends a message. Note that ConnectionToken is used for invoking this API instead of ParticipantToken.</p>
+    ///
+    /// See `ConnectParticipant::send_message`.
+    pub fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResponse, RusotoError<SendMessageError>> {
+        self.runtime.block_on(self.client.send_message(input))
+    }
+}
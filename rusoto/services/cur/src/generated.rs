@@ -166,9 +166,10 @@ impl DeleteReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteReportDefinitionError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReportDefinitionError::InternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -198,9 +199,10 @@ impl DescribeReportDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeReportDefinitionsError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReportDefinitionsError::InternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -230,9 +232,10 @@ impl ModifyReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ModifyReportDefinitionError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyReportDefinitionError::InternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -266,17 +269,22 @@ impl PutReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateReportNameException" => {
-                    return RusotoError::Service(PutReportDefinitionError::DuplicateReportName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutReportDefinitionError::DuplicateReportName(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutReportDefinitionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutReportDefinitionError::InternalError(err.msg),
+                    )
                 }
                 "ReportLimitReachedException" => {
-                    return RusotoError::Service(PutReportDefinitionError::ReportLimitReached(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutReportDefinitionError::ReportLimitReached(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -341,6 +349,7 @@ impl CostAndUsageReportClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -356,6 +365,7 @@ impl CostAndUsageReportClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CostAndUsageReportClient {
         CostAndUsageReportClient { client, region }
     }
@@ -369,6 +379,9 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: DeleteReportDefinitionRequest,
     ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReportDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.DeleteReportDefinition",
@@ -392,6 +405,9 @@ impl CostAndUsageReport for CostAndUsageReportClient {
     ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReportDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.DescribeReportDefinitions",
@@ -414,6 +430,9 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: ModifyReportDefinitionRequest,
     ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyReportDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.ModifyReportDefinition",
@@ -436,6 +455,9 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: PutReportDefinitionRequest,
     ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutReportDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.PutReportDefinition",
@@ -451,3 +473,101 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         proto::json::ResponsePayload::new(&response).deserialize::<PutReportDefinitionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Cost and Usage Report Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CostAndUsageReportClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CostAndUsageReportBlockingClient {
+    client: CostAndUsageReportClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CostAndUsageReportBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CostAndUsageReportBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CostAndUsageReportBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CostAndUsageReportBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CostAndUsageReportBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient {
+            client: CostAndUsageReportClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Deletes the specified report.</p>
+    ///
+    /// See `CostAndUsageReport::delete_report_definition`.
+    pub fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_report_definition(input))
+    }
+
+    /// <p>Lists the AWS Cost and Usage reports available to this account.</p>
+    ///
+    /// See `CostAndUsageReport::describe_report_definitions`.
+    pub fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_report_definitions(input))
+    }
+
+    /// <p>Allows you to programatically update your report preferences.</p>
+    ///
+    /// See `CostAndUsageReport::modify_report_definition`.
+    pub fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
+        self.runtime
+            .block_on(self.client.modify_report_definition(input))
+    }
+
+    /// <p>Creates a new report using the description that you provide.</p>
+    ///
+    /// See `CostAndUsageReport::put_report_definition`.
+    pub fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
+        self.runtime
+            .block_on(self.client.put_report_definition(input))
+    }
+}
@@ -1277,16 +1277,16 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CancelJobError::Conflict(err.msg))
+                    return RusotoError::service(&res, CancelJobError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelJobError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CancelJobError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CancelJobError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, CancelJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1325,16 +1325,19 @@ impl CreateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::InternalServer(err.msg))
                 }
                 "ServiceLimitExceededException" => {
-                    return RusotoError::Service(CreateDataSetError::ServiceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSetError::ServiceLimitExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1373,16 +1376,16 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateJobError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateJobError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateJobError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateJobError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1421,16 +1424,19 @@ impl CreateRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateRevisionError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateRevisionError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRevisionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRevisionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateRevisionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1471,19 +1477,19 @@ impl DeleteAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAssetError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteAssetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAssetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1525,19 +1531,22 @@ impl DeleteDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDataSetError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1579,19 +1588,22 @@ impl DeleteRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteRevisionError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRevisionError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteRevisionError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteRevisionError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRevisionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRevisionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteRevisionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1629,13 +1641,13 @@ impl GetAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAssetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetAssetError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetAssetError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAssetError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetAssetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1671,13 +1683,13 @@ impl GetDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDataSetError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetDataSetError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetDataSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1713,13 +1725,13 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetJobError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetJobError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1755,13 +1767,13 @@ impl GetRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetRevisionError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetRevisionError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRevisionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetRevisionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1797,15 +1809,22 @@ impl ListDataSetRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSetRevisionsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSetRevisionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSetRevisionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1841,13 +1860,13 @@ impl ListDataSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSetsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1883,13 +1902,13 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListJobsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListJobsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListJobsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1925,13 +1944,19 @@ impl ListRevisionAssetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRevisionAssetsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRevisionAssetsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListRevisionAssetsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1993,19 +2018,19 @@ impl StartJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartJobError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StartJobError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartJobError::Conflict(err.msg))
+                    return RusotoError::service(&res, StartJobError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartJobError::InternalServer(err.msg))
+                    return RusotoError::service(&res, StartJobError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartJobError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2091,19 +2116,19 @@ impl UpdateAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAssetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateAssetError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAssetError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateAssetError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateAssetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateAssetError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAssetError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAssetError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateAssetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2143,16 +2168,19 @@ impl UpdateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2193,19 +2221,22 @@ impl UpdateRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateRevisionError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRevisionError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateRevisionError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRevisionError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateRevisionError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRevisionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRevisionError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateRevisionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2377,6 +2408,7 @@ impl DataExchangeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2392,6 +2424,7 @@ impl DataExchangeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DataExchangeClient {
         DataExchangeClient { client, region }
     }
@@ -2405,6 +2438,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2432,6 +2468,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("CreateDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2463,6 +2502,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2497,6 +2539,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("CreateRevision");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2533,6 +2578,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("DeleteAsset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2563,6 +2611,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2594,6 +2645,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("DeleteRevision");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2626,6 +2680,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("GetAsset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2657,6 +2714,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("GetDataSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2685,6 +2745,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("GetJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2717,6 +2780,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("GetRevision");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2748,6 +2814,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("ListDataSetRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2785,6 +2854,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("ListDataSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2825,6 +2897,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2872,6 +2947,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("ListRevisionAssets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2909,6 +2987,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2937,6 +3018,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("StartJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2965,6 +3049,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2995,6 +3082,9 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3033,6 +3123,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("UpdateAsset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3067,6 +3160,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3102,6 +3198,9 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation_name("UpdateRevision");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3124,3 +3223,273 @@ impl DataExchange for DataExchangeClient {
         }
     }
 }
+
+/// A blocking client for the AWS Data Exchange API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DataExchangeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DataExchangeBlockingClient {
+    client: DataExchangeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DataExchangeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DataExchangeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DataExchangeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataExchangeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DataExchangeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient {
+            client: DataExchangeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>This operation cancels a job. Jobs can be cancelled only when they are in the WAITING state.</p>
+    ///
+    /// See `DataExchange::cancel_job`.
+    pub fn cancel_job(&self, input: CancelJobRequest) -> Result<(), RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p>This operation creates a data set.</p>
+    ///
+    /// See `DataExchange::create_data_set`.
+    pub fn create_data_set(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+        self.runtime.block_on(self.client.create_data_set(input))
+    }
+
+    /// <p>This operation creates a job.</p>
+    ///
+    /// See `DataExchange::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>This operation creates a revision for a data set.</p>
+    ///
+    /// See `DataExchange::create_revision`.
+    pub fn create_revision(
+        &self,
+        input: CreateRevisionRequest,
+    ) -> Result<CreateRevisionResponse, RusotoError<CreateRevisionError>> {
+        self.runtime.block_on(self.client.create_revision(input))
+    }
+
+    /// <p>This operation deletes an asset.</p>
+    ///
+    /// See `DataExchange::delete_asset`.
+    pub fn delete_asset(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<(), RusotoError<DeleteAssetError>> {
+        self.runtime.block_on(self.client.delete_asset(input))
+    }
+
+    /// <p>This operation deletes a data set.</p>
+    ///
+    /// See `DataExchange::delete_data_set`.
+    pub fn delete_data_set(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<(), RusotoError<DeleteDataSetError>> {
+        self.runtime.block_on(self.client.delete_data_set(input))
+    }
+
+    /// <p>This operation deletes a revision.</p>
+    ///
+    /// See `DataExchange::delete_revision`.
+    pub fn delete_revision(
+        &self,
+        input: DeleteRevisionRequest,
+    ) -> Result<(), RusotoError<DeleteRevisionError>> {
+        self.runtime.block_on(self.client.delete_revision(input))
+    }
+
+    /// <p>This operation returns information about an asset.</p>
+    ///
+    /// See `DataExchange::get_asset`.
+    pub fn get_asset(
+        &self,
+        input: GetAssetRequest,
+    ) -> Result<GetAssetResponse, RusotoError<GetAssetError>> {
+        self.runtime.block_on(self.client.get_asset(input))
+    }
+
+    /// <p>This operation returns information about a data set.</p>
+    ///
+    /// See `DataExchange::get_data_set`.
+    pub fn get_data_set(
+        &self,
+        input: GetDataSetRequest,
+    ) -> Result<GetDataSetResponse, RusotoError<GetDataSetError>> {
+        self.runtime.block_on(self.client.get_data_set(input))
+    }
+
+    /// <p>This operation returns information about a job.</p>
+    ///
+    /// See `DataExchange::get_job`.
+    pub fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// <p>This operation returns information about a revision.</p>
+    ///
+    /// See `DataExchange::get_revision`.
+    pub fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+        self.runtime.block_on(self.client.get_revision(input))
+    }
+
+    /// <p>This operation lists a data set's revisions sorted by CreatedAt in descending order.</p>
+    ///
+    /// See `DataExchange::list_data_set_revisions`.
+    pub fn list_data_set_revisions(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> Result<ListDataSetRevisionsResponse, RusotoError<ListDataSetRevisionsError>> {
+        self.runtime
+            .block_on(self.client.list_data_set_revisions(input))
+    }
+
+    /// <p>This operation lists your data sets. When listing by origin OWNED, results are sorted by CreatedAt in descending order. When listing by origin ENTITLED, there is no order and the maxResults parameter is ignored.</p>
+    ///
+    /// See `DataExchange::list_data_sets`.
+    pub fn list_data_sets(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+        self.runtime.block_on(self.client.list_data_sets(input))
+    }
+
+    /// <p>This operation lists your jobs sorted by CreatedAt in descending order.</p>
+    ///
+    /// See `DataExchange::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>This operation lists a revision's assets sorted alphabetically in descending order.</p>
+    ///
+    /// See `DataExchange::list_revision_assets`.
+    pub fn list_revision_assets(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> Result<ListRevisionAssetsResponse, RusotoError<ListRevisionAssetsError>> {
+        self.runtime
+            .block_on(self.client.list_revision_assets(input))
+    }
+
+    /// <p>This operation lists the tags on the resource.</p>
+    ///
+    /// See `DataExchange::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>This operation starts a job.</p>
+    ///
+    /// See `DataExchange::start_job`.
+    pub fn start_job(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<StartJobResponse, RusotoError<StartJobError>> {
+        self.runtime.block_on(self.client.start_job(input))
+    }
+
+    /// <p>This operation tags a resource.</p>
+    ///
+    /// See `DataExchange::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>This operation removes one or more tags from a resource.</p>
+    ///
+    /// See `DataExchange::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>This operation updates an asset.</p>
+    ///
+    /// See `DataExchange::update_asset`.
+    pub fn update_asset(
+        &self,
+        input: UpdateAssetRequest,
+    ) -> Result<UpdateAssetResponse, RusotoError<UpdateAssetError>> {
+        self.runtime.block_on(self.client.update_asset(input))
+    }
+
+    /// <p>This operation updates a data set.</p>
+    ///
+    /// See `DataExchange::update_data_set`.
+    pub fn update_data_set(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+        self.runtime.block_on(self.client.update_data_set(input))
+    }
+
+    /// <p>This operation updates a revision.</p>
+    ///
+    /// See `DataExchange::update_revision`.
+    pub fn update_revision(
+        &self,
+        input: UpdateRevisionRequest,
+    ) -> Result<UpdateRevisionResponse, RusotoError<UpdateRevisionError>> {
+        self.runtime.block_on(self.client.update_revision(input))
+    }
+}
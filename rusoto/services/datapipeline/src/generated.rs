@@ -765,18 +765,28 @@ impl ActivatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ActivatePipelineError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ActivatePipelineError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ActivatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivatePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ActivatePipelineError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivatePipelineError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ActivatePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivatePipelineError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -815,16 +825,16 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(AddTagsError::InternalServiceError(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InternalServiceError(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InvalidRequest(err.msg))
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(AddTagsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(&res, AddTagsError::PipelineDeleted(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(&res, AddTagsError::PipelineNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -859,10 +869,13 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreatePipelineError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -899,18 +912,28 @@ impl DeactivatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeactivatePipelineError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeactivatePipelineError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeactivatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivatePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DeactivatePipelineError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivatePipelineError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DeactivatePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivatePipelineError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -947,13 +970,19 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeletePipelineError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeletePipelineError::InvalidRequest(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -991,18 +1020,28 @@ impl DescribeObjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeObjectsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeObjectsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeObjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeObjectsError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DescribeObjectsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeObjectsError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DescribeObjectsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeObjectsError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1041,18 +1080,28 @@ impl DescribePipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribePipelinesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelinesError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelinesError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DescribePipelinesError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelinesError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DescribePipelinesError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelinesError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1093,21 +1142,34 @@ impl EvaluateExpressionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(EvaluateExpressionError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EvaluateExpressionError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(EvaluateExpressionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EvaluateExpressionError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(EvaluateExpressionError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EvaluateExpressionError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(EvaluateExpressionError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EvaluateExpressionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(EvaluateExpressionError::TaskNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EvaluateExpressionError::TaskNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1147,24 +1209,28 @@ impl GetPipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineDefinitionError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineDefinitionError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::PipelineDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineDefinitionError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPipelineDefinitionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1199,10 +1265,13 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListPipelinesError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPipelinesError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListPipelinesError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1237,13 +1306,16 @@ impl PollForTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PollForTaskError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PollForTaskError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PollForTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, PollForTaskError::InvalidRequest(err.msg))
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(PollForTaskError::TaskNotFound(err.msg))
+                    return RusotoError::service(&res, PollForTaskError::TaskNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1281,24 +1353,28 @@ impl PutPipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPipelineDefinitionError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPipelineDefinitionError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::PipelineDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPipelineDefinitionError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPipelineDefinitionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1337,16 +1413,19 @@ impl QueryObjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(QueryObjectsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        QueryObjectsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(QueryObjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, QueryObjectsError::InvalidRequest(err.msg))
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(QueryObjectsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(&res, QueryObjectsError::PipelineDeleted(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(QueryObjectsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(&res, QueryObjectsError::PipelineNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1385,16 +1464,19 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RemoveTagsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::InvalidRequest(err.msg))
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(RemoveTagsError::PipelineDeleted(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::PipelineDeleted(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsError::PipelineNotFound(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::PipelineNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1435,21 +1517,34 @@ impl ReportTaskProgressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ReportTaskProgressError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ReportTaskProgressError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReportTaskProgressError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReportTaskProgressError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ReportTaskProgressError::PipelineDeleted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReportTaskProgressError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ReportTaskProgressError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReportTaskProgressError::PipelineNotFound(err.msg),
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(ReportTaskProgressError::TaskNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReportTaskProgressError::TaskNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1485,14 +1580,16 @@ impl ReportTaskRunnerHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ReportTaskRunnerHeartbeatError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReportTaskRunnerHeartbeatError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ReportTaskRunnerHeartbeatError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1531,16 +1628,19 @@ impl SetStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(SetStatusError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetStatusError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, SetStatusError::InvalidRequest(err.msg))
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(SetStatusError::PipelineDeleted(err.msg))
+                    return RusotoError::service(&res, SetStatusError::PipelineDeleted(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(SetStatusError::PipelineNotFound(err.msg))
+                    return RusotoError::service(&res, SetStatusError::PipelineNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1581,19 +1681,25 @@ impl SetTaskStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(SetTaskStatusError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetTaskStatusError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetTaskStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, SetTaskStatusError::InvalidRequest(err.msg))
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(SetTaskStatusError::PipelineDeleted(err.msg))
+                    return RusotoError::service(&res, SetTaskStatusError::PipelineDeleted(err.msg))
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(SetTaskStatusError::PipelineNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetTaskStatusError::PipelineNotFound(err.msg),
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(SetTaskStatusError::TaskNotFound(err.msg))
+                    return RusotoError::service(&res, SetTaskStatusError::TaskNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1635,24 +1741,28 @@ impl ValidatePipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ValidatePipelineDefinitionError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidatePipelineDefinitionError::InvalidRequest(err.msg),
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::PipelineDeleted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidatePipelineDefinitionError::PipelineDeleted(err.msg),
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::PipelineNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidatePipelineDefinitionError::PipelineNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1807,6 +1917,7 @@ impl DataPipelineClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1822,6 +1933,7 @@ impl DataPipelineClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineClient {
         DataPipelineClient { client, region }
     }
@@ -1835,6 +1947,9 @@ impl DataPipeline for DataPipelineClient {
         input: ActivatePipelineInput,
     ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ActivatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.ActivatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1853,6 +1968,9 @@ impl DataPipeline for DataPipelineClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1871,6 +1989,9 @@ impl DataPipeline for DataPipelineClient {
         input: CreatePipelineInput,
     ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1889,6 +2010,9 @@ impl DataPipeline for DataPipelineClient {
         input: DeactivatePipelineInput,
     ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeactivatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.DeactivatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1907,6 +2031,9 @@ impl DataPipeline for DataPipelineClient {
         input: DeletePipelineInput,
     ) -> Result<(), RusotoError<DeletePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1924,6 +2051,9 @@ impl DataPipeline for DataPipelineClient {
         input: DescribeObjectsInput,
     ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeObjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DataPipeline.DescribeObjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1942,6 +2072,9 @@ impl DataPipeline for DataPipelineClient {
         input: DescribePipelinesInput,
     ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePipelines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DataPipeline.DescribePipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1960,6 +2093,9 @@ impl DataPipeline for DataPipelineClient {
         input: EvaluateExpressionInput,
     ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EvaluateExpression");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.EvaluateExpression");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1978,6 +2114,9 @@ impl DataPipeline for DataPipelineClient {
         input: GetPipelineDefinitionInput,
     ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPipelineDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DataPipeline.GetPipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1996,6 +2135,9 @@ impl DataPipeline for DataPipelineClient {
         input: ListPipelinesInput,
     ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPipelines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DataPipeline.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2014,6 +2156,9 @@ impl DataPipeline for DataPipelineClient {
         input: PollForTaskInput,
     ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PollForTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.PollForTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2032,6 +2177,9 @@ impl DataPipeline for DataPipelineClient {
         input: PutPipelineDefinitionInput,
     ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutPipelineDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.PutPipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2050,6 +2198,9 @@ impl DataPipeline for DataPipelineClient {
         input: QueryObjectsInput,
     ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("QueryObjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DataPipeline.QueryObjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2068,6 +2219,9 @@ impl DataPipeline for DataPipelineClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.RemoveTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2086,6 +2240,9 @@ impl DataPipeline for DataPipelineClient {
         input: ReportTaskProgressInput,
     ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ReportTaskProgress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.ReportTaskProgress");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2104,6 +2261,9 @@ impl DataPipeline for DataPipelineClient {
         input: ReportTaskRunnerHeartbeatInput,
     ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ReportTaskRunnerHeartbeat");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.ReportTaskRunnerHeartbeat");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2120,6 +2280,9 @@ impl DataPipeline for DataPipelineClient {
     /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
     async fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.SetStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2137,6 +2300,9 @@ impl DataPipeline for DataPipelineClient {
         input: SetTaskStatusInput,
     ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetTaskStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.SetTaskStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2156,6 +2322,9 @@ impl DataPipeline for DataPipelineClient {
     ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ValidatePipelineDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DataPipeline.ValidatePipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2169,3 +2338,248 @@ impl DataPipeline for DataPipelineClient {
             .deserialize::<ValidatePipelineDefinitionOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Data Pipeline API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DataPipelineClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DataPipelineBlockingClient {
+    client: DataPipelineClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DataPipelineBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DataPipelineBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DataPipelineBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataPipelineBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DataPipelineBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient {
+            client: DataPipelineClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Validates the specified pipeline and starts processing pipeline tasks. If the pipeline does not pass validation, activation fails.</p> <p>If you need to pause the pipeline to investigate an issue with a component, such as a data source or script, call <a>DeactivatePipeline</a>.</p> <p>To activate a finished pipeline, modify the end date for the pipeline and then activate it.</p>
+    ///
+    /// See `DataPipeline::activate_pipeline`.
+    pub fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
+        self.runtime.block_on(self.client.activate_pipeline(input))
+    }
+
+    /// <p>Adds or modifies tags for the specified pipeline.</p>
+    ///
+    /// See `DataPipeline::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Creates a new, empty pipeline. Use <a>PutPipelineDefinition</a> to populate the pipeline.</p>
+    ///
+    /// See `DataPipeline::create_pipeline`.
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// <p>Deactivates the specified running pipeline. The pipeline is set to the <code>DEACTIVATING</code> state until the deactivation process completes.</p> <p>To resume a deactivated pipeline, use <a>ActivatePipeline</a>. By default, the pipeline resumes from the last completed execution. Optionally, you can specify the date and time to resume the pipeline.</p>
+    ///
+    /// See `DataPipeline::deactivate_pipeline`.
+    pub fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
+        self.runtime
+            .block_on(self.client.deactivate_pipeline(input))
+    }
+
+    /// <p>Deletes a pipeline, its pipeline definition, and its run history. AWS Data Pipeline attempts to cancel instances associated with the pipeline that are currently being processed by task runners.</p> <p>Deleting a pipeline cannot be undone. You cannot query or restore a deleted pipeline. To temporarily pause a pipeline instead of deleting it, call <a>SetStatus</a> with the status set to <code>PAUSE</code> on individual components. Components that are paused by <a>SetStatus</a> can be resumed.</p>
+    ///
+    /// See `DataPipeline::delete_pipeline`.
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// <p>Gets the object definitions for a set of objects associated with the pipeline. Object definitions are composed of a set of fields that define the properties of the object.</p>
+    ///
+    /// See `DataPipeline::describe_objects`.
+    pub fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
+        self.runtime.block_on(self.client.describe_objects(input))
+    }
+
+    /// <p>Retrieves metadata about one or more pipelines. The information retrieved includes the name of the pipeline, the pipeline identifier, its current state, and the user account that owns the pipeline. Using account credentials, you can retrieve metadata about pipelines that you or your IAM users have created. If you are using an IAM user account, you can retrieve metadata about only those pipelines for which you have read permissions.</p> <p>To retrieve the full pipeline definition instead of metadata about the pipeline, call <a>GetPipelineDefinition</a>.</p>
+    ///
+    /// See `DataPipeline::describe_pipelines`.
+    pub fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
+        self.runtime.block_on(self.client.describe_pipelines(input))
+    }
+
+    /// <p>Task runners call <code>EvaluateExpression</code> to evaluate a string in the context of the specified object. For example, a task runner can evaluate SQL queries stored in Amazon S3.</p>
+    ///
+    /// See `DataPipeline::evaluate_expression`.
+    pub fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
+        self.runtime
+            .block_on(self.client.evaluate_expression(input))
+    }
+
+    /// <p>Gets the definition of the specified pipeline. You can call <code>GetPipelineDefinition</code> to retrieve the pipeline definition that you provided using <a>PutPipelineDefinition</a>.</p>
+    ///
+    /// See `DataPipeline::get_pipeline_definition`.
+    pub fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_pipeline_definition(input))
+    }
+
+    /// <p>Lists the pipeline identifiers for all active pipelines that you have permission to access.</p>
+    ///
+    /// See `DataPipeline::list_pipelines`.
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// <p>Task runners call <code>PollForTask</code> to receive a task to perform from AWS Data Pipeline. The task runner specifies which tasks it can perform by setting a value for the <code>workerGroup</code> parameter. The task returned can come from any of the pipelines that match the <code>workerGroup</code> value passed in by the task runner and that was launched using the IAM user credentials specified by the task runner.</p> <p>If tasks are ready in the work queue, <code>PollForTask</code> returns a response immediately. If no tasks are available in the queue, <code>PollForTask</code> uses long-polling and holds on to a poll connection for up to a 90 seconds, during which time the first newly scheduled task is handed to the task runner. To accomodate this, set the socket timeout in your task runner to 90 seconds. The task runner should not call <code>PollForTask</code> again on the same <code>workerGroup</code> until it receives a response, and this can take up to 90 seconds. </p>
+    ///
+    /// See `DataPipeline::poll_for_task`.
+    pub fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
+        self.runtime.block_on(self.client.poll_for_task(input))
+    }
+
+    /// <p>Adds tasks, schedules, and preconditions to the specified pipeline. You can use <code>PutPipelineDefinition</code> to populate a new pipeline.</p> <p> <code>PutPipelineDefinition</code> also validates the configuration as it adds it to the pipeline. Changes to the pipeline are saved unless one of the following three validation errors exists in the pipeline. </p> <ol> <li>An object is missing a name or identifier field.</li> <li>A string or reference field is empty.</li> <li>The number of objects in the pipeline exceeds the maximum allowed objects.</li> <li>The pipeline is in a FINISHED state.</li> </ol> <p> Pipeline object definitions are passed to the <code>PutPipelineDefinition</code> action and returned by the <a>GetPipelineDefinition</a> action. </p>
+    ///
+    /// See `DataPipeline::put_pipeline_definition`.
+    pub fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
+        self.runtime
+            .block_on(self.client.put_pipeline_definition(input))
+    }
+
+    /// <p>Queries the specified pipeline for the names of objects that match the specified set of conditions.</p>
+    ///
+    /// See `DataPipeline::query_objects`.
+    pub fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
+        self.runtime.block_on(self.client.query_objects(input))
+    }
+
+    /// <p>Removes existing tags from the specified pipeline.</p>
+    ///
+    /// See `DataPipeline::remove_tags`.
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// <p>Task runners call <code>ReportTaskProgress</code> when assigned a task to acknowledge that it has the task. If the web service does not receive this acknowledgement within 2 minutes, it assigns the task in a subsequent <a>PollForTask</a> call. After this initial acknowledgement, the task runner only needs to report progress every 15 minutes to maintain its ownership of the task. You can change this reporting time from 15 minutes by specifying a <code>reportProgressTimeout</code> field in your pipeline.</p> <p>If a task runner does not report its status after 5 minutes, AWS Data Pipeline assumes that the task runner is unable to process the task and reassigns the task in a subsequent response to <a>PollForTask</a>. Task runners should call <code>ReportTaskProgress</code> every 60 seconds.</p>
+    ///
+    /// See `DataPipeline::report_task_progress`.
+    pub fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
+        self.runtime
+            .block_on(self.client.report_task_progress(input))
+    }
+
+    /// <p>Task runners call <code>ReportTaskRunnerHeartbeat</code> every 15 minutes to indicate that they are operational. If the AWS Data Pipeline Task Runner is launched on a resource managed by AWS Data Pipeline, the web service can use this call to detect when the task runner application has failed and restart a new instance.</p>
+    ///
+    /// See `DataPipeline::report_task_runner_heartbeat`.
+    pub fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
+        self.runtime
+            .block_on(self.client.report_task_runner_heartbeat(input))
+    }
+
+    /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
+    ///
+    /// See `DataPipeline::set_status`.
+    pub fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
+        self.runtime.block_on(self.client.set_status(input))
+    }
+
+    /// <p>Task runners call <code>SetTaskStatus</code> to notify AWS Data Pipeline that a task is completed and provide information about the final status. A task runner makes this call regardless of whether the task was sucessful. A task runner does not need to call <code>SetTaskStatus</code> for tasks that are canceled by the web service during a call to <a>ReportTaskProgress</a>.</p>
+    ///
+    /// See `DataPipeline::set_task_status`.
+    pub fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
+        self.runtime.block_on(self.client.set_task_status(input))
+    }
+
+    /// <p>Validates the specified pipeline definition to ensure that it is well formed and can be run without error.</p>
+    ///
+    /// See `DataPipeline::validate_pipeline_definition`.
+    pub fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.validate_pipeline_definition(input))
+    }
+}
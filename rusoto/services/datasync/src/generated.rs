@@ -1249,10 +1249,13 @@ impl CancelTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CancelTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service(&res, CancelTaskExecutionError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelTaskExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1285,10 +1288,10 @@ impl CreateAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateAgentError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateAgentError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateAgentError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1321,10 +1324,13 @@ impl CreateLocationEfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationEfsError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateLocationEfsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationEfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLocationEfsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1357,12 +1363,16 @@ impl CreateLocationFsxWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationFsxWindowsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLocationFsxWindowsError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationFsxWindowsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLocationFsxWindowsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1395,10 +1405,13 @@ impl CreateLocationNfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationNfsError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateLocationNfsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationNfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLocationNfsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1431,10 +1444,13 @@ impl CreateLocationS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationS3Error::Internal(err.msg))
+                    return RusotoError::service(&res, CreateLocationS3Error::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationS3Error::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLocationS3Error::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1467,10 +1483,13 @@ impl CreateLocationSmbError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationSmbError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateLocationSmbError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationSmbError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLocationSmbError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1503,10 +1522,10 @@ impl CreateTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateTaskError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateTaskError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateTaskError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1539,10 +1558,10 @@ impl DeleteAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAgentError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteAgentError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAgentError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1575,10 +1594,10 @@ impl DeleteLocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteLocationError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteLocationError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteLocationError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteLocationError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1611,10 +1630,10 @@ impl DeleteTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTaskError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteTaskError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteTaskError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1647,10 +1666,10 @@ impl DescribeAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAgentError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeAgentError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeAgentError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1683,10 +1702,13 @@ impl DescribeLocationEfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationEfsError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeLocationEfsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationEfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationEfsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1721,12 +1743,16 @@ impl DescribeLocationFsxWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationFsxWindowsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationFsxWindowsError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationFsxWindowsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationFsxWindowsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1759,10 +1785,13 @@ impl DescribeLocationNfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationNfsError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeLocationNfsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationNfsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationNfsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1795,10 +1824,13 @@ impl DescribeLocationS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationS3Error::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeLocationS3Error::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationS3Error::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationS3Error::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1831,10 +1863,13 @@ impl DescribeLocationSmbError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationSmbError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeLocationSmbError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationSmbError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationSmbError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1867,10 +1902,10 @@ impl DescribeTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeTaskError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeTaskError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeTaskError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1903,12 +1938,16 @@ impl DescribeTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskExecutionError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTaskExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1941,10 +1980,10 @@ impl ListAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListAgentsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListAgentsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAgentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListAgentsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1977,10 +2016,10 @@ impl ListLocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListLocationsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListLocationsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLocationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListLocationsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2013,10 +2052,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2049,10 +2091,13 @@ impl ListTaskExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTaskExecutionsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTaskExecutionsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTaskExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTaskExecutionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2085,10 +2130,10 @@ impl ListTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTasksError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTasksError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTasksError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListTasksError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2121,10 +2166,13 @@ impl StartTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(StartTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service(&res, StartTaskExecutionError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTaskExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2157,10 +2205,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2193,10 +2241,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2229,10 +2277,10 @@ impl UpdateAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateAgentError::Internal(err.msg))
+                    return RusotoError::service(&res, UpdateAgentError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAgentError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2265,10 +2313,10 @@ impl UpdateTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateTaskError::Internal(err.msg))
+                    return RusotoError::service(&res, UpdateTaskError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateTaskError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2482,6 +2530,7 @@ impl DataSyncClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2497,6 +2546,7 @@ impl DataSyncClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DataSyncClient {
         DataSyncClient { client, region }
     }
@@ -2510,6 +2560,9 @@ impl DataSync for DataSyncClient {
         input: CancelTaskExecutionRequest,
     ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelTaskExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CancelTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2528,6 +2581,9 @@ impl DataSync for DataSyncClient {
         input: CreateAgentRequest,
     ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAgent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2546,6 +2602,9 @@ impl DataSync for DataSyncClient {
         input: CreateLocationEfsRequest,
     ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLocationEfs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateLocationEfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2564,6 +2623,9 @@ impl DataSync for DataSyncClient {
         input: CreateLocationFsxWindowsRequest,
     ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLocationFsxWindows");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateLocationFsxWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2583,6 +2645,9 @@ impl DataSync for DataSyncClient {
         input: CreateLocationNfsRequest,
     ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLocationNfs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateLocationNfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2601,6 +2666,9 @@ impl DataSync for DataSyncClient {
         input: CreateLocationS3Request,
     ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLocationS3");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateLocationS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2619,6 +2687,9 @@ impl DataSync for DataSyncClient {
         input: CreateLocationSmbRequest,
     ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLocationSmb");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateLocationSmb");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2637,6 +2708,9 @@ impl DataSync for DataSyncClient {
         input: CreateTaskRequest,
     ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.CreateTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2655,6 +2729,9 @@ impl DataSync for DataSyncClient {
         input: DeleteAgentRequest,
     ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAgent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.DeleteAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2673,6 +2750,9 @@ impl DataSync for DataSyncClient {
         input: DeleteLocationRequest,
     ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLocation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.DeleteLocation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2691,6 +2771,9 @@ impl DataSync for DataSyncClient {
         input: DeleteTaskRequest,
     ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.DeleteTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2709,6 +2792,9 @@ impl DataSync for DataSyncClient {
         input: DescribeAgentRequest,
     ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAgent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2727,6 +2813,9 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationEfsRequest,
     ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLocationEfs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeLocationEfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2746,6 +2835,9 @@ impl DataSync for DataSyncClient {
     ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLocationFsxWindows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeLocationFsxWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2765,6 +2857,9 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationNfsRequest,
     ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLocationNfs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeLocationNfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2783,6 +2878,9 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationS3Request,
     ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLocationS3");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeLocationS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2801,6 +2899,9 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationSmbRequest,
     ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLocationSmb");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeLocationSmb");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2819,6 +2920,9 @@ impl DataSync for DataSyncClient {
         input: DescribeTaskRequest,
     ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2837,6 +2941,9 @@ impl DataSync for DataSyncClient {
         input: DescribeTaskExecutionRequest,
     ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTaskExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.DescribeTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2856,6 +2963,9 @@ impl DataSync for DataSyncClient {
         input: ListAgentsRequest,
     ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAgents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.ListAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2874,6 +2984,9 @@ impl DataSync for DataSyncClient {
         input: ListLocationsRequest,
     ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLocations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.ListLocations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2892,6 +3005,9 @@ impl DataSync for DataSyncClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2910,6 +3026,9 @@ impl DataSync for DataSyncClient {
         input: ListTaskExecutionsRequest,
     ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTaskExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.ListTaskExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2928,6 +3047,9 @@ impl DataSync for DataSyncClient {
         input: ListTasksRequest,
     ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "FmrsService.ListTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2946,6 +3068,9 @@ impl DataSync for DataSyncClient {
         input: StartTaskExecutionRequest,
     ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTaskExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.StartTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2964,6 +3089,9 @@ impl DataSync for DataSyncClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2982,6 +3110,9 @@ impl DataSync for DataSyncClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3000,6 +3131,9 @@ impl DataSync for DataSyncClient {
         input: UpdateAgentRequest,
     ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAgent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.UpdateAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3018,6 +3152,9 @@ impl DataSync for DataSyncClient {
         input: UpdateTaskRequest,
     ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "FmrsService.UpdateTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3030,3 +3167,358 @@ impl DataSync for DataSyncClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskResponse, _>()
     }
 }
+
+/// A blocking client for the DataSync API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DataSyncClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DataSyncBlockingClient {
+    client: DataSyncClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DataSyncBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DataSyncBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DataSyncBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataSyncBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DataSyncBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DataSyncBlockingClient {
+        DataSyncBlockingClient {
+            client: DataSyncClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels execution of a task. </p> <p>When you cancel a task execution, the transfer of some files are abruptly interrupted. The contents of files that are transferred to the destination might be incomplete or inconsistent with the source files. However, if you start a new task execution on the same task and you allow the task execution to complete, file content on the destination is complete and consistent. This applies to other unexpected failures that interrupt a task execution. In all of these cases, AWS DataSync successfully complete the transfer when you start the next task execution.</p>
+    ///
+    /// See `DataSync::cancel_task_execution`.
+    pub fn cancel_task_execution(
+        &self,
+        input: CancelTaskExecutionRequest,
+    ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.cancel_task_execution(input))
+    }
+
+    /// <p><p>Activates an AWS DataSync agent that you have deployed on your host. The activation process associates your agent with your account. In the activation process, you specify information such as the AWS Region that you want to activate the agent in. You activate the agent in the AWS Region where your target locations (in Amazon S3 or Amazon EFS) reside. Your tasks are created in this AWS Region.</p> <p>You can activate the agent in a VPC (Virtual private Cloud) or provide the agent access to a VPC endpoint so you can run tasks without going over the public Internet.</p> <p>You can use an agent for more than one location. If a task uses multiple agents, all of them need to have status AVAILABLE for the task to run. If you use multiple agents for a source location, the status of all the agents must be AVAILABLE for the task to run. </p> <p>Agents are automatically updated by AWS on a regular basis, using a mechanism that ensures minimal interruption to your tasks.</p> <p/></p>
+    ///
+    /// See `DataSync::create_agent`.
+    pub fn create_agent(
+        &self,
+        input: CreateAgentRequest,
+    ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
+        self.runtime.block_on(self.client.create_agent(input))
+    }
+
+    /// <p>Creates an endpoint for an Amazon EFS file system.</p>
+    ///
+    /// See `DataSync::create_location_efs`.
+    pub fn create_location_efs(
+        &self,
+        input: CreateLocationEfsRequest,
+    ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
+        self.runtime
+            .block_on(self.client.create_location_efs(input))
+    }
+
+    /// <p>Creates an endpoint for an Amazon FSx for Windows file system.</p>
+    ///
+    /// See `DataSync::create_location_fsx_windows`.
+    pub fn create_location_fsx_windows(
+        &self,
+        input: CreateLocationFsxWindowsRequest,
+    ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
+        self.runtime
+            .block_on(self.client.create_location_fsx_windows(input))
+    }
+
+    /// <p>Defines a file system on a Network File System (NFS) server that can be read from or written to</p>
+    ///
+    /// See `DataSync::create_location_nfs`.
+    pub fn create_location_nfs(
+        &self,
+        input: CreateLocationNfsRequest,
+    ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
+        self.runtime
+            .block_on(self.client.create_location_nfs(input))
+    }
+
+    /// <p>Creates an endpoint for an Amazon S3 bucket.</p> <p>For AWS DataSync to access a destination S3 bucket, it needs an AWS Identity and Access Management (IAM) role that has the required permissions. You can set up the required permissions by creating an IAM policy that grants the required permissions and attaching the policy to the role. An example of such a policy is shown in the examples section.</p> <p>For more information, see https://docs.aws.amazon.com/datasync/latest/userguide/working-with-locations.html#create-s3-location in the <i>AWS DataSync User Guide.</i> </p>
+    ///
+    /// See `DataSync::create_location_s3`.
+    pub fn create_location_s3(
+        &self,
+        input: CreateLocationS3Request,
+    ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
+        self.runtime.block_on(self.client.create_location_s3(input))
+    }
+
+    /// <p>Defines a file system on an Server Message Block (SMB) server that can be read from or written to.</p>
+    ///
+    /// See `DataSync::create_location_smb`.
+    pub fn create_location_smb(
+        &self,
+        input: CreateLocationSmbRequest,
+    ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
+        self.runtime
+            .block_on(self.client.create_location_smb(input))
+    }
+
+    /// <p>Creates a task. A task is a set of two locations (source and destination) and a set of Options that you use to control the behavior of a task. If you don't specify Options when you create a task, AWS DataSync populates them with service defaults.</p> <p>When you create a task, it first enters the CREATING state. During CREATING AWS DataSync attempts to mount the on-premises Network File System (NFS) location. The task transitions to the AVAILABLE state without waiting for the AWS location to become mounted. If required, AWS DataSync mounts the AWS location before each task execution.</p> <p>If an agent that is associated with a source (NFS) location goes offline, the task transitions to the UNAVAILABLE status. If the status of the task remains in the CREATING status for more than a few minutes, it means that your agent might be having trouble mounting the source NFS file system. Check the task's ErrorCode and ErrorDetail. Mount issues are often caused by either a misconfigured firewall or a mistyped NFS server host name.</p>
+    ///
+    /// See `DataSync::create_task`.
+    pub fn create_task(
+        &self,
+        input: CreateTaskRequest,
+    ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
+        self.runtime.block_on(self.client.create_task(input))
+    }
+
+    /// <p>Deletes an agent. To specify which agent to delete, use the Amazon Resource Name (ARN) of the agent in your request. The operation disassociates the agent from your AWS account. However, it doesn't delete the agent virtual machine (VM) from your on-premises environment.</p>
+    ///
+    /// See `DataSync::delete_agent`.
+    pub fn delete_agent(
+        &self,
+        input: DeleteAgentRequest,
+    ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
+        self.runtime.block_on(self.client.delete_agent(input))
+    }
+
+    /// <p>Deletes the configuration of a location used by AWS DataSync. </p>
+    ///
+    /// See `DataSync::delete_location`.
+    pub fn delete_location(
+        &self,
+        input: DeleteLocationRequest,
+    ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
+        self.runtime.block_on(self.client.delete_location(input))
+    }
+
+    /// <p>Deletes a task.</p>
+    ///
+    /// See `DataSync::delete_task`.
+    pub fn delete_task(
+        &self,
+        input: DeleteTaskRequest,
+    ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
+        self.runtime.block_on(self.client.delete_task(input))
+    }
+
+    /// <p>Returns metadata such as the name, the network interfaces, and the status (that is, whether the agent is running or not) for an agent. To specify which agent to describe, use the Amazon Resource Name (ARN) of the agent in your request. </p>
+    ///
+    /// See `DataSync::describe_agent`.
+    pub fn describe_agent(
+        &self,
+        input: DescribeAgentRequest,
+    ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
+        self.runtime.block_on(self.client.describe_agent(input))
+    }
+
+    /// <p>Returns metadata, such as the path information about an Amazon EFS location.</p>
+    ///
+    /// See `DataSync::describe_location_efs`.
+    pub fn describe_location_efs(
+        &self,
+        input: DescribeLocationEfsRequest,
+    ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
+        self.runtime
+            .block_on(self.client.describe_location_efs(input))
+    }
+
+    /// <p>Returns metadata, such as the path information about an Amazon FSx for Windows location.</p>
+    ///
+    /// See `DataSync::describe_location_fsx_windows`.
+    pub fn describe_location_fsx_windows(
+        &self,
+        input: DescribeLocationFsxWindowsRequest,
+    ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_location_fsx_windows(input))
+    }
+
+    /// <p>Returns metadata, such as the path information, about a NFS location.</p>
+    ///
+    /// See `DataSync::describe_location_nfs`.
+    pub fn describe_location_nfs(
+        &self,
+        input: DescribeLocationNfsRequest,
+    ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
+        self.runtime
+            .block_on(self.client.describe_location_nfs(input))
+    }
+
+    /// <p>Returns metadata, such as bucket name, about an Amazon S3 bucket location.</p>
+    ///
+    /// See `DataSync::describe_location_s3`.
+    pub fn describe_location_s3(
+        &self,
+        input: DescribeLocationS3Request,
+    ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
+        self.runtime
+            .block_on(self.client.describe_location_s3(input))
+    }
+
+    /// <p>Returns metadata, such as the path and user information about a SMB location.</p>
+    ///
+    /// See `DataSync::describe_location_smb`.
+    pub fn describe_location_smb(
+        &self,
+        input: DescribeLocationSmbRequest,
+    ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
+        self.runtime
+            .block_on(self.client.describe_location_smb(input))
+    }
+
+    /// <p>Returns metadata about a task.</p>
+    ///
+    /// See `DataSync::describe_task`.
+    pub fn describe_task(
+        &self,
+        input: DescribeTaskRequest,
+    ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
+        self.runtime.block_on(self.client.describe_task(input))
+    }
+
+    /// <p>Returns detailed metadata about a task that is being executed.</p>
+    ///
+    /// See `DataSync::describe_task_execution`.
+    pub fn describe_task_execution(
+        &self,
+        input: DescribeTaskExecutionRequest,
+    ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_task_execution(input))
+    }
+
+    /// <p>Returns a list of agents owned by an AWS account in the AWS Region specified in the request. The returned list is ordered by agent Amazon Resource Name (ARN).</p> <p>By default, this operation returns a maximum of 100 agents. This operation supports pagination that enables you to optionally reduce the number of agents returned in a response.</p> <p>If you have more agents than are returned in a response (that is, the response returns only a truncated list of your agents), the response contains a marker that you can specify in your next request to fetch the next page of agents.</p>
+    ///
+    /// See `DataSync::list_agents`.
+    pub fn list_agents(
+        &self,
+        input: ListAgentsRequest,
+    ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
+        self.runtime.block_on(self.client.list_agents(input))
+    }
+
+    /// <p>Returns a lists of source and destination locations.</p> <p>If you have more locations than are returned in a response (that is, the response returns only a truncated list of your agents), the response contains a token that you can specify in your next request to fetch the next page of locations.</p>
+    ///
+    /// See `DataSync::list_locations`.
+    pub fn list_locations(
+        &self,
+        input: ListLocationsRequest,
+    ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
+        self.runtime.block_on(self.client.list_locations(input))
+    }
+
+    /// <p>Returns all the tags associated with a specified resources. </p>
+    ///
+    /// See `DataSync::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a list of executed tasks.</p>
+    ///
+    /// See `DataSync::list_task_executions`.
+    pub fn list_task_executions(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_task_executions(input))
+    }
+
+    /// <p>Returns a list of all the tasks.</p>
+    ///
+    /// See `DataSync::list_tasks`.
+    pub fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+        self.runtime.block_on(self.client.list_tasks(input))
+    }
+
+    /// <p>Starts a specific invocation of a task. A <code>TaskExecution</code> value represents an individual run of a task. Each task can have at most one <code>TaskExecution</code> at a time.</p> <p> <code>TaskExecution</code> has the following transition phases: INITIALIZING | PREPARING | TRANSFERRING | VERIFYING | SUCCESS/FAILURE. </p> <p>For detailed information, see the Task Execution section in the Components and Terminology topic in the <i>AWS DataSync User Guide</i>.</p>
+    ///
+    /// See `DataSync::start_task_execution`.
+    pub fn start_task_execution(
+        &self,
+        input: StartTaskExecutionRequest,
+    ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_task_execution(input))
+    }
+
+    /// <p>Applies a key-value pair to an AWS resource.</p>
+    ///
+    /// See `DataSync::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a tag from an AWS resource.</p>
+    ///
+    /// See `DataSync::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the name of an agent.</p>
+    ///
+    /// See `DataSync::update_agent`.
+    pub fn update_agent(
+        &self,
+        input: UpdateAgentRequest,
+    ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
+        self.runtime.block_on(self.client.update_agent(input))
+    }
+
+    /// <p>Updates the metadata associated with a task.</p>
+    ///
+    /// See `DataSync::update_task`.
+    pub fn update_task(
+        &self,
+        input: UpdateTaskRequest,
+    ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
+        self.runtime.block_on(self.client.update_task(input))
+    }
+}
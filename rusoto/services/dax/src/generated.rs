@@ -1006,72 +1006,88 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterAlreadyExistsFault" => {
-                    return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::ClusterAlreadyExistsFault(err.msg),
+                    )
                 }
                 "ClusterQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateClusterError::ClusterQuotaForCustomerExceededFault(err.msg),
                     )
                 }
                 "InsufficientClusterCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateClusterError::InsufficientClusterCapacityFault(err.msg),
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(CreateClusterError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateClusterError::InvalidParameterGroupStateFault(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidVPCNetworkStateFault" => {
-                    return RusotoError::Service(CreateClusterError::InvalidVPCNetworkStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidVPCNetworkStateFault(err.msg),
+                    )
                 }
                 "NodeQuotaForClusterExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateClusterError::NodeQuotaForClusterExceededFault(err.msg),
                     )
                 }
                 "NodeQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateClusterError::NodeQuotaForCustomerExceededFault(err.msg),
                     )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(CreateClusterError::ParameterGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::ParameterGroupNotFoundFault(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(CreateClusterError::SubnetGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::SubnetGroupNotFoundFault(err.msg),
+                    )
                 }
                 "TagQuotaPerResourceExceeded" => {
-                    return RusotoError::Service(CreateClusterError::TagQuotaPerResourceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::TagQuotaPerResourceExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1134,32 +1150,38 @@ impl CreateParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateParameterGroupError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateParameterGroupError::InvalidParameterGroupStateFault(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateParameterGroupError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateParameterGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ParameterGroupAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateParameterGroupError::ParameterGroupAlreadyExistsFault(err.msg),
                     )
                 }
                 "ParameterGroupQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateParameterGroupError::ParameterGroupQuotaExceededFault(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1214,27 +1236,34 @@ impl CreateSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateSubnetGroupError::InvalidSubnet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubnetGroupError::InvalidSubnet(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
                 "SubnetGroupAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSubnetGroupError::SubnetGroupAlreadyExistsFault(err.msg),
                     )
                 }
                 "SubnetGroupQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSubnetGroupError::SubnetGroupQuotaExceededFault(err.msg),
                     )
                 }
                 "SubnetQuotaExceededFault" => {
-                    return RusotoError::Service(CreateSubnetGroupError::SubnetQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubnetGroupError::SubnetQuotaExceededFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1284,32 +1313,38 @@ impl DecreaseReplicationFactorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseReplicationFactorError::ClusterNotFoundFault(err.msg),
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseReplicationFactorError::InvalidClusterStateFault(err.msg),
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseReplicationFactorError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseReplicationFactorError::InvalidParameterValue(err.msg),
                     )
                 }
                 "NodeNotFoundFault" => {
-                    return RusotoError::Service(DecreaseReplicationFactorError::NodeNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DecreaseReplicationFactorError::NodeNotFoundFault(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1364,23 +1399,32 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::ClusterNotFoundFault(err.msg),
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1424,27 +1468,32 @@ impl DeleteParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteParameterGroupError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteParameterGroupError::InvalidParameterGroupStateFault(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteParameterGroupError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteParameterGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteParameterGroupError::ParameterGroupNotFoundFault(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1492,19 +1541,22 @@ impl DeleteSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
                 "SubnetGroupInUseFault" => {
-                    return RusotoError::Service(DeleteSubnetGroupError::SubnetGroupInUseFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubnetGroupError::SubnetGroupInUseFault(err.msg),
+                    )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(DeleteSubnetGroupError::SubnetGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubnetGroupError::SubnetGroupNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1544,22 +1596,26 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::ClusterNotFoundFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeClustersError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeClustersError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeClustersError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1600,17 +1656,20 @@ impl DescribeDefaultParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDefaultParametersError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDefaultParametersError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1654,17 +1713,20 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventsError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEventsError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1706,22 +1768,26 @@ impl DescribeParameterGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParameterGroupsError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParameterGroupsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParameterGroupsError::ParameterGroupNotFoundFault(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1770,22 +1836,26 @@ impl DescribeParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParametersError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeParametersError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParametersError::ParameterGroupNotFoundFault(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1828,12 +1898,14 @@ impl DescribeSubnetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubnetGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubnetGroupsError::SubnetGroupNotFoundFault(err.msg),
                     )
                 }
@@ -1886,47 +1958,56 @@ impl IncreaseReplicationFactorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::ClusterNotFoundFault(err.msg),
                     )
                 }
                 "InsufficientClusterCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::InsufficientClusterCapacityFault(err.msg),
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::InvalidClusterStateFault(err.msg),
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::InvalidParameterValue(err.msg),
                     )
                 }
                 "InvalidVPCNetworkStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::InvalidVPCNetworkStateFault(err.msg),
                     )
                 }
                 "NodeQuotaForClusterExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::NodeQuotaForClusterExceededFault(err.msg),
                     )
                 }
                 "NodeQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::NodeQuotaForCustomerExceededFault(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -1994,26 +2075,34 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(ListTagsError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ClusterNotFoundFault(err.msg))
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(ListTagsError::InvalidARNFault(err.msg))
+                    return RusotoError::service(&res, ListTagsError::InvalidARNFault(err.msg))
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(ListTagsError::InvalidClusterStateFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(ListTagsError::ServiceLinkedRoleNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2058,26 +2147,37 @@ impl RebootNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootNodeError::ClusterNotFoundFault(err.msg),
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(RebootNodeError::InvalidClusterStateFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootNodeError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(RebootNodeError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootNodeError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RebootNodeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootNodeError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "NodeNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::NodeNotFoundFault(err.msg))
+                    return RusotoError::service(&res, RebootNodeError::NodeNotFoundFault(err.msg))
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::ServiceLinkedRoleNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootNodeError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2124,33 +2224,43 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(TagResourceError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ClusterNotFoundFault(err.msg),
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(TagResourceError::InvalidARNFault(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidARNFault(err.msg))
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(TagResourceError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(TagResourceError::ServiceLinkedRoleNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    )
                 }
                 "TagQuotaPerResourceExceeded" => {
-                    return RusotoError::Service(TagResourceError::TagQuotaPerResourceExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::TagQuotaPerResourceExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2198,31 +2308,43 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(UntagResourceError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ClusterNotFoundFault(err.msg),
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(UntagResourceError::InvalidARNFault(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidARNFault(err.msg))
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(UntagResourceError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UntagResourceError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
                 "TagNotFoundFault" => {
-                    return RusotoError::Service(UntagResourceError::TagNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::TagNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2270,33 +2392,44 @@ impl UpdateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(UpdateClusterError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::ClusterNotFoundFault(err.msg),
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidClusterStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::InvalidClusterStateFault(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateClusterError::InvalidParameterGroupStateFault(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(UpdateClusterError::ParameterGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::ParameterGroupNotFoundFault(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -2344,27 +2477,32 @@ impl UpdateParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateParameterGroupError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateParameterGroupError::InvalidParameterGroupStateFault(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateParameterGroupError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateParameterGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateParameterGroupError::ParameterGroupNotFoundFault(err.msg),
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
@@ -2416,25 +2554,31 @@ impl UpdateSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSubnet" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::InvalidSubnet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubnetGroupError::InvalidSubnet(err.msg),
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetGroupNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubnetGroupError::SubnetGroupNotFoundFault(err.msg),
+                    )
                 }
                 "SubnetInUse" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetInUse(err.msg))
+                    return RusotoError::service(&res, UpdateSubnetGroupError::SubnetInUse(err.msg))
                 }
                 "SubnetQuotaExceededFault" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubnetGroupError::SubnetQuotaExceededFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2605,6 +2749,7 @@ impl DynamodbAcceleratorClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2620,6 +2765,7 @@ impl DynamodbAcceleratorClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
         DynamodbAcceleratorClient { client, region }
     }
@@ -2633,6 +2779,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.CreateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2651,6 +2800,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateParameterGroupRequest,
     ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.CreateParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2670,6 +2822,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateSubnetGroupRequest,
     ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.CreateSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2689,6 +2844,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DecreaseReplicationFactor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.DecreaseReplicationFactor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2708,6 +2866,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2726,6 +2887,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteParameterGroupRequest,
     ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2745,6 +2909,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteSubnetGroupRequest,
     ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2763,6 +2930,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2782,6 +2952,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDefaultParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeDefaultParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2801,6 +2974,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2819,6 +2995,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParameterGroupsRequest,
     ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameterGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2838,6 +3017,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParametersRequest,
     ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2856,6 +3038,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeSubnetGroupsRequest,
     ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeSubnetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2876,6 +3061,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("IncreaseReplicationFactor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.IncreaseReplicationFactor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2895,6 +3083,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDAXV3.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2913,6 +3104,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: RebootNodeRequest,
     ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebootNode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.RebootNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2931,6 +3125,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2949,6 +3146,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2967,6 +3167,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateClusterRequest,
     ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2985,6 +3188,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateParameterGroupRequest,
     ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3004,6 +3210,9 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateSubnetGroupRequest,
     ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3016,3 +3225,285 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubnetGroupResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon DAX API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DynamodbAcceleratorClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DynamodbAcceleratorBlockingClient {
+    client: DynamodbAcceleratorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DynamodbAcceleratorBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DynamodbAcceleratorBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DynamodbAcceleratorBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamodbAcceleratorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DynamodbAcceleratorBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient {
+            client: DynamodbAcceleratorClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a DAX cluster. All nodes in the cluster run the same DAX caching software.</p>
+    ///
+    /// See `DynamodbAccelerator::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <p>Creates a new parameter group. A parameter group is a collection of parameters that you apply to all of the nodes in a DAX cluster.</p>
+    ///
+    /// See `DynamodbAccelerator::create_parameter_group`.
+    pub fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_parameter_group(input))
+    }
+
+    /// <p>Creates a new subnet group.</p>
+    ///
+    /// See `DynamodbAccelerator::create_subnet_group`.
+    pub fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_subnet_group(input))
+    }
+
+    /// <p><p>Removes one or more nodes from a DAX cluster.</p> <note> <p>You cannot use <code>DecreaseReplicationFactor</code> to remove the last node in a DAX cluster. If you need to do this, use <code>DeleteCluster</code> instead.</p> </note></p>
+    ///
+    /// See `DynamodbAccelerator::decrease_replication_factor`.
+    pub fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
+    {
+        self.runtime
+            .block_on(self.client.decrease_replication_factor(input))
+    }
+
+    /// <p>Deletes a previously provisioned DAX cluster. <i>DeleteCluster</i> deletes all associated nodes, node endpoints and the DAX cluster itself. When you receive a successful response from this action, DAX immediately begins deleting the cluster; you cannot cancel or revert this action.</p>
+    ///
+    /// See `DynamodbAccelerator::delete_cluster`.
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// <p>Deletes the specified parameter group. You cannot delete a parameter group if it is associated with any DAX clusters.</p>
+    ///
+    /// See `DynamodbAccelerator::delete_parameter_group`.
+    pub fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_parameter_group(input))
+    }
+
+    /// <p><p>Deletes a subnet group.</p> <note> <p>You cannot delete a subnet group if it is associated with any DAX clusters.</p> </note></p>
+    ///
+    /// See `DynamodbAccelerator::delete_subnet_group`.
+    pub fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_subnet_group(input))
+    }
+
+    /// <p>Returns information about all provisioned DAX clusters if no cluster identifier is specified, or about a specific DAX cluster if a cluster identifier is supplied.</p> <p>If the cluster is in the CREATING state, only cluster level information will be displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the DELETING state, only cluster level information will be displayed.</p> <p>If nodes are currently being added to the DAX cluster, node endpoint information and creation time for the additional nodes will not be displayed until they are completely provisioned. When the DAX cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If nodes are currently being removed from the DAX cluster, no endpoint information for the removed nodes is displayed.</p>
+    ///
+    /// See `DynamodbAccelerator::describe_clusters`.
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// <p>Returns the default system parameter information for the DAX caching software.</p>
+    ///
+    /// See `DynamodbAccelerator::describe_default_parameters`.
+    pub fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_default_parameters(input))
+    }
+
+    /// <p>Returns events related to DAX clusters and parameter groups. You can obtain events specific to a particular DAX cluster or parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last 24 hours are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
+    ///
+    /// See `DynamodbAccelerator::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns a list of parameter group descriptions. If a parameter group name is specified, the list will contain only the descriptions for that group.</p>
+    ///
+    /// See `DynamodbAccelerator::describe_parameter_groups`.
+    pub fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_parameter_groups(input))
+    }
+
+    /// <p>Returns the detailed parameter list for a particular parameter group.</p>
+    ///
+    /// See `DynamodbAccelerator::describe_parameters`.
+    pub fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_parameters(input))
+    }
+
+    /// <p>Returns a list of subnet group descriptions. If a subnet group name is specified, the list will contain only the description of that group.</p>
+    ///
+    /// See `DynamodbAccelerator::describe_subnet_groups`.
+    pub fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_subnet_groups(input))
+    }
+
+    /// <p>Adds one or more nodes to a DAX cluster.</p>
+    ///
+    /// See `DynamodbAccelerator::increase_replication_factor`.
+    pub fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
+    {
+        self.runtime
+            .block_on(self.client.increase_replication_factor(input))
+    }
+
+    /// <p>List all of the tags for a DAX cluster. You can call <code>ListTags</code> up to 10 times per second, per account.</p>
+    ///
+    /// See `DynamodbAccelerator::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p><p>Reboots a single node of a DAX cluster. The reboot action takes place as soon as possible. During the reboot, the node status is set to REBOOTING.</p> <note> <p> <code>RebootNode</code> restarts the DAX engine process and does not remove the contents of the cache. </p> </note></p>
+    ///
+    /// See `DynamodbAccelerator::reboot_node`.
+    pub fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
+        self.runtime.block_on(self.client.reboot_node(input))
+    }
+
+    /// <p>Associates a set of tags with a DAX resource. You can call <code>TagResource</code> up to 5 times per second, per account. </p>
+    ///
+    /// See `DynamodbAccelerator::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the association of tags from a DAX resource. You can call <code>UntagResource</code> up to 5 times per second, per account. </p>
+    ///
+    /// See `DynamodbAccelerator::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Modifies the settings for a DAX cluster. You can use this action to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
+    ///
+    /// See `DynamodbAccelerator::update_cluster`.
+    pub fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
+        self.runtime.block_on(self.client.update_cluster(input))
+    }
+
+    /// <p>Modifies the parameters of a parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
+    ///
+    /// See `DynamodbAccelerator::update_parameter_group`.
+    pub fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.update_parameter_group(input))
+    }
+
+    /// <p>Modifies an existing subnet group.</p>
+    ///
+    /// See `DynamodbAccelerator::update_subnet_group`.
+    pub fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.update_subnet_group(input))
+    }
+}
@@ -335,13 +335,19 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(AcceptInvitationError::Conflict(err.msg))
+                    return RusotoError::service(&res, AcceptInvitationError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AcceptInvitationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptInvitationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptInvitationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -377,13 +383,16 @@ impl CreateGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGraphError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateGraphError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateGraphError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateGraphError::InternalServer(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateGraphError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGraphError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -419,13 +428,19 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateMembersError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateMembersError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMembersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateMembersError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMembersError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -459,10 +474,10 @@ impl DeleteGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteGraphError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteGraphError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGraphError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteGraphError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -497,13 +512,16 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMembersError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteMembersError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMembersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -539,17 +557,22 @@ impl DisassociateMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateMembershipError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembershipError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateMembershipError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembershipError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateMembershipError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembershipError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -583,10 +606,10 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetMembersError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetMembersError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetMembersError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -617,7 +640,7 @@ impl ListGraphsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListGraphsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListGraphsError::InternalServer(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -647,7 +670,10 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInvitationsError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -679,10 +705,10 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListMembersError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListMembersError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListMembersError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -717,13 +743,19 @@ impl RejectInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RejectInvitationError::Conflict(err.msg))
+                    return RusotoError::service(&res, RejectInvitationError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RejectInvitationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RejectInvitationError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RejectInvitationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -761,22 +793,28 @@ impl StartMonitoringMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(StartMonitoringMemberError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringMemberError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartMonitoringMemberError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringMemberError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartMonitoringMemberError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringMemberError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartMonitoringMemberError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringMemberError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -887,6 +925,7 @@ impl DetectiveClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -902,6 +941,7 @@ impl DetectiveClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DetectiveClient {
         DetectiveClient { client, region }
     }
@@ -918,6 +958,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("PUT", "detective", &self.region, &request_uri);
+        request.set_operation_name("AcceptInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -946,6 +989,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("CreateGraph");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -976,6 +1022,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("CreateMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1008,6 +1057,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("DeleteGraph");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1039,6 +1091,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("DeleteMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1071,6 +1126,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/membership/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("DisassociateMembership");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1102,6 +1160,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/get";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("GetMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1134,6 +1195,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graphs/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("ListGraphs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1166,6 +1230,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitations/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("ListInvitations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1198,6 +1265,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/members/list";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("ListMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1230,6 +1300,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/invitation/removal";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("RejectInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1261,6 +1334,9 @@ impl Detective for DetectiveClient {
         let request_uri = "/graph/member/monitoringstate";
 
         let mut request = SignedRequest::new("POST", "detective", &self.region, &request_uri);
+        request.set_operation_name("StartMonitoringMember");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.detective".to_string());
@@ -1283,3 +1359,172 @@ impl Detective for DetectiveClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Detective API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DetectiveClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DetectiveBlockingClient {
+    client: DetectiveClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DetectiveBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DetectiveBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DetectiveBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DetectiveBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DetectiveBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DetectiveBlockingClient {
+        DetectiveBlockingClient {
+            client: DetectiveClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts an invitation for the member account to contribute data to a behavior graph. This operation can only be called by an invited member account. </p> <p>The request provides the ARN of behavior graph.</p> <p>The member account status in the graph must be <code>INVITED</code>.</p>
+    ///
+    /// See `Detective::accept_invitation`.
+    pub fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<(), RusotoError<AcceptInvitationError>> {
+        self.runtime.block_on(self.client.accept_invitation(input))
+    }
+
+    /// <p>Creates a new behavior graph for the calling account, and sets that account as the master account. This operation is called by the account that is enabling Detective.</p> <p>Before you try to enable Detective, make sure that your account has been enrolled in Amazon GuardDuty for at least 48 hours. If you do not meet this requirement, you cannot enable Detective. If you do meet the GuardDuty prerequisite, then when you make the request to enable Detective, it checks whether your data volume is within the Detective quota. If it exceeds the quota, then you cannot enable Detective. </p> <p>The operation also enables Detective for the calling account in the currently selected Region. It returns the ARN of the new behavior graph.</p> <p> <code>CreateGraph</code> triggers a process to create the corresponding data tables for the new behavior graph.</p> <p>An account can only be the master account for one behavior graph within a Region. If the same account calls <code>CreateGraph</code> with the same master account, it always returns the same behavior graph ARN. It does not create a new behavior graph.</p>
+    ///
+    /// See `Detective::create_graph`.
+    pub fn create_graph(&self) -> Result<CreateGraphResponse, RusotoError<CreateGraphError>> {
+        self.runtime.block_on(self.client.create_graph())
+    }
+
+    /// <p><p>Sends a request to invite the specified AWS accounts to be member accounts in the behavior graph. This operation can only be called by the master account for a behavior graph. </p> <p> <code>CreateMembers</code> verifies the accounts and then sends invitations to the verified accounts.</p> <p>The request provides the behavior graph ARN and the list of accounts to invite.</p> <p>The response separates the requested accounts into two lists:</p> <ul> <li> <p>The accounts that <code>CreateMembers</code> was able to start the verification for. This list includes member accounts that are being verified, that have passed verification and are being sent an invitation, and that have failed verification.</p> </li> <li> <p>The accounts that <code>CreateMembers</code> was unable to process. This list includes accounts that were already invited to be member accounts in the behavior graph.</p> </li> </ul></p>
+    ///
+    /// See `Detective::create_members`.
+    pub fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.runtime.block_on(self.client.create_members(input))
+    }
+
+    /// <p>Disables the specified behavior graph and queues it to be deleted. This operation removes the graph from each member account's list of behavior graphs.</p> <p> <code>DeleteGraph</code> can only be called by the master account for a behavior graph.</p>
+    ///
+    /// See `Detective::delete_graph`.
+    pub fn delete_graph(
+        &self,
+        input: DeleteGraphRequest,
+    ) -> Result<(), RusotoError<DeleteGraphError>> {
+        self.runtime.block_on(self.client.delete_graph(input))
+    }
+
+    /// <p>Deletes one or more member accounts from the master account behavior graph. This operation can only be called by a Detective master account. That account cannot use <code>DeleteMembers</code> to delete their own account from the behavior graph. To disable a behavior graph, the master account uses the <code>DeleteGraph</code> API method.</p>
+    ///
+    /// See `Detective::delete_members`.
+    pub fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.runtime.block_on(self.client.delete_members(input))
+    }
+
+    /// <p>Removes the member account from the specified behavior graph. This operation can only be called by a member account that has the <code>ENABLED</code> status.</p>
+    ///
+    /// See `Detective::disassociate_membership`.
+    pub fn disassociate_membership(
+        &self,
+        input: DisassociateMembershipRequest,
+    ) -> Result<(), RusotoError<DisassociateMembershipError>> {
+        self.runtime
+            .block_on(self.client.disassociate_membership(input))
+    }
+
+    /// <p>Returns the membership details for specified member accounts for a behavior graph.</p>
+    ///
+    /// See `Detective::get_members`.
+    pub fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.runtime.block_on(self.client.get_members(input))
+    }
+
+    /// <p>Returns the list of behavior graphs that the calling account is a master of. This operation can only be called by a master account.</p> <p>Because an account can currently only be the master of one behavior graph within a Region, the results always contain a single graph.</p>
+    ///
+    /// See `Detective::list_graphs`.
+    pub fn list_graphs(
+        &self,
+        input: ListGraphsRequest,
+    ) -> Result<ListGraphsResponse, RusotoError<ListGraphsError>> {
+        self.runtime.block_on(self.client.list_graphs(input))
+    }
+
+    /// <p>Retrieves the list of open and accepted behavior graph invitations for the member account. This operation can only be called by a member account.</p> <p>Open invitations are invitations that the member account has not responded to.</p> <p>The results do not include behavior graphs for which the member account declined the invitation. The results also do not include behavior graphs that the member account resigned from or was removed from.</p>
+    ///
+    /// See `Detective::list_invitations`.
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// <p>Retrieves the list of member accounts for a behavior graph. Does not return member accounts that were removed from the behavior graph.</p>
+    ///
+    /// See `Detective::list_members`.
+    pub fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// <p>Rejects an invitation to contribute the account data to a behavior graph. This operation must be called by a member account that has the <code>INVITED</code> status.</p>
+    ///
+    /// See `Detective::reject_invitation`.
+    pub fn reject_invitation(
+        &self,
+        input: RejectInvitationRequest,
+    ) -> Result<(), RusotoError<RejectInvitationError>> {
+        self.runtime.block_on(self.client.reject_invitation(input))
+    }
+
+    /// <p><p>Sends a request to enable data ingest for a member account that has a status of <code>ACCEPTED<em>BUT</em>DISABLED</code>.</p> <p>For valid member accounts, the status is updated as follows.</p> <ul> <li> <p>If Detective enabled the member account, then the new status is <code>ENABLED</code>.</p> </li> <li> <p>If Detective cannot enable the member account, the status remains <code>ACCEPTED<em>BUT</em>DISABLED</code>. </p> </li> </ul></p>
+    ///
+    /// See `Detective::start_monitoring_member`.
+    pub fn start_monitoring_member(
+        &self,
+        input: StartMonitoringMemberRequest,
+    ) -> Result<(), RusotoError<StartMonitoringMemberError>> {
+        self.runtime
+            .block_on(self.client.start_monitoring_member(input))
+    }
+}
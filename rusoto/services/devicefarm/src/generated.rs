@@ -3446,16 +3446,22 @@ impl CreateDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(&res, CreateDevicePoolError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevicePoolError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDevicePoolError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevicePoolError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3494,18 +3500,28 @@ impl CreateInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceProfileError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceProfileError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3544,16 +3560,22 @@ impl CreateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(&res, CreateNetworkProfileError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateNetworkProfileError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3594,19 +3616,19 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateProjectError::Argument(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::ServiceAccount(err.msg))
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(CreateProjectError::TagOperation(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::TagOperation(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3646,20 +3668,28 @@ impl CreateRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRemoteAccessSessionError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRemoteAccessSessionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRemoteAccessSessionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRemoteAccessSessionError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3692,9 +3722,10 @@ impl CreateTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTestGridProjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTestGridProjectError::InternalService(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3728,13 +3759,16 @@ impl CreateTestGridUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::Argument(err.msg))
+                    return RusotoError::service(&res, CreateTestGridUrlError::Argument(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTestGridUrlError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateTestGridUrlError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3772,16 +3806,16 @@ impl CreateUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateUploadError::Argument(err.msg))
+                    return RusotoError::service(&res, CreateUploadError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateUploadError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUploadError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateUploadError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, CreateUploadError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3818,17 +3852,22 @@ impl CreateVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVPCEConfigurationError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVPCEConfigurationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVPCEConfigurationError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3866,16 +3905,22 @@ impl DeleteDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(&res, DeleteDevicePoolError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDevicePoolError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDevicePoolError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDevicePoolError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3914,18 +3959,28 @@ impl DeleteInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceProfileError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceProfileError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3964,16 +4019,22 @@ impl DeleteNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(&res, DeleteNetworkProfileError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNetworkProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteNetworkProfileError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNetworkProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4012,16 +4073,16 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteProjectError::Argument(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4060,20 +4121,28 @@ impl DeleteRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRemoteAccessSessionError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRemoteAccessSessionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRemoteAccessSessionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRemoteAccessSessionError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4112,16 +4181,16 @@ impl DeleteRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteRunError::Argument(err.msg))
+                    return RusotoError::service(&res, DeleteRunError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteRunError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRunError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRunError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, DeleteRunError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4160,18 +4229,28 @@ impl DeleteTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTestGridProjectError::Argument(err.msg),
+                    )
                 }
                 "CannotDeleteException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::CannotDelete(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTestGridProjectError::CannotDelete(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTestGridProjectError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTestGridProjectError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4210,16 +4289,16 @@ impl DeleteUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteUploadError::Argument(err.msg))
+                    return RusotoError::service(&res, DeleteUploadError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteUploadError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUploadError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUploadError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, DeleteUploadError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4258,20 +4337,28 @@ impl DeleteVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVPCEConfigurationError::Argument(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVPCEConfigurationError::InvalidOperation(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVPCEConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVPCEConfigurationError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4310,16 +4397,22 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Argument(err.msg))
+                    return RusotoError::service(&res, GetAccountSettingsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAccountSettingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAccountSettingsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4358,16 +4451,16 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDeviceError::Argument(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDeviceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDeviceError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetDeviceError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4406,16 +4499,22 @@ impl GetDeviceInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::Argument(err.msg))
+                    return RusotoError::service(&res, GetDeviceInstanceError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceInstanceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDeviceInstanceError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceInstanceError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4454,16 +4553,16 @@ impl GetDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(&res, GetDevicePoolError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetDevicePoolError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDevicePoolError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetDevicePoolError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4504,20 +4603,28 @@ impl GetDevicePoolCompatibilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicePoolCompatibilityError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicePoolCompatibilityError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicePoolCompatibilityError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicePoolCompatibilityError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4556,16 +4663,22 @@ impl GetInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(&res, GetInstanceProfileError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceProfileError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetInstanceProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4603,13 +4716,17 @@ impl GetJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetJobError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ArgumentException" => return RusotoError::Service(GetJobError::Argument(err.msg)),
+                "ArgumentException" => {
+                    return RusotoError::service(&res, GetJobError::Argument(err.msg))
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetJobError::LimitExceeded(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetJobError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetJobError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetJobError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4648,16 +4765,22 @@ impl GetNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(&res, GetNetworkProfileError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetNetworkProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetNetworkProfileError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetNetworkProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4698,19 +4821,25 @@ impl GetOfferingStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetOfferingStatusError::Argument(err.msg))
+                    return RusotoError::service(&res, GetOfferingStatusError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetOfferingStatusError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOfferingStatusError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(GetOfferingStatusError::NotEligible(err.msg))
+                    return RusotoError::service(&res, GetOfferingStatusError::NotEligible(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOfferingStatusError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetOfferingStatusError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetOfferingStatusError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOfferingStatusError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4750,16 +4879,16 @@ impl GetProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetProjectError::Argument(err.msg))
+                    return RusotoError::service(&res, GetProjectError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetProjectError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetProjectError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetProjectError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4798,20 +4927,28 @@ impl GetRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRemoteAccessSessionError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRemoteAccessSessionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRemoteAccessSessionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRemoteAccessSessionError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4849,13 +4986,17 @@ impl GetRunError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetRunError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ArgumentException" => return RusotoError::Service(GetRunError::Argument(err.msg)),
+                "ArgumentException" => {
+                    return RusotoError::service(&res, GetRunError::Argument(err.msg))
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetRunError::LimitExceeded(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetRunError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetRunError::NotFound(err.msg)),
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetRunError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4894,16 +5035,16 @@ impl GetSuiteError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetSuiteError::Argument(err.msg))
+                    return RusotoError::service(&res, GetSuiteError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSuiteError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetSuiteError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSuiteError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSuiteError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetSuiteError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetSuiteError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4942,16 +5083,16 @@ impl GetTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestError::Argument(err.msg))
+                    return RusotoError::service(&res, GetTestError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTestError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetTestError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTestError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetTestError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetTestError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4988,13 +5129,16 @@ impl GetTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service(&res, GetTestGridProjectError::Argument(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTestGridProjectError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTestGridProjectError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTestGridProjectError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5030,13 +5174,16 @@ impl GetTestGridSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestGridSessionError::Argument(err.msg))
+                    return RusotoError::service(&res, GetTestGridSessionError::Argument(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTestGridSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTestGridSessionError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestGridSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTestGridSessionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5074,16 +5221,16 @@ impl GetUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetUploadError::Argument(err.msg))
+                    return RusotoError::service(&res, GetUploadError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetUploadError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUploadError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetUploadError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, GetUploadError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5120,13 +5267,16 @@ impl GetVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(&res, GetVPCEConfigurationError::Argument(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetVPCEConfigurationError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVPCEConfigurationError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5166,24 +5316,28 @@ impl InstallToRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::Argument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InstallToRemoteAccessSessionError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InstallToRemoteAccessSessionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InstallToRemoteAccessSessionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InstallToRemoteAccessSessionError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5222,16 +5376,16 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListArtifactsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListArtifactsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListArtifactsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListArtifactsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListArtifactsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5270,16 +5424,22 @@ impl ListDeviceInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::Argument(err.msg))
+                    return RusotoError::service(&res, ListDeviceInstancesError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceInstancesError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListDeviceInstancesError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceInstancesError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5318,16 +5478,19 @@ impl ListDevicePoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDevicePoolsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListDevicePoolsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevicePoolsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListDevicePoolsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDevicePoolsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListDevicePoolsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDevicePoolsError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevicePoolsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5366,16 +5529,16 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDevicesError::Argument(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevicesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDevicesError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5414,16 +5577,22 @@ impl ListInstanceProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::Argument(err.msg))
+                    return RusotoError::service(&res, ListInstanceProfilesError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInstanceProfilesError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListInstanceProfilesError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInstanceProfilesError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5462,16 +5631,16 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListJobsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListJobsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListJobsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListJobsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListJobsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListJobsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5510,16 +5679,22 @@ impl ListNetworkProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::Argument(err.msg))
+                    return RusotoError::service(&res, ListNetworkProfilesError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNetworkProfilesError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListNetworkProfilesError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNetworkProfilesError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5560,23 +5735,34 @@ impl ListOfferingPromotionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingPromotionsError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingPromotionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::NotEligible(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingPromotionsError::NotEligible(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingPromotionsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingPromotionsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5618,25 +5804,34 @@ impl ListOfferingTransactionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingTransactionsError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingTransactionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::NotEligible(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingTransactionsError::NotEligible(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingTransactionsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingTransactionsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5678,19 +5873,19 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::LimitExceeded(err.msg))
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingsError::NotEligible(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::NotEligible(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5730,16 +5925,16 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListProjectsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListProjectsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListProjectsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListProjectsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5778,20 +5973,28 @@ impl ListRemoteAccessSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRemoteAccessSessionsError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRemoteAccessSessionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRemoteAccessSessionsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRemoteAccessSessionsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5830,16 +6033,16 @@ impl ListRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListRunsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListRunsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListRunsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListRunsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRunsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListRunsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListRunsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListRunsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5878,16 +6081,16 @@ impl ListSamplesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListSamplesError::Argument(err.msg))
+                    return RusotoError::service(&res, ListSamplesError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListSamplesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListSamplesError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSamplesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListSamplesError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListSamplesError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListSamplesError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5926,16 +6129,16 @@ impl ListSuitesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListSuitesError::Argument(err.msg))
+                    return RusotoError::service(&res, ListSuitesError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListSuitesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListSuitesError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSuitesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListSuitesError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListSuitesError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListSuitesError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5972,13 +6175,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Argument(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Argument(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TagOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TagOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6012,12 +6218,13 @@ impl ListTestGridProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridProjectsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListTestGridProjectsError::Argument(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridProjectsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridProjectsError::InternalService(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6054,15 +6261,22 @@ impl ListTestGridSessionActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridSessionActionsError::Argument(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridSessionActionsError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridSessionActionsError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6100,19 +6314,22 @@ impl ListTestGridSessionArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionArtifactsError::Argument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridSessionArtifactsError::Argument(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTestGridSessionArtifactsError::InternalService(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionArtifactsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridSessionArtifactsError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6148,15 +6365,16 @@ impl ListTestGridSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListTestGridSessionsError::Argument(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTestGridSessionsError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTestGridSessionsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6194,16 +6412,16 @@ impl ListTestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListTestsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTestsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListTestsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTestsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListTestsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListTestsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6242,16 +6460,22 @@ impl ListUniqueProblemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListUniqueProblemsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUniqueProblemsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListUniqueProblemsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUniqueProblemsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6290,16 +6514,16 @@ impl ListUploadsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListUploadsError::Argument(err.msg))
+                    return RusotoError::service(&res, ListUploadsError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListUploadsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListUploadsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUploadsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListUploadsError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListUploadsError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ListUploadsError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6334,12 +6558,16 @@ impl ListVPCEConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListVPCEConfigurationsError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVPCEConfigurationsError::Argument(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListVPCEConfigurationsError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVPCEConfigurationsError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6378,19 +6606,25 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Argument(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PurchaseOfferingError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PurchaseOfferingError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotEligible(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::NotEligible(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(PurchaseOfferingError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PurchaseOfferingError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6432,19 +6666,19 @@ impl RenewOfferingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(RenewOfferingError::Argument(err.msg))
+                    return RusotoError::service(&res, RenewOfferingError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RenewOfferingError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, RenewOfferingError::LimitExceeded(err.msg))
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(RenewOfferingError::NotEligible(err.msg))
+                    return RusotoError::service(&res, RenewOfferingError::NotEligible(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RenewOfferingError::NotFound(err.msg))
+                    return RusotoError::service(&res, RenewOfferingError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(RenewOfferingError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, RenewOfferingError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6486,19 +6720,19 @@ impl ScheduleRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ScheduleRunError::Argument(err.msg))
+                    return RusotoError::service(&res, ScheduleRunError::Argument(err.msg))
                 }
                 "IdempotencyException" => {
-                    return RusotoError::Service(ScheduleRunError::Idempotency(err.msg))
+                    return RusotoError::service(&res, ScheduleRunError::Idempotency(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ScheduleRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ScheduleRunError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ScheduleRunError::NotFound(err.msg))
+                    return RusotoError::service(&res, ScheduleRunError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ScheduleRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, ScheduleRunError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6538,16 +6772,16 @@ impl StopJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopJobError::Argument(err.msg))
+                    return RusotoError::service(&res, StopJobError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StopJobError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopJobError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopJobError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, StopJobError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6586,20 +6820,28 @@ impl StopRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopRemoteAccessSessionError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRemoteAccessSessionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopRemoteAccessSessionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRemoteAccessSessionError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6638,16 +6880,16 @@ impl StopRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopRunError::Argument(err.msg))
+                    return RusotoError::service(&res, StopRunError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StopRunError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRunError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopRunError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopRunError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, StopRunError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6688,19 +6930,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(TagResourceError::Argument(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Argument(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(TagResourceError::TagOperation(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TagOperation(err.msg))
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TagPolicy(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6738,13 +6980,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::Argument(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Argument(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::TagOperation(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TagOperation(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6782,16 +7024,22 @@ impl UpdateDeviceInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::Argument(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceInstanceError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceInstanceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceInstanceError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceInstanceError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6830,16 +7078,22 @@ impl UpdateDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::Argument(err.msg))
+                    return RusotoError::service(&res, UpdateDevicePoolError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDevicePoolError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDevicePoolError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDevicePoolError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6878,18 +7132,28 @@ impl UpdateInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInstanceProfileError::Argument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInstanceProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInstanceProfileError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInstanceProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6928,16 +7192,22 @@ impl UpdateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service(&res, UpdateNetworkProfileError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNetworkProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateNetworkProfileError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNetworkProfileError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6976,16 +7246,16 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateProjectError::Argument(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7022,15 +7292,22 @@ impl UpdateTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTestGridProjectError::Argument(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTestGridProjectError::InternalService(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTestGridProjectError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7068,16 +7345,16 @@ impl UpdateUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateUploadError::Argument(err.msg))
+                    return RusotoError::service(&res, UpdateUploadError::Argument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateUploadError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUploadError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUploadError::NotFound(err.msg))
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service(&res, UpdateUploadError::ServiceAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7116,20 +7393,28 @@ impl UpdateVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVPCEConfigurationError::Argument(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVPCEConfigurationError::InvalidOperation(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVPCEConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::ServiceAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVPCEConfigurationError::ServiceAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7628,6 +7913,7 @@ impl DeviceFarmClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7643,6 +7929,7 @@ impl DeviceFarmClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmClient {
         DeviceFarmClient { client, region }
     }
@@ -7656,6 +7943,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateDevicePoolRequest,
     ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDevicePool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7674,6 +7964,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateInstanceProfileRequest,
     ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7692,6 +7985,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7710,6 +8006,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7728,6 +8027,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateRemoteAccessSessionRequest,
     ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRemoteAccessSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.CreateRemoteAccessSession",
@@ -7750,6 +8052,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateTestGridProjectRequest,
     ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTestGridProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7768,6 +8073,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateTestGridUrlRequest,
     ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTestGridUrl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7786,6 +8094,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateUploadRequest,
     ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7804,6 +8115,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateVPCEConfigurationRequest,
     ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateVPCEConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.CreateVPCEConfiguration",
@@ -7826,6 +8140,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteDevicePoolRequest,
     ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDevicePool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7844,6 +8161,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteInstanceProfileRequest,
     ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7862,6 +8182,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteNetworkProfileRequest,
     ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7880,6 +8203,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7898,6 +8224,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteRemoteAccessSessionRequest,
     ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRemoteAccessSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.DeleteRemoteAccessSession",
@@ -7920,6 +8249,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteRunRequest,
     ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7938,6 +8270,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteTestGridProjectRequest,
     ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTestGridProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7956,6 +8291,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteUploadRequest,
     ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7974,6 +8312,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteVPCEConfigurationRequest,
     ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVPCEConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.DeleteVPCEConfiguration",
@@ -7995,6 +8336,9 @@ impl DeviceFarm for DeviceFarmClient {
         &self,
     ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAccountSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetAccountSettings");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8012,6 +8356,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevice");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8030,6 +8377,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDeviceInstanceRequest,
     ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeviceInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDeviceInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8048,6 +8398,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDevicePoolRequest,
     ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevicePool");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8067,6 +8420,9 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevicePoolCompatibility");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.GetDevicePoolCompatibility",
@@ -8089,6 +8445,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetInstanceProfileRequest,
     ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8107,6 +8466,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetJobRequest,
     ) -> Result<GetJobResult, RusotoError<GetJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8125,6 +8487,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetNetworkProfileRequest,
     ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetNetworkProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8143,6 +8508,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetOfferingStatusRequest,
     ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOfferingStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetOfferingStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8161,6 +8529,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetProjectRequest,
     ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8179,6 +8550,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetRemoteAccessSessionRequest,
     ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRemoteAccessSession");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRemoteAccessSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8198,6 +8572,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetRunRequest,
     ) -> Result<GetRunResult, RusotoError<GetRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRun");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8216,6 +8593,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetSuiteRequest,
     ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSuite");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetSuite");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8234,6 +8614,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestRequest,
     ) -> Result<GetTestResult, RusotoError<GetTestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTest");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8252,6 +8635,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestGridProjectRequest,
     ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTestGridProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8270,6 +8656,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestGridSessionRequest,
     ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTestGridSession");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8288,6 +8677,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetUploadRequest,
     ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUpload");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8306,6 +8698,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetVPCEConfigurationRequest,
     ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetVPCEConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetVPCEConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8325,6 +8720,9 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InstallToRemoteAccessSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.InstallToRemoteAccessSession",
@@ -8347,6 +8745,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListArtifactsRequest,
     ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListArtifacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8365,6 +8766,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDeviceInstancesRequest,
     ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeviceInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDeviceInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8383,6 +8787,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDevicePoolsRequest,
     ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDevicePools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevicePools");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8401,6 +8808,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8419,6 +8829,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListInstanceProfilesRequest,
     ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListInstanceProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListInstanceProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8437,6 +8850,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8455,6 +8871,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListNetworkProfilesRequest,
     ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListNetworkProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListNetworkProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8473,6 +8892,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingPromotionsRequest,
     ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOfferingPromotions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferingPromotions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8492,6 +8914,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingTransactionsRequest,
     ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOfferingTransactions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListOfferingTransactions",
@@ -8514,6 +8939,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingsRequest,
     ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8532,6 +8960,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListProjectsRequest,
     ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8550,6 +8981,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListRemoteAccessSessionsRequest,
     ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRemoteAccessSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListRemoteAccessSessions",
@@ -8572,6 +9006,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListRunsRequest,
     ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8590,6 +9027,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListSamplesRequest,
     ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSamples");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSamples");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8608,6 +9048,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListSuitesRequest,
     ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSuites");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSuites");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8626,6 +9069,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8644,6 +9090,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestGridProjectsRequest,
     ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTestGridProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8663,6 +9112,9 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTestGridSessionActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListTestGridSessionActions",
@@ -8686,6 +9138,9 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTestGridSessionArtifacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListTestGridSessionArtifacts",
@@ -8708,6 +9163,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestGridSessionsRequest,
     ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTestGridSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8726,6 +9184,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestsRequest,
     ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8744,6 +9205,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListUniqueProblemsRequest,
     ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUniqueProblems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUniqueProblems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8762,6 +9226,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListUploadsRequest,
     ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUploads");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUploads");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8780,6 +9247,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListVPCEConfigurationsRequest,
     ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVPCEConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListVPCEConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8799,6 +9269,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: PurchaseOfferingRequest,
     ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PurchaseOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.PurchaseOffering");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8817,6 +9290,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: RenewOfferingRequest,
     ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RenewOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.RenewOffering");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8835,6 +9311,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: ScheduleRunRequest,
     ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ScheduleRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.ScheduleRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8853,6 +9332,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopJobRequest,
     ) -> Result<StopJobResult, RusotoError<StopJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8871,6 +9353,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopRemoteAccessSessionRequest,
     ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopRemoteAccessSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.StopRemoteAccessSession",
@@ -8893,6 +9378,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopRunRequest,
     ) -> Result<StopRunResult, RusotoError<StopRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8911,6 +9399,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8929,6 +9420,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8947,6 +9441,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateDeviceInstanceRequest,
     ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDeviceInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDeviceInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8965,6 +9462,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateDevicePoolRequest,
     ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDevicePool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8983,6 +9483,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateInstanceProfileRequest,
     ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9001,6 +9504,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateNetworkProfileRequest,
     ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNetworkProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9019,6 +9525,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateProjectRequest,
     ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9037,6 +9546,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateTestGridProjectRequest,
     ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTestGridProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9055,6 +9567,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateUploadRequest,
     ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9073,6 +9588,9 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateVPCEConfigurationRequest,
     ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVPCEConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.UpdateVPCEConfiguration",
@@ -9089,3 +9607,860 @@ impl DeviceFarm for DeviceFarmClient {
             .deserialize::<UpdateVPCEConfigurationResult, _>()
     }
 }
+
+/// A blocking client for the AWS Device Farm API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DeviceFarmClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DeviceFarmBlockingClient {
+    client: DeviceFarmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DeviceFarmBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DeviceFarmBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DeviceFarmBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DeviceFarmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DeviceFarmBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient {
+            client: DeviceFarmClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a device pool.</p>
+    ///
+    /// See `DeviceFarm::create_device_pool`.
+    pub fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
+        self.runtime.block_on(self.client.create_device_pool(input))
+    }
+
+    /// <p>Creates a profile that can be applied to one or more private fleet device instances.</p>
+    ///
+    /// See `DeviceFarm::create_instance_profile`.
+    pub fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.create_instance_profile(input))
+    }
+
+    /// <p>Creates a network profile.</p>
+    ///
+    /// See `DeviceFarm::create_network_profile`.
+    pub fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.create_network_profile(input))
+    }
+
+    /// <p>Creates a project.</p>
+    ///
+    /// See `DeviceFarm::create_project`.
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// <p>Specifies and starts a remote access session.</p>
+    ///
+    /// See `DeviceFarm::create_remote_access_session`.
+    pub fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.create_remote_access_session(input))
+    }
+
+    /// <p>Creates a Selenium testing project. Projects are used to track <a>TestGridSession</a> instances.</p>
+    ///
+    /// See `DeviceFarm::create_test_grid_project`.
+    pub fn create_test_grid_project(
+        &self,
+        input: CreateTestGridProjectRequest,
+    ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.create_test_grid_project(input))
+    }
+
+    /// <p>Creates a signed, short-term URL that can be passed to a Selenium <code>RemoteWebDriver</code> constructor.</p>
+    ///
+    /// See `DeviceFarm::create_test_grid_url`.
+    pub fn create_test_grid_url(
+        &self,
+        input: CreateTestGridUrlRequest,
+    ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
+        self.runtime
+            .block_on(self.client.create_test_grid_url(input))
+    }
+
+    /// <p>Uploads an app or test scripts.</p>
+    ///
+    /// See `DeviceFarm::create_upload`.
+    pub fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
+        self.runtime.block_on(self.client.create_upload(input))
+    }
+
+    /// <p>Creates a configuration record in Device Farm for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    ///
+    /// See `DeviceFarm::create_vpce_configuration`.
+    pub fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_vpce_configuration(input))
+    }
+
+    /// <p>Deletes a device pool given the pool ARN. Does not allow deletion of curated pools owned by the system.</p>
+    ///
+    /// See `DeviceFarm::delete_device_pool`.
+    pub fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
+        self.runtime.block_on(self.client.delete_device_pool(input))
+    }
+
+    /// <p>Deletes a profile that can be applied to one or more private device instances.</p>
+    ///
+    /// See `DeviceFarm::delete_instance_profile`.
+    pub fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_instance_profile(input))
+    }
+
+    /// <p>Deletes a network profile.</p>
+    ///
+    /// See `DeviceFarm::delete_network_profile`.
+    pub fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_network_profile(input))
+    }
+
+    /// <p>Deletes an AWS Device Farm project, given the project ARN.</p> <p> Deleting this resource does not stop an in-progress run.</p>
+    ///
+    /// See `DeviceFarm::delete_project`.
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// <p>Deletes a completed remote access session and its results.</p>
+    ///
+    /// See `DeviceFarm::delete_remote_access_session`.
+    pub fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.delete_remote_access_session(input))
+    }
+
+    /// <p>Deletes the run, given the run ARN.</p> <p> Deleting this resource does not stop an in-progress run.</p>
+    ///
+    /// See `DeviceFarm::delete_run`.
+    pub fn delete_run(
+        &self,
+        input: DeleteRunRequest,
+    ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
+        self.runtime.block_on(self.client.delete_run(input))
+    }
+
+    /// <p><p> Deletes a Selenium testing project and all content generated under it. </p> <important> <p>You cannot undo this operation.</p> </important> <note> <p>You cannot delete a project if it has active sessions.</p> </note></p>
+    ///
+    /// See `DeviceFarm::delete_test_grid_project`.
+    pub fn delete_test_grid_project(
+        &self,
+        input: DeleteTestGridProjectRequest,
+    ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.delete_test_grid_project(input))
+    }
+
+    /// <p>Deletes an upload given the upload ARN.</p>
+    ///
+    /// See `DeviceFarm::delete_upload`.
+    pub fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
+        self.runtime.block_on(self.client.delete_upload(input))
+    }
+
+    /// <p>Deletes a configuration for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    ///
+    /// See `DeviceFarm::delete_vpce_configuration`.
+    pub fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_vpce_configuration(input))
+    }
+
+    /// <p>Returns the number of unmetered iOS or unmetered Android devices that have been purchased by the account.</p>
+    ///
+    /// See `DeviceFarm::get_account_settings`.
+    pub fn get_account_settings(
+        &self,
+    ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
+        self.runtime.block_on(self.client.get_account_settings())
+    }
+
+    /// <p>Gets information about a unique device type.</p>
+    ///
+    /// See `DeviceFarm::get_device`.
+    pub fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
+        self.runtime.block_on(self.client.get_device(input))
+    }
+
+    /// <p>Returns information about a device instance that belongs to a private device fleet.</p>
+    ///
+    /// See `DeviceFarm::get_device_instance`.
+    pub fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_device_instance(input))
+    }
+
+    /// <p>Gets information about a device pool.</p>
+    ///
+    /// See `DeviceFarm::get_device_pool`.
+    pub fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
+        self.runtime.block_on(self.client.get_device_pool(input))
+    }
+
+    /// <p>Gets information about compatibility with a device pool.</p>
+    ///
+    /// See `DeviceFarm::get_device_pool_compatibility`.
+    pub fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
+    {
+        self.runtime
+            .block_on(self.client.get_device_pool_compatibility(input))
+    }
+
+    /// <p>Returns information about the specified instance profile.</p>
+    ///
+    /// See `DeviceFarm::get_instance_profile`.
+    pub fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.get_instance_profile(input))
+    }
+
+    /// <p>Gets information about a job.</p>
+    ///
+    /// See `DeviceFarm::get_job`.
+    pub fn get_job(&self, input: GetJobRequest) -> Result<GetJobResult, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// <p>Returns information about a network profile.</p>
+    ///
+    /// See `DeviceFarm::get_network_profile`.
+    pub fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.get_network_profile(input))
+    }
+
+    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    ///
+    /// See `DeviceFarm::get_offering_status`.
+    pub fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
+        self.runtime
+            .block_on(self.client.get_offering_status(input))
+    }
+
+    /// <p>Gets information about a project.</p>
+    ///
+    /// See `DeviceFarm::get_project`.
+    pub fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
+        self.runtime.block_on(self.client.get_project(input))
+    }
+
+    /// <p>Returns a link to a currently running remote access session.</p>
+    ///
+    /// See `DeviceFarm::get_remote_access_session`.
+    pub fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.get_remote_access_session(input))
+    }
+
+    /// <p>Gets information about a run.</p>
+    ///
+    /// See `DeviceFarm::get_run`.
+    pub fn get_run(&self, input: GetRunRequest) -> Result<GetRunResult, RusotoError<GetRunError>> {
+        self.runtime.block_on(self.client.get_run(input))
+    }
+
+    /// <p>Gets information about a suite.</p>
+    ///
+    /// See `DeviceFarm::get_suite`.
+    pub fn get_suite(
+        &self,
+        input: GetSuiteRequest,
+    ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
+        self.runtime.block_on(self.client.get_suite(input))
+    }
+
+    /// <p>Gets information about a test.</p>
+    ///
+    /// See `DeviceFarm::get_test`.
+    pub fn get_test(
+        &self,
+        input: GetTestRequest,
+    ) -> Result<GetTestResult, RusotoError<GetTestError>> {
+        self.runtime.block_on(self.client.get_test(input))
+    }
+
+    /// <p>Retrieves information about a Selenium testing project.</p>
+    ///
+    /// See `DeviceFarm::get_test_grid_project`.
+    pub fn get_test_grid_project(
+        &self,
+        input: GetTestGridProjectRequest,
+    ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.get_test_grid_project(input))
+    }
+
+    /// <p><p>A session is an instance of a browser created through a <code>RemoteWebDriver</code> with the URL from <a>CreateTestGridUrlResult$url</a>. You can use the following to look up sessions:</p> <ul> <li> <p>The session ARN (<a>GetTestGridSessionRequest$sessionArn</a>).</p> </li> <li> <p>The project ARN and a session ID (<a>GetTestGridSessionRequest$projectArn</a> and <a>GetTestGridSessionRequest$sessionId</a>).</p> </li> </ul> <p/></p>
+    ///
+    /// See `DeviceFarm::get_test_grid_session`.
+    pub fn get_test_grid_session(
+        &self,
+        input: GetTestGridSessionRequest,
+    ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
+        self.runtime
+            .block_on(self.client.get_test_grid_session(input))
+    }
+
+    /// <p>Gets information about an upload.</p>
+    ///
+    /// See `DeviceFarm::get_upload`.
+    pub fn get_upload(
+        &self,
+        input: GetUploadRequest,
+    ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
+        self.runtime.block_on(self.client.get_upload(input))
+    }
+
+    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    ///
+    /// See `DeviceFarm::get_vpce_configuration`.
+    pub fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_vpce_configuration(input))
+    }
+
+    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
+    ///
+    /// See `DeviceFarm::install_to_remote_access_session`.
+    pub fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.install_to_remote_access_session(input))
+    }
+
+    /// <p>Gets information about artifacts.</p>
+    ///
+    /// See `DeviceFarm::list_artifacts`.
+    pub fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+        self.runtime.block_on(self.client.list_artifacts(input))
+    }
+
+    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
+    ///
+    /// See `DeviceFarm::list_device_instances`.
+    pub fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_device_instances(input))
+    }
+
+    /// <p>Gets information about device pools.</p>
+    ///
+    /// See `DeviceFarm::list_device_pools`.
+    pub fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
+        self.runtime.block_on(self.client.list_device_pools(input))
+    }
+
+    /// <p>Gets information about unique device types.</p>
+    ///
+    /// See `DeviceFarm::list_devices`.
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// <p>Returns information about all the instance profiles in an AWS account.</p>
+    ///
+    /// See `DeviceFarm::list_instance_profiles`.
+    pub fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_instance_profiles(input))
+    }
+
+    /// <p>Gets information about jobs for a given test run.</p>
+    ///
+    /// See `DeviceFarm::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>Returns the list of available network profiles.</p>
+    ///
+    /// See `DeviceFarm::list_network_profiles`.
+    pub fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_network_profiles(input))
+    }
+
+    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you must be able to invoke this operation.</p>
+    ///
+    /// See `DeviceFarm::list_offering_promotions`.
+    pub fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
+        self.runtime
+            .block_on(self.client.list_offering_promotions(input))
+    }
+
+    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    ///
+    /// See `DeviceFarm::list_offering_transactions`.
+    pub fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
+        self.runtime
+            .block_on(self.client.list_offering_transactions(input))
+    }
+
+    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    ///
+    /// See `DeviceFarm::list_offerings`.
+    pub fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
+        self.runtime.block_on(self.client.list_offerings(input))
+    }
+
+    /// <p>Gets information about projects.</p>
+    ///
+    /// See `DeviceFarm::list_projects`.
+    pub fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// <p>Returns a list of all currently running remote access sessions.</p>
+    ///
+    /// See `DeviceFarm::list_remote_access_sessions`.
+    pub fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
+        self.runtime
+            .block_on(self.client.list_remote_access_sessions(input))
+    }
+
+    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
+    ///
+    /// See `DeviceFarm::list_runs`.
+    pub fn list_runs(
+        &self,
+        input: ListRunsRequest,
+    ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
+        self.runtime.block_on(self.client.list_runs(input))
+    }
+
+    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
+    ///
+    /// See `DeviceFarm::list_samples`.
+    pub fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
+        self.runtime.block_on(self.client.list_samples(input))
+    }
+
+    /// <p>Gets information about test suites for a given job.</p>
+    ///
+    /// See `DeviceFarm::list_suites`.
+    pub fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
+        self.runtime.block_on(self.client.list_suites(input))
+    }
+
+    /// <p>List the tags for an AWS Device Farm resource.</p>
+    ///
+    /// See `DeviceFarm::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Gets a list of all Selenium testing projects in your account.</p>
+    ///
+    /// See `DeviceFarm::list_test_grid_projects`.
+    pub fn list_test_grid_projects(
+        &self,
+        input: ListTestGridProjectsRequest,
+    ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
+        self.runtime
+            .block_on(self.client.list_test_grid_projects(input))
+    }
+
+    /// <p>Returns a list of the actions taken in a <a>TestGridSession</a>.</p>
+    ///
+    /// See `DeviceFarm::list_test_grid_session_actions`.
+    pub fn list_test_grid_session_actions(
+        &self,
+        input: ListTestGridSessionActionsRequest,
+    ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_test_grid_session_actions(input))
+    }
+
+    /// <p>Retrieves a list of artifacts created during the session.</p>
+    ///
+    /// See `DeviceFarm::list_test_grid_session_artifacts`.
+    pub fn list_test_grid_session_artifacts(
+        &self,
+        input: ListTestGridSessionArtifactsRequest,
+    ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_test_grid_session_artifacts(input))
+    }
+
+    /// <p>Retrieves a list of sessions for a <a>TestGridProject</a>.</p>
+    ///
+    /// See `DeviceFarm::list_test_grid_sessions`.
+    pub fn list_test_grid_sessions(
+        &self,
+        input: ListTestGridSessionsRequest,
+    ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
+        self.runtime
+            .block_on(self.client.list_test_grid_sessions(input))
+    }
+
+    /// <p>Gets information about tests in a given test suite.</p>
+    ///
+    /// See `DeviceFarm::list_tests`.
+    pub fn list_tests(
+        &self,
+        input: ListTestsRequest,
+    ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
+        self.runtime.block_on(self.client.list_tests(input))
+    }
+
+    /// <p>Gets information about unique problems, such as exceptions or crashes.</p> <p>Unique problems are defined as a single instance of an error across a run, job, or suite. For example, if a call in your application consistently raises an exception (<code>OutOfBoundsException in MyActivity.java:386</code>), <code>ListUniqueProblems</code> returns a single entry instead of many individual entries for that exception.</p>
+    ///
+    /// See `DeviceFarm::list_unique_problems`.
+    pub fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
+        self.runtime
+            .block_on(self.client.list_unique_problems(input))
+    }
+
+    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
+    ///
+    /// See `DeviceFarm::list_uploads`.
+    pub fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
+        self.runtime.block_on(self.client.list_uploads(input))
+    }
+
+    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
+    ///
+    /// See `DeviceFarm::list_vpce_configurations`.
+    pub fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_vpce_configurations(input))
+    }
+
+    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    ///
+    /// See `DeviceFarm::purchase_offering`.
+    pub fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
+        self.runtime.block_on(self.client.purchase_offering(input))
+    }
+
+    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. If you must be able to invoke this operation, contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a>.</p>
+    ///
+    /// See `DeviceFarm::renew_offering`.
+    pub fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
+        self.runtime.block_on(self.client.renew_offering(input))
+    }
+
+    /// <p>Schedules a run.</p>
+    ///
+    /// See `DeviceFarm::schedule_run`.
+    pub fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
+        self.runtime.block_on(self.client.schedule_run(input))
+    }
+
+    /// <p>Initiates a stop request for the current job. AWS Device Farm immediately stops the job on the device where tests have not started. You are not billed for this device. On the device where tests have started, setup suite and teardown suite tests run to completion on the device. You are billed for setup, teardown, and any tests that were in progress or already completed.</p>
+    ///
+    /// See `DeviceFarm::stop_job`.
+    pub fn stop_job(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+        self.runtime.block_on(self.client.stop_job(input))
+    }
+
+    /// <p>Ends a specified remote access session.</p>
+    ///
+    /// See `DeviceFarm::stop_remote_access_session`.
+    pub fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.stop_remote_access_session(input))
+    }
+
+    /// <p>Initiates a stop request for the current test run. AWS Device Farm immediately stops the run on devices where tests have not started. You are not billed for these devices. On devices where tests have started executing, setup suite and teardown suite tests run to completion on those devices. You are billed for setup, teardown, and any tests that were in progress or already completed.</p>
+    ///
+    /// See `DeviceFarm::stop_run`.
+    pub fn stop_run(
+        &self,
+        input: StopRunRequest,
+    ) -> Result<StopRunResult, RusotoError<StopRunError>> {
+        self.runtime.block_on(self.client.stop_run(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are also deleted.</p>
+    ///
+    /// See `DeviceFarm::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes the specified tags from a resource.</p>
+    ///
+    /// See `DeviceFarm::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates information about a private device instance.</p>
+    ///
+    /// See `DeviceFarm::update_device_instance`.
+    pub fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_device_instance(input))
+    }
+
+    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
+    ///
+    /// See `DeviceFarm::update_device_pool`.
+    pub fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
+        self.runtime.block_on(self.client.update_device_pool(input))
+    }
+
+    /// <p>Updates information about an existing private device instance profile.</p>
+    ///
+    /// See `DeviceFarm::update_instance_profile`.
+    pub fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.update_instance_profile(input))
+    }
+
+    /// <p>Updates the network profile.</p>
+    ///
+    /// See `DeviceFarm::update_network_profile`.
+    pub fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.update_network_profile(input))
+    }
+
+    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
+    ///
+    /// See `DeviceFarm::update_project`.
+    pub fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+
+    /// <p>Change details of a project.</p>
+    ///
+    /// See `DeviceFarm::update_test_grid_project`.
+    pub fn update_test_grid_project(
+        &self,
+        input: UpdateTestGridProjectRequest,
+    ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.update_test_grid_project(input))
+    }
+
+    /// <p>Updates an uploaded test spec.</p>
+    ///
+    /// See `DeviceFarm::update_upload`.
+    pub fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
+        self.runtime.block_on(self.client.update_upload(input))
+    }
+
+    /// <p>Updates information about an Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
+    ///
+    /// See `DeviceFarm::update_vpce_configuration`.
+    pub fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.update_vpce_configuration(input))
+    }
+}
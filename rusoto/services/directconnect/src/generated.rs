@@ -2116,14 +2116,16 @@ impl AcceptDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptDirectConnectGatewayAssociationProposalError::DirectConnectClient(
                             err.msg,
                         ),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptDirectConnectGatewayAssociationProposalError::DirectConnectServer(
                             err.msg,
                         ),
@@ -2166,12 +2168,14 @@ impl AllocateConnectionOnInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateConnectionOnInterconnectError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateConnectionOnInterconnectError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2214,24 +2218,28 @@ impl AllocateHostedConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateHostedConnectionError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateHostedConnectionError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(AllocateHostedConnectionError::DuplicateTagKeys(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AllocateHostedConnectionError::DuplicateTagKeys(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocateHostedConnectionError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AllocateHostedConnectionError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2272,24 +2280,28 @@ impl AllocatePrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocatePrivateVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocatePrivateVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocatePrivateVirtualInterfaceError::DuplicateTagKeys(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocatePrivateVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AllocatePrivateVirtualInterfaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2336,24 +2348,28 @@ impl AllocatePublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocatePublicVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocatePublicVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocatePublicVirtualInterfaceError::DuplicateTagKeys(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocatePublicVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AllocatePublicVirtualInterfaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2400,24 +2416,28 @@ impl AllocateTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateTransitVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateTransitVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AllocateTransitVirtualInterfaceError::DuplicateTagKeys(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocateTransitVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AllocateTransitVirtualInterfaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2460,12 +2480,14 @@ impl AssociateConnectionWithLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConnectionWithLagError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConnectionWithLagError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2504,12 +2526,14 @@ impl AssociateHostedConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateHostedConnectionError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateHostedConnectionError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2548,12 +2572,14 @@ impl AssociateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2592,14 +2618,16 @@ impl ConfirmConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(ConfirmConnectionError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmConnectionError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(ConfirmConnectionError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmConnectionError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2634,12 +2662,14 @@ impl ConfirmPrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmPrivateVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmPrivateVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2680,12 +2710,14 @@ impl ConfirmPublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmPublicVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmPublicVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2726,12 +2758,14 @@ impl ConfirmTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmTransitVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmTransitVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2770,10 +2804,16 @@ impl CreateBGPPeerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateBGPPeerError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBGPPeerError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateBGPPeerError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBGPPeerError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2810,20 +2850,25 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateConnectionError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateConnectionError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::DirectConnectServer(err.msg),
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateConnectionError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::DuplicateTagKeys(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateConnectionError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateConnectionError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2860,12 +2905,14 @@ impl CreateDirectConnectGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDirectConnectGatewayError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDirectConnectGatewayError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2906,12 +2953,14 @@ impl CreateDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
                     )
                 }
@@ -2952,14 +3001,16 @@ impl CreateDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDirectConnectGatewayAssociationProposalError::DirectConnectClient(
                             err.msg,
                         ),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDirectConnectGatewayAssociationProposalError::DirectConnectServer(
                             err.msg,
                         ),
@@ -3004,20 +3055,28 @@ impl CreateInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateInterconnectError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInterconnectError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateInterconnectError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInterconnectError::DirectConnectServer(err.msg),
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateInterconnectError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInterconnectError::DuplicateTagKeys(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateInterconnectError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInterconnectError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3056,16 +3115,16 @@ impl CreateLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service(&res, CreateLagError::DirectConnectClient(err.msg))
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service(&res, CreateLagError::DirectConnectServer(err.msg))
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateLagError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(&res, CreateLagError::DuplicateTagKeys(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateLagError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateLagError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3106,24 +3165,28 @@ impl CreatePrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePrivateVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePrivateVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePrivateVirtualInterfaceError::DuplicateTagKeys(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePrivateVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePrivateVirtualInterfaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3170,24 +3233,28 @@ impl CreatePublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePublicVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePublicVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePublicVirtualInterfaceError::DuplicateTagKeys(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePublicVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePublicVirtualInterfaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3234,24 +3301,28 @@ impl CreateTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTransitVirtualInterfaceError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTransitVirtualInterfaceError::DirectConnectServer(err.msg),
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTransitVirtualInterfaceError::DuplicateTagKeys(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateTransitVirtualInterfaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTransitVirtualInterfaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3292,10 +3363,16 @@ impl DeleteBGPPeerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteBGPPeerError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBGPPeerError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteBGPPeerError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBGPPeerError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3328,14 +3405,16 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteConnectionError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteConnectionError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3370,12 +3449,14 @@ impl DeleteDirectConnectGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDirectConnectGatewayError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDirectConnectGatewayError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3416,12 +3497,14 @@ impl DeleteDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3462,14 +3545,16 @@ impl DeleteDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDirectConnectGatewayAssociationProposalError::DirectConnectClient(
                             err.msg,
                         ),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDirectConnectGatewayAssociationProposalError::DirectConnectServer(
                             err.msg,
                         ),
@@ -3510,14 +3595,16 @@ impl DeleteInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteInterconnectError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInterconnectError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteInterconnectError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInterconnectError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3550,10 +3637,10 @@ impl DeleteLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service(&res, DeleteLagError::DirectConnectClient(err.msg))
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service(&res, DeleteLagError::DirectConnectServer(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3586,14 +3673,16 @@ impl DeleteVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteVirtualInterfaceError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualInterfaceError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteVirtualInterfaceError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVirtualInterfaceError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3626,14 +3715,16 @@ impl DescribeConnectionLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeConnectionLoaError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConnectionLoaError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeConnectionLoaError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConnectionLoaError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3666,14 +3757,16 @@ impl DescribeConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeConnectionsError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConnectionsError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeConnectionsError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConnectionsError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3708,12 +3801,14 @@ impl DescribeConnectionsOnInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConnectionsOnInterconnectError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConnectionsOnInterconnectError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3754,14 +3849,16 @@ impl DescribeDirectConnectGatewayAssociationProposalsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectClient(
                             err.msg,
                         ),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectServer(
                             err.msg,
                         ),
@@ -3804,12 +3901,14 @@ impl DescribeDirectConnectGatewayAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewayAssociationsError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewayAssociationsError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3850,12 +3949,14 @@ impl DescribeDirectConnectGatewayAttachmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewayAttachmentsError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3896,12 +3997,14 @@ impl DescribeDirectConnectGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewaysError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDirectConnectGatewaysError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3940,12 +4043,14 @@ impl DescribeHostedConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeHostedConnectionsError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeHostedConnectionsError::DirectConnectServer(err.msg),
                     )
                 }
@@ -3984,14 +4089,16 @@ impl DescribeInterconnectLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeInterconnectLoaError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInterconnectLoaError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeInterconnectLoaError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInterconnectLoaError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4024,14 +4131,16 @@ impl DescribeInterconnectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeInterconnectsError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInterconnectsError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeInterconnectsError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInterconnectsError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4064,10 +4173,16 @@ impl DescribeLagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLagsError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLagsError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLagsError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLagsError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4100,10 +4215,16 @@ impl DescribeLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLoaError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoaError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLoaError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoaError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4136,14 +4257,16 @@ impl DescribeLocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLocationsError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationsError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLocationsError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLocationsError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4176,10 +4299,16 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeTagsError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeTagsError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4212,14 +4341,16 @@ impl DescribeVirtualGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeVirtualGatewaysError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewaysError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeVirtualGatewaysError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVirtualGatewaysError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4252,12 +4383,14 @@ impl DescribeVirtualInterfacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVirtualInterfacesError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVirtualInterfacesError::DirectConnectServer(err.msg),
                     )
                 }
@@ -4298,12 +4431,14 @@ impl DisassociateConnectionFromLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConnectionFromLagError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConnectionFromLagError::DirectConnectServer(err.msg),
                     )
                 }
@@ -4344,12 +4479,14 @@ impl ListVirtualInterfaceTestHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVirtualInterfaceTestHistoryError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVirtualInterfaceTestHistoryError::DirectConnectServer(err.msg),
                     )
                 }
@@ -4388,14 +4525,16 @@ impl StartBgpFailoverTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(StartBgpFailoverTestError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartBgpFailoverTestError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(StartBgpFailoverTestError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartBgpFailoverTestError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4428,14 +4567,16 @@ impl StopBgpFailoverTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(StopBgpFailoverTestError::DirectConnectClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopBgpFailoverTestError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(StopBgpFailoverTestError::DirectConnectServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopBgpFailoverTestError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4472,16 +4613,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(TagResourceError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(TagResourceError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::DirectConnectServer(err.msg),
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(TagResourceError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service(&res, TagResourceError::DuplicateTagKeys(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4516,10 +4663,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(UntagResourceError::DirectConnectClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::DirectConnectClient(err.msg),
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(UntagResourceError::DirectConnectServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::DirectConnectServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4554,12 +4707,14 @@ impl UpdateDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
                     )
                 }
@@ -4598,10 +4753,10 @@ impl UpdateLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(UpdateLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service(&res, UpdateLagError::DirectConnectClient(err.msg))
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(UpdateLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service(&res, UpdateLagError::DirectConnectServer(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4636,12 +4791,14 @@ impl UpdateVirtualInterfaceAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateVirtualInterfaceAttributesError::DirectConnectClient(err.msg),
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateVirtualInterfaceAttributesError::DirectConnectServer(err.msg),
                     )
                 }
@@ -5055,6 +5212,7 @@ impl DirectConnectClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5070,6 +5228,7 @@ impl DirectConnectClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectClient {
         DirectConnectClient { client, region }
     }
@@ -5086,6 +5245,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptDirectConnectGatewayAssociationProposal");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.AcceptDirectConnectGatewayAssociationProposal",
@@ -5111,6 +5273,9 @@ impl DirectConnect for DirectConnectClient {
         input: AllocateConnectionOnInterconnectRequest,
     ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AllocateConnectionOnInterconnect");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocateConnectionOnInterconnect",
@@ -5135,6 +5300,9 @@ impl DirectConnect for DirectConnectClient {
         input: AllocateHostedConnectionRequest,
     ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AllocateHostedConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.AllocateHostedConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5153,6 +5321,9 @@ impl DirectConnect for DirectConnectClient {
         input: AllocatePrivateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AllocatePrivateVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocatePrivateVirtualInterface",
@@ -5174,6 +5345,9 @@ impl DirectConnect for DirectConnectClient {
         input: AllocatePublicVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AllocatePublicVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocatePublicVirtualInterface",
@@ -5198,6 +5372,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<AllocateTransitVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AllocateTransitVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocateTransitVirtualInterface",
@@ -5220,6 +5397,9 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateConnectionWithLagRequest,
     ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateConnectionWithLag");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.AssociateConnectionWithLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5238,6 +5418,9 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateHostedConnectionRequest,
     ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateHostedConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.AssociateHostedConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5256,6 +5439,9 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.AssociateVirtualInterface");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5274,6 +5460,9 @@ impl DirectConnect for DirectConnectClient {
         input: ConfirmConnectionRequest,
     ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.ConfirmConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5295,6 +5484,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmPrivateVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmPrivateVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.ConfirmPrivateVirtualInterface",
@@ -5320,6 +5512,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmPublicVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmPublicVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.ConfirmPublicVirtualInterface",
@@ -5345,6 +5540,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmTransitVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConfirmTransitVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.ConfirmTransitVirtualInterface",
@@ -5367,6 +5565,9 @@ impl DirectConnect for DirectConnectClient {
         input: CreateBGPPeerRequest,
     ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBGPPeer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.CreateBGPPeer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5385,6 +5586,9 @@ impl DirectConnect for DirectConnectClient {
         input: CreateConnectionRequest,
     ) -> Result<Connection, RusotoError<CreateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.CreateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5404,6 +5608,9 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDirectConnectGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.CreateDirectConnectGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5426,6 +5633,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<CreateDirectConnectGatewayAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDirectConnectGatewayAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.CreateDirectConnectGatewayAssociation",
@@ -5454,6 +5664,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDirectConnectGatewayAssociationProposal");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.CreateDirectConnectGatewayAssociationProposal",
@@ -5479,6 +5692,9 @@ impl DirectConnect for DirectConnectClient {
         input: CreateInterconnectRequest,
     ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateInterconnect");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.CreateInterconnect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5497,6 +5713,9 @@ impl DirectConnect for DirectConnectClient {
         input: CreateLagRequest,
     ) -> Result<Lag, RusotoError<CreateLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLag");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.CreateLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5515,6 +5734,9 @@ impl DirectConnect for DirectConnectClient {
         input: CreatePrivateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePrivateVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.CreatePrivateVirtualInterface",
@@ -5536,6 +5758,9 @@ impl DirectConnect for DirectConnectClient {
         input: CreatePublicVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePublicVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.CreatePublicVirtualInterface",
@@ -5558,6 +5783,9 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTransitVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.CreateTransitVirtualInterface",
@@ -5580,6 +5808,9 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteBGPPeerRequest,
     ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBGPPeer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.DeleteBGPPeer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5598,6 +5829,9 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteConnectionRequest,
     ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5617,6 +5851,9 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDirectConnectGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.DeleteDirectConnectGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5639,6 +5876,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DeleteDirectConnectGatewayAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDirectConnectGatewayAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.DeleteDirectConnectGatewayAssociation",
@@ -5667,6 +5907,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDirectConnectGatewayAssociationProposal");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.DeleteDirectConnectGatewayAssociationProposal",
@@ -5692,6 +5935,9 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteInterconnectRequest,
     ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteInterconnect");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.DeleteInterconnect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5710,6 +5956,9 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteLagRequest,
     ) -> Result<Lag, RusotoError<DeleteLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLag");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.DeleteLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5728,6 +5977,9 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteVirtualInterfaceRequest,
     ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVirtualInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.DeleteVirtualInterface");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5747,6 +5999,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionLoaRequest,
     ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConnectionLoa");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeConnectionLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5766,6 +6021,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionsRequest,
     ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5784,6 +6042,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionsOnInterconnectRequest,
     ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConnectionsOnInterconnect");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeConnectionsOnInterconnect",
@@ -5811,6 +6072,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDirectConnectGatewayAssociationProposals");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGatewayAssociationProposals",
@@ -5839,6 +6103,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAssociationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDirectConnectGatewayAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGatewayAssociations",
@@ -5867,6 +6134,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDirectConnectGatewayAttachments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGatewayAttachments",
@@ -5893,6 +6163,9 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDirectConnectGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGateways",
@@ -5915,6 +6188,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeHostedConnectionsRequest,
     ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHostedConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeHostedConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5933,6 +6209,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeInterconnectLoaRequest,
     ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInterconnectLoa");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnectLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5952,6 +6231,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeInterconnectsRequest,
     ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInterconnects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5970,6 +6252,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeLagsRequest,
     ) -> Result<Lags, RusotoError<DescribeLagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeLags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5988,6 +6273,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeLoaRequest,
     ) -> Result<Loa, RusotoError<DescribeLoaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLoa");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6003,6 +6291,9 @@ impl DirectConnect for DirectConnectClient {
     /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
     async fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLocations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeLocations");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6020,6 +6311,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6037,6 +6331,9 @@ impl DirectConnect for DirectConnectClient {
         &self,
     ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeVirtualGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualGateways");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6054,6 +6351,9 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeVirtualInterfacesRequest,
     ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeVirtualInterfaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualInterfaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6072,6 +6372,9 @@ impl DirectConnect for DirectConnectClient {
         input: DisassociateConnectionFromLagRequest,
     ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateConnectionFromLag");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.DisassociateConnectionFromLag",
@@ -6096,6 +6399,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ListVirtualInterfaceTestHistoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVirtualInterfaceTestHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OvertureService.ListVirtualInterfaceTestHistory",
@@ -6118,6 +6424,9 @@ impl DirectConnect for DirectConnectClient {
         input: StartBgpFailoverTestRequest,
     ) -> Result<StartBgpFailoverTestResponse, RusotoError<StartBgpFailoverTestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartBgpFailoverTest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.StartBgpFailoverTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6137,6 +6446,9 @@ impl DirectConnect for DirectConnectClient {
         input: StopBgpFailoverTestRequest,
     ) -> Result<StopBgpFailoverTestResponse, RusotoError<StopBgpFailoverTestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopBgpFailoverTest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.StopBgpFailoverTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6155,6 +6467,9 @@ impl DirectConnect for DirectConnectClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6173,6 +6488,9 @@ impl DirectConnect for DirectConnectClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6194,6 +6512,9 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<UpdateDirectConnectGatewayAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDirectConnectGatewayAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.UpdateDirectConnectGatewayAssociation",
@@ -6219,6 +6540,9 @@ impl DirectConnect for DirectConnectClient {
         input: UpdateLagRequest,
     ) -> Result<Lag, RusotoError<UpdateLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateLag");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OvertureService.UpdateLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6237,6 +6561,9 @@ impl DirectConnect for DirectConnectClient {
         input: UpdateVirtualInterfaceAttributesRequest,
     ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVirtualInterfaceAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OvertureService.UpdateVirtualInterfaceAttributes",
@@ -6255,3 +6582,700 @@ impl DirectConnect for DirectConnectClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 }
+
+/// A blocking client for the AWS Direct Connect API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DirectConnectClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DirectConnectBlockingClient {
+    client: DirectConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DirectConnectBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DirectConnectBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DirectConnectBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DirectConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DirectConnectBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient {
+            client: DirectConnectClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts a proposal request to attach a virtual private gateway or transit gateway to a Direct Connect gateway.</p>
+    ///
+    /// See `DirectConnect::accept_direct_connect_gateway_association_proposal`.
+    pub fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_direct_connect_gateway_association_proposal(input),
+        )
+    }
+
+    /// <p><p>Deprecated. Use <a>AllocateHostedConnection</a> instead.</p> <p>Creates a hosted connection on an interconnect.</p> <p>Allocates a VLAN number and a specified amount of bandwidth for use by a hosted connection on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::allocate_connection_on_interconnect`.
+    pub fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
+        self.runtime
+            .block_on(self.client.allocate_connection_on_interconnect(input))
+    }
+
+    /// <p><p>Creates a hosted connection on the specified interconnect or a link aggregation group (LAG) of interconnects.</p> <p>Allocates a VLAN number and a specified amount of capacity (bandwidth) for use by a hosted connection on the specified interconnect or LAG of interconnects. AWS polices the hosted connection for the specified capacity and the AWS Direct Connect Partner must also police the hosted connection for the specified capacity.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::allocate_hosted_connection`.
+    pub fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
+        self.runtime
+            .block_on(self.client.allocate_hosted_connection(input))
+    }
+
+    /// <p>Provisions a private virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this action must be confirmed by the owner using <a>ConfirmPrivateVirtualInterface</a>. Until then, the virtual interface is in the <code>Confirming</code> state and is not available to handle traffic.</p>
+    ///
+    /// See `DirectConnect::allocate_private_virtual_interface`.
+    pub fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.allocate_private_virtual_interface(input))
+    }
+
+    /// <p>Provisions a public virtual interface to be owned by the specified AWS account.</p> <p>The owner of a connection calls this function to provision a public virtual interface to be owned by the specified AWS account.</p> <p>Virtual interfaces created using this function must be confirmed by the owner using <a>ConfirmPublicVirtualInterface</a>. Until this step has been completed, the virtual interface is in the <code>confirming</code> state and is not available to handle traffic.</p> <p>When creating an IPv6 public virtual interface, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p>
+    ///
+    /// See `DirectConnect::allocate_public_virtual_interface`.
+    pub fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.allocate_public_virtual_interface(input))
+    }
+
+    /// <p>Provisions a transit virtual interface to be owned by the specified AWS account. Use this type of interface to connect a transit gateway to your Direct Connect gateway.</p> <p>The owner of a connection provisions a transit virtual interface to be owned by the specified AWS account.</p> <p>After you create a transit virtual interface, it must be confirmed by the owner using <a>ConfirmTransitVirtualInterface</a>. Until this step has been completed, the transit virtual interface is in the <code>requested</code> state and is not available to handle traffic.</p>
+    ///
+    /// See `DirectConnect::allocate_transit_virtual_interface`.
+    pub fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> Result<
+        AllocateTransitVirtualInterfaceResult,
+        RusotoError<AllocateTransitVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.allocate_transit_virtual_interface(input))
+    }
+
+    /// <p>Associates an existing connection with a link aggregation group (LAG). The connection is interrupted and re-established as a member of the LAG (connectivity to AWS is interrupted). The connection must be hosted on the same AWS Direct Connect endpoint as the LAG, and its bandwidth must match the bandwidth for the LAG. You can re-associate a connection that's currently associated with a different LAG; however, if removing the connection would cause the original LAG to fall below its setting for minimum number of operational connections, the request fails.</p> <p>Any virtual interfaces that are directly associated with the connection are automatically re-associated with the LAG. If the connection was originally associated with a different LAG, the virtual interfaces remain associated with the original LAG.</p> <p>For interconnects, any hosted connections are automatically re-associated with the LAG. If the interconnect was originally associated with a different LAG, the hosted connections remain associated with the original LAG.</p>
+    ///
+    /// See `DirectConnect::associate_connection_with_lag`.
+    pub fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
+        self.runtime
+            .block_on(self.client.associate_connection_with_lag(input))
+    }
+
+    /// <p><p>Associates a hosted connection and its virtual interfaces with a link aggregation group (LAG) or interconnect. If the target interconnect or LAG has an existing hosted connection with a conflicting VLAN number or IP address, the operation fails. This action temporarily interrupts the hosted connection&#39;s connectivity to AWS as it is being migrated.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::associate_hosted_connection`.
+    pub fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
+        self.runtime
+            .block_on(self.client.associate_hosted_connection(input))
+    }
+
+    /// <p>Associates a virtual interface with a specified link aggregation group (LAG) or connection. Connectivity to AWS is temporarily interrupted as the virtual interface is being migrated. If the target connection or LAG has an associated virtual interface with a conflicting VLAN number or a conflicting IP address, the operation fails.</p> <p>Virtual interfaces associated with a hosted connection cannot be associated with a LAG; hosted connections must be migrated along with their virtual interfaces using <a>AssociateHostedConnection</a>.</p> <p>To reassociate a virtual interface to a new connection or LAG, the requester must own either the virtual interface itself or the connection to which the virtual interface is currently associated. Additionally, the requester must own the connection or LAG for the association.</p>
+    ///
+    /// See `DirectConnect::associate_virtual_interface`.
+    pub fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.associate_virtual_interface(input))
+    }
+
+    /// <p>Confirms the creation of the specified hosted connection on an interconnect.</p> <p>Upon creation, the hosted connection is initially in the <code>Ordering</code> state, and remains in this state until the owner confirms creation of the hosted connection.</p>
+    ///
+    /// See `DirectConnect::confirm_connection`.
+    pub fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
+        self.runtime.block_on(self.client.confirm_connection(input))
+    }
+
+    /// <p>Accepts ownership of a private virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the virtual interface is created and attached to the specified virtual private gateway or Direct Connect gateway, and is made available to handle traffic.</p>
+    ///
+    /// See `DirectConnect::confirm_private_virtual_interface`.
+    pub fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmPrivateVirtualInterfaceResponse,
+        RusotoError<ConfirmPrivateVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.confirm_private_virtual_interface(input))
+    }
+
+    /// <p>Accepts ownership of a public virtual interface created by another AWS account.</p> <p>After the virtual interface owner makes this call, the specified virtual interface is created and made available to handle traffic.</p>
+    ///
+    /// See `DirectConnect::confirm_public_virtual_interface`.
+    pub fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmPublicVirtualInterfaceResponse,
+        RusotoError<ConfirmPublicVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.confirm_public_virtual_interface(input))
+    }
+
+    /// <p>Accepts ownership of a transit virtual interface created by another AWS account.</p> <p> After the owner of the transit virtual interface makes this call, the specified transit virtual interface is created and made available to handle traffic.</p>
+    ///
+    /// See `DirectConnect::confirm_transit_virtual_interface`.
+    pub fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmTransitVirtualInterfaceResponse,
+        RusotoError<ConfirmTransitVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.confirm_transit_virtual_interface(input))
+    }
+
+    /// <p>Creates a BGP peer on the specified virtual interface.</p> <p>You must create a BGP peer for the corresponding address family (IPv4/IPv6) in order to access AWS resources that also use that address family.</p> <p>If logical redundancy is not supported by the connection, interconnect, or LAG, the BGP peer cannot be in the same address family as an existing BGP peer on the virtual interface.</p> <p>When creating a IPv6 BGP peer, omit the Amazon address and customer address. IPv6 addresses are automatically assigned from the Amazon pool of IPv6 addresses; you cannot specify custom IPv6 addresses.</p> <p>For a public virtual interface, the Autonomous System Number (ASN) must be private or already whitelisted for the virtual interface.</p>
+    ///
+    /// See `DirectConnect::create_bgp_peer`.
+    pub fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
+        self.runtime.block_on(self.client.create_bgp_peer(input))
+    }
+
+    /// <p>Creates a connection between a customer network and a specific AWS Direct Connect location.</p> <p>A connection links your internal network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end of the cable is connected to your router, the other to an AWS Direct Connect router.</p> <p>To find the locations for your Region, use <a>DescribeLocations</a>.</p> <p>You can automatically add the new connection to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new connection is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no connection is created.</p>
+    ///
+    /// See `DirectConnect::create_connection`.
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<Connection, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// <p>Creates a Direct Connect gateway, which is an intermediate object that enables you to connect a set of virtual interfaces and virtual private gateways. A Direct Connect gateway is global and visible in any AWS Region after it is created. The virtual interfaces and virtual private gateways that are connected through a Direct Connect gateway can be in different AWS Regions. This enables you to connect to a VPC in any Region, regardless of the Region in which the virtual interfaces are located, and pass traffic between them.</p>
+    ///
+    /// See `DirectConnect::create_direct_connect_gateway`.
+    pub fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
+    {
+        self.runtime
+            .block_on(self.client.create_direct_connect_gateway(input))
+    }
+
+    /// <p>Creates an association between a Direct Connect gateway and a virtual private gateway. The virtual private gateway must be attached to a VPC and must not be associated with another Direct Connect gateway.</p>
+    ///
+    /// See `DirectConnect::create_direct_connect_gateway_association`.
+    pub fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        CreateDirectConnectGatewayAssociationResult,
+        RusotoError<CreateDirectConnectGatewayAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_direct_connect_gateway_association(input))
+    }
+
+    /// <p>Creates a proposal to associate the specified virtual private gateway or transit gateway with the specified Direct Connect gateway.</p> <p>You can only associate a Direct Connect gateway and virtual private gateway or transit gateway when the account that owns the Direct Connect gateway and the account that owns the virtual private gateway or transit gateway have the same AWS Payer ID.</p>
+    ///
+    /// See `DirectConnect::create_direct_connect_gateway_association_proposal`.
+    pub fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_direct_connect_gateway_association_proposal(input),
+        )
+    }
+
+    /// <p><p>Creates an interconnect between an AWS Direct Connect Partner&#39;s network and a specific AWS Direct Connect location.</p> <p>An interconnect is a connection that is capable of hosting other connections. The AWS Direct Connect partner can use an interconnect to provide AWS Direct Connect hosted connections to customers through their own network services. Like a standard connection, an interconnect links the partner&#39;s network to an AWS Direct Connect location over a standard Ethernet fiber-optic cable. One end is connected to the partner&#39;s router, the other to an AWS Direct Connect router.</p> <p>You can automatically add the new interconnect to a link aggregation group (LAG) by specifying a LAG ID in the request. This ensures that the new interconnect is allocated on the same AWS Direct Connect endpoint that hosts the specified LAG. If there are no available ports on the endpoint, the request fails and no interconnect is created.</p> <p>For each end customer, the AWS Direct Connect Partner provisions a connection on their interconnect by calling <a>AllocateHostedConnection</a>. The end customer can then connect to AWS resources by creating a virtual interface on their connection, using the VLAN assigned to them by the AWS Direct Connect Partner.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::create_interconnect`.
+    pub fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
+        self.runtime
+            .block_on(self.client.create_interconnect(input))
+    }
+
+    /// <p>Creates a link aggregation group (LAG) with the specified number of bundled physical connections between the customer network and a specific AWS Direct Connect location. A LAG is a logical interface that uses the Link Aggregation Control Protocol (LACP) to aggregate multiple interfaces, enabling you to treat them as a single interface.</p> <p>All connections in a LAG must use the same bandwidth and must terminate at the same AWS Direct Connect endpoint.</p> <p>You can have up to 10 connections per LAG. Regardless of this limit, if you request more connections for the LAG than AWS Direct Connect can allocate on a single endpoint, no LAG is created.</p> <p>You can specify an existing physical connection or interconnect to include in the LAG (which counts towards the total number of connections). Doing so interrupts the current physical connection or hosted connections, and re-establishes them as a member of the LAG. The LAG will be created on the same AWS Direct Connect endpoint to which the connection terminates. Any virtual interfaces associated with the connection are automatically disassociated and re-associated with the LAG. The connection ID does not change.</p> <p>If the AWS account used to create a LAG is a registered AWS Direct Connect Partner, the LAG is automatically enabled to host sub-connections. For a LAG owned by a partner, any associated virtual interfaces cannot be directly configured.</p>
+    ///
+    /// See `DirectConnect::create_lag`.
+    pub fn create_lag(&self, input: CreateLagRequest) -> Result<Lag, RusotoError<CreateLagError>> {
+        self.runtime.block_on(self.client.create_lag(input))
+    }
+
+    /// <p>Creates a private virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A private virtual interface can be connected to either a Direct Connect gateway or a Virtual Private Gateway (VGW). Connecting the private virtual interface to a Direct Connect gateway enables the possibility for connecting to multiple VPCs, including VPCs in different AWS Regions. Connecting the private virtual interface to a VGW only provides access to a single VPC within the same Region.</p> <p>Setting the MTU of a virtual interface to 9001 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
+    ///
+    /// See `DirectConnect::create_private_virtual_interface`.
+    pub fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.create_private_virtual_interface(input))
+    }
+
+    /// <p>Creates a public virtual interface. A virtual interface is the VLAN that transports AWS Direct Connect traffic. A public virtual interface supports sending traffic to public services of AWS such as Amazon S3.</p> <p>When creating an IPv6 public virtual interface (<code>addressFamily</code> is <code>ipv6</code>), leave the <code>customer</code> and <code>amazon</code> address fields blank to use auto-assigned IPv6 space. Custom IPv6 addresses are not supported.</p>
+    ///
+    /// See `DirectConnect::create_public_virtual_interface`.
+    pub fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.create_public_virtual_interface(input))
+    }
+
+    /// <p>Creates a transit virtual interface. A transit virtual interface should be used to access one or more transit gateways associated with Direct Connect gateways. A transit virtual interface enables the connection of multiple VPCs attached to a transit gateway to a Direct Connect gateway.</p> <important> <p>If you associate your transit gateway with one or more Direct Connect gateways, the Autonomous System Number (ASN) used by the transit gateway and the Direct Connect gateway must be different. For example, if you use the default ASN 64512 for both your the transit gateway and Direct Connect gateway, the association request fails.</p> </important> <p>Setting the MTU of a virtual interface to 8500 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
+    ///
+    /// See `DirectConnect::create_transit_virtual_interface`.
+    pub fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_transit_virtual_interface(input))
+    }
+
+    /// <p>Deletes the specified BGP peer on the specified virtual interface with the specified customer address and ASN.</p> <p>You cannot delete the last BGP peer from a virtual interface.</p>
+    ///
+    /// See `DirectConnect::delete_bgp_peer`.
+    pub fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
+        self.runtime.block_on(self.client.delete_bgp_peer(input))
+    }
+
+    /// <p>Deletes the specified connection.</p> <p>Deleting a connection only stops the AWS Direct Connect port hour and data transfer charges. If you are partnering with any third parties to connect with the AWS Direct Connect location, you must cancel your service with them separately.</p>
+    ///
+    /// See `DirectConnect::delete_connection`.
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// <p>Deletes the specified Direct Connect gateway. You must first delete all virtual interfaces that are attached to the Direct Connect gateway and disassociate all virtual private gateways associated with the Direct Connect gateway.</p>
+    ///
+    /// See `DirectConnect::delete_direct_connect_gateway`.
+    pub fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_direct_connect_gateway(input))
+    }
+
+    /// <p>Deletes the association between the specified Direct Connect gateway and virtual private gateway.</p> <p>We recommend that you specify the <code>associationID</code> to delete the association. Alternatively, if you own virtual gateway and a Direct Connect gateway association, you can specify the <code>virtualGatewayId</code> and <code>directConnectGatewayId</code> to delete an association.</p>
+    ///
+    /// See `DirectConnect::delete_direct_connect_gateway_association`.
+    pub fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        DeleteDirectConnectGatewayAssociationResult,
+        RusotoError<DeleteDirectConnectGatewayAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_direct_connect_gateway_association(input))
+    }
+
+    /// <p>Deletes the association proposal request between the specified Direct Connect gateway and virtual private gateway or transit gateway.</p>
+    ///
+    /// See `DirectConnect::delete_direct_connect_gateway_association_proposal`.
+    pub fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_direct_connect_gateway_association_proposal(input),
+        )
+    }
+
+    /// <p><p>Deletes the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::delete_interconnect`.
+    pub fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
+        self.runtime
+            .block_on(self.client.delete_interconnect(input))
+    }
+
+    /// <p>Deletes the specified link aggregation group (LAG). You cannot delete a LAG if it has active virtual interfaces or hosted connections.</p>
+    ///
+    /// See `DirectConnect::delete_lag`.
+    pub fn delete_lag(&self, input: DeleteLagRequest) -> Result<Lag, RusotoError<DeleteLagError>> {
+        self.runtime.block_on(self.client.delete_lag(input))
+    }
+
+    /// <p>Deletes a virtual interface.</p>
+    ///
+    /// See `DirectConnect::delete_virtual_interface`.
+    pub fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_interface(input))
+    }
+
+    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for a connection.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that your APN partner or service provider uses when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    ///
+    /// See `DirectConnect::describe_connection_loa`.
+    pub fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
+        self.runtime
+            .block_on(self.client.describe_connection_loa(input))
+    }
+
+    /// <p>Displays the specified connection or all connections in this Region.</p>
+    ///
+    /// See `DirectConnect::describe_connections`.
+    pub fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_connections(input))
+    }
+
+    /// <p><p>Deprecated. Use <a>DescribeHostedConnections</a> instead.</p> <p>Lists the connections that have been provisioned on the specified interconnect.</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::describe_connections_on_interconnect`.
+    pub fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
+        self.runtime
+            .block_on(self.client.describe_connections_on_interconnect(input))
+    }
+
+    /// <p>Describes one or more association proposals for connection between a virtual private gateway or transit gateway and a Direct Connect gateway. </p>
+    ///
+    /// See `DirectConnect::describe_direct_connect_gateway_association_proposals`.
+    pub fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_direct_connect_gateway_association_proposals(input),
+        )
+    }
+
+    /// <p>Lists the associations between your Direct Connect gateways and virtual private gateways. You must specify a Direct Connect gateway, a virtual private gateway, or both. If you specify a Direct Connect gateway, the response contains all virtual private gateways associated with the Direct Connect gateway. If you specify a virtual private gateway, the response contains all Direct Connect gateways associated with the virtual private gateway. If you specify both, the response contains the association between the Direct Connect gateway and the virtual private gateway.</p>
+    ///
+    /// See `DirectConnect::describe_direct_connect_gateway_associations`.
+    pub fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAssociationsResult,
+        RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_direct_connect_gateway_associations(input),
+        )
+    }
+
+    /// <p>Lists the attachments between your Direct Connect gateways and virtual interfaces. You must specify a Direct Connect gateway, a virtual interface, or both. If you specify a Direct Connect gateway, the response contains all virtual interfaces attached to the Direct Connect gateway. If you specify a virtual interface, the response contains all Direct Connect gateways attached to the virtual interface. If you specify both, the response contains the attachment between the Direct Connect gateway and the virtual interface.</p>
+    ///
+    /// See `DirectConnect::describe_direct_connect_gateway_attachments`.
+    pub fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_direct_connect_gateway_attachments(input),
+        )
+    }
+
+    /// <p>Lists all your Direct Connect gateways or only the specified Direct Connect gateway. Deleted Direct Connect gateways are not returned.</p>
+    ///
+    /// See `DirectConnect::describe_direct_connect_gateways`.
+    pub fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_direct_connect_gateways(input))
+    }
+
+    /// <p><p>Lists the hosted connections that have been provisioned on the specified interconnect or link aggregation group (LAG).</p> <note> <p>Intended for use by AWS Direct Connect Partners only.</p> </note></p>
+    ///
+    /// See `DirectConnect::describe_hosted_connections`.
+    pub fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_hosted_connections(input))
+    }
+
+    /// <p>Deprecated. Use <a>DescribeLoa</a> instead.</p> <p>Gets the LOA-CFA for the specified interconnect.</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    ///
+    /// See `DirectConnect::describe_interconnect_loa`.
+    pub fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
+        self.runtime
+            .block_on(self.client.describe_interconnect_loa(input))
+    }
+
+    /// <p>Lists the interconnects owned by the AWS account or only the specified interconnect.</p>
+    ///
+    /// See `DirectConnect::describe_interconnects`.
+    pub fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
+        self.runtime
+            .block_on(self.client.describe_interconnects(input))
+    }
+
+    /// <p>Describes all your link aggregation groups (LAG) or the specified LAG.</p>
+    ///
+    /// See `DirectConnect::describe_lags`.
+    pub fn describe_lags(
+        &self,
+        input: DescribeLagsRequest,
+    ) -> Result<Lags, RusotoError<DescribeLagsError>> {
+        self.runtime.block_on(self.client.describe_lags(input))
+    }
+
+    /// <p>Gets the LOA-CFA for a connection, interconnect, or link aggregation group (LAG).</p> <p>The Letter of Authorization - Connecting Facility Assignment (LOA-CFA) is a document that is used when establishing your cross connect to AWS at the colocation facility. For more information, see <a href="https://docs.aws.amazon.com/directconnect/latest/UserGuide/Colocation.html">Requesting Cross Connects at AWS Direct Connect Locations</a> in the <i>AWS Direct Connect User Guide</i>.</p>
+    ///
+    /// See `DirectConnect::describe_loa`.
+    pub fn describe_loa(
+        &self,
+        input: DescribeLoaRequest,
+    ) -> Result<Loa, RusotoError<DescribeLoaError>> {
+        self.runtime.block_on(self.client.describe_loa(input))
+    }
+
+    /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
+    ///
+    /// See `DirectConnect::describe_locations`.
+    pub fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
+        self.runtime.block_on(self.client.describe_locations())
+    }
+
+    /// <p>Describes the tags associated with the specified AWS Direct Connect resources.</p>
+    ///
+    /// See `DirectConnect::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Lists the virtual private gateways owned by the AWS account.</p> <p>You can create one or more AWS Direct Connect private virtual interfaces linked to a virtual private gateway.</p>
+    ///
+    /// See `DirectConnect::describe_virtual_gateways`.
+    pub fn describe_virtual_gateways(
+        &self,
+    ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_gateways())
+    }
+
+    /// <p>Displays all virtual interfaces for an AWS account. Virtual interfaces deleted fewer than 15 minutes before you make the request are also returned. If you specify a connection ID, only the virtual interfaces associated with the connection are returned. If you specify a virtual interface ID, then only a single virtual interface is returned.</p> <p>A virtual interface (VLAN) transmits the traffic between the AWS Direct Connect location and the customer network.</p>
+    ///
+    /// See `DirectConnect::describe_virtual_interfaces`.
+    pub fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_interfaces(input))
+    }
+
+    /// <p>Disassociates a connection from a link aggregation group (LAG). The connection is interrupted and re-established as a standalone connection (the connection is not deleted; to delete the connection, use the <a>DeleteConnection</a> request). If the LAG has associated virtual interfaces or hosted connections, they remain associated with the LAG. A disassociated connection owned by an AWS Direct Connect Partner is automatically converted to an interconnect.</p> <p>If disassociating the connection would cause the LAG to fall below its setting for minimum number of operational connections, the request fails, except when it's the last member of the LAG. If all connections are disassociated, the LAG continues to exist as an empty LAG with no physical connections. </p>
+    ///
+    /// See `DirectConnect::disassociate_connection_from_lag`.
+    pub fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
+        self.runtime
+            .block_on(self.client.disassociate_connection_from_lag(input))
+    }
+
+    /// <p>Lists the virtual interface failover test history.</p>
+    ///
+    /// See `DirectConnect::list_virtual_interface_test_history`.
+    pub fn list_virtual_interface_test_history(
+        &self,
+        input: ListVirtualInterfaceTestHistoryRequest,
+    ) -> Result<
+        ListVirtualInterfaceTestHistoryResponse,
+        RusotoError<ListVirtualInterfaceTestHistoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_virtual_interface_test_history(input))
+    }
+
+    /// <p>Starts the virtual interface failover test that verifies your configuration meets your resiliency requirements by placing the BGP peering session in the DOWN state. You can then send traffic to verify that there are no outages.</p> <p>You can run the test on public, private, transit, and hosted virtual interfaces.</p> <p>You can use <a href="https://docs.aws.amazon.com/directconnect/latest/APIReference/API_ListVirtualInterfaceTestHistory.html">ListVirtualInterfaceTestHistory</a> to view the virtual interface test history.</p> <p>If you need to stop the test before the test interval completes, use <a href="https://docs.aws.amazon.com/directconnect/latest/APIReference/API_StopBgpFailoverTest.html">StopBgpFailoverTest</a>.</p>
+    ///
+    /// See `DirectConnect::start_bgp_failover_test`.
+    pub fn start_bgp_failover_test(
+        &self,
+        input: StartBgpFailoverTestRequest,
+    ) -> Result<StartBgpFailoverTestResponse, RusotoError<StartBgpFailoverTestError>> {
+        self.runtime
+            .block_on(self.client.start_bgp_failover_test(input))
+    }
+
+    /// <p>Stops the virtual interface failover test.</p>
+    ///
+    /// See `DirectConnect::stop_bgp_failover_test`.
+    pub fn stop_bgp_failover_test(
+        &self,
+        input: StopBgpFailoverTestRequest,
+    ) -> Result<StopBgpFailoverTestResponse, RusotoError<StopBgpFailoverTestError>> {
+        self.runtime
+            .block_on(self.client.stop_bgp_failover_test(input))
+    }
+
+    /// <p>Adds the specified tags to the specified AWS Direct Connect resource. Each resource can have a maximum of 50 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the resource, this action updates its value.</p>
+    ///
+    /// See `DirectConnect::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from the specified AWS Direct Connect resource.</p>
+    ///
+    /// See `DirectConnect::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the specified attributes of the Direct Connect gateway association.</p> <p>Add or remove prefixes from the association.</p>
+    ///
+    /// See `DirectConnect::update_direct_connect_gateway_association`.
+    pub fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        UpdateDirectConnectGatewayAssociationResult,
+        RusotoError<UpdateDirectConnectGatewayAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_direct_connect_gateway_association(input))
+    }
+
+    /// <p>Updates the attributes of the specified link aggregation group (LAG).</p> <p>You can update the following attributes:</p> <ul> <li> <p>The name of the LAG.</p> </li> <li> <p>The value for the minimum number of connections that must be operational for the LAG itself to be operational. </p> </li> </ul> <p>When you create a LAG, the default value for the minimum number of operational connections is zero (0). If you update this value and the number of operational connections falls below the specified value, the LAG automatically goes down to avoid over-utilization of the remaining connections. Adjust this value with care, as it could force the LAG down if it is set higher than the current number of operational connections.</p>
+    ///
+    /// See `DirectConnect::update_lag`.
+    pub fn update_lag(&self, input: UpdateLagRequest) -> Result<Lag, RusotoError<UpdateLagError>> {
+        self.runtime.block_on(self.client.update_lag(input))
+    }
+
+    /// <p>Updates the specified attributes of the specified virtual private interface.</p> <p>Setting the MTU of a virtual interface to 9001 (jumbo frames) can cause an update to the underlying physical connection if it wasn't updated to support jumbo frames. Updating the connection disrupts network connectivity for all virtual interfaces associated with the connection for up to 30 seconds. To check whether your connection supports jumbo frames, call <a>DescribeConnections</a>. To check whether your virtual q interface supports jumbo frames, call <a>DescribeVirtualInterfaces</a>.</p>
+    ///
+    /// See `DirectConnect::update_virtual_interface_attributes`.
+    pub fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_interface_attributes(input))
+    }
+}
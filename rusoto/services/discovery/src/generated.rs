@@ -1075,29 +1075,34 @@ impl AssociateConfigurationItemsToApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConfigurationItemsToApplicationError::AuthorizationError(err.msg),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConfigurationItemsToApplicationError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConfigurationItemsToApplicationError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConfigurationItemsToApplicationError::InvalidParameterValue(
                             err.msg,
                         ),
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateConfigurationItemsToApplicationError::ServerInternalError(err.msg),
                     )
                 }
@@ -1151,29 +1156,34 @@ impl BatchDeleteImportDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImportDataError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImportDataError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImportDataError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImportDataError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImportDataError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1215,25 +1225,34 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateApplicationError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1277,22 +1296,28 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(CreateTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::AuthorizationError(err.msg))
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::HomeRegionNotSet(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::InvalidParameter(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::ResourceNotFound(err.msg))
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(CreateTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1335,25 +1360,34 @@ impl DeleteApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DeleteApplicationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteApplicationsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteApplicationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteApplicationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DeleteApplicationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1397,22 +1431,28 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::AuthorizationError(err.msg))
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::HomeRegionNotSet(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::InvalidParameter(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::ResourceNotFound(err.msg))
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1455,21 +1495,34 @@ impl DescribeAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeAgentsError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAgentsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeAgentsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAgentsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeAgentsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAgentsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeAgentsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAgentsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeAgentsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAgentsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1511,29 +1564,34 @@ impl DescribeConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1579,37 +1637,44 @@ impl DescribeContinuousExportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContinuousExportsError::AuthorizationError(err.msg),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContinuousExportsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContinuousExportsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContinuousExportsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContinuousExportsError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContinuousExportsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContinuousExportsError::ServerInternalError(err.msg),
                     )
                 }
@@ -1665,32 +1730,38 @@ impl DescribeExportConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeExportConfigurationsError::AuthorizationError(err.msg),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeExportConfigurationsError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeExportConfigurationsError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeExportConfigurationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeExportConfigurationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeExportConfigurationsError::ServerInternalError(err.msg),
                     )
                 }
@@ -1747,29 +1818,34 @@ impl DescribeExportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeExportTasksError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeExportTasksError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeExportTasksError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1811,29 +1887,34 @@ impl DescribeImportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeImportTasksError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImportTasksError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeImportTasksError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImportTasksError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImportTasksError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImportTasksError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeImportTasksError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImportTasksError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeImportTasksError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImportTasksError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1877,22 +1958,31 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::HomeRegionNotSet(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::InvalidParameter(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::ResourceNotFound(err.msg))
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1937,35 +2027,40 @@ impl DisassociateConfigurationItemsFromApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConfigurationItemsFromApplicationError::AuthorizationError(
                             err.msg,
                         ),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConfigurationItemsFromApplicationError::HomeRegionNotSet(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConfigurationItemsFromApplicationError::InvalidParameter(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConfigurationItemsFromApplicationError::InvalidParameterValue(
                             err.msg,
                         ),
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConfigurationItemsFromApplicationError::ServerInternalError(
                             err.msg,
                         ),
@@ -2023,34 +2118,40 @@ impl ExportConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ExportConfigurationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportConfigurationsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ExportConfigurationsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportConfigurationsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ExportConfigurationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportConfigurationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ExportConfigurationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportConfigurationsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ExportConfigurationsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportConfigurationsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ExportConfigurationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportConfigurationsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2093,29 +2194,34 @@ impl GetDiscoverySummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoverySummaryError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoverySummaryError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoverySummaryError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoverySummaryError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoverySummaryError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2159,28 +2265,40 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListConfigurationsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListConfigurationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListConfigurationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2223,29 +2341,34 @@ impl ListServerNeighborsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ListServerNeighborsError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServerNeighborsError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListServerNeighborsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServerNeighborsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListServerNeighborsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServerNeighborsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListServerNeighborsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServerNeighborsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ListServerNeighborsError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServerNeighborsError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2293,40 +2416,52 @@ impl StartContinuousExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::AuthorizationError(err.msg),
+                    )
                 }
                 "ConflictErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::ConflictError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::ConflictError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartContinuousExportError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContinuousExportError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartContinuousExportError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartContinuousExportError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartContinuousExportError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContinuousExportError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2373,27 +2508,32 @@ impl StartDataCollectionByAgentIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDataCollectionByAgentIdsError::AuthorizationError(err.msg),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDataCollectionByAgentIdsError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDataCollectionByAgentIdsError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDataCollectionByAgentIdsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDataCollectionByAgentIdsError::ServerInternalError(err.msg),
                     )
                 }
@@ -2449,26 +2589,40 @@ impl StartExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartExportTaskError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartExportTaskError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartExportTaskError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartExportTaskError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartExportTaskError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartExportTaskError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExportTaskError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartExportTaskError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExportTaskError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartExportTaskError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartExportTaskError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2513,24 +2667,37 @@ impl StartImportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartImportTaskError::AuthorizationError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImportTaskError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartImportTaskError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImportTaskError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartImportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImportTaskError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartImportTaskError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImportTaskError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartImportTaskError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, StartImportTaskError::ResourceInUse(err.msg))
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartImportTaskError::ServerInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImportTaskError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2579,42 +2746,52 @@ impl StopContinuousExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StopContinuousExportError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StopContinuousExportError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopContinuousExportError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StopContinuousExportError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopContinuousExportError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopContinuousExportError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContinuousExportError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StopContinuousExportError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopContinuousExportError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2661,27 +2838,32 @@ impl StopDataCollectionByAgentIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDataCollectionByAgentIdsError::AuthorizationError(err.msg),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDataCollectionByAgentIdsError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDataCollectionByAgentIdsError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDataCollectionByAgentIdsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDataCollectionByAgentIdsError::ServerInternalError(err.msg),
                     )
                 }
@@ -2735,25 +2917,34 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::AuthorizationError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::AuthorizationError(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(UpdateApplicationError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::ServerInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ServerInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2952,6 +3143,7 @@ impl DiscoveryClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2967,6 +3159,7 @@ impl DiscoveryClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryClient {
         DiscoveryClient { client, region }
     }
@@ -2983,6 +3176,9 @@ impl Discovery for DiscoveryClient {
         RusotoError<AssociateConfigurationItemsToApplicationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateConfigurationItemsToApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.AssociateConfigurationItemsToApplication",
@@ -3008,6 +3204,9 @@ impl Discovery for DiscoveryClient {
         input: BatchDeleteImportDataRequest,
     ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteImportData");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.BatchDeleteImportData",
@@ -3030,6 +3229,9 @@ impl Discovery for DiscoveryClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.CreateApplication",
@@ -3051,6 +3253,9 @@ impl Discovery for DiscoveryClient {
         input: CreateTagsRequest,
     ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.CreateTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3069,6 +3274,9 @@ impl Discovery for DiscoveryClient {
         input: DeleteApplicationsRequest,
     ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplications");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DeleteApplications",
@@ -3090,6 +3298,9 @@ impl Discovery for DiscoveryClient {
         input: DeleteTagsRequest,
     ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3108,6 +3319,9 @@ impl Discovery for DiscoveryClient {
         input: DescribeAgentsRequest,
     ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAgents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeAgents",
@@ -3129,6 +3343,9 @@ impl Discovery for DiscoveryClient {
         input: DescribeConfigurationsRequest,
     ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeConfigurations",
@@ -3152,6 +3369,9 @@ impl Discovery for DiscoveryClient {
     ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeContinuousExports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeContinuousExports",
@@ -3175,6 +3395,9 @@ impl Discovery for DiscoveryClient {
     ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeExportConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeExportConfigurations",
@@ -3197,6 +3420,9 @@ impl Discovery for DiscoveryClient {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeExportTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeExportTasks",
@@ -3218,6 +3444,9 @@ impl Discovery for DiscoveryClient {
         input: DescribeImportTasksRequest,
     ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeImportTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeImportTasks",
@@ -3239,6 +3468,9 @@ impl Discovery for DiscoveryClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeTags",
@@ -3263,6 +3495,9 @@ impl Discovery for DiscoveryClient {
         RusotoError<DisassociateConfigurationItemsFromApplicationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateConfigurationItemsFromApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DisassociateConfigurationItemsFromApplication",
@@ -3287,6 +3522,9 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExportConfigurations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.ExportConfigurations",
@@ -3307,6 +3545,9 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDiscoverySummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.GetDiscoverySummary",
@@ -3327,6 +3568,9 @@ impl Discovery for DiscoveryClient {
         input: ListConfigurationsRequest,
     ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.ListConfigurations",
@@ -3348,6 +3592,9 @@ impl Discovery for DiscoveryClient {
         input: ListServerNeighborsRequest,
     ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServerNeighbors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.ListServerNeighbors",
@@ -3368,6 +3615,9 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartContinuousExport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartContinuousExport",
@@ -3392,6 +3642,9 @@ impl Discovery for DiscoveryClient {
         RusotoError<StartDataCollectionByAgentIdsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDataCollectionByAgentIds");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartDataCollectionByAgentIds",
@@ -3414,6 +3667,9 @@ impl Discovery for DiscoveryClient {
         input: StartExportTaskRequest,
     ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartExportTask",
@@ -3435,6 +3691,9 @@ impl Discovery for DiscoveryClient {
         input: StartImportTaskRequest,
     ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartImportTask");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartImportTask",
@@ -3456,6 +3715,9 @@ impl Discovery for DiscoveryClient {
         input: StopContinuousExportRequest,
     ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopContinuousExport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StopContinuousExport",
@@ -3479,6 +3741,9 @@ impl Discovery for DiscoveryClient {
     ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopDataCollectionByAgentIds");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StopDataCollectionByAgentIds",
@@ -3501,6 +3766,9 @@ impl Discovery for DiscoveryClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.UpdateApplication",
@@ -3516,3 +3784,330 @@ impl Discovery for DiscoveryClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Application Discovery Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DiscoveryClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DiscoveryBlockingClient {
+    client: DiscoveryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DiscoveryBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DiscoveryBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DiscoveryBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DiscoveryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DiscoveryBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient {
+            client: DiscoveryClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates one or more configuration items with an application.</p>
+    ///
+    /// See `Discovery::associate_configuration_items_to_application`.
+    pub fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> Result<
+        AssociateConfigurationItemsToApplicationResponse,
+        RusotoError<AssociateConfigurationItemsToApplicationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_configuration_items_to_application(input),
+        )
+    }
+
+    /// <p>Deletes one or more import tasks, each identified by their import ID. Each import task has a number of records that can identify servers or applications. </p> <p>AWS Application Discovery Service has built-in matching logic that will identify when discovered servers match existing entries that you've previously discovered, the information for the already-existing discovered server is updated. When you delete an import task that contains records that were used to match, the information in those matched records that comes from the deleted records will also be deleted.</p>
+    ///
+    /// See `Discovery::batch_delete_import_data`.
+    pub fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_import_data(input))
+    }
+
+    /// <p>Creates an application with the given name and description.</p>
+    ///
+    /// See `Discovery::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p>Creates one or more tags for configuration items. Tags are metadata that help you categorize IT assets. This API accepts a list of multiple configuration items.</p>
+    ///
+    /// See `Discovery::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Deletes a list of applications and their associations with configuration items.</p>
+    ///
+    /// See `Discovery::delete_applications`.
+    pub fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
+        self.runtime
+            .block_on(self.client.delete_applications(input))
+    }
+
+    /// <p>Deletes the association between configuration items and one or more tags. This API accepts a list of multiple configuration items.</p>
+    ///
+    /// See `Discovery::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Lists agents or connectors as specified by ID or other filters. All agents/connectors associated with your user account can be listed if you call <code>DescribeAgents</code> as is without passing any parameters.</p>
+    ///
+    /// See `Discovery::describe_agents`.
+    pub fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
+        self.runtime.block_on(self.client.describe_agents(input))
+    }
+
+    /// <p><p>Retrieves attributes for a list of configuration item IDs.</p> <note> <p>All of the supplied IDs must be for the same asset type from one of the following:</p> <ul> <li> <p>server</p> </li> <li> <p>application</p> </li> <li> <p>process</p> </li> <li> <p>connection</p> </li> </ul> <p>Output fields are specific to the asset type specified. For example, the output for a <i>server</i> configuration item includes a list of attributes about the server, such as host name, operating system, number of network cards, etc.</p> <p>For a complete list of outputs for each asset type, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-api-queries.html#DescribeConfigurations">Using the DescribeConfigurations Action</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> </note></p>
+    ///
+    /// See `Discovery::describe_configurations`.
+    pub fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.describe_configurations(input))
+    }
+
+    /// <p>Lists exports as specified by ID. All continuous exports associated with your user account can be listed if you call <code>DescribeContinuousExports</code> as is without passing any parameters.</p>
+    ///
+    /// See `Discovery::describe_continuous_exports`.
+    pub fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_continuous_exports(input))
+    }
+
+    /// <p> <code>DescribeExportConfigurations</code> is deprecated. Use <a href="https://docs.aws.amazon.com/application-discovery/latest/APIReference/API_DescribeExportTasks.html">DescribeImportTasks</a>, instead.</p>
+    ///
+    /// See `Discovery::describe_export_configurations`.
+    pub fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_export_configurations(input))
+    }
+
+    /// <p>Retrieve status of one or more export tasks. You can retrieve the status of up to 100 export tasks.</p>
+    ///
+    /// See `Discovery::describe_export_tasks`.
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// <p>Returns an array of import tasks for your account, including status information, times, IDs, the Amazon S3 Object URL for the import file, and more.</p>
+    ///
+    /// See `Discovery::describe_import_tasks`.
+    pub fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_import_tasks(input))
+    }
+
+    /// <p>Retrieves a list of configuration items that have tags as specified by the key-value pairs, name and value, passed to the optional parameter <code>filters</code>.</p> <p>There are three valid tag filter names:</p> <ul> <li> <p>tagKey</p> </li> <li> <p>tagValue</p> </li> <li> <p>configurationId</p> </li> </ul> <p>Also, all configuration items associated with your user account that have tags can be listed if you call <code>DescribeTags</code> as is without passing any parameters.</p>
+    ///
+    /// See `Discovery::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Disassociates one or more configuration items from an application.</p>
+    ///
+    /// See `Discovery::disassociate_configuration_items_from_application`.
+    pub fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> Result<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        RusotoError<DisassociateConfigurationItemsFromApplicationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_configuration_items_from_application(input),
+        )
+    }
+
+    /// <p>Deprecated. Use <code>StartExportTask</code> instead.</p> <p>Exports all discovered configuration data to an Amazon S3 bucket or an application that enables you to view and evaluate the data. Data includes tags and tag associations, processes, connections, servers, and system performance. This API returns an export ID that you can query using the <i>DescribeExportConfigurations</i> API. The system imposes a limit of two configuration exports in six hours.</p>
+    ///
+    /// See `Discovery::export_configurations`.
+    pub fn export_configurations(
+        &self,
+    ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
+        self.runtime.block_on(self.client.export_configurations())
+    }
+
+    /// <p>Retrieves a short summary of discovered assets.</p> <p>This API operation takes no request parameters and is called as is at the command prompt as shown in the example.</p>
+    ///
+    /// See `Discovery::get_discovery_summary`.
+    pub fn get_discovery_summary(
+        &self,
+    ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
+        self.runtime.block_on(self.client.get_discovery_summary())
+    }
+
+    /// <p>Retrieves a list of configuration items as specified by the value passed to the required parameter <code>configurationType</code>. Optional filtering may be applied to refine search results.</p>
+    ///
+    /// See `Discovery::list_configurations`.
+    pub fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_configurations(input))
+    }
+
+    /// <p>Retrieves a list of servers that are one network hop away from a specified server.</p>
+    ///
+    /// See `Discovery::list_server_neighbors`.
+    pub fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
+        self.runtime
+            .block_on(self.client.list_server_neighbors(input))
+    }
+
+    /// <p>Start the continuous flow of agent's discovered data into Amazon Athena.</p>
+    ///
+    /// See `Discovery::start_continuous_export`.
+    pub fn start_continuous_export(
+        &self,
+    ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
+        self.runtime.block_on(self.client.start_continuous_export())
+    }
+
+    /// <p>Instructs the specified agents or connectors to start collecting data.</p>
+    ///
+    /// See `Discovery::start_data_collection_by_agent_ids`.
+    pub fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> Result<
+        StartDataCollectionByAgentIdsResponse,
+        RusotoError<StartDataCollectionByAgentIdsError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_data_collection_by_agent_ids(input))
+    }
+
+    /// <p> Begins the export of discovered data to an S3 bucket.</p> <p> If you specify <code>agentIds</code> in a filter, the task exports up to 72 hours of detailed data collected by the identified Application Discovery Agent, including network, process, and performance details. A time range for exported agent data may be set by using <code>startTime</code> and <code>endTime</code>. Export of detailed agent data is limited to five concurrently running exports. </p> <p> If you do not include an <code>agentIds</code> filter, summary data is exported that includes both AWS Agentless Discovery Connector data and summary data from AWS Discovery Agents. Export of summary data is limited to two exports per day. </p>
+    ///
+    /// See `Discovery::start_export_task`.
+    pub fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
+        self.runtime.block_on(self.client.start_export_task(input))
+    }
+
+    /// <p><p>Starts an import task, which allows you to import details of your on-premises environment directly into AWS Migration Hub without having to use the Application Discovery Service (ADS) tools such as the Discovery Connector or Discovery Agent. This gives you the option to perform migration assessment and planning directly from your imported data, including the ability to group your devices as applications and track their migration status.</p> <p>To start an import request, do this:</p> <ol> <li> <p>Download the specially formatted comma separated value (CSV) import template, which you can find here: <a href="https://s3-us-west-2.amazonaws.com/templates-7cffcf56-bd96-4b1c-b45b-a5b42f282e46/import_template.csv">https://s3-us-west-2.amazonaws.com/templates-7cffcf56-bd96-4b1c-b45b-a5b42f282e46/import<em>template.csv</a>.</p> </li> <li> <p>Fill out the template with your server and application data.</p> </li> <li> <p>Upload your import file to an Amazon S3 bucket, and make a note of it&#39;s Object URL. Your import file must be in the CSV format.</p> </li> <li> <p>Use the console or the <code>StartImportTask</code> command with the AWS CLI or one of the AWS SDKs to import the records from your file.</p> </li> </ol> <p>For more information, including step-by-step procedures, see <a href="https://docs.aws.amazon.com/application-discovery/latest/userguide/discovery-import.html">Migration Hub Import</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> <note> <p>There are limits to the number of import tasks you can create (and delete) in an AWS account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/application-discovery/latest/userguide/ads</em>service_limits.html&quot;&gt;AWS Application Discovery Service Limits</a> in the <i>AWS Application Discovery Service User Guide</i>.</p> </note></p>
+    ///
+    /// See `Discovery::start_import_task`.
+    pub fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
+        self.runtime.block_on(self.client.start_import_task(input))
+    }
+
+    /// <p>Stop the continuous flow of agent's discovered data into Amazon Athena.</p>
+    ///
+    /// See `Discovery::stop_continuous_export`.
+    pub fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
+        self.runtime
+            .block_on(self.client.stop_continuous_export(input))
+    }
+
+    /// <p>Instructs the specified agents or connectors to stop collecting data.</p>
+    ///
+    /// See `Discovery::stop_data_collection_by_agent_ids`.
+    pub fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_data_collection_by_agent_ids(input))
+    }
+
+    /// <p>Updates metadata about an application.</p>
+    ///
+    /// See `Discovery::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
@@ -2909,9 +2909,10 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(AddTagsToResourceError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2943,7 +2944,8 @@ impl ApplyPendingMaintenanceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ApplyPendingMaintenanceActionError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -2987,32 +2989,40 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateEndpointError::AccessDeniedFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(CreateEndpointError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(CreateEndpointError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::KMSKeyNotAccessibleFault(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceAlreadyExistsFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceQuotaExceededFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3065,52 +3075,62 @@ impl CreateEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::KMSAccessDeniedFault(err.msg),
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSDisabledFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSubscriptionError::KMSDisabledFault(err.msg),
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::KMSInvalidStateFault(err.msg),
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSubscriptionError::KMSNotFoundFault(err.msg),
+                    )
                 }
                 "KMSThrottlingFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSThrottlingFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSubscriptionError::KMSThrottlingFault(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::ResourceNotFoundFault(err.msg),
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
                 "SNSInvalidTopicFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::SNSInvalidTopicFault(err.msg),
                     )
                 }
                 "SNSNoAuthorizationFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
                     )
                 }
@@ -3177,54 +3197,64 @@ impl CreateReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateReplicationInstanceError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationInstanceError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InsufficientResourceCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::InsufficientResourceCapacityFault(err.msg),
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateReplicationInstanceError::InvalidSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationInstanceError::InvalidSubnet(err.msg),
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::KMSKeyNotAccessibleFault(err.msg),
                     )
                 }
                 "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::ResourceNotFoundFault(err.msg),
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
                 "StorageQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationInstanceError::StorageQuotaExceededFault(err.msg),
                     )
                 }
@@ -3293,32 +3323,38 @@ impl CreateReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationSubnetGroupError::AccessDeniedFault(err.msg),
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateReplicationSubnetGroupError::InvalidSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationSubnetGroupError::InvalidSubnet(err.msg),
+                    )
                 }
-                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service(
+                    &res,
                     CreateReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
                         err.msg,
                     ),
                 ),
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationSubnetGroupError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
@@ -3375,32 +3411,38 @@ impl CreateReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateReplicationTaskError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationTaskError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationTaskError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationTaskError::KMSKeyNotAccessibleFault(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationTaskError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(CreateReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationTaskError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationTaskError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
@@ -3447,14 +3489,16 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3489,17 +3533,22 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(DeleteConnectionError::AccessDeniedFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteConnectionError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3533,14 +3582,16 @@ impl DeleteEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteEndpointError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3573,12 +3624,14 @@ impl DeleteEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEventSubscriptionError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEventSubscriptionError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -3617,12 +3670,14 @@ impl DeleteReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteReplicationInstanceError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteReplicationInstanceError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -3663,12 +3718,14 @@ impl DeleteReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteReplicationSubnetGroupError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -3707,14 +3764,16 @@ impl DeleteReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteReplicationTaskError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReplicationTaskError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3769,9 +3828,10 @@ impl DescribeCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeCertificatesError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificatesError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3801,9 +3861,10 @@ impl DescribeConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeConnectionsError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConnectionsError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3855,9 +3916,10 @@ impl DescribeEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeEndpointsError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointsError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3911,7 +3973,8 @@ impl DescribeEventSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEventSubscriptionsError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -3993,7 +4056,8 @@ impl DescribePendingMaintenanceActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePendingMaintenanceActionsError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4031,12 +4095,14 @@ impl DescribeRefreshSchemasStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRefreshSchemasStatusError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRefreshSchemasStatusError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4077,14 +4143,16 @@ impl DescribeReplicationInstanceTaskLogsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReplicationInstanceTaskLogsError::InvalidResourceStateFault(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4123,7 +4191,8 @@ impl DescribeReplicationInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReplicationInstancesError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4159,7 +4228,8 @@ impl DescribeReplicationSubnetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReplicationSubnetGroupsError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4195,7 +4265,8 @@ impl DescribeReplicationTaskAssessmentResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReplicationTaskAssessmentResultsError::ResourceNotFoundFault(
                             err.msg,
                         ),
@@ -4231,7 +4302,8 @@ impl DescribeReplicationTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReplicationTasksError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4267,14 +4339,16 @@ impl DescribeSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DescribeSchemasError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSchemasError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeSchemasError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSchemasError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4307,12 +4381,14 @@ impl DescribeTableStatisticsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTableStatisticsError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTableStatisticsError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4353,17 +4429,20 @@ impl ImportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidCertificateFault" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidCertificateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportCertificateError::InvalidCertificateFault(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportCertificateError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
@@ -4397,9 +4476,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4437,27 +4517,34 @@ impl ModifyEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(ModifyEndpointError::AccessDeniedFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEndpointError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(ModifyEndpointError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEndpointError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(ModifyEndpointError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEndpointError::KMSKeyNotAccessibleFault(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(ModifyEndpointError::ResourceAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEndpointError::ResourceAlreadyExistsFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ModifyEndpointError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEndpointError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4507,47 +4594,56 @@ impl ModifyEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyEventSubscriptionError::KMSAccessDeniedFault(err.msg),
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSDisabledFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEventSubscriptionError::KMSDisabledFault(err.msg),
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyEventSubscriptionError::KMSInvalidStateFault(err.msg),
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEventSubscriptionError::KMSNotFoundFault(err.msg),
+                    )
                 }
                 "KMSThrottlingFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSThrottlingFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyEventSubscriptionError::KMSThrottlingFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyEventSubscriptionError::ResourceNotFoundFault(err.msg),
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyEventSubscriptionError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
                 "SNSInvalidTopicFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyEventSubscriptionError::SNSInvalidTopicFault(err.msg),
                     )
                 }
                 "SNSNoAuthorizationFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
                     )
                 }
@@ -4605,37 +4701,44 @@ impl ModifyReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(ModifyReplicationInstanceError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyReplicationInstanceError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InsufficientResourceCapacityFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationInstanceError::InsufficientResourceCapacityFault(err.msg),
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationInstanceError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationInstanceError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationInstanceError::ResourceNotFoundFault(err.msg),
                     )
                 }
                 "StorageQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationInstanceError::StorageQuotaExceededFault(err.msg),
                     )
                 }
                 "UpgradeDependencyFailureFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationInstanceError::UpgradeDependencyFailureFault(err.msg),
                     )
                 }
@@ -4697,32 +4800,38 @@ impl ModifyReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationSubnetGroupError::AccessDeniedFault(err.msg),
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(ModifyReplicationSubnetGroupError::InvalidSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyReplicationSubnetGroupError::InvalidSubnet(err.msg),
+                    )
                 }
-                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service(
+                    &res,
                     ModifyReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
                         err.msg,
                     ),
                 ),
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
                     )
                 }
                 "SubnetAlreadyInUse" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationSubnetGroupError::SubnetAlreadyInUse(err.msg),
                     )
                 }
@@ -4775,24 +4884,28 @@ impl ModifyReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationTaskError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationTaskError::KMSKeyNotAccessibleFault(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyReplicationTaskError::ResourceAlreadyExistsFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ModifyReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyReplicationTaskError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4833,12 +4946,14 @@ impl RebootReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RebootReplicationInstanceError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RebootReplicationInstanceError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -4881,24 +4996,28 @@ impl RefreshSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(RefreshSchemasError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RefreshSchemasError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(RefreshSchemasError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RefreshSchemasError::KMSKeyNotAccessibleFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(RefreshSchemasError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RefreshSchemasError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(RefreshSchemasError::ResourceQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RefreshSchemasError::ResourceQuotaExceededFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4933,12 +5052,16 @@ impl ReloadTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(ReloadTablesError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ReloadTablesError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ReloadTablesError::ResourceNotFoundFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReloadTablesError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4969,7 +5092,8 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromResourceError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -5005,19 +5129,22 @@ impl StartReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(StartReplicationTaskError::AccessDeniedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartReplicationTaskError::AccessDeniedFault(err.msg),
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartReplicationTaskError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(StartReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartReplicationTaskError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5055,12 +5182,14 @@ impl StartReplicationTaskAssessmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartReplicationTaskAssessmentError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartReplicationTaskAssessmentError::ResourceNotFoundFault(err.msg),
                     )
                 }
@@ -5099,14 +5228,16 @@ impl StopReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopReplicationTaskError::InvalidResourceStateFault(err.msg),
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(StopReplicationTaskError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopReplicationTaskError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5145,24 +5276,28 @@ impl TestConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(TestConnectionError::InvalidResourceStateFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestConnectionError::InvalidResourceStateFault(err.msg),
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(TestConnectionError::KMSKeyNotAccessibleFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestConnectionError::KMSKeyNotAccessibleFault(err.msg),
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(TestConnectionError::ResourceNotFoundFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestConnectionError::ResourceNotFoundFault(err.msg),
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(TestConnectionError::ResourceQuotaExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestConnectionError::ResourceQuotaExceededFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5506,6 +5641,7 @@ impl DatabaseMigrationServiceClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5521,6 +5657,7 @@ impl DatabaseMigrationServiceClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(
         client: Client,
         region: region::Region,
@@ -5537,6 +5674,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: AddTagsToResourceMessage,
     ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5558,6 +5698,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<ApplyPendingMaintenanceActionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ApplyPendingMaintenanceAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.ApplyPendingMaintenanceAction",
@@ -5580,6 +5723,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateEndpointMessage,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5598,6 +5744,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5618,6 +5767,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateReplicationInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.CreateReplicationInstance",
@@ -5641,6 +5793,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateReplicationSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.CreateReplicationSubnetGroup",
@@ -5663,6 +5818,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateReplicationTaskMessage,
     ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateReplicationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5682,6 +5840,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteCertificateMessage,
     ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5700,6 +5861,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteConnectionMessage,
     ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5718,6 +5882,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteEndpointMessage,
     ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5736,6 +5903,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5756,6 +5926,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReplicationInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DeleteReplicationInstance",
@@ -5779,6 +5952,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReplicationSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DeleteReplicationSubnetGroup",
@@ -5801,6 +5977,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteReplicationTaskMessage,
     ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReplicationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5820,6 +5999,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAccountAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeAccountAttributes",
@@ -5841,6 +6023,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeCertificatesMessage,
     ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5860,6 +6045,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeConnectionsMessage,
     ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5878,6 +6066,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEndpointTypesMessage,
     ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpointTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpointTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5897,6 +6088,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEndpointsMessage,
     ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5915,6 +6109,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventCategories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEventCategories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5935,6 +6132,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeEventSubscriptions",
@@ -5957,6 +6157,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEventsMessage,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5978,6 +6181,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeOrderableReplicationInstancesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrderableReplicationInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeOrderableReplicationInstances",
@@ -6006,6 +6212,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribePendingMaintenanceActionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePendingMaintenanceActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribePendingMaintenanceActions",
@@ -6032,6 +6241,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRefreshSchemasStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeRefreshSchemasStatus",
@@ -6057,6 +6269,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationInstanceTaskLogsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReplicationInstanceTaskLogs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationInstanceTaskLogs",
@@ -6083,6 +6298,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReplicationInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationInstances",
@@ -6108,6 +6326,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationSubnetGroupsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReplicationSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationSubnetGroups",
@@ -6133,6 +6354,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationTaskAssessmentResultsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReplicationTaskAssessmentResults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationTaskAssessmentResults",
@@ -6158,6 +6382,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeReplicationTasksMessage,
     ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReplicationTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationTasks",
@@ -6180,6 +6407,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeSchemasMessage,
     ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSchemas");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6198,6 +6428,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeTableStatisticsMessage,
     ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTableStatistics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeTableStatistics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6217,6 +6450,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ImportCertificateMessage,
     ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.ImportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6235,6 +6471,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ListTagsForResourceMessage,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonDMSv20160101.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6253,6 +6492,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyEndpointMessage,
     ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6271,6 +6513,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6291,6 +6536,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyReplicationInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.ModifyReplicationInstance",
@@ -6314,6 +6562,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyReplicationSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.ModifyReplicationSubnetGroup",
@@ -6336,6 +6587,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyReplicationTaskMessage,
     ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyReplicationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6356,6 +6610,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebootReplicationInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.RebootReplicationInstance",
@@ -6378,6 +6635,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: RefreshSchemasMessage,
     ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RefreshSchemas");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.RefreshSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6396,6 +6656,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ReloadTablesMessage,
     ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ReloadTables");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.ReloadTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6414,6 +6677,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.RemoveTagsFromResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6433,6 +6699,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: StartReplicationTaskMessage,
     ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartReplicationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.StartReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6455,6 +6724,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<StartReplicationTaskAssessmentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartReplicationTaskAssessment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.StartReplicationTaskAssessment",
@@ -6477,6 +6749,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: StopReplicationTaskMessage,
     ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopReplicationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.StopReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6495,6 +6770,9 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: TestConnectionMessage,
     ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TestConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonDMSv20160101.TestConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6507,3 +6785,598 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         proto::json::ResponsePayload::new(&response).deserialize::<TestConnectionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Database Migration Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DatabaseMigrationServiceClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DatabaseMigrationServiceBlockingClient {
+    client: DatabaseMigrationServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DatabaseMigrationServiceBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DatabaseMigrationServiceBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DatabaseMigrationServiceBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DatabaseMigrationServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DatabaseMigrationServiceBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient {
+            client: DatabaseMigrationServiceClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds metadata tags to an AWS DMS resource, including replication instance, endpoint, security group, and migration task. These tags can also be used with cost allocation reporting to track cost associated with DMS resources, or used in a Condition statement in an IAM policy for DMS.</p>
+    ///
+    /// See `DatabaseMigrationService::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a replication instance).</p>
+    ///
+    /// See `DatabaseMigrationService::apply_pending_maintenance_action`.
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<
+        ApplyPendingMaintenanceActionResponse,
+        RusotoError<ApplyPendingMaintenanceActionError>,
+    > {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// <p>Creates an endpoint using the provided settings.</p>
+    ///
+    /// See `DatabaseMigrationService::create_endpoint`.
+    pub fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        self.runtime.block_on(self.client.create_endpoint(input))
+    }
+
+    /// <p> Creates an AWS DMS event notification subscription. </p> <p>You can specify the type of source (<code>SourceType</code>) you want to be notified of, provide a list of AWS DMS source IDs (<code>SourceIds</code>) that triggers the events, and provide a list of event categories (<code>EventCategories</code>) for events you want to be notified of. If you specify both the <code>SourceType</code> and <code>SourceIds</code>, such as <code>SourceType = replication-instance</code> and <code>SourceIdentifier = my-replinstance</code>, you will be notified of all the replication instance events for the specified source. If you specify a <code>SourceType</code> but don't specify a <code>SourceIdentifier</code>, you receive notice of the events for that source type for all your AWS DMS sources. If you don't specify either <code>SourceType</code> nor <code>SourceIdentifier</code>, you will be notified of events generated from all AWS DMS sources belonging to your customer account.</p> <p>For more information about AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    ///
+    /// See `DatabaseMigrationService::create_event_subscription`.
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// <p>Creates the replication instance using the specified parameters.</p> <p>AWS DMS requires that your account have certain roles with appropriate permissions before you can create a replication instance. For information on the required roles, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Security.APIRole.html">Creating the IAM Roles to Use With the AWS CLI and AWS DMS API</a>. For information on the required permissions, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Security.IAMPermissions.html">IAM Permissions Needed to Use AWS DMS</a>.</p>
+    ///
+    /// See `DatabaseMigrationService::create_replication_instance`.
+    pub fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_replication_instance(input))
+    }
+
+    /// <p>Creates a replication subnet group given a list of the subnet IDs in a VPC.</p>
+    ///
+    /// See `DatabaseMigrationService::create_replication_subnet_group`.
+    pub fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_replication_subnet_group(input))
+    }
+
+    /// <p>Creates a replication task using the specified parameters.</p>
+    ///
+    /// See `DatabaseMigrationService::create_replication_task`.
+    pub fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.create_replication_task(input))
+    }
+
+    /// <p>Deletes the specified certificate. </p>
+    ///
+    /// See `DatabaseMigrationService::delete_certificate`.
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// <p>Deletes the connection between a replication instance and an endpoint.</p>
+    ///
+    /// See `DatabaseMigrationService::delete_connection`.
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionMessage,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// <p><p>Deletes the specified endpoint.</p> <note> <p>All tasks associated with the endpoint must be deleted before you can delete the endpoint.</p> </note> <p/></p>
+    ///
+    /// See `DatabaseMigrationService::delete_endpoint`.
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// <p> Deletes an AWS DMS event subscription. </p>
+    ///
+    /// See `DatabaseMigrationService::delete_event_subscription`.
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// <p><p>Deletes the specified replication instance.</p> <note> <p>You must delete any migration tasks that are associated with the replication instance before you can delete it.</p> </note> <p/></p>
+    ///
+    /// See `DatabaseMigrationService::delete_replication_instance`.
+    pub fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_replication_instance(input))
+    }
+
+    /// <p>Deletes a subnet group.</p>
+    ///
+    /// See `DatabaseMigrationService::delete_replication_subnet_group`.
+    pub fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_replication_subnet_group(input))
+    }
+
+    /// <p>Deletes the specified replication task.</p>
+    ///
+    /// See `DatabaseMigrationService::delete_replication_task`.
+    pub fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.delete_replication_task(input))
+    }
+
+    /// <p>Lists all of the AWS DMS attributes for a customer account. These attributes include AWS DMS quotas for the account and a unique account identifier in a particular DMS region. DMS quotas include a list of resource quotas supported by the account, such as the number of replication instances allowed. The description for each resource quota, includes the quota name, current usage toward that quota, and the quota's maximum value. DMS uses the unique account identifier to name each artifact used by DMS in the given region.</p> <p>This command does not take any parameters.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_account_attributes`.
+    pub fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_attributes())
+    }
+
+    /// <p>Provides a description of the certificate.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_certificates`.
+    pub fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_certificates(input))
+    }
+
+    /// <p>Describes the status of the connections that have been made between the replication instance and an endpoint. Connections are created when you test an endpoint.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_connections`.
+    pub fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_connections(input))
+    }
+
+    /// <p>Returns information about the type of endpoints available.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_endpoint_types`.
+    pub fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_endpoint_types(input))
+    }
+
+    /// <p>Returns information about the endpoints for your account in the current region.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_endpoints`.
+    pub fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.runtime.block_on(self.client.describe_endpoints(input))
+    }
+
+    /// <p>Lists categories for all event source types, or, if specified, for a specified source type. You can see a list of the event categories and source types in <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    ///
+    /// See `DatabaseMigrationService::describe_event_categories`.
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// <p>Lists all the event subscriptions for a customer account. The description of a subscription includes <code>SubscriptionName</code>, <code>SNSTopicARN</code>, <code>CustomerID</code>, <code>SourceType</code>, <code>SourceID</code>, <code>CreationTime</code>, and <code>Status</code>. </p> <p>If you specify <code>SubscriptionName</code>, this action lists the description for that subscription.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_event_subscriptions`.
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// <p> Lists events for a given source identifier and source type. You can also specify a start and end time. For more information on AWS DMS events, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Events.html">Working with Events and Notifications</a> in the <i>AWS Database Migration User Guide.</i> </p>
+    ///
+    /// See `DatabaseMigrationService::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns information about the replication instance types that can be created in the specified region.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_orderable_replication_instances`.
+    pub fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> Result<
+        DescribeOrderableReplicationInstancesResponse,
+        RusotoError<DescribeOrderableReplicationInstancesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_replication_instances(input))
+    }
+
+    /// <p>For internal use only</p>
+    ///
+    /// See `DatabaseMigrationService::describe_pending_maintenance_actions`.
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<
+        DescribePendingMaintenanceActionsResponse,
+        RusotoError<DescribePendingMaintenanceActionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// <p>Returns the status of the RefreshSchemas operation.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_refresh_schemas_status`.
+    pub fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_refresh_schemas_status(input))
+    }
+
+    /// <p>Returns information about the task logs for the specified task.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_replication_instance_task_logs`.
+    pub fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> Result<
+        DescribeReplicationInstanceTaskLogsResponse,
+        RusotoError<DescribeReplicationInstanceTaskLogsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_replication_instance_task_logs(input))
+    }
+
+    /// <p>Returns information about replication instances for your account in the current region.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_replication_instances`.
+    pub fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_replication_instances(input))
+    }
+
+    /// <p>Returns information about the replication subnet groups.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_replication_subnet_groups`.
+    pub fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> Result<
+        DescribeReplicationSubnetGroupsResponse,
+        RusotoError<DescribeReplicationSubnetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_replication_subnet_groups(input))
+    }
+
+    /// <p>Returns the task assessment results from Amazon S3. This action always returns the latest results.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_replication_task_assessment_results`.
+    pub fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> Result<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_replication_task_assessment_results(input),
+        )
+    }
+
+    /// <p>Returns information about replication tasks for your account in the current region.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_replication_tasks`.
+    pub fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_replication_tasks(input))
+    }
+
+    /// <p><p>Returns information about the schema for the specified endpoint.</p> <p/></p>
+    ///
+    /// See `DatabaseMigrationService::describe_schemas`.
+    pub fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
+        self.runtime.block_on(self.client.describe_schemas(input))
+    }
+
+    /// <p>Returns table statistics on the database migration task, including table name, rows inserted, rows updated, and rows deleted.</p> <p>Note that the "last updated" column the DMS console only indicates the time that AWS DMS last updated the table statistics record for a table. It does not indicate the time of the last update to the table.</p>
+    ///
+    /// See `DatabaseMigrationService::describe_table_statistics`.
+    pub fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
+        self.runtime
+            .block_on(self.client.describe_table_statistics(input))
+    }
+
+    /// <p>Uploads the specified certificate.</p>
+    ///
+    /// See `DatabaseMigrationService::import_certificate`.
+    pub fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+        self.runtime.block_on(self.client.import_certificate(input))
+    }
+
+    /// <p>Lists all tags for an AWS DMS resource.</p>
+    ///
+    /// See `DatabaseMigrationService::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Modifies the specified endpoint.</p>
+    ///
+    /// See `DatabaseMigrationService::modify_endpoint`.
+    pub fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
+        self.runtime.block_on(self.client.modify_endpoint(input))
+    }
+
+    /// <p>Modifies an existing AWS DMS event notification subscription. </p>
+    ///
+    /// See `DatabaseMigrationService::modify_event_subscription`.
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// <p><p>Modifies the replication instance to apply new settings. You can change one or more parameters by specifying these parameters and the new values in the request.</p> <p>Some settings are applied during the maintenance window.</p> <p/></p>
+    ///
+    /// See `DatabaseMigrationService::modify_replication_instance`.
+    pub fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_replication_instance(input))
+    }
+
+    /// <p>Modifies the settings for the specified replication subnet group.</p>
+    ///
+    /// See `DatabaseMigrationService::modify_replication_subnet_group`.
+    pub fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_replication_subnet_group(input))
+    }
+
+    /// <p>Modifies the specified replication task.</p> <p>You can't modify the task endpoints. The task must be stopped before you can modify it. </p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks</a> in the <i>AWS Database Migration Service User Guide</i>.</p>
+    ///
+    /// See `DatabaseMigrationService::modify_replication_task`.
+    pub fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.modify_replication_task(input))
+    }
+
+    /// <p>Reboots a replication instance. Rebooting results in a momentary outage, until the replication instance becomes available again.</p>
+    ///
+    /// See `DatabaseMigrationService::reboot_replication_instance`.
+    pub fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.reboot_replication_instance(input))
+    }
+
+    /// <p>Populates the schema for the specified endpoint. This is an asynchronous operation and can take several minutes. You can check the status of this operation by calling the DescribeRefreshSchemasStatus operation.</p>
+    ///
+    /// See `DatabaseMigrationService::refresh_schemas`.
+    pub fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
+        self.runtime.block_on(self.client.refresh_schemas(input))
+    }
+
+    /// <p>Reloads the target database table with the source data. </p>
+    ///
+    /// See `DatabaseMigrationService::reload_tables`.
+    pub fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
+        self.runtime.block_on(self.client.reload_tables(input))
+    }
+
+    /// <p>Removes metadata tags from a DMS resource.</p>
+    ///
+    /// See `DatabaseMigrationService::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p>Starts the replication task.</p> <p>For more information about AWS DMS tasks, see <a href="https://docs.aws.amazon.com/dms/latest/userguide/CHAP_Tasks.html">Working with Migration Tasks </a> in the <i>AWS Database Migration Service User Guide.</i> </p>
+    ///
+    /// See `DatabaseMigrationService::start_replication_task`.
+    pub fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.start_replication_task(input))
+    }
+
+    /// <p> Starts the replication task assessment for unsupported data types in the source database. </p>
+    ///
+    /// See `DatabaseMigrationService::start_replication_task_assessment`.
+    pub fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> Result<
+        StartReplicationTaskAssessmentResponse,
+        RusotoError<StartReplicationTaskAssessmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_replication_task_assessment(input))
+    }
+
+    /// <p><p>Stops the replication task.</p> <p/></p>
+    ///
+    /// See `DatabaseMigrationService::stop_replication_task`.
+    pub fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.stop_replication_task(input))
+    }
+
+    /// <p>Tests the connection between the replication instance and the endpoint.</p>
+    ///
+    /// See `DatabaseMigrationService::test_connection`.
+    pub fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
+        self.runtime.block_on(self.client.test_connection(input))
+    }
+}
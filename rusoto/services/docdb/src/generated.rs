@@ -5839,17 +5839,20 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -5901,21 +5904,24 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -5975,21 +5981,24 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -6053,42 +6062,48 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -6179,85 +6194,100 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DBInstanceNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -6327,14 +6357,16 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -6393,35 +6425,40 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -6509,89 +6546,104 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -6671,37 +6723,42 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6763,31 +6820,36 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -6843,14 +6905,16 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -6903,14 +6967,16 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
@@ -6969,31 +7035,36 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -7047,21 +7118,24 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
                                 parsed_error.message,
                             ),
@@ -7115,7 +7189,8 @@ impl DescribeCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCertificatesError::CertificateNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -7165,7 +7240,8 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -7215,7 +7291,8 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -7264,7 +7341,8 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service(
+                        &res,
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
                             parsed_error.message,
                         ),
@@ -7313,7 +7391,8 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -7361,7 +7440,8 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
@@ -7439,7 +7519,8 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
@@ -7483,7 +7564,8 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -7673,7 +7755,8 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -7725,19 +7808,22 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -7791,17 +7877,20 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -7867,63 +7956,74 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -7987,14 +8087,16 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -8051,19 +8153,22 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service(
+                        &res,
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
                             parsed_error.message,
                         ),
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -8141,83 +8246,96 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -8297,35 +8415,40 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8379,12 +8502,14 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -8435,21 +8560,24 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -8507,14 +8635,16 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -8593,94 +8723,108 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -8795,101 +8939,116 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -8981,17 +9140,20 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
@@ -9041,17 +9203,20 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
@@ -9366,6 +9531,7 @@ impl DocdbClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -9381,6 +9547,7 @@ impl DocdbClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DocdbClient {
         DocdbClient { client, region }
     }
@@ -9394,6 +9561,9 @@ impl Docdb for DocdbClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -9415,6 +9585,9 @@ impl Docdb for DocdbClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ApplyPendingMaintenanceAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ApplyPendingMaintenanceAction");
         let mut params = params;
         ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
@@ -9449,6 +9622,9 @@ impl Docdb for DocdbClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBClusterParameterGroup");
         let mut params = params;
         CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9482,6 +9658,9 @@ impl Docdb for DocdbClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBClusterSnapshot");
         let mut params = params;
         CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -9515,6 +9694,9 @@ impl Docdb for DocdbClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBCluster");
         let mut params = params;
         CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -9547,6 +9729,9 @@ impl Docdb for DocdbClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterParameterGroup");
         let mut params = params;
         CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9580,6 +9765,9 @@ impl Docdb for DocdbClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterSnapshot");
         let mut params = params;
         CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -9613,6 +9801,9 @@ impl Docdb for DocdbClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBInstance");
         let mut params = params;
         CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -9644,6 +9835,9 @@ impl Docdb for DocdbClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBSubnetGroup");
         let mut params = params;
         CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9677,6 +9871,9 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBCluster");
         let mut params = params;
         DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -9708,6 +9905,9 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterParameterGroup");
         let mut params = params;
         DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9728,6 +9928,9 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterSnapshot");
         let mut params = params;
         DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -9761,6 +9964,9 @@ impl Docdb for DocdbClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBInstance");
         let mut params = params;
         DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -9792,6 +9998,9 @@ impl Docdb for DocdbClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBSubnetGroup");
         let mut params = params;
         DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9812,6 +10021,9 @@ impl Docdb for DocdbClient {
         input: DescribeCertificatesMessage,
     ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCertificates");
         let mut params = params;
         DescribeCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -9844,6 +10056,9 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterParameterGroups");
         let mut params = params;
         DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -9880,6 +10095,9 @@ impl Docdb for DocdbClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterParameters");
         let mut params = params;
         DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -9916,6 +10134,9 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterSnapshotAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterSnapshotAttributes");
         let mut params = params;
         DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -9952,6 +10173,9 @@ impl Docdb for DocdbClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterSnapshots");
         let mut params = params;
         DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -9985,6 +10209,9 @@ impl Docdb for DocdbClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusters");
         let mut params = params;
         DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -10016,6 +10243,9 @@ impl Docdb for DocdbClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBEngineVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBEngineVersions");
         let mut params = params;
         DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10049,6 +10279,9 @@ impl Docdb for DocdbClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBInstances");
         let mut params = params;
         DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -10080,6 +10313,9 @@ impl Docdb for DocdbClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBSubnetGroups");
         let mut params = params;
         DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -10116,6 +10352,9 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEngineDefaultClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEngineDefaultClusterParameters");
         let mut params = params;
         DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -10152,6 +10391,9 @@ impl Docdb for DocdbClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEventCategories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -10185,6 +10427,9 @@ impl Docdb for DocdbClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -10218,6 +10463,9 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeOrderableDBInstanceOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeOrderableDBInstanceOptions");
         let mut params = params;
         DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10255,6 +10503,9 @@ impl Docdb for DocdbClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribePendingMaintenanceActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePendingMaintenanceActions");
         let mut params = params;
         DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10291,6 +10542,9 @@ impl Docdb for DocdbClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("FailoverDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("FailoverDBCluster");
         let mut params = params;
         FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10322,6 +10576,9 @@ impl Docdb for DocdbClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -10353,6 +10610,9 @@ impl Docdb for DocdbClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBCluster");
         let mut params = params;
         ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10385,6 +10645,9 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterParameterGroup");
         let mut params = params;
         ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -10421,6 +10684,9 @@ impl Docdb for DocdbClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterSnapshotAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterSnapshotAttribute");
         let mut params = params;
         ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -10457,6 +10723,9 @@ impl Docdb for DocdbClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBInstance");
         let mut params = params;
         ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -10488,6 +10757,9 @@ impl Docdb for DocdbClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBSubnetGroup");
         let mut params = params;
         ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -10521,6 +10793,9 @@ impl Docdb for DocdbClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RebootDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebootDBInstance");
         let mut params = params;
         RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -10552,6 +10827,9 @@ impl Docdb for DocdbClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -10573,6 +10851,9 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ResetDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetDBClusterParameterGroup");
         let mut params = params;
         ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -10607,6 +10888,9 @@ impl Docdb for DocdbClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterFromSnapshot");
         let mut params = params;
         RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -10641,6 +10925,9 @@ impl Docdb for DocdbClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterToPointInTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterToPointInTime");
         let mut params = params;
         RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -10674,6 +10961,9 @@ impl Docdb for DocdbClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StartDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartDBCluster");
         let mut params = params;
         StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10705,6 +10995,9 @@ impl Docdb for DocdbClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StopDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopDBCluster");
         let mut params = params;
         StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10730,3 +11023,528 @@ impl Docdb for DocdbClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon DocDB API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DocdbClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DocdbBlockingClient {
+    client: DocdbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DocdbBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DocdbBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DocdbBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DocdbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DocdbBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DocdbBlockingClient {
+        DocdbBlockingClient {
+            client: DocdbClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds metadata tags to an Amazon DocumentDB resource. You can use these tags with cost allocation reporting to track costs that are associated with Amazon DocumentDB resources. or in a <code>Condition</code> statement in an AWS Identity and Access Management (IAM) policy for Amazon DocumentDB.</p>
+    ///
+    /// See `Docdb::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to an Amazon DocumentDB instance).</p>
+    ///
+    /// See `Docdb::apply_pending_maintenance_action`.
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// <p>Copies the specified cluster parameter group.</p>
+    ///
+    /// See `Docdb::copy_db_cluster_parameter_group`.
+    pub fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Copies a snapshot of a cluster.</p> <p>To copy a cluster snapshot from a shared manual cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared cluster snapshot. You can only copy a shared DB cluster snapshot, whether encrypted or not, in the same AWS Region.</p> <p>To cancel the copy operation after it is in progress, delete the target cluster snapshot identified by <code>TargetDBClusterSnapshotIdentifier</code> while that cluster snapshot is in the <i>copying</i> status.</p>
+    ///
+    /// See `Docdb::copy_db_cluster_snapshot`.
+    pub fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new Amazon DocumentDB cluster.</p>
+    ///
+    /// See `Docdb::create_db_cluster`.
+    pub fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.runtime.block_on(self.client.create_db_cluster(input))
+    }
+
+    /// <p>Creates a new cluster parameter group.</p> <p>Parameters in a cluster parameter group apply to all of the instances in a cluster.</p> <p>A cluster parameter group is initially created with the default parameters for the database engine used by instances in the cluster. In Amazon DocumentDB, you cannot make modifications directly to the <code>default.docdb3.6</code> cluster parameter group. If your Amazon DocumentDB cluster is using the default cluster parameter group and you want to modify a value in it, you must first <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/cluster_parameter_group-create.html"> create a new parameter group</a> or <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/cluster_parameter_group-copy.html"> copy an existing parameter group</a>, modify it, and then apply the modified parameter group to your cluster. For the new cluster parameter group and associated settings to take effect, you must then reboot the instances in the cluster without failover. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/cluster_parameter_group-modify.html"> Modifying Amazon DocumentDB Cluster Parameter Groups</a>. </p>
+    ///
+    /// See `Docdb::create_db_cluster_parameter_group`.
+    pub fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Creates a snapshot of a cluster. </p>
+    ///
+    /// See `Docdb::create_db_cluster_snapshot`.
+    pub fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new instance.</p>
+    ///
+    /// See `Docdb::create_db_instance`.
+    pub fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.runtime.block_on(self.client.create_db_instance(input))
+    }
+
+    /// <p>Creates a new subnet group. subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
+    ///
+    /// See `Docdb::create_db_subnet_group`.
+    pub fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_subnet_group(input))
+    }
+
+    /// <p><p>Deletes a previously provisioned cluster. When you delete a cluster, all automated backups for that cluster are deleted and can&#39;t be recovered. Manual DB cluster snapshots of the specified cluster are not deleted.</p> <p/></p>
+    ///
+    /// See `Docdb::delete_db_cluster`.
+    pub fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.runtime.block_on(self.client.delete_db_cluster(input))
+    }
+
+    /// <p>Deletes a specified cluster parameter group. The cluster parameter group to be deleted can't be associated with any clusters.</p>
+    ///
+    /// See `Docdb::delete_db_cluster_parameter_group`.
+    pub fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Deletes a cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
+    ///
+    /// See `Docdb::delete_db_cluster_snapshot`.
+    pub fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_snapshot(input))
+    }
+
+    /// <p>Deletes a previously provisioned instance. </p>
+    ///
+    /// See `Docdb::delete_db_instance`.
+    pub fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.runtime.block_on(self.client.delete_db_instance(input))
+    }
+
+    /// <p><p>Deletes a subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
+    ///
+    /// See `Docdb::delete_db_subnet_group`.
+    pub fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_subnet_group(input))
+    }
+
+    /// <p>Returns a list of certificate authority (CA) certificates provided by Amazon DocumentDB for this AWS account.</p>
+    ///
+    /// See `Docdb::describe_certificates`.
+    pub fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_certificates(input))
+    }
+
+    /// <p>Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list contains only the description of the specified cluster parameter group. </p>
+    ///
+    /// See `Docdb::describe_db_cluster_parameter_groups`.
+    pub fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameter_groups(input))
+    }
+
+    /// <p>Returns the detailed parameter list for a particular cluster parameter group.</p>
+    ///
+    /// See `Docdb::describe_db_cluster_parameters`.
+    pub fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameters(input))
+    }
+
+    /// <p>Returns a list of cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When you share snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual cluster snapshot is public and can be copied or restored by all AWS accounts.</p>
+    ///
+    /// See `Docdb::describe_db_cluster_snapshot_attributes`.
+    pub fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshot_attributes(input))
+    }
+
+    /// <p>Returns information about cluster snapshots. This API operation supports pagination.</p>
+    ///
+    /// See `Docdb::describe_db_cluster_snapshots`.
+    pub fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshots(input))
+    }
+
+    /// <p>Returns information about provisioned Amazon DocumentDB clusters. This API operation supports pagination. For certain management features such as cluster and instance lifecycle management, Amazon DocumentDB leverages operational technology that is shared with Amazon RDS and Amazon Neptune. Use the <code>filterName=engine,Values=docdb</code> filter parameter to return only Amazon DocumentDB clusters.</p>
+    ///
+    /// See `Docdb::describe_db_clusters`.
+    pub fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_clusters(input))
+    }
+
+    /// <p>Returns a list of the available engines.</p>
+    ///
+    /// See `Docdb::describe_db_engine_versions`.
+    pub fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_engine_versions(input))
+    }
+
+    /// <p>Returns information about provisioned Amazon DocumentDB instances. This API supports pagination.</p>
+    ///
+    /// See `Docdb::describe_db_instances`.
+    pub fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_instances(input))
+    }
+
+    /// <p>Returns a list of <code>DBSubnetGroup</code> descriptions. If a <code>DBSubnetGroupName</code> is specified, the list will contain only the descriptions of the specified <code>DBSubnetGroup</code>.</p>
+    ///
+    /// See `Docdb::describe_db_subnet_groups`.
+    pub fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_subnet_groups(input))
+    }
+
+    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
+    ///
+    /// See `Docdb::describe_engine_default_cluster_parameters`.
+    pub fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_engine_default_cluster_parameters(input),
+        )
+    }
+
+    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type. </p>
+    ///
+    /// See `Docdb::describe_event_categories`.
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// <p>Returns events related to instances, security groups, snapshots, and DB parameter groups for the past 14 days. You can obtain events specific to a particular DB instance, security group, snapshot, or parameter group by providing the name as a parameter. By default, the events of the past hour are returned.</p>
+    ///
+    /// See `Docdb::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns a list of orderable instance options for the specified engine.</p>
+    ///
+    /// See `Docdb::describe_orderable_db_instance_options`.
+    pub fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_db_instance_options(input))
+    }
+
+    /// <p>Returns a list of resources (for example, instances) that have at least one pending maintenance action.</p>
+    ///
+    /// See `Docdb::describe_pending_maintenance_actions`.
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// <p>Forces a failover for a cluster.</p> <p>A failover for a cluster promotes one of the Amazon DocumentDB replicas (read-only instances) in the cluster to be the primary instance (the cluster writer).</p> <p>If the primary instance fails, Amazon DocumentDB automatically fails over to an Amazon DocumentDB replica, if one exists. You can force a failover when you want to simulate a failure of a primary instance for testing.</p>
+    ///
+    /// See `Docdb::failover_db_cluster`.
+    pub fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.runtime
+            .block_on(self.client.failover_db_cluster(input))
+    }
+
+    /// <p>Lists all tags on an Amazon DocumentDB resource.</p>
+    ///
+    /// See `Docdb::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Modifies a setting for an Amazon DocumentDB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. </p>
+    ///
+    /// See `Docdb::modify_db_cluster`.
+    pub fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.runtime.block_on(self.client.modify_db_cluster(input))
+    }
+
+    /// <p><p> Modifies the parameters of a cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot or maintenance window before the change can take effect.</p> </note> <important> <p>After you create a cluster parameter group, you should wait at least 5 minutes before creating your first cluster that uses that cluster parameter group as the default parameter group. This allows Amazon DocumentDB to fully complete the create action before the parameter group is used as the default for a new cluster. This step is especially important for parameters that are critical when creating the default database for a cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter.</p> </important></p>
+    ///
+    /// See `Docdb::modify_db_cluster_parameter_group`.
+    pub fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code>, and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual cluster snapshot. Use the value <code>all</code> to make the manual cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p>
+    ///
+    /// See `Docdb::modify_db_cluster_snapshot_attribute`.
+    pub fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_snapshot_attribute(input))
+    }
+
+    /// <p>Modifies settings for an instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
+    ///
+    /// See `Docdb::modify_db_instance`.
+    pub fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.runtime.block_on(self.client.modify_db_instance(input))
+    }
+
+    /// <p>Modifies an existing subnet group. subnet groups must contain at least one subnet in at least two Availability Zones in the AWS Region.</p>
+    ///
+    /// See `Docdb::modify_db_subnet_group`.
+    pub fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_subnet_group(input))
+    }
+
+    /// <p>You might need to reboot your instance, usually for maintenance reasons. For example, if you make certain changes, or if you change the cluster parameter group that is associated with the instance, you must reboot the instance for the changes to take effect. </p> <p>Rebooting an instance restarts the database engine service. Rebooting an instance results in a momentary outage, during which the instance status is set to <i>rebooting</i>. </p>
+    ///
+    /// See `Docdb::reboot_db_instance`.
+    pub fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.runtime.block_on(self.client.reboot_db_instance(input))
+    }
+
+    /// <p>Removes metadata tags from an Amazon DocumentDB resource.</p>
+    ///
+    /// See `Docdb::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p> Modifies the parameters of a cluster parameter group to the default value. To reset specific parameters, submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters. </p> <p> When you reset the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance reboot.</p>
+    ///
+    /// See `Docdb::reset_db_cluster_parameter_group`.
+    pub fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Creates a new cluster from a snapshot or cluster snapshot.</p> <p>If a snapshot is specified, the target cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a cluster snapshot is specified, the target cluster is created from the source cluster restore point with the same configuration as the original source DB cluster, except that the new cluster is created with the default security group.</p>
+    ///
+    /// See `Docdb::restore_db_cluster_from_snapshot`.
+    pub fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_snapshot(input))
+    }
+
+    /// <p>Restores a cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target cluster is created from the source cluster with the same configuration as the original cluster, except that the new cluster is created with the default security group. </p>
+    ///
+    /// See `Docdb::restore_db_cluster_to_point_in_time`.
+    pub fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_to_point_in_time(input))
+    }
+
+    /// <p>Restarts the stopped cluster that is specified by <code>DBClusterIdentifier</code>. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
+    ///
+    /// See `Docdb::start_db_cluster`.
+    pub fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.runtime.block_on(self.client.start_db_cluster(input))
+    }
+
+    /// <p>Stops the running cluster that is specified by <code>DBClusterIdentifier</code>. The cluster must be in the <i>available</i> state. For more information, see <a href="https://docs.aws.amazon.com/documentdb/latest/developerguide/db-cluster-stop-start.html">Stopping and Starting an Amazon DocumentDB Cluster</a>.</p>
+    ///
+    /// See `Docdb::stop_db_cluster`.
+    pub fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.runtime.block_on(self.client.stop_db_cluster(input))
+    }
+}
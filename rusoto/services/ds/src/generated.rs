@@ -2131,25 +2131,28 @@ impl AcceptSharedDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, AcceptSharedDirectoryError::Client(err.msg))
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptSharedDirectoryError::DirectoryAlreadyShared(err.msg),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptSharedDirectoryError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptSharedDirectoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, AcceptSharedDirectoryError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2195,25 +2198,37 @@ impl AddIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AddIpRoutesError::Client(err.msg))
+                    return RusotoError::service(&res, AddIpRoutesError::Client(err.msg))
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(AddIpRoutesError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddIpRoutesError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(AddIpRoutesError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddIpRoutesError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddIpRoutesError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, AddIpRoutesError::InvalidParameter(err.msg))
                 }
                 "IpRouteLimitExceededException" => {
-                    return RusotoError::Service(AddIpRoutesError::IpRouteLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddIpRoutesError::IpRouteLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddIpRoutesError::Service(err.msg))
+                    return RusotoError::service(&res, AddIpRoutesError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2257,21 +2272,28 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AddTagsToResourceError::Client(err.msg))
+                    return RusotoError::service(&res, AddTagsToResourceError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddTagsToResourceError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddTagsToResourceError::Service(err.msg))
+                    return RusotoError::service(&res, AddTagsToResourceError::Service(err.msg))
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(AddTagsToResourceError::TagLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::TagLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2309,15 +2331,16 @@ impl CancelSchemaExtensionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::Client(err.msg))
+                    return RusotoError::service(&res, CancelSchemaExtensionError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSchemaExtensionError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::Service(err.msg))
+                    return RusotoError::service(&res, CancelSchemaExtensionError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2355,18 +2378,22 @@ impl ConnectDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ConnectDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, ConnectDirectoryError::Client(err.msg))
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(ConnectDirectoryError::DirectoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConnectDirectoryError::DirectoryLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConnectDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConnectDirectoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ConnectDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, ConnectDirectoryError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2407,19 +2434,25 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateAliasError::Client(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::Client(err.msg))
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateAliasError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAliasError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateAliasError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAliasError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAliasError::Service(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2467,28 +2500,46 @@ impl CreateComputerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(CreateComputerError::AuthenticationFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputerError::AuthenticationFailed(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateComputerError::Client(err.msg))
+                    return RusotoError::service(&res, CreateComputerError::Client(err.msg))
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateComputerError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputerError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateComputerError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputerError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateComputerError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputerError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateComputerError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputerError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateComputerError::Service(err.msg))
+                    return RusotoError::service(&res, CreateComputerError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateComputerError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComputerError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2539,33 +2590,44 @@ impl CreateConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConditionalForwarderError::Client(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConditionalForwarderError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConditionalForwarderError::EntityAlreadyExists(err.msg),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConditionalForwarderError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConditionalForwarderError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConditionalForwarderError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConditionalForwarderError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2617,18 +2679,22 @@ impl CreateDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, CreateDirectoryError::Client(err.msg))
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryError::DirectoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryError::DirectoryLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDirectoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, CreateDirectoryError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2671,30 +2737,34 @@ impl CreateLogSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::Client(err.msg))
+                    return RusotoError::service(&res, CreateLogSubscriptionError::Client(err.msg))
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::EntityAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogSubscriptionError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogSubscriptionError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLogSubscriptionError::InsufficientPermissions(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::Service(err.msg))
+                    return RusotoError::service(&res, CreateLogSubscriptionError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogSubscriptionError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2739,23 +2809,28 @@ impl CreateMicrosoftADError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::Client(err.msg))
+                    return RusotoError::service(&res, CreateMicrosoftADError::Client(err.msg))
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::DirectoryLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMicrosoftADError::DirectoryLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMicrosoftADError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::Service(err.msg))
+                    return RusotoError::service(&res, CreateMicrosoftADError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMicrosoftADError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2797,21 +2872,28 @@ impl CreateSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateSnapshotError::Client(err.msg))
+                    return RusotoError::service(&res, CreateSnapshotError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateSnapshotError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSnapshotError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSnapshotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSnapshotError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, CreateSnapshotError::Service(err.msg))
                 }
                 "SnapshotLimitExceededException" => {
-                    return RusotoError::Service(CreateSnapshotError::SnapshotLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSnapshotError::SnapshotLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2855,22 +2937,31 @@ impl CreateTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateTrustError::Client(err.msg))
+                    return RusotoError::service(&res, CreateTrustError::Client(err.msg))
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTrustError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrustError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrustError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateTrustError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateTrustError::Service(err.msg))
+                    return RusotoError::service(&res, CreateTrustError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrustError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2917,28 +3008,38 @@ impl DeleteConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConditionalForwarderError::Client(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConditionalForwarderError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConditionalForwarderError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConditionalForwarderError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConditionalForwarderError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConditionalForwarderError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2985,13 +3086,16 @@ impl DeleteDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteDirectoryError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDirectoryError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteDirectoryError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3029,20 +3133,22 @@ impl DeleteLogSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteLogSubscriptionError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogSubscriptionError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteLogSubscriptionError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogSubscriptionError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3081,16 +3187,22 @@ impl DeleteSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteSnapshotError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteSnapshotError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteSnapshotError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSnapshotError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSnapshotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSnapshotError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteSnapshotError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3131,19 +3243,25 @@ impl DeleteTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteTrustError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteTrustError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTrustError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteTrustError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteTrustError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteTrustError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTrustError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3191,40 +3309,46 @@ impl DeregisterCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterCertificateError::CertificateDoesNotExist(err.msg),
                     )
                 }
                 "CertificateInUseException" => {
-                    return RusotoError::Service(DeregisterCertificateError::CertificateInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterCertificateError::CertificateInUse(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterCertificateError::Client(err.msg))
+                    return RusotoError::service(&res, DeregisterCertificateError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DeregisterCertificateError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterCertificateError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeregisterCertificateError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterCertificateError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterCertificateError::Service(err.msg))
+                    return RusotoError::service(&res, DeregisterCertificateError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeregisterCertificateError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterCertificateError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3269,20 +3393,22 @@ impl DeregisterEventTopicError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::Client(err.msg))
+                    return RusotoError::service(&res, DeregisterEventTopicError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterEventTopicError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterEventTopicError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::Service(err.msg))
+                    return RusotoError::service(&res, DeregisterEventTopicError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3325,30 +3451,34 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateDoesNotExistException" => {
-                    return RusotoError::Service(DescribeCertificateError::CertificateDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::CertificateDoesNotExist(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeCertificateError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeCertificateError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeCertificateError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeCertificateError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeCertificateError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeCertificateError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3395,32 +3525,38 @@ impl DescribeConditionalForwardersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeConditionalForwardersError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConditionalForwardersError::Client(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConditionalForwardersError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConditionalForwardersError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConditionalForwardersError::InvalidParameter(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeConditionalForwardersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConditionalForwardersError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConditionalForwardersError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3473,25 +3609,28 @@ impl DescribeDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeDirectoriesError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDirectoriesError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDirectoriesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDirectoriesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeDirectoriesError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3535,28 +3674,38 @@ impl DescribeDomainControllersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainControllersError::Client(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDomainControllersError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainControllersError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainControllersError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainControllersError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDomainControllersError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3601,20 +3750,22 @@ impl DescribeEventTopicsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeEventTopicsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventTopicsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventTopicsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeEventTopicsError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3657,30 +3808,34 @@ impl DescribeLDAPSSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeLDAPSSettingsError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLDAPSSettingsError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLDAPSSettingsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLDAPSSettingsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeLDAPSSettingsError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLDAPSSettingsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3725,28 +3880,38 @@ impl DescribeSharedDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSharedDirectoriesError::Client(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSharedDirectoriesError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSharedDirectoriesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSharedDirectoriesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSharedDirectoriesError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSharedDirectoriesError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3793,21 +3958,28 @@ impl DescribeSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeSnapshotsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSnapshotsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSnapshotsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSnapshotsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeSnapshotsError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3851,22 +4023,34 @@ impl DescribeTrustsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTrustsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeTrustsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeTrustsError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrustsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeTrustsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrustsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTrustsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrustsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeTrustsError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeTrustsError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeTrustsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrustsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3913,25 +4097,37 @@ impl DisableLDAPSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DisableLDAPSError::Client(err.msg))
+                    return RusotoError::service(&res, DisableLDAPSError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DisableLDAPSError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableLDAPSError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DisableLDAPSError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableLDAPSError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidLDAPSStatusException" => {
-                    return RusotoError::Service(DisableLDAPSError::InvalidLDAPSStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableLDAPSError::InvalidLDAPSStatus(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisableLDAPSError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DisableLDAPSError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableLDAPSError::Service(err.msg))
+                    return RusotoError::service(&res, DisableLDAPSError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DisableLDAPSError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableLDAPSError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3971,13 +4167,16 @@ impl DisableRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DisableRadiusError::Client(err.msg))
+                    return RusotoError::service(&res, DisableRadiusError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DisableRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableRadiusError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableRadiusError::Service(err.msg))
+                    return RusotoError::service(&res, DisableRadiusError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4017,17 +4216,25 @@ impl DisableSsoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(DisableSsoError::AuthenticationFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableSsoError::AuthenticationFailed(err.msg),
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(&res, DisableSsoError::Client(err.msg))
                 }
-                "ClientException" => return RusotoError::Service(DisableSsoError::Client(err.msg)),
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DisableSsoError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(&res, DisableSsoError::EntityDoesNotExist(err.msg))
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(DisableSsoError::InsufficientPermissions(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableSsoError::InsufficientPermissions(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableSsoError::Service(err.msg))
+                    return RusotoError::service(&res, DisableSsoError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4075,28 +4282,43 @@ impl EnableLDAPSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(EnableLDAPSError::Client(err.msg))
+                    return RusotoError::service(&res, EnableLDAPSError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(EnableLDAPSError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableLDAPSError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(EnableLDAPSError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableLDAPSError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidLDAPSStatusException" => {
-                    return RusotoError::Service(EnableLDAPSError::InvalidLDAPSStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableLDAPSError::InvalidLDAPSStatus(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableLDAPSError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, EnableLDAPSError::InvalidParameter(err.msg))
                 }
                 "NoAvailableCertificateException" => {
-                    return RusotoError::Service(EnableLDAPSError::NoAvailableCertificate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableLDAPSError::NoAvailableCertificate(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableLDAPSError::Service(err.msg))
+                    return RusotoError::service(&res, EnableLDAPSError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(EnableLDAPSError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableLDAPSError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4141,19 +4363,25 @@ impl EnableRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(EnableRadiusError::Client(err.msg))
+                    return RusotoError::service(&res, EnableRadiusError::Client(err.msg))
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(EnableRadiusError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableRadiusError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(EnableRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableRadiusError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableRadiusError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, EnableRadiusError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableRadiusError::Service(err.msg))
+                    return RusotoError::service(&res, EnableRadiusError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4195,17 +4423,25 @@ impl EnableSsoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(EnableSsoError::AuthenticationFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableSsoError::AuthenticationFailed(err.msg),
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(&res, EnableSsoError::Client(err.msg))
                 }
-                "ClientException" => return RusotoError::Service(EnableSsoError::Client(err.msg)),
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(EnableSsoError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(&res, EnableSsoError::EntityDoesNotExist(err.msg))
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(EnableSsoError::InsufficientPermissions(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableSsoError::InsufficientPermissions(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableSsoError::Service(err.msg))
+                    return RusotoError::service(&res, EnableSsoError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4243,15 +4479,16 @@ impl GetDirectoryLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::Client(err.msg))
+                    return RusotoError::service(&res, GetDirectoryLimitsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDirectoryLimitsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::Service(err.msg))
+                    return RusotoError::service(&res, GetDirectoryLimitsError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4287,15 +4524,16 @@ impl GetSnapshotLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::Client(err.msg))
+                    return RusotoError::service(&res, GetSnapshotLimitsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSnapshotLimitsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::Service(err.msg))
+                    return RusotoError::service(&res, GetSnapshotLimitsError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4337,26 +4575,34 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListCertificatesError::Client(err.msg))
+                    return RusotoError::service(&res, ListCertificatesError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(ListCertificatesError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCertificatesError::Service(err.msg))
+                    return RusotoError::service(&res, ListCertificatesError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListCertificatesError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4399,19 +4645,22 @@ impl ListIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListIpRoutesError::Client(err.msg))
+                    return RusotoError::service(&res, ListIpRoutesError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIpRoutesError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIpRoutesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListIpRoutesError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListIpRoutesError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListIpRoutesError::Service(err.msg))
+                    return RusotoError::service(&res, ListIpRoutesError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4451,20 +4700,22 @@ impl ListLogSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::Client(err.msg))
+                    return RusotoError::service(&res, ListLogSubscriptionsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLogSubscriptionsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLogSubscriptionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::Service(err.msg))
+                    return RusotoError::service(&res, ListLogSubscriptionsError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4503,20 +4754,22 @@ impl ListSchemaExtensionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::Client(err.msg))
+                    return RusotoError::service(&res, ListSchemaExtensionsError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemaExtensionsError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemaExtensionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::Service(err.msg))
+                    return RusotoError::service(&res, ListSchemaExtensionsError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4557,25 +4810,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListTagsForResourceError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4625,45 +4881,52 @@ impl RegisterCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateError::CertificateAlreadyExists(err.msg),
                     )
                 }
                 "CertificateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateError::CertificateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(RegisterCertificateError::Client(err.msg))
+                    return RusotoError::service(&res, RegisterCertificateError::Client(err.msg))
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(RegisterCertificateError::DirectoryDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::DirectoryDoesNotExist(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RegisterCertificateError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidCertificateException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidCertificate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::InvalidCertificate(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterCertificateError::Service(err.msg))
+                    return RusotoError::service(&res, RegisterCertificateError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RegisterCertificateError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4707,18 +4970,22 @@ impl RegisterEventTopicError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterEventTopicError::Client(err.msg))
+                    return RusotoError::service(&res, RegisterEventTopicError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RegisterEventTopicError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterEventTopicError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterEventTopicError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterEventTopicError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterEventTopicError::Service(err.msg))
+                    return RusotoError::service(&res, RegisterEventTopicError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4759,25 +5026,28 @@ impl RejectSharedDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, RejectSharedDirectoryError::Client(err.msg))
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectSharedDirectoryError::DirectoryAlreadyShared(err.msg),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectSharedDirectoryError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectSharedDirectoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, RejectSharedDirectoryError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4819,19 +5089,28 @@ impl RemoveIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::Client(err.msg))
+                    return RusotoError::service(&res, RemoveIpRoutesError::Client(err.msg))
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveIpRoutesError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveIpRoutesError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveIpRoutesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::Service(err.msg))
+                    return RusotoError::service(&res, RemoveIpRoutesError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4871,20 +5150,25 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::Client(err.msg))
+                    return RusotoError::service(&res, RemoveTagsFromResourceError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::Service(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4929,31 +5213,40 @@ impl ResetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ResetUserPasswordError::Client(err.msg))
+                    return RusotoError::service(&res, ResetUserPasswordError::Client(err.msg))
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(ResetUserPasswordError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResetUserPasswordError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ResetUserPasswordError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResetUserPasswordError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ResetUserPasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetUserPasswordError::InvalidPassword(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ResetUserPasswordError::Service(err.msg))
+                    return RusotoError::service(&res, ResetUserPasswordError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ResetUserPasswordError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResetUserPasswordError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "UserDoesNotExistException" => {
-                    return RusotoError::Service(ResetUserPasswordError::UserDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetUserPasswordError::UserDoesNotExist(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4995,20 +5288,22 @@ impl RestoreFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::Client(err.msg))
+                    return RusotoError::service(&res, RestoreFromSnapshotError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreFromSnapshotError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreFromSnapshotError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, RestoreFromSnapshotError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5059,36 +5354,49 @@ impl ShareDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ShareDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ShareDirectoryError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ShareDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, ShareDirectoryError::Client(err.msg))
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(ShareDirectoryError::DirectoryAlreadyShared(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ShareDirectoryError::DirectoryAlreadyShared(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ShareDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ShareDirectoryError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ShareDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ShareDirectoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(ShareDirectoryError::InvalidTarget(err.msg))
+                    return RusotoError::service(&res, ShareDirectoryError::InvalidTarget(err.msg))
                 }
                 "OrganizationsException" => {
-                    return RusotoError::Service(ShareDirectoryError::Organizations(err.msg))
+                    return RusotoError::service(&res, ShareDirectoryError::Organizations(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ShareDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, ShareDirectoryError::Service(err.msg))
                 }
                 "ShareLimitExceededException" => {
-                    return RusotoError::Service(ShareDirectoryError::ShareLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ShareDirectoryError::ShareLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ShareDirectoryError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ShareDirectoryError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5137,30 +5445,34 @@ impl StartSchemaExtensionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::Client(err.msg))
+                    return RusotoError::service(&res, StartSchemaExtensionError::Client(err.msg))
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaExtensionError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::EntityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaExtensionError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaExtensionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::Service(err.msg))
+                    return RusotoError::service(&res, StartSchemaExtensionError::Service(err.msg))
                 }
                 "SnapshotLimitExceededException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::SnapshotLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSchemaExtensionError::SnapshotLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5203,19 +5515,28 @@ impl UnshareDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UnshareDirectoryError::Client(err.msg))
+                    return RusotoError::service(&res, UnshareDirectoryError::Client(err.msg))
                 }
                 "DirectoryNotSharedException" => {
-                    return RusotoError::Service(UnshareDirectoryError::DirectoryNotShared(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnshareDirectoryError::DirectoryNotShared(err.msg),
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UnshareDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnshareDirectoryError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(UnshareDirectoryError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnshareDirectoryError::InvalidTarget(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UnshareDirectoryError::Service(err.msg))
+                    return RusotoError::service(&res, UnshareDirectoryError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5261,28 +5582,38 @@ impl UpdateConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConditionalForwarderError::Client(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConditionalForwarderError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConditionalForwarderError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConditionalForwarderError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConditionalForwarderError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConditionalForwarderError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -5339,39 +5670,46 @@ impl UpdateNumberOfDomainControllersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNumberOfDomainControllersError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNumberOfDomainControllersError::Client(err.msg),
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNumberOfDomainControllersError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "DomainControllerLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNumberOfDomainControllersError::DomainControllerLimitExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNumberOfDomainControllersError::EntityDoesNotExist(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNumberOfDomainControllersError::InvalidParameter(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateNumberOfDomainControllersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNumberOfDomainControllersError::Service(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNumberOfDomainControllersError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -5425,16 +5763,19 @@ impl UpdateRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateRadiusError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateRadiusError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UpdateRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRadiusError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRadiusError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateRadiusError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRadiusError::Service(err.msg))
+                    return RusotoError::service(&res, UpdateRadiusError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5473,16 +5814,19 @@ impl UpdateTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateTrustError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateTrustError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UpdateTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrustError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateTrustError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateTrustError::Service(err.msg))
+                    return RusotoError::service(&res, UpdateTrustError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5523,19 +5867,25 @@ impl VerifyTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(VerifyTrustError::Client(err.msg))
+                    return RusotoError::service(&res, VerifyTrustError::Client(err.msg))
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(VerifyTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyTrustError::EntityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifyTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, VerifyTrustError::InvalidParameter(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(VerifyTrustError::Service(err.msg))
+                    return RusotoError::service(&res, VerifyTrustError::Service(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(VerifyTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VerifyTrustError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5922,6 +6272,7 @@ impl DirectoryServiceClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5937,6 +6288,7 @@ impl DirectoryServiceClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DirectoryServiceClient {
         DirectoryServiceClient { client, region }
     }
@@ -5950,6 +6302,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: AcceptSharedDirectoryRequest,
     ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptSharedDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.AcceptSharedDirectory",
@@ -5971,6 +6326,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddIpRoutesRequest,
     ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddIpRoutes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.AddIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5989,6 +6347,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.AddTagsToResource",
@@ -6010,6 +6371,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CancelSchemaExtensionRequest,
     ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelSchemaExtension");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CancelSchemaExtension",
@@ -6031,6 +6395,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ConnectDirectoryRequest,
     ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConnectDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.ConnectDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6049,6 +6416,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateAliasRequest,
     ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6067,6 +6437,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateComputerRequest,
     ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateComputer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateComputer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6086,6 +6459,9 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateConditionalForwarder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CreateConditionalForwarder",
@@ -6108,6 +6484,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateDirectoryRequest,
     ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6126,6 +6505,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateLogSubscriptionRequest,
     ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLogSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CreateLogSubscription",
@@ -6147,6 +6529,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateMicrosoftADRequest,
     ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMicrosoftAD");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CreateMicrosoftAD",
@@ -6168,6 +6553,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateSnapshotRequest,
     ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6186,6 +6574,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateTrustRequest,
     ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTrust");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6205,6 +6596,9 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConditionalForwarder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeleteConditionalForwarder",
@@ -6227,6 +6621,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteDirectoryRequest,
     ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6245,6 +6642,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteLogSubscriptionRequest,
     ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLogSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeleteLogSubscription",
@@ -6266,6 +6666,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteSnapshotRequest,
     ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6284,6 +6687,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteTrustRequest,
     ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTrust");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6302,6 +6708,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeregisterCertificateRequest,
     ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeregisterCertificate",
@@ -6323,6 +6732,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeregisterEventTopicRequest,
     ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterEventTopic");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeregisterEventTopic",
@@ -6344,6 +6756,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeCertificateRequest,
     ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeCertificate",
@@ -6366,6 +6781,9 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConditionalForwarders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeConditionalForwarders",
@@ -6388,6 +6806,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeDirectoriesRequest,
     ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDirectories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeDirectories",
@@ -6409,6 +6830,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeDomainControllersRequest,
     ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDomainControllers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeDomainControllers",
@@ -6431,6 +6855,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeEventTopicsRequest,
     ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventTopics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeEventTopics",
@@ -6452,6 +6879,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeLDAPSSettingsRequest,
     ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLDAPSSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeLDAPSSettings",
@@ -6473,6 +6903,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeSharedDirectoriesRequest,
     ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSharedDirectories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeSharedDirectories",
@@ -6495,6 +6928,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeSnapshotsRequest,
     ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeSnapshots",
@@ -6516,6 +6952,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeTrustsRequest,
     ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrusts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DirectoryService_20150416.DescribeTrusts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6534,6 +6973,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableLDAPSRequest,
     ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableLDAPS");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableLDAPS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6552,6 +6994,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableRadiusRequest,
     ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableRadius");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6570,6 +7015,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableSsoRequest,
     ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableSso");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableSso");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6588,6 +7036,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableLDAPSRequest,
     ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableLDAPS");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableLDAPS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6606,6 +7057,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableRadiusRequest,
     ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableRadius");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6624,6 +7078,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableSsoRequest,
     ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableSso");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableSso");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6641,6 +7098,9 @@ impl DirectoryService for DirectoryServiceClient {
         &self,
     ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDirectoryLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.GetDirectoryLimits",
@@ -6661,6 +7121,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: GetSnapshotLimitsRequest,
     ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSnapshotLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.GetSnapshotLimits",
@@ -6682,6 +7145,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListCertificatesRequest,
     ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DirectoryService_20150416.ListCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6700,6 +7166,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListIpRoutesRequest,
     ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListIpRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DirectoryService_20150416.ListIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6718,6 +7187,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListLogSubscriptionsRequest,
     ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLogSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListLogSubscriptions",
@@ -6739,6 +7211,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListSchemaExtensionsRequest,
     ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSchemaExtensions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListSchemaExtensions",
@@ -6760,6 +7235,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListTagsForResource",
@@ -6781,6 +7259,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: RegisterCertificateRequest,
     ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RegisterCertificate",
@@ -6802,6 +7283,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: RegisterEventTopicRequest,
     ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterEventTopic");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RegisterEventTopic",
@@ -6823,6 +7307,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: RejectSharedDirectoryRequest,
     ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RejectSharedDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RejectSharedDirectory",
@@ -6844,6 +7331,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveIpRoutesRequest,
     ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveIpRoutes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.RemoveIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6862,6 +7352,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RemoveTagsFromResource",
@@ -6884,6 +7377,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ResetUserPasswordRequest,
     ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResetUserPassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ResetUserPassword",
@@ -6905,6 +7401,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: RestoreFromSnapshotRequest,
     ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RestoreFromSnapshot",
@@ -6926,6 +7425,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: ShareDirectoryRequest,
     ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ShareDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.ShareDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6944,6 +7446,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: StartSchemaExtensionRequest,
     ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartSchemaExtension");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.StartSchemaExtension",
@@ -6965,6 +7470,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: UnshareDirectoryRequest,
     ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnshareDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.UnshareDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6984,6 +7492,9 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateConditionalForwarder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.UpdateConditionalForwarder",
@@ -7009,6 +7520,9 @@ impl DirectoryService for DirectoryServiceClient {
         RusotoError<UpdateNumberOfDomainControllersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNumberOfDomainControllers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.UpdateNumberOfDomainControllers",
@@ -7031,6 +7545,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: UpdateRadiusRequest,
     ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRadius");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7049,6 +7566,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: UpdateTrustRequest,
     ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTrust");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7067,6 +7587,9 @@ impl DirectoryService for DirectoryServiceClient {
         input: VerifyTrustRequest,
     ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("VerifyTrust");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DirectoryService_20150416.VerifyTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7079,3 +7602,662 @@ impl DirectoryService for DirectoryServiceClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyTrustResult, _>()
     }
 }
+
+/// A blocking client for the Directory Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DirectoryServiceClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DirectoryServiceBlockingClient {
+    client: DirectoryServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DirectoryServiceBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DirectoryServiceBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DirectoryServiceBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DirectoryServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DirectoryServiceBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient {
+            client: DirectoryServiceClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts a directory sharing request that was sent from the directory owner account.</p>
+    ///
+    /// See `DirectoryService::accept_shared_directory`.
+    pub fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
+        self.runtime
+            .block_on(self.client.accept_shared_directory(input))
+    }
+
+    /// <p>If the DNS server for your on-premises domain uses a publicly addressable IP address, you must add a CIDR address block to correctly route traffic to and from your Microsoft AD on Amazon Web Services. <i>AddIpRoutes</i> adds this address block. You can also use <i>AddIpRoutes</i> to facilitate routing traffic that uses public IP ranges from your Microsoft AD on AWS to a peer VPC. </p> <p>Before you call <i>AddIpRoutes</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>AddIpRoutes</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    ///
+    /// See `DirectoryService::add_ip_routes`.
+    pub fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
+        self.runtime.block_on(self.client.add_ip_routes(input))
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified directory. Each directory can have a maximum of 50 tags. Each tag consists of a key and optional value. Tag keys must be unique to each resource.</p>
+    ///
+    /// See `DirectoryService::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Cancels an in-progress schema extension to a Microsoft AD directory. Once a schema extension has started replicating to all domain controllers, the task can no longer be canceled. A schema extension can be canceled during any of the following states; <code>Initializing</code>, <code>CreatingSnapshot</code>, and <code>UpdatingSchema</code>.</p>
+    ///
+    /// See `DirectoryService::cancel_schema_extension`.
+    pub fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
+        self.runtime
+            .block_on(self.client.cancel_schema_extension(input))
+    }
+
+    /// <p>Creates an AD Connector to connect to an on-premises directory.</p> <p>Before you call <code>ConnectDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>ConnectDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    ///
+    /// See `DirectoryService::connect_directory`.
+    pub fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
+        self.runtime.block_on(self.client.connect_directory(input))
+    }
+
+    /// <p><p>Creates an alias for a directory and assigns the alias to the directory. The alias is used to construct the access URL for the directory, such as <code>http://&lt;alias&gt;.awsapps.com</code>.</p> <important> <p>After an alias has been created, it cannot be deleted or reused, so this operation should only be used when absolutely necessary.</p> </important></p>
+    ///
+    /// See `DirectoryService::create_alias`.
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// <p>Creates a computer account in the specified directory, and joins the computer to the directory.</p>
+    ///
+    /// See `DirectoryService::create_computer`.
+    pub fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
+        self.runtime.block_on(self.client.create_computer(input))
+    }
+
+    /// <p>Creates a conditional forwarder associated with your AWS directory. Conditional forwarders are required in order to set up a trust relationship with another domain. The conditional forwarder points to the trusted domain.</p>
+    ///
+    /// See `DirectoryService::create_conditional_forwarder`.
+    pub fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
+    {
+        self.runtime
+            .block_on(self.client.create_conditional_forwarder(input))
+    }
+
+    /// <p>Creates a Simple AD directory. For more information, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/directory_simple_ad.html">Simple Active Directory</a> in the <i>AWS Directory Service Admin Guide</i>.</p> <p>Before you call <code>CreateDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>CreateDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    ///
+    /// See `DirectoryService::create_directory`.
+    pub fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
+        self.runtime.block_on(self.client.create_directory(input))
+    }
+
+    /// <p>Creates a subscription to forward real-time Directory Service domain controller security logs to the specified Amazon CloudWatch log group in your AWS account.</p>
+    ///
+    /// See `DirectoryService::create_log_subscription`.
+    pub fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_log_subscription(input))
+    }
+
+    /// <p>Creates a Microsoft AD directory in the AWS Cloud. For more information, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/directory_microsoft_ad.html">AWS Managed Microsoft AD</a> in the <i>AWS Directory Service Admin Guide</i>.</p> <p>Before you call <i>CreateMicrosoftAD</i>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <i>CreateMicrosoftAD</i> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    ///
+    /// See `DirectoryService::create_microsoft_ad`.
+    pub fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
+        self.runtime
+            .block_on(self.client.create_microsoft_ad(input))
+    }
+
+    /// <p><p>Creates a snapshot of a Simple AD or Microsoft AD directory in the AWS cloud.</p> <note> <p>You cannot take snapshots of AD Connector directories.</p> </note></p>
+    ///
+    /// See `DirectoryService::create_snapshot`.
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain. You can create either a forest trust or an external trust.</p>
+    ///
+    /// See `DirectoryService::create_trust`.
+    pub fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
+        self.runtime.block_on(self.client.create_trust(input))
+    }
+
+    /// <p>Deletes a conditional forwarder that has been set up for your AWS directory.</p>
+    ///
+    /// See `DirectoryService::delete_conditional_forwarder`.
+    pub fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_conditional_forwarder(input))
+    }
+
+    /// <p>Deletes an AWS Directory Service directory.</p> <p>Before you call <code>DeleteDirectory</code>, ensure that all of the required permissions have been explicitly granted through a policy. For details about what permissions are required to run the <code>DeleteDirectory</code> operation, see <a href="http://docs.aws.amazon.com/directoryservice/latest/admin-guide/UsingWithDS_IAM_ResourcePermissions.html">AWS Directory Service API Permissions: Actions, Resources, and Conditions Reference</a>.</p>
+    ///
+    /// See `DirectoryService::delete_directory`.
+    pub fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
+        self.runtime.block_on(self.client.delete_directory(input))
+    }
+
+    /// <p>Deletes the specified log subscription.</p>
+    ///
+    /// See `DirectoryService::delete_log_subscription`.
+    pub fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_log_subscription(input))
+    }
+
+    /// <p>Deletes a directory snapshot.</p>
+    ///
+    /// See `DirectoryService::delete_snapshot`.
+    pub fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        self.runtime.block_on(self.client.delete_snapshot(input))
+    }
+
+    /// <p>Deletes an existing trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
+    ///
+    /// See `DirectoryService::delete_trust`.
+    pub fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
+        self.runtime.block_on(self.client.delete_trust(input))
+    }
+
+    /// <p>Deletes from the system the certificate that was registered for a secured LDAP connection.</p>
+    ///
+    /// See `DirectoryService::deregister_certificate`.
+    pub fn deregister_certificate(
+        &self,
+        input: DeregisterCertificateRequest,
+    ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
+        self.runtime
+            .block_on(self.client.deregister_certificate(input))
+    }
+
+    /// <p>Removes the specified directory as a publisher to the specified SNS topic.</p>
+    ///
+    /// See `DirectoryService::deregister_event_topic`.
+    pub fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
+        self.runtime
+            .block_on(self.client.deregister_event_topic(input))
+    }
+
+    /// <p>Displays information about the certificate registered for a secured LDAP connection.</p>
+    ///
+    /// See `DirectoryService::describe_certificate`.
+    pub fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_certificate(input))
+    }
+
+    /// <p>Obtains information about the conditional forwarders for this account.</p> <p>If no input parameters are provided for RemoteDomainNames, this request describes all conditional forwarders for the specified directory ID.</p>
+    ///
+    /// See `DirectoryService::describe_conditional_forwarders`.
+    pub fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_conditional_forwarders(input))
+    }
+
+    /// <p>Obtains information about the directories that belong to this account.</p> <p>You can retrieve information about specific directories by passing the directory identifiers in the <code>DirectoryIds</code> parameter. Otherwise, all directories that belong to the current account are returned.</p> <p>This operation supports pagination with the use of the <code>NextToken</code> request and response parameters. If more results are available, the <code>DescribeDirectoriesResult.NextToken</code> member contains a token that you pass in the next call to <a>DescribeDirectories</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <code>Limit</code> parameter.</p>
+    ///
+    /// See `DirectoryService::describe_directories`.
+    pub fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_directories(input))
+    }
+
+    /// <p>Provides information about any domain controllers in your directory.</p>
+    ///
+    /// See `DirectoryService::describe_domain_controllers`.
+    pub fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
+        self.runtime
+            .block_on(self.client.describe_domain_controllers(input))
+    }
+
+    /// <p>Obtains information about which SNS topics receive status messages from the specified directory.</p> <p>If no input parameters are provided, such as DirectoryId or TopicName, this request describes all of the associations in the account.</p>
+    ///
+    /// See `DirectoryService::describe_event_topics`.
+    pub fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_topics(input))
+    }
+
+    /// <p>Describes the status of LDAP security for the specified directory.</p>
+    ///
+    /// See `DirectoryService::describe_ldaps_settings`.
+    pub fn describe_ldaps_settings(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_ldaps_settings(input))
+    }
+
+    /// <p>Returns the shared directories in your account. </p>
+    ///
+    /// See `DirectoryService::describe_shared_directories`.
+    pub fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_shared_directories(input))
+    }
+
+    /// <p>Obtains information about the directory snapshots that belong to this account.</p> <p>This operation supports pagination with the use of the <i>NextToken</i> request and response parameters. If more results are available, the <i>DescribeSnapshots.NextToken</i> member contains a token that you pass in the next call to <a>DescribeSnapshots</a> to retrieve the next set of items.</p> <p>You can also specify a maximum number of return results with the <i>Limit</i> parameter.</p>
+    ///
+    /// See `DirectoryService::describe_snapshots`.
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
+        self.runtime.block_on(self.client.describe_snapshots(input))
+    }
+
+    /// <p>Obtains information about the trust relationships for this account.</p> <p>If no input parameters are provided, such as DirectoryId or TrustIds, this request describes all the trust relationships belonging to the account.</p>
+    ///
+    /// See `DirectoryService::describe_trusts`.
+    pub fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
+        self.runtime.block_on(self.client.describe_trusts(input))
+    }
+
+    /// <p>Deactivates LDAP secure calls for the specified directory.</p>
+    ///
+    /// See `DirectoryService::disable_ldaps`.
+    pub fn disable_ldaps(
+        &self,
+        input: DisableLDAPSRequest,
+    ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
+        self.runtime.block_on(self.client.disable_ldaps(input))
+    }
+
+    /// <p>Disables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
+    ///
+    /// See `DirectoryService::disable_radius`.
+    pub fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
+        self.runtime.block_on(self.client.disable_radius(input))
+    }
+
+    /// <p>Disables single-sign on for a directory.</p>
+    ///
+    /// See `DirectoryService::disable_sso`.
+    pub fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
+        self.runtime.block_on(self.client.disable_sso(input))
+    }
+
+    /// <p>Activates the switch for the specific directory to always use LDAP secure calls.</p>
+    ///
+    /// See `DirectoryService::enable_ldaps`.
+    pub fn enable_ldaps(
+        &self,
+        input: EnableLDAPSRequest,
+    ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
+        self.runtime.block_on(self.client.enable_ldaps(input))
+    }
+
+    /// <p>Enables multi-factor authentication (MFA) with the Remote Authentication Dial In User Service (RADIUS) server for an AD Connector or Microsoft AD directory.</p>
+    ///
+    /// See `DirectoryService::enable_radius`.
+    pub fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
+        self.runtime.block_on(self.client.enable_radius(input))
+    }
+
+    /// <p>Enables single sign-on for a directory. Single sign-on allows users in your directory to access certain AWS services from a computer joined to the directory without having to enter their credentials separately.</p>
+    ///
+    /// See `DirectoryService::enable_sso`.
+    pub fn enable_sso(
+        &self,
+        input: EnableSsoRequest,
+    ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
+        self.runtime.block_on(self.client.enable_sso(input))
+    }
+
+    /// <p>Obtains directory limit information for the current Region.</p>
+    ///
+    /// See `DirectoryService::get_directory_limits`.
+    pub fn get_directory_limits(
+        &self,
+    ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
+        self.runtime.block_on(self.client.get_directory_limits())
+    }
+
+    /// <p>Obtains the manual snapshot limits for a directory.</p>
+    ///
+    /// See `DirectoryService::get_snapshot_limits`.
+    pub fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
+        self.runtime
+            .block_on(self.client.get_snapshot_limits(input))
+    }
+
+    /// <p>For the specified directory, lists all the certificates registered for a secured LDAP connection.</p>
+    ///
+    /// See `DirectoryService::list_certificates`.
+    pub fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
+        self.runtime.block_on(self.client.list_certificates(input))
+    }
+
+    /// <p>Lists the address blocks that you have added to a directory.</p>
+    ///
+    /// See `DirectoryService::list_ip_routes`.
+    pub fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
+        self.runtime.block_on(self.client.list_ip_routes(input))
+    }
+
+    /// <p>Lists the active log subscriptions for the AWS account.</p>
+    ///
+    /// See `DirectoryService::list_log_subscriptions`.
+    pub fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.list_log_subscriptions(input))
+    }
+
+    /// <p>Lists all schema extensions applied to a Microsoft AD Directory.</p>
+    ///
+    /// See `DirectoryService::list_schema_extensions`.
+    pub fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
+        self.runtime
+            .block_on(self.client.list_schema_extensions(input))
+    }
+
+    /// <p>Lists all tags on a directory.</p>
+    ///
+    /// See `DirectoryService::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Registers a certificate for secured LDAP connection.</p>
+    ///
+    /// See `DirectoryService::register_certificate`.
+    pub fn register_certificate(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
+        self.runtime
+            .block_on(self.client.register_certificate(input))
+    }
+
+    /// <p>Associates a directory with an SNS topic. This establishes the directory as a publisher to the specified SNS topic. You can then receive email or text (SMS) messages when the status of your directory changes. You get notified if your directory goes from an Active status to an Impaired or Inoperable status. You also receive a notification when the directory returns to an Active status.</p>
+    ///
+    /// See `DirectoryService::register_event_topic`.
+    pub fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
+        self.runtime
+            .block_on(self.client.register_event_topic(input))
+    }
+
+    /// <p>Rejects a directory sharing request that was sent from the directory owner account.</p>
+    ///
+    /// See `DirectoryService::reject_shared_directory`.
+    pub fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
+        self.runtime
+            .block_on(self.client.reject_shared_directory(input))
+    }
+
+    /// <p>Removes IP address blocks from a directory.</p>
+    ///
+    /// See `DirectoryService::remove_ip_routes`.
+    pub fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
+        self.runtime.block_on(self.client.remove_ip_routes(input))
+    }
+
+    /// <p>Removes tags from a directory.</p>
+    ///
+    /// See `DirectoryService::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p><p>Resets the password for any user in your AWS Managed Microsoft AD or Simple AD directory.</p> <p>You can reset the password for any user in your directory with the following exceptions:</p> <ul> <li> <p>For Simple AD, you cannot reset the password for any user that is a member of either the <b>Domain Admins</b> or <b>Enterprise Admins</b> group except for the administrator user.</p> </li> <li> <p>For AWS Managed Microsoft AD, you can only reset the password for a user that is in an OU based off of the NetBIOS name that you typed when you created your directory. For example, you cannot reset the password for a user in the <b>AWS Reserved</b> OU. For more information about the OU structure for an AWS Managed Microsoft AD directory, see <a href="https://docs.aws.amazon.com/directoryservice/latest/admin-guide/ms_ad_getting_started_what_gets_created.html">What Gets Created</a> in the <i>AWS Directory Service Administration Guide</i>.</p> </li> </ul></p>
+    ///
+    /// See `DirectoryService::reset_user_password`.
+    pub fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
+        self.runtime
+            .block_on(self.client.reset_user_password(input))
+    }
+
+    /// <p>Restores a directory using an existing directory snapshot.</p> <p>When you restore a directory from a snapshot, any changes made to the directory after the snapshot date are overwritten.</p> <p>This action returns as soon as the restore operation is initiated. You can monitor the progress of the restore operation by calling the <a>DescribeDirectories</a> operation with the directory identifier. When the <b>DirectoryDescription.Stage</b> value changes to <code>Active</code>, the restore operation is complete.</p>
+    ///
+    /// See `DirectoryService::restore_from_snapshot`.
+    pub fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
+        self.runtime
+            .block_on(self.client.restore_from_snapshot(input))
+    }
+
+    /// <p>Shares a specified directory (<code>DirectoryId</code>) in your AWS account (directory owner) with another AWS account (directory consumer). With this operation you can use your directory from any AWS account and from any Amazon VPC within an AWS Region.</p> <p>When you share your AWS Managed Microsoft AD directory, AWS Directory Service creates a shared directory in the directory consumer account. This shared directory contains the metadata to provide access to the directory within the directory owner account. The shared directory is visible in all VPCs in the directory consumer account.</p> <p>The <code>ShareMethod</code> parameter determines whether the specified directory can be shared between AWS accounts inside the same AWS organization (<code>ORGANIZATIONS</code>). It also determines whether you can share the directory with any other AWS account either inside or outside of the organization (<code>HANDSHAKE</code>).</p> <p>The <code>ShareNotes</code> parameter is only used when <code>HANDSHAKE</code> is called, which sends a directory sharing request to the directory consumer. </p>
+    ///
+    /// See `DirectoryService::share_directory`.
+    pub fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
+        self.runtime.block_on(self.client.share_directory(input))
+    }
+
+    /// <p>Applies a schema extension to a Microsoft AD directory.</p>
+    ///
+    /// See `DirectoryService::start_schema_extension`.
+    pub fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
+        self.runtime
+            .block_on(self.client.start_schema_extension(input))
+    }
+
+    /// <p>Stops the directory sharing between the directory owner and consumer accounts. </p>
+    ///
+    /// See `DirectoryService::unshare_directory`.
+    pub fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
+        self.runtime.block_on(self.client.unshare_directory(input))
+    }
+
+    /// <p>Updates a conditional forwarder that has been set up for your AWS directory.</p>
+    ///
+    /// See `DirectoryService::update_conditional_forwarder`.
+    pub fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
+    {
+        self.runtime
+            .block_on(self.client.update_conditional_forwarder(input))
+    }
+
+    /// <p>Adds or removes domain controllers to or from the directory. Based on the difference between current value and new value (provided through this API call), domain controllers will be added or removed. It may take up to 45 minutes for any new domain controllers to become fully active once the requested number of domain controllers is updated. During this time, you cannot make another update request.</p>
+    ///
+    /// See `DirectoryService::update_number_of_domain_controllers`.
+    pub fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> Result<
+        UpdateNumberOfDomainControllersResult,
+        RusotoError<UpdateNumberOfDomainControllersError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_number_of_domain_controllers(input))
+    }
+
+    /// <p>Updates the Remote Authentication Dial In User Service (RADIUS) server information for an AD Connector or Microsoft AD directory.</p>
+    ///
+    /// See `DirectoryService::update_radius`.
+    pub fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
+        self.runtime.block_on(self.client.update_radius(input))
+    }
+
+    /// <p>Updates the trust that has been set up between your AWS Managed Microsoft AD directory and an on-premises Active Directory.</p>
+    ///
+    /// See `DirectoryService::update_trust`.
+    pub fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
+        self.runtime.block_on(self.client.update_trust(input))
+    }
+
+    /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure and verify trust relationships.</p> <p>This action verifies a trust relationship between your AWS Managed Microsoft AD directory and an external domain.</p>
+    ///
+    /// See `DirectoryService::verify_trust`.
+    pub fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
+        self.runtime.block_on(self.client.verify_trust(input))
+    }
+}
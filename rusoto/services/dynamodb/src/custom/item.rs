@@ -0,0 +1,1007 @@
+//! Converting Rust values to and from the items and attribute values of DynamoDB with serde.
+//!
+//! Structs and maps are items of attributes (`M`), strings are `S`, numbers are `N`, booleans
+//! are `BOOL`, byte buffers are `B`, sequences and tuples are lists (`L`), and `None` and unit
+//! values are `NULL`. Enums are represented like serde_json represents them: unit variants are
+//! the name of the variant, and other variants are a map of the name to the value of the
+//! variant. The `#[serde]` attributes of the types, like `rename` or `tag`, apply.
+//!
+//! String, number and binary sets (`SS`, `NS` and `BS`) are read into sequences, but sequences
+//! are always written as lists.
+
+use std::collections::{hash_map, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::vec;
+
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+
+use crate::generated::AttributeValue;
+
+/// An item, the attributes of a value.
+pub type Item = HashMap<String, AttributeValue>;
+
+/// Converts a value to an item, to put it with `PutItem` for example.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_dynamodb::item::{from_item, to_item};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, Deserialize, PartialEq, Serialize)]
+/// #[serde(rename_all = "PascalCase")]
+/// struct User {
+///     user_id: String,
+///     age: u32,
+///     nickname: Option<String>,
+/// }
+///
+/// let user = User {
+///     user_id: "joe".to_owned(),
+///     age: 35,
+///     nickname: None,
+/// };
+/// let item = to_item(&user).unwrap();
+/// assert_eq!(item["UserId"].s.as_deref(), Some("joe"));
+/// assert_eq!(item["Age"].n.as_deref(), Some("35"));
+/// assert_eq!(item["Nickname"].null, Some(true));
+/// assert_eq!(from_item::<User>(item).unwrap(), user);
+/// ```
+pub fn to_item<T: Serialize + ?Sized>(value: &T) -> Result<Item, ItemError> {
+    to_attribute_value(value)?.m.ok_or(ItemError::NotAnItem)
+}
+
+/// Converts an item, like the `Item` of a `GetItem` output, to a value.
+pub fn from_item<T: DeserializeOwned>(item: Item) -> Result<T, ItemError> {
+    from_attribute_value(AttributeValue {
+        m: Some(item),
+        ..Default::default()
+    })
+}
+
+/// Converts a value to an attribute value, like the value of a key or of an expression
+/// attribute.
+pub fn to_attribute_value<T: Serialize + ?Sized>(value: &T) -> Result<AttributeValue, ItemError> {
+    value.serialize(Serializer)
+}
+
+/// Converts an attribute value to a value.
+pub fn from_attribute_value<T: DeserializeOwned>(value: AttributeValue) -> Result<T, ItemError> {
+    T::deserialize(Deserializer(value))
+}
+
+/// Error that occurs while converting a value to or from an item.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ItemError {
+    /// The value isn't a struct or a map, so it isn't an item.
+    NotAnItem,
+    /// A key of a map isn't a string, a number or a unit variant.
+    InvalidKey,
+    /// A number isn't finite, or can't be read into the type of the value.
+    InvalidNumber(String),
+    /// An attribute value has no type.
+    EmptyAttributeValue,
+    /// The value doesn't match the attribute value, or can't be serialized.
+    Message(String),
+}
+
+impl fmt::Display for ItemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ItemError::NotAnItem => write!(f, "The value isn't a struct or a map"),
+            ItemError::InvalidKey => write!(f, "Keys of maps must be strings or numbers"),
+            ItemError::InvalidNumber(ref number) => write!(f, "Invalid number {}", number),
+            ItemError::EmptyAttributeValue => write!(f, "The attribute value has no type"),
+            ItemError::Message(ref message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for ItemError {}
+
+impl ser::Error for ItemError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ItemError::Message(msg.to_string())
+    }
+}
+
+impl de::Error for ItemError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        ItemError::Message(msg.to_string())
+    }
+}
+
+fn number<T: ToString>(value: T) -> AttributeValue {
+    AttributeValue {
+        n: Some(value.to_string()),
+        ..Default::default()
+    }
+}
+
+fn string(value: String) -> AttributeValue {
+    AttributeValue {
+        s: Some(value),
+        ..Default::default()
+    }
+}
+
+fn null() -> AttributeValue {
+    AttributeValue {
+        null: Some(true),
+        ..Default::default()
+    }
+}
+
+fn list(values: Vec<AttributeValue>) -> AttributeValue {
+    AttributeValue {
+        l: Some(values),
+        ..Default::default()
+    }
+}
+
+fn map(values: Item) -> AttributeValue {
+    AttributeValue {
+        m: Some(values),
+        ..Default::default()
+    }
+}
+
+/// A map of the name of a variant to its value.
+fn variant(name: &str, value: AttributeValue) -> AttributeValue {
+    let mut values = HashMap::with_capacity(1);
+    values.insert(name.to_owned(), value);
+    map(values)
+}
+
+/// Serializes values to attribute values.
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeList;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> Result<AttributeValue, ItemError> {
+        Ok(AttributeValue {
+            bool: Some(v),
+            ..Default::default()
+        })
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<AttributeValue, ItemError> {
+        Ok(number(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<AttributeValue, ItemError> {
+        if !v.is_finite() {
+            return Err(ItemError::InvalidNumber(v.to_string()));
+        }
+        Ok(number(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<AttributeValue, ItemError> {
+        if !v.is_finite() {
+            return Err(ItemError::InvalidNumber(v.to_string()));
+        }
+        Ok(number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<AttributeValue, ItemError> {
+        Ok(string(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<AttributeValue, ItemError> {
+        Ok(string(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<AttributeValue, ItemError> {
+        Ok(AttributeValue {
+            b: Some(bytes::Bytes::copy_from_slice(v)),
+            ..Default::default()
+        })
+    }
+
+    fn serialize_none(self) -> Result<AttributeValue, ItemError> {
+        Ok(null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<AttributeValue, ItemError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<AttributeValue, ItemError> {
+        Ok(null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<AttributeValue, ItemError> {
+        Ok(null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<AttributeValue, ItemError> {
+        Ok(string(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<AttributeValue, ItemError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant_name: &'static str,
+        value: &T,
+    ) -> Result<AttributeValue, ItemError> {
+        Ok(variant(variant_name, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, ItemError> {
+        Ok(SerializeList {
+            variant: None,
+            values: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, ItemError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, ItemError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, ItemError> {
+        Ok(SerializeList {
+            variant: Some(variant),
+            values: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, ItemError> {
+        Ok(SerializeMap {
+            variant: None,
+            values: HashMap::with_capacity(len.unwrap_or(0)),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeMap, ItemError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, ItemError> {
+        Ok(SerializeMap {
+            variant: Some(variant),
+            values: HashMap::with_capacity(len),
+            key: None,
+        })
+    }
+}
+
+/// Serializes sequences, tuples and tuple variants to lists.
+struct SerializeList {
+    variant: Option<&'static str>,
+    values: Vec<AttributeValue>,
+}
+
+impl SerializeList {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.values.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<AttributeValue, ItemError> {
+        let value = list(self.values);
+        Ok(match self.variant {
+            Some(name) => variant(name, value),
+            None => value,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeList {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+/// Serializes maps, structs and struct variants to maps.
+struct SerializeMap {
+    variant: Option<&'static str>,
+    values: Item,
+    key: Option<String>,
+}
+
+impl SerializeMap {
+    fn finish(self) -> Result<AttributeValue, ItemError> {
+        let value = map(self.values);
+        Ok(match self.variant {
+            Some(name) => variant(name, value),
+            None => value,
+        })
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ItemError> {
+        // keys are serialized like values, so numbers and unit variants are keys too
+        let key = key.serialize(Serializer)?;
+        self.key = Some(key.s.or(key.n).ok_or(ItemError::InvalidKey)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ItemError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ItemError::Message("Map value without a key".to_owned()))?;
+        self.values.insert(key, value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ItemError> {
+        self.values
+            .insert(key.to_owned(), value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = AttributeValue;
+    type Error = ItemError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), ItemError> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<AttributeValue, ItemError> {
+        self.finish()
+    }
+}
+
+/// Deserializes values from an attribute value.
+struct Deserializer(AttributeValue);
+
+impl Deserializer {
+    /// The number of an `N` attribute value, parsed as the type of the value.
+    fn parse<T: std::str::FromStr>(&self) -> Result<Option<T>, ItemError> {
+        match self.0.n {
+            Some(ref n) => n
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|_| ItemError::InvalidNumber(n.clone())),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Deserializes numbers of the type of the method, and other values like `deserialize_any`.
+macro_rules! deserialize_number {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+                match self.parse()? {
+                    Some(n) => visitor.$visit(n),
+                    None => self.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = ItemError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        let value = self.0;
+        if let Some(s) = value.s {
+            visitor.visit_string(s)
+        } else if let Some(ref n) = value.n {
+            let n = n.trim();
+            if let Ok(n) = n.parse::<i64>() {
+                visitor.visit_i64(n)
+            } else if let Ok(n) = n.parse::<u64>() {
+                visitor.visit_u64(n)
+            } else {
+                match n.parse::<f64>() {
+                    Ok(n) => visitor.visit_f64(n),
+                    Err(_) => Err(ItemError::InvalidNumber(n.to_owned())),
+                }
+            }
+        } else if let Some(bool) = value.bool {
+            visitor.visit_bool(bool)
+        } else if value.null.is_some() {
+            visitor.visit_unit()
+        } else if let Some(b) = value.b {
+            visitor.visit_byte_buf(b.to_vec())
+        } else if let Some(m) = value.m {
+            visitor.visit_map(MapAccess {
+                entries: m.into_iter(),
+                value: None,
+            })
+        } else if let Some(l) = value.l {
+            visitor.visit_seq(SeqAccess(l.into_iter()))
+        } else if let Some(ss) = value.ss {
+            visitor.visit_seq(SeqAccess(
+                ss.into_iter().map(string).collect::<Vec<_>>().into_iter(),
+            ))
+        } else if let Some(ns) = value.ns {
+            visitor.visit_seq(SeqAccess(
+                ns.into_iter().map(number).collect::<Vec<_>>().into_iter(),
+            ))
+        } else if let Some(bs) = value.bs {
+            let values = bs
+                .into_iter()
+                .map(|b| AttributeValue {
+                    b: Some(b),
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>();
+            visitor.visit_seq(SeqAccess(values.into_iter()))
+        } else {
+            Err(ItemError::EmptyAttributeValue)
+        }
+    }
+
+    deserialize_number! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        if self.0.null.is_some() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        let value = self.0;
+        if let Some(s) = value.s {
+            return visitor.visit_enum(IntoDeserializer::<ItemError>::into_deserializer(s));
+        }
+        let mut m = match value.m {
+            Some(m) if m.len() == 1 => m,
+            _ => {
+                return Err(ItemError::Message(
+                    "An enum must be a string or a map with a single key".to_owned(),
+                ))
+            }
+        };
+        let (variant, value) = m.drain().next().unwrap();
+        visitor.visit_enum(EnumAccess { variant, value })
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool char str string bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+/// The values of a list or set.
+struct SeqAccess(vec::IntoIter<AttributeValue>);
+
+impl<'de> de::SeqAccess<'de> for SeqAccess {
+    type Error = ItemError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, ItemError> {
+        match self.0.next() {
+            Some(value) => seed.deserialize(Deserializer(value)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+/// The attributes of a map.
+struct MapAccess {
+    entries: hash_map::IntoIter<String, AttributeValue>,
+    value: Option<AttributeValue>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess {
+    type Error = ItemError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, ItemError> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(KeyDeserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, ItemError> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Deserializer(value)),
+            None => Err(ItemError::Message("Map value without a key".to_owned())),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+/// Deserializes the keys of maps, which are strings even if they're numbers.
+struct KeyDeserializer(String);
+
+/// Deserializes keys parsed as the type of the method.
+macro_rules! deserialize_number_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+                match self.0.parse() {
+                    Ok(n) => visitor.$visit(n),
+                    Err(_) => Err(ItemError::InvalidNumber(self.0)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = ItemError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ItemError> {
+        visitor.visit_string(self.0)
+    }
+
+    deserialize_number_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        visitor.visit_enum(IntoDeserializer::<ItemError>::into_deserializer(self.0))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool char str string bytes byte_buf option unit unit_struct seq tuple tuple_struct map
+        struct identifier ignored_any
+    }
+}
+
+/// A variant of an enum, a map of its name to its value.
+struct EnumAccess {
+    variant: String,
+    value: AttributeValue,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess {
+    type Error = ItemError;
+    type Variant = Deserializer;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Deserializer), ItemError> {
+        let variant = seed.deserialize(IntoDeserializer::<ItemError>::into_deserializer(
+            self.variant,
+        ))?;
+        Ok((variant, Deserializer(self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer {
+    type Error = ItemError;
+
+    fn unit_variant(self) -> Result<(), ItemError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, ItemError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ItemError> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    enum Status {
+        Active,
+        Suspended { until: String },
+        Renamed(String),
+        Moved(String, u16),
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(tag = "type")]
+    enum Pet {
+        Cat { lives: u8 },
+        Dog,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Address {
+        city: String,
+        zip: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct User {
+        #[serde(rename = "pk")]
+        id: u64,
+        name: String,
+        score: f64,
+        balance: i32,
+        admin: bool,
+        nickname: Option<String>,
+        tags: Vec<String>,
+        address: Address,
+        counts: BTreeMap<u32, u32>,
+        status: Status,
+        history: Vec<Status>,
+        pets: Vec<Pet>,
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        email: Option<String>,
+    }
+
+    fn user() -> User {
+        let mut counts = BTreeMap::new();
+        counts.insert(1, 2);
+        User {
+            id: 42,
+            name: "Joe".to_owned(),
+            score: 3.5,
+            balance: -7,
+            admin: false,
+            nickname: None,
+            tags: vec!["a".to_owned(), "b".to_owned()],
+            address: Address {
+                city: "Seattle".to_owned(),
+                zip: None,
+            },
+            counts,
+            status: Status::Active,
+            history: vec![
+                Status::Suspended {
+                    until: "tomorrow".to_owned(),
+                },
+                Status::Renamed("Jo".to_owned()),
+                Status::Moved("Paris".to_owned(), 75),
+            ],
+            pets: vec![Pet::Cat { lives: 9 }, Pet::Dog],
+            email: None,
+        }
+    }
+
+    #[test]
+    fn items() {
+        let item = to_item(&user()).unwrap();
+        assert_eq!(item["pk"].n.as_deref(), Some("42"));
+        assert_eq!(item["name"].s.as_deref(), Some("Joe"));
+        assert_eq!(item["score"].n.as_deref(), Some("3.5"));
+        assert_eq!(item["balance"].n.as_deref(), Some("-7"));
+        assert_eq!(item["admin"].bool, Some(false));
+        assert_eq!(item["nickname"].null, Some(true));
+        assert_eq!(item["tags"].l.as_ref().unwrap().len(), 2);
+        let address = item["address"].m.as_ref().unwrap();
+        assert_eq!(address["city"].s.as_deref(), Some("Seattle"));
+        assert_eq!(
+            item["counts"].m.as_ref().unwrap()["1"].n.as_deref(),
+            Some("2")
+        );
+        assert_eq!(item["status"].s.as_deref(), Some("active"));
+        let history = item["history"].l.as_ref().unwrap();
+        let suspended = history[0].m.as_ref().unwrap()["suspended"]
+            .m
+            .as_ref()
+            .unwrap();
+        assert_eq!(suspended["until"].s.as_deref(), Some("tomorrow"));
+        assert_eq!(
+            history[1].m.as_ref().unwrap()["renamed"].s.as_deref(),
+            Some("Jo")
+        );
+        assert_eq!(
+            history[2].m.as_ref().unwrap()["moved"].l.as_ref().unwrap()[1]
+                .n
+                .as_deref(),
+            Some("75")
+        );
+        let pets = item["pets"].l.as_ref().unwrap();
+        assert_eq!(
+            pets[0].m.as_ref().unwrap()["type"].s.as_deref(),
+            Some("Cat")
+        );
+        assert!(!item.contains_key("email"));
+
+        assert_eq!(from_item::<User>(item).unwrap(), user());
+    }
+
+    /// Bytes read from `B` attribute values, which `Vec<u8>` isn't.
+    #[derive(Debug, PartialEq)]
+    struct Blob(Vec<u8>);
+
+    impl<'de> Deserialize<'de> for Blob {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Blob, D::Error> {
+            struct BlobVisitor;
+
+            impl<'de> Visitor<'de> for BlobVisitor {
+                type Value = Blob;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "bytes")
+                }
+
+                fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Blob, E> {
+                    Ok(Blob(v))
+                }
+            }
+
+            deserializer.deserialize_byte_buf(BlobVisitor)
+        }
+    }
+
+    #[test]
+    fn sets() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Sets {
+            names: Vec<String>,
+            numbers: Vec<f32>,
+            blobs: Vec<Blob>,
+        }
+
+        let json = r#"{
+            "names": {"SS": ["a", "b"]},
+            "numbers": {"NS": ["1", "2.5"]},
+            "blobs": {"BS": ["Zm9v"]}
+        }"#;
+        let item: Item = serde_json::from_str(json).unwrap();
+        let sets = from_item::<Sets>(item).unwrap();
+        assert_eq!(sets.names, vec!["a", "b"]);
+        assert_eq!(sets.numbers, vec![1.0, 2.5]);
+        assert_eq!(sets.blobs[0].0, b"foo");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(to_item(&"string"), Err(ItemError::NotAnItem));
+        assert_eq!(
+            to_attribute_value(&f64::NAN),
+            Err(ItemError::InvalidNumber("NaN".to_owned()))
+        );
+        let mut keys = HashMap::new();
+        keys.insert(vec![1], 1);
+        assert_eq!(to_item(&keys), Err(ItemError::InvalidKey));
+
+        assert_eq!(
+            from_attribute_value::<u8>(number(256)),
+            Err(ItemError::InvalidNumber("256".to_owned()))
+        );
+        assert_eq!(
+            from_attribute_value::<String>(AttributeValue::default()),
+            Err(ItemError::EmptyAttributeValue)
+        );
+        assert!(from_attribute_value::<String>(number(1)).is_err());
+        assert!(from_attribute_value::<User>(string("user".to_owned())).is_err());
+    }
+}
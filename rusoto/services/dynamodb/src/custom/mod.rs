@@ -1,2 +1,5 @@
+/// Converting items to and from Rust types with serde
+pub mod item;
+
 #[cfg(test)]
 mod custom_tests;
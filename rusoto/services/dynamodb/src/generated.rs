@@ -3170,18 +3170,25 @@ impl BatchGetItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchGetItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetItemError::InternalServerError(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(BatchGetItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetItemError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchGetItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetItemError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchGetItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, BatchGetItemError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3222,23 +3229,34 @@ impl BatchWriteItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchWriteItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchWriteItemError::InternalServerError(err.msg),
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchWriteItemError::ItemCollectionSizeLimitExceeded(err.msg),
                     )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchWriteItemError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchWriteItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchWriteItemError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchWriteItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchWriteItemError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3284,24 +3302,28 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(CreateBackupError::BackupInUse(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::BackupInUse(err.msg))
                 }
                 "ContinuousBackupsUnavailableException" => {
-                    return RusotoError::Service(CreateBackupError::ContinuousBackupsUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::ContinuousBackupsUnavailable(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::LimitExceeded(err.msg))
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(CreateBackupError::TableInUse(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::TableInUse(err.msg))
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(CreateBackupError::TableNotFound(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::TableNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3342,20 +3364,28 @@ impl CreateGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableAlreadyExistsException" => {
-                    return RusotoError::Service(CreateGlobalTableError::GlobalTableAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalTableError::GlobalTableAlreadyExists(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateGlobalTableError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalTableError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGlobalTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalTableError::LimitExceeded(err.msg),
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(CreateGlobalTableError::TableNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalTableError::TableNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3392,13 +3422,16 @@ impl CreateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTableError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateTableError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateTableError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateTableError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3436,16 +3469,19 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(DeleteBackupError::BackupInUse(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::BackupInUse(err.msg))
                 }
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::BackupNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3490,29 +3526,43 @@ impl DeleteItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(DeleteItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteItemError::ConditionalCheckFailed(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteItemError::InternalServerError(err.msg),
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(DeleteItemError::ItemCollectionSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteItemError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(DeleteItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteItemError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteItemError::ResourceNotFound(err.msg))
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(DeleteItemError::TransactionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteItemError::TransactionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3554,16 +3604,19 @@ impl DeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTableError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteTableError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3598,10 +3651,13 @@ impl DescribeBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeBackupError::BackupNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3634,14 +3690,16 @@ impl DescribeContinuousBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContinuousBackupsError::InternalServerError(err.msg),
                     )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(DescribeContinuousBackupsError::TableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContinuousBackupsError::TableNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3678,12 +3736,14 @@ impl DescribeContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContributorInsightsError::InternalServerError(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeContributorInsightsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3742,14 +3802,16 @@ impl DescribeGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(DescribeGlobalTableError::GlobalTableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGlobalTableError::GlobalTableNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeGlobalTableError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGlobalTableError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3784,12 +3846,14 @@ impl DescribeGlobalTableSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGlobalTableSettingsError::GlobalTableNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGlobalTableSettingsError::InternalServerError(err.msg),
                     )
                 }
@@ -3826,7 +3890,10 @@ impl DescribeLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeLimitsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLimitsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3858,10 +3925,16 @@ impl DescribeTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTableError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTableError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3896,12 +3969,14 @@ impl DescribeTableReplicaAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTableReplicaAutoScalingError::InternalServerError(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTableReplicaAutoScalingError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3940,12 +4015,16 @@ impl DescribeTimeToLiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTimeToLiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTimeToLiveError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTimeToLiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTimeToLiveError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3982,18 +4061,19 @@ impl GetItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetItemError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetItemError::InternalServerError(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetItemError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(GetItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetItemError::RequestLimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetItemError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4026,7 +4106,10 @@ impl ListBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListBackupsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBackupsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4058,14 +4141,16 @@ impl ListContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListContributorInsightsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListContributorInsightsError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContributorInsightsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListContributorInsightsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4096,9 +4181,10 @@ impl ListGlobalTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListGlobalTablesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGlobalTablesError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4128,7 +4214,10 @@ impl ListTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTablesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTablesError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4160,12 +4249,16 @@ impl ListTagsOfResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsOfResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsOfResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsOfResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsOfResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4208,29 +4301,34 @@ impl PutItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(PutItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutItemError::ConditionalCheckFailed(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutItemError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, PutItemError::InternalServerError(err.msg))
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(PutItemError::ItemCollectionSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutItemError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(PutItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutItemError::RequestLimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutItemError::ResourceNotFound(err.msg))
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(PutItemError::TransactionConflict(err.msg))
+                    return RusotoError::service(&res, PutItemError::TransactionConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4272,16 +4370,19 @@ impl QueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(QueryError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, QueryError::InternalServerError(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(QueryError::ProvisionedThroughputExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        QueryError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(QueryError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(&res, QueryError::RequestLimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, QueryError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4324,30 +4425,40 @@ impl RestoreTableFromBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::BackupInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableFromBackupError::BackupInUse(err.msg),
+                    )
                 }
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::BackupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableFromBackupError::BackupNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableFromBackupError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableFromBackupError::LimitExceeded(err.msg),
+                    )
                 }
                 "TableAlreadyExistsException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::TableAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableFromBackupError::TableAlreadyExists(err.msg),
+                    )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::TableInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableFromBackupError::TableInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4394,39 +4505,46 @@ impl RestoreTableToPointInTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RestoreTableToPointInTimeError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRestoreTimeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RestoreTableToPointInTimeError::InvalidRestoreTime(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableToPointInTimeError::LimitExceeded(err.msg),
+                    )
                 }
                 "PointInTimeRecoveryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RestoreTableToPointInTimeError::PointInTimeRecoveryUnavailable(err.msg),
                     )
                 }
                 "TableAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RestoreTableToPointInTimeError::TableAlreadyExists(err.msg),
                     )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::TableInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableToPointInTimeError::TableInUse(err.msg),
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::TableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreTableToPointInTimeError::TableNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4472,16 +4590,19 @@ impl ScanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ScanError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ScanError::InternalServerError(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(ScanError::ProvisionedThroughputExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ScanError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(ScanError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(&res, ScanError::RequestLimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ScanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ScanError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4520,16 +4641,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4570,27 +4694,34 @@ impl TransactGetItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TransactGetItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactGetItemsError::InternalServerError(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TransactGetItemsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(TransactGetItemsError::RequestLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactGetItemsError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransactGetItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransactGetItemsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(TransactGetItemsError::TransactionCanceled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactGetItemsError::TransactionCanceled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4638,37 +4769,46 @@ impl TransactWriteItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TransactWriteItemsError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TransactWriteItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactWriteItemsError::InternalServerError(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TransactWriteItemsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(TransactWriteItemsError::RequestLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactWriteItemsError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransactWriteItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransactWriteItemsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(TransactWriteItemsError::TransactionCanceled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactWriteItemsError::TransactionCanceled(err.msg),
+                    )
                 }
                 "TransactionInProgressException" => {
-                    return RusotoError::Service(TransactWriteItemsError::TransactionInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransactWriteItemsError::TransactionInProgress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4714,16 +4854,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4760,19 +4906,22 @@ impl UpdateContinuousBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContinuousBackupsUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateContinuousBackupsError::ContinuousBackupsUnavailable(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateContinuousBackupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContinuousBackupsError::InternalServerError(err.msg),
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(UpdateContinuousBackupsError::TableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContinuousBackupsError::TableNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4808,14 +4957,16 @@ impl UpdateContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateContributorInsightsError::InternalServerError(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContributorInsightsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContributorInsightsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4856,25 +5007,34 @@ impl UpdateGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::GlobalTableNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableError::GlobalTableNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateGlobalTableError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableError::InternalServerError(err.msg),
+                    )
                 }
                 "ReplicaAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::ReplicaAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableError::ReplicaAlreadyExists(err.msg),
+                    )
                 }
                 "ReplicaNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::ReplicaNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableError::ReplicaNotFound(err.msg),
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::TableNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableError::TableNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4918,34 +5078,40 @@ impl UpdateGlobalTableSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGlobalTableSettingsError::GlobalTableNotFound(err.msg),
                     )
                 }
                 "IndexNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::IndexNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableSettingsError::IndexNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGlobalTableSettingsError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableSettingsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ReplicaNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::ReplicaNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableSettingsError::ReplicaNotFound(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalTableSettingsError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4996,29 +5162,43 @@ impl UpdateItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(UpdateItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateItemError::ConditionalCheckFailed(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateItemError::InternalServerError(err.msg),
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(UpdateItemError::ItemCollectionSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(UpdateItemError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateItemError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(UpdateItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateItemError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateItemError::ResourceNotFound(err.msg))
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(UpdateItemError::TransactionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateItemError::TransactionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5060,16 +5240,19 @@ impl UpdateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateTableError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTableError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTableError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5110,22 +5293,26 @@ impl UpdateTableReplicaAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTableReplicaAutoScalingError::InternalServerError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTableReplicaAutoScalingError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTableReplicaAutoScalingError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTableReplicaAutoScalingError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTableReplicaAutoScalingError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTableReplicaAutoScalingError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5170,18 +5357,28 @@ impl UpdateTimeToLiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTimeToLiveError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTimeToLiveError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTimeToLiveError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTimeToLiveError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5464,6 +5661,7 @@ impl DynamoDbClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5479,6 +5677,7 @@ impl DynamoDbClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
         DynamoDbClient { client, region }
     }
@@ -5492,6 +5691,9 @@ impl DynamoDb for DynamoDbClient {
         input: BatchGetItemInput,
     ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetItem");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchGetItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5510,6 +5712,9 @@ impl DynamoDb for DynamoDbClient {
         input: BatchWriteItemInput,
     ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchWriteItem");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchWriteItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5528,6 +5733,9 @@ impl DynamoDb for DynamoDbClient {
         input: CreateBackupInput,
     ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5546,6 +5754,9 @@ impl DynamoDb for DynamoDbClient {
         input: CreateGlobalTableInput,
     ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGlobalTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5564,6 +5775,9 @@ impl DynamoDb for DynamoDbClient {
         input: CreateTableInput,
     ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5582,6 +5796,9 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteBackupInput,
     ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5600,6 +5817,9 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteItemInput,
     ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteItem");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5618,6 +5838,9 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteTableInput,
     ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5636,6 +5859,9 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeBackupInput,
     ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBackup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5654,6 +5880,9 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeContinuousBackupsInput,
     ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeContinuousBackups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeContinuousBackups",
@@ -5677,6 +5906,9 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeContributorInsights");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeContributorInsights",
@@ -5698,6 +5930,9 @@ impl DynamoDb for DynamoDbClient {
         &self,
     ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeEndpoints");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -5715,6 +5950,9 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeGlobalTableInput,
     ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGlobalTable");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5734,6 +5972,9 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGlobalTableSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeGlobalTableSettings",
@@ -5755,6 +5996,9 @@ impl DynamoDb for DynamoDbClient {
         &self,
     ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -5772,6 +6016,9 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeTableInput,
     ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTable");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5793,6 +6040,9 @@ impl DynamoDb for DynamoDbClient {
         RusotoError<DescribeTableReplicaAutoScalingError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTableReplicaAutoScaling");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeTableReplicaAutoScaling",
@@ -5815,6 +6065,9 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeTimeToLiveInput,
     ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTimeToLive");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTimeToLive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5833,6 +6086,9 @@ impl DynamoDb for DynamoDbClient {
         input: GetItemInput,
     ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetItem");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5851,6 +6107,9 @@ impl DynamoDb for DynamoDbClient {
         input: ListBackupsInput,
     ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBackups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5869,6 +6128,9 @@ impl DynamoDb for DynamoDbClient {
         input: ListContributorInsightsInput,
     ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListContributorInsights");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.ListContributorInsights");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5888,6 +6150,9 @@ impl DynamoDb for DynamoDbClient {
         input: ListGlobalTablesInput,
     ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGlobalTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.ListGlobalTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5906,6 +6171,9 @@ impl DynamoDb for DynamoDbClient {
         input: ListTablesInput,
     ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5924,6 +6192,9 @@ impl DynamoDb for DynamoDbClient {
         input: ListTagsOfResourceInput,
     ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsOfResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTagsOfResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5942,6 +6213,9 @@ impl DynamoDb for DynamoDbClient {
         input: PutItemInput,
     ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutItem");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.PutItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5957,6 +6231,9 @@ impl DynamoDb for DynamoDbClient {
     /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
     async fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Query");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.Query");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5975,6 +6252,9 @@ impl DynamoDb for DynamoDbClient {
         input: RestoreTableFromBackupInput,
     ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreTableFromBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.RestoreTableFromBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5994,6 +6274,9 @@ impl DynamoDb for DynamoDbClient {
         input: RestoreTableToPointInTimeInput,
     ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreTableToPointInTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.RestoreTableToPointInTime",
@@ -6013,6 +6296,9 @@ impl DynamoDb for DynamoDbClient {
     /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
     async fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Scan");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDB_20120810.Scan");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6031,6 +6317,9 @@ impl DynamoDb for DynamoDbClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6048,6 +6337,9 @@ impl DynamoDb for DynamoDbClient {
         input: TransactGetItemsInput,
     ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TransactGetItems");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactGetItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6066,6 +6358,9 @@ impl DynamoDb for DynamoDbClient {
         input: TransactWriteItemsInput,
     ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TransactWriteItems");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6084,6 +6379,9 @@ impl DynamoDb for DynamoDbClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6101,6 +6399,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateContinuousBackupsInput,
     ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateContinuousBackups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateContinuousBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6120,6 +6421,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateContributorInsightsInput,
     ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateContributorInsights");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.UpdateContributorInsights",
@@ -6142,6 +6446,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateGlobalTableInput,
     ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGlobalTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6160,6 +6467,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateGlobalTableSettingsInput,
     ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGlobalTableSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.UpdateGlobalTableSettings",
@@ -6182,6 +6492,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateItemInput,
     ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateItem");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6200,6 +6513,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateTableInput,
     ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6219,6 +6535,9 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTableReplicaAutoScaling");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.UpdateTableReplicaAutoScaling",
@@ -6241,6 +6560,9 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateTimeToLiveInput,
     ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTimeToLive");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTimeToLive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6253,3 +6575,479 @@ impl DynamoDb for DynamoDbClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTimeToLiveOutput, _>()
     }
 }
+
+/// A blocking client for the DynamoDB API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DynamoDbClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DynamoDbBlockingClient {
+    client: DynamoDbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DynamoDbBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DynamoDbBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DynamoDbBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamoDbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DynamoDbBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient {
+            client: DynamoDbClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>The <code>BatchGetItem</code> operation returns the attributes of one or more items from one or more tables. You identify requested items by primary key.</p> <p>A single operation can retrieve up to 16 MB of data, which can contain as many as 100 items. <code>BatchGetItem</code> returns a partial result if the response size limit is exceeded, the table's provisioned throughput is exceeded, or an internal processing failure occurs. If a partial result is returned, the operation returns a value for <code>UnprocessedKeys</code>. You can use this value to retry the operation starting with the next item to get.</p> <important> <p>If you request more than 100 items, <code>BatchGetItem</code> returns a <code>ValidationException</code> with the message "Too many items requested for the BatchGetItem call."</p> </important> <p>For example, if you ask to retrieve 100 items, but each individual item is 300 KB in size, the system returns 52 items (so as not to exceed the 16 MB limit). It also returns an appropriate <code>UnprocessedKeys</code> value so you can get the next page of results. If desired, your application can include its own logic to assemble the pages of results into one dataset.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchGetItem</code> returns a <code>ProvisionedThroughputExceededException</code>. If <i>at least one</i> of the items is successfully processed, then <code>BatchGetItem</code> completes successfully, while returning the keys of the unread items in <code>UnprocessedKeys</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>By default, <code>BatchGetItem</code> performs eventually consistent reads on every table in the request. If you want strongly consistent reads instead, you can set <code>ConsistentRead</code> to <code>true</code> for any or all tables.</p> <p>In order to minimize response latency, <code>BatchGetItem</code> retrieves items in parallel.</p> <p>When designing your application, keep in mind that DynamoDB does not return items in any particular order. To help parse the response by item, include the primary key values for the items in your request in the <code>ProjectionExpression</code> parameter.</p> <p>If a requested item does not exist, it is not returned in the result. Requests for nonexistent items consume the minimum read capacity units according to the type of read. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithTables.html#CapacityUnitCalculations">Working with Tables</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// See `DynamoDb::batch_get_item`.
+    pub fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
+        self.runtime.block_on(self.client.batch_get_item(input))
+    }
+
+    /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::batch_write_item`.
+    pub fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
+        self.runtime.block_on(self.client.batch_write_item(input))
+    }
+
+    /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::create_backup`.
+    pub fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
+        self.runtime.block_on(self.client.create_backup(input))
+    }
+
+    /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <note> <p>This operation only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <p> If local secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The local secondary indexes must have the same name. </p> </li> <li> <p> The local secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
+    ///
+    /// See `DynamoDb::create_global_table`.
+    pub fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
+        self.runtime
+            .block_on(self.client.create_global_table(input))
+    }
+
+    /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
+    ///
+    /// See `DynamoDb::create_table`.
+    pub fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
+        self.runtime.block_on(self.client.create_table(input))
+    }
+
+    /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
+    ///
+    /// See `DynamoDb::delete_backup`.
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
+    ///
+    /// See `DynamoDb::delete_item`.
+    pub fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
+        self.runtime.block_on(self.client.delete_item(input))
+    }
+
+    /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
+    ///
+    /// See `DynamoDb::delete_table`.
+    pub fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
+        self.runtime.block_on(self.client.delete_table(input))
+    }
+
+    /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
+    ///
+    /// See `DynamoDb::describe_backup`.
+    pub fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
+        self.runtime.block_on(self.client.describe_backup(input))
+    }
+
+    /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
+    ///
+    /// See `DynamoDb::describe_continuous_backups`.
+    pub fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
+        self.runtime
+            .block_on(self.client.describe_continuous_backups(input))
+    }
+
+    /// <p>Returns information about contributor insights, for a given table or global secondary index.</p>
+    ///
+    /// See `DynamoDb::describe_contributor_insights`.
+    pub fn describe_contributor_insights(
+        &self,
+        input: DescribeContributorInsightsInput,
+    ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_contributor_insights(input))
+    }
+
+    /// <p>Returns the regional endpoint information.</p>
+    ///
+    /// See `DynamoDb::describe_endpoints`.
+    pub fn describe_endpoints(
+        &self,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.runtime.block_on(self.client.describe_endpoints())
+    }
+
+    /// <p><p>Returns information about the specified global table.</p> <note> <p>This operation only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables. If you are using global tables <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> you can use <a href="https://docs.aws.amazon.com/amazondynamodb/latest/APIReference/API_DescribeTable.html">DescribeTable</a> instead.</p> </note></p>
+    ///
+    /// See `DynamoDb::describe_global_table`.
+    pub fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
+        self.runtime
+            .block_on(self.client.describe_global_table(input))
+    }
+
+    /// <p><p>Describes Region-specific settings for a global table.</p> <note> <p>This operation only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
+    ///
+    /// See `DynamoDb::describe_global_table_settings`.
+    pub fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_global_table_settings(input))
+    }
+
+    /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
+    ///
+    /// See `DynamoDb::describe_limits`.
+    pub fn describe_limits(
+        &self,
+    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        self.runtime.block_on(self.client.describe_limits())
+    }
+
+    /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
+    ///
+    /// See `DynamoDb::describe_table`.
+    pub fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
+        self.runtime.block_on(self.client.describe_table(input))
+    }
+
+    /// <p><p>Describes auto scaling settings across replicas of the global table at once.</p> <note> <p>This operation only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> of global tables.</p> </note></p>
+    ///
+    /// See `DynamoDb::describe_table_replica_auto_scaling`.
+    pub fn describe_table_replica_auto_scaling(
+        &self,
+        input: DescribeTableReplicaAutoScalingInput,
+    ) -> Result<
+        DescribeTableReplicaAutoScalingOutput,
+        RusotoError<DescribeTableReplicaAutoScalingError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_table_replica_auto_scaling(input))
+    }
+
+    /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
+    ///
+    /// See `DynamoDb::describe_time_to_live`.
+    pub fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
+        self.runtime
+            .block_on(self.client.describe_time_to_live(input))
+    }
+
+    /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
+    ///
+    /// See `DynamoDb::get_item`.
+    pub fn get_item(
+        &self,
+        input: GetItemInput,
+    ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
+        self.runtime.block_on(self.client.get_item(input))
+    }
+
+    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
+    ///
+    /// See `DynamoDb::list_backups`.
+    pub fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
+        self.runtime.block_on(self.client.list_backups(input))
+    }
+
+    /// <p>Returns a list of ContributorInsightsSummary for a table and all its global secondary indexes.</p>
+    ///
+    /// See `DynamoDb::list_contributor_insights`.
+    pub fn list_contributor_insights(
+        &self,
+        input: ListContributorInsightsInput,
+    ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
+        self.runtime
+            .block_on(self.client.list_contributor_insights(input))
+    }
+
+    /// <p><p>Lists all global tables that have a replica in the specified Region.</p> <note> <p>This operation only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V1.html">Version 2017.11.29</a> of global tables.</p> </note></p>
+    ///
+    /// See `DynamoDb::list_global_tables`.
+    pub fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
+        self.runtime.block_on(self.client.list_global_tables(input))
+    }
+
+    /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
+    ///
+    /// See `DynamoDb::list_tables`.
+    pub fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
+        self.runtime.block_on(self.client.list_tables(input))
+    }
+
+    /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// See `DynamoDb::list_tags_of_resource`.
+    pub fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_of_resource(input))
+    }
+
+    /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null.</p> <p>Empty String and Binary attribute values are allowed. Attribute values of type String and Binary must have a length greater than zero if the attribute is used as a key attribute for a table or index. Set type attributes cannot be empty. </p> <p>Invalid Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// See `DynamoDb::put_item`.
+    pub fn put_item(
+        &self,
+        input: PutItemInput,
+    ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
+        self.runtime.block_on(self.client.put_item(input))
+    }
+
+    /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
+    ///
+    /// See `DynamoDb::query`.
+    pub fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
+        self.runtime.block_on(self.client.query(input))
+    }
+
+    /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::restore_table_from_backup`.
+    pub fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
+        self.runtime
+            .block_on(self.client.restore_table_from_backup(input))
+    }
+
+    /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::restore_table_to_point_in_time`.
+    pub fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
+        self.runtime
+            .block_on(self.client.restore_table_to_point_in_time(input))
+    }
+
+    /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
+    ///
+    /// See `DynamoDb::scan`.
+    pub fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
+        self.runtime.block_on(self.client.scan(input))
+    }
+
+    /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// See `DynamoDb::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::transact_get_items`.
+    pub fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
+        self.runtime.block_on(self.client.transact_get_items(input))
+    }
+
+    /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::transact_write_items`.
+    pub fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
+        self.runtime
+            .block_on(self.client.transact_write_items(input))
+    }
+
+    /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// See `DynamoDb::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
+    ///
+    /// See `DynamoDb::update_continuous_backups`.
+    pub fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
+        self.runtime
+            .block_on(self.client.update_continuous_backups(input))
+    }
+
+    /// <p>Updates the status for contributor insights for a specific table or index.</p>
+    ///
+    /// See `DynamoDb::update_contributor_insights`.
+    pub fn update_contributor_insights(
+        &self,
+        input: UpdateContributorInsightsInput,
+    ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
+        self.runtime
+            .block_on(self.client.update_contributor_insights(input))
+    }
+
+    /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
+    ///
+    /// See `DynamoDb::update_global_table`.
+    pub fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
+        self.runtime
+            .block_on(self.client.update_global_table(input))
+    }
+
+    /// <p>Updates settings for a global table.</p>
+    ///
+    /// See `DynamoDb::update_global_table_settings`.
+    pub fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_global_table_settings(input))
+    }
+
+    /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
+    ///
+    /// See `DynamoDb::update_item`.
+    pub fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
+        self.runtime.block_on(self.client.update_item(input))
+    }
+
+    /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
+    ///
+    /// See `DynamoDb::update_table`.
+    pub fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
+        self.runtime.block_on(self.client.update_table(input))
+    }
+
+    /// <p><p>Updates auto scaling settings on your global tables at once.</p> <note> <p>This operation only applies to <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/globaltables.V2.html">Version 2019.11.21</a> of global tables.</p> </note></p>
+    ///
+    /// See `DynamoDb::update_table_replica_auto_scaling`.
+    pub fn update_table_replica_auto_scaling(
+        &self,
+        input: UpdateTableReplicaAutoScalingInput,
+    ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
+    {
+        self.runtime
+            .block_on(self.client.update_table_replica_auto_scaling(input))
+    }
+
+    /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
+    ///
+    /// See `DynamoDb::update_time_to_live`.
+    pub fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
+        self.runtime
+            .block_on(self.client.update_time_to_live(input))
+    }
+}
@@ -422,10 +422,16 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeStreamError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -464,19 +470,22 @@ impl GetRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredIteratorException" => {
-                    return RusotoError::Service(GetRecordsError::ExpiredIterator(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::ExpiredIterator(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetRecordsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRecordsError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRecordsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::ResourceNotFound(err.msg))
                 }
                 "TrimmedDataAccessException" => {
-                    return RusotoError::Service(GetRecordsError::TrimmedDataAccess(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::TrimmedDataAccess(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -514,15 +523,22 @@ impl GetShardIteratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetShardIteratorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetShardIteratorError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetShardIteratorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TrimmedDataAccessException" => {
-                    return RusotoError::Service(GetShardIteratorError::TrimmedDataAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetShardIteratorError::TrimmedDataAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -556,10 +572,13 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListStreamsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamsError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListStreamsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -623,6 +642,7 @@ impl DynamoDbStreamsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -638,6 +658,7 @@ impl DynamoDbStreamsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbStreamsClient {
         DynamoDbStreamsClient { client, region }
     }
@@ -651,6 +672,9 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: DescribeStreamInput,
     ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.DescribeStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -669,6 +693,9 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: GetRecordsInput,
     ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRecords");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -687,6 +714,9 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: GetShardIteratorInput,
     ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetShardIterator");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetShardIterator");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -705,6 +735,9 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: ListStreamsInput,
     ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.ListStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -717,3 +750,96 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ListStreamsOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon DynamoDB Streams API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `DynamoDbStreamsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DynamoDbStreamsBlockingClient {
+    client: DynamoDbStreamsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DynamoDbStreamsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DynamoDbStreamsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        DynamoDbStreamsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamoDbStreamsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DynamoDbStreamsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient {
+            client: DynamoDbStreamsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns information about a stream, including the current status of the stream, its Amazon Resource Name (ARN), the composition of its shards, and its corresponding DynamoDB table.</p> <note> <p>You can call <code>DescribeStream</code> at a maximum rate of 10 times per second.</p> </note> <p>Each shard in the stream has a <code>SequenceNumberRange</code> associated with it. If the <code>SequenceNumberRange</code> has a <code>StartingSequenceNumber</code> but no <code>EndingSequenceNumber</code>, then the shard is still open (able to receive more stream records). If both <code>StartingSequenceNumber</code> and <code>EndingSequenceNumber</code> are present, then that shard is closed and can no longer receive more data.</p>
+    ///
+    /// See `DynamoDbStreams::describe_stream`.
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// <p><p>Retrieves the stream records from a given shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading stream records sequentially. If there are no stream records available in the portion of the shard that the iterator points to, <code>GetRecords</code> returns an empty list. Note that it might take multiple calls to get to a portion of the shard that contains stream records.</p> <note> <p> <code>GetRecords</code> can retrieve a maximum of 1 MB of data or 1000 stream records, whichever comes first.</p> </note></p>
+    ///
+    /// See `DynamoDbStreams::get_records`.
+    pub fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+        self.runtime.block_on(self.client.get_records(input))
+    }
+
+    /// <p><p>Returns a shard iterator. A shard iterator provides information about how to retrieve the stream records from within a shard. Use the shard iterator in a subsequent <code>GetRecords</code> request to read the stream records from the shard.</p> <note> <p>A shard iterator expires 15 minutes after it is returned to the requester.</p> </note></p>
+    ///
+    /// See `DynamoDbStreams::get_shard_iterator`.
+    pub fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+        self.runtime.block_on(self.client.get_shard_iterator(input))
+    }
+
+    /// <p><p>Returns an array of stream ARNs associated with the current account and endpoint. If the <code>TableName</code> parameter is present, then <code>ListStreams</code> will return only the streams ARNs for that table.</p> <note> <p>You can call <code>ListStreams</code> at a maximum rate of 5 times per second.</p> </note></p>
+    ///
+    /// See `DynamoDbStreams::list_streams`.
+    pub fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+}
@@ -368,21 +368,31 @@ impl CompleteSnapshotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CompleteSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CompleteSnapshotError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CompleteSnapshotError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteSnapshotError::InternalServer(err.msg),
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(CompleteSnapshotError::RequestThrottled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteSnapshotError::RequestThrottled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteSnapshotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteSnapshotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CompleteSnapshotError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteSnapshotError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -424,21 +434,31 @@ impl GetSnapshotBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetSnapshotBlockError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSnapshotBlockError::InternalServer(err.msg),
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::RequestThrottled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSnapshotBlockError::RequestThrottled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSnapshotBlockError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSnapshotBlockError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -480,21 +500,34 @@ impl ListChangedBlocksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChangedBlocksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChangedBlocksError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListChangedBlocksError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChangedBlocksError::InternalServer(err.msg),
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(ListChangedBlocksError::RequestThrottled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChangedBlocksError::RequestThrottled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListChangedBlocksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChangedBlocksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(ListChangedBlocksError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListChangedBlocksError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -536,21 +569,34 @@ impl ListSnapshotBlocksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSnapshotBlocksError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSnapshotBlocksError::InternalServer(err.msg),
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::RequestThrottled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSnapshotBlocksError::RequestThrottled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSnapshotBlocksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSnapshotBlocksError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -592,21 +638,31 @@ impl PutSnapshotBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, PutSnapshotBlockError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSnapshotBlockError::InternalServer(err.msg),
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::RequestThrottled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSnapshotBlockError::RequestThrottled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSnapshotBlockError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutSnapshotBlockError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -652,27 +708,37 @@ impl StartSnapshotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StartSnapshotError::AccessDenied(err.msg))
                 }
                 "ConcurrentLimitExceededException" => {
-                    return RusotoError::Service(StartSnapshotError::ConcurrentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSnapshotError::ConcurrentLimitExceeded(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartSnapshotError::Conflict(err.msg))
+                    return RusotoError::service(&res, StartSnapshotError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSnapshotError::InternalServer(err.msg))
+                    return RusotoError::service(&res, StartSnapshotError::InternalServer(err.msg))
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(StartSnapshotError::RequestThrottled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSnapshotError::RequestThrottled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartSnapshotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSnapshotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartSnapshotError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSnapshotError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -753,6 +819,7 @@ impl EbsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -768,6 +835,7 @@ impl EbsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EbsClient {
         EbsClient { client, region }
     }
@@ -787,6 +855,9 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
+        request.set_operation_name("CompleteSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header(
@@ -833,6 +904,9 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation_name("GetSnapshotBlock");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -876,6 +950,9 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation_name("ListChangedBlocks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -922,6 +999,9 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation_name("ListSnapshotBlocks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -966,6 +1046,9 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ebs", &self.region, &request_uri);
+        request.set_operation_name("PutSnapshotBlock");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.block_data.to_owned());
@@ -1006,6 +1089,9 @@ impl Ebs for EbsClient {
         let request_uri = "/snapshots";
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
+        request.set_operation_name("StartSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1028,3 +1114,115 @@ impl Ebs for EbsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon EBS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EbsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EbsBlockingClient {
+    client: EbsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EbsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EbsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EbsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EbsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EbsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EbsBlockingClient {
+        EbsBlockingClient {
+            client: EbsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Seals and completes the snapshot after all of the required blocks of data have been written to it. Completing the snapshot changes the status to <code>completed</code>. You cannot write new blocks to a snapshot after it has been completed.</p>
+    ///
+    /// See `Ebs::complete_snapshot`.
+    pub fn complete_snapshot(
+        &self,
+        input: CompleteSnapshotRequest,
+    ) -> Result<CompleteSnapshotResponse, RusotoError<CompleteSnapshotError>> {
+        self.runtime.block_on(self.client.complete_snapshot(input))
+    }
+
+    /// <p>Returns the data in a block in an Amazon Elastic Block Store snapshot.</p>
+    ///
+    /// See `Ebs::get_snapshot_block`.
+    pub fn get_snapshot_block(
+        &self,
+        input: GetSnapshotBlockRequest,
+    ) -> Result<GetSnapshotBlockResponse, RusotoError<GetSnapshotBlockError>> {
+        self.runtime.block_on(self.client.get_snapshot_block(input))
+    }
+
+    /// <p>Returns the block indexes and block tokens for blocks that are different between two Amazon Elastic Block Store snapshots of the same volume/snapshot lineage.</p>
+    ///
+    /// See `Ebs::list_changed_blocks`.
+    pub fn list_changed_blocks(
+        &self,
+        input: ListChangedBlocksRequest,
+    ) -> Result<ListChangedBlocksResponse, RusotoError<ListChangedBlocksError>> {
+        self.runtime
+            .block_on(self.client.list_changed_blocks(input))
+    }
+
+    /// <p>Returns the block indexes and block tokens for blocks in an Amazon Elastic Block Store snapshot.</p>
+    ///
+    /// See `Ebs::list_snapshot_blocks`.
+    pub fn list_snapshot_blocks(
+        &self,
+        input: ListSnapshotBlocksRequest,
+    ) -> Result<ListSnapshotBlocksResponse, RusotoError<ListSnapshotBlocksError>> {
+        self.runtime
+            .block_on(self.client.list_snapshot_blocks(input))
+    }
+
+    /// <p>Writes a block of data to a block in the snapshot. If the specified block contains data, the existing data is overwritten. The target snapshot must be in the <code>pending</code> state.</p> <p>Data written to a snapshot must be aligned with 512-byte sectors.</p>
+    ///
+    /// See `Ebs::put_snapshot_block`.
+    pub fn put_snapshot_block(
+        &self,
+        input: PutSnapshotBlockRequest,
+    ) -> Result<PutSnapshotBlockResponse, RusotoError<PutSnapshotBlockError>> {
+        self.runtime.block_on(self.client.put_snapshot_block(input))
+    }
+
+    /// <p>Creates a new Amazon EBS snapshot. The new snapshot enters the <code>pending</code> state after the request completes. </p> <p>After creating the snapshot, use <a href="https://docs.aws.amazon.com/ebs/latest/APIReference/API_PutSnapshotBlock.html"> PutSnapshotBlock</a> to write blocks of data to the snapshot.</p>
+    ///
+    /// See `Ebs::start_snapshot`.
+    pub fn start_snapshot(
+        &self,
+        input: StartSnapshotRequest,
+    ) -> Result<StartSnapshotResponse, RusotoError<StartSnapshotError>> {
+        self.runtime.block_on(self.client.start_snapshot(input))
+    }
+}
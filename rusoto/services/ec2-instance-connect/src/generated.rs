@@ -105,21 +105,22 @@ impl SendSSHPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Auth(err.msg))
+                    return RusotoError::service(&res, SendSSHPublicKeyError::Auth(err.msg))
                 }
                 "EC2InstanceNotFoundException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::EC2InstanceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendSSHPublicKeyError::EC2InstanceNotFound(err.msg),
+                    )
                 }
                 "InvalidArgsException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::InvalidArgs(err.msg))
+                    return RusotoError::service(&res, SendSSHPublicKeyError::InvalidArgs(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Service(err.msg))
+                    return RusotoError::service(&res, SendSSHPublicKeyError::Service(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service(&res, SendSSHPublicKeyError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -168,6 +169,7 @@ impl Ec2InstanceConnectClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -183,6 +185,7 @@ impl Ec2InstanceConnectClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Ec2InstanceConnectClient {
         Ec2InstanceConnectClient { client, region }
     }
@@ -196,6 +199,9 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         input: SendSSHPublicKeyRequest,
     ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendSSHPublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSEC2InstanceConnectService.SendSSHPublicKey",
@@ -211,3 +217,67 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         proto::json::ResponsePayload::new(&response).deserialize::<SendSSHPublicKeyResponse, _>()
     }
 }
+
+/// A blocking client for the EC2 Instance Connect API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Ec2InstanceConnectClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Ec2InstanceConnectBlockingClient {
+    client: Ec2InstanceConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Ec2InstanceConnectBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Ec2InstanceConnectBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Ec2InstanceConnectBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Ec2InstanceConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Ec2InstanceConnectBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient {
+            client: Ec2InstanceConnectClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Pushes an SSH public key to a particular OS user on a given EC2 instance for 60 seconds.</p>
+    ///
+    /// See `Ec2InstanceConnect::send_ssh_public_key`.
+    pub fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.send_ssh_public_key(input))
+    }
+}
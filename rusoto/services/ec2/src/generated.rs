@@ -83898,6 +83898,7 @@ impl Ec2Client {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -83913,6 +83914,7 @@ impl Ec2Client {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Ec2Client {
         Ec2Client { client, region }
     }
@@ -83929,6 +83931,9 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptReservedInstancesExchangeQuoteError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AcceptReservedInstancesExchangeQuote");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AcceptReservedInstancesExchangeQuote");
         let mut params = params;
         AcceptReservedInstancesExchangeQuoteRequestSerializer::serialize(&mut params, "", &input);
@@ -83964,6 +83969,9 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AcceptTransitGatewayPeeringAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AcceptTransitGatewayPeeringAttachment");
         let mut params = params;
         AcceptTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -83999,6 +84007,9 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AcceptTransitGatewayVpcAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AcceptTransitGatewayVpcAttachment");
         let mut params = params;
         AcceptTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -84029,6 +84040,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<AcceptVpcEndpointConnectionsResult, RusotoError<AcceptVpcEndpointConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AcceptVpcEndpointConnections");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AcceptVpcEndpointConnections");
         let mut params = params;
         AcceptVpcEndpointConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -84056,6 +84070,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<AcceptVpcPeeringConnectionResult, RusotoError<AcceptVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AcceptVpcPeeringConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AcceptVpcPeeringConnection");
         let mut params = params;
         AcceptVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -84082,6 +84099,9 @@ impl Ec2 for Ec2Client {
         input: AdvertiseByoipCidrRequest,
     ) -> Result<AdvertiseByoipCidrResult, RusotoError<AdvertiseByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AdvertiseByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AdvertiseByoipCidr");
         let mut params = params;
         AdvertiseByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -84108,6 +84128,9 @@ impl Ec2 for Ec2Client {
         input: AllocateAddressRequest,
     ) -> Result<AllocateAddressResult, RusotoError<AllocateAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AllocateAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AllocateAddress");
         let mut params = params;
         AllocateAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -84134,6 +84157,9 @@ impl Ec2 for Ec2Client {
         input: AllocateHostsRequest,
     ) -> Result<AllocateHostsResult, RusotoError<AllocateHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AllocateHosts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AllocateHosts");
         let mut params = params;
         AllocateHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -84163,6 +84189,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ApplySecurityGroupsToClientVpnTargetNetworkError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ApplySecurityGroupsToClientVpnTargetNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ApplySecurityGroupsToClientVpnTargetNetwork");
         let mut params = params;
         ApplySecurityGroupsToClientVpnTargetNetworkRequestSerializer::serialize(
@@ -84199,6 +84228,9 @@ impl Ec2 for Ec2Client {
         input: AssignIpv6AddressesRequest,
     ) -> Result<AssignIpv6AddressesResult, RusotoError<AssignIpv6AddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssignIpv6Addresses");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssignIpv6Addresses");
         let mut params = params;
         AssignIpv6AddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -84225,6 +84257,9 @@ impl Ec2 for Ec2Client {
         input: AssignPrivateIpAddressesRequest,
     ) -> Result<AssignPrivateIpAddressesResult, RusotoError<AssignPrivateIpAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssignPrivateIpAddresses");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssignPrivateIpAddresses");
         let mut params = params;
         AssignPrivateIpAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -84251,6 +84286,9 @@ impl Ec2 for Ec2Client {
         input: AssociateAddressRequest,
     ) -> Result<AssociateAddressResult, RusotoError<AssociateAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateAddress");
         let mut params = params;
         AssociateAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -84280,6 +84318,9 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateClientVpnTargetNetworkError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateClientVpnTargetNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("AssociateClientVpnTargetNetwork");
         let mut params = params;
         AssociateClientVpnTargetNetworkRequestSerializer::serialize(&mut params, "", &input);
@@ -84306,6 +84347,9 @@ impl Ec2 for Ec2Client {
         input: AssociateDhcpOptionsRequest,
     ) -> Result<(), RusotoError<AssociateDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateDhcpOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateDhcpOptions");
         let mut params = params;
         AssociateDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -84327,6 +84371,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<AssociateIamInstanceProfileResult, RusotoError<AssociateIamInstanceProfileError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateIamInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateIamInstanceProfile");
         let mut params = params;
         AssociateIamInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -84353,6 +84400,9 @@ impl Ec2 for Ec2Client {
         input: AssociateRouteTableRequest,
     ) -> Result<AssociateRouteTableResult, RusotoError<AssociateRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateRouteTable");
         let mut params = params;
         AssociateRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -84379,6 +84429,9 @@ impl Ec2 for Ec2Client {
         input: AssociateSubnetCidrBlockRequest,
     ) -> Result<AssociateSubnetCidrBlockResult, RusotoError<AssociateSubnetCidrBlockError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateSubnetCidrBlock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateSubnetCidrBlock");
         let mut params = params;
         AssociateSubnetCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -84408,6 +84461,9 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateTransitGatewayMulticastDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateTransitGatewayMulticastDomain");
         let mut params = params;
         AssociateTransitGatewayMulticastDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -84443,6 +84499,9 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateTransitGatewayRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateTransitGatewayRouteTable");
         let mut params = params;
         AssociateTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -84472,6 +84531,9 @@ impl Ec2 for Ec2Client {
         input: AssociateVpcCidrBlockRequest,
     ) -> Result<AssociateVpcCidrBlockResult, RusotoError<AssociateVpcCidrBlockError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AssociateVpcCidrBlock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateVpcCidrBlock");
         let mut params = params;
         AssociateVpcCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -84498,6 +84560,9 @@ impl Ec2 for Ec2Client {
         input: AttachClassicLinkVpcRequest,
     ) -> Result<AttachClassicLinkVpcResult, RusotoError<AttachClassicLinkVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AttachClassicLinkVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachClassicLinkVpc");
         let mut params = params;
         AttachClassicLinkVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -84524,6 +84589,9 @@ impl Ec2 for Ec2Client {
         input: AttachInternetGatewayRequest,
     ) -> Result<(), RusotoError<AttachInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AttachInternetGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachInternetGateway");
         let mut params = params;
         AttachInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -84544,6 +84612,9 @@ impl Ec2 for Ec2Client {
         input: AttachNetworkInterfaceRequest,
     ) -> Result<AttachNetworkInterfaceResult, RusotoError<AttachNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AttachNetworkInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachNetworkInterface");
         let mut params = params;
         AttachNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -84570,6 +84641,9 @@ impl Ec2 for Ec2Client {
         input: AttachVolumeRequest,
     ) -> Result<VolumeAttachment, RusotoError<AttachVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AttachVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachVolume");
         let mut params = params;
         AttachVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -84596,6 +84670,9 @@ impl Ec2 for Ec2Client {
         input: AttachVpnGatewayRequest,
     ) -> Result<AttachVpnGatewayResult, RusotoError<AttachVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AttachVpnGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachVpnGateway");
         let mut params = params;
         AttachVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -84622,6 +84699,9 @@ impl Ec2 for Ec2Client {
         input: AuthorizeClientVpnIngressRequest,
     ) -> Result<AuthorizeClientVpnIngressResult, RusotoError<AuthorizeClientVpnIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AuthorizeClientVpnIngress");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("AuthorizeClientVpnIngress");
         let mut params = params;
         AuthorizeClientVpnIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -84648,6 +84728,9 @@ impl Ec2 for Ec2Client {
         input: AuthorizeSecurityGroupEgressRequest,
     ) -> Result<(), RusotoError<AuthorizeSecurityGroupEgressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AuthorizeSecurityGroupEgress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AuthorizeSecurityGroupEgress");
         let mut params = params;
         AuthorizeSecurityGroupEgressRequestSerializer::serialize(&mut params, "", &input);
@@ -84668,6 +84751,9 @@ impl Ec2 for Ec2Client {
         input: AuthorizeSecurityGroupIngressRequest,
     ) -> Result<(), RusotoError<AuthorizeSecurityGroupIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("AuthorizeSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AuthorizeSecurityGroupIngress");
         let mut params = params;
         AuthorizeSecurityGroupIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -84688,6 +84774,9 @@ impl Ec2 for Ec2Client {
         input: BundleInstanceRequest,
     ) -> Result<BundleInstanceResult, RusotoError<BundleInstanceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("BundleInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BundleInstance");
         let mut params = params;
         BundleInstanceRequestSerializer::serialize(&mut params, "", &input);
@@ -84714,6 +84803,9 @@ impl Ec2 for Ec2Client {
         input: CancelBundleTaskRequest,
     ) -> Result<CancelBundleTaskResult, RusotoError<CancelBundleTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelBundleTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelBundleTask");
         let mut params = params;
         CancelBundleTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -84740,6 +84832,9 @@ impl Ec2 for Ec2Client {
         input: CancelCapacityReservationRequest,
     ) -> Result<CancelCapacityReservationResult, RusotoError<CancelCapacityReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelCapacityReservation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelCapacityReservation");
         let mut params = params;
         CancelCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -84766,6 +84861,9 @@ impl Ec2 for Ec2Client {
         input: CancelConversionRequest,
     ) -> Result<(), RusotoError<CancelConversionTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelConversionTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelConversionTask");
         let mut params = params;
         CancelConversionRequestSerializer::serialize(&mut params, "", &input);
@@ -84786,6 +84884,9 @@ impl Ec2 for Ec2Client {
         input: CancelExportTaskRequest,
     ) -> Result<(), RusotoError<CancelExportTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelExportTask");
         let mut params = params;
         CancelExportTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -84806,6 +84907,9 @@ impl Ec2 for Ec2Client {
         input: CancelImportTaskRequest,
     ) -> Result<CancelImportTaskResult, RusotoError<CancelImportTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelImportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelImportTask");
         let mut params = params;
         CancelImportTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -84835,6 +84939,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CancelReservedInstancesListingError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelReservedInstancesListing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelReservedInstancesListing");
         let mut params = params;
         CancelReservedInstancesListingRequestSerializer::serialize(&mut params, "", &input);
@@ -84861,6 +84968,9 @@ impl Ec2 for Ec2Client {
         input: CancelSpotFleetRequestsRequest,
     ) -> Result<CancelSpotFleetRequestsResponse, RusotoError<EC2CancelSpotFleetRequestsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelSpotFleetRequests");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelSpotFleetRequests");
         let mut params = params;
         CancelSpotFleetRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -84888,6 +84998,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<CancelSpotInstanceRequestsResult, RusotoError<CancelSpotInstanceRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CancelSpotInstanceRequests");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelSpotInstanceRequests");
         let mut params = params;
         CancelSpotInstanceRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -84914,6 +85027,9 @@ impl Ec2 for Ec2Client {
         input: ConfirmProductInstanceRequest,
     ) -> Result<ConfirmProductInstanceResult, RusotoError<ConfirmProductInstanceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ConfirmProductInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ConfirmProductInstance");
         let mut params = params;
         ConfirmProductInstanceRequestSerializer::serialize(&mut params, "", &input);
@@ -84940,6 +85056,9 @@ impl Ec2 for Ec2Client {
         input: CopyFpgaImageRequest,
     ) -> Result<CopyFpgaImageResult, RusotoError<CopyFpgaImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CopyFpgaImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyFpgaImage");
         let mut params = params;
         CopyFpgaImageRequestSerializer::serialize(&mut params, "", &input);
@@ -84966,6 +85085,9 @@ impl Ec2 for Ec2Client {
         input: CopyImageRequest,
     ) -> Result<CopyImageResult, RusotoError<CopyImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CopyImage");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CopyImage");
         let mut params = params;
         CopyImageRequestSerializer::serialize(&mut params, "", &input);
@@ -84992,6 +85114,9 @@ impl Ec2 for Ec2Client {
         input: CopySnapshotRequest,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CopySnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopySnapshot");
         let mut params = params;
         CopySnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -85018,6 +85143,9 @@ impl Ec2 for Ec2Client {
         input: CreateCapacityReservationRequest,
     ) -> Result<CreateCapacityReservationResult, RusotoError<CreateCapacityReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateCapacityReservation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCapacityReservation");
         let mut params = params;
         CreateCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -85044,6 +85172,9 @@ impl Ec2 for Ec2Client {
         input: CreateClientVpnEndpointRequest,
     ) -> Result<CreateClientVpnEndpointResult, RusotoError<CreateClientVpnEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateClientVpnEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateClientVpnEndpoint");
         let mut params = params;
         CreateClientVpnEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -85070,6 +85201,9 @@ impl Ec2 for Ec2Client {
         input: CreateClientVpnRouteRequest,
     ) -> Result<CreateClientVpnRouteResult, RusotoError<CreateClientVpnRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateClientVpnRoute");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateClientVpnRoute");
         let mut params = params;
         CreateClientVpnRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -85096,6 +85230,9 @@ impl Ec2 for Ec2Client {
         input: CreateCustomerGatewayRequest,
     ) -> Result<CreateCustomerGatewayResult, RusotoError<CreateCustomerGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateCustomerGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCustomerGateway");
         let mut params = params;
         CreateCustomerGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -85122,6 +85259,9 @@ impl Ec2 for Ec2Client {
         input: CreateDefaultSubnetRequest,
     ) -> Result<CreateDefaultSubnetResult, RusotoError<CreateDefaultSubnetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateDefaultSubnet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDefaultSubnet");
         let mut params = params;
         CreateDefaultSubnetRequestSerializer::serialize(&mut params, "", &input);
@@ -85148,6 +85288,9 @@ impl Ec2 for Ec2Client {
         input: CreateDefaultVpcRequest,
     ) -> Result<CreateDefaultVpcResult, RusotoError<CreateDefaultVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateDefaultVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDefaultVpc");
         let mut params = params;
         CreateDefaultVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -85174,6 +85317,9 @@ impl Ec2 for Ec2Client {
         input: CreateDhcpOptionsRequest,
     ) -> Result<CreateDhcpOptionsResult, RusotoError<CreateDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateDhcpOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDhcpOptions");
         let mut params = params;
         CreateDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -85203,6 +85349,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateEgressOnlyInternetGatewayError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateEgressOnlyInternetGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateEgressOnlyInternetGateway");
         let mut params = params;
         CreateEgressOnlyInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -85229,6 +85378,9 @@ impl Ec2 for Ec2Client {
         input: CreateFleetRequest,
     ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateFleet");
         let mut params = params;
         CreateFleetRequestSerializer::serialize(&mut params, "", &input);
@@ -85255,6 +85407,9 @@ impl Ec2 for Ec2Client {
         input: CreateFlowLogsRequest,
     ) -> Result<CreateFlowLogsResult, RusotoError<CreateFlowLogsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateFlowLogs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateFlowLogs");
         let mut params = params;
         CreateFlowLogsRequestSerializer::serialize(&mut params, "", &input);
@@ -85281,6 +85436,9 @@ impl Ec2 for Ec2Client {
         input: CreateFpgaImageRequest,
     ) -> Result<CreateFpgaImageResult, RusotoError<CreateFpgaImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateFpgaImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateFpgaImage");
         let mut params = params;
         CreateFpgaImageRequestSerializer::serialize(&mut params, "", &input);
@@ -85307,6 +85465,9 @@ impl Ec2 for Ec2Client {
         input: CreateImageRequest,
     ) -> Result<CreateImageResult, RusotoError<CreateImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateImage");
         let mut params = params;
         CreateImageRequestSerializer::serialize(&mut params, "", &input);
@@ -85333,6 +85494,9 @@ impl Ec2 for Ec2Client {
         input: CreateInstanceExportTaskRequest,
     ) -> Result<CreateInstanceExportTaskResult, RusotoError<CreateInstanceExportTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateInstanceExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateInstanceExportTask");
         let mut params = params;
         CreateInstanceExportTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -85359,6 +85523,9 @@ impl Ec2 for Ec2Client {
         input: CreateInternetGatewayRequest,
     ) -> Result<CreateInternetGatewayResult, RusotoError<CreateInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateInternetGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateInternetGateway");
         let mut params = params;
         CreateInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -85385,6 +85552,9 @@ impl Ec2 for Ec2Client {
         input: CreateKeyPairRequest,
     ) -> Result<KeyPair, RusotoError<CreateKeyPairError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateKeyPair");
         let mut params = params;
         CreateKeyPairRequestSerializer::serialize(&mut params, "", &input);
@@ -85411,6 +85581,9 @@ impl Ec2 for Ec2Client {
         input: CreateLaunchTemplateRequest,
     ) -> Result<CreateLaunchTemplateResult, RusotoError<CreateLaunchTemplateError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateLaunchTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateLaunchTemplate");
         let mut params = params;
         CreateLaunchTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -85438,6 +85611,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateLaunchTemplateVersionResult, RusotoError<CreateLaunchTemplateVersionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateLaunchTemplateVersion");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateLaunchTemplateVersion");
         let mut params = params;
         CreateLaunchTemplateVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -85464,6 +85640,9 @@ impl Ec2 for Ec2Client {
         input: CreateLocalGatewayRouteRequest,
     ) -> Result<CreateLocalGatewayRouteResult, RusotoError<CreateLocalGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateLocalGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLocalGatewayRoute");
         let mut params = params;
         CreateLocalGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -85493,6 +85672,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateLocalGatewayRouteTableVpcAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateLocalGatewayRouteTableVpcAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLocalGatewayRouteTableVpcAssociation");
         let mut params = params;
         CreateLocalGatewayRouteTableVpcAssociationRequestSerializer::serialize(
@@ -85529,6 +85711,9 @@ impl Ec2 for Ec2Client {
         input: CreateManagedPrefixListRequest,
     ) -> Result<CreateManagedPrefixListResult, RusotoError<CreateManagedPrefixListError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateManagedPrefixList");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateManagedPrefixList");
         let mut params = params;
         CreateManagedPrefixListRequestSerializer::serialize(&mut params, "", &input);
@@ -85555,6 +85740,9 @@ impl Ec2 for Ec2Client {
         input: CreateNatGatewayRequest,
     ) -> Result<CreateNatGatewayResult, RusotoError<CreateNatGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateNatGateway");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateNatGateway");
         let mut params = params;
         CreateNatGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -85581,6 +85769,9 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkAclRequest,
     ) -> Result<CreateNetworkAclResult, RusotoError<CreateNetworkAclError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateNetworkAcl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateNetworkAcl");
         let mut params = params;
         CreateNetworkAclRequestSerializer::serialize(&mut params, "", &input);
@@ -85607,6 +85798,9 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkAclEntryRequest,
     ) -> Result<(), RusotoError<CreateNetworkAclEntryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateNetworkAclEntry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateNetworkAclEntry");
         let mut params = params;
         CreateNetworkAclEntryRequestSerializer::serialize(&mut params, "", &input);
@@ -85627,6 +85821,9 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkInterfaceRequest,
     ) -> Result<CreateNetworkInterfaceResult, RusotoError<CreateNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateNetworkInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateNetworkInterface");
         let mut params = params;
         CreateNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -85656,6 +85853,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateNetworkInterfacePermissionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateNetworkInterfacePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateNetworkInterfacePermission");
         let mut params = params;
         CreateNetworkInterfacePermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -85685,6 +85885,9 @@ impl Ec2 for Ec2Client {
         input: CreatePlacementGroupRequest,
     ) -> Result<CreatePlacementGroupResult, RusotoError<CreatePlacementGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreatePlacementGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreatePlacementGroup");
         let mut params = params;
         CreatePlacementGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -85714,6 +85917,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateReservedInstancesListingError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateReservedInstancesListing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateReservedInstancesListing");
         let mut params = params;
         CreateReservedInstancesListingRequestSerializer::serialize(&mut params, "", &input);
@@ -85740,6 +85946,9 @@ impl Ec2 for Ec2Client {
         input: CreateRouteRequest,
     ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateRoute");
         let mut params = params;
         CreateRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -85766,6 +85975,9 @@ impl Ec2 for Ec2Client {
         input: CreateRouteTableRequest,
     ) -> Result<CreateRouteTableResult, RusotoError<CreateRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateRouteTable");
         let mut params = params;
         CreateRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -85792,6 +86004,9 @@ impl Ec2 for Ec2Client {
         input: CreateSecurityGroupRequest,
     ) -> Result<CreateSecurityGroupResult, RusotoError<CreateSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSecurityGroup");
         let mut params = params;
         CreateSecurityGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -85818,6 +86033,9 @@ impl Ec2 for Ec2Client {
         input: CreateSnapshotRequest,
     ) -> Result<Snapshot, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSnapshot");
         let mut params = params;
         CreateSnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -85844,6 +86062,9 @@ impl Ec2 for Ec2Client {
         input: CreateSnapshotsRequest,
     ) -> Result<CreateSnapshotsResult, RusotoError<CreateSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateSnapshots");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSnapshots");
         let mut params = params;
         CreateSnapshotsRequestSerializer::serialize(&mut params, "", &input);
@@ -85873,6 +86094,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateSpotDatafeedSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateSpotDatafeedSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSpotDatafeedSubscription");
         let mut params = params;
         CreateSpotDatafeedSubscriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -85899,6 +86123,9 @@ impl Ec2 for Ec2Client {
         input: CreateSubnetRequest,
     ) -> Result<CreateSubnetResult, RusotoError<CreateSubnetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateSubnet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSubnet");
         let mut params = params;
         CreateSubnetRequestSerializer::serialize(&mut params, "", &input);
@@ -85925,6 +86152,9 @@ impl Ec2 for Ec2Client {
         input: CreateTagsRequest,
     ) -> Result<(), RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTags");
         let mut params = params;
         CreateTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -85945,6 +86175,9 @@ impl Ec2 for Ec2Client {
         input: CreateTrafficMirrorFilterRequest,
     ) -> Result<CreateTrafficMirrorFilterResult, RusotoError<CreateTrafficMirrorFilterError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTrafficMirrorFilter");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateTrafficMirrorFilter");
         let mut params = params;
         CreateTrafficMirrorFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -85972,6 +86205,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateTrafficMirrorFilterRuleResult, RusotoError<CreateTrafficMirrorFilterRuleError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTrafficMirrorFilterRule");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateTrafficMirrorFilterRule");
         let mut params = params;
         CreateTrafficMirrorFilterRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -85999,6 +86235,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateTrafficMirrorSessionResult, RusotoError<CreateTrafficMirrorSessionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTrafficMirrorSession");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateTrafficMirrorSession");
         let mut params = params;
         CreateTrafficMirrorSessionRequestSerializer::serialize(&mut params, "", &input);
@@ -86025,6 +86264,9 @@ impl Ec2 for Ec2Client {
         input: CreateTrafficMirrorTargetRequest,
     ) -> Result<CreateTrafficMirrorTargetResult, RusotoError<CreateTrafficMirrorTargetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTrafficMirrorTarget");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("CreateTrafficMirrorTarget");
         let mut params = params;
         CreateTrafficMirrorTargetRequestSerializer::serialize(&mut params, "", &input);
@@ -86051,6 +86293,9 @@ impl Ec2 for Ec2Client {
         input: CreateTransitGatewayRequest,
     ) -> Result<CreateTransitGatewayResult, RusotoError<CreateTransitGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTransitGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTransitGateway");
         let mut params = params;
         CreateTransitGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -86080,6 +86325,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTransitGatewayMulticastDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTransitGatewayMulticastDomain");
         let mut params = params;
         CreateTransitGatewayMulticastDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -86115,6 +86363,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTransitGatewayPeeringAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTransitGatewayPeeringAttachment");
         let mut params = params;
         CreateTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -86147,6 +86398,9 @@ impl Ec2 for Ec2Client {
         input: CreateTransitGatewayRouteRequest,
     ) -> Result<CreateTransitGatewayRouteResult, RusotoError<CreateTransitGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTransitGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTransitGatewayRoute");
         let mut params = params;
         CreateTransitGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -86176,6 +86430,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTransitGatewayRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTransitGatewayRouteTable");
         let mut params = params;
         CreateTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -86205,6 +86462,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateTransitGatewayVpcAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTransitGatewayVpcAttachment");
         let mut params = params;
         CreateTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -86234,6 +86494,9 @@ impl Ec2 for Ec2Client {
         input: CreateVolumeRequest,
     ) -> Result<Volume, RusotoError<CreateVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVolume");
         let mut params = params;
         CreateVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -86260,6 +86523,9 @@ impl Ec2 for Ec2Client {
         input: CreateVpcRequest,
     ) -> Result<CreateVpcResult, RusotoError<CreateVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpc");
         let mut params = params;
         CreateVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -86286,6 +86552,9 @@ impl Ec2 for Ec2Client {
         input: CreateVpcEndpointRequest,
     ) -> Result<CreateVpcEndpointResult, RusotoError<CreateVpcEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpcEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpcEndpoint");
         let mut params = params;
         CreateVpcEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -86315,6 +86584,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateVpcEndpointConnectionNotificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpcEndpointConnectionNotification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpcEndpointConnectionNotification");
         let mut params = params;
         CreateVpcEndpointConnectionNotificationRequestSerializer::serialize(
@@ -86354,6 +86626,9 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateVpcEndpointServiceConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpcEndpointServiceConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpcEndpointServiceConfiguration");
         let mut params = params;
         CreateVpcEndpointServiceConfigurationRequestSerializer::serialize(&mut params, "", &input);
@@ -86387,6 +86662,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateVpcPeeringConnectionResult, RusotoError<CreateVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpcPeeringConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpcPeeringConnection");
         let mut params = params;
         CreateVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -86413,6 +86691,9 @@ impl Ec2 for Ec2Client {
         input: CreateVpnConnectionRequest,
     ) -> Result<CreateVpnConnectionResult, RusotoError<CreateVpnConnectionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpnConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpnConnection");
         let mut params = params;
         CreateVpnConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -86439,6 +86720,9 @@ impl Ec2 for Ec2Client {
         input: CreateVpnConnectionRouteRequest,
     ) -> Result<(), RusotoError<CreateVpnConnectionRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpnConnectionRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpnConnectionRoute");
         let mut params = params;
         CreateVpnConnectionRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -86459,6 +86743,9 @@ impl Ec2 for Ec2Client {
         input: CreateVpnGatewayRequest,
     ) -> Result<CreateVpnGatewayResult, RusotoError<CreateVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("CreateVpnGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVpnGateway");
         let mut params = params;
         CreateVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -86485,6 +86772,9 @@ impl Ec2 for Ec2Client {
         input: DeleteClientVpnEndpointRequest,
     ) -> Result<DeleteClientVpnEndpointResult, RusotoError<DeleteClientVpnEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteClientVpnEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteClientVpnEndpoint");
         let mut params = params;
         DeleteClientVpnEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -86511,6 +86801,9 @@ impl Ec2 for Ec2Client {
         input: DeleteClientVpnRouteRequest,
     ) -> Result<DeleteClientVpnRouteResult, RusotoError<DeleteClientVpnRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteClientVpnRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteClientVpnRoute");
         let mut params = params;
         DeleteClientVpnRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -86537,6 +86830,9 @@ impl Ec2 for Ec2Client {
         input: DeleteCustomerGatewayRequest,
     ) -> Result<(), RusotoError<DeleteCustomerGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteCustomerGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCustomerGateway");
         let mut params = params;
         DeleteCustomerGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -86557,6 +86853,9 @@ impl Ec2 for Ec2Client {
         input: DeleteDhcpOptionsRequest,
     ) -> Result<(), RusotoError<DeleteDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteDhcpOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDhcpOptions");
         let mut params = params;
         DeleteDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -86580,6 +86879,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteEgressOnlyInternetGatewayError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteEgressOnlyInternetGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteEgressOnlyInternetGateway");
         let mut params = params;
         DeleteEgressOnlyInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -86606,6 +86908,9 @@ impl Ec2 for Ec2Client {
         input: DeleteFleetsRequest,
     ) -> Result<DeleteFleetsResult, RusotoError<DeleteFleetsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteFleets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteFleets");
         let mut params = params;
         DeleteFleetsRequestSerializer::serialize(&mut params, "", &input);
@@ -86632,6 +86937,9 @@ impl Ec2 for Ec2Client {
         input: DeleteFlowLogsRequest,
     ) -> Result<DeleteFlowLogsResult, RusotoError<DeleteFlowLogsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteFlowLogs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteFlowLogs");
         let mut params = params;
         DeleteFlowLogsRequestSerializer::serialize(&mut params, "", &input);
@@ -86658,6 +86966,9 @@ impl Ec2 for Ec2Client {
         input: DeleteFpgaImageRequest,
     ) -> Result<DeleteFpgaImageResult, RusotoError<DeleteFpgaImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteFpgaImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteFpgaImage");
         let mut params = params;
         DeleteFpgaImageRequestSerializer::serialize(&mut params, "", &input);
@@ -86684,6 +86995,9 @@ impl Ec2 for Ec2Client {
         input: DeleteInternetGatewayRequest,
     ) -> Result<(), RusotoError<DeleteInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteInternetGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteInternetGateway");
         let mut params = params;
         DeleteInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -86704,6 +87018,9 @@ impl Ec2 for Ec2Client {
         input: DeleteKeyPairRequest,
     ) -> Result<(), RusotoError<DeleteKeyPairError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteKeyPair");
         let mut params = params;
         DeleteKeyPairRequestSerializer::serialize(&mut params, "", &input);
@@ -86724,6 +87041,9 @@ impl Ec2 for Ec2Client {
         input: DeleteLaunchTemplateRequest,
     ) -> Result<DeleteLaunchTemplateResult, RusotoError<DeleteLaunchTemplateError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteLaunchTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLaunchTemplate");
         let mut params = params;
         DeleteLaunchTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -86751,6 +87071,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteLaunchTemplateVersionsResult, RusotoError<DeleteLaunchTemplateVersionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteLaunchTemplateVersions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLaunchTemplateVersions");
         let mut params = params;
         DeleteLaunchTemplateVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -86777,6 +87100,9 @@ impl Ec2 for Ec2Client {
         input: DeleteLocalGatewayRouteRequest,
     ) -> Result<DeleteLocalGatewayRouteResult, RusotoError<DeleteLocalGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteLocalGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLocalGatewayRoute");
         let mut params = params;
         DeleteLocalGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -86806,6 +87132,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteLocalGatewayRouteTableVpcAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteLocalGatewayRouteTableVpcAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLocalGatewayRouteTableVpcAssociation");
         let mut params = params;
         DeleteLocalGatewayRouteTableVpcAssociationRequestSerializer::serialize(
@@ -86842,6 +87171,9 @@ impl Ec2 for Ec2Client {
         input: DeleteManagedPrefixListRequest,
     ) -> Result<DeleteManagedPrefixListResult, RusotoError<DeleteManagedPrefixListError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteManagedPrefixList");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteManagedPrefixList");
         let mut params = params;
         DeleteManagedPrefixListRequestSerializer::serialize(&mut params, "", &input);
@@ -86868,6 +87200,9 @@ impl Ec2 for Ec2Client {
         input: DeleteNatGatewayRequest,
     ) -> Result<DeleteNatGatewayResult, RusotoError<DeleteNatGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteNatGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteNatGateway");
         let mut params = params;
         DeleteNatGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -86894,6 +87229,9 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkAclRequest,
     ) -> Result<(), RusotoError<DeleteNetworkAclError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteNetworkAcl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteNetworkAcl");
         let mut params = params;
         DeleteNetworkAclRequestSerializer::serialize(&mut params, "", &input);
@@ -86914,6 +87252,9 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkAclEntryRequest,
     ) -> Result<(), RusotoError<DeleteNetworkAclEntryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteNetworkAclEntry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteNetworkAclEntry");
         let mut params = params;
         DeleteNetworkAclEntryRequestSerializer::serialize(&mut params, "", &input);
@@ -86934,6 +87275,9 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkInterfaceRequest,
     ) -> Result<(), RusotoError<DeleteNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteNetworkInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteNetworkInterface");
         let mut params = params;
         DeleteNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -86957,6 +87301,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteNetworkInterfacePermissionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteNetworkInterfacePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteNetworkInterfacePermission");
         let mut params = params;
         DeleteNetworkInterfacePermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -86986,6 +87333,9 @@ impl Ec2 for Ec2Client {
         input: DeletePlacementGroupRequest,
     ) -> Result<(), RusotoError<DeletePlacementGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeletePlacementGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeletePlacementGroup");
         let mut params = params;
         DeletePlacementGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -87007,6 +87357,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteQueuedReservedInstancesResult, RusotoError<DeleteQueuedReservedInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteQueuedReservedInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteQueuedReservedInstances");
         let mut params = params;
         DeleteQueuedReservedInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -87033,6 +87386,9 @@ impl Ec2 for Ec2Client {
         input: DeleteRouteRequest,
     ) -> Result<(), RusotoError<DeleteRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteRoute");
         let mut params = params;
         DeleteRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -87053,6 +87409,9 @@ impl Ec2 for Ec2Client {
         input: DeleteRouteTableRequest,
     ) -> Result<(), RusotoError<DeleteRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteRouteTable");
         let mut params = params;
         DeleteRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -87073,6 +87432,9 @@ impl Ec2 for Ec2Client {
         input: DeleteSecurityGroupRequest,
     ) -> Result<(), RusotoError<DeleteSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSecurityGroup");
         let mut params = params;
         DeleteSecurityGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -87093,6 +87455,9 @@ impl Ec2 for Ec2Client {
         input: DeleteSnapshotRequest,
     ) -> Result<(), RusotoError<DeleteSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSnapshot");
         let mut params = params;
         DeleteSnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -87113,6 +87478,9 @@ impl Ec2 for Ec2Client {
         input: DeleteSpotDatafeedSubscriptionRequest,
     ) -> Result<(), RusotoError<DeleteSpotDatafeedSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteSpotDatafeedSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSpotDatafeedSubscription");
         let mut params = params;
         DeleteSpotDatafeedSubscriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -87133,6 +87501,9 @@ impl Ec2 for Ec2Client {
         input: DeleteSubnetRequest,
     ) -> Result<(), RusotoError<DeleteSubnetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteSubnet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSubnet");
         let mut params = params;
         DeleteSubnetRequestSerializer::serialize(&mut params, "", &input);
@@ -87153,6 +87524,9 @@ impl Ec2 for Ec2Client {
         input: DeleteTagsRequest,
     ) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTags");
         let mut params = params;
         DeleteTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -87173,6 +87547,9 @@ impl Ec2 for Ec2Client {
         input: DeleteTrafficMirrorFilterRequest,
     ) -> Result<DeleteTrafficMirrorFilterResult, RusotoError<DeleteTrafficMirrorFilterError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTrafficMirrorFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTrafficMirrorFilter");
         let mut params = params;
         DeleteTrafficMirrorFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -87200,6 +87577,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteTrafficMirrorFilterRuleResult, RusotoError<DeleteTrafficMirrorFilterRuleError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTrafficMirrorFilterRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTrafficMirrorFilterRule");
         let mut params = params;
         DeleteTrafficMirrorFilterRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -87227,6 +87607,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteTrafficMirrorSessionResult, RusotoError<DeleteTrafficMirrorSessionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTrafficMirrorSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTrafficMirrorSession");
         let mut params = params;
         DeleteTrafficMirrorSessionRequestSerializer::serialize(&mut params, "", &input);
@@ -87253,6 +87636,9 @@ impl Ec2 for Ec2Client {
         input: DeleteTrafficMirrorTargetRequest,
     ) -> Result<DeleteTrafficMirrorTargetResult, RusotoError<DeleteTrafficMirrorTargetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTrafficMirrorTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTrafficMirrorTarget");
         let mut params = params;
         DeleteTrafficMirrorTargetRequestSerializer::serialize(&mut params, "", &input);
@@ -87279,6 +87665,9 @@ impl Ec2 for Ec2Client {
         input: DeleteTransitGatewayRequest,
     ) -> Result<DeleteTransitGatewayResult, RusotoError<DeleteTransitGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTransitGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTransitGateway");
         let mut params = params;
         DeleteTransitGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -87308,6 +87697,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTransitGatewayMulticastDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTransitGatewayMulticastDomain");
         let mut params = params;
         DeleteTransitGatewayMulticastDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -87343,6 +87735,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTransitGatewayPeeringAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTransitGatewayPeeringAttachment");
         let mut params = params;
         DeleteTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -87375,6 +87770,9 @@ impl Ec2 for Ec2Client {
         input: DeleteTransitGatewayRouteRequest,
     ) -> Result<DeleteTransitGatewayRouteResult, RusotoError<DeleteTransitGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTransitGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTransitGatewayRoute");
         let mut params = params;
         DeleteTransitGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -87404,6 +87802,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTransitGatewayRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTransitGatewayRouteTable");
         let mut params = params;
         DeleteTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -87433,6 +87834,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteTransitGatewayVpcAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTransitGatewayVpcAttachment");
         let mut params = params;
         DeleteTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -87462,6 +87866,9 @@ impl Ec2 for Ec2Client {
         input: DeleteVolumeRequest,
     ) -> Result<(), RusotoError<DeleteVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVolume");
         let mut params = params;
         DeleteVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -87479,6 +87886,9 @@ impl Ec2 for Ec2Client {
     /// <p>Deletes the specified VPC. You must detach or delete all gateways and resources that are associated with the VPC before you can delete it. For example, you must terminate all instances running in the VPC, delete all security groups associated with the VPC (except the default one), delete all route tables associated with the VPC (except the default one), and so on.</p>
     async fn delete_vpc(&self, input: DeleteVpcRequest) -> Result<(), RusotoError<DeleteVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpc");
         let mut params = params;
         DeleteVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -87502,6 +87912,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteVpcEndpointConnectionNotificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpcEndpointConnectionNotifications");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpcEndpointConnectionNotifications");
         let mut params = params;
         DeleteVpcEndpointConnectionNotificationsRequestSerializer::serialize(
@@ -87541,6 +87954,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteVpcEndpointServiceConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpcEndpointServiceConfigurations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpcEndpointServiceConfigurations");
         let mut params = params;
         DeleteVpcEndpointServiceConfigurationsRequestSerializer::serialize(&mut params, "", &input);
@@ -87573,6 +87989,9 @@ impl Ec2 for Ec2Client {
         input: DeleteVpcEndpointsRequest,
     ) -> Result<DeleteVpcEndpointsResult, RusotoError<DeleteVpcEndpointsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpcEndpoints");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpcEndpoints");
         let mut params = params;
         DeleteVpcEndpointsRequestSerializer::serialize(&mut params, "", &input);
@@ -87600,6 +88019,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteVpcPeeringConnectionResult, RusotoError<DeleteVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpcPeeringConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpcPeeringConnection");
         let mut params = params;
         DeleteVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -87626,6 +88048,9 @@ impl Ec2 for Ec2Client {
         input: DeleteVpnConnectionRequest,
     ) -> Result<(), RusotoError<DeleteVpnConnectionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpnConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpnConnection");
         let mut params = params;
         DeleteVpnConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -87646,6 +88071,9 @@ impl Ec2 for Ec2Client {
         input: DeleteVpnConnectionRouteRequest,
     ) -> Result<(), RusotoError<DeleteVpnConnectionRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpnConnectionRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpnConnectionRoute");
         let mut params = params;
         DeleteVpnConnectionRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -87666,6 +88094,9 @@ impl Ec2 for Ec2Client {
         input: DeleteVpnGatewayRequest,
     ) -> Result<(), RusotoError<DeleteVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeleteVpnGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVpnGateway");
         let mut params = params;
         DeleteVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -87686,6 +88117,9 @@ impl Ec2 for Ec2Client {
         input: DeprovisionByoipCidrRequest,
     ) -> Result<DeprovisionByoipCidrResult, RusotoError<DeprovisionByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeprovisionByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeprovisionByoipCidr");
         let mut params = params;
         DeprovisionByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -87712,6 +88146,9 @@ impl Ec2 for Ec2Client {
         input: DeregisterImageRequest,
     ) -> Result<(), RusotoError<DeregisterImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeregisterImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterImage");
         let mut params = params;
         DeregisterImageRequestSerializer::serialize(&mut params, "", &input);
@@ -87735,6 +88172,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeregisterInstanceEventNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeregisterInstanceEventNotificationAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterInstanceEventNotificationAttributes");
         let mut params = params;
         DeregisterInstanceEventNotificationAttributesRequestSerializer::serialize(
@@ -87774,6 +88214,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeregisterTransitGatewayMulticastGroupMembersError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeregisterTransitGatewayMulticastGroupMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterTransitGatewayMulticastGroupMembers");
         let mut params = params;
         DeregisterTransitGatewayMulticastGroupMembersRequestSerializer::serialize(
@@ -87813,6 +88256,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DeregisterTransitGatewayMulticastGroupSourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DeregisterTransitGatewayMulticastGroupSources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterTransitGatewayMulticastGroupSources");
         let mut params = params;
         DeregisterTransitGatewayMulticastGroupSourcesRequestSerializer::serialize(
@@ -87849,6 +88295,9 @@ impl Ec2 for Ec2Client {
         input: DescribeAccountAttributesRequest,
     ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeAccountAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountAttributes");
         let mut params = params;
         DescribeAccountAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -87875,6 +88324,9 @@ impl Ec2 for Ec2Client {
         input: DescribeAddressesRequest,
     ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeAddresses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAddresses");
         let mut params = params;
         DescribeAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -87901,6 +88353,9 @@ impl Ec2 for Ec2Client {
         input: DescribeAggregateIdFormatRequest,
     ) -> Result<DescribeAggregateIdFormatResult, RusotoError<DescribeAggregateIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeAggregateIdFormat");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAggregateIdFormat");
         let mut params = params;
         DescribeAggregateIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -87927,6 +88382,9 @@ impl Ec2 for Ec2Client {
         input: DescribeAvailabilityZonesRequest,
     ) -> Result<DescribeAvailabilityZonesResult, RusotoError<DescribeAvailabilityZonesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeAvailabilityZones");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAvailabilityZones");
         let mut params = params;
         DescribeAvailabilityZonesRequestSerializer::serialize(&mut params, "", &input);
@@ -87953,6 +88411,9 @@ impl Ec2 for Ec2Client {
         input: DescribeBundleTasksRequest,
     ) -> Result<DescribeBundleTasksResult, RusotoError<DescribeBundleTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeBundleTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeBundleTasks");
         let mut params = params;
         DescribeBundleTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -87979,6 +88440,9 @@ impl Ec2 for Ec2Client {
         input: DescribeByoipCidrsRequest,
     ) -> Result<DescribeByoipCidrsResult, RusotoError<DescribeByoipCidrsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeByoipCidrs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeByoipCidrs");
         let mut params = params;
         DescribeByoipCidrsRequestSerializer::serialize(&mut params, "", &input);
@@ -88006,6 +88470,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeCapacityReservationsResult, RusotoError<DescribeCapacityReservationsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeCapacityReservations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCapacityReservations");
         let mut params = params;
         DescribeCapacityReservationsRequestSerializer::serialize(&mut params, "", &input);
@@ -88033,6 +88500,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeClassicLinkInstancesResult, RusotoError<DescribeClassicLinkInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeClassicLinkInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClassicLinkInstances");
         let mut params = params;
         DescribeClassicLinkInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -88062,6 +88532,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeClientVpnAuthorizationRulesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeClientVpnAuthorizationRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClientVpnAuthorizationRules");
         let mut params = params;
         DescribeClientVpnAuthorizationRulesRequestSerializer::serialize(&mut params, "", &input);
@@ -88095,6 +88568,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeClientVpnConnectionsResult, RusotoError<DescribeClientVpnConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeClientVpnConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClientVpnConnections");
         let mut params = params;
         DescribeClientVpnConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -88122,6 +88598,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeClientVpnEndpointsResult, RusotoError<DescribeClientVpnEndpointsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeClientVpnEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClientVpnEndpoints");
         let mut params = params;
         DescribeClientVpnEndpointsRequestSerializer::serialize(&mut params, "", &input);
@@ -88148,6 +88627,9 @@ impl Ec2 for Ec2Client {
         input: DescribeClientVpnRoutesRequest,
     ) -> Result<DescribeClientVpnRoutesResult, RusotoError<DescribeClientVpnRoutesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeClientVpnRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClientVpnRoutes");
         let mut params = params;
         DescribeClientVpnRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -88177,6 +88659,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeClientVpnTargetNetworksError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeClientVpnTargetNetworks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClientVpnTargetNetworks");
         let mut params = params;
         DescribeClientVpnTargetNetworksRequestSerializer::serialize(&mut params, "", &input);
@@ -88203,6 +88688,9 @@ impl Ec2 for Ec2Client {
         input: DescribeCoipPoolsRequest,
     ) -> Result<DescribeCoipPoolsResult, RusotoError<DescribeCoipPoolsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeCoipPools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCoipPools");
         let mut params = params;
         DescribeCoipPoolsRequestSerializer::serialize(&mut params, "", &input);
@@ -88229,6 +88717,9 @@ impl Ec2 for Ec2Client {
         input: DescribeConversionTasksRequest,
     ) -> Result<DescribeConversionTasksResult, RusotoError<DescribeConversionTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeConversionTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeConversionTasks");
         let mut params = params;
         DescribeConversionTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -88255,6 +88746,9 @@ impl Ec2 for Ec2Client {
         input: DescribeCustomerGatewaysRequest,
     ) -> Result<DescribeCustomerGatewaysResult, RusotoError<DescribeCustomerGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeCustomerGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCustomerGateways");
         let mut params = params;
         DescribeCustomerGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -88281,6 +88775,9 @@ impl Ec2 for Ec2Client {
         input: DescribeDhcpOptionsRequest,
     ) -> Result<DescribeDhcpOptionsResult, RusotoError<DescribeDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeDhcpOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDhcpOptions");
         let mut params = params;
         DescribeDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -88310,6 +88807,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeEgressOnlyInternetGatewaysError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeEgressOnlyInternetGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEgressOnlyInternetGateways");
         let mut params = params;
         DescribeEgressOnlyInternetGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -88342,6 +88842,9 @@ impl Ec2 for Ec2Client {
         input: DescribeElasticGpusRequest,
     ) -> Result<DescribeElasticGpusResult, RusotoError<DescribeElasticGpusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeElasticGpus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeElasticGpus");
         let mut params = params;
         DescribeElasticGpusRequestSerializer::serialize(&mut params, "", &input);
@@ -88368,6 +88871,9 @@ impl Ec2 for Ec2Client {
         input: DescribeExportImageTasksRequest,
     ) -> Result<DescribeExportImageTasksResult, RusotoError<DescribeExportImageTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeExportImageTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeExportImageTasks");
         let mut params = params;
         DescribeExportImageTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -88394,6 +88900,9 @@ impl Ec2 for Ec2Client {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResult, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeExportTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeExportTasks");
         let mut params = params;
         DescribeExportTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -88421,6 +88930,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeFastSnapshotRestoresResult, RusotoError<DescribeFastSnapshotRestoresError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFastSnapshotRestores");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFastSnapshotRestores");
         let mut params = params;
         DescribeFastSnapshotRestoresRequestSerializer::serialize(&mut params, "", &input);
@@ -88447,6 +88959,9 @@ impl Ec2 for Ec2Client {
         input: DescribeFleetHistoryRequest,
     ) -> Result<DescribeFleetHistoryResult, RusotoError<DescribeFleetHistoryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFleetHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFleetHistory");
         let mut params = params;
         DescribeFleetHistoryRequestSerializer::serialize(&mut params, "", &input);
@@ -88473,6 +88988,9 @@ impl Ec2 for Ec2Client {
         input: DescribeFleetInstancesRequest,
     ) -> Result<DescribeFleetInstancesResult, RusotoError<DescribeFleetInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFleetInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFleetInstances");
         let mut params = params;
         DescribeFleetInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -88499,6 +89017,9 @@ impl Ec2 for Ec2Client {
         input: DescribeFleetsRequest,
     ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFleets");
         let mut params = params;
         DescribeFleetsRequestSerializer::serialize(&mut params, "", &input);
@@ -88525,6 +89046,9 @@ impl Ec2 for Ec2Client {
         input: DescribeFlowLogsRequest,
     ) -> Result<DescribeFlowLogsResult, RusotoError<DescribeFlowLogsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFlowLogs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFlowLogs");
         let mut params = params;
         DescribeFlowLogsRequestSerializer::serialize(&mut params, "", &input);
@@ -88552,6 +89076,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeFpgaImageAttributeResult, RusotoError<DescribeFpgaImageAttributeError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFpgaImageAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFpgaImageAttribute");
         let mut params = params;
         DescribeFpgaImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -88578,6 +89105,9 @@ impl Ec2 for Ec2Client {
         input: DescribeFpgaImagesRequest,
     ) -> Result<DescribeFpgaImagesResult, RusotoError<DescribeFpgaImagesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeFpgaImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeFpgaImages");
         let mut params = params;
         DescribeFpgaImagesRequestSerializer::serialize(&mut params, "", &input);
@@ -88607,6 +89137,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeHostReservationOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeHostReservationOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeHostReservationOfferings");
         let mut params = params;
         DescribeHostReservationOfferingsRequestSerializer::serialize(&mut params, "", &input);
@@ -88636,6 +89169,9 @@ impl Ec2 for Ec2Client {
         input: DescribeHostReservationsRequest,
     ) -> Result<DescribeHostReservationsResult, RusotoError<DescribeHostReservationsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeHostReservations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeHostReservations");
         let mut params = params;
         DescribeHostReservationsRequestSerializer::serialize(&mut params, "", &input);
@@ -88662,6 +89198,9 @@ impl Ec2 for Ec2Client {
         input: DescribeHostsRequest,
     ) -> Result<DescribeHostsResult, RusotoError<DescribeHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeHosts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeHosts");
         let mut params = params;
         DescribeHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -88691,6 +89230,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeIamInstanceProfileAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeIamInstanceProfileAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeIamInstanceProfileAssociations");
         let mut params = params;
         DescribeIamInstanceProfileAssociationsRequestSerializer::serialize(&mut params, "", &input);
@@ -88723,6 +89265,9 @@ impl Ec2 for Ec2Client {
         input: DescribeIdFormatRequest,
     ) -> Result<DescribeIdFormatResult, RusotoError<DescribeIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeIdFormat");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeIdFormat");
         let mut params = params;
         DescribeIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -88749,6 +89294,9 @@ impl Ec2 for Ec2Client {
         input: DescribeIdentityIdFormatRequest,
     ) -> Result<DescribeIdentityIdFormatResult, RusotoError<DescribeIdentityIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeIdentityIdFormat");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeIdentityIdFormat");
         let mut params = params;
         DescribeIdentityIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -88775,6 +89323,9 @@ impl Ec2 for Ec2Client {
         input: DescribeImageAttributeRequest,
     ) -> Result<ImageAttribute, RusotoError<DescribeImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeImageAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeImageAttribute");
         let mut params = params;
         DescribeImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -88801,6 +89352,9 @@ impl Ec2 for Ec2Client {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeImages");
         let mut params = params;
         DescribeImagesRequestSerializer::serialize(&mut params, "", &input);
@@ -88827,6 +89381,9 @@ impl Ec2 for Ec2Client {
         input: DescribeImportImageTasksRequest,
     ) -> Result<DescribeImportImageTasksResult, RusotoError<DescribeImportImageTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeImportImageTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeImportImageTasks");
         let mut params = params;
         DescribeImportImageTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -88854,6 +89411,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeImportSnapshotTasksResult, RusotoError<DescribeImportSnapshotTasksError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeImportSnapshotTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeImportSnapshotTasks");
         let mut params = params;
         DescribeImportSnapshotTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -88880,6 +89440,9 @@ impl Ec2 for Ec2Client {
         input: DescribeInstanceAttributeRequest,
     ) -> Result<InstanceAttribute, RusotoError<DescribeInstanceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstanceAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceAttribute");
         let mut params = params;
         DescribeInstanceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -88909,6 +89472,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeInstanceCreditSpecificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstanceCreditSpecifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceCreditSpecifications");
         let mut params = params;
         DescribeInstanceCreditSpecificationsRequestSerializer::serialize(&mut params, "", &input);
@@ -88944,6 +89510,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeInstanceEventNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstanceEventNotificationAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceEventNotificationAttributes");
         let mut params = params;
         DescribeInstanceEventNotificationAttributesRequestSerializer::serialize(
@@ -88980,6 +89549,9 @@ impl Ec2 for Ec2Client {
         input: DescribeInstanceStatusRequest,
     ) -> Result<DescribeInstanceStatusResult, RusotoError<DescribeInstanceStatusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstanceStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceStatus");
         let mut params = params;
         DescribeInstanceStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -89007,6 +89579,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeInstanceTypeOfferingsResult, RusotoError<DescribeInstanceTypeOfferingsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstanceTypeOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceTypeOfferings");
         let mut params = params;
         DescribeInstanceTypeOfferingsRequestSerializer::serialize(&mut params, "", &input);
@@ -89033,6 +89608,9 @@ impl Ec2 for Ec2Client {
         input: DescribeInstanceTypesRequest,
     ) -> Result<DescribeInstanceTypesResult, RusotoError<DescribeInstanceTypesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstanceTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceTypes");
         let mut params = params;
         DescribeInstanceTypesRequestSerializer::serialize(&mut params, "", &input);
@@ -89059,6 +89637,9 @@ impl Ec2 for Ec2Client {
         input: DescribeInstancesRequest,
     ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstances");
         let mut params = params;
         DescribeInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -89085,6 +89666,9 @@ impl Ec2 for Ec2Client {
         input: DescribeInternetGatewaysRequest,
     ) -> Result<DescribeInternetGatewaysResult, RusotoError<DescribeInternetGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeInternetGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInternetGateways");
         let mut params = params;
         DescribeInternetGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -89111,6 +89695,9 @@ impl Ec2 for Ec2Client {
         input: DescribeIpv6PoolsRequest,
     ) -> Result<DescribeIpv6PoolsResult, RusotoError<DescribeIpv6PoolsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeIpv6Pools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeIpv6Pools");
         let mut params = params;
         DescribeIpv6PoolsRequestSerializer::serialize(&mut params, "", &input);
@@ -89137,6 +89724,9 @@ impl Ec2 for Ec2Client {
         input: DescribeKeyPairsRequest,
     ) -> Result<DescribeKeyPairsResult, RusotoError<DescribeKeyPairsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeKeyPairs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeKeyPairs");
         let mut params = params;
         DescribeKeyPairsRequestSerializer::serialize(&mut params, "", &input);
@@ -89166,6 +89756,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLaunchTemplateVersionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLaunchTemplateVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLaunchTemplateVersions");
         let mut params = params;
         DescribeLaunchTemplateVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -89192,6 +89785,9 @@ impl Ec2 for Ec2Client {
         input: DescribeLaunchTemplatesRequest,
     ) -> Result<DescribeLaunchTemplatesResult, RusotoError<DescribeLaunchTemplatesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLaunchTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLaunchTemplates");
         let mut params = params;
         DescribeLaunchTemplatesRequestSerializer::serialize(&mut params, "", &input);
@@ -89221,6 +89817,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params =
             self.new_params("DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociations");
         let mut params = params;
@@ -89255,6 +89854,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayRouteTableVpcAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLocalGatewayRouteTableVpcAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLocalGatewayRouteTableVpcAssociations");
         let mut params = params;
         DescribeLocalGatewayRouteTableVpcAssociationsRequestSerializer::serialize(
@@ -89294,6 +89896,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayRouteTablesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLocalGatewayRouteTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLocalGatewayRouteTables");
         let mut params = params;
         DescribeLocalGatewayRouteTablesRequestSerializer::serialize(&mut params, "", &input);
@@ -89323,6 +89928,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayVirtualInterfaceGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLocalGatewayVirtualInterfaceGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLocalGatewayVirtualInterfaceGroups");
         let mut params = params;
         DescribeLocalGatewayVirtualInterfaceGroupsRequestSerializer::serialize(
@@ -89362,6 +89970,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayVirtualInterfacesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLocalGatewayVirtualInterfaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLocalGatewayVirtualInterfaces");
         let mut params = params;
         DescribeLocalGatewayVirtualInterfacesRequestSerializer::serialize(&mut params, "", &input);
@@ -89394,6 +90005,9 @@ impl Ec2 for Ec2Client {
         input: DescribeLocalGatewaysRequest,
     ) -> Result<DescribeLocalGatewaysResult, RusotoError<DescribeLocalGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeLocalGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLocalGateways");
         let mut params = params;
         DescribeLocalGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -89421,6 +90035,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeManagedPrefixListsResult, RusotoError<DescribeManagedPrefixListsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeManagedPrefixLists");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeManagedPrefixLists");
         let mut params = params;
         DescribeManagedPrefixListsRequestSerializer::serialize(&mut params, "", &input);
@@ -89447,6 +90064,9 @@ impl Ec2 for Ec2Client {
         input: DescribeMovingAddressesRequest,
     ) -> Result<DescribeMovingAddressesResult, RusotoError<DescribeMovingAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeMovingAddresses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeMovingAddresses");
         let mut params = params;
         DescribeMovingAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -89473,6 +90093,9 @@ impl Ec2 for Ec2Client {
         input: DescribeNatGatewaysRequest,
     ) -> Result<DescribeNatGatewaysResult, RusotoError<DescribeNatGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeNatGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNatGateways");
         let mut params = params;
         DescribeNatGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -89499,6 +90122,9 @@ impl Ec2 for Ec2Client {
         input: DescribeNetworkAclsRequest,
     ) -> Result<DescribeNetworkAclsResult, RusotoError<DescribeNetworkAclsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeNetworkAcls");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNetworkAcls");
         let mut params = params;
         DescribeNetworkAclsRequestSerializer::serialize(&mut params, "", &input);
@@ -89528,6 +90154,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeNetworkInterfaceAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeNetworkInterfaceAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNetworkInterfaceAttribute");
         let mut params = params;
         DescribeNetworkInterfaceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -89560,6 +90189,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeNetworkInterfacePermissionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeNetworkInterfacePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNetworkInterfacePermissions");
         let mut params = params;
         DescribeNetworkInterfacePermissionsRequestSerializer::serialize(&mut params, "", &input);
@@ -89592,6 +90224,9 @@ impl Ec2 for Ec2Client {
         input: DescribeNetworkInterfacesRequest,
     ) -> Result<DescribeNetworkInterfacesResult, RusotoError<DescribeNetworkInterfacesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeNetworkInterfaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNetworkInterfaces");
         let mut params = params;
         DescribeNetworkInterfacesRequestSerializer::serialize(&mut params, "", &input);
@@ -89618,6 +90253,9 @@ impl Ec2 for Ec2Client {
         input: DescribePlacementGroupsRequest,
     ) -> Result<DescribePlacementGroupsResult, RusotoError<DescribePlacementGroupsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribePlacementGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePlacementGroups");
         let mut params = params;
         DescribePlacementGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -89644,6 +90282,9 @@ impl Ec2 for Ec2Client {
         input: DescribePrefixListsRequest,
     ) -> Result<DescribePrefixListsResult, RusotoError<DescribePrefixListsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribePrefixLists");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePrefixLists");
         let mut params = params;
         DescribePrefixListsRequestSerializer::serialize(&mut params, "", &input);
@@ -89670,6 +90311,9 @@ impl Ec2 for Ec2Client {
         input: DescribePrincipalIdFormatRequest,
     ) -> Result<DescribePrincipalIdFormatResult, RusotoError<DescribePrincipalIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribePrincipalIdFormat");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePrincipalIdFormat");
         let mut params = params;
         DescribePrincipalIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -89696,6 +90340,9 @@ impl Ec2 for Ec2Client {
         input: DescribePublicIpv4PoolsRequest,
     ) -> Result<DescribePublicIpv4PoolsResult, RusotoError<DescribePublicIpv4PoolsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribePublicIpv4Pools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePublicIpv4Pools");
         let mut params = params;
         DescribePublicIpv4PoolsRequestSerializer::serialize(&mut params, "", &input);
@@ -89722,6 +90369,9 @@ impl Ec2 for Ec2Client {
         input: DescribeRegionsRequest,
     ) -> Result<DescribeRegionsResult, RusotoError<DescribeRegionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeRegions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeRegions");
         let mut params = params;
         DescribeRegionsRequestSerializer::serialize(&mut params, "", &input);
@@ -89748,6 +90398,9 @@ impl Ec2 for Ec2Client {
         input: DescribeReservedInstancesRequest,
     ) -> Result<DescribeReservedInstancesResult, RusotoError<DescribeReservedInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeReservedInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedInstances");
         let mut params = params;
         DescribeReservedInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -89777,6 +90430,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeReservedInstancesListingsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeReservedInstancesListings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedInstancesListings");
         let mut params = params;
         DescribeReservedInstancesListingsRequestSerializer::serialize(&mut params, "", &input);
@@ -89809,6 +90465,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeReservedInstancesModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeReservedInstancesModifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedInstancesModifications");
         let mut params = params;
         DescribeReservedInstancesModificationsRequestSerializer::serialize(&mut params, "", &input);
@@ -89844,6 +90503,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeReservedInstancesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeReservedInstancesOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedInstancesOfferings");
         let mut params = params;
         DescribeReservedInstancesOfferingsRequestSerializer::serialize(&mut params, "", &input);
@@ -89876,6 +90538,9 @@ impl Ec2 for Ec2Client {
         input: DescribeRouteTablesRequest,
     ) -> Result<DescribeRouteTablesResult, RusotoError<DescribeRouteTablesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeRouteTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeRouteTables");
         let mut params = params;
         DescribeRouteTablesRequestSerializer::serialize(&mut params, "", &input);
@@ -89905,6 +90570,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeScheduledInstanceAvailabilityError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeScheduledInstanceAvailability");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScheduledInstanceAvailability");
         let mut params = params;
         DescribeScheduledInstanceAvailabilityRequestSerializer::serialize(&mut params, "", &input);
@@ -89938,6 +90606,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeScheduledInstancesResult, RusotoError<DescribeScheduledInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeScheduledInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScheduledInstances");
         let mut params = params;
         DescribeScheduledInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -89967,6 +90638,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeSecurityGroupReferencesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSecurityGroupReferences");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSecurityGroupReferences");
         let mut params = params;
         DescribeSecurityGroupReferencesRequestSerializer::serialize(&mut params, "", &input);
@@ -89993,6 +90667,9 @@ impl Ec2 for Ec2Client {
         input: DescribeSecurityGroupsRequest,
     ) -> Result<DescribeSecurityGroupsResult, RusotoError<DescribeSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSecurityGroups");
         let mut params = params;
         DescribeSecurityGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -90019,6 +90696,9 @@ impl Ec2 for Ec2Client {
         input: DescribeSnapshotAttributeRequest,
     ) -> Result<DescribeSnapshotAttributeResult, RusotoError<DescribeSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSnapshotAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSnapshotAttribute");
         let mut params = params;
         DescribeSnapshotAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -90045,6 +90725,9 @@ impl Ec2 for Ec2Client {
         input: DescribeSnapshotsRequest,
     ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSnapshots");
         let mut params = params;
         DescribeSnapshotsRequestSerializer::serialize(&mut params, "", &input);
@@ -90074,6 +90757,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeSpotDatafeedSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSpotDatafeedSubscription");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSpotDatafeedSubscription");
         let mut params = params;
         DescribeSpotDatafeedSubscriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -90104,6 +90790,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeSpotFleetInstancesResponse, RusotoError<DescribeSpotFleetInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSpotFleetInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSpotFleetInstances");
         let mut params = params;
         DescribeSpotFleetInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -90133,6 +90822,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeSpotFleetRequestHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSpotFleetRequestHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSpotFleetRequestHistory");
         let mut params = params;
         DescribeSpotFleetRequestHistoryRequestSerializer::serialize(&mut params, "", &input);
@@ -90160,6 +90852,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeSpotFleetRequestsResponse, RusotoError<DescribeSpotFleetRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSpotFleetRequests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSpotFleetRequests");
         let mut params = params;
         DescribeSpotFleetRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -90187,6 +90882,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeSpotInstanceRequestsResult, RusotoError<DescribeSpotInstanceRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSpotInstanceRequests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSpotInstanceRequests");
         let mut params = params;
         DescribeSpotInstanceRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -90213,6 +90911,9 @@ impl Ec2 for Ec2Client {
         input: DescribeSpotPriceHistoryRequest,
     ) -> Result<DescribeSpotPriceHistoryResult, RusotoError<DescribeSpotPriceHistoryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSpotPriceHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSpotPriceHistory");
         let mut params = params;
         DescribeSpotPriceHistoryRequestSerializer::serialize(&mut params, "", &input);
@@ -90240,6 +90941,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeStaleSecurityGroupsResult, RusotoError<DescribeStaleSecurityGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeStaleSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStaleSecurityGroups");
         let mut params = params;
         DescribeStaleSecurityGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -90266,6 +90970,9 @@ impl Ec2 for Ec2Client {
         input: DescribeSubnetsRequest,
     ) -> Result<DescribeSubnetsResult, RusotoError<DescribeSubnetsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeSubnets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSubnets");
         let mut params = params;
         DescribeSubnetsRequestSerializer::serialize(&mut params, "", &input);
@@ -90292,6 +90999,9 @@ impl Ec2 for Ec2Client {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -90319,6 +91029,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeTrafficMirrorFiltersResult, RusotoError<DescribeTrafficMirrorFiltersError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTrafficMirrorFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTrafficMirrorFilters");
         let mut params = params;
         DescribeTrafficMirrorFiltersRequestSerializer::serialize(&mut params, "", &input);
@@ -90346,6 +91059,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeTrafficMirrorSessionsResult, RusotoError<DescribeTrafficMirrorSessionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTrafficMirrorSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTrafficMirrorSessions");
         let mut params = params;
         DescribeTrafficMirrorSessionsRequestSerializer::serialize(&mut params, "", &input);
@@ -90373,6 +91089,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeTrafficMirrorTargetsResult, RusotoError<DescribeTrafficMirrorTargetsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTrafficMirrorTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTrafficMirrorTargets");
         let mut params = params;
         DescribeTrafficMirrorTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -90402,6 +91121,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTransitGatewayAttachments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTransitGatewayAttachments");
         let mut params = params;
         DescribeTransitGatewayAttachmentsRequestSerializer::serialize(&mut params, "", &input);
@@ -90434,6 +91156,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayMulticastDomainsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTransitGatewayMulticastDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTransitGatewayMulticastDomains");
         let mut params = params;
         DescribeTransitGatewayMulticastDomainsRequestSerializer::serialize(&mut params, "", &input);
@@ -90469,6 +91194,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayPeeringAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTransitGatewayPeeringAttachments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTransitGatewayPeeringAttachments");
         let mut params = params;
         DescribeTransitGatewayPeeringAttachmentsRequestSerializer::serialize(
@@ -90508,6 +91236,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayRouteTablesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTransitGatewayRouteTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTransitGatewayRouteTables");
         let mut params = params;
         DescribeTransitGatewayRouteTablesRequestSerializer::serialize(&mut params, "", &input);
@@ -90540,6 +91271,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayVpcAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTransitGatewayVpcAttachments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTransitGatewayVpcAttachments");
         let mut params = params;
         DescribeTransitGatewayVpcAttachmentsRequestSerializer::serialize(&mut params, "", &input);
@@ -90572,6 +91306,9 @@ impl Ec2 for Ec2Client {
         input: DescribeTransitGatewaysRequest,
     ) -> Result<DescribeTransitGatewaysResult, RusotoError<DescribeTransitGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeTransitGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTransitGateways");
         let mut params = params;
         DescribeTransitGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -90598,6 +91335,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVolumeAttributeRequest,
     ) -> Result<DescribeVolumeAttributeResult, RusotoError<DescribeVolumeAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVolumeAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVolumeAttribute");
         let mut params = params;
         DescribeVolumeAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -90624,6 +91364,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVolumeStatusRequest,
     ) -> Result<DescribeVolumeStatusResult, RusotoError<DescribeVolumeStatusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVolumeStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVolumeStatus");
         let mut params = params;
         DescribeVolumeStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -90650,6 +91393,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVolumesRequest,
     ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVolumes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVolumes");
         let mut params = params;
         DescribeVolumesRequestSerializer::serialize(&mut params, "", &input);
@@ -90677,6 +91423,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeVolumesModificationsResult, RusotoError<DescribeVolumesModificationsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVolumesModifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVolumesModifications");
         let mut params = params;
         DescribeVolumesModificationsRequestSerializer::serialize(&mut params, "", &input);
@@ -90703,6 +91452,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcAttributeRequest,
     ) -> Result<DescribeVpcAttributeResult, RusotoError<DescribeVpcAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcAttribute");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcAttribute");
         let mut params = params;
         DescribeVpcAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -90729,6 +91481,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcClassicLinkRequest,
     ) -> Result<DescribeVpcClassicLinkResult, RusotoError<DescribeVpcClassicLinkError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcClassicLink");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcClassicLink");
         let mut params = params;
         DescribeVpcClassicLinkRequestSerializer::serialize(&mut params, "", &input);
@@ -90758,6 +91513,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcClassicLinkDnsSupportError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcClassicLinkDnsSupport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcClassicLinkDnsSupport");
         let mut params = params;
         DescribeVpcClassicLinkDnsSupportRequestSerializer::serialize(&mut params, "", &input);
@@ -90790,6 +91548,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointConnectionNotificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcEndpointConnectionNotifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcEndpointConnectionNotifications");
         let mut params = params;
         DescribeVpcEndpointConnectionNotificationsRequestSerializer::serialize(
@@ -90829,6 +91590,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointConnectionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcEndpointConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcEndpointConnections");
         let mut params = params;
         DescribeVpcEndpointConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -90858,6 +91622,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointServiceConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcEndpointServiceConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcEndpointServiceConfigurations");
         let mut params = params;
         DescribeVpcEndpointServiceConfigurationsRequestSerializer::serialize(
@@ -90897,6 +91664,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointServicePermissionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcEndpointServicePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcEndpointServicePermissions");
         let mut params = params;
         DescribeVpcEndpointServicePermissionsRequestSerializer::serialize(&mut params, "", &input);
@@ -90930,6 +91700,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeVpcEndpointServicesResult, RusotoError<DescribeVpcEndpointServicesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcEndpointServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcEndpointServices");
         let mut params = params;
         DescribeVpcEndpointServicesRequestSerializer::serialize(&mut params, "", &input);
@@ -90956,6 +91729,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcEndpointsRequest,
     ) -> Result<DescribeVpcEndpointsResult, RusotoError<DescribeVpcEndpointsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcEndpoints");
         let mut params = params;
         DescribeVpcEndpointsRequestSerializer::serialize(&mut params, "", &input);
@@ -90983,6 +91759,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeVpcPeeringConnectionsResult, RusotoError<DescribeVpcPeeringConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcPeeringConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcPeeringConnections");
         let mut params = params;
         DescribeVpcPeeringConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -91009,6 +91788,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcsRequest,
     ) -> Result<DescribeVpcsResult, RusotoError<DescribeVpcsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpcs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpcs");
         let mut params = params;
         DescribeVpcsRequestSerializer::serialize(&mut params, "", &input);
@@ -91035,6 +91817,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVpnConnectionsRequest,
     ) -> Result<DescribeVpnConnectionsResult, RusotoError<DescribeVpnConnectionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpnConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpnConnections");
         let mut params = params;
         DescribeVpnConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -91061,6 +91846,9 @@ impl Ec2 for Ec2Client {
         input: DescribeVpnGatewaysRequest,
     ) -> Result<DescribeVpnGatewaysResult, RusotoError<DescribeVpnGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DescribeVpnGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeVpnGateways");
         let mut params = params;
         DescribeVpnGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -91087,6 +91875,9 @@ impl Ec2 for Ec2Client {
         input: DetachClassicLinkVpcRequest,
     ) -> Result<DetachClassicLinkVpcResult, RusotoError<DetachClassicLinkVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DetachClassicLinkVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachClassicLinkVpc");
         let mut params = params;
         DetachClassicLinkVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -91113,6 +91904,9 @@ impl Ec2 for Ec2Client {
         input: DetachInternetGatewayRequest,
     ) -> Result<(), RusotoError<DetachInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DetachInternetGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachInternetGateway");
         let mut params = params;
         DetachInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -91133,6 +91927,9 @@ impl Ec2 for Ec2Client {
         input: DetachNetworkInterfaceRequest,
     ) -> Result<(), RusotoError<DetachNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DetachNetworkInterface");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachNetworkInterface");
         let mut params = params;
         DetachNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -91153,6 +91950,9 @@ impl Ec2 for Ec2Client {
         input: DetachVolumeRequest,
     ) -> Result<VolumeAttachment, RusotoError<DetachVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DetachVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachVolume");
         let mut params = params;
         DetachVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -91179,6 +91979,9 @@ impl Ec2 for Ec2Client {
         input: DetachVpnGatewayRequest,
     ) -> Result<(), RusotoError<DetachVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DetachVpnGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachVpnGateway");
         let mut params = params;
         DetachVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -91200,6 +92003,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DisableEbsEncryptionByDefaultResult, RusotoError<DisableEbsEncryptionByDefaultError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisableEbsEncryptionByDefault");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableEbsEncryptionByDefault");
         let mut params = params;
         DisableEbsEncryptionByDefaultRequestSerializer::serialize(&mut params, "", &input);
@@ -91227,6 +92033,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DisableFastSnapshotRestoresResult, RusotoError<DisableFastSnapshotRestoresError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisableFastSnapshotRestores");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableFastSnapshotRestores");
         let mut params = params;
         DisableFastSnapshotRestoresRequestSerializer::serialize(&mut params, "", &input);
@@ -91256,6 +92065,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DisableTransitGatewayRouteTablePropagationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisableTransitGatewayRouteTablePropagation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableTransitGatewayRouteTablePropagation");
         let mut params = params;
         DisableTransitGatewayRouteTablePropagationRequestSerializer::serialize(
@@ -91292,6 +92104,9 @@ impl Ec2 for Ec2Client {
         input: DisableVgwRoutePropagationRequest,
     ) -> Result<(), RusotoError<DisableVgwRoutePropagationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisableVgwRoutePropagation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableVgwRoutePropagation");
         let mut params = params;
         DisableVgwRoutePropagationRequestSerializer::serialize(&mut params, "", &input);
@@ -91312,6 +92127,9 @@ impl Ec2 for Ec2Client {
         input: DisableVpcClassicLinkRequest,
     ) -> Result<DisableVpcClassicLinkResult, RusotoError<DisableVpcClassicLinkError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisableVpcClassicLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableVpcClassicLink");
         let mut params = params;
         DisableVpcClassicLinkRequestSerializer::serialize(&mut params, "", &input);
@@ -91341,6 +92159,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DisableVpcClassicLinkDnsSupportError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisableVpcClassicLinkDnsSupport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableVpcClassicLinkDnsSupport");
         let mut params = params;
         DisableVpcClassicLinkDnsSupportRequestSerializer::serialize(&mut params, "", &input);
@@ -91367,6 +92188,9 @@ impl Ec2 for Ec2Client {
         input: DisassociateAddressRequest,
     ) -> Result<(), RusotoError<DisassociateAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateAddress");
         let mut params = params;
         DisassociateAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -91390,6 +92214,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateClientVpnTargetNetworkError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateClientVpnTargetNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateClientVpnTargetNetwork");
         let mut params = params;
         DisassociateClientVpnTargetNetworkRequestSerializer::serialize(&mut params, "", &input);
@@ -91425,6 +92252,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateIamInstanceProfileError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateIamInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateIamInstanceProfile");
         let mut params = params;
         DisassociateIamInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -91451,6 +92281,9 @@ impl Ec2 for Ec2Client {
         input: DisassociateRouteTableRequest,
     ) -> Result<(), RusotoError<DisassociateRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateRouteTable");
         let mut params = params;
         DisassociateRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -91472,6 +92305,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<DisassociateSubnetCidrBlockResult, RusotoError<DisassociateSubnetCidrBlockError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateSubnetCidrBlock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateSubnetCidrBlock");
         let mut params = params;
         DisassociateSubnetCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -91501,6 +92337,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateTransitGatewayMulticastDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateTransitGatewayMulticastDomain");
         let mut params = params;
         DisassociateTransitGatewayMulticastDomainRequestSerializer::serialize(
@@ -91540,6 +92379,9 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateTransitGatewayRouteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateTransitGatewayRouteTable");
         let mut params = params;
         DisassociateTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -91572,6 +92414,9 @@ impl Ec2 for Ec2Client {
         input: DisassociateVpcCidrBlockRequest,
     ) -> Result<DisassociateVpcCidrBlockResult, RusotoError<DisassociateVpcCidrBlockError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("DisassociateVpcCidrBlock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateVpcCidrBlock");
         let mut params = params;
         DisassociateVpcCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -91599,6 +92444,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<EnableEbsEncryptionByDefaultResult, RusotoError<EnableEbsEncryptionByDefaultError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableEbsEncryptionByDefault");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableEbsEncryptionByDefault");
         let mut params = params;
         EnableEbsEncryptionByDefaultRequestSerializer::serialize(&mut params, "", &input);
@@ -91626,6 +92474,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<EnableFastSnapshotRestoresResult, RusotoError<EnableFastSnapshotRestoresError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableFastSnapshotRestores");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableFastSnapshotRestores");
         let mut params = params;
         EnableFastSnapshotRestoresRequestSerializer::serialize(&mut params, "", &input);
@@ -91655,6 +92506,9 @@ impl Ec2 for Ec2Client {
         RusotoError<EnableTransitGatewayRouteTablePropagationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableTransitGatewayRouteTablePropagation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableTransitGatewayRouteTablePropagation");
         let mut params = params;
         EnableTransitGatewayRouteTablePropagationRequestSerializer::serialize(
@@ -91691,6 +92545,9 @@ impl Ec2 for Ec2Client {
         input: EnableVgwRoutePropagationRequest,
     ) -> Result<(), RusotoError<EnableVgwRoutePropagationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableVgwRoutePropagation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableVgwRoutePropagation");
         let mut params = params;
         EnableVgwRoutePropagationRequestSerializer::serialize(&mut params, "", &input);
@@ -91711,6 +92568,9 @@ impl Ec2 for Ec2Client {
         input: EnableVolumeIORequest,
     ) -> Result<(), RusotoError<EnableVolumeIOError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableVolumeIO");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableVolumeIO");
         let mut params = params;
         EnableVolumeIORequestSerializer::serialize(&mut params, "", &input);
@@ -91731,6 +92591,9 @@ impl Ec2 for Ec2Client {
         input: EnableVpcClassicLinkRequest,
     ) -> Result<EnableVpcClassicLinkResult, RusotoError<EnableVpcClassicLinkError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableVpcClassicLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableVpcClassicLink");
         let mut params = params;
         EnableVpcClassicLinkRequestSerializer::serialize(&mut params, "", &input);
@@ -91760,6 +92623,9 @@ impl Ec2 for Ec2Client {
         RusotoError<EnableVpcClassicLinkDnsSupportError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("EnableVpcClassicLinkDnsSupport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableVpcClassicLinkDnsSupport");
         let mut params = params;
         EnableVpcClassicLinkDnsSupportRequestSerializer::serialize(&mut params, "", &input);
@@ -91789,6 +92655,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ExportClientVpnClientCertificateRevocationListError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ExportClientVpnClientCertificateRevocationList");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ExportClientVpnClientCertificateRevocationList");
         let mut params = params;
         ExportClientVpnClientCertificateRevocationListRequestSerializer::serialize(
@@ -91828,6 +92697,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ExportClientVpnClientConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ExportClientVpnClientConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ExportClientVpnClientConfiguration");
         let mut params = params;
         ExportClientVpnClientConfigurationRequestSerializer::serialize(&mut params, "", &input);
@@ -91860,6 +92732,9 @@ impl Ec2 for Ec2Client {
         input: ExportImageRequest,
     ) -> Result<ExportImageResult, RusotoError<ExportImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ExportImage");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("ExportImage");
         let mut params = params;
         ExportImageRequestSerializer::serialize(&mut params, "", &input);
@@ -91887,6 +92762,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ExportTransitGatewayRoutesResult, RusotoError<ExportTransitGatewayRoutesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ExportTransitGatewayRoutes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ExportTransitGatewayRoutes");
         let mut params = params;
         ExportTransitGatewayRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -91914,6 +92792,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetAssociatedIpv6PoolCidrsResult, RusotoError<GetAssociatedIpv6PoolCidrsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetAssociatedIpv6PoolCidrs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAssociatedIpv6PoolCidrs");
         let mut params = params;
         GetAssociatedIpv6PoolCidrsRequestSerializer::serialize(&mut params, "", &input);
@@ -91941,6 +92822,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetCapacityReservationUsageResult, RusotoError<GetCapacityReservationUsageError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetCapacityReservationUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetCapacityReservationUsage");
         let mut params = params;
         GetCapacityReservationUsageRequestSerializer::serialize(&mut params, "", &input);
@@ -91967,6 +92851,9 @@ impl Ec2 for Ec2Client {
         input: GetCoipPoolUsageRequest,
     ) -> Result<GetCoipPoolUsageResult, RusotoError<GetCoipPoolUsageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetCoipPoolUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetCoipPoolUsage");
         let mut params = params;
         GetCoipPoolUsageRequestSerializer::serialize(&mut params, "", &input);
@@ -91993,6 +92880,9 @@ impl Ec2 for Ec2Client {
         input: GetConsoleOutputRequest,
     ) -> Result<GetConsoleOutputResult, RusotoError<GetConsoleOutputError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetConsoleOutput");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetConsoleOutput");
         let mut params = params;
         GetConsoleOutputRequestSerializer::serialize(&mut params, "", &input);
@@ -92019,6 +92909,9 @@ impl Ec2 for Ec2Client {
         input: GetConsoleScreenshotRequest,
     ) -> Result<GetConsoleScreenshotResult, RusotoError<GetConsoleScreenshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetConsoleScreenshot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetConsoleScreenshot");
         let mut params = params;
         GetConsoleScreenshotRequestSerializer::serialize(&mut params, "", &input);
@@ -92046,6 +92939,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetDefaultCreditSpecificationResult, RusotoError<GetDefaultCreditSpecificationError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetDefaultCreditSpecification");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetDefaultCreditSpecification");
         let mut params = params;
         GetDefaultCreditSpecificationRequestSerializer::serialize(&mut params, "", &input);
@@ -92072,6 +92968,9 @@ impl Ec2 for Ec2Client {
         input: GetEbsDefaultKmsKeyIdRequest,
     ) -> Result<GetEbsDefaultKmsKeyIdResult, RusotoError<GetEbsDefaultKmsKeyIdError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetEbsDefaultKmsKeyId");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetEbsDefaultKmsKeyId");
         let mut params = params;
         GetEbsDefaultKmsKeyIdRequestSerializer::serialize(&mut params, "", &input);
@@ -92098,6 +92997,9 @@ impl Ec2 for Ec2Client {
         input: GetEbsEncryptionByDefaultRequest,
     ) -> Result<GetEbsEncryptionByDefaultResult, RusotoError<GetEbsEncryptionByDefaultError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetEbsEncryptionByDefault");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetEbsEncryptionByDefault");
         let mut params = params;
         GetEbsEncryptionByDefaultRequestSerializer::serialize(&mut params, "", &input);
@@ -92127,6 +93029,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetHostReservationPurchasePreviewError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetHostReservationPurchasePreview");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetHostReservationPurchasePreview");
         let mut params = params;
         GetHostReservationPurchasePreviewRequestSerializer::serialize(&mut params, "", &input);
@@ -92156,6 +93061,9 @@ impl Ec2 for Ec2Client {
         input: GetLaunchTemplateDataRequest,
     ) -> Result<GetLaunchTemplateDataResult, RusotoError<GetLaunchTemplateDataError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetLaunchTemplateData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetLaunchTemplateData");
         let mut params = params;
         GetLaunchTemplateDataRequestSerializer::serialize(&mut params, "", &input);
@@ -92185,6 +93093,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetManagedPrefixListAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetManagedPrefixListAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetManagedPrefixListAssociations");
         let mut params = params;
         GetManagedPrefixListAssociationsRequestSerializer::serialize(&mut params, "", &input);
@@ -92215,6 +93126,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetManagedPrefixListEntriesResult, RusotoError<GetManagedPrefixListEntriesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetManagedPrefixListEntries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetManagedPrefixListEntries");
         let mut params = params;
         GetManagedPrefixListEntriesRequestSerializer::serialize(&mut params, "", &input);
@@ -92241,6 +93155,9 @@ impl Ec2 for Ec2Client {
         input: GetPasswordDataRequest,
     ) -> Result<GetPasswordDataResult, RusotoError<GetPasswordDataError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetPasswordData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetPasswordData");
         let mut params = params;
         GetPasswordDataRequestSerializer::serialize(&mut params, "", &input);
@@ -92270,6 +93187,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetReservedInstancesExchangeQuoteError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetReservedInstancesExchangeQuote");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetReservedInstancesExchangeQuote");
         let mut params = params;
         GetReservedInstancesExchangeQuoteRequestSerializer::serialize(&mut params, "", &input);
@@ -92302,6 +93222,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayAttachmentPropagationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetTransitGatewayAttachmentPropagations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTransitGatewayAttachmentPropagations");
         let mut params = params;
         GetTransitGatewayAttachmentPropagationsRequestSerializer::serialize(
@@ -92341,6 +93264,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayMulticastDomainAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetTransitGatewayMulticastDomainAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTransitGatewayMulticastDomainAssociations");
         let mut params = params;
         GetTransitGatewayMulticastDomainAssociationsRequestSerializer::serialize(
@@ -92380,6 +93306,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayRouteTableAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetTransitGatewayRouteTableAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTransitGatewayRouteTableAssociations");
         let mut params = params;
         GetTransitGatewayRouteTableAssociationsRequestSerializer::serialize(
@@ -92419,6 +93348,9 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayRouteTablePropagationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("GetTransitGatewayRouteTablePropagations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTransitGatewayRouteTablePropagations");
         let mut params = params;
         GetTransitGatewayRouteTablePropagationsRequestSerializer::serialize(
@@ -92458,6 +93390,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ImportClientVpnClientCertificateRevocationListError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ImportClientVpnClientCertificateRevocationList");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportClientVpnClientCertificateRevocationList");
         let mut params = params;
         ImportClientVpnClientCertificateRevocationListRequestSerializer::serialize(
@@ -92494,6 +93429,9 @@ impl Ec2 for Ec2Client {
         input: ImportImageRequest,
     ) -> Result<ImportImageResult, RusotoError<ImportImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ImportImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportImage");
         let mut params = params;
         ImportImageRequestSerializer::serialize(&mut params, "", &input);
@@ -92520,6 +93458,9 @@ impl Ec2 for Ec2Client {
         input: ImportInstanceRequest,
     ) -> Result<ImportInstanceResult, RusotoError<ImportInstanceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ImportInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportInstance");
         let mut params = params;
         ImportInstanceRequestSerializer::serialize(&mut params, "", &input);
@@ -92546,6 +93487,9 @@ impl Ec2 for Ec2Client {
         input: ImportKeyPairRequest,
     ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ImportKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportKeyPair");
         let mut params = params;
         ImportKeyPairRequestSerializer::serialize(&mut params, "", &input);
@@ -92572,6 +93516,9 @@ impl Ec2 for Ec2Client {
         input: ImportSnapshotRequest,
     ) -> Result<ImportSnapshotResult, RusotoError<ImportSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ImportSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportSnapshot");
         let mut params = params;
         ImportSnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -92598,6 +93545,9 @@ impl Ec2 for Ec2Client {
         input: ImportVolumeRequest,
     ) -> Result<ImportVolumeResult, RusotoError<ImportVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ImportVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportVolume");
         let mut params = params;
         ImportVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -92625,6 +93575,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyAvailabilityZoneGroupResult, RusotoError<ModifyAvailabilityZoneGroupError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyAvailabilityZoneGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyAvailabilityZoneGroup");
         let mut params = params;
         ModifyAvailabilityZoneGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -92651,6 +93604,9 @@ impl Ec2 for Ec2Client {
         input: ModifyCapacityReservationRequest,
     ) -> Result<ModifyCapacityReservationResult, RusotoError<ModifyCapacityReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyCapacityReservation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCapacityReservation");
         let mut params = params;
         ModifyCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -92677,6 +93633,9 @@ impl Ec2 for Ec2Client {
         input: ModifyClientVpnEndpointRequest,
     ) -> Result<ModifyClientVpnEndpointResult, RusotoError<ModifyClientVpnEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyClientVpnEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClientVpnEndpoint");
         let mut params = params;
         ModifyClientVpnEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -92706,6 +93665,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyDefaultCreditSpecificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyDefaultCreditSpecification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDefaultCreditSpecification");
         let mut params = params;
         ModifyDefaultCreditSpecificationRequestSerializer::serialize(&mut params, "", &input);
@@ -92735,6 +93697,9 @@ impl Ec2 for Ec2Client {
         input: ModifyEbsDefaultKmsKeyIdRequest,
     ) -> Result<ModifyEbsDefaultKmsKeyIdResult, RusotoError<ModifyEbsDefaultKmsKeyIdError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyEbsDefaultKmsKeyId");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyEbsDefaultKmsKeyId");
         let mut params = params;
         ModifyEbsDefaultKmsKeyIdRequestSerializer::serialize(&mut params, "", &input);
@@ -92761,6 +93726,9 @@ impl Ec2 for Ec2Client {
         input: ModifyFleetRequest,
     ) -> Result<ModifyFleetResult, RusotoError<ModifyFleetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyFleet");
         let mut params = params;
         ModifyFleetRequestSerializer::serialize(&mut params, "", &input);
@@ -92787,6 +93755,9 @@ impl Ec2 for Ec2Client {
         input: ModifyFpgaImageAttributeRequest,
     ) -> Result<ModifyFpgaImageAttributeResult, RusotoError<ModifyFpgaImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyFpgaImageAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyFpgaImageAttribute");
         let mut params = params;
         ModifyFpgaImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -92813,6 +93784,9 @@ impl Ec2 for Ec2Client {
         input: ModifyHostsRequest,
     ) -> Result<ModifyHostsResult, RusotoError<ModifyHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyHosts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyHosts");
         let mut params = params;
         ModifyHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -92839,6 +93813,9 @@ impl Ec2 for Ec2Client {
         input: ModifyIdFormatRequest,
     ) -> Result<(), RusotoError<ModifyIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyIdFormat");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyIdFormat");
         let mut params = params;
         ModifyIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -92859,6 +93836,9 @@ impl Ec2 for Ec2Client {
         input: ModifyIdentityIdFormatRequest,
     ) -> Result<(), RusotoError<ModifyIdentityIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyIdentityIdFormat");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyIdentityIdFormat");
         let mut params = params;
         ModifyIdentityIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -92879,6 +93859,9 @@ impl Ec2 for Ec2Client {
         input: ModifyImageAttributeRequest,
     ) -> Result<(), RusotoError<ModifyImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyImageAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyImageAttribute");
         let mut params = params;
         ModifyImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -92899,6 +93882,9 @@ impl Ec2 for Ec2Client {
         input: ModifyInstanceAttributeRequest,
     ) -> Result<(), RusotoError<ModifyInstanceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyInstanceAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyInstanceAttribute");
         let mut params = params;
         ModifyInstanceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -92922,6 +93908,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyInstanceCapacityReservationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyInstanceCapacityReservationAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyInstanceCapacityReservationAttributes");
         let mut params = params;
         ModifyInstanceCapacityReservationAttributesRequestSerializer::serialize(
@@ -92961,6 +93950,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyInstanceCreditSpecificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyInstanceCreditSpecification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyInstanceCreditSpecification");
         let mut params = params;
         ModifyInstanceCreditSpecificationRequestSerializer::serialize(&mut params, "", &input);
@@ -92991,6 +93983,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyInstanceEventStartTimeResult, RusotoError<ModifyInstanceEventStartTimeError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyInstanceEventStartTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyInstanceEventStartTime");
         let mut params = params;
         ModifyInstanceEventStartTimeRequestSerializer::serialize(&mut params, "", &input);
@@ -93018,6 +94013,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyInstanceMetadataOptionsResult, RusotoError<ModifyInstanceMetadataOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyInstanceMetadataOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyInstanceMetadataOptions");
         let mut params = params;
         ModifyInstanceMetadataOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -93044,6 +94042,9 @@ impl Ec2 for Ec2Client {
         input: ModifyInstancePlacementRequest,
     ) -> Result<ModifyInstancePlacementResult, RusotoError<ModifyInstancePlacementError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyInstancePlacement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyInstancePlacement");
         let mut params = params;
         ModifyInstancePlacementRequestSerializer::serialize(&mut params, "", &input);
@@ -93070,6 +94071,9 @@ impl Ec2 for Ec2Client {
         input: ModifyLaunchTemplateRequest,
     ) -> Result<ModifyLaunchTemplateResult, RusotoError<ModifyLaunchTemplateError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyLaunchTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("ModifyLaunchTemplate");
         let mut params = params;
         ModifyLaunchTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -93096,6 +94100,9 @@ impl Ec2 for Ec2Client {
         input: ModifyManagedPrefixListRequest,
     ) -> Result<ModifyManagedPrefixListResult, RusotoError<ModifyManagedPrefixListError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyManagedPrefixList");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyManagedPrefixList");
         let mut params = params;
         ModifyManagedPrefixListRequestSerializer::serialize(&mut params, "", &input);
@@ -93122,6 +94129,9 @@ impl Ec2 for Ec2Client {
         input: ModifyNetworkInterfaceAttributeRequest,
     ) -> Result<(), RusotoError<ModifyNetworkInterfaceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyNetworkInterfaceAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyNetworkInterfaceAttribute");
         let mut params = params;
         ModifyNetworkInterfaceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -93142,6 +94152,9 @@ impl Ec2 for Ec2Client {
         input: ModifyReservedInstancesRequest,
     ) -> Result<ModifyReservedInstancesResult, RusotoError<ModifyReservedInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyReservedInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyReservedInstances");
         let mut params = params;
         ModifyReservedInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -93168,6 +94181,9 @@ impl Ec2 for Ec2Client {
         input: ModifySnapshotAttributeRequest,
     ) -> Result<(), RusotoError<ModifySnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifySnapshotAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifySnapshotAttribute");
         let mut params = params;
         ModifySnapshotAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -93188,6 +94204,9 @@ impl Ec2 for Ec2Client {
         input: ModifySpotFleetRequestRequest,
     ) -> Result<ModifySpotFleetRequestResponse, RusotoError<ModifySpotFleetRequestError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifySpotFleetRequest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifySpotFleetRequest");
         let mut params = params;
         ModifySpotFleetRequestRequestSerializer::serialize(&mut params, "", &input);
@@ -93214,6 +94233,9 @@ impl Ec2 for Ec2Client {
         input: ModifySubnetAttributeRequest,
     ) -> Result<(), RusotoError<ModifySubnetAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifySubnetAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifySubnetAttribute");
         let mut params = params;
         ModifySubnetAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -93237,6 +94259,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyTrafficMirrorFilterNetworkServicesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyTrafficMirrorFilterNetworkServices");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyTrafficMirrorFilterNetworkServices");
         let mut params = params;
         ModifyTrafficMirrorFilterNetworkServicesRequestSerializer::serialize(
@@ -93274,6 +94299,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyTrafficMirrorFilterRuleResult, RusotoError<ModifyTrafficMirrorFilterRuleError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyTrafficMirrorFilterRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyTrafficMirrorFilterRule");
         let mut params = params;
         ModifyTrafficMirrorFilterRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -93301,6 +94329,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyTrafficMirrorSessionResult, RusotoError<ModifyTrafficMirrorSessionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyTrafficMirrorSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyTrafficMirrorSession");
         let mut params = params;
         ModifyTrafficMirrorSessionRequestSerializer::serialize(&mut params, "", &input);
@@ -93330,6 +94361,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyTransitGatewayVpcAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyTransitGatewayVpcAttachment");
         let mut params = params;
         ModifyTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -93359,6 +94393,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVolumeRequest,
     ) -> Result<ModifyVolumeResult, RusotoError<ModifyVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVolume");
         let mut params = params;
         ModifyVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -93385,6 +94422,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVolumeAttributeRequest,
     ) -> Result<(), RusotoError<ModifyVolumeAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVolumeAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVolumeAttribute");
         let mut params = params;
         ModifyVolumeAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -93405,6 +94445,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVpcAttributeRequest,
     ) -> Result<(), RusotoError<ModifyVpcAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcAttribute");
         let mut params = params;
         ModifyVpcAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -93425,6 +94468,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVpcEndpointRequest,
     ) -> Result<ModifyVpcEndpointResult, RusotoError<ModifyVpcEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcEndpoint");
         let mut params = params;
         ModifyVpcEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -93454,6 +94500,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcEndpointConnectionNotificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcEndpointConnectionNotification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcEndpointConnectionNotification");
         let mut params = params;
         ModifyVpcEndpointConnectionNotificationRequestSerializer::serialize(
@@ -93493,6 +94542,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcEndpointServiceConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcEndpointServiceConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcEndpointServiceConfiguration");
         let mut params = params;
         ModifyVpcEndpointServiceConfigurationRequestSerializer::serialize(&mut params, "", &input);
@@ -93528,6 +94580,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcEndpointServicePermissionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcEndpointServicePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcEndpointServicePermissions");
         let mut params = params;
         ModifyVpcEndpointServicePermissionsRequestSerializer::serialize(&mut params, "", &input);
@@ -93563,6 +94618,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcPeeringConnectionOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcPeeringConnectionOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcPeeringConnectionOptions");
         let mut params = params;
         ModifyVpcPeeringConnectionOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -93592,6 +94650,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVpcTenancyRequest,
     ) -> Result<ModifyVpcTenancyResult, RusotoError<ModifyVpcTenancyError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpcTenancy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpcTenancy");
         let mut params = params;
         ModifyVpcTenancyRequestSerializer::serialize(&mut params, "", &input);
@@ -93618,6 +94679,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVpnConnectionRequest,
     ) -> Result<ModifyVpnConnectionResult, RusotoError<ModifyVpnConnectionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpnConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpnConnection");
         let mut params = params;
         ModifyVpnConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -93645,6 +94709,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyVpnTunnelCertificateResult, RusotoError<ModifyVpnTunnelCertificateError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpnTunnelCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpnTunnelCertificate");
         let mut params = params;
         ModifyVpnTunnelCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -93671,6 +94738,9 @@ impl Ec2 for Ec2Client {
         input: ModifyVpnTunnelOptionsRequest,
     ) -> Result<ModifyVpnTunnelOptionsResult, RusotoError<ModifyVpnTunnelOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ModifyVpnTunnelOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyVpnTunnelOptions");
         let mut params = params;
         ModifyVpnTunnelOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -93697,6 +94767,9 @@ impl Ec2 for Ec2Client {
         input: MonitorInstancesRequest,
     ) -> Result<MonitorInstancesResult, RusotoError<MonitorInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("MonitorInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("MonitorInstances");
         let mut params = params;
         MonitorInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -93723,6 +94796,9 @@ impl Ec2 for Ec2Client {
         input: MoveAddressToVpcRequest,
     ) -> Result<MoveAddressToVpcResult, RusotoError<MoveAddressToVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("MoveAddressToVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("MoveAddressToVpc");
         let mut params = params;
         MoveAddressToVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -93749,6 +94825,9 @@ impl Ec2 for Ec2Client {
         input: ProvisionByoipCidrRequest,
     ) -> Result<ProvisionByoipCidrResult, RusotoError<ProvisionByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ProvisionByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ProvisionByoipCidr");
         let mut params = params;
         ProvisionByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -93775,6 +94854,9 @@ impl Ec2 for Ec2Client {
         input: PurchaseHostReservationRequest,
     ) -> Result<PurchaseHostReservationResult, RusotoError<PurchaseHostReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("PurchaseHostReservation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PurchaseHostReservation");
         let mut params = params;
         PurchaseHostReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -93804,6 +94886,9 @@ impl Ec2 for Ec2Client {
         RusotoError<PurchaseReservedInstancesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("PurchaseReservedInstancesOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PurchaseReservedInstancesOffering");
         let mut params = params;
         PurchaseReservedInstancesOfferingRequestSerializer::serialize(&mut params, "", &input);
@@ -93834,6 +94919,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<PurchaseScheduledInstancesResult, RusotoError<PurchaseScheduledInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("PurchaseScheduledInstances");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("PurchaseScheduledInstances");
         let mut params = params;
         PurchaseScheduledInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -93860,6 +94948,9 @@ impl Ec2 for Ec2Client {
         input: RebootInstancesRequest,
     ) -> Result<(), RusotoError<RebootInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RebootInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebootInstances");
         let mut params = params;
         RebootInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -93880,6 +94971,9 @@ impl Ec2 for Ec2Client {
         input: RegisterImageRequest,
     ) -> Result<RegisterImageResult, RusotoError<RegisterImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RegisterImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterImage");
         let mut params = params;
         RegisterImageRequestSerializer::serialize(&mut params, "", &input);
@@ -93909,6 +95003,9 @@ impl Ec2 for Ec2Client {
         RusotoError<RegisterInstanceEventNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RegisterInstanceEventNotificationAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterInstanceEventNotificationAttributes");
         let mut params = params;
         RegisterInstanceEventNotificationAttributesRequestSerializer::serialize(
@@ -93948,6 +95045,9 @@ impl Ec2 for Ec2Client {
         RusotoError<RegisterTransitGatewayMulticastGroupMembersError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RegisterTransitGatewayMulticastGroupMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterTransitGatewayMulticastGroupMembers");
         let mut params = params;
         RegisterTransitGatewayMulticastGroupMembersRequestSerializer::serialize(
@@ -93987,6 +95087,9 @@ impl Ec2 for Ec2Client {
         RusotoError<RegisterTransitGatewayMulticastGroupSourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RegisterTransitGatewayMulticastGroupSources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterTransitGatewayMulticastGroupSources");
         let mut params = params;
         RegisterTransitGatewayMulticastGroupSourcesRequestSerializer::serialize(
@@ -94026,6 +95129,9 @@ impl Ec2 for Ec2Client {
         RusotoError<RejectTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RejectTransitGatewayPeeringAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RejectTransitGatewayPeeringAttachment");
         let mut params = params;
         RejectTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -94061,6 +95167,9 @@ impl Ec2 for Ec2Client {
         RusotoError<RejectTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RejectTransitGatewayVpcAttachment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RejectTransitGatewayVpcAttachment");
         let mut params = params;
         RejectTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -94091,6 +95200,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<RejectVpcEndpointConnectionsResult, RusotoError<RejectVpcEndpointConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RejectVpcEndpointConnections");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RejectVpcEndpointConnections");
         let mut params = params;
         RejectVpcEndpointConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -94118,6 +95230,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<RejectVpcPeeringConnectionResult, RusotoError<RejectVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RejectVpcPeeringConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RejectVpcPeeringConnection");
         let mut params = params;
         RejectVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -94144,6 +95259,9 @@ impl Ec2 for Ec2Client {
         input: ReleaseAddressRequest,
     ) -> Result<(), RusotoError<ReleaseAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReleaseAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReleaseAddress");
         let mut params = params;
         ReleaseAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -94164,6 +95282,9 @@ impl Ec2 for Ec2Client {
         input: ReleaseHostsRequest,
     ) -> Result<ReleaseHostsResult, RusotoError<ReleaseHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReleaseHosts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReleaseHosts");
         let mut params = params;
         ReleaseHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -94193,6 +95314,9 @@ impl Ec2 for Ec2Client {
         RusotoError<ReplaceIamInstanceProfileAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReplaceIamInstanceProfileAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReplaceIamInstanceProfileAssociation");
         let mut params = params;
         ReplaceIamInstanceProfileAssociationRequestSerializer::serialize(&mut params, "", &input);
@@ -94226,6 +95350,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ReplaceNetworkAclAssociationResult, RusotoError<ReplaceNetworkAclAssociationError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReplaceNetworkAclAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReplaceNetworkAclAssociation");
         let mut params = params;
         ReplaceNetworkAclAssociationRequestSerializer::serialize(&mut params, "", &input);
@@ -94252,6 +95379,9 @@ impl Ec2 for Ec2Client {
         input: ReplaceNetworkAclEntryRequest,
     ) -> Result<(), RusotoError<ReplaceNetworkAclEntryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReplaceNetworkAclEntry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReplaceNetworkAclEntry");
         let mut params = params;
         ReplaceNetworkAclEntryRequestSerializer::serialize(&mut params, "", &input);
@@ -94272,6 +95402,9 @@ impl Ec2 for Ec2Client {
         input: ReplaceRouteRequest,
     ) -> Result<(), RusotoError<ReplaceRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReplaceRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReplaceRoute");
         let mut params = params;
         ReplaceRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -94293,6 +95426,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ReplaceRouteTableAssociationResult, RusotoError<ReplaceRouteTableAssociationError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReplaceRouteTableAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReplaceRouteTableAssociation");
         let mut params = params;
         ReplaceRouteTableAssociationRequestSerializer::serialize(&mut params, "", &input);
@@ -94320,6 +95456,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<ReplaceTransitGatewayRouteResult, RusotoError<ReplaceTransitGatewayRouteError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReplaceTransitGatewayRoute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReplaceTransitGatewayRoute");
         let mut params = params;
         ReplaceTransitGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -94346,6 +95485,9 @@ impl Ec2 for Ec2Client {
         input: ReportInstanceStatusRequest,
     ) -> Result<(), RusotoError<ReportInstanceStatusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ReportInstanceStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReportInstanceStatus");
         let mut params = params;
         ReportInstanceStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -94366,6 +95508,9 @@ impl Ec2 for Ec2Client {
         input: RequestSpotFleetRequest,
     ) -> Result<RequestSpotFleetResponse, RusotoError<RequestSpotFleetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RequestSpotFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RequestSpotFleet");
         let mut params = params;
         RequestSpotFleetRequestSerializer::serialize(&mut params, "", &input);
@@ -94392,6 +95537,9 @@ impl Ec2 for Ec2Client {
         input: RequestSpotInstancesRequest,
     ) -> Result<RequestSpotInstancesResult, RusotoError<RequestSpotInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RequestSpotInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RequestSpotInstances");
         let mut params = params;
         RequestSpotInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94418,6 +95566,9 @@ impl Ec2 for Ec2Client {
         input: ResetEbsDefaultKmsKeyIdRequest,
     ) -> Result<ResetEbsDefaultKmsKeyIdResult, RusotoError<ResetEbsDefaultKmsKeyIdError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ResetEbsDefaultKmsKeyId");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetEbsDefaultKmsKeyId");
         let mut params = params;
         ResetEbsDefaultKmsKeyIdRequestSerializer::serialize(&mut params, "", &input);
@@ -94444,6 +95595,9 @@ impl Ec2 for Ec2Client {
         input: ResetFpgaImageAttributeRequest,
     ) -> Result<ResetFpgaImageAttributeResult, RusotoError<ResetFpgaImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ResetFpgaImageAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetFpgaImageAttribute");
         let mut params = params;
         ResetFpgaImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -94470,6 +95624,9 @@ impl Ec2 for Ec2Client {
         input: ResetImageAttributeRequest,
     ) -> Result<(), RusotoError<ResetImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ResetImageAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetImageAttribute");
         let mut params = params;
         ResetImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -94490,6 +95647,9 @@ impl Ec2 for Ec2Client {
         input: ResetInstanceAttributeRequest,
     ) -> Result<(), RusotoError<ResetInstanceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ResetInstanceAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetInstanceAttribute");
         let mut params = params;
         ResetInstanceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -94510,6 +95670,9 @@ impl Ec2 for Ec2Client {
         input: ResetNetworkInterfaceAttributeRequest,
     ) -> Result<(), RusotoError<ResetNetworkInterfaceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ResetNetworkInterfaceAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetNetworkInterfaceAttribute");
         let mut params = params;
         ResetNetworkInterfaceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -94530,6 +95693,9 @@ impl Ec2 for Ec2Client {
         input: ResetSnapshotAttributeRequest,
     ) -> Result<(), RusotoError<ResetSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("ResetSnapshotAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetSnapshotAttribute");
         let mut params = params;
         ResetSnapshotAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -94550,6 +95716,9 @@ impl Ec2 for Ec2Client {
         input: RestoreAddressToClassicRequest,
     ) -> Result<RestoreAddressToClassicResult, RusotoError<RestoreAddressToClassicError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RestoreAddressToClassic");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreAddressToClassic");
         let mut params = params;
         RestoreAddressToClassicRequestSerializer::serialize(&mut params, "", &input);
@@ -94579,6 +95748,9 @@ impl Ec2 for Ec2Client {
         RusotoError<RestoreManagedPrefixListVersionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RestoreManagedPrefixListVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreManagedPrefixListVersion");
         let mut params = params;
         RestoreManagedPrefixListVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -94605,6 +95777,9 @@ impl Ec2 for Ec2Client {
         input: RevokeClientVpnIngressRequest,
     ) -> Result<RevokeClientVpnIngressResult, RusotoError<RevokeClientVpnIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RevokeClientVpnIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeClientVpnIngress");
         let mut params = params;
         RevokeClientVpnIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -94631,6 +95806,9 @@ impl Ec2 for Ec2Client {
         input: RevokeSecurityGroupEgressRequest,
     ) -> Result<(), RusotoError<RevokeSecurityGroupEgressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RevokeSecurityGroupEgress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeSecurityGroupEgress");
         let mut params = params;
         RevokeSecurityGroupEgressRequestSerializer::serialize(&mut params, "", &input);
@@ -94651,6 +95829,9 @@ impl Ec2 for Ec2Client {
         input: RevokeSecurityGroupIngressRequest,
     ) -> Result<(), RusotoError<RevokeSecurityGroupIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RevokeSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeSecurityGroupIngress");
         let mut params = params;
         RevokeSecurityGroupIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -94671,6 +95852,9 @@ impl Ec2 for Ec2Client {
         input: RunInstancesRequest,
     ) -> Result<Reservation, RusotoError<RunInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RunInstances");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("RunInstances");
         let mut params = params;
         RunInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94697,6 +95881,9 @@ impl Ec2 for Ec2Client {
         input: RunScheduledInstancesRequest,
     ) -> Result<RunScheduledInstancesResult, RusotoError<RunScheduledInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("RunScheduledInstances");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         let params = self.new_params("RunScheduledInstances");
         let mut params = params;
         RunScheduledInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94723,6 +95910,9 @@ impl Ec2 for Ec2Client {
         input: SearchLocalGatewayRoutesRequest,
     ) -> Result<SearchLocalGatewayRoutesResult, RusotoError<SearchLocalGatewayRoutesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("SearchLocalGatewayRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("SearchLocalGatewayRoutes");
         let mut params = params;
         SearchLocalGatewayRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -94752,6 +95942,9 @@ impl Ec2 for Ec2Client {
         RusotoError<SearchTransitGatewayMulticastGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("SearchTransitGatewayMulticastGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("SearchTransitGatewayMulticastGroups");
         let mut params = params;
         SearchTransitGatewayMulticastGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -94785,6 +95978,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<SearchTransitGatewayRoutesResult, RusotoError<SearchTransitGatewayRoutesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("SearchTransitGatewayRoutes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("SearchTransitGatewayRoutes");
         let mut params = params;
         SearchTransitGatewayRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -94811,6 +96007,9 @@ impl Ec2 for Ec2Client {
         input: SendDiagnosticInterruptRequest,
     ) -> Result<(), RusotoError<SendDiagnosticInterruptError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("SendDiagnosticInterrupt");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendDiagnosticInterrupt");
         let mut params = params;
         SendDiagnosticInterruptRequestSerializer::serialize(&mut params, "", &input);
@@ -94831,6 +96030,9 @@ impl Ec2 for Ec2Client {
         input: StartInstancesRequest,
     ) -> Result<StartInstancesResult, RusotoError<StartInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("StartInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartInstances");
         let mut params = params;
         StartInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94860,6 +96062,9 @@ impl Ec2 for Ec2Client {
         RusotoError<StartVpcEndpointServicePrivateDnsVerificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("StartVpcEndpointServicePrivateDnsVerification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartVpcEndpointServicePrivateDnsVerification");
         let mut params = params;
         StartVpcEndpointServicePrivateDnsVerificationRequestSerializer::serialize(
@@ -94896,6 +96101,9 @@ impl Ec2 for Ec2Client {
         input: StopInstancesRequest,
     ) -> Result<StopInstancesResult, RusotoError<StopInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("StopInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopInstances");
         let mut params = params;
         StopInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94923,6 +96131,9 @@ impl Ec2 for Ec2Client {
     ) -> Result<TerminateClientVpnConnectionsResult, RusotoError<TerminateClientVpnConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("TerminateClientVpnConnections");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TerminateClientVpnConnections");
         let mut params = params;
         TerminateClientVpnConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -94949,6 +96160,9 @@ impl Ec2 for Ec2Client {
         input: TerminateInstancesRequest,
     ) -> Result<TerminateInstancesResult, RusotoError<TerminateInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("TerminateInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TerminateInstances");
         let mut params = params;
         TerminateInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94975,6 +96189,9 @@ impl Ec2 for Ec2Client {
         input: UnassignIpv6AddressesRequest,
     ) -> Result<UnassignIpv6AddressesResult, RusotoError<UnassignIpv6AddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("UnassignIpv6Addresses");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UnassignIpv6Addresses");
         let mut params = params;
         UnassignIpv6AddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -95001,6 +96218,9 @@ impl Ec2 for Ec2Client {
         input: UnassignPrivateIpAddressesRequest,
     ) -> Result<(), RusotoError<UnassignPrivateIpAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("UnassignPrivateIpAddresses");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UnassignPrivateIpAddresses");
         let mut params = params;
         UnassignPrivateIpAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -95021,6 +96241,9 @@ impl Ec2 for Ec2Client {
         input: UnmonitorInstancesRequest,
     ) -> Result<UnmonitorInstancesResult, RusotoError<UnmonitorInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("UnmonitorInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UnmonitorInstances");
         let mut params = params;
         UnmonitorInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -95050,6 +96273,9 @@ impl Ec2 for Ec2Client {
         RusotoError<UpdateSecurityGroupRuleDescriptionsEgressError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("UpdateSecurityGroupRuleDescriptionsEgress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateSecurityGroupRuleDescriptionsEgress");
         let mut params = params;
         UpdateSecurityGroupRuleDescriptionsEgressRequestSerializer::serialize(
@@ -95089,6 +96315,9 @@ impl Ec2 for Ec2Client {
         RusotoError<UpdateSecurityGroupRuleDescriptionsIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("UpdateSecurityGroupRuleDescriptionsIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateSecurityGroupRuleDescriptionsIngress");
         let mut params = params;
         UpdateSecurityGroupRuleDescriptionsIngressRequestSerializer::serialize(
@@ -95125,6 +96354,9 @@ impl Ec2 for Ec2Client {
         input: WithdrawByoipCidrRequest,
     ) -> Result<WithdrawByoipCidrResult, RusotoError<WithdrawByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation_name("WithdrawByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("WithdrawByoipCidr");
         let mut params = params;
         WithdrawByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -95146,6 +96378,4916 @@ impl Ec2 for Ec2Client {
     }
 }
 
+/// A blocking client for the Amazon EC2 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Ec2Client` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Ec2BlockingClient {
+    client: Ec2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Ec2BlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Ec2BlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Ec2BlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Ec2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Ec2BlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> Ec2BlockingClient {
+        Ec2BlockingClient {
+            client: Ec2Client::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts the Convertible Reserved Instance exchange quote described in the <a>GetReservedInstancesExchangeQuote</a> call.</p>
+    ///
+    /// See `Ec2::accept_reserved_instances_exchange_quote`.
+    pub fn accept_reserved_instances_exchange_quote(
+        &self,
+        input: AcceptReservedInstancesExchangeQuoteRequest,
+    ) -> Result<
+        AcceptReservedInstancesExchangeQuoteResult,
+        RusotoError<AcceptReservedInstancesExchangeQuoteError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_reserved_instances_exchange_quote(input))
+    }
+
+    /// <p>Accepts a transit gateway peering attachment request. The peering attachment must be in the <code>pendingAcceptance</code> state.</p>
+    ///
+    /// See `Ec2::accept_transit_gateway_peering_attachment`.
+    pub fn accept_transit_gateway_peering_attachment(
+        &self,
+        input: AcceptTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        AcceptTransitGatewayPeeringAttachmentResult,
+        RusotoError<AcceptTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_transit_gateway_peering_attachment(input))
+    }
+
+    /// <p>Accepts a request to attach a VPC to a transit gateway.</p> <p>The VPC attachment must be in the <code>pendingAcceptance</code> state. Use <a>DescribeTransitGatewayVpcAttachments</a> to view your pending VPC attachment requests. Use <a>RejectTransitGatewayVpcAttachment</a> to reject a VPC attachment request.</p>
+    ///
+    /// See `Ec2::accept_transit_gateway_vpc_attachment`.
+    pub fn accept_transit_gateway_vpc_attachment(
+        &self,
+        input: AcceptTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        AcceptTransitGatewayVpcAttachmentResult,
+        RusotoError<AcceptTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_transit_gateway_vpc_attachment(input))
+    }
+
+    /// <p>Accepts one or more interface VPC endpoint connection requests to your VPC endpoint service.</p>
+    ///
+    /// See `Ec2::accept_vpc_endpoint_connections`.
+    pub fn accept_vpc_endpoint_connections(
+        &self,
+        input: AcceptVpcEndpointConnectionsRequest,
+    ) -> Result<AcceptVpcEndpointConnectionsResult, RusotoError<AcceptVpcEndpointConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_vpc_endpoint_connections(input))
+    }
+
+    /// <p>Accept a VPC peering connection request. To accept a request, the VPC peering connection must be in the <code>pending-acceptance</code> state, and you must be the owner of the peer VPC. Use <a>DescribeVpcPeeringConnections</a> to view your outstanding VPC peering connection requests.</p> <p>For an inter-Region VPC peering connection request, you must accept the VPC peering connection in the Region of the accepter VPC.</p>
+    ///
+    /// See `Ec2::accept_vpc_peering_connection`.
+    pub fn accept_vpc_peering_connection(
+        &self,
+        input: AcceptVpcPeeringConnectionRequest,
+    ) -> Result<AcceptVpcPeeringConnectionResult, RusotoError<AcceptVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_vpc_peering_connection(input))
+    }
+
+    /// <p>Advertises an IPv4 or IPv6 address range that is provisioned for use with your AWS resources through bring your own IP addresses (BYOIP).</p> <p>You can perform this operation at most once every 10 seconds, even if you specify different address ranges each time.</p> <p>We recommend that you stop advertising the BYOIP CIDR from other locations when you advertise it from AWS. To minimize down time, you can configure your AWS resources to use an address from a BYOIP CIDR before it is advertised, and then simultaneously stop advertising it from the current location and start advertising it through AWS.</p> <p>It can take a few minutes before traffic to the specified addresses starts routing to AWS because of BGP propagation delays.</p> <p>To stop advertising the BYOIP CIDR, use <a>WithdrawByoipCidr</a>.</p>
+    ///
+    /// See `Ec2::advertise_byoip_cidr`.
+    pub fn advertise_byoip_cidr(
+        &self,
+        input: AdvertiseByoipCidrRequest,
+    ) -> Result<AdvertiseByoipCidrResult, RusotoError<AdvertiseByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.advertise_byoip_cidr(input))
+    }
+
+    /// <p>Allocates an Elastic IP address to your AWS account. After you allocate the Elastic IP address you can associate it with an instance or network interface. After you release an Elastic IP address, it is released to the IP address pool and can be allocated to a different AWS account.</p> <p>You can allocate an Elastic IP address from an address pool owned by AWS or from an address pool created from a public IPv4 address range that you have brought to AWS for use with your AWS resources using bring your own IP addresses (BYOIP). For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-byoip.html">Bring Your Own IP Addresses (BYOIP)</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>[EC2-VPC] If you release an Elastic IP address, you might be able to recover it. You cannot recover an Elastic IP address that you released after it is allocated to another AWS account. You cannot recover an Elastic IP address for EC2-Classic. To attempt to recover an Elastic IP address that you released, specify it in this operation.</p> <p>An Elastic IP address is for use either in the EC2-Classic platform or in a VPC. By default, you can allocate 5 Elastic IP addresses for EC2-Classic per Region and 5 Elastic IP addresses for EC2-VPC per Region.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::allocate_address`.
+    pub fn allocate_address(
+        &self,
+        input: AllocateAddressRequest,
+    ) -> Result<AllocateAddressResult, RusotoError<AllocateAddressError>> {
+        self.runtime.block_on(self.client.allocate_address(input))
+    }
+
+    /// <p>Allocates a Dedicated Host to your account. At a minimum, specify the supported instance type or instance family, the Availability Zone in which to allocate the host, and the number of hosts to allocate.</p>
+    ///
+    /// See `Ec2::allocate_hosts`.
+    pub fn allocate_hosts(
+        &self,
+        input: AllocateHostsRequest,
+    ) -> Result<AllocateHostsResult, RusotoError<AllocateHostsError>> {
+        self.runtime.block_on(self.client.allocate_hosts(input))
+    }
+
+    /// <p>Applies a security group to the association between the target network and the Client VPN endpoint. This action replaces the existing security groups with the specified security groups.</p>
+    ///
+    /// See `Ec2::apply_security_groups_to_client_vpn_target_network`.
+    pub fn apply_security_groups_to_client_vpn_target_network(
+        &self,
+        input: ApplySecurityGroupsToClientVpnTargetNetworkRequest,
+    ) -> Result<
+        ApplySecurityGroupsToClientVpnTargetNetworkResult,
+        RusotoError<ApplySecurityGroupsToClientVpnTargetNetworkError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .apply_security_groups_to_client_vpn_target_network(input),
+        )
+    }
+
+    /// <p>Assigns one or more IPv6 addresses to the specified network interface. You can specify one or more specific IPv6 addresses, or you can specify the number of IPv6 addresses to be automatically assigned from within the subnet's IPv6 CIDR block range. You can assign as many IPv6 addresses to a network interface as you can assign private IPv4 addresses, and the limit varies per instance type. For information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-eni.html#AvailableIpPerENI">IP Addresses Per Network Interface Per Instance Type</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>You must specify either the IPv6 addresses or the IPv6 address count in the request.</p>
+    ///
+    /// See `Ec2::assign_ipv_6_addresses`.
+    pub fn assign_ipv_6_addresses(
+        &self,
+        input: AssignIpv6AddressesRequest,
+    ) -> Result<AssignIpv6AddressesResult, RusotoError<AssignIpv6AddressesError>> {
+        self.runtime
+            .block_on(self.client.assign_ipv_6_addresses(input))
+    }
+
+    /// <p>Assigns one or more secondary private IP addresses to the specified network interface.</p> <p>You can specify one or more specific secondary IP addresses, or you can specify the number of secondary IP addresses to be automatically assigned within the subnet's CIDR block range. The number of secondary IP addresses that you can assign to an instance varies by instance type. For information about instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance Types</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>. For more information about Elastic IP addresses, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>When you move a secondary private IP address to another network interface, any Elastic IP address that is associated with the IP address is also moved.</p> <p>Remapping an IP address is an asynchronous operation. When you move an IP address from one network interface to another, check <code>network/interfaces/macs/mac/local-ipv4s</code> in the instance metadata to confirm that the remapping is complete.</p> <p>You must specify either the IP addresses or the IP address count in the request.</p>
+    ///
+    /// See `Ec2::assign_private_ip_addresses`.
+    pub fn assign_private_ip_addresses(
+        &self,
+        input: AssignPrivateIpAddressesRequest,
+    ) -> Result<AssignPrivateIpAddressesResult, RusotoError<AssignPrivateIpAddressesError>> {
+        self.runtime
+            .block_on(self.client.assign_private_ip_addresses(input))
+    }
+
+    /// <p><p>Associates an Elastic IP address with an instance or a network interface. Before you can use an Elastic IP address, you must allocate it to your account.</p> <p>An Elastic IP address is for use in either the EC2-Classic platform or in a VPC. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>[EC2-Classic, VPC in an EC2-VPC-only account] If the Elastic IP address is already associated with a different instance, it is disassociated from that instance and associated with the specified instance. If you associate an Elastic IP address with an instance that has an existing Elastic IP address, the existing address is disassociated from the instance, but remains allocated to your account.</p> <p>[VPC in an EC2-Classic account] If you don&#39;t specify a private IP address, the Elastic IP address is associated with the primary IP address. If the Elastic IP address is already associated with a different instance or a network interface, you get an error unless you allow reassociation. You cannot associate an Elastic IP address with an instance or network interface that has an existing Elastic IP address.</p> <p>You cannot associate an Elastic IP address with an interface in a different network border group.</p> <important> <p>This is an idempotent operation. If you perform the operation more than once, Amazon EC2 doesn&#39;t return an error, and you may be charged for each time the Elastic IP address is remapped to the same instance. For more information, see the <i>Elastic IP Addresses</i> section of <a href="http://aws.amazon.com/ec2/pricing/">Amazon EC2 Pricing</a>.</p> </important></p>
+    ///
+    /// See `Ec2::associate_address`.
+    pub fn associate_address(
+        &self,
+        input: AssociateAddressRequest,
+    ) -> Result<AssociateAddressResult, RusotoError<AssociateAddressError>> {
+        self.runtime.block_on(self.client.associate_address(input))
+    }
+
+    /// <p>Associates a target network with a Client VPN endpoint. A target network is a subnet in a VPC. You can associate multiple subnets from the same VPC with a Client VPN endpoint. You can associate only one subnet in each Availability Zone. We recommend that you associate at least two subnets to provide Availability Zone redundancy.</p> <p>If you specified a VPC when you created the Client VPN endpoint or if you have previous subnet associations, the specified subnet must be in the same VPC. To specify a subnet that's in a different VPC, you must first modify the Client VPN endpoint (<a>ModifyClientVpnEndpoint</a>) and change the VPC that's associated with it.</p>
+    ///
+    /// See `Ec2::associate_client_vpn_target_network`.
+    pub fn associate_client_vpn_target_network(
+        &self,
+        input: AssociateClientVpnTargetNetworkRequest,
+    ) -> Result<
+        AssociateClientVpnTargetNetworkResult,
+        RusotoError<AssociateClientVpnTargetNetworkError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_client_vpn_target_network(input))
+    }
+
+    /// <p>Associates a set of DHCP options (that you've previously created) with the specified VPC, or associates no DHCP options with the VPC.</p> <p>After you associate the options with the VPC, any existing instances and all new instances that you launch in that VPC use the options. You don't need to restart or relaunch the instances. They automatically pick up the changes within a few hours, depending on how frequently the instance renews its DHCP lease. You can explicitly renew the lease using the operating system on the instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_DHCP_Options.html">DHCP Options Sets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::associate_dhcp_options`.
+    pub fn associate_dhcp_options(
+        &self,
+        input: AssociateDhcpOptionsRequest,
+    ) -> Result<(), RusotoError<AssociateDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.associate_dhcp_options(input))
+    }
+
+    /// <p>Associates an IAM instance profile with a running or stopped instance. You cannot associate more than one IAM instance profile with an instance.</p>
+    ///
+    /// See `Ec2::associate_iam_instance_profile`.
+    pub fn associate_iam_instance_profile(
+        &self,
+        input: AssociateIamInstanceProfileRequest,
+    ) -> Result<AssociateIamInstanceProfileResult, RusotoError<AssociateIamInstanceProfileError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_iam_instance_profile(input))
+    }
+
+    /// <p>Associates a subnet in your VPC or an internet gateway or virtual private gateway attached to your VPC with a route table in your VPC. This association causes traffic from the subnet or gateway to be routed according to the routes in the route table. The action returns an association ID, which you need in order to disassociate the route table later. A route table can be associated with multiple subnets.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::associate_route_table`.
+    pub fn associate_route_table(
+        &self,
+        input: AssociateRouteTableRequest,
+    ) -> Result<AssociateRouteTableResult, RusotoError<AssociateRouteTableError>> {
+        self.runtime
+            .block_on(self.client.associate_route_table(input))
+    }
+
+    /// <p>Associates a CIDR block with your subnet. You can only associate a single IPv6 CIDR block with your subnet. An IPv6 CIDR block must have a prefix length of /64.</p>
+    ///
+    /// See `Ec2::associate_subnet_cidr_block`.
+    pub fn associate_subnet_cidr_block(
+        &self,
+        input: AssociateSubnetCidrBlockRequest,
+    ) -> Result<AssociateSubnetCidrBlockResult, RusotoError<AssociateSubnetCidrBlockError>> {
+        self.runtime
+            .block_on(self.client.associate_subnet_cidr_block(input))
+    }
+
+    /// <p>Associates the specified subnets and transit gateway attachments with the specified transit gateway multicast domain.</p> <p>The transit gateway attachment must be in the available state before you can add a resource. Use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeTransitGatewayAttachments.html">DescribeTransitGatewayAttachments</a> to see the state of the attachment.</p>
+    ///
+    /// See `Ec2::associate_transit_gateway_multicast_domain`.
+    pub fn associate_transit_gateway_multicast_domain(
+        &self,
+        input: AssociateTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        AssociateTransitGatewayMulticastDomainResult,
+        RusotoError<AssociateTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_transit_gateway_multicast_domain(input),
+        )
+    }
+
+    /// <p>Associates the specified attachment with the specified transit gateway route table. You can associate only one route table with an attachment.</p>
+    ///
+    /// See `Ec2::associate_transit_gateway_route_table`.
+    pub fn associate_transit_gateway_route_table(
+        &self,
+        input: AssociateTransitGatewayRouteTableRequest,
+    ) -> Result<
+        AssociateTransitGatewayRouteTableResult,
+        RusotoError<AssociateTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_transit_gateway_route_table(input))
+    }
+
+    /// <p>Associates a CIDR block with your VPC. You can associate a secondary IPv4 CIDR block, an Amazon-provided IPv6 CIDR block, or an IPv6 CIDR block from an IPv6 address pool that you provisioned through bring your own IP addresses (<a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-byoip.html">BYOIP</a>). The IPv6 CIDR block size is fixed at /56.</p> <p>You must specify one of the following in the request: an IPv4 CIDR block, an IPv6 pool, or an Amazon-provided IPv6 CIDR block.</p> <p>For more information about associating CIDR blocks with your VPC and applicable restrictions, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html#VPC_Sizing">VPC and Subnet Sizing</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::associate_vpc_cidr_block`.
+    pub fn associate_vpc_cidr_block(
+        &self,
+        input: AssociateVpcCidrBlockRequest,
+    ) -> Result<AssociateVpcCidrBlockResult, RusotoError<AssociateVpcCidrBlockError>> {
+        self.runtime
+            .block_on(self.client.associate_vpc_cidr_block(input))
+    }
+
+    /// <p>Links an EC2-Classic instance to a ClassicLink-enabled VPC through one or more of the VPC's security groups. You cannot link an EC2-Classic instance to more than one VPC at a time. You can only link an instance that's in the <code>running</code> state. An instance is automatically unlinked from a VPC when it's stopped - you can link it to the VPC again when you restart it.</p> <p>After you've linked an instance, you cannot change the VPC security groups that are associated with it. To change the security groups, you must first unlink the instance, and then link it again.</p> <p>Linking your instance to a VPC is sometimes referred to as <i>attaching</i> your instance.</p>
+    ///
+    /// See `Ec2::attach_classic_link_vpc`.
+    pub fn attach_classic_link_vpc(
+        &self,
+        input: AttachClassicLinkVpcRequest,
+    ) -> Result<AttachClassicLinkVpcResult, RusotoError<AttachClassicLinkVpcError>> {
+        self.runtime
+            .block_on(self.client.attach_classic_link_vpc(input))
+    }
+
+    /// <p>Attaches an internet gateway or a virtual private gateway to a VPC, enabling connectivity between the internet and the VPC. For more information about your VPC and internet gateway, see the <a href="https://docs.aws.amazon.com/vpc/latest/userguide/">Amazon Virtual Private Cloud User Guide</a>.</p>
+    ///
+    /// See `Ec2::attach_internet_gateway`.
+    pub fn attach_internet_gateway(
+        &self,
+        input: AttachInternetGatewayRequest,
+    ) -> Result<(), RusotoError<AttachInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.attach_internet_gateway(input))
+    }
+
+    /// <p>Attaches a network interface to an instance.</p>
+    ///
+    /// See `Ec2::attach_network_interface`.
+    pub fn attach_network_interface(
+        &self,
+        input: AttachNetworkInterfaceRequest,
+    ) -> Result<AttachNetworkInterfaceResult, RusotoError<AttachNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.attach_network_interface(input))
+    }
+
+    /// <p>Attaches an EBS volume to a running or stopped instance and exposes it to the instance with the specified device name.</p> <p>Encrypted EBS volumes must be attached to instances that support Amazon EBS encryption. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>After you attach an EBS volume, you must make it available. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-using-volumes.html">Making an EBS Volume Available For Use</a>.</p> <p>If a volume has an AWS Marketplace product code:</p> <ul> <li> <p>The volume can be attached only to a stopped instance.</p> </li> <li> <p>AWS Marketplace product codes are copied from the volume to the instance.</p> </li> <li> <p>You must be subscribed to the product.</p> </li> <li> <p>The instance type and operating system of the instance must support the product. For example, you can't detach a volume from a Windows instance and attach it to a Linux instance.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-attaching-volume.html">Attaching Amazon EBS Volumes</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::attach_volume`.
+    pub fn attach_volume(
+        &self,
+        input: AttachVolumeRequest,
+    ) -> Result<VolumeAttachment, RusotoError<AttachVolumeError>> {
+        self.runtime.block_on(self.client.attach_volume(input))
+    }
+
+    /// <p>Attaches a virtual private gateway to a VPC. You can attach one virtual private gateway to one VPC at a time.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::attach_vpn_gateway`.
+    pub fn attach_vpn_gateway(
+        &self,
+        input: AttachVpnGatewayRequest,
+    ) -> Result<AttachVpnGatewayResult, RusotoError<AttachVpnGatewayError>> {
+        self.runtime.block_on(self.client.attach_vpn_gateway(input))
+    }
+
+    /// <p>Adds an ingress authorization rule to a Client VPN endpoint. Ingress authorization rules act as firewall rules that grant access to networks. You must configure ingress authorization rules to enable clients to access resources in AWS or on-premises networks.</p>
+    ///
+    /// See `Ec2::authorize_client_vpn_ingress`.
+    pub fn authorize_client_vpn_ingress(
+        &self,
+        input: AuthorizeClientVpnIngressRequest,
+    ) -> Result<AuthorizeClientVpnIngressResult, RusotoError<AuthorizeClientVpnIngressError>> {
+        self.runtime
+            .block_on(self.client.authorize_client_vpn_ingress(input))
+    }
+
+    /// <p>[VPC only] Adds the specified egress rules to a security group for use with a VPC.</p> <p>An outbound rule permits instances to send traffic to the specified IPv4 or IPv6 CIDR address ranges, or to the instances associated with the specified destination security groups.</p> <p>You specify a protocol for each rule (for example, TCP). For the TCP and UDP protocols, you must also specify the destination port or port range. For the ICMP protocol, you must also specify the ICMP type and code. You can use -1 for the type or code to mean all types or all codes.</p> <p>Rule changes are propagated to affected instances as quickly as possible. However, a small delay might occur.</p> <p>For more information about VPC security group limits, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/amazon-vpc-limits.html">Amazon VPC Limits</a>.</p>
+    ///
+    /// See `Ec2::authorize_security_group_egress`.
+    pub fn authorize_security_group_egress(
+        &self,
+        input: AuthorizeSecurityGroupEgressRequest,
+    ) -> Result<(), RusotoError<AuthorizeSecurityGroupEgressError>> {
+        self.runtime
+            .block_on(self.client.authorize_security_group_egress(input))
+    }
+
+    /// <p>Adds the specified ingress rules to a security group.</p> <p>An inbound rule permits instances to receive traffic from the specified IPv4 or IPv6 CIDR address ranges, or from the instances associated with the specified destination security groups.</p> <p>You specify a protocol for each rule (for example, TCP). For TCP and UDP, you must also specify the destination port or port range. For ICMP/ICMPv6, you must also specify the ICMP/ICMPv6 type and code. You can use -1 to mean all types or all codes.</p> <p>Rule changes are propagated to instances within the security group as quickly as possible. However, a small delay might occur.</p> <p>For more information about VPC security group limits, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/amazon-vpc-limits.html">Amazon VPC Limits</a>.</p>
+    ///
+    /// See `Ec2::authorize_security_group_ingress`.
+    pub fn authorize_security_group_ingress(
+        &self,
+        input: AuthorizeSecurityGroupIngressRequest,
+    ) -> Result<(), RusotoError<AuthorizeSecurityGroupIngressError>> {
+        self.runtime
+            .block_on(self.client.authorize_security_group_ingress(input))
+    }
+
+    /// <p><p>Bundles an Amazon instance store-backed Windows instance.</p> <p>During bundling, only the root device volume (C:) is bundled. Data on other instance store volumes is not preserved.</p> <note> <p>This action is not applicable for Linux/Unix instances or Windows instances that are backed by Amazon EBS.</p> </note></p>
+    ///
+    /// See `Ec2::bundle_instance`.
+    pub fn bundle_instance(
+        &self,
+        input: BundleInstanceRequest,
+    ) -> Result<BundleInstanceResult, RusotoError<BundleInstanceError>> {
+        self.runtime.block_on(self.client.bundle_instance(input))
+    }
+
+    /// <p>Cancels a bundling operation for an instance store-backed Windows instance.</p>
+    ///
+    /// See `Ec2::cancel_bundle_task`.
+    pub fn cancel_bundle_task(
+        &self,
+        input: CancelBundleTaskRequest,
+    ) -> Result<CancelBundleTaskResult, RusotoError<CancelBundleTaskError>> {
+        self.runtime.block_on(self.client.cancel_bundle_task(input))
+    }
+
+    /// <p>Cancels the specified Capacity Reservation, releases the reserved capacity, and changes the Capacity Reservation's state to <code>cancelled</code>.</p> <p>Instances running in the reserved capacity continue running until you stop them. Stopped instances that target the Capacity Reservation can no longer launch. Modify these instances to either target a different Capacity Reservation, launch On-Demand Instance capacity, or run in any open Capacity Reservation that has matching attributes and sufficient capacity.</p>
+    ///
+    /// See `Ec2::cancel_capacity_reservation`.
+    pub fn cancel_capacity_reservation(
+        &self,
+        input: CancelCapacityReservationRequest,
+    ) -> Result<CancelCapacityReservationResult, RusotoError<CancelCapacityReservationError>> {
+        self.runtime
+            .block_on(self.client.cancel_capacity_reservation(input))
+    }
+
+    /// <p>Cancels an active conversion task. The task can be the import of an instance or volume. The action removes all artifacts of the conversion, including a partially uploaded volume or instance. If the conversion is complete or is in the process of transferring the final disk image, the command fails and returns an exception.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/CommandLineReference/ec2-cli-vmimport-export.html">Importing a Virtual Machine Using the Amazon EC2 CLI</a>.</p>
+    ///
+    /// See `Ec2::cancel_conversion_task`.
+    pub fn cancel_conversion_task(
+        &self,
+        input: CancelConversionRequest,
+    ) -> Result<(), RusotoError<CancelConversionTaskError>> {
+        self.runtime
+            .block_on(self.client.cancel_conversion_task(input))
+    }
+
+    /// <p>Cancels an active export task. The request removes all artifacts of the export, including any partially-created Amazon S3 objects. If the export task is complete or is in the process of transferring the final disk image, the command fails and returns an error.</p>
+    ///
+    /// See `Ec2::cancel_export_task`.
+    pub fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> Result<(), RusotoError<CancelExportTaskError>> {
+        self.runtime.block_on(self.client.cancel_export_task(input))
+    }
+
+    /// <p>Cancels an in-process import virtual machine or import snapshot task.</p>
+    ///
+    /// See `Ec2::cancel_import_task`.
+    pub fn cancel_import_task(
+        &self,
+        input: CancelImportTaskRequest,
+    ) -> Result<CancelImportTaskResult, RusotoError<CancelImportTaskError>> {
+        self.runtime.block_on(self.client.cancel_import_task(input))
+    }
+
+    /// <p>Cancels the specified Reserved Instance listing in the Reserved Instance Marketplace.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-market-general.html">Reserved Instance Marketplace</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::cancel_reserved_instances_listing`.
+    pub fn cancel_reserved_instances_listing(
+        &self,
+        input: CancelReservedInstancesListingRequest,
+    ) -> Result<
+        CancelReservedInstancesListingResult,
+        RusotoError<CancelReservedInstancesListingError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_reserved_instances_listing(input))
+    }
+
+    /// <p>Cancels the specified Spot Fleet requests.</p> <p>After you cancel a Spot Fleet request, the Spot Fleet launches no new Spot Instances. You must specify whether the Spot Fleet should also terminate its Spot Instances. If you terminate the instances, the Spot Fleet request enters the <code>cancelled_terminating</code> state. Otherwise, the Spot Fleet request enters the <code>cancelled_running</code> state and the instances continue to run until they are interrupted or you terminate them manually.</p>
+    ///
+    /// See `Ec2::cancel_spot_fleet_requests`.
+    pub fn cancel_spot_fleet_requests(
+        &self,
+        input: CancelSpotFleetRequestsRequest,
+    ) -> Result<CancelSpotFleetRequestsResponse, RusotoError<EC2CancelSpotFleetRequestsError>> {
+        self.runtime
+            .block_on(self.client.cancel_spot_fleet_requests(input))
+    }
+
+    /// <p><p>Cancels one or more Spot Instance requests.</p> <important> <p>Canceling a Spot Instance request does not terminate running Spot Instances associated with the request.</p> </important></p>
+    ///
+    /// See `Ec2::cancel_spot_instance_requests`.
+    pub fn cancel_spot_instance_requests(
+        &self,
+        input: CancelSpotInstanceRequestsRequest,
+    ) -> Result<CancelSpotInstanceRequestsResult, RusotoError<CancelSpotInstanceRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.cancel_spot_instance_requests(input))
+    }
+
+    /// <p>Determines whether a product code is associated with an instance. This action can only be used by the owner of the product code. It is useful when a product code owner must verify whether another user's instance is eligible for support.</p>
+    ///
+    /// See `Ec2::confirm_product_instance`.
+    pub fn confirm_product_instance(
+        &self,
+        input: ConfirmProductInstanceRequest,
+    ) -> Result<ConfirmProductInstanceResult, RusotoError<ConfirmProductInstanceError>> {
+        self.runtime
+            .block_on(self.client.confirm_product_instance(input))
+    }
+
+    /// <p>Copies the specified Amazon FPGA Image (AFI) to the current Region.</p>
+    ///
+    /// See `Ec2::copy_fpga_image`.
+    pub fn copy_fpga_image(
+        &self,
+        input: CopyFpgaImageRequest,
+    ) -> Result<CopyFpgaImageResult, RusotoError<CopyFpgaImageError>> {
+        self.runtime.block_on(self.client.copy_fpga_image(input))
+    }
+
+    /// <p>Initiates the copy of an AMI from the specified source Region to the current Region. You specify the destination Region by using its endpoint when making the request.</p> <p>Copies of encrypted backing snapshots for the AMI are encrypted. Copies of unencrypted backing snapshots remain unencrypted, unless you set <code>Encrypted</code> during the copy operation. You cannot create an unencrypted copy of an encrypted backing snapshot.</p> <p>For more information about the prerequisites and limits when copying an AMI, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/CopyingAMIs.html">Copying an AMI</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::copy_image`.
+    pub fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> Result<CopyImageResult, RusotoError<CopyImageError>> {
+        self.runtime.block_on(self.client.copy_image(input))
+    }
+
+    /// <p>Copies a point-in-time snapshot of an EBS volume and stores it in Amazon S3. You can copy the snapshot within the same Region or from one Region to another. You can use the snapshot to create EBS volumes or Amazon Machine Images (AMIs).</p> <p>Copies of encrypted EBS snapshots remain encrypted. Copies of unencrypted snapshots remain unencrypted, unless you enable encryption for the snapshot copy operation. By default, encrypted snapshot copies use the default AWS Key Management Service (AWS KMS) customer master key (CMK); however, you can specify a different CMK.</p> <p>To copy an encrypted snapshot that has been shared from another account, you must have permissions for the CMK used to encrypt the snapshot.</p> <p>Snapshots created by copying another snapshot have an arbitrary volume ID that should not be used for any purpose.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-copy-snapshot.html">Copying an Amazon EBS Snapshot</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::copy_snapshot`.
+    pub fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.runtime.block_on(self.client.copy_snapshot(input))
+    }
+
+    /// <p>Creates a new Capacity Reservation with the specified attributes.</p> <p>Capacity Reservations enable you to reserve capacity for your Amazon EC2 instances in a specific Availability Zone for any duration. This gives you the flexibility to selectively add capacity reservations and still get the Regional RI discounts for that usage. By creating Capacity Reservations, you ensure that you always have access to Amazon EC2 capacity when you need it, for as long as you need it. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-capacity-reservations.html">Capacity Reservations</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>Your request to create a Capacity Reservation could fail if Amazon EC2 does not have sufficient capacity to fulfill the request. If your request fails due to Amazon EC2 capacity constraints, either try again at a later time, try in a different Availability Zone, or request a smaller capacity reservation. If your application is flexible across instance types and sizes, try to create a Capacity Reservation with different instance attributes.</p> <p>Your request could also fail if the requested quantity exceeds your On-Demand Instance limit for the selected instance type. If your request fails due to limit constraints, increase your On-Demand Instance limit for the required instance type and try again. For more information about increasing your instance limits, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-resource-limits.html">Amazon EC2 Service Limits</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_capacity_reservation`.
+    pub fn create_capacity_reservation(
+        &self,
+        input: CreateCapacityReservationRequest,
+    ) -> Result<CreateCapacityReservationResult, RusotoError<CreateCapacityReservationError>> {
+        self.runtime
+            .block_on(self.client.create_capacity_reservation(input))
+    }
+
+    /// <p>Creates a Client VPN endpoint. A Client VPN endpoint is the resource you create and configure to enable and manage client VPN sessions. It is the destination endpoint at which all client VPN sessions are terminated.</p>
+    ///
+    /// See `Ec2::create_client_vpn_endpoint`.
+    pub fn create_client_vpn_endpoint(
+        &self,
+        input: CreateClientVpnEndpointRequest,
+    ) -> Result<CreateClientVpnEndpointResult, RusotoError<CreateClientVpnEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_client_vpn_endpoint(input))
+    }
+
+    /// <p>Adds a route to a network to a Client VPN endpoint. Each Client VPN endpoint has a route table that describes the available destination network routes. Each route in the route table specifies the path for traﬃc to speciﬁc resources or networks.</p>
+    ///
+    /// See `Ec2::create_client_vpn_route`.
+    pub fn create_client_vpn_route(
+        &self,
+        input: CreateClientVpnRouteRequest,
+    ) -> Result<CreateClientVpnRouteResult, RusotoError<CreateClientVpnRouteError>> {
+        self.runtime
+            .block_on(self.client.create_client_vpn_route(input))
+    }
+
+    /// <p><p>Provides information to AWS about your VPN customer gateway device. The customer gateway is the appliance at your end of the VPN connection. (The device on the AWS side of the VPN connection is the virtual private gateway.) You must provide the Internet-routable IP address of the customer gateway&#39;s external interface. The IP address must be static and can be behind a device performing network address translation (NAT).</p> <p>For devices that use Border Gateway Protocol (BGP), you can also provide the device&#39;s BGP Autonomous System Number (ASN). You can use an existing ASN assigned to your network. If you don&#39;t have an ASN already, you can use a private ASN (in the 64512 - 65534 range).</p> <note> <p>Amazon EC2 supports all 2-byte ASN numbers in the range of 1 - 65534, with the exception of 7224, which is reserved in the <code>us-east-1</code> Region, and 9059, which is reserved in the <code>eu-west-1</code> Region.</p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p> <important> <p>To create more than one customer gateway with the same VPN type, IP address, and BGP ASN, specify a unique device name for each customer gateway. Identical requests return information about the existing customer gateway and do not create new customer gateways.</p> </important></p>
+    ///
+    /// See `Ec2::create_customer_gateway`.
+    pub fn create_customer_gateway(
+        &self,
+        input: CreateCustomerGatewayRequest,
+    ) -> Result<CreateCustomerGatewayResult, RusotoError<CreateCustomerGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_customer_gateway(input))
+    }
+
+    /// <p>Creates a default subnet with a size <code>/20</code> IPv4 CIDR block in the specified Availability Zone in your default VPC. You can have only one default subnet per Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/default-vpc.html#create-default-subnet">Creating a Default Subnet</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_default_subnet`.
+    pub fn create_default_subnet(
+        &self,
+        input: CreateDefaultSubnetRequest,
+    ) -> Result<CreateDefaultSubnetResult, RusotoError<CreateDefaultSubnetError>> {
+        self.runtime
+            .block_on(self.client.create_default_subnet(input))
+    }
+
+    /// <p>Creates a default VPC with a size <code>/16</code> IPv4 CIDR block and a default subnet in each Availability Zone. For more information about the components of a default VPC, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/default-vpc.html">Default VPC and Default Subnets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>. You cannot specify the components of the default VPC yourself.</p> <p>If you deleted your previous default VPC, you can create a default VPC. You cannot have more than one default VPC per Region.</p> <p>If your account supports EC2-Classic, you cannot use this action to create a default VPC in a Region that supports EC2-Classic. If you want a default VPC in a Region that supports EC2-Classic, see "I really want a default VPC for my existing EC2 account. Is that possible?" in the <a href="http://aws.amazon.com/vpc/faqs/#Default_VPCs">Default VPCs FAQ</a>.</p>
+    ///
+    /// See `Ec2::create_default_vpc`.
+    pub fn create_default_vpc(
+        &self,
+        input: CreateDefaultVpcRequest,
+    ) -> Result<CreateDefaultVpcResult, RusotoError<CreateDefaultVpcError>> {
+        self.runtime.block_on(self.client.create_default_vpc(input))
+    }
+
+    /// <p>Creates a set of DHCP options for your VPC. After creating the set, you must associate it with the VPC, causing all existing and new instances that you launch in the VPC to use this set of DHCP options. The following are the individual DHCP options you can specify. For more information about the options, see <a href="http://www.ietf.org/rfc/rfc2132.txt">RFC 2132</a>.</p> <ul> <li> <p> <code>domain-name-servers</code> - The IP addresses of up to four domain name servers, or AmazonProvidedDNS. The default DHCP option set specifies AmazonProvidedDNS. If specifying more than one domain name server, specify the IP addresses in a single parameter, separated by commas. To have your instance receive a custom DNS hostname as specified in <code>domain-name</code>, you must set <code>domain-name-servers</code> to a custom DNS server.</p> </li> <li> <p> <code>domain-name</code> - If you're using AmazonProvidedDNS in <code>us-east-1</code>, specify <code>ec2.internal</code>. If you're using AmazonProvidedDNS in another Region, specify <code>region.compute.internal</code> (for example, <code>ap-northeast-1.compute.internal</code>). Otherwise, specify a domain name (for example, <code>ExampleCompany.com</code>). This value is used to complete unqualified DNS hostnames. <b>Important</b>: Some Linux operating systems accept multiple domain names separated by spaces. However, Windows and other Linux operating systems treat the value as a single domain, which results in unexpected behavior. If your DHCP options set is associated with a VPC that has instances with multiple operating systems, specify only one domain name.</p> </li> <li> <p> <code>ntp-servers</code> - The IP addresses of up to four Network Time Protocol (NTP) servers.</p> </li> <li> <p> <code>netbios-name-servers</code> - The IP addresses of up to four NetBIOS name servers.</p> </li> <li> <p> <code>netbios-node-type</code> - The NetBIOS node type (1, 2, 4, or 8). We recommend that you specify 2 (broadcast and multicast are not currently supported). For more information about these node types, see <a href="http://www.ietf.org/rfc/rfc2132.txt">RFC 2132</a>.</p> </li> </ul> <p>Your VPC automatically starts out with a set of DHCP options that includes only a DNS server that we provide (AmazonProvidedDNS). If you create a set of options, and if your VPC has an internet gateway, make sure to set the <code>domain-name-servers</code> option either to <code>AmazonProvidedDNS</code> or to a domain name server of your choice. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_DHCP_Options.html">DHCP Options Sets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_dhcp_options`.
+    pub fn create_dhcp_options(
+        &self,
+        input: CreateDhcpOptionsRequest,
+    ) -> Result<CreateDhcpOptionsResult, RusotoError<CreateDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.create_dhcp_options(input))
+    }
+
+    /// <p>[IPv6 only] Creates an egress-only internet gateway for your VPC. An egress-only internet gateway is used to enable outbound communication over IPv6 from instances in your VPC to the internet, and prevents hosts outside of your VPC from initiating an IPv6 connection with your instance.</p>
+    ///
+    /// See `Ec2::create_egress_only_internet_gateway`.
+    pub fn create_egress_only_internet_gateway(
+        &self,
+        input: CreateEgressOnlyInternetGatewayRequest,
+    ) -> Result<
+        CreateEgressOnlyInternetGatewayResult,
+        RusotoError<CreateEgressOnlyInternetGatewayError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_egress_only_internet_gateway(input))
+    }
+
+    /// <p>Launches an EC2 Fleet.</p> <p>You can create a single EC2 Fleet that includes multiple launch specifications that vary by instance type, AMI, Availability Zone, or subnet.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-fleet.html">Launching an EC2 Fleet</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_fleet`.
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// <p>Creates one or more flow logs to capture information about IP traffic for a specific network interface, subnet, or VPC. </p> <p>Flow log data for a monitored network interface is recorded as flow log records, which are log events consisting of fields that describe the traffic flow. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/flow-logs.html#flow-log-records">Flow Log Records</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>When publishing to CloudWatch Logs, flow log records are published to a log group, and each network interface has a unique log stream in the log group. When publishing to Amazon S3, flow log records for all of the monitored network interfaces are published to a single log file object that is stored in the specified bucket.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/flow-logs.html">VPC Flow Logs</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_flow_logs`.
+    pub fn create_flow_logs(
+        &self,
+        input: CreateFlowLogsRequest,
+    ) -> Result<CreateFlowLogsResult, RusotoError<CreateFlowLogsError>> {
+        self.runtime.block_on(self.client.create_flow_logs(input))
+    }
+
+    /// <p>Creates an Amazon FPGA Image (AFI) from the specified design checkpoint (DCP).</p> <p>The create operation is asynchronous. To verify that the AFI is ready for use, check the output logs.</p> <p>An AFI contains the FPGA bitstream that is ready to download to an FPGA. You can securely deploy an AFI on multiple FPGA-accelerated instances. For more information, see the <a href="https://github.com/aws/aws-fpga/">AWS FPGA Hardware Development Kit</a>.</p>
+    ///
+    /// See `Ec2::create_fpga_image`.
+    pub fn create_fpga_image(
+        &self,
+        input: CreateFpgaImageRequest,
+    ) -> Result<CreateFpgaImageResult, RusotoError<CreateFpgaImageError>> {
+        self.runtime.block_on(self.client.create_fpga_image(input))
+    }
+
+    /// <p>Creates an Amazon EBS-backed AMI from an Amazon EBS-backed instance that is either running or stopped.</p> <p>If you customized your instance with instance store volumes or EBS volumes in addition to the root device volume, the new AMI contains block device mapping information for those volumes. When you launch an instance from this new AMI, the instance automatically launches with those additional volumes.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/creating-an-ami-ebs.html">Creating Amazon EBS-Backed Linux AMIs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_image`.
+    pub fn create_image(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<CreateImageResult, RusotoError<CreateImageError>> {
+        self.runtime.block_on(self.client.create_image(input))
+    }
+
+    /// <p>Exports a running or stopped instance to an Amazon S3 bucket.</p> <p>For information about the supported operating systems, image formats, and known limitations for the types of instances you can export, see <a href="https://docs.aws.amazon.com/vm-import/latest/userguide/vmexport.html">Exporting an Instance as a VM Using VM Import/Export</a> in the <i>VM Import/Export User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_instance_export_task`.
+    pub fn create_instance_export_task(
+        &self,
+        input: CreateInstanceExportTaskRequest,
+    ) -> Result<CreateInstanceExportTaskResult, RusotoError<CreateInstanceExportTaskError>> {
+        self.runtime
+            .block_on(self.client.create_instance_export_task(input))
+    }
+
+    /// <p>Creates an internet gateway for use with a VPC. After creating the internet gateway, you attach it to a VPC using <a>AttachInternetGateway</a>.</p> <p>For more information about your VPC and internet gateway, see the <a href="https://docs.aws.amazon.com/vpc/latest/userguide/">Amazon Virtual Private Cloud User Guide</a>.</p>
+    ///
+    /// See `Ec2::create_internet_gateway`.
+    pub fn create_internet_gateway(
+        &self,
+        input: CreateInternetGatewayRequest,
+    ) -> Result<CreateInternetGatewayResult, RusotoError<CreateInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_internet_gateway(input))
+    }
+
+    /// <p>Creates a 2048-bit RSA key pair with the specified name. Amazon EC2 stores the public key and displays the private key for you to save to a file. The private key is returned as an unencrypted PEM encoded PKCS#1 private key. If a key with the specified name already exists, Amazon EC2 returns an error.</p> <p>You can have up to five thousand key pairs per Region.</p> <p>The key pair returned to you is available only in the Region in which you create it. If you prefer, you can create your own key pair using a third-party tool and upload it to any Region using <a>ImportKeyPair</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-key-pairs.html">Key Pairs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_key_pair`.
+    pub fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> Result<KeyPair, RusotoError<CreateKeyPairError>> {
+        self.runtime.block_on(self.client.create_key_pair(input))
+    }
+
+    /// <p>Creates a launch template. A launch template contains the parameters to launch an instance. When you launch an instance using <a>RunInstances</a>, you can specify a launch template instead of providing the launch parameters in the request. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html">Launching an instance from a launch template</a>in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_launch_template`.
+    pub fn create_launch_template(
+        &self,
+        input: CreateLaunchTemplateRequest,
+    ) -> Result<CreateLaunchTemplateResult, RusotoError<CreateLaunchTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_launch_template(input))
+    }
+
+    /// <p>Creates a new version for a launch template. You can specify an existing version of launch template from which to base the new version.</p> <p>Launch template versions are numbered in the order in which they are created. You cannot specify, change, or replace the numbering of launch template versions.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html#manage-launch-template-versions">Managing launch template versions</a>in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_launch_template_version`.
+    pub fn create_launch_template_version(
+        &self,
+        input: CreateLaunchTemplateVersionRequest,
+    ) -> Result<CreateLaunchTemplateVersionResult, RusotoError<CreateLaunchTemplateVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_launch_template_version(input))
+    }
+
+    /// <p>Creates a static route for the specified local gateway route table.</p>
+    ///
+    /// See `Ec2::create_local_gateway_route`.
+    pub fn create_local_gateway_route(
+        &self,
+        input: CreateLocalGatewayRouteRequest,
+    ) -> Result<CreateLocalGatewayRouteResult, RusotoError<CreateLocalGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.create_local_gateway_route(input))
+    }
+
+    /// <p>Associates the specified VPC with the specified local gateway route table.</p>
+    ///
+    /// See `Ec2::create_local_gateway_route_table_vpc_association`.
+    pub fn create_local_gateway_route_table_vpc_association(
+        &self,
+        input: CreateLocalGatewayRouteTableVpcAssociationRequest,
+    ) -> Result<
+        CreateLocalGatewayRouteTableVpcAssociationResult,
+        RusotoError<CreateLocalGatewayRouteTableVpcAssociationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_local_gateway_route_table_vpc_association(input),
+        )
+    }
+
+    /// <p>Creates a managed prefix list. You can specify one or more entries for the prefix list. Each entry consists of a CIDR block and an optional description.</p> <p>You must specify the maximum number of entries for the prefix list. The maximum number of entries cannot be changed later.</p>
+    ///
+    /// See `Ec2::create_managed_prefix_list`.
+    pub fn create_managed_prefix_list(
+        &self,
+        input: CreateManagedPrefixListRequest,
+    ) -> Result<CreateManagedPrefixListResult, RusotoError<CreateManagedPrefixListError>> {
+        self.runtime
+            .block_on(self.client.create_managed_prefix_list(input))
+    }
+
+    /// <p>Creates a NAT gateway in the specified public subnet. This action creates a network interface in the specified subnet with a private IP address from the IP address range of the subnet. Internet-bound traffic from a private subnet can be routed to the NAT gateway, therefore enabling instances in the private subnet to connect to the internet. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/vpc-nat-gateway.html">NAT Gateways</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_nat_gateway`.
+    pub fn create_nat_gateway(
+        &self,
+        input: CreateNatGatewayRequest,
+    ) -> Result<CreateNatGatewayResult, RusotoError<CreateNatGatewayError>> {
+        self.runtime.block_on(self.client.create_nat_gateway(input))
+    }
+
+    /// <p>Creates a network ACL in a VPC. Network ACLs provide an optional layer of security (in addition to security groups) for the instances in your VPC.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_ACLs.html">Network ACLs</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_network_acl`.
+    pub fn create_network_acl(
+        &self,
+        input: CreateNetworkAclRequest,
+    ) -> Result<CreateNetworkAclResult, RusotoError<CreateNetworkAclError>> {
+        self.runtime.block_on(self.client.create_network_acl(input))
+    }
+
+    /// <p>Creates an entry (a rule) in a network ACL with the specified rule number. Each network ACL has a set of numbered ingress rules and a separate set of numbered egress rules. When determining whether a packet should be allowed in or out of a subnet associated with the ACL, we process the entries in the ACL according to the rule numbers, in ascending order. Each network ACL has a set of ingress rules and a separate set of egress rules.</p> <p>We recommend that you leave room between the rule numbers (for example, 100, 110, 120, ...), and not number them one right after the other (for example, 101, 102, 103, ...). This makes it easier to add a rule between existing ones without having to renumber the rules.</p> <p>After you add an entry, you can't modify it; you must either replace it, or create an entry and delete the old one.</p> <p>For more information about network ACLs, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_ACLs.html">Network ACLs</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_network_acl_entry`.
+    pub fn create_network_acl_entry(
+        &self,
+        input: CreateNetworkAclEntryRequest,
+    ) -> Result<(), RusotoError<CreateNetworkAclEntryError>> {
+        self.runtime
+            .block_on(self.client.create_network_acl_entry(input))
+    }
+
+    /// <p>Creates a network interface in the specified subnet.</p> <p>For more information about network interfaces, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-eni.html">Elastic Network Interfaces</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_network_interface`.
+    pub fn create_network_interface(
+        &self,
+        input: CreateNetworkInterfaceRequest,
+    ) -> Result<CreateNetworkInterfaceResult, RusotoError<CreateNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.create_network_interface(input))
+    }
+
+    /// <p>Grants an AWS-authorized account permission to attach the specified network interface to an instance in their account.</p> <p>You can grant permission to a single AWS account only, and only one account at a time.</p>
+    ///
+    /// See `Ec2::create_network_interface_permission`.
+    pub fn create_network_interface_permission(
+        &self,
+        input: CreateNetworkInterfacePermissionRequest,
+    ) -> Result<
+        CreateNetworkInterfacePermissionResult,
+        RusotoError<CreateNetworkInterfacePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_network_interface_permission(input))
+    }
+
+    /// <p>Creates a placement group in which to launch instances. The strategy of the placement group determines how the instances are organized within the group. </p> <p>A <code>cluster</code> placement group is a logical grouping of instances within a single Availability Zone that benefit from low network latency, high network throughput. A <code>spread</code> placement group places instances on distinct hardware. A <code>partition</code> placement group places groups of instances in different partitions, where instances in one partition do not share the same hardware with instances in another partition.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/placement-groups.html">Placement groups</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_placement_group`.
+    pub fn create_placement_group(
+        &self,
+        input: CreatePlacementGroupRequest,
+    ) -> Result<CreatePlacementGroupResult, RusotoError<CreatePlacementGroupError>> {
+        self.runtime
+            .block_on(self.client.create_placement_group(input))
+    }
+
+    /// <p>Creates a listing for Amazon EC2 Standard Reserved Instances to be sold in the Reserved Instance Marketplace. You can submit one Standard Reserved Instance listing at a time. To get a list of your Standard Reserved Instances, you can use the <a>DescribeReservedInstances</a> operation.</p> <note> <p>Only Standard Reserved Instances can be sold in the Reserved Instance Marketplace. Convertible Reserved Instances cannot be sold.</p> </note> <p>The Reserved Instance Marketplace matches sellers who want to resell Standard Reserved Instance capacity that they no longer need with buyers who want to purchase additional capacity. Reserved Instances bought and sold through the Reserved Instance Marketplace work like any other Reserved Instances.</p> <p>To sell your Standard Reserved Instances, you must first register as a seller in the Reserved Instance Marketplace. After completing the registration process, you can create a Reserved Instance Marketplace listing of some or all of your Standard Reserved Instances, and specify the upfront price to receive for them. Your Standard Reserved Instance listings then become available for purchase. To view the details of your Standard Reserved Instance listing, you can use the <a>DescribeReservedInstancesListings</a> operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-market-general.html">Reserved Instance Marketplace</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_reserved_instances_listing`.
+    pub fn create_reserved_instances_listing(
+        &self,
+        input: CreateReservedInstancesListingRequest,
+    ) -> Result<
+        CreateReservedInstancesListingResult,
+        RusotoError<CreateReservedInstancesListingError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_reserved_instances_listing(input))
+    }
+
+    /// <p>Creates a route in a route table within a VPC.</p> <p>You must specify one of the following targets: internet gateway or virtual private gateway, NAT instance, NAT gateway, VPC peering connection, network interface, egress-only internet gateway, or transit gateway.</p> <p>When determining how to route traffic, we use the route with the most specific match. For example, traffic is destined for the IPv4 address <code>192.0.2.3</code>, and the route table includes the following two IPv4 routes:</p> <ul> <li> <p> <code>192.0.2.0/24</code> (goes to some target A)</p> </li> <li> <p> <code>192.0.2.0/28</code> (goes to some target B)</p> </li> </ul> <p>Both routes apply to the traffic destined for <code>192.0.2.3</code>. However, the second route in the list covers a smaller number of IP addresses and is therefore more specific, so we use that route to determine where to target the traffic.</p> <p>For more information about route tables, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_route`.
+    pub fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
+        self.runtime.block_on(self.client.create_route(input))
+    }
+
+    /// <p>Creates a route table for the specified VPC. After you create a route table, you can add routes and associate the table with a subnet.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_route_table`.
+    pub fn create_route_table(
+        &self,
+        input: CreateRouteTableRequest,
+    ) -> Result<CreateRouteTableResult, RusotoError<CreateRouteTableError>> {
+        self.runtime.block_on(self.client.create_route_table(input))
+    }
+
+    /// <p>Creates a security group.</p> <p>A security group acts as a virtual firewall for your instance to control inbound and outbound traffic. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-network-security.html">Amazon EC2 Security Groups</a> in the <i>Amazon Elastic Compute Cloud User Guide</i> and <a href="https://docs.aws.amazon.com/AmazonVPC/latest/UserGuide/VPC_SecurityGroups.html">Security Groups for Your VPC</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>When you create a security group, you specify a friendly name of your choice. You can have a security group for use in EC2-Classic with the same name as a security group for use in a VPC. However, you can't have two security groups for use in EC2-Classic with the same name or two security groups for use in a VPC with the same name.</p> <p>You have a default security group for use in EC2-Classic and a default security group for use in your VPC. If you don't specify a security group when you launch an instance, the instance is launched into the appropriate default security group. A default security group includes a default rule that grants instances unrestricted network access to each other.</p> <p>You can add or remove rules from your security groups using <a>AuthorizeSecurityGroupIngress</a>, <a>AuthorizeSecurityGroupEgress</a>, <a>RevokeSecurityGroupIngress</a>, and <a>RevokeSecurityGroupEgress</a>.</p> <p>For more information about VPC security group limits, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/amazon-vpc-limits.html">Amazon VPC Limits</a>.</p>
+    ///
+    /// See `Ec2::create_security_group`.
+    pub fn create_security_group(
+        &self,
+        input: CreateSecurityGroupRequest,
+    ) -> Result<CreateSecurityGroupResult, RusotoError<CreateSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_security_group(input))
+    }
+
+    /// <p>Creates a snapshot of an EBS volume and stores it in Amazon S3. You can use snapshots for backups, to make copies of EBS volumes, and to save data before shutting down an instance.</p> <p>When a snapshot is created, any AWS Marketplace product codes that are associated with the source volume are propagated to the snapshot.</p> <p>You can take a snapshot of an attached volume that is in use. However, snapshots only capture data that has been written to your EBS volume at the time the snapshot command is issued; this may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the volume long enough to take a snapshot, your snapshot should be complete. However, if you cannot pause all file writes to the volume, you should unmount the volume from within the instance, issue the snapshot command, and then remount the volume to ensure a consistent and complete snapshot. You may remount and use your volume while the snapshot status is <code>pending</code>.</p> <p>To create a snapshot for EBS volumes that serve as root devices, you should stop the instance before taking the snapshot.</p> <p>Snapshots that are taken from encrypted volumes are automatically encrypted. Volumes that are created from encrypted snapshots are also automatically encrypted. Your encrypted volumes and any associated snapshots always remain protected.</p> <p>You can tag your snapshots during creation. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Amazon EC2 Resources</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/AmazonEBS.html">Amazon Elastic Block Store</a> and <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_snapshot`.
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> Result<Snapshot, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// <p>Creates crash-consistent snapshots of multiple EBS volumes and stores the data in S3. Volumes are chosen by specifying an instance. Any attached volumes will produce one snapshot each that is crash-consistent across the instance. Boot volumes can be excluded by changing the parameters. </p>
+    ///
+    /// See `Ec2::create_snapshots`.
+    pub fn create_snapshots(
+        &self,
+        input: CreateSnapshotsRequest,
+    ) -> Result<CreateSnapshotsResult, RusotoError<CreateSnapshotsError>> {
+        self.runtime.block_on(self.client.create_snapshots(input))
+    }
+
+    /// <p>Creates a data feed for Spot Instances, enabling you to view Spot Instance usage logs. You can create one data feed per AWS account. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/spot-data-feeds.html">Spot Instance data feed</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
+    ///
+    /// See `Ec2::create_spot_datafeed_subscription`.
+    pub fn create_spot_datafeed_subscription(
+        &self,
+        input: CreateSpotDatafeedSubscriptionRequest,
+    ) -> Result<
+        CreateSpotDatafeedSubscriptionResult,
+        RusotoError<CreateSpotDatafeedSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_spot_datafeed_subscription(input))
+    }
+
+    /// <p>Creates a subnet in a specified VPC.</p> <p>You must specify an IPv4 CIDR block for the subnet. After you create a subnet, you can't change its CIDR block. The allowed block size is between a /16 netmask (65,536 IP addresses) and /28 netmask (16 IP addresses). The CIDR block must not overlap with the CIDR block of an existing subnet in the VPC.</p> <p>If you've associated an IPv6 CIDR block with your VPC, you can create a subnet with an IPv6 CIDR block that uses a /64 prefix length. </p> <important> <p>AWS reserves both the first four and the last IPv4 address in each subnet's CIDR block. They're not available for use.</p> </important> <p>If you add more than one subnet to a VPC, they're set up in a star topology with a logical router in the middle.</p> <p>When you stop an instance in a subnet, it retains its private IPv4 address. It's therefore possible to have a subnet with no running instances (they're all stopped), but no remaining IP addresses available.</p> <p>For more information about subnets, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">Your VPC and Subnets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_subnet`.
+    pub fn create_subnet(
+        &self,
+        input: CreateSubnetRequest,
+    ) -> Result<CreateSubnetResult, RusotoError<CreateSubnetError>> {
+        self.runtime.block_on(self.client.create_subnet(input))
+    }
+
+    /// <p>Adds or overwrites only the specified tags for the specified Amazon EC2 resource or resources. When you specify an existing tag key, the value is overwritten with the new value. Each resource can have a maximum of 50 tags. Each tag consists of a key and optional value. Tag keys must be unique per resource.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Resources</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>. For more information about creating IAM policies that control users' access to resources based on tags, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-supported-iam-actions-resources.html">Supported Resource-Level Permissions for Amazon EC2 API Actions</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Creates a Traffic Mirror filter.</p> <p>A Traffic Mirror filter is a set of rules that defines the traffic to mirror.</p> <p>By default, no traffic is mirrored. To mirror traffic, use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_CreateTrafficMirrorFilterRule.htm">CreateTrafficMirrorFilterRule</a> to add Traffic Mirror rules to the filter. The rules you add define what traffic gets mirrored. You can also use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_ModifyTrafficMirrorFilterNetworkServices.html">ModifyTrafficMirrorFilterNetworkServices</a> to mirror supported network services.</p>
+    ///
+    /// See `Ec2::create_traffic_mirror_filter`.
+    pub fn create_traffic_mirror_filter(
+        &self,
+        input: CreateTrafficMirrorFilterRequest,
+    ) -> Result<CreateTrafficMirrorFilterResult, RusotoError<CreateTrafficMirrorFilterError>> {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_filter(input))
+    }
+
+    /// <p>Creates a Traffic Mirror filter rule. </p> <p>A Traffic Mirror rule defines the Traffic Mirror source traffic to mirror.</p> <p>You need the Traffic Mirror filter ID when you create the rule.</p>
+    ///
+    /// See `Ec2::create_traffic_mirror_filter_rule`.
+    pub fn create_traffic_mirror_filter_rule(
+        &self,
+        input: CreateTrafficMirrorFilterRuleRequest,
+    ) -> Result<CreateTrafficMirrorFilterRuleResult, RusotoError<CreateTrafficMirrorFilterRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_filter_rule(input))
+    }
+
+    /// <p>Creates a Traffic Mirror session.</p> <p>A Traffic Mirror session actively copies packets from a Traffic Mirror source to a Traffic Mirror target. Create a filter, and then assign it to the session to define a subset of the traffic to mirror, for example all TCP traffic.</p> <p>The Traffic Mirror source and the Traffic Mirror target (monitoring appliances) can be in the same VPC, or in a different VPC connected via VPC peering or a transit gateway. </p> <p>By default, no traffic is mirrored. Use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_CreateTrafficMirrorFilter.htm">CreateTrafficMirrorFilter</a> to create filter rules that specify the traffic to mirror.</p>
+    ///
+    /// See `Ec2::create_traffic_mirror_session`.
+    pub fn create_traffic_mirror_session(
+        &self,
+        input: CreateTrafficMirrorSessionRequest,
+    ) -> Result<CreateTrafficMirrorSessionResult, RusotoError<CreateTrafficMirrorSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_session(input))
+    }
+
+    /// <p>Creates a target for your Traffic Mirror session.</p> <p>A Traffic Mirror target is the destination for mirrored traffic. The Traffic Mirror source and the Traffic Mirror target (monitoring appliances) can be in the same VPC, or in different VPCs connected via VPC peering or a transit gateway.</p> <p>A Traffic Mirror target can be a network interface, or a Network Load Balancer.</p> <p>To use the target in a Traffic Mirror session, use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_CreateTrafficMirrorSession.htm">CreateTrafficMirrorSession</a>.</p>
+    ///
+    /// See `Ec2::create_traffic_mirror_target`.
+    pub fn create_traffic_mirror_target(
+        &self,
+        input: CreateTrafficMirrorTargetRequest,
+    ) -> Result<CreateTrafficMirrorTargetResult, RusotoError<CreateTrafficMirrorTargetError>> {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_target(input))
+    }
+
+    /// <p>Creates a transit gateway.</p> <p>You can use a transit gateway to interconnect your virtual private clouds (VPC) and on-premises networks. After the transit gateway enters the <code>available</code> state, you can attach your VPCs and VPN connections to the transit gateway.</p> <p>To attach your VPCs, use <a>CreateTransitGatewayVpcAttachment</a>.</p> <p>To attach a VPN connection, use <a>CreateCustomerGateway</a> to create a customer gateway and specify the ID of the customer gateway and the ID of the transit gateway in a call to <a>CreateVpnConnection</a>.</p> <p>When you create a transit gateway, we create a default transit gateway route table and use it as the default association route table and the default propagation route table. You can use <a>CreateTransitGatewayRouteTable</a> to create additional transit gateway route tables. If you disable automatic route propagation, we do not create a default transit gateway route table. You can use <a>EnableTransitGatewayRouteTablePropagation</a> to propagate routes from a resource attachment to a transit gateway route table. If you disable automatic associations, you can use <a>AssociateTransitGatewayRouteTable</a> to associate a resource attachment with a transit gateway route table.</p>
+    ///
+    /// See `Ec2::create_transit_gateway`.
+    pub fn create_transit_gateway(
+        &self,
+        input: CreateTransitGatewayRequest,
+    ) -> Result<CreateTransitGatewayResult, RusotoError<CreateTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_transit_gateway(input))
+    }
+
+    /// <p>Creates a multicast domain using the specified transit gateway.</p> <p>The transit gateway must be in the available state before you create a domain. Use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeTransitGateways.html">DescribeTransitGateways</a> to see the state of transit gateway.</p>
+    ///
+    /// See `Ec2::create_transit_gateway_multicast_domain`.
+    pub fn create_transit_gateway_multicast_domain(
+        &self,
+        input: CreateTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        CreateTransitGatewayMulticastDomainResult,
+        RusotoError<CreateTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_multicast_domain(input))
+    }
+
+    /// <p>Requests a transit gateway peering attachment between the specified transit gateway (requester) and a peer transit gateway (accepter). The transit gateways must be in different Regions. The peer transit gateway can be in your account or a different AWS account. </p> <p>After you create the peering attachment, the owner of the accepter transit gateway must accept the attachment request.</p>
+    ///
+    /// See `Ec2::create_transit_gateway_peering_attachment`.
+    pub fn create_transit_gateway_peering_attachment(
+        &self,
+        input: CreateTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        CreateTransitGatewayPeeringAttachmentResult,
+        RusotoError<CreateTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_peering_attachment(input))
+    }
+
+    /// <p>Creates a static route for the specified transit gateway route table.</p>
+    ///
+    /// See `Ec2::create_transit_gateway_route`.
+    pub fn create_transit_gateway_route(
+        &self,
+        input: CreateTransitGatewayRouteRequest,
+    ) -> Result<CreateTransitGatewayRouteResult, RusotoError<CreateTransitGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_route(input))
+    }
+
+    /// <p>Creates a route table for the specified transit gateway.</p>
+    ///
+    /// See `Ec2::create_transit_gateway_route_table`.
+    pub fn create_transit_gateway_route_table(
+        &self,
+        input: CreateTransitGatewayRouteTableRequest,
+    ) -> Result<
+        CreateTransitGatewayRouteTableResult,
+        RusotoError<CreateTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_route_table(input))
+    }
+
+    /// <p>Attaches the specified VPC to the specified transit gateway.</p> <p>If you attach a VPC with a CIDR range that overlaps the CIDR range of a VPC that is already attached, the new VPC CIDR range is not propagated to the default propagation route table.</p> <p>To send VPC traffic to an attached transit gateway, add a route to the VPC route table using <a>CreateRoute</a>.</p>
+    ///
+    /// See `Ec2::create_transit_gateway_vpc_attachment`.
+    pub fn create_transit_gateway_vpc_attachment(
+        &self,
+        input: CreateTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        CreateTransitGatewayVpcAttachmentResult,
+        RusotoError<CreateTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_vpc_attachment(input))
+    }
+
+    /// <p>Creates an EBS volume that can be attached to an instance in the same Availability Zone. The volume is created in the regional endpoint that you send the HTTP request to. For more information see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p> <p>You can create a new empty volume or restore a volume from an EBS snapshot. Any AWS Marketplace product codes from the snapshot are propagated to the volume.</p> <p>You can create encrypted volumes. Encrypted volumes must be attached to instances that support Amazon EBS encryption. Volumes that are created from encrypted snapshots are also automatically encrypted. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>You can tag your volumes during creation. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Amazon EC2 Resources</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-creating-volume.html">Creating an Amazon EBS Volume</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_volume`.
+    pub fn create_volume(
+        &self,
+        input: CreateVolumeRequest,
+    ) -> Result<Volume, RusotoError<CreateVolumeError>> {
+        self.runtime.block_on(self.client.create_volume(input))
+    }
+
+    /// <p>Creates a VPC with the specified IPv4 CIDR block. The smallest VPC you can create uses a /28 netmask (16 IPv4 addresses), and the largest uses a /16 netmask (65,536 IPv4 addresses). For more information about how large to make your VPC, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">Your VPC and Subnets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>You can optionally request an IPv6 CIDR block for the VPC. You can request an Amazon-provided IPv6 CIDR block from Amazon's pool of IPv6 addresses, or an IPv6 CIDR block from an IPv6 address pool that you provisioned through bring your own IP addresses (<a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-byoip.html">BYOIP</a>).</p> <p>By default, each instance you launch in the VPC has the default DHCP options, which include only a default DNS server that we provide (AmazonProvidedDNS). For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_DHCP_Options.html">DHCP Options Sets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>You can specify the instance tenancy value for the VPC when you create it. You can't change this value for the VPC after you create it. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/dedicated-instance.html">Dedicated Instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_vpc`.
+    pub fn create_vpc(
+        &self,
+        input: CreateVpcRequest,
+    ) -> Result<CreateVpcResult, RusotoError<CreateVpcError>> {
+        self.runtime.block_on(self.client.create_vpc(input))
+    }
+
+    /// <p>Creates a VPC endpoint for a specified service. An endpoint enables you to create a private connection between your VPC and the service. The service may be provided by AWS, an AWS Marketplace Partner, or another AWS account. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/vpc-endpoints.html">VPC Endpoints</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>A <code>gateway</code> endpoint serves as a target for a route in your route table for traffic destined for the AWS service. You can specify an endpoint policy to attach to the endpoint, which will control access to the service from your VPC. You can also specify the VPC route tables that use the endpoint.</p> <p>An <code>interface</code> endpoint is a network interface in your subnet that serves as an endpoint for communicating with the specified service. You can specify the subnets in which to create an endpoint, and the security groups to associate with the endpoint network interface.</p> <p>Use <a>DescribeVpcEndpointServices</a> to get a list of supported services.</p>
+    ///
+    /// See `Ec2::create_vpc_endpoint`.
+    pub fn create_vpc_endpoint(
+        &self,
+        input: CreateVpcEndpointRequest,
+    ) -> Result<CreateVpcEndpointResult, RusotoError<CreateVpcEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_vpc_endpoint(input))
+    }
+
+    /// <p>Creates a connection notification for a specified VPC endpoint or VPC endpoint service. A connection notification notifies you of specific endpoint events. You must create an SNS topic to receive notifications. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/CreateTopic.html">Create a Topic</a> in the <i>Amazon Simple Notification Service Developer Guide</i>.</p> <p>You can create a connection notification for interface endpoints only.</p>
+    ///
+    /// See `Ec2::create_vpc_endpoint_connection_notification`.
+    pub fn create_vpc_endpoint_connection_notification(
+        &self,
+        input: CreateVpcEndpointConnectionNotificationRequest,
+    ) -> Result<
+        CreateVpcEndpointConnectionNotificationResult,
+        RusotoError<CreateVpcEndpointConnectionNotificationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_vpc_endpoint_connection_notification(input),
+        )
+    }
+
+    /// <p>Creates a VPC endpoint service configuration to which service consumers (AWS accounts, IAM users, and IAM roles) can connect. Service consumers can create an interface VPC endpoint to connect to your service.</p> <p>To create an endpoint service configuration, you must first create a Network Load Balancer for your service. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/endpoint-service.html">VPC Endpoint Services</a> in the <i>Amazon Virtual Private Cloud User Guide</i>. </p> <p>If you set the private DNS name, you must prove that you own the private DNS domain name. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/endpoint-services-dns-validation.html">VPC Endpoint Service Private DNS Name Verification</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_vpc_endpoint_service_configuration`.
+    pub fn create_vpc_endpoint_service_configuration(
+        &self,
+        input: CreateVpcEndpointServiceConfigurationRequest,
+    ) -> Result<
+        CreateVpcEndpointServiceConfigurationResult,
+        RusotoError<CreateVpcEndpointServiceConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_vpc_endpoint_service_configuration(input))
+    }
+
+    /// <p>Requests a VPC peering connection between two VPCs: a requester VPC that you own and an accepter VPC with which to create the connection. The accepter VPC can belong to another AWS account and can be in a different Region to the requester VPC. The requester VPC and accepter VPC cannot have overlapping CIDR blocks.</p> <note> <p>Limitations and rules apply to a VPC peering connection. For more information, see the <a href="https://docs.aws.amazon.com/vpc/latest/peering/vpc-peering-basics.html#vpc-peering-limitations">limitations</a> section in the <i>VPC Peering Guide</i>.</p> </note> <p>The owner of the accepter VPC must accept the peering request to activate the peering connection. The VPC peering connection request expires after 7 days, after which it cannot be accepted or rejected.</p> <p>If you create a VPC peering connection request between VPCs with overlapping CIDR blocks, the VPC peering connection has a status of <code>failed</code>.</p>
+    ///
+    /// See `Ec2::create_vpc_peering_connection`.
+    pub fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionRequest,
+    ) -> Result<CreateVpcPeeringConnectionResult, RusotoError<CreateVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_vpc_peering_connection(input))
+    }
+
+    /// <p>Creates a VPN connection between an existing virtual private gateway and a VPN customer gateway. The supported connection type is <code>ipsec.1</code>.</p> <p>The response includes information that you need to give to your network administrator to configure your customer gateway.</p> <important> <p>We strongly recommend that you use HTTPS when calling this operation because the response contains sensitive cryptographic information for configuring your customer gateway.</p> </important> <p>If you decide to shut down your VPN connection for any reason and later create a new VPN connection, you must reconfigure your customer gateway with the new information returned from this call.</p> <p>This is an idempotent operation. If you perform the operation more than once, Amazon EC2 doesn't return an error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_vpn_connection`.
+    pub fn create_vpn_connection(
+        &self,
+        input: CreateVpnConnectionRequest,
+    ) -> Result<CreateVpnConnectionResult, RusotoError<CreateVpnConnectionError>> {
+        self.runtime
+            .block_on(self.client.create_vpn_connection(input))
+    }
+
+    /// <p>Creates a static route associated with a VPN connection between an existing virtual private gateway and a VPN customer gateway. The static route allows traffic to be routed from the virtual private gateway to the VPN customer gateway.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_vpn_connection_route`.
+    pub fn create_vpn_connection_route(
+        &self,
+        input: CreateVpnConnectionRouteRequest,
+    ) -> Result<(), RusotoError<CreateVpnConnectionRouteError>> {
+        self.runtime
+            .block_on(self.client.create_vpn_connection_route(input))
+    }
+
+    /// <p>Creates a virtual private gateway. A virtual private gateway is the endpoint on the VPC side of your VPN connection. You can create a virtual private gateway before creating the VPC itself.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::create_vpn_gateway`.
+    pub fn create_vpn_gateway(
+        &self,
+        input: CreateVpnGatewayRequest,
+    ) -> Result<CreateVpnGatewayResult, RusotoError<CreateVpnGatewayError>> {
+        self.runtime.block_on(self.client.create_vpn_gateway(input))
+    }
+
+    /// <p>Deletes the specified Client VPN endpoint. You must disassociate all target networks before you can delete a Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::delete_client_vpn_endpoint`.
+    pub fn delete_client_vpn_endpoint(
+        &self,
+        input: DeleteClientVpnEndpointRequest,
+    ) -> Result<DeleteClientVpnEndpointResult, RusotoError<DeleteClientVpnEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_client_vpn_endpoint(input))
+    }
+
+    /// <p>Deletes a route from a Client VPN endpoint. You can only delete routes that you manually added using the <b>CreateClientVpnRoute</b> action. You cannot delete routes that were automatically added when associating a subnet. To remove routes that have been automatically added, disassociate the target subnet from the Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::delete_client_vpn_route`.
+    pub fn delete_client_vpn_route(
+        &self,
+        input: DeleteClientVpnRouteRequest,
+    ) -> Result<DeleteClientVpnRouteResult, RusotoError<DeleteClientVpnRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_client_vpn_route(input))
+    }
+
+    /// <p>Deletes the specified customer gateway. You must delete the VPN connection before you can delete the customer gateway.</p>
+    ///
+    /// See `Ec2::delete_customer_gateway`.
+    pub fn delete_customer_gateway(
+        &self,
+        input: DeleteCustomerGatewayRequest,
+    ) -> Result<(), RusotoError<DeleteCustomerGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_customer_gateway(input))
+    }
+
+    /// <p>Deletes the specified set of DHCP options. You must disassociate the set of DHCP options before you can delete it. You can disassociate the set of DHCP options by associating either a new set of options or the default set of options with the VPC.</p>
+    ///
+    /// See `Ec2::delete_dhcp_options`.
+    pub fn delete_dhcp_options(
+        &self,
+        input: DeleteDhcpOptionsRequest,
+    ) -> Result<(), RusotoError<DeleteDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.delete_dhcp_options(input))
+    }
+
+    /// <p>Deletes an egress-only internet gateway.</p>
+    ///
+    /// See `Ec2::delete_egress_only_internet_gateway`.
+    pub fn delete_egress_only_internet_gateway(
+        &self,
+        input: DeleteEgressOnlyInternetGatewayRequest,
+    ) -> Result<
+        DeleteEgressOnlyInternetGatewayResult,
+        RusotoError<DeleteEgressOnlyInternetGatewayError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_egress_only_internet_gateway(input))
+    }
+
+    /// <p>Deletes the specified EC2 Fleet.</p> <p>After you delete an EC2 Fleet, it launches no new instances. You must specify whether an EC2 Fleet should also terminate its instances. If you terminate the instances, the EC2 Fleet enters the <code>deleted_terminating</code> state. Otherwise, the EC2 Fleet enters the <code>deleted_running</code> state, and the instances continue to run until they are interrupted or you terminate them manually. </p>
+    ///
+    /// See `Ec2::delete_fleets`.
+    pub fn delete_fleets(
+        &self,
+        input: DeleteFleetsRequest,
+    ) -> Result<DeleteFleetsResult, RusotoError<DeleteFleetsError>> {
+        self.runtime.block_on(self.client.delete_fleets(input))
+    }
+
+    /// <p>Deletes one or more flow logs.</p>
+    ///
+    /// See `Ec2::delete_flow_logs`.
+    pub fn delete_flow_logs(
+        &self,
+        input: DeleteFlowLogsRequest,
+    ) -> Result<DeleteFlowLogsResult, RusotoError<DeleteFlowLogsError>> {
+        self.runtime.block_on(self.client.delete_flow_logs(input))
+    }
+
+    /// <p>Deletes the specified Amazon FPGA Image (AFI).</p>
+    ///
+    /// See `Ec2::delete_fpga_image`.
+    pub fn delete_fpga_image(
+        &self,
+        input: DeleteFpgaImageRequest,
+    ) -> Result<DeleteFpgaImageResult, RusotoError<DeleteFpgaImageError>> {
+        self.runtime.block_on(self.client.delete_fpga_image(input))
+    }
+
+    /// <p>Deletes the specified internet gateway. You must detach the internet gateway from the VPC before you can delete it.</p>
+    ///
+    /// See `Ec2::delete_internet_gateway`.
+    pub fn delete_internet_gateway(
+        &self,
+        input: DeleteInternetGatewayRequest,
+    ) -> Result<(), RusotoError<DeleteInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_internet_gateway(input))
+    }
+
+    /// <p>Deletes the specified key pair, by removing the public key from Amazon EC2.</p>
+    ///
+    /// See `Ec2::delete_key_pair`.
+    pub fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> Result<(), RusotoError<DeleteKeyPairError>> {
+        self.runtime.block_on(self.client.delete_key_pair(input))
+    }
+
+    /// <p>Deletes a launch template. Deleting a launch template deletes all of its versions.</p>
+    ///
+    /// See `Ec2::delete_launch_template`.
+    pub fn delete_launch_template(
+        &self,
+        input: DeleteLaunchTemplateRequest,
+    ) -> Result<DeleteLaunchTemplateResult, RusotoError<DeleteLaunchTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_launch_template(input))
+    }
+
+    /// <p>Deletes one or more versions of a launch template. You cannot delete the default version of a launch template; you must first assign a different version as the default. If the default version is the only version for the launch template, you must delete the entire launch template using <a>DeleteLaunchTemplate</a>.</p>
+    ///
+    /// See `Ec2::delete_launch_template_versions`.
+    pub fn delete_launch_template_versions(
+        &self,
+        input: DeleteLaunchTemplateVersionsRequest,
+    ) -> Result<DeleteLaunchTemplateVersionsResult, RusotoError<DeleteLaunchTemplateVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_launch_template_versions(input))
+    }
+
+    /// <p>Deletes the specified route from the specified local gateway route table.</p>
+    ///
+    /// See `Ec2::delete_local_gateway_route`.
+    pub fn delete_local_gateway_route(
+        &self,
+        input: DeleteLocalGatewayRouteRequest,
+    ) -> Result<DeleteLocalGatewayRouteResult, RusotoError<DeleteLocalGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_local_gateway_route(input))
+    }
+
+    /// <p>Deletes the specified association between a VPC and local gateway route table.</p>
+    ///
+    /// See `Ec2::delete_local_gateway_route_table_vpc_association`.
+    pub fn delete_local_gateway_route_table_vpc_association(
+        &self,
+        input: DeleteLocalGatewayRouteTableVpcAssociationRequest,
+    ) -> Result<
+        DeleteLocalGatewayRouteTableVpcAssociationResult,
+        RusotoError<DeleteLocalGatewayRouteTableVpcAssociationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_local_gateway_route_table_vpc_association(input),
+        )
+    }
+
+    /// <p>Deletes the specified managed prefix list. You must first remove all references to the prefix list in your resources.</p>
+    ///
+    /// See `Ec2::delete_managed_prefix_list`.
+    pub fn delete_managed_prefix_list(
+        &self,
+        input: DeleteManagedPrefixListRequest,
+    ) -> Result<DeleteManagedPrefixListResult, RusotoError<DeleteManagedPrefixListError>> {
+        self.runtime
+            .block_on(self.client.delete_managed_prefix_list(input))
+    }
+
+    /// <p>Deletes the specified NAT gateway. Deleting a NAT gateway disassociates its Elastic IP address, but does not release the address from your account. Deleting a NAT gateway does not delete any NAT gateway routes in your route tables.</p>
+    ///
+    /// See `Ec2::delete_nat_gateway`.
+    pub fn delete_nat_gateway(
+        &self,
+        input: DeleteNatGatewayRequest,
+    ) -> Result<DeleteNatGatewayResult, RusotoError<DeleteNatGatewayError>> {
+        self.runtime.block_on(self.client.delete_nat_gateway(input))
+    }
+
+    /// <p>Deletes the specified network ACL. You can't delete the ACL if it's associated with any subnets. You can't delete the default network ACL.</p>
+    ///
+    /// See `Ec2::delete_network_acl`.
+    pub fn delete_network_acl(
+        &self,
+        input: DeleteNetworkAclRequest,
+    ) -> Result<(), RusotoError<DeleteNetworkAclError>> {
+        self.runtime.block_on(self.client.delete_network_acl(input))
+    }
+
+    /// <p>Deletes the specified ingress or egress entry (rule) from the specified network ACL.</p>
+    ///
+    /// See `Ec2::delete_network_acl_entry`.
+    pub fn delete_network_acl_entry(
+        &self,
+        input: DeleteNetworkAclEntryRequest,
+    ) -> Result<(), RusotoError<DeleteNetworkAclEntryError>> {
+        self.runtime
+            .block_on(self.client.delete_network_acl_entry(input))
+    }
+
+    /// <p>Deletes the specified network interface. You must detach the network interface before you can delete it.</p>
+    ///
+    /// See `Ec2::delete_network_interface`.
+    pub fn delete_network_interface(
+        &self,
+        input: DeleteNetworkInterfaceRequest,
+    ) -> Result<(), RusotoError<DeleteNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.delete_network_interface(input))
+    }
+
+    /// <p>Deletes a permission for a network interface. By default, you cannot delete the permission if the account for which you're removing the permission has attached the network interface to an instance. However, you can force delete the permission, regardless of any attachment.</p>
+    ///
+    /// See `Ec2::delete_network_interface_permission`.
+    pub fn delete_network_interface_permission(
+        &self,
+        input: DeleteNetworkInterfacePermissionRequest,
+    ) -> Result<
+        DeleteNetworkInterfacePermissionResult,
+        RusotoError<DeleteNetworkInterfacePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_network_interface_permission(input))
+    }
+
+    /// <p>Deletes the specified placement group. You must terminate all instances in the placement group before you can delete the placement group. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/placement-groups.html">Placement groups</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::delete_placement_group`.
+    pub fn delete_placement_group(
+        &self,
+        input: DeletePlacementGroupRequest,
+    ) -> Result<(), RusotoError<DeletePlacementGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_placement_group(input))
+    }
+
+    /// <p>Deletes the queued purchases for the specified Reserved Instances.</p>
+    ///
+    /// See `Ec2::delete_queued_reserved_instances`.
+    pub fn delete_queued_reserved_instances(
+        &self,
+        input: DeleteQueuedReservedInstancesRequest,
+    ) -> Result<DeleteQueuedReservedInstancesResult, RusotoError<DeleteQueuedReservedInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_queued_reserved_instances(input))
+    }
+
+    /// <p>Deletes the specified route from the specified route table.</p>
+    ///
+    /// See `Ec2::delete_route`.
+    pub fn delete_route(
+        &self,
+        input: DeleteRouteRequest,
+    ) -> Result<(), RusotoError<DeleteRouteError>> {
+        self.runtime.block_on(self.client.delete_route(input))
+    }
+
+    /// <p>Deletes the specified route table. You must disassociate the route table from any subnets before you can delete it. You can't delete the main route table.</p>
+    ///
+    /// See `Ec2::delete_route_table`.
+    pub fn delete_route_table(
+        &self,
+        input: DeleteRouteTableRequest,
+    ) -> Result<(), RusotoError<DeleteRouteTableError>> {
+        self.runtime.block_on(self.client.delete_route_table(input))
+    }
+
+    /// <p>Deletes a security group.</p> <p>If you attempt to delete a security group that is associated with an instance, or is referenced by another security group, the operation fails with <code>InvalidGroup.InUse</code> in EC2-Classic or <code>DependencyViolation</code> in EC2-VPC.</p>
+    ///
+    /// See `Ec2::delete_security_group`.
+    pub fn delete_security_group(
+        &self,
+        input: DeleteSecurityGroupRequest,
+    ) -> Result<(), RusotoError<DeleteSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_security_group(input))
+    }
+
+    /// <p>Deletes the specified snapshot.</p> <p>When you make periodic snapshots of a volume, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the volume.</p> <p>You cannot delete a snapshot of the root device of an EBS volume used by a registered AMI. You must first de-register the AMI before you can delete the snapshot.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-deleting-snapshot.html">Deleting an Amazon EBS Snapshot</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::delete_snapshot`.
+    pub fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> Result<(), RusotoError<DeleteSnapshotError>> {
+        self.runtime.block_on(self.client.delete_snapshot(input))
+    }
+
+    /// <p>Deletes the data feed for Spot Instances.</p>
+    ///
+    /// See `Ec2::delete_spot_datafeed_subscription`.
+    pub fn delete_spot_datafeed_subscription(
+        &self,
+        input: DeleteSpotDatafeedSubscriptionRequest,
+    ) -> Result<(), RusotoError<DeleteSpotDatafeedSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_spot_datafeed_subscription(input))
+    }
+
+    /// <p>Deletes the specified subnet. You must terminate all running instances in the subnet before you can delete the subnet.</p>
+    ///
+    /// See `Ec2::delete_subnet`.
+    pub fn delete_subnet(
+        &self,
+        input: DeleteSubnetRequest,
+    ) -> Result<(), RusotoError<DeleteSubnetError>> {
+        self.runtime.block_on(self.client.delete_subnet(input))
+    }
+
+    /// <p>Deletes the specified set of tags from the specified set of resources.</p> <p>To list the current tags, use <a>DescribeTags</a>. For more information about tags, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Resources</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Deletes the specified Traffic Mirror filter.</p> <p>You cannot delete a Traffic Mirror filter that is in use by a Traffic Mirror session.</p>
+    ///
+    /// See `Ec2::delete_traffic_mirror_filter`.
+    pub fn delete_traffic_mirror_filter(
+        &self,
+        input: DeleteTrafficMirrorFilterRequest,
+    ) -> Result<DeleteTrafficMirrorFilterResult, RusotoError<DeleteTrafficMirrorFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_filter(input))
+    }
+
+    /// <p>Deletes the specified Traffic Mirror rule.</p>
+    ///
+    /// See `Ec2::delete_traffic_mirror_filter_rule`.
+    pub fn delete_traffic_mirror_filter_rule(
+        &self,
+        input: DeleteTrafficMirrorFilterRuleRequest,
+    ) -> Result<DeleteTrafficMirrorFilterRuleResult, RusotoError<DeleteTrafficMirrorFilterRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_filter_rule(input))
+    }
+
+    /// <p>Deletes the specified Traffic Mirror session.</p>
+    ///
+    /// See `Ec2::delete_traffic_mirror_session`.
+    pub fn delete_traffic_mirror_session(
+        &self,
+        input: DeleteTrafficMirrorSessionRequest,
+    ) -> Result<DeleteTrafficMirrorSessionResult, RusotoError<DeleteTrafficMirrorSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_session(input))
+    }
+
+    /// <p>Deletes the specified Traffic Mirror target.</p> <p>You cannot delete a Traffic Mirror target that is in use by a Traffic Mirror session.</p>
+    ///
+    /// See `Ec2::delete_traffic_mirror_target`.
+    pub fn delete_traffic_mirror_target(
+        &self,
+        input: DeleteTrafficMirrorTargetRequest,
+    ) -> Result<DeleteTrafficMirrorTargetResult, RusotoError<DeleteTrafficMirrorTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_target(input))
+    }
+
+    /// <p>Deletes the specified transit gateway.</p>
+    ///
+    /// See `Ec2::delete_transit_gateway`.
+    pub fn delete_transit_gateway(
+        &self,
+        input: DeleteTransitGatewayRequest,
+    ) -> Result<DeleteTransitGatewayResult, RusotoError<DeleteTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway(input))
+    }
+
+    /// <p>Deletes the specified transit gateway multicast domain.</p>
+    ///
+    /// See `Ec2::delete_transit_gateway_multicast_domain`.
+    pub fn delete_transit_gateway_multicast_domain(
+        &self,
+        input: DeleteTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        DeleteTransitGatewayMulticastDomainResult,
+        RusotoError<DeleteTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_multicast_domain(input))
+    }
+
+    /// <p>Deletes a transit gateway peering attachment.</p>
+    ///
+    /// See `Ec2::delete_transit_gateway_peering_attachment`.
+    pub fn delete_transit_gateway_peering_attachment(
+        &self,
+        input: DeleteTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        DeleteTransitGatewayPeeringAttachmentResult,
+        RusotoError<DeleteTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_peering_attachment(input))
+    }
+
+    /// <p>Deletes the specified route from the specified transit gateway route table.</p>
+    ///
+    /// See `Ec2::delete_transit_gateway_route`.
+    pub fn delete_transit_gateway_route(
+        &self,
+        input: DeleteTransitGatewayRouteRequest,
+    ) -> Result<DeleteTransitGatewayRouteResult, RusotoError<DeleteTransitGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_route(input))
+    }
+
+    /// <p>Deletes the specified transit gateway route table. You must disassociate the route table from any transit gateway route tables before you can delete it.</p>
+    ///
+    /// See `Ec2::delete_transit_gateway_route_table`.
+    pub fn delete_transit_gateway_route_table(
+        &self,
+        input: DeleteTransitGatewayRouteTableRequest,
+    ) -> Result<
+        DeleteTransitGatewayRouteTableResult,
+        RusotoError<DeleteTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_route_table(input))
+    }
+
+    /// <p>Deletes the specified VPC attachment.</p>
+    ///
+    /// See `Ec2::delete_transit_gateway_vpc_attachment`.
+    pub fn delete_transit_gateway_vpc_attachment(
+        &self,
+        input: DeleteTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        DeleteTransitGatewayVpcAttachmentResult,
+        RusotoError<DeleteTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_vpc_attachment(input))
+    }
+
+    /// <p>Deletes the specified EBS volume. The volume must be in the <code>available</code> state (not attached to an instance).</p> <p>The volume can remain in the <code>deleting</code> state for several minutes.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-deleting-volume.html">Deleting an Amazon EBS Volume</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::delete_volume`.
+    pub fn delete_volume(
+        &self,
+        input: DeleteVolumeRequest,
+    ) -> Result<(), RusotoError<DeleteVolumeError>> {
+        self.runtime.block_on(self.client.delete_volume(input))
+    }
+
+    /// <p>Deletes the specified VPC. You must detach or delete all gateways and resources that are associated with the VPC before you can delete it. For example, you must terminate all instances running in the VPC, delete all security groups associated with the VPC (except the default one), delete all route tables associated with the VPC (except the default one), and so on.</p>
+    ///
+    /// See `Ec2::delete_vpc`.
+    pub fn delete_vpc(&self, input: DeleteVpcRequest) -> Result<(), RusotoError<DeleteVpcError>> {
+        self.runtime.block_on(self.client.delete_vpc(input))
+    }
+
+    /// <p>Deletes one or more VPC endpoint connection notifications.</p>
+    ///
+    /// See `Ec2::delete_vpc_endpoint_connection_notifications`.
+    pub fn delete_vpc_endpoint_connection_notifications(
+        &self,
+        input: DeleteVpcEndpointConnectionNotificationsRequest,
+    ) -> Result<
+        DeleteVpcEndpointConnectionNotificationsResult,
+        RusotoError<DeleteVpcEndpointConnectionNotificationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_vpc_endpoint_connection_notifications(input),
+        )
+    }
+
+    /// <p>Deletes one or more VPC endpoint service configurations in your account. Before you delete the endpoint service configuration, you must reject any <code>Available</code> or <code>PendingAcceptance</code> interface endpoint connections that are attached to the service.</p>
+    ///
+    /// See `Ec2::delete_vpc_endpoint_service_configurations`.
+    pub fn delete_vpc_endpoint_service_configurations(
+        &self,
+        input: DeleteVpcEndpointServiceConfigurationsRequest,
+    ) -> Result<
+        DeleteVpcEndpointServiceConfigurationsResult,
+        RusotoError<DeleteVpcEndpointServiceConfigurationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_vpc_endpoint_service_configurations(input),
+        )
+    }
+
+    /// <p>Deletes one or more specified VPC endpoints. Deleting a gateway endpoint also deletes the endpoint routes in the route tables that were associated with the endpoint. Deleting an interface endpoint deletes the endpoint network interfaces.</p>
+    ///
+    /// See `Ec2::delete_vpc_endpoints`.
+    pub fn delete_vpc_endpoints(
+        &self,
+        input: DeleteVpcEndpointsRequest,
+    ) -> Result<DeleteVpcEndpointsResult, RusotoError<DeleteVpcEndpointsError>> {
+        self.runtime
+            .block_on(self.client.delete_vpc_endpoints(input))
+    }
+
+    /// <p>Deletes a VPC peering connection. Either the owner of the requester VPC or the owner of the accepter VPC can delete the VPC peering connection if it's in the <code>active</code> state. The owner of the requester VPC can delete a VPC peering connection in the <code>pending-acceptance</code> state. You cannot delete a VPC peering connection that's in the <code>failed</code> state.</p>
+    ///
+    /// See `Ec2::delete_vpc_peering_connection`.
+    pub fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionRequest,
+    ) -> Result<DeleteVpcPeeringConnectionResult, RusotoError<DeleteVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_vpc_peering_connection(input))
+    }
+
+    /// <p>Deletes the specified VPN connection.</p> <p>If you're deleting the VPC and its associated components, we recommend that you detach the virtual private gateway from the VPC and delete the VPC before deleting the VPN connection. If you believe that the tunnel credentials for your VPN connection have been compromised, you can delete the VPN connection and create a new one that has new keys, without needing to delete the VPC or virtual private gateway. If you create a new VPN connection, you must reconfigure the customer gateway device using the new configuration information returned with the new VPN connection ID.</p> <p>For certificate-based authentication, delete all AWS Certificate Manager (ACM) private certificates used for the AWS-side tunnel endpoints for the VPN connection before deleting the VPN connection.</p>
+    ///
+    /// See `Ec2::delete_vpn_connection`.
+    pub fn delete_vpn_connection(
+        &self,
+        input: DeleteVpnConnectionRequest,
+    ) -> Result<(), RusotoError<DeleteVpnConnectionError>> {
+        self.runtime
+            .block_on(self.client.delete_vpn_connection(input))
+    }
+
+    /// <p>Deletes the specified static route associated with a VPN connection between an existing virtual private gateway and a VPN customer gateway. The static route allows traffic to be routed from the virtual private gateway to the VPN customer gateway.</p>
+    ///
+    /// See `Ec2::delete_vpn_connection_route`.
+    pub fn delete_vpn_connection_route(
+        &self,
+        input: DeleteVpnConnectionRouteRequest,
+    ) -> Result<(), RusotoError<DeleteVpnConnectionRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_vpn_connection_route(input))
+    }
+
+    /// <p>Deletes the specified virtual private gateway. You must first detach the virtual private gateway from the VPC. Note that you don't need to delete the virtual private gateway if you plan to delete and recreate the VPN connection between your VPC and your network.</p>
+    ///
+    /// See `Ec2::delete_vpn_gateway`.
+    pub fn delete_vpn_gateway(
+        &self,
+        input: DeleteVpnGatewayRequest,
+    ) -> Result<(), RusotoError<DeleteVpnGatewayError>> {
+        self.runtime.block_on(self.client.delete_vpn_gateway(input))
+    }
+
+    /// <p>Releases the specified address range that you provisioned for use with your AWS resources through bring your own IP addresses (BYOIP) and deletes the corresponding address pool.</p> <p>Before you can release an address range, you must stop advertising it using <a>WithdrawByoipCidr</a> and you must not have any IP addresses allocated from its address range.</p>
+    ///
+    /// See `Ec2::deprovision_byoip_cidr`.
+    pub fn deprovision_byoip_cidr(
+        &self,
+        input: DeprovisionByoipCidrRequest,
+    ) -> Result<DeprovisionByoipCidrResult, RusotoError<DeprovisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.deprovision_byoip_cidr(input))
+    }
+
+    /// <p>Deregisters the specified AMI. After you deregister an AMI, it can't be used to launch new instances; however, it doesn't affect any instances that you've already launched from the AMI. You'll continue to incur usage costs for those instances until you terminate them.</p> <p>When you deregister an Amazon EBS-backed AMI, it doesn't affect the snapshot that was created for the root volume of the instance during the AMI creation process. When you deregister an instance store-backed AMI, it doesn't affect the files that you uploaded to Amazon S3 when you created the AMI.</p>
+    ///
+    /// See `Ec2::deregister_image`.
+    pub fn deregister_image(
+        &self,
+        input: DeregisterImageRequest,
+    ) -> Result<(), RusotoError<DeregisterImageError>> {
+        self.runtime.block_on(self.client.deregister_image(input))
+    }
+
+    /// <p>Deregisters tag keys to prevent tags that have the specified tag keys from being included in scheduled event notifications for resources in the Region.</p>
+    ///
+    /// See `Ec2::deregister_instance_event_notification_attributes`.
+    pub fn deregister_instance_event_notification_attributes(
+        &self,
+        input: DeregisterInstanceEventNotificationAttributesRequest,
+    ) -> Result<
+        DeregisterInstanceEventNotificationAttributesResult,
+        RusotoError<DeregisterInstanceEventNotificationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .deregister_instance_event_notification_attributes(input),
+        )
+    }
+
+    /// <p>Deregisters the specified members (network interfaces) from the transit gateway multicast group.</p>
+    ///
+    /// See `Ec2::deregister_transit_gateway_multicast_group_members`.
+    pub fn deregister_transit_gateway_multicast_group_members(
+        &self,
+        input: DeregisterTransitGatewayMulticastGroupMembersRequest,
+    ) -> Result<
+        DeregisterTransitGatewayMulticastGroupMembersResult,
+        RusotoError<DeregisterTransitGatewayMulticastGroupMembersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .deregister_transit_gateway_multicast_group_members(input),
+        )
+    }
+
+    /// <p>Deregisters the specified sources (network interfaces) from the transit gateway multicast group.</p>
+    ///
+    /// See `Ec2::deregister_transit_gateway_multicast_group_sources`.
+    pub fn deregister_transit_gateway_multicast_group_sources(
+        &self,
+        input: DeregisterTransitGatewayMulticastGroupSourcesRequest,
+    ) -> Result<
+        DeregisterTransitGatewayMulticastGroupSourcesResult,
+        RusotoError<DeregisterTransitGatewayMulticastGroupSourcesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .deregister_transit_gateway_multicast_group_sources(input),
+        )
+    }
+
+    /// <p><p>Describes attributes of your AWS account. The following are the supported account attributes:</p> <ul> <li> <p> <code>supported-platforms</code>: Indicates whether your account can launch instances into EC2-Classic and EC2-VPC, or only into EC2-VPC.</p> </li> <li> <p> <code>default-vpc</code>: The ID of the default VPC for your account, or <code>none</code>.</p> </li> <li> <p> <code>max-instances</code>: This attribute is no longer supported. The returned value does not reflect your actual vCPU limit for running On-Demand Instances. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-on-demand-instances.html#ec2-on-demand-instances-limits">On-Demand Instance Limits</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> </li> <li> <p> <code>vpc-max-security-groups-per-interface</code>: The maximum number of security groups that you can assign to a network interface.</p> </li> <li> <p> <code>max-elastic-ips</code>: The maximum number of Elastic IP addresses that you can allocate for use with EC2-Classic. </p> </li> <li> <p> <code>vpc-max-elastic-ips</code>: The maximum number of Elastic IP addresses that you can allocate for use with EC2-VPC.</p> </li> </ul></p>
+    ///
+    /// See `Ec2::describe_account_attributes`.
+    pub fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesRequest,
+    ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes(input))
+    }
+
+    /// <p>Describes the specified Elastic IP addresses or all of your Elastic IP addresses.</p> <p>An Elastic IP address is for use in either the EC2-Classic platform or in a VPC. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_addresses`.
+    pub fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
+        self.runtime.block_on(self.client.describe_addresses(input))
+    }
+
+    /// <p>Describes the longer ID format settings for all resource types in a specific Region. This request is useful for performing a quick audit to determine whether a specific Region is fully opted in for longer IDs (17-character IDs).</p> <p>This request only returns information about resource types that support longer IDs.</p> <p>The following resource types support longer IDs: <code>bundle</code> | <code>conversion-task</code> | <code>customer-gateway</code> | <code>dhcp-options</code> | <code>elastic-ip-allocation</code> | <code>elastic-ip-association</code> | <code>export-task</code> | <code>flow-log</code> | <code>image</code> | <code>import-task</code> | <code>instance</code> | <code>internet-gateway</code> | <code>network-acl</code> | <code>network-acl-association</code> | <code>network-interface</code> | <code>network-interface-attachment</code> | <code>prefix-list</code> | <code>reservation</code> | <code>route-table</code> | <code>route-table-association</code> | <code>security-group</code> | <code>snapshot</code> | <code>subnet</code> | <code>subnet-cidr-block-association</code> | <code>volume</code> | <code>vpc</code> | <code>vpc-cidr-block-association</code> | <code>vpc-endpoint</code> | <code>vpc-peering-connection</code> | <code>vpn-connection</code> | <code>vpn-gateway</code>.</p>
+    ///
+    /// See `Ec2::describe_aggregate_id_format`.
+    pub fn describe_aggregate_id_format(
+        &self,
+        input: DescribeAggregateIdFormatRequest,
+    ) -> Result<DescribeAggregateIdFormatResult, RusotoError<DescribeAggregateIdFormatError>> {
+        self.runtime
+            .block_on(self.client.describe_aggregate_id_format(input))
+    }
+
+    /// <p>Describes the Availability Zones and Local Zones that are available to you. If there is an event impacting an Availability Zone or Local Zone, you can use this request to view the state and any provided messages for that Availability Zone or Local Zone.</p> <p>For more information about Availability Zones and Local Zones, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-regions-availability-zones.html">Regions and Availability Zones</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_availability_zones`.
+    pub fn describe_availability_zones(
+        &self,
+        input: DescribeAvailabilityZonesRequest,
+    ) -> Result<DescribeAvailabilityZonesResult, RusotoError<DescribeAvailabilityZonesError>> {
+        self.runtime
+            .block_on(self.client.describe_availability_zones(input))
+    }
+
+    /// <p><p>Describes the specified bundle tasks or all of your bundle tasks.</p> <note> <p>Completed bundle tasks are listed for only a limited time. If your bundle task is no longer in the list, you can still register an AMI from it. Just use <code>RegisterImage</code> with the Amazon S3 bucket name and image manifest name you provided to the bundle task.</p> </note></p>
+    ///
+    /// See `Ec2::describe_bundle_tasks`.
+    pub fn describe_bundle_tasks(
+        &self,
+        input: DescribeBundleTasksRequest,
+    ) -> Result<DescribeBundleTasksResult, RusotoError<DescribeBundleTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_bundle_tasks(input))
+    }
+
+    /// <p>Describes the IP address ranges that were specified in calls to <a>ProvisionByoipCidr</a>.</p> <p>To describe the address pools that were created when you provisioned the address ranges, use <a>DescribePublicIpv4Pools</a> or <a>DescribeIpv6Pools</a>.</p>
+    ///
+    /// See `Ec2::describe_byoip_cidrs`.
+    pub fn describe_byoip_cidrs(
+        &self,
+        input: DescribeByoipCidrsRequest,
+    ) -> Result<DescribeByoipCidrsResult, RusotoError<DescribeByoipCidrsError>> {
+        self.runtime
+            .block_on(self.client.describe_byoip_cidrs(input))
+    }
+
+    /// <p>Describes one or more of your Capacity Reservations. The results describe only the Capacity Reservations in the AWS Region that you're currently using.</p>
+    ///
+    /// See `Ec2::describe_capacity_reservations`.
+    pub fn describe_capacity_reservations(
+        &self,
+        input: DescribeCapacityReservationsRequest,
+    ) -> Result<DescribeCapacityReservationsResult, RusotoError<DescribeCapacityReservationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_capacity_reservations(input))
+    }
+
+    /// <p>Describes one or more of your linked EC2-Classic instances. This request only returns information about EC2-Classic instances linked to a VPC through ClassicLink. You cannot use this request to return information about other instances.</p>
+    ///
+    /// See `Ec2::describe_classic_link_instances`.
+    pub fn describe_classic_link_instances(
+        &self,
+        input: DescribeClassicLinkInstancesRequest,
+    ) -> Result<DescribeClassicLinkInstancesResult, RusotoError<DescribeClassicLinkInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_classic_link_instances(input))
+    }
+
+    /// <p>Describes the authorization rules for a specified Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::describe_client_vpn_authorization_rules`.
+    pub fn describe_client_vpn_authorization_rules(
+        &self,
+        input: DescribeClientVpnAuthorizationRulesRequest,
+    ) -> Result<
+        DescribeClientVpnAuthorizationRulesResult,
+        RusotoError<DescribeClientVpnAuthorizationRulesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_authorization_rules(input))
+    }
+
+    /// <p>Describes active client connections and connections that have been terminated within the last 60 minutes for the specified Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::describe_client_vpn_connections`.
+    pub fn describe_client_vpn_connections(
+        &self,
+        input: DescribeClientVpnConnectionsRequest,
+    ) -> Result<DescribeClientVpnConnectionsResult, RusotoError<DescribeClientVpnConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_connections(input))
+    }
+
+    /// <p>Describes one or more Client VPN endpoints in the account.</p>
+    ///
+    /// See `Ec2::describe_client_vpn_endpoints`.
+    pub fn describe_client_vpn_endpoints(
+        &self,
+        input: DescribeClientVpnEndpointsRequest,
+    ) -> Result<DescribeClientVpnEndpointsResult, RusotoError<DescribeClientVpnEndpointsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_endpoints(input))
+    }
+
+    /// <p>Describes the routes for the specified Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::describe_client_vpn_routes`.
+    pub fn describe_client_vpn_routes(
+        &self,
+        input: DescribeClientVpnRoutesRequest,
+    ) -> Result<DescribeClientVpnRoutesResult, RusotoError<DescribeClientVpnRoutesError>> {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_routes(input))
+    }
+
+    /// <p>Describes the target networks associated with the specified Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::describe_client_vpn_target_networks`.
+    pub fn describe_client_vpn_target_networks(
+        &self,
+        input: DescribeClientVpnTargetNetworksRequest,
+    ) -> Result<
+        DescribeClientVpnTargetNetworksResult,
+        RusotoError<DescribeClientVpnTargetNetworksError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_target_networks(input))
+    }
+
+    /// <p>Describes the specified customer-owned address pools or all of your customer-owned address pools.</p>
+    ///
+    /// See `Ec2::describe_coip_pools`.
+    pub fn describe_coip_pools(
+        &self,
+        input: DescribeCoipPoolsRequest,
+    ) -> Result<DescribeCoipPoolsResult, RusotoError<DescribeCoipPoolsError>> {
+        self.runtime
+            .block_on(self.client.describe_coip_pools(input))
+    }
+
+    /// <p>Describes the specified conversion tasks or all your conversion tasks. For more information, see the <a href="https://docs.aws.amazon.com/vm-import/latest/userguide/">VM Import/Export User Guide</a>.</p> <p>For information about the import manifest referenced by this API action, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/manifest.html">VM Import Manifest</a>.</p>
+    ///
+    /// See `Ec2::describe_conversion_tasks`.
+    pub fn describe_conversion_tasks(
+        &self,
+        input: DescribeConversionTasksRequest,
+    ) -> Result<DescribeConversionTasksResult, RusotoError<DescribeConversionTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_conversion_tasks(input))
+    }
+
+    /// <p>Describes one or more of your VPN customer gateways.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_customer_gateways`.
+    pub fn describe_customer_gateways(
+        &self,
+        input: DescribeCustomerGatewaysRequest,
+    ) -> Result<DescribeCustomerGatewaysResult, RusotoError<DescribeCustomerGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_customer_gateways(input))
+    }
+
+    /// <p>Describes one or more of your DHCP options sets.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_DHCP_Options.html">DHCP Options Sets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_dhcp_options`.
+    pub fn describe_dhcp_options(
+        &self,
+        input: DescribeDhcpOptionsRequest,
+    ) -> Result<DescribeDhcpOptionsResult, RusotoError<DescribeDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_dhcp_options(input))
+    }
+
+    /// <p>Describes one or more of your egress-only internet gateways.</p>
+    ///
+    /// See `Ec2::describe_egress_only_internet_gateways`.
+    pub fn describe_egress_only_internet_gateways(
+        &self,
+        input: DescribeEgressOnlyInternetGatewaysRequest,
+    ) -> Result<
+        DescribeEgressOnlyInternetGatewaysResult,
+        RusotoError<DescribeEgressOnlyInternetGatewaysError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_egress_only_internet_gateways(input))
+    }
+
+    /// <p>Describes the Elastic Graphics accelerator associated with your instances. For more information about Elastic Graphics, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/elastic-graphics.html">Amazon Elastic Graphics</a>.</p>
+    ///
+    /// See `Ec2::describe_elastic_gpus`.
+    pub fn describe_elastic_gpus(
+        &self,
+        input: DescribeElasticGpusRequest,
+    ) -> Result<DescribeElasticGpusResult, RusotoError<DescribeElasticGpusError>> {
+        self.runtime
+            .block_on(self.client.describe_elastic_gpus(input))
+    }
+
+    /// <p>Describes the specified export image tasks or all of your export image tasks.</p>
+    ///
+    /// See `Ec2::describe_export_image_tasks`.
+    pub fn describe_export_image_tasks(
+        &self,
+        input: DescribeExportImageTasksRequest,
+    ) -> Result<DescribeExportImageTasksResult, RusotoError<DescribeExportImageTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_image_tasks(input))
+    }
+
+    /// <p>Describes the specified export instance tasks or all of your export instance tasks.</p>
+    ///
+    /// See `Ec2::describe_export_tasks`.
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResult, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// <p>Describes the state of fast snapshot restores for your snapshots.</p>
+    ///
+    /// See `Ec2::describe_fast_snapshot_restores`.
+    pub fn describe_fast_snapshot_restores(
+        &self,
+        input: DescribeFastSnapshotRestoresRequest,
+    ) -> Result<DescribeFastSnapshotRestoresResult, RusotoError<DescribeFastSnapshotRestoresError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_fast_snapshot_restores(input))
+    }
+
+    /// <p>Describes the events for the specified EC2 Fleet during the specified time.</p> <p>EC2 Fleet events are delayed by up to 30 seconds before they can be described. This ensures that you can query by the last evaluated time and not miss a recorded event. EC2 Fleet events are available for 48 hours.</p>
+    ///
+    /// See `Ec2::describe_fleet_history`.
+    pub fn describe_fleet_history(
+        &self,
+        input: DescribeFleetHistoryRequest,
+    ) -> Result<DescribeFleetHistoryResult, RusotoError<DescribeFleetHistoryError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_history(input))
+    }
+
+    /// <p>Describes the running instances for the specified EC2 Fleet.</p>
+    ///
+    /// See `Ec2::describe_fleet_instances`.
+    pub fn describe_fleet_instances(
+        &self,
+        input: DescribeFleetInstancesRequest,
+    ) -> Result<DescribeFleetInstancesResult, RusotoError<DescribeFleetInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_instances(input))
+    }
+
+    /// <p>Describes the specified EC2 Fleets or all of your EC2 Fleets.</p>
+    ///
+    /// See `Ec2::describe_fleets`.
+    pub fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
+        self.runtime.block_on(self.client.describe_fleets(input))
+    }
+
+    /// <p>Describes one or more flow logs. To view the information in your flow logs (the log streams for the network interfaces), you must use the CloudWatch Logs console or the CloudWatch Logs API.</p>
+    ///
+    /// See `Ec2::describe_flow_logs`.
+    pub fn describe_flow_logs(
+        &self,
+        input: DescribeFlowLogsRequest,
+    ) -> Result<DescribeFlowLogsResult, RusotoError<DescribeFlowLogsError>> {
+        self.runtime.block_on(self.client.describe_flow_logs(input))
+    }
+
+    /// <p>Describes the specified attribute of the specified Amazon FPGA Image (AFI).</p>
+    ///
+    /// See `Ec2::describe_fpga_image_attribute`.
+    pub fn describe_fpga_image_attribute(
+        &self,
+        input: DescribeFpgaImageAttributeRequest,
+    ) -> Result<DescribeFpgaImageAttributeResult, RusotoError<DescribeFpgaImageAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_fpga_image_attribute(input))
+    }
+
+    /// <p>Describes the Amazon FPGA Images (AFIs) available to you. These include public AFIs, private AFIs that you own, and AFIs owned by other AWS accounts for which you have load permissions.</p>
+    ///
+    /// See `Ec2::describe_fpga_images`.
+    pub fn describe_fpga_images(
+        &self,
+        input: DescribeFpgaImagesRequest,
+    ) -> Result<DescribeFpgaImagesResult, RusotoError<DescribeFpgaImagesError>> {
+        self.runtime
+            .block_on(self.client.describe_fpga_images(input))
+    }
+
+    /// <p>Describes the Dedicated Host reservations that are available to purchase.</p> <p>The results describe all of the Dedicated Host reservation offerings, including offerings that might not match the instance family and Region of your Dedicated Hosts. When purchasing an offering, ensure that the instance family and Region of the offering matches that of the Dedicated Hosts with which it is to be associated. For more information about supported instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/dedicated-hosts-overview.html">Dedicated Hosts Overview</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>. </p>
+    ///
+    /// See `Ec2::describe_host_reservation_offerings`.
+    pub fn describe_host_reservation_offerings(
+        &self,
+        input: DescribeHostReservationOfferingsRequest,
+    ) -> Result<
+        DescribeHostReservationOfferingsResult,
+        RusotoError<DescribeHostReservationOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_host_reservation_offerings(input))
+    }
+
+    /// <p>Describes reservations that are associated with Dedicated Hosts in your account.</p>
+    ///
+    /// See `Ec2::describe_host_reservations`.
+    pub fn describe_host_reservations(
+        &self,
+        input: DescribeHostReservationsRequest,
+    ) -> Result<DescribeHostReservationsResult, RusotoError<DescribeHostReservationsError>> {
+        self.runtime
+            .block_on(self.client.describe_host_reservations(input))
+    }
+
+    /// <p>Describes the specified Dedicated Hosts or all your Dedicated Hosts.</p> <p>The results describe only the Dedicated Hosts in the Region you're currently using. All listed instances consume capacity on your Dedicated Host. Dedicated Hosts that have recently been released are listed with the state <code>released</code>.</p>
+    ///
+    /// See `Ec2::describe_hosts`.
+    pub fn describe_hosts(
+        &self,
+        input: DescribeHostsRequest,
+    ) -> Result<DescribeHostsResult, RusotoError<DescribeHostsError>> {
+        self.runtime.block_on(self.client.describe_hosts(input))
+    }
+
+    /// <p>Describes your IAM instance profile associations.</p>
+    ///
+    /// See `Ec2::describe_iam_instance_profile_associations`.
+    pub fn describe_iam_instance_profile_associations(
+        &self,
+        input: DescribeIamInstanceProfileAssociationsRequest,
+    ) -> Result<
+        DescribeIamInstanceProfileAssociationsResult,
+        RusotoError<DescribeIamInstanceProfileAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_iam_instance_profile_associations(input),
+        )
+    }
+
+    /// <p>Describes the ID format settings for your resources on a per-Region basis, for example, to view which resource types are enabled for longer IDs. This request only returns information about resource types whose ID formats can be modified; it does not return information about other resource types.</p> <p>The following resource types support longer IDs: <code>bundle</code> | <code>conversion-task</code> | <code>customer-gateway</code> | <code>dhcp-options</code> | <code>elastic-ip-allocation</code> | <code>elastic-ip-association</code> | <code>export-task</code> | <code>flow-log</code> | <code>image</code> | <code>import-task</code> | <code>instance</code> | <code>internet-gateway</code> | <code>network-acl</code> | <code>network-acl-association</code> | <code>network-interface</code> | <code>network-interface-attachment</code> | <code>prefix-list</code> | <code>reservation</code> | <code>route-table</code> | <code>route-table-association</code> | <code>security-group</code> | <code>snapshot</code> | <code>subnet</code> | <code>subnet-cidr-block-association</code> | <code>volume</code> | <code>vpc</code> | <code>vpc-cidr-block-association</code> | <code>vpc-endpoint</code> | <code>vpc-peering-connection</code> | <code>vpn-connection</code> | <code>vpn-gateway</code>. </p> <p>These settings apply to the IAM user who makes the request; they do not apply to the entire AWS account. By default, an IAM user defaults to the same settings as the root user, unless they explicitly override the settings by running the <a>ModifyIdFormat</a> command. Resources created with longer IDs are visible to all IAM users, regardless of these settings and provided that they have permission to use the relevant <code>Describe</code> command for the resource type.</p>
+    ///
+    /// See `Ec2::describe_id_format`.
+    pub fn describe_id_format(
+        &self,
+        input: DescribeIdFormatRequest,
+    ) -> Result<DescribeIdFormatResult, RusotoError<DescribeIdFormatError>> {
+        self.runtime.block_on(self.client.describe_id_format(input))
+    }
+
+    /// <p>Describes the ID format settings for resources for the specified IAM user, IAM role, or root user. For example, you can view the resource types that are enabled for longer IDs. This request only returns information about resource types whose ID formats can be modified; it does not return information about other resource types. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/resource-ids.html">Resource IDs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>. </p> <p>The following resource types support longer IDs: <code>bundle</code> | <code>conversion-task</code> | <code>customer-gateway</code> | <code>dhcp-options</code> | <code>elastic-ip-allocation</code> | <code>elastic-ip-association</code> | <code>export-task</code> | <code>flow-log</code> | <code>image</code> | <code>import-task</code> | <code>instance</code> | <code>internet-gateway</code> | <code>network-acl</code> | <code>network-acl-association</code> | <code>network-interface</code> | <code>network-interface-attachment</code> | <code>prefix-list</code> | <code>reservation</code> | <code>route-table</code> | <code>route-table-association</code> | <code>security-group</code> | <code>snapshot</code> | <code>subnet</code> | <code>subnet-cidr-block-association</code> | <code>volume</code> | <code>vpc</code> | <code>vpc-cidr-block-association</code> | <code>vpc-endpoint</code> | <code>vpc-peering-connection</code> | <code>vpn-connection</code> | <code>vpn-gateway</code>. </p> <p>These settings apply to the principal specified in the request. They do not apply to the principal that makes the request.</p>
+    ///
+    /// See `Ec2::describe_identity_id_format`.
+    pub fn describe_identity_id_format(
+        &self,
+        input: DescribeIdentityIdFormatRequest,
+    ) -> Result<DescribeIdentityIdFormatResult, RusotoError<DescribeIdentityIdFormatError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_id_format(input))
+    }
+
+    /// <p>Describes the specified attribute of the specified AMI. You can specify only one attribute at a time.</p>
+    ///
+    /// See `Ec2::describe_image_attribute`.
+    pub fn describe_image_attribute(
+        &self,
+        input: DescribeImageAttributeRequest,
+    ) -> Result<ImageAttribute, RusotoError<DescribeImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_image_attribute(input))
+    }
+
+    /// <p>Describes the specified images (AMIs, AKIs, and ARIs) available to you or all of the images available to you.</p> <p>The images available to you include public images, private images that you own, and private images owned by other AWS accounts for which you have explicit launch permissions.</p> <p>Recently deregistered images appear in the returned results for a short interval and then return empty results. After all instances that reference a deregistered AMI are terminated, specifying the ID of the image results in an error indicating that the AMI ID cannot be found.</p>
+    ///
+    /// See `Ec2::describe_images`.
+    pub fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
+        self.runtime.block_on(self.client.describe_images(input))
+    }
+
+    /// <p>Displays details about an import virtual machine or import snapshot tasks that are already created.</p>
+    ///
+    /// See `Ec2::describe_import_image_tasks`.
+    pub fn describe_import_image_tasks(
+        &self,
+        input: DescribeImportImageTasksRequest,
+    ) -> Result<DescribeImportImageTasksResult, RusotoError<DescribeImportImageTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_import_image_tasks(input))
+    }
+
+    /// <p>Describes your import snapshot tasks.</p>
+    ///
+    /// See `Ec2::describe_import_snapshot_tasks`.
+    pub fn describe_import_snapshot_tasks(
+        &self,
+        input: DescribeImportSnapshotTasksRequest,
+    ) -> Result<DescribeImportSnapshotTasksResult, RusotoError<DescribeImportSnapshotTasksError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_import_snapshot_tasks(input))
+    }
+
+    /// <p>Describes the specified attribute of the specified instance. You can specify only one attribute at a time. Valid attribute values are: <code>instanceType</code> | <code>kernel</code> | <code>ramdisk</code> | <code>userData</code> | <code>disableApiTermination</code> | <code>instanceInitiatedShutdownBehavior</code> | <code>rootDeviceName</code> | <code>blockDeviceMapping</code> | <code>productCodes</code> | <code>sourceDestCheck</code> | <code>groupSet</code> | <code>ebsOptimized</code> | <code>sriovNetSupport</code> </p>
+    ///
+    /// See `Ec2::describe_instance_attribute`.
+    pub fn describe_instance_attribute(
+        &self,
+        input: DescribeInstanceAttributeRequest,
+    ) -> Result<InstanceAttribute, RusotoError<DescribeInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_attribute(input))
+    }
+
+    /// <p>Describes the credit option for CPU usage of the specified burstable performance instances. The credit options are <code>standard</code> and <code>unlimited</code>.</p> <p>If you do not specify an instance ID, Amazon EC2 returns burstable performance instances with the <code>unlimited</code> credit option, as well as instances that were previously configured as T2, T3, and T3a with the <code>unlimited</code> credit option. For example, if you resize a T2 instance, while it is configured as <code>unlimited</code>, to an M4 instance, Amazon EC2 returns the M4 instance.</p> <p>If you specify one or more instance IDs, Amazon EC2 returns the credit option (<code>standard</code> or <code>unlimited</code>) of those instances. If you specify an instance ID that is not valid, such as an instance that is not a burstable performance instance, an error is returned.</p> <p>Recently terminated instances might appear in the returned results. This interval is usually less than one hour.</p> <p>If an Availability Zone is experiencing a service disruption and you specify instance IDs in the affected zone, or do not specify any instance IDs at all, the call fails. If you specify only instance IDs in an unaffected zone, the call works normally.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/burstable-performance-instances.html">Burstable performance instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_instance_credit_specifications`.
+    pub fn describe_instance_credit_specifications(
+        &self,
+        input: DescribeInstanceCreditSpecificationsRequest,
+    ) -> Result<
+        DescribeInstanceCreditSpecificationsResult,
+        RusotoError<DescribeInstanceCreditSpecificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_instance_credit_specifications(input))
+    }
+
+    /// <p>Describes the tag keys that are registered to appear in scheduled event notifications for resources in the current Region.</p>
+    ///
+    /// See `Ec2::describe_instance_event_notification_attributes`.
+    pub fn describe_instance_event_notification_attributes(
+        &self,
+        input: DescribeInstanceEventNotificationAttributesRequest,
+    ) -> Result<
+        DescribeInstanceEventNotificationAttributesResult,
+        RusotoError<DescribeInstanceEventNotificationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_instance_event_notification_attributes(input),
+        )
+    }
+
+    /// <p><p>Describes the status of the specified instances or all of your instances. By default, only running instances are described, unless you specifically indicate to return the status of all instances.</p> <p>Instance status includes the following components:</p> <ul> <li> <p> <b>Status checks</b> - Amazon EC2 performs status checks on running EC2 instances to identify hardware and software issues. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/monitoring-system-instance-status-check.html">Status checks for your instances</a> and <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/TroubleshootingInstances.html">Troubleshooting instances with failed status checks</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> </li> <li> <p> <b>Scheduled events</b> - Amazon EC2 can schedule events (such as reboot, stop, or terminate) for your instances related to hardware issues, software updates, or system maintenance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/monitoring-instances-status-check_sched.html">Scheduled events for your instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> </li> <li> <p> <b>Instance state</b> - You can manage your instances from the moment you launch them through their termination. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-instance-lifecycle.html">Instance lifecycle</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> </li> </ul></p>
+    ///
+    /// See `Ec2::describe_instance_status`.
+    pub fn describe_instance_status(
+        &self,
+        input: DescribeInstanceStatusRequest,
+    ) -> Result<DescribeInstanceStatusResult, RusotoError<DescribeInstanceStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_status(input))
+    }
+
+    /// <p>Returns a list of all instance types offered. The results can be filtered by location (Region or Availability Zone). If no location is specified, the instance types offered in the current Region are returned.</p>
+    ///
+    /// See `Ec2::describe_instance_type_offerings`.
+    pub fn describe_instance_type_offerings(
+        &self,
+        input: DescribeInstanceTypeOfferingsRequest,
+    ) -> Result<DescribeInstanceTypeOfferingsResult, RusotoError<DescribeInstanceTypeOfferingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_type_offerings(input))
+    }
+
+    /// <p>Describes the details of the instance types that are offered in a location. The results can be filtered by the attributes of the instance types.</p>
+    ///
+    /// See `Ec2::describe_instance_types`.
+    pub fn describe_instance_types(
+        &self,
+        input: DescribeInstanceTypesRequest,
+    ) -> Result<DescribeInstanceTypesResult, RusotoError<DescribeInstanceTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_types(input))
+    }
+
+    /// <p>Describes the specified instances or all instances.</p> <p>If you specify instance IDs, the output includes information for only the specified instances. If you specify filters, the output includes information for only those instances that meet the filter criteria. If you do not specify instance IDs or filters, the output includes information for all instances, which can affect performance. We recommend that you use pagination to ensure that the operation returns quickly and successfully.</p> <p>If you specify an instance ID that is not valid, an error is returned. If you specify an instance that you do not own, it is not included in the output.</p> <p>Recently terminated instances might appear in the returned results. This interval is usually less than one hour.</p> <p>If you describe instances in the rare case where an Availability Zone is experiencing a service disruption and you specify instance IDs that are in the affected zone, or do not specify any instance IDs at all, the call fails. If you describe instances and specify only instance IDs that are in an unaffected zone, the call works normally.</p>
+    ///
+    /// See `Ec2::describe_instances`.
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
+        self.runtime.block_on(self.client.describe_instances(input))
+    }
+
+    /// <p>Describes one or more of your internet gateways.</p>
+    ///
+    /// See `Ec2::describe_internet_gateways`.
+    pub fn describe_internet_gateways(
+        &self,
+        input: DescribeInternetGatewaysRequest,
+    ) -> Result<DescribeInternetGatewaysResult, RusotoError<DescribeInternetGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_internet_gateways(input))
+    }
+
+    /// <p>Describes your IPv6 address pools.</p>
+    ///
+    /// See `Ec2::describe_ipv_6_pools`.
+    pub fn describe_ipv_6_pools(
+        &self,
+        input: DescribeIpv6PoolsRequest,
+    ) -> Result<DescribeIpv6PoolsResult, RusotoError<DescribeIpv6PoolsError>> {
+        self.runtime
+            .block_on(self.client.describe_ipv_6_pools(input))
+    }
+
+    /// <p>Describes the specified key pairs or all of your key pairs.</p> <p>For more information about key pairs, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-key-pairs.html">Key Pairs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_key_pairs`.
+    pub fn describe_key_pairs(
+        &self,
+        input: DescribeKeyPairsRequest,
+    ) -> Result<DescribeKeyPairsResult, RusotoError<DescribeKeyPairsError>> {
+        self.runtime.block_on(self.client.describe_key_pairs(input))
+    }
+
+    /// <p>Describes one or more versions of a specified launch template. You can describe all versions, individual versions, or a range of versions.</p>
+    ///
+    /// See `Ec2::describe_launch_template_versions`.
+    pub fn describe_launch_template_versions(
+        &self,
+        input: DescribeLaunchTemplateVersionsRequest,
+    ) -> Result<
+        DescribeLaunchTemplateVersionsResult,
+        RusotoError<DescribeLaunchTemplateVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_launch_template_versions(input))
+    }
+
+    /// <p>Describes one or more launch templates.</p>
+    ///
+    /// See `Ec2::describe_launch_templates`.
+    pub fn describe_launch_templates(
+        &self,
+        input: DescribeLaunchTemplatesRequest,
+    ) -> Result<DescribeLaunchTemplatesResult, RusotoError<DescribeLaunchTemplatesError>> {
+        self.runtime
+            .block_on(self.client.describe_launch_templates(input))
+    }
+
+    /// <p>Describes the associations between virtual interface groups and local gateway route tables.</p>
+    ///
+    /// See `Ec2::describe_local_gateway_route_table_virtual_interface_group_associations`.
+    pub fn describe_local_gateway_route_table_virtual_interface_group_associations(
+        &self,
+        input: DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsRequest,
+    ) -> Result<
+        DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsResult,
+        RusotoError<DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_local_gateway_route_table_virtual_interface_group_associations(input),
+        )
+    }
+
+    /// <p>Describes the specified associations between VPCs and local gateway route tables.</p>
+    ///
+    /// See `Ec2::describe_local_gateway_route_table_vpc_associations`.
+    pub fn describe_local_gateway_route_table_vpc_associations(
+        &self,
+        input: DescribeLocalGatewayRouteTableVpcAssociationsRequest,
+    ) -> Result<
+        DescribeLocalGatewayRouteTableVpcAssociationsResult,
+        RusotoError<DescribeLocalGatewayRouteTableVpcAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_local_gateway_route_table_vpc_associations(input),
+        )
+    }
+
+    /// <p>Describes one or more local gateway route tables. By default, all local gateway route tables are described. Alternatively, you can filter the results.</p>
+    ///
+    /// See `Ec2::describe_local_gateway_route_tables`.
+    pub fn describe_local_gateway_route_tables(
+        &self,
+        input: DescribeLocalGatewayRouteTablesRequest,
+    ) -> Result<
+        DescribeLocalGatewayRouteTablesResult,
+        RusotoError<DescribeLocalGatewayRouteTablesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_local_gateway_route_tables(input))
+    }
+
+    /// <p>Describes the specified local gateway virtual interface groups.</p>
+    ///
+    /// See `Ec2::describe_local_gateway_virtual_interface_groups`.
+    pub fn describe_local_gateway_virtual_interface_groups(
+        &self,
+        input: DescribeLocalGatewayVirtualInterfaceGroupsRequest,
+    ) -> Result<
+        DescribeLocalGatewayVirtualInterfaceGroupsResult,
+        RusotoError<DescribeLocalGatewayVirtualInterfaceGroupsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_local_gateway_virtual_interface_groups(input),
+        )
+    }
+
+    /// <p>Describes the specified local gateway virtual interfaces.</p>
+    ///
+    /// See `Ec2::describe_local_gateway_virtual_interfaces`.
+    pub fn describe_local_gateway_virtual_interfaces(
+        &self,
+        input: DescribeLocalGatewayVirtualInterfacesRequest,
+    ) -> Result<
+        DescribeLocalGatewayVirtualInterfacesResult,
+        RusotoError<DescribeLocalGatewayVirtualInterfacesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_local_gateway_virtual_interfaces(input))
+    }
+
+    /// <p>Describes one or more local gateways. By default, all local gateways are described. Alternatively, you can filter the results.</p>
+    ///
+    /// See `Ec2::describe_local_gateways`.
+    pub fn describe_local_gateways(
+        &self,
+        input: DescribeLocalGatewaysRequest,
+    ) -> Result<DescribeLocalGatewaysResult, RusotoError<DescribeLocalGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_local_gateways(input))
+    }
+
+    /// <p>Describes your managed prefix lists and any AWS-managed prefix lists.</p> <p>To view the entries for your prefix list, use <a>GetManagedPrefixListEntries</a>.</p>
+    ///
+    /// See `Ec2::describe_managed_prefix_lists`.
+    pub fn describe_managed_prefix_lists(
+        &self,
+        input: DescribeManagedPrefixListsRequest,
+    ) -> Result<DescribeManagedPrefixListsResult, RusotoError<DescribeManagedPrefixListsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_managed_prefix_lists(input))
+    }
+
+    /// <p>Describes your Elastic IP addresses that are being moved to the EC2-VPC platform, or that are being restored to the EC2-Classic platform. This request does not return information about any other Elastic IP addresses in your account.</p>
+    ///
+    /// See `Ec2::describe_moving_addresses`.
+    pub fn describe_moving_addresses(
+        &self,
+        input: DescribeMovingAddressesRequest,
+    ) -> Result<DescribeMovingAddressesResult, RusotoError<DescribeMovingAddressesError>> {
+        self.runtime
+            .block_on(self.client.describe_moving_addresses(input))
+    }
+
+    /// <p>Describes one or more of your NAT gateways.</p>
+    ///
+    /// See `Ec2::describe_nat_gateways`.
+    pub fn describe_nat_gateways(
+        &self,
+        input: DescribeNatGatewaysRequest,
+    ) -> Result<DescribeNatGatewaysResult, RusotoError<DescribeNatGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_nat_gateways(input))
+    }
+
+    /// <p>Describes one or more of your network ACLs.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_ACLs.html">Network ACLs</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_network_acls`.
+    pub fn describe_network_acls(
+        &self,
+        input: DescribeNetworkAclsRequest,
+    ) -> Result<DescribeNetworkAclsResult, RusotoError<DescribeNetworkAclsError>> {
+        self.runtime
+            .block_on(self.client.describe_network_acls(input))
+    }
+
+    /// <p>Describes a network interface attribute. You can specify only one attribute at a time.</p>
+    ///
+    /// See `Ec2::describe_network_interface_attribute`.
+    pub fn describe_network_interface_attribute(
+        &self,
+        input: DescribeNetworkInterfaceAttributeRequest,
+    ) -> Result<
+        DescribeNetworkInterfaceAttributeResult,
+        RusotoError<DescribeNetworkInterfaceAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_network_interface_attribute(input))
+    }
+
+    /// <p>Describes the permissions for your network interfaces. </p>
+    ///
+    /// See `Ec2::describe_network_interface_permissions`.
+    pub fn describe_network_interface_permissions(
+        &self,
+        input: DescribeNetworkInterfacePermissionsRequest,
+    ) -> Result<
+        DescribeNetworkInterfacePermissionsResult,
+        RusotoError<DescribeNetworkInterfacePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_network_interface_permissions(input))
+    }
+
+    /// <p>Describes one or more of your network interfaces.</p>
+    ///
+    /// See `Ec2::describe_network_interfaces`.
+    pub fn describe_network_interfaces(
+        &self,
+        input: DescribeNetworkInterfacesRequest,
+    ) -> Result<DescribeNetworkInterfacesResult, RusotoError<DescribeNetworkInterfacesError>> {
+        self.runtime
+            .block_on(self.client.describe_network_interfaces(input))
+    }
+
+    /// <p>Describes the specified placement groups or all of your placement groups. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/placement-groups.html">Placement groups</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_placement_groups`.
+    pub fn describe_placement_groups(
+        &self,
+        input: DescribePlacementGroupsRequest,
+    ) -> Result<DescribePlacementGroupsResult, RusotoError<DescribePlacementGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_placement_groups(input))
+    }
+
+    /// <p>Describes available AWS services in a prefix list format, which includes the prefix list name and prefix list ID of the service and the IP address range for the service.</p> <p>We recommend that you use <a>DescribeManagedPrefixLists</a> instead.</p>
+    ///
+    /// See `Ec2::describe_prefix_lists`.
+    pub fn describe_prefix_lists(
+        &self,
+        input: DescribePrefixListsRequest,
+    ) -> Result<DescribePrefixListsResult, RusotoError<DescribePrefixListsError>> {
+        self.runtime
+            .block_on(self.client.describe_prefix_lists(input))
+    }
+
+    /// <p>Describes the ID format settings for the root user and all IAM roles and IAM users that have explicitly specified a longer ID (17-character ID) preference. </p> <p>By default, all IAM roles and IAM users default to the same ID settings as the root user, unless they explicitly override the settings. This request is useful for identifying those IAM users and IAM roles that have overridden the default ID settings.</p> <p>The following resource types support longer IDs: <code>bundle</code> | <code>conversion-task</code> | <code>customer-gateway</code> | <code>dhcp-options</code> | <code>elastic-ip-allocation</code> | <code>elastic-ip-association</code> | <code>export-task</code> | <code>flow-log</code> | <code>image</code> | <code>import-task</code> | <code>instance</code> | <code>internet-gateway</code> | <code>network-acl</code> | <code>network-acl-association</code> | <code>network-interface</code> | <code>network-interface-attachment</code> | <code>prefix-list</code> | <code>reservation</code> | <code>route-table</code> | <code>route-table-association</code> | <code>security-group</code> | <code>snapshot</code> | <code>subnet</code> | <code>subnet-cidr-block-association</code> | <code>volume</code> | <code>vpc</code> | <code>vpc-cidr-block-association</code> | <code>vpc-endpoint</code> | <code>vpc-peering-connection</code> | <code>vpn-connection</code> | <code>vpn-gateway</code>. </p>
+    ///
+    /// See `Ec2::describe_principal_id_format`.
+    pub fn describe_principal_id_format(
+        &self,
+        input: DescribePrincipalIdFormatRequest,
+    ) -> Result<DescribePrincipalIdFormatResult, RusotoError<DescribePrincipalIdFormatError>> {
+        self.runtime
+            .block_on(self.client.describe_principal_id_format(input))
+    }
+
+    /// <p>Describes the specified IPv4 address pools.</p>
+    ///
+    /// See `Ec2::describe_public_ipv_4_pools`.
+    pub fn describe_public_ipv_4_pools(
+        &self,
+        input: DescribePublicIpv4PoolsRequest,
+    ) -> Result<DescribePublicIpv4PoolsResult, RusotoError<DescribePublicIpv4PoolsError>> {
+        self.runtime
+            .block_on(self.client.describe_public_ipv_4_pools(input))
+    }
+
+    /// <p>Describes the Regions that are enabled for your account, or all Regions.</p> <p>For a list of the Regions supported by Amazon EC2, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html#ec2_region"> Regions and Endpoints</a>.</p> <p>For information about enabling and disabling Regions for your account, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande-manage.html">Managing AWS Regions</a> in the <i>AWS General Reference</i>.</p>
+    ///
+    /// See `Ec2::describe_regions`.
+    pub fn describe_regions(
+        &self,
+        input: DescribeRegionsRequest,
+    ) -> Result<DescribeRegionsResult, RusotoError<DescribeRegionsError>> {
+        self.runtime.block_on(self.client.describe_regions(input))
+    }
+
+    /// <p>Describes one or more of the Reserved Instances that you purchased.</p> <p>For more information about Reserved Instances, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/concepts-on-demand-reserved-instances.html">Reserved Instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_reserved_instances`.
+    pub fn describe_reserved_instances(
+        &self,
+        input: DescribeReservedInstancesRequest,
+    ) -> Result<DescribeReservedInstancesResult, RusotoError<DescribeReservedInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances(input))
+    }
+
+    /// <p>Describes your account's Reserved Instance listings in the Reserved Instance Marketplace.</p> <p>The Reserved Instance Marketplace matches sellers who want to resell Reserved Instance capacity that they no longer need with buyers who want to purchase additional capacity. Reserved Instances bought and sold through the Reserved Instance Marketplace work like any other Reserved Instances.</p> <p>As a seller, you choose to list some or all of your Reserved Instances, and you specify the upfront price to receive for them. Your Reserved Instances are then listed in the Reserved Instance Marketplace and are available for purchase.</p> <p>As a buyer, you specify the configuration of the Reserved Instance to purchase, and the Marketplace matches what you're searching for with what's available. The Marketplace first sells the lowest priced Reserved Instances to you, and continues to sell available Reserved Instance listings to you until your demand is met. You are charged based on the total price of all of the listings that you purchase.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-market-general.html">Reserved Instance Marketplace</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_reserved_instances_listings`.
+    pub fn describe_reserved_instances_listings(
+        &self,
+        input: DescribeReservedInstancesListingsRequest,
+    ) -> Result<
+        DescribeReservedInstancesListingsResult,
+        RusotoError<DescribeReservedInstancesListingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances_listings(input))
+    }
+
+    /// <p>Describes the modifications made to your Reserved Instances. If no parameter is specified, information about all your Reserved Instances modification requests is returned. If a modification ID is specified, only information about the specific modification is returned.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-modifying.html">Modifying Reserved Instances</a> in the Amazon Elastic Compute Cloud User Guide.</p>
+    ///
+    /// See `Ec2::describe_reserved_instances_modifications`.
+    pub fn describe_reserved_instances_modifications(
+        &self,
+        input: DescribeReservedInstancesModificationsRequest,
+    ) -> Result<
+        DescribeReservedInstancesModificationsResult,
+        RusotoError<DescribeReservedInstancesModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances_modifications(input))
+    }
+
+    /// <p>Describes Reserved Instance offerings that are available for purchase. With Reserved Instances, you purchase the right to launch instances for a period of time. During that time period, you do not receive insufficient capacity errors, and you pay a lower usage rate than the rate charged for On-Demand instances for the actual time used.</p> <p>If you have listed your own Reserved Instances for sale in the Reserved Instance Marketplace, they will be excluded from these results. This is to ensure that you do not purchase your own Reserved Instances.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-market-general.html">Reserved Instance Marketplace</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_reserved_instances_offerings`.
+    pub fn describe_reserved_instances_offerings(
+        &self,
+        input: DescribeReservedInstancesOfferingsRequest,
+    ) -> Result<
+        DescribeReservedInstancesOfferingsResult,
+        RusotoError<DescribeReservedInstancesOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances_offerings(input))
+    }
+
+    /// <p>Describes one or more of your route tables.</p> <p>Each subnet in your VPC must be associated with a route table. If a subnet is not explicitly associated with any route table, it is implicitly associated with the main route table. This command does not return the subnet ID for implicit associations.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_route_tables`.
+    pub fn describe_route_tables(
+        &self,
+        input: DescribeRouteTablesRequest,
+    ) -> Result<DescribeRouteTablesResult, RusotoError<DescribeRouteTablesError>> {
+        self.runtime
+            .block_on(self.client.describe_route_tables(input))
+    }
+
+    /// <p>Finds available schedules that meet the specified criteria.</p> <p>You can search for an available schedule no more than 3 months in advance. You must meet the minimum required duration of 1,200 hours per year. For example, the minimum daily schedule is 4 hours, the minimum weekly schedule is 24 hours, and the minimum monthly schedule is 100 hours.</p> <p>After you find a schedule that meets your needs, call <a>PurchaseScheduledInstances</a> to purchase Scheduled Instances with that schedule.</p>
+    ///
+    /// See `Ec2::describe_scheduled_instance_availability`.
+    pub fn describe_scheduled_instance_availability(
+        &self,
+        input: DescribeScheduledInstanceAvailabilityRequest,
+    ) -> Result<
+        DescribeScheduledInstanceAvailabilityResult,
+        RusotoError<DescribeScheduledInstanceAvailabilityError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_scheduled_instance_availability(input))
+    }
+
+    /// <p>Describes the specified Scheduled Instances or all your Scheduled Instances.</p>
+    ///
+    /// See `Ec2::describe_scheduled_instances`.
+    pub fn describe_scheduled_instances(
+        &self,
+        input: DescribeScheduledInstancesRequest,
+    ) -> Result<DescribeScheduledInstancesResult, RusotoError<DescribeScheduledInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scheduled_instances(input))
+    }
+
+    /// <p>[VPC only] Describes the VPCs on the other side of a VPC peering connection that are referencing the security groups you've specified in this request.</p>
+    ///
+    /// See `Ec2::describe_security_group_references`.
+    pub fn describe_security_group_references(
+        &self,
+        input: DescribeSecurityGroupReferencesRequest,
+    ) -> Result<
+        DescribeSecurityGroupReferencesResult,
+        RusotoError<DescribeSecurityGroupReferencesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_security_group_references(input))
+    }
+
+    /// <p>Describes the specified security groups or all of your security groups.</p> <p>A security group is for use with instances either in the EC2-Classic platform or in a specific VPC. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-network-security.html">Amazon EC2 Security Groups</a> in the <i>Amazon Elastic Compute Cloud User Guide</i> and <a href="https://docs.aws.amazon.com/AmazonVPC/latest/UserGuide/VPC_SecurityGroups.html">Security Groups for Your VPC</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_security_groups`.
+    pub fn describe_security_groups(
+        &self,
+        input: DescribeSecurityGroupsRequest,
+    ) -> Result<DescribeSecurityGroupsResult, RusotoError<DescribeSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_security_groups(input))
+    }
+
+    /// <p>Describes the specified attribute of the specified snapshot. You can specify only one attribute at a time.</p> <p>For more information about EBS snapshots, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSSnapshots.html">Amazon EBS Snapshots</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_snapshot_attribute`.
+    pub fn describe_snapshot_attribute(
+        &self,
+        input: DescribeSnapshotAttributeRequest,
+    ) -> Result<DescribeSnapshotAttributeResult, RusotoError<DescribeSnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_snapshot_attribute(input))
+    }
+
+    /// <p>Describes the specified EBS snapshots available to you or all of the EBS snapshots available to you.</p> <p>The snapshots available to you include public snapshots, private snapshots that you own, and private snapshots owned by other AWS accounts for which you have explicit create volume permissions.</p> <p>The create volume permissions fall into the following categories:</p> <ul> <li> <p> <i>public</i>: The owner of the snapshot granted create volume permissions for the snapshot to the <code>all</code> group. All AWS accounts have create volume permissions for these snapshots.</p> </li> <li> <p> <i>explicit</i>: The owner of the snapshot granted create volume permissions to a specific AWS account.</p> </li> <li> <p> <i>implicit</i>: An AWS account has implicit create volume permissions for all snapshots it owns.</p> </li> </ul> <p>The list of snapshots returned can be filtered by specifying snapshot IDs, snapshot owners, or AWS accounts with create volume permissions. If no options are specified, Amazon EC2 returns all snapshots for which you have create volume permissions.</p> <p>If you specify one or more snapshot IDs, only snapshots that have the specified IDs are returned. If you specify an invalid snapshot ID, an error is returned. If you specify a snapshot ID for which you do not have access, it is not included in the returned results.</p> <p>If you specify one or more snapshot owners using the <code>OwnerIds</code> option, only snapshots from the specified owners and for which you have access are returned. The results can include the AWS account IDs of the specified owners, <code>amazon</code> for snapshots owned by Amazon, or <code>self</code> for snapshots that you own.</p> <p>If you specify a list of restorable users, only snapshots with create snapshot permissions for those users are returned. You can specify AWS account IDs (if you own the snapshots), <code>self</code> for snapshots for which you own or have explicit permissions, or <code>all</code> for public snapshots.</p> <p>If you are describing a long list of snapshots, we recommend that you paginate the output to make the list more manageable. The <code>MaxResults</code> parameter sets the maximum number of results returned in a single page. If the list of results exceeds your <code>MaxResults</code> value, then that number of results is returned along with a <code>NextToken</code> value that can be passed to a subsequent <code>DescribeSnapshots</code> request to retrieve the remaining results.</p> <p>To get the state of fast snapshot restores for a snapshot, use <a>DescribeFastSnapshotRestores</a>.</p> <p>For more information about EBS snapshots, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSSnapshots.html">Amazon EBS Snapshots</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_snapshots`.
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
+        self.runtime.block_on(self.client.describe_snapshots(input))
+    }
+
+    /// <p>Describes the data feed for Spot Instances. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/spot-data-feeds.html">Spot Instance data feed</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
+    ///
+    /// See `Ec2::describe_spot_datafeed_subscription`.
+    pub fn describe_spot_datafeed_subscription(
+        &self,
+        input: DescribeSpotDatafeedSubscriptionRequest,
+    ) -> Result<
+        DescribeSpotDatafeedSubscriptionResult,
+        RusotoError<DescribeSpotDatafeedSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_spot_datafeed_subscription(input))
+    }
+
+    /// <p>Describes the running instances for the specified Spot Fleet.</p>
+    ///
+    /// See `Ec2::describe_spot_fleet_instances`.
+    pub fn describe_spot_fleet_instances(
+        &self,
+        input: DescribeSpotFleetInstancesRequest,
+    ) -> Result<DescribeSpotFleetInstancesResponse, RusotoError<DescribeSpotFleetInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_spot_fleet_instances(input))
+    }
+
+    /// <p>Describes the events for the specified Spot Fleet request during the specified time.</p> <p>Spot Fleet events are delayed by up to 30 seconds before they can be described. This ensures that you can query by the last evaluated time and not miss a recorded event. Spot Fleet events are available for 48 hours.</p>
+    ///
+    /// See `Ec2::describe_spot_fleet_request_history`.
+    pub fn describe_spot_fleet_request_history(
+        &self,
+        input: DescribeSpotFleetRequestHistoryRequest,
+    ) -> Result<
+        DescribeSpotFleetRequestHistoryResponse,
+        RusotoError<DescribeSpotFleetRequestHistoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_spot_fleet_request_history(input))
+    }
+
+    /// <p>Describes your Spot Fleet requests.</p> <p>Spot Fleet requests are deleted 48 hours after they are canceled and their instances are terminated.</p>
+    ///
+    /// See `Ec2::describe_spot_fleet_requests`.
+    pub fn describe_spot_fleet_requests(
+        &self,
+        input: DescribeSpotFleetRequestsRequest,
+    ) -> Result<DescribeSpotFleetRequestsResponse, RusotoError<DescribeSpotFleetRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_spot_fleet_requests(input))
+    }
+
+    /// <p>Describes the specified Spot Instance requests.</p> <p>You can use <code>DescribeSpotInstanceRequests</code> to find a running Spot Instance by examining the response. If the status of the Spot Instance is <code>fulfilled</code>, the instance ID appears in the response and contains the identifier of the instance. Alternatively, you can use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeInstances">DescribeInstances</a> with a filter to look for instances where the instance lifecycle is <code>spot</code>.</p> <p>We recommend that you set <code>MaxResults</code> to a value between 5 and 1000 to limit the number of results returned. This paginates the output, which makes the list more manageable and returns the results faster. If the list of results exceeds your <code>MaxResults</code> value, then that number of results is returned along with a <code>NextToken</code> value that can be passed to a subsequent <code>DescribeSpotInstanceRequests</code> request to retrieve the remaining results.</p> <p>Spot Instance requests are deleted four hours after they are canceled and their instances are terminated.</p>
+    ///
+    /// See `Ec2::describe_spot_instance_requests`.
+    pub fn describe_spot_instance_requests(
+        &self,
+        input: DescribeSpotInstanceRequestsRequest,
+    ) -> Result<DescribeSpotInstanceRequestsResult, RusotoError<DescribeSpotInstanceRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_spot_instance_requests(input))
+    }
+
+    /// <p>Describes the Spot price history. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-spot-instances-history.html">Spot Instance pricing history</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p> <p>When you specify a start and end time, this operation returns the prices of the instance types within the time range that you specified and the time when the price changed. The price is valid within the time period that you specified; the response merely indicates the last time that the price changed.</p>
+    ///
+    /// See `Ec2::describe_spot_price_history`.
+    pub fn describe_spot_price_history(
+        &self,
+        input: DescribeSpotPriceHistoryRequest,
+    ) -> Result<DescribeSpotPriceHistoryResult, RusotoError<DescribeSpotPriceHistoryError>> {
+        self.runtime
+            .block_on(self.client.describe_spot_price_history(input))
+    }
+
+    /// <p>[VPC only] Describes the stale security group rules for security groups in a specified VPC. Rules are stale when they reference a deleted security group in a peer VPC, or a security group in a peer VPC for which the VPC peering connection has been deleted.</p>
+    ///
+    /// See `Ec2::describe_stale_security_groups`.
+    pub fn describe_stale_security_groups(
+        &self,
+        input: DescribeStaleSecurityGroupsRequest,
+    ) -> Result<DescribeStaleSecurityGroupsResult, RusotoError<DescribeStaleSecurityGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_stale_security_groups(input))
+    }
+
+    /// <p>Describes one or more of your subnets.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">Your VPC and Subnets</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_subnets`.
+    pub fn describe_subnets(
+        &self,
+        input: DescribeSubnetsRequest,
+    ) -> Result<DescribeSubnetsResult, RusotoError<DescribeSubnetsError>> {
+        self.runtime.block_on(self.client.describe_subnets(input))
+    }
+
+    /// <p>Describes the specified tags for your EC2 resources.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging Your Resources</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Describes one or more Traffic Mirror filters.</p>
+    ///
+    /// See `Ec2::describe_traffic_mirror_filters`.
+    pub fn describe_traffic_mirror_filters(
+        &self,
+        input: DescribeTrafficMirrorFiltersRequest,
+    ) -> Result<DescribeTrafficMirrorFiltersResult, RusotoError<DescribeTrafficMirrorFiltersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_traffic_mirror_filters(input))
+    }
+
+    /// <p>Describes one or more Traffic Mirror sessions. By default, all Traffic Mirror sessions are described. Alternatively, you can filter the results.</p>
+    ///
+    /// See `Ec2::describe_traffic_mirror_sessions`.
+    pub fn describe_traffic_mirror_sessions(
+        &self,
+        input: DescribeTrafficMirrorSessionsRequest,
+    ) -> Result<DescribeTrafficMirrorSessionsResult, RusotoError<DescribeTrafficMirrorSessionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_traffic_mirror_sessions(input))
+    }
+
+    /// <p>Information about one or more Traffic Mirror targets.</p>
+    ///
+    /// See `Ec2::describe_traffic_mirror_targets`.
+    pub fn describe_traffic_mirror_targets(
+        &self,
+        input: DescribeTrafficMirrorTargetsRequest,
+    ) -> Result<DescribeTrafficMirrorTargetsResult, RusotoError<DescribeTrafficMirrorTargetsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_traffic_mirror_targets(input))
+    }
+
+    /// <p>Describes one or more attachments between resources and transit gateways. By default, all attachments are described. Alternatively, you can filter the results by attachment ID, attachment state, resource ID, or resource owner.</p>
+    ///
+    /// See `Ec2::describe_transit_gateway_attachments`.
+    pub fn describe_transit_gateway_attachments(
+        &self,
+        input: DescribeTransitGatewayAttachmentsRequest,
+    ) -> Result<
+        DescribeTransitGatewayAttachmentsResult,
+        RusotoError<DescribeTransitGatewayAttachmentsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_attachments(input))
+    }
+
+    /// <p>Describes one or more transit gateway multicast domains.</p>
+    ///
+    /// See `Ec2::describe_transit_gateway_multicast_domains`.
+    pub fn describe_transit_gateway_multicast_domains(
+        &self,
+        input: DescribeTransitGatewayMulticastDomainsRequest,
+    ) -> Result<
+        DescribeTransitGatewayMulticastDomainsResult,
+        RusotoError<DescribeTransitGatewayMulticastDomainsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_transit_gateway_multicast_domains(input),
+        )
+    }
+
+    /// <p>Describes your transit gateway peering attachments.</p>
+    ///
+    /// See `Ec2::describe_transit_gateway_peering_attachments`.
+    pub fn describe_transit_gateway_peering_attachments(
+        &self,
+        input: DescribeTransitGatewayPeeringAttachmentsRequest,
+    ) -> Result<
+        DescribeTransitGatewayPeeringAttachmentsResult,
+        RusotoError<DescribeTransitGatewayPeeringAttachmentsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_transit_gateway_peering_attachments(input),
+        )
+    }
+
+    /// <p>Describes one or more transit gateway route tables. By default, all transit gateway route tables are described. Alternatively, you can filter the results.</p>
+    ///
+    /// See `Ec2::describe_transit_gateway_route_tables`.
+    pub fn describe_transit_gateway_route_tables(
+        &self,
+        input: DescribeTransitGatewayRouteTablesRequest,
+    ) -> Result<
+        DescribeTransitGatewayRouteTablesResult,
+        RusotoError<DescribeTransitGatewayRouteTablesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_route_tables(input))
+    }
+
+    /// <p>Describes one or more VPC attachments. By default, all VPC attachments are described. Alternatively, you can filter the results.</p>
+    ///
+    /// See `Ec2::describe_transit_gateway_vpc_attachments`.
+    pub fn describe_transit_gateway_vpc_attachments(
+        &self,
+        input: DescribeTransitGatewayVpcAttachmentsRequest,
+    ) -> Result<
+        DescribeTransitGatewayVpcAttachmentsResult,
+        RusotoError<DescribeTransitGatewayVpcAttachmentsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_vpc_attachments(input))
+    }
+
+    /// <p>Describes one or more transit gateways. By default, all transit gateways are described. Alternatively, you can filter the results.</p>
+    ///
+    /// See `Ec2::describe_transit_gateways`.
+    pub fn describe_transit_gateways(
+        &self,
+        input: DescribeTransitGatewaysRequest,
+    ) -> Result<DescribeTransitGatewaysResult, RusotoError<DescribeTransitGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_transit_gateways(input))
+    }
+
+    /// <p>Describes the specified attribute of the specified volume. You can specify only one attribute at a time.</p> <p>For more information about EBS volumes, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSVolumes.html">Amazon EBS Volumes</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_volume_attribute`.
+    pub fn describe_volume_attribute(
+        &self,
+        input: DescribeVolumeAttributeRequest,
+    ) -> Result<DescribeVolumeAttributeResult, RusotoError<DescribeVolumeAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_volume_attribute(input))
+    }
+
+    /// <p>Describes the status of the specified volumes. Volume status provides the result of the checks performed on your volumes to determine events that can impair the performance of your volumes. The performance of a volume can be affected if an issue occurs on the volume's underlying host. If the volume's underlying host experiences a power outage or system issue, after the system is restored, there could be data inconsistencies on the volume. Volume events notify you if this occurs. Volume actions notify you if any action needs to be taken in response to the event.</p> <p>The <code>DescribeVolumeStatus</code> operation provides the following information about the specified volumes:</p> <p> <i>Status</i>: Reflects the current status of the volume. The possible values are <code>ok</code>, <code>impaired</code> , <code>warning</code>, or <code>insufficient-data</code>. If all checks pass, the overall status of the volume is <code>ok</code>. If the check fails, the overall status is <code>impaired</code>. If the status is <code>insufficient-data</code>, then the checks may still be taking place on your volume at the time. We recommend that you retry the request. For more information about volume status, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/monitoring-volume-status.html">Monitoring the Status of Your Volumes</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p> <i>Events</i>: Reflect the cause of a volume status and may require you to take action. For example, if your volume returns an <code>impaired</code> status, then the volume event might be <code>potential-data-inconsistency</code>. This means that your volume has been affected by an issue with the underlying host, has all I/O operations disabled, and may have inconsistent data.</p> <p> <i>Actions</i>: Reflect the actions you may have to take in response to an event. For example, if the status of the volume is <code>impaired</code> and the volume event shows <code>potential-data-inconsistency</code>, then the action shows <code>enable-volume-io</code>. This means that you may want to enable the I/O operations for the volume by calling the <a>EnableVolumeIO</a> action and then check the volume for data consistency.</p> <p>Volume status is based on the volume status checks, and does not reflect the volume state. Therefore, volume status does not indicate volumes in the <code>error</code> state (for example, when a volume is incapable of accepting I/O.)</p>
+    ///
+    /// See `Ec2::describe_volume_status`.
+    pub fn describe_volume_status(
+        &self,
+        input: DescribeVolumeStatusRequest,
+    ) -> Result<DescribeVolumeStatusResult, RusotoError<DescribeVolumeStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_volume_status(input))
+    }
+
+    /// <p>Describes the specified EBS volumes or all of your EBS volumes.</p> <p>If you are describing a long list of volumes, we recommend that you paginate the output to make the list more manageable. The <code>MaxResults</code> parameter sets the maximum number of results returned in a single page. If the list of results exceeds your <code>MaxResults</code> value, then that number of results is returned along with a <code>NextToken</code> value that can be passed to a subsequent <code>DescribeVolumes</code> request to retrieve the remaining results.</p> <p>For more information about EBS volumes, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSVolumes.html">Amazon EBS Volumes</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_volumes`.
+    pub fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
+        self.runtime.block_on(self.client.describe_volumes(input))
+    }
+
+    /// <p>Describes the most recent volume modification request for the specified EBS volumes.</p> <p>If a volume has never been modified, some information in the output will be null. If a volume has been modified more than once, the output includes only the most recent modification request.</p> <p>You can also use CloudWatch Events to check the status of a modification to an EBS volume. For information about CloudWatch Events, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/events/">Amazon CloudWatch Events User Guide</a>. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-expand-volume.html#monitoring_mods">Monitoring Volume Modifications</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_volumes_modifications`.
+    pub fn describe_volumes_modifications(
+        &self,
+        input: DescribeVolumesModificationsRequest,
+    ) -> Result<DescribeVolumesModificationsResult, RusotoError<DescribeVolumesModificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_volumes_modifications(input))
+    }
+
+    /// <p>Describes the specified attribute of the specified VPC. You can specify only one attribute at a time.</p>
+    ///
+    /// See `Ec2::describe_vpc_attribute`.
+    pub fn describe_vpc_attribute(
+        &self,
+        input: DescribeVpcAttributeRequest,
+    ) -> Result<DescribeVpcAttributeResult, RusotoError<DescribeVpcAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_vpc_attribute(input))
+    }
+
+    /// <p>Describes the ClassicLink status of one or more VPCs.</p>
+    ///
+    /// See `Ec2::describe_vpc_classic_link`.
+    pub fn describe_vpc_classic_link(
+        &self,
+        input: DescribeVpcClassicLinkRequest,
+    ) -> Result<DescribeVpcClassicLinkResult, RusotoError<DescribeVpcClassicLinkError>> {
+        self.runtime
+            .block_on(self.client.describe_vpc_classic_link(input))
+    }
+
+    /// <p>Describes the ClassicLink DNS support status of one or more VPCs. If enabled, the DNS hostname of a linked EC2-Classic instance resolves to its private IP address when addressed from an instance in the VPC to which it's linked. Similarly, the DNS hostname of an instance in a VPC resolves to its private IP address when addressed from a linked EC2-Classic instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/vpc-classiclink.html">ClassicLink</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_vpc_classic_link_dns_support`.
+    pub fn describe_vpc_classic_link_dns_support(
+        &self,
+        input: DescribeVpcClassicLinkDnsSupportRequest,
+    ) -> Result<
+        DescribeVpcClassicLinkDnsSupportResult,
+        RusotoError<DescribeVpcClassicLinkDnsSupportError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_classic_link_dns_support(input))
+    }
+
+    /// <p>Describes the connection notifications for VPC endpoints and VPC endpoint services.</p>
+    ///
+    /// See `Ec2::describe_vpc_endpoint_connection_notifications`.
+    pub fn describe_vpc_endpoint_connection_notifications(
+        &self,
+        input: DescribeVpcEndpointConnectionNotificationsRequest,
+    ) -> Result<
+        DescribeVpcEndpointConnectionNotificationsResult,
+        RusotoError<DescribeVpcEndpointConnectionNotificationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_vpc_endpoint_connection_notifications(input),
+        )
+    }
+
+    /// <p>Describes the VPC endpoint connections to your VPC endpoint services, including any endpoints that are pending your acceptance.</p>
+    ///
+    /// See `Ec2::describe_vpc_endpoint_connections`.
+    pub fn describe_vpc_endpoint_connections(
+        &self,
+        input: DescribeVpcEndpointConnectionsRequest,
+    ) -> Result<
+        DescribeVpcEndpointConnectionsResult,
+        RusotoError<DescribeVpcEndpointConnectionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoint_connections(input))
+    }
+
+    /// <p>Describes the VPC endpoint service configurations in your account (your services).</p>
+    ///
+    /// See `Ec2::describe_vpc_endpoint_service_configurations`.
+    pub fn describe_vpc_endpoint_service_configurations(
+        &self,
+        input: DescribeVpcEndpointServiceConfigurationsRequest,
+    ) -> Result<
+        DescribeVpcEndpointServiceConfigurationsResult,
+        RusotoError<DescribeVpcEndpointServiceConfigurationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_vpc_endpoint_service_configurations(input),
+        )
+    }
+
+    /// <p>Describes the principals (service consumers) that are permitted to discover your VPC endpoint service.</p>
+    ///
+    /// See `Ec2::describe_vpc_endpoint_service_permissions`.
+    pub fn describe_vpc_endpoint_service_permissions(
+        &self,
+        input: DescribeVpcEndpointServicePermissionsRequest,
+    ) -> Result<
+        DescribeVpcEndpointServicePermissionsResult,
+        RusotoError<DescribeVpcEndpointServicePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoint_service_permissions(input))
+    }
+
+    /// <p>Describes available services to which you can create a VPC endpoint.</p>
+    ///
+    /// See `Ec2::describe_vpc_endpoint_services`.
+    pub fn describe_vpc_endpoint_services(
+        &self,
+        input: DescribeVpcEndpointServicesRequest,
+    ) -> Result<DescribeVpcEndpointServicesResult, RusotoError<DescribeVpcEndpointServicesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoint_services(input))
+    }
+
+    /// <p>Describes one or more of your VPC endpoints.</p>
+    ///
+    /// See `Ec2::describe_vpc_endpoints`.
+    pub fn describe_vpc_endpoints(
+        &self,
+        input: DescribeVpcEndpointsRequest,
+    ) -> Result<DescribeVpcEndpointsResult, RusotoError<DescribeVpcEndpointsError>> {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoints(input))
+    }
+
+    /// <p>Describes one or more of your VPC peering connections.</p>
+    ///
+    /// See `Ec2::describe_vpc_peering_connections`.
+    pub fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsRequest,
+    ) -> Result<DescribeVpcPeeringConnectionsResult, RusotoError<DescribeVpcPeeringConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_vpc_peering_connections(input))
+    }
+
+    /// <p>Describes one or more of your VPCs.</p>
+    ///
+    /// See `Ec2::describe_vpcs`.
+    pub fn describe_vpcs(
+        &self,
+        input: DescribeVpcsRequest,
+    ) -> Result<DescribeVpcsResult, RusotoError<DescribeVpcsError>> {
+        self.runtime.block_on(self.client.describe_vpcs(input))
+    }
+
+    /// <p>Describes one or more of your VPN connections.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_vpn_connections`.
+    pub fn describe_vpn_connections(
+        &self,
+        input: DescribeVpnConnectionsRequest,
+    ) -> Result<DescribeVpnConnectionsResult, RusotoError<DescribeVpnConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_vpn_connections(input))
+    }
+
+    /// <p>Describes one or more of your virtual private gateways.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPC_VPN.html">AWS Site-to-Site VPN</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::describe_vpn_gateways`.
+    pub fn describe_vpn_gateways(
+        &self,
+        input: DescribeVpnGatewaysRequest,
+    ) -> Result<DescribeVpnGatewaysResult, RusotoError<DescribeVpnGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_vpn_gateways(input))
+    }
+
+    /// <p>Unlinks (detaches) a linked EC2-Classic instance from a VPC. After the instance has been unlinked, the VPC security groups are no longer associated with it. An instance is automatically unlinked from a VPC when it's stopped.</p>
+    ///
+    /// See `Ec2::detach_classic_link_vpc`.
+    pub fn detach_classic_link_vpc(
+        &self,
+        input: DetachClassicLinkVpcRequest,
+    ) -> Result<DetachClassicLinkVpcResult, RusotoError<DetachClassicLinkVpcError>> {
+        self.runtime
+            .block_on(self.client.detach_classic_link_vpc(input))
+    }
+
+    /// <p>Detaches an internet gateway from a VPC, disabling connectivity between the internet and the VPC. The VPC must not contain any running instances with Elastic IP addresses or public IPv4 addresses.</p>
+    ///
+    /// See `Ec2::detach_internet_gateway`.
+    pub fn detach_internet_gateway(
+        &self,
+        input: DetachInternetGatewayRequest,
+    ) -> Result<(), RusotoError<DetachInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.detach_internet_gateway(input))
+    }
+
+    /// <p>Detaches a network interface from an instance.</p>
+    ///
+    /// See `Ec2::detach_network_interface`.
+    pub fn detach_network_interface(
+        &self,
+        input: DetachNetworkInterfaceRequest,
+    ) -> Result<(), RusotoError<DetachNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.detach_network_interface(input))
+    }
+
+    /// <p>Detaches an EBS volume from an instance. Make sure to unmount any file systems on the device within your operating system before detaching the volume. Failure to do so can result in the volume becoming stuck in the <code>busy</code> state while detaching. If this happens, detachment can be delayed indefinitely until you unmount the volume, force detachment, reboot the instance, or all three. If an EBS volume is the root device of an instance, it can't be detached while the instance is running. To detach the root volume, stop the instance first.</p> <p>When a volume with an AWS Marketplace product code is detached from an instance, the product code is no longer associated with the instance.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-detaching-volume.html">Detaching an Amazon EBS Volume</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::detach_volume`.
+    pub fn detach_volume(
+        &self,
+        input: DetachVolumeRequest,
+    ) -> Result<VolumeAttachment, RusotoError<DetachVolumeError>> {
+        self.runtime.block_on(self.client.detach_volume(input))
+    }
+
+    /// <p>Detaches a virtual private gateway from a VPC. You do this if you're planning to turn off the VPC and not use it anymore. You can confirm a virtual private gateway has been completely detached from a VPC by describing the virtual private gateway (any attachments to the virtual private gateway are also described).</p> <p>You must wait for the attachment's state to switch to <code>detached</code> before you can delete the VPC or attach a different VPC to the virtual private gateway.</p>
+    ///
+    /// See `Ec2::detach_vpn_gateway`.
+    pub fn detach_vpn_gateway(
+        &self,
+        input: DetachVpnGatewayRequest,
+    ) -> Result<(), RusotoError<DetachVpnGatewayError>> {
+        self.runtime.block_on(self.client.detach_vpn_gateway(input))
+    }
+
+    /// <p>Disables EBS encryption by default for your account in the current Region.</p> <p>After you disable encryption by default, you can still create encrypted volumes by enabling encryption when you create each volume.</p> <p>Disabling encryption by default does not change the encryption status of your existing volumes.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::disable_ebs_encryption_by_default`.
+    pub fn disable_ebs_encryption_by_default(
+        &self,
+        input: DisableEbsEncryptionByDefaultRequest,
+    ) -> Result<DisableEbsEncryptionByDefaultResult, RusotoError<DisableEbsEncryptionByDefaultError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_ebs_encryption_by_default(input))
+    }
+
+    /// <p>Disables fast snapshot restores for the specified snapshots in the specified Availability Zones.</p>
+    ///
+    /// See `Ec2::disable_fast_snapshot_restores`.
+    pub fn disable_fast_snapshot_restores(
+        &self,
+        input: DisableFastSnapshotRestoresRequest,
+    ) -> Result<DisableFastSnapshotRestoresResult, RusotoError<DisableFastSnapshotRestoresError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_fast_snapshot_restores(input))
+    }
+
+    /// <p>Disables the specified resource attachment from propagating routes to the specified propagation route table.</p>
+    ///
+    /// See `Ec2::disable_transit_gateway_route_table_propagation`.
+    pub fn disable_transit_gateway_route_table_propagation(
+        &self,
+        input: DisableTransitGatewayRouteTablePropagationRequest,
+    ) -> Result<
+        DisableTransitGatewayRouteTablePropagationResult,
+        RusotoError<DisableTransitGatewayRouteTablePropagationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disable_transit_gateway_route_table_propagation(input),
+        )
+    }
+
+    /// <p>Disables a virtual private gateway (VGW) from propagating routes to a specified route table of a VPC.</p>
+    ///
+    /// See `Ec2::disable_vgw_route_propagation`.
+    pub fn disable_vgw_route_propagation(
+        &self,
+        input: DisableVgwRoutePropagationRequest,
+    ) -> Result<(), RusotoError<DisableVgwRoutePropagationError>> {
+        self.runtime
+            .block_on(self.client.disable_vgw_route_propagation(input))
+    }
+
+    /// <p>Disables ClassicLink for a VPC. You cannot disable ClassicLink for a VPC that has EC2-Classic instances linked to it.</p>
+    ///
+    /// See `Ec2::disable_vpc_classic_link`.
+    pub fn disable_vpc_classic_link(
+        &self,
+        input: DisableVpcClassicLinkRequest,
+    ) -> Result<DisableVpcClassicLinkResult, RusotoError<DisableVpcClassicLinkError>> {
+        self.runtime
+            .block_on(self.client.disable_vpc_classic_link(input))
+    }
+
+    /// <p>Disables ClassicLink DNS support for a VPC. If disabled, DNS hostnames resolve to public IP addresses when addressed between a linked EC2-Classic instance and instances in the VPC to which it's linked. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/vpc-classiclink.html">ClassicLink</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>You must specify a VPC ID in the request.</p>
+    ///
+    /// See `Ec2::disable_vpc_classic_link_dns_support`.
+    pub fn disable_vpc_classic_link_dns_support(
+        &self,
+        input: DisableVpcClassicLinkDnsSupportRequest,
+    ) -> Result<
+        DisableVpcClassicLinkDnsSupportResult,
+        RusotoError<DisableVpcClassicLinkDnsSupportError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_vpc_classic_link_dns_support(input))
+    }
+
+    /// <p>Disassociates an Elastic IP address from the instance or network interface it's associated with.</p> <p>An Elastic IP address is for use in either the EC2-Classic platform or in a VPC. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP Addresses</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>This is an idempotent operation. If you perform the operation more than once, Amazon EC2 doesn't return an error.</p>
+    ///
+    /// See `Ec2::disassociate_address`.
+    pub fn disassociate_address(
+        &self,
+        input: DisassociateAddressRequest,
+    ) -> Result<(), RusotoError<DisassociateAddressError>> {
+        self.runtime
+            .block_on(self.client.disassociate_address(input))
+    }
+
+    /// <p><p>Disassociates a target network from the specified Client VPN endpoint. When you disassociate the last target network from a Client VPN, the following happens:</p> <ul> <li> <p>The route that was automatically added for the VPC is deleted</p> </li> <li> <p>All active client connections are terminated</p> </li> <li> <p>New client connections are disallowed</p> </li> <li> <p>The Client VPN endpoint&#39;s status changes to <code>pending-associate</code> </p> </li> </ul></p>
+    ///
+    /// See `Ec2::disassociate_client_vpn_target_network`.
+    pub fn disassociate_client_vpn_target_network(
+        &self,
+        input: DisassociateClientVpnTargetNetworkRequest,
+    ) -> Result<
+        DisassociateClientVpnTargetNetworkResult,
+        RusotoError<DisassociateClientVpnTargetNetworkError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_client_vpn_target_network(input))
+    }
+
+    /// <p>Disassociates an IAM instance profile from a running or stopped instance.</p> <p>Use <a>DescribeIamInstanceProfileAssociations</a> to get the association ID.</p>
+    ///
+    /// See `Ec2::disassociate_iam_instance_profile`.
+    pub fn disassociate_iam_instance_profile(
+        &self,
+        input: DisassociateIamInstanceProfileRequest,
+    ) -> Result<
+        DisassociateIamInstanceProfileResult,
+        RusotoError<DisassociateIamInstanceProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_iam_instance_profile(input))
+    }
+
+    /// <p>Disassociates a subnet or gateway from a route table.</p> <p>After you perform this action, the subnet no longer uses the routes in the route table. Instead, it uses the routes in the VPC's main route table. For more information about route tables, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::disassociate_route_table`.
+    pub fn disassociate_route_table(
+        &self,
+        input: DisassociateRouteTableRequest,
+    ) -> Result<(), RusotoError<DisassociateRouteTableError>> {
+        self.runtime
+            .block_on(self.client.disassociate_route_table(input))
+    }
+
+    /// <p>Disassociates a CIDR block from a subnet. Currently, you can disassociate an IPv6 CIDR block only. You must detach or delete all gateways and resources that are associated with the CIDR block before you can disassociate it. </p>
+    ///
+    /// See `Ec2::disassociate_subnet_cidr_block`.
+    pub fn disassociate_subnet_cidr_block(
+        &self,
+        input: DisassociateSubnetCidrBlockRequest,
+    ) -> Result<DisassociateSubnetCidrBlockResult, RusotoError<DisassociateSubnetCidrBlockError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_subnet_cidr_block(input))
+    }
+
+    /// <p>Disassociates the specified subnets from the transit gateway multicast domain. </p>
+    ///
+    /// See `Ec2::disassociate_transit_gateway_multicast_domain`.
+    pub fn disassociate_transit_gateway_multicast_domain(
+        &self,
+        input: DisassociateTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        DisassociateTransitGatewayMulticastDomainResult,
+        RusotoError<DisassociateTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_transit_gateway_multicast_domain(input),
+        )
+    }
+
+    /// <p>Disassociates a resource attachment from a transit gateway route table.</p>
+    ///
+    /// See `Ec2::disassociate_transit_gateway_route_table`.
+    pub fn disassociate_transit_gateway_route_table(
+        &self,
+        input: DisassociateTransitGatewayRouteTableRequest,
+    ) -> Result<
+        DisassociateTransitGatewayRouteTableResult,
+        RusotoError<DisassociateTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_transit_gateway_route_table(input))
+    }
+
+    /// <p>Disassociates a CIDR block from a VPC. To disassociate the CIDR block, you must specify its association ID. You can get the association ID by using <a>DescribeVpcs</a>. You must detach or delete all gateways and resources that are associated with the CIDR block before you can disassociate it. </p> <p>You cannot disassociate the CIDR block with which you originally created the VPC (the primary CIDR block).</p>
+    ///
+    /// See `Ec2::disassociate_vpc_cidr_block`.
+    pub fn disassociate_vpc_cidr_block(
+        &self,
+        input: DisassociateVpcCidrBlockRequest,
+    ) -> Result<DisassociateVpcCidrBlockResult, RusotoError<DisassociateVpcCidrBlockError>> {
+        self.runtime
+            .block_on(self.client.disassociate_vpc_cidr_block(input))
+    }
+
+    /// <p>Enables EBS encryption by default for your account in the current Region.</p> <p>After you enable encryption by default, the EBS volumes that you create are are always encrypted, either using the default CMK or the CMK that you specified when you created each volume. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>You can specify the default CMK for encryption by default using <a>ModifyEbsDefaultKmsKeyId</a> or <a>ResetEbsDefaultKmsKeyId</a>.</p> <p>Enabling encryption by default has no effect on the encryption status of your existing volumes.</p> <p>After you enable encryption by default, you can no longer launch instances using instance types that do not support encryption. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html#EBSEncryption_supported_instances">Supported Instance Types</a>.</p>
+    ///
+    /// See `Ec2::enable_ebs_encryption_by_default`.
+    pub fn enable_ebs_encryption_by_default(
+        &self,
+        input: EnableEbsEncryptionByDefaultRequest,
+    ) -> Result<EnableEbsEncryptionByDefaultResult, RusotoError<EnableEbsEncryptionByDefaultError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_ebs_encryption_by_default(input))
+    }
+
+    /// <p>Enables fast snapshot restores for the specified snapshots in the specified Availability Zones.</p> <p>You get the full benefit of fast snapshot restores after they enter the <code>enabled</code> state. To get the current state of fast snapshot restores, use <a>DescribeFastSnapshotRestores</a>. To disable fast snapshot restores, use <a>DisableFastSnapshotRestores</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-fast-snapshot-restore.html">Amazon EBS Fast Snapshot Restore</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::enable_fast_snapshot_restores`.
+    pub fn enable_fast_snapshot_restores(
+        &self,
+        input: EnableFastSnapshotRestoresRequest,
+    ) -> Result<EnableFastSnapshotRestoresResult, RusotoError<EnableFastSnapshotRestoresError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_fast_snapshot_restores(input))
+    }
+
+    /// <p>Enables the specified attachment to propagate routes to the specified propagation route table.</p>
+    ///
+    /// See `Ec2::enable_transit_gateway_route_table_propagation`.
+    pub fn enable_transit_gateway_route_table_propagation(
+        &self,
+        input: EnableTransitGatewayRouteTablePropagationRequest,
+    ) -> Result<
+        EnableTransitGatewayRouteTablePropagationResult,
+        RusotoError<EnableTransitGatewayRouteTablePropagationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .enable_transit_gateway_route_table_propagation(input),
+        )
+    }
+
+    /// <p>Enables a virtual private gateway (VGW) to propagate routes to the specified route table of a VPC.</p>
+    ///
+    /// See `Ec2::enable_vgw_route_propagation`.
+    pub fn enable_vgw_route_propagation(
+        &self,
+        input: EnableVgwRoutePropagationRequest,
+    ) -> Result<(), RusotoError<EnableVgwRoutePropagationError>> {
+        self.runtime
+            .block_on(self.client.enable_vgw_route_propagation(input))
+    }
+
+    /// <p>Enables I/O operations for a volume that had I/O operations disabled because the data on the volume was potentially inconsistent.</p>
+    ///
+    /// See `Ec2::enable_volume_io`.
+    pub fn enable_volume_io(
+        &self,
+        input: EnableVolumeIORequest,
+    ) -> Result<(), RusotoError<EnableVolumeIOError>> {
+        self.runtime.block_on(self.client.enable_volume_io(input))
+    }
+
+    /// <p>Enables a VPC for ClassicLink. You can then link EC2-Classic instances to your ClassicLink-enabled VPC to allow communication over private IP addresses. You cannot enable your VPC for ClassicLink if any of your VPC route tables have existing routes for address ranges within the <code>10.0.0.0/8</code> IP address range, excluding local routes for VPCs in the <code>10.0.0.0/16</code> and <code>10.1.0.0/16</code> IP address ranges. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/vpc-classiclink.html">ClassicLink</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::enable_vpc_classic_link`.
+    pub fn enable_vpc_classic_link(
+        &self,
+        input: EnableVpcClassicLinkRequest,
+    ) -> Result<EnableVpcClassicLinkResult, RusotoError<EnableVpcClassicLinkError>> {
+        self.runtime
+            .block_on(self.client.enable_vpc_classic_link(input))
+    }
+
+    /// <p>Enables a VPC to support DNS hostname resolution for ClassicLink. If enabled, the DNS hostname of a linked EC2-Classic instance resolves to its private IP address when addressed from an instance in the VPC to which it's linked. Similarly, the DNS hostname of an instance in a VPC resolves to its private IP address when addressed from a linked EC2-Classic instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/vpc-classiclink.html">ClassicLink</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>You must specify a VPC ID in the request.</p>
+    ///
+    /// See `Ec2::enable_vpc_classic_link_dns_support`.
+    pub fn enable_vpc_classic_link_dns_support(
+        &self,
+        input: EnableVpcClassicLinkDnsSupportRequest,
+    ) -> Result<
+        EnableVpcClassicLinkDnsSupportResult,
+        RusotoError<EnableVpcClassicLinkDnsSupportError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_vpc_classic_link_dns_support(input))
+    }
+
+    /// <p>Downloads the client certificate revocation list for the specified Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::export_client_vpn_client_certificate_revocation_list`.
+    pub fn export_client_vpn_client_certificate_revocation_list(
+        &self,
+        input: ExportClientVpnClientCertificateRevocationListRequest,
+    ) -> Result<
+        ExportClientVpnClientCertificateRevocationListResult,
+        RusotoError<ExportClientVpnClientCertificateRevocationListError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .export_client_vpn_client_certificate_revocation_list(input),
+        )
+    }
+
+    /// <p>Downloads the contents of the Client VPN endpoint configuration file for the specified Client VPN endpoint. The Client VPN endpoint configuration file includes the Client VPN endpoint and certificate information clients need to establish a connection with the Client VPN endpoint.</p>
+    ///
+    /// See `Ec2::export_client_vpn_client_configuration`.
+    pub fn export_client_vpn_client_configuration(
+        &self,
+        input: ExportClientVpnClientConfigurationRequest,
+    ) -> Result<
+        ExportClientVpnClientConfigurationResult,
+        RusotoError<ExportClientVpnClientConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.export_client_vpn_client_configuration(input))
+    }
+
+    /// <p>Exports an Amazon Machine Image (AMI) to a VM file. For more information, see <a href="https://docs.aws.amazon.com/vm-import/latest/userguide/vmexport_image.html">Exporting a VM Directory from an Amazon Machine Image (AMI)</a> in the <i>VM Import/Export User Guide</i>.</p>
+    ///
+    /// See `Ec2::export_image`.
+    pub fn export_image(
+        &self,
+        input: ExportImageRequest,
+    ) -> Result<ExportImageResult, RusotoError<ExportImageError>> {
+        self.runtime.block_on(self.client.export_image(input))
+    }
+
+    /// <p>Exports routes from the specified transit gateway route table to the specified S3 bucket. By default, all routes are exported. Alternatively, you can filter by CIDR range.</p> <p>The routes are saved to the specified bucket in a JSON file. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/tgw/tgw-route-tables.html#tgw-export-route-tables">Export Route Tables to Amazon S3</a> in <i>Transit Gateways</i>.</p>
+    ///
+    /// See `Ec2::export_transit_gateway_routes`.
+    pub fn export_transit_gateway_routes(
+        &self,
+        input: ExportTransitGatewayRoutesRequest,
+    ) -> Result<ExportTransitGatewayRoutesResult, RusotoError<ExportTransitGatewayRoutesError>>
+    {
+        self.runtime
+            .block_on(self.client.export_transit_gateway_routes(input))
+    }
+
+    /// <p>Gets information about the IPv6 CIDR block associations for a specified IPv6 address pool.</p>
+    ///
+    /// See `Ec2::get_associated_ipv_6_pool_cidrs`.
+    pub fn get_associated_ipv_6_pool_cidrs(
+        &self,
+        input: GetAssociatedIpv6PoolCidrsRequest,
+    ) -> Result<GetAssociatedIpv6PoolCidrsResult, RusotoError<GetAssociatedIpv6PoolCidrsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_associated_ipv_6_pool_cidrs(input))
+    }
+
+    /// <p>Gets usage information about a Capacity Reservation. If the Capacity Reservation is shared, it shows usage information for the Capacity Reservation owner and each AWS account that is currently using the shared capacity. If the Capacity Reservation is not shared, it shows only the Capacity Reservation owner's usage.</p>
+    ///
+    /// See `Ec2::get_capacity_reservation_usage`.
+    pub fn get_capacity_reservation_usage(
+        &self,
+        input: GetCapacityReservationUsageRequest,
+    ) -> Result<GetCapacityReservationUsageResult, RusotoError<GetCapacityReservationUsageError>>
+    {
+        self.runtime
+            .block_on(self.client.get_capacity_reservation_usage(input))
+    }
+
+    /// <p>Describes the allocations from the specified customer-owned address pool.</p>
+    ///
+    /// See `Ec2::get_coip_pool_usage`.
+    pub fn get_coip_pool_usage(
+        &self,
+        input: GetCoipPoolUsageRequest,
+    ) -> Result<GetCoipPoolUsageResult, RusotoError<GetCoipPoolUsageError>> {
+        self.runtime
+            .block_on(self.client.get_coip_pool_usage(input))
+    }
+
+    /// <p>Gets the console output for the specified instance. For Linux instances, the instance console output displays the exact console output that would normally be displayed on a physical monitor attached to a computer. For Windows instances, the instance console output includes the last three system event log errors.</p> <p>By default, the console output returns buffered information that was posted shortly after an instance transition state (start, stop, reboot, or terminate). This information is available for at least one hour after the most recent post. Only the most recent 64 KB of console output is available.</p> <p>You can optionally retrieve the latest serial console output at any time during the instance lifecycle. This option is supported on instance types that use the Nitro hypervisor.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-console.html#instance-console-console-output">Instance Console Output</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::get_console_output`.
+    pub fn get_console_output(
+        &self,
+        input: GetConsoleOutputRequest,
+    ) -> Result<GetConsoleOutputResult, RusotoError<GetConsoleOutputError>> {
+        self.runtime.block_on(self.client.get_console_output(input))
+    }
+
+    /// <p>Retrieve a JPG-format screenshot of a running instance to help with troubleshooting.</p> <p>The returned content is Base64-encoded.</p>
+    ///
+    /// See `Ec2::get_console_screenshot`.
+    pub fn get_console_screenshot(
+        &self,
+        input: GetConsoleScreenshotRequest,
+    ) -> Result<GetConsoleScreenshotResult, RusotoError<GetConsoleScreenshotError>> {
+        self.runtime
+            .block_on(self.client.get_console_screenshot(input))
+    }
+
+    /// <p>Describes the default credit option for CPU usage of a burstable performance instance family.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/burstable-performance-instances.html">Burstable performance instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::get_default_credit_specification`.
+    pub fn get_default_credit_specification(
+        &self,
+        input: GetDefaultCreditSpecificationRequest,
+    ) -> Result<GetDefaultCreditSpecificationResult, RusotoError<GetDefaultCreditSpecificationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_default_credit_specification(input))
+    }
+
+    /// <p>Describes the default customer master key (CMK) for EBS encryption by default for your account in this Region. You can change the default CMK for encryption by default using <a>ModifyEbsDefaultKmsKeyId</a> or <a>ResetEbsDefaultKmsKeyId</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::get_ebs_default_kms_key_id`.
+    pub fn get_ebs_default_kms_key_id(
+        &self,
+        input: GetEbsDefaultKmsKeyIdRequest,
+    ) -> Result<GetEbsDefaultKmsKeyIdResult, RusotoError<GetEbsDefaultKmsKeyIdError>> {
+        self.runtime
+            .block_on(self.client.get_ebs_default_kms_key_id(input))
+    }
+
+    /// <p>Describes whether EBS encryption by default is enabled for your account in the current Region.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::get_ebs_encryption_by_default`.
+    pub fn get_ebs_encryption_by_default(
+        &self,
+        input: GetEbsEncryptionByDefaultRequest,
+    ) -> Result<GetEbsEncryptionByDefaultResult, RusotoError<GetEbsEncryptionByDefaultError>> {
+        self.runtime
+            .block_on(self.client.get_ebs_encryption_by_default(input))
+    }
+
+    /// <p>Preview a reservation purchase with configurations that match those of your Dedicated Host. You must have active Dedicated Hosts in your account before you purchase a reservation.</p> <p>This is a preview of the <a>PurchaseHostReservation</a> action and does not result in the offering being purchased.</p>
+    ///
+    /// See `Ec2::get_host_reservation_purchase_preview`.
+    pub fn get_host_reservation_purchase_preview(
+        &self,
+        input: GetHostReservationPurchasePreviewRequest,
+    ) -> Result<
+        GetHostReservationPurchasePreviewResult,
+        RusotoError<GetHostReservationPurchasePreviewError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_host_reservation_purchase_preview(input))
+    }
+
+    /// <p>Retrieves the configuration data of the specified instance. You can use this data to create a launch template.</p>
+    ///
+    /// See `Ec2::get_launch_template_data`.
+    pub fn get_launch_template_data(
+        &self,
+        input: GetLaunchTemplateDataRequest,
+    ) -> Result<GetLaunchTemplateDataResult, RusotoError<GetLaunchTemplateDataError>> {
+        self.runtime
+            .block_on(self.client.get_launch_template_data(input))
+    }
+
+    /// <p>Gets information about the resources that are associated with the specified managed prefix list.</p>
+    ///
+    /// See `Ec2::get_managed_prefix_list_associations`.
+    pub fn get_managed_prefix_list_associations(
+        &self,
+        input: GetManagedPrefixListAssociationsRequest,
+    ) -> Result<
+        GetManagedPrefixListAssociationsResult,
+        RusotoError<GetManagedPrefixListAssociationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_managed_prefix_list_associations(input))
+    }
+
+    /// <p>Gets information about the entries for a specified managed prefix list.</p>
+    ///
+    /// See `Ec2::get_managed_prefix_list_entries`.
+    pub fn get_managed_prefix_list_entries(
+        &self,
+        input: GetManagedPrefixListEntriesRequest,
+    ) -> Result<GetManagedPrefixListEntriesResult, RusotoError<GetManagedPrefixListEntriesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_managed_prefix_list_entries(input))
+    }
+
+    /// <p>Retrieves the encrypted administrator password for a running Windows instance.</p> <p>The Windows password is generated at boot by the <code>EC2Config</code> service or <code>EC2Launch</code> scripts (Windows Server 2016 and later). This usually only happens the first time an instance is launched. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/UsingConfig_WinAMI.html">EC2Config</a> and <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/ec2launch.html">EC2Launch</a> in the Amazon Elastic Compute Cloud User Guide.</p> <p>For the <code>EC2Config</code> service, the password is not generated for rebundled AMIs unless <code>Ec2SetPassword</code> is enabled before bundling.</p> <p>The password is encrypted using the key pair that you specified when you launched the instance. You must provide the corresponding key pair file.</p> <p>When you launch an instance, password generation and encryption may take a few minutes. If you try to retrieve the password before it's available, the output returns an empty string. We recommend that you wait up to 15 minutes after launching an instance before trying to retrieve the generated password.</p>
+    ///
+    /// See `Ec2::get_password_data`.
+    pub fn get_password_data(
+        &self,
+        input: GetPasswordDataRequest,
+    ) -> Result<GetPasswordDataResult, RusotoError<GetPasswordDataError>> {
+        self.runtime.block_on(self.client.get_password_data(input))
+    }
+
+    /// <p>Returns a quote and exchange information for exchanging one or more specified Convertible Reserved Instances for a new Convertible Reserved Instance. If the exchange cannot be performed, the reason is returned in the response. Use <a>AcceptReservedInstancesExchangeQuote</a> to perform the exchange.</p>
+    ///
+    /// See `Ec2::get_reserved_instances_exchange_quote`.
+    pub fn get_reserved_instances_exchange_quote(
+        &self,
+        input: GetReservedInstancesExchangeQuoteRequest,
+    ) -> Result<
+        GetReservedInstancesExchangeQuoteResult,
+        RusotoError<GetReservedInstancesExchangeQuoteError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reserved_instances_exchange_quote(input))
+    }
+
+    /// <p>Lists the route tables to which the specified resource attachment propagates routes.</p>
+    ///
+    /// See `Ec2::get_transit_gateway_attachment_propagations`.
+    pub fn get_transit_gateway_attachment_propagations(
+        &self,
+        input: GetTransitGatewayAttachmentPropagationsRequest,
+    ) -> Result<
+        GetTransitGatewayAttachmentPropagationsResult,
+        RusotoError<GetTransitGatewayAttachmentPropagationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_attachment_propagations(input),
+        )
+    }
+
+    /// <p>Gets information about the associations for the transit gateway multicast domain.</p>
+    ///
+    /// See `Ec2::get_transit_gateway_multicast_domain_associations`.
+    pub fn get_transit_gateway_multicast_domain_associations(
+        &self,
+        input: GetTransitGatewayMulticastDomainAssociationsRequest,
+    ) -> Result<
+        GetTransitGatewayMulticastDomainAssociationsResult,
+        RusotoError<GetTransitGatewayMulticastDomainAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_multicast_domain_associations(input),
+        )
+    }
+
+    /// <p>Gets information about the associations for the specified transit gateway route table.</p>
+    ///
+    /// See `Ec2::get_transit_gateway_route_table_associations`.
+    pub fn get_transit_gateway_route_table_associations(
+        &self,
+        input: GetTransitGatewayRouteTableAssociationsRequest,
+    ) -> Result<
+        GetTransitGatewayRouteTableAssociationsResult,
+        RusotoError<GetTransitGatewayRouteTableAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_route_table_associations(input),
+        )
+    }
+
+    /// <p>Gets information about the route table propagations for the specified transit gateway route table.</p>
+    ///
+    /// See `Ec2::get_transit_gateway_route_table_propagations`.
+    pub fn get_transit_gateway_route_table_propagations(
+        &self,
+        input: GetTransitGatewayRouteTablePropagationsRequest,
+    ) -> Result<
+        GetTransitGatewayRouteTablePropagationsResult,
+        RusotoError<GetTransitGatewayRouteTablePropagationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_route_table_propagations(input),
+        )
+    }
+
+    /// <p>Uploads a client certificate revocation list to the specified Client VPN endpoint. Uploading a client certificate revocation list overwrites the existing client certificate revocation list.</p> <p>Uploading a client certificate revocation list resets existing client connections.</p>
+    ///
+    /// See `Ec2::import_client_vpn_client_certificate_revocation_list`.
+    pub fn import_client_vpn_client_certificate_revocation_list(
+        &self,
+        input: ImportClientVpnClientCertificateRevocationListRequest,
+    ) -> Result<
+        ImportClientVpnClientCertificateRevocationListResult,
+        RusotoError<ImportClientVpnClientCertificateRevocationListError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .import_client_vpn_client_certificate_revocation_list(input),
+        )
+    }
+
+    /// <p>Import single or multi-volume disk images or EBS snapshots into an Amazon Machine Image (AMI). For more information, see <a href="https://docs.aws.amazon.com/vm-import/latest/userguide/vmimport-image-import.html">Importing a VM as an Image Using VM Import/Export</a> in the <i>VM Import/Export User Guide</i>.</p>
+    ///
+    /// See `Ec2::import_image`.
+    pub fn import_image(
+        &self,
+        input: ImportImageRequest,
+    ) -> Result<ImportImageResult, RusotoError<ImportImageError>> {
+        self.runtime.block_on(self.client.import_image(input))
+    }
+
+    /// <p>Creates an import instance task using metadata from the specified disk image. <code>ImportInstance</code> only supports single-volume VMs. To import multi-volume VMs, use <a>ImportImage</a>. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/CommandLineReference/ec2-cli-vmimport-export.html">Importing a Virtual Machine Using the Amazon EC2 CLI</a>.</p> <p>For information about the import manifest referenced by this API action, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/manifest.html">VM Import Manifest</a>.</p>
+    ///
+    /// See `Ec2::import_instance`.
+    pub fn import_instance(
+        &self,
+        input: ImportInstanceRequest,
+    ) -> Result<ImportInstanceResult, RusotoError<ImportInstanceError>> {
+        self.runtime.block_on(self.client.import_instance(input))
+    }
+
+    /// <p>Imports the public key from an RSA key pair that you created with a third-party tool. Compare this with <a>CreateKeyPair</a>, in which AWS creates the key pair and gives the keys to you (AWS keeps a copy of the public key). With ImportKeyPair, you create the key pair and give AWS just the public key. The private key is never transferred between you and AWS.</p> <p>For more information about key pairs, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-key-pairs.html">Key Pairs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::import_key_pair`.
+    pub fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
+        self.runtime.block_on(self.client.import_key_pair(input))
+    }
+
+    /// <p>Imports a disk into an EBS snapshot.</p>
+    ///
+    /// See `Ec2::import_snapshot`.
+    pub fn import_snapshot(
+        &self,
+        input: ImportSnapshotRequest,
+    ) -> Result<ImportSnapshotResult, RusotoError<ImportSnapshotError>> {
+        self.runtime.block_on(self.client.import_snapshot(input))
+    }
+
+    /// <p>Creates an import volume task using metadata from the specified disk image.For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/CommandLineReference/importing-your-volumes-into-amazon-ebs.html">Importing Disks to Amazon EBS</a>.</p> <p>For information about the import manifest referenced by this API action, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/manifest.html">VM Import Manifest</a>.</p>
+    ///
+    /// See `Ec2::import_volume`.
+    pub fn import_volume(
+        &self,
+        input: ImportVolumeRequest,
+    ) -> Result<ImportVolumeResult, RusotoError<ImportVolumeError>> {
+        self.runtime.block_on(self.client.import_volume(input))
+    }
+
+    /// <p>Enables or disables an Availability Zone group for your account.</p> <p>Use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeAvailabilityZones.html"> DescribeAvailabilityZones</a> to view the value for <code>GroupName</code>.</p>
+    ///
+    /// See `Ec2::modify_availability_zone_group`.
+    pub fn modify_availability_zone_group(
+        &self,
+        input: ModifyAvailabilityZoneGroupRequest,
+    ) -> Result<ModifyAvailabilityZoneGroupResult, RusotoError<ModifyAvailabilityZoneGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_availability_zone_group(input))
+    }
+
+    /// <p>Modifies a Capacity Reservation's capacity and the conditions under which it is to be released. You cannot change a Capacity Reservation's instance type, EBS optimization, instance store settings, platform, Availability Zone, or instance eligibility. If you need to modify any of these attributes, we recommend that you cancel the Capacity Reservation, and then create a new one with the required attributes.</p>
+    ///
+    /// See `Ec2::modify_capacity_reservation`.
+    pub fn modify_capacity_reservation(
+        &self,
+        input: ModifyCapacityReservationRequest,
+    ) -> Result<ModifyCapacityReservationResult, RusotoError<ModifyCapacityReservationError>> {
+        self.runtime
+            .block_on(self.client.modify_capacity_reservation(input))
+    }
+
+    /// <p>Modifies the specified Client VPN endpoint. Modifying the DNS server resets existing client connections.</p>
+    ///
+    /// See `Ec2::modify_client_vpn_endpoint`.
+    pub fn modify_client_vpn_endpoint(
+        &self,
+        input: ModifyClientVpnEndpointRequest,
+    ) -> Result<ModifyClientVpnEndpointResult, RusotoError<ModifyClientVpnEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_client_vpn_endpoint(input))
+    }
+
+    /// <p>Modifies the default credit option for CPU usage of burstable performance instances. The default credit option is set at the account level per AWS Region, and is specified per instance family. All new burstable performance instances in the account launch using the default credit option.</p> <p> <code>ModifyDefaultCreditSpecification</code> is an asynchronous operation, which works at an AWS Region level and modifies the credit option for each Availability Zone. All zones in a Region are updated within five minutes. But if instances are launched during this operation, they might not get the new credit option until the zone is updated. To verify whether the update has occurred, you can call <code>GetDefaultCreditSpecification</code> and check <code>DefaultCreditSpecification</code> for updates.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/burstable-performance-instances.html">Burstable performance instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_default_credit_specification`.
+    pub fn modify_default_credit_specification(
+        &self,
+        input: ModifyDefaultCreditSpecificationRequest,
+    ) -> Result<
+        ModifyDefaultCreditSpecificationResult,
+        RusotoError<ModifyDefaultCreditSpecificationError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_default_credit_specification(input))
+    }
+
+    /// <p>Changes the default customer master key (CMK) for EBS encryption by default for your account in this Region.</p> <p>AWS creates a unique AWS managed CMK in each Region for use with encryption by default. If you change the default CMK to a symmetric customer managed CMK, it is used instead of the AWS managed CMK. To reset the default CMK to the AWS managed CMK for EBS, use <a>ResetEbsDefaultKmsKeyId</a>. Amazon EBS does not support asymmetric CMKs.</p> <p>If you delete or disable the customer managed CMK that you specified for use with encryption by default, your instances will fail to launch.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_ebs_default_kms_key_id`.
+    pub fn modify_ebs_default_kms_key_id(
+        &self,
+        input: ModifyEbsDefaultKmsKeyIdRequest,
+    ) -> Result<ModifyEbsDefaultKmsKeyIdResult, RusotoError<ModifyEbsDefaultKmsKeyIdError>> {
+        self.runtime
+            .block_on(self.client.modify_ebs_default_kms_key_id(input))
+    }
+
+    /// <p>Modifies the specified EC2 Fleet.</p> <p>You can only modify an EC2 Fleet request of type <code>maintain</code>.</p> <p>While the EC2 Fleet is being modified, it is in the <code>modifying</code> state.</p> <p>To scale up your EC2 Fleet, increase its target capacity. The EC2 Fleet launches the additional Spot Instances according to the allocation strategy for the EC2 Fleet request. If the allocation strategy is <code>lowest-price</code>, the EC2 Fleet launches instances using the Spot Instance pool with the lowest price. If the allocation strategy is <code>diversified</code>, the EC2 Fleet distributes the instances across the Spot Instance pools. If the allocation strategy is <code>capacity-optimized</code>, EC2 Fleet launches instances from Spot Instance pools with optimal capacity for the number of instances that are launching.</p> <p>To scale down your EC2 Fleet, decrease its target capacity. First, the EC2 Fleet cancels any open requests that exceed the new target capacity. You can request that the EC2 Fleet terminate Spot Instances until the size of the fleet no longer exceeds the new target capacity. If the allocation strategy is <code>lowest-price</code>, the EC2 Fleet terminates the instances with the highest price per unit. If the allocation strategy is <code>capacity-optimized</code>, the EC2 Fleet terminates the instances in the Spot Instance pools that have the least available Spot Instance capacity. If the allocation strategy is <code>diversified</code>, the EC2 Fleet terminates instances across the Spot Instance pools. Alternatively, you can request that the EC2 Fleet keep the fleet at its current size, but not replace any Spot Instances that are interrupted or that you terminate manually.</p> <p>If you are finished with your EC2 Fleet for now, but will use it again later, you can set the target capacity to 0.</p>
+    ///
+    /// See `Ec2::modify_fleet`.
+    pub fn modify_fleet(
+        &self,
+        input: ModifyFleetRequest,
+    ) -> Result<ModifyFleetResult, RusotoError<ModifyFleetError>> {
+        self.runtime.block_on(self.client.modify_fleet(input))
+    }
+
+    /// <p>Modifies the specified attribute of the specified Amazon FPGA Image (AFI).</p>
+    ///
+    /// See `Ec2::modify_fpga_image_attribute`.
+    pub fn modify_fpga_image_attribute(
+        &self,
+        input: ModifyFpgaImageAttributeRequest,
+    ) -> Result<ModifyFpgaImageAttributeResult, RusotoError<ModifyFpgaImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_fpga_image_attribute(input))
+    }
+
+    /// <p>Modify the auto-placement setting of a Dedicated Host. When auto-placement is enabled, any instances that you launch with a tenancy of <code>host</code> but without a specific host ID are placed onto any available Dedicated Host in your account that has auto-placement enabled. When auto-placement is disabled, you need to provide a host ID to have the instance launch onto a specific host. If no host ID is provided, the instance is launched onto a suitable host with auto-placement enabled.</p> <p>You can also use this API action to modify a Dedicated Host to support either multiple instance types in an instance family, or to support a specific instance type only.</p>
+    ///
+    /// See `Ec2::modify_hosts`.
+    pub fn modify_hosts(
+        &self,
+        input: ModifyHostsRequest,
+    ) -> Result<ModifyHostsResult, RusotoError<ModifyHostsError>> {
+        self.runtime.block_on(self.client.modify_hosts(input))
+    }
+
+    /// <p>Modifies the ID format for the specified resource on a per-Region basis. You can specify that resources should receive longer IDs (17-character IDs) when they are created.</p> <p>This request can only be used to modify longer ID settings for resource types that are within the opt-in period. Resources currently in their opt-in period include: <code>bundle</code> | <code>conversion-task</code> | <code>customer-gateway</code> | <code>dhcp-options</code> | <code>elastic-ip-allocation</code> | <code>elastic-ip-association</code> | <code>export-task</code> | <code>flow-log</code> | <code>image</code> | <code>import-task</code> | <code>internet-gateway</code> | <code>network-acl</code> | <code>network-acl-association</code> | <code>network-interface</code> | <code>network-interface-attachment</code> | <code>prefix-list</code> | <code>route-table</code> | <code>route-table-association</code> | <code>security-group</code> | <code>subnet</code> | <code>subnet-cidr-block-association</code> | <code>vpc</code> | <code>vpc-cidr-block-association</code> | <code>vpc-endpoint</code> | <code>vpc-peering-connection</code> | <code>vpn-connection</code> | <code>vpn-gateway</code>.</p> <p>This setting applies to the IAM user who makes the request; it does not apply to the entire AWS account. By default, an IAM user defaults to the same settings as the root user. If you're using this action as the root user, then these settings apply to the entire account, unless an IAM user explicitly overrides these settings for themselves. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/resource-ids.html">Resource IDs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>Resources created with longer IDs are visible to all IAM roles and users, regardless of these settings and provided that they have permission to use the relevant <code>Describe</code> command for the resource type.</p>
+    ///
+    /// See `Ec2::modify_id_format`.
+    pub fn modify_id_format(
+        &self,
+        input: ModifyIdFormatRequest,
+    ) -> Result<(), RusotoError<ModifyIdFormatError>> {
+        self.runtime.block_on(self.client.modify_id_format(input))
+    }
+
+    /// <p>Modifies the ID format of a resource for a specified IAM user, IAM role, or the root user for an account; or all IAM users, IAM roles, and the root user for an account. You can specify that resources should receive longer IDs (17-character IDs) when they are created. </p> <p>This request can only be used to modify longer ID settings for resource types that are within the opt-in period. Resources currently in their opt-in period include: <code>bundle</code> | <code>conversion-task</code> | <code>customer-gateway</code> | <code>dhcp-options</code> | <code>elastic-ip-allocation</code> | <code>elastic-ip-association</code> | <code>export-task</code> | <code>flow-log</code> | <code>image</code> | <code>import-task</code> | <code>internet-gateway</code> | <code>network-acl</code> | <code>network-acl-association</code> | <code>network-interface</code> | <code>network-interface-attachment</code> | <code>prefix-list</code> | <code>route-table</code> | <code>route-table-association</code> | <code>security-group</code> | <code>subnet</code> | <code>subnet-cidr-block-association</code> | <code>vpc</code> | <code>vpc-cidr-block-association</code> | <code>vpc-endpoint</code> | <code>vpc-peering-connection</code> | <code>vpn-connection</code> | <code>vpn-gateway</code>. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/resource-ids.html">Resource IDs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>. </p> <p>This setting applies to the principal specified in the request; it does not apply to the principal that makes the request. </p> <p>Resources created with longer IDs are visible to all IAM roles and users, regardless of these settings and provided that they have permission to use the relevant <code>Describe</code> command for the resource type.</p>
+    ///
+    /// See `Ec2::modify_identity_id_format`.
+    pub fn modify_identity_id_format(
+        &self,
+        input: ModifyIdentityIdFormatRequest,
+    ) -> Result<(), RusotoError<ModifyIdentityIdFormatError>> {
+        self.runtime
+            .block_on(self.client.modify_identity_id_format(input))
+    }
+
+    /// <p>Modifies the specified attribute of the specified AMI. You can specify only one attribute at a time. You can use the <code>Attribute</code> parameter to specify the attribute or one of the following parameters: <code>Description</code>, <code>LaunchPermission</code>, or <code>ProductCode</code>.</p> <p>AWS Marketplace product codes cannot be modified. Images with an AWS Marketplace product code cannot be made public.</p> <p>To enable the SriovNetSupport enhanced networking attribute of an image, enable SriovNetSupport on an instance and create an AMI from the instance.</p>
+    ///
+    /// See `Ec2::modify_image_attribute`.
+    pub fn modify_image_attribute(
+        &self,
+        input: ModifyImageAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_image_attribute(input))
+    }
+
+    /// <p>Modifies the specified attribute of the specified instance. You can specify only one attribute at a time.</p> <p> <b>Note: </b>Using this action to change the security groups associated with an elastic network interface (ENI) attached to an instance in a VPC can result in an error if the instance has more than one ENI. To change the security groups associated with an ENI attached to an instance that has multiple ENIs, we recommend that you use the <a>ModifyNetworkInterfaceAttribute</a> action.</p> <p>To modify some attributes, the instance must be stopped. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_ChangingAttributesWhileInstanceStopped.html">Modifying attributes of a stopped instance</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_instance_attribute`.
+    pub fn modify_instance_attribute(
+        &self,
+        input: ModifyInstanceAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_attribute(input))
+    }
+
+    /// <p>Modifies the Capacity Reservation settings for a stopped instance. Use this action to configure an instance to target a specific Capacity Reservation, run in any <code>open</code> Capacity Reservation with matching attributes, or run On-Demand Instance capacity.</p>
+    ///
+    /// See `Ec2::modify_instance_capacity_reservation_attributes`.
+    pub fn modify_instance_capacity_reservation_attributes(
+        &self,
+        input: ModifyInstanceCapacityReservationAttributesRequest,
+    ) -> Result<
+        ModifyInstanceCapacityReservationAttributesResult,
+        RusotoError<ModifyInstanceCapacityReservationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_instance_capacity_reservation_attributes(input),
+        )
+    }
+
+    /// <p>Modifies the credit option for CPU usage on a running or stopped burstable performance instance. The credit options are <code>standard</code> and <code>unlimited</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/burstable-performance-instances.html">Burstable performance instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_instance_credit_specification`.
+    pub fn modify_instance_credit_specification(
+        &self,
+        input: ModifyInstanceCreditSpecificationRequest,
+    ) -> Result<
+        ModifyInstanceCreditSpecificationResult,
+        RusotoError<ModifyInstanceCreditSpecificationError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_instance_credit_specification(input))
+    }
+
+    /// <p>Modifies the start time for a scheduled Amazon EC2 instance event.</p>
+    ///
+    /// See `Ec2::modify_instance_event_start_time`.
+    pub fn modify_instance_event_start_time(
+        &self,
+        input: ModifyInstanceEventStartTimeRequest,
+    ) -> Result<ModifyInstanceEventStartTimeResult, RusotoError<ModifyInstanceEventStartTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_instance_event_start_time(input))
+    }
+
+    /// <p>Modify the instance metadata parameters on a running or stopped instance. When you modify the parameters on a stopped instance, they are applied when the instance is started. When you modify the parameters on a running instance, the API responds with a state of “pending”. After the parameter modifications are successfully applied to the instance, the state of the modifications changes from “pending” to “applied” in subsequent describe-instances API calls. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-instance-metadata.html">Instance metadata and user data</a>.</p>
+    ///
+    /// See `Ec2::modify_instance_metadata_options`.
+    pub fn modify_instance_metadata_options(
+        &self,
+        input: ModifyInstanceMetadataOptionsRequest,
+    ) -> Result<ModifyInstanceMetadataOptionsResult, RusotoError<ModifyInstanceMetadataOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_instance_metadata_options(input))
+    }
+
+    /// <p>Modifies the placement attributes for a specified instance. You can do the following:</p> <ul> <li> <p>Modify the affinity between an instance and a <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/dedicated-hosts-overview.html">Dedicated Host</a>. When affinity is set to <code>host</code> and the instance is not associated with a specific Dedicated Host, the next time the instance is launched, it is automatically associated with the host on which it lands. If the instance is restarted or rebooted, this relationship persists.</p> </li> <li> <p>Change the Dedicated Host with which an instance is associated.</p> </li> <li> <p>Change the instance tenancy of an instance from <code>host</code> to <code>dedicated</code>, or from <code>dedicated</code> to <code>host</code>.</p> </li> <li> <p>Move an instance to or from a <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/placement-groups.html">placement group</a>.</p> </li> </ul> <p>At least one attribute for affinity, host ID, tenancy, or placement group name must be specified in the request. Affinity and tenancy can be modified in the same request.</p> <p>To modify the host ID, tenancy, placement group, or partition for an instance, the instance must be in the <code>stopped</code> state.</p>
+    ///
+    /// See `Ec2::modify_instance_placement`.
+    pub fn modify_instance_placement(
+        &self,
+        input: ModifyInstancePlacementRequest,
+    ) -> Result<ModifyInstancePlacementResult, RusotoError<ModifyInstancePlacementError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_placement(input))
+    }
+
+    /// <p>Modifies a launch template. You can specify which version of the launch template to set as the default version. When launching an instance, the default version applies when a launch template version is not specified.</p>
+    ///
+    /// See `Ec2::modify_launch_template`.
+    pub fn modify_launch_template(
+        &self,
+        input: ModifyLaunchTemplateRequest,
+    ) -> Result<ModifyLaunchTemplateResult, RusotoError<ModifyLaunchTemplateError>> {
+        self.runtime
+            .block_on(self.client.modify_launch_template(input))
+    }
+
+    /// <p>Modifies the specified managed prefix list.</p> <p>Adding or removing entries in a prefix list creates a new version of the prefix list. Changing the name of the prefix list does not affect the version.</p> <p>If you specify a current version number that does not match the true current version number, the request fails.</p>
+    ///
+    /// See `Ec2::modify_managed_prefix_list`.
+    pub fn modify_managed_prefix_list(
+        &self,
+        input: ModifyManagedPrefixListRequest,
+    ) -> Result<ModifyManagedPrefixListResult, RusotoError<ModifyManagedPrefixListError>> {
+        self.runtime
+            .block_on(self.client.modify_managed_prefix_list(input))
+    }
+
+    /// <p>Modifies the specified network interface attribute. You can specify only one attribute at a time. You can use this action to attach and detach security groups from an existing EC2 instance.</p>
+    ///
+    /// See `Ec2::modify_network_interface_attribute`.
+    pub fn modify_network_interface_attribute(
+        &self,
+        input: ModifyNetworkInterfaceAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyNetworkInterfaceAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_network_interface_attribute(input))
+    }
+
+    /// <p>Modifies the Availability Zone, instance count, instance type, or network platform (EC2-Classic or EC2-VPC) of your Reserved Instances. The Reserved Instances to be modified must be identical, except for Availability Zone, network platform, and instance type.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-modifying.html">Modifying Reserved Instances</a> in the Amazon Elastic Compute Cloud User Guide.</p>
+    ///
+    /// See `Ec2::modify_reserved_instances`.
+    pub fn modify_reserved_instances(
+        &self,
+        input: ModifyReservedInstancesRequest,
+    ) -> Result<ModifyReservedInstancesResult, RusotoError<ModifyReservedInstancesError>> {
+        self.runtime
+            .block_on(self.client.modify_reserved_instances(input))
+    }
+
+    /// <p>Adds or removes permission settings for the specified snapshot. You may add or remove specified AWS account IDs from a snapshot's list of create volume permissions, but you cannot do both in a single operation. If you need to both add and remove account IDs for a snapshot, you must use multiple operations. You can make up to 500 modifications to a snapshot in a single operation.</p> <p>Encrypted snapshots and snapshots with AWS Marketplace product codes cannot be made public. Snapshots encrypted with your default CMK cannot be shared with other accounts.</p> <p>For more information about modifying snapshot permissions, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-modifying-snapshot-permissions.html">Sharing Snapshots</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_snapshot_attribute`.
+    pub fn modify_snapshot_attribute(
+        &self,
+        input: ModifySnapshotAttributeRequest,
+    ) -> Result<(), RusotoError<ModifySnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_snapshot_attribute(input))
+    }
+
+    /// <p>Modifies the specified Spot Fleet request.</p> <p>You can only modify a Spot Fleet request of type <code>maintain</code>.</p> <p>While the Spot Fleet request is being modified, it is in the <code>modifying</code> state.</p> <p>To scale up your Spot Fleet, increase its target capacity. The Spot Fleet launches the additional Spot Instances according to the allocation strategy for the Spot Fleet request. If the allocation strategy is <code>lowestPrice</code>, the Spot Fleet launches instances using the Spot Instance pool with the lowest price. If the allocation strategy is <code>diversified</code>, the Spot Fleet distributes the instances across the Spot Instance pools. If the allocation strategy is <code>capacityOptimized</code>, Spot Fleet launches instances from Spot Instance pools with optimal capacity for the number of instances that are launching.</p> <p>To scale down your Spot Fleet, decrease its target capacity. First, the Spot Fleet cancels any open requests that exceed the new target capacity. You can request that the Spot Fleet terminate Spot Instances until the size of the fleet no longer exceeds the new target capacity. If the allocation strategy is <code>lowestPrice</code>, the Spot Fleet terminates the instances with the highest price per unit. If the allocation strategy is <code>capacityOptimized</code>, the Spot Fleet terminates the instances in the Spot Instance pools that have the least available Spot Instance capacity. If the allocation strategy is <code>diversified</code>, the Spot Fleet terminates instances across the Spot Instance pools. Alternatively, you can request that the Spot Fleet keep the fleet at its current size, but not replace any Spot Instances that are interrupted or that you terminate manually.</p> <p>If you are finished with your Spot Fleet for now, but will use it again later, you can set the target capacity to 0.</p>
+    ///
+    /// See `Ec2::modify_spot_fleet_request`.
+    pub fn modify_spot_fleet_request(
+        &self,
+        input: ModifySpotFleetRequestRequest,
+    ) -> Result<ModifySpotFleetRequestResponse, RusotoError<ModifySpotFleetRequestError>> {
+        self.runtime
+            .block_on(self.client.modify_spot_fleet_request(input))
+    }
+
+    /// <p>Modifies a subnet attribute. You can only modify one attribute at a time.</p>
+    ///
+    /// See `Ec2::modify_subnet_attribute`.
+    pub fn modify_subnet_attribute(
+        &self,
+        input: ModifySubnetAttributeRequest,
+    ) -> Result<(), RusotoError<ModifySubnetAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_subnet_attribute(input))
+    }
+
+    /// <p>Allows or restricts mirroring network services.</p> <p> By default, Amazon DNS network services are not eligible for Traffic Mirror. Use <code>AddNetworkServices</code> to add network services to a Traffic Mirror filter. When a network service is added to the Traffic Mirror filter, all traffic related to that network service will be mirrored. When you no longer want to mirror network services, use <code>RemoveNetworkServices</code> to remove the network services from the Traffic Mirror filter. </p> <p>For information about filter rule properties, see <a href="https://docs.aws.amazon.com/vpc/latest/mirroring/traffic-mirroring-considerations.html">Network Services</a> in the <i>Traffic Mirroring User Guide </i>.</p>
+    ///
+    /// See `Ec2::modify_traffic_mirror_filter_network_services`.
+    pub fn modify_traffic_mirror_filter_network_services(
+        &self,
+        input: ModifyTrafficMirrorFilterNetworkServicesRequest,
+    ) -> Result<
+        ModifyTrafficMirrorFilterNetworkServicesResult,
+        RusotoError<ModifyTrafficMirrorFilterNetworkServicesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_traffic_mirror_filter_network_services(input),
+        )
+    }
+
+    /// <p>Modifies the specified Traffic Mirror rule.</p> <p> <code>DestinationCidrBlock</code> and <code>SourceCidrBlock</code> must both be an IPv4 range or an IPv6 range.</p>
+    ///
+    /// See `Ec2::modify_traffic_mirror_filter_rule`.
+    pub fn modify_traffic_mirror_filter_rule(
+        &self,
+        input: ModifyTrafficMirrorFilterRuleRequest,
+    ) -> Result<ModifyTrafficMirrorFilterRuleResult, RusotoError<ModifyTrafficMirrorFilterRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_traffic_mirror_filter_rule(input))
+    }
+
+    /// <p>Modifies a Traffic Mirror session.</p>
+    ///
+    /// See `Ec2::modify_traffic_mirror_session`.
+    pub fn modify_traffic_mirror_session(
+        &self,
+        input: ModifyTrafficMirrorSessionRequest,
+    ) -> Result<ModifyTrafficMirrorSessionResult, RusotoError<ModifyTrafficMirrorSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_traffic_mirror_session(input))
+    }
+
+    /// <p>Modifies the specified VPC attachment.</p>
+    ///
+    /// See `Ec2::modify_transit_gateway_vpc_attachment`.
+    pub fn modify_transit_gateway_vpc_attachment(
+        &self,
+        input: ModifyTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        ModifyTransitGatewayVpcAttachmentResult,
+        RusotoError<ModifyTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_transit_gateway_vpc_attachment(input))
+    }
+
+    /// <p>You can modify several parameters of an existing EBS volume, including volume size, volume type, and IOPS capacity. If your EBS volume is attached to a current-generation EC2 instance type, you may be able to apply these changes without stopping the instance or detaching the volume from it. For more information about modifying an EBS volume running Linux, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-expand-volume.html">Modifying the Size, IOPS, or Type of an EBS Volume on Linux</a>. For more information about modifying an EBS volume running Windows, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/ebs-expand-volume.html">Modifying the Size, IOPS, or Type of an EBS Volume on Windows</a>. </p> <p> When you complete a resize operation on your volume, you need to extend the volume's file-system size to take advantage of the new storage capacity. For information about extending a Linux file system, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-expand-volume.html#recognize-expanded-volume-linux">Extending a Linux File System</a>. For information about extending a Windows file system, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/ebs-expand-volume.html#recognize-expanded-volume-windows">Extending a Windows File System</a>. </p> <p> You can use CloudWatch Events to check the status of a modification to an EBS volume. For information about CloudWatch Events, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/events/">Amazon CloudWatch Events User Guide</a>. You can also track the status of a modification using <a>DescribeVolumesModifications</a>. For information about tracking status changes using either method, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-expand-volume.html#monitoring_mods">Monitoring Volume Modifications</a>. </p> <p>With previous-generation instance types, resizing an EBS volume may require detaching and reattaching the volume or stopping and restarting the instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-expand-volume.html">Modifying the Size, IOPS, or Type of an EBS Volume on Linux</a> and <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/ebs-expand-volume.html">Modifying the Size, IOPS, or Type of an EBS Volume on Windows</a>.</p> <p>If you reach the maximum volume modification rate per volume limit, you will need to wait at least six hours before applying further modifications to the affected EBS volume.</p>
+    ///
+    /// See `Ec2::modify_volume`.
+    pub fn modify_volume(
+        &self,
+        input: ModifyVolumeRequest,
+    ) -> Result<ModifyVolumeResult, RusotoError<ModifyVolumeError>> {
+        self.runtime.block_on(self.client.modify_volume(input))
+    }
+
+    /// <p>Modifies a volume attribute.</p> <p>By default, all I/O operations for the volume are suspended when the data on the volume is determined to be potentially inconsistent, to prevent undetectable, latent data corruption. The I/O access to the volume can be resumed by first enabling I/O access and then checking the data consistency on your volume.</p> <p>You can change the default behavior to resume I/O operations. We recommend that you change this only for boot volumes or for volumes that are stateless or disposable.</p>
+    ///
+    /// See `Ec2::modify_volume_attribute`.
+    pub fn modify_volume_attribute(
+        &self,
+        input: ModifyVolumeAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyVolumeAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_volume_attribute(input))
+    }
+
+    /// <p>Modifies the specified attribute of the specified VPC.</p>
+    ///
+    /// See `Ec2::modify_vpc_attribute`.
+    pub fn modify_vpc_attribute(
+        &self,
+        input: ModifyVpcAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyVpcAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_vpc_attribute(input))
+    }
+
+    /// <p>Modifies attributes of a specified VPC endpoint. The attributes that you can modify depend on the type of VPC endpoint (interface or gateway). For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/vpc-endpoints.html">VPC Endpoints</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_vpc_endpoint`.
+    pub fn modify_vpc_endpoint(
+        &self,
+        input: ModifyVpcEndpointRequest,
+    ) -> Result<ModifyVpcEndpointResult, RusotoError<ModifyVpcEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_vpc_endpoint(input))
+    }
+
+    /// <p>Modifies a connection notification for VPC endpoint or VPC endpoint service. You can change the SNS topic for the notification, or the events for which to be notified. </p>
+    ///
+    /// See `Ec2::modify_vpc_endpoint_connection_notification`.
+    pub fn modify_vpc_endpoint_connection_notification(
+        &self,
+        input: ModifyVpcEndpointConnectionNotificationRequest,
+    ) -> Result<
+        ModifyVpcEndpointConnectionNotificationResult,
+        RusotoError<ModifyVpcEndpointConnectionNotificationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_vpc_endpoint_connection_notification(input),
+        )
+    }
+
+    /// <p>Modifies the attributes of your VPC endpoint service configuration. You can change the Network Load Balancers for your service, and you can specify whether acceptance is required for requests to connect to your endpoint service through an interface VPC endpoint.</p> <p>If you set or modify the private DNS name, you must prove that you own the private DNS domain name. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/endpoint-services-dns-validation.html">VPC Endpoint Service Private DNS Name Verification</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_vpc_endpoint_service_configuration`.
+    pub fn modify_vpc_endpoint_service_configuration(
+        &self,
+        input: ModifyVpcEndpointServiceConfigurationRequest,
+    ) -> Result<
+        ModifyVpcEndpointServiceConfigurationResult,
+        RusotoError<ModifyVpcEndpointServiceConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_vpc_endpoint_service_configuration(input))
+    }
+
+    /// <p>Modifies the permissions for your <a href="https://docs.aws.amazon.com/vpc/latest/userguide/endpoint-service.html">VPC endpoint service</a>. You can add or remove permissions for service consumers (IAM users, IAM roles, and AWS accounts) to connect to your endpoint service.</p> <p>If you grant permissions to all principals, the service is public. Any users who know the name of a public service can send a request to attach an endpoint. If the service does not require manual approval, attachments are automatically approved.</p>
+    ///
+    /// See `Ec2::modify_vpc_endpoint_service_permissions`.
+    pub fn modify_vpc_endpoint_service_permissions(
+        &self,
+        input: ModifyVpcEndpointServicePermissionsRequest,
+    ) -> Result<
+        ModifyVpcEndpointServicePermissionsResult,
+        RusotoError<ModifyVpcEndpointServicePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_vpc_endpoint_service_permissions(input))
+    }
+
+    /// <p>Modifies the VPC peering connection options on one side of a VPC peering connection. You can do the following:</p> <ul> <li> <p>Enable/disable communication over the peering connection between an EC2-Classic instance that's linked to your VPC (using ClassicLink) and instances in the peer VPC.</p> </li> <li> <p>Enable/disable communication over the peering connection between instances in your VPC and an EC2-Classic instance that's linked to the peer VPC.</p> </li> <li> <p>Enable/disable the ability to resolve public DNS hostnames to private IP addresses when queried from instances in the peer VPC.</p> </li> </ul> <p>If the peered VPCs are in the same AWS account, you can enable DNS resolution for queries from the local VPC. This ensures that queries from the local VPC resolve to private IP addresses in the peer VPC. This option is not available if the peered VPCs are in different AWS accounts or different Regions. For peered VPCs in different AWS accounts, each AWS account owner must initiate a separate request to modify the peering connection options. For inter-region peering connections, you must use the Region for the requester VPC to modify the requester VPC peering options and the Region for the accepter VPC to modify the accepter VPC peering options. To verify which VPCs are the accepter and the requester for a VPC peering connection, use the <a>DescribeVpcPeeringConnections</a> command.</p>
+    ///
+    /// See `Ec2::modify_vpc_peering_connection_options`.
+    pub fn modify_vpc_peering_connection_options(
+        &self,
+        input: ModifyVpcPeeringConnectionOptionsRequest,
+    ) -> Result<
+        ModifyVpcPeeringConnectionOptionsResult,
+        RusotoError<ModifyVpcPeeringConnectionOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_vpc_peering_connection_options(input))
+    }
+
+    /// <p>Modifies the instance tenancy attribute of the specified VPC. You can change the instance tenancy attribute of a VPC to <code>default</code> only. You cannot change the instance tenancy attribute to <code>dedicated</code>.</p> <p>After you modify the tenancy of the VPC, any new instances that you launch into the VPC have a tenancy of <code>default</code>, unless you specify otherwise during launch. The tenancy of any existing instances in the VPC is not affected.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/dedicated-instance.html">Dedicated Instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_vpc_tenancy`.
+    pub fn modify_vpc_tenancy(
+        &self,
+        input: ModifyVpcTenancyRequest,
+    ) -> Result<ModifyVpcTenancyResult, RusotoError<ModifyVpcTenancyError>> {
+        self.runtime.block_on(self.client.modify_vpc_tenancy(input))
+    }
+
+    /// <p>Modifies the customer gateway or the target gateway of an AWS Site-to-Site VPN connection. To modify the target gateway, the following migration options are available:</p> <ul> <li> <p>An existing virtual private gateway to a new virtual private gateway</p> </li> <li> <p>An existing virtual private gateway to a transit gateway</p> </li> <li> <p>An existing transit gateway to a new transit gateway</p> </li> <li> <p>An existing transit gateway to a virtual private gateway</p> </li> </ul> <p>Before you perform the migration to the new gateway, you must configure the new gateway. Use <a>CreateVpnGateway</a> to create a virtual private gateway, or <a>CreateTransitGateway</a> to create a transit gateway.</p> <p>This step is required when you migrate from a virtual private gateway with static routes to a transit gateway. </p> <p>You must delete the static routes before you migrate to the new gateway.</p> <p>Keep a copy of the static route before you delete it. You will need to add back these routes to the transit gateway after the VPN connection migration is complete.</p> <p>After you migrate to the new gateway, you might need to modify your VPC route table. Use <a>CreateRoute</a> and <a>DeleteRoute</a> to make the changes described in <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/modify-vpn-target.html#step-update-routing">VPN Gateway Target Modification Required VPC Route Table Updates</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p> <p> When the new gateway is a transit gateway, modify the transit gateway route table to allow traffic between the VPC and the AWS Site-to-Site VPN connection. Use <a>CreateTransitGatewayRoute</a> to add the routes.</p> <p> If you deleted VPN static routes, you must add the static routes to the transit gateway route table.</p> <p>After you perform this operation, the AWS VPN endpoint's IP addresses on the AWS side and the tunnel options remain intact. Your AWS Site-to-Site VPN connection will be temporarily unavailable for a brief period while we provision the new endpoints.</p>
+    ///
+    /// See `Ec2::modify_vpn_connection`.
+    pub fn modify_vpn_connection(
+        &self,
+        input: ModifyVpnConnectionRequest,
+    ) -> Result<ModifyVpnConnectionResult, RusotoError<ModifyVpnConnectionError>> {
+        self.runtime
+            .block_on(self.client.modify_vpn_connection(input))
+    }
+
+    /// <p>Modifies the VPN tunnel endpoint certificate.</p>
+    ///
+    /// See `Ec2::modify_vpn_tunnel_certificate`.
+    pub fn modify_vpn_tunnel_certificate(
+        &self,
+        input: ModifyVpnTunnelCertificateRequest,
+    ) -> Result<ModifyVpnTunnelCertificateResult, RusotoError<ModifyVpnTunnelCertificateError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_vpn_tunnel_certificate(input))
+    }
+
+    /// <p>Modifies the options for a VPN tunnel in an AWS Site-to-Site VPN connection. You can modify multiple options for a tunnel in a single request, but you can only modify one tunnel at a time. For more information, see <a href="https://docs.aws.amazon.com/vpn/latest/s2svpn/VPNTunnels.html">Site-to-Site VPN Tunnel Options for Your Site-to-Site VPN Connection</a> in the <i>AWS Site-to-Site VPN User Guide</i>.</p>
+    ///
+    /// See `Ec2::modify_vpn_tunnel_options`.
+    pub fn modify_vpn_tunnel_options(
+        &self,
+        input: ModifyVpnTunnelOptionsRequest,
+    ) -> Result<ModifyVpnTunnelOptionsResult, RusotoError<ModifyVpnTunnelOptionsError>> {
+        self.runtime
+            .block_on(self.client.modify_vpn_tunnel_options(input))
+    }
+
+    /// <p>Enables detailed monitoring for a running instance. Otherwise, basic monitoring is enabled. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-cloudwatch.html">Monitoring your instances and volumes</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>To disable detailed monitoring, see .</p>
+    ///
+    /// See `Ec2::monitor_instances`.
+    pub fn monitor_instances(
+        &self,
+        input: MonitorInstancesRequest,
+    ) -> Result<MonitorInstancesResult, RusotoError<MonitorInstancesError>> {
+        self.runtime.block_on(self.client.monitor_instances(input))
+    }
+
+    /// <p>Moves an Elastic IP address from the EC2-Classic platform to the EC2-VPC platform. The Elastic IP address must be allocated to your account for more than 24 hours, and it must not be associated with an instance. After the Elastic IP address is moved, it is no longer available for use in the EC2-Classic platform, unless you move it back using the <a>RestoreAddressToClassic</a> request. You cannot move an Elastic IP address that was originally allocated for use in the EC2-VPC platform to the EC2-Classic platform. </p>
+    ///
+    /// See `Ec2::move_address_to_vpc`.
+    pub fn move_address_to_vpc(
+        &self,
+        input: MoveAddressToVpcRequest,
+    ) -> Result<MoveAddressToVpcResult, RusotoError<MoveAddressToVpcError>> {
+        self.runtime
+            .block_on(self.client.move_address_to_vpc(input))
+    }
+
+    /// <p>Provisions an IPv4 or IPv6 address range for use with your AWS resources through bring your own IP addresses (BYOIP) and creates a corresponding address pool. After the address range is provisioned, it is ready to be advertised using <a>AdvertiseByoipCidr</a>.</p> <p>AWS verifies that you own the address range and are authorized to advertise it. You must ensure that the address range is registered to you and that you created an RPKI ROA to authorize Amazon ASNs 16509 and 14618 to advertise the address range. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-byoip.html">Bring Your Own IP Addresses (BYOIP)</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>Provisioning an address range is an asynchronous operation, so the call returns immediately, but the address range is not ready to use until its status changes from <code>pending-provision</code> to <code>provisioned</code>. To monitor the status of an address range, use <a>DescribeByoipCidrs</a>. To allocate an Elastic IP address from your IPv4 address pool, use <a>AllocateAddress</a> with either the specific address from the address pool or the ID of the address pool.</p>
+    ///
+    /// See `Ec2::provision_byoip_cidr`.
+    pub fn provision_byoip_cidr(
+        &self,
+        input: ProvisionByoipCidrRequest,
+    ) -> Result<ProvisionByoipCidrResult, RusotoError<ProvisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.provision_byoip_cidr(input))
+    }
+
+    /// <p>Purchase a reservation with configurations that match those of your Dedicated Host. You must have active Dedicated Hosts in your account before you purchase a reservation. This action results in the specified reservation being purchased and charged to your account.</p>
+    ///
+    /// See `Ec2::purchase_host_reservation`.
+    pub fn purchase_host_reservation(
+        &self,
+        input: PurchaseHostReservationRequest,
+    ) -> Result<PurchaseHostReservationResult, RusotoError<PurchaseHostReservationError>> {
+        self.runtime
+            .block_on(self.client.purchase_host_reservation(input))
+    }
+
+    /// <p>Purchases a Reserved Instance for use with your account. With Reserved Instances, you pay a lower hourly rate compared to On-Demand instance pricing.</p> <p>Use <a>DescribeReservedInstancesOfferings</a> to get a list of Reserved Instance offerings that match your specifications. After you've purchased a Reserved Instance, you can check for your new Reserved Instance with <a>DescribeReservedInstances</a>.</p> <p>To queue a purchase for a future date and time, specify a purchase time. If you do not specify a purchase time, the default is the current time.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/concepts-on-demand-reserved-instances.html">Reserved Instances</a> and <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ri-market-general.html">Reserved Instance Marketplace</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::purchase_reserved_instances_offering`.
+    pub fn purchase_reserved_instances_offering(
+        &self,
+        input: PurchaseReservedInstancesOfferingRequest,
+    ) -> Result<
+        PurchaseReservedInstancesOfferingResult,
+        RusotoError<PurchaseReservedInstancesOfferingError>,
+    > {
+        self.runtime
+            .block_on(self.client.purchase_reserved_instances_offering(input))
+    }
+
+    /// <p>Purchases the Scheduled Instances with the specified schedule.</p> <p>Scheduled Instances enable you to purchase Amazon EC2 compute capacity by the hour for a one-year term. Before you can purchase a Scheduled Instance, you must call <a>DescribeScheduledInstanceAvailability</a> to check for available schedules and obtain a purchase token. After you purchase a Scheduled Instance, you must call <a>RunScheduledInstances</a> during each scheduled time period.</p> <p>After you purchase a Scheduled Instance, you can't cancel, modify, or resell your purchase.</p>
+    ///
+    /// See `Ec2::purchase_scheduled_instances`.
+    pub fn purchase_scheduled_instances(
+        &self,
+        input: PurchaseScheduledInstancesRequest,
+    ) -> Result<PurchaseScheduledInstancesResult, RusotoError<PurchaseScheduledInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.purchase_scheduled_instances(input))
+    }
+
+    /// <p>Requests a reboot of the specified instances. This operation is asynchronous; it only queues a request to reboot the specified instances. The operation succeeds if the instances are valid and belong to you. Requests to reboot terminated instances are ignored.</p> <p>If an instance does not cleanly shut down within four minutes, Amazon EC2 performs a hard reboot.</p> <p>For more information about troubleshooting, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-console.html">Getting console output and rebooting instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::reboot_instances`.
+    pub fn reboot_instances(
+        &self,
+        input: RebootInstancesRequest,
+    ) -> Result<(), RusotoError<RebootInstancesError>> {
+        self.runtime.block_on(self.client.reboot_instances(input))
+    }
+
+    /// <p>Registers an AMI. When you're creating an AMI, this is the final step you must complete before you can launch an instance from the AMI. For more information about creating AMIs, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/creating-an-ami.html">Creating Your Own AMIs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <note> <p>For Amazon EBS-backed instances, <a>CreateImage</a> creates and registers the AMI in a single request, so you don't have to register the AMI yourself.</p> </note> <p>You can also use <code>RegisterImage</code> to create an Amazon EBS-backed Linux AMI from a snapshot of a root device volume. You specify the snapshot using the block device mapping. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-launch-snapshot.html">Launching a Linux Instance from a Backup</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>You can't register an image where a secondary (non-root) snapshot has AWS Marketplace product codes.</p> <p>Windows and some Linux distributions, such as Red Hat Enterprise Linux (RHEL) and SUSE Linux Enterprise Server (SLES), use the EC2 billing product code associated with an AMI to verify the subscription status for package updates. To create a new AMI for operating systems that require a billing product code, instead of registering the AMI, do the following to preserve the billing product code association:</p> <ol> <li> <p>Launch an instance from an existing AMI with that billing product code.</p> </li> <li> <p>Customize the instance.</p> </li> <li> <p>Create an AMI from the instance using <a>CreateImage</a>.</p> </li> </ol> <p>If you purchase a Reserved Instance to apply to an On-Demand Instance that was launched from an AMI with a billing product code, make sure that the Reserved Instance has the matching billing product code. If you purchase a Reserved Instance without the matching billing product code, the Reserved Instance will not be applied to the On-Demand Instance. For information about how to obtain the platform details and billing information of an AMI, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ami-billing-info.html">Obtaining Billing Information</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>If needed, you can deregister an AMI at any time. Any modifications you make to an AMI backed by an instance store volume invalidates its registration. If you make changes to an image, deregister the previous image and register the new image.</p>
+    ///
+    /// See `Ec2::register_image`.
+    pub fn register_image(
+        &self,
+        input: RegisterImageRequest,
+    ) -> Result<RegisterImageResult, RusotoError<RegisterImageError>> {
+        self.runtime.block_on(self.client.register_image(input))
+    }
+
+    /// <p>Registers a set of tag keys to include in scheduled event notifications for your resources. </p> <p>To remove tags, use .</p>
+    ///
+    /// See `Ec2::register_instance_event_notification_attributes`.
+    pub fn register_instance_event_notification_attributes(
+        &self,
+        input: RegisterInstanceEventNotificationAttributesRequest,
+    ) -> Result<
+        RegisterInstanceEventNotificationAttributesResult,
+        RusotoError<RegisterInstanceEventNotificationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .register_instance_event_notification_attributes(input),
+        )
+    }
+
+    /// <p>Registers members (network interfaces) with the transit gateway multicast group. A member is a network interface associated with a supported EC2 instance that receives multicast traffic. For information about supported instances, see <a href="https://docs.aws.amazon.com/vpc/latest/tgw/transit-gateway-limits.html#multicast-limits">Multicast Consideration</a> in <i>Amazon VPC Transit Gateways</i>.</p> <p>After you add the members, use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_SearchTransitGatewayMulticastGroups.html">SearchTransitGatewayMulticastGroups</a> to verify that the members were added to the transit gateway multicast group.</p>
+    ///
+    /// See `Ec2::register_transit_gateway_multicast_group_members`.
+    pub fn register_transit_gateway_multicast_group_members(
+        &self,
+        input: RegisterTransitGatewayMulticastGroupMembersRequest,
+    ) -> Result<
+        RegisterTransitGatewayMulticastGroupMembersResult,
+        RusotoError<RegisterTransitGatewayMulticastGroupMembersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .register_transit_gateway_multicast_group_members(input),
+        )
+    }
+
+    /// <p>Registers sources (network interfaces) with the specified transit gateway multicast group.</p> <p>A multicast source is a network interface attached to a supported instance that sends multicast traffic. For information about supported instances, see <a href="https://docs.aws.amazon.com/vpc/latest/tgw/transit-gateway-limits.html#multicast-limits">Multicast Considerations</a> in <i>Amazon VPC Transit Gateways</i>.</p> <p>After you add the source, use <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_SearchTransitGatewayMulticastGroups.html">SearchTransitGatewayMulticastGroups</a> to verify that the source was added to the multicast group.</p>
+    ///
+    /// See `Ec2::register_transit_gateway_multicast_group_sources`.
+    pub fn register_transit_gateway_multicast_group_sources(
+        &self,
+        input: RegisterTransitGatewayMulticastGroupSourcesRequest,
+    ) -> Result<
+        RegisterTransitGatewayMulticastGroupSourcesResult,
+        RusotoError<RegisterTransitGatewayMulticastGroupSourcesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .register_transit_gateway_multicast_group_sources(input),
+        )
+    }
+
+    /// <p>Rejects a transit gateway peering attachment request.</p>
+    ///
+    /// See `Ec2::reject_transit_gateway_peering_attachment`.
+    pub fn reject_transit_gateway_peering_attachment(
+        &self,
+        input: RejectTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        RejectTransitGatewayPeeringAttachmentResult,
+        RusotoError<RejectTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.reject_transit_gateway_peering_attachment(input))
+    }
+
+    /// <p>Rejects a request to attach a VPC to a transit gateway.</p> <p>The VPC attachment must be in the <code>pendingAcceptance</code> state. Use <a>DescribeTransitGatewayVpcAttachments</a> to view your pending VPC attachment requests. Use <a>AcceptTransitGatewayVpcAttachment</a> to accept a VPC attachment request.</p>
+    ///
+    /// See `Ec2::reject_transit_gateway_vpc_attachment`.
+    pub fn reject_transit_gateway_vpc_attachment(
+        &self,
+        input: RejectTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        RejectTransitGatewayVpcAttachmentResult,
+        RusotoError<RejectTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.reject_transit_gateway_vpc_attachment(input))
+    }
+
+    /// <p>Rejects one or more VPC endpoint connection requests to your VPC endpoint service.</p>
+    ///
+    /// See `Ec2::reject_vpc_endpoint_connections`.
+    pub fn reject_vpc_endpoint_connections(
+        &self,
+        input: RejectVpcEndpointConnectionsRequest,
+    ) -> Result<RejectVpcEndpointConnectionsResult, RusotoError<RejectVpcEndpointConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_vpc_endpoint_connections(input))
+    }
+
+    /// <p>Rejects a VPC peering connection request. The VPC peering connection must be in the <code>pending-acceptance</code> state. Use the <a>DescribeVpcPeeringConnections</a> request to view your outstanding VPC peering connection requests. To delete an active VPC peering connection, or to delete a VPC peering connection request that you initiated, use <a>DeleteVpcPeeringConnection</a>.</p>
+    ///
+    /// See `Ec2::reject_vpc_peering_connection`.
+    pub fn reject_vpc_peering_connection(
+        &self,
+        input: RejectVpcPeeringConnectionRequest,
+    ) -> Result<RejectVpcPeeringConnectionResult, RusotoError<RejectVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_vpc_peering_connection(input))
+    }
+
+    /// <p>Releases the specified Elastic IP address.</p> <p>[EC2-Classic, default VPC] Releasing an Elastic IP address automatically disassociates it from any instance that it's associated with. To disassociate an Elastic IP address without releasing it, use <a>DisassociateAddress</a>.</p> <p>[Nondefault VPC] You must use <a>DisassociateAddress</a> to disassociate the Elastic IP address before you can release it. Otherwise, Amazon EC2 returns an error (<code>InvalidIPAddress.InUse</code>).</p> <p>After releasing an Elastic IP address, it is released to the IP address pool. Be sure to update your DNS records and any servers or devices that communicate with the address. If you attempt to release an Elastic IP address that you already released, you'll get an <code>AuthFailure</code> error if the address is already allocated to another AWS account.</p> <p>[EC2-VPC] After you release an Elastic IP address for use in a VPC, you might be able to recover it. For more information, see <a>AllocateAddress</a>.</p>
+    ///
+    /// See `Ec2::release_address`.
+    pub fn release_address(
+        &self,
+        input: ReleaseAddressRequest,
+    ) -> Result<(), RusotoError<ReleaseAddressError>> {
+        self.runtime.block_on(self.client.release_address(input))
+    }
+
+    /// <p>When you no longer want to use an On-Demand Dedicated Host it can be released. On-Demand billing is stopped and the host goes into <code>released</code> state. The host ID of Dedicated Hosts that have been released can no longer be specified in another request, for example, to modify the host. You must stop or terminate all instances on a host before it can be released.</p> <p>When Dedicated Hosts are released, it may take some time for them to stop counting toward your limit and you may receive capacity errors when trying to allocate new Dedicated Hosts. Wait a few minutes and then try again.</p> <p>Released hosts still appear in a <a>DescribeHosts</a> response.</p>
+    ///
+    /// See `Ec2::release_hosts`.
+    pub fn release_hosts(
+        &self,
+        input: ReleaseHostsRequest,
+    ) -> Result<ReleaseHostsResult, RusotoError<ReleaseHostsError>> {
+        self.runtime.block_on(self.client.release_hosts(input))
+    }
+
+    /// <p>Replaces an IAM instance profile for the specified running instance. You can use this action to change the IAM instance profile that's associated with an instance without having to disassociate the existing IAM instance profile first.</p> <p>Use <a>DescribeIamInstanceProfileAssociations</a> to get the association ID.</p>
+    ///
+    /// See `Ec2::replace_iam_instance_profile_association`.
+    pub fn replace_iam_instance_profile_association(
+        &self,
+        input: ReplaceIamInstanceProfileAssociationRequest,
+    ) -> Result<
+        ReplaceIamInstanceProfileAssociationResult,
+        RusotoError<ReplaceIamInstanceProfileAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.replace_iam_instance_profile_association(input))
+    }
+
+    /// <p>Changes which network ACL a subnet is associated with. By default when you create a subnet, it's automatically associated with the default network ACL. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_ACLs.html">Network ACLs</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>This is an idempotent operation.</p>
+    ///
+    /// See `Ec2::replace_network_acl_association`.
+    pub fn replace_network_acl_association(
+        &self,
+        input: ReplaceNetworkAclAssociationRequest,
+    ) -> Result<ReplaceNetworkAclAssociationResult, RusotoError<ReplaceNetworkAclAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.replace_network_acl_association(input))
+    }
+
+    /// <p>Replaces an entry (rule) in a network ACL. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_ACLs.html">Network ACLs</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::replace_network_acl_entry`.
+    pub fn replace_network_acl_entry(
+        &self,
+        input: ReplaceNetworkAclEntryRequest,
+    ) -> Result<(), RusotoError<ReplaceNetworkAclEntryError>> {
+        self.runtime
+            .block_on(self.client.replace_network_acl_entry(input))
+    }
+
+    /// <p>Replaces an existing route within a route table in a VPC. You must provide only one of the following: internet gateway, virtual private gateway, NAT instance, NAT gateway, VPC peering connection, network interface, egress-only internet gateway, or transit gateway.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::replace_route`.
+    pub fn replace_route(
+        &self,
+        input: ReplaceRouteRequest,
+    ) -> Result<(), RusotoError<ReplaceRouteError>> {
+        self.runtime.block_on(self.client.replace_route(input))
+    }
+
+    /// <p>Changes the route table associated with a given subnet, internet gateway, or virtual private gateway in a VPC. After the operation completes, the subnet or gateway uses the routes in the new route table. For more information about route tables, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Route_Tables.html">Route Tables</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p> <p>You can also use this operation to change which table is the main route table in the VPC. Specify the main route table's association ID and the route table ID of the new main route table.</p>
+    ///
+    /// See `Ec2::replace_route_table_association`.
+    pub fn replace_route_table_association(
+        &self,
+        input: ReplaceRouteTableAssociationRequest,
+    ) -> Result<ReplaceRouteTableAssociationResult, RusotoError<ReplaceRouteTableAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.replace_route_table_association(input))
+    }
+
+    /// <p>Replaces the specified route in the specified transit gateway route table.</p>
+    ///
+    /// See `Ec2::replace_transit_gateway_route`.
+    pub fn replace_transit_gateway_route(
+        &self,
+        input: ReplaceTransitGatewayRouteRequest,
+    ) -> Result<ReplaceTransitGatewayRouteResult, RusotoError<ReplaceTransitGatewayRouteError>>
+    {
+        self.runtime
+            .block_on(self.client.replace_transit_gateway_route(input))
+    }
+
+    /// <p>Submits feedback about the status of an instance. The instance must be in the <code>running</code> state. If your experience with the instance differs from the instance status returned by <a>DescribeInstanceStatus</a>, use <a>ReportInstanceStatus</a> to report your experience with the instance. Amazon EC2 collects this information to improve the accuracy of status checks.</p> <p>Use of this action does not change the value returned by <a>DescribeInstanceStatus</a>.</p>
+    ///
+    /// See `Ec2::report_instance_status`.
+    pub fn report_instance_status(
+        &self,
+        input: ReportInstanceStatusRequest,
+    ) -> Result<(), RusotoError<ReportInstanceStatusError>> {
+        self.runtime
+            .block_on(self.client.report_instance_status(input))
+    }
+
+    /// <p>Creates a Spot Fleet request.</p> <p>The Spot Fleet request specifies the total target capacity and the On-Demand target capacity. Amazon EC2 calculates the difference between the total capacity and On-Demand capacity, and launches the difference as Spot capacity.</p> <p>You can submit a single request that includes multiple launch specifications that vary by instance type, AMI, Availability Zone, or subnet.</p> <p>By default, the Spot Fleet requests Spot Instances in the Spot Instance pool where the price per unit is the lowest. Each launch specification can include its own instance weighting that reflects the value of the instance type to your application workload.</p> <p>Alternatively, you can specify that the Spot Fleet distribute the target capacity across the Spot pools included in its launch specifications. By ensuring that the Spot Instances in your Spot Fleet are in different Spot pools, you can improve the availability of your fleet.</p> <p>You can specify tags for the Spot Fleet request and instances launched by the fleet. You cannot tag other resource types in a Spot Fleet request because only the <code>spot-fleet-request</code> and <code>instance</code> resource types are supported.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/spot-fleet-requests.html">Spot Fleet requests</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
+    ///
+    /// See `Ec2::request_spot_fleet`.
+    pub fn request_spot_fleet(
+        &self,
+        input: RequestSpotFleetRequest,
+    ) -> Result<RequestSpotFleetResponse, RusotoError<RequestSpotFleetError>> {
+        self.runtime.block_on(self.client.request_spot_fleet(input))
+    }
+
+    /// <p>Creates a Spot Instance request.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/spot-requests.html">Spot Instance requests</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
+    ///
+    /// See `Ec2::request_spot_instances`.
+    pub fn request_spot_instances(
+        &self,
+        input: RequestSpotInstancesRequest,
+    ) -> Result<RequestSpotInstancesResult, RusotoError<RequestSpotInstancesError>> {
+        self.runtime
+            .block_on(self.client.request_spot_instances(input))
+    }
+
+    /// <p>Resets the default customer master key (CMK) for EBS encryption for your account in this Region to the AWS managed CMK for EBS.</p> <p>After resetting the default CMK to the AWS managed CMK, you can continue to encrypt by a customer managed CMK by specifying it when you create the volume. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSEncryption.html">Amazon EBS Encryption</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::reset_ebs_default_kms_key_id`.
+    pub fn reset_ebs_default_kms_key_id(
+        &self,
+        input: ResetEbsDefaultKmsKeyIdRequest,
+    ) -> Result<ResetEbsDefaultKmsKeyIdResult, RusotoError<ResetEbsDefaultKmsKeyIdError>> {
+        self.runtime
+            .block_on(self.client.reset_ebs_default_kms_key_id(input))
+    }
+
+    /// <p>Resets the specified attribute of the specified Amazon FPGA Image (AFI) to its default value. You can only reset the load permission attribute.</p>
+    ///
+    /// See `Ec2::reset_fpga_image_attribute`.
+    pub fn reset_fpga_image_attribute(
+        &self,
+        input: ResetFpgaImageAttributeRequest,
+    ) -> Result<ResetFpgaImageAttributeResult, RusotoError<ResetFpgaImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_fpga_image_attribute(input))
+    }
+
+    /// <p><p>Resets an attribute of an AMI to its default value.</p> <note> <p>The productCodes attribute can&#39;t be reset.</p> </note></p>
+    ///
+    /// See `Ec2::reset_image_attribute`.
+    pub fn reset_image_attribute(
+        &self,
+        input: ResetImageAttributeRequest,
+    ) -> Result<(), RusotoError<ResetImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_image_attribute(input))
+    }
+
+    /// <p>Resets an attribute of an instance to its default value. To reset the <code>kernel</code> or <code>ramdisk</code>, the instance must be in a stopped state. To reset the <code>sourceDestCheck</code>, the instance can be either running or stopped.</p> <p>The <code>sourceDestCheck</code> attribute controls whether source/destination checking is enabled. The default value is <code>true</code>, which means checking is enabled. This value must be <code>false</code> for a NAT instance to perform NAT. For more information, see <a href="https://docs.aws.amazon.com/AmazonVPC/latest/UserGuide/VPC_NAT_Instance.html">NAT Instances</a> in the <i>Amazon Virtual Private Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::reset_instance_attribute`.
+    pub fn reset_instance_attribute(
+        &self,
+        input: ResetInstanceAttributeRequest,
+    ) -> Result<(), RusotoError<ResetInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_instance_attribute(input))
+    }
+
+    /// <p>Resets a network interface attribute. You can specify only one attribute at a time.</p>
+    ///
+    /// See `Ec2::reset_network_interface_attribute`.
+    pub fn reset_network_interface_attribute(
+        &self,
+        input: ResetNetworkInterfaceAttributeRequest,
+    ) -> Result<(), RusotoError<ResetNetworkInterfaceAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_network_interface_attribute(input))
+    }
+
+    /// <p>Resets permission settings for the specified snapshot.</p> <p>For more information about modifying snapshot permissions, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ebs-modifying-snapshot-permissions.html">Sharing Snapshots</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::reset_snapshot_attribute`.
+    pub fn reset_snapshot_attribute(
+        &self,
+        input: ResetSnapshotAttributeRequest,
+    ) -> Result<(), RusotoError<ResetSnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_snapshot_attribute(input))
+    }
+
+    /// <p>Restores an Elastic IP address that was previously moved to the EC2-VPC platform back to the EC2-Classic platform. You cannot move an Elastic IP address that was originally allocated for use in EC2-VPC. The Elastic IP address must not be associated with an instance or network interface.</p>
+    ///
+    /// See `Ec2::restore_address_to_classic`.
+    pub fn restore_address_to_classic(
+        &self,
+        input: RestoreAddressToClassicRequest,
+    ) -> Result<RestoreAddressToClassicResult, RusotoError<RestoreAddressToClassicError>> {
+        self.runtime
+            .block_on(self.client.restore_address_to_classic(input))
+    }
+
+    /// <p>Restores the entries from a previous version of a managed prefix list to a new version of the prefix list.</p>
+    ///
+    /// See `Ec2::restore_managed_prefix_list_version`.
+    pub fn restore_managed_prefix_list_version(
+        &self,
+        input: RestoreManagedPrefixListVersionRequest,
+    ) -> Result<
+        RestoreManagedPrefixListVersionResult,
+        RusotoError<RestoreManagedPrefixListVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_managed_prefix_list_version(input))
+    }
+
+    /// <p>Removes an ingress authorization rule from a Client VPN endpoint. </p>
+    ///
+    /// See `Ec2::revoke_client_vpn_ingress`.
+    pub fn revoke_client_vpn_ingress(
+        &self,
+        input: RevokeClientVpnIngressRequest,
+    ) -> Result<RevokeClientVpnIngressResult, RusotoError<RevokeClientVpnIngressError>> {
+        self.runtime
+            .block_on(self.client.revoke_client_vpn_ingress(input))
+    }
+
+    /// <p>[VPC only] Removes the specified egress rules from a security group for EC2-VPC. This action doesn't apply to security groups for use in EC2-Classic. To remove a rule, the values that you specify (for example, ports) must match the existing rule's values exactly.</p> <p>Each rule consists of the protocol and the IPv4 or IPv6 CIDR range or source security group. For the TCP and UDP protocols, you must also specify the destination port or range of ports. For the ICMP protocol, you must also specify the ICMP type and code. If the security group rule has a description, you do not have to specify the description to revoke the rule.</p> <p>Rule changes are propagated to instances within the security group as quickly as possible. However, a small delay might occur.</p>
+    ///
+    /// See `Ec2::revoke_security_group_egress`.
+    pub fn revoke_security_group_egress(
+        &self,
+        input: RevokeSecurityGroupEgressRequest,
+    ) -> Result<(), RusotoError<RevokeSecurityGroupEgressError>> {
+        self.runtime
+            .block_on(self.client.revoke_security_group_egress(input))
+    }
+
+    /// <p>Removes the specified ingress rules from a security group. To remove a rule, the values that you specify (for example, ports) must match the existing rule's values exactly.</p> <note> <p>[EC2-Classic only] If the values you specify do not match the existing rule's values, no error is returned. Use <a>DescribeSecurityGroups</a> to verify that the rule has been removed.</p> </note> <p>Each rule consists of the protocol and the CIDR range or source security group. For the TCP and UDP protocols, you must also specify the destination port or range of ports. For the ICMP protocol, you must also specify the ICMP type and code. If the security group rule has a description, you do not have to specify the description to revoke the rule.</p> <p>Rule changes are propagated to instances within the security group as quickly as possible. However, a small delay might occur.</p>
+    ///
+    /// See `Ec2::revoke_security_group_ingress`.
+    pub fn revoke_security_group_ingress(
+        &self,
+        input: RevokeSecurityGroupIngressRequest,
+    ) -> Result<(), RusotoError<RevokeSecurityGroupIngressError>> {
+        self.runtime
+            .block_on(self.client.revoke_security_group_ingress(input))
+    }
+
+    /// <p>Launches the specified number of instances using an AMI for which you have permissions.</p> <p>You can specify a number of options, or leave the default options. The following rules apply:</p> <ul> <li> <p>[EC2-VPC] If you don't specify a subnet ID, we choose a default subnet from your default VPC for you. If you don't have a default VPC, you must specify a subnet ID in the request.</p> </li> <li> <p>[EC2-Classic] If don't specify an Availability Zone, we choose one for you.</p> </li> <li> <p>Some instance types must be launched into a VPC. If you do not have a default VPC, or if you do not specify a subnet ID, the request fails. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-vpc.html#vpc-only-instance-types">Instance types available only in a VPC</a>.</p> </li> <li> <p>[EC2-VPC] All instances have a network interface with a primary private IPv4 address. If you don't specify this address, we choose one from the IPv4 range of your subnet.</p> </li> <li> <p>Not all instance types support IPv6 addresses. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance types</a>.</p> </li> <li> <p>If you don't specify a security group ID, we use the default security group. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-network-security.html">Security groups</a>.</p> </li> <li> <p>If any of the AMIs have a product code attached for which the user has not subscribed, the request fails.</p> </li> </ul> <p>You can create a <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html">launch template</a>, which is a resource that contains the parameters to launch an instance. When you launch an instance using <a>RunInstances</a>, you can specify the launch template instead of specifying the launch parameters.</p> <p>To ensure faster instance launches, break up large requests into smaller batches. For example, create five separate launch requests for 100 instances each instead of one launch request for 500 instances.</p> <p>An instance is ready for you to use when it's in the <code>running</code> state. You can check the state of your instance using <a>DescribeInstances</a>. You can tag instances and EBS volumes during launch, after launch, or both. For more information, see <a>CreateTags</a> and <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging your Amazon EC2 resources</a>.</p> <p>Linux instances have access to the public key of the key pair at boot. You can use this key to provide secure access to the instance. Amazon EC2 public images use this feature to provide secure access without passwords. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-key-pairs.html">Key pairs</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>For troubleshooting, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_InstanceStraightToTerminated.html">What to do if an instance immediately terminates</a>, and <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/TroubleshootingInstancesConnecting.html">Troubleshooting connecting to your instance</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::run_instances`.
+    pub fn run_instances(
+        &self,
+        input: RunInstancesRequest,
+    ) -> Result<Reservation, RusotoError<RunInstancesError>> {
+        self.runtime.block_on(self.client.run_instances(input))
+    }
+
+    /// <p>Launches the specified Scheduled Instances.</p> <p>Before you can launch a Scheduled Instance, you must purchase it and obtain an identifier using <a>PurchaseScheduledInstances</a>.</p> <p>You must launch a Scheduled Instance during its scheduled time period. You can't stop or reboot a Scheduled Instance, but you can terminate it as needed. If you terminate a Scheduled Instance before the current scheduled time period ends, you can launch it again after a few minutes. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-scheduled-instances.html">Scheduled Instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::run_scheduled_instances`.
+    pub fn run_scheduled_instances(
+        &self,
+        input: RunScheduledInstancesRequest,
+    ) -> Result<RunScheduledInstancesResult, RusotoError<RunScheduledInstancesError>> {
+        self.runtime
+            .block_on(self.client.run_scheduled_instances(input))
+    }
+
+    /// <p>Searches for routes in the specified local gateway route table.</p>
+    ///
+    /// See `Ec2::search_local_gateway_routes`.
+    pub fn search_local_gateway_routes(
+        &self,
+        input: SearchLocalGatewayRoutesRequest,
+    ) -> Result<SearchLocalGatewayRoutesResult, RusotoError<SearchLocalGatewayRoutesError>> {
+        self.runtime
+            .block_on(self.client.search_local_gateway_routes(input))
+    }
+
+    /// <p>Searches one or more transit gateway multicast groups and returns the group membership information.</p>
+    ///
+    /// See `Ec2::search_transit_gateway_multicast_groups`.
+    pub fn search_transit_gateway_multicast_groups(
+        &self,
+        input: SearchTransitGatewayMulticastGroupsRequest,
+    ) -> Result<
+        SearchTransitGatewayMulticastGroupsResult,
+        RusotoError<SearchTransitGatewayMulticastGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.search_transit_gateway_multicast_groups(input))
+    }
+
+    /// <p>Searches for routes in the specified transit gateway route table.</p>
+    ///
+    /// See `Ec2::search_transit_gateway_routes`.
+    pub fn search_transit_gateway_routes(
+        &self,
+        input: SearchTransitGatewayRoutesRequest,
+    ) -> Result<SearchTransitGatewayRoutesResult, RusotoError<SearchTransitGatewayRoutesError>>
+    {
+        self.runtime
+            .block_on(self.client.search_transit_gateway_routes(input))
+    }
+
+    /// <p>Sends a diagnostic interrupt to the specified Amazon EC2 instance to trigger a <i>kernel panic</i> (on Linux instances), or a <i>blue screen</i>/<i>stop error</i> (on Windows instances). For instances based on Intel and AMD processors, the interrupt is received as a <i>non-maskable interrupt</i> (NMI).</p> <p>In general, the operating system crashes and reboots when a kernel panic or stop error is triggered. The operating system can also be configured to perform diagnostic tasks, such as generating a memory dump file, loading a secondary kernel, or obtaining a call trace.</p> <p>Before sending a diagnostic interrupt to your instance, ensure that its operating system is configured to perform the required diagnostic tasks.</p> <p>For more information about configuring your operating system to generate a crash dump when a kernel panic or stop error occurs, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/diagnostic-interrupt.html">Send a diagnostic interrupt</a> (Linux instances) or <a href="https://docs.aws.amazon.com/AWSEC2/latest/WindowsGuide/diagnostic-interrupt.html">Send a Diagnostic Interrupt</a> (Windows instances).</p>
+    ///
+    /// See `Ec2::send_diagnostic_interrupt`.
+    pub fn send_diagnostic_interrupt(
+        &self,
+        input: SendDiagnosticInterruptRequest,
+    ) -> Result<(), RusotoError<SendDiagnosticInterruptError>> {
+        self.runtime
+            .block_on(self.client.send_diagnostic_interrupt(input))
+    }
+
+    /// <p>Starts an Amazon EBS-backed instance that you've previously stopped.</p> <p>Instances that use Amazon EBS volumes as their root devices can be quickly stopped and started. When an instance is stopped, the compute resources are released and you are not billed for instance usage. However, your root partition Amazon EBS volume remains and continues to persist your data, and you are charged for Amazon EBS volume usage. You can restart your instance at any time. Every time you start your Windows instance, Amazon EC2 charges you for a full instance hour. If you stop and restart your Windows instance, a new instance hour begins and Amazon EC2 charges you for another full instance hour even if you are still within the same 60-minute period when it was stopped. Every time you start your Linux instance, Amazon EC2 charges a one-minute minimum for instance usage, and thereafter charges per second for instance usage.</p> <p>Before stopping an instance, make sure it is in a state from which it can be restarted. Stopping an instance does not preserve data stored in RAM.</p> <p>Performing this operation on an instance that uses an instance store as its root device returns an error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Stop_Start.html">Stopping instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::start_instances`.
+    pub fn start_instances(
+        &self,
+        input: StartInstancesRequest,
+    ) -> Result<StartInstancesResult, RusotoError<StartInstancesError>> {
+        self.runtime.block_on(self.client.start_instances(input))
+    }
+
+    /// <p>Initiates the verification process to prove that the service provider owns the private DNS name domain for the endpoint service.</p> <p>The service provider must successfully perform the verification before the consumer can use the name to access the service.</p> <p>Before the service provider runs this command, they must add a record to the DNS server. For more information, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/ndpoint-services-dns-validation.html#add-dns-txt-record">Adding a TXT Record to Your Domain's DNS Server </a> in the <i>Amazon VPC User Guide</i>.</p>
+    ///
+    /// See `Ec2::start_vpc_endpoint_service_private_dns_verification`.
+    pub fn start_vpc_endpoint_service_private_dns_verification(
+        &self,
+        input: StartVpcEndpointServicePrivateDnsVerificationRequest,
+    ) -> Result<
+        StartVpcEndpointServicePrivateDnsVerificationResult,
+        RusotoError<StartVpcEndpointServicePrivateDnsVerificationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .start_vpc_endpoint_service_private_dns_verification(input),
+        )
+    }
+
+    /// <p>Stops an Amazon EBS-backed instance.</p> <p>You can use the Stop action to hibernate an instance if the instance is <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Hibernate.html#enabling-hibernation">enabled for hibernation</a> and it meets the <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Hibernate.html#hibernating-prerequisites">hibernation prerequisites</a>. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Hibernate.html">Hibernate your instance</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>We don't charge usage for a stopped instance, or data transfer fees; however, your root partition Amazon EBS volume remains and continues to persist your data, and you are charged for Amazon EBS volume usage. Every time you start your Windows instance, Amazon EC2 charges you for a full instance hour. If you stop and restart your Windows instance, a new instance hour begins and Amazon EC2 charges you for another full instance hour even if you are still within the same 60-minute period when it was stopped. Every time you start your Linux instance, Amazon EC2 charges a one-minute minimum for instance usage, and thereafter charges per second for instance usage.</p> <p>You can't stop or hibernate instance store-backed instances. You can't use the Stop action to hibernate Spot Instances, but you can specify that Amazon EC2 should hibernate Spot Instances when they are interrupted. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/spot-interruptions.html#hibernate-spot-instances">Hibernating interrupted Spot Instances</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>When you stop or hibernate an instance, we shut it down. You can restart your instance at any time. Before stopping or hibernating an instance, make sure it is in a state from which it can be restarted. Stopping an instance does not preserve data stored in RAM, but hibernating an instance does preserve data stored in RAM. If an instance cannot hibernate successfully, a normal shutdown occurs.</p> <p>Stopping and hibernating an instance is different to rebooting or terminating it. For example, when you stop or hibernate an instance, the root device and any other devices attached to the instance persist. When you terminate an instance, the root device and any other devices attached during the instance launch are automatically deleted. For more information about the differences between rebooting, stopping, hibernating, and terminating instances, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-instance-lifecycle.html">Instance lifecycle</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>When you stop an instance, we attempt to shut it down forcibly after a short while. If your instance appears stuck in the stopping state after a period of time, there may be an issue with the underlying host computer. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/TroubleshootingInstancesStopping.html">Troubleshooting stopping your instance</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::stop_instances`.
+    pub fn stop_instances(
+        &self,
+        input: StopInstancesRequest,
+    ) -> Result<StopInstancesResult, RusotoError<StopInstancesError>> {
+        self.runtime.block_on(self.client.stop_instances(input))
+    }
+
+    /// <p>Terminates active Client VPN endpoint connections. This action can be used to terminate a specific client connection, or up to five connections established by a specific user.</p>
+    ///
+    /// See `Ec2::terminate_client_vpn_connections`.
+    pub fn terminate_client_vpn_connections(
+        &self,
+        input: TerminateClientVpnConnectionsRequest,
+    ) -> Result<TerminateClientVpnConnectionsResult, RusotoError<TerminateClientVpnConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.terminate_client_vpn_connections(input))
+    }
+
+    /// <p>Shuts down the specified instances. This operation is idempotent; if you terminate an instance more than once, each call succeeds. </p> <p>If you specify multiple instances and the request fails (for example, because of a single incorrect instance ID), none of the instances are terminated.</p> <p>Terminated instances remain visible after termination (for approximately one hour).</p> <p>By default, Amazon EC2 deletes all EBS volumes that were attached when the instance launched. Volumes attached after instance launch continue running.</p> <p>You can stop, start, and terminate EBS-backed instances. You can only terminate instance store-backed instances. What happens to an instance differs if you stop it or terminate it. For example, when you stop an instance, the root device and any other devices attached to the instance persist. When you terminate an instance, any attached EBS volumes with the <code>DeleteOnTermination</code> block device mapping parameter set to <code>true</code> are automatically deleted. For more information about the differences between stopping and terminating instances, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-instance-lifecycle.html">Instance lifecycle</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p> <p>For more information about troubleshooting, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/TroubleshootingInstancesShuttingDown.html">Troubleshooting terminating your instance</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::terminate_instances`.
+    pub fn terminate_instances(
+        &self,
+        input: TerminateInstancesRequest,
+    ) -> Result<TerminateInstancesResult, RusotoError<TerminateInstancesError>> {
+        self.runtime
+            .block_on(self.client.terminate_instances(input))
+    }
+
+    /// <p>Unassigns one or more IPv6 addresses from a network interface.</p>
+    ///
+    /// See `Ec2::unassign_ipv_6_addresses`.
+    pub fn unassign_ipv_6_addresses(
+        &self,
+        input: UnassignIpv6AddressesRequest,
+    ) -> Result<UnassignIpv6AddressesResult, RusotoError<UnassignIpv6AddressesError>> {
+        self.runtime
+            .block_on(self.client.unassign_ipv_6_addresses(input))
+    }
+
+    /// <p>Unassigns one or more secondary private IP addresses from a network interface.</p>
+    ///
+    /// See `Ec2::unassign_private_ip_addresses`.
+    pub fn unassign_private_ip_addresses(
+        &self,
+        input: UnassignPrivateIpAddressesRequest,
+    ) -> Result<(), RusotoError<UnassignPrivateIpAddressesError>> {
+        self.runtime
+            .block_on(self.client.unassign_private_ip_addresses(input))
+    }
+
+    /// <p>Disables detailed monitoring for a running instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/using-cloudwatch.html">Monitoring your instances and volumes</a> in the <i>Amazon Elastic Compute Cloud User Guide</i>.</p>
+    ///
+    /// See `Ec2::unmonitor_instances`.
+    pub fn unmonitor_instances(
+        &self,
+        input: UnmonitorInstancesRequest,
+    ) -> Result<UnmonitorInstancesResult, RusotoError<UnmonitorInstancesError>> {
+        self.runtime
+            .block_on(self.client.unmonitor_instances(input))
+    }
+
+    /// <p>[VPC only] Updates the description of an egress (outbound) security group rule. You can replace an existing description, or add a description to a rule that did not have one previously.</p> <p>You specify the description as part of the IP permissions structure. You can remove a description for a security group rule by omitting the description parameter in the request.</p>
+    ///
+    /// See `Ec2::update_security_group_rule_descriptions_egress`.
+    pub fn update_security_group_rule_descriptions_egress(
+        &self,
+        input: UpdateSecurityGroupRuleDescriptionsEgressRequest,
+    ) -> Result<
+        UpdateSecurityGroupRuleDescriptionsEgressResult,
+        RusotoError<UpdateSecurityGroupRuleDescriptionsEgressError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_security_group_rule_descriptions_egress(input),
+        )
+    }
+
+    /// <p>Updates the description of an ingress (inbound) security group rule. You can replace an existing description, or add a description to a rule that did not have one previously.</p> <p>You specify the description as part of the IP permissions structure. You can remove a description for a security group rule by omitting the description parameter in the request.</p>
+    ///
+    /// See `Ec2::update_security_group_rule_descriptions_ingress`.
+    pub fn update_security_group_rule_descriptions_ingress(
+        &self,
+        input: UpdateSecurityGroupRuleDescriptionsIngressRequest,
+    ) -> Result<
+        UpdateSecurityGroupRuleDescriptionsIngressResult,
+        RusotoError<UpdateSecurityGroupRuleDescriptionsIngressError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_security_group_rule_descriptions_ingress(input),
+        )
+    }
+
+    /// <p>Stops advertising an address range that is provisioned as an address pool.</p> <p>You can perform this operation at most once every 10 seconds, even if you specify different address ranges each time.</p> <p>It can take a few minutes before traffic to the specified addresses stops routing to AWS because of BGP propagation delays.</p>
+    ///
+    /// See `Ec2::withdraw_byoip_cidr`.
+    pub fn withdraw_byoip_cidr(
+        &self,
+        input: WithdrawByoipCidrRequest,
+    ) -> Result<WithdrawByoipCidrResult, RusotoError<WithdrawByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.withdraw_byoip_cidr(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -1382,17 +1382,22 @@ impl BatchCheckLayerAvailabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchCheckLayerAvailabilityError::InvalidParameter(err.msg),
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchCheckLayerAvailabilityError::RepositoryNotFound(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchCheckLayerAvailabilityError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCheckLayerAvailabilityError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1430,13 +1435,19 @@ impl BatchDeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImageError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteImageError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchDeleteImageError::Server(err.msg))
+                    return RusotoError::service(&res, BatchDeleteImageError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1472,13 +1483,19 @@ impl BatchGetImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchGetImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetImageError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(BatchGetImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetImageError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchGetImageError::Server(err.msg))
+                    return RusotoError::service(&res, BatchGetImageError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1524,36 +1541,49 @@ impl CompleteLayerUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EmptyUploadException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::EmptyUpload(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::EmptyUpload(err.msg),
+                    )
                 }
                 "InvalidLayerException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::InvalidLayer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::InvalidLayer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::InvalidParameter(err.msg),
+                    )
                 }
                 "LayerAlreadyExistsException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::LayerAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::LayerAlreadyExists(err.msg),
+                    )
                 }
                 "LayerPartTooSmallException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::LayerPartTooSmall(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::LayerPartTooSmall(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::Server(err.msg))
+                    return RusotoError::service(&res, CompleteLayerUploadError::Server(err.msg))
                 }
                 "UploadNotFoundException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::UploadNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteLayerUploadError::UploadNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1600,26 +1630,34 @@ impl CreateRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidTagParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::InvalidTagParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRepositoryError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::LimitExceeded(err.msg),
+                    )
                 }
                 "RepositoryAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRepositoryError::RepositoryAlreadyExists(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateRepositoryError::Server(err.msg))
+                    return RusotoError::service(&res, CreateRepositoryError::Server(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateRepositoryError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1660,22 +1698,25 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLifecyclePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLifecyclePolicyError::LifecyclePolicyNotFound(err.msg),
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLifecyclePolicyError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteLifecyclePolicyError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1716,16 +1757,25 @@ impl DeleteRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRepositoryError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotEmptyException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNotEmpty(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::RepositoryNotEmpty(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRepositoryError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteRepositoryError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1764,22 +1814,25 @@ impl DeleteRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRepositoryPolicyError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "RepositoryPolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRepositoryPolicyError::RepositoryPolicyNotFound(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteRepositoryPolicyError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1822,27 +1875,34 @@ impl DescribeImageScanFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::ImageNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImageScanFindingsError::ImageNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImageScanFindingsError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeImageScanFindingsError::RepositoryNotFound(err.msg),
                     )
                 }
                 "ScanNotFoundException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::ScanNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImageScanFindingsError::ScanNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImageScanFindingsError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1882,16 +1942,22 @@ impl DescribeImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::ImageNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeImagesError::ImageNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImagesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImagesError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeImagesError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeImagesError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeImagesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1928,17 +1994,19 @@ impl DescribeRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRepositoriesError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRepositoriesError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeRepositoriesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1972,12 +2040,13 @@ impl GetAuthorizationTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAuthorizationTokenError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAuthorizationTokenError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetAuthorizationTokenError::Server(err.msg))
+                    return RusotoError::service(&res, GetAuthorizationTokenError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2016,27 +2085,31 @@ impl GetDownloadUrlForLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDownloadUrlForLayerError::InvalidParameter(err.msg),
+                    )
                 }
                 "LayerInaccessibleException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::LayerInaccessible(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDownloadUrlForLayerError::LayerInaccessible(err.msg),
+                    )
                 }
                 "LayersNotFoundException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::LayersNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDownloadUrlForLayerError::LayersNotFound(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDownloadUrlForLayerError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::Server(err.msg))
+                    return RusotoError::service(&res, GetDownloadUrlForLayerError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2076,20 +2149,25 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::LifecyclePolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::LifecyclePolicyNotFound(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service(&res, GetLifecyclePolicyError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2128,22 +2206,28 @@ impl GetLifecyclePolicyPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLifecyclePolicyPreviewError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyPreviewError::InvalidParameter(err.msg),
+                    )
                 }
                 "LifecyclePolicyPreviewNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLifecyclePolicyPreviewError::LifecyclePolicyPreviewNotFound(err.msg),
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLifecyclePolicyPreviewError::RepositoryNotFound(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyPreviewError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyPreviewError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2184,22 +2268,25 @@ impl GetRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRepositoryPolicyError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "RepositoryPolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRepositoryPolicyError::RepositoryPolicyNotFound(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service(&res, GetRepositoryPolicyError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2236,17 +2323,19 @@ impl InitiateLayerUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateLayerUploadError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateLayerUploadError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::Server(err.msg))
+                    return RusotoError::service(&res, InitiateLayerUploadError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2282,12 +2371,14 @@ impl ListImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListImagesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListImagesError::InvalidParameter(err.msg))
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(ListImagesError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(&res, ListImagesError::RepositoryNotFound(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, ListImagesError::Server(err.msg))
                 }
-                "ServerException" => return RusotoError::Service(ListImagesError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -2322,17 +2413,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2380,30 +2473,41 @@ impl PutImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageAlreadyExistsException" => {
-                    return RusotoError::Service(PutImageError::ImageAlreadyExists(err.msg))
+                    return RusotoError::service(&res, PutImageError::ImageAlreadyExists(err.msg))
                 }
                 "ImageDigestDoesNotMatchException" => {
-                    return RusotoError::Service(PutImageError::ImageDigestDoesNotMatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImageError::ImageDigestDoesNotMatch(err.msg),
+                    )
                 }
                 "ImageTagAlreadyExistsException" => {
-                    return RusotoError::Service(PutImageError::ImageTagAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImageError::ImageTagAlreadyExists(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, PutImageError::InvalidParameter(err.msg))
                 }
                 "LayersNotFoundException" => {
-                    return RusotoError::Service(PutImageError::LayersNotFound(err.msg))
+                    return RusotoError::service(&res, PutImageError::LayersNotFound(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutImageError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutImageError::LimitExceeded(err.msg))
                 }
                 "ReferencedImagesNotFoundException" => {
-                    return RusotoError::Service(PutImageError::ReferencedImagesNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImageError::ReferencedImagesNotFound(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(&res, PutImageError::RepositoryNotFound(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, PutImageError::Server(err.msg))
                 }
-                "ServerException" => return RusotoError::Service(PutImageError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -2446,19 +2550,22 @@ impl PutImageScanningConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutImageScanningConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutImageScanningConfigurationError::RepositoryNotFound(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutImageScanningConfigurationError::Server(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageScanningConfigurationError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2498,17 +2605,19 @@ impl PutImageTagMutabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageTagMutabilityError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageTagMutabilityError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::Server(err.msg))
+                    return RusotoError::service(&res, PutImageTagMutabilityError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2544,15 +2653,19 @@ impl PutLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLifecyclePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLifecyclePolicyError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service(&res, PutLifecyclePolicyError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2588,17 +2701,19 @@ impl SetRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetRepositoryPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::RepositoryNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetRepositoryPolicyError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service(&res, SetRepositoryPolicyError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2640,22 +2755,31 @@ impl StartImageScanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(StartImageScanError::ImageNotFound(err.msg))
+                    return RusotoError::service(&res, StartImageScanError::ImageNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartImageScanError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImageScanError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartImageScanError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartImageScanError::LimitExceeded(err.msg))
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(StartImageScanError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImageScanError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(StartImageScanError::Server(err.msg))
+                    return RusotoError::service(&res, StartImageScanError::Server(err.msg))
                 }
                 "UnsupportedImageTypeException" => {
-                    return RusotoError::Service(StartImageScanError::UnsupportedImageType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImageScanError::UnsupportedImageType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2700,27 +2824,34 @@ impl StartLifecyclePolicyPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLifecyclePolicyPreviewError::InvalidParameter(err.msg),
                     )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLifecyclePolicyPreviewError::LifecyclePolicyNotFound(err.msg),
                     )
                 }
                 "LifecyclePolicyPreviewInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLifecyclePolicyPreviewError::LifecyclePolicyPreviewInProgress(err.msg),
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLifecyclePolicyPreviewError::RepositoryNotFound(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(StartLifecyclePolicyPreviewError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLifecyclePolicyPreviewError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2768,19 +2899,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidTagParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Server(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2822,19 +2959,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidTagParameter(err.msg),
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Server(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2878,22 +3024,34 @@ impl UploadLayerPartError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLayerPartException" => {
-                    return RusotoError::Service(UploadLayerPartError::InvalidLayerPart(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadLayerPartError::InvalidLayerPart(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UploadLayerPartError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadLayerPartError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UploadLayerPartError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UploadLayerPartError::LimitExceeded(err.msg))
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(UploadLayerPartError::RepositoryNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadLayerPartError::RepositoryNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UploadLayerPartError::Server(err.msg))
+                    return RusotoError::service(&res, UploadLayerPartError::Server(err.msg))
                 }
                 "UploadNotFoundException" => {
-                    return RusotoError::Service(UploadLayerPartError::UploadNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadLayerPartError::UploadNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3114,6 +3272,7 @@ impl EcrClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3129,6 +3288,7 @@ impl EcrClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EcrClient {
         EcrClient { client, region }
     }
@@ -3143,6 +3303,9 @@ impl Ecr for EcrClient {
     ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchCheckLayerAvailability");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.BatchCheckLayerAvailability",
@@ -3165,6 +3328,9 @@ impl Ecr for EcrClient {
         input: BatchDeleteImageRequest,
     ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.BatchDeleteImage",
@@ -3186,6 +3352,9 @@ impl Ecr for EcrClient {
         input: BatchGetImageRequest,
     ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetImage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.BatchGetImage",
@@ -3207,6 +3376,9 @@ impl Ecr for EcrClient {
         input: CompleteLayerUploadRequest,
     ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CompleteLayerUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.CompleteLayerUpload",
@@ -3228,6 +3400,9 @@ impl Ecr for EcrClient {
         input: CreateRepositoryRequest,
     ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.CreateRepository",
@@ -3249,6 +3424,9 @@ impl Ecr for EcrClient {
         input: DeleteLifecyclePolicyRequest,
     ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLifecyclePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteLifecyclePolicy",
@@ -3271,6 +3449,9 @@ impl Ecr for EcrClient {
         input: DeleteRepositoryRequest,
     ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteRepository",
@@ -3292,6 +3473,9 @@ impl Ecr for EcrClient {
         input: DeleteRepositoryPolicyRequest,
     ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRepositoryPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteRepositoryPolicy",
@@ -3315,6 +3499,9 @@ impl Ecr for EcrClient {
     ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeImageScanFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeImageScanFindings",
@@ -3337,6 +3524,9 @@ impl Ecr for EcrClient {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeImages",
@@ -3358,6 +3548,9 @@ impl Ecr for EcrClient {
         input: DescribeRepositoriesRequest,
     ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRepositories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeRepositories",
@@ -3380,6 +3573,9 @@ impl Ecr for EcrClient {
         input: GetAuthorizationTokenRequest,
     ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAuthorizationToken");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetAuthorizationToken",
@@ -3402,6 +3598,9 @@ impl Ecr for EcrClient {
         input: GetDownloadUrlForLayerRequest,
     ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDownloadUrlForLayer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetDownloadUrlForLayer",
@@ -3424,6 +3623,9 @@ impl Ecr for EcrClient {
         input: GetLifecyclePolicyRequest,
     ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLifecyclePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetLifecyclePolicy",
@@ -3446,6 +3648,9 @@ impl Ecr for EcrClient {
     ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLifecyclePolicyPreview");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetLifecyclePolicyPreview",
@@ -3468,6 +3673,9 @@ impl Ecr for EcrClient {
         input: GetRepositoryPolicyRequest,
     ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRepositoryPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetRepositoryPolicy",
@@ -3489,6 +3697,9 @@ impl Ecr for EcrClient {
         input: InitiateLayerUploadRequest,
     ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InitiateLayerUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.InitiateLayerUpload",
@@ -3510,6 +3721,9 @@ impl Ecr for EcrClient {
         input: ListImagesRequest,
     ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.ListImages",
@@ -3531,6 +3745,9 @@ impl Ecr for EcrClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.ListTagsForResource",
@@ -3552,6 +3769,9 @@ impl Ecr for EcrClient {
         input: PutImageRequest,
     ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutImage",
@@ -3576,6 +3796,9 @@ impl Ecr for EcrClient {
         RusotoError<PutImageScanningConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutImageScanningConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutImageScanningConfiguration",
@@ -3598,6 +3821,9 @@ impl Ecr for EcrClient {
         input: PutImageTagMutabilityRequest,
     ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutImageTagMutability");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutImageTagMutability",
@@ -3620,6 +3846,9 @@ impl Ecr for EcrClient {
         input: PutLifecyclePolicyRequest,
     ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutLifecyclePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutLifecyclePolicy",
@@ -3641,6 +3870,9 @@ impl Ecr for EcrClient {
         input: SetRepositoryPolicyRequest,
     ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetRepositoryPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.SetRepositoryPolicy",
@@ -3662,6 +3894,9 @@ impl Ecr for EcrClient {
         input: StartImageScanRequest,
     ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartImageScan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.StartImageScan",
@@ -3684,6 +3919,9 @@ impl Ecr for EcrClient {
     ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartLifecyclePolicyPreview");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.StartLifecyclePolicyPreview",
@@ -3706,6 +3944,9 @@ impl Ecr for EcrClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.TagResource",
@@ -3727,6 +3968,9 @@ impl Ecr for EcrClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.UntagResource",
@@ -3748,6 +3992,9 @@ impl Ecr for EcrClient {
         input: UploadLayerPartRequest,
     ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UploadLayerPart");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.UploadLayerPart",
@@ -3763,3 +4010,368 @@ impl Ecr for EcrClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UploadLayerPartResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon ECR API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EcrClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EcrBlockingClient {
+    client: EcrClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EcrBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EcrBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EcrBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EcrBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EcrBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EcrBlockingClient {
+        EcrBlockingClient {
+            client: EcrClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Checks the availability of one or more image layers in a repository.</p> <p>When an image is pushed to a repository, each image layer is checked to verify if it has been uploaded before. If it has been uploaded, then the image layer is skipped.</p> <note> <p>This operation is used by the Amazon ECR proxy and is not generally used by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    ///
+    /// See `Ecr::batch_check_layer_availability`.
+    pub fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_check_layer_availability(input))
+    }
+
+    /// <p>Deletes a list of specified images within a repository. Images are specified with either an <code>imageTag</code> or <code>imageDigest</code>.</p> <p>You can remove a tag from an image by specifying the image's tag in your request. When you remove the last tag from an image, the image is deleted from your repository.</p> <p>You can completely delete an image (and all of its tags) by specifying the image's digest in your request.</p>
+    ///
+    /// See `Ecr::batch_delete_image`.
+    pub fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
+        self.runtime.block_on(self.client.batch_delete_image(input))
+    }
+
+    /// <p>Gets detailed information for an image. Images are specified with either an <code>imageTag</code> or <code>imageDigest</code>.</p> <p>When an image is pulled, the BatchGetImage API is called once to retrieve the image manifest.</p>
+    ///
+    /// See `Ecr::batch_get_image`.
+    pub fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
+        self.runtime.block_on(self.client.batch_get_image(input))
+    }
+
+    /// <p><p>Informs Amazon ECR that the image layer upload has completed for a specified registry, repository name, and upload ID. You can optionally provide a <code>sha256</code> digest of the image layer for data validation purposes.</p> <p>When an image is pushed, the CompleteLayerUpload API is called once per each new image layer to verify that the upload has completed.</p> <note> <p>This operation is used by the Amazon ECR proxy and is not generally used by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    ///
+    /// See `Ecr::complete_layer_upload`.
+    pub fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
+        self.runtime
+            .block_on(self.client.complete_layer_upload(input))
+    }
+
+    /// <p>Creates a repository. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/Repositories.html">Amazon ECR Repositories</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    ///
+    /// See `Ecr::create_repository`.
+    pub fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
+        self.runtime.block_on(self.client.create_repository(input))
+    }
+
+    /// <p>Deletes the lifecycle policy associated with the specified repository.</p>
+    ///
+    /// See `Ecr::delete_lifecycle_policy`.
+    pub fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_lifecycle_policy(input))
+    }
+
+    /// <p>Deletes a repository. If the repository contains images, you must either delete all images in the repository or use the <code>force</code> option to delete the repository.</p>
+    ///
+    /// See `Ecr::delete_repository`.
+    pub fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
+        self.runtime.block_on(self.client.delete_repository(input))
+    }
+
+    /// <p>Deletes the repository policy associated with the specified repository.</p>
+    ///
+    /// See `Ecr::delete_repository_policy`.
+    pub fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_repository_policy(input))
+    }
+
+    /// <p>Returns the scan findings for the specified image.</p>
+    ///
+    /// See `Ecr::describe_image_scan_findings`.
+    pub fn describe_image_scan_findings(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_image_scan_findings(input))
+    }
+
+    /// <p><p>Returns metadata about the images in a repository.</p> <note> <p>Beginning with Docker version 1.9, the Docker client compresses image layers before pushing them to a V2 Docker registry. The output of the <code>docker images</code> command shows the uncompressed image size, so it may return a larger image size than the image sizes returned by <a>DescribeImages</a>.</p> </note></p>
+    ///
+    /// See `Ecr::describe_images`.
+    pub fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
+        self.runtime.block_on(self.client.describe_images(input))
+    }
+
+    /// <p>Describes image repositories in a registry.</p>
+    ///
+    /// See `Ecr::describe_repositories`.
+    pub fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_repositories(input))
+    }
+
+    /// <p>Retrieves an authorization token. An authorization token represents your IAM authentication credentials and can be used to access any Amazon ECR registry that your IAM principal has access to. The authorization token is valid for 12 hours.</p> <p>The <code>authorizationToken</code> returned is a base64 encoded string that can be decoded and used in a <code>docker login</code> command to authenticate to a registry. The AWS CLI offers an <code>get-login-password</code> command that simplifies the login process. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/Registries.html#registry_auth">Registry Authentication</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    ///
+    /// See `Ecr::get_authorization_token`.
+    pub fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
+        self.runtime
+            .block_on(self.client.get_authorization_token(input))
+    }
+
+    /// <p><p>Retrieves the pre-signed Amazon S3 download URL corresponding to an image layer. You can only get URLs for image layers that are referenced in an image.</p> <p>When an image is pulled, the GetDownloadUrlForLayer API is called once per image layer that is not already cached.</p> <note> <p>This operation is used by the Amazon ECR proxy and is not generally used by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    ///
+    /// See `Ecr::get_download_url_for_layer`.
+    pub fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
+        self.runtime
+            .block_on(self.client.get_download_url_for_layer(input))
+    }
+
+    /// <p>Retrieves the lifecycle policy for the specified repository.</p>
+    ///
+    /// See `Ecr::get_lifecycle_policy`.
+    pub fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_lifecycle_policy(input))
+    }
+
+    /// <p>Retrieves the results of the lifecycle policy preview request for the specified repository.</p>
+    ///
+    /// See `Ecr::get_lifecycle_policy_preview`.
+    pub fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
+    {
+        self.runtime
+            .block_on(self.client.get_lifecycle_policy_preview(input))
+    }
+
+    /// <p>Retrieves the repository policy for the specified repository.</p>
+    ///
+    /// See `Ecr::get_repository_policy`.
+    pub fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_repository_policy(input))
+    }
+
+    /// <p><p>Notifies Amazon ECR that you intend to upload an image layer.</p> <p>When an image is pushed, the InitiateLayerUpload API is called once per image layer that has not already been uploaded. Whether or not an image layer has been uploaded is determined by the BatchCheckLayerAvailability API action.</p> <note> <p>This operation is used by the Amazon ECR proxy and is not generally used by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    ///
+    /// See `Ecr::initiate_layer_upload`.
+    pub fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
+        self.runtime
+            .block_on(self.client.initiate_layer_upload(input))
+    }
+
+    /// <p>Lists all the image IDs for the specified repository.</p> <p>You can filter images based on whether or not they are tagged by using the <code>tagStatus</code> filter and specifying either <code>TAGGED</code>, <code>UNTAGGED</code> or <code>ANY</code>. For example, you can filter your results to return only <code>UNTAGGED</code> images and then pipe that result to a <a>BatchDeleteImage</a> operation to delete them. Or, you can filter your results to return only <code>TAGGED</code> images to list all of the tags in your repository.</p>
+    ///
+    /// See `Ecr::list_images`.
+    pub fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.runtime.block_on(self.client.list_images(input))
+    }
+
+    /// <p>List the tags for an Amazon ECR resource.</p>
+    ///
+    /// See `Ecr::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p><p>Creates or updates the image manifest and tags associated with an image.</p> <p>When an image is pushed and all new image layers have been uploaded, the PutImage API is called once to create or update the image manifest and the tags associated with the image.</p> <note> <p>This operation is used by the Amazon ECR proxy and is not generally used by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    ///
+    /// See `Ecr::put_image`.
+    pub fn put_image(
+        &self,
+        input: PutImageRequest,
+    ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
+        self.runtime.block_on(self.client.put_image(input))
+    }
+
+    /// <p>Updates the image scanning configuration for the specified repository.</p>
+    ///
+    /// See `Ecr::put_image_scanning_configuration`.
+    pub fn put_image_scanning_configuration(
+        &self,
+        input: PutImageScanningConfigurationRequest,
+    ) -> Result<
+        PutImageScanningConfigurationResponse,
+        RusotoError<PutImageScanningConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_image_scanning_configuration(input))
+    }
+
+    /// <p>Updates the image tag mutability settings for the specified repository. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/image-tag-mutability.html">Image Tag Mutability</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    ///
+    /// See `Ecr::put_image_tag_mutability`.
+    pub fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
+        self.runtime
+            .block_on(self.client.put_image_tag_mutability(input))
+    }
+
+    /// <p>Creates or updates the lifecycle policy for the specified repository. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/LifecyclePolicies.html">Lifecycle Policy Template</a>.</p>
+    ///
+    /// See `Ecr::put_lifecycle_policy`.
+    pub fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_lifecycle_policy(input))
+    }
+
+    /// <p>Applies a repository policy to the specified repository to control access permissions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/repository-policies.html">Amazon ECR Repository Policies</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    ///
+    /// See `Ecr::set_repository_policy`.
+    pub fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
+        self.runtime
+            .block_on(self.client.set_repository_policy(input))
+    }
+
+    /// <p>Starts an image vulnerability scan. An image scan can only be started once per day on an individual image. This limit includes if an image was scanned on initial push. For more information, see <a href="https://docs.aws.amazon.com/AmazonECR/latest/userguide/image-scanning.html">Image Scanning</a> in the <i>Amazon Elastic Container Registry User Guide</i>.</p>
+    ///
+    /// See `Ecr::start_image_scan`.
+    pub fn start_image_scan(
+        &self,
+        input: StartImageScanRequest,
+    ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
+        self.runtime.block_on(self.client.start_image_scan(input))
+    }
+
+    /// <p>Starts a preview of a lifecycle policy for the specified repository. This allows you to see the results before associating the lifecycle policy with the repository.</p>
+    ///
+    /// See `Ecr::start_lifecycle_policy_preview`.
+    pub fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
+    {
+        self.runtime
+            .block_on(self.client.start_lifecycle_policy_preview(input))
+    }
+
+    /// <p>Adds specified tags to a resource with the specified ARN. Existing tags on a resource are not changed if they are not specified in the request parameters.</p>
+    ///
+    /// See `Ecr::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    ///
+    /// See `Ecr::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Uploads an image layer part to Amazon ECR.</p> <p>When an image is pushed, each new image layer is uploaded in parts. The maximum size of each image layer part can be 20971520 bytes (or about 20MB). The UploadLayerPart API is called once per each new image layer part.</p> <note> <p>This operation is used by the Amazon ECR proxy and is not generally used by customers for pulling and pushing images. In most cases, you should use the <code>docker</code> CLI to pull, tag, and push images.</p> </note></p>
+    ///
+    /// See `Ecr::upload_layer_part`.
+    pub fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
+        self.runtime.block_on(self.client.upload_layer_part(input))
+    }
+}
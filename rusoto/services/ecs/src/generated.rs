@@ -3543,25 +3543,28 @@ impl CreateCapacityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::Client(err.msg))
+                    return RusotoError::service(&res, CreateCapacityProviderError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCapacityProviderError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCapacityProviderError::LimitExceeded(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::Server(err.msg))
+                    return RusotoError::service(&res, CreateCapacityProviderError::Server(err.msg))
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::UpdateInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCapacityProviderError::UpdateInProgress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3599,13 +3602,16 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateClusterError::Client(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateClusterError::Server(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3651,30 +3657,37 @@ impl CreateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateServiceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateServiceError::Client(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(CreateServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceError::InvalidParameter(err.msg),
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateServiceError::PlatformTaskDefinitionIncompatibility(err.msg),
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(CreateServiceError::PlatformUnknown(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::PlatformUnknown(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateServiceError::Server(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::Server(err.msg))
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(CreateServiceError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceError::UnsupportedFeature(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3731,36 +3744,46 @@ impl CreateTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateTaskSetError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateTaskSetError::Client(err.msg))
+                    return RusotoError::service(&res, CreateTaskSetError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(CreateTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTaskSetError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTaskSetError::InvalidParameter(err.msg),
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTaskSetError::PlatformTaskDefinitionIncompatibility(err.msg),
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(CreateTaskSetError::PlatformUnknown(err.msg))
+                    return RusotoError::service(&res, CreateTaskSetError::PlatformUnknown(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateTaskSetError::Server(err.msg))
+                    return RusotoError::service(&res, CreateTaskSetError::Server(err.msg))
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(CreateTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTaskSetError::ServiceNotActive(err.msg),
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(CreateTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTaskSetError::ServiceNotFound(err.msg))
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(CreateTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTaskSetError::UnsupportedFeature(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3805,15 +3828,16 @@ impl DeleteAccountSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteAccountSettingError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccountSettingError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteAccountSettingError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3849,13 +3873,22 @@ impl DeleteAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAttributesError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DeleteAttributesError::TargetNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAttributesError::TargetNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3891,15 +3924,16 @@ impl DeleteCapacityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteCapacityProviderError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteCapacityProviderError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteCapacityProviderError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCapacityProviderError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteCapacityProviderError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteCapacityProviderError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3945,32 +3979,43 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteClusterError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::Client(err.msg))
                 }
                 "ClusterContainsContainerInstancesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteClusterError::ClusterContainsContainerInstances(err.msg),
                     )
                 }
                 "ClusterContainsServicesException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterContainsServices(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::ClusterContainsServices(err.msg),
+                    )
                 }
                 "ClusterContainsTasksException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterContainsTasks(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::ClusterContainsTasks(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteClusterError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::Server(err.msg))
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(DeleteClusterError::UpdateInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::UpdateInProgress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4017,19 +4062,22 @@ impl DeleteServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteServiceError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServiceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteServiceError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::Server(err.msg))
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4079,31 +4127,40 @@ impl DeleteTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteTaskSetError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteTaskSetError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteTaskSetError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTaskSetError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTaskSetError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteTaskSetError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteTaskSetError::Server(err.msg))
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTaskSetError::ServiceNotActive(err.msg),
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTaskSetError::ServiceNotFound(err.msg))
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::TaskSetNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTaskSetError::TaskSetNotFound(err.msg))
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(DeleteTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTaskSetError::UnsupportedFeature(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4149,20 +4206,28 @@ impl DeregisterContainerInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterContainerInstanceError::Client(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterContainerInstanceError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterContainerInstanceError::InvalidParameter(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterContainerInstanceError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4199,15 +4264,22 @@ impl DeregisterTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTaskDefinitionError::Client(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTaskDefinitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTaskDefinitionError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4243,15 +4315,22 @@ impl DescribeCapacityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCapacityProvidersError::Client(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCapacityProvidersError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCapacityProvidersError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4287,13 +4366,16 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeClustersError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeClustersError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClustersError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeClustersError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeClustersError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4333,20 +4415,28 @@ impl DescribeContainerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContainerInstancesError::Client(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContainerInstancesError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContainerInstancesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContainerInstancesError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4385,16 +4475,22 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeServicesError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeServicesError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeServicesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeServicesError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeServicesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4431,15 +4527,16 @@ impl DescribeTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeTaskDefinitionError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskDefinitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeTaskDefinitionError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4485,28 +4582,43 @@ impl DescribeTaskSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeTaskSetsError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeTaskSetsError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskSetsError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskSetsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeTaskSetsError::Server(err.msg))
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskSetsError::ServiceNotActive(err.msg),
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskSetsError::ServiceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTaskSetsError::UnsupportedFeature(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4549,16 +4661,19 @@ impl DescribeTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTasksError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeTasksError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeTasksError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeTasksError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTasksError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTasksError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTasksError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeTasksError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4593,10 +4708,10 @@ impl DiscoverPollEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DiscoverPollEndpointError::Client(err.msg))
+                    return RusotoError::service(&res, DiscoverPollEndpointError::Client(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DiscoverPollEndpointError::Server(err.msg))
+                    return RusotoError::service(&res, DiscoverPollEndpointError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4631,15 +4746,16 @@ impl ListAccountSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListAccountSettingsError::Client(err.msg))
+                    return RusotoError::service(&res, ListAccountSettingsError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAccountSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountSettingsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListAccountSettingsError::Server(err.msg))
+                    return RusotoError::service(&res, ListAccountSettingsError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4673,10 +4789,16 @@ impl ListAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttributesError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4711,13 +4833,13 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListClustersError::Client(err.msg))
+                    return RusotoError::service(&res, ListClustersError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListClustersError::InvalidParameter(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListClustersError::Server(err.msg))
+                    return RusotoError::service(&res, ListClustersError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4755,20 +4877,22 @@ impl ListContainerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListContainerInstancesError::Client(err.msg))
+                    return RusotoError::service(&res, ListContainerInstancesError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListContainerInstancesError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListContainerInstancesError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContainerInstancesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListContainerInstancesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListContainerInstancesError::Server(err.msg))
+                    return RusotoError::service(&res, ListContainerInstancesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4807,16 +4931,16 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListServicesError::Client(err.msg))
+                    return RusotoError::service(&res, ListServicesError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListServicesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, ListServicesError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListServicesError::InvalidParameter(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListServicesError::Server(err.msg))
+                    return RusotoError::service(&res, ListServicesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4855,18 +4979,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4905,15 +5033,22 @@ impl ListTaskDefinitionFamiliesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTaskDefinitionFamiliesError::Client(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTaskDefinitionFamiliesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTaskDefinitionFamiliesError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4949,15 +5084,16 @@ impl ListTaskDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::Client(err.msg))
+                    return RusotoError::service(&res, ListTaskDefinitionsError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTaskDefinitionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::Server(err.msg))
+                    return RusotoError::service(&res, ListTaskDefinitionsError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4996,16 +5132,20 @@ impl ListTasksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTasksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListTasksError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(&res, ListTasksError::Client(err.msg))
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListTasksError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, ListTasksError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTasksError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListTasksError::InvalidParameter(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, ListTasksError::Server(err.msg))
                 }
-                "ServerException" => return RusotoError::Service(ListTasksError::Server(err.msg)),
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(ListTasksError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTasksError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5043,13 +5183,16 @@ impl PutAccountSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutAccountSettingError::Client(err.msg))
+                    return RusotoError::service(&res, PutAccountSettingError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccountSettingError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutAccountSettingError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutAccountSettingError::Server(err.msg))
+                    return RusotoError::service(&res, PutAccountSettingError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5085,15 +5228,22 @@ impl PutAccountSettingDefaultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutAccountSettingDefaultError::Client(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAccountSettingDefaultError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutAccountSettingDefaultError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5131,18 +5281,22 @@ impl PutAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttributeLimitExceededException" => {
-                    return RusotoError::Service(PutAttributesError::AttributeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAttributesError::AttributeLimitExceeded(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(PutAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, PutAttributesError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutAttributesError::InvalidParameter(err.msg),
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(PutAttributesError::TargetNotFound(err.msg))
+                    return RusotoError::service(&res, PutAttributesError::TargetNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5187,28 +5341,38 @@ impl PutClusterCapacityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutClusterCapacityProvidersError::Client(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutClusterCapacityProvidersError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutClusterCapacityProvidersError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutClusterCapacityProvidersError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutClusterCapacityProvidersError::Server(err.msg),
+                    )
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutClusterCapacityProvidersError::UpdateInProgress(err.msg),
                     )
                 }
@@ -5249,15 +5413,22 @@ impl RegisterContainerInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterContainerInstanceError::Client(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterContainerInstanceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterContainerInstanceError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5293,15 +5464,16 @@ impl RegisterTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service(&res, RegisterTaskDefinitionError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterTaskDefinitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service(&res, RegisterTaskDefinitionError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5349,27 +5521,34 @@ impl RunTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RunTaskError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, RunTaskError::AccessDenied(err.msg))
+                }
+                "BlockedException" => {
+                    return RusotoError::service(&res, RunTaskError::Blocked(err.msg))
+                }
+                "ClientException" => {
+                    return RusotoError::service(&res, RunTaskError::Client(err.msg))
                 }
-                "BlockedException" => return RusotoError::Service(RunTaskError::Blocked(err.msg)),
-                "ClientException" => return RusotoError::Service(RunTaskError::Client(err.msg)),
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(RunTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, RunTaskError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RunTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, RunTaskError::InvalidParameter(err.msg))
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RunTaskError::PlatformTaskDefinitionIncompatibility(err.msg),
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(RunTaskError::PlatformUnknown(err.msg))
+                    return RusotoError::service(&res, RunTaskError::PlatformUnknown(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, RunTaskError::Server(err.msg))
                 }
-                "ServerException" => return RusotoError::Service(RunTaskError::Server(err.msg)),
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(RunTaskError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(&res, RunTaskError::UnsupportedFeature(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5414,14 +5593,18 @@ impl StartTaskError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartTaskError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(StartTaskError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(&res, StartTaskError::Client(err.msg))
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(StartTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, StartTaskError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, StartTaskError::InvalidParameter(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, StartTaskError::Server(err.msg))
                 }
-                "ServerException" => return RusotoError::Service(StartTaskError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -5458,14 +5641,18 @@ impl StopTaskError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopTaskError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(StopTaskError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service(&res, StopTaskError::Client(err.msg))
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(StopTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, StopTaskError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, StopTaskError::InvalidParameter(err.msg))
+                }
+                "ServerException" => {
+                    return RusotoError::service(&res, StopTaskError::Server(err.msg))
                 }
-                "ServerException" => return RusotoError::Service(StopTaskError::Server(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -5505,20 +5692,28 @@ impl SubmitAttachmentStateChangesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SubmitAttachmentStateChangesError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitAttachmentStateChangesError::Client(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SubmitAttachmentStateChangesError::InvalidParameter(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitAttachmentStateChangesError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5559,15 +5754,22 @@ impl SubmitContainerStateChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SubmitContainerStateChangeError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitContainerStateChangeError::Client(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitContainerStateChangeError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5605,18 +5807,22 @@ impl SubmitTaskStateChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitTaskStateChangeError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::Client(err.msg))
+                    return RusotoError::service(&res, SubmitTaskStateChangeError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SubmitTaskStateChangeError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::Server(err.msg))
+                    return RusotoError::service(&res, SubmitTaskStateChangeError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5657,19 +5863,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TagResourceError::Client(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5711,19 +5917,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UntagResourceError::Client(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5763,20 +5975,22 @@ impl UpdateClusterSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateClusterSettingsError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterSettingsError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterSettingsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateClusterSettingsError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5821,33 +6035,40 @@ impl UpdateContainerAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateContainerAgentError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerAgentError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerAgentError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingVersionException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::MissingVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerAgentError::MissingVersion(err.msg),
+                    )
                 }
                 "NoUpdateAvailableException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::NoUpdateAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerAgentError::NoUpdateAvailable(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateContainerAgentError::Server(err.msg))
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::UpdateInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerAgentError::UpdateInProgress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5891,24 +6112,28 @@ impl UpdateContainerInstancesStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateContainerInstancesStateError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerInstancesStateError::Client(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateContainerInstancesStateError::ClusterNotFound(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateContainerInstancesStateError::InvalidParameter(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateContainerInstancesStateError::Server(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateContainerInstancesStateError::Server(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5961,33 +6186,40 @@ impl UpdateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServiceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateServiceError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceError::InvalidParameter(err.msg),
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateServiceError::PlatformTaskDefinitionIncompatibility(err.msg),
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(UpdateServiceError::PlatformUnknown(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::PlatformUnknown(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateServiceError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::Server(err.msg))
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceError::ServiceNotActive(err.msg),
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6045,43 +6277,56 @@ impl UpdateServicePrimaryTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServicePrimaryTaskSetError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServicePrimaryTaskSetError::Client(err.msg),
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::ClusterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServicePrimaryTaskSetError::ClusterNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateServicePrimaryTaskSetError::InvalidParameter(err.msg),
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::Server(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServicePrimaryTaskSetError::Server(err.msg),
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateServicePrimaryTaskSetError::ServiceNotActive(err.msg),
                     )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::ServiceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServicePrimaryTaskSetError::ServiceNotFound(err.msg),
+                    )
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::TaskSetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServicePrimaryTaskSetError::TaskSetNotFound(err.msg),
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateServicePrimaryTaskSetError::UnsupportedFeature(err.msg),
                     )
                 }
@@ -6139,31 +6384,40 @@ impl UpdateTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateTaskSetError::AccessDenied(err.msg))
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateTaskSetError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateTaskSetError::Client(err.msg))
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTaskSetError::ClusterNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTaskSetError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateTaskSetError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateTaskSetError::Server(err.msg))
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTaskSetError::ServiceNotActive(err.msg),
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTaskSetError::ServiceNotFound(err.msg))
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::TaskSetNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTaskSetError::TaskSetNotFound(err.msg))
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(UpdateTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTaskSetError::UnsupportedFeature(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6507,6 +6761,7 @@ impl EcsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6522,6 +6777,7 @@ impl EcsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EcsClient {
         EcsClient { client, region }
     }
@@ -6535,6 +6791,9 @@ impl Ecs for EcsClient {
         input: CreateCapacityProviderRequest,
     ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCapacityProvider");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateCapacityProvider",
@@ -6557,6 +6816,9 @@ impl Ecs for EcsClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateCluster",
@@ -6578,6 +6840,9 @@ impl Ecs for EcsClient {
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateService");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateService",
@@ -6599,6 +6864,9 @@ impl Ecs for EcsClient {
         input: CreateTaskSetRequest,
     ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTaskSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateTaskSet",
@@ -6620,6 +6888,9 @@ impl Ecs for EcsClient {
         input: DeleteAccountSettingRequest,
     ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAccountSetting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteAccountSetting",
@@ -6642,6 +6913,9 @@ impl Ecs for EcsClient {
         input: DeleteAttributesRequest,
     ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteAttributes",
@@ -6663,6 +6937,9 @@ impl Ecs for EcsClient {
         input: DeleteCapacityProviderRequest,
     ) -> Result<DeleteCapacityProviderResponse, RusotoError<DeleteCapacityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCapacityProvider");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteCapacityProvider",
@@ -6685,6 +6962,9 @@ impl Ecs for EcsClient {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCluster");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteCluster",
@@ -6706,6 +6986,9 @@ impl Ecs for EcsClient {
         input: DeleteServiceRequest,
     ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteService");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteService",
@@ -6727,6 +7010,9 @@ impl Ecs for EcsClient {
         input: DeleteTaskSetRequest,
     ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTaskSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteTaskSet",
@@ -6749,6 +7035,9 @@ impl Ecs for EcsClient {
     ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterContainerInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeregisterContainerInstance",
@@ -6771,6 +7060,9 @@ impl Ecs for EcsClient {
         input: DeregisterTaskDefinitionRequest,
     ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterTaskDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeregisterTaskDefinition",
@@ -6794,6 +7086,9 @@ impl Ecs for EcsClient {
     ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCapacityProviders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeCapacityProviders",
@@ -6816,6 +7111,9 @@ impl Ecs for EcsClient {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeClusters",
@@ -6838,6 +7136,9 @@ impl Ecs for EcsClient {
     ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeContainerInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeContainerInstances",
@@ -6860,6 +7161,9 @@ impl Ecs for EcsClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeServices",
@@ -6881,6 +7185,9 @@ impl Ecs for EcsClient {
         input: DescribeTaskDefinitionRequest,
     ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTaskDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeTaskDefinition",
@@ -6903,6 +7210,9 @@ impl Ecs for EcsClient {
         input: DescribeTaskSetsRequest,
     ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTaskSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeTaskSets",
@@ -6924,6 +7234,9 @@ impl Ecs for EcsClient {
         input: DescribeTasksRequest,
     ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeTasks",
@@ -6945,6 +7258,9 @@ impl Ecs for EcsClient {
         input: DiscoverPollEndpointRequest,
     ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DiscoverPollEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DiscoverPollEndpoint",
@@ -6967,6 +7283,9 @@ impl Ecs for EcsClient {
         input: ListAccountSettingsRequest,
     ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAccountSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListAccountSettings",
@@ -6988,6 +7307,9 @@ impl Ecs for EcsClient {
         input: ListAttributesRequest,
     ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListAttributes",
@@ -7009,6 +7331,9 @@ impl Ecs for EcsClient {
         input: ListClustersRequest,
     ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListClusters",
@@ -7030,6 +7355,9 @@ impl Ecs for EcsClient {
         input: ListContainerInstancesRequest,
     ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListContainerInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListContainerInstances",
@@ -7052,6 +7380,9 @@ impl Ecs for EcsClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListServices",
@@ -7073,6 +7404,9 @@ impl Ecs for EcsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTagsForResource",
@@ -7095,6 +7429,9 @@ impl Ecs for EcsClient {
     ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTaskDefinitionFamilies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTaskDefinitionFamilies",
@@ -7117,6 +7454,9 @@ impl Ecs for EcsClient {
         input: ListTaskDefinitionsRequest,
     ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTaskDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTaskDefinitions",
@@ -7138,6 +7478,9 @@ impl Ecs for EcsClient {
         input: ListTasksRequest,
     ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTasks",
@@ -7159,6 +7502,9 @@ impl Ecs for EcsClient {
         input: PutAccountSettingRequest,
     ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAccountSetting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutAccountSetting",
@@ -7180,6 +7526,9 @@ impl Ecs for EcsClient {
         input: PutAccountSettingDefaultRequest,
     ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAccountSettingDefault");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutAccountSettingDefault",
@@ -7202,6 +7551,9 @@ impl Ecs for EcsClient {
         input: PutAttributesRequest,
     ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutAttributes",
@@ -7224,6 +7576,9 @@ impl Ecs for EcsClient {
     ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutClusterCapacityProviders");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutClusterCapacityProviders",
@@ -7247,6 +7602,9 @@ impl Ecs for EcsClient {
     ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterContainerInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.RegisterContainerInstance",
@@ -7269,6 +7627,9 @@ impl Ecs for EcsClient {
         input: RegisterTaskDefinitionRequest,
     ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterTaskDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.RegisterTaskDefinition",
@@ -7291,6 +7652,9 @@ impl Ecs for EcsClient {
         input: RunTaskRequest,
     ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RunTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonEC2ContainerServiceV20141113.RunTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7309,6 +7673,9 @@ impl Ecs for EcsClient {
         input: StartTaskRequest,
     ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.StartTask",
@@ -7330,6 +7697,9 @@ impl Ecs for EcsClient {
         input: StopTaskRequest,
     ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.StopTask",
@@ -7352,6 +7722,9 @@ impl Ecs for EcsClient {
     ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SubmitAttachmentStateChanges");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.SubmitAttachmentStateChanges",
@@ -7375,6 +7748,9 @@ impl Ecs for EcsClient {
     ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SubmitContainerStateChange");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.SubmitContainerStateChange",
@@ -7397,6 +7773,9 @@ impl Ecs for EcsClient {
         input: SubmitTaskStateChangeRequest,
     ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SubmitTaskStateChange");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.SubmitTaskStateChange",
@@ -7419,6 +7798,9 @@ impl Ecs for EcsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.TagResource",
@@ -7440,6 +7822,9 @@ impl Ecs for EcsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UntagResource",
@@ -7461,6 +7846,9 @@ impl Ecs for EcsClient {
         input: UpdateClusterSettingsRequest,
     ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateClusterSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateClusterSettings",
@@ -7483,6 +7871,9 @@ impl Ecs for EcsClient {
         input: UpdateContainerAgentRequest,
     ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateContainerAgent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateContainerAgent",
@@ -7508,6 +7899,9 @@ impl Ecs for EcsClient {
         RusotoError<UpdateContainerInstancesStateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateContainerInstancesState");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateContainerInstancesState",
@@ -7530,6 +7924,9 @@ impl Ecs for EcsClient {
         input: UpdateServiceRequest,
     ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateService");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateService",
@@ -7552,6 +7949,9 @@ impl Ecs for EcsClient {
     ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServicePrimaryTaskSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateServicePrimaryTaskSet",
@@ -7574,6 +7974,9 @@ impl Ecs for EcsClient {
         input: UpdateTaskSetRequest,
     ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTaskSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateTaskSet",
@@ -7589,3 +7992,581 @@ impl Ecs for EcsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskSetResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon ECS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EcsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EcsBlockingClient {
+    client: EcsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EcsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EcsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EcsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EcsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EcsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EcsBlockingClient {
+        EcsBlockingClient {
+            client: EcsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new capacity provider. Capacity providers are associated with an Amazon ECS cluster and are used in capacity provider strategies to facilitate cluster auto scaling.</p> <p>Only capacity providers using an Auto Scaling group can be created. Amazon ECS tasks on AWS Fargate use the <code>FARGATE</code> and <code>FARGATE_SPOT</code> capacity providers which are already created and available to all accounts in Regions supported by AWS Fargate.</p>
+    ///
+    /// See `Ecs::create_capacity_provider`.
+    pub fn create_capacity_provider(
+        &self,
+        input: CreateCapacityProviderRequest,
+    ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
+        self.runtime
+            .block_on(self.client.create_capacity_provider(input))
+    }
+
+    /// <p><p>Creates a new Amazon ECS cluster. By default, your account receives a <code>default</code> cluster when you launch your first container instance. However, you can create your own cluster with a unique name with the <code>CreateCluster</code> action.</p> <note> <p>When you call the <a>CreateCluster</a> API operation, Amazon ECS attempts to create the Amazon ECS service-linked role for your account so that required resources in other AWS services can be managed on your behalf. However, if the IAM user that makes the call does not have permissions to create the service-linked role, it is not created. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Ecs::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <p><p>Runs and maintains a desired number of tasks from a specified task definition. If the number of tasks running in a service drops below the <code>desiredCount</code>, Amazon ECS runs another copy of the task in the specified cluster. To update an existing service, see the UpdateService action.</p> <p>In addition to maintaining the desired count of tasks in your service, you can optionally run your service behind one or more load balancers. The load balancers distribute traffic across the tasks that are associated with the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and the container instance that they&#39;re hosted on is reported as healthy by the load balancer.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code> - The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> <li> <p> <code>DAEMON</code> - The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. The service scheduler also evaluates the task placement constraints for running tasks and will stop tasks that do not meet the placement constraints. When using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Service Scheduler Concepts</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </li> </ul> <p>You can optionally specify a deployment configuration for your service. The deployment is triggered by changing properties, such as the task definition or the desired count of a service, with an <a>UpdateService</a> operation. The default value for a replica service for <code>minimumHealthyPercent</code> is 100%. The default value for a daemon service for <code>minimumHealthyPercent</code> is 0%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the minimum healthy percent represents a lower limit on the number of tasks in a service that must remain in the <code>RUNNING</code> state during a deployment, as a percentage of the desired number of tasks (rounded up to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to deploy without using additional cluster capacity. For example, if your service has a desired number of four tasks and a minimum healthy percent of 50%, the scheduler might stop two existing tasks to free up cluster capacity before starting two new tasks. Tasks for services that <i>do not</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state. Tasks for services that <i>do</i> use a load balancer are considered healthy if they&#39;re in the <code>RUNNING</code> state and they&#39;re reported as healthy by the load balancer. The default value for minimum healthy percent is 100%.</p> <p>If a service is using the <code>ECS</code> deployment controller, the <b>maximum percent</b> parameter represents an upper limit on the number of tasks in a service that are allowed in the <code>RUNNING</code> or <code>PENDING</code> state during a deployment, as a percentage of the desired number of tasks (rounded down to the nearest integer), and while any container instances are in the <code>DRAINING</code> state if the service contains tasks using the EC2 launch type. This parameter enables you to define the deployment batch size. For example, if your service has a desired number of four tasks and a maximum percent value of 200%, the scheduler may start four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available). The default value for maximum percent is 200%.</p> <p>If a service is using either the <code>CODE_DEPLOY</code> or <code>EXTERNAL</code> deployment controller types and tasks that use the EC2 launch type, the <b>minimum healthy percent</b> and <b>maximum percent</b> values are used only to define the lower and upper limit on the number of the tasks in the service that remain in the <code>RUNNING</code> state while the container instances are in the <code>DRAINING</code> state. If the tasks in the service use the Fargate launch type, the minimum healthy percent and maximum percent values aren&#39;t used, although they&#39;re currently visible when describing your service.</p> <p>When creating a service that uses the <code>EXTERNAL</code> deployment controller, you can specify only parameters that aren&#39;t controlled at the task set level. The only required parameter is the service name. You control your services using the <a>CreateTaskSet</a> operation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster using the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy) with the <code>placementStrategy</code> parameter):</p> <ul> <li> <p>Sort the valid container instances, giving priority to instances that have the fewest number of running tasks for this service in their respective Availability Zone. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `Ecs::create_service`.
+    pub fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        self.runtime.block_on(self.client.create_service(input))
+    }
+
+    /// <p>Create a task set in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::create_task_set`.
+    pub fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
+        self.runtime.block_on(self.client.create_task_set(input))
+    }
+
+    /// <p>Disables an account setting for a specified IAM user, IAM role, or the root user for an account.</p>
+    ///
+    /// See `Ecs::delete_account_setting`.
+    pub fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
+        self.runtime
+            .block_on(self.client.delete_account_setting(input))
+    }
+
+    /// <p>Deletes one or more custom attributes from an Amazon ECS resource.</p>
+    ///
+    /// See `Ecs::delete_attributes`.
+    pub fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
+        self.runtime.block_on(self.client.delete_attributes(input))
+    }
+
+    /// <p>Deletes the specified capacity provider.</p> <note> <p>The <code>FARGATE</code> and <code>FARGATE_SPOT</code> capacity providers are reserved and cannot be deleted. You can disassociate them from a cluster using either the <a>PutClusterCapacityProviders</a> API or by deleting the cluster.</p> </note> <p>Prior to a capacity provider being deleted, the capacity provider must be removed from the capacity provider strategy from all services. The <a>UpdateService</a> API can be used to remove a capacity provider from a service's capacity provider strategy. When updating a service, the <code>forceNewDeployment</code> option can be used to ensure that any tasks using the Amazon EC2 instance capacity provided by the capacity provider are transitioned to use the capacity from the remaining capacity providers. Only capacity providers that are not associated with a cluster can be deleted. To remove a capacity provider from a cluster, you can either use <a>PutClusterCapacityProviders</a> or delete the cluster.</p>
+    ///
+    /// See `Ecs::delete_capacity_provider`.
+    pub fn delete_capacity_provider(
+        &self,
+        input: DeleteCapacityProviderRequest,
+    ) -> Result<DeleteCapacityProviderResponse, RusotoError<DeleteCapacityProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_capacity_provider(input))
+    }
+
+    /// <p>Deletes the specified cluster. The cluster will transition to the <code>INACTIVE</code> state. Clusters with an <code>INACTIVE</code> status may remain discoverable in your account for a period of time. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> clusters persisting.</p> <p>You must deregister all container instances from this cluster before you may delete it. You can list the container instances in a cluster with <a>ListContainerInstances</a> and deregister them with <a>DeregisterContainerInstance</a>.</p>
+    ///
+    /// See `Ecs::delete_cluster`.
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// <p><p>Deletes a specified service within a cluster. You can delete a service if you have no running tasks in it and the desired task count is zero. If the service is actively maintaining tasks, you cannot delete it, and you must update the service to a desired task count of zero. For more information, see <a>UpdateService</a>.</p> <note> <p>When you delete a service, if there are still running tasks that require cleanup, the service status moves from <code>ACTIVE</code> to <code>DRAINING</code>, and the service is no longer visible in the console or in the <a>ListServices</a> API operation. After all tasks have transitioned to either <code>STOPPING</code> or <code>STOPPED</code> status, the service status moves from <code>DRAINING</code> to <code>INACTIVE</code>. Services in the <code>DRAINING</code> or <code>INACTIVE</code> status can still be viewed with the <a>DescribeServices</a> API operation. However, in the future, <code>INACTIVE</code> services may be cleaned up and purged from Amazon ECS record keeping, and <a>DescribeServices</a> calls on those services return a <code>ServiceNotFoundException</code> error.</p> </note> <important> <p>If you attempt to create a new service with the same name as an existing service in either <code>ACTIVE</code> or <code>DRAINING</code> status, you receive an error.</p> </important></p>
+    ///
+    /// See `Ecs::delete_service`.
+    pub fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+        self.runtime.block_on(self.client.delete_service(input))
+    }
+
+    /// <p>Deletes a specified task set within a service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::delete_task_set`.
+    pub fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
+        self.runtime.block_on(self.client.delete_task_set(input))
+    }
+
+    /// <p><p>Deregisters an Amazon ECS container instance from the specified cluster. This instance is no longer available to run tasks.</p> <p>If you intend to use the container instance for some other purpose after deregistration, you should stop all of the tasks running on the container instance before deregistration. That prevents any orphaned tasks from consuming resources.</p> <p>Deregistering a container instance removes the instance from a cluster, but it does not terminate the EC2 instance. If you are finished using the instance, be sure to terminate it in the Amazon EC2 console to stop billing.</p> <note> <p>If you terminate a running container instance, Amazon ECS automatically deregisters the instance from your cluster (stopped container instances or instances with disconnected agents are not automatically deregistered when terminated).</p> </note></p>
+    ///
+    /// See `Ecs::deregister_container_instance`.
+    pub fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.deregister_container_instance(input))
+    }
+
+    /// <p><p>Deregisters the specified task definition by family and revision. Upon deregistration, the task definition is marked as <code>INACTIVE</code>. Existing tasks and services that reference an <code>INACTIVE</code> task definition continue to run without disruption. Existing services that reference an <code>INACTIVE</code> task definition can still scale up or down by modifying the service&#39;s desired count.</p> <p>You cannot use an <code>INACTIVE</code> task definition to run new tasks or create new services, and you cannot update an existing service to reference an <code>INACTIVE</code> task definition. However, there may be up to a 10-minute window following deregistration where these restrictions have not yet taken effect.</p> <note> <p>At this time, <code>INACTIVE</code> task definitions remain discoverable in your account indefinitely. However, this behavior is subject to change in the future, so you should not rely on <code>INACTIVE</code> task definitions persisting beyond the lifecycle of any associated tasks and services.</p> </note></p>
+    ///
+    /// See `Ecs::deregister_task_definition`.
+    pub fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
+        self.runtime
+            .block_on(self.client.deregister_task_definition(input))
+    }
+
+    /// <p>Describes one or more of your capacity providers.</p>
+    ///
+    /// See `Ecs::describe_capacity_providers`.
+    pub fn describe_capacity_providers(
+        &self,
+        input: DescribeCapacityProvidersRequest,
+    ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_capacity_providers(input))
+    }
+
+    /// <p>Describes one or more of your clusters.</p>
+    ///
+    /// See `Ecs::describe_clusters`.
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// <p>Describes Amazon Elastic Container Service container instances. Returns metadata about registered and remaining resources on each container instance requested.</p>
+    ///
+    /// See `Ecs::describe_container_instances`.
+    pub fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_container_instances(input))
+    }
+
+    /// <p>Describes the specified services running in your cluster.</p>
+    ///
+    /// See `Ecs::describe_services`.
+    pub fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.runtime.block_on(self.client.describe_services(input))
+    }
+
+    /// <p><p>Describes a task definition. You can specify a <code>family</code> and <code>revision</code> to find information about a specific task definition, or you can simply specify the family to find the latest <code>ACTIVE</code> revision in that family.</p> <note> <p>You can only describe <code>INACTIVE</code> task definitions while an active task or service references them.</p> </note></p>
+    ///
+    /// See `Ecs::describe_task_definition`.
+    pub fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
+        self.runtime
+            .block_on(self.client.describe_task_definition(input))
+    }
+
+    /// <p>Describes the task sets in the specified cluster and service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::describe_task_sets`.
+    pub fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
+        self.runtime.block_on(self.client.describe_task_sets(input))
+    }
+
+    /// <p>Describes a specified task or tasks.</p>
+    ///
+    /// See `Ecs::describe_tasks`.
+    pub fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
+        self.runtime.block_on(self.client.describe_tasks(input))
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Returns an endpoint for the Amazon ECS agent to poll for updates.</p></p>
+    ///
+    /// See `Ecs::discover_poll_endpoint`.
+    pub fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
+        self.runtime
+            .block_on(self.client.discover_poll_endpoint(input))
+    }
+
+    /// <p>Lists the account settings for a specified principal.</p>
+    ///
+    /// See `Ecs::list_account_settings`.
+    pub fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.list_account_settings(input))
+    }
+
+    /// <p>Lists the attributes for Amazon ECS resources within a specified target type and cluster. When you specify a target type and cluster, <code>ListAttributes</code> returns a list of attribute objects, one for each attribute on each resource. You can filter the list of results to a single attribute name to only return results that have that name. You can also filter the results by attribute name and value, for example, to see which container instances in a cluster are running a Linux AMI (<code>ecs.os-type=linux</code>). </p>
+    ///
+    /// See `Ecs::list_attributes`.
+    pub fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
+        self.runtime.block_on(self.client.list_attributes(input))
+    }
+
+    /// <p>Returns a list of existing clusters.</p>
+    ///
+    /// See `Ecs::list_clusters`.
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// <p>Returns a list of container instances in a specified cluster. You can filter the results of a <code>ListContainerInstances</code> operation with cluster query language statements inside the <code>filter</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::list_container_instances`.
+    pub fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_container_instances(input))
+    }
+
+    /// <p>Lists the services that are running in a specified cluster.</p>
+    ///
+    /// See `Ecs::list_services`.
+    pub fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.runtime.block_on(self.client.list_services(input))
+    }
+
+    /// <p>List the tags for an Amazon ECS resource.</p>
+    ///
+    /// See `Ecs::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a list of task definition families that are registered to your account (which may include task definition families that no longer have any <code>ACTIVE</code> task definition revisions).</p> <p>You can filter out task definition families that do not contain any <code>ACTIVE</code> task definition revisions by setting the <code>status</code> parameter to <code>ACTIVE</code>. You can also filter the results with the <code>familyPrefix</code> parameter.</p>
+    ///
+    /// See `Ecs::list_task_definition_families`.
+    pub fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_task_definition_families(input))
+    }
+
+    /// <p>Returns a list of task definitions that are registered to your account. You can filter the results by family name with the <code>familyPrefix</code> parameter or by status with the <code>status</code> parameter.</p>
+    ///
+    /// See `Ecs::list_task_definitions`.
+    pub fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_task_definitions(input))
+    }
+
+    /// <p>Returns a list of tasks for a specified cluster. You can filter the results by family name, by a particular container instance, or by the desired status of the task with the <code>family</code>, <code>containerInstance</code>, and <code>desiredStatus</code> parameters.</p> <p>Recently stopped tasks might appear in the returned results. Currently, stopped tasks appear in the returned results for at least one hour. </p>
+    ///
+    /// See `Ecs::list_tasks`.
+    pub fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+        self.runtime.block_on(self.client.list_tasks(input))
+    }
+
+    /// <p>Modifies an account setting. Account settings are set on a per-Region basis.</p> <p>If you change the account setting for the root user, the default settings for all of the IAM users and roles for which no individual account setting has been specified are reset. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-account-settings.html">Account Settings</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>serviceLongArnFormat</code>, <code>taskLongArnFormat</code>, or <code>containerInstanceLongArnFormat</code> are specified, the Amazon Resource Name (ARN) and resource ID format of the resource type for a specified IAM user, IAM role, or the root user for an account is affected. The opt-in and opt-out account setting must be set for each Amazon ECS resource separately. The ARN and resource ID format of a resource will be defined by the opt-in status of the IAM user or role that created the resource. You must enable this setting to use Amazon ECS features such as resource tagging.</p> <p>When <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for any new container instances that support the feature is changed. If <code>awsvpcTrunking</code> is enabled, any new container instances that support the feature are launched have the increased ENI limits available to them. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/container-instance-eni.html">Elastic Network Interface Trunking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>When <code>containerInsights</code> is specified, the default setting indicating whether CloudWatch Container Insights is enabled for your clusters is changed. If <code>containerInsights</code> is enabled, any new clusters that are created will have Container Insights enabled unless you disable it during cluster creation. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cloudwatch-container-insights.html">CloudWatch Container Insights</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::put_account_setting`.
+    pub fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
+        self.runtime
+            .block_on(self.client.put_account_setting(input))
+    }
+
+    /// <p>Modifies an account setting for all IAM users on an account for whom no individual account setting has been specified. Account settings are set on a per-Region basis.</p>
+    ///
+    /// See `Ecs::put_account_setting_default`.
+    pub fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
+        self.runtime
+            .block_on(self.client.put_account_setting_default(input))
+    }
+
+    /// <p>Create or update an attribute on an Amazon ECS resource. If the attribute does not exist, it is created. If the attribute exists, its value is replaced with the specified value. To delete an attribute, use <a>DeleteAttributes</a>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html#attributes">Attributes</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::put_attributes`.
+    pub fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
+        self.runtime.block_on(self.client.put_attributes(input))
+    }
+
+    /// <p>Modifies the available capacity providers and the default capacity provider strategy for a cluster.</p> <p>You must specify both the available capacity providers and a default capacity provider strategy for the cluster. If the specified cluster has existing capacity providers associated with it, you must specify all existing capacity providers in addition to any new ones you want to add. Any existing capacity providers associated with a cluster that are omitted from a <a>PutClusterCapacityProviders</a> API call will be disassociated with the cluster. You can only disassociate an existing capacity provider from a cluster if it's not being used by any existing tasks.</p> <p>When creating a service or running a task on a cluster, if no capacity provider or launch type is specified, then the cluster's default capacity provider strategy is used. It is recommended to define a default capacity provider strategy for your cluster, however you may specify an empty array (<code>[]</code>) to bypass defining a default strategy.</p>
+    ///
+    /// See `Ecs::put_cluster_capacity_providers`.
+    pub fn put_cluster_capacity_providers(
+        &self,
+        input: PutClusterCapacityProvidersRequest,
+    ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
+    {
+        self.runtime
+            .block_on(self.client.put_cluster_capacity_providers(input))
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Registers an EC2 instance into the specified cluster. This instance becomes available to place containers on.</p></p>
+    ///
+    /// See `Ecs::register_container_instance`.
+    pub fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.register_container_instance(input))
+    }
+
+    /// <p>Registers a new task definition from the supplied <code>family</code> and <code>containerDefinitions</code>. Optionally, you can add data volumes to your containers with the <code>volumes</code> parameter. For more information about task definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify an IAM role for your task with the <code>taskRoleArn</code> parameter. When you specify an IAM role for a task, its containers can then use the latest versions of the AWS CLI or SDKs to make API requests to the AWS services that are specified in the IAM policy associated with the role. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-iam-roles.html">IAM Roles for Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>You can specify a Docker networking mode for the containers in your task definition with the <code>networkMode</code> parameter. The available network modes correspond to those described in <a href="https://docs.docker.com/engine/reference/run/#/network-settings">Network settings</a> in the Docker run reference. If you specify the <code>awsvpc</code> network mode, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::register_task_definition`.
+    pub fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
+        self.runtime
+            .block_on(self.client.register_task_definition(input))
+    }
+
+    /// <p><p>Starts a new task using the specified task definition.</p> <p>You can allow Amazon ECS to place tasks for you, or you can customize how Amazon ECS places tasks using placement constraints and placement strategies. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>Alternatively, you can use <a>StartTask</a> to use your own scheduler or place tasks manually on specific container instances.</p> <p>The Amazon ECS API follows an eventual consistency model, due to the distributed nature of the system supporting the API. This means that the result of an API command you run that affects your Amazon ECS resources might not be immediately visible to all subsequent commands you run. Keep this in mind when you carry out an API command that immediately follows a previous API command.</p> <p>To manage eventual consistency, you can do the following:</p> <ul> <li> <p>Confirm the state of the resource before you run a command to modify it. Run the DescribeTasks command using an exponential backoff algorithm to ensure that you allow enough time for the previous command to propagate through the system. To do this, run the DescribeTasks command repeatedly, starting with a couple of seconds of wait time and increasing gradually up to five minutes of wait time.</p> </li> <li> <p>Add wait time between subsequent commands, even if the DescribeTasks command returns an accurate response. Apply an exponential backoff algorithm starting with a couple of seconds of wait time, and increase gradually up to about five minutes of wait time.</p> </li> </ul></p>
+    ///
+    /// See `Ecs::run_task`.
+    pub fn run_task(
+        &self,
+        input: RunTaskRequest,
+    ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
+        self.runtime.block_on(self.client.run_task(input))
+    }
+
+    /// <p>Starts a new task from the specified task definition on the specified container instance or instances.</p> <p>Alternatively, you can use <a>RunTask</a> to place tasks for you. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/scheduling_tasks.html">Scheduling Tasks</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::start_task`.
+    pub fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
+        self.runtime.block_on(self.client.start_task(input))
+    }
+
+    /// <p><p>Stops a running task. Any tags associated with the task will be deleted.</p> <p>When <a>StopTask</a> is called on a task, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> value and a default 30-second timeout, after which the <code>SIGKILL</code> value is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> value gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> value is sent.</p> <note> <p>The default 30-second timeout can be configured on the Amazon ECS container agent with the <code>ECS<em>CONTAINER</em>STOP_TIMEOUT</code> variable. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-config.html">Amazon ECS Container Agent Configuration</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Ecs::stop_task`.
+    pub fn stop_task(
+        &self,
+        input: StopTaskRequest,
+    ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
+        self.runtime.block_on(self.client.stop_task(input))
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that an attachment changed states.</p></p>
+    ///
+    /// See `Ecs::submit_attachment_state_changes`.
+    pub fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
+    {
+        self.runtime
+            .block_on(self.client.submit_attachment_state_changes(input))
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a container changed states.</p></p>
+    ///
+    /// See `Ecs::submit_container_state_change`.
+    pub fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
+    {
+        self.runtime
+            .block_on(self.client.submit_container_state_change(input))
+    }
+
+    /// <p><note> <p>This action is only used by the Amazon ECS agent, and it is not intended for use outside of the agent.</p> </note> <p>Sent to acknowledge that a task changed states.</p></p>
+    ///
+    /// See `Ecs::submit_task_state_change`.
+    pub fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
+        self.runtime
+            .block_on(self.client.submit_task_state_change(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    ///
+    /// See `Ecs::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    ///
+    /// See `Ecs::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Modifies the settings to use for a cluster.</p>
+    ///
+    /// See `Ecs::update_cluster_settings`.
+    pub fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_cluster_settings(input))
+    }
+
+    /// <p>Updates the Amazon ECS container agent on a specified container instance. Updating the Amazon ECS container agent does not interrupt running tasks or services on the container instance. The process for updating the agent differs depending on whether your container instance was launched with the Amazon ECS-optimized AMI or another operating system.</p> <p> <code>UpdateContainerAgent</code> requires the Amazon ECS-optimized AMI or Amazon Linux with the <code>ecs-init</code> service installed and running. For help updating the Amazon ECS container agent on other operating systems, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html#manually_update_agent">Manually Updating the Amazon ECS Container Agent</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::update_container_agent`.
+    pub fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
+        self.runtime
+            .block_on(self.client.update_container_agent(input))
+    }
+
+    /// <p>Modifies the status of an Amazon ECS container instance.</p> <p>Once a container instance has reached an <code>ACTIVE</code> state, you can change the status of a container instance to <code>DRAINING</code> to manually remove an instance from a cluster, for example to perform system updates, update the Docker daemon, or scale down the cluster size.</p> <important> <p>A container instance cannot be changed to <code>DRAINING</code> until it has reached an <code>ACTIVE</code> status. If the instance is in any other status, an error will be received.</p> </important> <p>When you set a container instance to <code>DRAINING</code>, Amazon ECS prevents new tasks from being scheduled for placement on the container instance and replacement service tasks are started on other container instances in the cluster if the resources are available. Service tasks on the container instance that are in the <code>PENDING</code> state are stopped immediately.</p> <p>Service tasks on the container instance that are in the <code>RUNNING</code> state are stopped and replaced according to the service's deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>. You can change the deployment configuration of your service using <a>UpdateService</a>.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during task replacement. For example, <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. If the minimum is 100%, the service scheduler can't remove existing tasks until the replacement tasks are considered healthy. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during task replacement, which enables you to define the replacement batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four tasks to be drained, provided that the cluster resources required to do this are available. If the maximum is 100%, then replacement tasks can't start until the draining tasks have stopped.</p> </li> </ul> <p>Any <code>PENDING</code> or <code>RUNNING</code> tasks that do not belong to a service are not affected. You must wait for them to finish or stop them manually.</p> <p>A container instance has completed draining when it has no more <code>RUNNING</code> tasks. You can verify this using <a>ListTasks</a>.</p> <p>When a container instance has been drained, you can set a container instance to <code>ACTIVE</code> status and once it has reached that status the Amazon ECS scheduler can begin scheduling tasks on the instance again.</p>
+    ///
+    /// See `Ecs::update_container_instances_state`.
+    pub fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> Result<
+        UpdateContainerInstancesStateResponse,
+        RusotoError<UpdateContainerInstancesStateError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_container_instances_state(input))
+    }
+
+    /// <p><important> <p>Updating the task placement strategies and constraints on an Amazon ECS service remains in preview and is a Beta Service as defined by and subject to the Beta Service Participation Service Terms located at <a href="https://aws.amazon.com/service-terms">https://aws.amazon.com/service-terms</a> (&quot;Beta Terms&quot;). These Beta Terms apply to your participation in this preview.</p> </important> <p>Modifies the parameters of a service.</p> <p>For services using the rolling update (<code>ECS</code>) deployment controller, the desired count, deployment configuration, network configuration, task placement constraints and strategies, or task definition used can be updated.</p> <p>For services using the blue/green (<code>CODE<em>DEPLOY</code>) deployment controller, only the desired count, deployment configuration, task placement constraints and strategies, and health check grace period can be updated using this API. If the network configuration, platform version, or task definition need to be updated, a new AWS CodeDeploy deployment should be created. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/codedeploy/latest/APIReference/API</em>CreateDeployment.html&quot;&gt;CreateDeployment</a> in the <i>AWS CodeDeploy API Reference</i>.</p> <p>For services using an external deployment controller, you can update only the desired count, task placement constraints and strategies, and health check grace period using this API. If the launch type, load balancer, network configuration, platform version, or task definition need to be updated, you should create a new task set. For more information, see <a>CreateTaskSet</a>.</p> <p>You can add to or subtract from the number of instantiations of a task definition in a service by specifying the cluster that the service is running in and a new <code>desiredCount</code> parameter.</p> <p>If you have updated the Docker image of your application, you can create a new task definition with that image and deploy it to your service. The service scheduler uses the minimum healthy percent and maximum percent parameters (in the service&#39;s deployment configuration) to determine the deployment strategy.</p> <note> <p>If your updated Docker image uses the same tag as what is in the existing task definition for your service (for example, <code>my_image:latest</code>), you do not need to create a new revision of your task definition. You can update the service using the <code>forceNewDeployment</code> option. The new tasks launched by the deployment pull the current image/tag combination from your repository when they start.</p> </note> <p>You can also update the deployment configuration of a service. When a deployment is triggered by updating the task definition of a service, the service scheduler uses the deployment configuration parameters, <code>minimumHealthyPercent</code> and <code>maximumPercent</code>, to determine the deployment strategy.</p> <ul> <li> <p>If <code>minimumHealthyPercent</code> is below 100%, the scheduler can ignore <code>desiredCount</code> temporarily during a deployment. For example, if <code>desiredCount</code> is four tasks, a minimum of 50% allows the scheduler to stop two existing tasks before starting two new tasks. Tasks for services that do not use a load balancer are considered healthy if they are in the <code>RUNNING</code> state. Tasks for services that use a load balancer are considered healthy if they are in the <code>RUNNING</code> state and the container instance they are hosted on is reported as healthy by the load balancer.</p> </li> <li> <p>The <code>maximumPercent</code> parameter represents an upper limit on the number of running tasks during a deployment, which enables you to define the deployment batch size. For example, if <code>desiredCount</code> is four tasks, a maximum of 200% starts four new tasks before stopping the four older tasks (provided that the cluster resources required to do this are available).</p> </li> </ul> <p>When <a>UpdateService</a> stops a task during a deployment, the equivalent of <code>docker stop</code> is issued to the containers running in the task. This results in a <code>SIGTERM</code> and a 30-second timeout, after which <code>SIGKILL</code> is sent and the containers are forcibly stopped. If the container handles the <code>SIGTERM</code> gracefully and exits within 30 seconds from receiving it, no <code>SIGKILL</code> is sent.</p> <p>When the service scheduler launches new tasks, it determines task placement in your cluster with the following logic:</p> <ul> <li> <p>Determine which of the container instances in your cluster can support your service&#39;s task definition (for example, they have the required CPU, memory, ports, and container instance attributes).</p> </li> <li> <p>By default, the service scheduler attempts to balance tasks across Availability Zones in this manner (although you can choose a different placement strategy):</p> <ul> <li> <p>Sort the valid container instances by the fewest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have zero, valid container instances in either zone B or C are considered optimal for placement.</p> </li> <li> <p>Place the new service task on a valid container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the fewest number of running tasks for this service.</p> </li> </ul> </li> </ul> <p>When the service scheduler stops running tasks, it attempts to maintain balance across the Availability Zones in your cluster using the following logic: </p> <ul> <li> <p>Sort the container instances by the largest number of running tasks for this service in the same Availability Zone as the instance. For example, if zone A has one running service task and zones B and C each have two, container instances in either zone B or C are considered optimal for termination.</p> </li> <li> <p>Stop the task on a container instance in an optimal Availability Zone (based on the previous steps), favoring container instances with the largest number of running tasks for this service.</p> </li> </ul></p>
+    ///
+    /// See `Ecs::update_service`.
+    pub fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+        self.runtime.block_on(self.client.update_service(input))
+    }
+
+    /// <p>Modifies which task set in a service is the primary task set. Any parameters that are updated on the primary task set in a service will transition to the service. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::update_service_primary_task_set`.
+    pub fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_service_primary_task_set(input))
+    }
+
+    /// <p>Modifies a task set. This is used when a service uses the <code>EXTERNAL</code> deployment controller type. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    ///
+    /// See `Ecs::update_task_set`.
+    pub fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
+        self.runtime.block_on(self.client.update_task_set(input))
+    }
+}
@@ -756,32 +756,37 @@ impl CreateAccessPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointAlreadyExists" => {
-                    return RusotoError::Service(CreateAccessPointError::AccessPointAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccessPointError::AccessPointAlreadyExists(err.msg),
+                    )
                 }
                 "AccessPointLimitExceeded" => {
-                    return RusotoError::Service(CreateAccessPointError::AccessPointLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccessPointError::AccessPointLimitExceeded(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateAccessPointError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateAccessPointError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateAccessPointError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccessPointError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAccessPointError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAccessPointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccessPointError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -828,32 +833,37 @@ impl CreateFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateFileSystemError::BadRequest(err.msg))
                 }
                 "FileSystemAlreadyExists" => {
-                    return RusotoError::Service(CreateFileSystemError::FileSystemAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::FileSystemAlreadyExists(err.msg),
+                    )
                 }
                 "FileSystemLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::FileSystemLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::FileSystemLimitExceeded(err.msg),
+                    )
                 }
                 "InsufficientThroughputCapacity" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemError::InsufficientThroughputCapacity(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::InternalServerError(err.msg),
+                    )
                 }
                 "ThroughputLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::ThroughputLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::ThroughputLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -912,56 +922,71 @@ impl CreateMountTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateMountTargetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateMountTargetError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMountTargetError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateMountTargetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::InternalServerError(err.msg),
+                    )
                 }
                 "IpAddressInUse" => {
-                    return RusotoError::Service(CreateMountTargetError::IpAddressInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::IpAddressInUse(err.msg),
+                    )
                 }
                 "MountTargetConflict" => {
-                    return RusotoError::Service(CreateMountTargetError::MountTargetConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::MountTargetConflict(err.msg),
+                    )
                 }
                 "NetworkInterfaceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMountTargetError::NetworkInterfaceLimitExceeded(err.msg),
                     )
                 }
                 "NoFreeAddressesInSubnet" => {
-                    return RusotoError::Service(CreateMountTargetError::NoFreeAddressesInSubnet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::NoFreeAddressesInSubnet(err.msg),
+                    )
                 }
                 "SecurityGroupLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMountTargetError::SecurityGroupLimitExceeded(err.msg),
                     )
                 }
                 "SecurityGroupNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::SecurityGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::SecurityGroupNotFound(err.msg),
+                    )
                 }
                 "SubnetNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::SubnetNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMountTargetError::SubnetNotFound(err.msg),
+                    )
                 }
                 "UnsupportedAvailabilityZone" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMountTargetError::UnsupportedAvailabilityZone(err.msg),
                     )
                 }
@@ -1013,12 +1038,17 @@ impl CreateTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(CreateTagsError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service(&res, CreateTagsError::BadRequest(err.msg))
+                }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::FileSystemNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1054,17 +1084,19 @@ impl DeleteAccessPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DeleteAccessPointError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccessPointError::AccessPointNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteAccessPointError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAccessPointError::BadRequest(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteAccessPointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccessPointError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1102,18 +1134,25 @@ impl DeleteFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteFileSystemError::BadRequest(err.msg))
                 }
                 "FileSystemInUse" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::FileSystemInUse(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1150,19 +1189,22 @@ impl DeleteFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemPolicyError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteFileSystemPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1202,20 +1244,25 @@ impl DeleteMountTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteMountTargetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteMountTargetError::BadRequest(err.msg))
                 }
                 "DependencyTimeout" => {
-                    return RusotoError::Service(DeleteMountTargetError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMountTargetError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMountTargetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMountTargetError::InternalServerError(err.msg),
+                    )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(DeleteMountTargetError::MountTargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMountTargetError::MountTargetNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1251,12 +1298,17 @@ impl DeleteTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(DeleteTagsError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service(&res, DeleteTagsError::BadRequest(err.msg))
+                }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::FileSystemNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1294,22 +1346,28 @@ impl DescribeAccessPointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DescribeAccessPointsError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccessPointsError::AccessPointNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeAccessPointsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccessPointsError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeAccessPointsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccessPointsError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAccessPointsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccessPointsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1348,20 +1406,28 @@ impl DescribeBackupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupPolicyError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupPolicyError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFound" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1398,19 +1464,22 @@ impl DescribeFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemPolicyError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeFileSystemPolicyError::InternalServerError(err.msg),
                     )
                 }
                 "PolicyNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemPolicyError::PolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1446,17 +1515,22 @@ impl DescribeFileSystemsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemsError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemsError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeFileSystemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1494,17 +1568,20 @@ impl DescribeLifecycleConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeLifecycleConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLifecycleConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeLifecycleConfigurationError::FileSystemNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeLifecycleConfigurationError::InternalServerError(err.msg),
                     )
                 }
@@ -1550,22 +1627,26 @@ impl DescribeMountTargetSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMountTargetSecurityGroupsError::BadRequest(err.msg),
                     )
                 }
                 "IncorrectMountTargetState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMountTargetSecurityGroupsError::IncorrectMountTargetState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMountTargetSecurityGroupsError::InternalServerError(err.msg),
                     )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
                     )
                 }
@@ -1614,27 +1695,34 @@ impl DescribeMountTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMountTargetsError::AccessPointNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeMountTargetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMountTargetsError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMountTargetsError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeMountTargetsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMountTargetsError::InternalServerError(err.msg),
+                    )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::MountTargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMountTargetsError::MountTargetNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1672,13 +1760,19 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1716,22 +1810,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessPointNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessPointNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1776,32 +1876,38 @@ impl ModifyMountTargetSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(ModifyMountTargetSecurityGroupsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyMountTargetSecurityGroupsError::BadRequest(err.msg),
+                    )
                 }
                 "IncorrectMountTargetState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyMountTargetSecurityGroupsError::IncorrectMountTargetState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyMountTargetSecurityGroupsError::InternalServerError(err.msg),
                     )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
                     )
                 }
                 "SecurityGroupLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyMountTargetSecurityGroupsError::SecurityGroupLimitExceeded(err.msg),
                     )
                 }
                 "SecurityGroupNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound(err.msg),
                     )
                 }
@@ -1854,18 +1960,25 @@ impl PutBackupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(PutBackupPolicyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutBackupPolicyError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(PutBackupPolicyError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutBackupPolicyError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBackupPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutBackupPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutBackupPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1906,22 +2019,28 @@ impl PutFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileSystemPolicyError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutFileSystemPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFileSystemPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::InvalidPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFileSystemPolicyError::InvalidPolicy(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1962,22 +2081,26 @@ impl PutLifecycleConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(PutLifecycleConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLifecycleConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutLifecycleConfigurationError::FileSystemNotFound(err.msg),
                     )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutLifecycleConfigurationError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutLifecycleConfigurationError::InternalServerError(err.msg),
                     )
                 }
@@ -2022,14 +2145,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(TagResourceError::AccessPointNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::AccessPointNotFound(err.msg),
+                    )
+                }
+                "BadRequest" => {
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
-                "BadRequest" => return RusotoError::Service(TagResourceError::BadRequest(err.msg)),
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(TagResourceError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2068,16 +2202,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(UntagResourceError::AccessPointNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::AccessPointNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UntagResourceError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2122,33 +2265,43 @@ impl UpdateFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UpdateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateFileSystemError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UpdateFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFileSystemError::IncorrectFileSystemLifeCycleState(err.msg),
                     )
                 }
                 "InsufficientThroughputCapacity" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFileSystemError::InsufficientThroughputCapacity(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::InternalServerError(err.msg),
+                    )
                 }
                 "ThroughputLimitExceeded" => {
-                    return RusotoError::Service(UpdateFileSystemError::ThroughputLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::ThroughputLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequests" => {
-                    return RusotoError::Service(UpdateFileSystemError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2350,6 +2503,7 @@ impl EfsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2365,6 +2519,7 @@ impl EfsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EfsClient {
         EfsClient { client, region }
     }
@@ -2382,6 +2537,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("CreateAccessPoint");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2414,6 +2572,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("CreateFileSystem");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2446,6 +2607,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("CreateMountTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2481,6 +2645,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2515,6 +2682,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DeleteAccessPoint");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2546,6 +2716,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DeleteFileSystem");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2577,6 +2750,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DeleteFileSystemPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2608,6 +2784,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DeleteMountTarget");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2639,6 +2818,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2670,6 +2852,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeAccessPoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2717,6 +2902,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeBackupPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2749,6 +2937,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeFileSystemPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2778,6 +2969,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeFileSystems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2826,6 +3020,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeLifecycleConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2861,6 +3058,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeMountTargetSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2892,6 +3092,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeMountTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2942,6 +3145,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2983,6 +3189,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3024,6 +3233,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("ModifyMountTargetSecurityGroups");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3060,6 +3272,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("PutBackupPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3095,6 +3310,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("PutFileSystemPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3131,6 +3349,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("PutLifecycleConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3166,6 +3387,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3200,6 +3424,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3237,6 +3464,9 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation_name("UpdateFileSystem");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3259,3 +3489,324 @@ impl Efs for EfsClient {
         }
     }
 }
+
+/// A blocking client for the EFS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EfsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EfsBlockingClient {
+    client: EfsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EfsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EfsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EfsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EfsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EfsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EfsBlockingClient {
+        EfsBlockingClient {
+            client: EfsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an EFS access point. An access point is an application-specific view into an EFS file system that applies an operating system user and group, and a file system path, to any file system request made through the access point. The operating system user and group override any identity information provided by the NFS client. The file system path is exposed as the access point's root directory. Applications using the access point can only access data in its own directory and below. To learn more, see <a href="https://docs.aws.amazon.com/efs/latest/ug/efs-access-points.html">Mounting a File System Using EFS Access Points</a>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:CreateAccessPoint</code> action.</p>
+    ///
+    /// See `Efs::create_access_point`.
+    pub fn create_access_point(
+        &self,
+        input: CreateAccessPointRequest,
+    ) -> Result<AccessPointDescription, RusotoError<CreateAccessPointError>> {
+        self.runtime
+            .block_on(self.client.create_access_point(input))
+    }
+
+    /// <p>Creates a new, empty file system. The operation requires a creation token in the request that Amazon EFS uses to ensure idempotent creation (calling the operation with same creation token has no effect). If a file system does not currently exist that is owned by the caller's AWS account with the specified creation token, this operation does the following:</p> <ul> <li> <p>Creates a new, empty file system. The file system will have an Amazon EFS assigned ID, and an initial lifecycle state <code>creating</code>.</p> </li> <li> <p>Returns with the description of the created file system.</p> </li> </ul> <p>Otherwise, this operation returns a <code>FileSystemAlreadyExists</code> error with the ID of the existing file system.</p> <note> <p>For basic use cases, you can use a randomly generated UUID for the creation token.</p> </note> <p> The idempotent operation allows you to retry a <code>CreateFileSystem</code> call without risk of creating an extra file system. This can happen when an initial call fails in a way that leaves it uncertain whether or not a file system was actually created. An example might be that a transport level timeout occurred or your connection was reset. As long as you use the same creation token, if the initial call had succeeded in creating a file system, the client can learn of its existence from the <code>FileSystemAlreadyExists</code> error.</p> <note> <p>The <code>CreateFileSystem</code> call returns while the file system's lifecycle state is still <code>creating</code>. You can check the file system creation status by calling the <a>DescribeFileSystems</a> operation, which among other things returns the file system state.</p> </note> <p>This operation also takes an optional <code>PerformanceMode</code> parameter that you choose for your file system. We recommend <code>generalPurpose</code> performance mode for most file systems. File systems using the <code>maxIO</code> performance mode can scale to higher levels of aggregate throughput and operations per second with a tradeoff of slightly higher latencies for most file operations. The performance mode can't be changed after the file system has been created. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/performance.html#performancemodes.html">Amazon EFS: Performance Modes</a>.</p> <p>After the file system is fully created, Amazon EFS sets its lifecycle state to <code>available</code>, at which point you can create one or more mount targets for the file system in your VPC. For more information, see <a>CreateMountTarget</a>. You mount your Amazon EFS file system on an EC2 instances in your VPC by using the mount target. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html">Amazon EFS: How it Works</a>. </p> <p> This operation requires permissions for the <code>elasticfilesystem:CreateFileSystem</code> action. </p>
+    ///
+    /// See `Efs::create_file_system`.
+    pub fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<FileSystemDescription, RusotoError<CreateFileSystemError>> {
+        self.runtime.block_on(self.client.create_file_system(input))
+    }
+
+    /// <p><p>Creates a mount target for a file system. You can then mount the file system on EC2 instances by using the mount target.</p> <p>You can create one mount target in each Availability Zone in your VPC. All EC2 instances in a VPC within a given Availability Zone share a single mount target for a given file system. If you have multiple subnets in an Availability Zone, you create a mount target in one of the subnets. EC2 instances do not need to be in the same subnet as the mount target in order to access their file system. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html">Amazon EFS: How it Works</a>. </p> <p>In the request, you also specify a file system ID for which you are creating the mount target and the file system&#39;s lifecycle state must be <code>available</code>. For more information, see <a>DescribeFileSystems</a>.</p> <p>In the request, you also provide a subnet ID, which determines the following:</p> <ul> <li> <p>VPC in which Amazon EFS creates the mount target</p> </li> <li> <p>Availability Zone in which Amazon EFS creates the mount target</p> </li> <li> <p>IP address range from which Amazon EFS selects the IP address of the mount target (if you don&#39;t specify an IP address in the request)</p> </li> </ul> <p>After creating the mount target, Amazon EFS returns a response that includes, a <code>MountTargetId</code> and an <code>IpAddress</code>. You use this IP address when mounting the file system in an EC2 instance. You can also use the mount target&#39;s DNS name when mounting the file system. The EC2 instance on which you mount the file system by using the mount target can resolve the mount target&#39;s DNS name to its IP address. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/how-it-works.html#how-it-works-implementation">How it Works: Implementation Overview</a>. </p> <p>Note that you can create mount targets for a file system in only one VPC, and there can be only one mount target per Availability Zone. That is, if the file system already has one or more mount targets created for it, the subnet specified in the request to add another mount target must meet the following requirements:</p> <ul> <li> <p>Must belong to the same VPC as the subnets of the existing mount targets</p> </li> <li> <p>Must not be in the same Availability Zone as any of the subnets of the existing mount targets</p> </li> </ul> <p>If the request satisfies the requirements, Amazon EFS does the following:</p> <ul> <li> <p>Creates a new mount target in the specified subnet.</p> </li> <li> <p>Also creates a new network interface in the subnet as follows:</p> <ul> <li> <p>If the request provides an <code>IpAddress</code>, Amazon EFS assigns that IP address to the network interface. Otherwise, Amazon EFS assigns a free address in the subnet (in the same way that the Amazon EC2 <code>CreateNetworkInterface</code> call does when a request does not specify a primary private IP address).</p> </li> <li> <p>If the request provides <code>SecurityGroups</code>, this network interface is associated with those security groups. Otherwise, it belongs to the default security group for the subnet&#39;s VPC.</p> </li> <li> <p>Assigns the description <code>Mount target <i>fsmt-id</i> for file system <i>fs-id</i> </code> where <code> <i>fsmt-id</i> </code> is the mount target ID, and <code> <i>fs-id</i> </code> is the <code>FileSystemId</code>.</p> </li> <li> <p>Sets the <code>requesterManaged</code> property of the network interface to <code>true</code>, and the <code>requesterId</code> value to <code>EFS</code>.</p> </li> </ul> <p>Each Amazon EFS mount target has one corresponding requester-managed EC2 network interface. After the network interface is created, Amazon EFS sets the <code>NetworkInterfaceId</code> field in the mount target&#39;s description to the network interface ID, and the <code>IpAddress</code> field to its address. If network interface creation fails, the entire <code>CreateMountTarget</code> operation fails.</p> </li> </ul> <note> <p>The <code>CreateMountTarget</code> call returns only after creating the network interface, but while the mount target state is still <code>creating</code>, you can check the mount target creation status by calling the <a>DescribeMountTargets</a> operation, which among other things returns the mount target state.</p> </note> <p>We recommend that you create a mount target in each of the Availability Zones. There are cost considerations for using a file system in an Availability Zone through a mount target created in another Availability Zone. For more information, see <a href="http://aws.amazon.com/efs/">Amazon EFS</a>. In addition, by always using a mount target local to the instance&#39;s Availability Zone, you eliminate a partial failure scenario. If the Availability Zone in which your mount target is created goes down, then you can&#39;t access your file system through that mount target. </p> <p>This operation requires permissions for the following action on the file system:</p> <ul> <li> <p> <code>elasticfilesystem:CreateMountTarget</code> </p> </li> </ul> <p>This operation also requires permissions for the following Amazon EC2 actions:</p> <ul> <li> <p> <code>ec2:DescribeSubnets</code> </p> </li> <li> <p> <code>ec2:DescribeNetworkInterfaces</code> </p> </li> <li> <p> <code>ec2:CreateNetworkInterface</code> </p> </li> </ul></p>
+    ///
+    /// See `Efs::create_mount_target`.
+    pub fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> Result<MountTargetDescription, RusotoError<CreateMountTargetError>> {
+        self.runtime
+            .block_on(self.client.create_mount_target(input))
+    }
+
+    /// <p>Creates or overwrites tags associated with a file system. Each tag is a key-value pair. If a tag key specified in the request already exists on the file system, this operation overwrites its value with the value provided in the request. If you add the <code>Name</code> tag to your file system, Amazon EFS returns it in the response to the <a>DescribeFileSystems</a> operation. </p> <p>This operation requires permission for the <code>elasticfilesystem:CreateTags</code> action.</p>
+    ///
+    /// See `Efs::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Deletes the specified access point. After deletion is complete, new clients can no longer connect to the access points. Clients connected to the access point at the time of deletion will continue to function until they terminate their connection.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteAccessPoint</code> action.</p>
+    ///
+    /// See `Efs::delete_access_point`.
+    pub fn delete_access_point(
+        &self,
+        input: DeleteAccessPointRequest,
+    ) -> Result<(), RusotoError<DeleteAccessPointError>> {
+        self.runtime
+            .block_on(self.client.delete_access_point(input))
+    }
+
+    /// <p>Deletes a file system, permanently severing access to its contents. Upon return, the file system no longer exists and you can't access any contents of the deleted file system.</p> <p> You can't delete a file system that is in use. That is, if the file system has any mount targets, you must first delete them. For more information, see <a>DescribeMountTargets</a> and <a>DeleteMountTarget</a>. </p> <note> <p>The <code>DeleteFileSystem</code> call returns while the file system state is still <code>deleting</code>. You can check the file system deletion status by calling the <a>DescribeFileSystems</a> operation, which returns a list of file systems in your account. If you pass file system ID or creation token for the deleted file system, the <a>DescribeFileSystems</a> returns a <code>404 FileSystemNotFound</code> error.</p> </note> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteFileSystem</code> action.</p>
+    ///
+    /// See `Efs::delete_file_system`.
+    pub fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<(), RusotoError<DeleteFileSystemError>> {
+        self.runtime.block_on(self.client.delete_file_system(input))
+    }
+
+    /// <p>Deletes the <code>FileSystemPolicy</code> for the specified file system. The default <code>FileSystemPolicy</code> goes into effect once the existing policy is deleted. For more information about the default file system policy, see <a href="https://docs.aws.amazon.com/efs/latest/ug/res-based-policies-efs.html">Using Resource-based Policies with EFS</a>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteFileSystemPolicy</code> action.</p>
+    ///
+    /// See `Efs::delete_file_system_policy`.
+    pub fn delete_file_system_policy(
+        &self,
+        input: DeleteFileSystemPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteFileSystemPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_file_system_policy(input))
+    }
+
+    /// <p><p>Deletes the specified mount target.</p> <p>This operation forcibly breaks any mounts of the file system by using the mount target that is being deleted, which might disrupt instances or applications using those mounts. To avoid applications getting cut off abruptly, you might consider unmounting any mounts of the mount target, if feasible. The operation also deletes the associated network interface. Uncommitted writes might be lost, but breaking a mount target using this operation does not corrupt the file system itself. The file system you created remains. You can mount an EC2 instance in your VPC by using another mount target.</p> <p>This operation requires permissions for the following action on the file system:</p> <ul> <li> <p> <code>elasticfilesystem:DeleteMountTarget</code> </p> </li> </ul> <note> <p>The <code>DeleteMountTarget</code> call returns while the mount target state is still <code>deleting</code>. You can check the mount target deletion by calling the <a>DescribeMountTargets</a> operation, which returns a list of mount target descriptions for the given file system. </p> </note> <p>The operation also requires permissions for the following Amazon EC2 action on the mount target&#39;s network interface:</p> <ul> <li> <p> <code>ec2:DeleteNetworkInterface</code> </p> </li> </ul></p>
+    ///
+    /// See `Efs::delete_mount_target`.
+    pub fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> Result<(), RusotoError<DeleteMountTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_mount_target(input))
+    }
+
+    /// <p>Deletes the specified tags from a file system. If the <code>DeleteTags</code> request includes a tag key that doesn't exist, Amazon EFS ignores it and doesn't cause an error. For more information about tags and related restrictions, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DeleteTags</code> action.</p>
+    ///
+    /// See `Efs::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Returns the description of a specific Amazon EFS access point if the <code>AccessPointId</code> is provided. If you provide an EFS <code>FileSystemId</code>, it returns descriptions of all access points for that file system. You can provide either an <code>AccessPointId</code> or a <code>FileSystemId</code> in the request, but not both. </p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeAccessPoints</code> action.</p>
+    ///
+    /// See `Efs::describe_access_points`.
+    pub fn describe_access_points(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> Result<DescribeAccessPointsResponse, RusotoError<DescribeAccessPointsError>> {
+        self.runtime
+            .block_on(self.client.describe_access_points(input))
+    }
+
+    /// <p>Returns the backup policy for the specified EFS file system.</p>
+    ///
+    /// See `Efs::describe_backup_policy`.
+    pub fn describe_backup_policy(
+        &self,
+        input: DescribeBackupPolicyRequest,
+    ) -> Result<BackupPolicyDescription, RusotoError<DescribeBackupPolicyError>> {
+        self.runtime
+            .block_on(self.client.describe_backup_policy(input))
+    }
+
+    /// <p>Returns the <code>FileSystemPolicy</code> for the specified EFS file system.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeFileSystemPolicy</code> action.</p>
+    ///
+    /// See `Efs::describe_file_system_policy`.
+    pub fn describe_file_system_policy(
+        &self,
+        input: DescribeFileSystemPolicyRequest,
+    ) -> Result<FileSystemPolicyDescription, RusotoError<DescribeFileSystemPolicyError>> {
+        self.runtime
+            .block_on(self.client.describe_file_system_policy(input))
+    }
+
+    /// <p>Returns the description of a specific Amazon EFS file system if either the file system <code>CreationToken</code> or the <code>FileSystemId</code> is provided. Otherwise, it returns descriptions of all file systems owned by the caller's AWS account in the AWS Region of the endpoint that you're calling.</p> <p>When retrieving all file system descriptions, you can optionally specify the <code>MaxItems</code> parameter to limit the number of descriptions in a response. Currently, this number is automatically set to 10. If more file system descriptions remain, Amazon EFS returns a <code>NextMarker</code>, an opaque token, in the response. In this case, you should send a subsequent request with the <code>Marker</code> request parameter set to the value of <code>NextMarker</code>. </p> <p>To retrieve a list of your file system descriptions, this operation is used in an iterative process, where <code>DescribeFileSystems</code> is called first without the <code>Marker</code> and then the operation continues to call it with the <code>Marker</code> parameter set to the value of the <code>NextMarker</code> from the previous response until the response has no <code>NextMarker</code>. </p> <p> The order of file systems returned in the response of one <code>DescribeFileSystems</code> call and the order of file systems returned across the responses of a multi-call iteration is unspecified. </p> <p> This operation requires permissions for the <code>elasticfilesystem:DescribeFileSystems</code> action. </p>
+    ///
+    /// See `Efs::describe_file_systems`.
+    pub fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+        self.runtime
+            .block_on(self.client.describe_file_systems(input))
+    }
+
+    /// <p>Returns the current <code>LifecycleConfiguration</code> object for the specified Amazon EFS file system. EFS lifecycle management uses the <code>LifecycleConfiguration</code> object to identify which files to move to the EFS Infrequent Access (IA) storage class. For a file system without a <code>LifecycleConfiguration</code> object, the call returns an empty array in the response.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeLifecycleConfiguration</code> operation.</p>
+    ///
+    /// See `Efs::describe_lifecycle_configuration`.
+    pub fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> Result<LifecycleConfigurationDescription, RusotoError<DescribeLifecycleConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_lifecycle_configuration(input))
+    }
+
+    /// <p><p>Returns the security groups currently in effect for a mount target. This operation requires that the network interface of the mount target has been created and the lifecycle state of the mount target is not <code>deleted</code>.</p> <p>This operation requires permissions for the following actions:</p> <ul> <li> <p> <code>elasticfilesystem:DescribeMountTargetSecurityGroups</code> action on the mount target&#39;s file system. </p> </li> <li> <p> <code>ec2:DescribeNetworkInterfaceAttribute</code> action on the mount target&#39;s network interface. </p> </li> </ul></p>
+    ///
+    /// See `Efs::describe_mount_target_security_groups`.
+    pub fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> Result<
+        DescribeMountTargetSecurityGroupsResponse,
+        RusotoError<DescribeMountTargetSecurityGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_mount_target_security_groups(input))
+    }
+
+    /// <p>Returns the descriptions of all the current mount targets, or a specific mount target, for a file system. When requesting all of the current mount targets, the order of mount targets returned in the response is unspecified.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeMountTargets</code> action, on either the file system ID that you specify in <code>FileSystemId</code>, or on the file system of the mount target that you specify in <code>MountTargetId</code>.</p>
+    ///
+    /// See `Efs::describe_mount_targets`.
+    pub fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> Result<DescribeMountTargetsResponse, RusotoError<DescribeMountTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_mount_targets(input))
+    }
+
+    /// <p>Returns the tags associated with a file system. The order of tags returned in the response of one <code>DescribeTags</code> call and the order of tags returned across the responses of a multiple-call iteration (when using pagination) is unspecified. </p> <p> This operation requires permissions for the <code>elasticfilesystem:DescribeTags</code> action. </p>
+    ///
+    /// See `Efs::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Lists all tags for a top-level EFS resource. You must provide the ID of the resource that you want to retrieve the tags for.</p> <p>This operation requires permissions for the <code>elasticfilesystem:DescribeAccessPoints</code> action.</p>
+    ///
+    /// See `Efs::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p><p>Modifies the set of security groups in effect for a mount target.</p> <p>When you create a mount target, Amazon EFS also creates a new network interface. For more information, see <a>CreateMountTarget</a>. This operation replaces the security groups in effect for the network interface associated with a mount target, with the <code>SecurityGroups</code> provided in the request. This operation requires that the network interface of the mount target has been created and the lifecycle state of the mount target is not <code>deleted</code>. </p> <p>The operation requires permissions for the following actions:</p> <ul> <li> <p> <code>elasticfilesystem:ModifyMountTargetSecurityGroups</code> action on the mount target&#39;s file system. </p> </li> <li> <p> <code>ec2:ModifyNetworkInterfaceAttribute</code> action on the mount target&#39;s network interface. </p> </li> </ul></p>
+    ///
+    /// See `Efs::modify_mount_target_security_groups`.
+    pub fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> Result<(), RusotoError<ModifyMountTargetSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.modify_mount_target_security_groups(input))
+    }
+
+    /// <p>Updates the file system's backup policy. Use this action to start or stop automatic backups of the file system. </p>
+    ///
+    /// See `Efs::put_backup_policy`.
+    pub fn put_backup_policy(
+        &self,
+        input: PutBackupPolicyRequest,
+    ) -> Result<BackupPolicyDescription, RusotoError<PutBackupPolicyError>> {
+        self.runtime.block_on(self.client.put_backup_policy(input))
+    }
+
+    /// <p>Applies an Amazon EFS <code>FileSystemPolicy</code> to an Amazon EFS file system. A file system policy is an IAM resource-based policy and can contain multiple policy statements. A file system always has exactly one file system policy, which can be the default policy or an explicit policy set or updated using this API operation. When an explicit policy is set, it overrides the default policy. For more information about the default file system policy, see <a href="https://docs.aws.amazon.com/efs/latest/ug/iam-access-control-nfs-efs.html#default-filesystempolicy">Default EFS File System Policy</a>. </p> <p>This operation requires permissions for the <code>elasticfilesystem:PutFileSystemPolicy</code> action.</p>
+    ///
+    /// See `Efs::put_file_system_policy`.
+    pub fn put_file_system_policy(
+        &self,
+        input: PutFileSystemPolicyRequest,
+    ) -> Result<FileSystemPolicyDescription, RusotoError<PutFileSystemPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_file_system_policy(input))
+    }
+
+    /// <p>Enables lifecycle management by creating a new <code>LifecycleConfiguration</code> object. A <code>LifecycleConfiguration</code> object defines when files in an Amazon EFS file system are automatically transitioned to the lower-cost EFS Infrequent Access (IA) storage class. A <code>LifecycleConfiguration</code> applies to all files in a file system.</p> <p>Each Amazon EFS file system supports one lifecycle configuration, which applies to all files in the file system. If a <code>LifecycleConfiguration</code> object already exists for the specified file system, a <code>PutLifecycleConfiguration</code> call modifies the existing configuration. A <code>PutLifecycleConfiguration</code> call with an empty <code>LifecyclePolicies</code> array in the request body deletes any existing <code>LifecycleConfiguration</code> and disables lifecycle management.</p> <p>In the request, specify the following: </p> <ul> <li> <p>The ID for the file system for which you are enabling, disabling, or modifying lifecycle management.</p> </li> <li> <p>A <code>LifecyclePolicies</code> array of <code>LifecyclePolicy</code> objects that define when files are moved to the IA storage class. The array can contain only one <code>LifecyclePolicy</code> item.</p> </li> </ul> <p>This operation requires permissions for the <code>elasticfilesystem:PutLifecycleConfiguration</code> operation.</p> <p>To apply a <code>LifecycleConfiguration</code> object to an encrypted file system, you need the same AWS Key Management Service (AWS KMS) permissions as when you created the encrypted file system. </p>
+    ///
+    /// See `Efs::put_lifecycle_configuration`.
+    pub fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> Result<LifecycleConfigurationDescription, RusotoError<PutLifecycleConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_lifecycle_configuration(input))
+    }
+
+    /// <p>Creates a tag for an EFS resource. You can create tags for EFS file systems and access points using this API operation.</p> <p>This operation requires permissions for the <code>elasticfilesystem:TagResource</code> action.</p>
+    ///
+    /// See `Efs::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from an EFS resource. You can remove tags from EFS file systems and access points using this API operation.</p> <p>This operation requires permissions for the <code>elasticfilesystem:UntagResource</code> action.</p>
+    ///
+    /// See `Efs::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the throughput mode or the amount of provisioned throughput of an existing file system.</p>
+    ///
+    /// See `Efs::update_file_system`.
+    pub fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<FileSystemDescription, RusotoError<UpdateFileSystemError>> {
+        self.runtime.block_on(self.client.update_file_system(input))
+    }
+}
@@ -1111,27 +1111,37 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateClusterError::Client(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateClusterError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateClusterError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateClusterError::Server(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnsupportedAvailabilityZoneException" => {
-                    return RusotoError::Service(CreateClusterError::UnsupportedAvailabilityZone(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::UnsupportedAvailabilityZone(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1177,26 +1187,32 @@ impl CreateFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateFargateProfileError::Client(err.msg))
+                    return RusotoError::service(&res, CreateFargateProfileError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateFargateProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFargateProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFargateProfileError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFargateProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateFargateProfileError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFargateProfileError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateFargateProfileError::Server(err.msg))
+                    return RusotoError::service(&res, CreateFargateProfileError::Server(err.msg))
                 }
                 "UnsupportedAvailabilityZoneException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFargateProfileError::UnsupportedAvailabilityZone(err.msg),
                     )
                 }
@@ -1247,27 +1263,37 @@ impl CreateNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateNodegroupError::Client(err.msg))
+                    return RusotoError::service(&res, CreateNodegroupError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodegroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNodegroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodegroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateNodegroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateNodegroupError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNodegroupError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodegroupError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateNodegroupError::Server(err.msg))
+                    return RusotoError::service(&res, CreateNodegroupError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateNodegroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodegroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1311,19 +1337,25 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteClusterError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::Client(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteClusterError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteClusterError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1363,20 +1395,22 @@ impl DeleteFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteFargateProfileError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFargateProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFargateProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteFargateProfileError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1419,22 +1453,31 @@ impl DeleteNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteNodegroupError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteNodegroupError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNodegroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteNodegroupError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNodegroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteNodegroupError::Server(err.msg))
+                    return RusotoError::service(&res, DeleteNodegroupError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNodegroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1475,16 +1518,22 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeClusterError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeClusterError::Client(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeClusterError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeClusterError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1523,20 +1572,22 @@ impl DescribeFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeFargateProfileError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFargateProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFargateProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeFargateProfileError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1577,21 +1628,28 @@ impl DescribeNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeNodegroupError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeNodegroupError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNodegroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNodegroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNodegroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeNodegroupError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeNodegroupError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeNodegroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNodegroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1631,16 +1689,22 @@ impl DescribeUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeUpdateError::Client(err.msg))
+                    return RusotoError::service(&res, DescribeUpdateError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUpdateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUpdateError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUpdateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeUpdateError::Server(err.msg))
+                    return RusotoError::service(&res, DescribeUpdateError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1679,16 +1743,19 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListClustersError::Client(err.msg))
+                    return RusotoError::service(&res, ListClustersError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListClustersError::InvalidParameter(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListClustersError::Server(err.msg))
+                    return RusotoError::service(&res, ListClustersError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListClustersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClustersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1727,20 +1794,22 @@ impl ListFargateProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListFargateProfilesError::Client(err.msg))
+                    return RusotoError::service(&res, ListFargateProfilesError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFargateProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFargateProfilesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFargateProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFargateProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListFargateProfilesError::Server(err.msg))
+                    return RusotoError::service(&res, ListFargateProfilesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1781,19 +1850,28 @@ impl ListNodegroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListNodegroupsError::Client(err.msg))
+                    return RusotoError::service(&res, ListNodegroupsError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListNodegroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNodegroupsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListNodegroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNodegroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListNodegroupsError::Server(err.msg))
+                    return RusotoError::service(&res, ListNodegroupsError::Server(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListNodegroupsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNodegroupsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1829,10 +1907,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1869,16 +1950,16 @@ impl ListUpdatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListUpdatesError::Client(err.msg))
+                    return RusotoError::service(&res, ListUpdatesError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUpdatesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListUpdatesError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUpdatesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListUpdatesError::ResourceNotFound(err.msg))
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListUpdatesError::Server(err.msg))
+                    return RusotoError::service(&res, ListUpdatesError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1913,10 +1994,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1949,10 +2030,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1993,26 +2074,34 @@ impl UpdateClusterConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateClusterConfigError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateClusterConfigError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2057,26 +2146,34 @@ impl UpdateClusterVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateClusterVersionError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterVersionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterVersionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateClusterVersionError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2121,28 +2218,34 @@ impl UpdateNodegroupConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateNodegroupConfigError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupConfigError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupConfigError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupConfigError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateNodegroupConfigError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2187,30 +2290,34 @@ impl UpdateNodegroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateNodegroupVersionError::Client(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupVersionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupVersionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodegroupVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::Server(err.msg))
+                    return RusotoError::service(&res, UpdateNodegroupVersionError::Server(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2380,6 +2487,7 @@ impl EksClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2395,6 +2503,7 @@ impl EksClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EksClient {
         EksClient { client, region }
     }
@@ -2411,6 +2520,9 @@ impl Eks for EksClient {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2445,6 +2557,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("CreateFargateProfile");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2476,6 +2591,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("CreateNodegroup");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2507,6 +2625,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation_name("DeleteCluster");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2539,6 +2660,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation_name("DeleteFargateProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2571,6 +2695,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation_name("DeleteNodegroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2599,6 +2726,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("DescribeCluster");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2631,6 +2761,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("DescribeFargateProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2663,6 +2796,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("DescribeNodegroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2695,6 +2831,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("DescribeUpdate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2729,6 +2868,9 @@ impl Eks for EksClient {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("ListClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2769,6 +2911,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("ListFargateProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2806,6 +2951,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("ListNodegroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2843,6 +2991,9 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2871,6 +3022,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation_name("ListUpdates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2911,6 +3065,9 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2942,6 +3099,9 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2976,6 +3136,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/update-config", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("UpdateClusterConfig");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3007,6 +3170,9 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("UpdateClusterVersion");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3042,6 +3208,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("UpdateNodegroupConfig");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3077,6 +3246,9 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation_name("UpdateNodegroupVersion");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3099,3 +3271,272 @@ impl Eks for EksClient {
         }
     }
 }
+
+/// A blocking client for the Amazon EKS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EksClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EksBlockingClient {
+    client: EksClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EksBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EksBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EksBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EksBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EksBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EksBlockingClient {
+        EksBlockingClient {
+            client: EksClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an Amazon EKS control plane. </p> <p>The Amazon EKS control plane consists of control plane instances that run the Kubernetes software, such as <code>etcd</code> and the API server. The control plane runs in an account managed by AWS, and the Kubernetes API is exposed via the Amazon EKS API server endpoint. Each Amazon EKS cluster control plane is single-tenant and unique and runs on its own set of Amazon EC2 instances.</p> <p>The cluster control plane is provisioned across multiple Availability Zones and fronted by an Elastic Load Balancing Network Load Balancer. Amazon EKS also provisions elastic network interfaces in your VPC subnets to provide connectivity from the control plane instances to the worker nodes (for example, to support <code>kubectl exec</code>, <code>logs</code>, and <code>proxy</code> data flows).</p> <p>Amazon EKS worker nodes run in your AWS account and connect to your cluster's control plane via the Kubernetes API server endpoint and a certificate file that is created for your cluster.</p> <p>You can use the <code>endpointPublicAccess</code> and <code>endpointPrivateAccess</code> parameters to enable or disable public and private access to your cluster's Kubernetes API server endpoint. By default, public access is enabled, and private access is disabled. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>. </p> <p>You can use the <code>logging</code> parameter to enable or disable exporting the Kubernetes control plane logs for your cluster to CloudWatch Logs. By default, cluster control plane logs aren't exported to CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/control-plane-logs.html">Amazon EKS Cluster Control Plane Logs</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p> <note> <p>CloudWatch Logs ingestion, archive storage, and data scanning rates apply to exported control plane logs. For more information, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </note> <p>Cluster creation typically takes between 10 and 15 minutes. After you create an Amazon EKS cluster, you must configure your Kubernetes tooling to communicate with the API server and launch worker nodes into your cluster. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/managing-auth.html">Managing Cluster Authentication</a> and <a href="https://docs.aws.amazon.com/eks/latest/userguide/launch-workers.html">Launching Amazon EKS Worker Nodes</a> in the <i>Amazon EKS User Guide</i>.</p>
+    ///
+    /// See `Eks::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <p>Creates an AWS Fargate profile for your Amazon EKS cluster. You must have at least one Fargate profile in a cluster to be able to run pods on Fargate.</p> <p>The Fargate profile allows an administrator to declare which pods run on Fargate and specify which pods run on which Fargate profile. This declaration is done through the profile’s selectors. Each profile can have up to five selectors that contain a namespace and labels. A namespace is required for every selector. The label field consists of multiple optional key-value pairs. Pods that match the selectors are scheduled on Fargate. If a to-be-scheduled pod matches any of the selectors in the Fargate profile, then that pod is run on Fargate.</p> <p>When you create a Fargate profile, you must specify a pod execution role to use with the pods that are scheduled with the profile. This role is added to the cluster's Kubernetes <a href="https://kubernetes.io/docs/admin/authorization/rbac/">Role Based Access Control</a> (RBAC) for authorization so that the <code>kubelet</code> that is running on the Fargate infrastructure can register with your Amazon EKS cluster so that it can appear in your cluster as a node. The pod execution role also provides IAM permissions to the Fargate infrastructure to allow read access to Amazon ECR image repositories. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/pod-execution-role.html">Pod Execution Role</a> in the <i>Amazon EKS User Guide</i>.</p> <p>Fargate profiles are immutable. However, you can create a new updated profile to replace an existing profile and then delete the original after the updated profile has finished creating.</p> <p>If any Fargate profiles in a cluster are in the <code>DELETING</code> status, you must wait for that Fargate profile to finish deleting before you can create any other profiles in that cluster.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/fargate-profile.html">AWS Fargate Profile</a> in the <i>Amazon EKS User Guide</i>.</p>
+    ///
+    /// See `Eks::create_fargate_profile`.
+    pub fn create_fargate_profile(
+        &self,
+        input: CreateFargateProfileRequest,
+    ) -> Result<CreateFargateProfileResponse, RusotoError<CreateFargateProfileError>> {
+        self.runtime
+            .block_on(self.client.create_fargate_profile(input))
+    }
+
+    /// <p>Creates a managed worker node group for an Amazon EKS cluster. You can only create a node group for your cluster that is equal to the current Kubernetes version for the cluster. All node groups are created with the latest AMI release version for the respective minor Kubernetes version of the cluster.</p> <p>An Amazon EKS managed node group is an Amazon EC2 Auto Scaling group and associated Amazon EC2 instances that are managed by AWS for an Amazon EKS cluster. Each node group uses a version of the Amazon EKS-optimized Amazon Linux 2 AMI. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/managed-node-groups.html">Managed Node Groups</a> in the <i>Amazon EKS User Guide</i>. </p>
+    ///
+    /// See `Eks::create_nodegroup`.
+    pub fn create_nodegroup(
+        &self,
+        input: CreateNodegroupRequest,
+    ) -> Result<CreateNodegroupResponse, RusotoError<CreateNodegroupError>> {
+        self.runtime.block_on(self.client.create_nodegroup(input))
+    }
+
+    /// <p>Deletes the Amazon EKS cluster control plane.</p> <p>If you have active services in your cluster that are associated with a load balancer, you must delete those services before deleting the cluster so that the load balancers are deleted properly. Otherwise, you can have orphaned resources in your VPC that prevent you from being able to delete the VPC. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/delete-cluster.html">Deleting a Cluster</a> in the <i>Amazon EKS User Guide</i>.</p> <p>If you have managed node groups or Fargate profiles attached to the cluster, you must delete them first. For more information, see <a>DeleteNodegroup</a> and <a>DeleteFargateProfile</a>.</p>
+    ///
+    /// See `Eks::delete_cluster`.
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// <p>Deletes an AWS Fargate profile.</p> <p>When you delete a Fargate profile, any pods running on Fargate that were created with the profile are deleted. If those pods match another Fargate profile, then they are scheduled on Fargate with that profile. If they no longer match any Fargate profiles, then they are not scheduled on Fargate and they may remain in a pending state.</p> <p>Only one Fargate profile in a cluster can be in the <code>DELETING</code> status at a time. You must wait for a Fargate profile to finish deleting before you can delete any other profiles in that cluster.</p>
+    ///
+    /// See `Eks::delete_fargate_profile`.
+    pub fn delete_fargate_profile(
+        &self,
+        input: DeleteFargateProfileRequest,
+    ) -> Result<DeleteFargateProfileResponse, RusotoError<DeleteFargateProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_fargate_profile(input))
+    }
+
+    /// <p>Deletes an Amazon EKS node group for a cluster.</p>
+    ///
+    /// See `Eks::delete_nodegroup`.
+    pub fn delete_nodegroup(
+        &self,
+        input: DeleteNodegroupRequest,
+    ) -> Result<DeleteNodegroupResponse, RusotoError<DeleteNodegroupError>> {
+        self.runtime.block_on(self.client.delete_nodegroup(input))
+    }
+
+    /// <p><p>Returns descriptive information about an Amazon EKS cluster.</p> <p>The API server endpoint and certificate authority data returned by this operation are required for <code>kubelet</code> and <code>kubectl</code> to communicate with your Kubernetes API server. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/create-kubeconfig.html">Create a kubeconfig for Amazon EKS</a>.</p> <note> <p>The API server endpoint and certificate authority data aren&#39;t available until the cluster reaches the <code>ACTIVE</code> state.</p> </note></p>
+    ///
+    /// See `Eks::describe_cluster`.
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// <p>Returns descriptive information about an AWS Fargate profile.</p>
+    ///
+    /// See `Eks::describe_fargate_profile`.
+    pub fn describe_fargate_profile(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<DescribeFargateProfileResponse, RusotoError<DescribeFargateProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_fargate_profile(input))
+    }
+
+    /// <p>Returns descriptive information about an Amazon EKS node group.</p>
+    ///
+    /// See `Eks::describe_nodegroup`.
+    pub fn describe_nodegroup(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<DescribeNodegroupResponse, RusotoError<DescribeNodegroupError>> {
+        self.runtime.block_on(self.client.describe_nodegroup(input))
+    }
+
+    /// <p>Returns descriptive information about an update against your Amazon EKS cluster or associated managed node group.</p> <p>When the status of the update is <code>Succeeded</code>, the update is complete. If an update fails, the status is <code>Failed</code>, and an error detail explains the reason for the failure.</p>
+    ///
+    /// See `Eks::describe_update`.
+    pub fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> Result<DescribeUpdateResponse, RusotoError<DescribeUpdateError>> {
+        self.runtime.block_on(self.client.describe_update(input))
+    }
+
+    /// <p>Lists the Amazon EKS clusters in your AWS account in the specified Region.</p>
+    ///
+    /// See `Eks::list_clusters`.
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// <p>Lists the AWS Fargate profiles associated with the specified cluster in your AWS account in the specified Region.</p>
+    ///
+    /// See `Eks::list_fargate_profiles`.
+    pub fn list_fargate_profiles(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> Result<ListFargateProfilesResponse, RusotoError<ListFargateProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_fargate_profiles(input))
+    }
+
+    /// <p>Lists the Amazon EKS managed node groups associated with the specified cluster in your AWS account in the specified Region. Self-managed node groups are not listed.</p>
+    ///
+    /// See `Eks::list_nodegroups`.
+    pub fn list_nodegroups(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> Result<ListNodegroupsResponse, RusotoError<ListNodegroupsError>> {
+        self.runtime.block_on(self.client.list_nodegroups(input))
+    }
+
+    /// <p>List the tags for an Amazon EKS resource.</p>
+    ///
+    /// See `Eks::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the updates associated with an Amazon EKS cluster or managed node group in your AWS account, in the specified Region.</p>
+    ///
+    /// See `Eks::list_updates`.
+    pub fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> Result<ListUpdatesResponse, RusotoError<ListUpdatesError>> {
+        self.runtime.block_on(self.client.list_updates(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well. Tags that you create for Amazon EKS resources do not propagate to any other resources associated with the cluster. For example, if you tag a cluster with this operation, that tag does not automatically propagate to the subnets and worker nodes associated with the cluster.</p>
+    ///
+    /// See `Eks::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    ///
+    /// See `Eks::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an Amazon EKS cluster configuration. Your cluster continues to function during the update. The response output includes an update ID that you can use to track the status of your cluster update with the <a>DescribeUpdate</a> API operation.</p> <p>You can use this API operation to enable or disable exporting the Kubernetes control plane logs for your cluster to CloudWatch Logs. By default, cluster control plane logs aren't exported to CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/control-plane-logs.html">Amazon EKS Cluster Control Plane Logs</a> in the <i> <i>Amazon EKS User Guide</i> </i>.</p> <note> <p>CloudWatch Logs ingestion, archive storage, and data scanning rates apply to exported control plane logs. For more information, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </note> <p>You can also use this API operation to enable or disable public and private access to your cluster's Kubernetes API server endpoint. By default, public access is enabled, and private access is disabled. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/cluster-endpoint.html">Amazon EKS Cluster Endpoint Access Control</a> in the <i> <i>Amazon EKS User Guide</i> </i>. </p> <important> <p>At this time, you can not update the subnets or security group IDs for an existing cluster.</p> </important> <p>Cluster updates are asynchronous, and they should finish within a few minutes. During an update, the cluster status moves to <code>UPDATING</code> (this status transition is eventually consistent). When the update is complete (either <code>Failed</code> or <code>Successful</code>), the cluster status moves to <code>Active</code>.</p>
+    ///
+    /// See `Eks::update_cluster_config`.
+    pub fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> Result<UpdateClusterConfigResponse, RusotoError<UpdateClusterConfigError>> {
+        self.runtime
+            .block_on(self.client.update_cluster_config(input))
+    }
+
+    /// <p>Updates an Amazon EKS cluster to the specified Kubernetes version. Your cluster continues to function during the update. The response output includes an update ID that you can use to track the status of your cluster update with the <a>DescribeUpdate</a> API operation.</p> <p>Cluster updates are asynchronous, and they should finish within a few minutes. During an update, the cluster status moves to <code>UPDATING</code> (this status transition is eventually consistent). When the update is complete (either <code>Failed</code> or <code>Successful</code>), the cluster status moves to <code>Active</code>.</p> <p>If your cluster has managed node groups attached to it, all of your node groups’ Kubernetes versions must match the cluster’s Kubernetes version in order to update the cluster to a new Kubernetes version.</p>
+    ///
+    /// See `Eks::update_cluster_version`.
+    pub fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> Result<UpdateClusterVersionResponse, RusotoError<UpdateClusterVersionError>> {
+        self.runtime
+            .block_on(self.client.update_cluster_version(input))
+    }
+
+    /// <p>Updates an Amazon EKS managed node group configuration. Your node group continues to function during the update. The response output includes an update ID that you can use to track the status of your node group update with the <a>DescribeUpdate</a> API operation. Currently you can update the Kubernetes labels for a node group or the scaling configuration.</p>
+    ///
+    /// See `Eks::update_nodegroup_config`.
+    pub fn update_nodegroup_config(
+        &self,
+        input: UpdateNodegroupConfigRequest,
+    ) -> Result<UpdateNodegroupConfigResponse, RusotoError<UpdateNodegroupConfigError>> {
+        self.runtime
+            .block_on(self.client.update_nodegroup_config(input))
+    }
+
+    /// <p>Updates the Kubernetes version or AMI version of an Amazon EKS managed node group.</p> <p>You can update to the latest available AMI version of a node group's current Kubernetes version by not specifying a Kubernetes version in the request. You can update to the latest AMI version of your cluster's current Kubernetes version by specifying your cluster's Kubernetes version in the request. For more information, see <a href="https://docs.aws.amazon.com/eks/latest/userguide/eks-linux-ami-versions.html">Amazon EKS-Optimized Linux AMI Versions</a> in the <i>Amazon EKS User Guide</i>.</p> <p>You cannot roll back a node group to an earlier Kubernetes version or AMI version.</p> <p>When a node in a managed node group is terminated due to a scaling action or update, the pods in that node are drained first. Amazon EKS attempts to drain the nodes gracefully and will fail if it is unable to do so. You can <code>force</code> the update if Amazon EKS is unable to drain the nodes as a result of a pod disruption budget issue.</p>
+    ///
+    /// See `Eks::update_nodegroup_version`.
+    pub fn update_nodegroup_version(
+        &self,
+        input: UpdateNodegroupVersionRequest,
+    ) -> Result<UpdateNodegroupVersionResponse, RusotoError<UpdateNodegroupVersionError>> {
+        self.runtime
+            .block_on(self.client.update_nodegroup_version(input))
+    }
+}
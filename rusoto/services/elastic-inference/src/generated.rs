@@ -268,17 +268,20 @@ impl DescribeAcceleratorOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeAcceleratorOfferingsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorOfferingsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeAcceleratorOfferingsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorOfferingsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAcceleratorOfferingsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -314,9 +317,10 @@ impl DescribeAcceleratorTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeAcceleratorTypesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorTypesError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -350,15 +354,22 @@ impl DescribeAcceleratorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeAcceleratorsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeAcceleratorsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAcceleratorsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -394,15 +405,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -438,13 +456,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -480,13 +498,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -562,6 +583,7 @@ impl ElasticInferenceClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -577,6 +599,7 @@ impl ElasticInferenceClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ElasticInferenceClient {
         ElasticInferenceClient { client, region }
     }
@@ -595,6 +618,9 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation_name("DescribeAcceleratorOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -627,6 +653,9 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("GET", "elastic-inference", &self.region, &request_uri);
+        request.set_operation_name("DescribeAcceleratorTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -658,6 +687,9 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation_name("DescribeAccelerators");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -691,6 +723,9 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("GET", "elastic-inference", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -722,6 +757,9 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("POST", "elastic-inference", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -755,6 +793,9 @@ impl ElasticInference for ElasticInferenceClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastic-inference", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("api.elastic-inference".to_string());
@@ -782,3 +823,120 @@ impl ElasticInference for ElasticInferenceClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Elastic Inference API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ElasticInferenceClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElasticInferenceBlockingClient {
+    client: ElasticInferenceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElasticInferenceBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElasticInferenceBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ElasticInferenceBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElasticInferenceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElasticInferenceBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ElasticInferenceBlockingClient {
+        ElasticInferenceBlockingClient {
+            client: ElasticInferenceClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p> Describes the locations in which a given accelerator type or set of types is present in a given region. </p>
+    ///
+    /// See `ElasticInference::describe_accelerator_offerings`.
+    pub fn describe_accelerator_offerings(
+        &self,
+        input: DescribeAcceleratorOfferingsRequest,
+    ) -> Result<DescribeAcceleratorOfferingsResponse, RusotoError<DescribeAcceleratorOfferingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_accelerator_offerings(input))
+    }
+
+    /// <p> Describes the accelerator types available in a given region, as well as their characteristics, such as memory and throughput. </p>
+    ///
+    /// See `ElasticInference::describe_accelerator_types`.
+    pub fn describe_accelerator_types(
+        &self,
+    ) -> Result<DescribeAcceleratorTypesResponse, RusotoError<DescribeAcceleratorTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_accelerator_types())
+    }
+
+    /// <p> Describes information over a provided set of accelerators belonging to an account. </p>
+    ///
+    /// See `ElasticInference::describe_accelerators`.
+    pub fn describe_accelerators(
+        &self,
+        input: DescribeAcceleratorsRequest,
+    ) -> Result<DescribeAcceleratorsResponse, RusotoError<DescribeAcceleratorsError>> {
+        self.runtime
+            .block_on(self.client.describe_accelerators(input))
+    }
+
+    /// <p> Returns all tags of an Elastic Inference Accelerator. </p>
+    ///
+    /// See `ElasticInference::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p> Adds the specified tags to an Elastic Inference Accelerator. </p>
+    ///
+    /// See `ElasticInference::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p> Removes the specified tags from an Elastic Inference Accelerator. </p>
+    ///
+    /// See `ElasticInference::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
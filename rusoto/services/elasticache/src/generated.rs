@@ -8947,22 +8947,26 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::CacheClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(AddTagsToResourceError::InvalidARNFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsToResourceError::InvalidARNFault(parsed_error.message),
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(AddTagsToResourceError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsToResourceError::SnapshotNotFoundFault(parsed_error.message),
+                        )
                     }
                     "TagQuotaPerResourceExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::TagQuotaPerResourceExceeded(
                                 parsed_error.message,
                             ),
@@ -9022,7 +9026,7 @@ impl AuthorizeCacheSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"InvalidCacheSecurityGroupState" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::service(&res, AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::service(&res, AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"InvalidCacheSecurityGroupState" => return RusotoError::service(&res, AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::service(&res, AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -9078,14 +9082,16 @@ impl BatchApplyUpdateActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchApplyUpdateActionError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -9136,12 +9142,14 @@ impl BatchStopUpdateActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchStopUpdateActionError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchStopUpdateActionError::ServiceUpdateNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -9194,21 +9202,24 @@ impl CompleteMigrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CompleteMigrationError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CompleteMigrationError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotUnderMigrationFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(
                                 parsed_error.message,
                             ),
@@ -9272,34 +9283,40 @@ impl CopySnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopySnapshotError::InvalidParameterCombination(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CopySnapshotError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopySnapshotError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidSnapshotState" => {
-                        return RusotoError::Service(CopySnapshotError::InvalidSnapshotStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopySnapshotError::InvalidSnapshotStateFault(parsed_error.message),
+                        )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopySnapshotError::SnapshotAlreadyExistsFault(parsed_error.message),
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopySnapshotError::SnapshotNotFoundFault(parsed_error.message),
+                        )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotQuotaExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopySnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9372,96 +9389,110 @@ impl CreateCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::CacheClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::ClusterQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::NodeQuotaForClusterExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TagQuotaPerResourceExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheClusterError::TagQuotaPerResourceExceeded(
                                 parsed_error.message,
                             ),
@@ -9554,35 +9585,40 @@ impl CreateCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -9648,28 +9684,32 @@ impl CreateCacheSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "QuotaExceeded.CacheSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSecurityGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSecurityGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -9732,30 +9772,34 @@ impl CreateCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateCacheSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateCacheSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9815,34 +9859,39 @@ impl CreateGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "GlobalReplicationGroupAlreadyExistsFault" => return RusotoError::Service(
+                    "GlobalReplicationGroupAlreadyExistsFault" => return RusotoError::service(
+                        &res,
                         CreateGlobalReplicationGroupError::GlobalReplicationGroupAlreadyExistsFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalReplicationGroupError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalReplicationGroupError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalReplicationGroupError::ServiceLinkedRoleNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -9933,7 +9982,7 @@ impl CreateReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CacheClusterNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheClusterNotFoundFault(parsed_error.message)),"CacheParameterGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheParameterGroupNotFoundFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"CacheSubnetGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(parsed_error.message)),"ClusterQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(parsed_error.message)),"GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InsufficientCacheClusterCapacity" => return RusotoError::Service(CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(CreateReplicationGroupError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterValue(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(CreateReplicationGroupError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForClusterExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForClusterExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupAlreadyExists" => return RusotoError::Service(CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(parsed_error.message)),"TagQuotaPerResourceExceeded" => return RusotoError::Service(CreateReplicationGroupError::TagQuotaPerResourceExceeded(parsed_error.message)),_ => {}
+                                    "CacheClusterNotFound" => return RusotoError::service(&res, CreateReplicationGroupError::CacheClusterNotFoundFault(parsed_error.message)),"CacheParameterGroupNotFound" => return RusotoError::service(&res, CreateReplicationGroupError::CacheParameterGroupNotFoundFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::service(&res, CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"CacheSubnetGroupNotFoundFault" => return RusotoError::service(&res, CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(parsed_error.message)),"ClusterQuotaForCustomerExceeded" => return RusotoError::service(&res, CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(parsed_error.message)),"GlobalReplicationGroupNotFoundFault" => return RusotoError::service(&res, CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InsufficientCacheClusterCapacity" => return RusotoError::service(&res, CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::service(&res, CreateReplicationGroupError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::service(&res, CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::service(&res, CreateReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, CreateReplicationGroupError::InvalidParameterValue(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::service(&res, CreateReplicationGroupError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(&res, CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForClusterExceeded" => return RusotoError::service(&res, CreateReplicationGroupError::NodeQuotaForClusterExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::service(&res, CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupAlreadyExists" => return RusotoError::service(&res, CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(parsed_error.message)),"TagQuotaPerResourceExceeded" => return RusotoError::service(&res, CreateReplicationGroupError::TagQuotaPerResourceExceeded(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -10037,55 +10086,64 @@ impl CreateSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::CacheClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::InvalidParameterCombination(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateSnapshotError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateSnapshotError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::SnapshotAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::SnapshotFeatureNotSupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -10148,7 +10206,7 @@ impl DecreaseNodeGroupsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service(&res, DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::service(&res, DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::service(&res, DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -10213,78 +10271,90 @@ impl DecreaseReplicaCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoOperationFault" => {
-                        return RusotoError::Service(DecreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DecreaseReplicaCountError::NoOperationFault(parsed_error.message),
+                        )
                     }
-                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
+                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(
+                        &res,
                         DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
                             parsed_error.message,
                         ),
                     ),
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10373,47 +10443,54 @@ impl DeleteCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::SnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::SnapshotFeatureNotSupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheClusterError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -10481,28 +10558,32 @@ impl DeleteCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -10565,28 +10646,32 @@ impl DeleteCacheSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheSecurityGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheSecurityGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -10645,14 +10730,16 @@ impl DeleteCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheSubnetGroupError::CacheSubnetGroupInUse(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10707,19 +10794,22 @@ impl DeleteGlobalReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidGlobalReplicationGroupState" => return RusotoError::Service(
+                    "InvalidGlobalReplicationGroupState" => return RusotoError::service(
+                        &res,
                         DeleteGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteGlobalReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -10785,49 +10875,56 @@ impl DeleteReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::SnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReplicationGroupError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -10897,24 +10994,28 @@ impl DeleteSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteSnapshotError::InvalidParameterCombination(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteSnapshotError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSnapshotError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteSnapshotError::InvalidSnapshotStateFault(parsed_error.message),
                         )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(DeleteSnapshotError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSnapshotError::SnapshotNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10963,21 +11064,24 @@ impl DescribeCacheClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheClustersError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheClustersError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheClustersError::InvalidParameterValue(parsed_error.message),
                         )
                     }
@@ -11069,21 +11173,24 @@ impl DescribeCacheParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheParameterGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheParameterGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -11141,21 +11248,24 @@ impl DescribeCacheParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheParametersError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheParametersError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheParametersError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -11215,21 +11325,24 @@ impl DescribeCacheSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheSecurityGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheSecurityGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -11283,7 +11396,8 @@ impl DescribeCacheSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -11335,14 +11449,16 @@ impl DescribeEngineDefaultParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEngineDefaultParametersError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEngineDefaultParametersError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -11395,14 +11511,16 @@ impl DescribeEventsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEventsError::InvalidParameterCombination(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeEventsError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11450,20 +11568,23 @@ impl DescribeGlobalReplicationGroupsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(
+                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service(
+                        &res,
                         DescribeGlobalReplicationGroupsError::GlobalReplicationGroupNotFoundFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeGlobalReplicationGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeGlobalReplicationGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -11521,21 +11642,24 @@ impl DescribeReplicationGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReplicationGroupsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReplicationGroupsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -11595,21 +11719,24 @@ impl DescribeReservedCacheNodesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedCacheNodesError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedCacheNodesError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedCacheNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -11668,7 +11795,7 @@ impl DescribeReservedCacheNodesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::service(&res, DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::service(&res, DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11720,21 +11847,24 @@ impl DescribeServiceUpdatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeServiceUpdatesError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeServiceUpdatesError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -11792,26 +11922,30 @@ impl DescribeSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeSnapshotsError::CacheClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeSnapshotsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DescribeSnapshotsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeSnapshotsError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(DescribeSnapshotsError::SnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeSnapshotsError::SnapshotNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11860,14 +11994,16 @@ impl DescribeUpdateActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeUpdateActionsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeUpdateActionsError::InvalidParameterValue(parsed_error.message),
                         )
                     }
@@ -11921,7 +12057,7 @@ impl DisassociateGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service(&res, DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::service(&res, DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::service(&res, DisassociateGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, DisassociateGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11979,7 +12115,7 @@ impl FailoverGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service(&res, FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::service(&res, FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::service(&res, FailoverGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, FailoverGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -12035,7 +12171,7 @@ impl IncreaseNodeGroupsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service(&res, IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::service(&res, IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -12099,76 +12235,88 @@ impl IncreaseReplicaCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(IncreaseReplicaCountError::InvalidKMSKeyFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            IncreaseReplicaCountError::InvalidKMSKeyFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoOperationFault" => {
-                        return RusotoError::Service(IncreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            IncreaseReplicaCountError::NoOperationFault(parsed_error.message),
+                        )
                     }
-                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
+                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(
+                        &res,
                         IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
                             parsed_error.message,
                         ),
                     ),
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             IncreaseReplicaCountError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -12251,28 +12399,32 @@ impl ListAllowedNodeTypeModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListAllowedNodeTypeModificationsError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListAllowedNodeTypeModificationsError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -12333,19 +12485,22 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidARNFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::InvalidARNFault(parsed_error.message),
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::SnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -12413,75 +12568,86 @@ impl ModifyCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::NodeQuotaForClusterExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
@@ -12563,35 +12729,40 @@ impl ModifyCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -12657,28 +12828,32 @@ impl ModifyCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyCacheSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyCacheSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "SubnetInUse" => {
-                        return RusotoError::Service(ModifyCacheSubnetGroupError::SubnetInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyCacheSubnetGroupError::SubnetInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12733,19 +12908,22 @@ impl ModifyGlobalReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidGlobalReplicationGroupState" => return RusotoError::Service(
+                    "InvalidGlobalReplicationGroupState" => return RusotoError::service(
+                        &res,
                         ModifyGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyGlobalReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -12825,96 +13003,110 @@ impl ModifyReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidKMSKeyFault(parsed_error.message),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::NodeQuotaForClusterExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyReplicationGroupError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -13016,7 +13208,7 @@ impl ModifyReplicationGroupShardConfigurationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InsufficientCacheClusterCapacity" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidKMSKeyFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(parsed_error.message)),"InvalidReplicationGroupState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupNotFoundFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(parsed_error.message)),_ => {}
+                                    "InsufficientCacheClusterCapacity" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidKMSKeyFault" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(parsed_error.message)),"InvalidReplicationGroupState" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupNotFoundFault" => return RusotoError::service(&res, ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -13074,7 +13266,7 @@ impl PurchaseReservedCacheNodesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodeAlreadyExists" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(parsed_error.message)),"ReservedCacheNodeQuotaExceeded" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::service(&res, PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodeAlreadyExists" => return RusotoError::service(&res, PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(parsed_error.message)),"ReservedCacheNodeQuotaExceeded" => return RusotoError::service(&res, PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::service(&res, PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -13133,7 +13325,7 @@ impl RebalanceSlotsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service(&res, RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::service(&res, RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::service(&res, RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -13177,14 +13369,16 @@ impl RebootCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootCacheClusterError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootCacheClusterError::InvalidCacheClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -13239,28 +13433,32 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::CacheClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(RemoveTagsFromResourceError::InvalidARNFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsFromResourceError::InvalidARNFault(parsed_error.message),
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::SnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TagNotFound" => {
-                        return RusotoError::Service(RemoveTagsFromResourceError::TagNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsFromResourceError::TagNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13315,35 +13513,40 @@ impl ResetCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetCacheParameterGroupError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetCacheParameterGroupError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -13413,33 +13616,38 @@ impl RevokeCacheSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidCacheSecurityGroupState" => return RusotoError::Service(
+                    "InvalidCacheSecurityGroupState" => return RusotoError::service(
+                        &res,
                         RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeCacheSecurityGroupIngressError::InvalidParameterCombination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeCacheSecurityGroupIngressError::InvalidParameterValue(
                                 parsed_error.message,
                             ),
@@ -13505,26 +13713,30 @@ impl StartMigrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(StartMigrationError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartMigrationError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartMigrationError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupAlreadyUnderMigrationFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartMigrationError::ReplicationGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -13593,51 +13805,60 @@ impl TestFailoverError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "APICallRateForCustomerExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestFailoverError::APICallRateForCustomerExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestFailoverError::InvalidCacheClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(TestFailoverError::InvalidKMSKeyFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TestFailoverError::InvalidKMSKeyFault(parsed_error.message),
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestFailoverError::InvalidParameterCombination(parsed_error.message),
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(TestFailoverError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TestFailoverError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestFailoverError::InvalidReplicationGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NodeGroupNotFoundFault" => {
-                        return RusotoError::Service(TestFailoverError::NodeGroupNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TestFailoverError::NodeGroupNotFoundFault(parsed_error.message),
+                        )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestFailoverError::ReplicationGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "TestFailoverNotAvailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestFailoverError::TestFailoverNotAvailableFault(parsed_error.message),
                         )
                     }
@@ -14079,6 +14300,7 @@ impl ElastiCacheClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -14094,6 +14316,7 @@ impl ElastiCacheClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheClient {
         ElastiCacheClient { client, region }
     }
@@ -14107,6 +14330,9 @@ impl ElastiCache for ElastiCacheClient {
         input: AddTagsToResourceMessage,
     ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -14140,6 +14366,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<AuthorizeCacheSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("AuthorizeCacheSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AuthorizeCacheSecurityGroupIngress");
         let mut params = params;
         AuthorizeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -14176,6 +14405,9 @@ impl ElastiCache for ElastiCacheClient {
         input: BatchApplyUpdateActionMessage,
     ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("BatchApplyUpdateAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchApplyUpdateAction");
         let mut params = params;
         BatchApplyUpdateActionMessageSerializer::serialize(&mut params, "", &input);
@@ -14209,6 +14441,9 @@ impl ElastiCache for ElastiCacheClient {
         input: BatchStopUpdateActionMessage,
     ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("BatchStopUpdateAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchStopUpdateAction");
         let mut params = params;
         BatchStopUpdateActionMessageSerializer::serialize(&mut params, "", &input);
@@ -14242,6 +14477,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CompleteMigrationMessage,
     ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CompleteMigration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CompleteMigration");
         let mut params = params;
         CompleteMigrationMessageSerializer::serialize(&mut params, "", &input);
@@ -14275,6 +14513,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CopySnapshotMessage,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CopySnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopySnapshot");
         let mut params = params;
         CopySnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -14305,6 +14546,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheClusterMessage,
     ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateCacheCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCacheCluster");
         let mut params = params;
         CreateCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14338,6 +14582,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheParameterGroupMessage,
     ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateCacheParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCacheParameterGroup");
         let mut params = params;
         CreateCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14371,6 +14618,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheSecurityGroupMessage,
     ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateCacheSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCacheSecurityGroup");
         let mut params = params;
         CreateCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14404,6 +14654,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheSubnetGroupMessage,
     ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateCacheSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCacheSubnetGroup");
         let mut params = params;
         CreateCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14438,6 +14691,9 @@ impl ElastiCache for ElastiCacheClient {
     ) -> Result<CreateGlobalReplicationGroupResult, RusotoError<CreateGlobalReplicationGroupError>>
     {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateGlobalReplicationGroup");
         let mut params = params;
         CreateGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14471,6 +14727,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateReplicationGroupMessage,
     ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateReplicationGroup");
         let mut params = params;
         CreateReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14504,6 +14763,9 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateSnapshotMessage,
     ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("CreateSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSnapshot");
         let mut params = params;
         CreateSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -14538,6 +14800,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DecreaseNodeGroupsInGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DecreaseNodeGroupsInGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DecreaseNodeGroupsInGlobalReplicationGroup");
         let mut params = params;
         DecreaseNodeGroupsInGlobalReplicationGroupMessageSerializer::serialize(
@@ -14578,6 +14843,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DecreaseReplicaCountMessage,
     ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DecreaseReplicaCount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DecreaseReplicaCount");
         let mut params = params;
         DecreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
@@ -14611,6 +14879,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheClusterMessage,
     ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteCacheCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCacheCluster");
         let mut params = params;
         DeleteCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14644,6 +14915,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteCacheParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCacheParameterGroup");
         let mut params = params;
         DeleteCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14664,6 +14938,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteCacheSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCacheSecurityGroup");
         let mut params = params;
         DeleteCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14684,6 +14961,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteCacheSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCacheSubnetGroup");
         let mut params = params;
         DeleteCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14705,6 +14985,9 @@ impl ElastiCache for ElastiCacheClient {
     ) -> Result<DeleteGlobalReplicationGroupResult, RusotoError<DeleteGlobalReplicationGroupError>>
     {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteGlobalReplicationGroup");
         let mut params = params;
         DeleteGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14738,6 +15021,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteReplicationGroupMessage,
     ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteReplicationGroup");
         let mut params = params;
         DeleteReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14771,6 +15057,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteSnapshotMessage,
     ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DeleteSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSnapshot");
         let mut params = params;
         DeleteSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -14802,6 +15091,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheClustersMessage,
     ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeCacheClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCacheClusters");
         let mut params = params;
         DescribeCacheClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -14833,6 +15125,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheEngineVersionsMessage,
     ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeCacheEngineVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCacheEngineVersions");
         let mut params = params;
         DescribeCacheEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -14866,6 +15161,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheParameterGroupsMessage,
     ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeCacheParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCacheParameterGroups");
         let mut params = params;
         DescribeCacheParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -14899,6 +15197,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheParametersMessage,
     ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeCacheParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCacheParameters");
         let mut params = params;
         DescribeCacheParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -14932,6 +15233,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheSecurityGroupsMessage,
     ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeCacheSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCacheSecurityGroups");
         let mut params = params;
         DescribeCacheSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -14965,6 +15269,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheSubnetGroupsMessage,
     ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeCacheSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCacheSubnetGroups");
         let mut params = params;
         DescribeCacheSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -15001,6 +15308,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeEngineDefaultParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEngineDefaultParameters");
         let mut params = params;
         DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -15034,6 +15344,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -15067,6 +15380,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeGlobalReplicationGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeGlobalReplicationGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeGlobalReplicationGroups");
         let mut params = params;
         DescribeGlobalReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -15100,6 +15416,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeReplicationGroupsMessage,
     ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeReplicationGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReplicationGroups");
         let mut params = params;
         DescribeReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -15133,6 +15452,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeReservedCacheNodesMessage,
     ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeReservedCacheNodes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedCacheNodes");
         let mut params = params;
         DescribeReservedCacheNodesMessageSerializer::serialize(&mut params, "", &input);
@@ -15169,6 +15491,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeReservedCacheNodesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeReservedCacheNodesOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedCacheNodesOfferings");
         let mut params = params;
         DescribeReservedCacheNodesOfferingsMessageSerializer::serialize(&mut params, "", &input);
@@ -15205,6 +15530,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeServiceUpdatesMessage,
     ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeServiceUpdates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeServiceUpdates");
         let mut params = params;
         DescribeServiceUpdatesMessageSerializer::serialize(&mut params, "", &input);
@@ -15238,6 +15566,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeSnapshotsMessage,
     ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSnapshots");
         let mut params = params;
         DescribeSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -15271,6 +15602,9 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeUpdateActionsMessage,
     ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DescribeUpdateActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeUpdateActions");
         let mut params = params;
         DescribeUpdateActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -15307,6 +15641,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DisassociateGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("DisassociateGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateGlobalReplicationGroup");
         let mut params = params;
         DisassociateGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15346,6 +15683,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<FailoverGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("FailoverGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("FailoverGlobalReplicationGroup");
         let mut params = params;
         FailoverGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15382,6 +15722,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<IncreaseNodeGroupsInGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("IncreaseNodeGroupsInGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("IncreaseNodeGroupsInGlobalReplicationGroup");
         let mut params = params;
         IncreaseNodeGroupsInGlobalReplicationGroupMessageSerializer::serialize(
@@ -15422,6 +15765,9 @@ impl ElastiCache for ElastiCacheClient {
         input: IncreaseReplicaCountMessage,
     ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("IncreaseReplicaCount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("IncreaseReplicaCount");
         let mut params = params;
         IncreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
@@ -15458,6 +15804,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<ListAllowedNodeTypeModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ListAllowedNodeTypeModifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAllowedNodeTypeModifications");
         let mut params = params;
         ListAllowedNodeTypeModificationsMessageSerializer::serialize(&mut params, "", &input);
@@ -15494,6 +15843,9 @@ impl ElastiCache for ElastiCacheClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -15525,6 +15877,9 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheClusterMessage,
     ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ModifyCacheCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCacheCluster");
         let mut params = params;
         ModifyCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15558,6 +15913,9 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheParameterGroupMessage,
     ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ModifyCacheParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCacheParameterGroup");
         let mut params = params;
         ModifyCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15591,6 +15949,9 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheSubnetGroupMessage,
     ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ModifyCacheSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCacheSubnetGroup");
         let mut params = params;
         ModifyCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15625,6 +15986,9 @@ impl ElastiCache for ElastiCacheClient {
     ) -> Result<ModifyGlobalReplicationGroupResult, RusotoError<ModifyGlobalReplicationGroupError>>
     {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ModifyGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyGlobalReplicationGroup");
         let mut params = params;
         ModifyGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15658,6 +16022,9 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyReplicationGroupMessage,
     ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ModifyReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyReplicationGroup");
         let mut params = params;
         ModifyReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15694,6 +16061,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<ModifyReplicationGroupShardConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ModifyReplicationGroupShardConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyReplicationGroupShardConfiguration");
         let mut params = params;
         ModifyReplicationGroupShardConfigurationMessageSerializer::serialize(
@@ -15737,6 +16107,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<PurchaseReservedCacheNodesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("PurchaseReservedCacheNodesOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PurchaseReservedCacheNodesOffering");
         let mut params = params;
         PurchaseReservedCacheNodesOfferingMessageSerializer::serialize(&mut params, "", &input);
@@ -15776,6 +16149,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<RebalanceSlotsInGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("RebalanceSlotsInGlobalReplicationGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebalanceSlotsInGlobalReplicationGroup");
         let mut params = params;
         RebalanceSlotsInGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15812,6 +16188,9 @@ impl ElastiCache for ElastiCacheClient {
         input: RebootCacheClusterMessage,
     ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("RebootCacheCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebootCacheCluster");
         let mut params = params;
         RebootCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15845,6 +16224,9 @@ impl ElastiCache for ElastiCacheClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -15876,6 +16258,9 @@ impl ElastiCache for ElastiCacheClient {
         input: ResetCacheParameterGroupMessage,
     ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("ResetCacheParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetCacheParameterGroup");
         let mut params = params;
         ResetCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15912,6 +16297,9 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<RevokeCacheSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("RevokeCacheSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeCacheSecurityGroupIngress");
         let mut params = params;
         RevokeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -15945,6 +16333,9 @@ impl ElastiCache for ElastiCacheClient {
         input: StartMigrationMessage,
     ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("StartMigration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartMigration");
         let mut params = params;
         StartMigrationMessageSerializer::serialize(&mut params, "", &input);
@@ -15976,6 +16367,9 @@ impl ElastiCache for ElastiCacheClient {
         input: TestFailoverMessage,
     ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation_name("TestFailover");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TestFailover");
         let mut params = params;
         TestFailoverMessageSerializer::serialize(&mut params, "", &input);
@@ -16000,3 +16394,722 @@ impl ElastiCache for ElastiCacheClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon ElastiCache API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ElastiCacheClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElastiCacheBlockingClient {
+    client: ElastiCacheClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElastiCacheBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElastiCacheBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ElastiCacheBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElastiCacheBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElastiCacheBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient {
+            client: ElastiCacheClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds up to 50 cost allocation tags to the named resource. A cost allocation tag is a key-value pair where the key and value are case-sensitive. You can use cost allocation tags to categorize and track your AWS costs.</p> <p> When you apply tags to your ElastiCache resources, AWS generates a cost allocation report as a comma-separated value (CSV) file with your usage and costs aggregated by your tags. You can apply tags that represent business categories (such as cost centers, application names, or owners) to organize your costs across multiple services. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Using Cost Allocation Tags in Amazon ElastiCache</a> in the <i>ElastiCache User Guide</i>.</p>
+    ///
+    /// See `ElastiCache::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p><p>Allows network ingress to a cache security group. Applications using ElastiCache must be running on Amazon EC2, and Amazon EC2 security groups are used as the authorization mechanism.</p> <note> <p>You cannot authorize ingress from an Amazon EC2 security group in one region to an ElastiCache cluster in another region.</p> </note></p>
+    ///
+    /// See `ElastiCache::authorize_cache_security_group_ingress`.
+    pub fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeCacheSecurityGroupIngressResult,
+        RusotoError<AuthorizeCacheSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.authorize_cache_security_group_ingress(input))
+    }
+
+    /// <p>Apply the service update. For more information on service updates and applying them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/applying-updates.html">Applying Service Updates</a>.</p>
+    ///
+    /// See `ElastiCache::batch_apply_update_action`.
+    pub fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
+        self.runtime
+            .block_on(self.client.batch_apply_update_action(input))
+    }
+
+    /// <p>Stop the service update. For more information on service updates and stopping them, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/stopping-self-service-updates.html">Stopping Service Updates</a>.</p>
+    ///
+    /// See `ElastiCache::batch_stop_update_action`.
+    pub fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
+        self.runtime
+            .block_on(self.client.batch_stop_update_action(input))
+    }
+
+    /// <p>Complete the migration of data.</p>
+    ///
+    /// See `ElastiCache::complete_migration`.
+    pub fn complete_migration(
+        &self,
+        input: CompleteMigrationMessage,
+    ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
+        self.runtime.block_on(self.client.complete_migration(input))
+    }
+
+    /// <p><p>Makes a copy of an existing snapshot.</p> <note> <p>This operation is valid for Redis only.</p> </note> <important> <p>Users or groups that have permissions to use the <code>CopySnapshot</code> operation can create their own Amazon S3 buckets and copy snapshots to it. To control access to your snapshots, use an IAM policy to control who has the ability to use the <code>CopySnapshot</code> operation. For more information about using IAM to control the use of ElastiCache operations, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html">Exporting Snapshots</a> and <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/IAM.html">Authentication &amp; Access Control</a>.</p> </important> <p>You could receive the following error messages.</p> <p class="title"> <b>Error Messages</b> </p> <ul> <li> <p> <b>Error Message:</b> The S3 bucket %s is outside of the region.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s does not exist.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s is not owned by the authenticated user.</p> <p> <b>Solution:</b> Create an Amazon S3 bucket in the same region as your snapshot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-create-s3-bucket">Step 1: Create an Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message:</b> The authenticated user does not have sufficient permissions to perform the desired activity.</p> <p> <b>Solution:</b> Contact your system administrator to get the needed permissions.</p> </li> <li> <p> <b>Error Message:</b> The S3 bucket %s already contains an object with key %s.</p> <p> <b>Solution:</b> Give the <code>TargetSnapshotName</code> a new and unique value. If exporting a snapshot, you could alternatively create a new Amazon S3 bucket and use this same value for <code>TargetSnapshotName</code>.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add List and Read permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted WRITE permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add Upload/Delete permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> <li> <p> <b>Error Message: </b> ElastiCache has not been granted READ_ACP permissions %s on the S3 Bucket.</p> <p> <b>Solution:</b> Add View Permissions on the bucket. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/backups-exporting.html#backups-exporting-grant-access">Step 2: Grant ElastiCache Access to Your Amazon S3 Bucket</a> in the ElastiCache User Guide.</p> </li> </ul></p>
+    ///
+    /// See `ElastiCache::copy_snapshot`.
+    pub fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.runtime.block_on(self.client.copy_snapshot(input))
+    }
+
+    /// <p>Creates a cluster. All nodes in the cluster run the same protocol-compliant cache engine software, either Memcached or Redis.</p> <p>This operation is not supported for Redis (cluster mode enabled) clusters.</p>
+    ///
+    /// See `ElastiCache::create_cache_cluster`.
+    pub fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.create_cache_cluster(input))
+    }
+
+    /// <p><p>Creates a new Amazon ElastiCache cache parameter group. An ElastiCache cache parameter group is a collection of parameters and their values that are applied to all of the nodes in any cluster or replication group using the CacheParameterGroup.</p> <p>A newly created CacheParameterGroup is an exact duplicate of the default parameter group for the CacheParameterGroupFamily. To customize the newly created CacheParameterGroup you can change the values of specific parameters. For more information, see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyCacheParameterGroup.html">ModifyCacheParameterGroup</a> in the ElastiCache API Reference.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ParameterGroups.html">Parameters and Parameter Groups</a> in the ElastiCache User Guide.</p> </li> </ul></p>
+    ///
+    /// See `ElastiCache::create_cache_parameter_group`.
+    pub fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cache_parameter_group(input))
+    }
+
+    /// <p>Creates a new cache security group. Use a cache security group to control access to one or more clusters.</p> <p>Cache security groups are only used when you are creating a cluster outside of an Amazon Virtual Private Cloud (Amazon VPC). If you are creating a cluster inside of a VPC, use a cache subnet group instead. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_CreateCacheSubnetGroup.html">CreateCacheSubnetGroup</a>.</p>
+    ///
+    /// See `ElastiCache::create_cache_security_group`.
+    pub fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cache_security_group(input))
+    }
+
+    /// <p>Creates a new cache subnet group.</p> <p>Use this parameter only when you are creating a cluster in an Amazon Virtual Private Cloud (Amazon VPC).</p>
+    ///
+    /// See `ElastiCache::create_cache_subnet_group`.
+    pub fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cache_subnet_group(input))
+    }
+
+    /// <p><p>Global Datastore for Redis offers fully managed, fast, reliable and secure cross-region replication. Using Global Datastore for Redis, you can create cross-region read replica clusters for ElastiCache for Redis to enable low-latency reads and disaster recovery across regions. For more information, see <a href="/AmazonElastiCache/latest/red-ug/Redis-Global-Clusters.html">Replication Across Regions Using Global Datastore</a>. </p> <ul> <li> <p>The <b>GlobalReplicationGroupIdSuffix</b> is the name of the Global Datastore.</p> </li> <li> <p>The <b>PrimaryReplicationGroupId</b> represents the name of the primary cluster that accepts writes and will replicate updates to the secondary cluster.</p> </li> </ul></p>
+    ///
+    /// See `ElastiCache::create_global_replication_group`.
+    pub fn create_global_replication_group(
+        &self,
+        input: CreateGlobalReplicationGroupMessage,
+    ) -> Result<CreateGlobalReplicationGroupResult, RusotoError<CreateGlobalReplicationGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_global_replication_group(input))
+    }
+
+    /// <p><p>Creates a Redis (cluster mode disabled) or a Redis (cluster mode enabled) replication group.</p> <p>This API can be used to create a standalone regional replication group or a secondary replication group associated with a Global Datastore.</p> <p>A Redis (cluster mode disabled) replication group is a collection of clusters, where one of the clusters is a read/write primary and the others are read-only replicas. Writes to the primary are asynchronously propagated to the replicas.</p> <p>A Redis (cluster mode enabled) replication group is a collection of 1 to 90 node groups (shards). Each node group (shard) has one read/write primary node and up to 5 read-only replica nodes. Writes to the primary are asynchronously propagated to the replicas. Redis (cluster mode enabled) replication groups partition the data across node groups (shards).</p> <p>When a Redis (cluster mode disabled) replication group has been successfully created, you can add one or more read replicas to it, up to a total of 5 read replicas. If you need to increase or decrease the number of node groups (console: shards), you can avail yourself of ElastiCache for Redis&#39; scaling. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Scaling.html">Scaling ElastiCache for Redis Clusters</a> in the <i>ElastiCache User Guide</i>.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::create_replication_group`.
+    pub fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
+        self.runtime
+            .block_on(self.client.create_replication_group(input))
+    }
+
+    /// <p><p>Creates a copy of an entire cluster or replication group at a specific moment in time.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::create_snapshot`.
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// <p>Decreases the number of node groups in a Global Datastore</p>
+    ///
+    /// See `ElastiCache::decrease_node_groups_in_global_replication_group`.
+    pub fn decrease_node_groups_in_global_replication_group(
+        &self,
+        input: DecreaseNodeGroupsInGlobalReplicationGroupMessage,
+    ) -> Result<
+        DecreaseNodeGroupsInGlobalReplicationGroupResult,
+        RusotoError<DecreaseNodeGroupsInGlobalReplicationGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .decrease_node_groups_in_global_replication_group(input),
+        )
+    }
+
+    /// <p>Dynamically decreases the number of replicas in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
+    ///
+    /// See `ElastiCache::decrease_replica_count`.
+    pub fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
+        self.runtime
+            .block_on(self.client.decrease_replica_count(input))
+    }
+
+    /// <p><p>Deletes a previously provisioned cluster. <code>DeleteCacheCluster</code> deletes all associated cache nodes, node endpoints and the cluster itself. When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the cluster; you cannot cancel or revert this operation.</p> <p>This operation is not valid for:</p> <ul> <li> <p>Redis (cluster mode enabled) clusters</p> </li> <li> <p>A cluster that is the last read replica of a replication group</p> </li> <li> <p>A node group (shard) that has Multi-AZ mode enabled</p> </li> <li> <p>A cluster from a Redis (cluster mode enabled) replication group</p> </li> <li> <p>A cluster that is not in the <code>available</code> state</p> </li> </ul></p>
+    ///
+    /// See `ElastiCache::delete_cache_cluster`.
+    pub fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_cluster(input))
+    }
+
+    /// <p>Deletes the specified cache parameter group. You cannot delete a cache parameter group if it is associated with any cache clusters.</p>
+    ///
+    /// See `ElastiCache::delete_cache_parameter_group`.
+    pub fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_parameter_group(input))
+    }
+
+    /// <p><p>Deletes a cache security group.</p> <note> <p>You cannot delete a cache security group if it is associated with any clusters.</p> </note></p>
+    ///
+    /// See `ElastiCache::delete_cache_security_group`.
+    pub fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_security_group(input))
+    }
+
+    /// <p><p>Deletes a cache subnet group.</p> <note> <p>You cannot delete a cache subnet group if it is associated with any clusters.</p> </note></p>
+    ///
+    /// See `ElastiCache::delete_cache_subnet_group`.
+    pub fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_subnet_group(input))
+    }
+
+    /// <p>Deleting a Global Datastore is a two-step process: </p> <ul> <li> <p>First, you must <a>DisassociateGlobalReplicationGroup</a> to remove the secondary clusters in the Global Datastore.</p> </li> <li> <p>Once the Global Datastore contains only the primary cluster, you can use DeleteGlobalReplicationGroup API to delete the Global Datastore while retainining the primary cluster using Retain…= true.</p> </li> </ul> <p>Since the Global Datastore has only a primary cluster, you can delete the Global Datastore while retaining the primary by setting <code>RetainPrimaryCluster=true</code>.</p> <p>When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the selected resources; you cannot cancel or revert this operation.</p>
+    ///
+    /// See `ElastiCache::delete_global_replication_group`.
+    pub fn delete_global_replication_group(
+        &self,
+        input: DeleteGlobalReplicationGroupMessage,
+    ) -> Result<DeleteGlobalReplicationGroupResult, RusotoError<DeleteGlobalReplicationGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_global_replication_group(input))
+    }
+
+    /// <p><p>Deletes an existing replication group. By default, this operation deletes the entire replication group, including the primary/primaries and all of the read replicas. If the replication group has only one primary, you can optionally delete only the read replicas, while retaining the primary by setting <code>RetainPrimaryCluster=true</code>.</p> <p>When you receive a successful response from this operation, Amazon ElastiCache immediately begins deleting the selected resources; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::delete_replication_group`.
+    pub fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_replication_group(input))
+    }
+
+    /// <p><p>Deletes an existing snapshot. When you receive a successful response from this operation, ElastiCache immediately begins deleting the snapshot; you cannot cancel or revert this operation.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::delete_snapshot`.
+    pub fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        self.runtime.block_on(self.client.delete_snapshot(input))
+    }
+
+    /// <p>Returns information about all provisioned clusters if no cluster identifier is specified, or about a specific cache cluster if a cluster identifier is supplied.</p> <p>By default, abbreviated information about the clusters is returned. You can use the optional <i>ShowCacheNodeInfo</i> flag to retrieve detailed information about the cache nodes associated with the clusters. These details include the DNS address and port for the cache node endpoint.</p> <p>If the cluster is in the <i>creating</i> state, only cluster-level information is displayed until all of the nodes are successfully provisioned.</p> <p>If the cluster is in the <i>deleting</i> state, only cluster-level information is displayed.</p> <p>If cache nodes are currently being added to the cluster, node endpoint information and creation time for the additional nodes are not displayed until they are completely provisioned. When the cluster state is <i>available</i>, the cluster is ready for use.</p> <p>If cache nodes are currently being removed from the cluster, no endpoint information for the removed nodes is displayed.</p>
+    ///
+    /// See `ElastiCache::describe_cache_clusters`.
+    pub fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_clusters(input))
+    }
+
+    /// <p>Returns a list of the available cache engines and their versions.</p>
+    ///
+    /// See `ElastiCache::describe_cache_engine_versions`.
+    pub fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_engine_versions(input))
+    }
+
+    /// <p>Returns a list of cache parameter group descriptions. If a cache parameter group name is specified, the list contains only the descriptions for that group.</p>
+    ///
+    /// See `ElastiCache::describe_cache_parameter_groups`.
+    pub fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_parameter_groups(input))
+    }
+
+    /// <p>Returns the detailed parameter list for a particular cache parameter group.</p>
+    ///
+    /// See `ElastiCache::describe_cache_parameters`.
+    pub fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_parameters(input))
+    }
+
+    /// <p>Returns a list of cache security group descriptions. If a cache security group name is specified, the list contains only the description of that group. This applicable only when you have ElastiCache in Classic setup </p>
+    ///
+    /// See `ElastiCache::describe_cache_security_groups`.
+    pub fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_security_groups(input))
+    }
+
+    /// <p>Returns a list of cache subnet group descriptions. If a subnet group name is specified, the list contains only the description of that group. This is applicable only when you have ElastiCache in VPC setup. All ElastiCache clusters now launch in VPC by default. </p>
+    ///
+    /// See `ElastiCache::describe_cache_subnet_groups`.
+    pub fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_subnet_groups(input))
+    }
+
+    /// <p>Returns the default engine and system parameter information for the specified cache engine.</p>
+    ///
+    /// See `ElastiCache::describe_engine_default_parameters`.
+    pub fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_engine_default_parameters(input))
+    }
+
+    /// <p>Returns events related to clusters, cache security groups, and cache parameter groups. You can obtain events specific to a particular cluster, cache security group, or cache parameter group by providing the name as a parameter.</p> <p>By default, only the events occurring within the last hour are returned; however, you can retrieve up to 14 days' worth of events if necessary.</p>
+    ///
+    /// See `ElastiCache::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns information about a particular global replication group. If no identifier is specified, returns information about all Global Datastores. </p>
+    ///
+    /// See `ElastiCache::describe_global_replication_groups`.
+    pub fn describe_global_replication_groups(
+        &self,
+        input: DescribeGlobalReplicationGroupsMessage,
+    ) -> Result<
+        DescribeGlobalReplicationGroupsResult,
+        RusotoError<DescribeGlobalReplicationGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_global_replication_groups(input))
+    }
+
+    /// <p><p>Returns information about a particular replication group. If no identifier is specified, <code>DescribeReplicationGroups</code> returns information about all replication groups.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::describe_replication_groups`.
+    pub fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_replication_groups(input))
+    }
+
+    /// <p>Returns information about reserved cache nodes for this account, or about a specified reserved cache node.</p>
+    ///
+    /// See `ElastiCache::describe_reserved_cache_nodes`.
+    pub fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_cache_nodes(input))
+    }
+
+    /// <p>Lists available reserved cache node offerings.</p>
+    ///
+    /// See `ElastiCache::describe_reserved_cache_nodes_offerings`.
+    pub fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> Result<
+        ReservedCacheNodesOfferingMessage,
+        RusotoError<DescribeReservedCacheNodesOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_cache_nodes_offerings(input))
+    }
+
+    /// <p>Returns details of the service updates</p>
+    ///
+    /// See `ElastiCache::describe_service_updates`.
+    pub fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
+        self.runtime
+            .block_on(self.client.describe_service_updates(input))
+    }
+
+    /// <p><p>Returns information about cluster or replication group snapshots. By default, <code>DescribeSnapshots</code> lists all of your snapshots; it can optionally describe a single snapshot, or just the snapshots associated with a particular cache cluster.</p> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::describe_snapshots`.
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
+        self.runtime.block_on(self.client.describe_snapshots(input))
+    }
+
+    /// <p>Returns details of the update actions </p>
+    ///
+    /// See `ElastiCache::describe_update_actions`.
+    pub fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_update_actions(input))
+    }
+
+    /// <p>Remove a secondary cluster from the Global Datastore using the Global Datastore name. The secondary cluster will no longer receive updates from the primary cluster, but will remain as a standalone cluster in that AWS region.</p>
+    ///
+    /// See `ElastiCache::disassociate_global_replication_group`.
+    pub fn disassociate_global_replication_group(
+        &self,
+        input: DisassociateGlobalReplicationGroupMessage,
+    ) -> Result<
+        DisassociateGlobalReplicationGroupResult,
+        RusotoError<DisassociateGlobalReplicationGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_global_replication_group(input))
+    }
+
+    /// <p>Used to failover the primary region to a selected secondary region. The selected secondary region will become primary, and all other clusters will become secondary.</p>
+    ///
+    /// See `ElastiCache::failover_global_replication_group`.
+    pub fn failover_global_replication_group(
+        &self,
+        input: FailoverGlobalReplicationGroupMessage,
+    ) -> Result<
+        FailoverGlobalReplicationGroupResult,
+        RusotoError<FailoverGlobalReplicationGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.failover_global_replication_group(input))
+    }
+
+    /// <p>Increase the number of node groups in the Global Datastore</p>
+    ///
+    /// See `ElastiCache::increase_node_groups_in_global_replication_group`.
+    pub fn increase_node_groups_in_global_replication_group(
+        &self,
+        input: IncreaseNodeGroupsInGlobalReplicationGroupMessage,
+    ) -> Result<
+        IncreaseNodeGroupsInGlobalReplicationGroupResult,
+        RusotoError<IncreaseNodeGroupsInGlobalReplicationGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .increase_node_groups_in_global_replication_group(input),
+        )
+    }
+
+    /// <p>Dynamically increases the number of replics in a Redis (cluster mode disabled) replication group or the number of replica nodes in one or more node groups (shards) of a Redis (cluster mode enabled) replication group. This operation is performed with no cluster down time.</p>
+    ///
+    /// See `ElastiCache::increase_replica_count`.
+    pub fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
+        self.runtime
+            .block_on(self.client.increase_replica_count(input))
+    }
+
+    /// <p>Lists all available node types that you can scale your Redis cluster's or replication group's current node type.</p> <p>When you use the <code>ModifyCacheCluster</code> or <code>ModifyReplicationGroup</code> operations to scale your cluster or replication group, the value of the <code>CacheNodeType</code> parameter must be one of the node types returned by this operation.</p>
+    ///
+    /// See `ElastiCache::list_allowed_node_type_modifications`.
+    pub fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> Result<
+        AllowedNodeTypeModificationsMessage,
+        RusotoError<ListAllowedNodeTypeModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_allowed_node_type_modifications(input))
+    }
+
+    /// <p>Lists all cost allocation tags currently on the named resource. A <code>cost allocation tag</code> is a key-value pair where the key is case-sensitive and the value is optional. You can use cost allocation tags to categorize and track your AWS costs.</p> <p>If the cluster is not in the <i>available</i> state, <code>ListTagsForResource</code> returns an error.</p> <p>You can have a maximum of 50 cost allocation tags on an ElastiCache resource. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Tagging.html">Monitoring Costs with Tags</a>.</p>
+    ///
+    /// See `ElastiCache::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Modifies the settings for a cluster. You can use this operation to change one or more cluster configuration parameters by specifying the parameters and the new values.</p>
+    ///
+    /// See `ElastiCache::modify_cache_cluster`.
+    pub fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.modify_cache_cluster(input))
+    }
+
+    /// <p>Modifies the parameters of a cache parameter group. You can modify up to 20 parameters in a single request by submitting a list parameter name and value pairs.</p>
+    ///
+    /// See `ElastiCache::modify_cache_parameter_group`.
+    pub fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_cache_parameter_group(input))
+    }
+
+    /// <p>Modifies an existing cache subnet group.</p>
+    ///
+    /// See `ElastiCache::modify_cache_subnet_group`.
+    pub fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_cache_subnet_group(input))
+    }
+
+    /// <p>Modifies the settings for a Global Datastore.</p>
+    ///
+    /// See `ElastiCache::modify_global_replication_group`.
+    pub fn modify_global_replication_group(
+        &self,
+        input: ModifyGlobalReplicationGroupMessage,
+    ) -> Result<ModifyGlobalReplicationGroupResult, RusotoError<ModifyGlobalReplicationGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_global_replication_group(input))
+    }
+
+    /// <p><p>Modifies the settings for a replication group.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/scaling-redis-cluster-mode-enabled.html">Scaling for Amazon ElastiCache for Redis (cluster mode enabled)</a> in the ElastiCache User Guide</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_ModifyReplicationGroupShardConfiguration.html">ModifyReplicationGroupShardConfiguration</a> in the ElastiCache API Reference</p> </li> </ul> <note> <p>This operation is valid for Redis only.</p> </note></p>
+    ///
+    /// See `ElastiCache::modify_replication_group`.
+    pub fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_replication_group(input))
+    }
+
+    /// <p>Modifies a replication group's shards (node groups) by allowing you to add shards, remove shards, or rebalance the keyspaces among exisiting shards.</p>
+    ///
+    /// See `ElastiCache::modify_replication_group_shard_configuration`.
+    pub fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> Result<
+        ModifyReplicationGroupShardConfigurationResult,
+        RusotoError<ModifyReplicationGroupShardConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_replication_group_shard_configuration(input),
+        )
+    }
+
+    /// <p>Allows you to purchase a reserved cache node offering.</p>
+    ///
+    /// See `ElastiCache::purchase_reserved_cache_nodes_offering`.
+    pub fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> Result<
+        PurchaseReservedCacheNodesOfferingResult,
+        RusotoError<PurchaseReservedCacheNodesOfferingError>,
+    > {
+        self.runtime
+            .block_on(self.client.purchase_reserved_cache_nodes_offering(input))
+    }
+
+    /// <p>Redistribute slots to ensure uniform distribution across existing shards in the cluster.</p>
+    ///
+    /// See `ElastiCache::rebalance_slots_in_global_replication_group`.
+    pub fn rebalance_slots_in_global_replication_group(
+        &self,
+        input: RebalanceSlotsInGlobalReplicationGroupMessage,
+    ) -> Result<
+        RebalanceSlotsInGlobalReplicationGroupResult,
+        RusotoError<RebalanceSlotsInGlobalReplicationGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .rebalance_slots_in_global_replication_group(input),
+        )
+    }
+
+    /// <p>Reboots some, or all, of the cache nodes within a provisioned cluster. This operation applies any modified cache parameter groups to the cluster. The reboot operation takes place as soon as possible, and results in a momentary outage to the cluster. During the reboot, the cluster status is set to REBOOTING.</p> <p>The reboot causes the contents of the cache (for each cache node being rebooted) to be lost.</p> <p>When the reboot is complete, a cluster event is created.</p> <p>Rebooting a cluster is currently supported on Memcached and Redis (cluster mode disabled) clusters. Rebooting is not supported on Redis (cluster mode enabled) clusters.</p> <p>If you make changes to parameters that require a Redis (cluster mode enabled) cluster reboot for the changes to be applied, see <a href="http://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a> for an alternate process.</p>
+    ///
+    /// See `ElastiCache::reboot_cache_cluster`.
+    pub fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.reboot_cache_cluster(input))
+    }
+
+    /// <p>Removes the tags identified by the <code>TagKeys</code> list from the named resource.</p>
+    ///
+    /// See `ElastiCache::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p>Modifies the parameters of a cache parameter group to the engine or system default value. You can reset specific parameters by submitting a list of parameter names. To reset the entire cache parameter group, specify the <code>ResetAllParameters</code> and <code>CacheParameterGroupName</code> parameters.</p>
+    ///
+    /// See `ElastiCache::reset_cache_parameter_group`.
+    pub fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.reset_cache_parameter_group(input))
+    }
+
+    /// <p>Revokes ingress from a cache security group. Use this operation to disallow access from an Amazon EC2 security group that had been previously authorized.</p>
+    ///
+    /// See `ElastiCache::revoke_cache_security_group_ingress`.
+    pub fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        RevokeCacheSecurityGroupIngressResult,
+        RusotoError<RevokeCacheSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.revoke_cache_security_group_ingress(input))
+    }
+
+    /// <p>Start the migration of data.</p>
+    ///
+    /// See `ElastiCache::start_migration`.
+    pub fn start_migration(
+        &self,
+        input: StartMigrationMessage,
+    ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
+        self.runtime.block_on(self.client.start_migration(input))
+    }
+
+    /// <p>Represents the input of a <code>TestFailover</code> operation which test automatic failover on a specified node group (called shard in the console) in a replication group (called cluster in the console).</p> <p class="title"> <b>Note the following</b> </p> <ul> <li> <p>A customer can use this operation to test automatic failover on up to 5 shards (called node groups in the ElastiCache API and AWS CLI) in any rolling 24-hour period.</p> </li> <li> <p>If calling this operation on shards in different clusters (called replication groups in the API and CLI), the calls can be made concurrently.</p> <p> </p> </li> <li> <p>If calling this operation multiple times on different shards in the same Redis (cluster mode enabled) replication group, the first node replacement must complete before a subsequent call can be made.</p> </li> <li> <p>To determine whether the node replacement is complete you can check Events using the Amazon ElastiCache console, the AWS CLI, or the ElastiCache API. Look for the following automatic failover related events, listed here in order of occurrance:</p> <ol> <li> <p>Replication group message: <code>Test Failover API called for node group &lt;node-group-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Replication group message: <code>Failover from master node &lt;primary-node-id&gt; to replica node &lt;node-id&gt; completed</code> </p> </li> <li> <p>Cache cluster message: <code>Recovering cache nodes &lt;node-id&gt;</code> </p> </li> <li> <p>Cache cluster message: <code>Finished recovery for cache nodes &lt;node-id&gt;</code> </p> </li> </ol> <p>For more information see:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/ECEvents.Viewing.html">Viewing ElastiCache Events</a> in the <i>ElastiCache User Guide</i> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/APIReference/API_DescribeEvents.html">DescribeEvents</a> in the ElastiCache API Reference</p> </li> </ul> </li> </ul> <p>Also see, <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/AutoFailover.html#auto-failover-test">Testing Multi-AZ </a> in the <i>ElastiCache User Guide</i>.</p>
+    ///
+    /// See `ElastiCache::test_failover`.
+    pub fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
+        self.runtime.block_on(self.client.test_failover(input))
+    }
+}
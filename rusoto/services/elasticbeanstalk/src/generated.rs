@@ -7732,7 +7732,8 @@ impl AbortEnvironmentUpdateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AbortEnvironmentUpdateError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -7784,14 +7785,16 @@ impl ApplyEnvironmentManagedActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyEnvironmentManagedActionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ManagedActionInvalidStateException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyEnvironmentManagedActionError::ManagedActionInvalidState(
                                 parsed_error.message,
                             ),
@@ -7844,7 +7847,8 @@ impl AssociateEnvironmentOperationsRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateEnvironmentOperationsRoleError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -7928,14 +7932,16 @@ impl ComposeEnvironmentsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ComposeEnvironmentsError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
                     "TooManyEnvironmentsException" => {
-                        return RusotoError::Service(ComposeEnvironmentsError::TooManyEnvironments(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ComposeEnvironmentsError::TooManyEnvironments(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7978,9 +7984,10 @@ impl CreateApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyApplicationsException" => {
-                        return RusotoError::Service(CreateApplicationError::TooManyApplications(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateApplicationError::TooManyApplications(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8030,35 +8037,40 @@ impl CreateApplicationVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CodeBuildNotInServiceRegionException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateApplicationVersionError::CodeBuildNotInServiceRegion(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateApplicationVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateApplicationVersionError::S3LocationNotInServiceRegion(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyApplicationVersionsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateApplicationVersionError::TooManyApplicationVersions(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyApplicationsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateApplicationVersionError::TooManyApplications(
                                 parsed_error.message,
                             ),
@@ -8122,19 +8134,22 @@ impl CreateConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationTemplateError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
                         )
                     }
                     "TooManyConfigurationTemplatesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationTemplateError::TooManyConfigurationTemplates(
                                 parsed_error.message,
                             ),
@@ -8188,14 +8203,16 @@ impl CreateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEnvironmentError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
                     "TooManyEnvironmentsException" => {
-                        return RusotoError::Service(CreateEnvironmentError::TooManyEnvironments(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateEnvironmentError::TooManyEnvironments(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8242,23 +8259,26 @@ impl CreatePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreatePlatformVersionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreatePlatformVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyPlatformsException" => {
-                        return RusotoError::Service(CreatePlatformVersionError::TooManyPlatforms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePlatformVersionError::TooManyPlatforms(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8308,23 +8328,26 @@ impl CreateStorageLocationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStorageLocationError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "S3SubscriptionRequiredException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateStorageLocationError::S3SubscriptionRequired(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(CreateStorageLocationError::TooManyBuckets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateStorageLocationError::TooManyBuckets(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8368,9 +8391,10 @@ impl DeleteApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(DeleteApplicationError::OperationInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteApplicationError::OperationInProgress(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8418,28 +8442,32 @@ impl DeleteApplicationVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteApplicationVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteApplicationVersionError::OperationInProgress(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteApplicationVersionError::S3LocationNotInServiceRegion(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SourceBundleDeletionFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteApplicationVersionError::SourceBundleDeletion(
                                 parsed_error.message,
                             ),
@@ -8496,7 +8524,8 @@ impl DeleteConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteConfigurationTemplateError::OperationInProgress(
                                 parsed_error.message,
                             ),
@@ -8586,26 +8615,30 @@ impl DeletePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeletePlatformVersionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeletePlatformVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeletePlatformVersionError::OperationInProgress(parsed_error.message),
                         )
                     }
                     "PlatformVersionStillReferencedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeletePlatformVersionError::PlatformVersionStillReferenced(
                                 parsed_error.message,
                             ),
@@ -8658,7 +8691,8 @@ impl DescribeAccountAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeAccountAttributesError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -8778,7 +8812,8 @@ impl DescribeConfigurationOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeConfigurationOptionsError::TooManyBuckets(parsed_error.message),
                         )
                     }
@@ -8824,7 +8859,8 @@ impl DescribeConfigurationSettingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeConfigurationSettingsError::TooManyBuckets(
                                 parsed_error.message,
                             ),
@@ -8872,14 +8908,16 @@ impl DescribeEnvironmentHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEnvironmentHealthError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRequestException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEnvironmentHealthError::InvalidRequest(parsed_error.message),
                         )
                     }
@@ -8928,7 +8966,8 @@ impl DescribeEnvironmentManagedActionHistoryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
@@ -8978,7 +9017,8 @@ impl DescribeEnvironmentManagedActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
@@ -9028,7 +9068,8 @@ impl DescribeEnvironmentResourcesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEnvironmentResourcesError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -9146,16 +9187,18 @@ impl DescribeInstancesHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeInstancesHealthError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRequestException" => {
-                        return RusotoError::Service(DescribeInstancesHealthError::InvalidRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeInstancesHealthError::InvalidRequest(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9202,14 +9245,16 @@ impl DescribePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePlatformVersionError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePlatformVersionError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -9262,7 +9307,8 @@ impl DisassociateEnvironmentOperationsRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisassociateEnvironmentOperationsRoleError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -9382,14 +9428,16 @@ impl ListPlatformVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPlatformVersionsError::ElasticBeanstalkService(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPlatformVersionsError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
@@ -9438,17 +9486,20 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     "ResourceTypeNotSupportedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::ResourceTypeNotSupported(
                                 parsed_error.message,
                             ),
@@ -9496,7 +9547,8 @@ impl RebuildEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebuildEnvironmentError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
@@ -9676,7 +9728,8 @@ impl TerminateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TerminateEnvironmentError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
@@ -9756,7 +9809,8 @@ impl UpdateApplicationResourceLifecycleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateApplicationResourceLifecycleError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
@@ -9842,14 +9896,16 @@ impl UpdateConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationTemplateError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
                         )
                     }
@@ -9898,14 +9954,16 @@ impl UpdateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateEnvironmentError::InsufficientPrivileges(parsed_error.message),
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(UpdateEnvironmentError::TooManyBuckets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateEnvironmentError::TooManyBuckets(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9956,33 +10014,38 @@ impl UpdateTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTagsForResourceError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTagsForResourceError::OperationInProgress(parsed_error.message),
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(UpdateTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateTagsForResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     "ResourceTypeNotSupportedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTagsForResourceError::ResourceTypeNotSupported(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTagsException" => {
-                        return RusotoError::Service(UpdateTagsForResourceError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateTagsForResourceError::TooManyTags(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10034,14 +10097,16 @@ impl ValidateConfigurationSettingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ValidateConfigurationSettingsError::InsufficientPrivileges(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ValidateConfigurationSettingsError::TooManyBuckets(
                                 parsed_error.message,
                             ),
@@ -10392,6 +10457,7 @@ impl ElasticBeanstalkClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -10407,6 +10473,7 @@ impl ElasticBeanstalkClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
         ElasticBeanstalkClient { client, region }
     }
@@ -10420,6 +10487,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: AbortEnvironmentUpdateMessage,
     ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("AbortEnvironmentUpdate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AbortEnvironmentUpdate");
         let mut params = params;
         AbortEnvironmentUpdateMessageSerializer::serialize(&mut params, "", &input);
@@ -10441,6 +10511,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ApplyEnvironmentManagedAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ApplyEnvironmentManagedAction");
         let mut params = params;
         ApplyEnvironmentManagedActionRequestSerializer::serialize(&mut params, "", &input);
@@ -10474,6 +10547,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: AssociateEnvironmentOperationsRoleMessage,
     ) -> Result<(), RusotoError<AssociateEnvironmentOperationsRoleError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("AssociateEnvironmentOperationsRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AssociateEnvironmentOperationsRole");
         let mut params = params;
         AssociateEnvironmentOperationsRoleMessageSerializer::serialize(&mut params, "", &input);
@@ -10497,6 +10573,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CheckDNSAvailabilityMessage,
     ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CheckDNSAvailability");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CheckDNSAvailability");
         let mut params = params;
         CheckDNSAvailabilityMessageSerializer::serialize(&mut params, "", &input);
@@ -10530,6 +10609,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ComposeEnvironmentsMessage,
     ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ComposeEnvironments");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ComposeEnvironments");
         let mut params = params;
         ComposeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
@@ -10563,6 +10645,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreateApplicationMessage,
     ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateApplication");
         let mut params = params;
         CreateApplicationMessageSerializer::serialize(&mut params, "", &input);
@@ -10597,6 +10682,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CreateApplicationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateApplicationVersion");
         let mut params = params;
         CreateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
@@ -10631,6 +10719,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CreateConfigurationTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateConfigurationTemplate");
         let mut params = params;
         CreateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
@@ -10664,6 +10755,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CreateEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateEnvironment");
         let mut params = params;
         CreateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -10695,6 +10789,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreatePlatformVersionRequest,
     ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CreatePlatformVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreatePlatformVersion");
         let mut params = params;
         CreatePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -10727,6 +10824,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         &self,
     ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("CreateStorageLocation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateStorageLocation");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -10759,6 +10859,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteApplicationMessage,
     ) -> Result<(), RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteApplication");
         let mut params = params;
         DeleteApplicationMessageSerializer::serialize(&mut params, "", &input);
@@ -10779,6 +10882,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteApplicationVersionMessage,
     ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DeleteApplicationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteApplicationVersion");
         let mut params = params;
         DeleteApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
@@ -10799,6 +10905,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteConfigurationTemplateMessage,
     ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DeleteConfigurationTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteConfigurationTemplate");
         let mut params = params;
         DeleteConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
@@ -10819,6 +10928,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteEnvironmentConfigurationMessage,
     ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DeleteEnvironmentConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteEnvironmentConfiguration");
         let mut params = params;
         DeleteEnvironmentConfigurationMessageSerializer::serialize(&mut params, "", &input);
@@ -10839,6 +10951,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeletePlatformVersionRequest,
     ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DeletePlatformVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeletePlatformVersion");
         let mut params = params;
         DeletePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -10871,6 +10986,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         &self,
     ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeAccountAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountAttributes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -10904,6 +11022,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeApplicationVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeApplicationVersions");
         let mut params = params;
         DescribeApplicationVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10937,6 +11058,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeApplicationsMessage,
     ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeApplications");
         let mut params = params;
         DescribeApplicationsMessageSerializer::serialize(&mut params, "", &input);
@@ -10971,6 +11095,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeConfigurationOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeConfigurationOptions");
         let mut params = params;
         DescribeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -11005,6 +11132,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeConfigurationSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeConfigurationSettings");
         let mut params = params;
         DescribeConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
@@ -11038,6 +11168,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEnvironmentHealthRequest,
     ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeEnvironmentHealth");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEnvironmentHealth");
         let mut params = params;
         DescribeEnvironmentHealthRequestSerializer::serialize(&mut params, "", &input);
@@ -11074,6 +11207,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentManagedActionHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeEnvironmentManagedActionHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEnvironmentManagedActionHistory");
         let mut params = params;
         DescribeEnvironmentManagedActionHistoryRequestSerializer::serialize(
@@ -11117,6 +11253,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentManagedActionsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeEnvironmentManagedActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEnvironmentManagedActions");
         let mut params = params;
         DescribeEnvironmentManagedActionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11156,6 +11295,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentResourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeEnvironmentResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEnvironmentResources");
         let mut params = params;
         DescribeEnvironmentResourcesMessageSerializer::serialize(&mut params, "", &input);
@@ -11189,6 +11331,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEnvironmentsMessage,
     ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeEnvironments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEnvironments");
         let mut params = params;
         DescribeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
@@ -11222,6 +11367,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEventsMessage,
     ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -11253,6 +11401,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeInstancesHealthRequest,
     ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribeInstancesHealth");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstancesHealth");
         let mut params = params;
         DescribeInstancesHealthRequestSerializer::serialize(&mut params, "", &input);
@@ -11286,6 +11437,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribePlatformVersionRequest,
     ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DescribePlatformVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePlatformVersion");
         let mut params = params;
         DescribePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -11319,6 +11473,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DisassociateEnvironmentOperationsRoleMessage,
     ) -> Result<(), RusotoError<DisassociateEnvironmentOperationsRoleError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("DisassociateEnvironmentOperationsRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisassociateEnvironmentOperationsRole");
         let mut params = params;
         DisassociateEnvironmentOperationsRoleMessageSerializer::serialize(&mut params, "", &input);
@@ -11344,6 +11501,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<ListAvailableSolutionStacksError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ListAvailableSolutionStacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAvailableSolutionStacks");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11376,6 +11536,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListPlatformBranchesRequest,
     ) -> Result<ListPlatformBranchesResult, RusotoError<ListPlatformBranchesError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ListPlatformBranches");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPlatformBranches");
         let mut params = params;
         ListPlatformBranchesRequestSerializer::serialize(&mut params, "", &input);
@@ -11409,6 +11572,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListPlatformVersionsRequest,
     ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ListPlatformVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPlatformVersions");
         let mut params = params;
         ListPlatformVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11442,6 +11608,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListTagsForResourceMessage,
     ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -11475,6 +11644,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RebuildEnvironmentMessage,
     ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("RebuildEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebuildEnvironment");
         let mut params = params;
         RebuildEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -11495,6 +11667,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RequestEnvironmentInfoMessage,
     ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("RequestEnvironmentInfo");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RequestEnvironmentInfo");
         let mut params = params;
         RequestEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
@@ -11515,6 +11690,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RestartAppServerMessage,
     ) -> Result<(), RusotoError<RestartAppServerError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("RestartAppServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestartAppServer");
         let mut params = params;
         RestartAppServerMessageSerializer::serialize(&mut params, "", &input);
@@ -11536,6 +11714,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("RetrieveEnvironmentInfo");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RetrieveEnvironmentInfo");
         let mut params = params;
         RetrieveEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
@@ -11569,6 +11750,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: SwapEnvironmentCNAMEsMessage,
     ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("SwapEnvironmentCNAMEs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SwapEnvironmentCNAMEs");
         let mut params = params;
         SwapEnvironmentCNAMEsMessageSerializer::serialize(&mut params, "", &input);
@@ -11589,6 +11773,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: TerminateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("TerminateEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TerminateEnvironment");
         let mut params = params;
         TerminateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -11622,6 +11809,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateApplicationMessage,
     ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateApplication");
         let mut params = params;
         UpdateApplicationMessageSerializer::serialize(&mut params, "", &input);
@@ -11658,6 +11848,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<UpdateApplicationResourceLifecycleError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("UpdateApplicationResourceLifecycle");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateApplicationResourceLifecycle");
         let mut params = params;
         UpdateApplicationResourceLifecycleMessageSerializer::serialize(&mut params, "", &input);
@@ -11695,6 +11888,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("UpdateApplicationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateApplicationVersion");
         let mut params = params;
         UpdateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
@@ -11729,6 +11925,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("UpdateConfigurationTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateConfigurationTemplate");
         let mut params = params;
         UpdateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
@@ -11762,6 +11961,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("UpdateEnvironment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateEnvironment");
         let mut params = params;
         UpdateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -11793,6 +11995,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateTagsForResourceMessage,
     ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("UpdateTagsForResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateTagsForResource");
         let mut params = params;
         UpdateTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -11816,6 +12021,9 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<ValidateConfigurationSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation_name("ValidateConfigurationSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ValidateConfigurationSettings");
         let mut params = params;
         ValidateConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
@@ -11844,6 +12052,594 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     }
 }
 
+/// A blocking client for the Elastic Beanstalk API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ElasticBeanstalkClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElasticBeanstalkBlockingClient {
+    client: ElasticBeanstalkClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElasticBeanstalkBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElasticBeanstalkBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ElasticBeanstalkBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElasticBeanstalkBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElasticBeanstalkBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient {
+            client: ElasticBeanstalkClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels in-progress environment configuration update or application version deployment.</p>
+    ///
+    /// See `ElasticBeanstalk::abort_environment_update`.
+    pub fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
+        self.runtime
+            .block_on(self.client.abort_environment_update(input))
+    }
+
+    /// <p>Applies a scheduled managed action immediately. A managed action can be applied only if its status is <code>Scheduled</code>. Get the status and action ID of a managed action with <a>DescribeEnvironmentManagedActions</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::apply_environment_managed_action`.
+    pub fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_environment_managed_action(input))
+    }
+
+    /// <p>Add or change the operations role used by an environment. After this call is made, Elastic Beanstalk uses the associated operations role for permissions to downstream services during subsequent calls acting on this environment. For more information, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/iam-operationsrole.html">Operations roles</a> in the <i>AWS Elastic Beanstalk Developer Guide</i>.</p>
+    ///
+    /// See `ElasticBeanstalk::associate_environment_operations_role`.
+    pub fn associate_environment_operations_role(
+        &self,
+        input: AssociateEnvironmentOperationsRoleMessage,
+    ) -> Result<(), RusotoError<AssociateEnvironmentOperationsRoleError>> {
+        self.runtime
+            .block_on(self.client.associate_environment_operations_role(input))
+    }
+
+    /// <p>Checks if the specified CNAME is available.</p>
+    ///
+    /// See `ElasticBeanstalk::check_dns_availability`.
+    pub fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
+        self.runtime
+            .block_on(self.client.check_dns_availability(input))
+    }
+
+    /// <p>Create or update a group of environments that each run a separate component of a single application. Takes a list of version labels that specify application source bundles for each of the environments to create or update. The name of each environment and other required information must be included in the source bundles in an environment manifest named <code>env.yaml</code>. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-mgmt-compose.html">Compose Environments</a> for details.</p>
+    ///
+    /// See `ElasticBeanstalk::compose_environments`.
+    pub fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.compose_environments(input))
+    }
+
+    /// <p>Creates an application that has one configuration template named <code>default</code> and no application versions.</p>
+    ///
+    /// See `ElasticBeanstalk::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p><p>Creates an application version for the specified application. You can create an application version from a source bundle in Amazon S3, a commit in AWS CodeCommit, or the output of an AWS CodeBuild build as follows:</p> <p>Specify a commit in an AWS CodeCommit repository with <code>SourceBuildInformation</code>.</p> <p>Specify a build in an AWS CodeBuild with <code>SourceBuildInformation</code> and <code>BuildConfiguration</code>.</p> <p>Specify a source bundle in S3 with <code>SourceBundle</code> </p> <p>Omit both <code>SourceBuildInformation</code> and <code>SourceBundle</code> to use the default sample application.</p> <note> <p>After you create an application version with a specified Amazon S3 bucket and key location, you can&#39;t change that Amazon S3 location. If you change the Amazon S3 location, you receive an exception when you attempt to launch an environment from the application version.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::create_application_version`.
+    pub fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_application_version(input))
+    }
+
+    /// <p><p>Creates an AWS Elastic Beanstalk configuration template, associated with a specific Elastic Beanstalk application. You define application configuration settings in a configuration template. You can then use the configuration template to deploy different versions of the application with the same configuration settings.</p> <p>Templates aren&#39;t associated with any environment. The <code>EnvironmentName</code> response element is always <code>null</code>.</p> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> <li> <p> <a>DescribeConfigurationSettings</a> </p> </li> <li> <p> <a>ListAvailableSolutionStacks</a> </p> </li> </ul></p>
+    ///
+    /// See `ElasticBeanstalk::create_configuration_template`.
+    pub fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_configuration_template(input))
+    }
+
+    /// <p>Launches an AWS Elastic Beanstalk environment for the specified application using the specified configuration.</p>
+    ///
+    /// See `ElasticBeanstalk::create_environment`.
+    pub fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
+        self.runtime.block_on(self.client.create_environment(input))
+    }
+
+    /// <p>Create a new version of your custom platform.</p>
+    ///
+    /// See `ElasticBeanstalk::create_platform_version`.
+    pub fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
+        self.runtime
+            .block_on(self.client.create_platform_version(input))
+    }
+
+    /// <p>Creates a bucket in Amazon S3 to store application versions, logs, and other files used by Elastic Beanstalk environments. The Elastic Beanstalk console and EB CLI call this API the first time you create an environment in a region. If the storage location already exists, <code>CreateStorageLocation</code> still returns the bucket name but does not create a new bucket.</p>
+    ///
+    /// See `ElasticBeanstalk::create_storage_location`.
+    pub fn create_storage_location(
+        &self,
+    ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
+        self.runtime.block_on(self.client.create_storage_location())
+    }
+
+    /// <p><p>Deletes the specified application along with all associated versions and configurations. The application versions will not be deleted from your Amazon S3 bucket.</p> <note> <p>You cannot delete an application that has a running environment.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p><p>Deletes the specified version from the specified application.</p> <note> <p>You cannot delete an application version that is associated with a running environment.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::delete_application_version`.
+    pub fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_application_version(input))
+    }
+
+    /// <p><p>Deletes the specified configuration template.</p> <note> <p>When you launch an environment using a configuration template, the environment gets a copy of the template. You can delete or modify the environment&#39;s copy of the template without affecting the running environment.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::delete_configuration_template`.
+    pub fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_template(input))
+    }
+
+    /// <p>Deletes the draft configuration associated with the running environment.</p> <p>Updating a running environment with any configuration changes creates a draft configuration set. You can get the draft configuration using <a>DescribeConfigurationSettings</a> while the update is in progress or if the update fails. The <code>DeploymentStatus</code> for the draft configuration indicates whether the deployment is in process or has failed. The draft configuration remains in existence until it is deleted with this action.</p>
+    ///
+    /// See `ElasticBeanstalk::delete_environment_configuration`.
+    pub fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_environment_configuration(input))
+    }
+
+    /// <p>Deletes the specified version of a custom platform.</p>
+    ///
+    /// See `ElasticBeanstalk::delete_platform_version`.
+    pub fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_platform_version(input))
+    }
+
+    /// <p>Returns attributes related to AWS Elastic Beanstalk that are associated with the calling AWS account.</p> <p>The result currently has one set of attributes—resource quotas.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_account_attributes`.
+    pub fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes())
+    }
+
+    /// <p>Retrieve a list of application versions.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_application_versions`.
+    pub fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_application_versions(input))
+    }
+
+    /// <p>Returns the descriptions of existing applications.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_applications`.
+    pub fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
+        self.runtime
+            .block_on(self.client.describe_applications(input))
+    }
+
+    /// <p>Describes the configuration options that are used in a particular configuration template or environment, or that a specified solution stack defines. The description includes the values the options, their default values, and an indication of the required action on a running environment if an option value is changed.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_configuration_options`.
+    pub fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_configuration_options(input))
+    }
+
+    /// <p><p>Returns a description of the settings for the specified configuration set, that is, either a configuration template or the configuration set associated with a running environment.</p> <p>When describing the settings for the configuration set associated with a running environment, it is possible to receive two sets of setting descriptions. One is the deployed configuration set, and the other is a draft configuration of an environment that is either in the process of deployment or that failed to deploy.</p> <p>Related Topics</p> <ul> <li> <p> <a>DeleteEnvironmentConfiguration</a> </p> </li> </ul></p>
+    ///
+    /// See `ElasticBeanstalk::describe_configuration_settings`.
+    pub fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_configuration_settings(input))
+    }
+
+    /// <p>Returns information about the overall health of the specified environment. The <b>DescribeEnvironmentHealth</b> operation is only available with AWS Elastic Beanstalk Enhanced Health.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_environment_health`.
+    pub fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_environment_health(input))
+    }
+
+    /// <p>Lists an environment's completed and failed managed actions.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_environment_managed_action_history`.
+    pub fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> Result<
+        DescribeEnvironmentManagedActionHistoryResult,
+        RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_environment_managed_action_history(input),
+        )
+    }
+
+    /// <p>Lists an environment's upcoming and in-progress managed actions.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_environment_managed_actions`.
+    pub fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> Result<
+        DescribeEnvironmentManagedActionsResult,
+        RusotoError<DescribeEnvironmentManagedActionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_environment_managed_actions(input))
+    }
+
+    /// <p>Returns AWS resources for this environment.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_environment_resources`.
+    pub fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> Result<
+        EnvironmentResourceDescriptionsMessage,
+        RusotoError<DescribeEnvironmentResourcesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_environment_resources(input))
+    }
+
+    /// <p>Returns descriptions for existing environments.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_environments`.
+    pub fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.describe_environments(input))
+    }
+
+    /// <p><p>Returns list of event descriptions matching criteria up to the last 6 weeks.</p> <note> <p>This action returns the most recent 1,000 events from the specified <code>NextToken</code>.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Retrieves detailed information about the health of instances in your AWS Elastic Beanstalk. This operation requires <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced.html">enhanced health reporting</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_instances_health`.
+    pub fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_instances_health(input))
+    }
+
+    /// <p>Describes a platform version. Provides full details. Compare to <a>ListPlatformVersions</a>, which provides summary information about a list of platform versions.</p> <p>For definitions of platform version and other platform-related terms, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/platforms-glossary.html">AWS Elastic Beanstalk Platforms Glossary</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::describe_platform_version`.
+    pub fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
+        self.runtime
+            .block_on(self.client.describe_platform_version(input))
+    }
+
+    /// <p>Disassociate the operations role from an environment. After this call is made, Elastic Beanstalk uses the caller's permissions for permissions to downstream services during subsequent calls acting on this environment. For more information, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/iam-operationsrole.html">Operations roles</a> in the <i>AWS Elastic Beanstalk Developer Guide</i>.</p>
+    ///
+    /// See `ElasticBeanstalk::disassociate_environment_operations_role`.
+    pub fn disassociate_environment_operations_role(
+        &self,
+        input: DisassociateEnvironmentOperationsRoleMessage,
+    ) -> Result<(), RusotoError<DisassociateEnvironmentOperationsRoleError>> {
+        self.runtime
+            .block_on(self.client.disassociate_environment_operations_role(input))
+    }
+
+    /// <p>Returns a list of the available solution stack names, with the public version first and then in reverse chronological order.</p>
+    ///
+    /// See `ElasticBeanstalk::list_available_solution_stacks`.
+    pub fn list_available_solution_stacks(
+        &self,
+    ) -> Result<
+        ListAvailableSolutionStacksResultMessage,
+        RusotoError<ListAvailableSolutionStacksError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_available_solution_stacks())
+    }
+
+    /// <p>Lists the platform branches available for your account in an AWS Region. Provides summary information about each platform branch.</p> <p>For definitions of platform branch and other platform-related terms, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/platforms-glossary.html">AWS Elastic Beanstalk Platforms Glossary</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::list_platform_branches`.
+    pub fn list_platform_branches(
+        &self,
+        input: ListPlatformBranchesRequest,
+    ) -> Result<ListPlatformBranchesResult, RusotoError<ListPlatformBranchesError>> {
+        self.runtime
+            .block_on(self.client.list_platform_branches(input))
+    }
+
+    /// <p>Lists the platform versions available for your account in an AWS Region. Provides summary information about each platform version. Compare to <a>DescribePlatformVersion</a>, which provides full details about a single platform version.</p> <p>For definitions of platform version and other platform-related terms, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/platforms-glossary.html">AWS Elastic Beanstalk Platforms Glossary</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::list_platform_versions`.
+    pub fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_platform_versions(input))
+    }
+
+    /// <p>Return the tags applied to an AWS Elastic Beanstalk resource. The response contains a list of tag key-value pairs.</p> <p>Elastic Beanstalk supports tagging of all of its resources. For details about resource tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/applications-tagging-resources.html">Tagging Application Resources</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Deletes and recreates all of the AWS resources (for example: the Auto Scaling group, load balancer, etc.) for a specified environment and forces a restart.</p>
+    ///
+    /// See `ElasticBeanstalk::rebuild_environment`.
+    pub fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.rebuild_environment(input))
+    }
+
+    /// <p><p>Initiates a request to compile the specified type of information of the deployed environment.</p> <p> Setting the <code>InfoType</code> to <code>tail</code> compiles the last lines from the application server log files of every Amazon EC2 instance in your environment. </p> <p> Setting the <code>InfoType</code> to <code>bundle</code> compresses the application server log files for every Amazon EC2 instance into a <code>.zip</code> file. Legacy and .NET containers do not support bundle logs. </p> <p> Use <a>RetrieveEnvironmentInfo</a> to obtain the set of logs. </p> <p>Related Topics</p> <ul> <li> <p> <a>RetrieveEnvironmentInfo</a> </p> </li> </ul></p>
+    ///
+    /// See `ElasticBeanstalk::request_environment_info`.
+    pub fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
+        self.runtime
+            .block_on(self.client.request_environment_info(input))
+    }
+
+    /// <p>Causes the environment to restart the application container server running on each Amazon EC2 instance.</p>
+    ///
+    /// See `ElasticBeanstalk::restart_app_server`.
+    pub fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> Result<(), RusotoError<RestartAppServerError>> {
+        self.runtime.block_on(self.client.restart_app_server(input))
+    }
+
+    /// <p><p>Retrieves the compiled information from a <a>RequestEnvironmentInfo</a> request.</p> <p>Related Topics</p> <ul> <li> <p> <a>RequestEnvironmentInfo</a> </p> </li> </ul></p>
+    ///
+    /// See `ElasticBeanstalk::retrieve_environment_info`.
+    pub fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
+    {
+        self.runtime
+            .block_on(self.client.retrieve_environment_info(input))
+    }
+
+    /// <p>Swaps the CNAMEs of two environments.</p>
+    ///
+    /// See `ElasticBeanstalk::swap_environment_cnam_es`.
+    pub fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
+        self.runtime
+            .block_on(self.client.swap_environment_cnam_es(input))
+    }
+
+    /// <p>Terminates the specified environment.</p>
+    ///
+    /// See `ElasticBeanstalk::terminate_environment`.
+    pub fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.terminate_environment(input))
+    }
+
+    /// <p><p>Updates the specified application to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear these properties, specify an empty string.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// <p>Modifies lifecycle settings for an application.</p>
+    ///
+    /// See `ElasticBeanstalk::update_application_resource_lifecycle`.
+    pub fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> Result<
+        ApplicationResourceLifecycleDescriptionMessage,
+        RusotoError<UpdateApplicationResourceLifecycleError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_application_resource_lifecycle(input))
+    }
+
+    /// <p><p>Updates the specified application version to have the specified properties.</p> <note> <p>If a property (for example, <code>description</code>) is not provided, the value remains unchanged. To clear properties, specify an empty string.</p> </note></p>
+    ///
+    /// See `ElasticBeanstalk::update_application_version`.
+    pub fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_application_version(input))
+    }
+
+    /// <p><p>Updates the specified configuration template to have the specified properties or configuration option values.</p> <note> <p>If a property (for example, <code>ApplicationName</code>) is not provided, its value remains unchanged. To clear such properties, specify an empty string.</p> </note> <p>Related Topics</p> <ul> <li> <p> <a>DescribeConfigurationOptions</a> </p> </li> </ul></p>
+    ///
+    /// See `ElasticBeanstalk::update_configuration_template`.
+    pub fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.update_configuration_template(input))
+    }
+
+    /// <p>Updates the environment description, deploys a new application version, updates the configuration settings to an entirely new configuration template, or updates select configuration option values in the running environment.</p> <p> Attempting to update both the release and configuration is not allowed and AWS Elastic Beanstalk returns an <code>InvalidParameterCombination</code> error. </p> <p> When updating the configuration settings to a new template or individual settings, a draft configuration is created and <a>DescribeConfigurationSettings</a> for this environment returns two setting descriptions with different <code>DeploymentStatus</code> values. </p>
+    ///
+    /// See `ElasticBeanstalk::update_environment`.
+    pub fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
+        self.runtime.block_on(self.client.update_environment(input))
+    }
+
+    /// <p>Update the list of tags applied to an AWS Elastic Beanstalk resource. Two lists can be passed: <code>TagsToAdd</code> for tags to add or update, and <code>TagsToRemove</code>.</p> <p>Elastic Beanstalk supports tagging of all of its resources. For details about resource tagging, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/applications-tagging-resources.html">Tagging Application Resources</a>.</p> <p>If you create a custom IAM user policy to control permission to this operation, specify one of the following two virtual actions (or both) instead of the API operation name:</p> <dl> <dt>elasticbeanstalk:AddTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tags to add in the <code>TagsToAdd</code> parameter.</p> </dd> <dt>elasticbeanstalk:RemoveTags</dt> <dd> <p>Controls permission to call <code>UpdateTagsForResource</code> and pass a list of tag keys to remove in the <code>TagsToRemove</code> parameter.</p> </dd> </dl> <p>For details about creating a custom user policy, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/AWSHowTo.iam.managed-policies.html#AWSHowTo.iam.policies">Creating a Custom User Policy</a>.</p>
+    ///
+    /// See `ElasticBeanstalk::update_tags_for_resource`.
+    pub fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.update_tags_for_resource(input))
+    }
+
+    /// <p>Takes a set of configuration settings and either a configuration template or environment, and determines whether those values are valid.</p> <p>This action returns a list of messages indicating any errors or warnings associated with the selection of option values.</p>
+    ///
+    /// See `ElasticBeanstalk::validate_configuration_settings`.
+    pub fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> Result<
+        ConfigurationSettingsValidationMessages,
+        RusotoError<ValidateConfigurationSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.validate_configuration_settings(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -1449,19 +1449,19 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelJobError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CancelJobError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CancelJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(&res, CancelJobError::IncompatibleVersion(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, CancelJobError::InternalService(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CancelJobError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CancelJobError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1503,19 +1503,19 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateJobError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateJobError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreateJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(&res, CreateJobError::IncompatibleVersion(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InternalService(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateJobError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateJobError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1557,19 +1557,28 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreatePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InternalService(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1609,16 +1618,19 @@ impl CreatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePresetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreatePresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePresetError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePresetError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::InternalService(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePresetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1659,19 +1671,28 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeletePipelineError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(DeletePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::InternalService(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeletePipelineError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1711,16 +1732,19 @@ impl DeletePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePresetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(DeletePresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePresetError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePresetError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::InternalService(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePresetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1759,18 +1783,28 @@ impl ListJobsByPipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByPipelineError::AccessDenied(err.msg),
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::IncompatibleVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByPipelineError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByPipelineError::InternalService(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByPipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1809,18 +1843,25 @@ impl ListJobsByStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListJobsByStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListJobsByStatusError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListJobsByStatusError::IncompatibleVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByStatusError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsByStatusError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByStatusError::InternalService(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsByStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsByStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1857,13 +1898,16 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPipelinesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListPipelinesError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListPipelinesError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPipelinesError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPipelinesError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListPipelinesError::InternalService(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1899,13 +1943,16 @@ impl ListPresetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPresetsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListPresetsError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPresetsError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPresetsError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::InternalService(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1943,16 +1990,16 @@ impl ReadJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadJobError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ReadJobError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(&res, ReadJobError::IncompatibleVersion(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, ReadJobError::InternalService(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ReadJobError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1991,16 +2038,19 @@ impl ReadPipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadPipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ReadPipelineError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadPipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReadPipelineError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadPipelineError::InternalService(err.msg))
+                    return RusotoError::service(&res, ReadPipelineError::InternalService(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadPipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ReadPipelineError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2039,16 +2089,19 @@ impl ReadPresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadPresetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ReadPresetError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadPresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReadPresetError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadPresetError::InternalService(err.msg))
+                    return RusotoError::service(&res, ReadPresetError::InternalService(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadPresetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ReadPresetError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2087,16 +2140,16 @@ impl TestRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TestRoleError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TestRoleError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(TestRoleError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(&res, TestRoleError::IncompatibleVersion(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TestRoleError::InternalService(err.msg))
+                    return RusotoError::service(&res, TestRoleError::InternalService(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestRoleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TestRoleError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2137,19 +2190,28 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdatePipelineError::AccessDenied(err.msg))
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(UpdatePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::InternalService(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdatePipelineError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2193,27 +2255,32 @@ impl UpdatePipelineNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineNotificationsError::AccessDenied(err.msg),
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePipelineNotificationsError::IncompatibleVersion(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineNotificationsError::InternalService(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineNotificationsError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePipelineNotificationsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2259,25 +2326,34 @@ impl UpdatePipelineStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineStatusError::AccessDenied(err.msg),
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::IncompatibleVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineStatusError::IncompatibleVersion(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineStatusError::InternalService(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineStatusError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2422,6 +2498,7 @@ impl EtsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2437,6 +2514,7 @@ impl EtsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EtsClient {
         EtsClient { client, region }
     }
@@ -2454,6 +2532,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2483,6 +2564,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2515,6 +2599,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("CreatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2547,6 +2634,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("CreatePreset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2579,6 +2669,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("DeletePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2608,6 +2701,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("DeletePreset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2640,6 +2736,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ListJobsByPipeline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2678,6 +2777,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ListJobsByStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2716,6 +2818,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ListPipelines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2754,6 +2859,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ListPresets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2792,6 +2900,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ReadJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2821,6 +2932,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ReadPipeline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2850,6 +2964,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("ReadPreset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2879,6 +2996,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("TestRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2911,6 +3031,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("UpdatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2944,6 +3067,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("UpdatePipelineNotifications");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2976,6 +3102,9 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation_name("UpdatePipelineStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2998,3 +3127,228 @@ impl Ets for EtsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Elastic Transcoder API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EtsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EtsBlockingClient {
+    client: EtsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EtsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EtsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EtsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EtsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EtsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EtsBlockingClient {
+        EtsBlockingClient {
+            client: EtsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>The CancelJob operation cancels an unfinished job.</p> <note> <p>You can only cancel a job that has a status of <code>Submitted</code>. To prevent a pipeline from starting to process a job while you&#39;re getting the job identifier, use <a>UpdatePipelineStatus</a> to temporarily pause the pipeline.</p> </note></p>
+    ///
+    /// See `Ets::cancel_job`.
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p>When you create a job, Elastic Transcoder returns JSON data that includes the values that you specified plus information about the job that is created.</p> <p>If you have specified more than one output for your jobs (for example, one output for the Kindle Fire and another output for the Apple iPhone 4s), you currently must use the Elastic Transcoder API to list the jobs (as opposed to the AWS Console).</p>
+    ///
+    /// See `Ets::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>The CreatePipeline operation creates a pipeline with settings that you specify.</p>
+    ///
+    /// See `Ets::create_pipeline`.
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// <p>The CreatePreset operation creates a preset with settings that you specify.</p> <important> <p>Elastic Transcoder checks the CreatePreset settings to ensure that they meet Elastic Transcoder requirements and to determine whether they comply with H.264 standards. If your settings are not valid for Elastic Transcoder, Elastic Transcoder returns an HTTP 400 response (<code>ValidationException</code>) and does not create the preset. If the settings are valid for Elastic Transcoder but aren't strictly compliant with the H.264 standard, Elastic Transcoder creates the preset and returns a warning message in the response. This helps you determine whether your settings comply with the H.264 standard while giving you greater flexibility with respect to the video that Elastic Transcoder produces.</p> </important> <p>Elastic Transcoder uses the H.264 video-compression format. For more information, see the International Telecommunication Union publication <i>Recommendation ITU-T H.264: Advanced video coding for generic audiovisual services</i>.</p>
+    ///
+    /// See `Ets::create_preset`.
+    pub fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
+        self.runtime.block_on(self.client.create_preset(input))
+    }
+
+    /// <p>The DeletePipeline operation removes a pipeline.</p> <p> You can only delete a pipeline that has never been used or that is not currently in use (doesn't contain any active jobs). If the pipeline is currently in use, <code>DeletePipeline</code> returns an error. </p>
+    ///
+    /// See `Ets::delete_pipeline`.
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// <p><p>The DeletePreset operation removes a preset that you&#39;ve added in an AWS region.</p> <note> <p>You can&#39;t delete the default presets that are included with Elastic Transcoder.</p> </note></p>
+    ///
+    /// See `Ets::delete_preset`.
+    pub fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
+        self.runtime.block_on(self.client.delete_preset(input))
+    }
+
+    /// <p>The ListJobsByPipeline operation gets a list of the jobs currently in a pipeline.</p> <p>Elastic Transcoder returns all of the jobs currently in the specified pipeline. The response body contains one element for each job that satisfies the search criteria.</p>
+    ///
+    /// See `Ets::list_jobs_by_pipeline`.
+    pub fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> Result<ListJobsByPipelineResponse, RusotoError<ListJobsByPipelineError>> {
+        self.runtime
+            .block_on(self.client.list_jobs_by_pipeline(input))
+    }
+
+    /// <p>The ListJobsByStatus operation gets a list of jobs that have a specified status. The response body contains one element for each job that satisfies the search criteria.</p>
+    ///
+    /// See `Ets::list_jobs_by_status`.
+    pub fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> Result<ListJobsByStatusResponse, RusotoError<ListJobsByStatusError>> {
+        self.runtime
+            .block_on(self.client.list_jobs_by_status(input))
+    }
+
+    /// <p>The ListPipelines operation gets a list of the pipelines associated with the current AWS account.</p>
+    ///
+    /// See `Ets::list_pipelines`.
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// <p>The ListPresets operation gets a list of the default presets included with Elastic Transcoder and the presets that you've added in an AWS region.</p>
+    ///
+    /// See `Ets::list_presets`.
+    pub fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+        self.runtime.block_on(self.client.list_presets(input))
+    }
+
+    /// <p>The ReadJob operation returns detailed information about a job.</p>
+    ///
+    /// See `Ets::read_job`.
+    pub fn read_job(
+        &self,
+        input: ReadJobRequest,
+    ) -> Result<ReadJobResponse, RusotoError<ReadJobError>> {
+        self.runtime.block_on(self.client.read_job(input))
+    }
+
+    /// <p>The ReadPipeline operation gets detailed information about a pipeline.</p>
+    ///
+    /// See `Ets::read_pipeline`.
+    pub fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> Result<ReadPipelineResponse, RusotoError<ReadPipelineError>> {
+        self.runtime.block_on(self.client.read_pipeline(input))
+    }
+
+    /// <p>The ReadPreset operation gets detailed information about a preset.</p>
+    ///
+    /// See `Ets::read_preset`.
+    pub fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> Result<ReadPresetResponse, RusotoError<ReadPresetError>> {
+        self.runtime.block_on(self.client.read_preset(input))
+    }
+
+    /// <p>The TestRole operation tests the IAM role used to create the pipeline.</p> <p>The <code>TestRole</code> action lets you determine whether the IAM role you are using has sufficient permissions to let Elastic Transcoder perform tasks associated with the transcoding process. The action attempts to assume the specified IAM role, checks read access to the input and output buckets, and tries to send a test notification to Amazon SNS topics that you specify.</p>
+    ///
+    /// See `Ets::test_role`.
+    pub fn test_role(
+        &self,
+        input: TestRoleRequest,
+    ) -> Result<TestRoleResponse, RusotoError<TestRoleError>> {
+        self.runtime.block_on(self.client.test_role(input))
+    }
+
+    /// <p><p> Use the <code>UpdatePipeline</code> operation to update settings for a pipeline.</p> <important> <p>When you change pipeline settings, your changes take effect immediately. Jobs that you have already submitted and that Elastic Transcoder has not started to process are affected in addition to jobs that you submit after you change settings. </p> </important></p>
+    ///
+    /// See `Ets::update_pipeline`.
+    pub fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>> {
+        self.runtime.block_on(self.client.update_pipeline(input))
+    }
+
+    /// <p>With the UpdatePipelineNotifications operation, you can update Amazon Simple Notification Service (Amazon SNS) notifications for a pipeline.</p> <p>When you update notifications for a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
+    ///
+    /// See `Ets::update_pipeline_notifications`.
+    pub fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> Result<UpdatePipelineNotificationsResponse, RusotoError<UpdatePipelineNotificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_pipeline_notifications(input))
+    }
+
+    /// <p>The UpdatePipelineStatus operation pauses or reactivates a pipeline, so that the pipeline stops or restarts the processing of jobs.</p> <p>Changing the pipeline status is useful if you want to cancel one or more jobs. You can't cancel jobs after Elastic Transcoder has started processing them; if you pause the pipeline to which you submitted the jobs, you have more time to get the job IDs for the jobs that you want to cancel, and to send a <a>CancelJob</a> request. </p>
+    ///
+    /// See `Ets::update_pipeline_status`.
+    pub fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> Result<UpdatePipelineStatusResponse, RusotoError<UpdatePipelineStatusError>> {
+        self.runtime
+            .block_on(self.client.update_pipeline_status(input))
+    }
+}
@@ -4130,19 +4130,22 @@ impl AddTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(AddTagsError::AccessPointNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::AccessPointNotFound(parsed_error.message),
+                        )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(AddTagsError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::DuplicateTagKeys(parsed_error.message),
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(AddTagsError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::TooManyTags(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4192,21 +4195,24 @@ impl ApplySecurityGroupsToLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(
                                 parsed_error.message,
                             ),
@@ -4268,26 +4274,30 @@ impl AttachLoadBalancerToSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancerToSubnetsError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancerToSubnetsError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AttachLoadBalancerToSubnetsError::SubnetNotFound(parsed_error.message),
                         )
                     }
@@ -4338,7 +4348,8 @@ impl ConfigureHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ConfigureHealthCheckError::AccessPointNotFound(parsed_error.message),
                         )
                     }
@@ -4390,28 +4401,32 @@ impl CreateAppCookieStickinessPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAppCookieStickinessPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAppCookieStickinessPolicyError::DuplicatePolicyName(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateAppCookieStickinessPolicyError::TooManyPolicies(
                                 parsed_error.message,
                             ),
@@ -4476,28 +4491,32 @@ impl CreateLBCookieStickinessPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLBCookieStickinessPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLBCookieStickinessPolicyError::DuplicatePolicyName(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLBCookieStickinessPolicyError::TooManyPolicies(
                                 parsed_error.message,
                             ),
@@ -4576,66 +4595,78 @@ impl CreateLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::CertificateNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::CertificateNotFound(parsed_error.message),
+                        )
                     }
                     "DuplicateLoadBalancerName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::DuplicateAccessPointName(parsed_error.message),
                         )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::DuplicateTagKeys(parsed_error.message),
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidScheme" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::InvalidScheme(parsed_error.message),
+                        )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::InvalidSecurityGroup(parsed_error.message),
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
                         )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::SubnetNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyLoadBalancers" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyAccessPoints(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::TooManyAccessPoints(parsed_error.message),
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::TooManyTags(parsed_error.message),
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateLoadBalancerError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4700,35 +4731,40 @@ impl CreateLoadBalancerListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerListenersError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerListenersError::CertificateNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerListenersError::DuplicateListener(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerListenersError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerListenersError::UnsupportedProtocol(
                                 parsed_error.message,
                             ),
@@ -4796,33 +4832,38 @@ impl CreateLoadBalancerPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerPolicyError::DuplicatePolicyName(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PolicyTypeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerPolicyError::PolicyTypeNotFound(parsed_error.message),
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerPolicyError::TooManyPolicies(parsed_error.message),
                         )
                     }
@@ -4908,7 +4949,8 @@ impl DeleteLoadBalancerListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLoadBalancerListenersError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
@@ -4958,14 +5000,16 @@ impl DeleteLoadBalancerPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLoadBalancerPolicyError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
@@ -5018,14 +5062,16 @@ impl DeregisterInstancesFromLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeregisterInstancesFromLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(
                                 parsed_error.message,
                             ),
@@ -5112,14 +5158,16 @@ impl DescribeInstanceHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeInstanceHealthError::AccessPointNotFound(parsed_error.message),
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(DescribeInstanceHealthError::InvalidEndPoint(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeInstanceHealthError::InvalidEndPoint(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5166,14 +5214,16 @@ impl DescribeLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerAttributesError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
                                 parsed_error.message,
                             ),
@@ -5228,14 +5278,16 @@ impl DescribeLoadBalancerPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerPoliciesError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerPoliciesError::PolicyNotFound(parsed_error.message),
                         )
                     }
@@ -5284,7 +5336,8 @@ impl DescribeLoadBalancerPolicyTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "PolicyTypeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(
                                 parsed_error.message,
                             ),
@@ -5334,12 +5387,14 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancersError::AccessPointNotFound(parsed_error.message),
                         )
                     }
                     "DependencyThrottle" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancersError::DependencyThrottle(parsed_error.message),
                         )
                     }
@@ -5384,9 +5439,10 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::AccessPointNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::AccessPointNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5432,14 +5488,16 @@ impl DetachLoadBalancerFromSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DetachLoadBalancerFromSubnetsError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
@@ -5494,13 +5552,15 @@ impl DisableAvailabilityZonesForLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidConfigurationRequest" => return RusotoError::Service(
+                    "InvalidConfigurationRequest" => return RusotoError::service(
+                        &res,
                         DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(
                             parsed_error.message,
                         ),
@@ -5552,7 +5612,8 @@ impl EnableAvailabilityZonesForLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
@@ -5606,21 +5667,24 @@ impl ModifyLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyLoadBalancerAttributesError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
                                 parsed_error.message,
                             ),
@@ -5678,14 +5742,16 @@ impl RegisterInstancesWithLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterInstancesWithLoadBalancerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterInstancesWithLoadBalancerError::InvalidEndPoint(
                                 parsed_error.message,
                             ),
@@ -5736,9 +5802,10 @@ impl RemoveTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::AccessPointNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsError::AccessPointNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5790,35 +5857,40 @@ impl SetLoadBalancerListenerSSLCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerListenerSSLCertificateError::CertificateNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerListenerSSLCertificateError::ListenerNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(
                                 parsed_error.message,
                             ),
@@ -5884,19 +5956,22 @@ impl SetLoadBalancerPoliciesForBackendServerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidConfigurationRequest" => return RusotoError::Service(
+                    "InvalidConfigurationRequest" => return RusotoError::service(
+                        &res,
                         SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest(
                             parsed_error.message,
                         ),
                     ),
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(
                                 parsed_error.message,
                             ),
@@ -5958,28 +6033,32 @@ impl SetLoadBalancerPoliciesOfListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerPoliciesOfListenerError::ListenerNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(
                                 parsed_error.message,
                             ),
@@ -6242,6 +6321,7 @@ impl ElbClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6257,6 +6337,7 @@ impl ElbClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
         ElbClient { client, region }
     }
@@ -6270,6 +6351,9 @@ impl Elb for ElbClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddTags");
         let mut params = params;
         AddTagsInputSerializer::serialize(&mut params, "", &input);
@@ -6295,6 +6379,9 @@ impl Elb for ElbClient {
         RusotoError<ApplySecurityGroupsToLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ApplySecurityGroupsToLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ApplySecurityGroupsToLoadBalancer");
         let mut params = params;
         ApplySecurityGroupsToLoadBalancerInputSerializer::serialize(&mut params, "", &input);
@@ -6332,6 +6419,9 @@ impl Elb for ElbClient {
     ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("AttachLoadBalancerToSubnets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachLoadBalancerToSubnets");
         let mut params = params;
         AttachLoadBalancerToSubnetsInputSerializer::serialize(&mut params, "", &input);
@@ -6365,6 +6455,9 @@ impl Elb for ElbClient {
         input: ConfigureHealthCheckInput,
     ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ConfigureHealthCheck");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ConfigureHealthCheck");
         let mut params = params;
         ConfigureHealthCheckInputSerializer::serialize(&mut params, "", &input);
@@ -6401,6 +6494,9 @@ impl Elb for ElbClient {
         RusotoError<CreateAppCookieStickinessPolicyError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateAppCookieStickinessPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateAppCookieStickinessPolicy");
         let mut params = params;
         CreateAppCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6426,6 +6522,9 @@ impl Elb for ElbClient {
         RusotoError<CreateLBCookieStickinessPolicyError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateLBCookieStickinessPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLBCookieStickinessPolicy");
         let mut params = params;
         CreateLBCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6448,6 +6547,9 @@ impl Elb for ElbClient {
         input: CreateAccessPointInput,
     ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLoadBalancer");
         let mut params = params;
         CreateAccessPointInputSerializer::serialize(&mut params, "", &input);
@@ -6482,6 +6584,9 @@ impl Elb for ElbClient {
     ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateLoadBalancerListeners");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLoadBalancerListeners");
         let mut params = params;
         CreateLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
@@ -6504,6 +6609,9 @@ impl Elb for ElbClient {
         input: CreateLoadBalancerPolicyInput,
     ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateLoadBalancerPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLoadBalancerPolicy");
         let mut params = params;
         CreateLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6526,6 +6634,9 @@ impl Elb for ElbClient {
         input: DeleteAccessPointInput,
     ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLoadBalancer");
         let mut params = params;
         DeleteAccessPointInputSerializer::serialize(&mut params, "", &input);
@@ -6549,6 +6660,9 @@ impl Elb for ElbClient {
     ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteLoadBalancerListeners");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLoadBalancerListeners");
         let mut params = params;
         DeleteLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
@@ -6571,6 +6685,9 @@ impl Elb for ElbClient {
         input: DeleteLoadBalancerPolicyInput,
     ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteLoadBalancerPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLoadBalancerPolicy");
         let mut params = params;
         DeleteLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6594,6 +6711,9 @@ impl Elb for ElbClient {
     ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeregisterInstancesFromLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterInstancesFromLoadBalancer");
         let mut params = params;
         DeregisterEndPointsInputSerializer::serialize(&mut params, "", &input);
@@ -6630,6 +6750,9 @@ impl Elb for ElbClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeAccountLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountLimits");
         let mut params = params;
         DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
@@ -6663,6 +6786,9 @@ impl Elb for ElbClient {
         input: DescribeEndPointStateInput,
     ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeInstanceHealth");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstanceHealth");
         let mut params = params;
         DescribeEndPointStateInputSerializer::serialize(&mut params, "", &input);
@@ -6699,6 +6825,9 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancerAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancerAttributes");
         let mut params = params;
         DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -6733,6 +6862,9 @@ impl Elb for ElbClient {
     ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancerPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancerPolicies");
         let mut params = params;
         DescribeLoadBalancerPoliciesInputSerializer::serialize(&mut params, "", &input);
@@ -6769,6 +6901,9 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerPolicyTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancerPolicyTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancerPolicyTypes");
         let mut params = params;
         DescribeLoadBalancerPolicyTypesInputSerializer::serialize(&mut params, "", &input);
@@ -6802,6 +6937,9 @@ impl Elb for ElbClient {
         input: DescribeAccessPointsInput,
     ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancers");
         let mut params = params;
         DescribeAccessPointsInputSerializer::serialize(&mut params, "", &input);
@@ -6835,6 +6973,9 @@ impl Elb for ElbClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsInputSerializer::serialize(&mut params, "", &input);
@@ -6866,6 +7007,9 @@ impl Elb for ElbClient {
     ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DetachLoadBalancerFromSubnets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachLoadBalancerFromSubnets");
         let mut params = params;
         DetachLoadBalancerFromSubnetsInputSerializer::serialize(&mut params, "", &input);
@@ -6902,6 +7046,9 @@ impl Elb for ElbClient {
         RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DisableAvailabilityZonesForLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableAvailabilityZonesForLoadBalancer");
         let mut params = params;
         RemoveAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
@@ -6939,6 +7086,9 @@ impl Elb for ElbClient {
     ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("EnableAvailabilityZonesForLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableAvailabilityZonesForLoadBalancer");
         let mut params = params;
         AddAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
@@ -6976,6 +7126,9 @@ impl Elb for ElbClient {
     ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ModifyLoadBalancerAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyLoadBalancerAttributes");
         let mut params = params;
         ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -7009,6 +7162,9 @@ impl Elb for ElbClient {
         input: RegisterEndPointsInput,
     ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("RegisterInstancesWithLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterInstancesWithLoadBalancer");
         let mut params = params;
         RegisterEndPointsInputSerializer::serialize(&mut params, "", &input);
@@ -7045,6 +7201,9 @@ impl Elb for ElbClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("RemoveTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveTags");
         let mut params = params;
         RemoveTagsInputSerializer::serialize(&mut params, "", &input);
@@ -7070,6 +7229,9 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerListenerSSLCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetLoadBalancerListenerSSLCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetLoadBalancerListenerSSLCertificate");
         let mut params = params;
         SetLoadBalancerListenerSSLCertificateInputSerializer::serialize(&mut params, "", &input);
@@ -7098,6 +7260,9 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetLoadBalancerPoliciesForBackendServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetLoadBalancerPoliciesForBackendServer");
         let mut params = params;
         SetLoadBalancerPoliciesForBackendServerInputSerializer::serialize(&mut params, "", &input);
@@ -7126,6 +7291,9 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerPoliciesOfListenerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetLoadBalancerPoliciesOfListener");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetLoadBalancerPoliciesOfListener");
         let mut params = params;
         SetLoadBalancerPoliciesOfListenerInputSerializer::serialize(&mut params, "", &input);
@@ -7146,6 +7314,415 @@ impl Elb for ElbClient {
     }
 }
 
+/// A blocking client for the Elastic Load Balancing API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ElbClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElbBlockingClient {
+    client: ElbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElbBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElbBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ElbBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElbBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client: ElbClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds the specified tags to the specified load balancer. Each load balancer can have a maximum of 10 tags.</p> <p>Each tag consists of a key and an optional value. If a tag with the same key is already associated with the load balancer, <code>AddTags</code> updates its value.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/add-remove-tags.html">Tag Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Associates one or more security groups with your load balancer in a virtual private cloud (VPC). The specified security groups override the previously associated security groups.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-security-groups.html#elb-vpc-security-groups">Security Groups for Load Balancers in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::apply_security_groups_to_load_balancer`.
+    pub fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> Result<
+        ApplySecurityGroupsToLoadBalancerOutput,
+        RusotoError<ApplySecurityGroupsToLoadBalancerError>,
+    > {
+        self.runtime
+            .block_on(self.client.apply_security_groups_to_load_balancer(input))
+    }
+
+    /// <p>Adds one or more subnets to the set of configured subnets for the specified load balancer.</p> <p>The load balancer evenly distributes requests across all registered subnets. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-manage-subnets.html">Add or Remove Subnets for Your Load Balancer in a VPC</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::attach_load_balancer_to_subnets`.
+    pub fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
+    {
+        self.runtime
+            .block_on(self.client.attach_load_balancer_to_subnets(input))
+    }
+
+    /// <p>Specifies the health check settings to use when evaluating the health state of your EC2 instances.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-healthchecks.html">Configure Health Checks for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::configure_health_check`.
+    pub fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.configure_health_check(input))
+    }
+
+    /// <p>Generates a stickiness policy with sticky session lifetimes that follow that of an application-generated cookie. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>This policy is similar to the policy created by <a>CreateLBCookieStickinessPolicy</a>, except that the lifetime of the special Elastic Load Balancing cookie, <code>AWSELB</code>, follows the lifetime of the application-generated cookie specified in the policy configuration. The load balancer only inserts a new stickiness cookie when the application response includes a new application cookie.</p> <p>If the application cookie is explicitly removed or expires, the session stops being sticky until a new application cookie is issued.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_app_cookie_stickiness_policy`.
+    pub fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> Result<
+        CreateAppCookieStickinessPolicyOutput,
+        RusotoError<CreateAppCookieStickinessPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_app_cookie_stickiness_policy(input))
+    }
+
+    /// <p>Generates a stickiness policy with sticky session lifetimes controlled by the lifetime of the browser (user-agent) or a specified expiration period. This policy can be associated only with HTTP/HTTPS listeners.</p> <p>When a load balancer implements this policy, the load balancer uses a special cookie to track the instance for each request. When the load balancer receives a request, it first checks to see if this cookie is present in the request. If so, the load balancer sends the request to the application server specified in the cookie. If not, the load balancer sends the request to a server that is chosen based on the existing load-balancing algorithm.</p> <p>A cookie is inserted into the response for binding subsequent requests from the same user to that server. The validity of the cookie is based on the cookie expiration time, which is specified in the policy configuration.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_lb_cookie_stickiness_policy`.
+    pub fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> Result<
+        CreateLBCookieStickinessPolicyOutput,
+        RusotoError<CreateLBCookieStickinessPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_lb_cookie_stickiness_policy(input))
+    }
+
+    /// <p>Creates a Classic Load Balancer.</p> <p>You can add listeners, security groups, subnets, and tags when you create your load balancer, or you can add them later using <a>CreateLoadBalancerListeners</a>, <a>ApplySecurityGroupsToLoadBalancer</a>, <a>AttachLoadBalancerToSubnets</a>, and <a>AddTags</a>.</p> <p>To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>You can create up to 20 load balancers per region per account. You can request an increase for the number of load balancers for your account. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_load_balancer`.
+    pub fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer(input))
+    }
+
+    /// <p>Creates one or more listeners for the specified load balancer. If a listener with the specified port does not already exist, it is created; otherwise, the properties of the new listener must match the properties of the existing listener.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-listener-config.html">Listeners for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_load_balancer_listeners`.
+    pub fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
+    {
+        self.runtime
+            .block_on(self.client.create_load_balancer_listeners(input))
+    }
+
+    /// <p>Creates a policy with the specified attributes for the specified load balancer.</p> <p>Policies are settings that are saved for your load balancer and that can be applied to the listener or the application server, depending on the policy type.</p>
+    ///
+    /// See `Elb::create_load_balancer_policy`.
+    pub fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer_policy(input))
+    }
+
+    /// <p>Deletes the specified load balancer.</p> <p>If you are attempting to recreate a load balancer, you must reconfigure all settings. The DNS name associated with a deleted load balancer are no longer usable. The name and associated DNS record of the deleted load balancer no longer exist and traffic sent to any of its IP addresses is no longer delivered to your instances.</p> <p>If the load balancer does not exist or has already been deleted, the call to <code>DeleteLoadBalancer</code> still succeeds.</p>
+    ///
+    /// See `Elb::delete_load_balancer`.
+    pub fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer(input))
+    }
+
+    /// <p>Deletes the specified listeners from the specified load balancer.</p>
+    ///
+    /// See `Elb::delete_load_balancer_listeners`.
+    pub fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_load_balancer_listeners(input))
+    }
+
+    /// <p>Deletes the specified policy from the specified load balancer. This policy must not be enabled for any listeners.</p>
+    ///
+    /// See `Elb::delete_load_balancer_policy`.
+    pub fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer_policy(input))
+    }
+
+    /// <p>Deregisters the specified instances from the specified load balancer. After the instance is deregistered, it no longer receives traffic from the load balancer.</p> <p>You can use <a>DescribeLoadBalancers</a> to verify that the instance is deregistered from the load balancer.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::deregister_instances_from_load_balancer`.
+    pub fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
+    {
+        self.runtime
+            .block_on(self.client.deregister_instances_from_load_balancer(input))
+    }
+
+    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-limits.html">Limits for Your Classic Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::describe_account_limits`.
+    pub fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_limits(input))
+    }
+
+    /// <p>Describes the state of the specified instances with respect to the specified load balancer. If no instances are specified, the call describes the state of all instances that are currently registered with the load balancer. If instances are specified, their state is returned even if they are no longer registered with the load balancer. The state of terminated instances is not returned.</p>
+    ///
+    /// See `Elb::describe_instance_health`.
+    pub fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_health(input))
+    }
+
+    /// <p>Describes the attributes for the specified load balancer.</p>
+    ///
+    /// See `Elb::describe_load_balancer_attributes`.
+    pub fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        DescribeLoadBalancerAttributesOutput,
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_attributes(input))
+    }
+
+    /// <p>Describes the specified policies.</p> <p>If you specify a load balancer name, the action returns the descriptions of all policies created for the load balancer. If you specify a policy name associated with your load balancer, the action returns the description of that policy. If you don't specify a load balancer name, the action returns descriptions of the specified sample policies, or descriptions of all sample policies. The names of the sample policies have the <code>ELBSample-</code> prefix.</p>
+    ///
+    /// See `Elb::describe_load_balancer_policies`.
+    pub fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_policies(input))
+    }
+
+    /// <p>Describes the specified load balancer policy types or all load balancer policy types.</p> <p>The description of each type indicates how it can be used. For example, some policies can be used only with layer 7 listeners, some policies can be used only with layer 4 listeners, and some policies can be used only with your EC2 instances.</p> <p>You can use <a>CreateLoadBalancerPolicy</a> to create a policy configuration for any of these policy types. Then, depending on the policy type, use either <a>SetLoadBalancerPoliciesOfListener</a> or <a>SetLoadBalancerPoliciesForBackendServer</a> to set the policy.</p>
+    ///
+    /// See `Elb::describe_load_balancer_policy_types`.
+    pub fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> Result<
+        DescribeLoadBalancerPolicyTypesOutput,
+        RusotoError<DescribeLoadBalancerPolicyTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_policy_types(input))
+    }
+
+    /// <p>Describes the specified the load balancers. If no load balancers are specified, the call describes all of your load balancers.</p>
+    ///
+    /// See `Elb::describe_load_balancers`.
+    pub fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.describe_load_balancers(input))
+    }
+
+    /// <p>Describes the tags associated with the specified load balancers.</p>
+    ///
+    /// See `Elb::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Removes the specified subnets from the set of configured subnets for the load balancer.</p> <p>After a subnet is removed, all EC2 instances registered with the load balancer in the removed subnet go into the <code>OutOfService</code> state. Then, the load balancer balances the traffic among the remaining routable subnets.</p>
+    ///
+    /// See `Elb::detach_load_balancer_from_subnets`.
+    pub fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
+    {
+        self.runtime
+            .block_on(self.client.detach_load_balancer_from_subnets(input))
+    }
+
+    /// <p>Removes the specified Availability Zones from the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>DetachLoadBalancerFromSubnets</a>.</p> <p>There must be at least one Availability Zone registered with a load balancer at all times. After an Availability Zone is removed, all instances registered with the load balancer that are in the removed Availability Zone go into the <code>OutOfService</code> state. Then, the load balancer attempts to equally balance the traffic among its remaining Availability Zones.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::disable_availability_zones_for_load_balancer`.
+    pub fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> Result<
+        RemoveAvailabilityZonesOutput,
+        RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disable_availability_zones_for_load_balancer(input),
+        )
+    }
+
+    /// <p>Adds the specified Availability Zones to the set of Availability Zones for the specified load balancer in EC2-Classic or a default VPC.</p> <p>For load balancers in a non-default VPC, use <a>AttachLoadBalancerToSubnets</a>.</p> <p>The load balancer evenly distributes requests across all its registered Availability Zones that contain instances. For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-az.html">Add or Remove Availability Zones</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::enable_availability_zones_for_load_balancer`.
+    pub fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
+    {
+        self.runtime.block_on(
+            self.client
+                .enable_availability_zones_for_load_balancer(input),
+        )
+    }
+
+    /// <p><p>Modifies the attributes of the specified load balancer.</p> <p>You can modify the load balancer attributes, such as <code>AccessLogs</code>, <code>ConnectionDraining</code>, and <code>CrossZoneLoadBalancing</code> by either enabling or disabling them. Or, you can modify the load balancer attribute <code>ConnectionSettings</code> by specifying an idle connection timeout value for your load balancer.</p> <p>For more information, see the following in the <i>Classic Load Balancers Guide</i>:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-disable-crosszone-lb.html">Cross-Zone Load Balancing</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-conn-drain.html">Connection Draining</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/access-log-collection.html">Access Logs</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/config-idle-timeout.html">Idle Connection Timeout</a> </p> </li> </ul></p>
+    ///
+    /// See `Elb::modify_load_balancer_attributes`.
+    pub fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_load_balancer_attributes(input))
+    }
+
+    /// <p>Adds the specified instances to the specified load balancer.</p> <p>The instance must be a running instance in the same network as the load balancer (EC2-Classic or the same VPC). If you have EC2-Classic instances and a load balancer in a VPC with ClassicLink enabled, you can link the EC2-Classic instances to that VPC and then register the linked EC2-Classic instances with the load balancer in the VPC.</p> <p>Note that <code>RegisterInstanceWithLoadBalancer</code> completes when the request has been registered. Instance registration takes a little time to complete. To check the state of the registered instances, use <a>DescribeLoadBalancers</a> or <a>DescribeInstanceHealth</a>.</p> <p>After the instance is registered, it starts receiving traffic and requests from the load balancer. Any instance that is not in one of the Availability Zones registered for the load balancer is moved to the <code>OutOfService</code> state. If an Availability Zone is added to the load balancer later, any instances registered with the load balancer move to the <code>InService</code> state.</p> <p>To deregister instances from a load balancer, use <a>DeregisterInstancesFromLoadBalancer</a>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-deregister-register-instances.html">Register or De-Register EC2 Instances</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::register_instances_with_load_balancer`.
+    pub fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.register_instances_with_load_balancer(input))
+    }
+
+    /// <p>Removes one or more tags from the specified load balancer.</p>
+    ///
+    /// See `Elb::remove_tags`.
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// <p>Sets the certificate that terminates the specified listener's SSL connections. The specified certificate replaces any prior certificate that was used on the same load balancer and port.</p> <p>For more information about updating your SSL certificate, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-update-ssl-cert.html">Replace the SSL Certificate for Your Load Balancer</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::set_load_balancer_listener_ssl_certificate`.
+    pub fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> Result<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        RusotoError<SetLoadBalancerListenerSSLCertificateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .set_load_balancer_listener_ssl_certificate(input),
+        )
+    }
+
+    /// <p>Replaces the set of policies associated with the specified port on which the EC2 instance is listening with a new set of policies. At this time, only the back-end server authentication policy type can be applied to the instance ports; this policy type is composed of multiple public key policies.</p> <p>Each time you use <code>SetLoadBalancerPoliciesForBackendServer</code> to enable the policies, use the <code>PolicyNames</code> parameter to list the policies that you want to enable.</p> <p>You can use <a>DescribeLoadBalancers</a> or <a>DescribeLoadBalancerPolicies</a> to verify that the policy is associated with the EC2 instance.</p> <p>For more information about enabling back-end instance authentication, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-create-https-ssl-load-balancer.html#configure_backendauth_clt">Configure Back-end Instance Authentication</a> in the <i>Classic Load Balancers Guide</i>. For more information about Proxy Protocol, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/enable-proxy-protocol.html">Configure Proxy Protocol Support</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::set_load_balancer_policies_for_backend_server`.
+    pub fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> Result<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .set_load_balancer_policies_for_backend_server(input),
+        )
+    }
+
+    /// <p>Replaces the current set of policies for the specified load balancer port with the specified set of policies.</p> <p>To enable back-end server authentication, use <a>SetLoadBalancerPoliciesForBackendServer</a>.</p> <p>For more information about setting policies, see <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/ssl-config-update.html">Update the SSL Negotiation Configuration</a>, <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-duration">Duration-Based Session Stickiness</a>, and <a href="http://docs.aws.amazon.com/elasticloadbalancing/latest/classic/elb-sticky-sessions.html#enable-sticky-sessions-application">Application-Controlled Session Stickiness</a> in the <i>Classic Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::set_load_balancer_policies_of_listener`.
+    pub fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> Result<
+        SetLoadBalancerPoliciesOfListenerOutput,
+        RusotoError<SetLoadBalancerPoliciesOfListenerError>,
+    > {
+        self.runtime
+            .block_on(self.client.set_load_balancer_policies_of_listener(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -6318,17 +6318,20 @@ impl AddListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddListenerCertificatesError::CertificateNotFound(parsed_error.message),
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddListenerCertificatesError::ListenerNotFound(parsed_error.message),
                         )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddListenerCertificatesError::TooManyCertificates(parsed_error.message),
                         )
                     }
@@ -6380,24 +6383,28 @@ impl AddTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(AddTagsError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::DuplicateTagKeys(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(AddTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(AddTagsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(AddTagsError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsError::TooManyTags(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6474,93 +6481,110 @@ impl CreateListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ALPNPolicyNotFound" => {
-                        return RusotoError::Service(CreateListenerError::ALPNPolicyNotSupported(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::ALPNPolicyNotSupported(parsed_error.message),
+                        )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(CreateListenerError::CertificateNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::CertificateNotFound(parsed_error.message),
+                        )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(CreateListenerError::DuplicateListener(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::DuplicateListener(parsed_error.message),
+                        )
                     }
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(CreateListenerError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::IncompatibleProtocols(parsed_error.message),
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateListenerError::InvalidConfigurationRequest(parsed_error.message),
                         )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateListenerError::InvalidLoadBalancerAction(parsed_error.message),
                         )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(CreateListenerError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(CreateListenerError::SSLPolicyNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::SSLPolicyNotFound(parsed_error.message),
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateListenerError::TargetGroupAssociationLimit(parsed_error.message),
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(CreateListenerError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(CreateListenerError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::TooManyActions(parsed_error.message),
+                        )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(CreateListenerError::TooManyCertificates(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::TooManyCertificates(parsed_error.message),
+                        )
                     }
                     "TooManyListeners" => {
-                        return RusotoError::Service(CreateListenerError::TooManyListeners(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::TooManyListeners(parsed_error.message),
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateListenerError::TooManyRegistrationsForTargetId(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(CreateListenerError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::TooManyTargets(parsed_error.message),
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateListenerError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateListenerError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6646,75 +6670,88 @@ impl CreateLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::AllocationIdNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::AllocationIdNotFound(parsed_error.message),
+                        )
                     }
                     "AvailabilityZoneNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::AvailabilityZoneNotSupported(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DuplicateLoadBalancerName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::DuplicateLoadBalancerName(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::DuplicateTagKeys(parsed_error.message),
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidScheme" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::InvalidScheme(parsed_error.message),
+                        )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::InvalidSecurityGroup(parsed_error.message),
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(CreateLoadBalancerError::ResourceInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::ResourceInUse(parsed_error.message),
+                        )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::SubnetNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyLoadBalancers" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyLoadBalancers(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::TooManyLoadBalancers(parsed_error.message),
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoadBalancerError::TooManyTags(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6798,76 +6835,90 @@ impl CreateRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(CreateRuleError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::IncompatibleProtocols(parsed_error.message),
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(CreateRuleError::InvalidConfigurationRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::InvalidConfigurationRequest(parsed_error.message),
+                        )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(CreateRuleError::InvalidLoadBalancerAction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::InvalidLoadBalancerAction(parsed_error.message),
+                        )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(CreateRuleError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     "PriorityInUse" => {
-                        return RusotoError::Service(CreateRuleError::PriorityInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::PriorityInUse(parsed_error.message),
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(CreateRuleError::TargetGroupAssociationLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::TargetGroupAssociationLimit(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(CreateRuleError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(CreateRuleError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::TooManyActions(parsed_error.message),
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
                         )
                     }
                     "TooManyRules" => {
-                        return RusotoError::Service(CreateRuleError::TooManyRules(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::TooManyRules(parsed_error.message),
+                        )
                     }
                     "TooManyTargetGroups" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTargetGroups(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::TooManyTargetGroups(parsed_error.message),
+                        )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::TooManyTargets(parsed_error.message),
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateRuleError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRuleError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6928,21 +6979,24 @@ impl CreateTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateTargetGroupName" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTargetGroupError::DuplicateTargetGroupName(parsed_error.message),
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTargetGroupError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTargetGroups" => {
-                        return RusotoError::Service(CreateTargetGroupError::TooManyTargetGroups(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTargetGroupError::TooManyTargetGroups(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -6988,9 +7042,10 @@ impl DeleteListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DeleteListenerError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteListenerError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7036,19 +7091,22 @@ impl DeleteLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DeleteLoadBalancerError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteLoadBalancerError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLoadBalancerError::OperationNotPermitted(parsed_error.message),
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteLoadBalancerError::ResourceInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteLoadBalancerError::ResourceInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7094,14 +7152,16 @@ impl DeleteRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(DeleteRuleError::OperationNotPermitted(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRuleError::OperationNotPermitted(parsed_error.message),
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DeleteRuleError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRuleError::RuleNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7144,9 +7204,10 @@ impl DeleteTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteTargetGroupError::ResourceInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTargetGroupError::ResourceInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7190,14 +7251,16 @@ impl DeregisterTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
-                        return RusotoError::Service(DeregisterTargetsError::InvalidTarget(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeregisterTargetsError::InvalidTarget(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(DeregisterTargetsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeregisterTargetsError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7276,7 +7339,8 @@ impl DescribeListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeListenerCertificatesError::ListenerNotFound(
                                 parsed_error.message,
                             ),
@@ -7328,19 +7392,22 @@ impl DescribeListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeListenersError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeListenersError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeListenersError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeListenersError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(DescribeListenersError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeListenersError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7386,7 +7453,8 @@ impl DescribeLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancerAttributesError::LoadBalancerNotFound(
                                 parsed_error.message,
                             ),
@@ -7434,7 +7502,8 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoadBalancersError::LoadBalancerNotFound(parsed_error.message),
                         )
                     }
@@ -7482,19 +7551,22 @@ impl DescribeRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeRulesError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeRulesError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DescribeRulesError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeRulesError::RuleNotFound(parsed_error.message),
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(DescribeRulesError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeRulesError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7538,9 +7610,10 @@ impl DescribeSSLPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(DescribeSSLPoliciesError::SSLPolicyNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeSSLPoliciesError::SSLPolicyNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7588,24 +7661,28 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::RuleNotFound(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7652,7 +7729,8 @@ impl DescribeTargetGroupAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTargetGroupAttributesError::TargetGroupNotFound(
                                 parsed_error.message,
                             ),
@@ -7702,12 +7780,14 @@ impl DescribeTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTargetGroupsError::LoadBalancerNotFound(parsed_error.message),
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTargetGroupsError::TargetGroupNotFound(parsed_error.message),
                         )
                     }
@@ -7756,17 +7836,20 @@ impl DescribeTargetHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthUnavailable" => {
-                        return RusotoError::Service(DescribeTargetHealthError::HealthUnavailable(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTargetHealthError::HealthUnavailable(parsed_error.message),
+                        )
                     }
                     "InvalidTarget" => {
-                        return RusotoError::Service(DescribeTargetHealthError::InvalidTarget(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTargetHealthError::InvalidTarget(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTargetHealthError::TargetGroupNotFound(parsed_error.message),
                         )
                     }
@@ -7844,93 +7927,110 @@ impl ModifyListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ALPNPolicyNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::ALPNPolicyNotSupported(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::ALPNPolicyNotSupported(parsed_error.message),
+                        )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::CertificateNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::CertificateNotFound(parsed_error.message),
+                        )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(ModifyListenerError::DuplicateListener(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::DuplicateListener(parsed_error.message),
+                        )
                     }
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(ModifyListenerError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::IncompatibleProtocols(parsed_error.message),
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyListenerError::InvalidConfigurationRequest(parsed_error.message),
                         )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyListenerError::InvalidLoadBalancerAction(parsed_error.message),
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::SSLPolicyNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::SSLPolicyNotFound(parsed_error.message),
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyListenerError::TargetGroupAssociationLimit(parsed_error.message),
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::TooManyActions(parsed_error.message),
+                        )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyCertificates(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::TooManyCertificates(parsed_error.message),
+                        )
                     }
                     "TooManyListeners" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyListeners(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::TooManyListeners(parsed_error.message),
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyListenerError::TooManyRegistrationsForTargetId(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::TooManyTargets(parsed_error.message),
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(ModifyListenerError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyListenerError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7996,14 +8096,16 @@ impl ModifyLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyLoadBalancerAttributesError::LoadBalancerNotFound(
                                 parsed_error.message,
                             ),
@@ -8074,61 +8176,72 @@ impl ModifyRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(ModifyRuleError::IncompatibleProtocols(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::IncompatibleProtocols(parsed_error.message),
+                        )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(ModifyRuleError::InvalidLoadBalancerAction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::InvalidLoadBalancerAction(parsed_error.message),
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(ModifyRuleError::OperationNotPermitted(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::OperationNotPermitted(parsed_error.message),
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(ModifyRuleError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::RuleNotFound(parsed_error.message),
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(ModifyRuleError::TargetGroupAssociationLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::TargetGroupAssociationLimit(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyRuleError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(ModifyRuleError::TooManyActions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::TooManyActions(parsed_error.message),
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(ModifyRuleError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::TooManyTargets(parsed_error.message),
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(ModifyRuleError::UnsupportedProtocol(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyRuleError::UnsupportedProtocol(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8184,16 +8297,18 @@ impl ModifyTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyTargetGroupError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyTargetGroupError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyTargetGroupError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8242,14 +8357,16 @@ impl ModifyTargetGroupAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyTargetGroupAttributesError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyTargetGroupAttributesError::TargetGroupNotFound(
                                 parsed_error.message,
                             ),
@@ -8306,26 +8423,30 @@ impl RegisterTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
-                        return RusotoError::Service(RegisterTargetsError::InvalidTarget(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RegisterTargetsError::InvalidTarget(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(RegisterTargetsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RegisterTargetsError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterTargetsError::TooManyRegistrationsForTargetId(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(RegisterTargetsError::TooManyTargets(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RegisterTargetsError::TooManyTargets(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8376,12 +8497,14 @@ impl RemoveListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveListenerCertificatesError::ListenerNotFound(parsed_error.message),
                         )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveListenerCertificatesError::OperationNotPermitted(
                                 parsed_error.message,
                             ),
@@ -8438,29 +8561,34 @@ impl RemoveTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::ListenerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsError::ListenerNotFound(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsError::RuleNotFound(parsed_error.message),
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::TargetGroupNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsError::TargetGroupNotFound(parsed_error.message),
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(RemoveTagsError::TooManyTags(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveTagsError::TooManyTags(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8510,21 +8638,24 @@ impl SetIpAddressTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetIpAddressTypeError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(SetIpAddressTypeError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetIpAddressTypeError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetIpAddressTypeError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetIpAddressTypeError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8572,19 +8703,22 @@ impl SetRulePrioritiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(SetRulePrioritiesError::OperationNotPermitted(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetRulePrioritiesError::OperationNotPermitted(parsed_error.message),
+                        )
                     }
                     "PriorityInUse" => {
-                        return RusotoError::Service(SetRulePrioritiesError::PriorityInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetRulePrioritiesError::PriorityInUse(parsed_error.message),
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(SetRulePrioritiesError::RuleNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetRulePrioritiesError::RuleNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8632,21 +8766,24 @@ impl SetSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetSecurityGroupsError::InvalidConfigurationRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(SetSecurityGroupsError::InvalidSecurityGroup(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSecurityGroupsError::InvalidSecurityGroup(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetSecurityGroupsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSecurityGroupsError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8702,34 +8839,40 @@ impl SetSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::AllocationIdNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubnetsError::AllocationIdNotFound(parsed_error.message),
+                        )
                     }
                     "AvailabilityZoneNotSupported" => {
-                        return RusotoError::Service(SetSubnetsError::AvailabilityZoneNotSupported(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubnetsError::AvailabilityZoneNotSupported(parsed_error.message),
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(SetSubnetsError::InvalidConfigurationRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubnetsError::InvalidConfigurationRequest(parsed_error.message),
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(SetSubnetsError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubnetsError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::LoadBalancerNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubnetsError::LoadBalancerNotFound(parsed_error.message),
+                        )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::SubnetNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubnetsError::SubnetNotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8988,6 +9131,7 @@ impl ElbClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -9003,6 +9147,7 @@ impl ElbClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
         ElbClient { client, region }
     }
@@ -9016,6 +9161,9 @@ impl Elb for ElbClient {
         input: AddListenerCertificatesInput,
     ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("AddListenerCertificates");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddListenerCertificates");
         let mut params = params;
         AddListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
@@ -9049,6 +9197,9 @@ impl Elb for ElbClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddTags");
         let mut params = params;
         AddTagsInputSerializer::serialize(&mut params, "", &input);
@@ -9071,6 +9222,9 @@ impl Elb for ElbClient {
         input: CreateListenerInput,
     ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateListener");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateListener");
         let mut params = params;
         CreateListenerInputSerializer::serialize(&mut params, "", &input);
@@ -9102,6 +9256,9 @@ impl Elb for ElbClient {
         input: CreateLoadBalancerInput,
     ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLoadBalancer");
         let mut params = params;
         CreateLoadBalancerInputSerializer::serialize(&mut params, "", &input);
@@ -9135,6 +9292,9 @@ impl Elb for ElbClient {
         input: CreateRuleInput,
     ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateRule");
         let mut params = params;
         CreateRuleInputSerializer::serialize(&mut params, "", &input);
@@ -9165,6 +9325,9 @@ impl Elb for ElbClient {
         input: CreateTargetGroupInput,
     ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("CreateTargetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTargetGroup");
         let mut params = params;
         CreateTargetGroupInputSerializer::serialize(&mut params, "", &input);
@@ -9196,6 +9359,9 @@ impl Elb for ElbClient {
         input: DeleteListenerInput,
     ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteListener");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteListener");
         let mut params = params;
         DeleteListenerInputSerializer::serialize(&mut params, "", &input);
@@ -9218,6 +9384,9 @@ impl Elb for ElbClient {
         input: DeleteLoadBalancerInput,
     ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLoadBalancer");
         let mut params = params;
         DeleteLoadBalancerInputSerializer::serialize(&mut params, "", &input);
@@ -9240,6 +9409,9 @@ impl Elb for ElbClient {
         input: DeleteRuleInput,
     ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteRule");
         let mut params = params;
         DeleteRuleInputSerializer::serialize(&mut params, "", &input);
@@ -9262,6 +9434,9 @@ impl Elb for ElbClient {
         input: DeleteTargetGroupInput,
     ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeleteTargetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTargetGroup");
         let mut params = params;
         DeleteTargetGroupInputSerializer::serialize(&mut params, "", &input);
@@ -9284,6 +9459,9 @@ impl Elb for ElbClient {
         input: DeregisterTargetsInput,
     ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DeregisterTargets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterTargets");
         let mut params = params;
         DeregisterTargetsInputSerializer::serialize(&mut params, "", &input);
@@ -9306,6 +9484,9 @@ impl Elb for ElbClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeAccountLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountLimits");
         let mut params = params;
         DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
@@ -9340,6 +9521,9 @@ impl Elb for ElbClient {
     ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeListenerCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeListenerCertificates");
         let mut params = params;
         DescribeListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
@@ -9373,6 +9557,9 @@ impl Elb for ElbClient {
         input: DescribeListenersInput,
     ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeListeners");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeListeners");
         let mut params = params;
         DescribeListenersInputSerializer::serialize(&mut params, "", &input);
@@ -9407,6 +9594,9 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancerAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancerAttributes");
         let mut params = params;
         DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9440,6 +9630,9 @@ impl Elb for ElbClient {
         input: DescribeLoadBalancersInput,
     ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeLoadBalancers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoadBalancers");
         let mut params = params;
         DescribeLoadBalancersInputSerializer::serialize(&mut params, "", &input);
@@ -9473,6 +9666,9 @@ impl Elb for ElbClient {
         input: DescribeRulesInput,
     ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeRules");
         let mut params = params;
         DescribeRulesInputSerializer::serialize(&mut params, "", &input);
@@ -9504,6 +9700,9 @@ impl Elb for ElbClient {
         input: DescribeSSLPoliciesInput,
     ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeSSLPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSSLPolicies");
         let mut params = params;
         DescribeSSLPoliciesInputSerializer::serialize(&mut params, "", &input);
@@ -9537,6 +9736,9 @@ impl Elb for ElbClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsInputSerializer::serialize(&mut params, "", &input);
@@ -9568,6 +9770,9 @@ impl Elb for ElbClient {
     ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeTargetGroupAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTargetGroupAttributes");
         let mut params = params;
         DescribeTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9601,6 +9806,9 @@ impl Elb for ElbClient {
         input: DescribeTargetGroupsInput,
     ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeTargetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTargetGroups");
         let mut params = params;
         DescribeTargetGroupsInputSerializer::serialize(&mut params, "", &input);
@@ -9634,6 +9842,9 @@ impl Elb for ElbClient {
         input: DescribeTargetHealthInput,
     ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("DescribeTargetHealth");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTargetHealth");
         let mut params = params;
         DescribeTargetHealthInputSerializer::serialize(&mut params, "", &input);
@@ -9667,6 +9878,9 @@ impl Elb for ElbClient {
         input: ModifyListenerInput,
     ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ModifyListener");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyListener");
         let mut params = params;
         ModifyListenerInputSerializer::serialize(&mut params, "", &input);
@@ -9699,6 +9913,9 @@ impl Elb for ElbClient {
     ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ModifyLoadBalancerAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyLoadBalancerAttributes");
         let mut params = params;
         ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9732,6 +9949,9 @@ impl Elb for ElbClient {
         input: ModifyRuleInput,
     ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ModifyRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyRule");
         let mut params = params;
         ModifyRuleInputSerializer::serialize(&mut params, "", &input);
@@ -9762,6 +9982,9 @@ impl Elb for ElbClient {
         input: ModifyTargetGroupInput,
     ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ModifyTargetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyTargetGroup");
         let mut params = params;
         ModifyTargetGroupInputSerializer::serialize(&mut params, "", &input);
@@ -9794,6 +10017,9 @@ impl Elb for ElbClient {
     ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("ModifyTargetGroupAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyTargetGroupAttributes");
         let mut params = params;
         ModifyTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9827,6 +10053,9 @@ impl Elb for ElbClient {
         input: RegisterTargetsInput,
     ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("RegisterTargets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterTargets");
         let mut params = params;
         RegisterTargetsInputSerializer::serialize(&mut params, "", &input);
@@ -9850,6 +10079,9 @@ impl Elb for ElbClient {
     ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("RemoveListenerCertificates");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveListenerCertificates");
         let mut params = params;
         RemoveListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
@@ -9872,6 +10104,9 @@ impl Elb for ElbClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("RemoveTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveTags");
         let mut params = params;
         RemoveTagsInputSerializer::serialize(&mut params, "", &input);
@@ -9894,6 +10129,9 @@ impl Elb for ElbClient {
         input: SetIpAddressTypeInput,
     ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetIpAddressType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetIpAddressType");
         let mut params = params;
         SetIpAddressTypeInputSerializer::serialize(&mut params, "", &input);
@@ -9925,6 +10163,9 @@ impl Elb for ElbClient {
         input: SetRulePrioritiesInput,
     ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetRulePriorities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetRulePriorities");
         let mut params = params;
         SetRulePrioritiesInputSerializer::serialize(&mut params, "", &input);
@@ -9956,6 +10197,9 @@ impl Elb for ElbClient {
         input: SetSecurityGroupsInput,
     ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetSecurityGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetSecurityGroups");
         let mut params = params;
         SetSecurityGroupsInputSerializer::serialize(&mut params, "", &input);
@@ -9987,6 +10231,9 @@ impl Elb for ElbClient {
         input: SetSubnetsInput,
     ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation_name("SetSubnets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetSubnets");
         let mut params = params;
         SetSubnetsInputSerializer::serialize(&mut params, "", &input);
@@ -10012,6 +10259,424 @@ impl Elb for ElbClient {
     }
 }
 
+/// A blocking client for the Elastic Load Balancing v2 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ElbClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElbBlockingClient {
+    client: ElbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElbBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElbBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ElbBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElbBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client: ElbClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds the specified SSL server certificate to the certificate list for the specified HTTPS or TLS listener.</p> <p>If the certificate in already in the certificate list, the call is successful but the certificate is not added again.</p> <p>To get the certificate list for a listener, use <a>DescribeListenerCertificates</a>. To remove certificates from the certificate list for a listener, use <a>RemoveListenerCertificates</a>. To replace the default certificate for a listener, use <a>ModifyListener</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::add_listener_certificates`.
+    pub fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
+        self.runtime
+            .block_on(self.client.add_listener_certificates(input))
+    }
+
+    /// <p>Adds the specified tags to the specified Elastic Load Balancing resource. You can tag your Application Load Balancers, Network Load Balancers, and your target groups.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, <code>AddTags</code> updates its value.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>. To remove tags from your resources, use <a>RemoveTags</a>.</p>
+    ///
+    /// See `Elb::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Creates a listener for the specified Application Load Balancer or Network Load Balancer.</p> <p>To update a listener, use <a>ModifyListener</a>. When you are finished with a listener, you can delete it using <a>DeleteListener</a>. If you are finished with both the listener and the load balancer, you can delete them both using <a>DeleteLoadBalancer</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple listeners with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html">Listeners for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-listeners.html">Listeners for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_listener`.
+    pub fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
+        self.runtime.block_on(self.client.create_listener(input))
+    }
+
+    /// <p>Creates an Application Load Balancer or a Network Load Balancer.</p> <p>When you create a load balancer, you can specify security groups, public subnets, IP address type, and tags. Otherwise, you could do so later using <a>SetSecurityGroups</a>, <a>SetSubnets</a>, <a>SetIpAddressType</a>, and <a>AddTags</a>.</p> <p>To create listeners for your load balancer, use <a>CreateListener</a>. To describe your current load balancers, see <a>DescribeLoadBalancers</a>. When you are finished with a load balancer, you can delete it using <a>DeleteLoadBalancer</a>.</p> <p>For limit information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancer</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancer</a> in the <i>Network Load Balancers Guide</i>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple load balancers with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html">Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> and <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html">Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_load_balancer`.
+    pub fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer(input))
+    }
+
+    /// <p>Creates a rule for the specified listener. The listener must be associated with an Application Load Balancer.</p> <p>Rules are evaluated in priority order, from the lowest value to the highest value. When the conditions for a rule are met, its actions are performed. If the conditions for no rules are met, the actions for the default rule are performed. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-listeners.html#listener-rules">Listener Rules</a> in the <i>Application Load Balancers Guide</i>.</p> <p>To view your current rules, use <a>DescribeRules</a>. To update a rule, use <a>ModifyRule</a>. To set the priorities of your rules, use <a>SetRulePriorities</a>. To delete a rule, use <a>DeleteRule</a>.</p>
+    ///
+    /// See `Elb::create_rule`.
+    pub fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// <p>Creates a target group.</p> <p>To register targets with the target group, use <a>RegisterTargets</a>. To update the health check settings for the target group, use <a>ModifyTargetGroup</a>. To monitor the health of targets in the target group, use <a>DescribeTargetHealth</a>.</p> <p>To route traffic to the targets in a target group, specify the target group in an action using <a>CreateListener</a> or <a>CreateRule</a>.</p> <p>To delete a target group, use <a>DeleteTargetGroup</a>.</p> <p>This operation is idempotent, which means that it completes at most one time. If you attempt to create multiple target groups with the same settings, each call succeeds.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html">Target Groups for Your Application Load Balancers</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html">Target Groups for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::create_target_group`.
+    pub fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_target_group(input))
+    }
+
+    /// <p>Deletes the specified listener.</p> <p>Alternatively, your listener is deleted when you delete the load balancer to which it is attached, using <a>DeleteLoadBalancer</a>.</p>
+    ///
+    /// See `Elb::delete_listener`.
+    pub fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
+        self.runtime.block_on(self.client.delete_listener(input))
+    }
+
+    /// <p>Deletes the specified Application Load Balancer or Network Load Balancer and its attached listeners.</p> <p>You can't delete a load balancer if deletion protection is enabled. If the load balancer does not exist or has already been deleted, the call succeeds.</p> <p>Deleting a load balancer does not affect its registered targets. For example, your EC2 instances continue to run and are still registered to their target groups. If you no longer need these EC2 instances, you can stop or terminate them.</p>
+    ///
+    /// See `Elb::delete_load_balancer`.
+    pub fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer(input))
+    }
+
+    /// <p>Deletes the specified rule.</p>
+    ///
+    /// See `Elb::delete_rule`.
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// <p>Deletes the specified target group.</p> <p>You can delete a target group if it is not referenced by any actions. Deleting a target group also deletes any associated health checks.</p>
+    ///
+    /// See `Elb::delete_target_group`.
+    pub fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_target_group(input))
+    }
+
+    /// <p>Deregisters the specified targets from the specified target group. After the targets are deregistered, they no longer receive traffic from the load balancer.</p>
+    ///
+    /// See `Elb::deregister_targets`.
+    pub fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
+        self.runtime.block_on(self.client.deregister_targets(input))
+    }
+
+    /// <p>Describes the current Elastic Load Balancing resource limits for your AWS account.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-limits.html">Limits for Your Application Load Balancers</a> in the <i>Application Load Balancer Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-limits.html">Limits for Your Network Load Balancers</a> in the <i>Network Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::describe_account_limits`.
+    pub fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_limits(input))
+    }
+
+    /// <p>Describes the default certificate and the certificate list for the specified HTTPS or TLS listener.</p> <p>If the default certificate is also in the certificate list, it appears twice in the results (once with <code>IsDefault</code> set to true and once with <code>IsDefault</code> set to false).</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#https-listener-certificates">SSL Certificates</a> in the <i>Application Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::describe_listener_certificates`.
+    pub fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_listener_certificates(input))
+    }
+
+    /// <p>Describes the specified listeners or the listeners for the specified Application Load Balancer or Network Load Balancer. You must specify either a load balancer or one or more listeners.</p> <p>For an HTTPS or TLS listener, the output includes the default certificate for the listener. To describe the certificate list for the listener, use <a>DescribeListenerCertificates</a>.</p>
+    ///
+    /// See `Elb::describe_listeners`.
+    pub fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
+        self.runtime.block_on(self.client.describe_listeners(input))
+    }
+
+    /// <p>Describes the attributes for the specified Application Load Balancer or Network Load Balancer.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/application-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/network-load-balancers.html#load-balancer-attributes">Load Balancer Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::describe_load_balancer_attributes`.
+    pub fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        DescribeLoadBalancerAttributesOutput,
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_attributes(input))
+    }
+
+    /// <p>Describes the specified load balancers or all of your load balancers.</p> <p>To describe the listeners for a load balancer, use <a>DescribeListeners</a>. To describe the attributes for a load balancer, use <a>DescribeLoadBalancerAttributes</a>.</p>
+    ///
+    /// See `Elb::describe_load_balancers`.
+    pub fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.describe_load_balancers(input))
+    }
+
+    /// <p>Describes the specified rules or the rules for the specified listener. You must specify either a listener or one or more rules.</p>
+    ///
+    /// See `Elb::describe_rules`.
+    pub fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
+        self.runtime.block_on(self.client.describe_rules(input))
+    }
+
+    /// <p>Describes the specified policies or all policies used for SSL negotiation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::describe_ssl_policies`.
+    pub fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_ssl_policies(input))
+    }
+
+    /// <p>Describes the tags for the specified resources. You can describe the tags for one or more Application Load Balancers, Network Load Balancers, and target groups.</p>
+    ///
+    /// See `Elb::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Describes the attributes for the specified target group.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Application Load Balancers Guide</i> or <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/network/load-balancer-target-groups.html#target-group-attributes">Target Group Attributes</a> in the <i>Network Load Balancers Guide</i>.</p>
+    ///
+    /// See `Elb::describe_target_group_attributes`.
+    pub fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_target_group_attributes(input))
+    }
+
+    /// <p>Describes the specified target groups or all of your target groups. By default, all target groups are described. Alternatively, you can specify one of the following to filter the results: the ARN of the load balancer, the names of one or more target groups, or the ARNs of one or more target groups.</p> <p>To describe the targets for a target group, use <a>DescribeTargetHealth</a>. To describe the attributes of a target group, use <a>DescribeTargetGroupAttributes</a>.</p>
+    ///
+    /// See `Elb::describe_target_groups`.
+    pub fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_target_groups(input))
+    }
+
+    /// <p>Describes the health of the specified targets or all of your targets.</p>
+    ///
+    /// See `Elb::describe_target_health`.
+    pub fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_target_health(input))
+    }
+
+    /// <p>Replaces the specified properties of the specified listener. Any properties that you do not specify remain unchanged.</p> <p>Changing the protocol from HTTPS to HTTP, or from TLS to TCP, removes the security policy and default certificate properties. If you change the protocol from HTTP to HTTPS, or from TCP to TLS, you must add the security policy and default certificate properties.</p> <p>To add an item to a list, remove an item from a list, or update an item in a list, you must provide the entire list. For example, to add an action, specify a list with the current actions plus the new action.</p>
+    ///
+    /// See `Elb::modify_listener`.
+    pub fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
+        self.runtime.block_on(self.client.modify_listener(input))
+    }
+
+    /// <p>Modifies the specified attributes of the specified Application Load Balancer or Network Load Balancer.</p> <p>If any of the specified attributes can't be modified as requested, the call fails. Any existing attributes that you do not modify retain their current values.</p>
+    ///
+    /// See `Elb::modify_load_balancer_attributes`.
+    pub fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_load_balancer_attributes(input))
+    }
+
+    /// <p>Replaces the specified properties of the specified rule. Any properties that you do not specify are unchanged.</p> <p>To add an item to a list, remove an item from a list, or update an item in a list, you must provide the entire list. For example, to add an action, specify a list with the current actions plus the new action.</p> <p>To modify the actions for the default rule, use <a>ModifyListener</a>.</p>
+    ///
+    /// See `Elb::modify_rule`.
+    pub fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
+        self.runtime.block_on(self.client.modify_rule(input))
+    }
+
+    /// <p>Modifies the health checks used when evaluating the health state of the targets in the specified target group.</p> <p>To monitor the health of the targets, use <a>DescribeTargetHealth</a>.</p>
+    ///
+    /// See `Elb::modify_target_group`.
+    pub fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_target_group(input))
+    }
+
+    /// <p>Modifies the specified attributes of the specified target group.</p>
+    ///
+    /// See `Elb::modify_target_group_attributes`.
+    pub fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_target_group_attributes(input))
+    }
+
+    /// <p>Registers the specified targets with the specified target group.</p> <p>If the target is an EC2 instance, it must be in the <code>running</code> state when you register it.</p> <p>By default, the load balancer routes requests to registered targets using the protocol and port for the target group. Alternatively, you can override the port for a target when you register it. You can register each EC2 instance or IP address with the same target group multiple times using different ports.</p> <p>With a Network Load Balancer, you cannot register instances by instance ID if they have the following instance types: C1, CC1, CC2, CG1, CG2, CR1, CS1, G1, G2, HI1, HS1, M1, M2, M3, and T1. You can register instances of these types by IP address.</p> <p>To remove a target from a target group, use <a>DeregisterTargets</a>.</p>
+    ///
+    /// See `Elb::register_targets`.
+    pub fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
+        self.runtime.block_on(self.client.register_targets(input))
+    }
+
+    /// <p>Removes the specified certificate from the certificate list for the specified HTTPS or TLS listener.</p> <p>You can't remove the default certificate for a listener. To replace the default certificate, call <a>ModifyListener</a>.</p> <p>To list the certificates for your listener, use <a>DescribeListenerCertificates</a>.</p>
+    ///
+    /// See `Elb::remove_listener_certificates`.
+    pub fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_listener_certificates(input))
+    }
+
+    /// <p>Removes the specified tags from the specified Elastic Load Balancing resource.</p> <p>To list the current tags for your resources, use <a>DescribeTags</a>.</p>
+    ///
+    /// See `Elb::remove_tags`.
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// <p>Sets the type of IP addresses used by the subnets of the specified Application Load Balancer or Network Load Balancer.</p>
+    ///
+    /// See `Elb::set_ip_address_type`.
+    pub fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
+        self.runtime
+            .block_on(self.client.set_ip_address_type(input))
+    }
+
+    /// <p>Sets the priorities of the specified rules.</p> <p>You can reorder the rules as long as there are no priority conflicts in the new order. Any existing rules that you do not specify retain their current priority.</p>
+    ///
+    /// See `Elb::set_rule_priorities`.
+    pub fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
+        self.runtime
+            .block_on(self.client.set_rule_priorities(input))
+    }
+
+    /// <p>Associates the specified security groups with the specified Application Load Balancer. The specified security groups override the previously associated security groups.</p> <p>You can't specify a security group for a Network Load Balancer.</p>
+    ///
+    /// See `Elb::set_security_groups`.
+    pub fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.set_security_groups(input))
+    }
+
+    /// <p>Enables the Availability Zones for the specified public subnets for the specified load balancer. The specified subnets replace the previously enabled subnets.</p> <p>When you specify subnets for a Network Load Balancer, you must include all subnets that were enabled previously, with their existing configurations, plus any additional subnets.</p>
+    ///
+    /// See `Elb::set_subnets`.
+    pub fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
+        self.runtime.block_on(self.client.set_subnets(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -2695,10 +2695,16 @@ impl AddInstanceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddInstanceFleetError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddInstanceFleetError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddInstanceFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddInstanceFleetError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2729,9 +2735,10 @@ impl AddInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddInstanceGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddInstanceGroupsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2761,7 +2768,10 @@ impl AddJobFlowStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddJobFlowStepsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddJobFlowStepsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2793,10 +2803,10 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddTagsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2829,10 +2839,13 @@ impl CancelStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelStepsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelStepsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelStepsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CancelStepsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2867,14 +2880,16 @@ impl CreateSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityConfigurationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2909,14 +2924,16 @@ impl DeleteSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityConfigurationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2949,10 +2966,16 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeClusterError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeClusterError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2983,9 +3006,10 @@ impl DescribeJobFlowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeJobFlowsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobFlowsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3019,12 +3043,14 @@ impl DescribeSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSecurityConfigurationError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSecurityConfigurationError::InvalidRequest(err.msg),
                     )
                 }
@@ -3059,10 +3085,10 @@ impl DescribeStepError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeStepError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DescribeStepError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeStepError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeStepError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3097,12 +3123,14 @@ impl GetBlockPublicAccessConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBlockPublicAccessConfigurationError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
                     )
                 }
@@ -3163,10 +3191,16 @@ impl ListBootstrapActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListBootstrapActionsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBootstrapActionsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBootstrapActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBootstrapActionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3199,10 +3233,10 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListClustersError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListClustersError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListClustersError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListClustersError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3235,10 +3269,16 @@ impl ListInstanceFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstanceFleetsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInstanceFleetsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInstanceFleetsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3271,10 +3311,16 @@ impl ListInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstanceGroupsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInstanceGroupsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInstanceGroupsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3307,10 +3353,10 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstancesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListInstancesError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListInstancesError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3345,14 +3391,16 @@ impl ListSecurityConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSecurityConfigurationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityConfigurationsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityConfigurationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityConfigurationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3385,10 +3433,10 @@ impl ListStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListStepsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListStepsError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStepsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListStepsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3421,10 +3469,13 @@ impl ModifyClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ModifyClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyClusterError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyClusterError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ModifyClusterError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3457,10 +3508,16 @@ impl ModifyInstanceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ModifyInstanceFleetError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyInstanceFleetError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyInstanceFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyInstanceFleetError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3491,9 +3548,10 @@ impl ModifyInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ModifyInstanceGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyInstanceGroupsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3549,12 +3607,14 @@ impl PutBlockPublicAccessConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBlockPublicAccessConfigurationError::InternalServer(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
                     )
                 }
@@ -3661,10 +3721,10 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RemoveTagsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3695,7 +3755,10 @@ impl RunJobFlowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RunJobFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RunJobFlowError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3725,7 +3788,8 @@ impl SetTerminationProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetTerminationProtectionError::InternalServerError(err.msg),
                     )
                 }
@@ -3757,9 +3821,10 @@ impl SetVisibleToAllUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetVisibleToAllUsersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVisibleToAllUsersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3789,9 +3854,10 @@ impl TerminateJobFlowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TerminateJobFlowsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TerminateJobFlowsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4033,6 +4099,7 @@ impl EmrClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4048,6 +4115,7 @@ impl EmrClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EmrClient {
         EmrClient { client, region }
     }
@@ -4061,6 +4129,9 @@ impl Emr for EmrClient {
         input: AddInstanceFleetInput,
     ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddInstanceFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4079,6 +4150,9 @@ impl Emr for EmrClient {
         input: AddInstanceGroupsInput,
     ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddInstanceGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4097,6 +4171,9 @@ impl Emr for EmrClient {
         input: AddJobFlowStepsInput,
     ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddJobFlowSteps");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.AddJobFlowSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4115,6 +4192,9 @@ impl Emr for EmrClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4133,6 +4213,9 @@ impl Emr for EmrClient {
         input: CancelStepsInput,
     ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelSteps");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.CancelSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4152,6 +4235,9 @@ impl Emr for EmrClient {
     ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSecurityConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.CreateSecurityConfiguration",
@@ -4175,6 +4261,9 @@ impl Emr for EmrClient {
     ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSecurityConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.DeleteSecurityConfiguration",
@@ -4197,6 +4286,9 @@ impl Emr for EmrClient {
         input: DescribeClusterInput,
     ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCluster");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4215,6 +4307,9 @@ impl Emr for EmrClient {
         input: DescribeJobFlowsInput,
     ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeJobFlows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4234,6 +4329,9 @@ impl Emr for EmrClient {
     ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSecurityConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.DescribeSecurityConfiguration",
@@ -4256,6 +4354,9 @@ impl Emr for EmrClient {
         input: DescribeStepInput,
     ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStep");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeStep");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4276,6 +4377,9 @@ impl Emr for EmrClient {
         RusotoError<GetBlockPublicAccessConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetBlockPublicAccessConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.GetBlockPublicAccessConfiguration",
@@ -4300,6 +4404,9 @@ impl Emr for EmrClient {
         input: GetManagedScalingPolicyInput,
     ) -> Result<GetManagedScalingPolicyOutput, RusotoError<GetManagedScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetManagedScalingPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.GetManagedScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4319,6 +4426,9 @@ impl Emr for EmrClient {
         input: ListBootstrapActionsInput,
     ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBootstrapActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.ListBootstrapActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4337,6 +4447,9 @@ impl Emr for EmrClient {
         input: ListClustersInput,
     ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.ListClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4355,6 +4468,9 @@ impl Emr for EmrClient {
         input: ListInstanceFleetsInput,
     ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListInstanceFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4373,6 +4489,9 @@ impl Emr for EmrClient {
         input: ListInstanceGroupsInput,
     ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListInstanceGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4391,6 +4510,9 @@ impl Emr for EmrClient {
         input: ListInstancesInput,
     ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4410,6 +4532,9 @@ impl Emr for EmrClient {
     ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSecurityConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.ListSecurityConfigurations",
@@ -4432,6 +4557,9 @@ impl Emr for EmrClient {
         input: ListStepsInput,
     ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSteps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ElasticMapReduce.ListSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4450,6 +4578,9 @@ impl Emr for EmrClient {
         input: ModifyClusterInput,
     ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4468,6 +4599,9 @@ impl Emr for EmrClient {
         input: ModifyInstanceFleetInput,
     ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyInstanceFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4485,6 +4619,9 @@ impl Emr for EmrClient {
         input: ModifyInstanceGroupsInput,
     ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyInstanceGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4502,6 +4639,9 @@ impl Emr for EmrClient {
         input: PutAutoScalingPolicyInput,
     ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAutoScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.PutAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4523,6 +4663,9 @@ impl Emr for EmrClient {
         RusotoError<PutBlockPublicAccessConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutBlockPublicAccessConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.PutBlockPublicAccessConfiguration",
@@ -4548,6 +4691,9 @@ impl Emr for EmrClient {
         input: PutManagedScalingPolicyInput,
     ) -> Result<PutManagedScalingPolicyOutput, RusotoError<PutManagedScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutManagedScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.PutManagedScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4567,6 +4713,9 @@ impl Emr for EmrClient {
         input: RemoveAutoScalingPolicyInput,
     ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveAutoScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4587,6 +4736,9 @@ impl Emr for EmrClient {
     ) -> Result<RemoveManagedScalingPolicyOutput, RusotoError<RemoveManagedScalingPolicyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveManagedScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.RemoveManagedScalingPolicy",
@@ -4609,6 +4761,9 @@ impl Emr for EmrClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4627,6 +4782,9 @@ impl Emr for EmrClient {
         input: RunJobFlowInput,
     ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RunJobFlow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.RunJobFlow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4645,6 +4803,9 @@ impl Emr for EmrClient {
         input: SetTerminationProtectionInput,
     ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetTerminationProtection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.SetTerminationProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4662,6 +4823,9 @@ impl Emr for EmrClient {
         input: SetVisibleToAllUsersInput,
     ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetVisibleToAllUsers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.SetVisibleToAllUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4679,6 +4843,9 @@ impl Emr for EmrClient {
         input: TerminateJobFlowsInput,
     ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TerminateJobFlows");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "ElasticMapReduce.TerminateJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4690,3 +4857,413 @@ impl Emr for EmrClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon EMR API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EmrClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EmrBlockingClient {
+    client: EmrClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EmrBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EmrBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EmrBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EmrBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EmrBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EmrBlockingClient {
+        EmrBlockingClient {
+            client: EmrClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Adds an instance fleet to a running cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x.</p> </note></p>
+    ///
+    /// See `Emr::add_instance_fleet`.
+    pub fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
+        self.runtime.block_on(self.client.add_instance_fleet(input))
+    }
+
+    /// <p>Adds one or more instance groups to a running cluster.</p>
+    ///
+    /// See `Emr::add_instance_groups`.
+    pub fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
+        self.runtime
+            .block_on(self.client.add_instance_groups(input))
+    }
+
+    /// <p>AddJobFlowSteps adds new steps to a running cluster. A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using SSH to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>A step specifies the location of a JAR file stored either on the master node of the cluster or in Amazon S3. Each step is performed by the main function of the main class of the JAR file. The main class can be specified either in the manifest of the JAR or by using the MainFunction parameter of the step.</p> <p>Amazon EMR executes each step in the order listed. For a step to be considered complete, the main function must exit with a zero exit code and all Hadoop jobs started while the step was running must have completed and run successfully.</p> <p>You can only add steps to a cluster that is in one of the following states: STARTING, BOOTSTRAPPING, RUNNING, or WAITING.</p>
+    ///
+    /// See `Emr::add_job_flow_steps`.
+    pub fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
+        self.runtime.block_on(self.client.add_job_flow_steps(input))
+    }
+
+    /// <p>Adds tags to an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p>
+    ///
+    /// See `Emr::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Cancels a pending step or steps in a running cluster. Available only in Amazon EMR versions 4.8.0 and later, excluding version 5.0.0. A maximum of 256 steps are allowed in each CancelSteps request. CancelSteps is idempotent but asynchronous; it does not guarantee a step will be canceled, even if the request is successfully submitted. You can only cancel steps that are in a <code>PENDING</code> state.</p>
+    ///
+    /// See `Emr::cancel_steps`.
+    pub fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
+        self.runtime.block_on(self.client.cancel_steps(input))
+    }
+
+    /// <p>Creates a security configuration, which is stored in the service and can be specified when a cluster is created.</p>
+    ///
+    /// See `Emr::create_security_configuration`.
+    pub fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_security_configuration(input))
+    }
+
+    /// <p>Deletes a security configuration.</p>
+    ///
+    /// See `Emr::delete_security_configuration`.
+    pub fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_security_configuration(input))
+    }
+
+    /// <p>Provides cluster-level details including status, hardware and software configuration, VPC settings, and so on. </p>
+    ///
+    /// See `Emr::describe_cluster`.
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// <p>This API is deprecated and will eventually be removed. We recommend you use <a>ListClusters</a>, <a>DescribeCluster</a>, <a>ListSteps</a>, <a>ListInstanceGroups</a> and <a>ListBootstrapActions</a> instead.</p> <p>DescribeJobFlows returns a list of job flows that match all of the supplied parameters. The parameters can include a list of job flow IDs, job flow states, and restrictions on job flow creation date and time.</p> <p>Regardless of supplied parameters, only job flows created within the last two months are returned.</p> <p>If no parameters are supplied, then job flows matching either of the following criteria are returned:</p> <ul> <li> <p>Job flows created and completed in the last two weeks</p> </li> <li> <p> Job flows created within the last two months that are in one of the following states: <code>RUNNING</code>, <code>WAITING</code>, <code>SHUTTING_DOWN</code>, <code>STARTING</code> </p> </li> </ul> <p>Amazon EMR can return a maximum of 512 job flow descriptions.</p>
+    ///
+    /// See `Emr::describe_job_flows`.
+    pub fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
+        self.runtime.block_on(self.client.describe_job_flows(input))
+    }
+
+    /// <p>Provides the details of a security configuration by returning the configuration JSON.</p>
+    ///
+    /// See `Emr::describe_security_configuration`.
+    pub fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_security_configuration(input))
+    }
+
+    /// <p>Provides more detail about the cluster step.</p>
+    ///
+    /// See `Emr::describe_step`.
+    pub fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
+        self.runtime.block_on(self.client.describe_step(input))
+    }
+
+    /// <p>Returns the Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
+    ///
+    /// See `Emr::get_block_public_access_configuration`.
+    pub fn get_block_public_access_configuration(
+        &self,
+    ) -> Result<
+        GetBlockPublicAccessConfigurationOutput,
+        RusotoError<GetBlockPublicAccessConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_block_public_access_configuration())
+    }
+
+    /// <p> Fetches the attached managed scaling policy for an Amazon EMR cluster. </p>
+    ///
+    /// See `Emr::get_managed_scaling_policy`.
+    pub fn get_managed_scaling_policy(
+        &self,
+        input: GetManagedScalingPolicyInput,
+    ) -> Result<GetManagedScalingPolicyOutput, RusotoError<GetManagedScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_managed_scaling_policy(input))
+    }
+
+    /// <p>Provides information about the bootstrap actions associated with a cluster.</p>
+    ///
+    /// See `Emr::list_bootstrap_actions`.
+    pub fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
+        self.runtime
+            .block_on(self.client.list_bootstrap_actions(input))
+    }
+
+    /// <p>Provides the status of all clusters visible to this AWS account. Allows you to filter the list of clusters based on certain criteria; for example, filtering by cluster creation date and time or by status. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListClusters calls.</p>
+    ///
+    /// See `Emr::list_clusters`.
+    pub fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// <p><p>Lists all available details about the instance fleets in a cluster.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
+    ///
+    /// See `Emr::list_instance_fleets`.
+    pub fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
+        self.runtime
+            .block_on(self.client.list_instance_fleets(input))
+    }
+
+    /// <p>Provides all available details about the instance groups in a cluster.</p>
+    ///
+    /// See `Emr::list_instance_groups`.
+    pub fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_instance_groups(input))
+    }
+
+    /// <p>Provides information for all active EC2 instances and EC2 instances terminated in the last 30 days, up to a maximum of 2,000. EC2 instances in any of the following states are considered active: AWAITING_FULFILLMENT, PROVISIONING, BOOTSTRAPPING, RUNNING.</p>
+    ///
+    /// See `Emr::list_instances`.
+    pub fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
+        self.runtime.block_on(self.client.list_instances(input))
+    }
+
+    /// <p>Lists all the security configurations visible to this account, providing their creation dates and times, and their names. This call returns a maximum of 50 clusters per call, but returns a marker to track the paging of the cluster list across multiple ListSecurityConfigurations calls.</p>
+    ///
+    /// See `Emr::list_security_configurations`.
+    pub fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_security_configurations(input))
+    }
+
+    /// <p>Provides a list of steps for the cluster in reverse order unless you specify <code>stepIds</code> with the request of filter by <code>StepStates</code>. You can specify a maximum of ten <code>stepIDs</code>.</p>
+    ///
+    /// See `Emr::list_steps`.
+    pub fn list_steps(
+        &self,
+        input: ListStepsInput,
+    ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
+        self.runtime.block_on(self.client.list_steps(input))
+    }
+
+    /// <p>Modifies the number of steps that can be executed concurrently for the cluster specified using ClusterID.</p>
+    ///
+    /// See `Emr::modify_cluster`.
+    pub fn modify_cluster(
+        &self,
+        input: ModifyClusterInput,
+    ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
+        self.runtime.block_on(self.client.modify_cluster(input))
+    }
+
+    /// <p><p>Modifies the target On-Demand and target Spot capacities for the instance fleet with the specified InstanceFleetID within the cluster specified using ClusterID. The call either succeeds or fails atomically.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
+    ///
+    /// See `Emr::modify_instance_fleet`.
+    pub fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_fleet(input))
+    }
+
+    /// <p>ModifyInstanceGroups modifies the number of nodes and configuration settings of an instance group. The input parameters include the new target instance count for the group and the instance group ID. The call will either succeed or fail atomically.</p>
+    ///
+    /// See `Emr::modify_instance_groups`.
+    pub fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_groups(input))
+    }
+
+    /// <p>Creates or updates an automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric.</p>
+    ///
+    /// See `Emr::put_auto_scaling_policy`.
+    pub fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_auto_scaling_policy(input))
+    }
+
+    /// <p>Creates or updates an Amazon EMR block public access configuration for your AWS account in the current Region. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/configure-block-public-access.html">Configure Block Public Access for Amazon EMR</a> in the <i>Amazon EMR Management Guide</i>.</p>
+    ///
+    /// See `Emr::put_block_public_access_configuration`.
+    pub fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> Result<
+        PutBlockPublicAccessConfigurationOutput,
+        RusotoError<PutBlockPublicAccessConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_block_public_access_configuration(input))
+    }
+
+    /// <p> Creates or updates a managed scaling policy for an Amazon EMR cluster. The managed scaling policy defines the limits for resources, such as EC2 instances that can be added or terminated from a cluster. The policy only applies to the core and task nodes. The master node cannot be scaled after initial configuration. </p>
+    ///
+    /// See `Emr::put_managed_scaling_policy`.
+    pub fn put_managed_scaling_policy(
+        &self,
+        input: PutManagedScalingPolicyInput,
+    ) -> Result<PutManagedScalingPolicyOutput, RusotoError<PutManagedScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_managed_scaling_policy(input))
+    }
+
+    /// <p>Removes an automatic scaling policy from a specified instance group within an EMR cluster.</p>
+    ///
+    /// See `Emr::remove_auto_scaling_policy`.
+    pub fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.remove_auto_scaling_policy(input))
+    }
+
+    /// <p> Removes a managed scaling policy from a specified EMR cluster. </p>
+    ///
+    /// See `Emr::remove_managed_scaling_policy`.
+    pub fn remove_managed_scaling_policy(
+        &self,
+        input: RemoveManagedScalingPolicyInput,
+    ) -> Result<RemoveManagedScalingPolicyOutput, RusotoError<RemoveManagedScalingPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_managed_scaling_policy(input))
+    }
+
+    /// <p>Removes tags from an Amazon EMR resource. Tags make it easier to associate clusters in various ways, such as grouping clusters to track your Amazon EMR resource allocation costs. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-plan-tags.html">Tag Clusters</a>. </p> <p>The following example removes the stack tag with value Prod from a cluster:</p>
+    ///
+    /// See `Emr::remove_tags`.
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// <p><p>RunJobFlow creates and starts running a new cluster (job flow). The cluster runs the steps specified. After the steps complete, the cluster stops and the HDFS partition is lost. To prevent loss of data, configure the last step of the job flow to store results in Amazon S3. If the <a>JobFlowInstancesConfig</a> <code>KeepJobFlowAliveWhenNoSteps</code> parameter is set to <code>TRUE</code>, the cluster transitions to the WAITING state rather than shutting down after the steps have completed. </p> <p>For additional protection, you can set the <a>JobFlowInstancesConfig</a> <code>TerminationProtected</code> parameter to <code>TRUE</code> to lock the cluster and prevent it from being terminated by API call, user intervention, or in the event of a job flow error.</p> <p>A maximum of 256 steps are allowed in each job flow.</p> <p>If your cluster is long-running (such as a Hive data warehouse) or complex, you may require more than 256 steps to process your data. You can bypass the 256-step limitation in various ways, including using the SSH shell to connect to the master node and submitting queries directly to the software running on the master node, such as Hive and Hadoop. For more information on how to do this, see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/AddMoreThan256Steps.html">Add More than 256 Steps to a Cluster</a> in the <i>Amazon EMR Management Guide</i>.</p> <p>For long running clusters, we recommend that you periodically store your results.</p> <note> <p>The instance fleets configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions. The RunJobFlow request can contain InstanceFleets parameters or InstanceGroups parameters, but not both.</p> </note></p>
+    ///
+    /// See `Emr::run_job_flow`.
+    pub fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
+        self.runtime.block_on(self.client.run_job_flow(input))
+    }
+
+    /// <p>SetTerminationProtection locks a cluster (job flow) so the EC2 instances in the cluster cannot be terminated by user intervention, an API call, or in the event of a job-flow error. The cluster still terminates upon successful completion of the job flow. Calling <code>SetTerminationProtection</code> on a cluster is similar to calling the Amazon EC2 <code>DisableAPITermination</code> API on all EC2 instances in a cluster.</p> <p> <code>SetTerminationProtection</code> is used to prevent accidental termination of a cluster and to ensure that in the event of an error, the instances persist so that you can recover any data stored in their ephemeral instance storage.</p> <p> To terminate a cluster that has been locked by setting <code>SetTerminationProtection</code> to <code>true</code>, you must first unlock the job flow by a subsequent call to <code>SetTerminationProtection</code> in which you set the value to <code>false</code>. </p> <p> For more information, see<a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/UsingEMR_TerminationProtection.html">Managing Cluster Termination</a> in the <i>Amazon EMR Management Guide</i>. </p>
+    ///
+    /// See `Emr::set_termination_protection`.
+    pub fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
+        self.runtime
+            .block_on(self.client.set_termination_protection(input))
+    }
+
+    /// <p>Sets the <a>Cluster$VisibleToAllUsers</a> value, which determines whether the cluster is visible to all IAM users of the AWS account associated with the cluster. Only the IAM user who created the cluster or the AWS account root user can call this action. The default value, <code>true</code>, indicates that all IAM users in the AWS account can perform cluster actions if they have the proper IAM policy permissions. If set to <code>false</code>, only the IAM user that created the cluster can perform actions. This action works on running clusters. You can override the default <code>true</code> setting when you create a cluster by using the <code>VisibleToAllUsers</code> parameter with <code>RunJobFlow</code>.</p>
+    ///
+    /// See `Emr::set_visible_to_all_users`.
+    pub fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
+        self.runtime
+            .block_on(self.client.set_visible_to_all_users(input))
+    }
+
+    /// <p>TerminateJobFlows shuts a list of clusters (job flows) down. When a job flow is shut down, any step not yet completed is canceled and the EC2 instances on which the cluster is running are stopped. Any log files not already saved are uploaded to Amazon S3 if a LogUri was specified when the cluster was created.</p> <p>The maximum number of clusters allowed is 10. The call to <code>TerminateJobFlows</code> is asynchronous. Depending on the configuration of the cluster, it may take up to 1-5 minutes for the cluster to completely terminate and release allocated resources, such as Amazon EC2 instances.</p>
+    ///
+    /// See `Emr::terminate_job_flows`.
+    pub fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
+        self.runtime
+            .block_on(self.client.terminate_job_flows(input))
+    }
+}
@@ -2075,17 +2075,20 @@ impl AcceptInboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptInboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptInboundCrossClusterSearchConnectionError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptInboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2128,12 +2131,12 @@ impl AddTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AddTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(AddTagsError::Base(err.msg)),
+                "BaseException" => return RusotoError::service(&res, AddTagsError::Base(err.msg)),
                 "InternalException" => {
-                    return RusotoError::Service(AddTagsError::Internal(err.msg))
+                    return RusotoError::service(&res, AddTagsError::Internal(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AddTagsError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2173,19 +2176,22 @@ impl AssociatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AssociatePackageError::AccessDenied(err.msg))
                 }
                 "BaseException" => {
-                    return RusotoError::Service(AssociatePackageError::Base(err.msg))
+                    return RusotoError::service(&res, AssociatePackageError::Base(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociatePackageError::Conflict(err.msg))
+                    return RusotoError::service(&res, AssociatePackageError::Conflict(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AssociatePackageError::Internal(err.msg))
+                    return RusotoError::service(&res, AssociatePackageError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociatePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociatePackageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2225,17 +2231,20 @@ impl CancelElasticsearchServiceSoftwareUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelElasticsearchServiceSoftwareUpdateError::Base(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelElasticsearchServiceSoftwareUpdateError::Internal(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelElasticsearchServiceSoftwareUpdateError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2285,28 +2294,38 @@ impl CreateElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateElasticsearchDomainError::Base(err.msg),
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::DisabledOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateElasticsearchDomainError::DisabledOperation(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateElasticsearchDomainError::Internal(err.msg),
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::InvalidType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateElasticsearchDomainError::InvalidType(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateElasticsearchDomainError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateElasticsearchDomainError::ResourceAlreadyExists(err.msg),
                     )
                 }
@@ -2353,22 +2372,26 @@ impl CreateOutboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOutboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOutboundCrossClusterSearchConnectionError::Internal(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOutboundCrossClusterSearchConnectionError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOutboundCrossClusterSearchConnectionError::ResourceAlreadyExists(
                             err.msg,
                         ),
@@ -2423,20 +2446,25 @@ impl CreatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreatePackageError::AccessDenied(err.msg))
+                }
+                "BaseException" => {
+                    return RusotoError::service(&res, CreatePackageError::Base(err.msg))
                 }
-                "BaseException" => return RusotoError::Service(CreatePackageError::Base(err.msg)),
                 "InternalException" => {
-                    return RusotoError::Service(CreatePackageError::Internal(err.msg))
+                    return RusotoError::service(&res, CreatePackageError::Internal(err.msg))
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(CreatePackageError::InvalidType(err.msg))
+                    return RusotoError::service(&res, CreatePackageError::InvalidType(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePackageError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePackageError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePackageError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackageError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2475,15 +2503,22 @@ impl DeleteElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteElasticsearchDomainError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteElasticsearchDomainError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteElasticsearchDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2519,12 +2554,16 @@ impl DeleteElasticsearchServiceRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DeleteElasticsearchServiceRoleError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteElasticsearchServiceRoleError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteElasticsearchServiceRoleError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteElasticsearchServiceRoleError::Internal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2559,12 +2598,14 @@ impl DeleteInboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2605,12 +2646,14 @@ impl DeleteOutboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOutboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOutboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2655,17 +2698,22 @@ impl DeletePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePackageError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeletePackageError::AccessDenied(err.msg))
+                }
+                "BaseException" => {
+                    return RusotoError::service(&res, DeletePackageError::Base(err.msg))
                 }
-                "BaseException" => return RusotoError::Service(DeletePackageError::Base(err.msg)),
                 "ConflictException" => {
-                    return RusotoError::Service(DeletePackageError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeletePackageError::Conflict(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeletePackageError::Internal(err.msg))
+                    return RusotoError::service(&res, DeletePackageError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2705,15 +2753,20 @@ impl DescribeElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticsearchDomainError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticsearchDomainError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchDomainError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2753,17 +2806,20 @@ impl DescribeElasticsearchDomainConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainConfigError::Base(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticsearchDomainConfigError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainConfigError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticsearchDomainConfigError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchDomainConfigError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2803,12 +2859,16 @@ impl DescribeElasticsearchDomainsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainsError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticsearchDomainsError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticsearchDomainsError::Internal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2849,27 +2909,32 @@ impl DescribeElasticsearchInstanceTypeLimitsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchInstanceTypeLimitsError::Base(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchInstanceTypeLimitsError::Internal(err.msg),
                     )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchInstanceTypeLimitsError::InvalidType(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchInstanceTypeLimitsError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticsearchInstanceTypeLimitsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2917,14 +2982,16 @@ impl DescribeInboundCrossClusterSearchConnectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInboundCrossClusterSearchConnectionsError::DisabledOperation(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInboundCrossClusterSearchConnectionsError::InvalidPaginationToken(
                             err.msg,
                         ),
@@ -2967,14 +3034,16 @@ impl DescribeOutboundCrossClusterSearchConnectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOutboundCrossClusterSearchConnectionsError::DisabledOperation(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOutboundCrossClusterSearchConnectionsError::InvalidPaginationToken(
                             err.msg,
                         ),
@@ -3019,16 +3088,19 @@ impl DescribePackagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribePackagesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribePackagesError::AccessDenied(err.msg))
                 }
                 "BaseException" => {
-                    return RusotoError::Service(DescribePackagesError::Base(err.msg))
+                    return RusotoError::service(&res, DescribePackagesError::Base(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribePackagesError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribePackagesError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePackagesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3067,19 +3139,22 @@ impl DescribeReservedElasticsearchInstanceOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReservedElasticsearchInstanceOfferingsError::DisabledOperation(
                             err.msg,
                         ),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReservedElasticsearchInstanceOfferingsError::Internal(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReservedElasticsearchInstanceOfferingsError::ResourceNotFound(
                             err.msg,
                         ),
@@ -3127,17 +3202,20 @@ impl DescribeReservedElasticsearchInstancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReservedElasticsearchInstancesError::DisabledOperation(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReservedElasticsearchInstancesError::Internal(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeReservedElasticsearchInstancesError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3185,19 +3263,25 @@ impl DissociatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DissociatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DissociatePackageError::AccessDenied(err.msg),
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(DissociatePackageError::Base(err.msg))
+                    return RusotoError::service(&res, DissociatePackageError::Base(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DissociatePackageError::Conflict(err.msg))
+                    return RusotoError::service(&res, DissociatePackageError::Conflict(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DissociatePackageError::Internal(err.msg))
+                    return RusotoError::service(&res, DissociatePackageError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DissociatePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DissociatePackageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3239,22 +3323,26 @@ impl GetCompatibleElasticsearchVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetCompatibleElasticsearchVersionsError::Base(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleElasticsearchVersionsError::Base(err.msg),
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCompatibleElasticsearchVersionsError::DisabledOperation(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetCompatibleElasticsearchVersionsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleElasticsearchVersionsError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCompatibleElasticsearchVersionsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3299,16 +3387,22 @@ impl GetUpgradeHistoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::Base(err.msg))
+                    return RusotoError::service(&res, GetUpgradeHistoryError::Base(err.msg))
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::DisabledOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUpgradeHistoryError::DisabledOperation(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::Internal(err.msg))
+                    return RusotoError::service(&res, GetUpgradeHistoryError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUpgradeHistoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3347,16 +3441,22 @@ impl GetUpgradeStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::Base(err.msg))
+                    return RusotoError::service(&res, GetUpgradeStatusError::Base(err.msg))
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::DisabledOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUpgradeStatusError::DisabledOperation(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::Internal(err.msg))
+                    return RusotoError::service(&res, GetUpgradeStatusError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUpgradeStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3389,7 +3489,7 @@ impl ListDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListDomainNamesError::Base(err.msg))
+                    return RusotoError::service(&res, ListDomainNamesError::Base(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3425,18 +3525,25 @@ impl ListDomainsForPackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainsForPackageError::AccessDenied(err.msg),
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::Base(err.msg))
+                    return RusotoError::service(&res, ListDomainsForPackageError::Base(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainsForPackageError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainsForPackageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3475,15 +3582,20 @@ impl ListElasticsearchInstanceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListElasticsearchInstanceTypesError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListElasticsearchInstanceTypesError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListElasticsearchInstanceTypesError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListElasticsearchInstanceTypesError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListElasticsearchInstanceTypesError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3523,15 +3635,22 @@ impl ListElasticsearchVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListElasticsearchVersionsError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListElasticsearchVersionsError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListElasticsearchVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3569,18 +3688,25 @@ impl ListPackagesForDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagesForDomainError::AccessDenied(err.msg),
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::Base(err.msg))
+                    return RusotoError::service(&res, ListPackagesForDomainError::Base(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagesForDomainError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagesForDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3616,12 +3742,12 @@ impl ListTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(ListTagsError::Base(err.msg)),
+                "BaseException" => return RusotoError::service(&res, ListTagsError::Base(err.msg)),
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTagsError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3663,31 +3789,36 @@ impl PurchaseReservedElasticsearchInstanceOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseReservedElasticsearchInstanceOfferingError::DisabledOperation(
                             err.msg,
                         ),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseReservedElasticsearchInstanceOfferingError::Internal(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseReservedElasticsearchInstanceOfferingError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseReservedElasticsearchInstanceOfferingError::ResourceAlreadyExists(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseReservedElasticsearchInstanceOfferingError::ResourceNotFound(
                             err.msg,
                         ),
@@ -3739,12 +3870,14 @@ impl RejectInboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectInboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectInboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3782,9 +3915,11 @@ impl RemoveTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemoveTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(RemoveTagsError::Base(err.msg)),
+                "BaseException" => {
+                    return RusotoError::service(&res, RemoveTagsError::Base(err.msg))
+                }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveTagsError::Internal(err.msg))
+                    return RusotoError::service(&res, RemoveTagsError::Internal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3821,17 +3956,20 @@ impl StartElasticsearchServiceSoftwareUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartElasticsearchServiceSoftwareUpdateError::Base(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartElasticsearchServiceSoftwareUpdateError::Internal(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartElasticsearchServiceSoftwareUpdateError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3879,27 +4017,32 @@ impl UpdateElasticsearchDomainConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::Base(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateElasticsearchDomainConfigError::Base(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateElasticsearchDomainConfigError::Internal(err.msg),
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::InvalidType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateElasticsearchDomainConfigError::InvalidType(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateElasticsearchDomainConfigError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateElasticsearchDomainConfigError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3949,25 +4092,34 @@ impl UpgradeElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeElasticsearchDomainError::Base(err.msg),
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpgradeElasticsearchDomainError::DisabledOperation(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeElasticsearchDomainError::Internal(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpgradeElasticsearchDomainError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpgradeElasticsearchDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4280,6 +4432,7 @@ impl EsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4295,6 +4448,7 @@ impl EsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EsClient {
         EsClient { client, region }
     }
@@ -4317,6 +4471,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "es", &self.region, &request_uri);
+        request.set_operation_name("AcceptInboundCrossClusterSearchConnection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4342,6 +4499,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4376,6 +4536,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("AssociatePackage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4407,6 +4570,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/cancel";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("CancelElasticsearchServiceSoftwareUpdate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4439,6 +4605,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("CreateElasticsearchDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4473,6 +4642,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/outboundConnection";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("CreateOutboundCrossClusterSearchConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4504,6 +4676,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("CreatePackage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4539,6 +4714,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation_name("DeleteElasticsearchDomain");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4566,6 +4744,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/role";
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation_name("DeleteElasticsearchServiceRole");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4599,6 +4780,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation_name("DeleteInboundCrossClusterSearchConnection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4633,6 +4817,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation_name("DeleteOutboundCrossClusterSearchConnection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4664,6 +4851,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation_name("DeletePackage");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4696,6 +4886,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeElasticsearchDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4730,6 +4923,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeElasticsearchDomainConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4761,6 +4957,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain-info";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeElasticsearchDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4799,6 +4998,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeElasticsearchInstanceTypeLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4838,6 +5040,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/inboundConnection/search";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeInboundCrossClusterSearchConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4873,6 +5078,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/outboundConnection/search";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeOutboundCrossClusterSearchConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4905,6 +5113,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages/describe";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribePackages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4939,6 +5150,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstanceOfferings";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeReservedElasticsearchInstanceOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4983,6 +5197,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstances";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("DescribeReservedElasticsearchInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5029,6 +5246,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("DissociatePackage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5060,6 +5280,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/compatibleVersions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("GetCompatibleElasticsearchVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5099,6 +5322,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("GetUpgradeHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5139,6 +5365,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("GetUpgradeStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5166,6 +5395,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/domain";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("ListDomainNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5197,6 +5429,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("ListDomainsForPackage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5240,6 +5475,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("ListElasticsearchInstanceTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5281,6 +5519,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/versions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("ListElasticsearchVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5321,6 +5562,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("ListPackagesForDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5358,6 +5602,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags/";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5393,6 +5640,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/purchaseReservedInstanceOffering";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("PurchaseReservedElasticsearchInstanceOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5431,6 +5681,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "es", &self.region, &request_uri);
+        request.set_operation_name("RejectInboundCrossClusterSearchConnection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5459,6 +5712,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags-removal";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("RemoveTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5492,6 +5748,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/start";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("StartElasticsearchServiceSoftwareUpdate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5531,6 +5790,9 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("UpdateElasticsearchDomainConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5565,6 +5827,9 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/upgradeDomain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation_name("UpgradeElasticsearchDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5587,3 +5852,526 @@ impl Es for EsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Elasticsearch Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EsBlockingClient {
+    client: EsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EsBlockingClient {
+        EsBlockingClient {
+            client: EsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Allows the destination domain owner to accept an inbound cross-cluster search connection request.</p>
+    ///
+    /// See `Es::accept_inbound_cross_cluster_search_connection`.
+    pub fn accept_inbound_cross_cluster_search_connection(
+        &self,
+        input: AcceptInboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        AcceptInboundCrossClusterSearchConnectionResponse,
+        RusotoError<AcceptInboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_inbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// <p>Attaches tags to an existing Elasticsearch domain. Tags are a set of case-sensitive key value pairs. An Elasticsearch domain may have up to 10 tags. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-managedomains.html#es-managedomains-awsresorcetagging" target="_blank"> Tagging Amazon Elasticsearch Service Domains for more information.</a></p>
+    ///
+    /// See `Es::add_tags`.
+    pub fn add_tags(&self, input: AddTagsRequest) -> Result<(), RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Associates a package with an Amazon ES domain.</p>
+    ///
+    /// See `Es::associate_package`.
+    pub fn associate_package(
+        &self,
+        input: AssociatePackageRequest,
+    ) -> Result<AssociatePackageResponse, RusotoError<AssociatePackageError>> {
+        self.runtime.block_on(self.client.associate_package(input))
+    }
+
+    /// <p>Cancels a scheduled service software update for an Amazon ES domain. You can only perform this operation before the <code>AutomatedUpdateDate</code> and when the <code>UpdateStatus</code> is in the <code>PENDING_UPDATE</code> state.</p>
+    ///
+    /// See `Es::cancel_elasticsearch_service_software_update`.
+    pub fn cancel_elasticsearch_service_software_update(
+        &self,
+        input: CancelElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        CancelElasticsearchServiceSoftwareUpdateResponse,
+        RusotoError<CancelElasticsearchServiceSoftwareUpdateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .cancel_elasticsearch_service_software_update(input),
+        )
+    }
+
+    /// <p>Creates a new Elasticsearch domain. For more information, see <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-createupdatedomains.html#es-createdomains" target="_blank">Creating Elasticsearch Domains</a> in the <i>Amazon Elasticsearch Service Developer Guide</i>.</p>
+    ///
+    /// See `Es::create_elasticsearch_domain`.
+    pub fn create_elasticsearch_domain(
+        &self,
+        input: CreateElasticsearchDomainRequest,
+    ) -> Result<CreateElasticsearchDomainResponse, RusotoError<CreateElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.create_elasticsearch_domain(input))
+    }
+
+    /// <p>Creates a new cross-cluster search connection from a source domain to a destination domain.</p>
+    ///
+    /// See `Es::create_outbound_cross_cluster_search_connection`.
+    pub fn create_outbound_cross_cluster_search_connection(
+        &self,
+        input: CreateOutboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        CreateOutboundCrossClusterSearchConnectionResponse,
+        RusotoError<CreateOutboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_outbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// <p>Create a package for use with Amazon ES domains.</p>
+    ///
+    /// See `Es::create_package`.
+    pub fn create_package(
+        &self,
+        input: CreatePackageRequest,
+    ) -> Result<CreatePackageResponse, RusotoError<CreatePackageError>> {
+        self.runtime.block_on(self.client.create_package(input))
+    }
+
+    /// <p>Permanently deletes the specified Elasticsearch domain and all of its data. Once a domain is deleted, it cannot be recovered.</p>
+    ///
+    /// See `Es::delete_elasticsearch_domain`.
+    pub fn delete_elasticsearch_domain(
+        &self,
+        input: DeleteElasticsearchDomainRequest,
+    ) -> Result<DeleteElasticsearchDomainResponse, RusotoError<DeleteElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_elasticsearch_domain(input))
+    }
+
+    /// <p>Deletes the service-linked role that Elasticsearch Service uses to manage and maintain VPC domains. Role deletion will fail if any existing VPC domains use the role. You must delete any such Elasticsearch domains before deleting the role. See <a href="http://docs.aws.amazon.com/elasticsearch-service/latest/developerguide/es-vpc.html#es-enabling-slr" target="_blank">Deleting Elasticsearch Service Role</a> in <i>VPC Endpoints for Amazon Elasticsearch Service Domains</i>.</p>
+    ///
+    /// See `Es::delete_elasticsearch_service_role`.
+    pub fn delete_elasticsearch_service_role(
+        &self,
+    ) -> Result<(), RusotoError<DeleteElasticsearchServiceRoleError>> {
+        self.runtime
+            .block_on(self.client.delete_elasticsearch_service_role())
+    }
+
+    /// <p>Allows the destination domain owner to delete an existing inbound cross-cluster search connection.</p>
+    ///
+    /// See `Es::delete_inbound_cross_cluster_search_connection`.
+    pub fn delete_inbound_cross_cluster_search_connection(
+        &self,
+        input: DeleteInboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        DeleteInboundCrossClusterSearchConnectionResponse,
+        RusotoError<DeleteInboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_inbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// <p>Allows the source domain owner to delete an existing outbound cross-cluster search connection.</p>
+    ///
+    /// See `Es::delete_outbound_cross_cluster_search_connection`.
+    pub fn delete_outbound_cross_cluster_search_connection(
+        &self,
+        input: DeleteOutboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        DeleteOutboundCrossClusterSearchConnectionResponse,
+        RusotoError<DeleteOutboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_outbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// <p>Delete the package.</p>
+    ///
+    /// See `Es::delete_package`.
+    pub fn delete_package(
+        &self,
+        input: DeletePackageRequest,
+    ) -> Result<DeletePackageResponse, RusotoError<DeletePackageError>> {
+        self.runtime.block_on(self.client.delete_package(input))
+    }
+
+    /// <p>Returns domain configuration information about the specified Elasticsearch domain, including the domain ID, domain endpoint, and domain ARN.</p>
+    ///
+    /// See `Es::describe_elasticsearch_domain`.
+    pub fn describe_elasticsearch_domain(
+        &self,
+        input: DescribeElasticsearchDomainRequest,
+    ) -> Result<DescribeElasticsearchDomainResponse, RusotoError<DescribeElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_elasticsearch_domain(input))
+    }
+
+    /// <p>Provides cluster configuration information about the specified Elasticsearch domain, such as the state, creation date, update version, and update date for cluster options.</p>
+    ///
+    /// See `Es::describe_elasticsearch_domain_config`.
+    pub fn describe_elasticsearch_domain_config(
+        &self,
+        input: DescribeElasticsearchDomainConfigRequest,
+    ) -> Result<
+        DescribeElasticsearchDomainConfigResponse,
+        RusotoError<DescribeElasticsearchDomainConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_elasticsearch_domain_config(input))
+    }
+
+    /// <p>Returns domain configuration information about the specified Elasticsearch domains, including the domain ID, domain endpoint, and domain ARN.</p>
+    ///
+    /// See `Es::describe_elasticsearch_domains`.
+    pub fn describe_elasticsearch_domains(
+        &self,
+        input: DescribeElasticsearchDomainsRequest,
+    ) -> Result<DescribeElasticsearchDomainsResponse, RusotoError<DescribeElasticsearchDomainsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_elasticsearch_domains(input))
+    }
+
+    /// <p> Describe Elasticsearch Limits for a given InstanceType and ElasticsearchVersion. When modifying existing Domain, specify the <code> <a>DomainName</a> </code> to know what Limits are supported for modifying. </p>
+    ///
+    /// See `Es::describe_elasticsearch_instance_type_limits`.
+    pub fn describe_elasticsearch_instance_type_limits(
+        &self,
+        input: DescribeElasticsearchInstanceTypeLimitsRequest,
+    ) -> Result<
+        DescribeElasticsearchInstanceTypeLimitsResponse,
+        RusotoError<DescribeElasticsearchInstanceTypeLimitsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_elasticsearch_instance_type_limits(input),
+        )
+    }
+
+    /// <p>Lists all the inbound cross-cluster search connections for a destination domain.</p>
+    ///
+    /// See `Es::describe_inbound_cross_cluster_search_connections`.
+    pub fn describe_inbound_cross_cluster_search_connections(
+        &self,
+        input: DescribeInboundCrossClusterSearchConnectionsRequest,
+    ) -> Result<
+        DescribeInboundCrossClusterSearchConnectionsResponse,
+        RusotoError<DescribeInboundCrossClusterSearchConnectionsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_inbound_cross_cluster_search_connections(input),
+        )
+    }
+
+    /// <p>Lists all the outbound cross-cluster search connections for a source domain.</p>
+    ///
+    /// See `Es::describe_outbound_cross_cluster_search_connections`.
+    pub fn describe_outbound_cross_cluster_search_connections(
+        &self,
+        input: DescribeOutboundCrossClusterSearchConnectionsRequest,
+    ) -> Result<
+        DescribeOutboundCrossClusterSearchConnectionsResponse,
+        RusotoError<DescribeOutboundCrossClusterSearchConnectionsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_outbound_cross_cluster_search_connections(input),
+        )
+    }
+
+    /// <p>Describes all packages available to Amazon ES. Includes options for filtering, limiting the number of results, and pagination.</p>
+    ///
+    /// See `Es::describe_packages`.
+    pub fn describe_packages(
+        &self,
+        input: DescribePackagesRequest,
+    ) -> Result<DescribePackagesResponse, RusotoError<DescribePackagesError>> {
+        self.runtime.block_on(self.client.describe_packages(input))
+    }
+
+    /// <p>Lists available reserved Elasticsearch instance offerings.</p>
+    ///
+    /// See `Es::describe_reserved_elasticsearch_instance_offerings`.
+    pub fn describe_reserved_elasticsearch_instance_offerings(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> Result<
+        DescribeReservedElasticsearchInstanceOfferingsResponse,
+        RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_reserved_elasticsearch_instance_offerings(input),
+        )
+    }
+
+    /// <p>Returns information about reserved Elasticsearch instances for this account.</p>
+    ///
+    /// See `Es::describe_reserved_elasticsearch_instances`.
+    pub fn describe_reserved_elasticsearch_instances(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> Result<
+        DescribeReservedElasticsearchInstancesResponse,
+        RusotoError<DescribeReservedElasticsearchInstancesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_elasticsearch_instances(input))
+    }
+
+    /// <p>Dissociates a package from the Amazon ES domain.</p>
+    ///
+    /// See `Es::dissociate_package`.
+    pub fn dissociate_package(
+        &self,
+        input: DissociatePackageRequest,
+    ) -> Result<DissociatePackageResponse, RusotoError<DissociatePackageError>> {
+        self.runtime.block_on(self.client.dissociate_package(input))
+    }
+
+    /// <p> Returns a list of upgrade compatible Elastisearch versions. You can optionally pass a <code> <a>DomainName</a> </code> to get all upgrade compatible Elasticsearch versions for that specific domain. </p>
+    ///
+    /// See `Es::get_compatible_elasticsearch_versions`.
+    pub fn get_compatible_elasticsearch_versions(
+        &self,
+        input: GetCompatibleElasticsearchVersionsRequest,
+    ) -> Result<
+        GetCompatibleElasticsearchVersionsResponse,
+        RusotoError<GetCompatibleElasticsearchVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compatible_elasticsearch_versions(input))
+    }
+
+    /// <p>Retrieves the complete history of the last 10 upgrades that were performed on the domain.</p>
+    ///
+    /// See `Es::get_upgrade_history`.
+    pub fn get_upgrade_history(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> Result<GetUpgradeHistoryResponse, RusotoError<GetUpgradeHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_upgrade_history(input))
+    }
+
+    /// <p>Retrieves the latest status of the last upgrade or upgrade eligibility check that was performed on the domain.</p>
+    ///
+    /// See `Es::get_upgrade_status`.
+    pub fn get_upgrade_status(
+        &self,
+        input: GetUpgradeStatusRequest,
+    ) -> Result<GetUpgradeStatusResponse, RusotoError<GetUpgradeStatusError>> {
+        self.runtime.block_on(self.client.get_upgrade_status(input))
+    }
+
+    /// <p>Returns the name of all Elasticsearch domains owned by the current user's account. </p>
+    ///
+    /// See `Es::list_domain_names`.
+    pub fn list_domain_names(
+        &self,
+    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        self.runtime.block_on(self.client.list_domain_names())
+    }
+
+    /// <p>Lists all Amazon ES domains associated with the package.</p>
+    ///
+    /// See `Es::list_domains_for_package`.
+    pub fn list_domains_for_package(
+        &self,
+        input: ListDomainsForPackageRequest,
+    ) -> Result<ListDomainsForPackageResponse, RusotoError<ListDomainsForPackageError>> {
+        self.runtime
+            .block_on(self.client.list_domains_for_package(input))
+    }
+
+    /// <p>List all Elasticsearch instance types that are supported for given ElasticsearchVersion</p>
+    ///
+    /// See `Es::list_elasticsearch_instance_types`.
+    pub fn list_elasticsearch_instance_types(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> Result<
+        ListElasticsearchInstanceTypesResponse,
+        RusotoError<ListElasticsearchInstanceTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_elasticsearch_instance_types(input))
+    }
+
+    /// <p>List all supported Elasticsearch versions</p>
+    ///
+    /// See `Es::list_elasticsearch_versions`.
+    pub fn list_elasticsearch_versions(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> Result<ListElasticsearchVersionsResponse, RusotoError<ListElasticsearchVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_elasticsearch_versions(input))
+    }
+
+    /// <p>Lists all packages associated with the Amazon ES domain.</p>
+    ///
+    /// See `Es::list_packages_for_domain`.
+    pub fn list_packages_for_domain(
+        &self,
+        input: ListPackagesForDomainRequest,
+    ) -> Result<ListPackagesForDomainResponse, RusotoError<ListPackagesForDomainError>> {
+        self.runtime
+            .block_on(self.client.list_packages_for_domain(input))
+    }
+
+    /// <p>Returns all tags for the given Elasticsearch domain.</p>
+    ///
+    /// See `Es::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Allows you to purchase reserved Elasticsearch instances.</p>
+    ///
+    /// See `Es::purchase_reserved_elasticsearch_instance_offering`.
+    pub fn purchase_reserved_elasticsearch_instance_offering(
+        &self,
+        input: PurchaseReservedElasticsearchInstanceOfferingRequest,
+    ) -> Result<
+        PurchaseReservedElasticsearchInstanceOfferingResponse,
+        RusotoError<PurchaseReservedElasticsearchInstanceOfferingError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .purchase_reserved_elasticsearch_instance_offering(input),
+        )
+    }
+
+    /// <p>Allows the destination domain owner to reject an inbound cross-cluster search connection request.</p>
+    ///
+    /// See `Es::reject_inbound_cross_cluster_search_connection`.
+    pub fn reject_inbound_cross_cluster_search_connection(
+        &self,
+        input: RejectInboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        RejectInboundCrossClusterSearchConnectionResponse,
+        RusotoError<RejectInboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .reject_inbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// <p>Removes the specified set of tags from the specified Elasticsearch domain.</p>
+    ///
+    /// See `Es::remove_tags`.
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<(), RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// <p>Schedules a service software update for an Amazon ES domain.</p>
+    ///
+    /// See `Es::start_elasticsearch_service_software_update`.
+    pub fn start_elasticsearch_service_software_update(
+        &self,
+        input: StartElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        StartElasticsearchServiceSoftwareUpdateResponse,
+        RusotoError<StartElasticsearchServiceSoftwareUpdateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .start_elasticsearch_service_software_update(input),
+        )
+    }
+
+    /// <p>Modifies the cluster configuration of the specified Elasticsearch domain, setting as setting the instance type and the number of instances. </p>
+    ///
+    /// See `Es::update_elasticsearch_domain_config`.
+    pub fn update_elasticsearch_domain_config(
+        &self,
+        input: UpdateElasticsearchDomainConfigRequest,
+    ) -> Result<
+        UpdateElasticsearchDomainConfigResponse,
+        RusotoError<UpdateElasticsearchDomainConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_elasticsearch_domain_config(input))
+    }
+
+    /// <p>Allows you to either upgrade your domain or perform an Upgrade eligibility check to a compatible Elasticsearch version.</p>
+    ///
+    /// See `Es::upgrade_elasticsearch_domain`.
+    pub fn upgrade_elasticsearch_domain(
+        &self,
+        input: UpgradeElasticsearchDomainRequest,
+    ) -> Result<UpgradeElasticsearchDomainResponse, RusotoError<UpgradeElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.upgrade_elasticsearch_domain(input))
+    }
+}
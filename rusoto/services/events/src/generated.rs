@@ -1279,25 +1279,31 @@ impl ActivateEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ActivateEventSourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ActivateEventSourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ActivateEventSourceError::Internal(err.msg))
+                    return RusotoError::service(&res, ActivateEventSourceError::Internal(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ActivateEventSourceError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivateEventSourceError::InvalidState(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(ActivateEventSourceError::OperationDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ActivateEventSourceError::OperationDisabled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ActivateEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ActivateEventSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1343,29 +1349,37 @@ impl CreateEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateEventBusError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventBusError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateEventBusError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateEventBusError::Internal(err.msg))
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateEventBusError::InvalidState(err.msg))
+                    return RusotoError::service(&res, CreateEventBusError::InvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEventBusError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateEventBusError::LimitExceeded(err.msg))
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(CreateEventBusError::OperationDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventBusError::OperationDisabled(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateEventBusError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventBusError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventBusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventBusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1409,25 +1423,32 @@ impl CreatePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePartnerEventSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartnerEventSourceError::Internal(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartnerEventSourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::OperationDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartnerEventSourceError::OperationDisabled(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePartnerEventSourceError::ResourceAlreadyExists(err.msg),
                     )
                 }
@@ -1475,25 +1496,34 @@ impl DeactivateEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeactivateEventSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateEventSourceError::Internal(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateEventSourceError::InvalidState(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::OperationDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateEventSourceError::OperationDisabled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateEventSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1529,12 +1559,13 @@ impl DeleteEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteEventBusError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventBusError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteEventBusError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteEventBusError::Internal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1569,17 +1600,22 @@ impl DeletePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePartnerEventSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeletePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePartnerEventSourceError::Internal(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(DeletePartnerEventSourceError::OperationDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePartnerEventSourceError::OperationDisabled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1619,16 +1655,19 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::Internal(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(DeleteRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1663,10 +1702,13 @@ impl DescribeEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeEventBusError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeEventBusError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventBusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventBusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1701,17 +1743,19 @@ impl DescribeEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeEventSourceError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeEventSourceError::Internal(err.msg))
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(DescribeEventSourceError::OperationDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventSourceError::OperationDisabled(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1749,17 +1793,22 @@ impl DescribePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePartnerEventSourceError::Internal(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePartnerEventSourceError::OperationDisabled(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePartnerEventSourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePartnerEventSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1793,10 +1842,10 @@ impl DescribeRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeRuleError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeRuleError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1833,16 +1882,19 @@ impl DisableRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisableRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableRuleError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisableRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, DisableRuleError::Internal(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(DisableRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, DisableRuleError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DisableRuleError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1881,16 +1933,19 @@ impl EnableRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnableRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableRuleError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, EnableRuleError::Internal(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(EnableRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, EnableRuleError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, EnableRuleError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1923,7 +1978,7 @@ impl ListEventBusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEventBusesError::Internal(err.msg))
+                    return RusotoError::service(&res, ListEventBusesError::Internal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1955,10 +2010,13 @@ impl ListEventSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEventSourcesError::Internal(err.msg))
+                    return RusotoError::service(&res, ListEventSourcesError::Internal(err.msg))
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(ListEventSourcesError::OperationDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSourcesError::OperationDisabled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1995,17 +2053,20 @@ impl ListPartnerEventSourceAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListPartnerEventSourceAccountsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPartnerEventSourceAccountsError::Internal(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPartnerEventSourceAccountsError::OperationDisabled(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPartnerEventSourceAccountsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2045,12 +2106,16 @@ impl ListPartnerEventSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListPartnerEventSourcesError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPartnerEventSourcesError::Internal(err.msg),
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(ListPartnerEventSourcesError::OperationDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPartnerEventSourcesError::OperationDisabled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2083,12 +2148,16 @@ impl ListRuleNamesByTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListRuleNamesByTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRuleNamesByTargetError::Internal(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRuleNamesByTargetError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRuleNamesByTargetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2121,10 +2190,10 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListRulesError::Internal(err.msg))
+                    return RusotoError::service(&res, ListRulesError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListRulesError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2157,12 +2226,13 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2195,10 +2265,13 @@ impl ListTargetsByRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTargetsByRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTargetsByRuleError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTargetsByRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsByRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2229,7 +2302,7 @@ impl PutEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(PutEventsError::Internal(err.msg))
+                    return RusotoError::service(&res, PutEventsError::Internal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2261,10 +2334,13 @@ impl PutPartnerEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(PutPartnerEventsError::Internal(err.msg))
+                    return RusotoError::service(&res, PutPartnerEventsError::Internal(err.msg))
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(PutPartnerEventsError::OperationDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutPartnerEventsError::OperationDisabled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2301,18 +2377,25 @@ impl PutPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutPermissionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutPermissionError::Internal(err.msg))
+                    return RusotoError::service(&res, PutPermissionError::Internal(err.msg))
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(PutPermissionError::PolicyLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionError::PolicyLengthExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2355,22 +2438,25 @@ impl PutRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRuleError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, PutRuleError::Internal(err.msg))
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(PutRuleError::InvalidEventPattern(err.msg))
+                    return RusotoError::service(&res, PutRuleError::InvalidEventPattern(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutRuleError::LimitExceeded(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(PutRuleError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, PutRuleError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutRuleError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2413,19 +2499,22 @@ impl PutTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutTargetsError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutTargetsError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutTargetsError::Internal(err.msg))
+                    return RusotoError::service(&res, PutTargetsError::Internal(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutTargetsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutTargetsError::LimitExceeded(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(PutTargetsError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, PutTargetsError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutTargetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutTargetsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2463,15 +2552,19 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RemovePermissionError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemovePermissionError::Internal(err.msg))
+                    return RusotoError::service(&res, RemovePermissionError::Internal(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2509,18 +2602,22 @@ impl RemoveTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RemoveTargetsError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTargetsError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveTargetsError::Internal(err.msg))
+                    return RusotoError::service(&res, RemoveTargetsError::Internal(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(RemoveTargetsError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, RemoveTargetsError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTargetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTargetsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2559,16 +2656,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Internal(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(TagResourceError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2603,12 +2703,13 @@ impl TestEventPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TestEventPatternError::Internal(err.msg))
+                    return RusotoError::service(&res, TestEventPatternError::Internal(err.msg))
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(TestEventPatternError::InvalidEventPattern(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestEventPatternError::InvalidEventPattern(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2645,18 +2746,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Internal(err.msg))
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(UntagResourceError::ManagedRule(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ManagedRule(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2887,6 +2992,7 @@ impl EventBridgeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2902,6 +3008,7 @@ impl EventBridgeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeClient {
         EventBridgeClient { client, region }
     }
@@ -2915,6 +3022,9 @@ impl EventBridge for EventBridgeClient {
         input: ActivateEventSourceRequest,
     ) -> Result<(), RusotoError<ActivateEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ActivateEventSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.ActivateEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2932,6 +3042,9 @@ impl EventBridge for EventBridgeClient {
         input: CreateEventBusRequest,
     ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEventBus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.CreateEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2950,6 +3063,9 @@ impl EventBridge for EventBridgeClient {
         input: CreatePartnerEventSourceRequest,
     ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePartnerEventSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.CreatePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2969,6 +3085,9 @@ impl EventBridge for EventBridgeClient {
         input: DeactivateEventSourceRequest,
     ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeactivateEventSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.DeactivateEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2986,6 +3105,9 @@ impl EventBridge for EventBridgeClient {
         input: DeleteEventBusRequest,
     ) -> Result<(), RusotoError<DeleteEventBusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEventBus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.DeleteEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3003,6 +3125,9 @@ impl EventBridge for EventBridgeClient {
         input: DeletePartnerEventSourceRequest,
     ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePartnerEventSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.DeletePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3020,6 +3145,9 @@ impl EventBridge for EventBridgeClient {
         input: DeleteRuleRequest,
     ) -> Result<(), RusotoError<DeleteRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3037,6 +3165,9 @@ impl EventBridge for EventBridgeClient {
         input: DescribeEventBusRequest,
     ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventBus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.DescribeEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3055,6 +3186,9 @@ impl EventBridge for EventBridgeClient {
         input: DescribeEventSourceRequest,
     ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.DescribeEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3074,6 +3208,9 @@ impl EventBridge for EventBridgeClient {
     ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePartnerEventSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.DescribePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3093,6 +3230,9 @@ impl EventBridge for EventBridgeClient {
         input: DescribeRuleRequest,
     ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.DescribeRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3111,6 +3251,9 @@ impl EventBridge for EventBridgeClient {
         input: DisableRuleRequest,
     ) -> Result<(), RusotoError<DisableRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.DisableRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3128,6 +3271,9 @@ impl EventBridge for EventBridgeClient {
         input: EnableRuleRequest,
     ) -> Result<(), RusotoError<EnableRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.EnableRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3145,6 +3291,9 @@ impl EventBridge for EventBridgeClient {
         input: ListEventBusesRequest,
     ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEventBuses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListEventBuses");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3163,6 +3312,9 @@ impl EventBridge for EventBridgeClient {
         input: ListEventSourcesRequest,
     ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEventSources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListEventSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3184,6 +3336,9 @@ impl EventBridge for EventBridgeClient {
         RusotoError<ListPartnerEventSourceAccountsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPartnerEventSourceAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSourceAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3203,6 +3358,9 @@ impl EventBridge for EventBridgeClient {
         input: ListPartnerEventSourcesRequest,
     ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPartnerEventSources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3222,6 +3380,9 @@ impl EventBridge for EventBridgeClient {
         input: ListRuleNamesByTargetRequest,
     ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRuleNamesByTarget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListRuleNamesByTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3241,6 +3402,9 @@ impl EventBridge for EventBridgeClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3259,6 +3423,9 @@ impl EventBridge for EventBridgeClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3277,6 +3444,9 @@ impl EventBridge for EventBridgeClient {
         input: ListTargetsByRuleRequest,
     ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTargetsByRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSEvents.ListTargetsByRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3295,6 +3465,9 @@ impl EventBridge for EventBridgeClient {
         input: PutEventsRequest,
     ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.PutEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3313,6 +3486,9 @@ impl EventBridge for EventBridgeClient {
         input: PutPartnerEventsRequest,
     ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutPartnerEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.PutPartnerEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3331,6 +3507,9 @@ impl EventBridge for EventBridgeClient {
         input: PutPermissionRequest,
     ) -> Result<(), RusotoError<PutPermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.PutPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3348,6 +3527,9 @@ impl EventBridge for EventBridgeClient {
         input: PutRuleRequest,
     ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.PutRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3366,6 +3548,9 @@ impl EventBridge for EventBridgeClient {
         input: PutTargetsRequest,
     ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutTargets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.PutTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3384,6 +3569,9 @@ impl EventBridge for EventBridgeClient {
         input: RemovePermissionRequest,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemovePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.RemovePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3401,6 +3589,9 @@ impl EventBridge for EventBridgeClient {
         input: RemoveTargetsRequest,
     ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTargets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.RemoveTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3419,6 +3610,9 @@ impl EventBridge for EventBridgeClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3437,6 +3631,9 @@ impl EventBridge for EventBridgeClient {
         input: TestEventPatternRequest,
     ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TestEventPattern");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.TestEventPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3455,6 +3652,9 @@ impl EventBridge for EventBridgeClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSEvents.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3467,3 +3667,378 @@ impl EventBridge for EventBridgeClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon EventBridge API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `EventBridgeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EventBridgeBlockingClient {
+    client: EventBridgeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EventBridgeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> EventBridgeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        EventBridgeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EventBridgeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EventBridgeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient {
+            client: EventBridgeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Activates a partner event source that has been deactivated. Once activated, your matching event bus will start receiving events from the event source.</p>
+    ///
+    /// See `EventBridge::activate_event_source`.
+    pub fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> Result<(), RusotoError<ActivateEventSourceError>> {
+        self.runtime
+            .block_on(self.client.activate_event_source(input))
+    }
+
+    /// <p>Creates a new event bus within your account. This can be a custom event bus which you can use to receive events from your custom applications and services, or it can be a partner event bus which can be matched to a partner event source.</p>
+    ///
+    /// See `EventBridge::create_event_bus`.
+    pub fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
+        self.runtime.block_on(self.client.create_event_bus(input))
+    }
+
+    /// <p>Called by an SaaS partner to create a partner event source. This operation is not used by AWS customers.</p> <p>Each partner event source can be used by one AWS account to create a matching partner event bus in that AWS account. A SaaS partner must create one partner event source for each AWS account that wants to receive those event types. </p> <p>A partner event source creates events based on resources within the SaaS partner's service or application.</p> <p>An AWS account that creates a partner event bus that matches the partner event source can use that event bus to receive events from the partner, and then process them using AWS Events rules and targets.</p> <p>Partner event source names follow this format:</p> <p> <code> <i>partner_name</i>/<i>event_namespace</i>/<i>event_name</i> </code> </p> <p> <i>partner_name</i> is determined during partner registration and identifies the partner to AWS customers. <i>event_namespace</i> is determined by the partner and is a way for the partner to categorize their events. <i>event_name</i> is determined by the partner, and should uniquely identify an event-generating resource within the partner system. The combination of <i>event_namespace</i> and <i>event_name</i> should help AWS customers decide whether to create an event bus to receive these events.</p>
+    ///
+    /// See `EventBridge::create_partner_event_source`.
+    pub fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
+        self.runtime
+            .block_on(self.client.create_partner_event_source(input))
+    }
+
+    /// <p>You can use this operation to temporarily stop receiving events from the specified partner event source. The matching event bus is not deleted. </p> <p>When you deactivate a partner event source, the source goes into PENDING state. If it remains in PENDING state for more than two weeks, it is deleted.</p> <p>To activate a deactivated partner event source, use <a>ActivateEventSource</a>.</p>
+    ///
+    /// See `EventBridge::deactivate_event_source`.
+    pub fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
+        self.runtime
+            .block_on(self.client.deactivate_event_source(input))
+    }
+
+    /// <p>Deletes the specified custom event bus or partner event bus. All rules associated with this event bus need to be deleted. You can't delete your account's default event bus.</p>
+    ///
+    /// See `EventBridge::delete_event_bus`.
+    pub fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> Result<(), RusotoError<DeleteEventBusError>> {
+        self.runtime.block_on(self.client.delete_event_bus(input))
+    }
+
+    /// <p><p>This operation is used by SaaS partners to delete a partner event source. This operation is not used by AWS customers.</p> <p>When you delete an event source, the status of the corresponding partner event bus in the AWS customer account becomes DELETED.</p> <p/></p>
+    ///
+    /// See `EventBridge::delete_partner_event_source`.
+    pub fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
+        self.runtime
+            .block_on(self.client.delete_partner_event_source(input))
+    }
+
+    /// <p>Deletes the specified rule.</p> <p>Before you can delete the rule, you must remove all targets, using <a>RemoveTargets</a>.</p> <p>When you delete a rule, incoming events might continue to match to the deleted rule. Allow a short period of time for changes to take effect.</p> <p>Managed rules are rules created and managed by another AWS service on your behalf. These rules are created by those other AWS services to support functionality in those services. You can delete these rules using the <code>Force</code> option, but you should do so only if you are sure the other service is not still using that rule.</p>
+    ///
+    /// See `EventBridge::delete_rule`.
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<(), RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// <p>Displays details about an event bus in your account. This can include the external AWS accounts that are permitted to write events to your default event bus, and the associated policy. For custom event buses and partner event buses, it displays the name, ARN, policy, state, and creation time.</p> <p> To enable your account to receive events from other accounts on its default event bus, use <a>PutPermission</a>.</p> <p>For more information about partner event buses, see <a>CreateEventBus</a>.</p>
+    ///
+    /// See `EventBridge::describe_event_bus`.
+    pub fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
+        self.runtime.block_on(self.client.describe_event_bus(input))
+    }
+
+    /// <p>This operation lists details about a partner event source that is shared with your account.</p>
+    ///
+    /// See `EventBridge::describe_event_source`.
+    pub fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
+        self.runtime
+            .block_on(self.client.describe_event_source(input))
+    }
+
+    /// <p>An SaaS partner can use this operation to list details about a partner event source that they have created. AWS customers do not use this operation. Instead, AWS customers can use <a>DescribeEventSource</a> to see details about a partner event source that is shared with them.</p>
+    ///
+    /// See `EventBridge::describe_partner_event_source`.
+    pub fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_partner_event_source(input))
+    }
+
+    /// <p>Describes the specified rule.</p> <p>DescribeRule does not list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
+    ///
+    /// See `EventBridge::describe_rule`.
+    pub fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
+        self.runtime.block_on(self.client.describe_rule(input))
+    }
+
+    /// <p>Disables the specified rule. A disabled rule won't match any events, and won't self-trigger if it has a schedule expression.</p> <p>When you disable a rule, incoming events might continue to match to the disabled rule. Allow a short period of time for changes to take effect.</p>
+    ///
+    /// See `EventBridge::disable_rule`.
+    pub fn disable_rule(
+        &self,
+        input: DisableRuleRequest,
+    ) -> Result<(), RusotoError<DisableRuleError>> {
+        self.runtime.block_on(self.client.disable_rule(input))
+    }
+
+    /// <p>Enables the specified rule. If the rule does not exist, the operation fails.</p> <p>When you enable a rule, incoming events might not immediately start matching to a newly enabled rule. Allow a short period of time for changes to take effect.</p>
+    ///
+    /// See `EventBridge::enable_rule`.
+    pub fn enable_rule(
+        &self,
+        input: EnableRuleRequest,
+    ) -> Result<(), RusotoError<EnableRuleError>> {
+        self.runtime.block_on(self.client.enable_rule(input))
+    }
+
+    /// <p>Lists all the event buses in your account, including the default event bus, custom event buses, and partner event buses.</p>
+    ///
+    /// See `EventBridge::list_event_buses`.
+    pub fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
+        self.runtime.block_on(self.client.list_event_buses(input))
+    }
+
+    /// <p>You can use this to see all the partner event sources that have been shared with your AWS account. For more information about partner event sources, see <a>CreateEventBus</a>.</p>
+    ///
+    /// See `EventBridge::list_event_sources`.
+    pub fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
+        self.runtime.block_on(self.client.list_event_sources(input))
+    }
+
+    /// <p>An SaaS partner can use this operation to display the AWS account ID that a particular partner event source name is associated with. This operation is not used by AWS customers.</p>
+    ///
+    /// See `EventBridge::list_partner_event_source_accounts`.
+    pub fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> Result<
+        ListPartnerEventSourceAccountsResponse,
+        RusotoError<ListPartnerEventSourceAccountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_partner_event_source_accounts(input))
+    }
+
+    /// <p>An SaaS partner can use this operation to list all the partner event source names that they have created. This operation is not used by AWS customers.</p>
+    ///
+    /// See `EventBridge::list_partner_event_sources`.
+    pub fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
+        self.runtime
+            .block_on(self.client.list_partner_event_sources(input))
+    }
+
+    /// <p>Lists the rules for the specified target. You can see which of the rules in Amazon EventBridge can invoke a specific target in your account.</p>
+    ///
+    /// See `EventBridge::list_rule_names_by_target`.
+    pub fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
+        self.runtime
+            .block_on(self.client.list_rule_names_by_target(input))
+    }
+
+    /// <p>Lists your Amazon EventBridge rules. You can either list all the rules or you can provide a prefix to match to the rule names.</p> <p>ListRules does not list the targets of a rule. To see the targets associated with a rule, use <a>ListTargetsByRule</a>.</p>
+    ///
+    /// See `EventBridge::list_rules`.
+    pub fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.runtime.block_on(self.client.list_rules(input))
+    }
+
+    /// <p>Displays the tags associated with an EventBridge resource. In EventBridge, rules and event buses can be tagged.</p>
+    ///
+    /// See `EventBridge::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the targets assigned to the specified rule.</p>
+    ///
+    /// See `EventBridge::list_targets_by_rule`.
+    pub fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
+        self.runtime
+            .block_on(self.client.list_targets_by_rule(input))
+    }
+
+    /// <p>Sends custom events to Amazon EventBridge so that they can be matched to rules.</p>
+    ///
+    /// See `EventBridge::put_events`.
+    pub fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
+        self.runtime.block_on(self.client.put_events(input))
+    }
+
+    /// <p>This is used by SaaS partners to write events to a customer's partner event bus. AWS customers do not use this operation.</p>
+    ///
+    /// See `EventBridge::put_partner_events`.
+    pub fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
+        self.runtime.block_on(self.client.put_partner_events(input))
+    }
+
+    /// <p>Running <code>PutPermission</code> permits the specified AWS account or AWS organization to put events to the specified <i>event bus</i>. Amazon EventBridge (CloudWatch Events) rules in your account are triggered by these events arriving to an event bus in your account. </p> <p>For another account to send events to your account, that external account must have an EventBridge rule with your account's event bus as a target.</p> <p>To enable multiple AWS accounts to put events to your event bus, run <code>PutPermission</code> once for each of these accounts. Or, if all the accounts are members of the same AWS organization, you can run <code>PutPermission</code> once specifying <code>Principal</code> as "*" and specifying the AWS organization ID in <code>Condition</code>, to grant permissions to all accounts in that organization.</p> <p>If you grant permissions using an organization, then accounts in that organization must specify a <code>RoleArn</code> with proper permissions when they use <code>PutTarget</code> to add your account's event bus as a target. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>The permission policy on the default event bus cannot exceed 10 KB in size.</p>
+    ///
+    /// See `EventBridge::put_permission`.
+    pub fn put_permission(
+        &self,
+        input: PutPermissionRequest,
+    ) -> Result<(), RusotoError<PutPermissionError>> {
+        self.runtime.block_on(self.client.put_permission(input))
+    }
+
+    /// <p>Creates or updates the specified rule. Rules are enabled by default, or based on value of the state. You can disable a rule using <a>DisableRule</a>.</p> <p>A single rule watches for events from a single event bus. Events generated by AWS services go to your account's default event bus. Events generated by SaaS partner services or applications go to the matching partner event bus. If you have custom applications or services, you can specify whether their events go to your default event bus or a custom event bus that you have created. For more information, see <a>CreateEventBus</a>.</p> <p>If you are updating an existing rule, the rule is replaced with what you specify in this <code>PutRule</code> command. If you omit arguments in <code>PutRule</code>, the old values for those arguments are not kept. Instead, they are replaced with null values.</p> <p>When you create or update a rule, incoming events might not immediately start matching to new or updated rules. Allow a short period of time for changes to take effect.</p> <p>A rule must contain at least an EventPattern or ScheduleExpression. Rules with EventPatterns are triggered when a matching event is observed. Rules with ScheduleExpressions self-trigger based on the given schedule. A rule can have both an EventPattern and a ScheduleExpression, in which case the rule triggers on matching events as well as on a schedule.</p> <p>When you initially create a rule, you can optionally assign one or more tags to the rule. Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only rules with certain tag values. To use the <code>PutRule</code> operation and assign tags, you must have both the <code>events:PutRule</code> and <code>events:TagResource</code> permissions.</p> <p>If you are updating an existing rule, any tags you specify in the <code>PutRule</code> operation are ignored. To update the tags of an existing rule, use <a>TagResource</a> and <a>UntagResource</a>.</p> <p>Most services in AWS treat : or / as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event you want to match.</p> <p>In EventBridge, it is possible to create rules that lead to infinite loops, where a rule is fired repeatedly. For example, a rule might detect that ACLs have changed on an S3 bucket, and trigger software to change them to the desired state. If the rule is not written carefully, the subsequent change to the ACLs fires the rule again, creating an infinite loop.</p> <p>To prevent this, write the rules so that the triggered actions do not re-fire the same rule. For example, your rule could fire only if ACLs are found to be in a bad state, instead of after any change. </p> <p>An infinite loop can quickly cause higher than expected charges. We recommend that you use budgeting, which alerts you when charges exceed your specified limit. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/budgets-managing-costs.html">Managing Your Costs with Budgets</a>.</p>
+    ///
+    /// See `EventBridge::put_rule`.
+    pub fn put_rule(
+        &self,
+        input: PutRuleRequest,
+    ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
+        self.runtime.block_on(self.client.put_rule(input))
+    }
+
+    /// <p>Adds the specified targets to the specified rule, or updates the targets if they are already associated with the rule.</p> <p>Targets are the resources that are invoked when a rule is triggered.</p> <p>You can configure the following as targets for Events:</p> <ul> <li> <p>EC2 instances</p> </li> <li> <p>SSM Run Command</p> </li> <li> <p>SSM Automation</p> </li> <li> <p>AWS Lambda functions</p> </li> <li> <p>Data streams in Amazon Kinesis Data Streams</p> </li> <li> <p>Data delivery streams in Amazon Kinesis Data Firehose</p> </li> <li> <p>Amazon ECS tasks</p> </li> <li> <p>AWS Step Functions state machines</p> </li> <li> <p>AWS Batch jobs</p> </li> <li> <p>AWS CodeBuild projects</p> </li> <li> <p>Pipelines in AWS CodePipeline</p> </li> <li> <p>Amazon Inspector assessment templates</p> </li> <li> <p>Amazon SNS topics</p> </li> <li> <p>Amazon SQS queues, including FIFO queues</p> </li> <li> <p>The default event bus of another AWS account</p> </li> <li> <p>Amazon API Gateway REST APIs</p> </li> </ul> <p>Creating rules with built-in targets is supported only in the AWS Management Console. The built-in targets are <code>EC2 CreateSnapshot API call</code>, <code>EC2 RebootInstances API call</code>, <code>EC2 StopInstances API call</code>, and <code>EC2 TerminateInstances API call</code>. </p> <p>For some target types, <code>PutTargets</code> provides target-specific parameters. If the target is a Kinesis data stream, you can optionally specify which shard the event goes to by using the <code>KinesisParameters</code> argument. To invoke a command on multiple EC2 instances with one rule, you can use the <code>RunCommandParameters</code> field.</p> <p>To be able to make API calls against the resources that you own, Amazon EventBridge (CloudWatch Events) needs the appropriate permissions. For AWS Lambda and Amazon SNS resources, EventBridge relies on resource-based policies. For EC2 instances, Kinesis data streams, AWS Step Functions state machines and API Gateway REST APIs, EventBridge relies on IAM roles that you specify in the <code>RoleARN</code> argument in <code>PutTargets</code>. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/auth-and-access-control-eventbridge.html">Authentication and Access Control</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>If another AWS account is in the same region and has granted you permission (using <code>PutPermission</code>), you can send events to that account. Set that account's event bus as a target of the rules in your account. To send the matched events to the other account, specify that account's event bus as the <code>Arn</code> value when you run <code>PutTargets</code>. If your account sends events to another account, your account is charged for each sent event. Each event sent to another account is charged as a custom event. The account receiving the event is not charged. For more information, see <a href="https://aws.amazon.com/eventbridge/pricing/">Amazon EventBridge (CloudWatch Events) Pricing</a>.</p> <note> <p> <code>Input</code>, <code>InputPath</code>, and <code>InputTransformer</code> are not available with <code>PutTarget</code> if the target is an event bus of a different AWS account.</p> </note> <p>If you are setting the event bus of another account as the target, and that account granted permission to your account through an organization instead of directly by the account ID, then you must specify a <code>RoleArn</code> with proper permissions in the <code>Target</code> structure. For more information, see <a href="https://docs.aws.amazon.com/eventbridge/latest/userguide/eventbridge-cross-account-event-delivery.html">Sending and Receiving Events Between AWS Accounts</a> in the <i>Amazon EventBridge User Guide</i>.</p> <p>For more information about enabling cross-account events, see <a>PutPermission</a>.</p> <p> <b>Input</b>, <b>InputPath</b>, and <b>InputTransformer</b> are mutually exclusive and optional parameters of a target. When a rule is triggered due to a matched event:</p> <ul> <li> <p>If none of the following arguments are specified for a target, then the entire event is passed to the target in JSON format (unless the target is Amazon EC2 Run Command or Amazon ECS task, in which case nothing from the event is passed to the target).</p> </li> <li> <p>If <b>Input</b> is specified in the form of valid JSON, then the matched event is overridden with this constant.</p> </li> <li> <p>If <b>InputPath</b> is specified in the form of JSONPath (for example, <code>$.detail</code>), then only the part of the event specified in the path is passed to the target (for example, only the detail part of the event is passed).</p> </li> <li> <p>If <b>InputTransformer</b> is specified, then one or more specified JSONPaths are extracted from the event and used as values in a template that you specify as the input to the target.</p> </li> </ul> <p>When you specify <code>InputPath</code> or <code>InputTransformer</code>, you must use JSON dot notation, not bracket notation.</p> <p>When you add targets to a rule and the associated rule triggers soon after, new or updated targets might not be immediately invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is non-zero in the response and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+    ///
+    /// See `EventBridge::put_targets`.
+    pub fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
+        self.runtime.block_on(self.client.put_targets(input))
+    }
+
+    /// <p>Revokes the permission of another AWS account to be able to put events to the specified event bus. Specify the account to revoke by the <code>StatementId</code> value that you associated with the account when you granted it permission with <code>PutPermission</code>. You can find the <code>StatementId</code> by using <a>DescribeEventBus</a>.</p>
+    ///
+    /// See `EventBridge::remove_permission`.
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// <p>Removes the specified targets from the specified rule. When the rule is triggered, those targets are no longer be invoked.</p> <p>When you remove a target, when the associated rule triggers, removed targets might continue to be invoked. Allow a short period of time for changes to take effect.</p> <p>This action can partially fail if too many requests are made at the same time. If that happens, <code>FailedEntryCount</code> is non-zero in the response and each entry in <code>FailedEntries</code> provides the ID of the failed target and the error code.</p>
+    ///
+    /// See `EventBridge::remove_targets`.
+    pub fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
+        self.runtime.block_on(self.client.remove_targets(input))
+    }
+
+    /// <p>Assigns one or more tags (key-value pairs) to the specified EventBridge resource. Tags can help you organize and categorize your resources. You can also use them to scope user permissions by granting a user permission to access or change only resources with certain tag values. In EventBridge, rules and event buses can be tagged.</p> <p>Tags don't have any semantic meaning to AWS and are interpreted strictly as strings of characters.</p> <p>You can use the <code>TagResource</code> action with a resource that already has tags. If you specify a new tag key, this tag is appended to the list of tags associated with the resource. If you specify a tag key that is already associated with the resource, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource.</p>
+    ///
+    /// See `EventBridge::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Tests whether the specified event pattern matches the provided event.</p> <p>Most services in AWS treat : or / as the same character in Amazon Resource Names (ARNs). However, EventBridge uses an exact match in event patterns and rules. Be sure to use the correct ARN characters when creating event patterns so that they match the ARN syntax in the event you want to match.</p>
+    ///
+    /// See `EventBridge::test_event_pattern`.
+    pub fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
+        self.runtime.block_on(self.client.test_event_pattern(input))
+    }
+
+    /// <p>Removes one or more tags from the specified EventBridge resource. In Amazon EventBridge (CloudWatch Events, rules and event buses can be tagged.</p>
+    ///
+    /// See `EventBridge::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
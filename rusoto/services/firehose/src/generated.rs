@@ -1614,20 +1614,28 @@ impl CreateDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeliveryStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::InvalidKMSResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeliveryStreamError::InvalidKMSResource(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeliveryStreamError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeliveryStreamError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1662,12 +1670,16 @@ impl DeleteDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeliveryStreamError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeliveryStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1698,9 +1710,10 @@ impl DescribeDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeliveryStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1756,19 +1769,22 @@ impl ListTagsForDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForDeliveryStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForDeliveryStreamError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForDeliveryStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1806,16 +1822,16 @@ impl PutRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, PutRecordError::InvalidArgument(err.msg))
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(PutRecordError::InvalidKMSResource(err.msg))
+                    return RusotoError::service(&res, PutRecordError::InvalidKMSResource(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutRecordError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRecordError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, PutRecordError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1854,16 +1870,28 @@ impl PutRecordBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordBatchError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRecordBatchError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(PutRecordBatchError::InvalidKMSResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRecordBatchError::InvalidKMSResource(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordBatchError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRecordBatchError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRecordBatchError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRecordBatchError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1906,27 +1934,32 @@ impl StartDeliveryStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDeliveryStreamEncryptionError::InvalidArgument(err.msg),
                     )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDeliveryStreamEncryptionError::InvalidKMSResource(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDeliveryStreamEncryptionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDeliveryStreamEncryptionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartDeliveryStreamEncryptionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDeliveryStreamEncryptionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1976,22 +2009,26 @@ impl StopDeliveryStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDeliveryStreamEncryptionError::InvalidArgument(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopDeliveryStreamEncryptionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDeliveryStreamEncryptionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopDeliveryStreamEncryptionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDeliveryStreamEncryptionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2034,16 +2071,28 @@ impl TagDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagDeliveryStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagDeliveryStreamError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagDeliveryStreamError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagDeliveryStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2082,18 +2131,28 @@ impl UntagDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagDeliveryStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagDeliveryStreamError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagDeliveryStreamError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagDeliveryStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2132,18 +2191,28 @@ impl UpdateDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDestinationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDestinationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateDestinationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDestinationError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDestinationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDestinationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDestinationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDestinationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2257,6 +2326,7 @@ impl KinesisFirehoseClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2272,6 +2342,7 @@ impl KinesisFirehoseClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisFirehoseClient {
         KinesisFirehoseClient { client, region }
     }
@@ -2285,6 +2356,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: CreateDeliveryStreamInput,
     ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDeliveryStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.CreateDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2303,6 +2377,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: DeleteDeliveryStreamInput,
     ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDeliveryStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.DeleteDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2321,6 +2398,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: DescribeDeliveryStreamInput,
     ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDeliveryStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Firehose_20150804.DescribeDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2340,6 +2420,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: ListDeliveryStreamsInput,
     ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDeliveryStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Firehose_20150804.ListDeliveryStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2358,6 +2441,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: ListTagsForDeliveryStreamInput,
     ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForDeliveryStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Firehose_20150804.ListTagsForDeliveryStream",
@@ -2380,6 +2466,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: PutRecordInput,
     ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRecord");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.PutRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2398,6 +2487,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: PutRecordBatchInput,
     ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRecordBatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.PutRecordBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2417,6 +2509,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
     ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDeliveryStreamEncryption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Firehose_20150804.StartDeliveryStreamEncryption",
@@ -2440,6 +2535,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
     ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopDeliveryStreamEncryption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Firehose_20150804.StopDeliveryStreamEncryption",
@@ -2462,6 +2560,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: TagDeliveryStreamInput,
     ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagDeliveryStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.TagDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2480,6 +2581,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: UntagDeliveryStreamInput,
     ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagDeliveryStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.UntagDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2498,6 +2602,9 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: UpdateDestinationInput,
     ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Firehose_20150804.UpdateDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2510,3 +2617,187 @@ impl KinesisFirehose for KinesisFirehoseClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDestinationOutput, _>()
     }
 }
+
+/// A blocking client for the Firehose API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisFirehoseClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisFirehoseBlockingClient {
+    client: KinesisFirehoseClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisFirehoseBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisFirehoseBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisFirehoseBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisFirehoseBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisFirehoseBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient {
+            client: KinesisFirehoseClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a Kinesis Data Firehose delivery stream.</p> <p>By default, you can create up to 50 delivery streams per AWS Region.</p> <p>This is an asynchronous operation that immediately returns. The initial status of the delivery stream is <code>CREATING</code>. After the delivery stream is created, its status is <code>ACTIVE</code> and it now accepts data. If the delivery stream creation fails, the status transitions to <code>CREATING_FAILED</code>. Attempts to send data to a delivery stream that is not in the <code>ACTIVE</code> state cause an exception. To check the state of a delivery stream, use <a>DescribeDeliveryStream</a>.</p> <p>If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <code>CreateDeliveryStream</code> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it.</p> <p>A Kinesis Data Firehose delivery stream can be configured to receive records directly from providers using <a>PutRecord</a> or <a>PutRecordBatch</a>, or it can be configured to use an existing Kinesis stream as its source. To specify a Kinesis data stream as input, set the <code>DeliveryStreamType</code> parameter to <code>KinesisStreamAsSource</code>, and provide the Kinesis stream Amazon Resource Name (ARN) and role ARN in the <code>KinesisStreamSourceConfiguration</code> parameter.</p> <p>To create a delivery stream with server-side encryption (SSE) enabled, include <a>DeliveryStreamEncryptionConfigurationInput</a> in your request. This is optional. You can also invoke <a>StartDeliveryStreamEncryption</a> to turn on SSE for an existing delivery stream that doesn't have SSE enabled.</p> <p>A delivery stream is configured with a single destination: Amazon S3, Amazon ES, Amazon Redshift, or Splunk. You must specify only one of the following destination configuration parameters: <code>ExtendedS3DestinationConfiguration</code>, <code>S3DestinationConfiguration</code>, <code>ElasticsearchDestinationConfiguration</code>, <code>RedshiftDestinationConfiguration</code>, or <code>SplunkDestinationConfiguration</code>.</p> <p>When you specify <code>S3DestinationConfiguration</code>, you can also provide the following optional values: BufferingHints, <code>EncryptionConfiguration</code>, and <code>CompressionFormat</code>. By default, if no <code>BufferingHints</code> value is provided, Kinesis Data Firehose buffers data up to 5 MB or for 5 minutes, whichever condition is satisfied first. <code>BufferingHints</code> is a hint, so there are some cases where the service cannot adhere to these conditions strictly. For example, record boundaries might be such that the size is a little over or under the configured buffering size. By default, no encryption is performed. We strongly recommend that you enable encryption to ensure secure data storage in Amazon S3.</p> <p>A few notes about Amazon Redshift as a destination:</p> <ul> <li> <p>An Amazon Redshift destination requires an S3 bucket as intermediate location. Kinesis Data Firehose first delivers data to Amazon S3 and then uses <code>COPY</code> syntax to load data into an Amazon Redshift table. This is specified in the <code>RedshiftDestinationConfiguration.S3Configuration</code> parameter.</p> </li> <li> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified in <code>RedshiftDestinationConfiguration.S3Configuration</code> because the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket doesn't support these compression formats.</p> </li> <li> <p>We strongly recommend that you use the user name and password you provide exclusively with Kinesis Data Firehose, and that the permissions for the account are restricted for Amazon Redshift <code>INSERT</code> permissions.</p> </li> </ul> <p>Kinesis Data Firehose assumes the IAM role that is configured as part of the destination. The role should allow the Kinesis Data Firehose principal to assume the role, and the role should have permissions that allow the service to deliver the data. For more information, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/controlling-access.html#using-iam-s3">Grant Kinesis Data Firehose Access to an Amazon S3 Destination</a> in the <i>Amazon Kinesis Data Firehose Developer Guide</i>.</p>
+    ///
+    /// See `KinesisFirehose::create_delivery_stream`.
+    pub fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.create_delivery_stream(input))
+    }
+
+    /// <p>Deletes a delivery stream and its data.</p> <p>To check the state of a delivery stream, use <a>DescribeDeliveryStream</a>. You can delete a delivery stream only if it is in one of the following states: <code>ACTIVE</code>, <code>DELETING</code>, <code>CREATING_FAILED</code>, or <code>DELETING_FAILED</code>. You can't delete a delivery stream that is in the <code>CREATING</code> state. While the deletion request is in process, the delivery stream is in the <code>DELETING</code> state.</p> <p>While the delivery stream is in the <code>DELETING</code> state, the service might continue to accept records, but it doesn't make any guarantees with respect to delivering the data. Therefore, as a best practice, first stop any applications that are sending records before you delete a delivery stream.</p>
+    ///
+    /// See `KinesisFirehose::delete_delivery_stream`.
+    pub fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.delete_delivery_stream(input))
+    }
+
+    /// <p>Describes the specified delivery stream and its status. For example, after your delivery stream is created, call <code>DescribeDeliveryStream</code> to see whether the delivery stream is <code>ACTIVE</code> and therefore ready for data to be sent to it. </p> <p>If the status of a delivery stream is <code>CREATING_FAILED</code>, this status doesn't change, and you can't invoke <a>CreateDeliveryStream</a> again on it. However, you can invoke the <a>DeleteDeliveryStream</a> operation to delete it. If the status is <code>DELETING_FAILED</code>, you can force deletion by invoking <a>DeleteDeliveryStream</a> again but with <a>DeleteDeliveryStreamInput$AllowForceDelete</a> set to true.</p>
+    ///
+    /// See `KinesisFirehose::describe_delivery_stream`.
+    pub fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.describe_delivery_stream(input))
+    }
+
+    /// <p>Lists your delivery streams in alphabetical order of their names.</p> <p>The number of delivery streams might be too large to return using a single call to <code>ListDeliveryStreams</code>. You can limit the number of delivery streams returned, using the <code>Limit</code> parameter. To determine whether there are more delivery streams to list, check the value of <code>HasMoreDeliveryStreams</code> in the output. If there are more delivery streams to list, you can request them by calling this operation again and setting the <code>ExclusiveStartDeliveryStreamName</code> parameter to the name of the last delivery stream returned in the last call.</p>
+    ///
+    /// See `KinesisFirehose::list_delivery_streams`.
+    pub fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
+        self.runtime
+            .block_on(self.client.list_delivery_streams(input))
+    }
+
+    /// <p>Lists the tags for the specified delivery stream. This operation has a limit of five transactions per second per account. </p>
+    ///
+    /// See `KinesisFirehose::list_tags_for_delivery_stream`.
+    pub fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_delivery_stream(input))
+    }
+
+    /// <p><p>Writes a single data record into an Amazon Kinesis Data Firehose delivery stream. To write multiple data records into a delivery stream, use <a>PutRecordBatch</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits and how to request an increase, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>. </p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it can be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <code>PutRecord</code> operation returns a <code>RecordId</code>, which is a unique string assigned to each record. Producer applications can use this ID for purposes such as auditability and investigation.</p> <p>If the <code>PutRecord</code> operation throws a <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream. </p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it tries to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
+    ///
+    /// See `KinesisFirehose::put_record`.
+    pub fn put_record(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+        self.runtime.block_on(self.client.put_record(input))
+    }
+
+    /// <p><p>Writes multiple data records into a delivery stream in a single call, which can achieve higher throughput per producer than when writing single records. To write single data records into a delivery stream, use <a>PutRecord</a>. Applications using these operations are referred to as producers.</p> <p>By default, each delivery stream can take in up to 2,000 transactions per second, 5,000 records per second, or 5 MB per second. If you use <a>PutRecord</a> and <a>PutRecordBatch</a>, the limits are an aggregate across these two operations for each delivery stream. For more information about limits, see <a href="https://docs.aws.amazon.com/firehose/latest/dev/limits.html">Amazon Kinesis Data Firehose Limits</a>.</p> <p>Each <a>PutRecordBatch</a> request supports up to 500 records. Each record in the request can be as large as 1,000 KB (before 64-bit encoding), up to a limit of 4 MB for the entire request. These limits cannot be changed.</p> <p>You must specify the name of the delivery stream and the data record when using <a>PutRecord</a>. The data record consists of a data blob that can be up to 1,000 KB in size, and any kind of data. For example, it could be a segment from a log file, geographic location data, website clickstream data, and so on.</p> <p>Kinesis Data Firehose buffers records before delivering them to the destination. To disambiguate the data blobs at the destination, a common solution is to use delimiters in the data, such as a newline (<code>\n</code>) or some other character unique within the data. This allows the consumer application to parse individual data items when reading the data from the destination.</p> <p>The <a>PutRecordBatch</a> response includes a count of failed records, <code>FailedPutCount</code>, and an array of responses, <code>RequestResponses</code>. Even if the <a>PutRecordBatch</a> call succeeds, the value of <code>FailedPutCount</code> may be greater than 0, indicating that there are records for which the operation didn&#39;t succeed. Each entry in the <code>RequestResponses</code> array provides additional information about the processed record. It directly correlates with a record in the request array using the same ordering, from the top to the bottom. The response array always includes the same number of records as the request array. <code>RequestResponses</code> includes both successfully and unsuccessfully processed records. Kinesis Data Firehose tries to process all records in each <a>PutRecordBatch</a> request. A single record failure does not stop the processing of subsequent records. </p> <p>A successfully processed record includes a <code>RecordId</code> value, which is unique for the record. An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error, and is one of the following values: <code>ServiceUnavailableException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the error.</p> <p>If there is an internal server error or a timeout, the write might have completed or it might have failed. If <code>FailedPutCount</code> is greater than 0, retry the request, resending only those records that might have failed processing. This minimizes the possible duplicate records and also reduces the total bytes sent (and corresponding charges). We recommend that you handle any duplicates at the destination.</p> <p>If <a>PutRecordBatch</a> throws <code>ServiceUnavailableException</code>, back off and retry. If the exception persists, it is possible that the throughput limits have been exceeded for the delivery stream.</p> <p>Data records sent to Kinesis Data Firehose are stored for 24 hours from the time they are added to a delivery stream as it attempts to send the records to the destination. If the destination is unreachable for more than 24 hours, the data is no longer available.</p> <important> <p>Don&#39;t concatenate two or more base64 strings to form the data fields of your records. Instead, concatenate the raw data, then perform base64 encoding.</p> </important></p>
+    ///
+    /// See `KinesisFirehose::put_record_batch`.
+    pub fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
+        self.runtime.block_on(self.client.put_record_batch(input))
+    }
+
+    /// <p>Enables server-side encryption (SSE) for the delivery stream. </p> <p>This operation is asynchronous. It returns immediately. When you invoke it, Kinesis Data Firehose first sets the encryption status of the stream to <code>ENABLING</code>, and then to <code>ENABLED</code>. The encryption status of a delivery stream is the <code>Status</code> property in <a>DeliveryStreamEncryptionConfiguration</a>. If the operation fails, the encryption status changes to <code>ENABLING_FAILED</code>. You can continue to read and write data to your delivery stream while the encryption status is <code>ENABLING</code>, but the data is not encrypted. It can take up to 5 seconds after the encryption status changes to <code>ENABLED</code> before all records written to the delivery stream are encrypted. To find out whether a record or a batch of records was encrypted, check the response elements <a>PutRecordOutput$Encrypted</a> and <a>PutRecordBatchOutput$Encrypted</a>, respectively.</p> <p>To check the encryption status of a delivery stream, use <a>DescribeDeliveryStream</a>.</p> <p>Even if encryption is currently enabled for a delivery stream, you can still invoke this operation on it to change the ARN of the CMK or both its type and ARN. If you invoke this method to change the CMK, and the old CMK is of type <code>CUSTOMER_MANAGED_CMK</code>, Kinesis Data Firehose schedules the grant it had on the old CMK for retirement. If the new CMK is of type <code>CUSTOMER_MANAGED_CMK</code>, Kinesis Data Firehose creates a grant that enables it to use the new CMK to encrypt and decrypt data and to manage the grant.</p> <p>If a delivery stream already has encryption enabled and then you invoke this operation to change the ARN of the CMK or both its type and ARN and you get <code>ENABLING_FAILED</code>, this only means that the attempt to change the CMK failed. In this case, encryption remains enabled with the old CMK.</p> <p>If the encryption status of your delivery stream is <code>ENABLING_FAILED</code>, you can invoke this operation again with a valid CMK. The CMK must be enabled and the key policy mustn't explicitly deny the permission for Kinesis Data Firehose to invoke KMS encrypt and decrypt operations.</p> <p>You can enable SSE for a delivery stream only if it's a delivery stream that uses <code>DirectPut</code> as its source. </p> <p>The <code>StartDeliveryStreamEncryption</code> and <code>StopDeliveryStreamEncryption</code> operations have a combined limit of 25 calls per delivery stream per 24 hours. For example, you reach the limit if you call <code>StartDeliveryStreamEncryption</code> 13 times and <code>StopDeliveryStreamEncryption</code> 12 times for the same delivery stream in a 24-hour period.</p>
+    ///
+    /// See `KinesisFirehose::start_delivery_stream_encryption`.
+    pub fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_delivery_stream_encryption(input))
+    }
+
+    /// <p>Disables server-side encryption (SSE) for the delivery stream. </p> <p>This operation is asynchronous. It returns immediately. When you invoke it, Kinesis Data Firehose first sets the encryption status of the stream to <code>DISABLING</code>, and then to <code>DISABLED</code>. You can continue to read and write data to your stream while its status is <code>DISABLING</code>. It can take up to 5 seconds after the encryption status changes to <code>DISABLED</code> before all records written to the delivery stream are no longer subject to encryption. To find out whether a record or a batch of records was encrypted, check the response elements <a>PutRecordOutput$Encrypted</a> and <a>PutRecordBatchOutput$Encrypted</a>, respectively.</p> <p>To check the encryption state of a delivery stream, use <a>DescribeDeliveryStream</a>. </p> <p>If SSE is enabled using a customer managed CMK and then you invoke <code>StopDeliveryStreamEncryption</code>, Kinesis Data Firehose schedules the related KMS grant for retirement and then retires it after it ensures that it is finished delivering records to the destination.</p> <p>The <code>StartDeliveryStreamEncryption</code> and <code>StopDeliveryStreamEncryption</code> operations have a combined limit of 25 calls per delivery stream per 24 hours. For example, you reach the limit if you call <code>StartDeliveryStreamEncryption</code> 13 times and <code>StopDeliveryStreamEncryption</code> 12 times for the same delivery stream in a 24-hour period.</p>
+    ///
+    /// See `KinesisFirehose::stop_delivery_stream_encryption`.
+    pub fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_delivery_stream_encryption(input))
+    }
+
+    /// <p>Adds or updates tags for the specified delivery stream. A tag is a key-value pair that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. Tags are metadata. For example, you can add friendly names and descriptions or other types of information that can help you distinguish the delivery stream. For more information about tags, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>Each delivery stream can have up to 50 tags. </p> <p>This operation has a limit of five transactions per second per account. </p>
+    ///
+    /// See `KinesisFirehose::tag_delivery_stream`.
+    pub fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.tag_delivery_stream(input))
+    }
+
+    /// <p>Removes tags from the specified delivery stream. Removed tags are deleted, and you can't recover them after this operation successfully completes.</p> <p>If you specify a tag that doesn't exist, the operation ignores it.</p> <p>This operation has a limit of five transactions per second per account. </p>
+    ///
+    /// See `KinesisFirehose::untag_delivery_stream`.
+    pub fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.untag_delivery_stream(input))
+    }
+
+    /// <p>Updates the specified destination of the specified delivery stream.</p> <p>Use this operation to change the destination type (for example, to replace the Amazon S3 destination with Amazon Redshift) or change the parameters associated with a destination (for example, to change the bucket name of the Amazon S3 destination). The update might not occur immediately. The target delivery stream remains active while the configurations are updated, so data writes to the delivery stream can continue during this process. The updated configurations are usually effective within a few minutes.</p> <p>Switching between Amazon ES and other services is not supported. For an Amazon ES destination, you can only update to another Amazon ES destination.</p> <p>If the destination type is the same, Kinesis Data Firehose merges the configuration parameters specified with the destination configuration that already exists on the delivery stream. If any of the parameters are not specified in the call, the existing values are retained. For example, in the Amazon S3 destination, if <a>EncryptionConfiguration</a> is not specified, then the existing <code>EncryptionConfiguration</code> is maintained on the destination.</p> <p>If the destination type is not the same, for example, changing the destination from Amazon S3 to Amazon Redshift, Kinesis Data Firehose does not merge any parameters. In this case, all parameters must be specified.</p> <p>Kinesis Data Firehose uses <code>CurrentDeliveryStreamVersionId</code> to avoid race conditions and conflicting merges. This is a required field, and the service updates the configuration only if the existing configuration has a version ID that matches. After the update is applied successfully, the version ID is updated, and can be retrieved using <a>DescribeDeliveryStream</a>. Use the new version ID to set <code>CurrentDeliveryStreamVersionId</code> in the next call.</p>
+    ///
+    /// See `KinesisFirehose::update_destination`.
+    pub fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
+        self.runtime.block_on(self.client.update_destination(input))
+    }
+}
@@ -597,20 +597,28 @@ impl AssociateAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateAdminAccountError::InternalError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateAdminAccountError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateAdminAccountError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateAdminAccountError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -647,19 +655,22 @@ impl DeleteNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationChannelError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationChannelError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNotificationChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -695,13 +706,13 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeletePolicyError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::InternalError(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::InvalidOperation(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -737,19 +748,22 @@ impl DisassociateAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateAdminAccountError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateAdminAccountError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateAdminAccountError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -785,13 +799,19 @@ impl GetAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAdminAccountError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetAdminAccountError::InternalError(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetAdminAccountError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAdminAccountError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAdminAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAdminAccountError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -825,12 +845,16 @@ impl GetComplianceDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetComplianceDetailError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetComplianceDetailError::InternalError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetComplianceDetailError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetComplianceDetailError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -865,19 +889,22 @@ impl GetNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetNotificationChannelError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNotificationChannelError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetNotificationChannelError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNotificationChannelError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNotificationChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -915,16 +942,16 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetPolicyError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::InternalError(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::InvalidOperation(err.msg))
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidType(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::InvalidType(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -961,15 +988,22 @@ impl GetProtectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProtectionStatusError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProtectionStatusError::InternalError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetProtectionStatusError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProtectionStatusError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProtectionStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetProtectionStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1003,12 +1037,16 @@ impl ListComplianceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListComplianceStatusError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceStatusError::InternalError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListComplianceStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1041,10 +1079,16 @@ impl ListMemberAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListMemberAccountsError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMemberAccountsError::InternalError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMemberAccountsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMemberAccountsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1081,16 +1125,16 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListPoliciesError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::InternalError(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidOperation(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::InvalidOperation(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPoliciesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1129,20 +1173,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1179,19 +1231,22 @@ impl PutNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutNotificationChannelError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutNotificationChannelError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutNotificationChannelError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutNotificationChannelError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutNotificationChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutNotificationChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1233,22 +1288,22 @@ impl PutPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutPolicyError::InternalError(err.msg))
+                    return RusotoError::service(&res, PutPolicyError::InternalError(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, PutPolicyError::InvalidInput(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service(&res, PutPolicyError::InvalidOperation(err.msg))
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidType(err.msg))
+                    return RusotoError::service(&res, PutPolicyError::InvalidType(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutPolicyError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutPolicyError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1291,19 +1346,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalError(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(TagResourceError::InvalidOperation(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidOperation(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1343,16 +1398,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalError(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidOperation(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1492,6 +1553,7 @@ impl FmsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1507,6 +1569,7 @@ impl FmsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> FmsClient {
         FmsClient { client, region }
     }
@@ -1520,6 +1583,9 @@ impl Fms for FmsClient {
         input: AssociateAdminAccountRequest,
     ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateAdminAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.AssociateAdminAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1536,6 +1602,9 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNotificationChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteNotificationChannel");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -1552,6 +1621,9 @@ impl Fms for FmsClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1568,6 +1640,9 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateAdminAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.DisassociateAdminAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -1583,6 +1658,9 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAdminAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.GetAdminAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -1600,6 +1678,9 @@ impl Fms for FmsClient {
         input: GetComplianceDetailRequest,
     ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComplianceDetail");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.GetComplianceDetail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1617,6 +1698,9 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetNotificationChannel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.GetNotificationChannel");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -1635,6 +1719,9 @@ impl Fms for FmsClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.GetPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1653,6 +1740,9 @@ impl Fms for FmsClient {
         input: GetProtectionStatusRequest,
     ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetProtectionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.GetProtectionStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1671,6 +1761,9 @@ impl Fms for FmsClient {
         input: ListComplianceStatusRequest,
     ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListComplianceStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.ListComplianceStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1690,6 +1783,9 @@ impl Fms for FmsClient {
         input: ListMemberAccountsRequest,
     ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMemberAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.ListMemberAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1708,6 +1804,9 @@ impl Fms for FmsClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.ListPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1726,6 +1825,9 @@ impl Fms for FmsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSFMS_20180101.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1744,6 +1846,9 @@ impl Fms for FmsClient {
         input: PutNotificationChannelRequest,
     ) -> Result<(), RusotoError<PutNotificationChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutNotificationChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.PutNotificationChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1761,6 +1866,9 @@ impl Fms for FmsClient {
         input: PutPolicyRequest,
     ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.PutPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1779,6 +1887,9 @@ impl Fms for FmsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1797,6 +1908,9 @@ impl Fms for FmsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSFMS_20180101.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1809,3 +1923,229 @@ impl Fms for FmsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the FMS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `FmsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct FmsBlockingClient {
+    client: FmsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl FmsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> FmsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        FmsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> FmsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        FmsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> FmsBlockingClient {
+        FmsBlockingClient {
+            client: FmsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Sets the AWS Firewall Manager administrator account. AWS Firewall Manager must be associated with the master account of your AWS organization or associated with a member account that has the appropriate permissions. If the account ID that you submit is not an AWS Organizations master account, AWS Firewall Manager will set the appropriate permissions for the given member account.</p> <p>The account that you associate with AWS Firewall Manager is called the AWS Firewall Manager administrator account. </p>
+    ///
+    /// See `Fms::associate_admin_account`.
+    pub fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
+        self.runtime
+            .block_on(self.client.associate_admin_account(input))
+    }
+
+    /// <p>Deletes an AWS Firewall Manager association with the IAM role and the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
+    ///
+    /// See `Fms::delete_notification_channel`.
+    pub fn delete_notification_channel(
+        &self,
+    ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_channel())
+    }
+
+    /// <p>Permanently deletes an AWS Firewall Manager policy. </p>
+    ///
+    /// See `Fms::delete_policy`.
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// <p>Disassociates the account that has been set as the AWS Firewall Manager administrator account. To set a different account as the administrator account, you must submit an <code>AssociateAdminAccount</code> request.</p>
+    ///
+    /// See `Fms::disassociate_admin_account`.
+    pub fn disassociate_admin_account(
+        &self,
+    ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
+        self.runtime
+            .block_on(self.client.disassociate_admin_account())
+    }
+
+    /// <p>Returns the AWS Organizations master account that is associated with AWS Firewall Manager as the AWS Firewall Manager administrator.</p>
+    ///
+    /// See `Fms::get_admin_account`.
+    pub fn get_admin_account(
+        &self,
+    ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
+        self.runtime.block_on(self.client.get_admin_account())
+    }
+
+    /// <p>Returns detailed compliance information about the specified member account. Details include resources that are in and out of compliance with the specified policy. Resources are considered noncompliant for AWS WAF and Shield Advanced policies if the specified policy has not been applied to them. Resources are considered noncompliant for security group policies if they are in scope of the policy, they violate one or more of the policy rules, and remediation is disabled or not possible. </p>
+    ///
+    /// See `Fms::get_compliance_detail`.
+    pub fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
+        self.runtime
+            .block_on(self.client.get_compliance_detail(input))
+    }
+
+    /// <p>Information about the Amazon Simple Notification Service (SNS) topic that is used to record AWS Firewall Manager SNS logs.</p>
+    ///
+    /// See `Fms::get_notification_channel`.
+    pub fn get_notification_channel(
+        &self,
+    ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
+        self.runtime
+            .block_on(self.client.get_notification_channel())
+    }
+
+    /// <p>Returns information about the specified AWS Firewall Manager policy.</p>
+    ///
+    /// See `Fms::get_policy`.
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// <p>If you created a Shield Advanced policy, returns policy-level attack summary information in the event of a potential DDoS attack. Other policy types are currently unsupported.</p>
+    ///
+    /// See `Fms::get_protection_status`.
+    pub fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
+        self.runtime
+            .block_on(self.client.get_protection_status(input))
+    }
+
+    /// <p>Returns an array of <code>PolicyComplianceStatus</code> objects in the response. Use <code>PolicyComplianceStatus</code> to get a summary of which member accounts are protected by the specified policy. </p>
+    ///
+    /// See `Fms::list_compliance_status`.
+    pub fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
+        self.runtime
+            .block_on(self.client.list_compliance_status(input))
+    }
+
+    /// <p>Returns a <code>MemberAccounts</code> object that lists the member accounts in the administrator's AWS organization.</p> <p>The <code>ListMemberAccounts</code> must be submitted by the account that is set as the AWS Firewall Manager administrator.</p>
+    ///
+    /// See `Fms::list_member_accounts`.
+    pub fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
+        self.runtime
+            .block_on(self.client.list_member_accounts(input))
+    }
+
+    /// <p>Returns an array of <code>PolicySummary</code> objects in the response.</p>
+    ///
+    /// See `Fms::list_policies`.
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// <p>Retrieves the list of tags for the specified AWS resource. </p>
+    ///
+    /// See `Fms::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Designates the IAM role and Amazon Simple Notification Service (SNS) topic that AWS Firewall Manager uses to record SNS logs.</p>
+    ///
+    /// See `Fms::put_notification_channel`.
+    pub fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> Result<(), RusotoError<PutNotificationChannelError>> {
+        self.runtime
+            .block_on(self.client.put_notification_channel(input))
+    }
+
+    /// <p>Creates an AWS Firewall Manager policy.</p> <p>Firewall Manager provides the following types of policies: </p> <ul> <li> <p>A Shield Advanced policy, which applies Shield Advanced protection to specified accounts and resources</p> </li> <li> <p>An AWS WAF policy (type WAFV2), which defines rule groups to run first in the corresponding AWS WAF web ACL and rule groups to run last in the web ACL.</p> </li> <li> <p>An AWS WAF Classic policy (type WAF), which defines a rule group. </p> </li> <li> <p>A security group policy, which manages VPC security groups across your AWS organization. </p> </li> </ul> <p>Each policy is specific to one of the types. If you want to enforce more than one policy type across accounts, create multiple policies. You can create multiple policies for each type.</p> <p>You must be subscribed to Shield Advanced to create a Shield Advanced policy. For more information about subscribing to Shield Advanced, see <a href="https://docs.aws.amazon.com/waf/latest/DDOSAPIReference/API_CreateSubscription.html">CreateSubscription</a>.</p>
+    ///
+    /// See `Fms::put_policy`.
+    pub fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
+        self.runtime.block_on(self.client.put_policy(input))
+    }
+
+    /// <p>Adds one or more tags to an AWS resource.</p>
+    ///
+    /// See `Fms::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from an AWS resource.</p>
+    ///
+    /// See `Fms::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
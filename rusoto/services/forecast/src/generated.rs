@@ -1492,13 +1492,16 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1538,21 +1541,34 @@ impl CreateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1594,27 +1610,34 @@ impl CreateDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDatasetImportJobError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1656,21 +1679,25 @@ impl CreateForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateForecastError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateForecastError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateForecastError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateForecastError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateForecastError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1712,29 +1739,34 @@ impl CreateForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateForecastExportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateForecastExportJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateForecastExportJobError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateForecastExportJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateForecastExportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1778,21 +1810,25 @@ impl CreatePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreatePredictorError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePredictorError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePredictorError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePredictorError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreatePredictorError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePredictorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1830,13 +1866,16 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1872,13 +1911,22 @@ impl DeleteDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetGroupError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1914,17 +1962,22 @@ impl DeleteDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetImportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetImportJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1960,13 +2013,16 @@ impl DeleteForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteForecastError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteForecastError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteForecastError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2002,19 +2058,22 @@ impl DeleteForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteForecastExportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteForecastExportJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteForecastExportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2050,13 +2109,16 @@ impl DeletePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeletePredictorError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePredictorError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeletePredictorError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePredictorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2090,10 +2152,13 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeDatasetError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2126,12 +2191,16 @@ impl DescribeDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2164,14 +2233,16 @@ impl DescribeDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetImportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2204,10 +2275,13 @@ impl DescribeForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeForecastError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeForecastError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2240,14 +2314,16 @@ impl DescribeForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeForecastExportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeForecastExportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeForecastExportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeForecastExportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2280,10 +2356,16 @@ impl DescribePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePredictorError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePredictorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2318,13 +2400,22 @@ impl GetAccuracyMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccuracyMetricsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccuracyMetricsError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccuracyMetricsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2356,7 +2447,10 @@ impl ListDatasetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetGroupsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2388,12 +2482,16 @@ impl ListDatasetImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetImportJobsError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetImportJobsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2424,7 +2522,7 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2456,12 +2554,16 @@ impl ListForecastExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListForecastExportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListForecastExportJobsError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListForecastExportJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListForecastExportJobsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2494,10 +2596,13 @@ impl ListForecastsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListForecastsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListForecastsError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListForecastsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListForecastsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2530,10 +2635,13 @@ impl ListPredictorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPredictorsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListPredictorsError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPredictorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPredictorsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2566,12 +2674,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2606,13 +2718,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2646,10 +2758,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2684,13 +2799,22 @@ impl UpdateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatasetGroupError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatasetGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2905,6 +3029,7 @@ impl ForecastClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2920,6 +3045,7 @@ impl ForecastClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ForecastClient {
         ForecastClient { client, region }
     }
@@ -2933,6 +3059,9 @@ impl Forecast for ForecastClient {
         input: CreateDatasetRequest,
     ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.CreateDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2951,6 +3080,9 @@ impl Forecast for ForecastClient {
         input: CreateDatasetGroupRequest,
     ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDatasetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2969,6 +3101,9 @@ impl Forecast for ForecastClient {
         input: CreateDatasetImportJobRequest,
     ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDatasetImportJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2988,6 +3123,9 @@ impl Forecast for ForecastClient {
         input: CreateForecastRequest,
     ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateForecast");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.CreateForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3006,6 +3144,9 @@ impl Forecast for ForecastClient {
         input: CreateForecastExportJobRequest,
     ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateForecastExportJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.CreateForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3025,6 +3166,9 @@ impl Forecast for ForecastClient {
         input: CreatePredictorRequest,
     ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePredictor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.CreatePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3043,6 +3187,9 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetRequest,
     ) -> Result<(), RusotoError<DeleteDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DeleteDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3060,6 +3207,9 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetGroupRequest,
     ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDatasetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3077,6 +3227,9 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetImportJobRequest,
     ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDatasetImportJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3094,6 +3247,9 @@ impl Forecast for ForecastClient {
         input: DeleteForecastRequest,
     ) -> Result<(), RusotoError<DeleteForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteForecast");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3111,6 +3267,9 @@ impl Forecast for ForecastClient {
         input: DeleteForecastExportJobRequest,
     ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteForecastExportJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3128,6 +3287,9 @@ impl Forecast for ForecastClient {
         input: DeletePredictorRequest,
     ) -> Result<(), RusotoError<DeletePredictorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePredictor");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DeletePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3145,6 +3307,9 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetRequest,
     ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDataset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DescribeDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3163,6 +3328,9 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetGroupRequest,
     ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDatasetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3182,6 +3350,9 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetImportJobRequest,
     ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDatasetImportJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3201,6 +3372,9 @@ impl Forecast for ForecastClient {
         input: DescribeForecastRequest,
     ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeForecast");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3220,6 +3394,9 @@ impl Forecast for ForecastClient {
     ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeForecastExportJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3239,6 +3416,9 @@ impl Forecast for ForecastClient {
         input: DescribePredictorRequest,
     ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePredictor");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.DescribePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3257,6 +3437,9 @@ impl Forecast for ForecastClient {
         input: GetAccuracyMetricsRequest,
     ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAccuracyMetrics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.GetAccuracyMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3275,6 +3458,9 @@ impl Forecast for ForecastClient {
         input: ListDatasetGroupsRequest,
     ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatasetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3293,6 +3479,9 @@ impl Forecast for ForecastClient {
         input: ListDatasetImportJobsRequest,
     ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatasetImportJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetImportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3312,6 +3501,9 @@ impl Forecast for ForecastClient {
         input: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatasets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListDatasets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3330,6 +3522,9 @@ impl Forecast for ForecastClient {
         input: ListForecastExportJobsRequest,
     ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListForecastExportJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListForecastExportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3349,6 +3544,9 @@ impl Forecast for ForecastClient {
         input: ListForecastsRequest,
     ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListForecasts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListForecasts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3367,6 +3565,9 @@ impl Forecast for ForecastClient {
         input: ListPredictorsRequest,
     ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPredictors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListPredictors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3385,6 +3586,9 @@ impl Forecast for ForecastClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3403,6 +3607,9 @@ impl Forecast for ForecastClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3421,6 +3628,9 @@ impl Forecast for ForecastClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonForecast.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3439,6 +3649,9 @@ impl Forecast for ForecastClient {
         input: UpdateDatasetGroupRequest,
     ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDatasetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecast.UpdateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3451,3 +3664,359 @@ impl Forecast for ForecastClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDatasetGroupResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Forecast Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ForecastClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ForecastBlockingClient {
+    client: ForecastClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ForecastBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ForecastBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ForecastBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ForecastBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ForecastBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ForecastBlockingClient {
+        ForecastBlockingClient {
+            client: ForecastClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Creates an Amazon Forecast dataset. The information about the dataset that you provide helps Forecast understand how to consume the data for model training. This includes the following:</p> <ul> <li> <p> <i> <code>DataFrequency</code> </i> - How frequently your historical time-series data is collected.</p> </li> <li> <p> <i> <code>Domain</code> </i> and <i> <code>DatasetType</code> </i> - Each dataset has an associated dataset domain and a type within the domain. Amazon Forecast provides a list of predefined domains and types within each domain. For each unique dataset domain and type within the domain, Amazon Forecast requires your data to include a minimum set of predefined fields.</p> </li> <li> <p> <i> <code>Schema</code> </i> - A schema specifies the fields in the dataset, including the field name and data type.</p> </li> </ul> <p>After creating a dataset, you import your training data into it and add the dataset to a dataset group. You use the dataset group to create a predictor. For more information, see <a>howitworks-datasets-groups</a>.</p> <p>To get a list of all your datasets, use the <a>ListDatasets</a> operation.</p> <p>For example Forecast datasets, see the <a href="https://github.com/aws-samples/amazon-forecast-samples">Amazon Forecast Sample GitHub repository</a>.</p> <note> <p>The <code>Status</code> of a dataset must be <code>ACTIVE</code> before you can import training data. Use the <a>DescribeDataset</a> operation to get the status.</p> </note></p>
+    ///
+    /// See `Forecast::create_dataset`.
+    pub fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.runtime.block_on(self.client.create_dataset(input))
+    }
+
+    /// <p><p>Creates a dataset group, which holds a collection of related datasets. You can add datasets to the dataset group when you create the dataset group, or later by using the <a>UpdateDatasetGroup</a> operation.</p> <p>After creating a dataset group and adding datasets, you use the dataset group when you create a predictor. For more information, see <a>howitworks-datasets-groups</a>.</p> <p>To get a list of all your datasets groups, use the <a>ListDatasetGroups</a> operation.</p> <note> <p>The <code>Status</code> of a dataset group must be <code>ACTIVE</code> before you can create use the dataset group to create a predictor. To get the status, use the <a>DescribeDatasetGroup</a> operation.</p> </note></p>
+    ///
+    /// See `Forecast::create_dataset_group`.
+    pub fn create_dataset_group(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_group(input))
+    }
+
+    /// <p>Imports your training data to an Amazon Forecast dataset. You provide the location of your training data in an Amazon Simple Storage Service (Amazon S3) bucket and the Amazon Resource Name (ARN) of the dataset that you want to import the data to.</p> <p>You must specify a <a>DataSource</a> object that includes an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the data, as Amazon Forecast makes a copy of your data and processes it in an internal AWS system. For more information, see <a>aws-forecast-iam-roles</a>.</p> <p>The training data must be in CSV format. The delimiter must be a comma (,).</p> <p>You can specify the path to a specific CSV file, the S3 bucket, or to a folder in the S3 bucket. For the latter two cases, Amazon Forecast imports all files up to the limit of 10,000 files.</p> <p>Because dataset imports are not aggregated, your most recent dataset import is the one that is used when training a predictor or generating a forecast. Make sure that your most recent dataset import contains all of the data you want to model off of, and not just the new data collected since the previous import.</p> <p>To get a list of all your dataset import jobs, filtered by specified criteria, use the <a>ListDatasetImportJobs</a> operation.</p>
+    ///
+    /// See `Forecast::create_dataset_import_job`.
+    pub fn create_dataset_import_job(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_import_job(input))
+    }
+
+    /// <p><p>Creates a forecast for each item in the <code>TARGET<em>TIME</em>SERIES</code> dataset that was used to train the predictor. This is known as inference. To retrieve the forecast for a single item at low latency, use the operation. To export the complete forecast into your Amazon Simple Storage Service (Amazon S3) bucket, use the <a>CreateForecastExportJob</a> operation.</p> <p>The range of the forecast is determined by the <code>ForecastHorizon</code> value, which you specify in the <a>CreatePredictor</a> request. When you query a forecast, you can request a specific date range within the forecast.</p> <p>To get a list of all your forecasts, use the <a>ListForecasts</a> operation.</p> <note> <p>The forecasts generated by Amazon Forecast are in the same time zone as the dataset that was used to create the predictor.</p> </note> <p>For more information, see <a>howitworks-forecast</a>.</p> <note> <p>The <code>Status</code> of the forecast must be <code>ACTIVE</code> before you can query or export the forecast. Use the <a>DescribeForecast</a> operation to get the status.</p> </note></p>
+    ///
+    /// See `Forecast::create_forecast`.
+    pub fn create_forecast(
+        &self,
+        input: CreateForecastRequest,
+    ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
+        self.runtime.block_on(self.client.create_forecast(input))
+    }
+
+    /// <p><p>Exports a forecast created by the <a>CreateForecast</a> operation to your Amazon Simple Storage Service (Amazon S3) bucket. The forecast file name will match the following conventions:</p> <p>&lt;ForecastExportJobName&gt;<em>&lt;ExportTimestamp&gt;</em>&lt;PartNumber&gt;</p> <p>where the &lt;ExportTimestamp&gt; component is in Java SimpleDateFormat (yyyy-MM-ddTHH-mm-ssZ).</p> <p>You must specify a <a>DataDestination</a> object that includes an AWS Identity and Access Management (IAM) role that Amazon Forecast can assume to access the Amazon S3 bucket. For more information, see <a>aws-forecast-iam-roles</a>.</p> <p>For more information, see <a>howitworks-forecast</a>.</p> <p>To get a list of all your forecast export jobs, use the <a>ListForecastExportJobs</a> operation.</p> <note> <p>The <code>Status</code> of the forecast export job must be <code>ACTIVE</code> before you can access the forecast in your Amazon S3 bucket. To get the status, use the <a>DescribeForecastExportJob</a> operation.</p> </note></p>
+    ///
+    /// See `Forecast::create_forecast_export_job`.
+    pub fn create_forecast_export_job(
+        &self,
+        input: CreateForecastExportJobRequest,
+    ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
+        self.runtime
+            .block_on(self.client.create_forecast_export_job(input))
+    }
+
+    /// <p><p>Creates an Amazon Forecast predictor.</p> <p>In the request, you provide a dataset group and either specify an algorithm or let Amazon Forecast choose the algorithm for you using AutoML. If you specify an algorithm, you also can override algorithm-specific hyperparameters.</p> <p>Amazon Forecast uses the chosen algorithm to train a model using the latest version of the datasets in the specified dataset group. The result is called a predictor. You then generate a forecast using the <a>CreateForecast</a> operation.</p> <p>After training a model, the <code>CreatePredictor</code> operation also evaluates it. To see the evaluation metrics, use the <a>GetAccuracyMetrics</a> operation. Always review the evaluation metrics before deciding to use the predictor to generate a forecast.</p> <p>Optionally, you can specify a featurization configuration to fill and aggregate the data fields in the <code>TARGET<em>TIME</em>SERIES</code> dataset to improve model training. For more information, see <a>FeaturizationConfig</a>.</p> <p>For RELATED<em>TIME</em>SERIES datasets, <code>CreatePredictor</code> verifies that the <code>DataFrequency</code> specified when the dataset was created matches the <code>ForecastFrequency</code>. TARGET<em>TIME</em>SERIES datasets don&#39;t have this restriction. Amazon Forecast also verifies the delimiter and timestamp format. For more information, see <a>howitworks-datasets-groups</a>.</p> <p> <b>AutoML</b> </p> <p>If you want Amazon Forecast to evaluate each algorithm and choose the one that minimizes the <code>objective function</code>, set <code>PerformAutoML</code> to <code>true</code>. The <code>objective function</code> is defined as the mean of the weighted p10, p50, and p90 quantile losses. For more information, see <a>EvaluationResult</a>.</p> <p>When AutoML is enabled, the following properties are disallowed:</p> <ul> <li> <p> <code>AlgorithmArn</code> </p> </li> <li> <p> <code>HPOConfig</code> </p> </li> <li> <p> <code>PerformHPO</code> </p> </li> <li> <p> <code>TrainingParameters</code> </p> </li> </ul> <p>To get a list of all of your predictors, use the <a>ListPredictors</a> operation.</p> <note> <p>Before you can use the predictor to create a forecast, the <code>Status</code> of the predictor must be <code>ACTIVE</code>, signifying that training has completed. To get the status, use the <a>DescribePredictor</a> operation.</p> </note></p>
+    ///
+    /// See `Forecast::create_predictor`.
+    pub fn create_predictor(
+        &self,
+        input: CreatePredictorRequest,
+    ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
+        self.runtime.block_on(self.client.create_predictor(input))
+    }
+
+    /// <p><p>Deletes an Amazon Forecast dataset that was created using the <a>CreateDataset</a> operation. You can only delete datasets that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status use the <a>DescribeDataset</a> operation.</p> <note> <p>Forecast does not automatically update any dataset groups that contain the deleted dataset. In order to update the dataset group, use the operation, omitting the deleted dataset&#39;s ARN.</p> </note></p>
+    ///
+    /// See `Forecast::delete_dataset`.
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// <p>Deletes a dataset group created using the <a>CreateDatasetGroup</a> operation. You can only delete dataset groups that have a status of <code>ACTIVE</code>, <code>CREATE_FAILED</code>, or <code>UPDATE_FAILED</code>. To get the status, use the <a>DescribeDatasetGroup</a> operation.</p> <p>This operation deletes only the dataset group, not the datasets in the group.</p>
+    ///
+    /// See `Forecast::delete_dataset_group`.
+    pub fn delete_dataset_group(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_group(input))
+    }
+
+    /// <p>Deletes a dataset import job created using the <a>CreateDatasetImportJob</a> operation. You can delete only dataset import jobs that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeDatasetImportJob</a> operation.</p>
+    ///
+    /// See `Forecast::delete_dataset_import_job`.
+    pub fn delete_dataset_import_job(
+        &self,
+        input: DeleteDatasetImportJobRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_import_job(input))
+    }
+
+    /// <p>Deletes a forecast created using the <a>CreateForecast</a> operation. You can delete only forecasts that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeForecast</a> operation.</p> <p>You can't delete a forecast while it is being exported. After a forecast is deleted, you can no longer query the forecast.</p>
+    ///
+    /// See `Forecast::delete_forecast`.
+    pub fn delete_forecast(
+        &self,
+        input: DeleteForecastRequest,
+    ) -> Result<(), RusotoError<DeleteForecastError>> {
+        self.runtime.block_on(self.client.delete_forecast(input))
+    }
+
+    /// <p>Deletes a forecast export job created using the <a>CreateForecastExportJob</a> operation. You can delete only export jobs that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribeForecastExportJob</a> operation.</p>
+    ///
+    /// See `Forecast::delete_forecast_export_job`.
+    pub fn delete_forecast_export_job(
+        &self,
+        input: DeleteForecastExportJobRequest,
+    ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
+        self.runtime
+            .block_on(self.client.delete_forecast_export_job(input))
+    }
+
+    /// <p>Deletes a predictor created using the <a>CreatePredictor</a> operation. You can delete only predictor that have a status of <code>ACTIVE</code> or <code>CREATE_FAILED</code>. To get the status, use the <a>DescribePredictor</a> operation.</p>
+    ///
+    /// See `Forecast::delete_predictor`.
+    pub fn delete_predictor(
+        &self,
+        input: DeletePredictorRequest,
+    ) -> Result<(), RusotoError<DeletePredictorError>> {
+        self.runtime.block_on(self.client.delete_predictor(input))
+    }
+
+    /// <p><p>Describes an Amazon Forecast dataset created using the <a>CreateDataset</a> operation.</p> <p>In addition to listing the parameters specified in the <code>CreateDataset</code> request, this operation includes the following dataset properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> </ul></p>
+    ///
+    /// See `Forecast::describe_dataset`.
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// <p><p>Describes a dataset group created using the <a>CreateDatasetGroup</a> operation.</p> <p>In addition to listing the parameters provided in the <code>CreateDatasetGroup</code> request, this operation includes the following properties:</p> <ul> <li> <p> <code>DatasetArns</code> - The datasets belonging to the group.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> </ul></p>
+    ///
+    /// See `Forecast::describe_dataset_group`.
+    pub fn describe_dataset_group(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_group(input))
+    }
+
+    /// <p><p>Describes a dataset import job created using the <a>CreateDatasetImportJob</a> operation.</p> <p>In addition to listing the parameters provided in the <code>CreateDatasetImportJob</code> request, this operation includes the following properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>DataSize</code> </p> </li> <li> <p> <code>FieldStatistics</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    ///
+    /// See `Forecast::describe_dataset_import_job`.
+    pub fn describe_dataset_import_job(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_import_job(input))
+    }
+
+    /// <p><p>Describes a forecast created using the <a>CreateForecast</a> operation.</p> <p>In addition to listing the properties provided in the <code>CreateForecast</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>DatasetGroupArn</code> - The dataset group that provided the training data.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    ///
+    /// See `Forecast::describe_forecast`.
+    pub fn describe_forecast(
+        &self,
+        input: DescribeForecastRequest,
+    ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
+        self.runtime.block_on(self.client.describe_forecast(input))
+    }
+
+    /// <p><p>Describes a forecast export job created using the <a>CreateForecastExportJob</a> operation.</p> <p>In addition to listing the properties provided by the user in the <code>CreateForecastExportJob</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    ///
+    /// See `Forecast::describe_forecast_export_job`.
+    pub fn describe_forecast_export_job(
+        &self,
+        input: DescribeForecastExportJobRequest,
+    ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_forecast_export_job(input))
+    }
+
+    /// <p><p>Describes a predictor created using the <a>CreatePredictor</a> operation.</p> <p>In addition to listing the properties provided in the <code>CreatePredictor</code> request, this operation lists the following properties:</p> <ul> <li> <p> <code>DatasetImportJobArns</code> - The dataset import jobs used to import training data.</p> </li> <li> <p> <code>AutoMLAlgorithmArns</code> - If AutoML is performed, the algorithms that were evaluated.</p> </li> <li> <p> <code>CreationTime</code> </p> </li> <li> <p> <code>LastModificationTime</code> </p> </li> <li> <p> <code>Status</code> </p> </li> <li> <p> <code>Message</code> - If an error occurred, information about the error.</p> </li> </ul></p>
+    ///
+    /// See `Forecast::describe_predictor`.
+    pub fn describe_predictor(
+        &self,
+        input: DescribePredictorRequest,
+    ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
+        self.runtime.block_on(self.client.describe_predictor(input))
+    }
+
+    /// <p><p>Provides metrics on the accuracy of the models that were trained by the <a>CreatePredictor</a> operation. Use metrics to see how well the model performed and to decide whether to use the predictor to generate a forecast. For more information, see <a>metrics</a>.</p> <p>This operation generates metrics for each backtest window that was evaluated. The number of backtest windows (<code>NumberOfBacktestWindows</code>) is specified using the <a>EvaluationParameters</a> object, which is optionally included in the <code>CreatePredictor</code> request. If <code>NumberOfBacktestWindows</code> isn&#39;t specified, the number defaults to one.</p> <p>The parameters of the <code>filling</code> method determine which items contribute to the metrics. If you want all items to contribute, specify <code>zero</code>. If you want only those items that have complete data in the range being evaluated to contribute, specify <code>nan</code>. For more information, see <a>FeaturizationMethod</a>.</p> <note> <p>Before you can get accuracy metrics, the <code>Status</code> of the predictor must be <code>ACTIVE</code>, signifying that training has completed. To get the status, use the <a>DescribePredictor</a> operation.</p> </note></p>
+    ///
+    /// See `Forecast::get_accuracy_metrics`.
+    pub fn get_accuracy_metrics(
+        &self,
+        input: GetAccuracyMetricsRequest,
+    ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_accuracy_metrics(input))
+    }
+
+    /// <p>Returns a list of dataset groups created using the <a>CreateDatasetGroup</a> operation. For each dataset group, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the dataset group ARN with the <a>DescribeDatasetGroup</a> operation.</p>
+    ///
+    /// See `Forecast::list_dataset_groups`.
+    pub fn list_dataset_groups(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_groups(input))
+    }
+
+    /// <p>Returns a list of dataset import jobs created using the <a>CreateDatasetImportJob</a> operation. For each import job, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the ARN with the <a>DescribeDatasetImportJob</a> operation. You can filter the list by providing an array of <a>Filter</a> objects.</p>
+    ///
+    /// See `Forecast::list_dataset_import_jobs`.
+    pub fn list_dataset_import_jobs(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_import_jobs(input))
+    }
+
+    /// <p>Returns a list of datasets created using the <a>CreateDataset</a> operation. For each dataset, a summary of its properties, including its Amazon Resource Name (ARN), is returned. To retrieve the complete set of properties, use the ARN with the <a>DescribeDataset</a> operation.</p>
+    ///
+    /// See `Forecast::list_datasets`.
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// <p>Returns a list of forecast export jobs created using the <a>CreateForecastExportJob</a> operation. For each forecast export job, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). To retrieve the complete set of properties, use the ARN with the <a>DescribeForecastExportJob</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
+    ///
+    /// See `Forecast::list_forecast_export_jobs`.
+    pub fn list_forecast_export_jobs(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_forecast_export_jobs(input))
+    }
+
+    /// <p>Returns a list of forecasts created using the <a>CreateForecast</a> operation. For each forecast, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). To retrieve the complete set of properties, specify the ARN with the <a>DescribeForecast</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
+    ///
+    /// See `Forecast::list_forecasts`.
+    pub fn list_forecasts(
+        &self,
+        input: ListForecastsRequest,
+    ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
+        self.runtime.block_on(self.client.list_forecasts(input))
+    }
+
+    /// <p>Returns a list of predictors created using the <a>CreatePredictor</a> operation. For each predictor, this operation returns a summary of its properties, including its Amazon Resource Name (ARN). You can retrieve the complete set of properties by using the ARN with the <a>DescribePredictor</a> operation. You can filter the list using an array of <a>Filter</a> objects.</p>
+    ///
+    /// See `Forecast::list_predictors`.
+    pub fn list_predictors(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
+        self.runtime.block_on(self.client.list_predictors(input))
+    }
+
+    /// <p>Lists the tags for an Amazon Forecast resource.</p>
+    ///
+    /// See `Forecast::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are also deleted.</p>
+    ///
+    /// See `Forecast::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes the specified tags from a resource.</p>
+    ///
+    /// See `Forecast::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Replaces the datasets in a dataset group with the specified datasets.</p> <note> <p>The <code>Status</code> of the dataset group must be <code>ACTIVE</code> before you can use the dataset group to create a predictor. Use the <a>DescribeDatasetGroup</a> operation to get the status.</p> </note></p>
+    ///
+    /// See `Forecast::update_dataset_group`.
+    pub fn update_dataset_group(
+        &self,
+        input: UpdateDatasetGroupRequest,
+    ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.update_dataset_group(input))
+    }
+}
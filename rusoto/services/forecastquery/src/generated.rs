@@ -127,19 +127,25 @@ impl QueryForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(QueryForecastError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, QueryForecastError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(QueryForecastError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        QueryForecastError::InvalidNextToken(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(QueryForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, QueryForecastError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(QueryForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, QueryForecastError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        QueryForecastError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -188,6 +194,7 @@ impl ForecastQueryClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -203,6 +210,7 @@ impl ForecastQueryClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ForecastQueryClient {
         ForecastQueryClient { client, region }
     }
@@ -216,6 +224,9 @@ impl ForecastQuery for ForecastQueryClient {
         input: QueryForecastRequest,
     ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("QueryForecast");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonForecastRuntime.QueryForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -228,3 +239,63 @@ impl ForecastQuery for ForecastQueryClient {
         proto::json::ResponsePayload::new(&response).deserialize::<QueryForecastResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Forecast Query Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ForecastQueryClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ForecastQueryBlockingClient {
+    client: ForecastQueryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ForecastQueryBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ForecastQueryBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ForecastQueryBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ForecastQueryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ForecastQueryBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient {
+            client: ForecastQueryClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Retrieves a forecast for a single item, filtered by the supplied criteria.</p> <p>The criteria is a key-value pair. The key is either <code>item<em>id</code> (or the equivalent non-timestamp, non-target field) from the <code>TARGET</em>TIME<em>SERIES</code> dataset, or one of the forecast dimensions specified as part of the <code>FeaturizationConfig</code> object.</p> <p>By default, <code>QueryForecast</code> returns the complete date range for the filtered forecast. You can request a specific date range.</p> <p>To get the full forecast, use the &lt;a href=&quot;https://docs.aws.amazon.com/en</em>us/forecast/latest/dg/API_CreateForecastExportJob.html&quot;&gt;CreateForecastExportJob</a> operation.</p> <note> <p>The forecasts generated by Amazon Forecast are in the same timezone as the dataset that was used to create the predictor.</p> </note></p>
+    ///
+    /// See `ForecastQuery::query_forecast`.
+    pub fn query_forecast(
+        &self,
+        input: QueryForecastRequest,
+    ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
+        self.runtime.block_on(self.client.query_forecast(input))
+    }
+}
@@ -1447,10 +1447,16 @@ impl BatchCreateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchCreateVariableError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateVariableError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchCreateVariableError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreateVariableError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1483,10 +1489,13 @@ impl BatchGetVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetVariableError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetVariableError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchGetVariableError::Throttling(err.msg))
+                    return RusotoError::service(&res, BatchGetVariableError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1521,17 +1530,22 @@ impl CreateDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1567,13 +1581,19 @@ impl CreateModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateModelVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateModelVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateModelVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateModelVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1607,10 +1627,10 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRuleError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1643,10 +1663,10 @@ impl CreateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateVariableError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateVariableError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateVariableError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateVariableError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1681,13 +1701,13 @@ impl DeleteDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDetectorError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteDetectorError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDetectorError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteDetectorError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDetectorError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1725,20 +1745,28 @@ impl DeleteDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorVersionError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1773,10 +1801,10 @@ impl DeleteEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEventError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteEventError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteEventError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteEventError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1811,13 +1839,16 @@ impl DeleteRuleVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRuleVersionError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteRuleVersionError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRuleVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRuleVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteRuleVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1853,13 +1884,19 @@ impl DescribeDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDetectorError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDetectorError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1895,17 +1932,22 @@ impl DescribeModelVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeModelVersionsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeModelVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeModelVersionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1941,13 +1983,19 @@ impl GetDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDetectorVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDetectorVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDetectorVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDetectorVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetDetectorVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1983,13 +2031,13 @@ impl GetDetectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDetectorsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDetectorsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDetectorsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetDetectorsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDetectorsError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetDetectorsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2025,13 +2073,19 @@ impl GetExternalModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetExternalModelsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExternalModelsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetExternalModelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExternalModelsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetExternalModelsError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetExternalModelsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2067,13 +2121,19 @@ impl GetModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetModelVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetModelVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetModelVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetModelVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2109,13 +2169,13 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetModelsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetModelsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetModelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetModelsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetModelsError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetModelsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2151,13 +2211,13 @@ impl GetOutcomesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutcomesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetOutcomesError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOutcomesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetOutcomesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetOutcomesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetOutcomesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2193,13 +2253,16 @@ impl GetPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetPredictionError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetPredictionError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPredictionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPredictionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPredictionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetPredictionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2235,13 +2298,13 @@ impl GetRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRulesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetRulesError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetRulesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRulesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetRulesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2277,13 +2340,13 @@ impl GetVariablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetVariablesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetVariablesError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVariablesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetVariablesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetVariablesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetVariablesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2317,10 +2380,10 @@ impl PutDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutDetectorError::InternalServer(err.msg))
+                    return RusotoError::service(&res, PutDetectorError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutDetectorError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutDetectorError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2353,10 +2416,13 @@ impl PutExternalModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutExternalModelError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutExternalModelError::InternalServer(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutExternalModelError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutExternalModelError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2389,10 +2455,10 @@ impl PutModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutModelError::InternalServer(err.msg))
+                    return RusotoError::service(&res, PutModelError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutModelError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutModelError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2425,10 +2491,10 @@ impl PutOutcomeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PutOutcomeError::InternalServer(err.msg))
+                    return RusotoError::service(&res, PutOutcomeError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutOutcomeError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutOutcomeError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2463,17 +2529,22 @@ impl UpdateDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2509,14 +2580,16 @@ impl UpdateDetectorVersionMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDetectorVersionMetadataError::InternalServer(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionMetadataError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorVersionMetadataError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2553,19 +2626,22 @@ impl UpdateDetectorVersionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorVersionStatusError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDetectorVersionStatusError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorVersionStatusError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2601,13 +2677,19 @@ impl UpdateModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateModelVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateModelVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateModelVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateModelVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2643,13 +2725,19 @@ impl UpdateRuleMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleMetadataError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleMetadataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateRuleMetadataError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2685,13 +2773,19 @@ impl UpdateRuleVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleVersionError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateRuleVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2727,13 +2821,16 @@ impl UpdateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateVariableError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateVariableError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateVariableError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVariableError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateVariableError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateVariableError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2966,6 +3063,7 @@ impl FraudDetectorClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2981,6 +3079,7 @@ impl FraudDetectorClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> FraudDetectorClient {
         FraudDetectorClient { client, region }
     }
@@ -2994,6 +3093,9 @@ impl FraudDetector for FraudDetectorClient {
         input: BatchCreateVariableRequest,
     ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchCreateVariable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.BatchCreateVariable",
@@ -3015,6 +3117,9 @@ impl FraudDetector for FraudDetectorClient {
         input: BatchGetVariableRequest,
     ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetVariable");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.BatchGetVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3033,6 +3138,9 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateDetectorVersionRequest,
     ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDetectorVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.CreateDetectorVersion",
@@ -3054,6 +3162,9 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateModelVersionRequest,
     ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateModelVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.CreateModelVersion",
@@ -3075,6 +3186,9 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3093,6 +3207,9 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateVariableRequest,
     ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateVariable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3111,6 +3228,9 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteDetectorRequest,
     ) -> Result<DeleteDetectorResult, RusotoError<DeleteDetectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDetector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3129,6 +3249,9 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteDetectorVersionRequest,
     ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDetectorVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DeleteDetectorVersion",
@@ -3150,6 +3273,9 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteEventRequest,
     ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEvent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3168,6 +3294,9 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteRuleVersionRequest,
     ) -> Result<DeleteRuleVersionResult, RusotoError<DeleteRuleVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRuleVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DeleteRuleVersion",
@@ -3189,6 +3318,9 @@ impl FraudDetector for FraudDetectorClient {
         input: DescribeDetectorRequest,
     ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDetector");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DescribeDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3207,6 +3339,9 @@ impl FraudDetector for FraudDetectorClient {
         input: DescribeModelVersionsRequest,
     ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeModelVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DescribeModelVersions",
@@ -3228,6 +3363,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetDetectorVersionRequest,
     ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDetectorVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.GetDetectorVersion",
@@ -3249,6 +3387,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetDetectorsRequest,
     ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDetectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetDetectors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3267,6 +3408,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetExternalModelsRequest,
     ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetExternalModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.GetExternalModels",
@@ -3288,6 +3432,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetModelVersionRequest,
     ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetModelVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModelVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3306,6 +3453,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetModelsRequest,
     ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3324,6 +3474,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetOutcomesRequest,
     ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOutcomes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetOutcomes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3342,6 +3495,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetPredictionRequest,
     ) -> Result<GetPredictionResult, RusotoError<GetPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPrediction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3360,6 +3516,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetRulesRequest,
     ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3378,6 +3537,9 @@ impl FraudDetector for FraudDetectorClient {
         input: GetVariablesRequest,
     ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetVariables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetVariables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3396,6 +3558,9 @@ impl FraudDetector for FraudDetectorClient {
         input: PutDetectorRequest,
     ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutDetector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3414,6 +3579,9 @@ impl FraudDetector for FraudDetectorClient {
         input: PutExternalModelRequest,
     ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutExternalModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutExternalModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3432,6 +3600,9 @@ impl FraudDetector for FraudDetectorClient {
         input: PutModelRequest,
     ) -> Result<PutModelResult, RusotoError<PutModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3450,6 +3621,9 @@ impl FraudDetector for FraudDetectorClient {
         input: PutOutcomeRequest,
     ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutOutcome");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutOutcome");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3468,6 +3642,9 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateDetectorVersionRequest,
     ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDetectorVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateDetectorVersion",
@@ -3490,6 +3667,9 @@ impl FraudDetector for FraudDetectorClient {
     ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDetectorVersionMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateDetectorVersionMetadata",
@@ -3513,6 +3693,9 @@ impl FraudDetector for FraudDetectorClient {
     ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDetectorVersionStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateDetectorVersionStatus",
@@ -3535,6 +3718,9 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateModelVersionRequest,
     ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateModelVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateModelVersion",
@@ -3556,6 +3742,9 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateRuleMetadataRequest,
     ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRuleMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateRuleMetadata",
@@ -3577,6 +3766,9 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateRuleVersionRequest,
     ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRuleVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateRuleVersion",
@@ -3598,6 +3790,9 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateVariableRequest,
     ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVariable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.UpdateVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3610,3 +3805,389 @@ impl FraudDetector for FraudDetectorClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVariableResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Fraud Detector API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `FraudDetectorClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct FraudDetectorBlockingClient {
+    client: FraudDetectorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl FraudDetectorBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> FraudDetectorBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        FraudDetectorBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> FraudDetectorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        FraudDetectorBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient {
+            client: FraudDetectorClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a batch of variables.</p>
+    ///
+    /// See `FraudDetector::batch_create_variable`.
+    pub fn batch_create_variable(
+        &self,
+        input: BatchCreateVariableRequest,
+    ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
+        self.runtime
+            .block_on(self.client.batch_create_variable(input))
+    }
+
+    /// <p>Gets a batch of variables.</p>
+    ///
+    /// See `FraudDetector::batch_get_variable`.
+    pub fn batch_get_variable(
+        &self,
+        input: BatchGetVariableRequest,
+    ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
+        self.runtime.block_on(self.client.batch_get_variable(input))
+    }
+
+    /// <p>Creates a detector version. The detector version starts in a <code>DRAFT</code> status.</p>
+    ///
+    /// See `FraudDetector::create_detector_version`.
+    pub fn create_detector_version(
+        &self,
+        input: CreateDetectorVersionRequest,
+    ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.create_detector_version(input))
+    }
+
+    /// <p>Creates a version of the model using the specified model type. </p>
+    ///
+    /// See `FraudDetector::create_model_version`.
+    pub fn create_model_version(
+        &self,
+        input: CreateModelVersionRequest,
+    ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
+        self.runtime
+            .block_on(self.client.create_model_version(input))
+    }
+
+    /// <p>Creates a rule for use with the specified detector. </p>
+    ///
+    /// See `FraudDetector::create_rule`.
+    pub fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// <p>Creates a variable.</p>
+    ///
+    /// See `FraudDetector::create_variable`.
+    pub fn create_variable(
+        &self,
+        input: CreateVariableRequest,
+    ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
+        self.runtime.block_on(self.client.create_variable(input))
+    }
+
+    /// <p>Deletes the detector. Before deleting a detector, you must first delete all detector versions and rule versions associated with the detector.</p>
+    ///
+    /// See `FraudDetector::delete_detector`.
+    pub fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> Result<DeleteDetectorResult, RusotoError<DeleteDetectorError>> {
+        self.runtime.block_on(self.client.delete_detector(input))
+    }
+
+    /// <p>Deletes the detector version. You cannot delete detector versions that are in <code>ACTIVE</code> status.</p>
+    ///
+    /// See `FraudDetector::delete_detector_version`.
+    pub fn delete_detector_version(
+        &self,
+        input: DeleteDetectorVersionRequest,
+    ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_detector_version(input))
+    }
+
+    /// <p>Deletes the specified event.</p>
+    ///
+    /// See `FraudDetector::delete_event`.
+    pub fn delete_event(
+        &self,
+        input: DeleteEventRequest,
+    ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
+        self.runtime.block_on(self.client.delete_event(input))
+    }
+
+    /// <p>Deletes the rule version. You cannot delete a rule version if it is used by an <code>ACTIVE</code> or <code>INACTIVE</code> detector version.</p>
+    ///
+    /// See `FraudDetector::delete_rule_version`.
+    pub fn delete_rule_version(
+        &self,
+        input: DeleteRuleVersionRequest,
+    ) -> Result<DeleteRuleVersionResult, RusotoError<DeleteRuleVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_rule_version(input))
+    }
+
+    /// <p>Gets all versions for a specified detector.</p>
+    ///
+    /// See `FraudDetector::describe_detector`.
+    pub fn describe_detector(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
+        self.runtime.block_on(self.client.describe_detector(input))
+    }
+
+    /// <p>Gets all of the model versions for the specified model type or for the specified model type and model ID. You can also get details for a single, specified model version. </p>
+    ///
+    /// See `FraudDetector::describe_model_versions`.
+    pub fn describe_model_versions(
+        &self,
+        input: DescribeModelVersionsRequest,
+    ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_model_versions(input))
+    }
+
+    /// <p>Gets a particular detector version. </p>
+    ///
+    /// See `FraudDetector::get_detector_version`.
+    pub fn get_detector_version(
+        &self,
+        input: GetDetectorVersionRequest,
+    ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.get_detector_version(input))
+    }
+
+    /// <p>Gets all of detectors. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 5 and 10. To get the next page results, provide the pagination token from the <code>GetEventTypesResponse</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
+    ///
+    /// See `FraudDetector::get_detectors`.
+    pub fn get_detectors(
+        &self,
+        input: GetDetectorsRequest,
+    ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
+        self.runtime.block_on(self.client.get_detectors(input))
+    }
+
+    /// <p>Gets the details for one or more Amazon SageMaker models that have been imported into the service. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 5 and 10. To get the next page results, provide the pagination token from the <code>GetExternalModelsResult</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
+    ///
+    /// See `FraudDetector::get_external_models`.
+    pub fn get_external_models(
+        &self,
+        input: GetExternalModelsRequest,
+    ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
+        self.runtime
+            .block_on(self.client.get_external_models(input))
+    }
+
+    /// <p>Gets a model version. </p>
+    ///
+    /// See `FraudDetector::get_model_version`.
+    pub fn get_model_version(
+        &self,
+        input: GetModelVersionRequest,
+    ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
+        self.runtime.block_on(self.client.get_model_version(input))
+    }
+
+    /// <p>Gets all of the models for the AWS account, or the specified model type, or gets a single model for the specified model type, model ID combination. </p>
+    ///
+    /// See `FraudDetector::get_models`.
+    pub fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
+        self.runtime.block_on(self.client.get_models(input))
+    }
+
+    /// <p>Gets one or more outcomes. This is a paginated API. If you provide a null <code>maxSizePerPage</code>, this actions retrieves a maximum of 10 records per page. If you provide a <code>maxSizePerPage</code>, the value must be between 50 and 100. To get the next page results, provide the pagination token from the <code>GetOutcomesResult</code> as part of your request. A null pagination token fetches the records from the beginning. </p>
+    ///
+    /// See `FraudDetector::get_outcomes`.
+    pub fn get_outcomes(
+        &self,
+        input: GetOutcomesRequest,
+    ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
+        self.runtime.block_on(self.client.get_outcomes(input))
+    }
+
+    /// <p>Evaluates an event against a detector version. If a version ID is not provided, the detector’s (<code>ACTIVE</code>) version is used. </p>
+    ///
+    /// See `FraudDetector::get_prediction`.
+    pub fn get_prediction(
+        &self,
+        input: GetPredictionRequest,
+    ) -> Result<GetPredictionResult, RusotoError<GetPredictionError>> {
+        self.runtime.block_on(self.client.get_prediction(input))
+    }
+
+    /// <p>Gets all rules available for the specified detector.</p>
+    ///
+    /// See `FraudDetector::get_rules`.
+    pub fn get_rules(
+        &self,
+        input: GetRulesRequest,
+    ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
+        self.runtime.block_on(self.client.get_rules(input))
+    }
+
+    /// <p>Gets all of the variables or the specific variable. This is a paginated API. Providing null <code>maxSizePerPage</code> results in retrieving maximum of 100 records per page. If you provide <code>maxSizePerPage</code> the value must be between 50 and 100. To get the next page result, a provide a pagination token from <code>GetVariablesResult</code> as part of your request. Null pagination token fetches the records from the beginning. </p>
+    ///
+    /// See `FraudDetector::get_variables`.
+    pub fn get_variables(
+        &self,
+        input: GetVariablesRequest,
+    ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
+        self.runtime.block_on(self.client.get_variables(input))
+    }
+
+    /// <p>Creates or updates a detector. </p>
+    ///
+    /// See `FraudDetector::put_detector`.
+    pub fn put_detector(
+        &self,
+        input: PutDetectorRequest,
+    ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
+        self.runtime.block_on(self.client.put_detector(input))
+    }
+
+    /// <p>Creates or updates an Amazon SageMaker model endpoint. You can also use this action to update the configuration of the model endpoint, including the IAM role and/or the mapped variables. </p>
+    ///
+    /// See `FraudDetector::put_external_model`.
+    pub fn put_external_model(
+        &self,
+        input: PutExternalModelRequest,
+    ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
+        self.runtime.block_on(self.client.put_external_model(input))
+    }
+
+    /// <p>Creates or updates a model. </p>
+    ///
+    /// See `FraudDetector::put_model`.
+    pub fn put_model(
+        &self,
+        input: PutModelRequest,
+    ) -> Result<PutModelResult, RusotoError<PutModelError>> {
+        self.runtime.block_on(self.client.put_model(input))
+    }
+
+    /// <p>Creates or updates an outcome. </p>
+    ///
+    /// See `FraudDetector::put_outcome`.
+    pub fn put_outcome(
+        &self,
+        input: PutOutcomeRequest,
+    ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
+        self.runtime.block_on(self.client.put_outcome(input))
+    }
+
+    /// <p> Updates a detector version. The detector version attributes that you can update include models, external model endpoints, rules, and description. You can only update a <code>DRAFT</code> detector version.</p>
+    ///
+    /// See `FraudDetector::update_detector_version`.
+    pub fn update_detector_version(
+        &self,
+        input: UpdateDetectorVersionRequest,
+    ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.update_detector_version(input))
+    }
+
+    /// <p>Updates the detector version's description. You can update the metadata for any detector version (<code>DRAFT, ACTIVE,</code> or <code>INACTIVE</code>). </p>
+    ///
+    /// See `FraudDetector::update_detector_version_metadata`.
+    pub fn update_detector_version_metadata(
+        &self,
+        input: UpdateDetectorVersionMetadataRequest,
+    ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
+    {
+        self.runtime
+            .block_on(self.client.update_detector_version_metadata(input))
+    }
+
+    /// <p>Updates the detector version’s status. You can perform the following promotions or demotions using <code>UpdateDetectorVersionStatus</code>: <code>DRAFT</code> to <code>ACTIVE</code>, <code>ACTIVE</code> to <code>INACTIVE</code>, and <code>INACTIVE</code> to <code>ACTIVE</code>.</p>
+    ///
+    /// See `FraudDetector::update_detector_version_status`.
+    pub fn update_detector_version_status(
+        &self,
+        input: UpdateDetectorVersionStatusRequest,
+    ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.update_detector_version_status(input))
+    }
+
+    /// <p><p>Updates a model version. You can update the description and status attributes using this action. You can perform the following status updates: </p> <ol> <li> <p>Change the <code>TRAINING<em>COMPLETE</code> status to <code>ACTIVE</code> </p> </li> <li> <p>Change <code>ACTIVE</code> back to <code>TRAINING</em>COMPLETE</code> </p> </li> </ol></p>
+    ///
+    /// See `FraudDetector::update_model_version`.
+    pub fn update_model_version(
+        &self,
+        input: UpdateModelVersionRequest,
+    ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
+        self.runtime
+            .block_on(self.client.update_model_version(input))
+    }
+
+    /// <p>Updates a rule's metadata. </p>
+    ///
+    /// See `FraudDetector::update_rule_metadata`.
+    pub fn update_rule_metadata(
+        &self,
+        input: UpdateRuleMetadataRequest,
+    ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_rule_metadata(input))
+    }
+
+    /// <p>Updates a rule version resulting in a new rule version. </p>
+    ///
+    /// See `FraudDetector::update_rule_version`.
+    pub fn update_rule_version(
+        &self,
+        input: UpdateRuleVersionRequest,
+    ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
+        self.runtime
+            .block_on(self.client.update_rule_version(input))
+    }
+
+    /// <p>Updates a variable.</p>
+    ///
+    /// See `FraudDetector::update_variable`.
+    pub fn update_variable(
+        &self,
+        input: UpdateVariableRequest,
+    ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
+        self.runtime.block_on(self.client.update_variable(input))
+    }
+}
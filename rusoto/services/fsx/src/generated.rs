@@ -1198,25 +1198,32 @@ impl CancelDataRepositoryTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CancelDataRepositoryTaskError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelDataRepositoryTaskError::BadRequest(err.msg),
+                    )
                 }
                 "DataRepositoryTaskEnded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelDataRepositoryTaskError::DataRepositoryTaskEnded(err.msg),
                     )
                 }
                 "DataRepositoryTaskNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelDataRepositoryTaskError::DataRepositoryTaskNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelDataRepositoryTaskError::InternalServerError(err.msg),
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelDataRepositoryTaskError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -1270,27 +1277,40 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInProgress" => {
-                    return RusotoError::Service(CreateBackupError::BackupInProgress(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::BackupInProgress(err.msg))
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateBackupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateBackupError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(CreateBackupError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::IncompatibleParameterError(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(CreateBackupError::ServiceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::ServiceLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(CreateBackupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBackupError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1338,35 +1358,44 @@ impl CreateDataRepositoryTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateDataRepositoryTaskError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataRepositoryTaskError::BadRequest(err.msg),
+                    )
                 }
                 "DataRepositoryTaskExecuting" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataRepositoryTaskError::DataRepositoryTaskExecuting(err.msg),
                     )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateDataRepositoryTaskError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataRepositoryTaskError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataRepositoryTaskError::IncompatibleParameterError(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataRepositoryTaskError::InternalServerError(err.msg),
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataRepositoryTaskError::ServiceLimitExceeded(err.msg),
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataRepositoryTaskError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -1430,48 +1459,61 @@ impl CreateFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActiveDirectoryError" => {
-                    return RusotoError::Service(CreateFileSystemError::ActiveDirectoryError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::ActiveDirectoryError(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateFileSystemError::BadRequest(err.msg))
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(CreateFileSystemError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::IncompatibleParameterError(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidExportPath" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidExportPath(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::InvalidExportPath(err.msg),
+                    )
                 }
                 "InvalidImportPath" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidImportPath(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::InvalidImportPath(err.msg),
+                    )
                 }
                 "InvalidNetworkSettings" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidNetworkSettings(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::InvalidNetworkSettings(err.msg),
+                    )
                 }
                 "InvalidPerUnitStorageThroughput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemError::InvalidPerUnitStorageThroughput(err.msg),
                     )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemError::MissingFileSystemConfiguration(err.msg),
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::ServiceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemError::ServiceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1532,47 +1574,56 @@ impl CreateFileSystemFromBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActiveDirectoryError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::ActiveDirectoryError(err.msg),
                     )
                 }
                 "BackupNotFound" => {
-                    return RusotoError::Service(CreateFileSystemFromBackupError::BackupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemFromBackupError::BackupNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemFromBackupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFileSystemFromBackupError::BadRequest(err.msg),
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::IncompatibleParameterError(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidNetworkSettings" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::InvalidNetworkSettings(err.msg),
                     )
                 }
                 "InvalidPerUnitStorageThroughput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::InvalidPerUnitStorageThroughput(err.msg),
                     )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::MissingFileSystemConfiguration(err.msg),
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateFileSystemFromBackupError::ServiceLimitExceeded(err.msg),
                     )
                 }
@@ -1636,24 +1687,28 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInProgress" => {
-                    return RusotoError::Service(DeleteBackupError::BackupInProgress(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::BackupInProgress(err.msg))
                 }
                 "BackupNotFound" => {
-                    return RusotoError::Service(DeleteBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::BackupNotFound(err.msg))
                 }
                 "BackupRestoring" => {
-                    return RusotoError::Service(DeleteBackupError::BackupRestoring(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::BackupRestoring(err.msg))
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteBackupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::BadRequest(err.msg))
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(DeleteBackupError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::IncompatibleParameterError(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBackupError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1696,25 +1751,31 @@ impl DeleteFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteFileSystemError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(DeleteFileSystemError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::IncompatibleParameterError(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(DeleteFileSystemError::ServiceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileSystemError::ServiceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1754,16 +1815,25 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupNotFound" => {
-                    return RusotoError::Service(DescribeBackupsError::BackupNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::BackupNotFound(err.msg),
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeBackupsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeBackupsError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeBackupsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1804,22 +1874,26 @@ impl DescribeDataRepositoryTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeDataRepositoryTasksError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataRepositoryTasksError::BadRequest(err.msg),
+                    )
                 }
                 "DataRepositoryTaskNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataRepositoryTasksError::DataRepositoryTaskNotFound(err.msg),
                     )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataRepositoryTasksError::FileSystemNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataRepositoryTasksError::InternalServerError(err.msg),
                     )
                 }
@@ -1864,17 +1938,22 @@ impl DescribeFileSystemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemsError::BadRequest(err.msg),
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemsError::FileSystemNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemsError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeFileSystemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFileSystemsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1914,27 +1993,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotServiceResourceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::NotServiceResourceError(err.msg),
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTagsForResourceError::ResourceDoesNotSupportTagging(err.msg),
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1977,20 +2063,29 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(TagResourceError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
+                }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(TagResourceError::NotServiceResourceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::NotServiceResourceError(err.msg),
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(TagResourceError::ResourceDoesNotSupportTagging(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ResourceDoesNotSupportTagging(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2032,23 +2127,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(UntagResourceError::NotServiceResourceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::NotServiceResourceError(err.msg),
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(UntagResourceError::ResourceDoesNotSupportTagging(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceDoesNotSupportTagging(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2094,35 +2197,43 @@ impl UpdateFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UpdateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateFileSystemError::BadRequest(err.msg))
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UpdateFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::FileSystemNotFound(err.msg),
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(UpdateFileSystemError::IncompatibleParameterError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::IncompatibleParameterError(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateFileSystemError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::InternalServerError(err.msg),
+                    )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFileSystemError::MissingFileSystemConfiguration(err.msg),
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(UpdateFileSystemError::ServiceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::ServiceLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(UpdateFileSystemError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFileSystemError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2253,6 +2364,7 @@ impl FsxClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2268,6 +2380,7 @@ impl FsxClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> FsxClient {
         FsxClient { client, region }
     }
@@ -2281,6 +2394,9 @@ impl Fsx for FsxClient {
         input: CancelDataRepositoryTaskRequest,
     ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelDataRepositoryTask");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CancelDataRepositoryTask",
@@ -2303,6 +2419,9 @@ impl Fsx for FsxClient {
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBackup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2321,6 +2440,9 @@ impl Fsx for FsxClient {
         input: CreateDataRepositoryTaskRequest,
     ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataRepositoryTask");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CreateDataRepositoryTask",
@@ -2343,6 +2465,9 @@ impl Fsx for FsxClient {
         input: CreateFileSystemRequest,
     ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFileSystem");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CreateFileSystem",
@@ -2365,6 +2490,9 @@ impl Fsx for FsxClient {
     ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFileSystemFromBackup");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CreateFileSystemFromBackup",
@@ -2387,6 +2515,9 @@ impl Fsx for FsxClient {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBackup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2405,6 +2536,9 @@ impl Fsx for FsxClient {
         input: DeleteFileSystemRequest,
     ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFileSystem");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DeleteFileSystem",
@@ -2426,6 +2560,9 @@ impl Fsx for FsxClient {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBackups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeBackups",
@@ -2448,6 +2585,9 @@ impl Fsx for FsxClient {
     ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDataRepositoryTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeDataRepositoryTasks",
@@ -2470,6 +2610,9 @@ impl Fsx for FsxClient {
         input: DescribeFileSystemsRequest,
     ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFileSystems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeFileSystems",
@@ -2491,6 +2634,9 @@ impl Fsx for FsxClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.ListTagsForResource",
@@ -2512,6 +2658,9 @@ impl Fsx for FsxClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2530,6 +2679,9 @@ impl Fsx for FsxClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2548,6 +2700,9 @@ impl Fsx for FsxClient {
         input: UpdateFileSystemRequest,
     ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateFileSystem");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.UpdateFileSystem",
@@ -2563,3 +2718,201 @@ impl Fsx for FsxClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFileSystemResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon FSx API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `FsxClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct FsxBlockingClient {
+    client: FsxClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl FsxBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> FsxBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        FsxBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> FsxBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        FsxBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> FsxBlockingClient {
+        FsxBlockingClient {
+            client: FsxClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Cancels an existing Amazon FSx for Lustre data repository task if that task is in either the <code>PENDING</code> or <code>EXECUTING</code> state. When you cancel a task, Amazon FSx does the following.</p> <ul> <li> <p>Any files that FSx has already exported are not reverted.</p> </li> <li> <p>FSx continues to export any files that are &quot;in-flight&quot; when the cancel operation is received.</p> </li> <li> <p>FSx does not export any files that have not yet been exported.</p> </li> </ul></p>
+    ///
+    /// See `Fsx::cancel_data_repository_task`.
+    pub fn cancel_data_repository_task(
+        &self,
+        input: CancelDataRepositoryTaskRequest,
+    ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
+        self.runtime
+            .block_on(self.client.cancel_data_repository_task(input))
+    }
+
+    /// <p>Creates a backup of an existing Amazon FSx file system. Creating regular backups for your file system is a best practice, enabling you to restore a file system from a backup if an issue arises with the original file system.</p> <p>For Amazon FSx for Lustre file systems, you can create a backup only for file systems with the following configuration:</p> <ul> <li> <p>a Persistent deployment type</p> </li> <li> <p>is <i>not</i> linked to an Amazon S3 data respository.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/lustre-backups.html">https://docs.aws.amazon.com/fsx/latest/LustreGuide/lustre-backups.html</a>.</p> <p>If a backup with the specified client request token exists, and the parameters match, this operation returns the description of the existing backup. If a backup specified client request token exists, and the parameters don't match, this operation returns <code>IncompatibleParameterError</code>. If a backup with the specified client request token doesn't exist, <code>CreateBackup</code> does the following: </p> <ul> <li> <p>Creates a new Amazon FSx backup with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the backup.</p> </li> </ul> <p>By using the idempotent operation, you can retry a <code>CreateBackup</code> operation without the risk of creating an extra backup. This approach can be useful when an initial call fails in a way that makes it unclear whether a backup was created. If you use the same client request token and the initial call created a backup, the operation returns a successful result because all the parameters are the same.</p> <p>The <code>CreateBackup</code> operation returns while the backup's lifecycle state is still <code>CREATING</code>. You can check the backup creation status by calling the <a>DescribeBackups</a> operation, which returns the backup state along with other information.</p>
+    ///
+    /// See `Fsx::create_backup`.
+    pub fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        self.runtime.block_on(self.client.create_backup(input))
+    }
+
+    /// <p>Creates an Amazon FSx for Lustre data repository task. You use data repository tasks to perform bulk operations between your Amazon FSx file system and its linked data repository. An example of a data repository task is exporting any data and metadata changes, including POSIX metadata, to files, directories, and symbolic links (symlinks) from your FSx file system to its linked data repository. A <code>CreateDataRepositoryTask</code> operation will fail if a data repository is not linked to the FSx file system. To learn more about data repository tasks, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/data-repository-tasks.html">Using Data Repository Tasks</a>. To learn more about linking a data repository to your file system, see <a href="https://docs.aws.amazon.com/fsx/latest/LustreGuide/export-data-repository.html#export-prefix">Setting the Export Prefix</a>.</p>
+    ///
+    /// See `Fsx::create_data_repository_task`.
+    pub fn create_data_repository_task(
+        &self,
+        input: CreateDataRepositoryTaskRequest,
+    ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
+        self.runtime
+            .block_on(self.client.create_data_repository_task(input))
+    }
+
+    /// <p><p>Creates a new, empty Amazon FSx file system.</p> <p>If a file system with the specified client request token exists and the parameters match, <code>CreateFileSystem</code> returns the description of the existing file system. If a file system specified client request token exists and the parameters don&#39;t match, this call returns <code>IncompatibleParameterError</code>. If a file system with the specified client request token doesn&#39;t exist, <code>CreateFileSystem</code> does the following: </p> <ul> <li> <p>Creates a new, empty Amazon FSx file system with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the file system.</p> </li> </ul> <p>This operation requires a client request token in the request that Amazon FSx uses to ensure idempotent creation. This means that calling the operation multiple times with the same client request token has no effect. By using the idempotent operation, you can retry a <code>CreateFileSystem</code> operation without the risk of creating an extra file system. This approach can be useful when an initial call fails in a way that makes it unclear whether a file system was created. Examples are if a transport level timeout occurred, or your connection was reset. If you use the same client request token and the initial call created a file system, the client receives success as long as the parameters are the same.</p> <note> <p>The <code>CreateFileSystem</code> call returns while the file system&#39;s lifecycle state is still <code>CREATING</code>. You can check the file-system creation status by calling the <a>DescribeFileSystems</a> operation, which returns the file system state along with other information.</p> </note></p>
+    ///
+    /// See `Fsx::create_file_system`.
+    pub fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
+        self.runtime.block_on(self.client.create_file_system(input))
+    }
+
+    /// <p><p>Creates a new Amazon FSx file system from an existing Amazon FSx backup.</p> <p>If a file system with the specified client request token exists and the parameters match, this operation returns the description of the file system. If a client request token specified by the file system exists and the parameters don&#39;t match, this call returns <code>IncompatibleParameterError</code>. If a file system with the specified client request token doesn&#39;t exist, this operation does the following:</p> <ul> <li> <p>Creates a new Amazon FSx file system from backup with an assigned ID, and an initial lifecycle state of <code>CREATING</code>.</p> </li> <li> <p>Returns the description of the file system.</p> </li> </ul> <p>Parameters like Active Directory, default share name, automatic backup, and backup settings default to the parameters of the file system that was backed up, unless overridden. You can explicitly supply other settings.</p> <p>By using the idempotent operation, you can retry a <code>CreateFileSystemFromBackup</code> call without the risk of creating an extra file system. This approach can be useful when an initial call fails in a way that makes it unclear whether a file system was created. Examples are if a transport level timeout occurred, or your connection was reset. If you use the same client request token and the initial call created a file system, the client receives success as long as the parameters are the same.</p> <note> <p>The <code>CreateFileSystemFromBackup</code> call returns while the file system&#39;s lifecycle state is still <code>CREATING</code>. You can check the file-system creation status by calling the <a>DescribeFileSystems</a> operation, which returns the file system state along with other information.</p> </note></p>
+    ///
+    /// See `Fsx::create_file_system_from_backup`.
+    pub fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_file_system_from_backup(input))
+    }
+
+    /// <p><p>Deletes an Amazon FSx backup, deleting its contents. After deletion, the backup no longer exists, and its data is gone.</p> <p>The <code>DeleteBackup</code> call returns instantly. The backup will not show up in later <code>DescribeBackups</code> calls.</p> <important> <p>The data in a deleted backup is also deleted and can&#39;t be recovered by any means.</p> </important></p>
+    ///
+    /// See `Fsx::delete_backup`.
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// <p><p>Deletes a file system, deleting its contents. After deletion, the file system no longer exists, and its data is gone. Any existing automatic backups will also be deleted.</p> <p>By default, when you delete an Amazon FSx for Windows File Server file system, a final backup is created upon deletion. This final backup is not subject to the file system&#39;s retention policy, and must be manually deleted.</p> <p>The <code>DeleteFileSystem</code> action returns while the file system has the <code>DELETING</code> status. You can check the file system deletion status by calling the <a>DescribeFileSystems</a> action, which returns a list of file systems in your account. If you pass the file system ID for a deleted file system, the <a>DescribeFileSystems</a> returns a <code>FileSystemNotFound</code> error.</p> <note> <p>Deleting an Amazon FSx for Lustre file system will fail with a 400 BadRequest if a data repository task is in a <code>PENDING</code> or <code>EXECUTING</code> state.</p> </note> <important> <p>The data in a deleted file system is also deleted and can&#39;t be recovered by any means.</p> </important></p>
+    ///
+    /// See `Fsx::delete_file_system`.
+    pub fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
+        self.runtime.block_on(self.client.delete_file_system(input))
+    }
+
+    /// <p><p>Returns the description of specific Amazon FSx backups, if a <code>BackupIds</code> value is provided for that backup. Otherwise, it returns all backups owned by your AWS account in the AWS Region of the endpoint that you&#39;re calling.</p> <p>When retrieving all backups, you can optionally specify the <code>MaxResults</code> parameter to limit the number of backups in a response. If more backups remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your backups. <code>DescribeBackups</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of backups returned in the response of one <code>DescribeBackups</code> call and the order of backups returned across the responses of a multi-call iteration is unspecified.</p> </li> </ul></p>
+    ///
+    /// See `Fsx::describe_backups`.
+    pub fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.runtime.block_on(self.client.describe_backups(input))
+    }
+
+    /// <p>Returns the description of specific Amazon FSx for Lustre data repository tasks, if one or more <code>TaskIds</code> values are provided in the request, or if filters are used in the request. You can use filters to narrow the response to include just tasks for specific file systems, or tasks in a specific lifecycle state. Otherwise, it returns all data repository tasks owned by your AWS account in the AWS Region of the endpoint that you're calling.</p> <p>When retrieving all tasks, you can paginate the response by using the optional <code>MaxResults</code> parameter to limit the number of tasks returned in a response. If more tasks remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p>
+    ///
+    /// See `Fsx::describe_data_repository_tasks`.
+    pub fn describe_data_repository_tasks(
+        &self,
+        input: DescribeDataRepositoryTasksRequest,
+    ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_data_repository_tasks(input))
+    }
+
+    /// <p><p>Returns the description of specific Amazon FSx file systems, if a <code>FileSystemIds</code> value is provided for that file system. Otherwise, it returns descriptions of all file systems owned by your AWS account in the AWS Region of the endpoint that you&#39;re calling.</p> <p>When retrieving all file system descriptions, you can optionally specify the <code>MaxResults</code> parameter to limit the number of descriptions in a response. If more file system descriptions remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your file system descriptions. <code>DescribeFileSystems</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of file systems returned in the response of one <code>DescribeFileSystems</code> call and the order of file systems returned across the responses of a multicall iteration is unspecified.</p> </li> </ul></p>
+    ///
+    /// See `Fsx::describe_file_systems`.
+    pub fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+        self.runtime
+            .block_on(self.client.describe_file_systems(input))
+    }
+
+    /// <p><p>Lists tags for an Amazon FSx file systems and backups in the case of Amazon FSx for Windows File Server.</p> <p>When retrieving all tags, you can optionally specify the <code>MaxResults</code> parameter to limit the number of tags in a response. If more tags remain, Amazon FSx returns a <code>NextToken</code> value in the response. In this case, send a later request with the <code>NextToken</code> request parameter set to the value of <code>NextToken</code> from the last response.</p> <p>This action is used in an iterative process to retrieve a list of your tags. <code>ListTagsForResource</code> is called first without a <code>NextToken</code>value. Then the action continues to be called with the <code>NextToken</code> parameter set to the value of the last <code>NextToken</code> value until a response has no <code>NextToken</code>.</p> <p>When using this action, keep the following in mind:</p> <ul> <li> <p>The implementation might return fewer than <code>MaxResults</code> file system descriptions while still including a <code>NextToken</code> value.</p> </li> <li> <p>The order of tags returned in the response of one <code>ListTagsForResource</code> call and the order of tags returned across the responses of a multi-call iteration is unspecified.</p> </li> </ul></p>
+    ///
+    /// See `Fsx::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Tags an Amazon FSx resource.</p>
+    ///
+    /// See `Fsx::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>This action removes a tag from an Amazon FSx resource.</p>
+    ///
+    /// See `Fsx::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Use this operation to update the configuration of an existing Amazon FSx file system. For an Amazon FSx for Lustre file system, you can update only the WeeklyMaintenanceStartTime. For an Amazon for Windows File Server file system, you can update the following properties:</p> <ul> <li> <p>AutomaticBackupRetentionDays</p> </li> <li> <p>DailyAutomaticBackupStartTime</p> </li> <li> <p>SelfManagedActiveDirectoryConfiguration</p> </li> <li> <p>StorageCapacity</p> </li> <li> <p>ThroughputCapacity</p> </li> <li> <p>WeeklyMaintenanceStartTime</p> </li> </ul> <p>You can update multiple properties in a single request.</p>
+    ///
+    /// See `Fsx::update_file_system`.
+    pub fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
+        self.runtime.block_on(self.client.update_file_system(input))
+    }
+}
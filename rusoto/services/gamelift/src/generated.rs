@@ -3930,16 +3930,16 @@ impl AcceptMatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AcceptMatchError::InternalService(err.msg))
+                    return RusotoError::service(&res, AcceptMatchError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AcceptMatchError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, AcceptMatchError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AcceptMatchError::NotFound(err.msg))
+                    return RusotoError::service(&res, AcceptMatchError::NotFound(err.msg))
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(AcceptMatchError::UnsupportedRegion(err.msg))
+                    return RusotoError::service(&res, AcceptMatchError::UnsupportedRegion(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3982,22 +3982,28 @@ impl ClaimGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(ClaimGameServerError::Conflict(err.msg))
+                    return RusotoError::service(&res, ClaimGameServerError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ClaimGameServerError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClaimGameServerError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClaimGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClaimGameServerError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ClaimGameServerError::NotFound(err.msg))
+                    return RusotoError::service(&res, ClaimGameServerError::NotFound(err.msg))
                 }
                 "OutOfCapacityException" => {
-                    return RusotoError::Service(ClaimGameServerError::OutOfCapacity(err.msg))
+                    return RusotoError::service(&res, ClaimGameServerError::OutOfCapacity(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ClaimGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ClaimGameServerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4042,22 +4048,22 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateAliasError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::LimitExceeded(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateAliasError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4100,19 +4106,19 @@ impl CreateBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBuildError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateBuildError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateBuildError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateBuildError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateBuildError::InvalidRequest(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateBuildError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, CreateBuildError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateBuildError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4158,25 +4164,25 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateFleetError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateFleetError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateFleetError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4220,23 +4226,34 @@ impl CreateGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameServerGroupError::Conflict(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameServerGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameServerGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameServerGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameServerGroupError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4288,42 +4305,58 @@ impl CreateGameSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGameSessionError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateGameSessionError::Conflict(err.msg))
                 }
                 "FleetCapacityExceededException" => {
-                    return RusotoError::Service(CreateGameSessionError::FleetCapacityExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::FleetCapacityExceeded(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGameSessionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(CreateGameSessionError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::InvalidFleetStatus(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGameSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateGameSessionError::NotFound(err.msg))
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(CreateGameSessionError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::TerminalRoutingStrategy(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameSessionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4372,27 +4405,34 @@ impl CreateGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionQueueError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionQueueError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionQueueError::LimitExceeded(err.msg),
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionQueueError::TaggingFailed(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGameSessionQueueError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4438,32 +4478,38 @@ impl CreateMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMatchmakingConfigurationError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMatchmakingConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMatchmakingConfigurationError::LimitExceeded(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateMatchmakingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMatchmakingConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMatchmakingConfigurationError::TaggingFailed(err.msg),
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
                     )
                 }
@@ -4512,24 +4558,28 @@ impl CreateMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMatchmakingRuleSetError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMatchmakingRuleSetError::InvalidRequest(err.msg),
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMatchmakingRuleSetError::TaggingFailed(err.msg),
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMatchmakingRuleSetError::UnsupportedRegion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4574,29 +4624,43 @@ impl CreatePlayerSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GameSessionFullException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::GameSessionFull(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionError::GameSessionFull(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePlayerSessionError::InvalidGameSessionStatus(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreatePlayerSessionError::NotFound(err.msg))
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionError::TerminalRoutingStrategy(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4644,33 +4708,43 @@ impl CreatePlayerSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GameSessionFullException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::GameSessionFull(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionsError::GameSessionFull(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePlayerSessionsError::InvalidGameSessionStatus(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreatePlayerSessionsError::NotFound(err.msg))
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePlayerSessionsError::TerminalRoutingStrategy(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlayerSessionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4716,19 +4790,19 @@ impl CreateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateScriptError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScriptError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::InvalidRequest(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateScriptError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4770,24 +4844,28 @@ impl CreateVpcPeeringAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateVpcPeeringAuthorizationError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateVpcPeeringAuthorizationError::InvalidRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVpcPeeringAuthorizationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVpcPeeringAuthorizationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcPeeringAuthorizationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVpcPeeringAuthorizationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4830,22 +4908,28 @@ impl CreateVpcPeeringConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVpcPeeringConnectionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVpcPeeringConnectionError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVpcPeeringConnectionError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVpcPeeringConnectionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4886,19 +4970,19 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteAliasError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteAliasError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4940,19 +5024,19 @@ impl DeleteBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteBuildError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteBuildError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBuildError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBuildError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBuildError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteBuildError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, DeleteBuildError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteBuildError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4996,22 +5080,25 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::InternalService(err.msg))
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidFleetStatus(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFleetError::InvalidFleetStatus(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteFleetError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5052,20 +5139,28 @@ impl DeleteGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameServerGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameServerGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameServerGroupError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameServerGroupError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5106,25 +5201,34 @@ impl DeleteGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameSessionQueueError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameSessionQueueError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameSessionQueueError::NotFound(err.msg),
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameSessionQueueError::TaggingFailed(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGameSessionQueueError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5168,27 +5272,32 @@ impl DeleteMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteMatchmakingConfigurationError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteMatchmakingConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMatchmakingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMatchmakingConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteMatchmakingConfigurationError::TaggingFailed(err.msg),
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteMatchmakingConfigurationError::UnsupportedRegion(err.msg),
                     )
                 }
@@ -5238,27 +5347,34 @@ impl DeleteMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMatchmakingRuleSetError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMatchmakingRuleSetError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMatchmakingRuleSetError::NotFound(err.msg),
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::TaggingFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMatchmakingRuleSetError::TaggingFailed(err.msg),
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMatchmakingRuleSetError::UnsupportedRegion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5298,16 +5414,25 @@ impl DeleteScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPolicyError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteScalingPolicyError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScalingPolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5348,19 +5473,19 @@ impl DeleteScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScriptError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteScriptError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteScriptError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScriptError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteScriptError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteScriptError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, DeleteScriptError::TaggingFailed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteScriptError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5402,24 +5527,28 @@ impl DeleteVpcPeeringAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVpcPeeringAuthorizationError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVpcPeeringAuthorizationError::InvalidRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcPeeringAuthorizationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVpcPeeringAuthorizationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcPeeringAuthorizationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVpcPeeringAuthorizationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5462,22 +5591,28 @@ impl DeleteVpcPeeringConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVpcPeeringConnectionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVpcPeeringConnectionError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVpcPeeringConnectionError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVpcPeeringConnectionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5516,18 +5651,25 @@ impl DeregisterGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterGameServerError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterGameServerError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeregisterGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterGameServerError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeregisterGameServerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeregisterGameServerError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeregisterGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterGameServerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5566,16 +5708,16 @@ impl DescribeAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeAliasError::InternalService(err.msg))
+                    return RusotoError::service(&res, DescribeAliasError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeAliasError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeAliasError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5614,16 +5756,16 @@ impl DescribeBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeBuildError::InternalService(err.msg))
+                    return RusotoError::service(&res, DescribeBuildError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeBuildError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBuildError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeBuildError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeBuildError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5660,19 +5802,22 @@ impl DescribeEC2InstanceLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEC2InstanceLimitsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEC2InstanceLimitsError::InvalidRequest(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEC2InstanceLimitsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5710,22 +5855,28 @@ impl DescribeFleetAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetAttributesError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetAttributesError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetAttributesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5764,20 +5915,28 @@ impl DescribeFleetCapacityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetCapacityError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetCapacityError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetCapacityError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetCapacityError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5816,16 +5975,25 @@ impl DescribeFleetEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetEventsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetEventsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeFleetEventsError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetEventsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5864,22 +6032,28 @@ impl DescribeFleetPortSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetPortSettingsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetPortSettingsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetPortSettingsError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetPortSettingsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5918,22 +6092,28 @@ impl DescribeFleetUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetUtilizationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetUtilizationError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetUtilizationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetUtilizationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5972,16 +6152,25 @@ impl DescribeGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameServerError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameServerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeGameServerError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6020,22 +6209,28 @@ impl DescribeGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerGroupError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameServerGroupError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6078,27 +6273,34 @@ impl DescribeGameSessionDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionDetailsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionDetailsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionDetailsError::NotFound(err.msg),
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGameSessionDetailsError::TerminalRoutingStrategy(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionDetailsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6142,24 +6344,28 @@ impl DescribeGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGameSessionPlacementError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionPlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionPlacementError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionPlacementError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6198,22 +6404,28 @@ impl DescribeGameSessionQueuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionQueuesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionQueuesError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionQueuesError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionQueuesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6254,23 +6466,31 @@ impl DescribeGameSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeGameSessionsError::NotFound(err.msg))
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGameSessionsError::TerminalRoutingStrategy(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGameSessionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6310,16 +6530,25 @@ impl DescribeInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeInstancesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancesError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInstancesError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeInstancesError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeInstancesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6356,15 +6585,22 @@ impl DescribeMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMatchmakingError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMatchmakingError::InvalidRequest(err.msg),
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMatchmakingError::UnsupportedRegion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6402,17 +6638,20 @@ impl DescribeMatchmakingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMatchmakingConfigurationsError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMatchmakingConfigurationsError::InvalidRequest(err.msg),
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMatchmakingConfigurationsError::UnsupportedRegion(err.msg),
                     )
                 }
@@ -6460,22 +6699,26 @@ impl DescribeMatchmakingRuleSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMatchmakingRuleSetsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMatchmakingRuleSetsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMatchmakingRuleSetsError::NotFound(err.msg),
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMatchmakingRuleSetsError::UnsupportedRegion(err.msg),
                     )
                 }
@@ -6518,20 +6761,28 @@ impl DescribePlayerSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlayerSessionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlayerSessionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlayerSessionsError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlayerSessionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6572,24 +6823,28 @@ impl DescribeRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRuntimeConfigurationError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRuntimeConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRuntimeConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRuntimeConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6628,22 +6883,28 @@ impl DescribeScalingPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScalingPoliciesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6682,16 +6943,19 @@ impl DescribeScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScriptError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScriptError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeScriptError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScriptError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeScriptError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeScriptError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6730,17 +6994,20 @@ impl DescribeVpcPeeringAuthorizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVpcPeeringAuthorizationsError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVpcPeeringAuthorizationsError::InvalidRequest(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVpcPeeringAuthorizationsError::Unauthorized(err.msg),
                     )
                 }
@@ -6788,24 +7055,28 @@ impl DescribeVpcPeeringConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVpcPeeringConnectionsError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeVpcPeeringConnectionsError::InvalidRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVpcPeeringConnectionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVpcPeeringConnectionsError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeVpcPeeringConnectionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVpcPeeringConnectionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6846,18 +7117,25 @@ impl GetGameSessionLogUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetGameSessionLogUrlError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGameSessionLogUrlError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGameSessionLogUrlError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGameSessionLogUrlError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6896,16 +7174,25 @@ impl GetInstanceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetInstanceAccessError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInstanceAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceAccessError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceAccessError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetInstanceAccessError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6942,13 +7229,13 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAliasesError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::InvalidRequest(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAliasesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6984,13 +7271,13 @@ impl ListBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListBuildsError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListBuildsError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBuildsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListBuildsError::InvalidRequest(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBuildsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListBuildsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7028,16 +7315,16 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFleetsError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListFleetsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7074,15 +7361,22 @@ impl ListGameServerGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListGameServerGroupsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGameServerGroupsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListGameServerGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGameServerGroupsError::InvalidRequest(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGameServerGroupsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGameServerGroupsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7118,13 +7412,19 @@ impl ListGameServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListGameServersError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGameServersError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListGameServersError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGameServersError::InvalidRequest(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGameServersError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListGameServersError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7160,13 +7460,13 @@ impl ListScriptsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListScriptsError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListScriptsError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListScriptsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListScriptsError::InvalidRequest(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListScriptsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListScriptsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7204,16 +7504,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TaggingFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7252,16 +7561,22 @@ impl PutScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutScalingPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutScalingPolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutScalingPolicyError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutScalingPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, PutScalingPolicyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7302,19 +7617,31 @@ impl RegisterGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RegisterGameServerError::Conflict(err.msg))
+                    return RusotoError::service(&res, RegisterGameServerError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterGameServerError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterGameServerError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterGameServerError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterGameServerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterGameServerError::LimitExceeded(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterGameServerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7354,22 +7681,28 @@ impl RequestUploadCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestUploadCredentialsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestUploadCredentialsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RequestUploadCredentialsError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestUploadCredentialsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7410,21 +7743,22 @@ impl ResolveAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResolveAliasError::InternalService(err.msg))
+                    return RusotoError::service(&res, ResolveAliasError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ResolveAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ResolveAliasError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResolveAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, ResolveAliasError::NotFound(err.msg))
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(ResolveAliasError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResolveAliasError::TerminalRoutingStrategy(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ResolveAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ResolveAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7464,20 +7798,28 @@ impl ResumeGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResumeGameServerGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResumeGameServerGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResumeGameServerGroupError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResumeGameServerGroupError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7518,21 +7860,31 @@ impl SearchGameSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SearchGameSessionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchGameSessionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchGameSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchGameSessionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SearchGameSessionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, SearchGameSessionsError::NotFound(err.msg))
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(SearchGameSessionsError::TerminalRoutingStrategy(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchGameSessionsError::TerminalRoutingStrategy(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchGameSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchGameSessionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7572,16 +7924,25 @@ impl StartFleetActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartFleetActionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetActionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartFleetActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetActionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartFleetActionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartFleetActionsError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartFleetActionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFleetActionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7620,22 +7981,28 @@ impl StartGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartGameSessionPlacementError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartGameSessionPlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartGameSessionPlacementError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartGameSessionPlacementError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7674,18 +8041,25 @@ impl StartMatchBackfillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMatchBackfillError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMatchBackfillError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartMatchBackfillError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMatchBackfillError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMatchBackfillError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartMatchBackfillError::NotFound(err.msg))
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StartMatchBackfillError::UnsupportedRegion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMatchBackfillError::UnsupportedRegion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7724,16 +8098,25 @@ impl StartMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMatchmakingError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMatchmakingError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMatchmakingError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartMatchmakingError::NotFound(err.msg))
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StartMatchmakingError::UnsupportedRegion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMatchmakingError::UnsupportedRegion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7772,16 +8155,22 @@ impl StopFleetActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopFleetActionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopFleetActionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopFleetActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopFleetActionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopFleetActionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopFleetActionsError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopFleetActionsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, StopFleetActionsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7820,22 +8209,28 @@ impl StopGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopGameSessionPlacementError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopGameSessionPlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopGameSessionPlacementError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopGameSessionPlacementError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7874,16 +8269,25 @@ impl StopMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopMatchmakingError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopMatchmakingError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopMatchmakingError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopMatchmakingError::NotFound(err.msg))
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StopMatchmakingError::UnsupportedRegion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopMatchmakingError::UnsupportedRegion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7922,20 +8326,28 @@ impl SuspendGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SuspendGameServerGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SuspendGameServerGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SuspendGameServerGroupError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SuspendGameServerGroupError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7974,16 +8386,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(TagResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TaggingFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8022,16 +8434,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(UntagResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TaggingFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8070,16 +8482,16 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateAliasError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8118,16 +8530,16 @@ impl UpdateBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateBuildError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateBuildError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateBuildError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBuildError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateBuildError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBuildError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateBuildError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8172,31 +8584,46 @@ impl UpdateFleetAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::Conflict(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::InvalidFleetStatus(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetAttributesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8244,27 +8671,40 @@ impl UpdateFleetCapacityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateFleetCapacityError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetCapacityError::InternalService(err.msg),
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetCapacityError::InvalidFleetStatus(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetCapacityError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetCapacityError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFleetCapacityError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetCapacityError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8312,35 +8752,46 @@ impl UpdateFleetPortSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::Conflict(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InvalidFleetStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::InvalidFleetStatus(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetPortSettingsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8382,16 +8833,22 @@ impl UpdateGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameServerError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameServerError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameServerError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameServerError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGameServerError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateGameServerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8430,20 +8887,28 @@ impl UpdateGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameServerGroupError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameServerGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameServerGroupError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameServerGroupError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8486,24 +8951,34 @@ impl UpdateGameSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGameSessionError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateGameSessionError::Conflict(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InvalidGameSessionStatus(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionError::InvalidGameSessionStatus(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGameSessionError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameSessionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8544,20 +9019,28 @@ impl UpdateGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionQueueError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionQueueError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionQueueError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGameSessionQueueError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8598,22 +9081,26 @@ impl UpdateMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMatchmakingConfigurationError::InternalService(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMatchmakingConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMatchmakingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMatchmakingConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
                     )
                 }
@@ -8664,27 +9151,34 @@ impl UpdateRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuntimeConfigurationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRuntimeConfigurationError::InvalidFleetStatus(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuntimeConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuntimeConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuntimeConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8726,16 +9220,16 @@ impl UpdateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateScriptError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateScriptError::InternalService(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateScriptError::InvalidRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateScriptError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateScriptError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateScriptError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateScriptError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8774,17 +9268,20 @@ impl ValidateMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ValidateMatchmakingRuleSetError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateMatchmakingRuleSetError::InternalService(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidateMatchmakingRuleSetError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateMatchmakingRuleSetError::InvalidRequest(err.msg),
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ValidateMatchmakingRuleSetError::UnsupportedRegion(err.msg),
                     )
                 }
@@ -9375,6 +9872,7 @@ impl GameLiftClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -9390,6 +9888,7 @@ impl GameLiftClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GameLiftClient {
         GameLiftClient { client, region }
     }
@@ -9403,6 +9902,9 @@ impl GameLift for GameLiftClient {
         input: AcceptMatchInput,
     ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptMatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.AcceptMatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9421,6 +9923,9 @@ impl GameLift for GameLiftClient {
         input: ClaimGameServerInput,
     ) -> Result<ClaimGameServerOutput, RusotoError<ClaimGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ClaimGameServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.ClaimGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9439,6 +9944,9 @@ impl GameLift for GameLiftClient {
         input: CreateAliasInput,
     ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9457,6 +9965,9 @@ impl GameLift for GameLiftClient {
         input: CreateBuildInput,
     ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBuild");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9475,6 +9986,9 @@ impl GameLift for GameLiftClient {
         input: CreateFleetInput,
     ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9493,6 +10007,9 @@ impl GameLift for GameLiftClient {
         input: CreateGameServerGroupInput,
     ) -> Result<CreateGameServerGroupOutput, RusotoError<CreateGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGameServerGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9511,6 +10028,9 @@ impl GameLift for GameLiftClient {
         input: CreateGameSessionInput,
     ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGameSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateGameSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9529,6 +10049,9 @@ impl GameLift for GameLiftClient {
         input: CreateGameSessionQueueInput,
     ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGameSessionQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9551,6 +10074,9 @@ impl GameLift for GameLiftClient {
         RusotoError<CreateMatchmakingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMatchmakingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9570,6 +10096,9 @@ impl GameLift for GameLiftClient {
         input: CreateMatchmakingRuleSetInput,
     ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMatchmakingRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9589,6 +10118,9 @@ impl GameLift for GameLiftClient {
         input: CreatePlayerSessionInput,
     ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePlayerSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreatePlayerSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9607,6 +10139,9 @@ impl GameLift for GameLiftClient {
         input: CreatePlayerSessionsInput,
     ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePlayerSessions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreatePlayerSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9625,6 +10160,9 @@ impl GameLift for GameLiftClient {
         input: CreateScriptInput,
     ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateScript");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9644,6 +10182,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateVpcPeeringAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9664,6 +10205,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateVpcPeeringConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9683,6 +10227,9 @@ impl GameLift for GameLiftClient {
         input: DeleteAliasInput,
     ) -> Result<(), RusotoError<DeleteAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9700,6 +10247,9 @@ impl GameLift for GameLiftClient {
         input: DeleteBuildInput,
     ) -> Result<(), RusotoError<DeleteBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBuild");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9717,6 +10267,9 @@ impl GameLift for GameLiftClient {
         input: DeleteFleetInput,
     ) -> Result<(), RusotoError<DeleteFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9734,6 +10287,9 @@ impl GameLift for GameLiftClient {
         input: DeleteGameServerGroupInput,
     ) -> Result<DeleteGameServerGroupOutput, RusotoError<DeleteGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGameServerGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9752,6 +10308,9 @@ impl GameLift for GameLiftClient {
         input: DeleteGameSessionQueueInput,
     ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGameSessionQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9774,6 +10333,9 @@ impl GameLift for GameLiftClient {
         RusotoError<DeleteMatchmakingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMatchmakingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9793,6 +10355,9 @@ impl GameLift for GameLiftClient {
         input: DeleteMatchmakingRuleSetInput,
     ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMatchmakingRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9812,6 +10377,9 @@ impl GameLift for GameLiftClient {
         input: DeleteScalingPolicyInput,
     ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9829,6 +10397,9 @@ impl GameLift for GameLiftClient {
         input: DeleteScriptInput,
     ) -> Result<(), RusotoError<DeleteScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteScript");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9847,6 +10418,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVpcPeeringAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9867,6 +10441,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVpcPeeringConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9886,6 +10463,9 @@ impl GameLift for GameLiftClient {
         input: DeregisterGameServerInput,
     ) -> Result<(), RusotoError<DeregisterGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterGameServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.DeregisterGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9903,6 +10483,9 @@ impl GameLift for GameLiftClient {
         input: DescribeAliasInput,
     ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAlias");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9921,6 +10504,9 @@ impl GameLift for GameLiftClient {
         input: DescribeBuildInput,
     ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBuild");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9939,6 +10525,9 @@ impl GameLift for GameLiftClient {
         input: DescribeEC2InstanceLimitsInput,
     ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEC2InstanceLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeEC2InstanceLimits");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9958,6 +10547,9 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetAttributesInput,
     ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFleetAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeFleetAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9977,6 +10569,9 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetCapacityInput,
     ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFleetCapacity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeFleetCapacity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9995,6 +10590,9 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetEventsInput,
     ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFleetEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeFleetEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10013,6 +10611,9 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetPortSettingsInput,
     ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFleetPortSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeFleetPortSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10032,6 +10633,9 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetUtilizationInput,
     ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFleetUtilization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeFleetUtilization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10051,6 +10655,9 @@ impl GameLift for GameLiftClient {
         input: DescribeGameServerInput,
     ) -> Result<DescribeGameServerOutput, RusotoError<DescribeGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGameServer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10069,6 +10676,9 @@ impl GameLift for GameLiftClient {
         input: DescribeGameServerGroupInput,
     ) -> Result<DescribeGameServerGroupOutput, RusotoError<DescribeGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGameServerGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10089,6 +10699,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGameSessionDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10109,6 +10722,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGameSessionPlacement");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10128,6 +10744,9 @@ impl GameLift for GameLiftClient {
         input: DescribeGameSessionQueuesInput,
     ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGameSessionQueues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionQueues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10147,6 +10766,9 @@ impl GameLift for GameLiftClient {
         input: DescribeGameSessionsInput,
     ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGameSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeGameSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10165,6 +10787,9 @@ impl GameLift for GameLiftClient {
         input: DescribeInstancesInput,
     ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10183,6 +10808,9 @@ impl GameLift for GameLiftClient {
         input: DescribeMatchmakingInput,
     ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMatchmaking");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10204,6 +10832,9 @@ impl GameLift for GameLiftClient {
         RusotoError<DescribeMatchmakingConfigurationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMatchmakingConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10227,6 +10858,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMatchmakingRuleSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingRuleSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10246,6 +10880,9 @@ impl GameLift for GameLiftClient {
         input: DescribePlayerSessionsInput,
     ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePlayerSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribePlayerSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10266,6 +10903,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRuntimeConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeRuntimeConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10285,6 +10925,9 @@ impl GameLift for GameLiftClient {
         input: DescribeScalingPoliciesInput,
     ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScalingPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeScalingPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10304,6 +10947,9 @@ impl GameLift for GameLiftClient {
         input: DescribeScriptInput,
     ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeScript");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10324,6 +10970,9 @@ impl GameLift for GameLiftClient {
         RusotoError<DescribeVpcPeeringAuthorizationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeVpcPeeringAuthorizations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringAuthorizations");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -10346,6 +10995,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeVpcPeeringConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10365,6 +11017,9 @@ impl GameLift for GameLiftClient {
         input: GetGameSessionLogUrlInput,
     ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetGameSessionLogUrl");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.GetGameSessionLogUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10383,6 +11038,9 @@ impl GameLift for GameLiftClient {
         input: GetInstanceAccessInput,
     ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceAccess");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.GetInstanceAccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10401,6 +11059,9 @@ impl GameLift for GameLiftClient {
         input: ListAliasesInput,
     ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10419,6 +11080,9 @@ impl GameLift for GameLiftClient {
         input: ListBuildsInput,
     ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBuilds");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10437,6 +11101,9 @@ impl GameLift for GameLiftClient {
         input: ListFleetsInput,
     ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10455,6 +11122,9 @@ impl GameLift for GameLiftClient {
         input: ListGameServerGroupsInput,
     ) -> Result<ListGameServerGroupsOutput, RusotoError<ListGameServerGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGameServerGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListGameServerGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10473,6 +11143,9 @@ impl GameLift for GameLiftClient {
         input: ListGameServersInput,
     ) -> Result<ListGameServersOutput, RusotoError<ListGameServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGameServers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListGameServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10491,6 +11164,9 @@ impl GameLift for GameLiftClient {
         input: ListScriptsInput,
     ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListScripts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListScripts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10509,6 +11185,9 @@ impl GameLift for GameLiftClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10527,6 +11206,9 @@ impl GameLift for GameLiftClient {
         input: PutScalingPolicyInput,
     ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutScalingPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.PutScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10545,6 +11227,9 @@ impl GameLift for GameLiftClient {
         input: RegisterGameServerInput,
     ) -> Result<RegisterGameServerOutput, RusotoError<RegisterGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterGameServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.RegisterGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10563,6 +11248,9 @@ impl GameLift for GameLiftClient {
         input: RequestUploadCredentialsInput,
     ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RequestUploadCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.RequestUploadCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10582,6 +11270,9 @@ impl GameLift for GameLiftClient {
         input: ResolveAliasInput,
     ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResolveAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.ResolveAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10600,6 +11291,9 @@ impl GameLift for GameLiftClient {
         input: ResumeGameServerGroupInput,
     ) -> Result<ResumeGameServerGroupOutput, RusotoError<ResumeGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResumeGameServerGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.ResumeGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10618,6 +11312,9 @@ impl GameLift for GameLiftClient {
         input: SearchGameSessionsInput,
     ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchGameSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GameLift.SearchGameSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10636,6 +11333,9 @@ impl GameLift for GameLiftClient {
         input: StartFleetActionsInput,
     ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartFleetActions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StartFleetActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10654,6 +11354,9 @@ impl GameLift for GameLiftClient {
         input: StartGameSessionPlacementInput,
     ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartGameSessionPlacement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StartGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10673,6 +11376,9 @@ impl GameLift for GameLiftClient {
         input: StartMatchBackfillInput,
     ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMatchBackfill");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StartMatchBackfill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10691,6 +11397,9 @@ impl GameLift for GameLiftClient {
         input: StartMatchmakingInput,
     ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMatchmaking");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StartMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10709,6 +11418,9 @@ impl GameLift for GameLiftClient {
         input: StopFleetActionsInput,
     ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopFleetActions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StopFleetActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10727,6 +11439,9 @@ impl GameLift for GameLiftClient {
         input: StopGameSessionPlacementInput,
     ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopGameSessionPlacement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StopGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10746,6 +11461,9 @@ impl GameLift for GameLiftClient {
         input: StopMatchmakingInput,
     ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopMatchmaking");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.StopMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10764,6 +11482,9 @@ impl GameLift for GameLiftClient {
         input: SuspendGameServerGroupInput,
     ) -> Result<SuspendGameServerGroupOutput, RusotoError<SuspendGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SuspendGameServerGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.SuspendGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10783,6 +11504,9 @@ impl GameLift for GameLiftClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10801,6 +11525,9 @@ impl GameLift for GameLiftClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10819,6 +11546,9 @@ impl GameLift for GameLiftClient {
         input: UpdateAliasInput,
     ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10837,6 +11567,9 @@ impl GameLift for GameLiftClient {
         input: UpdateBuildInput,
     ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateBuild");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10855,6 +11588,9 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetAttributesInput,
     ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateFleetAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateFleetAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10873,6 +11609,9 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetCapacityInput,
     ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateFleetCapacity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateFleetCapacity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10891,6 +11630,9 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetPortSettingsInput,
     ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateFleetPortSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateFleetPortSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10910,6 +11652,9 @@ impl GameLift for GameLiftClient {
         input: UpdateGameServerInput,
     ) -> Result<UpdateGameServerOutput, RusotoError<UpdateGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGameServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10928,6 +11673,9 @@ impl GameLift for GameLiftClient {
         input: UpdateGameServerGroupInput,
     ) -> Result<UpdateGameServerGroupOutput, RusotoError<UpdateGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGameServerGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10946,6 +11694,9 @@ impl GameLift for GameLiftClient {
         input: UpdateGameSessionInput,
     ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGameSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateGameSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10964,6 +11715,9 @@ impl GameLift for GameLiftClient {
         input: UpdateGameSessionQueueInput,
     ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGameSessionQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10986,6 +11740,9 @@ impl GameLift for GameLiftClient {
         RusotoError<UpdateMatchmakingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMatchmakingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11006,6 +11763,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRuntimeConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateRuntimeConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11025,6 +11785,9 @@ impl GameLift for GameLiftClient {
         input: UpdateScriptInput,
     ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateScript");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.UpdateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11044,6 +11807,9 @@ impl GameLift for GameLiftClient {
     ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ValidateMatchmakingRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GameLift.ValidateMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11057,3 +11823,1027 @@ impl GameLift for GameLiftClient {
             .deserialize::<ValidateMatchmakingRuleSetOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon GameLift API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GameLiftClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GameLiftBlockingClient {
+    client: GameLiftClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GameLiftBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GameLiftBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GameLiftBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GameLiftBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GameLiftBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> GameLiftBlockingClient {
+        GameLiftBlockingClient {
+            client: GameLiftClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Registers a player&#39;s acceptance or rejection of a proposed FlexMatch match. A matchmaking configuration may require player acceptance; if so, then matches built with that configuration cannot be completed unless all players accept the proposed match within a specified time limit. </p> <p>When FlexMatch builds a match, all the matchmaking tickets involved in the proposed match are placed into status <code>REQUIRES_ACCEPTANCE</code>. This is a trigger for your game to get acceptance from all players in the ticket. Acceptances are only valid for tickets when they are in this status; all other acceptances result in an error.</p> <p>To register acceptance, specify the ticket ID, a response, and one or more players. Once all players have registered acceptance, the matchmaking tickets advance to status <code>PLACING</code>, where a new game session is created for the match. </p> <p>If any player rejects the match, or if acceptances are not received before a specified timeout, the proposed match is dropped. The matchmaking tickets are then handled in one of two ways: For tickets where one or more players rejected the match, the ticket status is returned to <code>SEARCHING</code> to find a new match. For tickets where one or more players failed to respond, the ticket status is set to <code>CANCELLED</code>, and processing is terminated. A new matchmaking request for these players can be submitted as needed. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-events.html"> FlexMatch Events Reference</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::accept_match`.
+    pub fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
+        self.runtime.block_on(self.client.accept_match(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Locates an available game server and temporarily reserves it to host gameplay and players. This action is called by a game client or client service (such as a matchmaker) to request hosting resources for a new game session. In response, GameLift FleetIQ searches for an available game server in the specified game server group, places the game server in &quot;claimed&quot; status for 60 seconds, and returns connection information back to the requester so that players can connect to the game server. </p> <p>There are two ways you can claim a game server. For the first option, you provide a game server group ID only, which prompts GameLift FleetIQ to search for an available game server in the specified group and claim it. With this option, GameLift FleetIQ attempts to consolidate gameplay on as few instances as possible to minimize hosting costs. For the second option, you request a specific game server by its ID. This option results in a less efficient claiming process because it does not take advantage of consolidation and may fail if the requested game server is unavailable. </p> <p>To claim a game server, identify a game server group and (optionally) a game server ID. If your game requires that game data be provided to the game server at the start of a game, such as a game map or player information, you can provide it in your claim request. </p> <p>When a game server is successfully claimed, connection information is returned. A claimed game server&#39;s utilization status remains AVAILABLE, while the claim status is set to CLAIMED for up to 60 seconds. This time period allows the game server to be prompted to update its status to UTILIZED (using <a>UpdateGameServer</a>). If the game server&#39;s status is not updated within 60 seconds, the game server reverts to unclaimed status and is available to be claimed by another request.</p> <p>If you try to claim a specific game server, this request will fail in the following cases: (1) if the game server utilization status is UTILIZED, (2) if the game server claim status is CLAIMED, or (3) if the instance that the game server is running on is flagged as draining.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>RegisterGameServer</a> </p> </li> <li> <p> <a>ListGameServers</a> </p> </li> <li> <p> <a>ClaimGameServer</a> </p> </li> <li> <p> <a>DescribeGameServer</a> </p> </li> <li> <p> <a>UpdateGameServer</a> </p> </li> <li> <p> <a>DeregisterGameServer</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::claim_game_server`.
+    pub fn claim_game_server(
+        &self,
+        input: ClaimGameServerInput,
+    ) -> Result<ClaimGameServerOutput, RusotoError<ClaimGameServerError>> {
+        self.runtime.block_on(self.client.claim_game_server(input))
+    }
+
+    /// <p><p>Creates an alias for a fleet. In most situations, you can use an alias ID in place of a fleet ID. An alias provides a level of abstraction for a fleet that is useful when redirecting player traffic from one fleet to another, such as when updating your game build. </p> <p>Amazon GameLift supports two types of routing strategies for aliases: simple and terminal. A simple alias points to an active fleet. A terminal alias is used to display messaging or link to a URL instead of routing players to an active fleet. For example, you might use a terminal alias when a game version is no longer supported and you want to direct players to an upgrade site. </p> <p>To create a fleet alias, specify an alias name, routing strategy, and optional description. Each simple alias can point to only one fleet, but a fleet can have multiple aliases. If successful, a new alias record is returned, including an alias ID and an ARN. You can reassign an alias to another fleet by calling <code>UpdateAlias</code>.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_alias`.
+    pub fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// <p><p>Creates a new Amazon GameLift build resource for your game server binary files. Game server binaries must be combined into a zip file for use with Amazon GameLift. </p> <important> <p>When setting up a new game build for GameLift, we recommend using the AWS CLI command <b> <a href="https://docs.aws.amazon.com/cli/latest/reference/gamelift/upload-build.html">upload-build</a> </b>. This helper command combines two tasks: (1) it uploads your build files from a file directory to a GameLift Amazon S3 location, and (2) it creates a new build resource. </p> </important> <p>The <code>CreateBuild</code> operation can used in the following scenarios:</p> <ul> <li> <p>To create a new game build with build files that are in an S3 location under an AWS account that you control. To use this option, you must first give Amazon GameLift access to the S3 bucket. With permissions in place, call <code>CreateBuild</code> and specify a build name, operating system, and the S3 storage location of your game build.</p> </li> <li> <p>To directly upload your build files to a GameLift S3 location. To use this option, first call <code>CreateBuild</code> and specify a build name and operating system. This action creates a new build resource and also returns an S3 location with temporary access credentials. Use the credentials to manually upload your build files to the specified S3 location. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UploadingObjects.html">Uploading Objects</a> in the <i>Amazon S3 Developer Guide</i>. Build files can be uploaded to the GameLift S3 location once only; that can&#39;t be updated. </p> </li> </ul> <p>If successful, this operation creates a new build resource with a unique build ID and places it in <code>INITIALIZED</code> status. A build must be in <code>READY</code> status before you can create fleets with it.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html">Uploading Your Game</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-cli-uploading.html#gamelift-build-cli-uploading-create-build"> Create a Build with Files in Amazon S3</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_build`.
+    pub fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
+        self.runtime.block_on(self.client.create_build(input))
+    }
+
+    /// <p><p>Creates a new fleet to run your game servers. whether they are custom game builds or Realtime Servers with game-specific script. A fleet is a set of Amazon Elastic Compute Cloud (Amazon EC2) instances, each of which can host multiple game sessions. When creating a fleet, you choose the hardware specifications, set some configuration options, and specify the game server to deploy on the new fleet. </p> <p>To create a new fleet, provide the following: (1) a fleet name, (2) an EC2 instance type and fleet type (spot or on-demand), (3) the build ID for your game build or script ID if using Realtime Servers, and (4) a runtime configuration, which determines how game servers will run on each instance in the fleet. </p> <p>If the <code>CreateFleet</code> call is successful, Amazon GameLift performs the following tasks. You can track the process of a fleet by checking the fleet status or by monitoring fleet creation events:</p> <ul> <li> <p>Creates a fleet resource. Status: <code>NEW</code>.</p> </li> <li> <p>Begins writing events to the fleet event log, which can be accessed in the Amazon GameLift console.</p> </li> <li> <p>Sets the fleet&#39;s target capacity to 1 (desired instances), which triggers Amazon GameLift to start one new EC2 instance.</p> </li> <li> <p>Downloads the game build or Realtime script to the new instance and installs it. Statuses: <code>DOWNLOADING</code>, <code>VALIDATING</code>, <code>BUILDING</code>. </p> </li> <li> <p>Starts launching server processes on the instance. If the fleet is configured to run multiple server processes per instance, Amazon GameLift staggers each process launch by a few seconds. Status: <code>ACTIVATING</code>.</p> </li> <li> <p>Sets the fleet&#39;s status to <code>ACTIVE</code> as soon as one server process is ready to host a game session.</p> </li> </ul> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting Up Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html#fleets-creating-debug-creation">Debug Fleet Creation Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_fleet`.
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Creates a GameLift FleetIQ game server group to manage a collection of EC2 instances for game hosting. In addition to creating the game server group, this action also creates an Auto Scaling group in your AWS account and establishes a link between the two groups. You have full control over configuration of the Auto Scaling group, but GameLift FleetIQ routinely certain Auto Scaling group properties in order to optimize the group&#39;s instances for low-cost game hosting. You can view the status of your game server groups in the GameLift Console. Game server group metrics and events are emitted to Amazon CloudWatch.</p> <p>Prior creating a new game server group, you must set up the following: </p> <ul> <li> <p>An EC2 launch template. The template provides configuration settings for a set of EC2 instances and includes the game server build that you want to deploy and run on each instance. For more information on creating a launch template, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ec2-launch-templates.html"> Launching an Instance from a Launch Template</a> in the <i>Amazon EC2 User Guide</i>. </p> </li> <li> <p>An IAM role. The role sets up limited access to your AWS account, allowing GameLift FleetIQ to create and manage the EC2 Auto Scaling group, get instance data, and emit metrics and events to CloudWatch. For more information on setting up an IAM permissions policy with principal access for GameLift, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/s3-bucket-user-policy-specifying-principal-intro.html"> Specifying a Principal in a Policy</a> in the <i>Amazon S3 Developer Guide</i>.</p> </li> </ul> <p>To create a new game server group, provide a name and specify the IAM role and EC2 launch template. You also need to provide a list of instance types to be used in the group and set initial maximum and minimum limits on the group&#39;s instance count. You can optionally set an autoscaling policy with target tracking based on a GameLift FleetIQ metric.</p> <p>Once the game server group and corresponding Auto Scaling group are created, you have full access to change the Auto Scaling group&#39;s configuration as needed. Keep in mind, however, that some properties are periodically updated by GameLift FleetIQ as it balances the group&#39;s instances based on availability and cost.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-asgroups.html">Updating a GameLift FleetIQ-Linked Auto Scaling Group</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_game_server_group`.
+    pub fn create_game_server_group(
+        &self,
+        input: CreateGameServerGroupInput,
+    ) -> Result<CreateGameServerGroupOutput, RusotoError<CreateGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.create_game_server_group(input))
+    }
+
+    /// <p><p>Creates a multiplayer game session for players. This action creates a game session record and assigns an available server process in the specified fleet to host the game session. A fleet must have an <code>ACTIVE</code> status before a game session can be created in it.</p> <p>To create a game session, specify either fleet ID or alias ID and indicate a maximum number of players to allow in the game session. You can also provide a name and game-specific properties for this game session. If successful, a <a>GameSession</a> object is returned containing the game session properties and other settings you specified.</p> <p> <b>Idempotency tokens.</b> You can add a token that uniquely identifies game session requests. This is useful for ensuring that game session requests are idempotent. Multiple requests with the same idempotency token are processed only once; subsequent requests return the original result. All response values are the same with the exception of game session status, which may change.</p> <p> <b>Resource creation limits.</b> If you are creating a game session on a fleet with a resource creation limit policy in force, then you must specify a creator ID. Without this ID, Amazon GameLift has no way to evaluate the policy for this new game session request.</p> <p> <b>Player acceptance policy.</b> By default, newly created game sessions are open to new players. You can restrict new player access by using <a>UpdateGameSession</a> to change the game session&#39;s player session creation policy.</p> <p> <b>Game session logs.</b> Logs are retained for all active game sessions for 14 days. To access the logs, call <a>GetGameSessionLogUrl</a> to download the log files.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::create_game_session`.
+    pub fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
+        self.runtime
+            .block_on(self.client.create_game_session(input))
+    }
+
+    /// <p><p>Establishes a new queue for processing requests to place new game sessions. A queue identifies where new game sessions can be hosted -- by specifying a list of destinations (fleets or aliases) -- and how long requests can wait in the queue before timing out. You can set up a queue to try to place game sessions on fleets in multiple Regions. To add placement requests to a queue, call <a>StartGameSessionPlacement</a> and reference the queue name.</p> <p> <b>Destination order.</b> When processing a request for a game session, Amazon GameLift tries each destination in order until it finds one with available resources to host the new game session. A queue&#39;s default order is determined by how destinations are listed. The default order is overridden when a game session placement request provides player latency information. Player latency information enables Amazon GameLift to prioritize destinations where players report the lowest average latency, as a result placing the new game session where the majority of players will have the best possible gameplay experience.</p> <p> <b>Player latency policies.</b> For placement requests containing player latency information, use player latency policies to protect individual players from very high latencies. With a latency cap, even when a destination can deliver a low latency for most players, the game is not placed where any individual player is reporting latency higher than a policy&#39;s maximum. A queue can have multiple latency policies, which are enforced consecutively starting with the policy with the lowest latency cap. Use multiple policies to gradually relax latency controls; for example, you might set a policy with a low latency cap for the first 60 seconds, a second policy with a higher cap for the next 60 seconds, etc. </p> <p>To create a new queue, provide a name, timeout value, a list of destinations and, if desired, a set of latency policies. If successful, a new queue object is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/queues-design.html"> Design a Game Session Queue</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/queues-creating.html"> Create a Game Session Queue</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_game_session_queue`.
+    pub fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
+        self.runtime
+            .block_on(self.client.create_game_session_queue(input))
+    }
+
+    /// <p><p>Defines a new matchmaking configuration for use with FlexMatch. A matchmaking configuration sets out guidelines for matching players and getting the matches into games. You can set up multiple matchmaking configurations to handle the scenarios needed for your game. Each matchmaking ticket (<a>StartMatchmaking</a> or <a>StartMatchBackfill</a>) specifies a configuration for the match and provides player attributes to support the configuration being used. </p> <p>To create a matchmaking configuration, at a minimum you must specify the following: configuration name; a rule set that governs how to evaluate players and find acceptable matches; a game session queue to use when placing a new game session for the match; and the maximum time allowed for a matchmaking attempt.</p> <p>There are two ways to track the progress of matchmaking tickets: (1) polling ticket status with <a>DescribeMatchmaking</a>; or (2) receiving notifications with Amazon Simple Notification Service (SNS). To use notifications, you first need to set up an SNS topic to receive the notifications, and provide the topic ARN in the matchmaking configuration. Since notifications promise only &quot;best effort&quot; delivery, we recommend calling <code>DescribeMatchmaking</code> if no notifications are received within 30 seconds.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Setting up Notifications for Matchmaking</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_matchmaking_configuration`.
+    pub fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> Result<
+        CreateMatchmakingConfigurationOutput,
+        RusotoError<CreateMatchmakingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_matchmaking_configuration(input))
+    }
+
+    /// <p><p>Creates a new rule set for FlexMatch matchmaking. A rule set describes the type of match to create, such as the number and size of teams. It also sets the parameters for acceptable player matches, such as minimum skill level or character type. A rule set is used by a <a>MatchmakingConfiguration</a>. </p> <p>To create a matchmaking rule set, provide unique rule set name and the rule set body in JSON format. Rule sets must be defined in the same Region as the matchmaking configuration they are used with.</p> <p>Since matchmaking rule sets cannot be edited, it is a good idea to check the rule set syntax using <a>ValidateMatchmakingRuleSet</a> before creating a new rule set.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html">Design a Matchmaker</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-intro.html">Matchmaking with FlexMatch</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_matchmaking_rule_set`.
+    pub fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
+        self.runtime
+            .block_on(self.client.create_matchmaking_rule_set(input))
+    }
+
+    /// <p><p>Reserves an open player slot in an active game session. Before a player can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a group of players to a game session, use <a>CreatePlayerSessions</a>. When the player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create a player session, specify a game session ID, player ID, and optionally a string of player data. If successful, a slot is reserved in the game session for the player and a new <a>PlayerSession</a> object is returned. Player sessions cannot be updated. </p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::create_player_session`.
+    pub fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
+        self.runtime
+            .block_on(self.client.create_player_session(input))
+    }
+
+    /// <p><p>Reserves open slots in a game session for a group of players. Before players can be added, a game session must have an <code>ACTIVE</code> status, have a creation policy of <code>ALLOW_ALL</code>, and have an open player slot. To add a single player to a game session, use <a>CreatePlayerSession</a>. When a player connects to the game server and references a player session ID, the game server contacts the Amazon GameLift service to validate the player reservation and accept the player.</p> <p>To create player sessions, specify a game session ID, a list of player IDs, and optionally a set of player data strings. If successful, a slot is reserved in the game session for each player and a set of new <a>PlayerSession</a> objects is returned. Player sessions cannot be updated.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::create_player_sessions`.
+    pub fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
+        self.runtime
+            .block_on(self.client.create_player_sessions(input))
+    }
+
+    /// <p><p>Creates a new script record for your Realtime Servers script. Realtime scripts are JavaScript that provide configuration settings and optional custom game logic for your game. The script is deployed when you create a Realtime Servers fleet to host your game sessions. Script logic is executed during an active game session. </p> <p>To create a new script record, specify a script name and provide the script file(s). The script files and all dependencies must be zipped into a single file. You can pull the zip file from either of these locations: </p> <ul> <li> <p>A locally available directory. Use the <i>ZipFile</i> parameter for this option.</p> </li> <li> <p>An Amazon Simple Storage Service (Amazon S3) bucket under your AWS account. Use the <i>StorageLocation</i> parameter for this option. You&#39;ll need to have an Identity Access Management (IAM) role that allows the Amazon GameLift service to access your S3 bucket. </p> </li> </ul> <p>If the call is successful, a new script record is created with a unique script ID. If the script file is provided as a local file, the file is uploaded to an Amazon GameLift-owned S3 bucket and the script record&#39;s storage location reflects this location. If the script file is provided as an S3 bucket, Amazon GameLift accesses the file at this storage location as needed for deployment.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/setting-up-role.html">Set Up a Role for Amazon GameLift Access</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_script`.
+    pub fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
+        self.runtime.block_on(self.client.create_script(input))
+    }
+
+    /// <p><p>Requests authorization to create or delete a peer connection between the VPC for your Amazon GameLift fleet and a virtual private cloud (VPC) in your AWS account. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. Once you&#39;ve received authorization, call <a>CreateVpcPeeringConnection</a> to establish the peering connection. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>You can peer with VPCs that are owned by any AWS account you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different Regions.</p> <p>To request authorization to create a connection, call this operation from the AWS account with the VPC that you want to peer to your Amazon GameLift fleet. For example, to enable your game servers to retrieve data from a DynamoDB table, use the account that manages that DynamoDB resource. Identify the following values: (1) The ID of the VPC that you want to peer with, and (2) the ID of the AWS account that you use to manage Amazon GameLift. If successful, VPC peering is authorized for the specified VPC. </p> <p>To request authorization to delete a connection, call this operation from the AWS account with the VPC that is peered with your Amazon GameLift fleet. Identify the following values: (1) VPC ID that you want to delete the peering connection for, and (2) ID of the AWS account that you use to manage Amazon GameLift. </p> <p>The authorization remains valid for 24 hours unless it is canceled by a call to <a>DeleteVpcPeeringAuthorization</a>. You must create or delete the peering connection while the authorization is valid. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_vpc_peering_authorization`.
+    pub fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_vpc_peering_authorization(input))
+    }
+
+    /// <p><p>Establishes a VPC peering connection between a virtual private cloud (VPC) in an AWS account with the VPC for your Amazon GameLift fleet. VPC peering enables the game servers on your fleet to communicate directly with other AWS resources. You can peer with VPCs in any AWS account that you have access to, including the account that you use to manage your Amazon GameLift fleets. You cannot peer with VPCs that are in different Regions. For more information, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/vpc-peering.html">VPC Peering with Amazon GameLift Fleets</a>.</p> <p>Before calling this operation to establish the peering connection, you first need to call <a>CreateVpcPeeringAuthorization</a> and identify the VPC you want to peer with. Once the authorization for the specified VPC is issued, you have 24 hours to establish the connection. These two operations handle all tasks necessary to peer the two VPCs, including acceptance, updating routing tables, etc. </p> <p>To establish the connection, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the following values: (1) The ID of the fleet you want to be enable a VPC peering connection for; (2) The AWS account with the VPC that you want to peer with; and (3) The ID of the VPC you want to peer with. This operation is asynchronous. If successful, a <a>VpcPeeringConnection</a> request is created. You can use continuous polling to track the request&#39;s status using <a>DescribeVpcPeeringConnections</a>, or by monitoring fleet events for success or failure using <a>DescribeFleetEvents</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::create_vpc_peering_connection`.
+    pub fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_vpc_peering_connection(input))
+    }
+
+    /// <p><p>Deletes an alias. This action removes all record of the alias. Game clients attempting to access a server process using the deleted alias receive an error. To delete an alias, specify the alias ID to be deleted.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_alias`.
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasInput,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// <p><p>Deletes a build. This action permanently deletes the build resource and any uploaded build files. Deleting a build does not affect the status of any active fleets using the build, but you can no longer create new fleets with the deleted build.</p> <p>To delete a build, specify the build ID. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html"> Upload a Custom Server Build</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_build`.
+    pub fn delete_build(
+        &self,
+        input: DeleteBuildInput,
+    ) -> Result<(), RusotoError<DeleteBuildError>> {
+        self.runtime.block_on(self.client.delete_build(input))
+    }
+
+    /// <p><p>Deletes everything related to a fleet. Before deleting a fleet, you must set the fleet&#39;s desired capacity to zero. See <a>UpdateFleetCapacity</a>.</p> <p>If the fleet being deleted has a VPC peering connection, you first need to get a valid authorization (good for 24 hours) by calling <a>CreateVpcPeeringAuthorization</a>. You do not need to explicitly delete the VPC peering connection--this is done as part of the delete fleet process.</p> <p>This action removes the fleet and its resources. Once a fleet is deleted, you can no longer use any of the resource in that fleet.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_fleet`.
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetInput,
+    ) -> Result<(), RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Terminates a game server group and permanently deletes the game server group record. You have several options for how these resources are impacted when deleting the game server group. Depending on the type of delete action selected, this action may affect three types of resources: the game server group, the corresponding Auto Scaling group, and all game servers currently running in the group. </p> <p>To delete a game server group, identify the game server group to delete and specify the type of delete action to initiate. Game server groups can only be deleted if they are in ACTIVE or ERROR status.</p> <p>If the delete request is successful, a series of actions are kicked off. The game server group status is changed to DELETE_SCHEDULED, which prevents new game servers from being registered and stops autoscaling activity. Once all game servers in the game server group are de-registered, GameLift FleetIQ can begin deleting resources. If any of the delete actions fail, the game server group is placed in ERROR status.</p> <p>GameLift FleetIQ emits delete events to Amazon CloudWatch.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_game_server_group`.
+    pub fn delete_game_server_group(
+        &self,
+        input: DeleteGameServerGroupInput,
+    ) -> Result<DeleteGameServerGroupOutput, RusotoError<DeleteGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_game_server_group(input))
+    }
+
+    /// <p><p>Deletes a game session queue. This action means that any <a>StartGameSessionPlacement</a> requests that reference this queue will fail. To delete a queue, specify the queue name.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/queues-intro.html"> Using Multi-Region Queues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_game_session_queue`.
+    pub fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
+        self.runtime
+            .block_on(self.client.delete_game_session_queue(input))
+    }
+
+    /// <p><p>Permanently removes a FlexMatch matchmaking configuration. To delete, specify the configuration name. A matchmaking configuration cannot be deleted if it is being used in any active matchmaking tickets.</p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_matchmaking_configuration`.
+    pub fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> Result<
+        DeleteMatchmakingConfigurationOutput,
+        RusotoError<DeleteMatchmakingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_matchmaking_configuration(input))
+    }
+
+    /// <p><p>Deletes an existing matchmaking rule set. To delete the rule set, provide the rule set name. Rule sets cannot be deleted if they are currently being used by a matchmaking configuration. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_matchmaking_rule_set`.
+    pub fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
+        self.runtime
+            .block_on(self.client.delete_matchmaking_rule_set(input))
+    }
+
+    /// <p><p>Deletes a fleet scaling policy. This action means that the policy is no longer in force and removes all record of it. To delete a scaling policy, specify both the scaling policy name and the fleet ID it is associated with.</p> <p>To temporarily suspend scaling policies, call <a>StopFleetActions</a>. This operation suspends all policies for the fleet.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_scaling_policy`.
+    pub fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_scaling_policy(input))
+    }
+
+    /// <p><p>Deletes a Realtime script. This action permanently deletes the script record. If script files were uploaded, they are also deleted (files stored in an S3 bucket are not deleted). </p> <p>To delete a script, specify the script ID. Before deleting a script, be sure to terminate all fleets that are deployed with the script being deleted. Fleet instances periodically check for script updates, and if the script record no longer exists, the instance will go into an error state and be unable to host game sessions.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_script`.
+    pub fn delete_script(
+        &self,
+        input: DeleteScriptInput,
+    ) -> Result<(), RusotoError<DeleteScriptError>> {
+        self.runtime.block_on(self.client.delete_script(input))
+    }
+
+    /// <p><p>Cancels a pending VPC peering authorization for the specified VPC. If you need to delete an existing VPC peering connection, call <a>DeleteVpcPeeringConnection</a>. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_vpc_peering_authorization`.
+    pub fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_vpc_peering_authorization(input))
+    }
+
+    /// <p><p>Removes a VPC peering connection. To delete the connection, you must have a valid authorization for the VPC peering connection that you want to delete. You can check for an authorization by calling <a>DescribeVpcPeeringAuthorizations</a> or request a new one using <a>CreateVpcPeeringAuthorization</a>. </p> <p>Once a valid authorization exists, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Identify the connection to delete by the connection ID and fleet ID. If successful, the connection is removed. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::delete_vpc_peering_connection`.
+    pub fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_vpc_peering_connection(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Removes the game server resource from the game server group. As a result of this action, the de-registered game server can no longer be claimed and will not returned in a list of active game servers. </p> <p>To de-register a game server, specify the game server group and game server ID. If successful, this action emits a CloudWatch event with termination time stamp and reason.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>RegisterGameServer</a> </p> </li> <li> <p> <a>ListGameServers</a> </p> </li> <li> <p> <a>ClaimGameServer</a> </p> </li> <li> <p> <a>DescribeGameServer</a> </p> </li> <li> <p> <a>UpdateGameServer</a> </p> </li> <li> <p> <a>DeregisterGameServer</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::deregister_game_server`.
+    pub fn deregister_game_server(
+        &self,
+        input: DeregisterGameServerInput,
+    ) -> Result<(), RusotoError<DeregisterGameServerError>> {
+        self.runtime
+            .block_on(self.client.deregister_game_server(input))
+    }
+
+    /// <p><p>Retrieves properties for an alias. This operation returns all alias metadata and settings. To get an alias&#39;s target fleet ID only, use <code>ResolveAlias</code>. </p> <p>To get alias properties, specify the alias ID. If successful, the requested alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_alias`.
+    pub fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
+        self.runtime.block_on(self.client.describe_alias(input))
+    }
+
+    /// <p><p>Retrieves properties for a custom game build. To request a build resource, specify a build ID. If successful, an object containing the build properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html"> Upload a Custom Server Build</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_build`.
+    pub fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
+        self.runtime.block_on(self.client.describe_build(input))
+    }
+
+    /// <p><p>Retrieves the following information for the specified EC2 instance type:</p> <ul> <li> <p>Maximum number of instances allowed per AWS account (service limit).</p> </li> <li> <p>Current usage for the AWS account.</p> </li> </ul> <p>To learn more about the capabilities of each instance type, see <a href="http://aws.amazon.com/ec2/instance-types/">Amazon EC2 Instance Types</a>. Note that the instance types offered may vary depending on the region.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_ec2_instance_limits`.
+    pub fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_ec2_instance_limits(input))
+    }
+
+    /// <p><p>Retrieves core properties, including configuration, status, and metadata, for a fleet. </p> <p>To get attributes for one or more fleets, provide a list of fleet IDs or fleet ARNs. To get attributes for all fleets, do not specify a fleet identifier. When requesting attributes for multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetAttributes</a> object is returned for each fleet requested, unless the fleet identifier is not found.</p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed number.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_fleet_attributes`.
+    pub fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_attributes(input))
+    }
+
+    /// <p><p>Retrieves the current capacity statistics for one or more fleets. These statistics present a snapshot of the fleet&#39;s instances and provide insight on current or imminent scaling activity. To get statistics on game hosting activity in the fleet, see <a>DescribeFleetUtilization</a>.</p> <p>You can request capacity for all fleets or specify a list of one or more fleet identifiers. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetCapacity</a> object is returned for each requested fleet ID. When a list of fleet IDs is provided, attribute objects are returned only for fleets that currently exist.</p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/monitoring-cloudwatch.html#gamelift-metrics-fleet">GameLift Metrics for Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_fleet_capacity`.
+    pub fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_capacity(input))
+    }
+
+    /// <p><p>Retrieves entries from the specified fleet&#39;s event log. You can specify a time range to limit the result set. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a collection of event log entries matching the request are returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_fleet_events`.
+    pub fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_events(input))
+    }
+
+    /// <p><p>Retrieves a fleet&#39;s inbound connection permissions. Connection permissions specify the range of IP addresses and port settings that incoming traffic can use to access server processes in the fleet. Game sessions that are running on instances in the fleet use connections that fall in this range. </p> <p>To get a fleet&#39;s inbound connection permissions, specify the fleet&#39;s unique identifier. If successful, a collection of <a>IpPermission</a> objects is returned for the requested fleet ID. If the requested fleet has been deleted, the result set is empty.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_fleet_port_settings`.
+    pub fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_port_settings(input))
+    }
+
+    /// <p><p>Retrieves utilization statistics for one or more fleets. These statistics provide insight into how available hosting resources are currently being used. To get statistics on available hosting resources, see <a>DescribeFleetCapacity</a>.</p> <p>You can request utilization data for all fleets, or specify a list of one or more fleet IDs. When requesting multiple fleets, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>FleetUtilization</a> object is returned for each requested fleet ID, unless the fleet identifier is not found. </p> <note> <p>Some API actions may limit the number of fleet IDs allowed in one request. If a request exceeds this limit, the request fails and the error message includes the maximum allowed.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/monitoring-cloudwatch.html#gamelift-metrics-fleet">GameLift Metrics for Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_fleet_utilization`.
+    pub fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_utilization(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Retrieves information for a game server resource. Information includes the game server statuses, health check info, and the instance the game server is running on. </p> <p>To retrieve game server information, specify the game server ID. If successful, the requested game server object is returned. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>RegisterGameServer</a> </p> </li> <li> <p> <a>ListGameServers</a> </p> </li> <li> <p> <a>ClaimGameServer</a> </p> </li> <li> <p> <a>DescribeGameServer</a> </p> </li> <li> <p> <a>UpdateGameServer</a> </p> </li> <li> <p> <a>DeregisterGameServer</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_game_server`.
+    pub fn describe_game_server(
+        &self,
+        input: DescribeGameServerInput,
+    ) -> Result<DescribeGameServerOutput, RusotoError<DescribeGameServerError>> {
+        self.runtime
+            .block_on(self.client.describe_game_server(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Retrieves information on a game server group. </p> <p>To get attributes for a game server group, provide a group name or ARN value. If successful, a <a>GameServerGroup</a> object is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_game_server_group`.
+    pub fn describe_game_server_group(
+        &self,
+        input: DescribeGameServerGroupInput,
+    ) -> Result<DescribeGameServerGroupOutput, RusotoError<DescribeGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_game_server_group(input))
+    }
+
+    /// <p><p>Retrieves properties, including the protection policy in force, for one or more game sessions. This action can be used in several ways: (1) provide a <code>GameSessionId</code> or <code>GameSessionArn</code> to request details for a specific game session; (2) provide either a <code>FleetId</code> or an <code>AliasId</code> to request properties for all game sessions running on a fleet. </p> <p>To get game session record(s), specify just one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionDetail</a> object is returned for each session matching the request.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_game_session_details`.
+    pub fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_game_session_details(input))
+    }
+
+    /// <p><p>Retrieves properties and current status of a game session placement request. To get game session placement details, specify the placement ID. If successful, a <a>GameSessionPlacement</a> object is returned.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_game_session_placement`.
+    pub fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_game_session_placement(input))
+    }
+
+    /// <p><p>Retrieves the properties for one or more game session queues. When requesting multiple queues, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSessionQueue</a> object is returned for each requested queue. When specifying a list of queues, objects are returned only for queues that currently exist in the Region.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/queues-console.html"> View Your Queues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_game_session_queues`.
+    pub fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
+        self.runtime
+            .block_on(self.client.describe_game_session_queues(input))
+    }
+
+    /// <p><p>Retrieves a set of one or more game sessions. Request a specific game session or request all game sessions on a fleet. Alternatively, use <a>SearchGameSessions</a> to request a set of active game sessions that are filtered by certain criteria. To retrieve protection policy settings for game sessions, use <a>DescribeGameSessionDetails</a>.</p> <p>To get game sessions, specify one of the following: game session ID, fleet ID, or alias ID. You can filter this request by game session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>GameSession</a> object is returned for each game session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_game_sessions`.
+    pub fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
+        self.runtime
+            .block_on(self.client.describe_game_sessions(input))
+    }
+
+    /// <p><p>Retrieves information about a fleet&#39;s instances, including instance IDs. Use this action to get details on all instances in the fleet or get details on one specific instance.</p> <p>To get a specific instance, specify fleet ID and instance ID. To get all instances in a fleet, specify a fleet ID only. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, an <a>Instance</a> object is returned for each result.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-remote-access.html">Remotely Access Fleet Instances</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html">Debug Fleet Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>DescribeInstances</a> </p> </li> <li> <p> <a>GetInstanceAccess</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_instances`.
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
+        self.runtime.block_on(self.client.describe_instances(input))
+    }
+
+    /// <p><p>Retrieves one or more matchmaking tickets. Use this operation to retrieve ticket information, including status and--once a successful match is made--acquire connection information for the resulting new game session. </p> <p>You can use this operation to track the progress of matchmaking requests (through polling) as an alternative to using event notifications. See more details on tracking matchmaking requests through polling or notifications in <a>StartMatchmaking</a>. </p> <p>To request matchmaking tickets, provide a list of up to 10 ticket IDs. If the request is successful, a ticket object is returned for each requested ID that currently exists.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_matchmaking`.
+    pub fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
+        self.runtime
+            .block_on(self.client.describe_matchmaking(input))
+    }
+
+    /// <p><p>Retrieves the details of FlexMatch matchmaking configurations. With this operation, you have the following options: (1) retrieve all existing configurations, (2) provide the names of one or more configurations to retrieve, or (3) retrieve all configurations that use a specified rule set name. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a configuration is returned for each requested name. When specifying a list of names, only configurations that currently exist are returned. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/matchmaker-build.html"> Setting Up FlexMatch Matchmakers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_matchmaking_configurations`.
+    pub fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> Result<
+        DescribeMatchmakingConfigurationsOutput,
+        RusotoError<DescribeMatchmakingConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_matchmaking_configurations(input))
+    }
+
+    /// <p><p>Retrieves the details for FlexMatch matchmaking rule sets. You can request all existing rule sets for the Region, or provide a list of one or more rule set names. When requesting multiple items, use the pagination parameters to retrieve results as a set of sequential pages. If successful, a rule set is returned for each requested name. </p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_matchmaking_rule_sets`.
+    pub fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_matchmaking_rule_sets(input))
+    }
+
+    /// <p><p>Retrieves properties for one or more player sessions. This action can be used in several ways: (1) provide a <code>PlayerSessionId</code> to request properties for a specific player session; (2) provide a <code>GameSessionId</code> to request properties for all player sessions in the specified game session; (3) provide a <code>PlayerId</code> to request properties for all player sessions of a specified player. </p> <p>To get game session record(s), specify only one of the following: a player session ID, a game session ID, or a player ID. You can filter this request by player session status. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, a <a>PlayerSession</a> object is returned for each session matching the request.</p> <p> <i>Available in Amazon GameLift Local.</i> </p> <ul> <li> <p> <a>CreatePlayerSession</a> </p> </li> <li> <p> <a>CreatePlayerSessions</a> </p> </li> <li> <p> <a>DescribePlayerSessions</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_player_sessions`.
+    pub fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
+        self.runtime
+            .block_on(self.client.describe_player_sessions(input))
+    }
+
+    /// <p><p>Retrieves a fleet&#39;s runtime configuration settings. The runtime configuration tells Amazon GameLift which server processes to run (and how) on each instance in the fleet.</p> <p>To get a runtime configuration, specify the fleet&#39;s unique identifier. If successful, a <a>RuntimeConfiguration</a> object is returned for the requested fleet. If the requested fleet has been deleted, the result set is empty.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-multiprocess.html">Running Multiple Processes on a Fleet</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p>Describe fleets:</p> <ul> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>DescribeFleetPortSettings</a> </p> </li> <li> <p> <a>DescribeFleetUtilization</a> </p> </li> <li> <p> <a>DescribeRuntimeConfiguration</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p> <a>DescribeFleetEvents</a> </p> </li> </ul> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_runtime_configuration`.
+    pub fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_runtime_configuration(input))
+    }
+
+    /// <p><p>Retrieves all scaling policies applied to a fleet.</p> <p>To get a fleet&#39;s scaling policies, specify the fleet ID. You can filter this request by policy status, such as to retrieve only active scaling policies. Use the pagination parameters to retrieve results as a set of sequential pages. If successful, set of <a>ScalingPolicy</a> objects is returned for the fleet.</p> <p>A fleet may have all of its scaling policies suspended (<a>StopFleetActions</a>). This action does not affect the status of the scaling policies, which remains ACTIVE. To see whether a fleet&#39;s scaling policies are in force or suspended, call <a>DescribeFleetAttributes</a> and check the stopped actions.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_scaling_policies`.
+    pub fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_policies(input))
+    }
+
+    /// <p><p>Retrieves properties for a Realtime script. </p> <p>To request a script record, specify the script ID. If successful, an object containing the script properties is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_script`.
+    pub fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
+        self.runtime.block_on(self.client.describe_script(input))
+    }
+
+    /// <p><p>Retrieves valid VPC peering authorizations that are pending for the AWS account. This operation returns all VPC peering authorizations and requests for peering. This includes those initiated and received by this account. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_vpc_peering_authorizations`.
+    pub fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> Result<
+        DescribeVpcPeeringAuthorizationsOutput,
+        RusotoError<DescribeVpcPeeringAuthorizationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_peering_authorizations())
+    }
+
+    /// <p><p>Retrieves information on VPC peering connections. Use this operation to get peering information for all fleets or for one specific fleet ID. </p> <p>To retrieve connection information, call this operation from the AWS account that is used to manage the Amazon GameLift fleets. Specify a fleet ID or leave the parameter empty to retrieve all connection records. If successful, the retrieved information includes both active and pending connections. Active connections identify the IpV4 CIDR block that the VPC uses to connect. </p> <ul> <li> <p> <a>CreateVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>DescribeVpcPeeringAuthorizations</a> </p> </li> <li> <p> <a>DeleteVpcPeeringAuthorization</a> </p> </li> <li> <p> <a>CreateVpcPeeringConnection</a> </p> </li> <li> <p> <a>DescribeVpcPeeringConnections</a> </p> </li> <li> <p> <a>DeleteVpcPeeringConnection</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::describe_vpc_peering_connections`.
+    pub fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_vpc_peering_connections(input))
+    }
+
+    /// <p><p>Retrieves the location of stored game session logs for a specified game session. When a game session is terminated, Amazon GameLift automatically stores the logs in Amazon S3 and retains them for 14 days. Use this URL to download the logs.</p> <note> <p>See the <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_gamelift">AWS Service Limits</a> page for maximum log file sizes. Log files that exceed this limit are not saved.</p> </note> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::get_game_session_log_url`.
+    pub fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
+        self.runtime
+            .block_on(self.client.get_game_session_log_url(input))
+    }
+
+    /// <p><p>Requests remote access to a fleet instance. Remote access is useful for debugging, gathering benchmarking data, or observing activity in real time. </p> <p>To remotely access an instance, you need credentials that match the operating system of the instance. For a Windows instance, Amazon GameLift returns a user name and password as strings for use with a Windows Remote Desktop client. For a Linux instance, Amazon GameLift returns a user name and RSA private key, also as strings, for use with an SSH client. The private key must be saved in the proper format to a <code>.pem</code> file before using. If you&#39;re making this request using the AWS CLI, saving the secret can be handled as part of the GetInstanceAccess request, as shown in one of the examples for this action. </p> <p>To request access to a specific instance, specify the IDs of both the instance and the fleet it belongs to. You can retrieve a fleet&#39;s instance IDs by calling <a>DescribeInstances</a>. If successful, an <a>InstanceAccess</a> object is returned that contains the instance&#39;s IP address and a set of credentials.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-remote-access.html">Remotely Access Fleet Instances</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-creating-debug.html">Debug Fleet Issues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>DescribeInstances</a> </p> </li> <li> <p> <a>GetInstanceAccess</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::get_instance_access`.
+    pub fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
+        self.runtime
+            .block_on(self.client.get_instance_access(input))
+    }
+
+    /// <p><p>Retrieves all aliases for this AWS account. You can filter the result set by alias name and/or routing strategy type. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Returned aliases are not listed in any particular order.</p> </note> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_aliases`.
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// <p><p>Retrieves build resources for all builds associated with the AWS account in use. You can limit results to builds that are in a specific status by using the <code>Status</code> parameter. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <note> <p>Build resources are not listed in any particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html"> Upload a Custom Server Build</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_builds`.
+    pub fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+        self.runtime.block_on(self.client.list_builds(input))
+    }
+
+    /// <p><p>Retrieves a collection of fleet resources for this AWS account. You can filter the result set to find only those fleets that are deployed with a specific build or script. Use the pagination parameters to retrieve results in sequential pages.</p> <note> <p>Fleet resources are not listed in a particular order.</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_fleets`.
+    pub fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
+        self.runtime.block_on(self.client.list_fleets(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Retrieves information on all game servers groups that exist in the current AWS account for the selected region. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_game_server_groups`.
+    pub fn list_game_server_groups(
+        &self,
+        input: ListGameServerGroupsInput,
+    ) -> Result<ListGameServerGroupsOutput, RusotoError<ListGameServerGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_game_server_groups(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Retrieves information on all game servers that are currently running in a specified game server group. If there are custom key sort values for your game servers, you can opt to have the returned list sorted based on these values. Use the pagination parameters to retrieve results in a set of sequential pages. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>RegisterGameServer</a> </p> </li> <li> <p> <a>ListGameServers</a> </p> </li> <li> <p> <a>ClaimGameServer</a> </p> </li> <li> <p> <a>DescribeGameServer</a> </p> </li> <li> <p> <a>UpdateGameServer</a> </p> </li> <li> <p> <a>DeregisterGameServer</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_game_servers`.
+    pub fn list_game_servers(
+        &self,
+        input: ListGameServersInput,
+    ) -> Result<ListGameServersOutput, RusotoError<ListGameServersError>> {
+        self.runtime.block_on(self.client.list_game_servers(input))
+    }
+
+    /// <p><p>Retrieves script records for all Realtime scripts that are associated with the AWS account in use. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_scripts`.
+    pub fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
+        self.runtime.block_on(self.client.list_scripts(input))
+    }
+
+    /// <p><p> Retrieves all tags that are assigned to a GameLift resource. Resource tags are used to organize AWS resources for a range of purposes. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To list tags for a resource, specify the unique ARN value for the resource.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p><p>Creates or updates a scaling policy for a fleet. Scaling policies are used to automatically scale a fleet&#39;s hosting capacity to meet player demand. An active scaling policy instructs Amazon GameLift to track a fleet metric and automatically change the fleet&#39;s capacity when a certain threshold is reached. There are two types of scaling policies: target-based and rule-based. Use a target-based policy to quickly and efficiently manage fleet scaling; this option is the most commonly used. Use rule-based policies when you need to exert fine-grained control over auto-scaling. </p> <p>Fleets can have multiple scaling policies of each type in force at the same time; you can have one target-based policy, one or multiple rule-based scaling policies, or both. We recommend caution, however, because multiple auto-scaling policies can have unintended consequences.</p> <p>You can temporarily suspend all scaling policies for a fleet by calling <a>StopFleetActions</a> with the fleet action AUTO_SCALING. To resume scaling policies, call <a>StartFleetActions</a> with the same fleet action. To stop just one scaling policy--or to permanently remove it, you must delete the policy with <a>DeleteScalingPolicy</a>.</p> <p>Learn more about how to work with auto-scaling in <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-autoscaling.html">Set Up Fleet Automatic Scaling</a>.</p> <p> <b>Target-based policy</b> </p> <p>A target-based policy tracks a single metric: PercentAvailableGameSessions. This metric tells us how much of a fleet&#39;s hosting capacity is ready to host game sessions but is not currently in use. This is the fleet&#39;s buffer; it measures the additional player demand that the fleet could handle at current capacity. With a target-based policy, you set your ideal buffer size and leave it to Amazon GameLift to take whatever action is needed to maintain that target. </p> <p>For example, you might choose to maintain a 10% buffer for a fleet that has the capacity to host 100 simultaneous game sessions. This policy tells Amazon GameLift to take action whenever the fleet&#39;s available capacity falls below or rises above 10 game sessions. Amazon GameLift will start new instances or stop unused instances in order to return to the 10% buffer. </p> <p>To create or update a target-based policy, specify a fleet ID and name, and set the policy type to &quot;TargetBased&quot;. Specify the metric to track (PercentAvailableGameSessions) and reference a <a>TargetConfiguration</a> object with your desired buffer value. Exclude all other parameters. On a successful request, the policy name is returned. The scaling policy is automatically in force as soon as it&#39;s successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <p> <b>Rule-based policy</b> </p> <p>A rule-based policy tracks specified fleet metric, sets a threshold value, and specifies the type of action to initiate when triggered. With a rule-based policy, you can select from several available fleet metrics. Each policy specifies whether to scale up or scale down (and by how much), so you need one policy for each type of action. </p> <p>For example, a policy may make the following statement: &quot;If the percentage of idle instances is greater than 20% for more than 15 minutes, then reduce the fleet capacity by 10%.&quot;</p> <p>A policy&#39;s rule statement has the following structure:</p> <p>If <code>[MetricName]</code> is <code>[ComparisonOperator]</code> <code>[Threshold]</code> for <code>[EvaluationPeriods]</code> minutes, then <code>[ScalingAdjustmentType]</code> to/by <code>[ScalingAdjustment]</code>.</p> <p>To implement the example, the rule statement would look like this:</p> <p>If <code>[PercentIdleInstances]</code> is <code>[GreaterThanThreshold]</code> <code>[20]</code> for <code>[15]</code> minutes, then <code>[PercentChangeInCapacity]</code> to/by <code>[10]</code>.</p> <p>To create or update a scaling policy, specify a unique combination of name and fleet ID, and set the policy type to &quot;RuleBased&quot;. Specify the parameter values for a policy rule statement. On a successful request, the policy name is returned. Scaling policies are automatically in force as soon as they&#39;re successfully created. If the fleet&#39;s auto-scaling actions are temporarily suspended, the new policy will be in force once the fleet actions are restarted.</p> <ul> <li> <p> <a>DescribeFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>DescribeEC2InstanceLimits</a> </p> </li> <li> <p>Manage scaling policies:</p> <ul> <li> <p> <a>PutScalingPolicy</a> (auto-scaling)</p> </li> <li> <p> <a>DescribeScalingPolicies</a> (auto-scaling)</p> </li> <li> <p> <a>DeleteScalingPolicy</a> (auto-scaling)</p> </li> </ul> </li> <li> <p>Manage fleet actions:</p> <ul> <li> <p> <a>StartFleetActions</a> </p> </li> <li> <p> <a>StopFleetActions</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::put_scaling_policy`.
+    pub fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
+        self.runtime.block_on(self.client.put_scaling_policy(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Creates a new game server resource and notifies GameLift FleetIQ that the game server is ready to host gameplay and players. This action is called by a game server process that is running on an instance in a game server group. Registering game servers enables GameLift FleetIQ to track available game servers and enables game clients and services to claim a game server for a new game session. </p> <p>To register a game server, identify the game server group and instance where the game server is running, and provide a unique identifier for the game server. You can also include connection and game server data; when a game client or service requests a game server by calling <a>ClaimGameServer</a>, this information is returned in response.</p> <p>Once a game server is successfully registered, it is put in status AVAILABLE. A request to register a game server may fail if the instance it is in the process of shutting down as part of instance rebalancing or scale-down activity. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>RegisterGameServer</a> </p> </li> <li> <p> <a>ListGameServers</a> </p> </li> <li> <p> <a>ClaimGameServer</a> </p> </li> <li> <p> <a>DescribeGameServer</a> </p> </li> <li> <p> <a>UpdateGameServer</a> </p> </li> <li> <p> <a>DeregisterGameServer</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::register_game_server`.
+    pub fn register_game_server(
+        &self,
+        input: RegisterGameServerInput,
+    ) -> Result<RegisterGameServerOutput, RusotoError<RegisterGameServerError>> {
+        self.runtime
+            .block_on(self.client.register_game_server(input))
+    }
+
+    /// <p><p>Retrieves a fresh set of credentials for use when uploading a new set of game build files to Amazon GameLift&#39;s Amazon S3. This is done as part of the build creation process; see <a>CreateBuild</a>.</p> <p>To request new credentials, specify the build ID as returned with an initial <code>CreateBuild</code> request. If successful, a new set of credentials are returned, along with the S3 storage location associated with the build ID.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-cli-uploading.html#gamelift-build-cli-uploading-create-build"> Create a Build with Files in S3</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::request_upload_credentials`.
+    pub fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
+        self.runtime
+            .block_on(self.client.request_upload_credentials(input))
+    }
+
+    /// <p><p>Retrieves the fleet ID that an alias is currently pointing to.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::resolve_alias`.
+    pub fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
+        self.runtime.block_on(self.client.resolve_alias(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Reinstates activity on a game server group after it has been suspended. A game server group may be suspended by calling <a>SuspendGameServerGroup</a>, or it may have been involuntarily suspended due to a configuration problem. You can manually resume activity on the group once the configuration problem has been resolved. Refer to the game server group status and status reason for more information on why group activity is suspended.</p> <p>To resume activity, specify a game server group ARN and the type of activity to be resumed.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::resume_game_server_group`.
+    pub fn resume_game_server_group(
+        &self,
+        input: ResumeGameServerGroupInput,
+    ) -> Result<ResumeGameServerGroupOutput, RusotoError<ResumeGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.resume_game_server_group(input))
+    }
+
+    /// <p><p>Retrieves all active game sessions that match a set of search criteria and sorts them in a specified order. You can search or sort by the following game session attributes:</p> <ul> <li> <p> <b>gameSessionId</b> -- A unique identifier for the game session. You can use either a <code>GameSessionId</code> or <code>GameSessionArn</code> value. </p> </li> <li> <p> <b>gameSessionName</b> -- Name assigned to a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>. Game session names do not need to be unique to a game session.</p> </li> <li> <p> <b>gameSessionProperties</b> -- Custom data defined in a game session&#39;s <code>GameProperty</code> parameter. <code>GameProperty</code> values are stored as key:value pairs; the filter expression must indicate the key and a string to search the data values for. For example, to search for game sessions with custom data containing the key:value pair &quot;gameMode:brawl&quot;, specify the following: <code>gameSessionProperties.gameMode = &quot;brawl&quot;</code>. All custom data values are searched as strings.</p> </li> <li> <p> <b>maximumSessions</b> -- Maximum number of player sessions allowed for a game session. This value is set when requesting a new game session with <a>CreateGameSession</a> or updating with <a>UpdateGameSession</a>.</p> </li> <li> <p> <b>creationTimeMillis</b> -- Value indicating when a game session was created. It is expressed in Unix time as milliseconds.</p> </li> <li> <p> <b>playerSessionCount</b> -- Number of players currently connected to a game session. This value changes rapidly as players join the session or drop out.</p> </li> <li> <p> <b>hasAvailablePlayerSessions</b> -- Boolean value indicating whether a game session has reached its maximum number of players. It is highly recommended that all search requests include this filter attribute to optimize search performance and return only sessions that players can join. </p> </li> </ul> <note> <p>Returned values for <code>playerSessionCount</code> and <code>hasAvailablePlayerSessions</code> change quickly as players join sessions and others drop out. Results should be considered a snapshot in time. Be sure to refresh search results often, and handle sessions that fill up before a player can join. </p> </note> <p>To search or sort, specify either a fleet ID or an alias ID, and provide a search filter expression, a sort expression, or both. If successful, a collection of <a>GameSession</a> objects matching the request is returned. Use the pagination parameters to retrieve results as a set of sequential pages. </p> <p>You can search for game sessions one fleet at a time only. To find game sessions across multiple fleets, you must search each fleet separately and combine the results. This search feature finds only game sessions that are in <code>ACTIVE</code> status. To locate games in statuses other than active, use <a>DescribeGameSessionDetails</a>.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::search_game_sessions`.
+    pub fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
+        self.runtime
+            .block_on(self.client.search_game_sessions(input))
+    }
+
+    /// <p><p>Resumes activity on a fleet that was suspended with <a>StopFleetActions</a>. Currently, this operation is used to restart a fleet&#39;s auto-scaling activity. </p> <p>To start fleet actions, specify the fleet ID and the type of actions to restart. When auto-scaling fleet actions are restarted, Amazon GameLift once again initiates scaling events as triggered by the fleet&#39;s scaling policies. If actions on the fleet were never stopped, this operation will have no effect. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::start_fleet_actions`.
+    pub fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
+        self.runtime
+            .block_on(self.client.start_fleet_actions(input))
+    }
+
+    /// <p><p>Places a request for a new game session in a queue (see <a>CreateGameSessionQueue</a>). When processing a placement request, Amazon GameLift searches for available resources on the queue&#39;s destinations, scanning each until it finds resources or the placement request times out.</p> <p>A game session placement request can also request player sessions. When a new game session is successfully created, Amazon GameLift creates a player session for each player included in the request.</p> <p>When placing a game session, by default Amazon GameLift tries each fleet in the order they are listed in the queue configuration. Ideally, a queue&#39;s destinations are listed in preference order.</p> <p>Alternatively, when requesting a game session with players, you can also provide latency data for each player in relevant Regions. Latency data indicates the performance lag a player experiences when connected to a fleet in the Region. Amazon GameLift uses latency data to reorder the list of destinations to place the game session in a Region with minimal lag. If latency data is provided for multiple players, Amazon GameLift calculates each Region&#39;s average lag for all players and reorders to get the best game play across all players. </p> <p>To place a new game session request, specify the following:</p> <ul> <li> <p>The queue name and a set of game session properties and settings</p> </li> <li> <p>A unique ID (such as a UUID) for the placement. You use this ID to track the status of the placement request</p> </li> <li> <p>(Optional) A set of player data and a unique player ID for each player that you are joining to the new game session (player data is optional, but if you include it, you must also provide a unique ID for each player)</p> </li> <li> <p>Latency data for all players (if you want to optimize game play for the players)</p> </li> </ul> <p>If successful, a new game session placement is created.</p> <p>To track the status of a placement request, call <a>DescribeGameSessionPlacement</a> and check the request&#39;s status. If the status is <code>FULFILLED</code>, a new game session has been created and a game session ARN and Region are referenced. If the placement request times out, you can resubmit the request or retry it with a different queue. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::start_game_session_placement`.
+    pub fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
+        self.runtime
+            .block_on(self.client.start_game_session_placement(input))
+    }
+
+    /// <p><p>Finds new players to fill open slots in an existing game session. This operation can be used to add players to matched games that start with fewer than the maximum number of players or to replace players when they drop out. By backfilling with the same matchmaker used to create the original match, you ensure that new players meet the match criteria and maintain a consistent experience throughout the game session. You can backfill a match anytime after a game session has been created. </p> <p>To request a match backfill, specify a unique ticket ID, the existing game session&#39;s ARN, a matchmaking configuration, and a set of data that describes all current players in the game session. If successful, a match backfill ticket is created and returned with status set to QUEUED. The ticket is placed in the matchmaker&#39;s ticket pool and processed. Track the status of the ticket to respond as needed. </p> <p>The process of finding backfill matches is essentially identical to the initial matchmaking process. The matchmaker searches the pool and groups tickets together to form potential matches, allowing only one backfill ticket per potential match. Once the a match is formed, the matchmaker creates player sessions for the new players. All tickets in the match are updated with the game session&#39;s connection information, and the <a>GameSession</a> object is updated to include matchmaker data on the new players. For more detail on how match backfill requests are processed, see <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How Amazon GameLift FlexMatch Works</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-backfill.html"> Backfill Existing Games with FlexMatch</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::start_match_backfill`.
+    pub fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
+        self.runtime
+            .block_on(self.client.start_match_backfill(input))
+    }
+
+    /// <p><p>Uses FlexMatch to create a game match for a group of players based on custom matchmaking rules, and starts a new game for the matched players. Each matchmaking request specifies the type of match to build (team configuration, rules for an acceptable match, etc.). The request also specifies the players to find a match for and where to host the new game session for optimal performance. A matchmaking request might start with a single player or a group of players who want to play together. FlexMatch finds additional players as needed to fill the match. Match type, rules, and the queue used to place a new game session are defined in a <code>MatchmakingConfiguration</code>. </p> <p>To start matchmaking, provide a unique ticket ID, specify a matchmaking configuration, and include the players to be matched. You must also include a set of player attributes relevant for the matchmaking configuration. If successful, a matchmaking ticket is returned with status set to <code>QUEUED</code>. Track the status of the ticket to respond as needed and acquire game session connection information for successfully completed matches.</p> <p> <b>Tracking ticket status</b> -- A couple of options are available for tracking the status of matchmaking requests: </p> <ul> <li> <p>Polling -- Call <code>DescribeMatchmaking</code>. This operation returns the full ticket object, including current status and (for completed tickets) game session connection info. We recommend polling no more than once every 10 seconds.</p> </li> <li> <p>Notifications -- Get event notifications for changes in ticket status using Amazon Simple Notification Service (SNS). Notifications are easy to set up (see <a>CreateMatchmakingConfiguration</a>) and typically deliver match status changes faster and more efficiently than polling. We recommend that you use polling to back up to notifications (since delivery is not guaranteed) and call <code>DescribeMatchmaking</code> only when notifications are not received within 30 seconds.</p> </li> </ul> <p> <b>Processing a matchmaking request</b> -- FlexMatch handles a matchmaking request as follows: </p> <ol> <li> <p>Your client code submits a <code>StartMatchmaking</code> request for one or more players and tracks the status of the request ticket. </p> </li> <li> <p>FlexMatch uses this ticket and others in process to build an acceptable match. When a potential match is identified, all tickets in the proposed match are advanced to the next status. </p> </li> <li> <p>If the match requires player acceptance (set in the matchmaking configuration), the tickets move into status <code>REQUIRES_ACCEPTANCE</code>. This status triggers your client code to solicit acceptance from all players in every ticket involved in the match, and then call <a>AcceptMatch</a> for each player. If any player rejects or fails to accept the match before a specified timeout, the proposed match is dropped (see <code>AcceptMatch</code> for more details).</p> </li> <li> <p>Once a match is proposed and accepted, the matchmaking tickets move into status <code>PLACING</code>. FlexMatch locates resources for a new game session using the game session queue (set in the matchmaking configuration) and creates the game session based on the match data. </p> </li> <li> <p>When the match is successfully placed, the matchmaking tickets move into <code>COMPLETED</code> status. Connection information (including game session endpoint and player session) is added to the matchmaking tickets. Matched players can use the connection information to join the game. </p> </li> </ol> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-notification.html"> Set Up FlexMatch Event Notification</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-tasks.html"> FlexMatch Integration Roadmap</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-match.html"> How GameLift FlexMatch Works</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::start_matchmaking`.
+    pub fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
+        self.runtime.block_on(self.client.start_matchmaking(input))
+    }
+
+    /// <p><p>Suspends activity on a fleet. Currently, this operation is used to stop a fleet&#39;s auto-scaling activity. It is used to temporarily stop triggering scaling events. The policies can be retained and auto-scaling activity can be restarted using <a>StartFleetActions</a>. You can view a fleet&#39;s stopped actions using <a>DescribeFleetAttributes</a>.</p> <p>To stop fleet actions, specify the fleet ID and the type of actions to suspend. When auto-scaling fleet actions are stopped, Amazon GameLift no longer initiates scaling events except in response to manual changes using <a>UpdateFleetCapacity</a>. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::stop_fleet_actions`.
+    pub fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
+        self.runtime.block_on(self.client.stop_fleet_actions(input))
+    }
+
+    /// <p><p>Cancels a game session placement that is in <code>PENDING</code> status. To stop a placement, provide the placement ID values. If successful, the placement is moved to <code>CANCELLED</code> status.</p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::stop_game_session_placement`.
+    pub fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
+        self.runtime
+            .block_on(self.client.stop_game_session_placement(input))
+    }
+
+    /// <p><p>Cancels a matchmaking ticket or match backfill ticket that is currently being processed. To stop the matchmaking operation, specify the ticket ID. If successful, work on the ticket is stopped, and the ticket status is changed to <code>CANCELLED</code>.</p> <p>This call is also used to turn off automatic backfill for an individual game session. This is for game sessions that are created with a matchmaking configuration that has automatic backfill enabled. The ticket ID is included in the <code>MatchmakerData</code> of an updated game session object, which is provided to the game server.</p> <note> <p>If the action is successful, the service sends back an empty JSON struct with the HTTP 200 response (not an empty HTTP body).</p> </note> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-client.html"> Add FlexMatch to a Game Client</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>StartMatchmaking</a> </p> </li> <li> <p> <a>DescribeMatchmaking</a> </p> </li> <li> <p> <a>StopMatchmaking</a> </p> </li> <li> <p> <a>AcceptMatch</a> </p> </li> <li> <p> <a>StartMatchBackfill</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::stop_matchmaking`.
+    pub fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
+        self.runtime.block_on(self.client.stop_matchmaking(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Temporarily stops activity on a game server group without terminating instances or the game server group. Activity can be restarted by calling <a>ResumeGameServerGroup</a>. Activities that can suspended are:</p> <ul> <li> <p>Instance type replacement. This activity evaluates the current Spot viability of all instance types that are defined for the game server group. It updates the Auto Scaling group to remove nonviable Spot instance types (which have a higher chance of game server interruptions) and rebalances capacity across the remaining viable Spot instance types. When this activity is suspended, the Auto Scaling group continues with its current balance, regardless of viability. Instance protection, utilization metrics, and capacity autoscaling activities continue to be active. </p> </li> </ul> <p>To suspend activity, specify a game server group ARN and the type of activity to be suspended.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::suspend_game_server_group`.
+    pub fn suspend_game_server_group(
+        &self,
+        input: SuspendGameServerGroupInput,
+    ) -> Result<SuspendGameServerGroupOutput, RusotoError<SuspendGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.suspend_game_server_group(input))
+    }
+
+    /// <p><p> Assigns a tag to a GameLift resource. AWS resource tags provide an additional management tool set. You can use tags to organize resources, create IAM permissions policies to manage access to groups of resources, customize AWS cost breakdowns, etc. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To add a tag to a resource, specify the unique ARN value for the resource and provide a tag list containing one or more tags. The operation succeeds even if the list includes tags that are already assigned to the specified resource. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p><p>Removes a tag that is assigned to a GameLift resource. Resource tags are used to organize AWS resources for a range of purposes. This action handles the permissions necessary to manage tags for the following GameLift resource types:</p> <ul> <li> <p>Build</p> </li> <li> <p>Script</p> </li> <li> <p>Fleet</p> </li> <li> <p>Alias</p> </li> <li> <p>GameSessionQueue</p> </li> <li> <p>MatchmakingConfiguration</p> </li> <li> <p>MatchmakingRuleSet</p> </li> </ul> <p>To remove a tag from a resource, specify the unique ARN value for the resource and provide a string list containing one or more tags to be removed. This action succeeds even if the list includes tags that are not currently assigned to the specified resource.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/general/latest/gr/aws_tagging.html">Tagging AWS Resources</a> in the <i>AWS General Reference</i> </p> <p> <a href="http://aws.amazon.com/answers/account-management/aws-tagging-strategies/"> AWS Tagging Strategies</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>TagResource</a> </p> </li> <li> <p> <a>UntagResource</a> </p> </li> <li> <p> <a>ListTagsForResource</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Updates properties for an alias. To update properties, specify the alias ID to be updated and provide the information to be changed. To reassign an alias to another fleet, provide an updated routing strategy. If successful, the updated alias record is returned.</p> <ul> <li> <p> <a>CreateAlias</a> </p> </li> <li> <p> <a>ListAliases</a> </p> </li> <li> <p> <a>DescribeAlias</a> </p> </li> <li> <p> <a>UpdateAlias</a> </p> </li> <li> <p> <a>DeleteAlias</a> </p> </li> <li> <p> <a>ResolveAlias</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_alias`.
+    pub fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
+        self.runtime.block_on(self.client.update_alias(input))
+    }
+
+    /// <p><p>Updates metadata in a build resource, including the build name and version. To update the metadata, specify the build ID to update and provide the new values. If successful, a build object containing the updated metadata is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gamelift-build-intro.html"> Upload a Custom Server Build</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateBuild</a> </p> </li> <li> <p> <a>ListBuilds</a> </p> </li> <li> <p> <a>DescribeBuild</a> </p> </li> <li> <p> <a>UpdateBuild</a> </p> </li> <li> <p> <a>DeleteBuild</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_build`.
+    pub fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
+        self.runtime.block_on(self.client.update_build(input))
+    }
+
+    /// <p><p>Updates fleet properties, including name and description, for a fleet. To update metadata, specify the fleet ID and the property values that you want to change. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_fleet_attributes`.
+    pub fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_attributes(input))
+    }
+
+    /// <p><p>Updates capacity settings for a fleet. Use this action to specify the number of EC2 instances (hosts) that you want this fleet to contain. Before calling this action, you may want to call <a>DescribeEC2InstanceLimits</a> to get the maximum capacity based on the fleet&#39;s EC2 instance type.</p> <p>Specify minimum and maximum number of instances. Amazon GameLift will not change fleet capacity to values fall outside of this range. This is particularly important when using auto-scaling (see <a>PutScalingPolicy</a>) to allow capacity to adjust based on player demand while imposing limits on automatic adjustments.</p> <p>To update fleet capacity, specify the fleet ID and the number of instances you want the fleet to host. If successful, Amazon GameLift starts or terminates instances so that the fleet&#39;s active instance count matches the desired instance count. You can view a fleet&#39;s current capacity information by calling <a>DescribeFleetCapacity</a>. If the desired instance count is higher than the instance type&#39;s limit, the &quot;Limit Exceeded&quot; exception occurs.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_fleet_capacity`.
+    pub fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_capacity(input))
+    }
+
+    /// <p><p>Updates port settings for a fleet. To update settings, specify the fleet ID to be updated and list the permissions you want to update. List the permissions you want to add in <code>InboundPermissionAuthorizations</code>, and permissions you want to remove in <code>InboundPermissionRevocations</code>. Permissions to be removed must match existing fleet permissions. If successful, the fleet ID for the updated fleet is returned.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_fleet_port_settings`.
+    pub fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_port_settings(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Updates information about a registered game server. This action is called by a game server process that is running on an instance in a game server group. There are three reasons to update game server information: (1) to change the utilization status of the game server, (2) to report game server health status, and (3) to change game server metadata. A registered game server should regularly report health and should update utilization status when it is supporting gameplay so that GameLift FleetIQ can accurately track game server availability. You can make all three types of updates in the same request.</p> <ul> <li> <p>To update the game server&#39;s utilization status, identify the game server and game server group and specify the current utilization status. Use this status to identify when game servers are currently hosting games and when they are available to be claimed. </p> </li> <li> <p>To report health status, identify the game server and game server group and set health check to HEALTHY. If a game server does not report health status for a certain length of time, the game server is no longer considered healthy and will be eventually de-registered from the game server group to avoid affecting utilization metrics. The best practice is to report health every 60 seconds.</p> </li> <li> <p>To change game server metadata, provide updated game server data and custom sort key values.</p> </li> </ul> <p>Once a game server is successfully updated, the relevant statuses and timestamps are updated.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>RegisterGameServer</a> </p> </li> <li> <p> <a>ListGameServers</a> </p> </li> <li> <p> <a>ClaimGameServer</a> </p> </li> <li> <p> <a>DescribeGameServer</a> </p> </li> <li> <p> <a>UpdateGameServer</a> </p> </li> <li> <p> <a>DeregisterGameServer</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_game_server`.
+    pub fn update_game_server(
+        &self,
+        input: UpdateGameServerInput,
+    ) -> Result<UpdateGameServerOutput, RusotoError<UpdateGameServerError>> {
+        self.runtime.block_on(self.client.update_game_server(input))
+    }
+
+    /// <p><p> <b>This action is part of Amazon GameLift FleetIQ with game server groups, which is in preview release and is subject to change.</b> </p> <p>Updates GameLift FleetIQ-specific properties for a game server group. These properties include instance rebalancing and game server protection. Many Auto Scaling group properties are updated directly. These include autoscaling policies, minimum/maximum/desired instance counts, and launch template. </p> <p>To update the game server group, specify the game server group ID and provide the updated values.</p> <p>Updated properties are validated to ensure that GameLift FleetIQ can continue to perform its core instance rebalancing activity. When you change Auto Scaling group properties directly and the changes cause errors with GameLift FleetIQ activities, an alert is sent.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-intro.html">GameLift FleetIQ Guide</a> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/gsg-asgroups.html">Updating a GameLift FleetIQ-Linked Auto Scaling Group</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameServerGroup</a> </p> </li> <li> <p> <a>ListGameServerGroups</a> </p> </li> <li> <p> <a>DescribeGameServerGroup</a> </p> </li> <li> <p> <a>UpdateGameServerGroup</a> </p> </li> <li> <p> <a>DeleteGameServerGroup</a> </p> </li> <li> <p> <a>ResumeGameServerGroup</a> </p> </li> <li> <p> <a>SuspendGameServerGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_game_server_group`.
+    pub fn update_game_server_group(
+        &self,
+        input: UpdateGameServerGroupInput,
+    ) -> Result<UpdateGameServerGroupOutput, RusotoError<UpdateGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.update_game_server_group(input))
+    }
+
+    /// <p><p>Updates game session properties. This includes the session name, maximum player count, protection policy, which controls whether or not an active game session can be terminated during a scale-down event, and the player session creation policy, which controls whether or not new players can join the session. To update a game session, specify the game session ID and the values you want to change. If successful, an updated <a>GameSession</a> object is returned. </p> <ul> <li> <p> <a>CreateGameSession</a> </p> </li> <li> <p> <a>DescribeGameSessions</a> </p> </li> <li> <p> <a>DescribeGameSessionDetails</a> </p> </li> <li> <p> <a>SearchGameSessions</a> </p> </li> <li> <p> <a>UpdateGameSession</a> </p> </li> <li> <p> <a>GetGameSessionLogUrl</a> </p> </li> <li> <p>Game session placements</p> <ul> <li> <p> <a>StartGameSessionPlacement</a> </p> </li> <li> <p> <a>DescribeGameSessionPlacement</a> </p> </li> <li> <p> <a>StopGameSessionPlacement</a> </p> </li> </ul> </li> </ul></p>
+    ///
+    /// See `GameLift::update_game_session`.
+    pub fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
+        self.runtime
+            .block_on(self.client.update_game_session(input))
+    }
+
+    /// <p><p>Updates settings for a game session queue, which determines how new game session requests in the queue are processed. To update settings, specify the queue name to be updated and provide the new settings. When updating destinations, provide a complete list of destinations. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/queues-intro.html"> Using Multi-Region Queues</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateGameSessionQueue</a> </p> </li> <li> <p> <a>DescribeGameSessionQueues</a> </p> </li> <li> <p> <a>UpdateGameSessionQueue</a> </p> </li> <li> <p> <a>DeleteGameSessionQueue</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_game_session_queue`.
+    pub fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
+        self.runtime
+            .block_on(self.client.update_game_session_queue(input))
+    }
+
+    /// <p><p>Updates settings for a FlexMatch matchmaking configuration. These changes affect all matches and game sessions that are created after the update. To update settings, specify the configuration name to be updated and provide the new settings. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-configuration.html"> Design a FlexMatch Matchmaker</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_matchmaking_configuration`.
+    pub fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> Result<
+        UpdateMatchmakingConfigurationOutput,
+        RusotoError<UpdateMatchmakingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_matchmaking_configuration(input))
+    }
+
+    /// <p><p>Updates the current runtime configuration for the specified fleet, which tells Amazon GameLift how to launch server processes on instances in the fleet. You can update a fleet&#39;s runtime configuration at any time after the fleet is created; it does not need to be in an <code>ACTIVE</code> status.</p> <p>To update runtime configuration, specify the fleet ID and provide a <code>RuntimeConfiguration</code> object with an updated set of server process configurations.</p> <p>Each instance in a Amazon GameLift fleet checks regularly for an updated runtime configuration and changes how it launches server processes to comply with the latest version. Existing server processes are not affected by the update; runtime configuration changes are applied gradually as existing processes shut down and new processes are launched during Amazon GameLift&#39;s normal process recycling activity.</p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/fleets-intro.html">Setting up GameLift Fleets</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateFleet</a> </p> </li> <li> <p> <a>ListFleets</a> </p> </li> <li> <p> <a>DeleteFleet</a> </p> </li> <li> <p> <a>DescribeFleetAttributes</a> </p> </li> <li> <p>Update fleets:</p> <ul> <li> <p> <a>UpdateFleetAttributes</a> </p> </li> <li> <p> <a>UpdateFleetCapacity</a> </p> </li> <li> <p> <a>UpdateFleetPortSettings</a> </p> </li> <li> <p> <a>UpdateRuntimeConfiguration</a> </p> </li> </ul> </li> <li> <p> <a>StartFleetActions</a> or <a>StopFleetActions</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_runtime_configuration`.
+    pub fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_runtime_configuration(input))
+    }
+
+    /// <p><p>Updates Realtime script metadata and content.</p> <p>To update script metadata, specify the script ID and provide updated name and/or version values. </p> <p>To update script content, provide an updated zip file by pointing to either a local file or an Amazon S3 bucket location. You can use either method regardless of how the original script was uploaded. Use the <i>Version</i> parameter to track updates to the script.</p> <p>If the call is successful, the updated metadata is stored in the script record and a revised script is uploaded to the Amazon GameLift service. Once the script is updated and acquired by a fleet instance, the new version is used for all new game sessions. </p> <p> <b>Learn more</b> </p> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/realtime-intro.html">Amazon GameLift Realtime Servers</a> </p> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateScript</a> </p> </li> <li> <p> <a>ListScripts</a> </p> </li> <li> <p> <a>DescribeScript</a> </p> </li> <li> <p> <a>UpdateScript</a> </p> </li> <li> <p> <a>DeleteScript</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::update_script`.
+    pub fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
+        self.runtime.block_on(self.client.update_script(input))
+    }
+
+    /// <p><p>Validates the syntax of a matchmaking rule or rule set. This operation checks that the rule set is using syntactically correct JSON and that it conforms to allowed property expressions. To validate syntax, provide a rule set JSON string.</p> <p> <b>Learn more</b> </p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/gamelift/latest/developerguide/match-rulesets.html">Build a Rule Set</a> </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p> <a>CreateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DescribeMatchmakingConfigurations</a> </p> </li> <li> <p> <a>UpdateMatchmakingConfiguration</a> </p> </li> <li> <p> <a>DeleteMatchmakingConfiguration</a> </p> </li> <li> <p> <a>CreateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DescribeMatchmakingRuleSets</a> </p> </li> <li> <p> <a>ValidateMatchmakingRuleSet</a> </p> </li> <li> <p> <a>DeleteMatchmakingRuleSet</a> </p> </li> </ul></p>
+    ///
+    /// See `GameLift::validate_matchmaking_rule_set`.
+    pub fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
+    {
+        self.runtime
+            .block_on(self.client.validate_matchmaking_rule_set(input))
+    }
+}
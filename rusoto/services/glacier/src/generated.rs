@@ -1342,24 +1342,28 @@ impl AbortMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortMultipartUploadError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortMultipartUploadError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortMultipartUploadError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortMultipartUploadError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1398,20 +1402,28 @@ impl AbortVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AbortVaultLockError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortVaultLockError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AbortVaultLockError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortVaultLockError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AbortVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AbortVaultLockError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AbortVaultLockError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AbortVaultLockError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1452,23 +1464,31 @@ impl AddTagsToVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AddTagsToVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsToVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AddTagsToVaultError::LimitExceeded(err.msg))
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AddTagsToVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToVaultError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddTagsToVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1508,24 +1528,28 @@ impl CompleteMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CompleteMultipartUploadError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CompleteMultipartUploadError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteMultipartUploadError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteMultipartUploadError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CompleteMultipartUploadError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteMultipartUploadError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1568,22 +1592,28 @@ impl CompleteVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CompleteVaultLockError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteVaultLockError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CompleteVaultLockError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteVaultLockError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompleteVaultLockError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CompleteVaultLockError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompleteVaultLockError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1622,16 +1652,25 @@ impl CreateVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateVaultError::LimitExceeded(err.msg))
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1670,16 +1709,28 @@ impl DeleteArchiveError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteArchiveError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteArchiveError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteArchiveError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteArchiveError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1718,16 +1769,25 @@ impl DeleteVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVaultError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1766,24 +1826,28 @@ impl DeleteVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVaultAccessPolicyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVaultAccessPolicyError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultAccessPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultAccessPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1826,24 +1890,28 @@ impl DeleteVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVaultNotificationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteVaultNotificationsError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultNotificationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultNotificationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultNotificationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVaultNotificationsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1886,16 +1954,25 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1934,16 +2011,28 @@ impl DescribeVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVaultError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1980,19 +2069,22 @@ impl GetDataRetrievalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDataRetrievalPolicyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDataRetrievalPolicyError::MissingParameterValue(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDataRetrievalPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDataRetrievalPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2030,16 +2122,25 @@ impl GetJobOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetJobOutputError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobOutputError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetJobOutputError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobOutputError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobOutputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobOutputError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetJobOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobOutputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2078,24 +2179,28 @@ impl GetVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultAccessPolicyError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultAccessPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2134,16 +2239,25 @@ impl GetVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultLockError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultLockError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultLockError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultLockError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetVaultLockError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultLockError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultLockError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2182,24 +2296,28 @@ impl GetVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultNotificationsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultNotificationsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultNotificationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetVaultNotificationsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2242,22 +2360,34 @@ impl InitiateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InsufficientCapacityException" => {
-                    return RusotoError::Service(InitiateJobError::InsufficientCapacity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateJobError::InsufficientCapacity(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InitiateJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateJobError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(InitiateJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateJobError::MissingParameterValue(err.msg),
+                    )
                 }
                 "PolicyEnforcedException" => {
-                    return RusotoError::Service(InitiateJobError::PolicyEnforced(err.msg))
+                    return RusotoError::service(&res, InitiateJobError::PolicyEnforced(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, InitiateJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2298,24 +2428,28 @@ impl InitiateMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateMultipartUploadError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateMultipartUploadError::MissingParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateMultipartUploadError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateMultipartUploadError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateMultipartUploadError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateMultipartUploadError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2358,22 +2492,28 @@ impl InitiateVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InitiateVaultLockError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateVaultLockError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(InitiateVaultLockError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateVaultLockError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateVaultLockError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateVaultLockError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateVaultLockError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2412,16 +2552,22 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListJobsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListJobsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListJobsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListJobsError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2460,24 +2606,28 @@ impl ListMultipartUploadsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultipartUploadsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultipartUploadsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultipartUploadsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultipartUploadsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2516,16 +2666,22 @@ impl ListPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListPartsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPartsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListPartsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPartsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPartsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListPartsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPartsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListPartsError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2562,19 +2718,22 @@ impl ListProvisionedCapacityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedCapacityError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedCapacityError::MissingParameterValue(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProvisionedCapacityError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisionedCapacityError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2616,20 +2775,28 @@ impl ListTagsForVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListTagsForVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForVaultError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2668,16 +2835,22 @@ impl ListVaultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListVaultsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVaultsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListVaultsError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVaultsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListVaultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListVaultsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVaultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListVaultsError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2718,22 +2891,26 @@ impl PurchaseProvisionedCapacityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseProvisionedCapacityError::InvalidParameterValue(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PurchaseProvisionedCapacityError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PurchaseProvisionedCapacityError::LimitExceeded(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseProvisionedCapacityError::MissingParameterValue(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PurchaseProvisionedCapacityError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2780,24 +2957,28 @@ impl RemoveTagsFromVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromVaultError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromVaultError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromVaultError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromVaultError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2834,19 +3015,22 @@ impl SetDataRetrievalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetDataRetrievalPolicyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetDataRetrievalPolicyError::MissingParameterValue(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDataRetrievalPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDataRetrievalPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2884,24 +3068,28 @@ impl SetVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultAccessPolicyError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultAccessPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2940,24 +3128,28 @@ impl SetVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultNotificationsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultNotificationsError::MissingParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultNotificationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetVaultNotificationsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2998,19 +3190,31 @@ impl UploadArchiveError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UploadArchiveError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadArchiveError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UploadArchiveError::MissingParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadArchiveError::MissingParameterValue(err.msg),
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(UploadArchiveError::RequestTimeout(err.msg))
+                    return RusotoError::service(&res, UploadArchiveError::RequestTimeout(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UploadArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadArchiveError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UploadArchiveError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadArchiveError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3052,27 +3256,34 @@ impl UploadMultipartPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UploadMultipartPartError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UploadMultipartPartError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UploadMultipartPartError::MissingParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UploadMultipartPartError::MissingParameterValue(err.msg),
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(UploadMultipartPartError::RequestTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadMultipartPartError::RequestTimeout(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UploadMultipartPartError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UploadMultipartPartError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UploadMultipartPartError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UploadMultipartPartError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3313,6 +3524,7 @@ impl GlacierClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3328,6 +3540,7 @@ impl GlacierClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GlacierClient {
         GlacierClient { client, region }
     }
@@ -3349,6 +3562,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation_name("AbortMultipartUpload");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3381,6 +3597,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation_name("AbortVaultLock");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3413,6 +3632,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("AddTagsToVault");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3453,6 +3675,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("CompleteMultipartUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3493,6 +3718,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("CompleteVaultLock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3525,6 +3753,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation_name("CreateVault");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3560,6 +3791,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation_name("DeleteArchive");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3592,6 +3826,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation_name("DeleteVault");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3624,6 +3861,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation_name("DeleteVaultAccessPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3656,6 +3896,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation_name("DeleteVaultNotifications");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3689,6 +3932,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("DescribeJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3722,6 +3968,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("DescribeVault");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3754,6 +4003,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("GetDataRetrievalPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3788,6 +4040,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("GetJobOutput");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3830,6 +4085,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("GetVaultAccessPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3866,6 +4124,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("GetVaultLock");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3899,6 +4160,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("GetVaultNotifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3935,6 +4199,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("InitiateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3974,6 +4241,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("InitiateMultipartUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4015,6 +4285,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("InitiateVaultLock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4052,6 +4325,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4100,6 +4376,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("ListMultipartUploads");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4143,6 +4422,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("ListParts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4184,6 +4466,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("ListProvisionedCapacity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4217,6 +4502,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForVault");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4246,6 +4534,9 @@ impl Glacier for GlacierClient {
         let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation_name("ListVaults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4288,6 +4579,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("PurchaseProvisionedCapacity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4322,6 +4616,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("RemoveTagsFromVault");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4360,6 +4657,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation_name("SetDataRetrievalPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4395,6 +4695,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation_name("SetVaultAccessPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4430,6 +4733,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation_name("SetVaultNotifications");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4465,6 +4771,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation_name("UploadArchive");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4514,6 +4823,9 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation_name("UploadMultipartPart");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4544,3 +4856,403 @@ impl Glacier for GlacierClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Glacier API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GlacierClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GlacierBlockingClient {
+    client: GlacierClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GlacierBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GlacierBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GlacierBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlacierBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GlacierBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> GlacierBlockingClient {
+        GlacierBlockingClient {
+            client: GlacierClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>This operation aborts a multipart upload identified by the upload ID.</p> <p>After the Abort Multipart Upload request succeeds, you cannot upload any more parts to the multipart upload or complete the multipart upload. Aborting a completed upload fails. However, aborting an already-aborted upload will succeed, for a short time. For more information about uploading a part and completing a multipart upload, see <a>UploadMultipartPart</a> and <a>CompleteMultipartUpload</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::abort_multipart_upload`.
+    pub fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> Result<(), RusotoError<AbortMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.abort_multipart_upload(input))
+    }
+
+    /// <p>This operation aborts the vault locking process if the vault lock is not in the <code>Locked</code> state. If the vault lock is in the <code>Locked</code> state when this operation is requested, the operation returns an <code>AccessDeniedException</code> error. Aborting the vault locking process removes the vault lock policy from the specified vault. </p> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can get the state of a vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>This operation is idempotent. You can successfully invoke this operation multiple times, if the vault lock is in the <code>InProgress</code> state or if there is no policy associated with the vault.</p>
+    ///
+    /// See `Glacier::abort_vault_lock`.
+    pub fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> Result<(), RusotoError<AbortVaultLockError>> {
+        self.runtime.block_on(self.client.abort_vault_lock(input))
+    }
+
+    /// <p>This operation adds the specified tags to a vault. Each tag is composed of a key and a value. Each vault can have up to 10 tags. If your request would cause the tag limit for the vault to be exceeded, the operation throws the <code>LimitExceededException</code> error. If a tag already exists on the vault under a specified key, the existing key value will be overwritten. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. </p>
+    ///
+    /// See `Glacier::add_tags_to_vault`.
+    pub fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> Result<(), RusotoError<AddTagsToVaultError>> {
+        self.runtime.block_on(self.client.add_tags_to_vault(input))
+    }
+
+    /// <p>You call this operation to inform Amazon S3 Glacier (Glacier) that all the archive parts have been uploaded and that Glacier can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource. Using the URI path, you can then access the archive. After you upload an archive, you should save the archive ID returned to retrieve the archive at a later point. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>.</p> <p>In the request, you must include the computed SHA256 tree hash of the entire archive you have uploaded. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. On the server side, Glacier also constructs the SHA256 tree hash of the assembled archive. If the values match, Glacier saves the archive to the vault; otherwise, it returns an error, and the operation fails. The <a>ListParts</a> operation returns a list of parts uploaded for a specific multipart upload. It includes checksum information for each uploaded part that can be used to debug a bad checksum issue.</p> <p>Additionally, Glacier also checks for any missing content ranges when assembling the archive, if missing content ranges are found, Glacier returns an error and the operation fails.</p> <p>Complete Multipart Upload is an idempotent operation. After your first successful complete multipart upload, if you call the operation again within a short period, the operation will succeed and return the same archive ID. This is useful in the event you experience a network issue that causes an aborted connection or receive a 500 server error, in which case you can repeat your Complete Multipart Upload request and get the same archive ID without creating duplicate archives. Note, however, that after the multipart upload completes, you cannot call the List Parts operation and the multipart upload will not appear in List Multipart Uploads response, even if idempotent complete is possible.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-complete-upload.html">Complete Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::complete_multipart_upload`.
+    pub fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> Result<ArchiveCreationOutput, RusotoError<CompleteMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.complete_multipart_upload(input))
+    }
+
+    /// <p>This operation completes the vault locking process by transitioning the vault lock from the <code>InProgress</code> state to the <code>Locked</code> state, which causes the vault lock policy to become unchangeable. A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. You can obtain the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>This operation is idempotent. This request is always successful if the vault lock is in the <code>Locked</code> state and the provided lock ID matches the lock ID originally used to lock the vault.</p> <p>If an invalid lock ID is passed in the request when the vault lock is in the <code>Locked</code> state, the operation returns an <code>AccessDeniedException</code> error. If an invalid lock ID is passed in the request when the vault lock is in the <code>InProgress</code> state, the operation throws an <code>InvalidParameter</code> error.</p>
+    ///
+    /// See `Glacier::complete_vault_lock`.
+    pub fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> Result<(), RusotoError<CompleteVaultLockError>> {
+        self.runtime
+            .block_on(self.client.complete_vault_lock(input))
+    }
+
+    /// <p>This operation creates a new vault with the specified name. The name of the vault must be unique within a region for an AWS account. You can create up to 1,000 vaults per account. If you need to create more vaults, contact Amazon S3 Glacier.</p> <p>You must use the following guidelines when naming a vault.</p> <ul> <li> <p>Names can be between 1 and 255 characters long.</p> </li> <li> <p>Allowed characters are a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), and '.' (period).</p> </li> </ul> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/creating-vaults.html">Creating a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-put.html">Create Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::create_vault`.
+    pub fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> Result<CreateVaultOutput, RusotoError<CreateVaultError>> {
+        self.runtime.block_on(self.client.create_vault(input))
+    }
+
+    /// <p>This operation deletes an archive from a vault. Subsequent requests to initiate a retrieval of this archive will fail. Archive retrievals that are in progress for this archive ID may or may not succeed according to the following scenarios:</p> <ul> <li> <p>If the archive retrieval job is actively preparing the data for download when Amazon S3 Glacier receives the delete archive request, the archival retrieval operation might fail.</p> </li> <li> <p>If the archive retrieval job has successfully prepared the archive for download when Amazon S3 Glacier receives the delete archive request, you will be able to download the output.</p> </li> </ul> <p>This operation is idempotent. Attempting to delete an already-deleted archive does not result in an error.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-an-archive.html">Deleting an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::delete_archive`.
+    pub fn delete_archive(
+        &self,
+        input: DeleteArchiveInput,
+    ) -> Result<(), RusotoError<DeleteArchiveError>> {
+        self.runtime.block_on(self.client.delete_archive(input))
+    }
+
+    /// <p>This operation deletes a vault. Amazon S3 Glacier will delete a vault only if there are no archives in the vault as of the last inventory and there have been no writes to the vault since the last inventory. If either of these conditions is not satisfied, the vault deletion fails (that is, the vault is not removed) and Amazon S3 Glacier returns an error. You can use <a>DescribeVault</a> to return the number of archives in a vault, and you can use <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job (POST jobs)</a> to initiate a new inventory retrieval for a vault. The inventory contains the archive IDs you use to delete archives using <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-delete.html">Delete Archive (DELETE archive)</a>.</p> <p>This operation is idempotent.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/deleting-vaults.html">Deleting a Vault in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-delete.html">Delete Vault </a> in the <i>Amazon S3 Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::delete_vault`.
+    pub fn delete_vault(
+        &self,
+        input: DeleteVaultInput,
+    ) -> Result<(), RusotoError<DeleteVaultError>> {
+        self.runtime.block_on(self.client.delete_vault(input))
+    }
+
+    /// <p>This operation deletes the access policy associated with the specified vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely remove the access policy, and you might still see the effect of the policy for a short time after you send the delete request.</p> <p>This operation is idempotent. You can invoke delete multiple times, even if there is no policy associated with the vault. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
+    ///
+    /// See `Glacier::delete_vault_access_policy`.
+    pub fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<DeleteVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_vault_access_policy(input))
+    }
+
+    /// <p>This operation deletes the notification configuration set for a vault. The operation is eventually consistent; that is, it might take some time for Amazon S3 Glacier to completely disable the notifications and you might still receive some notifications for a short time after you send the delete request.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-delete.html">Delete Vault Notification Configuration </a> in the Amazon S3 Glacier Developer Guide. </p>
+    ///
+    /// See `Glacier::delete_vault_notifications`.
+    pub fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> Result<(), RusotoError<DeleteVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.delete_vault_notifications(input))
+    }
+
+    /// <p>This operation returns information about a job you previously initiated, including the job initiation date, the user who initiated the job, the job status code/message and the Amazon SNS topic to notify after Amazon S3 Glacier (Glacier) completes the job. For more information about initiating a job, see <a>InitiateJob</a>. </p> <note> <p>This operation enables you to check the status of your job. However, it is strongly recommended that you set up an Amazon SNS topic and specify it in your initiate job request so that Glacier can notify the topic after it completes the job.</p> </note> <p>A job ID will not expire for at least 24 hours after Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-describe-job-get.html">Describe Job</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::describe_job`.
+    pub fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> Result<GlacierJobDescription, RusotoError<DescribeJobError>> {
+        self.runtime.block_on(self.client.describe_job(input))
+    }
+
+    /// <p>This operation returns information about a vault, including the vault's Amazon Resource Name (ARN), the date the vault was created, the number of archives it contains, and the total size of all the archives in the vault. The number of archives and their total size are as of the last inventory generation. This means that if you add or remove an archive from a vault, and then immediately use Describe Vault, the change in contents will not be immediately reflected. If you want to retrieve the latest inventory of the vault, use <a>InitiateJob</a>. Amazon S3 Glacier generates vault inventories approximately daily. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-get.html">Describe Vault </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::describe_vault`.
+    pub fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<DescribeVaultOutput, RusotoError<DescribeVaultError>> {
+        self.runtime.block_on(self.client.describe_vault(input))
+    }
+
+    /// <p>This operation returns the current data retrieval policy for the account and region specified in the GET request. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>.</p>
+    ///
+    /// See `Glacier::get_data_retrieval_policy`.
+    pub fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> Result<GetDataRetrievalPolicyOutput, RusotoError<GetDataRetrievalPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_data_retrieval_policy(input))
+    }
+
+    /// <p>This operation downloads the output of the job you initiated using <a>InitiateJob</a>. Depending on the job type you specified when you initiated the job, the output will be either the content of an archive or a vault inventory.</p> <p>You can download all the job output or download a portion of the output by specifying a byte range. In the case of an archive retrieval job, depending on the byte range you specify, Amazon S3 Glacier (Glacier) returns the checksum for the portion of the data. You can compute the checksum on the client and verify that the values match to ensure the portion you downloaded is the correct data.</p> <p>A job ID will not expire for at least 24 hours after Glacier completes the job. That a byte range. For both archive and inventory retrieval jobs, you should verify the downloaded size against the size returned in the headers from the <b>Get Job Output</b> response.</p> <p>For archive retrieval jobs, you should also verify that the size is what you expected. If you download a portion of the output, the expected size is based on the range of bytes you specified. For example, if you specify a range of <code>bytes=0-1048575</code>, you should verify your download size is 1,048,576 bytes. If you download an entire archive, the expected size is the size of the archive when you uploaded it to Amazon S3 Glacier The expected size is also returned in the headers from the <b>Get Job Output</b> response.</p> <p>In the case of an archive retrieval job, depending on the byte range you specify, Glacier returns the checksum for the portion of the data. To ensure the portion you downloaded is the correct data, compute the checksum on the client, verify that the values match, and verify that the size is what you expected.</p> <p>A job ID does not expire for at least 24 hours after Glacier completes the job. That is, you can download the job output within the 24 hours period after Amazon Glacier completes the job.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-inventory.html">Downloading a Vault Inventory</a>, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/downloading-an-archive.html">Downloading an Archive</a>, and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-job-output-get.html">Get Job Output </a> </p>
+    ///
+    /// See `Glacier::get_job_output`.
+    pub fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> Result<GetJobOutputOutput, RusotoError<GetJobOutputError>> {
+        self.runtime.block_on(self.client.get_job_output(input))
+    }
+
+    /// <p>This operation retrieves the <code>access-policy</code> subresource set on the vault; for more information on setting this subresource, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-SetVaultAccessPolicy.html">Set Vault Access Policy (PUT access-policy)</a>. If there is no access policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>.</p>
+    ///
+    /// See `Glacier::get_vault_access_policy`.
+    pub fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> Result<GetVaultAccessPolicyOutput, RusotoError<GetVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_vault_access_policy(input))
+    }
+
+    /// <p>This operation retrieves the following attributes from the <code>lock-policy</code> subresource set on the specified vault: </p> <ul> <li> <p>The vault lock policy set on the vault.</p> </li> <li> <p>The state of the vault lock, which is either <code>InProgess</code> or <code>Locked</code>.</p> </li> <li> <p>When the lock ID expires. The lock ID is used to complete the vault locking process.</p> </li> <li> <p>When the vault lock was initiated and put into the <code>InProgress</code> state.</p> </li> </ul> <p>A vault lock is put into the <code>InProgress</code> state by calling <a>InitiateVaultLock</a>. A vault lock is put into the <code>Locked</code> state by calling <a>CompleteVaultLock</a>. You can abort the vault locking process by calling <a>AbortVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>. </p> <p>If there is no vault lock policy set on the vault, the operation returns a <code>404 Not found</code> error. For more information about vault lock policies, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p>
+    ///
+    /// See `Glacier::get_vault_lock`.
+    pub fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> Result<GetVaultLockOutput, RusotoError<GetVaultLockError>> {
+        self.runtime.block_on(self.client.get_vault_lock(input))
+    }
+
+    /// <p>This operation retrieves the <code>notification-configuration</code> subresource of the specified vault.</p> <p>For information about setting a notification configuration on a vault, see <a>SetVaultNotifications</a>. If a notification configuration for a vault is not set, the operation returns a <code>404 Not Found</code> error. For more information about vault notifications, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a>. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-get.html">Get Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::get_vault_notifications`.
+    pub fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> Result<GetVaultNotificationsOutput, RusotoError<GetVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.get_vault_notifications(input))
+    }
+
+    /// <p>This operation initiates a job of the specified type, which can be a select, an archival retrieval, or a vault retrieval. For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-initiate-job-post.html">Initiate a Job</a>. </p>
+    ///
+    /// See `Glacier::initiate_job`.
+    pub fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> Result<InitiateJobOutput, RusotoError<InitiateJobError>> {
+        self.runtime.block_on(self.client.initiate_job(input))
+    }
+
+    /// <p>This operation initiates a multipart upload. Amazon S3 Glacier creates a multipart upload resource and returns its ID in the response. The multipart upload ID is used in subsequent requests to upload parts of an archive (see <a>UploadMultipartPart</a>).</p> <p>When you initiate a multipart upload, you specify the part size in number of bytes. The part size must be a megabyte (1024 KB) multiplied by a power of 2-for example, 1048576 (1 MB), 2097152 (2 MB), 4194304 (4 MB), 8388608 (8 MB), and so on. The minimum allowable part size is 1 MB, and the maximum is 4 GB.</p> <p>Every part you upload to this resource (see <a>UploadMultipartPart</a>), except the last one, must have the same size. The last one can be the same size or smaller. For example, suppose you want to upload a 16.2 MB file. If you initiate the multipart upload with a part size of 4 MB, you will upload four parts of 4 MB each and one part of 0.2 MB. </p> <note> <p>You don't need to know the size of the archive when you start a multipart upload because Amazon S3 Glacier does not require you to specify the overall archive size.</p> </note> <p>After you complete the multipart upload, Amazon S3 Glacier (Glacier) removes the multipart upload resource referenced by the ID. Glacier also removes the multipart upload resource if you cancel the multipart upload or it may be removed if there is no activity for a period of 24 hours.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-initiate-upload.html">Initiate Multipart Upload</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    ///
+    /// See `Glacier::initiate_multipart_upload`.
+    pub fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> Result<InitiateMultipartUploadOutput, RusotoError<InitiateMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.initiate_multipart_upload(input))
+    }
+
+    /// <p>This operation initiates the vault locking process by doing the following:</p> <ul> <li> <p>Installing a vault lock policy on the specified vault.</p> </li> <li> <p>Setting the lock state of vault lock to <code>InProgress</code>.</p> </li> <li> <p>Returning a lock ID, which is used to complete the vault locking process.</p> </li> </ul> <p>You can set one vault lock policy for each vault and this policy can be up to 20 KB in size. For more information about vault lock policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock-policy.html">Amazon Glacier Access Control with Vault Lock Policies</a>. </p> <p>You must complete the vault locking process within 24 hours after the vault lock enters the <code>InProgress</code> state. After the 24 hour window ends, the lock ID expires, the vault automatically exits the <code>InProgress</code> state, and the vault lock policy is removed from the vault. You call <a>CompleteVaultLock</a> to complete the vault locking process by setting the state of the vault lock to <code>Locked</code>. </p> <p>After a vault lock is in the <code>Locked</code> state, you cannot initiate a new vault lock for the vault.</p> <p>You can abort the vault locking process by calling <a>AbortVaultLock</a>. You can get the state of the vault lock by calling <a>GetVaultLock</a>. For more information about the vault locking process, <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-lock.html">Amazon Glacier Vault Lock</a>.</p> <p>If this operation is called when the vault lock is in the <code>InProgress</code> state, the operation returns an <code>AccessDeniedException</code> error. When the vault lock is in the <code>InProgress</code> state you must call <a>AbortVaultLock</a> before you can initiate a new vault lock policy. </p>
+    ///
+    /// See `Glacier::initiate_vault_lock`.
+    pub fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> Result<InitiateVaultLockOutput, RusotoError<InitiateVaultLockError>> {
+        self.runtime
+            .block_on(self.client.initiate_vault_lock(input))
+    }
+
+    /// <p>This operation lists jobs for a vault, including jobs that are in-progress and jobs that have recently finished. The List Job operation returns a list of these jobs sorted by job initiation time.</p> <note> <p>Amazon Glacier retains recently completed jobs for a period before deleting them; however, it eventually removes completed jobs. The output of completed jobs can be retrieved. Retaining completed jobs for a period of time after they have completed enables you to get a job output in the event you miss the job completion notification or your first attempt to download it fails. For example, suppose you start an archive retrieval job to download an archive. After the job completes, you start to download the archive but encounter a network error. In this scenario, you can retry and download the archive while the job exists.</p> </note> <p>The List Jobs operation supports pagination. You should always check the response <code>Marker</code> field. If there are no more jobs to list, the <code>Marker</code> field is set to <code>null</code>. If there are more jobs to list, the <code>Marker</code> field is set to a non-null value, which you can use to continue the pagination of the list. To return a list of jobs that begins at a specific job, set the marker request parameter to the <code>Marker</code> value for that job that you obtained from a previous List Jobs request.</p> <p>You can set a maximum limit for the number of jobs returned in the response by specifying the <code>limit</code> parameter in the request. The default limit is 50. The number of jobs returned might be fewer than the limit, but the number of returned jobs never exceeds the limit.</p> <p>Additionally, you can filter the jobs list returned by specifying the optional <code>statuscode</code> parameter or <code>completed</code> parameter, or both. Using the <code>statuscode</code> parameter, you can specify to return only jobs that match either the <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code> status. Using the <code>completed</code> parameter, you can specify to return only jobs that were completed (<code>true</code>) or jobs that were not completed (<code>false</code>).</p> <p>For more information about using this operation, see the documentation for the underlying REST API <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-jobs-get.html">List Jobs</a>. </p>
+    ///
+    /// See `Glacier::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>This operation lists in-progress multipart uploads for the specified vault. An in-progress multipart upload is a multipart upload that has been initiated by an <a>InitiateMultipartUpload</a> request, but has not yet been completed or aborted. The list returned in the List Multipart Upload response has no guaranteed order. </p> <p>The List Multipart Uploads operation supports pagination. By default, this operation returns up to 50 multipart uploads in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of multipart uploads that begins at a specific upload, set the <code>marker</code> request parameter to the value you obtained from a previous List Multipart Upload request. You can also limit the number of uploads returned in the response by specifying the <code>limit</code> parameter in the request.</p> <p>Note the difference between this operation and listing parts (<a>ListParts</a>). The List Multipart Uploads operation lists all multipart uploads for a vault and does not require a multipart upload ID. The List Parts operation requires a multipart upload ID since parts are associated with a single upload.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-uploads.html">List Multipart Uploads </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    ///
+    /// See `Glacier::list_multipart_uploads`.
+    pub fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        self.runtime
+            .block_on(self.client.list_multipart_uploads(input))
+    }
+
+    /// <p>This operation lists the parts of an archive that have been uploaded in a specific multipart upload. You can make this request at any time during an in-progress multipart upload before you complete the upload (see <a>CompleteMultipartUpload</a>. List Parts returns an error for completed uploads. The list returned in the List Parts response is sorted by part range. </p> <p>The List Parts operation supports pagination. By default, this operation returns up to 50 uploaded parts in the response. You should always check the response for a <code>marker</code> at which to continue the list; if there are no more items the <code>marker</code> is <code>null</code>. To return a list of parts that begins at a specific part, set the <code>marker</code> request parameter to the value you obtained from a previous List Parts request. You can also limit the number of parts returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-list-parts.html">List Parts</a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    ///
+    /// See `Glacier::list_parts`.
+    pub fn list_parts(
+        &self,
+        input: ListPartsInput,
+    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        self.runtime.block_on(self.client.list_parts(input))
+    }
+
+    /// <p>This operation lists the provisioned capacity units for the specified AWS account.</p>
+    ///
+    /// See `Glacier::list_provisioned_capacity`.
+    pub fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> Result<ListProvisionedCapacityOutput, RusotoError<ListProvisionedCapacityError>> {
+        self.runtime
+            .block_on(self.client.list_provisioned_capacity(input))
+    }
+
+    /// <p>This operation lists all the tags attached to a vault. The operation returns an empty map if there are no tags. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>.</p>
+    ///
+    /// See `Glacier::list_tags_for_vault`.
+    pub fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> Result<ListTagsForVaultOutput, RusotoError<ListTagsForVaultError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_vault(input))
+    }
+
+    /// <p>This operation lists all vaults owned by the calling user's account. The list returned in the response is ASCII-sorted by vault name.</p> <p>By default, this operation returns up to 10 items. If there are more vaults to list, the response <code>marker</code> field contains the vault Amazon Resource Name (ARN) at which to continue the list with a new List Vaults request; otherwise, the <code>marker</code> field is <code>null</code>. To return a list of vaults that begins at a specific vault, set the <code>marker</code> request parameter to the vault ARN you obtained from a previous List Vaults request. You can also limit the number of vaults returned in the response by specifying the <code>limit</code> parameter in the request. </p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/retrieving-vault-info.html">Retrieving Vault Metadata in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vaults-get.html">List Vaults </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::list_vaults`.
+    pub fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> Result<ListVaultsOutput, RusotoError<ListVaultsError>> {
+        self.runtime.block_on(self.client.list_vaults(input))
+    }
+
+    /// <p>This operation purchases a provisioned capacity unit for an AWS account. </p>
+    ///
+    /// See `Glacier::purchase_provisioned_capacity`.
+    pub fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> Result<PurchaseProvisionedCapacityOutput, RusotoError<PurchaseProvisionedCapacityError>>
+    {
+        self.runtime
+            .block_on(self.client.purchase_provisioned_capacity(input))
+    }
+
+    /// <p>This operation removes one or more tags from the set of tags attached to a vault. For more information about tags, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/tagging.html">Tagging Amazon S3 Glacier Resources</a>. This operation is idempotent. The operation will be successful, even if there are no tags attached to the vault. </p>
+    ///
+    /// See `Glacier::remove_tags_from_vault`.
+    pub fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromVaultError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_vault(input))
+    }
+
+    /// <p>This operation sets and then enacts a data retrieval policy in the region specified in the PUT request. You can set one policy per region for an AWS account. The policy is enacted within a few minutes of a successful PUT operation.</p> <p>The set policy operation does not affect retrieval jobs that were in progress before the policy was enacted. For more information about data retrieval policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/data-retrieval-policy.html">Amazon Glacier Data Retrieval Policies</a>. </p>
+    ///
+    /// See `Glacier::set_data_retrieval_policy`.
+    pub fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> Result<(), RusotoError<SetDataRetrievalPolicyError>> {
+        self.runtime
+            .block_on(self.client.set_data_retrieval_policy(input))
+    }
+
+    /// <p>This operation configures an access policy for a vault and will overwrite an existing policy. To configure a vault access policy, send a PUT request to the <code>access-policy</code> subresource of the vault. An access policy is specific to a vault and is also called a vault subresource. You can set one access policy per vault and the policy can be up to 20 KB in size. For more information about vault access policies, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/vault-access-policy.html">Amazon Glacier Access Control with Vault Access Policies</a>. </p>
+    ///
+    /// See `Glacier::set_vault_access_policy`.
+    pub fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<SetVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.set_vault_access_policy(input))
+    }
+
+    /// <p>This operation configures notifications that will be sent when specific events happen to a vault. By default, you don't get any notifications.</p> <p>To configure vault notifications, send a PUT request to the <code>notification-configuration</code> subresource of the vault. The request should include a JSON document that provides an Amazon SNS topic and specific events for which you want Amazon S3 Glacier to send notifications to the topic.</p> <p>Amazon SNS topics must grant permission to the vault to be allowed to publish notifications to the topic. You can configure a vault to publish a notification for the following vault events:</p> <ul> <li> <p> <b>ArchiveRetrievalCompleted</b> This event occurs when a job that was initiated for an archive retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> <li> <p> <b>InventoryRetrievalCompleted</b> This event occurs when a job that was initiated for an inventory retrieval is completed (<a>InitiateJob</a>). The status of the completed job can be "Succeeded" or "Failed". The notification sent to the SNS topic is the same output as returned from <a>DescribeJob</a>. </p> </li> </ul> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p>For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/configuring-notifications.html">Configuring Vault Notifications in Amazon S3 Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-vault-notifications-put.html">Set Vault Notification Configuration </a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::set_vault_notifications`.
+    pub fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> Result<(), RusotoError<SetVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.set_vault_notifications(input))
+    }
+
+    /// <p>This operation adds an archive to a vault. This is a synchronous operation, and for a successful upload, your data is durably persisted. Amazon S3 Glacier returns the archive ID in the <code>x-amz-archive-id</code> header of the response. </p> <p>You must use the archive ID to access your data in Amazon S3 Glacier. After you upload an archive, you should save the archive ID returned so that you can retrieve or delete the archive later. Besides saving the archive ID, you can also index it and give it a friendly name to allow for better searching. You can also use the optional archive description field to specify how the archive is referred to in an external index of archives, such as you might create in Amazon DynamoDB. You can also get the vault inventory to obtain a list of archive IDs in a vault. For more information, see <a>InitiateJob</a>. </p> <p>You must provide a SHA256 tree hash of the data you are uploading. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>. </p> <p>You can optionally specify an archive description of up to 1,024 printable ASCII characters. You can get the archive description when you either retrieve the archive or get the vault inventory. For more information, see <a>InitiateJob</a>. Amazon Glacier does not interpret the description in any way. An archive description does not need to be unique. You cannot use the description to retrieve or sort the archive list. </p> <p>Archives are immutable. After you upload an archive, you cannot edit the archive or its description.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-an-archive.html">Uploading an Archive in Amazon Glacier</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a> in the <i>Amazon Glacier Developer Guide</i>. </p>
+    ///
+    /// See `Glacier::upload_archive`.
+    pub fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>> {
+        self.runtime.block_on(self.client.upload_archive(input))
+    }
+
+    /// <p>This operation uploads a part of an archive. You can upload archive parts in any order. You can also upload them in parallel. You can upload up to 10,000 parts for a multipart upload.</p> <p>Amazon Glacier rejects your upload part request if any of the following conditions is true:</p> <ul> <li> <p> <b>SHA256 tree hash does not match</b>To ensure that part data is not corrupted in transmission, you compute a SHA256 tree hash of the part and include it in your request. Upon receiving the part data, Amazon S3 Glacier also computes a SHA256 tree hash. If these hash values don't match, the operation fails. For information about computing a SHA256 tree hash, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/checksum-calculations.html">Computing Checksums</a>.</p> </li> <li> <p> <b>Part size does not match</b>The size of each part except the last must match the size specified in the corresponding <a>InitiateMultipartUpload</a> request. The size of the last part must be the same size as, or smaller than, the specified size.</p> <note> <p>If you upload a part whose size is smaller than the part size you specified in your initiate multipart upload request and that part is not the last part, then the upload part request will succeed. However, the subsequent Complete Multipart Upload request will fail.</p> </note> </li> <li> <p> <b>Range does not align</b>The byte range value in the request does not align with the part size specified in the corresponding initiate request. For example, if you specify a part size of 4194304 bytes (4 MB), then 0 to 4194303 bytes (4 MB - 1) and 4194304 (4 MB) to 8388607 (8 MB - 1) are valid part ranges. However, if you set a range value of 2 MB to 6 MB, the range does not align with the part size and the upload will fail. </p> </li> </ul> <p>This operation is idempotent. If you upload the same part multiple times, the data included in the most recent request overwrites the previously uploaded data.</p> <p>An AWS account has full permission to perform all operations (actions). However, AWS Identity and Access Management (IAM) users don't have any permissions by default. You must grant them explicit permission to perform specific actions. For more information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/using-iam-with-amazon-glacier.html">Access Control Using AWS Identity and Access Management (IAM)</a>.</p> <p> For conceptual information and underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/uploading-archive-mpu.html">Uploading Large Archives in Parts (Multipart Upload)</a> and <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-upload-part.html">Upload Part </a> in the <i>Amazon Glacier Developer Guide</i>.</p>
+    ///
+    /// See `Glacier::upload_multipart_part`.
+    pub fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> Result<UploadMultipartPartOutput, RusotoError<UploadMultipartPartError>> {
+        self.runtime
+            .block_on(self.client.upload_multipart_part(input))
+    }
+}
@@ -890,25 +890,34 @@ impl AdvertiseByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdvertiseByoipCidrError::AccessDenied(err.msg),
+                    )
                 }
                 "ByoipCidrNotFoundException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::ByoipCidrNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdvertiseByoipCidrError::ByoipCidrNotFound(err.msg),
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::IncorrectCidrState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdvertiseByoipCidrError::IncorrectCidrState(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AdvertiseByoipCidrError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AdvertiseByoipCidrError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -946,15 +955,22 @@ impl CreateAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAcceleratorError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAcceleratorError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAcceleratorError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAcceleratorError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -998,33 +1014,46 @@ impl CreateEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointGroupError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointGroupError::AccessDenied(err.msg),
+                    )
                 }
                 "EndpointGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEndpointGroupError::EndpointGroupAlreadyExists(err.msg),
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointGroupError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointGroupError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::ListenerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointGroupError::ListenerNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1070,19 +1099,31 @@ impl CreateListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(CreateListenerError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateListenerError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateListenerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateListenerError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(CreateListenerError::InvalidPortRange(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateListenerError::InvalidPortRange(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateListenerError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateListenerError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1124,27 +1165,34 @@ impl DeleteAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotDisabledException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AcceleratorNotDisabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAcceleratorError::AcceleratorNotDisabled(err.msg),
+                    )
                 }
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAcceleratorError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "AssociatedListenerFoundException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AssociatedListenerFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAcceleratorError::AssociatedListenerFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAcceleratorError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAcceleratorError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1182,17 +1230,22 @@ impl DeleteEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::EndpointGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointGroupError::EndpointGroupNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointGroupError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEndpointGroupError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1230,18 +1283,28 @@ impl DeleteListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedEndpointGroupFoundException" => {
-                    return RusotoError::Service(DeleteListenerError::AssociatedEndpointGroupFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteListenerError::AssociatedEndpointGroupFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteListenerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteListenerError::InvalidArgument(err.msg),
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(DeleteListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteListenerError::ListenerNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1282,27 +1345,34 @@ impl DeprovisionByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprovisionByoipCidrError::AccessDenied(err.msg),
+                    )
                 }
                 "ByoipCidrNotFoundException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::ByoipCidrNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprovisionByoipCidrError::ByoipCidrNotFound(err.msg),
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::IncorrectCidrState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprovisionByoipCidrError::IncorrectCidrState(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprovisionByoipCidrError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprovisionByoipCidrError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1340,17 +1410,22 @@ impl DescribeAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAcceleratorError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1388,17 +1463,20 @@ impl DescribeAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAcceleratorAttributesError::AcceleratorNotFound(err.msg),
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAcceleratorAttributesError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAcceleratorAttributesError::InvalidArgument(err.msg),
                     )
                 }
@@ -1442,19 +1520,22 @@ impl DescribeEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::EndpointGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointGroupError::EndpointGroupNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointGroupError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointGroupError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1490,15 +1571,22 @@ impl DescribeListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeListenerError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeListenerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeListenerError::InvalidArgument(err.msg),
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(DescribeListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeListenerError::ListenerNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1534,15 +1622,22 @@ impl ListAcceleratorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAcceleratorsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAcceleratorsError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAcceleratorsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1580,16 +1675,25 @@ impl ListByoipCidrsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListByoipCidrsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListByoipCidrsError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListByoipCidrsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListByoipCidrsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListByoipCidrsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListByoipCidrsError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListByoipCidrsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListByoipCidrsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1628,18 +1732,28 @@ impl ListEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEndpointGroupsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEndpointGroupsError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEndpointGroupsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEndpointGroupsError::ListenerNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1678,16 +1792,25 @@ impl ListListenersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(ListListenersError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListListenersError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListListenersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListListenersError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListListenersError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, ListListenersError::InvalidArgument(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListListenersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListListenersError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1724,17 +1847,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1774,23 +1902,34 @@ impl ProvisionByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionByoipCidrError::AccessDenied(err.msg),
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::IncorrectCidrState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionByoipCidrError::IncorrectCidrState(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionByoipCidrError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionByoipCidrError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionByoipCidrError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1828,13 +1967,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArgument(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1870,13 +2015,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArgument(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1912,17 +2063,22 @@ impl UpdateAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::AcceleratorNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAcceleratorError::AcceleratorNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAcceleratorError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAcceleratorError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1962,24 +2118,28 @@ impl UpdateAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAcceleratorAttributesError::AcceleratorNotFound(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAcceleratorAttributesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAcceleratorAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAcceleratorAttributesError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateAcceleratorAttributesError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAcceleratorAttributesError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2024,23 +2184,34 @@ impl UpdateEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointGroupError::AccessDenied(err.msg),
+                    )
                 }
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::EndpointGroupNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointGroupError::EndpointGroupNotFound(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointGroupError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointGroupError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2082,19 +2253,31 @@ impl UpdateListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateListenerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateListenerError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(UpdateListenerError::InvalidPortRange(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateListenerError::InvalidPortRange(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateListenerError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateListenerError::LimitExceeded(err.msg))
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(UpdateListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateListenerError::ListenerNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2136,23 +2319,34 @@ impl WithdrawByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        WithdrawByoipCidrError::AccessDenied(err.msg),
+                    )
                 }
                 "ByoipCidrNotFoundException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::ByoipCidrNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        WithdrawByoipCidrError::ByoipCidrNotFound(err.msg),
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::IncorrectCidrState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        WithdrawByoipCidrError::IncorrectCidrState(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        WithdrawByoipCidrError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        WithdrawByoipCidrError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2348,6 +2542,7 @@ impl GlobalAcceleratorClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2363,6 +2558,7 @@ impl GlobalAcceleratorClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GlobalAcceleratorClient {
         GlobalAcceleratorClient { client, region }
     }
@@ -2376,6 +2572,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: AdvertiseByoipCidrRequest,
     ) -> Result<AdvertiseByoipCidrResponse, RusotoError<AdvertiseByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AdvertiseByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.AdvertiseByoipCidr",
@@ -2397,6 +2596,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateAcceleratorRequest,
     ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAccelerator");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateAccelerator",
@@ -2418,6 +2620,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateEndpointGroupRequest,
     ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEndpointGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateEndpointGroup",
@@ -2439,6 +2644,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateListenerRequest,
     ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateListener");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.CreateListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2457,6 +2665,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteAcceleratorRequest,
     ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAccelerator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteAccelerator",
@@ -2477,6 +2688,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteEndpointGroupRequest,
     ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEndpointGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteEndpointGroup",
@@ -2497,6 +2711,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteListenerRequest,
     ) -> Result<(), RusotoError<DeleteListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteListener");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.DeleteListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2514,6 +2731,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeprovisionByoipCidrRequest,
     ) -> Result<DeprovisionByoipCidrResponse, RusotoError<DeprovisionByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeprovisionByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeprovisionByoipCidr",
@@ -2536,6 +2756,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeAcceleratorRequest,
     ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAccelerator");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeAccelerator",
@@ -2560,6 +2783,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeAcceleratorAttributesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAcceleratorAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeAcceleratorAttributes",
@@ -2582,6 +2808,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeEndpointGroupRequest,
     ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpointGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeEndpointGroup",
@@ -2604,6 +2833,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeListenerRequest,
     ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeListener");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeListener",
@@ -2625,6 +2857,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListAcceleratorsRequest,
     ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAccelerators");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListAccelerators",
@@ -2646,6 +2881,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListByoipCidrsRequest,
     ) -> Result<ListByoipCidrsResponse, RusotoError<ListByoipCidrsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListByoipCidrs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.ListByoipCidrs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2664,6 +2902,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListEndpointGroupsRequest,
     ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEndpointGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListEndpointGroups",
@@ -2685,6 +2926,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListListenersRequest,
     ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListListeners");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.ListListeners");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2703,6 +2947,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListTagsForResource",
@@ -2724,6 +2971,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ProvisionByoipCidrRequest,
     ) -> Result<ProvisionByoipCidrResponse, RusotoError<ProvisionByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ProvisionByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ProvisionByoipCidr",
@@ -2745,6 +2995,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2763,6 +3016,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2781,6 +3037,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateAcceleratorRequest,
     ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAccelerator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateAccelerator",
@@ -2803,6 +3062,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
     ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAcceleratorAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateAcceleratorAttributes",
@@ -2825,6 +3087,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateEndpointGroupRequest,
     ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEndpointGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateEndpointGroup",
@@ -2846,6 +3111,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateListenerRequest,
     ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateListener");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.UpdateListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2864,6 +3132,9 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: WithdrawByoipCidrRequest,
     ) -> Result<WithdrawByoipCidrResponse, RusotoError<WithdrawByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("WithdrawByoipCidr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.WithdrawByoipCidr",
@@ -2879,3 +3150,323 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         proto::json::ResponsePayload::new(&response).deserialize::<WithdrawByoipCidrResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Global Accelerator API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GlobalAcceleratorClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GlobalAcceleratorBlockingClient {
+    client: GlobalAcceleratorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GlobalAcceleratorBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GlobalAcceleratorBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GlobalAcceleratorBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlobalAcceleratorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GlobalAcceleratorBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient {
+            client: GlobalAcceleratorClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Advertises an IPv4 address range that is provisioned for use with your AWS resources through bring your own IP addresses (BYOIP). It can take a few minutes before traffic to the specified addresses starts routing to AWS because of propagation delays. To see an AWS CLI example of advertising an address range, scroll down to <b>Example</b>.</p> <p>To stop advertising the BYOIP address range, use <a href="https://docs.aws.amazon.com/global-accelerator/latest/api/WithdrawByoipCidr.html"> WithdrawByoipCidr</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/using-byoip.html">Bring Your Own IP Addresses (BYOIP)</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p>
+    ///
+    /// See `GlobalAccelerator::advertise_byoip_cidr`.
+    pub fn advertise_byoip_cidr(
+        &self,
+        input: AdvertiseByoipCidrRequest,
+    ) -> Result<AdvertiseByoipCidrResponse, RusotoError<AdvertiseByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.advertise_byoip_cidr(input))
+    }
+
+    /// <p><p>Create an accelerator. An accelerator includes one or more listeners that process inbound connections and direct traffic to one or more endpoint groups, each of which includes endpoints, such as Network Load Balancers. To see an AWS CLI example of creating an accelerator, scroll down to <b>Example</b>.</p> <p>If you bring your own IP address ranges to AWS Global Accelerator (BYOIP), you can assign IP addresses from your own pool to your accelerator as the static IP address entry points. Only one IP address from each of your IP address ranges can be used for each accelerator.</p> <important> <p>You must specify the US West (Oregon) Region to create or update accelerators.</p> </important></p>
+    ///
+    /// See `GlobalAccelerator::create_accelerator`.
+    pub fn create_accelerator(
+        &self,
+        input: CreateAcceleratorRequest,
+    ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
+        self.runtime.block_on(self.client.create_accelerator(input))
+    }
+
+    /// <p>Create an endpoint group for the specified listener. An endpoint group is a collection of endpoints in one AWS Region. To see an AWS CLI example of creating an endpoint group, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::create_endpoint_group`.
+    pub fn create_endpoint_group(
+        &self,
+        input: CreateEndpointGroupRequest,
+    ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.create_endpoint_group(input))
+    }
+
+    /// <p>Create a listener to process inbound connections from clients to an accelerator. Connections arrive to assigned static IP addresses on a port, port range, or list of port ranges that you specify. To see an AWS CLI example of creating a listener, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::create_listener`.
+    pub fn create_listener(
+        &self,
+        input: CreateListenerRequest,
+    ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
+        self.runtime.block_on(self.client.create_listener(input))
+    }
+
+    /// <p><p>Delete an accelerator. Before you can delete an accelerator, you must disable it and remove all dependent resources (listeners and endpoint groups). To disable the accelerator, update the accelerator to set <code>Enabled</code> to false.</p> <important> <p>When you create an accelerator, by default, Global Accelerator provides you with a set of two static IP addresses. Alternatively, you can bring your own IP address ranges to Global Accelerator and assign IP addresses from those ranges. </p> <p>The IP addresses are assigned to your accelerator for as long as it exists, even if you disable the accelerator and it no longer accepts or routes traffic. However, when you <i>delete</i> an accelerator, you lose the static IP addresses that are assigned to the accelerator, so you can no longer route traffic by using them. As a best practice, ensure that you have permissions in place to avoid inadvertently deleting accelerators. You can use IAM policies with Global Accelerator to limit the users who have permissions to delete an accelerator. For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/auth-and-access-control.html">Authentication and Access Control</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p> </important></p>
+    ///
+    /// See `GlobalAccelerator::delete_accelerator`.
+    pub fn delete_accelerator(
+        &self,
+        input: DeleteAcceleratorRequest,
+    ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
+        self.runtime.block_on(self.client.delete_accelerator(input))
+    }
+
+    /// <p>Delete an endpoint group from a listener.</p>
+    ///
+    /// See `GlobalAccelerator::delete_endpoint_group`.
+    pub fn delete_endpoint_group(
+        &self,
+        input: DeleteEndpointGroupRequest,
+    ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_endpoint_group(input))
+    }
+
+    /// <p>Delete a listener from an accelerator.</p>
+    ///
+    /// See `GlobalAccelerator::delete_listener`.
+    pub fn delete_listener(
+        &self,
+        input: DeleteListenerRequest,
+    ) -> Result<(), RusotoError<DeleteListenerError>> {
+        self.runtime.block_on(self.client.delete_listener(input))
+    }
+
+    /// <p>Releases the specified address range that you provisioned to use with your AWS resources through bring your own IP addresses (BYOIP) and deletes the corresponding address pool. To see an AWS CLI example of deprovisioning an address range, scroll down to <b>Example</b>.</p> <p>Before you can release an address range, you must stop advertising it by using <a href="https://docs.aws.amazon.com/global-accelerator/latest/api/WithdrawByoipCidr.html">WithdrawByoipCidr</a> and you must not have any accelerators that are using static IP addresses allocated from its address range. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/using-byoip.html">Bring Your Own IP Addresses (BYOIP)</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p>
+    ///
+    /// See `GlobalAccelerator::deprovision_byoip_cidr`.
+    pub fn deprovision_byoip_cidr(
+        &self,
+        input: DeprovisionByoipCidrRequest,
+    ) -> Result<DeprovisionByoipCidrResponse, RusotoError<DeprovisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.deprovision_byoip_cidr(input))
+    }
+
+    /// <p>Describe an accelerator. To see an AWS CLI example of describing an accelerator, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::describe_accelerator`.
+    pub fn describe_accelerator(
+        &self,
+        input: DescribeAcceleratorRequest,
+    ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
+        self.runtime
+            .block_on(self.client.describe_accelerator(input))
+    }
+
+    /// <p>Describe the attributes of an accelerator. To see an AWS CLI example of describing the attributes of an accelerator, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::describe_accelerator_attributes`.
+    pub fn describe_accelerator_attributes(
+        &self,
+        input: DescribeAcceleratorAttributesRequest,
+    ) -> Result<
+        DescribeAcceleratorAttributesResponse,
+        RusotoError<DescribeAcceleratorAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_accelerator_attributes(input))
+    }
+
+    /// <p>Describe an endpoint group. To see an AWS CLI example of describing an endpoint group, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::describe_endpoint_group`.
+    pub fn describe_endpoint_group(
+        &self,
+        input: DescribeEndpointGroupRequest,
+    ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_endpoint_group(input))
+    }
+
+    /// <p>Describe a listener. To see an AWS CLI example of describing a listener, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::describe_listener`.
+    pub fn describe_listener(
+        &self,
+        input: DescribeListenerRequest,
+    ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
+        self.runtime.block_on(self.client.describe_listener(input))
+    }
+
+    /// <p>List the accelerators for an AWS account. To see an AWS CLI example of listing the accelerators for an AWS account, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::list_accelerators`.
+    pub fn list_accelerators(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
+        self.runtime.block_on(self.client.list_accelerators(input))
+    }
+
+    /// <p>Lists the IP address ranges that were specified in calls to <a href="https://docs.aws.amazon.com/global-accelerator/latest/api/ProvisionByoipCidr.html">ProvisionByoipCidr</a>, including the current state and a history of state changes.</p> <p>To see an AWS CLI example of listing BYOIP CIDR addresses, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::list_byoip_cidrs`.
+    pub fn list_byoip_cidrs(
+        &self,
+        input: ListByoipCidrsRequest,
+    ) -> Result<ListByoipCidrsResponse, RusotoError<ListByoipCidrsError>> {
+        self.runtime.block_on(self.client.list_byoip_cidrs(input))
+    }
+
+    /// <p>List the endpoint groups that are associated with a listener. To see an AWS CLI example of listing the endpoint groups for listener, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::list_endpoint_groups`.
+    pub fn list_endpoint_groups(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_endpoint_groups(input))
+    }
+
+    /// <p>List the listeners for an accelerator. To see an AWS CLI example of listing the listeners for an accelerator, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::list_listeners`.
+    pub fn list_listeners(
+        &self,
+        input: ListListenersRequest,
+    ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
+        self.runtime.block_on(self.client.list_listeners(input))
+    }
+
+    /// <p>List all tags for an accelerator. To see an AWS CLI example of listing tags for an accelerator, scroll down to <b>Example</b>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/tagging-in-global-accelerator.html">Tagging in AWS Global Accelerator</a> in the <i>AWS Global Accelerator Developer Guide</i>. </p>
+    ///
+    /// See `GlobalAccelerator::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Provisions an IP address range to use with your AWS resources through bring your own IP addresses (BYOIP) and creates a corresponding address pool. After the address range is provisioned, it is ready to be advertised using <a href="https://docs.aws.amazon.com/global-accelerator/latest/api/AdvertiseByoipCidr.html"> AdvertiseByoipCidr</a>.</p> <p>To see an AWS CLI example of provisioning an address range for BYOIP, scroll down to <b>Example</b>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/using-byoip.html">Bring Your Own IP Addresses (BYOIP)</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p>
+    ///
+    /// See `GlobalAccelerator::provision_byoip_cidr`.
+    pub fn provision_byoip_cidr(
+        &self,
+        input: ProvisionByoipCidrRequest,
+    ) -> Result<ProvisionByoipCidrResponse, RusotoError<ProvisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.provision_byoip_cidr(input))
+    }
+
+    /// <p>Add tags to an accelerator resource. To see an AWS CLI example of adding tags to an accelerator, scroll down to <b>Example</b>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/tagging-in-global-accelerator.html">Tagging in AWS Global Accelerator</a> in the <i>AWS Global Accelerator Developer Guide</i>. </p>
+    ///
+    /// See `GlobalAccelerator::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove tags from a Global Accelerator resource. When you specify a tag key, the action removes both that key and its associated value. To see an AWS CLI example of removing tags from an accelerator, scroll down to <b>Example</b>. The operation succeeds even if you attempt to remove tags from an accelerator that was already removed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/tagging-in-global-accelerator.html">Tagging in AWS Global Accelerator</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p>
+    ///
+    /// See `GlobalAccelerator::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Update an accelerator. To see an AWS CLI example of updating an accelerator, scroll down to <b>Example</b>.</p> <important> <p>You must specify the US West (Oregon) Region to create or update accelerators.</p> </important></p>
+    ///
+    /// See `GlobalAccelerator::update_accelerator`.
+    pub fn update_accelerator(
+        &self,
+        input: UpdateAcceleratorRequest,
+    ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
+        self.runtime.block_on(self.client.update_accelerator(input))
+    }
+
+    /// <p>Update the attributes for an accelerator. To see an AWS CLI example of updating an accelerator to enable flow logs, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::update_accelerator_attributes`.
+    pub fn update_accelerator_attributes(
+        &self,
+        input: UpdateAcceleratorAttributesRequest,
+    ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.update_accelerator_attributes(input))
+    }
+
+    /// <p>Update an endpoint group. To see an AWS CLI example of updating an endpoint group, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::update_endpoint_group`.
+    pub fn update_endpoint_group(
+        &self,
+        input: UpdateEndpointGroupRequest,
+    ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.update_endpoint_group(input))
+    }
+
+    /// <p>Update a listener. To see an AWS CLI example of updating listener, scroll down to <b>Example</b>.</p>
+    ///
+    /// See `GlobalAccelerator::update_listener`.
+    pub fn update_listener(
+        &self,
+        input: UpdateListenerRequest,
+    ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
+        self.runtime.block_on(self.client.update_listener(input))
+    }
+
+    /// <p>Stops advertising an address range that is provisioned as an address pool. You can perform this operation at most once every 10 seconds, even if you specify different address ranges each time. To see an AWS CLI example of withdrawing an address range for BYOIP so it will no longer be advertised by AWS, scroll down to <b>Example</b>.</p> <p>It can take a few minutes before traffic to the specified addresses stops routing to AWS because of propagation delays.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/global-accelerator/latest/dg/using-byoip.html">Bring Your Own IP Addresses (BYOIP)</a> in the <i>AWS Global Accelerator Developer Guide</i>.</p>
+    ///
+    /// See `GlobalAccelerator::withdraw_byoip_cidr`.
+    pub fn withdraw_byoip_cidr(
+        &self,
+        input: WithdrawByoipCidrRequest,
+    ) -> Result<WithdrawByoipCidrResponse, RusotoError<WithdrawByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.withdraw_byoip_cidr(input))
+    }
+}
@@ -6649,29 +6649,44 @@ impl BatchCreatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreatePartitionError::AlreadyExists(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreatePartitionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreatePartitionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreatePartitionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreatePartitionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchCreatePartitionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchCreatePartitionError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -6713,14 +6728,16 @@ impl BatchDeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteConnectionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteConnectionError::InternalService(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteConnectionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteConnectionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6757,20 +6774,28 @@ impl BatchDeletePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePartitionError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePartitionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePartitionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeletePartitionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6809,16 +6834,25 @@ impl BatchDeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteTableError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteTableError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchDeleteTableError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6857,24 +6891,28 @@ impl BatchDeleteTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableVersionError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableVersionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableVersionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteTableVersionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6909,10 +6947,13 @@ impl BatchGetCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetCrawlersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchGetCrawlersError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetCrawlersError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6949,20 +6990,28 @@ impl BatchGetDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDevEndpointsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDevEndpointsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDevEndpointsError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetDevEndpointsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6999,13 +7048,13 @@ impl BatchGetJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetJobsError::InternalService(err.msg))
+                    return RusotoError::service(&res, BatchGetJobsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchGetJobsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, BatchGetJobsError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7045,19 +7094,34 @@ impl BatchGetPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchGetPartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetPartitionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchGetPartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetPartitionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetPartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetPartitionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetPartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetPartitionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetPartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetPartitionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7095,13 +7159,19 @@ impl BatchGetTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetTriggersError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetTriggersError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, BatchGetTriggersError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetTriggersError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7137,13 +7207,22 @@ impl BatchGetWorkflowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetWorkflowsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetWorkflowsError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGetWorkflowsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7179,15 +7258,22 @@ impl GlueBatchStopJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GlueBatchStopJobRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GlueBatchStopJobRunError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GlueBatchStopJobRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7225,16 +7311,25 @@ impl CancelMLTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelMLTaskRunError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelMLTaskRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CancelMLTaskRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelMLTaskRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7271,13 +7366,19 @@ impl CreateClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateClassifierError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClassifierError::AlreadyExists(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateClassifierError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateClassifierError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClassifierError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7317,19 +7418,29 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConnectionError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::AlreadyExists(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateConnectionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConnectionError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -7371,18 +7482,22 @@ impl CreateCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateCrawlerError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateCrawlerError::AlreadyExists(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCrawlerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateCrawlerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCrawlerError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateCrawlerError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCrawlerError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7425,24 +7540,31 @@ impl CreateDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatabaseError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateDatabaseError::AlreadyExists(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, CreateDatabaseError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatabaseError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateDatabaseError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatabaseError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateDatabaseError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatabaseError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7489,27 +7611,44 @@ impl CreateDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDevEndpointError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevEndpointError::AccessDenied(err.msg),
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDevEndpointError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevEndpointError::AlreadyExists(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDevEndpointError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevEndpointError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevEndpointError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDevEndpointError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDevEndpointError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -7563,29 +7702,34 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateJobError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateJobError::AlreadyExists(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateJobError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobError::ConcurrentModification(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateJobError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateJobError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, CreateJobError::OperationTimeout(err.msg))
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7633,27 +7777,44 @@ impl CreateMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateMLTransformError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMLTransformError::AccessDenied(err.msg),
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateMLTransformError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMLTransformError::AlreadyExists(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMLTransformError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMLTransformError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMLTransformError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMLTransformError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMLTransformError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -7707,27 +7868,40 @@ impl CreatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreatePartitionError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreatePartitionError::AlreadyExists(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartitionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartitionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartitionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreatePartitionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreatePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartitionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreatePartitionError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePartitionError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7767,13 +7941,13 @@ impl CreateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScriptError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateScriptError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateScriptError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, CreateScriptError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7815,27 +7989,32 @@ impl CreateSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityConfigurationError::AlreadyExists(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityConfigurationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityConfigurationError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSecurityConfigurationError::OperationTimeout(err.msg),
                     )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSecurityConfigurationError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -7885,27 +8064,28 @@ impl CreateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateTableError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateTableError::AlreadyExists(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTableError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateTableError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, CreateTableError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTableError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateTableError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateTableError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateTableError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, CreateTableError::OperationTimeout(err.msg))
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateTableError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTableError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7955,34 +8135,40 @@ impl CreateTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateTriggerError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateTriggerError::AlreadyExists(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateTriggerError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTriggerError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTriggerError::EntityNotFound(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateTriggerError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTriggerError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTriggerError::InternalService(err.msg))
+                    return RusotoError::service(&res, CreateTriggerError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateTriggerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTriggerError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateTriggerError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTriggerError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8031,37 +8217,44 @@ impl CreateUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserDefinedFunctionError::AlreadyExists(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserDefinedFunctionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserDefinedFunctionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserDefinedFunctionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserDefinedFunctionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserDefinedFunctionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUserDefinedFunctionError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -8111,26 +8304,34 @@ impl CreateWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateWorkflowError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateWorkflowError::AlreadyExists(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateWorkflowError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkflowError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkflowError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateWorkflowError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkflowError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateWorkflowError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkflowError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8167,10 +8368,16 @@ impl DeleteClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClassifierError::EntityNotFound(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClassifierError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8211,27 +8418,32 @@ impl DeleteColumnStatisticsForPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForPartitionError::EntityNotFound(err.msg),
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForPartitionError::GlueEncryption(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForPartitionError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForPartitionError::InvalidInput(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForPartitionError::OperationTimeout(err.msg),
                     )
                 }
@@ -8287,27 +8499,32 @@ impl DeleteColumnStatisticsForTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForTableError::EntityNotFound(err.msg),
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForTableError::GlueEncryption(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForTableError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteColumnStatisticsForTableError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteColumnStatisticsForTableError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteColumnStatisticsForTableError::OperationTimeout(err.msg),
                     )
                 }
@@ -8353,10 +8570,16 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::EntityNotFound(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8393,18 +8616,22 @@ impl DeleteCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(DeleteCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service(&res, DeleteCrawlerError::CrawlerRunning(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteCrawlerError::EntityNotFound(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCrawlerError::OperationTimeout(err.msg),
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(DeleteCrawlerError::SchedulerTransitioning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCrawlerError::SchedulerTransitioning(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8443,16 +8670,22 @@ impl DeleteDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDatabaseError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatabaseError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteDatabaseError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatabaseError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8491,16 +8724,28 @@ impl DeleteDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDevEndpointError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDevEndpointError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDevEndpointError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDevEndpointError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8537,13 +8782,13 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8581,16 +8826,28 @@ impl DeleteMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMLTransformError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMLTransformError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMLTransformError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMLTransformError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8629,16 +8886,25 @@ impl DeletePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeletePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePartitionError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePartitionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeletePartitionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeletePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePartitionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8679,25 +8945,34 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionCheckFailureException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ConditionCheckFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::ConditionCheckFailure(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8739,22 +9014,26 @@ impl DeleteSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityConfigurationError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityConfigurationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityConfigurationError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSecurityConfigurationError::OperationTimeout(err.msg),
                     )
                 }
@@ -8795,16 +9074,16 @@ impl DeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTableError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTableError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, DeleteTableError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8843,16 +9122,28 @@ impl DeleteTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteTableVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTableVersionError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTableVersionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTableVersionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTableVersionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTableVersionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTableVersionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTableVersionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8891,18 +9182,22 @@ impl DeleteTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteTriggerError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTriggerError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTriggerError::InternalService(err.msg))
+                    return RusotoError::service(&res, DeleteTriggerError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteTriggerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTriggerError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8941,24 +9236,28 @@ impl DeleteUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserDefinedFunctionError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserDefinedFunctionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserDefinedFunctionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserDefinedFunctionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8997,18 +9296,25 @@ impl DeleteWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteWorkflowError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkflowError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkflowError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteWorkflowError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkflowError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9043,14 +9349,16 @@ impl GetCatalogImportStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetCatalogImportStatusError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCatalogImportStatusError::InternalService(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCatalogImportStatusError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCatalogImportStatusError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9083,10 +9391,13 @@ impl GetClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetClassifierError::EntityNotFound(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetClassifierError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9117,7 +9428,10 @@ impl GetClassifiersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetClassifiersError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetClassifiersError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9157,27 +9471,32 @@ impl GetColumnStatisticsForPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetColumnStatisticsForPartitionError::EntityNotFound(err.msg),
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetColumnStatisticsForPartitionError::GlueEncryption(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetColumnStatisticsForPartitionError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetColumnStatisticsForPartitionError::InvalidInput(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetColumnStatisticsForPartitionError::OperationTimeout(err.msg),
                     )
                 }
@@ -9231,27 +9550,32 @@ impl GetColumnStatisticsForTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetColumnStatisticsForTableError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetColumnStatisticsForTableError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetColumnStatisticsForTableError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetColumnStatisticsForTableError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetColumnStatisticsForTableError::OperationTimeout(err.msg),
                     )
                 }
@@ -9293,16 +9617,19 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetConnectionError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetConnectionError::GlueEncryption(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetConnectionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9341,16 +9668,19 @@ impl GetConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetConnectionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetConnectionsError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetConnectionsError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetConnectionsError::GlueEncryption(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetConnectionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetConnectionsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetConnectionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9385,10 +9715,10 @@ impl GetCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetCrawlerError::EntityNotFound(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetCrawlerError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9419,7 +9749,10 @@ impl GetCrawlerMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlerMetricsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCrawlerMetricsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9449,7 +9782,7 @@ impl GetCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetCrawlersError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9485,17 +9818,20 @@ impl GetDataCatalogEncryptionSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDataCatalogEncryptionSettingsError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDataCatalogEncryptionSettingsError::InvalidInput(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
                     )
                 }
@@ -9543,19 +9879,19 @@ impl GetDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetDatabaseError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9595,16 +9931,16 @@ impl GetDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetDatabasesError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetDatabasesError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDatabasesError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetDatabasesError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDatabasesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDatabasesError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDatabasesError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetDatabasesError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9641,13 +9977,19 @@ impl GetDataflowGraphError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDataflowGraphError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataflowGraphError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataflowGraphError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDataflowGraphError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDataflowGraphError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataflowGraphError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9685,16 +10027,22 @@ impl GetDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetDevEndpointError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevEndpointError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDevEndpointError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevEndpointError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9733,16 +10081,25 @@ impl GetDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDevEndpointsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevEndpointsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDevEndpointsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevEndpointsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDevEndpointsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDevEndpointsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDevEndpointsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9781,16 +10138,16 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetJobError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetJobError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetJobError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9829,16 +10186,22 @@ impl GetJobBookmarkError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobBookmarkError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobBookmarkError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobBookmarkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobBookmarkError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobBookmarkError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetJobBookmarkError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobBookmarkError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobBookmarkError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9877,16 +10240,16 @@ impl GetJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobRunError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobRunError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetJobRunError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetJobRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetJobRunError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9925,16 +10288,16 @@ impl GetJobRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobRunsError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobRunsError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetJobRunsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetJobRunsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetJobRunsError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9973,16 +10336,16 @@ impl GetJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetJobsError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobsError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetJobsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetJobsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetJobsError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10021,16 +10384,16 @@ impl GetMLTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTaskRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTaskRunError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTaskRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTaskRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10069,16 +10432,19 @@ impl GetMLTaskRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunsError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMLTaskRunsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMLTaskRunsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10117,16 +10483,22 @@ impl GetMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetMLTransformError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMLTransformError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMLTransformError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMLTransformError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10165,16 +10537,25 @@ impl GetMLTransformsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTransformsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMLTransformsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTransformsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMLTransformsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTransformsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMLTransformsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTransformsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMLTransformsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10213,16 +10594,16 @@ impl GetMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMappingError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetMappingError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMappingError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetMappingError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMappingError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMappingError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMappingError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetMappingError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10263,19 +10644,19 @@ impl GetPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetPartitionError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetPartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetPartitionError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetPartitionError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetPartitionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetPartitionError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10317,19 +10698,22 @@ impl GetPartitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetPartitionsError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetPartitionsError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetPartitionsError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionsError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetPartitionsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetPartitionsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPartitionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10367,13 +10751,13 @@ impl GetPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPlanError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetPlanError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPlanError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetPlanError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPlanError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetPlanError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10411,18 +10795,28 @@ impl GetResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10461,16 +10855,28 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetResourcePolicyError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10509,24 +10915,28 @@ impl GetSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10565,24 +10975,28 @@ impl GetSecurityConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationsError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSecurityConfigurationsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10623,19 +11037,19 @@ impl GetTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetTableError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetTableError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTableError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTableError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetTableError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10677,19 +11091,31 @@ impl GetTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableVersionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableVersionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableVersionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTableVersionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableVersionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10731,19 +11157,31 @@ impl GetTableVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableVersionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableVersionsError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionsError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableVersionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTableVersionsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableVersionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTableVersionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10785,19 +11223,19 @@ impl GetTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTablesError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetTablesError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTablesError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, GetTablesError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTablesError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTablesError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTablesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTablesError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTablesError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetTablesError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10837,16 +11275,16 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTagsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetTagsError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagsError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTagsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTagsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTagsError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetTagsError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10885,16 +11323,16 @@ impl GetTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetTriggerError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTriggerError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTriggerError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTriggerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetTriggerError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10933,16 +11371,16 @@ impl GetTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTriggersError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetTriggersError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTriggersError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTriggersError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetTriggersError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetTriggersError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10983,27 +11421,34 @@ impl GetUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11045,29 +11490,34 @@ impl GetUserDefinedFunctionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionsError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionsError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetUserDefinedFunctionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11107,16 +11557,16 @@ impl GetWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetWorkflowError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetWorkflowError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetWorkflowError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, GetWorkflowError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11155,16 +11605,22 @@ impl GetWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, GetWorkflowRunError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetWorkflowRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11203,24 +11659,28 @@ impl GetWorkflowRunPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunPropertiesError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunPropertiesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunPropertiesError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunPropertiesError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11259,16 +11719,25 @@ impl GetWorkflowRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetWorkflowRunsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetWorkflowRunsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11303,12 +11772,16 @@ impl ImportCatalogToGlueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ImportCatalogToGlueError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportCatalogToGlueError::InternalService(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ImportCatalogToGlueError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportCatalogToGlueError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11339,7 +11812,7 @@ impl ListCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, ListCrawlersError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11375,16 +11848,25 @@ impl ListDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListDevEndpointsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevEndpointsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDevEndpointsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevEndpointsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListDevEndpointsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListDevEndpointsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevEndpointsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11423,16 +11905,16 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, ListJobsError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, ListJobsError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11471,16 +11953,25 @@ impl ListMLTransformsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListMLTransformsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMLTransformsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListMLTransformsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMLTransformsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMLTransformsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListMLTransformsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListMLTransformsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMLTransformsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11519,16 +12010,16 @@ impl ListTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListTriggersError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, ListTriggersError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTriggersError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListTriggersError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListTriggersError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, ListTriggersError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11565,13 +12056,16 @@ impl ListWorkflowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListWorkflowsError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListWorkflowsError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListWorkflowsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListWorkflowsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListWorkflowsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListWorkflowsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11609,17 +12103,20 @@ impl PutDataCatalogEncryptionSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDataCatalogEncryptionSettingsError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDataCatalogEncryptionSettingsError::InvalidInput(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
                     )
                 }
@@ -11667,21 +12164,34 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionCheckFailureException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ConditionCheckFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::ConditionCheckFailure(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(PutResourcePolicyError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11727,37 +12237,44 @@ impl PutWorkflowRunPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutWorkflowRunPropertiesError::AlreadyExists(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutWorkflowRunPropertiesError::ConcurrentModification(err.msg),
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutWorkflowRunPropertiesError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutWorkflowRunPropertiesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutWorkflowRunPropertiesError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutWorkflowRunPropertiesError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -11805,16 +12322,25 @@ impl ResetJobBookmarkError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetJobBookmarkError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetJobBookmarkError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ResetJobBookmarkError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetJobBookmarkError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11851,13 +12377,13 @@ impl SearchTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SearchTablesError::InternalService(err.msg))
+                    return RusotoError::service(&res, SearchTablesError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SearchTablesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, SearchTablesError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(SearchTablesError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, SearchTablesError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11893,13 +12419,13 @@ impl StartCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(StartCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service(&res, StartCrawlerError::CrawlerRunning(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, StartCrawlerError::EntityNotFound(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, StartCrawlerError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11939,25 +12465,34 @@ impl StartCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartCrawlerScheduleError::EntityNotFound(err.msg),
+                    )
                 }
                 "NoScheduleException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::NoSchedule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartCrawlerScheduleError::NoSchedule(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCrawlerScheduleError::OperationTimeout(err.msg),
+                    )
                 }
                 "SchedulerRunningException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::SchedulerRunning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCrawlerScheduleError::SchedulerRunning(err.msg),
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::SchedulerTransitioning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCrawlerScheduleError::SchedulerTransitioning(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11997,24 +12532,28 @@ impl StartExportLabelsTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExportLabelsTaskRunError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExportLabelsTaskRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExportLabelsTaskRunError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExportLabelsTaskRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12055,27 +12594,32 @@ impl StartImportLabelsTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImportLabelsTaskRunError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImportLabelsTaskRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImportLabelsTaskRunError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImportLabelsTaskRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartImportLabelsTaskRunError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -12123,24 +12667,28 @@ impl StartJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartJobRunError::ConcurrentRunsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartJobRunError::ConcurrentRunsExceeded(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartJobRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, StartJobRunError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartJobRunError::InternalService(err.msg))
+                    return RusotoError::service(&res, StartJobRunError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StartJobRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartJobRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, StartJobRunError::OperationTimeout(err.msg))
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(StartJobRunError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartJobRunError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12185,34 +12733,40 @@ impl StartMLEvaluationTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLEvaluationTaskRunError::ConcurrentRunsExceeded(err.msg),
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMLEvaluationTaskRunError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMLEvaluationTaskRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMLEvaluationTaskRunError::InvalidInput(err.msg),
+                    )
                 }
                 "MLTransformNotReadyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLEvaluationTaskRunError::MLTransformNotReady(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMLEvaluationTaskRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12259,27 +12813,32 @@ impl StartMLLabelingSetGenerationTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLLabelingSetGenerationTaskRunError::ConcurrentRunsExceeded(err.msg),
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLLabelingSetGenerationTaskRunError::EntityNotFound(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLLabelingSetGenerationTaskRunError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLLabelingSetGenerationTaskRunError::InvalidInput(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMLLabelingSetGenerationTaskRunError::OperationTimeout(err.msg),
                     )
                 }
@@ -12335,24 +12894,28 @@ impl StartTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartTriggerError::ConcurrentRunsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTriggerError::ConcurrentRunsExceeded(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, StartTriggerError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartTriggerError::InternalService(err.msg))
+                    return RusotoError::service(&res, StartTriggerError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StartTriggerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, StartTriggerError::OperationTimeout(err.msg))
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(StartTriggerError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTriggerError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12397,24 +12960,35 @@ impl StartWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartWorkflowRunError::ConcurrentRunsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowRunError::ConcurrentRunsExceeded(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowRunError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StartWorkflowRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartWorkflowRunError::ResourceNumberLimitExceeded(err.msg),
                     )
                 }
@@ -12457,16 +13031,16 @@ impl StopCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerNotRunningException" => {
-                    return RusotoError::Service(StopCrawlerError::CrawlerNotRunning(err.msg))
+                    return RusotoError::service(&res, StopCrawlerError::CrawlerNotRunning(err.msg))
                 }
                 "CrawlerStoppingException" => {
-                    return RusotoError::Service(StopCrawlerError::CrawlerStopping(err.msg))
+                    return RusotoError::service(&res, StopCrawlerError::CrawlerStopping(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, StopCrawlerError::EntityNotFound(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, StopCrawlerError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12505,22 +13079,28 @@ impl StopCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopCrawlerScheduleError::EntityNotFound(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopCrawlerScheduleError::OperationTimeout(err.msg),
+                    )
                 }
                 "SchedulerNotRunningException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::SchedulerNotRunning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopCrawlerScheduleError::SchedulerNotRunning(err.msg),
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::SchedulerTransitioning(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopCrawlerScheduleError::SchedulerTransitioning(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12561,19 +13141,22 @@ impl StopTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopTriggerError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopTriggerError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, StopTriggerError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopTriggerError::InternalService(err.msg))
+                    return RusotoError::service(&res, StopTriggerError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StopTriggerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, StopTriggerError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12615,21 +13198,31 @@ impl StopWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopWorkflowRunError::EntityNotFound(err.msg),
+                    )
                 }
                 "IllegalWorkflowStateException" => {
-                    return RusotoError::Service(StopWorkflowRunError::IllegalWorkflowState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopWorkflowRunError::IllegalWorkflowState(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopWorkflowRunError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StopWorkflowRunError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopWorkflowRunError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12669,16 +13262,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(TagResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, TagResourceError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12717,16 +13310,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UntagResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12765,16 +13361,25 @@ impl UpdateClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClassifierError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateClassifierError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateClassifierError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClassifierError::OperationTimeout(err.msg),
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateClassifierError::VersionMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClassifierError::VersionMismatch(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12817,27 +13422,32 @@ impl UpdateColumnStatisticsForPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForPartitionError::EntityNotFound(err.msg),
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForPartitionError::GlueEncryption(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForPartitionError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForPartitionError::InvalidInput(err.msg),
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForPartitionError::OperationTimeout(err.msg),
                     )
                 }
@@ -12893,27 +13503,32 @@ impl UpdateColumnStatisticsForTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForTableError::EntityNotFound(err.msg),
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForTableError::GlueEncryption(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForTableError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateColumnStatisticsForTableError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateColumnStatisticsForTableError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateColumnStatisticsForTableError::OperationTimeout(err.msg),
                     )
                 }
@@ -12963,16 +13578,25 @@ impl UpdateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConnectionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConnectionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateConnectionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConnectionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13013,19 +13637,22 @@ impl UpdateCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(UpdateCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service(&res, UpdateCrawlerError::CrawlerRunning(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateCrawlerError::EntityNotFound(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCrawlerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateCrawlerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCrawlerError::OperationTimeout(err.msg),
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateCrawlerError::VersionMismatch(err.msg))
+                    return RusotoError::service(&res, UpdateCrawlerError::VersionMismatch(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13067,27 +13694,34 @@ impl UpdateCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCrawlerScheduleError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCrawlerScheduleError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCrawlerScheduleError::OperationTimeout(err.msg),
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCrawlerScheduleError::SchedulerTransitioning(err.msg),
                     )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::VersionMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCrawlerScheduleError::VersionMismatch(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13129,19 +13763,25 @@ impl UpdateDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDatabaseError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, UpdateDatabaseError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateDatabaseError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatabaseError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateDatabaseError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatabaseError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13181,16 +13821,28 @@ impl UpdateDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDevEndpointError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDevEndpointError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDevEndpointError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDevEndpointError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13231,19 +13883,22 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateJobError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateJobError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateJobError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateJobError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::OperationTimeout(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13285,19 +13940,34 @@ impl UpdateMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateMLTransformError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMLTransformError::AccessDenied(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMLTransformError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateMLTransformError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMLTransformError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMLTransformError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMLTransformError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13339,19 +14009,31 @@ impl UpdatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePartitionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePartitionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePartitionError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePartitionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdatePartitionError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdatePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePartitionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13397,27 +14079,31 @@ impl UpdateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTableError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTableError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateTableError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::EntityNotFound(err.msg))
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateTableError::GlueEncryption(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::GlueEncryption(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTableError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateTableError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateTableError::OperationTimeout(err.msg))
+                    return RusotoError::service(&res, UpdateTableError::OperationTimeout(err.msg))
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceNumberLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTableError::ResourceNumberLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13461,21 +14147,25 @@ impl UpdateTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTriggerError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTriggerError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTriggerError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTriggerError::InternalService(err.msg))
+                    return RusotoError::service(&res, UpdateTriggerError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateTriggerError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTriggerError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13517,29 +14207,34 @@ impl UpdateUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserDefinedFunctionError::EntityNotFound(err.msg),
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::GlueEncryption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserDefinedFunctionError::GlueEncryption(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserDefinedFunctionError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserDefinedFunctionError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserDefinedFunctionError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13581,21 +14276,28 @@ impl UpdateWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateWorkflowError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWorkflowError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateWorkflowError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateWorkflowError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateWorkflowError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWorkflowError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateWorkflowError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWorkflowError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14454,6 +15156,7 @@ impl GlueClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -14469,6 +15172,7 @@ impl GlueClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GlueClient {
         GlueClient { client, region }
     }
@@ -14482,6 +15186,9 @@ impl Glue for GlueClient {
         input: BatchCreatePartitionRequest,
     ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchCreatePartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.BatchCreatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14501,6 +15208,9 @@ impl Glue for GlueClient {
         input: BatchDeleteConnectionRequest,
     ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14520,6 +15230,9 @@ impl Glue for GlueClient {
         input: BatchDeletePartitionRequest,
     ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeletePartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.BatchDeletePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14539,6 +15252,9 @@ impl Glue for GlueClient {
         input: BatchDeleteTableRequest,
     ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14557,6 +15273,9 @@ impl Glue for GlueClient {
         input: BatchDeleteTableVersionRequest,
     ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteTableVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14576,6 +15295,9 @@ impl Glue for GlueClient {
         input: BatchGetCrawlersRequest,
     ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetCrawlers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.BatchGetCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14594,6 +15316,9 @@ impl Glue for GlueClient {
         input: BatchGetDevEndpointsRequest,
     ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetDevEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.BatchGetDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14613,6 +15338,9 @@ impl Glue for GlueClient {
         input: BatchGetJobsRequest,
     ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.BatchGetJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14631,6 +15359,9 @@ impl Glue for GlueClient {
         input: BatchGetPartitionRequest,
     ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetPartition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.BatchGetPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14649,6 +15380,9 @@ impl Glue for GlueClient {
         input: BatchGetTriggersRequest,
     ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetTriggers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.BatchGetTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14667,6 +15401,9 @@ impl Glue for GlueClient {
         input: BatchGetWorkflowsRequest,
     ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGetWorkflows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.BatchGetWorkflows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14685,6 +15422,9 @@ impl Glue for GlueClient {
         input: BatchStopJobRunRequest,
     ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchStopJobRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.BatchStopJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14703,6 +15443,9 @@ impl Glue for GlueClient {
         input: CancelMLTaskRunRequest,
     ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelMLTaskRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CancelMLTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14721,6 +15464,9 @@ impl Glue for GlueClient {
         input: CreateClassifierRequest,
     ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateClassifier");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14739,6 +15485,9 @@ impl Glue for GlueClient {
         input: CreateConnectionRequest,
     ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14757,6 +15506,9 @@ impl Glue for GlueClient {
         input: CreateCrawlerRequest,
     ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCrawler");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14775,6 +15527,9 @@ impl Glue for GlueClient {
         input: CreateDatabaseRequest,
     ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14793,6 +15548,9 @@ impl Glue for GlueClient {
         input: CreateDevEndpointRequest,
     ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDevEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14811,6 +15569,9 @@ impl Glue for GlueClient {
         input: CreateJobRequest,
     ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14829,6 +15590,9 @@ impl Glue for GlueClient {
         input: CreateMLTransformRequest,
     ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMLTransform");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14847,6 +15611,9 @@ impl Glue for GlueClient {
         input: CreatePartitionRequest,
     ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14865,6 +15632,9 @@ impl Glue for GlueClient {
         input: CreateScriptRequest,
     ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateScript");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14884,6 +15654,9 @@ impl Glue for GlueClient {
     ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSecurityConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14903,6 +15676,9 @@ impl Glue for GlueClient {
         input: CreateTableRequest,
     ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14921,6 +15697,9 @@ impl Glue for GlueClient {
         input: CreateTriggerRequest,
     ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTrigger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14940,6 +15719,9 @@ impl Glue for GlueClient {
     ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserDefinedFunction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14959,6 +15741,9 @@ impl Glue for GlueClient {
         input: CreateWorkflowRequest,
     ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWorkflow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.CreateWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14977,6 +15762,9 @@ impl Glue for GlueClient {
         input: DeleteClassifierRequest,
     ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteClassifier");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14998,6 +15786,9 @@ impl Glue for GlueClient {
         RusotoError<DeleteColumnStatisticsForPartitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteColumnStatisticsForPartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteColumnStatisticsForPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15023,6 +15814,9 @@ impl Glue for GlueClient {
         RusotoError<DeleteColumnStatisticsForTableError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteColumnStatisticsForTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteColumnStatisticsForTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15042,6 +15836,9 @@ impl Glue for GlueClient {
         input: DeleteConnectionRequest,
     ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15060,6 +15857,9 @@ impl Glue for GlueClient {
         input: DeleteCrawlerRequest,
     ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCrawler");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15078,6 +15878,9 @@ impl Glue for GlueClient {
         input: DeleteDatabaseRequest,
     ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15096,6 +15899,9 @@ impl Glue for GlueClient {
         input: DeleteDevEndpointRequest,
     ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDevEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15114,6 +15920,9 @@ impl Glue for GlueClient {
         input: DeleteJobRequest,
     ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15132,6 +15941,9 @@ impl Glue for GlueClient {
         input: DeleteMLTransformRequest,
     ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMLTransform");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15150,6 +15962,9 @@ impl Glue for GlueClient {
         input: DeletePartitionRequest,
     ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeletePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15168,6 +15983,9 @@ impl Glue for GlueClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15188,6 +16006,9 @@ impl Glue for GlueClient {
     ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSecurityConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15207,6 +16028,9 @@ impl Glue for GlueClient {
         input: DeleteTableRequest,
     ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15225,6 +16049,9 @@ impl Glue for GlueClient {
         input: DeleteTableVersionRequest,
     ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTableVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15243,6 +16070,9 @@ impl Glue for GlueClient {
         input: DeleteTriggerRequest,
     ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTrigger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15262,6 +16092,9 @@ impl Glue for GlueClient {
     ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserDefinedFunction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15281,6 +16114,9 @@ impl Glue for GlueClient {
         input: DeleteWorkflowRequest,
     ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWorkflow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.DeleteWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15299,6 +16135,9 @@ impl Glue for GlueClient {
         input: GetCatalogImportStatusRequest,
     ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCatalogImportStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetCatalogImportStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15318,6 +16157,9 @@ impl Glue for GlueClient {
         input: GetClassifierRequest,
     ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetClassifier");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15336,6 +16178,9 @@ impl Glue for GlueClient {
         input: GetClassifiersRequest,
     ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetClassifiers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetClassifiers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15357,6 +16202,9 @@ impl Glue for GlueClient {
         RusotoError<GetColumnStatisticsForPartitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetColumnStatisticsForPartition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetColumnStatisticsForPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15377,6 +16225,9 @@ impl Glue for GlueClient {
     ) -> Result<GetColumnStatisticsForTableResponse, RusotoError<GetColumnStatisticsForTableError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetColumnStatisticsForTable");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetColumnStatisticsForTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15396,6 +16247,9 @@ impl Glue for GlueClient {
         input: GetConnectionRequest,
     ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConnection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15414,6 +16268,9 @@ impl Glue for GlueClient {
         input: GetConnectionsRequest,
     ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConnections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15432,6 +16289,9 @@ impl Glue for GlueClient {
         input: GetCrawlerRequest,
     ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCrawler");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15450,6 +16310,9 @@ impl Glue for GlueClient {
         input: GetCrawlerMetricsRequest,
     ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCrawlerMetrics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetCrawlerMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15468,6 +16331,9 @@ impl Glue for GlueClient {
         input: GetCrawlersRequest,
     ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCrawlers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15489,6 +16355,9 @@ impl Glue for GlueClient {
         RusotoError<GetDataCatalogEncryptionSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDataCatalogEncryptionSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15511,6 +16380,9 @@ impl Glue for GlueClient {
         input: GetDatabaseRequest,
     ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDatabase");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15529,6 +16401,9 @@ impl Glue for GlueClient {
         input: GetDatabasesRequest,
     ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDatabases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15547,6 +16422,9 @@ impl Glue for GlueClient {
         input: GetDataflowGraphRequest,
     ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDataflowGraph");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetDataflowGraph");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15565,6 +16443,9 @@ impl Glue for GlueClient {
         input: GetDevEndpointRequest,
     ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15583,6 +16464,9 @@ impl Glue for GlueClient {
         input: GetDevEndpointsRequest,
     ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDevEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15601,6 +16485,9 @@ impl Glue for GlueClient {
         input: GetJobRequest,
     ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15619,6 +16506,9 @@ impl Glue for GlueClient {
         input: GetJobBookmarkRequest,
     ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobBookmark");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetJobBookmark");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15637,6 +16527,9 @@ impl Glue for GlueClient {
         input: GetJobRunRequest,
     ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobRun");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15655,6 +16548,9 @@ impl Glue for GlueClient {
         input: GetJobRunsRequest,
     ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetJobRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15673,6 +16569,9 @@ impl Glue for GlueClient {
         input: GetJobsRequest,
     ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15691,6 +16590,9 @@ impl Glue for GlueClient {
         input: GetMLTaskRunRequest,
     ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMLTaskRun");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15709,6 +16611,9 @@ impl Glue for GlueClient {
         input: GetMLTaskRunsRequest,
     ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMLTaskRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15727,6 +16632,9 @@ impl Glue for GlueClient {
         input: GetMLTransformRequest,
     ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMLTransform");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15745,6 +16653,9 @@ impl Glue for GlueClient {
         input: GetMLTransformsRequest,
     ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMLTransforms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetMLTransforms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15763,6 +16674,9 @@ impl Glue for GlueClient {
         input: GetMappingRequest,
     ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMapping");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetMapping");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15781,6 +16695,9 @@ impl Glue for GlueClient {
         input: GetPartitionRequest,
     ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPartition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15799,6 +16716,9 @@ impl Glue for GlueClient {
         input: GetPartitionsRequest,
     ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPartitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetPartitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15817,6 +16737,9 @@ impl Glue for GlueClient {
         input: GetPlanRequest,
     ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPlan");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetPlan");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15835,6 +16758,9 @@ impl Glue for GlueClient {
         input: GetResourcePoliciesRequest,
     ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResourcePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetResourcePolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15853,6 +16779,9 @@ impl Glue for GlueClient {
         input: GetResourcePolicyRequest,
     ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResourcePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15871,6 +16800,9 @@ impl Glue for GlueClient {
         input: GetSecurityConfigurationRequest,
     ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSecurityConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15891,6 +16823,9 @@ impl Glue for GlueClient {
     ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSecurityConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15910,6 +16845,9 @@ impl Glue for GlueClient {
         input: GetTableRequest,
     ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTable");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15928,6 +16866,9 @@ impl Glue for GlueClient {
         input: GetTableVersionRequest,
     ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTableVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15946,6 +16887,9 @@ impl Glue for GlueClient {
         input: GetTableVersionsRequest,
     ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTableVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTableVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15964,6 +16908,9 @@ impl Glue for GlueClient {
         input: GetTablesRequest,
     ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15982,6 +16929,9 @@ impl Glue for GlueClient {
         input: GetTagsRequest,
     ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16000,6 +16950,9 @@ impl Glue for GlueClient {
         input: GetTriggerRequest,
     ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTrigger");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16018,6 +16971,9 @@ impl Glue for GlueClient {
         input: GetTriggersRequest,
     ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTriggers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16036,6 +16992,9 @@ impl Glue for GlueClient {
         input: GetUserDefinedFunctionRequest,
     ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUserDefinedFunction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16055,6 +17014,9 @@ impl Glue for GlueClient {
         input: GetUserDefinedFunctionsRequest,
     ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUserDefinedFunctions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunctions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16074,6 +17036,9 @@ impl Glue for GlueClient {
         input: GetWorkflowRequest,
     ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWorkflow");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16092,6 +17057,9 @@ impl Glue for GlueClient {
         input: GetWorkflowRunRequest,
     ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWorkflowRun");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16110,6 +17078,9 @@ impl Glue for GlueClient {
         input: GetWorkflowRunPropertiesRequest,
     ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWorkflowRunProperties");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRunProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16129,6 +17100,9 @@ impl Glue for GlueClient {
         input: GetWorkflowRunsRequest,
     ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWorkflowRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16147,6 +17121,9 @@ impl Glue for GlueClient {
         input: ImportCatalogToGlueRequest,
     ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportCatalogToGlue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.ImportCatalogToGlue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16165,6 +17142,9 @@ impl Glue for GlueClient {
         input: ListCrawlersRequest,
     ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCrawlers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.ListCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16183,6 +17163,9 @@ impl Glue for GlueClient {
         input: ListDevEndpointsRequest,
     ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDevEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.ListDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16201,6 +17184,9 @@ impl Glue for GlueClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16219,6 +17205,9 @@ impl Glue for GlueClient {
         input: ListMLTransformsRequest,
     ) -> Result<ListMLTransformsResponse, RusotoError<ListMLTransformsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMLTransforms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.ListMLTransforms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16237,6 +17226,9 @@ impl Glue for GlueClient {
         input: ListTriggersRequest,
     ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTriggers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.ListTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16255,6 +17247,9 @@ impl Glue for GlueClient {
         input: ListWorkflowsRequest,
     ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWorkflows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.ListWorkflows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16276,6 +17271,9 @@ impl Glue for GlueClient {
         RusotoError<PutDataCatalogEncryptionSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutDataCatalogEncryptionSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.PutDataCatalogEncryptionSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16298,6 +17296,9 @@ impl Glue for GlueClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16316,6 +17317,9 @@ impl Glue for GlueClient {
         input: PutWorkflowRunPropertiesRequest,
     ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutWorkflowRunProperties");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.PutWorkflowRunProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16335,6 +17339,9 @@ impl Glue for GlueClient {
         input: ResetJobBookmarkRequest,
     ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResetJobBookmark");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.ResetJobBookmark");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16353,6 +17360,9 @@ impl Glue for GlueClient {
         input: SearchTablesRequest,
     ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchTables");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSGlue.SearchTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16371,6 +17381,9 @@ impl Glue for GlueClient {
         input: StartCrawlerRequest,
     ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartCrawler");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16389,6 +17402,9 @@ impl Glue for GlueClient {
         input: StartCrawlerScheduleRequest,
     ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartCrawlerSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16408,6 +17424,9 @@ impl Glue for GlueClient {
         input: StartExportLabelsTaskRunRequest,
     ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartExportLabelsTaskRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartExportLabelsTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16427,6 +17446,9 @@ impl Glue for GlueClient {
         input: StartImportLabelsTaskRunRequest,
     ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartImportLabelsTaskRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartImportLabelsTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16446,6 +17468,9 @@ impl Glue for GlueClient {
         input: StartJobRunRequest,
     ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartJobRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16464,6 +17489,9 @@ impl Glue for GlueClient {
         input: StartMLEvaluationTaskRunRequest,
     ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMLEvaluationTaskRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartMLEvaluationTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16486,6 +17514,9 @@ impl Glue for GlueClient {
         RusotoError<StartMLLabelingSetGenerationTaskRunError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMLLabelingSetGenerationTaskRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSGlue.StartMLLabelingSetGenerationTaskRun",
@@ -16511,6 +17542,9 @@ impl Glue for GlueClient {
         input: StartTriggerRequest,
     ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTrigger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16529,6 +17563,9 @@ impl Glue for GlueClient {
         input: StartWorkflowRunRequest,
     ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartWorkflowRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StartWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16547,6 +17584,9 @@ impl Glue for GlueClient {
         input: StopCrawlerRequest,
     ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopCrawler");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StopCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16565,6 +17605,9 @@ impl Glue for GlueClient {
         input: StopCrawlerScheduleRequest,
     ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopCrawlerSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StopCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16583,6 +17626,9 @@ impl Glue for GlueClient {
         input: StopTriggerRequest,
     ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTrigger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StopTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16601,6 +17647,9 @@ impl Glue for GlueClient {
         input: StopWorkflowRunRequest,
     ) -> Result<StopWorkflowRunResponse, RusotoError<StopWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopWorkflowRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.StopWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16619,6 +17668,9 @@ impl Glue for GlueClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16637,6 +17689,9 @@ impl Glue for GlueClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16655,6 +17710,9 @@ impl Glue for GlueClient {
         input: UpdateClassifierRequest,
     ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateClassifier");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16676,6 +17734,9 @@ impl Glue for GlueClient {
         RusotoError<UpdateColumnStatisticsForPartitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateColumnStatisticsForPartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateColumnStatisticsForPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16701,6 +17762,9 @@ impl Glue for GlueClient {
         RusotoError<UpdateColumnStatisticsForTableError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateColumnStatisticsForTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateColumnStatisticsForTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16720,6 +17784,9 @@ impl Glue for GlueClient {
         input: UpdateConnectionRequest,
     ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateConnection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16738,6 +17805,9 @@ impl Glue for GlueClient {
         input: UpdateCrawlerRequest,
     ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCrawler");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16756,6 +17826,9 @@ impl Glue for GlueClient {
         input: UpdateCrawlerScheduleRequest,
     ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCrawlerSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16775,6 +17848,9 @@ impl Glue for GlueClient {
         input: UpdateDatabaseRequest,
     ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16793,6 +17869,9 @@ impl Glue for GlueClient {
         input: UpdateDevEndpointRequest,
     ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDevEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16811,6 +17890,9 @@ impl Glue for GlueClient {
         input: UpdateJobRequest,
     ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16829,6 +17911,9 @@ impl Glue for GlueClient {
         input: UpdateMLTransformRequest,
     ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMLTransform");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16847,6 +17932,9 @@ impl Glue for GlueClient {
         input: UpdatePartitionRequest,
     ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePartition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16865,6 +17953,9 @@ impl Glue for GlueClient {
         input: UpdateTableRequest,
     ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTable");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16883,6 +17974,9 @@ impl Glue for GlueClient {
         input: UpdateTriggerRequest,
     ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTrigger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16902,6 +17996,9 @@ impl Glue for GlueClient {
     ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserDefinedFunction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16921,6 +18018,9 @@ impl Glue for GlueClient {
         input: UpdateWorkflowRequest,
     ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateWorkflow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSGlue.UpdateWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16933,3 +18033,1451 @@ impl Glue for GlueClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkflowResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Glue API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GlueClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GlueBlockingClient {
+    client: GlueClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GlueBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GlueBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GlueBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlueBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GlueBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> GlueBlockingClient {
+        GlueBlockingClient {
+            client: GlueClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates one or more partitions in a batch operation.</p>
+    ///
+    /// See `Glue::batch_create_partition`.
+    pub fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_create_partition(input))
+    }
+
+    /// <p>Deletes a list of connection definitions from the Data Catalog.</p>
+    ///
+    /// See `Glue::batch_delete_connection`.
+    pub fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_connection(input))
+    }
+
+    /// <p>Deletes one or more partitions in a batch operation.</p>
+    ///
+    /// See `Glue::batch_delete_partition`.
+    pub fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_partition(input))
+    }
+
+    /// <p><p>Deletes multiple tables at once.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>BatchDeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
+    ///
+    /// See `Glue::batch_delete_table`.
+    pub fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
+        self.runtime.block_on(self.client.batch_delete_table(input))
+    }
+
+    /// <p>Deletes a specified batch of versions of a table.</p>
+    ///
+    /// See `Glue::batch_delete_table_version`.
+    pub fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_table_version(input))
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of crawler names. After calling the <code>ListCrawlers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    ///
+    /// See `Glue::batch_get_crawlers`.
+    pub fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
+        self.runtime.block_on(self.client.batch_get_crawlers(input))
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of development endpoint names. After calling the <code>ListDevEndpoints</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    ///
+    /// See `Glue::batch_get_dev_endpoints`.
+    pub fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_dev_endpoints(input))
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of job names. After calling the <code>ListJobs</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags. </p>
+    ///
+    /// See `Glue::batch_get_jobs`.
+    pub fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
+        self.runtime.block_on(self.client.batch_get_jobs(input))
+    }
+
+    /// <p>Retrieves partitions in a batch request.</p>
+    ///
+    /// See `Glue::batch_get_partition`.
+    pub fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_get_partition(input))
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of trigger names. After calling the <code>ListTriggers</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    ///
+    /// See `Glue::batch_get_triggers`.
+    pub fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
+        self.runtime.block_on(self.client.batch_get_triggers(input))
+    }
+
+    /// <p>Returns a list of resource metadata for a given list of workflow names. After calling the <code>ListWorkflows</code> operation, you can call this operation to access the data to which you have been granted permissions. This operation supports all IAM permissions, including permission conditions that uses tags.</p>
+    ///
+    /// See `Glue::batch_get_workflows`.
+    pub fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_workflows(input))
+    }
+
+    /// <p>Stops one or more job runs for a specified job definition.</p>
+    ///
+    /// See `Glue::batch_stop_job_run`.
+    pub fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
+        self.runtime.block_on(self.client.batch_stop_job_run(input))
+    }
+
+    /// <p>Cancels (stops) a task run. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can cancel a machine learning task run at any time by calling <code>CancelMLTaskRun</code> with a task run's parent transform's <code>TransformID</code> and the task run's <code>TaskRunId</code>. </p>
+    ///
+    /// See `Glue::cancel_ml_task_run`.
+    pub fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
+        self.runtime.block_on(self.client.cancel_ml_task_run(input))
+    }
+
+    /// <p>Creates a classifier in the user's account. This can be a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field of the request is present.</p>
+    ///
+    /// See `Glue::create_classifier`.
+    pub fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
+        self.runtime.block_on(self.client.create_classifier(input))
+    }
+
+    /// <p>Creates a connection definition in the Data Catalog.</p>
+    ///
+    /// See `Glue::create_connection`.
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// <p>Creates a new crawler with specified targets, role, configuration, and optional schedule. At least one crawl target must be specified, in the <code>s3Targets</code> field, the <code>jdbcTargets</code> field, or the <code>DynamoDBTargets</code> field.</p>
+    ///
+    /// See `Glue::create_crawler`.
+    pub fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
+        self.runtime.block_on(self.client.create_crawler(input))
+    }
+
+    /// <p>Creates a new database in a Data Catalog.</p>
+    ///
+    /// See `Glue::create_database`.
+    pub fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
+        self.runtime.block_on(self.client.create_database(input))
+    }
+
+    /// <p>Creates a new development endpoint.</p>
+    ///
+    /// See `Glue::create_dev_endpoint`.
+    pub fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_dev_endpoint(input))
+    }
+
+    /// <p>Creates a new job definition.</p>
+    ///
+    /// See `Glue::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>Creates an AWS Glue machine learning transform. This operation creates the transform and all the necessary parameters to train it.</p> <p>Call this operation as the first step in the process of using a machine learning transform (such as the <code>FindMatches</code> transform) for deduplicating data. You can provide an optional <code>Description</code>, in addition to the parameters that you want to use for your algorithm.</p> <p>You must also specify certain parameters for the tasks that AWS Glue runs on your behalf as part of learning from your data and creating a high-quality machine learning transform. These parameters include <code>Role</code>, and optionally, <code>AllocatedCapacity</code>, <code>Timeout</code>, and <code>MaxRetries</code>. For more information, see <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-jobs-job.html">Jobs</a>.</p>
+    ///
+    /// See `Glue::create_ml_transform`.
+    pub fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
+        self.runtime
+            .block_on(self.client.create_ml_transform(input))
+    }
+
+    /// <p>Creates a new partition.</p>
+    ///
+    /// See `Glue::create_partition`.
+    pub fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
+        self.runtime.block_on(self.client.create_partition(input))
+    }
+
+    /// <p>Transforms a directed acyclic graph (DAG) into code.</p>
+    ///
+    /// See `Glue::create_script`.
+    pub fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
+        self.runtime.block_on(self.client.create_script(input))
+    }
+
+    /// <p>Creates a new security configuration. A security configuration is a set of security properties that can be used by AWS Glue. You can use a security configuration to encrypt data at rest. For information about using security configurations in AWS Glue, see <a href="https://docs.aws.amazon.com/glue/latest/dg/encryption-security-configuration.html">Encrypting Data Written by Crawlers, Jobs, and Development Endpoints</a>.</p>
+    ///
+    /// See `Glue::create_security_configuration`.
+    pub fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_security_configuration(input))
+    }
+
+    /// <p>Creates a new table definition in the Data Catalog.</p>
+    ///
+    /// See `Glue::create_table`.
+    pub fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
+        self.runtime.block_on(self.client.create_table(input))
+    }
+
+    /// <p>Creates a new trigger.</p>
+    ///
+    /// See `Glue::create_trigger`.
+    pub fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
+        self.runtime.block_on(self.client.create_trigger(input))
+    }
+
+    /// <p>Creates a new function definition in the Data Catalog.</p>
+    ///
+    /// See `Glue::create_user_defined_function`.
+    pub fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_user_defined_function(input))
+    }
+
+    /// <p>Creates a new workflow.</p>
+    ///
+    /// See `Glue::create_workflow`.
+    pub fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
+        self.runtime.block_on(self.client.create_workflow(input))
+    }
+
+    /// <p>Removes a classifier from the Data Catalog.</p>
+    ///
+    /// See `Glue::delete_classifier`.
+    pub fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
+        self.runtime.block_on(self.client.delete_classifier(input))
+    }
+
+    /// <p>Delete the partition column statistics of a column.</p>
+    ///
+    /// See `Glue::delete_column_statistics_for_partition`.
+    pub fn delete_column_statistics_for_partition(
+        &self,
+        input: DeleteColumnStatisticsForPartitionRequest,
+    ) -> Result<
+        DeleteColumnStatisticsForPartitionResponse,
+        RusotoError<DeleteColumnStatisticsForPartitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_column_statistics_for_partition(input))
+    }
+
+    /// <p>Retrieves table statistics of columns.</p>
+    ///
+    /// See `Glue::delete_column_statistics_for_table`.
+    pub fn delete_column_statistics_for_table(
+        &self,
+        input: DeleteColumnStatisticsForTableRequest,
+    ) -> Result<
+        DeleteColumnStatisticsForTableResponse,
+        RusotoError<DeleteColumnStatisticsForTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_column_statistics_for_table(input))
+    }
+
+    /// <p>Deletes a connection from the Data Catalog.</p>
+    ///
+    /// See `Glue::delete_connection`.
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// <p>Removes a specified crawler from the AWS Glue Data Catalog, unless the crawler state is <code>RUNNING</code>.</p>
+    ///
+    /// See `Glue::delete_crawler`.
+    pub fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
+        self.runtime.block_on(self.client.delete_crawler(input))
+    }
+
+    /// <p><p>Removes a specified database from a Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the tables (and all table versions and partitions that might belong to the tables) and the user-defined functions in the deleted database. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteDatabase</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, <code>DeletePartition</code> or <code>BatchDeletePartition</code>, <code>DeleteUserDefinedFunction</code>, and <code>DeleteTable</code> or <code>BatchDeleteTable</code>, to delete any resources that belong to the database.</p> </note></p>
+    ///
+    /// See `Glue::delete_database`.
+    pub fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
+        self.runtime.block_on(self.client.delete_database(input))
+    }
+
+    /// <p>Deletes a specified development endpoint.</p>
+    ///
+    /// See `Glue::delete_dev_endpoint`.
+    pub fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_dev_endpoint(input))
+    }
+
+    /// <p>Deletes a specified job definition. If the job definition is not found, no exception is thrown.</p>
+    ///
+    /// See `Glue::delete_job`.
+    pub fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
+        self.runtime.block_on(self.client.delete_job(input))
+    }
+
+    /// <p>Deletes an AWS Glue machine learning transform. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. If you no longer need a transform, you can delete it by calling <code>DeleteMLTransforms</code>. However, any AWS Glue jobs that still reference the deleted transform will no longer succeed.</p>
+    ///
+    /// See `Glue::delete_ml_transform`.
+    pub fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
+        self.runtime
+            .block_on(self.client.delete_ml_transform(input))
+    }
+
+    /// <p>Deletes a specified partition.</p>
+    ///
+    /// See `Glue::delete_partition`.
+    pub fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
+        self.runtime.block_on(self.client.delete_partition(input))
+    }
+
+    /// <p>Deletes a specified policy.</p>
+    ///
+    /// See `Glue::delete_resource_policy`.
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// <p>Deletes a specified security configuration.</p>
+    ///
+    /// See `Glue::delete_security_configuration`.
+    pub fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_security_configuration(input))
+    }
+
+    /// <p><p>Removes a table definition from the Data Catalog.</p> <note> <p>After completing this operation, you no longer have access to the table versions and partitions that belong to the deleted table. AWS Glue deletes these &quot;orphaned&quot; resources asynchronously in a timely manner, at the discretion of the service.</p> <p>To ensure the immediate deletion of all related resources, before calling <code>DeleteTable</code>, use <code>DeleteTableVersion</code> or <code>BatchDeleteTableVersion</code>, and <code>DeletePartition</code> or <code>BatchDeletePartition</code>, to delete any resources that belong to the table.</p> </note></p>
+    ///
+    /// See `Glue::delete_table`.
+    pub fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
+        self.runtime.block_on(self.client.delete_table(input))
+    }
+
+    /// <p>Deletes a specified version of a table.</p>
+    ///
+    /// See `Glue::delete_table_version`.
+    pub fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_table_version(input))
+    }
+
+    /// <p>Deletes a specified trigger. If the trigger is not found, no exception is thrown.</p>
+    ///
+    /// See `Glue::delete_trigger`.
+    pub fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
+        self.runtime.block_on(self.client.delete_trigger(input))
+    }
+
+    /// <p>Deletes an existing function definition from the Data Catalog.</p>
+    ///
+    /// See `Glue::delete_user_defined_function`.
+    pub fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_user_defined_function(input))
+    }
+
+    /// <p>Deletes a workflow.</p>
+    ///
+    /// See `Glue::delete_workflow`.
+    pub fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
+        self.runtime.block_on(self.client.delete_workflow(input))
+    }
+
+    /// <p>Retrieves the status of a migration operation.</p>
+    ///
+    /// See `Glue::get_catalog_import_status`.
+    pub fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
+        self.runtime
+            .block_on(self.client.get_catalog_import_status(input))
+    }
+
+    /// <p>Retrieve a classifier by name.</p>
+    ///
+    /// See `Glue::get_classifier`.
+    pub fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
+        self.runtime.block_on(self.client.get_classifier(input))
+    }
+
+    /// <p>Lists all classifier objects in the Data Catalog.</p>
+    ///
+    /// See `Glue::get_classifiers`.
+    pub fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
+        self.runtime.block_on(self.client.get_classifiers(input))
+    }
+
+    /// <p>Retrieves partition statistics of columns.</p>
+    ///
+    /// See `Glue::get_column_statistics_for_partition`.
+    pub fn get_column_statistics_for_partition(
+        &self,
+        input: GetColumnStatisticsForPartitionRequest,
+    ) -> Result<
+        GetColumnStatisticsForPartitionResponse,
+        RusotoError<GetColumnStatisticsForPartitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_column_statistics_for_partition(input))
+    }
+
+    /// <p>Retrieves table statistics of columns.</p>
+    ///
+    /// See `Glue::get_column_statistics_for_table`.
+    pub fn get_column_statistics_for_table(
+        &self,
+        input: GetColumnStatisticsForTableRequest,
+    ) -> Result<GetColumnStatisticsForTableResponse, RusotoError<GetColumnStatisticsForTableError>>
+    {
+        self.runtime
+            .block_on(self.client.get_column_statistics_for_table(input))
+    }
+
+    /// <p>Retrieves a connection definition from the Data Catalog.</p>
+    ///
+    /// See `Glue::get_connection`.
+    pub fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        self.runtime.block_on(self.client.get_connection(input))
+    }
+
+    /// <p>Retrieves a list of connection definitions from the Data Catalog.</p>
+    ///
+    /// See `Glue::get_connections`.
+    pub fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
+        self.runtime.block_on(self.client.get_connections(input))
+    }
+
+    /// <p>Retrieves metadata for a specified crawler.</p>
+    ///
+    /// See `Glue::get_crawler`.
+    pub fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
+        self.runtime.block_on(self.client.get_crawler(input))
+    }
+
+    /// <p>Retrieves metrics about specified crawlers.</p>
+    ///
+    /// See `Glue::get_crawler_metrics`.
+    pub fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_crawler_metrics(input))
+    }
+
+    /// <p>Retrieves metadata for all crawlers defined in the customer account.</p>
+    ///
+    /// See `Glue::get_crawlers`.
+    pub fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
+        self.runtime.block_on(self.client.get_crawlers(input))
+    }
+
+    /// <p>Retrieves the security configuration for a specified catalog.</p>
+    ///
+    /// See `Glue::get_data_catalog_encryption_settings`.
+    pub fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        GetDataCatalogEncryptionSettingsResponse,
+        RusotoError<GetDataCatalogEncryptionSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_data_catalog_encryption_settings(input))
+    }
+
+    /// <p>Retrieves the definition of a specified database.</p>
+    ///
+    /// See `Glue::get_database`.
+    pub fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
+        self.runtime.block_on(self.client.get_database(input))
+    }
+
+    /// <p>Retrieves all databases defined in a given Data Catalog.</p>
+    ///
+    /// See `Glue::get_databases`.
+    pub fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
+        self.runtime.block_on(self.client.get_databases(input))
+    }
+
+    /// <p>Transforms a Python script into a directed acyclic graph (DAG). </p>
+    ///
+    /// See `Glue::get_dataflow_graph`.
+    pub fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
+        self.runtime.block_on(self.client.get_dataflow_graph(input))
+    }
+
+    /// <p><p>Retrieves information about a specified development endpoint.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address, and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
+    ///
+    /// See `Glue::get_dev_endpoint`.
+    pub fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
+        self.runtime.block_on(self.client.get_dev_endpoint(input))
+    }
+
+    /// <p><p>Retrieves all the development endpoints in this AWS account.</p> <note> <p>When you create a development endpoint in a virtual private cloud (VPC), AWS Glue returns only a private IP address and the public IP address field is not populated. When you create a non-VPC development endpoint, AWS Glue returns only a public IP address.</p> </note></p>
+    ///
+    /// See `Glue::get_dev_endpoints`.
+    pub fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
+        self.runtime.block_on(self.client.get_dev_endpoints(input))
+    }
+
+    /// <p>Retrieves an existing job definition.</p>
+    ///
+    /// See `Glue::get_job`.
+    pub fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// <p>Returns information on a job bookmark entry.</p>
+    ///
+    /// See `Glue::get_job_bookmark`.
+    pub fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
+        self.runtime.block_on(self.client.get_job_bookmark(input))
+    }
+
+    /// <p>Retrieves the metadata for a given job run.</p>
+    ///
+    /// See `Glue::get_job_run`.
+    pub fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
+        self.runtime.block_on(self.client.get_job_run(input))
+    }
+
+    /// <p>Retrieves metadata for all runs of a given job definition.</p>
+    ///
+    /// See `Glue::get_job_runs`.
+    pub fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
+        self.runtime.block_on(self.client.get_job_runs(input))
+    }
+
+    /// <p>Retrieves all current job definitions.</p>
+    ///
+    /// See `Glue::get_jobs`.
+    pub fn get_jobs(
+        &self,
+        input: GetJobsRequest,
+    ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
+        self.runtime.block_on(self.client.get_jobs(input))
+    }
+
+    /// <p>Gets details for a specific task run on a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can check the stats of any task run by calling <code>GetMLTaskRun</code> with the <code>TaskRunID</code> and its parent transform's <code>TransformID</code>.</p>
+    ///
+    /// See `Glue::get_ml_task_run`.
+    pub fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
+        self.runtime.block_on(self.client.get_ml_task_run(input))
+    }
+
+    /// <p>Gets a list of runs for a machine learning transform. Machine learning task runs are asynchronous tasks that AWS Glue runs on your behalf as part of various machine learning workflows. You can get a sortable, filterable list of machine learning task runs by calling <code>GetMLTaskRuns</code> with their parent transform's <code>TransformID</code> and other optional parameters as documented in this section.</p> <p>This operation returns a list of historic runs and must be paginated.</p>
+    ///
+    /// See `Glue::get_ml_task_runs`.
+    pub fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
+        self.runtime.block_on(self.client.get_ml_task_runs(input))
+    }
+
+    /// <p>Gets an AWS Glue machine learning transform artifact and all its corresponding metadata. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue. You can retrieve their metadata by calling <code>GetMLTransform</code>.</p>
+    ///
+    /// See `Glue::get_ml_transform`.
+    pub fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
+        self.runtime.block_on(self.client.get_ml_transform(input))
+    }
+
+    /// <p>Gets a sortable, filterable list of existing AWS Glue machine learning transforms. Machine learning transforms are a special type of transform that use machine learning to learn the details of the transformation to be performed by learning from examples provided by humans. These transformations are then saved by AWS Glue, and you can retrieve their metadata by calling <code>GetMLTransforms</code>.</p>
+    ///
+    /// See `Glue::get_ml_transforms`.
+    pub fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
+        self.runtime.block_on(self.client.get_ml_transforms(input))
+    }
+
+    /// <p>Creates mappings.</p>
+    ///
+    /// See `Glue::get_mapping`.
+    pub fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
+        self.runtime.block_on(self.client.get_mapping(input))
+    }
+
+    /// <p>Retrieves information about a specified partition.</p>
+    ///
+    /// See `Glue::get_partition`.
+    pub fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
+        self.runtime.block_on(self.client.get_partition(input))
+    }
+
+    /// <p>Retrieves information about the partitions in a table.</p>
+    ///
+    /// See `Glue::get_partitions`.
+    pub fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
+        self.runtime.block_on(self.client.get_partitions(input))
+    }
+
+    /// <p>Gets code to perform a specified mapping.</p>
+    ///
+    /// See `Glue::get_plan`.
+    pub fn get_plan(
+        &self,
+        input: GetPlanRequest,
+    ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
+        self.runtime.block_on(self.client.get_plan(input))
+    }
+
+    /// <p>Retrieves the security configurations for the resource policies set on individual resources, and also the account-level policy.</p>
+    ///
+    /// See `Glue::get_resource_policies`.
+    pub fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policies(input))
+    }
+
+    /// <p>Retrieves a specified resource policy.</p>
+    ///
+    /// See `Glue::get_resource_policy`.
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// <p>Retrieves a specified security configuration.</p>
+    ///
+    /// See `Glue::get_security_configuration`.
+    pub fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_security_configuration(input))
+    }
+
+    /// <p>Retrieves a list of all security configurations.</p>
+    ///
+    /// See `Glue::get_security_configurations`.
+    pub fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_security_configurations(input))
+    }
+
+    /// <p>Retrieves the <code>Table</code> definition in a Data Catalog for a specified table.</p>
+    ///
+    /// See `Glue::get_table`.
+    pub fn get_table(
+        &self,
+        input: GetTableRequest,
+    ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
+        self.runtime.block_on(self.client.get_table(input))
+    }
+
+    /// <p>Retrieves a specified version of a table.</p>
+    ///
+    /// See `Glue::get_table_version`.
+    pub fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
+        self.runtime.block_on(self.client.get_table_version(input))
+    }
+
+    /// <p>Retrieves a list of strings that identify available versions of a specified table.</p>
+    ///
+    /// See `Glue::get_table_versions`.
+    pub fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
+        self.runtime.block_on(self.client.get_table_versions(input))
+    }
+
+    /// <p>Retrieves the definitions of some or all of the tables in a given <code>Database</code>.</p>
+    ///
+    /// See `Glue::get_tables`.
+    pub fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
+        self.runtime.block_on(self.client.get_tables(input))
+    }
+
+    /// <p>Retrieves a list of tags associated with a resource.</p>
+    ///
+    /// See `Glue::get_tags`.
+    pub fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// <p>Retrieves the definition of a trigger.</p>
+    ///
+    /// See `Glue::get_trigger`.
+    pub fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
+        self.runtime.block_on(self.client.get_trigger(input))
+    }
+
+    /// <p>Gets all the triggers associated with a job.</p>
+    ///
+    /// See `Glue::get_triggers`.
+    pub fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
+        self.runtime.block_on(self.client.get_triggers(input))
+    }
+
+    /// <p>Retrieves a specified function definition from the Data Catalog.</p>
+    ///
+    /// See `Glue::get_user_defined_function`.
+    pub fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
+        self.runtime
+            .block_on(self.client.get_user_defined_function(input))
+    }
+
+    /// <p>Retrieves multiple function definitions from the Data Catalog.</p>
+    ///
+    /// See `Glue::get_user_defined_functions`.
+    pub fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
+        self.runtime
+            .block_on(self.client.get_user_defined_functions(input))
+    }
+
+    /// <p>Retrieves resource metadata for a workflow.</p>
+    ///
+    /// See `Glue::get_workflow`.
+    pub fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
+        self.runtime.block_on(self.client.get_workflow(input))
+    }
+
+    /// <p>Retrieves the metadata for a given workflow run. </p>
+    ///
+    /// See `Glue::get_workflow_run`.
+    pub fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
+        self.runtime.block_on(self.client.get_workflow_run(input))
+    }
+
+    /// <p>Retrieves the workflow run properties which were set during the run.</p>
+    ///
+    /// See `Glue::get_workflow_run_properties`.
+    pub fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
+        self.runtime
+            .block_on(self.client.get_workflow_run_properties(input))
+    }
+
+    /// <p>Retrieves metadata for all runs of a given workflow.</p>
+    ///
+    /// See `Glue::get_workflow_runs`.
+    pub fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
+        self.runtime.block_on(self.client.get_workflow_runs(input))
+    }
+
+    /// <p>Imports an existing Amazon Athena Data Catalog to AWS Glue</p>
+    ///
+    /// See `Glue::import_catalog_to_glue`.
+    pub fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
+        self.runtime
+            .block_on(self.client.import_catalog_to_glue(input))
+    }
+
+    /// <p>Retrieves the names of all crawler resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    ///
+    /// See `Glue::list_crawlers`.
+    pub fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
+        self.runtime.block_on(self.client.list_crawlers(input))
+    }
+
+    /// <p>Retrieves the names of all <code>DevEndpoint</code> resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    ///
+    /// See `Glue::list_dev_endpoints`.
+    pub fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
+        self.runtime.block_on(self.client.list_dev_endpoints(input))
+    }
+
+    /// <p>Retrieves the names of all job resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    ///
+    /// See `Glue::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p> Retrieves a sortable, filterable list of existing AWS Glue machine learning transforms in this AWS account, or the resources with the specified tag. This operation takes the optional <code>Tags</code> field, which you can use as a filter of the responses so that tagged resources can be retrieved as a group. If you choose to use tag filtering, only resources with the tags are retrieved. </p>
+    ///
+    /// See `Glue::list_ml_transforms`.
+    pub fn list_ml_transforms(
+        &self,
+        input: ListMLTransformsRequest,
+    ) -> Result<ListMLTransformsResponse, RusotoError<ListMLTransformsError>> {
+        self.runtime.block_on(self.client.list_ml_transforms(input))
+    }
+
+    /// <p>Retrieves the names of all trigger resources in this AWS account, or the resources with the specified tag. This operation allows you to see which resources are available in your account, and their names.</p> <p>This operation takes the optional <code>Tags</code> field, which you can use as a filter on the response so that tagged resources can be retrieved as a group. If you choose to use tags filtering, only resources with the tag are retrieved.</p>
+    ///
+    /// See `Glue::list_triggers`.
+    pub fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
+        self.runtime.block_on(self.client.list_triggers(input))
+    }
+
+    /// <p>Lists names of workflows created in the account.</p>
+    ///
+    /// See `Glue::list_workflows`.
+    pub fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
+        self.runtime.block_on(self.client.list_workflows(input))
+    }
+
+    /// <p>Sets the security configuration for a specified catalog. After the configuration has been set, the specified encryption is applied to every catalog write thereafter.</p>
+    ///
+    /// See `Glue::put_data_catalog_encryption_settings`.
+    pub fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        PutDataCatalogEncryptionSettingsResponse,
+        RusotoError<PutDataCatalogEncryptionSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_data_catalog_encryption_settings(input))
+    }
+
+    /// <p>Sets the Data Catalog resource policy for access control.</p>
+    ///
+    /// See `Glue::put_resource_policy`.
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// <p>Puts the specified workflow run properties for the given workflow run. If a property already exists for the specified run, then it overrides the value otherwise adds the property to existing properties.</p>
+    ///
+    /// See `Glue::put_workflow_run_properties`.
+    pub fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
+        self.runtime
+            .block_on(self.client.put_workflow_run_properties(input))
+    }
+
+    /// <p>Resets a bookmark entry.</p>
+    ///
+    /// See `Glue::reset_job_bookmark`.
+    pub fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
+        self.runtime.block_on(self.client.reset_job_bookmark(input))
+    }
+
+    /// <p>Searches a set of tables based on properties in the table metadata as well as on the parent database. You can search against text or filter conditions. </p> <p>You can only get tables that you have access to based on the security policies defined in Lake Formation. You need at least a read-only access to the table for it to be returned. If you do not have access to all the columns in the table, these columns will not be searched against when returning the list of tables back to you. If you have access to the columns but not the data in the columns, those columns and the associated metadata for those columns will be included in the search. </p>
+    ///
+    /// See `Glue::search_tables`.
+    pub fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
+        self.runtime.block_on(self.client.search_tables(input))
+    }
+
+    /// <p>Starts a crawl using the specified crawler, regardless of what is scheduled. If the crawler is already running, returns a <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-api-exceptions.html#aws-glue-api-exceptions-CrawlerRunningException">CrawlerRunningException</a>.</p>
+    ///
+    /// See `Glue::start_crawler`.
+    pub fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
+        self.runtime.block_on(self.client.start_crawler(input))
+    }
+
+    /// <p>Changes the schedule state of the specified crawler to <code>SCHEDULED</code>, unless the crawler is already running or the schedule state is already <code>SCHEDULED</code>.</p>
+    ///
+    /// See `Glue::start_crawler_schedule`.
+    pub fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
+        self.runtime
+            .block_on(self.client.start_crawler_schedule(input))
+    }
+
+    /// <p>Begins an asynchronous task to export all labeled data for a particular transform. This task is the only label-related API call that is not part of the typical active learning workflow. You typically use <code>StartExportLabelsTaskRun</code> when you want to work with all of your existing labels at the same time, such as when you want to remove or change labels that were previously submitted as truth. This API operation accepts the <code>TransformId</code> whose labels you want to export and an Amazon Simple Storage Service (Amazon S3) path to export the labels to. The operation returns a <code>TaskRunId</code>. You can check on the status of your task run by calling the <code>GetMLTaskRun</code> API.</p>
+    ///
+    /// See `Glue::start_export_labels_task_run`.
+    pub fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
+        self.runtime
+            .block_on(self.client.start_export_labels_task_run(input))
+    }
+
+    /// <p>Enables you to provide additional labels (examples of truth) to be used to teach the machine learning transform and improve its quality. This API operation is generally used as part of the active learning workflow that starts with the <code>StartMLLabelingSetGenerationTaskRun</code> call and that ultimately results in improving the quality of your machine learning transform. </p> <p>After the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue machine learning will have generated a series of questions for humans to answer. (Answering these questions is often called 'labeling' in the machine learning workflows). In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” After the labeling process is finished, users upload their answers/labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform use the new and improved labels and perform a higher-quality transformation.</p> <p>By default, <code>StartMLLabelingSetGenerationTaskRun</code> continually learns from and combines all labels that you upload unless you set <code>Replace</code> to true. If you set <code>Replace</code> to true, <code>StartImportLabelsTaskRun</code> deletes and forgets all previously uploaded labels and learns only from the exact set that you upload. Replacing labels can be helpful if you realize that you previously uploaded incorrect labels, and you believe that they are having a negative effect on your transform quality.</p> <p>You can check on the status of your task run by calling the <code>GetMLTaskRun</code> operation. </p>
+    ///
+    /// See `Glue::start_import_labels_task_run`.
+    pub fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
+        self.runtime
+            .block_on(self.client.start_import_labels_task_run(input))
+    }
+
+    /// <p>Starts a job run using a job definition.</p>
+    ///
+    /// See `Glue::start_job_run`.
+    pub fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
+        self.runtime.block_on(self.client.start_job_run(input))
+    }
+
+    /// <p>Starts a task to estimate the quality of the transform. </p> <p>When you provide label sets as examples of truth, AWS Glue machine learning uses some of those examples to learn from them. The rest of the labels are used as a test to estimate quality.</p> <p>Returns a unique identifier for the run. You can call <code>GetMLTaskRun</code> to get more information about the stats of the <code>EvaluationTaskRun</code>.</p>
+    ///
+    /// See `Glue::start_ml_evaluation_task_run`.
+    pub fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
+        self.runtime
+            .block_on(self.client.start_ml_evaluation_task_run(input))
+    }
+
+    /// <p>Starts the active learning workflow for your machine learning transform to improve the transform's quality by generating label sets and adding labels.</p> <p>When the <code>StartMLLabelingSetGenerationTaskRun</code> finishes, AWS Glue will have generated a "labeling set" or a set of questions for humans to answer.</p> <p>In the case of the <code>FindMatches</code> transform, these questions are of the form, “What is the correct way to group these rows together into groups composed entirely of matching records?” </p> <p>After the labeling process is finished, you can upload your labels with a call to <code>StartImportLabelsTaskRun</code>. After <code>StartImportLabelsTaskRun</code> finishes, all future runs of the machine learning transform will use the new and improved labels and perform a higher-quality transformation.</p>
+    ///
+    /// See `Glue::start_ml_labeling_set_generation_task_run`.
+    pub fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> Result<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        RusotoError<StartMLLabelingSetGenerationTaskRunError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_ml_labeling_set_generation_task_run(input))
+    }
+
+    /// <p>Starts an existing trigger. See <a href="https://docs.aws.amazon.com/glue/latest/dg/trigger-job.html">Triggering Jobs</a> for information about how different types of trigger are started.</p>
+    ///
+    /// See `Glue::start_trigger`.
+    pub fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
+        self.runtime.block_on(self.client.start_trigger(input))
+    }
+
+    /// <p>Starts a new run of the specified workflow.</p>
+    ///
+    /// See `Glue::start_workflow_run`.
+    pub fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
+        self.runtime.block_on(self.client.start_workflow_run(input))
+    }
+
+    /// <p>If the specified crawler is running, stops the crawl.</p>
+    ///
+    /// See `Glue::stop_crawler`.
+    pub fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
+        self.runtime.block_on(self.client.stop_crawler(input))
+    }
+
+    /// <p>Sets the schedule state of the specified crawler to <code>NOT_SCHEDULED</code>, but does not stop the crawler if it is already running.</p>
+    ///
+    /// See `Glue::stop_crawler_schedule`.
+    pub fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
+        self.runtime
+            .block_on(self.client.stop_crawler_schedule(input))
+    }
+
+    /// <p>Stops a specified trigger.</p>
+    ///
+    /// See `Glue::stop_trigger`.
+    pub fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
+        self.runtime.block_on(self.client.stop_trigger(input))
+    }
+
+    /// <p>Stops the execution of the specified workflow run.</p>
+    ///
+    /// See `Glue::stop_workflow_run`.
+    pub fn stop_workflow_run(
+        &self,
+        input: StopWorkflowRunRequest,
+    ) -> Result<StopWorkflowRunResponse, RusotoError<StopWorkflowRunError>> {
+        self.runtime.block_on(self.client.stop_workflow_run(input))
+    }
+
+    /// <p>Adds tags to a resource. A tag is a label you can assign to an AWS resource. In AWS Glue, you can tag only certain resources. For information about what resources you can tag, see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-tags.html">AWS Tags in AWS Glue</a>.</p>
+    ///
+    /// See `Glue::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    ///
+    /// See `Glue::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Modifies an existing classifier (a <code>GrokClassifier</code>, an <code>XMLClassifier</code>, a <code>JsonClassifier</code>, or a <code>CsvClassifier</code>, depending on which field is present).</p>
+    ///
+    /// See `Glue::update_classifier`.
+    pub fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
+        self.runtime.block_on(self.client.update_classifier(input))
+    }
+
+    /// <p>Creates or updates partition statistics of columns.</p>
+    ///
+    /// See `Glue::update_column_statistics_for_partition`.
+    pub fn update_column_statistics_for_partition(
+        &self,
+        input: UpdateColumnStatisticsForPartitionRequest,
+    ) -> Result<
+        UpdateColumnStatisticsForPartitionResponse,
+        RusotoError<UpdateColumnStatisticsForPartitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_column_statistics_for_partition(input))
+    }
+
+    /// <p>Creates or updates table statistics of columns.</p>
+    ///
+    /// See `Glue::update_column_statistics_for_table`.
+    pub fn update_column_statistics_for_table(
+        &self,
+        input: UpdateColumnStatisticsForTableRequest,
+    ) -> Result<
+        UpdateColumnStatisticsForTableResponse,
+        RusotoError<UpdateColumnStatisticsForTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_column_statistics_for_table(input))
+    }
+
+    /// <p>Updates a connection definition in the Data Catalog.</p>
+    ///
+    /// See `Glue::update_connection`.
+    pub fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
+        self.runtime.block_on(self.client.update_connection(input))
+    }
+
+    /// <p>Updates a crawler. If a crawler is running, you must stop it using <code>StopCrawler</code> before updating it.</p>
+    ///
+    /// See `Glue::update_crawler`.
+    pub fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
+        self.runtime.block_on(self.client.update_crawler(input))
+    }
+
+    /// <p>Updates the schedule of a crawler using a <code>cron</code> expression. </p>
+    ///
+    /// See `Glue::update_crawler_schedule`.
+    pub fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
+        self.runtime
+            .block_on(self.client.update_crawler_schedule(input))
+    }
+
+    /// <p>Updates an existing database definition in a Data Catalog.</p>
+    ///
+    /// See `Glue::update_database`.
+    pub fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
+        self.runtime.block_on(self.client.update_database(input))
+    }
+
+    /// <p>Updates a specified development endpoint.</p>
+    ///
+    /// See `Glue::update_dev_endpoint`.
+    pub fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
+        self.runtime
+            .block_on(self.client.update_dev_endpoint(input))
+    }
+
+    /// <p>Updates an existing job definition.</p>
+    ///
+    /// See `Glue::update_job`.
+    pub fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+
+    /// <p>Updates an existing machine learning transform. Call this operation to tune the algorithm parameters to achieve better results.</p> <p>After calling this operation, you can call the <code>StartMLEvaluationTaskRun</code> operation to assess how well your new parameters achieved your goals (such as improving the quality of your machine learning transform, or making it more cost-effective).</p>
+    ///
+    /// See `Glue::update_ml_transform`.
+    pub fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
+        self.runtime
+            .block_on(self.client.update_ml_transform(input))
+    }
+
+    /// <p>Updates a partition.</p>
+    ///
+    /// See `Glue::update_partition`.
+    pub fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
+        self.runtime.block_on(self.client.update_partition(input))
+    }
+
+    /// <p>Updates a metadata table in the Data Catalog.</p>
+    ///
+    /// See `Glue::update_table`.
+    pub fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
+        self.runtime.block_on(self.client.update_table(input))
+    }
+
+    /// <p>Updates a trigger definition.</p>
+    ///
+    /// See `Glue::update_trigger`.
+    pub fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
+        self.runtime.block_on(self.client.update_trigger(input))
+    }
+
+    /// <p>Updates an existing function definition in the Data Catalog.</p>
+    ///
+    /// See `Glue::update_user_defined_function`.
+    pub fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_user_defined_function(input))
+    }
+
+    /// <p>Updates an existing workflow.</p>
+    ///
+    /// See `Glue::update_workflow`.
+    pub fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
+        self.runtime.block_on(self.client.update_workflow(input))
+    }
+}
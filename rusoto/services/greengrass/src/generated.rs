@@ -3462,12 +3462,16 @@ impl AssociateRoleToGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateRoleToGroupError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateRoleToGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3502,12 +3506,14 @@ impl AssociateServiceRoleToAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateServiceRoleToAccountError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateServiceRoleToAccountError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceRoleToAccountError::InternalServerError(err.msg),
                     )
                 }
@@ -3542,9 +3548,10 @@ impl CreateConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConnectorDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectorDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3576,9 +3583,10 @@ impl CreateConnectorDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConnectorDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConnectorDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3608,7 +3616,10 @@ impl CreateCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCoreDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3640,9 +3651,10 @@ impl CreateCoreDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCoreDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCoreDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3672,7 +3684,7 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDeploymentError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3702,7 +3714,10 @@ impl CreateDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeviceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3734,9 +3749,10 @@ impl CreateDeviceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeviceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeviceDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3766,7 +3782,10 @@ impl CreateFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3798,9 +3817,10 @@ impl CreateFunctionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFunctionDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3830,7 +3850,7 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3864,12 +3884,14 @@ impl CreateGroupCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupCertificateAuthorityError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupCertificateAuthorityError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGroupCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
@@ -3904,7 +3926,7 @@ impl CreateGroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupVersionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateGroupVersionError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3934,7 +3956,10 @@ impl CreateLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoggerDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3966,9 +3991,10 @@ impl CreateLoggerDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLoggerDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoggerDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3998,7 +4024,10 @@ impl CreateResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4030,9 +4059,10 @@ impl CreateResourceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4064,12 +4094,16 @@ impl CreateSoftwareUpdateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSoftwareUpdateJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSoftwareUpdateJobError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSoftwareUpdateJobError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSoftwareUpdateJobError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4102,9 +4136,10 @@ impl CreateSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriptionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4136,7 +4171,8 @@ impl CreateSubscriptionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSubscriptionDefinitionVersionError::BadRequest(err.msg),
                     )
                 }
@@ -4170,9 +4206,10 @@ impl DeleteConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConnectorDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConnectorDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4202,7 +4239,10 @@ impl DeleteCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCoreDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4232,7 +4272,10 @@ impl DeleteDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDeviceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4262,7 +4305,10 @@ impl DeleteFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4292,7 +4338,7 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4322,7 +4368,10 @@ impl DeleteLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoggerDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4352,7 +4401,10 @@ impl DeleteResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4384,9 +4436,10 @@ impl DeleteSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4418,12 +4471,14 @@ impl DisassociateRoleFromGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateRoleFromGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateRoleFromGroupError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateRoleFromGroupError::InternalServerError(err.msg),
                     )
                 }
@@ -4460,7 +4515,8 @@ impl DisassociateServiceRoleFromAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceRoleFromAccountError::InternalServerError(err.msg),
                     )
                 }
@@ -4496,12 +4552,13 @@ impl GetAssociatedRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAssociatedRoleError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAssociatedRoleError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetAssociatedRoleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAssociatedRoleError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4532,7 +4589,10 @@ impl GetBulkDeploymentStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBulkDeploymentStatusError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBulkDeploymentStatusError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4564,12 +4624,16 @@ impl GetConnectivityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectivityInfoError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectivityInfoError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetConnectivityInfoError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectivityInfoError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4600,7 +4664,10 @@ impl GetConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectorDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectorDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4632,9 +4699,10 @@ impl GetConnectorDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectorDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectorDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4664,7 +4732,7 @@ impl GetCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetCoreDefinitionError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4694,7 +4762,10 @@ impl GetCoreDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCoreDefinitionVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCoreDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4724,7 +4795,10 @@ impl GetDeploymentStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentStatusError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeploymentStatusError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4754,7 +4828,10 @@ impl GetDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4786,9 +4863,10 @@ impl GetDeviceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeviceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4818,7 +4896,10 @@ impl GetFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4850,9 +4931,10 @@ impl GetFunctionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFunctionDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4882,7 +4964,7 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetGroupError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4916,12 +4998,14 @@ impl GetGroupCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupCertificateAuthorityError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetGroupCertificateAuthorityError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetGroupCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
@@ -4960,12 +5044,14 @@ impl GetGroupCertificateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupCertificateConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetGroupCertificateConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetGroupCertificateConfigurationError::InternalServerError(err.msg),
                     )
                 }
@@ -5000,7 +5086,7 @@ impl GetGroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupVersionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetGroupVersionError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5030,7 +5116,10 @@ impl GetLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoggerDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5062,9 +5151,10 @@ impl GetLoggerDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetLoggerDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoggerDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5094,7 +5184,10 @@ impl GetResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5126,9 +5219,10 @@ impl GetResourceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourceDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5158,7 +5252,8 @@ impl GetServiceRoleForAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceRoleForAccountError::InternalServerError(err.msg),
                     )
                 }
@@ -5190,9 +5285,10 @@ impl GetSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSubscriptionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5224,9 +5320,10 @@ impl GetSubscriptionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSubscriptionDefinitionVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSubscriptionDefinitionVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5258,7 +5355,8 @@ impl ListBulkDeploymentDetailedReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListBulkDeploymentDetailedReportsError::BadRequest(err.msg),
                     )
                 }
@@ -5290,7 +5388,10 @@ impl ListBulkDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBulkDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBulkDeploymentsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5322,9 +5423,10 @@ impl ListConnectorDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConnectorDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConnectorDefinitionVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5378,9 +5480,10 @@ impl ListCoreDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListCoreDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCoreDefinitionVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5432,7 +5535,7 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListDeploymentsError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5464,9 +5567,10 @@ impl ListDeviceDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeviceDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceDefinitionVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5520,9 +5624,10 @@ impl ListFunctionDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFunctionDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFunctionDefinitionVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5578,12 +5683,14 @@ impl ListGroupCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupCertificateAuthoritiesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupCertificateAuthoritiesError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListGroupCertificateAuthoritiesError::InternalServerError(err.msg),
                     )
                 }
@@ -5618,7 +5725,7 @@ impl ListGroupVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListGroupVersionsError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5672,9 +5779,10 @@ impl ListLoggerDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListLoggerDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLoggerDefinitionVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5728,9 +5836,10 @@ impl ListResourceDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResourceDefinitionVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDefinitionVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5784,7 +5893,8 @@ impl ListSubscriptionDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListSubscriptionDefinitionVersionsError::BadRequest(err.msg),
                     )
                 }
@@ -5842,7 +5952,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5872,7 +5985,7 @@ impl ResetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ResetDeploymentsError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5902,7 +6015,10 @@ impl StartBulkDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartBulkDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartBulkDeploymentError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5932,7 +6048,7 @@ impl StopBulkDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopBulkDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopBulkDeploymentError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5962,7 +6078,7 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5992,7 +6108,7 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6024,12 +6140,16 @@ impl UpdateConnectivityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConnectivityInfoError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConnectivityInfoError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateConnectivityInfoError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConnectivityInfoError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6060,9 +6180,10 @@ impl UpdateConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConnectorDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConnectorDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6092,7 +6213,10 @@ impl UpdateCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCoreDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6122,7 +6246,10 @@ impl UpdateDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6152,7 +6279,10 @@ impl UpdateFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6182,7 +6312,7 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::BadRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6216,12 +6346,14 @@ impl UpdateGroupCertificateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGroupCertificateConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGroupCertificateConfigurationError::InternalServerError(err.msg),
                     )
                 }
@@ -6258,7 +6390,10 @@ impl UpdateLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLoggerDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6288,7 +6423,10 @@ impl UpdateResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6320,9 +6458,10 @@ impl UpdateSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSubscriptionDefinitionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriptionDefinitionError::BadRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6956,6 +7095,7 @@ impl GreenGrassClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6971,6 +7111,7 @@ impl GreenGrassClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassClient {
         GreenGrassClient { client, region }
     }
@@ -6990,6 +7131,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("AssociateRoleToGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7024,6 +7168,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("AssociateServiceRoleToAccount");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7056,6 +7203,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateConnectorDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7094,6 +7244,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateConnectorDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7128,6 +7281,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateCoreDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7164,6 +7320,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateCoreDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7199,6 +7358,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7231,6 +7393,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateDeviceDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7269,6 +7434,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateDeviceDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7301,6 +7469,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateFunctionDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7339,6 +7510,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateFunctionDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7373,6 +7547,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7411,6 +7588,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateGroupCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("X-Amzn-Client-Token", input.amzn_client_token.as_ref());
@@ -7446,6 +7626,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateGroupVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7478,6 +7661,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateLoggerDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7516,6 +7702,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateLoggerDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7548,6 +7737,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateResourceDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7586,6 +7778,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateResourceDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7620,6 +7815,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/updates";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateSoftwareUpdateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7653,6 +7851,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateSubscriptionDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7691,6 +7892,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("CreateSubscriptionDefinitionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7729,6 +7933,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteConnectorDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7760,6 +7967,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteCoreDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7791,6 +8001,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteDeviceDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7822,6 +8035,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteFunctionDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7850,6 +8066,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7881,6 +8100,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteLoggerDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7912,6 +8134,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteResourceDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7944,6 +8169,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DeleteSubscriptionDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7976,6 +8204,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DisassociateRoleFromGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8006,6 +8237,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("DisassociateServiceRoleFromAccount");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8039,6 +8273,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetAssociatedRole");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8070,6 +8307,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetBulkDeploymentStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8101,6 +8341,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetConnectivityInfo");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8132,6 +8375,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetConnectorDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8163,6 +8409,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetConnectorDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8200,6 +8449,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetCoreDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8228,6 +8480,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetCoreDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8260,6 +8515,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetDeploymentStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8291,6 +8549,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetDeviceDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8320,6 +8581,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetDeviceDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8357,6 +8621,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetFunctionDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8386,6 +8653,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetFunctionDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8420,6 +8690,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8453,6 +8726,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetGroupCertificateAuthority");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8487,6 +8763,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetGroupCertificateConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8521,6 +8800,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetGroupVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8552,6 +8834,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetLoggerDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8581,6 +8866,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetLoggerDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8618,6 +8906,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetResourceDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8647,6 +8938,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetResourceDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8674,6 +8968,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetServiceRoleForAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8706,6 +9003,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetSubscriptionDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8737,6 +9037,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("GetSubscriptionDefinitionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8779,6 +9082,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListBulkDeploymentDetailedReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8818,6 +9124,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListBulkDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8861,6 +9170,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListConnectorDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8900,6 +9212,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListConnectorDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8941,6 +9256,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListCoreDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8978,6 +9296,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListCoreDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9018,6 +9339,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9059,6 +9383,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListDeviceDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9096,6 +9423,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListDeviceDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9139,6 +9469,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListFunctionDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9176,6 +9509,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListFunctionDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9219,6 +9555,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListGroupCertificateAuthorities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9252,6 +9591,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListGroupVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9289,6 +9631,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9330,6 +9675,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListLoggerDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9367,6 +9715,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListLoggerDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9410,6 +9761,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListResourceDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9447,6 +9801,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListResourceDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9490,6 +9847,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListSubscriptionDefinitionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9530,6 +9890,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListSubscriptionDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9567,6 +9930,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9598,6 +9964,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("ResetDeployments");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9630,6 +9999,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("StartBulkDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9665,6 +10037,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("StopBulkDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9693,6 +10068,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9723,6 +10101,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9759,6 +10140,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateConnectivityInfo");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9794,6 +10178,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateConnectorDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9828,6 +10215,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateCoreDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9862,6 +10252,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateDeviceDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9896,6 +10289,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateFunctionDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9927,6 +10323,9 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9964,6 +10363,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateGroupCertificateConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10000,6 +10402,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateLoggerDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10034,6 +10439,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateResourceDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10069,6 +10477,9 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation_name("UpdateSubscriptionDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10091,3 +10502,1105 @@ impl GreenGrass for GreenGrassClient {
         }
     }
 }
+
+/// A blocking client for the AWS Greengrass API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GreenGrassClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GreenGrassBlockingClient {
+    client: GreenGrassClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GreenGrassBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GreenGrassBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GreenGrassBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GreenGrassBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GreenGrassBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient {
+            client: GreenGrassClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a role with a group. Your Greengrass core will use the role to access AWS cloud services. The role&#39;s permissions should allow Greengrass core Lambda functions to perform actions against the cloud.</p>
+    ///
+    /// See `GreenGrass::associate_role_to_group`.
+    pub fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> Result<AssociateRoleToGroupResponse, RusotoError<AssociateRoleToGroupError>> {
+        self.runtime
+            .block_on(self.client.associate_role_to_group(input))
+    }
+
+    /// <p>Associates a role with your account. AWS IoT Greengrass will use the role to access your Lambda functions and AWS IoT resources. This is necessary for deployments to succeed. The role must have at least minimum permissions in the policy &#39;&#39;AWSGreengrassResourceAccessRolePolicy&#39;&#39;.</p>
+    ///
+    /// See `GreenGrass::associate_service_role_to_account`.
+    pub fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> Result<
+        AssociateServiceRoleToAccountResponse,
+        RusotoError<AssociateServiceRoleToAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_service_role_to_account(input))
+    }
+
+    /// <p>Creates a connector definition. You may provide the initial version of the connector definition now or use &#39;&#39;CreateConnectorDefinitionVersion&#39;&#39; at a later time.</p>
+    ///
+    /// See `GreenGrass::create_connector_definition`.
+    pub fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> Result<CreateConnectorDefinitionResponse, RusotoError<CreateConnectorDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_connector_definition(input))
+    }
+
+    /// <p>Creates a version of a connector definition which has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_connector_definition_version`.
+    pub fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> Result<
+        CreateConnectorDefinitionVersionResponse,
+        RusotoError<CreateConnectorDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_connector_definition_version(input))
+    }
+
+    /// <p>Creates a core definition. You may provide the initial version of the core definition now or use &#39;&#39;CreateCoreDefinitionVersion&#39;&#39; at a later time. Greengrass groups must each contain exactly one Greengrass core.</p>
+    ///
+    /// See `GreenGrass::create_core_definition`.
+    pub fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> Result<CreateCoreDefinitionResponse, RusotoError<CreateCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_core_definition(input))
+    }
+
+    /// <p>Creates a version of a core definition that has already been defined. Greengrass groups must each contain exactly one Greengrass core.</p>
+    ///
+    /// See `GreenGrass::create_core_definition_version`.
+    pub fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> Result<CreateCoreDefinitionVersionResponse, RusotoError<CreateCoreDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_core_definition_version(input))
+    }
+
+    /// <p>Creates a deployment. &#39;&#39;CreateDeployment&#39;&#39; requests are idempotent with respect to the &#39;&#39;X-Amzn-Client-Token&#39;&#39; token and the request parameters.</p>
+    ///
+    /// See `GreenGrass::create_deployment`.
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// <p>Creates a device definition. You may provide the initial version of the device definition now or use &#39;&#39;CreateDeviceDefinitionVersion&#39;&#39; at a later time.</p>
+    ///
+    /// See `GreenGrass::create_device_definition`.
+    pub fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> Result<CreateDeviceDefinitionResponse, RusotoError<CreateDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_device_definition(input))
+    }
+
+    /// <p>Creates a version of a device definition that has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_device_definition_version`.
+    pub fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> Result<
+        CreateDeviceDefinitionVersionResponse,
+        RusotoError<CreateDeviceDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_device_definition_version(input))
+    }
+
+    /// <p>Creates a Lambda function definition which contains a list of Lambda functions and their configurations to be used in a group. You can create an initial version of the definition by providing a list of Lambda functions and their configurations now, or use &#39;&#39;CreateFunctionDefinitionVersion&#39;&#39; later.</p>
+    ///
+    /// See `GreenGrass::create_function_definition`.
+    pub fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> Result<CreateFunctionDefinitionResponse, RusotoError<CreateFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_function_definition(input))
+    }
+
+    /// <p>Creates a version of a Lambda function definition that has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_function_definition_version`.
+    pub fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> Result<
+        CreateFunctionDefinitionVersionResponse,
+        RusotoError<CreateFunctionDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_function_definition_version(input))
+    }
+
+    /// <p>Creates a group. You may provide the initial version of the group or use &#39;&#39;CreateGroupVersion&#39;&#39; at a later time. Tip: You can use the &#39;&#39;gg<em>group</em>setup&#39;&#39; package (https://github.com/awslabs/aws-greengrass-group-setup) as a library or command-line application to create and deploy Greengrass groups.</p>
+    ///
+    /// See `GreenGrass::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p>Creates a CA for the group. If a CA already exists, it will rotate the existing CA.</p>
+    ///
+    /// See `GreenGrass::create_group_certificate_authority`.
+    pub fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> Result<
+        CreateGroupCertificateAuthorityResponse,
+        RusotoError<CreateGroupCertificateAuthorityError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_group_certificate_authority(input))
+    }
+
+    /// <p>Creates a version of a group which has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_group_version`.
+    pub fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> Result<CreateGroupVersionResponse, RusotoError<CreateGroupVersionError>> {
+        self.runtime
+            .block_on(self.client.create_group_version(input))
+    }
+
+    /// <p>Creates a logger definition. You may provide the initial version of the logger definition now or use &#39;&#39;CreateLoggerDefinitionVersion&#39;&#39; at a later time.</p>
+    ///
+    /// See `GreenGrass::create_logger_definition`.
+    pub fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> Result<CreateLoggerDefinitionResponse, RusotoError<CreateLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_logger_definition(input))
+    }
+
+    /// <p>Creates a version of a logger definition that has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_logger_definition_version`.
+    pub fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> Result<
+        CreateLoggerDefinitionVersionResponse,
+        RusotoError<CreateLoggerDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_logger_definition_version(input))
+    }
+
+    /// <p>Creates a resource definition which contains a list of resources to be used in a group. You can create an initial version of the definition by providing a list of resources now, or use &#39;&#39;CreateResourceDefinitionVersion&#39;&#39; later.</p>
+    ///
+    /// See `GreenGrass::create_resource_definition`.
+    pub fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> Result<CreateResourceDefinitionResponse, RusotoError<CreateResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_resource_definition(input))
+    }
+
+    /// <p>Creates a version of a resource definition that has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_resource_definition_version`.
+    pub fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> Result<
+        CreateResourceDefinitionVersionResponse,
+        RusotoError<CreateResourceDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_resource_definition_version(input))
+    }
+
+    /// <p>Creates a software update for a core or group of cores (specified as an IoT thing group.) Use this to update the OTA Agent as well as the Greengrass core software. It makes use of the IoT Jobs feature which provides additional commands to manage a Greengrass core software update job.</p>
+    ///
+    /// See `GreenGrass::create_software_update_job`.
+    pub fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> Result<CreateSoftwareUpdateJobResponse, RusotoError<CreateSoftwareUpdateJobError>> {
+        self.runtime
+            .block_on(self.client.create_software_update_job(input))
+    }
+
+    /// <p>Creates a subscription definition. You may provide the initial version of the subscription definition now or use &#39;&#39;CreateSubscriptionDefinitionVersion&#39;&#39; at a later time.</p>
+    ///
+    /// See `GreenGrass::create_subscription_definition`.
+    pub fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> Result<CreateSubscriptionDefinitionResponse, RusotoError<CreateSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_subscription_definition(input))
+    }
+
+    /// <p>Creates a version of a subscription definition which has already been defined.</p>
+    ///
+    /// See `GreenGrass::create_subscription_definition_version`.
+    pub fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        CreateSubscriptionDefinitionVersionResponse,
+        RusotoError<CreateSubscriptionDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_subscription_definition_version(input))
+    }
+
+    /// <p>Deletes a connector definition.</p>
+    ///
+    /// See `GreenGrass::delete_connector_definition`.
+    pub fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> Result<DeleteConnectorDefinitionResponse, RusotoError<DeleteConnectorDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_connector_definition(input))
+    }
+
+    /// <p>Deletes a core definition.</p>
+    ///
+    /// See `GreenGrass::delete_core_definition`.
+    pub fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> Result<DeleteCoreDefinitionResponse, RusotoError<DeleteCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_core_definition(input))
+    }
+
+    /// <p>Deletes a device definition.</p>
+    ///
+    /// See `GreenGrass::delete_device_definition`.
+    pub fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> Result<DeleteDeviceDefinitionResponse, RusotoError<DeleteDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_device_definition(input))
+    }
+
+    /// <p>Deletes a Lambda function definition.</p>
+    ///
+    /// See `GreenGrass::delete_function_definition`.
+    pub fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> Result<DeleteFunctionDefinitionResponse, RusotoError<DeleteFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_function_definition(input))
+    }
+
+    /// <p>Deletes a group.</p>
+    ///
+    /// See `GreenGrass::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Deletes a logger definition.</p>
+    ///
+    /// See `GreenGrass::delete_logger_definition`.
+    pub fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> Result<DeleteLoggerDefinitionResponse, RusotoError<DeleteLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_logger_definition(input))
+    }
+
+    /// <p>Deletes a resource definition.</p>
+    ///
+    /// See `GreenGrass::delete_resource_definition`.
+    pub fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> Result<DeleteResourceDefinitionResponse, RusotoError<DeleteResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_definition(input))
+    }
+
+    /// <p>Deletes a subscription definition.</p>
+    ///
+    /// See `GreenGrass::delete_subscription_definition`.
+    pub fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> Result<DeleteSubscriptionDefinitionResponse, RusotoError<DeleteSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_subscription_definition(input))
+    }
+
+    /// <p>Disassociates the role from a group.</p>
+    ///
+    /// See `GreenGrass::disassociate_role_from_group`.
+    pub fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> Result<DisassociateRoleFromGroupResponse, RusotoError<DisassociateRoleFromGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_role_from_group(input))
+    }
+
+    /// <p>Disassociates the service role from your account. Without a service role, deployments will not work.</p>
+    ///
+    /// See `GreenGrass::disassociate_service_role_from_account`.
+    pub fn disassociate_service_role_from_account(
+        &self,
+    ) -> Result<
+        DisassociateServiceRoleFromAccountResponse,
+        RusotoError<DisassociateServiceRoleFromAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_service_role_from_account())
+    }
+
+    /// <p>Retrieves the role associated with a particular group.</p>
+    ///
+    /// See `GreenGrass::get_associated_role`.
+    pub fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> Result<GetAssociatedRoleResponse, RusotoError<GetAssociatedRoleError>> {
+        self.runtime
+            .block_on(self.client.get_associated_role(input))
+    }
+
+    /// <p>Returns the status of a bulk deployment.</p>
+    ///
+    /// See `GreenGrass::get_bulk_deployment_status`.
+    pub fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> Result<GetBulkDeploymentStatusResponse, RusotoError<GetBulkDeploymentStatusError>> {
+        self.runtime
+            .block_on(self.client.get_bulk_deployment_status(input))
+    }
+
+    /// <p>Retrieves the connectivity information for a core.</p>
+    ///
+    /// See `GreenGrass::get_connectivity_info`.
+    pub fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> Result<GetConnectivityInfoResponse, RusotoError<GetConnectivityInfoError>> {
+        self.runtime
+            .block_on(self.client.get_connectivity_info(input))
+    }
+
+    /// <p>Retrieves information about a connector definition.</p>
+    ///
+    /// See `GreenGrass::get_connector_definition`.
+    pub fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> Result<GetConnectorDefinitionResponse, RusotoError<GetConnectorDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_connector_definition(input))
+    }
+
+    /// <p>Retrieves information about a connector definition version, including the connectors that the version contains. Connectors are prebuilt modules that interact with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    ///
+    /// See `GreenGrass::get_connector_definition_version`.
+    pub fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> Result<
+        GetConnectorDefinitionVersionResponse,
+        RusotoError<GetConnectorDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_connector_definition_version(input))
+    }
+
+    /// <p>Retrieves information about a core definition version.</p>
+    ///
+    /// See `GreenGrass::get_core_definition`.
+    pub fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> Result<GetCoreDefinitionResponse, RusotoError<GetCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_core_definition(input))
+    }
+
+    /// <p>Retrieves information about a core definition version.</p>
+    ///
+    /// See `GreenGrass::get_core_definition_version`.
+    pub fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> Result<GetCoreDefinitionVersionResponse, RusotoError<GetCoreDefinitionVersionError>> {
+        self.runtime
+            .block_on(self.client.get_core_definition_version(input))
+    }
+
+    /// <p>Returns the status of a deployment.</p>
+    ///
+    /// See `GreenGrass::get_deployment_status`.
+    pub fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> Result<GetDeploymentStatusResponse, RusotoError<GetDeploymentStatusError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_status(input))
+    }
+
+    /// <p>Retrieves information about a device definition.</p>
+    ///
+    /// See `GreenGrass::get_device_definition`.
+    pub fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> Result<GetDeviceDefinitionResponse, RusotoError<GetDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_device_definition(input))
+    }
+
+    /// <p>Retrieves information about a device definition version.</p>
+    ///
+    /// See `GreenGrass::get_device_definition_version`.
+    pub fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> Result<GetDeviceDefinitionVersionResponse, RusotoError<GetDeviceDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_device_definition_version(input))
+    }
+
+    /// <p>Retrieves information about a Lambda function definition, including its creation time and latest version.</p>
+    ///
+    /// See `GreenGrass::get_function_definition`.
+    pub fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> Result<GetFunctionDefinitionResponse, RusotoError<GetFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_function_definition(input))
+    }
+
+    /// <p>Retrieves information about a Lambda function definition version, including which Lambda functions are included in the version and their configurations.</p>
+    ///
+    /// See `GreenGrass::get_function_definition_version`.
+    pub fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> Result<GetFunctionDefinitionVersionResponse, RusotoError<GetFunctionDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_function_definition_version(input))
+    }
+
+    /// <p>Retrieves information about a group.</p>
+    ///
+    /// See `GreenGrass::get_group`.
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// <p>Retreives the CA associated with a group. Returns the public key of the CA.</p>
+    ///
+    /// See `GreenGrass::get_group_certificate_authority`.
+    pub fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> Result<GetGroupCertificateAuthorityResponse, RusotoError<GetGroupCertificateAuthorityError>>
+    {
+        self.runtime
+            .block_on(self.client.get_group_certificate_authority(input))
+    }
+
+    /// <p>Retrieves the current configuration for the CA used by the group.</p>
+    ///
+    /// See `GreenGrass::get_group_certificate_configuration`.
+    pub fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> Result<
+        GetGroupCertificateConfigurationResponse,
+        RusotoError<GetGroupCertificateConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_group_certificate_configuration(input))
+    }
+
+    /// <p>Retrieves information about a group version.</p>
+    ///
+    /// See `GreenGrass::get_group_version`.
+    pub fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> Result<GetGroupVersionResponse, RusotoError<GetGroupVersionError>> {
+        self.runtime.block_on(self.client.get_group_version(input))
+    }
+
+    /// <p>Retrieves information about a logger definition.</p>
+    ///
+    /// See `GreenGrass::get_logger_definition`.
+    pub fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> Result<GetLoggerDefinitionResponse, RusotoError<GetLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_logger_definition(input))
+    }
+
+    /// <p>Retrieves information about a logger definition version.</p>
+    ///
+    /// See `GreenGrass::get_logger_definition_version`.
+    pub fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> Result<GetLoggerDefinitionVersionResponse, RusotoError<GetLoggerDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_logger_definition_version(input))
+    }
+
+    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
+    ///
+    /// See `GreenGrass::get_resource_definition`.
+    pub fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> Result<GetResourceDefinitionResponse, RusotoError<GetResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_resource_definition(input))
+    }
+
+    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
+    ///
+    /// See `GreenGrass::get_resource_definition_version`.
+    pub fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> Result<GetResourceDefinitionVersionResponse, RusotoError<GetResourceDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resource_definition_version(input))
+    }
+
+    /// <p>Retrieves the service role that is attached to your account.</p>
+    ///
+    /// See `GreenGrass::get_service_role_for_account`.
+    pub fn get_service_role_for_account(
+        &self,
+    ) -> Result<GetServiceRoleForAccountResponse, RusotoError<GetServiceRoleForAccountError>> {
+        self.runtime
+            .block_on(self.client.get_service_role_for_account())
+    }
+
+    /// <p>Retrieves information about a subscription definition.</p>
+    ///
+    /// See `GreenGrass::get_subscription_definition`.
+    pub fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> Result<GetSubscriptionDefinitionResponse, RusotoError<GetSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_subscription_definition(input))
+    }
+
+    /// <p>Retrieves information about a subscription definition version.</p>
+    ///
+    /// See `GreenGrass::get_subscription_definition_version`.
+    pub fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        GetSubscriptionDefinitionVersionResponse,
+        RusotoError<GetSubscriptionDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_subscription_definition_version(input))
+    }
+
+    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
+    ///
+    /// See `GreenGrass::list_bulk_deployment_detailed_reports`.
+    pub fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> Result<
+        ListBulkDeploymentDetailedReportsResponse,
+        RusotoError<ListBulkDeploymentDetailedReportsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_bulk_deployment_detailed_reports(input))
+    }
+
+    /// <p>Returns a list of bulk deployments.</p>
+    ///
+    /// See `GreenGrass::list_bulk_deployments`.
+    pub fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> Result<ListBulkDeploymentsResponse, RusotoError<ListBulkDeploymentsError>> {
+        self.runtime
+            .block_on(self.client.list_bulk_deployments(input))
+    }
+
+    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    ///
+    /// See `GreenGrass::list_connector_definition_versions`.
+    pub fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> Result<
+        ListConnectorDefinitionVersionsResponse,
+        RusotoError<ListConnectorDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_connector_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of connector definitions.</p>
+    ///
+    /// See `GreenGrass::list_connector_definitions`.
+    pub fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> Result<ListConnectorDefinitionsResponse, RusotoError<ListConnectorDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_connector_definitions(input))
+    }
+
+    /// <p>Lists the versions of a core definition.</p>
+    ///
+    /// See `GreenGrass::list_core_definition_versions`.
+    pub fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> Result<ListCoreDefinitionVersionsResponse, RusotoError<ListCoreDefinitionVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_core_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of core definitions.</p>
+    ///
+    /// See `GreenGrass::list_core_definitions`.
+    pub fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> Result<ListCoreDefinitionsResponse, RusotoError<ListCoreDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_core_definitions(input))
+    }
+
+    /// <p>Returns a history of deployments for the group.</p>
+    ///
+    /// See `GreenGrass::list_deployments`.
+    pub fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<ListDeploymentsResponse, RusotoError<ListDeploymentsError>> {
+        self.runtime.block_on(self.client.list_deployments(input))
+    }
+
+    /// <p>Lists the versions of a device definition.</p>
+    ///
+    /// See `GreenGrass::list_device_definition_versions`.
+    pub fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> Result<ListDeviceDefinitionVersionsResponse, RusotoError<ListDeviceDefinitionVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_device_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of device definitions.</p>
+    ///
+    /// See `GreenGrass::list_device_definitions`.
+    pub fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> Result<ListDeviceDefinitionsResponse, RusotoError<ListDeviceDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_device_definitions(input))
+    }
+
+    /// <p>Lists the versions of a Lambda function definition.</p>
+    ///
+    /// See `GreenGrass::list_function_definition_versions`.
+    pub fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> Result<
+        ListFunctionDefinitionVersionsResponse,
+        RusotoError<ListFunctionDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_function_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of Lambda function definitions.</p>
+    ///
+    /// See `GreenGrass::list_function_definitions`.
+    pub fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> Result<ListFunctionDefinitionsResponse, RusotoError<ListFunctionDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_function_definitions(input))
+    }
+
+    /// <p>Retrieves the current CAs for a group.</p>
+    ///
+    /// See `GreenGrass::list_group_certificate_authorities`.
+    pub fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> Result<
+        ListGroupCertificateAuthoritiesResponse,
+        RusotoError<ListGroupCertificateAuthoritiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_group_certificate_authorities(input))
+    }
+
+    /// <p>Lists the versions of a group.</p>
+    ///
+    /// See `GreenGrass::list_group_versions`.
+    pub fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> Result<ListGroupVersionsResponse, RusotoError<ListGroupVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_group_versions(input))
+    }
+
+    /// <p>Retrieves a list of groups.</p>
+    ///
+    /// See `GreenGrass::list_groups`.
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// <p>Lists the versions of a logger definition.</p>
+    ///
+    /// See `GreenGrass::list_logger_definition_versions`.
+    pub fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> Result<ListLoggerDefinitionVersionsResponse, RusotoError<ListLoggerDefinitionVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_logger_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of logger definitions.</p>
+    ///
+    /// See `GreenGrass::list_logger_definitions`.
+    pub fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> Result<ListLoggerDefinitionsResponse, RusotoError<ListLoggerDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_logger_definitions(input))
+    }
+
+    /// <p>Lists the versions of a resource definition.</p>
+    ///
+    /// See `GreenGrass::list_resource_definition_versions`.
+    pub fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> Result<
+        ListResourceDefinitionVersionsResponse,
+        RusotoError<ListResourceDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resource_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of resource definitions.</p>
+    ///
+    /// See `GreenGrass::list_resource_definitions`.
+    pub fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> Result<ListResourceDefinitionsResponse, RusotoError<ListResourceDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_resource_definitions(input))
+    }
+
+    /// <p>Lists the versions of a subscription definition.</p>
+    ///
+    /// See `GreenGrass::list_subscription_definition_versions`.
+    pub fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> Result<
+        ListSubscriptionDefinitionVersionsResponse,
+        RusotoError<ListSubscriptionDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_subscription_definition_versions(input))
+    }
+
+    /// <p>Retrieves a list of subscription definitions.</p>
+    ///
+    /// See `GreenGrass::list_subscription_definitions`.
+    pub fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> Result<ListSubscriptionDefinitionsResponse, RusotoError<ListSubscriptionDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_subscription_definitions(input))
+    }
+
+    /// <p>Retrieves a list of resource tags for a resource arn.</p>
+    ///
+    /// See `GreenGrass::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Resets a group&#39;s deployments.</p>
+    ///
+    /// See `GreenGrass::reset_deployments`.
+    pub fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> Result<ResetDeploymentsResponse, RusotoError<ResetDeploymentsError>> {
+        self.runtime.block_on(self.client.reset_deployments(input))
+    }
+
+    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
+    ///
+    /// See `GreenGrass::start_bulk_deployment`.
+    pub fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> Result<StartBulkDeploymentResponse, RusotoError<StartBulkDeploymentError>> {
+        self.runtime
+            .block_on(self.client.start_bulk_deployment(input))
+    }
+
+    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
+    ///
+    /// See `GreenGrass::stop_bulk_deployment`.
+    pub fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> Result<StopBulkDeploymentResponse, RusotoError<StopBulkDeploymentError>> {
+        self.runtime
+            .block_on(self.client.stop_bulk_deployment(input))
+    }
+
+    /// <p>Adds tags to a Greengrass resource. Valid resources are &#39;Group&#39;, &#39;ConnectorDefinition&#39;, &#39;CoreDefinition&#39;, &#39;DeviceDefinition&#39;, &#39;FunctionDefinition&#39;, &#39;LoggerDefinition&#39;, &#39;SubscriptionDefinition&#39;, &#39;ResourceDefinition&#39;, and &#39;BulkDeployment&#39;.</p>
+    ///
+    /// See `GreenGrass::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove resource tags from a Greengrass Resource.</p>
+    ///
+    /// See `GreenGrass::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
+    ///
+    /// See `GreenGrass::update_connectivity_info`.
+    pub fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> Result<UpdateConnectivityInfoResponse, RusotoError<UpdateConnectivityInfoError>> {
+        self.runtime
+            .block_on(self.client.update_connectivity_info(input))
+    }
+
+    /// <p>Updates a connector definition.</p>
+    ///
+    /// See `GreenGrass::update_connector_definition`.
+    pub fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> Result<UpdateConnectorDefinitionResponse, RusotoError<UpdateConnectorDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_connector_definition(input))
+    }
+
+    /// <p>Updates a core definition.</p>
+    ///
+    /// See `GreenGrass::update_core_definition`.
+    pub fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> Result<UpdateCoreDefinitionResponse, RusotoError<UpdateCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_core_definition(input))
+    }
+
+    /// <p>Updates a device definition.</p>
+    ///
+    /// See `GreenGrass::update_device_definition`.
+    pub fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> Result<UpdateDeviceDefinitionResponse, RusotoError<UpdateDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_device_definition(input))
+    }
+
+    /// <p>Updates a Lambda function definition.</p>
+    ///
+    /// See `GreenGrass::update_function_definition`.
+    pub fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> Result<UpdateFunctionDefinitionResponse, RusotoError<UpdateFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_function_definition(input))
+    }
+
+    /// <p>Updates a group.</p>
+    ///
+    /// See `GreenGrass::update_group`.
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// <p>Updates the Certificate expiry time for a group.</p>
+    ///
+    /// See `GreenGrass::update_group_certificate_configuration`.
+    pub fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> Result<
+        UpdateGroupCertificateConfigurationResponse,
+        RusotoError<UpdateGroupCertificateConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_group_certificate_configuration(input))
+    }
+
+    /// <p>Updates a logger definition.</p>
+    ///
+    /// See `GreenGrass::update_logger_definition`.
+    pub fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> Result<UpdateLoggerDefinitionResponse, RusotoError<UpdateLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_logger_definition(input))
+    }
+
+    /// <p>Updates a resource definition.</p>
+    ///
+    /// See `GreenGrass::update_resource_definition`.
+    pub fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> Result<UpdateResourceDefinitionResponse, RusotoError<UpdateResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_resource_definition(input))
+    }
+
+    /// <p>Updates a subscription definition.</p>
+    ///
+    /// See `GreenGrass::update_subscription_definition`.
+    pub fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> Result<UpdateSubscriptionDefinitionResponse, RusotoError<UpdateSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_subscription_definition(input))
+    }
+}
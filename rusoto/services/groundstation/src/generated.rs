@@ -1161,13 +1161,19 @@ impl CancelContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CancelContactError::Dependency(err.msg))
+                    return RusotoError::service(&res, CancelContactError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelContactError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelContactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1205,16 +1211,19 @@ impl CreateConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateConfigError::Dependency(err.msg))
+                    return RusotoError::service(&res, CreateConfigError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateConfigError::InvalidParameter(err.msg))
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateConfigError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateConfigError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1253,17 +1262,20 @@ impl CreateDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateDataflowEndpointGroupError::Dependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataflowEndpointGroupError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataflowEndpointGroupError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataflowEndpointGroupError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1301,17 +1313,22 @@ impl CreateMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMissionProfileError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateMissionProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMissionProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMissionProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMissionProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1347,13 +1364,13 @@ impl DeleteConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteConfigError::Dependency(err.msg))
+                    return RusotoError::service(&res, DeleteConfigError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteConfigError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteConfigError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1391,17 +1408,20 @@ impl DeleteDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteDataflowEndpointGroupError::Dependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataflowEndpointGroupError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDataflowEndpointGroupError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDataflowEndpointGroupError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1439,17 +1459,22 @@ impl DeleteMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMissionProfileError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMissionProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMissionProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1485,13 +1510,19 @@ impl DescribeContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DescribeContactError::Dependency(err.msg))
+                    return RusotoError::service(&res, DescribeContactError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContactError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1527,13 +1558,13 @@ impl GetConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetConfigError::Dependency(err.msg))
+                    return RusotoError::service(&res, GetConfigError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetConfigError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetConfigError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1569,17 +1600,22 @@ impl GetDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::Dependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataflowEndpointGroupError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDataflowEndpointGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDataflowEndpointGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1615,13 +1651,19 @@ impl GetMinuteUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetMinuteUsageError::Dependency(err.msg))
+                    return RusotoError::service(&res, GetMinuteUsageError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMinuteUsageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMinuteUsageError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMinuteUsageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMinuteUsageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1657,13 +1699,19 @@ impl GetMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(&res, GetMissionProfileError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMissionProfileError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMissionProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMissionProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMissionProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1699,13 +1747,13 @@ impl GetSatelliteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetSatelliteError::Dependency(err.msg))
+                    return RusotoError::service(&res, GetSatelliteError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSatelliteError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetSatelliteError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSatelliteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetSatelliteError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1741,13 +1789,13 @@ impl ListConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListConfigsError::Dependency(err.msg))
+                    return RusotoError::service(&res, ListConfigsError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListConfigsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListConfigsError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListConfigsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1783,13 +1831,13 @@ impl ListContactsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListContactsError::Dependency(err.msg))
+                    return RusotoError::service(&res, ListContactsError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContactsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListContactsError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContactsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListContactsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1827,19 +1875,22 @@ impl ListDataflowEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::Dependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataflowEndpointGroupsError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataflowEndpointGroupsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataflowEndpointGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1875,13 +1926,19 @@ impl ListGroundStationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListGroundStationsError::Dependency(err.msg))
+                    return RusotoError::service(&res, ListGroundStationsError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroundStationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroundStationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroundStationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroundStationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1917,17 +1974,22 @@ impl ListMissionProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListMissionProfilesError::Dependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMissionProfilesError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListMissionProfilesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMissionProfilesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMissionProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMissionProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1963,13 +2025,19 @@ impl ListSatellitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListSatellitesError::Dependency(err.msg))
+                    return RusotoError::service(&res, ListSatellitesError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSatellitesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSatellitesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSatellitesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSatellitesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2005,17 +2073,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Dependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2051,13 +2124,19 @@ impl ReserveContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ReserveContactError::Dependency(err.msg))
+                    return RusotoError::service(&res, ReserveContactError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ReserveContactError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReserveContactError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReserveContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReserveContactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2093,13 +2172,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(TagResourceError::Dependency(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2135,13 +2214,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UntagResourceError::Dependency(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2177,13 +2262,13 @@ impl UpdateConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UpdateConfigError::Dependency(err.msg))
+                    return RusotoError::service(&res, UpdateConfigError::Dependency(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateConfigError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateConfigError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2219,17 +2304,22 @@ impl UpdateMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMissionProfileError::Dependency(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMissionProfileError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMissionProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2420,6 +2510,7 @@ impl GroundStationClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2435,6 +2526,7 @@ impl GroundStationClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GroundStationClient {
         GroundStationClient { client, region }
     }
@@ -2451,6 +2543,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("CancelContact");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2479,6 +2574,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("CreateConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2511,6 +2609,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("CreateDataflowEndpointGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2542,6 +2643,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("CreateMissionProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2577,6 +2681,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("DeleteConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2609,6 +2716,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataflowEndpointGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2640,6 +2750,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("DeleteMissionProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2668,6 +2781,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("DescribeContact");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2700,6 +2816,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("GetConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2731,6 +2850,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("GetDataflowEndpointGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2759,6 +2881,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/minute-usage";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("GetMinuteUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2793,6 +2918,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("GetMissionProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2824,6 +2952,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("GetSatellite");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2852,6 +2983,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2889,6 +3023,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contacts";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListContacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2921,6 +3058,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListDataflowEndpointGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2958,6 +3098,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/groundstation";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListGroundStations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2998,6 +3141,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListMissionProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3035,6 +3181,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/satellite";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListSatellites");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3072,6 +3221,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3100,6 +3252,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contact";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("ReserveContact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3131,6 +3286,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3162,6 +3320,9 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3200,6 +3361,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("UpdateConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3234,6 +3398,9 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation_name("UpdateMissionProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3256,3 +3423,317 @@ impl GroundStation for GroundStationClient {
         }
     }
 }
+
+/// A blocking client for the AWS Ground Station API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GroundStationClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GroundStationBlockingClient {
+    client: GroundStationClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GroundStationBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GroundStationBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GroundStationBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GroundStationBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GroundStationBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> GroundStationBlockingClient {
+        GroundStationBlockingClient {
+            client: GroundStationClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels a contact with a specified contact ID.</p>
+    ///
+    /// See `GroundStation::cancel_contact`.
+    pub fn cancel_contact(
+        &self,
+        input: CancelContactRequest,
+    ) -> Result<ContactIdResponse, RusotoError<CancelContactError>> {
+        self.runtime.block_on(self.client.cancel_contact(input))
+    }
+
+    /// <p>Creates a <code>Config</code> with the specified <code>configData</code> parameters.</p> <p>Only one type of <code>configData</code> can be specified.</p>
+    ///
+    /// See `GroundStation::create_config`.
+    pub fn create_config(
+        &self,
+        input: CreateConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<CreateConfigError>> {
+        self.runtime.block_on(self.client.create_config(input))
+    }
+
+    /// <p>Creates a <code>DataflowEndpoint</code> group containing the specified list of <code>DataflowEndpoint</code> objects.</p> <p>The <code>name</code> field in each endpoint is used in your mission profile <code>DataflowEndpointConfig</code> to specify which endpoints to use during a contact.</p> <p>When a contact uses multiple <code>DataflowEndpointConfig</code> objects, each <code>Config</code> must match a <code>DataflowEndpoint</code> in the same group.</p>
+    ///
+    /// See `GroundStation::create_dataflow_endpoint_group`.
+    pub fn create_dataflow_endpoint_group(
+        &self,
+        input: CreateDataflowEndpointGroupRequest,
+    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<CreateDataflowEndpointGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_dataflow_endpoint_group(input))
+    }
+
+    /// <p>Creates a mission profile.</p> <p> <code>dataflowEdges</code> is a list of lists of strings. Each lower level list of strings has two elements: a <i>from</i> ARN and a <i>to</i> ARN.</p>
+    ///
+    /// See `GroundStation::create_mission_profile`.
+    pub fn create_mission_profile(
+        &self,
+        input: CreateMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<CreateMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.create_mission_profile(input))
+    }
+
+    /// <p>Deletes a <code>Config</code>.</p>
+    ///
+    /// See `GroundStation::delete_config`.
+    pub fn delete_config(
+        &self,
+        input: DeleteConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<DeleteConfigError>> {
+        self.runtime.block_on(self.client.delete_config(input))
+    }
+
+    /// <p>Deletes a dataflow endpoint group.</p>
+    ///
+    /// See `GroundStation::delete_dataflow_endpoint_group`.
+    pub fn delete_dataflow_endpoint_group(
+        &self,
+        input: DeleteDataflowEndpointGroupRequest,
+    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<DeleteDataflowEndpointGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_dataflow_endpoint_group(input))
+    }
+
+    /// <p>Deletes a mission profile.</p>
+    ///
+    /// See `GroundStation::delete_mission_profile`.
+    pub fn delete_mission_profile(
+        &self,
+        input: DeleteMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<DeleteMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_mission_profile(input))
+    }
+
+    /// <p>Describes an existing contact.</p>
+    ///
+    /// See `GroundStation::describe_contact`.
+    pub fn describe_contact(
+        &self,
+        input: DescribeContactRequest,
+    ) -> Result<DescribeContactResponse, RusotoError<DescribeContactError>> {
+        self.runtime.block_on(self.client.describe_contact(input))
+    }
+
+    /// <p>Returns <code>Config</code> information.</p> <p>Only one <code>Config</code> response can be returned.</p>
+    ///
+    /// See `GroundStation::get_config`.
+    pub fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+        self.runtime.block_on(self.client.get_config(input))
+    }
+
+    /// <p>Returns the dataflow endpoint group.</p>
+    ///
+    /// See `GroundStation::get_dataflow_endpoint_group`.
+    pub fn get_dataflow_endpoint_group(
+        &self,
+        input: GetDataflowEndpointGroupRequest,
+    ) -> Result<GetDataflowEndpointGroupResponse, RusotoError<GetDataflowEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.get_dataflow_endpoint_group(input))
+    }
+
+    /// <p>Returns the number of minutes used by account.</p>
+    ///
+    /// See `GroundStation::get_minute_usage`.
+    pub fn get_minute_usage(
+        &self,
+        input: GetMinuteUsageRequest,
+    ) -> Result<GetMinuteUsageResponse, RusotoError<GetMinuteUsageError>> {
+        self.runtime.block_on(self.client.get_minute_usage(input))
+    }
+
+    /// <p>Returns a mission profile.</p>
+    ///
+    /// See `GroundStation::get_mission_profile`.
+    pub fn get_mission_profile(
+        &self,
+        input: GetMissionProfileRequest,
+    ) -> Result<GetMissionProfileResponse, RusotoError<GetMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.get_mission_profile(input))
+    }
+
+    /// <p>Returns a satellite.</p>
+    ///
+    /// See `GroundStation::get_satellite`.
+    pub fn get_satellite(
+        &self,
+        input: GetSatelliteRequest,
+    ) -> Result<GetSatelliteResponse, RusotoError<GetSatelliteError>> {
+        self.runtime.block_on(self.client.get_satellite(input))
+    }
+
+    /// <p>Returns a list of <code>Config</code> objects.</p>
+    ///
+    /// See `GroundStation::list_configs`.
+    pub fn list_configs(
+        &self,
+        input: ListConfigsRequest,
+    ) -> Result<ListConfigsResponse, RusotoError<ListConfigsError>> {
+        self.runtime.block_on(self.client.list_configs(input))
+    }
+
+    /// <p>Returns a list of contacts.</p> <p>If <code>statusList</code> contains AVAILABLE, the request must include <code>groundStation</code>, <code>missionprofileArn</code>, and <code>satelliteArn</code>. </p>
+    ///
+    /// See `GroundStation::list_contacts`.
+    pub fn list_contacts(
+        &self,
+        input: ListContactsRequest,
+    ) -> Result<ListContactsResponse, RusotoError<ListContactsError>> {
+        self.runtime.block_on(self.client.list_contacts(input))
+    }
+
+    /// <p>Returns a list of <code>DataflowEndpoint</code> groups.</p>
+    ///
+    /// See `GroundStation::list_dataflow_endpoint_groups`.
+    pub fn list_dataflow_endpoint_groups(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> Result<ListDataflowEndpointGroupsResponse, RusotoError<ListDataflowEndpointGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_dataflow_endpoint_groups(input))
+    }
+
+    /// <p>Returns a list of ground stations. </p>
+    ///
+    /// See `GroundStation::list_ground_stations`.
+    pub fn list_ground_stations(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> Result<ListGroundStationsResponse, RusotoError<ListGroundStationsError>> {
+        self.runtime
+            .block_on(self.client.list_ground_stations(input))
+    }
+
+    /// <p>Returns a list of mission profiles.</p>
+    ///
+    /// See `GroundStation::list_mission_profiles`.
+    pub fn list_mission_profiles(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> Result<ListMissionProfilesResponse, RusotoError<ListMissionProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_mission_profiles(input))
+    }
+
+    /// <p>Returns a list of satellites.</p>
+    ///
+    /// See `GroundStation::list_satellites`.
+    pub fn list_satellites(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> Result<ListSatellitesResponse, RusotoError<ListSatellitesError>> {
+        self.runtime.block_on(self.client.list_satellites(input))
+    }
+
+    /// <p>Returns a list of tags for a specified resource.</p>
+    ///
+    /// See `GroundStation::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Reserves a contact using specified parameters.</p>
+    ///
+    /// See `GroundStation::reserve_contact`.
+    pub fn reserve_contact(
+        &self,
+        input: ReserveContactRequest,
+    ) -> Result<ContactIdResponse, RusotoError<ReserveContactError>> {
+        self.runtime.block_on(self.client.reserve_contact(input))
+    }
+
+    /// <p>Assigns a tag to a resource.</p>
+    ///
+    /// See `GroundStation::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deassigns a resource tag.</p>
+    ///
+    /// See `GroundStation::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the <code>Config</code> used when scheduling contacts.</p> <p>Updating a <code>Config</code> will not update the execution parameters for existing future contacts scheduled with this <code>Config</code>.</p>
+    ///
+    /// See `GroundStation::update_config`.
+    pub fn update_config(
+        &self,
+        input: UpdateConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<UpdateConfigError>> {
+        self.runtime.block_on(self.client.update_config(input))
+    }
+
+    /// <p>Updates a mission profile.</p> <p>Updating a mission profile will not update the execution parameters for existing future contacts.</p>
+    ///
+    /// See `GroundStation::update_mission_profile`.
+    pub fn update_mission_profile(
+        &self,
+        input: UpdateMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<UpdateMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.update_mission_profile(input))
+    }
+}
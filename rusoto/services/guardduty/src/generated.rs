@@ -2259,12 +2259,13 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AcceptInvitationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, AcceptInvitationError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AcceptInvitationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptInvitationError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2297,10 +2298,13 @@ impl ArchiveFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ArchiveFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ArchiveFindingsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ArchiveFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ArchiveFindingsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2333,10 +2337,13 @@ impl CreateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDetectorError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2369,10 +2376,13 @@ impl CreateFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFilterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateFilterError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFilterError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2405,10 +2415,13 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateIPSetError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIPSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2441,10 +2454,13 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMembersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateMembersError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2479,12 +2495,14 @@ impl CreatePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePublishingDestinationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePublishingDestinationError::InternalServerError(err.msg),
                     )
                 }
@@ -2521,12 +2539,16 @@ impl CreateSampleFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSampleFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSampleFindingsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSampleFindingsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSampleFindingsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2559,12 +2581,16 @@ impl CreateThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThreatIntelSetError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateThreatIntelSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2597,12 +2623,13 @@ impl DeclineInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeclineInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeclineInvitationsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeclineInvitationsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2635,10 +2662,13 @@ impl DeleteDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteDetectorError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2671,10 +2701,13 @@ impl DeleteFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFilterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteFilterError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFilterError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2707,10 +2740,13 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteIPSetError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIPSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2743,12 +2779,13 @@ impl DeleteInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteInvitationsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInvitationsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2781,10 +2818,13 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMembersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2819,12 +2859,14 @@ impl DeletePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePublishingDestinationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePublishingDestinationError::InternalServerError(err.msg),
                     )
                 }
@@ -2861,12 +2903,16 @@ impl DeleteThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThreatIntelSetError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThreatIntelSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2901,12 +2947,14 @@ impl DescribeOrganizationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigurationError::BadRequest(err.msg),
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationConfigurationError::InternalServerError(err.msg),
                     )
                 }
@@ -2945,12 +2993,14 @@ impl DescribePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePublishingDestinationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePublishingDestinationError::InternalServerError(err.msg),
                     )
                 }
@@ -2989,12 +3039,14 @@ impl DisableOrganizationAdminAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisableOrganizationAdminAccountError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableOrganizationAdminAccountError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableOrganizationAdminAccountError::InternalServerError(err.msg),
                     )
                 }
@@ -3033,12 +3085,14 @@ impl DisassociateFromMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFromMasterAccountError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateFromMasterAccountError::InternalServerError(err.msg),
                     )
                 }
@@ -3075,12 +3129,16 @@ impl DisassociateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateMembersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembersError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateMembersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3115,12 +3173,14 @@ impl EnableOrganizationAdminAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(EnableOrganizationAdminAccountError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableOrganizationAdminAccountError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableOrganizationAdminAccountError::InternalServerError(err.msg),
                     )
                 }
@@ -3157,10 +3217,13 @@ impl GetDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDetectorError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDetectorError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3193,10 +3256,10 @@ impl GetFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFilterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetFilterError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetFilterError::InternalServerError(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3229,10 +3292,13 @@ impl GetFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetFindingsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFindingsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3265,12 +3331,16 @@ impl GetFindingsStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFindingsStatisticsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFindingsStatisticsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFindingsStatisticsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFindingsStatisticsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3303,10 +3373,10 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetIPSetError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetIPSetError::InternalServerError(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3339,12 +3409,16 @@ impl GetInvitationsCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetInvitationsCountError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInvitationsCountError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInvitationsCountError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3377,12 +3451,13 @@ impl GetMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMasterAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetMasterAccountError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMasterAccountError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMasterAccountError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3415,10 +3490,13 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMembersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetMembersError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3451,12 +3529,13 @@ impl GetThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetThreatIntelSetError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetThreatIntelSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3489,10 +3568,13 @@ impl InviteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteMembersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, InviteMembersError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(InviteMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InviteMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3525,10 +3607,13 @@ impl ListDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDetectorsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListDetectorsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDetectorsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3561,10 +3646,13 @@ impl ListFiltersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFiltersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListFiltersError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFiltersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFiltersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3597,10 +3685,13 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFindingsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3633,10 +3724,13 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListIPSetsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListIPSetsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIPSetsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3669,10 +3763,13 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInvitationsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3705,10 +3802,13 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMembersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListMembersError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMembersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3743,12 +3843,14 @@ impl ListOrganizationAdminAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListOrganizationAdminAccountsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOrganizationAdminAccountsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationAdminAccountsError::InternalServerError(err.msg),
                     )
                 }
@@ -3787,12 +3889,14 @@ impl ListPublishingDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPublishingDestinationsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPublishingDestinationsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPublishingDestinationsError::InternalServerError(err.msg),
                     )
                 }
@@ -3829,12 +3933,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3867,12 +3975,16 @@ impl ListThreatIntelSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListThreatIntelSetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThreatIntelSetsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListThreatIntelSetsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThreatIntelSetsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3905,12 +4017,16 @@ impl StartMonitoringMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMonitoringMembersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringMembersError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartMonitoringMembersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3943,12 +4059,16 @@ impl StopMonitoringMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopMonitoringMembersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopMonitoringMembersError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopMonitoringMembersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopMonitoringMembersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3981,10 +4101,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4017,12 +4140,13 @@ impl UnarchiveFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UnarchiveFindingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UnarchiveFindingsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UnarchiveFindingsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnarchiveFindingsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4055,10 +4179,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4091,10 +4218,13 @@ impl UpdateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDetectorError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDetectorError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4127,10 +4257,13 @@ impl UpdateFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFilterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateFilterError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFilterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFilterError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4163,12 +4296,16 @@ impl UpdateFindingsFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFindingsFeedbackError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFindingsFeedbackError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFindingsFeedbackError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFindingsFeedbackError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4201,10 +4338,13 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIPSetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateIPSetError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIPSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4239,12 +4379,14 @@ impl UpdateOrganizationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateOrganizationConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOrganizationConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateOrganizationConfigurationError::InternalServerError(err.msg),
                     )
                 }
@@ -4283,12 +4425,14 @@ impl UpdatePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePublishingDestinationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePublishingDestinationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePublishingDestinationError::InternalServerError(err.msg),
                     )
                 }
@@ -4325,12 +4469,16 @@ impl UpdateThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThreatIntelSetError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateThreatIntelSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThreatIntelSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4720,6 +4868,7 @@ impl GuardDutyClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4735,6 +4884,7 @@ impl GuardDutyClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyClient {
         GuardDutyClient { client, region }
     }
@@ -4754,6 +4904,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("AcceptInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4788,6 +4941,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ArchiveFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4819,6 +4975,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreateDetector");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4853,6 +5012,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreateFilter");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4887,6 +5049,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreateIPSet");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4921,6 +5086,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreateMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4956,6 +5124,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreatePublishingDestination");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4990,6 +5161,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreateSampleFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5024,6 +5198,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("CreateThreatIntelSet");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5055,6 +5232,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/decline";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeclineInvitations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5086,6 +5266,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeleteDetector");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5118,6 +5301,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeleteFilter");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5150,6 +5336,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeleteIPSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5178,6 +5367,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/delete";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeleteInvitations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5212,6 +5404,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeleteMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5248,6 +5443,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeletePublishingDestination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5280,6 +5478,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DeleteThreatIntelSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5314,6 +5515,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DescribeOrganizationConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5351,6 +5555,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DescribePublishingDestination");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5382,6 +5589,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin/disable";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DisableOrganizationAdminAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5421,6 +5631,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DisassociateFromMasterAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5452,6 +5665,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("DisassociateMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5486,6 +5702,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin/enable";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("EnableOrganizationAdminAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5517,6 +5736,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetDetector");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5549,6 +5771,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetFilter");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5580,6 +5805,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5614,6 +5842,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetFindingsStatistics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5649,6 +5880,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetIPSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5676,6 +5910,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/count";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetInvitationsCount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5707,6 +5944,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetMasterAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5738,6 +5978,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5773,6 +6016,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("GetThreatIntelSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5804,6 +6050,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("InviteMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5835,6 +6084,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListDetectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5875,6 +6127,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5915,6 +6170,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5949,6 +6207,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListIPSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5986,6 +6247,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListInvitations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6026,6 +6290,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6069,6 +6336,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListOrganizationAdminAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6110,6 +6380,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListPublishingDestinations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6147,6 +6420,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6178,6 +6454,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("ListThreatIntelSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6218,6 +6497,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("StartMonitoringMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6252,6 +6534,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("StopMonitoringMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6283,6 +6568,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6317,6 +6605,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UnarchiveFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6348,6 +6639,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6382,6 +6676,9 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdateDetector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6417,6 +6714,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdateFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6451,6 +6751,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdateFindingsFeedback");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6486,6 +6789,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdateIPSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6523,6 +6829,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdateOrganizationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6561,6 +6870,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdatePublishingDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6596,6 +6908,9 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation_name("UpdateThreatIntelSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6618,3 +6933,651 @@ impl GuardDuty for GuardDutyClient {
         }
     }
 }
+
+/// A blocking client for the Amazon GuardDuty API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `GuardDutyClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GuardDutyBlockingClient {
+    client: GuardDutyClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GuardDutyBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GuardDutyBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        GuardDutyBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GuardDutyBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GuardDutyBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient {
+            client: GuardDutyClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts the invitation to be monitored by a master GuardDuty account.</p>
+    ///
+    /// See `GuardDuty::accept_invitation`.
+    pub fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+        self.runtime.block_on(self.client.accept_invitation(input))
+    }
+
+    /// <p><p>Archives GuardDuty findings that are specified by the list of finding IDs.</p> <note> <p>Only the master account can archive findings. Member accounts don&#39;t have permission to archive findings from their accounts.</p> </note></p>
+    ///
+    /// See `GuardDuty::archive_findings`.
+    pub fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> Result<ArchiveFindingsResponse, RusotoError<ArchiveFindingsError>> {
+        self.runtime.block_on(self.client.archive_findings(input))
+    }
+
+    /// <p>Creates a single Amazon GuardDuty detector. A detector is a resource that represents the GuardDuty service. To start using GuardDuty, you must create a detector in each Region where you enable the service. You can have only one detector per account per Region.</p>
+    ///
+    /// See `GuardDuty::create_detector`.
+    pub fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> Result<CreateDetectorResponse, RusotoError<CreateDetectorError>> {
+        self.runtime.block_on(self.client.create_detector(input))
+    }
+
+    /// <p>Creates a filter using the specified finding criteria.</p>
+    ///
+    /// See `GuardDuty::create_filter`.
+    pub fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+        self.runtime.block_on(self.client.create_filter(input))
+    }
+
+    /// <p>Creates a new IPSet, which is called a trusted IP list in the console user interface. An IPSet is a list of IP addresses that are trusted for secure communication with AWS infrastructure and applications. GuardDuty doesn't generate findings for IP addresses that are included in IPSets. Only users from the master account can use this operation.</p>
+    ///
+    /// See `GuardDuty::create_ip_set`.
+    pub fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.runtime.block_on(self.client.create_ip_set(input))
+    }
+
+    /// <p>Creates member accounts of the current AWS account by specifying a list of AWS account IDs. The current AWS account can then invite these members to manage GuardDuty in their accounts.</p>
+    ///
+    /// See `GuardDuty::create_members`.
+    pub fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.runtime.block_on(self.client.create_members(input))
+    }
+
+    /// <p>Creates a publishing destination to export findings to. The resource to export findings to must exist before you use this operation.</p>
+    ///
+    /// See `GuardDuty::create_publishing_destination`.
+    pub fn create_publishing_destination(
+        &self,
+        input: CreatePublishingDestinationRequest,
+    ) -> Result<CreatePublishingDestinationResponse, RusotoError<CreatePublishingDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_publishing_destination(input))
+    }
+
+    /// <p>Generates example findings of types specified by the list of finding types. If 'NULL' is specified for <code>findingTypes</code>, the API generates example findings of all supported finding types.</p>
+    ///
+    /// See `GuardDuty::create_sample_findings`.
+    pub fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> Result<CreateSampleFindingsResponse, RusotoError<CreateSampleFindingsError>> {
+        self.runtime
+            .block_on(self.client.create_sample_findings(input))
+    }
+
+    /// <p>Creates a new ThreatIntelSet. ThreatIntelSets consist of known malicious IP addresses. GuardDuty generates findings based on ThreatIntelSets. Only users of the master account can use this operation.</p>
+    ///
+    /// See `GuardDuty::create_threat_intel_set`.
+    pub fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> Result<CreateThreatIntelSetResponse, RusotoError<CreateThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.create_threat_intel_set(input))
+    }
+
+    /// <p>Declines invitations sent to the current member account by AWS accounts specified by their account IDs.</p>
+    ///
+    /// See `GuardDuty::decline_invitations`.
+    pub fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+        self.runtime
+            .block_on(self.client.decline_invitations(input))
+    }
+
+    /// <p>Deletes an Amazon GuardDuty detector that is specified by the detector ID.</p>
+    ///
+    /// See `GuardDuty::delete_detector`.
+    pub fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> Result<DeleteDetectorResponse, RusotoError<DeleteDetectorError>> {
+        self.runtime.block_on(self.client.delete_detector(input))
+    }
+
+    /// <p>Deletes the filter specified by the filter name.</p>
+    ///
+    /// See `GuardDuty::delete_filter`.
+    pub fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> Result<DeleteFilterResponse, RusotoError<DeleteFilterError>> {
+        self.runtime.block_on(self.client.delete_filter(input))
+    }
+
+    /// <p>Deletes the IPSet specified by the <code>ipSetId</code>. IPSets are called trusted IP lists in the console user interface.</p>
+    ///
+    /// See `GuardDuty::delete_ip_set`.
+    pub fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.runtime.block_on(self.client.delete_ip_set(input))
+    }
+
+    /// <p>Deletes invitations sent to the current member account by AWS accounts specified by their account IDs.</p>
+    ///
+    /// See `GuardDuty::delete_invitations`.
+    pub fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+        self.runtime.block_on(self.client.delete_invitations(input))
+    }
+
+    /// <p>Deletes GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    ///
+    /// See `GuardDuty::delete_members`.
+    pub fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.runtime.block_on(self.client.delete_members(input))
+    }
+
+    /// <p>Deletes the publishing definition with the specified <code>destinationId</code>.</p>
+    ///
+    /// See `GuardDuty::delete_publishing_destination`.
+    pub fn delete_publishing_destination(
+        &self,
+        input: DeletePublishingDestinationRequest,
+    ) -> Result<DeletePublishingDestinationResponse, RusotoError<DeletePublishingDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_publishing_destination(input))
+    }
+
+    /// <p>Deletes the ThreatIntelSet specified by the ThreatIntelSet ID.</p>
+    ///
+    /// See `GuardDuty::delete_threat_intel_set`.
+    pub fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> Result<DeleteThreatIntelSetResponse, RusotoError<DeleteThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.delete_threat_intel_set(input))
+    }
+
+    /// <p>Returns information about the account selected as the delegated administrator for GuardDuty.</p>
+    ///
+    /// See `GuardDuty::describe_organization_configuration`.
+    pub fn describe_organization_configuration(
+        &self,
+        input: DescribeOrganizationConfigurationRequest,
+    ) -> Result<
+        DescribeOrganizationConfigurationResponse,
+        RusotoError<DescribeOrganizationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_configuration(input))
+    }
+
+    /// <p>Returns information about the publishing destination specified by the provided <code>destinationId</code>.</p>
+    ///
+    /// See `GuardDuty::describe_publishing_destination`.
+    pub fn describe_publishing_destination(
+        &self,
+        input: DescribePublishingDestinationRequest,
+    ) -> Result<
+        DescribePublishingDestinationResponse,
+        RusotoError<DescribePublishingDestinationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_publishing_destination(input))
+    }
+
+    /// <p>Disables an AWS account within the Organization as the GuardDuty delegated administrator.</p>
+    ///
+    /// See `GuardDuty::disable_organization_admin_account`.
+    pub fn disable_organization_admin_account(
+        &self,
+        input: DisableOrganizationAdminAccountRequest,
+    ) -> Result<
+        DisableOrganizationAdminAccountResponse,
+        RusotoError<DisableOrganizationAdminAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_organization_admin_account(input))
+    }
+
+    /// <p>Disassociates the current GuardDuty member account from its master account.</p>
+    ///
+    /// See `GuardDuty::disassociate_from_master_account`.
+    pub fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> Result<
+        DisassociateFromMasterAccountResponse,
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_from_master_account(input))
+    }
+
+    /// <p>Disassociates GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    ///
+    /// See `GuardDuty::disassociate_members`.
+    pub fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+        self.runtime
+            .block_on(self.client.disassociate_members(input))
+    }
+
+    /// <p>Enables an AWS account within the organization as the GuardDuty delegated administrator.</p>
+    ///
+    /// See `GuardDuty::enable_organization_admin_account`.
+    pub fn enable_organization_admin_account(
+        &self,
+        input: EnableOrganizationAdminAccountRequest,
+    ) -> Result<
+        EnableOrganizationAdminAccountResponse,
+        RusotoError<EnableOrganizationAdminAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_organization_admin_account(input))
+    }
+
+    /// <p>Retrieves an Amazon GuardDuty detector specified by the detectorId.</p>
+    ///
+    /// See `GuardDuty::get_detector`.
+    pub fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> Result<GetDetectorResponse, RusotoError<GetDetectorError>> {
+        self.runtime.block_on(self.client.get_detector(input))
+    }
+
+    /// <p>Returns the details of the filter specified by the filter name.</p>
+    ///
+    /// See `GuardDuty::get_filter`.
+    pub fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> Result<GetFilterResponse, RusotoError<GetFilterError>> {
+        self.runtime.block_on(self.client.get_filter(input))
+    }
+
+    /// <p>Describes Amazon GuardDuty findings specified by finding IDs.</p>
+    ///
+    /// See `GuardDuty::get_findings`.
+    pub fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+        self.runtime.block_on(self.client.get_findings(input))
+    }
+
+    /// <p>Lists Amazon GuardDuty findings statistics for the specified detector ID.</p>
+    ///
+    /// See `GuardDuty::get_findings_statistics`.
+    pub fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> Result<GetFindingsStatisticsResponse, RusotoError<GetFindingsStatisticsError>> {
+        self.runtime
+            .block_on(self.client.get_findings_statistics(input))
+    }
+
+    /// <p>Retrieves the IPSet specified by the <code>ipSetId</code>.</p>
+    ///
+    /// See `GuardDuty::get_ip_set`.
+    pub fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.runtime.block_on(self.client.get_ip_set(input))
+    }
+
+    /// <p>Returns the count of all GuardDuty membership invitations that were sent to the current member account except the currently accepted invitation.</p>
+    ///
+    /// See `GuardDuty::get_invitations_count`.
+    pub fn get_invitations_count(
+        &self,
+    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        self.runtime.block_on(self.client.get_invitations_count())
+    }
+
+    /// <p>Provides the details for the GuardDuty master account associated with the current GuardDuty member account.</p>
+    ///
+    /// See `GuardDuty::get_master_account`.
+    pub fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        self.runtime.block_on(self.client.get_master_account(input))
+    }
+
+    /// <p>Retrieves GuardDuty member accounts (to the current GuardDuty master account) specified by the account IDs.</p>
+    ///
+    /// See `GuardDuty::get_members`.
+    pub fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.runtime.block_on(self.client.get_members(input))
+    }
+
+    /// <p>Retrieves the ThreatIntelSet that is specified by the ThreatIntelSet ID.</p>
+    ///
+    /// See `GuardDuty::get_threat_intel_set`.
+    pub fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> Result<GetThreatIntelSetResponse, RusotoError<GetThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.get_threat_intel_set(input))
+    }
+
+    /// <p>Invites other AWS accounts (created as members of the current AWS account by CreateMembers) to enable GuardDuty, and allow the current AWS account to view and manage these accounts' GuardDuty findings on their behalf as the master account.</p>
+    ///
+    /// See `GuardDuty::invite_members`.
+    pub fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+        self.runtime.block_on(self.client.invite_members(input))
+    }
+
+    /// <p>Lists detectorIds of all the existing Amazon GuardDuty detector resources.</p>
+    ///
+    /// See `GuardDuty::list_detectors`.
+    pub fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+        self.runtime.block_on(self.client.list_detectors(input))
+    }
+
+    /// <p>Returns a paginated list of the current filters.</p>
+    ///
+    /// See `GuardDuty::list_filters`.
+    pub fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
+        self.runtime.block_on(self.client.list_filters(input))
+    }
+
+    /// <p>Lists Amazon GuardDuty findings for the specified detector ID.</p>
+    ///
+    /// See `GuardDuty::list_findings`.
+    pub fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.runtime.block_on(self.client.list_findings(input))
+    }
+
+    /// <p>Lists the IPSets of the GuardDuty service specified by the detector ID. If you use this operation from a member account, the IPSets returned are the IPSets from the associated master account.</p>
+    ///
+    /// See `GuardDuty::list_ip_sets`.
+    pub fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.runtime.block_on(self.client.list_ip_sets(input))
+    }
+
+    /// <p>Lists all GuardDuty membership invitations that were sent to the current AWS account.</p>
+    ///
+    /// See `GuardDuty::list_invitations`.
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// <p>Lists details about all member accounts for the current GuardDuty master account.</p>
+    ///
+    /// See `GuardDuty::list_members`.
+    pub fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// <p>Lists the accounts configured as GuardDuty delegated administrators.</p>
+    ///
+    /// See `GuardDuty::list_organization_admin_accounts`.
+    pub fn list_organization_admin_accounts(
+        &self,
+        input: ListOrganizationAdminAccountsRequest,
+    ) -> Result<
+        ListOrganizationAdminAccountsResponse,
+        RusotoError<ListOrganizationAdminAccountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organization_admin_accounts(input))
+    }
+
+    /// <p>Returns a list of publishing destinations associated with the specified <code>dectectorId</code>.</p>
+    ///
+    /// See `GuardDuty::list_publishing_destinations`.
+    pub fn list_publishing_destinations(
+        &self,
+        input: ListPublishingDestinationsRequest,
+    ) -> Result<ListPublishingDestinationsResponse, RusotoError<ListPublishingDestinationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_publishing_destinations(input))
+    }
+
+    /// <p>Lists tags for a resource. Tagging is currently supported for detectors, finding filters, IP sets, and threat intel sets, with a limit of 50 tags per resource. When invoked, this operation returns all assigned tags for a given resource.</p>
+    ///
+    /// See `GuardDuty::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the ThreatIntelSets of the GuardDuty service specified by the detector ID. If you use this operation from a member account, the ThreatIntelSets associated with the master account are returned.</p>
+    ///
+    /// See `GuardDuty::list_threat_intel_sets`.
+    pub fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> Result<ListThreatIntelSetsResponse, RusotoError<ListThreatIntelSetsError>> {
+        self.runtime
+            .block_on(self.client.list_threat_intel_sets(input))
+    }
+
+    /// <p>Turns on GuardDuty monitoring of the specified member accounts. Use this operation to restart monitoring of accounts that you stopped monitoring with the <code>StopMonitoringMembers</code> operation.</p>
+    ///
+    /// See `GuardDuty::start_monitoring_members`.
+    pub fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> Result<StartMonitoringMembersResponse, RusotoError<StartMonitoringMembersError>> {
+        self.runtime
+            .block_on(self.client.start_monitoring_members(input))
+    }
+
+    /// <p>Stops GuardDuty monitoring for the specified member accounts. Use the <code>StartMonitoringMembers</code> operation to restart monitoring for those accounts.</p>
+    ///
+    /// See `GuardDuty::stop_monitoring_members`.
+    pub fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> Result<StopMonitoringMembersResponse, RusotoError<StopMonitoringMembersError>> {
+        self.runtime
+            .block_on(self.client.stop_monitoring_members(input))
+    }
+
+    /// <p>Adds tags to a resource.</p>
+    ///
+    /// See `GuardDuty::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Unarchives GuardDuty findings specified by the <code>findingIds</code>.</p>
+    ///
+    /// See `GuardDuty::unarchive_findings`.
+    pub fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> Result<UnarchiveFindingsResponse, RusotoError<UnarchiveFindingsError>> {
+        self.runtime.block_on(self.client.unarchive_findings(input))
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    ///
+    /// See `GuardDuty::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the Amazon GuardDuty detector specified by the detectorId.</p>
+    ///
+    /// See `GuardDuty::update_detector`.
+    pub fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> Result<UpdateDetectorResponse, RusotoError<UpdateDetectorError>> {
+        self.runtime.block_on(self.client.update_detector(input))
+    }
+
+    /// <p>Updates the filter specified by the filter name.</p>
+    ///
+    /// See `GuardDuty::update_filter`.
+    pub fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> Result<UpdateFilterResponse, RusotoError<UpdateFilterError>> {
+        self.runtime.block_on(self.client.update_filter(input))
+    }
+
+    /// <p>Marks the specified GuardDuty findings as useful or not useful.</p>
+    ///
+    /// See `GuardDuty::update_findings_feedback`.
+    pub fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> Result<UpdateFindingsFeedbackResponse, RusotoError<UpdateFindingsFeedbackError>> {
+        self.runtime
+            .block_on(self.client.update_findings_feedback(input))
+    }
+
+    /// <p>Updates the IPSet specified by the IPSet ID.</p>
+    ///
+    /// See `GuardDuty::update_ip_set`.
+    pub fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.runtime.block_on(self.client.update_ip_set(input))
+    }
+
+    /// <p>Updates the delegated administrator account with the values provided.</p>
+    ///
+    /// See `GuardDuty::update_organization_configuration`.
+    pub fn update_organization_configuration(
+        &self,
+        input: UpdateOrganizationConfigurationRequest,
+    ) -> Result<
+        UpdateOrganizationConfigurationResponse,
+        RusotoError<UpdateOrganizationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_organization_configuration(input))
+    }
+
+    /// <p>Updates information about the publishing destination specified by the <code>destinationId</code>.</p>
+    ///
+    /// See `GuardDuty::update_publishing_destination`.
+    pub fn update_publishing_destination(
+        &self,
+        input: UpdatePublishingDestinationRequest,
+    ) -> Result<UpdatePublishingDestinationResponse, RusotoError<UpdatePublishingDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_publishing_destination(input))
+    }
+
+    /// <p>Updates the ThreatIntelSet specified by the ThreatIntelSet ID.</p>
+    ///
+    /// See `GuardDuty::update_threat_intel_set`.
+    pub fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> Result<UpdateThreatIntelSetResponse, RusotoError<UpdateThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.update_threat_intel_set(input))
+    }
+}
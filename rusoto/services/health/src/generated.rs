@@ -817,7 +817,8 @@ impl DescribeAffectedAccountsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAffectedAccountsForOrganizationError::InvalidPaginationToken(
                             err.msg,
                         ),
@@ -855,14 +856,16 @@ impl DescribeAffectedEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAffectedEntitiesError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeAffectedEntitiesError::UnsupportedLocale(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAffectedEntitiesError::UnsupportedLocale(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -899,14 +902,16 @@ impl DescribeAffectedEntitiesForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAffectedEntitiesForOrganizationError::InvalidPaginationToken(
                             err.msg,
                         ),
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAffectedEntitiesForOrganizationError::UnsupportedLocale(err.msg),
                     )
                 }
@@ -965,7 +970,8 @@ impl DescribeEventAggregatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEventAggregatesError::InvalidPaginationToken(err.msg),
                     )
                 }
@@ -999,9 +1005,10 @@ impl DescribeEventDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventDetailsError::UnsupportedLocale(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventDetailsError::UnsupportedLocale(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1033,7 +1040,8 @@ impl DescribeEventDetailsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEventDetailsForOrganizationError::UnsupportedLocale(err.msg),
                     )
                 }
@@ -1069,14 +1077,16 @@ impl DescribeEventTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(DescribeEventTypesError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventTypesError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventTypesError::UnsupportedLocale(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventTypesError::UnsupportedLocale(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1109,12 +1119,16 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventsError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventsError::UnsupportedLocale(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventsError::UnsupportedLocale(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1149,12 +1163,14 @@ impl DescribeEventsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEventsForOrganizationError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEventsForOrganizationError::UnsupportedLocale(err.msg),
                     )
                 }
@@ -1217,7 +1233,8 @@ impl DisableHealthServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableHealthServiceAccessForOrganizationError::ConcurrentModification(
                             err.msg,
                         ),
@@ -1255,7 +1272,8 @@ impl EnableHealthServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableHealthServiceAccessForOrganizationError::ConcurrentModification(
                             err.msg,
                         ),
@@ -1390,6 +1408,7 @@ impl AWSHealthClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1405,6 +1424,7 @@ impl AWSHealthClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AWSHealthClient {
         AWSHealthClient { client, region }
     }
@@ -1421,6 +1441,9 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeAffectedAccountsForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAffectedAccountsForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeAffectedAccountsForOrganization",
@@ -1446,6 +1469,9 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeAffectedEntitiesRequest,
     ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAffectedEntities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeAffectedEntities",
@@ -1471,6 +1497,9 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeAffectedEntitiesForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAffectedEntitiesForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeAffectedEntitiesForOrganization",
@@ -1496,6 +1525,9 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEntityAggregatesRequest,
     ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEntityAggregates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeEntityAggregates",
@@ -1518,6 +1550,9 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventAggregatesRequest,
     ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventAggregates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventAggregates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1537,6 +1572,9 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventDetailsRequest,
     ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1559,6 +1597,9 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeEventDetailsForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventDetailsForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeEventDetailsForOrganization",
@@ -1584,6 +1625,9 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventTypesRequest,
     ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1602,6 +1646,9 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1623,6 +1670,9 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeEventsForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventsForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeEventsForOrganization",
@@ -1647,6 +1697,9 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeHealthServiceStatusForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHealthServiceStatusForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeHealthServiceStatusForOrganization",
@@ -1670,6 +1723,9 @@ impl AWSHealth for AWSHealthClient {
         &self,
     ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableHealthServiceAccessForOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DisableHealthServiceAccessForOrganization",
@@ -1691,6 +1747,9 @@ impl AWSHealth for AWSHealthClient {
         &self,
     ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableHealthServiceAccessForOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.EnableHealthServiceAccessForOrganization",
@@ -1707,3 +1766,213 @@ impl AWSHealth for AWSHealthClient {
         Ok(())
     }
 }
+
+/// A blocking client for the AWSHealth API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AWSHealthClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AWSHealthBlockingClient {
+    client: AWSHealthClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AWSHealthBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AWSHealthBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AWSHealthBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AWSHealthBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AWSHealthBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient {
+            client: AWSHealthClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns a list of accounts in the organization from AWS Organizations that are affected by the provided event.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
+    ///
+    /// See `AWSHealth::describe_affected_accounts_for_organization`.
+    pub fn describe_affected_accounts_for_organization(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> Result<
+        DescribeAffectedAccountsForOrganizationResponse,
+        RusotoError<DescribeAffectedAccountsForOrganizationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_affected_accounts_for_organization(input),
+        )
+    }
+
+    /// <p>Returns a list of entities that have been affected by the specified events, based on the specified filter criteria. Entities can refer to individual customer resources, groups of customer resources, or any other construct, depending on the AWS service. Events that have impact beyond that of the affected entities, or where the extent of impact is unknown, include at least one entity indicating this.</p> <p>At least one event ARN is required. Results are sorted by the <code>lastUpdatedTime</code> of the entity, starting with the most recent.</p>
+    ///
+    /// See `AWSHealth::describe_affected_entities`.
+    pub fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
+        self.runtime
+            .block_on(self.client.describe_affected_entities(input))
+    }
+
+    /// <p>Returns a list of entities that have been affected by one or more events for one or more accounts in your organization in AWS Organizations, based on the filter criteria. Entities can refer to individual customer resources, groups of customer resources, or any other construct, depending on the AWS service.</p> <p>At least one event ARN and account ID are required. Results are sorted by the <code>lastUpdatedTime</code> of the entity, starting with the most recent.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account. </p>
+    ///
+    /// See `AWSHealth::describe_affected_entities_for_organization`.
+    pub fn describe_affected_entities_for_organization(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> Result<
+        DescribeAffectedEntitiesForOrganizationResponse,
+        RusotoError<DescribeAffectedEntitiesForOrganizationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_affected_entities_for_organization(input),
+        )
+    }
+
+    /// <p>Returns the number of entities that are affected by each of the specified events. If no events are specified, the counts of all affected entities are returned.</p>
+    ///
+    /// See `AWSHealth::describe_entity_aggregates`.
+    pub fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
+        self.runtime
+            .block_on(self.client.describe_entity_aggregates(input))
+    }
+
+    /// <p>Returns the number of events of each event type (issue, scheduled change, and account notification). If no filter is specified, the counts of all events in each category are returned.</p>
+    ///
+    /// See `AWSHealth::describe_event_aggregates`.
+    pub fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_aggregates(input))
+    }
+
+    /// <p>Returns detailed information about one or more specified events. Information includes standard event data (region, service, and so on, as returned by <a>DescribeEvents</a>), a detailed event description, and possible additional metadata that depends upon the nature of the event. Affected entities are not included; to retrieve those, use the <a>DescribeAffectedEntities</a> operation.</p> <p>If a specified event cannot be retrieved, an error message is returned for that event.</p>
+    ///
+    /// See `AWSHealth::describe_event_details`.
+    pub fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_details(input))
+    }
+
+    /// <p>Returns detailed information about one or more specified events for one or more accounts in your organization. Information includes standard event data (Region, service, and so on, as returned by <a>DescribeEventsForOrganization</a>, a detailed event description, and possible additional metadata that depends upon the nature of the event. Affected entities are not included; to retrieve those, use the <a>DescribeAffectedEntitiesForOrganization</a> operation.</p> <p>Before you can call this operation, you must first enable AWS Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
+    ///
+    /// See `AWSHealth::describe_event_details_for_organization`.
+    pub fn describe_event_details_for_organization(
+        &self,
+        input: DescribeEventDetailsForOrganizationRequest,
+    ) -> Result<
+        DescribeEventDetailsForOrganizationResponse,
+        RusotoError<DescribeEventDetailsForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_event_details_for_organization(input))
+    }
+
+    /// <p>Returns the event types that meet the specified filter criteria. If no filter criteria are specified, all event types are returned, in no particular order.</p>
+    ///
+    /// See `AWSHealth::describe_event_types`.
+    pub fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_types(input))
+    }
+
+    /// <p>Returns information about events that meet the specified filter criteria. Events are returned in a summary form and do not include the detailed description, any additional metadata that depends on the event type, or any affected resources. To retrieve that information, use the <a>DescribeEventDetails</a> and <a>DescribeAffectedEntities</a> operations.</p> <p>If no filter criteria are specified, all events are returned. Results are sorted by <code>lastModifiedTime</code>, starting with the most recent.</p>
+    ///
+    /// See `AWSHealth::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns information about events across your organization in AWS Organizations, meeting the specified filter criteria. Events are returned in a summary form and do not include the accounts impacted, detailed description, any additional metadata that depends on the event type, or any affected resources. To retrieve that information, use the <a>DescribeAffectedAccountsForOrganization</a>, <a>DescribeEventDetailsForOrganization</a>, and <a>DescribeAffectedEntitiesForOrganization</a> operations.</p> <p>If no filter criteria are specified, all events across your organization are returned. Results are sorted by <code>lastModifiedTime</code>, starting with the most recent.</p> <p>Before you can call this operation, you must first enable Health to work with AWS Organizations. To do this, call the <a>EnableHealthServiceAccessForOrganization</a> operation from your organization's master account.</p>
+    ///
+    /// See `AWSHealth::describe_events_for_organization`.
+    pub fn describe_events_for_organization(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> Result<
+        DescribeEventsForOrganizationResponse,
+        RusotoError<DescribeEventsForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_events_for_organization(input))
+    }
+
+    /// <p>This operation provides status information on enabling or disabling AWS Health to work with your organization. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
+    ///
+    /// See `AWSHealth::describe_health_service_status_for_organization`.
+    pub fn describe_health_service_status_for_organization(
+        &self,
+    ) -> Result<
+        DescribeHealthServiceStatusForOrganizationResponse,
+        RusotoError<DescribeHealthServiceStatusForOrganizationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_health_service_status_for_organization(),
+        )
+    }
+
+    /// <p>Calling this operation disables Health from working with AWS Organizations. This does not remove the Service Linked Role (SLR) from the the master account in your organization. Use the IAM console, API, or AWS CLI to remove the SLR if desired. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
+    ///
+    /// See `AWSHealth::disable_health_service_access_for_organization`.
+    pub fn disable_health_service_access_for_organization(
+        &self,
+    ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
+        self.runtime
+            .block_on(self.client.disable_health_service_access_for_organization())
+    }
+
+    /// <p>Calling this operation enables AWS Health to work with AWS Organizations. This applies a Service Linked Role (SLR) to the master account in the organization. To learn more about the steps in this process, visit enabling service access for AWS Health in AWS Organizations. To call this operation, you must sign in as an IAM user, assume an IAM role, or sign in as the root user (not recommended) in the organization's master account.</p>
+    ///
+    /// See `AWSHealth::enable_health_service_access_for_organization`.
+    pub fn enable_health_service_access_for_organization(
+        &self,
+    ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
+        self.runtime
+            .block_on(self.client.enable_health_service_access_for_organization())
+    }
+}
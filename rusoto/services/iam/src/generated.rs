@@ -11370,28 +11370,32 @@ impl AddClientIDToOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddClientIDToOpenIDConnectProviderError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddClientIDToOpenIDConnectProviderError::LimitExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddClientIDToOpenIDConnectProviderError::NoSuchEntity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddClientIDToOpenIDConnectProviderError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -11456,29 +11460,34 @@ impl AddRoleToInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToInstanceProfileError::EntityAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AddRoleToInstanceProfileError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddRoleToInstanceProfileError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AddRoleToInstanceProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddRoleToInstanceProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AddRoleToInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddRoleToInstanceProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToInstanceProfileError::UnmodifiableEntity(parsed_error.message),
                         )
                     }
@@ -11530,19 +11539,22 @@ impl AddUserToGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(AddUserToGroupError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddUserToGroupError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AddUserToGroupError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddUserToGroupError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AddUserToGroupError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddUserToGroupError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11594,29 +11606,34 @@ impl AttachGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(AttachGroupPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachGroupPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AttachGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachGroupPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AttachGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachGroupPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(AttachGroupPolicyError::PolicyNotAttachable(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachGroupPolicyError::PolicyNotAttachable(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AttachGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachGroupPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11672,34 +11689,40 @@ impl AttachRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(AttachRolePolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachRolePolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AttachRolePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachRolePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AttachRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachRolePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(AttachRolePolicyError::PolicyNotAttachable(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachRolePolicyError::PolicyNotAttachable(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AttachRolePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachRolePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(AttachRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachRolePolicyError::UnmodifiableEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11754,29 +11777,34 @@ impl AttachUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(AttachUserPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachUserPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AttachUserPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachUserPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AttachUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachUserPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(AttachUserPolicyError::PolicyNotAttachable(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachUserPolicyError::PolicyNotAttachable(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AttachUserPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AttachUserPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11832,36 +11860,42 @@ impl ChangePasswordError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangePasswordError::EntityTemporarilyUnmodifiable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidUserType" => {
-                        return RusotoError::Service(ChangePasswordError::InvalidUserType(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangePasswordError::InvalidUserType(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(ChangePasswordError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangePasswordError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ChangePasswordError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangePasswordError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PasswordPolicyViolation" => {
-                        return RusotoError::Service(ChangePasswordError::PasswordPolicyViolation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangePasswordError::PasswordPolicyViolation(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ChangePasswordError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangePasswordError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11912,19 +11946,22 @@ impl CreateAccessKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateAccessKeyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateAccessKeyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateAccessKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateAccessKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateAccessKeyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateAccessKeyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11972,19 +12009,22 @@ impl CreateAccountAliasError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateAccountAliasError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateAccountAliasError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateAccountAliasError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateAccountAliasError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateAccountAliasError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateAccountAliasError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12034,24 +12074,28 @@ impl CreateGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateGroupError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateGroupError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateGroupError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateGroupError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateGroupError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateGroupError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateGroupError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateGroupError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12100,19 +12144,22 @@ impl CreateInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateInstanceProfileError::EntityAlreadyExists(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateInstanceProfileError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInstanceProfileError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateInstanceProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12164,29 +12211,34 @@ impl CreateLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateLoginProfileError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoginProfileError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateLoginProfileError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoginProfileError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoginProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PasswordPolicyViolation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateLoginProfileError::PasswordPolicyViolation(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateLoginProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateLoginProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12240,24 +12292,28 @@ impl CreateOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOpenIDConnectProviderError::EntityAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOpenIDConnectProviderError::InvalidInput(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOpenIDConnectProviderError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -12314,29 +12370,34 @@ impl CreatePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreatePolicyError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreatePolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreatePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(CreatePolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyError::MalformedPolicyDocument(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreatePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12390,29 +12451,34 @@ impl CreatePolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreatePolicyVersionError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyVersionError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreatePolicyVersionError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyVersionError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreatePolicyVersionError::MalformedPolicyDocument(parsed_error.message),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreatePolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyVersionError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreatePolicyVersionError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePolicyVersionError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12468,34 +12534,40 @@ impl CreateRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(CreateRoleError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRoleError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateRoleError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRoleError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateRoleError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRoleError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateRoleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRoleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(CreateRoleError::MalformedPolicyDocument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRoleError::MalformedPolicyDocument(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12548,24 +12620,28 @@ impl CreateSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateSAMLProviderError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateSAMLProviderError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateSAMLProviderError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateSAMLProviderError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateSAMLProviderError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateSAMLProviderError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateSAMLProviderError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12616,24 +12692,28 @@ impl CreateServiceLinkedRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateServiceLinkedRoleError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateServiceLinkedRoleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateServiceLinkedRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateServiceLinkedRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12684,21 +12764,24 @@ impl CreateServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateServiceSpecificCredentialError::LimitExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateServiceSpecificCredentialError::NoSuchEntity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NotSupportedService" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateServiceSpecificCredentialError::ServiceNotSupported(
                                 parsed_error.message,
                             ),
@@ -12760,34 +12843,40 @@ impl CreateUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(CreateUserError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUserError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateUserError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUserError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateUserError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUserError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateUserError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUserError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12838,19 +12927,22 @@ impl CreateVirtualMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateVirtualMFADeviceError::EntityAlreadyExists(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateVirtualMFADeviceError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateVirtualMFADeviceError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateVirtualMFADeviceError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateVirtualMFADeviceError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12900,26 +12992,30 @@ impl DeactivateMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeactivateMFADeviceError::EntityTemporarilyUnmodifiable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeactivateMFADeviceError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeactivateMFADeviceError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeactivateMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeactivateMFADeviceError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeactivateMFADeviceError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeactivateMFADeviceError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12970,19 +13066,22 @@ impl DeleteAccessKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteAccessKeyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAccessKeyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteAccessKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAccessKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteAccessKeyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAccessKeyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13030,19 +13129,22 @@ impl DeleteAccountAliasError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteAccountAliasError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAccountAliasError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteAccountAliasError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAccountAliasError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteAccountAliasError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAccountAliasError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13092,17 +13194,20 @@ impl DeleteAccountPasswordPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAccountPasswordPolicyError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -13154,24 +13259,28 @@ impl DeleteGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteGroupError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteGroupError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteGroupError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteGroupError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13220,19 +13329,22 @@ impl DeleteGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteGroupPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13282,24 +13394,28 @@ impl DeleteInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteInstanceProfileError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteInstanceProfileError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteInstanceProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteInstanceProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13350,26 +13466,30 @@ impl DeleteLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteLoginProfileError::EntityTemporarilyUnmodifiable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteLoginProfileError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteLoginProfileError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteLoginProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteLoginProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteLoginProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13422,17 +13542,20 @@ impl DeleteOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteOpenIDConnectProviderError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteOpenIDConnectProviderError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -13486,29 +13609,34 @@ impl DeletePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeletePolicyError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeletePolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeletePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeletePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeletePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13562,29 +13690,34 @@ impl DeletePolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeletePolicyVersionError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyVersionError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeletePolicyVersionError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyVersionError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeletePolicyVersionError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyVersionError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeletePolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyVersionError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeletePolicyVersionError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePolicyVersionError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13640,34 +13773,40 @@ impl DeleteRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(DeleteRoleError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRoleError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteRoleError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRoleError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteRoleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRoleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(DeleteRoleError::UnmodifiableEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRoleError::UnmodifiableEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13720,19 +13859,22 @@ impl DeleteRolePermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteRolePermissionsBoundaryError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteRolePermissionsBoundaryError::ServiceFailure(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteRolePermissionsBoundaryError::UnmodifiableEntity(
                                 parsed_error.message,
                             ),
@@ -13788,24 +13930,28 @@ impl DeleteRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteRolePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRolePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRolePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteRolePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRolePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(DeleteRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteRolePolicyError::UnmodifiableEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13856,24 +14002,28 @@ impl DeleteSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSAMLProviderError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSAMLProviderError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSAMLProviderError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSAMLProviderError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13918,9 +14068,10 @@ impl DeleteSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSSHPublicKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13968,24 +14119,28 @@ impl DeleteServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteServerCertificateError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServerCertificateError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteServerCertificateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServerCertificateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteServerCertificateError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServerCertificateError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteServerCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServerCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14034,19 +14189,22 @@ impl DeleteServiceLinkedRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteServiceLinkedRoleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServiceLinkedRoleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteServiceLinkedRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServiceLinkedRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteServiceLinkedRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteServiceLinkedRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14092,7 +14250,8 @@ impl DeleteServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteServiceSpecificCredentialError::NoSuchEntity(
                                 parsed_error.message,
                             ),
@@ -14142,19 +14301,22 @@ impl DeleteSigningCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteSigningCertificateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSigningCertificateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteSigningCertificateError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSigningCertificateError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteSigningCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteSigningCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14206,29 +14368,34 @@ impl DeleteUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(DeleteUserError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteUserError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteUserError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14278,12 +14445,14 @@ impl DeleteUserPermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteUserPermissionsBoundaryError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteUserPermissionsBoundaryError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -14334,19 +14503,22 @@ impl DeleteUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteUserPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteUserPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteUserPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14396,24 +14568,28 @@ impl DeleteVirtualMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::DeleteConflict(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteVirtualMFADeviceError::DeleteConflict(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteVirtualMFADeviceError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteVirtualMFADeviceError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteVirtualMFADeviceError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14464,24 +14640,28 @@ impl DetachGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DetachGroupPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachGroupPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DetachGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachGroupPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DetachGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachGroupPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DetachGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachGroupPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14534,29 +14714,34 @@ impl DetachRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DetachRolePolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachRolePolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DetachRolePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachRolePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DetachRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachRolePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DetachRolePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachRolePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(DetachRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachRolePolicyError::UnmodifiableEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14608,24 +14793,28 @@ impl DetachUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DetachUserPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachUserPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DetachUserPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachUserPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DetachUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachUserPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DetachUserPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DetachUserPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14680,36 +14869,42 @@ impl EnableMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(EnableMFADeviceError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableMFADeviceError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableMFADeviceError::EntityTemporarilyUnmodifiable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidAuthenticationCode" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableMFADeviceError::InvalidAuthenticationCode(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(EnableMFADeviceError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableMFADeviceError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(EnableMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableMFADeviceError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(EnableMFADeviceError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableMFADeviceError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14760,14 +14955,16 @@ impl GenerateCredentialReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(GenerateCredentialReportError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GenerateCredentialReportError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GenerateCredentialReportError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GenerateCredentialReportError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14812,7 +15009,8 @@ impl GenerateOrganizationsAccessReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReportGenerationLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GenerateOrganizationsAccessReportError::ReportGenerationLimitExceeded(
                                 parsed_error.message,
                             ),
@@ -14864,14 +15062,16 @@ impl GenerateServiceLastAccessedDetailsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GenerateServiceLastAccessedDetailsError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GenerateServiceLastAccessedDetailsError::NoSuchEntity(
                                 parsed_error.message,
                             ),
@@ -14958,7 +15158,8 @@ impl GetAccountAuthorizationDetailsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetAccountAuthorizationDetailsError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -15008,14 +15209,16 @@ impl GetAccountPasswordPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetAccountPasswordPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetAccountPasswordPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15058,9 +15261,10 @@ impl GetAccountSummaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetAccountSummaryError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAccountSummaryError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15104,7 +15308,8 @@ impl GetContextKeysForCustomPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetContextKeysForCustomPolicyError::InvalidInput(parsed_error.message),
                         )
                     }
@@ -15152,14 +15357,16 @@ impl GetContextKeysForPrincipalPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetContextKeysForPrincipalPolicyError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetContextKeysForPrincipalPolicyError::NoSuchEntity(
                                 parsed_error.message,
                             ),
@@ -15216,28 +15423,32 @@ impl GetCredentialReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReportExpired" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetCredentialReportError::CredentialReportExpired(parsed_error.message),
                         )
                     }
                     "ReportNotPresent" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetCredentialReportError::CredentialReportNotPresent(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReportInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetCredentialReportError::CredentialReportNotReady(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetCredentialReportError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetCredentialReportError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15286,14 +15497,16 @@ impl GetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetGroupError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetGroupError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetGroupError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetGroupError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15338,14 +15551,16 @@ impl GetGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetGroupPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetGroupPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15390,14 +15605,16 @@ impl GetInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetInstanceProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetInstanceProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetInstanceProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetInstanceProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15442,14 +15659,16 @@ impl GetLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetLoginProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetLoginProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetLoginProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15496,19 +15715,22 @@ impl GetOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetOpenIDConnectProviderError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetOpenIDConnectProviderError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetOpenIDConnectProviderError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetOpenIDConnectProviderError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetOpenIDConnectProviderError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15554,7 +15776,8 @@ impl GetOrganizationsAccessReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetOrganizationsAccessReportError::NoSuchEntity(parsed_error.message),
                         )
                     }
@@ -15602,19 +15825,22 @@ impl GetPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15662,19 +15888,22 @@ impl GetPolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetPolicyVersionError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPolicyVersionError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetPolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPolicyVersionError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetPolicyVersionError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetPolicyVersionError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15720,14 +15949,16 @@ impl GetRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15772,14 +16003,16 @@ impl GetRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetRolePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetRolePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetRolePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15826,19 +16059,22 @@ impl GetSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetSAMLProviderError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSAMLProviderError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetSAMLProviderError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSAMLProviderError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSAMLProviderError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15884,12 +16120,14 @@ impl GetSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSSHPublicKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "UnrecognizedPublicKeyEncoding" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetSSHPublicKeyError::UnrecognizedPublicKeyEncoding(
                                 parsed_error.message,
                             ),
@@ -15940,14 +16178,16 @@ impl GetServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetServerCertificateError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetServerCertificateError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetServerCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetServerCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15994,12 +16234,14 @@ impl GetServiceLastAccessedDetailsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLastAccessedDetailsError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLastAccessedDetailsError::NoSuchEntity(parsed_error.message),
                         )
                     }
@@ -16048,14 +16290,16 @@ impl GetServiceLastAccessedDetailsWithEntitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLastAccessedDetailsWithEntitiesError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLastAccessedDetailsWithEntitiesError::NoSuchEntity(
                                 parsed_error.message,
                             ),
@@ -16112,21 +16356,24 @@ impl GetServiceLinkedRoleDeletionStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLinkedRoleDeletionStatusError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLinkedRoleDeletionStatusError::NoSuchEntity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetServiceLinkedRoleDeletionStatusError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -16182,14 +16429,16 @@ impl GetUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16234,14 +16483,16 @@ impl GetUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetUserPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetUserPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetUserPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16286,14 +16537,16 @@ impl ListAccessKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAccessKeysError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAccessKeysError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAccessKeysError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAccessKeysError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16336,9 +16589,10 @@ impl ListAccountAliasesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAccountAliasesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAccountAliasesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16384,17 +16638,20 @@ impl ListAttachedGroupPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListAttachedGroupPoliciesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedGroupPoliciesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAttachedGroupPoliciesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedGroupPoliciesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListAttachedGroupPoliciesError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -16444,19 +16701,22 @@ impl ListAttachedRolePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListAttachedRolePoliciesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedRolePoliciesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAttachedRolePoliciesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedRolePoliciesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAttachedRolePoliciesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedRolePoliciesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16504,19 +16764,22 @@ impl ListAttachedUserPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListAttachedUserPoliciesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedUserPoliciesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAttachedUserPoliciesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedUserPoliciesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAttachedUserPoliciesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListAttachedUserPoliciesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16564,19 +16827,22 @@ impl ListEntitiesForPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListEntitiesForPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListEntitiesForPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListEntitiesForPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListEntitiesForPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListEntitiesForPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListEntitiesForPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16622,14 +16888,16 @@ impl ListGroupPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListGroupPoliciesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListGroupPoliciesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListGroupPoliciesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListGroupPoliciesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16672,9 +16940,10 @@ impl ListGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListGroupsError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListGroupsError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16718,14 +16987,16 @@ impl ListGroupsForUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListGroupsForUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListGroupsForUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListGroupsForUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListGroupsForUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16768,9 +17039,10 @@ impl ListInstanceProfilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListInstanceProfilesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListInstanceProfilesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16816,12 +17088,14 @@ impl ListInstanceProfilesForRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListInstanceProfilesForRoleError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListInstanceProfilesForRoleError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -16868,14 +17142,16 @@ impl ListMFADevicesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListMFADevicesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListMFADevicesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListMFADevicesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListMFADevicesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16920,7 +17196,8 @@ impl ListOpenIDConnectProvidersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListOpenIDConnectProvidersError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -16964,9 +17241,10 @@ impl ListPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListPoliciesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPoliciesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17012,14 +17290,16 @@ impl ListPoliciesGrantingServiceAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPoliciesGrantingServiceAccessError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPoliciesGrantingServiceAccessError::NoSuchEntity(
                                 parsed_error.message,
                             ),
@@ -17074,19 +17354,22 @@ impl ListPolicyVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListPolicyVersionsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPolicyVersionsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListPolicyVersionsError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPolicyVersionsError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListPolicyVersionsError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPolicyVersionsError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17132,14 +17415,16 @@ impl ListRolePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListRolePoliciesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListRolePoliciesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListRolePoliciesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListRolePoliciesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17184,14 +17469,16 @@ impl ListRoleTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListRoleTagsError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListRoleTagsError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListRoleTagsError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListRoleTagsError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17234,9 +17521,10 @@ impl ListRolesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListRolesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListRolesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17278,9 +17566,10 @@ impl ListSAMLProvidersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListSAMLProvidersError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSAMLProvidersError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17322,9 +17611,10 @@ impl ListSSHPublicKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListSSHPublicKeysError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSSHPublicKeysError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17366,9 +17656,10 @@ impl ListServerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListServerCertificatesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListServerCertificatesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17414,12 +17705,14 @@ impl ListServiceSpecificCredentialsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListServiceSpecificCredentialsError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "NotSupportedService" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListServiceSpecificCredentialsError::ServiceNotSupported(
                                 parsed_error.message,
                             ),
@@ -17470,14 +17763,16 @@ impl ListSigningCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListSigningCertificatesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSigningCertificatesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListSigningCertificatesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSigningCertificatesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17522,14 +17817,16 @@ impl ListUserPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListUserPoliciesError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListUserPoliciesError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListUserPoliciesError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListUserPoliciesError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17574,14 +17871,16 @@ impl ListUserTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListUserTagsError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListUserTagsError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListUserTagsError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListUserTagsError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17624,9 +17923,10 @@ impl ListUsersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListUsersError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListUsersError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17708,24 +18008,28 @@ impl PutGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutGroupPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutGroupPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(PutGroupPolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutGroupPolicyError::MalformedPolicyDocument(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutGroupPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutGroupPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(PutGroupPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutGroupPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17780,29 +18084,34 @@ impl PutRolePermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(PutRolePermissionsBoundaryError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePermissionsBoundaryError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutRolePermissionsBoundaryError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePermissionsBoundaryError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutRolePermissionsBoundaryError::PolicyNotAttachable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutRolePermissionsBoundaryError::ServiceFailure(parsed_error.message),
                         )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutRolePermissionsBoundaryError::UnmodifiableEntity(
                                 parsed_error.message,
                             ),
@@ -17864,29 +18173,34 @@ impl PutRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutRolePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(PutRolePolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePolicyError::MalformedPolicyDocument(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(PutRolePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(PutRolePolicyError::UnmodifiableEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutRolePolicyError::UnmodifiableEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17940,24 +18254,28 @@ impl PutUserPermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(PutUserPermissionsBoundaryError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutUserPermissionsBoundaryError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutUserPermissionsBoundaryError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutUserPermissionsBoundaryError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutUserPermissionsBoundaryError::PolicyNotAttachable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutUserPermissionsBoundaryError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -18012,24 +18330,28 @@ impl PutUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutUserPolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutUserPolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(PutUserPolicyError::MalformedPolicyDocument(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutUserPolicyError::MalformedPolicyDocument(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutUserPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutUserPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(PutUserPolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutUserPolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18080,21 +18402,24 @@ impl RemoveClientIDFromOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveClientIDFromOpenIDConnectProviderError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveClientIDFromOpenIDConnectProviderError::NoSuchEntity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveClientIDFromOpenIDConnectProviderError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -18156,24 +18481,28 @@ impl RemoveRoleFromInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromInstanceProfileError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromInstanceProfileError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromInstanceProfileError::ServiceFailure(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromInstanceProfileError::UnmodifiableEntity(
                                 parsed_error.message,
                             ),
@@ -18228,19 +18557,22 @@ impl RemoveUserFromGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(RemoveUserFromGroupError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveUserFromGroupError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(RemoveUserFromGroupError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveUserFromGroupError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(RemoveUserFromGroupError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemoveUserFromGroupError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18286,7 +18618,8 @@ impl ResetServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetServiceSpecificCredentialError::NoSuchEntity(parsed_error.message),
                         )
                     }
@@ -18336,24 +18669,28 @@ impl ResyncMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAuthenticationCode" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResyncMFADeviceError::InvalidAuthenticationCode(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(ResyncMFADeviceError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResyncMFADeviceError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ResyncMFADeviceError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResyncMFADeviceError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ResyncMFADeviceError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResyncMFADeviceError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18404,24 +18741,28 @@ impl SetDefaultPolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetDefaultPolicyVersionError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetDefaultPolicyVersionError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetDefaultPolicyVersionError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetDefaultPolicyVersionError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18468,7 +18809,8 @@ impl SetSecurityTokenServicePreferencesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetSecurityTokenServicePreferencesError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -18518,14 +18860,16 @@ impl SimulateCustomPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(SimulateCustomPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SimulateCustomPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "PolicyEvaluation" => {
-                        return RusotoError::Service(SimulateCustomPolicyError::PolicyEvaluation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SimulateCustomPolicyError::PolicyEvaluation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18572,17 +18916,20 @@ impl SimulatePrincipalPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(SimulatePrincipalPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SimulatePrincipalPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(SimulatePrincipalPolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SimulatePrincipalPolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PolicyEvaluation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SimulatePrincipalPolicyError::PolicyEvaluation(parsed_error.message),
                         )
                     }
@@ -18636,29 +18983,34 @@ impl TagRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(TagRoleError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagRoleError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(TagRoleError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagRoleError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(TagRoleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagRoleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(TagRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(TagRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18712,29 +19064,34 @@ impl TagUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(TagUserError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagUserError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(TagUserError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagUserError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(TagUserError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagUserError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(TagUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(TagUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18784,19 +19141,22 @@ impl UntagRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(UntagRoleError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagRoleError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UntagRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UntagRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18844,19 +19204,22 @@ impl UntagUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(UntagUserError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagUserError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UntagUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UntagUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18904,19 +19267,22 @@ impl UpdateAccessKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAccessKeyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAccessKeyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateAccessKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAccessKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateAccessKeyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAccessKeyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18968,24 +19334,28 @@ impl UpdateAccountPasswordPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAccountPasswordPolicyError::LimitExceeded(parsed_error.message),
                         )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAccountPasswordPolicyError::MalformedPolicyDocument(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
                         )
                     }
@@ -19042,29 +19412,34 @@ impl UpdateAssumeRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAssumeRolePolicyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAssumeRolePolicyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAssumeRolePolicyError::MalformedPolicyDocument(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateAssumeRolePolicyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAssumeRolePolicyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateAssumeRolePolicyError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateAssumeRolePolicyError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateAssumeRolePolicyError::UnmodifiableEntity(parsed_error.message),
                         )
                     }
@@ -19120,24 +19495,28 @@ impl UpdateGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(UpdateGroupError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateGroupError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateGroupError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateGroupError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateGroupError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateGroupError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateGroupError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateGroupError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19190,31 +19569,36 @@ impl UpdateLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateLoginProfileError::EntityTemporarilyUnmodifiable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateLoginProfileError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateLoginProfileError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateLoginProfileError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateLoginProfileError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "PasswordPolicyViolation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateLoginProfileError::PasswordPolicyViolation(parsed_error.message),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateLoginProfileError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateLoginProfileError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19268,21 +19652,24 @@ impl UpdateOpenIDConnectProviderThumbprintError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateOpenIDConnectProviderThumbprintError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateOpenIDConnectProviderThumbprintError::NoSuchEntity(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateOpenIDConnectProviderThumbprintError::ServiceFailure(
                                 parsed_error.message,
                             ),
@@ -19340,19 +19727,22 @@ impl UpdateRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateRoleError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateRoleError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateRoleError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateRoleError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(UpdateRoleError::UnmodifiableEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateRoleError::UnmodifiableEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19400,17 +19790,20 @@ impl UpdateRoleDescriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateRoleDescriptionError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateRoleDescriptionError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateRoleDescriptionError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateRoleDescriptionError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateRoleDescriptionError::UnmodifiableEntity(parsed_error.message),
                         )
                     }
@@ -19462,24 +19855,28 @@ impl UpdateSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSAMLProviderError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSAMLProviderError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSAMLProviderError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSAMLProviderError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19524,9 +19921,10 @@ impl UpdateSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSSHPublicKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19574,24 +19972,28 @@ impl UpdateServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateServerCertificateError::EntityAlreadyExists(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateServerCertificateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateServerCertificateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateServerCertificateError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateServerCertificateError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateServerCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateServerCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19638,7 +20040,8 @@ impl UpdateServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateServiceSpecificCredentialError::NoSuchEntity(
                                 parsed_error.message,
                             ),
@@ -19688,19 +20091,22 @@ impl UpdateSigningCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateSigningCertificateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSigningCertificateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateSigningCertificateError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSigningCertificateError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateSigningCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateSigningCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19754,34 +20160,40 @@ impl UpdateUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(UpdateUserError::ConcurrentModification(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateUserError::ConcurrentModification(parsed_error.message),
+                        )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(UpdateUserError::EntityAlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateUserError::EntityAlreadyExists(parsed_error.message),
+                        )
                     }
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateUserError::EntityTemporarilyUnmodifiable(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateUserError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateUserError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateUserError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateUserError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateUserError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateUserError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19836,27 +20248,32 @@ impl UploadSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateSSHPublicKey" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadSSHPublicKeyError::DuplicateSSHPublicKey(parsed_error.message),
                         )
                     }
                     "InvalidPublicKey" => {
-                        return RusotoError::Service(UploadSSHPublicKeyError::InvalidPublicKey(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadSSHPublicKeyError::InvalidPublicKey(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UploadSSHPublicKeyError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadSSHPublicKeyError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UploadSSHPublicKeyError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadSSHPublicKeyError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "UnrecognizedPublicKeyEncoding" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadSSHPublicKeyError::UnrecognizedPublicKeyEncoding(
                                 parsed_error.message,
                             ),
@@ -19916,31 +20333,36 @@ impl UploadServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadServerCertificateError::EntityAlreadyExists(parsed_error.message),
                         )
                     }
                     "KeyPairMismatch" => {
-                        return RusotoError::Service(UploadServerCertificateError::KeyPairMismatch(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadServerCertificateError::KeyPairMismatch(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UploadServerCertificateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadServerCertificateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedCertificate" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadServerCertificateError::MalformedCertificate(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UploadServerCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadServerCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -19998,45 +20420,52 @@ impl UploadSigningCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateCertificate" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadSigningCertificateError::DuplicateCertificate(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadSigningCertificateError::EntityAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidCertificate" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadSigningCertificateError::InvalidCertificate(parsed_error.message),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UploadSigningCertificateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadSigningCertificateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "MalformedCertificate" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UploadSigningCertificateError::MalformedCertificate(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UploadSigningCertificateError::NoSuchEntity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadSigningCertificateError::NoSuchEntity(parsed_error.message),
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UploadSigningCertificateError::ServiceFailure(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UploadSigningCertificateError::ServiceFailure(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -20946,6 +21375,7 @@ impl IamClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -20961,6 +21391,7 @@ impl IamClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IamClient {
         IamClient { client, region }
     }
@@ -20974,6 +21405,9 @@ impl Iam for IamClient {
         input: AddClientIDToOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<AddClientIDToOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("AddClientIDToOpenIDConnectProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddClientIDToOpenIDConnectProvider");
         let mut params = params;
         AddClientIDToOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -20997,6 +21431,9 @@ impl Iam for IamClient {
         input: AddRoleToInstanceProfileRequest,
     ) -> Result<(), RusotoError<AddRoleToInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("AddRoleToInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddRoleToInstanceProfile");
         let mut params = params;
         AddRoleToInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21017,6 +21454,9 @@ impl Iam for IamClient {
         input: AddUserToGroupRequest,
     ) -> Result<(), RusotoError<AddUserToGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("AddUserToGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddUserToGroup");
         let mut params = params;
         AddUserToGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -21037,6 +21477,9 @@ impl Iam for IamClient {
         input: AttachGroupPolicyRequest,
     ) -> Result<(), RusotoError<AttachGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("AttachGroupPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachGroupPolicy");
         let mut params = params;
         AttachGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21057,6 +21500,9 @@ impl Iam for IamClient {
         input: AttachRolePolicyRequest,
     ) -> Result<(), RusotoError<AttachRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("AttachRolePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachRolePolicy");
         let mut params = params;
         AttachRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21077,6 +21523,9 @@ impl Iam for IamClient {
         input: AttachUserPolicyRequest,
     ) -> Result<(), RusotoError<AttachUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("AttachUserPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AttachUserPolicy");
         let mut params = params;
         AttachUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21097,6 +21546,9 @@ impl Iam for IamClient {
         input: ChangePasswordRequest,
     ) -> Result<(), RusotoError<ChangePasswordError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ChangePassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ChangePassword");
         let mut params = params;
         ChangePasswordRequestSerializer::serialize(&mut params, "", &input);
@@ -21117,6 +21569,9 @@ impl Iam for IamClient {
         input: CreateAccessKeyRequest,
     ) -> Result<CreateAccessKeyResponse, RusotoError<CreateAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateAccessKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateAccessKey");
         let mut params = params;
         CreateAccessKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -21148,6 +21603,9 @@ impl Iam for IamClient {
         input: CreateAccountAliasRequest,
     ) -> Result<(), RusotoError<CreateAccountAliasError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateAccountAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateAccountAlias");
         let mut params = params;
         CreateAccountAliasRequestSerializer::serialize(&mut params, "", &input);
@@ -21168,6 +21626,9 @@ impl Iam for IamClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateGroup");
         let mut params = params;
         CreateGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -21198,6 +21659,9 @@ impl Iam for IamClient {
         input: CreateInstanceProfileRequest,
     ) -> Result<CreateInstanceProfileResponse, RusotoError<CreateInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateInstanceProfile");
         let mut params = params;
         CreateInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21231,6 +21695,9 @@ impl Iam for IamClient {
         input: CreateLoginProfileRequest,
     ) -> Result<CreateLoginProfileResponse, RusotoError<CreateLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateLoginProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateLoginProfile");
         let mut params = params;
         CreateLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21265,6 +21732,9 @@ impl Iam for IamClient {
     ) -> Result<CreateOpenIDConnectProviderResponse, RusotoError<CreateOpenIDConnectProviderError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateOpenIDConnectProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateOpenIDConnectProvider");
         let mut params = params;
         CreateOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21298,6 +21768,9 @@ impl Iam for IamClient {
         input: CreatePolicyRequest,
     ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreatePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreatePolicy");
         let mut params = params;
         CreatePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21329,6 +21802,9 @@ impl Iam for IamClient {
         input: CreatePolicyVersionRequest,
     ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreatePolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreatePolicyVersion");
         let mut params = params;
         CreatePolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -21362,6 +21838,9 @@ impl Iam for IamClient {
         input: CreateRoleRequest,
     ) -> Result<CreateRoleResponse, RusotoError<CreateRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateRole");
         let mut params = params;
         CreateRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21392,6 +21871,9 @@ impl Iam for IamClient {
         input: CreateSAMLProviderRequest,
     ) -> Result<CreateSAMLProviderResponse, RusotoError<CreateSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateSAMLProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSAMLProvider");
         let mut params = params;
         CreateSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21425,6 +21907,9 @@ impl Iam for IamClient {
         input: CreateServiceLinkedRoleRequest,
     ) -> Result<CreateServiceLinkedRoleResponse, RusotoError<CreateServiceLinkedRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateServiceLinkedRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateServiceLinkedRole");
         let mut params = params;
         CreateServiceLinkedRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21461,6 +21946,9 @@ impl Iam for IamClient {
         RusotoError<CreateServiceSpecificCredentialError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateServiceSpecificCredential");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateServiceSpecificCredential");
         let mut params = params;
         CreateServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -21494,6 +21982,9 @@ impl Iam for IamClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateUser");
         let mut params = params;
         CreateUserRequestSerializer::serialize(&mut params, "", &input);
@@ -21524,6 +22015,9 @@ impl Iam for IamClient {
         input: CreateVirtualMFADeviceRequest,
     ) -> Result<CreateVirtualMFADeviceResponse, RusotoError<CreateVirtualMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("CreateVirtualMFADevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateVirtualMFADevice");
         let mut params = params;
         CreateVirtualMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -21557,6 +22051,9 @@ impl Iam for IamClient {
         input: DeactivateMFADeviceRequest,
     ) -> Result<(), RusotoError<DeactivateMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeactivateMFADevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeactivateMFADevice");
         let mut params = params;
         DeactivateMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -21577,6 +22074,9 @@ impl Iam for IamClient {
         input: DeleteAccessKeyRequest,
     ) -> Result<(), RusotoError<DeleteAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteAccessKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAccessKey");
         let mut params = params;
         DeleteAccessKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -21597,6 +22097,9 @@ impl Iam for IamClient {
         input: DeleteAccountAliasRequest,
     ) -> Result<(), RusotoError<DeleteAccountAliasError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteAccountAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAccountAlias");
         let mut params = params;
         DeleteAccountAliasRequestSerializer::serialize(&mut params, "", &input);
@@ -21616,6 +22119,9 @@ impl Iam for IamClient {
         &self,
     ) -> Result<(), RusotoError<DeleteAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteAccountPasswordPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAccountPasswordPolicy");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -21635,6 +22141,9 @@ impl Iam for IamClient {
         input: DeleteGroupRequest,
     ) -> Result<(), RusotoError<DeleteGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteGroup");
         let mut params = params;
         DeleteGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -21655,6 +22164,9 @@ impl Iam for IamClient {
         input: DeleteGroupPolicyRequest,
     ) -> Result<(), RusotoError<DeleteGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteGroupPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteGroupPolicy");
         let mut params = params;
         DeleteGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21675,6 +22187,9 @@ impl Iam for IamClient {
         input: DeleteInstanceProfileRequest,
     ) -> Result<(), RusotoError<DeleteInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteInstanceProfile");
         let mut params = params;
         DeleteInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21695,6 +22210,9 @@ impl Iam for IamClient {
         input: DeleteLoginProfileRequest,
     ) -> Result<(), RusotoError<DeleteLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteLoginProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteLoginProfile");
         let mut params = params;
         DeleteLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21715,6 +22233,9 @@ impl Iam for IamClient {
         input: DeleteOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<DeleteOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteOpenIDConnectProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteOpenIDConnectProvider");
         let mut params = params;
         DeleteOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21735,6 +22256,9 @@ impl Iam for IamClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeletePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeletePolicy");
         let mut params = params;
         DeletePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21755,6 +22279,9 @@ impl Iam for IamClient {
         input: DeletePolicyVersionRequest,
     ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeletePolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeletePolicyVersion");
         let mut params = params;
         DeletePolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -21775,6 +22302,9 @@ impl Iam for IamClient {
         input: DeleteRoleRequest,
     ) -> Result<(), RusotoError<DeleteRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteRole");
         let mut params = params;
         DeleteRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21795,6 +22325,9 @@ impl Iam for IamClient {
         input: DeleteRolePermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<DeleteRolePermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteRolePermissionsBoundary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteRolePermissionsBoundary");
         let mut params = params;
         DeleteRolePermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -21815,6 +22348,9 @@ impl Iam for IamClient {
         input: DeleteRolePolicyRequest,
     ) -> Result<(), RusotoError<DeleteRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteRolePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteRolePolicy");
         let mut params = params;
         DeleteRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21835,6 +22371,9 @@ impl Iam for IamClient {
         input: DeleteSAMLProviderRequest,
     ) -> Result<(), RusotoError<DeleteSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteSAMLProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSAMLProvider");
         let mut params = params;
         DeleteSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21855,6 +22394,9 @@ impl Iam for IamClient {
         input: DeleteSSHPublicKeyRequest,
     ) -> Result<(), RusotoError<DeleteSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteSSHPublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSSHPublicKey");
         let mut params = params;
         DeleteSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -21875,6 +22417,9 @@ impl Iam for IamClient {
         input: DeleteServerCertificateRequest,
     ) -> Result<(), RusotoError<DeleteServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteServerCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteServerCertificate");
         let mut params = params;
         DeleteServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -21895,6 +22440,9 @@ impl Iam for IamClient {
         input: DeleteServiceLinkedRoleRequest,
     ) -> Result<DeleteServiceLinkedRoleResponse, RusotoError<DeleteServiceLinkedRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteServiceLinkedRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteServiceLinkedRole");
         let mut params = params;
         DeleteServiceLinkedRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21928,6 +22476,9 @@ impl Iam for IamClient {
         input: DeleteServiceSpecificCredentialRequest,
     ) -> Result<(), RusotoError<DeleteServiceSpecificCredentialError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteServiceSpecificCredential");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteServiceSpecificCredential");
         let mut params = params;
         DeleteServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -21948,6 +22499,9 @@ impl Iam for IamClient {
         input: DeleteSigningCertificateRequest,
     ) -> Result<(), RusotoError<DeleteSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteSigningCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSigningCertificate");
         let mut params = params;
         DeleteSigningCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -21968,6 +22522,9 @@ impl Iam for IamClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteUser");
         let mut params = params;
         DeleteUserRequestSerializer::serialize(&mut params, "", &input);
@@ -21988,6 +22545,9 @@ impl Iam for IamClient {
         input: DeleteUserPermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<DeleteUserPermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteUserPermissionsBoundary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteUserPermissionsBoundary");
         let mut params = params;
         DeleteUserPermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -22008,6 +22568,9 @@ impl Iam for IamClient {
         input: DeleteUserPolicyRequest,
     ) -> Result<(), RusotoError<DeleteUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteUserPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteUserPolicy");
         let mut params = params;
         DeleteUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22028,6 +22591,9 @@ impl Iam for IamClient {
         input: DeleteVirtualMFADeviceRequest,
     ) -> Result<(), RusotoError<DeleteVirtualMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DeleteVirtualMFADevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVirtualMFADevice");
         let mut params = params;
         DeleteVirtualMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -22048,6 +22614,9 @@ impl Iam for IamClient {
         input: DetachGroupPolicyRequest,
     ) -> Result<(), RusotoError<DetachGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DetachGroupPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachGroupPolicy");
         let mut params = params;
         DetachGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22068,6 +22637,9 @@ impl Iam for IamClient {
         input: DetachRolePolicyRequest,
     ) -> Result<(), RusotoError<DetachRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DetachRolePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachRolePolicy");
         let mut params = params;
         DetachRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22088,6 +22660,9 @@ impl Iam for IamClient {
         input: DetachUserPolicyRequest,
     ) -> Result<(), RusotoError<DetachUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("DetachUserPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DetachUserPolicy");
         let mut params = params;
         DetachUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22108,6 +22683,9 @@ impl Iam for IamClient {
         input: EnableMFADeviceRequest,
     ) -> Result<(), RusotoError<EnableMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("EnableMFADevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableMFADevice");
         let mut params = params;
         EnableMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -22127,6 +22705,9 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GenerateCredentialReportResponse, RusotoError<GenerateCredentialReportError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GenerateCredentialReport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("GenerateCredentialReport");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22162,6 +22743,9 @@ impl Iam for IamClient {
         RusotoError<GenerateOrganizationsAccessReportError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GenerateOrganizationsAccessReport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("GenerateOrganizationsAccessReport");
         let mut params = params;
         GenerateOrganizationsAccessReportRequestSerializer::serialize(&mut params, "", &input);
@@ -22201,6 +22785,9 @@ impl Iam for IamClient {
         RusotoError<GenerateServiceLastAccessedDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GenerateServiceLastAccessedDetails");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("GenerateServiceLastAccessedDetails");
         let mut params = params;
         GenerateServiceLastAccessedDetailsRequestSerializer::serialize(&mut params, "", &input);
@@ -22237,6 +22824,9 @@ impl Iam for IamClient {
         input: GetAccessKeyLastUsedRequest,
     ) -> Result<GetAccessKeyLastUsedResponse, RusotoError<GetAccessKeyLastUsedError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetAccessKeyLastUsed");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAccessKeyLastUsed");
         let mut params = params;
         GetAccessKeyLastUsedRequestSerializer::serialize(&mut params, "", &input);
@@ -22273,6 +22863,9 @@ impl Iam for IamClient {
         RusotoError<GetAccountAuthorizationDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetAccountAuthorizationDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAccountAuthorizationDetails");
         let mut params = params;
         GetAccountAuthorizationDetailsRequestSerializer::serialize(&mut params, "", &input);
@@ -22305,6 +22898,9 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetAccountPasswordPolicyResponse, RusotoError<GetAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetAccountPasswordPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAccountPasswordPolicy");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22336,6 +22932,9 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetAccountSummaryResponse, RusotoError<GetAccountSummaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetAccountSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAccountSummary");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22369,6 +22968,9 @@ impl Iam for IamClient {
     ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForCustomPolicyError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetContextKeysForCustomPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetContextKeysForCustomPolicy");
         let mut params = params;
         GetContextKeysForCustomPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22403,6 +23005,9 @@ impl Iam for IamClient {
     ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForPrincipalPolicyError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetContextKeysForPrincipalPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetContextKeysForPrincipalPolicy");
         let mut params = params;
         GetContextKeysForPrincipalPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22438,6 +23043,9 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetCredentialReportResponse, RusotoError<GetCredentialReportError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetCredentialReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetCredentialReport");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22470,6 +23078,9 @@ impl Iam for IamClient {
         input: GetGroupRequest,
     ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetGroup");
         let mut params = params;
         GetGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -22500,6 +23111,9 @@ impl Iam for IamClient {
         input: GetGroupPolicyRequest,
     ) -> Result<GetGroupPolicyResponse, RusotoError<GetGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetGroupPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetGroupPolicy");
         let mut params = params;
         GetGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22531,6 +23145,9 @@ impl Iam for IamClient {
         input: GetInstanceProfileRequest,
     ) -> Result<GetInstanceProfileResponse, RusotoError<GetInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetInstanceProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetInstanceProfile");
         let mut params = params;
         GetInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -22564,6 +23181,9 @@ impl Iam for IamClient {
         input: GetLoginProfileRequest,
     ) -> Result<GetLoginProfileResponse, RusotoError<GetLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetLoginProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetLoginProfile");
         let mut params = params;
         GetLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -22595,6 +23215,9 @@ impl Iam for IamClient {
         input: GetOpenIDConnectProviderRequest,
     ) -> Result<GetOpenIDConnectProviderResponse, RusotoError<GetOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetOpenIDConnectProvider");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetOpenIDConnectProvider");
         let mut params = params;
         GetOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -22629,6 +23252,9 @@ impl Iam for IamClient {
     ) -> Result<GetOrganizationsAccessReportResponse, RusotoError<GetOrganizationsAccessReportError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetOrganizationsAccessReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetOrganizationsAccessReport");
         let mut params = params;
         GetOrganizationsAccessReportRequestSerializer::serialize(&mut params, "", &input);
@@ -22662,6 +23288,9 @@ impl Iam for IamClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetPolicy");
         let mut params = params;
         GetPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22692,6 +23321,9 @@ impl Iam for IamClient {
         input: GetPolicyVersionRequest,
     ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetPolicyVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetPolicyVersion");
         let mut params = params;
         GetPolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -22723,6 +23355,9 @@ impl Iam for IamClient {
         input: GetRoleRequest,
     ) -> Result<GetRoleResponse, RusotoError<GetRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetRole");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetRole");
         let mut params = params;
         GetRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -22753,6 +23388,9 @@ impl Iam for IamClient {
         input: GetRolePolicyRequest,
     ) -> Result<GetRolePolicyResponse, RusotoError<GetRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetRolePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetRolePolicy");
         let mut params = params;
         GetRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22784,6 +23422,9 @@ impl Iam for IamClient {
         input: GetSAMLProviderRequest,
     ) -> Result<GetSAMLProviderResponse, RusotoError<GetSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetSAMLProvider");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetSAMLProvider");
         let mut params = params;
         GetSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -22815,6 +23456,9 @@ impl Iam for IamClient {
         input: GetSSHPublicKeyRequest,
     ) -> Result<GetSSHPublicKeyResponse, RusotoError<GetSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetSSHPublicKey");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetSSHPublicKey");
         let mut params = params;
         GetSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -22846,6 +23490,9 @@ impl Iam for IamClient {
         input: GetServerCertificateRequest,
     ) -> Result<GetServerCertificateResponse, RusotoError<GetServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetServerCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetServerCertificate");
         let mut params = params;
         GetServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -22882,6 +23529,9 @@ impl Iam for IamClient {
         RusotoError<GetServiceLastAccessedDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetServiceLastAccessedDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetServiceLastAccessedDetails");
         let mut params = params;
         GetServiceLastAccessedDetailsRequestSerializer::serialize(&mut params, "", &input);
@@ -22918,6 +23568,9 @@ impl Iam for IamClient {
         RusotoError<GetServiceLastAccessedDetailsWithEntitiesError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetServiceLastAccessedDetailsWithEntities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetServiceLastAccessedDetailsWithEntities");
         let mut params = params;
         GetServiceLastAccessedDetailsWithEntitiesRequestSerializer::serialize(
@@ -22962,6 +23615,9 @@ impl Iam for IamClient {
         RusotoError<GetServiceLinkedRoleDeletionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetServiceLinkedRoleDeletionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetServiceLinkedRoleDeletionStatus");
         let mut params = params;
         GetServiceLinkedRoleDeletionStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -22998,6 +23654,9 @@ impl Iam for IamClient {
         input: GetUserRequest,
     ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetUser");
         let mut params = params;
         GetUserRequestSerializer::serialize(&mut params, "", &input);
@@ -23028,6 +23687,9 @@ impl Iam for IamClient {
         input: GetUserPolicyRequest,
     ) -> Result<GetUserPolicyResponse, RusotoError<GetUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("GetUserPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetUserPolicy");
         let mut params = params;
         GetUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -23059,6 +23721,9 @@ impl Iam for IamClient {
         input: ListAccessKeysRequest,
     ) -> Result<ListAccessKeysResponse, RusotoError<ListAccessKeysError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListAccessKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAccessKeys");
         let mut params = params;
         ListAccessKeysRequestSerializer::serialize(&mut params, "", &input);
@@ -23090,6 +23755,9 @@ impl Iam for IamClient {
         input: ListAccountAliasesRequest,
     ) -> Result<ListAccountAliasesResponse, RusotoError<ListAccountAliasesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListAccountAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAccountAliases");
         let mut params = params;
         ListAccountAliasesRequestSerializer::serialize(&mut params, "", &input);
@@ -23124,6 +23792,9 @@ impl Iam for IamClient {
     ) -> Result<ListAttachedGroupPoliciesResponse, RusotoError<ListAttachedGroupPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListAttachedGroupPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAttachedGroupPolicies");
         let mut params = params;
         ListAttachedGroupPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23157,6 +23828,9 @@ impl Iam for IamClient {
         input: ListAttachedRolePoliciesRequest,
     ) -> Result<ListAttachedRolePoliciesResponse, RusotoError<ListAttachedRolePoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListAttachedRolePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAttachedRolePolicies");
         let mut params = params;
         ListAttachedRolePoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23190,6 +23864,9 @@ impl Iam for IamClient {
         input: ListAttachedUserPoliciesRequest,
     ) -> Result<ListAttachedUserPoliciesResponse, RusotoError<ListAttachedUserPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListAttachedUserPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListAttachedUserPolicies");
         let mut params = params;
         ListAttachedUserPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23223,6 +23900,9 @@ impl Iam for IamClient {
         input: ListEntitiesForPolicyRequest,
     ) -> Result<ListEntitiesForPolicyResponse, RusotoError<ListEntitiesForPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListEntitiesForPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListEntitiesForPolicy");
         let mut params = params;
         ListEntitiesForPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -23256,6 +23936,9 @@ impl Iam for IamClient {
         input: ListGroupPoliciesRequest,
     ) -> Result<ListGroupPoliciesResponse, RusotoError<ListGroupPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListGroupPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListGroupPolicies");
         let mut params = params;
         ListGroupPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23289,6 +23972,9 @@ impl Iam for IamClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListGroups");
         let mut params = params;
         ListGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -23319,6 +24005,9 @@ impl Iam for IamClient {
         input: ListGroupsForUserRequest,
     ) -> Result<ListGroupsForUserResponse, RusotoError<ListGroupsForUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListGroupsForUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListGroupsForUser");
         let mut params = params;
         ListGroupsForUserRequestSerializer::serialize(&mut params, "", &input);
@@ -23352,6 +24041,9 @@ impl Iam for IamClient {
         input: ListInstanceProfilesRequest,
     ) -> Result<ListInstanceProfilesResponse, RusotoError<ListInstanceProfilesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListInstanceProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListInstanceProfiles");
         let mut params = params;
         ListInstanceProfilesRequestSerializer::serialize(&mut params, "", &input);
@@ -23386,6 +24078,9 @@ impl Iam for IamClient {
     ) -> Result<ListInstanceProfilesForRoleResponse, RusotoError<ListInstanceProfilesForRoleError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListInstanceProfilesForRole");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListInstanceProfilesForRole");
         let mut params = params;
         ListInstanceProfilesForRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -23419,6 +24114,9 @@ impl Iam for IamClient {
         input: ListMFADevicesRequest,
     ) -> Result<ListMFADevicesResponse, RusotoError<ListMFADevicesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListMFADevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListMFADevices");
         let mut params = params;
         ListMFADevicesRequestSerializer::serialize(&mut params, "", &input);
@@ -23451,6 +24149,9 @@ impl Iam for IamClient {
     ) -> Result<ListOpenIDConnectProvidersResponse, RusotoError<ListOpenIDConnectProvidersError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListOpenIDConnectProviders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListOpenIDConnectProviders");
         let mut params = params;
         ListOpenIDConnectProvidersRequestSerializer::serialize(&mut params, "", &input);
@@ -23484,6 +24185,9 @@ impl Iam for IamClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPolicies");
         let mut params = params;
         ListPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23518,6 +24222,9 @@ impl Iam for IamClient {
         RusotoError<ListPoliciesGrantingServiceAccessError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListPoliciesGrantingServiceAccess");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPoliciesGrantingServiceAccess");
         let mut params = params;
         ListPoliciesGrantingServiceAccessRequestSerializer::serialize(&mut params, "", &input);
@@ -23554,6 +24261,9 @@ impl Iam for IamClient {
         input: ListPolicyVersionsRequest,
     ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListPolicyVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPolicyVersions");
         let mut params = params;
         ListPolicyVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -23587,6 +24297,9 @@ impl Iam for IamClient {
         input: ListRolePoliciesRequest,
     ) -> Result<ListRolePoliciesResponse, RusotoError<ListRolePoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListRolePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListRolePolicies");
         let mut params = params;
         ListRolePoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23618,6 +24331,9 @@ impl Iam for IamClient {
         input: ListRoleTagsRequest,
     ) -> Result<ListRoleTagsResponse, RusotoError<ListRoleTagsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListRoleTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListRoleTags");
         let mut params = params;
         ListRoleTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -23649,6 +24365,9 @@ impl Iam for IamClient {
         input: ListRolesRequest,
     ) -> Result<ListRolesResponse, RusotoError<ListRolesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListRoles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListRoles");
         let mut params = params;
         ListRolesRequestSerializer::serialize(&mut params, "", &input);
@@ -23679,6 +24398,9 @@ impl Iam for IamClient {
         input: ListSAMLProvidersRequest,
     ) -> Result<ListSAMLProvidersResponse, RusotoError<ListSAMLProvidersError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListSAMLProviders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListSAMLProviders");
         let mut params = params;
         ListSAMLProvidersRequestSerializer::serialize(&mut params, "", &input);
@@ -23712,6 +24434,9 @@ impl Iam for IamClient {
         input: ListSSHPublicKeysRequest,
     ) -> Result<ListSSHPublicKeysResponse, RusotoError<ListSSHPublicKeysError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListSSHPublicKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListSSHPublicKeys");
         let mut params = params;
         ListSSHPublicKeysRequestSerializer::serialize(&mut params, "", &input);
@@ -23745,6 +24470,9 @@ impl Iam for IamClient {
         input: ListServerCertificatesRequest,
     ) -> Result<ListServerCertificatesResponse, RusotoError<ListServerCertificatesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListServerCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListServerCertificates");
         let mut params = params;
         ListServerCertificatesRequestSerializer::serialize(&mut params, "", &input);
@@ -23781,6 +24509,9 @@ impl Iam for IamClient {
         RusotoError<ListServiceSpecificCredentialsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListServiceSpecificCredentials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListServiceSpecificCredentials");
         let mut params = params;
         ListServiceSpecificCredentialsRequestSerializer::serialize(&mut params, "", &input);
@@ -23814,6 +24545,9 @@ impl Iam for IamClient {
         input: ListSigningCertificatesRequest,
     ) -> Result<ListSigningCertificatesResponse, RusotoError<ListSigningCertificatesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListSigningCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListSigningCertificates");
         let mut params = params;
         ListSigningCertificatesRequestSerializer::serialize(&mut params, "", &input);
@@ -23847,6 +24581,9 @@ impl Iam for IamClient {
         input: ListUserPoliciesRequest,
     ) -> Result<ListUserPoliciesResponse, RusotoError<ListUserPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListUserPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListUserPolicies");
         let mut params = params;
         ListUserPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23878,6 +24615,9 @@ impl Iam for IamClient {
         input: ListUserTagsRequest,
     ) -> Result<ListUserTagsResponse, RusotoError<ListUserTagsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListUserTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListUserTags");
         let mut params = params;
         ListUserTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -23909,6 +24649,9 @@ impl Iam for IamClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListUsers");
         let mut params = params;
         ListUsersRequestSerializer::serialize(&mut params, "", &input);
@@ -23939,6 +24682,9 @@ impl Iam for IamClient {
         input: ListVirtualMFADevicesRequest,
     ) -> Result<ListVirtualMFADevicesResponse, RusotoError<ListVirtualMFADevicesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ListVirtualMFADevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListVirtualMFADevices");
         let mut params = params;
         ListVirtualMFADevicesRequestSerializer::serialize(&mut params, "", &input);
@@ -23972,6 +24718,9 @@ impl Iam for IamClient {
         input: PutGroupPolicyRequest,
     ) -> Result<(), RusotoError<PutGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("PutGroupPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutGroupPolicy");
         let mut params = params;
         PutGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -23992,6 +24741,9 @@ impl Iam for IamClient {
         input: PutRolePermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<PutRolePermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("PutRolePermissionsBoundary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutRolePermissionsBoundary");
         let mut params = params;
         PutRolePermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -24012,6 +24764,9 @@ impl Iam for IamClient {
         input: PutRolePolicyRequest,
     ) -> Result<(), RusotoError<PutRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("PutRolePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutRolePolicy");
         let mut params = params;
         PutRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24032,6 +24787,9 @@ impl Iam for IamClient {
         input: PutUserPermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<PutUserPermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("PutUserPermissionsBoundary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutUserPermissionsBoundary");
         let mut params = params;
         PutUserPermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -24052,6 +24810,9 @@ impl Iam for IamClient {
         input: PutUserPolicyRequest,
     ) -> Result<(), RusotoError<PutUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("PutUserPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutUserPolicy");
         let mut params = params;
         PutUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24072,6 +24833,9 @@ impl Iam for IamClient {
         input: RemoveClientIDFromOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<RemoveClientIDFromOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("RemoveClientIDFromOpenIDConnectProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveClientIDFromOpenIDConnectProvider");
         let mut params = params;
         RemoveClientIDFromOpenIDConnectProviderRequestSerializer::serialize(
@@ -24099,6 +24863,9 @@ impl Iam for IamClient {
         input: RemoveRoleFromInstanceProfileRequest,
     ) -> Result<(), RusotoError<RemoveRoleFromInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("RemoveRoleFromInstanceProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveRoleFromInstanceProfile");
         let mut params = params;
         RemoveRoleFromInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -24119,6 +24886,9 @@ impl Iam for IamClient {
         input: RemoveUserFromGroupRequest,
     ) -> Result<(), RusotoError<RemoveUserFromGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("RemoveUserFromGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveUserFromGroup");
         let mut params = params;
         RemoveUserFromGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -24142,6 +24912,9 @@ impl Iam for IamClient {
         RusotoError<ResetServiceSpecificCredentialError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ResetServiceSpecificCredential");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetServiceSpecificCredential");
         let mut params = params;
         ResetServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -24175,6 +24948,9 @@ impl Iam for IamClient {
         input: ResyncMFADeviceRequest,
     ) -> Result<(), RusotoError<ResyncMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("ResyncMFADevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResyncMFADevice");
         let mut params = params;
         ResyncMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -24195,6 +24971,9 @@ impl Iam for IamClient {
         input: SetDefaultPolicyVersionRequest,
     ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("SetDefaultPolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetDefaultPolicyVersion");
         let mut params = params;
         SetDefaultPolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -24215,6 +24994,9 @@ impl Iam for IamClient {
         input: SetSecurityTokenServicePreferencesRequest,
     ) -> Result<(), RusotoError<SetSecurityTokenServicePreferencesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("SetSecurityTokenServicePreferences");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetSecurityTokenServicePreferences");
         let mut params = params;
         SetSecurityTokenServicePreferencesRequestSerializer::serialize(&mut params, "", &input);
@@ -24238,6 +25020,9 @@ impl Iam for IamClient {
         input: SimulateCustomPolicyRequest,
     ) -> Result<SimulatePolicyResponse, RusotoError<SimulateCustomPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("SimulateCustomPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SimulateCustomPolicy");
         let mut params = params;
         SimulateCustomPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24271,6 +25056,9 @@ impl Iam for IamClient {
         input: SimulatePrincipalPolicyRequest,
     ) -> Result<SimulatePolicyResponse, RusotoError<SimulatePrincipalPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("SimulatePrincipalPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SimulatePrincipalPolicy");
         let mut params = params;
         SimulatePrincipalPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24301,6 +25089,9 @@ impl Iam for IamClient {
     /// <p>Adds one or more tags to an IAM role. The role can be a regular role or a service-linked role. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM user or role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn tag_role(&self, input: TagRoleRequest) -> Result<(), RusotoError<TagRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("TagRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TagRole");
         let mut params = params;
         TagRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -24318,6 +25109,9 @@ impl Iam for IamClient {
     /// <p>Adds one or more tags to an IAM user. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM requesting user or to a role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn tag_user(&self, input: TagUserRequest) -> Result<(), RusotoError<TagUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("TagUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TagUser");
         let mut params = params;
         TagUserRequestSerializer::serialize(&mut params, "", &input);
@@ -24335,6 +25129,9 @@ impl Iam for IamClient {
     /// <p>Removes the specified tags from the role. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn untag_role(&self, input: UntagRoleRequest) -> Result<(), RusotoError<UntagRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UntagRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UntagRole");
         let mut params = params;
         UntagRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -24352,6 +25149,9 @@ impl Iam for IamClient {
     /// <p>Removes the specified tags from the user. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn untag_user(&self, input: UntagUserRequest) -> Result<(), RusotoError<UntagUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UntagUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UntagUser");
         let mut params = params;
         UntagUserRequestSerializer::serialize(&mut params, "", &input);
@@ -24372,6 +25172,9 @@ impl Iam for IamClient {
         input: UpdateAccessKeyRequest,
     ) -> Result<(), RusotoError<UpdateAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateAccessKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateAccessKey");
         let mut params = params;
         UpdateAccessKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -24392,6 +25195,9 @@ impl Iam for IamClient {
         input: UpdateAccountPasswordPolicyRequest,
     ) -> Result<(), RusotoError<UpdateAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateAccountPasswordPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateAccountPasswordPolicy");
         let mut params = params;
         UpdateAccountPasswordPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24412,6 +25218,9 @@ impl Iam for IamClient {
         input: UpdateAssumeRolePolicyRequest,
     ) -> Result<(), RusotoError<UpdateAssumeRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateAssumeRolePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateAssumeRolePolicy");
         let mut params = params;
         UpdateAssumeRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24432,6 +25241,9 @@ impl Iam for IamClient {
         input: UpdateGroupRequest,
     ) -> Result<(), RusotoError<UpdateGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateGroup");
         let mut params = params;
         UpdateGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -24452,6 +25264,9 @@ impl Iam for IamClient {
         input: UpdateLoginProfileRequest,
     ) -> Result<(), RusotoError<UpdateLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateLoginProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateLoginProfile");
         let mut params = params;
         UpdateLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -24472,6 +25287,9 @@ impl Iam for IamClient {
         input: UpdateOpenIDConnectProviderThumbprintRequest,
     ) -> Result<(), RusotoError<UpdateOpenIDConnectProviderThumbprintError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateOpenIDConnectProviderThumbprint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateOpenIDConnectProviderThumbprint");
         let mut params = params;
         UpdateOpenIDConnectProviderThumbprintRequestSerializer::serialize(&mut params, "", &input);
@@ -24495,6 +25313,9 @@ impl Iam for IamClient {
         input: UpdateRoleRequest,
     ) -> Result<UpdateRoleResponse, RusotoError<UpdateRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateRole");
         let mut params = params;
         UpdateRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -24517,6 +25338,9 @@ impl Iam for IamClient {
         input: UpdateRoleDescriptionRequest,
     ) -> Result<UpdateRoleDescriptionResponse, RusotoError<UpdateRoleDescriptionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateRoleDescription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateRoleDescription");
         let mut params = params;
         UpdateRoleDescriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -24550,6 +25374,9 @@ impl Iam for IamClient {
         input: UpdateSAMLProviderRequest,
     ) -> Result<UpdateSAMLProviderResponse, RusotoError<UpdateSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateSAMLProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateSAMLProvider");
         let mut params = params;
         UpdateSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -24583,6 +25410,9 @@ impl Iam for IamClient {
         input: UpdateSSHPublicKeyRequest,
     ) -> Result<(), RusotoError<UpdateSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateSSHPublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateSSHPublicKey");
         let mut params = params;
         UpdateSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -24603,6 +25433,9 @@ impl Iam for IamClient {
         input: UpdateServerCertificateRequest,
     ) -> Result<(), RusotoError<UpdateServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateServerCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateServerCertificate");
         let mut params = params;
         UpdateServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24623,6 +25456,9 @@ impl Iam for IamClient {
         input: UpdateServiceSpecificCredentialRequest,
     ) -> Result<(), RusotoError<UpdateServiceSpecificCredentialError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateServiceSpecificCredential");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateServiceSpecificCredential");
         let mut params = params;
         UpdateServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -24643,6 +25479,9 @@ impl Iam for IamClient {
         input: UpdateSigningCertificateRequest,
     ) -> Result<(), RusotoError<UpdateSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateSigningCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateSigningCertificate");
         let mut params = params;
         UpdateSigningCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24663,6 +25502,9 @@ impl Iam for IamClient {
         input: UpdateUserRequest,
     ) -> Result<(), RusotoError<UpdateUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateUser");
         let mut params = params;
         UpdateUserRequestSerializer::serialize(&mut params, "", &input);
@@ -24683,6 +25525,9 @@ impl Iam for IamClient {
         input: UploadSSHPublicKeyRequest,
     ) -> Result<UploadSSHPublicKeyResponse, RusotoError<UploadSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UploadSSHPublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UploadSSHPublicKey");
         let mut params = params;
         UploadSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -24716,6 +25561,9 @@ impl Iam for IamClient {
         input: UploadServerCertificateRequest,
     ) -> Result<UploadServerCertificateResponse, RusotoError<UploadServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UploadServerCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UploadServerCertificate");
         let mut params = params;
         UploadServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24749,6 +25597,9 @@ impl Iam for IamClient {
         input: UploadSigningCertificateRequest,
     ) -> Result<UploadSigningCertificateResponse, RusotoError<UploadSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation_name("UploadSigningCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UploadSigningCertificate");
         let mut params = params;
         UploadSigningCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24777,6 +25628,1568 @@ impl Iam for IamClient {
     }
 }
 
+/// A blocking client for the IAM API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IamClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IamBlockingClient {
+    client: IamClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IamBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IamBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IamBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IamBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IamBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IamBlockingClient {
+        IamBlockingClient {
+            client: IamClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds a new client ID (also known as audience) to the list of client IDs already registered for the specified IAM OpenID Connect (OIDC) provider resource.</p> <p>This operation is idempotent; it does not fail or return an error if you add an existing client ID to the provider.</p>
+    ///
+    /// See `Iam::add_client_id_to_open_id_connect_provider`.
+    pub fn add_client_id_to_open_id_connect_provider(
+        &self,
+        input: AddClientIDToOpenIDConnectProviderRequest,
+    ) -> Result<(), RusotoError<AddClientIDToOpenIDConnectProviderError>> {
+        self.runtime
+            .block_on(self.client.add_client_id_to_open_id_connect_provider(input))
+    }
+
+    /// <p>Adds the specified IAM role to the specified instance profile. An instance profile can contain only one role. (The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.) You can remove the existing role and then add a different role to an instance profile. You must then wait for the change to appear across all of AWS because of <a href="https://en.wikipedia.org/wiki/Eventual_consistency">eventual consistency</a>. To force the change, you must <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DisassociateIamInstanceProfile.html">disassociate the instance profile</a> and then <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_AssociateIamInstanceProfile.html">associate the instance profile</a>, or you can stop your instance and then restart it.</p> <note> <p>The caller of this API must be granted the <code>PassRole</code> permission on the IAM role by a permissions policy.</p> </note> <p>For more information about roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/WorkingWithRoles.html">Working with Roles</a>. For more information about instance profiles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a>.</p>
+    ///
+    /// See `Iam::add_role_to_instance_profile`.
+    pub fn add_role_to_instance_profile(
+        &self,
+        input: AddRoleToInstanceProfileRequest,
+    ) -> Result<(), RusotoError<AddRoleToInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_instance_profile(input))
+    }
+
+    /// <p>Adds the specified user to the specified group.</p>
+    ///
+    /// See `Iam::add_user_to_group`.
+    pub fn add_user_to_group(
+        &self,
+        input: AddUserToGroupRequest,
+    ) -> Result<(), RusotoError<AddUserToGroupError>> {
+        self.runtime.block_on(self.client.add_user_to_group(input))
+    }
+
+    /// <p>Attaches the specified managed policy to the specified IAM group.</p> <p>You use this API to attach a managed policy to a group. To embed an inline policy in a group, use <a>PutGroupPolicy</a>.</p> <p>For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::attach_group_policy`.
+    pub fn attach_group_policy(
+        &self,
+        input: AttachGroupPolicyRequest,
+    ) -> Result<(), RusotoError<AttachGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.attach_group_policy(input))
+    }
+
+    /// <p>Attaches the specified managed policy to the specified IAM role. When you attach a managed policy to a role, the managed policy becomes part of the role's permission (access) policy.</p> <note> <p>You cannot use a managed policy as the role's trust policy. The role's trust policy is created at the same time as the role, using <a>CreateRole</a>. You can update a role's trust policy using <a>UpdateAssumeRolePolicy</a>.</p> </note> <p>Use this API to attach a <i>managed</i> policy to a role. To embed an inline policy in a role, use <a>PutRolePolicy</a>. For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::attach_role_policy`.
+    pub fn attach_role_policy(
+        &self,
+        input: AttachRolePolicyRequest,
+    ) -> Result<(), RusotoError<AttachRolePolicyError>> {
+        self.runtime.block_on(self.client.attach_role_policy(input))
+    }
+
+    /// <p>Attaches the specified managed policy to the specified user.</p> <p>You use this API to attach a <i>managed</i> policy to a user. To embed an inline policy in a user, use <a>PutUserPolicy</a>.</p> <p>For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::attach_user_policy`.
+    pub fn attach_user_policy(
+        &self,
+        input: AttachUserPolicyRequest,
+    ) -> Result<(), RusotoError<AttachUserPolicyError>> {
+        self.runtime.block_on(self.client.attach_user_policy(input))
+    }
+
+    /// <p>Changes the password of the IAM user who is calling this operation. The AWS account root user password is not affected by this operation.</p> <p>To change the password for a different user, see <a>UpdateLoginProfile</a>. For more information about modifying passwords, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_ManagingLogins.html">Managing Passwords</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::change_password`.
+    pub fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> Result<(), RusotoError<ChangePasswordError>> {
+        self.runtime.block_on(self.client.change_password(input))
+    }
+
+    /// <p><p> Creates a new AWS secret access key and corresponding AWS access key ID for the specified user. The default status for new keys is <code>Active</code>.</p> <p>If you do not specify a user name, IAM determines the user name implicitly based on the AWS access key ID signing the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials. This is true even if the AWS account has no associated users.</p> <p>The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p> <important> <p>To ensure the security of your AWS account, the secret access key is accessible only during key and user creation. You must save the key (for example, in a text file) if you want to be able to access it again. If a secret key is lost, you can delete the access keys for the associated user and then create new keys.</p> </important></p>
+    ///
+    /// See `Iam::create_access_key`.
+    pub fn create_access_key(
+        &self,
+        input: CreateAccessKeyRequest,
+    ) -> Result<CreateAccessKeyResponse, RusotoError<CreateAccessKeyError>> {
+        self.runtime.block_on(self.client.create_access_key(input))
+    }
+
+    /// <p>Creates an alias for your AWS account. For information about using an AWS account alias, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AccountAlias.html">Using an Alias for Your AWS Account ID</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_account_alias`.
+    pub fn create_account_alias(
+        &self,
+        input: CreateAccountAliasRequest,
+    ) -> Result<(), RusotoError<CreateAccountAliasError>> {
+        self.runtime
+            .block_on(self.client.create_account_alias(input))
+    }
+
+    /// <p>Creates a new group.</p> <p>The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p> Creates a new instance profile. For information about instance profiles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a>.</p> <p>The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_instance_profile`.
+    pub fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> Result<CreateInstanceProfileResponse, RusotoError<CreateInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.create_instance_profile(input))
+    }
+
+    /// <p> Creates a password for the specified user, giving the user the ability to access AWS services through the AWS Management Console. For more information about managing passwords, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_ManagingLogins.html">Managing Passwords</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_login_profile`.
+    pub fn create_login_profile(
+        &self,
+        input: CreateLoginProfileRequest,
+    ) -> Result<CreateLoginProfileResponse, RusotoError<CreateLoginProfileError>> {
+        self.runtime
+            .block_on(self.client.create_login_profile(input))
+    }
+
+    /// <p><p>Creates an IAM entity to describe an identity provider (IdP) that supports <a href="http://openid.net/connect/">OpenID Connect (OIDC)</a>.</p> <p>The OIDC provider that you create with this operation can be used as a principal in a role&#39;s trust policy. Such a policy establishes a trust relationship between AWS and the OIDC provider.</p> <p>When you create the IAM OIDC provider, you specify the following:</p> <ul> <li> <p>The URL of the OIDC identity provider (IdP) to trust</p> </li> <li> <p>A list of client IDs (also known as audiences) that identify the application or applications that are allowed to authenticate using the OIDC provider</p> </li> <li> <p>A list of thumbprints of one or more server certificates that the IdP uses</p> </li> </ul> <p>You get all of this information from the OIDC IdP that you want to use to access AWS.</p> <note> <p>The trust for the OIDC provider is derived from the IAM provider that this operation creates. Therefore, it is best to limit access to the <a>CreateOpenIDConnectProvider</a> operation to highly privileged users.</p> </note></p>
+    ///
+    /// See `Iam::create_open_id_connect_provider`.
+    pub fn create_open_id_connect_provider(
+        &self,
+        input: CreateOpenIDConnectProviderRequest,
+    ) -> Result<CreateOpenIDConnectProviderResponse, RusotoError<CreateOpenIDConnectProviderError>>
+    {
+        self.runtime
+            .block_on(self.client.create_open_id_connect_provider(input))
+    }
+
+    /// <p>Creates a new managed policy for your AWS account.</p> <p>This operation creates a policy version with a version identifier of <code>v1</code> and sets v1 as the policy's default version. For more information about policy versions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-versions.html">Versioning for Managed Policies</a> in the <i>IAM User Guide</i>.</p> <p>For more information about managed policies in general, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_policy`.
+    pub fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.runtime.block_on(self.client.create_policy(input))
+    }
+
+    /// <p>Creates a new version of the specified managed policy. To update a managed policy, you create a new policy version. A managed policy can have up to five versions. If the policy has five versions, you must delete an existing version using <a>DeletePolicyVersion</a> before you create a new version.</p> <p>Optionally, you can set the new version as the policy's default version. The default version is the version that is in effect for the IAM users, groups, and roles to which the policy is attached.</p> <p>For more information about managed policy versions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-versions.html">Versioning for Managed Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_policy_version`.
+    pub fn create_policy_version(
+        &self,
+        input: CreatePolicyVersionRequest,
+    ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.create_policy_version(input))
+    }
+
+    /// <p>Creates a new role for your AWS account. For more information about roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/WorkingWithRoles.html">IAM Roles</a>. The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_role`.
+    pub fn create_role(
+        &self,
+        input: CreateRoleRequest,
+    ) -> Result<CreateRoleResponse, RusotoError<CreateRoleError>> {
+        self.runtime.block_on(self.client.create_role(input))
+    }
+
+    /// <p>Creates an IAM resource that describes an identity provider (IdP) that supports SAML 2.0.</p> <p>The SAML provider resource that you create with this operation can be used as a principal in an IAM role's trust policy. Such a policy can enable federated users who sign in using the SAML IdP to assume the role. You can create an IAM role that supports Web-based single sign-on (SSO) to the AWS Management Console or one that supports API access to AWS.</p> <p>When you create the SAML provider resource, you upload a SAML metadata document that you get from your IdP. That document includes the issuer's name, expiration information, and keys that can be used to validate the SAML authentication response (assertions) that the IdP sends. You must generate the metadata document using the identity management software that is used as your organization's IdP.</p> <note> <p> This operation requires <a href="https://docs.aws.amazon.com/general/latest/gr/signature-version-4.html">Signature Version 4</a>.</p> </note> <p> For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_providers_enable-console-saml.html">Enabling SAML 2.0 Federated Users to Access the AWS Management Console</a> and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_providers_saml.html">About SAML 2.0-based Federation</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_saml_provider`.
+    pub fn create_saml_provider(
+        &self,
+        input: CreateSAMLProviderRequest,
+    ) -> Result<CreateSAMLProviderResponse, RusotoError<CreateSAMLProviderError>> {
+        self.runtime
+            .block_on(self.client.create_saml_provider(input))
+    }
+
+    /// <p>Creates an IAM role that is linked to a specific AWS service. The service controls the attached policies and when the role can be deleted. This helps ensure that the service is not broken by an unexpectedly changed or deleted role, which could put your AWS resources into an unknown state. Allowing the service to control the role helps improve service stability and proper cleanup when a service and its role are no longer needed. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">Using Service-Linked Roles</a> in the <i>IAM User Guide</i>. </p> <p>To attach a policy to this service-linked role, you must make the request using the AWS service that depends on this role.</p>
+    ///
+    /// See `Iam::create_service_linked_role`.
+    pub fn create_service_linked_role(
+        &self,
+        input: CreateServiceLinkedRoleRequest,
+    ) -> Result<CreateServiceLinkedRoleResponse, RusotoError<CreateServiceLinkedRoleError>> {
+        self.runtime
+            .block_on(self.client.create_service_linked_role(input))
+    }
+
+    /// <p>Generates a set of credentials consisting of a user name and password that can be used to access the service specified in the request. These credentials are generated by IAM, and can be used only for the specified service. </p> <p>You can have a maximum of two sets of service-specific credentials for each supported service per user.</p> <p>The only supported service at this time is AWS CodeCommit.</p> <p>You can reset the password to a new service-generated value by calling <a>ResetServiceSpecificCredential</a>.</p> <p>For more information about service-specific credentials, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_ssh-keys.html">Using IAM with AWS CodeCommit: Git Credentials, SSH Keys, and AWS Access Keys</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_service_specific_credential`.
+    pub fn create_service_specific_credential(
+        &self,
+        input: CreateServiceSpecificCredentialRequest,
+    ) -> Result<
+        CreateServiceSpecificCredentialResponse,
+        RusotoError<CreateServiceSpecificCredentialError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_service_specific_credential(input))
+    }
+
+    /// <p>Creates a new IAM user for your AWS account.</p> <p>The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p><p>Creates a new virtual MFA device for the AWS account. After creating the virtual MFA, use <a>EnableMFADevice</a> to attach the MFA device to an IAM user. For more information about creating and working with virtual MFA devices, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_VirtualMFA.html">Using a Virtual MFA Device</a> in the <i>IAM User Guide</i>.</p> <p>The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p> <important> <p>The seed information contained in the QR code and the Base32 string should be treated like any other secret access information. In other words, protect the seed information as you would your AWS access keys or your passwords. After you provision your virtual device, you should ensure that the information is destroyed following secure procedures.</p> </important></p>
+    ///
+    /// See `Iam::create_virtual_mfa_device`.
+    pub fn create_virtual_mfa_device(
+        &self,
+        input: CreateVirtualMFADeviceRequest,
+    ) -> Result<CreateVirtualMFADeviceResponse, RusotoError<CreateVirtualMFADeviceError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_mfa_device(input))
+    }
+
+    /// <p>Deactivates the specified MFA device and removes it from association with the user name for which it was originally enabled.</p> <p>For more information about creating and working with virtual MFA devices, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_VirtualMFA.html">Enabling a Virtual Multi-factor Authentication (MFA) Device</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::deactivate_mfa_device`.
+    pub fn deactivate_mfa_device(
+        &self,
+        input: DeactivateMFADeviceRequest,
+    ) -> Result<(), RusotoError<DeactivateMFADeviceError>> {
+        self.runtime
+            .block_on(self.client.deactivate_mfa_device(input))
+    }
+
+    /// <p>Deletes the access key pair associated with the specified IAM user.</p> <p>If you do not specify a user name, IAM determines the user name implicitly based on the AWS access key ID signing the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated users.</p>
+    ///
+    /// See `Iam::delete_access_key`.
+    pub fn delete_access_key(
+        &self,
+        input: DeleteAccessKeyRequest,
+    ) -> Result<(), RusotoError<DeleteAccessKeyError>> {
+        self.runtime.block_on(self.client.delete_access_key(input))
+    }
+
+    /// <p> Deletes the specified AWS account alias. For information about using an AWS account alias, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AccountAlias.html">Using an Alias for Your AWS Account ID</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_account_alias`.
+    pub fn delete_account_alias(
+        &self,
+        input: DeleteAccountAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAccountAliasError>> {
+        self.runtime
+            .block_on(self.client.delete_account_alias(input))
+    }
+
+    /// <p>Deletes the password policy for the AWS account. There are no parameters.</p>
+    ///
+    /// See `Iam::delete_account_password_policy`.
+    pub fn delete_account_password_policy(
+        &self,
+    ) -> Result<(), RusotoError<DeleteAccountPasswordPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_account_password_policy())
+    }
+
+    /// <p>Deletes the specified IAM group. The group must not contain any users or have any attached policies.</p>
+    ///
+    /// See `Iam::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(), RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Deletes the specified inline policy that is embedded in the specified IAM group.</p> <p>A group can also have managed policies attached to it. To detach a managed policy from a group, use <a>DetachGroupPolicy</a>. For more information about policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_group_policy`.
+    pub fn delete_group_policy(
+        &self,
+        input: DeleteGroupPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_group_policy(input))
+    }
+
+    /// <p>Deletes the specified instance profile. The instance profile must not have an associated role.</p> <important> <p>Make sure that you do not have any Amazon EC2 instances running with the instance profile you are about to delete. Deleting a role or instance profile that is associated with a running instance will break any applications running on the instance.</p> </important> <p>For more information about instance profiles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a>.</p>
+    ///
+    /// See `Iam::delete_instance_profile`.
+    pub fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> Result<(), RusotoError<DeleteInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_instance_profile(input))
+    }
+
+    /// <p><p>Deletes the password for the specified IAM user, which terminates the user&#39;s ability to access AWS services through the AWS Management Console.</p> <important> <p> Deleting a user&#39;s password does not prevent a user from accessing AWS through the command line interface or the API. To prevent all user access, you must also either make any access keys inactive or delete them. For more information about making keys inactive or deleting them, see <a>UpdateAccessKey</a> and <a>DeleteAccessKey</a>. </p> </important></p>
+    ///
+    /// See `Iam::delete_login_profile`.
+    pub fn delete_login_profile(
+        &self,
+        input: DeleteLoginProfileRequest,
+    ) -> Result<(), RusotoError<DeleteLoginProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_login_profile(input))
+    }
+
+    /// <p>Deletes an OpenID Connect identity provider (IdP) resource object in IAM.</p> <p>Deleting an IAM OIDC provider resource does not update any roles that reference the provider as a principal in their trust policies. Any attempt to assume a role that references a deleted provider fails.</p> <p>This operation is idempotent; it does not fail or return an error if you call the operation for a provider that does not exist.</p>
+    ///
+    /// See `Iam::delete_open_id_connect_provider`.
+    pub fn delete_open_id_connect_provider(
+        &self,
+        input: DeleteOpenIDConnectProviderRequest,
+    ) -> Result<(), RusotoError<DeleteOpenIDConnectProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_open_id_connect_provider(input))
+    }
+
+    /// <p>Deletes the specified managed policy.</p> <p>Before you can delete a managed policy, you must first detach the policy from all users, groups, and roles that it is attached to. In addition, you must delete all the policy's versions. The following steps describe the process for deleting a managed policy:</p> <ul> <li> <p>Detach the policy from all users, groups, and roles that the policy is attached to, using the <a>DetachUserPolicy</a>, <a>DetachGroupPolicy</a>, or <a>DetachRolePolicy</a> API operations. To list all the users, groups, and roles that a policy is attached to, use <a>ListEntitiesForPolicy</a>.</p> </li> <li> <p>Delete all versions of the policy using <a>DeletePolicyVersion</a>. To list the policy's versions, use <a>ListPolicyVersions</a>. You cannot use <a>DeletePolicyVersion</a> to delete the version that is marked as the default version. You delete the policy's default version in the next step of the process.</p> </li> <li> <p>Delete the policy (this automatically deletes the policy's default version) using this API.</p> </li> </ul> <p>For information about managed policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_policy`.
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// <p>Deletes the specified version from the specified managed policy.</p> <p>You cannot delete the default version from a policy using this API. To delete the default version from a policy, use <a>DeletePolicy</a>. To find out which version of a policy is marked as the default version, use <a>ListPolicyVersions</a>.</p> <p>For information about versions for managed policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-versions.html">Versioning for Managed Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_policy_version`.
+    pub fn delete_policy_version(
+        &self,
+        input: DeletePolicyVersionRequest,
+    ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_policy_version(input))
+    }
+
+    /// <p><p>Deletes the specified role. The role must not have any policies attached. For more information about roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/WorkingWithRoles.html">Working with Roles</a>.</p> <important> <p>Make sure that you do not have any Amazon EC2 instances running with the role you are about to delete. Deleting a role or instance profile that is associated with a running instance will break any applications running on the instance.</p> </important></p>
+    ///
+    /// See `Iam::delete_role`.
+    pub fn delete_role(
+        &self,
+        input: DeleteRoleRequest,
+    ) -> Result<(), RusotoError<DeleteRoleError>> {
+        self.runtime.block_on(self.client.delete_role(input))
+    }
+
+    /// <p><p>Deletes the permissions boundary for the specified IAM role. </p> <important> <p>Deleting the permissions boundary for a role might increase its permissions. For example, it might allow anyone who assumes the role to perform all the actions granted in its permissions policies. </p> </important></p>
+    ///
+    /// See `Iam::delete_role_permissions_boundary`.
+    pub fn delete_role_permissions_boundary(
+        &self,
+        input: DeleteRolePermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<DeleteRolePermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.delete_role_permissions_boundary(input))
+    }
+
+    /// <p>Deletes the specified inline policy that is embedded in the specified IAM role.</p> <p>A role can also have managed policies attached to it. To detach a managed policy from a role, use <a>DetachRolePolicy</a>. For more information about policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_role_policy`.
+    pub fn delete_role_policy(
+        &self,
+        input: DeleteRolePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteRolePolicyError>> {
+        self.runtime.block_on(self.client.delete_role_policy(input))
+    }
+
+    /// <p><p>Deletes a SAML provider resource in IAM.</p> <p>Deleting the provider resource from IAM does not update any roles that reference the SAML provider resource&#39;s ARN as a principal in their trust policies. Any attempt to assume a role that references a non-existent provider resource ARN fails.</p> <note> <p> This operation requires <a href="https://docs.aws.amazon.com/general/latest/gr/signature-version-4.html">Signature Version 4</a>.</p> </note></p>
+    ///
+    /// See `Iam::delete_saml_provider`.
+    pub fn delete_saml_provider(
+        &self,
+        input: DeleteSAMLProviderRequest,
+    ) -> Result<(), RusotoError<DeleteSAMLProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_saml_provider(input))
+    }
+
+    /// <p>Deletes the specified SSH public key.</p> <p>The SSH public key deleted by this operation is used only for authenticating the associated IAM user to an AWS CodeCommit repository. For more information about using SSH keys to authenticate to an AWS CodeCommit repository, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/setting-up-credentials-ssh.html">Set up AWS CodeCommit for SSH Connections</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_ssh_public_key`.
+    pub fn delete_ssh_public_key(
+        &self,
+        input: DeleteSSHPublicKeyRequest,
+    ) -> Result<(), RusotoError<DeleteSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.delete_ssh_public_key(input))
+    }
+
+    /// <p><p>Deletes the specified server certificate.</p> <p>For more information about working with server certificates, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_server-certs.html">Working with Server Certificates</a> in the <i>IAM User Guide</i>. This topic also includes a list of AWS services that can use the server certificates that you manage with IAM.</p> <important> <p> If you are using a server certificate with Elastic Load Balancing, deleting the certificate could have implications for your application. If Elastic Load Balancing doesn&#39;t detect the deletion of bound certificates, it may continue to use the certificates. This could cause Elastic Load Balancing to stop accepting traffic. We recommend that you remove the reference to the certificate from Elastic Load Balancing before using this command to delete the certificate. For more information, go to <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/APIReference/API_DeleteLoadBalancerListeners.html">DeleteLoadBalancerListeners</a> in the <i>Elastic Load Balancing API Reference</i>.</p> </important></p>
+    ///
+    /// See `Iam::delete_server_certificate`.
+    pub fn delete_server_certificate(
+        &self,
+        input: DeleteServerCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_server_certificate(input))
+    }
+
+    /// <p>Submits a service-linked role deletion request and returns a <code>DeletionTaskId</code>, which you can use to check the status of the deletion. Before you call this operation, confirm that the role has no active sessions and that any resources used by the role in the linked service are deleted. If you call this operation more than once for the same service-linked role and an earlier deletion task is not complete, then the <code>DeletionTaskId</code> of the earlier request is returned.</p> <p>If you submit a deletion request for a service-linked role whose linked service is still accessing a resource, then the deletion task fails. If it fails, the <a>GetServiceLinkedRoleDeletionStatus</a> API operation returns the reason for the failure, usually including the resources that must be deleted. To delete the service-linked role, you must first remove those resources from the linked service and then submit the deletion request again. Resources are specific to the service that is linked to the role. For more information about removing resources from a service, see the <a href="http://docs.aws.amazon.com/">AWS documentation</a> for your service.</p> <p>For more information about service-linked roles, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_terms-and-concepts.html#iam-term-service-linked-role">Roles Terms and Concepts: AWS Service-Linked Role</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_service_linked_role`.
+    pub fn delete_service_linked_role(
+        &self,
+        input: DeleteServiceLinkedRoleRequest,
+    ) -> Result<DeleteServiceLinkedRoleResponse, RusotoError<DeleteServiceLinkedRoleError>> {
+        self.runtime
+            .block_on(self.client.delete_service_linked_role(input))
+    }
+
+    /// <p>Deletes the specified service-specific credential.</p>
+    ///
+    /// See `Iam::delete_service_specific_credential`.
+    pub fn delete_service_specific_credential(
+        &self,
+        input: DeleteServiceSpecificCredentialRequest,
+    ) -> Result<(), RusotoError<DeleteServiceSpecificCredentialError>> {
+        self.runtime
+            .block_on(self.client.delete_service_specific_credential(input))
+    }
+
+    /// <p>Deletes a signing certificate associated with the specified IAM user.</p> <p>If you do not specify a user name, IAM determines the user name implicitly based on the AWS access key ID signing the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated IAM users.</p>
+    ///
+    /// See `Iam::delete_signing_certificate`.
+    pub fn delete_signing_certificate(
+        &self,
+        input: DeleteSigningCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_signing_certificate(input))
+    }
+
+    /// <p><p>Deletes the specified IAM user. Unlike the AWS Management Console, when you delete a user programmatically, you must delete the items attached to the user manually, or the deletion fails. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_users_manage.html#id_users_deleting_cli">Deleting an IAM User</a>. Before attempting to delete a user, remove the following items:</p> <ul> <li> <p>Password (<a>DeleteLoginProfile</a>)</p> </li> <li> <p>Access keys (<a>DeleteAccessKey</a>)</p> </li> <li> <p>Signing certificate (<a>DeleteSigningCertificate</a>)</p> </li> <li> <p>SSH public key (<a>DeleteSSHPublicKey</a>)</p> </li> <li> <p>Git credentials (<a>DeleteServiceSpecificCredential</a>)</p> </li> <li> <p>Multi-factor authentication (MFA) device (<a>DeactivateMFADevice</a>, <a>DeleteVirtualMFADevice</a>)</p> </li> <li> <p>Inline policies (<a>DeleteUserPolicy</a>)</p> </li> <li> <p>Attached managed policies (<a>DetachUserPolicy</a>)</p> </li> <li> <p>Group memberships (<a>RemoveUserFromGroup</a>)</p> </li> </ul></p>
+    ///
+    /// See `Iam::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p><p>Deletes the permissions boundary for the specified IAM user.</p> <important> <p>Deleting the permissions boundary for a user might increase its permissions by allowing the user to perform all the actions granted in its permissions policies. </p> </important></p>
+    ///
+    /// See `Iam::delete_user_permissions_boundary`.
+    pub fn delete_user_permissions_boundary(
+        &self,
+        input: DeleteUserPermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<DeleteUserPermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.delete_user_permissions_boundary(input))
+    }
+
+    /// <p>Deletes the specified inline policy that is embedded in the specified IAM user.</p> <p>A user can also have managed policies attached to it. To detach a managed policy from a user, use <a>DetachUserPolicy</a>. For more information about policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::delete_user_policy`.
+    pub fn delete_user_policy(
+        &self,
+        input: DeleteUserPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteUserPolicyError>> {
+        self.runtime.block_on(self.client.delete_user_policy(input))
+    }
+
+    /// <p><p>Deletes a virtual MFA device.</p> <note> <p> You must deactivate a user&#39;s virtual MFA device before you can delete it. For information about deactivating MFA devices, see <a>DeactivateMFADevice</a>. </p> </note></p>
+    ///
+    /// See `Iam::delete_virtual_mfa_device`.
+    pub fn delete_virtual_mfa_device(
+        &self,
+        input: DeleteVirtualMFADeviceRequest,
+    ) -> Result<(), RusotoError<DeleteVirtualMFADeviceError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_mfa_device(input))
+    }
+
+    /// <p>Removes the specified managed policy from the specified IAM group.</p> <p>A group can also have inline policies embedded with it. To delete an inline policy, use the <a>DeleteGroupPolicy</a> API. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::detach_group_policy`.
+    pub fn detach_group_policy(
+        &self,
+        input: DetachGroupPolicyRequest,
+    ) -> Result<(), RusotoError<DetachGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.detach_group_policy(input))
+    }
+
+    /// <p>Removes the specified managed policy from the specified role.</p> <p>A role can also have inline policies embedded with it. To delete an inline policy, use the <a>DeleteRolePolicy</a> API. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::detach_role_policy`.
+    pub fn detach_role_policy(
+        &self,
+        input: DetachRolePolicyRequest,
+    ) -> Result<(), RusotoError<DetachRolePolicyError>> {
+        self.runtime.block_on(self.client.detach_role_policy(input))
+    }
+
+    /// <p>Removes the specified managed policy from the specified user.</p> <p>A user can also have inline policies embedded with it. To delete an inline policy, use the <a>DeleteUserPolicy</a> API. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::detach_user_policy`.
+    pub fn detach_user_policy(
+        &self,
+        input: DetachUserPolicyRequest,
+    ) -> Result<(), RusotoError<DetachUserPolicyError>> {
+        self.runtime.block_on(self.client.detach_user_policy(input))
+    }
+
+    /// <p>Enables the specified MFA device and associates it with the specified IAM user. When enabled, the MFA device is required for every subsequent login by the IAM user associated with the device.</p>
+    ///
+    /// See `Iam::enable_mfa_device`.
+    pub fn enable_mfa_device(
+        &self,
+        input: EnableMFADeviceRequest,
+    ) -> Result<(), RusotoError<EnableMFADeviceError>> {
+        self.runtime.block_on(self.client.enable_mfa_device(input))
+    }
+
+    /// <p> Generates a credential report for the AWS account. For more information about the credential report, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/credential-reports.html">Getting Credential Reports</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::generate_credential_report`.
+    pub fn generate_credential_report(
+        &self,
+    ) -> Result<GenerateCredentialReportResponse, RusotoError<GenerateCredentialReportError>> {
+        self.runtime
+            .block_on(self.client.generate_credential_report())
+    }
+
+    /// <p>Generates a report for service last accessed data for AWS Organizations. You can generate a report for any entities (organization root, organizational unit, or account) or policies in your organization.</p> <p>To call this operation, you must be signed in using your AWS Organizations master account credentials. You can use your long-term IAM user or root user credentials, or temporary credentials from assuming an IAM role. SCPs must be enabled for your organization root. You must have the required IAM and AWS Organizations permissions. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html">Refining Permissions Using Service Last Accessed Data</a> in the <i>IAM User Guide</i>.</p> <p>You can generate a service last accessed data report for entities by specifying only the entity's path. This data includes a list of services that are allowed by any service control policies (SCPs) that apply to the entity.</p> <p>You can generate a service last accessed data report for a policy by specifying an entity's path and an optional AWS Organizations policy ID. This data includes a list of services that are allowed by the specified SCP.</p> <p>For each service in both report types, the data includes the most recent account activity that the policy allows to account principals in the entity or the entity's children. For important information about the data, reporting period, permissions required, troubleshooting, and supported Regions see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html">Reducing Permissions Using Service Last Accessed Data</a> in the <i>IAM User Guide</i>.</p> <important> <p>The data includes all attempts to access AWS, not just the successful ones. This includes all attempts that were made using the AWS Management Console, the AWS API through any of the SDKs, or any of the command line tools. An unexpected entry in the service last accessed data does not mean that an account has been compromised, because the request might have been denied. Refer to your CloudTrail logs as the authoritative source for information about all API calls and whether they were successful or denied access. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/cloudtrail-integration.html">Logging IAM Events with CloudTrail</a> in the <i>IAM User Guide</i>.</p> </important> <p>This operation returns a <code>JobId</code>. Use this parameter in the <code> <a>GetOrganizationsAccessReport</a> </code> operation to check the status of the report generation. To check the status of this request, use the <code>JobId</code> parameter in the <code> <a>GetOrganizationsAccessReport</a> </code> operation and test the <code>JobStatus</code> response parameter. When the job is complete, you can retrieve the report.</p> <p>To generate a service last accessed data report for entities, specify an entity path without specifying the optional AWS Organizations policy ID. The type of entity that you specify determines the data returned in the report.</p> <ul> <li> <p> <b>Root</b> – When you specify the organizations root as the entity, the resulting report lists all of the services allowed by SCPs that are attached to your root. For each service, the report includes data for all accounts in your organization except the master account, because the master account is not limited by SCPs.</p> </li> <li> <p> <b>OU</b> – When you specify an organizational unit (OU) as the entity, the resulting report lists all of the services allowed by SCPs that are attached to the OU and its parents. For each service, the report includes data for all accounts in the OU or its children. This data excludes the master account, because the master account is not limited by SCPs.</p> </li> <li> <p> <b>Master account</b> – When you specify the master account, the resulting report lists all AWS services, because the master account is not limited by SCPs. For each service, the report includes data for only the master account.</p> </li> <li> <p> <b>Account</b> – When you specify another account as the entity, the resulting report lists all of the services allowed by SCPs that are attached to the account and its parents. For each service, the report includes data for only the specified account.</p> </li> </ul> <p>To generate a service last accessed data report for policies, specify an entity path and the optional AWS Organizations policy ID. The type of entity that you specify determines the data returned for each service.</p> <ul> <li> <p> <b>Root</b> – When you specify the root entity and a policy ID, the resulting report lists all of the services that are allowed by the specified SCP. For each service, the report includes data for all accounts in your organization to which the SCP applies. This data excludes the master account, because the master account is not limited by SCPs. If the SCP is not attached to any entities in the organization, then the report will return a list of services with no data.</p> </li> <li> <p> <b>OU</b> – When you specify an OU entity and a policy ID, the resulting report lists all of the services that are allowed by the specified SCP. For each service, the report includes data for all accounts in the OU or its children to which the SCP applies. This means that other accounts outside the OU that are affected by the SCP might not be included in the data. This data excludes the master account, because the master account is not limited by SCPs. If the SCP is not attached to the OU or one of its children, the report will return a list of services with no data.</p> </li> <li> <p> <b>Master account</b> – When you specify the master account, the resulting report lists all AWS services, because the master account is not limited by SCPs. If you specify a policy ID in the CLI or API, the policy is ignored. For each service, the report includes data for only the master account.</p> </li> <li> <p> <b>Account</b> – When you specify another account entity and a policy ID, the resulting report lists all of the services that are allowed by the specified SCP. For each service, the report includes data for only the specified account. This means that other accounts in the organization that are affected by the SCP might not be included in the data. If the SCP is not attached to the account, the report will return a list of services with no data.</p> </li> </ul> <note> <p>Service last accessed data does not use other policy types when determining whether a principal could access a service. These other policy types include identity-based policies, resource-based policies, access control lists, IAM permissions boundaries, and STS assume role policies. It only applies SCP logic. For more about the evaluation of policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html#policy-eval-basics">Evaluating Policies</a> in the <i>IAM User Guide</i>.</p> </note> <p>For more information about service last accessed data, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html">Reducing Policy Scope by Viewing User Activity</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::generate_organizations_access_report`.
+    pub fn generate_organizations_access_report(
+        &self,
+        input: GenerateOrganizationsAccessReportRequest,
+    ) -> Result<
+        GenerateOrganizationsAccessReportResponse,
+        RusotoError<GenerateOrganizationsAccessReportError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_organizations_access_report(input))
+    }
+
+    /// <p>Generates a report that includes details about when an IAM resource (user, group, role, or policy) was last used in an attempt to access AWS services. Recent activity usually appears within four hours. IAM reports activity for the last 365 days, or less if your Region began supporting this feature within the last year. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#access-advisor_tracking-period">Regions Where Data Is Tracked</a>.</p> <important> <p>The service last accessed data includes all attempts to access an AWS API, not just the successful ones. This includes all attempts that were made using the AWS Management Console, the AWS API through any of the SDKs, or any of the command line tools. An unexpected entry in the service last accessed data does not mean that your account has been compromised, because the request might have been denied. Refer to your CloudTrail logs as the authoritative source for information about all API calls and whether they were successful or denied access. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/cloudtrail-integration.html">Logging IAM Events with CloudTrail</a> in the <i>IAM User Guide</i>.</p> </important> <p>The <code>GenerateServiceLastAccessedDetails</code> operation returns a <code>JobId</code>. Use this parameter in the following operations to retrieve the following details from your report: </p> <ul> <li> <p> <a>GetServiceLastAccessedDetails</a> – Use this operation for users, groups, roles, or policies to list every AWS service that the resource could access using permissions policies. For each service, the response includes information about the most recent access attempt.</p> <p>The <code>JobId</code> returned by <code>GenerateServiceLastAccessedDetail</code> must be used by the same role within a session, or by the same user when used to call <code>GetServiceLastAccessedDetail</code>.</p> </li> <li> <p> <a>GetServiceLastAccessedDetailsWithEntities</a> – Use this operation for groups and policies to list information about the associated entities (users or roles) that attempted to access a specific AWS service. </p> </li> </ul> <p>To check the status of the <code>GenerateServiceLastAccessedDetails</code> request, use the <code>JobId</code> parameter in the same operations and test the <code>JobStatus</code> response parameter.</p> <p>For additional information about the permissions policies that allow an identity (user, group, or role) to access specific services, use the <a>ListPoliciesGrantingServiceAccess</a> operation.</p> <note> <p>Service last accessed data does not use other policy types when determining whether a resource could access a service. These other policy types include resource-based policies, access control lists, AWS Organizations policies, IAM permissions boundaries, and AWS STS assume role policies. It only applies permissions policy logic. For more about the evaluation of policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html#policy-eval-basics">Evaluating Policies</a> in the <i>IAM User Guide</i>.</p> </note> <p>For more information about service and action last accessed data, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html">Reducing Permissions Using Service Last Accessed Data</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::generate_service_last_accessed_details`.
+    pub fn generate_service_last_accessed_details(
+        &self,
+        input: GenerateServiceLastAccessedDetailsRequest,
+    ) -> Result<
+        GenerateServiceLastAccessedDetailsResponse,
+        RusotoError<GenerateServiceLastAccessedDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_service_last_accessed_details(input))
+    }
+
+    /// <p>Retrieves information about when the specified access key was last used. The information includes the date and time of last use, along with the AWS service and Region that were specified in the last request made with that key.</p>
+    ///
+    /// See `Iam::get_access_key_last_used`.
+    pub fn get_access_key_last_used(
+        &self,
+        input: GetAccessKeyLastUsedRequest,
+    ) -> Result<GetAccessKeyLastUsedResponse, RusotoError<GetAccessKeyLastUsedError>> {
+        self.runtime
+            .block_on(self.client.get_access_key_last_used(input))
+    }
+
+    /// <p>Retrieves information about all IAM users, groups, roles, and policies in your AWS account, including their relationships to one another. Use this API to obtain a snapshot of the configuration of IAM permissions (users, groups, roles, and policies) in your account.</p> <note> <p>Policies returned by this API are URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p> </note> <p>You can optionally filter the results using the <code>Filter</code> parameter. You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::get_account_authorization_details`.
+    pub fn get_account_authorization_details(
+        &self,
+        input: GetAccountAuthorizationDetailsRequest,
+    ) -> Result<
+        GetAccountAuthorizationDetailsResponse,
+        RusotoError<GetAccountAuthorizationDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_account_authorization_details(input))
+    }
+
+    /// <p>Retrieves the password policy for the AWS account. For more information about using a password policy, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_ManagingPasswordPolicies.html">Managing an IAM Password Policy</a>.</p>
+    ///
+    /// See `Iam::get_account_password_policy`.
+    pub fn get_account_password_policy(
+        &self,
+    ) -> Result<GetAccountPasswordPolicyResponse, RusotoError<GetAccountPasswordPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_account_password_policy())
+    }
+
+    /// <p>Retrieves information about IAM entity usage and IAM quotas in the AWS account.</p> <p>The number and size of IAM resources in an AWS account are limited. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-quotas.html">IAM and STS Quotas</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_account_summary`.
+    pub fn get_account_summary(
+        &self,
+    ) -> Result<GetAccountSummaryResponse, RusotoError<GetAccountSummaryError>> {
+        self.runtime.block_on(self.client.get_account_summary())
+    }
+
+    /// <p>Gets a list of all of the context keys referenced in the input policies. The policies are supplied as a list of one or more strings. To get the context keys from policies associated with an IAM user, group, or role, use <a>GetContextKeysForPrincipalPolicy</a>.</p> <p>Context keys are variables maintained by AWS and its services that provide details about the context of an API query request. Context keys can be evaluated by testing against a value specified in an IAM policy. Use <code>GetContextKeysForCustomPolicy</code> to understand what key names and values you must supply when you call <a>SimulateCustomPolicy</a>. Note that all parameters are shown in unencoded form here for clarity but must be URL encoded to be included as a part of a real HTML request.</p>
+    ///
+    /// See `Iam::get_context_keys_for_custom_policy`.
+    pub fn get_context_keys_for_custom_policy(
+        &self,
+        input: GetContextKeysForCustomPolicyRequest,
+    ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForCustomPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_context_keys_for_custom_policy(input))
+    }
+
+    /// <p>Gets a list of all of the context keys referenced in all the IAM policies that are attached to the specified IAM entity. The entity can be an IAM user, group, or role. If you specify a user, then the request also includes all of the policies attached to groups that the user is a member of.</p> <p>You can optionally include a list of one or more additional policies, specified as strings. If you want to include <i>only</i> a list of policies by string, use <a>GetContextKeysForCustomPolicy</a> instead.</p> <p> <b>Note:</b> This API discloses information about the permissions granted to other users. If you do not want users to see other user's permissions, then consider allowing them to use <a>GetContextKeysForCustomPolicy</a> instead.</p> <p>Context keys are variables maintained by AWS and its services that provide details about the context of an API query request. Context keys can be evaluated by testing against a value in an IAM policy. Use <a>GetContextKeysForPrincipalPolicy</a> to understand what key names and values you must supply when you call <a>SimulatePrincipalPolicy</a>.</p>
+    ///
+    /// See `Iam::get_context_keys_for_principal_policy`.
+    pub fn get_context_keys_for_principal_policy(
+        &self,
+        input: GetContextKeysForPrincipalPolicyRequest,
+    ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForPrincipalPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_context_keys_for_principal_policy(input))
+    }
+
+    /// <p> Retrieves a credential report for the AWS account. For more information about the credential report, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/credential-reports.html">Getting Credential Reports</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_credential_report`.
+    pub fn get_credential_report(
+        &self,
+    ) -> Result<GetCredentialReportResponse, RusotoError<GetCredentialReportError>> {
+        self.runtime.block_on(self.client.get_credential_report())
+    }
+
+    /// <p> Returns a list of IAM users that are in the specified IAM group. You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::get_group`.
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// <p>Retrieves the specified inline policy document that is embedded in the specified IAM group.</p> <note> <p>Policies returned by this API are URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p> </note> <p>An IAM group can also have managed policies attached to it. To retrieve a managed policy document that is attached to a group, use <a>GetPolicy</a> to determine the policy's default version, then use <a>GetPolicyVersion</a> to retrieve the policy document.</p> <p>For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_group_policy`.
+    pub fn get_group_policy(
+        &self,
+        input: GetGroupPolicyRequest,
+    ) -> Result<GetGroupPolicyResponse, RusotoError<GetGroupPolicyError>> {
+        self.runtime.block_on(self.client.get_group_policy(input))
+    }
+
+    /// <p> Retrieves information about the specified instance profile, including the instance profile's path, GUID, ARN, and role. For more information about instance profiles, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_instance_profile`.
+    pub fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<GetInstanceProfileResponse, RusotoError<GetInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.get_instance_profile(input))
+    }
+
+    /// <p>Retrieves the user name and password-creation date for the specified IAM user. If the user has not been assigned a password, the operation returns a 404 (<code>NoSuchEntity</code>) error.</p>
+    ///
+    /// See `Iam::get_login_profile`.
+    pub fn get_login_profile(
+        &self,
+        input: GetLoginProfileRequest,
+    ) -> Result<GetLoginProfileResponse, RusotoError<GetLoginProfileError>> {
+        self.runtime.block_on(self.client.get_login_profile(input))
+    }
+
+    /// <p>Returns information about the specified OpenID Connect (OIDC) provider resource object in IAM.</p>
+    ///
+    /// See `Iam::get_open_id_connect_provider`.
+    pub fn get_open_id_connect_provider(
+        &self,
+        input: GetOpenIDConnectProviderRequest,
+    ) -> Result<GetOpenIDConnectProviderResponse, RusotoError<GetOpenIDConnectProviderError>> {
+        self.runtime
+            .block_on(self.client.get_open_id_connect_provider(input))
+    }
+
+    /// <p>Retrieves the service last accessed data report for AWS Organizations that was previously generated using the <code> <a>GenerateOrganizationsAccessReport</a> </code> operation. This operation retrieves the status of your report job and the report contents.</p> <p>Depending on the parameters that you passed when you generated the report, the data returned could include different information. For details, see <a>GenerateOrganizationsAccessReport</a>.</p> <p>To call this operation, you must be signed in to the master account in your organization. SCPs must be enabled for your organization root. You must have permissions to perform this operation. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html">Refining Permissions Using Service Last Accessed Data</a> in the <i>IAM User Guide</i>.</p> <p>For each service that principals in an account (root users, IAM users, or IAM roles) could access using SCPs, the operation returns details about the most recent access attempt. If there was no attempt, the service is listed without details about the most recent attempt to access the service. If the operation fails, it returns the reason that it failed.</p> <p>By default, the list is sorted by service namespace.</p>
+    ///
+    /// See `Iam::get_organizations_access_report`.
+    pub fn get_organizations_access_report(
+        &self,
+        input: GetOrganizationsAccessReportRequest,
+    ) -> Result<GetOrganizationsAccessReportResponse, RusotoError<GetOrganizationsAccessReportError>>
+    {
+        self.runtime
+            .block_on(self.client.get_organizations_access_report(input))
+    }
+
+    /// <p>Retrieves information about the specified managed policy, including the policy's default version and the total number of IAM users, groups, and roles to which the policy is attached. To retrieve the list of the specific users, groups, and roles that the policy is attached to, use the <a>ListEntitiesForPolicy</a> API. This API returns metadata about the policy. To retrieve the actual policy document for a specific version of the policy, use <a>GetPolicyVersion</a>.</p> <p>This API retrieves information about managed policies. To retrieve information about an inline policy that is embedded with an IAM user, group, or role, use the <a>GetUserPolicy</a>, <a>GetGroupPolicy</a>, or <a>GetRolePolicy</a> API.</p> <p>For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_policy`.
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// <p>Retrieves information about the specified version of the specified managed policy, including the policy document.</p> <note> <p>Policies returned by this API are URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p> </note> <p>To list the available versions for a policy, use <a>ListPolicyVersions</a>.</p> <p>This API retrieves information about managed policies. To retrieve information about an inline policy that is embedded in a user, group, or role, use the <a>GetUserPolicy</a>, <a>GetGroupPolicy</a>, or <a>GetRolePolicy</a> API.</p> <p>For more information about the types of policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>For more information about managed policy versions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-versions.html">Versioning for Managed Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_policy_version`.
+    pub fn get_policy_version(
+        &self,
+        input: GetPolicyVersionRequest,
+    ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
+        self.runtime.block_on(self.client.get_policy_version(input))
+    }
+
+    /// <p><p>Retrieves information about the specified role, including the role&#39;s path, GUID, ARN, and the role&#39;s trust policy that grants permission to assume the role. For more information about roles, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/WorkingWithRoles.html">Working with Roles</a>.</p> <note> <p>Policies returned by this API are URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p> </note></p>
+    ///
+    /// See `Iam::get_role`.
+    pub fn get_role(
+        &self,
+        input: GetRoleRequest,
+    ) -> Result<GetRoleResponse, RusotoError<GetRoleError>> {
+        self.runtime.block_on(self.client.get_role(input))
+    }
+
+    /// <p>Retrieves the specified inline policy document that is embedded with the specified IAM role.</p> <note> <p>Policies returned by this API are URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p> </note> <p>An IAM role can also have managed policies attached to it. To retrieve a managed policy document that is attached to a role, use <a>GetPolicy</a> to determine the policy's default version, then use <a>GetPolicyVersion</a> to retrieve the policy document.</p> <p>For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>For more information about roles, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/roles-toplevel.html">Using Roles to Delegate Permissions and Federate Identities</a>.</p>
+    ///
+    /// See `Iam::get_role_policy`.
+    pub fn get_role_policy(
+        &self,
+        input: GetRolePolicyRequest,
+    ) -> Result<GetRolePolicyResponse, RusotoError<GetRolePolicyError>> {
+        self.runtime.block_on(self.client.get_role_policy(input))
+    }
+
+    /// <p><p>Returns the SAML provider metadocument that was uploaded when the IAM SAML provider resource object was created or updated.</p> <note> <p>This operation requires <a href="https://docs.aws.amazon.com/general/latest/gr/signature-version-4.html">Signature Version 4</a>.</p> </note></p>
+    ///
+    /// See `Iam::get_saml_provider`.
+    pub fn get_saml_provider(
+        &self,
+        input: GetSAMLProviderRequest,
+    ) -> Result<GetSAMLProviderResponse, RusotoError<GetSAMLProviderError>> {
+        self.runtime.block_on(self.client.get_saml_provider(input))
+    }
+
+    /// <p>Retrieves the specified SSH public key, including metadata about the key.</p> <p>The SSH public key retrieved by this operation is used only for authenticating the associated IAM user to an AWS CodeCommit repository. For more information about using SSH keys to authenticate to an AWS CodeCommit repository, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/setting-up-credentials-ssh.html">Set up AWS CodeCommit for SSH Connections</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `Iam::get_ssh_public_key`.
+    pub fn get_ssh_public_key(
+        &self,
+        input: GetSSHPublicKeyRequest,
+    ) -> Result<GetSSHPublicKeyResponse, RusotoError<GetSSHPublicKeyError>> {
+        self.runtime.block_on(self.client.get_ssh_public_key(input))
+    }
+
+    /// <p>Retrieves information about the specified server certificate stored in IAM.</p> <p>For more information about working with server certificates, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_server-certs.html">Working with Server Certificates</a> in the <i>IAM User Guide</i>. This topic includes a list of AWS services that can use the server certificates that you manage with IAM.</p>
+    ///
+    /// See `Iam::get_server_certificate`.
+    pub fn get_server_certificate(
+        &self,
+        input: GetServerCertificateRequest,
+    ) -> Result<GetServerCertificateResponse, RusotoError<GetServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.get_server_certificate(input))
+    }
+
+    /// <p>Retrieves a service last accessed report that was created using the <code>GenerateServiceLastAccessedDetails</code> operation. You can use the <code>JobId</code> parameter in <code>GetServiceLastAccessedDetails</code> to retrieve the status of your report job. When the report is complete, you can retrieve the generated report. The report includes a list of AWS services that the resource (user, group, role, or managed policy) can access.</p> <note> <p>Service last accessed data does not use other policy types when determining whether a resource could access a service. These other policy types include resource-based policies, access control lists, AWS Organizations policies, IAM permissions boundaries, and AWS STS assume role policies. It only applies permissions policy logic. For more about the evaluation of policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html#policy-eval-basics">Evaluating Policies</a> in the <i>IAM User Guide</i>.</p> </note> <p>For each service that the resource could access using permissions policies, the operation returns details about the most recent access attempt. If there was no attempt, the service is listed without details about the most recent attempt to access the service. If the operation fails, the <code>GetServiceLastAccessedDetails</code> operation returns the reason that it failed.</p> <p>The <code>GetServiceLastAccessedDetails</code> operation returns a list of services. This list includes the number of entities that have attempted to access the service and the date and time of the last attempt. It also returns the ARN of the following entity, depending on the resource ARN that you used to generate the report:</p> <ul> <li> <p> <b>User</b> – Returns the user ARN that you used to generate the report</p> </li> <li> <p> <b>Group</b> – Returns the ARN of the group member (user) that last attempted to access the service</p> </li> <li> <p> <b>Role</b> – Returns the role ARN that you used to generate the report</p> </li> <li> <p> <b>Policy</b> – Returns the ARN of the user or role that last used the policy to attempt to access the service</p> </li> </ul> <p>By default, the list is sorted by service namespace.</p> <p>If you specified <code>ACTION_LEVEL</code> granularity when you generated the report, this operation returns service and action last accessed data. This includes the most recent access attempt for each tracked action within a service. Otherwise, this operation returns only service data.</p> <p>For more information about service and action last accessed data, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html">Reducing Permissions Using Service Last Accessed Data</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_service_last_accessed_details`.
+    pub fn get_service_last_accessed_details(
+        &self,
+        input: GetServiceLastAccessedDetailsRequest,
+    ) -> Result<
+        GetServiceLastAccessedDetailsResponse,
+        RusotoError<GetServiceLastAccessedDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_service_last_accessed_details(input))
+    }
+
+    /// <p>After you generate a group or policy report using the <code>GenerateServiceLastAccessedDetails</code> operation, you can use the <code>JobId</code> parameter in <code>GetServiceLastAccessedDetailsWithEntities</code>. This operation retrieves the status of your report job and a list of entities that could have used group or policy permissions to access the specified service.</p> <ul> <li> <p> <b>Group</b> – For a group report, this operation returns a list of users in the group that could have used the group’s policies in an attempt to access the service.</p> </li> <li> <p> <b>Policy</b> – For a policy report, this operation returns a list of entities (users or roles) that could have used the policy in an attempt to access the service.</p> </li> </ul> <p>You can also use this operation for user or role reports to retrieve details about those entities.</p> <p>If the operation fails, the <code>GetServiceLastAccessedDetailsWithEntities</code> operation returns the reason that it failed.</p> <p>By default, the list of associated entities is sorted by date, with the most recent access listed first.</p>
+    ///
+    /// See `Iam::get_service_last_accessed_details_with_entities`.
+    pub fn get_service_last_accessed_details_with_entities(
+        &self,
+        input: GetServiceLastAccessedDetailsWithEntitiesRequest,
+    ) -> Result<
+        GetServiceLastAccessedDetailsWithEntitiesResponse,
+        RusotoError<GetServiceLastAccessedDetailsWithEntitiesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_service_last_accessed_details_with_entities(input),
+        )
+    }
+
+    /// <p>Retrieves the status of your service-linked role deletion. After you use the <a>DeleteServiceLinkedRole</a> API operation to submit a service-linked role for deletion, you can use the <code>DeletionTaskId</code> parameter in <code>GetServiceLinkedRoleDeletionStatus</code> to check the status of the deletion. If the deletion fails, this operation returns the reason that it failed, if that information is returned by the service.</p>
+    ///
+    /// See `Iam::get_service_linked_role_deletion_status`.
+    pub fn get_service_linked_role_deletion_status(
+        &self,
+        input: GetServiceLinkedRoleDeletionStatusRequest,
+    ) -> Result<
+        GetServiceLinkedRoleDeletionStatusResponse,
+        RusotoError<GetServiceLinkedRoleDeletionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_service_linked_role_deletion_status(input))
+    }
+
+    /// <p>Retrieves information about the specified IAM user, including the user's creation date, path, unique ID, and ARN.</p> <p>If you do not specify a user name, IAM determines the user name implicitly based on the AWS access key ID used to sign the request to this API.</p>
+    ///
+    /// See `Iam::get_user`.
+    pub fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.runtime.block_on(self.client.get_user(input))
+    }
+
+    /// <p>Retrieves the specified inline policy document that is embedded in the specified IAM user.</p> <note> <p>Policies returned by this API are URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p> </note> <p>An IAM user can also have managed policies attached to it. To retrieve a managed policy document that is attached to a user, use <a>GetPolicy</a> to determine the policy's default version. Then use <a>GetPolicyVersion</a> to retrieve the policy document.</p> <p>For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::get_user_policy`.
+    pub fn get_user_policy(
+        &self,
+        input: GetUserPolicyRequest,
+    ) -> Result<GetUserPolicyResponse, RusotoError<GetUserPolicyError>> {
+        self.runtime.block_on(self.client.get_user_policy(input))
+    }
+
+    /// <p><p>Returns information about the access key IDs associated with the specified IAM user. If there is none, the operation returns an empty list.</p> <p>Although each user is limited to a small number of keys, you can still paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p> <p>If the <code>UserName</code> field is not specified, the user name is determined implicitly based on the AWS access key ID used to sign the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated users.</p> <note> <p>To ensure the security of your AWS account, the secret access key is accessible only during key and user creation.</p> </note></p>
+    ///
+    /// See `Iam::list_access_keys`.
+    pub fn list_access_keys(
+        &self,
+        input: ListAccessKeysRequest,
+    ) -> Result<ListAccessKeysResponse, RusotoError<ListAccessKeysError>> {
+        self.runtime.block_on(self.client.list_access_keys(input))
+    }
+
+    /// <p>Lists the account alias associated with the AWS account (Note: you can have only one). For information about using an AWS account alias, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AccountAlias.html">Using an Alias for Your AWS Account ID</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::list_account_aliases`.
+    pub fn list_account_aliases(
+        &self,
+        input: ListAccountAliasesRequest,
+    ) -> Result<ListAccountAliasesResponse, RusotoError<ListAccountAliasesError>> {
+        self.runtime
+            .block_on(self.client.list_account_aliases(input))
+    }
+
+    /// <p>Lists all managed policies that are attached to the specified IAM group.</p> <p>An IAM group can also have inline policies embedded with it. To list the inline policies for a group, use the <a>ListGroupPolicies</a> API. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters. You can use the <code>PathPrefix</code> parameter to limit the list of policies to only those matching the specified path prefix. If there are no policies attached to the specified group (or none that match the specified path prefix), the operation returns an empty list.</p>
+    ///
+    /// See `Iam::list_attached_group_policies`.
+    pub fn list_attached_group_policies(
+        &self,
+        input: ListAttachedGroupPoliciesRequest,
+    ) -> Result<ListAttachedGroupPoliciesResponse, RusotoError<ListAttachedGroupPoliciesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_attached_group_policies(input))
+    }
+
+    /// <p>Lists all managed policies that are attached to the specified IAM role.</p> <p>An IAM role can also have inline policies embedded with it. To list the inline policies for a role, use the <a>ListRolePolicies</a> API. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters. You can use the <code>PathPrefix</code> parameter to limit the list of policies to only those matching the specified path prefix. If there are no policies attached to the specified role (or none that match the specified path prefix), the operation returns an empty list.</p>
+    ///
+    /// See `Iam::list_attached_role_policies`.
+    pub fn list_attached_role_policies(
+        &self,
+        input: ListAttachedRolePoliciesRequest,
+    ) -> Result<ListAttachedRolePoliciesResponse, RusotoError<ListAttachedRolePoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_role_policies(input))
+    }
+
+    /// <p>Lists all managed policies that are attached to the specified IAM user.</p> <p>An IAM user can also have inline policies embedded with it. To list the inline policies for a user, use the <a>ListUserPolicies</a> API. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters. You can use the <code>PathPrefix</code> parameter to limit the list of policies to only those matching the specified path prefix. If there are no policies attached to the specified group (or none that match the specified path prefix), the operation returns an empty list.</p>
+    ///
+    /// See `Iam::list_attached_user_policies`.
+    pub fn list_attached_user_policies(
+        &self,
+        input: ListAttachedUserPoliciesRequest,
+    ) -> Result<ListAttachedUserPoliciesResponse, RusotoError<ListAttachedUserPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_user_policies(input))
+    }
+
+    /// <p>Lists all IAM users, groups, and roles that the specified managed policy is attached to.</p> <p>You can use the optional <code>EntityFilter</code> parameter to limit the results to a particular type of entity (users, groups, or roles). For example, to list only the roles that are attached to the specified policy, set <code>EntityFilter</code> to <code>Role</code>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_entities_for_policy`.
+    pub fn list_entities_for_policy(
+        &self,
+        input: ListEntitiesForPolicyRequest,
+    ) -> Result<ListEntitiesForPolicyResponse, RusotoError<ListEntitiesForPolicyError>> {
+        self.runtime
+            .block_on(self.client.list_entities_for_policy(input))
+    }
+
+    /// <p>Lists the names of the inline policies that are embedded in the specified IAM group.</p> <p>An IAM group can also have managed policies attached to it. To list the managed policies that are attached to a group, use <a>ListAttachedGroupPolicies</a>. For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters. If there are no inline policies embedded with the specified group, the operation returns an empty list.</p>
+    ///
+    /// See `Iam::list_group_policies`.
+    pub fn list_group_policies(
+        &self,
+        input: ListGroupPoliciesRequest,
+    ) -> Result<ListGroupPoliciesResponse, RusotoError<ListGroupPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_group_policies(input))
+    }
+
+    /// <p>Lists the IAM groups that have the specified path prefix.</p> <p> You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_groups`.
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// <p>Lists the IAM groups that the specified IAM user belongs to.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_groups_for_user`.
+    pub fn list_groups_for_user(
+        &self,
+        input: ListGroupsForUserRequest,
+    ) -> Result<ListGroupsForUserResponse, RusotoError<ListGroupsForUserError>> {
+        self.runtime
+            .block_on(self.client.list_groups_for_user(input))
+    }
+
+    /// <p>Lists the instance profiles that have the specified path prefix. If there are none, the operation returns an empty list. For more information about instance profiles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_instance_profiles`.
+    pub fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> Result<ListInstanceProfilesResponse, RusotoError<ListInstanceProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_instance_profiles(input))
+    }
+
+    /// <p>Lists the instance profiles that have the specified associated IAM role. If there are none, the operation returns an empty list. For more information about instance profiles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_instance_profiles_for_role`.
+    pub fn list_instance_profiles_for_role(
+        &self,
+        input: ListInstanceProfilesForRoleRequest,
+    ) -> Result<ListInstanceProfilesForRoleResponse, RusotoError<ListInstanceProfilesForRoleError>>
+    {
+        self.runtime
+            .block_on(self.client.list_instance_profiles_for_role(input))
+    }
+
+    /// <p>Lists the MFA devices for an IAM user. If the request includes a IAM user name, then this operation lists all the MFA devices associated with the specified user. If you do not specify a user name, IAM determines the user name implicitly based on the AWS access key ID signing the request for this API.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_mfa_devices`.
+    pub fn list_mfa_devices(
+        &self,
+        input: ListMFADevicesRequest,
+    ) -> Result<ListMFADevicesResponse, RusotoError<ListMFADevicesError>> {
+        self.runtime.block_on(self.client.list_mfa_devices(input))
+    }
+
+    /// <p>Lists information about the IAM OpenID Connect (OIDC) provider resource objects defined in the AWS account.</p>
+    ///
+    /// See `Iam::list_open_id_connect_providers`.
+    pub fn list_open_id_connect_providers(
+        &self,
+        input: ListOpenIDConnectProvidersRequest,
+    ) -> Result<ListOpenIDConnectProvidersResponse, RusotoError<ListOpenIDConnectProvidersError>>
+    {
+        self.runtime
+            .block_on(self.client.list_open_id_connect_providers(input))
+    }
+
+    /// <p>Lists all the managed policies that are available in your AWS account, including your own customer-defined managed policies and all AWS managed policies.</p> <p>You can filter the list of policies that is returned using the optional <code>OnlyAttached</code>, <code>Scope</code>, and <code>PathPrefix</code> parameters. For example, to list only the customer managed policies in your AWS account, set <code>Scope</code> to <code>Local</code>. To list only AWS managed policies, set <code>Scope</code> to <code>AWS</code>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p> <p>For more information about managed policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::list_policies`.
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// <p>Retrieves a list of policies that the IAM identity (user, group, or role) can use to access each specified service.</p> <note> <p>This operation does not use other policy types when determining whether a resource could access a service. These other policy types include resource-based policies, access control lists, AWS Organizations policies, IAM permissions boundaries, and AWS STS assume role policies. It only applies permissions policy logic. For more about the evaluation of policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html#policy-eval-basics">Evaluating Policies</a> in the <i>IAM User Guide</i>.</p> </note> <p>The list of policies returned by the operation depends on the ARN of the identity that you provide.</p> <ul> <li> <p> <b>User</b> – The list of policies includes the managed and inline policies that are attached to the user directly. The list also includes any additional managed and inline policies that are attached to the group to which the user belongs. </p> </li> <li> <p> <b>Group</b> – The list of policies includes only the managed and inline policies that are attached to the group directly. Policies that are attached to the group’s user are not included.</p> </li> <li> <p> <b>Role</b> – The list of policies includes only the managed and inline policies that are attached to the role.</p> </li> </ul> <p>For each managed policy, this operation returns the ARN and policy name. For each inline policy, it returns the policy name and the entity to which it is attached. Inline policies do not have an ARN. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>Policies that are attached to users and roles as permissions boundaries are not returned. To view which managed policy is currently used to set the permissions boundary for a user or role, use the <a>GetUser</a> or <a>GetRole</a> operations.</p>
+    ///
+    /// See `Iam::list_policies_granting_service_access`.
+    pub fn list_policies_granting_service_access(
+        &self,
+        input: ListPoliciesGrantingServiceAccessRequest,
+    ) -> Result<
+        ListPoliciesGrantingServiceAccessResponse,
+        RusotoError<ListPoliciesGrantingServiceAccessError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_policies_granting_service_access(input))
+    }
+
+    /// <p>Lists information about the versions of the specified managed policy, including the version that is currently set as the policy's default version.</p> <p>For more information about managed policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::list_policy_versions`.
+    pub fn list_policy_versions(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_versions(input))
+    }
+
+    /// <p>Lists the names of the inline policies that are embedded in the specified IAM role.</p> <p>An IAM role can also have managed policies attached to it. To list the managed policies that are attached to a role, use <a>ListAttachedRolePolicies</a>. For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters. If there are no inline policies embedded with the specified role, the operation returns an empty list.</p>
+    ///
+    /// See `Iam::list_role_policies`.
+    pub fn list_role_policies(
+        &self,
+        input: ListRolePoliciesRequest,
+    ) -> Result<ListRolePoliciesResponse, RusotoError<ListRolePoliciesError>> {
+        self.runtime.block_on(self.client.list_role_policies(input))
+    }
+
+    /// <p>Lists the tags that are attached to the specified role. The returned list of tags is sorted by tag key. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::list_role_tags`.
+    pub fn list_role_tags(
+        &self,
+        input: ListRoleTagsRequest,
+    ) -> Result<ListRoleTagsResponse, RusotoError<ListRoleTagsError>> {
+        self.runtime.block_on(self.client.list_role_tags(input))
+    }
+
+    /// <p>Lists the IAM roles that have the specified path prefix. If there are none, the operation returns an empty list. For more information about roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/WorkingWithRoles.html">Working with Roles</a>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_roles`.
+    pub fn list_roles(
+        &self,
+        input: ListRolesRequest,
+    ) -> Result<ListRolesResponse, RusotoError<ListRolesError>> {
+        self.runtime.block_on(self.client.list_roles(input))
+    }
+
+    /// <p><p>Lists the SAML provider resource objects defined in IAM in the account.</p> <note> <p> This operation requires <a href="https://docs.aws.amazon.com/general/latest/gr/signature-version-4.html">Signature Version 4</a>.</p> </note></p>
+    ///
+    /// See `Iam::list_saml_providers`.
+    pub fn list_saml_providers(
+        &self,
+        input: ListSAMLProvidersRequest,
+    ) -> Result<ListSAMLProvidersResponse, RusotoError<ListSAMLProvidersError>> {
+        self.runtime
+            .block_on(self.client.list_saml_providers(input))
+    }
+
+    /// <p>Returns information about the SSH public keys associated with the specified IAM user. If none exists, the operation returns an empty list.</p> <p>The SSH public keys returned by this operation are used only for authenticating the IAM user to an AWS CodeCommit repository. For more information about using SSH keys to authenticate to an AWS CodeCommit repository, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/setting-up-credentials-ssh.html">Set up AWS CodeCommit for SSH Connections</a> in the <i>AWS CodeCommit User Guide</i>.</p> <p>Although each user is limited to a small number of keys, you can still paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_ssh_public_keys`.
+    pub fn list_ssh_public_keys(
+        &self,
+        input: ListSSHPublicKeysRequest,
+    ) -> Result<ListSSHPublicKeysResponse, RusotoError<ListSSHPublicKeysError>> {
+        self.runtime
+            .block_on(self.client.list_ssh_public_keys(input))
+    }
+
+    /// <p>Lists the server certificates stored in IAM that have the specified path prefix. If none exist, the operation returns an empty list.</p> <p> You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p> <p>For more information about working with server certificates, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_server-certs.html">Working with Server Certificates</a> in the <i>IAM User Guide</i>. This topic also includes a list of AWS services that can use the server certificates that you manage with IAM.</p>
+    ///
+    /// See `Iam::list_server_certificates`.
+    pub fn list_server_certificates(
+        &self,
+        input: ListServerCertificatesRequest,
+    ) -> Result<ListServerCertificatesResponse, RusotoError<ListServerCertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_server_certificates(input))
+    }
+
+    /// <p>Returns information about the service-specific credentials associated with the specified IAM user. If none exists, the operation returns an empty list. The service-specific credentials returned by this operation are used only for authenticating the IAM user to a specific service. For more information about using service-specific credentials to authenticate to an AWS service, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/setting-up-gc.html">Set Up service-specific credentials</a> in the AWS CodeCommit User Guide.</p>
+    ///
+    /// See `Iam::list_service_specific_credentials`.
+    pub fn list_service_specific_credentials(
+        &self,
+        input: ListServiceSpecificCredentialsRequest,
+    ) -> Result<
+        ListServiceSpecificCredentialsResponse,
+        RusotoError<ListServiceSpecificCredentialsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_service_specific_credentials(input))
+    }
+
+    /// <p>Returns information about the signing certificates associated with the specified IAM user. If none exists, the operation returns an empty list.</p> <p>Although each user is limited to a small number of signing certificates, you can still paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p> <p>If the <code>UserName</code> field is not specified, the user name is determined implicitly based on the AWS access key ID used to sign the request for this API. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated users.</p>
+    ///
+    /// See `Iam::list_signing_certificates`.
+    pub fn list_signing_certificates(
+        &self,
+        input: ListSigningCertificatesRequest,
+    ) -> Result<ListSigningCertificatesResponse, RusotoError<ListSigningCertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_signing_certificates(input))
+    }
+
+    /// <p>Lists the names of the inline policies embedded in the specified IAM user.</p> <p>An IAM user can also have managed policies attached to it. To list the managed policies that are attached to a user, use <a>ListAttachedUserPolicies</a>. For more information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters. If there are no inline policies embedded with the specified user, the operation returns an empty list.</p>
+    ///
+    /// See `Iam::list_user_policies`.
+    pub fn list_user_policies(
+        &self,
+        input: ListUserPoliciesRequest,
+    ) -> Result<ListUserPoliciesResponse, RusotoError<ListUserPoliciesError>> {
+        self.runtime.block_on(self.client.list_user_policies(input))
+    }
+
+    /// <p>Lists the tags that are attached to the specified user. The returned list of tags is sorted by tag key. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::list_user_tags`.
+    pub fn list_user_tags(
+        &self,
+        input: ListUserTagsRequest,
+    ) -> Result<ListUserTagsResponse, RusotoError<ListUserTagsError>> {
+        self.runtime.block_on(self.client.list_user_tags(input))
+    }
+
+    /// <p>Lists the IAM users that have the specified path prefix. If no path prefix is specified, the operation returns all users in the AWS account. If there are none, the operation returns an empty list.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Lists the virtual MFA devices defined in the AWS account by assignment status. If you do not specify an assignment status, the operation returns a list of all virtual MFA devices. Assignment status can be <code>Assigned</code>, <code>Unassigned</code>, or <code>Any</code>.</p> <p>You can paginate the results using the <code>MaxItems</code> and <code>Marker</code> parameters.</p>
+    ///
+    /// See `Iam::list_virtual_mfa_devices`.
+    pub fn list_virtual_mfa_devices(
+        &self,
+        input: ListVirtualMFADevicesRequest,
+    ) -> Result<ListVirtualMFADevicesResponse, RusotoError<ListVirtualMFADevicesError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_mfa_devices(input))
+    }
+
+    /// <p><p>Adds or updates an inline policy document that is embedded in the specified IAM group.</p> <p>A user can also have managed policies attached to it. To attach a managed policy to a group, use <a>AttachGroupPolicy</a>. To create a new managed policy, use <a>CreatePolicy</a>. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>For information about limits on the number of inline policies that you can embed in a group, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/LimitationsOnEntities.html">Limitations on IAM Entities</a> in the <i>IAM User Guide</i>.</p> <note> <p>Because policy documents can be large, you should use POST rather than GET when calling <code>PutGroupPolicy</code>. For general information about using the Query API with IAM, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/IAM_UsingQueryAPI.html">Making Query Requests</a> in the <i>IAM User Guide</i>.</p> </note></p>
+    ///
+    /// See `Iam::put_group_policy`.
+    pub fn put_group_policy(
+        &self,
+        input: PutGroupPolicyRequest,
+    ) -> Result<(), RusotoError<PutGroupPolicyError>> {
+        self.runtime.block_on(self.client.put_group_policy(input))
+    }
+
+    /// <p><p>Adds or updates the policy that is specified as the IAM role&#39;s permissions boundary. You can use an AWS managed policy or a customer managed policy to set the boundary for a role. Use the boundary to control the maximum permissions that the role can have. Setting a permissions boundary is an advanced feature that can affect the permissions for the role.</p> <p>You cannot set the boundary for a service-linked role. </p> <important> <p>Policies used as permissions boundaries do not provide permissions. You must also attach a permissions policy to the role. To learn how the effective permissions for a role are evaluated, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html">IAM JSON Policy Evaluation Logic</a> in the IAM User Guide. </p> </important></p>
+    ///
+    /// See `Iam::put_role_permissions_boundary`.
+    pub fn put_role_permissions_boundary(
+        &self,
+        input: PutRolePermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<PutRolePermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.put_role_permissions_boundary(input))
+    }
+
+    /// <p><p>Adds or updates an inline policy document that is embedded in the specified IAM role.</p> <p>When you embed an inline policy in a role, the inline policy is used as part of the role&#39;s access (permissions) policy. The role&#39;s trust policy is created at the same time as the role, using <a>CreateRole</a>. You can update a role&#39;s trust policy using <a>UpdateAssumeRolePolicy</a>. For more information about IAM roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/roles-toplevel.html">Using Roles to Delegate Permissions and Federate Identities</a>.</p> <p>A role can also have a managed policy attached to it. To attach a managed policy to a role, use <a>AttachRolePolicy</a>. To create a new managed policy, use <a>CreatePolicy</a>. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>For information about limits on the number of inline policies that you can embed with a role, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/LimitationsOnEntities.html">Limitations on IAM Entities</a> in the <i>IAM User Guide</i>.</p> <note> <p>Because policy documents can be large, you should use POST rather than GET when calling <code>PutRolePolicy</code>. For general information about using the Query API with IAM, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/IAM_UsingQueryAPI.html">Making Query Requests</a> in the <i>IAM User Guide</i>.</p> </note></p>
+    ///
+    /// See `Iam::put_role_policy`.
+    pub fn put_role_policy(
+        &self,
+        input: PutRolePolicyRequest,
+    ) -> Result<(), RusotoError<PutRolePolicyError>> {
+        self.runtime.block_on(self.client.put_role_policy(input))
+    }
+
+    /// <p><p>Adds or updates the policy that is specified as the IAM user&#39;s permissions boundary. You can use an AWS managed policy or a customer managed policy to set the boundary for a user. Use the boundary to control the maximum permissions that the user can have. Setting a permissions boundary is an advanced feature that can affect the permissions for the user.</p> <important> <p>Policies that are used as permissions boundaries do not provide permissions. You must also attach a permissions policy to the user. To learn how the effective permissions for a user are evaluated, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_evaluation-logic.html">IAM JSON Policy Evaluation Logic</a> in the IAM User Guide. </p> </important></p>
+    ///
+    /// See `Iam::put_user_permissions_boundary`.
+    pub fn put_user_permissions_boundary(
+        &self,
+        input: PutUserPermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<PutUserPermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.put_user_permissions_boundary(input))
+    }
+
+    /// <p><p>Adds or updates an inline policy document that is embedded in the specified IAM user.</p> <p>An IAM user can also have a managed policy attached to it. To attach a managed policy to a user, use <a>AttachUserPolicy</a>. To create a new managed policy, use <a>CreatePolicy</a>. For information about policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p> <p>For information about limits on the number of inline policies that you can embed in a user, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/LimitationsOnEntities.html">Limitations on IAM Entities</a> in the <i>IAM User Guide</i>.</p> <note> <p>Because policy documents can be large, you should use POST rather than GET when calling <code>PutUserPolicy</code>. For general information about using the Query API with IAM, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/IAM_UsingQueryAPI.html">Making Query Requests</a> in the <i>IAM User Guide</i>.</p> </note></p>
+    ///
+    /// See `Iam::put_user_policy`.
+    pub fn put_user_policy(
+        &self,
+        input: PutUserPolicyRequest,
+    ) -> Result<(), RusotoError<PutUserPolicyError>> {
+        self.runtime.block_on(self.client.put_user_policy(input))
+    }
+
+    /// <p>Removes the specified client ID (also known as audience) from the list of client IDs registered for the specified IAM OpenID Connect (OIDC) provider resource object.</p> <p>This operation is idempotent; it does not fail or return an error if you try to remove a client ID that does not exist.</p>
+    ///
+    /// See `Iam::remove_client_id_from_open_id_connect_provider`.
+    pub fn remove_client_id_from_open_id_connect_provider(
+        &self,
+        input: RemoveClientIDFromOpenIDConnectProviderRequest,
+    ) -> Result<(), RusotoError<RemoveClientIDFromOpenIDConnectProviderError>> {
+        self.runtime.block_on(
+            self.client
+                .remove_client_id_from_open_id_connect_provider(input),
+        )
+    }
+
+    /// <p>Removes the specified IAM role from the specified EC2 instance profile.</p> <important> <p>Make sure that you do not have any Amazon EC2 instances running with the role you are about to remove from the instance profile. Removing a role from an instance profile that is associated with a running instance might break any applications running on the instance.</p> </important> <p> For more information about IAM roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/WorkingWithRoles.html">Working with Roles</a>. For more information about instance profiles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/AboutInstanceProfiles.html">About Instance Profiles</a>.</p>
+    ///
+    /// See `Iam::remove_role_from_instance_profile`.
+    pub fn remove_role_from_instance_profile(
+        &self,
+        input: RemoveRoleFromInstanceProfileRequest,
+    ) -> Result<(), RusotoError<RemoveRoleFromInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_instance_profile(input))
+    }
+
+    /// <p>Removes the specified user from the specified group.</p>
+    ///
+    /// See `Iam::remove_user_from_group`.
+    pub fn remove_user_from_group(
+        &self,
+        input: RemoveUserFromGroupRequest,
+    ) -> Result<(), RusotoError<RemoveUserFromGroupError>> {
+        self.runtime
+            .block_on(self.client.remove_user_from_group(input))
+    }
+
+    /// <p>Resets the password for a service-specific credential. The new password is AWS generated and cryptographically strong. It cannot be configured by the user. Resetting the password immediately invalidates the previous password associated with this user.</p>
+    ///
+    /// See `Iam::reset_service_specific_credential`.
+    pub fn reset_service_specific_credential(
+        &self,
+        input: ResetServiceSpecificCredentialRequest,
+    ) -> Result<
+        ResetServiceSpecificCredentialResponse,
+        RusotoError<ResetServiceSpecificCredentialError>,
+    > {
+        self.runtime
+            .block_on(self.client.reset_service_specific_credential(input))
+    }
+
+    /// <p>Synchronizes the specified MFA device with its IAM resource object on the AWS servers.</p> <p>For more information about creating and working with virtual MFA devices, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_VirtualMFA.html">Using a Virtual MFA Device</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::resync_mfa_device`.
+    pub fn resync_mfa_device(
+        &self,
+        input: ResyncMFADeviceRequest,
+    ) -> Result<(), RusotoError<ResyncMFADeviceError>> {
+        self.runtime.block_on(self.client.resync_mfa_device(input))
+    }
+
+    /// <p>Sets the specified version of the specified policy as the policy's default (operative) version.</p> <p>This operation affects all users, groups, and roles that the policy is attached to. To list the users, groups, and roles that the policy is attached to, use the <a>ListEntitiesForPolicy</a> API.</p> <p>For information about managed policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::set_default_policy_version`.
+    pub fn set_default_policy_version(
+        &self,
+        input: SetDefaultPolicyVersionRequest,
+    ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.set_default_policy_version(input))
+    }
+
+    /// <p>Sets the specified version of the global endpoint token as the token version used for the AWS account.</p> <p>By default, AWS Security Token Service (STS) is available as a global service, and all STS requests go to a single endpoint at <code>https://sts.amazonaws.com</code>. AWS recommends using Regional STS endpoints to reduce latency, build in redundancy, and increase session token availability. For information about Regional endpoints for STS, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html#sts_region">AWS Regions and Endpoints</a> in the <i>AWS General Reference</i>.</p> <p>If you make an STS call to the global endpoint, the resulting session tokens might be valid in some Regions but not others. It depends on the version that is set in this operation. Version 1 tokens are valid only in AWS Regions that are available by default. These tokens do not work in manually enabled Regions, such as Asia Pacific (Hong Kong). Version 2 tokens are valid in all Regions. However, version 2 tokens are longer and might affect systems where you temporarily store tokens. For information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p> <p>To view the current session token version, see the <code>GlobalEndpointTokenVersion</code> entry in the response of the <a>GetAccountSummary</a> operation.</p>
+    ///
+    /// See `Iam::set_security_token_service_preferences`.
+    pub fn set_security_token_service_preferences(
+        &self,
+        input: SetSecurityTokenServicePreferencesRequest,
+    ) -> Result<(), RusotoError<SetSecurityTokenServicePreferencesError>> {
+        self.runtime
+            .block_on(self.client.set_security_token_service_preferences(input))
+    }
+
+    /// <p>Simulate how a set of IAM policies and optionally a resource-based policy works with a list of API operations and AWS resources to determine the policies' effective permissions. The policies are provided as strings.</p> <p>The simulation does not perform the API operations; it only checks the authorization to determine if the simulated policies allow or deny the operations.</p> <p>If you want to simulate existing policies that are attached to an IAM user, group, or role, use <a>SimulatePrincipalPolicy</a> instead.</p> <p>Context keys are variables that are maintained by AWS and its services and which provide details about the context of an API query request. You can use the <code>Condition</code> element of an IAM policy to evaluate context keys. To get the list of context keys that the policies require for correct simulation, use <a>GetContextKeysForCustomPolicy</a>.</p> <p>If the output is long, you can use <code>MaxItems</code> and <code>Marker</code> parameters to paginate the results.</p>
+    ///
+    /// See `Iam::simulate_custom_policy`.
+    pub fn simulate_custom_policy(
+        &self,
+        input: SimulateCustomPolicyRequest,
+    ) -> Result<SimulatePolicyResponse, RusotoError<SimulateCustomPolicyError>> {
+        self.runtime
+            .block_on(self.client.simulate_custom_policy(input))
+    }
+
+    /// <p>Simulate how a set of IAM policies attached to an IAM entity works with a list of API operations and AWS resources to determine the policies' effective permissions. The entity can be an IAM user, group, or role. If you specify a user, then the simulation also includes all of the policies that are attached to groups that the user belongs to.</p> <p>You can optionally include a list of one or more additional policies specified as strings to include in the simulation. If you want to simulate only policies specified as strings, use <a>SimulateCustomPolicy</a> instead.</p> <p>You can also optionally include one resource-based policy to be evaluated with each of the resources included in the simulation.</p> <p>The simulation does not perform the API operations; it only checks the authorization to determine if the simulated policies allow or deny the operations.</p> <p> <b>Note:</b> This API discloses information about the permissions granted to other users. If you do not want users to see other user's permissions, then consider allowing them to use <a>SimulateCustomPolicy</a> instead.</p> <p>Context keys are variables maintained by AWS and its services that provide details about the context of an API query request. You can use the <code>Condition</code> element of an IAM policy to evaluate context keys. To get the list of context keys that the policies require for correct simulation, use <a>GetContextKeysForPrincipalPolicy</a>.</p> <p>If the output is long, you can use the <code>MaxItems</code> and <code>Marker</code> parameters to paginate the results.</p>
+    ///
+    /// See `Iam::simulate_principal_policy`.
+    pub fn simulate_principal_policy(
+        &self,
+        input: SimulatePrincipalPolicyRequest,
+    ) -> Result<SimulatePolicyResponse, RusotoError<SimulatePrincipalPolicyError>> {
+        self.runtime
+            .block_on(self.client.simulate_principal_policy(input))
+    }
+
+    /// <p>Adds one or more tags to an IAM role. The role can be a regular role or a service-linked role. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM user or role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::tag_role`.
+    pub fn tag_role(&self, input: TagRoleRequest) -> Result<(), RusotoError<TagRoleError>> {
+        self.runtime.block_on(self.client.tag_role(input))
+    }
+
+    /// <p>Adds one or more tags to an IAM user. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM requesting user or to a role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::tag_user`.
+    pub fn tag_user(&self, input: TagUserRequest) -> Result<(), RusotoError<TagUserError>> {
+        self.runtime.block_on(self.client.tag_user(input))
+    }
+
+    /// <p>Removes the specified tags from the role. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::untag_role`.
+    pub fn untag_role(&self, input: UntagRoleRequest) -> Result<(), RusotoError<UntagRoleError>> {
+        self.runtime.block_on(self.client.untag_role(input))
+    }
+
+    /// <p>Removes the specified tags from the user. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::untag_user`.
+    pub fn untag_user(&self, input: UntagUserRequest) -> Result<(), RusotoError<UntagUserError>> {
+        self.runtime.block_on(self.client.untag_user(input))
+    }
+
+    /// <p>Changes the status of the specified access key from Active to Inactive, or vice versa. This operation can be used to disable a user's key as part of a key rotation workflow.</p> <p>If the <code>UserName</code> is not specified, the user name is determined implicitly based on the AWS access key ID used to sign the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated users.</p> <p>For information about rotating keys, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/ManagingCredentials.html">Managing Keys and Certificates</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::update_access_key`.
+    pub fn update_access_key(
+        &self,
+        input: UpdateAccessKeyRequest,
+    ) -> Result<(), RusotoError<UpdateAccessKeyError>> {
+        self.runtime.block_on(self.client.update_access_key(input))
+    }
+
+    /// <p>Updates the password policy settings for the AWS account.</p> <note> <ul> <li> <p>This operation does not support partial updates. No parameters are required, but if you do not specify a parameter, that parameter's value reverts to its default value. See the <b>Request Parameters</b> section for each parameter's default value. Also note that some parameters do not allow the default parameter to be explicitly set. Instead, to invoke the default value, do not include that parameter when you invoke the operation.</p> </li> </ul> </note> <p> For more information about using a password policy, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_ManagingPasswordPolicies.html">Managing an IAM Password Policy</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::update_account_password_policy`.
+    pub fn update_account_password_policy(
+        &self,
+        input: UpdateAccountPasswordPolicyRequest,
+    ) -> Result<(), RusotoError<UpdateAccountPasswordPolicyError>> {
+        self.runtime
+            .block_on(self.client.update_account_password_policy(input))
+    }
+
+    /// <p>Updates the policy that grants an IAM entity permission to assume a role. This is typically referred to as the "role trust policy". For more information about roles, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/roles-toplevel.html">Using Roles to Delegate Permissions and Federate Identities</a>.</p>
+    ///
+    /// See `Iam::update_assume_role_policy`.
+    pub fn update_assume_role_policy(
+        &self,
+        input: UpdateAssumeRolePolicyRequest,
+    ) -> Result<(), RusotoError<UpdateAssumeRolePolicyError>> {
+        self.runtime
+            .block_on(self.client.update_assume_role_policy(input))
+    }
+
+    /// <p><p>Updates the name and/or the path of the specified IAM group.</p> <important> <p> You should understand the implications of changing a group&#39;s path or name. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_WorkingWithGroupsAndUsers.html">Renaming Users and Groups</a> in the <i>IAM User Guide</i>.</p> </important> <note> <p>The person making the request (the principal), must have permission to change the role group with the old name and the new name. For example, to change the group named <code>Managers</code> to <code>MGRs</code>, the principal must have a policy that allows them to update both groups. If the principal has permission to update the <code>Managers</code> group, but not the <code>MGRs</code> group, then the update fails. For more information about permissions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a>. </p> </note></p>
+    ///
+    /// See `Iam::update_group`.
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<(), RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// <p>Changes the password for the specified IAM user.</p> <p>IAM users can change their own passwords by calling <a>ChangePassword</a>. For more information about modifying passwords, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_ManagingLogins.html">Managing Passwords</a> in the <i>IAM User Guide</i>.</p>
+    ///
+    /// See `Iam::update_login_profile`.
+    pub fn update_login_profile(
+        &self,
+        input: UpdateLoginProfileRequest,
+    ) -> Result<(), RusotoError<UpdateLoginProfileError>> {
+        self.runtime
+            .block_on(self.client.update_login_profile(input))
+    }
+
+    /// <p><p>Replaces the existing list of server certificate thumbprints associated with an OpenID Connect (OIDC) provider resource object with a new list of thumbprints.</p> <p>The list that you pass with this operation completely replaces the existing list of thumbprints. (The lists are not merged.)</p> <p>Typically, you need to update a thumbprint only when the identity provider&#39;s certificate changes, which occurs rarely. However, if the provider&#39;s certificate <i>does</i> change, any attempt to assume an IAM role that specifies the OIDC provider as a principal fails until the certificate thumbprint is updated.</p> <note> <p>Trust for the OIDC provider is derived from the provider&#39;s certificate and is validated by the thumbprint. Therefore, it is best to limit access to the <code>UpdateOpenIDConnectProviderThumbprint</code> operation to highly privileged users.</p> </note></p>
+    ///
+    /// See `Iam::update_open_id_connect_provider_thumbprint`.
+    pub fn update_open_id_connect_provider_thumbprint(
+        &self,
+        input: UpdateOpenIDConnectProviderThumbprintRequest,
+    ) -> Result<(), RusotoError<UpdateOpenIDConnectProviderThumbprintError>> {
+        self.runtime.block_on(
+            self.client
+                .update_open_id_connect_provider_thumbprint(input),
+        )
+    }
+
+    /// <p>Updates the description or maximum session duration setting of a role.</p>
+    ///
+    /// See `Iam::update_role`.
+    pub fn update_role(
+        &self,
+        input: UpdateRoleRequest,
+    ) -> Result<UpdateRoleResponse, RusotoError<UpdateRoleError>> {
+        self.runtime.block_on(self.client.update_role(input))
+    }
+
+    /// <p>Use <a>UpdateRole</a> instead.</p> <p>Modifies only the description of a role. This operation performs the same function as the <code>Description</code> parameter in the <code>UpdateRole</code> operation.</p>
+    ///
+    /// See `Iam::update_role_description`.
+    pub fn update_role_description(
+        &self,
+        input: UpdateRoleDescriptionRequest,
+    ) -> Result<UpdateRoleDescriptionResponse, RusotoError<UpdateRoleDescriptionError>> {
+        self.runtime
+            .block_on(self.client.update_role_description(input))
+    }
+
+    /// <p><p>Updates the metadata document for an existing SAML provider resource object.</p> <note> <p>This operation requires <a href="https://docs.aws.amazon.com/general/latest/gr/signature-version-4.html">Signature Version 4</a>.</p> </note></p>
+    ///
+    /// See `Iam::update_saml_provider`.
+    pub fn update_saml_provider(
+        &self,
+        input: UpdateSAMLProviderRequest,
+    ) -> Result<UpdateSAMLProviderResponse, RusotoError<UpdateSAMLProviderError>> {
+        self.runtime
+            .block_on(self.client.update_saml_provider(input))
+    }
+
+    /// <p>Sets the status of an IAM user's SSH public key to active or inactive. SSH public keys that are inactive cannot be used for authentication. This operation can be used to disable a user's SSH public key as part of a key rotation work flow.</p> <p>The SSH public key affected by this operation is used only for authenticating the associated IAM user to an AWS CodeCommit repository. For more information about using SSH keys to authenticate to an AWS CodeCommit repository, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/setting-up-credentials-ssh.html">Set up AWS CodeCommit for SSH Connections</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `Iam::update_ssh_public_key`.
+    pub fn update_ssh_public_key(
+        &self,
+        input: UpdateSSHPublicKeyRequest,
+    ) -> Result<(), RusotoError<UpdateSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.update_ssh_public_key(input))
+    }
+
+    /// <p><p>Updates the name and/or the path of the specified server certificate stored in IAM.</p> <p>For more information about working with server certificates, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_server-certs.html">Working with Server Certificates</a> in the <i>IAM User Guide</i>. This topic also includes a list of AWS services that can use the server certificates that you manage with IAM.</p> <important> <p>You should understand the implications of changing a server certificate&#39;s path or name. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_server-certs_manage.html#RenamingServerCerts">Renaming a Server Certificate</a> in the <i>IAM User Guide</i>.</p> </important> <note> <p>The person making the request (the principal), must have permission to change the server certificate with the old name and the new name. For example, to change the certificate named <code>ProductionCert</code> to <code>ProdCert</code>, the principal must have a policy that allows them to update both certificates. If the principal has permission to update the <code>ProductionCert</code> group, but not the <code>ProdCert</code> certificate, then the update fails. For more information about permissions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access.html">Access Management</a> in the <i>IAM User Guide</i>.</p> </note></p>
+    ///
+    /// See `Iam::update_server_certificate`.
+    pub fn update_server_certificate(
+        &self,
+        input: UpdateServerCertificateRequest,
+    ) -> Result<(), RusotoError<UpdateServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.update_server_certificate(input))
+    }
+
+    /// <p>Sets the status of a service-specific credential to <code>Active</code> or <code>Inactive</code>. Service-specific credentials that are inactive cannot be used for authentication to the service. This operation can be used to disable a user's service-specific credential as part of a credential rotation work flow.</p>
+    ///
+    /// See `Iam::update_service_specific_credential`.
+    pub fn update_service_specific_credential(
+        &self,
+        input: UpdateServiceSpecificCredentialRequest,
+    ) -> Result<(), RusotoError<UpdateServiceSpecificCredentialError>> {
+        self.runtime
+            .block_on(self.client.update_service_specific_credential(input))
+    }
+
+    /// <p>Changes the status of the specified user signing certificate from active to disabled, or vice versa. This operation can be used to disable an IAM user's signing certificate as part of a certificate rotation work flow.</p> <p>If the <code>UserName</code> field is not specified, the user name is determined implicitly based on the AWS access key ID used to sign the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated users.</p>
+    ///
+    /// See `Iam::update_signing_certificate`.
+    pub fn update_signing_certificate(
+        &self,
+        input: UpdateSigningCertificateRequest,
+    ) -> Result<(), RusotoError<UpdateSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.update_signing_certificate(input))
+    }
+
+    /// <p><p>Updates the name and/or the path of the specified IAM user.</p> <important> <p> You should understand the implications of changing an IAM user&#39;s path or name. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_users_manage.html#id_users_renaming">Renaming an IAM User</a> and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_groups_manage_rename.html">Renaming an IAM Group</a> in the <i>IAM User Guide</i>.</p> </important> <note> <p> To change a user name, the requester must have appropriate permissions on both the source object and the target object. For example, to change Bob to Robert, the entity making the request must have permission on Bob and Robert, or must have permission on all (*). For more information about permissions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/PermissionsAndPolicies.html">Permissions and Policies</a>. </p> </note></p>
+    ///
+    /// See `Iam::update_user`.
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<(), RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+
+    /// <p>Uploads an SSH public key and associates it with the specified IAM user.</p> <p>The SSH public key uploaded by this operation can be used only for authenticating the associated IAM user to an AWS CodeCommit repository. For more information about using SSH keys to authenticate to an AWS CodeCommit repository, see <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/setting-up-credentials-ssh.html">Set up AWS CodeCommit for SSH Connections</a> in the <i>AWS CodeCommit User Guide</i>.</p>
+    ///
+    /// See `Iam::upload_ssh_public_key`.
+    pub fn upload_ssh_public_key(
+        &self,
+        input: UploadSSHPublicKeyRequest,
+    ) -> Result<UploadSSHPublicKeyResponse, RusotoError<UploadSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.upload_ssh_public_key(input))
+    }
+
+    /// <p><p>Uploads a server certificate entity for the AWS account. The server certificate entity includes a public key certificate, a private key, and an optional certificate chain, which should all be PEM-encoded.</p> <p>We recommend that you use <a href="https://docs.aws.amazon.com/acm/">AWS Certificate Manager</a> to provision, manage, and deploy your server certificates. With ACM you can request a certificate, deploy it to AWS resources, and let ACM handle certificate renewals for you. Certificates provided by ACM are free. For more information about using ACM, see the <a href="https://docs.aws.amazon.com/acm/latest/userguide/">AWS Certificate Manager User Guide</a>.</p> <p>For more information about working with server certificates, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_server-certs.html">Working with Server Certificates</a> in the <i>IAM User Guide</i>. This topic includes a list of AWS services that can use the server certificates that you manage with IAM.</p> <p>For information about the number of server certificates you can upload, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_iam-limits.html">Limitations on IAM Entities and Objects</a> in the <i>IAM User Guide</i>.</p> <note> <p>Because the body of the public key certificate, private key, and the certificate chain can be large, you should use POST rather than GET when calling <code>UploadServerCertificate</code>. For information about setting up signatures and authorization through the API, go to <a href="https://docs.aws.amazon.com/general/latest/gr/signing_aws_api_requests.html">Signing AWS API Requests</a> in the <i>AWS General Reference</i>. For general information about using the Query API with IAM, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/programming.html">Calling the API by Making HTTP Query Requests</a> in the <i>IAM User Guide</i>.</p> </note></p>
+    ///
+    /// See `Iam::upload_server_certificate`.
+    pub fn upload_server_certificate(
+        &self,
+        input: UploadServerCertificateRequest,
+    ) -> Result<UploadServerCertificateResponse, RusotoError<UploadServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.upload_server_certificate(input))
+    }
+
+    /// <p><p>Uploads an X.509 signing certificate and associates it with the specified IAM user. Some AWS services use X.509 signing certificates to validate requests that are signed with a corresponding private key. When you upload the certificate, its default status is <code>Active</code>.</p> <p>If the <code>UserName</code> is not specified, the IAM user name is determined implicitly based on the AWS access key ID used to sign the request. This operation works for access keys under the AWS account. Consequently, you can use this operation to manage AWS account root user credentials even if the AWS account has no associated users.</p> <note> <p>Because the body of an X.509 certificate can be large, you should use POST rather than GET when calling <code>UploadSigningCertificate</code>. For information about setting up signatures and authorization through the API, go to <a href="https://docs.aws.amazon.com/general/latest/gr/signing_aws_api_requests.html">Signing AWS API Requests</a> in the <i>AWS General Reference</i>. For general information about using the Query API with IAM, go to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/IAM_UsingQueryAPI.html">Making Query Requests</a> in the <i>IAM User Guide</i>.</p> </note></p>
+    ///
+    /// See `Iam::upload_signing_certificate`.
+    pub fn upload_signing_certificate(
+        &self,
+        input: UploadSigningCertificateRequest,
+    ) -> Result<UploadSigningCertificateResponse, RusotoError<UploadSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.upload_signing_certificate(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
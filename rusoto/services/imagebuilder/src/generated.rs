@@ -2280,34 +2280,43 @@ impl CancelImageCreationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CancelImageCreationError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelImageCreationError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CancelImageCreationError::Client(err.msg))
+                    return RusotoError::service(&res, CancelImageCreationError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CancelImageCreationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CancelImageCreationError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelImageCreationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelImageCreationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelImageCreationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelImageCreationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelImageCreationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelImageCreationError::Service(err.msg))
+                    return RusotoError::service(&res, CancelImageCreationError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelImageCreationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelImageCreationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2366,47 +2375,58 @@ impl CreateComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateComponentError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateComponentError::Client(err.msg))
+                    return RusotoError::service(&res, CreateComponentError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateComponentError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateComponentError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateComponentError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidVersionNumber(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::InvalidVersionNumber(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateComponentError::ResourceInUse(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateComponentError::Service(err.msg))
+                    return RusotoError::service(&res, CreateComponentError::Service(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateComponentError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateComponentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2468,57 +2488,68 @@ impl CreateDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDistributionConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDistributionConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDistributionConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::ServiceQuotaExceeded(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDistributionConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2592,33 +2623,43 @@ impl CreateImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImageError::Client(err.msg))
+                    return RusotoError::service(&res, CreateImageError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImageError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateImageError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateImageError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImageError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateImageError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImageError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateImageError::ResourceInUse(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImageError::Service(err.msg))
+                    return RusotoError::service(&res, CreateImageError::Service(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateImageError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2674,44 +2715,55 @@ impl CreateImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImagePipelineError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Client(err.msg))
+                    return RusotoError::service(&res, CreateImagePipelineError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateImagePipelineError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateImagePipelineError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImagePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImagePipelineError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImagePipelineError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Service(err.msg))
+                    return RusotoError::service(&res, CreateImagePipelineError::Service(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImagePipelineError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImagePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2772,49 +2824,61 @@ impl CreateImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImageRecipeError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Client(err.msg))
+                    return RusotoError::service(&res, CreateImageRecipeError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateImageRecipeError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateImageRecipeError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateImageRecipeError::InvalidVersionNumber(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::InvalidVersionNumber(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Service(err.msg))
+                    return RusotoError::service(&res, CreateImageRecipeError::Service(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateImageRecipeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2876,54 +2940,64 @@ impl CreateInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInfrastructureConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInfrastructureConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::IdempotentParameterMismatch(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInfrastructureConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::ServiceQuotaExceeded(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInfrastructureConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2990,27 +3064,37 @@ impl DeleteComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteComponentError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComponentError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteComponentError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteComponentError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteComponentError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteComponentError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComponentError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteComponentError::ResourceDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComponentError::ResourceDependency(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteComponentError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteComponentError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteComponentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3060,37 +3144,44 @@ impl DeleteDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDistributionConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDistributionConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDistributionConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDistributionConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDistributionConfigurationError::ResourceDependency(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDistributionConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDistributionConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3148,25 +3239,34 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImageError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteImageError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImageError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteImageError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImageError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteImageError::InvalidRequest(err.msg))
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageError::ResourceDependency(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImageError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteImageError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3214,31 +3314,37 @@ impl DeleteImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImagePipelineError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteImagePipelineError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteImagePipelineError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImagePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::ResourceDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImagePipelineError::ResourceDependency(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteImagePipelineError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImagePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3286,31 +3392,37 @@ impl DeleteImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageRecipeError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Client(err.msg))
+                    return RusotoError::service(&res, DeleteImageRecipeError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteImageRecipeError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageRecipeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::ResourceDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageRecipeError::ResourceDependency(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteImageRecipeError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImageRecipeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3360,37 +3472,44 @@ impl DeleteInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInfrastructureConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInfrastructureConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInfrastructureConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInfrastructureConfigurationError::ResourceDependency(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInfrastructureConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInfrastructureConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3446,22 +3565,28 @@ impl GetComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetComponentError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetComponentError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetComponentError::Client(err.msg))
+                    return RusotoError::service(&res, GetComponentError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetComponentError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetComponentError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetComponentError::InvalidRequest(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetComponentError::Service(err.msg))
+                    return RusotoError::service(&res, GetComponentError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetComponentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3506,26 +3631,34 @@ impl GetComponentPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetComponentPolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetComponentPolicyError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetComponentPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetComponentPolicyError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetComponentPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetComponentPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetComponentPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetComponentPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetComponentPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, GetComponentPolicyError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetComponentPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetComponentPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3572,30 +3705,38 @@ impl GetDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDistributionConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDistributionConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDistributionConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDistributionConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDistributionConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDistributionConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -3646,18 +3787,25 @@ impl GetImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImageError::CallRateLimitExceeded(err.msg),
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(&res, GetImageError::Client(err.msg))
                 }
-                "ClientException" => return RusotoError::Service(GetImageError::Client(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetImageError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetImageError::InvalidRequest(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, GetImageError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(GetImageError::Service(err.msg)),
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetImageError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3702,24 +3850,31 @@ impl GetImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetImagePipelineError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetImagePipelineError::Client(err.msg))
+                    return RusotoError::service(&res, GetImagePipelineError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetImagePipelineError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImagePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImagePipelineError::Service(err.msg))
+                    return RusotoError::service(&res, GetImagePipelineError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImagePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImagePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3764,24 +3919,31 @@ impl GetImagePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImagePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetImagePolicyError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImagePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetImagePolicyError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImagePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetImagePolicyError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetImagePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImagePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImagePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, GetImagePolicyError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImagePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImagePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3826,24 +3988,28 @@ impl GetImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageRecipeError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipeError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetImageRecipeError::Client(err.msg))
+                    return RusotoError::service(&res, GetImageRecipeError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetImageRecipeError::Forbidden(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetImageRecipeError::InvalidRequest(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImageRecipeError::Service(err.msg))
+                    return RusotoError::service(&res, GetImageRecipeError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageRecipeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3888,28 +4054,37 @@ impl GetImageRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipePolicyError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipePolicyError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipePolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, GetImageRecipePolicyError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetImageRecipePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3956,32 +4131,38 @@ impl GetInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInfrastructureConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInfrastructureConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetInfrastructureConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInfrastructureConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetInfrastructureConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4042,42 +4223,52 @@ impl ImportComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ImportComponentError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportComponentError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ImportComponentError::Client(err.msg))
+                    return RusotoError::service(&res, ImportComponentError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ImportComponentError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ImportComponentError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(ImportComponentError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportComponentError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidParameterCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportComponentError::InvalidParameterCombination(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportComponentError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidVersionNumber(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportComponentError::InvalidVersionNumber(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ImportComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, ImportComponentError::ResourceInUse(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ImportComponentError::Service(err.msg))
+                    return RusotoError::service(&res, ImportComponentError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportComponentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4130,33 +4321,44 @@ impl ListComponentBuildVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListComponentBuildVersionsError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentBuildVersionsError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentBuildVersionsError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListComponentBuildVersionsError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentBuildVersionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentBuildVersionsError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListComponentBuildVersionsError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4212,29 +4414,34 @@ impl ListComponentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListComponentsError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentsError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListComponentsError::Client(err.msg))
+                    return RusotoError::service(&res, ListComponentsError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListComponentsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListComponentsError::Forbidden(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListComponentsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentsError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListComponentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListComponentsError::InvalidRequest(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListComponentsError::Service(err.msg))
+                    return RusotoError::service(&res, ListComponentsError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListComponentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListComponentsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4284,37 +4491,44 @@ impl ListDistributionConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDistributionConfigurationsError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDistributionConfigurationsError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDistributionConfigurationsError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDistributionConfigurationsError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDistributionConfigurationsError::InvalidRequest(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDistributionConfigurationsError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDistributionConfigurationsError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4372,33 +4586,43 @@ impl ListImageBuildVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListImageBuildVersionsError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Client(err.msg))
+                    return RusotoError::service(&res, ListImageBuildVersionsError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImageBuildVersionsError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListImageBuildVersionsError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImageBuildVersionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImageBuildVersionsError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImageBuildVersionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4450,38 +4674,52 @@ impl ListImagePipelineImagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListImagePipelineImagesError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelineImagesError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelineImagesError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListImagePipelineImagesError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelineImagesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelineImagesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelineImagesError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelineImagesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4534,31 +4772,37 @@ impl ListImagePipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImagePipelinesError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelinesError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Client(err.msg))
+                    return RusotoError::service(&res, ListImagePipelinesError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListImagePipelinesError::Forbidden(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImagePipelinesError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelinesError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagePipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelinesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Service(err.msg))
+                    return RusotoError::service(&res, ListImagePipelinesError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagePipelinesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImagePipelinesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4606,29 +4850,37 @@ impl ListImageRecipesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImageRecipesError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImageRecipesError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImageRecipesError::Client(err.msg))
+                    return RusotoError::service(&res, ListImageRecipesError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImageRecipesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListImageRecipesError::Forbidden(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImageRecipesError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListImageRecipesError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImageRecipesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImageRecipesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImageRecipesError::Service(err.msg))
+                    return RusotoError::service(&res, ListImageRecipesError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImageRecipesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImageRecipesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4676,23 +4928,31 @@ impl ListImagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImagesError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImagesError::CallRateLimitExceeded(err.msg),
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service(&res, ListImagesError::Client(err.msg))
                 }
-                "ClientException" => return RusotoError::Service(ListImagesError::Client(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListImagesError::Forbidden(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImagesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListImagesError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListImagesError::InvalidRequest(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagesError::Service(err.msg))
+                    return RusotoError::service(&res, ListImagesError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListImagesError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4742,37 +5002,44 @@ impl ListInfrastructureConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListInfrastructureConfigurationsError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInfrastructureConfigurationsError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInfrastructureConfigurationsError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListInfrastructureConfigurationsError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListInfrastructureConfigurationsError::InvalidRequest(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInfrastructureConfigurationsError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListInfrastructureConfigurationsError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -4822,17 +5089,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4878,34 +5147,43 @@ impl PutComponentPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutComponentPolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComponentPolicyError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Client(err.msg))
+                    return RusotoError::service(&res, PutComponentPolicyError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(&res, PutComponentPolicyError::Forbidden(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutComponentPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComponentPolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutComponentPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutComponentPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutComponentPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutComponentPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, PutComponentPolicyError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutComponentPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComponentPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4956,32 +5234,40 @@ impl PutImagePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutImagePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImagePolicyError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutImagePolicyError::Client(err.msg))
+                    return RusotoError::service(&res, PutImagePolicyError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutImagePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(&res, PutImagePolicyError::Forbidden(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutImagePolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImagePolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutImagePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, PutImagePolicyError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutImagePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImagePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutImagePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, PutImagePolicyError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutImagePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImagePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5032,36 +5318,46 @@ impl PutImageRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageRecipePolicyError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Client(err.msg))
+                    return RusotoError::service(&res, PutImageRecipePolicyError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImageRecipePolicyError::Forbidden(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageRecipePolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutImageRecipePolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageRecipePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, PutImageRecipePolicyError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutImageRecipePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5116,43 +5412,56 @@ impl StartImagePipelineExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartImagePipelineExecutionError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Client(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImagePipelineExecutionError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImagePipelineExecutionError::Forbidden(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartImagePipelineExecutionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImagePipelineExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartImagePipelineExecutionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartImagePipelineExecutionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartImagePipelineExecutionError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartImagePipelineExecutionError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -5202,13 +5511,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5244,13 +5553,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5300,47 +5615,56 @@ impl UpdateDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDistributionConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDistributionConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDistributionConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDistributionConfigurationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDistributionConfigurationError::InvalidParameterCombination(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDistributionConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDistributionConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDistributionConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDistributionConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -5406,34 +5730,43 @@ impl UpdateImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::CallRateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePipelineError::CallRateLimitExceeded(err.msg),
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Client(err.msg))
+                    return RusotoError::service(&res, UpdateImagePipelineError::Client(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateImagePipelineError::Forbidden(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateImagePipelineError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePipelineError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Service(err.msg))
+                    return RusotoError::service(&res, UpdateImagePipelineError::Service(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateImagePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5488,44 +5821,52 @@ impl UpdateInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Client(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInfrastructureConfigurationError::Client(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInfrastructureConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInfrastructureConfigurationError::IdempotentParameterMismatch(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInfrastructureConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInfrastructureConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInfrastructureConfigurationError::Service(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInfrastructureConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -5862,6 +6203,7 @@ impl ImageBuilderClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5877,6 +6219,7 @@ impl ImageBuilderClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ImageBuilderClient {
         ImageBuilderClient { client, region }
     }
@@ -5893,6 +6236,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CancelImageCreation";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CancelImageCreation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5924,6 +6270,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CreateComponent");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5958,6 +6307,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CreateDistributionConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5991,6 +6343,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImage";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CreateImage");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6022,6 +6377,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CreateImagePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6053,6 +6411,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImageRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CreateImageRecipe");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6087,6 +6448,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("CreateInfrastructureConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6120,6 +6484,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteComponent";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("DeleteComponent");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6158,6 +6525,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteDistributionConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("DeleteDistributionConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6195,6 +6565,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImage";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("DeleteImage");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6227,6 +6600,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImagePipeline";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("DeleteImagePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6259,6 +6635,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImageRecipe";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("DeleteImageRecipe");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6294,6 +6673,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("DeleteInfrastructureConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6331,6 +6713,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponent";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetComponent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6366,6 +6751,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponentPolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetComponentPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6399,6 +6787,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetDistributionConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetDistributionConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6434,6 +6825,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImage";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetImage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6466,6 +6860,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePipeline";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetImagePipeline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6498,6 +6895,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetImagePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6530,6 +6930,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipe";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetImageRecipe");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6562,6 +6965,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetImageRecipePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6597,6 +7003,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("GetInfrastructureConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6632,6 +7041,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ImportComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ImportComponent");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6664,6 +7076,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponentBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListComponentBuildVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6695,6 +7110,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponents";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListComponents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6729,6 +7147,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListDistributionConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListDistributionConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6760,6 +7181,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListImageBuildVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6791,6 +7215,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelineImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListImagePipelineImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6822,6 +7249,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelines";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListImagePipelines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6853,6 +7283,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageRecipes";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListImageRecipes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6884,6 +7317,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6918,6 +7354,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListInfrastructureConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListInfrastructureConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6951,6 +7390,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6979,6 +7421,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutComponentPolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("PutComponentPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7010,6 +7455,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImagePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("PutImagePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7041,6 +7489,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImageRecipePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("PutImageRecipePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7073,6 +7524,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/StartImagePipelineExecution";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("StartImagePipelineExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7104,6 +7558,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7135,6 +7592,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7172,6 +7632,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("UpdateDistributionConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7205,6 +7668,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("UpdateImagePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7239,6 +7705,9 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation_name("UpdateInfrastructureConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7263,3 +7732,529 @@ impl ImageBuilder for ImageBuilderClient {
         }
     }
 }
+
+/// A blocking client for the imagebuilder API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ImageBuilderClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ImageBuilderBlockingClient {
+    client: ImageBuilderClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ImageBuilderBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ImageBuilderBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ImageBuilderBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ImageBuilderBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ImageBuilderBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient {
+            client: ImageBuilderClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>CancelImageCreation cancels the creation of Image. This operation can only be used on images in a non-terminal state.</p>
+    ///
+    /// See `ImageBuilder::cancel_image_creation`.
+    pub fn cancel_image_creation(
+        &self,
+        input: CancelImageCreationRequest,
+    ) -> Result<CancelImageCreationResponse, RusotoError<CancelImageCreationError>> {
+        self.runtime
+            .block_on(self.client.cancel_image_creation(input))
+    }
+
+    /// <p>Creates a new component that can be used to build, validate, test, and assess your image.</p>
+    ///
+    /// See `ImageBuilder::create_component`.
+    pub fn create_component(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        self.runtime.block_on(self.client.create_component(input))
+    }
+
+    /// <p>Creates a new distribution configuration. Distribution configurations define and configure the outputs of your pipeline. </p>
+    ///
+    /// See `ImageBuilder::create_distribution_configuration`.
+    pub fn create_distribution_configuration(
+        &self,
+        input: CreateDistributionConfigurationRequest,
+    ) -> Result<
+        CreateDistributionConfigurationResponse,
+        RusotoError<CreateDistributionConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_distribution_configuration(input))
+    }
+
+    /// <p> Creates a new image. This request will create a new image along with all of the configured output resources defined in the distribution configuration. </p>
+    ///
+    /// See `ImageBuilder::create_image`.
+    pub fn create_image(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
+        self.runtime.block_on(self.client.create_image(input))
+    }
+
+    /// <p> Creates a new image pipeline. Image pipelines enable you to automate the creation and distribution of images. </p>
+    ///
+    /// See `ImageBuilder::create_image_pipeline`.
+    pub fn create_image_pipeline(
+        &self,
+        input: CreateImagePipelineRequest,
+    ) -> Result<CreateImagePipelineResponse, RusotoError<CreateImagePipelineError>> {
+        self.runtime
+            .block_on(self.client.create_image_pipeline(input))
+    }
+
+    /// <p> Creates a new image recipe. Image recipes define how images are configured, tested, and assessed. </p>
+    ///
+    /// See `ImageBuilder::create_image_recipe`.
+    pub fn create_image_recipe(
+        &self,
+        input: CreateImageRecipeRequest,
+    ) -> Result<CreateImageRecipeResponse, RusotoError<CreateImageRecipeError>> {
+        self.runtime
+            .block_on(self.client.create_image_recipe(input))
+    }
+
+    /// <p> Creates a new infrastructure configuration. An infrastructure configuration defines the environment in which your image will be built and tested. </p>
+    ///
+    /// See `ImageBuilder::create_infrastructure_configuration`.
+    pub fn create_infrastructure_configuration(
+        &self,
+        input: CreateInfrastructureConfigurationRequest,
+    ) -> Result<
+        CreateInfrastructureConfigurationResponse,
+        RusotoError<CreateInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_infrastructure_configuration(input))
+    }
+
+    /// <p> Deletes a component build version. </p>
+    ///
+    /// See `ImageBuilder::delete_component`.
+    pub fn delete_component(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+        self.runtime.block_on(self.client.delete_component(input))
+    }
+
+    /// <p> Deletes a distribution configuration. </p>
+    ///
+    /// See `ImageBuilder::delete_distribution_configuration`.
+    pub fn delete_distribution_configuration(
+        &self,
+        input: DeleteDistributionConfigurationRequest,
+    ) -> Result<
+        DeleteDistributionConfigurationResponse,
+        RusotoError<DeleteDistributionConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_distribution_configuration(input))
+    }
+
+    /// <p> Deletes an image. </p>
+    ///
+    /// See `ImageBuilder::delete_image`.
+    pub fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResponse, RusotoError<DeleteImageError>> {
+        self.runtime.block_on(self.client.delete_image(input))
+    }
+
+    /// <p> Deletes an image pipeline. </p>
+    ///
+    /// See `ImageBuilder::delete_image_pipeline`.
+    pub fn delete_image_pipeline(
+        &self,
+        input: DeleteImagePipelineRequest,
+    ) -> Result<DeleteImagePipelineResponse, RusotoError<DeleteImagePipelineError>> {
+        self.runtime
+            .block_on(self.client.delete_image_pipeline(input))
+    }
+
+    /// <p> Deletes an image recipe. </p>
+    ///
+    /// See `ImageBuilder::delete_image_recipe`.
+    pub fn delete_image_recipe(
+        &self,
+        input: DeleteImageRecipeRequest,
+    ) -> Result<DeleteImageRecipeResponse, RusotoError<DeleteImageRecipeError>> {
+        self.runtime
+            .block_on(self.client.delete_image_recipe(input))
+    }
+
+    /// <p> Deletes an infrastructure configuration. </p>
+    ///
+    /// See `ImageBuilder::delete_infrastructure_configuration`.
+    pub fn delete_infrastructure_configuration(
+        &self,
+        input: DeleteInfrastructureConfigurationRequest,
+    ) -> Result<
+        DeleteInfrastructureConfigurationResponse,
+        RusotoError<DeleteInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_infrastructure_configuration(input))
+    }
+
+    /// <p> Gets a component object. </p>
+    ///
+    /// See `ImageBuilder::get_component`.
+    pub fn get_component(
+        &self,
+        input: GetComponentRequest,
+    ) -> Result<GetComponentResponse, RusotoError<GetComponentError>> {
+        self.runtime.block_on(self.client.get_component(input))
+    }
+
+    /// <p> Gets a component policy. </p>
+    ///
+    /// See `ImageBuilder::get_component_policy`.
+    pub fn get_component_policy(
+        &self,
+        input: GetComponentPolicyRequest,
+    ) -> Result<GetComponentPolicyResponse, RusotoError<GetComponentPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_component_policy(input))
+    }
+
+    /// <p> Gets a distribution configuration. </p>
+    ///
+    /// See `ImageBuilder::get_distribution_configuration`.
+    pub fn get_distribution_configuration(
+        &self,
+        input: GetDistributionConfigurationRequest,
+    ) -> Result<GetDistributionConfigurationResponse, RusotoError<GetDistributionConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_distribution_configuration(input))
+    }
+
+    /// <p> Gets an image. </p>
+    ///
+    /// See `ImageBuilder::get_image`.
+    pub fn get_image(
+        &self,
+        input: GetImageRequest,
+    ) -> Result<GetImageResponse, RusotoError<GetImageError>> {
+        self.runtime.block_on(self.client.get_image(input))
+    }
+
+    /// <p> Gets an image pipeline. </p>
+    ///
+    /// See `ImageBuilder::get_image_pipeline`.
+    pub fn get_image_pipeline(
+        &self,
+        input: GetImagePipelineRequest,
+    ) -> Result<GetImagePipelineResponse, RusotoError<GetImagePipelineError>> {
+        self.runtime.block_on(self.client.get_image_pipeline(input))
+    }
+
+    /// <p> Gets an image policy. </p>
+    ///
+    /// See `ImageBuilder::get_image_policy`.
+    pub fn get_image_policy(
+        &self,
+        input: GetImagePolicyRequest,
+    ) -> Result<GetImagePolicyResponse, RusotoError<GetImagePolicyError>> {
+        self.runtime.block_on(self.client.get_image_policy(input))
+    }
+
+    /// <p> Gets an image recipe. </p>
+    ///
+    /// See `ImageBuilder::get_image_recipe`.
+    pub fn get_image_recipe(
+        &self,
+        input: GetImageRecipeRequest,
+    ) -> Result<GetImageRecipeResponse, RusotoError<GetImageRecipeError>> {
+        self.runtime.block_on(self.client.get_image_recipe(input))
+    }
+
+    /// <p> Gets an image recipe policy. </p>
+    ///
+    /// See `ImageBuilder::get_image_recipe_policy`.
+    pub fn get_image_recipe_policy(
+        &self,
+        input: GetImageRecipePolicyRequest,
+    ) -> Result<GetImageRecipePolicyResponse, RusotoError<GetImageRecipePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_image_recipe_policy(input))
+    }
+
+    /// <p> Gets an infrastructure configuration. </p>
+    ///
+    /// See `ImageBuilder::get_infrastructure_configuration`.
+    pub fn get_infrastructure_configuration(
+        &self,
+        input: GetInfrastructureConfigurationRequest,
+    ) -> Result<
+        GetInfrastructureConfigurationResponse,
+        RusotoError<GetInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_infrastructure_configuration(input))
+    }
+
+    /// <p>Imports a component and transforms its data into a component document. </p>
+    ///
+    /// See `ImageBuilder::import_component`.
+    pub fn import_component(
+        &self,
+        input: ImportComponentRequest,
+    ) -> Result<ImportComponentResponse, RusotoError<ImportComponentError>> {
+        self.runtime.block_on(self.client.import_component(input))
+    }
+
+    /// <p> Returns the list of component build versions for the specified semantic version. </p>
+    ///
+    /// See `ImageBuilder::list_component_build_versions`.
+    pub fn list_component_build_versions(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> Result<ListComponentBuildVersionsResponse, RusotoError<ListComponentBuildVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_component_build_versions(input))
+    }
+
+    /// <p>Returns the list of component build versions for the specified semantic version. </p>
+    ///
+    /// See `ImageBuilder::list_components`.
+    pub fn list_components(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+        self.runtime.block_on(self.client.list_components(input))
+    }
+
+    /// <p> Returns a list of distribution configurations. </p>
+    ///
+    /// See `ImageBuilder::list_distribution_configurations`.
+    pub fn list_distribution_configurations(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> Result<
+        ListDistributionConfigurationsResponse,
+        RusotoError<ListDistributionConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_distribution_configurations(input))
+    }
+
+    /// <p> Returns a list of image build versions. </p>
+    ///
+    /// See `ImageBuilder::list_image_build_versions`.
+    pub fn list_image_build_versions(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> Result<ListImageBuildVersionsResponse, RusotoError<ListImageBuildVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_image_build_versions(input))
+    }
+
+    /// <p> Returns a list of images created by the specified pipeline. </p>
+    ///
+    /// See `ImageBuilder::list_image_pipeline_images`.
+    pub fn list_image_pipeline_images(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> Result<ListImagePipelineImagesResponse, RusotoError<ListImagePipelineImagesError>> {
+        self.runtime
+            .block_on(self.client.list_image_pipeline_images(input))
+    }
+
+    /// <p>Returns a list of image pipelines. </p>
+    ///
+    /// See `ImageBuilder::list_image_pipelines`.
+    pub fn list_image_pipelines(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> Result<ListImagePipelinesResponse, RusotoError<ListImagePipelinesError>> {
+        self.runtime
+            .block_on(self.client.list_image_pipelines(input))
+    }
+
+    /// <p> Returns a list of image recipes. </p>
+    ///
+    /// See `ImageBuilder::list_image_recipes`.
+    pub fn list_image_recipes(
+        &self,
+        input: ListImageRecipesRequest,
+    ) -> Result<ListImageRecipesResponse, RusotoError<ListImageRecipesError>> {
+        self.runtime.block_on(self.client.list_image_recipes(input))
+    }
+
+    /// <p> Returns the list of images that you have access to. </p>
+    ///
+    /// See `ImageBuilder::list_images`.
+    pub fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.runtime.block_on(self.client.list_images(input))
+    }
+
+    /// <p> Returns a list of infrastructure configurations. </p>
+    ///
+    /// See `ImageBuilder::list_infrastructure_configurations`.
+    pub fn list_infrastructure_configurations(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> Result<
+        ListInfrastructureConfigurationsResponse,
+        RusotoError<ListInfrastructureConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_infrastructure_configurations(input))
+    }
+
+    /// <p> Returns the list of tags for the specified resource. </p>
+    ///
+    /// See `ImageBuilder::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p> Applies a policy to a component. We recommend that you call the RAM API <a href="https://docs.aws.amazon.com/ram/latest/APIReference/API_CreateResourceShare.html">CreateResourceShare</a> to share resources. If you call the Image Builder API <code>PutComponentPolicy</code>, you must also call the RAM API <a href="https://docs.aws.amazon.com/ram/latest/APIReference/API_PromoteResourceShareCreatedFromPolicy.html">PromoteResourceShareCreatedFromPolicy</a> in order for the resource to be visible to all principals with whom the resource is shared. </p>
+    ///
+    /// See `ImageBuilder::put_component_policy`.
+    pub fn put_component_policy(
+        &self,
+        input: PutComponentPolicyRequest,
+    ) -> Result<PutComponentPolicyResponse, RusotoError<PutComponentPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_component_policy(input))
+    }
+
+    /// <p>Applies a policy to an image. We recommend that you call the RAM API <a href="https://docs.aws.amazon.com/ram/latest/APIReference/API_CreateResourceShare.html">CreateResourceShare</a> to share resources. If you call the Image Builder API <code>PutImagePolicy</code>, you must also call the RAM API <a href="https://docs.aws.amazon.com/ram/latest/APIReference/API_PromoteResourceShareCreatedFromPolicy.html">PromoteResourceShareCreatedFromPolicy</a> in order for the resource to be visible to all principals with whom the resource is shared. </p>
+    ///
+    /// See `ImageBuilder::put_image_policy`.
+    pub fn put_image_policy(
+        &self,
+        input: PutImagePolicyRequest,
+    ) -> Result<PutImagePolicyResponse, RusotoError<PutImagePolicyError>> {
+        self.runtime.block_on(self.client.put_image_policy(input))
+    }
+
+    /// <p> Applies a policy to an image recipe. We recommend that you call the RAM API <a href="https://docs.aws.amazon.com/ram/latest/APIReference/API_CreateResourceShare.html">CreateResourceShare</a> to share resources. If you call the Image Builder API <code>PutImageRecipePolicy</code>, you must also call the RAM API <a href="https://docs.aws.amazon.com/ram/latest/APIReference/API_PromoteResourceShareCreatedFromPolicy.html">PromoteResourceShareCreatedFromPolicy</a> in order for the resource to be visible to all principals with whom the resource is shared. </p>
+    ///
+    /// See `ImageBuilder::put_image_recipe_policy`.
+    pub fn put_image_recipe_policy(
+        &self,
+        input: PutImageRecipePolicyRequest,
+    ) -> Result<PutImageRecipePolicyResponse, RusotoError<PutImageRecipePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_image_recipe_policy(input))
+    }
+
+    /// <p> Manually triggers a pipeline to create an image. </p>
+    ///
+    /// See `ImageBuilder::start_image_pipeline_execution`.
+    pub fn start_image_pipeline_execution(
+        &self,
+        input: StartImagePipelineExecutionRequest,
+    ) -> Result<StartImagePipelineExecutionResponse, RusotoError<StartImagePipelineExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_image_pipeline_execution(input))
+    }
+
+    /// <p> Adds a tag to a resource. </p>
+    ///
+    /// See `ImageBuilder::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p> Removes a tag from a resource. </p>
+    ///
+    /// See `ImageBuilder::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p> Updates a new distribution configuration. Distribution configurations define and configure the outputs of your pipeline. </p>
+    ///
+    /// See `ImageBuilder::update_distribution_configuration`.
+    pub fn update_distribution_configuration(
+        &self,
+        input: UpdateDistributionConfigurationRequest,
+    ) -> Result<
+        UpdateDistributionConfigurationResponse,
+        RusotoError<UpdateDistributionConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_distribution_configuration(input))
+    }
+
+    /// <p> Updates a new image pipeline. Image pipelines enable you to automate the creation and distribution of images. </p>
+    ///
+    /// See `ImageBuilder::update_image_pipeline`.
+    pub fn update_image_pipeline(
+        &self,
+        input: UpdateImagePipelineRequest,
+    ) -> Result<UpdateImagePipelineResponse, RusotoError<UpdateImagePipelineError>> {
+        self.runtime
+            .block_on(self.client.update_image_pipeline(input))
+    }
+
+    /// <p> Updates a new infrastructure configuration. An infrastructure configuration defines the environment in which your image will be built and tested. </p>
+    ///
+    /// See `ImageBuilder::update_infrastructure_configuration`.
+    pub fn update_infrastructure_configuration(
+        &self,
+        input: UpdateInfrastructureConfigurationRequest,
+    ) -> Result<
+        UpdateInfrastructureConfigurationResponse,
+        RusotoError<UpdateInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_infrastructure_configuration(input))
+    }
+}
@@ -881,34 +881,40 @@ impl CancelJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(CancelJobError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelJobError::CanceledJobId(parsed_error.message),
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(CancelJobError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelJobError::ExpiredJobId(parsed_error.message),
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(CancelJobError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelJobError::InvalidAccessKeyId(parsed_error.message),
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(CancelJobError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelJobError::InvalidJobId(parsed_error.message),
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(CancelJobError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelJobError::InvalidVersion(parsed_error.message),
+                        )
                     }
                     "UnableToCancelJobIdException" => {
-                        return RusotoError::Service(CancelJobError::UnableToCancelJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelJobError::UnableToCancelJobId(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -985,84 +991,100 @@ impl CreateJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
-                        return RusotoError::Service(CreateJobError::BucketPermission(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::BucketPermission(parsed_error.message),
+                        )
                     }
                     "CreateJobQuotaExceededException" => {
-                        return RusotoError::Service(CreateJobError::CreateJobQuotaExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::CreateJobQuotaExceeded(parsed_error.message),
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(CreateJobError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidAccessKeyId(parsed_error.message),
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(CreateJobError::InvalidAddress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidAddress(parsed_error.message),
+                        )
                     }
                     "InvalidCustomsException" => {
-                        return RusotoError::Service(CreateJobError::InvalidCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidCustoms(parsed_error.message),
+                        )
                     }
                     "InvalidFileSystemException" => {
-                        return RusotoError::Service(CreateJobError::InvalidFileSystem(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidFileSystem(parsed_error.message),
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(CreateJobError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidJobId(parsed_error.message),
+                        )
                     }
                     "InvalidManifestFieldException" => {
-                        return RusotoError::Service(CreateJobError::InvalidManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidManifestField(parsed_error.message),
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(CreateJobError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(CreateJobError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::InvalidVersion(parsed_error.message),
+                        )
                     }
                     "MalformedManifestException" => {
-                        return RusotoError::Service(CreateJobError::MalformedManifest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::MalformedManifest(parsed_error.message),
+                        )
                     }
                     "MissingCustomsException" => {
-                        return RusotoError::Service(CreateJobError::MissingCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::MissingCustoms(parsed_error.message),
+                        )
                     }
                     "MissingManifestFieldException" => {
-                        return RusotoError::Service(CreateJobError::MissingManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::MissingManifestField(parsed_error.message),
+                        )
                     }
                     "MissingParameterException" => {
-                        return RusotoError::Service(CreateJobError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "MultipleRegionsException" => {
-                        return RusotoError::Service(CreateJobError::MultipleRegions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::MultipleRegions(parsed_error.message),
+                        )
                     }
                     "NoSuchBucketException" => {
-                        return RusotoError::Service(CreateJobError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateJobError::NoSuchBucket(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1131,39 +1153,46 @@ impl GetShippingLabelError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::CanceledJobId(parsed_error.message),
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::ExpiredJobId(parsed_error.message),
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::InvalidAccessKeyId(parsed_error.message),
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidAddress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::InvalidAddress(parsed_error.message),
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::InvalidJobId(parsed_error.message),
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetShippingLabelError::InvalidVersion(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1219,29 +1248,34 @@ impl GetStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(GetStatusError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetStatusError::CanceledJobId(parsed_error.message),
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(GetStatusError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetStatusError::ExpiredJobId(parsed_error.message),
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(GetStatusError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetStatusError::InvalidAccessKeyId(parsed_error.message),
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(GetStatusError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetStatusError::InvalidJobId(parsed_error.message),
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(GetStatusError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetStatusError::InvalidVersion(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1291,19 +1325,22 @@ impl ListJobsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(ListJobsError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListJobsError::InvalidAccessKeyId(parsed_error.message),
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(ListJobsError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListJobsError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(ListJobsError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListJobsError::InvalidVersion(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1381,94 +1418,112 @@ impl UpdateJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
-                        return RusotoError::Service(UpdateJobError::BucketPermission(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::BucketPermission(parsed_error.message),
+                        )
                     }
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::CanceledJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::CanceledJobId(parsed_error.message),
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::ExpiredJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::ExpiredJobId(parsed_error.message),
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidAccessKeyId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidAccessKeyId(parsed_error.message),
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidAddress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidAddress(parsed_error.message),
+                        )
                     }
                     "InvalidCustomsException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidCustoms(parsed_error.message),
+                        )
                     }
                     "InvalidFileSystemException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidFileSystem(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidFileSystem(parsed_error.message),
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidJobId(parsed_error.message),
+                        )
                     }
                     "InvalidManifestFieldException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidManifestField(parsed_error.message),
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::InvalidVersion(parsed_error.message),
+                        )
                     }
                     "MalformedManifestException" => {
-                        return RusotoError::Service(UpdateJobError::MalformedManifest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::MalformedManifest(parsed_error.message),
+                        )
                     }
                     "MissingCustomsException" => {
-                        return RusotoError::Service(UpdateJobError::MissingCustoms(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::MissingCustoms(parsed_error.message),
+                        )
                     }
                     "MissingManifestFieldException" => {
-                        return RusotoError::Service(UpdateJobError::MissingManifestField(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::MissingManifestField(parsed_error.message),
+                        )
                     }
                     "MissingParameterException" => {
-                        return RusotoError::Service(UpdateJobError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "MultipleRegionsException" => {
-                        return RusotoError::Service(UpdateJobError::MultipleRegions(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::MultipleRegions(parsed_error.message),
+                        )
                     }
                     "NoSuchBucketException" => {
-                        return RusotoError::Service(UpdateJobError::NoSuchBucket(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::NoSuchBucket(parsed_error.message),
+                        )
                     }
                     "UnableToUpdateJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::UnableToUpdateJobId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateJobError::UnableToUpdateJobId(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1568,6 +1623,7 @@ impl ImportExportClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1583,6 +1639,7 @@ impl ImportExportClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ImportExportClient {
         ImportExportClient { client, region }
     }
@@ -1601,6 +1658,9 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CancelJob",
         );
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelJob");
         let mut params = params;
         CancelJobInputSerializer::serialize(&mut params, "", &input);
@@ -1636,6 +1696,9 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CreateJob",
         );
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateJob");
         let mut params = params;
         CreateJobInputSerializer::serialize(&mut params, "", &input);
@@ -1671,6 +1734,9 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetShippingLabel",
         );
+        request.set_operation_name("GetShippingLabel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetShippingLabel");
         let mut params = params;
         GetShippingLabelInputSerializer::serialize(&mut params, "", &input);
@@ -1707,6 +1773,9 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetStatus",
         );
+        request.set_operation_name("GetStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetStatus");
         let mut params = params;
         GetStatusInputSerializer::serialize(&mut params, "", &input);
@@ -1738,6 +1807,9 @@ impl ImportExport for ImportExportClient {
     ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
         let mut request =
             SignedRequest::new("POST", "importexport", &self.region, "/?Operation=ListJobs");
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListJobs");
         let mut params = params;
         ListJobsInputSerializer::serialize(&mut params, "", &input);
@@ -1773,6 +1845,9 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=UpdateJob",
         );
+        request.set_operation_name("UpdateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateJob");
         let mut params = params;
         UpdateJobInputSerializer::serialize(&mut params, "", &input);
@@ -1798,6 +1873,116 @@ impl ImportExport for ImportExportClient {
     }
 }
 
+/// A blocking client for the AWS Import/Export API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ImportExportClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ImportExportBlockingClient {
+    client: ImportExportClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ImportExportBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ImportExportBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ImportExportBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ImportExportBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ImportExportBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ImportExportBlockingClient {
+        ImportExportBlockingClient {
+            client: ImportExportClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>This operation cancels a specified job. Only the job owner can cancel it. The operation fails if the job has already started or is complete.</p>
+    ///
+    /// See `ImportExport::cancel_job`.
+    pub fn cancel_job(
+        &self,
+        input: CancelJobInput,
+    ) -> Result<CancelJobOutput, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p>This operation initiates the process of scheduling an upload or download of your data. You include in the request a manifest that describes the data transfer specifics. The response to the request includes a job ID, which you can use in other operations, a signature that you use to identify your storage device, and the address where you should ship your storage device.</p>
+    ///
+    /// See `ImportExport::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobInput,
+    ) -> Result<CreateJobOutput, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>This operation generates a pre-paid UPS shipping label that you will use to ship your device to AWS for processing.</p>
+    ///
+    /// See `ImportExport::get_shipping_label`.
+    pub fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> Result<GetShippingLabelOutput, RusotoError<GetShippingLabelError>> {
+        self.runtime.block_on(self.client.get_shipping_label(input))
+    }
+
+    /// <p>This operation returns information about a job, including where the job is in the processing pipeline, the status of the results, and the signature value associated with the job. You can only return information about jobs you own.</p>
+    ///
+    /// See `ImportExport::get_status`.
+    pub fn get_status(
+        &self,
+        input: GetStatusInput,
+    ) -> Result<GetStatusOutput, RusotoError<GetStatusError>> {
+        self.runtime.block_on(self.client.get_status(input))
+    }
+
+    /// <p>This operation returns the jobs associated with the requester. AWS Import/Export lists the jobs in reverse chronological order based on the date of creation. For example if Job Test1 was created 2009Dec30 and Test2 was created 2010Feb05, the ListJobs operation would return Test2 followed by Test1.</p>
+    ///
+    /// See `ImportExport::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>You use this operation to change the parameters specified in the original manifest file by supplying a new manifest file. The manifest file attached to this request replaces the original manifest file. You can only use the operation after a CreateJob request but before the data transfer starts and you can only use it on jobs you own.</p>
+    ///
+    /// See `ImportExport::update_job`.
+    pub fn update_job(
+        &self,
+        input: UpdateJobInput,
+    ) -> Result<UpdateJobOutput, RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -1586,25 +1586,32 @@ impl AddAttributesToFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttributesToFindingsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddAttributesToFindingsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttributesToFindingsError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttributesToFindingsError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddAttributesToFindingsError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -1654,29 +1661,44 @@ impl CreateAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTargetError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTargetError::Internal(err.msg),
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssessmentTargetError::InvalidCrossAccountRole(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTargetError::LimitExceeded(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTargetError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -1728,30 +1750,38 @@ impl CreateAssessmentTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTemplateError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTemplateError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTemplateError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTemplateError::LimitExceeded(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssessmentTemplateError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -1800,30 +1830,38 @@ impl CreateExclusionsPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateExclusionsPreviewError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateExclusionsPreviewError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateExclusionsPreviewError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateExclusionsPreviewError::NoSuchEntity(err.msg),
+                    )
                 }
                 "PreviewGenerationInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateExclusionsPreviewError::PreviewGenerationInProgress(err.msg),
                     )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateExclusionsPreviewError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -1872,19 +1910,29 @@ impl CreateResourceGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateResourceGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceGroupError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateResourceGroupError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateResourceGroupError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateResourceGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceGroupError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -1932,24 +1980,35 @@ impl DeleteAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentRunError::AccessDenied(err.msg),
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::AssessmentRunInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentRunError::AssessmentRunInProgress(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteAssessmentRunError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentRunError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentRunError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -1998,24 +2057,38 @@ impl DeleteAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTargetError::AccessDenied(err.msg),
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAssessmentTargetError::AssessmentRunInProgress(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTargetError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTargetError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -2066,30 +2139,38 @@ impl DeleteAssessmentTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTemplateError::AccessDenied(err.msg),
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAssessmentTemplateError::AssessmentRunInProgress(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTemplateError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTemplateError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssessmentTemplateError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -2132,10 +2213,16 @@ impl DescribeAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentRunsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssessmentRunsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssessmentRunsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2168,12 +2255,16 @@ impl DescribeAssessmentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentTargetsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssessmentTargetsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentTargetsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssessmentTargetsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2208,14 +2299,16 @@ impl DescribeAssessmentTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentTemplatesError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssessmentTemplatesError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentTemplatesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssessmentTemplatesError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2248,9 +2341,10 @@ impl DescribeCrossAccountAccessRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeCrossAccountAccessRoleError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCrossAccountAccessRoleError::Internal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2282,10 +2376,13 @@ impl DescribeExclusionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeExclusionsError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeExclusionsError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeExclusionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExclusionsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2318,10 +2415,10 @@ impl DescribeFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeFindingsError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeFindingsError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeFindingsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2354,10 +2451,16 @@ impl DescribeResourceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeResourceGroupsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceGroupsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeResourceGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceGroupsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2390,10 +2493,16 @@ impl DescribeRulesPackagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeRulesPackagesError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRulesPackagesError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeRulesPackagesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRulesPackagesError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2436,31 +2545,43 @@ impl GetAssessmentReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAssessmentReportError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAssessmentReportError::AccessDenied(err.msg),
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(GetAssessmentReportError::AssessmentRunInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAssessmentReportError::AssessmentRunInProgress(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetAssessmentReportError::Internal(err.msg))
+                    return RusotoError::service(&res, GetAssessmentReportError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAssessmentReportError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAssessmentReportError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetAssessmentReportError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAssessmentReportError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssessmentReportError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(GetAssessmentReportError::UnsupportedFeature(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAssessmentReportError::UnsupportedFeature(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2504,16 +2625,25 @@ impl GetExclusionsPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExclusionsPreviewError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::Internal(err.msg))
+                    return RusotoError::service(&res, GetExclusionsPreviewError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExclusionsPreviewError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExclusionsPreviewError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2552,16 +2682,25 @@ impl GetTelemetryMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTelemetryMetadataError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::Internal(err.msg))
+                    return RusotoError::service(&res, GetTelemetryMetadataError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTelemetryMetadataError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTelemetryMetadataError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2600,22 +2739,28 @@ impl ListAssessmentRunAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunAgentsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunAgentsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunAgentsError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunAgentsError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2654,16 +2799,25 @@ impl ListAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListAssessmentRunsError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunsError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentRunsError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2700,13 +2854,22 @@ impl ListAssessmentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTargetsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTargetsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTargetsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2744,22 +2907,28 @@ impl ListAssessmentTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTemplatesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTemplatesError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTemplatesError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssessmentTemplatesError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2798,16 +2967,28 @@ impl ListEventSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSubscriptionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSubscriptionsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSubscriptionsError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSubscriptionsError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2846,16 +3027,16 @@ impl ListExclusionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListExclusionsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListExclusionsError::AccessDenied(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListExclusionsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListExclusionsError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListExclusionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListExclusionsError::InvalidInput(err.msg))
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListExclusionsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(&res, ListExclusionsError::NoSuchEntity(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2894,16 +3075,16 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::AccessDenied(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListFindingsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::InvalidInput(err.msg))
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListFindingsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(&res, ListFindingsError::NoSuchEntity(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2940,13 +3121,19 @@ impl ListRulesPackagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRulesPackagesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRulesPackagesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListRulesPackagesError::Internal(err.msg))
+                    return RusotoError::service(&res, ListRulesPackagesError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRulesPackagesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRulesPackagesError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2984,16 +3171,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3034,21 +3230,22 @@ impl PreviewAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PreviewAgentsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, PreviewAgentsError::AccessDenied(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PreviewAgentsError::Internal(err.msg))
+                    return RusotoError::service(&res, PreviewAgentsError::Internal(err.msg))
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(PreviewAgentsError::InvalidCrossAccountRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PreviewAgentsError::InvalidCrossAccountRole(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PreviewAgentsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, PreviewAgentsError::InvalidInput(err.msg))
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(PreviewAgentsError::NoSuchEntity(err.msg))
+                    return RusotoError::service(&res, PreviewAgentsError::NoSuchEntity(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3092,27 +3289,32 @@ impl RegisterCrossAccountAccessRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCrossAccountAccessRoleError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCrossAccountAccessRoleError::Internal(err.msg),
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCrossAccountAccessRoleError::InvalidCrossAccountRole(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCrossAccountAccessRoleError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3162,27 +3364,32 @@ impl RemoveAttributesFromFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAttributesFromFindingsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAttributesFromFindingsError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAttributesFromFindingsError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::NoSuchEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAttributesFromFindingsError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3228,19 +3435,29 @@ impl SetTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SetTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(SetTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, SetTagsForResourceError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SetTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(SetTagsForResourceError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetTagsForResourceError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetTagsForResourceError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3292,32 +3509,47 @@ impl StartAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartAssessmentRunError::AccessDenied(err.msg),
+                    )
                 }
                 "AgentsAlreadyRunningAssessmentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAssessmentRunError::AgentsAlreadyRunningAssessment(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(StartAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service(&res, StartAssessmentRunError::Internal(err.msg))
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(StartAssessmentRunError::InvalidCrossAccountRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAssessmentRunError::InvalidCrossAccountRole(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartAssessmentRunError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartAssessmentRunError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartAssessmentRunError::LimitExceeded(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(StartAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartAssessmentRunError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3368,19 +3600,29 @@ impl StopAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAssessmentRunError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(StopAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service(&res, StopAssessmentRunError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAssessmentRunError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(StopAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAssessmentRunError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3428,22 +3670,26 @@ impl SubscribeToEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubscribeToEventError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, SubscribeToEventError::AccessDenied(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(SubscribeToEventError::Internal(err.msg))
+                    return RusotoError::service(&res, SubscribeToEventError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SubscribeToEventError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, SubscribeToEventError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SubscribeToEventError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToEventError::LimitExceeded(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(SubscribeToEventError::NoSuchEntity(err.msg))
+                    return RusotoError::service(&res, SubscribeToEventError::NoSuchEntity(err.msg))
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SubscribeToEventError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3490,19 +3736,29 @@ impl UnsubscribeFromEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromEventError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::Internal(err.msg))
+                    return RusotoError::service(&res, UnsubscribeFromEventError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromEventError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnsubscribeFromEventError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UnsubscribeFromEventError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3548,19 +3804,32 @@ impl UpdateAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssessmentTargetError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssessmentTargetError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssessmentTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssessmentTargetError::NoSuchEntity(err.msg),
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
                     )
                 }
@@ -3831,6 +4100,7 @@ impl InspectorClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3846,6 +4116,7 @@ impl InspectorClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> InspectorClient {
         InspectorClient { client, region }
     }
@@ -3859,6 +4130,9 @@ impl Inspector for InspectorClient {
         input: AddAttributesToFindingsRequest,
     ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddAttributesToFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.AddAttributesToFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3878,6 +4152,9 @@ impl Inspector for InspectorClient {
         input: CreateAssessmentTargetRequest,
     ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAssessmentTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3897,6 +4174,9 @@ impl Inspector for InspectorClient {
         input: CreateAssessmentTemplateRequest,
     ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAssessmentTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3916,6 +4196,9 @@ impl Inspector for InspectorClient {
         input: CreateExclusionsPreviewRequest,
     ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateExclusionsPreview");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.CreateExclusionsPreview");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3935,6 +4218,9 @@ impl Inspector for InspectorClient {
         input: CreateResourceGroupRequest,
     ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateResourceGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.CreateResourceGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3953,6 +4239,9 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentRunRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAssessmentRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3970,6 +4259,9 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentTargetRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAssessmentTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3987,6 +4279,9 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentTemplateRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAssessmentTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4004,6 +4299,9 @@ impl Inspector for InspectorClient {
         input: DescribeAssessmentRunsRequest,
     ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAssessmentRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4024,6 +4322,9 @@ impl Inspector for InspectorClient {
     ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAssessmentTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4044,6 +4345,9 @@ impl Inspector for InspectorClient {
     ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAssessmentTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "InspectorService.DescribeAssessmentTemplates",
@@ -4068,6 +4372,9 @@ impl Inspector for InspectorClient {
         RusotoError<DescribeCrossAccountAccessRoleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCrossAccountAccessRole");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "InspectorService.DescribeCrossAccountAccessRole",
@@ -4089,6 +4396,9 @@ impl Inspector for InspectorClient {
         input: DescribeExclusionsRequest,
     ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeExclusions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.DescribeExclusions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4107,6 +4417,9 @@ impl Inspector for InspectorClient {
         input: DescribeFindingsRequest,
     ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.DescribeFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4125,6 +4438,9 @@ impl Inspector for InspectorClient {
         input: DescribeResourceGroupsRequest,
     ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeResourceGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.DescribeResourceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4144,6 +4460,9 @@ impl Inspector for InspectorClient {
         input: DescribeRulesPackagesRequest,
     ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRulesPackages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.DescribeRulesPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4163,6 +4482,9 @@ impl Inspector for InspectorClient {
         input: GetAssessmentReportRequest,
     ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAssessmentReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.GetAssessmentReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4181,6 +4503,9 @@ impl Inspector for InspectorClient {
         input: GetExclusionsPreviewRequest,
     ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetExclusionsPreview");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.GetExclusionsPreview");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4200,6 +4525,9 @@ impl Inspector for InspectorClient {
         input: GetTelemetryMetadataRequest,
     ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTelemetryMetadata");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.GetTelemetryMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4219,6 +4547,9 @@ impl Inspector for InspectorClient {
         input: ListAssessmentRunAgentsRequest,
     ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssessmentRunAgents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRunAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4238,6 +4569,9 @@ impl Inspector for InspectorClient {
         input: ListAssessmentRunsRequest,
     ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssessmentRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4256,6 +4590,9 @@ impl Inspector for InspectorClient {
         input: ListAssessmentTargetsRequest,
     ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssessmentTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4275,6 +4612,9 @@ impl Inspector for InspectorClient {
         input: ListAssessmentTemplatesRequest,
     ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssessmentTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTemplates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4294,6 +4634,9 @@ impl Inspector for InspectorClient {
         input: ListEventSubscriptionsRequest,
     ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEventSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListEventSubscriptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4313,6 +4656,9 @@ impl Inspector for InspectorClient {
         input: ListExclusionsRequest,
     ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListExclusions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListExclusions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4331,6 +4677,9 @@ impl Inspector for InspectorClient {
         input: ListFindingsRequest,
     ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4349,6 +4698,9 @@ impl Inspector for InspectorClient {
         input: ListRulesPackagesRequest,
     ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRulesPackages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListRulesPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4367,6 +4719,9 @@ impl Inspector for InspectorClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "InspectorService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4385,6 +4740,9 @@ impl Inspector for InspectorClient {
         input: PreviewAgentsRequest,
     ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PreviewAgents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.PreviewAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4403,6 +4761,9 @@ impl Inspector for InspectorClient {
         input: RegisterCrossAccountAccessRoleRequest,
     ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterCrossAccountAccessRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "InspectorService.RegisterCrossAccountAccessRole",
@@ -4424,6 +4785,9 @@ impl Inspector for InspectorClient {
     ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveAttributesFromFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "InspectorService.RemoveAttributesFromFindings",
@@ -4446,6 +4810,9 @@ impl Inspector for InspectorClient {
         input: SetTagsForResourceRequest,
     ) -> Result<(), RusotoError<SetTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetTagsForResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.SetTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4463,6 +4830,9 @@ impl Inspector for InspectorClient {
         input: StartAssessmentRunRequest,
     ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartAssessmentRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.StartAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4481,6 +4851,9 @@ impl Inspector for InspectorClient {
         input: StopAssessmentRunRequest,
     ) -> Result<(), RusotoError<StopAssessmentRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopAssessmentRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.StopAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4498,6 +4871,9 @@ impl Inspector for InspectorClient {
         input: SubscribeToEventRequest,
     ) -> Result<(), RusotoError<SubscribeToEventError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SubscribeToEvent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.SubscribeToEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4515,6 +4891,9 @@ impl Inspector for InspectorClient {
         input: UnsubscribeFromEventRequest,
     ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnsubscribeFromEvent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.UnsubscribeFromEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4532,6 +4911,9 @@ impl Inspector for InspectorClient {
         input: UpdateAssessmentTargetRequest,
     ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAssessmentTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "InspectorService.UpdateAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4543,3 +4925,460 @@ impl Inspector for InspectorClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon Inspector API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `InspectorClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct InspectorBlockingClient {
+    client: InspectorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl InspectorBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> InspectorBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        InspectorBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> InspectorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        InspectorBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> InspectorBlockingClient {
+        InspectorBlockingClient {
+            client: InspectorClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Assigns attributes (key and value pairs) to the findings that are specified by the ARNs of the findings.</p>
+    ///
+    /// See `Inspector::add_attributes_to_findings`.
+    pub fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
+        self.runtime
+            .block_on(self.client.add_attributes_to_findings(input))
+    }
+
+    /// <p>Creates a new assessment target using the ARN of the resource group that is generated by <a>CreateResourceGroup</a>. If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments. You can create up to 50 assessment targets per AWS account. You can run up to 500 concurrent agents per AWS account. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html"> Amazon Inspector Assessment Targets</a>.</p>
+    ///
+    /// See `Inspector::create_assessment_target`.
+    pub fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
+        self.runtime
+            .block_on(self.client.create_assessment_target(input))
+    }
+
+    /// <p>Creates an assessment template for the assessment target that is specified by the ARN of the assessment target. If the <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_slr.html">service-linked role</a> isn’t already registered, this action also creates and registers a service-linked role to grant Amazon Inspector access to AWS Services needed to perform security assessments.</p>
+    ///
+    /// See `Inspector::create_assessment_template`.
+    pub fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_assessment_template(input))
+    }
+
+    /// <p>Starts the generation of an exclusions preview for the specified assessment template. The exclusions preview lists the potential exclusions (ExclusionPreview) that Inspector can detect before it runs the assessment. </p>
+    ///
+    /// See `Inspector::create_exclusions_preview`.
+    pub fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
+        self.runtime
+            .block_on(self.client.create_exclusions_preview(input))
+    }
+
+    /// <p>Creates a resource group using the specified set of tags (key and value pairs) that are used to select the EC2 instances to be included in an Amazon Inspector assessment target. The created resource group is then used to create an Amazon Inspector assessment target. For more information, see <a>CreateAssessmentTarget</a>.</p>
+    ///
+    /// See `Inspector::create_resource_group`.
+    pub fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
+        self.runtime
+            .block_on(self.client.create_resource_group(input))
+    }
+
+    /// <p>Deletes the assessment run that is specified by the ARN of the assessment run.</p>
+    ///
+    /// See `Inspector::delete_assessment_run`.
+    pub fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
+        self.runtime
+            .block_on(self.client.delete_assessment_run(input))
+    }
+
+    /// <p>Deletes the assessment target that is specified by the ARN of the assessment target.</p>
+    ///
+    /// See `Inspector::delete_assessment_target`.
+    pub fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_assessment_target(input))
+    }
+
+    /// <p>Deletes the assessment template that is specified by the ARN of the assessment template.</p>
+    ///
+    /// See `Inspector::delete_assessment_template`.
+    pub fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_assessment_template(input))
+    }
+
+    /// <p>Describes the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    ///
+    /// See `Inspector::describe_assessment_runs`.
+    pub fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
+        self.runtime
+            .block_on(self.client.describe_assessment_runs(input))
+    }
+
+    /// <p>Describes the assessment targets that are specified by the ARNs of the assessment targets.</p>
+    ///
+    /// See `Inspector::describe_assessment_targets`.
+    pub fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_assessment_targets(input))
+    }
+
+    /// <p>Describes the assessment templates that are specified by the ARNs of the assessment templates.</p>
+    ///
+    /// See `Inspector::describe_assessment_templates`.
+    pub fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_assessment_templates(input))
+    }
+
+    /// <p>Describes the IAM role that enables Amazon Inspector to access your AWS account.</p>
+    ///
+    /// See `Inspector::describe_cross_account_access_role`.
+    pub fn describe_cross_account_access_role(
+        &self,
+    ) -> Result<
+        DescribeCrossAccountAccessRoleResponse,
+        RusotoError<DescribeCrossAccountAccessRoleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_cross_account_access_role())
+    }
+
+    /// <p>Describes the exclusions that are specified by the exclusions' ARNs.</p>
+    ///
+    /// See `Inspector::describe_exclusions`.
+    pub fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
+        self.runtime
+            .block_on(self.client.describe_exclusions(input))
+    }
+
+    /// <p>Describes the findings that are specified by the ARNs of the findings.</p>
+    ///
+    /// See `Inspector::describe_findings`.
+    pub fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
+        self.runtime.block_on(self.client.describe_findings(input))
+    }
+
+    /// <p>Describes the resource groups that are specified by the ARNs of the resource groups.</p>
+    ///
+    /// See `Inspector::describe_resource_groups`.
+    pub fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_resource_groups(input))
+    }
+
+    /// <p>Describes the rules packages that are specified by the ARNs of the rules packages.</p>
+    ///
+    /// See `Inspector::describe_rules_packages`.
+    pub fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
+        self.runtime
+            .block_on(self.client.describe_rules_packages(input))
+    }
+
+    /// <p>Produces an assessment report that includes detailed and comprehensive results of a specified assessment run. </p>
+    ///
+    /// See `Inspector::get_assessment_report`.
+    pub fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
+        self.runtime
+            .block_on(self.client.get_assessment_report(input))
+    }
+
+    /// <p>Retrieves the exclusions preview (a list of ExclusionPreview objects) specified by the preview token. You can obtain the preview token by running the CreateExclusionsPreview API.</p>
+    ///
+    /// See `Inspector::get_exclusions_preview`.
+    pub fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
+        self.runtime
+            .block_on(self.client.get_exclusions_preview(input))
+    }
+
+    /// <p>Information about the data that is collected for the specified assessment run.</p>
+    ///
+    /// See `Inspector::get_telemetry_metadata`.
+    pub fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
+        self.runtime
+            .block_on(self.client.get_telemetry_metadata(input))
+    }
+
+    /// <p>Lists the agents of the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    ///
+    /// See `Inspector::list_assessment_run_agents`.
+    pub fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_run_agents(input))
+    }
+
+    /// <p>Lists the assessment runs that correspond to the assessment templates that are specified by the ARNs of the assessment templates.</p>
+    ///
+    /// See `Inspector::list_assessment_runs`.
+    pub fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_runs(input))
+    }
+
+    /// <p>Lists the ARNs of the assessment targets within this AWS account. For more information about assessment targets, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_applications.html">Amazon Inspector Assessment Targets</a>.</p>
+    ///
+    /// See `Inspector::list_assessment_targets`.
+    pub fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_targets(input))
+    }
+
+    /// <p>Lists the assessment templates that correspond to the assessment targets that are specified by the ARNs of the assessment targets.</p>
+    ///
+    /// See `Inspector::list_assessment_templates`.
+    pub fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_templates(input))
+    }
+
+    /// <p>Lists all the event subscriptions for the assessment template that is specified by the ARN of the assessment template. For more information, see <a>SubscribeToEvent</a> and <a>UnsubscribeFromEvent</a>.</p>
+    ///
+    /// See `Inspector::list_event_subscriptions`.
+    pub fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.list_event_subscriptions(input))
+    }
+
+    /// <p>List exclusions that are generated by the assessment run.</p>
+    ///
+    /// See `Inspector::list_exclusions`.
+    pub fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
+        self.runtime.block_on(self.client.list_exclusions(input))
+    }
+
+    /// <p>Lists findings that are generated by the assessment runs that are specified by the ARNs of the assessment runs.</p>
+    ///
+    /// See `Inspector::list_findings`.
+    pub fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.runtime.block_on(self.client.list_findings(input))
+    }
+
+    /// <p>Lists all available Amazon Inspector rules packages.</p>
+    ///
+    /// See `Inspector::list_rules_packages`.
+    pub fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
+        self.runtime
+            .block_on(self.client.list_rules_packages(input))
+    }
+
+    /// <p>Lists all tags associated with an assessment template.</p>
+    ///
+    /// See `Inspector::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Previews the agents installed on the EC2 instances that are part of the specified assessment target.</p>
+    ///
+    /// See `Inspector::preview_agents`.
+    pub fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
+        self.runtime.block_on(self.client.preview_agents(input))
+    }
+
+    /// <p>Registers the IAM role that grants Amazon Inspector access to AWS Services needed to perform security assessments.</p>
+    ///
+    /// See `Inspector::register_cross_account_access_role`.
+    pub fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
+        self.runtime
+            .block_on(self.client.register_cross_account_access_role(input))
+    }
+
+    /// <p>Removes entire attributes (key and value pairs) from the findings that are specified by the ARNs of the findings where an attribute with the specified key exists.</p>
+    ///
+    /// See `Inspector::remove_attributes_from_findings`.
+    pub fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_attributes_from_findings(input))
+    }
+
+    /// <p>Sets tags (key and value pairs) to the assessment template that is specified by the ARN of the assessment template.</p>
+    ///
+    /// See `Inspector::set_tags_for_resource`.
+    pub fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> Result<(), RusotoError<SetTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.set_tags_for_resource(input))
+    }
+
+    /// <p>Starts the assessment run specified by the ARN of the assessment template. For this API to function properly, you must not exceed the limit of running up to 500 concurrent agents per AWS account.</p>
+    ///
+    /// See `Inspector::start_assessment_run`.
+    pub fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
+        self.runtime
+            .block_on(self.client.start_assessment_run(input))
+    }
+
+    /// <p>Stops the assessment run that is specified by the ARN of the assessment run.</p>
+    ///
+    /// See `Inspector::stop_assessment_run`.
+    pub fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> Result<(), RusotoError<StopAssessmentRunError>> {
+        self.runtime
+            .block_on(self.client.stop_assessment_run(input))
+    }
+
+    /// <p>Enables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+    ///
+    /// See `Inspector::subscribe_to_event`.
+    pub fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> Result<(), RusotoError<SubscribeToEventError>> {
+        self.runtime.block_on(self.client.subscribe_to_event(input))
+    }
+
+    /// <p>Disables the process of sending Amazon Simple Notification Service (SNS) notifications about a specified event to a specified SNS topic.</p>
+    ///
+    /// See `Inspector::unsubscribe_from_event`.
+    pub fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
+        self.runtime
+            .block_on(self.client.unsubscribe_from_event(input))
+    }
+
+    /// <p>Updates the assessment target that is specified by the ARN of the assessment target.</p> <p>If resourceGroupArn is not specified, all EC2 instances in the current AWS account and region are included in the assessment target.</p>
+    ///
+    /// See `Inspector::update_assessment_target`.
+    pub fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
+        self.runtime
+            .block_on(self.client.update_assessment_target(input))
+    }
+}
@@ -173,30 +173,47 @@ impl DeleteThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingShadowError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingShadowError::InvalidRequest(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(DeleteThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingShadowError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingShadowError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingShadowError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingShadowError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingShadowError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingShadowError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteThingShadowError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingShadowError::Unauthorized(err.msg),
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteThingShadowError::UnsupportedDocumentEncoding(err.msg),
                     )
                 }
@@ -251,30 +268,43 @@ impl GetThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetThingShadowError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetThingShadowError::InvalidRequest(err.msg))
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetThingShadowError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetThingShadowError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetThingShadowError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetThingShadowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetThingShadowError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetThingShadowError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetThingShadowError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetThingShadowError::Unauthorized(err.msg))
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(GetThingShadowError::UnsupportedDocumentEncoding(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetThingShadowError::UnsupportedDocumentEncoding(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -323,37 +353,46 @@ impl ListNamedShadowsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::InvalidRequest(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::MethodNotAllowed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListNamedShadowsForThingError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -395,16 +434,16 @@ impl PublishError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PublishError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PublishError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PublishError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, PublishError::InvalidRequest(err.msg))
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(PublishError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, PublishError::MethodNotAllowed(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PublishError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, PublishError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -453,35 +492,50 @@ impl UpdateThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateThingShadowError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingShadowError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingShadowError::InvalidRequest(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingShadowError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "RequestEntityTooLargeException" => {
-                    return RusotoError::Service(UpdateThingShadowError::RequestEntityTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingShadowError::RequestEntityTooLarge(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateThingShadowError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingShadowError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateThingShadowError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingShadowError::Unauthorized(err.msg),
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateThingShadowError::UnsupportedDocumentEncoding(err.msg),
                     )
                 }
@@ -559,6 +613,7 @@ impl IotDataClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -574,6 +629,7 @@ impl IotDataClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotDataClient {
         IotDataClient { client, region }
     }
@@ -590,6 +646,9 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "iotdata", &self.region, &request_uri);
+        request.set_operation_name("DeleteThingShadow");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -627,6 +686,9 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation_name("GetThingShadow");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -667,6 +729,9 @@ impl IotData for IotDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation_name("ListNamedShadowsForThing");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -703,6 +768,9 @@ impl IotData for IotDataClient {
         let request_uri = format!("/topics/{topic}", topic = input.topic);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation_name("Publish");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -744,6 +812,9 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation_name("UpdateThingShadow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -774,3 +845,103 @@ impl IotData for IotDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Data Plane API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotDataClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotDataBlockingClient {
+    client: IotDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotDataBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotDataBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotDataBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotDataBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotDataBlockingClient {
+        IotDataBlockingClient {
+            client: IotDataClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Deletes the shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_DeleteThingShadow.html">DeleteThingShadow</a> in the AWS IoT Developer Guide.</p>
+    ///
+    /// See `IotData::delete_thing_shadow`.
+    pub fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> Result<DeleteThingShadowResponse, RusotoError<DeleteThingShadowError>> {
+        self.runtime
+            .block_on(self.client.delete_thing_shadow(input))
+    }
+
+    /// <p>Gets the shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_GetThingShadow.html">GetThingShadow</a> in the AWS IoT Developer Guide.</p>
+    ///
+    /// See `IotData::get_thing_shadow`.
+    pub fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> Result<GetThingShadowResponse, RusotoError<GetThingShadowError>> {
+        self.runtime.block_on(self.client.get_thing_shadow(input))
+    }
+
+    /// <p>Lists the shadows for the specified thing.</p>
+    ///
+    /// See `IotData::list_named_shadows_for_thing`.
+    pub fn list_named_shadows_for_thing(
+        &self,
+        input: ListNamedShadowsForThingRequest,
+    ) -> Result<ListNamedShadowsForThingResponse, RusotoError<ListNamedShadowsForThingError>> {
+        self.runtime
+            .block_on(self.client.list_named_shadows_for_thing(input))
+    }
+
+    /// <p>Publishes state information.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/protocols.html#http">HTTP Protocol</a> in the AWS IoT Developer Guide.</p>
+    ///
+    /// See `IotData::publish`.
+    pub fn publish(&self, input: PublishRequest) -> Result<(), RusotoError<PublishError>> {
+        self.runtime.block_on(self.client.publish(input))
+    }
+
+    /// <p>Updates the shadow for the specified thing.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/iot/latest/developerguide/API_UpdateThingShadow.html">UpdateThingShadow</a> in the AWS IoT Developer Guide.</p>
+    ///
+    /// See `IotData::update_thing_shadow`.
+    pub fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> Result<UpdateThingShadowResponse, RusotoError<UpdateThingShadowError>> {
+        self.runtime
+            .block_on(self.client.update_thing_shadow(input))
+    }
+}
@@ -270,28 +270,40 @@ impl DescribeJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::CertificateValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::CertificateValidation(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TerminalStateException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::TerminalState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::TerminalState(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -334,27 +346,34 @@ impl GetPendingJobExecutionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPendingJobExecutionsError::CertificateValidation(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPendingJobExecutionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPendingJobExecutionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPendingJobExecutionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPendingJobExecutionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -400,29 +419,34 @@ impl StartNextPendingJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartNextPendingJobExecutionError::CertificateValidation(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartNextPendingJobExecutionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartNextPendingJobExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartNextPendingJobExecutionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartNextPendingJobExecutionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartNextPendingJobExecutionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartNextPendingJobExecutionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -472,28 +496,37 @@ impl UpdateJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::CertificateValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobExecutionError::CertificateValidation(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobExecutionError::InvalidStateTransition(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobExecutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobExecutionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateJobExecutionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -561,6 +594,7 @@ impl IotJobsDataClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -576,6 +610,7 @@ impl IotJobsDataClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotJobsDataClient {
         IotJobsDataClient { client, region }
     }
@@ -596,6 +631,9 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation_name("DescribeJobExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -635,6 +673,9 @@ impl IotJobsData for IotJobsDataClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation_name("GetPendingJobExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -669,6 +710,9 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation_name("StartNextPendingJobExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -705,6 +749,9 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation_name("UpdateJobExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -728,3 +775,98 @@ impl IotJobsData for IotJobsDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Jobs Data Plane API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotJobsDataClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotJobsDataBlockingClient {
+    client: IotJobsDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotJobsDataBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotJobsDataBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotJobsDataBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotJobsDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotJobsDataBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient {
+            client: IotJobsDataClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Gets details of a job execution.</p>
+    ///
+    /// See `IotJobsData::describe_job_execution`.
+    pub fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> Result<DescribeJobExecutionResponse, RusotoError<DescribeJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_job_execution(input))
+    }
+
+    /// <p>Gets the list of all jobs for a thing that are not in a terminal status.</p>
+    ///
+    /// See `IotJobsData::get_pending_job_executions`.
+    pub fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> Result<GetPendingJobExecutionsResponse, RusotoError<GetPendingJobExecutionsError>> {
+        self.runtime
+            .block_on(self.client.get_pending_job_executions(input))
+    }
+
+    /// <p>Gets and starts the next pending (status IN_PROGRESS or QUEUED) job execution for a thing.</p>
+    ///
+    /// See `IotJobsData::start_next_pending_job_execution`.
+    pub fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> Result<StartNextPendingJobExecutionResponse, RusotoError<StartNextPendingJobExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_next_pending_job_execution(input))
+    }
+
+    /// <p>Updates the status of a job execution.</p>
+    ///
+    /// See `IotJobsData::update_job_execution`.
+    pub fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> Result<UpdateJobExecutionResponse, RusotoError<UpdateJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.update_job_execution(input))
+    }
+}
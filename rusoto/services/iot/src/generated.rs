@@ -8873,39 +8873,46 @@ impl AcceptCertificateTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptCertificateTransferError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptCertificateTransferError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptCertificateTransferError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptCertificateTransferError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptCertificateTransferError::Throttling(err.msg),
+                    )
                 }
                 "TransferAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptCertificateTransferError::TransferAlreadyCompleted(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptCertificateTransferError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8949,22 +8956,28 @@ impl AddThingToBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToBillingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToBillingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9003,20 +9016,28 @@ impl AddThingToThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddThingToThingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9057,27 +9078,34 @@ impl AssociateTargetsWithJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTargetsWithJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTargetsWithJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTargetsWithJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTargetsWithJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTargetsWithJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9123,25 +9151,28 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachPolicyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AttachPolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachPolicyError::Throttling(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AttachPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9189,33 +9220,46 @@ impl AttachPrincipalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPrincipalPolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9261,30 +9305,40 @@ impl AttachSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachSecurityProfileError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachSecurityProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachSecurityProfileError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachSecurityProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::VersionConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachSecurityProfileError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9329,28 +9383,40 @@ impl AttachThingPrincipalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachThingPrincipalError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachThingPrincipalError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachThingPrincipalError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachThingPrincipalError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachThingPrincipalError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachThingPrincipalError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9393,24 +9459,28 @@ impl CancelAuditMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelAuditMitigationActionsTaskError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelAuditMitigationActionsTaskError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelAuditMitigationActionsTaskError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelAuditMitigationActionsTaskError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelAuditMitigationActionsTaskError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9455,16 +9525,25 @@ impl CancelAuditTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelAuditTaskError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelAuditTaskError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelAuditTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelAuditTaskError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelAuditTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelAuditTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelAuditTaskError::Throttling(err.msg))
+                    return RusotoError::service(&res, CancelAuditTaskError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9509,39 +9588,46 @@ impl CancelCertificateTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelCertificateTransferError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelCertificateTransferError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelCertificateTransferError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelCertificateTransferError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelCertificateTransferError::Throttling(err.msg),
+                    )
                 }
                 "TransferAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelCertificateTransferError::TransferAlreadyCompleted(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelCertificateTransferError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9585,16 +9671,16 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CancelJobError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CancelJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CancelJobError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, CancelJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9637,26 +9723,37 @@ impl CancelJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelJobExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(CancelJobExecutionError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelJobExecutionError::InvalidStateTransition(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelJobExecutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelJobExecutionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CancelJobExecutionError::Throttling(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(CancelJobExecutionError::VersionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelJobExecutionError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9701,30 +9798,40 @@ impl ClearDefaultAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClearDefaultAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClearDefaultAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClearDefaultAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClearDefaultAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClearDefaultAuthorizerError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClearDefaultAuthorizerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9769,29 +9876,34 @@ impl ConfirmTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ConfirmTopicRuleDestinationError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmTopicRuleDestinationError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ConfirmTopicRuleDestinationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmTopicRuleDestinationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConfirmTopicRuleDestinationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ConfirmTopicRuleDestinationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ConfirmTopicRuleDestinationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9841,27 +9953,40 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAuthorizerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9903,18 +10028,25 @@ impl CreateBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBillingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateBillingGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBillingGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateBillingGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9955,27 +10087,34 @@ impl CreateCertificateFromCsrError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateFromCsrError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateFromCsrError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateFromCsrError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateFromCsrError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCertificateFromCsrError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10017,21 +10156,28 @@ impl CreateDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDimensionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDimensionError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDimensionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDimensionError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDimensionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDimensionError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDimensionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDimensionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10079,44 +10225,52 @@ impl CreateDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDomainConfigurationError::CertificateValidation(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainConfigurationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainConfigurationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDomainConfigurationError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDomainConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10169,37 +10323,46 @@ impl CreateDynamicThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDynamicThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::InvalidQuery(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDynamicThingGroupError::InvalidQuery(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDynamicThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDynamicThingGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDynamicThingGroupError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDynamicThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDynamicThingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10247,22 +10410,25 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateJobError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateJobError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateJobError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10305,27 +10471,34 @@ impl CreateKeysAndCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeysAndCertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeysAndCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeysAndCertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeysAndCertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeysAndCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10367,27 +10540,34 @@ impl CreateMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateMitigationActionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMitigationActionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMitigationActionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMitigationActionError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMitigationActionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMitigationActionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMitigationActionError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMitigationActionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10435,30 +10615,43 @@ impl CreateOTAUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOTAUpdateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOTAUpdateError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateOTAUpdateError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOTAUpdateError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOTAUpdateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOTAUpdateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateOTAUpdateError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateOTAUpdateError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10507,25 +10700,31 @@ impl CreatePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePolicyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::InvalidRequest(err.msg))
                 }
                 "MalformedPolicyException" => {
-                    return RusotoError::Service(CreatePolicyError::MalformedPolicy(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::MalformedPolicy(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePolicyError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreatePolicyError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10575,34 +10774,52 @@ impl CreatePolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::InvalidRequest(err.msg),
+                    )
                 }
                 "MalformedPolicyException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::MalformedPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::MalformedPolicy(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "VersionsLimitExceededException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::VersionsLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyVersionError::VersionsLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10649,32 +10866,40 @@ impl CreateProvisioningClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningClaimError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningClaimError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningClaimError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningClaimError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningClaimError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningClaimError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10721,34 +10946,40 @@ impl CreateProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningTemplateError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningTemplateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningTemplateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10799,37 +11030,44 @@ impl CreateProvisioningTemplateVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::ConflictingResourceUpdate(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::Throttling(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::Unauthorized(err.msg),
                     )
                 }
                 "VersionsLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningTemplateVersionError::VersionsLimitExceeded(err.msg),
                     )
                 }
@@ -10891,27 +11129,37 @@ impl CreateRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoleAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoleAliasError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRoleAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateRoleAliasError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRoleAliasError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoleAliasError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoleAliasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRoleAliasError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateRoleAliasError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateRoleAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10955,23 +11203,34 @@ impl CreateScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateScheduledAuditError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateScheduledAuditError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateScheduledAuditError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateScheduledAuditError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateScheduledAuditError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11011,22 +11270,28 @@ impl CreateSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityProfileError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityProfileError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11073,28 +11338,34 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateStreamError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateStreamError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateStreamError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11143,25 +11414,31 @@ impl CreateThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThingError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateThingError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateThingError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateThingError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateThingError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateThingError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThingError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateThingError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateThingError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateThingError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11203,18 +11480,25 @@ impl CreateThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateThingGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateThingGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11257,24 +11541,34 @@ impl CreateThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingTypeError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingTypeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateThingTypeError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingTypeError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateThingTypeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThingTypeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThingTypeError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateThingTypeError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateThingTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11319,26 +11613,34 @@ impl CreateTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(CreateTopicRuleError::ConflictingResourceUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTopicRuleError::ConflictingResourceUpdate(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateTopicRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateTopicRuleError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTopicRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTopicRuleError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTopicRuleError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTopicRuleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "SqlParseException" => {
-                    return RusotoError::Service(CreateTopicRuleError::SqlParse(err.msg))
+                    return RusotoError::service(&res, CreateTopicRuleError::SqlParse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11383,25 +11685,32 @@ impl CreateTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTopicRuleDestinationError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTopicRuleDestinationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTopicRuleDestinationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTopicRuleDestinationError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateTopicRuleDestinationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -11451,24 +11760,28 @@ impl DeleteAccountAuditConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAccountAuditConfigurationError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAccountAuditConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAccountAuditConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAccountAuditConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccountAuditConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11519,25 +11832,40 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::DeleteConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::DeleteConflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11579,16 +11907,25 @@ impl DeleteBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteBillingGroupError::Throttling(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBillingGroupError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11633,31 +11970,46 @@ impl DeleteCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(DeleteCACertificateError::CertificateState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::CertificateState(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteCACertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteCACertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCACertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCACertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCACertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCACertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11707,30 +12059,49 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(DeleteCertificateError::CertificateState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::CertificateState(err.msg),
+                    )
                 }
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteCertificateError::DeleteConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::DeleteConflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCertificateError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteCertificateError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11771,13 +12142,19 @@ impl DeleteDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDimensionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDimensionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDimensionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDimensionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11819,34 +12196,40 @@ impl DeleteDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainConfigurationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDomainConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11887,22 +12270,28 @@ impl DeleteDynamicThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDynamicThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDynamicThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDynamicThingGroupError::Throttling(err.msg),
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::VersionConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDynamicThingGroupError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11945,22 +12334,25 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::InvalidRequest(err.msg))
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidStateTransition(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobError::InvalidStateTransition(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12003,23 +12395,31 @@ impl DeleteJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobExecutionError::InvalidStateTransition(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobExecutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobExecutionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteJobExecutionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12057,17 +12457,22 @@ impl DeleteMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteMitigationActionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMitigationActionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMitigationActionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMitigationActionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMitigationActionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12111,25 +12516,40 @@ impl DeleteOTAUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOTAUpdateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOTAUpdateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOTAUpdateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOTAUpdateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteOTAUpdateError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteOTAUpdateError::Unauthorized(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::VersionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOTAUpdateError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12177,25 +12597,28 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeletePolicyError::DeleteConflict(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::DeleteConflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePolicyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeletePolicyError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeletePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12243,29 +12666,46 @@ impl DeletePolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::DeleteConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::DeleteConflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12313,34 +12753,40 @@ impl DeleteProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::DeleteConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningTemplateError::DeleteConflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningTemplateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningTemplateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12387,32 +12833,38 @@ impl DeleteProvisioningTemplateVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningTemplateVersionError::DeleteConflict(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningTemplateVersionError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningTemplateVersionError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningTemplateVersionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningTemplateVersionError::Throttling(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningTemplateVersionError::Unauthorized(err.msg),
                     )
                 }
@@ -12467,25 +12919,34 @@ impl DeleteRegistrationCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistrationCodeError::InternalFailure(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistrationCodeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistrationCodeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistrationCodeError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistrationCodeError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12531,25 +12992,40 @@ impl DeleteRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::DeleteConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoleAliasError::DeleteConflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoleAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoleAliasError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoleAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRoleAliasError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteRoleAliasError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteRoleAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12591,20 +13067,28 @@ impl DeleteScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledAuditError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledAuditError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledAuditError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduledAuditError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12643,22 +13127,28 @@ impl DeleteSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityProfileError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::VersionConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecurityProfileError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12703,25 +13193,28 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteStreamError::DeleteConflict(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::DeleteConflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteStreamError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteStreamError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteStreamError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12769,25 +13262,28 @@ impl DeleteThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteThingError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteThingError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteThingError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteThingError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteThingError::Unauthorized(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteThingError::VersionConflict(err.msg))
+                    return RusotoError::service(&res, DeleteThingError::VersionConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12829,16 +13325,25 @@ impl DeleteThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteThingGroupError::Throttling(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteThingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingGroupError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12881,22 +13386,34 @@ impl DeleteThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingTypeError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingTypeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingTypeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingTypeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingTypeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThingTypeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingTypeError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteThingTypeError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteThingTypeError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12939,21 +13456,28 @@ impl DeleteTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::ConflictingResourceUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTopicRuleError::ConflictingResourceUpdate(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteTopicRuleError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTopicRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTopicRuleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteTopicRuleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12997,27 +13521,34 @@ impl DeleteTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTopicRuleDestinationError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTopicRuleDestinationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTopicRuleDestinationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteTopicRuleDestinationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTopicRuleDestinationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTopicRuleDestinationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13059,15 +13590,19 @@ impl DeleteV2LoggingLevelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteV2LoggingLevelError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteV2LoggingLevelError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteV2LoggingLevelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteV2LoggingLevelError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteV2LoggingLevelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteV2LoggingLevelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13109,24 +13644,37 @@ impl DeprecateThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateThingTypeError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateThingTypeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateThingTypeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateThingTypeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeprecateThingTypeError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateThingTypeError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13165,12 +13713,14 @@ impl DescribeAccountAuditConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAccountAuditConfigurationError::InternalFailure(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAccountAuditConfigurationError::Throttling(err.msg),
                     )
                 }
@@ -13211,20 +13761,28 @@ impl DescribeAuditFindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditFindingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditFindingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditFindingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditFindingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13265,22 +13823,26 @@ impl DescribeAuditMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditMitigationActionsTaskError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditMitigationActionsTaskError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditMitigationActionsTaskError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditMitigationActionsTaskError::Throttling(err.msg),
                     )
                 }
@@ -13329,16 +13891,25 @@ impl DescribeAuditTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditTaskError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditTaskError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuditTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeAuditTaskError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13381,24 +13952,37 @@ impl DescribeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeAuthorizerError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAuthorizerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13439,20 +14023,28 @@ impl DescribeBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBillingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBillingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13495,30 +14087,40 @@ impl DescribeCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeCACertificateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCACertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeCACertificateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCACertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCACertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCACertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCACertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCACertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCACertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCACertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCACertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13563,26 +14165,40 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13627,34 +14243,40 @@ impl DescribeDefaultAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDefaultAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDefaultAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDefaultAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDefaultAuthorizerError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDefaultAuthorizerError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDefaultAuthorizerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13695,16 +14317,25 @@ impl DescribeDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDimensionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDimensionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDimensionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDimensionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDimensionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDimensionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13749,34 +14380,40 @@ impl DescribeDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainConfigurationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDomainConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDomainConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13819,16 +14456,22 @@ impl DescribeEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeEndpointError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEndpointError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeEndpointError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeEndpointError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeEndpointError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13865,14 +14508,16 @@ impl DescribeEventConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeEventConfigurationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventConfigurationsError::InternalFailure(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEventConfigurationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventConfigurationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13913,22 +14558,28 @@ impl DescribeIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIndexError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeIndexError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIndexError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeIndexError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIndexError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIndexError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeIndexError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13969,16 +14620,19 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeJobError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14017,20 +14671,28 @@ impl DescribeJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJobExecutionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14069,22 +14731,28 @@ impl DescribeMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMitigationActionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMitigationActionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMitigationActionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMitigationActionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14127,29 +14795,34 @@ impl DescribeProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeProvisioningTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProvisioningTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProvisioningTemplateError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProvisioningTemplateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeProvisioningTemplateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProvisioningTemplateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14195,27 +14868,32 @@ impl DescribeProvisioningTemplateVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateVersionError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateVersionError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateVersionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateVersionError::Throttling(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningTemplateVersionError::Unauthorized(err.msg),
                     )
                 }
@@ -14271,24 +14949,37 @@ impl DescribeRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRoleAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRoleAliasError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRoleAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRoleAliasError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeRoleAliasError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRoleAliasError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14329,22 +15020,28 @@ impl DescribeScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledAuditError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledAuditError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledAuditError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduledAuditError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14383,22 +15080,28 @@ impl DescribeSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSecurityProfileError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSecurityProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSecurityProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14441,22 +15144,31 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeStreamError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeStreamError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeStreamError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeStreamError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeStreamError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeStreamError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14501,22 +15213,28 @@ impl DescribeThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThingError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DescribeThingError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeThingError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeThingError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeThingError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeThingError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeThingError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14557,16 +15275,25 @@ impl DescribeThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThingGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeThingGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14609,29 +15336,34 @@ impl DescribeThingRegistrationTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeThingRegistrationTaskError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeThingRegistrationTaskError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeThingRegistrationTaskError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingRegistrationTaskError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingRegistrationTaskError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeThingRegistrationTaskError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingRegistrationTaskError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14679,24 +15411,37 @@ impl DescribeThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingTypeError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingTypeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThingTypeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingTypeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeThingTypeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingTypeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingTypeError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeThingTypeError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThingTypeError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14741,22 +15486,25 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachPolicyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::LimitExceeded(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetachPolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachPolicyError::Throttling(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DetachPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14801,30 +15549,40 @@ impl DetachPrincipalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachPrincipalPolicyError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachPrincipalPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachPrincipalPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachPrincipalPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPrincipalPolicyError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPrincipalPolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14865,22 +15623,28 @@ impl DetachSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachSecurityProfileError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachSecurityProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachSecurityProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14923,28 +15687,40 @@ impl DetachThingPrincipalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachThingPrincipalError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachThingPrincipalError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachThingPrincipalError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachThingPrincipalError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachThingPrincipalError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachThingPrincipalError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14987,21 +15763,28 @@ impl DisableTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(DisableTopicRuleError::ConflictingResourceUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableTopicRuleError::ConflictingResourceUpdate(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisableTopicRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, DisableTopicRuleError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisableTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableTopicRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DisableTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableTopicRuleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DisableTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DisableTopicRuleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15043,21 +15826,28 @@ impl EnableTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(EnableTopicRuleError::ConflictingResourceUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableTopicRuleError::ConflictingResourceUpdate(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableTopicRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, EnableTopicRuleError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(EnableTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableTopicRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(EnableTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableTopicRuleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(EnableTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, EnableTopicRuleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15107,31 +15897,43 @@ impl GetCardinalityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(GetCardinalityError::IndexNotReady(err.msg))
+                    return RusotoError::service(&res, GetCardinalityError::IndexNotReady(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetCardinalityError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCardinalityError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidAggregationException" => {
-                    return RusotoError::Service(GetCardinalityError::InvalidAggregation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCardinalityError::InvalidAggregation(err.msg),
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(GetCardinalityError::InvalidQuery(err.msg))
+                    return RusotoError::service(&res, GetCardinalityError::InvalidQuery(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetCardinalityError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetCardinalityError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCardinalityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCardinalityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetCardinalityError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCardinalityError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCardinalityError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetCardinalityError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetCardinalityError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetCardinalityError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15181,31 +15983,46 @@ impl GetEffectivePoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePoliciesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15249,27 +16066,34 @@ impl GetIndexingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIndexingConfigurationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIndexingConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIndexingConfigurationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIndexingConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIndexingConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15309,16 +16133,22 @@ impl GetJobDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetJobDocumentError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetJobDocumentError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobDocumentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobDocumentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetJobDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobDocumentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetJobDocumentError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetJobDocumentError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15355,15 +16185,19 @@ impl GetLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetLoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service(&res, GetLoggingOptionsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15405,22 +16239,25 @@ impl GetOTAUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetOTAUpdateError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetOTAUpdateError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetOTAUpdateError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetOTAUpdateError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOTAUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetOTAUpdateError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetOTAUpdateError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOTAUpdateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetOTAUpdateError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetOTAUpdateError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetOTAUpdateError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetOTAUpdateError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15471,31 +16308,43 @@ impl GetPercentilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(GetPercentilesError::IndexNotReady(err.msg))
+                    return RusotoError::service(&res, GetPercentilesError::IndexNotReady(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetPercentilesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPercentilesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidAggregationException" => {
-                    return RusotoError::Service(GetPercentilesError::InvalidAggregation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPercentilesError::InvalidAggregation(err.msg),
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(GetPercentilesError::InvalidQuery(err.msg))
+                    return RusotoError::service(&res, GetPercentilesError::InvalidQuery(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPercentilesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetPercentilesError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPercentilesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPercentilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPercentilesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPercentilesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPercentilesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetPercentilesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetPercentilesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetPercentilesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15543,22 +16392,22 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetPolicyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPolicyError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15603,22 +16452,34 @@ impl GetPolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetPolicyVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPolicyVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPolicyVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPolicyVersionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPolicyVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPolicyVersionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPolicyVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetPolicyVersionError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetPolicyVersionError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetPolicyVersionError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15661,21 +16522,34 @@ impl GetRegistrationCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegistrationCodeError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegistrationCodeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRegistrationCodeError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegistrationCodeError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegistrationCodeError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15725,31 +16599,40 @@ impl GetStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(GetStatisticsError::IndexNotReady(err.msg))
+                    return RusotoError::service(&res, GetStatisticsError::IndexNotReady(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetStatisticsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetStatisticsError::InternalFailure(err.msg))
                 }
                 "InvalidAggregationException" => {
-                    return RusotoError::Service(GetStatisticsError::InvalidAggregation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetStatisticsError::InvalidAggregation(err.msg),
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(GetStatisticsError::InvalidQuery(err.msg))
+                    return RusotoError::service(&res, GetStatisticsError::InvalidQuery(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetStatisticsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetStatisticsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetStatisticsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetStatisticsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetStatisticsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetStatisticsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetStatisticsError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetStatisticsError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStatisticsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetStatisticsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15793,16 +16676,19 @@ impl GetTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetTopicRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, GetTopicRuleError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetTopicRuleError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTopicRuleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, GetTopicRuleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15841,22 +16727,28 @@ impl GetTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTopicRuleDestinationError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTopicRuleDestinationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTopicRuleDestinationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTopicRuleDestinationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15893,15 +16785,19 @@ impl GetV2LoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetV2LoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service(&res, GetV2LoggingOptionsError::Internal(err.msg))
                 }
                 "NotConfiguredException" => {
-                    return RusotoError::Service(GetV2LoggingOptionsError::NotConfigured(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetV2LoggingOptionsError::NotConfigured(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetV2LoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetV2LoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15939,20 +16835,28 @@ impl ListActiveViolationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListActiveViolationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListActiveViolationsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListActiveViolationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListActiveViolationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListActiveViolationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListActiveViolationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListActiveViolationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListActiveViolationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15997,31 +16901,46 @@ impl ListAttachedPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAttachedPoliciesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16061,13 +16980,19 @@ impl ListAuditFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuditFindingsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAuditFindingsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuditFindingsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAuditFindingsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditFindingsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListAuditFindingsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16105,17 +17030,20 @@ impl ListAuditMitigationActionsExecutionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAuditMitigationActionsExecutionsError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAuditMitigationActionsExecutionsError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAuditMitigationActionsExecutionsError::Throttling(err.msg),
                     )
                 }
@@ -16161,19 +17089,22 @@ impl ListAuditMitigationActionsTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAuditMitigationActionsTasksError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAuditMitigationActionsTasksError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditMitigationActionsTasksError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAuditMitigationActionsTasksError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16213,13 +17144,16 @@ impl ListAuditTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuditTasksError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAuditTasksError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuditTasksError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListAuditTasksError::InvalidRequest(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditTasksError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListAuditTasksError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16259,19 +17193,28 @@ impl ListAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuthorizersError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAuthorizersError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuthorizersError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAuthorizersError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAuthorizersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAuthorizersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuthorizersError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListAuthorizersError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAuthorizersError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListAuthorizersError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16311,16 +17254,25 @@ impl ListBillingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBillingGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBillingGroupsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBillingGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBillingGroupsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBillingGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBillingGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListBillingGroupsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListBillingGroupsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16361,21 +17313,31 @@ impl ListCACertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCACertificatesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCACertificatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCACertificatesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCACertificatesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCACertificatesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCACertificatesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCACertificatesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListCACertificatesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListCACertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCACertificatesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16417,19 +17379,28 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCertificatesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCertificatesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCertificatesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListCertificatesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListCertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListCertificatesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16471,23 +17442,34 @@ impl ListCertificatesByCAError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesByCAError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesByCAError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesByCAError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesByCAError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCertificatesByCAError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16525,13 +17507,16 @@ impl ListDimensionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDimensionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDimensionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDimensionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDimensionsError::InvalidRequest(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDimensionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDimensionsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16571,27 +17556,34 @@ impl ListDomainConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainConfigurationsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainConfigurationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainConfigurationsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainConfigurationsError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainConfigurationsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16633,19 +17625,22 @@ impl ListIndicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIndicesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListIndicesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListIndicesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIndicesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIndicesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListIndicesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16685,22 +17680,28 @@ impl ListJobExecutionsForJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16739,24 +17740,28 @@ impl ListJobExecutionsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListJobExecutionsForThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForThingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobExecutionsForThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListJobExecutionsForThingError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobExecutionsForThingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobExecutionsForThingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16795,16 +17800,16 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListJobsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListJobsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListJobsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListJobsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16841,17 +17846,22 @@ impl ListMitigationActionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListMitigationActionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMitigationActionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListMitigationActionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMitigationActionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMitigationActionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMitigationActionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16891,19 +17901,25 @@ impl ListOTAUpdatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOTAUpdatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListOTAUpdatesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOTAUpdatesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListOTAUpdatesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListOTAUpdatesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -16945,27 +17961,34 @@ impl ListOutgoingCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingCertificatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingCertificatesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingCertificatesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingCertificatesError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOutgoingCertificatesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17007,19 +18030,22 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPoliciesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPoliciesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPoliciesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17063,28 +18089,40 @@ impl ListPolicyPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyPrincipalsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyPrincipalsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyPrincipalsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyPrincipalsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyPrincipalsError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyPrincipalsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17129,24 +18167,37 @@ impl ListPolicyVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyVersionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyVersionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListPolicyVersionsError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPolicyVersionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17191,30 +18242,40 @@ impl ListPrincipalPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalPoliciesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalPoliciesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalPoliciesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalPoliciesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalPoliciesError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalPoliciesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17259,26 +18320,40 @@ impl ListPrincipalThingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalThingsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalThingsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalThingsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalThingsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalThingsError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalThingsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17323,27 +18398,32 @@ impl ListProvisioningTemplateVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisioningTemplateVersionsError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisioningTemplateVersionsError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisioningTemplateVersionsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProvisioningTemplateVersionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningTemplateVersionsError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisioningTemplateVersionsError::Unauthorized(err.msg),
                     )
                 }
@@ -17393,24 +18473,28 @@ impl ListProvisioningTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningTemplatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningTemplatesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningTemplatesError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningTemplatesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17451,19 +18535,28 @@ impl ListRoleAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListRoleAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoleAliasesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRoleAliasesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoleAliasesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoleAliasesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRoleAliasesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRoleAliasesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListRoleAliasesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListRoleAliasesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListRoleAliasesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17501,13 +18594,22 @@ impl ListScheduledAuditsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListScheduledAuditsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListScheduledAuditsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListScheduledAuditsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListScheduledAuditsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListScheduledAuditsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListScheduledAuditsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17545,20 +18647,28 @@ impl ListSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17599,24 +18709,28 @@ impl ListSecurityProfilesForTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListSecurityProfilesForTargetError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListSecurityProfilesForTargetError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListSecurityProfilesForTargetError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesForTargetError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecurityProfilesForTargetError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17661,19 +18775,22 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListStreamsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStreamsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListStreamsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListStreamsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListStreamsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17713,18 +18830,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17769,31 +18896,46 @@ impl ListTargetsForPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17837,24 +18979,28 @@ impl ListTargetsForSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTargetsForSecurityProfileError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTargetsForSecurityProfileError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTargetsForSecurityProfileError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTargetsForSecurityProfileError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17895,13 +19041,22 @@ impl ListThingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingGroupsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingGroupsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17937,19 +19092,22 @@ impl ListThingGroupsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingGroupsForThingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingGroupsForThingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingGroupsForThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -17991,26 +19149,40 @@ impl ListThingPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingPrincipalsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingPrincipalsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingPrincipalsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingPrincipalsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingPrincipalsError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingPrincipalsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18053,22 +19225,26 @@ impl ListThingRegistrationTaskReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListThingRegistrationTaskReportsError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListThingRegistrationTaskReportsError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingRegistrationTaskReportsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingRegistrationTaskReportsError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListThingRegistrationTaskReportsError::Unauthorized(err.msg),
                     )
                 }
@@ -18117,24 +19293,28 @@ impl ListThingRegistrationTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingRegistrationTasksError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingRegistrationTasksError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingRegistrationTasksError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingRegistrationTasksError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18175,19 +19355,25 @@ impl ListThingTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingTypesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingTypesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListThingTypesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListThingTypesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingTypesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingTypesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListThingTypesError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingTypesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListThingTypesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18229,19 +19415,19 @@ impl ListThingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListThingsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListThingsError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListThingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListThingsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListThingsError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListThingsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18281,22 +19467,28 @@ impl ListThingsInBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInBillingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInBillingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18333,19 +19525,22 @@ impl ListThingsInThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThingsInThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18383,22 +19578,28 @@ impl ListTopicRuleDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTopicRuleDestinationsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicRuleDestinationsError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicRuleDestinationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicRuleDestinationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTopicRuleDestinationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTopicRuleDestinationsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicRuleDestinationsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18435,13 +19636,16 @@ impl ListTopicRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTopicRulesError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTopicRulesError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListTopicRulesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTopicRulesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTopicRulesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18479,18 +19683,25 @@ impl ListV2LoggingLevelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListV2LoggingLevelsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListV2LoggingLevelsError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotConfiguredException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::NotConfigured(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListV2LoggingLevelsError::NotConfigured(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListV2LoggingLevelsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18527,13 +19738,22 @@ impl ListViolationEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListViolationEventsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListViolationEventsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListViolationEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListViolationEventsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListViolationEventsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListViolationEventsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18581,43 +19801,58 @@ impl RegisterCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(RegisterCACertificateError::CertificateValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::CertificateValidation(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterCACertificateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterCACertificateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterCACertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::LimitExceeded(err.msg),
+                    )
                 }
                 "RegistrationCodeValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCACertificateError::RegistrationCodeValidation(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(RegisterCACertificateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegisterCACertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterCACertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCACertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18673,41 +19908,58 @@ impl RegisterCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateConflictException" => {
-                    return RusotoError::Service(RegisterCertificateError::CertificateConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::CertificateConflict(err.msg),
+                    )
                 }
                 "CertificateStateException" => {
-                    return RusotoError::Service(RegisterCertificateError::CertificateState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::CertificateState(err.msg),
+                    )
                 }
                 "CertificateValidationException" => {
-                    return RusotoError::Service(RegisterCertificateError::CertificateValidation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::CertificateValidation(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(RegisterCertificateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegisterCertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterCertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18761,44 +20013,52 @@ impl RegisterCertificateWithoutCAError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateWithoutCAError::CertificateState(err.msg),
                     )
                 }
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateWithoutCAError::CertificateValidation(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateWithoutCAError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterCertificateWithoutCAError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateWithoutCAError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateWithoutCAError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterCertificateWithoutCAError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterCertificateWithoutCAError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateWithoutCAError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterCertificateWithoutCAError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterCertificateWithoutCAError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18855,29 +20115,34 @@ impl RegisterThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(RegisterThingError::ConflictingResourceUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterThingError::ConflictingResourceUpdate(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterThingError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, RegisterThingError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterThingError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, RegisterThingError::InvalidRequest(err.msg))
                 }
                 "ResourceRegistrationFailureException" => {
-                    return RusotoError::Service(RegisterThingError::ResourceRegistrationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterThingError::ResourceRegistrationFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegisterThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterThingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterThingError::Throttling(err.msg))
+                    return RusotoError::service(&res, RegisterThingError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterThingError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, RegisterThingError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -18925,39 +20190,46 @@ impl RejectCertificateTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectCertificateTransferError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectCertificateTransferError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectCertificateTransferError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectCertificateTransferError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectCertificateTransferError::Throttling(err.msg),
+                    )
                 }
                 "TransferAlreadyCompletedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectCertificateTransferError::TransferAlreadyCompleted(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectCertificateTransferError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19003,24 +20275,28 @@ impl RemoveThingFromBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RemoveThingFromBillingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveThingFromBillingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveThingFromBillingGroupError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemoveThingFromBillingGroupError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromBillingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19059,24 +20335,28 @@ impl RemoveThingFromThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveThingFromThingGroupError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19119,24 +20399,31 @@ impl ReplaceTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::ConflictingResourceUpdate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ReplaceTopicRuleError::ConflictingResourceUpdate(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::Internal(err.msg))
+                    return RusotoError::service(&res, ReplaceTopicRuleError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReplaceTopicRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReplaceTopicRuleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "SqlParseException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::SqlParse(err.msg))
+                    return RusotoError::service(&res, ReplaceTopicRuleError::SqlParse(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ReplaceTopicRuleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19185,28 +20472,31 @@ impl SearchIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(SearchIndexError::IndexNotReady(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::IndexNotReady(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchIndexError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::InternalFailure(err.msg))
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(SearchIndexError::InvalidQuery(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::InvalidQuery(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchIndexError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SearchIndexError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchIndexError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchIndexError::Throttling(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchIndexError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, SearchIndexError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19255,33 +20545,46 @@ impl SetDefaultAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultAuthorizerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19327,32 +20630,40 @@ impl SetDefaultPolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultPolicyVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultPolicyVersionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultPolicyVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultPolicyVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultPolicyVersionError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetDefaultPolicyVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19391,15 +20702,19 @@ impl SetLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(SetLoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service(&res, SetLoggingOptionsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetLoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetLoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19437,18 +20752,25 @@ impl SetV2LoggingLevelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::Internal(err.msg))
+                    return RusotoError::service(&res, SetV2LoggingLevelError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetV2LoggingLevelError::InvalidRequest(err.msg),
+                    )
                 }
                 "NotConfiguredException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::NotConfigured(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetV2LoggingLevelError::NotConfigured(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetV2LoggingLevelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19485,15 +20807,19 @@ impl SetV2LoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(SetV2LoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service(&res, SetV2LoggingOptionsError::Internal(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetV2LoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetV2LoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetV2LoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetV2LoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19535,29 +20861,34 @@ impl StartAuditMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAuditMitigationActionsTaskError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAuditMitigationActionsTaskError::InvalidRequest(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAuditMitigationActionsTaskError::LimitExceeded(err.msg),
                     )
                 }
                 "TaskAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAuditMitigationActionsTaskError::TaskAlreadyExists(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartAuditMitigationActionsTaskError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAuditMitigationActionsTaskError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19605,22 +20936,28 @@ impl StartOnDemandAuditTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOnDemandAuditTaskError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOnDemandAuditTaskError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartOnDemandAuditTaskError::LimitExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartOnDemandAuditTaskError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19661,24 +20998,28 @@ impl StartThingRegistrationTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartThingRegistrationTaskError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartThingRegistrationTaskError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartThingRegistrationTaskError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartThingRegistrationTaskError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19719,29 +21060,34 @@ impl StopThingRegistrationTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopThingRegistrationTaskError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopThingRegistrationTaskError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopThingRegistrationTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopThingRegistrationTaskError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopThingRegistrationTaskError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19783,19 +21129,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19841,27 +21187,43 @@ impl TestAuthorizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TestAuthorizationError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestAuthorizationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TestAuthorizationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestAuthorizationError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TestAuthorizationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestAuthorizationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestAuthorizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestAuthorizationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestAuthorizationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestAuthorizationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TestAuthorizationError::Throttling(err.msg))
+                    return RusotoError::service(&res, TestAuthorizationError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestAuthorizationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestAuthorizationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19909,33 +21271,46 @@ impl TestInvokeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "InvalidResponseException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::InvalidResponse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::InvalidResponse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestInvokeAuthorizerError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -19985,36 +21360,52 @@ impl TransferCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(TransferCertificateError::CertificateState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::CertificateState(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TransferCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TransferCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransferCertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TransferCertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TransferCertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::Throttling(err.msg),
+                    )
                 }
                 "TransferConflictException" => {
-                    return RusotoError::Service(TransferCertificateError::TransferConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::TransferConflict(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TransferCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20057,16 +21448,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20105,19 +21499,22 @@ impl UpdateAccountAuditConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAccountAuditConfigurationError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAccountAuditConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAccountAuditConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAccountAuditConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20165,25 +21562,40 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuthorizerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateAuthorizerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20227,19 +21639,31 @@ impl UpdateBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBillingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBillingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBillingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateBillingGroupError::Throttling(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBillingGroupError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20283,26 +21707,40 @@ impl UpdateCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateCACertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCACertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateCACertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCACertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCACertificateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCACertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateCACertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCACertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateCACertificateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCACertificateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCACertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20349,27 +21787,43 @@ impl UpdateCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(UpdateCertificateError::CertificateState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateError::CertificateState(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateCertificateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateCertificateError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateCertificateError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCertificateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20411,16 +21865,25 @@ impl UpdateDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDimensionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDimensionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDimensionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDimensionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDimensionError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDimensionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20465,39 +21928,46 @@ impl UpdateDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDomainConfigurationError::CertificateValidation(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainConfigurationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDomainConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20545,32 +22015,40 @@ impl UpdateDynamicThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDynamicThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::InvalidQuery(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDynamicThingGroupError::InvalidQuery(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDynamicThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDynamicThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDynamicThingGroupError::Throttling(err.msg),
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::VersionConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDynamicThingGroupError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20609,19 +22087,22 @@ impl UpdateEventConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateEventConfigurationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventConfigurationsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateEventConfigurationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventConfigurationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateEventConfigurationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventConfigurationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20663,29 +22144,34 @@ impl UpdateIndexingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIndexingConfigurationError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIndexingConfigurationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIndexingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIndexingConfigurationError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIndexingConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20727,16 +22213,16 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20775,22 +22261,28 @@ impl UpdateMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMitigationActionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMitigationActionError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMitigationActionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMitigationActionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20833,29 +22325,34 @@ impl UpdateProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateProvisioningTemplateError::ConflictingResourceUpdate(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisioningTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisioningTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisioningTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisioningTemplateError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20901,22 +22398,34 @@ impl UpdateRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoleAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoleAliasError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoleAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRoleAliasError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateRoleAliasError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateRoleAliasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -20957,20 +22466,28 @@ impl UpdateScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScheduledAuditError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScheduledAuditError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScheduledAuditError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateScheduledAuditError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21011,27 +22528,34 @@ impl UpdateSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecurityProfileError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecurityProfileError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecurityProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecurityProfileError::Throttling(err.msg),
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::VersionConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecurityProfileError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21075,22 +22599,25 @@ impl UpdateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateStreamError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStreamError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateStreamError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateStreamError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21137,25 +22664,28 @@ impl UpdateThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateThingError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateThingError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateThingError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateThingError::Throttling(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateThingError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateThingError::Unauthorized(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateThingError::VersionConflict(err.msg))
+                    return RusotoError::service(&res, UpdateThingError::VersionConflict(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21199,19 +22729,31 @@ impl UpdateThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThingGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateThingGroupError::Throttling(err.msg))
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateThingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupError::VersionConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21251,24 +22793,28 @@ impl UpdateThingGroupsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupsForThingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupsForThingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupsForThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThingGroupsForThingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21311,27 +22857,34 @@ impl UpdateTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTopicRuleDestinationError::Internal(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateTopicRuleDestinationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTopicRuleDestinationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTopicRuleDestinationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateTopicRuleDestinationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTopicRuleDestinationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -21375,19 +22928,22 @@ impl ValidateSecurityProfileBehaviorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ValidateSecurityProfileBehaviorsError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ValidateSecurityProfileBehaviorsError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ValidateSecurityProfileBehaviorsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateSecurityProfileBehaviorsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -22710,6 +24266,7 @@ impl IotClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -22725,6 +24282,7 @@ impl IotClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotClient {
         IotClient { client, region }
     }
@@ -22744,6 +24302,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AcceptCertificateTransfer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22779,6 +24340,9 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups/addThingToBillingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AddThingToBillingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22811,6 +24375,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/addThingToThingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AddThingToThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22843,6 +24410,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/targets", job_id = input.job_id);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AssociateTargetsWithJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22878,6 +24448,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AttachPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22912,6 +24485,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AttachPrincipalPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22946,6 +24522,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AttachSecurityProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -22986,6 +24565,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("AttachThingPrincipal");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23024,6 +24606,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CancelAuditMitigationActionsTask");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23056,6 +24641,9 @@ impl Iot for IotClient {
         let request_uri = format!("/audit/tasks/{task_id}/cancel", task_id = input.task_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CancelAuditTask");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23089,6 +24677,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CancelCertificateTransfer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23118,6 +24709,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/cancel", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23160,6 +24754,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CancelJobExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23196,6 +24793,9 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ClearDefaultAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23230,6 +24830,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ConfirmTopicRuleDestination");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23263,6 +24866,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23298,6 +24904,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateBillingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23330,6 +24939,9 @@ impl Iot for IotClient {
         let request_uri = "/certificates";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateCertificateFromCsr");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23368,6 +24980,9 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateDimension");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23404,6 +25019,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateDomainConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23439,6 +25057,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateDynamicThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23471,6 +25092,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23503,6 +25127,9 @@ impl Iot for IotClient {
         let request_uri = "/keys-and-certificate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateKeysAndCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23542,6 +25169,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateMitigationAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23577,6 +25207,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateOTAUpdate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23609,6 +25242,9 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreatePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23644,6 +25280,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreatePolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23685,6 +25324,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateProvisioningClaim");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23716,6 +25358,9 @@ impl Iot for IotClient {
         let request_uri = "/provisioning-templates";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateProvisioningTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23754,6 +25399,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateProvisioningTemplateVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23794,6 +25442,9 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateRoleAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23829,6 +25480,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateScheduledAudit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23864,6 +25518,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateSecurityProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23896,6 +25553,9 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23928,6 +25588,9 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateThing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23963,6 +25626,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -23998,6 +25664,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateThingType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24030,6 +25699,9 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateTopicRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24063,6 +25735,9 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("CreateTopicRuleDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24098,6 +25773,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteAccountAuditConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24139,6 +25817,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24172,6 +25853,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteBillingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24211,6 +25895,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteCACertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24244,6 +25931,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24279,6 +25969,9 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteDimension");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24313,6 +26006,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteDomainConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24346,6 +26042,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteDynamicThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24379,6 +26078,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24419,6 +26121,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteJobExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24457,6 +26162,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteMitigationAction");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24490,6 +26198,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteOTAUpdate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24529,6 +26240,9 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeletePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24562,6 +26276,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeletePolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24595,6 +26312,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteProvisioningTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24632,6 +26352,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteProvisioningTemplateVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24663,6 +26386,9 @@ impl Iot for IotClient {
         let request_uri = "/registrationcode";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteRegistrationCode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24693,6 +26419,9 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteRoleAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24726,6 +26455,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteScheduledAudit");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24759,6 +26491,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteSecurityProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24795,6 +26530,9 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteStream");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24825,6 +26563,9 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteThing");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24864,6 +26605,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24903,6 +26647,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteThingType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24933,6 +26680,9 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteTopicRule");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24963,6 +26713,9 @@ impl Iot for IotClient {
         let request_uri = format!("/destinations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteTopicRuleDestination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24993,6 +26746,9 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeleteV2LoggingLevel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25030,6 +26786,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DeprecateThingType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25064,6 +26823,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeAccountAuditConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25099,6 +26861,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeAuditFinding");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25135,6 +26900,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeAuditMitigationActionsTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25167,6 +26935,9 @@ impl Iot for IotClient {
         let request_uri = format!("/audit/tasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeAuditTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25200,6 +26971,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeAuthorizer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25233,6 +27007,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeBillingGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25266,6 +27043,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeCACertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25299,6 +27079,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeCertificate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25329,6 +27112,9 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeDefaultAuthorizer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25359,6 +27145,9 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeDimension");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25393,6 +27182,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeDomainConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25423,6 +27215,9 @@ impl Iot for IotClient {
         let request_uri = "/endpoint";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25459,6 +27254,9 @@ impl Iot for IotClient {
         let request_uri = "/event-configurations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeEventConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25489,6 +27287,9 @@ impl Iot for IotClient {
         let request_uri = format!("/indices/{index_name}", index_name = input.index_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeIndex");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25519,6 +27320,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25553,6 +27357,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeJobExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25592,6 +27399,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeMitigationAction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25626,6 +27436,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeProvisioningTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25663,6 +27476,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeProvisioningTemplateVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25695,6 +27511,9 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeRoleAlias");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25728,6 +27547,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeScheduledAudit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25761,6 +27583,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeSecurityProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25791,6 +27616,9 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25821,6 +27649,9 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeThing");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25854,6 +27685,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeThingGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25890,6 +27724,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeThingRegistrationTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25923,6 +27760,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DescribeThingType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25956,6 +27796,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DetachPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25990,6 +27833,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DetachPrincipalPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26024,6 +27870,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DetachSecurityProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26064,6 +27913,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DetachThingPrincipal");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26096,6 +27948,9 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}/disable", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("DisableTopicRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26125,6 +27980,9 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}/enable", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("EnableTopicRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26154,6 +28012,9 @@ impl Iot for IotClient {
         let request_uri = "/indices/cardinality";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetCardinality");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26186,6 +28047,9 @@ impl Iot for IotClient {
         let request_uri = "/effective-policies";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetEffectivePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26223,6 +28087,9 @@ impl Iot for IotClient {
         let request_uri = "/indexing/config";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetIndexingConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26253,6 +28120,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/job-document", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetJobDocument");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26282,6 +28152,9 @@ impl Iot for IotClient {
         let request_uri = "/loggingOptions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetLoggingOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26315,6 +28188,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetOTAUpdate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26345,6 +28221,9 @@ impl Iot for IotClient {
         let request_uri = "/indices/percentiles";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetPercentiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26377,6 +28256,9 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26411,6 +28293,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetPolicyVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26440,6 +28325,9 @@ impl Iot for IotClient {
         let request_uri = "/registrationcode";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetRegistrationCode");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26470,6 +28358,9 @@ impl Iot for IotClient {
         let request_uri = "/indices/statistics";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetStatistics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26502,6 +28393,9 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetTopicRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26532,6 +28426,9 @@ impl Iot for IotClient {
         let request_uri = format!("/destinations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetTopicRuleDestination");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26561,6 +28458,9 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingOptions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("GetV2LoggingOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26591,6 +28491,9 @@ impl Iot for IotClient {
         let request_uri = "/active-violations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListActiveViolations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26636,6 +28539,9 @@ impl Iot for IotClient {
         let request_uri = format!("/attached-policies/{target}", target = input.target);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListAttachedPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26678,6 +28584,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/findings";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListAuditFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26713,6 +28622,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/mitigationactions/executions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListAuditMitigationActionsExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26762,6 +28674,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/mitigationactions/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListAuditMitigationActionsTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26814,6 +28729,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListAuditTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26861,6 +28779,9 @@ impl Iot for IotClient {
         let request_uri = "/authorizers/";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListAuthorizers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26906,6 +28827,9 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListBillingGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26948,6 +28872,9 @@ impl Iot for IotClient {
         let request_uri = "/cacertificates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListCACertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26990,6 +28917,9 @@ impl Iot for IotClient {
         let request_uri = "/certificates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27035,6 +28965,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListCertificatesByCA");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27077,6 +29010,9 @@ impl Iot for IotClient {
         let request_uri = "/dimensions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListDimensions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27116,6 +29052,9 @@ impl Iot for IotClient {
         let request_uri = "/domainConfigurations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListDomainConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27158,6 +29097,9 @@ impl Iot for IotClient {
         let request_uri = "/indices";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListIndices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27197,6 +29139,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/things", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListJobExecutionsForJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27240,6 +29185,9 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListJobExecutionsForThing");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27282,6 +29230,9 @@ impl Iot for IotClient {
         let request_uri = "/jobs";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27333,6 +29284,9 @@ impl Iot for IotClient {
         let request_uri = "/mitigationactions/actions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListMitigationActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27375,6 +29329,9 @@ impl Iot for IotClient {
         let request_uri = "/otaUpdates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListOTAUpdates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27417,6 +29374,9 @@ impl Iot for IotClient {
         let request_uri = "/certificates-out-going";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListOutgoingCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27459,6 +29419,9 @@ impl Iot for IotClient {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27501,6 +29464,9 @@ impl Iot for IotClient {
         let request_uri = "/policy-principals";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListPolicyPrincipals");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27547,6 +29513,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListPolicyVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27577,6 +29546,9 @@ impl Iot for IotClient {
         let request_uri = "/principal-policies";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListPrincipalPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27620,6 +29592,9 @@ impl Iot for IotClient {
         let request_uri = "/principals/things";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListPrincipalThings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27666,6 +29641,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListProvisioningTemplateVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27708,6 +29686,9 @@ impl Iot for IotClient {
         let request_uri = "/provisioning-templates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListProvisioningTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27747,6 +29728,9 @@ impl Iot for IotClient {
         let request_uri = "/role-aliases";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListRoleAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27789,6 +29773,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/scheduledaudits";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListScheduledAudits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27828,6 +29815,9 @@ impl Iot for IotClient {
         let request_uri = "/security-profiles";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListSecurityProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27873,6 +29863,9 @@ impl Iot for IotClient {
         let request_uri = "/security-profiles-for-target";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListSecurityProfilesForTarget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27919,6 +29912,9 @@ impl Iot for IotClient {
         let request_uri = "/streams";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27961,6 +29957,9 @@ impl Iot for IotClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28001,6 +30000,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListTargetsForPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28046,6 +30048,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListTargetsForSecurityProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28085,6 +30090,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28136,6 +30144,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingGroupsForThing");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28178,6 +30189,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingPrincipals");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28214,6 +30228,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingRegistrationTaskReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28257,6 +30274,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-registration-tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingRegistrationTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28299,6 +30319,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-types";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28341,6 +30364,9 @@ impl Iot for IotClient {
         let request_uri = "/things";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28392,6 +30418,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingsInBillingGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28434,6 +30463,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListThingsInThingGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28477,6 +30509,9 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListTopicRuleDestinations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28516,6 +30551,9 @@ impl Iot for IotClient {
         let request_uri = "/rules";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListTopicRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28561,6 +30599,9 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListV2LoggingLevels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28603,6 +30644,9 @@ impl Iot for IotClient {
         let request_uri = "/violation-events";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ListViolationEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28650,6 +30694,9 @@ impl Iot for IotClient {
         let request_uri = "/cacertificate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RegisterCACertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28691,6 +30738,9 @@ impl Iot for IotClient {
         let request_uri = "/certificate/register";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RegisterCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28724,6 +30774,9 @@ impl Iot for IotClient {
         let request_uri = "/certificate/register-no-ca";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RegisterCertificateWithoutCA");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28756,6 +30809,9 @@ impl Iot for IotClient {
         let request_uri = "/things";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RegisterThing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28791,6 +30847,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RejectCertificateTransfer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28823,6 +30882,9 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups/removeThingFromBillingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RemoveThingFromBillingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28856,6 +30918,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/removeThingFromThingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("RemoveThingFromThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28888,6 +30953,9 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ReplaceTopicRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28919,6 +30987,9 @@ impl Iot for IotClient {
         let request_uri = "/indices/search";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SearchIndex");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28951,6 +31022,9 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SetDefaultAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28987,6 +31061,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SetDefaultPolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29016,6 +31093,9 @@ impl Iot for IotClient {
         let request_uri = "/loggingOptions";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SetLoggingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29047,6 +31127,9 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SetV2LoggingLevel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29078,6 +31161,9 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingOptions";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("SetV2LoggingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29115,6 +31201,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("StartAuditMitigationActionsTask");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29149,6 +31238,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/tasks";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("StartOnDemandAuditTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29182,6 +31274,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-registration-tasks";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("StartThingRegistrationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29218,6 +31313,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("StopThingRegistrationTask");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29248,6 +31346,9 @@ impl Iot for IotClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29280,6 +31381,9 @@ impl Iot for IotClient {
         let request_uri = "/test-authorization";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("TestAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29321,6 +31425,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("TestInvokeAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29356,6 +31463,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("TransferCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29392,6 +31502,9 @@ impl Iot for IotClient {
         let request_uri = "/untag";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29427,6 +31540,9 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateAccountAuditConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29464,6 +31580,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateAuthorizer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29499,6 +31618,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateBillingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29534,6 +31656,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateCACertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29577,6 +31702,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29610,6 +31738,9 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateDimension");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29646,6 +31777,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateDomainConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29681,6 +31815,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateDynamicThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29714,6 +31851,9 @@ impl Iot for IotClient {
         let request_uri = "/event-configurations";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateEventConfigurations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29747,6 +31887,9 @@ impl Iot for IotClient {
         let request_uri = "/indexing/config";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateIndexingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29776,6 +31919,9 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29810,6 +31956,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateMitigationAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29846,6 +31995,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateProvisioningTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29878,6 +32030,9 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateRoleAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29913,6 +32068,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateScheduledAudit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29948,6 +32106,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateSecurityProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29986,6 +32147,9 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateStream");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30018,6 +32182,9 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateThing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30053,6 +32220,9 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateThingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30086,6 +32256,9 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/updateThingGroupsForThing";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateThingGroupsForThing");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30119,6 +32292,9 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("UpdateTopicRuleDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30154,6 +32330,9 @@ impl Iot for IotClient {
         let request_uri = "/security-profile-behaviors/validate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation_name("ValidateSecurityProfileBehaviors");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30179,3 +32358,2318 @@ impl Iot for IotClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotBlockingClient {
+    client: IotClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotBlockingClient {
+        IotBlockingClient {
+            client: IotClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts a pending certificate transfer. The default state of the certificate is INACTIVE.</p> <p>To check for pending certificate transfers, call <a>ListCertificates</a> to enumerate your certificates.</p>
+    ///
+    /// See `Iot::accept_certificate_transfer`.
+    pub fn accept_certificate_transfer(
+        &self,
+        input: AcceptCertificateTransferRequest,
+    ) -> Result<(), RusotoError<AcceptCertificateTransferError>> {
+        self.runtime
+            .block_on(self.client.accept_certificate_transfer(input))
+    }
+
+    /// <p>Adds a thing to a billing group.</p>
+    ///
+    /// See `Iot::add_thing_to_billing_group`.
+    pub fn add_thing_to_billing_group(
+        &self,
+        input: AddThingToBillingGroupRequest,
+    ) -> Result<AddThingToBillingGroupResponse, RusotoError<AddThingToBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.add_thing_to_billing_group(input))
+    }
+
+    /// <p>Adds a thing to a thing group.</p>
+    ///
+    /// See `Iot::add_thing_to_thing_group`.
+    pub fn add_thing_to_thing_group(
+        &self,
+        input: AddThingToThingGroupRequest,
+    ) -> Result<AddThingToThingGroupResponse, RusotoError<AddThingToThingGroupError>> {
+        self.runtime
+            .block_on(self.client.add_thing_to_thing_group(input))
+    }
+
+    /// <p><p>Associates a group with a continuous job. The following criteria must be met: </p> <ul> <li> <p>The job must have been created with the <code>targetSelection</code> field set to &quot;CONTINUOUS&quot;.</p> </li> <li> <p>The job status must currently be &quot;IN_PROGRESS&quot;.</p> </li> <li> <p>The total number of targets associated with a job must not exceed 100.</p> </li> </ul></p>
+    ///
+    /// See `Iot::associate_targets_with_job`.
+    pub fn associate_targets_with_job(
+        &self,
+        input: AssociateTargetsWithJobRequest,
+    ) -> Result<AssociateTargetsWithJobResponse, RusotoError<AssociateTargetsWithJobError>> {
+        self.runtime
+            .block_on(self.client.associate_targets_with_job(input))
+    }
+
+    /// <p>Attaches a policy to the specified target.</p>
+    ///
+    /// See `Iot::attach_policy`.
+    pub fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPolicyError>> {
+        self.runtime.block_on(self.client.attach_policy(input))
+    }
+
+    /// <p>Attaches the specified policy to the specified principal (certificate or other credential).</p> <p> <b>Note:</b> This API is deprecated. Please use <a>AttachPolicy</a> instead.</p>
+    ///
+    /// See `Iot::attach_principal_policy`.
+    pub fn attach_principal_policy(
+        &self,
+        input: AttachPrincipalPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPrincipalPolicyError>> {
+        self.runtime
+            .block_on(self.client.attach_principal_policy(input))
+    }
+
+    /// <p>Associates a Device Defender security profile with a thing group or this account. Each thing group or account can have up to five security profiles associated with it.</p>
+    ///
+    /// See `Iot::attach_security_profile`.
+    pub fn attach_security_profile(
+        &self,
+        input: AttachSecurityProfileRequest,
+    ) -> Result<AttachSecurityProfileResponse, RusotoError<AttachSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.attach_security_profile(input))
+    }
+
+    /// <p>Attaches the specified principal to the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p>
+    ///
+    /// See `Iot::attach_thing_principal`.
+    pub fn attach_thing_principal(
+        &self,
+        input: AttachThingPrincipalRequest,
+    ) -> Result<AttachThingPrincipalResponse, RusotoError<AttachThingPrincipalError>> {
+        self.runtime
+            .block_on(self.client.attach_thing_principal(input))
+    }
+
+    /// <p>Cancels a mitigation action task that is in progress. If the task is not in progress, an InvalidRequestException occurs.</p>
+    ///
+    /// See `Iot::cancel_audit_mitigation_actions_task`.
+    pub fn cancel_audit_mitigation_actions_task(
+        &self,
+        input: CancelAuditMitigationActionsTaskRequest,
+    ) -> Result<
+        CancelAuditMitigationActionsTaskResponse,
+        RusotoError<CancelAuditMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_audit_mitigation_actions_task(input))
+    }
+
+    /// <p>Cancels an audit that is in progress. The audit can be either scheduled or on-demand. If the audit is not in progress, an "InvalidRequestException" occurs.</p>
+    ///
+    /// See `Iot::cancel_audit_task`.
+    pub fn cancel_audit_task(
+        &self,
+        input: CancelAuditTaskRequest,
+    ) -> Result<CancelAuditTaskResponse, RusotoError<CancelAuditTaskError>> {
+        self.runtime.block_on(self.client.cancel_audit_task(input))
+    }
+
+    /// <p>Cancels a pending transfer for the specified certificate.</p> <p> <b>Note</b> Only the transfer source account can use this operation to cancel a transfer. (Transfer destinations can use <a>RejectCertificateTransfer</a> instead.) After transfer, AWS IoT returns the certificate to the source account in the INACTIVE state. After the destination account has accepted the transfer, the transfer cannot be cancelled.</p> <p>After a certificate transfer is cancelled, the status of the certificate changes from PENDING_TRANSFER to INACTIVE.</p>
+    ///
+    /// See `Iot::cancel_certificate_transfer`.
+    pub fn cancel_certificate_transfer(
+        &self,
+        input: CancelCertificateTransferRequest,
+    ) -> Result<(), RusotoError<CancelCertificateTransferError>> {
+        self.runtime
+            .block_on(self.client.cancel_certificate_transfer(input))
+    }
+
+    /// <p>Cancels a job.</p>
+    ///
+    /// See `Iot::cancel_job`.
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p>Cancels the execution of a job for a given thing.</p>
+    ///
+    /// See `Iot::cancel_job_execution`.
+    pub fn cancel_job_execution(
+        &self,
+        input: CancelJobExecutionRequest,
+    ) -> Result<(), RusotoError<CancelJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.cancel_job_execution(input))
+    }
+
+    /// <p>Clears the default authorizer.</p>
+    ///
+    /// See `Iot::clear_default_authorizer`.
+    pub fn clear_default_authorizer(
+        &self,
+    ) -> Result<ClearDefaultAuthorizerResponse, RusotoError<ClearDefaultAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.clear_default_authorizer())
+    }
+
+    /// <p>Confirms a topic rule destination. When you create a rule requiring a destination, AWS IoT sends a confirmation message to the endpoint or base address you specify. The message includes a token which you pass back when calling <code>ConfirmTopicRuleDestination</code> to confirm that you own or have access to the endpoint.</p>
+    ///
+    /// See `Iot::confirm_topic_rule_destination`.
+    pub fn confirm_topic_rule_destination(
+        &self,
+        input: ConfirmTopicRuleDestinationRequest,
+    ) -> Result<ConfirmTopicRuleDestinationResponse, RusotoError<ConfirmTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.confirm_topic_rule_destination(input))
+    }
+
+    /// <p>Creates an authorizer.</p>
+    ///
+    /// See `Iot::create_authorizer`.
+    pub fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<CreateAuthorizerResponse, RusotoError<CreateAuthorizerError>> {
+        self.runtime.block_on(self.client.create_authorizer(input))
+    }
+
+    /// <p>Creates a billing group.</p>
+    ///
+    /// See `Iot::create_billing_group`.
+    pub fn create_billing_group(
+        &self,
+        input: CreateBillingGroupRequest,
+    ) -> Result<CreateBillingGroupResponse, RusotoError<CreateBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_billing_group(input))
+    }
+
+    /// <p>Creates an X.509 certificate using the specified certificate signing request.</p> <p> <b>Note:</b> The CSR must include a public key that is either an RSA key with a length of at least 2048 bits or an ECC key from NIST P-256 or NIST P-384 curves. </p> <p> <b>Note:</b> Reusing the same certificate signing request (CSR) results in a distinct certificate.</p> <p>You can create multiple certificates in a batch by creating a directory, copying multiple .csr files into that directory, and then specifying that directory on the command line. The following commands show how to create a batch of certificates given a batch of CSRs.</p> <p>Assuming a set of CSRs are located inside of the directory my-csr-directory:</p> <p>On Linux and OS X, the command is:</p> <p>$ ls my-csr-directory/ | xargs -I {} aws iot create-certificate-from-csr --certificate-signing-request file://my-csr-directory/{}</p> <p>This command lists all of the CSRs in my-csr-directory and pipes each CSR file name to the aws iot create-certificate-from-csr AWS CLI command to create a certificate for the corresponding CSR.</p> <p>The aws iot create-certificate-from-csr part of the command can also be run in parallel to speed up the certificate creation process:</p> <p>$ ls my-csr-directory/ | xargs -P 10 -I {} aws iot create-certificate-from-csr --certificate-signing-request file://my-csr-directory/{}</p> <p>On Windows PowerShell, the command to create certificates for all CSRs in my-csr-directory is:</p> <p>&gt; ls -Name my-csr-directory | %{aws iot create-certificate-from-csr --certificate-signing-request file://my-csr-directory/$_}</p> <p>On a Windows command prompt, the command to create certificates for all CSRs in my-csr-directory is:</p> <p>&gt; forfiles /p my-csr-directory /c "cmd /c aws iot create-certificate-from-csr --certificate-signing-request file://@path"</p>
+    ///
+    /// See `Iot::create_certificate_from_csr`.
+    pub fn create_certificate_from_csr(
+        &self,
+        input: CreateCertificateFromCsrRequest,
+    ) -> Result<CreateCertificateFromCsrResponse, RusotoError<CreateCertificateFromCsrError>> {
+        self.runtime
+            .block_on(self.client.create_certificate_from_csr(input))
+    }
+
+    /// <p>Create a dimension that you can use to limit the scope of a metric used in a security profile for AWS IoT Device Defender. For example, using a <code>TOPIC_FILTER</code> dimension, you can narrow down the scope of the metric only to MQTT topics whose name match the pattern specified in the dimension.</p>
+    ///
+    /// See `Iot::create_dimension`.
+    pub fn create_dimension(
+        &self,
+        input: CreateDimensionRequest,
+    ) -> Result<CreateDimensionResponse, RusotoError<CreateDimensionError>> {
+        self.runtime.block_on(self.client.create_dimension(input))
+    }
+
+    /// <p><p>Creates a domain configuration.</p> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
+    ///
+    /// See `Iot::create_domain_configuration`.
+    pub fn create_domain_configuration(
+        &self,
+        input: CreateDomainConfigurationRequest,
+    ) -> Result<CreateDomainConfigurationResponse, RusotoError<CreateDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_domain_configuration(input))
+    }
+
+    /// <p>Creates a dynamic thing group.</p>
+    ///
+    /// See `Iot::create_dynamic_thing_group`.
+    pub fn create_dynamic_thing_group(
+        &self,
+        input: CreateDynamicThingGroupRequest,
+    ) -> Result<CreateDynamicThingGroupResponse, RusotoError<CreateDynamicThingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_dynamic_thing_group(input))
+    }
+
+    /// <p>Creates a job.</p>
+    ///
+    /// See `Iot::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>Creates a 2048-bit RSA key pair and issues an X.509 certificate using the issued public key. You can also call <code>CreateKeysAndCertificate</code> over MQTT from a device, for more information, see <a href="https://docs.aws.amazon.com/iot/latest/developerguide/provision-wo-cert.html#provision-mqtt-api">Provisioning MQTT API</a>.</p> <p> <b>Note</b> This is the only time AWS IoT issues the private key for this certificate, so it is important to keep it in a secure location.</p>
+    ///
+    /// See `Iot::create_keys_and_certificate`.
+    pub fn create_keys_and_certificate(
+        &self,
+        input: CreateKeysAndCertificateRequest,
+    ) -> Result<CreateKeysAndCertificateResponse, RusotoError<CreateKeysAndCertificateError>> {
+        self.runtime
+            .block_on(self.client.create_keys_and_certificate(input))
+    }
+
+    /// <p>Defines an action that can be applied to audit findings by using StartAuditMitigationActionsTask. Each mitigation action can apply only one type of change.</p>
+    ///
+    /// See `Iot::create_mitigation_action`.
+    pub fn create_mitigation_action(
+        &self,
+        input: CreateMitigationActionRequest,
+    ) -> Result<CreateMitigationActionResponse, RusotoError<CreateMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.create_mitigation_action(input))
+    }
+
+    /// <p>Creates an AWS IoT OTAUpdate on a target group of things or groups.</p>
+    ///
+    /// See `Iot::create_ota_update`.
+    pub fn create_ota_update(
+        &self,
+        input: CreateOTAUpdateRequest,
+    ) -> Result<CreateOTAUpdateResponse, RusotoError<CreateOTAUpdateError>> {
+        self.runtime.block_on(self.client.create_ota_update(input))
+    }
+
+    /// <p>Creates an AWS IoT policy.</p> <p>The created policy is the default version for the policy. This operation creates a policy version with a version identifier of <b>1</b> and sets <b>1</b> as the policy's default version.</p>
+    ///
+    /// See `Iot::create_policy`.
+    pub fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.runtime.block_on(self.client.create_policy(input))
+    }
+
+    /// <p>Creates a new version of the specified AWS IoT policy. To update a policy, create a new policy version. A managed policy can have up to five versions. If the policy has five versions, you must use <a>DeletePolicyVersion</a> to delete an existing version before you create a new one.</p> <p>Optionally, you can set the new version as the policy's default version. The default version is the operative version (that is, the version that is in effect for the certificates to which the policy is attached).</p>
+    ///
+    /// See `Iot::create_policy_version`.
+    pub fn create_policy_version(
+        &self,
+        input: CreatePolicyVersionRequest,
+    ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.create_policy_version(input))
+    }
+
+    /// <p>Creates a provisioning claim.</p>
+    ///
+    /// See `Iot::create_provisioning_claim`.
+    pub fn create_provisioning_claim(
+        &self,
+        input: CreateProvisioningClaimRequest,
+    ) -> Result<CreateProvisioningClaimResponse, RusotoError<CreateProvisioningClaimError>> {
+        self.runtime
+            .block_on(self.client.create_provisioning_claim(input))
+    }
+
+    /// <p>Creates a fleet provisioning template.</p>
+    ///
+    /// See `Iot::create_provisioning_template`.
+    pub fn create_provisioning_template(
+        &self,
+        input: CreateProvisioningTemplateRequest,
+    ) -> Result<CreateProvisioningTemplateResponse, RusotoError<CreateProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_provisioning_template(input))
+    }
+
+    /// <p>Creates a new version of a fleet provisioning template.</p>
+    ///
+    /// See `Iot::create_provisioning_template_version`.
+    pub fn create_provisioning_template_version(
+        &self,
+        input: CreateProvisioningTemplateVersionRequest,
+    ) -> Result<
+        CreateProvisioningTemplateVersionResponse,
+        RusotoError<CreateProvisioningTemplateVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_provisioning_template_version(input))
+    }
+
+    /// <p>Creates a role alias.</p>
+    ///
+    /// See `Iot::create_role_alias`.
+    pub fn create_role_alias(
+        &self,
+        input: CreateRoleAliasRequest,
+    ) -> Result<CreateRoleAliasResponse, RusotoError<CreateRoleAliasError>> {
+        self.runtime.block_on(self.client.create_role_alias(input))
+    }
+
+    /// <p>Creates a scheduled audit that is run at a specified time interval.</p>
+    ///
+    /// See `Iot::create_scheduled_audit`.
+    pub fn create_scheduled_audit(
+        &self,
+        input: CreateScheduledAuditRequest,
+    ) -> Result<CreateScheduledAuditResponse, RusotoError<CreateScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.create_scheduled_audit(input))
+    }
+
+    /// <p>Creates a Device Defender security profile.</p>
+    ///
+    /// See `Iot::create_security_profile`.
+    pub fn create_security_profile(
+        &self,
+        input: CreateSecurityProfileRequest,
+    ) -> Result<CreateSecurityProfileResponse, RusotoError<CreateSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.create_security_profile(input))
+    }
+
+    /// <p>Creates a stream for delivering one or more large files in chunks over MQTT. A stream transports data bytes in chunks or blocks packaged as MQTT messages from a source like S3. You can have one or more files associated with a stream.</p>
+    ///
+    /// See `Iot::create_stream`.
+    pub fn create_stream(
+        &self,
+        input: CreateStreamRequest,
+    ) -> Result<CreateStreamResponse, RusotoError<CreateStreamError>> {
+        self.runtime.block_on(self.client.create_stream(input))
+    }
+
+    /// <p><p>Creates a thing record in the registry. If this call is made multiple times using the same thing name and configuration, the call will succeed. If this call is made with the same thing name but different configuration a <code>ResourceAlreadyExistsException</code> is thrown.</p> <note> <p>This is a control plane operation. See <a href="https://docs.aws.amazon.com/iot/latest/developerguide/iot-authorization.html">Authorization</a> for information about authorizing control plane actions.</p> </note></p>
+    ///
+    /// See `Iot::create_thing`.
+    pub fn create_thing(
+        &self,
+        input: CreateThingRequest,
+    ) -> Result<CreateThingResponse, RusotoError<CreateThingError>> {
+        self.runtime.block_on(self.client.create_thing(input))
+    }
+
+    /// <p><p>Create a thing group.</p> <note> <p>This is a control plane operation. See <a href="https://docs.aws.amazon.com/iot/latest/developerguide/iot-authorization.html">Authorization</a> for information about authorizing control plane actions.</p> </note></p>
+    ///
+    /// See `Iot::create_thing_group`.
+    pub fn create_thing_group(
+        &self,
+        input: CreateThingGroupRequest,
+    ) -> Result<CreateThingGroupResponse, RusotoError<CreateThingGroupError>> {
+        self.runtime.block_on(self.client.create_thing_group(input))
+    }
+
+    /// <p>Creates a new thing type.</p>
+    ///
+    /// See `Iot::create_thing_type`.
+    pub fn create_thing_type(
+        &self,
+        input: CreateThingTypeRequest,
+    ) -> Result<CreateThingTypeResponse, RusotoError<CreateThingTypeError>> {
+        self.runtime.block_on(self.client.create_thing_type(input))
+    }
+
+    /// <p>Creates a rule. Creating rules is an administrator-level action. Any user who has permission to create rules will be able to access data processed by the rule.</p>
+    ///
+    /// See `Iot::create_topic_rule`.
+    pub fn create_topic_rule(
+        &self,
+        input: CreateTopicRuleRequest,
+    ) -> Result<(), RusotoError<CreateTopicRuleError>> {
+        self.runtime.block_on(self.client.create_topic_rule(input))
+    }
+
+    /// <p>Creates a topic rule destination. The destination must be confirmed prior to use.</p>
+    ///
+    /// See `Iot::create_topic_rule_destination`.
+    pub fn create_topic_rule_destination(
+        &self,
+        input: CreateTopicRuleDestinationRequest,
+    ) -> Result<CreateTopicRuleDestinationResponse, RusotoError<CreateTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_topic_rule_destination(input))
+    }
+
+    /// <p>Restores the default settings for Device Defender audits for this account. Any configuration data you entered is deleted and all audit checks are reset to disabled. </p>
+    ///
+    /// See `Iot::delete_account_audit_configuration`.
+    pub fn delete_account_audit_configuration(
+        &self,
+        input: DeleteAccountAuditConfigurationRequest,
+    ) -> Result<
+        DeleteAccountAuditConfigurationResponse,
+        RusotoError<DeleteAccountAuditConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_account_audit_configuration(input))
+    }
+
+    /// <p>Deletes an authorizer.</p>
+    ///
+    /// See `Iot::delete_authorizer`.
+    pub fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<DeleteAuthorizerResponse, RusotoError<DeleteAuthorizerError>> {
+        self.runtime.block_on(self.client.delete_authorizer(input))
+    }
+
+    /// <p>Deletes the billing group.</p>
+    ///
+    /// See `Iot::delete_billing_group`.
+    pub fn delete_billing_group(
+        &self,
+        input: DeleteBillingGroupRequest,
+    ) -> Result<DeleteBillingGroupResponse, RusotoError<DeleteBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_billing_group(input))
+    }
+
+    /// <p>Deletes a registered CA certificate.</p>
+    ///
+    /// See `Iot::delete_ca_certificate`.
+    pub fn delete_ca_certificate(
+        &self,
+        input: DeleteCACertificateRequest,
+    ) -> Result<DeleteCACertificateResponse, RusotoError<DeleteCACertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_ca_certificate(input))
+    }
+
+    /// <p>Deletes the specified certificate.</p> <p>A certificate cannot be deleted if it has a policy or IoT thing attached to it or if its status is set to ACTIVE. To delete a certificate, first use the <a>DetachPrincipalPolicy</a> API to detach all policies. Next, use the <a>UpdateCertificate</a> API to set the certificate to the INACTIVE status.</p>
+    ///
+    /// See `Iot::delete_certificate`.
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// <p>Removes the specified dimension from your AWS account.</p>
+    ///
+    /// See `Iot::delete_dimension`.
+    pub fn delete_dimension(
+        &self,
+        input: DeleteDimensionRequest,
+    ) -> Result<DeleteDimensionResponse, RusotoError<DeleteDimensionError>> {
+        self.runtime.block_on(self.client.delete_dimension(input))
+    }
+
+    /// <p><p>Deletes the specified domain configuration.</p> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
+    ///
+    /// See `Iot::delete_domain_configuration`.
+    pub fn delete_domain_configuration(
+        &self,
+        input: DeleteDomainConfigurationRequest,
+    ) -> Result<DeleteDomainConfigurationResponse, RusotoError<DeleteDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_domain_configuration(input))
+    }
+
+    /// <p>Deletes a dynamic thing group.</p>
+    ///
+    /// See `Iot::delete_dynamic_thing_group`.
+    pub fn delete_dynamic_thing_group(
+        &self,
+        input: DeleteDynamicThingGroupRequest,
+    ) -> Result<DeleteDynamicThingGroupResponse, RusotoError<DeleteDynamicThingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_dynamic_thing_group(input))
+    }
+
+    /// <p>Deletes a job and its related job executions.</p> <p>Deleting a job may take time, depending on the number of job executions created for the job and various other factors. While the job is being deleted, the status of the job will be shown as "DELETION_IN_PROGRESS". Attempting to delete or cancel a job whose status is already "DELETION_IN_PROGRESS" will result in an error.</p> <p>Only 10 jobs may have status "DELETION_IN_PROGRESS" at the same time, or a LimitExceededException will occur.</p>
+    ///
+    /// See `Iot::delete_job`.
+    pub fn delete_job(&self, input: DeleteJobRequest) -> Result<(), RusotoError<DeleteJobError>> {
+        self.runtime.block_on(self.client.delete_job(input))
+    }
+
+    /// <p>Deletes a job execution.</p>
+    ///
+    /// See `Iot::delete_job_execution`.
+    pub fn delete_job_execution(
+        &self,
+        input: DeleteJobExecutionRequest,
+    ) -> Result<(), RusotoError<DeleteJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.delete_job_execution(input))
+    }
+
+    /// <p>Deletes a defined mitigation action from your AWS account.</p>
+    ///
+    /// See `Iot::delete_mitigation_action`.
+    pub fn delete_mitigation_action(
+        &self,
+        input: DeleteMitigationActionRequest,
+    ) -> Result<DeleteMitigationActionResponse, RusotoError<DeleteMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.delete_mitigation_action(input))
+    }
+
+    /// <p>Delete an OTA update.</p>
+    ///
+    /// See `Iot::delete_ota_update`.
+    pub fn delete_ota_update(
+        &self,
+        input: DeleteOTAUpdateRequest,
+    ) -> Result<DeleteOTAUpdateResponse, RusotoError<DeleteOTAUpdateError>> {
+        self.runtime.block_on(self.client.delete_ota_update(input))
+    }
+
+    /// <p>Deletes the specified policy.</p> <p>A policy cannot be deleted if it has non-default versions or it is attached to any certificate.</p> <p>To delete a policy, use the DeletePolicyVersion API to delete all non-default versions of the policy; use the DetachPrincipalPolicy API to detach the policy from any certificate; and then use the DeletePolicy API to delete the policy.</p> <p>When a policy is deleted using DeletePolicy, its default version is deleted with it.</p>
+    ///
+    /// See `Iot::delete_policy`.
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// <p>Deletes the specified version of the specified policy. You cannot delete the default version of a policy using this API. To delete the default version of a policy, use <a>DeletePolicy</a>. To find out which version of a policy is marked as the default version, use ListPolicyVersions.</p>
+    ///
+    /// See `Iot::delete_policy_version`.
+    pub fn delete_policy_version(
+        &self,
+        input: DeletePolicyVersionRequest,
+    ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_policy_version(input))
+    }
+
+    /// <p>Deletes a fleet provisioning template.</p>
+    ///
+    /// See `Iot::delete_provisioning_template`.
+    pub fn delete_provisioning_template(
+        &self,
+        input: DeleteProvisioningTemplateRequest,
+    ) -> Result<DeleteProvisioningTemplateResponse, RusotoError<DeleteProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_provisioning_template(input))
+    }
+
+    /// <p>Deletes a fleet provisioning template version.</p>
+    ///
+    /// See `Iot::delete_provisioning_template_version`.
+    pub fn delete_provisioning_template_version(
+        &self,
+        input: DeleteProvisioningTemplateVersionRequest,
+    ) -> Result<
+        DeleteProvisioningTemplateVersionResponse,
+        RusotoError<DeleteProvisioningTemplateVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_provisioning_template_version(input))
+    }
+
+    /// <p>Deletes a CA certificate registration code.</p>
+    ///
+    /// See `Iot::delete_registration_code`.
+    pub fn delete_registration_code(
+        &self,
+    ) -> Result<DeleteRegistrationCodeResponse, RusotoError<DeleteRegistrationCodeError>> {
+        self.runtime
+            .block_on(self.client.delete_registration_code())
+    }
+
+    /// <p>Deletes a role alias</p>
+    ///
+    /// See `Iot::delete_role_alias`.
+    pub fn delete_role_alias(
+        &self,
+        input: DeleteRoleAliasRequest,
+    ) -> Result<DeleteRoleAliasResponse, RusotoError<DeleteRoleAliasError>> {
+        self.runtime.block_on(self.client.delete_role_alias(input))
+    }
+
+    /// <p>Deletes a scheduled audit.</p>
+    ///
+    /// See `Iot::delete_scheduled_audit`.
+    pub fn delete_scheduled_audit(
+        &self,
+        input: DeleteScheduledAuditRequest,
+    ) -> Result<DeleteScheduledAuditResponse, RusotoError<DeleteScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_audit(input))
+    }
+
+    /// <p>Deletes a Device Defender security profile.</p>
+    ///
+    /// See `Iot::delete_security_profile`.
+    pub fn delete_security_profile(
+        &self,
+        input: DeleteSecurityProfileRequest,
+    ) -> Result<DeleteSecurityProfileResponse, RusotoError<DeleteSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_security_profile(input))
+    }
+
+    /// <p>Deletes a stream.</p>
+    ///
+    /// See `Iot::delete_stream`.
+    pub fn delete_stream(
+        &self,
+        input: DeleteStreamRequest,
+    ) -> Result<DeleteStreamResponse, RusotoError<DeleteStreamError>> {
+        self.runtime.block_on(self.client.delete_stream(input))
+    }
+
+    /// <p>Deletes the specified thing. Returns successfully with no error if the deletion is successful or you specify a thing that doesn't exist.</p>
+    ///
+    /// See `Iot::delete_thing`.
+    pub fn delete_thing(
+        &self,
+        input: DeleteThingRequest,
+    ) -> Result<DeleteThingResponse, RusotoError<DeleteThingError>> {
+        self.runtime.block_on(self.client.delete_thing(input))
+    }
+
+    /// <p>Deletes a thing group.</p>
+    ///
+    /// See `Iot::delete_thing_group`.
+    pub fn delete_thing_group(
+        &self,
+        input: DeleteThingGroupRequest,
+    ) -> Result<DeleteThingGroupResponse, RusotoError<DeleteThingGroupError>> {
+        self.runtime.block_on(self.client.delete_thing_group(input))
+    }
+
+    /// <p>Deletes the specified thing type. You cannot delete a thing type if it has things associated with it. To delete a thing type, first mark it as deprecated by calling <a>DeprecateThingType</a>, then remove any associated things by calling <a>UpdateThing</a> to change the thing type on any associated thing, and finally use <a>DeleteThingType</a> to delete the thing type.</p>
+    ///
+    /// See `Iot::delete_thing_type`.
+    pub fn delete_thing_type(
+        &self,
+        input: DeleteThingTypeRequest,
+    ) -> Result<DeleteThingTypeResponse, RusotoError<DeleteThingTypeError>> {
+        self.runtime.block_on(self.client.delete_thing_type(input))
+    }
+
+    /// <p>Deletes the rule.</p>
+    ///
+    /// See `Iot::delete_topic_rule`.
+    pub fn delete_topic_rule(
+        &self,
+        input: DeleteTopicRuleRequest,
+    ) -> Result<(), RusotoError<DeleteTopicRuleError>> {
+        self.runtime.block_on(self.client.delete_topic_rule(input))
+    }
+
+    /// <p>Deletes a topic rule destination.</p>
+    ///
+    /// See `Iot::delete_topic_rule_destination`.
+    pub fn delete_topic_rule_destination(
+        &self,
+        input: DeleteTopicRuleDestinationRequest,
+    ) -> Result<DeleteTopicRuleDestinationResponse, RusotoError<DeleteTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_topic_rule_destination(input))
+    }
+
+    /// <p>Deletes a logging level.</p>
+    ///
+    /// See `Iot::delete_v2_logging_level`.
+    pub fn delete_v2_logging_level(
+        &self,
+        input: DeleteV2LoggingLevelRequest,
+    ) -> Result<(), RusotoError<DeleteV2LoggingLevelError>> {
+        self.runtime
+            .block_on(self.client.delete_v2_logging_level(input))
+    }
+
+    /// <p>Deprecates a thing type. You can not associate new things with deprecated thing type.</p>
+    ///
+    /// See `Iot::deprecate_thing_type`.
+    pub fn deprecate_thing_type(
+        &self,
+        input: DeprecateThingTypeRequest,
+    ) -> Result<DeprecateThingTypeResponse, RusotoError<DeprecateThingTypeError>> {
+        self.runtime
+            .block_on(self.client.deprecate_thing_type(input))
+    }
+
+    /// <p>Gets information about the Device Defender audit settings for this account. Settings include how audit notifications are sent and which audit checks are enabled or disabled.</p>
+    ///
+    /// See `Iot::describe_account_audit_configuration`.
+    pub fn describe_account_audit_configuration(
+        &self,
+    ) -> Result<
+        DescribeAccountAuditConfigurationResponse,
+        RusotoError<DescribeAccountAuditConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_account_audit_configuration())
+    }
+
+    /// <p>Gets information about a single audit finding. Properties include the reason for noncompliance, the severity of the issue, and when the audit that returned the finding was started.</p>
+    ///
+    /// See `Iot::describe_audit_finding`.
+    pub fn describe_audit_finding(
+        &self,
+        input: DescribeAuditFindingRequest,
+    ) -> Result<DescribeAuditFindingResponse, RusotoError<DescribeAuditFindingError>> {
+        self.runtime
+            .block_on(self.client.describe_audit_finding(input))
+    }
+
+    /// <p>Gets information about an audit mitigation task that is used to apply mitigation actions to a set of audit findings. Properties include the actions being applied, the audit checks to which they're being applied, the task status, and aggregated task statistics.</p>
+    ///
+    /// See `Iot::describe_audit_mitigation_actions_task`.
+    pub fn describe_audit_mitigation_actions_task(
+        &self,
+        input: DescribeAuditMitigationActionsTaskRequest,
+    ) -> Result<
+        DescribeAuditMitigationActionsTaskResponse,
+        RusotoError<DescribeAuditMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_audit_mitigation_actions_task(input))
+    }
+
+    /// <p>Gets information about a Device Defender audit.</p>
+    ///
+    /// See `Iot::describe_audit_task`.
+    pub fn describe_audit_task(
+        &self,
+        input: DescribeAuditTaskRequest,
+    ) -> Result<DescribeAuditTaskResponse, RusotoError<DescribeAuditTaskError>> {
+        self.runtime
+            .block_on(self.client.describe_audit_task(input))
+    }
+
+    /// <p>Describes an authorizer.</p>
+    ///
+    /// See `Iot::describe_authorizer`.
+    pub fn describe_authorizer(
+        &self,
+        input: DescribeAuthorizerRequest,
+    ) -> Result<DescribeAuthorizerResponse, RusotoError<DescribeAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.describe_authorizer(input))
+    }
+
+    /// <p>Returns information about a billing group.</p>
+    ///
+    /// See `Iot::describe_billing_group`.
+    pub fn describe_billing_group(
+        &self,
+        input: DescribeBillingGroupRequest,
+    ) -> Result<DescribeBillingGroupResponse, RusotoError<DescribeBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_billing_group(input))
+    }
+
+    /// <p>Describes a registered CA certificate.</p>
+    ///
+    /// See `Iot::describe_ca_certificate`.
+    pub fn describe_ca_certificate(
+        &self,
+        input: DescribeCACertificateRequest,
+    ) -> Result<DescribeCACertificateResponse, RusotoError<DescribeCACertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_ca_certificate(input))
+    }
+
+    /// <p>Gets information about the specified certificate.</p>
+    ///
+    /// See `Iot::describe_certificate`.
+    pub fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_certificate(input))
+    }
+
+    /// <p>Describes the default authorizer.</p>
+    ///
+    /// See `Iot::describe_default_authorizer`.
+    pub fn describe_default_authorizer(
+        &self,
+    ) -> Result<DescribeDefaultAuthorizerResponse, RusotoError<DescribeDefaultAuthorizerError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_default_authorizer())
+    }
+
+    /// <p>Provides details about a dimension that is defined in your AWS account.</p>
+    ///
+    /// See `Iot::describe_dimension`.
+    pub fn describe_dimension(
+        &self,
+        input: DescribeDimensionRequest,
+    ) -> Result<DescribeDimensionResponse, RusotoError<DescribeDimensionError>> {
+        self.runtime.block_on(self.client.describe_dimension(input))
+    }
+
+    /// <p><p>Gets summary information about a domain configuration.</p> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
+    ///
+    /// See `Iot::describe_domain_configuration`.
+    pub fn describe_domain_configuration(
+        &self,
+        input: DescribeDomainConfigurationRequest,
+    ) -> Result<DescribeDomainConfigurationResponse, RusotoError<DescribeDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_domain_configuration(input))
+    }
+
+    /// <p>Returns a unique endpoint specific to the AWS account making the call.</p>
+    ///
+    /// See `Iot::describe_endpoint`.
+    pub fn describe_endpoint(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
+        self.runtime.block_on(self.client.describe_endpoint(input))
+    }
+
+    /// <p>Describes event configurations.</p>
+    ///
+    /// See `Iot::describe_event_configurations`.
+    pub fn describe_event_configurations(
+        &self,
+    ) -> Result<DescribeEventConfigurationsResponse, RusotoError<DescribeEventConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_event_configurations())
+    }
+
+    /// <p>Describes a search index.</p>
+    ///
+    /// See `Iot::describe_index`.
+    pub fn describe_index(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
+        self.runtime.block_on(self.client.describe_index(input))
+    }
+
+    /// <p>Describes a job.</p>
+    ///
+    /// See `Iot::describe_job`.
+    pub fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> Result<DescribeJobResponse, RusotoError<DescribeJobError>> {
+        self.runtime.block_on(self.client.describe_job(input))
+    }
+
+    /// <p>Describes a job execution.</p>
+    ///
+    /// See `Iot::describe_job_execution`.
+    pub fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> Result<DescribeJobExecutionResponse, RusotoError<DescribeJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_job_execution(input))
+    }
+
+    /// <p>Gets information about a mitigation action.</p>
+    ///
+    /// See `Iot::describe_mitigation_action`.
+    pub fn describe_mitigation_action(
+        &self,
+        input: DescribeMitigationActionRequest,
+    ) -> Result<DescribeMitigationActionResponse, RusotoError<DescribeMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.describe_mitigation_action(input))
+    }
+
+    /// <p>Returns information about a fleet provisioning template.</p>
+    ///
+    /// See `Iot::describe_provisioning_template`.
+    pub fn describe_provisioning_template(
+        &self,
+        input: DescribeProvisioningTemplateRequest,
+    ) -> Result<DescribeProvisioningTemplateResponse, RusotoError<DescribeProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_provisioning_template(input))
+    }
+
+    /// <p>Returns information about a fleet provisioning template version.</p>
+    ///
+    /// See `Iot::describe_provisioning_template_version`.
+    pub fn describe_provisioning_template_version(
+        &self,
+        input: DescribeProvisioningTemplateVersionRequest,
+    ) -> Result<
+        DescribeProvisioningTemplateVersionResponse,
+        RusotoError<DescribeProvisioningTemplateVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_provisioning_template_version(input))
+    }
+
+    /// <p>Describes a role alias.</p>
+    ///
+    /// See `Iot::describe_role_alias`.
+    pub fn describe_role_alias(
+        &self,
+        input: DescribeRoleAliasRequest,
+    ) -> Result<DescribeRoleAliasResponse, RusotoError<DescribeRoleAliasError>> {
+        self.runtime
+            .block_on(self.client.describe_role_alias(input))
+    }
+
+    /// <p>Gets information about a scheduled audit.</p>
+    ///
+    /// See `Iot::describe_scheduled_audit`.
+    pub fn describe_scheduled_audit(
+        &self,
+        input: DescribeScheduledAuditRequest,
+    ) -> Result<DescribeScheduledAuditResponse, RusotoError<DescribeScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_audit(input))
+    }
+
+    /// <p>Gets information about a Device Defender security profile.</p>
+    ///
+    /// See `Iot::describe_security_profile`.
+    pub fn describe_security_profile(
+        &self,
+        input: DescribeSecurityProfileRequest,
+    ) -> Result<DescribeSecurityProfileResponse, RusotoError<DescribeSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_security_profile(input))
+    }
+
+    /// <p>Gets information about a stream.</p>
+    ///
+    /// See `Iot::describe_stream`.
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamRequest,
+    ) -> Result<DescribeStreamResponse, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// <p>Gets information about the specified thing.</p>
+    ///
+    /// See `Iot::describe_thing`.
+    pub fn describe_thing(
+        &self,
+        input: DescribeThingRequest,
+    ) -> Result<DescribeThingResponse, RusotoError<DescribeThingError>> {
+        self.runtime.block_on(self.client.describe_thing(input))
+    }
+
+    /// <p>Describe a thing group.</p>
+    ///
+    /// See `Iot::describe_thing_group`.
+    pub fn describe_thing_group(
+        &self,
+        input: DescribeThingGroupRequest,
+    ) -> Result<DescribeThingGroupResponse, RusotoError<DescribeThingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_thing_group(input))
+    }
+
+    /// <p>Describes a bulk thing provisioning task.</p>
+    ///
+    /// See `Iot::describe_thing_registration_task`.
+    pub fn describe_thing_registration_task(
+        &self,
+        input: DescribeThingRegistrationTaskRequest,
+    ) -> Result<
+        DescribeThingRegistrationTaskResponse,
+        RusotoError<DescribeThingRegistrationTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_thing_registration_task(input))
+    }
+
+    /// <p>Gets information about the specified thing type.</p>
+    ///
+    /// See `Iot::describe_thing_type`.
+    pub fn describe_thing_type(
+        &self,
+        input: DescribeThingTypeRequest,
+    ) -> Result<DescribeThingTypeResponse, RusotoError<DescribeThingTypeError>> {
+        self.runtime
+            .block_on(self.client.describe_thing_type(input))
+    }
+
+    /// <p>Detaches a policy from the specified target.</p>
+    ///
+    /// See `Iot::detach_policy`.
+    pub fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPolicyError>> {
+        self.runtime.block_on(self.client.detach_policy(input))
+    }
+
+    /// <p>Removes the specified policy from the specified certificate.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>DetachPolicy</a> instead.</p>
+    ///
+    /// See `Iot::detach_principal_policy`.
+    pub fn detach_principal_policy(
+        &self,
+        input: DetachPrincipalPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPrincipalPolicyError>> {
+        self.runtime
+            .block_on(self.client.detach_principal_policy(input))
+    }
+
+    /// <p>Disassociates a Device Defender security profile from a thing group or from this account.</p>
+    ///
+    /// See `Iot::detach_security_profile`.
+    pub fn detach_security_profile(
+        &self,
+        input: DetachSecurityProfileRequest,
+    ) -> Result<DetachSecurityProfileResponse, RusotoError<DetachSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.detach_security_profile(input))
+    }
+
+    /// <p><p>Detaches the specified principal from the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p> <note> <p>This call is asynchronous. It might take several seconds for the detachment to propagate.</p> </note></p>
+    ///
+    /// See `Iot::detach_thing_principal`.
+    pub fn detach_thing_principal(
+        &self,
+        input: DetachThingPrincipalRequest,
+    ) -> Result<DetachThingPrincipalResponse, RusotoError<DetachThingPrincipalError>> {
+        self.runtime
+            .block_on(self.client.detach_thing_principal(input))
+    }
+
+    /// <p>Disables the rule.</p>
+    ///
+    /// See `Iot::disable_topic_rule`.
+    pub fn disable_topic_rule(
+        &self,
+        input: DisableTopicRuleRequest,
+    ) -> Result<(), RusotoError<DisableTopicRuleError>> {
+        self.runtime.block_on(self.client.disable_topic_rule(input))
+    }
+
+    /// <p>Enables the rule.</p>
+    ///
+    /// See `Iot::enable_topic_rule`.
+    pub fn enable_topic_rule(
+        &self,
+        input: EnableTopicRuleRequest,
+    ) -> Result<(), RusotoError<EnableTopicRuleError>> {
+        self.runtime.block_on(self.client.enable_topic_rule(input))
+    }
+
+    /// <p>Returns the approximate count of unique values that match the query.</p>
+    ///
+    /// See `Iot::get_cardinality`.
+    pub fn get_cardinality(
+        &self,
+        input: GetCardinalityRequest,
+    ) -> Result<GetCardinalityResponse, RusotoError<GetCardinalityError>> {
+        self.runtime.block_on(self.client.get_cardinality(input))
+    }
+
+    /// <p>Gets a list of the policies that have an effect on the authorization behavior of the specified device when it connects to the AWS IoT device gateway.</p>
+    ///
+    /// See `Iot::get_effective_policies`.
+    pub fn get_effective_policies(
+        &self,
+        input: GetEffectivePoliciesRequest,
+    ) -> Result<GetEffectivePoliciesResponse, RusotoError<GetEffectivePoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_effective_policies(input))
+    }
+
+    /// <p>Gets the indexing configuration.</p>
+    ///
+    /// See `Iot::get_indexing_configuration`.
+    pub fn get_indexing_configuration(
+        &self,
+    ) -> Result<GetIndexingConfigurationResponse, RusotoError<GetIndexingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_indexing_configuration())
+    }
+
+    /// <p>Gets a job document.</p>
+    ///
+    /// See `Iot::get_job_document`.
+    pub fn get_job_document(
+        &self,
+        input: GetJobDocumentRequest,
+    ) -> Result<GetJobDocumentResponse, RusotoError<GetJobDocumentError>> {
+        self.runtime.block_on(self.client.get_job_document(input))
+    }
+
+    /// <p>Gets the logging options.</p> <p>NOTE: use of this command is not recommended. Use <code>GetV2LoggingOptions</code> instead.</p>
+    ///
+    /// See `Iot::get_logging_options`.
+    pub fn get_logging_options(
+        &self,
+    ) -> Result<GetLoggingOptionsResponse, RusotoError<GetLoggingOptionsError>> {
+        self.runtime.block_on(self.client.get_logging_options())
+    }
+
+    /// <p>Gets an OTA update.</p>
+    ///
+    /// See `Iot::get_ota_update`.
+    pub fn get_ota_update(
+        &self,
+        input: GetOTAUpdateRequest,
+    ) -> Result<GetOTAUpdateResponse, RusotoError<GetOTAUpdateError>> {
+        self.runtime.block_on(self.client.get_ota_update(input))
+    }
+
+    /// <p>Groups the aggregated values that match the query into percentile groupings. The default percentile groupings are: 1,5,25,50,75,95,99, although you can specify your own when you call <code>GetPercentiles</code>. This function returns a value for each percentile group specified (or the default percentile groupings). The percentile group "1" contains the aggregated field value that occurs in approximately one percent of the values that match the query. The percentile group "5" contains the aggregated field value that occurs in approximately five percent of the values that match the query, and so on. The result is an approximation, the more values that match the query, the more accurate the percentile values.</p>
+    ///
+    /// See `Iot::get_percentiles`.
+    pub fn get_percentiles(
+        &self,
+        input: GetPercentilesRequest,
+    ) -> Result<GetPercentilesResponse, RusotoError<GetPercentilesError>> {
+        self.runtime.block_on(self.client.get_percentiles(input))
+    }
+
+    /// <p>Gets information about the specified policy with the policy document of the default version.</p>
+    ///
+    /// See `Iot::get_policy`.
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// <p>Gets information about the specified policy version.</p>
+    ///
+    /// See `Iot::get_policy_version`.
+    pub fn get_policy_version(
+        &self,
+        input: GetPolicyVersionRequest,
+    ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
+        self.runtime.block_on(self.client.get_policy_version(input))
+    }
+
+    /// <p>Gets a registration code used to register a CA certificate with AWS IoT.</p>
+    ///
+    /// See `Iot::get_registration_code`.
+    pub fn get_registration_code(
+        &self,
+    ) -> Result<GetRegistrationCodeResponse, RusotoError<GetRegistrationCodeError>> {
+        self.runtime.block_on(self.client.get_registration_code())
+    }
+
+    /// <p>Returns the count, average, sum, minimum, maximum, sum of squares, variance, and standard deviation for the specified aggregated field. If the aggregation field is of type <code>String</code>, only the count statistic is returned.</p>
+    ///
+    /// See `Iot::get_statistics`.
+    pub fn get_statistics(
+        &self,
+        input: GetStatisticsRequest,
+    ) -> Result<GetStatisticsResponse, RusotoError<GetStatisticsError>> {
+        self.runtime.block_on(self.client.get_statistics(input))
+    }
+
+    /// <p>Gets information about the rule.</p>
+    ///
+    /// See `Iot::get_topic_rule`.
+    pub fn get_topic_rule(
+        &self,
+        input: GetTopicRuleRequest,
+    ) -> Result<GetTopicRuleResponse, RusotoError<GetTopicRuleError>> {
+        self.runtime.block_on(self.client.get_topic_rule(input))
+    }
+
+    /// <p>Gets information about a topic rule destination.</p>
+    ///
+    /// See `Iot::get_topic_rule_destination`.
+    pub fn get_topic_rule_destination(
+        &self,
+        input: GetTopicRuleDestinationRequest,
+    ) -> Result<GetTopicRuleDestinationResponse, RusotoError<GetTopicRuleDestinationError>> {
+        self.runtime
+            .block_on(self.client.get_topic_rule_destination(input))
+    }
+
+    /// <p>Gets the fine grained logging options.</p>
+    ///
+    /// See `Iot::get_v2_logging_options`.
+    pub fn get_v2_logging_options(
+        &self,
+    ) -> Result<GetV2LoggingOptionsResponse, RusotoError<GetV2LoggingOptionsError>> {
+        self.runtime.block_on(self.client.get_v2_logging_options())
+    }
+
+    /// <p>Lists the active violations for a given Device Defender security profile.</p>
+    ///
+    /// See `Iot::list_active_violations`.
+    pub fn list_active_violations(
+        &self,
+        input: ListActiveViolationsRequest,
+    ) -> Result<ListActiveViolationsResponse, RusotoError<ListActiveViolationsError>> {
+        self.runtime
+            .block_on(self.client.list_active_violations(input))
+    }
+
+    /// <p>Lists the policies attached to the specified thing group.</p>
+    ///
+    /// See `Iot::list_attached_policies`.
+    pub fn list_attached_policies(
+        &self,
+        input: ListAttachedPoliciesRequest,
+    ) -> Result<ListAttachedPoliciesResponse, RusotoError<ListAttachedPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_policies(input))
+    }
+
+    /// <p>Lists the findings (results) of a Device Defender audit or of the audits performed during a specified time period. (Findings are retained for 180 days.)</p>
+    ///
+    /// See `Iot::list_audit_findings`.
+    pub fn list_audit_findings(
+        &self,
+        input: ListAuditFindingsRequest,
+    ) -> Result<ListAuditFindingsResponse, RusotoError<ListAuditFindingsError>> {
+        self.runtime
+            .block_on(self.client.list_audit_findings(input))
+    }
+
+    /// <p>Gets the status of audit mitigation action tasks that were executed.</p>
+    ///
+    /// See `Iot::list_audit_mitigation_actions_executions`.
+    pub fn list_audit_mitigation_actions_executions(
+        &self,
+        input: ListAuditMitigationActionsExecutionsRequest,
+    ) -> Result<
+        ListAuditMitigationActionsExecutionsResponse,
+        RusotoError<ListAuditMitigationActionsExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_audit_mitigation_actions_executions(input))
+    }
+
+    /// <p>Gets a list of audit mitigation action tasks that match the specified filters.</p>
+    ///
+    /// See `Iot::list_audit_mitigation_actions_tasks`.
+    pub fn list_audit_mitigation_actions_tasks(
+        &self,
+        input: ListAuditMitigationActionsTasksRequest,
+    ) -> Result<
+        ListAuditMitigationActionsTasksResponse,
+        RusotoError<ListAuditMitigationActionsTasksError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_audit_mitigation_actions_tasks(input))
+    }
+
+    /// <p>Lists the Device Defender audits that have been performed during a given time period.</p>
+    ///
+    /// See `Iot::list_audit_tasks`.
+    pub fn list_audit_tasks(
+        &self,
+        input: ListAuditTasksRequest,
+    ) -> Result<ListAuditTasksResponse, RusotoError<ListAuditTasksError>> {
+        self.runtime.block_on(self.client.list_audit_tasks(input))
+    }
+
+    /// <p>Lists the authorizers registered in your account.</p>
+    ///
+    /// See `Iot::list_authorizers`.
+    pub fn list_authorizers(
+        &self,
+        input: ListAuthorizersRequest,
+    ) -> Result<ListAuthorizersResponse, RusotoError<ListAuthorizersError>> {
+        self.runtime.block_on(self.client.list_authorizers(input))
+    }
+
+    /// <p>Lists the billing groups you have created.</p>
+    ///
+    /// See `Iot::list_billing_groups`.
+    pub fn list_billing_groups(
+        &self,
+        input: ListBillingGroupsRequest,
+    ) -> Result<ListBillingGroupsResponse, RusotoError<ListBillingGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_billing_groups(input))
+    }
+
+    /// <p>Lists the CA certificates registered for your AWS account.</p> <p>The results are paginated with a default page size of 25. You can use the returned marker to retrieve additional results.</p>
+    ///
+    /// See `Iot::list_ca_certificates`.
+    pub fn list_ca_certificates(
+        &self,
+        input: ListCACertificatesRequest,
+    ) -> Result<ListCACertificatesResponse, RusotoError<ListCACertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_ca_certificates(input))
+    }
+
+    /// <p>Lists the certificates registered in your AWS account.</p> <p>The results are paginated with a default page size of 25. You can use the returned marker to retrieve additional results.</p>
+    ///
+    /// See `Iot::list_certificates`.
+    pub fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
+        self.runtime.block_on(self.client.list_certificates(input))
+    }
+
+    /// <p>List the device certificates signed by the specified CA certificate.</p>
+    ///
+    /// See `Iot::list_certificates_by_ca`.
+    pub fn list_certificates_by_ca(
+        &self,
+        input: ListCertificatesByCARequest,
+    ) -> Result<ListCertificatesByCAResponse, RusotoError<ListCertificatesByCAError>> {
+        self.runtime
+            .block_on(self.client.list_certificates_by_ca(input))
+    }
+
+    /// <p>List the set of dimensions that are defined for your AWS account.</p>
+    ///
+    /// See `Iot::list_dimensions`.
+    pub fn list_dimensions(
+        &self,
+        input: ListDimensionsRequest,
+    ) -> Result<ListDimensionsResponse, RusotoError<ListDimensionsError>> {
+        self.runtime.block_on(self.client.list_dimensions(input))
+    }
+
+    /// <p><p>Gets a list of domain configurations for the user. This list is sorted alphabetically by domain configuration name.</p> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
+    ///
+    /// See `Iot::list_domain_configurations`.
+    pub fn list_domain_configurations(
+        &self,
+        input: ListDomainConfigurationsRequest,
+    ) -> Result<ListDomainConfigurationsResponse, RusotoError<ListDomainConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_domain_configurations(input))
+    }
+
+    /// <p>Lists the search indices.</p>
+    ///
+    /// See `Iot::list_indices`.
+    pub fn list_indices(
+        &self,
+        input: ListIndicesRequest,
+    ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
+        self.runtime.block_on(self.client.list_indices(input))
+    }
+
+    /// <p>Lists the job executions for a job.</p>
+    ///
+    /// See `Iot::list_job_executions_for_job`.
+    pub fn list_job_executions_for_job(
+        &self,
+        input: ListJobExecutionsForJobRequest,
+    ) -> Result<ListJobExecutionsForJobResponse, RusotoError<ListJobExecutionsForJobError>> {
+        self.runtime
+            .block_on(self.client.list_job_executions_for_job(input))
+    }
+
+    /// <p>Lists the job executions for the specified thing.</p>
+    ///
+    /// See `Iot::list_job_executions_for_thing`.
+    pub fn list_job_executions_for_thing(
+        &self,
+        input: ListJobExecutionsForThingRequest,
+    ) -> Result<ListJobExecutionsForThingResponse, RusotoError<ListJobExecutionsForThingError>>
+    {
+        self.runtime
+            .block_on(self.client.list_job_executions_for_thing(input))
+    }
+
+    /// <p>Lists jobs.</p>
+    ///
+    /// See `Iot::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>Gets a list of all mitigation actions that match the specified filter criteria.</p>
+    ///
+    /// See `Iot::list_mitigation_actions`.
+    pub fn list_mitigation_actions(
+        &self,
+        input: ListMitigationActionsRequest,
+    ) -> Result<ListMitigationActionsResponse, RusotoError<ListMitigationActionsError>> {
+        self.runtime
+            .block_on(self.client.list_mitigation_actions(input))
+    }
+
+    /// <p>Lists OTA updates.</p>
+    ///
+    /// See `Iot::list_ota_updates`.
+    pub fn list_ota_updates(
+        &self,
+        input: ListOTAUpdatesRequest,
+    ) -> Result<ListOTAUpdatesResponse, RusotoError<ListOTAUpdatesError>> {
+        self.runtime.block_on(self.client.list_ota_updates(input))
+    }
+
+    /// <p>Lists certificates that are being transferred but not yet accepted.</p>
+    ///
+    /// See `Iot::list_outgoing_certificates`.
+    pub fn list_outgoing_certificates(
+        &self,
+        input: ListOutgoingCertificatesRequest,
+    ) -> Result<ListOutgoingCertificatesResponse, RusotoError<ListOutgoingCertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_outgoing_certificates(input))
+    }
+
+    /// <p>Lists your policies.</p>
+    ///
+    /// See `Iot::list_policies`.
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// <p>Lists the principals associated with the specified policy.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>ListTargetsForPolicy</a> instead.</p>
+    ///
+    /// See `Iot::list_policy_principals`.
+    pub fn list_policy_principals(
+        &self,
+        input: ListPolicyPrincipalsRequest,
+    ) -> Result<ListPolicyPrincipalsResponse, RusotoError<ListPolicyPrincipalsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_principals(input))
+    }
+
+    /// <p>Lists the versions of the specified policy and identifies the default version.</p>
+    ///
+    /// See `Iot::list_policy_versions`.
+    pub fn list_policy_versions(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_versions(input))
+    }
+
+    /// <p>Lists the policies attached to the specified principal. If you use an Cognito identity, the ID must be in <a href="https://docs.aws.amazon.com/cognitoidentity/latest/APIReference/API_GetCredentialsForIdentity.html#API_GetCredentialsForIdentity_RequestSyntax">AmazonCognito Identity format</a>.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>ListAttachedPolicies</a> instead.</p>
+    ///
+    /// See `Iot::list_principal_policies`.
+    pub fn list_principal_policies(
+        &self,
+        input: ListPrincipalPoliciesRequest,
+    ) -> Result<ListPrincipalPoliciesResponse, RusotoError<ListPrincipalPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_principal_policies(input))
+    }
+
+    /// <p>Lists the things associated with the specified principal. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities. </p>
+    ///
+    /// See `Iot::list_principal_things`.
+    pub fn list_principal_things(
+        &self,
+        input: ListPrincipalThingsRequest,
+    ) -> Result<ListPrincipalThingsResponse, RusotoError<ListPrincipalThingsError>> {
+        self.runtime
+            .block_on(self.client.list_principal_things(input))
+    }
+
+    /// <p>A list of fleet provisioning template versions.</p>
+    ///
+    /// See `Iot::list_provisioning_template_versions`.
+    pub fn list_provisioning_template_versions(
+        &self,
+        input: ListProvisioningTemplateVersionsRequest,
+    ) -> Result<
+        ListProvisioningTemplateVersionsResponse,
+        RusotoError<ListProvisioningTemplateVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_provisioning_template_versions(input))
+    }
+
+    /// <p>Lists the fleet provisioning templates in your AWS account.</p>
+    ///
+    /// See `Iot::list_provisioning_templates`.
+    pub fn list_provisioning_templates(
+        &self,
+        input: ListProvisioningTemplatesRequest,
+    ) -> Result<ListProvisioningTemplatesResponse, RusotoError<ListProvisioningTemplatesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_provisioning_templates(input))
+    }
+
+    /// <p>Lists the role aliases registered in your account.</p>
+    ///
+    /// See `Iot::list_role_aliases`.
+    pub fn list_role_aliases(
+        &self,
+        input: ListRoleAliasesRequest,
+    ) -> Result<ListRoleAliasesResponse, RusotoError<ListRoleAliasesError>> {
+        self.runtime.block_on(self.client.list_role_aliases(input))
+    }
+
+    /// <p>Lists all of your scheduled audits.</p>
+    ///
+    /// See `Iot::list_scheduled_audits`.
+    pub fn list_scheduled_audits(
+        &self,
+        input: ListScheduledAuditsRequest,
+    ) -> Result<ListScheduledAuditsResponse, RusotoError<ListScheduledAuditsError>> {
+        self.runtime
+            .block_on(self.client.list_scheduled_audits(input))
+    }
+
+    /// <p>Lists the Device Defender security profiles you have created. You can use filters to list only those security profiles associated with a thing group or only those associated with your account.</p>
+    ///
+    /// See `Iot::list_security_profiles`.
+    pub fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_security_profiles(input))
+    }
+
+    /// <p>Lists the Device Defender security profiles attached to a target (thing group).</p>
+    ///
+    /// See `Iot::list_security_profiles_for_target`.
+    pub fn list_security_profiles_for_target(
+        &self,
+        input: ListSecurityProfilesForTargetRequest,
+    ) -> Result<
+        ListSecurityProfilesForTargetResponse,
+        RusotoError<ListSecurityProfilesForTargetError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_security_profiles_for_target(input))
+    }
+
+    /// <p>Lists all of the streams in your AWS account.</p>
+    ///
+    /// See `Iot::list_streams`.
+    pub fn list_streams(
+        &self,
+        input: ListStreamsRequest,
+    ) -> Result<ListStreamsResponse, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+
+    /// <p>Lists the tags (metadata) you have assigned to the resource.</p>
+    ///
+    /// See `Iot::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>List targets for the specified policy.</p>
+    ///
+    /// See `Iot::list_targets_for_policy`.
+    pub fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
+        self.runtime
+            .block_on(self.client.list_targets_for_policy(input))
+    }
+
+    /// <p>Lists the targets (thing groups) associated with a given Device Defender security profile.</p>
+    ///
+    /// See `Iot::list_targets_for_security_profile`.
+    pub fn list_targets_for_security_profile(
+        &self,
+        input: ListTargetsForSecurityProfileRequest,
+    ) -> Result<
+        ListTargetsForSecurityProfileResponse,
+        RusotoError<ListTargetsForSecurityProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_targets_for_security_profile(input))
+    }
+
+    /// <p>List the thing groups in your account.</p>
+    ///
+    /// See `Iot::list_thing_groups`.
+    pub fn list_thing_groups(
+        &self,
+        input: ListThingGroupsRequest,
+    ) -> Result<ListThingGroupsResponse, RusotoError<ListThingGroupsError>> {
+        self.runtime.block_on(self.client.list_thing_groups(input))
+    }
+
+    /// <p>List the thing groups to which the specified thing belongs.</p>
+    ///
+    /// See `Iot::list_thing_groups_for_thing`.
+    pub fn list_thing_groups_for_thing(
+        &self,
+        input: ListThingGroupsForThingRequest,
+    ) -> Result<ListThingGroupsForThingResponse, RusotoError<ListThingGroupsForThingError>> {
+        self.runtime
+            .block_on(self.client.list_thing_groups_for_thing(input))
+    }
+
+    /// <p>Lists the principals associated with the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p>
+    ///
+    /// See `Iot::list_thing_principals`.
+    pub fn list_thing_principals(
+        &self,
+        input: ListThingPrincipalsRequest,
+    ) -> Result<ListThingPrincipalsResponse, RusotoError<ListThingPrincipalsError>> {
+        self.runtime
+            .block_on(self.client.list_thing_principals(input))
+    }
+
+    /// <p>Information about the thing registration tasks.</p>
+    ///
+    /// See `Iot::list_thing_registration_task_reports`.
+    pub fn list_thing_registration_task_reports(
+        &self,
+        input: ListThingRegistrationTaskReportsRequest,
+    ) -> Result<
+        ListThingRegistrationTaskReportsResponse,
+        RusotoError<ListThingRegistrationTaskReportsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_thing_registration_task_reports(input))
+    }
+
+    /// <p>List bulk thing provisioning tasks.</p>
+    ///
+    /// See `Iot::list_thing_registration_tasks`.
+    pub fn list_thing_registration_tasks(
+        &self,
+        input: ListThingRegistrationTasksRequest,
+    ) -> Result<ListThingRegistrationTasksResponse, RusotoError<ListThingRegistrationTasksError>>
+    {
+        self.runtime
+            .block_on(self.client.list_thing_registration_tasks(input))
+    }
+
+    /// <p>Lists the existing thing types.</p>
+    ///
+    /// See `Iot::list_thing_types`.
+    pub fn list_thing_types(
+        &self,
+        input: ListThingTypesRequest,
+    ) -> Result<ListThingTypesResponse, RusotoError<ListThingTypesError>> {
+        self.runtime.block_on(self.client.list_thing_types(input))
+    }
+
+    /// <p>Lists your things. Use the <b>attributeName</b> and <b>attributeValue</b> parameters to filter your things. For example, calling <code>ListThings</code> with attributeName=Color and attributeValue=Red retrieves all things in the registry that contain an attribute <b>Color</b> with the value <b>Red</b>. </p>
+    ///
+    /// See `Iot::list_things`.
+    pub fn list_things(
+        &self,
+        input: ListThingsRequest,
+    ) -> Result<ListThingsResponse, RusotoError<ListThingsError>> {
+        self.runtime.block_on(self.client.list_things(input))
+    }
+
+    /// <p>Lists the things you have added to the given billing group.</p>
+    ///
+    /// See `Iot::list_things_in_billing_group`.
+    pub fn list_things_in_billing_group(
+        &self,
+        input: ListThingsInBillingGroupRequest,
+    ) -> Result<ListThingsInBillingGroupResponse, RusotoError<ListThingsInBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.list_things_in_billing_group(input))
+    }
+
+    /// <p>Lists the things in the specified group.</p>
+    ///
+    /// See `Iot::list_things_in_thing_group`.
+    pub fn list_things_in_thing_group(
+        &self,
+        input: ListThingsInThingGroupRequest,
+    ) -> Result<ListThingsInThingGroupResponse, RusotoError<ListThingsInThingGroupError>> {
+        self.runtime
+            .block_on(self.client.list_things_in_thing_group(input))
+    }
+
+    /// <p>Lists all the topic rule destinations in your AWS account.</p>
+    ///
+    /// See `Iot::list_topic_rule_destinations`.
+    pub fn list_topic_rule_destinations(
+        &self,
+        input: ListTopicRuleDestinationsRequest,
+    ) -> Result<ListTopicRuleDestinationsResponse, RusotoError<ListTopicRuleDestinationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_topic_rule_destinations(input))
+    }
+
+    /// <p>Lists the rules for the specific topic.</p>
+    ///
+    /// See `Iot::list_topic_rules`.
+    pub fn list_topic_rules(
+        &self,
+        input: ListTopicRulesRequest,
+    ) -> Result<ListTopicRulesResponse, RusotoError<ListTopicRulesError>> {
+        self.runtime.block_on(self.client.list_topic_rules(input))
+    }
+
+    /// <p>Lists logging levels.</p>
+    ///
+    /// See `Iot::list_v2_logging_levels`.
+    pub fn list_v2_logging_levels(
+        &self,
+        input: ListV2LoggingLevelsRequest,
+    ) -> Result<ListV2LoggingLevelsResponse, RusotoError<ListV2LoggingLevelsError>> {
+        self.runtime
+            .block_on(self.client.list_v2_logging_levels(input))
+    }
+
+    /// <p>Lists the Device Defender security profile violations discovered during the given time period. You can use filters to limit the results to those alerts issued for a particular security profile, behavior, or thing (device).</p>
+    ///
+    /// See `Iot::list_violation_events`.
+    pub fn list_violation_events(
+        &self,
+        input: ListViolationEventsRequest,
+    ) -> Result<ListViolationEventsResponse, RusotoError<ListViolationEventsError>> {
+        self.runtime
+            .block_on(self.client.list_violation_events(input))
+    }
+
+    /// <p>Registers a CA certificate with AWS IoT. This CA certificate can then be used to sign device certificates, which can be then registered with AWS IoT. You can register up to 10 CA certificates per AWS account that have the same subject field. This enables you to have up to 10 certificate authorities sign your device certificates. If you have more than one CA certificate registered, make sure you pass the CA certificate when you register your device certificates with the RegisterCertificate API.</p>
+    ///
+    /// See `Iot::register_ca_certificate`.
+    pub fn register_ca_certificate(
+        &self,
+        input: RegisterCACertificateRequest,
+    ) -> Result<RegisterCACertificateResponse, RusotoError<RegisterCACertificateError>> {
+        self.runtime
+            .block_on(self.client.register_ca_certificate(input))
+    }
+
+    /// <p>Registers a device certificate with AWS IoT. If you have more than one CA certificate that has the same subject field, you must specify the CA certificate that was used to sign the device certificate being registered.</p>
+    ///
+    /// See `Iot::register_certificate`.
+    pub fn register_certificate(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> Result<RegisterCertificateResponse, RusotoError<RegisterCertificateError>> {
+        self.runtime
+            .block_on(self.client.register_certificate(input))
+    }
+
+    /// <p>Register a certificate that does not have a certificate authority (CA).</p>
+    ///
+    /// See `Iot::register_certificate_without_ca`.
+    pub fn register_certificate_without_ca(
+        &self,
+        input: RegisterCertificateWithoutCARequest,
+    ) -> Result<RegisterCertificateWithoutCAResponse, RusotoError<RegisterCertificateWithoutCAError>>
+    {
+        self.runtime
+            .block_on(self.client.register_certificate_without_ca(input))
+    }
+
+    /// <p>Provisions a thing in the device registry. RegisterThing calls other AWS IoT control plane APIs. These calls might exceed your account level <a href="https://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_iot"> AWS IoT Throttling Limits</a> and cause throttle errors. Please contact <a href="https://console.aws.amazon.com/support/home">AWS Customer Support</a> to raise your throttling limits if necessary.</p>
+    ///
+    /// See `Iot::register_thing`.
+    pub fn register_thing(
+        &self,
+        input: RegisterThingRequest,
+    ) -> Result<RegisterThingResponse, RusotoError<RegisterThingError>> {
+        self.runtime.block_on(self.client.register_thing(input))
+    }
+
+    /// <p>Rejects a pending certificate transfer. After AWS IoT rejects a certificate transfer, the certificate status changes from <b>PENDING_TRANSFER</b> to <b>INACTIVE</b>.</p> <p>To check for pending certificate transfers, call <a>ListCertificates</a> to enumerate your certificates.</p> <p>This operation can only be called by the transfer destination. After it is called, the certificate will be returned to the source's account in the INACTIVE state.</p>
+    ///
+    /// See `Iot::reject_certificate_transfer`.
+    pub fn reject_certificate_transfer(
+        &self,
+        input: RejectCertificateTransferRequest,
+    ) -> Result<(), RusotoError<RejectCertificateTransferError>> {
+        self.runtime
+            .block_on(self.client.reject_certificate_transfer(input))
+    }
+
+    /// <p>Removes the given thing from the billing group.</p>
+    ///
+    /// See `Iot::remove_thing_from_billing_group`.
+    pub fn remove_thing_from_billing_group(
+        &self,
+        input: RemoveThingFromBillingGroupRequest,
+    ) -> Result<RemoveThingFromBillingGroupResponse, RusotoError<RemoveThingFromBillingGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_thing_from_billing_group(input))
+    }
+
+    /// <p>Remove the specified thing from the specified group.</p> <p>You must specify either a <code>thingGroupArn</code> or a <code>thingGroupName</code> to identify the thing group and either a <code>thingArn</code> or a <code>thingName</code> to identify the thing to remove from the thing group. </p>
+    ///
+    /// See `Iot::remove_thing_from_thing_group`.
+    pub fn remove_thing_from_thing_group(
+        &self,
+        input: RemoveThingFromThingGroupRequest,
+    ) -> Result<RemoveThingFromThingGroupResponse, RusotoError<RemoveThingFromThingGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_thing_from_thing_group(input))
+    }
+
+    /// <p>Replaces the rule. You must specify all parameters for the new rule. Creating rules is an administrator-level action. Any user who has permission to create rules will be able to access data processed by the rule.</p>
+    ///
+    /// See `Iot::replace_topic_rule`.
+    pub fn replace_topic_rule(
+        &self,
+        input: ReplaceTopicRuleRequest,
+    ) -> Result<(), RusotoError<ReplaceTopicRuleError>> {
+        self.runtime.block_on(self.client.replace_topic_rule(input))
+    }
+
+    /// <p>The query search index.</p>
+    ///
+    /// See `Iot::search_index`.
+    pub fn search_index(
+        &self,
+        input: SearchIndexRequest,
+    ) -> Result<SearchIndexResponse, RusotoError<SearchIndexError>> {
+        self.runtime.block_on(self.client.search_index(input))
+    }
+
+    /// <p>Sets the default authorizer. This will be used if a websocket connection is made without specifying an authorizer.</p>
+    ///
+    /// See `Iot::set_default_authorizer`.
+    pub fn set_default_authorizer(
+        &self,
+        input: SetDefaultAuthorizerRequest,
+    ) -> Result<SetDefaultAuthorizerResponse, RusotoError<SetDefaultAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.set_default_authorizer(input))
+    }
+
+    /// <p>Sets the specified version of the specified policy as the policy's default (operative) version. This action affects all certificates to which the policy is attached. To list the principals the policy is attached to, use the ListPrincipalPolicy API.</p>
+    ///
+    /// See `Iot::set_default_policy_version`.
+    pub fn set_default_policy_version(
+        &self,
+        input: SetDefaultPolicyVersionRequest,
+    ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.set_default_policy_version(input))
+    }
+
+    /// <p>Sets the logging options.</p> <p>NOTE: use of this command is not recommended. Use <code>SetV2LoggingOptions</code> instead.</p>
+    ///
+    /// See `Iot::set_logging_options`.
+    pub fn set_logging_options(
+        &self,
+        input: SetLoggingOptionsRequest,
+    ) -> Result<(), RusotoError<SetLoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.set_logging_options(input))
+    }
+
+    /// <p>Sets the logging level.</p>
+    ///
+    /// See `Iot::set_v2_logging_level`.
+    pub fn set_v2_logging_level(
+        &self,
+        input: SetV2LoggingLevelRequest,
+    ) -> Result<(), RusotoError<SetV2LoggingLevelError>> {
+        self.runtime
+            .block_on(self.client.set_v2_logging_level(input))
+    }
+
+    /// <p>Sets the logging options for the V2 logging service.</p>
+    ///
+    /// See `Iot::set_v2_logging_options`.
+    pub fn set_v2_logging_options(
+        &self,
+        input: SetV2LoggingOptionsRequest,
+    ) -> Result<(), RusotoError<SetV2LoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.set_v2_logging_options(input))
+    }
+
+    /// <p>Starts a task that applies a set of mitigation actions to the specified target.</p>
+    ///
+    /// See `Iot::start_audit_mitigation_actions_task`.
+    pub fn start_audit_mitigation_actions_task(
+        &self,
+        input: StartAuditMitigationActionsTaskRequest,
+    ) -> Result<
+        StartAuditMitigationActionsTaskResponse,
+        RusotoError<StartAuditMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_audit_mitigation_actions_task(input))
+    }
+
+    /// <p>Starts an on-demand Device Defender audit.</p>
+    ///
+    /// See `Iot::start_on_demand_audit_task`.
+    pub fn start_on_demand_audit_task(
+        &self,
+        input: StartOnDemandAuditTaskRequest,
+    ) -> Result<StartOnDemandAuditTaskResponse, RusotoError<StartOnDemandAuditTaskError>> {
+        self.runtime
+            .block_on(self.client.start_on_demand_audit_task(input))
+    }
+
+    /// <p>Creates a bulk thing provisioning task.</p>
+    ///
+    /// See `Iot::start_thing_registration_task`.
+    pub fn start_thing_registration_task(
+        &self,
+        input: StartThingRegistrationTaskRequest,
+    ) -> Result<StartThingRegistrationTaskResponse, RusotoError<StartThingRegistrationTaskError>>
+    {
+        self.runtime
+            .block_on(self.client.start_thing_registration_task(input))
+    }
+
+    /// <p>Cancels a bulk thing provisioning task.</p>
+    ///
+    /// See `Iot::stop_thing_registration_task`.
+    pub fn stop_thing_registration_task(
+        &self,
+        input: StopThingRegistrationTaskRequest,
+    ) -> Result<StopThingRegistrationTaskResponse, RusotoError<StopThingRegistrationTaskError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_thing_registration_task(input))
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+    ///
+    /// See `Iot::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Tests if a specified principal is authorized to perform an AWS IoT action on a specified resource. Use this to test and debug the authorization behavior of devices that connect to the AWS IoT device gateway.</p>
+    ///
+    /// See `Iot::test_authorization`.
+    pub fn test_authorization(
+        &self,
+        input: TestAuthorizationRequest,
+    ) -> Result<TestAuthorizationResponse, RusotoError<TestAuthorizationError>> {
+        self.runtime.block_on(self.client.test_authorization(input))
+    }
+
+    /// <p>Tests a custom authorization behavior by invoking a specified custom authorizer. Use this to test and debug the custom authorization behavior of devices that connect to the AWS IoT device gateway.</p>
+    ///
+    /// See `Iot::test_invoke_authorizer`.
+    pub fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> Result<TestInvokeAuthorizerResponse, RusotoError<TestInvokeAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.test_invoke_authorizer(input))
+    }
+
+    /// <p>Transfers the specified certificate to the specified AWS account.</p> <p>You can cancel the transfer until it is acknowledged by the recipient.</p> <p>No notification is sent to the transfer destination's account. It is up to the caller to notify the transfer target.</p> <p>The certificate being transferred must not be in the ACTIVE state. You can use the UpdateCertificate API to deactivate it.</p> <p>The certificate must not have any policies attached to it. You can use the DetachPrincipalPolicy API to detach them.</p>
+    ///
+    /// See `Iot::transfer_certificate`.
+    pub fn transfer_certificate(
+        &self,
+        input: TransferCertificateRequest,
+    ) -> Result<TransferCertificateResponse, RusotoError<TransferCertificateError>> {
+        self.runtime
+            .block_on(self.client.transfer_certificate(input))
+    }
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    ///
+    /// See `Iot::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Configures or reconfigures the Device Defender audit settings for this account. Settings include how audit notifications are sent and which audit checks are enabled or disabled.</p>
+    ///
+    /// See `Iot::update_account_audit_configuration`.
+    pub fn update_account_audit_configuration(
+        &self,
+        input: UpdateAccountAuditConfigurationRequest,
+    ) -> Result<
+        UpdateAccountAuditConfigurationResponse,
+        RusotoError<UpdateAccountAuditConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_account_audit_configuration(input))
+    }
+
+    /// <p>Updates an authorizer.</p>
+    ///
+    /// See `Iot::update_authorizer`.
+    pub fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<UpdateAuthorizerResponse, RusotoError<UpdateAuthorizerError>> {
+        self.runtime.block_on(self.client.update_authorizer(input))
+    }
+
+    /// <p>Updates information about the billing group.</p>
+    ///
+    /// See `Iot::update_billing_group`.
+    pub fn update_billing_group(
+        &self,
+        input: UpdateBillingGroupRequest,
+    ) -> Result<UpdateBillingGroupResponse, RusotoError<UpdateBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_billing_group(input))
+    }
+
+    /// <p>Updates a registered CA certificate.</p>
+    ///
+    /// See `Iot::update_ca_certificate`.
+    pub fn update_ca_certificate(
+        &self,
+        input: UpdateCACertificateRequest,
+    ) -> Result<(), RusotoError<UpdateCACertificateError>> {
+        self.runtime
+            .block_on(self.client.update_ca_certificate(input))
+    }
+
+    /// <p>Updates the status of the specified certificate. This operation is idempotent.</p> <p>Moving a certificate from the ACTIVE state (including REVOKED) will not disconnect currently connected devices, but these devices will be unable to reconnect.</p> <p>The ACTIVE state is required to authenticate devices connecting to AWS IoT using a certificate.</p>
+    ///
+    /// See `Iot::update_certificate`.
+    pub fn update_certificate(
+        &self,
+        input: UpdateCertificateRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateError>> {
+        self.runtime.block_on(self.client.update_certificate(input))
+    }
+
+    /// <p>Updates the definition for a dimension. You cannot change the type of a dimension after it is created (you can delete it and re-create it).</p>
+    ///
+    /// See `Iot::update_dimension`.
+    pub fn update_dimension(
+        &self,
+        input: UpdateDimensionRequest,
+    ) -> Result<UpdateDimensionResponse, RusotoError<UpdateDimensionError>> {
+        self.runtime.block_on(self.client.update_dimension(input))
+    }
+
+    /// <p><p>Updates values stored in the domain configuration. Domain configurations for default endpoints can&#39;t be updated.</p> <note> <p>The domain configuration feature is in public preview and is subject to change.</p> </note></p>
+    ///
+    /// See `Iot::update_domain_configuration`.
+    pub fn update_domain_configuration(
+        &self,
+        input: UpdateDomainConfigurationRequest,
+    ) -> Result<UpdateDomainConfigurationResponse, RusotoError<UpdateDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_domain_configuration(input))
+    }
+
+    /// <p>Updates a dynamic thing group.</p>
+    ///
+    /// See `Iot::update_dynamic_thing_group`.
+    pub fn update_dynamic_thing_group(
+        &self,
+        input: UpdateDynamicThingGroupRequest,
+    ) -> Result<UpdateDynamicThingGroupResponse, RusotoError<UpdateDynamicThingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_dynamic_thing_group(input))
+    }
+
+    /// <p>Updates the event configurations.</p>
+    ///
+    /// See `Iot::update_event_configurations`.
+    pub fn update_event_configurations(
+        &self,
+        input: UpdateEventConfigurationsRequest,
+    ) -> Result<UpdateEventConfigurationsResponse, RusotoError<UpdateEventConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_event_configurations(input))
+    }
+
+    /// <p>Updates the search configuration.</p>
+    ///
+    /// See `Iot::update_indexing_configuration`.
+    pub fn update_indexing_configuration(
+        &self,
+        input: UpdateIndexingConfigurationRequest,
+    ) -> Result<UpdateIndexingConfigurationResponse, RusotoError<UpdateIndexingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_indexing_configuration(input))
+    }
+
+    /// <p>Updates supported fields of the specified job.</p>
+    ///
+    /// See `Iot::update_job`.
+    pub fn update_job(&self, input: UpdateJobRequest) -> Result<(), RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+
+    /// <p>Updates the definition for the specified mitigation action.</p>
+    ///
+    /// See `Iot::update_mitigation_action`.
+    pub fn update_mitigation_action(
+        &self,
+        input: UpdateMitigationActionRequest,
+    ) -> Result<UpdateMitigationActionResponse, RusotoError<UpdateMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.update_mitigation_action(input))
+    }
+
+    /// <p>Updates a fleet provisioning template.</p>
+    ///
+    /// See `Iot::update_provisioning_template`.
+    pub fn update_provisioning_template(
+        &self,
+        input: UpdateProvisioningTemplateRequest,
+    ) -> Result<UpdateProvisioningTemplateResponse, RusotoError<UpdateProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.update_provisioning_template(input))
+    }
+
+    /// <p>Updates a role alias.</p>
+    ///
+    /// See `Iot::update_role_alias`.
+    pub fn update_role_alias(
+        &self,
+        input: UpdateRoleAliasRequest,
+    ) -> Result<UpdateRoleAliasResponse, RusotoError<UpdateRoleAliasError>> {
+        self.runtime.block_on(self.client.update_role_alias(input))
+    }
+
+    /// <p>Updates a scheduled audit, including which checks are performed and how often the audit takes place.</p>
+    ///
+    /// See `Iot::update_scheduled_audit`.
+    pub fn update_scheduled_audit(
+        &self,
+        input: UpdateScheduledAuditRequest,
+    ) -> Result<UpdateScheduledAuditResponse, RusotoError<UpdateScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.update_scheduled_audit(input))
+    }
+
+    /// <p>Updates a Device Defender security profile.</p>
+    ///
+    /// See `Iot::update_security_profile`.
+    pub fn update_security_profile(
+        &self,
+        input: UpdateSecurityProfileRequest,
+    ) -> Result<UpdateSecurityProfileResponse, RusotoError<UpdateSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.update_security_profile(input))
+    }
+
+    /// <p>Updates an existing stream. The stream version will be incremented by one.</p>
+    ///
+    /// See `Iot::update_stream`.
+    pub fn update_stream(
+        &self,
+        input: UpdateStreamRequest,
+    ) -> Result<UpdateStreamResponse, RusotoError<UpdateStreamError>> {
+        self.runtime.block_on(self.client.update_stream(input))
+    }
+
+    /// <p>Updates the data for a thing.</p>
+    ///
+    /// See `Iot::update_thing`.
+    pub fn update_thing(
+        &self,
+        input: UpdateThingRequest,
+    ) -> Result<UpdateThingResponse, RusotoError<UpdateThingError>> {
+        self.runtime.block_on(self.client.update_thing(input))
+    }
+
+    /// <p>Update a thing group.</p>
+    ///
+    /// See `Iot::update_thing_group`.
+    pub fn update_thing_group(
+        &self,
+        input: UpdateThingGroupRequest,
+    ) -> Result<UpdateThingGroupResponse, RusotoError<UpdateThingGroupError>> {
+        self.runtime.block_on(self.client.update_thing_group(input))
+    }
+
+    /// <p>Updates the groups to which the thing belongs.</p>
+    ///
+    /// See `Iot::update_thing_groups_for_thing`.
+    pub fn update_thing_groups_for_thing(
+        &self,
+        input: UpdateThingGroupsForThingRequest,
+    ) -> Result<UpdateThingGroupsForThingResponse, RusotoError<UpdateThingGroupsForThingError>>
+    {
+        self.runtime
+            .block_on(self.client.update_thing_groups_for_thing(input))
+    }
+
+    /// <p>Updates a topic rule destination. You use this to change the status, endpoint URL, or confirmation URL of the destination.</p>
+    ///
+    /// See `Iot::update_topic_rule_destination`.
+    pub fn update_topic_rule_destination(
+        &self,
+        input: UpdateTopicRuleDestinationRequest,
+    ) -> Result<UpdateTopicRuleDestinationResponse, RusotoError<UpdateTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_topic_rule_destination(input))
+    }
+
+    /// <p>Validates a Device Defender security profile behaviors specification.</p>
+    ///
+    /// See `Iot::validate_security_profile_behaviors`.
+    pub fn validate_security_profile_behaviors(
+        &self,
+        input: ValidateSecurityProfileBehaviorsRequest,
+    ) -> Result<
+        ValidateSecurityProfileBehaviorsResponse,
+        RusotoError<ValidateSecurityProfileBehaviorsError>,
+    > {
+        self.runtime
+            .block_on(self.client.validate_security_profile_behaviors(input))
+    }
+}
@@ -397,17 +397,22 @@ impl ClaimDevicesByClaimCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ClaimDevicesByClaimCodeError::Forbidden(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClaimDevicesByClaimCodeError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ClaimDevicesByClaimCodeError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -442,13 +447,19 @@ impl DescribeDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDeviceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeviceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeDeviceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -487,25 +498,34 @@ impl FinalizeDeviceClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FinalizeDeviceClaimError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FinalizeDeviceClaimError::InvalidRequest(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FinalizeDeviceClaimError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FinalizeDeviceClaimError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        FinalizeDeviceClaimError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -542,13 +562,22 @@ impl GetDeviceMethodsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceMethodsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceMethodsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDeviceMethodsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -585,20 +614,28 @@ impl InitiateDeviceClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateDeviceClaimError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InitiateDeviceClaimError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateDeviceClaimError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InitiateDeviceClaimError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -640,26 +677,40 @@ impl InvokeDeviceMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeDeviceMethodError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeDeviceMethodError::InvalidRequest(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InvokeDeviceMethodError::PreconditionFailed(err.msg),
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::RangeNotSatisfiable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InvokeDeviceMethodError::RangeNotSatisfiable(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeDeviceMethodError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeDeviceMethodError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -699,18 +750,28 @@ impl ListDeviceEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDeviceEventsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceEventsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDeviceEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceEventsError::InvalidRequest(err.msg),
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(ListDeviceEventsError::RangeNotSatisfiable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceEventsError::RangeNotSatisfiable(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeviceEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeviceEventsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -746,13 +807,16 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDevicesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::InvalidRequest(err.msg))
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(ListDevicesError::RangeNotSatisfiable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevicesError::RangeNotSatisfiable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -785,12 +849,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -824,13 +892,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -865,13 +933,16 @@ impl UnclaimDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UnclaimDeviceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UnclaimDeviceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UnclaimDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UnclaimDeviceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnclaimDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnclaimDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -906,13 +977,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -947,13 +1021,22 @@ impl UpdateDeviceStateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDeviceStateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1089,6 +1172,7 @@ impl Iot1ClickDevicesClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1104,6 +1188,7 @@ impl Iot1ClickDevicesClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickDevicesClient {
         Iot1ClickDevicesClient { client, region }
     }
@@ -1121,6 +1206,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/claims/{claim_code}", claim_code = input.claim_code);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ClaimDevicesByClaimCode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1152,6 +1240,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("DescribeDevice");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1190,6 +1281,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("FinalizeDeviceClaim");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1222,6 +1316,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("GetDeviceMethods");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1260,6 +1357,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("InitiateDeviceClaim");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1291,6 +1391,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("InvokeDeviceMethod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1324,6 +1427,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/events", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ListDeviceEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1365,6 +1471,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = "/devices";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ListDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1407,6 +1516,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1438,6 +1550,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1469,6 +1584,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/unclaim", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("UnclaimDevice");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1500,6 +1618,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1536,6 +1657,9 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/state", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("UpdateDeviceState");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1559,3 +1683,209 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT 1-Click Devices Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Iot1ClickDevicesClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Iot1ClickDevicesBlockingClient {
+    client: Iot1ClickDevicesClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Iot1ClickDevicesBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Iot1ClickDevicesBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Iot1ClickDevicesBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Iot1ClickDevicesBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Iot1ClickDevicesBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient {
+            client: Iot1ClickDevicesClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds device(s) to your account (i.e., claim one or more devices) if and only if you
+    /// received a claim code with the device(s).</p>
+    ///
+    /// See `Iot1ClickDevices::claim_devices_by_claim_code`.
+    pub fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> Result<ClaimDevicesByClaimCodeResponse, RusotoError<ClaimDevicesByClaimCodeError>> {
+        self.runtime
+            .block_on(self.client.claim_devices_by_claim_code(input))
+    }
+
+    /// <p>Given a device ID, returns a DescribeDeviceResponse object describing the
+    /// details of the device.</p>
+    ///
+    /// See `Iot1ClickDevices::describe_device`.
+    pub fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+        self.runtime.block_on(self.client.describe_device(input))
+    }
+
+    /// <p>Given a device ID, finalizes the claim request for the associated device.</p><note>
+    /// <p>Claiming a device consists of initiating a claim, then publishing a device event,
+    /// and finalizing the claim. For a device of type button, a device event can
+    /// be published by simply clicking the device.</p>
+    ///
+    /// <p></note></p>
+    ///
+    /// See `Iot1ClickDevices::finalize_device_claim`.
+    pub fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> Result<FinalizeDeviceClaimResponse, RusotoError<FinalizeDeviceClaimError>> {
+        self.runtime
+            .block_on(self.client.finalize_device_claim(input))
+    }
+
+    /// <p>Given a device ID, returns the invokable methods associated with the device.</p>
+    ///
+    /// See `Iot1ClickDevices::get_device_methods`.
+    pub fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> Result<GetDeviceMethodsResponse, RusotoError<GetDeviceMethodsError>> {
+        self.runtime.block_on(self.client.get_device_methods(input))
+    }
+
+    /// <p>Given a device ID, initiates a claim request for the associated device.</p><note>
+    /// <p>Claiming a device consists of initiating a claim, then publishing a device event,
+    /// and finalizing the claim. For a device of type button, a device event can
+    /// be published by simply clicking the device.</p>
+    ///
+    /// <p></note></p>
+    ///
+    /// See `Iot1ClickDevices::initiate_device_claim`.
+    pub fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> Result<InitiateDeviceClaimResponse, RusotoError<InitiateDeviceClaimError>> {
+        self.runtime
+            .block_on(self.client.initiate_device_claim(input))
+    }
+
+    /// <p>Given a device ID, issues a request to invoke a named device method (with possible
+    /// parameters). See the "Example POST" code snippet below.</p>
+    ///
+    /// See `Iot1ClickDevices::invoke_device_method`.
+    pub fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> Result<InvokeDeviceMethodResponse, RusotoError<InvokeDeviceMethodError>> {
+        self.runtime
+            .block_on(self.client.invoke_device_method(input))
+    }
+
+    /// <p>Using a device ID, returns a DeviceEventsResponse object containing an
+    /// array of events for the device.</p>
+    ///
+    /// See `Iot1ClickDevices::list_device_events`.
+    pub fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+        self.runtime.block_on(self.client.list_device_events(input))
+    }
+
+    /// <p>Lists the 1-Click compatible devices associated with your AWS account.</p>
+    ///
+    /// See `Iot1ClickDevices::list_devices`.
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// <p>Lists the tags associated with the specified resource ARN.</p>
+    ///
+    /// See `Iot1ClickDevices::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Adds or updates the tags associated with the resource ARN. See <a href="https://docs.aws.amazon.com/iot-1-click/latest/developerguide/1click-appendix.html#1click-limits">AWS IoT 1-Click Service Limits</a> for the maximum number of tags allowed per
+    /// resource.</p>
+    ///
+    /// See `Iot1ClickDevices::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Disassociates a device from your AWS account using its device ID.</p>
+    ///
+    /// See `Iot1ClickDevices::unclaim_device`.
+    pub fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> Result<UnclaimDeviceResponse, RusotoError<UnclaimDeviceError>> {
+        self.runtime.block_on(self.client.unclaim_device(input))
+    }
+
+    /// <p>Using tag keys, deletes the tags (key/value pairs) associated with the specified
+    /// resource ARN.</p>
+    ///
+    /// See `Iot1ClickDevices::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Using a Boolean value (true or false), this operation
+    /// enables or disables the device given a device ID.</p>
+    ///
+    /// See `Iot1ClickDevices::update_device_state`.
+    pub fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> Result<UpdateDeviceStateResponse, RusotoError<UpdateDeviceStateError>> {
+        self.runtime
+            .block_on(self.client.update_device_state(input))
+    }
+}
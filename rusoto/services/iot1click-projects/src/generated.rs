@@ -466,22 +466,26 @@ impl AssociateDeviceWithPlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDeviceWithPlacementError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateDeviceWithPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDeviceWithPlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDeviceWithPlacementError::ResourceConflict(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDeviceWithPlacementError::ResourceNotFound(err.msg),
                     )
                 }
@@ -526,16 +530,28 @@ impl CreatePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlacementError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreatePlacementError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlacementError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePlacementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -572,13 +588,16 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::InvalidRequest(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ResourceConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -616,16 +635,28 @@ impl DeletePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePlacementError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePlacementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePlacementError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePlacementError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -664,16 +695,19 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -710,13 +744,22 @@ impl DescribePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlacementError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePlacementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -752,13 +795,22 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -798,22 +850,26 @@ impl DisassociateDeviceFromPlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDeviceFromPlacementError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDeviceFromPlacementError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDeviceFromPlacementError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDeviceFromPlacementError::TooManyRequests(err.msg),
                     )
                 }
@@ -860,19 +916,22 @@ impl GetDevicesInPlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDevicesInPlacementError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicesInPlacementError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDevicesInPlacementError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicesInPlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDevicesInPlacementError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDevicesInPlacementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -908,13 +967,19 @@ impl ListPlacementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPlacementsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPlacementsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPlacementsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListPlacementsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPlacementsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPlacementsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -948,10 +1013,10 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListProjectsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -986,15 +1051,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1030,13 +1102,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1072,13 +1144,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1116,16 +1191,28 @@ impl UpdatePlacementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdatePlacementError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePlacementError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdatePlacementError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePlacementError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePlacementError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePlacementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePlacementError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePlacementError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1164,16 +1251,19 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateProjectError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1314,6 +1404,7 @@ impl Iot1ClickProjectsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1329,6 +1420,7 @@ impl Iot1ClickProjectsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickProjectsClient {
         Iot1ClickProjectsClient { client, region }
     }
@@ -1351,6 +1443,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("AssociateDeviceWithPlacement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1386,6 +1481,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("CreatePlacement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1418,6 +1516,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("CreateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1454,6 +1555,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("DeletePlacement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1487,6 +1591,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("DeleteProject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1521,6 +1628,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("DescribePlacement");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1554,6 +1664,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("DescribeProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1592,6 +1705,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("DisassociateDeviceFromPlacement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1628,6 +1744,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("GetDevicesInPlacement");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1661,6 +1780,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ListPlacements");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1700,6 +1822,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = "/projects";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ListProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1739,6 +1864,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1769,6 +1897,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1801,6 +1932,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1841,6 +1975,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("UpdatePlacement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1876,6 +2013,9 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation_name("UpdateProject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("projects.iot1click".to_string());
@@ -1899,3 +2039,224 @@ impl Iot1ClickProjects for Iot1ClickProjectsClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT 1-Click Projects API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Iot1ClickProjectsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Iot1ClickProjectsBlockingClient {
+    client: Iot1ClickProjectsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Iot1ClickProjectsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Iot1ClickProjectsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Iot1ClickProjectsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Iot1ClickProjectsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Iot1ClickProjectsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Iot1ClickProjectsBlockingClient {
+        Iot1ClickProjectsBlockingClient {
+            client: Iot1ClickProjectsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a physical device with a placement.</p>
+    ///
+    /// See `Iot1ClickProjects::associate_device_with_placement`.
+    pub fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> Result<AssociateDeviceWithPlacementResponse, RusotoError<AssociateDeviceWithPlacementError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_device_with_placement(input))
+    }
+
+    /// <p>Creates an empty placement.</p>
+    ///
+    /// See `Iot1ClickProjects::create_placement`.
+    pub fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> Result<CreatePlacementResponse, RusotoError<CreatePlacementError>> {
+        self.runtime.block_on(self.client.create_placement(input))
+    }
+
+    /// <p>Creates an empty project with a placement template. A project contains zero or more placements that adhere to the placement template defined in the project.</p>
+    ///
+    /// See `Iot1ClickProjects::create_project`.
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// <p><p>Deletes a placement. To delete a placement, it must not have any devices associated with it.</p> <note> <p>When you delete a placement, all associated data becomes irretrievable.</p> </note></p>
+    ///
+    /// See `Iot1ClickProjects::delete_placement`.
+    pub fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> Result<DeletePlacementResponse, RusotoError<DeletePlacementError>> {
+        self.runtime.block_on(self.client.delete_placement(input))
+    }
+
+    /// <p><p>Deletes a project. To delete a project, it must not have any placements associated with it.</p> <note> <p>When you delete a project, all associated data becomes irretrievable.</p> </note></p>
+    ///
+    /// See `Iot1ClickProjects::delete_project`.
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// <p>Describes a placement in a project.</p>
+    ///
+    /// See `Iot1ClickProjects::describe_placement`.
+    pub fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> Result<DescribePlacementResponse, RusotoError<DescribePlacementError>> {
+        self.runtime.block_on(self.client.describe_placement(input))
+    }
+
+    /// <p>Returns an object describing a project.</p>
+    ///
+    /// See `Iot1ClickProjects::describe_project`.
+    pub fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResponse, RusotoError<DescribeProjectError>> {
+        self.runtime.block_on(self.client.describe_project(input))
+    }
+
+    /// <p>Removes a physical device from a placement.</p>
+    ///
+    /// See `Iot1ClickProjects::disassociate_device_from_placement`.
+    pub fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> Result<
+        DisassociateDeviceFromPlacementResponse,
+        RusotoError<DisassociateDeviceFromPlacementError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_device_from_placement(input))
+    }
+
+    /// <p>Returns an object enumerating the devices in a placement.</p>
+    ///
+    /// See `Iot1ClickProjects::get_devices_in_placement`.
+    pub fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> Result<GetDevicesInPlacementResponse, RusotoError<GetDevicesInPlacementError>> {
+        self.runtime
+            .block_on(self.client.get_devices_in_placement(input))
+    }
+
+    /// <p>Lists the placement(s) of a project.</p>
+    ///
+    /// See `Iot1ClickProjects::list_placements`.
+    pub fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> Result<ListPlacementsResponse, RusotoError<ListPlacementsError>> {
+        self.runtime.block_on(self.client.list_placements(input))
+    }
+
+    /// <p>Lists the AWS IoT 1-Click project(s) associated with your AWS account and region.</p>
+    ///
+    /// See `Iot1ClickProjects::list_projects`.
+    pub fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResponse, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// <p>Lists the tags (metadata key/value pairs) which you have assigned to the resource.</p>
+    ///
+    /// See `Iot1ClickProjects::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates or modifies tags for a resource. Tags are key/value pairs (metadata) that can be used to manage a resource. For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p>
+    ///
+    /// See `Iot1ClickProjects::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags (metadata key/value pairs) from a resource.</p>
+    ///
+    /// See `Iot1ClickProjects::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a placement with the given attributes. To clear an attribute, pass an empty value (i.e., "").</p>
+    ///
+    /// See `Iot1ClickProjects::update_placement`.
+    pub fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> Result<UpdatePlacementResponse, RusotoError<UpdatePlacementError>> {
+        self.runtime.block_on(self.client.update_placement(input))
+    }
+
+    /// <p>Updates a project associated with your AWS account and region. With the exception of device template names, you can pass just the values that need to be updated because the update request will change only the values that are provided. To clear a value, pass the empty string (i.e., <code>""</code>).</p>
+    ///
+    /// See `Iot1ClickProjects::update_project`.
+    pub fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResponse, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+}
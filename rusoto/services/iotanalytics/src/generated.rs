@@ -1790,19 +1790,31 @@ impl BatchPutMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(BatchPutMessageError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchPutMessageError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchPutMessageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchPutMessageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutMessageError::Throttling(err.msg))
+                    return RusotoError::service(&res, BatchPutMessageError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1846,29 +1858,34 @@ impl CancelPipelineReprocessingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelPipelineReprocessingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelPipelineReprocessingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelPipelineReprocessingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelPipelineReprocessingError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelPipelineReprocessingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelPipelineReprocessingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1914,22 +1931,28 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateChannelError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateChannelError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateChannelError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1974,22 +1997,28 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDatasetError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2032,25 +2061,34 @@ impl CreateDatasetContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDatasetContentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetContentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDatasetContentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetContentError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetContentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetContentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDatasetContentError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetContentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDatasetContentError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetContentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2094,24 +2132,34 @@ impl CreateDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatastoreError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatastoreError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatastoreError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDatastoreError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatastoreError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatastoreError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDatastoreError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatastoreError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDatastoreError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2156,24 +2204,31 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreatePipelineError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreatePipelineError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2216,19 +2271,25 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteChannelError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2270,19 +2331,25 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDatasetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2324,25 +2391,34 @@ impl DeleteDatasetContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetContentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetContentError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetContentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetContentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDatasetContentError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetContentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2384,19 +2460,31 @@ impl DeleteDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatastoreError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatastoreError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatastoreError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatastoreError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDatastoreError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatastoreError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDatastoreError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2438,19 +2526,28 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeletePipelineError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeletePipelineError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeletePipelineError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2492,19 +2589,31 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeChannelError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2546,19 +2655,31 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDatasetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDatasetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2600,21 +2721,31 @@ impl DescribeDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatastoreError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatastoreError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatastoreError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatastoreError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDatastoreError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatastoreError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDatastoreError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2656,27 +2787,34 @@ impl DescribeLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2718,19 +2856,31 @@ impl DescribePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelineError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelineError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribePipelineError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribePipelineError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2772,21 +2922,31 @@ impl GetDatasetContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDatasetContentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDatasetContentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDatasetContentError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDatasetContentError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDatasetContentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDatasetContentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDatasetContentError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDatasetContentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDatasetContentError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetDatasetContentError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2826,16 +2986,19 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListChannelsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListChannelsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChannelsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListChannelsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2876,23 +3039,34 @@ impl ListDatasetContentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDatasetContentsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetContentsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatasetContentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetContentsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDatasetContentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetContentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDatasetContentsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetContentsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDatasetContentsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetContentsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2932,16 +3106,19 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDatasetsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDatasetsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDatasetsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2980,16 +3157,22 @@ impl ListDatastoresError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDatastoresError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatastoresError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatastoresError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDatastoresError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDatastoresError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatastoresError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDatastoresError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDatastoresError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3028,16 +3211,19 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPipelinesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListPipelinesError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListPipelinesError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPipelinesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPipelinesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPipelinesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListPipelinesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3080,26 +3266,40 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3140,18 +3340,25 @@ impl PutLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutLoggingOptionsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3190,18 +3397,28 @@ impl RunPipelineActivityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RunPipelineActivityError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RunPipelineActivityError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RunPipelineActivityError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RunPipelineActivityError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RunPipelineActivityError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RunPipelineActivityError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RunPipelineActivityError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RunPipelineActivityError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3242,21 +3459,31 @@ impl SampleChannelDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SampleChannelDataError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SampleChannelDataError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SampleChannelDataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SampleChannelDataError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SampleChannelDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SampleChannelDataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SampleChannelDataError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SampleChannelDataError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SampleChannelDataError::Throttling(err.msg))
+                    return RusotoError::service(&res, SampleChannelDataError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3300,34 +3527,40 @@ impl StartPipelineReprocessingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPipelineReprocessingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPipelineReprocessingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartPipelineReprocessingError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPipelineReprocessingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartPipelineReprocessingError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartPipelineReprocessingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPipelineReprocessingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3374,22 +3607,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3434,22 +3670,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3492,19 +3734,25 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateChannelError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateChannelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateChannelError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3546,19 +3794,25 @@ impl UpdateDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDatasetError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateDatasetError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDatasetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDatasetError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDatasetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatasetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDatasetError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDatasetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3600,19 +3854,31 @@ impl UpdateDatastoreError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDatastoreError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatastoreError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDatastoreError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatastoreError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatastoreError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatastoreError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDatastoreError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDatastoreError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDatastoreError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDatastoreError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3656,22 +3922,31 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdatePipelineError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdatePipelineError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdatePipelineError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePipelineError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdatePipelineError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdatePipelineError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3918,6 +4193,7 @@ impl IotAnalyticsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3933,6 +4209,7 @@ impl IotAnalyticsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotAnalyticsClient {
         IotAnalyticsClient { client, region }
     }
@@ -3949,6 +4226,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/messages/batch";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("BatchPutMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3985,6 +4265,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("CancelPipelineReprocessing");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4013,6 +4296,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("CreateChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4044,6 +4330,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datasets";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("CreateDataset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4078,6 +4367,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("CreateDatasetContent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4106,6 +4398,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datastores";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("CreateDatastore");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4137,6 +4432,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/pipelines";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("CreatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4171,6 +4469,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DeleteChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4201,6 +4502,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4231,6 +4535,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DeleteDatasetContent");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4267,6 +4574,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DeleteDatastore");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4297,6 +4607,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DeletePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4327,6 +4640,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DescribeChannel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4364,6 +4680,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DescribeDataset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4395,6 +4714,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DescribeDatastore");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4428,6 +4750,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DescribeLoggingOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4459,6 +4784,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("DescribePipeline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4490,6 +4818,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("GetDatasetContent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4524,6 +4855,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("ListChannels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4564,6 +4898,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("ListDatasetContents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4607,6 +4944,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datasets";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("ListDatasets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4644,6 +4984,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/datastores";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("ListDatastores");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4681,6 +5024,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/pipelines";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("ListPipelines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4718,6 +5064,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4750,6 +5099,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("PutLoggingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4780,6 +5132,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/pipelineactivities/run";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("RunPipelineActivity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4814,6 +5169,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("SampleChannelData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4858,6 +5216,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("StartPipelineReprocessing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4889,6 +5250,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4924,6 +5288,9 @@ impl IotAnalytics for IotAnalyticsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4962,6 +5329,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("UpdateChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4995,6 +5365,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5028,6 +5401,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("UpdateDatastore");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5061,6 +5437,9 @@ impl IotAnalytics for IotAnalyticsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iotanalytics", &self.region, &request_uri);
+        request.set_operation_name("UpdatePipeline");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5082,3 +5461,405 @@ impl IotAnalytics for IotAnalyticsClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Analytics API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotAnalyticsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotAnalyticsBlockingClient {
+    client: IotAnalyticsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotAnalyticsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotAnalyticsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotAnalyticsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotAnalyticsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotAnalyticsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotAnalyticsBlockingClient {
+        IotAnalyticsBlockingClient {
+            client: IotAnalyticsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Sends messages to a channel.</p>
+    ///
+    /// See `IotAnalytics::batch_put_message`.
+    pub fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>> {
+        self.runtime.block_on(self.client.batch_put_message(input))
+    }
+
+    /// <p>Cancels the reprocessing of data through the pipeline.</p>
+    ///
+    /// See `IotAnalytics::cancel_pipeline_reprocessing`.
+    pub fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> Result<CancelPipelineReprocessingResponse, RusotoError<CancelPipelineReprocessingError>>
+    {
+        self.runtime
+            .block_on(self.client.cancel_pipeline_reprocessing(input))
+    }
+
+    /// <p>Creates a channel. A channel collects data from an MQTT topic and archives the raw, unprocessed messages before publishing the data to a pipeline.</p>
+    ///
+    /// See `IotAnalytics::create_channel`.
+    pub fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.runtime.block_on(self.client.create_channel(input))
+    }
+
+    /// <p>Creates a data set. A data set stores data retrieved from a data store by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application). This operation creates the skeleton of a data set. The data set can be populated manually by calling "CreateDatasetContent" or automatically according to a "trigger" you specify.</p>
+    ///
+    /// See `IotAnalytics::create_dataset`.
+    pub fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.runtime.block_on(self.client.create_dataset(input))
+    }
+
+    /// <p>Creates the content of a data set by applying a "queryAction" (a SQL query) or a "containerAction" (executing a containerized application).</p>
+    ///
+    /// See `IotAnalytics::create_dataset_content`.
+    pub fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> Result<CreateDatasetContentResponse, RusotoError<CreateDatasetContentError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_content(input))
+    }
+
+    /// <p>Creates a data store, which is a repository for messages.</p>
+    ///
+    /// See `IotAnalytics::create_datastore`.
+    pub fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> Result<CreateDatastoreResponse, RusotoError<CreateDatastoreError>> {
+        self.runtime.block_on(self.client.create_datastore(input))
+    }
+
+    /// <p>Creates a pipeline. A pipeline consumes messages from a channel and allows you to process the messages before storing them in a data store. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
+    ///
+    /// See `IotAnalytics::create_pipeline`.
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// <p>Deletes the specified channel.</p>
+    ///
+    /// See `IotAnalytics::delete_channel`.
+    pub fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<(), RusotoError<DeleteChannelError>> {
+        self.runtime.block_on(self.client.delete_channel(input))
+    }
+
+    /// <p>Deletes the specified data set.</p> <p>You do not have to delete the content of the data set before you perform this operation.</p>
+    ///
+    /// See `IotAnalytics::delete_dataset`.
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// <p>Deletes the content of the specified data set.</p>
+    ///
+    /// See `IotAnalytics::delete_dataset_content`.
+    pub fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetContentError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_content(input))
+    }
+
+    /// <p>Deletes the specified data store.</p>
+    ///
+    /// See `IotAnalytics::delete_datastore`.
+    pub fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> Result<(), RusotoError<DeleteDatastoreError>> {
+        self.runtime.block_on(self.client.delete_datastore(input))
+    }
+
+    /// <p>Deletes the specified pipeline.</p>
+    ///
+    /// See `IotAnalytics::delete_pipeline`.
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// <p>Retrieves information about a channel.</p>
+    ///
+    /// See `IotAnalytics::describe_channel`.
+    pub fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.runtime.block_on(self.client.describe_channel(input))
+    }
+
+    /// <p>Retrieves information about a data set.</p>
+    ///
+    /// See `IotAnalytics::describe_dataset`.
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// <p>Retrieves information about a data store.</p>
+    ///
+    /// See `IotAnalytics::describe_datastore`.
+    pub fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> Result<DescribeDatastoreResponse, RusotoError<DescribeDatastoreError>> {
+        self.runtime.block_on(self.client.describe_datastore(input))
+    }
+
+    /// <p>Retrieves the current settings of the AWS IoT Analytics logging options.</p>
+    ///
+    /// See `IotAnalytics::describe_logging_options`.
+    pub fn describe_logging_options(
+        &self,
+    ) -> Result<DescribeLoggingOptionsResponse, RusotoError<DescribeLoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_logging_options())
+    }
+
+    /// <p>Retrieves information about a pipeline.</p>
+    ///
+    /// See `IotAnalytics::describe_pipeline`.
+    pub fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> Result<DescribePipelineResponse, RusotoError<DescribePipelineError>> {
+        self.runtime.block_on(self.client.describe_pipeline(input))
+    }
+
+    /// <p>Retrieves the contents of a data set as pre-signed URIs.</p>
+    ///
+    /// See `IotAnalytics::get_dataset_content`.
+    pub fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> Result<GetDatasetContentResponse, RusotoError<GetDatasetContentError>> {
+        self.runtime
+            .block_on(self.client.get_dataset_content(input))
+    }
+
+    /// <p>Retrieves a list of channels.</p>
+    ///
+    /// See `IotAnalytics::list_channels`.
+    pub fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.runtime.block_on(self.client.list_channels(input))
+    }
+
+    /// <p>Lists information about data set contents that have been created.</p>
+    ///
+    /// See `IotAnalytics::list_dataset_contents`.
+    pub fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> Result<ListDatasetContentsResponse, RusotoError<ListDatasetContentsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_contents(input))
+    }
+
+    /// <p>Retrieves information about data sets.</p>
+    ///
+    /// See `IotAnalytics::list_datasets`.
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// <p>Retrieves a list of data stores.</p>
+    ///
+    /// See `IotAnalytics::list_datastores`.
+    pub fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> Result<ListDatastoresResponse, RusotoError<ListDatastoresError>> {
+        self.runtime.block_on(self.client.list_datastores(input))
+    }
+
+    /// <p>Retrieves a list of pipelines.</p>
+    ///
+    /// See `IotAnalytics::list_pipelines`.
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// <p>Lists the tags (metadata) which you have assigned to the resource.</p>
+    ///
+    /// See `IotAnalytics::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Sets or updates the AWS IoT Analytics logging options.</p> <p>Note that if you update the value of any <code>loggingOptions</code> field, it takes up to one minute for the change to take effect. Also, if you change the policy attached to the role you specified in the roleArn field (for example, to correct an invalid policy) it takes up to 5 minutes for that change to take effect. </p>
+    ///
+    /// See `IotAnalytics::put_logging_options`.
+    pub fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> Result<(), RusotoError<PutLoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.put_logging_options(input))
+    }
+
+    /// <p>Simulates the results of running a pipeline activity on a message payload.</p>
+    ///
+    /// See `IotAnalytics::run_pipeline_activity`.
+    pub fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> Result<RunPipelineActivityResponse, RusotoError<RunPipelineActivityError>> {
+        self.runtime
+            .block_on(self.client.run_pipeline_activity(input))
+    }
+
+    /// <p>Retrieves a sample of messages from the specified channel ingested during the specified timeframe. Up to 10 messages can be retrieved.</p>
+    ///
+    /// See `IotAnalytics::sample_channel_data`.
+    pub fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> Result<SampleChannelDataResponse, RusotoError<SampleChannelDataError>> {
+        self.runtime
+            .block_on(self.client.sample_channel_data(input))
+    }
+
+    /// <p>Starts the reprocessing of raw message data through the pipeline.</p>
+    ///
+    /// See `IotAnalytics::start_pipeline_reprocessing`.
+    pub fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> Result<StartPipelineReprocessingResponse, RusotoError<StartPipelineReprocessingError>>
+    {
+        self.runtime
+            .block_on(self.client.start_pipeline_reprocessing(input))
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+    ///
+    /// See `IotAnalytics::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    ///
+    /// See `IotAnalytics::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the settings of a channel.</p>
+    ///
+    /// See `IotAnalytics::update_channel`.
+    pub fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<(), RusotoError<UpdateChannelError>> {
+        self.runtime.block_on(self.client.update_channel(input))
+    }
+
+    /// <p>Updates the settings of a data set.</p>
+    ///
+    /// See `IotAnalytics::update_dataset`.
+    pub fn update_dataset(
+        &self,
+        input: UpdateDatasetRequest,
+    ) -> Result<(), RusotoError<UpdateDatasetError>> {
+        self.runtime.block_on(self.client.update_dataset(input))
+    }
+
+    /// <p>Updates the settings of a data store.</p>
+    ///
+    /// See `IotAnalytics::update_datastore`.
+    pub fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> Result<(), RusotoError<UpdateDatastoreError>> {
+        self.runtime.block_on(self.client.update_datastore(input))
+    }
+
+    /// <p>Updates the settings of a pipeline. You must specify both a <code>channel</code> and a <code>datastore</code> activity and, optionally, as many as 23 additional activities in the <code>pipelineActivities</code> array.</p>
+    ///
+    /// See `IotAnalytics::update_pipeline`.
+    pub fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<(), RusotoError<UpdatePipelineError>> {
+        self.runtime.block_on(self.client.update_pipeline(input))
+    }
+}
@@ -354,16 +354,25 @@ impl BatchPutMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(BatchPutMessageError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchPutMessageError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchPutMessageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutMessageError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutMessageError::Throttling(err.msg))
+                    return RusotoError::service(&res, BatchPutMessageError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -402,18 +411,28 @@ impl BatchUpdateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateDetectorError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateDetectorError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateDetectorError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchUpdateDetectorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateDetectorError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -454,19 +473,31 @@ impl DescribeDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDetectorError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDetectorError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDetectorError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDetectorError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -508,19 +539,25 @@ impl ListDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDetectorsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListDetectorsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDetectorsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDetectorsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDetectorsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDetectorsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectorsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDetectorsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -587,6 +624,7 @@ impl IotEventsDataClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -602,6 +640,7 @@ impl IotEventsDataClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotEventsDataClient {
         IotEventsDataClient { client, region }
     }
@@ -618,6 +657,9 @@ impl IotEventsData for IotEventsDataClient {
         let request_uri = "/inputs/messages";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation_name("BatchPutMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -650,6 +692,9 @@ impl IotEventsData for IotEventsDataClient {
         let request_uri = "/detectors";
 
         let mut request = SignedRequest::new("POST", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation_name("BatchUpdateDetector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -685,6 +730,9 @@ impl IotEventsData for IotEventsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation_name("DescribeDetector");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -724,6 +772,9 @@ impl IotEventsData for IotEventsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "ioteventsdata", &self.region, &request_uri);
+        request.set_operation_name("ListDetectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iotevents".to_string());
@@ -757,3 +808,94 @@ impl IotEventsData for IotEventsDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Events Data API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotEventsDataClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotEventsDataBlockingClient {
+    client: IotEventsDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotEventsDataBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotEventsDataBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotEventsDataBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotEventsDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotEventsDataBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotEventsDataBlockingClient {
+        IotEventsDataBlockingClient {
+            client: IotEventsDataClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Sends a set of messages to the AWS IoT Events system. Each message payload is transformed into the input you specify (<code>"inputName"</code>) and ingested into any detectors that monitor that input. If multiple messages are sent, the order in which the messages are processed isn't guaranteed. To guarantee ordering, you must send messages one at a time and wait for a successful response.</p>
+    ///
+    /// See `IotEventsData::batch_put_message`.
+    pub fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> Result<BatchPutMessageResponse, RusotoError<BatchPutMessageError>> {
+        self.runtime.block_on(self.client.batch_put_message(input))
+    }
+
+    /// <p>Updates the state, variable values, and timer settings of one or more detectors (instances) of a specified detector model.</p>
+    ///
+    /// See `IotEventsData::batch_update_detector`.
+    pub fn batch_update_detector(
+        &self,
+        input: BatchUpdateDetectorRequest,
+    ) -> Result<BatchUpdateDetectorResponse, RusotoError<BatchUpdateDetectorError>> {
+        self.runtime
+            .block_on(self.client.batch_update_detector(input))
+    }
+
+    /// <p>Returns information about the specified detector (instance).</p>
+    ///
+    /// See `IotEventsData::describe_detector`.
+    pub fn describe_detector(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<DescribeDetectorResponse, RusotoError<DescribeDetectorError>> {
+        self.runtime.block_on(self.client.describe_detector(input))
+    }
+
+    /// <p>Lists detectors (the instances of a detector model).</p>
+    ///
+    /// See `IotEventsData::list_detectors`.
+    pub fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+        self.runtime.block_on(self.client.list_detectors(input))
+    }
+}
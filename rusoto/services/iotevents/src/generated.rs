@@ -1032,29 +1032,46 @@ impl CreateDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDetectorModelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDetectorModelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDetectorModelError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDetectorModelError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDetectorModelError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDetectorModelError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1098,19 +1115,25 @@ impl CreateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateInputError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateInputError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateInputError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateInputError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateInputError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateInputError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1154,26 +1177,40 @@ impl DeleteDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorModelError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorModelError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorModelError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorModelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorModelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDetectorModelError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1218,22 +1255,25 @@ impl DeleteInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteInputError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteInputError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1276,27 +1316,34 @@ impl DescribeDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorModelError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorModelError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorModelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorModelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDetectorModelError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1338,19 +1385,25 @@ impl DescribeInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeInputError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeInputError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1394,32 +1447,40 @@ impl DescribeLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeLoggingOptionsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLoggingOptionsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1462,29 +1523,34 @@ impl ListDetectorModelVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelVersionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDetectorModelVersionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectorModelVersionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelVersionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1524,18 +1590,25 @@ impl ListDetectorModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDetectorModelsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDetectorModelsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDetectorModelsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDetectorModelsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectorModelsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDetectorModelsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1574,16 +1647,16 @@ impl ListInputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListInputsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListInputsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInputsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListInputsError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListInputsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListInputsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInputsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListInputsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1624,21 +1697,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1682,26 +1768,37 @@ impl PutLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutLoggingOptionsError::Throttling(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutLoggingOptionsError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingOptionsError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1746,22 +1843,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1804,19 +1901,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1860,26 +1960,40 @@ impl UpdateDetectorModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorModelError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorModelError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorModelError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorModelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorModelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorModelError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDetectorModelError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1924,22 +2038,25 @@ impl UpdateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateInputError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::InvalidRequest(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInputError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateInputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateInputError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2078,6 +2195,7 @@ impl IotEventsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2093,6 +2211,7 @@ impl IotEventsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotEventsClient {
         IotEventsClient { client, region }
     }
@@ -2109,6 +2228,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("CreateDetectorModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2140,6 +2262,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("CreateInput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2174,6 +2299,9 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("DeleteDetectorModel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2202,6 +2330,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("DeleteInput");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2233,6 +2364,9 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("DescribeDetectorModel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2267,6 +2401,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("DescribeInput");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2294,6 +2431,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("DescribeLoggingOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2326,6 +2466,9 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("ListDetectorModelVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2363,6 +2506,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/detector-models";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("ListDetectorModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2400,6 +2546,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/inputs";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("ListInputs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2437,6 +2586,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2469,6 +2621,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/logging";
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("PutLoggingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2499,6 +2654,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2534,6 +2692,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("DELETE", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2572,6 +2733,9 @@ impl IotEvents for IotEventsClient {
         );
 
         let mut request = SignedRequest::new("POST", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("UpdateDetectorModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2603,6 +2767,9 @@ impl IotEvents for IotEventsClient {
         let request_uri = format!("/inputs/{input_name}", input_name = input.input_name);
 
         let mut request = SignedRequest::new("PUT", "iotevents", &self.region, &request_uri);
+        request.set_operation_name("UpdateInput");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2625,3 +2792,222 @@ impl IotEvents for IotEventsClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Events API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotEventsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotEventsBlockingClient {
+    client: IotEventsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotEventsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotEventsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotEventsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotEventsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotEventsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotEventsBlockingClient {
+        IotEventsBlockingClient {
+            client: IotEventsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a detector model.</p>
+    ///
+    /// See `IotEvents::create_detector_model`.
+    pub fn create_detector_model(
+        &self,
+        input: CreateDetectorModelRequest,
+    ) -> Result<CreateDetectorModelResponse, RusotoError<CreateDetectorModelError>> {
+        self.runtime
+            .block_on(self.client.create_detector_model(input))
+    }
+
+    /// <p>Creates an input.</p>
+    ///
+    /// See `IotEvents::create_input`.
+    pub fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+        self.runtime.block_on(self.client.create_input(input))
+    }
+
+    /// <p>Deletes a detector model. Any active instances of the detector model are also deleted.</p>
+    ///
+    /// See `IotEvents::delete_detector_model`.
+    pub fn delete_detector_model(
+        &self,
+        input: DeleteDetectorModelRequest,
+    ) -> Result<DeleteDetectorModelResponse, RusotoError<DeleteDetectorModelError>> {
+        self.runtime
+            .block_on(self.client.delete_detector_model(input))
+    }
+
+    /// <p>Deletes an input.</p>
+    ///
+    /// See `IotEvents::delete_input`.
+    pub fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+        self.runtime.block_on(self.client.delete_input(input))
+    }
+
+    /// <p>Describes a detector model. If the <code>version</code> parameter is not specified, information about the latest version is returned.</p>
+    ///
+    /// See `IotEvents::describe_detector_model`.
+    pub fn describe_detector_model(
+        &self,
+        input: DescribeDetectorModelRequest,
+    ) -> Result<DescribeDetectorModelResponse, RusotoError<DescribeDetectorModelError>> {
+        self.runtime
+            .block_on(self.client.describe_detector_model(input))
+    }
+
+    /// <p>Describes an input.</p>
+    ///
+    /// See `IotEvents::describe_input`.
+    pub fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+        self.runtime.block_on(self.client.describe_input(input))
+    }
+
+    /// <p>Retrieves the current settings of the AWS IoT Events logging options.</p>
+    ///
+    /// See `IotEvents::describe_logging_options`.
+    pub fn describe_logging_options(
+        &self,
+    ) -> Result<DescribeLoggingOptionsResponse, RusotoError<DescribeLoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_logging_options())
+    }
+
+    /// <p>Lists all the versions of a detector model. Only the metadata associated with each detector model version is returned.</p>
+    ///
+    /// See `IotEvents::list_detector_model_versions`.
+    pub fn list_detector_model_versions(
+        &self,
+        input: ListDetectorModelVersionsRequest,
+    ) -> Result<ListDetectorModelVersionsResponse, RusotoError<ListDetectorModelVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_detector_model_versions(input))
+    }
+
+    /// <p>Lists the detector models you have created. Only the metadata associated with each detector model is returned.</p>
+    ///
+    /// See `IotEvents::list_detector_models`.
+    pub fn list_detector_models(
+        &self,
+        input: ListDetectorModelsRequest,
+    ) -> Result<ListDetectorModelsResponse, RusotoError<ListDetectorModelsError>> {
+        self.runtime
+            .block_on(self.client.list_detector_models(input))
+    }
+
+    /// <p>Lists the inputs you have created.</p>
+    ///
+    /// See `IotEvents::list_inputs`.
+    pub fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+        self.runtime.block_on(self.client.list_inputs(input))
+    }
+
+    /// <p>Lists the tags (metadata) you have assigned to the resource.</p>
+    ///
+    /// See `IotEvents::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Sets or updates the AWS IoT Events logging options.</p> <p>If you update the value of any <code>loggingOptions</code> field, it takes up to one minute for the change to take effect. If you change the policy attached to the role you specified in the <code>roleArn</code> field (for example, to correct an invalid policy), it takes up to five minutes for that change to take effect.</p>
+    ///
+    /// See `IotEvents::put_logging_options`.
+    pub fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> Result<(), RusotoError<PutLoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.put_logging_options(input))
+    }
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata that can be used to manage a resource.</p>
+    ///
+    /// See `IotEvents::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    ///
+    /// See `IotEvents::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a detector model. Detectors (instances) spawned by the previous version are deleted and then re-created as new inputs arrive.</p>
+    ///
+    /// See `IotEvents::update_detector_model`.
+    pub fn update_detector_model(
+        &self,
+        input: UpdateDetectorModelRequest,
+    ) -> Result<UpdateDetectorModelResponse, RusotoError<UpdateDetectorModelError>> {
+        self.runtime
+            .block_on(self.client.update_detector_model(input))
+    }
+
+    /// <p>Updates an input.</p>
+    ///
+    /// See `IotEvents::update_input`.
+    pub fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+        self.runtime.block_on(self.client.update_input(input))
+    }
+}
@@ -345,7 +345,7 @@ impl CloseTunnelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CloseTunnelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CloseTunnelError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -375,7 +375,10 @@ impl DescribeTunnelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTunnelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTunnelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -405,9 +408,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -459,7 +463,7 @@ impl OpenTunnelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(OpenTunnelError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, OpenTunnelError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -489,7 +493,7 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -519,7 +523,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -600,6 +607,7 @@ impl IoTSecureTunnelingClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -615,6 +623,7 @@ impl IoTSecureTunnelingClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IoTSecureTunnelingClient {
         IoTSecureTunnelingClient { client, region }
     }
@@ -628,6 +637,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: CloseTunnelRequest,
     ) -> Result<CloseTunnelResponse, RusotoError<CloseTunnelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CloseTunnel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "IoTSecuredTunneling.CloseTunnel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -646,6 +658,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: DescribeTunnelRequest,
     ) -> Result<DescribeTunnelResponse, RusotoError<DescribeTunnelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTunnel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "IoTSecuredTunneling.DescribeTunnel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -664,6 +679,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "IoTSecuredTunneling.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -682,6 +700,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: ListTunnelsRequest,
     ) -> Result<ListTunnelsResponse, RusotoError<ListTunnelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTunnels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "IoTSecuredTunneling.ListTunnels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -700,6 +721,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: OpenTunnelRequest,
     ) -> Result<OpenTunnelResponse, RusotoError<OpenTunnelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("OpenTunnel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "IoTSecuredTunneling.OpenTunnel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -718,6 +742,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "IoTSecuredTunneling.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -736,6 +763,9 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "IoTSecuredTunneling.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -748,3 +778,127 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the AWS IoT Secure Tunneling API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IoTSecureTunnelingClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IoTSecureTunnelingBlockingClient {
+    client: IoTSecureTunnelingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IoTSecureTunnelingBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IoTSecureTunnelingBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IoTSecureTunnelingBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IoTSecureTunnelingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IoTSecureTunnelingBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient {
+            client: IoTSecureTunnelingClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Closes a tunnel identified by the unique tunnel id. When a <code>CloseTunnel</code> request is received, we close the WebSocket connections between the client and proxy server so no data can be transmitted.</p>
+    ///
+    /// See `IoTSecureTunneling::close_tunnel`.
+    pub fn close_tunnel(
+        &self,
+        input: CloseTunnelRequest,
+    ) -> Result<CloseTunnelResponse, RusotoError<CloseTunnelError>> {
+        self.runtime.block_on(self.client.close_tunnel(input))
+    }
+
+    /// <p>Gets information about a tunnel identified by the unique tunnel id.</p>
+    ///
+    /// See `IoTSecureTunneling::describe_tunnel`.
+    pub fn describe_tunnel(
+        &self,
+        input: DescribeTunnelRequest,
+    ) -> Result<DescribeTunnelResponse, RusotoError<DescribeTunnelError>> {
+        self.runtime.block_on(self.client.describe_tunnel(input))
+    }
+
+    /// <p>Lists the tags for the specified resource.</p>
+    ///
+    /// See `IoTSecureTunneling::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>List all tunnels for an AWS account. Tunnels are listed by creation time in descending order, newer tunnels will be listed before older tunnels.</p>
+    ///
+    /// See `IoTSecureTunneling::list_tunnels`.
+    pub fn list_tunnels(
+        &self,
+        input: ListTunnelsRequest,
+    ) -> Result<ListTunnelsResponse, RusotoError<ListTunnelsError>> {
+        self.runtime.block_on(self.client.list_tunnels(input))
+    }
+
+    /// <p>Creates a new tunnel, and returns two client access tokens for clients to use to connect to the AWS IoT Secure Tunneling proxy server. .</p>
+    ///
+    /// See `IoTSecureTunneling::open_tunnel`.
+    pub fn open_tunnel(
+        &self,
+        input: OpenTunnelRequest,
+    ) -> Result<OpenTunnelResponse, RusotoError<OpenTunnelError>> {
+        self.runtime.block_on(self.client.open_tunnel(input))
+    }
+
+    /// <p>A resource tag.</p>
+    ///
+    /// See `IoTSecureTunneling::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a tag from a resource.</p>
+    ///
+    /// See `IoTSecureTunneling::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -1248,22 +1248,28 @@ impl AssociateEntityToThingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateEntityToThingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateEntityToThingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateEntityToThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateEntityToThingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1304,21 +1310,31 @@ impl CreateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowTemplateError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowTemplateError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateFlowTemplateError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1360,23 +1376,34 @@ impl CreateSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemInstanceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemInstanceError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemInstanceError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemInstanceError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemInstanceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1416,20 +1443,28 @@ impl CreateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemTemplateError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSystemTemplateError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1468,16 +1503,25 @@ impl DeleteFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFlowTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFlowTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFlowTemplateError::ResourceInUse(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteFlowTemplateError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1512,10 +1556,13 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNamespaceError::InternalFailure(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNamespaceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteNamespaceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1552,18 +1599,28 @@ impl DeleteSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemInstanceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemInstanceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemInstanceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemInstanceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1602,18 +1659,28 @@ impl DeleteSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemTemplateError::ResourceInUse(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSystemTemplateError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1654,23 +1721,34 @@ impl DeploySystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeploySystemInstanceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeploySystemInstanceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeploySystemInstanceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeploySystemInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeploySystemInstanceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1710,22 +1788,28 @@ impl DeprecateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateFlowTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateFlowTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateFlowTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateFlowTemplateError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1764,22 +1848,28 @@ impl DeprecateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateSystemTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateSystemTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateSystemTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateSystemTemplateError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1818,16 +1908,25 @@ impl DescribeNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNamespaceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNamespaceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNamespaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNamespaceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeNamespaceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeNamespaceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1866,24 +1965,28 @@ impl DissociateEntityFromThingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DissociateEntityFromThingError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DissociateEntityFromThingError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DissociateEntityFromThingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DissociateEntityFromThingError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1922,16 +2025,16 @@ impl GetEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetEntitiesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetEntitiesError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetEntitiesError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEntitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetEntitiesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEntitiesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetEntitiesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1970,16 +2073,25 @@ impl GetFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFlowTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetFlowTemplateError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2018,22 +2130,28 @@ impl GetFlowTemplateRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateRevisionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateRevisionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateRevisionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFlowTemplateRevisionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2072,19 +2190,22 @@ impl GetNamespaceDeletionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNamespaceDeletionStatusError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNamespaceDeletionStatusError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetNamespaceDeletionStatusError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2122,16 +2243,25 @@ impl GetSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemInstanceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemInstanceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemInstanceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetSystemInstanceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2170,16 +2300,25 @@ impl GetSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetSystemTemplateError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2220,24 +2359,28 @@ impl GetSystemTemplateRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateRevisionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateRevisionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateRevisionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSystemTemplateRevisionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2276,16 +2419,25 @@ impl GetUploadStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetUploadStatusError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUploadStatusError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetUploadStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUploadStatusError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUploadStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUploadStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetUploadStatusError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetUploadStatusError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2324,24 +2476,28 @@ impl ListFlowExecutionMessagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFlowExecutionMessagesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFlowExecutionMessagesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFlowExecutionMessagesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFlowExecutionMessagesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2380,18 +2536,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2428,13 +2594,16 @@ impl SearchEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchEntitiesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchEntitiesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, SearchEntitiesError::InvalidRequest(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchEntitiesError::Throttling(err.msg))
+                    return RusotoError::service(&res, SearchEntitiesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2472,20 +2641,28 @@ impl SearchFlowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowExecutionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowExecutionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowExecutionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowExecutionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2522,13 +2699,22 @@ impl SearchFlowTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowTemplatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowTemplatesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFlowTemplatesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2564,17 +2750,22 @@ impl SearchSystemInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchSystemInstancesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchSystemInstancesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchSystemInstancesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2610,17 +2801,22 @@ impl SearchSystemTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchSystemTemplatesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchSystemTemplatesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchSystemTemplatesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2658,16 +2854,16 @@ impl SearchThingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchThingsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, SearchThingsError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, SearchThingsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchThingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SearchThingsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchThingsError::Throttling(err.msg))
+                    return RusotoError::service(&res, SearchThingsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2706,16 +2902,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(TagResourceError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2756,27 +2955,34 @@ impl UndeploySystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UndeploySystemInstanceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UndeploySystemInstanceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UndeploySystemInstanceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UndeploySystemInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UndeploySystemInstanceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2816,16 +3022,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2864,16 +3073,25 @@ impl UpdateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateFlowTemplateError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2912,20 +3130,28 @@ impl UpdateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSystemTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSystemTemplateError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSystemTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSystemTemplateError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2962,17 +3188,22 @@ impl UploadEntityDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UploadEntityDefinitionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UploadEntityDefinitionsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UploadEntityDefinitionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3221,6 +3452,7 @@ impl IotThingsGraphClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3236,6 +3468,7 @@ impl IotThingsGraphClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> IotThingsGraphClient {
         IotThingsGraphClient { client, region }
     }
@@ -3249,6 +3482,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: AssociateEntityToThingRequest,
     ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateEntityToThing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.AssociateEntityToThing",
@@ -3271,6 +3507,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateFlowTemplateRequest,
     ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFlowTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.CreateFlowTemplate",
@@ -3292,6 +3531,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateSystemInstanceRequest,
     ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSystemInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.CreateSystemInstance",
@@ -3314,6 +3556,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateSystemTemplateRequest,
     ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSystemTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.CreateSystemTemplate",
@@ -3336,6 +3581,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteFlowTemplateRequest,
     ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFlowTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteFlowTemplate",
@@ -3356,6 +3604,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         &self,
     ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNamespace");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteNamespace",
@@ -3376,6 +3627,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteSystemInstanceRequest,
     ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSystemInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteSystemInstance",
@@ -3398,6 +3652,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteSystemTemplateRequest,
     ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSystemTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteSystemTemplate",
@@ -3420,6 +3677,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeploySystemInstanceRequest,
     ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeploySystemInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeploySystemInstance",
@@ -3442,6 +3702,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeprecateFlowTemplateRequest,
     ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeprecateFlowTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeprecateFlowTemplate",
@@ -3464,6 +3727,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeprecateSystemTemplateRequest,
     ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeprecateSystemTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeprecateSystemTemplate",
@@ -3486,6 +3752,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DescribeNamespaceRequest,
     ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeNamespace");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DescribeNamespace",
@@ -3508,6 +3777,9 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DissociateEntityFromThing");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DissociateEntityFromThing",
@@ -3530,6 +3802,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetEntitiesRequest,
     ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEntities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.GetEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3548,6 +3823,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetFlowTemplateRequest,
     ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFlowTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetFlowTemplate",
@@ -3569,6 +3847,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetFlowTemplateRevisionsRequest,
     ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFlowTemplateRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetFlowTemplateRevisions",
@@ -3591,6 +3872,9 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetNamespaceDeletionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetNamespaceDeletionStatus",
@@ -3612,6 +3896,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetSystemInstanceRequest,
     ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSystemInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetSystemInstance",
@@ -3633,6 +3920,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetSystemTemplateRequest,
     ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSystemTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetSystemTemplate",
@@ -3655,6 +3945,9 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSystemTemplateRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetSystemTemplateRevisions",
@@ -3677,6 +3970,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetUploadStatusRequest,
     ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetUploadStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetUploadStatus",
@@ -3699,6 +3995,9 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFlowExecutionMessages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.ListFlowExecutionMessages",
@@ -3721,6 +4020,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.ListTagsForResource",
@@ -3742,6 +4044,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchEntitiesRequest,
     ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchEntities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchEntities",
@@ -3763,6 +4068,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchFlowExecutionsRequest,
     ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchFlowExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchFlowExecutions",
@@ -3785,6 +4093,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchFlowTemplatesRequest,
     ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchFlowTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchFlowTemplates",
@@ -3806,6 +4117,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchSystemInstancesRequest,
     ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchSystemInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchSystemInstances",
@@ -3828,6 +4142,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchSystemTemplatesRequest,
     ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchSystemTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchSystemTemplates",
@@ -3850,6 +4167,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchThingsRequest,
     ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchThings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.SearchThings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3868,6 +4188,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3886,6 +4209,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UndeploySystemInstanceRequest,
     ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UndeploySystemInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UndeploySystemInstance",
@@ -3908,6 +4234,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UntagResource",
@@ -3929,6 +4258,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UpdateFlowTemplateRequest,
     ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateFlowTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UpdateFlowTemplate",
@@ -3950,6 +4282,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UpdateSystemTemplateRequest,
     ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSystemTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UpdateSystemTemplate",
@@ -3972,6 +4307,9 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UploadEntityDefinitionsRequest,
     ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UploadEntityDefinitions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UploadEntityDefinitions",
@@ -3988,3 +4326,431 @@ impl IotThingsGraph for IotThingsGraphClient {
             .deserialize::<UploadEntityDefinitionsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS IoT Things Graph API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `IotThingsGraphClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotThingsGraphBlockingClient {
+    client: IotThingsGraphClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotThingsGraphBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IotThingsGraphBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        IotThingsGraphBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotThingsGraphBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotThingsGraphBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient {
+            client: IotThingsGraphClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a device with a concrete thing that is in the user's registry.</p> <p>A thing can be associated with only one device at a time. If you associate a thing with a new device id, its previous association will be removed.</p>
+    ///
+    /// See `IotThingsGraph::associate_entity_to_thing`.
+    pub fn associate_entity_to_thing(
+        &self,
+        input: AssociateEntityToThingRequest,
+    ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
+        self.runtime
+            .block_on(self.client.associate_entity_to_thing(input))
+    }
+
+    /// <p>Creates a workflow template. Workflows can be created only in the user's namespace. (The public namespace contains only entities.) The workflow can contain only entities in the specified namespace. The workflow is validated against the entities in the latest version of the user's namespace unless another namespace version is specified in the request.</p>
+    ///
+    /// See `IotThingsGraph::create_flow_template`.
+    pub fn create_flow_template(
+        &self,
+        input: CreateFlowTemplateRequest,
+    ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_flow_template(input))
+    }
+
+    /// <p>Creates a system instance. </p> <p>This action validates the system instance, prepares the deployment-related resources. For Greengrass deployments, it updates the Greengrass group that is specified by the <code>greengrassGroupName</code> parameter. It also adds a file to the S3 bucket specified by the <code>s3BucketName</code> parameter. You need to call <code>DeploySystemInstance</code> after running this action.</p> <p>For Greengrass deployments, since this action modifies and adds resources to a Greengrass group and an S3 bucket on the caller's behalf, the calling identity must have write permissions to both the specified Greengrass group and S3 bucket. Otherwise, the call will fail with an authorization error.</p> <p>For cloud deployments, this action requires a <code>flowActionsRoleArn</code> value. This is an IAM role that has permissions to access AWS services, such as AWS Lambda and AWS IoT, that the flow uses when it executes.</p> <p>If the definition document doesn't specify a version of the user's namespace, the latest version will be used by default.</p>
+    ///
+    /// See `IotThingsGraph::create_system_instance`.
+    pub fn create_system_instance(
+        &self,
+        input: CreateSystemInstanceRequest,
+    ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.create_system_instance(input))
+    }
+
+    /// <p>Creates a system. The system is validated against the entities in the latest version of the user's namespace unless another namespace version is specified in the request.</p>
+    ///
+    /// See `IotThingsGraph::create_system_template`.
+    pub fn create_system_template(
+        &self,
+        input: CreateSystemTemplateRequest,
+    ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_system_template(input))
+    }
+
+    /// <p>Deletes a workflow. Any new system or deployment that contains this workflow will fail to update or deploy. Existing deployments that contain the workflow will continue to run (since they use a snapshot of the workflow taken at the time of deployment).</p>
+    ///
+    /// See `IotThingsGraph::delete_flow_template`.
+    pub fn delete_flow_template(
+        &self,
+        input: DeleteFlowTemplateRequest,
+    ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_flow_template(input))
+    }
+
+    /// <p>Deletes the specified namespace. This action deletes all of the entities in the namespace. Delete the systems and flows that use entities in the namespace before performing this action.</p>
+    ///
+    /// See `IotThingsGraph::delete_namespace`.
+    pub fn delete_namespace(
+        &self,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.runtime.block_on(self.client.delete_namespace())
+    }
+
+    /// <p>Deletes a system instance. Only system instances that have never been deployed, or that have been undeployed can be deleted.</p> <p>Users can create a new system instance that has the same ID as a deleted system instance.</p>
+    ///
+    /// See `IotThingsGraph::delete_system_instance`.
+    pub fn delete_system_instance(
+        &self,
+        input: DeleteSystemInstanceRequest,
+    ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.delete_system_instance(input))
+    }
+
+    /// <p>Deletes a system. New deployments can't contain the system after its deletion. Existing deployments that contain the system will continue to work because they use a snapshot of the system that is taken when it is deployed.</p>
+    ///
+    /// See `IotThingsGraph::delete_system_template`.
+    pub fn delete_system_template(
+        &self,
+        input: DeleteSystemTemplateRequest,
+    ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_system_template(input))
+    }
+
+    /// <p> <b>Greengrass and Cloud Deployments</b> </p> <p>Deploys the system instance to the target specified in <code>CreateSystemInstance</code>. </p> <p> <b>Greengrass Deployments</b> </p> <p>If the system or any workflows and entities have been updated before this action is called, then the deployment will create a new Amazon Simple Storage Service resource file and then deploy it.</p> <p>Since this action creates a Greengrass deployment on the caller's behalf, the calling identity must have write permissions to the specified Greengrass group. Otherwise, the call will fail with an authorization error.</p> <p>For information about the artifacts that get added to your Greengrass core device when you use this API, see <a href="https://docs.aws.amazon.com/thingsgraph/latest/ug/iot-tg-greengrass.html">AWS IoT Things Graph and AWS IoT Greengrass</a>.</p>
+    ///
+    /// See `IotThingsGraph::deploy_system_instance`.
+    pub fn deploy_system_instance(
+        &self,
+        input: DeploySystemInstanceRequest,
+    ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.deploy_system_instance(input))
+    }
+
+    /// <p>Deprecates the specified workflow. This action marks the workflow for deletion. Deprecated flows can't be deployed, but existing deployments will continue to run.</p>
+    ///
+    /// See `IotThingsGraph::deprecate_flow_template`.
+    pub fn deprecate_flow_template(
+        &self,
+        input: DeprecateFlowTemplateRequest,
+    ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.deprecate_flow_template(input))
+    }
+
+    /// <p>Deprecates the specified system.</p>
+    ///
+    /// See `IotThingsGraph::deprecate_system_template`.
+    pub fn deprecate_system_template(
+        &self,
+        input: DeprecateSystemTemplateRequest,
+    ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.deprecate_system_template(input))
+    }
+
+    /// <p>Gets the latest version of the user's namespace and the public version that it is tracking.</p>
+    ///
+    /// See `IotThingsGraph::describe_namespace`.
+    pub fn describe_namespace(
+        &self,
+        input: DescribeNamespaceRequest,
+    ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
+        self.runtime.block_on(self.client.describe_namespace(input))
+    }
+
+    /// <p>Dissociates a device entity from a concrete thing. The action takes only the type of the entity that you need to dissociate because only one entity of a particular type can be associated with a thing.</p>
+    ///
+    /// See `IotThingsGraph::dissociate_entity_from_thing`.
+    pub fn dissociate_entity_from_thing(
+        &self,
+        input: DissociateEntityFromThingRequest,
+    ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
+    {
+        self.runtime
+            .block_on(self.client.dissociate_entity_from_thing(input))
+    }
+
+    /// <p>Gets definitions of the specified entities. Uses the latest version of the user's namespace by default. This API returns the following TDM entities.</p> <ul> <li> <p>Properties</p> </li> <li> <p>States</p> </li> <li> <p>Events</p> </li> <li> <p>Actions</p> </li> <li> <p>Capabilities</p> </li> <li> <p>Mappings</p> </li> <li> <p>Devices</p> </li> <li> <p>Device Models</p> </li> <li> <p>Services</p> </li> </ul> <p>This action doesn't return definitions for systems, flows, and deployments.</p>
+    ///
+    /// See `IotThingsGraph::get_entities`.
+    pub fn get_entities(
+        &self,
+        input: GetEntitiesRequest,
+    ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
+        self.runtime.block_on(self.client.get_entities(input))
+    }
+
+    /// <p>Gets the latest version of the <code>DefinitionDocument</code> and <code>FlowTemplateSummary</code> for the specified workflow.</p>
+    ///
+    /// See `IotThingsGraph::get_flow_template`.
+    pub fn get_flow_template(
+        &self,
+        input: GetFlowTemplateRequest,
+    ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
+        self.runtime.block_on(self.client.get_flow_template(input))
+    }
+
+    /// <p>Gets revisions of the specified workflow. Only the last 100 revisions are stored. If the workflow has been deprecated, this action will return revisions that occurred before the deprecation. This action won't work for workflows that have been deleted.</p>
+    ///
+    /// See `IotThingsGraph::get_flow_template_revisions`.
+    pub fn get_flow_template_revisions(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
+        self.runtime
+            .block_on(self.client.get_flow_template_revisions(input))
+    }
+
+    /// <p>Gets the status of a namespace deletion task.</p>
+    ///
+    /// See `IotThingsGraph::get_namespace_deletion_status`.
+    pub fn get_namespace_deletion_status(
+        &self,
+    ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.get_namespace_deletion_status())
+    }
+
+    /// <p>Gets a system instance.</p>
+    ///
+    /// See `IotThingsGraph::get_system_instance`.
+    pub fn get_system_instance(
+        &self,
+        input: GetSystemInstanceRequest,
+    ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_system_instance(input))
+    }
+
+    /// <p>Gets a system.</p>
+    ///
+    /// See `IotThingsGraph::get_system_template`.
+    pub fn get_system_template(
+        &self,
+        input: GetSystemTemplateRequest,
+    ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.get_system_template(input))
+    }
+
+    /// <p>Gets revisions made to the specified system template. Only the previous 100 revisions are stored. If the system has been deprecated, this action will return the revisions that occurred before its deprecation. This action won't work with systems that have been deleted.</p>
+    ///
+    /// See `IotThingsGraph::get_system_template_revisions`.
+    pub fn get_system_template_revisions(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_system_template_revisions(input))
+    }
+
+    /// <p>Gets the status of the specified upload.</p>
+    ///
+    /// See `IotThingsGraph::get_upload_status`.
+    pub fn get_upload_status(
+        &self,
+        input: GetUploadStatusRequest,
+    ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
+        self.runtime.block_on(self.client.get_upload_status(input))
+    }
+
+    /// <p>Returns a list of objects that contain information about events in a flow execution.</p>
+    ///
+    /// See `IotThingsGraph::list_flow_execution_messages`.
+    pub fn list_flow_execution_messages(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_flow_execution_messages(input))
+    }
+
+    /// <p>Lists all tags on an AWS IoT Things Graph resource.</p>
+    ///
+    /// See `IotThingsGraph::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Searches for entities of the specified type. You can search for entities in your namespace and the public namespace that you're tracking.</p>
+    ///
+    /// See `IotThingsGraph::search_entities`.
+    pub fn search_entities(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
+        self.runtime.block_on(self.client.search_entities(input))
+    }
+
+    /// <p>Searches for AWS IoT Things Graph workflow execution instances.</p>
+    ///
+    /// See `IotThingsGraph::search_flow_executions`.
+    pub fn search_flow_executions(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.search_flow_executions(input))
+    }
+
+    /// <p>Searches for summary information about workflows.</p>
+    ///
+    /// See `IotThingsGraph::search_flow_templates`.
+    pub fn search_flow_templates(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
+        self.runtime
+            .block_on(self.client.search_flow_templates(input))
+    }
+
+    /// <p>Searches for system instances in the user's account.</p>
+    ///
+    /// See `IotThingsGraph::search_system_instances`.
+    pub fn search_system_instances(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
+        self.runtime
+            .block_on(self.client.search_system_instances(input))
+    }
+
+    /// <p>Searches for summary information about systems in the user's account. You can filter by the ID of a workflow to return only systems that use the specified workflow.</p>
+    ///
+    /// See `IotThingsGraph::search_system_templates`.
+    pub fn search_system_templates(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
+        self.runtime
+            .block_on(self.client.search_system_templates(input))
+    }
+
+    /// <p>Searches for things associated with the specified entity. You can search by both device and device model.</p> <p>For example, if two different devices, camera1 and camera2, implement the camera device model, the user can associate thing1 to camera1 and thing2 to camera2. <code>SearchThings(camera2)</code> will return only thing2, but <code>SearchThings(camera)</code> will return both thing1 and thing2.</p> <p>This action searches for exact matches and doesn't perform partial text matching.</p>
+    ///
+    /// See `IotThingsGraph::search_things`.
+    pub fn search_things(
+        &self,
+        input: SearchThingsRequest,
+    ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
+        self.runtime.block_on(self.client.search_things(input))
+    }
+
+    /// <p>Creates a tag for the specified resource.</p>
+    ///
+    /// See `IotThingsGraph::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a system instance from its target (Cloud or Greengrass).</p>
+    ///
+    /// See `IotThingsGraph::undeploy_system_instance`.
+    pub fn undeploy_system_instance(
+        &self,
+        input: UndeploySystemInstanceRequest,
+    ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.undeploy_system_instance(input))
+    }
+
+    /// <p>Removes a tag from the specified resource.</p>
+    ///
+    /// See `IotThingsGraph::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the specified workflow. All deployed systems and system instances that use the workflow will see the changes in the flow when it is redeployed. If you don't want this behavior, copy the workflow (creating a new workflow with a different ID), and update the copy. The workflow can contain only entities in the specified namespace. </p>
+    ///
+    /// See `IotThingsGraph::update_flow_template`.
+    pub fn update_flow_template(
+        &self,
+        input: UpdateFlowTemplateRequest,
+    ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_flow_template(input))
+    }
+
+    /// <p>Updates the specified system. You don't need to run this action after updating a workflow. Any deployment that uses the system will see the changes in the system when it is redeployed.</p>
+    ///
+    /// See `IotThingsGraph::update_system_template`.
+    pub fn update_system_template(
+        &self,
+        input: UpdateSystemTemplateRequest,
+    ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_system_template(input))
+    }
+
+    /// <p>Asynchronously uploads one or more entity definitions to the user's namespace. The <code>document</code> parameter is required if <code>syncWithPublicNamespace</code> and <code>deleteExistingEntites</code> are false. If the <code>syncWithPublicNamespace</code> parameter is set to <code>true</code>, the user's namespace will synchronize with the latest version of the public namespace. If <code>deprecateExistingEntities</code> is set to true, all entities in the latest version will be deleted before the new <code>DefinitionDocument</code> is uploaded.</p> <p>When a user uploads entity definitions for the first time, the service creates a new namespace for the user. The new namespace tracks the public namespace. Currently users can have only one namespace. The namespace version increments whenever a user uploads entity definitions that are backwards-incompatible and whenever a user sets the <code>syncWithPublicNamespace</code> parameter or the <code>deprecateExistingEntities</code> parameter to <code>true</code>.</p> <p>The IDs for all of the entities should be in URN format. Each entity must be in the user's namespace. Users can't create entities in the public namespace, but entity definitions can refer to entities in the public namespace.</p> <p>Valid entities are <code>Device</code>, <code>DeviceModel</code>, <code>Service</code>, <code>Capability</code>, <code>State</code>, <code>Action</code>, <code>Event</code>, <code>Property</code>, <code>Mapping</code>, <code>Enum</code>. </p>
+    ///
+    /// See `IotThingsGraph::upload_entity_definitions`.
+    pub fn upload_entity_definitions(
+        &self,
+        input: UploadEntityDefinitionsRequest,
+    ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.upload_entity_definitions(input))
+    }
+}
@@ -1585,25 +1585,31 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateClusterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateClusterError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateClusterError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateClusterError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateClusterError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1658,29 +1664,40 @@ impl CreateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateConfigurationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateConfigurationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1726,16 +1743,19 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteClusterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteClusterError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteClusterError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteClusterError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1781,19 +1801,22 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeClusterError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeClusterError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeClusterError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeClusterError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeClusterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeClusterError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeClusterError::NotFound(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeClusterError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeClusterError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1840,23 +1863,34 @@ impl DescribeClusterOperationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterOperationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterOperationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeClusterOperationError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterOperationError::NotFound(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterOperationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1906,26 +1940,40 @@ impl DescribeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1978,34 +2026,40 @@ impl DescribeConfigurationRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationRevisionError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationRevisionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2057,21 +2111,28 @@ impl GetBootstrapBrokersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBootstrapBrokersError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetBootstrapBrokersError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetBootstrapBrokersError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBootstrapBrokersError::InternalServerError(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBootstrapBrokersError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2126,37 +2187,46 @@ impl GetCompatibleKafkaVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleKafkaVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleKafkaVersionsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCompatibleKafkaVersionsError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleKafkaVersionsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCompatibleKafkaVersionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleKafkaVersionsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCompatibleKafkaVersionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2206,18 +2276,28 @@ impl ListClusterOperationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListClusterOperationsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClusterOperationsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListClusterOperationsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClusterOperationsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListClusterOperationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListClusterOperationsError::InternalServerError(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListClusterOperationsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClusterOperationsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2260,16 +2340,19 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListClustersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListClustersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListClustersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListClustersError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListClustersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClustersError::InternalServerError(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListClustersError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListClustersError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2320,32 +2403,40 @@ impl ListConfigurationRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListConfigurationRevisionsError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListConfigurationRevisionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2397,23 +2488,28 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListConfigurationsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListConfigurationsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListConfigurationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListConfigurationsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2457,18 +2553,22 @@ impl ListKafkaVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListKafkaVersionsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListKafkaVersionsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListKafkaVersionsError::InternalServerError(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListKafkaVersionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2511,16 +2611,16 @@ impl ListNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListNodesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListNodesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListNodesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListNodesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListNodesError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListNodesError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListNodesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListNodesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2560,15 +2660,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2607,13 +2711,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2652,13 +2759,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2703,23 +2813,28 @@ impl UpdateBrokerCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerCountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerCountError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerCountError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerCountError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerCountError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2766,23 +2881,31 @@ impl UpdateBrokerStorageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerStorageError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerStorageError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerStorageError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerStorageError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerStorageError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2834,32 +2957,40 @@ impl UpdateClusterConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateClusterConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateClusterConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2917,35 +3048,46 @@ impl UpdateClusterKafkaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterKafkaVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterKafkaVersionError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateClusterKafkaVersionError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterKafkaVersionError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateClusterKafkaVersionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterKafkaVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterKafkaVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2996,21 +3138,25 @@ impl UpdateMonitoringError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMonitoringError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateMonitoringError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMonitoringError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateMonitoringError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMonitoringError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMonitoringError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateMonitoringError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMonitoringError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMonitoringError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateMonitoringError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3217,6 +3363,7 @@ impl KafkaClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3232,6 +3379,7 @@ impl KafkaClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KafkaClient {
         KafkaClient { client, region }
     }
@@ -3249,6 +3397,9 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3281,6 +3432,9 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation_name("CreateConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3316,6 +3470,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation_name("DeleteCluster");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3354,6 +3511,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("DescribeCluster");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3386,6 +3546,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("DescribeClusterOperation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3415,6 +3578,9 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("DescribeConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3451,6 +3617,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("DescribeConfigurationRevision");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3483,6 +3652,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("GetBootstrapBrokers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3513,6 +3685,9 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/compatible-kafka-versions";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("GetCompatibleKafkaVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3551,6 +3726,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListClusterOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3589,6 +3767,9 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3631,6 +3812,9 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}/revisions", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListConfigurationRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3669,6 +3853,9 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3707,6 +3894,9 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/kafka-versions";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListKafkaVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3748,6 +3938,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListNodes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3786,6 +3979,9 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3815,6 +4011,9 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3846,6 +4045,9 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3883,6 +4085,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation_name("UpdateBrokerCount");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3918,6 +4123,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation_name("UpdateBrokerStorage");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3954,6 +4162,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation_name("UpdateClusterConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3990,6 +4201,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation_name("UpdateClusterKafkaVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4025,6 +4239,9 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation_name("UpdateMonitoring");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4047,3 +4264,328 @@ impl Kafka for KafkaClient {
         }
     }
 }
+
+/// A blocking client for the Kafka API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KafkaClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KafkaBlockingClient {
+    client: KafkaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KafkaBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KafkaBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KafkaBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KafkaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KafkaBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> KafkaBlockingClient {
+        KafkaBlockingClient {
+            client: KafkaClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <pre><code>        &lt;p&gt;Creates a new MSK cluster.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Creates a new MSK configuration.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::create_configuration`.
+    pub fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_configuration(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Deletes the MSK cluster specified by the Amazon Resource Name (ARN) in the request.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::delete_cluster`.
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of the MSK cluster whose Amazon Resource Name (ARN) is specified in the request.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::describe_cluster`.
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of the cluster operation specified by the ARN.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::describe_cluster_operation`.
+    pub fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> Result<DescribeClusterOperationResponse, RusotoError<DescribeClusterOperationError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_operation(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of this MSK configuration.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::describe_configuration`.
+    pub fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+        self.runtime
+            .block_on(self.client.describe_configuration(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a description of this revision of the configuration.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::describe_configuration_revision`.
+    pub fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        DescribeConfigurationRevisionResponse,
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_revision(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;A list of brokers that a client application can use to bootstrap.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::get_bootstrap_brokers`.
+    pub fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> Result<GetBootstrapBrokersResponse, RusotoError<GetBootstrapBrokersError>> {
+        self.runtime
+            .block_on(self.client.get_bootstrap_brokers(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Gets the Apache Kafka versions to which you can update the MSK cluster.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::get_compatible_kafka_versions`.
+    pub fn get_compatible_kafka_versions(
+        &self,
+        input: GetCompatibleKafkaVersionsRequest,
+    ) -> Result<GetCompatibleKafkaVersionsResponse, RusotoError<GetCompatibleKafkaVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_compatible_kafka_versions(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the operations that have been performed on the specified MSK cluster.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_cluster_operations`.
+    pub fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> Result<ListClusterOperationsResponse, RusotoError<ListClusterOperationsError>> {
+        self.runtime
+            .block_on(self.client.list_cluster_operations(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK clusters in the current Region.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_clusters`.
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK configurations in this Region.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_configuration_revisions`.
+    pub fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_configuration_revisions(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of all the MSK configurations in this Region.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_configurations`.
+    pub fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_configurations(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of Kafka versions.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_kafka_versions`.
+    pub fn list_kafka_versions(
+        &self,
+        input: ListKafkaVersionsRequest,
+    ) -> Result<ListKafkaVersionsResponse, RusotoError<ListKafkaVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_kafka_versions(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of the broker nodes in the cluster.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_nodes`.
+    pub fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> Result<ListNodesResponse, RusotoError<ListNodesError>> {
+        self.runtime.block_on(self.client.list_nodes(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Returns a list of the tags associated with the specified resource.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Adds tags to the specified MSK resource.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Removes the tags associated with the keys that are provided in the query.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the number of broker nodes in the cluster.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::update_broker_count`.
+    pub fn update_broker_count(
+        &self,
+        input: UpdateBrokerCountRequest,
+    ) -> Result<UpdateBrokerCountResponse, RusotoError<UpdateBrokerCountError>> {
+        self.runtime
+            .block_on(self.client.update_broker_count(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the EBS storage associated with MSK brokers.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::update_broker_storage`.
+    pub fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> Result<UpdateBrokerStorageResponse, RusotoError<UpdateBrokerStorageError>> {
+        self.runtime
+            .block_on(self.client.update_broker_storage(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the cluster with the configuration that is specified in the request body.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::update_cluster_configuration`.
+    pub fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> Result<UpdateClusterConfigurationResponse, RusotoError<UpdateClusterConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_cluster_configuration(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the Apache Kafka version for the cluster.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::update_cluster_kafka_version`.
+    pub fn update_cluster_kafka_version(
+        &self,
+        input: UpdateClusterKafkaVersionRequest,
+    ) -> Result<UpdateClusterKafkaVersionResponse, RusotoError<UpdateClusterKafkaVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_cluster_kafka_version(input))
+    }
+
+    /// <pre><code>        &lt;p&gt;Updates the monitoring settings for the cluster. You can use this operation to specify which Apache Kafka metrics you want Amazon MSK to send to Amazon CloudWatch. You can also specify settings for open monitoring with Prometheus.&lt;/p&gt;
+    /// </code></pre>
+    ///
+    /// See `Kafka::update_monitoring`.
+    pub fn update_monitoring(
+        &self,
+        input: UpdateMonitoringRequest,
+    ) -> Result<UpdateMonitoringResponse, RusotoError<UpdateMonitoringError>> {
+        self.runtime.block_on(self.client.update_monitoring(input))
+    }
+}
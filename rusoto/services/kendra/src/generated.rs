@@ -1880,21 +1880,31 @@ impl BatchDeleteDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteDocumentError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::Conflict(err.msg))
+                    return RusotoError::service(&res, BatchDeleteDocumentError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteDocumentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteDocumentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDeleteDocumentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1938,24 +1948,31 @@ impl BatchPutDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchPutDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, BatchPutDocumentError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchPutDocumentError::Conflict(err.msg))
+                    return RusotoError::service(&res, BatchPutDocumentError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchPutDocumentError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutDocumentError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchPutDocumentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutDocumentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(BatchPutDocumentError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchPutDocumentError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutDocumentError::Throttling(err.msg))
+                    return RusotoError::service(&res, BatchPutDocumentError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2002,29 +2019,37 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceAlreadyExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::ResourceAlreadyExist(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateDataSourceError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2070,22 +2095,25 @@ impl CreateFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateFaqError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateFaqError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateFaqError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateFaqError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateFaqError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateFaqError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateFaqError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateFaqError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFaqError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFaqError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateFaqError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2130,22 +2158,28 @@ impl CreateIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIndexError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateIndexError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::InternalServer(err.msg))
                 }
                 "ResourceAlreadyExistException" => {
-                    return RusotoError::Service(CreateIndexError::ResourceAlreadyExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIndexError::ResourceAlreadyExist(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateIndexError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIndexError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIndexError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateIndexError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2188,19 +2222,25 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2242,19 +2282,19 @@ impl DeleteFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFaqError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteFaqError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteFaqError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteFaqError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteFaqError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteFaqError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFaqError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFaqError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteFaqError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2296,19 +2336,19 @@ impl DeleteIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteIndexError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIndexError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteIndexError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteIndexError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteIndexError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteIndexError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIndexError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteIndexError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2348,16 +2388,25 @@ impl DescribeDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2396,16 +2445,16 @@ impl DescribeFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeFaqError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeFaqError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeFaqError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DescribeFaqError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeFaqError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeFaqError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeFaqError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2444,16 +2493,19 @@ impl DescribeIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeIndexError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIndexError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIndexError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeIndexError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2494,23 +2546,34 @@ impl ListDataSourceSyncJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourceSyncJobsError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourceSyncJobsError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourceSyncJobsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourceSyncJobsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourceSyncJobsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2550,16 +2613,22 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourcesError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSourcesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourcesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourcesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2598,16 +2667,16 @@ impl ListFaqsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFaqsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListFaqsError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFaqsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListFaqsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFaqsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListFaqsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFaqsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListFaqsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2644,13 +2713,13 @@ impl ListIndicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIndicesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListIndicesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::InternalServer(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIndicesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListIndicesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2688,18 +2757,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2742,20 +2821,22 @@ impl QueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(QueryError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, QueryError::AccessDenied(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::service(&res, QueryError::Conflict(err.msg))
                 }
-                "ConflictException" => return RusotoError::Service(QueryError::Conflict(err.msg)),
                 "InternalServerException" => {
-                    return RusotoError::Service(QueryError::InternalServer(err.msg))
+                    return RusotoError::service(&res, QueryError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, QueryError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(QueryError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(&res, QueryError::ServiceQuotaExceeded(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(QueryError::Throttling(err.msg))
+                    return RusotoError::service(&res, QueryError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2800,28 +2881,40 @@ impl StartDataSourceSyncJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDataSourceSyncJobError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDataSourceSyncJobError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDataSourceSyncJobError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDataSourceSyncJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDataSourceSyncJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDataSourceSyncJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2862,20 +2955,28 @@ impl StopDataSourceSyncJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDataSourceSyncJobError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDataSourceSyncJobError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopDataSourceSyncJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDataSourceSyncJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2916,19 +3017,25 @@ impl SubmitFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitFeedbackError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, SubmitFeedbackError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SubmitFeedbackError::InternalServer(err.msg))
+                    return RusotoError::service(&res, SubmitFeedbackError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitFeedbackError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubmitFeedbackError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SubmitFeedbackError::Throttling(err.msg))
+                    return RusotoError::service(&res, SubmitFeedbackError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2968,16 +3075,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3016,16 +3126,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3066,19 +3179,25 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3122,22 +3241,25 @@ impl UpdateIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateIndexError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIndexError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateIndexError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateIndexError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateIndexError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateIndexError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(UpdateIndexError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIndexError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIndexError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateIndexError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3319,6 +3441,7 @@ impl KendraClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3334,6 +3457,7 @@ impl KendraClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KendraClient {
         KendraClient { client, region }
     }
@@ -3347,6 +3471,9 @@ impl Kendra for KendraClient {
         input: BatchDeleteDocumentRequest,
     ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDeleteDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.BatchDeleteDocument",
@@ -3368,6 +3495,9 @@ impl Kendra for KendraClient {
         input: BatchPutDocumentRequest,
     ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchPutDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.BatchPutDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3386,6 +3516,9 @@ impl Kendra for KendraClient {
         input: CreateDataSourceRequest,
     ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3404,6 +3537,9 @@ impl Kendra for KendraClient {
         input: CreateFaqRequest,
     ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFaq");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3422,6 +3558,9 @@ impl Kendra for KendraClient {
         input: CreateIndexRequest,
     ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateIndex");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3440,6 +3579,9 @@ impl Kendra for KendraClient {
         input: DeleteDataSourceRequest,
     ) -> Result<(), RusotoError<DeleteDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3454,6 +3596,9 @@ impl Kendra for KendraClient {
     /// <p>Removes an FAQ from an index.</p>
     async fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFaq");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3471,6 +3616,9 @@ impl Kendra for KendraClient {
         input: DeleteIndexRequest,
     ) -> Result<(), RusotoError<DeleteIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteIndex");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3488,6 +3636,9 @@ impl Kendra for KendraClient {
         input: DescribeDataSourceRequest,
     ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDataSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.DescribeDataSource",
@@ -3509,6 +3660,9 @@ impl Kendra for KendraClient {
         input: DescribeFaqRequest,
     ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFaq");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3527,6 +3681,9 @@ impl Kendra for KendraClient {
         input: DescribeIndexRequest,
     ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeIndex");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3545,6 +3702,9 @@ impl Kendra for KendraClient {
         input: ListDataSourceSyncJobsRequest,
     ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDataSourceSyncJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.ListDataSourceSyncJobs",
@@ -3567,6 +3727,9 @@ impl Kendra for KendraClient {
         input: ListDataSourcesRequest,
     ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDataSources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListDataSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3585,6 +3748,9 @@ impl Kendra for KendraClient {
         input: ListFaqsRequest,
     ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFaqs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListFaqs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3603,6 +3769,9 @@ impl Kendra for KendraClient {
         input: ListIndicesRequest,
     ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListIndices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListIndices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3621,6 +3790,9 @@ impl Kendra for KendraClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.ListTagsForResource",
@@ -3639,6 +3811,9 @@ impl Kendra for KendraClient {
     /// <p>Searches an active index. Use this API to search your documents using query. The <code>Query</code> operation enables to do faceted search and to filter results based on document attributes.</p> <p>It also enables you to provide user context that Amazon Kendra uses to enforce document access control in the search results. </p> <p>Amazon Kendra searches your index for text content and question and answer (FAQ) content. By default the response contains three types of results.</p> <ul> <li> <p>Relevant passages</p> </li> <li> <p>Matching FAQs</p> </li> <li> <p>Relevant documents</p> </li> </ul> <p>You can specify that the query return only one type of result using the <code>QueryResultTypeConfig</code> parameter.</p>
     async fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Query");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSKendraFrontendService.Query");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3657,6 +3832,9 @@ impl Kendra for KendraClient {
         input: StartDataSourceSyncJobRequest,
     ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDataSourceSyncJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.StartDataSourceSyncJob",
@@ -3679,6 +3857,9 @@ impl Kendra for KendraClient {
         input: StopDataSourceSyncJobRequest,
     ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopDataSourceSyncJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.StopDataSourceSyncJob",
@@ -3699,6 +3880,9 @@ impl Kendra for KendraClient {
         input: SubmitFeedbackRequest,
     ) -> Result<(), RusotoError<SubmitFeedbackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SubmitFeedback");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.SubmitFeedback");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3716,6 +3900,9 @@ impl Kendra for KendraClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3734,6 +3921,9 @@ impl Kendra for KendraClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3752,6 +3942,9 @@ impl Kendra for KendraClient {
         input: UpdateDataSourceRequest,
     ) -> Result<(), RusotoError<UpdateDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3769,6 +3962,9 @@ impl Kendra for KendraClient {
         input: UpdateIndexRequest,
     ) -> Result<(), RusotoError<UpdateIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateIndex");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3780,3 +3976,293 @@ impl Kendra for KendraClient {
         Ok(())
     }
 }
+
+/// A blocking client for the kendra API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KendraClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KendraBlockingClient {
+    client: KendraClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KendraBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KendraBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KendraBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KendraBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KendraBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> KendraBlockingClient {
+        KendraBlockingClient {
+            client: KendraClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Removes one or more documents from an index. The documents must have been added with the <a>BatchPutDocument</a> operation.</p> <p>The documents are deleted asynchronously. You can see the progress of the deletion by using AWS CloudWatch. Any error messages releated to the processing of the batch are sent to you CloudWatch log.</p>
+    ///
+    /// See `Kendra::batch_delete_document`.
+    pub fn batch_delete_document(
+        &self,
+        input: BatchDeleteDocumentRequest,
+    ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_document(input))
+    }
+
+    /// <p>Adds one or more documents to an index.</p> <p>The <code>BatchPutDocument</code> operation enables you to ingest inline documents or a set of documents stored in an Amazon S3 bucket. Use this operation to ingest your text and unstructured text into an index, add custom attributes to the documents, and to attach an access control list to the documents added to the index.</p> <p>The documents are indexed asynchronously. You can see the progress of the batch using AWS CloudWatch. Any error messages related to processing the batch are sent to your AWS CloudWatch log.</p>
+    ///
+    /// See `Kendra::batch_put_document`.
+    pub fn batch_put_document(
+        &self,
+        input: BatchPutDocumentRequest,
+    ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
+        self.runtime.block_on(self.client.batch_put_document(input))
+    }
+
+    /// <p>Creates a data source that you use to with an Amazon Kendra index. </p> <p>You specify a name, connector type and description for your data source. You can choose between an S3 connector, a SharePoint Online connector, and a database connector.</p> <p>You also specify configuration information such as document metadata (author, source URI, and so on) and user context information.</p> <p> <code>CreateDataSource</code> is a synchronous operation. The operation returns 200 if the data source was successfully created. Otherwise, an exception is raised.</p>
+    ///
+    /// See `Kendra::create_data_source`.
+    pub fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.runtime.block_on(self.client.create_data_source(input))
+    }
+
+    /// <p>Creates an new set of frequently asked question (FAQ) questions and answers.</p>
+    ///
+    /// See `Kendra::create_faq`.
+    pub fn create_faq(
+        &self,
+        input: CreateFaqRequest,
+    ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
+        self.runtime.block_on(self.client.create_faq(input))
+    }
+
+    /// <p>Creates a new Amazon Kendra index. Index creation is an asynchronous operation. To determine if index creation has completed, check the <code>Status</code> field returned from a call to . The <code>Status</code> field is set to <code>ACTIVE</code> when the index is ready to use.</p> <p>Once the index is active you can index your documents using the operation or using one of the supported data sources. </p>
+    ///
+    /// See `Kendra::create_index`.
+    pub fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        self.runtime.block_on(self.client.create_index(input))
+    }
+
+    /// <p>Deletes an Amazon Kendra data source. An exception is not thrown if the data source is already being deleted. While the data source is being deleted, the <code>Status</code> field returned by a call to the operation is set to <code>DELETING</code>. For more information, see <a href="https://docs.aws.amazon.com/kendra/latest/dg/delete-data-source.html">Deleting Data Sources</a>.</p>
+    ///
+    /// See `Kendra::delete_data_source`.
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<(), RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// <p>Removes an FAQ from an index.</p>
+    ///
+    /// See `Kendra::delete_faq`.
+    pub fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
+        self.runtime.block_on(self.client.delete_faq(input))
+    }
+
+    /// <p>Deletes an existing Amazon Kendra index. An exception is not thrown if the index is already being deleted. While the index is being deleted, the <code>Status</code> field returned by a call to the <a>DescribeIndex</a> operation is set to <code>DELETING</code>.</p>
+    ///
+    /// See `Kendra::delete_index`.
+    pub fn delete_index(
+        &self,
+        input: DeleteIndexRequest,
+    ) -> Result<(), RusotoError<DeleteIndexError>> {
+        self.runtime.block_on(self.client.delete_index(input))
+    }
+
+    /// <p>Gets information about a Amazon Kendra data source.</p>
+    ///
+    /// See `Kendra::describe_data_source`.
+    pub fn describe_data_source(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+        self.runtime
+            .block_on(self.client.describe_data_source(input))
+    }
+
+    /// <p>Gets information about an FAQ list.</p>
+    ///
+    /// See `Kendra::describe_faq`.
+    pub fn describe_faq(
+        &self,
+        input: DescribeFaqRequest,
+    ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
+        self.runtime.block_on(self.client.describe_faq(input))
+    }
+
+    /// <p>Describes an existing Amazon Kendra index</p>
+    ///
+    /// See `Kendra::describe_index`.
+    pub fn describe_index(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
+        self.runtime.block_on(self.client.describe_index(input))
+    }
+
+    /// <p>Gets statistics about synchronizing Amazon Kendra with a data source.</p>
+    ///
+    /// See `Kendra::list_data_source_sync_jobs`.
+    pub fn list_data_source_sync_jobs(
+        &self,
+        input: ListDataSourceSyncJobsRequest,
+    ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
+        self.runtime
+            .block_on(self.client.list_data_source_sync_jobs(input))
+    }
+
+    /// <p>Lists the data sources that you have created.</p>
+    ///
+    /// See `Kendra::list_data_sources`.
+    pub fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.runtime.block_on(self.client.list_data_sources(input))
+    }
+
+    /// <p>Gets a list of FAQ lists associated with an index.</p>
+    ///
+    /// See `Kendra::list_faqs`.
+    pub fn list_faqs(
+        &self,
+        input: ListFaqsRequest,
+    ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
+        self.runtime.block_on(self.client.list_faqs(input))
+    }
+
+    /// <p>Lists the Amazon Kendra indexes that you have created.</p>
+    ///
+    /// See `Kendra::list_indices`.
+    pub fn list_indices(
+        &self,
+        input: ListIndicesRequest,
+    ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
+        self.runtime.block_on(self.client.list_indices(input))
+    }
+
+    /// <p>Gets a list of tags associated with a specified resource. Indexes, FAQs, and data sources can have tags associated with them.</p>
+    ///
+    /// See `Kendra::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Searches an active index. Use this API to search your documents using query. The <code>Query</code> operation enables to do faceted search and to filter results based on document attributes.</p> <p>It also enables you to provide user context that Amazon Kendra uses to enforce document access control in the search results. </p> <p>Amazon Kendra searches your index for text content and question and answer (FAQ) content. By default the response contains three types of results.</p> <ul> <li> <p>Relevant passages</p> </li> <li> <p>Matching FAQs</p> </li> <li> <p>Relevant documents</p> </li> </ul> <p>You can specify that the query return only one type of result using the <code>QueryResultTypeConfig</code> parameter.</p>
+    ///
+    /// See `Kendra::query`.
+    pub fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
+        self.runtime.block_on(self.client.query(input))
+    }
+
+    /// <p>Starts a synchronization job for a data source. If a synchronization job is already in progress, Amazon Kendra returns a <code>ResourceInUseException</code> exception.</p>
+    ///
+    /// See `Kendra::start_data_source_sync_job`.
+    pub fn start_data_source_sync_job(
+        &self,
+        input: StartDataSourceSyncJobRequest,
+    ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
+        self.runtime
+            .block_on(self.client.start_data_source_sync_job(input))
+    }
+
+    /// <p>Stops a running synchronization job. You can't stop a scheduled synchronization job.</p>
+    ///
+    /// See `Kendra::stop_data_source_sync_job`.
+    pub fn stop_data_source_sync_job(
+        &self,
+        input: StopDataSourceSyncJobRequest,
+    ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
+        self.runtime
+            .block_on(self.client.stop_data_source_sync_job(input))
+    }
+
+    /// <p>Enables you to provide feedback to Amazon Kendra to improve the performance of the service. </p>
+    ///
+    /// See `Kendra::submit_feedback`.
+    pub fn submit_feedback(
+        &self,
+        input: SubmitFeedbackRequest,
+    ) -> Result<(), RusotoError<SubmitFeedbackError>> {
+        self.runtime.block_on(self.client.submit_feedback(input))
+    }
+
+    /// <p>Adds the specified tag to the specified index, FAQ, or data source resource. If the tag already exists, the existing value is replaced with the new value.</p>
+    ///
+    /// See `Kendra::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a tag from an index, FAQ, or a data source.</p>
+    ///
+    /// See `Kendra::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an existing Amazon Kendra data source.</p>
+    ///
+    /// See `Kendra::update_data_source`.
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<(), RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// <p>Updates an existing Amazon Kendra index.</p>
+    ///
+    /// See `Kendra::update_index`.
+    pub fn update_index(
+        &self,
+        input: UpdateIndexRequest,
+    ) -> Result<(), RusotoError<UpdateIndexError>> {
+        self.runtime.block_on(self.client.update_index(input))
+    }
+}
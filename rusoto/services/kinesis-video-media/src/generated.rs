@@ -91,22 +91,25 @@ impl GetMediaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetMediaError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetMediaError::ClientLimitExceeded(err.msg))
                 }
                 "ConnectionLimitExceededException" => {
-                    return RusotoError::Service(GetMediaError::ConnectionLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMediaError::ConnectionLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetMediaError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, GetMediaError::InvalidArgument(err.msg))
                 }
                 "InvalidEndpointException" => {
-                    return RusotoError::Service(GetMediaError::InvalidEndpoint(err.msg))
+                    return RusotoError::service(&res, GetMediaError::InvalidEndpoint(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetMediaError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetMediaError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMediaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetMediaError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -156,6 +159,7 @@ impl KinesisVideoMediaClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -171,6 +175,7 @@ impl KinesisVideoMediaClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoMediaClient {
         KinesisVideoMediaClient { client, region }
     }
@@ -187,6 +192,9 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         let request_uri = "/getMedia";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("GetMedia");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -212,3 +220,66 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         }
     }
 }
+
+/// A blocking client for the Kinesis Video Media API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisVideoMediaClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoMediaBlockingClient {
+    client: KinesisVideoMediaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoMediaBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisVideoMediaBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisVideoMediaBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoMediaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoMediaBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient {
+            client: KinesisVideoMediaClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p> Use this API to retrieve media content from a Kinesis video stream. In the request, you identify the stream name or stream Amazon Resource Name (ARN), and the starting chunk. Kinesis Video Streams then returns a stream of chunks in order by fragment number.</p> <note> <p>You must first call the <code>GetDataEndpoint</code> API to get an endpoint. Then send the <code>GetMedia</code> requests to this endpoint using the <a href="https://docs.aws.amazon.com/cli/latest/reference/">--endpoint-url parameter</a>. </p> </note> <p>When you put media data (fragments) on a stream, Kinesis Video Streams stores each incoming fragment and related metadata in what is called a &quot;chunk.&quot; For more information, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_dataplane_PutMedia.html">PutMedia</a>. The <code>GetMedia</code> API returns a stream of these chunks starting from the chunk that you specify in the request. </p> <p>The following limits apply when using the <code>GetMedia</code> API:</p> <ul> <li> <p>A client can call <code>GetMedia</code> up to five times per second per stream. </p> </li> <li> <p>Kinesis Video Streams sends media data at a rate of up to 25 megabytes per second (or 200 megabits per second) during a <code>GetMedia</code> session. </p> </li> </ul> <note> <p>If an error is thrown after invoking a Kinesis Video Streams media API, in addition to the HTTP status code and the response body, it includes the following pieces of information: </p> <ul> <li> <p> <code>x-amz-ErrorType</code> HTTP header – contains a more specific error type in addition to what the HTTP status code provides. </p> </li> <li> <p> <code>x-amz-RequestId</code> HTTP header – if you want to report an issue to AWS, the support team can better diagnose the problem if given the Request Id.</p> </li> </ul> <p>Both the HTTP status code and the ErrorType header can be utilized to make programmatic decisions about whether errors are retry-able and under what conditions, as well as provide information on what actions the client programmer might need to take in order to successfully try again.</p> <p>For more information, see the <b>Errors</b> section at the bottom of this topic, as well as <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/CommonErrors.html">Common Errors</a>. </p> </note></p>
+    ///
+    /// See `KinesisVideoMedia::get_media`.
+    pub fn get_media(
+        &self,
+        input: GetMediaInput,
+    ) -> Result<GetMediaOutput, RusotoError<GetMediaError>> {
+        self.runtime.block_on(self.client.get_media(input))
+    }
+}
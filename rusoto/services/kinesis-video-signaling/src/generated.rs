@@ -120,24 +120,40 @@ impl GetIceServerConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetIceServerConfigError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetIceServerConfigError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetIceServerConfigError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIceServerConfigError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(GetIceServerConfigError::InvalidClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIceServerConfigError::InvalidClient(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIceServerConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIceServerConfigError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIceServerConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIceServerConfigError::ResourceNotFound(err.msg),
+                    )
                 }
                 "SessionExpiredException" => {
-                    return RusotoError::Service(GetIceServerConfigError::SessionExpired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIceServerConfigError::SessionExpired(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -178,24 +194,28 @@ impl SendAlexaOfferToMasterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendAlexaOfferToMasterError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendAlexaOfferToMasterError::InvalidArgument(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::NotAuthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendAlexaOfferToMasterError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendAlexaOfferToMasterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -249,6 +269,7 @@ impl KinesisVideoSignalingClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -264,6 +285,7 @@ impl KinesisVideoSignalingClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoSignalingClient {
         KinesisVideoSignalingClient { client, region }
     }
@@ -280,6 +302,9 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/get-ice-server-config";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("GetIceServerConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -311,6 +336,9 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/send-alexa-offer-to-master";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("SendAlexaOfferToMaster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -333,3 +361,82 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Kinesis Video Signaling Channels API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisVideoSignalingClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoSignalingBlockingClient {
+    client: KinesisVideoSignalingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoSignalingBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisVideoSignalingBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisVideoSignalingBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoSignalingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoSignalingBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient {
+            client: KinesisVideoSignalingClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Gets the Interactive Connectivity Establishment (ICE) server configuration information, including URIs, username, and password which can be used to configure the WebRTC connection. The ICE component uses this configuration information to setup the WebRTC connection, including authenticating with the Traversal Using Relays around NAT (TURN) relay server. </p> <p>TURN is a protocol that is used to improve the connectivity of peer-to-peer applications. By providing a cloud-based relay service, TURN ensures that a connection can be established even when one or more peers are incapable of a direct peer-to-peer connection. For more information, see <a href="https://tools.ietf.org/html/draft-uberti-rtcweb-turn-rest-00">A REST API For Access To TURN Services</a>.</p> <p> You can invoke this API to establish a fallback mechanism in case either of the peers is unable to establish a direct peer-to-peer connection over a signaling channel. You must specify either a signaling channel ARN or the client ID in order to invoke this API.</p>
+    ///
+    /// See `KinesisVideoSignaling::get_ice_server_config`.
+    pub fn get_ice_server_config(
+        &self,
+        input: GetIceServerConfigRequest,
+    ) -> Result<GetIceServerConfigResponse, RusotoError<GetIceServerConfigError>> {
+        self.runtime
+            .block_on(self.client.get_ice_server_config(input))
+    }
+
+    /// <p>This API allows you to connect WebRTC-enabled devices with Alexa display devices. When invoked, it sends the Alexa Session Description Protocol (SDP) offer to the master peer. The offer is delivered as soon as the master is connected to the specified signaling channel. This API returns the SDP answer from the connected master. If the master is not connected to the signaling channel, redelivery requests are made until the message expires.</p>
+    ///
+    /// See `KinesisVideoSignaling::send_alexa_offer_to_master`.
+    pub fn send_alexa_offer_to_master(
+        &self,
+        input: SendAlexaOfferToMasterRequest,
+    ) -> Result<SendAlexaOfferToMasterResponse, RusotoError<SendAlexaOfferToMasterError>> {
+        self.runtime
+            .block_on(self.client.send_alexa_offer_to_master(input))
+    }
+}
@@ -1146,16 +1146,22 @@ impl AddTagsToStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddTagsToStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsToStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, AddTagsToStreamError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddTagsToStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, AddTagsToStreamError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1192,13 +1198,13 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::InvalidArgument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1238,22 +1244,26 @@ impl DecreaseStreamRetentionPeriodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseStreamRetentionPeriodError::InvalidArgument(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DecreaseStreamRetentionPeriodError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DecreaseStreamRetentionPeriodError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DecreaseStreamRetentionPeriodError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DecreaseStreamRetentionPeriodError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DecreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1296,13 +1306,13 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1338,19 +1348,22 @@ impl DeregisterStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterStreamConsumerError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterStreamConsumerError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterStreamConsumerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1382,7 +1395,7 @@ impl DescribeLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeLimitsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DescribeLimitsError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1414,10 +1427,13 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DescribeStreamError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1452,19 +1468,22 @@ impl DescribeStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamConsumerError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamConsumerError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamConsumerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1498,12 +1517,16 @@ impl DescribeStreamSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamSummaryError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamSummaryError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamSummaryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamSummaryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1540,24 +1563,28 @@ impl DisableEnhancedMonitoringError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableEnhancedMonitoringError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableEnhancedMonitoringError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableEnhancedMonitoringError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableEnhancedMonitoringError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1596,24 +1623,28 @@ impl EnableEnhancedMonitoringError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableEnhancedMonitoringError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableEnhancedMonitoringError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableEnhancedMonitoringError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableEnhancedMonitoringError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1664,36 +1695,37 @@ impl GetRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredIteratorException" => {
-                    return RusotoError::Service(GetRecordsError::ExpiredIterator(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::ExpiredIterator(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetRecordsError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::InvalidArgument(err.msg))
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(GetRecordsError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::KMSAccessDenied(err.msg))
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(GetRecordsError::KMSDisabled(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::KMSDisabled(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetRecordsError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::KMSInvalidState(err.msg))
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::KMSNotFound(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::KMSNotFound(err.msg))
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(GetRecordsError::KMSOptInRequired(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::KMSOptInRequired(err.msg))
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(GetRecordsError::KMSThrottling(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::KMSThrottling(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetRecordsError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRecordsError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetRecordsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1736,15 +1768,22 @@ impl GetShardIteratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetShardIteratorError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetShardIteratorError::InvalidArgument(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetShardIteratorError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetShardIteratorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1786,22 +1825,26 @@ impl IncreaseStreamRetentionPeriodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseStreamRetentionPeriodError::InvalidArgument(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(IncreaseStreamRetentionPeriodError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        IncreaseStreamRetentionPeriodError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(IncreaseStreamRetentionPeriodError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        IncreaseStreamRetentionPeriodError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         IncreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1848,19 +1891,19 @@ impl ListShardsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(ListShardsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(&res, ListShardsError::ExpiredNextToken(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListShardsError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, ListShardsError::InvalidArgument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListShardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListShardsError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListShardsError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, ListShardsError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListShardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListShardsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1902,23 +1945,34 @@ impl ListStreamConsumersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ExpiredNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamConsumersError::ExpiredNextToken(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListStreamConsumersError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamConsumersError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListStreamConsumersError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamConsumersError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamConsumersError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamConsumersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1952,7 +2006,7 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListStreamsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1986,13 +2040,22 @@ impl ListTagsForStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2030,16 +2093,16 @@ impl MergeShardsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(MergeShardsError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, MergeShardsError::InvalidArgument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(MergeShardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, MergeShardsError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(MergeShardsError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, MergeShardsError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MergeShardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, MergeShardsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2088,33 +2151,34 @@ impl PutRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, PutRecordError::InvalidArgument(err.msg))
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(PutRecordError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(&res, PutRecordError::KMSAccessDenied(err.msg))
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(PutRecordError::KMSDisabled(err.msg))
+                    return RusotoError::service(&res, PutRecordError::KMSDisabled(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutRecordError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, PutRecordError::KMSInvalidState(err.msg))
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::KMSNotFound(err.msg))
+                    return RusotoError::service(&res, PutRecordError::KMSNotFound(err.msg))
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(PutRecordError::KMSOptInRequired(err.msg))
+                    return RusotoError::service(&res, PutRecordError::KMSOptInRequired(err.msg))
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(PutRecordError::KMSThrottling(err.msg))
+                    return RusotoError::service(&res, PutRecordError::KMSThrottling(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutRecordError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRecordError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutRecordError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2168,33 +2232,34 @@ impl PutRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordsError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::InvalidArgument(err.msg))
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(PutRecordsError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::KMSAccessDenied(err.msg))
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(PutRecordsError::KMSDisabled(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::KMSDisabled(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutRecordsError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::KMSInvalidState(err.msg))
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(PutRecordsError::KMSNotFound(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::KMSNotFound(err.msg))
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(PutRecordsError::KMSOptInRequired(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::KMSOptInRequired(err.msg))
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(PutRecordsError::KMSThrottling(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::KMSThrottling(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutRecordsError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRecordsError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutRecordsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2238,24 +2303,28 @@ impl RegisterStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterStreamConsumerError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterStreamConsumerError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterStreamConsumerError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterStreamConsumerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2294,20 +2363,28 @@ impl RemoveTagsFromStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromStreamError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromStreamError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2346,16 +2423,16 @@ impl SplitShardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SplitShardError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, SplitShardError::InvalidArgument(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SplitShardError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, SplitShardError::LimitExceeded(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(SplitShardError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, SplitShardError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SplitShardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SplitShardError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2406,44 +2483,64 @@ impl StartStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::InvalidArgument(err.msg),
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSAccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::KMSAccessDenied(err.msg),
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSDisabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::KMSDisabled(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::KMSInvalidState(err.msg),
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::KMSNotFound(err.msg),
+                    )
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSOptInRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::KMSOptInRequired(err.msg),
+                    )
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSThrottling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::KMSThrottling(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamEncryptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2488,20 +2585,28 @@ impl StopStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamEncryptionError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamEncryptionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamEncryptionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamEncryptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2540,16 +2645,28 @@ impl SubscribeToShardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SubscribeToShardError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToShardError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SubscribeToShardError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToShardError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(SubscribeToShardError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToShardError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeToShardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SubscribeToShardError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2588,16 +2705,28 @@ impl UpdateShardCountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateShardCountError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateShardCountError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateShardCountError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateShardCountError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateShardCountError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateShardCountError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateShardCountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateShardCountError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2804,6 +2933,7 @@ impl KinesisClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2819,6 +2949,7 @@ impl KinesisClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisClient {
         KinesisClient { client, region }
     }
@@ -2832,6 +2963,9 @@ impl Kinesis for KinesisClient {
         input: AddTagsToStreamInput,
     ) -> Result<(), RusotoError<AddTagsToStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.AddTagsToStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2849,6 +2983,9 @@ impl Kinesis for KinesisClient {
         input: CreateStreamInput,
     ) -> Result<(), RusotoError<CreateStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.CreateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2866,6 +3003,9 @@ impl Kinesis for KinesisClient {
         input: DecreaseStreamRetentionPeriodInput,
     ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DecreaseStreamRetentionPeriod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Kinesis_20131202.DecreaseStreamRetentionPeriod",
@@ -2886,6 +3026,9 @@ impl Kinesis for KinesisClient {
         input: DeleteStreamInput,
     ) -> Result<(), RusotoError<DeleteStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.DeleteStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2903,6 +3046,9 @@ impl Kinesis for KinesisClient {
         input: DeregisterStreamConsumerInput,
     ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterStreamConsumer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.DeregisterStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2919,6 +3065,9 @@ impl Kinesis for KinesisClient {
         &self,
     ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2936,6 +3085,9 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamInput,
     ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2954,6 +3106,9 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamConsumerInput,
     ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStreamConsumer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2973,6 +3128,9 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamSummaryInput,
     ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStreamSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2991,6 +3149,9 @@ impl Kinesis for KinesisClient {
         input: DisableEnhancedMonitoringInput,
     ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableEnhancedMonitoring");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.DisableEnhancedMonitoring");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3009,6 +3170,9 @@ impl Kinesis for KinesisClient {
         input: EnableEnhancedMonitoringInput,
     ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableEnhancedMonitoring");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.EnableEnhancedMonitoring");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3027,6 +3191,9 @@ impl Kinesis for KinesisClient {
         input: GetRecordsInput,
     ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRecords");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.GetRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3045,6 +3212,9 @@ impl Kinesis for KinesisClient {
         input: GetShardIteratorInput,
     ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetShardIterator");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.GetShardIterator");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3063,6 +3233,9 @@ impl Kinesis for KinesisClient {
         input: IncreaseStreamRetentionPeriodInput,
     ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("IncreaseStreamRetentionPeriod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Kinesis_20131202.IncreaseStreamRetentionPeriod",
@@ -3083,6 +3256,9 @@ impl Kinesis for KinesisClient {
         input: ListShardsInput,
     ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListShards");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.ListShards");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3101,6 +3277,9 @@ impl Kinesis for KinesisClient {
         input: ListStreamConsumersInput,
     ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListStreamConsumers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreamConsumers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3119,6 +3298,9 @@ impl Kinesis for KinesisClient {
         input: ListStreamsInput,
     ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3137,6 +3319,9 @@ impl Kinesis for KinesisClient {
         input: ListTagsForStreamInput,
     ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Kinesis_20131202.ListTagsForStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3155,6 +3340,9 @@ impl Kinesis for KinesisClient {
         input: MergeShardsInput,
     ) -> Result<(), RusotoError<MergeShardsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MergeShards");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.MergeShards");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3172,6 +3360,9 @@ impl Kinesis for KinesisClient {
         input: PutRecordInput,
     ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRecord");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3190,6 +3381,9 @@ impl Kinesis for KinesisClient {
         input: PutRecordsInput,
     ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRecords");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3208,6 +3402,9 @@ impl Kinesis for KinesisClient {
         input: RegisterStreamConsumerInput,
     ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterStreamConsumer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.RegisterStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3227,6 +3424,9 @@ impl Kinesis for KinesisClient {
         input: RemoveTagsFromStreamInput,
     ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.RemoveTagsFromStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3244,6 +3444,9 @@ impl Kinesis for KinesisClient {
         input: SplitShardInput,
     ) -> Result<(), RusotoError<SplitShardError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SplitShard");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.SplitShard");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3261,6 +3464,9 @@ impl Kinesis for KinesisClient {
         input: StartStreamEncryptionInput,
     ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartStreamEncryption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.StartStreamEncryption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3278,6 +3484,9 @@ impl Kinesis for KinesisClient {
         input: StopStreamEncryptionInput,
     ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopStreamEncryption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.StopStreamEncryption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3295,6 +3504,9 @@ impl Kinesis for KinesisClient {
         input: SubscribeToShardInput,
     ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SubscribeToShard");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.SubscribeToShard");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3313,6 +3525,9 @@ impl Kinesis for KinesisClient {
         input: UpdateShardCountInput,
     ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateShardCount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Kinesis_20131202.UpdateShardCount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3325,3 +3540,342 @@ impl Kinesis for KinesisClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateShardCountOutput, _>()
     }
 }
+
+/// A blocking client for the Kinesis API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisBlockingClient {
+    client: KinesisClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> KinesisBlockingClient {
+        KinesisBlockingClient {
+            client: KinesisClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds or updates tags for the specified Kinesis data stream. Each time you invoke this operation, you can specify up to 10 tags. If you want to add more than 10 tags to your stream, you can invoke this operation multiple times. In total, each stream can have up to 50 tags.</p> <p>If tags have already been assigned to the stream, <code>AddTagsToStream</code> overwrites any existing tags that correspond to the specified tag keys.</p> <p> <a>AddTagsToStream</a> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::add_tags_to_stream`.
+    pub fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> Result<(), RusotoError<AddTagsToStreamError>> {
+        self.runtime.block_on(self.client.add_tags_to_stream(input))
+    }
+
+    /// <p>Creates a Kinesis data stream. A stream captures and transports data records that are continuously emitted from different data sources or <i>producers</i>. Scale-out within a stream is explicitly supported by means of shards, which are uniquely identified groups of data records in a stream.</p> <p>You specify and control the number of shards that a stream is composed of. Each shard can support reads up to five transactions per second, up to a maximum data read total of 2 MB per second. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second. If the amount of data input increases or decreases, you can add or remove shards.</p> <p>The stream name identifies the stream. The name is scoped to the AWS account used by the application. It is also scoped by AWS Region. That is, two streams in two different accounts can have the same name, and two streams in the same account, but in two different Regions, can have the same name.</p> <p> <code>CreateStream</code> is an asynchronous operation. Upon receiving a <code>CreateStream</code> request, Kinesis Data Streams immediately returns and sets the stream status to <code>CREATING</code>. After the stream is created, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. You should perform read and write operations only on an <code>ACTIVE</code> stream. </p> <p>You receive a <code>LimitExceededException</code> when making a <code>CreateStream</code> request when you try to do one of the following:</p> <ul> <li> <p>Have more than five streams in the <code>CREATING</code> state at any point in time.</p> </li> <li> <p>Create more shards than are authorized for your account.</p> </li> </ul> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>You can use <code>DescribeStream</code> to check the stream status, which is returned in <code>StreamStatus</code>.</p> <p> <a>CreateStream</a> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::create_stream`.
+    pub fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<(), RusotoError<CreateStreamError>> {
+        self.runtime.block_on(self.client.create_stream(input))
+    }
+
+    /// <p>Decreases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The minimum value of a stream's retention period is 24 hours.</p> <p>This operation may result in lost data. For example, if the stream's retention period is 48 hours and is decreased to 24 hours, any data already in the stream that is older than 24 hours is inaccessible.</p>
+    ///
+    /// See `Kinesis::decrease_stream_retention_period`.
+    pub fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
+        self.runtime
+            .block_on(self.client.decrease_stream_retention_period(input))
+    }
+
+    /// <p>Deletes a Kinesis data stream and all its shards and data. You must shut down any applications that are operating on the stream before you delete the stream. If an application attempts to operate on a deleted stream, it receives the exception <code>ResourceNotFoundException</code>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can delete it. After a <code>DeleteStream</code> request, the specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> <p> <b>Note:</b> Kinesis Data Streams might continue to accept data read and write operations, such as <a>PutRecord</a>, <a>PutRecords</a>, and <a>GetRecords</a>, on a stream in the <code>DELETING</code> state until the stream deletion is complete.</p> <p>When you delete a stream, any shards in that stream are also deleted, and any tags are dissociated from the stream.</p> <p>You can use the <a>DescribeStream</a> operation to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <a>DeleteStream</a> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::delete_stream`.
+    pub fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<(), RusotoError<DeleteStreamError>> {
+        self.runtime.block_on(self.client.delete_stream(input))
+    }
+
+    /// <p>To deregister a consumer, provide its ARN. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to deregister, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its name and ARN.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::deregister_stream_consumer`.
+    pub fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
+        self.runtime
+            .block_on(self.client.deregister_stream_consumer(input))
+    }
+
+    /// <p>Describes the shard limits and usage for the account.</p> <p>If you update your account limits, the old limits might be returned for a few minutes.</p> <p>This operation has a limit of one transaction per second per account.</p>
+    ///
+    /// See `Kinesis::describe_limits`.
+    pub fn describe_limits(
+        &self,
+    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        self.runtime.block_on(self.client.describe_limits())
+    }
+
+    /// <p>Describes the specified Kinesis data stream.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), creation time, enhanced metric configuration, and shard map. The shard map is an array of shard objects. For each shard object, there is the hash key and sequence number ranges that the shard spans, and the IDs of any earlier shards that played in a role in creating the shard. Every record ingested in the stream is identified by a sequence number, which is assigned when the record is put into the stream.</p> <p>You can limit the number of shards returned by each call. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-retrieve-shards.html">Retrieving Shards from a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>There are no guarantees about the chronological order shards returned. To process shards in chronological order, use the ID of the parent shard to track the lineage to the oldest shard.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
+    ///
+    /// See `Kinesis::describe_stream`.
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// <p>To get the description of a registered consumer, provide the ARN of the consumer. Alternatively, you can provide the ARN of the data stream and the name you gave the consumer when you registered it. You may also provide all three parameters, as long as they don't conflict with each other. If you don't know the name or ARN of the consumer that you want to describe, you can use the <a>ListStreamConsumers</a> operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream.</p> <p>This operation has a limit of 20 transactions per second per account.</p>
+    ///
+    /// See `Kinesis::describe_stream_consumer`.
+    pub fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
+        self.runtime
+            .block_on(self.client.describe_stream_consumer(input))
+    }
+
+    /// <p>Provides a summarized description of the specified Kinesis data stream without the shard list.</p> <p>The information returned includes the stream name, Amazon Resource Name (ARN), status, record retention period, approximate creation time, monitoring, encryption details, and open shard count. </p>
+    ///
+    /// See `Kinesis::describe_stream_summary`.
+    pub fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
+        self.runtime
+            .block_on(self.client.describe_stream_summary(input))
+    }
+
+    /// <p>Disables enhanced monitoring.</p>
+    ///
+    /// See `Kinesis::disable_enhanced_monitoring`.
+    pub fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
+        self.runtime
+            .block_on(self.client.disable_enhanced_monitoring(input))
+    }
+
+    /// <p>Enables enhanced Kinesis data stream monitoring for shard-level metrics.</p>
+    ///
+    /// See `Kinesis::enable_enhanced_monitoring`.
+    pub fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
+        self.runtime
+            .block_on(self.client.enable_enhanced_monitoring(input))
+    }
+
+    /// <p>Gets data records from a Kinesis data stream's shard.</p> <p>Specify a shard iterator using the <code>ShardIterator</code> parameter. The shard iterator specifies the position in the shard from which you want to start reading data records sequentially. If there are no records available in the portion of the shard that the iterator points to, <a>GetRecords</a> returns an empty list. It might take multiple calls to get to a portion of the shard that contains records.</p> <p>You can scale by provisioning multiple shards per stream while considering service limits (for more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Amazon Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>). Your application should have one thread per shard, each reading continuously from its stream. To read from a stream continually, call <a>GetRecords</a> in a loop. Use <a>GetShardIterator</a> to get the shard iterator to specify in the first <a>GetRecords</a> call. <a>GetRecords</a> returns a new shard iterator in <code>NextShardIterator</code>. Specify the shard iterator returned in <code>NextShardIterator</code> in subsequent calls to <a>GetRecords</a>. If the shard has been closed, the shard iterator can't return more data and <a>GetRecords</a> returns <code>null</code> in <code>NextShardIterator</code>. You can terminate the loop when the shard is closed, or when the shard iterator reaches the record with the sequence number or other attribute that marks it as the last record to process.</p> <p>Each data record can be up to 1 MiB in size, and each shard can read up to 2 MiB per second. You can ensure that your calls don't exceed the maximum supported size or throughput by using the <code>Limit</code> parameter to specify the maximum number of records that <a>GetRecords</a> can return. Consider your average record size when determining this limit. The maximum number of records that can be returned per call is 10,000.</p> <p>The size of the data returned by <a>GetRecords</a> varies depending on the utilization of the shard. The maximum size of data that <a>GetRecords</a> can return is 10 MiB. If a call returns this amount of data, subsequent calls made within the next 5 seconds throw <code>ProvisionedThroughputExceededException</code>. If there is insufficient provisioned throughput on the stream, subsequent calls made within the next 1 second throw <code>ProvisionedThroughputExceededException</code>. <a>GetRecords</a> doesn't return any data when it throws an exception. For this reason, we recommend that you wait 1 second between calls to <a>GetRecords</a>. However, it's possible that the application will get exceptions for longer than 1 second.</p> <p>To detect whether the application is falling behind in processing, you can use the <code>MillisBehindLatest</code> response attribute. You can also monitor the stream using CloudWatch metrics and other mechanisms (see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring.html">Monitoring</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>).</p> <p>Each Amazon Kinesis record includes a value, <code>ApproximateArrivalTimestamp</code>, that is set when a stream successfully receives and stores a record. This is commonly referred to as a server-side time stamp, whereas a client-side time stamp is set when a data producer creates or sends the record to a stream (a data producer is any data source putting data records into a stream, for example with <a>PutRecords</a>). The time stamp has millisecond precision. There are no guarantees about the time stamp accuracy, or that the time stamp is always increasing. For example, records in a shard or across a stream might have time stamps that are out of order.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::get_records`.
+    pub fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+        self.runtime.block_on(self.client.get_records(input))
+    }
+
+    /// <p>Gets an Amazon Kinesis shard iterator. A shard iterator expires 5 minutes after it is returned to the requester.</p> <p>A shard iterator specifies the shard position from which to start reading data records sequentially. The position is specified using the sequence number of a data record in a shard. A sequence number is the identifier associated with every record ingested in the stream, and is assigned when a record is put into the stream. Each stream has one or more shards.</p> <p>You must specify the shard iterator type. For example, you can set the <code>ShardIteratorType</code> parameter to read exactly from the position denoted by a specific sequence number by using the <code>AT_SEQUENCE_NUMBER</code> shard iterator type. Alternatively, the parameter can read right after the sequence number by using the <code>AFTER_SEQUENCE_NUMBER</code> shard iterator type, using sequence numbers returned by earlier calls to <a>PutRecord</a>, <a>PutRecords</a>, <a>GetRecords</a>, or <a>DescribeStream</a>. In the request, you can specify the shard iterator type <code>AT_TIMESTAMP</code> to read records from an arbitrary point in time, <code>TRIM_HORIZON</code> to cause <code>ShardIterator</code> to point to the last untrimmed record in the shard in the system (the oldest data record in the shard), or <code>LATEST</code> so that you always read the most recent data in the shard. </p> <p>When you read repeatedly from a stream, use a <a>GetShardIterator</a> request to get the first shard iterator for use in your first <a>GetRecords</a> request and for subsequent reads use the shard iterator returned by the <a>GetRecords</a> request in <code>NextShardIterator</code>. A new shard iterator is returned by every <a>GetRecords</a> request in <code>NextShardIterator</code>, which you use in the <code>ShardIterator</code> parameter of the next <a>GetRecords</a> request. </p> <p>If a <a>GetShardIterator</a> request is made too often, you receive a <code>ProvisionedThroughputExceededException</code>. For more information about throughput limits, see <a>GetRecords</a>, and <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the shard is closed, <a>GetShardIterator</a> returns a valid iterator for the last sequence number of the shard. A shard can be closed as a result of using <a>SplitShard</a> or <a>MergeShards</a>.</p> <p> <a>GetShardIterator</a> has a limit of five transactions per second per account per open shard.</p>
+    ///
+    /// See `Kinesis::get_shard_iterator`.
+    pub fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+        self.runtime.block_on(self.client.get_shard_iterator(input))
+    }
+
+    /// <p>Increases the Kinesis data stream's retention period, which is the length of time data records are accessible after they are added to the stream. The maximum value of a stream's retention period is 168 hours (7 days).</p> <p>If you choose a longer stream retention period, this operation increases the time period during which records that have not yet expired are accessible. However, it does not make previous, expired data (older than the stream's previous retention period) accessible after the operation has been called. For example, if a stream's retention period is set to 24 hours and is increased to 168 hours, any data that is older than 24 hours remains inaccessible to consumer applications.</p>
+    ///
+    /// See `Kinesis::increase_stream_retention_period`.
+    pub fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
+        self.runtime
+            .block_on(self.client.increase_stream_retention_period(input))
+    }
+
+    /// <p><p>Lists the shards in a stream and provides information about each shard. This operation has a limit of 100 transactions per second per data stream.</p> <important> <p>This API is a new operation that is used by the Amazon Kinesis Client Library (KCL). If you have a fine-grained IAM policy that only allows specific operations, you must update your policy to allow calls to this API. For more information, see <a href="https://docs.aws.amazon.com/streams/latest/dev/controlling-access.html">Controlling Access to Amazon Kinesis Data Streams Resources Using IAM</a>.</p> </important></p>
+    ///
+    /// See `Kinesis::list_shards`.
+    pub fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
+        self.runtime.block_on(self.client.list_shards(input))
+    }
+
+    /// <p>Lists the consumers registered to receive data from a stream using enhanced fan-out, and provides information about each consumer.</p> <p>This operation has a limit of 10 transactions per second per account.</p>
+    ///
+    /// See `Kinesis::list_stream_consumers`.
+    pub fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
+        self.runtime
+            .block_on(self.client.list_stream_consumers(input))
+    }
+
+    /// <p>Lists your Kinesis data streams.</p> <p>The number of streams may be too large to return from a single call to <code>ListStreams</code>. You can limit the number of returned streams using the <code>Limit</code> parameter. If you do not specify a value for the <code>Limit</code> parameter, Kinesis Data Streams uses the default limit, which is currently 10.</p> <p>You can detect if there are more streams available to list by using the <code>HasMoreStreams</code> flag from the returned output. If there are more streams available, you can request more streams by using the name of the last stream returned by the <code>ListStreams</code> request in the <code>ExclusiveStartStreamName</code> parameter in a subsequent request to <code>ListStreams</code>. The group of stream names returned by the subsequent request is then added to the list. You can continue this process until all the stream names have been collected in the list. </p> <p> <a>ListStreams</a> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::list_streams`.
+    pub fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+
+    /// <p>Lists the tags for the specified Kinesis data stream. This operation has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::list_tags_for_stream`.
+    pub fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_stream(input))
+    }
+
+    /// <p>Merges two adjacent shards in a Kinesis data stream and combines them into a single shard to reduce the stream's capacity to ingest and transport data. Two shards are considered adjacent if the union of the hash key ranges for the two shards form a contiguous set with no gaps. For example, if you have two shards, one with a hash key range of 276...381 and the other with a hash key range of 382...454, then you could merge these two shards into a single shard that would have a hash key range of 276...454. After the merge, the single child shard receives data for all hash key values covered by the two parent shards.</p> <p> <code>MergeShards</code> is called when there is a need to reduce the overall capacity of a stream because of excess capacity that is not being used. You must specify the shard to be merged and the adjacent shard for a stream. For more information about merging shards, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-merge.html">Merge Two Shards</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If the stream is in the <code>ACTIVE</code> state, you can call <code>MergeShards</code>. If a stream is in the <code>CREATING</code>, <code>UPDATING</code>, or <code>DELETING</code> state, <code>MergeShards</code> returns a <code>ResourceInUseException</code>. If the specified stream does not exist, <code>MergeShards</code> returns a <code>ResourceNotFoundException</code>. </p> <p>You can use <a>DescribeStream</a> to check the state of the stream, which is returned in <code>StreamStatus</code>.</p> <p> <code>MergeShards</code> is an asynchronous operation. Upon receiving a <code>MergeShards</code> request, Amazon Kinesis Data Streams immediately returns a response and sets the <code>StreamStatus</code> to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the <code>StreamStatus</code> to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You use <a>DescribeStream</a> to determine the shard IDs that are specified in the <code>MergeShards</code> request. </p> <p>If you try to operate on too many streams in parallel using <a>CreateStream</a>, <a>DeleteStream</a>, <code>MergeShards</code>, or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>MergeShards</code> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::merge_shards`.
+    pub fn merge_shards(
+        &self,
+        input: MergeShardsInput,
+    ) -> Result<(), RusotoError<MergeShardsError>> {
+        self.runtime.block_on(self.client.merge_shards(input))
+    }
+
+    /// <p>Writes a single data record into an Amazon Kinesis data stream. Call <code>PutRecord</code> to send data into the stream for real-time ingestion and subsequent processing, one record at a time. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; a partition key; and the data blob itself.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams to distribute data across shards. Kinesis Data Streams segregates the data records that belong to a stream into multiple shards, using the partition key associated with each data record to determine the shard to which a given data record belongs.</p> <p>Partition keys are Unicode strings, with a maximum length limit of 256 characters for each key. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards using the hash key ranges of the shards. You can override hashing the partition key to determine the shard by explicitly specifying a hash value using the <code>ExplicitHashKey</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p> <code>PutRecord</code> returns the shard ID of where the data record was placed and the sequence number that was assigned to the data record.</p> <p>Sequence numbers increase over time and are specific to a shard within a stream, not across all shards within a stream. To guarantee strictly increasing ordering, write serially to a shard and use the <code>SequenceNumberForOrdering</code> parameter. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>If a <code>PutRecord</code> request cannot be processed because of insufficient provisioned throughput on the shard involved in the request, <code>PutRecord</code> throws <code>ProvisionedThroughputExceededException</code>. </p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
+    ///
+    /// See `Kinesis::put_record`.
+    pub fn put_record(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+        self.runtime.block_on(self.client.put_record(input))
+    }
+
+    /// <p>Writes multiple data records into a Kinesis data stream in a single call (also referred to as a <code>PutRecords</code> request). Use this operation to send data into the stream for data ingestion and processing. </p> <p>Each <code>PutRecords</code> request can support up to 500 records. Each record in the request can be as large as 1 MB, up to a limit of 5 MB for the entire request, including partition keys. Each shard can support writes up to 1,000 records per second, up to a maximum data write total of 1 MB per second.</p> <p>You must specify the name of the stream that captures, stores, and transports the data; and an array of request <code>Records</code>, with each record in the array requiring a partition key and data blob. The record size limit applies to the total size of the partition key and data blob.</p> <p>The data blob can be any type of data; for example, a segment from a log file, geographic/location data, website clickstream data, and so on.</p> <p>The partition key is used by Kinesis Data Streams as input to a hash function that maps the partition key and associated data to a specific shard. An MD5 hash function is used to map partition keys to 128-bit integer values and to map associated data records to shards. As a result of this hashing mechanism, all data records with the same partition key map to the same shard within the stream. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-add-data-to-stream">Adding Data to a Stream</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>Each record in the <code>Records</code> array may include an optional parameter, <code>ExplicitHashKey</code>, which overrides the partition key to shard mapping. This parameter allows a data producer to determine explicitly the shard where the record is stored. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/developing-producers-with-sdk.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>The <code>PutRecords</code> response includes an array of response <code>Records</code>. Each record in the response array directly correlates with a record in the request array using natural ordering, from the top to the bottom of the request and response. The response <code>Records</code> array always includes the same number of records as the request array.</p> <p>The response <code>Records</code> array includes both successfully and unsuccessfully processed records. Kinesis Data Streams attempts to process all records in each <code>PutRecords</code> request. A single record failure does not stop the processing of subsequent records.</p> <p>A successfully processed record includes <code>ShardId</code> and <code>SequenceNumber</code> values. The <code>ShardId</code> parameter identifies the shard in the stream where the record is stored. The <code>SequenceNumber</code> parameter is an identifier assigned to the put record, unique to all records in the stream.</p> <p>An unsuccessfully processed record includes <code>ErrorCode</code> and <code>ErrorMessage</code> values. <code>ErrorCode</code> reflects the type of error and can be one of the following values: <code>ProvisionedThroughputExceededException</code> or <code>InternalFailure</code>. <code>ErrorMessage</code> provides more detailed information about the <code>ProvisionedThroughputExceededException</code> exception including the account ID, stream name, and shard ID of the record that was throttled. For more information about partially successful responses, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-add-data-to-stream.html#kinesis-using-sdk-java-putrecords">Adding Multiple Records with PutRecords</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>By default, data records are accessible for 24 hours from the time that they are added to a stream. You can use <a>IncreaseStreamRetentionPeriod</a> or <a>DecreaseStreamRetentionPeriod</a> to modify this retention period.</p>
+    ///
+    /// See `Kinesis::put_records`.
+    pub fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
+        self.runtime.block_on(self.client.put_records(input))
+    }
+
+    /// <p>Registers a consumer with a Kinesis data stream. When you use this operation, the consumer you register can read data from the stream at a rate of up to 2 MiB per second. This rate is unaffected by the total number of consumers that read from the same stream.</p> <p>You can register up to 5 consumers per stream. A given consumer can only be registered with one stream.</p> <p>This operation has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::register_stream_consumer`.
+    pub fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
+        self.runtime
+            .block_on(self.client.register_stream_consumer(input))
+    }
+
+    /// <p>Removes tags from the specified Kinesis data stream. Removed tags are deleted and cannot be recovered after this operation successfully completes.</p> <p>If you specify a tag that does not exist, it is ignored.</p> <p> <a>RemoveTagsFromStream</a> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::remove_tags_from_stream`.
+    pub fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_stream(input))
+    }
+
+    /// <p>Splits a shard into two new shards in the Kinesis data stream, to increase the stream's capacity to ingest and transport data. <code>SplitShard</code> is called when there is a need to increase the overall capacity of a stream because of an expected increase in the volume of data records being ingested. </p> <p>You can also use <code>SplitShard</code> when a shard appears to be approaching its maximum utilization; for example, the producers sending data into the specific shard are suddenly sending more than previously anticipated. You can also call <code>SplitShard</code> to increase stream capacity, so that more Kinesis Data Streams applications can simultaneously read data from the stream for real-time processing. </p> <p>You must specify the shard to be split and the new hash key, which is the position in the shard where the shard gets split in two. In many cases, the new hash key might be the average of the beginning and ending hash key, but it can be any hash key value in the range being mapped into the shard. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/kinesis-using-sdk-java-resharding-split.html">Split a Shard</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p> <p>You can use <a>DescribeStream</a> to determine the shard ID and hash key values for the <code>ShardToSplit</code> and <code>NewStartingHashKey</code> parameters that are specified in the <code>SplitShard</code> request.</p> <p> <code>SplitShard</code> is an asynchronous operation. Upon receiving a <code>SplitShard</code> request, Kinesis Data Streams immediately returns a response and sets the stream status to <code>UPDATING</code>. After the operation is completed, Kinesis Data Streams sets the stream status to <code>ACTIVE</code>. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state. </p> <p>You can use <code>DescribeStream</code> to check the status of the stream, which is returned in <code>StreamStatus</code>. If the stream is in the <code>ACTIVE</code> state, you can call <code>SplitShard</code>. If a stream is in <code>CREATING</code> or <code>UPDATING</code> or <code>DELETING</code> states, <code>DescribeStream</code> returns a <code>ResourceInUseException</code>.</p> <p>If the specified stream does not exist, <code>DescribeStream</code> returns a <code>ResourceNotFoundException</code>. If you try to create more shards than are authorized for your account, you receive a <code>LimitExceededException</code>. </p> <p>For the default shard limit for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To increase this limit, <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html">contact AWS Support</a>.</p> <p>If you try to operate on too many streams simultaneously using <a>CreateStream</a>, <a>DeleteStream</a>, <a>MergeShards</a>, and/or <a>SplitShard</a>, you receive a <code>LimitExceededException</code>. </p> <p> <code>SplitShard</code> has a limit of five transactions per second per account.</p>
+    ///
+    /// See `Kinesis::split_shard`.
+    pub fn split_shard(&self, input: SplitShardInput) -> Result<(), RusotoError<SplitShardError>> {
+        self.runtime.block_on(self.client.split_shard(input))
+    }
+
+    /// <p>Enables or updates server-side encryption using an AWS KMS key for a specified stream. </p> <p>Starting encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Updating or applying encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, encryption begins for records written to the stream. </p> <p>API Limits: You can successfully apply a new AWS KMS key for server-side encryption 25 times in a rolling 24-hour period.</p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are encrypted. After you enable encryption, you can verify that encryption is applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
+    ///
+    /// See `Kinesis::start_stream_encryption`.
+    pub fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
+        self.runtime
+            .block_on(self.client.start_stream_encryption(input))
+    }
+
+    /// <p>Disables server-side encryption for a specified stream. </p> <p>Stopping encryption is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Stopping encryption normally takes a few seconds to complete, but it can take minutes. You can continue to read and write data to your stream while its status is <code>UPDATING</code>. Once the status of the stream is <code>ACTIVE</code>, records written to the stream are no longer encrypted by Kinesis Data Streams. </p> <p>API Limits: You can successfully disable server-side encryption 25 times in a rolling 24-hour period. </p> <p>Note: It can take up to 5 seconds after the stream is in an <code>ACTIVE</code> status before all records written to the stream are no longer subject to encryption. After you disabled encryption, you can verify that encryption is not applied by inspecting the API response from <code>PutRecord</code> or <code>PutRecords</code>.</p>
+    ///
+    /// See `Kinesis::stop_stream_encryption`.
+    pub fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
+        self.runtime
+            .block_on(self.client.stop_stream_encryption(input))
+    }
+
+    /// <p>Call this operation from your consumer after you call <a>RegisterStreamConsumer</a> to register the consumer with Kinesis Data Streams. If the call succeeds, your consumer starts receiving events of type <a>SubscribeToShardEvent</a> for up to 5 minutes, after which time you need to call <code>SubscribeToShard</code> again to renew the subscription if you want to continue to receive records.</p> <p>You can make one call to <code>SubscribeToShard</code> per second per <code>ConsumerARN</code>. If your call succeeds, and then you call the operation again less than 5 seconds later, the second call generates a <a>ResourceInUseException</a>. If you call the operation a second time more than 5 seconds after the first call succeeds, the second call succeeds and the first connection gets shut down.</p>
+    ///
+    /// See `Kinesis::subscribe_to_shard`.
+    pub fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
+        self.runtime.block_on(self.client.subscribe_to_shard(input))
+    }
+
+    /// <p>Updates the shard count of the specified stream to the specified number of shards.</p> <p>Updating the shard count is an asynchronous operation. Upon receiving the request, Kinesis Data Streams returns immediately and sets the status of the stream to <code>UPDATING</code>. After the update is complete, Kinesis Data Streams sets the status of the stream back to <code>ACTIVE</code>. Depending on the size of the stream, the scaling action could take a few minutes to complete. You can continue to read and write data to your stream while its status is <code>UPDATING</code>.</p> <p>To update the shard count, Kinesis Data Streams performs splits or merges on individual shards. This can cause short-lived shards to be created, in addition to the final shards. We recommend that you double or halve the shard count, as this results in the fewest number of splits or merges.</p> <p>This operation has the following default limits. By default, you cannot do the following:</p> <ul> <li> <p>Scale more than twice per rolling 24-hour period per stream</p> </li> <li> <p>Scale up to more than double your current shard count for a stream</p> </li> <li> <p>Scale down below half your current shard count for a stream</p> </li> <li> <p>Scale up to more than 500 shards in a stream</p> </li> <li> <p>Scale a stream with more than 500 shards down unless the result is less than 500 shards</p> </li> <li> <p>Scale up to more than the shard limit for your account</p> </li> </ul> <p>For the default limits for an AWS account, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>. To request an increase in the call rate limit, the shard limit for this API, or your overall shard limit, use the <a href="https://console.aws.amazon.com/support/v1#/case/create?issueType=service-limit-increase&amp;limitType=service-code-kinesis">limits form</a>.</p>
+    ///
+    /// See `Kinesis::update_shard_count`.
+    pub fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
+        self.runtime.block_on(self.client.update_shard_count(input))
+    }
+}
@@ -1353,27 +1353,32 @@ impl AddApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -1429,28 +1434,40 @@ impl AddApplicationInputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(AddApplicationInputError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::CodeValidation(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationInputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddApplicationInputError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1495,29 +1512,34 @@ impl AddApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::UnsupportedOperation(
                             err.msg,
                         ),
@@ -1573,27 +1595,34 @@ impl AddApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1637,27 +1666,32 @@ impl AddApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -1713,24 +1747,37 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(CreateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::CodeValidation(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateApplicationError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1771,20 +1818,28 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1827,29 +1882,34 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::UnsupportedOperation(
                             err.msg,
                         ),
@@ -1907,33 +1967,38 @@ impl DeleteApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::InvalidArgument(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::UnsupportedOperation(
                             err.msg,
                         ),
@@ -1989,27 +2054,32 @@ impl DeleteApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationOutputError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationOutputError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2057,27 +2127,32 @@ impl DeleteApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2125,14 +2200,16 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2169,22 +2246,28 @@ impl DiscoverInputSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnableToDetectSchemaException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::UnableToDetectSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::UnableToDetectSchema(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2245,17 +2328,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2295,23 +2383,34 @@ impl StartApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartApplicationError::InvalidApplicationConfiguration(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StartApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2351,15 +2450,19 @@ impl StopApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, StopApplicationError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StopApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopApplicationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2399,19 +2502,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArgument(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2453,21 +2559,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArgument(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2511,26 +2621,40 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(UpdateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::CodeValidation(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateApplicationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2712,6 +2836,7 @@ impl KinesisAnalyticsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2727,6 +2852,7 @@ impl KinesisAnalyticsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
         KinesisAnalyticsClient { client, region }
     }
@@ -2743,6 +2869,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationCloudWatchLoggingOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationCloudWatchLoggingOption",
@@ -2768,6 +2897,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: AddApplicationInputRequest,
     ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationInput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationInput",
@@ -2792,6 +2924,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationInputProcessingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationInputProcessingConfiguration",
@@ -2817,6 +2952,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: AddApplicationOutputRequest,
     ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationOutput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationOutput",
@@ -2842,6 +2980,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationReferenceDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationReferenceDataSource",
@@ -2867,6 +3008,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.CreateApplication",
@@ -2888,6 +3032,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplication",
@@ -2912,6 +3059,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationCloudWatchLoggingOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationCloudWatchLoggingOption",
@@ -2940,6 +3090,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationInputProcessingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationInputProcessingConfiguration",
@@ -2965,6 +3118,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DeleteApplicationOutputRequest,
     ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationOutput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationOutput",
@@ -2990,6 +3146,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationReferenceDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationReferenceDataSource",
@@ -3015,6 +3174,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DescribeApplication",
@@ -3036,6 +3198,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DiscoverInputSchemaRequest,
     ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DiscoverInputSchema");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DiscoverInputSchema",
@@ -3057,6 +3222,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3075,6 +3243,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.ListTagsForResource",
@@ -3096,6 +3267,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: StartApplicationRequest,
     ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StartApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3114,6 +3288,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: StopApplicationRequest,
     ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StopApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3132,6 +3309,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3150,6 +3330,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3168,6 +3351,9 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.UpdateApplication",
@@ -3183,3 +3369,294 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
+
+/// A blocking client for the Kinesis Analytics API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisAnalyticsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisAnalyticsBlockingClient {
+    client: KinesisAnalyticsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisAnalyticsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisAnalyticsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisAnalyticsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisAnalyticsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisAnalyticsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient {
+            client: KinesisAnalyticsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a CloudWatch log stream to monitor application configuration errors. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
+    ///
+    /// See `KinesisAnalytics::add_application_cloud_watch_logging_option`.
+    pub fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Adds a streaming source to your Amazon Kinesis application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p> <p>You can add a streaming source either when you create an application or you can use this operation to add a streaming source after you create an application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_CreateApplication.html">CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationInput</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::add_application_input`.
+    pub fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        self.runtime
+            .block_on(self.client.add_application_input(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> to an application. An input processor preprocesses records on the input stream before the application&#39;s SQL code executes. Currently, the only input processor available is <a href="https://docs.aws.amazon.com/lambda/">AWS Lambda</a>.</p></p>
+    ///
+    /// See `KinesisAnalytics::add_application_input_processing_configuration`.
+    pub fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        AddApplicationInputProcessingConfigurationResponse,
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_input_processing_configuration(input),
+        )
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds an external destination to your Amazon Kinesis Analytics application.</p> <p>If you want Amazon Kinesis Analytics to deliver data from an in-application stream within your application to an external destination (such as an Amazon Kinesis stream, an Amazon Kinesis Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Understanding Application Output (Destination)</a>. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the current application version.</p> <p>For the limits on the number of application inputs and outputs you can configure, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::add_application_output`.
+    pub fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.add_application_output(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Adds a reference data source to an existing application.</p> <p>Amazon Kinesis Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in Amazon S3 object maps to columns in the resulting in-application table.</p> <p> For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. For the limits on data sources you can add to your application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:AddApplicationOutput</code> action. </p></p>
+    ///
+    /// See `KinesisAnalytics::add_application_reference_data_source`.
+    pub fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        AddApplicationReferenceDataSourceResponse,
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_application_reference_data_source(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p> Creates an Amazon Kinesis Analytics application. You can configure each application with one streaming source as input, application code to process the input, and up to three destinations where you want Amazon Kinesis Analytics to write the output data from your application. For an overview, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works.html">How it Works</a>. </p> <p>In the input configuration, you map the streaming source to an in-application stream, which you can think of as a constantly updating table. In the mapping, you must provide a schema for the in-application stream and map each data column in the in-application stream to a data element in the streaming source.</p> <p>Your application code is one or more SQL statements that read input data, transform it, and generate output. Your application code can create one or more SQL artifacts like SQL streams or pumps.</p> <p>In the output configuration, you can configure the application to write data from in-application streams created in your applications to up to three destinations.</p> <p> To read data from your source stream or write data to destination streams, Amazon Kinesis Analytics needs your permissions. You grant these permissions by creating IAM roles. This operation requires permissions to perform the <code>kinesisanalytics:CreateApplication</code> action. </p> <p> For introductory exercises to create an Amazon Kinesis Analytics application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/getting-started.html">Getting Started</a>. </p></p>
+    ///
+    /// See `KinesisAnalytics::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes the specified application. Amazon Kinesis Analytics halts application execution and deletes the application, including any application artifacts (such as in-application streams, reference table, and application code).</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplication</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes a CloudWatch log stream from an application. For more information about using CloudWatch log streams with Amazon Kinesis Analytics applications, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/cloudwatch-logs.html">Working with Amazon CloudWatch Logs</a>.</p></p>
+    ///
+    /// See `KinesisAnalytics::delete_application_cloud_watch_logging_option`.
+    pub fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes an <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_InputProcessingConfiguration.html">InputProcessingConfiguration</a> from an input.</p></p>
+    ///
+    /// See `KinesisAnalytics::delete_application_input_processing_configuration`.
+    pub fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_input_processing_configuration(input),
+        )
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes output destination configuration from your application configuration. Amazon Kinesis Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DeleteApplicationOutput</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::delete_application_output`.
+    pub fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.delete_application_output(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Deletes a reference data source configuration from the specified application configuration.</p> <p>If the application is running, Amazon Kinesis Analytics immediately removes the in-application table that you created using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_AddApplicationReferenceDataSource.html">AddApplicationReferenceDataSource</a> operation. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics.DeleteApplicationReferenceDataSource</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::delete_application_reference_data_source`.
+    pub fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        DeleteApplicationReferenceDataSourceResponse,
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_application_reference_data_source(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Returns information about a specific Amazon Kinesis Analytics application.</p> <p>If you want to retrieve a list of all applications in your account, use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_ListApplications.html">ListApplications</a> operation.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:DescribeApplication</code> action. You can use <code>DescribeApplication</code> to get the current application versionId, which you need to call other operations such as <code>Update</code>. </p></p>
+    ///
+    /// See `KinesisAnalytics::describe_application`.
+    pub fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_application(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Infers a schema by evaluating sample records on the specified streaming source (Amazon Kinesis stream or Amazon Kinesis Firehose delivery stream) or S3 object. In the response, the operation returns the inferred schema and also the sample records that the operation used to infer the schema.</p> <p> You can use the inferred schema when configuring a streaming source for your application. For conceptual information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. Note that when you create an application using the Amazon Kinesis Analytics console, the console uses this operation to infer a schema and show it in the console user interface. </p> <p> This operation requires permissions to perform the <code>kinesisanalytics:DiscoverInputSchema</code> action. </p></p>
+    ///
+    /// See `KinesisAnalytics::discover_input_schema`.
+    pub fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+        self.runtime
+            .block_on(self.client.discover_input_schema(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Returns a list of Amazon Kinesis Analytics applications in your account. For each application, the response includes the application name, Amazon Resource Name (ARN), and status. If the response returns the <code>HasMoreApplications</code> value as true, you can send another request by adding the <code>ExclusiveStartApplicationName</code> in the request body, and set the value of this to the last application name from the previous response. </p> <p>If you want detailed information about a specific application, use <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a>.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:ListApplications</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::list_applications`.
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// <p>Retrieves the list of key-value tags assigned to the application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
+    ///
+    /// See `KinesisAnalytics::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Starts the specified Amazon Kinesis Analytics application. After creating an application, you must exclusively call this operation to start your application.</p> <p>After the application starts, it begins consuming the input data, processes it, and writes the output to the configured destination.</p> <p> The application status must be <code>READY</code> for you to start an application. You can get the application status in the console or using the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation.</p> <p>After you start the application, you can stop the application from processing the input by calling the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_StopApplication.html">StopApplication</a> operation.</p> <p>This operation requires permissions to perform the <code>kinesisanalytics:StartApplication</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::start_application`.
+    pub fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+        self.runtime.block_on(self.client.start_application(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Stops the application from processing input data. You can stop an application only if it is in the running state. You can use the <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/API_DescribeApplication.html">DescribeApplication</a> operation to find the application state. After the application is stopped, Amazon Kinesis Analytics stops reading data from the input, the application stops processing data, and there is no output written to the destination. </p> <p>This operation requires permissions to perform the <code>kinesisanalytics:StopApplication</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::stop_application`.
+    pub fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+        self.runtime.block_on(self.client.stop_application(input))
+    }
+
+    /// <p>Adds one or more key-value tags to a Kinesis Analytics application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
+    ///
+    /// See `KinesisAnalytics::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a Kinesis Analytics application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-tagging.html">Using Tagging</a>.</p>
+    ///
+    /// See `KinesisAnalytics::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Updates an existing Amazon Kinesis Analytics application. Using this API, you can update application code, input configuration, and output configuration. </p> <p>Note that Amazon Kinesis Analytics updates the <code>CurrentApplicationVersionId</code> each time you update your application. </p> <p>This operation requires permission for the <code>kinesisanalytics:UpdateApplication</code> action.</p></p>
+    ///
+    /// See `KinesisAnalytics::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
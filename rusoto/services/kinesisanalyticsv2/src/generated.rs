@@ -2256,34 +2256,40 @@ impl AddApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2342,26 +2348,40 @@ impl AddApplicationInputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(AddApplicationInputError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::CodeValidation(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationInputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationInputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2406,29 +2426,34 @@ impl AddApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::InvalidArgument(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationInputProcessingConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2482,25 +2507,34 @@ impl AddApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddApplicationOutputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2544,27 +2578,32 @@ impl AddApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::InvalidArgument(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2618,22 +2657,26 @@ impl AddApplicationVpcConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationVpcConfigurationError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationVpcConfigurationError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationVpcConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddApplicationVpcConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2686,27 +2729,43 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(CreateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::CodeValidation(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateApplicationError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2752,32 +2811,38 @@ impl CreateApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationSnapshotError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationSnapshotError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationSnapshotError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationSnapshotError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationSnapshotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApplicationSnapshotError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2826,26 +2891,40 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationError::InvalidApplicationConfiguration(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2894,34 +2973,40 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(
                             err.msg,
                         ),
                     )
                 }
-                "InvalidApplicationConfigurationException" => return RusotoError::Service(
+                "InvalidApplicationConfigurationException" => return RusotoError::service(
+                    &res,
                     DeleteApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(
                         err.msg,
                     ),
                 ),
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2980,31 +3065,36 @@ impl DeleteApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::InvalidArgument(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(
                             err.msg,
                         ),
@@ -3060,29 +3150,34 @@ impl DeleteApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationOutputError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationOutputError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3128,27 +3223,32 @@ impl DeleteApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::InvalidArgument(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3202,27 +3302,32 @@ impl DeleteApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationSnapshotError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationSnapshotError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationSnapshotError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationSnapshotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationSnapshotError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3268,22 +3373,26 @@ impl DeleteApplicationVpcConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationVpcConfigurationError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationVpcConfigurationError::InvalidArgument(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationVpcConfigurationError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteApplicationVpcConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3330,15 +3439,22 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3376,17 +3492,20 @@ impl DescribeApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeApplicationSnapshotError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationSnapshotError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeApplicationSnapshotError::ResourceNotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeApplicationSnapshotError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3430,25 +3549,34 @@ impl DiscoverInputSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnableToDetectSchemaException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::UnableToDetectSchema(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInputSchemaError::UnableToDetectSchema(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3486,12 +3614,14 @@ impl ListApplicationSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListApplicationSnapshotsError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationSnapshotsError::InvalidArgument(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationSnapshotsError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3526,7 +3656,10 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3560,17 +3693,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3610,21 +3748,34 @@ impl StartApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartApplicationError::InvalidApplicationConfiguration(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3668,21 +3819,31 @@ impl StopApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopApplicationError::InvalidApplicationConfiguration(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StopApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopApplicationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, StopApplicationError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3726,19 +3887,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArgument(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3780,21 +3944,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArgument(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3840,29 +4008,46 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(UpdateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::CodeValidation(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApplicationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateApplicationError::InvalidApplicationConfiguration(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4089,6 +4274,7 @@ impl KinesisAnalyticsV2Client {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4104,6 +4290,7 @@ impl KinesisAnalyticsV2Client {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsV2Client {
         KinesisAnalyticsV2Client { client, region }
     }
@@ -4120,6 +4307,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationCloudWatchLoggingOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationCloudWatchLoggingOption",
@@ -4145,6 +4335,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: AddApplicationInputRequest,
     ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationInput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationInput",
@@ -4169,6 +4362,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationInputProcessingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationInputProcessingConfiguration",
@@ -4194,6 +4390,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: AddApplicationOutputRequest,
     ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationOutput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationOutput",
@@ -4219,6 +4418,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationReferenceDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationReferenceDataSource",
@@ -4247,6 +4449,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationVpcConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddApplicationVpcConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationVpcConfiguration",
@@ -4269,6 +4474,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.CreateApplication",
@@ -4291,6 +4499,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApplicationSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.CreateApplicationSnapshot",
@@ -4313,6 +4524,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplication",
@@ -4337,6 +4551,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationCloudWatchLoggingOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationCloudWatchLoggingOption",
@@ -4365,6 +4582,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationInputProcessingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationInputProcessingConfiguration",
@@ -4390,6 +4610,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DeleteApplicationOutputRequest,
     ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationOutput");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationOutput",
@@ -4415,6 +4638,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationReferenceDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationReferenceDataSource",
@@ -4441,6 +4667,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationSnapshot",
@@ -4466,6 +4695,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationVpcConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApplicationVpcConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationVpcConfiguration",
@@ -4491,6 +4723,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DescribeApplication",
@@ -4513,6 +4748,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApplicationSnapshot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DescribeApplicationSnapshot",
@@ -4535,6 +4773,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DiscoverInputSchemaRequest,
     ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DiscoverInputSchema");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DiscoverInputSchema",
@@ -4556,6 +4797,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListApplicationSnapshotsRequest,
     ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplicationSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.ListApplicationSnapshots",
@@ -4578,6 +4822,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4596,6 +4843,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.ListTagsForResource",
@@ -4617,6 +4867,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: StartApplicationRequest,
     ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StartApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4635,6 +4888,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: StopApplicationRequest,
     ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StopApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4653,6 +4909,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4671,6 +4930,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4689,6 +4951,9 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.UpdateApplication",
@@ -4704,3 +4969,369 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
+
+/// A blocking client for the Kinesis Analytics V2 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisAnalyticsV2Client` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisAnalyticsV2BlockingClient {
+    client: KinesisAnalyticsV2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisAnalyticsV2BlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisAnalyticsV2BlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisAnalyticsV2BlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisAnalyticsV2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisAnalyticsV2BlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient {
+            client: KinesisAnalyticsV2Client::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds an Amazon CloudWatch log stream to monitor application configuration errors.</p>
+    ///
+    /// See `KinesisAnalyticsV2::add_application_cloud_watch_logging_option`.
+    pub fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// <p> Adds a streaming source to your SQL-based Amazon Kinesis Data Analytics application. </p> <p>You can add a streaming source when you create an application, or you can use this operation to add a streaming source after you create an application. For more information, see <a>CreateApplication</a>.</p> <p>Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a>DescribeApplication</a> operation to find the current application version. </p>
+    ///
+    /// See `KinesisAnalyticsV2::add_application_input`.
+    pub fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        self.runtime
+            .block_on(self.client.add_application_input(input))
+    }
+
+    /// <p>Adds an <a>InputProcessingConfiguration</a> to an SQL-based Kinesis Data Analytics application. An input processor pre-processes records on the input stream before the application's SQL code executes. Currently, the only input processor available is <a href="https://aws.amazon.com/documentation/lambda/">AWS Lambda</a>.</p>
+    ///
+    /// See `KinesisAnalyticsV2::add_application_input_processing_configuration`.
+    pub fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        AddApplicationInputProcessingConfigurationResponse,
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_input_processing_configuration(input),
+        )
+    }
+
+    /// <p>Adds an external destination to your SQL-based Amazon Kinesis Data Analytics application.</p> <p>If you want Kinesis Data Analytics to deliver data from an in-application stream within your application to an external destination (such as an Kinesis data stream, a Kinesis Data Firehose delivery stream, or an AWS Lambda function), you add the relevant configuration to your application using this operation. You can configure one or more outputs for your application. Each output configuration maps an in-application stream and an external destination.</p> <p> You can use one of the output configurations to deliver data from your in-application error stream to an external destination so that you can analyze the errors. </p> <p> Any configuration update, including adding a streaming source using this operation, results in a new version of the application. You can use the <a>DescribeApplication</a> operation to find the current application version.</p>
+    ///
+    /// See `KinesisAnalyticsV2::add_application_output`.
+    pub fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.add_application_output(input))
+    }
+
+    /// <p>Adds a reference data source to an existing SQL-based Amazon Kinesis Data Analytics application.</p> <p>Kinesis Data Analytics reads reference data (that is, an Amazon S3 object) and creates an in-application table within your application. In the request, you provide the source (S3 bucket name and object key name), name of the in-application table to create, and the necessary mapping information that describes how data in an Amazon S3 object maps to columns in the resulting in-application table.</p>
+    ///
+    /// See `KinesisAnalyticsV2::add_application_reference_data_source`.
+    pub fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        AddApplicationReferenceDataSourceResponse,
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_application_reference_data_source(input))
+    }
+
+    /// <p><p>Adds a Virtual Private Cloud (VPC) configuration to the application. Applications can use VPCs to store and access resources securely.</p> <p>Note the following about VPC configurations for Kinesis Data Analytics applications:</p> <ul> <li> <p>VPC configurations are not supported for SQL applications.</p> </li> <li> <p>When a VPC is added to a Kinesis Data Analytics application, the application can no longer be accessed from the Internet directly. To enable Internet access to the application, add an Internet gateway to your VPC.</p> </li> </ul></p>
+    ///
+    /// See `KinesisAnalyticsV2::add_application_vpc_configuration`.
+    pub fn add_application_vpc_configuration(
+        &self,
+        input: AddApplicationVpcConfigurationRequest,
+    ) -> Result<
+        AddApplicationVpcConfigurationResponse,
+        RusotoError<AddApplicationVpcConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_application_vpc_configuration(input))
+    }
+
+    /// <p>Creates an Amazon Kinesis Data Analytics application. For information about creating a Kinesis Data Analytics application, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/getting-started.html">Creating an Application</a>. </p>
+    ///
+    /// See `KinesisAnalyticsV2::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p>Creates a snapshot of the application's state data.</p>
+    ///
+    /// See `KinesisAnalyticsV2::create_application_snapshot`.
+    pub fn create_application_snapshot(
+        &self,
+        input: CreateApplicationSnapshotRequest,
+    ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.create_application_snapshot(input))
+    }
+
+    /// <p>Deletes the specified application. Kinesis Data Analytics halts application execution and deletes the application.</p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p>Deletes an Amazon CloudWatch log stream from an Amazon Kinesis Data Analytics application. </p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application_cloud_watch_logging_option`.
+    pub fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// <p>Deletes an <a>InputProcessingConfiguration</a> from an input.</p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application_input_processing_configuration`.
+    pub fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_input_processing_configuration(input),
+        )
+    }
+
+    /// <p>Deletes the output destination configuration from your SQL-based Amazon Kinesis Data Analytics application's configuration. Kinesis Data Analytics will no longer write data from the corresponding in-application stream to the external output destination.</p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application_output`.
+    pub fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.delete_application_output(input))
+    }
+
+    /// <p>Deletes a reference data source configuration from the specified SQL-based Amazon Kinesis Data Analytics application's configuration.</p> <p>If the application is running, Kinesis Data Analytics immediately removes the in-application table that you created using the <a>AddApplicationReferenceDataSource</a> operation. </p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application_reference_data_source`.
+    pub fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        DeleteApplicationReferenceDataSourceResponse,
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_application_reference_data_source(input))
+    }
+
+    /// <p>Deletes a snapshot of application state.</p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application_snapshot`.
+    pub fn delete_application_snapshot(
+        &self,
+        input: DeleteApplicationSnapshotRequest,
+    ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_application_snapshot(input))
+    }
+
+    /// <p>Removes a VPC configuration from a Kinesis Data Analytics application.</p>
+    ///
+    /// See `KinesisAnalyticsV2::delete_application_vpc_configuration`.
+    pub fn delete_application_vpc_configuration(
+        &self,
+        input: DeleteApplicationVpcConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationVpcConfigurationResponse,
+        RusotoError<DeleteApplicationVpcConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_application_vpc_configuration(input))
+    }
+
+    /// <p>Returns information about a specific Amazon Kinesis Data Analytics application.</p> <p>If you want to retrieve a list of all applications in your account, use the <a>ListApplications</a> operation.</p>
+    ///
+    /// See `KinesisAnalyticsV2::describe_application`.
+    pub fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_application(input))
+    }
+
+    /// <p>Returns information about a snapshot of application state data.</p>
+    ///
+    /// See `KinesisAnalyticsV2::describe_application_snapshot`.
+    pub fn describe_application_snapshot(
+        &self,
+        input: DescribeApplicationSnapshotRequest,
+    ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_application_snapshot(input))
+    }
+
+    /// <p>Infers a schema for an SQL-based Amazon Kinesis Data Analytics application by evaluating sample records on the specified streaming source (Kinesis data stream or Kinesis Data Firehose delivery stream) or Amazon S3 object. In the response, the operation returns the inferred schema and also the sample records that the operation used to infer the schema.</p> <p> You can use the inferred schema when configuring a streaming source for your application. When you create an application using the Kinesis Data Analytics console, the console uses this operation to infer a schema and show it in the console user interface. </p>
+    ///
+    /// See `KinesisAnalyticsV2::discover_input_schema`.
+    pub fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+        self.runtime
+            .block_on(self.client.discover_input_schema(input))
+    }
+
+    /// <p>Lists information about the current application snapshots.</p>
+    ///
+    /// See `KinesisAnalyticsV2::list_application_snapshots`.
+    pub fn list_application_snapshots(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.list_application_snapshots(input))
+    }
+
+    /// <p>Returns a list of Amazon Kinesis Data Analytics applications in your account. For each application, the response includes the application name, Amazon Resource Name (ARN), and status. </p> <p>If you want detailed information about a specific application, use <a>DescribeApplication</a>.</p>
+    ///
+    /// See `KinesisAnalyticsV2::list_applications`.
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// <p>Retrieves the list of key-value tags assigned to the application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
+    ///
+    /// See `KinesisAnalyticsV2::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Starts the specified Amazon Kinesis Data Analytics application. After creating an application, you must exclusively call this operation to start your application.</p>
+    ///
+    /// See `KinesisAnalyticsV2::start_application`.
+    pub fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+        self.runtime.block_on(self.client.start_application(input))
+    }
+
+    /// <p>Stops the application from processing data. You can stop an application only if it is in the running state. You can use the <a>DescribeApplication</a> operation to find the application state. </p>
+    ///
+    /// See `KinesisAnalyticsV2::stop_application`.
+    pub fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+        self.runtime.block_on(self.client.stop_application(input))
+    }
+
+    /// <p>Adds one or more key-value tags to a Kinesis Analytics application. Note that the maximum number of application tags includes system tags. The maximum number of user-defined application tags is 50. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
+    ///
+    /// See `KinesisAnalyticsV2::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a Kinesis Analytics application. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/java/how-tagging.html">Using Tagging</a>.</p>
+    ///
+    /// See `KinesisAnalyticsV2::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an existing Amazon Kinesis Data Analytics application. Using this operation, you can update application code, input configuration, and output configuration. </p> <p>Kinesis Data Analytics updates the <code>ApplicationVersionId</code> each time you update your application. </p>
+    ///
+    /// See `KinesisAnalyticsV2::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
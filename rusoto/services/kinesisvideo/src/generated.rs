@@ -646,30 +646,38 @@ impl CreateSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSignalingChannelError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountChannelLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSignalingChannelError::AccountChannelLimitExceeded(err.msg),
                     )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSignalingChannelError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSignalingChannelError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSignalingChannelError::ResourceInUse(err.msg),
+                    )
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSignalingChannelError::TagsPerResourceExceededLimit(err.msg),
                     )
                 }
@@ -722,31 +730,37 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountStreamLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::AccountStreamLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamError::AccountStreamLimitExceeded(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "DeviceStreamLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::DeviceStreamLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamError::DeviceStreamLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::InvalidArgument(err.msg))
                 }
                 "InvalidDeviceException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidDevice(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::InvalidDevice(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateStreamError::ResourceInUse(err.msg))
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(CreateStreamError::TagsPerResourceExceededLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamError::TagsPerResourceExceededLimit(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -792,32 +806,40 @@ impl DeleteSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSignalingChannelError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSignalingChannelError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSignalingChannelError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSignalingChannelError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSignalingChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::VersionMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSignalingChannelError::VersionMismatch(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -862,22 +884,25 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DeleteStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::InvalidArgument(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::NotAuthorized(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::ResourceNotFound(err.msg))
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(DeleteStreamError::VersionMismatch(err.msg))
+                    return RusotoError::service(&res, DeleteStreamError::VersionMismatch(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -918,24 +943,28 @@ impl DescribeSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSignalingChannelError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSignalingChannelError::ClientLimitExceeded(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSignalingChannelError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSignalingChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -974,16 +1003,25 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, DescribeStreamError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1022,16 +1060,25 @@ impl GetDataEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetDataEndpointError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataEndpointError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetDataEndpointError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataEndpointError::InvalidArgument(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDataEndpointError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, GetDataEndpointError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1074,27 +1121,32 @@ impl GetSignalingChannelEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSignalingChannelEndpointError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSignalingChannelEndpointError::ClientLimitExceeded(err.msg),
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSignalingChannelEndpointError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSignalingChannelEndpointError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSignalingChannelEndpointError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1136,17 +1188,22 @@ impl ListSignalingChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSignalingChannelsError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSignalingChannelsError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSignalingChannelsError::InvalidArgument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1180,10 +1237,13 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListStreamsError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamsError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListStreamsError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, ListStreamsError::InvalidArgument(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1220,20 +1280,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1274,23 +1342,34 @@ impl ListTagsForStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::InvalidArgument(err.msg),
+                    )
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidResourceFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::InvalidResourceFormat(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1332,21 +1411,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArgument(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(TagResourceError::TagsPerResourceExceededLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::TagsPerResourceExceededLimit(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1390,24 +1473,28 @@ impl TagStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(TagStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagStreamError::ClientLimitExceeded(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, TagStreamError::InvalidArgument(err.msg))
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(TagStreamError::InvalidResourceFormat(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagStreamError::InvalidResourceFormat(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, TagStreamError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagStreamError::ResourceNotFound(err.msg))
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(TagStreamError::TagsPerResourceExceededLimit(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagStreamError::TagsPerResourceExceededLimit(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1448,16 +1535,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArgument(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1498,19 +1591,25 @@ impl UntagStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UntagStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagStreamError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UntagStreamError::InvalidArgument(err.msg))
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(UntagStreamError::InvalidResourceFormat(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagStreamError::InvalidResourceFormat(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UntagStreamError::NotAuthorized(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UntagStreamError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1554,26 +1653,40 @@ impl UpdateDataRetentionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataRetentionError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataRetentionError::InvalidArgument(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataRetentionError::NotAuthorized(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataRetentionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataRetentionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::VersionMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataRetentionError::VersionMismatch(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1618,32 +1731,40 @@ impl UpdateSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSignalingChannelError::AccessDenied(err.msg),
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ClientLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSignalingChannelError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSignalingChannelError::InvalidArgument(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSignalingChannelError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSignalingChannelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::VersionMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSignalingChannelError::VersionMismatch(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1688,22 +1809,25 @@ impl UpdateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStreamError::ClientLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::InvalidArgument(err.msg))
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::NotAuthorized(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::ResourceNotFound(err.msg))
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateStreamError::VersionMismatch(err.msg))
+                    return RusotoError::service(&res, UpdateStreamError::VersionMismatch(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1861,6 +1985,7 @@ impl KinesisVideoClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1876,6 +2001,7 @@ impl KinesisVideoClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoClient {
         KinesisVideoClient { client, region }
     }
@@ -1892,6 +2018,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/createSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("CreateSignalingChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1923,6 +2052,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/createStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("CreateStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1954,6 +2086,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/deleteSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("DeleteSignalingChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1985,6 +2120,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/deleteStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("DeleteStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2016,6 +2154,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/describeSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("DescribeSignalingChannel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2047,6 +2188,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/describeStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("DescribeStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2078,6 +2222,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/getDataEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("GetDataEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2110,6 +2257,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/getSignalingChannelEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("GetSignalingChannelEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2141,6 +2291,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listSignalingChannels";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("ListSignalingChannels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2172,6 +2325,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listStreams";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("ListStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2203,6 +2359,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2234,6 +2393,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listTagsForStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2265,6 +2427,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2296,6 +2461,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/tagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("TagStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2327,6 +2495,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2358,6 +2529,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/untagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("UntagStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2389,6 +2563,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateDataRetention";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataRetention");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2420,6 +2597,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("UpdateSignalingChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2451,6 +2631,9 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation_name("UpdateStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2473,3 +2656,253 @@ impl KinesisVideo for KinesisVideoClient {
         }
     }
 }
+
+/// A blocking client for the Kinesis Video API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KinesisVideoClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoBlockingClient {
+    client: KinesisVideoClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KinesisVideoBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KinesisVideoBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient {
+            client: KinesisVideoClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a signaling channel. </p> <p> <code>CreateSignalingChannel</code> is an asynchronous operation.</p>
+    ///
+    /// See `KinesisVideo::create_signaling_channel`.
+    pub fn create_signaling_channel(
+        &self,
+        input: CreateSignalingChannelInput,
+    ) -> Result<CreateSignalingChannelOutput, RusotoError<CreateSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.create_signaling_channel(input))
+    }
+
+    /// <p>Creates a new Kinesis video stream. </p> <p>When you create a new stream, Kinesis Video Streams assigns it a version number. When you change the stream's metadata, Kinesis Video Streams updates the version. </p> <p> <code>CreateStream</code> is an asynchronous operation.</p> <p>For information about how the service works, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/how-it-works.html">How it Works</a>. </p> <p>You must have permissions for the <code>KinesisVideo:CreateStream</code> action.</p>
+    ///
+    /// See `KinesisVideo::create_stream`.
+    pub fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<CreateStreamOutput, RusotoError<CreateStreamError>> {
+        self.runtime.block_on(self.client.create_stream(input))
+    }
+
+    /// <p>Deletes a specified signaling channel. <code>DeleteSignalingChannel</code> is an asynchronous operation. If you don't specify the channel's current version, the most recent version is deleted.</p>
+    ///
+    /// See `KinesisVideo::delete_signaling_channel`.
+    pub fn delete_signaling_channel(
+        &self,
+        input: DeleteSignalingChannelInput,
+    ) -> Result<DeleteSignalingChannelOutput, RusotoError<DeleteSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.delete_signaling_channel(input))
+    }
+
+    /// <p>Deletes a Kinesis video stream and the data contained in the stream. </p> <p>This method marks the stream for deletion, and makes the data in the stream inaccessible immediately.</p> <p> </p> <p> To ensure that you have the latest version of the stream before deleting it, you can specify the stream version. Kinesis Video Streams assigns a version to each stream. When you update a stream, Kinesis Video Streams assigns a new version number. To get the latest stream version, use the <code>DescribeStream</code> API. </p> <p>This operation requires permission for the <code>KinesisVideo:DeleteStream</code> action.</p>
+    ///
+    /// See `KinesisVideo::delete_stream`.
+    pub fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<DeleteStreamOutput, RusotoError<DeleteStreamError>> {
+        self.runtime.block_on(self.client.delete_stream(input))
+    }
+
+    /// <p>Returns the most current information about the signaling channel. You must specify either the name or the Amazon Resource Name (ARN) of the channel that you want to describe.</p>
+    ///
+    /// See `KinesisVideo::describe_signaling_channel`.
+    pub fn describe_signaling_channel(
+        &self,
+        input: DescribeSignalingChannelInput,
+    ) -> Result<DescribeSignalingChannelOutput, RusotoError<DescribeSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.describe_signaling_channel(input))
+    }
+
+    /// <p>Returns the most current information about the specified stream. You must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p>
+    ///
+    /// See `KinesisVideo::describe_stream`.
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// <p>Gets an endpoint for a specified stream for either reading or writing. Use this endpoint in your application to read from the specified stream (using the <code>GetMedia</code> or <code>GetMediaForFragmentList</code> operations) or write to it (using the <code>PutMedia</code> operation). </p> <note> <p>The returned endpoint does not have the API name appended. The client needs to add the API name to the returned endpoint.</p> </note> <p>In the request, specify the stream either by <code>StreamName</code> or <code>StreamARN</code>.</p>
+    ///
+    /// See `KinesisVideo::get_data_endpoint`.
+    pub fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> Result<GetDataEndpointOutput, RusotoError<GetDataEndpointError>> {
+        self.runtime.block_on(self.client.get_data_endpoint(input))
+    }
+
+    /// <p>Provides an endpoint for the specified signaling channel to send and receive messages. This API uses the <code>SingleMasterChannelEndpointConfiguration</code> input parameter, which consists of the <code>Protocols</code> and <code>Role</code> properties.</p> <p> <code>Protocols</code> is used to determine the communication mechanism. For example, if you specify <code>WSS</code> as the protocol, this API produces a secure websocket endpoint. If you specify <code>HTTPS</code> as the protocol, this API generates an HTTPS endpoint. </p> <p> <code>Role</code> determines the messaging permissions. A <code>MASTER</code> role results in this API generating an endpoint that a client can use to communicate with any of the viewers on the channel. A <code>VIEWER</code> role results in this API generating an endpoint that a client can use to communicate only with a <code>MASTER</code>. </p>
+    ///
+    /// See `KinesisVideo::get_signaling_channel_endpoint`.
+    pub fn get_signaling_channel_endpoint(
+        &self,
+        input: GetSignalingChannelEndpointInput,
+    ) -> Result<GetSignalingChannelEndpointOutput, RusotoError<GetSignalingChannelEndpointError>>
+    {
+        self.runtime
+            .block_on(self.client.get_signaling_channel_endpoint(input))
+    }
+
+    /// <p>Returns an array of <code>ChannelInfo</code> objects. Each object describes a signaling channel. To retrieve only those channels that satisfy a specific condition, you can specify a <code>ChannelNameCondition</code>.</p>
+    ///
+    /// See `KinesisVideo::list_signaling_channels`.
+    pub fn list_signaling_channels(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> Result<ListSignalingChannelsOutput, RusotoError<ListSignalingChannelsError>> {
+        self.runtime
+            .block_on(self.client.list_signaling_channels(input))
+    }
+
+    /// <p>Returns an array of <code>StreamInfo</code> objects. Each object describes a stream. To retrieve only streams that satisfy a specific condition, you can specify a <code>StreamNameCondition</code>. </p>
+    ///
+    /// See `KinesisVideo::list_streams`.
+    pub fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+
+    /// <p>Returns a list of tags associated with the specified signaling channel.</p>
+    ///
+    /// See `KinesisVideo::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a list of tags associated with the specified stream.</p> <p>In the request, you must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p>
+    ///
+    /// See `KinesisVideo::list_tags_for_stream`.
+    pub fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_stream(input))
+    }
+
+    /// <p>Adds one or more tags to a signaling channel. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    ///
+    /// See `KinesisVideo::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Adds one or more tags to a stream. A <i>tag</i> is a key-value pair (the value is optional) that you can define and assign to AWS resources. If you specify a tag that already exists, the tag value is replaced with the value that you specify in the request. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p> <p>You must provide either the <code>StreamName</code> or the <code>StreamARN</code>.</p> <p>This operation requires permission for the <code>KinesisVideo:TagStream</code> action.</p> <p>Kinesis video streams support up to 50 tags.</p>
+    ///
+    /// See `KinesisVideo::tag_stream`.
+    pub fn tag_stream(
+        &self,
+        input: TagStreamInput,
+    ) -> Result<TagStreamOutput, RusotoError<TagStreamError>> {
+        self.runtime.block_on(self.client.tag_stream(input))
+    }
+
+    /// <p>Removes one or more tags from a signaling channel. In the request, specify only a tag key or keys; don't specify the value. If you specify a tag key that does not exist, it's ignored.</p>
+    ///
+    /// See `KinesisVideo::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a stream. In the request, specify only a tag key or keys; don't specify the value. If you specify a tag key that does not exist, it's ignored.</p> <p>In the request, you must provide the <code>StreamName</code> or <code>StreamARN</code>.</p>
+    ///
+    /// See `KinesisVideo::untag_stream`.
+    pub fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> Result<UntagStreamOutput, RusotoError<UntagStreamError>> {
+        self.runtime.block_on(self.client.untag_stream(input))
+    }
+
+    /// <p><p> Increases or decreases the stream&#39;s data retention period by the value that you specify. To indicate whether you want to increase or decrease the data retention period, specify the <code>Operation</code> parameter in the request body. In the request, you must specify either the <code>StreamName</code> or the <code>StreamARN</code>. </p> <note> <p>The retention period that you specify replaces the current value.</p> </note> <p>This operation requires permission for the <code>KinesisVideo:UpdateDataRetention</code> action.</p> <p>Changing the data retention period affects the data in the stream as follows:</p> <ul> <li> <p>If the data retention period is increased, existing data is retained for the new retention period. For example, if the data retention period is increased from one hour to seven hours, all existing data is retained for seven hours.</p> </li> <li> <p>If the data retention period is decreased, existing data is retained for the new retention period. For example, if the data retention period is decreased from seven hours to one hour, all existing data is retained for one hour, and any data older than one hour is deleted immediately.</p> </li> </ul></p>
+    ///
+    /// See `KinesisVideo::update_data_retention`.
+    pub fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> Result<UpdateDataRetentionOutput, RusotoError<UpdateDataRetentionError>> {
+        self.runtime
+            .block_on(self.client.update_data_retention(input))
+    }
+
+    /// <p>Updates the existing signaling channel. This is an asynchronous operation and takes time to complete. </p> <p>If the <code>MessageTtlSeconds</code> value is updated (either increased or reduced), it only applies to new messages sent via this channel after it's been updated. Existing messages are still expired as per the previous <code>MessageTtlSeconds</code> value.</p>
+    ///
+    /// See `KinesisVideo::update_signaling_channel`.
+    pub fn update_signaling_channel(
+        &self,
+        input: UpdateSignalingChannelInput,
+    ) -> Result<UpdateSignalingChannelOutput, RusotoError<UpdateSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.update_signaling_channel(input))
+    }
+
+    /// <p>Updates stream metadata, such as the device name and media type.</p> <p>You must provide the stream name or the Amazon Resource Name (ARN) of the stream.</p> <p>To make sure that you have the latest version of the stream before updating it, you can specify the stream version. Kinesis Video Streams assigns a version to each stream. When you update a stream, Kinesis Video Streams assigns a new version number. To get the latest stream version, use the <code>DescribeStream</code> API. </p> <p> <code>UpdateStream</code> is an asynchronous operation, and takes time to complete.</p>
+    ///
+    /// See `KinesisVideo::update_stream`.
+    pub fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> Result<UpdateStreamOutput, RusotoError<UpdateStreamError>> {
+        self.runtime.block_on(self.client.update_stream(input))
+    }
+}
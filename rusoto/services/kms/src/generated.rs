@@ -1548,19 +1548,25 @@ impl CancelKeyDeletionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelKeyDeletionError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CancelKeyDeletionError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, CancelKeyDeletionError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelKeyDeletionError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CancelKeyDeletionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1602,27 +1608,34 @@ impl ConnectCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConnectCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConnectCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConnectCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ConnectCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ConnectCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ConnectCustomKeyStoreError::KMSInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1674,25 +1687,25 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateAliasError::AlreadyExists(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::AlreadyExists(err.msg))
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::DependencyTimeout(err.msg))
                 }
                 "InvalidAliasNameException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidAliasName(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::InvalidAliasName(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateAliasError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CreateAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::KMSInvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1740,37 +1753,46 @@ impl CreateCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInUseException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::CloudHsmClusterInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomKeyStoreError::CloudHsmClusterInUse(err.msg),
+                    )
                 }
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
                     )
                 }
                 "CloudHsmClusterNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomKeyStoreError::CloudHsmClusterNotFound(err.msg),
                     )
                 }
                 "CustomKeyStoreNameInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomKeyStoreError::CustomKeyStoreNameInUse(err.msg),
                     )
                 }
                 "IncorrectTrustAnchorException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::IncorrectTrustAnchor(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomKeyStoreError::IncorrectTrustAnchor(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomKeyStoreError::KMSInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1824,28 +1846,28 @@ impl CreateGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::DependencyTimeout(err.msg))
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(CreateGrantError::Disabled(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::Disabled(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::InvalidArn(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::InvalidGrantToken(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateGrantError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CreateGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::KMSInvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGrantError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGrantError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateGrantError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1900,36 +1922,47 @@ impl CreateKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateKeyError::CloudHsmClusterInvalidConfiguration(err.msg),
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(CreateKeyError::CustomKeyStoreInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeyError::CustomKeyStoreInvalidState(err.msg),
+                    )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(CreateKeyError::CustomKeyStoreNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeyError::CustomKeyStoreNotFound(err.msg),
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, CreateKeyError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateKeyError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, CreateKeyError::KMSInternal(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateKeyError::LimitExceeded(err.msg))
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreateKeyError::MalformedPolicyDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeyError::MalformedPolicyDocument(err.msg),
+                    )
                 }
-                "TagException" => return RusotoError::Service(CreateKeyError::Tag(err.msg)),
+                "TagException" => return RusotoError::service(&res, CreateKeyError::Tag(err.msg)),
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateKeyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeyError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1988,34 +2021,34 @@ impl DecryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DecryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, DecryptError::DependencyTimeout(err.msg))
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(DecryptError::Disabled(err.msg))
+                    return RusotoError::service(&res, DecryptError::Disabled(err.msg))
                 }
                 "IncorrectKeyException" => {
-                    return RusotoError::Service(DecryptError::IncorrectKey(err.msg))
+                    return RusotoError::service(&res, DecryptError::IncorrectKey(err.msg))
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(DecryptError::InvalidCiphertext(err.msg))
+                    return RusotoError::service(&res, DecryptError::InvalidCiphertext(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(DecryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, DecryptError::InvalidGrantToken(err.msg))
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(DecryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(&res, DecryptError::InvalidKeyUsage(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DecryptError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, DecryptError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DecryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, DecryptError::KMSInvalidState(err.msg))
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(DecryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service(&res, DecryptError::KeyUnavailable(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DecryptError::NotFound(err.msg))
+                    return RusotoError::service(&res, DecryptError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2060,16 +2093,16 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DeleteAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::DependencyTimeout(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteAliasError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DeleteAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2108,22 +2141,28 @@ impl DeleteCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreHasCMKsException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs(err.msg),
+                    )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::CustomKeyStoreNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomKeyStoreError::KMSInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2168,30 +2207,38 @@ impl DeleteImportedKeyMaterialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImportedKeyMaterialError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImportedKeyMaterialError::InvalidArn(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::KMSInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImportedKeyMaterialError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImportedKeyMaterialError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteImportedKeyMaterialError::NotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteImportedKeyMaterialError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2232,12 +2279,16 @@ impl DescribeCustomKeyStoresError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCustomKeyStoresError::CustomKeyStoreNotFound(err.msg),
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DescribeCustomKeyStoresError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCustomKeyStoresError::KMSInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2276,16 +2327,16 @@ impl DescribeKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DescribeKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, DescribeKeyError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DescribeKeyError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DescribeKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, DescribeKeyError::KMSInternal(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeKeyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2326,19 +2377,19 @@ impl DisableKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DisableKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, DisableKeyError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DisableKeyError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisableKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, DisableKeyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DisableKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, DisableKeyError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DisableKeyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2384,29 +2435,37 @@ impl DisableKeyRotationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DisableKeyRotationError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableKeyRotationError::DependencyTimeout(err.msg),
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(DisableKeyRotationError::Disabled(err.msg))
+                    return RusotoError::service(&res, DisableKeyRotationError::Disabled(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableKeyRotationError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DisableKeyRotationError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisableKeyRotationError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableKeyRotationError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DisableKeyRotationError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableKeyRotationError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableKeyRotationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DisableKeyRotationError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DisableKeyRotationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableKeyRotationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2446,19 +2505,22 @@ impl DisconnectCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisconnectCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisconnectCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisconnectCustomKeyStoreError::KMSInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisconnectCustomKeyStoreError::KMSInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2504,22 +2566,22 @@ impl EnableKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EnableKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, EnableKeyError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, EnableKeyError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EnableKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, EnableKeyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EnableKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, EnableKeyError::KMSInvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, EnableKeyError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, EnableKeyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2566,27 +2628,34 @@ impl EnableKeyRotationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EnableKeyRotationError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableKeyRotationError::DependencyTimeout(err.msg),
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(EnableKeyRotationError::Disabled(err.msg))
+                    return RusotoError::service(&res, EnableKeyRotationError::Disabled(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableKeyRotationError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, EnableKeyRotationError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EnableKeyRotationError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, EnableKeyRotationError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EnableKeyRotationError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableKeyRotationError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableKeyRotationError::NotFound(err.msg))
+                    return RusotoError::service(&res, EnableKeyRotationError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(EnableKeyRotationError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableKeyRotationError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2636,28 +2705,28 @@ impl EncryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EncryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, EncryptError::DependencyTimeout(err.msg))
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(EncryptError::Disabled(err.msg))
+                    return RusotoError::service(&res, EncryptError::Disabled(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(EncryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, EncryptError::InvalidGrantToken(err.msg))
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(EncryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(&res, EncryptError::InvalidKeyUsage(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EncryptError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, EncryptError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EncryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, EncryptError::KMSInvalidState(err.msg))
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(EncryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service(&res, EncryptError::KeyUnavailable(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EncryptError::NotFound(err.msg))
+                    return RusotoError::service(&res, EncryptError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2708,28 +2777,43 @@ impl GenerateDataKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateDataKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyError::DependencyTimeout(err.msg),
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyError::Disabled(err.msg))
+                    return RusotoError::service(&res, GenerateDataKeyError::Disabled(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GenerateDataKeyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyError::InvalidGrantToken(err.msg),
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GenerateDataKeyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyError::InvalidKeyUsage(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, GenerateDataKeyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyError::KMSInvalidState(err.msg),
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyError::KeyUnavailable(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GenerateDataKeyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2782,37 +2866,52 @@ impl GenerateDataKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::DependencyTimeout(err.msg),
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::Disabled(err.msg))
+                    return RusotoError::service(&res, GenerateDataKeyPairError::Disabled(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::InvalidGrantToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::InvalidGrantToken(err.msg),
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::InvalidKeyUsage(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::KMSInvalidState(err.msg),
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KeyUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::KeyUnavailable(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(&res, GenerateDataKeyPairError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyPairError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2868,47 +2967,56 @@ impl GenerateDataKeyPairWithoutPlaintextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::DependencyTimeout(err.msg),
                     )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::Disabled(err.msg),
                     )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::InvalidGrantToken(err.msg),
                     )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::InvalidKeyUsage(err.msg),
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::KMSInternal(err.msg),
                     )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::KMSInvalidState(err.msg),
                     )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::KeyUnavailable(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::NotFound(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyPairWithoutPlaintextError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2978,44 +3086,52 @@ impl GenerateDataKeyWithoutPlaintextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyWithoutPlaintextError::DependencyTimeout(err.msg),
                     )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::Disabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyWithoutPlaintextError::Disabled(err.msg),
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyWithoutPlaintextError::InvalidGrantToken(err.msg),
                     )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyWithoutPlaintextError::InvalidKeyUsage(err.msg),
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::KMSInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyWithoutPlaintextError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyWithoutPlaintextError::KMSInvalidState(err.msg),
                     )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataKeyWithoutPlaintextError::KeyUnavailable(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateDataKeyWithoutPlaintextError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3068,20 +3184,25 @@ impl GenerateRandomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(GenerateRandomError::CustomKeyStoreInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateRandomError::CustomKeyStoreInvalidState(err.msg),
+                    )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(GenerateRandomError::CustomKeyStoreNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateRandomError::CustomKeyStoreNotFound(err.msg),
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateRandomError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateRandomError::DependencyTimeout(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateRandomError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, GenerateRandomError::KMSInternal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3122,19 +3243,22 @@ impl GetKeyPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetKeyPolicyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyPolicyError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetKeyPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetKeyPolicyError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetKeyPolicyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, GetKeyPolicyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetKeyPolicyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, GetKeyPolicyError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetKeyPolicyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3178,28 +3302,37 @@ impl GetKeyRotationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyRotationStatusError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyRotationStatusError::InvalidArn(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyRotationStatusError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyRotationStatusError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetKeyRotationStatusError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyRotationStatusError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3244,28 +3377,40 @@ impl GetParametersForImportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetParametersForImportError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersForImportError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetParametersForImportError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersForImportError::InvalidArn(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetParametersForImportError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersForImportError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetParametersForImportError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersForImportError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetParametersForImportError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersForImportError::NotFound(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetParametersForImportError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersForImportError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3318,34 +3463,43 @@ impl GetPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetPublicKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPublicKeyError::DependencyTimeout(err.msg),
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GetPublicKeyError::Disabled(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::Disabled(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::InvalidArn(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPublicKeyError::InvalidGrantToken(err.msg),
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::InvalidKeyUsage(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetPublicKeyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetPublicKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::KMSInvalidState(err.msg))
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GetPublicKeyError::KeyUnavailable(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::KeyUnavailable(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPublicKeyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetPublicKeyError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetPublicKeyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPublicKeyError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3402,42 +3556,55 @@ impl ImportKeyMaterialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::DependencyTimeout(err.msg),
+                    )
                 }
                 "ExpiredImportTokenException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::ExpiredImportToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::ExpiredImportToken(err.msg),
+                    )
                 }
                 "IncorrectKeyMaterialException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::IncorrectKeyMaterial(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::IncorrectKeyMaterial(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ImportKeyMaterialError::InvalidArn(err.msg))
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidCiphertext(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::InvalidCiphertext(err.msg),
+                    )
                 }
                 "InvalidImportTokenException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidImportToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::InvalidImportToken(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ImportKeyMaterialError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::NotFound(err.msg))
+                    return RusotoError::service(&res, ImportKeyMaterialError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyMaterialError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3484,19 +3651,19 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListAliasesError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::InvalidArn(err.msg))
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidMarker(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::InvalidMarker(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListAliasesError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::KMSInternal(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3540,22 +3707,22 @@ impl ListGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListGrantsError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, ListGrantsError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListGrantsError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListGrantsError::InvalidArn(err.msg))
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListGrantsError::InvalidMarker(err.msg))
+                    return RusotoError::service(&res, ListGrantsError::InvalidMarker(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListGrantsError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ListGrantsError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ListGrantsError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, ListGrantsError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGrantsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListGrantsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3598,19 +3765,25 @@ impl ListKeyPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListKeyPoliciesError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListKeyPoliciesError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ListKeyPoliciesError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListKeyPoliciesError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListKeyPoliciesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3648,13 +3821,13 @@ impl ListKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListKeysError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, ListKeysError::DependencyTimeout(err.msg))
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListKeysError::InvalidMarker(err.msg))
+                    return RusotoError::service(&res, ListKeysError::InvalidMarker(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListKeysError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ListKeysError::KMSInternal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3692,16 +3865,19 @@ impl ListResourceTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListResourceTagsError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListResourceTagsError::InvalidArn(err.msg))
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListResourceTagsError::InvalidMarker(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceTagsError::InvalidMarker(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListResourceTagsError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ListResourceTagsError::KMSInternal(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResourceTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListResourceTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3742,21 +3918,31 @@ impl ListRetirableGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRetirableGrantsError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRetirableGrantsError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::InvalidMarker(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRetirableGrantsError::InvalidMarker(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRetirableGrantsError::KMSInternal(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListRetirableGrantsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3804,30 +3990,37 @@ impl PutKeyPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(PutKeyPolicyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutKeyPolicyError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutKeyPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, PutKeyPolicyError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(PutKeyPolicyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, PutKeyPolicyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutKeyPolicyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, PutKeyPolicyError::KMSInvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutKeyPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutKeyPolicyError::LimitExceeded(err.msg))
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(PutKeyPolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutKeyPolicyError::MalformedPolicyDocument(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutKeyPolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutKeyPolicyError::NotFound(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutKeyPolicyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutKeyPolicyError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3882,34 +4075,34 @@ impl ReEncryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ReEncryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::DependencyTimeout(err.msg))
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(ReEncryptError::Disabled(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::Disabled(err.msg))
                 }
                 "IncorrectKeyException" => {
-                    return RusotoError::Service(ReEncryptError::IncorrectKey(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::IncorrectKey(err.msg))
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidCiphertext(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::InvalidCiphertext(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::InvalidGrantToken(err.msg))
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::InvalidKeyUsage(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ReEncryptError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ReEncryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::KMSInvalidState(err.msg))
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(ReEncryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::KeyUnavailable(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReEncryptError::NotFound(err.msg))
+                    return RusotoError::service(&res, ReEncryptError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3960,25 +4153,25 @@ impl RetireGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(RetireGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::InvalidArn(err.msg))
                 }
                 "InvalidGrantIdException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidGrantId(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::InvalidGrantId(err.msg))
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::InvalidGrantToken(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(RetireGrantError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(RetireGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RetireGrantError::NotFound(err.msg))
+                    return RusotoError::service(&res, RetireGrantError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4024,22 +4217,22 @@ impl RevokeGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(RevokeGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, RevokeGrantError::DependencyTimeout(err.msg))
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RevokeGrantError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, RevokeGrantError::InvalidArn(err.msg))
                 }
                 "InvalidGrantIdException" => {
-                    return RusotoError::Service(RevokeGrantError::InvalidGrantId(err.msg))
+                    return RusotoError::service(&res, RevokeGrantError::InvalidGrantId(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(RevokeGrantError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, RevokeGrantError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(RevokeGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, RevokeGrantError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeGrantError::NotFound(err.msg))
+                    return RusotoError::service(&res, RevokeGrantError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4082,21 +4275,31 @@ impl ScheduleKeyDeletionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ScheduleKeyDeletionError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ScheduleKeyDeletionError::InvalidArn(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ScheduleKeyDeletionError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::KMSInvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ScheduleKeyDeletionError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::NotFound(err.msg))
+                    return RusotoError::service(&res, ScheduleKeyDeletionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4144,25 +4347,29 @@ impl SignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(SignError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, SignError::DependencyTimeout(err.msg))
+                }
+                "DisabledException" => {
+                    return RusotoError::service(&res, SignError::Disabled(err.msg))
                 }
-                "DisabledException" => return RusotoError::Service(SignError::Disabled(err.msg)),
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(SignError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, SignError::InvalidGrantToken(err.msg))
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(SignError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(&res, SignError::InvalidKeyUsage(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(SignError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, SignError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(SignError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, SignError::KMSInvalidState(err.msg))
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(SignError::KeyUnavailable(err.msg))
+                    return RusotoError::service(&res, SignError::KeyUnavailable(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, SignError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(SignError::NotFound(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -4208,21 +4415,23 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(TagResourceError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, TagResourceError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(TagResourceError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, TagResourceError::KMSInvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
+                }
+                "TagException" => {
+                    return RusotoError::service(&res, TagResourceError::Tag(err.msg))
                 }
-                "TagException" => return RusotoError::Service(TagResourceError::Tag(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -4264,18 +4473,20 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArn(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UntagResourceError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UntagResourceError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::KMSInvalidState(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
+                }
+                "TagException" => {
+                    return RusotoError::service(&res, UntagResourceError::Tag(err.msg))
                 }
-                "TagException" => return RusotoError::Service(UntagResourceError::Tag(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -4316,19 +4527,19 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(UpdateAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::DependencyTimeout(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateAliasError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::KMSInternal(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UpdateAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::KMSInvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4376,42 +4587,52 @@ impl UpdateCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
                     )
                 }
                 "CloudHsmClusterNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCustomKeyStoreError::CloudHsmClusterNotFound(err.msg),
                     )
                 }
                 "CloudHsmClusterNotRelatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCustomKeyStoreError::CloudHsmClusterNotRelated(err.msg),
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
                     )
                 }
                 "CustomKeyStoreNameInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCustomKeyStoreError::CustomKeyStoreNameInUse(err.msg),
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(UpdateCustomKeyStoreError::CustomKeyStoreNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCustomKeyStoreError::KMSInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4464,23 +4685,31 @@ impl UpdateKeyDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::DependencyTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateKeyDescriptionError::DependencyTimeout(err.msg),
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateKeyDescriptionError::InvalidArn(err.msg),
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::KMSInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateKeyDescriptionError::KMSInternal(err.msg),
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::KMSInvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateKeyDescriptionError::KMSInvalidState(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateKeyDescriptionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4530,28 +4759,32 @@ impl VerifyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(VerifyError::DependencyTimeout(err.msg))
+                    return RusotoError::service(&res, VerifyError::DependencyTimeout(err.msg))
+                }
+                "DisabledException" => {
+                    return RusotoError::service(&res, VerifyError::Disabled(err.msg))
                 }
-                "DisabledException" => return RusotoError::Service(VerifyError::Disabled(err.msg)),
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(VerifyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service(&res, VerifyError::InvalidGrantToken(err.msg))
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(VerifyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service(&res, VerifyError::InvalidKeyUsage(err.msg))
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(VerifyError::KMSInternal(err.msg))
+                    return RusotoError::service(&res, VerifyError::KMSInternal(err.msg))
                 }
                 "KMSInvalidSignatureException" => {
-                    return RusotoError::Service(VerifyError::KMSInvalidSignature(err.msg))
+                    return RusotoError::service(&res, VerifyError::KMSInvalidSignature(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(VerifyError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, VerifyError::KMSInvalidState(err.msg))
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(VerifyError::KeyUnavailable(err.msg))
+                    return RusotoError::service(&res, VerifyError::KeyUnavailable(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, VerifyError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(VerifyError::NotFound(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -4873,6 +5106,7 @@ impl KmsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4888,6 +5122,7 @@ impl KmsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> KmsClient {
         KmsClient { client, region }
     }
@@ -4901,6 +5136,9 @@ impl Kms for KmsClient {
         input: CancelKeyDeletionRequest,
     ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelKeyDeletion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4919,6 +5157,9 @@ impl Kms for KmsClient {
         input: ConnectCustomKeyStoreRequest,
     ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ConnectCustomKeyStore");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.ConnectCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4938,6 +5179,9 @@ impl Kms for KmsClient {
         input: CreateAliasRequest,
     ) -> Result<(), RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4955,6 +5199,9 @@ impl Kms for KmsClient {
         input: CreateCustomKeyStoreRequest,
     ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCustomKeyStore");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.CreateCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4974,6 +5221,9 @@ impl Kms for KmsClient {
         input: CreateGrantRequest,
     ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGrant");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.CreateGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4992,6 +5242,9 @@ impl Kms for KmsClient {
         input: CreateKeyRequest,
     ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.CreateKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5010,6 +5263,9 @@ impl Kms for KmsClient {
         input: DecryptRequest,
     ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Decrypt");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.Decrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5028,6 +5284,9 @@ impl Kms for KmsClient {
         input: DeleteAliasRequest,
     ) -> Result<(), RusotoError<DeleteAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5045,6 +5304,9 @@ impl Kms for KmsClient {
         input: DeleteCustomKeyStoreRequest,
     ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCustomKeyStore");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.DeleteCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5064,6 +5326,9 @@ impl Kms for KmsClient {
         input: DeleteImportedKeyMaterialRequest,
     ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteImportedKeyMaterial");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.DeleteImportedKeyMaterial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5081,6 +5346,9 @@ impl Kms for KmsClient {
         input: DescribeCustomKeyStoresRequest,
     ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCustomKeyStores");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.DescribeCustomKeyStores");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5100,6 +5368,9 @@ impl Kms for KmsClient {
         input: DescribeKeyRequest,
     ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeKey");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.DescribeKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5118,6 +5389,9 @@ impl Kms for KmsClient {
         input: DisableKeyRequest,
     ) -> Result<(), RusotoError<DisableKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.DisableKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5135,6 +5409,9 @@ impl Kms for KmsClient {
         input: DisableKeyRotationRequest,
     ) -> Result<(), RusotoError<DisableKeyRotationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableKeyRotation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5152,6 +5429,9 @@ impl Kms for KmsClient {
         input: DisconnectCustomKeyStoreRequest,
     ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisconnectCustomKeyStore");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.DisconnectCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5168,6 +5448,9 @@ impl Kms for KmsClient {
     /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     async fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.EnableKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5185,6 +5468,9 @@ impl Kms for KmsClient {
         input: EnableKeyRotationRequest,
     ) -> Result<(), RusotoError<EnableKeyRotationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableKeyRotation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5202,6 +5488,9 @@ impl Kms for KmsClient {
         input: EncryptRequest,
     ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Encrypt");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.Encrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5220,6 +5509,9 @@ impl Kms for KmsClient {
         input: GenerateDataKeyRequest,
     ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateDataKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.GenerateDataKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5238,6 +5530,9 @@ impl Kms for KmsClient {
         input: GenerateDataKeyPairRequest,
     ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateDataKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.GenerateDataKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5259,6 +5554,9 @@ impl Kms for KmsClient {
         RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateDataKeyPairWithoutPlaintext");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "TrentService.GenerateDataKeyPairWithoutPlaintext",
@@ -5287,6 +5585,9 @@ impl Kms for KmsClient {
         RusotoError<GenerateDataKeyWithoutPlaintextError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateDataKeyWithoutPlaintext");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "TrentService.GenerateDataKeyWithoutPlaintext",
@@ -5309,6 +5610,9 @@ impl Kms for KmsClient {
         input: GenerateRandomRequest,
     ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateRandom");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.GenerateRandom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5327,6 +5631,9 @@ impl Kms for KmsClient {
         input: GetKeyPolicyRequest,
     ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetKeyPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5345,6 +5652,9 @@ impl Kms for KmsClient {
         input: GetKeyRotationStatusRequest,
     ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetKeyRotationStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.GetKeyRotationStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5364,6 +5674,9 @@ impl Kms for KmsClient {
         input: GetParametersForImportRequest,
     ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetParametersForImport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.GetParametersForImport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5383,6 +5696,9 @@ impl Kms for KmsClient {
         input: GetPublicKeyRequest,
     ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPublicKey");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.GetPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5401,6 +5717,9 @@ impl Kms for KmsClient {
         input: ImportKeyMaterialRequest,
     ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportKeyMaterial");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.ImportKeyMaterial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5419,6 +5738,9 @@ impl Kms for KmsClient {
         input: ListAliasesRequest,
     ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5437,6 +5759,9 @@ impl Kms for KmsClient {
         input: ListGrantsRequest,
     ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGrants");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.ListGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5455,6 +5780,9 @@ impl Kms for KmsClient {
         input: ListKeyPoliciesRequest,
     ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListKeyPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5473,6 +5801,9 @@ impl Kms for KmsClient {
         input: ListKeysRequest,
     ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.ListKeys");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5491,6 +5822,9 @@ impl Kms for KmsClient {
         input: ListResourceTagsRequest,
     ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourceTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.ListResourceTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5509,6 +5843,9 @@ impl Kms for KmsClient {
         input: ListRetirableGrantsRequest,
     ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRetirableGrants");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TrentService.ListRetirableGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5527,6 +5864,9 @@ impl Kms for KmsClient {
         input: PutKeyPolicyRequest,
     ) -> Result<(), RusotoError<PutKeyPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutKeyPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5544,6 +5884,9 @@ impl Kms for KmsClient {
         input: ReEncryptRequest,
     ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ReEncrypt");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.ReEncrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5562,6 +5905,9 @@ impl Kms for KmsClient {
         input: RetireGrantRequest,
     ) -> Result<(), RusotoError<RetireGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RetireGrant");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.RetireGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5579,6 +5925,9 @@ impl Kms for KmsClient {
         input: RevokeGrantRequest,
     ) -> Result<(), RusotoError<RevokeGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RevokeGrant");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.RevokeGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5596,6 +5945,9 @@ impl Kms for KmsClient {
         input: ScheduleKeyDeletionRequest,
     ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ScheduleKeyDeletion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.ScheduleKeyDeletion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5611,6 +5963,9 @@ impl Kms for KmsClient {
     /// <p>Creates a <a href="https://en.wikipedia.org/wiki/Digital_signature">digital signature</a> for a message or message digest by using the private key in an asymmetric CMK. To verify the signature, use the <a>Verify</a> operation, or use the public key in the same asymmetric CMK outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Digital signatures are generated and verified by using asymmetric key pair, such as an RSA or ECC pair that is represented by an asymmetric customer master key (CMK). The key owner (or an authorized user) uses their private key to sign a message. Anyone with the public key can verify that the message was signed with that particular private key and that the message hasn't changed since it was signed. </p> <p>To use the <code>Sign</code> operation, provide the following information:</p> <ul> <li> <p>Use the <code>KeyId</code> parameter to identify an asymmetric CMK with a <code>KeyUsage</code> value of <code>SIGN_VERIFY</code>. To get the <code>KeyUsage</code> value of a CMK, use the <a>DescribeKey</a> operation. The caller must have <code>kms:Sign</code> permission on the CMK.</p> </li> <li> <p>Use the <code>Message</code> parameter to specify the message or message digest to sign. You can submit messages of up to 4096 bytes. To sign a larger message, generate a hash digest of the message, and then provide the hash digest in the <code>Message</code> parameter. To indicate whether the message is a full message or a digest, use the <code>MessageType</code> parameter.</p> </li> <li> <p>Choose a signing algorithm that is compatible with the CMK. </p> </li> </ul> <important> <p>When signing a message, be sure to record the CMK and the signing algorithm. This information is required to verify the signature.</p> </important> <p>To verify the signature that this operation generates, use the <a>Verify</a> operation. Or use the <a>GetPublicKey</a> operation to download the public key and then use the public key to verify the signature outside of AWS KMS. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     async fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Sign");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.Sign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5629,6 +5984,9 @@ impl Kms for KmsClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5646,6 +6004,9 @@ impl Kms for KmsClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5663,6 +6024,9 @@ impl Kms for KmsClient {
         input: UpdateAliasRequest,
     ) -> Result<(), RusotoError<UpdateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.UpdateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5680,6 +6044,9 @@ impl Kms for KmsClient {
         input: UpdateCustomKeyStoreRequest,
     ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCustomKeyStore");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.UpdateCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5699,6 +6066,9 @@ impl Kms for KmsClient {
         input: UpdateKeyDescriptionRequest,
     ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateKeyDescription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.UpdateKeyDescription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5716,6 +6086,9 @@ impl Kms for KmsClient {
         input: VerifyRequest,
     ) -> Result<VerifyResponse, RusotoError<VerifyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Verify");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TrentService.Verify");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5728,3 +6101,529 @@ impl Kms for KmsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyResponse, _>()
     }
 }
+
+/// A blocking client for the KMS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `KmsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KmsBlockingClient {
+    client: KmsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KmsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> KmsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        KmsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KmsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KmsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> KmsBlockingClient {
+        KmsBlockingClient {
+            client: KmsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels the deletion of a customer master key (CMK). When this operation succeeds, the key state of the CMK is <code>Disabled</code>. To enable the CMK, use <a>EnableKey</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling and canceling deletion of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::cancel_key_deletion`.
+    pub fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
+        self.runtime
+            .block_on(self.client.cancel_key_deletion(input))
+    }
+
+    /// <p>Connects or reconnects a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> to its associated AWS CloudHSM cluster.</p> <p>The custom key store must be connected before you can create customer master keys (CMKs) in the key store or use the CMKs it contains. You can disconnect and reconnect a custom key store at any time.</p> <p>To connect a custom key store, its associated AWS CloudHSM cluster must have at least one active HSM. To get the number of active HSMs in a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation. To add HSMs to the cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_CreateHsm.html">CreateHsm</a> operation. Also, the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user</a> (CU) must not be logged into the cluster. This prevents AWS KMS from using this account to log in.</p> <p>The connection process can take an extended amount of time to complete; up to 20 minutes. This operation starts the connection process, but it does not wait for it to complete. When it succeeds, this operation quickly returns an HTTP 200 response and a JSON object with no properties. However, this response does not indicate that the custom key store is connected. To get the connection state of the custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>During the connection process, AWS KMS finds the AWS CloudHSM cluster that is associated with the custom key store, creates the connection infrastructure, connects to the cluster, logs into the AWS CloudHSM client as the <code>kmsuser</code> CU, and rotates its password.</p> <p>The <code>ConnectCustomKeyStore</code> operation might fail for various reasons. To find the reason, use the <a>DescribeCustomKeyStores</a> operation and see the <code>ConnectionErrorCode</code> in the response. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>To fix the failure, use the <a>DisconnectCustomKeyStore</a> operation to disconnect the custom key store, correct the error, use the <a>UpdateCustomKeyStore</a> operation if necessary, and then use <code>ConnectCustomKeyStore</code> again.</p> <p>If you are having trouble connecting or disconnecting a custom key store, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::connect_custom_key_store`.
+    pub fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.connect_custom_key_store(input))
+    }
+
+    /// <p>Creates a display name for a customer managed customer master key (CMK). You can use an alias to identify a CMK in <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a>, such as <a>Encrypt</a> and <a>GenerateDataKey</a>. You can change the CMK associated with the alias at any time.</p> <p>Aliases are easier to remember than key IDs. They can also help to simplify your applications. For example, if you use an alias in your code, you can change the CMK your code uses by associating a given alias with a different CMK. </p> <p>To run the same code in multiple AWS regions, use an alias in your code, such as <code>alias/ApplicationKey</code>. Then, in each AWS Region, create an <code>alias/ApplicationKey</code> alias that is associated with a CMK in that Region. When you run your code, it uses the <code>alias/ApplicationKey</code> CMK for that AWS Region without any Region-specific code.</p> <p>This operation does not return a response. To get the alias that you created, use the <a>ListAliases</a> operation.</p> <p>To use aliases successfully, be aware of the following information.</p> <ul> <li> <p>Each alias points to only one CMK at a time, although a single CMK can have multiple aliases. The alias and its associated CMK must be in the same AWS account and Region. </p> </li> <li> <p>You can associate an alias with any customer managed CMK in the same AWS account and Region. However, you do not have permission to associate an alias with an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMK</a> or an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-owned-cmk">AWS owned CMK</a>. </p> </li> <li> <p>To change the CMK associated with an alias, use the <a>UpdateAlias</a> operation. The current CMK and the new CMK must be the same type (both symmetric or both asymmetric) and they must have the same key usage (<code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>). This restriction prevents cryptographic errors in code that uses aliases.</p> </li> <li> <p>The alias name must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. It can contain only alphanumeric characters, forward slashes (/), underscores (_), and dashes (-). The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMKs</a>. </p> </li> <li> <p>The alias name must be unique within an AWS Region. However, you can use the same alias name in multiple Regions of the same AWS account. Each instance of the alias is associated with a CMK in its Region.</p> </li> <li> <p>After you create an alias, you cannot change its alias name. However, you can use the <a>DeleteAlias</a> operation to delete the alias and then create a new alias with the desired name.</p> </li> <li> <p>You can use an alias name or alias ARN to identify a CMK in AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a> and in the <a>DescribeKey</a> operation. However, you cannot use alias names or alias ARNs in API operations that manage CMKs, such as <a>DisableKey</a> or <a>GetKeyPolicy</a>. For information about the valid CMK identifiers for each AWS KMS API operation, see the descriptions of the <code>KeyId</code> parameter in the API operation documentation.</p> </li> </ul> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases and alias ARNs of CMKs in each AWS account and Region, use the <a>ListAliases</a> operation.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::create_alias`.
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<(), RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// <p>Creates a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that is associated with an <a href="https://docs.aws.amazon.com/cloudhsm/latest/userguide/clusters.html">AWS CloudHSM cluster</a> that you own and manage.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>Before you create the custom key store, you must assemble the required elements, including an AWS CloudHSM cluster that fulfills the requirements for a custom key store. For details about the required elements, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">Assemble the Prerequisites</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the operation completes successfully, it returns the ID of the new custom key store. Before you can use your new custom key store, you need to use the <a>ConnectCustomKeyStore</a> operation to connect the new key store to its AWS CloudHSM cluster. Even if you are not going to use your custom key store immediately, you might want to connect it to verify that all settings are correct and then disconnect it until you are ready to use it.</p> <p>For help with failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::create_custom_key_store`.
+    pub fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.create_custom_key_store(input))
+    }
+
+    /// <p>Adds a grant to a customer master key (CMK). The grant allows the grantee principal to use the CMK when the conditions specified in the grant are met. When setting permissions, grants are an alternative to key policies. </p> <p>To create a grant that allows a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operation</a> only when the request includes a particular <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">encryption context</a>, use the <code>Constraints</code> parameter. For details, see <a>GrantConstraints</a>.</p> <p>You can create grants on symmetric and asymmetric CMKs. However, if the grant allows an operation that the CMK does not support, <code>CreateGrant</code> fails with a <code>ValidationException</code>. </p> <ul> <li> <p>Grants for symmetric CMKs cannot allow operations that are not supported for symmetric CMKs, including <a>Sign</a>, <a>Verify</a>, and <a>GetPublicKey</a>. (There are limited exceptions to this rule for legacy operations, but you should not create a grant for an operation that AWS KMS does not support.)</p> </li> <li> <p>Grants for asymmetric CMKs cannot allow operations that are not supported for asymmetric CMKs, including operations that <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GenerateDataKey">generate data keys</a> or <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GenerateDataKeyPair">data key pairs</a>, or operations related to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic key rotation</a>, <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">imported key material</a>, or CMKs in <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a>.</p> </li> <li> <p>Grants for asymmetric CMKs with a <code>KeyUsage</code> of <code>ENCRYPT_DECRYPT</code> cannot allow the <a>Sign</a> or <a>Verify</a> operations. Grants for asymmetric CMKs with a <code>KeyUsage</code> of <code>SIGN_VERIFY</code> cannot allow the <a>Encrypt</a> or <a>Decrypt</a> operations.</p> </li> <li> <p>Grants for asymmetric CMKs cannot include an encryption context grant constraint. An encryption context is not supported on asymmetric CMKs.</p> </li> </ul> <p>For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter. For more information about grants, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/grants.html">Grants</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::create_grant`.
+    pub fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
+        self.runtime.block_on(self.client.create_grant(input))
+    }
+
+    /// <p><p>Creates a unique customer managed <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master-keys">customer master key</a> (CMK) in your AWS account and Region. You cannot use this operation to create a CMK in a different AWS account.</p> <p>You can use the <code>CreateKey</code> operation to create symmetric or asymmetric CMKs.</p> <ul> <li> <p> <b>Symmetric CMKs</b> contain a 256-bit symmetric key that never leaves AWS KMS unencrypted. To use the CMK, you must call AWS KMS. You can use a symmetric CMK to encrypt and decrypt small amounts of data, but they are typically used to generate <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-keys">data keys</a> and <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#data-key-pairs">data keys pairs</a>. For details, see <a>GenerateDataKey</a> and <a>GenerateDataKeyPair</a>.</p> </li> <li> <p> <b>Asymmetric CMKs</b> can contain an RSA key pair or an Elliptic Curve (ECC) key pair. The private key in an asymmetric CMK never leaves AWS KMS unencrypted. However, you can use the <a>GetPublicKey</a> operation to download the public key so it can be used outside of AWS KMS. CMKs with RSA key pairs can be used to encrypt or decrypt data or sign and verify messages (but not both). CMKs with ECC key pairs can be used only to sign and verify messages.</p> </li> </ul> <p>For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To create different types of CMKs, use the following guidance:</p> <dl> <dt>Asymmetric CMKs</dt> <dd> <p>To create an asymmetric CMK, use the <code>CustomerMasterKeySpec</code> parameter to specify the type of key material in the CMK. Then, use the <code>KeyUsage</code> parameter to determine whether the CMK will be used to encrypt and decrypt or sign and verify. You can&#39;t change these properties after the CMK is created.</p> <p> </p> </dd> <dt>Symmetric CMKs</dt> <dd> <p>When creating a symmetric CMK, you don&#39;t need to specify the <code>CustomerMasterKeySpec</code> or <code>KeyUsage</code> parameters. The default value for <code>CustomerMasterKeySpec</code>, <code>SYMMETRIC<em>DEFAULT</code>, and the default value for <code>KeyUsage</code>, <code>ENCRYPT</em>DECRYPT</code>, are the only valid values for symmetric CMKs. </p> <p> </p> </dd> <dt>Imported Key Material</dt> <dd> <p>To import your own key material, begin by creating a symmetric CMK with no key material. To do this, use the <code>Origin</code> parameter of <code>CreateKey</code> with a value of <code>EXTERNAL</code>. Next, use <a>GetParametersForImport</a> operation to get a public key and import token, and use the public key to encrypt your key material. Then, use <a>ImportKeyMaterial</a> with your import token to import the key material. For step-by-step instructions, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>. You cannot import the key material into an asymmetric CMK.</p> <p> </p> </dd> <dt>Custom Key Stores</dt> <dd> <p>To create a symmetric CMK in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, use the <code>CustomKeyStoreId</code> parameter to specify the custom key store. You must also use the <code>Origin</code> parameter with a value of <code>AWS_CLOUDHSM</code>. The AWS CloudHSM cluster that is associated with the custom key store must have at least two active HSMs in different Availability Zones in the AWS Region. </p> <p>You cannot create an asymmetric CMK in a custom key store. For information about custom key stores in AWS KMS see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Using Custom Key Stores</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </dd> </dl></p>
+    ///
+    /// See `Kms::create_key`.
+    pub fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
+        self.runtime.block_on(self.client.create_key(input))
+    }
+
+    /// <p>Decrypts ciphertext that was encrypted by a AWS KMS customer master key (CMK) using any of the following operations:</p> <ul> <li> <p> <a>Encrypt</a> </p> </li> <li> <p> <a>GenerateDataKey</a> </p> </li> <li> <p> <a>GenerateDataKeyPair</a> </p> </li> <li> <p> <a>GenerateDataKeyWithoutPlaintext</a> </p> </li> <li> <p> <a>GenerateDataKeyPairWithoutPlaintext</a> </p> </li> </ul> <p>You can use this operation to decrypt ciphertext that was encrypted under a symmetric or asymmetric CMK. When the CMK is asymmetric, you must specify the CMK and the encryption algorithm that was used to encrypt the ciphertext. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The Decrypt operation also decrypts ciphertext that was encrypted outside of AWS KMS by the public key in an AWS KMS asymmetric CMK. However, it cannot decrypt ciphertext produced by other libraries, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a> or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a>. These libraries return a ciphertext format that is incompatible with AWS KMS.</p> <p>If the ciphertext was encrypted under a symmetric CMK, you do not need to specify the CMK or the encryption algorithm. AWS KMS can get this information from metadata that it adds to the symmetric ciphertext blob. However, if you prefer, you can specify the <code>KeyId</code> to ensure that a particular CMK is used to decrypt the ciphertext. If you specify a different CMK than the one used to encrypt the ciphertext, the <code>Decrypt</code> operation fails.</p> <p>Whenever possible, use key policies to give users permission to call the Decrypt operation on a particular CMK, instead of using IAM policies. Otherwise, you might create an IAM user policy that gives the user Decrypt permission on all CMKs. This user could decrypt ciphertext that was encrypted by CMKs in other accounts if the key policy for the cross-account CMK permits it. If you must use an IAM policy for <code>Decrypt</code> permissions, limit the user to particular CMKs or particular trusted accounts.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::decrypt`.
+    pub fn decrypt(
+        &self,
+        input: DecryptRequest,
+    ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
+        self.runtime.block_on(self.client.decrypt(input))
+    }
+
+    /// <p>Deletes the specified alias. You cannot perform this operation on an alias in a different AWS account. </p> <p>Because an alias is not a property of a CMK, you can delete and change the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs, use the <a>ListAliases</a> operation. </p> <p>Each CMK can have multiple aliases. To change the alias of a CMK, use <a>DeleteAlias</a> to delete the current alias and <a>CreateAlias</a> to create a new alias. To associate an existing alias with a different customer master key (CMK), call <a>UpdateAlias</a>.</p>
+    ///
+    /// See `Kms::delete_alias`.
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// <p>Deletes a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. This operation does not delete the AWS CloudHSM cluster that is associated with the custom key store, or affect any users or keys in the cluster.</p> <p>The custom key store that you delete cannot contain any AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">customer master keys (CMKs)</a>. Before deleting the key store, verify that you will never need to use any of the CMKs in the key store for any <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a>. Then, use <a>ScheduleKeyDeletion</a> to delete the AWS KMS customer master keys (CMKs) from the key store. When the scheduled waiting period expires, the <code>ScheduleKeyDeletion</code> operation deletes the CMKs. Then it makes a best effort to delete the key material from the associated cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>After all CMKs are deleted from AWS KMS, use <a>DisconnectCustomKeyStore</a> to disconnect the key store from AWS KMS. Then, you can delete the custom key store.</p> <p>Instead of deleting the custom key store, consider using <a>DisconnectCustomKeyStore</a> to disconnect it from AWS KMS. While the key store is disconnected, you cannot create or use the CMKs in the key store. But, you do not need to delete CMKs and you can reconnect a disconnected custom key store at any time.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    ///
+    /// See `Kms::delete_custom_key_store`.
+    pub fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_key_store(input))
+    }
+
+    /// <p>Deletes key material that you previously imported. This operation makes the specified customer master key (CMK) unusable. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>When the specified CMK is in the <code>PendingDeletion</code> state, this operation does not change the CMK's state. Otherwise, it changes the CMK's state to <code>PendingImport</code>.</p> <p>After you delete key material, you can use <a>ImportKeyMaterial</a> to reimport the same key material into the CMK.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::delete_imported_key_material`.
+    pub fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
+        self.runtime
+            .block_on(self.client.delete_imported_key_material(input))
+    }
+
+    /// <p>Gets information about <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key stores</a> in the account and region.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p> <p>By default, this operation returns information about all custom key stores in the account and region. To get only information about a particular custom key store, use either the <code>CustomKeyStoreName</code> or <code>CustomKeyStoreId</code> parameter (but not both).</p> <p>To determine whether the custom key store is connected to its AWS CloudHSM cluster, use the <code>ConnectionState</code> element in the response. If an attempt to connect the custom key store failed, the <code>ConnectionState</code> value is <code>FAILED</code> and the <code>ConnectionErrorCode</code> element in the response indicates the cause of the failure. For help interpreting the <code>ConnectionErrorCode</code>, see <a>CustomKeyStoresListEntry</a>.</p> <p>Custom key stores have a <code>DISCONNECTED</code> connection state if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If your custom key store state is <code>CONNECTED</code> but you are having trouble using it, make sure that its associated AWS CloudHSM cluster is active and contains the minimum number of HSMs required for the operation, if any.</p> <p> For help repairing your custom key store, see the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> topic in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::describe_custom_key_stores`.
+    pub fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
+        self.runtime
+            .block_on(self.client.describe_custom_key_stores(input))
+    }
+
+    /// <p>Provides detailed information about a customer master key (CMK). You can run <code>DescribeKey</code> on a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#customer-cmk">customer managed CMK</a> or an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#aws-managed-cmk">AWS managed CMK</a>.</p> <p>This detailed information includes the key ARN, creation date (and deletion date, if applicable), the key state, and the origin and expiration date (if any) of the key material. For CMKs in custom key stores, it includes information about the custom key store, such as the key store ID and the AWS CloudHSM cluster ID. It includes fields, like <code>KeySpec</code>, that help you distinguish symmetric from asymmetric CMKs. It also provides information that is particularly important to asymmetric CMKs, such as the key usage (encryption or signing) and the encryption algorithms or signing algorithms that the CMK supports.</p> <p> <code>DescribeKey</code> does not return the following information:</p> <ul> <li> <p>Aliases associated with the CMK. To get this information, use <a>ListAliases</a>.</p> </li> <li> <p>Whether automatic key rotation is enabled on the CMK. To get this information, use <a>GetKeyRotationStatus</a>. Also, some key states prevent a CMK from being automatically rotated. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html#rotate-keys-how-it-works">How Automatic Key Rotation Works</a> in <i>AWS Key Management Service Developer Guide</i>.</p> </li> <li> <p>Tags on the CMK. To get this information, use <a>ListResourceTags</a>.</p> </li> <li> <p>Key policies and grants on the CMK. To get this information, use <a>GetKeyPolicy</a> and <a>ListGrants</a>.</p> </li> </ul> <p>If you call the <code>DescribeKey</code> operation on a <i>predefined AWS alias</i>, that is, an AWS alias with no key ID, AWS KMS creates an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">AWS managed CMK</a>. Then, it associates the alias with the new CMK, and returns the <code>KeyId</code> and <code>Arn</code> of the new CMK in the response.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
+    ///
+    /// See `Kms::describe_key`.
+    pub fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
+        self.runtime.block_on(self.client.describe_key(input))
+    }
+
+    /// <p>Sets the state of a customer master key (CMK) to disabled, thereby preventing its use for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::disable_key`.
+    pub fn disable_key(
+        &self,
+        input: DisableKeyRequest,
+    ) -> Result<(), RusotoError<DisableKeyError>> {
+        self.runtime.block_on(self.client.disable_key(input))
+    }
+
+    /// <p>Disables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified symmetric customer master key (CMK).</p> <p> You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::disable_key_rotation`.
+    pub fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> Result<(), RusotoError<DisableKeyRotationError>> {
+        self.runtime
+            .block_on(self.client.disable_key_rotation(input))
+    }
+
+    /// <p>Disconnects the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> from its associated AWS CloudHSM cluster. While a custom key store is disconnected, you can manage the custom key store and its customer master keys (CMKs), but you cannot create or use CMKs in the custom key store. You can reconnect the custom key store at any time.</p> <note> <p>While a custom key store is disconnected, all attempts to create customer master keys (CMKs) in the custom key store or to use existing CMKs in <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a> will fail. This action can prevent users from storing and accessing sensitive data.</p> </note> <p/> <p>To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation. To reconnect a custom key store, use the <a>ConnectCustomKeyStore</a> operation.</p> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    ///
+    /// See `Kms::disconnect_custom_key_store`.
+    pub fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.disconnect_custom_key_store(input))
+    }
+
+    /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a>. You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::enable_key`.
+    pub fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
+        self.runtime.block_on(self.client.enable_key(input))
+    }
+
+    /// <p>Enables <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> for the specified symmetric customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::enable_key_rotation`.
+    pub fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> Result<(), RusotoError<EnableKeyRotationError>> {
+        self.runtime
+            .block_on(self.client.enable_key_rotation(input))
+    }
+
+    /// <p>Encrypts plaintext into ciphertext by using a customer master key (CMK). The <code>Encrypt</code> operation has two primary use cases:</p> <ul> <li> <p>You can encrypt small amounts of arbitrary data, such as a personal identifier or database password, or other sensitive information. </p> </li> <li> <p>You can use the <code>Encrypt</code> operation to move encrypted data from one AWS Region to another. For example, in Region A, generate a data key and use the plaintext key to encrypt your data. Then, in Region A, use the <code>Encrypt</code> operation to encrypt the plaintext data key under a CMK in Region B. Now, you can move the encrypted data and the encrypted data key to Region B. When necessary, you can decrypt the encrypted data key and the encrypted data entirely within in Region B.</p> </li> </ul> <p>You don't need to use the <code>Encrypt</code> operation to encrypt a data key. The <a>GenerateDataKey</a> and <a>GenerateDataKeyPair</a> operations return a plaintext data key and an encrypted copy of that data key.</p> <p>When you encrypt data, you must specify a symmetric or asymmetric CMK to use in the encryption operation. The CMK must have a <code>KeyUsage</code> value of <code>ENCRYPT_DECRYPT.</code> To find the <code>KeyUsage</code> of a CMK, use the <a>DescribeKey</a> operation. </p> <p>If you use a symmetric CMK, you can use an encryption context to add additional security to your encryption operation. If you specify an <code>EncryptionContext</code> when encrypting data, you must specify the same encryption context (a case-sensitive exact match) when decrypting the data. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>If you specify an asymmetric CMK, you must also specify the encryption algorithm. The algorithm must be compatible with the CMK type.</p> <important> <p>When you use an asymmetric CMK to encrypt or reencrypt data, be sure to record the CMK and encryption algorithm that you choose. You will be required to provide the same CMK and encryption algorithm when you decrypt the data. If the CMK and algorithm do not match the values used to encrypt the data, the decrypt operation fails.</p> <p>You are not required to supply the CMK ID and encryption algorithm when you decrypt with symmetric CMKs because AWS KMS stores this information in the ciphertext blob. AWS KMS cannot store metadata in ciphertext generated with asymmetric keys. The standard format for asymmetric key ciphertext does not include configurable fields.</p> </important> <p>The maximum size of the data that you can encrypt varies with the type of CMK and the encryption algorithm that you choose.</p> <ul> <li> <p>Symmetric CMKs</p> <ul> <li> <p> <code>SYMMETRIC_DEFAULT</code>: 4096 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_2048</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 214 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 190 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_3072</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 342 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 318 bytes</p> </li> </ul> </li> <li> <p> <code>RSA_4096</code> </p> <ul> <li> <p> <code>RSAES_OAEP_SHA_1</code>: 470 bytes</p> </li> <li> <p> <code>RSAES_OAEP_SHA_256</code>: 446 bytes</p> </li> </ul> </li> </ul> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the KeyId parameter.</p>
+    ///
+    /// See `Kms::encrypt`.
+    pub fn encrypt(
+        &self,
+        input: EncryptRequest,
+    ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
+        self.runtime.block_on(self.client.encrypt(input))
+    }
+
+    /// <p><p>Generates a unique symmetric data key for client-side encryption. This operation returns a plaintext copy of the data key and a copy that is encrypted under a customer master key (CMK) that you specify. You can use the plaintext key to encrypt your data outside of AWS KMS and store the encrypted data key with the encrypted data.</p> <p> <code>GenerateDataKey</code> returns a unique data key for each request. The bytes in the plaintext key are not related to the caller or the CMK.</p> <p>To generate a data key, specify the symmetric CMK that will be used to encrypt the data key. You cannot use an asymmetric CMK to generate data keys. To get the type of your CMK, use the <a>DescribeKey</a> operation. You must also specify the length of the data key. Use either the <code>KeySpec</code> or <code>NumberOfBytes</code> parameters (but not both). For 128-bit and 256-bit data keys, use the <code>KeySpec</code> parameter. </p> <p>To get only an encrypted copy of the data key, use <a>GenerateDataKeyWithoutPlaintext</a>. To generate an asymmetric data key pair, use the <a>GenerateDataKeyPair</a> or <a>GenerateDataKeyPairWithoutPlaintext</a> operation. To get a cryptographically secure random byte string, use <a>GenerateRandom</a>.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p> <b>How to use your data key</b> </p> <p>We recommend that you use the following pattern to encrypt data locally in your application. You can write your own code or use a client-side encryption library, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a>, the <a href="https://docs.aws.amazon.com/dynamodb-encryption-client/latest/devguide/">Amazon DynamoDB Encryption Client</a>, or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a> to do these tasks for you.</p> <p>To encrypt data outside of AWS KMS:</p> <ol> <li> <p>Use the <code>GenerateDataKey</code> operation to get a data key.</p> </li> <li> <p>Use the plaintext data key (in the <code>Plaintext</code> field of the response) to encrypt your data outside of AWS KMS. Then erase the plaintext data key from memory.</p> </li> <li> <p>Store the encrypted data key (in the <code>CiphertextBlob</code> field of the response) with the encrypted data.</p> </li> </ol> <p>To decrypt data outside of AWS KMS:</p> <ol> <li> <p>Use the <a>Decrypt</a> operation to decrypt the encrypted data key. The operation returns a plaintext copy of the data key.</p> </li> <li> <p>Use the plaintext data key to decrypt data outside of AWS KMS, then erase the plaintext data key from memory.</p> </li> </ol></p>
+    ///
+    /// See `Kms::generate_data_key`.
+    pub fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
+        self.runtime.block_on(self.client.generate_data_key(input))
+    }
+
+    /// <p>Generates a unique asymmetric data key pair. The <code>GenerateDataKeyPair</code> operation returns a plaintext public key, a plaintext private key, and a copy of the private key that is encrypted under the symmetric CMK you specify. You can use the data key pair to perform asymmetric cryptography outside of AWS KMS.</p> <p> <code>GenerateDataKeyPair</code> returns a unique data key pair for each request. The bytes in the keys are not related to the caller or the CMK that is used to encrypt the private key.</p> <p>You can use the public key that <code>GenerateDataKeyPair</code> returns to encrypt data or verify a signature outside of AWS KMS. Then, store the encrypted private key with the data. When you are ready to decrypt data or sign a message, you can use the <a>Decrypt</a> operation to decrypt the encrypted private key.</p> <p>To generate a data key pair, you must specify a symmetric customer master key (CMK) to encrypt the private key in a data key pair. You cannot use an asymmetric CMK or a CMK in a custom key store. To get the type and origin of your CMK, use the <a>DescribeKey</a> operation. </p> <p>If you are using the data key pair to encrypt data, or for any operation where you don't immediately need a private key, consider using the <a>GenerateDataKeyPairWithoutPlaintext</a> operation. <code>GenerateDataKeyPairWithoutPlaintext</code> returns a plaintext public key and an encrypted private key, but omits the plaintext private key that you need only to decrypt ciphertext or sign a message. Later, when you need to decrypt the data or sign a message, use the <a>Decrypt</a> operation to decrypt the encrypted private key in the data key pair.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::generate_data_key_pair`.
+    pub fn generate_data_key_pair(
+        &self,
+        input: GenerateDataKeyPairRequest,
+    ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
+        self.runtime
+            .block_on(self.client.generate_data_key_pair(input))
+    }
+
+    /// <p>Generates a unique asymmetric data key pair. The <code>GenerateDataKeyPairWithoutPlaintext</code> operation returns a plaintext public key and a copy of the private key that is encrypted under the symmetric CMK you specify. Unlike <a>GenerateDataKeyPair</a>, this operation does not return a plaintext private key. </p> <p>To generate a data key pair, you must specify a symmetric customer master key (CMK) to encrypt the private key in the data key pair. You cannot use an asymmetric CMK or a CMK in a custom key store. To get the type and origin of your CMK, use the <code>KeySpec</code> field in the <a>DescribeKey</a> response.</p> <p>You can use the public key that <code>GenerateDataKeyPairWithoutPlaintext</code> returns to encrypt data or verify a signature outside of AWS KMS. Then, store the encrypted private key with the data. When you are ready to decrypt data or sign a message, you can use the <a>Decrypt</a> operation to decrypt the encrypted private key.</p> <p> <code>GenerateDataKeyPairWithoutPlaintext</code> returns a unique data key pair for each request. The bytes in the key are not related to the caller or CMK that is used to encrypt the private key.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::generate_data_key_pair_without_plaintext`.
+    pub fn generate_data_key_pair_without_plaintext(
+        &self,
+        input: GenerateDataKeyPairWithoutPlaintextRequest,
+    ) -> Result<
+        GenerateDataKeyPairWithoutPlaintextResponse,
+        RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_data_key_pair_without_plaintext(input))
+    }
+
+    /// <p>Generates a unique symmetric data key. This operation returns a data key that is encrypted under a customer master key (CMK) that you specify. To request an asymmetric data key pair, use the <a>GenerateDataKeyPair</a> or <a>GenerateDataKeyPairWithoutPlaintext</a> operations.</p> <p> <code>GenerateDataKeyWithoutPlaintext</code> is identical to the <a>GenerateDataKey</a> operation except that returns only the encrypted copy of the data key. This operation is useful for systems that need to encrypt data at some point, but not immediately. When you need to encrypt the data, you call the <a>Decrypt</a> operation on the encrypted copy of the key. </p> <p>It's also useful in distributed systems with different levels of trust. For example, you might store encrypted data in containers. One component of your system creates new containers and stores an encrypted data key with each container. Then, a different component puts the data into the containers. That component first decrypts the data key, uses the plaintext data key to encrypt data, puts the encrypted data into the container, and then destroys the plaintext data key. In this system, the component that creates the containers never sees the plaintext data key.</p> <p> <code>GenerateDataKeyWithoutPlaintext</code> returns a unique data key for each request. The bytes in the keys are not related to the caller or CMK that is used to encrypt the private key.</p> <p>To generate a data key, you must specify the symmetric customer master key (CMK) that is used to encrypt the data key. You cannot use an asymmetric CMK to generate a data key. To get the type of your CMK, use the <a>DescribeKey</a> operation.</p> <p>If the operation succeeds, you will find the encrypted copy of the data key in the <code>CiphertextBlob</code> field.</p> <p>You can use the optional encryption context to add additional security to the encryption operation. If you specify an <code>EncryptionContext</code>, you must specify the same encryption context (a case-sensitive exact match) when decrypting the encrypted data key. Otherwise, the request to decrypt fails with an <code>InvalidCiphertextException</code>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::generate_data_key_without_plaintext`.
+    pub fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> Result<
+        GenerateDataKeyWithoutPlaintextResponse,
+        RusotoError<GenerateDataKeyWithoutPlaintextError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_data_key_without_plaintext(input))
+    }
+
+    /// <p>Returns a random byte string that is cryptographically secure.</p> <p>By default, the random byte string is generated in AWS KMS. To generate the byte string in the AWS CloudHSM cluster that is associated with a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, specify the custom key store ID.</p> <p>For more information about entropy and random number generation, see the <a href="https://d0.awsstatic.com/whitepapers/KMS-Cryptographic-Details.pdf">AWS Key Management Service Cryptographic Details</a> whitepaper.</p>
+    ///
+    /// See `Kms::generate_random`.
+    pub fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
+        self.runtime.block_on(self.client.generate_random(input))
+    }
+
+    /// <p>Gets a key policy attached to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p>
+    ///
+    /// See `Kms::get_key_policy`.
+    pub fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
+        self.runtime.block_on(self.client.get_key_policy(input))
+    }
+
+    /// <p>Gets a Boolean value that indicates whether <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html">automatic rotation of the key material</a> is enabled for the specified customer master key (CMK).</p> <p>You cannot enable automatic rotation of asymmetric CMKs, CMKs with imported key material, or CMKs in a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>. The key rotation status for these CMKs is always <code>false</code>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <ul> <li> <p>Disabled: The key rotation status does not change when you disable a CMK. However, while the CMK is disabled, AWS KMS does not rotate the backing key.</p> </li> <li> <p>Pending deletion: While a CMK is pending deletion, its key rotation status is <code>false</code> and AWS KMS does not rotate the backing key. If you cancel the deletion, the original key rotation status is restored.</p> </li> </ul> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    ///
+    /// See `Kms::get_key_rotation_status`.
+    pub fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
+        self.runtime
+            .block_on(self.client.get_key_rotation_status(input))
+    }
+
+    /// <p>Returns the items you need to import key material into a symmetric, customer managed customer master key (CMK). For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>This operation returns a public key and an import token. Use the public key to encrypt the symmetric key material. Store the import token to send with a subsequent <a>ImportKeyMaterial</a> request.</p> <p>You must specify the key ID of the symmetric CMK into which you will import key material. This CMK's <code>Origin</code> must be <code>EXTERNAL</code>. You must also specify the wrapping algorithm and type of wrapping key (public key) that you will use to encrypt the key material. You cannot perform this operation on an asymmetric CMK or on any CMK in a different AWS account.</p> <p>To import key material, you must use the public key and import token from the same response. These items are valid for 24 hours. The expiration date and time appear in the <code>GetParametersForImport</code> response. You cannot use an expired token in an <a>ImportKeyMaterial</a> request. If your key and token expire, send another <code>GetParametersForImport</code> request.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::get_parameters_for_import`.
+    pub fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
+        self.runtime
+            .block_on(self.client.get_parameters_for_import(input))
+    }
+
+    /// <p>Returns the public key of an asymmetric CMK. Unlike the private key of a asymmetric CMK, which never leaves AWS KMS unencrypted, callers with <code>kms:GetPublicKey</code> permission can download the public key of an asymmetric CMK. You can share the public key to allow others to encrypt messages and verify signatures outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>You do not need to download the public key. Instead, you can use the public key within AWS KMS by calling the <a>Encrypt</a>, <a>ReEncrypt</a>, or <a>Verify</a> operations with the identifier of an asymmetric CMK. When you use the public key within AWS KMS, you benefit from the authentication, authorization, and logging that are part of every AWS KMS operation. You also reduce of risk of encrypting data that cannot be decrypted. These features are not effective outside of AWS KMS. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/download-public-key.html#download-public-key-considerations">Special Considerations for Downloading Public Keys</a>.</p> <p>To help you use the public key safely outside of AWS KMS, <code>GetPublicKey</code> returns important information about the public key in the response, including:</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-CustomerMasterKeySpec">CustomerMasterKeySpec</a>: The type of key material in the public key, such as <code>RSA_4096</code> or <code>ECC_NIST_P521</code>.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-KeyUsage">KeyUsage</a>: Whether the key is used for encryption or signing.</p> </li> <li> <p> <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-EncryptionAlgorithms">EncryptionAlgorithms</a> or <a href="https://docs.aws.amazon.com/kms/latest/APIReference/API_GetPublicKey.html#KMS-GetPublicKey-response-SigningAlgorithms">SigningAlgorithms</a>: A list of the encryption algorithms or the signing algorithms for the key.</p> </li> </ul> <p>Although AWS KMS cannot enforce these restrictions on external operations, it is crucial that you use this information to prevent the public key from being used improperly. For example, you can prevent a public signing key from being used encrypt data, or prevent a public key from being used with an encryption algorithm that is not supported by AWS KMS. You can also avoid errors, such as using the wrong signing algorithm in a verification operation.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::get_public_key`.
+    pub fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
+        self.runtime.block_on(self.client.get_public_key(input))
+    }
+
+    /// <p>Imports key material into an existing symmetric AWS KMS customer master key (CMK) that was created without key material. After you successfully import key material into a CMK, you can <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html#reimport-key-material">reimport the same key material</a> into that CMK, but you cannot import different key material.</p> <p>You cannot perform this operation on an asymmetric CMK or on any CMK in a different AWS account. For more information about creating CMKs with no key material and then importing key material, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Before using this operation, call <a>GetParametersForImport</a>. Its response includes a public key and an import token. Use the public key to encrypt the key material. Then, submit the import token from the same <code>GetParametersForImport</code> response.</p> <p>When calling this operation, you must specify the following values:</p> <ul> <li> <p>The key ID or key ARN of a CMK with no key material. Its <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>To create a CMK with no key material, call <a>CreateKey</a> and set the value of its <code>Origin</code> parameter to <code>EXTERNAL</code>. To get the <code>Origin</code> of a CMK, call <a>DescribeKey</a>.)</p> </li> <li> <p>The encrypted key material. To get the public key to encrypt the key material, call <a>GetParametersForImport</a>.</p> </li> <li> <p>The import token that <a>GetParametersForImport</a> returned. You must use a public key and token from the same <code>GetParametersForImport</code> response.</p> </li> <li> <p>Whether the key material expires and if so, when. If you set an expiration date, AWS KMS deletes the key material from the CMK on the specified date, and the CMK becomes unusable. To use the CMK again, you must reimport the same key material. The only way to change an expiration date is by reimporting the same key material and specifying a new expiration date. </p> </li> </ul> <p>When this operation is successful, the key state of the CMK changes from <code>PendingImport</code> to <code>Enabled</code>, and you can use the CMK.</p> <p>If this operation fails, use the exception to help determine the problem. If the error is related to the key material, the import token, or wrapping key, use <a>GetParametersForImport</a> to get a new public key and import token for the CMK and repeat the import procedure. For help, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html#importing-keys-overview">How To Import Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::import_key_material`.
+    pub fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
+        self.runtime
+            .block_on(self.client.import_key_material(input))
+    }
+
+    /// <p>Gets a list of aliases in the caller's AWS account and region. You cannot list aliases in other accounts. For more information about aliases, see <a>CreateAlias</a>.</p> <p>By default, the ListAliases command returns all aliases in the account and region. To get only the aliases that point to a particular customer master key (CMK), use the <code>KeyId</code> parameter.</p> <p>The <code>ListAliases</code> response can include aliases that you created and associated with your customer managed CMKs, and aliases that AWS created and associated with AWS managed CMKs in your account. You can recognize AWS aliases because their names have the format <code>aws/&lt;service-name&gt;</code>, such as <code>aws/dynamodb</code>.</p> <p>The response might also include aliases that have no <code>TargetKeyId</code> field. These are predefined aliases that AWS has created but has not yet associated with a CMK. Aliases that AWS creates in your account, including predefined aliases, do not count against your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/limits.html#aliases-limit">AWS KMS aliases quota</a>.</p>
+    ///
+    /// See `Kms::list_aliases`.
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// <p><p>Gets a list of all grants for the specified customer master key (CMK).</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p> <note> <p>The <code>GranteePrincipal</code> field in the <code>ListGrants</code> response usually contains the user or role designated as the grantee principal in the grant. However, when the grantee principal in the grant is an AWS service, the <code>GranteePrincipal</code> field contains the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_principal.html#principal-services">service principal</a>, which might represent several different grantee principals.</p> </note></p>
+    ///
+    /// See `Kms::list_grants`.
+    pub fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
+        self.runtime.block_on(self.client.list_grants(input))
+    }
+
+    /// <p>Gets the names of the key policies that are attached to a customer master key (CMK). This operation is designed to get policy names that you can use in a <a>GetKeyPolicy</a> operation. However, the only valid policy name is <code>default</code>. You cannot perform this operation on a CMK in a different AWS account.</p>
+    ///
+    /// See `Kms::list_key_policies`.
+    pub fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
+        self.runtime.block_on(self.client.list_key_policies(input))
+    }
+
+    /// <p>Gets a list of all customer master keys (CMKs) in the caller's AWS account and Region.</p>
+    ///
+    /// See `Kms::list_keys`.
+    pub fn list_keys(
+        &self,
+        input: ListKeysRequest,
+    ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
+        self.runtime.block_on(self.client.list_keys(input))
+    }
+
+    /// <p>Returns a list of all tags for the specified customer master key (CMK).</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p>
+    ///
+    /// See `Kms::list_resource_tags`.
+    pub fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
+        self.runtime.block_on(self.client.list_resource_tags(input))
+    }
+
+    /// <p>Returns a list of all grants for which the grant's <code>RetiringPrincipal</code> matches the one specified.</p> <p>A typical use is to list all grants that you are able to retire. To retire a grant, use <a>RetireGrant</a>.</p>
+    ///
+    /// See `Kms::list_retirable_grants`.
+    pub fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
+        self.runtime
+            .block_on(self.client.list_retirable_grants(input))
+    }
+
+    /// <p>Attaches a key policy to the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about key policies, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">Key Policies</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::put_key_policy`.
+    pub fn put_key_policy(
+        &self,
+        input: PutKeyPolicyRequest,
+    ) -> Result<(), RusotoError<PutKeyPolicyError>> {
+        self.runtime.block_on(self.client.put_key_policy(input))
+    }
+
+    /// <p>Decrypts ciphertext and then reencrypts it entirely within AWS KMS. You can use this operation to change the customer master key (CMK) under which data is encrypted, such as when you <a href="https://docs.aws.amazon.com/kms/latest/developerguide/rotate-keys.html#rotate-keys-manually">manually rotate</a> a CMK or change the CMK that protects a ciphertext. You can also use it to reencrypt ciphertext under the same CMK, such as to change the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">encryption context</a> of a ciphertext.</p> <p>The <code>ReEncrypt</code> operation can decrypt ciphertext that was encrypted by using an AWS KMS CMK in an AWS KMS operation, such as <a>Encrypt</a> or <a>GenerateDataKey</a>. It can also decrypt ciphertext that was encrypted by using the public key of an <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symm-asymm-concepts.html#asymmetric-cmks">asymmetric CMK</a> outside of AWS KMS. However, it cannot decrypt ciphertext produced by other libraries, such as the <a href="https://docs.aws.amazon.com/encryption-sdk/latest/developer-guide/">AWS Encryption SDK</a> or <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingClientSideEncryption.html">Amazon S3 client-side encryption</a>. These libraries return a ciphertext format that is incompatible with AWS KMS.</p> <p>When you use the <code>ReEncrypt</code> operation, you need to provide information for the decrypt operation and the subsequent encrypt operation.</p> <ul> <li> <p>If your ciphertext was encrypted under an asymmetric CMK, you must identify the <i>source CMK</i>, that is, the CMK that encrypted the ciphertext. You must also supply the encryption algorithm that was used. This information is required to decrypt the data.</p> </li> <li> <p>It is optional, but you can specify a source CMK even when the ciphertext was encrypted under a symmetric CMK. This ensures that the ciphertext is decrypted only by using a particular CMK. If the CMK that you specify cannot decrypt the ciphertext, the <code>ReEncrypt</code> operation fails.</p> </li> <li> <p>To reencrypt the data, you must specify the <i>destination CMK</i>, that is, the CMK that re-encrypts the data after it is decrypted. You can select a symmetric or asymmetric CMK. If the destination CMK is an asymmetric CMK, you must also provide the encryption algorithm. The algorithm that you choose must be compatible with the CMK.</p> <important> <p>When you use an asymmetric CMK to encrypt or reencrypt data, be sure to record the CMK and encryption algorithm that you choose. You will be required to provide the same CMK and encryption algorithm when you decrypt the data. If the CMK and algorithm do not match the values used to encrypt the data, the decrypt operation fails.</p> <p>You are not required to supply the CMK ID and encryption algorithm when you decrypt with symmetric CMKs because AWS KMS stores this information in the ciphertext blob. AWS KMS cannot store metadata in ciphertext generated with asymmetric keys. The standard format for asymmetric key ciphertext does not include configurable fields.</p> </important> </li> </ul> <p>Unlike other AWS KMS API operations, <code>ReEncrypt</code> callers must have two permissions:</p> <ul> <li> <p> <code>kms:ReEncryptFrom</code> permission on the source CMK</p> </li> <li> <p> <code>kms:ReEncryptTo</code> permission on the destination CMK</p> </li> </ul> <p>To permit reencryption from or to a CMK, include the <code>"kms:ReEncrypt*"</code> permission in your <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html">key policy</a>. This permission is automatically included in the key policy when you use the console to create a CMK. But you must include it manually when you create a CMK programmatically or when you use the <a>PutKeyPolicy</a> operation to set a key policy.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::re_encrypt`.
+    pub fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
+        self.runtime.block_on(self.client.re_encrypt(input))
+    }
+
+    /// <p>Retires a grant. To clean up, you can retire a grant when you're done using it. You should revoke a grant when you intend to actively deny operations that depend on it. The following are permitted to call this API:</p> <ul> <li> <p>The AWS account (root user) under which the grant was created</p> </li> <li> <p>The <code>RetiringPrincipal</code>, if present in the grant</p> </li> <li> <p>The <code>GranteePrincipal</code>, if <code>RetireGrant</code> is an operation specified in the grant</p> </li> </ul> <p>You must identify the grant to retire by its grant token or by a combination of the grant ID and the Amazon Resource Name (ARN) of the customer master key (CMK). A grant token is a unique variable-length base64-encoded string. A grant ID is a 64 character unique identifier of a grant. The <a>CreateGrant</a> operation returns both.</p>
+    ///
+    /// See `Kms::retire_grant`.
+    pub fn retire_grant(
+        &self,
+        input: RetireGrantRequest,
+    ) -> Result<(), RusotoError<RetireGrantError>> {
+        self.runtime.block_on(self.client.retire_grant(input))
+    }
+
+    /// <p>Revokes the specified grant for the specified customer master key (CMK). You can revoke a grant to actively deny operations that depend on it.</p> <p>To perform this operation on a CMK in a different AWS account, specify the key ARN in the value of the <code>KeyId</code> parameter.</p>
+    ///
+    /// See `Kms::revoke_grant`.
+    pub fn revoke_grant(
+        &self,
+        input: RevokeGrantRequest,
+    ) -> Result<(), RusotoError<RevokeGrantError>> {
+        self.runtime.block_on(self.client.revoke_grant(input))
+    }
+
+    /// <p>Schedules the deletion of a customer master key (CMK). You may provide a waiting period, specified in days, before deletion occurs. If you do not provide a waiting period, the default period of 30 days is used. When this operation is successful, the key state of the CMK changes to <code>PendingDeletion</code>. Before the waiting period ends, you can use <a>CancelKeyDeletion</a> to cancel the deletion of the CMK. After the waiting period ends, AWS KMS deletes the CMK and all AWS KMS data associated with it, including all aliases that refer to it.</p> <important> <p>Deleting a CMK is a destructive and potentially dangerous operation. When a CMK is deleted, all data that was encrypted under the CMK is unrecoverable. To prevent the use of a CMK without deleting it, use <a>DisableKey</a>.</p> </important> <p>If you schedule deletion of a CMK from a <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a>, when the waiting period expires, <code>ScheduleKeyDeletion</code> deletes the CMK from AWS KMS. Then AWS KMS makes a best effort to delete the key material from the associated AWS CloudHSM cluster. However, you might need to manually <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-orphaned-key">delete the orphaned key material</a> from the cluster and its backups.</p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>For more information about scheduling a CMK for deletion, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/deleting-keys.html">Deleting Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::schedule_key_deletion`.
+    pub fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
+        self.runtime
+            .block_on(self.client.schedule_key_deletion(input))
+    }
+
+    /// <p>Creates a <a href="https://en.wikipedia.org/wiki/Digital_signature">digital signature</a> for a message or message digest by using the private key in an asymmetric CMK. To verify the signature, use the <a>Verify</a> operation, or use the public key in the same asymmetric CMK outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Digital signatures are generated and verified by using asymmetric key pair, such as an RSA or ECC pair that is represented by an asymmetric customer master key (CMK). The key owner (or an authorized user) uses their private key to sign a message. Anyone with the public key can verify that the message was signed with that particular private key and that the message hasn't changed since it was signed. </p> <p>To use the <code>Sign</code> operation, provide the following information:</p> <ul> <li> <p>Use the <code>KeyId</code> parameter to identify an asymmetric CMK with a <code>KeyUsage</code> value of <code>SIGN_VERIFY</code>. To get the <code>KeyUsage</code> value of a CMK, use the <a>DescribeKey</a> operation. The caller must have <code>kms:Sign</code> permission on the CMK.</p> </li> <li> <p>Use the <code>Message</code> parameter to specify the message or message digest to sign. You can submit messages of up to 4096 bytes. To sign a larger message, generate a hash digest of the message, and then provide the hash digest in the <code>Message</code> parameter. To indicate whether the message is a full message or a digest, use the <code>MessageType</code> parameter.</p> </li> <li> <p>Choose a signing algorithm that is compatible with the CMK. </p> </li> </ul> <important> <p>When signing a message, be sure to record the CMK and the signing algorithm. This information is required to verify the signature.</p> </important> <p>To verify the signature that this operation generates, use the <a>Verify</a> operation. Or use the <a>GetPublicKey</a> operation to download the public key and then use the public key to verify the signature outside of AWS KMS. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::sign`.
+    pub fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
+        self.runtime.block_on(self.client.sign(input))
+    }
+
+    /// <p>Adds or edits tags for a customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty (null) strings.</p> <p>You can only use a tag key once for each CMK. If you use the tag key again, AWS KMS replaces the current tag value with the specified value.</p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from the specified customer master key (CMK). You cannot perform this operation on a CMK in a different AWS account.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a>TagResource</a>.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Associates an existing AWS KMS alias with a different customer master key (CMK). Each alias is associated with only one CMK at a time, although a CMK can have multiple aliases. The alias and the CMK must be in the same AWS account and region. You cannot perform this operation on an alias in a different AWS account. </p> <p>The current and new CMK must be the same type (both symmetric or both asymmetric), and they must have the same key usage (<code>ENCRYPT_DECRYPT</code> or <code>SIGN_VERIFY</code>). This restriction prevents errors in code that uses aliases. If you must assign an alias to a different type of CMK, use <a>DeleteAlias</a> to delete the old alias and <a>CreateAlias</a> to create a new alias.</p> <p>You cannot use <code>UpdateAlias</code> to change an alias name. To change an alias name, use <a>DeleteAlias</a> to delete the old alias and <a>CreateAlias</a> to create a new alias.</p> <p>Because an alias is not a property of a CMK, you can create, update, and delete the aliases of a CMK without affecting the CMK. Also, aliases do not appear in the response from the <a>DescribeKey</a> operation. To get the aliases of all CMKs in the account, use the <a>ListAliases</a> operation. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::update_alias`.
+    pub fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<(), RusotoError<UpdateAliasError>> {
+        self.runtime.block_on(self.client.update_alias(input))
+    }
+
+    /// <p>Changes the properties of a custom key store. Use the <code>CustomKeyStoreId</code> parameter to identify the custom key store you want to edit. Use the remaining parameters to change the properties of the custom key store.</p> <p>You can only update a custom key store that is disconnected. To disconnect the custom key store, use <a>DisconnectCustomKeyStore</a>. To reconnect the custom key store after the update completes, use <a>ConnectCustomKeyStore</a>. To find the connection state of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p> <p>Use the parameters of <code>UpdateCustomKeyStore</code> to edit your keystore settings.</p> <ul> <li> <p>Use the <b>NewCustomKeyStoreName</b> parameter to change the friendly name of the custom key store to the value that you specify.</p> <p> </p> </li> <li> <p>Use the <b>KeyStorePassword</b> parameter tell AWS KMS the current password of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-store-concepts.html#concept-kmsuser"> <code>kmsuser</code> crypto user (CU)</a> in the associated AWS CloudHSM cluster. You can use this parameter to <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html#fix-keystore-password">fix connection failures</a> that occur when AWS KMS cannot log into the associated cluster because the <code>kmsuser</code> password has changed. This value does not change the password in the AWS CloudHSM cluster.</p> <p> </p> </li> <li> <p>Use the <b>CloudHsmClusterId</b> parameter to associate the custom key store with a different, but related, AWS CloudHSM cluster. You can use this parameter to repair a custom key store if its AWS CloudHSM cluster becomes corrupted or is deleted, or when you need to create or restore a cluster from a backup. </p> </li> </ul> <p>If the operation succeeds, it returns a JSON object with no properties.</p> <p>This operation is part of the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">Custom Key Store feature</a> feature in AWS KMS, which combines the convenience and extensive integration of AWS KMS with the isolation and control of a single-tenant key store.</p>
+    ///
+    /// See `Kms::update_custom_key_store`.
+    pub fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.update_custom_key_store(input))
+    }
+
+    /// <p>Updates the description of a customer master key (CMK). To see the description of a CMK, use <a>DescribeKey</a>. </p> <p>You cannot perform this operation on a CMK in a different AWS account.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::update_key_description`.
+    pub fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
+        self.runtime
+            .block_on(self.client.update_key_description(input))
+    }
+
+    /// <p>Verifies a digital signature that was generated by the <a>Sign</a> operation. </p> <p/> <p>Verification confirms that an authorized user signed the message with the specified CMK and signing algorithm, and the message hasn't changed since it was signed. If the signature is verified, the value of the <code>SignatureValid</code> field in the response is <code>True</code>. If the signature verification fails, the <code>Verify</code> operation fails with an <code>KMSInvalidSignatureException</code> exception.</p> <p>A digital signature is generated by using the private key in an asymmetric CMK. The signature is verified by using the public key in the same asymmetric CMK. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>To verify a digital signature, you can use the <code>Verify</code> operation. Specify the same asymmetric CMK, message, and signing algorithm that were used to produce the signature.</p> <p>You can also verify the digital signature by using the public key of the CMK outside of AWS KMS. Use the <a>GetPublicKey</a> operation to download the public key in the asymmetric CMK and then use the public key to verify the signature outside of AWS KMS. The advantage of using the <code>Verify</code> operation is that it is performed within AWS KMS. As a result, it's easy to call, the operation is performed within the FIPS boundary, it is logged in AWS CloudTrail, and you can use key policy and IAM policy to determine who is authorized to use the CMK to verify signatures.</p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+    ///
+    /// See `Kms::verify`.
+    pub fn verify(&self, input: VerifyRequest) -> Result<VerifyResponse, RusotoError<VerifyError>> {
+        self.runtime.block_on(self.client.verify(input))
+    }
+}
@@ -624,12 +624,16 @@ impl BatchGrantPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGrantPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchGrantPermissionsError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGrantPermissionsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchGrantPermissionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -662,12 +666,16 @@ impl BatchRevokePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchRevokePermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchRevokePermissionsError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchRevokePermissionsError::OperationTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchRevokePermissionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -704,16 +712,28 @@ impl DeregisterResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeregisterResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterResourceError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeregisterResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeregisterResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterResourceError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -752,16 +772,25 @@ impl DescribeResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeResourceError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DescribeResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -798,13 +827,22 @@ impl GetDataLakeSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataLakeSettingsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataLakeSettingsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataLakeSettingsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -844,22 +882,26 @@ impl GetEffectivePermissionsForPathError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEffectivePermissionsForPathError::EntityNotFound(err.msg),
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEffectivePermissionsForPathError::InternalService(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEffectivePermissionsForPathError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEffectivePermissionsForPathError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetEffectivePermissionsForPathError::OperationTimeout(err.msg),
                     )
                 }
@@ -904,15 +946,19 @@ impl GrantPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GrantPermissionsError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GrantPermissionsError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GrantPermissionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GrantPermissionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GrantPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GrantPermissionsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -948,13 +994,19 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPermissionsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListPermissionsError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListPermissionsError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -990,13 +1042,16 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListResourcesError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListResourcesError::InternalService(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListResourcesError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListResourcesError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1030,10 +1085,16 @@ impl PutDataLakeSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutDataLakeSettingsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDataLakeSettingsError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutDataLakeSettingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDataLakeSettingsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1070,16 +1131,25 @@ impl RegisterResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(RegisterResourceError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterResourceError::AlreadyExists(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, RegisterResourceError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(RegisterResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterResourceError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1116,15 +1186,22 @@ impl RevokePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokePermissionsError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokePermissionsError::ConcurrentModification(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RevokePermissionsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokePermissionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RevokePermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokePermissionsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1162,16 +1239,22 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::EntityNotFound(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateResourceError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::InternalService(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::InvalidInput(err.msg))
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::OperationTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1294,6 +1377,7 @@ impl LakeFormationClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1309,6 +1393,7 @@ impl LakeFormationClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> LakeFormationClient {
         LakeFormationClient { client, region }
     }
@@ -1322,6 +1407,9 @@ impl LakeFormation for LakeFormationClient {
         input: BatchGrantPermissionsRequest,
     ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchGrantPermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.BatchGrantPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1341,6 +1429,9 @@ impl LakeFormation for LakeFormationClient {
         input: BatchRevokePermissionsRequest,
     ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchRevokePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.BatchRevokePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1360,6 +1451,9 @@ impl LakeFormation for LakeFormationClient {
         input: DeregisterResourceRequest,
     ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.DeregisterResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1378,6 +1472,9 @@ impl LakeFormation for LakeFormationClient {
         input: DescribeResourceRequest,
     ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLakeFormation.DescribeResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1396,6 +1493,9 @@ impl LakeFormation for LakeFormationClient {
         input: GetDataLakeSettingsRequest,
     ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDataLakeSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLakeFormation.GetDataLakeSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1417,6 +1517,9 @@ impl LakeFormation for LakeFormationClient {
         RusotoError<GetEffectivePermissionsForPathError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEffectivePermissionsForPath");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSLakeFormation.GetEffectivePermissionsForPath",
@@ -1439,6 +1542,9 @@ impl LakeFormation for LakeFormationClient {
         input: GrantPermissionsRequest,
     ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GrantPermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.GrantPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1457,6 +1563,9 @@ impl LakeFormation for LakeFormationClient {
         input: ListPermissionsRequest,
     ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLakeFormation.ListPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1475,6 +1584,9 @@ impl LakeFormation for LakeFormationClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLakeFormation.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1493,6 +1605,9 @@ impl LakeFormation for LakeFormationClient {
         input: PutDataLakeSettingsRequest,
     ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutDataLakeSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.PutDataLakeSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1511,6 +1626,9 @@ impl LakeFormation for LakeFormationClient {
         input: RegisterResourceRequest,
     ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.RegisterResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1529,6 +1647,9 @@ impl LakeFormation for LakeFormationClient {
         input: RevokePermissionsRequest,
     ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RevokePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.RevokePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1547,6 +1668,9 @@ impl LakeFormation for LakeFormationClient {
         input: UpdateResourceRequest,
     ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLakeFormation.UpdateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1559,3 +1683,192 @@ impl LakeFormation for LakeFormationClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Lake Formation API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `LakeFormationClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LakeFormationBlockingClient {
+    client: LakeFormationClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LakeFormationBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LakeFormationBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        LakeFormationBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LakeFormationBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LakeFormationBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient {
+            client: LakeFormationClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Batch operation to grant permissions to the principal.</p>
+    ///
+    /// See `LakeFormation::batch_grant_permissions`.
+    pub fn batch_grant_permissions(
+        &self,
+        input: BatchGrantPermissionsRequest,
+    ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
+        self.runtime
+            .block_on(self.client.batch_grant_permissions(input))
+    }
+
+    /// <p>Batch operation to revoke permissions from the principal.</p>
+    ///
+    /// See `LakeFormation::batch_revoke_permissions`.
+    pub fn batch_revoke_permissions(
+        &self,
+        input: BatchRevokePermissionsRequest,
+    ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
+        self.runtime
+            .block_on(self.client.batch_revoke_permissions(input))
+    }
+
+    /// <p>Deregisters the resource as managed by the Data Catalog.</p> <p>When you deregister a path, Lake Formation removes the path from the inline policy attached to your service-linked role.</p>
+    ///
+    /// See `LakeFormation::deregister_resource`.
+    pub fn deregister_resource(
+        &self,
+        input: DeregisterResourceRequest,
+    ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
+        self.runtime
+            .block_on(self.client.deregister_resource(input))
+    }
+
+    /// <p>Retrieves the current data access role for the given resource registered in AWS Lake Formation.</p>
+    ///
+    /// See `LakeFormation::describe_resource`.
+    pub fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
+        self.runtime.block_on(self.client.describe_resource(input))
+    }
+
+    /// <p>Retrieves the list of the data lake administrators of a Lake Formation-managed data lake. </p>
+    ///
+    /// See `LakeFormation::get_data_lake_settings`.
+    pub fn get_data_lake_settings(
+        &self,
+        input: GetDataLakeSettingsRequest,
+    ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_data_lake_settings(input))
+    }
+
+    /// <p>Returns the Lake Formation permissions for a specified table or database resource located at a path in Amazon S3. <code>GetEffectivePermissionsForPath</code> will not return databases and tables if the catalog is encrypted.</p>
+    ///
+    /// See `LakeFormation::get_effective_permissions_for_path`.
+    pub fn get_effective_permissions_for_path(
+        &self,
+        input: GetEffectivePermissionsForPathRequest,
+    ) -> Result<
+        GetEffectivePermissionsForPathResponse,
+        RusotoError<GetEffectivePermissionsForPathError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_effective_permissions_for_path(input))
+    }
+
+    /// <p>Grants permissions to the principal to access metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3.</p> <p>For information about permissions, see <a href="https://docs-aws.amazon.com/lake-formation/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
+    ///
+    /// See `LakeFormation::grant_permissions`.
+    pub fn grant_permissions(
+        &self,
+        input: GrantPermissionsRequest,
+    ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
+        self.runtime.block_on(self.client.grant_permissions(input))
+    }
+
+    /// <p>Returns a list of the principal permissions on the resource, filtered by the permissions of the caller. For example, if you are granted an ALTER permission, you are able to see only the principal permissions for ALTER.</p> <p>This operation returns only those permissions that have been explicitly granted.</p> <p>For information about permissions, see <a href="https://docs-aws.amazon.com/lake-formation/latest/dg/security-data-access.html">Security and Access Control to Metadata and Data</a>.</p>
+    ///
+    /// See `LakeFormation::list_permissions`.
+    pub fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.runtime.block_on(self.client.list_permissions(input))
+    }
+
+    /// <p>Lists the resources registered to be managed by the Data Catalog.</p>
+    ///
+    /// See `LakeFormation::list_resources`.
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// <p>Sets the list of data lake administrators who have admin privileges on all resources managed by Lake Formation. For more information on admin privileges, see <a href="https://docs.aws.amazon.com/lake-formation/latest/dg/lake-formation-permissions.html">Granting Lake Formation Permissions</a>.</p> <p>This API replaces the current list of data lake admins with the new list being passed. To add an admin, fetch the current list and add the new admin to that list and pass that list in this API.</p>
+    ///
+    /// See `LakeFormation::put_data_lake_settings`.
+    pub fn put_data_lake_settings(
+        &self,
+        input: PutDataLakeSettingsRequest,
+    ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
+        self.runtime
+            .block_on(self.client.put_data_lake_settings(input))
+    }
+
+    /// <p>Registers the resource as managed by the Data Catalog.</p> <p>To add or update data, Lake Formation needs read/write access to the chosen Amazon S3 path. Choose a role that you know has permission to do this, or choose the AWSServiceRoleForLakeFormationDataAccess service-linked role. When you register the first Amazon S3 path, the service-linked role and a new inline policy are created on your behalf. Lake Formation adds the first path to the inline policy and attaches it to the service-linked role. When you register subsequent paths, Lake Formation adds the path to the existing policy.</p> <p>The following request registers a new location and gives AWS Lake Formation permission to use the service-linked role to access that location.</p> <p> <code>ResourceArn = arn:aws:s3:::my-bucket UseServiceLinkedRole = true</code> </p> <p>If <code>UseServiceLinkedRole</code> is not set to true, you must provide or set the <code>RoleArn</code>:</p> <p> <code>arn:aws:iam::12345:role/my-data-access-role</code> </p>
+    ///
+    /// See `LakeFormation::register_resource`.
+    pub fn register_resource(
+        &self,
+        input: RegisterResourceRequest,
+    ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
+        self.runtime.block_on(self.client.register_resource(input))
+    }
+
+    /// <p>Revokes permissions to the principal to access metadata in the Data Catalog and data organized in underlying data storage such as Amazon S3.</p>
+    ///
+    /// See `LakeFormation::revoke_permissions`.
+    pub fn revoke_permissions(
+        &self,
+        input: RevokePermissionsRequest,
+    ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
+        self.runtime.block_on(self.client.revoke_permissions(input))
+    }
+
+    /// <p>Updates the data access role used for vending access to the given (registered) resource in AWS Lake Formation. </p>
+    ///
+    /// See `LakeFormation::update_resource`.
+    pub fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
+        self.runtime.block_on(self.client.update_resource(input))
+    }
+}
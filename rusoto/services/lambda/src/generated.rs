@@ -1976,37 +1976,46 @@ impl AddLayerVersionPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddLayerVersionPermissionError::InvalidParameterValue(err.msg),
                     )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddLayerVersionPermissionError::PolicyLengthExceeded(err.msg),
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddLayerVersionPermissionError::PreconditionFailed(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddLayerVersionPermissionError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddLayerVersionPermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddLayerVersionPermissionError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddLayerVersionPermissionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2058,25 +2067,40 @@ impl AddPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AddPermissionError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddPermissionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(AddPermissionError::PolicyLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddPermissionError::PolicyLengthExceeded(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(AddPermissionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddPermissionError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AddPermissionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddPermissionError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddPermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddPermissionError::Service(err.msg))
+                    return RusotoError::service(&res, AddPermissionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddPermissionError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, AddPermissionError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2120,19 +2144,22 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::ResourceConflict(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAliasError::Service(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2174,27 +2201,34 @@ impl CreateEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEventSourceMappingError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSourceMappingError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSourceMappingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSourceMappingError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventSourceMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2240,24 +2274,37 @@ impl CreateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(CreateFunctionError::CodeStorageExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::CodeStorageExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateFunctionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateFunctionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateFunctionError::Service(err.msg))
+                    return RusotoError::service(&res, CreateFunctionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFunctionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2298,16 +2345,19 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::ResourceConflict(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAliasError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2348,27 +2398,34 @@ impl DeleteEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteEventSourceMappingError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventSourceMappingError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventSourceMappingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventSourceMappingError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventSourceMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2412,21 +2469,31 @@ impl DeleteFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteFunctionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteFunctionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteFunctionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2468,27 +2535,34 @@ impl DeleteFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteFunctionConcurrencyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionConcurrencyError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionConcurrencyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionConcurrencyError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionConcurrencyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2532,22 +2606,26 @@ impl DeleteFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFunctionEventInvokeConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteFunctionEventInvokeConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -2590,10 +2668,13 @@ impl DeleteLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLayerVersionError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteLayerVersionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLayerVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2634,27 +2715,32 @@ impl DeleteProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisionedConcurrencyConfigError::ResourceConflict(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteProvisionedConcurrencyConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisionedConcurrencyConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -2700,10 +2786,13 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Service(err.msg))
+                    return RusotoError::service(&res, GetAccountSettingsError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountSettingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountSettingsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2740,14 +2829,19 @@ impl GetAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetAliasError::ResourceNotFound(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, GetAliasError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(GetAliasError::Service(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetAliasError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2786,22 +2880,25 @@ impl GetEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSourceMappingError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSourceMappingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(&res, GetEventSourceMappingError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEventSourceMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2840,16 +2937,19 @@ impl GetFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetFunctionError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetFunctionError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionError::Service(err.msg))
+                    return RusotoError::service(&res, GetFunctionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetFunctionError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2888,22 +2988,28 @@ impl GetFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFunctionConcurrencyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionConcurrencyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionConcurrencyError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionConcurrencyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2942,22 +3048,28 @@ impl GetFunctionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFunctionConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionConfigurationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionConfigurationError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionConfigurationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3000,22 +3112,26 @@ impl GetFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFunctionEventInvokeConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFunctionEventInvokeConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -3060,18 +3176,25 @@ impl GetLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionError::Service(err.msg))
+                    return RusotoError::service(&res, GetLayerVersionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3110,22 +3233,25 @@ impl GetLayerVersionByArnError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionByArnError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionByArnError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::Service(err.msg))
+                    return RusotoError::service(&res, GetLayerVersionByArnError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionByArnError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3164,22 +3290,25 @@ impl GetLayerVersionPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionPolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, GetLayerVersionPolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLayerVersionPolicyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3218,16 +3347,19 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPolicyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetPolicyError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3270,29 +3402,34 @@ impl GetProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ProvisionedConcurrencyConfigNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetProvisionedConcurrencyConfigError::ProvisionedConcurrencyConfigNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetProvisionedConcurrencyConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetProvisionedConcurrencyConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -3386,78 +3523,83 @@ impl InvokeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EC2AccessDeniedException" => {
-                    return RusotoError::Service(InvokeError::EC2AccessDenied(err.msg))
+                    return RusotoError::service(&res, InvokeError::EC2AccessDenied(err.msg))
                 }
                 "EC2ThrottledException" => {
-                    return RusotoError::Service(InvokeError::EC2Throttled(err.msg))
+                    return RusotoError::service(&res, InvokeError::EC2Throttled(err.msg))
                 }
                 "EC2UnexpectedException" => {
-                    return RusotoError::Service(InvokeError::EC2Unexpected(err.msg))
+                    return RusotoError::service(&res, InvokeError::EC2Unexpected(err.msg))
                 }
-                "EFSIOException" => return RusotoError::Service(InvokeError::EFSIO(err.msg)),
+                "EFSIOException" => return RusotoError::service(&res, InvokeError::EFSIO(err.msg)),
                 "EFSMountConnectivityException" => {
-                    return RusotoError::Service(InvokeError::EFSMountConnectivity(err.msg))
+                    return RusotoError::service(&res, InvokeError::EFSMountConnectivity(err.msg))
                 }
                 "EFSMountFailureException" => {
-                    return RusotoError::Service(InvokeError::EFSMountFailure(err.msg))
+                    return RusotoError::service(&res, InvokeError::EFSMountFailure(err.msg))
                 }
                 "EFSMountTimeoutException" => {
-                    return RusotoError::Service(InvokeError::EFSMountTimeout(err.msg))
+                    return RusotoError::service(&res, InvokeError::EFSMountTimeout(err.msg))
                 }
                 "ENILimitReachedException" => {
-                    return RusotoError::Service(InvokeError::ENILimitReached(err.msg))
+                    return RusotoError::service(&res, InvokeError::ENILimitReached(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InvokeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(&res, InvokeError::InvalidParameterValue(err.msg))
                 }
                 "InvalidRequestContentException" => {
-                    return RusotoError::Service(InvokeError::InvalidRequestContent(err.msg))
+                    return RusotoError::service(&res, InvokeError::InvalidRequestContent(err.msg))
                 }
                 "InvalidRuntimeException" => {
-                    return RusotoError::Service(InvokeError::InvalidRuntime(err.msg))
+                    return RusotoError::service(&res, InvokeError::InvalidRuntime(err.msg))
                 }
                 "InvalidSecurityGroupIDException" => {
-                    return RusotoError::Service(InvokeError::InvalidSecurityGroupID(err.msg))
+                    return RusotoError::service(&res, InvokeError::InvalidSecurityGroupID(err.msg))
                 }
                 "InvalidSubnetIDException" => {
-                    return RusotoError::Service(InvokeError::InvalidSubnetID(err.msg))
+                    return RusotoError::service(&res, InvokeError::InvalidSubnetID(err.msg))
                 }
                 "InvalidZipFileException" => {
-                    return RusotoError::Service(InvokeError::InvalidZipFile(err.msg))
+                    return RusotoError::service(&res, InvokeError::InvalidZipFile(err.msg))
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(InvokeError::KMSAccessDenied(err.msg))
+                    return RusotoError::service(&res, InvokeError::KMSAccessDenied(err.msg))
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(InvokeError::KMSDisabled(err.msg))
+                    return RusotoError::service(&res, InvokeError::KMSDisabled(err.msg))
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(InvokeError::KMSInvalidState(err.msg))
+                    return RusotoError::service(&res, InvokeError::KMSInvalidState(err.msg))
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(InvokeError::KMSNotFound(err.msg))
+                    return RusotoError::service(&res, InvokeError::KMSNotFound(err.msg))
                 }
                 "RequestTooLargeException" => {
-                    return RusotoError::Service(InvokeError::RequestTooLarge(err.msg))
+                    return RusotoError::service(&res, InvokeError::RequestTooLarge(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, InvokeError::ResourceConflict(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, InvokeError::ResourceNotFound(err.msg))
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(InvokeError::ResourceNotReady(err.msg))
+                    return RusotoError::service(&res, InvokeError::ResourceNotReady(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, InvokeError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(InvokeError::Service(err.msg)),
                 "SubnetIPAddressLimitReachedException" => {
-                    return RusotoError::Service(InvokeError::SubnetIPAddressLimitReached(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeError::SubnetIPAddressLimitReached(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InvokeError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, InvokeError::TooManyRequests(err.msg))
                 }
                 "UnsupportedMediaTypeException" => {
-                    return RusotoError::Service(InvokeError::UnsupportedMediaType(err.msg))
+                    return RusotoError::service(&res, InvokeError::UnsupportedMediaType(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3520,19 +3662,22 @@ impl InvokeAsyncError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestContentException" => {
-                    return RusotoError::Service(InvokeAsyncError::InvalidRequestContent(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeAsyncError::InvalidRequestContent(err.msg),
+                    )
                 }
                 "InvalidRuntimeException" => {
-                    return RusotoError::Service(InvokeAsyncError::InvalidRuntime(err.msg))
+                    return RusotoError::service(&res, InvokeAsyncError::InvalidRuntime(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeAsyncError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, InvokeAsyncError::ResourceConflict(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeAsyncError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, InvokeAsyncError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(InvokeAsyncError::Service(err.msg))
+                    return RusotoError::service(&res, InvokeAsyncError::Service(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3572,16 +3717,19 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAliasesError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAliasesError::Service(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAliasesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3620,22 +3768,28 @@ impl ListEventSourceMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListEventSourceMappingsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSourceMappingsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSourceMappingsError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEventSourceMappingsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3678,22 +3832,26 @@ impl ListFunctionEventInvokeConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFunctionEventInvokeConfigsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFunctionEventInvokeConfigsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionEventInvokeConfigsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFunctionEventInvokeConfigsError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFunctionEventInvokeConfigsError::TooManyRequests(err.msg),
                     )
                 }
@@ -3738,13 +3896,16 @@ impl ListFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListFunctionsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFunctionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionsError::Service(err.msg))
+                    return RusotoError::service(&res, ListFunctionsError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFunctionsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListFunctionsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3782,18 +3943,25 @@ impl ListLayerVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLayerVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLayerVersionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLayerVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLayerVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLayerVersionsError::Service(err.msg))
+                    return RusotoError::service(&res, ListLayerVersionsError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListLayerVersionsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLayerVersionsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3830,13 +3998,16 @@ impl ListLayersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLayersError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLayersError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLayersError::Service(err.msg))
+                    return RusotoError::service(&res, ListLayersError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListLayersError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListLayersError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3876,22 +4047,26 @@ impl ListProvisionedConcurrencyConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedConcurrencyConfigsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedConcurrencyConfigsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListProvisionedConcurrencyConfigsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisionedConcurrencyConfigsError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedConcurrencyConfigsError::TooManyRequests(err.msg),
                     )
                 }
@@ -3938,14 +4113,19 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ResourceNotFound(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, ListTagsError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(ListTagsError::Service(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListTagsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3984,22 +4164,28 @@ impl ListVersionsByFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVersionsByFunctionError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVersionsByFunctionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVersionsByFunctionError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVersionsByFunctionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4040,25 +4226,31 @@ impl PublishLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(PublishLayerVersionError::CodeStorageExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PublishLayerVersionError::CodeStorageExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PublishLayerVersionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PublishLayerVersionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishLayerVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PublishLayerVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PublishLayerVersionError::Service(err.msg))
+                    return RusotoError::service(&res, PublishLayerVersionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PublishLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishLayerVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4104,27 +4296,43 @@ impl PublishVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(PublishVersionError::CodeStorageExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishVersionError::CodeStorageExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PublishVersionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PublishVersionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PublishVersionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishVersionError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(PublishVersionError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishVersionError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PublishVersionError::Service(err.msg))
+                    return RusotoError::service(&res, PublishVersionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PublishVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PublishVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4168,27 +4376,34 @@ impl PutFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutFunctionConcurrencyError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFunctionConcurrencyError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFunctionConcurrencyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutFunctionConcurrencyError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFunctionConcurrencyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4230,22 +4445,26 @@ impl PutFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutFunctionEventInvokeConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutFunctionEventInvokeConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -4294,27 +4513,32 @@ impl PutProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutProvisionedConcurrencyConfigError::ResourceConflict(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutProvisionedConcurrencyConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutProvisionedConcurrencyConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -4368,27 +4592,32 @@ impl RemoveLayerVersionPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveLayerVersionPermissionError::InvalidParameterValue(err.msg),
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveLayerVersionPermissionError::PreconditionFailed(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveLayerVersionPermissionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveLayerVersionPermissionError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveLayerVersionPermissionError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveLayerVersionPermissionError::TooManyRequests(err.msg),
                     )
                 }
@@ -4438,21 +4667,31 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RemovePermissionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(RemovePermissionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemovePermissionError::Service(err.msg))
+                    return RusotoError::service(&res, RemovePermissionError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemovePermissionError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemovePermissionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4494,19 +4733,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(TagResourceError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceConflict(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4548,19 +4790,28 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4604,22 +4855,28 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(UpdateAliasError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAliasError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::ResourceConflict(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::ResourceNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateAliasError::Service(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateAliasError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4664,32 +4921,40 @@ impl UpdateEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEventSourceMappingError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceConflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventSourceMappingError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventSourceMappingError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventSourceMappingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventSourceMappingError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEventSourceMappingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4738,31 +5003,43 @@ impl UpdateFunctionCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::CodeStorageExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionCodeError::CodeStorageExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionCodeError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionCodeError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionCodeError::ResourceConflict(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionCodeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::Service(err.msg))
+                    return RusotoError::service(&res, UpdateFunctionCodeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionCodeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4810,32 +5087,40 @@ impl UpdateFunctionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionConfigurationError::PreconditionFailed(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionConfigurationError::ResourceConflict(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionConfigurationError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionConfigurationError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFunctionConfigurationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionConfigurationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4882,22 +5167,26 @@ impl UpdateFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionEventInvokeConfigError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFunctionEventInvokeConfigError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateFunctionEventInvokeConfigError::TooManyRequests(err.msg),
                     )
                 }
@@ -5252,6 +5541,7 @@ impl LambdaClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5267,6 +5557,7 @@ impl LambdaClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> LambdaClient {
         LambdaClient { client, region }
     }
@@ -5288,6 +5579,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("AddLayerVersionPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5328,6 +5622,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("AddPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5368,6 +5665,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("CreateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5399,6 +5699,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("CreateEventSourceMapping");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5430,6 +5733,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("CreateFunction");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5465,6 +5771,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5495,6 +5804,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteEventSourceMapping");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5526,6 +5838,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteFunction");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5562,6 +5877,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteFunctionConcurrency");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5592,6 +5910,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteFunctionEventInvokeConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5631,6 +5952,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteLayerVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5661,6 +5985,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("DeleteProvisionedConcurrencyConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5693,6 +6020,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2016-08-19/account-settings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetAccountSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5725,6 +6055,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetAlias");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5756,6 +6089,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetEventSourceMapping");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5787,6 +6123,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetFunction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5824,6 +6163,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetFunctionConcurrency");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5855,6 +6197,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetFunctionConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5892,6 +6237,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetFunctionEventInvokeConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5930,6 +6278,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetLayerVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5958,6 +6309,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetLayerVersionByArn");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5995,6 +6349,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetLayerVersionPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6026,6 +6383,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6066,6 +6426,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("GetProvisionedConcurrencyConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6103,6 +6466,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("Invoke");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = if let Some(ref payload) = input.payload {
@@ -6154,6 +6520,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("InvokeAsync");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.invoke_args.to_owned());
@@ -6189,6 +6558,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6229,6 +6601,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListEventSourceMappings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6278,6 +6653,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListFunctionEventInvokeConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6315,6 +6693,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListFunctions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6361,6 +6742,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListLayerVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6401,6 +6785,9 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListLayers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6447,6 +6834,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListProvisionedConcurrencyConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6487,6 +6877,9 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6518,6 +6911,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation_name("ListVersionsByFunction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6558,6 +6954,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("PublishLayerVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6592,6 +6991,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("PublishVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6626,6 +7028,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("PutFunctionConcurrency");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6660,6 +7065,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("PutFunctionEventInvokeConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6703,6 +7111,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("PutProvisionedConcurrencyConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6745,6 +7156,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("RemoveLayerVersionPermission");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6782,6 +7196,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("RemovePermission");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6818,6 +7235,9 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6848,6 +7268,9 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6885,6 +7308,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("UpdateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6919,6 +7345,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("UpdateEventSourceMapping");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6953,6 +7382,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("UpdateFunctionCode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6987,6 +7419,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation_name("UpdateFunctionConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7021,6 +7456,9 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation_name("UpdateFunctionEventInvokeConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7051,3 +7489,583 @@ impl Lambda for LambdaClient {
         }
     }
 }
+
+/// A blocking client for the AWS Lambda API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `LambdaClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LambdaBlockingClient {
+    client: LambdaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LambdaBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LambdaBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        LambdaBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LambdaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LambdaBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> LambdaBlockingClient {
+        LambdaBlockingClient {
+            client: LambdaClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds permissions to the resource-based policy of a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Use this action to grant layer usage permission to other accounts. You can grant permission to a single account, all AWS accounts, or all accounts in an organization.</p> <p>To revoke permission, call <a>RemoveLayerVersionPermission</a> with the statement ID that you specified when you added it.</p>
+    ///
+    /// See `Lambda::add_layer_version_permission`.
+    pub fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> Result<AddLayerVersionPermissionResponse, RusotoError<AddLayerVersionPermissionError>>
+    {
+        self.runtime
+            .block_on(self.client.add_layer_version_permission(input))
+    }
+
+    /// <p>Grants an AWS service or another account permission to use a function. You can apply the policy at the function level, or specify a qualifier to restrict access to a single version or alias. If you use a qualifier, the invoker must use the full Amazon Resource Name (ARN) of that version or alias to invoke the function.</p> <p>To grant permission to another account, specify the account ID as the <code>Principal</code>. For AWS services, the principal is a domain-style identifier defined by the service, like <code>s3.amazonaws.com</code> or <code>sns.amazonaws.com</code>. For AWS services, you can also specify the ARN of the associated resource as the <code>SourceArn</code>. If you grant permission to a service principal without specifying the source, other accounts could potentially configure resources in their account to invoke your Lambda function.</p> <p>This action adds a statement to a resource-based permissions policy for the function. For more information about function policies, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">Lambda Function Policies</a>. </p>
+    ///
+    /// See `Lambda::add_permission`.
+    pub fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<AddPermissionResponse, RusotoError<AddPermissionError>> {
+        self.runtime.block_on(self.client.add_permission(input))
+    }
+
+    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a> for a Lambda function version. Use aliases to provide clients with a function identifier that you can update to invoke a different version.</p> <p>You can also map an alias to split invocation requests between two versions. Use the <code>RoutingConfig</code> parameter to specify a second version and the percentage of invocation requests that it receives.</p>
+    ///
+    /// See `Lambda::create_alias`.
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// <p><p>Creates a mapping between an event source and an AWS Lambda function. Lambda reads items from the event source and triggers the function.</p> <p>For details about each event source type, see the following topics.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-ddb.html">Using AWS Lambda with Amazon DynamoDB</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-kinesis.html">Using AWS Lambda with Amazon Kinesis</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/lambda/latest/dg/with-sqs.html">Using AWS Lambda with Amazon SQS</a> </p> </li> </ul> <p>The following error handling options are only available for stream sources (DynamoDB and Kinesis):</p> <ul> <li> <p> <code>BisectBatchOnFunctionError</code> - If the function returns an error, split the batch in two and retry.</p> </li> <li> <p> <code>DestinationConfig</code> - Send discarded records to an Amazon SQS queue or Amazon SNS topic.</p> </li> <li> <p> <code>MaximumRecordAgeInSeconds</code> - Discard records older than the specified age.</p> </li> <li> <p> <code>MaximumRetryAttempts</code> - Discard records after the specified number of retries.</p> </li> <li> <p> <code>ParallelizationFactor</code> - Process multiple batches from each shard concurrently.</p> </li> </ul></p>
+    ///
+    /// See `Lambda::create_event_source_mapping`.
+    pub fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<CreateEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.create_event_source_mapping(input))
+    }
+
+    /// <p>Creates a Lambda function. To create a function, you need a <a href="https://docs.aws.amazon.com/lambda/latest/dg/deployment-package-v2.html">deployment package</a> and an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-permission-model.html#lambda-intro-execution-role">execution role</a>. The deployment package contains your function code. The execution role grants the function permission to use AWS services, such as Amazon CloudWatch Logs for log streaming and AWS X-Ray for request tracing.</p> <p>When you create a function, Lambda provisions an instance of the function and its supporting resources. If your function connects to a VPC, this process can take a minute or so. During this time, you can't invoke or modify the function. The <code>State</code>, <code>StateReason</code>, and <code>StateReasonCode</code> fields in the response from <a>GetFunctionConfiguration</a> indicate when the function is ready to invoke. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/functions-states.html">Function States</a>.</p> <p>A function has an unpublished version, and can have published versions and aliases. The unpublished version changes when you update your function's code and configuration. A published version is a snapshot of your function code and configuration that can't be changed. An alias is a named resource that maps to a version, and can be changed to map to a different version. Use the <code>Publish</code> parameter to create version <code>1</code> of your function from its initial configuration.</p> <p>The other parameters let you configure version-specific and function-level settings. You can modify version-specific settings later with <a>UpdateFunctionConfiguration</a>. Function-level settings apply to both the unpublished and published versions of the function, and include tags (<a>TagResource</a>) and per-function concurrency limits (<a>PutFunctionConcurrency</a>).</p> <p>If another account or an AWS service invokes your function, use <a>AddPermission</a> to grant permission by creating a resource-based IAM policy. You can grant permissions at the function level, on a version, or on an alias.</p> <p>To invoke your function directly, use <a>Invoke</a>. To invoke your function in response to events in other AWS services, create an event source mapping (<a>CreateEventSourceMapping</a>), or configure a function trigger in the other service. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-invocation.html">Invoking Functions</a>.</p>
+    ///
+    /// See `Lambda::create_function`.
+    pub fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<CreateFunctionError>> {
+        self.runtime.block_on(self.client.create_function(input))
+    }
+
+    /// <p>Deletes a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    ///
+    /// See `Lambda::delete_alias`.
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// <p>Deletes an <a href="https://docs.aws.amazon.com/lambda/latest/dg/intro-invocation-modes.html">event source mapping</a>. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p> <p>When you delete an event source mapping, it enters a <code>Deleting</code> state and might not be completely deleted for several seconds.</p>
+    ///
+    /// See `Lambda::delete_event_source_mapping`.
+    pub fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<DeleteEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.delete_event_source_mapping(input))
+    }
+
+    /// <p>Deletes a Lambda function. To delete a specific function version, use the <code>Qualifier</code> parameter. Otherwise, all versions and aliases are deleted.</p> <p>To delete Lambda event source mappings that invoke a function, use <a>DeleteEventSourceMapping</a>. For AWS services and resources that invoke your function directly, delete the trigger in the service where you originally configured it.</p>
+    ///
+    /// See `Lambda::delete_function`.
+    pub fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionError>> {
+        self.runtime.block_on(self.client.delete_function(input))
+    }
+
+    /// <p>Removes a concurrent execution limit from a function.</p>
+    ///
+    /// See `Lambda::delete_function_concurrency`.
+    pub fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.delete_function_concurrency(input))
+    }
+
+    /// <p>Deletes the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    ///
+    /// See `Lambda::delete_function_event_invoke_config`.
+    pub fn delete_function_event_invoke_config(
+        &self,
+        input: DeleteFunctionEventInvokeConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_function_event_invoke_config(input))
+    }
+
+    /// <p>Deletes a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Deleted versions can no longer be viewed or added to functions. To avoid breaking functions, a copy of the version remains in Lambda until no functions refer to it.</p>
+    ///
+    /// See `Lambda::delete_layer_version`.
+    pub fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> Result<(), RusotoError<DeleteLayerVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_layer_version(input))
+    }
+
+    /// <p>Deletes the provisioned concurrency configuration for a function.</p>
+    ///
+    /// See `Lambda::delete_provisioned_concurrency_config`.
+    pub fn delete_provisioned_concurrency_config(
+        &self,
+        input: DeleteProvisionedConcurrencyConfigRequest,
+    ) -> Result<(), RusotoError<DeleteProvisionedConcurrencyConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_provisioned_concurrency_config(input))
+    }
+
+    /// <p>Retrieves details about your account's <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limits</a> and usage in an AWS Region.</p>
+    ///
+    /// See `Lambda::get_account_settings`.
+    pub fn get_account_settings(
+        &self,
+    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        self.runtime.block_on(self.client.get_account_settings())
+    }
+
+    /// <p>Returns details about a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    ///
+    /// See `Lambda::get_alias`.
+    pub fn get_alias(
+        &self,
+        input: GetAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<GetAliasError>> {
+        self.runtime.block_on(self.client.get_alias(input))
+    }
+
+    /// <p>Returns details about an event source mapping. You can get the identifier of a mapping from the output of <a>ListEventSourceMappings</a>.</p>
+    ///
+    /// See `Lambda::get_event_source_mapping`.
+    pub fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<GetEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.get_event_source_mapping(input))
+    }
+
+    /// <p>Returns information about the function or function version, with a link to download the deployment package that's valid for 10 minutes. If you specify a function version, only details that are specific to that version are returned.</p>
+    ///
+    /// See `Lambda::get_function`.
+    pub fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+        self.runtime.block_on(self.client.get_function(input))
+    }
+
+    /// <p>Returns details about the reserved concurrency configuration for a function. To set a concurrency limit for a function, use <a>PutFunctionConcurrency</a>.</p>
+    ///
+    /// See `Lambda::get_function_concurrency`.
+    pub fn get_function_concurrency(
+        &self,
+        input: GetFunctionConcurrencyRequest,
+    ) -> Result<GetFunctionConcurrencyResponse, RusotoError<GetFunctionConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.get_function_concurrency(input))
+    }
+
+    /// <p>Returns the version-specific settings of a Lambda function or version. The output includes only options that can vary between versions of a function. To modify these settings, use <a>UpdateFunctionConfiguration</a>.</p> <p>To get all of a function's details, including function-level settings, use <a>GetFunction</a>.</p>
+    ///
+    /// See `Lambda::get_function_configuration`.
+    pub fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_function_configuration(input))
+    }
+
+    /// <p>Retrieves the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    ///
+    /// See `Lambda::get_function_event_invoke_config`.
+    pub fn get_function_event_invoke_config(
+        &self,
+        input: GetFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<GetFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.get_function_event_invoke_config(input))
+    }
+
+    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
+    ///
+    /// See `Lambda::get_layer_version`.
+    pub fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionError>> {
+        self.runtime.block_on(self.client.get_layer_version(input))
+    }
+
+    /// <p>Returns information about a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>, with a link to download the layer archive that's valid for 10 minutes.</p>
+    ///
+    /// See `Lambda::get_layer_version_by_arn`.
+    pub fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionByArnError>> {
+        self.runtime
+            .block_on(self.client.get_layer_version_by_arn(input))
+    }
+
+    /// <p>Returns the permission policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
+    ///
+    /// See `Lambda::get_layer_version_policy`.
+    pub fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> Result<GetLayerVersionPolicyResponse, RusotoError<GetLayerVersionPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_layer_version_policy(input))
+    }
+
+    /// <p>Returns the <a href="https://docs.aws.amazon.com/lambda/latest/dg/access-control-resource-based.html">resource-based IAM policy</a> for a function, version, or alias.</p>
+    ///
+    /// See `Lambda::get_policy`.
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// <p>Retrieves the provisioned concurrency configuration for a function's alias or version.</p>
+    ///
+    /// See `Lambda::get_provisioned_concurrency_config`.
+    pub fn get_provisioned_concurrency_config(
+        &self,
+        input: GetProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        GetProvisionedConcurrencyConfigResponse,
+        RusotoError<GetProvisionedConcurrencyConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_provisioned_concurrency_config(input))
+    }
+
+    /// <p>Invokes a Lambda function. You can invoke a function synchronously (and wait for the response), or asynchronously. To invoke a function asynchronously, set <code>InvocationType</code> to <code>Event</code>.</p> <p>For <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-sync.html">synchronous invocation</a>, details about the function response, including errors, are included in the response body and headers. For either invocation type, you can find more information in the <a href="https://docs.aws.amazon.com/lambda/latest/dg/monitoring-functions.html">execution log</a> and <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-x-ray.html">trace</a>.</p> <p>When an error occurs, your function may be invoked multiple times. Retry behavior varies by error type, client, event source, and invocation type. For example, if you invoke a function asynchronously and it returns an error, Lambda executes the function up to two more times. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/retries-on-errors.html">Retry Behavior</a>.</p> <p>For <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html">asynchronous invocation</a>, Lambda adds events to a queue before sending them to your function. If your function does not have enough capacity to keep up with the queue, events may be lost. Occasionally, your function may receive the same event multiple times, even if no error occurs. To retain events that were not processed, configure your function with a <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html#dlq">dead-letter queue</a>.</p> <p>The status code in the API response doesn't reflect function errors. Error codes are reserved for errors that prevent your function from executing, such as permissions errors, <a href="https://docs.aws.amazon.com/lambda/latest/dg/limits.html">limit errors</a>, or issues with your function's code and configuration. For example, Lambda returns <code>TooManyRequestsException</code> if executing the function would cause you to exceed a concurrency limit at either the account level (<code>ConcurrentInvocationLimitExceeded</code>) or function level (<code>ReservedFunctionConcurrentInvocationLimitExceeded</code>).</p> <p>For functions with a long timeout, your client might be disconnected during synchronous invocation while it waits for a response. Configure your HTTP client, SDK, firewall, proxy, or operating system to allow for long connections with timeout or keep-alive settings.</p> <p>This operation requires permission for the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/list_awslambda.html">lambda:InvokeFunction</a> action.</p>
+    ///
+    /// See `Lambda::invoke`.
+    pub fn invoke(
+        &self,
+        input: InvocationRequest,
+    ) -> Result<InvocationResponse, RusotoError<InvokeError>> {
+        self.runtime.block_on(self.client.invoke(input))
+    }
+
+    /// <p><important> <p>For asynchronous function invocation, use <a>Invoke</a>.</p> </important> <p>Invokes a function asynchronously.</p></p>
+    ///
+    /// See `Lambda::invoke_async`.
+    pub fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> Result<InvokeAsyncResponse, RusotoError<InvokeAsyncError>> {
+        self.runtime.block_on(self.client.invoke_async(input))
+    }
+
+    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">aliases</a> for a Lambda function.</p>
+    ///
+    /// See `Lambda::list_aliases`.
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// <p>Lists event source mappings. Specify an <code>EventSourceArn</code> to only show event source mappings for a single event source.</p>
+    ///
+    /// See `Lambda::list_event_source_mappings`.
+    pub fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> Result<ListEventSourceMappingsResponse, RusotoError<ListEventSourceMappingsError>> {
+        self.runtime
+            .block_on(self.client.list_event_source_mappings(input))
+    }
+
+    /// <p>Retrieves a list of configurations for asynchronous invocation for a function.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    ///
+    /// See `Lambda::list_function_event_invoke_configs`.
+    pub fn list_function_event_invoke_configs(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> Result<
+        ListFunctionEventInvokeConfigsResponse,
+        RusotoError<ListFunctionEventInvokeConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_function_event_invoke_configs(input))
+    }
+
+    /// <p>Returns a list of Lambda functions, with the version-specific configuration of each. Lambda returns up to 50 functions per call.</p> <p>Set <code>FunctionVersion</code> to <code>ALL</code> to include all published versions of each function in addition to the unpublished version. To get more information about a function or version, use <a>GetFunction</a>.</p>
+    ///
+    /// See `Lambda::list_functions`.
+    pub fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+        self.runtime.block_on(self.client.list_functions(input))
+    }
+
+    /// <p>Lists the versions of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. Versions that have been deleted aren't listed. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only versions that indicate that they're compatible with that runtime.</p>
+    ///
+    /// See `Lambda::list_layer_versions`.
+    pub fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> Result<ListLayerVersionsResponse, RusotoError<ListLayerVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_layer_versions(input))
+    }
+
+    /// <p>Lists <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layers</a> and shows information about the latest version of each. Specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime identifier</a> to list only layers that indicate that they're compatible with that runtime.</p>
+    ///
+    /// See `Lambda::list_layers`.
+    pub fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> Result<ListLayersResponse, RusotoError<ListLayersError>> {
+        self.runtime.block_on(self.client.list_layers(input))
+    }
+
+    /// <p>Retrieves a list of provisioned concurrency configurations for a function.</p>
+    ///
+    /// See `Lambda::list_provisioned_concurrency_configs`.
+    pub fn list_provisioned_concurrency_configs(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> Result<
+        ListProvisionedConcurrencyConfigsResponse,
+        RusotoError<ListProvisionedConcurrencyConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_provisioned_concurrency_configs(input))
+    }
+
+    /// <p>Returns a function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a>. You can also view tags with <a>GetFunction</a>.</p>
+    ///
+    /// See `Lambda::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Returns a list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">versions</a>, with the version-specific configuration of each. Lambda returns up to 50 versions per call.</p>
+    ///
+    /// See `Lambda::list_versions_by_function`.
+    pub fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> Result<ListVersionsByFunctionResponse, RusotoError<ListVersionsByFunctionError>> {
+        self.runtime
+            .block_on(self.client.list_versions_by_function(input))
+    }
+
+    /// <p>Creates an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a> from a ZIP archive. Each time you call <code>PublishLayerVersion</code> with the same layer name, a new version is created.</p> <p>Add layers to your function with <a>CreateFunction</a> or <a>UpdateFunctionConfiguration</a>.</p>
+    ///
+    /// See `Lambda::publish_layer_version`.
+    pub fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> Result<PublishLayerVersionResponse, RusotoError<PublishLayerVersionError>> {
+        self.runtime
+            .block_on(self.client.publish_layer_version(input))
+    }
+
+    /// <p>Creates a <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">version</a> from the current code and configuration of a function. Use versions to create a snapshot of your function code and configuration that doesn't change.</p> <p>AWS Lambda doesn't publish a version if the function's configuration and code haven't changed since the last version. Use <a>UpdateFunctionCode</a> or <a>UpdateFunctionConfiguration</a> to update the function before publishing a version.</p> <p>Clients can invoke versions directly or with an alias. To create an alias, use <a>CreateAlias</a>.</p>
+    ///
+    /// See `Lambda::publish_version`.
+    pub fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<PublishVersionError>> {
+        self.runtime.block_on(self.client.publish_version(input))
+    }
+
+    /// <p>Sets the maximum number of simultaneous executions for a function, and reserves capacity for that concurrency level.</p> <p>Concurrency settings apply to the function as a whole, including all published versions and the unpublished version. Reserving concurrency both ensures that your function has capacity to process the specified number of events simultaneously, and prevents it from scaling beyond that level. Use <a>GetFunction</a> to see the current setting for a function.</p> <p>Use <a>GetAccountSettings</a> to see your Regional concurrency limit. You can reserve concurrency for as many functions as you like, as long as you leave at least 100 simultaneous executions unreserved for functions that aren't configured with a per-function limit. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/concurrent-executions.html">Managing Concurrency</a>.</p>
+    ///
+    /// See `Lambda::put_function_concurrency`.
+    pub fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> Result<Concurrency, RusotoError<PutFunctionConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.put_function_concurrency(input))
+    }
+
+    /// <p>Configures options for <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html">asynchronous invocation</a> on a function, version, or alias. If a configuration already exists for a function, version, or alias, this operation overwrites it. If you exclude any settings, they are removed. To set one option without affecting existing settings for other options, use <a>UpdateFunctionEventInvokeConfig</a>.</p> <p>By default, Lambda retries an asynchronous invocation twice if the function returns an error. It retains events in a queue for up to six hours. When an event fails all processing attempts or stays in the asynchronous invocation queue for too long, Lambda discards it. To retain discarded events, configure a dead-letter queue with <a>UpdateFunctionConfiguration</a>.</p> <p>To send an invocation record to a queue, topic, function, or event bus, specify a <a href="https://docs.aws.amazon.com/lambda/latest/dg/invocation-async.html#invocation-async-destinations">destination</a>. You can configure separate destinations for successful invocations (on-success) and events that fail all processing attempts (on-failure). You can configure destinations in addition to or instead of a dead-letter queue.</p>
+    ///
+    /// See `Lambda::put_function_event_invoke_config`.
+    pub fn put_function_event_invoke_config(
+        &self,
+        input: PutFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<PutFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.put_function_event_invoke_config(input))
+    }
+
+    /// <p>Adds a provisioned concurrency configuration to a function's alias or version.</p>
+    ///
+    /// See `Lambda::put_provisioned_concurrency_config`.
+    pub fn put_provisioned_concurrency_config(
+        &self,
+        input: PutProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        PutProvisionedConcurrencyConfigResponse,
+        RusotoError<PutProvisionedConcurrencyConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_provisioned_concurrency_config(input))
+    }
+
+    /// <p>Removes a statement from the permissions policy for a version of an <a href="https://docs.aws.amazon.com/lambda/latest/dg/configuration-layers.html">AWS Lambda layer</a>. For more information, see <a>AddLayerVersionPermission</a>.</p>
+    ///
+    /// See `Lambda::remove_layer_version_permission`.
+    pub fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> Result<(), RusotoError<RemoveLayerVersionPermissionError>> {
+        self.runtime
+            .block_on(self.client.remove_layer_version_permission(input))
+    }
+
+    /// <p>Revokes function-use permission from an AWS service or another account. You can get the ID of the statement from the output of <a>GetPolicy</a>.</p>
+    ///
+    /// See `Lambda::remove_permission`.
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// <p>Adds <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to a function.</p>
+    ///
+    /// See `Lambda::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> from a function.</p>
+    ///
+    /// See `Lambda::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the configuration of a Lambda function <a href="https://docs.aws.amazon.com/lambda/latest/dg/versioning-aliases.html">alias</a>.</p>
+    ///
+    /// See `Lambda::update_alias`.
+    pub fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<UpdateAliasError>> {
+        self.runtime.block_on(self.client.update_alias(input))
+    }
+
+    /// <p><p>Updates an event source mapping. You can change the function that AWS Lambda invokes, or pause invocation and resume later from the same location.</p> <p>The following error handling options are only available for stream sources (DynamoDB and Kinesis):</p> <ul> <li> <p> <code>BisectBatchOnFunctionError</code> - If the function returns an error, split the batch in two and retry.</p> </li> <li> <p> <code>DestinationConfig</code> - Send discarded records to an Amazon SQS queue or Amazon SNS topic.</p> </li> <li> <p> <code>MaximumRecordAgeInSeconds</code> - Discard records older than the specified age.</p> </li> <li> <p> <code>MaximumRetryAttempts</code> - Discard records after the specified number of retries.</p> </li> <li> <p> <code>ParallelizationFactor</code> - Process multiple batches from each shard concurrently.</p> </li> </ul></p>
+    ///
+    /// See `Lambda::update_event_source_mapping`.
+    pub fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<UpdateEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.update_event_source_mapping(input))
+    }
+
+    /// <p>Updates a Lambda function's code.</p> <p>The function's code is locked when you publish a version. You can't modify the code of a published version, only the unpublished version.</p>
+    ///
+    /// See `Lambda::update_function_code`.
+    pub fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionCodeError>> {
+        self.runtime
+            .block_on(self.client.update_function_code(input))
+    }
+
+    /// <p>Modify the version-specific settings of a Lambda function.</p> <p>When you update a function, Lambda provisions an instance of the function and its supporting resources. If your function connects to a VPC, this process can take a minute. During this time, you can't modify the function, but you can still invoke it. The <code>LastUpdateStatus</code>, <code>LastUpdateStatusReason</code>, and <code>LastUpdateStatusReasonCode</code> fields in the response from <a>GetFunctionConfiguration</a> indicate when the update is complete and the function is processing events with the new configuration. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/functions-states.html">Function States</a>.</p> <p>These settings can vary between versions of a function and are locked when you publish a version. You can't modify the configuration of a published version, only the unpublished version.</p> <p>To configure function concurrency, use <a>PutFunctionConcurrency</a>. To grant invoke permissions to an account or AWS service, use <a>AddPermission</a>.</p>
+    ///
+    /// See `Lambda::update_function_configuration`.
+    pub fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionConfigurationError>> {
+        self.runtime
+            .block_on(self.client.update_function_configuration(input))
+    }
+
+    /// <p>Updates the configuration for asynchronous invocation for a function, version, or alias.</p> <p>To configure options for asynchronous invocation, use <a>PutFunctionEventInvokeConfig</a>.</p>
+    ///
+    /// See `Lambda::update_function_event_invoke_config`.
+    pub fn update_function_event_invoke_config(
+        &self,
+        input: UpdateFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<UpdateFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.update_function_event_invoke_config(input))
+    }
+}
@@ -2167,22 +2167,31 @@ impl CreateBotVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBotVersionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateBotVersionError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBotVersionError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateBotVersionError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBotVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBotVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBotVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBotVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBotVersionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateBotVersionError::NotFound(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateBotVersionError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBotVersionError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2227,24 +2236,34 @@ impl CreateIntentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntentVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntentVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntentVersionError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateIntentVersionError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIntentVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntentVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIntentVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntentVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntentVersionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateIntentVersionError::NotFound(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateIntentVersionError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIntentVersionError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2289,26 +2308,40 @@ impl CreateSlotTypeVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSlotTypeVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSlotTypeVersionError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSlotTypeVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSlotTypeVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSlotTypeVersionError::NotFound(err.msg),
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSlotTypeVersionError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2353,22 +2386,22 @@ impl DeleteBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBotError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteBotError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteBotError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteBotError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBotError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteBotError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2413,22 +2446,25 @@ impl DeleteBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBotAliasError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteBotAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteBotAliasError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBotAliasError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotAliasError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteBotAliasError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2473,29 +2509,34 @@ impl DeleteBotChannelAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotChannelAssociationError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotChannelAssociationError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotChannelAssociationError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotChannelAssociationError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotChannelAssociationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2539,22 +2580,31 @@ impl DeleteBotVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotVersionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBotVersionError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotVersionError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteBotVersionError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotVersionError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBotVersionError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBotVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2599,22 +2649,22 @@ impl DeleteIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteIntentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntentError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteIntentError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteIntentError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteIntentError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntentError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteIntentError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteIntentError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteIntentError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2659,22 +2709,34 @@ impl DeleteIntentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntentVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteIntentVersionError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntentVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntentVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteIntentVersionError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIntentVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2719,22 +2781,25 @@ impl DeleteSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteSlotTypeError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteSlotTypeError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteSlotTypeError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSlotTypeError::NotFound(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteSlotTypeError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2779,24 +2844,40 @@ impl DeleteSlotTypeVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeVersionError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeVersionError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeVersionError::NotFound(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSlotTypeVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2837,16 +2918,22 @@ impl DeleteUtterancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUtterancesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteUtterancesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUtterancesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUtterancesError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteUtterancesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUtterancesError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUtterancesError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUtterancesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2885,15 +2972,17 @@ impl GetBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBotError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetBotError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetBotError::LimitExceeded(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetBotError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -2931,16 +3020,16 @@ impl GetBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBotAliasError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetBotAliasError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetBotAliasError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotAliasError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBotAliasError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2977,13 +3066,13 @@ impl GetBotAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotAliasesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBotAliasesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetBotAliasesError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotAliasesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetBotAliasesError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3021,20 +3110,28 @@ impl GetBotChannelAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3071,19 +3168,22 @@ impl GetBotChannelAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationsError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBotChannelAssociationsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3121,16 +3221,19 @@ impl GetBotVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBotVersionsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBotVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetBotVersionsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotVersionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBotVersionsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3169,16 +3272,16 @@ impl GetBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBotsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetBotsError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetBotsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBotsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3217,16 +3320,22 @@ impl GetBuiltinIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBuiltinIntentError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinIntentError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinIntentError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBuiltinIntentError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3263,13 +3372,19 @@ impl GetBuiltinIntentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetBuiltinIntentsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinIntentsError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinIntentsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3305,13 +3420,22 @@ impl GetBuiltinSlotTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinSlotTypesError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinSlotTypesError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBuiltinSlotTypesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3349,16 +3473,16 @@ impl GetExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetExportError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetExportError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetExportError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetExportError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetExportError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetExportError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetExportError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3397,16 +3521,16 @@ impl GetImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetImportError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetImportError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetImportError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetImportError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetImportError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetImportError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetImportError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetImportError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3445,16 +3569,16 @@ impl GetIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetIntentError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetIntentError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetIntentError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetIntentError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3493,16 +3617,22 @@ impl GetIntentVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetIntentVersionsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntentVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetIntentVersionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentVersionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetIntentVersionsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3541,16 +3671,16 @@ impl GetIntentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetIntentsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetIntentsError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetIntentsError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetIntentsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3589,16 +3719,16 @@ impl GetSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetSlotTypeError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetSlotTypeError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetSlotTypeError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypeError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSlotTypeError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3637,16 +3767,25 @@ impl GetSlotTypeVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSlotTypeVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSlotTypeVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSlotTypeVersionsError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSlotTypeVersionsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3685,16 +3824,16 @@ impl GetSlotTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetSlotTypesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetSlotTypesError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypesError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetSlotTypesError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSlotTypesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3731,13 +3870,19 @@ impl GetUtterancesViewError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUtterancesViewError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetUtterancesViewError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetUtterancesViewError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUtterancesViewError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUtterancesViewError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetUtterancesViewError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3775,16 +3920,25 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3825,17 +3979,19 @@ impl PutBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutBotError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutBotError::BadRequest(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::service(&res, PutBotError::Conflict(err.msg))
                 }
-                "ConflictException" => return RusotoError::Service(PutBotError::Conflict(err.msg)),
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutBotError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PutBotError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutBotError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutBotError::LimitExceeded(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutBotError::PreconditionFailed(err.msg))
+                    return RusotoError::service(&res, PutBotError::PreconditionFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3877,19 +4033,22 @@ impl PutBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutBotAliasError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutBotAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutBotAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PutBotAliasError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutBotAliasError::LimitExceeded(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutBotAliasError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutBotAliasError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3931,19 +4090,19 @@ impl PutIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutIntentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntentError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutIntentError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutIntentError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PutIntentError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutIntentError::LimitExceeded(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutIntentError::PreconditionFailed(err.msg))
+                    return RusotoError::service(&res, PutIntentError::PreconditionFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3985,19 +4144,22 @@ impl PutSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutSlotTypeError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutSlotTypeError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutSlotTypeError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PutSlotTypeError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutSlotTypeError::LimitExceeded(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutSlotTypeError::PreconditionFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSlotTypeError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4035,13 +4197,13 @@ impl StartImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartImportError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartImportError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartImportError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, StartImportError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartImportError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartImportError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4081,19 +4243,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4135,19 +4297,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4421,6 +4583,7 @@ impl LexModelsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4436,6 +4599,7 @@ impl LexModelsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> LexModelsClient {
         LexModelsClient { client, region }
     }
@@ -4452,6 +4616,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("CreateBotVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4484,6 +4651,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("CreateIntentVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4516,6 +4686,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("CreateSlotTypeVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4545,6 +4718,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteBot");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4578,6 +4754,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteBotAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4612,6 +4791,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteBotChannelAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4645,6 +4827,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteBotVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4674,6 +4859,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteIntent");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4707,6 +4895,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteIntentVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4736,6 +4927,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteSlotType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4769,6 +4963,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteSlotTypeVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4802,6 +4999,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteUtterances");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4835,6 +5035,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4869,6 +5072,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBotAlias");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4899,6 +5105,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBotAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4946,6 +5155,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBotChannelAssociation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4981,6 +5193,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBotChannelAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5023,6 +5238,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBotVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5062,6 +5280,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/bots/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5104,6 +5325,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBuiltinIntent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5134,6 +5358,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBuiltinIntents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5179,6 +5406,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetBuiltinSlotTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5224,6 +5454,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/exports/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetExport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5261,6 +5494,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetImport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5295,6 +5531,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetIntent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5325,6 +5564,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetIntentVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5364,6 +5606,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetIntents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5410,6 +5655,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetSlotType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5440,6 +5688,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetSlotTypeVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5479,6 +5730,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetSlotTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5521,6 +5775,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetUtterancesView");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5559,6 +5816,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5589,6 +5849,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation_name("PutBot");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5625,6 +5888,9 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation_name("PutBotAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5657,6 +5923,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation_name("PutIntent");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5689,6 +5958,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation_name("PutSlotType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5721,6 +5993,9 @@ impl LexModels for LexModelsClient {
         let request_uri = "/imports/";
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("StartImport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5753,6 +6028,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5785,6 +6063,9 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5812,3 +6093,454 @@ impl LexModels for LexModelsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Lex Model Building Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `LexModelsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LexModelsBlockingClient {
+    client: LexModelsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LexModelsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LexModelsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        LexModelsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LexModelsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LexModelsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> LexModelsBlockingClient {
+        LexModelsBlockingClient {
+            client: LexModelsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new version of the bot based on the <code>$LATEST</code> version. If the <code>$LATEST</code> version of this resource hasn't changed since you created the last version, Amazon Lex doesn't create a new version. It returns the last created version.</p> <note> <p>You can update only the <code>$LATEST</code> version of the bot. You can't update the numbered versions that you create with the <code>CreateBotVersion</code> operation.</p> </note> <p> When you create the first version of a bot, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p> This operation requires permission for the <code>lex:CreateBotVersion</code> action. </p>
+    ///
+    /// See `LexModels::create_bot_version`.
+    pub fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> Result<CreateBotVersionResponse, RusotoError<CreateBotVersionError>> {
+        self.runtime.block_on(self.client.create_bot_version(input))
+    }
+
+    /// <p>Creates a new version of an intent based on the <code>$LATEST</code> version of the intent. If the <code>$LATEST</code> version of this intent hasn't changed since you last updated it, Amazon Lex doesn't create a new version. It returns the last version you created.</p> <note> <p>You can update only the <code>$LATEST</code> version of the intent. You can't update the numbered versions that you create with the <code>CreateIntentVersion</code> operation.</p> </note> <p> When you create a version of an intent, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions to perform the <code>lex:CreateIntentVersion</code> action. </p>
+    ///
+    /// See `LexModels::create_intent_version`.
+    pub fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> Result<CreateIntentVersionResponse, RusotoError<CreateIntentVersionError>> {
+        self.runtime
+            .block_on(self.client.create_intent_version(input))
+    }
+
+    /// <p>Creates a new version of a slot type based on the <code>$LATEST</code> version of the specified slot type. If the <code>$LATEST</code> version of this resource has not changed since the last version that you created, Amazon Lex doesn't create a new version. It returns the last version that you created. </p> <note> <p>You can update only the <code>$LATEST</code> version of a slot type. You can't update the numbered versions that you create with the <code>CreateSlotTypeVersion</code> operation.</p> </note> <p>When you create a version of a slot type, Amazon Lex sets the version to 1. Subsequent versions increment by 1. For more information, see <a>versioning-intro</a>. </p> <p>This operation requires permissions for the <code>lex:CreateSlotTypeVersion</code> action.</p>
+    ///
+    /// See `LexModels::create_slot_type_version`.
+    pub fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> Result<CreateSlotTypeVersionResponse, RusotoError<CreateSlotTypeVersionError>> {
+        self.runtime
+            .block_on(self.client.create_slot_type_version(input))
+    }
+
+    /// <p>Deletes all versions of the bot, including the <code>$LATEST</code> version. To delete a specific version of the bot, use the <a>DeleteBotVersion</a> operation. The <code>DeleteBot</code> operation doesn't immediately remove the bot schema. Instead, it is marked for deletion and removed later.</p> <p>Amazon Lex stores utterances indefinitely for improving the ability of your bot to respond to user inputs. These utterances are not removed when the bot is deleted. To remove the utterances, use the <a>DeleteUtterances</a> operation.</p> <p>If a bot has an alias, you can't delete it. Instead, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the alias that refers to the bot. To remove the reference to the bot, delete the alias. If you get the same exception again, delete the referring alias until the <code>DeleteBot</code> operation is successful.</p> <p>This operation requires permissions for the <code>lex:DeleteBot</code> action.</p>
+    ///
+    /// See `LexModels::delete_bot`.
+    pub fn delete_bot(&self, input: DeleteBotRequest) -> Result<(), RusotoError<DeleteBotError>> {
+        self.runtime.block_on(self.client.delete_bot(input))
+    }
+
+    /// <p>Deletes an alias for the specified bot. </p> <p>You can't delete an alias that is used in the association between a bot and a messaging channel. If an alias is used in a channel association, the <code>DeleteBot</code> operation returns a <code>ResourceInUseException</code> exception that includes a reference to the channel association that refers to the bot. You can remove the reference to the alias by deleting the channel association. If you get the same exception again, delete the referring association until the <code>DeleteBotAlias</code> operation is successful.</p>
+    ///
+    /// See `LexModels::delete_bot_alias`.
+    pub fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> Result<(), RusotoError<DeleteBotAliasError>> {
+        self.runtime.block_on(self.client.delete_bot_alias(input))
+    }
+
+    /// <p>Deletes the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permission for the <code>lex:DeleteBotChannelAssociation</code> action.</p>
+    ///
+    /// See `LexModels::delete_bot_channel_association`.
+    pub fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> Result<(), RusotoError<DeleteBotChannelAssociationError>> {
+        self.runtime
+            .block_on(self.client.delete_bot_channel_association(input))
+    }
+
+    /// <p>Deletes a specific version of a bot. To delete all versions of a bot, use the <a>DeleteBot</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteBotVersion</code> action.</p>
+    ///
+    /// See `LexModels::delete_bot_version`.
+    pub fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> Result<(), RusotoError<DeleteBotVersionError>> {
+        self.runtime.block_on(self.client.delete_bot_version(input))
+    }
+
+    /// <p>Deletes all versions of the intent, including the <code>$LATEST</code> version. To delete a specific version of the intent, use the <a>DeleteIntentVersion</a> operation.</p> <p> You can delete a version of an intent only if it is not referenced. To delete an intent that is referred to in one or more bots (see <a>how-it-works</a>), you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, it provides an example reference that shows where the intent is referenced. To remove the reference to the intent, either update the bot or delete it. If you get the same exception when you attempt to delete the intent again, repeat until the intent has no references and the call to <code>DeleteIntent</code> is successful. </p> </note> <p> This operation requires permission for the <code>lex:DeleteIntent</code> action. </p>
+    ///
+    /// See `LexModels::delete_intent`.
+    pub fn delete_intent(
+        &self,
+        input: DeleteIntentRequest,
+    ) -> Result<(), RusotoError<DeleteIntentError>> {
+        self.runtime.block_on(self.client.delete_intent(input))
+    }
+
+    /// <p>Deletes a specific version of an intent. To delete all versions of a intent, use the <a>DeleteIntent</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteIntentVersion</code> action.</p>
+    ///
+    /// See `LexModels::delete_intent_version`.
+    pub fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> Result<(), RusotoError<DeleteIntentVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_intent_version(input))
+    }
+
+    /// <p>Deletes all versions of the slot type, including the <code>$LATEST</code> version. To delete a specific version of the slot type, use the <a>DeleteSlotTypeVersion</a> operation.</p> <p> You can delete a version of a slot type only if it is not referenced. To delete a slot type that is referred to in one or more intents, you must remove those references first. </p> <note> <p> If you get the <code>ResourceInUseException</code> exception, the exception provides an example reference that shows the intent where the slot type is referenced. To remove the reference to the slot type, either update the intent or delete it. If you get the same exception when you attempt to delete the slot type again, repeat until the slot type has no references and the <code>DeleteSlotType</code> call is successful. </p> </note> <p>This operation requires permission for the <code>lex:DeleteSlotType</code> action.</p>
+    ///
+    /// See `LexModels::delete_slot_type`.
+    pub fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> Result<(), RusotoError<DeleteSlotTypeError>> {
+        self.runtime.block_on(self.client.delete_slot_type(input))
+    }
+
+    /// <p>Deletes a specific version of a slot type. To delete all versions of a slot type, use the <a>DeleteSlotType</a> operation. </p> <p>This operation requires permissions for the <code>lex:DeleteSlotTypeVersion</code> action.</p>
+    ///
+    /// See `LexModels::delete_slot_type_version`.
+    pub fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> Result<(), RusotoError<DeleteSlotTypeVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_slot_type_version(input))
+    }
+
+    /// <p>Deletes stored utterances.</p> <p>Amazon Lex stores the utterances that users send to your bot. Utterances are stored for 15 days for use with the <a>GetUtterancesView</a> operation, and then stored indefinitely for use in improving the ability of your bot to respond to user input.</p> <p>Use the <code>DeleteUtterances</code> operation to manually delete stored utterances for a specific user. When you use the <code>DeleteUtterances</code> operation, utterances stored for improving your bot's ability to respond to user input are deleted immediately. Utterances stored for use with the <code>GetUtterancesView</code> operation are deleted after 15 days.</p> <p>This operation requires permissions for the <code>lex:DeleteUtterances</code> action.</p>
+    ///
+    /// See `LexModels::delete_utterances`.
+    pub fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> Result<(), RusotoError<DeleteUtterancesError>> {
+        self.runtime.block_on(self.client.delete_utterances(input))
+    }
+
+    /// <p>Returns metadata information for a specific bot. You must provide the bot name and the bot version or alias. </p> <p> This operation requires permissions for the <code>lex:GetBot</code> action. </p>
+    ///
+    /// See `LexModels::get_bot`.
+    pub fn get_bot(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+        self.runtime.block_on(self.client.get_bot(input))
+    }
+
+    /// <p>Returns information about an Amazon Lex bot alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:GetBotAlias</code> action.</p>
+    ///
+    /// See `LexModels::get_bot_alias`.
+    pub fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> Result<GetBotAliasResponse, RusotoError<GetBotAliasError>> {
+        self.runtime.block_on(self.client.get_bot_alias(input))
+    }
+
+    /// <p>Returns a list of aliases for a specified Amazon Lex bot.</p> <p>This operation requires permissions for the <code>lex:GetBotAliases</code> action.</p>
+    ///
+    /// See `LexModels::get_bot_aliases`.
+    pub fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>> {
+        self.runtime.block_on(self.client.get_bot_aliases(input))
+    }
+
+    /// <p>Returns information about the association between an Amazon Lex bot and a messaging platform.</p> <p>This operation requires permissions for the <code>lex:GetBotChannelAssociation</code> action.</p>
+    ///
+    /// See `LexModels::get_bot_channel_association`.
+    pub fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> Result<GetBotChannelAssociationResponse, RusotoError<GetBotChannelAssociationError>> {
+        self.runtime
+            .block_on(self.client.get_bot_channel_association(input))
+    }
+
+    /// <p> Returns a list of all of the channels associated with the specified bot. </p> <p>The <code>GetBotChannelAssociations</code> operation requires permissions for the <code>lex:GetBotChannelAssociations</code> action.</p>
+    ///
+    /// See `LexModels::get_bot_channel_associations`.
+    pub fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> Result<GetBotChannelAssociationsResponse, RusotoError<GetBotChannelAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_bot_channel_associations(input))
+    }
+
+    /// <p>Gets information about all of the versions of a bot.</p> <p>The <code>GetBotVersions</code> operation returns a <code>BotMetadata</code> object for each version of a bot. For example, if a bot has three numbered versions, the <code>GetBotVersions</code> operation returns four <code>BotMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetBotVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetBotVersions</code> action.</p>
+    ///
+    /// See `LexModels::get_bot_versions`.
+    pub fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> Result<GetBotVersionsResponse, RusotoError<GetBotVersionsError>> {
+        self.runtime.block_on(self.client.get_bot_versions(input))
+    }
+
+    /// <p>Returns bot information as follows: </p> <ul> <li> <p>If you provide the <code>nameContains</code> field, the response includes information for the <code>$LATEST</code> version of all bots whose name contains the specified string.</p> </li> <li> <p>If you don't specify the <code>nameContains</code> field, the operation returns information about the <code>$LATEST</code> version of all of your bots.</p> </li> </ul> <p>This operation requires permission for the <code>lex:GetBots</code> action.</p>
+    ///
+    /// See `LexModels::get_bots`.
+    pub fn get_bots(
+        &self,
+        input: GetBotsRequest,
+    ) -> Result<GetBotsResponse, RusotoError<GetBotsError>> {
+        self.runtime.block_on(self.client.get_bots(input))
+    }
+
+    /// <p>Returns information about a built-in intent.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntent</code> action.</p>
+    ///
+    /// See `LexModels::get_builtin_intent`.
+    pub fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> Result<GetBuiltinIntentResponse, RusotoError<GetBuiltinIntentError>> {
+        self.runtime.block_on(self.client.get_builtin_intent(input))
+    }
+
+    /// <p>Gets a list of built-in intents that meet the specified criteria.</p> <p>This operation requires permission for the <code>lex:GetBuiltinIntents</code> action.</p>
+    ///
+    /// See `LexModels::get_builtin_intents`.
+    pub fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> Result<GetBuiltinIntentsResponse, RusotoError<GetBuiltinIntentsError>> {
+        self.runtime
+            .block_on(self.client.get_builtin_intents(input))
+    }
+
+    /// <p>Gets a list of built-in slot types that meet the specified criteria.</p> <p>For a list of built-in slot types, see <a href="https://developer.amazon.com/public/solutions/alexa/alexa-skills-kit/docs/built-in-intent-ref/slot-type-reference">Slot Type Reference</a> in the <i>Alexa Skills Kit</i>.</p> <p>This operation requires permission for the <code>lex:GetBuiltInSlotTypes</code> action.</p>
+    ///
+    /// See `LexModels::get_builtin_slot_types`.
+    pub fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> Result<GetBuiltinSlotTypesResponse, RusotoError<GetBuiltinSlotTypesError>> {
+        self.runtime
+            .block_on(self.client.get_builtin_slot_types(input))
+    }
+
+    /// <p>Exports the contents of a Amazon Lex resource in a specified format. </p>
+    ///
+    /// See `LexModels::get_export`.
+    pub fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<GetExportResponse, RusotoError<GetExportError>> {
+        self.runtime.block_on(self.client.get_export(input))
+    }
+
+    /// <p>Gets information about an import job started with the <code>StartImport</code> operation.</p>
+    ///
+    /// See `LexModels::get_import`.
+    pub fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> Result<GetImportResponse, RusotoError<GetImportError>> {
+        self.runtime.block_on(self.client.get_import(input))
+    }
+
+    /// <p> Returns information about an intent. In addition to the intent name, you must specify the intent version. </p> <p> This operation requires permissions to perform the <code>lex:GetIntent</code> action. </p>
+    ///
+    /// See `LexModels::get_intent`.
+    pub fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> Result<GetIntentResponse, RusotoError<GetIntentError>> {
+        self.runtime.block_on(self.client.get_intent(input))
+    }
+
+    /// <p>Gets information about all of the versions of an intent.</p> <p>The <code>GetIntentVersions</code> operation returns an <code>IntentMetadata</code> object for each version of an intent. For example, if an intent has three numbered versions, the <code>GetIntentVersions</code> operation returns four <code>IntentMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetIntentVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetIntentVersions</code> action.</p>
+    ///
+    /// See `LexModels::get_intent_versions`.
+    pub fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> Result<GetIntentVersionsResponse, RusotoError<GetIntentVersionsError>> {
+        self.runtime
+            .block_on(self.client.get_intent_versions(input))
+    }
+
+    /// <p>Returns intent information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all intents that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all intents. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetIntents</code> action. </p>
+    ///
+    /// See `LexModels::get_intents`.
+    pub fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> Result<GetIntentsResponse, RusotoError<GetIntentsError>> {
+        self.runtime.block_on(self.client.get_intents(input))
+    }
+
+    /// <p>Returns information about a specific version of a slot type. In addition to specifying the slot type name, you must specify the slot type version.</p> <p>This operation requires permissions for the <code>lex:GetSlotType</code> action.</p>
+    ///
+    /// See `LexModels::get_slot_type`.
+    pub fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> Result<GetSlotTypeResponse, RusotoError<GetSlotTypeError>> {
+        self.runtime.block_on(self.client.get_slot_type(input))
+    }
+
+    /// <p>Gets information about all versions of a slot type.</p> <p>The <code>GetSlotTypeVersions</code> operation returns a <code>SlotTypeMetadata</code> object for each version of a slot type. For example, if a slot type has three numbered versions, the <code>GetSlotTypeVersions</code> operation returns four <code>SlotTypeMetadata</code> objects in the response, one for each numbered version and one for the <code>$LATEST</code> version. </p> <p>The <code>GetSlotTypeVersions</code> operation always returns at least one version, the <code>$LATEST</code> version.</p> <p>This operation requires permissions for the <code>lex:GetSlotTypeVersions</code> action.</p>
+    ///
+    /// See `LexModels::get_slot_type_versions`.
+    pub fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> Result<GetSlotTypeVersionsResponse, RusotoError<GetSlotTypeVersionsError>> {
+        self.runtime
+            .block_on(self.client.get_slot_type_versions(input))
+    }
+
+    /// <p>Returns slot type information as follows: </p> <ul> <li> <p>If you specify the <code>nameContains</code> field, returns the <code>$LATEST</code> version of all slot types that contain the specified string.</p> </li> <li> <p> If you don't specify the <code>nameContains</code> field, returns information about the <code>$LATEST</code> version of all slot types. </p> </li> </ul> <p> The operation requires permission for the <code>lex:GetSlotTypes</code> action. </p>
+    ///
+    /// See `LexModels::get_slot_types`.
+    pub fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>> {
+        self.runtime.block_on(self.client.get_slot_types(input))
+    }
+
+    /// <p>Use the <code>GetUtterancesView</code> operation to get information about the utterances that your users have made to your bot. You can use this list to tune the utterances that your bot responds to.</p> <p>For example, say that you have created a bot to order flowers. After your users have used your bot for a while, use the <code>GetUtterancesView</code> operation to see the requests that they have made and whether they have been successful. You might find that the utterance "I want flowers" is not being recognized. You could add this utterance to the <code>OrderFlowers</code> intent so that your bot recognizes that utterance.</p> <p>After you publish a new version of a bot, you can get information about the old version and the new so that you can compare the performance across the two versions. </p> <p>Utterance statistics are generated once a day. Data is available for the last 15 days. You can request information for up to 5 versions of your bot in each request. Amazon Lex returns the most frequent utterances received by the bot in the last 15 days. The response contains information about a maximum of 100 utterances for each version.</p> <p>If you set <code>childDirected</code> field to true when you created your bot, or if you opted out of participating in improving Amazon Lex, utterances are not available.</p> <p>This operation requires permissions for the <code>lex:GetUtterancesView</code> action.</p>
+    ///
+    /// See `LexModels::get_utterances_view`.
+    pub fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> Result<GetUtterancesViewResponse, RusotoError<GetUtterancesViewError>> {
+        self.runtime
+            .block_on(self.client.get_utterances_view(input))
+    }
+
+    /// <p>Gets a list of tags associated with the specified resource. Only bots, bot aliases, and bot channels can have tags associated with them.</p>
+    ///
+    /// See `LexModels::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates an Amazon Lex conversational bot or replaces an existing bot. When you create or update a bot you are only required to specify a name, a locale, and whether the bot is directed toward children under age 13. You can use this to add intents later, or to remove intents from an existing bot. When you create a bot with the minimum information, the bot is created or updated but Amazon Lex returns the <code/> response <code>FAILED</code>. You can build the bot after you add one or more intents. For more information about Amazon Lex bots, see <a>how-it-works</a>. </p> <p>If you specify the name of an existing bot, the fields in the request replace the existing values in the <code>$LATEST</code> version of the bot. Amazon Lex removes any fields that you don't provide values for in the request, except for the <code>idleTTLInSeconds</code> and <code>privacySettings</code> fields, which are set to their default values. If you don't specify values for required fields, Amazon Lex throws an exception.</p> <p>This operation requires permissions for the <code>lex:PutBot</code> action. For more information, see <a>security-iam</a>.</p>
+    ///
+    /// See `LexModels::put_bot`.
+    pub fn put_bot(
+        &self,
+        input: PutBotRequest,
+    ) -> Result<PutBotResponse, RusotoError<PutBotError>> {
+        self.runtime.block_on(self.client.put_bot(input))
+    }
+
+    /// <p>Creates an alias for the specified version of the bot or replaces an alias for the specified bot. To change the version of the bot that the alias points to, replace the alias. For more information about aliases, see <a>versioning-aliases</a>.</p> <p>This operation requires permissions for the <code>lex:PutBotAlias</code> action. </p>
+    ///
+    /// See `LexModels::put_bot_alias`.
+    pub fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> Result<PutBotAliasResponse, RusotoError<PutBotAliasError>> {
+        self.runtime.block_on(self.client.put_bot_alias(input))
+    }
+
+    /// <p>Creates an intent or replaces an existing intent.</p> <p>To define the interaction between the user and your bot, you use one or more intents. For a pizza ordering bot, for example, you would create an <code>OrderPizza</code> intent. </p> <p>To create an intent or replace an existing intent, you must provide the following:</p> <ul> <li> <p>Intent name. For example, <code>OrderPizza</code>.</p> </li> <li> <p>Sample utterances. For example, "Can I order a pizza, please." and "I want to order a pizza."</p> </li> <li> <p>Information to be gathered. You specify slot types for the information that your bot will request from the user. You can specify standard slot types, such as a date or a time, or custom slot types such as the size and crust of a pizza.</p> </li> <li> <p>How the intent will be fulfilled. You can provide a Lambda function or configure the intent to return the intent information to the client application. If you use a Lambda function, when all of the intent information is available, Amazon Lex invokes your Lambda function. If you configure your intent to return the intent information to the client application. </p> </li> </ul> <p>You can specify other optional information in the request, such as:</p> <ul> <li> <p>A confirmation prompt to ask the user to confirm an intent. For example, "Shall I order your pizza?"</p> </li> <li> <p>A conclusion statement to send to the user after the intent has been fulfilled. For example, "I placed your pizza order."</p> </li> <li> <p>A follow-up prompt that asks the user for additional activity. For example, asking "Do you want to order a drink with your pizza?"</p> </li> </ul> <p>If you specify an existing intent name to update the intent, Amazon Lex replaces the values in the <code>$LATEST</code> version of the intent with the values in the request. Amazon Lex removes fields that you don't provide in the request. If you don't specify the required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of an intent, the <code>status</code> field of any bot that uses the <code>$LATEST</code> version of the intent is set to <code>NOT_BUILT</code>.</p> <p>For more information, see <a>how-it-works</a>.</p> <p>This operation requires permissions for the <code>lex:PutIntent</code> action.</p>
+    ///
+    /// See `LexModels::put_intent`.
+    pub fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> Result<PutIntentResponse, RusotoError<PutIntentError>> {
+        self.runtime.block_on(self.client.put_intent(input))
+    }
+
+    /// <p>Creates a custom slot type or replaces an existing custom slot type.</p> <p>To create a custom slot type, specify a name for the slot type and a set of enumeration values, which are the values that a slot of this type can assume. For more information, see <a>how-it-works</a>.</p> <p>If you specify the name of an existing slot type, the fields in the request replace the existing values in the <code>$LATEST</code> version of the slot type. Amazon Lex removes the fields that you don't provide in the request. If you don't specify required fields, Amazon Lex throws an exception. When you update the <code>$LATEST</code> version of a slot type, if a bot uses the <code>$LATEST</code> version of an intent that contains the slot type, the bot's <code>status</code> field is set to <code>NOT_BUILT</code>.</p> <p>This operation requires permissions for the <code>lex:PutSlotType</code> action.</p>
+    ///
+    /// See `LexModels::put_slot_type`.
+    pub fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> Result<PutSlotTypeResponse, RusotoError<PutSlotTypeError>> {
+        self.runtime.block_on(self.client.put_slot_type(input))
+    }
+
+    /// <p>Starts a job to import a resource to Amazon Lex.</p>
+    ///
+    /// See `LexModels::start_import`.
+    pub fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> Result<StartImportResponse, RusotoError<StartImportError>> {
+        self.runtime.block_on(self.client.start_import(input))
+    }
+
+    /// <p>Adds the specified tags to the specified resource. If a tag key already exists, the existing value is replaced with the new value.</p>
+    ///
+    /// See `LexModels::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from a bot, bot alias or bot channel.</p>
+    ///
+    /// See `LexModels::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
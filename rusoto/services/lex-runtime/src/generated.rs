@@ -442,19 +442,19 @@ impl DeleteSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteSessionError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSessionError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteSessionError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSessionError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteSessionError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteSessionError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSessionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -494,16 +494,16 @@ impl GetSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetSessionError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSessionError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetSessionError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetSessionError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetSessionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -556,37 +556,40 @@ impl PostContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PostContentError::BadGateway(err.msg))
+                    return RusotoError::service(&res, PostContentError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PostContentError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PostContentError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PostContentError::Conflict(err.msg))
+                    return RusotoError::service(&res, PostContentError::Conflict(err.msg))
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PostContentError::DependencyFailed(err.msg))
+                    return RusotoError::service(&res, PostContentError::DependencyFailed(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PostContentError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PostContentError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostContentError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PostContentError::LimitExceeded(err.msg))
                 }
                 "LoopDetectedException" => {
-                    return RusotoError::Service(PostContentError::LoopDetected(err.msg))
+                    return RusotoError::service(&res, PostContentError::LoopDetected(err.msg))
                 }
                 "NotAcceptableException" => {
-                    return RusotoError::Service(PostContentError::NotAcceptable(err.msg))
+                    return RusotoError::service(&res, PostContentError::NotAcceptable(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PostContentError::NotFound(err.msg))
+                    return RusotoError::service(&res, PostContentError::NotFound(err.msg))
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(PostContentError::RequestTimeout(err.msg))
+                    return RusotoError::service(&res, PostContentError::RequestTimeout(err.msg))
                 }
                 "UnsupportedMediaTypeException" => {
-                    return RusotoError::Service(PostContentError::UnsupportedMediaType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PostContentError::UnsupportedMediaType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -640,28 +643,28 @@ impl PostTextError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PostTextError::BadGateway(err.msg))
+                    return RusotoError::service(&res, PostTextError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PostTextError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PostTextError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PostTextError::Conflict(err.msg))
+                    return RusotoError::service(&res, PostTextError::Conflict(err.msg))
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PostTextError::DependencyFailed(err.msg))
+                    return RusotoError::service(&res, PostTextError::DependencyFailed(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PostTextError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PostTextError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostTextError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PostTextError::LimitExceeded(err.msg))
                 }
                 "LoopDetectedException" => {
-                    return RusotoError::Service(PostTextError::LoopDetected(err.msg))
+                    return RusotoError::service(&res, PostTextError::LoopDetected(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PostTextError::NotFound(err.msg))
+                    return RusotoError::service(&res, PostTextError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -712,28 +715,28 @@ impl PutSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PutSessionError::BadGateway(err.msg))
+                    return RusotoError::service(&res, PutSessionError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSessionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutSessionError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutSessionError::Conflict(err.msg))
+                    return RusotoError::service(&res, PutSessionError::Conflict(err.msg))
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PutSessionError::DependencyFailed(err.msg))
+                    return RusotoError::service(&res, PutSessionError::DependencyFailed(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutSessionError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, PutSessionError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutSessionError::LimitExceeded(err.msg))
                 }
                 "NotAcceptableException" => {
-                    return RusotoError::Service(PutSessionError::NotAcceptable(err.msg))
+                    return RusotoError::service(&res, PutSessionError::NotAcceptable(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutSessionError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutSessionError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -809,6 +812,7 @@ impl LexRuntimeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -824,6 +828,7 @@ impl LexRuntimeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> LexRuntimeClient {
         LexRuntimeClient { client, region }
     }
@@ -845,6 +850,9 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation_name("DeleteSession");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -880,6 +888,9 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation_name("GetSession");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -921,6 +932,9 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("PostContent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -981,6 +995,9 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("PostText");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1018,6 +1035,9 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation_name("PutSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1053,3 +1073,103 @@ impl LexRuntime for LexRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Lex Runtime Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `LexRuntimeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LexRuntimeBlockingClient {
+    client: LexRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LexRuntimeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LexRuntimeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        LexRuntimeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LexRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LexRuntimeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient {
+            client: LexRuntimeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Removes session information for a specified bot, alias, and user ID. </p>
+    ///
+    /// See `LexRuntime::delete_session`.
+    pub fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> Result<DeleteSessionResponse, RusotoError<DeleteSessionError>> {
+        self.runtime.block_on(self.client.delete_session(input))
+    }
+
+    /// <p>Returns session information for a specified bot, alias, and user ID.</p>
+    ///
+    /// See `LexRuntime::get_session`.
+    pub fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> Result<GetSessionResponse, RusotoError<GetSessionError>> {
+        self.runtime.block_on(self.client.get_session(input))
+    }
+
+    /// <p> Sends user input (text or speech) to Amazon Lex. Clients use this API to send text and audio requests to Amazon Lex at runtime. Amazon Lex interprets the user input using the machine learning model that it built for the bot. </p> <p>The <code>PostContent</code> operation supports audio input at 8kHz and 16kHz. You can use 8kHz audio to achieve higher speech recognition accuracy in telephone audio applications. </p> <p> In response, Amazon Lex returns the next message to convey to the user. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza," Amazon Lex might return a response with a message eliciting slot data (for example, <code>PizzaSize</code>): "What size pizza would you like?". </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to get user confirmation: "Order the pizza?". </p> </li> <li> <p> After the user replies "Yes" to the confirmation prompt, Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a response from the user. For example, conclusion statements do not require a response. Some messages require only a yes or no response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you can use to enhance client behavior, such as displaying the appropriate client user interface. Consider the following examples: </p> <ul> <li> <p> If the message is to elicit slot data, Amazon Lex returns the following context information: </p> <ul> <li> <p> <code>x-amz-lex-dialog-state</code> header set to <code>ElicitSlot</code> </p> </li> <li> <p> <code>x-amz-lex-intent-name</code> header set to the intent name in the current context </p> </li> <li> <p> <code>x-amz-lex-slot-to-elicit</code> header set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>x-amz-lex-slots</code> header set to a map of slots configured for the intent with their current values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>x-amz-lex-dialog-state</code> header is set to <code>Confirmation</code> and the <code>x-amz-lex-slot-to-elicit</code> header is omitted. </p> </li> <li> <p> If the message is a clarification prompt configured for the intent, indicating that the user intent is not understood, the <code>x-amz-dialog-state</code> header is set to <code>ElicitIntent</code> and the <code>x-amz-slot-to-elicit</code> header is omitted. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
+    ///
+    /// See `LexRuntime::post_content`.
+    pub fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> Result<PostContentResponse, RusotoError<PostContentError>> {
+        self.runtime.block_on(self.client.post_content(input))
+    }
+
+    /// <p>Sends user input to Amazon Lex. Client applications can use this API to send requests to Amazon Lex at runtime. Amazon Lex then interprets the user input using the machine learning model it built for the bot. </p> <p> In response, Amazon Lex returns the next <code>message</code> to convey to the user an optional <code>responseCard</code> to display. Consider the following example messages: </p> <ul> <li> <p> For a user input "I would like a pizza", Amazon Lex might return a response with a message eliciting slot data (for example, PizzaSize): "What size pizza would you like?" </p> </li> <li> <p> After the user provides all of the pizza order information, Amazon Lex might return a response with a message to obtain user confirmation "Proceed with the pizza order?". </p> </li> <li> <p> After the user replies to a confirmation prompt with a "yes", Amazon Lex might return a conclusion statement: "Thank you, your cheese pizza has been ordered.". </p> </li> </ul> <p> Not all Amazon Lex messages require a user response. For example, a conclusion statement does not require a response. Some messages require only a "yes" or "no" user response. In addition to the <code>message</code>, Amazon Lex provides additional context about the message in the response that you might use to enhance client behavior, for example, to display the appropriate client user interface. These are the <code>slotToElicit</code>, <code>dialogState</code>, <code>intentName</code>, and <code>slots</code> fields in the response. Consider the following examples: </p> <ul> <li> <p>If the message is to elicit slot data, Amazon Lex returns the following context information:</p> <ul> <li> <p> <code>dialogState</code> set to ElicitSlot </p> </li> <li> <p> <code>intentName</code> set to the intent name in the current context </p> </li> <li> <p> <code>slotToElicit</code> set to the slot name for which the <code>message</code> is eliciting information </p> </li> <li> <p> <code>slots</code> set to a map of slots, configured for the intent, with currently known values </p> </li> </ul> </li> <li> <p> If the message is a confirmation prompt, the <code>dialogState</code> is set to ConfirmIntent and <code>SlotToElicit</code> is set to null. </p> </li> <li> <p>If the message is a clarification prompt (configured for the intent) that indicates that user intent is not understood, the <code>dialogState</code> is set to ElicitIntent and <code>slotToElicit</code> is set to null. </p> </li> </ul> <p> In addition, Amazon Lex also returns your application-specific <code>sessionAttributes</code>. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/context-mgmt.html">Managing Conversation Context</a>. </p>
+    ///
+    /// See `LexRuntime::post_text`.
+    pub fn post_text(
+        &self,
+        input: PostTextRequest,
+    ) -> Result<PostTextResponse, RusotoError<PostTextError>> {
+        self.runtime.block_on(self.client.post_text(input))
+    }
+
+    /// <p>Creates a new session or modifies an existing session with an Amazon Lex bot. Use this operation to enable your application to set the state of the bot.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/how-session-api.html">Managing Sessions</a>.</p>
+    ///
+    /// See `LexRuntime::put_session`.
+    pub fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> Result<PutSessionResponse, RusotoError<PutSessionError>> {
+        self.runtime.block_on(self.client.put_session(input))
+    }
+}
@@ -875,34 +875,40 @@ impl CreateLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLicenseConfigurationError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLicenseConfigurationError::Authorization(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLicenseConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLicenseConfigurationError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLicenseConfigurationError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLicenseConfigurationError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -951,29 +957,34 @@ impl DeleteLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLicenseConfigurationError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLicenseConfigurationError::Authorization(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLicenseConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLicenseConfigurationError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLicenseConfigurationError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1017,29 +1028,34 @@ impl GetLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLicenseConfigurationError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLicenseConfigurationError::Authorization(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLicenseConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLicenseConfigurationError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLicenseConfigurationError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1081,18 +1097,28 @@ impl GetServiceSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetServiceSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceSettingsError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetServiceSettingsError::Authorization(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceSettingsError::Authorization(err.msg),
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetServiceSettingsError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceSettingsError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetServiceSettingsError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceSettingsError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1137,34 +1163,40 @@ impl ListAssociationsForLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationsForLicenseConfigurationError::AccessDenied(err.msg),
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationsForLicenseConfigurationError::Authorization(err.msg),
                     )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationsForLicenseConfigurationError::FilterLimitExceeded(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationsForLicenseConfigurationError::InvalidParameterValue(
                             err.msg,
                         ),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationsForLicenseConfigurationError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationsForLicenseConfigurationError::ServerInternal(err.msg),
                     )
                 }
@@ -1223,31 +1255,36 @@ impl ListFailuresForLicenseConfigurationOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFailuresForLicenseConfigurationOperationsError::AccessDenied(err.msg),
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFailuresForLicenseConfigurationOperationsError::Authorization(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFailuresForLicenseConfigurationOperationsError::InvalidParameterValue(
                             err.msg,
                         ),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFailuresForLicenseConfigurationOperationsError::RateLimitExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListFailuresForLicenseConfigurationOperationsError::ServerInternal(err.msg),
                     )
                 }
@@ -1303,34 +1340,40 @@ impl ListLicenseConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLicenseConfigurationsError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLicenseConfigurationsError::Authorization(err.msg),
+                    )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseConfigurationsError::FilterLimitExceeded(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseConfigurationsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLicenseConfigurationsError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLicenseConfigurationsError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1379,27 +1422,32 @@ impl ListLicenseSpecificationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseSpecificationsForResourceError::AccessDenied(err.msg),
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseSpecificationsForResourceError::Authorization(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseSpecificationsForResourceError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseSpecificationsForResourceError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLicenseSpecificationsForResourceError::ServerInternal(err.msg),
                     )
                 }
@@ -1457,35 +1505,46 @@ impl ListResourceInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListResourceInventoryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListResourceInventoryError::Authorization(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::Authorization(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(ListResourceInventoryError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::FailedDependency(err.msg),
+                    )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(ListResourceInventoryError::FilterLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::FilterLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListResourceInventoryError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListResourceInventoryError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceInventoryError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceInventoryError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1529,23 +1588,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Authorization(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Authorization(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1591,32 +1661,38 @@ impl ListUsageForLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListUsageForLicenseConfigurationError::AccessDenied(err.msg),
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListUsageForLicenseConfigurationError::Authorization(err.msg),
                     )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListUsageForLicenseConfigurationError::FilterLimitExceeded(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListUsageForLicenseConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListUsageForLicenseConfigurationError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListUsageForLicenseConfigurationError::ServerInternal(err.msg),
                     )
                 }
@@ -1673,19 +1749,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(TagResourceError::Authorization(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Authorization(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::RateLimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::RateLimitExceeded(err.msg))
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ServerInternal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1727,19 +1806,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UntagResourceError::Authorization(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Authorization(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::RateLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ServerInternal(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1783,29 +1868,34 @@ impl UpdateLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLicenseConfigurationError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::Authorization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLicenseConfigurationError::Authorization(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseConfigurationError::InvalidParameterValue(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseConfigurationError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLicenseConfigurationError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1855,37 +1945,44 @@ impl UpdateLicenseSpecificationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::AccessDenied(err.msg),
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::Authorization(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::InvalidParameterValue(err.msg),
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::InvalidResourceState(err.msg),
                     )
                 }
                 "LicenseUsageException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::LicenseUsage(err.msg),
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::RateLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLicenseSpecificationsForResourceError::ServerInternal(err.msg),
                     )
                 }
@@ -1945,25 +2042,34 @@ impl UpdateServiceSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingsError::AccessDenied(err.msg),
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::Authorization(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingsError::Authorization(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::RateLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingsError::RateLimitExceeded(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingsError::ServerInternal(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2116,6 +2222,7 @@ impl LicenseManagerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2131,6 +2238,7 @@ impl LicenseManagerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> LicenseManagerClient {
         LicenseManagerClient { client, region }
     }
@@ -2145,6 +2253,9 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLicenseConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.CreateLicenseConfiguration",
@@ -2168,6 +2279,9 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLicenseConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.DeleteLicenseConfiguration",
@@ -2190,6 +2304,9 @@ impl LicenseManager for LicenseManagerClient {
         input: GetLicenseConfigurationRequest,
     ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLicenseConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicenseConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2208,6 +2325,9 @@ impl LicenseManager for LicenseManagerClient {
         &self,
     ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetServiceSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLicenseManager.GetServiceSettings");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2228,6 +2348,9 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListAssociationsForLicenseConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssociationsForLicenseConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListAssociationsForLicenseConfiguration",
@@ -2256,6 +2379,9 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFailuresForLicenseConfigurationOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListFailuresForLicenseConfigurationOperations",
@@ -2282,6 +2408,9 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLicenseConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListLicenseConfigurations",
@@ -2307,6 +2436,9 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListLicenseSpecificationsForResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLicenseSpecificationsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListLicenseSpecificationsForResource",
@@ -2332,6 +2464,9 @@ impl LicenseManager for LicenseManagerClient {
         input: ListResourceInventoryRequest,
     ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourceInventory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLicenseManager.ListResourceInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2351,6 +2486,9 @@ impl LicenseManager for LicenseManagerClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSLicenseManager.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2372,6 +2510,9 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListUsageForLicenseConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUsageForLicenseConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListUsageForLicenseConfiguration",
@@ -2397,6 +2538,9 @@ impl LicenseManager for LicenseManagerClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLicenseManager.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2415,6 +2559,9 @@ impl LicenseManager for LicenseManagerClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLicenseManager.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2434,6 +2581,9 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateLicenseConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.UpdateLicenseConfiguration",
@@ -2459,6 +2609,9 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<UpdateLicenseSpecificationsForResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateLicenseSpecificationsForResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.UpdateLicenseSpecificationsForResource",
@@ -2484,6 +2637,9 @@ impl LicenseManager for LicenseManagerClient {
         input: UpdateServiceSettingsRequest,
     ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServiceSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSLicenseManager.UpdateServiceSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2497,3 +2653,250 @@ impl LicenseManager for LicenseManagerClient {
             .deserialize::<UpdateServiceSettingsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS License Manager API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `LicenseManagerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LicenseManagerBlockingClient {
+    client: LicenseManagerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LicenseManagerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LicenseManagerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        LicenseManagerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LicenseManagerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LicenseManagerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient {
+            client: LicenseManagerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a license configuration.</p> <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
+    ///
+    /// See `LicenseManager::create_license_configuration`.
+    pub fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_license_configuration(input))
+    }
+
+    /// <p>Deletes the specified license configuration.</p> <p>You cannot delete a license configuration that is in use.</p>
+    ///
+    /// See `LicenseManager::delete_license_configuration`.
+    pub fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_license_configuration(input))
+    }
+
+    /// <p>Gets detailed information about the specified license configuration.</p>
+    ///
+    /// See `LicenseManager::get_license_configuration`.
+    pub fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_license_configuration(input))
+    }
+
+    /// <p>Gets the License Manager settings for the current Region.</p>
+    ///
+    /// See `LicenseManager::get_service_settings`.
+    pub fn get_service_settings(
+        &self,
+    ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
+        self.runtime.block_on(self.client.get_service_settings())
+    }
+
+    /// <p>Lists the resource associations for the specified license configuration.</p> <p>Resource associations need not consume licenses from a license configuration. For example, an AMI or a stopped instance might not consume a license (depending on the license rules).</p>
+    ///
+    /// See `LicenseManager::list_associations_for_license_configuration`.
+    pub fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> Result<
+        ListAssociationsForLicenseConfigurationResponse,
+        RusotoError<ListAssociationsForLicenseConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_associations_for_license_configuration(input),
+        )
+    }
+
+    /// <p>Lists the license configuration operations that failed.</p>
+    ///
+    /// See `LicenseManager::list_failures_for_license_configuration_operations`.
+    pub fn list_failures_for_license_configuration_operations(
+        &self,
+        input: ListFailuresForLicenseConfigurationOperationsRequest,
+    ) -> Result<
+        ListFailuresForLicenseConfigurationOperationsResponse,
+        RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_failures_for_license_configuration_operations(input),
+        )
+    }
+
+    /// <p>Lists the license configurations for your account.</p>
+    ///
+    /// See `LicenseManager::list_license_configurations`.
+    pub fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_license_configurations(input))
+    }
+
+    /// <p>Describes the license configurations for the specified resource.</p>
+    ///
+    /// See `LicenseManager::list_license_specifications_for_resource`.
+    pub fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        ListLicenseSpecificationsForResourceResponse,
+        RusotoError<ListLicenseSpecificationsForResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_license_specifications_for_resource(input))
+    }
+
+    /// <p>Lists resources managed using Systems Manager inventory.</p>
+    ///
+    /// See `LicenseManager::list_resource_inventory`.
+    pub fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
+        self.runtime
+            .block_on(self.client.list_resource_inventory(input))
+    }
+
+    /// <p>Lists the tags for the specified license configuration.</p>
+    ///
+    /// See `LicenseManager::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists all license usage records for a license configuration, displaying license consumption details by resource at a selected point in time. Use this action to audit the current license consumption for any license inventory and configuration.</p>
+    ///
+    /// See `LicenseManager::list_usage_for_license_configuration`.
+    pub fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> Result<
+        ListUsageForLicenseConfigurationResponse,
+        RusotoError<ListUsageForLicenseConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_usage_for_license_configuration(input))
+    }
+
+    /// <p>Adds the specified tags to the specified license configuration.</p>
+    ///
+    /// See `LicenseManager::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from the specified license configuration.</p>
+    ///
+    /// See `LicenseManager::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Modifies the attributes of an existing license configuration.</p> <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or vCPU), allowed tenancy (shared tenancy, Dedicated Instance, Dedicated Host, or all of these), host affinity (how long a VM must be associated with a host), and the number of licenses purchased and used.</p>
+    ///
+    /// See `LicenseManager::update_license_configuration`.
+    pub fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_license_configuration(input))
+    }
+
+    /// <p>Adds or removes the specified license configurations for the specified AWS resource.</p> <p>You can update the license specifications of AMIs, instances, and hosts. You cannot update the license specifications for launch templates and AWS CloudFormation templates, as they send license configurations to the operation that creates the resource.</p>
+    ///
+    /// See `LicenseManager::update_license_specifications_for_resource`.
+    pub fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        UpdateLicenseSpecificationsForResourceResponse,
+        RusotoError<UpdateLicenseSpecificationsForResourceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_license_specifications_for_resource(input),
+        )
+    }
+
+    /// <p>Updates License Manager settings for the current Region.</p>
+    ///
+    /// See `LicenseManager::update_service_settings`.
+    pub fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_service_settings(input))
+    }
+}
@@ -4752,27 +4752,34 @@ impl AllocateStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AllocateStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AllocateStaticIpError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AllocateStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AllocateStaticIpError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AllocateStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AllocateStaticIpError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AllocateStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(&res, AllocateStaticIpError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AllocateStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AllocateStaticIpError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AllocateStaticIpError::Service(err.msg))
+                    return RusotoError::service(&res, AllocateStaticIpError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AllocateStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AllocateStaticIpError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4820,25 +4827,28 @@ impl AttachDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachDiskError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AttachDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachDiskError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AttachDiskError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachDiskError::NotFound(err.msg))
+                    return RusotoError::service(&res, AttachDiskError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AttachDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, AttachDiskError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachDiskError::Service(err.msg))
+                    return RusotoError::service(&res, AttachDiskError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AttachDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, AttachDiskError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4888,37 +4898,44 @@ impl AttachInstancesToLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachInstancesToLoadBalancerError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachInstancesToLoadBalancerError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachInstancesToLoadBalancerError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachInstancesToLoadBalancerError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachInstancesToLoadBalancerError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachInstancesToLoadBalancerError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachInstancesToLoadBalancerError::Unauthenticated(err.msg),
                     )
                 }
@@ -4976,37 +4993,44 @@ impl AttachLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachLoadBalancerTlsCertificateError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachLoadBalancerTlsCertificateError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachLoadBalancerTlsCertificateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachLoadBalancerTlsCertificateError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachLoadBalancerTlsCertificateError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachLoadBalancerTlsCertificateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachLoadBalancerTlsCertificateError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AttachLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
                     )
                 }
@@ -5066,27 +5090,34 @@ impl AttachStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachStaticIpError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AttachStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachStaticIpError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AttachStaticIpError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(&res, AttachStaticIpError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AttachStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachStaticIpError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachStaticIpError::Service(err.msg))
+                    return RusotoError::service(&res, AttachStaticIpError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AttachStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachStaticIpError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5134,35 +5165,46 @@ impl CloseInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CloseInstancePublicPortsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CloseInstancePublicPortsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CloseInstancePublicPortsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CloseInstancePublicPortsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CloseInstancePublicPortsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CloseInstancePublicPortsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CloseInstancePublicPortsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5212,25 +5254,28 @@ impl CopySnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CopySnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CopySnapshotError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CopySnapshotError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopySnapshotError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CopySnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CopySnapshotError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CopySnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, CopySnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CopySnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, CopySnapshotError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CopySnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, CopySnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CopySnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, CopySnapshotError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5278,35 +5323,46 @@ impl CreateCloudFormationStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationStackError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCloudFormationStackError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationStackError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationStackError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationStackError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationStackError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationStackError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5354,24 +5410,34 @@ impl CreateContactMethodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateContactMethodError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateContactMethodError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateContactMethodError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateContactMethodError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateContactMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateContactMethodError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateContactMethodError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateContactMethodError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateContactMethodError::Service(err.msg))
+                    return RusotoError::service(&res, CreateContactMethodError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateContactMethodError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateContactMethodError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5418,25 +5484,28 @@ impl CreateDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDiskError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateDiskError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDiskError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, CreateDiskError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskError::Service(err.msg))
+                    return RusotoError::service(&res, CreateDiskError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, CreateDiskError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5484,31 +5553,46 @@ impl CreateDiskFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskFromSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDiskFromSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskFromSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskFromSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskFromSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskFromSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskFromSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5558,27 +5642,40 @@ impl CreateDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskSnapshotError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDiskSnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, CreateDiskSnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiskSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5626,25 +5723,28 @@ impl CreateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDomainError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDomainError::Service(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5692,27 +5792,40 @@ impl CreateDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainEntryError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDomainEntryError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainEntryError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainEntryError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateDomainEntryError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainEntryError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDomainEntryError::Service(err.msg))
+                    return RusotoError::service(&res, CreateDomainEntryError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainEntryError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5760,31 +5873,46 @@ impl CreateInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInstanceSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5834,27 +5962,34 @@ impl CreateInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstancesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateInstancesError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateInstancesError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateInstancesError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstancesError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateInstancesError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateInstancesError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstancesError::Service(err.msg))
+                    return RusotoError::service(&res, CreateInstancesError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstancesError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5904,37 +6039,46 @@ impl CreateInstancesFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesFromSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInstancesFromSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesFromSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesFromSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInstancesFromSnapshotError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesFromSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstancesFromSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5984,27 +6128,31 @@ impl CreateKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateKeyPairError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateKeyPairError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeyPairError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateKeyPairError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateKeyPairError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateKeyPairError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateKeyPairError::Service(err.msg))
+                    return RusotoError::service(&res, CreateKeyPairError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, CreateKeyPairError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6052,27 +6200,40 @@ impl CreateLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateLoadBalancerError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::Service(err.msg))
+                    return RusotoError::service(&res, CreateLoadBalancerError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6122,37 +6283,44 @@ impl CreateLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLoadBalancerTlsCertificateError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLoadBalancerTlsCertificateError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateLoadBalancerTlsCertificateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerTlsCertificateError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLoadBalancerTlsCertificateError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLoadBalancerTlsCertificateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLoadBalancerTlsCertificateError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
                     )
                 }
@@ -6212,35 +6380,46 @@ impl CreateRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6292,37 +6471,44 @@ impl CreateRelationalDatabaseFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::NotFound(err.msg),
                     )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::Service(err.msg),
                     )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseFromSnapshotError::Unauthenticated(err.msg),
                     )
                 }
@@ -6386,37 +6572,44 @@ impl CreateRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseSnapshotError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseSnapshotError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseSnapshotError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseSnapshotError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRelationalDatabaseSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
                     )
                 }
@@ -6474,22 +6667,22 @@ impl DeleteAlarmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAlarmError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteAlarmError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAlarmError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteAlarmError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAlarmError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAlarmError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteAlarmError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, DeleteAlarmError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAlarmError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteAlarmError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteAlarmError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, DeleteAlarmError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6534,22 +6727,34 @@ impl DeleteAutoSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAutoSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAutoSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAutoSnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAutoSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteAutoSnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAutoSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6594,24 +6799,34 @@ impl DeleteContactMethodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteContactMethodError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContactMethodError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteContactMethodError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContactMethodError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContactMethodError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteContactMethodError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteContactMethodError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContactMethodError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteContactMethodError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteContactMethodError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteContactMethodError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContactMethodError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6658,25 +6873,28 @@ impl DeleteDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDiskError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiskError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteDiskError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiskError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDiskError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, DeleteDiskError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDiskError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteDiskError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, DeleteDiskError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6724,27 +6942,40 @@ impl DeleteDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiskSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiskSnapshotError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiskSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDiskSnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiskSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteDiskSnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiskSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6792,25 +7023,28 @@ impl DeleteDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDomainError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDomainError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6858,27 +7092,40 @@ impl DeleteDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainEntryError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainEntryError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainEntryError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDomainEntryError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainEntryError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteDomainEntryError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDomainEntryError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6926,27 +7173,34 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteInstanceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteInstanceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteInstanceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteInstanceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInstanceError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteInstanceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6994,31 +7248,46 @@ impl DeleteInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInstanceSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7068,27 +7337,31 @@ impl DeleteKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteKeyPairError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteKeyPairError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKeyPairError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteKeyPairError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteKeyPairError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKeyPairError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteKeyPairError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteKeyPairError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, DeleteKeyPairError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7136,29 +7409,40 @@ impl DeleteKnownHostKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKnownHostKeysError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKnownHostKeysError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKnownHostKeysError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteKnownHostKeysError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKnownHostKeysError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteKnownHostKeysError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteKnownHostKeysError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7206,27 +7490,40 @@ impl DeleteLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteLoadBalancerError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteLoadBalancerError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7276,37 +7573,44 @@ impl DeleteLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLoadBalancerTlsCertificateError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLoadBalancerTlsCertificateError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteLoadBalancerTlsCertificateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerTlsCertificateError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLoadBalancerTlsCertificateError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLoadBalancerTlsCertificateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoadBalancerTlsCertificateError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
                     )
                 }
@@ -7366,35 +7670,46 @@ impl DeleteRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7446,37 +7761,44 @@ impl DeleteRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRelationalDatabaseSnapshotError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRelationalDatabaseSnapshotError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRelationalDatabaseSnapshotError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseSnapshotError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRelationalDatabaseSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
                     )
                 }
@@ -7536,25 +7858,28 @@ impl DetachDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachDiskError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DetachDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachDiskError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DetachDiskError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachDiskError::NotFound(err.msg))
+                    return RusotoError::service(&res, DetachDiskError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DetachDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, DetachDiskError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachDiskError::Service(err.msg))
+                    return RusotoError::service(&res, DetachDiskError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DetachDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, DetachDiskError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7604,37 +7929,44 @@ impl DetachInstancesFromLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetachInstancesFromLoadBalancerError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetachInstancesFromLoadBalancerError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetachInstancesFromLoadBalancerError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachInstancesFromLoadBalancerError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachInstancesFromLoadBalancerError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetachInstancesFromLoadBalancerError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachInstancesFromLoadBalancerError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachInstancesFromLoadBalancerError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetachInstancesFromLoadBalancerError::Unauthenticated(err.msg),
                     )
                 }
@@ -7690,27 +8022,34 @@ impl DetachStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachStaticIpError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DetachStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachStaticIpError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DetachStaticIpError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(&res, DetachStaticIpError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DetachStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachStaticIpError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachStaticIpError::Service(err.msg))
+                    return RusotoError::service(&res, DetachStaticIpError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DetachStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachStaticIpError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7756,22 +8095,22 @@ impl DisableAddOnError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableAddOnError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DisableAddOnError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisableAddOnError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DisableAddOnError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableAddOnError::NotFound(err.msg))
+                    return RusotoError::service(&res, DisableAddOnError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DisableAddOnError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, DisableAddOnError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableAddOnError::Service(err.msg))
+                    return RusotoError::service(&res, DisableAddOnError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DisableAddOnError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, DisableAddOnError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7818,31 +8157,46 @@ impl DownloadDefaultKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DownloadDefaultKeyPairError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DownloadDefaultKeyPairError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DownloadDefaultKeyPairError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DownloadDefaultKeyPairError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DownloadDefaultKeyPairError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DownloadDefaultKeyPairError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DownloadDefaultKeyPairError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7890,22 +8244,22 @@ impl EnableAddOnError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAddOnError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, EnableAddOnError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAddOnError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, EnableAddOnError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableAddOnError::NotFound(err.msg))
+                    return RusotoError::service(&res, EnableAddOnError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(EnableAddOnError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, EnableAddOnError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAddOnError::Service(err.msg))
+                    return RusotoError::service(&res, EnableAddOnError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(EnableAddOnError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, EnableAddOnError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7952,27 +8306,34 @@ impl ExportSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ExportSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ExportSnapshotError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ExportSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportSnapshotError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ExportSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ExportSnapshotError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, ExportSnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ExportSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ExportSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, ExportSnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ExportSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8020,27 +8381,34 @@ impl GetActiveNamesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetActiveNamesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetActiveNamesError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetActiveNamesError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetActiveNamesError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetActiveNamesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetActiveNamesError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetActiveNamesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetActiveNamesError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetActiveNamesError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetActiveNamesError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetActiveNamesError::Service(err.msg))
+                    return RusotoError::service(&res, GetActiveNamesError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetActiveNamesError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetActiveNamesError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8086,22 +8454,22 @@ impl GetAlarmsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAlarmsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetAlarmsError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAlarmsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetAlarmsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAlarmsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAlarmsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetAlarmsError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetAlarmsError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAlarmsError::Service(err.msg))
+                    return RusotoError::service(&res, GetAlarmsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetAlarmsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetAlarmsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8146,22 +8514,28 @@ impl GetAutoSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetAutoSnapshotsError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetAutoSnapshotsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAutoSnapshotsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAutoSnapshotsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::Service(err.msg))
+                    return RusotoError::service(&res, GetAutoSnapshotsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAutoSnapshotsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8208,27 +8582,31 @@ impl GetBlueprintsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetBlueprintsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetBlueprintsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetBlueprintsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetBlueprintsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBlueprintsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetBlueprintsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBlueprintsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBlueprintsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetBlueprintsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlueprintsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetBlueprintsError::Service(err.msg))
+                    return RusotoError::service(&res, GetBlueprintsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetBlueprintsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetBlueprintsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8276,25 +8654,28 @@ impl GetBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetBundlesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetBundlesError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetBundlesError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBundlesError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBundlesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetBundlesError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBundlesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBundlesError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetBundlesError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetBundlesError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetBundlesError::Service(err.msg))
+                    return RusotoError::service(&res, GetBundlesError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetBundlesError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetBundlesError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8344,37 +8725,44 @@ impl GetCloudFormationStackRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationStackRecordsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCloudFormationStackRecordsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationStackRecordsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationStackRecordsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCloudFormationStackRecordsError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationStackRecordsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCloudFormationStackRecordsError::Unauthenticated(err.msg),
                     )
                 }
@@ -8428,22 +8816,34 @@ impl GetContactMethodsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContactMethodsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContactMethodsError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContactMethodsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContactMethodsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContactMethodsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetContactMethodsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetContactMethodsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContactMethodsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContactMethodsError::Service(err.msg))
+                    return RusotoError::service(&res, GetContactMethodsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContactMethodsError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContactMethodsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8490,23 +8890,28 @@ impl GetDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDiskError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDiskError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDiskError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetDiskError::OperationFailure(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, GetDiskError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(GetDiskError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetDiskError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8554,27 +8959,34 @@ impl GetDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskSnapshotError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8622,27 +9034,34 @@ impl GetDiskSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskSnapshotsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskSnapshotsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::Service(err.msg))
+                    return RusotoError::service(&res, GetDiskSnapshotsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiskSnapshotsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8690,23 +9109,28 @@ impl GetDisksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDisksError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDisksError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDisksError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDisksError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDisksError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDisksError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDisksError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDisksError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDisksError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetDisksError::OperationFailure(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, GetDisksError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(GetDisksError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDisksError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetDisksError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8754,25 +9178,28 @@ impl GetDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDomainError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDomainError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDomainError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDomainError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDomainError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetDomainError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDomainError::Service(err.msg))
+                    return RusotoError::service(&res, GetDomainError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetDomainError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8820,25 +9247,28 @@ impl GetDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDomainsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDomainsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDomainsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDomainsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDomainsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDomainsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDomainsError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetDomainsError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDomainsError::Service(err.msg))
+                    return RusotoError::service(&res, GetDomainsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDomainsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetDomainsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8886,35 +9316,46 @@ impl GetExportSnapshotRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetExportSnapshotRecordsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetExportSnapshotRecordsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetExportSnapshotRecordsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExportSnapshotRecordsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetExportSnapshotRecordsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExportSnapshotRecordsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetExportSnapshotRecordsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8964,25 +9405,28 @@ impl GetInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9030,35 +9474,46 @@ impl GetInstanceAccessDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessDetailsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetInstanceAccessDetailsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessDetailsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessDetailsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessDetailsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessDetailsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceAccessDetailsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9108,31 +9563,43 @@ impl GetInstanceMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceMetricDataError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetInstanceMetricDataError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceMetricDataError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceMetricDataError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceMetricDataError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstanceMetricDataError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceMetricDataError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9180,31 +9647,43 @@ impl GetInstancePortStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancePortStatesError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetInstancePortStatesError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancePortStatesError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancePortStatesError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancePortStatesError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstancePortStatesError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancePortStatesError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9252,29 +9731,40 @@ impl GetInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceSnapshotError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstanceSnapshotError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9322,31 +9812,40 @@ impl GetInstanceSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceSnapshotsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstanceSnapshotsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceSnapshotsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9394,27 +9893,34 @@ impl GetInstanceStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceStateError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetInstanceStateError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceStateError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceStateError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceStateError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetInstanceStateError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceStateError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceStateError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceStateError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceStateError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceStateError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstanceStateError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceStateError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstanceStateError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9462,25 +9968,28 @@ impl GetInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstancesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetInstancesError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstancesError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancesError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetInstancesError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstancesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetInstancesError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstancesError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetInstancesError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstancesError::Service(err.msg))
+                    return RusotoError::service(&res, GetInstancesError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstancesError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetInstancesError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9528,25 +10037,28 @@ impl GetKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetKeyPairError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetKeyPairError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyPairError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetKeyPairError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetKeyPairError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetKeyPairError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetKeyPairError::Service(err.msg))
+                    return RusotoError::service(&res, GetKeyPairError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetKeyPairError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9594,25 +10106,28 @@ impl GetKeyPairsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKeyPairsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetKeyPairsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetKeyPairsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetKeyPairsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetKeyPairsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetKeyPairsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPairsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetKeyPairsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetKeyPairsError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetKeyPairsError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetKeyPairsError::Service(err.msg))
+                    return RusotoError::service(&res, GetKeyPairsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetKeyPairsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetKeyPairsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9660,27 +10175,34 @@ impl GetLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancerError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetLoadBalancerError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancerError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancerError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerError::Service(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancerError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9728,35 +10250,46 @@ impl GetLoadBalancerMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerMetricDataError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLoadBalancerMetricDataError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerMetricDataError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerMetricDataError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerMetricDataError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerMetricDataError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerMetricDataError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9808,37 +10341,44 @@ impl GetLoadBalancerTlsCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerTlsCertificatesError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLoadBalancerTlsCertificatesError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerTlsCertificatesError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerTlsCertificatesError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLoadBalancerTlsCertificatesError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancerTlsCertificatesError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLoadBalancerTlsCertificatesError::Unauthenticated(err.msg),
                     )
                 }
@@ -9894,27 +10434,34 @@ impl GetLoadBalancersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancersError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancersError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetLoadBalancersError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancersError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancersError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancersError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancersError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancersError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancersError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancersError::Service(err.msg))
+                    return RusotoError::service(&res, GetLoadBalancersError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancersError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLoadBalancersError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9962,25 +10509,28 @@ impl GetOperationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetOperationError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetOperationError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetOperationError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetOperationError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetOperationError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationError::Service(err.msg))
+                    return RusotoError::service(&res, GetOperationError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetOperationError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10028,27 +10578,31 @@ impl GetOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetOperationsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetOperationsError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetOperationsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetOperationsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationsError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationsError::Service(err.msg))
+                    return RusotoError::service(&res, GetOperationsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetOperationsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10096,35 +10650,46 @@ impl GetOperationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetOperationsForResourceError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsForResourceError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsForResourceError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsForResourceError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationsForResourceError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10174,25 +10739,28 @@ impl GetRegionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRegionsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetRegionsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetRegionsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegionsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRegionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetRegionsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRegionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetRegionsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRegionsError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetRegionsError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRegionsError::Service(err.msg))
+                    return RusotoError::service(&res, GetRegionsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRegionsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetRegionsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10240,31 +10808,43 @@ impl GetRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(&res, GetRelationalDatabaseError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10314,37 +10894,44 @@ impl GetRelationalDatabaseBlueprintsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBlueprintsError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBlueprintsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBlueprintsError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBlueprintsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseBlueprintsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBlueprintsError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBlueprintsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseBlueprintsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBlueprintsError::Unauthenticated(err.msg),
                     )
                 }
@@ -10402,37 +10989,44 @@ impl GetRelationalDatabaseBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseBundlesError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBundlesError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseBundlesError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseBundlesError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBundlesError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseBundlesError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseBundlesError::Unauthenticated(err.msg),
                     )
                 }
@@ -10488,37 +11082,46 @@ impl GetRelationalDatabaseEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseEventsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseEventsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseEventsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseEventsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseEventsError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseEventsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseEventsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10570,37 +11173,44 @@ impl GetRelationalDatabaseLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseLogEventsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogEventsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseLogEventsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseLogEventsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogEventsError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseLogEventsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogEventsError::Unauthenticated(err.msg),
                     )
                 }
@@ -10658,37 +11268,44 @@ impl GetRelationalDatabaseLogStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogStreamsError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogStreamsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogStreamsError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogStreamsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseLogStreamsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogStreamsError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogStreamsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseLogStreamsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseLogStreamsError::Unauthenticated(err.msg),
                     )
                 }
@@ -10746,39 +11363,46 @@ impl GetRelationalDatabaseMasterUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::AccountSetupInProgress(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::NotFound(err.msg),
                     )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::Service(err.msg),
                     )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMasterUserPasswordError::Unauthenticated(err.msg),
                     )
                 }
@@ -10844,37 +11468,44 @@ impl GetRelationalDatabaseMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMetricDataError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMetricDataError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMetricDataError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseMetricDataError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseMetricDataError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMetricDataError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseMetricDataError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseMetricDataError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseMetricDataError::Unauthenticated(err.msg),
                     )
                 }
@@ -10932,37 +11563,44 @@ impl GetRelationalDatabaseParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseParametersError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseParametersError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseParametersError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseParametersError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseParametersError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseParametersError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseParametersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseParametersError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseParametersError::Unauthenticated(err.msg),
                     )
                 }
@@ -11020,37 +11658,44 @@ impl GetRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseSnapshotError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
                     )
                 }
@@ -11108,37 +11753,44 @@ impl GetRelationalDatabaseSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseSnapshotsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseSnapshotsError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabaseSnapshotsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabaseSnapshotsError::Unauthenticated(err.msg),
                     )
                 }
@@ -11194,31 +11846,46 @@ impl GetRelationalDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabasesError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRelationalDatabasesError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabasesError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabasesError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabasesError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabasesError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRelationalDatabasesError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11268,25 +11935,28 @@ impl GetStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetStaticIpError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetStaticIpError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetStaticIpError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetStaticIpError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetStaticIpError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetStaticIpError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetStaticIpError::Service(err.msg))
+                    return RusotoError::service(&res, GetStaticIpError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetStaticIpError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11334,25 +12004,28 @@ impl GetStaticIpsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetStaticIpsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetStaticIpsError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetStaticIpsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetStaticIpsError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetStaticIpsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetStaticIpsError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStaticIpsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetStaticIpsError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetStaticIpsError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, GetStaticIpsError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetStaticIpsError::Service(err.msg))
+                    return RusotoError::service(&res, GetStaticIpsError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetStaticIpsError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, GetStaticIpsError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11400,27 +12073,31 @@ impl ImportKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ImportKeyPairError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ImportKeyPairError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyPairError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ImportKeyPairError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportKeyPairError::NotFound(err.msg))
+                    return RusotoError::service(&res, ImportKeyPairError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ImportKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportKeyPairError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ImportKeyPairError::Service(err.msg))
+                    return RusotoError::service(&res, ImportKeyPairError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ImportKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, ImportKeyPairError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11468,25 +12145,28 @@ impl IsVpcPeeredError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(IsVpcPeeredError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, IsVpcPeeredError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(IsVpcPeeredError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        IsVpcPeeredError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(IsVpcPeeredError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, IsVpcPeeredError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(IsVpcPeeredError::NotFound(err.msg))
+                    return RusotoError::service(&res, IsVpcPeeredError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(IsVpcPeeredError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, IsVpcPeeredError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(IsVpcPeeredError::Service(err.msg))
+                    return RusotoError::service(&res, IsVpcPeeredError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(IsVpcPeeredError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, IsVpcPeeredError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11534,35 +12214,46 @@ impl OpenInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        OpenInstancePublicPortsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         OpenInstancePublicPortsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        OpenInstancePublicPortsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        OpenInstancePublicPortsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        OpenInstancePublicPortsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        OpenInstancePublicPortsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        OpenInstancePublicPortsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11612,23 +12303,28 @@ impl PeerVpcError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PeerVpcError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, PeerVpcError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(PeerVpcError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PeerVpcError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PeerVpcError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, PeerVpcError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PeerVpcError::NotFound(err.msg))
+                    return RusotoError::service(&res, PeerVpcError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PeerVpcError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, PeerVpcError::OperationFailure(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, PeerVpcError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(PeerVpcError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PeerVpcError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, PeerVpcError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11674,20 +12370,22 @@ impl PutAlarmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutAlarmError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, PutAlarmError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutAlarmError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, PutAlarmError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutAlarmError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutAlarmError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PutAlarmError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, PutAlarmError::OperationFailure(err.msg))
+                }
+                "ServiceException" => {
+                    return RusotoError::service(&res, PutAlarmError::Service(err.msg))
                 }
-                "ServiceException" => return RusotoError::Service(PutAlarmError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PutAlarmError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, PutAlarmError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11734,31 +12432,46 @@ impl PutInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInstancePublicPortsError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInstancePublicPortsError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInstancePublicPortsError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInstancePublicPortsError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInstancePublicPortsError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInstancePublicPortsError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInstancePublicPortsError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11808,27 +12521,34 @@ impl RebootInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RebootInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, RebootInstanceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(RebootInstanceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootInstanceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RebootInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, RebootInstanceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, RebootInstanceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(RebootInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootInstanceError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RebootInstanceError::Service(err.msg))
+                    return RusotoError::service(&res, RebootInstanceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RebootInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootInstanceError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11876,35 +12596,46 @@ impl RebootRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RebootRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootRelationalDatabaseError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RebootRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11954,27 +12685,34 @@ impl ReleaseStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ReleaseStaticIpError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ReleaseStaticIpError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ReleaseStaticIpError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::NotFound(err.msg))
+                    return RusotoError::service(&res, ReleaseStaticIpError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReleaseStaticIpError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::Service(err.msg))
+                    return RusotoError::service(&res, ReleaseStaticIpError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ReleaseStaticIpError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12022,32 +12760,38 @@ impl SendContactMethodVerificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendContactMethodVerificationError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendContactMethodVerificationError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendContactMethodVerificationError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SendContactMethodVerificationError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendContactMethodVerificationError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SendContactMethodVerificationError::Unauthenticated(err.msg),
                     )
                 }
@@ -12100,27 +12844,31 @@ impl StartInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StartInstanceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(StartInstanceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartInstanceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StartInstanceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartInstanceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StartInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartInstanceError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartInstanceError::Service(err.msg))
+                    return RusotoError::service(&res, StartInstanceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StartInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, StartInstanceError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12168,35 +12916,46 @@ impl StartRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartRelationalDatabaseError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12246,25 +13005,28 @@ impl StopInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(StopInstanceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopInstanceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopInstanceError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StopInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StopInstanceError::Service(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StopInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12312,31 +13074,46 @@ impl StopRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopRelationalDatabaseError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12386,25 +13163,28 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(TagResourceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(TagResourceError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12450,22 +13230,22 @@ impl TestAlarmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TestAlarmError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TestAlarmError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TestAlarmError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TestAlarmError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestAlarmError::NotFound(err.msg))
+                    return RusotoError::service(&res, TestAlarmError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(TestAlarmError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, TestAlarmError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TestAlarmError::Service(err.msg))
+                    return RusotoError::service(&res, TestAlarmError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(TestAlarmError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, TestAlarmError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12512,25 +13292,28 @@ impl UnpeerVpcError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UnpeerVpcError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UnpeerVpcError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UnpeerVpcError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnpeerVpcError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UnpeerVpcError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UnpeerVpcError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UnpeerVpcError::NotFound(err.msg))
+                    return RusotoError::service(&res, UnpeerVpcError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UnpeerVpcError::OperationFailure(err.msg))
+                    return RusotoError::service(&res, UnpeerVpcError::OperationFailure(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UnpeerVpcError::Service(err.msg))
+                    return RusotoError::service(&res, UnpeerVpcError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UnpeerVpcError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, UnpeerVpcError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12578,27 +13361,31 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UntagResourceError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UntagResourceError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthenticated(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Unauthenticated(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12646,27 +13433,40 @@ impl UpdateDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainEntryError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::AccountSetupInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainEntryError::AccountSetupInProgress(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainEntryError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDomainEntryError::NotFound(err.msg))
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainEntryError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::Service(err.msg))
+                    return RusotoError::service(&res, UpdateDomainEntryError::Service(err.msg))
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainEntryError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12716,37 +13516,46 @@ impl UpdateLoadBalancerAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLoadBalancerAttributeError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLoadBalancerAttributeError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLoadBalancerAttributeError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLoadBalancerAttributeError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateLoadBalancerAttributeError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLoadBalancerAttributeError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLoadBalancerAttributeError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12796,35 +13605,46 @@ impl UpdateRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRelationalDatabaseError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseError::InvalidInput(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::OperationFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseError::OperationFailure(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::Unauthenticated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseError::Unauthenticated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12876,37 +13696,44 @@ impl UpdateRelationalDatabaseParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRelationalDatabaseParametersError::AccessDenied(err.msg),
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRelationalDatabaseParametersError::AccountSetupInProgress(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRelationalDatabaseParametersError::InvalidInput(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseParametersError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseParametersError::NotFound(err.msg),
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRelationalDatabaseParametersError::OperationFailure(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseParametersError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRelationalDatabaseParametersError::Service(err.msg),
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRelationalDatabaseParametersError::Unauthenticated(err.msg),
                     )
                 }
@@ -13679,6 +14506,7 @@ impl LightsailClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -13694,6 +14522,7 @@ impl LightsailClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> LightsailClient {
         LightsailClient { client, region }
     }
@@ -13707,6 +14536,9 @@ impl Lightsail for LightsailClient {
         input: AllocateStaticIpRequest,
     ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AllocateStaticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.AllocateStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13725,6 +14557,9 @@ impl Lightsail for LightsailClient {
         input: AttachDiskRequest,
     ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachDisk");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.AttachDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13744,6 +14579,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachInstancesToLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.AttachInstancesToLoadBalancer",
@@ -13769,6 +14607,9 @@ impl Lightsail for LightsailClient {
         RusotoError<AttachLoadBalancerTlsCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachLoadBalancerTlsCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.AttachLoadBalancerTlsCertificate",
@@ -13794,6 +14635,9 @@ impl Lightsail for LightsailClient {
         input: AttachStaticIpRequest,
     ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachStaticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.AttachStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13812,6 +14656,9 @@ impl Lightsail for LightsailClient {
         input: CloseInstancePublicPortsRequest,
     ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CloseInstancePublicPorts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CloseInstancePublicPorts",
@@ -13834,6 +14681,9 @@ impl Lightsail for LightsailClient {
         input: CopySnapshotRequest,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CopySnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CopySnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13852,6 +14702,9 @@ impl Lightsail for LightsailClient {
         input: CreateCloudFormationStackRequest,
     ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCloudFormationStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateCloudFormationStack",
@@ -13874,6 +14727,9 @@ impl Lightsail for LightsailClient {
         input: CreateContactMethodRequest,
     ) -> Result<CreateContactMethodResult, RusotoError<CreateContactMethodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateContactMethod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateContactMethod");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13892,6 +14748,9 @@ impl Lightsail for LightsailClient {
         input: CreateDiskRequest,
     ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDisk");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13910,6 +14769,9 @@ impl Lightsail for LightsailClient {
         input: CreateDiskFromSnapshotRequest,
     ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDiskFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskFromSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13929,6 +14791,9 @@ impl Lightsail for LightsailClient {
         input: CreateDiskSnapshotRequest,
     ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDiskSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13947,6 +14812,9 @@ impl Lightsail for LightsailClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13965,6 +14833,9 @@ impl Lightsail for LightsailClient {
         input: CreateDomainEntryRequest,
     ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDomainEntry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13983,6 +14854,9 @@ impl Lightsail for LightsailClient {
         input: CreateInstanceSnapshotRequest,
     ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateInstanceSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14002,6 +14876,9 @@ impl Lightsail for LightsailClient {
         input: CreateInstancesRequest,
     ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14021,6 +14898,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateInstancesFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateInstancesFromSnapshot",
@@ -14043,6 +14923,9 @@ impl Lightsail for LightsailClient {
         input: CreateKeyPairRequest,
     ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14061,6 +14944,9 @@ impl Lightsail for LightsailClient {
         input: CreateLoadBalancerRequest,
     ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.CreateLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14082,6 +14968,9 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateLoadBalancerTlsCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLoadBalancerTlsCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateLoadBalancerTlsCertificate",
@@ -14107,6 +14996,9 @@ impl Lightsail for LightsailClient {
         input: CreateRelationalDatabaseRequest,
     ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRelationalDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateRelationalDatabase",
@@ -14132,6 +15024,9 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateRelationalDatabaseFromSnapshotError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRelationalDatabaseFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateRelationalDatabaseFromSnapshot",
@@ -14160,6 +15055,9 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateRelationalDatabaseSnapshotError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRelationalDatabaseSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateRelationalDatabaseSnapshot",
@@ -14185,6 +15083,9 @@ impl Lightsail for LightsailClient {
         input: DeleteAlarmRequest,
     ) -> Result<DeleteAlarmResult, RusotoError<DeleteAlarmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAlarm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteAlarm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14203,6 +15104,9 @@ impl Lightsail for LightsailClient {
         input: DeleteAutoSnapshotRequest,
     ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAutoSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteAutoSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14221,6 +15125,9 @@ impl Lightsail for LightsailClient {
         input: DeleteContactMethodRequest,
     ) -> Result<DeleteContactMethodResult, RusotoError<DeleteContactMethodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteContactMethod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteContactMethod");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14239,6 +15146,9 @@ impl Lightsail for LightsailClient {
         input: DeleteDiskRequest,
     ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDisk");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14257,6 +15167,9 @@ impl Lightsail for LightsailClient {
         input: DeleteDiskSnapshotRequest,
     ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDiskSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14275,6 +15188,9 @@ impl Lightsail for LightsailClient {
         input: DeleteDomainRequest,
     ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14293,6 +15209,9 @@ impl Lightsail for LightsailClient {
         input: DeleteDomainEntryRequest,
     ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDomainEntry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14311,6 +15230,9 @@ impl Lightsail for LightsailClient {
         input: DeleteInstanceRequest,
     ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14329,6 +15251,9 @@ impl Lightsail for LightsailClient {
         input: DeleteInstanceSnapshotRequest,
     ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteInstanceSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14348,6 +15273,9 @@ impl Lightsail for LightsailClient {
         input: DeleteKeyPairRequest,
     ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14366,6 +15294,9 @@ impl Lightsail for LightsailClient {
         input: DeleteKnownHostKeysRequest,
     ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteKnownHostKeys");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKnownHostKeys");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14384,6 +15315,9 @@ impl Lightsail for LightsailClient {
         input: DeleteLoadBalancerRequest,
     ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14405,6 +15339,9 @@ impl Lightsail for LightsailClient {
         RusotoError<DeleteLoadBalancerTlsCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLoadBalancerTlsCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DeleteLoadBalancerTlsCertificate",
@@ -14430,6 +15367,9 @@ impl Lightsail for LightsailClient {
         input: DeleteRelationalDatabaseRequest,
     ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRelationalDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DeleteRelationalDatabase",
@@ -14455,6 +15395,9 @@ impl Lightsail for LightsailClient {
         RusotoError<DeleteRelationalDatabaseSnapshotError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRelationalDatabaseSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DeleteRelationalDatabaseSnapshot",
@@ -14480,6 +15423,9 @@ impl Lightsail for LightsailClient {
         input: DetachDiskRequest,
     ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetachDisk");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DetachDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14501,6 +15447,9 @@ impl Lightsail for LightsailClient {
         RusotoError<DetachInstancesFromLoadBalancerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetachInstancesFromLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DetachInstancesFromLoadBalancer",
@@ -14523,6 +15472,9 @@ impl Lightsail for LightsailClient {
         input: DetachStaticIpRequest,
     ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetachStaticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DetachStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14541,6 +15493,9 @@ impl Lightsail for LightsailClient {
         input: DisableAddOnRequest,
     ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableAddOn");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DisableAddOn");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14558,6 +15513,9 @@ impl Lightsail for LightsailClient {
         &self,
     ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DownloadDefaultKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.DownloadDefaultKeyPair");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -14576,6 +15534,9 @@ impl Lightsail for LightsailClient {
         input: EnableAddOnRequest,
     ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableAddOn");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.EnableAddOn");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14594,6 +15555,9 @@ impl Lightsail for LightsailClient {
         input: ExportSnapshotRequest,
     ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExportSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.ExportSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14612,6 +15576,9 @@ impl Lightsail for LightsailClient {
         input: GetActiveNamesRequest,
     ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetActiveNames");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetActiveNames");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14630,6 +15597,9 @@ impl Lightsail for LightsailClient {
         input: GetAlarmsRequest,
     ) -> Result<GetAlarmsResult, RusotoError<GetAlarmsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAlarms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetAlarms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14648,6 +15618,9 @@ impl Lightsail for LightsailClient {
         input: GetAutoSnapshotsRequest,
     ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAutoSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetAutoSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14666,6 +15639,9 @@ impl Lightsail for LightsailClient {
         input: GetBlueprintsRequest,
     ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetBlueprints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetBlueprints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14684,6 +15660,9 @@ impl Lightsail for LightsailClient {
         input: GetBundlesRequest,
     ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetBundles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetBundles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14703,6 +15682,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCloudFormationStackRecords");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetCloudFormationStackRecords",
@@ -14725,6 +15707,9 @@ impl Lightsail for LightsailClient {
         input: GetContactMethodsRequest,
     ) -> Result<GetContactMethodsResult, RusotoError<GetContactMethodsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetContactMethods");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetContactMethods");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14743,6 +15728,9 @@ impl Lightsail for LightsailClient {
         input: GetDiskRequest,
     ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDisk");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14761,6 +15749,9 @@ impl Lightsail for LightsailClient {
         input: GetDiskSnapshotRequest,
     ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDiskSnapshot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14779,6 +15770,9 @@ impl Lightsail for LightsailClient {
         input: GetDiskSnapshotsRequest,
     ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDiskSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14797,6 +15791,9 @@ impl Lightsail for LightsailClient {
         input: GetDisksRequest,
     ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDisks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14815,6 +15812,9 @@ impl Lightsail for LightsailClient {
         input: GetDomainRequest,
     ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14833,6 +15833,9 @@ impl Lightsail for LightsailClient {
         input: GetDomainsRequest,
     ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14851,6 +15854,9 @@ impl Lightsail for LightsailClient {
         input: GetExportSnapshotRecordsRequest,
     ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetExportSnapshotRecords");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetExportSnapshotRecords",
@@ -14873,6 +15879,9 @@ impl Lightsail for LightsailClient {
         input: GetInstanceRequest,
     ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14891,6 +15900,9 @@ impl Lightsail for LightsailClient {
         input: GetInstanceAccessDetailsRequest,
     ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceAccessDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetInstanceAccessDetails",
@@ -14913,6 +15925,9 @@ impl Lightsail for LightsailClient {
         input: GetInstanceMetricDataRequest,
     ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceMetricData");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14931,6 +15946,9 @@ impl Lightsail for LightsailClient {
         input: GetInstancePortStatesRequest,
     ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstancePortStates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstancePortStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14949,6 +15967,9 @@ impl Lightsail for LightsailClient {
         input: GetInstanceSnapshotRequest,
     ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceSnapshot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14967,6 +15988,9 @@ impl Lightsail for LightsailClient {
         input: GetInstanceSnapshotsRequest,
     ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14985,6 +16009,9 @@ impl Lightsail for LightsailClient {
         input: GetInstanceStateRequest,
     ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstanceState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15003,6 +16030,9 @@ impl Lightsail for LightsailClient {
         input: GetInstancesRequest,
     ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15021,6 +16051,9 @@ impl Lightsail for LightsailClient {
         input: GetKeyPairRequest,
     ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetKeyPair");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15039,6 +16072,9 @@ impl Lightsail for LightsailClient {
         input: GetKeyPairsRequest,
     ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetKeyPairs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPairs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15057,6 +16093,9 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancerRequest,
     ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLoadBalancer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15075,6 +16114,9 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancerMetricDataRequest,
     ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLoadBalancerMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetLoadBalancerMetricData",
@@ -15100,6 +16142,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetLoadBalancerTlsCertificatesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLoadBalancerTlsCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetLoadBalancerTlsCertificates",
@@ -15122,6 +16167,9 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancersRequest,
     ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLoadBalancers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15140,6 +16188,9 @@ impl Lightsail for LightsailClient {
         input: GetOperationRequest,
     ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOperation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15158,6 +16209,9 @@ impl Lightsail for LightsailClient {
         input: GetOperationsRequest,
     ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15176,6 +16230,9 @@ impl Lightsail for LightsailClient {
         input: GetOperationsForResourceRequest,
     ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOperationsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetOperationsForResource",
@@ -15198,6 +16255,9 @@ impl Lightsail for LightsailClient {
         input: GetRegionsRequest,
     ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRegions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetRegions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15216,6 +16276,9 @@ impl Lightsail for LightsailClient {
         input: GetRelationalDatabaseRequest,
     ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabase");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15237,6 +16300,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseBlueprintsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseBlueprints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseBlueprints",
@@ -15260,6 +16326,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseBundles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseBundles",
@@ -15283,6 +16352,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseEvents",
@@ -15308,6 +16380,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseLogEventsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseLogEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseLogEvents",
@@ -15333,6 +16408,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseLogStreamsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseLogStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseLogStreams",
@@ -15358,6 +16436,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseMasterUserPassword");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseMasterUserPassword",
@@ -15386,6 +16467,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseMetricDataError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseMetricData");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseMetricData",
@@ -15411,6 +16495,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseParameters",
@@ -15434,6 +16521,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseSnapshot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseSnapshot",
@@ -15459,6 +16549,9 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseSnapshotsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabaseSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseSnapshots",
@@ -15481,6 +16574,9 @@ impl Lightsail for LightsailClient {
         input: GetRelationalDatabasesRequest,
     ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRelationalDatabases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15500,6 +16596,9 @@ impl Lightsail for LightsailClient {
         input: GetStaticIpRequest,
     ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetStaticIp");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15518,6 +16617,9 @@ impl Lightsail for LightsailClient {
         input: GetStaticIpsRequest,
     ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetStaticIps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15536,6 +16638,9 @@ impl Lightsail for LightsailClient {
         input: ImportKeyPairRequest,
     ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportKeyPair");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.ImportKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15551,6 +16656,9 @@ impl Lightsail for LightsailClient {
     /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
     async fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("IsVpcPeered");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.IsVpcPeered");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -15568,6 +16676,9 @@ impl Lightsail for LightsailClient {
         input: OpenInstancePublicPortsRequest,
     ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("OpenInstancePublicPorts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.OpenInstancePublicPorts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15584,6 +16695,9 @@ impl Lightsail for LightsailClient {
     /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
     async fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PeerVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.PeerVpc");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -15601,6 +16715,9 @@ impl Lightsail for LightsailClient {
         input: PutAlarmRequest,
     ) -> Result<PutAlarmResult, RusotoError<PutAlarmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAlarm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.PutAlarm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15619,6 +16736,9 @@ impl Lightsail for LightsailClient {
         input: PutInstancePublicPortsRequest,
     ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutInstancePublicPorts");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.PutInstancePublicPorts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15638,6 +16758,9 @@ impl Lightsail for LightsailClient {
         input: RebootInstanceRequest,
     ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebootInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.RebootInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15656,6 +16779,9 @@ impl Lightsail for LightsailClient {
         input: RebootRelationalDatabaseRequest,
     ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebootRelationalDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.RebootRelationalDatabase",
@@ -15678,6 +16804,9 @@ impl Lightsail for LightsailClient {
         input: ReleaseStaticIpRequest,
     ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ReleaseStaticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.ReleaseStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15697,6 +16826,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<SendContactMethodVerificationResult, RusotoError<SendContactMethodVerificationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendContactMethodVerification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.SendContactMethodVerification",
@@ -15719,6 +16851,9 @@ impl Lightsail for LightsailClient {
         input: StartInstanceRequest,
     ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.StartInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15737,6 +16872,9 @@ impl Lightsail for LightsailClient {
         input: StartRelationalDatabaseRequest,
     ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartRelationalDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.StartRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15756,6 +16894,9 @@ impl Lightsail for LightsailClient {
         input: StopInstanceRequest,
     ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.StopInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15774,6 +16915,9 @@ impl Lightsail for LightsailClient {
         input: StopRelationalDatabaseRequest,
     ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopRelationalDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.StopRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15793,6 +16937,9 @@ impl Lightsail for LightsailClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15811,6 +16958,9 @@ impl Lightsail for LightsailClient {
         input: TestAlarmRequest,
     ) -> Result<TestAlarmResult, RusotoError<TestAlarmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TestAlarm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.TestAlarm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15826,6 +16976,9 @@ impl Lightsail for LightsailClient {
     /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
     async fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnpeerVpc");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.UnpeerVpc");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -15843,6 +16996,9 @@ impl Lightsail for LightsailClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15861,6 +17017,9 @@ impl Lightsail for LightsailClient {
         input: UpdateDomainEntryRequest,
     ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDomainEntry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Lightsail_20161128.UpdateDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15880,6 +17039,9 @@ impl Lightsail for LightsailClient {
     ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateLoadBalancerAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateLoadBalancerAttribute",
@@ -15902,6 +17064,9 @@ impl Lightsail for LightsailClient {
         input: UpdateRelationalDatabaseRequest,
     ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRelationalDatabase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateRelationalDatabase",
@@ -15927,6 +17092,9 @@ impl Lightsail for LightsailClient {
         RusotoError<UpdateRelationalDatabaseParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRelationalDatabaseParameters");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateRelationalDatabaseParameters",
@@ -15946,3 +17114,1291 @@ impl Lightsail for LightsailClient {
             .deserialize::<UpdateRelationalDatabaseParametersResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Lightsail API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `LightsailClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LightsailBlockingClient {
+    client: LightsailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LightsailBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> LightsailBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        LightsailBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LightsailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LightsailBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> LightsailBlockingClient {
+        LightsailBlockingClient {
+            client: LightsailClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Allocates a static IP address.</p>
+    ///
+    /// See `Lightsail::allocate_static_ip`.
+    pub fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
+        self.runtime.block_on(self.client.allocate_static_ip(input))
+    }
+
+    /// <p>Attaches a block storage disk to a running or stopped Lightsail instance and exposes it to the instance with the specified disk name.</p> <p>The <code>attach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::attach_disk`.
+    pub fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
+        self.runtime.block_on(self.client.attach_disk(input))
+    }
+
+    /// <p>Attaches one or more Lightsail instances to a load balancer.</p> <p>After some time, the instances are attached to the load balancer and the health check status is available.</p> <p>The <code>attach instances to load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::attach_instances_to_load_balancer`.
+    pub fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
+    {
+        self.runtime
+            .block_on(self.client.attach_instances_to_load_balancer(input))
+    }
+
+    /// <p>Attaches a Transport Layer Security (TLS) certificate to your load balancer. TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>Once you create and validate your certificate, you can attach it to your load balancer. You can also use this API to rotate the certificates on your account. Use the <code>AttachLoadBalancerTlsCertificate</code> action with the non-attached certificate, and it will replace the existing one and become the attached certificate.</p> <p>The <code>AttachLoadBalancerTlsCertificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::attach_load_balancer_tls_certificate`.
+    pub fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        AttachLoadBalancerTlsCertificateResult,
+        RusotoError<AttachLoadBalancerTlsCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.attach_load_balancer_tls_certificate(input))
+    }
+
+    /// <p>Attaches a static IP address to a specific Amazon Lightsail instance.</p>
+    ///
+    /// See `Lightsail::attach_static_ip`.
+    pub fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
+        self.runtime.block_on(self.client.attach_static_ip(input))
+    }
+
+    /// <p>Closes ports for a specific Amazon Lightsail instance.</p> <p>The <code>CloseInstancePublicPorts</code> action supports tag-based access control via resource tags applied to the resource identified by <code>instanceName</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::close_instance_public_ports`.
+    pub fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
+        self.runtime
+            .block_on(self.client.close_instance_public_ports(input))
+    }
+
+    /// <p>Copies a manual snapshot of an instance or disk as another manual snapshot, or copies an automatic snapshot of an instance or disk as a manual snapshot. This operation can also be used to copy a manual or automatic snapshot of an instance or a disk from one AWS Region to another in Amazon Lightsail.</p> <p>When copying a <i>manual snapshot</i>, be sure to define the <code>source region</code>, <code>source snapshot name</code>, and <code>target snapshot name</code> parameters.</p> <p>When copying an <i>automatic snapshot</i>, be sure to define the <code>source region</code>, <code>source resource name</code>, <code>target snapshot name</code>, and either the <code>restore date</code> or the <code>use latest restorable auto snapshot</code> parameters.</p>
+    ///
+    /// See `Lightsail::copy_snapshot`.
+    pub fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.runtime.block_on(self.client.copy_snapshot(input))
+    }
+
+    /// <p><p>Creates an AWS CloudFormation stack, which creates a new Amazon EC2 instance from an exported Amazon Lightsail snapshot. This operation results in a CloudFormation stack record that can be used to track the AWS CloudFormation stack created. Use the <code>get cloud formation stack records</code> operation to get a list of the CloudFormation stacks created.</p> <important> <p>Wait until after your new Amazon EC2 instance is created before running the <code>create cloud formation stack</code> operation again with the same export snapshot record.</p> </important></p>
+    ///
+    /// See `Lightsail::create_cloud_formation_stack`.
+    pub fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
+        self.runtime
+            .block_on(self.client.create_cloud_formation_stack(input))
+    }
+
+    /// <p>Creates an email or SMS text message contact method.</p> <p>A contact method is used to send you notifications about your Amazon Lightsail resources. You can add one email address and one mobile phone number contact method in each AWS Region. However, SMS text messaging is not supported in some AWS Regions, and SMS text messages cannot be sent to some countries/regions. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-notifications">Notifications in Amazon Lightsail</a>.</p>
+    ///
+    /// See `Lightsail::create_contact_method`.
+    pub fn create_contact_method(
+        &self,
+        input: CreateContactMethodRequest,
+    ) -> Result<CreateContactMethodResult, RusotoError<CreateContactMethodError>> {
+        self.runtime
+            .block_on(self.client.create_contact_method(input))
+    }
+
+    /// <p>Creates a block storage disk that can be attached to an Amazon Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>).</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_disk`.
+    pub fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
+        self.runtime.block_on(self.client.create_disk(input))
+    }
+
+    /// <p>Creates a block storage disk from a manual or automatic snapshot of a disk. The resulting disk can be attached to an Amazon Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>).</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>disk snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_disk_from_snapshot`.
+    pub fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_disk_from_snapshot(input))
+    }
+
+    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_disk_snapshot`.
+    pub fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_disk_snapshot(input))
+    }
+
+    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_domain`.
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_domain_entry`.
+    pub fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
+        self.runtime
+            .block_on(self.client.create_domain_entry(input))
+    }
+
+    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_instance_snapshot`.
+    pub fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_instance_snapshot(input))
+    }
+
+    /// <p>Creates one or more Amazon Lightsail instances.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_instances`.
+    pub fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
+        self.runtime.block_on(self.client.create_instances(input))
+    }
+
+    /// <p>Creates one or more new instances from a manual or automatic snapshot of an instance.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>instance snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_instances_from_snapshot`.
+    pub fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.create_instances_from_snapshot(input))
+    }
+
+    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_key_pair`.
+    pub fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
+        self.runtime.block_on(self.client.create_key_pair(input))
+    }
+
+    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_load_balancer`.
+    pub fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer(input))
+    }
+
+    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>CreateLoadBalancerTlsCertificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_load_balancer_tls_certificate`.
+    pub fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        CreateLoadBalancerTlsCertificateResult,
+        RusotoError<CreateLoadBalancerTlsCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_load_balancer_tls_certificate(input))
+    }
+
+    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_relational_database`.
+    pub fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.create_relational_database(input))
+    }
+
+    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_relational_database_from_snapshot`.
+    pub fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> Result<
+        CreateRelationalDatabaseFromSnapshotResult,
+        RusotoError<CreateRelationalDatabaseFromSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_relational_database_from_snapshot(input))
+    }
+
+    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::create_relational_database_snapshot`.
+    pub fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        CreateRelationalDatabaseSnapshotResult,
+        RusotoError<CreateRelationalDatabaseSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_relational_database_snapshot(input))
+    }
+
+    /// <p>Deletes an alarm.</p> <p>An alarm is used to monitor a single metric for one of your resources. When a metric condition is met, the alarm can notify you by email, SMS text message, and a banner displayed on the Amazon Lightsail console. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-alarms">Alarms in Amazon Lightsail</a>.</p>
+    ///
+    /// See `Lightsail::delete_alarm`.
+    pub fn delete_alarm(
+        &self,
+        input: DeleteAlarmRequest,
+    ) -> Result<DeleteAlarmResult, RusotoError<DeleteAlarmError>> {
+        self.runtime.block_on(self.client.delete_alarm(input))
+    }
+
+    /// <p>Deletes an automatic snapshot of an instance or disk. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_auto_snapshot`.
+    pub fn delete_auto_snapshot(
+        &self,
+        input: DeleteAutoSnapshotRequest,
+    ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_auto_snapshot(input))
+    }
+
+    /// <p>Deletes a contact method.</p> <p>A contact method is used to send you notifications about your Amazon Lightsail resources. You can add one email address and one mobile phone number contact method in each AWS Region. However, SMS text messaging is not supported in some AWS Regions, and SMS text messages cannot be sent to some countries/regions. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-notifications">Notifications in Amazon Lightsail</a>.</p>
+    ///
+    /// See `Lightsail::delete_contact_method`.
+    pub fn delete_contact_method(
+        &self,
+        input: DeleteContactMethodRequest,
+    ) -> Result<DeleteContactMethodResult, RusotoError<DeleteContactMethodError>> {
+        self.runtime
+            .block_on(self.client.delete_contact_method(input))
+    }
+
+    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_disk`.
+    pub fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
+        self.runtime.block_on(self.client.delete_disk(input))
+    }
+
+    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_disk_snapshot`.
+    pub fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_disk_snapshot(input))
+    }
+
+    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_domain`.
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_domain_entry`.
+    pub fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
+        self.runtime
+            .block_on(self.client.delete_domain_entry(input))
+    }
+
+    /// <p>Deletes an Amazon Lightsail instance.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_instance`.
+    pub fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
+        self.runtime.block_on(self.client.delete_instance(input))
+    }
+
+    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_instance_snapshot`.
+    pub fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_instance_snapshot(input))
+    }
+
+    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>key pair name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_key_pair`.
+    pub fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
+        self.runtime.block_on(self.client.delete_key_pair(input))
+    }
+
+    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
+    ///
+    /// See `Lightsail::delete_known_host_keys`.
+    pub fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
+        self.runtime
+            .block_on(self.client.delete_known_host_keys(input))
+    }
+
+    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_load_balancer`.
+    pub fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer(input))
+    }
+
+    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>DeleteLoadBalancerTlsCertificate</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_load_balancer_tls_certificate`.
+    pub fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        DeleteLoadBalancerTlsCertificateResult,
+        RusotoError<DeleteLoadBalancerTlsCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_load_balancer_tls_certificate(input))
+    }
+
+    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_relational_database`.
+    pub fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.delete_relational_database(input))
+    }
+
+    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::delete_relational_database_snapshot`.
+    pub fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        DeleteRelationalDatabaseSnapshotResult,
+        RusotoError<DeleteRelationalDatabaseSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_relational_database_snapshot(input))
+    }
+
+    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>disk name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::detach_disk`.
+    pub fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
+        self.runtime.block_on(self.client.detach_disk(input))
+    }
+
+    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::detach_instances_from_load_balancer`.
+    pub fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> Result<
+        DetachInstancesFromLoadBalancerResult,
+        RusotoError<DetachInstancesFromLoadBalancerError>,
+    > {
+        self.runtime
+            .block_on(self.client.detach_instances_from_load_balancer(input))
+    }
+
+    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
+    ///
+    /// See `Lightsail::detach_static_ip`.
+    pub fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
+        self.runtime.block_on(self.client.detach_static_ip(input))
+    }
+
+    /// <p>Disables an add-on for an Amazon Lightsail resource. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::disable_add_on`.
+    pub fn disable_add_on(
+        &self,
+        input: DisableAddOnRequest,
+    ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
+        self.runtime.block_on(self.client.disable_add_on(input))
+    }
+
+    /// <p>Downloads the default SSH key pair from the user's account.</p>
+    ///
+    /// See `Lightsail::download_default_key_pair`.
+    pub fn download_default_key_pair(
+        &self,
+    ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
+        self.runtime
+            .block_on(self.client.download_default_key_pair())
+    }
+
+    /// <p>Enables or modifies an add-on for an Amazon Lightsail resource. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::enable_add_on`.
+    pub fn enable_add_on(
+        &self,
+        input: EnableAddOnRequest,
+    ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
+        self.runtime.block_on(self.client.enable_add_on(input))
+    }
+
+    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>source snapshot name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
+    ///
+    /// See `Lightsail::export_snapshot`.
+    pub fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
+        self.runtime.block_on(self.client.export_snapshot(input))
+    }
+
+    /// <p>Returns the names of all active (not deleted) resources.</p>
+    ///
+    /// See `Lightsail::get_active_names`.
+    pub fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
+        self.runtime.block_on(self.client.get_active_names(input))
+    }
+
+    /// <p>Returns information about the configured alarms. Specify an alarm name in your request to return information about a specific alarm, or specify a monitored resource name to return information about all alarms for a specific resource.</p> <p>An alarm is used to monitor a single metric for one of your resources. When a metric condition is met, the alarm can notify you by email, SMS text message, and a banner displayed on the Amazon Lightsail console. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-alarms">Alarms in Amazon Lightsail</a>.</p>
+    ///
+    /// See `Lightsail::get_alarms`.
+    pub fn get_alarms(
+        &self,
+        input: GetAlarmsRequest,
+    ) -> Result<GetAlarmsResult, RusotoError<GetAlarmsError>> {
+        self.runtime.block_on(self.client.get_alarms(input))
+    }
+
+    /// <p>Returns the available automatic snapshots for an instance or disk. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-configuring-automatic-snapshots">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::get_auto_snapshots`.
+    pub fn get_auto_snapshots(
+        &self,
+        input: GetAutoSnapshotsRequest,
+    ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
+        self.runtime.block_on(self.client.get_auto_snapshots(input))
+    }
+
+    /// <p><p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new instance already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p> <note> <p>Use active blueprints when creating new instances. Inactive blueprints are listed to support customers with existing instances and are not necessarily available to create new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases.</p> </note></p>
+    ///
+    /// See `Lightsail::get_blueprints`.
+    pub fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
+        self.runtime.block_on(self.client.get_blueprints(input))
+    }
+
+    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
+    ///
+    /// See `Lightsail::get_bundles`.
+    pub fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
+        self.runtime.block_on(self.client.get_bundles(input))
+    }
+
+    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
+    ///
+    /// See `Lightsail::get_cloud_formation_stack_records`.
+    pub fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_cloud_formation_stack_records(input))
+    }
+
+    /// <p>Returns information about the configured contact methods. Specify a protocol in your request to return information about a specific contact method.</p> <p>A contact method is used to send you notifications about your Amazon Lightsail resources. You can add one email address and one mobile phone number contact method in each AWS Region. However, SMS text messaging is not supported in some AWS Regions, and SMS text messages cannot be sent to some countries/regions. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-notifications">Notifications in Amazon Lightsail</a>.</p>
+    ///
+    /// See `Lightsail::get_contact_methods`.
+    pub fn get_contact_methods(
+        &self,
+        input: GetContactMethodsRequest,
+    ) -> Result<GetContactMethodsResult, RusotoError<GetContactMethodsError>> {
+        self.runtime
+            .block_on(self.client.get_contact_methods(input))
+    }
+
+    /// <p>Returns information about a specific block storage disk.</p>
+    ///
+    /// See `Lightsail::get_disk`.
+    pub fn get_disk(
+        &self,
+        input: GetDiskRequest,
+    ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
+        self.runtime.block_on(self.client.get_disk(input))
+    }
+
+    /// <p>Returns information about a specific block storage disk snapshot.</p>
+    ///
+    /// See `Lightsail::get_disk_snapshot`.
+    pub fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
+        self.runtime.block_on(self.client.get_disk_snapshot(input))
+    }
+
+    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p>
+    ///
+    /// See `Lightsail::get_disk_snapshots`.
+    pub fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
+        self.runtime.block_on(self.client.get_disk_snapshots(input))
+    }
+
+    /// <p>Returns information about all block storage disks in your AWS account and region.</p>
+    ///
+    /// See `Lightsail::get_disks`.
+    pub fn get_disks(
+        &self,
+        input: GetDisksRequest,
+    ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
+        self.runtime.block_on(self.client.get_disks(input))
+    }
+
+    /// <p>Returns information about a specific domain recordset.</p>
+    ///
+    /// See `Lightsail::get_domain`.
+    pub fn get_domain(
+        &self,
+        input: GetDomainRequest,
+    ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
+        self.runtime.block_on(self.client.get_domain(input))
+    }
+
+    /// <p>Returns a list of all domains in the user's account.</p>
+    ///
+    /// See `Lightsail::get_domains`.
+    pub fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
+        self.runtime.block_on(self.client.get_domains(input))
+    }
+
+    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
+    ///
+    /// See `Lightsail::get_export_snapshot_records`.
+    pub fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
+        self.runtime
+            .block_on(self.client.get_export_snapshot_records(input))
+    }
+
+    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
+    ///
+    /// See `Lightsail::get_instance`.
+    pub fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
+        self.runtime.block_on(self.client.get_instance(input))
+    }
+
+    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::get_instance_access_details`.
+    pub fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_instance_access_details(input))
+    }
+
+    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p> <p>Metrics report the utilization of your resources, and the error counts generated by them. Monitor and collect metric data regularly to maintain the reliability, availability, and performance of your resources.</p>
+    ///
+    /// See `Lightsail::get_instance_metric_data`.
+    pub fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_instance_metric_data(input))
+    }
+
+    /// <p>Returns the firewall port states for a specific Amazon Lightsail instance, the IP addresses allowed to connect to the instance through the ports, and the protocol.</p>
+    ///
+    /// See `Lightsail::get_instance_port_states`.
+    pub fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
+        self.runtime
+            .block_on(self.client.get_instance_port_states(input))
+    }
+
+    /// <p>Returns information about a specific instance snapshot.</p>
+    ///
+    /// See `Lightsail::get_instance_snapshot`.
+    pub fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
+        self.runtime
+            .block_on(self.client.get_instance_snapshot(input))
+    }
+
+    /// <p>Returns all instance snapshots for the user's account.</p>
+    ///
+    /// See `Lightsail::get_instance_snapshots`.
+    pub fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.get_instance_snapshots(input))
+    }
+
+    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
+    ///
+    /// See `Lightsail::get_instance_state`.
+    pub fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
+        self.runtime.block_on(self.client.get_instance_state(input))
+    }
+
+    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
+    ///
+    /// See `Lightsail::get_instances`.
+    pub fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
+        self.runtime.block_on(self.client.get_instances(input))
+    }
+
+    /// <p>Returns information about a specific key pair.</p>
+    ///
+    /// See `Lightsail::get_key_pair`.
+    pub fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
+        self.runtime.block_on(self.client.get_key_pair(input))
+    }
+
+    /// <p>Returns information about all key pairs in the user's account.</p>
+    ///
+    /// See `Lightsail::get_key_pairs`.
+    pub fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
+        self.runtime.block_on(self.client.get_key_pairs(input))
+    }
+
+    /// <p>Returns information about the specified Lightsail load balancer.</p>
+    ///
+    /// See `Lightsail::get_load_balancer`.
+    pub fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
+        self.runtime.block_on(self.client.get_load_balancer(input))
+    }
+
+    /// <p>Returns information about health metrics for your Lightsail load balancer.</p> <p>Metrics report the utilization of your resources, and the error counts generated by them. Monitor and collect metric data regularly to maintain the reliability, availability, and performance of your resources.</p>
+    ///
+    /// See `Lightsail::get_load_balancer_metric_data`.
+    pub fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_load_balancer_metric_data(input))
+    }
+
+    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
+    ///
+    /// See `Lightsail::get_load_balancer_tls_certificates`.
+    pub fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> Result<
+        GetLoadBalancerTlsCertificatesResult,
+        RusotoError<GetLoadBalancerTlsCertificatesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_load_balancer_tls_certificates(input))
+    }
+
+    /// <p>Returns information about all load balancers in an account.</p>
+    ///
+    /// See `Lightsail::get_load_balancers`.
+    pub fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
+        self.runtime.block_on(self.client.get_load_balancers(input))
+    }
+
+    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
+    ///
+    /// See `Lightsail::get_operation`.
+    pub fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
+        self.runtime.block_on(self.client.get_operation(input))
+    }
+
+    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
+    ///
+    /// See `Lightsail::get_operations`.
+    pub fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
+        self.runtime.block_on(self.client.get_operations(input))
+    }
+
+    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
+    ///
+    /// See `Lightsail::get_operations_for_resource`.
+    pub fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
+        self.runtime
+            .block_on(self.client.get_operations_for_resource(input))
+    }
+
+    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
+    ///
+    /// See `Lightsail::get_regions`.
+    pub fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
+        self.runtime.block_on(self.client.get_regions(input))
+    }
+
+    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_database`.
+    pub fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.get_relational_database(input))
+    }
+
+    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
+    ///
+    /// See `Lightsail::get_relational_database_blueprints`.
+    pub fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> Result<
+        GetRelationalDatabaseBlueprintsResult,
+        RusotoError<GetRelationalDatabaseBlueprintsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_blueprints(input))
+    }
+
+    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
+    ///
+    /// See `Lightsail::get_relational_database_bundles`.
+    pub fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_relational_database_bundles(input))
+    }
+
+    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_database_events`.
+    pub fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_relational_database_events(input))
+    }
+
+    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_database_log_events`.
+    pub fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> Result<
+        GetRelationalDatabaseLogEventsResult,
+        RusotoError<GetRelationalDatabaseLogEventsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_log_events(input))
+    }
+
+    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_database_log_streams`.
+    pub fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> Result<
+        GetRelationalDatabaseLogStreamsResult,
+        RusotoError<GetRelationalDatabaseLogStreamsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_log_streams(input))
+    }
+
+    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>GetRelationalDatabaseMasterUserPassword</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
+    ///
+    /// See `Lightsail::get_relational_database_master_user_password`.
+    pub fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> Result<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_relational_database_master_user_password(input),
+        )
+    }
+
+    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p> <p>Metrics report the utilization of your resources, and the error counts generated by them. Monitor and collect metric data regularly to maintain the reliability, availability, and performance of your resources.</p>
+    ///
+    /// See `Lightsail::get_relational_database_metric_data`.
+    pub fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> Result<
+        GetRelationalDatabaseMetricDataResult,
+        RusotoError<GetRelationalDatabaseMetricDataError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_metric_data(input))
+    }
+
+    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
+    ///
+    /// See `Lightsail::get_relational_database_parameters`.
+    pub fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> Result<
+        GetRelationalDatabaseParametersResult,
+        RusotoError<GetRelationalDatabaseParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_parameters(input))
+    }
+
+    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_database_snapshot`.
+    pub fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.get_relational_database_snapshot(input))
+    }
+
+    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_database_snapshots`.
+    pub fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> Result<
+        GetRelationalDatabaseSnapshotsResult,
+        RusotoError<GetRelationalDatabaseSnapshotsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_snapshots(input))
+    }
+
+    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
+    ///
+    /// See `Lightsail::get_relational_databases`.
+    pub fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
+        self.runtime
+            .block_on(self.client.get_relational_databases(input))
+    }
+
+    /// <p>Returns information about a specific static IP.</p>
+    ///
+    /// See `Lightsail::get_static_ip`.
+    pub fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
+        self.runtime.block_on(self.client.get_static_ip(input))
+    }
+
+    /// <p>Returns information about all static IPs in the user's account.</p>
+    ///
+    /// See `Lightsail::get_static_ips`.
+    pub fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
+        self.runtime.block_on(self.client.get_static_ips(input))
+    }
+
+    /// <p>Imports a public SSH key from a specific key pair.</p>
+    ///
+    /// See `Lightsail::import_key_pair`.
+    pub fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
+        self.runtime.block_on(self.client.import_key_pair(input))
+    }
+
+    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
+    ///
+    /// See `Lightsail::is_vpc_peered`.
+    pub fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
+        self.runtime.block_on(self.client.is_vpc_peered())
+    }
+
+    /// <p>Opens ports for a specific Amazon Lightsail instance, and specifies the IP addresses allowed to connect to the instance through the ports, and the protocol.</p> <p>The <code>OpenInstancePublicPorts</code> action supports tag-based access control via resource tags applied to the resource identified by <code>instanceName</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::open_instance_public_ports`.
+    pub fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
+        self.runtime
+            .block_on(self.client.open_instance_public_ports(input))
+    }
+
+    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
+    ///
+    /// See `Lightsail::peer_vpc`.
+    pub fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
+        self.runtime.block_on(self.client.peer_vpc())
+    }
+
+    /// <p>Creates or updates an alarm, and associates it with the specified metric.</p> <p>An alarm is used to monitor a single metric for one of your resources. When a metric condition is met, the alarm can notify you by email, SMS text message, and a banner displayed on the Amazon Lightsail console. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-alarms">Alarms in Amazon Lightsail</a>.</p> <p>When this action creates an alarm, the alarm state is immediately set to <code>INSUFFICIENT_DATA</code>. The alarm is then evaluated and its state is set appropriately. Any actions associated with the new state are then executed.</p> <p>When you update an existing alarm, its state is left unchanged, but the update completely overwrites the previous configuration of the alarm. The alarm is then evaluated with the updated configuration.</p>
+    ///
+    /// See `Lightsail::put_alarm`.
+    pub fn put_alarm(
+        &self,
+        input: PutAlarmRequest,
+    ) -> Result<PutAlarmResult, RusotoError<PutAlarmError>> {
+        self.runtime.block_on(self.client.put_alarm(input))
+    }
+
+    /// <p>Opens ports for a specific Amazon Lightsail instance, and specifies the IP addresses allowed to connect to the instance through the ports, and the protocol. This action also closes all currently open ports that are not included in the request. Include all of the ports and the protocols you want to open in your <code>PutInstancePublicPorts</code>request. Or use the <code>OpenInstancePublicPorts</code> action to open ports without closing currently open ports.</p> <p>The <code>PutInstancePublicPorts</code> action supports tag-based access control via resource tags applied to the resource identified by <code>instanceName</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::put_instance_public_ports`.
+    pub fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
+        self.runtime
+            .block_on(self.client.put_instance_public_ports(input))
+    }
+
+    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::reboot_instance`.
+    pub fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
+        self.runtime.block_on(self.client.reboot_instance(input))
+    }
+
+    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::reboot_relational_database`.
+    pub fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.reboot_relational_database(input))
+    }
+
+    /// <p>Deletes a specific static IP from your account.</p>
+    ///
+    /// See `Lightsail::release_static_ip`.
+    pub fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
+        self.runtime.block_on(self.client.release_static_ip(input))
+    }
+
+    /// <p><p>Sends a verification request to an email contact method to ensure it&#39;s owned by the requester. SMS contact methods don&#39;t need to be verified.</p> <p>A contact method is used to send you notifications about your Amazon Lightsail resources. You can add one email address and one mobile phone number contact method in each AWS Region. However, SMS text messaging is not supported in some AWS Regions, and SMS text messages cannot be sent to some countries/regions. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-notifications">Notifications in Amazon Lightsail</a>.</p> <p>A verification request is sent to the contact method when you initially create it. Use this action to send another verification request if a previous verification request was deleted, or has expired.</p> <important> <p>Notifications are not sent to an email contact method until after it is verified, and confirmed as valid.</p> </important></p>
+    ///
+    /// See `Lightsail::send_contact_method_verification`.
+    pub fn send_contact_method_verification(
+        &self,
+        input: SendContactMethodVerificationRequest,
+    ) -> Result<SendContactMethodVerificationResult, RusotoError<SendContactMethodVerificationError>>
+    {
+        self.runtime
+            .block_on(self.client.send_contact_method_verification(input))
+    }
+
+    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::start_instance`.
+    pub fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
+        self.runtime.block_on(self.client.start_instance(input))
+    }
+
+    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::start_relational_database`.
+    pub fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.start_relational_database(input))
+    }
+
+    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>instance name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::stop_instance`.
+    pub fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
+        self.runtime.block_on(self.client.stop_instance(input))
+    }
+
+    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::stop_relational_database`.
+    pub fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.stop_relational_database(input))
+    }
+
+    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>resource name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Tests an alarm by displaying a banner on the Amazon Lightsail console. If a notification trigger is configured for the specified alarm, the test also sends a notification to the notification protocol (<code>Email</code> and/or <code>SMS</code>) configured for the alarm.</p> <p>An alarm is used to monitor a single metric for one of your resources. When a metric condition is met, the alarm can notify you by email, SMS text message, and a banner displayed on the Amazon Lightsail console. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en_us/articles/amazon-lightsail-alarms">Alarms in Amazon Lightsail</a>.</p>
+    ///
+    /// See `Lightsail::test_alarm`.
+    pub fn test_alarm(
+        &self,
+        input: TestAlarmRequest,
+    ) -> Result<TestAlarmResult, RusotoError<TestAlarmError>> {
+        self.runtime.block_on(self.client.test_alarm(input))
+    }
+
+    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
+    ///
+    /// See `Lightsail::unpeer_vpc`.
+    pub fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
+        self.runtime.block_on(self.client.unpeer_vpc())
+    }
+
+    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by <code>resource name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>domain name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::update_domain_entry`.
+    pub fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
+        self.runtime
+            .block_on(self.client.update_domain_entry(input))
+    }
+
+    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by <code>load balancer name</code>. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::update_load_balancer_attribute`.
+    pub fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.update_load_balancer_attribute(input))
+    }
+
+    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::update_relational_database`.
+    pub fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.update_relational_database(input))
+    }
+
+    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which parameter updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ///
+    /// See `Lightsail::update_relational_database_parameters`.
+    pub fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> Result<
+        UpdateRelationalDatabaseParametersResult,
+        RusotoError<UpdateRelationalDatabaseParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_relational_database_parameters(input))
+    }
+}
@@ -1438,16 +1438,28 @@ impl AssociateKmsKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateKmsKeyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateKmsKeyError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateKmsKeyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateKmsKeyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1486,16 +1498,28 @@ impl CancelExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidOperationException" => {
-                    return RusotoError::Service(CancelExportTaskError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelExportTaskError::InvalidOperation(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelExportTaskError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelExportTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelExportTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelExportTaskError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelExportTaskError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1538,24 +1562,40 @@ impl CreateExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateExportTaskError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateExportTaskError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateExportTaskError::LimitExceeded(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(CreateExportTaskError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateExportTaskError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateExportTaskError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateExportTaskError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateExportTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateExportTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateExportTaskError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateExportTaskError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1598,21 +1638,31 @@ impl CreateLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLogGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateLogGroupError::LimitExceeded(err.msg))
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(CreateLogGroupError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogGroupError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1652,18 +1702,28 @@ impl CreateLogStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLogStreamError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogStreamError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogStreamError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLogStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLogStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLogStreamError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1702,18 +1762,28 @@ impl DeleteDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteDestinationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDestinationError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteDestinationError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDestinationError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDestinationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDestinationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDestinationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDestinationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1752,16 +1822,28 @@ impl DeleteLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteLogGroupError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogGroupError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1800,16 +1882,28 @@ impl DeleteLogStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLogStreamError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogStreamError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteLogStreamError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogStreamError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLogStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLogStreamError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1848,18 +1942,28 @@ impl DeleteMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricFilterError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricFilterError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricFilterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricFilterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1896,19 +2000,22 @@ impl DeleteQueryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteQueryDefinitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteQueryDefinitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteQueryDefinitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteQueryDefinitionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteQueryDefinitionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteQueryDefinitionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1944,19 +2051,22 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1994,24 +2104,28 @@ impl DeleteRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2050,24 +2164,28 @@ impl DeleteSubscriptionFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionFilterError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionFilterError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionFilterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionFilterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2102,14 +2220,16 @@ impl DescribeDestinationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDestinationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDestinationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDestinationsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDestinationsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2142,14 +2262,16 @@ impl DescribeExportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeExportTasksError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExportTasksError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2182,12 +2304,16 @@ impl DescribeLogGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLogGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogGroupsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLogGroupsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogGroupsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2222,15 +2348,22 @@ impl DescribeLogStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogStreamsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogStreamsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLogStreamsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2266,19 +2399,22 @@ impl DescribeMetricFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMetricFiltersError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMetricFiltersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMetricFiltersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2314,13 +2450,22 @@ impl DescribeQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeQueriesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeQueriesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeQueriesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeQueriesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeQueriesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeQueriesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2354,14 +2499,16 @@ impl DescribeQueryDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeQueryDefinitionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeQueryDefinitionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeQueryDefinitionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeQueryDefinitionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2394,14 +2541,16 @@ impl DescribeResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourcePoliciesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourcePoliciesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeResourcePoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourcePoliciesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2438,17 +2587,20 @@ impl DescribeSubscriptionFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscriptionFiltersError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscriptionFiltersError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSubscriptionFiltersError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2490,18 +2642,28 @@ impl DisassociateKmsKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateKmsKeyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateKmsKeyError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateKmsKeyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateKmsKeyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2538,13 +2700,22 @@ impl FilterLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(FilterLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FilterLogEventsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(FilterLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FilterLogEventsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(FilterLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        FilterLogEventsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2580,13 +2751,16 @@ impl GetLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetLogEventsError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetLogEventsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLogEventsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2624,18 +2798,28 @@ impl GetLogGroupFieldsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLogGroupFieldsError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLogGroupFieldsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLogGroupFieldsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLogGroupFieldsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2674,16 +2858,19 @@ impl GetLogRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogRecordError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetLogRecordError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLogRecordError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetLogRecordError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetLogRecordError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogRecordError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLogRecordError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2720,13 +2907,22 @@ impl GetQueryResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetQueryResultsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryResultsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetQueryResultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryResultsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetQueryResultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQueryResultsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2760,10 +2956,16 @@ impl ListTagsLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsLogGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsLogGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2798,13 +3000,22 @@ impl PutDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutDestinationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDestinationError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutDestinationError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDestinationError::OperationAborted(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutDestinationError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDestinationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2840,19 +3051,22 @@ impl PutDestinationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDestinationPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDestinationPolicyError::OperationAborted(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDestinationPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2894,22 +3108,34 @@ impl PutLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataAlreadyAcceptedException" => {
-                    return RusotoError::Service(PutLogEventsError::DataAlreadyAccepted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLogEventsError::DataAlreadyAccepted(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, PutLogEventsError::InvalidParameter(err.msg))
                 }
                 "InvalidSequenceTokenException" => {
-                    return RusotoError::Service(PutLogEventsError::InvalidSequenceToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLogEventsError::InvalidSequenceToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PutLogEventsError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLogEventsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnrecognizedClientException" => {
-                    return RusotoError::Service(PutLogEventsError::UnrecognizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLogEventsError::UnrecognizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2952,19 +3178,31 @@ impl PutMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricFilterError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMetricFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutMetricFilterError::LimitExceeded(err.msg))
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutMetricFilterError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricFilterError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutMetricFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricFilterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutMetricFilterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricFilterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3002,15 +3240,22 @@ impl PutQueryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutQueryDefinitionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutQueryDefinitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutQueryDefinitionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutQueryDefinitionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutQueryDefinitionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutQueryDefinitionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3046,15 +3291,22 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutResourcePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::LimitExceeded(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3092,18 +3344,28 @@ impl PutRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OperationAborted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3144,27 +3406,34 @@ impl PutSubscriptionFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutSubscriptionFilterError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSubscriptionFilterError::LimitExceeded(err.msg),
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::OperationAborted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutSubscriptionFilterError::OperationAborted(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutSubscriptionFilterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutSubscriptionFilterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3206,19 +3475,19 @@ impl StartQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartQueryError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, StartQueryError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartQueryError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartQueryError::LimitExceeded(err.msg))
                 }
                 "MalformedQueryException" => {
-                    return RusotoError::Service(StartQueryError::MalformedQuery(err.msg))
+                    return RusotoError::service(&res, StartQueryError::MalformedQuery(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartQueryError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartQueryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, StartQueryError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3256,13 +3525,13 @@ impl StopQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopQueryError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, StopQueryError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopQueryError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopQueryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, StopQueryError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3296,10 +3565,10 @@ impl TagLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagLogGroupError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagLogGroupError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3332,10 +3601,16 @@ impl TestMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TestMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestMetricFilterError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestMetricFilterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestMetricFilterError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3366,7 +3641,10 @@ impl UntagLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagLogGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3654,6 +3932,7 @@ impl CloudWatchLogsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3669,6 +3948,7 @@ impl CloudWatchLogsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
         CloudWatchLogsClient { client, region }
     }
@@ -3682,6 +3962,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: AssociateKmsKeyRequest,
     ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateKmsKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.AssociateKmsKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3699,6 +3982,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CancelExportTaskRequest,
     ) -> Result<(), RusotoError<CancelExportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.CancelExportTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3716,6 +4002,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateExportTaskRequest,
     ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.CreateExportTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3734,6 +4023,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateLogGroupRequest,
     ) -> Result<(), RusotoError<CreateLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLogGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.CreateLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3751,6 +4043,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateLogStreamRequest,
     ) -> Result<(), RusotoError<CreateLogStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLogStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.CreateLogStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3768,6 +4063,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteDestinationRequest,
     ) -> Result<(), RusotoError<DeleteDestinationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3785,6 +4083,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteLogGroupRequest,
     ) -> Result<(), RusotoError<DeleteLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLogGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3802,6 +4103,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteLogStreamRequest,
     ) -> Result<(), RusotoError<DeleteLogStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLogStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3819,6 +4123,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteMetricFilterRequest,
     ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMetricFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3835,6 +4142,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteQueryDefinitionRequest,
     ) -> Result<DeleteQueryDefinitionResponse, RusotoError<DeleteQueryDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteQueryDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteQueryDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3854,6 +4164,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3871,6 +4184,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteRetentionPolicyRequest,
     ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRetentionPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3888,6 +4204,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteSubscriptionFilterRequest,
     ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSubscriptionFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DeleteSubscriptionFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3905,6 +4224,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeDestinationsRequest,
     ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDestinations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeDestinations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3924,6 +4246,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeExportTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeExportTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3942,6 +4267,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeLogGroupsRequest,
     ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLogGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3960,6 +4288,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeLogStreamsRequest,
     ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLogStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3978,6 +4309,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeMetricFiltersRequest,
     ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMetricFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeMetricFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3997,6 +4331,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeQueriesRequest,
     ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeQueries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4014,6 +4351,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeQueryDefinitionsRequest,
     ) -> Result<DescribeQueryDefinitionsResponse, RusotoError<DescribeQueryDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeQueryDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeQueryDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4033,6 +4373,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeResourcePoliciesRequest,
     ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeResourcePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeResourcePolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4053,6 +4396,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
     ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSubscriptionFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.DescribeSubscriptionFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4072,6 +4418,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DisassociateKmsKeyRequest,
     ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateKmsKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.DisassociateKmsKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4089,6 +4438,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: FilterLogEventsRequest,
     ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("FilterLogEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.FilterLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4107,6 +4459,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogEventsRequest,
     ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLogEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.GetLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4125,6 +4480,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogGroupFieldsRequest,
     ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLogGroupFields");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.GetLogGroupFields");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4143,6 +4501,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogRecordRequest,
     ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLogRecord");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.GetLogRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4161,6 +4522,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetQueryResultsRequest,
     ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetQueryResults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.GetQueryResults");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4179,6 +4543,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: ListTagsLogGroupRequest,
     ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsLogGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Logs_20140328.ListTagsLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4197,6 +4564,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutDestinationRequest,
     ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4215,6 +4585,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutDestinationPolicyRequest,
     ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutDestinationPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutDestinationPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4232,6 +4605,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutLogEventsRequest,
     ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutLogEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4250,6 +4626,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutMetricFilterRequest,
     ) -> Result<(), RusotoError<PutMetricFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutMetricFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4266,6 +4645,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutQueryDefinitionRequest,
     ) -> Result<PutQueryDefinitionResponse, RusotoError<PutQueryDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutQueryDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutQueryDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4284,6 +4666,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4302,6 +4687,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutRetentionPolicyRequest,
     ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRetentionPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4319,6 +4707,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutSubscriptionFilterRequest,
     ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutSubscriptionFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.PutSubscriptionFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4336,6 +4727,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: StartQueryRequest,
     ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartQuery");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.StartQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4354,6 +4748,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: StopQueryRequest,
     ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopQuery");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.StopQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4372,6 +4769,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: TagLogGroupRequest,
     ) -> Result<(), RusotoError<TagLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagLogGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.TagLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4389,6 +4789,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: TestMetricFilterRequest,
     ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TestMetricFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.TestMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4407,6 +4810,9 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: UntagLogGroupRequest,
     ) -> Result<(), RusotoError<UntagLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagLogGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Logs_20140328.UntagLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4418,3 +4824,489 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon CloudWatch Logs API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `CloudWatchLogsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudWatchLogsBlockingClient {
+    client: CloudWatchLogsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudWatchLogsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> CloudWatchLogsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        CloudWatchLogsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudWatchLogsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudWatchLogsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient {
+            client: CloudWatchLogsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates the specified AWS Key Management Service (AWS KMS) customer master key (CMK) with the specified log group.</p> <p>Associating an AWS KMS CMK with a log group overrides any existing associations between the log group and a CMK. After a CMK is associated with a log group, all newly ingested data for the log group is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <note> <p> <b>Important:</b> CloudWatch Logs supports only symmetric CMKs. Do not use an associate an asymmetric CMK with your log group. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a>.</p> </note> <p>Note that it can take up to 5 minutes for this operation to take effect.</p> <p>If you attempt to associate a CMK with a log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p>
+    ///
+    /// See `CloudWatchLogs::associate_kms_key`.
+    pub fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
+        self.runtime.block_on(self.client.associate_kms_key(input))
+    }
+
+    /// <p>Cancels the specified export task.</p> <p>The task must be in the <code>PENDING</code> or <code>RUNNING</code> state.</p>
+    ///
+    /// See `CloudWatchLogs::cancel_export_task`.
+    pub fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> Result<(), RusotoError<CancelExportTaskError>> {
+        self.runtime.block_on(self.client.cancel_export_task(input))
+    }
+
+    /// <p>Creates an export task, which allows you to efficiently export data from a log group to an Amazon S3 bucket.</p> <p>This is an asynchronous call. If all the required information is provided, this operation initiates an export task and responds with the ID of the task. After the task has started, you can use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_DescribeExportTasks.html">DescribeExportTasks</a> to get the status of the export task. Each account can only have one active (<code>RUNNING</code> or <code>PENDING</code>) export task at a time. To cancel an export task, use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_CancelExportTask.html">CancelExportTask</a>.</p> <p>You can export logs from multiple log groups or multiple time ranges to the same S3 bucket. To separate out log data for each export task, you can specify a prefix to be used as the Amazon S3 key prefix for all exported objects.</p> <p>Exporting to S3 buckets that are encrypted with AES-256 is supported. Exporting to S3 buckets encrypted with SSE-KMS is not supported. </p>
+    ///
+    /// See `CloudWatchLogs::create_export_task`.
+    pub fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
+        self.runtime.block_on(self.client.create_export_task(input))
+    }
+
+    /// <p><p>Creates a log group with the specified name.</p> <p>You can create up to 20,000 log groups per account.</p> <p>You must use the following guidelines when naming a log group:</p> <ul> <li> <p>Log group names must be unique within a region for an AWS account.</p> </li> <li> <p>Log group names can be between 1 and 512 characters long.</p> </li> <li> <p>Log group names consist of the following characters: a-z, A-Z, 0-9, &#39;_&#39; (underscore), &#39;-&#39; (hyphen), &#39;/&#39; (forward slash), &#39;.&#39; (period), and &#39;#&#39; (number sign)</p> </li> </ul> <p>If you associate a AWS Key Management Service (AWS KMS) customer master key (CMK) with the log group, ingested data is encrypted using the CMK. This association is stored as long as the data encrypted with the CMK is still within Amazon CloudWatch Logs. This enables Amazon CloudWatch Logs to decrypt this data whenever it is requested.</p> <p>If you attempt to associate a CMK with the log group but the CMK does not exist or the CMK is disabled, you will receive an <code>InvalidParameterException</code> error. </p> <note> <p> <b>Important:</b> CloudWatch Logs supports only symmetric CMKs. Do not associate an asymmetric CMK with your log group. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric Keys</a>.</p> </note></p>
+    ///
+    /// See `CloudWatchLogs::create_log_group`.
+    pub fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> Result<(), RusotoError<CreateLogGroupError>> {
+        self.runtime.block_on(self.client.create_log_group(input))
+    }
+
+    /// <p><p>Creates a log stream for the specified log group.</p> <p>There is no limit on the number of log streams that you can create for a log group. There is a limit of 50 TPS on <code>CreateLogStream</code> operations, after which transactions are throttled.</p> <p>You must use the following guidelines when naming a log stream:</p> <ul> <li> <p>Log stream names must be unique within the log group.</p> </li> <li> <p>Log stream names can be between 1 and 512 characters long.</p> </li> <li> <p>The &#39;:&#39; (colon) and &#39;*&#39; (asterisk) characters are not allowed.</p> </li> </ul></p>
+    ///
+    /// See `CloudWatchLogs::create_log_stream`.
+    pub fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> Result<(), RusotoError<CreateLogStreamError>> {
+        self.runtime.block_on(self.client.create_log_stream(input))
+    }
+
+    /// <p>Deletes the specified destination, and eventually disables all the subscription filters that publish to it. This operation does not delete the physical resource encapsulated by the destination.</p>
+    ///
+    /// See `CloudWatchLogs::delete_destination`.
+    pub fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> Result<(), RusotoError<DeleteDestinationError>> {
+        self.runtime.block_on(self.client.delete_destination(input))
+    }
+
+    /// <p>Deletes the specified log group and permanently deletes all the archived log events associated with the log group.</p>
+    ///
+    /// See `CloudWatchLogs::delete_log_group`.
+    pub fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> Result<(), RusotoError<DeleteLogGroupError>> {
+        self.runtime.block_on(self.client.delete_log_group(input))
+    }
+
+    /// <p>Deletes the specified log stream and permanently deletes all the archived log events associated with the log stream.</p>
+    ///
+    /// See `CloudWatchLogs::delete_log_stream`.
+    pub fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> Result<(), RusotoError<DeleteLogStreamError>> {
+        self.runtime.block_on(self.client.delete_log_stream(input))
+    }
+
+    /// <p>Deletes the specified metric filter.</p>
+    ///
+    /// See `CloudWatchLogs::delete_metric_filter`.
+    pub fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_metric_filter(input))
+    }
+
+    /// See `CloudWatchLogs::delete_query_definition`.
+    pub fn delete_query_definition(
+        &self,
+        input: DeleteQueryDefinitionRequest,
+    ) -> Result<DeleteQueryDefinitionResponse, RusotoError<DeleteQueryDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_query_definition(input))
+    }
+
+    /// <p>Deletes a resource policy from this account. This revokes the access of the identities in that policy to put log events to this account.</p>
+    ///
+    /// See `CloudWatchLogs::delete_resource_policy`.
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// <p>Deletes the specified retention policy.</p> <p>Log events do not expire if they belong to log groups without a retention policy.</p>
+    ///
+    /// See `CloudWatchLogs::delete_retention_policy`.
+    pub fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_retention_policy(input))
+    }
+
+    /// <p>Deletes the specified subscription filter.</p>
+    ///
+    /// See `CloudWatchLogs::delete_subscription_filter`.
+    pub fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_subscription_filter(input))
+    }
+
+    /// <p>Lists all your destinations. The results are ASCII-sorted by destination name.</p>
+    ///
+    /// See `CloudWatchLogs::describe_destinations`.
+    pub fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
+        self.runtime
+            .block_on(self.client.describe_destinations(input))
+    }
+
+    /// <p>Lists the specified export tasks. You can list all your export tasks or filter the results based on task ID or task status.</p>
+    ///
+    /// See `CloudWatchLogs::describe_export_tasks`.
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// <p>Lists the specified log groups. You can list all your log groups or filter the results by prefix. The results are ASCII-sorted by log group name.</p>
+    ///
+    /// See `CloudWatchLogs::describe_log_groups`.
+    pub fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_log_groups(input))
+    }
+
+    /// <p>Lists the log streams for the specified log group. You can list all the log streams or filter the results by prefix. You can also control how the results are ordered.</p> <p>This operation has a limit of five transactions per second, after which transactions are throttled.</p>
+    ///
+    /// See `CloudWatchLogs::describe_log_streams`.
+    pub fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
+        self.runtime
+            .block_on(self.client.describe_log_streams(input))
+    }
+
+    /// <p>Lists the specified metric filters. You can list all the metric filters or filter the results by log name, prefix, metric name, or metric namespace. The results are ASCII-sorted by filter name.</p>
+    ///
+    /// See `CloudWatchLogs::describe_metric_filters`.
+    pub fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
+        self.runtime
+            .block_on(self.client.describe_metric_filters(input))
+    }
+
+    /// <p>Returns a list of CloudWatch Logs Insights queries that are scheduled, executing, or have been executed recently in this account. You can request all queries, or limit it to queries of a specific log group or queries with a certain status.</p>
+    ///
+    /// See `CloudWatchLogs::describe_queries`.
+    pub fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
+        self.runtime.block_on(self.client.describe_queries(input))
+    }
+
+    /// See `CloudWatchLogs::describe_query_definitions`.
+    pub fn describe_query_definitions(
+        &self,
+        input: DescribeQueryDefinitionsRequest,
+    ) -> Result<DescribeQueryDefinitionsResponse, RusotoError<DescribeQueryDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.describe_query_definitions(input))
+    }
+
+    /// <p>Lists the resource policies in this account.</p>
+    ///
+    /// See `CloudWatchLogs::describe_resource_policies`.
+    pub fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_resource_policies(input))
+    }
+
+    /// <p>Lists the subscription filters for the specified log group. You can list all the subscription filters or filter the results by prefix. The results are ASCII-sorted by filter name.</p>
+    ///
+    /// See `CloudWatchLogs::describe_subscription_filters`.
+    pub fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_subscription_filters(input))
+    }
+
+    /// <p>Disassociates the associated AWS Key Management Service (AWS KMS) customer master key (CMK) from the specified log group.</p> <p>After the AWS KMS CMK is disassociated from the log group, AWS CloudWatch Logs stops encrypting newly ingested data for the log group. All previously ingested data remains encrypted, and AWS CloudWatch Logs requires permissions for the CMK whenever the encrypted data is requested.</p> <p>Note that it can take up to 5 minutes for this operation to take effect.</p>
+    ///
+    /// See `CloudWatchLogs::disassociate_kms_key`.
+    pub fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
+        self.runtime
+            .block_on(self.client.disassociate_kms_key(input))
+    }
+
+    /// <p>Lists log events from the specified log group. You can list all the log events or filter the results using a filter pattern, a time range, and the name of the log stream.</p> <p>By default, this operation returns as many log events as can fit in 1 MB (up to 10,000 log events), or all the events found within the time range that you specify. If the results include a token, then there are more log events available, and you can get additional results by specifying the token in a subsequent call.</p>
+    ///
+    /// See `CloudWatchLogs::filter_log_events`.
+    pub fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
+        self.runtime.block_on(self.client.filter_log_events(input))
+    }
+
+    /// <p>Lists log events from the specified log stream. You can list all the log events or filter using a time range.</p> <p>By default, this operation returns as many log events as can fit in a response size of 1MB (up to 10,000 log events). You can get additional log events by specifying one of the tokens in a subsequent call.</p>
+    ///
+    /// See `CloudWatchLogs::get_log_events`.
+    pub fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
+        self.runtime.block_on(self.client.get_log_events(input))
+    }
+
+    /// <p>Returns a list of the fields that are included in log events in the specified log group, along with the percentage of log events that contain each field. The search is limited to a time period that you specify.</p> <p>In the results, fields that start with @ are fields generated by CloudWatch Logs. For example, <code>@timestamp</code> is the timestamp of each log event. For more information about the fields that are generated by CloudWatch logs, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/CWL_AnalyzeLogData-discoverable-fields.html">Supported Logs and Discovered Fields</a>.</p> <p>The response results are sorted by the frequency percentage, starting with the highest percentage.</p>
+    ///
+    /// See `CloudWatchLogs::get_log_group_fields`.
+    pub fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
+        self.runtime
+            .block_on(self.client.get_log_group_fields(input))
+    }
+
+    /// <p>Retrieves all the fields and values of a single log event. All fields are retrieved, even if the original query that produced the <code>logRecordPointer</code> retrieved only a subset of fields. Fields are returned as field name/field value pairs.</p> <p>Additionally, the entire unparsed log event is returned within <code>@message</code>.</p>
+    ///
+    /// See `CloudWatchLogs::get_log_record`.
+    pub fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
+        self.runtime.block_on(self.client.get_log_record(input))
+    }
+
+    /// <p>Returns the results from the specified query.</p> <p>Only the fields requested in the query are returned, along with a <code>@ptr</code> field which is the identifier for the log record. You can use the value of <code>@ptr</code> in a <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_GetLogRecord.html">GetLogRecord</a> operation to get the full log record.</p> <p> <code>GetQueryResults</code> does not start a query execution. To run a query, use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_StartQuery.html">StartQuery</a>.</p> <p>If the value of the <code>Status</code> field in the output is <code>Running</code>, this operation returns only partial results. If you see a value of <code>Scheduled</code> or <code>Running</code> for the status, you can retry the operation later to see the final results. </p>
+    ///
+    /// See `CloudWatchLogs::get_query_results`.
+    pub fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
+        self.runtime.block_on(self.client.get_query_results(input))
+    }
+
+    /// <p>Lists the tags for the specified log group.</p>
+    ///
+    /// See `CloudWatchLogs::list_tags_log_group`.
+    pub fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
+        self.runtime
+            .block_on(self.client.list_tags_log_group(input))
+    }
+
+    /// <p>Creates or updates a destination. This operation is used only to create destinations for cross-account subscriptions.</p> <p>A destination encapsulates a physical resource (such as an Amazon Kinesis stream) and enables you to subscribe to a real-time stream of log events for a different account, ingested using <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_PutLogEvents.html">PutLogEvents</a>.</p> <p>Through an access policy, a destination controls what is written to it. By default, <code>PutDestination</code> does not set any access policy with the destination, which means a cross-account user cannot call <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_PutSubscriptionFilter.html">PutSubscriptionFilter</a> against this destination. To enable this, the destination owner must call <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_PutDestinationPolicy.html">PutDestinationPolicy</a> after <code>PutDestination</code>.</p>
+    ///
+    /// See `CloudWatchLogs::put_destination`.
+    pub fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
+        self.runtime.block_on(self.client.put_destination(input))
+    }
+
+    /// <p>Creates or updates an access policy associated with an existing destination. An access policy is an <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies_overview.html">IAM policy document</a> that is used to authorize claims to register a subscription filter against a given destination.</p>
+    ///
+    /// See `CloudWatchLogs::put_destination_policy`.
+    pub fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_destination_policy(input))
+    }
+
+    /// <p>Uploads a batch of log events to the specified log stream.</p> <p>You must include the sequence token obtained from the response of the previous call. An upload in a newly created log stream does not require a sequence token. You can also get the sequence token in the <code>expectedSequenceToken</code> field from <code>InvalidSequenceTokenException</code>. If you call <code>PutLogEvents</code> twice within a narrow time period using the same value for <code>sequenceToken</code>, both calls may be successful, or one may be rejected.</p> <p>The batch of events must satisfy the following constraints:</p> <ul> <li> <p>The maximum batch size is 1,048,576 bytes, and this size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p> </li> <li> <p>None of the log events in the batch can be more than 2 hours in the future.</p> </li> <li> <p>None of the log events in the batch can be older than 14 days or older than the retention period of the log group.</p> </li> <li> <p>The log events in the batch must be in chronological ordered by their timestamp. The timestamp is the time the event occurred, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. (In AWS Tools for PowerShell and the AWS SDK for .NET, the timestamp is specified in .NET format: yyyy-mm-ddThh:mm:ss. For example, 2017-09-15T13:45:30.) </p> </li> <li> <p>A batch of log events in a single request cannot span more than 24 hours. Otherwise, the operation fails.</p> </li> <li> <p>The maximum number of log events in a batch is 10,000.</p> </li> <li> <p>There is a quota of 5 requests per second per log stream. Additional requests are throttled. This quota can't be changed.</p> </li> </ul> <p>If a call to PutLogEvents returns "UnrecognizedClientException" the most likely cause is an invalid AWS access key ID or secret key. </p>
+    ///
+    /// See `CloudWatchLogs::put_log_events`.
+    pub fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
+        self.runtime.block_on(self.client.put_log_events(input))
+    }
+
+    /// <p>Creates or updates a metric filter and associates it with the specified log group. Metric filters allow you to configure rules to extract metric data from log events ingested through <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_PutLogEvents.html">PutLogEvents</a>.</p> <p>The maximum number of metric filters that can be associated with a log group is 100.</p>
+    ///
+    /// See `CloudWatchLogs::put_metric_filter`.
+    pub fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> Result<(), RusotoError<PutMetricFilterError>> {
+        self.runtime.block_on(self.client.put_metric_filter(input))
+    }
+
+    /// See `CloudWatchLogs::put_query_definition`.
+    pub fn put_query_definition(
+        &self,
+        input: PutQueryDefinitionRequest,
+    ) -> Result<PutQueryDefinitionResponse, RusotoError<PutQueryDefinitionError>> {
+        self.runtime
+            .block_on(self.client.put_query_definition(input))
+    }
+
+    /// <p>Creates or updates a resource policy allowing other AWS services to put log events to this account, such as Amazon Route 53. An account can have up to 10 resource policies per region.</p>
+    ///
+    /// See `CloudWatchLogs::put_resource_policy`.
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// <p>Sets the retention of the specified log group. A retention policy allows you to configure the number of days for which to retain log events in the specified log group.</p>
+    ///
+    /// See `CloudWatchLogs::put_retention_policy`.
+    pub fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_retention_policy(input))
+    }
+
+    /// <p>Creates or updates a subscription filter and associates it with the specified log group. Subscription filters allow you to subscribe to a real-time stream of log events ingested through <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_PutLogEvents.html">PutLogEvents</a> and have them delivered to a specific destination. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination that belongs to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function that belongs to the same account as the subscription filter, for same-account delivery.</p> </li> </ul> <p>There can only be one subscription filter associated with a log group. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group.</p>
+    ///
+    /// See `CloudWatchLogs::put_subscription_filter`.
+    pub fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
+        self.runtime
+            .block_on(self.client.put_subscription_filter(input))
+    }
+
+    /// <p>Schedules a query of a log group using CloudWatch Logs Insights. You specify the log group and time range to query, and the query string to use.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/CWL_QuerySyntax.html">CloudWatch Logs Insights Query Syntax</a>.</p> <p>Queries time out after 15 minutes of execution. If your queries are timing out, reduce the time range being searched, or partition your query into a number of queries.</p>
+    ///
+    /// See `CloudWatchLogs::start_query`.
+    pub fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
+        self.runtime.block_on(self.client.start_query(input))
+    }
+
+    /// <p>Stops a CloudWatch Logs Insights query that is in progress. If the query has already ended, the operation returns an error indicating that the specified query is not running.</p>
+    ///
+    /// See `CloudWatchLogs::stop_query`.
+    pub fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
+        self.runtime.block_on(self.client.stop_query(input))
+    }
+
+    /// <p>Adds or updates the specified tags for the specified log group.</p> <p>To list the tags for a log group, use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_ListTagsLogGroup.html">ListTagsLogGroup</a>. To remove tags, use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_UntagLogGroup.html">UntagLogGroup</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html#log-group-tagging">Tag Log Groups in Amazon CloudWatch Logs</a> in the <i>Amazon CloudWatch Logs User Guide</i>.</p>
+    ///
+    /// See `CloudWatchLogs::tag_log_group`.
+    pub fn tag_log_group(
+        &self,
+        input: TagLogGroupRequest,
+    ) -> Result<(), RusotoError<TagLogGroupError>> {
+        self.runtime.block_on(self.client.tag_log_group(input))
+    }
+
+    /// <p>Tests the filter pattern of a metric filter against a sample of log event messages. You can use this operation to validate the correctness of a metric filter pattern.</p>
+    ///
+    /// See `CloudWatchLogs::test_metric_filter`.
+    pub fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
+        self.runtime.block_on(self.client.test_metric_filter(input))
+    }
+
+    /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_ListTagsLogGroup.html">ListTagsLogGroup</a>. To add tags, use <a href="https://docs.aws.amazon.com/AmazonCloudWatchLogs/latest/APIReference/API_TagLogGroup.html">TagLogGroup</a>.</p>
+    ///
+    /// See `CloudWatchLogs::untag_log_group`.
+    pub fn untag_log_group(
+        &self,
+        input: UntagLogGroupRequest,
+    ) -> Result<(), RusotoError<UntagLogGroupError>> {
+        self.runtime.block_on(self.client.untag_log_group(input))
+    }
+}
@@ -1691,19 +1691,19 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddTagsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AddTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InvalidInput(err.msg))
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTag(err.msg))
+                    return RusotoError::service(&res, AddTagsError::InvalidTag(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AddTagsError::ResourceNotFound(err.msg))
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::TagLimitExceeded(err.msg))
+                    return RusotoError::service(&res, AddTagsError::TagLimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1741,17 +1741,22 @@ impl CreateBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateBatchPredictionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateBatchPredictionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBatchPredictionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBatchPredictionError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1789,19 +1794,22 @@ impl CreateDataSourceFromRDSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataSourceFromRDSError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromRDSError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceFromRDSError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromRDSError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceFromRDSError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1841,19 +1849,22 @@ impl CreateDataSourceFromRedshiftError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataSourceFromRedshiftError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromRedshiftError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceFromRedshiftError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromRedshiftError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceFromRedshiftError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1891,17 +1902,22 @@ impl CreateDataSourceFromS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDataSourceFromS3Error::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromS3Error::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceFromS3Error::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromS3Error::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceFromS3Error::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1939,15 +1955,19 @@ impl CreateEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateEvaluationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEvaluationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateEvaluationError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1983,15 +2003,16 @@ impl CreateMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateMLModelError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMLModelError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateMLModelError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateMLModelError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2027,17 +2048,22 @@ impl CreateRealtimeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRealtimeEndpointError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRealtimeEndpointError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRealtimeEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2073,17 +2099,22 @@ impl DeleteBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBatchPredictionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBatchPredictionError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBatchPredictionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2119,13 +2150,19 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2161,13 +2198,19 @@ impl DeleteEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEvaluationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteEvaluationError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEvaluationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2203,13 +2246,16 @@ impl DeleteMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteMLModelError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteMLModelError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMLModelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2245,17 +2291,22 @@ impl DeleteRealtimeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRealtimeEndpointError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRealtimeEndpointError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRealtimeEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2293,16 +2344,16 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::InvalidInput(err.msg))
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidTag(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::InvalidTag(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2337,14 +2388,16 @@ impl DescribeBatchPredictionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeBatchPredictionsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBatchPredictionsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeBatchPredictionsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBatchPredictionsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2377,10 +2430,16 @@ impl DescribeDataSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDataSourcesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourcesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDataSourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourcesError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2413,10 +2472,16 @@ impl DescribeEvaluationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEvaluationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEvaluationsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEvaluationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEvaluationsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2449,10 +2514,13 @@ impl DescribeMLModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeMLModelsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMLModelsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeMLModelsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeMLModelsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2487,13 +2555,13 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTagsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2529,13 +2597,22 @@ impl GetBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetBatchPredictionError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBatchPredictionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBatchPredictionError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBatchPredictionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBatchPredictionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2571,13 +2648,16 @@ impl GetDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDataSourceError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDataSourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2613,13 +2693,16 @@ impl GetEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetEvaluationError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetEvaluationError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEvaluationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2655,13 +2738,13 @@ impl GetMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetMLModelError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMLModelError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetMLModelError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2701,19 +2784,19 @@ impl PredictError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PredictError::InternalServer(err.msg))
+                    return RusotoError::service(&res, PredictError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PredictError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, PredictError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PredictError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PredictError::LimitExceeded(err.msg))
                 }
                 "PredictorNotMountedException" => {
-                    return RusotoError::Service(PredictError::PredictorNotMounted(err.msg))
+                    return RusotoError::service(&res, PredictError::PredictorNotMounted(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PredictError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, PredictError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2751,17 +2834,22 @@ impl UpdateBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBatchPredictionError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBatchPredictionError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBatchPredictionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2797,13 +2885,19 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2839,13 +2933,19 @@ impl UpdateEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEvaluationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateEvaluationError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEvaluationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2881,13 +2981,16 @@ impl UpdateMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateMLModelError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateMLModelError::InternalServer(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateMLModelError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMLModelError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3096,6 +3199,7 @@ impl MachineLearningClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3111,6 +3215,7 @@ impl MachineLearningClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MachineLearningClient {
         MachineLearningClient { client, region }
     }
@@ -3124,6 +3229,9 @@ impl MachineLearning for MachineLearningClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3142,6 +3250,9 @@ impl MachineLearning for MachineLearningClient {
         input: CreateBatchPredictionInput,
     ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBatchPrediction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.CreateBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3160,6 +3271,9 @@ impl MachineLearning for MachineLearningClient {
         input: CreateDataSourceFromRDSInput,
     ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataSourceFromRDS");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromRDS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3180,6 +3294,9 @@ impl MachineLearning for MachineLearningClient {
     ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataSourceFromRedshift");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonML_20141212.CreateDataSourceFromRedshift",
@@ -3202,6 +3319,9 @@ impl MachineLearning for MachineLearningClient {
         input: CreateDataSourceFromS3Input,
     ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataSourceFromS3");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3221,6 +3341,9 @@ impl MachineLearning for MachineLearningClient {
         input: CreateEvaluationInput,
     ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEvaluation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.CreateEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3239,6 +3362,9 @@ impl MachineLearning for MachineLearningClient {
         input: CreateMLModelInput,
     ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMLModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.CreateMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3257,6 +3383,9 @@ impl MachineLearning for MachineLearningClient {
         input: CreateRealtimeEndpointInput,
     ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRealtimeEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.CreateRealtimeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3276,6 +3405,9 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteBatchPredictionInput,
     ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBatchPrediction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3294,6 +3426,9 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteDataSourceInput,
     ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3312,6 +3447,9 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteEvaluationInput,
     ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEvaluation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3330,6 +3468,9 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteMLModelInput,
     ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMLModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3348,6 +3489,9 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteRealtimeEndpointInput,
     ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRealtimeEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteRealtimeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3367,6 +3511,9 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteTagsInput,
     ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3385,6 +3532,9 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeBatchPredictionsInput,
     ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBatchPredictions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeBatchPredictions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3404,6 +3554,9 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeDataSourcesInput,
     ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDataSources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeDataSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3422,6 +3575,9 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeEvaluationsInput,
     ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEvaluations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeEvaluations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3440,6 +3596,9 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeMLModelsInput,
     ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMLModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeMLModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3458,6 +3617,9 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3476,6 +3638,9 @@ impl MachineLearning for MachineLearningClient {
         input: GetBatchPredictionInput,
     ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetBatchPrediction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.GetBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3494,6 +3659,9 @@ impl MachineLearning for MachineLearningClient {
         input: GetDataSourceInput,
     ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDataSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.GetDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3512,6 +3680,9 @@ impl MachineLearning for MachineLearningClient {
         input: GetEvaluationInput,
     ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEvaluation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.GetEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3530,6 +3701,9 @@ impl MachineLearning for MachineLearningClient {
         input: GetMLModelInput,
     ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMLModel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonML_20141212.GetMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3548,6 +3722,9 @@ impl MachineLearning for MachineLearningClient {
         input: PredictInput,
     ) -> Result<PredictOutput, RusotoError<PredictError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Predict");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.Predict");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3566,6 +3743,9 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateBatchPredictionInput,
     ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateBatchPrediction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3584,6 +3764,9 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateDataSourceInput,
     ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3602,6 +3785,9 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateEvaluationInput,
     ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEvaluation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3620,6 +3806,9 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateMLModelInput,
     ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMLModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3632,3 +3821,346 @@ impl MachineLearning for MachineLearningClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateMLModelOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon Machine Learning API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MachineLearningClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MachineLearningBlockingClient {
+    client: MachineLearningClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MachineLearningBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MachineLearningBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MachineLearningBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MachineLearningBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MachineLearningBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient {
+            client: MachineLearningClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds one or more tags to an object, up to a limit of 10. Each tag consists of a key and an optional value. If you add a tag using a key that is already associated with the ML object, <code>AddTags</code> updates the tag's value.</p>
+    ///
+    /// See `MachineLearning::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Generates predictions for a group of observations. The observations to process exist in one or more data files referenced by a <code>DataSource</code>. This operation creates a new <code>BatchPrediction</code>, and uses an <code>MLModel</code> and the data files referenced by the <code>DataSource</code> as information sources. </p> <p><code>CreateBatchPrediction</code> is an asynchronous operation. In response to <code>CreateBatchPrediction</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>BatchPrediction</code> status to <code>PENDING</code>. After the <code>BatchPrediction</code> completes, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can poll for status updates by using the <a>GetBatchPrediction</a> operation and checking the <code>Status</code> parameter of the result. After the <code>COMPLETED</code> status appears, the results are available in the location specified by the <code>OutputUri</code> parameter.</p>
+    ///
+    /// See `MachineLearning::create_batch_prediction`.
+    pub fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.create_batch_prediction(input))
+    }
+
+    /// <p>Creates a <code>DataSource</code> object from an <a href="http://aws.amazon.com/rds/"> Amazon Relational Database Service</a> (Amazon RDS). A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRDS</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRDS</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used only to perform <code>&gt;CreateMLModel</code>&gt;, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML cannot accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p>
+    ///
+    /// See `MachineLearning::create_data_source_from_rds`.
+    pub fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
+        self.runtime
+            .block_on(self.client.create_data_source_from_rds(input))
+    }
+
+    /// <p><p>Creates a <code>DataSource</code> from a database hosted on an Amazon Redshift cluster. A <code>DataSource</code> references data that can be used to perform either <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromRedshift</code> is an asynchronous operation. In response to <code>CreateDataSourceFromRedshift</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> is created and ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in <code>COMPLETED</code> or <code>PENDING</code> states can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can&#39;t accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observations should be contained in the database hosted on an Amazon Redshift cluster and should be specified by a <code>SelectSqlQuery</code> query. Amazon ML executes an <code>Unload</code> command in Amazon Redshift to transfer the result set of the <code>SelectSqlQuery</code> query to <code>S3StagingLocation</code>.</p> <p>After the <code>DataSource</code> has been created, it&#39;s ready for use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also requires a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p> &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160406T153842-0700&quot;&gt;<p>You can&#39;t change an existing datasource, but you can copy and modify the settings from an existing Amazon Redshift datasource to create a new datasource. To do so, call <code>GetDataSource</code> for an existing datasource and copy the values to a <code>CreateDataSource</code> call. Change the settings that you want to change and make sure that all required fields have the appropriate values.</p> &lt;?oxy<em>insert</em>end&gt;</p>
+    ///
+    /// See `MachineLearning::create_data_source_from_redshift`.
+    pub fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
+    {
+        self.runtime
+            .block_on(self.client.create_data_source_from_redshift(input))
+    }
+
+    /// <p>Creates a <code>DataSource</code> object. A <code>DataSource</code> references data that can be used to perform <code>CreateMLModel</code>, <code>CreateEvaluation</code>, or <code>CreateBatchPrediction</code> operations.</p> <p><code>CreateDataSourceFromS3</code> is an asynchronous operation. In response to <code>CreateDataSourceFromS3</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>DataSource</code> status to <code>PENDING</code>. After the <code>DataSource</code> has been created and is ready for use, Amazon ML sets the <code>Status</code> parameter to <code>COMPLETED</code>. <code>DataSource</code> in the <code>COMPLETED</code> or <code>PENDING</code> state can be used to perform only <code>CreateMLModel</code>, <code>CreateEvaluation</code> or <code>CreateBatchPrediction</code> operations. </p> <p> If Amazon ML can't accept the input source, it sets the <code>Status</code> parameter to <code>FAILED</code> and includes an error message in the <code>Message</code> attribute of the <code>GetDataSource</code> operation response. </p> <p>The observation data used in a <code>DataSource</code> should be ready to use; that is, it should have a consistent structure, and missing data values should be kept to a minimum. The observation data must reside in one or more .csv files in an Amazon Simple Storage Service (Amazon S3) location, along with a schema that describes the data items by name and type. The same schema must be used for all of the data files referenced by the <code>DataSource</code>. </p> <p>After the <code>DataSource</code> has been created, it's ready to use in evaluations and batch predictions. If you plan to use the <code>DataSource</code> to train an <code>MLModel</code>, the <code>DataSource</code> also needs a recipe. A recipe describes how each input variable will be used in training an <code>MLModel</code>. Will the variable be included or excluded from training? Will the variable be manipulated; for example, will it be combined with another variable or will it be split apart into word combinations? The recipe provides answers to these questions.</p>
+    ///
+    /// See `MachineLearning::create_data_source_from_s3`.
+    pub fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
+        self.runtime
+            .block_on(self.client.create_data_source_from_s3(input))
+    }
+
+    /// <p>Creates a new <code>Evaluation</code> of an <code>MLModel</code>. An <code>MLModel</code> is evaluated on a set of observations associated to a <code>DataSource</code>. Like a <code>DataSource</code> for an <code>MLModel</code>, the <code>DataSource</code> for an <code>Evaluation</code> contains values for the <code>Target Variable</code>. The <code>Evaluation</code> compares the predicted result for each observation to the actual outcome and provides a summary so that you know how effective the <code>MLModel</code> functions on the test data. Evaluation generates a relevant performance metric, such as BinaryAUC, RegressionRMSE or MulticlassAvgFScore based on the corresponding <code>MLModelType</code>: <code>BINARY</code>, <code>REGRESSION</code> or <code>MULTICLASS</code>. </p> <p><code>CreateEvaluation</code> is an asynchronous operation. In response to <code>CreateEvaluation</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the evaluation status to <code>PENDING</code>. After the <code>Evaluation</code> is created and ready for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetEvaluation</code> operation to check progress of the evaluation during the creation operation.</p>
+    ///
+    /// See `MachineLearning::create_evaluation`.
+    pub fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
+        self.runtime.block_on(self.client.create_evaluation(input))
+    }
+
+    /// <p>Creates a new <code>MLModel</code> using the <code>DataSource</code> and the recipe as information sources. </p> <p>An <code>MLModel</code> is nearly immutable. Users can update only the <code>MLModelName</code> and the <code>ScoreThreshold</code> in an <code>MLModel</code> without creating a new <code>MLModel</code>. </p> <p><code>CreateMLModel</code> is an asynchronous operation. In response to <code>CreateMLModel</code>, Amazon Machine Learning (Amazon ML) immediately returns and sets the <code>MLModel</code> status to <code>PENDING</code>. After the <code>MLModel</code> has been created and ready is for use, Amazon ML sets the status to <code>COMPLETED</code>. </p> <p>You can use the <code>GetMLModel</code> operation to check the progress of the <code>MLModel</code> during the creation operation.</p> <p> <code>CreateMLModel</code> requires a <code>DataSource</code> with computed statistics, which can be created by setting <code>ComputeStatistics</code> to <code>true</code> in <code>CreateDataSourceFromRDS</code>, <code>CreateDataSourceFromS3</code>, or <code>CreateDataSourceFromRedshift</code> operations. </p>
+    ///
+    /// See `MachineLearning::create_ml_model`.
+    pub fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
+        self.runtime.block_on(self.client.create_ml_model(input))
+    }
+
+    /// <p>Creates a real-time endpoint for the <code>MLModel</code>. The endpoint contains the URI of the <code>MLModel</code>; that is, the location to send real-time prediction requests for the specified <code>MLModel</code>.</p>
+    ///
+    /// See `MachineLearning::create_realtime_endpoint`.
+    pub fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_realtime_endpoint(input))
+    }
+
+    /// <p>Assigns the DELETED status to a <code>BatchPrediction</code>, rendering it unusable.</p> <p>After using the <code>DeleteBatchPrediction</code> operation, you can use the <a>GetBatchPrediction</a> operation to verify that the status of the <code>BatchPrediction</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteBatchPrediction</code> operation is irreversible.</p>
+    ///
+    /// See `MachineLearning::delete_batch_prediction`.
+    pub fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.delete_batch_prediction(input))
+    }
+
+    /// <p>Assigns the DELETED status to a <code>DataSource</code>, rendering it unusable.</p> <p>After using the <code>DeleteDataSource</code> operation, you can use the <a>GetDataSource</a> operation to verify that the status of the <code>DataSource</code> changed to DELETED.</p> <p><b>Caution:</b> The results of the <code>DeleteDataSource</code> operation are irreversible.</p>
+    ///
+    /// See `MachineLearning::delete_data_source`.
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// <p><p>Assigns the <code>DELETED</code> status to an <code>Evaluation</code>, rendering it unusable.</p> <p>After invoking the <code>DeleteEvaluation</code> operation, you can use the <code>GetEvaluation</code> operation to verify that the status of the <code>Evaluation</code> changed to <code>DELETED</code>.</p> <caution><title>Caution</title> <p>The results of the <code>DeleteEvaluation</code> operation are irreversible.</p></caution></p>
+    ///
+    /// See `MachineLearning::delete_evaluation`.
+    pub fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
+        self.runtime.block_on(self.client.delete_evaluation(input))
+    }
+
+    /// <p>Assigns the <code>DELETED</code> status to an <code>MLModel</code>, rendering it unusable.</p> <p>After using the <code>DeleteMLModel</code> operation, you can use the <code>GetMLModel</code> operation to verify that the status of the <code>MLModel</code> changed to DELETED.</p> <p><b>Caution:</b> The result of the <code>DeleteMLModel</code> operation is irreversible.</p>
+    ///
+    /// See `MachineLearning::delete_ml_model`.
+    pub fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
+        self.runtime.block_on(self.client.delete_ml_model(input))
+    }
+
+    /// <p>Deletes a real time endpoint of an <code>MLModel</code>.</p>
+    ///
+    /// See `MachineLearning::delete_realtime_endpoint`.
+    pub fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_realtime_endpoint(input))
+    }
+
+    /// <p>Deletes the specified tags associated with an ML object. After this operation is complete, you can't recover deleted tags.</p> <p>If you specify a tag that doesn't exist, Amazon ML ignores it.</p>
+    ///
+    /// See `MachineLearning::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Returns a list of <code>BatchPrediction</code> operations that match the search criteria in the request.</p>
+    ///
+    /// See `MachineLearning::describe_batch_predictions`.
+    pub fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
+        self.runtime
+            .block_on(self.client.describe_batch_predictions(input))
+    }
+
+    /// <p>Returns a list of <code>DataSource</code> that match the search criteria in the request.</p>
+    ///
+    /// See `MachineLearning::describe_data_sources`.
+    pub fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
+        self.runtime
+            .block_on(self.client.describe_data_sources(input))
+    }
+
+    /// <p>Returns a list of <code>DescribeEvaluations</code> that match the search criteria in the request.</p>
+    ///
+    /// See `MachineLearning::describe_evaluations`.
+    pub fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
+        self.runtime
+            .block_on(self.client.describe_evaluations(input))
+    }
+
+    /// <p>Returns a list of <code>MLModel</code> that match the search criteria in the request.</p>
+    ///
+    /// See `MachineLearning::describe_ml_models`.
+    pub fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
+        self.runtime.block_on(self.client.describe_ml_models(input))
+    }
+
+    /// <p>Describes one or more of the tags for your Amazon ML object.</p>
+    ///
+    /// See `MachineLearning::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Returns a <code>BatchPrediction</code> that includes detailed metadata, status, and data file information for a <code>Batch Prediction</code> request.</p>
+    ///
+    /// See `MachineLearning::get_batch_prediction`.
+    pub fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.get_batch_prediction(input))
+    }
+
+    /// <p>Returns a <code>DataSource</code> that includes metadata and data file information, as well as the current status of the <code>DataSource</code>.</p> <p><code>GetDataSource</code> provides results in normal or verbose format. The verbose format adds the schema description and the list of files pointed to by the DataSource to the normal format.</p>
+    ///
+    /// See `MachineLearning::get_data_source`.
+    pub fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
+        self.runtime.block_on(self.client.get_data_source(input))
+    }
+
+    /// <p>Returns an <code>Evaluation</code> that includes metadata as well as the current status of the <code>Evaluation</code>.</p>
+    ///
+    /// See `MachineLearning::get_evaluation`.
+    pub fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
+        self.runtime.block_on(self.client.get_evaluation(input))
+    }
+
+    /// <p>Returns an <code>MLModel</code> that includes detailed metadata, data source information, and the current status of the <code>MLModel</code>.</p> <p><code>GetMLModel</code> provides results in normal or verbose format. </p>
+    ///
+    /// See `MachineLearning::get_ml_model`.
+    pub fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
+        self.runtime.block_on(self.client.get_ml_model(input))
+    }
+
+    /// <p><p>Generates a prediction for the observation using the specified <code>ML Model</code>.</p> <note><title>Note</title> <p>Not all response parameters will be populated. Whether a response parameter is populated depends on the type of model requested.</p></note></p>
+    ///
+    /// See `MachineLearning::predict`.
+    pub fn predict(&self, input: PredictInput) -> Result<PredictOutput, RusotoError<PredictError>> {
+        self.runtime.block_on(self.client.predict(input))
+    }
+
+    /// <p>Updates the <code>BatchPredictionName</code> of a <code>BatchPrediction</code>.</p> <p>You can use the <code>GetBatchPrediction</code> operation to view the contents of the updated data element.</p>
+    ///
+    /// See `MachineLearning::update_batch_prediction`.
+    pub fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.update_batch_prediction(input))
+    }
+
+    /// <p>Updates the <code>DataSourceName</code> of a <code>DataSource</code>.</p> <p>You can use the <code>GetDataSource</code> operation to view the contents of the updated data element.</p>
+    ///
+    /// See `MachineLearning::update_data_source`.
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// <p>Updates the <code>EvaluationName</code> of an <code>Evaluation</code>.</p> <p>You can use the <code>GetEvaluation</code> operation to view the contents of the updated data element.</p>
+    ///
+    /// See `MachineLearning::update_evaluation`.
+    pub fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
+        self.runtime.block_on(self.client.update_evaluation(input))
+    }
+
+    /// <p>Updates the <code>MLModelName</code> and the <code>ScoreThreshold</code> of an <code>MLModel</code>.</p> <p>You can use the <code>GetMLModel</code> operation to view the contents of the updated data element.</p>
+    ///
+    /// See `MachineLearning::update_ml_model`.
+    pub fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
+        self.runtime.block_on(self.client.update_ml_model(input))
+    }
+}
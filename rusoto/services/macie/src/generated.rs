@@ -297,15 +297,22 @@ impl AssociateMemberAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberAccountError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberAccountError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberAccountError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -343,16 +350,25 @@ impl AssociateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateS3ResourcesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(&res, AssociateS3ResourcesError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateS3ResourcesError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateS3ResourcesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -387,12 +403,16 @@ impl DisassociateMemberAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateMemberAccountError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMemberAccountError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateMemberAccountError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMemberAccountError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -427,17 +447,22 @@ impl DisassociateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateS3ResourcesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateS3ResourcesError::Internal(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateS3ResourcesError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -471,10 +496,13 @@ impl ListMemberAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListMemberAccountsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListMemberAccountsError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMemberAccountsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMemberAccountsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -509,13 +537,13 @@ impl ListS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListS3ResourcesError::AccessDenied(err.msg))
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(&res, ListS3ResourcesError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListS3ResourcesError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -551,13 +579,19 @@ impl UpdateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateS3ResourcesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service(&res, UpdateS3ResourcesError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateS3ResourcesError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -640,6 +674,7 @@ impl MacieClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -655,6 +690,7 @@ impl MacieClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MacieClient {
         MacieClient { client, region }
     }
@@ -668,6 +704,9 @@ impl Macie for MacieClient {
         input: AssociateMemberAccountRequest,
     ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateMemberAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MacieService.AssociateMemberAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -685,6 +724,9 @@ impl Macie for MacieClient {
         input: AssociateS3ResourcesRequest,
     ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateS3Resources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MacieService.AssociateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -703,6 +745,9 @@ impl Macie for MacieClient {
         input: DisassociateMemberAccountRequest,
     ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateMemberAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MacieService.DisassociateMemberAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -720,6 +765,9 @@ impl Macie for MacieClient {
         input: DisassociateS3ResourcesRequest,
     ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateS3Resources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MacieService.DisassociateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -739,6 +787,9 @@ impl Macie for MacieClient {
         input: ListMemberAccountsRequest,
     ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMemberAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MacieService.ListMemberAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -757,6 +808,9 @@ impl Macie for MacieClient {
         input: ListS3ResourcesRequest,
     ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListS3Resources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MacieService.ListS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -775,6 +829,9 @@ impl Macie for MacieClient {
         input: UpdateS3ResourcesRequest,
     ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateS3Resources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MacieService.UpdateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -787,3 +844,129 @@ impl Macie for MacieClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateS3ResourcesResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Macie API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MacieClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MacieBlockingClient {
+    client: MacieClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MacieBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MacieBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MacieBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MacieBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MacieBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MacieBlockingClient {
+        MacieBlockingClient {
+            client: MacieClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a specified AWS account with Amazon Macie Classic as a member account.</p>
+    ///
+    /// See `Macie::associate_member_account`.
+    pub fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
+        self.runtime
+            .block_on(self.client.associate_member_account(input))
+    }
+
+    /// <p>Associates specified S3 resources with Amazon Macie Classic for monitoring and data classification. If memberAccountId isn't specified, the action associates specified S3 resources with Macie Classic for the current master account. If memberAccountId is specified, the action associates specified S3 resources with Macie Classic for the specified member account. </p>
+    ///
+    /// See `Macie::associate_s3_resources`.
+    pub fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
+        self.runtime
+            .block_on(self.client.associate_s3_resources(input))
+    }
+
+    /// <p>Removes the specified member account from Amazon Macie Classic.</p>
+    ///
+    /// See `Macie::disassociate_member_account`.
+    pub fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
+        self.runtime
+            .block_on(self.client.disassociate_member_account(input))
+    }
+
+    /// <p>Removes specified S3 resources from being monitored by Amazon Macie Classic. If memberAccountId isn't specified, the action removes specified S3 resources from Macie Classic for the current master account. If memberAccountId is specified, the action removes specified S3 resources from Macie Classic for the specified member account.</p>
+    ///
+    /// See `Macie::disassociate_s3_resources`.
+    pub fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
+        self.runtime
+            .block_on(self.client.disassociate_s3_resources(input))
+    }
+
+    /// <p>Lists all Amazon Macie Classic member accounts for the current Amazon Macie Classic master account.</p>
+    ///
+    /// See `Macie::list_member_accounts`.
+    pub fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
+        self.runtime
+            .block_on(self.client.list_member_accounts(input))
+    }
+
+    /// <p>Lists all the S3 resources associated with Amazon Macie Classic. If memberAccountId isn't specified, the action lists the S3 resources associated with Amazon Macie Classic for the current master account. If memberAccountId is specified, the action lists the S3 resources associated with Amazon Macie Classic for the specified member account. </p>
+    ///
+    /// See `Macie::list_s3_resources`.
+    pub fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
+        self.runtime.block_on(self.client.list_s3_resources(input))
+    }
+
+    /// <p>Updates the classification types for the specified S3 resources. If memberAccountId isn't specified, the action updates the classification types of the S3 resources associated with Amazon Macie Classic for the current master account. If memberAccountId is specified, the action updates the classification types of the S3 resources associated with Amazon Macie Classic for the specified member account. </p>
+    ///
+    /// See `Macie::update_s3_resources`.
+    pub fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
+        self.runtime
+            .block_on(self.client.update_s3_resources(input))
+    }
+}
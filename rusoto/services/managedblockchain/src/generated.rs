@@ -1175,28 +1175,37 @@ impl CreateMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateMemberError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMemberError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateMemberError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMemberError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMemberError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateMemberError::ResourceNotFound(err.msg))
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceNotReady(err.msg))
+                    return RusotoError::service(&res, CreateMemberError::ResourceNotReady(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateMemberError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateMemberError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1243,22 +1252,31 @@ impl CreateNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateNetworkError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateNetworkError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNetworkError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateNetworkError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateNetworkError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNetworkError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNetworkError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateNetworkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1307,28 +1325,37 @@ impl CreateNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateNodeError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodeError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateNodeError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodeError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateNodeError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateNodeError::ResourceNotFound(err.msg))
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceNotReady(err.msg))
+                    return RusotoError::service(&res, CreateNodeError::ResourceNotReady(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNodeError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateNodeError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1375,22 +1402,31 @@ impl CreateProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProposalError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateProposalError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProposalError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateProposalError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProposalError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateProposalError::ResourceNotReady(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProposalError::ResourceNotReady(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProposalError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateProposalError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1435,22 +1471,25 @@ impl DeleteMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteMemberError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMemberError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteMemberError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteMemberError::ResourceNotFound(err.msg))
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DeleteMemberError::ResourceNotReady(err.msg))
+                    return RusotoError::service(&res, DeleteMemberError::ResourceNotReady(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteMemberError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteMemberError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1495,22 +1534,25 @@ impl DeleteNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteNodeError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNodeError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteNodeError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteNodeError::ResourceNotFound(err.msg))
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DeleteNodeError::ResourceNotReady(err.msg))
+                    return RusotoError::service(&res, DeleteNodeError::ResourceNotReady(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNodeError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteNodeError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1553,19 +1595,22 @@ impl GetMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetMemberError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMemberError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetMemberError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetMemberError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetMemberError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetMemberError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1607,19 +1652,22 @@ impl GetNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetNetworkError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetNetworkError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetNetworkError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNetworkError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetNetworkError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNetworkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetNetworkError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNetworkError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetNetworkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1661,19 +1709,19 @@ impl GetNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetNodeError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(&res, GetNodeError::InternalServiceError(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetNodeError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetNodeError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNodeError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetNodeError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1715,19 +1763,22 @@ impl GetProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetProposalError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetProposalError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetProposalError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetProposalError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetProposalError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetProposalError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetProposalError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1771,26 +1822,34 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListInvitationsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInvitationsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInvitationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListInvitationsError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInvitationsError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInvitationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInvitationsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1831,16 +1890,19 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListMembersError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListMembersError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListMembersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMembersError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListMembersError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListMembersError::InvalidRequest(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMembersError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListMembersError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1879,16 +1941,19 @@ impl ListNetworksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNetworksError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListNetworksError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListNetworksError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNetworksError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNetworksError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListNetworksError::InvalidRequest(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNetworksError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListNetworksError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1927,16 +1992,19 @@ impl ListNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNodesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListNodesError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListNodesError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListNodesError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNodesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListNodesError::InvalidRequest(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNodesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListNodesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1975,18 +2043,25 @@ impl ListProposalVotesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProposalVotesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProposalVotesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProposalVotesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProposalVotesError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProposalVotesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProposalVotesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProposalVotesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListProposalVotesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2027,19 +2102,25 @@ impl ListProposalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProposalsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListProposalsError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProposalsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProposalsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProposalsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListProposalsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProposalsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProposalsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProposalsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListProposalsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2083,24 +2164,34 @@ impl RejectInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RejectInvitationError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, RejectInvitationError::AccessDenied(err.msg))
                 }
                 "IllegalActionException" => {
-                    return RusotoError::Service(RejectInvitationError::IllegalAction(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RejectInvitationError::IllegalAction(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RejectInvitationError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectInvitationError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RejectInvitationError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RejectInvitationError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RejectInvitationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RejectInvitationError::Throttling(err.msg))
+                    return RusotoError::service(&res, RejectInvitationError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2143,19 +2234,22 @@ impl UpdateMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateMemberError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateMemberError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMemberError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateMemberError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateMemberError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateMemberError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateMemberError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2197,19 +2291,22 @@ impl UpdateNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateNodeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateNodeError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNodeError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateNodeError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateNodeError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateNodeError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateNodeError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2253,22 +2350,28 @@ impl VoteOnProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(VoteOnProposalError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, VoteOnProposalError::AccessDenied(err.msg))
                 }
                 "IllegalActionException" => {
-                    return RusotoError::Service(VoteOnProposalError::IllegalAction(err.msg))
+                    return RusotoError::service(&res, VoteOnProposalError::IllegalAction(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(VoteOnProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VoteOnProposalError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(VoteOnProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, VoteOnProposalError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VoteOnProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        VoteOnProposalError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(VoteOnProposalError::Throttling(err.msg))
+                    return RusotoError::service(&res, VoteOnProposalError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2432,6 +2535,7 @@ impl ManagedBlockchainClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2447,6 +2551,7 @@ impl ManagedBlockchainClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ManagedBlockchainClient {
         ManagedBlockchainClient { client, region }
     }
@@ -2467,6 +2572,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("CreateMember");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2499,6 +2607,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("CreateNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2535,6 +2646,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("CreateNode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2570,6 +2684,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("CreateProposal");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2606,6 +2723,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("DeleteMember");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2640,6 +2760,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("DeleteNode");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2673,6 +2796,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("GetMember");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2702,6 +2828,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("GetNetwork");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2736,6 +2865,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("GetNode");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2769,6 +2901,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("GetProposal");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2798,6 +2933,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("ListInvitations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2839,6 +2977,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("ListMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2886,6 +3027,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("ListNetworks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2937,6 +3081,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("ListNodes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2982,6 +3129,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("ListProposalVotes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3023,6 +3173,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("ListProposals");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3064,6 +3217,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("RejectInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3097,6 +3253,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("PATCH", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("UpdateMember");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3134,6 +3293,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("PATCH", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("UpdateNode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3170,6 +3332,9 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation_name("VoteOnProposal");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3192,3 +3357,257 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
     }
 }
+
+/// A blocking client for the ManagedBlockchain API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ManagedBlockchainClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ManagedBlockchainBlockingClient {
+    client: ManagedBlockchainClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ManagedBlockchainBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ManagedBlockchainBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ManagedBlockchainBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ManagedBlockchainBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ManagedBlockchainBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient {
+            client: ManagedBlockchainClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a member within a Managed Blockchain network.</p>
+    ///
+    /// See `ManagedBlockchain::create_member`.
+    pub fn create_member(
+        &self,
+        input: CreateMemberInput,
+    ) -> Result<CreateMemberOutput, RusotoError<CreateMemberError>> {
+        self.runtime.block_on(self.client.create_member(input))
+    }
+
+    /// <p>Creates a new blockchain network using Amazon Managed Blockchain.</p>
+    ///
+    /// See `ManagedBlockchain::create_network`.
+    pub fn create_network(
+        &self,
+        input: CreateNetworkInput,
+    ) -> Result<CreateNetworkOutput, RusotoError<CreateNetworkError>> {
+        self.runtime.block_on(self.client.create_network(input))
+    }
+
+    /// <p>Creates a peer node in a member.</p>
+    ///
+    /// See `ManagedBlockchain::create_node`.
+    pub fn create_node(
+        &self,
+        input: CreateNodeInput,
+    ) -> Result<CreateNodeOutput, RusotoError<CreateNodeError>> {
+        self.runtime.block_on(self.client.create_node(input))
+    }
+
+    /// <p>Creates a proposal for a change to the network that other members of the network can vote on, for example, a proposal to add a new member to the network. Any member can create a proposal.</p>
+    ///
+    /// See `ManagedBlockchain::create_proposal`.
+    pub fn create_proposal(
+        &self,
+        input: CreateProposalInput,
+    ) -> Result<CreateProposalOutput, RusotoError<CreateProposalError>> {
+        self.runtime.block_on(self.client.create_proposal(input))
+    }
+
+    /// <p>Deletes a member. Deleting a member removes the member and all associated resources from the network. <code>DeleteMember</code> can only be called for a specified <code>MemberId</code> if the principal performing the action is associated with the AWS account that owns the member. In all other cases, the <code>DeleteMember</code> action is carried out as the result of an approved proposal to remove a member. If <code>MemberId</code> is the last member in a network specified by the last AWS account, the network is deleted also.</p>
+    ///
+    /// See `ManagedBlockchain::delete_member`.
+    pub fn delete_member(
+        &self,
+        input: DeleteMemberInput,
+    ) -> Result<DeleteMemberOutput, RusotoError<DeleteMemberError>> {
+        self.runtime.block_on(self.client.delete_member(input))
+    }
+
+    /// <p>Deletes a peer node from a member that your AWS account owns. All data on the node is lost and cannot be recovered.</p>
+    ///
+    /// See `ManagedBlockchain::delete_node`.
+    pub fn delete_node(
+        &self,
+        input: DeleteNodeInput,
+    ) -> Result<DeleteNodeOutput, RusotoError<DeleteNodeError>> {
+        self.runtime.block_on(self.client.delete_node(input))
+    }
+
+    /// <p>Returns detailed information about a member.</p>
+    ///
+    /// See `ManagedBlockchain::get_member`.
+    pub fn get_member(
+        &self,
+        input: GetMemberInput,
+    ) -> Result<GetMemberOutput, RusotoError<GetMemberError>> {
+        self.runtime.block_on(self.client.get_member(input))
+    }
+
+    /// <p>Returns detailed information about a network.</p>
+    ///
+    /// See `ManagedBlockchain::get_network`.
+    pub fn get_network(
+        &self,
+        input: GetNetworkInput,
+    ) -> Result<GetNetworkOutput, RusotoError<GetNetworkError>> {
+        self.runtime.block_on(self.client.get_network(input))
+    }
+
+    /// <p>Returns detailed information about a peer node.</p>
+    ///
+    /// See `ManagedBlockchain::get_node`.
+    pub fn get_node(
+        &self,
+        input: GetNodeInput,
+    ) -> Result<GetNodeOutput, RusotoError<GetNodeError>> {
+        self.runtime.block_on(self.client.get_node(input))
+    }
+
+    /// <p>Returns detailed information about a proposal.</p>
+    ///
+    /// See `ManagedBlockchain::get_proposal`.
+    pub fn get_proposal(
+        &self,
+        input: GetProposalInput,
+    ) -> Result<GetProposalOutput, RusotoError<GetProposalError>> {
+        self.runtime.block_on(self.client.get_proposal(input))
+    }
+
+    /// <p>Returns a listing of all invitations made on the specified network.</p>
+    ///
+    /// See `ManagedBlockchain::list_invitations`.
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsInput,
+    ) -> Result<ListInvitationsOutput, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// <p>Returns a listing of the members in a network and properties of their configurations.</p>
+    ///
+    /// See `ManagedBlockchain::list_members`.
+    pub fn list_members(
+        &self,
+        input: ListMembersInput,
+    ) -> Result<ListMembersOutput, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// <p>Returns information about the networks in which the current AWS account has members.</p>
+    ///
+    /// See `ManagedBlockchain::list_networks`.
+    pub fn list_networks(
+        &self,
+        input: ListNetworksInput,
+    ) -> Result<ListNetworksOutput, RusotoError<ListNetworksError>> {
+        self.runtime.block_on(self.client.list_networks(input))
+    }
+
+    /// <p>Returns information about the nodes within a network.</p>
+    ///
+    /// See `ManagedBlockchain::list_nodes`.
+    pub fn list_nodes(
+        &self,
+        input: ListNodesInput,
+    ) -> Result<ListNodesOutput, RusotoError<ListNodesError>> {
+        self.runtime.block_on(self.client.list_nodes(input))
+    }
+
+    /// <p>Returns the listing of votes for a specified proposal, including the value of each vote and the unique identifier of the member that cast the vote.</p>
+    ///
+    /// See `ManagedBlockchain::list_proposal_votes`.
+    pub fn list_proposal_votes(
+        &self,
+        input: ListProposalVotesInput,
+    ) -> Result<ListProposalVotesOutput, RusotoError<ListProposalVotesError>> {
+        self.runtime
+            .block_on(self.client.list_proposal_votes(input))
+    }
+
+    /// <p>Returns a listing of proposals for the network.</p>
+    ///
+    /// See `ManagedBlockchain::list_proposals`.
+    pub fn list_proposals(
+        &self,
+        input: ListProposalsInput,
+    ) -> Result<ListProposalsOutput, RusotoError<ListProposalsError>> {
+        self.runtime.block_on(self.client.list_proposals(input))
+    }
+
+    /// <p>Rejects an invitation to join a network. This action can be called by a principal in an AWS account that has received an invitation to create a member and join a network.</p>
+    ///
+    /// See `ManagedBlockchain::reject_invitation`.
+    pub fn reject_invitation(
+        &self,
+        input: RejectInvitationInput,
+    ) -> Result<RejectInvitationOutput, RusotoError<RejectInvitationError>> {
+        self.runtime.block_on(self.client.reject_invitation(input))
+    }
+
+    /// <p>Updates a member configuration with new parameters.</p>
+    ///
+    /// See `ManagedBlockchain::update_member`.
+    pub fn update_member(
+        &self,
+        input: UpdateMemberInput,
+    ) -> Result<UpdateMemberOutput, RusotoError<UpdateMemberError>> {
+        self.runtime.block_on(self.client.update_member(input))
+    }
+
+    /// <p>Updates a node configuration with new parameters.</p>
+    ///
+    /// See `ManagedBlockchain::update_node`.
+    pub fn update_node(
+        &self,
+        input: UpdateNodeInput,
+    ) -> Result<UpdateNodeOutput, RusotoError<UpdateNodeError>> {
+        self.runtime.block_on(self.client.update_node(input))
+    }
+
+    /// <p>Casts a vote for a specified <code>ProposalId</code> on behalf of a member. The member to vote as, specified by <code>VoterMemberId</code>, must be in the same AWS account as the principal that calls the action.</p>
+    ///
+    /// See `ManagedBlockchain::vote_on_proposal`.
+    pub fn vote_on_proposal(
+        &self,
+        input: VoteOnProposalInput,
+    ) -> Result<VoteOnProposalOutput, RusotoError<VoteOnProposalError>> {
+        self.runtime.block_on(self.client.vote_on_proposal(input))
+    }
+}
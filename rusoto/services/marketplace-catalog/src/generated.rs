@@ -417,19 +417,25 @@ impl CancelChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CancelChangeSetError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelChangeSetError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelChangeSetError::InternalService(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelChangeSetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CancelChangeSetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelChangeSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelChangeSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, CancelChangeSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -469,16 +475,25 @@ impl DescribeChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChangeSetError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeChangeSetError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChangeSetError::InternalService(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChangeSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeChangeSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeChangeSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -519,19 +534,28 @@ impl DescribeEntityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeEntityError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeEntityError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeEntityError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityError::InternalService(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEntityError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceNotSupportedException" => {
-                    return RusotoError::Service(DescribeEntityError::ResourceNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEntityError::ResourceNotSupported(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEntityError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeEntityError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -569,13 +593,16 @@ impl ListChangeSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChangeSetsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListChangeSetsError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListChangeSetsError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChangeSetsError::InternalService(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListChangeSetsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListChangeSetsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -613,16 +640,16 @@ impl ListEntitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListEntitiesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListEntitiesError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListEntitiesError::InternalService(err.msg))
+                    return RusotoError::service(&res, ListEntitiesError::InternalService(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEntitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListEntitiesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListEntitiesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListEntitiesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -665,22 +692,31 @@ impl StartChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StartChangeSetError::AccessDenied(err.msg))
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartChangeSetError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChangeSetError::InternalService(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartChangeSetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, StartChangeSetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChangeSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartChangeSetError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChangeSetError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartChangeSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartChangeSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -760,6 +796,7 @@ impl MarketplaceCatalogClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -775,6 +812,7 @@ impl MarketplaceCatalogClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceCatalogClient {
         MarketplaceCatalogClient { client, region }
     }
@@ -792,6 +830,9 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
 
         let mut request =
             SignedRequest::new("PATCH", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation_name("CancelChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -827,6 +868,9 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeChangeSet";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation_name("DescribeChangeSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -862,6 +906,9 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeEntity";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation_name("DescribeEntity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -897,6 +944,9 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListChangeSets";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation_name("ListChangeSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -929,6 +979,9 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListEntities";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation_name("ListEntities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -961,6 +1014,9 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/StartChangeSet";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation_name("StartChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -984,3 +1040,117 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         }
     }
 }
+
+/// A blocking client for the AWS Marketplace Catalog API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MarketplaceCatalogClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceCatalogBlockingClient {
+    client: MarketplaceCatalogClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceCatalogBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MarketplaceCatalogBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MarketplaceCatalogBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceCatalogBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceCatalogBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient {
+            client: MarketplaceCatalogClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Used to cancel an open change request. Must be sent before the status of the request changes to <code>APPLYING</code>, the final stage of completing your change request. You can describe a change during the 60-day request history retention period for API calls.</p>
+    ///
+    /// See `MarketplaceCatalog::cancel_change_set`.
+    pub fn cancel_change_set(
+        &self,
+        input: CancelChangeSetRequest,
+    ) -> Result<CancelChangeSetResponse, RusotoError<CancelChangeSetError>> {
+        self.runtime.block_on(self.client.cancel_change_set(input))
+    }
+
+    /// <p>Provides information about a given change set.</p>
+    ///
+    /// See `MarketplaceCatalog::describe_change_set`.
+    pub fn describe_change_set(
+        &self,
+        input: DescribeChangeSetRequest,
+    ) -> Result<DescribeChangeSetResponse, RusotoError<DescribeChangeSetError>> {
+        self.runtime
+            .block_on(self.client.describe_change_set(input))
+    }
+
+    /// <p>Returns the metadata and content of the entity.</p>
+    ///
+    /// See `MarketplaceCatalog::describe_entity`.
+    pub fn describe_entity(
+        &self,
+        input: DescribeEntityRequest,
+    ) -> Result<DescribeEntityResponse, RusotoError<DescribeEntityError>> {
+        self.runtime.block_on(self.client.describe_entity(input))
+    }
+
+    /// <p>Returns the list of change sets owned by the account being used to make the call. You can filter this list by providing any combination of <code>entityId</code>, <code>ChangeSetName</code>, and status. If you provide more than one filter, the API operation applies a logical AND between the filters.</p> <p>You can describe a change during the 60-day request history retention period for API calls.</p>
+    ///
+    /// See `MarketplaceCatalog::list_change_sets`.
+    pub fn list_change_sets(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> Result<ListChangeSetsResponse, RusotoError<ListChangeSetsError>> {
+        self.runtime.block_on(self.client.list_change_sets(input))
+    }
+
+    /// <p>Provides the list of entities of a given type.</p>
+    ///
+    /// See `MarketplaceCatalog::list_entities`.
+    pub fn list_entities(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> Result<ListEntitiesResponse, RusotoError<ListEntitiesError>> {
+        self.runtime.block_on(self.client.list_entities(input))
+    }
+
+    /// <p>This operation allows you to request changes for your entities. Within a single ChangeSet, you cannot start the same change type against the same entity multiple times. Additionally, when a ChangeSet is running, all the entities targeted by the different changes are locked until the ChangeSet has completed (either succeeded, cancelled, or failed). If you try to start a ChangeSet containing a change against an entity that is already locked, you will receive a <code>ResourceInUseException</code>.</p> <p>For example, you cannot start the ChangeSet described in the <a href="https://docs.aws.amazon.com/marketplace-catalog/latest/api-reference/API_StartChangeSet.html#API_StartChangeSet_Examples">example</a> below because it contains two changes to execute the same change type (<code>AddRevisions</code>) against the same entity (<code>entity-id@1)</code>.</p>
+    ///
+    /// See `MarketplaceCatalog::start_change_set`.
+    pub fn start_change_set(
+        &self,
+        input: StartChangeSetRequest,
+    ) -> Result<StartChangeSetResponse, RusotoError<StartChangeSetError>> {
+        self.runtime.block_on(self.client.start_change_set(input))
+    }
+}
@@ -151,15 +151,19 @@ impl GetEntitlementsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetEntitlementsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetEntitlementsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetEntitlementsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEntitlementsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEntitlementsError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetEntitlementsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -206,6 +210,7 @@ impl MarketplaceEntitlementClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -221,6 +226,7 @@ impl MarketplaceEntitlementClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceEntitlementClient {
         MarketplaceEntitlementClient { client, region }
     }
@@ -234,6 +240,9 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         input: GetEntitlementsRequest,
     ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetEntitlements");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMPEntitlementService.GetEntitlements");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -246,3 +255,70 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetEntitlementsResult, _>()
     }
 }
+
+/// A blocking client for the AWS Marketplace Entitlement Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MarketplaceEntitlementClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceEntitlementBlockingClient {
+    client: MarketplaceEntitlementClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceEntitlementBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MarketplaceEntitlementBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MarketplaceEntitlementBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceEntitlementBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceEntitlementBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient {
+            client: MarketplaceEntitlementClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>GetEntitlements retrieves entitlement values for a given product. The results can be filtered based on customer identifier or product dimensions.</p>
+    ///
+    /// See `MarketplaceEntitlement::get_entitlements`.
+    pub fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
+        self.runtime.block_on(self.client.get_entitlements(input))
+    }
+}
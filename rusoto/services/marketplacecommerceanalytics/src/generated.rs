@@ -145,7 +145,8 @@ impl GenerateDataSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "MarketplaceCommerceAnalyticsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GenerateDataSetError::MarketplaceCommerceAnalytics(err.msg),
                     )
                 }
@@ -177,7 +178,8 @@ impl StartSupportDataExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "MarketplaceCommerceAnalyticsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSupportDataExportError::MarketplaceCommerceAnalytics(err.msg),
                     )
                 }
@@ -232,6 +234,7 @@ impl MarketplaceCommerceAnalyticsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -247,6 +250,7 @@ impl MarketplaceCommerceAnalyticsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(
         client: Client,
         region: region::Region,
@@ -263,6 +267,9 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         input: GenerateDataSetRequest,
     ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MarketplaceCommerceAnalytics20150701.GenerateDataSet",
@@ -284,6 +291,9 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         input: StartSupportDataExportRequest,
     ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartSupportDataExport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MarketplaceCommerceAnalytics20150701.StartSupportDataExport",
@@ -300,3 +310,81 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
             .deserialize::<StartSupportDataExportResult, _>()
     }
 }
+
+/// A blocking client for the AWS Marketplace Commerce Analytics API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MarketplaceCommerceAnalyticsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceCommerceAnalyticsBlockingClient {
+    client: MarketplaceCommerceAnalyticsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceCommerceAnalyticsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MarketplaceCommerceAnalyticsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MarketplaceCommerceAnalyticsBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceCommerceAnalyticsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceCommerceAnalyticsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient {
+            client: MarketplaceCommerceAnalyticsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Given a data set type and data set publication date, asynchronously publishes the requested data set to the specified S3 bucket and notifies the specified SNS topic once the data is available. Returns a unique request identifier that can be used to correlate requests with notifications from the SNS topic. Data sets will be published in comma-separated values (CSV) format with the file name {data<em>set</em>type}_YYYY-MM-DD.csv. If a file with the same name already exists (e.g. if the same data set is requested twice), the original file will be overwritten by the new file. Requires a Role with an attached permissions policy providing Allow permissions for the following actions: s3:PutObject, s3:GetBucketLocation, sns:GetTopicAttributes, sns:Publish, iam:GetRolePolicy.</p>
+    ///
+    /// See `MarketplaceCommerceAnalytics::generate_data_set`.
+    pub fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
+        self.runtime.block_on(self.client.generate_data_set(input))
+    }
+
+    /// <p>Given a data set type and a from date, asynchronously publishes the requested customer support data to the specified S3 bucket and notifies the specified SNS topic once the data is available. Returns a unique request identifier that can be used to correlate requests with notifications from the SNS topic. Data sets will be published in comma-separated values (CSV) format with the file name {data<em>set</em>type}_YYYY-MM-DD&#39;T&#39;HH-mm-ss&#39;Z&#39;.csv. If a file with the same name already exists (e.g. if the same data set is requested twice), the original file will be overwritten by the new file. Requires a Role with an attached permissions policy providing Allow permissions for the following actions: s3:PutObject, s3:GetBucketLocation, sns:GetTopicAttributes, sns:Publish, iam:GetRolePolicy.</p>
+    ///
+    /// See `MarketplaceCommerceAnalytics::start_support_data_export`.
+    pub fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
+        self.runtime
+            .block_on(self.client.start_support_data_export(input))
+    }
+}
@@ -1185,25 +1185,37 @@ impl AddFlowOutputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AddFlowOutputs420Exception" => {
-                    return RusotoError::Service(AddFlowOutputsError::AddFlowOutputs420(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowOutputsError::AddFlowOutputs420(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowOutputsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, AddFlowOutputsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowOutputsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, AddFlowOutputsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowOutputsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowOutputsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowOutputsError::NotFound(err.msg))
+                    return RusotoError::service(&res, AddFlowOutputsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowOutputsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowOutputsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowOutputsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowOutputsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1249,22 +1261,31 @@ impl AddFlowSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowSourcesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, AddFlowSourcesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowSourcesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, AddFlowSourcesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowSourcesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowSourcesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowSourcesError::NotFound(err.msg))
+                    return RusotoError::service(&res, AddFlowSourcesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowSourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowSourcesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowSourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowSourcesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1309,28 +1330,37 @@ impl AddFlowVpcInterfacesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowVpcInterfacesError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowVpcInterfacesError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowVpcInterfacesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::NotFound(err.msg))
+                    return RusotoError::service(&res, AddFlowVpcInterfacesError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowVpcInterfacesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddFlowVpcInterfacesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1375,22 +1405,25 @@ impl CreateFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFlowError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateFlowError::BadRequest(err.msg))
                 }
                 "CreateFlow420Exception" => {
-                    return RusotoError::Service(CreateFlowError::CreateFlow420(err.msg))
+                    return RusotoError::service(&res, CreateFlowError::CreateFlow420(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateFlowError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateFlowError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateFlowError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateFlowError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1435,22 +1468,25 @@ impl DeleteFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFlowError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteFlowError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteFlowError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteFlowError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFlowError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFlowError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFlowError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DeleteFlowError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteFlowError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1495,22 +1531,28 @@ impl DescribeFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeFlowError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeFlowError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeFlowError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeFlowError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFlowError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFlowError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeFlowError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFlowError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DescribeFlowError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1557,33 +1599,46 @@ impl GrantFlowEntitlementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GrantFlowEntitlementsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GrantFlowEntitlementsError::Forbidden(err.msg),
+                    )
                 }
                 "GrantFlowEntitlements420Exception" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GrantFlowEntitlementsError::GrantFlowEntitlements420(err.msg),
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GrantFlowEntitlementsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GrantFlowEntitlementsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GrantFlowEntitlementsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GrantFlowEntitlementsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1627,18 +1682,25 @@ impl ListEntitlementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEntitlementsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListEntitlementsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListEntitlementsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitlementsError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListEntitlementsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitlementsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitlementsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEntitlementsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1677,16 +1739,16 @@ impl ListFlowsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFlowsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListFlowsError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFlowsError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListFlowsError::InternalServerError(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListFlowsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListFlowsError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFlowsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListFlowsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1723,15 +1785,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1773,24 +1839,31 @@ impl RemoveFlowOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::BadRequest(err.msg))
+                    return RusotoError::service(&res, RemoveFlowOutputError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::Forbidden(err.msg))
+                    return RusotoError::service(&res, RemoveFlowOutputError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowOutputError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::NotFound(err.msg))
+                    return RusotoError::service(&res, RemoveFlowOutputError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowOutputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowOutputError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1835,24 +1908,31 @@ impl RemoveFlowSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, RemoveFlowSourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, RemoveFlowSourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowSourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, RemoveFlowSourceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowSourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowSourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1897,28 +1977,40 @@ impl RemoveFlowVpcInterfaceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowVpcInterfaceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowVpcInterfaceError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowVpcInterfaceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowVpcInterfaceError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowVpcInterfaceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveFlowVpcInterfaceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1963,28 +2055,40 @@ impl RevokeFlowEntitlementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeFlowEntitlementError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeFlowEntitlementError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeFlowEntitlementError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeFlowEntitlementError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeFlowEntitlementError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeFlowEntitlementError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2029,22 +2133,22 @@ impl StartFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartFlowError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartFlowError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartFlowError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StartFlowError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, StartFlowError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartFlowError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartFlowError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, StartFlowError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, StartFlowError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2089,22 +2193,22 @@ impl StopFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopFlowError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopFlowError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopFlowError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StopFlowError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, StopFlowError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopFlowError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopFlowError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, StopFlowError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, StopFlowError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2143,13 +2247,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2185,13 +2292,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2233,22 +2343,25 @@ impl UpdateFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateFlowError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateFlowError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFlowError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateFlowError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateFlowError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2293,28 +2406,40 @@ impl UpdateFlowEntitlementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowEntitlementError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowEntitlementError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowEntitlementError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowEntitlementError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowEntitlementError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowEntitlementError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2359,24 +2484,31 @@ impl UpdateFlowOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateFlowOutputError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateFlowOutputError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowOutputError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFlowOutputError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowOutputError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowOutputError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2421,24 +2553,31 @@ impl UpdateFlowSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateFlowSourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateFlowSourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowSourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateFlowSourceError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowSourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFlowSourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2614,6 +2753,7 @@ impl MediaConnectClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2629,6 +2769,7 @@ impl MediaConnectClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MediaConnectClient {
         MediaConnectClient { client, region }
     }
@@ -2645,6 +2786,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/outputs", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("AddFlowOutputs");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2676,6 +2820,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/source", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("AddFlowSources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2710,6 +2857,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("AddFlowVpcInterfaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2741,6 +2891,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("CreateFlow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2772,6 +2925,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("DeleteFlow");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2800,6 +2956,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("DescribeFlow");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2831,6 +2990,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("GrantFlowEntitlements");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2862,6 +3024,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/entitlements";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("ListEntitlements");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2899,6 +3064,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("ListFlows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2936,6 +3104,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2968,6 +3139,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("RemoveFlowOutput");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3000,6 +3174,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("RemoveFlowSource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3032,6 +3209,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("RemoveFlowVpcInterface");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3064,6 +3244,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("RevokeFlowEntitlement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3092,6 +3275,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/start/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("StartFlow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3120,6 +3306,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/stop/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("StopFlow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3148,6 +3337,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3178,6 +3370,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3211,6 +3406,9 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("UpdateFlow");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3246,6 +3444,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("UpdateFlowEntitlement");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3281,6 +3482,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("UpdateFlowOutput");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3316,6 +3520,9 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation_name("UpdateFlowSource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3338,3 +3545,279 @@ impl MediaConnect for MediaConnectClient {
         }
     }
 }
+
+/// A blocking client for the AWS MediaConnect API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MediaConnectClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaConnectBlockingClient {
+    client: MediaConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaConnectBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaConnectBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MediaConnectBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaConnectBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient {
+            client: MediaConnectClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds outputs to an existing flow. You can create up to 50 outputs per flow.</p>
+    ///
+    /// See `MediaConnect::add_flow_outputs`.
+    pub fn add_flow_outputs(
+        &self,
+        input: AddFlowOutputsRequest,
+    ) -> Result<AddFlowOutputsResponse, RusotoError<AddFlowOutputsError>> {
+        self.runtime.block_on(self.client.add_flow_outputs(input))
+    }
+
+    /// <p>Adds Sources to flow</p>
+    ///
+    /// See `MediaConnect::add_flow_sources`.
+    pub fn add_flow_sources(
+        &self,
+        input: AddFlowSourcesRequest,
+    ) -> Result<AddFlowSourcesResponse, RusotoError<AddFlowSourcesError>> {
+        self.runtime.block_on(self.client.add_flow_sources(input))
+    }
+
+    /// <p>Adds VPC interfaces to flow</p>
+    ///
+    /// See `MediaConnect::add_flow_vpc_interfaces`.
+    pub fn add_flow_vpc_interfaces(
+        &self,
+        input: AddFlowVpcInterfacesRequest,
+    ) -> Result<AddFlowVpcInterfacesResponse, RusotoError<AddFlowVpcInterfacesError>> {
+        self.runtime
+            .block_on(self.client.add_flow_vpc_interfaces(input))
+    }
+
+    /// <p>Creates a new flow. The request must include one source. The request optionally can include outputs (up to 50) and entitlements (up to 50).</p>
+    ///
+    /// See `MediaConnect::create_flow`.
+    pub fn create_flow(
+        &self,
+        input: CreateFlowRequest,
+    ) -> Result<CreateFlowResponse, RusotoError<CreateFlowError>> {
+        self.runtime.block_on(self.client.create_flow(input))
+    }
+
+    /// <p>Deletes a flow. Before you can delete a flow, you must stop the flow.</p>
+    ///
+    /// See `MediaConnect::delete_flow`.
+    pub fn delete_flow(
+        &self,
+        input: DeleteFlowRequest,
+    ) -> Result<DeleteFlowResponse, RusotoError<DeleteFlowError>> {
+        self.runtime.block_on(self.client.delete_flow(input))
+    }
+
+    /// <p>Displays the details of a flow. The response includes the flow ARN, name, and Availability Zone, as well as details about the source, outputs, and entitlements.</p>
+    ///
+    /// See `MediaConnect::describe_flow`.
+    pub fn describe_flow(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<DescribeFlowResponse, RusotoError<DescribeFlowError>> {
+        self.runtime.block_on(self.client.describe_flow(input))
+    }
+
+    /// <p>Grants entitlements to an existing flow.</p>
+    ///
+    /// See `MediaConnect::grant_flow_entitlements`.
+    pub fn grant_flow_entitlements(
+        &self,
+        input: GrantFlowEntitlementsRequest,
+    ) -> Result<GrantFlowEntitlementsResponse, RusotoError<GrantFlowEntitlementsError>> {
+        self.runtime
+            .block_on(self.client.grant_flow_entitlements(input))
+    }
+
+    /// <p>Displays a list of all entitlements that have been granted to this account. This request returns 20 results per page.</p>
+    ///
+    /// See `MediaConnect::list_entitlements`.
+    pub fn list_entitlements(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> Result<ListEntitlementsResponse, RusotoError<ListEntitlementsError>> {
+        self.runtime.block_on(self.client.list_entitlements(input))
+    }
+
+    /// <p>Displays a list of flows that are associated with this account. This request returns a paginated result.</p>
+    ///
+    /// See `MediaConnect::list_flows`.
+    pub fn list_flows(
+        &self,
+        input: ListFlowsRequest,
+    ) -> Result<ListFlowsResponse, RusotoError<ListFlowsError>> {
+        self.runtime.block_on(self.client.list_flows(input))
+    }
+
+    /// <p>List all tags on an AWS Elemental MediaConnect resource</p>
+    ///
+    /// See `MediaConnect::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Removes an output from an existing flow. This request can be made only on an output that does not have an entitlement associated with it. If the output has an entitlement, you must revoke the entitlement instead. When an entitlement is revoked from a flow, the service automatically removes the associated output.</p>
+    ///
+    /// See `MediaConnect::remove_flow_output`.
+    pub fn remove_flow_output(
+        &self,
+        input: RemoveFlowOutputRequest,
+    ) -> Result<RemoveFlowOutputResponse, RusotoError<RemoveFlowOutputError>> {
+        self.runtime.block_on(self.client.remove_flow_output(input))
+    }
+
+    /// <p>Removes a source from an existing flow. This request can be made only if there is more than one source on the flow.</p>
+    ///
+    /// See `MediaConnect::remove_flow_source`.
+    pub fn remove_flow_source(
+        &self,
+        input: RemoveFlowSourceRequest,
+    ) -> Result<RemoveFlowSourceResponse, RusotoError<RemoveFlowSourceError>> {
+        self.runtime.block_on(self.client.remove_flow_source(input))
+    }
+
+    /// <p>Removes a VPC Interface from an existing flow. This request can be made only on a VPC interface that does not have a Source or Output associated with it. If the VPC interface is referenced by a Source or Output, you must first delete or update the Source or Output to no longer reference the VPC interface.</p>
+    ///
+    /// See `MediaConnect::remove_flow_vpc_interface`.
+    pub fn remove_flow_vpc_interface(
+        &self,
+        input: RemoveFlowVpcInterfaceRequest,
+    ) -> Result<RemoveFlowVpcInterfaceResponse, RusotoError<RemoveFlowVpcInterfaceError>> {
+        self.runtime
+            .block_on(self.client.remove_flow_vpc_interface(input))
+    }
+
+    /// <p>Revokes an entitlement from a flow. Once an entitlement is revoked, the content becomes unavailable to the subscriber and the associated output is removed.</p>
+    ///
+    /// See `MediaConnect::revoke_flow_entitlement`.
+    pub fn revoke_flow_entitlement(
+        &self,
+        input: RevokeFlowEntitlementRequest,
+    ) -> Result<RevokeFlowEntitlementResponse, RusotoError<RevokeFlowEntitlementError>> {
+        self.runtime
+            .block_on(self.client.revoke_flow_entitlement(input))
+    }
+
+    /// <p>Starts a flow.</p>
+    ///
+    /// See `MediaConnect::start_flow`.
+    pub fn start_flow(
+        &self,
+        input: StartFlowRequest,
+    ) -> Result<StartFlowResponse, RusotoError<StartFlowError>> {
+        self.runtime.block_on(self.client.start_flow(input))
+    }
+
+    /// <p>Stops a flow.</p>
+    ///
+    /// See `MediaConnect::stop_flow`.
+    pub fn stop_flow(
+        &self,
+        input: StopFlowRequest,
+    ) -> Result<StopFlowResponse, RusotoError<StopFlowError>> {
+        self.runtime.block_on(self.client.stop_flow(input))
+    }
+
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    ///
+    /// See `MediaConnect::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes specified tags from a resource.</p>
+    ///
+    /// See `MediaConnect::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates flow</p>
+    ///
+    /// See `MediaConnect::update_flow`.
+    pub fn update_flow(
+        &self,
+        input: UpdateFlowRequest,
+    ) -> Result<UpdateFlowResponse, RusotoError<UpdateFlowError>> {
+        self.runtime.block_on(self.client.update_flow(input))
+    }
+
+    /// <p>You can change an entitlement&#39;s description, subscribers, and encryption. If you change the subscribers, the service will remove the outputs that are are used by the subscribers that are removed.</p>
+    ///
+    /// See `MediaConnect::update_flow_entitlement`.
+    pub fn update_flow_entitlement(
+        &self,
+        input: UpdateFlowEntitlementRequest,
+    ) -> Result<UpdateFlowEntitlementResponse, RusotoError<UpdateFlowEntitlementError>> {
+        self.runtime
+            .block_on(self.client.update_flow_entitlement(input))
+    }
+
+    /// <p>Updates an existing flow output.</p>
+    ///
+    /// See `MediaConnect::update_flow_output`.
+    pub fn update_flow_output(
+        &self,
+        input: UpdateFlowOutputRequest,
+    ) -> Result<UpdateFlowOutputResponse, RusotoError<UpdateFlowOutputError>> {
+        self.runtime.block_on(self.client.update_flow_output(input))
+    }
+
+    /// <p>Updates the source of a flow.</p>
+    ///
+    /// See `MediaConnect::update_flow_source`.
+    pub fn update_flow_source(
+        &self,
+        input: UpdateFlowSourceRequest,
+    ) -> Result<UpdateFlowSourceResponse, RusotoError<UpdateFlowSourceError>> {
+        self.runtime.block_on(self.client.update_flow_source(input))
+    }
+}
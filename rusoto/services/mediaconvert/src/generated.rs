@@ -4910,26 +4910,34 @@ impl AssociateCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCertificateError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateCertificateError::Conflict(err.msg))
+                    return RusotoError::service(&res, AssociateCertificateError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AssociateCertificateError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCertificateError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateCertificateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCertificateError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateCertificateError::NotFound(err.msg))
+                    return RusotoError::service(&res, AssociateCertificateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AssociateCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCertificateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4974,22 +4982,22 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CancelJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CancelJobError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CancelJobError::Conflict(err.msg))
+                    return RusotoError::service(&res, CancelJobError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CancelJobError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CancelJobError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CancelJobError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, CancelJobError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CancelJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, CancelJobError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CancelJobError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5034,22 +5042,22 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateJobError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateJobError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateJobError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateJobError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateJobError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateJobError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateJobError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateJobError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5094,24 +5102,28 @@ impl CreateJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateJobTemplateError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateJobTemplateError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateJobTemplateError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateJobTemplateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobTemplateError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateJobTemplateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5156,22 +5168,25 @@ impl CreatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePresetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreatePresetError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePresetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreatePresetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePresetError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePresetError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreatePresetError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5216,22 +5231,25 @@ impl CreateQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateQueueError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateQueueError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateQueueError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateQueueError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateQueueError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateQueueError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateQueueError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateQueueError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateQueueError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateQueueError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5276,24 +5294,28 @@ impl DeleteJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteJobTemplateError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteJobTemplateError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteJobTemplateError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobTemplateError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteJobTemplateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteJobTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5338,22 +5360,25 @@ impl DeletePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePresetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeletePresetError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePresetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeletePresetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePresetError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePresetError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeletePresetError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5398,22 +5423,25 @@ impl DeleteQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteQueueError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteQueueError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteQueueError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteQueueError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteQueueError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteQueueError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteQueueError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteQueueError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteQueueError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteQueueError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5458,24 +5486,28 @@ impl DescribeEndpointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEndpointsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeEndpointsError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEndpointsError::Conflict(err.msg))
+                    return RusotoError::service(&res, DescribeEndpointsError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEndpointsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeEndpointsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeEndpointsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeEndpointsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEndpointsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5520,26 +5552,40 @@ impl DisassociateCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateCertificateError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCertificateError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateCertificateError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCertificateError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DisassociateCertificateError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCertificateError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateCertificateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCertificateError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateCertificateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCertificateError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisassociateCertificateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCertificateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5584,18 +5630,22 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetJobError::BadRequest(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::service(&res, GetJobError::Conflict(err.msg))
                 }
-                "ConflictException" => return RusotoError::Service(GetJobError::Conflict(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetJobError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetJobError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetJobError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetJobError::InternalServerError(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, GetJobError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetJobError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5640,22 +5690,28 @@ impl GetJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetJobTemplateError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetJobTemplateError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetJobTemplateError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetJobTemplateError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobTemplateError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetJobTemplateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5700,22 +5756,22 @@ impl GetPresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPresetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetPresetError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetPresetError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetPresetError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPresetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetPresetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetPresetError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetPresetError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPresetError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetPresetError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetPresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetPresetError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5760,22 +5816,22 @@ impl GetQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetQueueError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetQueueError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetQueueError::Conflict(err.msg))
+                    return RusotoError::service(&res, GetQueueError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetQueueError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetQueueError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetQueueError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetQueueError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetQueueError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetQueueError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5820,24 +5876,28 @@ impl ListJobTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobTemplatesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListJobTemplatesError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListJobTemplatesError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListJobTemplatesError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListJobTemplatesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListJobTemplatesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListJobTemplatesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListJobTemplatesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobTemplatesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListJobTemplatesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListJobTemplatesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListJobTemplatesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5882,22 +5942,22 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListJobsError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListJobsError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListJobsError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListJobsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListJobsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListJobsError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListJobsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListJobsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5942,22 +6002,25 @@ impl ListPresetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPresetsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListPresetsError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPresetsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListPresetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPresetsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPresetsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPresetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListPresetsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6002,22 +6065,25 @@ impl ListQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListQueuesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListQueuesError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListQueuesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListQueuesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListQueuesError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListQueuesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListQueuesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListQueuesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6062,24 +6128,31 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6124,22 +6197,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6184,22 +6260,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6244,24 +6323,28 @@ impl UpdateJobTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateJobTemplateError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateJobTemplateError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateJobTemplateError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobTemplateError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateJobTemplateError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateJobTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6306,22 +6389,25 @@ impl UpdatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePresetError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdatePresetError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdatePresetError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdatePresetError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePresetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdatePresetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdatePresetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePresetError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePresetError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdatePresetError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePresetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdatePresetError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6366,22 +6452,25 @@ impl UpdateQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateQueueError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateQueueError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateQueueError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateQueueError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateQueueError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateQueueError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateQueueError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateQueueError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateQueueError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateQueueError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateQueueError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateQueueError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6575,6 +6664,7 @@ impl MediaConvertClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6590,6 +6680,7 @@ impl MediaConvertClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MediaConvertClient {
         MediaConvertClient { client, region }
     }
@@ -6606,6 +6697,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/certificates";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("AssociateCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6637,6 +6731,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6665,6 +6762,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/jobs";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6696,6 +6796,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/jobTemplates";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("CreateJobTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6727,6 +6830,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/presets";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("CreatePreset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6758,6 +6864,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/queues";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("CreateQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6789,6 +6898,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("DeleteJobTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6817,6 +6929,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("DeletePreset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6845,6 +6960,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("DeleteQueue");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6873,6 +6991,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/endpoints";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("DescribeEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6904,6 +7025,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/certificates/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("DisassociateCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6932,6 +7056,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("GetJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6960,6 +7087,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("GetJobTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6988,6 +7118,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("GetPreset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7016,6 +7149,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("GetQueue");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7044,6 +7180,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/jobTemplates";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("ListJobTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7090,6 +7229,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/jobs";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7136,6 +7278,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/presets";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("ListPresets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7182,6 +7327,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/queues";
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("ListQueues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7225,6 +7373,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/tags/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7253,6 +7404,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = "/2017-08-29/tags";
 
         let mut request = SignedRequest::new("POST", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7284,6 +7438,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/tags/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7315,6 +7472,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/jobTemplates/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("UpdateJobTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7346,6 +7506,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/presets/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("UpdatePreset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7377,6 +7540,9 @@ impl MediaConvert for MediaConvertClient {
         let request_uri = format!("/2017-08-29/queues/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "mediaconvert", &self.region, &request_uri);
+        request.set_operation_name("UpdateQueue");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7399,3 +7565,309 @@ impl MediaConvert for MediaConvertClient {
         }
     }
 }
+
+/// A blocking client for the MediaConvert API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MediaConvertClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaConvertBlockingClient {
+    client: MediaConvertClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaConvertBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaConvertBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MediaConvertBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaConvertBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaConvertBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaConvertBlockingClient {
+        MediaConvertBlockingClient {
+            client: MediaConvertClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates an AWS Certificate Manager (ACM) Amazon Resource Name (ARN) with AWS Elemental MediaConvert.</p>
+    ///
+    /// See `MediaConvert::associate_certificate`.
+    pub fn associate_certificate(
+        &self,
+        input: AssociateCertificateRequest,
+    ) -> Result<AssociateCertificateResponse, RusotoError<AssociateCertificateError>> {
+        self.runtime
+            .block_on(self.client.associate_certificate(input))
+    }
+
+    /// <p>Permanently cancel a job. Once you have canceled a job, you can&#39;t start it again.</p>
+    ///
+    /// See `MediaConvert::cancel_job`.
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p>Create a new transcoding job. For information about jobs and job settings, see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    ///
+    /// See `MediaConvert::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>Create a new job template. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    ///
+    /// See `MediaConvert::create_job_template`.
+    pub fn create_job_template(
+        &self,
+        input: CreateJobTemplateRequest,
+    ) -> Result<CreateJobTemplateResponse, RusotoError<CreateJobTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_job_template(input))
+    }
+
+    /// <p>Create a new preset. For information about job templates see the User Guide at http://docs.aws.amazon.com/mediaconvert/latest/ug/what-is.html</p>
+    ///
+    /// See `MediaConvert::create_preset`.
+    pub fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
+        self.runtime.block_on(self.client.create_preset(input))
+    }
+
+    /// <p>Create a new transcoding queue. For information about queues, see Working With Queues in the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/working-with-queues.html</p>
+    ///
+    /// See `MediaConvert::create_queue`.
+    pub fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<CreateQueueResponse, RusotoError<CreateQueueError>> {
+        self.runtime.block_on(self.client.create_queue(input))
+    }
+
+    /// <p>Permanently delete a job template you have created.</p>
+    ///
+    /// See `MediaConvert::delete_job_template`.
+    pub fn delete_job_template(
+        &self,
+        input: DeleteJobTemplateRequest,
+    ) -> Result<DeleteJobTemplateResponse, RusotoError<DeleteJobTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_job_template(input))
+    }
+
+    /// <p>Permanently delete a preset you have created.</p>
+    ///
+    /// See `MediaConvert::delete_preset`.
+    pub fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
+        self.runtime.block_on(self.client.delete_preset(input))
+    }
+
+    /// <p>Permanently delete a queue you have created.</p>
+    ///
+    /// See `MediaConvert::delete_queue`.
+    pub fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<DeleteQueueResponse, RusotoError<DeleteQueueError>> {
+        self.runtime.block_on(self.client.delete_queue(input))
+    }
+
+    /// <p>Send an request with an empty body to the regional API endpoint to get your account API endpoint.</p>
+    ///
+    /// See `MediaConvert::describe_endpoints`.
+    pub fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsRequest,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.runtime.block_on(self.client.describe_endpoints(input))
+    }
+
+    /// <p>Removes an association between the Amazon Resource Name (ARN) of an AWS Certificate Manager (ACM) certificate and an AWS Elemental MediaConvert resource.</p>
+    ///
+    /// See `MediaConvert::disassociate_certificate`.
+    pub fn disassociate_certificate(
+        &self,
+        input: DisassociateCertificateRequest,
+    ) -> Result<DisassociateCertificateResponse, RusotoError<DisassociateCertificateError>> {
+        self.runtime
+            .block_on(self.client.disassociate_certificate(input))
+    }
+
+    /// <p>Retrieve the JSON for a specific completed transcoding job.</p>
+    ///
+    /// See `MediaConvert::get_job`.
+    pub fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// <p>Retrieve the JSON for a specific job template.</p>
+    ///
+    /// See `MediaConvert::get_job_template`.
+    pub fn get_job_template(
+        &self,
+        input: GetJobTemplateRequest,
+    ) -> Result<GetJobTemplateResponse, RusotoError<GetJobTemplateError>> {
+        self.runtime.block_on(self.client.get_job_template(input))
+    }
+
+    /// <p>Retrieve the JSON for a specific preset.</p>
+    ///
+    /// See `MediaConvert::get_preset`.
+    pub fn get_preset(
+        &self,
+        input: GetPresetRequest,
+    ) -> Result<GetPresetResponse, RusotoError<GetPresetError>> {
+        self.runtime.block_on(self.client.get_preset(input))
+    }
+
+    /// <p>Retrieve the JSON for a specific queue.</p>
+    ///
+    /// See `MediaConvert::get_queue`.
+    pub fn get_queue(
+        &self,
+        input: GetQueueRequest,
+    ) -> Result<GetQueueResponse, RusotoError<GetQueueError>> {
+        self.runtime.block_on(self.client.get_queue(input))
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your job templates. This will return the templates themselves, not just a list of them. To retrieve the next twenty templates, use the nextToken string returned with the array</p>
+    ///
+    /// See `MediaConvert::list_job_templates`.
+    pub fn list_job_templates(
+        &self,
+        input: ListJobTemplatesRequest,
+    ) -> Result<ListJobTemplatesResponse, RusotoError<ListJobTemplatesError>> {
+        self.runtime.block_on(self.client.list_job_templates(input))
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your most recently created jobs. This array includes in-process, completed, and errored jobs. This will return the jobs themselves, not just a list of the jobs. To retrieve the twenty next most recent jobs, use the nextToken string returned with the array.</p>
+    ///
+    /// See `MediaConvert::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your presets. This will return the presets themselves, not just a list of them. To retrieve the next twenty presets, use the nextToken string returned with the array.</p>
+    ///
+    /// See `MediaConvert::list_presets`.
+    pub fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+        self.runtime.block_on(self.client.list_presets(input))
+    }
+
+    /// <p>Retrieve a JSON array of up to twenty of your queues. This will return the queues themselves, not just a list of them. To retrieve the next twenty queues, use the nextToken string returned with the array.</p>
+    ///
+    /// See `MediaConvert::list_queues`.
+    pub fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+        self.runtime.block_on(self.client.list_queues(input))
+    }
+
+    /// <p>Retrieve the tags for a MediaConvert resource.</p>
+    ///
+    /// See `MediaConvert::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Add tags to a MediaConvert queue, preset, or job template. For information about tagging, see the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/tagging-resources.html</p>
+    ///
+    /// See `MediaConvert::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove tags from a MediaConvert queue, preset, or job template. For information about tagging, see the User Guide at https://docs.aws.amazon.com/mediaconvert/latest/ug/tagging-resources.html</p>
+    ///
+    /// See `MediaConvert::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Modify one of your existing job templates.</p>
+    ///
+    /// See `MediaConvert::update_job_template`.
+    pub fn update_job_template(
+        &self,
+        input: UpdateJobTemplateRequest,
+    ) -> Result<UpdateJobTemplateResponse, RusotoError<UpdateJobTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_job_template(input))
+    }
+
+    /// <p>Modify one of your existing presets.</p>
+    ///
+    /// See `MediaConvert::update_preset`.
+    pub fn update_preset(
+        &self,
+        input: UpdatePresetRequest,
+    ) -> Result<UpdatePresetResponse, RusotoError<UpdatePresetError>> {
+        self.runtime.block_on(self.client.update_preset(input))
+    }
+
+    /// <p>Modify one of your existing queues.</p>
+    ///
+    /// See `MediaConvert::update_queue`.
+    pub fn update_queue(
+        &self,
+        input: UpdateQueueRequest,
+    ) -> Result<UpdateQueueResponse, RusotoError<UpdateQueueError>> {
+        self.runtime.block_on(self.client.update_queue(input))
+    }
+}
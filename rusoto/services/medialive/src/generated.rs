@@ -6215,32 +6215,46 @@ impl BatchUpdateScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateScheduleError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateScheduleError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::Forbidden(err.msg))
+                    return RusotoError::service(&res, BatchUpdateScheduleError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateScheduleError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateScheduleError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::NotFound(err.msg))
+                    return RusotoError::service(&res, BatchUpdateScheduleError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateScheduleError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateScheduleError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6291,28 +6305,34 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateChannelError::BadGateway(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6359,22 +6379,25 @@ impl CreateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateInputError::BadGateway(err.msg))
+                    return RusotoError::service(&res, CreateInputError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateInputError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateInputError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInputError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateInputError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, CreateInputError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateInputError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateInputError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6419,28 +6442,40 @@ impl CreateInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputSecurityGroupError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputSecurityGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputSecurityGroupError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputSecurityGroupError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateInputSecurityGroupError::InternalServerError(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateInputSecurityGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6489,28 +6524,40 @@ impl CreateMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(&res, CreateMultiplexError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateMultiplexError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateMultiplexError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateMultiplexError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateMultiplexError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6561,36 +6608,52 @@ impl CreateMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMultiplexProgramError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6633,16 +6696,19 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6689,28 +6755,31 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteChannelError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteChannelError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6761,28 +6830,31 @@ impl DeleteInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteInputError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInputError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteInputError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInputError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInputError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteInputError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteInputError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6831,31 +6903,46 @@ impl DeleteInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputSecurityGroupError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputSecurityGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputSecurityGroupError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputSecurityGroupError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInputSecurityGroupError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputSecurityGroupError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInputSecurityGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6905,28 +6992,37 @@ impl DeleteMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DeleteMultiplexError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteMultiplexError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteMultiplexError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteMultiplexError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteMultiplexError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6977,34 +7073,52 @@ impl DeleteMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMultiplexProgramError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7055,30 +7169,37 @@ impl DeleteReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteReservationError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DeleteReservationError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteReservationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteReservationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteReservationError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteReservationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteReservationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteReservationError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReservationError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteReservationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReservationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteReservationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteReservationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReservationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7127,25 +7248,31 @@ impl DeleteScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteScheduleError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DeleteScheduleError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteScheduleError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteScheduleError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteScheduleError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteScheduleError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, DeleteScheduleError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteScheduleError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduleError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScheduleError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteScheduleError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteScheduleError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7187,16 +7314,19 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7241,25 +7371,34 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeChannelError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7307,25 +7446,28 @@ impl DescribeInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DescribeInputError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7373,27 +7515,40 @@ impl DescribeInputDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputDeviceError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputDeviceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeInputDeviceError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputDeviceError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputDeviceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeInputDeviceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputDeviceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7443,37 +7598,46 @@ impl DescribeInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::BadGateway(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputSecurityGroupError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputSecurityGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputSecurityGroupError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputSecurityGroupError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInputSecurityGroupError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputSecurityGroupError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInputSecurityGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7523,27 +7687,34 @@ impl DescribeMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DescribeMultiplexError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeMultiplexError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeMultiplexError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeMultiplexError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeMultiplexError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7591,31 +7762,46 @@ impl DescribeMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexProgramError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexProgramError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexProgramError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexProgramError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMultiplexProgramError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexProgramError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMultiplexProgramError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7663,27 +7849,34 @@ impl DescribeOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DescribeOfferingError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeOfferingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeOfferingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeOfferingError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeOfferingError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOfferingError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeOfferingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOfferingError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeOfferingError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeOfferingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOfferingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7731,27 +7924,40 @@ impl DescribeReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeReservationError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReservationError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeReservationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReservationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeReservationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeReservationError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReservationError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeReservationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReservationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeReservationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeReservationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReservationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7799,27 +8005,34 @@ impl DescribeScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeScheduleError::BadGateway(err.msg))
+                    return RusotoError::service(&res, DescribeScheduleError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeScheduleError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeScheduleError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeScheduleError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeScheduleError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduleError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduleError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScheduleError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeScheduleError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeScheduleError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7865,22 +8078,25 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListChannelsError::BadGateway(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListChannelsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListChannelsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChannelsError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChannelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7925,24 +8141,31 @@ impl ListInputDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputDevicesError::BadGateway(err.msg))
+                    return RusotoError::service(&res, ListInputDevicesError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputDevicesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListInputDevicesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputDevicesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListInputDevicesError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputDevicesError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInputDevicesError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputDevicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInputDevicesError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInputDevicesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7987,28 +8210,40 @@ impl ListInputSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInputSecurityGroupsError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInputSecurityGroupsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInputSecurityGroupsError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInputSecurityGroupsError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInputSecurityGroupsError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInputSecurityGroupsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8053,22 +8288,25 @@ impl ListInputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputsError::BadGateway(err.msg))
+                    return RusotoError::service(&res, ListInputsError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListInputsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListInputsError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, ListInputsError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInputsError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListInputsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8115,31 +8353,46 @@ impl ListMultiplexProgramsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexProgramsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8185,22 +8438,31 @@ impl ListMultiplexesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListMultiplexesError::BadGateway(err.msg))
+                    return RusotoError::service(&res, ListMultiplexesError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMultiplexesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListMultiplexesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMultiplexesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListMultiplexesError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListMultiplexesError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexesError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMultiplexesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexesError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMultiplexesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMultiplexesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8245,22 +8507,25 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListOfferingsError::BadGateway(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListOfferingsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListOfferingsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListOfferingsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListOfferingsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOfferingsError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListOfferingsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListOfferingsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8305,24 +8570,31 @@ impl ListReservationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListReservationsError::BadGateway(err.msg))
+                    return RusotoError::service(&res, ListReservationsError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListReservationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListReservationsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListReservationsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListReservationsError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListReservationsError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListReservationsError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListReservationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListReservationsError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListReservationsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListReservationsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8363,18 +8635,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8421,30 +8697,37 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadGateway(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Conflict(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(PurchaseOfferingError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PurchaseOfferingError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PurchaseOfferingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PurchaseOfferingError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service(&res, PurchaseOfferingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PurchaseOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PurchaseOfferingError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8495,28 +8778,31 @@ impl StartChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StartChannelError::BadGateway(err.msg))
+                    return RusotoError::service(&res, StartChannelError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StartChannelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartChannelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartChannelError::Conflict(err.msg))
+                    return RusotoError::service(&res, StartChannelError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StartChannelError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StartChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, StartChannelError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartChannelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, StartChannelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8567,28 +8853,34 @@ impl StartMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StartMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(&res, StartMultiplexError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartMultiplexError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(&res, StartMultiplexError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StartMultiplexError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StartMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, StartMultiplexError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMultiplexError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartMultiplexError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMultiplexError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8639,28 +8931,31 @@ impl StopChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StopChannelError::BadGateway(err.msg))
+                    return RusotoError::service(&res, StopChannelError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StopChannelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopChannelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StopChannelError::Conflict(err.msg))
+                    return RusotoError::service(&res, StopChannelError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StopChannelError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StopChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, StopChannelError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopChannelError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, StopChannelError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8711,28 +9006,31 @@ impl StopMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StopMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StopMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StopMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StopMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopMultiplexError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, StopMultiplexError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8781,25 +9079,31 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateChannelError::BadGateway(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8851,35 +9155,43 @@ impl UpdateChannelClassError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateChannelClassError::BadGateway(err.msg))
+                    return RusotoError::service(&res, UpdateChannelClassError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelClassError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateChannelClassError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelClassError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateChannelClassError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelClassError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateChannelClassError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateChannelClassError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelClassError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelClassError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelClassError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateChannelClassError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateChannelClassError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateChannelClassError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelClassError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelClassError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelClassError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8929,25 +9241,28 @@ impl UpdateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputError::BadGateway(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateInputError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::GatewayTimeout(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateInputError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateInputError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8997,32 +9312,40 @@ impl UpdateInputDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::BadGateway(err.msg))
+                    return RusotoError::service(&res, UpdateInputDeviceError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateInputDeviceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateInputDeviceError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputDeviceError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputDeviceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateInputDeviceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputDeviceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputDeviceError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9071,29 +9394,46 @@ impl UpdateInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputSecurityGroupError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputSecurityGroupError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputSecurityGroupError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputSecurityGroupError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputSecurityGroupError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInputSecurityGroupError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInputSecurityGroupError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9143,28 +9483,37 @@ impl UpdateMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service(&res, UpdateMultiplexError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateMultiplexError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMultiplexError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateMultiplexError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateMultiplexError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMultiplexError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateMultiplexError::NotFound(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateMultiplexError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9215,34 +9564,52 @@ impl UpdateMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::BadGateway(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::Forbidden(err.msg),
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::GatewayTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::NotFound(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMultiplexProgramError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9293,30 +9660,37 @@ impl UpdateReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateReservationError::BadGateway(err.msg))
+                    return RusotoError::service(&res, UpdateReservationError::BadGateway(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateReservationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateReservationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateReservationError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateReservationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateReservationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateReservationError::Forbidden(err.msg))
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReservationError::GatewayTimeout(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateReservationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReservationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateReservationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateReservationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReservationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9638,6 +10012,7 @@ impl MediaLiveClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -9653,6 +10028,7 @@ impl MediaLiveClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveClient {
         MediaLiveClient { client, region }
     }
@@ -9672,6 +10048,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("BatchUpdateSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9703,6 +10082,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("CreateChannel");
+        request.set_read_only(false);
+        request.set_idempotent(input.request_id.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9734,6 +10116,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("CreateInput");
+        request.set_read_only(false);
+        request.set_idempotent(input.request_id.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9765,6 +10150,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("CreateInputSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9796,6 +10184,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("CreateMultiplex");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9830,6 +10221,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("CreateMultiplexProgram");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9864,6 +10258,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9894,6 +10291,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9922,6 +10322,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteInput");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9953,6 +10356,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteInputSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9984,6 +10390,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteMultiplex");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10016,6 +10425,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteMultiplexProgram");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10047,6 +10459,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteReservation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10078,6 +10493,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10109,6 +10527,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10142,6 +10563,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeChannel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10170,6 +10594,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeInput");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10201,6 +10628,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeInputDevice");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10233,6 +10663,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeInputSecurityGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10264,6 +10697,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeMultiplex");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10296,6 +10732,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeMultiplexProgram");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10327,6 +10766,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeOffering");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10358,6 +10800,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeReservation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10389,6 +10834,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("DescribeSchedule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10426,6 +10874,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListChannels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10463,6 +10914,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputDevices";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListInputDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10500,6 +10954,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListInputSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10537,6 +10994,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListInputs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10577,6 +11037,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListMultiplexPrograms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10614,6 +11077,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListMultiplexes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10651,6 +11117,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/offerings";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10718,6 +11187,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/reservations";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListReservations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10782,6 +11254,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10813,6 +11288,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("PurchaseOffering");
+        request.set_read_only(false);
+        request.set_idempotent(input.request_id.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10847,6 +11325,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("StartChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10878,6 +11359,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("StartMultiplex");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10909,6 +11393,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("StopChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10940,6 +11427,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation_name("StopMultiplex");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10968,6 +11458,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11002,6 +11495,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateChannelClass");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11033,6 +11529,9 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateInput");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11067,6 +11566,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateInputDevice");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11101,6 +11603,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateInputSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11135,6 +11640,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateMultiplex");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11170,6 +11678,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateMultiplexProgram");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11204,6 +11715,9 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation_name("UpdateReservation");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11226,3 +11740,530 @@ impl MediaLive for MediaLiveClient {
         }
     }
 }
+
+/// A blocking client for the MediaLive API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MediaLiveClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaLiveBlockingClient {
+    client: MediaLiveClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaLiveBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaLiveBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MediaLiveBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaLiveBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaLiveBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient {
+            client: MediaLiveClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Update a channel schedule</p>
+    ///
+    /// See `MediaLive::batch_update_schedule`.
+    pub fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> Result<BatchUpdateScheduleResponse, RusotoError<BatchUpdateScheduleError>> {
+        self.runtime
+            .block_on(self.client.batch_update_schedule(input))
+    }
+
+    /// <p>Creates a new channel</p>
+    ///
+    /// See `MediaLive::create_channel`.
+    pub fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.runtime.block_on(self.client.create_channel(input))
+    }
+
+    /// <p>Create an input</p>
+    ///
+    /// See `MediaLive::create_input`.
+    pub fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+        self.runtime.block_on(self.client.create_input(input))
+    }
+
+    /// <p>Creates a Input Security Group</p>
+    ///
+    /// See `MediaLive::create_input_security_group`.
+    pub fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> Result<CreateInputSecurityGroupResponse, RusotoError<CreateInputSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_input_security_group(input))
+    }
+
+    /// <p>Create a new multiplex.</p>
+    ///
+    /// See `MediaLive::create_multiplex`.
+    pub fn create_multiplex(
+        &self,
+        input: CreateMultiplexRequest,
+    ) -> Result<CreateMultiplexResponse, RusotoError<CreateMultiplexError>> {
+        self.runtime.block_on(self.client.create_multiplex(input))
+    }
+
+    /// <p>Create a new program in the multiplex.</p>
+    ///
+    /// See `MediaLive::create_multiplex_program`.
+    pub fn create_multiplex_program(
+        &self,
+        input: CreateMultiplexProgramRequest,
+    ) -> Result<CreateMultiplexProgramResponse, RusotoError<CreateMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.create_multiplex_program(input))
+    }
+
+    /// <p>Create tags for a resource</p>
+    ///
+    /// See `MediaLive::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Starts deletion of channel. The associated outputs are also deleted.</p>
+    ///
+    /// See `MediaLive::delete_channel`.
+    pub fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+        self.runtime.block_on(self.client.delete_channel(input))
+    }
+
+    /// <p>Deletes the input end point</p>
+    ///
+    /// See `MediaLive::delete_input`.
+    pub fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+        self.runtime.block_on(self.client.delete_input(input))
+    }
+
+    /// <p>Deletes an Input Security Group</p>
+    ///
+    /// See `MediaLive::delete_input_security_group`.
+    pub fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> Result<DeleteInputSecurityGroupResponse, RusotoError<DeleteInputSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_input_security_group(input))
+    }
+
+    /// <p>Delete a multiplex. The multiplex must be idle.</p>
+    ///
+    /// See `MediaLive::delete_multiplex`.
+    pub fn delete_multiplex(
+        &self,
+        input: DeleteMultiplexRequest,
+    ) -> Result<DeleteMultiplexResponse, RusotoError<DeleteMultiplexError>> {
+        self.runtime.block_on(self.client.delete_multiplex(input))
+    }
+
+    /// <p>Delete a program from a multiplex.</p>
+    ///
+    /// See `MediaLive::delete_multiplex_program`.
+    pub fn delete_multiplex_program(
+        &self,
+        input: DeleteMultiplexProgramRequest,
+    ) -> Result<DeleteMultiplexProgramResponse, RusotoError<DeleteMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.delete_multiplex_program(input))
+    }
+
+    /// <p>Delete an expired reservation.</p>
+    ///
+    /// See `MediaLive::delete_reservation`.
+    pub fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> Result<DeleteReservationResponse, RusotoError<DeleteReservationError>> {
+        self.runtime.block_on(self.client.delete_reservation(input))
+    }
+
+    /// <p>Delete all schedule actions on a channel.</p>
+    ///
+    /// See `MediaLive::delete_schedule`.
+    pub fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> Result<DeleteScheduleResponse, RusotoError<DeleteScheduleError>> {
+        self.runtime.block_on(self.client.delete_schedule(input))
+    }
+
+    /// <p>Removes tags for a resource</p>
+    ///
+    /// See `MediaLive::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Gets details about a channel</p>
+    ///
+    /// See `MediaLive::describe_channel`.
+    pub fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.runtime.block_on(self.client.describe_channel(input))
+    }
+
+    /// <p>Produces details about an input</p>
+    ///
+    /// See `MediaLive::describe_input`.
+    pub fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+        self.runtime.block_on(self.client.describe_input(input))
+    }
+
+    /// <p>Gets the details for the input device</p>
+    ///
+    /// See `MediaLive::describe_input_device`.
+    pub fn describe_input_device(
+        &self,
+        input: DescribeInputDeviceRequest,
+    ) -> Result<DescribeInputDeviceResponse, RusotoError<DescribeInputDeviceError>> {
+        self.runtime
+            .block_on(self.client.describe_input_device(input))
+    }
+
+    /// <p>Produces a summary of an Input Security Group</p>
+    ///
+    /// See `MediaLive::describe_input_security_group`.
+    pub fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> Result<DescribeInputSecurityGroupResponse, RusotoError<DescribeInputSecurityGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_input_security_group(input))
+    }
+
+    /// <p>Gets details about a multiplex.</p>
+    ///
+    /// See `MediaLive::describe_multiplex`.
+    pub fn describe_multiplex(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<DescribeMultiplexResponse, RusotoError<DescribeMultiplexError>> {
+        self.runtime.block_on(self.client.describe_multiplex(input))
+    }
+
+    /// <p>Get the details for a program in a multiplex.</p>
+    ///
+    /// See `MediaLive::describe_multiplex_program`.
+    pub fn describe_multiplex_program(
+        &self,
+        input: DescribeMultiplexProgramRequest,
+    ) -> Result<DescribeMultiplexProgramResponse, RusotoError<DescribeMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.describe_multiplex_program(input))
+    }
+
+    /// <p>Get details for an offering.</p>
+    ///
+    /// See `MediaLive::describe_offering`.
+    pub fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> Result<DescribeOfferingResponse, RusotoError<DescribeOfferingError>> {
+        self.runtime.block_on(self.client.describe_offering(input))
+    }
+
+    /// <p>Get details for a reservation.</p>
+    ///
+    /// See `MediaLive::describe_reservation`.
+    pub fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> Result<DescribeReservationResponse, RusotoError<DescribeReservationError>> {
+        self.runtime
+            .block_on(self.client.describe_reservation(input))
+    }
+
+    /// <p>Get a channel schedule</p>
+    ///
+    /// See `MediaLive::describe_schedule`.
+    pub fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> Result<DescribeScheduleResponse, RusotoError<DescribeScheduleError>> {
+        self.runtime.block_on(self.client.describe_schedule(input))
+    }
+
+    /// <p>Produces list of channels that have been created</p>
+    ///
+    /// See `MediaLive::list_channels`.
+    pub fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.runtime.block_on(self.client.list_channels(input))
+    }
+
+    /// <p>List input devices</p>
+    ///
+    /// See `MediaLive::list_input_devices`.
+    pub fn list_input_devices(
+        &self,
+        input: ListInputDevicesRequest,
+    ) -> Result<ListInputDevicesResponse, RusotoError<ListInputDevicesError>> {
+        self.runtime.block_on(self.client.list_input_devices(input))
+    }
+
+    /// <p>Produces a list of Input Security Groups for an account</p>
+    ///
+    /// See `MediaLive::list_input_security_groups`.
+    pub fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> Result<ListInputSecurityGroupsResponse, RusotoError<ListInputSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_input_security_groups(input))
+    }
+
+    /// <p>Produces list of inputs that have been created</p>
+    ///
+    /// See `MediaLive::list_inputs`.
+    pub fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+        self.runtime.block_on(self.client.list_inputs(input))
+    }
+
+    /// <p>List the programs that currently exist for a specific multiplex.</p>
+    ///
+    /// See `MediaLive::list_multiplex_programs`.
+    pub fn list_multiplex_programs(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> Result<ListMultiplexProgramsResponse, RusotoError<ListMultiplexProgramsError>> {
+        self.runtime
+            .block_on(self.client.list_multiplex_programs(input))
+    }
+
+    /// <p>Retrieve a list of the existing multiplexes.</p>
+    ///
+    /// See `MediaLive::list_multiplexes`.
+    pub fn list_multiplexes(
+        &self,
+        input: ListMultiplexesRequest,
+    ) -> Result<ListMultiplexesResponse, RusotoError<ListMultiplexesError>> {
+        self.runtime.block_on(self.client.list_multiplexes(input))
+    }
+
+    /// <p>List offerings available for purchase.</p>
+    ///
+    /// See `MediaLive::list_offerings`.
+    pub fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResponse, RusotoError<ListOfferingsError>> {
+        self.runtime.block_on(self.client.list_offerings(input))
+    }
+
+    /// <p>List purchased reservations.</p>
+    ///
+    /// See `MediaLive::list_reservations`.
+    pub fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> Result<ListReservationsResponse, RusotoError<ListReservationsError>> {
+        self.runtime.block_on(self.client.list_reservations(input))
+    }
+
+    /// <p>Produces list of tags that have been created for a resource</p>
+    ///
+    /// See `MediaLive::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Purchase an offering and create a reservation.</p>
+    ///
+    /// See `MediaLive::purchase_offering`.
+    pub fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResponse, RusotoError<PurchaseOfferingError>> {
+        self.runtime.block_on(self.client.purchase_offering(input))
+    }
+
+    /// <p>Starts an existing channel</p>
+    ///
+    /// See `MediaLive::start_channel`.
+    pub fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> Result<StartChannelResponse, RusotoError<StartChannelError>> {
+        self.runtime.block_on(self.client.start_channel(input))
+    }
+
+    /// <p>Start (run) the multiplex. Starting the multiplex does not start the channels. You must explicitly start each channel.</p>
+    ///
+    /// See `MediaLive::start_multiplex`.
+    pub fn start_multiplex(
+        &self,
+        input: StartMultiplexRequest,
+    ) -> Result<StartMultiplexResponse, RusotoError<StartMultiplexError>> {
+        self.runtime.block_on(self.client.start_multiplex(input))
+    }
+
+    /// <p>Stops a running channel</p>
+    ///
+    /// See `MediaLive::stop_channel`.
+    pub fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> Result<StopChannelResponse, RusotoError<StopChannelError>> {
+        self.runtime.block_on(self.client.stop_channel(input))
+    }
+
+    /// <p>Stops a running multiplex. If the multiplex isn&#39;t running, this action has no effect.</p>
+    ///
+    /// See `MediaLive::stop_multiplex`.
+    pub fn stop_multiplex(
+        &self,
+        input: StopMultiplexRequest,
+    ) -> Result<StopMultiplexResponse, RusotoError<StopMultiplexError>> {
+        self.runtime.block_on(self.client.stop_multiplex(input))
+    }
+
+    /// <p>Updates a channel.</p>
+    ///
+    /// See `MediaLive::update_channel`.
+    pub fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+        self.runtime.block_on(self.client.update_channel(input))
+    }
+
+    /// <p>Changes the class of the channel.</p>
+    ///
+    /// See `MediaLive::update_channel_class`.
+    pub fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> Result<UpdateChannelClassResponse, RusotoError<UpdateChannelClassError>> {
+        self.runtime
+            .block_on(self.client.update_channel_class(input))
+    }
+
+    /// <p>Updates an input.</p>
+    ///
+    /// See `MediaLive::update_input`.
+    pub fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+        self.runtime.block_on(self.client.update_input(input))
+    }
+
+    /// <p>Updates the parameters for the input device.</p>
+    ///
+    /// See `MediaLive::update_input_device`.
+    pub fn update_input_device(
+        &self,
+        input: UpdateInputDeviceRequest,
+    ) -> Result<UpdateInputDeviceResponse, RusotoError<UpdateInputDeviceError>> {
+        self.runtime
+            .block_on(self.client.update_input_device(input))
+    }
+
+    /// <p>Update an Input Security Group&#39;s Whilelists.</p>
+    ///
+    /// See `MediaLive::update_input_security_group`.
+    pub fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> Result<UpdateInputSecurityGroupResponse, RusotoError<UpdateInputSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.update_input_security_group(input))
+    }
+
+    /// <p>Updates a multiplex.</p>
+    ///
+    /// See `MediaLive::update_multiplex`.
+    pub fn update_multiplex(
+        &self,
+        input: UpdateMultiplexRequest,
+    ) -> Result<UpdateMultiplexResponse, RusotoError<UpdateMultiplexError>> {
+        self.runtime.block_on(self.client.update_multiplex(input))
+    }
+
+    /// <p>Update a program in a multiplex.</p>
+    ///
+    /// See `MediaLive::update_multiplex_program`.
+    pub fn update_multiplex_program(
+        &self,
+        input: UpdateMultiplexProgramRequest,
+    ) -> Result<UpdateMultiplexProgramResponse, RusotoError<UpdateMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.update_multiplex_program(input))
+    }
+
+    /// <p>Update reservation.</p>
+    ///
+    /// See `MediaLive::update_reservation`.
+    pub fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> Result<UpdateReservationResponse, RusotoError<UpdateReservationError>> {
+        self.runtime.block_on(self.client.update_reservation(input))
+    }
+}
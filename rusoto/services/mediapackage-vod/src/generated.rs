@@ -867,22 +867,31 @@ impl CreateAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAssetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateAssetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateAssetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssetError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAssetError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateAssetError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateAssetError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssetError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -929,32 +938,38 @@ impl CreatePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePackagingConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePackagingConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePackagingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePackagingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePackagingConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePackagingConfigurationError::UnprocessableEntity(err.msg),
                     )
                 }
@@ -1007,30 +1022,37 @@ impl CreatePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingGroupError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreatePackagingGroupError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePackagingGroupError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1075,22 +1097,31 @@ impl DeleteAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAssetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteAssetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssetError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAssetError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteAssetError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssetError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1137,32 +1168,38 @@ impl DeletePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePackagingConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePackagingConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePackagingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePackagingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePackagingConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeletePackagingConfigurationError::UnprocessableEntity(err.msg),
                     )
                 }
@@ -1215,30 +1252,37 @@ impl DeletePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingGroupError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeletePackagingGroupError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePackagingGroupError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1283,22 +1327,31 @@ impl DescribeAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeAssetError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeAssetError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeAssetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssetError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeAssetError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeAssetError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssetError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DescribeAssetError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssetError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1345,32 +1398,38 @@ impl DescribePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribePackagingConfigurationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePackagingConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePackagingConfigurationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePackagingConfigurationError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePackagingConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePackagingConfigurationError::UnprocessableEntity(err.msg),
                     )
                 }
@@ -1425,30 +1484,40 @@ impl DescribePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingGroupError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingGroupError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePackagingGroupError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1493,22 +1562,28 @@ impl ListAssetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAssetsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListAssetsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListAssetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssetsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAssetsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListAssetsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAssetsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListAssetsError::ServiceUnavailable(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAssetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListAssetsError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListAssetsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssetsError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1555,32 +1630,38 @@ impl ListPackagingConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingConfigurationsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPackagingConfigurationsError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingConfigurationsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPackagingConfigurationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingConfigurationsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPackagingConfigurationsError::UnprocessableEntity(err.msg),
                     )
                 }
@@ -1633,28 +1714,34 @@ impl ListPackagingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListPackagingGroupsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingGroupsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListPackagingGroupsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingGroupsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingGroupsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPackagingGroupsError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1765,30 +1852,37 @@ impl UpdatePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePackagingGroupError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePackagingGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdatePackagingGroupError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePackagingGroupError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePackagingGroupError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePackagingGroupError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1931,6 +2025,7 @@ impl MediaPackageVodClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1946,6 +2041,7 @@ impl MediaPackageVodClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageVodClient {
         MediaPackageVodClient { client, region }
     }
@@ -1963,6 +2059,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("CreateAsset");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1996,6 +2095,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("CreatePackagingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2028,6 +2130,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("CreatePackagingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2060,6 +2165,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("DeleteAsset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2090,6 +2198,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("DeletePackagingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2119,6 +2230,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("DeletePackagingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2147,6 +2261,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/assets/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("DescribeAsset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2178,6 +2295,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_configurations/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("DescribePackagingConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2206,6 +2326,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("DescribePackagingGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2234,6 +2357,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/assets";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("ListAssets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2275,6 +2401,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_configurations";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("ListPackagingConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2315,6 +2444,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_groups";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("ListPackagingGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2352,6 +2484,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2381,6 +2516,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2412,6 +2550,9 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2445,6 +2586,9 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation_name("UpdatePackagingGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2467,3 +2611,232 @@ impl MediaPackageVod for MediaPackageVodClient {
         }
     }
 }
+
+/// A blocking client for the MediaPackage Vod API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MediaPackageVodClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaPackageVodBlockingClient {
+    client: MediaPackageVodClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaPackageVodBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaPackageVodBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MediaPackageVodBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaPackageVodBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaPackageVodBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient {
+            client: MediaPackageVodClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new MediaPackage VOD Asset resource.</p>
+    ///
+    /// See `MediaPackageVod::create_asset`.
+    pub fn create_asset(
+        &self,
+        input: CreateAssetRequest,
+    ) -> Result<CreateAssetResponse, RusotoError<CreateAssetError>> {
+        self.runtime.block_on(self.client.create_asset(input))
+    }
+
+    /// <p>Creates a new MediaPackage VOD PackagingConfiguration resource.</p>
+    ///
+    /// See `MediaPackageVod::create_packaging_configuration`.
+    pub fn create_packaging_configuration(
+        &self,
+        input: CreatePackagingConfigurationRequest,
+    ) -> Result<CreatePackagingConfigurationResponse, RusotoError<CreatePackagingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_packaging_configuration(input))
+    }
+
+    /// <p>Creates a new MediaPackage VOD PackagingGroup resource.</p>
+    ///
+    /// See `MediaPackageVod::create_packaging_group`.
+    pub fn create_packaging_group(
+        &self,
+        input: CreatePackagingGroupRequest,
+    ) -> Result<CreatePackagingGroupResponse, RusotoError<CreatePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_packaging_group(input))
+    }
+
+    /// <p>Deletes an existing MediaPackage VOD Asset resource.</p>
+    ///
+    /// See `MediaPackageVod::delete_asset`.
+    pub fn delete_asset(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<DeleteAssetResponse, RusotoError<DeleteAssetError>> {
+        self.runtime.block_on(self.client.delete_asset(input))
+    }
+
+    /// <p>Deletes a MediaPackage VOD PackagingConfiguration resource.</p>
+    ///
+    /// See `MediaPackageVod::delete_packaging_configuration`.
+    pub fn delete_packaging_configuration(
+        &self,
+        input: DeletePackagingConfigurationRequest,
+    ) -> Result<DeletePackagingConfigurationResponse, RusotoError<DeletePackagingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_packaging_configuration(input))
+    }
+
+    /// <p>Deletes a MediaPackage VOD PackagingGroup resource.</p>
+    ///
+    /// See `MediaPackageVod::delete_packaging_group`.
+    pub fn delete_packaging_group(
+        &self,
+        input: DeletePackagingGroupRequest,
+    ) -> Result<DeletePackagingGroupResponse, RusotoError<DeletePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_packaging_group(input))
+    }
+
+    /// <p>Returns a description of a MediaPackage VOD Asset resource.</p>
+    ///
+    /// See `MediaPackageVod::describe_asset`.
+    pub fn describe_asset(
+        &self,
+        input: DescribeAssetRequest,
+    ) -> Result<DescribeAssetResponse, RusotoError<DescribeAssetError>> {
+        self.runtime.block_on(self.client.describe_asset(input))
+    }
+
+    /// <p>Returns a description of a MediaPackage VOD PackagingConfiguration resource.</p>
+    ///
+    /// See `MediaPackageVod::describe_packaging_configuration`.
+    pub fn describe_packaging_configuration(
+        &self,
+        input: DescribePackagingConfigurationRequest,
+    ) -> Result<
+        DescribePackagingConfigurationResponse,
+        RusotoError<DescribePackagingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_packaging_configuration(input))
+    }
+
+    /// <p>Returns a description of a MediaPackage VOD PackagingGroup resource.</p>
+    ///
+    /// See `MediaPackageVod::describe_packaging_group`.
+    pub fn describe_packaging_group(
+        &self,
+        input: DescribePackagingGroupRequest,
+    ) -> Result<DescribePackagingGroupResponse, RusotoError<DescribePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_packaging_group(input))
+    }
+
+    /// <p>Returns a collection of MediaPackage VOD Asset resources.</p>
+    ///
+    /// See `MediaPackageVod::list_assets`.
+    pub fn list_assets(
+        &self,
+        input: ListAssetsRequest,
+    ) -> Result<ListAssetsResponse, RusotoError<ListAssetsError>> {
+        self.runtime.block_on(self.client.list_assets(input))
+    }
+
+    /// <p>Returns a collection of MediaPackage VOD PackagingConfiguration resources.</p>
+    ///
+    /// See `MediaPackageVod::list_packaging_configurations`.
+    pub fn list_packaging_configurations(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> Result<ListPackagingConfigurationsResponse, RusotoError<ListPackagingConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_packaging_configurations(input))
+    }
+
+    /// <p>Returns a collection of MediaPackage VOD PackagingGroup resources.</p>
+    ///
+    /// See `MediaPackageVod::list_packaging_groups`.
+    pub fn list_packaging_groups(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> Result<ListPackagingGroupsResponse, RusotoError<ListPackagingGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_packaging_groups(input))
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified resource.</p>
+    ///
+    /// See `MediaPackageVod::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Adds tags to the specified resource. You can specify one or more tags to add.</p>
+    ///
+    /// See `MediaPackageVod::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from the specified resource. You can specify one or more tags to remove.</p>
+    ///
+    /// See `MediaPackageVod::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a specific packaging group. You can&#39;t change the id attribute or any other system-generated attributes.</p>
+    ///
+    /// See `MediaPackageVod::update_packaging_group`.
+    pub fn update_packaging_group(
+        &self,
+        input: UpdatePackagingGroupRequest,
+    ) -> Result<UpdatePackagingGroupResponse, RusotoError<UpdatePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_packaging_group(input))
+    }
+}
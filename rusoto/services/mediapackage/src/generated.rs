@@ -1387,22 +1387,31 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateChannelError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateChannelError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1447,26 +1456,34 @@ impl CreateHarvestJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateHarvestJobError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateHarvestJobError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateHarvestJobError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHarvestJobError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateHarvestJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateHarvestJobError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateHarvestJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHarvestJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateHarvestJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHarvestJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateHarvestJobError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHarvestJobError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1511,30 +1528,37 @@ impl CreateOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOriginEndpointError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOriginEndpointError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateOriginEndpointError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOriginEndpointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOriginEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOriginEndpointError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1579,22 +1603,31 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteChannelError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChannelError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1639,30 +1672,37 @@ impl DeleteOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOriginEndpointError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOriginEndpointError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteOriginEndpointError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOriginEndpointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOriginEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOriginEndpointError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1707,22 +1747,34 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeChannelError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChannelError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1767,28 +1819,34 @@ impl DescribeHarvestJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeHarvestJobError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHarvestJobError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeHarvestJobError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHarvestJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHarvestJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeHarvestJobError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHarvestJobError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1833,30 +1891,40 @@ impl DescribeOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOriginEndpointError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOriginEndpointError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOriginEndpointError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOriginEndpointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOriginEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOriginEndpointError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1901,22 +1969,31 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListChannelsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChannelsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListChannelsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChannelsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChannelsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListChannelsError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListChannelsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListChannelsError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1961,22 +2038,34 @@ impl ListHarvestJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListHarvestJobsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListHarvestJobsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListHarvestJobsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHarvestJobsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListHarvestJobsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListHarvestJobsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListHarvestJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHarvestJobsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListHarvestJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHarvestJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListHarvestJobsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHarvestJobsError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2021,28 +2110,34 @@ impl ListOriginEndpointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListOriginEndpointsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOriginEndpointsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListOriginEndpointsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOriginEndpointsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOriginEndpointsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListOriginEndpointsError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOriginEndpointsError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2109,28 +2204,38 @@ impl RotateChannelCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RotateChannelCredentialsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RotateChannelCredentialsError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RotateChannelCredentialsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RotateChannelCredentialsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RotateChannelCredentialsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RotateChannelCredentialsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RotateChannelCredentialsError::UnprocessableEntity(err.msg),
                     )
                 }
@@ -2179,32 +2284,38 @@ impl RotateIngestEndpointCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(RotateIngestEndpointCredentialsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RotateIngestEndpointCredentialsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RotateIngestEndpointCredentialsError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RotateIngestEndpointCredentialsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RotateIngestEndpointCredentialsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RotateIngestEndpointCredentialsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RotateIngestEndpointCredentialsError::TooManyRequests(err.msg),
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RotateIngestEndpointCredentialsError::UnprocessableEntity(err.msg),
                     )
                 }
@@ -2303,22 +2414,31 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateChannelError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateChannelError::TooManyRequests(err.msg))
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChannelError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2363,30 +2483,37 @@ impl UpdateOriginEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOriginEndpointError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOriginEndpointError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateOriginEndpointError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOriginEndpointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOriginEndpointError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateOriginEndpointError::UnprocessableEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOriginEndpointError::UnprocessableEntity(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2538,6 +2665,7 @@ impl MediaPackageClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2553,6 +2681,7 @@ impl MediaPackageClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageClient {
         MediaPackageClient { client, region }
     }
@@ -2569,6 +2698,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("CreateChannel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2600,6 +2732,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/harvest_jobs";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("CreateHarvestJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2631,6 +2766,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("CreateOriginEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2662,6 +2800,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("DeleteChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2690,6 +2831,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("DeleteOriginEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2718,6 +2862,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("DescribeChannel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2746,6 +2893,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/harvest_jobs/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("DescribeHarvestJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2774,6 +2924,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("DescribeOriginEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2802,6 +2955,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("ListChannels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2839,6 +2995,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/harvest_jobs";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("ListHarvestJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2882,6 +3041,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = "/origin_endpoints";
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("ListOriginEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2921,6 +3083,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2949,6 +3114,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}/credentials", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("RotateChannelCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2984,6 +3152,9 @@ impl MediaPackage for MediaPackageClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("RotateIngestEndpointCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3013,6 +3184,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3042,6 +3216,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3075,6 +3252,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/channels/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("UpdateChannel");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3106,6 +3286,9 @@ impl MediaPackage for MediaPackageClient {
         let request_uri = format!("/origin_endpoints/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage", &self.region, &request_uri);
+        request.set_operation_name("UpdateOriginEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3128,3 +3311,239 @@ impl MediaPackage for MediaPackageClient {
         }
     }
 }
+
+/// A blocking client for the MediaPackage API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MediaPackageClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaPackageBlockingClient {
+    client: MediaPackageClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaPackageBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaPackageBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MediaPackageBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaPackageBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaPackageBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageBlockingClient {
+        MediaPackageBlockingClient {
+            client: MediaPackageClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new Channel.</p>
+    ///
+    /// See `MediaPackage::create_channel`.
+    pub fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.runtime.block_on(self.client.create_channel(input))
+    }
+
+    /// <p>Creates a new HarvestJob record.</p>
+    ///
+    /// See `MediaPackage::create_harvest_job`.
+    pub fn create_harvest_job(
+        &self,
+        input: CreateHarvestJobRequest,
+    ) -> Result<CreateHarvestJobResponse, RusotoError<CreateHarvestJobError>> {
+        self.runtime.block_on(self.client.create_harvest_job(input))
+    }
+
+    /// <p>Creates a new OriginEndpoint record.</p>
+    ///
+    /// See `MediaPackage::create_origin_endpoint`.
+    pub fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> Result<CreateOriginEndpointResponse, RusotoError<CreateOriginEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_origin_endpoint(input))
+    }
+
+    /// <p>Deletes an existing Channel.</p>
+    ///
+    /// See `MediaPackage::delete_channel`.
+    pub fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+        self.runtime.block_on(self.client.delete_channel(input))
+    }
+
+    /// <p>Deletes an existing OriginEndpoint.</p>
+    ///
+    /// See `MediaPackage::delete_origin_endpoint`.
+    pub fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> Result<DeleteOriginEndpointResponse, RusotoError<DeleteOriginEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_origin_endpoint(input))
+    }
+
+    /// <p>Gets details about a Channel.</p>
+    ///
+    /// See `MediaPackage::describe_channel`.
+    pub fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.runtime.block_on(self.client.describe_channel(input))
+    }
+
+    /// <p>Gets details about an existing HarvestJob.</p>
+    ///
+    /// See `MediaPackage::describe_harvest_job`.
+    pub fn describe_harvest_job(
+        &self,
+        input: DescribeHarvestJobRequest,
+    ) -> Result<DescribeHarvestJobResponse, RusotoError<DescribeHarvestJobError>> {
+        self.runtime
+            .block_on(self.client.describe_harvest_job(input))
+    }
+
+    /// <p>Gets details about an existing OriginEndpoint.</p>
+    ///
+    /// See `MediaPackage::describe_origin_endpoint`.
+    pub fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> Result<DescribeOriginEndpointResponse, RusotoError<DescribeOriginEndpointError>> {
+        self.runtime
+            .block_on(self.client.describe_origin_endpoint(input))
+    }
+
+    /// <p>Returns a collection of Channels.</p>
+    ///
+    /// See `MediaPackage::list_channels`.
+    pub fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.runtime.block_on(self.client.list_channels(input))
+    }
+
+    /// <p>Returns a collection of HarvestJob records.</p>
+    ///
+    /// See `MediaPackage::list_harvest_jobs`.
+    pub fn list_harvest_jobs(
+        &self,
+        input: ListHarvestJobsRequest,
+    ) -> Result<ListHarvestJobsResponse, RusotoError<ListHarvestJobsError>> {
+        self.runtime.block_on(self.client.list_harvest_jobs(input))
+    }
+
+    /// <p>Returns a collection of OriginEndpoint records.</p>
+    ///
+    /// See `MediaPackage::list_origin_endpoints`.
+    pub fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> Result<ListOriginEndpointsResponse, RusotoError<ListOriginEndpointsError>> {
+        self.runtime
+            .block_on(self.client.list_origin_endpoints(input))
+    }
+
+    /// See `MediaPackage::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Changes the Channel&#39;s first IngestEndpoint&#39;s username and password. WARNING - This API is deprecated. Please use RotateIngestEndpointCredentials instead</p>
+    ///
+    /// See `MediaPackage::rotate_channel_credentials`.
+    pub fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> Result<RotateChannelCredentialsResponse, RusotoError<RotateChannelCredentialsError>> {
+        self.runtime
+            .block_on(self.client.rotate_channel_credentials(input))
+    }
+
+    /// <p>Rotate the IngestEndpoint&#39;s username and password, as specified by the IngestEndpoint&#39;s id.</p>
+    ///
+    /// See `MediaPackage::rotate_ingest_endpoint_credentials`.
+    pub fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> Result<
+        RotateIngestEndpointCredentialsResponse,
+        RusotoError<RotateIngestEndpointCredentialsError>,
+    > {
+        self.runtime
+            .block_on(self.client.rotate_ingest_endpoint_credentials(input))
+    }
+
+    /// See `MediaPackage::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// See `MediaPackage::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates an existing Channel.</p>
+    ///
+    /// See `MediaPackage::update_channel`.
+    pub fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+        self.runtime.block_on(self.client.update_channel(input))
+    }
+
+    /// <p>Updates an existing OriginEndpoint.</p>
+    ///
+    /// See `MediaPackage::update_origin_endpoint`.
+    pub fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> Result<UpdateOriginEndpointResponse, RusotoError<UpdateOriginEndpointError>> {
+        self.runtime
+            .block_on(self.client.update_origin_endpoint(input))
+    }
+}
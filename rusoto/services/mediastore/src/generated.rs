@@ -472,13 +472,19 @@ impl CreateContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(CreateContainerError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateContainerError::ContainerInUse(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateContainerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateContainerError::InternalServerError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContainerError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateContainerError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -514,13 +520,22 @@ impl DeleteContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteContainerError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteContainerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -558,24 +573,28 @@ impl DeleteContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::ContainerInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::PolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteContainerPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -614,18 +633,28 @@ impl DeleteCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCorsPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCorsPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "CorsPolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::CorsPolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCorsPolicyError::CorsPolicyNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCorsPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -664,24 +693,28 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ContainerInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLifecyclePolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLifecyclePolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLifecyclePolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::PolicyNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLifecyclePolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -720,20 +753,28 @@ impl DeleteMetricPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMetricPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -768,12 +809,16 @@ impl DescribeContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DescribeContainerError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContainerError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeContainerError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeContainerError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -810,20 +855,28 @@ impl GetContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetContainerPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContainerPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetContainerPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContainerPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetContainerPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContainerPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetContainerPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContainerPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -862,16 +915,25 @@ impl GetCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(&res, GetCorsPolicyError::ContainerInUse(err.msg))
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCorsPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "CorsPolicyNotFoundException" => {
-                    return RusotoError::Service(GetCorsPolicyError::CorsPolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCorsPolicyError::CorsPolicyNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCorsPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCorsPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -910,20 +972,28 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLifecyclePolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -962,16 +1032,28 @@ impl GetMetricPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetMetricPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMetricPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetMetricPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMetricPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetMetricPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMetricPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetMetricPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMetricPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1004,7 +1086,10 @@ impl ListContainersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListContainersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListContainersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1038,17 +1123,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1084,17 +1174,22 @@ impl PutContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutContainerPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutContainerPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutContainerPolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutContainerPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutContainerPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutContainerPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1130,13 +1225,19 @@ impl PutCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(&res, PutCorsPolicyError::ContainerInUse(err.msg))
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutCorsPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutCorsPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutCorsPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1172,17 +1273,22 @@ impl PutLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLifecyclePolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLifecyclePolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLifecyclePolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1218,13 +1324,22 @@ impl PutMetricPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutMetricPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricPolicyError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutMetricPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricPolicyError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutMetricPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMetricPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1260,17 +1375,22 @@ impl StartAccessLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(StartAccessLoggingError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartAccessLoggingError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(StartAccessLoggingError::ContainerNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAccessLoggingError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartAccessLoggingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAccessLoggingError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1306,15 +1426,22 @@ impl StopAccessLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(StopAccessLoggingError::ContainerInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAccessLoggingError::ContainerInUse(err.msg),
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(StopAccessLoggingError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAccessLoggingError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopAccessLoggingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopAccessLoggingError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1350,13 +1477,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(TagResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ContainerInUse(err.msg))
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ContainerNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ContainerNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1392,13 +1522,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ContainerInUse(err.msg))
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ContainerNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ContainerNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1565,6 +1701,7 @@ impl MediaStoreClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1580,6 +1717,7 @@ impl MediaStoreClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreClient {
         MediaStoreClient { client, region }
     }
@@ -1593,6 +1731,9 @@ impl MediaStore for MediaStoreClient {
         input: CreateContainerInput,
     ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateContainer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.CreateContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1611,6 +1752,9 @@ impl MediaStore for MediaStoreClient {
         input: DeleteContainerInput,
     ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteContainer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1629,6 +1773,9 @@ impl MediaStore for MediaStoreClient {
         input: DeleteContainerPolicyInput,
     ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteContainerPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1647,6 +1794,9 @@ impl MediaStore for MediaStoreClient {
         input: DeleteCorsPolicyInput,
     ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCorsPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1665,6 +1815,9 @@ impl MediaStore for MediaStoreClient {
         input: DeleteLifecyclePolicyInput,
     ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLifecyclePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1683,6 +1836,9 @@ impl MediaStore for MediaStoreClient {
         input: DeleteMetricPolicyInput,
     ) -> Result<DeleteMetricPolicyOutput, RusotoError<DeleteMetricPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMetricPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteMetricPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1701,6 +1857,9 @@ impl MediaStore for MediaStoreClient {
         input: DescribeContainerInput,
     ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeContainer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.DescribeContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1719,6 +1878,9 @@ impl MediaStore for MediaStoreClient {
         input: GetContainerPolicyInput,
     ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetContainerPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.GetContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1737,6 +1899,9 @@ impl MediaStore for MediaStoreClient {
         input: GetCorsPolicyInput,
     ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCorsPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.GetCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1755,6 +1920,9 @@ impl MediaStore for MediaStoreClient {
         input: GetLifecyclePolicyInput,
     ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLifecyclePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.GetLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1773,6 +1941,9 @@ impl MediaStore for MediaStoreClient {
         input: GetMetricPolicyInput,
     ) -> Result<GetMetricPolicyOutput, RusotoError<GetMetricPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMetricPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.GetMetricPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1791,6 +1962,9 @@ impl MediaStore for MediaStoreClient {
         input: ListContainersInput,
     ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListContainers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.ListContainers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1809,6 +1983,9 @@ impl MediaStore for MediaStoreClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MediaStore_20170901.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1827,6 +2004,9 @@ impl MediaStore for MediaStoreClient {
         input: PutContainerPolicyInput,
     ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutContainerPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.PutContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1845,6 +2025,9 @@ impl MediaStore for MediaStoreClient {
         input: PutCorsPolicyInput,
     ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutCorsPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.PutCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1863,6 +2046,9 @@ impl MediaStore for MediaStoreClient {
         input: PutLifecyclePolicyInput,
     ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutLifecyclePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.PutLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1881,6 +2067,9 @@ impl MediaStore for MediaStoreClient {
         input: PutMetricPolicyInput,
     ) -> Result<PutMetricPolicyOutput, RusotoError<PutMetricPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutMetricPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.PutMetricPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1899,6 +2088,9 @@ impl MediaStore for MediaStoreClient {
         input: StartAccessLoggingInput,
     ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartAccessLogging");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.StartAccessLogging");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1917,6 +2109,9 @@ impl MediaStore for MediaStoreClient {
         input: StopAccessLoggingInput,
     ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopAccessLogging");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.StopAccessLogging");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1935,6 +2130,9 @@ impl MediaStore for MediaStoreClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1953,6 +2151,9 @@ impl MediaStore for MediaStoreClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MediaStore_20170901.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1965,3 +2166,273 @@ impl MediaStore for MediaStoreClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 }
+
+/// A blocking client for the MediaStore API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MediaStoreClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaStoreBlockingClient {
+    client: MediaStoreClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaStoreBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MediaStoreBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MediaStoreBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaStoreBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaStoreBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient {
+            client: MediaStoreClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a storage container to hold objects. A container is similar to a bucket in the Amazon S3 service.</p>
+    ///
+    /// See `MediaStore::create_container`.
+    pub fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
+        self.runtime.block_on(self.client.create_container(input))
+    }
+
+    /// <p>Deletes the specified container. Before you make a <code>DeleteContainer</code> request, delete any objects in the container or in any folders in the container. You can delete only empty containers. </p>
+    ///
+    /// See `MediaStore::delete_container`.
+    pub fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
+        self.runtime.block_on(self.client.delete_container(input))
+    }
+
+    /// <p>Deletes the access policy that is associated with the specified container.</p>
+    ///
+    /// See `MediaStore::delete_container_policy`.
+    pub fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_container_policy(input))
+    }
+
+    /// <p>Deletes the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:DeleteCorsPolicy</code> action. The container owner has this permission by default and can grant this permission to others.</p>
+    ///
+    /// See `MediaStore::delete_cors_policy`.
+    pub fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
+        self.runtime.block_on(self.client.delete_cors_policy(input))
+    }
+
+    /// <p>Removes an object lifecycle policy from a container. It takes up to 20 minutes for the change to take effect.</p>
+    ///
+    /// See `MediaStore::delete_lifecycle_policy`.
+    pub fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_lifecycle_policy(input))
+    }
+
+    /// <p>Deletes the metric policy that is associated with the specified container. If there is no metric policy associated with the container, MediaStore doesn't send metrics to CloudWatch.</p>
+    ///
+    /// See `MediaStore::delete_metric_policy`.
+    pub fn delete_metric_policy(
+        &self,
+        input: DeleteMetricPolicyInput,
+    ) -> Result<DeleteMetricPolicyOutput, RusotoError<DeleteMetricPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_metric_policy(input))
+    }
+
+    /// <p>Retrieves the properties of the requested container. This request is commonly used to retrieve the endpoint of a container. An endpoint is a value assigned by the service when a new container is created. A container's endpoint does not change after it has been assigned. The <code>DescribeContainer</code> request returns a single <code>Container</code> object based on <code>ContainerName</code>. To return all <code>Container</code> objects that are associated with a specified AWS account, use <a>ListContainers</a>.</p>
+    ///
+    /// See `MediaStore::describe_container`.
+    pub fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
+        self.runtime.block_on(self.client.describe_container(input))
+    }
+
+    /// <p>Retrieves the access policy for the specified container. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p>
+    ///
+    /// See `MediaStore::get_container_policy`.
+    pub fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_container_policy(input))
+    }
+
+    /// <p>Returns the cross-origin resource sharing (CORS) configuration information that is set for the container.</p> <p>To use this operation, you must have permission to perform the <code>MediaStore:GetCorsPolicy</code> action. By default, the container owner has this permission and can grant it to others.</p>
+    ///
+    /// See `MediaStore::get_cors_policy`.
+    pub fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
+        self.runtime.block_on(self.client.get_cors_policy(input))
+    }
+
+    /// <p>Retrieves the object lifecycle policy that is assigned to a container.</p>
+    ///
+    /// See `MediaStore::get_lifecycle_policy`.
+    pub fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_lifecycle_policy(input))
+    }
+
+    /// <p>Returns the metric policy for the specified container. </p>
+    ///
+    /// See `MediaStore::get_metric_policy`.
+    pub fn get_metric_policy(
+        &self,
+        input: GetMetricPolicyInput,
+    ) -> Result<GetMetricPolicyOutput, RusotoError<GetMetricPolicyError>> {
+        self.runtime.block_on(self.client.get_metric_policy(input))
+    }
+
+    /// <p>Lists the properties of all containers in AWS Elemental MediaStore. </p> <p>You can query to receive all the containers in one response. Or you can include the <code>MaxResults</code> parameter to receive a limited number of containers in each response. In this case, the response includes a token. To get the next set of containers, send the command again, this time with the <code>NextToken</code> parameter (with the returned token as its value). The next set of responses appears, with a token if there are still more containers to receive. </p> <p>See also <a>DescribeContainer</a>, which gets the properties of one container. </p>
+    ///
+    /// See `MediaStore::list_containers`.
+    pub fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
+        self.runtime.block_on(self.client.list_containers(input))
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified container. </p>
+    ///
+    /// See `MediaStore::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates an access policy for the specified container to restrict the users and clients that can access it. For information about the data that is included in an access policy, see the <a href="https://aws.amazon.com/documentation/iam/">AWS Identity and Access Management User Guide</a>.</p> <p>For this release of the REST API, you can create only one policy for a container. If you enter <code>PutContainerPolicy</code> twice, the second command modifies the existing policy. </p>
+    ///
+    /// See `MediaStore::put_container_policy`.
+    pub fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_container_policy(input))
+    }
+
+    /// <p>Sets the cross-origin resource sharing (CORS) configuration on a container so that the container can service cross-origin requests. For example, you might want to enable a request whose origin is http://www.example.com to access your AWS Elemental MediaStore container at my.example.container.com by using the browser's XMLHttpRequest capability.</p> <p>To enable CORS on a container, you attach a CORS policy to the container. In the CORS policy, you configure rules that identify origins and the HTTP methods that can be executed on your container. The policy can contain up to 398,000 characters. You can add up to 100 rules to a CORS policy. If more than one rule applies, the service uses the first applicable rule listed.</p> <p>To learn more about CORS, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/cors-policy.html">Cross-Origin Resource Sharing (CORS) in AWS Elemental MediaStore</a>.</p>
+    ///
+    /// See `MediaStore::put_cors_policy`.
+    pub fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
+        self.runtime.block_on(self.client.put_cors_policy(input))
+    }
+
+    /// <p>Writes an object lifecycle policy to a container. If the container already has an object lifecycle policy, the service replaces the existing policy with the new policy. It takes up to 20 minutes for the change to take effect.</p> <p>For information about how to construct an object lifecycle policy, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/policies-object-lifecycle-components.html">Components of an Object Lifecycle Policy</a>.</p>
+    ///
+    /// See `MediaStore::put_lifecycle_policy`.
+    pub fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_lifecycle_policy(input))
+    }
+
+    /// <p>The metric policy that you want to add to the container. A metric policy allows AWS Elemental MediaStore to send metrics to Amazon CloudWatch. It takes up to 20 minutes for the new policy to take effect.</p>
+    ///
+    /// See `MediaStore::put_metric_policy`.
+    pub fn put_metric_policy(
+        &self,
+        input: PutMetricPolicyInput,
+    ) -> Result<PutMetricPolicyOutput, RusotoError<PutMetricPolicyError>> {
+        self.runtime.block_on(self.client.put_metric_policy(input))
+    }
+
+    /// <p>Starts access logging on the specified container. When you enable access logging on a container, MediaStore delivers access logs for objects stored in that container to Amazon CloudWatch Logs.</p>
+    ///
+    /// See `MediaStore::start_access_logging`.
+    pub fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
+        self.runtime
+            .block_on(self.client.start_access_logging(input))
+    }
+
+    /// <p>Stops access logging on the specified container. When you stop access logging on a container, MediaStore stops sending access logs to Amazon CloudWatch Logs. These access logs are not saved and are not retrievable.</p>
+    ///
+    /// See `MediaStore::stop_access_logging`.
+    pub fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
+        self.runtime
+            .block_on(self.client.stop_access_logging(input))
+    }
+
+    /// <p>Adds tags to the specified AWS Elemental MediaStore container. Tags are key:value pairs that you can associate with AWS resources. For example, the tag key might be "customer" and the tag value might be "companyA." You can specify one or more tags to add to each container. You can add up to 50 tags to each container. For more information about tagging, including naming and usage conventions, see <a href="https://docs.aws.amazon.com/mediastore/latest/ug/tagging.html">Tagging Resources in MediaStore</a>.</p>
+    ///
+    /// See `MediaStore::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from the specified container. You can specify one or more tags to remove. </p>
+    ///
+    /// See `MediaStore::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
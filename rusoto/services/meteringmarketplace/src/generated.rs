@@ -220,33 +220,40 @@ impl BatchMeterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DisabledApiException" => {
-                    return RusotoError::Service(BatchMeterUsageError::DisabledApi(err.msg))
+                    return RusotoError::service(&res, BatchMeterUsageError::DisabledApi(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchMeterUsageError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidCustomerIdentifierException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidCustomerIdentifier(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchMeterUsageError::InvalidCustomerIdentifier(err.msg),
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchMeterUsageError::InvalidProductCode(err.msg),
+                    )
                 }
                 "InvalidUsageDimensionException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidUsageDimension(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchMeterUsageError::InvalidUsageDimension(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchMeterUsageError::Throttling(err.msg))
+                    return RusotoError::service(&res, BatchMeterUsageError::Throttling(err.msg))
                 }
                 "TimestampOutOfBoundsException" => {
-                    return RusotoError::Service(BatchMeterUsageError::TimestampOutOfBounds(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchMeterUsageError::TimestampOutOfBounds(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -296,28 +303,43 @@ impl MeterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomerNotEntitledException" => {
-                    return RusotoError::Service(MeterUsageError::CustomerNotEntitled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MeterUsageError::CustomerNotEntitled(err.msg),
+                    )
                 }
                 "DuplicateRequestException" => {
-                    return RusotoError::Service(MeterUsageError::DuplicateRequest(err.msg))
+                    return RusotoError::service(&res, MeterUsageError::DuplicateRequest(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(MeterUsageError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MeterUsageError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidEndpointRegionException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidEndpointRegion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MeterUsageError::InvalidEndpointRegion(err.msg),
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service(&res, MeterUsageError::InvalidProductCode(err.msg))
                 }
                 "InvalidUsageDimensionException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidUsageDimension(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MeterUsageError::InvalidUsageDimension(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(MeterUsageError::Throttling(err.msg))
+                    return RusotoError::service(&res, MeterUsageError::Throttling(err.msg))
                 }
                 "TimestampOutOfBoundsException" => {
-                    return RusotoError::Service(MeterUsageError::TimestampOutOfBounds(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MeterUsageError::TimestampOutOfBounds(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -368,30 +390,43 @@ impl RegisterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomerNotEntitledException" => {
-                    return RusotoError::Service(RegisterUsageError::CustomerNotEntitled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUsageError::CustomerNotEntitled(err.msg),
+                    )
                 }
                 "DisabledApiException" => {
-                    return RusotoError::Service(RegisterUsageError::DisabledApi(err.msg))
+                    return RusotoError::service(&res, RegisterUsageError::DisabledApi(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RegisterUsageError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUsageError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUsageError::InvalidProductCode(err.msg),
+                    )
                 }
                 "InvalidPublicKeyVersionException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidPublicKeyVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUsageError::InvalidPublicKeyVersion(err.msg),
+                    )
                 }
                 "InvalidRegionException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidRegion(err.msg))
+                    return RusotoError::service(&res, RegisterUsageError::InvalidRegion(err.msg))
                 }
                 "PlatformNotSupportedException" => {
-                    return RusotoError::Service(RegisterUsageError::PlatformNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUsageError::PlatformNotSupported(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterUsageError::Throttling(err.msg))
+                    return RusotoError::service(&res, RegisterUsageError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -436,21 +471,22 @@ impl ResolveCustomerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DisabledApiException" => {
-                    return RusotoError::Service(ResolveCustomerError::DisabledApi(err.msg))
+                    return RusotoError::service(&res, ResolveCustomerError::DisabledApi(err.msg))
                 }
                 "ExpiredTokenException" => {
-                    return RusotoError::Service(ResolveCustomerError::ExpiredToken(err.msg))
+                    return RusotoError::service(&res, ResolveCustomerError::ExpiredToken(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ResolveCustomerError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResolveCustomerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ResolveCustomerError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, ResolveCustomerError::InvalidToken(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ResolveCustomerError::Throttling(err.msg))
+                    return RusotoError::service(&res, ResolveCustomerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -517,6 +553,7 @@ impl MarketplaceMeteringClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -532,6 +569,7 @@ impl MarketplaceMeteringClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceMeteringClient {
         MarketplaceMeteringClient { client, region }
     }
@@ -545,6 +583,9 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: BatchMeterUsageRequest,
     ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchMeterUsage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMPMeteringService.BatchMeterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -563,6 +604,9 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: MeterUsageRequest,
     ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MeterUsage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMPMeteringService.MeterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -581,6 +625,9 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: RegisterUsageRequest,
     ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterUsage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMPMeteringService.RegisterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -599,6 +646,9 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: ResolveCustomerRequest,
     ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResolveCustomer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMPMeteringService.ResolveCustomer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -611,3 +661,100 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveCustomerResult, _>()
     }
 }
+
+/// A blocking client for the AWSMarketplace Metering API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MarketplaceMeteringClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceMeteringBlockingClient {
+    client: MarketplaceMeteringClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceMeteringBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MarketplaceMeteringBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MarketplaceMeteringBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceMeteringBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceMeteringBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient {
+            client: MarketplaceMeteringClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>BatchMeterUsage is called from a SaaS application listed on the AWS Marketplace to post metering records for a set of customers.</p> <p>For identical requests, the API is idempotent; requests can be retried with the same records or a subset of the input records.</p> <p>Every request to BatchMeterUsage is for one product. If you need to meter usage for multiple products, you must make multiple calls to BatchMeterUsage.</p> <p>BatchMeterUsage can process up to 25 UsageRecords at a time.</p>
+    ///
+    /// See `MarketplaceMetering::batch_meter_usage`.
+    pub fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
+        self.runtime.block_on(self.client.batch_meter_usage(input))
+    }
+
+    /// <p>API to emit metering records. For identical requests, the API is idempotent. It simply returns the metering record ID.</p> <p>MeterUsage is authenticated on the buyer's AWS account using credentials from the EC2 instance, ECS task, or EKS pod.</p>
+    ///
+    /// See `MarketplaceMetering::meter_usage`.
+    pub fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
+        self.runtime.block_on(self.client.meter_usage(input))
+    }
+
+    /// <p><p>Paid container software products sold through AWS Marketplace must integrate with the AWS Marketplace Metering Service and call the RegisterUsage operation for software entitlement and metering. Free and BYOL products for Amazon ECS or Amazon EKS aren&#39;t required to call RegisterUsage, but you may choose to do so if you would like to receive usage data in your seller reports. The sections below explain the behavior of RegisterUsage. RegisterUsage performs two primary functions: metering and entitlement.</p> <ul> <li> <p> <i>Entitlement</i>: RegisterUsage allows you to verify that the customer running your paid software is subscribed to your product on AWS Marketplace, enabling you to guard against unauthorized use. Your container image that integrates with RegisterUsage is only required to guard against unauthorized use at container startup, as such a CustomerNotSubscribedException/PlatformNotSupportedException will only be thrown on the initial call to RegisterUsage. Subsequent calls from the same Amazon ECS task instance (e.g. task-id) or Amazon EKS pod will not throw a CustomerNotSubscribedException, even if the customer unsubscribes while the Amazon ECS task or Amazon EKS pod is still running.</p> </li> <li> <p> <i>Metering</i>: RegisterUsage meters software use per ECS task, per hour, or per pod for Amazon EKS with usage prorated to the second. A minimum of 1 minute of usage applies to tasks that are short lived. For example, if a customer has a 10 node Amazon ECS or Amazon EKS cluster and a service configured as a Daemon Set, then Amazon ECS or Amazon EKS will launch a task on all 10 cluster nodes and the customer will be charged: (10 * hourly_rate). Metering for software use is automatically handled by the AWS Marketplace Metering Control Plane -- your software is not required to perform any metering specific actions, other than call RegisterUsage once for metering of software use to commence. The AWS Marketplace Metering Control Plane will also continue to bill customers for running ECS tasks and Amazon EKS pods, regardless of the customers subscription state, removing the need for your software to perform entitlement checks at runtime.</p> </li> </ul></p>
+    ///
+    /// See `MarketplaceMetering::register_usage`.
+    pub fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
+        self.runtime.block_on(self.client.register_usage(input))
+    }
+
+    /// <p>ResolveCustomer is called by a SaaS application during the registration process. When a buyer visits your website during the registration process, the buyer submits a registration token through their browser. The registration token is resolved through this API to obtain a CustomerIdentifier and product code.</p>
+    ///
+    /// See `MarketplaceMetering::resolve_customer`.
+    pub fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
+        self.runtime.block_on(self.client.resolve_customer(input))
+    }
+}
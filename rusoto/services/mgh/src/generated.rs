@@ -616,45 +616,56 @@ impl AssociateCreatedArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateCreatedArtifactError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCreatedArtifactError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateCreatedArtifactError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -716,52 +727,62 @@ impl AssociateDiscoveredResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDiscoveredResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDiscoveredResourceError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDiscoveredResourceError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDiscoveredResourceError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDiscoveredResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::PolicyError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDiscoveredResourceError::PolicyError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDiscoveredResourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDiscoveredResourceError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDiscoveredResourceError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -824,42 +845,50 @@ impl CreateProgressUpdateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProgressUpdateStreamError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProgressUpdateStreamError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProgressUpdateStreamError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProgressUpdateStreamError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProgressUpdateStreamError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProgressUpdateStreamError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProgressUpdateStreamError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProgressUpdateStreamError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -922,47 +951,56 @@ impl DeleteProgressUpdateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProgressUpdateStreamError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProgressUpdateStreamError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProgressUpdateStreamError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProgressUpdateStreamError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProgressUpdateStreamError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProgressUpdateStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProgressUpdateStreamError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProgressUpdateStreamError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProgressUpdateStreamError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -1022,42 +1060,52 @@ impl DescribeApplicationStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeApplicationStateError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::InvalidInput(err.msg),
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::PolicyError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::PolicyError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeApplicationStateError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1106,33 +1154,46 @@ impl DescribeMigrationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMigrationTaskError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1186,47 +1247,56 @@ impl DisassociateCreatedArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCreatedArtifactError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCreatedArtifactError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateCreatedArtifactError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateCreatedArtifactError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCreatedArtifactError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateCreatedArtifactError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateCreatedArtifactError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCreatedArtifactError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateCreatedArtifactError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -1290,47 +1360,56 @@ impl DisassociateDiscoveredResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDiscoveredResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDiscoveredResourceError::DryRunOperation(err.msg),
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDiscoveredResourceError::HomeRegionNotSet(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDiscoveredResourceError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDiscoveredResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDiscoveredResourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDiscoveredResourceError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDiscoveredResourceError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -1398,41 +1477,58 @@ impl ImportMigrationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(ImportMigrationTaskError::DryRunOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ImportMigrationTaskError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(ImportMigrationTaskError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportMigrationTaskError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1480,28 +1576,40 @@ impl ListApplicationStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListApplicationStatesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationStatesError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListApplicationStatesError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationStatesError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListApplicationStatesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationStatesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListApplicationStatesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationStatesError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListApplicationStatesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationStatesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListApplicationStatesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationStatesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1548,33 +1656,46 @@ impl ListCreatedArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCreatedArtifactsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1622,37 +1743,46 @@ impl ListDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoveredResourcesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1702,32 +1832,49 @@ impl ListMigrationTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListMigrationTasksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListMigrationTasksError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListMigrationTasksError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMigrationTasksError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::InvalidInput(err.msg),
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(ListMigrationTasksError::PolicyError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::PolicyError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMigrationTasksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMigrationTasksError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMigrationTasksError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMigrationTasksError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListMigrationTasksError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1774,34 +1921,40 @@ impl ListProgressUpdateStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProgressUpdateStreamsError::AccessDenied(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProgressUpdateStreamsError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProgressUpdateStreamsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProgressUpdateStreamsError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProgressUpdateStreamsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProgressUpdateStreamsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1856,44 +2009,62 @@ impl NotifyApplicationStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(NotifyApplicationStateError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(NotifyApplicationStateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::InvalidInput(err.msg),
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::PolicyError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::PolicyError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        NotifyApplicationStateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         NotifyApplicationStateError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -1950,45 +2121,56 @@ impl NotifyMigrationTaskStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         NotifyMigrationTaskStateError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        NotifyMigrationTaskStateError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         NotifyMigrationTaskStateError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -2046,43 +2228,58 @@ impl PutResourceAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutResourceAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(PutResourceAttributesError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::DryRunOperation(err.msg),
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(PutResourceAttributesError::HomeRegionNotSet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::HomeRegionNotSet(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutResourceAttributesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourceAttributesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourceAttributesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourceAttributesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutResourceAttributesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::Throttling(err.msg),
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(PutResourceAttributesError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourceAttributesError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2234,6 +2431,7 @@ impl MigrationHubClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2249,6 +2447,7 @@ impl MigrationHubClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubClient {
         MigrationHubClient { client, region }
     }
@@ -2262,6 +2461,9 @@ impl MigrationHub for MigrationHubClient {
         input: AssociateCreatedArtifactRequest,
     ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateCreatedArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.AssociateCreatedArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2282,6 +2484,9 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateDiscoveredResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSMigrationHub.AssociateDiscoveredResource",
@@ -2305,6 +2510,9 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProgressUpdateStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.CreateProgressUpdateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2325,6 +2533,9 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProgressUpdateStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.DeleteProgressUpdateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2344,6 +2555,9 @@ impl MigrationHub for MigrationHubClient {
         input: DescribeApplicationStateRequest,
     ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApplicationState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeApplicationState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2363,6 +2577,9 @@ impl MigrationHub for MigrationHubClient {
         input: DescribeMigrationTaskRequest,
     ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMigrationTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeMigrationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2382,6 +2599,9 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateCreatedArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSMigrationHub.DisassociateCreatedArtifact",
@@ -2407,6 +2627,9 @@ impl MigrationHub for MigrationHubClient {
         RusotoError<DisassociateDiscoveredResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateDiscoveredResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSMigrationHub.DisassociateDiscoveredResource",
@@ -2429,6 +2652,9 @@ impl MigrationHub for MigrationHubClient {
         input: ImportMigrationTaskRequest,
     ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportMigrationTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.ImportMigrationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2447,6 +2673,9 @@ impl MigrationHub for MigrationHubClient {
         input: ListApplicationStatesRequest,
     ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApplicationStates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.ListApplicationStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2465,6 +2694,9 @@ impl MigrationHub for MigrationHubClient {
         input: ListCreatedArtifactsRequest,
     ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCreatedArtifacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.ListCreatedArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2483,6 +2715,9 @@ impl MigrationHub for MigrationHubClient {
         input: ListDiscoveredResourcesRequest,
     ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDiscoveredResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.ListDiscoveredResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2502,6 +2737,9 @@ impl MigrationHub for MigrationHubClient {
         input: ListMigrationTasksRequest,
     ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMigrationTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.ListMigrationTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2520,6 +2758,9 @@ impl MigrationHub for MigrationHubClient {
         input: ListProgressUpdateStreamsRequest,
     ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProgressUpdateStreams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSMigrationHub.ListProgressUpdateStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2539,6 +2780,9 @@ impl MigrationHub for MigrationHubClient {
         input: NotifyApplicationStateRequest,
     ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("NotifyApplicationState");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyApplicationState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2558,6 +2802,9 @@ impl MigrationHub for MigrationHubClient {
         input: NotifyMigrationTaskStateRequest,
     ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("NotifyMigrationTaskState");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyMigrationTaskState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2577,6 +2824,9 @@ impl MigrationHub for MigrationHubClient {
         input: PutResourceAttributesRequest,
     ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResourceAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSMigrationHub.PutResourceAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2589,3 +2839,247 @@ impl MigrationHub for MigrationHubClient {
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourceAttributesResult, _>()
     }
 }
+
+/// A blocking client for the AWS Migration Hub API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MigrationHubClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MigrationHubBlockingClient {
+    client: MigrationHubClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MigrationHubBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MigrationHubBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MigrationHubBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MigrationHubBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MigrationHubBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient {
+            client: MigrationHubClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Associates a created artifact of an AWS cloud resource, the target receiving the migration, with the migration task performed by a migration tool. This API has the following traits:</p> <ul> <li> <p>Migration tools can call the <code>AssociateCreatedArtifact</code> operation to indicate which AWS artifact is associated with a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or DMS endpoint, etc.</p> </li> </ul></p>
+    ///
+    /// See `MigrationHub::associate_created_artifact`.
+    pub fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
+        self.runtime
+            .block_on(self.client.associate_created_artifact(input))
+    }
+
+    /// <p>Associates a discovered resource ID from Application Discovery Service with a migration task.</p>
+    ///
+    /// See `MigrationHub::associate_discovered_resource`.
+    pub fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_discovered_resource(input))
+    }
+
+    /// <p>Creates a progress update stream which is an AWS resource used for access control as well as a namespace for migration task names that is implicitly linked to your AWS account. It must uniquely identify the migration tool as it is used for all updates made by the tool; however, it does not need to be unique for each AWS account because it is scoped to the AWS account.</p>
+    ///
+    /// See `MigrationHub::create_progress_update_stream`.
+    pub fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.create_progress_update_stream(input))
+    }
+
+    /// <p><p>Deletes a progress update stream, including all of its tasks, which was previously created as an AWS resource used for access control. This API has the following traits:</p> <ul> <li> <p>The only parameter needed for <code>DeleteProgressUpdateStream</code> is the stream name (same as a <code>CreateProgressUpdateStream</code> call).</p> </li> <li> <p>The call will return, and a background process will asynchronously delete the stream and all of its resources (tasks, associated resources, resource attributes, created artifacts).</p> </li> <li> <p>If the stream takes time to be deleted, it might still show up on a <code>ListProgressUpdateStreams</code> call.</p> </li> <li> <p> <code>CreateProgressUpdateStream</code>, <code>ImportMigrationTask</code>, <code>NotifyMigrationTaskState</code>, and all Associate[*] APIs related to the tasks belonging to the stream will throw &quot;InvalidInputException&quot; if the stream of the same name is in the process of being deleted.</p> </li> <li> <p>Once the stream and all of its resources are deleted, <code>CreateProgressUpdateStream</code> for a stream of the same name will succeed, and that stream will be an entirely new logical resource (without any resources associated with the old stream).</p> </li> </ul></p>
+    ///
+    /// See `MigrationHub::delete_progress_update_stream`.
+    pub fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_progress_update_stream(input))
+    }
+
+    /// <p>Gets the migration status of an application.</p>
+    ///
+    /// See `MigrationHub::describe_application_state`.
+    pub fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
+        self.runtime
+            .block_on(self.client.describe_application_state(input))
+    }
+
+    /// <p>Retrieves a list of all attributes associated with a specific migration task.</p>
+    ///
+    /// See `MigrationHub::describe_migration_task`.
+    pub fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
+        self.runtime
+            .block_on(self.client.describe_migration_task(input))
+    }
+
+    /// <p><p>Disassociates a created artifact of an AWS resource with a migration task performed by a migration tool that was previously associated. This API has the following traits:</p> <ul> <li> <p>A migration user can call the <code>DisassociateCreatedArtifacts</code> operation to disassociate a created AWS Artifact from a migration task.</p> </li> <li> <p>The created artifact name must be provided in ARN (Amazon Resource Name) format which will contain information about type and region; for example: <code>arn:aws:ec2:us-east-1:488216288981:image/ami-6d0ba87b</code>.</p> </li> <li> <p>Examples of the AWS resource behind the created artifact are, AMI&#39;s, EC2 instance, or RDS instance, etc.</p> </li> </ul></p>
+    ///
+    /// See `MigrationHub::disassociate_created_artifact`.
+    pub fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_created_artifact(input))
+    }
+
+    /// <p>Disassociate an Application Discovery Service discovered resource from a migration task.</p>
+    ///
+    /// See `MigrationHub::disassociate_discovered_resource`.
+    pub fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> Result<
+        DisassociateDiscoveredResourceResult,
+        RusotoError<DisassociateDiscoveredResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_discovered_resource(input))
+    }
+
+    /// <p>Registers a new migration task which represents a server, database, etc., being migrated to AWS by a migration tool.</p> <p>This API is a prerequisite to calling the <code>NotifyMigrationTaskState</code> API as the migration tool must first register the migration task with Migration Hub.</p>
+    ///
+    /// See `MigrationHub::import_migration_task`.
+    pub fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
+        self.runtime
+            .block_on(self.client.import_migration_task(input))
+    }
+
+    /// <p>Lists all the migration statuses for your applications. If you use the optional <code>ApplicationIds</code> parameter, only the migration statuses for those applications will be returned.</p>
+    ///
+    /// See `MigrationHub::list_application_states`.
+    pub fn list_application_states(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
+        self.runtime
+            .block_on(self.client.list_application_states(input))
+    }
+
+    /// <p><p>Lists the created artifacts attached to a given migration task in an update stream. This API has the following traits:</p> <ul> <li> <p>Gets the list of the created artifacts while migration is taking place.</p> </li> <li> <p>Shows the artifacts created by the migration tool that was associated by the <code>AssociateCreatedArtifact</code> API. </p> </li> <li> <p>Lists created artifacts in a paginated interface. </p> </li> </ul></p>
+    ///
+    /// See `MigrationHub::list_created_artifacts`.
+    pub fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
+        self.runtime
+            .block_on(self.client.list_created_artifacts(input))
+    }
+
+    /// <p>Lists discovered resources associated with the given <code>MigrationTask</code>.</p>
+    ///
+    /// See `MigrationHub::list_discovered_resources`.
+    pub fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_discovered_resources(input))
+    }
+
+    /// <p><p>Lists all, or filtered by resource name, migration tasks associated with the user account making this call. This API has the following traits:</p> <ul> <li> <p>Can show a summary list of the most recent migration tasks.</p> </li> <li> <p>Can show a summary list of migration tasks associated with a given discovered resource.</p> </li> <li> <p>Lists migration tasks in a paginated interface.</p> </li> </ul></p>
+    ///
+    /// See `MigrationHub::list_migration_tasks`.
+    pub fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
+        self.runtime
+            .block_on(self.client.list_migration_tasks(input))
+    }
+
+    /// <p>Lists progress update streams associated with the user account making this call.</p>
+    ///
+    /// See `MigrationHub::list_progress_update_streams`.
+    pub fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
+        self.runtime
+            .block_on(self.client.list_progress_update_streams(input))
+    }
+
+    /// <p>Sets the migration state of an application. For a given application identified by the value passed to <code>ApplicationId</code>, its status is set or updated by passing one of three values to <code>Status</code>: <code>NOT_STARTED | IN_PROGRESS | COMPLETED</code>.</p>
+    ///
+    /// See `MigrationHub::notify_application_state`.
+    pub fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
+        self.runtime
+            .block_on(self.client.notify_application_state(input))
+    }
+
+    /// <p><p>Notifies Migration Hub of the current status, progress, or other detail regarding a migration task. This API has the following traits:</p> <ul> <li> <p>Migration tools will call the <code>NotifyMigrationTaskState</code> API to share the latest progress and status.</p> </li> <li> <p> <code>MigrationTaskName</code> is used for addressing updates to the correct target.</p> </li> <li> <p> <code>ProgressUpdateStream</code> is used for access control and to provide a namespace for each migration tool.</p> </li> </ul></p>
+    ///
+    /// See `MigrationHub::notify_migration_task_state`.
+    pub fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
+        self.runtime
+            .block_on(self.client.notify_migration_task_state(input))
+    }
+
+    /// <p><p>Provides identifying details of the resource being migrated so that it can be associated in the Application Discovery Service repository. This association occurs asynchronously after <code>PutResourceAttributes</code> returns.</p> <important> <ul> <li> <p>Keep in mind that subsequent calls to PutResourceAttributes will override previously stored attributes. For example, if it is first called with a MAC address, but later, it is desired to <i>add</i> an IP address, it will then be required to call it with <i>both</i> the IP and MAC addresses to prevent overriding the MAC address.</p> </li> <li> <p>Note the instructions regarding the special use case of the <a href="https://docs.aws.amazon.com/migrationhub/latest/ug/API_PutResourceAttributes.html#migrationhub-PutResourceAttributes-request-ResourceAttributeList"> <code>ResourceAttributeList</code> </a> parameter when specifying any &quot;VM&quot; related value.</p> </li> </ul> </important> <note> <p>Because this is an asynchronous call, it will always return 200, whether an association occurs or not. To confirm if an association was found based on the provided details, call <code>ListDiscoveredResources</code>.</p> </note></p>
+    ///
+    /// See `MigrationHub::put_resource_attributes`.
+    pub fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
+        self.runtime
+            .block_on(self.client.put_resource_attributes(input))
+    }
+}
@@ -182,32 +182,40 @@ impl CreateHomeRegionControlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHomeRegionControlError::AccessDenied(err.msg),
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::DryRunOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHomeRegionControlError::DryRunOperation(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHomeRegionControlError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHomeRegionControlError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHomeRegionControlError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHomeRegionControlError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -252,29 +260,34 @@ impl DescribeHomeRegionControlsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHomeRegionControlsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeHomeRegionControlsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHomeRegionControlsError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeHomeRegionControlsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHomeRegionControlsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -320,19 +333,25 @@ impl GetHomeRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetHomeRegionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetHomeRegionError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetHomeRegionError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetHomeRegionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetHomeRegionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetHomeRegionError::InvalidInput(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetHomeRegionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetHomeRegionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetHomeRegionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetHomeRegionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -391,6 +410,7 @@ impl MigrationHubConfigClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -406,6 +426,7 @@ impl MigrationHubConfigClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubConfigClient {
         MigrationHubConfigClient { client, region }
     }
@@ -419,6 +440,9 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         input: CreateHomeRegionControlRequest,
     ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHomeRegionControl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSMigrationHubMultiAccountService.CreateHomeRegionControl",
@@ -442,6 +466,9 @@ impl MigrationHubConfig for MigrationHubConfigClient {
     ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHomeRegionControls");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSMigrationHubMultiAccountService.DescribeHomeRegionControls",
@@ -463,6 +490,9 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         &self,
     ) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetHomeRegion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSMigrationHubMultiAccountService.GetHomeRegion",
@@ -477,3 +507,86 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetHomeRegionResult, _>()
     }
 }
+
+/// A blocking client for the AWS Migration Hub Config API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MigrationHubConfigClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MigrationHubConfigBlockingClient {
+    client: MigrationHubConfigClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MigrationHubConfigBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MigrationHubConfigBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MigrationHubConfigBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MigrationHubConfigBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MigrationHubConfigBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient {
+            client: MigrationHubConfigClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>This API sets up the home region for the calling account only.</p>
+    ///
+    /// See `MigrationHubConfig::create_home_region_control`.
+    pub fn create_home_region_control(
+        &self,
+        input: CreateHomeRegionControlRequest,
+    ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
+        self.runtime
+            .block_on(self.client.create_home_region_control(input))
+    }
+
+    /// <p>This API permits filtering on the <code>ControlId</code> and <code>HomeRegion</code> fields.</p>
+    ///
+    /// See `MigrationHubConfig::describe_home_region_controls`.
+    pub fn describe_home_region_controls(
+        &self,
+        input: DescribeHomeRegionControlsRequest,
+    ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_home_region_controls(input))
+    }
+
+    /// <p>Returns the calling account’s home region, if configured. This API is used by other AWS services to determine the regional endpoint for calling AWS Application Discovery Service and Migration Hub. You must call <code>GetHomeRegion</code> at least once before you call any other AWS Application Discovery Service and AWS Migration Hub APIs, to obtain the account's Migration Hub home region.</p>
+    ///
+    /// See `MigrationHubConfig::get_home_region`.
+    pub fn get_home_region(&self) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
+        self.runtime.block_on(self.client.get_home_region())
+    }
+}
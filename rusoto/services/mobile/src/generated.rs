@@ -377,25 +377,28 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateProjectError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -439,19 +442,22 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -495,22 +501,31 @@ impl DescribeBundleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBundleError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeBundleError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeBundleError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBundleError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBundleError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeBundleError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBundleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBundleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeBundleError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBundleError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeBundleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeBundleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -555,22 +570,31 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeProjectError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeProjectError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::InternalFailure(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeProjectError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeProjectError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -615,22 +639,25 @@ impl ExportBundleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExportBundleError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ExportBundleError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ExportBundleError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ExportBundleError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportBundleError::NotFound(err.msg))
+                    return RusotoError::service(&res, ExportBundleError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportBundleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportBundleError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ExportBundleError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ExportBundleError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ExportBundleError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ExportBundleError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -675,22 +702,25 @@ impl ExportProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExportProjectError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ExportProjectError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ExportProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ExportProjectError::InternalFailure(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, ExportProjectError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportProjectError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ExportProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ExportProjectError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ExportProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ExportProjectError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -733,19 +763,22 @@ impl ListBundlesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBundlesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListBundlesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBundlesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListBundlesError::InternalFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBundlesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBundlesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListBundlesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListBundlesError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBundlesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListBundlesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -787,19 +820,22 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListProjectsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListProjectsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::InternalFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProjectsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProjectsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListProjectsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListProjectsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListProjectsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -847,28 +883,34 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountActionRequiredException" => {
-                    return RusotoError::Service(UpdateProjectError::AccountActionRequired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProjectError::AccountActionRequired(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateProjectError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateProjectError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateProjectError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProjectError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateProjectError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateProjectError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateProjectError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -968,6 +1010,7 @@ impl MobileClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -983,6 +1026,7 @@ impl MobileClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MobileClient {
         MobileClient { client, region }
     }
@@ -1000,6 +1044,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("CreateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1049,6 +1096,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("DELETE", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("DeleteProject");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1080,6 +1130,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("DescribeBundle");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1111,6 +1164,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("DescribeProject");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1149,6 +1205,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("ExportBundle");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1189,6 +1248,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("ExportProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1220,6 +1282,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("ListBundles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1260,6 +1325,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("GET", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("ListProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1300,6 +1368,9 @@ impl Mobile for MobileClient {
 
         let mut request =
             SignedRequest::new("POST", "AWSMobileHubService", &self.region, &request_uri);
+        request.set_operation_name("UpdateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("mobile".to_string());
@@ -1331,3 +1402,143 @@ impl Mobile for MobileClient {
         }
     }
 }
+
+/// A blocking client for the AWS Mobile API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MobileClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MobileBlockingClient {
+    client: MobileClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MobileBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MobileBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MobileBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MobileBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MobileBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MobileBlockingClient {
+        MobileBlockingClient {
+            client: MobileClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p> Creates an AWS Mobile Hub project. </p>
+    ///
+    /// See `Mobile::create_project`.
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// <p> Delets a project in AWS Mobile Hub. </p>
+    ///
+    /// See `Mobile::delete_project`.
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// <p> Get the bundle details for the requested bundle id. </p>
+    ///
+    /// See `Mobile::describe_bundle`.
+    pub fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> Result<DescribeBundleResult, RusotoError<DescribeBundleError>> {
+        self.runtime.block_on(self.client.describe_bundle(input))
+    }
+
+    /// <p> Gets details about a project in AWS Mobile Hub. </p>
+    ///
+    /// See `Mobile::describe_project`.
+    pub fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+        self.runtime.block_on(self.client.describe_project(input))
+    }
+
+    /// <p> Generates customized software development kit (SDK) and or tool packages used to integrate mobile web or mobile app clients with backend AWS resources. </p>
+    ///
+    /// See `Mobile::export_bundle`.
+    pub fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> Result<ExportBundleResult, RusotoError<ExportBundleError>> {
+        self.runtime.block_on(self.client.export_bundle(input))
+    }
+
+    /// <p> Exports project configuration to a snapshot which can be downloaded and shared. Note that mobile app push credentials are encrypted in exported projects, so they can only be shared successfully within the same AWS account. </p>
+    ///
+    /// See `Mobile::export_project`.
+    pub fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> Result<ExportProjectResult, RusotoError<ExportProjectError>> {
+        self.runtime.block_on(self.client.export_project(input))
+    }
+
+    /// <p> List all available bundles. </p>
+    ///
+    /// See `Mobile::list_bundles`.
+    pub fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> Result<ListBundlesResult, RusotoError<ListBundlesError>> {
+        self.runtime.block_on(self.client.list_bundles(input))
+    }
+
+    /// <p> Lists projects in AWS Mobile Hub. </p>
+    ///
+    /// See `Mobile::list_projects`.
+    pub fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// <p> Update an existing project. </p>
+    ///
+    /// See `Mobile::update_project`.
+    pub fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+}
@@ -1225,19 +1225,22 @@ impl CreateBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateBrokerError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBrokerError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateBrokerError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateBrokerError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateBrokerError::InternalServerError(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBrokerError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateBrokerError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1277,18 +1280,22 @@ impl CreateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateConfigurationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateConfigurationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1327,16 +1334,19 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1377,19 +1387,22 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateUserError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUserError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateUserError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateUserError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateUserError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1429,16 +1442,19 @@ impl DeleteBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteBrokerError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteBrokerError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteBrokerError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBrokerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBrokerError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1477,16 +1493,19 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1525,16 +1544,19 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1573,16 +1595,19 @@ impl DescribeBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeBrokerError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeBrokerError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBrokerError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBrokerError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeBrokerError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1619,15 +1644,20 @@ impl DescribeBrokerEngineTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBrokerEngineTypesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBrokerEngineTypesError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeBrokerEngineTypesError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBrokerEngineTypesError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBrokerEngineTypesError::InternalServerError(err.msg),
                     )
                 }
@@ -1669,17 +1699,20 @@ impl DescribeBrokerInstanceOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeBrokerInstanceOptionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBrokerInstanceOptionsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeBrokerInstanceOptionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBrokerInstanceOptionsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBrokerInstanceOptionsError::InternalServerError(err.msg),
                     )
                 }
@@ -1721,18 +1754,28 @@ impl DescribeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1773,24 +1816,28 @@ impl DescribeConfigurationRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeConfigurationRevisionError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConfigurationRevisionError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1831,16 +1878,19 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1877,13 +1927,16 @@ impl ListBrokersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBrokersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListBrokersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListBrokersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListBrokersError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListBrokersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBrokersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1923,22 +1976,28 @@ impl ListConfigurationRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListConfigurationRevisionsError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationRevisionsError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1977,15 +2036,16 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListConfigurationsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListConfigurationsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2023,16 +2083,16 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListTagsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListTagsError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2071,16 +2131,16 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListUsersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListUsersError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListUsersError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InternalServerError(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListUsersError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2119,16 +2179,19 @@ impl RebootBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RebootBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, RebootBrokerError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RebootBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(&res, RebootBrokerError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RebootBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootBrokerError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootBrokerError::NotFound(err.msg))
+                    return RusotoError::service(&res, RebootBrokerError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2169,19 +2232,22 @@ impl UpdateBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateBrokerError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateBrokerError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBrokerError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateBrokerError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2223,21 +2289,25 @@ impl UpdateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConfigurationError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateConfigurationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateConfigurationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateConfigurationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConfigurationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateConfigurationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateConfigurationError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2279,19 +2349,22 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateUserError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2472,6 +2545,7 @@ impl MQClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2487,6 +2561,7 @@ impl MQClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MQClient {
         MQClient { client, region }
     }
@@ -2503,6 +2578,9 @@ impl MQ for MQClient {
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation_name("CreateBroker");
+        request.set_read_only(false);
+        request.set_idempotent(input.creator_request_id.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2534,6 +2612,9 @@ impl MQ for MQClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation_name("CreateConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2565,6 +2646,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2599,6 +2683,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2630,6 +2717,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation_name("DeleteBroker");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2658,6 +2748,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2695,6 +2788,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mq", &self.region, &request_uri);
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2723,6 +2819,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("DescribeBroker");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2752,6 +2851,9 @@ impl MQ for MQClient {
         let request_uri = "/v1/broker-engine-types";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("DescribeBrokerEngineTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2795,6 +2897,9 @@ impl MQ for MQClient {
         let request_uri = "/v1/broker-instance-options";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("DescribeBrokerInstanceOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2844,6 +2949,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("DescribeConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2879,6 +2987,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("DescribeConfigurationRevision");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2911,6 +3022,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("DescribeUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2939,6 +3053,9 @@ impl MQ for MQClient {
         let request_uri = "/v1/brokers";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("ListBrokers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2980,6 +3097,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("ListConfigurationRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3017,6 +3137,9 @@ impl MQ for MQClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("ListConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3054,6 +3177,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3082,6 +3208,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}/users", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("GET", "mq", &self.region, &request_uri);
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3122,6 +3251,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("POST", "mq", &self.region, &request_uri);
+        request.set_operation_name("RebootBroker");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3150,6 +3282,9 @@ impl MQ for MQClient {
         let request_uri = format!("/v1/brokers/{broker_id}", broker_id = input.broker_id);
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation_name("UpdateBroker");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3184,6 +3319,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation_name("UpdateConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3219,6 +3357,9 @@ impl MQ for MQClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mq", &self.region, &request_uri);
+        request.set_operation_name("UpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3241,3 +3382,289 @@ impl MQ for MQClient {
         }
     }
 }
+
+/// A blocking client for the AmazonMQ API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MQClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MQBlockingClient {
+    client: MQClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MQBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MQBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MQBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MQBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MQBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MQBlockingClient {
+        MQBlockingClient {
+            client: MQClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a broker. Note: This API is asynchronous.</p>
+    ///
+    /// See `MQ::create_broker`.
+    pub fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> Result<CreateBrokerResponse, RusotoError<CreateBrokerError>> {
+        self.runtime.block_on(self.client.create_broker(input))
+    }
+
+    /// <p>Creates a new configuration for the specified configuration name. Amazon MQ uses the default configuration (the engine type and version).</p>
+    ///
+    /// See `MQ::create_configuration`.
+    pub fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_configuration(input))
+    }
+
+    /// <p>Add a tag to a resource.</p>
+    ///
+    /// See `MQ::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Creates an ActiveMQ user.</p>
+    ///
+    /// See `MQ::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deletes a broker. Note: This API is asynchronous.</p>
+    ///
+    /// See `MQ::delete_broker`.
+    pub fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> Result<DeleteBrokerResponse, RusotoError<DeleteBrokerError>> {
+        self.runtime.block_on(self.client.delete_broker(input))
+    }
+
+    /// <p>Removes a tag from a resource.</p>
+    ///
+    /// See `MQ::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Deletes an ActiveMQ user.</p>
+    ///
+    /// See `MQ::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Returns information about the specified broker.</p>
+    ///
+    /// See `MQ::describe_broker`.
+    pub fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> Result<DescribeBrokerResponse, RusotoError<DescribeBrokerError>> {
+        self.runtime.block_on(self.client.describe_broker(input))
+    }
+
+    /// <p>Describe available engine types and versions.</p>
+    ///
+    /// See `MQ::describe_broker_engine_types`.
+    pub fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> Result<DescribeBrokerEngineTypesResponse, RusotoError<DescribeBrokerEngineTypesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_broker_engine_types(input))
+    }
+
+    /// <p>Describe available broker instance options.</p>
+    ///
+    /// See `MQ::describe_broker_instance_options`.
+    pub fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> Result<
+        DescribeBrokerInstanceOptionsResponse,
+        RusotoError<DescribeBrokerInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_broker_instance_options(input))
+    }
+
+    /// <p>Returns information about the specified configuration.</p>
+    ///
+    /// See `MQ::describe_configuration`.
+    pub fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+        self.runtime
+            .block_on(self.client.describe_configuration(input))
+    }
+
+    /// <p>Returns the specified configuration revision for the specified configuration.</p>
+    ///
+    /// See `MQ::describe_configuration_revision`.
+    pub fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        DescribeConfigurationRevisionResponse,
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_revision(input))
+    }
+
+    /// <p>Returns information about an ActiveMQ user.</p>
+    ///
+    /// See `MQ::describe_user`.
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// <p>Returns a list of all brokers.</p>
+    ///
+    /// See `MQ::list_brokers`.
+    pub fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> Result<ListBrokersResponse, RusotoError<ListBrokersError>> {
+        self.runtime.block_on(self.client.list_brokers(input))
+    }
+
+    /// <p>Returns a list of all revisions for the specified configuration.</p>
+    ///
+    /// See `MQ::list_configuration_revisions`.
+    pub fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_configuration_revisions(input))
+    }
+
+    /// <p>Returns a list of all configurations.</p>
+    ///
+    /// See `MQ::list_configurations`.
+    pub fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_configurations(input))
+    }
+
+    /// <p>Lists tags for a resource.</p>
+    ///
+    /// See `MQ::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Returns a list of all ActiveMQ users.</p>
+    ///
+    /// See `MQ::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Reboots a broker. Note: This API is asynchronous.</p>
+    ///
+    /// See `MQ::reboot_broker`.
+    pub fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> Result<RebootBrokerResponse, RusotoError<RebootBrokerError>> {
+        self.runtime.block_on(self.client.reboot_broker(input))
+    }
+
+    /// <p>Adds a pending configuration change to a broker.</p>
+    ///
+    /// See `MQ::update_broker`.
+    pub fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> Result<UpdateBrokerResponse, RusotoError<UpdateBrokerError>> {
+        self.runtime.block_on(self.client.update_broker(input))
+    }
+
+    /// <p>Updates the specified configuration.</p>
+    ///
+    /// See `MQ::update_configuration`.
+    pub fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> Result<UpdateConfigurationResponse, RusotoError<UpdateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.update_configuration(input))
+    }
+
+    /// <p>Updates the information for an ActiveMQ user.</p>
+    ///
+    /// See `MQ::update_user`.
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+}
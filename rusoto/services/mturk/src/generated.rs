@@ -1617,14 +1617,16 @@ impl AcceptQualificationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(AcceptQualificationRequestError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptQualificationRequestError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(AcceptQualificationRequestError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptQualificationRequestError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1657,10 +1659,16 @@ impl ApproveAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ApproveAssignmentError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ApproveAssignmentError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ApproveAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ApproveAssignmentError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1695,12 +1703,14 @@ impl AssociateQualificationWithWorkerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateQualificationWithWorkerError::RequestError(err.msg),
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateQualificationWithWorkerError::ServiceFault(err.msg),
                     )
                 }
@@ -1741,12 +1751,14 @@ impl CreateAdditionalAssignmentsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAdditionalAssignmentsForHITError::RequestError(err.msg),
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAdditionalAssignmentsForHITError::ServiceFault(err.msg),
                     )
                 }
@@ -1785,10 +1797,10 @@ impl CreateHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITError::RequestError(err.msg))
+                    return RusotoError::service(&res, CreateHITError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, CreateHITError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1821,10 +1833,10 @@ impl CreateHITTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITTypeError::RequestError(err.msg))
+                    return RusotoError::service(&res, CreateHITTypeError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITTypeError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, CreateHITTypeError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1857,10 +1869,16 @@ impl CreateHITWithHITTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITWithHITTypeError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHITWithHITTypeError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITWithHITTypeError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHITWithHITTypeError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1893,14 +1911,16 @@ impl CreateQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateQualificationTypeError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateQualificationTypeError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1933,10 +1953,16 @@ impl CreateWorkerBlockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateWorkerBlockError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkerBlockError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateWorkerBlockError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkerBlockError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1969,10 +1995,10 @@ impl DeleteHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteHITError::RequestError(err.msg))
+                    return RusotoError::service(&res, DeleteHITError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteHITError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, DeleteHITError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2005,14 +2031,16 @@ impl DeleteQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteQualificationTypeError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteQualificationTypeError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2045,10 +2073,16 @@ impl DeleteWorkerBlockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteWorkerBlockError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkerBlockError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteWorkerBlockError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkerBlockError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2083,12 +2117,14 @@ impl DisassociateQualificationFromWorkerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateQualificationFromWorkerError::RequestError(err.msg),
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateQualificationFromWorkerError::ServiceFault(err.msg),
                     )
                 }
@@ -2127,10 +2163,16 @@ impl GetAccountBalanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetAccountBalanceError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountBalanceError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetAccountBalanceError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAccountBalanceError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2163,10 +2205,10 @@ impl GetAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetAssignmentError::RequestError(err.msg))
+                    return RusotoError::service(&res, GetAssignmentError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, GetAssignmentError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2199,10 +2241,10 @@ impl GetFileUploadURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetFileUploadURLError::RequestError(err.msg))
+                    return RusotoError::service(&res, GetFileUploadURLError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetFileUploadURLError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, GetFileUploadURLError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2234,8 +2276,12 @@ impl GetHITError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetHITError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "RequestError" => return RusotoError::Service(GetHITError::RequestError(err.msg)),
-                "ServiceFault" => return RusotoError::Service(GetHITError::ServiceFault(err.msg)),
+                "RequestError" => {
+                    return RusotoError::service(&res, GetHITError::RequestError(err.msg))
+                }
+                "ServiceFault" => {
+                    return RusotoError::service(&res, GetHITError::ServiceFault(err.msg))
+                }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
             }
@@ -2267,10 +2313,16 @@ impl GetQualificationScoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetQualificationScoreError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQualificationScoreError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetQualificationScoreError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQualificationScoreError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2303,10 +2355,16 @@ impl GetQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetQualificationTypeError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQualificationTypeError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetQualificationTypeError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetQualificationTypeError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2339,10 +2397,16 @@ impl ListAssignmentsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListAssignmentsForHITError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssignmentsForHITError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListAssignmentsForHITError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssignmentsForHITError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2375,10 +2439,16 @@ impl ListBonusPaymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListBonusPaymentsError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBonusPaymentsError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListBonusPaymentsError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBonusPaymentsError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2411,10 +2481,10 @@ impl ListHITsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListHITsError::RequestError(err.msg))
+                    return RusotoError::service(&res, ListHITsError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListHITsError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, ListHITsError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2449,14 +2519,16 @@ impl ListHITsForQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListHITsForQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHITsForQualificationTypeError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListHITsForQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHITsForQualificationTypeError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2489,14 +2561,16 @@ impl ListQualificationRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListQualificationRequestsError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListQualificationRequestsError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListQualificationRequestsError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListQualificationRequestsError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2529,10 +2603,16 @@ impl ListQualificationTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListQualificationTypesError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListQualificationTypesError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListQualificationTypesError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListQualificationTypesError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2567,14 +2647,16 @@ impl ListReviewPolicyResultsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListReviewPolicyResultsForHITError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListReviewPolicyResultsForHITError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListReviewPolicyResultsForHITError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListReviewPolicyResultsForHITError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2607,10 +2689,16 @@ impl ListReviewableHITsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListReviewableHITsError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListReviewableHITsError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListReviewableHITsError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListReviewableHITsError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2643,10 +2731,10 @@ impl ListWorkerBlocksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListWorkerBlocksError::RequestError(err.msg))
+                    return RusotoError::service(&res, ListWorkerBlocksError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListWorkerBlocksError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, ListWorkerBlocksError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2681,12 +2769,14 @@ impl ListWorkersWithQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWorkersWithQualificationTypeError::RequestError(err.msg),
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWorkersWithQualificationTypeError::ServiceFault(err.msg),
                     )
                 }
@@ -2725,10 +2815,10 @@ impl NotifyWorkersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(NotifyWorkersError::RequestError(err.msg))
+                    return RusotoError::service(&res, NotifyWorkersError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(NotifyWorkersError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, NotifyWorkersError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2761,10 +2851,10 @@ impl RejectAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(RejectAssignmentError::RequestError(err.msg))
+                    return RusotoError::service(&res, RejectAssignmentError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(RejectAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, RejectAssignmentError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2799,14 +2889,16 @@ impl RejectQualificationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(RejectQualificationRequestError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectQualificationRequestError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(RejectQualificationRequestError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectQualificationRequestError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2839,10 +2931,10 @@ impl SendBonusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(SendBonusError::RequestError(err.msg))
+                    return RusotoError::service(&res, SendBonusError::RequestError(err.msg))
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(SendBonusError::ServiceFault(err.msg))
+                    return RusotoError::service(&res, SendBonusError::ServiceFault(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2875,14 +2967,16 @@ impl SendTestEventNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(SendTestEventNotificationError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendTestEventNotificationError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(SendTestEventNotificationError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendTestEventNotificationError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2915,10 +3009,16 @@ impl UpdateExpirationForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateExpirationForHITError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateExpirationForHITError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateExpirationForHITError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateExpirationForHITError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2951,10 +3051,16 @@ impl UpdateHITReviewStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateHITReviewStatusError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateHITReviewStatusError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateHITReviewStatusError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateHITReviewStatusError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2987,10 +3093,16 @@ impl UpdateHITTypeOfHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateHITTypeOfHITError::RequestError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateHITTypeOfHITError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateHITTypeOfHITError::ServiceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateHITTypeOfHITError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3025,14 +3137,16 @@ impl UpdateNotificationSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateNotificationSettingsError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationSettingsError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateNotificationSettingsError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNotificationSettingsError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3065,14 +3179,16 @@ impl UpdateQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateQualificationTypeError::RequestError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateQualificationTypeError::RequestError(err.msg),
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateQualificationTypeError::ServiceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateQualificationTypeError::ServiceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3360,6 +3476,7 @@ impl MechanicalTurkClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3375,6 +3492,7 @@ impl MechanicalTurkClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkClient {
         MechanicalTurkClient { client, region }
     }
@@ -3389,6 +3507,9 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptQualificationRequest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.AcceptQualificationRequest",
@@ -3411,6 +3532,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ApproveAssignmentRequest,
     ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ApproveAssignment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ApproveAssignment",
@@ -3435,6 +3559,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<AssociateQualificationWithWorkerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateQualificationWithWorker");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.AssociateQualificationWithWorker",
@@ -3463,6 +3590,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<CreateAdditionalAssignmentsForHITError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAdditionalAssignmentsForHIT");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateAdditionalAssignmentsForHIT",
@@ -3488,6 +3618,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITRequest,
     ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHIT");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.CreateHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3506,6 +3639,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITTypeRequest,
     ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHITType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateHITType",
@@ -3527,6 +3663,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITWithHITTypeRequest,
     ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHITWithHITType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateHITWithHITType",
@@ -3549,6 +3688,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateQualificationTypeRequest,
     ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateQualificationType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateQualificationType",
@@ -3571,6 +3713,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateWorkerBlockRequest,
     ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWorkerBlock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateWorkerBlock",
@@ -3592,6 +3737,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteHITRequest,
     ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteHIT");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.DeleteHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3610,6 +3758,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteQualificationTypeRequest,
     ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteQualificationType");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.DeleteQualificationType",
@@ -3632,6 +3783,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteWorkerBlockRequest,
     ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWorkerBlock");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.DeleteWorkerBlock",
@@ -3656,6 +3810,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<DisassociateQualificationFromWorkerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateQualificationFromWorker");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.DisassociateQualificationFromWorker",
@@ -3680,6 +3837,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         &self,
     ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAccountBalance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetAccountBalance",
@@ -3700,6 +3860,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetAssignmentRequest,
     ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAssignment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetAssignment",
@@ -3721,6 +3884,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetFileUploadURLRequest,
     ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFileUploadURL");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetFileUploadURL",
@@ -3742,6 +3908,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetHITRequest,
     ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetHIT");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.GetHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3760,6 +3929,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetQualificationScoreRequest,
     ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetQualificationScore");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetQualificationScore",
@@ -3782,6 +3954,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetQualificationTypeRequest,
     ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetQualificationType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetQualificationType",
@@ -3804,6 +3979,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListAssignmentsForHITRequest,
     ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssignmentsForHIT");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListAssignmentsForHIT",
@@ -3826,6 +4004,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListBonusPaymentsRequest,
     ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBonusPayments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListBonusPayments",
@@ -3847,6 +4028,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListHITsRequest,
     ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHITs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.ListHITs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3866,6 +4050,9 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHITsForQualificationType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListHITsForQualificationType",
@@ -3889,6 +4076,9 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListQualificationRequests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListQualificationRequests",
@@ -3911,6 +4101,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListQualificationTypesRequest,
     ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListQualificationTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListQualificationTypes",
@@ -3936,6 +4129,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<ListReviewPolicyResultsForHITError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListReviewPolicyResultsForHIT");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListReviewPolicyResultsForHIT",
@@ -3958,6 +4154,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListReviewableHITsRequest,
     ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListReviewableHITs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListReviewableHITs",
@@ -3979,6 +4178,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListWorkerBlocksRequest,
     ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWorkerBlocks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListWorkerBlocks",
@@ -4003,6 +4205,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<ListWorkersWithQualificationTypeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWorkersWithQualificationType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListWorkersWithQualificationType",
@@ -4028,6 +4233,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: NotifyWorkersRequest,
     ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("NotifyWorkers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.NotifyWorkers",
@@ -4049,6 +4257,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: RejectAssignmentRequest,
     ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RejectAssignment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.RejectAssignment",
@@ -4071,6 +4282,9 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RejectQualificationRequest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.RejectQualificationRequest",
@@ -4093,6 +4307,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: SendBonusRequest,
     ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendBonus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.SendBonus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4112,6 +4329,9 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendTestEventNotification");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.SendTestEventNotification",
@@ -4134,6 +4354,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateExpirationForHITRequest,
     ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateExpirationForHIT");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateExpirationForHIT",
@@ -4156,6 +4379,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateHITReviewStatusRequest,
     ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateHITReviewStatus");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateHITReviewStatus",
@@ -4178,6 +4404,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateHITTypeOfHITRequest,
     ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateHITTypeOfHIT");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateHITTypeOfHIT",
@@ -4200,6 +4429,9 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNotificationSettings");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateNotificationSettings",
@@ -4222,6 +4454,9 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateQualificationTypeRequest,
     ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateQualificationType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateQualificationType",
@@ -4238,3 +4473,490 @@ impl MechanicalTurk for MechanicalTurkClient {
             .deserialize::<UpdateQualificationTypeResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon MTurk API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `MechanicalTurkClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MechanicalTurkBlockingClient {
+    client: MechanicalTurkClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MechanicalTurkBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> MechanicalTurkBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        MechanicalTurkBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MechanicalTurkBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MechanicalTurkBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient {
+            client: MechanicalTurkClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p> The <code>AcceptQualificationRequest</code> operation approves a Worker's request for a Qualification. </p> <p> Only the owner of the Qualification type can grant a Qualification request for that type. </p> <p> A successful request for the <code>AcceptQualificationRequest</code> operation returns with no errors and an empty body. </p>
+    ///
+    /// See `MechanicalTurk::accept_qualification_request`.
+    pub fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_qualification_request(input))
+    }
+
+    /// <p> The <code>ApproveAssignment</code> operation approves the results of a completed assignment. </p> <p> Approving an assignment initiates two payments from the Requester's Amazon.com account </p> <ul> <li> <p> The Worker who submitted the results is paid the reward specified in the HIT. </p> </li> <li> <p> Amazon Mechanical Turk fees are debited. </p> </li> </ul> <p> If the Requester's account does not have adequate funds for these payments, the call to ApproveAssignment returns an exception, and the approval is not processed. You can include an optional feedback message with the approval, which the Worker can see in the Status section of the web site. </p> <p> You can also call this operation for assignments that were previous rejected and approve them by explicitly overriding the previous rejection. This only works on rejected assignments that were submitted within the previous 30 days and only if the assignment's related HIT has not been deleted. </p>
+    ///
+    /// See `MechanicalTurk::approve_assignment`.
+    pub fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
+        self.runtime.block_on(self.client.approve_assignment(input))
+    }
+
+    /// <p><p> The <code>AssociateQualificationWithWorker</code> operation gives a Worker a Qualification. <code>AssociateQualificationWithWorker</code> does not require that the Worker submit a Qualification request. It gives the Qualification directly to the Worker. </p> <p> You can only assign a Qualification of a Qualification type that you created (using the <code>CreateQualificationType</code> operation). </p> <note> <p> Note: <code>AssociateQualificationWithWorker</code> does not affect any pending Qualification requests for the Qualification by the Worker. If you assign a Qualification to a Worker, then later grant a Qualification request made by the Worker, the granting of the request may modify the Qualification score. To resolve a pending Qualification request without affecting the Qualification the Worker already has, reject the request with the <code>RejectQualificationRequest</code> operation. </p> </note></p>
+    ///
+    /// See `MechanicalTurk::associate_qualification_with_worker`.
+    pub fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> Result<
+        AssociateQualificationWithWorkerResponse,
+        RusotoError<AssociateQualificationWithWorkerError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_qualification_with_worker(input))
+    }
+
+    /// <p><p> The <code>CreateAdditionalAssignmentsForHIT</code> operation increases the maximum number of assignments of an existing HIT. </p> <p> To extend the maximum number of assignments, specify the number of additional assignments.</p> <note> <ul> <li> <p>HITs created with fewer than 10 assignments cannot be extended to have 10 or more assignments. Attempting to add assignments in a way that brings the total number of assignments for a HIT from fewer than 10 assignments to 10 or more assignments will result in an <code>AWS.MechanicalTurk.InvalidMaximumAssignmentsIncrease</code> exception.</p> </li> <li> <p>HITs that were created before July 22, 2015 cannot be extended. Attempting to extend HITs that were created before July 22, 2015 will result in an <code>AWS.MechanicalTurk.HITTooOldForExtension</code> exception. </p> </li> </ul> </note></p>
+    ///
+    /// See `MechanicalTurk::create_additional_assignments_for_hit`.
+    pub fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> Result<
+        CreateAdditionalAssignmentsForHITResponse,
+        RusotoError<CreateAdditionalAssignmentsForHITError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_additional_assignments_for_hit(input))
+    }
+
+    /// <p><p>The <code>CreateHIT</code> operation creates a new Human Intelligence Task (HIT). The new HIT is made available for Workers to find and accept on the Amazon Mechanical Turk website. </p> <p> This operation allows you to specify a new HIT by passing in values for the properties of the HIT, such as its title, reward amount and number of assignments. When you pass these values to <code>CreateHIT</code>, a new HIT is created for you, with a new <code>HITTypeID</code>. The HITTypeID can be used to create additional HITs in the future without needing to specify common parameters such as the title, description and reward amount each time.</p> <p> An alternative way to create HITs is to first generate a HITTypeID using the <code>CreateHITType</code> operation and then call the <code>CreateHITWithHITType</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHIT also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>.</p> </note></p>
+    ///
+    /// See `MechanicalTurk::create_hit`.
+    pub fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
+        self.runtime.block_on(self.client.create_hit(input))
+    }
+
+    /// <p> The <code>CreateHITType</code> operation creates a new HIT type. This operation allows you to define a standard set of HIT properties to use when creating HITs. If you register a HIT type with values that match an existing HIT type, the HIT type ID of the existing type will be returned. </p>
+    ///
+    /// See `MechanicalTurk::create_hit_type`.
+    pub fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
+        self.runtime.block_on(self.client.create_hit_type(input))
+    }
+
+    /// <p><p> The <code>CreateHITWithHITType</code> operation creates a new Human Intelligence Task (HIT) using an existing HITTypeID generated by the <code>CreateHITType</code> operation. </p> <p> This is an alternative way to create HITs from the <code>CreateHIT</code> operation. This is the recommended best practice for Requesters who are creating large numbers of HITs. </p> <p>CreateHITWithHITType also supports several ways to provide question data: by providing a value for the <code>Question</code> parameter that fully specifies the contents of the HIT, or by providing a <code>HitLayoutId</code> and associated <code>HitLayoutParameters</code>. </p> <note> <p> If a HIT is created with 10 or more maximum assignments, there is an additional fee. For more information, see <a href="https://requester.mturk.com/pricing">Amazon Mechanical Turk Pricing</a>. </p> </note></p>
+    ///
+    /// See `MechanicalTurk::create_hit_with_hit_type`.
+    pub fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
+        self.runtime
+            .block_on(self.client.create_hit_with_hit_type(input))
+    }
+
+    /// <p> The <code>CreateQualificationType</code> operation creates a new Qualification type, which is represented by a <code>QualificationType</code> data structure. </p>
+    ///
+    /// See `MechanicalTurk::create_qualification_type`.
+    pub fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.create_qualification_type(input))
+    }
+
+    /// <p>The <code>CreateWorkerBlock</code> operation allows you to prevent a Worker from working on your HITs. For example, you can block a Worker who is producing poor quality work. You can block up to 100,000 Workers.</p>
+    ///
+    /// See `MechanicalTurk::create_worker_block`.
+    pub fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
+        self.runtime
+            .block_on(self.client.create_worker_block(input))
+    }
+
+    /// <p><p> The <code>DeleteHIT</code> operation is used to delete HIT that is no longer needed. Only the Requester who created the HIT can delete it. </p> <p> You can only dispose of HITs that are in the <code>Reviewable</code> state, with all of their submitted assignments already either approved or rejected. If you call the DeleteHIT operation on a HIT that is not in the <code>Reviewable</code> state (for example, that has not expired, or still has active assignments), or on a HIT that is Reviewable but without all of its submitted assignments already approved or rejected, the service will return an error. </p> <note> <ul> <li> <p> HITs are automatically disposed of after 120 days. </p> </li> <li> <p> After you dispose of a HIT, you can no longer approve the HIT&#39;s rejected assignments. </p> </li> <li> <p> Disposed HITs are not returned in results for the ListHITs operation. </p> </li> <li> <p> Disposing HITs can improve the performance of operations such as ListReviewableHITs and ListHITs. </p> </li> </ul> </note></p>
+    ///
+    /// See `MechanicalTurk::delete_hit`.
+    pub fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
+        self.runtime.block_on(self.client.delete_hit(input))
+    }
+
+    /// <p><p> The <code>DeleteQualificationType</code> deletes a Qualification type and deletes any HIT types that are associated with the Qualification type. </p> <p>This operation does not revoke Qualifications already assigned to Workers because the Qualifications might be needed for active HITs. If there are any pending requests for the Qualification type, Amazon Mechanical Turk rejects those requests. After you delete a Qualification type, you can no longer use it to create HITs or HIT types.</p> <note> <p>DeleteQualificationType must wait for all the HITs that use the deleted Qualification type to be deleted before completing. It may take up to 48 hours before DeleteQualificationType completes and the unique name of the Qualification type is available for reuse with CreateQualificationType.</p> </note></p>
+    ///
+    /// See `MechanicalTurk::delete_qualification_type`.
+    pub fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.delete_qualification_type(input))
+    }
+
+    /// <p>The <code>DeleteWorkerBlock</code> operation allows you to reinstate a blocked Worker to work on your HITs. This operation reverses the effects of the CreateWorkerBlock operation. You need the Worker ID to use this operation. If the Worker ID is missing or invalid, this operation fails and returns the message “WorkerId is invalid.” If the specified Worker is not blocked, this operation returns successfully.</p>
+    ///
+    /// See `MechanicalTurk::delete_worker_block`.
+    pub fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
+        self.runtime
+            .block_on(self.client.delete_worker_block(input))
+    }
+
+    /// <p> The <code>DisassociateQualificationFromWorker</code> revokes a previously granted Qualification from a user. </p> <p> You can provide a text message explaining why the Qualification was revoked. The user who had the Qualification can see this message. </p>
+    ///
+    /// See `MechanicalTurk::disassociate_qualification_from_worker`.
+    pub fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> Result<
+        DisassociateQualificationFromWorkerResponse,
+        RusotoError<DisassociateQualificationFromWorkerError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_qualification_from_worker(input))
+    }
+
+    /// <p>The <code>GetAccountBalance</code> operation retrieves the amount of money in your Amazon Mechanical Turk account.</p>
+    ///
+    /// See `MechanicalTurk::get_account_balance`.
+    pub fn get_account_balance(
+        &self,
+    ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
+        self.runtime.block_on(self.client.get_account_balance())
+    }
+
+    /// <p> The <code>GetAssignment</code> operation retrieves the details of the specified Assignment. </p>
+    ///
+    /// See `MechanicalTurk::get_assignment`.
+    pub fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
+        self.runtime.block_on(self.client.get_assignment(input))
+    }
+
+    /// <p> The <code>GetFileUploadURL</code> operation generates and returns a temporary URL. You use the temporary URL to retrieve a file uploaded by a Worker as an answer to a FileUploadAnswer question for a HIT. The temporary URL is generated the instant the GetFileUploadURL operation is called, and is valid for 60 seconds. You can get a temporary file upload URL any time until the HIT is disposed. After the HIT is disposed, any uploaded files are deleted, and cannot be retrieved. Pending Deprecation on December 12, 2017. The Answer Specification structure will no longer support the <code>FileUploadAnswer</code> element to be used for the QuestionForm data structure. Instead, we recommend that Requesters who want to create HITs asking Workers to upload files to use Amazon S3. </p>
+    ///
+    /// See `MechanicalTurk::get_file_upload_url`.
+    pub fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
+        self.runtime
+            .block_on(self.client.get_file_upload_url(input))
+    }
+
+    /// <p> The <code>GetHIT</code> operation retrieves the details of the specified HIT. </p>
+    ///
+    /// See `MechanicalTurk::get_hit`.
+    pub fn get_hit(
+        &self,
+        input: GetHITRequest,
+    ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
+        self.runtime.block_on(self.client.get_hit(input))
+    }
+
+    /// <p> The <code>GetQualificationScore</code> operation returns the value of a Worker's Qualification for a given Qualification type. </p> <p> To get a Worker's Qualification, you must know the Worker's ID. The Worker's ID is included in the assignment data returned by the <code>ListAssignmentsForHIT</code> operation. </p> <p>Only the owner of a Qualification type can query the value of a Worker's Qualification of that type.</p>
+    ///
+    /// See `MechanicalTurk::get_qualification_score`.
+    pub fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
+        self.runtime
+            .block_on(self.client.get_qualification_score(input))
+    }
+
+    /// <p> The <code>GetQualificationType</code>operation retrieves information about a Qualification type using its ID. </p>
+    ///
+    /// See `MechanicalTurk::get_qualification_type`.
+    pub fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.get_qualification_type(input))
+    }
+
+    /// <p> The <code>ListAssignmentsForHIT</code> operation retrieves completed assignments for a HIT. You can use this operation to retrieve the results for a HIT. </p> <p> You can get assignments for a HIT at any time, even if the HIT is not yet Reviewable. If a HIT requested multiple assignments, and has received some results but has not yet become Reviewable, you can still retrieve the partial results with this operation. </p> <p> Use the AssignmentStatus parameter to control which set of assignments for a HIT are returned. The ListAssignmentsForHIT operation can return submitted assignments awaiting approval, or it can return assignments that have already been approved or rejected. You can set AssignmentStatus=Approved,Rejected to get assignments that have already been approved and rejected together in one result set. </p> <p> Only the Requester who created the HIT can retrieve the assignments for that HIT. </p> <p> Results are sorted and divided into numbered pages and the operation returns a single page of results. You can use the parameters of the operation to control sorting and pagination. </p>
+    ///
+    /// See `MechanicalTurk::list_assignments_for_hit`.
+    pub fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
+        self.runtime
+            .block_on(self.client.list_assignments_for_hit(input))
+    }
+
+    /// <p> The <code>ListBonusPayments</code> operation retrieves the amounts of bonuses you have paid to Workers for a given HIT or assignment. </p>
+    ///
+    /// See `MechanicalTurk::list_bonus_payments`.
+    pub fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
+        self.runtime
+            .block_on(self.client.list_bonus_payments(input))
+    }
+
+    /// <p> The <code>ListHITs</code> operation returns all of a Requester's HITs. The operation returns HITs of any status, except for HITs that have been deleted of with the DeleteHIT operation or that have been auto-deleted. </p>
+    ///
+    /// See `MechanicalTurk::list_hi_ts`.
+    pub fn list_hi_ts(
+        &self,
+        input: ListHITsRequest,
+    ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
+        self.runtime.block_on(self.client.list_hi_ts(input))
+    }
+
+    /// <p> The <code>ListHITsForQualificationType</code> operation returns the HITs that use the given Qualification type for a Qualification requirement. The operation returns HITs of any status, except for HITs that have been deleted with the <code>DeleteHIT</code> operation or that have been auto-deleted. </p>
+    ///
+    /// See `MechanicalTurk::list_hi_ts_for_qualification_type`.
+    pub fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
+    {
+        self.runtime
+            .block_on(self.client.list_hi_ts_for_qualification_type(input))
+    }
+
+    /// <p> The <code>ListQualificationRequests</code> operation retrieves requests for Qualifications of a particular Qualification type. The owner of the Qualification type calls this operation to poll for pending requests, and accepts them using the AcceptQualification operation. </p>
+    ///
+    /// See `MechanicalTurk::list_qualification_requests`.
+    pub fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_qualification_requests(input))
+    }
+
+    /// <p> The <code>ListQualificationTypes</code> operation returns a list of Qualification types, filtered by an optional search term. </p>
+    ///
+    /// See `MechanicalTurk::list_qualification_types`.
+    pub fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
+        self.runtime
+            .block_on(self.client.list_qualification_types(input))
+    }
+
+    /// <p> The <code>ListReviewPolicyResultsForHIT</code> operation retrieves the computed results and the actions taken in the course of executing your Review Policies for a given HIT. For information about how to specify Review Policies when you call CreateHIT, see Review Policies. The ListReviewPolicyResultsForHIT operation can return results for both Assignment-level and HIT-level review results. </p>
+    ///
+    /// See `MechanicalTurk::list_review_policy_results_for_hit`.
+    pub fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> Result<
+        ListReviewPolicyResultsForHITResponse,
+        RusotoError<ListReviewPolicyResultsForHITError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_review_policy_results_for_hit(input))
+    }
+
+    /// <p> The <code>ListReviewableHITs</code> operation retrieves the HITs with Status equal to Reviewable or Status equal to Reviewing that belong to the Requester calling the operation. </p>
+    ///
+    /// See `MechanicalTurk::list_reviewable_hi_ts`.
+    pub fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
+        self.runtime
+            .block_on(self.client.list_reviewable_hi_ts(input))
+    }
+
+    /// <p>The <code>ListWorkersBlocks</code> operation retrieves a list of Workers who are blocked from working on your HITs.</p>
+    ///
+    /// See `MechanicalTurk::list_worker_blocks`.
+    pub fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
+        self.runtime.block_on(self.client.list_worker_blocks(input))
+    }
+
+    /// <p> The <code>ListWorkersWithQualificationType</code> operation returns all of the Workers that have been associated with a given Qualification type. </p>
+    ///
+    /// See `MechanicalTurk::list_workers_with_qualification_type`.
+    pub fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> Result<
+        ListWorkersWithQualificationTypeResponse,
+        RusotoError<ListWorkersWithQualificationTypeError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_workers_with_qualification_type(input))
+    }
+
+    /// <p> The <code>NotifyWorkers</code> operation sends an email to one or more Workers that you specify with the Worker ID. You can specify up to 100 Worker IDs to send the same message with a single call to the NotifyWorkers operation. The NotifyWorkers operation will send a notification email to a Worker only if you have previously approved or rejected work from the Worker. </p>
+    ///
+    /// See `MechanicalTurk::notify_workers`.
+    pub fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
+        self.runtime.block_on(self.client.notify_workers(input))
+    }
+
+    /// <p> The <code>RejectAssignment</code> operation rejects the results of a completed assignment. </p> <p> You can include an optional feedback message with the rejection, which the Worker can see in the Status section of the web site. When you include a feedback message with the rejection, it helps the Worker understand why the assignment was rejected, and can improve the quality of the results the Worker submits in the future. </p> <p> Only the Requester who created the HIT can reject an assignment for the HIT. </p>
+    ///
+    /// See `MechanicalTurk::reject_assignment`.
+    pub fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
+        self.runtime.block_on(self.client.reject_assignment(input))
+    }
+
+    /// <p> The <code>RejectQualificationRequest</code> operation rejects a user's request for a Qualification. </p> <p> You can provide a text message explaining why the request was rejected. The Worker who made the request can see this message.</p>
+    ///
+    /// See `MechanicalTurk::reject_qualification_request`.
+    pub fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_qualification_request(input))
+    }
+
+    /// <p> The <code>SendBonus</code> operation issues a payment of money from your account to a Worker. This payment happens separately from the reward you pay to the Worker when you approve the Worker's assignment. The SendBonus operation requires the Worker's ID and the assignment ID as parameters to initiate payment of the bonus. You must include a message that explains the reason for the bonus payment, as the Worker may not be expecting the payment. Amazon Mechanical Turk collects a fee for bonus payments, similar to the HIT listing fee. This operation fails if your account does not have enough funds to pay for both the bonus and the fees. </p>
+    ///
+    /// See `MechanicalTurk::send_bonus`.
+    pub fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
+        self.runtime.block_on(self.client.send_bonus(input))
+    }
+
+    /// <p> The <code>SendTestEventNotification</code> operation causes Amazon Mechanical Turk to send a notification message as if a HIT event occurred, according to the provided notification specification. This allows you to test notifications without setting up notifications for a real HIT type and trying to trigger them using the website. When you call this operation, the service attempts to send the test notification immediately. </p>
+    ///
+    /// See `MechanicalTurk::send_test_event_notification`.
+    pub fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
+    {
+        self.runtime
+            .block_on(self.client.send_test_event_notification(input))
+    }
+
+    /// <p> The <code>UpdateExpirationForHIT</code> operation allows you update the expiration time of a HIT. If you update it to a time in the past, the HIT will be immediately expired. </p>
+    ///
+    /// See `MechanicalTurk::update_expiration_for_hit`.
+    pub fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
+        self.runtime
+            .block_on(self.client.update_expiration_for_hit(input))
+    }
+
+    /// <p> The <code>UpdateHITReviewStatus</code> operation updates the status of a HIT. If the status is Reviewable, this operation can update the status to Reviewing, or it can revert a Reviewing HIT back to the Reviewable status. </p>
+    ///
+    /// See `MechanicalTurk::update_hit_review_status`.
+    pub fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
+        self.runtime
+            .block_on(self.client.update_hit_review_status(input))
+    }
+
+    /// <p> The <code>UpdateHITTypeOfHIT</code> operation allows you to change the HITType properties of a HIT. This operation disassociates the HIT from its old HITType properties and associates it with the new HITType properties. The HIT takes on the properties of the new HITType in place of the old ones. </p>
+    ///
+    /// See `MechanicalTurk::update_hit_type_of_hit`.
+    pub fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
+        self.runtime
+            .block_on(self.client.update_hit_type_of_hit(input))
+    }
+
+    /// <p> The <code>UpdateNotificationSettings</code> operation creates, updates, disables or re-enables notifications for a HIT type. If you call the UpdateNotificationSettings operation for a HIT type that already has a notification specification, the operation replaces the old specification with a new one. You can call the UpdateNotificationSettings operation to enable or disable notifications for the HIT type, without having to modify the notification specification itself by providing updates to the Active status without specifying a new notification specification. To change the Active status of a HIT type's notifications, the HIT type must already have a notification specification, or one must be provided in the same call to <code>UpdateNotificationSettings</code>. </p>
+    ///
+    /// See `MechanicalTurk::update_notification_settings`.
+    pub fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_notification_settings(input))
+    }
+
+    /// <p> The <code>UpdateQualificationType</code> operation modifies the attributes of an existing Qualification type, which is represented by a QualificationType data structure. Only the owner of a Qualification type can modify its attributes. </p> <p> Most attributes of a Qualification type can be changed after the type has been created. However, the Name and Keywords fields cannot be modified. The RetryDelayInSeconds parameter can be modified or added to change the delay or to enable retries, but RetryDelayInSeconds cannot be used to disable retries. </p> <p> You can use this operation to update the test for a Qualification type. The test is updated based on the values specified for the Test, TestDurationInSeconds and AnswerKey parameters. All three parameters specify the updated test. If you are updating the test for a type, you must specify the Test and TestDurationInSeconds parameters. The AnswerKey parameter is optional; omitting it specifies that the updated test does not have an answer key. </p> <p> If you omit the Test parameter, the test for the Qualification type is unchanged. There is no way to remove a test from a Qualification type that has one. If the type already has a test, you cannot update it to be AutoGranted. If the Qualification type does not have a test and one is provided by an update, the type will henceforth have a test. </p> <p> If you want to update the test duration or answer key for an existing test without changing the questions, you must specify a Test parameter with the original questions, along with the updated values. </p> <p> If you provide an updated Test but no AnswerKey, the new test will not have an answer key. Requests for such Qualifications must be granted manually. </p> <p> You can also update the AutoGranted and AutoGrantedValue attributes of the Qualification type.</p>
+    ///
+    /// See `MechanicalTurk::update_qualification_type`.
+    pub fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.update_qualification_type(input))
+    }
+}
@@ -8558,26 +8558,30 @@ impl AddRoleToDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -8636,14 +8640,16 @@ impl AddSourceIdentifierToSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -8698,17 +8704,20 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -8756,7 +8765,8 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -8810,21 +8820,24 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -8888,42 +8901,48 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -8990,21 +9009,24 @@ impl CopyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -9086,85 +9108,100 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DBInstanceNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -9234,14 +9271,16 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -9300,35 +9339,40 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -9422,106 +9466,124 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::DomainNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -9600,14 +9662,16 @@ impl CreateDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -9666,37 +9730,42 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9762,47 +9831,54 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -9872,31 +9948,36 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -9952,14 +10033,16 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -10012,14 +10095,16 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
@@ -10078,31 +10163,36 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -10154,14 +10244,16 @@ impl DeleteDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -10216,21 +10308,24 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
                                 parsed_error.message,
                             ),
@@ -10286,14 +10381,16 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidEventSubscriptionState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10346,7 +10443,8 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10396,7 +10494,8 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10445,7 +10544,8 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service(
+                        &res,
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
                             parsed_error.message,
                         ),
@@ -10494,7 +10594,8 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10542,7 +10643,8 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
@@ -10620,7 +10722,8 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
@@ -10664,7 +10767,8 @@ impl DescribeDBParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10712,7 +10816,8 @@ impl DescribeDBParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10760,7 +10865,8 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -10916,7 +11022,8 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -11036,7 +11143,8 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -11088,14 +11196,16 @@ impl DescribeValidDBInstanceModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -11150,19 +11260,22 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -11216,17 +11329,20 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -11292,63 +11408,74 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -11412,14 +11539,16 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -11476,19 +11605,22 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service(
+                        &res,
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
                             parsed_error.message,
                         ),
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -11572,100 +11704,116 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBInstanceError::DomainNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -11744,14 +11892,16 @@ impl ModifyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -11810,35 +11960,40 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11900,42 +12055,48 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -12000,14 +12161,16 @@ impl PromoteReadReplicaDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -12060,12 +12223,14 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -12116,21 +12281,24 @@ impl RemoveRoleFromDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterRoleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -12188,14 +12356,16 @@ impl RemoveSourceIdentifierFromSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -12250,21 +12420,24 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -12322,14 +12495,16 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -12382,14 +12557,16 @@ impl ResetDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -12472,108 +12649,124 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -12698,113 +12891,130 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "DBClusterParameterGroupNotFound" => return RusotoError::Service(
+                    "DBClusterParameterGroupNotFound" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
                             parsed_error.message,
                         ),
                     ),
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -12902,17 +13112,20 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
@@ -12962,17 +13175,20 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
@@ -13401,6 +13617,7 @@ impl NeptuneClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -13416,6 +13633,7 @@ impl NeptuneClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> NeptuneClient {
         NeptuneClient { client, region }
     }
@@ -13429,6 +13647,9 @@ impl Neptune for NeptuneClient {
         input: AddRoleToDBClusterMessage,
     ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddRoleToDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddRoleToDBCluster");
         let mut params = params;
         AddRoleToDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -13452,6 +13673,9 @@ impl Neptune for NeptuneClient {
         RusotoError<AddSourceIdentifierToSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddSourceIdentifierToSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddSourceIdentifierToSubscription");
         let mut params = params;
         AddSourceIdentifierToSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -13488,6 +13712,9 @@ impl Neptune for NeptuneClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -13509,6 +13736,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ApplyPendingMaintenanceAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ApplyPendingMaintenanceAction");
         let mut params = params;
         ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
@@ -13543,6 +13773,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBClusterParameterGroup");
         let mut params = params;
         CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -13576,6 +13809,9 @@ impl Neptune for NeptuneClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBClusterSnapshot");
         let mut params = params;
         CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -13609,6 +13845,9 @@ impl Neptune for NeptuneClient {
         input: CopyDBParameterGroupMessage,
     ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBParameterGroup");
         let mut params = params;
         CopyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -13642,6 +13881,9 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBCluster");
         let mut params = params;
         CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -13674,6 +13916,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterParameterGroup");
         let mut params = params;
         CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -13707,6 +13952,9 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterSnapshot");
         let mut params = params;
         CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -13740,6 +13988,9 @@ impl Neptune for NeptuneClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBInstance");
         let mut params = params;
         CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -13771,6 +14022,9 @@ impl Neptune for NeptuneClient {
         input: CreateDBParameterGroupMessage,
     ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBParameterGroup");
         let mut params = params;
         CreateDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -13804,6 +14058,9 @@ impl Neptune for NeptuneClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBSubnetGroup");
         let mut params = params;
         CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -13837,6 +14094,9 @@ impl Neptune for NeptuneClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateEventSubscription");
         let mut params = params;
         CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -13870,6 +14130,9 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBCluster");
         let mut params = params;
         DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -13901,6 +14164,9 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterParameterGroup");
         let mut params = params;
         DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -13921,6 +14187,9 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterSnapshot");
         let mut params = params;
         DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -13954,6 +14223,9 @@ impl Neptune for NeptuneClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBInstance");
         let mut params = params;
         DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -13985,6 +14257,9 @@ impl Neptune for NeptuneClient {
         input: DeleteDBParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBParameterGroup");
         let mut params = params;
         DeleteDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14005,6 +14280,9 @@ impl Neptune for NeptuneClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBSubnetGroup");
         let mut params = params;
         DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14025,6 +14303,9 @@ impl Neptune for NeptuneClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteEventSubscription");
         let mut params = params;
         DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -14059,6 +14340,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterParameterGroups");
         let mut params = params;
         DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -14095,6 +14379,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterParameters");
         let mut params = params;
         DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -14131,6 +14418,9 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterSnapshotAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterSnapshotAttributes");
         let mut params = params;
         DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -14167,6 +14457,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterSnapshots");
         let mut params = params;
         DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -14200,6 +14493,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusters");
         let mut params = params;
         DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -14231,6 +14527,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBEngineVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBEngineVersions");
         let mut params = params;
         DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -14264,6 +14563,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBInstances");
         let mut params = params;
         DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -14295,6 +14597,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBParameterGroupsMessage,
     ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBParameterGroups");
         let mut params = params;
         DescribeDBParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -14328,6 +14633,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBParametersMessage,
     ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBParameters");
         let mut params = params;
         DescribeDBParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -14361,6 +14669,9 @@ impl Neptune for NeptuneClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBSubnetGroups");
         let mut params = params;
         DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -14397,6 +14708,9 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEngineDefaultClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEngineDefaultClusterParameters");
         let mut params = params;
         DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -14436,6 +14750,9 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEngineDefaultParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEngineDefaultParameters");
         let mut params = params;
         DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -14469,6 +14786,9 @@ impl Neptune for NeptuneClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEventCategories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -14502,6 +14822,9 @@ impl Neptune for NeptuneClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEventSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventSubscriptions");
         let mut params = params;
         DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -14535,6 +14858,9 @@ impl Neptune for NeptuneClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -14568,6 +14894,9 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeOrderableDBInstanceOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeOrderableDBInstanceOptions");
         let mut params = params;
         DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -14605,6 +14934,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribePendingMaintenanceActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePendingMaintenanceActions");
         let mut params = params;
         DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -14644,6 +14976,9 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeValidDBInstanceModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeValidDBInstanceModifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeValidDBInstanceModifications");
         let mut params = params;
         DescribeValidDBInstanceModificationsMessageSerializer::serialize(&mut params, "", &input);
@@ -14680,6 +15015,9 @@ impl Neptune for NeptuneClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("FailoverDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("FailoverDBCluster");
         let mut params = params;
         FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14711,6 +15049,9 @@ impl Neptune for NeptuneClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -14742,6 +15083,9 @@ impl Neptune for NeptuneClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBCluster");
         let mut params = params;
         ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14774,6 +15118,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterParameterGroup");
         let mut params = params;
         ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14810,6 +15157,9 @@ impl Neptune for NeptuneClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterSnapshotAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterSnapshotAttribute");
         let mut params = params;
         ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -14846,6 +15196,9 @@ impl Neptune for NeptuneClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBInstance");
         let mut params = params;
         ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -14877,6 +15230,9 @@ impl Neptune for NeptuneClient {
         input: ModifyDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBParameterGroup");
         let mut params = params;
         ModifyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14910,6 +15266,9 @@ impl Neptune for NeptuneClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBSubnetGroup");
         let mut params = params;
         ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14943,6 +15302,9 @@ impl Neptune for NeptuneClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyEventSubscription");
         let mut params = params;
         ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -14977,6 +15339,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("PromoteReadReplicaDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PromoteReadReplicaDBCluster");
         let mut params = params;
         PromoteReadReplicaDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15010,6 +15375,9 @@ impl Neptune for NeptuneClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RebootDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebootDBInstance");
         let mut params = params;
         RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -15041,6 +15409,9 @@ impl Neptune for NeptuneClient {
         input: RemoveRoleFromDBClusterMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveRoleFromDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveRoleFromDBCluster");
         let mut params = params;
         RemoveRoleFromDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15064,6 +15435,9 @@ impl Neptune for NeptuneClient {
         RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveSourceIdentifierFromSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveSourceIdentifierFromSubscription");
         let mut params = params;
         RemoveSourceIdentifierFromSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -15100,6 +15474,9 @@ impl Neptune for NeptuneClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -15121,6 +15498,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ResetDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetDBClusterParameterGroup");
         let mut params = params;
         ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15154,6 +15534,9 @@ impl Neptune for NeptuneClient {
         input: ResetDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ResetDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetDBParameterGroup");
         let mut params = params;
         ResetDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15188,6 +15571,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterFromSnapshot");
         let mut params = params;
         RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -15222,6 +15608,9 @@ impl Neptune for NeptuneClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterToPointInTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterToPointInTime");
         let mut params = params;
         RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -15255,6 +15644,9 @@ impl Neptune for NeptuneClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StartDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartDBCluster");
         let mut params = params;
         StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15286,6 +15678,9 @@ impl Neptune for NeptuneClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StopDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopDBCluster");
         let mut params = params;
         StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15311,3 +15706,730 @@ impl Neptune for NeptuneClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon Neptune API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `NeptuneClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct NeptuneBlockingClient {
+    client: NeptuneClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl NeptuneBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> NeptuneBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        NeptuneBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> NeptuneBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        NeptuneBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> NeptuneBlockingClient {
+        NeptuneBlockingClient {
+            client: NeptuneClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates an Identity and Access Management (IAM) role from an Neptune DB cluster.</p>
+    ///
+    /// See `Neptune::add_role_to_db_cluster`.
+    pub fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_db_cluster(input))
+    }
+
+    /// <p>Adds a source identifier to an existing event notification subscription.</p>
+    ///
+    /// See `Neptune::add_source_identifier_to_subscription`.
+    pub fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<
+        AddSourceIdentifierToSubscriptionResult,
+        RusotoError<AddSourceIdentifierToSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_source_identifier_to_subscription(input))
+    }
+
+    /// <p>Adds metadata tags to an Amazon Neptune resource. These tags can also be used with cost allocation reporting to track cost associated with Amazon Neptune resources, or used in a Condition statement in an IAM policy for Amazon Neptune.</p>
+    ///
+    /// See `Neptune::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
+    ///
+    /// See `Neptune::apply_pending_maintenance_action`.
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// <p>Copies the specified DB cluster parameter group.</p>
+    ///
+    /// See `Neptune::copy_db_cluster_parameter_group`.
+    pub fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p>
+    ///
+    /// See `Neptune::copy_db_cluster_snapshot`.
+    pub fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_snapshot(input))
+    }
+
+    /// <p>Copies the specified DB parameter group.</p>
+    ///
+    /// See `Neptune::copy_db_parameter_group`.
+    pub fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.copy_db_parameter_group(input))
+    }
+
+    /// <p>Creates a new Amazon Neptune DB cluster.</p> <p>You can use the <code>ReplicationSourceIdentifier</code> parameter to create the DB cluster as a Read Replica of another DB cluster or Amazon Neptune DB instance.</p> <p>Note that when you create a new cluster using <code>CreateDBCluster</code> directly, deletion protection is disabled by default (when you create a new production cluster in the console, deletion protection is enabled by default). You can only delete a DB cluster if its <code>DeletionProtection</code> field is set to <code>false</code>.</p>
+    ///
+    /// See `Neptune::create_db_cluster`.
+    pub fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.runtime.block_on(self.client.create_db_cluster(input))
+    }
+
+    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p> A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after creating it using <a>ModifyDBClusterParameterGroup</a>. Once you&#39;ve created a DB cluster parameter group, you need to associate it with your DB cluster using <a>ModifyDBCluster</a>. When you associate a new DB cluster parameter group with a running DB cluster, you need to reboot the DB instances in the DB cluster without failover for the new DB cluster parameter group and associated settings to take effect.</p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon Neptune console</a> or the <a>DescribeDBClusterParameters</a> command to verify that your DB cluster parameter group has been created or modified.</p> </important></p>
+    ///
+    /// See `Neptune::create_db_cluster_parameter_group`.
+    pub fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Creates a snapshot of a DB cluster.</p>
+    ///
+    /// See `Neptune::create_db_cluster_snapshot`.
+    pub fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new DB instance.</p>
+    ///
+    /// See `Neptune::create_db_instance`.
+    pub fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.runtime.block_on(self.client.create_db_instance(input))
+    }
+
+    /// <p><p>Creates a new DB parameter group.</p> <p>A DB parameter group is initially created with the default parameters for the database engine used by the DB instance. To provide custom values for any of the parameters, you must modify the group after creating it using <i>ModifyDBParameterGroup</i>. Once you&#39;ve created a DB parameter group, you need to associate it with your DB instance using <i>ModifyDBInstance</i>. When you associate a new DB parameter group with a running DB instance, you need to reboot the DB instance without failover for the new DB parameter group and associated settings to take effect.</p> <important> <p>After you create a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
+    ///
+    /// See `Neptune::create_db_parameter_group`.
+    pub fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_parameter_group(input))
+    }
+
+    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
+    ///
+    /// See `Neptune::create_db_subnet_group`.
+    pub fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_subnet_group(input))
+    }
+
+    /// <p>Creates an event notification subscription. This action requires a topic ARN (Amazon Resource Name) created by either the Neptune console, the SNS console, or the SNS API. To obtain an ARN with SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the type of source (SourceType) you want to be notified of, provide a list of Neptune sources (SourceIds) that triggers the events, and provide a list of event categories (EventCategories) for events you want to be notified of. For example, you can specify SourceType = db-instance, SourceIds = mydbinstance1, mydbinstance2 and EventCategories = Availability, Backup.</p> <p>If you specify both the SourceType and SourceIds, such as SourceType = db-instance and SourceIdentifier = myDBInstance1, you are notified of all the db-instance events for the specified source. If you specify a SourceType but do not specify a SourceIdentifier, you receive notice of the events for that source type for all your Neptune sources. If you do not specify either the SourceType nor the SourceIdentifier, you are notified of events generated from all Neptune sources belonging to your customer account.</p>
+    ///
+    /// See `Neptune::create_event_subscription`.
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// <p>The DeleteDBCluster action deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can't be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p> <p>Note that the DB Cluster cannot be deleted if deletion protection is enabled. To delete it, you must first set its <code>DeletionProtection</code> field to <code>False</code>.</p>
+    ///
+    /// See `Neptune::delete_db_cluster`.
+    pub fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.runtime.block_on(self.client.delete_db_cluster(input))
+    }
+
+    /// <p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can't be associated with any DB clusters.</p>
+    ///
+    /// See `Neptune::delete_db_cluster_parameter_group`.
+    pub fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
+    ///
+    /// See `Neptune::delete_db_cluster_snapshot`.
+    pub fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_snapshot(input))
+    }
+
+    /// <p>The DeleteDBInstance action deletes a previously provisioned DB instance. When you delete a DB instance, all automated backups for that instance are deleted and can't be recovered. Manual DB snapshots of the DB instance to be deleted by <code>DeleteDBInstance</code> are not deleted.</p> <p> If you request a final DB snapshot the status of the Amazon Neptune DB instance is <code>deleting</code> until the DB snapshot is created. The API action <code>DescribeDBInstance</code> is used to monitor the status of this operation. The action can't be canceled or reverted once submitted.</p> <p>Note that when a DB instance is in a failure state and has a status of <code>failed</code>, <code>incompatible-restore</code>, or <code>incompatible-network</code>, you can only delete it when the <code>SkipFinalSnapshot</code> parameter is set to <code>true</code>.</p> <p>You can't delete a DB instance if it is the only instance in the DB cluster, or if it has deletion protection enabled.</p>
+    ///
+    /// See `Neptune::delete_db_instance`.
+    pub fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.runtime.block_on(self.client.delete_db_instance(input))
+    }
+
+    /// <p>Deletes a specified DBParameterGroup. The DBParameterGroup to be deleted can't be associated with any DB instances.</p>
+    ///
+    /// See `Neptune::delete_db_parameter_group`.
+    pub fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_parameter_group(input))
+    }
+
+    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
+    ///
+    /// See `Neptune::delete_db_subnet_group`.
+    pub fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_subnet_group(input))
+    }
+
+    /// <p>Deletes an event notification subscription.</p>
+    ///
+    /// See `Neptune::delete_event_subscription`.
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// <p> Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list will contain only the description of the specified DB cluster parameter group.</p>
+    ///
+    /// See `Neptune::describe_db_cluster_parameter_groups`.
+    pub fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameter_groups(input))
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB cluster parameter group.</p>
+    ///
+    /// See `Neptune::describe_db_cluster_parameters`.
+    pub fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameters(input))
+    }
+
+    /// <p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When sharing snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p> <p>To add or remove access for an AWS account to copy or restore a manual DB cluster snapshot, or to make the manual DB cluster snapshot public or private, use the <a>ModifyDBClusterSnapshotAttribute</a> API action.</p>
+    ///
+    /// See `Neptune::describe_db_cluster_snapshot_attributes`.
+    pub fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshot_attributes(input))
+    }
+
+    /// <p>Returns information about DB cluster snapshots. This API action supports pagination.</p>
+    ///
+    /// See `Neptune::describe_db_cluster_snapshots`.
+    pub fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshots(input))
+    }
+
+    /// <p><p>Returns information about provisioned DB clusters, and supports pagination.</p> <note> <p>This operation can also return information for Amazon RDS clusters and Amazon DocDB clusters.</p> </note></p>
+    ///
+    /// See `Neptune::describe_db_clusters`.
+    pub fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_clusters(input))
+    }
+
+    /// <p>Returns a list of the available DB engines.</p>
+    ///
+    /// See `Neptune::describe_db_engine_versions`.
+    pub fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_engine_versions(input))
+    }
+
+    /// <p><p>Returns information about provisioned instances, and supports pagination.</p> <note> <p>This operation can also return information for Amazon RDS instances and Amazon DocDB instances.</p> </note></p>
+    ///
+    /// See `Neptune::describe_db_instances`.
+    pub fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_instances(input))
+    }
+
+    /// <p>Returns a list of <code>DBParameterGroup</code> descriptions. If a <code>DBParameterGroupName</code> is specified, the list will contain only the description of the specified DB parameter group.</p>
+    ///
+    /// See `Neptune::describe_db_parameter_groups`.
+    pub fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameter_groups(input))
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB parameter group.</p>
+    ///
+    /// See `Neptune::describe_db_parameters`.
+    pub fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameters(input))
+    }
+
+    /// <p>Returns a list of DBSubnetGroup descriptions. If a DBSubnetGroupName is specified, the list will contain only the descriptions of the specified DBSubnetGroup.</p> <p>For an overview of CIDR ranges, go to the <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Wikipedia Tutorial</a>.</p>
+    ///
+    /// See `Neptune::describe_db_subnet_groups`.
+    pub fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_subnet_groups(input))
+    }
+
+    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p>
+    ///
+    /// See `Neptune::describe_engine_default_cluster_parameters`.
+    pub fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_engine_default_cluster_parameters(input),
+        )
+    }
+
+    /// <p>Returns the default engine and system parameter information for the specified database engine.</p>
+    ///
+    /// See `Neptune::describe_engine_default_parameters`.
+    pub fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_engine_default_parameters(input))
+    }
+
+    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type.</p>
+    ///
+    /// See `Neptune::describe_event_categories`.
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// <p>Lists all the subscription descriptions for a customer account. The description for a subscription includes SubscriptionName, SNSTopicARN, CustomerID, SourceType, SourceID, CreationTime, and Status.</p> <p>If you specify a SubscriptionName, lists the description for that subscription.</p>
+    ///
+    /// See `Neptune::describe_event_subscriptions`.
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. Events specific to a particular DB instance, DB security group, database snapshot, or DB parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
+    ///
+    /// See `Neptune::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
+    ///
+    /// See `Neptune::describe_orderable_db_instance_options`.
+    pub fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_db_instance_options(input))
+    }
+
+    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
+    ///
+    /// See `Neptune::describe_pending_maintenance_actions`.
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// <p>You can call <a>DescribeValidDBInstanceModifications</a> to learn what modifications you can make to your DB instance. You can use this information when you call <a>ModifyDBInstance</a>.</p>
+    ///
+    /// See `Neptune::describe_valid_db_instance_modifications`.
+    pub fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<
+        DescribeValidDBInstanceModificationsResult,
+        RusotoError<DescribeValidDBInstanceModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_valid_db_instance_modifications(input))
+    }
+
+    /// <p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Read Replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>Amazon Neptune will automatically fail over to a Read Replica, if one exists, when the primary instance fails. You can force a failover when you want to simulate a failure of a primary instance for testing. Because each instance in a DB cluster has its own endpoint address, you will need to clean up and re-establish any existing connections that use those endpoint addresses when the failover is complete.</p>
+    ///
+    /// See `Neptune::failover_db_cluster`.
+    pub fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.runtime
+            .block_on(self.client.failover_db_cluster(input))
+    }
+
+    /// <p>Lists all tags on an Amazon Neptune resource.</p>
+    ///
+    /// See `Neptune::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Modify a setting for a DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request.</p>
+    ///
+    /// See `Neptune::modify_db_cluster`.
+    pub fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.runtime.block_on(self.client.modify_db_cluster(input))
+    }
+
+    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request.</p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB cluster associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon Neptune to fully complete the create action before the parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <a>DescribeDBClusterParameters</a> command to verify that your DB cluster parameter group has been created or modified.</p> </important></p>
+    ///
+    /// See `Neptune::modify_db_cluster_parameter_group`.
+    pub fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code> and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts. Do not add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don't want available to all AWS accounts. If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p> <p>To view which AWS accounts have access to copy or restore a manual DB cluster snapshot, or whether a manual DB cluster snapshot public or private, use the <a>DescribeDBClusterSnapshotAttributes</a> API action.</p>
+    ///
+    /// See `Neptune::modify_db_cluster_snapshot_attribute`.
+    pub fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_snapshot_attribute(input))
+    }
+
+    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. To learn what modifications you can make to your DB instance, call <a>DescribeValidDBInstanceModifications</a> before you call <a>ModifyDBInstance</a>.</p>
+    ///
+    /// See `Neptune::modify_db_instance`.
+    pub fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.runtime.block_on(self.client.modify_db_instance(input))
+    }
+
+    /// <p><p>Modifies the parameters of a DB parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request.</p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB instance associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you modify a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon Neptune to fully complete the modify action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the Amazon Neptune console or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
+    ///
+    /// See `Neptune::modify_db_parameter_group`.
+    pub fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_parameter_group(input))
+    }
+
+    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
+    ///
+    /// See `Neptune::modify_db_subnet_group`.
+    pub fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_subnet_group(input))
+    }
+
+    /// <p>Modifies an existing event notification subscription. Note that you can't modify the source identifiers using this call; to change source identifiers for a subscription, use the <a>AddSourceIdentifierToSubscription</a> and <a>RemoveSourceIdentifierFromSubscription</a> calls.</p> <p>You can see a list of the event categories for a given SourceType by using the <b>DescribeEventCategories</b> action.</p>
+    ///
+    /// See `Neptune::modify_event_subscription`.
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// <p>Not supported.</p>
+    ///
+    /// See `Neptune::promote_read_replica_db_cluster`.
+    pub fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
+    {
+        self.runtime
+            .block_on(self.client.promote_read_replica_db_cluster(input))
+    }
+
+    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain modifications, or if you change the DB parameter group associated with the DB instance, you must reboot the instance for the changes to take effect.</p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to rebooting.</p>
+    ///
+    /// See `Neptune::reboot_db_instance`.
+    pub fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.runtime.block_on(self.client.reboot_db_instance(input))
+    }
+
+    /// <p>Disassociates an Identity and Access Management (IAM) role from a DB cluster.</p>
+    ///
+    /// See `Neptune::remove_role_from_db_cluster`.
+    pub fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_db_cluster(input))
+    }
+
+    /// <p>Removes a source identifier from an existing event notification subscription.</p>
+    ///
+    /// See `Neptune::remove_source_identifier_from_subscription`.
+    pub fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .remove_source_identifier_from_subscription(input),
+        )
+    }
+
+    /// <p>Removes metadata tags from an Amazon Neptune resource.</p>
+    ///
+    /// See `Neptune::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters.</p> <p> When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <a>RebootDBInstance</a> request. You must call <a>RebootDBInstance</a> for every DB instance in your DB cluster that you want the updated static parameter to apply to.</p>
+    ///
+    /// See `Neptune::reset_db_cluster_parameter_group`.
+    pub fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Modifies the parameters of a DB parameter group to the engine/system default value. To reset specific parameters, provide a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB parameter group, specify the <code>DBParameterGroup</code> name and <code>ResetAllParameters</code> parameters. When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <code>RebootDBInstance</code> request.</p>
+    ///
+    /// See `Neptune::reset_db_parameter_group`.
+    pub fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.reset_db_parameter_group(input))
+    }
+
+    /// <p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot.</p> <p>If a DB snapshot is specified, the target DB cluster is created from the source DB snapshot with a default configuration and default security group.</p> <p>If a DB cluster snapshot is specified, the target DB cluster is created from the source DB cluster restore point with the same configuration as the original source DB cluster, except that the new DB cluster is created with the default security group.</p>
+    ///
+    /// See `Neptune::restore_db_cluster_from_snapshot`.
+    pub fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_snapshot(input))
+    }
+
+    /// <p><p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group.</p> <note> <p>This action only restores the DB cluster, not the DB instances for that DB cluster. You must invoke the <a>CreateDBInstance</a> action to create DB instances for the restored DB cluster, specifying the identifier of the restored DB cluster in <code>DBClusterIdentifier</code>. You can create DB instances only after the <code>RestoreDBClusterToPointInTime</code> action has completed and the DB cluster is available.</p> </note></p>
+    ///
+    /// See `Neptune::restore_db_cluster_to_point_in_time`.
+    pub fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_to_point_in_time(input))
+    }
+
+    /// <p>Starts an Amazon Neptune DB cluster that was stopped using the AWS console, the AWS CLI stop-db-cluster command, or the StopDBCluster API.</p>
+    ///
+    /// See `Neptune::start_db_cluster`.
+    pub fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.runtime.block_on(self.client.start_db_cluster(input))
+    }
+
+    /// <p>Stops an Amazon Neptune DB cluster. When you stop a DB cluster, Neptune retains the DB cluster's metadata, including its endpoints and DB parameter groups.</p> <p>Neptune also retains the transaction logs so you can do a point-in-time restore if necessary.</p>
+    ///
+    /// See `Neptune::stop_db_cluster`.
+    pub fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.runtime.block_on(self.client.stop_db_cluster(input))
+    }
+}
@@ -1131,30 +1131,40 @@ impl AssociateCustomerGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCustomerGatewayError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCustomerGatewayError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCustomerGatewayError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCustomerGatewayError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateCustomerGatewayError::ServiceQuotaExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateCustomerGatewayError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1201,22 +1211,28 @@ impl AssociateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AssociateLinkError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateLinkError::Conflict(err.msg))
+                    return RusotoError::service(&res, AssociateLinkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(&res, AssociateLinkError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateLinkError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(AssociateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateLinkError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateLinkError::Throttling(err.msg))
+                    return RusotoError::service(&res, AssociateLinkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1261,22 +1277,25 @@ impl CreateDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDeviceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeviceError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDeviceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeviceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateDeviceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateDeviceError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateDeviceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeviceError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeviceError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDeviceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1319,21 +1338,31 @@ impl CreateGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalNetworkError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateGlobalNetworkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalNetworkError::InternalServer(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalNetworkError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGlobalNetworkError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1377,22 +1406,25 @@ impl CreateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateLinkError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateLinkError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateLinkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateLinkError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateLinkError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLinkError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateLinkError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateLinkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1437,22 +1469,25 @@ impl CreateSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateSiteError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSiteError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateSiteError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSiteError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateSiteError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateSiteError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateSiteError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSiteError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSiteError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateSiteError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1495,19 +1530,19 @@ impl DeleteDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDeviceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDeviceError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteDeviceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDeviceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteDeviceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDeviceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDeviceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDeviceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1549,21 +1584,31 @@ impl DeleteGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGlobalNetworkError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteGlobalNetworkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGlobalNetworkError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGlobalNetworkError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGlobalNetworkError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1605,19 +1650,19 @@ impl DeleteLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteLinkError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteLinkError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteLinkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLinkError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteLinkError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteLinkError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteLinkError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteLinkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1659,19 +1704,19 @@ impl DeleteSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSiteError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSiteError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSiteError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1713,25 +1758,34 @@ impl DeregisterTransitGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTransitGatewayError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTransitGatewayError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTransitGatewayError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTransitGatewayError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterTransitGatewayError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1771,20 +1825,28 @@ impl DescribeGlobalNetworksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGlobalNetworksError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGlobalNetworksError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGlobalNetworksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGlobalNetworksError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1827,29 +1889,34 @@ impl DisassociateCustomerGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCustomerGatewayError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCustomerGatewayError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCustomerGatewayError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateCustomerGatewayError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateCustomerGatewayError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1891,19 +1958,25 @@ impl DisassociateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DisassociateLinkError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateLinkError::Conflict(err.msg))
+                    return RusotoError::service(&res, DisassociateLinkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateLinkError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateLinkError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateLinkError::Throttling(err.msg))
+                    return RusotoError::service(&res, DisassociateLinkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1947,29 +2020,34 @@ impl GetCustomerGatewayAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCustomerGatewayAssociationsError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCustomerGatewayAssociationsError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCustomerGatewayAssociationsError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCustomerGatewayAssociationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCustomerGatewayAssociationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2013,16 +2091,16 @@ impl GetDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDevicesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetDevicesError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDevicesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetDevicesError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetDevicesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDevicesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetDevicesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2061,18 +2139,28 @@ impl GetLinkAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAssociationsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAssociationsError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAssociationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLinkAssociationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2111,16 +2199,16 @@ impl GetLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinksError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetLinksError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLinksError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetLinksError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetLinksError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLinksError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetLinksError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2159,16 +2247,16 @@ impl GetSitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSitesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetSitesError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetSitesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetSitesError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSitesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetSitesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSitesError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetSitesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2209,24 +2297,28 @@ impl GetTransitGatewayRegistrationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTransitGatewayRegistrationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTransitGatewayRegistrationsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTransitGatewayRegistrationsError::InternalServer(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTransitGatewayRegistrationsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTransitGatewayRegistrationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTransitGatewayRegistrationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2269,18 +2361,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2321,23 +2423,34 @@ impl RegisterTransitGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterTransitGatewayError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterTransitGatewayError::Conflict(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterTransitGatewayError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterTransitGatewayError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterTransitGatewayError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2381,22 +2494,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(TagResourceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2439,19 +2555,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2493,19 +2612,19 @@ impl UpdateDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDeviceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDeviceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDeviceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDeviceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2547,21 +2666,31 @@ impl UpdateGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalNetworkError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateGlobalNetworkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalNetworkError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalNetworkError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGlobalNetworkError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2605,22 +2734,25 @@ impl UpdateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateLinkError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateLinkError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateLinkError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLinkError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateLinkError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateLinkError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(UpdateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateLinkError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateLinkError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateLinkError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2663,19 +2795,19 @@ impl UpdateSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateSiteError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateSiteError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateSiteError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateSiteError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UpdateSiteError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateSiteError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSiteError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateSiteError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2892,6 +3024,7 @@ impl NetworkManagerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2907,6 +3040,7 @@ impl NetworkManagerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> NetworkManagerClient {
         NetworkManagerClient { client, region }
     }
@@ -2926,6 +3060,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("AssociateCustomerGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2960,6 +3097,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("AssociateLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2994,6 +3134,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("CreateDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3025,6 +3168,9 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("CreateGlobalNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3059,6 +3205,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("CreateLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3093,6 +3242,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("CreateSite");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3129,6 +3281,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DeleteDevice");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3161,6 +3316,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DeleteGlobalNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3194,6 +3352,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DeleteLink");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3227,6 +3388,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DeleteSite");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3256,6 +3420,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DeregisterTransitGateway");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3284,6 +3451,9 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DescribeGlobalNetworks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3328,6 +3498,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DisassociateCustomerGateway");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3360,6 +3533,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("DisassociateLink");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3399,6 +3575,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("GetCustomerGatewayAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3444,6 +3623,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("GetDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3492,6 +3674,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("GetLinkAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3538,6 +3723,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("GetLinks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3592,6 +3780,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("GetSites");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3640,6 +3831,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("GetTransitGatewayRegistrations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3682,6 +3876,9 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3713,6 +3910,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("RegisterTransitGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3744,6 +3944,9 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3776,6 +3979,9 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3814,6 +4020,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("UpdateDevice");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3848,6 +4057,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("UpdateGlobalNetwork");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3883,6 +4095,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("UpdateLink");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3918,6 +4133,9 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation_name("UpdateSite");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3940,3 +4158,352 @@ impl NetworkManager for NetworkManagerClient {
         }
     }
 }
+
+/// A blocking client for the NetworkManager API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `NetworkManagerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct NetworkManagerBlockingClient {
+    client: NetworkManagerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl NetworkManagerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> NetworkManagerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        NetworkManagerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> NetworkManagerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        NetworkManagerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient {
+            client: NetworkManagerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates a customer gateway with a device and optionally, with a link. If you specify a link, it must be associated with the specified device. </p> <p>You can only associate customer gateways that are connected to a VPN attachment on a transit gateway. The transit gateway must be registered in your global network. When you register a transit gateway, customer gateways that are connected to the transit gateway are automatically included in the global network. To list customer gateways that are connected to a transit gateway, use the <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeVpnConnections.html">DescribeVpnConnections</a> EC2 API and filter by <code>transit-gateway-id</code>.</p> <p>You cannot associate a customer gateway with more than one device and link. </p>
+    ///
+    /// See `NetworkManager::associate_customer_gateway`.
+    pub fn associate_customer_gateway(
+        &self,
+        input: AssociateCustomerGatewayRequest,
+    ) -> Result<AssociateCustomerGatewayResponse, RusotoError<AssociateCustomerGatewayError>> {
+        self.runtime
+            .block_on(self.client.associate_customer_gateway(input))
+    }
+
+    /// <p>Associates a link to a device. A device can be associated to multiple links and a link can be associated to multiple devices. The device and link must be in the same global network and the same site.</p>
+    ///
+    /// See `NetworkManager::associate_link`.
+    pub fn associate_link(
+        &self,
+        input: AssociateLinkRequest,
+    ) -> Result<AssociateLinkResponse, RusotoError<AssociateLinkError>> {
+        self.runtime.block_on(self.client.associate_link(input))
+    }
+
+    /// <p>Creates a new device in a global network. If you specify both a site ID and a location, the location of the site is used for visualization in the Network Manager console.</p>
+    ///
+    /// See `NetworkManager::create_device`.
+    pub fn create_device(
+        &self,
+        input: CreateDeviceRequest,
+    ) -> Result<CreateDeviceResponse, RusotoError<CreateDeviceError>> {
+        self.runtime.block_on(self.client.create_device(input))
+    }
+
+    /// <p>Creates a new, empty global network.</p>
+    ///
+    /// See `NetworkManager::create_global_network`.
+    pub fn create_global_network(
+        &self,
+        input: CreateGlobalNetworkRequest,
+    ) -> Result<CreateGlobalNetworkResponse, RusotoError<CreateGlobalNetworkError>> {
+        self.runtime
+            .block_on(self.client.create_global_network(input))
+    }
+
+    /// <p>Creates a new link for a specified site.</p>
+    ///
+    /// See `NetworkManager::create_link`.
+    pub fn create_link(
+        &self,
+        input: CreateLinkRequest,
+    ) -> Result<CreateLinkResponse, RusotoError<CreateLinkError>> {
+        self.runtime.block_on(self.client.create_link(input))
+    }
+
+    /// <p>Creates a new site in a global network.</p>
+    ///
+    /// See `NetworkManager::create_site`.
+    pub fn create_site(
+        &self,
+        input: CreateSiteRequest,
+    ) -> Result<CreateSiteResponse, RusotoError<CreateSiteError>> {
+        self.runtime.block_on(self.client.create_site(input))
+    }
+
+    /// <p>Deletes an existing device. You must first disassociate the device from any links and customer gateways.</p>
+    ///
+    /// See `NetworkManager::delete_device`.
+    pub fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+        self.runtime.block_on(self.client.delete_device(input))
+    }
+
+    /// <p>Deletes an existing global network. You must first delete all global network objects (devices, links, and sites) and deregister all transit gateways.</p>
+    ///
+    /// See `NetworkManager::delete_global_network`.
+    pub fn delete_global_network(
+        &self,
+        input: DeleteGlobalNetworkRequest,
+    ) -> Result<DeleteGlobalNetworkResponse, RusotoError<DeleteGlobalNetworkError>> {
+        self.runtime
+            .block_on(self.client.delete_global_network(input))
+    }
+
+    /// <p>Deletes an existing link. You must first disassociate the link from any devices and customer gateways.</p>
+    ///
+    /// See `NetworkManager::delete_link`.
+    pub fn delete_link(
+        &self,
+        input: DeleteLinkRequest,
+    ) -> Result<DeleteLinkResponse, RusotoError<DeleteLinkError>> {
+        self.runtime.block_on(self.client.delete_link(input))
+    }
+
+    /// <p>Deletes an existing site. The site cannot be associated with any device or link.</p>
+    ///
+    /// See `NetworkManager::delete_site`.
+    pub fn delete_site(
+        &self,
+        input: DeleteSiteRequest,
+    ) -> Result<DeleteSiteResponse, RusotoError<DeleteSiteError>> {
+        self.runtime.block_on(self.client.delete_site(input))
+    }
+
+    /// <p>Deregisters a transit gateway from your global network. This action does not delete your transit gateway, or modify any of its attachments. This action removes any customer gateway associations.</p>
+    ///
+    /// See `NetworkManager::deregister_transit_gateway`.
+    pub fn deregister_transit_gateway(
+        &self,
+        input: DeregisterTransitGatewayRequest,
+    ) -> Result<DeregisterTransitGatewayResponse, RusotoError<DeregisterTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.deregister_transit_gateway(input))
+    }
+
+    /// <p>Describes one or more global networks. By default, all global networks are described. To describe the objects in your global network, you must use the appropriate <code>Get*</code> action. For example, to list the transit gateways in your global network, use <a>GetTransitGatewayRegistrations</a>.</p>
+    ///
+    /// See `NetworkManager::describe_global_networks`.
+    pub fn describe_global_networks(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> Result<DescribeGlobalNetworksResponse, RusotoError<DescribeGlobalNetworksError>> {
+        self.runtime
+            .block_on(self.client.describe_global_networks(input))
+    }
+
+    /// <p>Disassociates a customer gateway from a device and a link.</p>
+    ///
+    /// See `NetworkManager::disassociate_customer_gateway`.
+    pub fn disassociate_customer_gateway(
+        &self,
+        input: DisassociateCustomerGatewayRequest,
+    ) -> Result<DisassociateCustomerGatewayResponse, RusotoError<DisassociateCustomerGatewayError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_customer_gateway(input))
+    }
+
+    /// <p>Disassociates an existing device from a link. You must first disassociate any customer gateways that are associated with the link.</p>
+    ///
+    /// See `NetworkManager::disassociate_link`.
+    pub fn disassociate_link(
+        &self,
+        input: DisassociateLinkRequest,
+    ) -> Result<DisassociateLinkResponse, RusotoError<DisassociateLinkError>> {
+        self.runtime.block_on(self.client.disassociate_link(input))
+    }
+
+    /// <p>Gets the association information for customer gateways that are associated with devices and links in your global network.</p>
+    ///
+    /// See `NetworkManager::get_customer_gateway_associations`.
+    pub fn get_customer_gateway_associations(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> Result<
+        GetCustomerGatewayAssociationsResponse,
+        RusotoError<GetCustomerGatewayAssociationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_customer_gateway_associations(input))
+    }
+
+    /// <p>Gets information about one or more of your devices in a global network.</p>
+    ///
+    /// See `NetworkManager::get_devices`.
+    pub fn get_devices(
+        &self,
+        input: GetDevicesRequest,
+    ) -> Result<GetDevicesResponse, RusotoError<GetDevicesError>> {
+        self.runtime.block_on(self.client.get_devices(input))
+    }
+
+    /// <p>Gets the link associations for a device or a link. Either the device ID or the link ID must be specified.</p>
+    ///
+    /// See `NetworkManager::get_link_associations`.
+    pub fn get_link_associations(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> Result<GetLinkAssociationsResponse, RusotoError<GetLinkAssociationsError>> {
+        self.runtime
+            .block_on(self.client.get_link_associations(input))
+    }
+
+    /// <p>Gets information about one or more links in a specified global network.</p> <p>If you specify the site ID, you cannot specify the type or provider in the same request. You can specify the type and provider in the same request.</p>
+    ///
+    /// See `NetworkManager::get_links`.
+    pub fn get_links(
+        &self,
+        input: GetLinksRequest,
+    ) -> Result<GetLinksResponse, RusotoError<GetLinksError>> {
+        self.runtime.block_on(self.client.get_links(input))
+    }
+
+    /// <p>Gets information about one or more of your sites in a global network.</p>
+    ///
+    /// See `NetworkManager::get_sites`.
+    pub fn get_sites(
+        &self,
+        input: GetSitesRequest,
+    ) -> Result<GetSitesResponse, RusotoError<GetSitesError>> {
+        self.runtime.block_on(self.client.get_sites(input))
+    }
+
+    /// <p>Gets information about the transit gateway registrations in a specified global network.</p>
+    ///
+    /// See `NetworkManager::get_transit_gateway_registrations`.
+    pub fn get_transit_gateway_registrations(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> Result<
+        GetTransitGatewayRegistrationsResponse,
+        RusotoError<GetTransitGatewayRegistrationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_transit_gateway_registrations(input))
+    }
+
+    /// <p>Lists the tags for a specified resource.</p>
+    ///
+    /// See `NetworkManager::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Registers a transit gateway in your global network. The transit gateway can be in any AWS Region, but it must be owned by the same AWS account that owns the global network. You cannot register a transit gateway in more than one global network.</p>
+    ///
+    /// See `NetworkManager::register_transit_gateway`.
+    pub fn register_transit_gateway(
+        &self,
+        input: RegisterTransitGatewayRequest,
+    ) -> Result<RegisterTransitGatewayResponse, RusotoError<RegisterTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.register_transit_gateway(input))
+    }
+
+    /// <p>Tags a specified resource.</p>
+    ///
+    /// See `NetworkManager::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from a specified resource.</p>
+    ///
+    /// See `NetworkManager::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the details for an existing device. To remove information for any of the parameters, specify an empty string.</p>
+    ///
+    /// See `NetworkManager::update_device`.
+    pub fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+        self.runtime.block_on(self.client.update_device(input))
+    }
+
+    /// <p>Updates an existing global network. To remove information for any of the parameters, specify an empty string.</p>
+    ///
+    /// See `NetworkManager::update_global_network`.
+    pub fn update_global_network(
+        &self,
+        input: UpdateGlobalNetworkRequest,
+    ) -> Result<UpdateGlobalNetworkResponse, RusotoError<UpdateGlobalNetworkError>> {
+        self.runtime
+            .block_on(self.client.update_global_network(input))
+    }
+
+    /// <p>Updates the details for an existing link. To remove information for any of the parameters, specify an empty string.</p>
+    ///
+    /// See `NetworkManager::update_link`.
+    pub fn update_link(
+        &self,
+        input: UpdateLinkRequest,
+    ) -> Result<UpdateLinkResponse, RusotoError<UpdateLinkError>> {
+        self.runtime.block_on(self.client.update_link(input))
+    }
+
+    /// <p>Updates the information for an existing site. To remove information for any of the parameters, specify an empty string.</p>
+    ///
+    /// See `NetworkManager::update_site`.
+    pub fn update_site(
+        &self,
+        input: UpdateSiteRequest,
+    ) -> Result<UpdateSiteResponse, RusotoError<UpdateSiteError>> {
+        self.runtime.block_on(self.client.update_site(input))
+    }
+}
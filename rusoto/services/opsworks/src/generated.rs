@@ -3368,7 +3368,10 @@ impl AssignInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssignInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssignInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3398,7 +3401,7 @@ impl AssignVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssignVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, AssignVolumeError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3428,7 +3431,10 @@ impl AssociateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateElasticIpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3458,9 +3464,10 @@ impl AttachElasticLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachElasticLoadBalancerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachElasticLoadBalancerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3490,7 +3497,7 @@ impl CloneStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CloneStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CloneStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3520,7 +3527,7 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateAppError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3550,7 +3557,10 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3580,7 +3590,10 @@ impl CreateInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3610,7 +3623,7 @@ impl CreateLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateLayerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3684,7 +3697,7 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3714,7 +3727,10 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3744,7 +3760,7 @@ impl DeleteLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteLayerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3774,7 +3790,7 @@ impl DeleteStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3804,7 +3820,10 @@ impl DeleteUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3834,9 +3853,10 @@ impl DeregisterEcsClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterEcsClusterError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterEcsClusterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3866,9 +3886,10 @@ impl DeregisterElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterElasticIpError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterElasticIpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3898,7 +3919,10 @@ impl DeregisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3928,9 +3952,10 @@ impl DeregisterRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterRdsDbInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterRdsDbInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3960,7 +3985,10 @@ impl DeregisterVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterVolumeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3990,9 +4018,10 @@ impl DescribeAgentVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAgentVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAgentVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4022,7 +4051,7 @@ impl DescribeAppsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAppsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeAppsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4052,7 +4081,10 @@ impl DescribeCommandsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCommandsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommandsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4082,9 +4114,10 @@ impl DescribeDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeploymentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeploymentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4114,9 +4147,10 @@ impl DescribeEcsClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEcsClustersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEcsClustersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4146,7 +4180,10 @@ impl DescribeElasticIpsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeElasticIpsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeElasticIpsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4178,7 +4215,8 @@ impl DescribeElasticLoadBalancersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeElasticLoadBalancersError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4212,7 +4250,10 @@ impl DescribeInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInstancesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4242,7 +4283,10 @@ impl DescribeLayersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLayersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLayersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4274,7 +4318,8 @@ impl DescribeLoadBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeLoadBasedAutoScalingError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4352,9 +4397,10 @@ impl DescribePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4384,7 +4430,10 @@ impl DescribeRaidArraysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRaidArraysError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRaidArraysError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4414,9 +4463,10 @@ impl DescribeRdsDbInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRdsDbInstancesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRdsDbInstancesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4446,9 +4496,10 @@ impl DescribeServiceErrorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServiceErrorsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServiceErrorsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4480,7 +4531,8 @@ impl DescribeStackProvisioningParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeStackProvisioningParametersError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4514,9 +4566,10 @@ impl DescribeStackSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStackSummaryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStackSummaryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4546,7 +4599,10 @@ impl DescribeStacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStacksError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4578,7 +4634,8 @@ impl DescribeTimeBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTimeBasedAutoScalingError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4612,9 +4669,10 @@ impl DescribeUserProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserProfilesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserProfilesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4644,7 +4702,10 @@ impl DescribeVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeVolumesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVolumesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4674,9 +4735,10 @@ impl DetachElasticLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachElasticLoadBalancerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachElasticLoadBalancerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4706,9 +4768,10 @@ impl DisassociateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateElasticIpError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateElasticIpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4738,9 +4801,10 @@ impl GetHostnameSuggestionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetHostnameSuggestionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetHostnameSuggestionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4770,7 +4834,7 @@ impl GrantAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GrantAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GrantAccessError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4800,7 +4864,7 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4830,7 +4894,10 @@ impl RebootInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RebootInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RebootInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4860,7 +4927,10 @@ impl RegisterEcsClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterEcsClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterEcsClusterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4890,7 +4960,10 @@ impl RegisterElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterElasticIpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4920,7 +4993,10 @@ impl RegisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4950,9 +5026,10 @@ impl RegisterRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterRdsDbInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterRdsDbInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4982,7 +5059,10 @@ impl RegisterVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterVolumeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5012,9 +5092,10 @@ impl SetLoadBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetLoadBasedAutoScalingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetLoadBasedAutoScalingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5044,7 +5125,10 @@ impl SetPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SetPermissionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5074,9 +5158,10 @@ impl SetTimeBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetTimeBasedAutoScalingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetTimeBasedAutoScalingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5106,7 +5191,10 @@ impl StartInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5136,7 +5224,7 @@ impl StartStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5166,7 +5254,7 @@ impl StopInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopInstanceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5196,7 +5284,7 @@ impl StopStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5226,7 +5314,7 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5256,7 +5344,10 @@ impl UnassignInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnassignInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnassignInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5286,7 +5377,10 @@ impl UnassignVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnassignVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnassignVolumeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5316,7 +5410,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5346,7 +5443,7 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5376,7 +5473,10 @@ impl UpdateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateElasticIpError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5406,7 +5506,10 @@ impl UpdateInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5436,7 +5539,7 @@ impl UpdateLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateLayerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5488,9 +5591,10 @@ impl UpdateRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRdsDbInstanceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRdsDbInstanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5520,7 +5624,7 @@ impl UpdateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateStackError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5550,7 +5654,10 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5580,7 +5687,7 @@ impl UpdateVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVolumeError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6055,6 +6162,7 @@ impl OpsWorksClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6070,6 +6178,7 @@ impl OpsWorksClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksClient {
         OpsWorksClient { client, region }
     }
@@ -6083,6 +6192,9 @@ impl OpsWorks for OpsWorksClient {
         input: AssignInstanceRequest,
     ) -> Result<(), RusotoError<AssignInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssignInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6100,6 +6212,9 @@ impl OpsWorks for OpsWorksClient {
         input: AssignVolumeRequest,
     ) -> Result<(), RusotoError<AssignVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssignVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6117,6 +6232,9 @@ impl OpsWorks for OpsWorksClient {
         input: AssociateElasticIpRequest,
     ) -> Result<(), RusotoError<AssociateElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateElasticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.AssociateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6134,6 +6252,9 @@ impl OpsWorks for OpsWorksClient {
         input: AttachElasticLoadBalancerRequest,
     ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachElasticLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.AttachElasticLoadBalancer",
@@ -6154,6 +6275,9 @@ impl OpsWorks for OpsWorksClient {
         input: CloneStackRequest,
     ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CloneStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CloneStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6172,6 +6296,9 @@ impl OpsWorks for OpsWorksClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6190,6 +6317,9 @@ impl OpsWorks for OpsWorksClient {
         input: CreateDeploymentRequest,
     ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDeployment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6208,6 +6338,9 @@ impl OpsWorks for OpsWorksClient {
         input: CreateInstanceRequest,
     ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6226,6 +6359,9 @@ impl OpsWorks for OpsWorksClient {
         input: CreateLayerRequest,
     ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLayer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6244,6 +6380,9 @@ impl OpsWorks for OpsWorksClient {
         input: CreateStackRequest,
     ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6262,6 +6401,9 @@ impl OpsWorks for OpsWorksClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6277,6 +6419,9 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6294,6 +6439,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteInstanceRequest,
     ) -> Result<(), RusotoError<DeleteInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6311,6 +6459,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteLayerRequest,
     ) -> Result<(), RusotoError<DeleteLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLayer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6328,6 +6479,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteStackRequest,
     ) -> Result<(), RusotoError<DeleteStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6345,6 +6499,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteUserProfileRequest,
     ) -> Result<(), RusotoError<DeleteUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6362,6 +6519,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterEcsClusterRequest,
     ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterEcsCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterEcsCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6379,6 +6539,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterElasticIpRequest,
     ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterElasticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6396,6 +6559,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterInstanceRequest,
     ) -> Result<(), RusotoError<DeregisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6413,6 +6579,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterRdsDbInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6430,6 +6599,9 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterVolumeRequest,
     ) -> Result<(), RusotoError<DeregisterVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6447,6 +6619,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeAgentVersionsRequest,
     ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAgentVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeAgentVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6465,6 +6640,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeAppsRequest,
     ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeApps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6483,6 +6661,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeCommandsRequest,
     ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCommands");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeCommands");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6501,6 +6682,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeDeploymentsRequest,
     ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDeployments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6519,6 +6703,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeEcsClustersRequest,
     ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEcsClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeEcsClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6537,6 +6724,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeElasticIpsRequest,
     ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeElasticIps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeElasticIps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6556,6 +6746,9 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeElasticLoadBalancers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeElasticLoadBalancers",
@@ -6578,6 +6771,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeInstancesRequest,
     ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6596,6 +6792,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeLayersRequest,
     ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLayers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeLayers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6615,6 +6814,9 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLoadBasedAutoScaling");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeLoadBasedAutoScaling",
@@ -6636,6 +6838,9 @@ impl OpsWorks for OpsWorksClient {
         &self,
     ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMyUserProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeMyUserProfile");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6652,6 +6857,9 @@ impl OpsWorks for OpsWorksClient {
         &self,
     ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOperatingSystems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeOperatingSystems");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6670,6 +6878,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribePermissionsRequest,
     ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6688,6 +6899,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeRaidArraysRequest,
     ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRaidArrays");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRaidArrays");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6706,6 +6920,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeRdsDbInstancesRequest,
     ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRdsDbInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRdsDbInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6725,6 +6942,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeServiceErrorsRequest,
     ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServiceErrors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeServiceErrors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6746,6 +6966,9 @@ impl OpsWorks for OpsWorksClient {
         RusotoError<DescribeStackProvisioningParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStackProvisioningParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeStackProvisioningParameters",
@@ -6771,6 +6994,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeStackSummaryRequest,
     ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStackSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStackSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6789,6 +7015,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeStacksRequest,
     ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6808,6 +7037,9 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTimeBasedAutoScaling");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeTimeBasedAutoScaling",
@@ -6830,6 +7062,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeUserProfilesRequest,
     ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6848,6 +7083,9 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeVolumesRequest,
     ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeVolumes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeVolumes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6866,6 +7104,9 @@ impl OpsWorks for OpsWorksClient {
         input: DetachElasticLoadBalancerRequest,
     ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetachElasticLoadBalancer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DetachElasticLoadBalancer",
@@ -6886,6 +7127,9 @@ impl OpsWorks for OpsWorksClient {
         input: DisassociateElasticIpRequest,
     ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateElasticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.DisassociateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6903,6 +7147,9 @@ impl OpsWorks for OpsWorksClient {
         input: GetHostnameSuggestionRequest,
     ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetHostnameSuggestion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.GetHostnameSuggestion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6921,6 +7168,9 @@ impl OpsWorks for OpsWorksClient {
         input: GrantAccessRequest,
     ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GrantAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.GrantAccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6939,6 +7189,9 @@ impl OpsWorks for OpsWorksClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorks_20130218.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6957,6 +7210,9 @@ impl OpsWorks for OpsWorksClient {
         input: RebootInstanceRequest,
     ) -> Result<(), RusotoError<RebootInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebootInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.RebootInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6974,6 +7230,9 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterEcsClusterRequest,
     ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterEcsCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterEcsCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6992,6 +7251,9 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterElasticIpRequest,
     ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterElasticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7010,6 +7272,9 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7028,6 +7293,9 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterRdsDbInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7045,6 +7313,9 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterVolumeRequest,
     ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7063,6 +7334,9 @@ impl OpsWorks for OpsWorksClient {
         input: SetLoadBasedAutoScalingRequest,
     ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetLoadBasedAutoScaling");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.SetLoadBasedAutoScaling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7080,6 +7354,9 @@ impl OpsWorks for OpsWorksClient {
         input: SetPermissionRequest,
     ) -> Result<(), RusotoError<SetPermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.SetPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7097,6 +7374,9 @@ impl OpsWorks for OpsWorksClient {
         input: SetTimeBasedAutoScalingRequest,
     ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetTimeBasedAutoScaling");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.SetTimeBasedAutoScaling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7114,6 +7394,9 @@ impl OpsWorks for OpsWorksClient {
         input: StartInstanceRequest,
     ) -> Result<(), RusotoError<StartInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.StartInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7131,6 +7414,9 @@ impl OpsWorks for OpsWorksClient {
         input: StartStackRequest,
     ) -> Result<(), RusotoError<StartStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.StartStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7148,6 +7434,9 @@ impl OpsWorks for OpsWorksClient {
         input: StopInstanceRequest,
     ) -> Result<(), RusotoError<StopInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.StopInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7162,6 +7451,9 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.StopStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7179,6 +7471,9 @@ impl OpsWorks for OpsWorksClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7196,6 +7491,9 @@ impl OpsWorks for OpsWorksClient {
         input: UnassignInstanceRequest,
     ) -> Result<(), RusotoError<UnassignInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnassignInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7213,6 +7511,9 @@ impl OpsWorks for OpsWorksClient {
         input: UnassignVolumeRequest,
     ) -> Result<(), RusotoError<UnassignVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UnassignVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7230,6 +7531,9 @@ impl OpsWorks for OpsWorksClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7244,6 +7548,9 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7261,6 +7568,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateElasticIpRequest,
     ) -> Result<(), RusotoError<UpdateElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateElasticIp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7278,6 +7588,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateInstanceRequest,
     ) -> Result<(), RusotoError<UpdateInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7295,6 +7608,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateLayerRequest,
     ) -> Result<(), RusotoError<UpdateLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateLayer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7312,6 +7628,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateMyUserProfileRequest,
     ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMyUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateMyUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7329,6 +7648,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRdsDbInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7346,6 +7668,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateStackRequest,
     ) -> Result<(), RusotoError<UpdateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7363,6 +7688,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateUserProfileRequest,
     ) -> Result<(), RusotoError<UpdateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7380,6 +7708,9 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateVolumeRequest,
     ) -> Result<(), RusotoError<UpdateVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7391,3 +7722,823 @@ impl OpsWorks for OpsWorksClient {
         Ok(())
     }
 }
+
+/// A blocking client for the AWS OpsWorks API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `OpsWorksClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OpsWorksBlockingClient {
+    client: OpsWorksClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OpsWorksBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OpsWorksBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        OpsWorksBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OpsWorksBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OpsWorksBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient {
+            client: OpsWorksClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Assign a registered instance to a layer.</p> <ul> <li> <p>You can assign registered on-premises instances to any layer type.</p> </li> <li> <p>You can assign registered Amazon EC2 instances only to custom layers.</p> </li> <li> <p>You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> </li> </ul> <p> <b>Required Permissions</b>: To use this action, an AWS Identity and Access Management (IAM) user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::assign_instance`.
+    pub fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> Result<(), RusotoError<AssignInstanceError>> {
+        self.runtime.block_on(self.client.assign_instance(input))
+    }
+
+    /// <p>Assigns one of the stack's registered Amazon EBS volumes to a specified instance. The volume must first be registered with the stack by calling <a>RegisterVolume</a>. After you register the volume, you must call <a>UpdateVolume</a> to specify a mount point before calling <code>AssignVolume</code>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::assign_volume`.
+    pub fn assign_volume(
+        &self,
+        input: AssignVolumeRequest,
+    ) -> Result<(), RusotoError<AssignVolumeError>> {
+        self.runtime.block_on(self.client.assign_volume(input))
+    }
+
+    /// <p>Associates one of the stack's registered Elastic IP addresses with a specified instance. The address must first be registered with the stack by calling <a>RegisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::associate_elastic_ip`.
+    pub fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> Result<(), RusotoError<AssociateElasticIpError>> {
+        self.runtime
+            .block_on(self.client.associate_elastic_ip(input))
+    }
+
+    /// <p>Attaches an Elastic Load Balancing load balancer to a specified layer. AWS OpsWorks Stacks does not support Application Load Balancer. You can only use Classic Load Balancer with AWS OpsWorks Stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/layers-elb.html">Elastic Load Balancing</a>.</p> <note> <p>You must create the Elastic Load Balancing instance separately, by using the Elastic Load Balancing console, API, or CLI. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/Welcome.html"> Elastic Load Balancing Developer Guide</a>.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::attach_elastic_load_balancer`.
+    pub fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.attach_elastic_load_balancer(input))
+    }
+
+    /// <p>Creates a clone of a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-cloning.html">Clone a Stack</a>. By default, all parameters are set to the values used by the parent stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::clone_stack`.
+    pub fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
+        self.runtime.block_on(self.client.clone_stack(input))
+    }
+
+    /// <p>Creates an app for a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-creating.html">Creating Apps</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::create_app`.
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// <p>Runs deployment or stack commands. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-deploying.html">Deploying Apps</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-commands.html">Run Stack Commands</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::create_deployment`.
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// <p>Creates an instance in a specified stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html">Adding an Instance to a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::create_instance`.
+    pub fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
+        self.runtime.block_on(self.client.create_instance(input))
+    }
+
+    /// <p>Creates a layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-create.html">How to Create a Layer</a>.</p> <note> <p>You should use <b>CreateLayer</b> for noncustom layer types such as PHP App Server only if the stack does not have an existing layer of that type. A stack can have at most one instance of each noncustom layer; if you attempt to create a second instance, <b>CreateLayer</b> fails. A stack can have an arbitrary number of custom layers, so you can call <b>CreateLayer</b> as many times as you like for that layer type.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::create_layer`.
+    pub fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
+        self.runtime.block_on(self.client.create_layer(input))
+    }
+
+    /// <p>Creates a new stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-edit.html">Create a New Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::create_stack`.
+    pub fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+        self.runtime.block_on(self.client.create_stack(input))
+    }
+
+    /// <p>Creates a new user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::create_user_profile`.
+    pub fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.create_user_profile(input))
+    }
+
+    /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::delete_app`.
+    pub fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// <p>Deletes a specified instance, which terminates the associated Amazon EC2 instance. You must stop an instance before you can delete it.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-delete.html">Deleting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::delete_instance`.
+    pub fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<(), RusotoError<DeleteInstanceError>> {
+        self.runtime.block_on(self.client.delete_instance(input))
+    }
+
+    /// <p>Deletes a specified layer. You must first stop and then delete all associated instances or unassign registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-delete.html">How to Delete a Layer</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::delete_layer`.
+    pub fn delete_layer(
+        &self,
+        input: DeleteLayerRequest,
+    ) -> Result<(), RusotoError<DeleteLayerError>> {
+        self.runtime.block_on(self.client.delete_layer(input))
+    }
+
+    /// <p>Deletes a specified stack. You must first delete all instances, layers, and apps or deregister registered instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-shutting.html">Shut Down a Stack</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::delete_stack`.
+    pub fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<(), RusotoError<DeleteStackError>> {
+        self.runtime.block_on(self.client.delete_stack(input))
+    }
+
+    /// <p>Deletes a user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::delete_user_profile`.
+    pub fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_user_profile(input))
+    }
+
+    /// <p>Deregisters a specified Amazon ECS cluster from a stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html#workinglayers-ecscluster-delete"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html</a>.</p>
+    ///
+    /// See `OpsWorks::deregister_ecs_cluster`.
+    pub fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
+        self.runtime
+            .block_on(self.client.deregister_ecs_cluster(input))
+    }
+
+    /// <p>Deregisters a specified Elastic IP address. The address can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::deregister_elastic_ip`.
+    pub fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
+        self.runtime
+            .block_on(self.client.deregister_elastic_ip(input))
+    }
+
+    /// <p>Deregister a registered Amazon EC2 or on-premises instance. This action removes the instance from the stack and returns it to your control. This action cannot be used with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::deregister_instance`.
+    pub fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<(), RusotoError<DeregisterInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_instance(input))
+    }
+
+    /// <p>Deregisters an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::deregister_rds_db_instance`.
+    pub fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_rds_db_instance(input))
+    }
+
+    /// <p>Deregisters an Amazon EBS volume. The volume can then be registered by another stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::deregister_volume`.
+    pub fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> Result<(), RusotoError<DeregisterVolumeError>> {
+        self.runtime.block_on(self.client.deregister_volume(input))
+    }
+
+    /// <p>Describes the available AWS OpsWorks Stacks agent versions. You must specify a stack ID or a configuration manager. <code>DescribeAgentVersions</code> returns a list of available agent versions for the specified stack or configuration manager.</p>
+    ///
+    /// See `OpsWorks::describe_agent_versions`.
+    pub fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_agent_versions(input))
+    }
+
+    /// <p>Requests a description of a specified set of apps.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_apps`.
+    pub fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
+        self.runtime.block_on(self.client.describe_apps(input))
+    }
+
+    /// <p>Describes the results of specified commands.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_commands`.
+    pub fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
+        self.runtime.block_on(self.client.describe_commands(input))
+    }
+
+    /// <p>Requests a description of a specified set of deployments.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_deployments`.
+    pub fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
+        self.runtime
+            .block_on(self.client.describe_deployments(input))
+    }
+
+    /// <p>Describes Amazon ECS clusters that are registered with a stack. If you specify only a stack ID, you can use the <code>MaxResults</code> and <code>NextToken</code> parameters to paginate the response. However, AWS OpsWorks Stacks currently supports only one cluster per layer, so the result set has a maximum of one element.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permission. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    ///
+    /// See `OpsWorks::describe_ecs_clusters`.
+    pub fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_ecs_clusters(input))
+    }
+
+    /// <p>Describes <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP addresses</a>.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_elastic_ips`.
+    pub fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
+        self.runtime
+            .block_on(self.client.describe_elastic_ips(input))
+    }
+
+    /// <p>Describes a stack's Elastic Load Balancing instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_elastic_load_balancers`.
+    pub fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_elastic_load_balancers(input))
+    }
+
+    /// <p>Requests a description of a set of instances.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_instances`.
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
+        self.runtime.block_on(self.client.describe_instances(input))
+    }
+
+    /// <p>Requests a description of one or more layers in a specified stack.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_layers`.
+    pub fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
+        self.runtime.block_on(self.client.describe_layers(input))
+    }
+
+    /// <p>Describes load-based auto scaling configurations for specified layers.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_load_based_auto_scaling`.
+    pub fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_load_based_auto_scaling(input))
+    }
+
+    /// <p>Describes a user's SSH information.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_my_user_profile`.
+    pub fn describe_my_user_profile(
+        &self,
+    ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_my_user_profile())
+    }
+
+    /// <p>Describes the operating systems that are supported by AWS OpsWorks Stacks.</p>
+    ///
+    /// See `OpsWorks::describe_operating_systems`.
+    pub fn describe_operating_systems(
+        &self,
+    ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
+        self.runtime
+            .block_on(self.client.describe_operating_systems())
+    }
+
+    /// <p>Describes the permissions for a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_permissions`.
+    pub fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
+        self.runtime
+            .block_on(self.client.describe_permissions(input))
+    }
+
+    /// <p>Describe an instance's RAID arrays.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_raid_arrays`.
+    pub fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
+        self.runtime
+            .block_on(self.client.describe_raid_arrays(input))
+    }
+
+    /// <p>Describes Amazon RDS instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    ///
+    /// See `OpsWorks::describe_rds_db_instances`.
+    pub fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_rds_db_instances(input))
+    }
+
+    /// <p>Describes AWS OpsWorks Stacks service errors.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p> <p>This call accepts only one resource-identifying parameter.</p>
+    ///
+    /// See `OpsWorks::describe_service_errors`.
+    pub fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
+        self.runtime
+            .block_on(self.client.describe_service_errors(input))
+    }
+
+    /// <p>Requests a description of a stack's provisioning parameters.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_stack_provisioning_parameters`.
+    pub fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> Result<
+        DescribeStackProvisioningParametersResult,
+        RusotoError<DescribeStackProvisioningParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_stack_provisioning_parameters(input))
+    }
+
+    /// <p>Describes the number of layers and apps in a specified stack, and the number of instances in each state, such as <code>running_setup</code> or <code>online</code>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_stack_summary`.
+    pub fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_summary(input))
+    }
+
+    /// <p>Requests a description of one or more stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_stacks`.
+    pub fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+        self.runtime.block_on(self.client.describe_stacks(input))
+    }
+
+    /// <p>Describes time-based auto scaling configurations for specified instances.</p> <note> <p>You must specify at least one of the parameters.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_time_based_auto_scaling`.
+    pub fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_time_based_auto_scaling(input))
+    }
+
+    /// <p>Describe specified users.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_user_profiles`.
+    pub fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
+        self.runtime
+            .block_on(self.client.describe_user_profiles(input))
+    }
+
+    /// <p>Describes an instance's Amazon EBS volumes.</p> <note> <p>This call accepts only one resource-identifying parameter.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Show, Deploy, or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::describe_volumes`.
+    pub fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
+        self.runtime.block_on(self.client.describe_volumes(input))
+    }
+
+    /// <p>Detaches a specified Elastic Load Balancing instance from its layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::detach_elastic_load_balancer`.
+    pub fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.detach_elastic_load_balancer(input))
+    }
+
+    /// <p>Disassociates an Elastic IP address from its instance. The address remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::disassociate_elastic_ip`.
+    pub fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
+        self.runtime
+            .block_on(self.client.disassociate_elastic_ip(input))
+    }
+
+    /// <p>Gets a generated host name for the specified layer, based on the current host name theme.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::get_hostname_suggestion`.
+    pub fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
+        self.runtime
+            .block_on(self.client.get_hostname_suggestion(input))
+    }
+
+    /// <p><note> <p>This action can be used only with Windows stacks.</p> </note> <p>Grants RDP access to a Windows instance for a specified time period.</p></p>
+    ///
+    /// See `OpsWorks::grant_access`.
+    pub fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
+        self.runtime.block_on(self.client.grant_access(input))
+    }
+
+    /// <p>Returns a list of tags that are applied to the specified stack or layer.</p>
+    ///
+    /// See `OpsWorks::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Reboots a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::reboot_instance`.
+    pub fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<(), RusotoError<RebootInstanceError>> {
+        self.runtime.block_on(self.client.reboot_instance(input))
+    }
+
+    /// <p>Registers a specified Amazon ECS cluster with a stack. You can register only one cluster with a stack. A cluster can be registered with only one stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-ecscluster.html"> Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html"> Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::register_ecs_cluster`.
+    pub fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
+        self.runtime
+            .block_on(self.client.register_ecs_cluster(input))
+    }
+
+    /// <p>Registers an Elastic IP address with a specified stack. An address can be registered with only one stack at a time. If the address is already registered, you must first deregister it by calling <a>DeregisterElasticIp</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::register_elastic_ip`.
+    pub fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
+        self.runtime
+            .block_on(self.client.register_elastic_ip(input))
+    }
+
+    /// <p>Registers instances that were created outside of AWS OpsWorks Stacks with a specified stack.</p> <note> <p>We do not recommend using this action to register instances. The complete registration operation includes two tasks: installing the AWS OpsWorks Stacks agent on the instance, and registering the instance with the stack. <code>RegisterInstance</code> handles only the second step. You should instead use the AWS CLI <code>register</code> command, which performs the entire registration operation. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register.html"> Registering an Instance with an AWS OpsWorks Stacks Stack</a>.</p> </note> <p>Registered instances have the same requirements as instances that are created by using the <a>CreateInstance</a> API. For example, registered instances must be running a supported Linux-based operating system, and they must have a supported instance type. For more information about requirements for instances that you want to register, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/registered-instances-register-registering-preparer.html"> Preparing the Instance</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::register_instance`.
+    pub fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
+        self.runtime.block_on(self.client.register_instance(input))
+    }
+
+    /// <p>Registers an Amazon RDS instance with a stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::register_rds_db_instance`.
+    pub fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
+        self.runtime
+            .block_on(self.client.register_rds_db_instance(input))
+    }
+
+    /// <p>Registers an Amazon EBS volume with a specified stack. A volume can be registered with only one stack at a time. If the volume is already registered, you must first deregister it by calling <a>DeregisterVolume</a>. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::register_volume`.
+    pub fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
+        self.runtime.block_on(self.client.register_volume(input))
+    }
+
+    /// <p>Specify the load-based auto scaling configuration for a specified layer. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <note> <p>To use load-based auto scaling, you must create a set of load-based auto scaling instances. Load-based auto scaling operates only on the instances from that set, so you must ensure that you have created enough instances to handle the maximum anticipated load.</p> </note> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::set_load_based_auto_scaling`.
+    pub fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
+        self.runtime
+            .block_on(self.client.set_load_based_auto_scaling(input))
+    }
+
+    /// <p>Specifies a user's permissions. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingsecurity.html">Security and Permissions</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::set_permission`.
+    pub fn set_permission(
+        &self,
+        input: SetPermissionRequest,
+    ) -> Result<(), RusotoError<SetPermissionError>> {
+        self.runtime.block_on(self.client.set_permission(input))
+    }
+
+    /// <p>Specify the time-based auto scaling configuration for a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-autoscaling.html">Managing Load with Time-based and Load-based Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::set_time_based_auto_scaling`.
+    pub fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
+        self.runtime
+            .block_on(self.client.set_time_based_auto_scaling(input))
+    }
+
+    /// <p>Starts a specified instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::start_instance`.
+    pub fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<(), RusotoError<StartInstanceError>> {
+        self.runtime.block_on(self.client.start_instance(input))
+    }
+
+    /// <p>Starts a stack's instances.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::start_stack`.
+    pub fn start_stack(
+        &self,
+        input: StartStackRequest,
+    ) -> Result<(), RusotoError<StartStackError>> {
+        self.runtime.block_on(self.client.start_stack(input))
+    }
+
+    /// <p>Stops a specified instance. When you stop a standard instance, the data disappears and must be reinstalled when you restart the instance. You can stop an Amazon EBS-backed instance without losing data. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-starting.html">Starting, Stopping, and Rebooting Instances</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::stop_instance`.
+    pub fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<(), RusotoError<StopInstanceError>> {
+        self.runtime.block_on(self.client.stop_instance(input))
+    }
+
+    /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::stop_stack`.
+    pub fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
+        self.runtime.block_on(self.client.stop_stack(input))
+    }
+
+    /// <p>Apply cost-allocation tags to a specified stack or layer in AWS OpsWorks Stacks. For more information about how tagging works, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/tagging.html">Tags</a> in the AWS OpsWorks User Guide.</p>
+    ///
+    /// See `OpsWorks::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Unassigns a registered instance from all layers that are using the instance. The instance remains in the stack as an unassigned instance, and can be assigned to another layer as needed. You cannot use this action with instances that were created with AWS OpsWorks Stacks.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::unassign_instance`.
+    pub fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> Result<(), RusotoError<UnassignInstanceError>> {
+        self.runtime.block_on(self.client.unassign_instance(input))
+    }
+
+    /// <p>Unassigns an assigned Amazon EBS volume. The volume remains registered with the stack. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::unassign_volume`.
+    pub fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> Result<(), RusotoError<UnassignVolumeError>> {
+        self.runtime.block_on(self.client.unassign_volume(input))
+    }
+
+    /// <p>Removes tags from a specified stack or layer.</p>
+    ///
+    /// See `OpsWorks::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_app`.
+    pub fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
+        self.runtime.block_on(self.client.update_app(input))
+    }
+
+    /// <p>Updates a registered Elastic IP address's name. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_elastic_ip`.
+    pub fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> Result<(), RusotoError<UpdateElasticIpError>> {
+        self.runtime.block_on(self.client.update_elastic_ip(input))
+    }
+
+    /// <p>Updates a specified instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_instance`.
+    pub fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceError>> {
+        self.runtime.block_on(self.client.update_instance(input))
+    }
+
+    /// <p>Updates a specified layer.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_layer`.
+    pub fn update_layer(
+        &self,
+        input: UpdateLayerRequest,
+    ) -> Result<(), RusotoError<UpdateLayerError>> {
+        self.runtime.block_on(self.client.update_layer(input))
+    }
+
+    /// <p>Updates a user's SSH public key.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have self-management enabled or an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_my_user_profile`.
+    pub fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_my_user_profile(input))
+    }
+
+    /// <p>Updates an Amazon RDS instance.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_rds_db_instance`.
+    pub fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_rds_db_instance(input))
+    }
+
+    /// <p>Updates a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_stack`.
+    pub fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<(), RusotoError<UpdateStackError>> {
+        self.runtime.block_on(self.client.update_stack(input))
+    }
+
+    /// <p>Updates a specified user profile.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have an attached policy that explicitly grants permissions. For more information about user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_user_profile`.
+    pub fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<(), RusotoError<UpdateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_profile(input))
+    }
+
+    /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
+    ///
+    /// See `OpsWorks::update_volume`.
+    pub fn update_volume(
+        &self,
+        input: UpdateVolumeRequest,
+    ) -> Result<(), RusotoError<UpdateVolumeError>> {
+        self.runtime.block_on(self.client.update_volume(input))
+    }
+}
@@ -829,10 +829,13 @@ impl AssociateNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(AssociateNodeError::InvalidState(err.msg))
+                    return RusotoError::service(&res, AssociateNodeError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateNodeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -867,13 +870,13 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateBackupError::InvalidState(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::InvalidState(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateBackupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateBackupError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -909,13 +912,16 @@ impl CreateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateServerError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateServerError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateServerError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServerError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateServerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -949,10 +955,10 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteBackupError::InvalidState(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteBackupError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -985,10 +991,10 @@ impl DeleteServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteServerError::InvalidState(err.msg))
+                    return RusotoError::service(&res, DeleteServerError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteServerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1043,10 +1049,16 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeBackupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBackupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1079,10 +1091,16 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1115,7 +1133,8 @@ impl DescribeNodeAssociationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNodeAssociationStatusError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1151,10 +1170,16 @@ impl DescribeServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeServersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServersError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1187,10 +1212,13 @@ impl DisassociateNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DisassociateNodeError::InvalidState(err.msg))
+                    return RusotoError::service(&res, DisassociateNodeError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateNodeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1225,12 +1253,14 @@ impl ExportServerEngineAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(ExportServerEngineAttributeError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExportServerEngineAttributeError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportServerEngineAttributeError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1263,9 +1293,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1297,10 +1328,13 @@ impl RestoreServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(RestoreServerError::InvalidState(err.msg))
+                    return RusotoError::service(&res, RestoreServerError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreServerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1333,10 +1367,13 @@ impl StartMaintenanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(StartMaintenanceError::InvalidState(err.msg))
+                    return RusotoError::service(&res, StartMaintenanceError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartMaintenanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartMaintenanceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1369,10 +1406,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(TagResourceError::InvalidState(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1405,10 +1442,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidState(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1441,10 +1481,10 @@ impl UpdateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateServerError::InvalidState(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::InvalidState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1479,12 +1519,14 @@ impl UpdateServerEngineAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateServerEngineAttributesError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServerEngineAttributesError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateServerEngineAttributesError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1644,6 +1686,7 @@ impl OpsWorksCMClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1659,6 +1702,7 @@ impl OpsWorksCMClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMClient {
         OpsWorksCMClient { client, region }
     }
@@ -1672,6 +1716,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: AssociateNodeRequest,
     ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateNode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.AssociateNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1690,6 +1737,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1708,6 +1758,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: CreateServerRequest,
     ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1726,6 +1779,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1744,6 +1800,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DeleteServerRequest,
     ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1762,6 +1821,9 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAccountAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.DescribeAccountAttributes",
@@ -1783,6 +1845,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBackups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1801,6 +1866,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1822,6 +1890,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         RusotoError<DescribeNodeAssociationStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeNodeAssociationStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.DescribeNodeAssociationStatus",
@@ -1844,6 +1915,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeServersRequest,
     ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1862,6 +1936,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DisassociateNodeRequest,
     ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateNode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DisassociateNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1881,6 +1958,9 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExportServerEngineAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.ExportServerEngineAttribute",
@@ -1903,6 +1983,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1921,6 +2004,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: RestoreServerRequest,
     ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.RestoreServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1939,6 +2025,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: StartMaintenanceRequest,
     ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMaintenance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.StartMaintenance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1957,6 +2046,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1975,6 +2067,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1993,6 +2088,9 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: UpdateServerRequest,
     ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UpdateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2012,6 +2110,9 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServerEngineAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.UpdateServerEngineAttributes",
@@ -2028,3 +2129,253 @@ impl OpsWorksCM for OpsWorksCMClient {
             .deserialize::<UpdateServerEngineAttributesResponse, _>()
     }
 }
+
+/// A blocking client for the OpsWorksCM API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `OpsWorksCMClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OpsWorksCMBlockingClient {
+    client: OpsWorksCMClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OpsWorksCMBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OpsWorksCMBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        OpsWorksCMBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OpsWorksCMBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OpsWorksCMBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient {
+            client: OpsWorksCMClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p> Associates a new node with the server. For more information about how to disassociate a node, see <a>DisassociateNode</a>.</p> <p> On a Chef server: This command is an alternative to <code>knife bootstrap</code>.</p> <p> Example (Chef): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>CHEF_ORGANIZATION</i>,Value=default" "Name=<i>CHEF_NODE_PUBLIC_KEY</i>,Value=<i>public-key-pem</i>"</code> </p> <p> On a Puppet server, this command is an alternative to the <code>puppet cert sign</code> command that signs a Puppet node CSR. </p> <p> Example (Puppet): <code>aws opsworks-cm associate-node --server-name <i>MyServer</i> --node-name <i>MyManagedNode</i> --engine-attributes "Name=<i>PUPPET_NODE_CSR</i>,Value=<i>csr-pem</i>"</code> </p> <p> A node can can only be associated with servers that are in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. The AssociateNode API call can be integrated into Auto Scaling configurations, AWS Cloudformation templates, or the user data of a server's instance. </p>
+    ///
+    /// See `OpsWorksCM::associate_node`.
+    pub fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
+        self.runtime.block_on(self.client.associate_node(input))
+    }
+
+    /// <p> Creates an application-level backup of a server. While the server is in the <code>BACKING_UP</code> state, the server cannot be changed, and no additional backup can be created. </p> <p> Backups can be created for servers in <code>RUNNING</code>, <code>HEALTHY</code>, and <code>UNHEALTHY</code> states. By default, you can create a maximum of 50 manual backups. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when the maximum number of manual backups is reached. An <code>InvalidStateException</code> is thrown when the server is not in any of the following states: RUNNING, HEALTHY, or UNHEALTHY. A <code>ResourceNotFoundException</code> is thrown when the server is not found. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::create_backup`.
+    pub fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        self.runtime.block_on(self.client.create_backup(input))
+    }
+
+    /// <p> Creates and immedately starts a new server. The server is ready to use when it is in the <code>HEALTHY</code> state. By default, you can create a maximum of 10 servers. </p> <p> This operation is asynchronous. </p> <p> A <code>LimitExceededException</code> is thrown when you have created the maximum number of servers (10). A <code>ResourceAlreadyExistsException</code> is thrown when a server with the same name already exists in the account. A <code>ResourceNotFoundException</code> is thrown when you specify a backup ID that is not valid or is for a backup that does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p> <p> If you do not specify a security group by adding the <code>SecurityGroupIds</code> parameter, AWS OpsWorks creates a new security group. </p> <p> <i>Chef Automate:</i> The default security group opens the Chef server to the world on TCP port 443. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p> <i>Puppet Enterprise:</i> The default security group opens TCP ports 22, 443, 4433, 8140, 8142, 8143, and 8170. If a KeyName is present, AWS OpsWorks enables SSH access. SSH is also open to the world on TCP port 22. </p> <p>By default, your server is accessible from any IP address. We recommend that you update your security group rules to allow access from known IP addresses and address ranges only. To edit security group rules, open Security Groups in the navigation pane of the EC2 management console. </p> <p>To specify your own domain for a server, and provide your own self-signed or CA-signed certificate and private key, specify values for <code>CustomDomain</code>, <code>CustomCertificate</code>, and <code>CustomPrivateKey</code>.</p>
+    ///
+    /// See `OpsWorksCM::create_server`.
+    pub fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+        self.runtime.block_on(self.client.create_server(input))
+    }
+
+    /// <p> Deletes a backup. You can delete both manual and automated backups. This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a backup deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is thrown when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::delete_backup`.
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// <p> Deletes the server and the underlying AWS CloudFormation stacks (including the server's EC2 instance). When you run this command, the server state is updated to <code>DELETING</code>. After the server is deleted, it is no longer returned by <code>DescribeServer</code> requests. If the AWS CloudFormation stack cannot be deleted, the server cannot be deleted. </p> <p> This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when a server deletion is already in progress. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p> <p> </p>
+    ///
+    /// See `OpsWorksCM::delete_server`.
+    pub fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
+        self.runtime.block_on(self.client.delete_server(input))
+    }
+
+    /// <p> Describes your OpsWorks-CM account attributes. </p> <p> This operation is synchronous. </p>
+    ///
+    /// See `OpsWorksCM::describe_account_attributes`.
+    pub fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_attributes())
+    }
+
+    /// <p> Describes backups. The results are ordered by time, with newest backups first. If you do not specify a BackupId or ServerName, the command returns all backups. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the backup does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::describe_backups`.
+    pub fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.runtime.block_on(self.client.describe_backups(input))
+    }
+
+    /// <p> Describes events for a specified server. Results are ordered by time, with newest events first. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p> Returns the current status of an existing association or disassociation request. </p> <p> A <code>ResourceNotFoundException</code> is thrown when no recent association or disassociation request with the specified token is found, or when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::describe_node_association_status`.
+    pub fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> Result<
+        DescribeNodeAssociationStatusResponse,
+        RusotoError<DescribeNodeAssociationStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_node_association_status(input))
+    }
+
+    /// <p> Lists all configuration management servers that are identified with your account. Only the stored results from Amazon DynamoDB are returned. AWS OpsWorks CM does not query other services. </p> <p> This operation is synchronous. </p> <p> A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::describe_servers`.
+    pub fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
+        self.runtime.block_on(self.client.describe_servers(input))
+    }
+
+    /// <p> Disassociates a node from an AWS OpsWorks CM server, and removes the node from the server's managed nodes. After a node is disassociated, the node key pair is no longer valid for accessing the configuration manager's API. For more information about how to associate a node, see <a>AssociateNode</a>. </p> <p>A node can can only be disassociated from a server that is in a <code>HEALTHY</code> state. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::disassociate_node`.
+    pub fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
+        self.runtime.block_on(self.client.disassociate_node(input))
+    }
+
+    /// <p> Exports a specified server engine attribute as a base64-encoded string. For example, you can export user data that you can use in EC2 to associate nodes with a server. </p> <p> This operation is synchronous. </p> <p> A <code>ValidationException</code> is raised when parameters of the request are not valid. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. An <code>InvalidStateException</code> is thrown when the server is in any of the following states: CREATING, TERMINATED, FAILED or DELETING. </p>
+    ///
+    /// See `OpsWorksCM::export_server_engine_attribute`.
+    pub fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.export_server_engine_attribute(input))
+    }
+
+    /// <p>Returns a list of tags that are applied to the specified AWS OpsWorks for Chef Automate or AWS OpsWorks for Puppet Enterprise servers or backups.</p>
+    ///
+    /// See `OpsWorksCM::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p> Restores a backup to a server that is in a <code>CONNECTION_LOST</code>, <code>HEALTHY</code>, <code>RUNNING</code>, <code>UNHEALTHY</code>, or <code>TERMINATED</code> state. When you run RestoreServer, the server's EC2 instance is deleted, and a new EC2 instance is configured. RestoreServer maintains the existing server endpoint, so configuration management of the server's client devices (nodes) should continue to work. </p> <p>Restoring from a backup is performed by creating a new EC2 instance. If restoration is successful, and the server is in a <code>HEALTHY</code> state, AWS OpsWorks CM switches traffic over to the new instance. After restoration is finished, the old EC2 instance is maintained in a <code>Running</code> or <code>Stopped</code> state, but is eventually terminated.</p> <p> This operation is asynchronous. </p> <p> An <code>InvalidStateException</code> is thrown when the server is not in a valid state. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::restore_server`.
+    pub fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
+        self.runtime.block_on(self.client.restore_server(input))
+    }
+
+    /// <p> Manually starts server maintenance. This command can be useful if an earlier maintenance attempt failed, and the underlying cause of maintenance failure has been resolved. The server is in an <code>UNDER_MAINTENANCE</code> state while maintenance is in progress. </p> <p> Maintenance can only be started on servers in <code>HEALTHY</code> and <code>UNHEALTHY</code> states. Otherwise, an <code>InvalidStateException</code> is thrown. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::start_maintenance`.
+    pub fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
+        self.runtime.block_on(self.client.start_maintenance(input))
+    }
+
+    /// <p>Applies tags to an AWS OpsWorks for Chef Automate or AWS OpsWorks for Puppet Enterprise server, or to server backups.</p>
+    ///
+    /// See `OpsWorksCM::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes specified tags from an AWS OpsWorks-CM server or backup.</p>
+    ///
+    /// See `OpsWorksCM::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p> Updates settings for a server. </p> <p> This operation is synchronous. </p>
+    ///
+    /// See `OpsWorksCM::update_server`.
+    pub fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+        self.runtime.block_on(self.client.update_server(input))
+    }
+
+    /// <p> Updates engine-specific attributes on a specified server. The server enters the <code>MODIFYING</code> state when this operation is in progress. Only one update can occur at a time. You can use this command to reset a Chef server's public key (<code>CHEF_PIVOTAL_KEY</code>) or a Puppet server's admin password (<code>PUPPET_ADMIN_PASSWORD</code>). </p> <p> This operation is asynchronous. </p> <p> This operation can only be called for servers in <code>HEALTHY</code> or <code>UNHEALTHY</code> states. Otherwise, an <code>InvalidStateException</code> is raised. A <code>ResourceNotFoundException</code> is thrown when the server does not exist. A <code>ValidationException</code> is raised when parameters of the request are not valid. </p>
+    ///
+    /// See `OpsWorksCM::update_server_engine_attributes`.
+    pub fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.update_server_engine_attributes(input))
+    }
+}
@@ -1507,49 +1507,61 @@ impl AcceptHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AcceptHandshakeError::AccessDenied(err.msg))
                 }
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AccessDeniedForDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::AccessDeniedForDependency(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AcceptHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(AcceptHandshakeError::HandshakeAlreadyInState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::HandshakeAlreadyInState(err.msg),
+                    )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptHandshakeError::HandshakeConstraintViolation(err.msg),
                     )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(AcceptHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::HandshakeNotFound(err.msg),
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(AcceptHandshakeError::InvalidHandshakeTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::InvalidHandshakeTransition(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AcceptHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AcceptHandshakeError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AcceptHandshakeError::Service(err.msg))
+                    return RusotoError::service(&res, AcceptHandshakeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AcceptHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptHandshakeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1613,49 +1625,64 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(AttachPolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AttachPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(AttachPolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::ConstraintViolation(err.msg),
+                    )
                 }
                 "DuplicatePolicyAttachmentException" => {
-                    return RusotoError::Service(AttachPolicyError::DuplicatePolicyAttachment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::DuplicatePolicyAttachment(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::InvalidInput(err.msg))
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::PolicyChangesInProgress(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::PolicyNotFound(err.msg))
                 }
                 "PolicyTypeNotEnabledException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyTypeNotEnabled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::PolicyTypeNotEnabled(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::Service(err.msg))
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::TargetNotFound(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::TargetNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AttachPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, AttachPolicyError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(AttachPolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachPolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1711,34 +1738,43 @@ impl CancelHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CancelHandshakeError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CancelHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelHandshakeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(CancelHandshakeError::HandshakeAlreadyInState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelHandshakeError::HandshakeAlreadyInState(err.msg),
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(CancelHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelHandshakeError::HandshakeNotFound(err.msg),
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(CancelHandshakeError::InvalidHandshakeTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelHandshakeError::InvalidHandshakeTransition(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CancelHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CancelHandshakeError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelHandshakeError::Service(err.msg))
+                    return RusotoError::service(&res, CancelHandshakeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelHandshakeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1791,39 +1827,46 @@ impl CreateAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreateAccountError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateAccountError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateAccountError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::ConstraintViolation(err.msg),
+                    )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(CreateAccountError::FinalizingOrganization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::FinalizingOrganization(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAccountError::Service(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateAccountError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(CreateAccountError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAccountError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1877,41 +1920,53 @@ impl CreateGovCloudAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGovCloudAccountError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGovCloudAccountError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGovCloudAccountError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGovCloudAccountError::ConstraintViolation(err.msg),
+                    )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGovCloudAccountError::FinalizingOrganization(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGovCloudAccountError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::Service(err.msg))
+                    return RusotoError::service(&res, CreateGovCloudAccountError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGovCloudAccountError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGovCloudAccountError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -1967,36 +2022,49 @@ impl CreateOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOrganizationError::AccessDeniedForDependency(err.msg),
                     )
                 }
                 "AlreadyInOrganizationException" => {
-                    return RusotoError::Service(CreateOrganizationError::AlreadyInOrganization(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationError::AlreadyInOrganization(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateOrganizationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateOrganizationError::Service(err.msg))
+                    return RusotoError::service(&res, CreateOrganizationError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2049,47 +2117,58 @@ impl CreateOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationalUnitError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOrganizationalUnitError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOrganizationalUnitError::ConstraintViolation(err.msg),
                     )
                 }
                 "DuplicateOrganizationalUnitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateOrganizationalUnitError::DuplicateOrganizationalUnit(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationalUnitError::InvalidInput(err.msg),
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::ParentNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationalUnitError::ParentNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationalUnitError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOrganizationalUnitError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2153,43 +2232,55 @@ impl CreatePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreatePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreatePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreatePolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::ConstraintViolation(err.msg),
+                    )
                 }
                 "DuplicatePolicyException" => {
-                    return RusotoError::Service(CreatePolicyError::DuplicatePolicy(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::DuplicatePolicy(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::InvalidInput(err.msg))
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreatePolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::MalformedPolicyDocument(err.msg),
+                    )
                 }
                 "PolicyTypeNotAvailableForOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePolicyError::PolicyTypeNotAvailableForOrganization(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreatePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreatePolicyError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(CreatePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2245,34 +2336,43 @@ impl DeclineHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeclineHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeclineHandshakeError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeclineHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeclineHandshakeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(DeclineHandshakeError::HandshakeAlreadyInState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeclineHandshakeError::HandshakeAlreadyInState(err.msg),
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(DeclineHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeclineHandshakeError::HandshakeNotFound(err.msg),
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(DeclineHandshakeError::InvalidHandshakeTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeclineHandshakeError::InvalidHandshakeTransition(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeclineHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeclineHandshakeError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeclineHandshakeError::Service(err.msg))
+                    return RusotoError::service(&res, DeclineHandshakeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeclineHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeclineHandshakeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2321,31 +2421,43 @@ impl DeleteOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DeleteOrganizationError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationError::InvalidInput(err.msg),
+                    )
                 }
                 "OrganizationNotEmptyException" => {
-                    return RusotoError::Service(DeleteOrganizationError::OrganizationNotEmpty(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationError::OrganizationNotEmpty(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteOrganizationError::Service(err.msg))
+                    return RusotoError::service(&res, DeleteOrganizationError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2395,42 +2507,52 @@ impl DeleteOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationalUnitError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationalUnitError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationalUnitError::InvalidInput(err.msg),
+                    )
                 }
                 "OrganizationalUnitNotEmptyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationalUnitError::OrganizationalUnitNotEmpty(err.msg),
                     )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationalUnitError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteOrganizationalUnitError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2491,33 +2613,40 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DeletePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyError::ConcurrentModification(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::InvalidInput(err.msg))
                 }
                 "PolicyInUseException" => {
-                    return RusotoError::Service(DeletePolicyError::PolicyInUse(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::PolicyInUse(err.msg))
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::PolicyNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeletePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeletePolicyError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DeletePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2575,52 +2704,62 @@ impl DeregisterDelegatedAdministratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::AccessDenied(err.msg),
                     )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::AccountNotFound(err.msg),
                     )
                 }
                 "AccountNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::AccountNotRegistered(err.msg),
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::ConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::InvalidInput(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterDelegatedAdministratorError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterDelegatedAdministratorError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::TooManyRequests(err.msg),
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterDelegatedAdministratorError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -2689,24 +2828,31 @@ impl DescribeAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DescribeAccountError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccountError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeAccountError::AccessDenied(err.msg))
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(DescribeAccountError::AccountNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccountError::AccountNotFound(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeAccountError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeAccountError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeAccountError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccountError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2755,35 +2901,44 @@ impl DescribeCreateAccountStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCreateAccountStatusError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCreateAccountStatusError::AccessDenied(err.msg),
+                    )
                 }
                 "CreateAccountStatusNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCreateAccountStatusError::CreateAccountStatusNotFound(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCreateAccountStatusError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCreateAccountStatusError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCreateAccountStatusError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -2843,45 +2998,56 @@ impl DescribeEffectivePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePolicyError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEffectivePolicyError::AccessDenied(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEffectivePolicyError::ConstraintViolation(err.msg),
+                    )
                 }
                 "EffectivePolicyNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePolicyError::EffectivePolicyNotFound(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEffectivePolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEffectivePolicyError::Service(err.msg),
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::TargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEffectivePolicyError::TargetNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEffectivePolicyError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePolicyError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -2937,24 +3103,37 @@ impl DescribeHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHandshakeError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeHandshakeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHandshakeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(DescribeHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHandshakeError::HandshakeNotFound(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHandshakeError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeHandshakeError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeHandshakeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHandshakeError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2997,25 +3176,31 @@ impl DescribeOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeOrganizationError::Service(err.msg))
+                    return RusotoError::service(&res, DescribeOrganizationError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOrganizationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3063,32 +3248,40 @@ impl DescribeOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationalUnitError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationalUnitError::InvalidInput(err.msg),
+                    )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationalUnitError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationalUnitError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3139,29 +3332,34 @@ impl DescribePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DescribePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePolicyError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribePolicyError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribePolicyError::InvalidInput(err.msg))
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DescribePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(&res, DescribePolicyError::PolicyNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, DescribePolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePolicyError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DescribePolicyError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3219,44 +3417,58 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DetachPolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DetachPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DetachPolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::InvalidInput(err.msg))
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::PolicyChangesInProgress(err.msg),
+                    )
                 }
                 "PolicyNotAttachedException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyNotAttached(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::PolicyNotAttached(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::PolicyNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::Service(err.msg))
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::TargetNotFound(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::TargetNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetachPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DetachPolicyError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DetachPolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachPolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3311,40 +3523,50 @@ impl DisableAWSServiceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableAWSServiceAccessError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableAWSServiceAccessError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableAWSServiceAccessError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableAWSServiceAccessError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableAWSServiceAccessError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableAWSServiceAccessError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableAWSServiceAccessError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableAWSServiceAccessError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -3409,49 +3631,67 @@ impl DisablePolicyTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::InvalidInput(err.msg),
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::PolicyChangesInProgress(err.msg),
+                    )
                 }
                 "PolicyTypeNotEnabledException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::PolicyTypeNotEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::PolicyTypeNotEnabled(err.msg),
+                    )
                 }
                 "RootNotFoundException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::RootNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::RootNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::Service(err.msg))
+                    return RusotoError::service(&res, DisablePolicyTypeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisablePolicyTypeError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3505,36 +3745,50 @@ impl EnableAWSServiceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableAWSServiceAccessError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableAWSServiceAccessError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableAWSServiceAccessError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableAWSServiceAccessError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableAWSServiceAccessError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableAWSServiceAccessError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableAWSServiceAccessError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableAWSServiceAccessError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -3591,31 +3845,43 @@ impl EnableAllFeaturesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableAllFeaturesError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableAllFeaturesError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableAllFeaturesError::ConcurrentModification(err.msg),
+                    )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableAllFeaturesError::HandshakeConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableAllFeaturesError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::Service(err.msg))
+                    return RusotoError::service(&res, EnableAllFeaturesError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableAllFeaturesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3675,54 +3941,64 @@ impl EnablePolicyTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, EnablePolicyTypeError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, EnablePolicyTypeError::InvalidInput(err.msg))
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::PolicyChangesInProgress(err.msg),
+                    )
                 }
                 "PolicyTypeAlreadyEnabledException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::PolicyTypeAlreadyEnabled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::PolicyTypeAlreadyEnabled(err.msg),
+                    )
                 }
                 "PolicyTypeNotAvailableForOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnablePolicyTypeError::PolicyTypeNotAvailableForOrganization(err.msg),
                     )
                 }
                 "RootNotFoundException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::RootNotFound(err.msg))
+                    return RusotoError::service(&res, EnablePolicyTypeError::RootNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::Service(err.msg))
+                    return RusotoError::service(&res, EnablePolicyTypeError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnablePolicyTypeError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3785,52 +4061,64 @@ impl InviteAccountToOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InviteAccountToOrganizationError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InviteAccountToOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountOwnerNotVerifiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InviteAccountToOrganizationError::AccountOwnerNotVerified(err.msg),
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InviteAccountToOrganizationError::ConcurrentModification(err.msg),
                     )
                 }
                 "DuplicateHandshakeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InviteAccountToOrganizationError::DuplicateHandshake(err.msg),
                     )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InviteAccountToOrganizationError::FinalizingOrganization(err.msg),
                     )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InviteAccountToOrganizationError::HandshakeConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InviteAccountToOrganizationError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InviteAccountToOrganizationError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        InviteAccountToOrganizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3897,39 +4185,55 @@ impl LeaveOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AccountNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::AccountNotFound(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(LeaveOrganizationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(LeaveOrganizationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(LeaveOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::InvalidInput(err.msg),
+                    )
                 }
                 "MasterCannotLeaveOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         LeaveOrganizationError::MasterCannotLeaveOrganization(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(LeaveOrganizationError::Service(err.msg))
+                    return RusotoError::service(&res, LeaveOrganizationError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LeaveOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LeaveOrganizationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3983,37 +4287,44 @@ impl ListAWSServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSServiceAccessForOrganizationError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSServiceAccessForOrganizationError::AccessDenied(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSServiceAccessForOrganizationError::ConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSServiceAccessForOrganizationError::InvalidInput(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAWSServiceAccessForOrganizationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAWSServiceAccessForOrganizationError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSServiceAccessForOrganizationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSServiceAccessForOrganizationError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -4071,21 +4382,22 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListAccountsError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAccountsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAccountsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAccountsError::Service(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4129,28 +4441,37 @@ impl ListAccountsForParentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAccountsForParentError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAccountsForParentError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsForParentError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAccountsForParentError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsForParentError::InvalidInput(err.msg),
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(ListAccountsForParentError::ParentNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsForParentError::ParentNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAccountsForParentError::Service(err.msg))
+                    return RusotoError::service(&res, ListAccountsForParentError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsForParentError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountsForParentError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4197,24 +4518,25 @@ impl ListChildrenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListChildrenError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListChildrenError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChildrenError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListChildrenError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListChildrenError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListChildrenError::InvalidInput(err.msg))
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(ListChildrenError::ParentNotFound(err.msg))
+                    return RusotoError::service(&res, ListChildrenError::ParentNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListChildrenError::Service(err.msg))
+                    return RusotoError::service(&res, ListChildrenError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChildrenError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListChildrenError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4259,30 +4581,38 @@ impl ListCreateAccountStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListCreateAccountStatusError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreateAccountStatusError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreateAccountStatusError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCreateAccountStatusError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCreateAccountStatusError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -4337,35 +4667,44 @@ impl ListDelegatedAdministratorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedAdministratorsError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDelegatedAdministratorsError::AccessDenied(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedAdministratorsError::ConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDelegatedAdministratorsError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDelegatedAdministratorsError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDelegatedAdministratorsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedAdministratorsError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -4427,47 +4766,56 @@ impl ListDelegatedServicesForAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::AccessDenied(err.msg),
                     )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::AccountNotFound(err.msg),
                     )
                 }
                 "AccountNotRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::AccountNotRegistered(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::ConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::InvalidInput(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDelegatedServicesForAccountError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDelegatedServicesForAccountError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::TooManyRequests(err.msg),
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDelegatedServicesForAccountError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -4527,27 +4875,34 @@ impl ListHandshakesForAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForAccountError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListHandshakesForAccountError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForAccountError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForAccountError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForAccountError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4595,32 +4950,38 @@ impl ListHandshakesForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListHandshakesForOrganizationError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListHandshakesForOrganizationError::ConcurrentModification(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForOrganizationError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListHandshakesForOrganizationError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListHandshakesForOrganizationError::TooManyRequests(err.msg),
                     )
                 }
@@ -4675,32 +5036,38 @@ impl ListOrganizationalUnitsForParentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationalUnitsForParentError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationalUnitsForParentError::AccessDenied(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationalUnitsForParentError::InvalidInput(err.msg),
                     )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationalUnitsForParentError::ParentNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListOrganizationalUnitsForParentError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListOrganizationalUnitsForParentError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationalUnitsForParentError::TooManyRequests(err.msg),
                     )
                 }
@@ -4757,24 +5124,25 @@ impl ListParentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListParentsError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListParentsError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListParentsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListParentsError::AccessDenied(err.msg))
                 }
                 "ChildNotFoundException" => {
-                    return RusotoError::Service(ListParentsError::ChildNotFound(err.msg))
+                    return RusotoError::service(&res, ListParentsError::ChildNotFound(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListParentsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListParentsError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListParentsError::Service(err.msg))
+                    return RusotoError::service(&res, ListParentsError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListParentsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListParentsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4819,24 +5187,28 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListPoliciesError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPoliciesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListPoliciesError::Service(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPoliciesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListPoliciesError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(ListPoliciesError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4883,31 +5255,41 @@ impl ListPoliciesForTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPoliciesForTargetError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesForTargetError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesForTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::Service(err.msg))
+                    return RusotoError::service(&res, ListPoliciesForTargetError::Service(err.msg))
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::TargetNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesForTargetError::TargetNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPoliciesForTargetError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPoliciesForTargetError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -4955,19 +5337,22 @@ impl ListRootsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListRootsError::AWSOrganizationsNotInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRootsError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRootsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListRootsError::AccessDenied(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRootsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListRootsError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListRootsError::Service(err.msg))
+                    return RusotoError::service(&res, ListRootsError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRootsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListRootsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5011,24 +5396,37 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTagsForResourceError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Service(err.msg))
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TargetNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5075,31 +5473,43 @@ impl ListTargetsForPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTargetsForPolicyError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::InvalidInput(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::PolicyNotFound(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Service(err.msg))
+                    return RusotoError::service(&res, ListTargetsForPolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::UnsupportedAPIEndpoint(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTargetsForPolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5155,38 +5565,46 @@ impl MoveAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(MoveAccountError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MoveAccountError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(MoveAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, MoveAccountError::AccessDenied(err.msg))
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::AccountNotFound(err.msg))
+                    return RusotoError::service(&res, MoveAccountError::AccountNotFound(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(MoveAccountError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MoveAccountError::ConcurrentModification(err.msg),
+                    )
                 }
                 "DestinationParentNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::DestinationParentNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MoveAccountError::DestinationParentNotFound(err.msg),
+                    )
                 }
                 "DuplicateAccountException" => {
-                    return RusotoError::Service(MoveAccountError::DuplicateAccount(err.msg))
+                    return RusotoError::service(&res, MoveAccountError::DuplicateAccount(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(MoveAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, MoveAccountError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(MoveAccountError::Service(err.msg))
+                    return RusotoError::service(&res, MoveAccountError::Service(err.msg))
                 }
                 "SourceParentNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::SourceParentNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MoveAccountError::SourceParentNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(MoveAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, MoveAccountError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5245,52 +5663,62 @@ impl RegisterDelegatedAdministratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterDelegatedAdministratorError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDelegatedAdministratorError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountAlreadyRegisteredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::AccountAlreadyRegistered(err.msg),
                     )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::AccountNotFound(err.msg),
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::ConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterDelegatedAdministratorError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDelegatedAdministratorError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterDelegatedAdministratorError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDelegatedAdministratorError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::TooManyRequests(err.msg),
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDelegatedAdministratorError::UnsupportedAPIEndpoint(err.msg),
                     )
                 }
@@ -5363,47 +5791,56 @@ impl RemoveAccountFromOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAccountFromOrganizationError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAccountFromOrganizationError::AccessDenied(err.msg),
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAccountFromOrganizationError::AccountNotFound(err.msg),
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAccountFromOrganizationError::ConcurrentModification(err.msg),
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAccountFromOrganizationError::ConstraintViolation(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAccountFromOrganizationError::InvalidInput(err.msg),
+                    )
                 }
                 "MasterCannotLeaveOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAccountFromOrganizationError::MasterCannotLeaveOrganization(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveAccountFromOrganizationError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAccountFromOrganizationError::TooManyRequests(err.msg),
                     )
                 }
@@ -5469,30 +5906,37 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(TagResourceError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(TagResourceError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Service(err.msg))
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TargetNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5543,32 +5987,37 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(UntagResourceError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(UntagResourceError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Service(err.msg))
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TargetNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5619,42 +6068,52 @@ impl UpdateOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOrganizationalUnitError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateOrganizationalUnitError::ConcurrentModification(err.msg),
                     )
                 }
                 "DuplicateOrganizationalUnitException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateOrganizationalUnitError::DuplicateOrganizationalUnit(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOrganizationalUnitError::InvalidInput(err.msg),
+                    )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOrganizationalUnitError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOrganizationalUnitError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5721,46 +6180,58 @@ impl UpdatePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(UpdatePolicyError::AWSOrganizationsNotInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePolicyError::AWSOrganizationsNotInUse(err.msg),
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdatePolicyError::AccessDenied(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdatePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePolicyError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(UpdatePolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePolicyError::ConstraintViolation(err.msg),
+                    )
                 }
                 "DuplicatePolicyException" => {
-                    return RusotoError::Service(UpdatePolicyError::DuplicatePolicy(err.msg))
+                    return RusotoError::service(&res, UpdatePolicyError::DuplicatePolicy(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdatePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdatePolicyError::InvalidInput(err.msg))
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(UpdatePolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePolicyError::MalformedPolicyDocument(err.msg),
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(UpdatePolicyError::PolicyChangesInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePolicyError::PolicyChangesInProgress(err.msg),
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(UpdatePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service(&res, UpdatePolicyError::PolicyNotFound(err.msg))
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdatePolicyError::Service(err.msg))
+                    return RusotoError::service(&res, UpdatePolicyError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdatePolicyError::TooManyRequests(err.msg))
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(UpdatePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePolicyError::UnsupportedAPIEndpoint(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6120,6 +6591,7 @@ impl OrganizationsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6135,6 +6607,7 @@ impl OrganizationsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsClient {
         OrganizationsClient { client, region }
     }
@@ -6148,6 +6621,9 @@ impl Organizations for OrganizationsClient {
         input: AcceptHandshakeRequest,
     ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptHandshake");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6166,6 +6642,9 @@ impl Organizations for OrganizationsClient {
         input: AttachPolicyRequest,
     ) -> Result<(), RusotoError<AttachPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6183,6 +6662,9 @@ impl Organizations for OrganizationsClient {
         input: CancelHandshakeRequest,
     ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelHandshake");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6201,6 +6683,9 @@ impl Organizations for OrganizationsClient {
         input: CreateAccountRequest,
     ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreateAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6219,6 +6704,9 @@ impl Organizations for OrganizationsClient {
         input: CreateGovCloudAccountRequest,
     ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGovCloudAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.CreateGovCloudAccount",
@@ -6241,6 +6729,9 @@ impl Organizations for OrganizationsClient {
         input: CreateOrganizationRequest,
     ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.CreateOrganization",
@@ -6262,6 +6753,9 @@ impl Organizations for OrganizationsClient {
         input: CreateOrganizationalUnitRequest,
     ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateOrganizationalUnit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.CreateOrganizationalUnit",
@@ -6284,6 +6778,9 @@ impl Organizations for OrganizationsClient {
         input: CreatePolicyRequest,
     ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreatePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6302,6 +6799,9 @@ impl Organizations for OrganizationsClient {
         input: DeclineHandshakeRequest,
     ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeclineHandshake");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeclineHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6317,6 +6817,9 @@ impl Organizations for OrganizationsClient {
     /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
     async fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DeleteOrganization",
@@ -6336,6 +6839,9 @@ impl Organizations for OrganizationsClient {
         input: DeleteOrganizationalUnitRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteOrganizationalUnit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DeleteOrganizationalUnit",
@@ -6356,6 +6862,9 @@ impl Organizations for OrganizationsClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6373,6 +6882,9 @@ impl Organizations for OrganizationsClient {
         input: DeregisterDelegatedAdministratorRequest,
     ) -> Result<(), RusotoError<DeregisterDelegatedAdministratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterDelegatedAdministrator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DeregisterDelegatedAdministrator",
@@ -6396,6 +6908,9 @@ impl Organizations for OrganizationsClient {
         input: DescribeAccountRequest,
     ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribeAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6415,6 +6930,9 @@ impl Organizations for OrganizationsClient {
     ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCreateAccountStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeCreateAccountStatus",
@@ -6437,6 +6955,9 @@ impl Organizations for OrganizationsClient {
         input: DescribeEffectivePolicyRequest,
     ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEffectivePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeEffectivePolicy",
@@ -6459,6 +6980,9 @@ impl Organizations for OrganizationsClient {
         input: DescribeHandshakeRequest,
     ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHandshake");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeHandshake",
@@ -6479,6 +7003,9 @@ impl Organizations for OrganizationsClient {
         &self,
     ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeOrganization",
@@ -6501,6 +7028,9 @@ impl Organizations for OrganizationsClient {
     ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganizationalUnit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeOrganizationalUnit",
@@ -6523,6 +7053,9 @@ impl Organizations for OrganizationsClient {
         input: DescribePolicyRequest,
     ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6541,6 +7074,9 @@ impl Organizations for OrganizationsClient {
         input: DetachPolicyRequest,
     ) -> Result<(), RusotoError<DetachPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetachPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DetachPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6558,6 +7094,9 @@ impl Organizations for OrganizationsClient {
         input: DisableAWSServiceAccessRequest,
     ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableAWSServiceAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DisableAWSServiceAccess",
@@ -6578,6 +7117,9 @@ impl Organizations for OrganizationsClient {
         input: DisablePolicyTypeRequest,
     ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisablePolicyType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DisablePolicyType",
@@ -6599,6 +7141,9 @@ impl Organizations for OrganizationsClient {
         input: EnableAWSServiceAccessRequest,
     ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableAWSServiceAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.EnableAWSServiceAccess",
@@ -6618,6 +7163,9 @@ impl Organizations for OrganizationsClient {
         &self,
     ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableAllFeatures");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.EnableAllFeatures",
@@ -6638,6 +7186,9 @@ impl Organizations for OrganizationsClient {
         input: EnablePolicyTypeRequest,
     ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnablePolicyType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.EnablePolicyType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6657,6 +7208,9 @@ impl Organizations for OrganizationsClient {
     ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("InviteAccountToOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.InviteAccountToOrganization",
@@ -6676,6 +7230,9 @@ impl Organizations for OrganizationsClient {
     /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do. This includes preventing them from successfully calling <code>LeaveOrganization</code> and leaving the organization. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must do the following steps:</p> <ul> <li> <p>Accept the end user license agreement (EULA)</p> </li> <li> <p>Choose a support plan</p> </li> <li> <p>Provide and verify the required contact information</p> </li> <li> <p>Provide a current payment method</p> </li> </ul> <p>AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
     async fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("LeaveOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.LeaveOrganization",
@@ -6698,6 +7255,9 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListAWSServiceAccessForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAWSServiceAccessForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListAWSServiceAccessForOrganization",
@@ -6723,6 +7283,9 @@ impl Organizations for OrganizationsClient {
         input: ListAccountsRequest,
     ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6741,6 +7304,9 @@ impl Organizations for OrganizationsClient {
         input: ListAccountsForParentRequest,
     ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAccountsForParent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListAccountsForParent",
@@ -6763,6 +7329,9 @@ impl Organizations for OrganizationsClient {
         input: ListChildrenRequest,
     ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListChildren");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListChildren");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6781,6 +7350,9 @@ impl Organizations for OrganizationsClient {
         input: ListCreateAccountStatusRequest,
     ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCreateAccountStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListCreateAccountStatus",
@@ -6804,6 +7376,9 @@ impl Organizations for OrganizationsClient {
     ) -> Result<ListDelegatedAdministratorsResponse, RusotoError<ListDelegatedAdministratorsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDelegatedAdministrators");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListDelegatedAdministrators",
@@ -6829,6 +7404,9 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListDelegatedServicesForAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDelegatedServicesForAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListDelegatedServicesForAccount",
@@ -6851,6 +7429,9 @@ impl Organizations for OrganizationsClient {
         input: ListHandshakesForAccountRequest,
     ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHandshakesForAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListHandshakesForAccount",
@@ -6876,6 +7457,9 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListHandshakesForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHandshakesForOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListHandshakesForOrganization",
@@ -6901,6 +7485,9 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListOrganizationalUnitsForParentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOrganizationalUnitsForParent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListOrganizationalUnitsForParent",
@@ -6926,6 +7513,9 @@ impl Organizations for OrganizationsClient {
         input: ListParentsRequest,
     ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListParents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListParents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6944,6 +7534,9 @@ impl Organizations for OrganizationsClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6962,6 +7555,9 @@ impl Organizations for OrganizationsClient {
         input: ListPoliciesForTargetRequest,
     ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPoliciesForTarget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListPoliciesForTarget",
@@ -6984,6 +7580,9 @@ impl Organizations for OrganizationsClient {
         input: ListRootsRequest,
     ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRoots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListRoots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7002,6 +7601,9 @@ impl Organizations for OrganizationsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListTagsForResource",
@@ -7023,6 +7625,9 @@ impl Organizations for OrganizationsClient {
         input: ListTargetsForPolicyRequest,
     ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTargetsForPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListTargetsForPolicy",
@@ -7045,6 +7650,9 @@ impl Organizations for OrganizationsClient {
         input: MoveAccountRequest,
     ) -> Result<(), RusotoError<MoveAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MoveAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.MoveAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7062,6 +7670,9 @@ impl Organizations for OrganizationsClient {
         input: RegisterDelegatedAdministratorRequest,
     ) -> Result<(), RusotoError<RegisterDelegatedAdministratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterDelegatedAdministrator");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.RegisterDelegatedAdministrator",
@@ -7082,6 +7693,9 @@ impl Organizations for OrganizationsClient {
         input: RemoveAccountFromOrganizationRequest,
     ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveAccountFromOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.RemoveAccountFromOrganization",
@@ -7102,6 +7716,9 @@ impl Organizations for OrganizationsClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7119,6 +7736,9 @@ impl Organizations for OrganizationsClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7136,6 +7756,9 @@ impl Organizations for OrganizationsClient {
         input: UpdateOrganizationalUnitRequest,
     ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateOrganizationalUnit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.UpdateOrganizationalUnit",
@@ -7158,6 +7781,9 @@ impl Organizations for OrganizationsClient {
         input: UpdatePolicyRequest,
     ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UpdatePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7170,3 +7796,597 @@ impl Organizations for OrganizationsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePolicyResponse, _>()
     }
 }
+
+/// A blocking client for the Organizations API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `OrganizationsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OrganizationsBlockingClient {
+    client: OrganizationsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OrganizationsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OrganizationsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        OrganizationsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OrganizationsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OrganizationsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient {
+            client: OrganizationsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Sends a response to the originator of a handshake agreeing to the action proposed by the handshake request. </p> <p>This operation can be called only by the following principals when they also have the relevant IAM permissions:</p> <ul> <li> <p> <b>Invitation to join</b> or <b>Approve all features request</b> handshakes: only a principal from the member account. </p> <p>The user who calls the API for an invitation to join must have the <code>organizations:AcceptHandshake</code> permission. If you enabled all features in the organization, the user must also have the <code>iam:CreateServiceLinkedRole</code> permission so that AWS Organizations can create the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integration_services.html#orgs_integration_service-linked-roles">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p> <b>Enable all features final confirmation</b> handshake: only a principal from the master account.</p> <p>For more information about invitations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_invites.html">Inviting an AWS Account to Join Your Organization</a> in the <i>AWS Organizations User Guide.</i> For more information about requests to enable all features in the organization, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>After you accept a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    ///
+    /// See `Organizations::accept_handshake`.
+    pub fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
+        self.runtime.block_on(self.client.accept_handshake(input))
+    }
+
+    /// <p>Attaches a policy to a root, an organizational unit (OU), or an individual account. How the policy affects accounts depends on the type of policy. Refer to the <i>AWS Organizations User Guide</i> for information about each policy type:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_ai-opt-out.html">AISERVICES_OPT_OUT_POLICY</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_backup.html">BACKUP_POLICY</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_scp.html">SERVICE_CONTROL_POLICY</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">TAG_POLICY</a> </p> </li> </ul> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::attach_policy`.
+    pub fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPolicyError>> {
+        self.runtime.block_on(self.client.attach_policy(input))
+    }
+
+    /// <p>Cancels a handshake. Canceling a handshake sets the handshake state to <code>CANCELED</code>. </p> <p>This operation can be called only from the account that originated the handshake. The recipient of the handshake can't cancel it, but can use <a>DeclineHandshake</a> instead. After a handshake is canceled, the recipient can no longer respond to that handshake.</p> <p>After you cancel a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    ///
+    /// See `Organizations::cancel_handshake`.
+    pub fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
+        self.runtime.block_on(self.client.cancel_handshake(input))
+    }
+
+    /// <p><p>Creates an AWS account that is automatically a member of the organization whose credentials made the request. This is an asynchronous request that AWS performs in the background. Because <code>CreateAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>The user who calls the API to create an account must have the <code>organizations:CreateAccount</code> permission. If you enabled all features in the organization, AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide</i>.</p> <p>AWS Organizations preconfigures the new member account with a role (named <code>OrganizationAccountAccessRole</code> by default) that grants users in the master account administrator permissions in the new member account. Principals in the master account can assume the role. AWS Organizations clones the company name and address information for the new account from the organization&#39;s master account.</p> <p>This operation can be called only from the organization&#39;s master account.</p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required for the account to operate as a standalone account, such as a payment method and signing the end user license agreement (EULA) is <i>not</i> automatically collected. If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the Billing and Cost Management Console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
+    ///
+    /// See `Organizations::create_account`.
+    pub fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+        self.runtime.block_on(self.client.create_account(input))
+    }
+
+    /// <p><p>This action is available if all of the following are true:</p> <ul> <li> <p>You&#39;re authorized to create accounts in the AWS GovCloud (US) Region. For more information on the AWS GovCloud (US) Region, see the <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/welcome.html"> <i>AWS GovCloud User Guide</i>.</a> </p> </li> <li> <p>You already have an account in the AWS GovCloud (US) Region that is associated with your master account in the commercial Region. </p> </li> <li> <p>You call this action from the master account of your organization in the commercial Region.</p> </li> <li> <p>You have the <code>organizations:CreateGovCloudAccount</code> permission. AWS Organizations creates the required service-linked role named <code>AWSServiceRoleForOrganizations</code>. For more information, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html#orgs_integrate_services-using_slrs">AWS Organizations and Service-Linked Roles</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p>AWS automatically enables AWS CloudTrail for AWS GovCloud (US) accounts, but you should also do the following:</p> <ul> <li> <p>Verify that AWS CloudTrail is enabled to store logs.</p> </li> <li> <p>Create an S3 bucket for AWS CloudTrail log storage.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/verifying-cloudtrail.html">Verifying AWS CloudTrail Is Enabled</a> in the <i>AWS GovCloud User Guide</i>. </p> </li> </ul> <p>You call this action from the master account of your organization in the commercial Region to create a standalone AWS account in the AWS GovCloud (US) Region. After the account is created, the master account of an organization in the AWS GovCloud (US) Region can invite it to that organization. For more information on inviting standalone accounts in the AWS GovCloud (US) to join an organization, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>Calling <code>CreateGovCloudAccount</code> is an asynchronous request that AWS performs in the background. Because <code>CreateGovCloudAccount</code> operates asynchronously, it can return a successful completion message even though account initialization might still be in progress. You might need to wait a few minutes before you can successfully access the account. To check the status of the request, do one of the following:</p> <ul> <li> <p>Use the <code>OperationId</code> response element from this operation to provide as a parameter to the <a>DescribeCreateAccountStatus</a> operation.</p> </li> <li> <p>Check the AWS CloudTrail log for the <code>CreateAccountResult</code> event. For information on using AWS CloudTrail with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_monitoring.html">Monitoring the Activity in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <p/> <p>When you call the <code>CreateGovCloudAccount</code> action, you create two accounts: a standalone account in the AWS GovCloud (US) Region and an associated account in the commercial Region for billing and support purposes. The account in the commercial Region is automatically a member of the organization whose credentials made the request. Both accounts are associated with the same email address.</p> <p>A role is created in the new account in the commercial Region that allows the master account in the organization in the commercial Region to assume it. An AWS GovCloud (US) account is then created and associated with the commercial account that you just created. A role is created in the new AWS GovCloud (US) account that can be assumed by the AWS GovCloud (US) account that is associated with the master account of the commercial organization. For more information and to view a diagram that explains how account access works, see <a href="http://docs.aws.amazon.com/govcloud-us/latest/UserGuide/govcloud-organizations.html">AWS Organizations</a> in the <i>AWS GovCloud User Guide.</i> </p> <p>For more information about creating accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_create.html">Creating an AWS Account in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <ul> <li> <p>When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required for the account to operate as a standalone account is <i>not</i> automatically collected. This includes a payment method and signing the end user license agreement (EULA). If you must remove an account from your organization later, you can do so only after you provide the missing information. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization as a member account</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>If you get an exception that indicates that you exceeded your account limits for the organization, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>If you get an exception that indicates that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> <li> <p>Using <code>CreateGovCloudAccount</code> to create multiple temporary accounts isn&#39;t recommended. You can only close an account from the AWS Billing and Cost Management console, and you must be signed in as the root user. For information on the requirements and process for closing an account, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_close.html">Closing an AWS Account</a> in the <i>AWS Organizations User Guide</i>.</p> </li> </ul> </important> <note> <p>When you create a member account with this operation, you can choose whether to create the account with the <b>IAM User and Role Access to Billing Information</b> switch enabled. If you enable it, IAM users and roles that have appropriate permissions can view billing information for the account. If you disable it, only the account root user can access billing information. For information about how to disable this switch for an account, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html">Granting Access to Your Billing Information and Tools</a>.</p> </note></p>
+    ///
+    /// See `Organizations::create_gov_cloud_account`.
+    pub fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
+        self.runtime
+            .block_on(self.client.create_gov_cloud_account(input))
+    }
+
+    /// <p>Creates an AWS organization. The account whose user is calling the <code>CreateOrganization</code> operation automatically becomes the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/orgs_getting-started_concepts.html#account">master account</a> of the new organization.</p> <p>This operation must be called using credentials from the account that is to become the new organization's master account. The principal must also have the relevant IAM permissions.</p> <p>By default (or if you set the <code>FeatureSet</code> parameter to <code>ALL</code>), the new organization is created with all features enabled and service control policies automatically enabled in the root. If you instead choose to create the organization supporting only the consolidated billing features by setting the <code>FeatureSet</code> parameter to <code>CONSOLIDATED_BILLING"</code>, no policy types are enabled by default, and you can't use organization policies</p>
+    ///
+    /// See `Organizations::create_organization`.
+    pub fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
+        self.runtime
+            .block_on(self.client.create_organization(input))
+    }
+
+    /// <p>Creates an organizational unit (OU) within a root or parent OU. An OU is a container for accounts that enables you to organize your accounts to apply policies according to your business requirements. The number of levels deep that you can nest OUs is dependent upon the policy types enabled for that root. For service control policies, the limit is five. </p> <p>For more information about OUs, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_ous.html">Managing Organizational Units</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::create_organizational_unit`.
+    pub fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
+        self.runtime
+            .block_on(self.client.create_organizational_unit(input))
+    }
+
+    /// <p>Creates a policy of a specified type that you can attach to a root, an organizational unit (OU), or an individual AWS account.</p> <p>For more information about policies and their use, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies.html">Managing Organization Policies</a>.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::create_policy`.
+    pub fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.runtime.block_on(self.client.create_policy(input))
+    }
+
+    /// <p>Declines a handshake request. This sets the handshake state to <code>DECLINED</code> and effectively deactivates the request.</p> <p>This operation can be called only from the account that received the handshake. The originator of the handshake can use <a>CancelHandshake</a> instead. The originator can't reactivate a declined request, but can reinitiate the process with a new handshake request.</p> <p>After you decline a handshake, it continues to appear in the results of relevant APIs for only 30 days. After that, it's deleted.</p>
+    ///
+    /// See `Organizations::decline_handshake`.
+    pub fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
+        self.runtime.block_on(self.client.decline_handshake(input))
+    }
+
+    /// <p>Deletes the organization. You can delete an organization only by using credentials from the master account. The organization must be empty of member accounts.</p>
+    ///
+    /// See `Organizations::delete_organization`.
+    pub fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
+        self.runtime.block_on(self.client.delete_organization())
+    }
+
+    /// <p>Deletes an organizational unit (OU) from a root or another OU. You must first remove all accounts and child OUs from the OU that you want to delete.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::delete_organizational_unit`.
+    pub fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
+        self.runtime
+            .block_on(self.client.delete_organizational_unit(input))
+    }
+
+    /// <p>Deletes the specified policy from your organization. Before you perform this operation, you must first detach the policy from all organizational units (OUs), roots, and accounts.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::delete_policy`.
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// <p>Removes the specified member AWS account as a delegated administrator for the specified AWS service.</p> <p>You can run this action only for AWS services that support this feature. For a current list of services that support it, see the column <i>Supports Delegated Administrator</i> in the table at <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrated-services-list.html">AWS Services that you can use with AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::deregister_delegated_administrator`.
+    pub fn deregister_delegated_administrator(
+        &self,
+        input: DeregisterDelegatedAdministratorRequest,
+    ) -> Result<(), RusotoError<DeregisterDelegatedAdministratorError>> {
+        self.runtime
+            .block_on(self.client.deregister_delegated_administrator(input))
+    }
+
+    /// <p>Retrieves AWS Organizations-related information about the specified account.</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::describe_account`.
+    pub fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
+        self.runtime.block_on(self.client.describe_account(input))
+    }
+
+    /// <p>Retrieves the current status of an asynchronous request to create an account.</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::describe_create_account_status`.
+    pub fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_create_account_status(input))
+    }
+
+    /// <p>Returns the contents of the effective policy for specified policy type and account. The effective policy is the aggregation of any policies of the specified type that the account inherits, plus any policy of that type that is directly attached to the account. </p> <p>This operation applies only to policy types <i>other</i> than service control policies (SCPs).</p> <p>For more information about policy inheritance, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies-inheritance.html">How Policy Inheritance Works</a> in the <i>AWS Organizations User Guide</i>.</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::describe_effective_policy`.
+    pub fn describe_effective_policy(
+        &self,
+        input: DescribeEffectivePolicyRequest,
+    ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
+        self.runtime
+            .block_on(self.client.describe_effective_policy(input))
+    }
+
+    /// <p>Retrieves information about a previously requested handshake. The handshake ID comes from the response to the original <a>InviteAccountToOrganization</a> operation that generated the handshake.</p> <p>You can access handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> for only 30 days after they change to that state. They're then deleted and no longer accessible.</p> <p>This operation can be called from any account in the organization.</p>
+    ///
+    /// See `Organizations::describe_handshake`.
+    pub fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
+        self.runtime.block_on(self.client.describe_handshake(input))
+    }
+
+    /// <p><p>Retrieves information about the organization that the user&#39;s account belongs to.</p> <p>This operation can be called from any account in the organization.</p> <note> <p>Even if a policy type is shown as available in the organization, you can disable it separately at the root level with <a>DisablePolicyType</a>. Use <a>ListRoots</a> to see the status of policy types for a specified root.</p> </note></p>
+    ///
+    /// See `Organizations::describe_organization`.
+    pub fn describe_organization(
+        &self,
+    ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
+        self.runtime.block_on(self.client.describe_organization())
+    }
+
+    /// <p>Retrieves information about an organizational unit (OU).</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::describe_organizational_unit`.
+    pub fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_organizational_unit(input))
+    }
+
+    /// <p>Retrieves information about a policy.</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::describe_policy`.
+    pub fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
+        self.runtime.block_on(self.client.describe_policy(input))
+    }
+
+    /// <p>Detaches a policy from a target root, organizational unit (OU), or account. </p> <important> <p>If the policy being detached is a service control policy (SCP), the changes to permissions for AWS Identity and Access Management (IAM) users and roles in affected accounts are immediate.</p> </important> <p>Every root, OU, and account must have at least one SCP attached. If you want to replace the default <code>FullAWSAccess</code> policy with an SCP that limits the permissions that can be delegated, you must attach the replacement SCP before you can remove the default SCP. This is the authorization strategy of an "<a href="https://docs.aws.amazon.com/organizations/latest/userguide/SCP_strategies.html#orgs_policies_allowlist">allow list</a>". If you instead attach a second SCP and leave the <code>FullAWSAccess</code> SCP still attached, and specify <code>"Effect": "Deny"</code> in the second SCP to override the <code>"Effect": "Allow"</code> in the <code>FullAWSAccess</code> policy (or any other attached SCP), you're using the authorization strategy of a "<a href="https://docs.aws.amazon.com/organizations/latest/userguide/SCP_strategies.html#orgs_policies_denylist">deny list</a>".</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::detach_policy`.
+    pub fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPolicyError>> {
+        self.runtime.block_on(self.client.detach_policy(input))
+    }
+
+    /// <p>Disables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you disable integration, the specified service no longer can create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in <i>new</i> accounts in your organization. This means the service can't perform operations on your behalf on any new accounts in your organization. The service can still perform operations in older accounts until the service completes its clean-up from AWS Organizations.</p> <p/> <important> <p>We recommend that you disable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the other service is aware that it can clean up any resources that are required only for the integration. How the service cleans up its resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>After you perform the <code>DisableAWSServiceAccess</code> operation, the specified service can no longer perform operations in your organization's accounts unless the operations are explicitly permitted by the IAM policies that are attached to your roles.</p> <p>For more information about integrating other services with AWS Organizations, including the list of services that work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::disable_aws_service_access`.
+    pub fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
+        self.runtime
+            .block_on(self.client.disable_aws_service_access(input))
+    }
+
+    /// <p>Disables an organizational policy type in a root. A policy of a certain type can be attached to entities in a root only if that type is enabled in the root. After you perform this operation, you no longer can attach policies of the specified type to that root or to any organizational unit (OU) or account in that root. You can undo this by using the <a>EnablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. If you disable a policy type for a root, it still appears enabled for the organization if <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">all features</a> are enabled for the organization. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p> To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
+    ///
+    /// See `Organizations::disable_policy_type`.
+    pub fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
+        self.runtime
+            .block_on(self.client.disable_policy_type(input))
+    }
+
+    /// <p>Enables the integration of an AWS service (the service that is specified by <code>ServicePrincipal</code>) with AWS Organizations. When you enable integration, you allow the specified service to create a <a href="http://docs.aws.amazon.com/IAM/latest/UserGuide/using-service-linked-roles.html">service-linked role</a> in all the accounts in your organization. This allows the service to perform operations on your behalf in your organization and its accounts.</p> <important> <p>We recommend that you enable integration between AWS Organizations and the specified AWS service by using the console or commands that are provided by the specified service. Doing so ensures that the service is aware that it can create the resources that are required for the integration. How the service creates those resources in the organization's accounts depends on that service. For more information, see the documentation for the other AWS service.</p> </important> <p>For more information about enabling services to integrate with AWS Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account and only if the organization has <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">enabled all features</a>.</p>
+    ///
+    /// See `Organizations::enable_aws_service_access`.
+    pub fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
+        self.runtime
+            .block_on(self.client.enable_aws_service_access(input))
+    }
+
+    /// <p>Enables all features in an organization. This enables the use of organization policies that can restrict the services and actions that can be called in each account. Until you enable all features, you have access only to consolidated billing, and you can't use any of the advanced account administration features that AWS Organizations supports. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide.</i> </p> <important> <p>This operation is required only for organizations that were created explicitly with only the consolidated billing features enabled. Calling this operation sends a handshake to every invited account in the organization. The feature set change can be finalized and the additional features enabled only after all administrators in the invited accounts approve the change by accepting the handshake.</p> </important> <p>After you enable all features, you can separately enable or disable individual policy types in a root using <a>EnablePolicyType</a> and <a>DisablePolicyType</a>. To see the status of policy types in a root, use <a>ListRoots</a>.</p> <p>After all invited member accounts accept the handshake, you finalize the feature set change by accepting the handshake that contains <code>"Action": "ENABLE_ALL_FEATURES"</code>. This completes the change.</p> <p>After you enable all features in your organization, the master account in the organization can apply policies on all member accounts. These policies can restrict what users and even administrators in those accounts can do. The master account can apply policies that prevent accounts from leaving the organization. Ensure that your account administrators are aware of this.</p> <p>This operation can be called only from the organization's master account. </p>
+    ///
+    /// See `Organizations::enable_all_features`.
+    pub fn enable_all_features(
+        &self,
+    ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
+        self.runtime.block_on(self.client.enable_all_features())
+    }
+
+    /// <p>Enables a policy type in a root. After you enable a policy type in a root, you can attach policies of that type to the root, any organizational unit (OU), or account in that root. You can undo this by using the <a>DisablePolicyType</a> operation.</p> <p>This is an asynchronous request that AWS performs in the background. AWS recommends that you first use <a>ListRoots</a> to see the status of policy types for a specified root, and then use this operation. </p> <p>This operation can be called only from the organization's master account.</p> <p>You can enable a policy type in a root only if that policy type is available in the organization. To view the status of available policy types in the organization, use <a>DescribeOrganization</a>.</p>
+    ///
+    /// See `Organizations::enable_policy_type`.
+    pub fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
+        self.runtime.block_on(self.client.enable_policy_type(input))
+    }
+
+    /// <p>Sends an invitation to another account to join your organization as a member account. AWS Organizations sends email on your behalf to the email address that is associated with the other account's owner. The invitation is implemented as a <a>Handshake</a> whose details are in the response.</p> <important> <ul> <li> <p>You can invite AWS accounts only from the same seller as the master account. For example, if your organization's master account was created by Amazon Internet Services Pvt. Ltd (AISPL), an AWS seller in India, you can invite only other AISPL accounts to your organization. You can't combine accounts from AISPL and AWS or from any other AWS seller. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/useconsolidatedbilliing-India.html">Consolidated Billing in India</a>.</p> </li> <li> <p>If you receive an exception that indicates that you exceeded your account limits for the organization or that the operation failed because your organization is still initializing, wait one hour and then try again. If the error persists after an hour, contact <a href="https://console.aws.amazon.com/support/home#/">AWS Support</a>.</p> </li> </ul> </important> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::invite_account_to_organization`.
+    pub fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
+    {
+        self.runtime
+            .block_on(self.client.invite_account_to_organization(input))
+    }
+
+    /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the master account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The master account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do. This includes preventing them from successfully calling <code>LeaveOrganization</code> and leaving the organization. </p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must do the following steps:</p> <ul> <li> <p>Accept the end user license agreement (EULA)</p> </li> <li> <p>Choose a support plan</p> </li> <li> <p>Provide and verify the required contact information</p> </li> <li> <p>Provide a current payment method</p> </li> </ul> <p>AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> </ul> </important></p>
+    ///
+    /// See `Organizations::leave_organization`.
+    pub fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
+        self.runtime.block_on(self.client.leave_organization())
+    }
+
+    /// <p>Returns a list of the AWS services that you enabled to integrate with your organization. After a service on this list creates the resources that it requires for the integration, it can perform operations on your organization and its accounts.</p> <p>For more information about integrating other services with AWS Organizations, including the list of services that currently work with Organizations, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrate_services.html">Integrating AWS Organizations with Other AWS Services</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_aws_service_access_for_organization`.
+    pub fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> Result<
+        ListAWSServiceAccessForOrganizationResponse,
+        RusotoError<ListAWSServiceAccessForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_aws_service_access_for_organization(input))
+    }
+
+    /// <p>Lists all the accounts in the organization. To request only the accounts in a specified root or organizational unit (OU), use the <a>ListAccountsForParent</a> operation instead.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_accounts`.
+    pub fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.runtime.block_on(self.client.list_accounts(input))
+    }
+
+    /// <p>Lists the accounts in an organization that are contained by the specified target root or organizational unit (OU). If you specify the root, you get a list of all the accounts that aren't in any OU. If you specify an OU, you get a list of all the accounts in only that OU and not in any child OUs. To get a list of all accounts in the organization, use the <a>ListAccounts</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_accounts_for_parent`.
+    pub fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
+        self.runtime
+            .block_on(self.client.list_accounts_for_parent(input))
+    }
+
+    /// <p>Lists all of the organizational units (OUs) or accounts that are contained in the specified parent OU or root. This operation, along with <a>ListParents</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_children`.
+    pub fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
+        self.runtime.block_on(self.client.list_children(input))
+    }
+
+    /// <p>Lists the account creation requests that match the specified status that is currently being tracked for the organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_create_account_status`.
+    pub fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
+        self.runtime
+            .block_on(self.client.list_create_account_status(input))
+    }
+
+    /// <p>Lists the AWS accounts that are designated as delegated administrators in this organization.</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_delegated_administrators`.
+    pub fn list_delegated_administrators(
+        &self,
+        input: ListDelegatedAdministratorsRequest,
+    ) -> Result<ListDelegatedAdministratorsResponse, RusotoError<ListDelegatedAdministratorsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_delegated_administrators(input))
+    }
+
+    /// <p>List the AWS services for which the specified account is a delegated administrator. </p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_delegated_services_for_account`.
+    pub fn list_delegated_services_for_account(
+        &self,
+        input: ListDelegatedServicesForAccountRequest,
+    ) -> Result<
+        ListDelegatedServicesForAccountResponse,
+        RusotoError<ListDelegatedServicesForAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_delegated_services_for_account(input))
+    }
+
+    /// <p>Lists the current handshakes that are associated with the account of the requesting user.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_handshakes_for_account`.
+    pub fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
+        self.runtime
+            .block_on(self.client.list_handshakes_for_account(input))
+    }
+
+    /// <p>Lists the handshakes that are associated with the organization that the requesting user is part of. The <code>ListHandshakesForOrganization</code> operation returns a list of handshake structures. Each structure contains details and status about a handshake.</p> <p>Handshakes that are <code>ACCEPTED</code>, <code>DECLINED</code>, or <code>CANCELED</code> appear in the results of this API for only 30 days after changing to that state. After that, they're deleted and no longer accessible.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_handshakes_for_organization`.
+    pub fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> Result<
+        ListHandshakesForOrganizationResponse,
+        RusotoError<ListHandshakesForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_handshakes_for_organization(input))
+    }
+
+    /// <p>Lists the organizational units (OUs) in a parent organizational unit or root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_organizational_units_for_parent`.
+    pub fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> Result<
+        ListOrganizationalUnitsForParentResponse,
+        RusotoError<ListOrganizationalUnitsForParentError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organizational_units_for_parent(input))
+    }
+
+    /// <p><p>Lists the root or organizational units (OUs) that serve as the immediate parent of the specified child OU or account. This operation, along with <a>ListChildren</a> enables you to traverse the tree structure that makes up this root.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account or by a member account that is a delegated administrator for an AWS service.</p> <note> <p>In the current release, a child can have only a single parent. </p> </note></p>
+    ///
+    /// See `Organizations::list_parents`.
+    pub fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
+        self.runtime.block_on(self.client.list_parents(input))
+    }
+
+    /// <p>Retrieves the list of all policies in an organization of a specified type.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_policies`.
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// <p>Lists the policies that are directly attached to the specified target root, organizational unit (OU), or account. You must specify the policy type that you want included in the returned list.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_policies_for_target`.
+    pub fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
+        self.runtime
+            .block_on(self.client.list_policies_for_target(input))
+    }
+
+    /// <p><p>Lists the roots that are defined in the current organization.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization&#39;s master account or by a member account that is a delegated administrator for an AWS service.</p> <note> <p>Policy types can be enabled and disabled in roots. This is distinct from whether they&#39;re available in the organization. When you enable all features, you make policy types available for use in that organization. Individual policy types can then be enabled and disabled in a root. To see the availability of a policy type in an organization, use <a>DescribeOrganization</a>.</p> </note></p>
+    ///
+    /// See `Organizations::list_roots`.
+    pub fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
+        self.runtime.block_on(self.client.list_roots(input))
+    }
+
+    /// <p>Lists tags for the specified resource. </p> <p>Currently, you can list tags on an account in AWS Organizations.</p> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists all the roots, organizational units (OUs), and accounts that the specified policy is attached to.</p> <note> <p>Always check the <code>NextToken</code> response parameter for a <code>null</code> value when calling a <code>List*</code> operation. These operations can occasionally return an empty set of results even when there are more results available. The <code>NextToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display.</p> </note> <p>This operation can be called only from the organization's master account or by a member account that is a delegated administrator for an AWS service.</p>
+    ///
+    /// See `Organizations::list_targets_for_policy`.
+    pub fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
+        self.runtime
+            .block_on(self.client.list_targets_for_policy(input))
+    }
+
+    /// <p>Moves an account from its current source parent root or organizational unit (OU) to the specified destination parent root or OU.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::move_account`.
+    pub fn move_account(
+        &self,
+        input: MoveAccountRequest,
+    ) -> Result<(), RusotoError<MoveAccountError>> {
+        self.runtime.block_on(self.client.move_account(input))
+    }
+
+    /// <p>Enables the specified member account to administer the Organizations features of the specified AWS service. It grants read-only access to AWS Organizations service data. The account still requires IAM permissions to access and administer the AWS service.</p> <p>You can run this action only for AWS services that support this feature. For a current list of services that support it, see the column <i>Supports Delegated Administrator</i> in the table at <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_integrated-services-list.html">AWS Services that you can use with AWS Organizations</a> in the <i>AWS Organizations User Guide.</i> </p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::register_delegated_administrator`.
+    pub fn register_delegated_administrator(
+        &self,
+        input: RegisterDelegatedAdministratorRequest,
+    ) -> Result<(), RusotoError<RegisterDelegatedAdministratorError>> {
+        self.runtime
+            .block_on(self.client.register_delegated_administrator(input))
+    }
+
+    /// <p><p>Removes the specified account from the organization.</p> <p>The removed account becomes a standalone account that isn&#39;t a member of any organization. It&#39;s no longer subject to any policies and is responsible for its own bill payments. The organization&#39;s master account is no longer charged for any expenses accrued by the member account after it&#39;s removed from the organization.</p> <p>This operation can be called only from the organization&#39;s master account. Member accounts can remove themselves with <a>LeaveOrganization</a> instead.</p> <important> <p>You can remove an account from your organization only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For an account that you want to make standalone, you must accept the end user license agreement (EULA), choose a support plan, provide and verify the required contact information, and provide a current payment method. AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. To remove an account that doesn&#39;t yet have this information, you must sign in as the member account and follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </important></p>
+    ///
+    /// See `Organizations::remove_account_from_organization`.
+    pub fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
+        self.runtime
+            .block_on(self.client.remove_account_from_organization(input))
+    }
+
+    /// <p>Adds one or more tags to the specified resource.</p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a tag from the specified resource. </p> <p>Currently, you can tag and untag accounts in AWS Organizations.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Renames the specified organizational unit (OU). The ID and ARN don't change. The child OUs and accounts remain in place, and any attached policies of the OU remain attached. </p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::update_organizational_unit`.
+    pub fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
+        self.runtime
+            .block_on(self.client.update_organizational_unit(input))
+    }
+
+    /// <p>Updates an existing policy with a new name, description, or content. If you don't supply any parameter, that value remains unchanged. You can't change a policy's type.</p> <p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `Organizations::update_policy`.
+    pub fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
+        self.runtime.block_on(self.client.update_policy(input))
+    }
+}
@@ -241,16 +241,19 @@ impl CreateOutpostError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOutpostError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateOutpostError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateOutpostError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateOutpostError::InternalServer(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateOutpostError::NotFound(err.msg))
+                    return RusotoError::service(&res, CreateOutpostError::NotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateOutpostError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOutpostError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -287,13 +290,13 @@ impl DeleteOutpostError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOutpostError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteOutpostError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteOutpostError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteOutpostError::InternalServer(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteOutpostError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteOutpostError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -329,13 +332,13 @@ impl DeleteSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSiteError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSiteError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::InternalServer(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSiteError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSiteError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -371,13 +374,13 @@ impl GetOutpostError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOutpostError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetOutpostError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutpostError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetOutpostError::InternalServer(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOutpostError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetOutpostError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -413,17 +416,22 @@ impl GetOutpostInstanceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOutpostInstanceTypesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOutpostInstanceTypesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutpostInstanceTypesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOutpostInstanceTypesError::InternalServer(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOutpostInstanceTypesError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOutpostInstanceTypesError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -457,10 +465,10 @@ impl ListOutpostsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListOutpostsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListOutpostsError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListOutpostsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListOutpostsError::InternalServer(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -493,10 +501,10 @@ impl ListSitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSitesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListSitesError::AccessDenied(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSitesError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListSitesError::InternalServer(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -578,6 +586,7 @@ impl OutpostsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -593,6 +602,7 @@ impl OutpostsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> OutpostsClient {
         OutpostsClient { client, region }
     }
@@ -609,6 +619,9 @@ impl Outposts for OutpostsClient {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("POST", "outposts", &self.region, &request_uri);
+        request.set_operation_name("CreateOutpost");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -640,6 +653,9 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/outposts/{outpost_id}", outpost_id = input.outpost_id);
 
         let mut request = SignedRequest::new("DELETE", "outposts", &self.region, &request_uri);
+        request.set_operation_name("DeleteOutpost");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -668,6 +684,9 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/sites/{site_id}", site_id = input.site_id);
 
         let mut request = SignedRequest::new("DELETE", "outposts", &self.region, &request_uri);
+        request.set_operation_name("DeleteSite");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -696,6 +715,9 @@ impl Outposts for OutpostsClient {
         let request_uri = format!("/outposts/{outpost_id}", outpost_id = input.outpost_id);
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation_name("GetOutpost");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -727,6 +749,9 @@ impl Outposts for OutpostsClient {
         );
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation_name("GetOutpostInstanceTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -764,6 +789,9 @@ impl Outposts for OutpostsClient {
         let request_uri = "/outposts";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation_name("ListOutposts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -801,6 +829,9 @@ impl Outposts for OutpostsClient {
         let request_uri = "/sites";
 
         let mut request = SignedRequest::new("GET", "outposts", &self.region, &request_uri);
+        request.set_operation_name("ListSites");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -829,3 +860,124 @@ impl Outposts for OutpostsClient {
         }
     }
 }
+
+/// A blocking client for the Outposts API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `OutpostsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OutpostsBlockingClient {
+    client: OutpostsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OutpostsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> OutpostsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        OutpostsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OutpostsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OutpostsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> OutpostsBlockingClient {
+        OutpostsBlockingClient {
+            client: OutpostsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an Outpost.</p>
+    ///
+    /// See `Outposts::create_outpost`.
+    pub fn create_outpost(
+        &self,
+        input: CreateOutpostInput,
+    ) -> Result<CreateOutpostOutput, RusotoError<CreateOutpostError>> {
+        self.runtime.block_on(self.client.create_outpost(input))
+    }
+
+    /// <p>Deletes the Outpost.</p>
+    ///
+    /// See `Outposts::delete_outpost`.
+    pub fn delete_outpost(
+        &self,
+        input: DeleteOutpostInput,
+    ) -> Result<DeleteOutpostOutput, RusotoError<DeleteOutpostError>> {
+        self.runtime.block_on(self.client.delete_outpost(input))
+    }
+
+    /// <p>Deletes the site.</p>
+    ///
+    /// See `Outposts::delete_site`.
+    pub fn delete_site(
+        &self,
+        input: DeleteSiteInput,
+    ) -> Result<DeleteSiteOutput, RusotoError<DeleteSiteError>> {
+        self.runtime.block_on(self.client.delete_site(input))
+    }
+
+    /// <p>Gets information about the specified Outpost.</p>
+    ///
+    /// See `Outposts::get_outpost`.
+    pub fn get_outpost(
+        &self,
+        input: GetOutpostInput,
+    ) -> Result<GetOutpostOutput, RusotoError<GetOutpostError>> {
+        self.runtime.block_on(self.client.get_outpost(input))
+    }
+
+    /// <p>Lists the instance types for the specified Outpost.</p>
+    ///
+    /// See `Outposts::get_outpost_instance_types`.
+    pub fn get_outpost_instance_types(
+        &self,
+        input: GetOutpostInstanceTypesInput,
+    ) -> Result<GetOutpostInstanceTypesOutput, RusotoError<GetOutpostInstanceTypesError>> {
+        self.runtime
+            .block_on(self.client.get_outpost_instance_types(input))
+    }
+
+    /// <p>List the Outposts for your AWS account.</p>
+    ///
+    /// See `Outposts::list_outposts`.
+    pub fn list_outposts(
+        &self,
+        input: ListOutpostsInput,
+    ) -> Result<ListOutpostsOutput, RusotoError<ListOutpostsError>> {
+        self.runtime.block_on(self.client.list_outposts(input))
+    }
+
+    /// <p>Lists the sites for the specified AWS account.</p>
+    ///
+    /// See `Outposts::list_sites`.
+    pub fn list_sites(
+        &self,
+        input: ListSitesInput,
+    ) -> Result<ListSitesOutput, RusotoError<ListSitesError>> {
+        self.runtime.block_on(self.client.list_sites(input))
+    }
+}
@@ -73,7 +73,7 @@ impl PutEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutEventsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, PutEventsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -115,6 +115,7 @@ impl PersonalizeEventsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -130,6 +131,7 @@ impl PersonalizeEventsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeEventsClient {
         PersonalizeEventsClient { client, region }
     }
@@ -143,6 +145,9 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         let request_uri = "/events";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation_name("PutEvents");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-events".to_string());
@@ -165,3 +170,63 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Personalize Events API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PersonalizeEventsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PersonalizeEventsBlockingClient {
+    client: PersonalizeEventsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PersonalizeEventsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PersonalizeEventsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PersonalizeEventsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PersonalizeEventsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PersonalizeEventsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient {
+            client: PersonalizeEventsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Records user interaction event data.</p>
+    ///
+    /// See `PersonalizeEvents::put_events`.
+    pub fn put_events(&self, input: PutEventsRequest) -> Result<(), RusotoError<PutEventsError>> {
+        self.runtime.block_on(self.client.put_events(input))
+    }
+}
@@ -116,12 +116,16 @@ impl GetPersonalizedRankingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPersonalizedRankingError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonalizedRankingError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPersonalizedRankingError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonalizedRankingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -154,10 +158,16 @@ impl GetRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRecommendationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecommendationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRecommendationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -209,6 +219,7 @@ impl PersonalizeRuntimeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -224,6 +235,7 @@ impl PersonalizeRuntimeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeRuntimeClient {
         PersonalizeRuntimeClient { client, region }
     }
@@ -240,6 +252,9 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/personalize-ranking";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation_name("GetPersonalizedRanking");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -272,6 +287,9 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/recommendations";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation_name("GetRecommendations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -295,3 +313,78 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Personalize Runtime API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PersonalizeRuntimeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PersonalizeRuntimeBlockingClient {
+    client: PersonalizeRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PersonalizeRuntimeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PersonalizeRuntimeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PersonalizeRuntimeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PersonalizeRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PersonalizeRuntimeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient {
+            client: PersonalizeRuntimeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Re-ranks a list of recommended items for the given user. The first item in the list is deemed the most likely item to be of interest to the user.</p> <note> <p>The solution backing the campaign must have been created using a recipe of type PERSONALIZED_RANKING.</p> </note></p>
+    ///
+    /// See `PersonalizeRuntime::get_personalized_ranking`.
+    pub fn get_personalized_ranking(
+        &self,
+        input: GetPersonalizedRankingRequest,
+    ) -> Result<GetPersonalizedRankingResponse, RusotoError<GetPersonalizedRankingError>> {
+        self.runtime
+            .block_on(self.client.get_personalized_ranking(input))
+    }
+
+    /// <p><p>Returns a list of recommended items. The required input depends on the recipe type used to create the solution backing the campaign, as follows:</p> <ul> <li> <p>RELATED<em>ITEMS - <code>itemId</code> required, <code>userId</code> not used</p> </li> <li> <p>USER</em>PERSONALIZATION - <code>itemId</code> optional, <code>userId</code> required</p> </li> </ul> <note> <p>Campaigns that are backed by a solution created using a recipe of type PERSONALIZED_RANKING use the API.</p> </note></p>
+    ///
+    /// See `PersonalizeRuntime::get_recommendations`.
+    pub fn get_recommendations(
+        &self,
+        input: GetRecommendationsRequest,
+    ) -> Result<GetRecommendationsResponse, RusotoError<GetRecommendationsError>> {
+        self.runtime
+            .block_on(self.client.get_recommendations(input))
+    }
+}
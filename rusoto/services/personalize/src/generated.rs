@@ -2180,29 +2180,34 @@ impl CreateBatchInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBatchInferenceJobError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBatchInferenceJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateBatchInferenceJobError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBatchInferenceJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateBatchInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2246,21 +2251,25 @@ impl CreateCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateCampaignError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCampaignError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateCampaignError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCampaignError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateCampaignError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCampaignError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2302,19 +2311,25 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateDatasetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2352,15 +2367,22 @@ impl CreateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2400,27 +2422,34 @@ impl CreateDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDatasetImportJobError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDatasetImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2462,21 +2491,34 @@ impl CreateEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventTrackerError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEventTrackerError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventTrackerError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventTrackerError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventTrackerError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEventTrackerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2516,16 +2558,19 @@ impl CreateFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateFilterError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateFilterError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateFilterError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFilterError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFilterError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateFilterError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2562,13 +2607,16 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSchemaError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2608,21 +2656,25 @@ impl CreateSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateSolutionError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSolutionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateSolutionError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSolutionError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateSolutionError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSolutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2660,15 +2712,22 @@ impl CreateSolutionVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSolutionVersionError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSolutionVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSolutionVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2704,13 +2763,16 @@ impl DeleteCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteCampaignError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteCampaignError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCampaignError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2746,13 +2808,16 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteDatasetError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2788,13 +2853,22 @@ impl DeleteDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetGroupError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDatasetGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2830,13 +2904,22 @@ impl DeleteEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventTrackerError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventTrackerError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEventTrackerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2870,10 +2953,10 @@ impl DeleteFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteFilterError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteFilterError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFilterError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2908,13 +2991,13 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2950,13 +3033,16 @@ impl DeleteSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteSolutionError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSolutionError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteSolutionError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSolutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2990,10 +3076,16 @@ impl DescribeAlgorithmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAlgorithmError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAlgorithmError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAlgorithmError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAlgorithmError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3026,14 +3118,16 @@ impl DescribeBatchInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeBatchInferenceJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBatchInferenceJobError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBatchInferenceJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeBatchInferenceJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3066,10 +3160,13 @@ impl DescribeCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeCampaignError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCampaignError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3102,10 +3199,13 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeDatasetError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3138,12 +3238,16 @@ impl DescribeDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetGroupError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3176,14 +3280,16 @@ impl DescribeDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetImportJobError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDatasetImportJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3216,12 +3322,16 @@ impl DescribeEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventTrackerError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventTrackerError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeEventTrackerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3256,12 +3366,14 @@ impl DescribeFeatureTransformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFeatureTransformationError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFeatureTransformationError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeFeatureTransformationError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3298,10 +3410,13 @@ impl DescribeFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFilterError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeFilterError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFilterError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3334,10 +3449,13 @@ impl DescribeRecipeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeRecipeError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeRecipeError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecipeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecipeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3370,10 +3488,13 @@ impl DescribeSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeSchemaError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSchemaError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3406,10 +3527,13 @@ impl DescribeSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeSolutionError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSolutionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3442,14 +3566,16 @@ impl DescribeSolutionVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSolutionVersionError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSolutionVersionError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSolutionVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSolutionVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3484,13 +3610,22 @@ impl GetSolutionMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSolutionMetricsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSolutionMetricsError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSolutionMetricsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3524,12 +3659,16 @@ impl ListBatchInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBatchInferenceJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListBatchInferenceJobsError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListBatchInferenceJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBatchInferenceJobsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3562,10 +3701,13 @@ impl ListCampaignsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCampaignsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListCampaignsError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCampaignsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCampaignsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3596,7 +3738,10 @@ impl ListDatasetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetGroupsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3628,12 +3773,16 @@ impl ListDatasetImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetImportJobsError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDatasetImportJobsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3666,10 +3815,10 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListDatasetsError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3702,10 +3851,16 @@ impl ListEventTrackersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListEventTrackersError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventTrackersError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEventTrackersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEventTrackersError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3738,10 +3893,10 @@ impl ListFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListFiltersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListFiltersError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFiltersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListFiltersError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3772,7 +3927,7 @@ impl ListRecipesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListRecipesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListRecipesError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3802,7 +3957,7 @@ impl ListSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSchemasError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListSchemasError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3836,17 +3991,22 @@ impl ListSolutionVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSolutionVersionsError::InvalidInput(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSolutionVersionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSolutionVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3880,10 +4040,13 @@ impl ListSolutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSolutionsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListSolutionsError::InvalidInput(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSolutionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSolutionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3918,13 +4081,16 @@ impl UpdateCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateCampaignError::InvalidInput(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateCampaignError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateCampaignError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4226,6 +4392,7 @@ impl PersonalizeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4241,6 +4408,7 @@ impl PersonalizeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeClient {
         PersonalizeClient { client, region }
     }
@@ -4254,6 +4422,9 @@ impl Personalize for PersonalizeClient {
         input: CreateBatchInferenceJobRequest,
     ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateBatchInferenceJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateBatchInferenceJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4273,6 +4444,9 @@ impl Personalize for PersonalizeClient {
         input: CreateCampaignRequest,
     ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCampaign");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4291,6 +4465,9 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetRequest,
     ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4309,6 +4486,9 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetGroupRequest,
     ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDatasetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4327,6 +4507,9 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetImportJobRequest,
     ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDatasetImportJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4346,6 +4529,9 @@ impl Personalize for PersonalizeClient {
         input: CreateEventTrackerRequest,
     ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEventTracker");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4364,6 +4550,9 @@ impl Personalize for PersonalizeClient {
         input: CreateFilterRequest,
     ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4382,6 +4571,9 @@ impl Personalize for PersonalizeClient {
         input: CreateSchemaRequest,
     ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4400,6 +4592,9 @@ impl Personalize for PersonalizeClient {
         input: CreateSolutionRequest,
     ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSolution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4418,6 +4613,9 @@ impl Personalize for PersonalizeClient {
         input: CreateSolutionVersionRequest,
     ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSolutionVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolutionVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4437,6 +4635,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteCampaignRequest,
     ) -> Result<(), RusotoError<DeleteCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCampaign");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4454,6 +4655,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteDatasetRequest,
     ) -> Result<(), RusotoError<DeleteDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDataset");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4471,6 +4675,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteDatasetGroupRequest,
     ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDatasetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4488,6 +4695,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteEventTrackerRequest,
     ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEventTracker");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4505,6 +4715,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteFilterRequest,
     ) -> Result<(), RusotoError<DeleteFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4522,6 +4735,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteSchemaRequest,
     ) -> Result<(), RusotoError<DeleteSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4539,6 +4755,9 @@ impl Personalize for PersonalizeClient {
         input: DeleteSolutionRequest,
     ) -> Result<(), RusotoError<DeleteSolutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSolution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4556,6 +4775,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeAlgorithmRequest,
     ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAlgorithm");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4575,6 +4797,9 @@ impl Personalize for PersonalizeClient {
     ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBatchInferenceJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonPersonalize.DescribeBatchInferenceJob",
@@ -4597,6 +4822,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeCampaignRequest,
     ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCampaign");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4615,6 +4843,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetRequest,
     ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDataset");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4633,6 +4864,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetGroupRequest,
     ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDatasetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4652,6 +4886,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetImportJobRequest,
     ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDatasetImportJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4671,6 +4908,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeEventTrackerRequest,
     ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEventTracker");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4693,6 +4933,9 @@ impl Personalize for PersonalizeClient {
         RusotoError<DescribeFeatureTransformationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFeatureTransformation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonPersonalize.DescribeFeatureTransformation",
@@ -4715,6 +4958,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeFilterRequest,
     ) -> Result<DescribeFilterResponse, RusotoError<DescribeFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFilter");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4733,6 +4979,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeRecipeRequest,
     ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRecipe");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeRecipe");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4751,6 +5000,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeSchemaRequest,
     ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSchema");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4769,6 +5021,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeSolutionRequest,
     ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSolution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4787,6 +5042,9 @@ impl Personalize for PersonalizeClient {
         input: DescribeSolutionVersionRequest,
     ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSolutionVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolutionVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4806,6 +5064,9 @@ impl Personalize for PersonalizeClient {
         input: GetSolutionMetricsRequest,
     ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSolutionMetrics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.GetSolutionMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4824,6 +5085,9 @@ impl Personalize for PersonalizeClient {
         input: ListBatchInferenceJobsRequest,
     ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBatchInferenceJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListBatchInferenceJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4843,6 +5107,9 @@ impl Personalize for PersonalizeClient {
         input: ListCampaignsRequest,
     ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCampaigns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListCampaigns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4861,6 +5128,9 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetGroupsRequest,
     ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatasetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4879,6 +5149,9 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetImportJobsRequest,
     ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatasetImportJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetImportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4898,6 +5171,9 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDatasets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4916,6 +5192,9 @@ impl Personalize for PersonalizeClient {
         input: ListEventTrackersRequest,
     ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEventTrackers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListEventTrackers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4934,6 +5213,9 @@ impl Personalize for PersonalizeClient {
         input: ListFiltersRequest,
     ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4952,6 +5234,9 @@ impl Personalize for PersonalizeClient {
         input: ListRecipesRequest,
     ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRecipes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListRecipes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4970,6 +5255,9 @@ impl Personalize for PersonalizeClient {
         input: ListSchemasRequest,
     ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSchemas");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4988,6 +5276,9 @@ impl Personalize for PersonalizeClient {
         input: ListSolutionVersionsRequest,
     ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSolutionVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutionVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5007,6 +5298,9 @@ impl Personalize for PersonalizeClient {
         input: ListSolutionsRequest,
     ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSolutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5025,6 +5319,9 @@ impl Personalize for PersonalizeClient {
         input: UpdateCampaignRequest,
     ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCampaign");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonPersonalize.UpdateCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5037,3 +5334,506 @@ impl Personalize for PersonalizeClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateCampaignResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Personalize API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PersonalizeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PersonalizeBlockingClient {
+    client: PersonalizeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PersonalizeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PersonalizeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PersonalizeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PersonalizeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PersonalizeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient {
+            client: PersonalizeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a batch inference job. The operation can handle up to 50 million records and the input file must be in JSON format. For more information, see <a>recommendations-batch</a>.</p>
+    ///
+    /// See `Personalize::create_batch_inference_job`.
+    pub fn create_batch_inference_job(
+        &self,
+        input: CreateBatchInferenceJobRequest,
+    ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.create_batch_inference_job(input))
+    }
+
+    /// <p><p>Creates a campaign by deploying a solution version. When a client calls the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> and <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetPersonalizedRanking.html">GetPersonalizedRanking</a> APIs, a campaign is specified in the request.</p> <p> <b>Minimum Provisioned TPS and Auto-Scaling</b> </p> <p>A transaction is a single <code>GetRecommendations</code> or <code>GetPersonalizedRanking</code> call. Transactions per second (TPS) is the throughput and unit of billing for Amazon Personalize. The minimum provisioned TPS (<code>minProvisionedTPS</code>) specifies the baseline throughput provisioned by Amazon Personalize, and thus, the minimum billing charge. If your TPS increases beyond <code>minProvisionedTPS</code>, Amazon Personalize auto-scales the provisioned capacity up and down, but never below <code>minProvisionedTPS</code>, to maintain a 70% utilization. There&#39;s a short time delay while the capacity is increased that might cause loss of transactions. It&#39;s recommended to start with a low <code>minProvisionedTPS</code>, track your usage using Amazon CloudWatch metrics, and then increase the <code>minProvisionedTPS</code> as necessary.</p> <p> <b>Status</b> </p> <p>A campaign can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the campaign status, call <a>DescribeCampaign</a>.</p> <note> <p>Wait until the <code>status</code> of the campaign is <code>ACTIVE</code> before asking the campaign for recommendations.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListCampaigns</a> </p> </li> <li> <p> <a>DescribeCampaign</a> </p> </li> <li> <p> <a>UpdateCampaign</a> </p> </li> <li> <p> <a>DeleteCampaign</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_campaign`.
+    pub fn create_campaign(
+        &self,
+        input: CreateCampaignRequest,
+    ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
+        self.runtime.block_on(self.client.create_campaign(input))
+    }
+
+    /// <p><p>Creates an empty dataset and adds it to the specified dataset group. Use <a>CreateDatasetImportJob</a> to import your training data to a dataset.</p> <p>There are three types of datasets:</p> <ul> <li> <p>Interactions</p> </li> <li> <p>Items</p> </li> <li> <p>Users</p> </li> </ul> <p>Each dataset type has an associated schema with required field types. Only the <code>Interactions</code> dataset is required in order to train a model (also referred to as creating a solution).</p> <p>A dataset can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the status of the dataset, call <a>DescribeDataset</a>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>CreateDatasetGroup</a> </p> </li> <li> <p> <a>ListDatasets</a> </p> </li> <li> <p> <a>DescribeDataset</a> </p> </li> <li> <p> <a>DeleteDataset</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_dataset`.
+    pub fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.runtime.block_on(self.client.create_dataset(input))
+    }
+
+    /// <p><p>Creates an empty dataset group. A dataset group contains related datasets that supply data for training a model. A dataset group can contain at most three datasets, one for each type of dataset:</p> <ul> <li> <p>Interactions</p> </li> <li> <p>Items</p> </li> <li> <p>Users</p> </li> </ul> <p>To train a model (create a solution), a dataset group that contains an <code>Interactions</code> dataset is required. Call <a>CreateDataset</a> to add a dataset to the group.</p> <p>A dataset group can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING</p> </li> </ul> <p>To get the status of the dataset group, call <a>DescribeDatasetGroup</a>. If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the creation failed.</p> <note> <p>You must wait until the <code>status</code> of the dataset group is <code>ACTIVE</code> before adding a dataset to the group.</p> </note> <p>You can specify an AWS Key Management Service (KMS) key to encrypt the datasets in the group. If you specify a KMS key, you must also include an AWS Identity and Access Management (IAM) role that has permission to access the key.</p> <p class="title"> <b>APIs that require a dataset group ARN in the request</b> </p> <ul> <li> <p> <a>CreateDataset</a> </p> </li> <li> <p> <a>CreateEventTracker</a> </p> </li> <li> <p> <a>CreateSolution</a> </p> </li> </ul> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListDatasetGroups</a> </p> </li> <li> <p> <a>DescribeDatasetGroup</a> </p> </li> <li> <p> <a>DeleteDatasetGroup</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_dataset_group`.
+    pub fn create_dataset_group(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_group(input))
+    }
+
+    /// <p><p>Creates a job that imports training data from your data source (an Amazon S3 bucket) to an Amazon Personalize dataset. To allow Amazon Personalize to import the training data, you must specify an AWS Identity and Access Management (IAM) role that has permission to read from the data source.</p> <important> <p>The dataset import job replaces any previous data in the dataset.</p> </important> <p> <b>Status</b> </p> <p>A dataset import job can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul> <p>To get the status of the import job, call <a>DescribeDatasetImportJob</a>, providing the Amazon Resource Name (ARN) of the dataset import job. The dataset import is complete when the status shows as ACTIVE. If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the job failed.</p> <note> <p>Importing takes time. You must wait until the status shows as ACTIVE before training a model using the dataset.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListDatasetImportJobs</a> </p> </li> <li> <p> <a>DescribeDatasetImportJob</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_dataset_import_job`.
+    pub fn create_dataset_import_job(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_import_job(input))
+    }
+
+    /// <p><p>Creates an event tracker that you use when sending event data to the specified dataset group using the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_UBS_PutEvents.html">PutEvents</a> API.</p> <p>When Amazon Personalize creates an event tracker, it also creates an <i>event-interactions</i> dataset in the dataset group associated with the event tracker. The event-interactions dataset stores the event data from the <code>PutEvents</code> call. The contents of this dataset are not available to the user.</p> <note> <p>Only one event tracker can be associated with a dataset group. You will get an error if you call <code>CreateEventTracker</code> using the same dataset group as an existing event tracker.</p> </note> <p>When you send event data you include your tracking ID. The tracking ID identifies the customer and authorizes the customer to send the data.</p> <p>The event tracker can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN<em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN</em>PROGRESS</p> </li> </ul> <p>To get the status of the event tracker, call <a>DescribeEventTracker</a>.</p> <note> <p>The event tracker must be in the ACTIVE state before using the tracking ID.</p> </note> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListEventTrackers</a> </p> </li> <li> <p> <a>DescribeEventTracker</a> </p> </li> <li> <p> <a>DeleteEventTracker</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_event_tracker`.
+    pub fn create_event_tracker(
+        &self,
+        input: CreateEventTrackerRequest,
+    ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
+        self.runtime
+            .block_on(self.client.create_event_tracker(input))
+    }
+
+    /// <p>Creates a recommendation filter. For more information, see Using Filters with Amazon Personalize.</p>
+    ///
+    /// See `Personalize::create_filter`.
+    pub fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+        self.runtime.block_on(self.client.create_filter(input))
+    }
+
+    /// <p><p>Creates an Amazon Personalize schema from the specified schema string. The schema you create must be in Avro JSON format.</p> <p>Amazon Personalize recognizes three schema variants. Each schema is associated with a dataset type and has a set of required field and keywords. You specify a schema when you call <a>CreateDataset</a>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSchemas</a> </p> </li> <li> <p> <a>DescribeSchema</a> </p> </li> <li> <p> <a>DeleteSchema</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_schema`.
+    pub fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.runtime.block_on(self.client.create_schema(input))
+    }
+
+    /// <p><p>Creates the configuration for training a model. A trained model is known as a solution. After the configuration is created, you train the model (create a solution) by calling the <a>CreateSolutionVersion</a> operation. Every time you call <code>CreateSolutionVersion</code>, a new version of the solution is created.</p> <p>After creating a solution version, you check its accuracy by calling <a>GetSolutionMetrics</a>. When you are satisfied with the version, you deploy it using <a>CreateCampaign</a>. The campaign provides recommendations to a client through the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> API.</p> <p>To train a model, Amazon Personalize requires training data and a recipe. The training data comes from the dataset group that you provide in the request. A recipe specifies the training algorithm and a feature transformation. You can specify one of the predefined recipes provided by Amazon Personalize. Alternatively, you can specify <code>performAutoML</code> and Amazon Personalize will analyze your data and select the optimum USER<em>PERSONALIZATION recipe for you.</p> <p> <b>Status</b> </p> <p>A solution can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN</em>PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN_PROGRESS</p> </li> </ul> <p>To get the status of the solution, call <a>DescribeSolution</a>. Wait until the status shows as ACTIVE before calling <code>CreateSolutionVersion</code>.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSolutions</a> </p> </li> <li> <p> <a>CreateSolutionVersion</a> </p> </li> <li> <p> <a>DescribeSolution</a> </p> </li> <li> <p> <a>DeleteSolution</a> </p> </li> </ul> <ul> <li> <p> <a>ListSolutionVersions</a> </p> </li> <li> <p> <a>DescribeSolutionVersion</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_solution`.
+    pub fn create_solution(
+        &self,
+        input: CreateSolutionRequest,
+    ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
+        self.runtime.block_on(self.client.create_solution(input))
+    }
+
+    /// <p><p>Trains or retrains an active solution. A solution is created using the <a>CreateSolution</a> operation and must be in the ACTIVE state before calling <code>CreateSolutionVersion</code>. A new version of the solution is created every time you call this operation.</p> <p> <b>Status</b> </p> <p>A solution version can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> </ul> <p>To get the status of the version, call <a>DescribeSolutionVersion</a>. Wait until the status shows as ACTIVE before calling <code>CreateCampaign</code>.</p> <p>If the status shows as CREATE FAILED, the response includes a <code>failureReason</code> key, which describes why the job failed.</p> <p class="title"> <b>Related APIs</b> </p> <ul> <li> <p> <a>ListSolutionVersions</a> </p> </li> <li> <p> <a>DescribeSolutionVersion</a> </p> </li> </ul> <ul> <li> <p> <a>ListSolutions</a> </p> </li> <li> <p> <a>CreateSolution</a> </p> </li> <li> <p> <a>DescribeSolution</a> </p> </li> <li> <p> <a>DeleteSolution</a> </p> </li> </ul></p>
+    ///
+    /// See `Personalize::create_solution_version`.
+    pub fn create_solution_version(
+        &self,
+        input: CreateSolutionVersionRequest,
+    ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
+        self.runtime
+            .block_on(self.client.create_solution_version(input))
+    }
+
+    /// <p>Removes a campaign by deleting the solution deployment. The solution that the campaign is based on is not deleted and can be redeployed when needed. A deleted campaign can no longer be specified in a <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> request. For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    ///
+    /// See `Personalize::delete_campaign`.
+    pub fn delete_campaign(
+        &self,
+        input: DeleteCampaignRequest,
+    ) -> Result<(), RusotoError<DeleteCampaignError>> {
+        self.runtime.block_on(self.client.delete_campaign(input))
+    }
+
+    /// <p>Deletes a dataset. You can't delete a dataset if an associated <code>DatasetImportJob</code> or <code>SolutionVersion</code> is in the CREATE PENDING or IN PROGRESS state. For more information on datasets, see <a>CreateDataset</a>.</p>
+    ///
+    /// See `Personalize::delete_dataset`.
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// <p><p>Deletes a dataset group. Before you delete a dataset group, you must delete the following:</p> <ul> <li> <p>All associated event trackers.</p> </li> <li> <p>All associated solutions.</p> </li> <li> <p>All datasets in the dataset group.</p> </li> </ul></p>
+    ///
+    /// See `Personalize::delete_dataset_group`.
+    pub fn delete_dataset_group(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_group(input))
+    }
+
+    /// <p>Deletes the event tracker. Does not delete the event-interactions dataset from the associated dataset group. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
+    ///
+    /// See `Personalize::delete_event_tracker`.
+    pub fn delete_event_tracker(
+        &self,
+        input: DeleteEventTrackerRequest,
+    ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
+        self.runtime
+            .block_on(self.client.delete_event_tracker(input))
+    }
+
+    /// <p>Deletes a filter.</p>
+    ///
+    /// See `Personalize::delete_filter`.
+    pub fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> Result<(), RusotoError<DeleteFilterError>> {
+        self.runtime.block_on(self.client.delete_filter(input))
+    }
+
+    /// <p>Deletes a schema. Before deleting a schema, you must delete all datasets referencing the schema. For more information on schemas, see <a>CreateSchema</a>.</p>
+    ///
+    /// See `Personalize::delete_schema`.
+    pub fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+        self.runtime.block_on(self.client.delete_schema(input))
+    }
+
+    /// <p>Deletes all versions of a solution and the <code>Solution</code> object itself. Before deleting a solution, you must delete all campaigns based on the solution. To determine what campaigns are using the solution, call <a>ListCampaigns</a> and supply the Amazon Resource Name (ARN) of the solution. You can't delete a solution if an associated <code>SolutionVersion</code> is in the CREATE PENDING or IN PROGRESS state. For more information on solutions, see <a>CreateSolution</a>.</p>
+    ///
+    /// See `Personalize::delete_solution`.
+    pub fn delete_solution(
+        &self,
+        input: DeleteSolutionRequest,
+    ) -> Result<(), RusotoError<DeleteSolutionError>> {
+        self.runtime.block_on(self.client.delete_solution(input))
+    }
+
+    /// <p>Describes the given algorithm.</p>
+    ///
+    /// See `Personalize::describe_algorithm`.
+    pub fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmRequest,
+    ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
+        self.runtime.block_on(self.client.describe_algorithm(input))
+    }
+
+    /// <p>Gets the properties of a batch inference job including name, Amazon Resource Name (ARN), status, input and output configurations, and the ARN of the solution version used to generate the recommendations.</p>
+    ///
+    /// See `Personalize::describe_batch_inference_job`.
+    pub fn describe_batch_inference_job(
+        &self,
+        input: DescribeBatchInferenceJobRequest,
+    ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_batch_inference_job(input))
+    }
+
+    /// <p>Describes the given campaign, including its status.</p> <p>A campaign can be in one of the following states:</p> <ul> <li> <p>CREATE PENDING &gt; CREATE IN_PROGRESS &gt; ACTIVE -or- CREATE FAILED</p> </li> <li> <p>DELETE PENDING &gt; DELETE IN_PROGRESS</p> </li> </ul> <p>When the <code>status</code> is <code>CREATE FAILED</code>, the response includes the <code>failureReason</code> key, which describes why.</p> <p>For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    ///
+    /// See `Personalize::describe_campaign`.
+    pub fn describe_campaign(
+        &self,
+        input: DescribeCampaignRequest,
+    ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
+        self.runtime.block_on(self.client.describe_campaign(input))
+    }
+
+    /// <p>Describes the given dataset. For more information on datasets, see <a>CreateDataset</a>.</p>
+    ///
+    /// See `Personalize::describe_dataset`.
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// <p>Describes the given dataset group. For more information on dataset groups, see <a>CreateDatasetGroup</a>.</p>
+    ///
+    /// See `Personalize::describe_dataset_group`.
+    pub fn describe_dataset_group(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_group(input))
+    }
+
+    /// <p>Describes the dataset import job created by <a>CreateDatasetImportJob</a>, including the import job status.</p>
+    ///
+    /// See `Personalize::describe_dataset_import_job`.
+    pub fn describe_dataset_import_job(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_import_job(input))
+    }
+
+    /// <p>Describes an event tracker. The response includes the <code>trackingId</code> and <code>status</code> of the event tracker. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
+    ///
+    /// See `Personalize::describe_event_tracker`.
+    pub fn describe_event_tracker(
+        &self,
+        input: DescribeEventTrackerRequest,
+    ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
+        self.runtime
+            .block_on(self.client.describe_event_tracker(input))
+    }
+
+    /// <p>Describes the given feature transformation.</p>
+    ///
+    /// See `Personalize::describe_feature_transformation`.
+    pub fn describe_feature_transformation(
+        &self,
+        input: DescribeFeatureTransformationRequest,
+    ) -> Result<
+        DescribeFeatureTransformationResponse,
+        RusotoError<DescribeFeatureTransformationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_feature_transformation(input))
+    }
+
+    /// <p>Describes a filter's properties.</p>
+    ///
+    /// See `Personalize::describe_filter`.
+    pub fn describe_filter(
+        &self,
+        input: DescribeFilterRequest,
+    ) -> Result<DescribeFilterResponse, RusotoError<DescribeFilterError>> {
+        self.runtime.block_on(self.client.describe_filter(input))
+    }
+
+    /// <p>Describes a recipe.</p> <p>A recipe contains three items:</p> <ul> <li> <p>An algorithm that trains a model.</p> </li> <li> <p>Hyperparameters that govern the training.</p> </li> <li> <p>Feature transformation information for modifying the input data before training.</p> </li> </ul> <p>Amazon Personalize provides a set of predefined recipes. You specify a recipe when you create a solution with the <a>CreateSolution</a> API. <code>CreateSolution</code> trains a model by using the algorithm in the specified recipe and a training dataset. The solution, when deployed as a campaign, can provide recommendations using the <a href="https://docs.aws.amazon.com/personalize/latest/dg/API_RS_GetRecommendations.html">GetRecommendations</a> API.</p>
+    ///
+    /// See `Personalize::describe_recipe`.
+    pub fn describe_recipe(
+        &self,
+        input: DescribeRecipeRequest,
+    ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
+        self.runtime.block_on(self.client.describe_recipe(input))
+    }
+
+    /// <p>Describes a schema. For more information on schemas, see <a>CreateSchema</a>.</p>
+    ///
+    /// See `Personalize::describe_schema`.
+    pub fn describe_schema(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+        self.runtime.block_on(self.client.describe_schema(input))
+    }
+
+    /// <p>Describes a solution. For more information on solutions, see <a>CreateSolution</a>.</p>
+    ///
+    /// See `Personalize::describe_solution`.
+    pub fn describe_solution(
+        &self,
+        input: DescribeSolutionRequest,
+    ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
+        self.runtime.block_on(self.client.describe_solution(input))
+    }
+
+    /// <p>Describes a specific version of a solution. For more information on solutions, see <a>CreateSolution</a>.</p>
+    ///
+    /// See `Personalize::describe_solution_version`.
+    pub fn describe_solution_version(
+        &self,
+        input: DescribeSolutionVersionRequest,
+    ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
+        self.runtime
+            .block_on(self.client.describe_solution_version(input))
+    }
+
+    /// <p>Gets the metrics for the specified solution version.</p>
+    ///
+    /// See `Personalize::get_solution_metrics`.
+    pub fn get_solution_metrics(
+        &self,
+        input: GetSolutionMetricsRequest,
+    ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_solution_metrics(input))
+    }
+
+    /// <p>Gets a list of the batch inference jobs that have been performed off of a solution version.</p>
+    ///
+    /// See `Personalize::list_batch_inference_jobs`.
+    pub fn list_batch_inference_jobs(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
+        self.runtime
+            .block_on(self.client.list_batch_inference_jobs(input))
+    }
+
+    /// <p>Returns a list of campaigns that use the given solution. When a solution is not specified, all the campaigns associated with the account are listed. The response provides the properties for each campaign, including the Amazon Resource Name (ARN). For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    ///
+    /// See `Personalize::list_campaigns`.
+    pub fn list_campaigns(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
+        self.runtime.block_on(self.client.list_campaigns(input))
+    }
+
+    /// <p>Returns a list of dataset groups. The response provides the properties for each dataset group, including the Amazon Resource Name (ARN). For more information on dataset groups, see <a>CreateDatasetGroup</a>.</p>
+    ///
+    /// See `Personalize::list_dataset_groups`.
+    pub fn list_dataset_groups(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_groups(input))
+    }
+
+    /// <p>Returns a list of dataset import jobs that use the given dataset. When a dataset is not specified, all the dataset import jobs associated with the account are listed. The response provides the properties for each dataset import job, including the Amazon Resource Name (ARN). For more information on dataset import jobs, see <a>CreateDatasetImportJob</a>. For more information on datasets, see <a>CreateDataset</a>.</p>
+    ///
+    /// See `Personalize::list_dataset_import_jobs`.
+    pub fn list_dataset_import_jobs(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_import_jobs(input))
+    }
+
+    /// <p>Returns the list of datasets contained in the given dataset group. The response provides the properties for each dataset, including the Amazon Resource Name (ARN). For more information on datasets, see <a>CreateDataset</a>.</p>
+    ///
+    /// See `Personalize::list_datasets`.
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// <p>Returns the list of event trackers associated with the account. The response provides the properties for each event tracker, including the Amazon Resource Name (ARN) and tracking ID. For more information on event trackers, see <a>CreateEventTracker</a>.</p>
+    ///
+    /// See `Personalize::list_event_trackers`.
+    pub fn list_event_trackers(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
+        self.runtime
+            .block_on(self.client.list_event_trackers(input))
+    }
+
+    /// <p>Lists all filters that belong to a given dataset group.</p>
+    ///
+    /// See `Personalize::list_filters`.
+    pub fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
+        self.runtime.block_on(self.client.list_filters(input))
+    }
+
+    /// <p>Returns a list of available recipes. The response provides the properties for each recipe, including the recipe's Amazon Resource Name (ARN).</p>
+    ///
+    /// See `Personalize::list_recipes`.
+    pub fn list_recipes(
+        &self,
+        input: ListRecipesRequest,
+    ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
+        self.runtime.block_on(self.client.list_recipes(input))
+    }
+
+    /// <p>Returns the list of schemas associated with the account. The response provides the properties for each schema, including the Amazon Resource Name (ARN). For more information on schemas, see <a>CreateSchema</a>.</p>
+    ///
+    /// See `Personalize::list_schemas`.
+    pub fn list_schemas(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+        self.runtime.block_on(self.client.list_schemas(input))
+    }
+
+    /// <p>Returns a list of solution versions for the given solution. When a solution is not specified, all the solution versions associated with the account are listed. The response provides the properties for each solution version, including the Amazon Resource Name (ARN). For more information on solutions, see <a>CreateSolution</a>.</p>
+    ///
+    /// See `Personalize::list_solution_versions`.
+    pub fn list_solution_versions(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_solution_versions(input))
+    }
+
+    /// <p>Returns a list of solutions that use the given dataset group. When a dataset group is not specified, all the solutions associated with the account are listed. The response provides the properties for each solution, including the Amazon Resource Name (ARN). For more information on solutions, see <a>CreateSolution</a>.</p>
+    ///
+    /// See `Personalize::list_solutions`.
+    pub fn list_solutions(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
+        self.runtime.block_on(self.client.list_solutions(input))
+    }
+
+    /// <p>Updates a campaign by either deploying a new solution or changing the value of the campaign's <code>minProvisionedTPS</code> parameter.</p> <p>To update a campaign, the campaign status must be ACTIVE or CREATE FAILED. Check the campaign status using the <a>DescribeCampaign</a> API.</p> <note> <p>You must wait until the <code>status</code> of the updated campaign is <code>ACTIVE</code> before asking the campaign for recommendations.</p> </note> <p>For more information on campaigns, see <a>CreateCampaign</a>.</p>
+    ///
+    /// See `Personalize::update_campaign`.
+    pub fn update_campaign(
+        &self,
+        input: UpdateCampaignRequest,
+    ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
+        self.runtime.block_on(self.client.update_campaign(input))
+    }
+}
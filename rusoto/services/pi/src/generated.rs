@@ -291,17 +291,22 @@ impl DescribeDimensionKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDimensionKeysError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDimensionKeysError::InvalidArgument(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDimensionKeysError::NotAuthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -337,15 +342,22 @@ impl GetResourceMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetResourceMetricsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceMetricsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetResourceMetricsError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceMetricsError::InvalidArgument(err.msg),
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetResourceMetricsError::NotAuthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceMetricsError::NotAuthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -398,6 +410,7 @@ impl PerformanceInsightsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -413,6 +426,7 @@ impl PerformanceInsightsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PerformanceInsightsClient {
         PerformanceInsightsClient { client, region }
     }
@@ -426,6 +440,9 @@ impl PerformanceInsights for PerformanceInsightsClient {
         input: DescribeDimensionKeysRequest,
     ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDimensionKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PerformanceInsightsv20180227.DescribeDimensionKeys",
@@ -448,6 +465,9 @@ impl PerformanceInsights for PerformanceInsightsClient {
         input: GetResourceMetricsRequest,
     ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResourceMetrics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "PerformanceInsightsv20180227.GetResourceMetrics",
@@ -463,3 +483,82 @@ impl PerformanceInsights for PerformanceInsightsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourceMetricsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS PI API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PerformanceInsightsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PerformanceInsightsBlockingClient {
+    client: PerformanceInsightsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PerformanceInsightsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PerformanceInsightsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PerformanceInsightsBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PerformanceInsightsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PerformanceInsightsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient {
+            client: PerformanceInsightsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>For a specific time period, retrieve the top <code>N</code> dimension keys for a metric.</p>
+    ///
+    /// See `PerformanceInsights::describe_dimension_keys`.
+    pub fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
+        self.runtime
+            .block_on(self.client.describe_dimension_keys(input))
+    }
+
+    /// <p>Retrieve Performance Insights metrics for a set of data sources, over a time period. You can provide specific dimension groups and dimensions, and provide aggregation and filtering criteria for each group.</p>
+    ///
+    /// See `PerformanceInsights::get_resource_metrics`.
+    pub fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_resource_metrics(input))
+    }
+}
@@ -1648,30 +1648,40 @@ impl CreateConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::AlreadyExists(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetError::ConcurrentModification(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1718,27 +1728,32 @@ impl CreateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::AlreadyExists(err.msg),
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::BadRequest(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::LimitExceeded(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
                     )
                 }
@@ -1792,23 +1807,34 @@ impl CreateDedicatedIpPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::AlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDedicatedIpPoolError::AlreadyExists(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDedicatedIpPoolError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDedicatedIpPoolError::ConcurrentModification(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDedicatedIpPoolError::LimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDedicatedIpPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1860,47 +1886,56 @@ impl CreateDeliverabilityTestReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountSuspendedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::AccountSuspended(err.msg),
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeliverabilityTestReportError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeliverabilityTestReportError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::ConcurrentModification(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::LimitExceeded(err.msg),
                     )
                 }
                 "MailFromDomainNotVerifiedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::MailFromDomainNotVerified(err.msg),
                     )
                 }
                 "MessageRejected" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::MessageRejected(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeliverabilityTestReportError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeliverabilityTestReportError::NotFound(err.msg),
+                    )
                 }
                 "SendingPausedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::SendingPaused(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeliverabilityTestReportError::TooManyRequests(err.msg),
                     )
                 }
@@ -1956,18 +1991,28 @@ impl CreateEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEmailIdentityError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEmailIdentityError::ConcurrentModification(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEmailIdentityError::LimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateEmailIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2006,20 +2051,28 @@ impl DeleteConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2060,17 +2113,20 @@ impl DeleteConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::TooManyRequests(err.msg),
                     )
                 }
@@ -2116,20 +2172,28 @@ impl DeleteDedicatedIpPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDedicatedIpPoolError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteDedicatedIpPoolError::ConcurrentModification(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDedicatedIpPoolError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDedicatedIpPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2168,18 +2232,25 @@ impl DeleteEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEmailIdentityError::BadRequest(err.msg),
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEmailIdentityError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteEmailIdentityError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteEmailIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2214,10 +2285,10 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetAccountError::BadRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetAccountError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2252,13 +2323,19 @@ impl GetBlacklistReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlacklistReportsError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetBlacklistReportsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlacklistReportsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2294,13 +2371,19 @@ impl GetConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationSetError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetConfigurationSetError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetConfigurationSetError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConfigurationSetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2338,17 +2421,20 @@ impl GetConfigurationSetEventDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::TooManyRequests(err.msg),
                     )
                 }
@@ -2392,13 +2478,16 @@ impl GetDedicatedIpError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDedicatedIpError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDedicatedIpError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDedicatedIpError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDedicatedIpError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDedicatedIpError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDedicatedIpError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2434,13 +2523,16 @@ impl GetDedicatedIpsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetDedicatedIpsError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetDedicatedIpsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDedicatedIpsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2478,17 +2570,20 @@ impl GetDeliverabilityDashboardOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeliverabilityDashboardOptionsError::BadRequest(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeliverabilityDashboardOptionsError::LimitExceeded(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeliverabilityDashboardOptionsError::TooManyRequests(err.msg),
                     )
                 }
@@ -2532,19 +2627,22 @@ impl GetDeliverabilityTestReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeliverabilityTestReportError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeliverabilityTestReportError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDeliverabilityTestReportError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2582,17 +2680,20 @@ impl GetDomainDeliverabilityCampaignError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainDeliverabilityCampaignError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainDeliverabilityCampaignError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainDeliverabilityCampaignError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainDeliverabilityCampaignError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDomainDeliverabilityCampaignError::TooManyRequests(err.msg),
                     )
                 }
@@ -2632,17 +2733,22 @@ impl GetDomainStatisticsReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainStatisticsReportError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainStatisticsReportError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainStatisticsReportError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2678,13 +2784,16 @@ impl GetEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetEmailIdentityError::BadRequest(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetEmailIdentityError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetEmailIdentityError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEmailIdentityError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2718,12 +2827,16 @@ impl ListConfigurationSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationSetsError::BadRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListConfigurationSetsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2756,12 +2869,16 @@ impl ListDedicatedIpPoolsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDedicatedIpPoolsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDedicatedIpPoolsError::BadRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDedicatedIpPoolsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDedicatedIpPoolsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2798,17 +2915,20 @@ impl ListDeliverabilityTestReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeliverabilityTestReportsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeliverabilityTestReportsError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeliverabilityTestReportsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDeliverabilityTestReportsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDeliverabilityTestReportsError::TooManyRequests(err.msg),
                     )
                 }
@@ -2850,17 +2970,20 @@ impl ListDomainDeliverabilityCampaignsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDomainDeliverabilityCampaignsError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDomainDeliverabilityCampaignsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainDeliverabilityCampaignsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDomainDeliverabilityCampaignsError::TooManyRequests(err.msg),
                     )
                 }
@@ -2898,10 +3021,16 @@ impl ListEmailIdentitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEmailIdentitiesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEmailIdentitiesError::BadRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEmailIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListEmailIdentitiesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2936,13 +3065,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2978,12 +3113,14 @@ impl PutAccountDedicatedIpWarmupAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAccountDedicatedIpWarmupAttributesError::BadRequest(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAccountDedicatedIpWarmupAttributesError::TooManyRequests(err.msg),
                     )
                 }
@@ -3024,14 +3161,16 @@ impl PutAccountSendingAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutAccountSendingAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAccountSendingAttributesError::BadRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutAccountSendingAttributesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAccountSendingAttributesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3068,17 +3207,20 @@ impl PutConfigurationSetDeliveryOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetDeliveryOptionsError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetDeliveryOptionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigurationSetDeliveryOptionsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetDeliveryOptionsError::TooManyRequests(err.msg),
                     )
                 }
@@ -3122,17 +3264,20 @@ impl PutConfigurationSetReputationOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetReputationOptionsError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetReputationOptionsError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetReputationOptionsError::TooManyRequests(err.msg),
                     )
                 }
@@ -3178,17 +3323,20 @@ impl PutConfigurationSetSendingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetSendingOptionsError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetSendingOptionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigurationSetSendingOptionsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetSendingOptionsError::TooManyRequests(err.msg),
                     )
                 }
@@ -3230,17 +3378,20 @@ impl PutConfigurationSetTrackingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetTrackingOptionsError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetTrackingOptionsError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutConfigurationSetTrackingOptionsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutConfigurationSetTrackingOptionsError::TooManyRequests(err.msg),
                     )
                 }
@@ -3282,15 +3433,19 @@ impl PutDedicatedIpInPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutDedicatedIpInPoolError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutDedicatedIpInPoolError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDedicatedIpInPoolError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3328,17 +3483,20 @@ impl PutDedicatedIpWarmupAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDedicatedIpWarmupAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDedicatedIpWarmupAttributesError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDedicatedIpWarmupAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDedicatedIpWarmupAttributesError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDedicatedIpWarmupAttributesError::TooManyRequests(err.msg),
                     )
                 }
@@ -3384,27 +3542,32 @@ impl PutDeliverabilityDashboardOptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliverabilityDashboardOptionError::AlreadyExists(err.msg),
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDeliverabilityDashboardOptionError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDeliverabilityDashboardOptionError::BadRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliverabilityDashboardOptionError::LimitExceeded(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDeliverabilityDashboardOptionError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutDeliverabilityDashboardOptionError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutDeliverabilityDashboardOptionError::TooManyRequests(err.msg),
                     )
                 }
@@ -3452,17 +3615,20 @@ impl PutEmailIdentityDkimAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEmailIdentityDkimAttributesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEmailIdentityDkimAttributesError::BadRequest(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityDkimAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEmailIdentityDkimAttributesError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEmailIdentityDkimAttributesError::TooManyRequests(err.msg),
                     )
                 }
@@ -3504,17 +3670,20 @@ impl PutEmailIdentityFeedbackAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEmailIdentityFeedbackAttributesError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityFeedbackAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEmailIdentityFeedbackAttributesError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEmailIdentityFeedbackAttributesError::TooManyRequests(err.msg),
                     )
                 }
@@ -3558,17 +3727,20 @@ impl PutEmailIdentityMailFromAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEmailIdentityMailFromAttributesError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityMailFromAttributesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutEmailIdentityMailFromAttributesError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutEmailIdentityMailFromAttributesError::TooManyRequests(err.msg),
                     )
                 }
@@ -3620,28 +3792,31 @@ impl SendEmailError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountSuspendedException" => {
-                    return RusotoError::Service(SendEmailError::AccountSuspended(err.msg))
+                    return RusotoError::service(&res, SendEmailError::AccountSuspended(err.msg))
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(SendEmailError::BadRequest(err.msg))
+                    return RusotoError::service(&res, SendEmailError::BadRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendEmailError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, SendEmailError::LimitExceeded(err.msg))
                 }
                 "MailFromDomainNotVerifiedException" => {
-                    return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendEmailError::MailFromDomainNotVerified(err.msg),
+                    )
                 }
                 "MessageRejected" => {
-                    return RusotoError::Service(SendEmailError::MessageRejected(err.msg))
+                    return RusotoError::service(&res, SendEmailError::MessageRejected(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendEmailError::NotFound(err.msg))
+                    return RusotoError::service(&res, SendEmailError::NotFound(err.msg))
                 }
                 "SendingPausedException" => {
-                    return RusotoError::Service(SendEmailError::SendingPaused(err.msg))
+                    return RusotoError::service(&res, SendEmailError::SendingPaused(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SendEmailError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, SendEmailError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3684,16 +3859,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3732,18 +3910,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ConcurrentModification(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3782,17 +3961,20 @@ impl UpdateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::BadRequest(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
                     )
                 }
@@ -4146,6 +4328,7 @@ impl PinpointEmailClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4161,6 +4344,7 @@ impl PinpointEmailClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PinpointEmailClient {
         PinpointEmailClient { client, region }
     }
@@ -4177,6 +4361,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("CreateConfigurationSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4215,6 +4402,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("CreateConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4249,6 +4439,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("CreateDedicatedIpPool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4284,6 +4477,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("CreateDeliverabilityTestReport");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4316,6 +4512,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("CreateEmailIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4351,6 +4550,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation_name("DeleteConfigurationSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4384,6 +4586,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation_name("DeleteConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4419,6 +4624,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation_name("DeleteDedicatedIpPool");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4452,6 +4660,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation_name("DeleteEmailIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4479,6 +4690,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4509,6 +4723,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/blacklist-report";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetBlacklistReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4548,6 +4765,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetConfigurationSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4584,6 +4804,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetConfigurationSetEventDestinations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4616,6 +4839,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}", ip = input.ip);
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetDedicatedIp");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4646,6 +4872,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ips";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetDedicatedIps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4690,6 +4919,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetDeliverabilityDashboardOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4726,6 +4958,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetDeliverabilityTestReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4762,6 +4997,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetDomainDeliverabilityCampaign");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4798,6 +5036,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetDomainStatisticsReport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4836,6 +5077,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("GetEmailIdentity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4866,6 +5110,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("ListConfigurationSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4905,6 +5152,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("ListDedicatedIpPools");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4947,6 +5197,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test-reports";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("ListDeliverabilityTestReports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4992,6 +5245,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("ListDomainDeliverabilityCampaigns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5035,6 +5291,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("ListEmailIdentities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5074,6 +5333,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5111,6 +5373,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/dedicated-ips/warmup";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutAccountDedicatedIpWarmupAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5146,6 +5411,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/sending";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutAccountSendingAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5184,6 +5452,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutConfigurationSetDeliveryOptions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5224,6 +5495,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutConfigurationSetReputationOptions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5264,6 +5538,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutConfigurationSetSendingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5304,6 +5581,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutConfigurationSetTrackingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5338,6 +5618,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/pool", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutDedicatedIpInPool");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5373,6 +5656,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/warmup", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutDedicatedIpWarmupAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5408,6 +5694,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutDeliverabilityDashboardOption");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5448,6 +5737,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutEmailIdentityDkimAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5486,6 +5778,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutEmailIdentityFeedbackAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5526,6 +5821,9 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("PutEmailIdentityMailFromAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5560,6 +5858,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/outbound-emails";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("SendEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5592,6 +5893,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5624,6 +5928,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5664,6 +5971,9 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation_name("UpdateConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5689,3 +5999,572 @@ impl PinpointEmail for PinpointEmailClient {
         }
     }
 }
+
+/// A blocking client for the Pinpoint Email API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PinpointEmailClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PinpointEmailBlockingClient {
+    client: PinpointEmailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PinpointEmailBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PinpointEmailBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PinpointEmailBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PinpointEmailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PinpointEmailBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient {
+            client: PinpointEmailClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Create a configuration set. <i>Configuration sets</i> are groups of rules that you can apply to the emails you send using Amazon Pinpoint. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email. </p>
+    ///
+    /// See `PinpointEmail::create_configuration_set`.
+    pub fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_set(input))
+    }
+
+    /// <p>Create an event destination. In Amazon Pinpoint, <i>events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p> <p>A single configuration set can include more than one event destination.</p>
+    ///
+    /// See `PinpointEmail::create_configuration_set_event_destination`.
+    pub fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p>Create a new pool of dedicated IP addresses. A pool can include one or more dedicated IP addresses that are associated with your Amazon Pinpoint account. You can associate a pool with a configuration set. When you send an email that uses that configuration set, Amazon Pinpoint sends it using only the IP addresses in the associated pool.</p>
+    ///
+    /// See `PinpointEmail::create_dedicated_ip_pool`.
+    pub fn create_dedicated_ip_pool(
+        &self,
+        input: CreateDedicatedIpPoolRequest,
+    ) -> Result<CreateDedicatedIpPoolResponse, RusotoError<CreateDedicatedIpPoolError>> {
+        self.runtime
+            .block_on(self.client.create_dedicated_ip_pool(input))
+    }
+
+    /// <p>Create a new predictive inbox placement test. Predictive inbox placement tests can help you predict how your messages will be handled by various email providers around the world. When you perform a predictive inbox placement test, you provide a sample message that contains the content that you plan to send to your customers. Amazon Pinpoint then sends that message to special email addresses spread across several major email providers. After about 24 hours, the test is complete, and you can use the <code>GetDeliverabilityTestReport</code> operation to view the results of the test.</p>
+    ///
+    /// See `PinpointEmail::create_deliverability_test_report`.
+    pub fn create_deliverability_test_report(
+        &self,
+        input: CreateDeliverabilityTestReportRequest,
+    ) -> Result<
+        CreateDeliverabilityTestReportResponse,
+        RusotoError<CreateDeliverabilityTestReportError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_deliverability_test_report(input))
+    }
+
+    /// <p>Verifies an email identity for use with Amazon Pinpoint. In Amazon Pinpoint, an identity is an email address or domain that you use when you send email. Before you can use an identity to send email with Amazon Pinpoint, you first have to verify it. By verifying an address, you demonstrate that you're the owner of the address, and that you've given Amazon Pinpoint permission to send email from the address.</p> <p>When you verify an email address, Amazon Pinpoint sends an email to the address. Your email address is verified as soon as you follow the link in the verification email. </p> <p>When you verify a domain, this operation provides a set of DKIM tokens, which you can convert into CNAME tokens. You add these CNAME tokens to the DNS configuration for your domain. Your domain is verified when Amazon Pinpoint detects these records in the DNS configuration for your domain. It usually takes around 72 hours to complete the domain verification process.</p>
+    ///
+    /// See `PinpointEmail::create_email_identity`.
+    pub fn create_email_identity(
+        &self,
+        input: CreateEmailIdentityRequest,
+    ) -> Result<CreateEmailIdentityResponse, RusotoError<CreateEmailIdentityError>> {
+        self.runtime
+            .block_on(self.client.create_email_identity(input))
+    }
+
+    /// <p>Delete an existing configuration set.</p> <p>In Amazon Pinpoint, <i>configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
+    ///
+    /// See `PinpointEmail::delete_configuration_set`.
+    pub fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_set(input))
+    }
+
+    /// <p>Delete an event destination.</p> <p>In Amazon Pinpoint, <i>events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
+    ///
+    /// See `PinpointEmail::delete_configuration_set_event_destination`.
+    pub fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p>Delete a dedicated IP pool.</p>
+    ///
+    /// See `PinpointEmail::delete_dedicated_ip_pool`.
+    pub fn delete_dedicated_ip_pool(
+        &self,
+        input: DeleteDedicatedIpPoolRequest,
+    ) -> Result<DeleteDedicatedIpPoolResponse, RusotoError<DeleteDedicatedIpPoolError>> {
+        self.runtime
+            .block_on(self.client.delete_dedicated_ip_pool(input))
+    }
+
+    /// <p>Deletes an email identity that you previously verified for use with Amazon Pinpoint. An identity can be either an email address or a domain name.</p>
+    ///
+    /// See `PinpointEmail::delete_email_identity`.
+    pub fn delete_email_identity(
+        &self,
+        input: DeleteEmailIdentityRequest,
+    ) -> Result<DeleteEmailIdentityResponse, RusotoError<DeleteEmailIdentityError>> {
+        self.runtime
+            .block_on(self.client.delete_email_identity(input))
+    }
+
+    /// <p>Obtain information about the email-sending status and capabilities of your Amazon Pinpoint account in the current AWS Region.</p>
+    ///
+    /// See `PinpointEmail::get_account`.
+    pub fn get_account(&self) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+        self.runtime.block_on(self.client.get_account())
+    }
+
+    /// <p>Retrieve a list of the blacklists that your dedicated IP addresses appear on.</p>
+    ///
+    /// See `PinpointEmail::get_blacklist_reports`.
+    pub fn get_blacklist_reports(
+        &self,
+        input: GetBlacklistReportsRequest,
+    ) -> Result<GetBlacklistReportsResponse, RusotoError<GetBlacklistReportsError>> {
+        self.runtime
+            .block_on(self.client.get_blacklist_reports(input))
+    }
+
+    /// <p>Get information about an existing configuration set, including the dedicated IP pool that it's associated with, whether or not it's enabled for sending email, and more.</p> <p>In Amazon Pinpoint, <i>configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
+    ///
+    /// See `PinpointEmail::get_configuration_set`.
+    pub fn get_configuration_set(
+        &self,
+        input: GetConfigurationSetRequest,
+    ) -> Result<GetConfigurationSetResponse, RusotoError<GetConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.get_configuration_set(input))
+    }
+
+    /// <p>Retrieve a list of event destinations that are associated with a configuration set.</p> <p>In Amazon Pinpoint, <i>events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
+    ///
+    /// See `PinpointEmail::get_configuration_set_event_destinations`.
+    pub fn get_configuration_set_event_destinations(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        GetConfigurationSetEventDestinationsResponse,
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_configuration_set_event_destinations(input))
+    }
+
+    /// <p>Get information about a dedicated IP address, including the name of the dedicated IP pool that it's associated with, as well information about the automatic warm-up process for the address.</p>
+    ///
+    /// See `PinpointEmail::get_dedicated_ip`.
+    pub fn get_dedicated_ip(
+        &self,
+        input: GetDedicatedIpRequest,
+    ) -> Result<GetDedicatedIpResponse, RusotoError<GetDedicatedIpError>> {
+        self.runtime.block_on(self.client.get_dedicated_ip(input))
+    }
+
+    /// <p>List the dedicated IP addresses that are associated with your Amazon Pinpoint account.</p>
+    ///
+    /// See `PinpointEmail::get_dedicated_ips`.
+    pub fn get_dedicated_ips(
+        &self,
+        input: GetDedicatedIpsRequest,
+    ) -> Result<GetDedicatedIpsResponse, RusotoError<GetDedicatedIpsError>> {
+        self.runtime.block_on(self.client.get_dedicated_ips(input))
+    }
+
+    /// <p>Retrieve information about the status of the Deliverability dashboard for your Amazon Pinpoint account. When the Deliverability dashboard is enabled, you gain access to reputation, deliverability, and other metrics for the domains that you use to send email using Amazon Pinpoint. You also gain the ability to perform predictive inbox placement tests.</p> <p>When you use the Deliverability dashboard, you pay a monthly subscription charge, in addition to any other fees that you accrue by using Amazon Pinpoint. For more information about the features and cost of a Deliverability dashboard subscription, see <a href="http://aws.amazon.com/pinpoint/pricing/">Amazon Pinpoint Pricing</a>.</p>
+    ///
+    /// See `PinpointEmail::get_deliverability_dashboard_options`.
+    pub fn get_deliverability_dashboard_options(
+        &self,
+    ) -> Result<
+        GetDeliverabilityDashboardOptionsResponse,
+        RusotoError<GetDeliverabilityDashboardOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_deliverability_dashboard_options())
+    }
+
+    /// <p>Retrieve the results of a predictive inbox placement test.</p>
+    ///
+    /// See `PinpointEmail::get_deliverability_test_report`.
+    pub fn get_deliverability_test_report(
+        &self,
+        input: GetDeliverabilityTestReportRequest,
+    ) -> Result<GetDeliverabilityTestReportResponse, RusotoError<GetDeliverabilityTestReportError>>
+    {
+        self.runtime
+            .block_on(self.client.get_deliverability_test_report(input))
+    }
+
+    /// <p>Retrieve all the deliverability data for a specific campaign. This data is available for a campaign only if the campaign sent email by using a domain that the Deliverability dashboard is enabled for (<code>PutDeliverabilityDashboardOption</code> operation).</p>
+    ///
+    /// See `PinpointEmail::get_domain_deliverability_campaign`.
+    pub fn get_domain_deliverability_campaign(
+        &self,
+        input: GetDomainDeliverabilityCampaignRequest,
+    ) -> Result<
+        GetDomainDeliverabilityCampaignResponse,
+        RusotoError<GetDomainDeliverabilityCampaignError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_domain_deliverability_campaign(input))
+    }
+
+    /// <p>Retrieve inbox placement and engagement rates for the domains that you use to send email.</p>
+    ///
+    /// See `PinpointEmail::get_domain_statistics_report`.
+    pub fn get_domain_statistics_report(
+        &self,
+        input: GetDomainStatisticsReportRequest,
+    ) -> Result<GetDomainStatisticsReportResponse, RusotoError<GetDomainStatisticsReportError>>
+    {
+        self.runtime
+            .block_on(self.client.get_domain_statistics_report(input))
+    }
+
+    /// <p>Provides information about a specific identity associated with your Amazon Pinpoint account, including the identity's verification status, its DKIM authentication status, and its custom Mail-From settings.</p>
+    ///
+    /// See `PinpointEmail::get_email_identity`.
+    pub fn get_email_identity(
+        &self,
+        input: GetEmailIdentityRequest,
+    ) -> Result<GetEmailIdentityResponse, RusotoError<GetEmailIdentityError>> {
+        self.runtime.block_on(self.client.get_email_identity(input))
+    }
+
+    /// <p>List all of the configuration sets associated with your Amazon Pinpoint account in the current region.</p> <p>In Amazon Pinpoint, <i>configuration sets</i> are groups of rules that you can apply to the emails you send. You apply a configuration set to an email by including a reference to the configuration set in the headers of the email. When you apply a configuration set to an email, all of the rules in that configuration set are applied to the email.</p>
+    ///
+    /// See `PinpointEmail::list_configuration_sets`.
+    pub fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_sets(input))
+    }
+
+    /// <p>List all of the dedicated IP pools that exist in your Amazon Pinpoint account in the current AWS Region.</p>
+    ///
+    /// See `PinpointEmail::list_dedicated_ip_pools`.
+    pub fn list_dedicated_ip_pools(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> Result<ListDedicatedIpPoolsResponse, RusotoError<ListDedicatedIpPoolsError>> {
+        self.runtime
+            .block_on(self.client.list_dedicated_ip_pools(input))
+    }
+
+    /// <p>Show a list of the predictive inbox placement tests that you've performed, regardless of their statuses. For predictive inbox placement tests that are complete, you can use the <code>GetDeliverabilityTestReport</code> operation to view the results.</p>
+    ///
+    /// See `PinpointEmail::list_deliverability_test_reports`.
+    pub fn list_deliverability_test_reports(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> Result<
+        ListDeliverabilityTestReportsResponse,
+        RusotoError<ListDeliverabilityTestReportsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_deliverability_test_reports(input))
+    }
+
+    /// <p>Retrieve deliverability data for all the campaigns that used a specific domain to send email during a specified time range. This data is available for a domain only if you enabled the Deliverability dashboard (<code>PutDeliverabilityDashboardOption</code> operation) for the domain.</p>
+    ///
+    /// See `PinpointEmail::list_domain_deliverability_campaigns`.
+    pub fn list_domain_deliverability_campaigns(
+        &self,
+        input: ListDomainDeliverabilityCampaignsRequest,
+    ) -> Result<
+        ListDomainDeliverabilityCampaignsResponse,
+        RusotoError<ListDomainDeliverabilityCampaignsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_domain_deliverability_campaigns(input))
+    }
+
+    /// <p>Returns a list of all of the email identities that are associated with your Amazon Pinpoint account. An identity can be either an email address or a domain. This operation returns identities that are verified as well as those that aren't.</p>
+    ///
+    /// See `PinpointEmail::list_email_identities`.
+    pub fn list_email_identities(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> Result<ListEmailIdentitiesResponse, RusotoError<ListEmailIdentitiesError>> {
+        self.runtime
+            .block_on(self.client.list_email_identities(input))
+    }
+
+    /// <p>Retrieve a list of the tags (keys and values) that are associated with a specified resource. A <i>tag</i> is a label that you optionally define and associate with a resource in Amazon Pinpoint. Each tag consists of a required <i>tag key</i> and an optional associated <i>tag value</i>. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    ///
+    /// See `PinpointEmail::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Enable or disable the automatic warm-up feature for dedicated IP addresses.</p>
+    ///
+    /// See `PinpointEmail::put_account_dedicated_ip_warmup_attributes`.
+    pub fn put_account_dedicated_ip_warmup_attributes(
+        &self,
+        input: PutAccountDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        PutAccountDedicatedIpWarmupAttributesResponse,
+        RusotoError<PutAccountDedicatedIpWarmupAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_account_dedicated_ip_warmup_attributes(input),
+        )
+    }
+
+    /// <p>Enable or disable the ability of your account to send email.</p>
+    ///
+    /// See `PinpointEmail::put_account_sending_attributes`.
+    pub fn put_account_sending_attributes(
+        &self,
+        input: PutAccountSendingAttributesRequest,
+    ) -> Result<PutAccountSendingAttributesResponse, RusotoError<PutAccountSendingAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.put_account_sending_attributes(input))
+    }
+
+    /// <p>Associate a configuration set with a dedicated IP pool. You can use dedicated IP pools to create groups of dedicated IP addresses for sending specific types of email.</p>
+    ///
+    /// See `PinpointEmail::put_configuration_set_delivery_options`.
+    pub fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetDeliveryOptionsResponse,
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_delivery_options(input))
+    }
+
+    /// <p>Enable or disable collection of reputation metrics for emails that you send using a particular configuration set in a specific AWS Region.</p>
+    ///
+    /// See `PinpointEmail::put_configuration_set_reputation_options`.
+    pub fn put_configuration_set_reputation_options(
+        &self,
+        input: PutConfigurationSetReputationOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetReputationOptionsResponse,
+        RusotoError<PutConfigurationSetReputationOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_reputation_options(input))
+    }
+
+    /// <p>Enable or disable email sending for messages that use a particular configuration set in a specific AWS Region.</p>
+    ///
+    /// See `PinpointEmail::put_configuration_set_sending_options`.
+    pub fn put_configuration_set_sending_options(
+        &self,
+        input: PutConfigurationSetSendingOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetSendingOptionsResponse,
+        RusotoError<PutConfigurationSetSendingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_sending_options(input))
+    }
+
+    /// <p>Specify a custom domain to use for open and click tracking elements in email that you send using Amazon Pinpoint.</p>
+    ///
+    /// See `PinpointEmail::put_configuration_set_tracking_options`.
+    pub fn put_configuration_set_tracking_options(
+        &self,
+        input: PutConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetTrackingOptionsResponse,
+        RusotoError<PutConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_tracking_options(input))
+    }
+
+    /// <p><p>Move a dedicated IP address to an existing dedicated IP pool.</p> <note> <p>The dedicated IP address that you specify must already exist, and must be associated with your Amazon Pinpoint account. </p> <p>The dedicated IP pool you specify must already exist. You can create a new pool by using the <code>CreateDedicatedIpPool</code> operation.</p> </note></p>
+    ///
+    /// See `PinpointEmail::put_dedicated_ip_in_pool`.
+    pub fn put_dedicated_ip_in_pool(
+        &self,
+        input: PutDedicatedIpInPoolRequest,
+    ) -> Result<PutDedicatedIpInPoolResponse, RusotoError<PutDedicatedIpInPoolError>> {
+        self.runtime
+            .block_on(self.client.put_dedicated_ip_in_pool(input))
+    }
+
+    /// <p><p/></p>
+    ///
+    /// See `PinpointEmail::put_dedicated_ip_warmup_attributes`.
+    pub fn put_dedicated_ip_warmup_attributes(
+        &self,
+        input: PutDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        PutDedicatedIpWarmupAttributesResponse,
+        RusotoError<PutDedicatedIpWarmupAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_dedicated_ip_warmup_attributes(input))
+    }
+
+    /// <p>Enable or disable the Deliverability dashboard for your Amazon Pinpoint account. When you enable the Deliverability dashboard, you gain access to reputation, deliverability, and other metrics for the domains that you use to send email using Amazon Pinpoint. You also gain the ability to perform predictive inbox placement tests.</p> <p>When you use the Deliverability dashboard, you pay a monthly subscription charge, in addition to any other fees that you accrue by using Amazon Pinpoint. For more information about the features and cost of a Deliverability dashboard subscription, see <a href="http://aws.amazon.com/pinpoint/pricing/">Amazon Pinpoint Pricing</a>.</p>
+    ///
+    /// See `PinpointEmail::put_deliverability_dashboard_option`.
+    pub fn put_deliverability_dashboard_option(
+        &self,
+        input: PutDeliverabilityDashboardOptionRequest,
+    ) -> Result<
+        PutDeliverabilityDashboardOptionResponse,
+        RusotoError<PutDeliverabilityDashboardOptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_deliverability_dashboard_option(input))
+    }
+
+    /// <p>Used to enable or disable DKIM authentication for an email identity.</p>
+    ///
+    /// See `PinpointEmail::put_email_identity_dkim_attributes`.
+    pub fn put_email_identity_dkim_attributes(
+        &self,
+        input: PutEmailIdentityDkimAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityDkimAttributesResponse,
+        RusotoError<PutEmailIdentityDkimAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_email_identity_dkim_attributes(input))
+    }
+
+    /// <p>Used to enable or disable feedback forwarding for an identity. This setting determines what happens when an identity is used to send an email that results in a bounce or complaint event.</p> <p>When you enable feedback forwarding, Amazon Pinpoint sends you email notifications when bounce or complaint events occur. Amazon Pinpoint sends this notification to the address that you specified in the Return-Path header of the original email.</p> <p>When you disable feedback forwarding, Amazon Pinpoint sends notifications through other mechanisms, such as by notifying an Amazon SNS topic. You're required to have a method of tracking bounces and complaints. If you haven't set up another mechanism for receiving bounce or complaint notifications, Amazon Pinpoint sends an email notification when these events occur (even if this setting is disabled).</p>
+    ///
+    /// See `PinpointEmail::put_email_identity_feedback_attributes`.
+    pub fn put_email_identity_feedback_attributes(
+        &self,
+        input: PutEmailIdentityFeedbackAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityFeedbackAttributesResponse,
+        RusotoError<PutEmailIdentityFeedbackAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_email_identity_feedback_attributes(input))
+    }
+
+    /// <p>Used to enable or disable the custom Mail-From domain configuration for an email identity.</p>
+    ///
+    /// See `PinpointEmail::put_email_identity_mail_from_attributes`.
+    pub fn put_email_identity_mail_from_attributes(
+        &self,
+        input: PutEmailIdentityMailFromAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityMailFromAttributesResponse,
+        RusotoError<PutEmailIdentityMailFromAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_email_identity_mail_from_attributes(input))
+    }
+
+    /// <p><p>Sends an email message. You can use the Amazon Pinpoint Email API to send two types of messages:</p> <ul> <li> <p> <b>Simple</b> – A standard email message. When you create this type of message, you specify the sender, the recipient, and the message body, and Amazon Pinpoint assembles the message for you.</p> </li> <li> <p> <b>Raw</b> – A raw, MIME-formatted email message. When you send this type of email, you have to specify all of the message headers, as well as the message body. You can use this message type to send messages that contain attachments. The message that you specify has to be a valid MIME message.</p> </li> </ul></p>
+    ///
+    /// See `PinpointEmail::send_email`.
+    pub fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        self.runtime.block_on(self.client.send_email(input))
+    }
+
+    /// <p>Add one or more tags (keys and values) to a specified resource. A <i>tag</i> is a label that you optionally define and associate with a resource in Amazon Pinpoint. Tags can help you categorize and manage resources in different ways, such as by purpose, owner, environment, or other criteria. A resource can have as many as 50 tags.</p> <p>Each tag consists of a required <i>tag key</i> and an associated <i>tag value</i>, both of which you define. A tag key is a general label that acts as a category for more specific tag values. A tag value acts as a descriptor within a tag key.</p>
+    ///
+    /// See `PinpointEmail::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove one or more tags (keys and values) from a specified resource.</p>
+    ///
+    /// See `PinpointEmail::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Update the configuration of an event destination for a configuration set.</p> <p>In Amazon Pinpoint, <i>events</i> include message sends, deliveries, opens, clicks, bounces, and complaints. <i>Event destinations</i> are places that you can send information about these events to. For example, you can send event data to Amazon SNS to receive notifications when you receive bounces or complaints, or you can use Amazon Kinesis Data Firehose to stream data to Amazon S3 for long-term storage.</p>
+    ///
+    /// See `PinpointEmail::update_configuration_set_event_destination`.
+    pub fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_event_destination(input),
+        )
+    }
+}
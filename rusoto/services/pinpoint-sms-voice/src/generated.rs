@@ -325,27 +325,34 @@ impl CreateConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::AlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::AlreadyExists(err.msg),
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::InternalServiceError(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::LimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateConfigurationSetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -391,32 +398,38 @@ impl CreateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::AlreadyExists(err.msg),
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::BadRequest(err.msg),
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::InternalServiceError(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::LimitExceeded(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
                     )
                 }
@@ -471,20 +484,28 @@ impl DeleteConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::InternalServiceError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConfigurationSetError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -525,22 +546,26 @@ impl DeleteConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::BadRequest(err.msg),
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::InternalServiceError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::TooManyRequests(err.msg),
                     )
                 }
@@ -591,22 +616,26 @@ impl GetConfigurationSetEventDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::BadRequest(err.msg),
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::InternalServiceError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetConfigurationSetEventDestinationsError::TooManyRequests(err.msg),
                     )
                 }
@@ -653,15 +682,19 @@ impl SendVoiceMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SendVoiceMessageError::BadRequest(err.msg))
+                    return RusotoError::service(&res, SendVoiceMessageError::BadRequest(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(SendVoiceMessageError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendVoiceMessageError::InternalServiceError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SendVoiceMessageError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendVoiceMessageError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -701,22 +734,26 @@ impl UpdateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::BadRequest(err.msg),
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::InternalServiceError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::NotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
                     )
                 }
@@ -822,6 +859,7 @@ impl PinpointSmsVoiceClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -837,6 +875,7 @@ impl PinpointSmsVoiceClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PinpointSmsVoiceClient {
         PinpointSmsVoiceClient { client, region }
     }
@@ -853,6 +892,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = "/v1/sms-voice/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("CreateConfigurationSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -891,6 +933,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         );
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("CreateConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -928,6 +973,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("DeleteConfigurationSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -961,6 +1009,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = format!("/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("DeleteConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -999,6 +1050,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         );
 
         let mut request = SignedRequest::new("GET", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("GetConfigurationSetEventDestinations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -1031,6 +1085,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = "/v1/sms-voice/voice/message";
 
         let mut request = SignedRequest::new("POST", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("SendVoiceMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -1066,6 +1123,9 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         let request_uri = format!("/v1/sms-voice/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "sms-voice", &self.region, &request_uri);
+        request.set_operation_name("UpdateConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("sms-voice.pinpoint".to_string());
@@ -1091,3 +1151,150 @@ impl PinpointSmsVoice for PinpointSmsVoiceClient {
         }
     }
 }
+
+/// A blocking client for the Pinpoint SMS Voice API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PinpointSmsVoiceClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PinpointSmsVoiceBlockingClient {
+    client: PinpointSmsVoiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PinpointSmsVoiceBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PinpointSmsVoiceBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PinpointSmsVoiceBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PinpointSmsVoiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PinpointSmsVoiceBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PinpointSmsVoiceBlockingClient {
+        PinpointSmsVoiceBlockingClient {
+            client: PinpointSmsVoiceClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Create a new configuration set. After you create the configuration set, you can add one or more event destinations to it.</p>
+    ///
+    /// See `PinpointSmsVoice::create_configuration_set`.
+    pub fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_set(input))
+    }
+
+    /// <p>Create a new event destination in a configuration set.</p>
+    ///
+    /// See `PinpointSmsVoice::create_configuration_set_event_destination`.
+    pub fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p>Deletes an existing configuration set.</p>
+    ///
+    /// See `PinpointSmsVoice::delete_configuration_set`.
+    pub fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_set(input))
+    }
+
+    /// <p>Deletes an event destination in a configuration set.</p>
+    ///
+    /// See `PinpointSmsVoice::delete_configuration_set_event_destination`.
+    pub fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p>Obtain information about an event destination, including the types of events it reports, the Amazon Resource Name (ARN) of the destination, and the name of the event destination.</p>
+    ///
+    /// See `PinpointSmsVoice::get_configuration_set_event_destinations`.
+    pub fn get_configuration_set_event_destinations(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        GetConfigurationSetEventDestinationsResponse,
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_configuration_set_event_destinations(input))
+    }
+
+    /// <p>Create a new voice message and send it to a recipient&#39;s phone number.</p>
+    ///
+    /// See `PinpointSmsVoice::send_voice_message`.
+    pub fn send_voice_message(
+        &self,
+        input: SendVoiceMessageRequest,
+    ) -> Result<SendVoiceMessageResponse, RusotoError<SendVoiceMessageError>> {
+        self.runtime.block_on(self.client.send_voice_message(input))
+    }
+
+    /// <p>Update an event destination in a configuration set. An event destination is a location that you publish information about your voice calls to. For example, you can log an event to an Amazon CloudWatch destination when a call fails.</p>
+    ///
+    /// See `PinpointSmsVoice::update_configuration_set_event_destination`.
+    pub fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_event_destination(input),
+        )
+    }
+}
@@ -454,10 +454,10 @@ impl DeleteLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(DeleteLexiconError::LexiconNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteLexiconError::LexiconNotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, DeleteLexiconError::ServiceFailure(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -490,10 +490,13 @@ impl DescribeVoicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeVoicesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVoicesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeVoicesError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, DescribeVoicesError::ServiceFailure(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -526,10 +529,10 @@ impl GetLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(GetLexiconError::LexiconNotFound(err.msg))
+                    return RusotoError::service(&res, GetLexiconError::LexiconNotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, GetLexiconError::ServiceFailure(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -564,17 +567,20 @@ impl GetSpeechSynthesisTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidTaskIdException" => {
-                    return RusotoError::Service(GetSpeechSynthesisTaskError::InvalidTaskId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSpeechSynthesisTaskError::InvalidTaskId(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetSpeechSynthesisTaskError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSpeechSynthesisTaskError::ServiceFailure(err.msg),
+                    )
                 }
                 "SynthesisTaskNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSpeechSynthesisTaskError::SynthesisTaskNotFound(err.msg),
                     )
                 }
@@ -610,10 +616,10 @@ impl ListLexiconsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListLexiconsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListLexiconsError::InvalidNextToken(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListLexiconsError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, ListLexiconsError::ServiceFailure(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -646,14 +652,16 @@ impl ListSpeechSynthesisTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSpeechSynthesisTasksError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSpeechSynthesisTasksError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListSpeechSynthesisTasksError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSpeechSynthesisTasksError::ServiceFailure(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -696,27 +704,40 @@ impl PutLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidLexiconException" => {
-                    return RusotoError::Service(PutLexiconError::InvalidLexicon(err.msg))
+                    return RusotoError::service(&res, PutLexiconError::InvalidLexicon(err.msg))
                 }
                 "LexiconSizeExceededException" => {
-                    return RusotoError::Service(PutLexiconError::LexiconSizeExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLexiconError::LexiconSizeExceeded(err.msg),
+                    )
                 }
                 "MaxLexemeLengthExceededException" => {
-                    return RusotoError::Service(PutLexiconError::MaxLexemeLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLexiconError::MaxLexemeLengthExceeded(err.msg),
+                    )
                 }
                 "MaxLexiconsNumberExceededException" => {
-                    return RusotoError::Service(PutLexiconError::MaxLexiconsNumberExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLexiconError::MaxLexiconsNumberExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service(&res, PutLexiconError::ServiceFailure(err.msg))
                 }
                 "UnsupportedPlsAlphabetException" => {
-                    return RusotoError::Service(PutLexiconError::UnsupportedPlsAlphabet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLexiconError::UnsupportedPlsAlphabet(err.msg),
+                    )
                 }
                 "UnsupportedPlsLanguageException" => {
-                    return RusotoError::Service(PutLexiconError::UnsupportedPlsLanguage(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutLexiconError::UnsupportedPlsLanguage(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -774,64 +795,76 @@ impl StartSpeechSynthesisTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EngineNotSupportedException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::EngineNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::EngineNotSupported(err.msg),
+                    )
                 }
                 "InvalidS3BucketException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidS3Bucket(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::InvalidS3Bucket(err.msg),
+                    )
                 }
                 "InvalidS3KeyException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidS3Key(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::InvalidS3Key(err.msg),
+                    )
                 }
                 "InvalidSampleRateException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSampleRate(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::InvalidSampleRate(err.msg),
+                    )
                 }
                 "InvalidSnsTopicArnException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSnsTopicArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::InvalidSnsTopicArn(err.msg),
+                    )
                 }
                 "InvalidSsmlException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSsml(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::InvalidSsml(err.msg),
+                    )
                 }
                 "LanguageNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSpeechSynthesisTaskError::LanguageNotSupported(err.msg),
                     )
                 }
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::LexiconNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::LexiconNotFound(err.msg),
+                    )
                 }
                 "MarksNotSupportedForFormatException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSpeechSynthesisTaskError::MarksNotSupportedForFormat(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::ServiceFailure(err.msg),
+                    )
                 }
                 "SsmlMarksNotSupportedForTextTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSpeechSynthesisTaskError::SsmlMarksNotSupportedForTextType(err.msg),
                     )
                 }
                 "TextLengthExceededException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::TextLengthExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSpeechSynthesisTaskError::TextLengthExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -894,37 +927,55 @@ impl SynthesizeSpeechError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EngineNotSupportedException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::EngineNotSupported(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::EngineNotSupported(err.msg),
+                    )
                 }
                 "InvalidSampleRateException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::InvalidSampleRate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::InvalidSampleRate(err.msg),
+                    )
                 }
                 "InvalidSsmlException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::InvalidSsml(err.msg))
+                    return RusotoError::service(&res, SynthesizeSpeechError::InvalidSsml(err.msg))
                 }
                 "LanguageNotSupportedException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::LanguageNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::LanguageNotSupported(err.msg),
+                    )
                 }
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::LexiconNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::LexiconNotFound(err.msg),
+                    )
                 }
                 "MarksNotSupportedForFormatException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::MarksNotSupportedForFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::MarksNotSupportedForFormat(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::ServiceFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::ServiceFailure(err.msg),
+                    )
                 }
                 "SsmlMarksNotSupportedForTextTypeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SynthesizeSpeechError::SsmlMarksNotSupportedForTextType(err.msg),
                     )
                 }
                 "TextLengthExceededException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::TextLengthExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SynthesizeSpeechError::TextLengthExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1027,6 +1078,7 @@ impl PollyClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1042,6 +1094,7 @@ impl PollyClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PollyClient {
         PollyClient { client, region }
     }
@@ -1058,6 +1111,9 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "polly", &self.region, &request_uri);
+        request.set_operation_name("DeleteLexicon");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1086,6 +1142,9 @@ impl Polly for PollyClient {
         let request_uri = "/v1/voices";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation_name("DescribeVoices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1129,6 +1188,9 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation_name("GetLexicon");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1157,6 +1219,9 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/synthesisTasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation_name("GetSpeechSynthesisTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1185,6 +1250,9 @@ impl Polly for PollyClient {
         let request_uri = "/v1/lexicons";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation_name("ListLexicons");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1219,6 +1287,9 @@ impl Polly for PollyClient {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation_name("ListSpeechSynthesisTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1259,6 +1330,9 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("PUT", "polly", &self.region, &request_uri);
+        request.set_operation_name("PutLexicon");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1290,6 +1364,9 @@ impl Polly for PollyClient {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation_name("StartSpeechSynthesisTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1321,6 +1398,9 @@ impl Polly for PollyClient {
         let request_uri = "/v1/speech";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation_name("SynthesizeSpeech");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1350,3 +1430,146 @@ impl Polly for PollyClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Polly API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PollyClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PollyBlockingClient {
+    client: PollyClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PollyBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PollyBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PollyBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PollyBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PollyBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> PollyBlockingClient {
+        PollyBlockingClient {
+            client: PollyClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Deletes the specified pronunciation lexicon stored in an AWS Region. A lexicon which has been deleted is not available for speech synthesis, nor is it possible to retrieve it using either the <code>GetLexicon</code> or <code>ListLexicon</code> APIs.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    ///
+    /// See `Polly::delete_lexicon`.
+    pub fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> Result<DeleteLexiconOutput, RusotoError<DeleteLexiconError>> {
+        self.runtime.block_on(self.client.delete_lexicon(input))
+    }
+
+    /// <p>Returns the list of voices that are available for use when requesting speech synthesis. Each voice speaks a specified language, is either male or female, and is identified by an ID, which is the ASCII version of the voice name. </p> <p>When synthesizing speech ( <code>SynthesizeSpeech</code> ), you provide the voice ID for the voice you want from the list of voices returned by <code>DescribeVoices</code>.</p> <p>For example, you want your news reader application to read news in a specific language, but giving a user the option to choose the voice. Using the <code>DescribeVoices</code> operation you can provide the user with a list of available voices to select from.</p> <p> You can optionally specify a language code to filter the available voices. For example, if you specify <code>en-US</code>, the operation returns a list of all available US English voices. </p> <p>This operation requires permissions to perform the <code>polly:DescribeVoices</code> action.</p>
+    ///
+    /// See `Polly::describe_voices`.
+    pub fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>> {
+        self.runtime.block_on(self.client.describe_voices(input))
+    }
+
+    /// <p>Returns the content of the specified pronunciation lexicon stored in an AWS Region. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    ///
+    /// See `Polly::get_lexicon`.
+    pub fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> Result<GetLexiconOutput, RusotoError<GetLexiconError>> {
+        self.runtime.block_on(self.client.get_lexicon(input))
+    }
+
+    /// <p>Retrieves a specific SpeechSynthesisTask object based on its TaskID. This object contains information about the given speech synthesis task, including the status of the task, and a link to the S3 bucket containing the output of the task.</p>
+    ///
+    /// See `Polly::get_speech_synthesis_task`.
+    pub fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> Result<GetSpeechSynthesisTaskOutput, RusotoError<GetSpeechSynthesisTaskError>> {
+        self.runtime
+            .block_on(self.client.get_speech_synthesis_task(input))
+    }
+
+    /// <p>Returns a list of pronunciation lexicons stored in an AWS Region. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    ///
+    /// See `Polly::list_lexicons`.
+    pub fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> Result<ListLexiconsOutput, RusotoError<ListLexiconsError>> {
+        self.runtime.block_on(self.client.list_lexicons(input))
+    }
+
+    /// <p>Returns a list of SpeechSynthesisTask objects ordered by their creation date. This operation can filter the tasks by their status, for example, allowing users to list only tasks that are completed.</p>
+    ///
+    /// See `Polly::list_speech_synthesis_tasks`.
+    pub fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> Result<ListSpeechSynthesisTasksOutput, RusotoError<ListSpeechSynthesisTasksError>> {
+        self.runtime
+            .block_on(self.client.list_speech_synthesis_tasks(input))
+    }
+
+    /// <p>Stores a pronunciation lexicon in an AWS Region. If a lexicon with the same name already exists in the region, it is overwritten by the new lexicon. Lexicon operations have eventual consistency, therefore, it might take some time before the lexicon is available to the SynthesizeSpeech operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/managing-lexicons.html">Managing Lexicons</a>.</p>
+    ///
+    /// See `Polly::put_lexicon`.
+    pub fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> Result<PutLexiconOutput, RusotoError<PutLexiconError>> {
+        self.runtime.block_on(self.client.put_lexicon(input))
+    }
+
+    /// <p>Allows the creation of an asynchronous synthesis task, by starting a new <code>SpeechSynthesisTask</code>. This operation requires all the standard information needed for speech synthesis, plus the name of an Amazon S3 bucket for the service to store the output of the synthesis task and two optional parameters (OutputS3KeyPrefix and SnsTopicArn). Once the synthesis task is created, this operation will return a SpeechSynthesisTask object, which will include an identifier of this task as well as the current status.</p>
+    ///
+    /// See `Polly::start_speech_synthesis_task`.
+    pub fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> Result<StartSpeechSynthesisTaskOutput, RusotoError<StartSpeechSynthesisTaskError>> {
+        self.runtime
+            .block_on(self.client.start_speech_synthesis_task(input))
+    }
+
+    /// <p>Synthesizes UTF-8 input, plain text or SSML, to a stream of bytes. SSML input must be valid, well-formed SSML. Some alphabets might not be available with all the voices (for example, Cyrillic might not be read at all by English voices) unless phoneme mapping is used. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/how-text-to-speech-works.html">How it Works</a>.</p>
+    ///
+    /// See `Polly::synthesize_speech`.
+    pub fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> Result<SynthesizeSpeechOutput, RusotoError<SynthesizeSpeechError>> {
+        self.runtime.block_on(self.client.synthesize_speech(input))
+    }
+}
@@ -222,19 +222,31 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(DescribeServicesError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::ExpiredNextToken(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeServicesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeServicesError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeServicesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -276,19 +288,31 @@ impl GetAttributeValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(GetAttributeValuesError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAttributeValuesError::ExpiredNextToken(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAttributeValuesError::InternalError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAttributeValuesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAttributeValuesError::InvalidParameter(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAttributeValuesError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetAttributeValuesError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -330,19 +354,19 @@ impl GetProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(GetProductsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service(&res, GetProductsError::ExpiredNextToken(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProductsError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetProductsError::InternalError(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetProductsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, GetProductsError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetProductsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetProductsError::InvalidParameter(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProductsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetProductsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -403,6 +427,7 @@ impl PricingClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -418,6 +443,7 @@ impl PricingClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> PricingClient {
         PricingClient { client, region }
     }
@@ -431,6 +457,9 @@ impl Pricing for PricingClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSPriceListService.DescribeServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -449,6 +478,9 @@ impl Pricing for PricingClient {
         input: GetAttributeValuesRequest,
     ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAttributeValues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSPriceListService.GetAttributeValues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -467,6 +499,9 @@ impl Pricing for PricingClient {
         input: GetProductsRequest,
     ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetProducts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSPriceListService.GetProducts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -479,3 +514,84 @@ impl Pricing for PricingClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetProductsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Pricing API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `PricingClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PricingBlockingClient {
+    client: PricingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PricingBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> PricingBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        PricingBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PricingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PricingBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> PricingBlockingClient {
+        PricingBlockingClient {
+            client: PricingClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns the metadata for one service or a list of the metadata for all services. Use this without a service code to get the service codes for all services. Use it with a service code, such as <code>AmazonEC2</code>, to get information specific to that service, such as the attribute names available for that service. For example, some of the attribute names available for EC2 are <code>volumeType</code>, <code>maxIopsVolume</code>, <code>operation</code>, <code>locationType</code>, and <code>instanceCapacity10xlarge</code>.</p>
+    ///
+    /// See `Pricing::describe_services`.
+    pub fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.runtime.block_on(self.client.describe_services(input))
+    }
+
+    /// <p>Returns a list of attribute values. Attibutes are similar to the details in a Price List API offer file. For a list of available attributes, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/reading-an-offer.html#pps-defs">Offer File Definitions</a> in the <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/billing-what-is.html">AWS Billing and Cost Management User Guide</a>.</p>
+    ///
+    /// See `Pricing::get_attribute_values`.
+    pub fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
+        self.runtime
+            .block_on(self.client.get_attribute_values(input))
+    }
+
+    /// <p>Returns a list of all products that match the filter criteria.</p>
+    ///
+    /// See `Pricing::get_products`.
+    pub fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
+        self.runtime.block_on(self.client.get_products(input))
+    }
+}
@@ -311,19 +311,19 @@ impl SendCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SendCommandError::BadRequest(err.msg))
+                    return RusotoError::service(&res, SendCommandError::BadRequest(err.msg))
                 }
                 "InvalidSessionException" => {
-                    return RusotoError::Service(SendCommandError::InvalidSession(err.msg))
+                    return RusotoError::service(&res, SendCommandError::InvalidSession(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendCommandError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, SendCommandError::LimitExceeded(err.msg))
                 }
                 "OccConflictException" => {
-                    return RusotoError::Service(SendCommandError::OccConflict(err.msg))
+                    return RusotoError::service(&res, SendCommandError::OccConflict(err.msg))
                 }
                 "RateExceededException" => {
-                    return RusotoError::Service(SendCommandError::RateExceeded(err.msg))
+                    return RusotoError::service(&res, SendCommandError::RateExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -372,6 +372,7 @@ impl QldbSessionClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -387,6 +388,7 @@ impl QldbSessionClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> QldbSessionClient {
         QldbSessionClient { client, region }
     }
@@ -400,6 +402,9 @@ impl QldbSession for QldbSessionClient {
         input: SendCommandRequest,
     ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendCommand");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "QLDBSession.SendCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -412,3 +417,63 @@ impl QldbSession for QldbSessionClient {
         proto::json::ResponsePayload::new(&response).deserialize::<SendCommandResult, _>()
     }
 }
+
+/// A blocking client for the QLDB Session API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `QldbSessionClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct QldbSessionBlockingClient {
+    client: QldbSessionClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl QldbSessionBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> QldbSessionBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        QldbSessionBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> QldbSessionBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        QldbSessionBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient {
+            client: QldbSessionClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Sends a command to an Amazon QLDB ledger.</p> <note> <p>Instead of interacting directly with this API, we recommend that you use the Amazon QLDB Driver or the QLDB Shell to execute data transactions on a ledger.</p> <ul> <li> <p>If you are working with an AWS SDK, use the QLDB Driver. The driver provides a high-level abstraction layer above this <code>qldbsession</code> data plane and manages <code>SendCommand</code> API calls for you. For information and a list of supported programming languages, see <a href="https://docs.aws.amazon.com/qldb/latest/developerguide/getting-started-driver.html">Getting started with the driver</a> in the <i>Amazon QLDB Developer Guide</i>.</p> </li> <li> <p>If you are working with the AWS Command Line Interface (AWS CLI), use the QLDB Shell. The shell is a command line interface that uses the QLDB Driver to interact with a ledger. For information, see <a href="https://docs.aws.amazon.com/qldb/latest/developerguide/data-shell.html">Accessing Amazon QLDB using the QLDB Shell</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `QldbSession::send_command`.
+    pub fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+        self.runtime.block_on(self.client.send_command(input))
+    }
+}
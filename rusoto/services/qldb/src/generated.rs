@@ -665,17 +665,20 @@ impl CancelJournalKinesisStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelJournalKinesisStreamError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelJournalKinesisStreamError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJournalKinesisStreamError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelJournalKinesisStreamError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelJournalKinesisStreamError::ResourcePreconditionNotMet(err.msg),
                     )
                 }
@@ -717,16 +720,19 @@ impl CreateLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateLedgerError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLedgerError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateLedgerError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLedgerError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLedgerError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateLedgerError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateLedgerError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -765,18 +771,19 @@ impl DeleteLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteLedgerError::InvalidParameter(err.msg))
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteLedgerError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteLedgerError::ResourceNotFound(err.msg))
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourcePreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLedgerError::ResourcePreconditionNotMet(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -815,17 +822,20 @@ impl DescribeJournalKinesisStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeJournalKinesisStreamError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeJournalKinesisStreamError::ResourceNotFound(err.msg),
                     )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeJournalKinesisStreamError::ResourcePreconditionNotMet(err.msg),
                     )
                 }
@@ -865,9 +875,10 @@ impl DescribeJournalS3ExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJournalS3ExportError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeJournalS3ExportError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -899,10 +910,16 @@ impl DescribeLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLedgerError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLedgerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -935,10 +952,14 @@ impl ExportJournalToS3Error {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportJournalToS3Error::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExportJournalToS3Error::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExportJournalToS3Error::ResourcePreconditionNotMet(err.msg),
                     )
                 }
@@ -975,13 +996,16 @@ impl GetBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetBlockError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetBlockError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetBlockError::ResourceNotFound(err.msg))
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetBlockError::ResourcePreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetBlockError::ResourcePreconditionNotMet(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1017,15 +1041,16 @@ impl GetDigestError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDigestError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetDigestError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDigestError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetDigestError::ResourceNotFound(err.msg))
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetDigestError::ResourcePreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDigestError::ResourcePreconditionNotMet(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1061,15 +1086,16 @@ impl GetRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRevisionError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetRevisionError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetRevisionError::ResourceNotFound(err.msg))
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetRevisionError::ResourcePreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRevisionError::ResourcePreconditionNotMet(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1107,17 +1133,20 @@ impl ListJournalKinesisStreamsForLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListJournalKinesisStreamsForLedgerError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListJournalKinesisStreamsForLedgerError::ResourceNotFound(err.msg),
                     )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListJournalKinesisStreamsForLedgerError::ResourcePreconditionNotMet(
                             err.msg,
                         ),
@@ -1229,14 +1258,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1271,17 +1302,20 @@ impl StreamJournalToKinesisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StreamJournalToKinesisError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StreamJournalToKinesisError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StreamJournalToKinesisError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StreamJournalToKinesisError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StreamJournalToKinesisError::ResourcePreconditionNotMet(err.msg),
                     )
                 }
@@ -1319,10 +1353,10 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1355,10 +1389,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1391,10 +1431,10 @@ impl UpdateLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateLedgerError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateLedgerError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1554,6 +1594,7 @@ impl QldbClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1569,6 +1610,7 @@ impl QldbClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> QldbClient {
         QldbClient { client, region }
     }
@@ -1590,6 +1632,9 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation_name("CancelJournalKinesisStream");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1618,6 +1663,9 @@ impl Qldb for QldbClient {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("CreateLedger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1649,6 +1697,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation_name("DeleteLedger");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1681,6 +1732,9 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("DescribeJournalKinesisStream");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1713,6 +1767,9 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("DescribeJournalS3Export");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1741,6 +1798,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("DescribeLedger");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1769,6 +1829,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("ExportJournalToS3");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1800,6 +1863,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/block", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("GetBlock");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1831,6 +1897,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/digest", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("GetDigest");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1859,6 +1928,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/revision", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("GetRevision");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1896,6 +1968,9 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("ListJournalKinesisStreamsForLedger");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1935,6 +2010,9 @@ impl Qldb for QldbClient {
         let request_uri = "/journal-s3-exports";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("ListJournalS3Exports");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1975,6 +2053,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("ListJournalS3ExportsForLedger");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2012,6 +2093,9 @@ impl Qldb for QldbClient {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("ListLedgers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2049,6 +2133,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2080,6 +2167,9 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("StreamJournalToKinesis");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2111,6 +2201,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2142,6 +2235,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2176,6 +2272,9 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "qldb", &self.region, &request_uri);
+        request.set_operation_name("UpdateLedger");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2198,3 +2297,260 @@ impl Qldb for QldbClient {
         }
     }
 }
+
+/// A blocking client for the QLDB API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `QldbClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct QldbBlockingClient {
+    client: QldbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl QldbBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> QldbBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        QldbBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> QldbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        QldbBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> QldbBlockingClient {
+        QldbBlockingClient {
+            client: QldbClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Ends a given Amazon QLDB journal stream. Before a stream can be canceled, its current status must be <code>ACTIVE</code>.</p> <p>You can't restart a stream after you cancel it. Canceled QLDB stream resources are subject to a 7-day retention period, so they are automatically deleted after this limit expires.</p>
+    ///
+    /// See `Qldb::cancel_journal_kinesis_stream`.
+    pub fn cancel_journal_kinesis_stream(
+        &self,
+        input: CancelJournalKinesisStreamRequest,
+    ) -> Result<CancelJournalKinesisStreamResponse, RusotoError<CancelJournalKinesisStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.cancel_journal_kinesis_stream(input))
+    }
+
+    /// <p>Creates a new ledger in your AWS account.</p>
+    ///
+    /// See `Qldb::create_ledger`.
+    pub fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> Result<CreateLedgerResponse, RusotoError<CreateLedgerError>> {
+        self.runtime.block_on(self.client.create_ledger(input))
+    }
+
+    /// <p>Deletes a ledger and all of its contents. This action is irreversible.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
+    ///
+    /// See `Qldb::delete_ledger`.
+    pub fn delete_ledger(
+        &self,
+        input: DeleteLedgerRequest,
+    ) -> Result<(), RusotoError<DeleteLedgerError>> {
+        self.runtime.block_on(self.client.delete_ledger(input))
+    }
+
+    /// <p>Returns detailed information about a given Amazon QLDB journal stream. The output includes the Amazon Resource Name (ARN), stream name, current status, creation time, and the parameters of your original stream creation request.</p>
+    ///
+    /// See `Qldb::describe_journal_kinesis_stream`.
+    pub fn describe_journal_kinesis_stream(
+        &self,
+        input: DescribeJournalKinesisStreamRequest,
+    ) -> Result<DescribeJournalKinesisStreamResponse, RusotoError<DescribeJournalKinesisStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_journal_kinesis_stream(input))
+    }
+
+    /// <p>Returns information about a journal export job, including the ledger name, export ID, when it was created, current status, and its start and end time export parameters.</p> <p>This action does not return any expired export jobs. For more information, see <a href="https://docs.aws.amazon.com/qldb/latest/developerguide/export-journal.request.html#export-journal.request.expiration">Export Job Expiration</a> in the <i>Amazon QLDB Developer Guide</i>.</p> <p>If the export job with the given <code>ExportId</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p> <p>If the ledger with the given <code>Name</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p>
+    ///
+    /// See `Qldb::describe_journal_s3_export`.
+    pub fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> Result<DescribeJournalS3ExportResponse, RusotoError<DescribeJournalS3ExportError>> {
+        self.runtime
+            .block_on(self.client.describe_journal_s3_export(input))
+    }
+
+    /// <p>Returns information about a ledger, including its state and when it was created.</p>
+    ///
+    /// See `Qldb::describe_ledger`.
+    pub fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> Result<DescribeLedgerResponse, RusotoError<DescribeLedgerError>> {
+        self.runtime.block_on(self.client.describe_ledger(input))
+    }
+
+    /// <p>Exports journal contents within a date and time range from a ledger into a specified Amazon Simple Storage Service (Amazon S3) bucket. The data is written as files in Amazon Ion format.</p> <p>If the ledger with the given <code>Name</code> doesn't exist, then throws <code>ResourceNotFoundException</code>.</p> <p>If the ledger with the given <code>Name</code> is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>You can initiate up to two concurrent journal export requests for each ledger. Beyond this limit, journal export requests throw <code>LimitExceededException</code>.</p>
+    ///
+    /// See `Qldb::export_journal_to_s3`.
+    pub fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> Result<ExportJournalToS3Response, RusotoError<ExportJournalToS3Error>> {
+        self.runtime
+            .block_on(self.client.export_journal_to_s3(input))
+    }
+
+    /// <p>Returns a block object at a specified address in a journal. Also returns a proof of the specified block for verification if <code>DigestTipAddress</code> is provided.</p> <p>For information about the data contents in a block, see <a href="https://docs.aws.amazon.com/qldb/latest/developerguide/journal-contents.html">Journal contents</a> in the <i>Amazon QLDB Developer Guide</i>.</p> <p>If the specified ledger doesn't exist or is in <code>DELETING</code> status, then throws <code>ResourceNotFoundException</code>.</p> <p>If the specified ledger is in <code>CREATING</code> status, then throws <code>ResourcePreconditionNotMetException</code>.</p> <p>If no block exists with the specified address, then throws <code>InvalidParameterException</code>.</p>
+    ///
+    /// See `Qldb::get_block`.
+    pub fn get_block(
+        &self,
+        input: GetBlockRequest,
+    ) -> Result<GetBlockResponse, RusotoError<GetBlockError>> {
+        self.runtime.block_on(self.client.get_block(input))
+    }
+
+    /// <p>Returns the digest of a ledger at the latest committed block in the journal. The response includes a 256-bit hash value and a block address.</p>
+    ///
+    /// See `Qldb::get_digest`.
+    pub fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> Result<GetDigestResponse, RusotoError<GetDigestError>> {
+        self.runtime.block_on(self.client.get_digest(input))
+    }
+
+    /// <p>Returns a revision data object for a specified document ID and block address. Also returns a proof of the specified revision for verification if <code>DigestTipAddress</code> is provided.</p>
+    ///
+    /// See `Qldb::get_revision`.
+    pub fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+        self.runtime.block_on(self.client.get_revision(input))
+    }
+
+    /// <p>Returns an array of all Amazon QLDB journal stream descriptors for a given ledger. The output of each stream descriptor includes the same details that are returned by <code>DescribeJournalKinesisStream</code>.</p> <p>This action returns a maximum of <code>MaxResults</code> items. It is paginated so that you can retrieve all the items by calling <code>ListJournalKinesisStreamsForLedger</code> multiple times.</p>
+    ///
+    /// See `Qldb::list_journal_kinesis_streams_for_ledger`.
+    pub fn list_journal_kinesis_streams_for_ledger(
+        &self,
+        input: ListJournalKinesisStreamsForLedgerRequest,
+    ) -> Result<
+        ListJournalKinesisStreamsForLedgerResponse,
+        RusotoError<ListJournalKinesisStreamsForLedgerError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_journal_kinesis_streams_for_ledger(input))
+    }
+
+    /// <p>Returns an array of journal export job descriptions for all ledgers that are associated with the current AWS account and Region.</p> <p>This action returns a maximum of <code>MaxResults</code> items, and is paginated so that you can retrieve all the items by calling <code>ListJournalS3Exports</code> multiple times.</p> <p>This action does not return any expired export jobs. For more information, see <a href="https://docs.aws.amazon.com/qldb/latest/developerguide/export-journal.request.html#export-journal.request.expiration">Export Job Expiration</a> in the <i>Amazon QLDB Developer Guide</i>.</p>
+    ///
+    /// See `Qldb::list_journal_s3_exports`.
+    pub fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> Result<ListJournalS3ExportsResponse, RusotoError<ListJournalS3ExportsError>> {
+        self.runtime
+            .block_on(self.client.list_journal_s3_exports(input))
+    }
+
+    /// <p>Returns an array of journal export job descriptions for a specified ledger.</p> <p>This action returns a maximum of <code>MaxResults</code> items, and is paginated so that you can retrieve all the items by calling <code>ListJournalS3ExportsForLedger</code> multiple times.</p> <p>This action does not return any expired export jobs. For more information, see <a href="https://docs.aws.amazon.com/qldb/latest/developerguide/export-journal.request.html#export-journal.request.expiration">Export Job Expiration</a> in the <i>Amazon QLDB Developer Guide</i>.</p>
+    ///
+    /// See `Qldb::list_journal_s3_exports_for_ledger`.
+    pub fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> Result<
+        ListJournalS3ExportsForLedgerResponse,
+        RusotoError<ListJournalS3ExportsForLedgerError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_journal_s3_exports_for_ledger(input))
+    }
+
+    /// <p>Returns an array of ledger summaries that are associated with the current AWS account and Region.</p> <p>This action returns a maximum of 100 items and is paginated so that you can retrieve all the items by calling <code>ListLedgers</code> multiple times.</p>
+    ///
+    /// See `Qldb::list_ledgers`.
+    pub fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> Result<ListLedgersResponse, RusotoError<ListLedgersError>> {
+        self.runtime.block_on(self.client.list_ledgers(input))
+    }
+
+    /// <p>Returns all tags for a specified Amazon QLDB resource.</p>
+    ///
+    /// See `Qldb::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates a journal stream for a given Amazon QLDB ledger. The stream captures every document revision that is committed to the ledger's journal and delivers the data to a specified Amazon Kinesis Data Streams resource.</p>
+    ///
+    /// See `Qldb::stream_journal_to_kinesis`.
+    pub fn stream_journal_to_kinesis(
+        &self,
+        input: StreamJournalToKinesisRequest,
+    ) -> Result<StreamJournalToKinesisResponse, RusotoError<StreamJournalToKinesisError>> {
+        self.runtime
+            .block_on(self.client.stream_journal_to_kinesis(input))
+    }
+
+    /// <p>Adds one or more tags to a specified Amazon QLDB resource.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, your request fails and returns an error.</p>
+    ///
+    /// See `Qldb::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a specified Amazon QLDB resource. You can specify up to 50 tag keys to remove.</p>
+    ///
+    /// See `Qldb::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates properties on a ledger.</p>
+    ///
+    /// See `Qldb::update_ledger`.
+    pub fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> Result<UpdateLedgerResponse, RusotoError<UpdateLedgerError>> {
+        self.runtime.block_on(self.client.update_ledger(input))
+    }
+}
@@ -5158,24 +5158,34 @@ impl CancelIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CancelIngestionError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelIngestionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CancelIngestionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelIngestionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CancelIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelIngestionError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelIngestionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelIngestionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CancelIngestionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5222,29 +5232,40 @@ impl CreateDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDashboardError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDashboardError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDashboardError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDashboardError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDashboardError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDashboardError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDashboardError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDashboardError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDashboardError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDashboardError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5296,33 +5317,40 @@ impl CreateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSetError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSetError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDataSetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDataSetError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDataSetError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateDataSetError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSetError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5374,30 +5402,43 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDataSourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5450,31 +5491,40 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::LimitExceeded(err.msg))
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5524,35 +5574,46 @@ impl CreateGroupMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupMembershipError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5600,39 +5661,46 @@ impl CreateIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIAMPolicyAssignmentError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIAMPolicyAssignmentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIAMPolicyAssignmentError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIAMPolicyAssignmentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5682,27 +5750,37 @@ impl CreateIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateIngestionError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIngestionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateIngestionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIngestionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIngestionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateIngestionError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIngestionError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIngestionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIngestionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateIngestionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5754,35 +5832,43 @@ impl CreateTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTemplateError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateTemplateError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateTemplateError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateTemplateError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateTemplateError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateTemplateError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5832,29 +5918,43 @@ impl CreateTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateTemplateAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateAliasError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateAliasError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateAliasError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTemplateAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5904,28 +6004,34 @@ impl CreateThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateThemeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateThemeError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThemeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, CreateThemeError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateThemeError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateThemeError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateThemeError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateThemeError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateThemeError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThemeError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateThemeError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateThemeError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5976,32 +6082,46 @@ impl CreateThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateThemeAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateThemeAliasError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateThemeAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeAliasError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateThemeAliasError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeAliasError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateThemeAliasError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateThemeAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateThemeAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6048,26 +6168,34 @@ impl DeleteDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDashboardError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteDashboardError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDashboardError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDashboardError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDashboardError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDashboardError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDashboardError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDashboardError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6110,19 +6238,25 @@ impl DeleteDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSetError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDataSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6164,21 +6298,28 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6224,25 +6365,34 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6290,35 +6440,46 @@ impl DeleteGroupMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupMembershipError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6366,39 +6527,46 @@ impl DeleteIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIAMPolicyAssignmentError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIAMPolicyAssignmentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIAMPolicyAssignmentError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIAMPolicyAssignmentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6448,29 +6616,37 @@ impl DeleteTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteTemplateError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteTemplateError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteTemplateError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6514,23 +6690,31 @@ impl DeleteTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteTemplateAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateAliasError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTemplateAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6576,25 +6760,31 @@ impl DeleteThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteThemeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteThemeError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteThemeError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteThemeError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThemeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteThemeError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThemeError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteThemeError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThemeError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteThemeError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteThemeError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThemeError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6640,26 +6830,34 @@ impl DeleteThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteThemeAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThemeAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThemeAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThemeAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteThemeAliasError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteThemeAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6704,22 +6902,28 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteUserError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUserError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6764,32 +6968,40 @@ impl DeleteUserByPrincipalIdError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserByPrincipalIdError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserByPrincipalIdError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteUserByPrincipalIdError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserByPrincipalIdError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserByPrincipalIdError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserByPrincipalIdError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6836,26 +7048,37 @@ impl DescribeDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDashboardError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDashboardError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDashboardError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDashboardError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDashboardError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDashboardError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDashboardError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDashboardError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6900,27 +7123,32 @@ impl DescribeDashboardPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDashboardPermissionsError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDashboardPermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDashboardPermissionsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDashboardPermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDashboardPermissionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDashboardPermissionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -6970,21 +7198,28 @@ impl DescribeDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeDataSetError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDataSetError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDataSetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7028,29 +7263,34 @@ impl DescribeDataSetPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetPermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetPermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataSetPermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetPermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSetPermissionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7094,21 +7334,31 @@ impl DescribeDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7152,29 +7402,34 @@ impl DescribeDataSourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourcePermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourcePermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataSourcePermissionsError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataSourcePermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDataSourcePermissionsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourcePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDataSourcePermissionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7226,25 +7481,37 @@ impl DescribeGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeGroupError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DescribeGroupError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DescribeGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7292,34 +7559,40 @@ impl DescribeIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIAMPolicyAssignmentError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIAMPolicyAssignmentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIAMPolicyAssignmentError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIAMPolicyAssignmentError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIAMPolicyAssignmentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7366,24 +7639,37 @@ impl DescribeIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIngestionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIngestionError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeIngestionError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIngestionError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIngestionError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIngestionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIngestionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeIngestionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7432,32 +7718,43 @@ impl DescribeTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeTemplateError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeTemplateError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, DescribeTemplateError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeTemplateError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7500,20 +7797,26 @@ impl DescribeTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplateAliasError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTemplateAliasError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -7560,32 +7863,38 @@ impl DescribeTemplatePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplatePermissionsError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplatePermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTemplatePermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTemplatePermissionsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTemplatePermissionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTemplatePermissionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -7638,27 +7947,34 @@ impl DescribeThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeThemeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeThemeError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThemeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DescribeThemeError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeThemeError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, DescribeThemeError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThemeError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeThemeError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeThemeError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7704,26 +8020,34 @@ impl DescribeThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, DescribeThemeAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeThemeAliasError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemeAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7768,30 +8092,38 @@ impl DescribeThemePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemePermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemePermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeThemePermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeThemePermissionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeThemePermissionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -7842,22 +8174,28 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeUserError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeUserError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7912,53 +8250,70 @@ impl GetDashboardEmbedUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::AccessDenied(err.msg),
+                    )
                 }
                 "DomainNotWhitelistedException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::DomainNotWhitelisted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::DomainNotWhitelisted(err.msg),
+                    )
                 }
                 "IdentityTypeNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDashboardEmbedUrlError::IdentityTypeNotSupported(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "QuickSightUserNotFoundException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::QuickSightUserNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::QuickSightUserNotFound(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::ResourceNotFound(err.msg),
+                    )
                 }
                 "SessionLifetimeInMinutesInvalidException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDashboardEmbedUrlError::SessionLifetimeInMinutesInvalid(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDashboardEmbedUrlError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8012,30 +8367,38 @@ impl ListDashboardVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardVersionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardVersionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardVersionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListDashboardVersionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -8078,18 +8441,25 @@ impl ListDashboardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDashboardsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDashboardsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDashboardsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDashboardsError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListDashboardsError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDashboardsError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8130,19 +8500,22 @@ impl ListDataSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSetsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSetsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::InternalFailure(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDataSetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDataSetsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSetsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDataSetsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8184,21 +8557,28 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourcesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourcesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDataSourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourcesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDataSourcesError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDataSourcesError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourcesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDataSourcesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8246,40 +8626,52 @@ impl ListGroupMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::PreconditionNotMet(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembershipsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8330,28 +8722,34 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::InternalFailure(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListGroupsError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::PreconditionNotMet(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupsError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListGroupsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8398,32 +8796,40 @@ impl ListIAMPolicyAssignmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIAMPolicyAssignmentsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIAMPolicyAssignmentsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIAMPolicyAssignmentsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIAMPolicyAssignmentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIAMPolicyAssignmentsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8474,39 +8880,46 @@ impl ListIAMPolicyAssignmentsForUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsForUserError::AccessDenied(err.msg),
                     )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsForUserError::ConcurrentUpdating(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsForUserError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsForUserError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsForUserError::ResourceExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListIAMPolicyAssignmentsForUserError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsForUserError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIAMPolicyAssignmentsForUserError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8564,27 +8977,37 @@ impl ListIngestionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIngestionsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListIngestionsError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIngestionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIngestionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIngestionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIngestionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListIngestionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListIngestionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(ListIngestionsError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, ListIngestionsError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIngestionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListIngestionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIngestionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListIngestionsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8628,23 +9051,34 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8686,25 +9120,34 @@ impl ListTemplateAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateAliasesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateAliasesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateAliasesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateAliasesError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateAliasesError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8748,32 +9191,40 @@ impl ListTemplateVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateVersionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateVersionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateVersionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplateVersionsError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8818,24 +9269,34 @@ impl ListTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplatesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListTemplatesError::InternalFailure(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplatesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplatesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTemplatesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplatesError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplatesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplatesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplatesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListTemplatesError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplatesError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTemplatesError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8882,29 +9343,40 @@ impl ListThemeAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(ListThemeAliasesError::Conflict(err.msg))
+                    return RusotoError::service(&res, ListThemeAliasesError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThemeAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeAliasesError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListThemeAliasesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeAliasesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListThemeAliasesError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeAliasesError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThemeAliasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeAliasesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThemeAliasesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListThemeAliasesError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListThemeAliasesError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeAliasesError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8952,29 +9424,43 @@ impl ListThemeVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListThemeVersionsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeVersionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThemeVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeVersionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListThemeVersionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeVersionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListThemeVersionsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeVersionsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThemeVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThemeVersionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListThemeVersionsError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListThemeVersionsError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListThemeVersionsError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9022,25 +9508,31 @@ impl ListThemesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListThemesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListThemesError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThemesError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListThemesError::InternalFailure(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListThemesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListThemesError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListThemesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemesError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThemesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListThemesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThemesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListThemesError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListThemesError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListThemesError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9086,24 +9578,34 @@ impl ListUserGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListUserGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListUserGroupsError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListUserGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserGroupsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListUserGroupsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListUserGroupsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListUserGroupsError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUserGroupsError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUserGroupsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListUserGroupsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9150,25 +9652,28 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListUsersError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListUsersError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListUsersError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InternalFailure(err.msg))
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUsersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ResourceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUsersError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListUsersError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9220,31 +9725,40 @@ impl RegisterUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterUserError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, RegisterUserError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterUserError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, RegisterUserError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RegisterUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUserError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterUserError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, RegisterUserError::LimitExceeded(err.msg))
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(RegisterUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUserError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, RegisterUserError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, RegisterUserError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterUserError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, RegisterUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9292,26 +9806,37 @@ impl SearchDashboardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchDashboardsError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchDashboardsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(SearchDashboardsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchDashboardsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SearchDashboardsError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchDashboardsError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchDashboardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchDashboardsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchDashboardsError::Throttling(err.msg))
+                    return RusotoError::service(&res, SearchDashboardsError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(SearchDashboardsError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchDashboardsError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9356,22 +9881,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, TagResourceError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9414,19 +9942,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9472,29 +10006,37 @@ impl UpdateDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDashboardError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDashboardError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDashboardError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateDashboardError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDashboardError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateDashboardError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9542,30 +10084,38 @@ impl UpdateDashboardPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardPermissionsError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardPermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDashboardPermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardPermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardPermissionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDashboardPermissionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -9618,32 +10168,38 @@ impl UpdateDashboardPublishedVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardPublishedVersionError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardPublishedVersionError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDashboardPublishedVersionError::InternalFailure(err.msg),
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDashboardPublishedVersionError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDashboardPublishedVersionError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardPublishedVersionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDashboardPublishedVersionError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDashboardPublishedVersionError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -9702,30 +10258,37 @@ impl UpdateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSetError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDataSetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDataSetError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateDataSetError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9772,30 +10335,40 @@ impl UpdateDataSetPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetPermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetPermissionsError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetPermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDataSetPermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetPermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSetPermissionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9842,24 +10415,31 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateDataSourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9906,34 +10486,40 @@ impl UpdateDataSourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourcePermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourcePermissionsError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourcePermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDataSourcePermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDataSourcePermissionsError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDataSourcePermissionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9982,25 +10568,34 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateGroupError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10048,39 +10643,46 @@ impl UpdateIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIAMPolicyAssignmentError::AccessDenied(err.msg),
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIAMPolicyAssignmentError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIAMPolicyAssignmentError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIAMPolicyAssignmentError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10132,32 +10734,40 @@ impl UpdateTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplateError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateTemplateError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateTemplateError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateTemplateError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, UpdateTemplateError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplateError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateTemplateError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateTemplateError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10202,23 +10812,31 @@ impl UpdateTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateTemplateAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateAliasError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplateAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10262,30 +10880,38 @@ impl UpdateTemplatePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplatePermissionsError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplatePermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTemplatePermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplatePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTemplatePermissionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateTemplatePermissionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -10340,28 +10966,34 @@ impl UpdateThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateThemeError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateThemeError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThemeError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateThemeError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateThemeError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateThemeError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateThemeError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, UpdateThemeError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateThemeError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThemeError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateThemeError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateThemeError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10410,29 +11042,40 @@ impl UpdateThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateThemeAliasError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeAliasError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeAliasError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeAliasError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeAliasError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateThemeAliasError::Throttling(err.msg))
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::UnsupportedUserEdition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemeAliasError::UnsupportedUserEdition(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10478,28 +11121,38 @@ impl UpdateThemePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemePermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemePermissionsError::InternalFailure(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateThemePermissionsError::InvalidParameterValue(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemePermissionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateThemePermissionsError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateThemePermissionsError::UnsupportedUserEdition(err.msg),
                     )
                 }
@@ -10548,22 +11201,28 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateUserError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::AccessDenied(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateUserError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::InternalFailure(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ResourceNotFound(err.msg))
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11090,6 +11749,7 @@ impl QuicksightClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -11105,6 +11765,7 @@ impl QuicksightClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> QuicksightClient {
         QuicksightClient { client, region }
     }
@@ -11126,6 +11787,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CancelIngestion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11159,6 +11823,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateDashboard");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11194,6 +11861,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11229,6 +11899,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11265,6 +11938,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11297,6 +11973,9 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateGroupMembership");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11331,6 +12010,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateIAMPolicyAssignment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11368,6 +12050,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateIngestion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11401,6 +12086,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11438,6 +12126,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateTemplateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11474,6 +12165,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateTheme");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11511,6 +12205,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("CreateThemeAlias");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11547,6 +12244,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteDashboard");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11586,6 +12286,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11619,6 +12322,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11653,6 +12359,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11682,6 +12391,9 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteGroupMembership");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11712,6 +12424,9 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespace/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteIAMPolicyAssignment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11745,6 +12460,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11785,6 +12503,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteTemplateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11818,6 +12539,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteTheme");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11858,6 +12582,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteThemeAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11892,6 +12619,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11926,6 +12656,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DeleteUserByPrincipalId");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11959,6 +12692,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeDashboard");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12002,6 +12738,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeDashboardPermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12035,6 +12774,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeDataSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12069,6 +12811,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeDataSetPermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12102,6 +12847,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeDataSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12138,6 +12886,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeDataSourcePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12172,6 +12923,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12202,6 +12956,9 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeIAMPolicyAssignment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12236,6 +12993,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeIngestion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12269,6 +13029,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12312,6 +13075,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeTemplateAlias");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12346,6 +13112,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeTemplatePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12379,6 +13148,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeTheme");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12422,6 +13194,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeThemeAlias");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12455,6 +13230,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeThemePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12489,6 +13267,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("DescribeUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12522,6 +13303,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("GetDashboardEmbedUrl");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12571,6 +13355,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListDashboardVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12612,6 +13399,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListDashboards");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12653,6 +13443,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListDataSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12694,6 +13487,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListDataSources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12737,6 +13533,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListGroupMemberships");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12779,6 +13578,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12821,6 +13623,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListIAMPolicyAssignments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12865,6 +13670,9 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}/iam-policy-assignments", aws_account_id = input.aws_account_id, namespace = input.namespace, user_name = input.user_name);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListIAMPolicyAssignmentsForUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12909,6 +13717,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListIngestions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12950,6 +13761,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12983,6 +13797,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListTemplateAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13025,6 +13842,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListTemplateVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13066,6 +13886,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13108,6 +13931,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListThemeAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13150,6 +13976,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListThemeVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13191,6 +14020,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListThemes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13237,6 +14069,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListUserGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13279,6 +14114,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13321,6 +14159,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("RegisterUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13356,6 +14197,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("SearchDashboards");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13391,6 +14235,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13426,6 +14273,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13465,6 +14315,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDashboard");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13501,6 +14354,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDashboardPermissions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13541,6 +14397,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDashboardPublishedVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13576,6 +14435,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13612,6 +14474,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataSetPermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13648,6 +14513,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataSource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13685,6 +14553,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateDataSourcePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13722,6 +14593,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13755,6 +14629,9 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateIAMPolicyAssignment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13791,6 +14668,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13828,6 +14708,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateTemplateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13865,6 +14748,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateTemplatePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13901,6 +14787,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateTheme");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13938,6 +14827,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateThemeAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13974,6 +14866,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateThemePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14011,6 +14906,9 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation_name("UpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14034,3 +14932,895 @@ impl Quicksight for QuicksightClient {
         }
     }
 }
+
+/// A blocking client for the Amazon QuickSight API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `QuicksightClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct QuicksightBlockingClient {
+    client: QuicksightClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl QuicksightBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> QuicksightBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        QuicksightBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> QuicksightBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        QuicksightBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> QuicksightBlockingClient {
+        QuicksightBlockingClient {
+            client: QuicksightClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels an ongoing ingestion of data into SPICE.</p>
+    ///
+    /// See `Quicksight::cancel_ingestion`.
+    pub fn cancel_ingestion(
+        &self,
+        input: CancelIngestionRequest,
+    ) -> Result<CancelIngestionResponse, RusotoError<CancelIngestionError>> {
+        self.runtime.block_on(self.client.cancel_ingestion(input))
+    }
+
+    /// <p>Creates a dashboard from a template. To first create a template, see the <a>CreateTemplate</a> API operation.</p> <p>A dashboard is an entity in QuickSight that identifies QuickSight reports, created from analyses. You can share QuickSight dashboards. With the right permissions, you can create scheduled email reports from them. The <code>CreateDashboard</code>, <code>DescribeDashboard</code>, and <code>ListDashboardsByUser</code> API operations act on the dashboard entity. If you have the correct permissions, you can create a dashboard from a template that exists in a different AWS account.</p>
+    ///
+    /// See `Quicksight::create_dashboard`.
+    pub fn create_dashboard(
+        &self,
+        input: CreateDashboardRequest,
+    ) -> Result<CreateDashboardResponse, RusotoError<CreateDashboardError>> {
+        self.runtime.block_on(self.client.create_dashboard(input))
+    }
+
+    /// <p>Creates a dataset.</p>
+    ///
+    /// See `Quicksight::create_data_set`.
+    pub fn create_data_set(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+        self.runtime.block_on(self.client.create_data_set(input))
+    }
+
+    /// <p>Creates a data source.</p>
+    ///
+    /// See `Quicksight::create_data_source`.
+    pub fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.runtime.block_on(self.client.create_data_source(input))
+    }
+
+    /// <p>Creates an Amazon QuickSight group.</p> <p>The permissions resource is <code>arn:aws:quicksight:us-east-1:<i>&lt;relevant-aws-account-id&gt;</i>:group/default/<i>&lt;group-name&gt;</i> </code>.</p> <p>The response is a group object.</p>
+    ///
+    /// See `Quicksight::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p>Adds an Amazon QuickSight user to an Amazon QuickSight group. </p>
+    ///
+    /// See `Quicksight::create_group_membership`.
+    pub fn create_group_membership(
+        &self,
+        input: CreateGroupMembershipRequest,
+    ) -> Result<CreateGroupMembershipResponse, RusotoError<CreateGroupMembershipError>> {
+        self.runtime
+            .block_on(self.client.create_group_membership(input))
+    }
+
+    /// <p>Creates an assignment with one specified IAM policy, identified by its Amazon Resource Name (ARN). This policy will be assigned to specified groups or users of Amazon QuickSight. The users and groups need to be in the same namespace. </p>
+    ///
+    /// See `Quicksight::create_iam_policy_assignment`.
+    pub fn create_iam_policy_assignment(
+        &self,
+        input: CreateIAMPolicyAssignmentRequest,
+    ) -> Result<CreateIAMPolicyAssignmentResponse, RusotoError<CreateIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.create_iam_policy_assignment(input))
+    }
+
+    /// <p>Creates and starts a new SPICE ingestion on a dataset</p> <p>Any ingestions operating on tagged datasets inherit the same tags automatically for use in access control. For an example, see <a href="https://aws.amazon.com/premiumsupport/knowledge-center/iam-ec2-resource-tags/">How do I create an IAM policy to control access to Amazon EC2 resources using tags?</a> in the AWS Knowledge Center. Tags are visible on the tagged dataset, but not on the ingestion resource.</p>
+    ///
+    /// See `Quicksight::create_ingestion`.
+    pub fn create_ingestion(
+        &self,
+        input: CreateIngestionRequest,
+    ) -> Result<CreateIngestionResponse, RusotoError<CreateIngestionError>> {
+        self.runtime.block_on(self.client.create_ingestion(input))
+    }
+
+    /// <p>Creates a template from an existing QuickSight analysis or template. You can use the resulting template to create a dashboard.</p> <p>A <i>template</i> is an entity in QuickSight that encapsulates the metadata required to create an analysis and that you can use to create s dashboard. A template adds a layer of abstraction by using placeholders to replace the dataset associated with the analysis. You can use templates to create dashboards by replacing dataset placeholders with datasets that follow the same schema that was used to create the source analysis and template.</p>
+    ///
+    /// See `Quicksight::create_template`.
+    pub fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        self.runtime.block_on(self.client.create_template(input))
+    }
+
+    /// <p>Creates a template alias for a template.</p>
+    ///
+    /// See `Quicksight::create_template_alias`.
+    pub fn create_template_alias(
+        &self,
+        input: CreateTemplateAliasRequest,
+    ) -> Result<CreateTemplateAliasResponse, RusotoError<CreateTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.create_template_alias(input))
+    }
+
+    /// <p>Creates a theme.</p> <p>A <i>theme</i> is set of configuration options for color and layout. Themes apply to analyses and dashboards. For more information, see <a href="https://docs.aws.amazon.com/quicksight/latest/user/themes-in-quicksight.html">Using Themes in Amazon QuickSight</a> in the <i>Amazon QuickSight User Guide</i>.</p>
+    ///
+    /// See `Quicksight::create_theme`.
+    pub fn create_theme(
+        &self,
+        input: CreateThemeRequest,
+    ) -> Result<CreateThemeResponse, RusotoError<CreateThemeError>> {
+        self.runtime.block_on(self.client.create_theme(input))
+    }
+
+    /// <p>Creates a theme alias for a theme.</p>
+    ///
+    /// See `Quicksight::create_theme_alias`.
+    pub fn create_theme_alias(
+        &self,
+        input: CreateThemeAliasRequest,
+    ) -> Result<CreateThemeAliasResponse, RusotoError<CreateThemeAliasError>> {
+        self.runtime.block_on(self.client.create_theme_alias(input))
+    }
+
+    /// <p>Deletes a dashboard.</p>
+    ///
+    /// See `Quicksight::delete_dashboard`.
+    pub fn delete_dashboard(
+        &self,
+        input: DeleteDashboardRequest,
+    ) -> Result<DeleteDashboardResponse, RusotoError<DeleteDashboardError>> {
+        self.runtime.block_on(self.client.delete_dashboard(input))
+    }
+
+    /// <p>Deletes a dataset.</p>
+    ///
+    /// See `Quicksight::delete_data_set`.
+    pub fn delete_data_set(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<DeleteDataSetResponse, RusotoError<DeleteDataSetError>> {
+        self.runtime.block_on(self.client.delete_data_set(input))
+    }
+
+    /// <p>Deletes the data source permanently. This action breaks all the datasets that reference the deleted data source.</p>
+    ///
+    /// See `Quicksight::delete_data_source`.
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// <p>Removes a user group from Amazon QuickSight. </p>
+    ///
+    /// See `Quicksight::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Removes a user from a group so that the user is no longer a member of the group.</p>
+    ///
+    /// See `Quicksight::delete_group_membership`.
+    pub fn delete_group_membership(
+        &self,
+        input: DeleteGroupMembershipRequest,
+    ) -> Result<DeleteGroupMembershipResponse, RusotoError<DeleteGroupMembershipError>> {
+        self.runtime
+            .block_on(self.client.delete_group_membership(input))
+    }
+
+    /// <p>Deletes an existing IAM policy assignment.</p>
+    ///
+    /// See `Quicksight::delete_iam_policy_assignment`.
+    pub fn delete_iam_policy_assignment(
+        &self,
+        input: DeleteIAMPolicyAssignmentRequest,
+    ) -> Result<DeleteIAMPolicyAssignmentResponse, RusotoError<DeleteIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_iam_policy_assignment(input))
+    }
+
+    /// <p>Deletes a template.</p>
+    ///
+    /// See `Quicksight::delete_template`.
+    pub fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        self.runtime.block_on(self.client.delete_template(input))
+    }
+
+    /// <p>Deletes the item that the specified template alias points to. If you provide a specific alias, you delete the version of the template that the alias points to.</p>
+    ///
+    /// See `Quicksight::delete_template_alias`.
+    pub fn delete_template_alias(
+        &self,
+        input: DeleteTemplateAliasRequest,
+    ) -> Result<DeleteTemplateAliasResponse, RusotoError<DeleteTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.delete_template_alias(input))
+    }
+
+    /// <p>Deletes a theme.</p>
+    ///
+    /// See `Quicksight::delete_theme`.
+    pub fn delete_theme(
+        &self,
+        input: DeleteThemeRequest,
+    ) -> Result<DeleteThemeResponse, RusotoError<DeleteThemeError>> {
+        self.runtime.block_on(self.client.delete_theme(input))
+    }
+
+    /// <p>Deletes the version of the theme that the specified theme alias points to. If you provide a specific alias, you delete the version of the theme that the alias points to.</p>
+    ///
+    /// See `Quicksight::delete_theme_alias`.
+    pub fn delete_theme_alias(
+        &self,
+        input: DeleteThemeAliasRequest,
+    ) -> Result<DeleteThemeAliasResponse, RusotoError<DeleteThemeAliasError>> {
+        self.runtime.block_on(self.client.delete_theme_alias(input))
+    }
+
+    /// <p>Deletes the Amazon QuickSight user that is associated with the identity of the AWS Identity and Access Management (IAM) user or role that's making the call. The IAM user isn't deleted as a result of this call. </p>
+    ///
+    /// See `Quicksight::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Deletes a user identified by its principal ID. </p>
+    ///
+    /// See `Quicksight::delete_user_by_principal_id`.
+    pub fn delete_user_by_principal_id(
+        &self,
+        input: DeleteUserByPrincipalIdRequest,
+    ) -> Result<DeleteUserByPrincipalIdResponse, RusotoError<DeleteUserByPrincipalIdError>> {
+        self.runtime
+            .block_on(self.client.delete_user_by_principal_id(input))
+    }
+
+    /// <p>Provides a summary for a dashboard.</p>
+    ///
+    /// See `Quicksight::describe_dashboard`.
+    pub fn describe_dashboard(
+        &self,
+        input: DescribeDashboardRequest,
+    ) -> Result<DescribeDashboardResponse, RusotoError<DescribeDashboardError>> {
+        self.runtime.block_on(self.client.describe_dashboard(input))
+    }
+
+    /// <p>Describes read and write permissions for a dashboard.</p>
+    ///
+    /// See `Quicksight::describe_dashboard_permissions`.
+    pub fn describe_dashboard_permissions(
+        &self,
+        input: DescribeDashboardPermissionsRequest,
+    ) -> Result<DescribeDashboardPermissionsResponse, RusotoError<DescribeDashboardPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_dashboard_permissions(input))
+    }
+
+    /// <p>Describes a dataset. </p>
+    ///
+    /// See `Quicksight::describe_data_set`.
+    pub fn describe_data_set(
+        &self,
+        input: DescribeDataSetRequest,
+    ) -> Result<DescribeDataSetResponse, RusotoError<DescribeDataSetError>> {
+        self.runtime.block_on(self.client.describe_data_set(input))
+    }
+
+    /// <p>Describes the permissions on a dataset.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/data-set-id</code>.</p>
+    ///
+    /// See `Quicksight::describe_data_set_permissions`.
+    pub fn describe_data_set_permissions(
+        &self,
+        input: DescribeDataSetPermissionsRequest,
+    ) -> Result<DescribeDataSetPermissionsResponse, RusotoError<DescribeDataSetPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_data_set_permissions(input))
+    }
+
+    /// <p>Describes a data source.</p>
+    ///
+    /// See `Quicksight::describe_data_source`.
+    pub fn describe_data_source(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+        self.runtime
+            .block_on(self.client.describe_data_source(input))
+    }
+
+    /// <p>Describes the resource permissions for a data source.</p>
+    ///
+    /// See `Quicksight::describe_data_source_permissions`.
+    pub fn describe_data_source_permissions(
+        &self,
+        input: DescribeDataSourcePermissionsRequest,
+    ) -> Result<
+        DescribeDataSourcePermissionsResponse,
+        RusotoError<DescribeDataSourcePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_data_source_permissions(input))
+    }
+
+    /// <p>Returns an Amazon QuickSight group's description and Amazon Resource Name (ARN). </p>
+    ///
+    /// See `Quicksight::describe_group`.
+    pub fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
+        self.runtime.block_on(self.client.describe_group(input))
+    }
+
+    /// <p>Describes an existing IAM policy assignment, as specified by the assignment name.</p>
+    ///
+    /// See `Quicksight::describe_iam_policy_assignment`.
+    pub fn describe_iam_policy_assignment(
+        &self,
+        input: DescribeIAMPolicyAssignmentRequest,
+    ) -> Result<DescribeIAMPolicyAssignmentResponse, RusotoError<DescribeIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_iam_policy_assignment(input))
+    }
+
+    /// <p>Describes a SPICE ingestion.</p>
+    ///
+    /// See `Quicksight::describe_ingestion`.
+    pub fn describe_ingestion(
+        &self,
+        input: DescribeIngestionRequest,
+    ) -> Result<DescribeIngestionResponse, RusotoError<DescribeIngestionError>> {
+        self.runtime.block_on(self.client.describe_ingestion(input))
+    }
+
+    /// <p>Describes a template's metadata.</p>
+    ///
+    /// See `Quicksight::describe_template`.
+    pub fn describe_template(
+        &self,
+        input: DescribeTemplateRequest,
+    ) -> Result<DescribeTemplateResponse, RusotoError<DescribeTemplateError>> {
+        self.runtime.block_on(self.client.describe_template(input))
+    }
+
+    /// <p>Describes the template alias for a template.</p>
+    ///
+    /// See `Quicksight::describe_template_alias`.
+    pub fn describe_template_alias(
+        &self,
+        input: DescribeTemplateAliasRequest,
+    ) -> Result<DescribeTemplateAliasResponse, RusotoError<DescribeTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.describe_template_alias(input))
+    }
+
+    /// <p>Describes read and write permissions on a template.</p>
+    ///
+    /// See `Quicksight::describe_template_permissions`.
+    pub fn describe_template_permissions(
+        &self,
+        input: DescribeTemplatePermissionsRequest,
+    ) -> Result<DescribeTemplatePermissionsResponse, RusotoError<DescribeTemplatePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_template_permissions(input))
+    }
+
+    /// <p>Describes a theme.</p>
+    ///
+    /// See `Quicksight::describe_theme`.
+    pub fn describe_theme(
+        &self,
+        input: DescribeThemeRequest,
+    ) -> Result<DescribeThemeResponse, RusotoError<DescribeThemeError>> {
+        self.runtime.block_on(self.client.describe_theme(input))
+    }
+
+    /// <p>Describes the alias for a theme.</p>
+    ///
+    /// See `Quicksight::describe_theme_alias`.
+    pub fn describe_theme_alias(
+        &self,
+        input: DescribeThemeAliasRequest,
+    ) -> Result<DescribeThemeAliasResponse, RusotoError<DescribeThemeAliasError>> {
+        self.runtime
+            .block_on(self.client.describe_theme_alias(input))
+    }
+
+    /// <p>Describes the read and write permissions for a theme.</p>
+    ///
+    /// See `Quicksight::describe_theme_permissions`.
+    pub fn describe_theme_permissions(
+        &self,
+        input: DescribeThemePermissionsRequest,
+    ) -> Result<DescribeThemePermissionsResponse, RusotoError<DescribeThemePermissionsError>> {
+        self.runtime
+            .block_on(self.client.describe_theme_permissions(input))
+    }
+
+    /// <p>Returns information about a user, given the user name. </p>
+    ///
+    /// See `Quicksight::describe_user`.
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// <p>Generates a URL and authorization code that you can embed in your web server code. Before you use this command, make sure that you have configured the dashboards and permissions. </p> <p>Currently, you can use <code>GetDashboardEmbedURL</code> only from the server, not from the user's browser. The following rules apply to the combination of URL and authorization code:</p> <ul> <li> <p>They must be used together.</p> </li> <li> <p>They can be used one time only.</p> </li> <li> <p>They are valid for 5 minutes after you run this command.</p> </li> <li> <p>The resulting user session is valid for 10 hours.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/quicksight/latest/user/embedding-dashboards.html">Embedding Amazon QuickSight Dashboards</a> in the <i>Amazon QuickSight User Guide</i> or <a href="https://docs.aws.amazon.com/quicksight/latest/APIReference/qs-dev-embedded-dashboards.html">Embedding Amazon QuickSight Dashboards</a> in the <i>Amazon QuickSight API Reference</i>.</p>
+    ///
+    /// See `Quicksight::get_dashboard_embed_url`.
+    pub fn get_dashboard_embed_url(
+        &self,
+        input: GetDashboardEmbedUrlRequest,
+    ) -> Result<GetDashboardEmbedUrlResponse, RusotoError<GetDashboardEmbedUrlError>> {
+        self.runtime
+            .block_on(self.client.get_dashboard_embed_url(input))
+    }
+
+    /// <p>Lists all the versions of the dashboards in the QuickSight subscription.</p>
+    ///
+    /// See `Quicksight::list_dashboard_versions`.
+    pub fn list_dashboard_versions(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> Result<ListDashboardVersionsResponse, RusotoError<ListDashboardVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_dashboard_versions(input))
+    }
+
+    /// <p>Lists dashboards in an AWS account.</p>
+    ///
+    /// See `Quicksight::list_dashboards`.
+    pub fn list_dashboards(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> Result<ListDashboardsResponse, RusotoError<ListDashboardsError>> {
+        self.runtime.block_on(self.client.list_dashboards(input))
+    }
+
+    /// <p>Lists all of the datasets belonging to the current AWS account in an AWS Region.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/*</code>.</p>
+    ///
+    /// See `Quicksight::list_data_sets`.
+    pub fn list_data_sets(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+        self.runtime.block_on(self.client.list_data_sets(input))
+    }
+
+    /// <p>Lists data sources in current AWS Region that belong to this AWS account.</p>
+    ///
+    /// See `Quicksight::list_data_sources`.
+    pub fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.runtime.block_on(self.client.list_data_sources(input))
+    }
+
+    /// <p>Lists member users in a group.</p>
+    ///
+    /// See `Quicksight::list_group_memberships`.
+    pub fn list_group_memberships(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> Result<ListGroupMembershipsResponse, RusotoError<ListGroupMembershipsError>> {
+        self.runtime
+            .block_on(self.client.list_group_memberships(input))
+    }
+
+    /// <p>Lists all user groups in Amazon QuickSight. </p>
+    ///
+    /// See `Quicksight::list_groups`.
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// <p>Lists IAM policy assignments in the current Amazon QuickSight account.</p>
+    ///
+    /// See `Quicksight::list_iam_policy_assignments`.
+    pub fn list_iam_policy_assignments(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> Result<ListIAMPolicyAssignmentsResponse, RusotoError<ListIAMPolicyAssignmentsError>> {
+        self.runtime
+            .block_on(self.client.list_iam_policy_assignments(input))
+    }
+
+    /// <p>Lists all the IAM policy assignments, including the Amazon Resource Names (ARNs) for the IAM policies assigned to the specified user and group or groups that the user belongs to.</p>
+    ///
+    /// See `Quicksight::list_iam_policy_assignments_for_user`.
+    pub fn list_iam_policy_assignments_for_user(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> Result<
+        ListIAMPolicyAssignmentsForUserResponse,
+        RusotoError<ListIAMPolicyAssignmentsForUserError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_iam_policy_assignments_for_user(input))
+    }
+
+    /// <p>Lists the history of SPICE ingestions for a dataset.</p>
+    ///
+    /// See `Quicksight::list_ingestions`.
+    pub fn list_ingestions(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> Result<ListIngestionsResponse, RusotoError<ListIngestionsError>> {
+        self.runtime.block_on(self.client.list_ingestions(input))
+    }
+
+    /// <p>Lists the tags assigned to a resource.</p>
+    ///
+    /// See `Quicksight::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists all the aliases of a template.</p>
+    ///
+    /// See `Quicksight::list_template_aliases`.
+    pub fn list_template_aliases(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> Result<ListTemplateAliasesResponse, RusotoError<ListTemplateAliasesError>> {
+        self.runtime
+            .block_on(self.client.list_template_aliases(input))
+    }
+
+    /// <p>Lists all the versions of the templates in the current Amazon QuickSight account.</p>
+    ///
+    /// See `Quicksight::list_template_versions`.
+    pub fn list_template_versions(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> Result<ListTemplateVersionsResponse, RusotoError<ListTemplateVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_template_versions(input))
+    }
+
+    /// <p>Lists all the templates in the current Amazon QuickSight account.</p>
+    ///
+    /// See `Quicksight::list_templates`.
+    pub fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        self.runtime.block_on(self.client.list_templates(input))
+    }
+
+    /// <p>Lists all the aliases of a theme.</p>
+    ///
+    /// See `Quicksight::list_theme_aliases`.
+    pub fn list_theme_aliases(
+        &self,
+        input: ListThemeAliasesRequest,
+    ) -> Result<ListThemeAliasesResponse, RusotoError<ListThemeAliasesError>> {
+        self.runtime.block_on(self.client.list_theme_aliases(input))
+    }
+
+    /// <p>Lists all the versions of the themes in the current AWS account.</p>
+    ///
+    /// See `Quicksight::list_theme_versions`.
+    pub fn list_theme_versions(
+        &self,
+        input: ListThemeVersionsRequest,
+    ) -> Result<ListThemeVersionsResponse, RusotoError<ListThemeVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_theme_versions(input))
+    }
+
+    /// <p>Lists all the themes in the current AWS account.</p>
+    ///
+    /// See `Quicksight::list_themes`.
+    pub fn list_themes(
+        &self,
+        input: ListThemesRequest,
+    ) -> Result<ListThemesResponse, RusotoError<ListThemesError>> {
+        self.runtime.block_on(self.client.list_themes(input))
+    }
+
+    /// <p>Lists the Amazon QuickSight groups that an Amazon QuickSight user is a member of.</p>
+    ///
+    /// See `Quicksight::list_user_groups`.
+    pub fn list_user_groups(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> Result<ListUserGroupsResponse, RusotoError<ListUserGroupsError>> {
+        self.runtime.block_on(self.client.list_user_groups(input))
+    }
+
+    /// <p>Returns a list of all of the Amazon QuickSight users belonging to this account. </p>
+    ///
+    /// See `Quicksight::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Creates an Amazon QuickSight user, whose identity is associated with the AWS Identity and Access Management (IAM) identity or role specified in the request. </p>
+    ///
+    /// See `Quicksight::register_user`.
+    pub fn register_user(
+        &self,
+        input: RegisterUserRequest,
+    ) -> Result<RegisterUserResponse, RusotoError<RegisterUserError>> {
+        self.runtime.block_on(self.client.register_user(input))
+    }
+
+    /// <p>Searchs for dashboards that belong to a user. </p>
+    ///
+    /// See `Quicksight::search_dashboards`.
+    pub fn search_dashboards(
+        &self,
+        input: SearchDashboardsRequest,
+    ) -> Result<SearchDashboardsResponse, RusotoError<SearchDashboardsError>> {
+        self.runtime.block_on(self.client.search_dashboards(input))
+    }
+
+    /// <p><p>Assigns one or more tags (key-value pairs) to the specified QuickSight resource. </p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values. You can use the <code>TagResource</code> operation with a resource that already has tags. If you specify a new tag key for the resource, this tag is appended to the list of tags associated with the resource. If you specify a tag key that is already associated with the resource, the new tag value that you specify replaces the previous value for that tag.</p> <p>You can associate as many as 50 tags with a resource. QuickSight supports tagging on data set, data source, dashboard, and template. </p> <p>Tagging for QuickSight works in a similar way to tagging for other AWS services, except for the following:</p> <ul> <li> <p>You can&#39;t use tags to track AWS costs for QuickSight. This restriction is because QuickSight costs are based on users and SPICE capacity, which aren&#39;t taggable resources.</p> </li> <li> <p>QuickSight doesn&#39;t currently support the Tag Editor for AWS Resource Groups.</p> </li> </ul></p>
+    ///
+    /// See `Quicksight::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes a tag or tags from a resource.</p>
+    ///
+    /// See `Quicksight::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a dashboard in an AWS account.</p>
+    ///
+    /// See `Quicksight::update_dashboard`.
+    pub fn update_dashboard(
+        &self,
+        input: UpdateDashboardRequest,
+    ) -> Result<UpdateDashboardResponse, RusotoError<UpdateDashboardError>> {
+        self.runtime.block_on(self.client.update_dashboard(input))
+    }
+
+    /// <p>Updates read and write permissions on a dashboard.</p>
+    ///
+    /// See `Quicksight::update_dashboard_permissions`.
+    pub fn update_dashboard_permissions(
+        &self,
+        input: UpdateDashboardPermissionsRequest,
+    ) -> Result<UpdateDashboardPermissionsResponse, RusotoError<UpdateDashboardPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_dashboard_permissions(input))
+    }
+
+    /// <p>Updates the published version of a dashboard.</p>
+    ///
+    /// See `Quicksight::update_dashboard_published_version`.
+    pub fn update_dashboard_published_version(
+        &self,
+        input: UpdateDashboardPublishedVersionRequest,
+    ) -> Result<
+        UpdateDashboardPublishedVersionResponse,
+        RusotoError<UpdateDashboardPublishedVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_dashboard_published_version(input))
+    }
+
+    /// <p>Updates a dataset.</p>
+    ///
+    /// See `Quicksight::update_data_set`.
+    pub fn update_data_set(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+        self.runtime.block_on(self.client.update_data_set(input))
+    }
+
+    /// <p>Updates the permissions on a dataset.</p> <p>The permissions resource is <code>arn:aws:quicksight:region:aws-account-id:dataset/data-set-id</code>.</p>
+    ///
+    /// See `Quicksight::update_data_set_permissions`.
+    pub fn update_data_set_permissions(
+        &self,
+        input: UpdateDataSetPermissionsRequest,
+    ) -> Result<UpdateDataSetPermissionsResponse, RusotoError<UpdateDataSetPermissionsError>> {
+        self.runtime
+            .block_on(self.client.update_data_set_permissions(input))
+    }
+
+    /// <p>Updates a data source.</p>
+    ///
+    /// See `Quicksight::update_data_source`.
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// <p>Updates the permissions to a data source.</p>
+    ///
+    /// See `Quicksight::update_data_source_permissions`.
+    pub fn update_data_source_permissions(
+        &self,
+        input: UpdateDataSourcePermissionsRequest,
+    ) -> Result<UpdateDataSourcePermissionsResponse, RusotoError<UpdateDataSourcePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_data_source_permissions(input))
+    }
+
+    /// <p>Changes a group description. </p>
+    ///
+    /// See `Quicksight::update_group`.
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// <p>Updates an existing IAM policy assignment. This operation updates only the optional parameter or parameters that are specified in the request.</p>
+    ///
+    /// See `Quicksight::update_iam_policy_assignment`.
+    pub fn update_iam_policy_assignment(
+        &self,
+        input: UpdateIAMPolicyAssignmentRequest,
+    ) -> Result<UpdateIAMPolicyAssignmentResponse, RusotoError<UpdateIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.update_iam_policy_assignment(input))
+    }
+
+    /// <p>Updates a template from an existing Amazon QuickSight analysis or another template.</p>
+    ///
+    /// See `Quicksight::update_template`.
+    pub fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        self.runtime.block_on(self.client.update_template(input))
+    }
+
+    /// <p>Updates the template alias of a template.</p>
+    ///
+    /// See `Quicksight::update_template_alias`.
+    pub fn update_template_alias(
+        &self,
+        input: UpdateTemplateAliasRequest,
+    ) -> Result<UpdateTemplateAliasResponse, RusotoError<UpdateTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.update_template_alias(input))
+    }
+
+    /// <p>Updates the resource permissions for a template.</p>
+    ///
+    /// See `Quicksight::update_template_permissions`.
+    pub fn update_template_permissions(
+        &self,
+        input: UpdateTemplatePermissionsRequest,
+    ) -> Result<UpdateTemplatePermissionsResponse, RusotoError<UpdateTemplatePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_template_permissions(input))
+    }
+
+    /// <p>Updates a theme.</p>
+    ///
+    /// See `Quicksight::update_theme`.
+    pub fn update_theme(
+        &self,
+        input: UpdateThemeRequest,
+    ) -> Result<UpdateThemeResponse, RusotoError<UpdateThemeError>> {
+        self.runtime.block_on(self.client.update_theme(input))
+    }
+
+    /// <p>Updates an alias of a theme.</p>
+    ///
+    /// See `Quicksight::update_theme_alias`.
+    pub fn update_theme_alias(
+        &self,
+        input: UpdateThemeAliasRequest,
+    ) -> Result<UpdateThemeAliasResponse, RusotoError<UpdateThemeAliasError>> {
+        self.runtime.block_on(self.client.update_theme_alias(input))
+    }
+
+    /// <p><p>Updates the resource permissions for a theme. Permissions apply to the action to grant or revoke permissions on, for example <code>&quot;quicksight:DescribeTheme&quot;</code>.</p> <p>Theme permissions apply in groupings. Valid groupings include the following for the three levels of permissions, which are user, owner, or no permissions: </p> <ul> <li> <p>User</p> <ul> <li> <p> <code>&quot;quicksight:DescribeTheme&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:DescribeThemeAlias&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:ListThemeAliases&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:ListThemeVersions&quot;</code> </p> </li> </ul> </li> <li> <p>Owner</p> <ul> <li> <p> <code>&quot;quicksight:DescribeTheme&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:DescribeThemeAlias&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:ListThemeAliases&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:ListThemeVersions&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:DeleteTheme&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:UpdateTheme&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:CreateThemeAlias&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:DeleteThemeAlias&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:UpdateThemeAlias&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:UpdateThemePermissions&quot;</code> </p> </li> <li> <p> <code>&quot;quicksight:DescribeThemePermissions&quot;</code> </p> </li> </ul> </li> <li> <p>To specify no permissions, omit the permissions list.</p> </li> </ul></p>
+    ///
+    /// See `Quicksight::update_theme_permissions`.
+    pub fn update_theme_permissions(
+        &self,
+        input: UpdateThemePermissionsRequest,
+    ) -> Result<UpdateThemePermissionsResponse, RusotoError<UpdateThemePermissionsError>> {
+        self.runtime
+            .block_on(self.client.update_theme_permissions(input))
+    }
+
+    /// <p>Updates an Amazon QuickSight user.</p>
+    ///
+    /// See `Quicksight::update_user`.
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+}
@@ -1081,58 +1081,68 @@ impl AcceptResourceShareInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::InvalidClientToken(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(AcceptResourceShareInvitationError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptResourceShareInvitationError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ResourceShareInvitationAlreadyAcceptedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::ResourceShareInvitationExpired(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptResourceShareInvitationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -1207,52 +1217,64 @@ impl AssociateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceShareError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(AssociateResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResourceShareError::InvalidClientToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResourceShareError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceShareError::InvalidStateTransition(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(AssociateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResourceShareError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceShareError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceShareError::ResourceShareLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(AssociateResourceShareError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResourceShareError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResourceShareError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(AssociateResourceShareError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResourceShareError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1311,37 +1333,44 @@ impl AssociateResourceSharePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::InvalidClientToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::MalformedArn(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResourceSharePermissionError::UnknownResource(err.msg),
                     )
                 }
@@ -1413,53 +1442,70 @@ impl CreateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceShareError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::InvalidClientToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::InvalidStateTransition(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(CreateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateResourceShareError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceShareError::ResourceShareLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(CreateResourceShareError::TagPolicyViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::TagPolicyViolation(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(CreateResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceShareError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1519,43 +1565,58 @@ impl DeleteResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteResourceShareError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::InvalidClientToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidStateTransition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::InvalidStateTransition(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(DeleteResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteResourceShareError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(DeleteResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceShareError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1613,54 +1674,64 @@ impl DisassociateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceShareError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceShareError::InvalidClientToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResourceShareError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceShareError::InvalidStateTransition(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResourceShareError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceShareError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceShareError::ResourceShareLimitExceeded(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResourceShareError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceShareError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResourceShareError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1721,37 +1792,44 @@ impl DisassociateResourceSharePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::InvalidClientToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::MalformedArn(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResourceSharePermissionError::UnknownResource(err.msg),
                     )
                 }
@@ -1809,17 +1887,20 @@ impl EnableSharingWithAwsOrganizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableSharingWithAwsOrganizationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableSharingWithAwsOrganizationError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableSharingWithAwsOrganizationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -1869,22 +1950,31 @@ impl GetPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetPermissionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPermissionError::InvalidParameter(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetPermissionError::MalformedArn(err.msg))
+                    return RusotoError::service(&res, GetPermissionError::MalformedArn(err.msg))
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetPermissionError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPermissionError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetPermissionError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, GetPermissionError::ServerInternal(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPermissionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPermissionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetPermissionError::UnknownResource(err.msg))
+                    return RusotoError::service(&res, GetPermissionError::UnknownResource(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1929,30 +2019,40 @@ impl GetResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::InvalidParameter(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::MalformedArn(err.msg),
+                    )
                 }
                 "ResourceArnNotFoundException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ResourceArnNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::ResourceArnNotFound(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePoliciesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2001,37 +2101,44 @@ impl GetResourceShareAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareAssociationsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareAssociationsError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceShareAssociationsError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceShareAssociationsError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareAssociationsError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceShareAssociationsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceShareAssociationsError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareAssociationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareAssociationsError::UnknownResource(err.msg),
                     )
                 }
@@ -2093,46 +2200,54 @@ impl GetResourceShareInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareInvitationsError::InvalidMaxResults(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareInvitationsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareInvitationsError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceShareInvitationsError::MalformedArn(err.msg),
+                    )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareInvitationsError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceShareInvitationsError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResourceShareInvitationsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceShareInvitationsError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2185,24 +2300,40 @@ impl GetResourceSharesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourceSharesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceSharesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourceSharesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceSharesError::InvalidParameter(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceSharesError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceSharesError::MalformedArn(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceSharesError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceSharesError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourceSharesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceSharesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResourceSharesError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourceSharesError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2255,53 +2386,62 @@ impl ListPendingInvitationResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListPendingInvitationResourcesError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPendingInvitationResourcesError::MalformedArn(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::ResourceShareInvitationAlreadyRejected(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::ResourceShareInvitationExpired(
                             err.msg,
                         ),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPendingInvitationResourcesError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2365,21 +2505,34 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListPermissionsError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListPermissionsError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPermissionsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPermissionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2423,22 +2576,34 @@ impl ListPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPrincipalsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPrincipalsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalsError::InvalidParameter(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListPrincipalsError::MalformedArn(err.msg))
+                    return RusotoError::service(&res, ListPrincipalsError::MalformedArn(err.msg))
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListPrincipalsError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, ListPrincipalsError::ServerInternal(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(ListPrincipalsError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalsError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2487,37 +2652,44 @@ impl ListResourceSharePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceSharePermissionsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceSharePermissionsError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListResourceSharePermissionsError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceSharePermissionsError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceSharePermissionsError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceSharePermissionsError::ServerInternal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceSharePermissionsError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceSharePermissionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceSharePermissionsError::UnknownResource(err.msg),
                     )
                 }
@@ -2569,18 +2741,28 @@ impl ListResourceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourceTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceTypesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceTypesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceTypesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceTypesError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceTypesError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListResourceTypesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceTypesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2625,25 +2807,37 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidResourceTypeException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidResourceType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::InvalidResourceType(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListResourcesError::MalformedArn(err.msg))
+                    return RusotoError::service(&res, ListResourcesError::MalformedArn(err.msg))
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourcesError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, ListResourcesError::ServerInternal(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListResourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(ListResourcesError::UnknownResource(err.msg))
+                    return RusotoError::service(&res, ListResourcesError::UnknownResource(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2693,39 +2887,46 @@ impl PromoteResourceShareCreatedFromPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::InvalidParameter(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::MalformedArn(err.msg),
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::MissingRequiredParameter(
                             err.msg,
                         ),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PromoteResourceShareCreatedFromPolicyError::UnknownResource(err.msg),
                     )
                 }
@@ -2797,58 +2998,68 @@ impl RejectResourceShareInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::InvalidClientToken(err.msg),
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(RejectResourceShareInvitationError::MalformedArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectResourceShareInvitationError::MalformedArn(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ResourceShareInvitationAlreadyAcceptedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::ResourceShareInvitationArnNotFound(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::ResourceShareInvitationExpired(err.msg),
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::ServerInternal(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectResourceShareInvitationError::ServiceUnavailable(err.msg),
                     )
                 }
@@ -2917,25 +3128,34 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(TagResourceError::MalformedArn(err.msg))
+                    return RusotoError::service(&res, TagResourceError::MalformedArn(err.msg))
                 }
                 "ResourceArnNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ResourceArnNotFound(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ServerInternal(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::TagLimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TagLimitExceeded(err.msg))
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicyViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::TagPolicyViolation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2975,13 +3195,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::ServerInternal(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3029,43 +3255,58 @@ impl UpdateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateResourceShareError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(UpdateResourceShareError::InvalidClientToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::InvalidClientToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResourceShareError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::InvalidParameter(err.msg),
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(UpdateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::MalformedArn(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateResourceShareError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateResourceShareError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::ServerInternal(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateResourceShareError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(UpdateResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceShareError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3278,6 +3519,7 @@ impl RamClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3293,6 +3535,7 @@ impl RamClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> RamClient {
         RamClient { client, region }
     }
@@ -3312,6 +3555,9 @@ impl Ram for RamClient {
         let request_uri = "/acceptresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("AcceptResourceShareInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3343,6 +3589,9 @@ impl Ram for RamClient {
         let request_uri = "/associateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("AssociateResourceShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3377,6 +3626,9 @@ impl Ram for RamClient {
         let request_uri = "/associateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("AssociateResourceSharePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3410,6 +3662,9 @@ impl Ram for RamClient {
         let request_uri = "/createresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("CreateResourceShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3441,6 +3696,9 @@ impl Ram for RamClient {
         let request_uri = "/deleteresourceshare";
 
         let mut request = SignedRequest::new("DELETE", "ram", &self.region, &request_uri);
+        request.set_operation_name("DeleteResourceShare");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3477,6 +3735,9 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("DisassociateResourceShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3511,6 +3772,9 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("DisassociateResourceSharePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3546,6 +3810,9 @@ impl Ram for RamClient {
         let request_uri = "/enablesharingwithawsorganization";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("EnableSharingWithAwsOrganization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3576,6 +3843,9 @@ impl Ram for RamClient {
         let request_uri = "/getpermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("GetPermission");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3607,6 +3877,9 @@ impl Ram for RamClient {
         let request_uri = "/getresourcepolicies";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("GetResourcePolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3639,6 +3912,9 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshareassociations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("GetResourceShareAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3671,6 +3947,9 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshareinvitations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("GetResourceShareInvitations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3702,6 +3981,9 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshares";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("GetResourceShares");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3736,6 +4018,9 @@ impl Ram for RamClient {
         let request_uri = "/listpendinginvitationresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("ListPendingInvitationResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3767,6 +4052,9 @@ impl Ram for RamClient {
         let request_uri = "/listpermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("ListPermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3798,6 +4086,9 @@ impl Ram for RamClient {
         let request_uri = "/listprincipals";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("ListPrincipals");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3830,6 +4121,9 @@ impl Ram for RamClient {
         let request_uri = "/listresourcesharepermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("ListResourceSharePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3861,6 +4155,9 @@ impl Ram for RamClient {
         let request_uri = "/listresourcetypes";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("ListResourceTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3892,6 +4189,9 @@ impl Ram for RamClient {
         let request_uri = "/listresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("ListResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3926,6 +4226,9 @@ impl Ram for RamClient {
         let request_uri = "/promoteresourcesharecreatedfrompolicy";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("PromoteResourceShareCreatedFromPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3963,6 +4266,9 @@ impl Ram for RamClient {
         let request_uri = "/rejectresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("RejectResourceShareInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3994,6 +4300,9 @@ impl Ram for RamClient {
         let request_uri = "/tagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4025,6 +4334,9 @@ impl Ram for RamClient {
         let request_uri = "/untagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4056,6 +4368,9 @@ impl Ram for RamClient {
         let request_uri = "/updateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation_name("UpdateResourceShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4078,3 +4393,337 @@ impl Ram for RamClient {
         }
     }
 }
+
+/// A blocking client for the RAM API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `RamClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RamBlockingClient {
+    client: RamClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RamBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RamBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        RamBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RamBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RamBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> RamBlockingClient {
+        RamBlockingClient {
+            client: RamClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts an invitation to a resource share from another AWS account.</p>
+    ///
+    /// See `Ram::accept_resource_share_invitation`.
+    pub fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> Result<
+        AcceptResourceShareInvitationResponse,
+        RusotoError<AcceptResourceShareInvitationError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_resource_share_invitation(input))
+    }
+
+    /// <p>Associates the specified resource share with the specified principals and resources.</p>
+    ///
+    /// See `Ram::associate_resource_share`.
+    pub fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> Result<AssociateResourceShareResponse, RusotoError<AssociateResourceShareError>> {
+        self.runtime
+            .block_on(self.client.associate_resource_share(input))
+    }
+
+    /// <p>Associates a permission with a resource share.</p>
+    ///
+    /// See `Ram::associate_resource_share_permission`.
+    pub fn associate_resource_share_permission(
+        &self,
+        input: AssociateResourceSharePermissionRequest,
+    ) -> Result<
+        AssociateResourceSharePermissionResponse,
+        RusotoError<AssociateResourceSharePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_resource_share_permission(input))
+    }
+
+    /// <p>Creates a resource share.</p>
+    ///
+    /// See `Ram::create_resource_share`.
+    pub fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> Result<CreateResourceShareResponse, RusotoError<CreateResourceShareError>> {
+        self.runtime
+            .block_on(self.client.create_resource_share(input))
+    }
+
+    /// <p>Deletes the specified resource share.</p>
+    ///
+    /// See `Ram::delete_resource_share`.
+    pub fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> Result<DeleteResourceShareResponse, RusotoError<DeleteResourceShareError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_share(input))
+    }
+
+    /// <p>Disassociates the specified principals or resources from the specified resource share.</p>
+    ///
+    /// See `Ram::disassociate_resource_share`.
+    pub fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> Result<DisassociateResourceShareResponse, RusotoError<DisassociateResourceShareError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_resource_share(input))
+    }
+
+    /// <p>Disassociates an AWS RAM permission from a resource share.</p>
+    ///
+    /// See `Ram::disassociate_resource_share_permission`.
+    pub fn disassociate_resource_share_permission(
+        &self,
+        input: DisassociateResourceSharePermissionRequest,
+    ) -> Result<
+        DisassociateResourceSharePermissionResponse,
+        RusotoError<DisassociateResourceSharePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_resource_share_permission(input))
+    }
+
+    /// <p>Enables resource sharing within your AWS Organization.</p> <p>The caller must be the master account for the AWS Organization.</p>
+    ///
+    /// See `Ram::enable_sharing_with_aws_organization`.
+    pub fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> Result<
+        EnableSharingWithAwsOrganizationResponse,
+        RusotoError<EnableSharingWithAwsOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_sharing_with_aws_organization())
+    }
+
+    /// <p>Gets the contents of an AWS RAM permission in JSON format.</p>
+    ///
+    /// See `Ram::get_permission`.
+    pub fn get_permission(
+        &self,
+        input: GetPermissionRequest,
+    ) -> Result<GetPermissionResponse, RusotoError<GetPermissionError>> {
+        self.runtime.block_on(self.client.get_permission(input))
+    }
+
+    /// <p>Gets the policies for the specified resources that you own and have shared.</p>
+    ///
+    /// See `Ram::get_resource_policies`.
+    pub fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policies(input))
+    }
+
+    /// <p>Gets the resources or principals for the resource shares that you own.</p>
+    ///
+    /// See `Ram::get_resource_share_associations`.
+    pub fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> Result<GetResourceShareAssociationsResponse, RusotoError<GetResourceShareAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resource_share_associations(input))
+    }
+
+    /// <p>Gets the invitations for resource sharing that you've received.</p>
+    ///
+    /// See `Ram::get_resource_share_invitations`.
+    pub fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> Result<GetResourceShareInvitationsResponse, RusotoError<GetResourceShareInvitationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resource_share_invitations(input))
+    }
+
+    /// <p>Gets the resource shares that you own or the resource shares that are shared with you.</p>
+    ///
+    /// See `Ram::get_resource_shares`.
+    pub fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> Result<GetResourceSharesResponse, RusotoError<GetResourceSharesError>> {
+        self.runtime
+            .block_on(self.client.get_resource_shares(input))
+    }
+
+    /// <p>Lists the resources in a resource share that is shared with you but that the invitation is still pending for.</p>
+    ///
+    /// See `Ram::list_pending_invitation_resources`.
+    pub fn list_pending_invitation_resources(
+        &self,
+        input: ListPendingInvitationResourcesRequest,
+    ) -> Result<
+        ListPendingInvitationResourcesResponse,
+        RusotoError<ListPendingInvitationResourcesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_pending_invitation_resources(input))
+    }
+
+    /// <p>Lists the AWS RAM permissions.</p>
+    ///
+    /// See `Ram::list_permissions`.
+    pub fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.runtime.block_on(self.client.list_permissions(input))
+    }
+
+    /// <p>Lists the principals that you have shared resources with or that have shared resources with you.</p>
+    ///
+    /// See `Ram::list_principals`.
+    pub fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> Result<ListPrincipalsResponse, RusotoError<ListPrincipalsError>> {
+        self.runtime.block_on(self.client.list_principals(input))
+    }
+
+    /// <p>Lists the AWS RAM permissions that are associated with a resource share.</p>
+    ///
+    /// See `Ram::list_resource_share_permissions`.
+    pub fn list_resource_share_permissions(
+        &self,
+        input: ListResourceSharePermissionsRequest,
+    ) -> Result<ListResourceSharePermissionsResponse, RusotoError<ListResourceSharePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_resource_share_permissions(input))
+    }
+
+    /// <p>Lists the shareable resource types supported by AWS RAM.</p>
+    ///
+    /// See `Ram::list_resource_types`.
+    pub fn list_resource_types(
+        &self,
+        input: ListResourceTypesRequest,
+    ) -> Result<ListResourceTypesResponse, RusotoError<ListResourceTypesError>> {
+        self.runtime
+            .block_on(self.client.list_resource_types(input))
+    }
+
+    /// <p>Lists the resources that you added to a resource shares or the resources that are shared with you.</p>
+    ///
+    /// See `Ram::list_resources`.
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// <p><p>Resource shares that were created by attaching a policy to a resource are visible only to the resource share owner, and the resource share cannot be modified in AWS RAM.</p> <p>Use this API action to promote the resource share. When you promote the resource share, it becomes:</p> <ul> <li> <p>Visible to all principals that it is shared with.</p> </li> <li> <p>Modifiable in AWS RAM.</p> </li> </ul></p>
+    ///
+    /// See `Ram::promote_resource_share_created_from_policy`.
+    pub fn promote_resource_share_created_from_policy(
+        &self,
+        input: PromoteResourceShareCreatedFromPolicyRequest,
+    ) -> Result<
+        PromoteResourceShareCreatedFromPolicyResponse,
+        RusotoError<PromoteResourceShareCreatedFromPolicyError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .promote_resource_share_created_from_policy(input),
+        )
+    }
+
+    /// <p>Rejects an invitation to a resource share from another AWS account.</p>
+    ///
+    /// See `Ram::reject_resource_share_invitation`.
+    pub fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> Result<
+        RejectResourceShareInvitationResponse,
+        RusotoError<RejectResourceShareInvitationError>,
+    > {
+        self.runtime
+            .block_on(self.client.reject_resource_share_invitation(input))
+    }
+
+    /// <p>Adds the specified tags to the specified resource share that you own.</p>
+    ///
+    /// See `Ram::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from the specified resource share that you own.</p>
+    ///
+    /// See `Ram::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the specified resource share that you own.</p>
+    ///
+    /// See `Ram::update_resource_share`.
+    pub fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> Result<UpdateResourceShareResponse, RusotoError<UpdateResourceShareError>> {
+        self.runtime
+            .block_on(self.client.update_resource_share(input))
+    }
+}
@@ -539,25 +539,34 @@ impl BatchExecuteStatementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchExecuteStatementError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchExecuteStatementError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchExecuteStatementError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchExecuteStatementError::ServiceUnavailableError(err.msg),
                     )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::StatementTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchExecuteStatementError::StatementTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -601,23 +610,28 @@ impl BeginTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BeginTransactionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, BeginTransactionError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BeginTransactionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, BeginTransactionError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BeginTransactionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BeginTransactionError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(BeginTransactionError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BeginTransactionError::ServiceUnavailableError(err.msg),
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(BeginTransactionError::StatementTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BeginTransactionError::StatementTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -661,26 +675,31 @@ impl CommitTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CommitTransactionError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CommitTransactionError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CommitTransactionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CommitTransactionError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CommitTransactionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CommitTransactionError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CommitTransactionError::NotFound(err.msg))
+                    return RusotoError::service(&res, CommitTransactionError::NotFound(err.msg))
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(CommitTransactionError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CommitTransactionError::ServiceUnavailableError(err.msg),
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(CommitTransactionError::StatementTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CommitTransactionError::StatementTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -721,16 +740,22 @@ impl ExecuteSqlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExecuteSqlError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ExecuteSqlError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ExecuteSqlError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ExecuteSqlError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ExecuteSqlError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExecuteSqlError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(ExecuteSqlError::ServiceUnavailableError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExecuteSqlError::ServiceUnavailableError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -771,23 +796,28 @@ impl ExecuteStatementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExecuteStatementError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ExecuteStatementError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ExecuteStatementError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ExecuteStatementError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ExecuteStatementError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExecuteStatementError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(ExecuteStatementError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExecuteStatementError::ServiceUnavailableError(err.msg),
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(ExecuteStatementError::StatementTimeout(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ExecuteStatementError::StatementTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -831,28 +861,34 @@ impl RollbackTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RollbackTransactionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RollbackTransactionError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RollbackTransactionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, RollbackTransactionError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RollbackTransactionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RollbackTransactionError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RollbackTransactionError::NotFound(err.msg))
+                    return RusotoError::service(&res, RollbackTransactionError::NotFound(err.msg))
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(RollbackTransactionError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RollbackTransactionError::ServiceUnavailableError(err.msg),
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(RollbackTransactionError::StatementTimeout(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RollbackTransactionError::StatementTimeout(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -932,6 +968,7 @@ impl RdsDataClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -947,6 +984,7 @@ impl RdsDataClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> RdsDataClient {
         RdsDataClient { client, region }
     }
@@ -963,6 +1001,9 @@ impl RdsData for RdsDataClient {
         let request_uri = "/BatchExecute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation_name("BatchExecuteStatement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -994,6 +1035,9 @@ impl RdsData for RdsDataClient {
         let request_uri = "/BeginTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation_name("BeginTransaction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1025,6 +1069,9 @@ impl RdsData for RdsDataClient {
         let request_uri = "/CommitTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation_name("CommitTransaction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1056,6 +1103,9 @@ impl RdsData for RdsDataClient {
         let request_uri = "/ExecuteSql";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation_name("ExecuteSql");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1087,6 +1137,9 @@ impl RdsData for RdsDataClient {
         let request_uri = "/Execute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation_name("ExecuteStatement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1118,6 +1171,9 @@ impl RdsData for RdsDataClient {
         let request_uri = "/RollbackTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation_name("RollbackTransaction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1140,3 +1196,115 @@ impl RdsData for RdsDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS RDS DataService API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `RdsDataClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RdsDataBlockingClient {
+    client: RdsDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RdsDataBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RdsDataBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        RdsDataBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RdsDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RdsDataBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> RdsDataBlockingClient {
+        RdsDataBlockingClient {
+            client: RdsDataClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Runs a batch SQL statement over an array of data.</p> <p>You can run bulk update and insert operations for multiple records using a DML statement with different parameter sets. Bulk operations can provide a significant performance improvement over individual insert and update operations.</p> <important> <p>If a call isn&#39;t part of a transaction because it doesn&#39;t include the <code>transactionID</code> parameter, changes that result from the call are committed automatically.</p> </important></p>
+    ///
+    /// See `RdsData::batch_execute_statement`.
+    pub fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> Result<BatchExecuteStatementResponse, RusotoError<BatchExecuteStatementError>> {
+        self.runtime
+            .block_on(self.client.batch_execute_statement(input))
+    }
+
+    /// <p><p>Starts a SQL transaction.</p> <pre><code> &lt;important&gt; &lt;p&gt;A transaction can run for a maximum of 24 hours. A transaction is terminated and rolled back automatically after 24 hours.&lt;/p&gt; &lt;p&gt;A transaction times out if no calls use its transaction ID in three minutes. If a transaction times out before it&#39;s committed, it&#39;s rolled back automatically.&lt;/p&gt; &lt;p&gt;DDL statements inside a transaction cause an implicit commit. We recommend that you run each DDL statement in a separate &lt;code&gt;ExecuteStatement&lt;/code&gt; call with &lt;code&gt;continueAfterTimeout&lt;/code&gt; enabled.&lt;/p&gt; &lt;/important&gt; </code></pre></p>
+    ///
+    /// See `RdsData::begin_transaction`.
+    pub fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> Result<BeginTransactionResponse, RusotoError<BeginTransactionError>> {
+        self.runtime.block_on(self.client.begin_transaction(input))
+    }
+
+    /// <p>Ends a SQL transaction started with the <code>BeginTransaction</code> operation and commits the changes.</p>
+    ///
+    /// See `RdsData::commit_transaction`.
+    pub fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> Result<CommitTransactionResponse, RusotoError<CommitTransactionError>> {
+        self.runtime.block_on(self.client.commit_transaction(input))
+    }
+
+    /// <p><p>Runs one or more SQL statements.</p> <important> <p>This operation is deprecated. Use the <code>BatchExecuteStatement</code> or <code>ExecuteStatement</code> operation.</p> </important></p>
+    ///
+    /// See `RdsData::execute_sql`.
+    pub fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> Result<ExecuteSqlResponse, RusotoError<ExecuteSqlError>> {
+        self.runtime.block_on(self.client.execute_sql(input))
+    }
+
+    /// <p>Runs a SQL statement against a database.</p> <important> <p>If a call isn't part of a transaction because it doesn't include the <code>transactionID</code> parameter, changes that result from the call are committed automatically.</p> </important> <p>The response size limit is 1 MB. If the call returns more than 1 MB of response data, the call is terminated.</p>
+    ///
+    /// See `RdsData::execute_statement`.
+    pub fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> Result<ExecuteStatementResponse, RusotoError<ExecuteStatementError>> {
+        self.runtime.block_on(self.client.execute_statement(input))
+    }
+
+    /// <p>Performs a rollback of a transaction. Rolling back a transaction cancels its changes.</p>
+    ///
+    /// See `RdsData::rollback_transaction`.
+    pub fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> Result<RollbackTransactionResponse, RusotoError<RollbackTransactionError>> {
+        self.runtime
+            .block_on(self.client.rollback_transaction(input))
+    }
+}
@@ -19015,26 +19015,30 @@ impl AddRoleToDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -19095,26 +19099,30 @@ impl AddRoleToDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceRoleAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBInstanceError::DBInstanceRoleAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceRoleQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBInstanceError::DBInstanceRoleQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddRoleToDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -19173,14 +19181,16 @@ impl AddSourceIdentifierToSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -19239,29 +19249,34 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(AddTagsToResourceError::DBProxyNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddTagsToResourceError::DBProxyNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -19317,21 +19332,24 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -19393,28 +19411,32 @@ impl AuthorizeDBSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeDBSecurityGroupIngressError::AuthorizationAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeDBSecurityGroupIngressError::AuthorizationQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -19473,12 +19495,14 @@ impl BacktrackDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BacktrackDBClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BacktrackDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -19529,12 +19553,14 @@ impl CancelExportTaskError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExportTaskNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CancelExportTaskError::ExportTaskNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidExportTaskStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CancelExportTaskError::InvalidExportTaskStateFault(
                                 parsed_error.message,
                             ),
@@ -19587,21 +19613,24 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -19665,42 +19694,48 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -19767,21 +19802,24 @@ impl CopyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -19843,27 +19881,32 @@ impl CopyDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBSnapshotError::DBSnapshotAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(CopyDBSnapshotError::DBSnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopyDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBSnapshotError::InvalidDBSnapshotStateFault(parsed_error.message),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(CopyDBSnapshotError::KMSKeyNotAccessibleFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CopyDBSnapshotError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyDBSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -19915,19 +19958,22 @@ impl CopyOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OptionGroupAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyOptionGroupError::OptionGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "OptionGroupQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyOptionGroupError::OptionGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -19984,18 +20030,21 @@ impl CreateCustomAvailabilityZoneError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "CustomAvailabilityZoneAlreadyExists" => return RusotoError::Service(
+                    "CustomAvailabilityZoneAlreadyExists" => return RusotoError::service(
+                        &res,
                         CreateCustomAvailabilityZoneError::CustomAvailabilityZoneAlreadyExistsFault(
                             parsed_error.message,
                         ),
                     ),
-                    "CustomAvailabilityZoneQuotaExceeded" => return RusotoError::Service(
+                    "CustomAvailabilityZoneQuotaExceeded" => return RusotoError::service(
+                        &res,
                         CreateCustomAvailabilityZoneError::CustomAvailabilityZoneQuotaExceededFault(
                             parsed_error.message,
                         ),
                     ),
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
@@ -20083,102 +20132,120 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DBInstanceNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::DomainNotFoundFault(parsed_error.message),
+                        )
                     }
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::GlobalClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InsufficientStorageClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidGlobalClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -20259,42 +20326,48 @@ impl CreateDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterEndpointQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterEndpointError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterEndpointError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterEndpointError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterEndpointError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -20361,14 +20434,16 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -20427,35 +20502,40 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -20551,111 +20631,130 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::BackupPolicyNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::DomainNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBInstanceError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -20771,131 +20870,150 @@ impl CreateDBInstanceReadReplicaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotAllowedFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBSubnetGroupNotAllowedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::DomainNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::InstanceQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::InvalidDBSubnetGroupFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBInstanceReadReplicaError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -20997,14 +21115,16 @@ impl CreateDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -21059,19 +21179,22 @@ impl CreateDBProxyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyTargetExistsFault" => {
-                        return RusotoError::Service(CreateDBProxyError::DBProxyAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBProxyError::DBProxyAlreadyExistsFault(parsed_error.message),
+                        )
                     }
                     "DBProxyQuotaExceededFault" => {
-                        return RusotoError::Service(CreateDBProxyError::DBProxyQuotaExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBProxyError::DBProxyQuotaExceededFault(parsed_error.message),
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBProxyError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBProxyError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -21119,21 +21242,24 @@ impl CreateDBSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSecurityGroupError::DBSecurityGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSecurityGroupError::DBSecurityGroupNotSupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "QuotaExceeded.DBSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSecurityGroupError::DBSecurityGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -21193,26 +21319,30 @@ impl CreateDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSnapshotError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSnapshotError::DBSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSnapshotError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -21269,37 +21399,42 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -21365,47 +21500,54 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -21473,26 +21615,30 @@ impl CreateGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "GlobalClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalClusterError::GlobalClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "GlobalClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalClusterError::GlobalClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateGlobalClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -21549,14 +21695,16 @@ impl CreateOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OptionGroupAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOptionGroupError::OptionGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateOptionGroupError::OptionGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -21611,14 +21759,16 @@ impl DeleteCustomAvailabilityZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCustomAvailabilityZoneError::CustomAvailabilityZoneNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
@@ -21677,31 +21827,36 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -21757,21 +21912,24 @@ impl DeleteDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterEndpointError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -21829,14 +21987,16 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -21889,14 +22049,16 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
@@ -21957,38 +22119,44 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceAutomatedBackupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBInstanceAutomatedBackupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -22044,7 +22212,7 @@ impl DeleteDBInstanceAutomatedBackupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),"InvalidDBInstanceAutomatedBackupState" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::service(&res, DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),"InvalidDBInstanceAutomatedBackupState" => return RusotoError::service(&res, DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -22091,14 +22259,16 @@ impl DeleteDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -22151,14 +22321,16 @@ impl DeleteDBProxyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBProxyError::DBProxyNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDBProxyError::DBProxyNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(DeleteDBProxyError::InvalidDBProxyStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDBProxyError::InvalidDBProxyStateFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -22203,14 +22375,16 @@ impl DeleteDBSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSecurityGroupError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSecurityGroupError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -22263,12 +22437,14 @@ impl DeleteDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
@@ -22319,21 +22495,24 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
                                 parsed_error.message,
                             ),
@@ -22389,14 +22568,16 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidEventSubscriptionState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -22449,14 +22630,16 @@ impl DeleteGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteGlobalClusterError::GlobalClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteGlobalClusterError::InvalidGlobalClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -22507,7 +22690,8 @@ impl DeleteInstallationMediaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InstallationMediaNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteInstallationMediaError::InstallationMediaNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -22557,14 +22741,16 @@ impl DeleteOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOptionGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteOptionGroupError::InvalidOptionGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
@@ -22617,28 +22803,32 @@ impl DeregisterDBProxyTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeregisterDBProxyTargetsError::DBProxyNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeregisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyTargetNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeregisterDBProxyTargetsError::DBProxyTargetNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeregisterDBProxyTargetsError::InvalidDBProxyStateFault(
                                 parsed_error.message,
                             ),
@@ -22729,7 +22919,8 @@ impl DescribeCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeCertificatesError::CertificateNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -22778,7 +22969,8 @@ impl DescribeCustomAvailabilityZonesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "CustomAvailabilityZoneNotFound" => return RusotoError::Service(
+                    "CustomAvailabilityZoneNotFound" => return RusotoError::service(
+                        &res,
                         DescribeCustomAvailabilityZonesError::CustomAvailabilityZoneNotFoundFault(
                             parsed_error.message,
                         ),
@@ -22829,14 +23021,16 @@ impl DescribeDBClusterBacktracksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterBacktrackNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterBacktracksError::DBClusterBacktrackNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterBacktracksError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -22889,7 +23083,8 @@ impl DescribeDBClusterEndpointsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterEndpointsError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -22939,7 +23134,8 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -22989,7 +23185,8 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23038,7 +23235,8 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service(
+                        &res,
                         DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
                             parsed_error.message,
                         ),
@@ -23087,7 +23285,8 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23135,7 +23334,8 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
@@ -23214,7 +23414,7 @@ impl DescribeDBInstanceAutomatedBackupsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::service(&res, DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -23256,7 +23456,8 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
@@ -23300,7 +23501,8 @@ impl DescribeDBLogFilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBLogFilesError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
@@ -23344,7 +23546,8 @@ impl DescribeDBParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23392,7 +23595,8 @@ impl DescribeDBParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBParametersError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23440,9 +23644,10 @@ impl DescribeDBProxiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(DescribeDBProxiesError::DBProxyNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeDBProxiesError::DBProxyNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -23490,21 +23695,24 @@ impl DescribeDBProxyTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetGroupsError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetGroupsError::InvalidDBProxyStateFault(
                                 parsed_error.message,
                             ),
@@ -23564,26 +23772,30 @@ impl DescribeDBProxyTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetsError::DBProxyNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyTargetNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetsError::DBProxyTargetNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBProxyTargetsError::InvalidDBProxyStateFault(
                                 parsed_error.message,
                             ),
@@ -23638,7 +23850,8 @@ impl DescribeDBSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBSecurityGroupsError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23688,7 +23901,8 @@ impl DescribeDBSnapshotAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBSnapshotAttributesError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23736,7 +23950,8 @@ impl DescribeDBSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBSnapshotsError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -23780,7 +23995,8 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -23936,7 +24152,8 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24018,7 +24235,8 @@ impl DescribeExportTasksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExportTaskNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeExportTasksError::ExportTaskNotFoundFault(parsed_error.message),
                         )
                     }
@@ -24062,7 +24280,8 @@ impl DescribeGlobalClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeGlobalClustersError::GlobalClusterNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24110,7 +24329,8 @@ impl DescribeInstallationMediaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InstallationMediaNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeInstallationMediaError::InstallationMediaNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24194,7 +24414,8 @@ impl DescribeOptionGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeOptionGroupsError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24280,7 +24501,8 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24330,7 +24552,8 @@ impl DescribeReservedDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReservedDBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedDBInstancesError::ReservedDBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24379,7 +24602,7 @@ impl DescribeReservedDBInstancesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "ReservedDBInstancesOfferingNotFound" => return RusotoError::service(&res, DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -24459,14 +24682,16 @@ impl DescribeValidDBInstanceModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -24519,14 +24744,16 @@ impl DownloadDBLogFilePortionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DownloadDBLogFilePortionError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBLogFileNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DownloadDBLogFilePortionError::DBLogFileNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -24581,19 +24808,22 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             FailoverDBClusterError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -24645,14 +24875,16 @@ impl ImportInstallationMediaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ImportInstallationMediaError::CustomAvailabilityZoneNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InstallationMediaAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ImportInstallationMediaError::InstallationMediaAlreadyExistsFault(
                                 parsed_error.message,
                             ),
@@ -24711,29 +24943,34 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBProxyNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -24783,7 +25020,8 @@ impl ModifyCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCertificatesError::CertificateNotFoundFault(parsed_error.message),
                         )
                     }
@@ -24833,21 +25071,24 @@ impl ModifyCurrentDBClusterCapacityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCurrentDBClusterCapacityError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCurrentDBClusterCapacityError::InvalidDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyCurrentDBClusterCapacityError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -24923,68 +25164,80 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::DomainNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -25053,35 +25306,40 @@ impl ModifyDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterEndpointError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterEndpointError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -25145,14 +25403,16 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -25209,19 +25469,22 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service(
+                        &res,
                         ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
                             parsed_error.message,
                         ),
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -25311,115 +25574,134 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::BackupPolicyNotFoundFault(parsed_error.message),
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBInstanceError::DomainNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBInstanceError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -25501,14 +25783,16 @@ impl ModifyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -25563,19 +25847,22 @@ impl ModifyDBProxyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyTargetExistsFault" => {
-                        return RusotoError::Service(ModifyDBProxyError::DBProxyAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBProxyError::DBProxyAlreadyExistsFault(parsed_error.message),
+                        )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBProxyError::DBProxyNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBProxyError::DBProxyNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(ModifyDBProxyError::InvalidDBProxyStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBProxyError::InvalidDBProxyStateFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -25623,21 +25910,24 @@ impl ModifyDBProxyTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBProxyTargetGroupError::DBProxyNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(
                                 parsed_error.message,
                             ),
@@ -25691,7 +25981,8 @@ impl ModifyDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
                         )
                     }
@@ -25739,21 +26030,24 @@ impl ModifyDBSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSnapshotAttributeError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSnapshotAttributeError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -25815,35 +26109,40 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -25905,42 +26204,48 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -26003,14 +26308,16 @@ impl ModifyGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyGlobalClusterError::GlobalClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyGlobalClusterError::InvalidGlobalClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -26063,14 +26370,16 @@ impl ModifyOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOptionGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyOptionGroupError::InvalidOptionGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
                         )
                     }
@@ -26119,12 +26428,14 @@ impl PromoteReadReplicaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PromoteReadReplicaError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PromoteReadReplicaError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -26177,14 +26488,16 @@ impl PromoteReadReplicaDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -26240,7 +26553,7 @@ impl PurchaseReservedDBInstancesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ReservedDBInstanceAlreadyExists" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(parsed_error.message)),"ReservedDBInstanceQuotaExceeded" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(parsed_error.message)),"ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "ReservedDBInstanceAlreadyExists" => return RusotoError::service(&res, PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(parsed_error.message)),"ReservedDBInstanceQuotaExceeded" => return RusotoError::service(&res, PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(parsed_error.message)),"ReservedDBInstancesOfferingNotFound" => return RusotoError::service(&res, PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -26290,12 +26603,14 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RebootDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -26356,54 +26671,62 @@ impl RegisterDBProxyTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::DBProxyNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBProxyTargetAlreadyRegisteredFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::DBProxyTargetAlreadyRegisteredFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RegisterDBProxyTargetsError::InvalidDBProxyStateFault(
                                 parsed_error.message,
                             ),
@@ -26474,21 +26797,24 @@ impl RemoveFromGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveFromGlobalClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveFromGlobalClusterError::GlobalClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveFromGlobalClusterError::InvalidGlobalClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -26546,21 +26872,24 @@ impl RemoveRoleFromDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterRoleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -26618,21 +26947,24 @@ impl RemoveRoleFromDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBInstanceError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceRoleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBInstanceError::DBInstanceRoleNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveRoleFromDBInstanceError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
@@ -26690,14 +27022,16 @@ impl RemoveSourceIdentifierFromSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -26756,33 +27090,38 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBProxyNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBProxyTargetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -26844,14 +27183,16 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -26904,14 +27245,16 @@ impl ResetDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -26988,92 +27331,106 @@ impl RestoreDBClusterFromS3Error {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::DomainNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::InsufficientStorageClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::InvalidDBSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidS3BucketFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::InvalidS3BucketFault(parsed_error.message),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(RestoreDBClusterFromS3Error::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RestoreDBClusterFromS3Error::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromS3Error::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -27188,115 +27545,132 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::DomainNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -27426,120 +27800,138 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "DBClusterParameterGroupNotFound" => return RusotoError::Service(
+                    "DBClusterParameterGroupNotFound" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
                             parsed_error.message,
                         ),
                     ),
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::DomainNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
@@ -27676,134 +28068,154 @@ impl RestoreDBInstanceFromDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::BackupPolicyNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::DBSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "DBSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
+                    "DBSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service(
+                        &res,
                         RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupDoesNotCoverEnoughAZs(
                             parsed_error.message,
                         ),
                     ),
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::DomainNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::InstanceQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientDBInstanceCapacity" => return RusotoError::Service(
+                    "InsufficientDBInstanceCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBInstanceFromDBSnapshotError::InsufficientDBInstanceCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::InvalidDBSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::InvalidSubnet(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::Service(
+                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::service(
+                        &res,
                         RestoreDBInstanceFromDBSnapshotError::ProvisionedIopsNotAvailableInAZFault(
                             parsed_error.message,
                         ),
                     ),
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromDBSnapshotError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -27940,117 +28352,134 @@ impl RestoreDBInstanceFromS3Error {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::BackupPolicyNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::InstanceQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidS3BucketFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::InvalidS3BucketFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(RestoreDBInstanceFromS3Error::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RestoreDBInstanceFromS3Error::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::ProvisionedIopsNotAvailableInAZFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceFromS3Error::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -28186,148 +28615,170 @@ impl RestoreDBInstanceToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::BackupPolicyNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DBInstanceAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(
+                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::service(
+                        &res,
                         RestoreDBInstanceToPointInTimeError::DBInstanceAutomatedBackupNotFoundFault(
                             parsed_error.message,
                         ),
                     ),
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DBInstanceNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DBParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DBSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::DomainNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::InstanceQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InsufficientDBInstanceCapacity" => return RusotoError::Service(
+                    "InsufficientDBInstanceCapacity" => return RusotoError::service(
+                        &res,
                         RestoreDBInstanceToPointInTimeError::InsufficientDBInstanceCapacityFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::InvalidSubnet(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::OptionGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PointInTimeRestoreNotEnabled" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::PointInTimeRestoreNotEnabledFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::Service(
+                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::service(
+                        &res,
                         RestoreDBInstanceToPointInTimeError::ProvisionedIopsNotAvailableInAZFault(
                             parsed_error.message,
                         ),
                     ),
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::StorageQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreDBInstanceToPointInTimeError::StorageTypeNotSupportedFault(
                                 parsed_error.message,
                             ),
@@ -28442,21 +28893,24 @@ impl RevokeDBSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeDBSecurityGroupIngressError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -28520,38 +28974,44 @@ impl StartActivityStreamError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartActivityStreamError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartActivityStreamError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartActivityStreamError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartActivityStreamError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartActivityStreamError::KMSKeyNotAccessibleFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartActivityStreamError::ResourceNotFoundFault(parsed_error.message),
                         )
                     }
@@ -28608,17 +29068,20 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
@@ -28684,61 +29147,72 @@ impl StartDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBInstanceError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartDBInstanceError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(StartDBInstanceError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::InsufficientDBInstanceCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(StartDBInstanceError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartDBInstanceError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
@@ -28812,55 +29286,64 @@ impl StartExportTaskError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartExportTaskError::DBClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(StartExportTaskError::DBSnapshotNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartExportTaskError::DBSnapshotNotFoundFault(parsed_error.message),
+                        )
                     }
                     "ExportTaskAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartExportTaskError::ExportTaskAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "IamRoleMissingPermissions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartExportTaskError::IamRoleMissingPermissionsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "IamRoleNotFound" => {
-                        return RusotoError::Service(StartExportTaskError::IamRoleNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartExportTaskError::IamRoleNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidExportOnly" => {
-                        return RusotoError::Service(StartExportTaskError::InvalidExportOnlyFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartExportTaskError::InvalidExportOnlyFault(parsed_error.message),
+                        )
                     }
                     "InvalidExportSourceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartExportTaskError::InvalidExportSourceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidS3BucketFault" => {
-                        return RusotoError::Service(StartExportTaskError::InvalidS3BucketFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StartExportTaskError::InvalidS3BucketFault(parsed_error.message),
+                        )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StartExportTaskError::KMSKeyNotAccessibleFault(parsed_error.message),
                         )
                     }
@@ -28926,31 +29409,36 @@ impl StopActivityStreamError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopActivityStreamError::DBClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopActivityStreamError::DBInstanceNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopActivityStreamError::InvalidDBClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopActivityStreamError::InvalidDBInstanceStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopActivityStreamError::ResourceNotFoundFault(parsed_error.message),
                         )
                     }
@@ -29006,17 +29494,20 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
@@ -29070,27 +29561,32 @@ impl StopDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(StopDBInstanceError::DBInstanceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            StopDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBInstanceError::DBSnapshotAlreadyExistsFault(parsed_error.message),
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBInstanceError::InvalidDBInstanceStateFault(parsed_error.message),
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             StopDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
                         )
                     }
@@ -29968,6 +30464,7 @@ impl RdsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -29983,6 +30480,7 @@ impl RdsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> RdsClient {
         RdsClient { client, region }
     }
@@ -29996,6 +30494,9 @@ impl Rds for RdsClient {
         input: AddRoleToDBClusterMessage,
     ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddRoleToDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddRoleToDBCluster");
         let mut params = params;
         AddRoleToDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30016,6 +30517,9 @@ impl Rds for RdsClient {
         input: AddRoleToDBInstanceMessage,
     ) -> Result<(), RusotoError<AddRoleToDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddRoleToDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddRoleToDBInstance");
         let mut params = params;
         AddRoleToDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -30039,6 +30543,9 @@ impl Rds for RdsClient {
         RusotoError<AddSourceIdentifierToSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddSourceIdentifierToSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddSourceIdentifierToSubscription");
         let mut params = params;
         AddSourceIdentifierToSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -30075,6 +30582,9 @@ impl Rds for RdsClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -30096,6 +30606,9 @@ impl Rds for RdsClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ApplyPendingMaintenanceAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ApplyPendingMaintenanceAction");
         let mut params = params;
         ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
@@ -30132,6 +30645,9 @@ impl Rds for RdsClient {
         RusotoError<AuthorizeDBSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("AuthorizeDBSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AuthorizeDBSecurityGroupIngress");
         let mut params = params;
         AuthorizeDBSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -30165,6 +30681,9 @@ impl Rds for RdsClient {
         input: BacktrackDBClusterMessage,
     ) -> Result<DBClusterBacktrack, RusotoError<BacktrackDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("BacktrackDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BacktrackDBCluster");
         let mut params = params;
         BacktrackDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30196,6 +30715,9 @@ impl Rds for RdsClient {
         input: CancelExportTaskMessage,
     ) -> Result<ExportTask, RusotoError<CancelExportTaskError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CancelExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelExportTask");
         let mut params = params;
         CancelExportTaskMessageSerializer::serialize(&mut params, "", &input);
@@ -30227,6 +30749,9 @@ impl Rds for RdsClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBClusterParameterGroup");
         let mut params = params;
         CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30260,6 +30785,9 @@ impl Rds for RdsClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBClusterSnapshot");
         let mut params = params;
         CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -30293,6 +30821,9 @@ impl Rds for RdsClient {
         input: CopyDBParameterGroupMessage,
     ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBParameterGroup");
         let mut params = params;
         CopyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30326,6 +30857,9 @@ impl Rds for RdsClient {
         input: CopyDBSnapshotMessage,
     ) -> Result<CopyDBSnapshotResult, RusotoError<CopyDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyDBSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyDBSnapshot");
         let mut params = params;
         CopyDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -30357,6 +30891,9 @@ impl Rds for RdsClient {
         input: CopyOptionGroupMessage,
     ) -> Result<CopyOptionGroupResult, RusotoError<CopyOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CopyOptionGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyOptionGroup");
         let mut params = params;
         CopyOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30389,6 +30926,9 @@ impl Rds for RdsClient {
     ) -> Result<CreateCustomAvailabilityZoneResult, RusotoError<CreateCustomAvailabilityZoneError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateCustomAvailabilityZone");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCustomAvailabilityZone");
         let mut params = params;
         CreateCustomAvailabilityZoneMessageSerializer::serialize(&mut params, "", &input);
@@ -30422,6 +30962,9 @@ impl Rds for RdsClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBCluster");
         let mut params = params;
         CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30453,6 +30996,9 @@ impl Rds for RdsClient {
         input: CreateDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<CreateDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterEndpoint");
         let mut params = params;
         CreateDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -30485,6 +31031,9 @@ impl Rds for RdsClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterParameterGroup");
         let mut params = params;
         CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30518,6 +31067,9 @@ impl Rds for RdsClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBClusterSnapshot");
         let mut params = params;
         CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -30551,6 +31103,9 @@ impl Rds for RdsClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBInstance");
         let mut params = params;
         CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -30583,6 +31138,9 @@ impl Rds for RdsClient {
     ) -> Result<CreateDBInstanceReadReplicaResult, RusotoError<CreateDBInstanceReadReplicaError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBInstanceReadReplica");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBInstanceReadReplica");
         let mut params = params;
         CreateDBInstanceReadReplicaMessageSerializer::serialize(&mut params, "", &input);
@@ -30616,6 +31174,9 @@ impl Rds for RdsClient {
         input: CreateDBParameterGroupMessage,
     ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBParameterGroup");
         let mut params = params;
         CreateDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30649,6 +31210,9 @@ impl Rds for RdsClient {
         input: CreateDBProxyRequest,
     ) -> Result<CreateDBProxyResponse, RusotoError<CreateDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBProxy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBProxy");
         let mut params = params;
         CreateDBProxyRequestSerializer::serialize(&mut params, "", &input);
@@ -30680,6 +31244,9 @@ impl Rds for RdsClient {
         input: CreateDBSecurityGroupMessage,
     ) -> Result<CreateDBSecurityGroupResult, RusotoError<CreateDBSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBSecurityGroup");
         let mut params = params;
         CreateDBSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30713,6 +31280,9 @@ impl Rds for RdsClient {
         input: CreateDBSnapshotMessage,
     ) -> Result<CreateDBSnapshotResult, RusotoError<CreateDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBSnapshot");
         let mut params = params;
         CreateDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -30744,6 +31314,9 @@ impl Rds for RdsClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDBSubnetGroup");
         let mut params = params;
         CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30777,6 +31350,9 @@ impl Rds for RdsClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateEventSubscription");
         let mut params = params;
         CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -30810,6 +31386,9 @@ impl Rds for RdsClient {
         input: CreateGlobalClusterMessage,
     ) -> Result<CreateGlobalClusterResult, RusotoError<CreateGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateGlobalCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateGlobalCluster");
         let mut params = params;
         CreateGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30843,6 +31422,9 @@ impl Rds for RdsClient {
         input: CreateOptionGroupMessage,
     ) -> Result<CreateOptionGroupResult, RusotoError<CreateOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("CreateOptionGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateOptionGroup");
         let mut params = params;
         CreateOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30875,6 +31457,9 @@ impl Rds for RdsClient {
     ) -> Result<DeleteCustomAvailabilityZoneResult, RusotoError<DeleteCustomAvailabilityZoneError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteCustomAvailabilityZone");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCustomAvailabilityZone");
         let mut params = params;
         DeleteCustomAvailabilityZoneMessageSerializer::serialize(&mut params, "", &input);
@@ -30908,6 +31493,9 @@ impl Rds for RdsClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBCluster");
         let mut params = params;
         DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30939,6 +31527,9 @@ impl Rds for RdsClient {
         input: DeleteDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<DeleteDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterEndpoint");
         let mut params = params;
         DeleteDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -30970,6 +31561,9 @@ impl Rds for RdsClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterParameterGroup");
         let mut params = params;
         DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30990,6 +31584,9 @@ impl Rds for RdsClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBClusterSnapshot");
         let mut params = params;
         DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31023,6 +31620,9 @@ impl Rds for RdsClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBInstance");
         let mut params = params;
         DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -31057,6 +31657,9 @@ impl Rds for RdsClient {
         RusotoError<DeleteDBInstanceAutomatedBackupError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBInstanceAutomatedBackup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBInstanceAutomatedBackup");
         let mut params = params;
         DeleteDBInstanceAutomatedBackupMessageSerializer::serialize(&mut params, "", &input);
@@ -31090,6 +31693,9 @@ impl Rds for RdsClient {
         input: DeleteDBParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBParameterGroup");
         let mut params = params;
         DeleteDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31110,6 +31716,9 @@ impl Rds for RdsClient {
         input: DeleteDBProxyRequest,
     ) -> Result<DeleteDBProxyResponse, RusotoError<DeleteDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBProxy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBProxy");
         let mut params = params;
         DeleteDBProxyRequestSerializer::serialize(&mut params, "", &input);
@@ -31141,6 +31750,9 @@ impl Rds for RdsClient {
         input: DeleteDBSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBSecurityGroup");
         let mut params = params;
         DeleteDBSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31161,6 +31773,9 @@ impl Rds for RdsClient {
         input: DeleteDBSnapshotMessage,
     ) -> Result<DeleteDBSnapshotResult, RusotoError<DeleteDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBSnapshot");
         let mut params = params;
         DeleteDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31192,6 +31807,9 @@ impl Rds for RdsClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDBSubnetGroup");
         let mut params = params;
         DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31212,6 +31830,9 @@ impl Rds for RdsClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteEventSubscription");
         let mut params = params;
         DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -31245,6 +31866,9 @@ impl Rds for RdsClient {
         input: DeleteGlobalClusterMessage,
     ) -> Result<DeleteGlobalClusterResult, RusotoError<DeleteGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteGlobalCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteGlobalCluster");
         let mut params = params;
         DeleteGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -31278,6 +31902,9 @@ impl Rds for RdsClient {
         input: DeleteInstallationMediaMessage,
     ) -> Result<InstallationMedia, RusotoError<DeleteInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteInstallationMedia");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteInstallationMedia");
         let mut params = params;
         DeleteInstallationMediaMessageSerializer::serialize(&mut params, "", &input);
@@ -31309,6 +31936,9 @@ impl Rds for RdsClient {
         input: DeleteOptionGroupMessage,
     ) -> Result<(), RusotoError<DeleteOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeleteOptionGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteOptionGroup");
         let mut params = params;
         DeleteOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31329,6 +31959,9 @@ impl Rds for RdsClient {
         input: DeregisterDBProxyTargetsRequest,
     ) -> Result<DeregisterDBProxyTargetsResponse, RusotoError<DeregisterDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DeregisterDBProxyTargets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeregisterDBProxyTargets");
         let mut params = params;
         DeregisterDBProxyTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -31351,6 +31984,9 @@ impl Rds for RdsClient {
         input: DescribeAccountAttributesMessage,
     ) -> Result<AccountAttributesMessage, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeAccountAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountAttributes");
         let mut params = params;
         DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -31384,6 +32020,9 @@ impl Rds for RdsClient {
         input: DescribeCertificatesMessage,
     ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCertificates");
         let mut params = params;
         DescribeCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -31416,6 +32055,9 @@ impl Rds for RdsClient {
     ) -> Result<CustomAvailabilityZoneMessage, RusotoError<DescribeCustomAvailabilityZonesError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeCustomAvailabilityZones");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeCustomAvailabilityZones");
         let mut params = params;
         DescribeCustomAvailabilityZonesMessageSerializer::serialize(&mut params, "", &input);
@@ -31449,6 +32091,9 @@ impl Rds for RdsClient {
         input: DescribeDBClusterBacktracksMessage,
     ) -> Result<DBClusterBacktrackMessage, RusotoError<DescribeDBClusterBacktracksError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterBacktracks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterBacktracks");
         let mut params = params;
         DescribeDBClusterBacktracksMessageSerializer::serialize(&mut params, "", &input);
@@ -31482,6 +32127,9 @@ impl Rds for RdsClient {
         input: DescribeDBClusterEndpointsMessage,
     ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterEndpoints");
         let mut params = params;
         DescribeDBClusterEndpointsMessageSerializer::serialize(&mut params, "", &input);
@@ -31516,6 +32164,9 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterParameterGroups");
         let mut params = params;
         DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -31552,6 +32203,9 @@ impl Rds for RdsClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterParameters");
         let mut params = params;
         DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -31588,6 +32242,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterSnapshotAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterSnapshotAttributes");
         let mut params = params;
         DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -31624,6 +32281,9 @@ impl Rds for RdsClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusterSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusterSnapshots");
         let mut params = params;
         DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -31657,6 +32317,9 @@ impl Rds for RdsClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBClusters");
         let mut params = params;
         DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -31688,6 +32351,9 @@ impl Rds for RdsClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBEngineVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBEngineVersions");
         let mut params = params;
         DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -31724,6 +32390,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeDBInstanceAutomatedBackupsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBInstanceAutomatedBackups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBInstanceAutomatedBackups");
         let mut params = params;
         DescribeDBInstanceAutomatedBackupsMessageSerializer::serialize(&mut params, "", &input);
@@ -31760,6 +32429,9 @@ impl Rds for RdsClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBInstances");
         let mut params = params;
         DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -31791,6 +32463,9 @@ impl Rds for RdsClient {
         input: DescribeDBLogFilesMessage,
     ) -> Result<DescribeDBLogFilesResponse, RusotoError<DescribeDBLogFilesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBLogFiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBLogFiles");
         let mut params = params;
         DescribeDBLogFilesMessageSerializer::serialize(&mut params, "", &input);
@@ -31824,6 +32499,9 @@ impl Rds for RdsClient {
         input: DescribeDBParameterGroupsMessage,
     ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBParameterGroups");
         let mut params = params;
         DescribeDBParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -31857,6 +32535,9 @@ impl Rds for RdsClient {
         input: DescribeDBParametersMessage,
     ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBParameters");
         let mut params = params;
         DescribeDBParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -31890,6 +32571,9 @@ impl Rds for RdsClient {
         input: DescribeDBProxiesRequest,
     ) -> Result<DescribeDBProxiesResponse, RusotoError<DescribeDBProxiesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBProxies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBProxies");
         let mut params = params;
         DescribeDBProxiesRequestSerializer::serialize(&mut params, "", &input);
@@ -31924,6 +32608,9 @@ impl Rds for RdsClient {
     ) -> Result<DescribeDBProxyTargetGroupsResponse, RusotoError<DescribeDBProxyTargetGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBProxyTargetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBProxyTargetGroups");
         let mut params = params;
         DescribeDBProxyTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -31957,6 +32644,9 @@ impl Rds for RdsClient {
         input: DescribeDBProxyTargetsRequest,
     ) -> Result<DescribeDBProxyTargetsResponse, RusotoError<DescribeDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBProxyTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBProxyTargets");
         let mut params = params;
         DescribeDBProxyTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -31990,6 +32680,9 @@ impl Rds for RdsClient {
         input: DescribeDBSecurityGroupsMessage,
     ) -> Result<DBSecurityGroupMessage, RusotoError<DescribeDBSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBSecurityGroups");
         let mut params = params;
         DescribeDBSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32024,6 +32717,9 @@ impl Rds for RdsClient {
     ) -> Result<DescribeDBSnapshotAttributesResult, RusotoError<DescribeDBSnapshotAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBSnapshotAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBSnapshotAttributes");
         let mut params = params;
         DescribeDBSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -32057,6 +32753,9 @@ impl Rds for RdsClient {
         input: DescribeDBSnapshotsMessage,
     ) -> Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBSnapshots");
         let mut params = params;
         DescribeDBSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -32088,6 +32787,9 @@ impl Rds for RdsClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeDBSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDBSubnetGroups");
         let mut params = params;
         DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32124,6 +32826,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEngineDefaultClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEngineDefaultClusterParameters");
         let mut params = params;
         DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -32163,6 +32868,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEngineDefaultParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEngineDefaultParameters");
         let mut params = params;
         DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -32196,6 +32904,9 @@ impl Rds for RdsClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEventCategories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -32229,6 +32940,9 @@ impl Rds for RdsClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEventSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventSubscriptions");
         let mut params = params;
         DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32262,6 +32976,9 @@ impl Rds for RdsClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -32292,6 +33009,9 @@ impl Rds for RdsClient {
         input: DescribeExportTasksMessage,
     ) -> Result<ExportTasksMessage, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeExportTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeExportTasks");
         let mut params = params;
         DescribeExportTasksMessageSerializer::serialize(&mut params, "", &input);
@@ -32323,6 +33043,9 @@ impl Rds for RdsClient {
         input: DescribeGlobalClustersMessage,
     ) -> Result<GlobalClustersMessage, RusotoError<DescribeGlobalClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeGlobalClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeGlobalClusters");
         let mut params = params;
         DescribeGlobalClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -32356,6 +33079,9 @@ impl Rds for RdsClient {
         input: DescribeInstallationMediaMessage,
     ) -> Result<InstallationMediaMessage, RusotoError<DescribeInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeInstallationMedia");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeInstallationMedia");
         let mut params = params;
         DescribeInstallationMediaMessageSerializer::serialize(&mut params, "", &input);
@@ -32389,6 +33115,9 @@ impl Rds for RdsClient {
         input: DescribeOptionGroupOptionsMessage,
     ) -> Result<OptionGroupOptionsMessage, RusotoError<DescribeOptionGroupOptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeOptionGroupOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeOptionGroupOptions");
         let mut params = params;
         DescribeOptionGroupOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32422,6 +33151,9 @@ impl Rds for RdsClient {
         input: DescribeOptionGroupsMessage,
     ) -> Result<OptionGroups, RusotoError<DescribeOptionGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeOptionGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeOptionGroups");
         let mut params = params;
         DescribeOptionGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32456,6 +33188,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeOrderableDBInstanceOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeOrderableDBInstanceOptions");
         let mut params = params;
         DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32493,6 +33228,9 @@ impl Rds for RdsClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribePendingMaintenanceActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribePendingMaintenanceActions");
         let mut params = params;
         DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32529,6 +33267,9 @@ impl Rds for RdsClient {
         input: DescribeReservedDBInstancesMessage,
     ) -> Result<ReservedDBInstanceMessage, RusotoError<DescribeReservedDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeReservedDBInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedDBInstances");
         let mut params = params;
         DescribeReservedDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -32565,6 +33306,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeReservedDBInstancesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeReservedDBInstancesOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedDBInstancesOfferings");
         let mut params = params;
         DescribeReservedDBInstancesOfferingsMessageSerializer::serialize(&mut params, "", &input);
@@ -32601,6 +33345,9 @@ impl Rds for RdsClient {
         input: DescribeSourceRegionsMessage,
     ) -> Result<SourceRegionMessage, RusotoError<DescribeSourceRegionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeSourceRegions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSourceRegions");
         let mut params = params;
         DescribeSourceRegionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32635,6 +33382,9 @@ impl Rds for RdsClient {
         RusotoError<DescribeValidDBInstanceModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DescribeValidDBInstanceModifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeValidDBInstanceModifications");
         let mut params = params;
         DescribeValidDBInstanceModificationsMessageSerializer::serialize(&mut params, "", &input);
@@ -32671,6 +33421,9 @@ impl Rds for RdsClient {
         input: DownloadDBLogFilePortionMessage,
     ) -> Result<DownloadDBLogFilePortionDetails, RusotoError<DownloadDBLogFilePortionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("DownloadDBLogFilePortion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DownloadDBLogFilePortion");
         let mut params = params;
         DownloadDBLogFilePortionMessageSerializer::serialize(&mut params, "", &input);
@@ -32704,6 +33457,9 @@ impl Rds for RdsClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("FailoverDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("FailoverDBCluster");
         let mut params = params;
         FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -32735,6 +33491,9 @@ impl Rds for RdsClient {
         input: ImportInstallationMediaMessage,
     ) -> Result<InstallationMedia, RusotoError<ImportInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ImportInstallationMedia");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ImportInstallationMedia");
         let mut params = params;
         ImportInstallationMediaMessageSerializer::serialize(&mut params, "", &input);
@@ -32766,6 +33525,9 @@ impl Rds for RdsClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -32797,6 +33559,9 @@ impl Rds for RdsClient {
         input: ModifyCertificatesMessage,
     ) -> Result<ModifyCertificatesResult, RusotoError<ModifyCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyCertificates");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCertificates");
         let mut params = params;
         ModifyCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -32830,6 +33595,9 @@ impl Rds for RdsClient {
         input: ModifyCurrentDBClusterCapacityMessage,
     ) -> Result<DBClusterCapacityInfo, RusotoError<ModifyCurrentDBClusterCapacityError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyCurrentDBClusterCapacity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCurrentDBClusterCapacity");
         let mut params = params;
         ModifyCurrentDBClusterCapacityMessageSerializer::serialize(&mut params, "", &input);
@@ -32863,6 +33631,9 @@ impl Rds for RdsClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBCluster");
         let mut params = params;
         ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -32894,6 +33665,9 @@ impl Rds for RdsClient {
         input: ModifyDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<ModifyDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterEndpoint");
         let mut params = params;
         ModifyDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -32926,6 +33700,9 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterParameterGroup");
         let mut params = params;
         ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -32962,6 +33739,9 @@ impl Rds for RdsClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBClusterSnapshotAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBClusterSnapshotAttribute");
         let mut params = params;
         ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -32998,6 +33778,9 @@ impl Rds for RdsClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBInstance");
         let mut params = params;
         ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -33029,6 +33812,9 @@ impl Rds for RdsClient {
         input: ModifyDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBParameterGroup");
         let mut params = params;
         ModifyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33062,6 +33848,9 @@ impl Rds for RdsClient {
         input: ModifyDBProxyRequest,
     ) -> Result<ModifyDBProxyResponse, RusotoError<ModifyDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBProxy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBProxy");
         let mut params = params;
         ModifyDBProxyRequestSerializer::serialize(&mut params, "", &input);
@@ -33093,6 +33882,9 @@ impl Rds for RdsClient {
         input: ModifyDBProxyTargetGroupRequest,
     ) -> Result<ModifyDBProxyTargetGroupResponse, RusotoError<ModifyDBProxyTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBProxyTargetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBProxyTargetGroup");
         let mut params = params;
         ModifyDBProxyTargetGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -33126,6 +33918,9 @@ impl Rds for RdsClient {
         input: ModifyDBSnapshotMessage,
     ) -> Result<ModifyDBSnapshotResult, RusotoError<ModifyDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBSnapshot");
         let mut params = params;
         ModifyDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -33157,6 +33952,9 @@ impl Rds for RdsClient {
         input: ModifyDBSnapshotAttributeMessage,
     ) -> Result<ModifyDBSnapshotAttributeResult, RusotoError<ModifyDBSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBSnapshotAttribute");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBSnapshotAttribute");
         let mut params = params;
         ModifyDBSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -33190,6 +33988,9 @@ impl Rds for RdsClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyDBSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyDBSubnetGroup");
         let mut params = params;
         ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33223,6 +34024,9 @@ impl Rds for RdsClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyEventSubscription");
         let mut params = params;
         ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -33256,6 +34060,9 @@ impl Rds for RdsClient {
         input: ModifyGlobalClusterMessage,
     ) -> Result<ModifyGlobalClusterResult, RusotoError<ModifyGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyGlobalCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyGlobalCluster");
         let mut params = params;
         ModifyGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33289,6 +34096,9 @@ impl Rds for RdsClient {
         input: ModifyOptionGroupMessage,
     ) -> Result<ModifyOptionGroupResult, RusotoError<ModifyOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ModifyOptionGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyOptionGroup");
         let mut params = params;
         ModifyOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33320,6 +34130,9 @@ impl Rds for RdsClient {
         input: PromoteReadReplicaMessage,
     ) -> Result<PromoteReadReplicaResult, RusotoError<PromoteReadReplicaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("PromoteReadReplica");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PromoteReadReplica");
         let mut params = params;
         PromoteReadReplicaMessageSerializer::serialize(&mut params, "", &input);
@@ -33354,6 +34167,9 @@ impl Rds for RdsClient {
     ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("PromoteReadReplicaDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PromoteReadReplicaDBCluster");
         let mut params = params;
         PromoteReadReplicaDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33390,6 +34206,9 @@ impl Rds for RdsClient {
         RusotoError<PurchaseReservedDBInstancesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("PurchaseReservedDBInstancesOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PurchaseReservedDBInstancesOffering");
         let mut params = params;
         PurchaseReservedDBInstancesOfferingMessageSerializer::serialize(&mut params, "", &input);
@@ -33426,6 +34245,9 @@ impl Rds for RdsClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RebootDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebootDBInstance");
         let mut params = params;
         RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -33457,6 +34279,9 @@ impl Rds for RdsClient {
         input: RegisterDBProxyTargetsRequest,
     ) -> Result<RegisterDBProxyTargetsResponse, RusotoError<RegisterDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RegisterDBProxyTargets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RegisterDBProxyTargets");
         let mut params = params;
         RegisterDBProxyTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -33490,6 +34315,9 @@ impl Rds for RdsClient {
         input: RemoveFromGlobalClusterMessage,
     ) -> Result<RemoveFromGlobalClusterResult, RusotoError<RemoveFromGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveFromGlobalCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveFromGlobalCluster");
         let mut params = params;
         RemoveFromGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33523,6 +34351,9 @@ impl Rds for RdsClient {
         input: RemoveRoleFromDBClusterMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveRoleFromDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveRoleFromDBCluster");
         let mut params = params;
         RemoveRoleFromDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33543,6 +34374,9 @@ impl Rds for RdsClient {
         input: RemoveRoleFromDBInstanceMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveRoleFromDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveRoleFromDBInstance");
         let mut params = params;
         RemoveRoleFromDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -33566,6 +34400,9 @@ impl Rds for RdsClient {
         RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveSourceIdentifierFromSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveSourceIdentifierFromSubscription");
         let mut params = params;
         RemoveSourceIdentifierFromSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -33602,6 +34439,9 @@ impl Rds for RdsClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -33623,6 +34463,9 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ResetDBClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetDBClusterParameterGroup");
         let mut params = params;
         ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33656,6 +34499,9 @@ impl Rds for RdsClient {
         input: ResetDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("ResetDBParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetDBParameterGroup");
         let mut params = params;
         ResetDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33689,6 +34535,9 @@ impl Rds for RdsClient {
         input: RestoreDBClusterFromS3Message,
     ) -> Result<RestoreDBClusterFromS3Result, RusotoError<RestoreDBClusterFromS3Error>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterFromS3");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterFromS3");
         let mut params = params;
         RestoreDBClusterFromS3MessageSerializer::serialize(&mut params, "", &input);
@@ -33723,6 +34572,9 @@ impl Rds for RdsClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterFromSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterFromSnapshot");
         let mut params = params;
         RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -33757,6 +34609,9 @@ impl Rds for RdsClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBClusterToPointInTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBClusterToPointInTime");
         let mut params = params;
         RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -33793,6 +34648,9 @@ impl Rds for RdsClient {
         RusotoError<RestoreDBInstanceFromDBSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBInstanceFromDBSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBInstanceFromDBSnapshot");
         let mut params = params;
         RestoreDBInstanceFromDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -33826,6 +34684,9 @@ impl Rds for RdsClient {
         input: RestoreDBInstanceFromS3Message,
     ) -> Result<RestoreDBInstanceFromS3Result, RusotoError<RestoreDBInstanceFromS3Error>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBInstanceFromS3");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBInstanceFromS3");
         let mut params = params;
         RestoreDBInstanceFromS3MessageSerializer::serialize(&mut params, "", &input);
@@ -33862,6 +34723,9 @@ impl Rds for RdsClient {
         RusotoError<RestoreDBInstanceToPointInTimeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RestoreDBInstanceToPointInTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreDBInstanceToPointInTime");
         let mut params = params;
         RestoreDBInstanceToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -33896,6 +34760,9 @@ impl Rds for RdsClient {
     ) -> Result<RevokeDBSecurityGroupIngressResult, RusotoError<RevokeDBSecurityGroupIngressError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("RevokeDBSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeDBSecurityGroupIngress");
         let mut params = params;
         RevokeDBSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -33929,6 +34796,9 @@ impl Rds for RdsClient {
         input: StartActivityStreamRequest,
     ) -> Result<StartActivityStreamResponse, RusotoError<StartActivityStreamError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StartActivityStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartActivityStream");
         let mut params = params;
         StartActivityStreamRequestSerializer::serialize(&mut params, "", &input);
@@ -33962,6 +34832,9 @@ impl Rds for RdsClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StartDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartDBCluster");
         let mut params = params;
         StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33993,6 +34866,9 @@ impl Rds for RdsClient {
         input: StartDBInstanceMessage,
     ) -> Result<StartDBInstanceResult, RusotoError<StartDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StartDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartDBInstance");
         let mut params = params;
         StartDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -34024,6 +34900,9 @@ impl Rds for RdsClient {
         input: StartExportTaskMessage,
     ) -> Result<ExportTask, RusotoError<StartExportTaskError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StartExportTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StartExportTask");
         let mut params = params;
         StartExportTaskMessageSerializer::serialize(&mut params, "", &input);
@@ -34054,6 +34933,9 @@ impl Rds for RdsClient {
         input: StopActivityStreamRequest,
     ) -> Result<StopActivityStreamResponse, RusotoError<StopActivityStreamError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StopActivityStream");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopActivityStream");
         let mut params = params;
         StopActivityStreamRequestSerializer::serialize(&mut params, "", &input);
@@ -34087,6 +34969,9 @@ impl Rds for RdsClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StopDBCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopDBCluster");
         let mut params = params;
         StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -34118,6 +35003,9 @@ impl Rds for RdsClient {
         input: StopDBInstanceMessage,
     ) -> Result<StopDBInstanceResult, RusotoError<StopDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation_name("StopDBInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("StopDBInstance");
         let mut params = params;
         StopDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -34144,6 +35032,1530 @@ impl Rds for RdsClient {
     }
 }
 
+/// A blocking client for the Amazon RDS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `RdsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RdsBlockingClient {
+    client: RdsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RdsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RdsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        RdsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RdsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RdsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> RdsBlockingClient {
+        RdsBlockingClient {
+            client: RdsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Associates an Identity and Access Management (IAM) role from an Amazon Aurora DB cluster. For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/AuroraMySQL.Integrating.Authorizing.html">Authorizing Amazon Aurora MySQL to Access Other AWS Services on Your Behalf</a> in the <i>Amazon Aurora User Guide</i>.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::add_role_to_db_cluster`.
+    pub fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_db_cluster(input))
+    }
+
+    /// <p><p>Associates an AWS Identity and Access Management (IAM) role with a DB instance.</p> <note> <p>To add a role to a DB instance, the status of the DB instance must be <code>available</code>.</p> </note></p>
+    ///
+    /// See `Rds::add_role_to_db_instance`.
+    pub fn add_role_to_db_instance(
+        &self,
+        input: AddRoleToDBInstanceMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBInstanceError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_db_instance(input))
+    }
+
+    /// <p>Adds a source identifier to an existing RDS event notification subscription.</p>
+    ///
+    /// See `Rds::add_source_identifier_to_subscription`.
+    pub fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<
+        AddSourceIdentifierToSubscriptionResult,
+        RusotoError<AddSourceIdentifierToSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_source_identifier_to_subscription(input))
+    }
+
+    /// <p>Adds metadata tags to an Amazon RDS resource. These tags can also be used with cost allocation reporting to track cost associated with Amazon RDS resources, or used in a Condition statement in an IAM policy for Amazon RDS.</p> <p>For an overview on tagging Amazon RDS resources, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/Overview.Tagging.html">Tagging Amazon RDS Resources</a>.</p>
+    ///
+    /// See `Rds::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Applies a pending maintenance action to a resource (for example, to a DB instance).</p>
+    ///
+    /// See `Rds::apply_pending_maintenance_action`.
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// <p>Enables ingress to a DBSecurityGroup using one of two forms of authorization. First, EC2 or VPC security groups can be added to the DBSecurityGroup if the application using the database is running on EC2 or VPC instances. Second, IP ranges are available if the application accessing your database is running on the Internet. Required parameters for this API are one of CIDR range, EC2SecurityGroupId for VPC, or (EC2SecurityGroupOwnerId and either EC2SecurityGroupName or EC2SecurityGroupId for non-VPC).</p> <note> <p>You can't authorize ingress from an EC2 security group in one AWS Region to an Amazon RDS DB instance in another. You can't authorize ingress from a VPC security group in one VPC to an Amazon RDS DB instance in another.</p> </note> <p>For an overview of CIDR ranges, go to the <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Wikipedia Tutorial</a>. </p>
+    ///
+    /// See `Rds::authorize_db_security_group_ingress`.
+    pub fn authorize_db_security_group_ingress(
+        &self,
+        input: AuthorizeDBSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeDBSecurityGroupIngressResult,
+        RusotoError<AuthorizeDBSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.authorize_db_security_group_ingress(input))
+    }
+
+    /// <p><p>Backtracks a DB cluster to a specific time, without creating a new DB cluster.</p> <p>For more information on backtracking, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/AuroraMySQL.Managing.Backtrack.html"> Backtracking an Aurora DB Cluster</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora MySQL DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::backtrack_db_cluster`.
+    pub fn backtrack_db_cluster(
+        &self,
+        input: BacktrackDBClusterMessage,
+    ) -> Result<DBClusterBacktrack, RusotoError<BacktrackDBClusterError>> {
+        self.runtime
+            .block_on(self.client.backtrack_db_cluster(input))
+    }
+
+    /// <p>Cancels an export task in progress that is exporting a snapshot to Amazon S3. Any data that has already been written to the S3 bucket isn't removed. </p>
+    ///
+    /// See `Rds::cancel_export_task`.
+    pub fn cancel_export_task(
+        &self,
+        input: CancelExportTaskMessage,
+    ) -> Result<ExportTask, RusotoError<CancelExportTaskError>> {
+        self.runtime.block_on(self.client.cancel_export_task(input))
+    }
+
+    /// <p><p>Copies the specified DB cluster parameter group.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::copy_db_cluster_parameter_group`.
+    pub fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Copies a snapshot of a DB cluster.</p> <p>To copy a DB cluster snapshot from a shared manual DB cluster snapshot, <code>SourceDBClusterSnapshotIdentifier</code> must be the Amazon Resource Name (ARN) of the shared DB cluster snapshot.</p> <p>You can copy an encrypted DB cluster snapshot from another AWS Region. In that case, the AWS Region where you call the <code>CopyDBClusterSnapshot</code> action is the destination AWS Region for the encrypted DB cluster snapshot to be copied to. To copy an encrypted DB cluster snapshot from another AWS Region, you must provide the following values:</p> <ul> <li> <p> <code>KmsKeyId</code> - The AWS Key Management System (AWS KMS) key identifier for the key to use to encrypt the copy of the DB cluster snapshot in the destination AWS Region.</p> </li> <li> <p> <code>PreSignedUrl</code> - A URL that contains a Signature Version 4 signed request for the <code>CopyDBClusterSnapshot</code> action to be called in the source AWS Region where the DB cluster snapshot is copied from. The pre-signed URL must be a valid request for the <code>CopyDBClusterSnapshot</code> API action that can be executed in the source AWS Region that contains the encrypted DB cluster snapshot to be copied.</p> <p>The pre-signed URL request must contain the following parameter values:</p> <ul> <li> <p> <code>KmsKeyId</code> - The KMS key identifier for the key to use to encrypt the copy of the DB cluster snapshot in the destination AWS Region. This is the same identifier for both the <code>CopyDBClusterSnapshot</code> action that is called in the destination AWS Region, and the action contained in the pre-signed URL.</p> </li> <li> <p> <code>DestinationRegion</code> - The name of the AWS Region that the DB cluster snapshot is to be created in.</p> </li> <li> <p> <code>SourceDBClusterSnapshotIdentifier</code> - The DB cluster snapshot identifier for the encrypted DB cluster snapshot to be copied. This identifier must be in the Amazon Resource Name (ARN) format for the source AWS Region. For example, if you are copying an encrypted DB cluster snapshot from the us-west-2 AWS Region, then your <code>SourceDBClusterSnapshotIdentifier</code> looks like the following example: <code>arn:aws:rds:us-west-2:123456789012:cluster-snapshot:aurora-cluster1-snapshot-20161115</code>.</p> </li> </ul> <p>To learn how to generate a Signature Version 4 signed request, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-query-string-auth.html"> Authenticating Requests: Using Query Parameters (AWS Signature Version 4)</a> and <a href="https://docs.aws.amazon.com/general/latest/gr/signature-version-4.html"> Signature Version 4 Signing Process</a>.</p> <note> <p>If you are using an AWS SDK tool or the AWS CLI, you can specify <code>SourceRegion</code> (or <code>--source-region</code> for the AWS CLI) instead of specifying <code>PreSignedUrl</code> manually. Specifying <code>SourceRegion</code> autogenerates a pre-signed URL that is a valid request for the operation that can be executed in the source AWS Region.</p> </note> </li> <li> <p> <code>TargetDBClusterSnapshotIdentifier</code> - The identifier for the new copy of the DB cluster snapshot in the destination AWS Region.</p> </li> <li> <p> <code>SourceDBClusterSnapshotIdentifier</code> - The DB cluster snapshot identifier for the encrypted DB cluster snapshot to be copied. This identifier must be in the ARN format for the source AWS Region and is the same value as the <code>SourceDBClusterSnapshotIdentifier</code> in the pre-signed URL. </p> </li> </ul> <p>To cancel the copy operation once it is in progress, delete the target DB cluster snapshot identified by <code>TargetDBClusterSnapshotIdentifier</code> while that DB cluster snapshot is in &quot;copying&quot; status.</p> <p>For more information on copying encrypted DB cluster snapshots from one AWS Region to another, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/USER_CopySnapshot.html"> Copying a Snapshot</a> in the <i>Amazon Aurora User Guide.</i> </p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::copy_db_cluster_snapshot`.
+    pub fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_snapshot(input))
+    }
+
+    /// <p>Copies the specified DB parameter group.</p>
+    ///
+    /// See `Rds::copy_db_parameter_group`.
+    pub fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.copy_db_parameter_group(input))
+    }
+
+    /// <p>Copies the specified DB snapshot. The source DB snapshot must be in the "available" state.</p> <p>You can copy a snapshot from one AWS Region to another. In that case, the AWS Region where you call the <code>CopyDBSnapshot</code> action is the destination AWS Region for the DB snapshot copy. </p> <p>For more information about copying snapshots, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_CopySnapshot.html#USER_CopyDBSnapshot">Copying a DB Snapshot</a> in the <i>Amazon RDS User Guide.</i> </p>
+    ///
+    /// See `Rds::copy_db_snapshot`.
+    pub fn copy_db_snapshot(
+        &self,
+        input: CopyDBSnapshotMessage,
+    ) -> Result<CopyDBSnapshotResult, RusotoError<CopyDBSnapshotError>> {
+        self.runtime.block_on(self.client.copy_db_snapshot(input))
+    }
+
+    /// <p>Copies the specified option group.</p>
+    ///
+    /// See `Rds::copy_option_group`.
+    pub fn copy_option_group(
+        &self,
+        input: CopyOptionGroupMessage,
+    ) -> Result<CopyOptionGroupResult, RusotoError<CopyOptionGroupError>> {
+        self.runtime.block_on(self.client.copy_option_group(input))
+    }
+
+    /// <p>Creates a custom Availability Zone (AZ).</p> <p>A custom AZ is an on-premises AZ that is integrated with a VMware vSphere cluster.</p> <p>For more information about RDS on VMware, see the <a href="https://docs.aws.amazon.com/AmazonRDS/latest/RDSonVMwareUserGuide/rds-on-vmware.html"> <i>RDS on VMware User Guide.</i> </a> </p>
+    ///
+    /// See `Rds::create_custom_availability_zone`.
+    pub fn create_custom_availability_zone(
+        &self,
+        input: CreateCustomAvailabilityZoneMessage,
+    ) -> Result<CreateCustomAvailabilityZoneResult, RusotoError<CreateCustomAvailabilityZoneError>>
+    {
+        self.runtime
+            .block_on(self.client.create_custom_availability_zone(input))
+    }
+
+    /// <p><p>Creates a new Amazon Aurora DB cluster.</p> <p>You can use the <code>ReplicationSourceIdentifier</code> parameter to create the DB cluster as a read replica of another DB cluster or Amazon RDS MySQL DB instance. For cross-region replication where the DB cluster identified by <code>ReplicationSourceIdentifier</code> is encrypted, you must also specify the <code>PreSignedUrl</code> parameter.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::create_db_cluster`.
+    pub fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.runtime.block_on(self.client.create_db_cluster(input))
+    }
+
+    /// <p><p>Creates a new custom endpoint and associates it with an Amazon Aurora DB cluster.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::create_db_cluster_endpoint`.
+    pub fn create_db_cluster_endpoint(
+        &self,
+        input: CreateDBClusterEndpointMessage,
+    ) -> Result<DBClusterEndpoint, RusotoError<CreateDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_endpoint(input))
+    }
+
+    /// <p><p>Creates a new DB cluster parameter group.</p> <p>Parameters in a DB cluster parameter group apply to all of the instances in a DB cluster.</p> <p> A DB cluster parameter group is initially created with the default parameters for the database engine used by instances in the DB cluster. To provide custom values for any of the parameters, you must modify the group after creating it using <code>ModifyDBClusterParameterGroup</code>. Once you&#39;ve created a DB cluster parameter group, you need to associate it with your DB cluster using <code>ModifyDBCluster</code>. When you associate a new DB cluster parameter group with a running DB cluster, you need to reboot the DB instances in the DB cluster without failover for the new DB cluster parameter group and associated settings to take effect. </p> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon RDS to fully complete the create action before the DB cluster parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon RDS console</a> or the <code>DescribeDBClusterParameters</code> action to verify that your DB cluster parameter group has been created or modified.</p> </important> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::create_db_cluster_parameter_group`.
+    pub fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Creates a snapshot of a DB cluster. For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::create_db_cluster_snapshot`.
+    pub fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new DB instance.</p>
+    ///
+    /// See `Rds::create_db_instance`.
+    pub fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.runtime.block_on(self.client.create_db_instance(input))
+    }
+
+    /// <p><p>Creates a new DB instance that acts as a read replica for an existing source DB instance. You can create a read replica for a DB instance running MySQL, MariaDB, Oracle, PostgreSQL, or SQL Server. For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_ReadRepl.html">Working with Read Replicas</a> in the <i>Amazon RDS User Guide</i>. </p> <p>Amazon Aurora doesn&#39;t support this action. Call the <code>CreateDBInstance</code> action to create a DB instance for an Aurora DB cluster.</p> <p>All read replica DB instances are created with backups disabled. All other DB instance attributes (including DB security groups and DB parameter groups) are inherited from the source DB instance, except as specified.</p> <important> <p>Your source DB instance must have backup retention enabled. </p> </important></p>
+    ///
+    /// See `Rds::create_db_instance_read_replica`.
+    pub fn create_db_instance_read_replica(
+        &self,
+        input: CreateDBInstanceReadReplicaMessage,
+    ) -> Result<CreateDBInstanceReadReplicaResult, RusotoError<CreateDBInstanceReadReplicaError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_instance_read_replica(input))
+    }
+
+    /// <p><p>Creates a new DB parameter group.</p> <p> A DB parameter group is initially created with the default parameters for the database engine used by the DB instance. To provide custom values for any of the parameters, you must modify the group after creating it using <i>ModifyDBParameterGroup</i>. Once you&#39;ve created a DB parameter group, you need to associate it with your DB instance using <i>ModifyDBInstance</i>. When you associate a new DB parameter group with a running DB instance, you need to reboot the DB instance without failover for the new DB parameter group and associated settings to take effect. </p> <important> <p>After you create a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon RDS to fully complete the create action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon RDS console</a> or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
+    ///
+    /// See `Rds::create_db_parameter_group`.
+    pub fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_parameter_group(input))
+    }
+
+    /// <p>Creates a new DB proxy.</p>
+    ///
+    /// See `Rds::create_db_proxy`.
+    pub fn create_db_proxy(
+        &self,
+        input: CreateDBProxyRequest,
+    ) -> Result<CreateDBProxyResponse, RusotoError<CreateDBProxyError>> {
+        self.runtime.block_on(self.client.create_db_proxy(input))
+    }
+
+    /// <p><p>Creates a new DB security group. DB security groups control access to a DB instance.</p> <note> <p>A DB security group controls access to EC2-Classic DB instances that are not in a VPC.</p> </note></p>
+    ///
+    /// See `Rds::create_db_security_group`.
+    pub fn create_db_security_group(
+        &self,
+        input: CreateDBSecurityGroupMessage,
+    ) -> Result<CreateDBSecurityGroupResult, RusotoError<CreateDBSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_security_group(input))
+    }
+
+    /// <p>Creates a DBSnapshot. The source DBInstance must be in "available" state.</p>
+    ///
+    /// See `Rds::create_db_snapshot`.
+    pub fn create_db_snapshot(
+        &self,
+        input: CreateDBSnapshotMessage,
+    ) -> Result<CreateDBSnapshotResult, RusotoError<CreateDBSnapshotError>> {
+        self.runtime.block_on(self.client.create_db_snapshot(input))
+    }
+
+    /// <p>Creates a new DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
+    ///
+    /// See `Rds::create_db_subnet_group`.
+    pub fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_subnet_group(input))
+    }
+
+    /// <p><p>Creates an RDS event notification subscription. This action requires a topic Amazon Resource Name (ARN) created by either the RDS console, the SNS console, or the SNS API. To obtain an ARN with SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the type of source (SourceType) you want to be notified of, provide a list of RDS sources (SourceIds) that triggers the events, and provide a list of event categories (EventCategories) for events you want to be notified of. For example, you can specify SourceType = db-instance, SourceIds = mydbinstance1, mydbinstance2 and EventCategories = Availability, Backup.</p> <p>If you specify both the SourceType and SourceIds, such as SourceType = db-instance and SourceIdentifier = myDBInstance1, you are notified of all the db-instance events for the specified source. If you specify a SourceType but do not specify a SourceIdentifier, you receive notice of the events for that source type for all your RDS sources. If you don&#39;t specify either the SourceType or the SourceIdentifier, you are notified of events generated from all RDS sources belonging to your customer account.</p> <note> <p>RDS event notification is only available for unencrypted SNS topics. If you specify an encrypted SNS topic, event notifications aren&#39;t sent for the topic.</p> </note></p>
+    ///
+    /// See `Rds::create_event_subscription`.
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// <p><p> Creates an Aurora global database spread across multiple regions. The global database contains a single primary cluster with read-write capability, and a read-only secondary cluster that receives data from the primary cluster through high-speed replication performed by the Aurora storage subsystem. </p> <p> You can create a global database that is initially empty, and then add a primary cluster and a secondary cluster to it. Or you can specify an existing Aurora cluster during the create operation, and this cluster becomes the primary cluster of the global database. </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::create_global_cluster`.
+    pub fn create_global_cluster(
+        &self,
+        input: CreateGlobalClusterMessage,
+    ) -> Result<CreateGlobalClusterResult, RusotoError<CreateGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.create_global_cluster(input))
+    }
+
+    /// <p>Creates a new option group. You can create up to 20 option groups.</p>
+    ///
+    /// See `Rds::create_option_group`.
+    pub fn create_option_group(
+        &self,
+        input: CreateOptionGroupMessage,
+    ) -> Result<CreateOptionGroupResult, RusotoError<CreateOptionGroupError>> {
+        self.runtime
+            .block_on(self.client.create_option_group(input))
+    }
+
+    /// <p>Deletes a custom Availability Zone (AZ).</p> <p>A custom AZ is an on-premises AZ that is integrated with a VMware vSphere cluster.</p> <p>For more information about RDS on VMware, see the <a href="https://docs.aws.amazon.com/AmazonRDS/latest/RDSonVMwareUserGuide/rds-on-vmware.html"> <i>RDS on VMware User Guide.</i> </a> </p>
+    ///
+    /// See `Rds::delete_custom_availability_zone`.
+    pub fn delete_custom_availability_zone(
+        &self,
+        input: DeleteCustomAvailabilityZoneMessage,
+    ) -> Result<DeleteCustomAvailabilityZoneResult, RusotoError<DeleteCustomAvailabilityZoneError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_custom_availability_zone(input))
+    }
+
+    /// <p><p>The DeleteDBCluster action deletes a previously provisioned DB cluster. When you delete a DB cluster, all automated backups for that DB cluster are deleted and can&#39;t be recovered. Manual DB cluster snapshots of the specified DB cluster are not deleted.</p> <p/> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_cluster`.
+    pub fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.runtime.block_on(self.client.delete_db_cluster(input))
+    }
+
+    /// <p><p>Deletes a custom endpoint and removes it from an Amazon Aurora DB cluster.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_cluster_endpoint`.
+    pub fn delete_db_cluster_endpoint(
+        &self,
+        input: DeleteDBClusterEndpointMessage,
+    ) -> Result<DBClusterEndpoint, RusotoError<DeleteDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_endpoint(input))
+    }
+
+    /// <p><p>Deletes a specified DB cluster parameter group. The DB cluster parameter group to be deleted can&#39;t be associated with any DB clusters.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_cluster_parameter_group`.
+    pub fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Deletes a DB cluster snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB cluster snapshot must be in the <code>available</code> state to be deleted.</p> </note> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_cluster_snapshot`.
+    pub fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_snapshot(input))
+    }
+
+    /// <p>The DeleteDBInstance action deletes a previously provisioned DB instance. When you delete a DB instance, all automated backups for that instance are deleted and can't be recovered. Manual DB snapshots of the DB instance to be deleted by <code>DeleteDBInstance</code> are not deleted.</p> <p> If you request a final DB snapshot the status of the Amazon RDS DB instance is <code>deleting</code> until the DB snapshot is created. The API action <code>DescribeDBInstance</code> is used to monitor the status of this operation. The action can't be canceled or reverted once submitted. </p> <p>When a DB instance is in a failure state and has a status of <code>failed</code>, <code>incompatible-restore</code>, or <code>incompatible-network</code>, you can only delete it when you skip creation of the final snapshot with the <code>SkipFinalSnapshot</code> parameter.</p> <p>If the specified DB instance is part of an Amazon Aurora DB cluster, you can't delete the DB instance if both of the following conditions are true:</p> <ul> <li> <p>The DB cluster is a read replica of another Amazon Aurora DB cluster.</p> </li> <li> <p>The DB instance is the only instance in the DB cluster.</p> </li> </ul> <p>To delete a DB instance in this case, first call the <code>PromoteReadReplicaDBCluster</code> API action to promote the DB cluster so it's no longer a read replica. After the promotion completes, then call the <code>DeleteDBInstance</code> API action to delete the final instance in the DB cluster.</p>
+    ///
+    /// See `Rds::delete_db_instance`.
+    pub fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.runtime.block_on(self.client.delete_db_instance(input))
+    }
+
+    /// <p>Deletes automated backups based on the source instance's <code>DbiResourceId</code> value or the restorable instance's resource ID.</p>
+    ///
+    /// See `Rds::delete_db_instance_automated_backup`.
+    pub fn delete_db_instance_automated_backup(
+        &self,
+        input: DeleteDBInstanceAutomatedBackupMessage,
+    ) -> Result<
+        DeleteDBInstanceAutomatedBackupResult,
+        RusotoError<DeleteDBInstanceAutomatedBackupError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_db_instance_automated_backup(input))
+    }
+
+    /// <p>Deletes a specified DB parameter group. The DB parameter group to be deleted can't be associated with any DB instances.</p>
+    ///
+    /// See `Rds::delete_db_parameter_group`.
+    pub fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_parameter_group(input))
+    }
+
+    /// <p>Deletes an existing proxy.</p>
+    ///
+    /// See `Rds::delete_db_proxy`.
+    pub fn delete_db_proxy(
+        &self,
+        input: DeleteDBProxyRequest,
+    ) -> Result<DeleteDBProxyResponse, RusotoError<DeleteDBProxyError>> {
+        self.runtime.block_on(self.client.delete_db_proxy(input))
+    }
+
+    /// <p><p>Deletes a DB security group.</p> <note> <p>The specified DB security group must not be associated with any DB instances.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_security_group`.
+    pub fn delete_db_security_group(
+        &self,
+        input: DeleteDBSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_security_group(input))
+    }
+
+    /// <p><p>Deletes a DB snapshot. If the snapshot is being copied, the copy operation is terminated.</p> <note> <p>The DB snapshot must be in the <code>available</code> state to be deleted.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_snapshot`.
+    pub fn delete_db_snapshot(
+        &self,
+        input: DeleteDBSnapshotMessage,
+    ) -> Result<DeleteDBSnapshotResult, RusotoError<DeleteDBSnapshotError>> {
+        self.runtime.block_on(self.client.delete_db_snapshot(input))
+    }
+
+    /// <p><p>Deletes a DB subnet group.</p> <note> <p>The specified database subnet group must not be associated with any DB instances.</p> </note></p>
+    ///
+    /// See `Rds::delete_db_subnet_group`.
+    pub fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_subnet_group(input))
+    }
+
+    /// <p>Deletes an RDS event notification subscription.</p>
+    ///
+    /// See `Rds::delete_event_subscription`.
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// <p><p> Deletes a global database cluster. The primary and secondary clusters must already be detached or destroyed first. </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::delete_global_cluster`.
+    pub fn delete_global_cluster(
+        &self,
+        input: DeleteGlobalClusterMessage,
+    ) -> Result<DeleteGlobalClusterResult, RusotoError<DeleteGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.delete_global_cluster(input))
+    }
+
+    /// <p>Deletes the installation medium for a DB engine that requires an on-premises customer provided license, such as Microsoft SQL Server.</p>
+    ///
+    /// See `Rds::delete_installation_media`.
+    pub fn delete_installation_media(
+        &self,
+        input: DeleteInstallationMediaMessage,
+    ) -> Result<InstallationMedia, RusotoError<DeleteInstallationMediaError>> {
+        self.runtime
+            .block_on(self.client.delete_installation_media(input))
+    }
+
+    /// <p>Deletes an existing option group.</p>
+    ///
+    /// See `Rds::delete_option_group`.
+    pub fn delete_option_group(
+        &self,
+        input: DeleteOptionGroupMessage,
+    ) -> Result<(), RusotoError<DeleteOptionGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_option_group(input))
+    }
+
+    /// <p>Remove the association between one or more <code>DBProxyTarget</code> data structures and a <code>DBProxyTargetGroup</code>.</p>
+    ///
+    /// See `Rds::deregister_db_proxy_targets`.
+    pub fn deregister_db_proxy_targets(
+        &self,
+        input: DeregisterDBProxyTargetsRequest,
+    ) -> Result<DeregisterDBProxyTargetsResponse, RusotoError<DeregisterDBProxyTargetsError>> {
+        self.runtime
+            .block_on(self.client.deregister_db_proxy_targets(input))
+    }
+
+    /// <p>Lists all of the attributes for a customer account. The attributes include Amazon RDS quotas for the account, such as the number of DB instances allowed. The description for a quota includes the quota name, current usage toward that quota, and the quota's maximum value.</p> <p>This command doesn't take any parameters.</p>
+    ///
+    /// See `Rds::describe_account_attributes`.
+    pub fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<AccountAttributesMessage, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes(input))
+    }
+
+    /// <p>Lists the set of CA certificates provided by Amazon RDS for this AWS account.</p>
+    ///
+    /// See `Rds::describe_certificates`.
+    pub fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_certificates(input))
+    }
+
+    /// <p>Returns information about custom Availability Zones (AZs).</p> <p>A custom AZ is an on-premises AZ that is integrated with a VMware vSphere cluster.</p> <p>For more information about RDS on VMware, see the <a href="https://docs.aws.amazon.com/AmazonRDS/latest/RDSonVMwareUserGuide/rds-on-vmware.html"> <i>RDS on VMware User Guide.</i> </a> </p>
+    ///
+    /// See `Rds::describe_custom_availability_zones`.
+    pub fn describe_custom_availability_zones(
+        &self,
+        input: DescribeCustomAvailabilityZonesMessage,
+    ) -> Result<CustomAvailabilityZoneMessage, RusotoError<DescribeCustomAvailabilityZonesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_custom_availability_zones(input))
+    }
+
+    /// <p><p>Returns information about backtracks for a DB cluster.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora MySQL DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_cluster_backtracks`.
+    pub fn describe_db_cluster_backtracks(
+        &self,
+        input: DescribeDBClusterBacktracksMessage,
+    ) -> Result<DBClusterBacktrackMessage, RusotoError<DescribeDBClusterBacktracksError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_backtracks(input))
+    }
+
+    /// <p><p>Returns information about endpoints for an Amazon Aurora DB cluster.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_cluster_endpoints`.
+    pub fn describe_db_cluster_endpoints(
+        &self,
+        input: DescribeDBClusterEndpointsMessage,
+    ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_endpoints(input))
+    }
+
+    /// <p><p> Returns a list of <code>DBClusterParameterGroup</code> descriptions. If a <code>DBClusterParameterGroupName</code> parameter is specified, the list will contain only the description of the specified DB cluster parameter group. </p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_cluster_parameter_groups`.
+    pub fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameter_groups(input))
+    }
+
+    /// <p><p>Returns the detailed parameter list for a particular DB cluster parameter group.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_cluster_parameters`.
+    pub fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameters(input))
+    }
+
+    /// <p><p>Returns a list of DB cluster snapshot attribute names and values for a manual DB cluster snapshot.</p> <p>When sharing snapshots with other AWS accounts, <code>DescribeDBClusterSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB cluster snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB cluster snapshot is public and can be copied or restored by all AWS accounts.</p> <p>To add or remove access for an AWS account to copy or restore a manual DB cluster snapshot, or to make the manual DB cluster snapshot public or private, use the <code>ModifyDBClusterSnapshotAttribute</code> API action.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_cluster_snapshot_attributes`.
+    pub fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshot_attributes(input))
+    }
+
+    /// <p><p>Returns information about DB cluster snapshots. This API action supports pagination.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_cluster_snapshots`.
+    pub fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshots(input))
+    }
+
+    /// <p><p>Returns information about provisioned Aurora DB clusters. This API supports pagination.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This operation can also return information for Amazon Neptune DB instances and Amazon DocumentDB instances.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_clusters`.
+    pub fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_clusters(input))
+    }
+
+    /// <p>Returns a list of the available DB engines.</p>
+    ///
+    /// See `Rds::describe_db_engine_versions`.
+    pub fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_engine_versions(input))
+    }
+
+    /// <p>Displays backups for both current and deleted instances. For example, use this operation to find details about automated backups for previously deleted instances. Current instances with retention periods greater than zero (0) are returned for both the <code>DescribeDBInstanceAutomatedBackups</code> and <code>DescribeDBInstances</code> operations.</p> <p>All parameters are optional.</p>
+    ///
+    /// See `Rds::describe_db_instance_automated_backups`.
+    pub fn describe_db_instance_automated_backups(
+        &self,
+        input: DescribeDBInstanceAutomatedBackupsMessage,
+    ) -> Result<
+        DBInstanceAutomatedBackupMessage,
+        RusotoError<DescribeDBInstanceAutomatedBackupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_instance_automated_backups(input))
+    }
+
+    /// <p><p>Returns information about provisioned RDS instances. This API supports pagination.</p> <note> <p>This operation can also return information for Amazon Neptune DB instances and Amazon DocumentDB instances.</p> </note></p>
+    ///
+    /// See `Rds::describe_db_instances`.
+    pub fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_instances(input))
+    }
+
+    /// <p>Returns a list of DB log files for the DB instance.</p>
+    ///
+    /// See `Rds::describe_db_log_files`.
+    pub fn describe_db_log_files(
+        &self,
+        input: DescribeDBLogFilesMessage,
+    ) -> Result<DescribeDBLogFilesResponse, RusotoError<DescribeDBLogFilesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_log_files(input))
+    }
+
+    /// <p> Returns a list of <code>DBParameterGroup</code> descriptions. If a <code>DBParameterGroupName</code> is specified, the list will contain only the description of the specified DB parameter group. </p>
+    ///
+    /// See `Rds::describe_db_parameter_groups`.
+    pub fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameter_groups(input))
+    }
+
+    /// <p>Returns the detailed parameter list for a particular DB parameter group.</p>
+    ///
+    /// See `Rds::describe_db_parameters`.
+    pub fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameters(input))
+    }
+
+    /// <p>Returns information about DB proxies.</p>
+    ///
+    /// See `Rds::describe_db_proxies`.
+    pub fn describe_db_proxies(
+        &self,
+        input: DescribeDBProxiesRequest,
+    ) -> Result<DescribeDBProxiesResponse, RusotoError<DescribeDBProxiesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_proxies(input))
+    }
+
+    /// <p>Returns information about DB proxy target groups, represented by <code>DBProxyTargetGroup</code> data structures.</p>
+    ///
+    /// See `Rds::describe_db_proxy_target_groups`.
+    pub fn describe_db_proxy_target_groups(
+        &self,
+        input: DescribeDBProxyTargetGroupsRequest,
+    ) -> Result<DescribeDBProxyTargetGroupsResponse, RusotoError<DescribeDBProxyTargetGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_proxy_target_groups(input))
+    }
+
+    /// <p>Returns information about <code>DBProxyTarget</code> objects. This API supports pagination.</p>
+    ///
+    /// See `Rds::describe_db_proxy_targets`.
+    pub fn describe_db_proxy_targets(
+        &self,
+        input: DescribeDBProxyTargetsRequest,
+    ) -> Result<DescribeDBProxyTargetsResponse, RusotoError<DescribeDBProxyTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_proxy_targets(input))
+    }
+
+    /// <p> Returns a list of <code>DBSecurityGroup</code> descriptions. If a <code>DBSecurityGroupName</code> is specified, the list will contain only the descriptions of the specified DB security group. </p>
+    ///
+    /// See `Rds::describe_db_security_groups`.
+    pub fn describe_db_security_groups(
+        &self,
+        input: DescribeDBSecurityGroupsMessage,
+    ) -> Result<DBSecurityGroupMessage, RusotoError<DescribeDBSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_security_groups(input))
+    }
+
+    /// <p>Returns a list of DB snapshot attribute names and values for a manual DB snapshot.</p> <p>When sharing snapshots with other AWS accounts, <code>DescribeDBSnapshotAttributes</code> returns the <code>restore</code> attribute and a list of IDs for the AWS accounts that are authorized to copy or restore the manual DB snapshot. If <code>all</code> is included in the list of values for the <code>restore</code> attribute, then the manual DB snapshot is public and can be copied or restored by all AWS accounts.</p> <p>To add or remove access for an AWS account to copy or restore a manual DB snapshot, or to make the manual DB snapshot public or private, use the <code>ModifyDBSnapshotAttribute</code> API action.</p>
+    ///
+    /// See `Rds::describe_db_snapshot_attributes`.
+    pub fn describe_db_snapshot_attributes(
+        &self,
+        input: DescribeDBSnapshotAttributesMessage,
+    ) -> Result<DescribeDBSnapshotAttributesResult, RusotoError<DescribeDBSnapshotAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_snapshot_attributes(input))
+    }
+
+    /// <p>Returns information about DB snapshots. This API action supports pagination.</p>
+    ///
+    /// See `Rds::describe_db_snapshots`.
+    pub fn describe_db_snapshots(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_snapshots(input))
+    }
+
+    /// <p>Returns a list of DBSubnetGroup descriptions. If a DBSubnetGroupName is specified, the list will contain only the descriptions of the specified DBSubnetGroup.</p> <p>For an overview of CIDR ranges, go to the <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Wikipedia Tutorial</a>. </p>
+    ///
+    /// See `Rds::describe_db_subnet_groups`.
+    pub fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_subnet_groups(input))
+    }
+
+    /// <p>Returns the default engine and system parameter information for the cluster database engine.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p>
+    ///
+    /// See `Rds::describe_engine_default_cluster_parameters`.
+    pub fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_engine_default_cluster_parameters(input),
+        )
+    }
+
+    /// <p>Returns the default engine and system parameter information for the specified database engine.</p>
+    ///
+    /// See `Rds::describe_engine_default_parameters`.
+    pub fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_engine_default_parameters(input))
+    }
+
+    /// <p>Displays a list of categories for all event source types, or, if specified, for a specified source type. You can see a list of the event categories and source types in the <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_Events.html"> Events</a> topic in the <i>Amazon RDS User Guide.</i> </p>
+    ///
+    /// See `Rds::describe_event_categories`.
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// <p>Lists all the subscription descriptions for a customer account. The description for a subscription includes SubscriptionName, SNSTopicARN, CustomerID, SourceType, SourceID, CreationTime, and Status.</p> <p>If you specify a SubscriptionName, lists the description for that subscription.</p>
+    ///
+    /// See `Rds::describe_event_subscriptions`.
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// <p>Returns events related to DB instances, DB security groups, DB snapshots, and DB parameter groups for the past 14 days. Events specific to a particular DB instance, DB security group, database snapshot, or DB parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
+    ///
+    /// See `Rds::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns information about a snapshot export to Amazon S3. This API operation supports pagination. </p>
+    ///
+    /// See `Rds::describe_export_tasks`.
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksMessage,
+    ) -> Result<ExportTasksMessage, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// <p><p> Returns information about Aurora global database clusters. This API supports pagination. </p> <p> For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::describe_global_clusters`.
+    pub fn describe_global_clusters(
+        &self,
+        input: DescribeGlobalClustersMessage,
+    ) -> Result<GlobalClustersMessage, RusotoError<DescribeGlobalClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_global_clusters(input))
+    }
+
+    /// <p>Describes the available installation media for a DB engine that requires an on-premises customer provided license, such as Microsoft SQL Server.</p>
+    ///
+    /// See `Rds::describe_installation_media`.
+    pub fn describe_installation_media(
+        &self,
+        input: DescribeInstallationMediaMessage,
+    ) -> Result<InstallationMediaMessage, RusotoError<DescribeInstallationMediaError>> {
+        self.runtime
+            .block_on(self.client.describe_installation_media(input))
+    }
+
+    /// <p>Describes all available options.</p>
+    ///
+    /// See `Rds::describe_option_group_options`.
+    pub fn describe_option_group_options(
+        &self,
+        input: DescribeOptionGroupOptionsMessage,
+    ) -> Result<OptionGroupOptionsMessage, RusotoError<DescribeOptionGroupOptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_option_group_options(input))
+    }
+
+    /// <p>Describes the available option groups.</p>
+    ///
+    /// See `Rds::describe_option_groups`.
+    pub fn describe_option_groups(
+        &self,
+        input: DescribeOptionGroupsMessage,
+    ) -> Result<OptionGroups, RusotoError<DescribeOptionGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_option_groups(input))
+    }
+
+    /// <p>Returns a list of orderable DB instance options for the specified engine.</p>
+    ///
+    /// See `Rds::describe_orderable_db_instance_options`.
+    pub fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_db_instance_options(input))
+    }
+
+    /// <p>Returns a list of resources (for example, DB instances) that have at least one pending maintenance action.</p>
+    ///
+    /// See `Rds::describe_pending_maintenance_actions`.
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// <p>Returns information about reserved DB instances for this account, or about a specified reserved DB instance.</p>
+    ///
+    /// See `Rds::describe_reserved_db_instances`.
+    pub fn describe_reserved_db_instances(
+        &self,
+        input: DescribeReservedDBInstancesMessage,
+    ) -> Result<ReservedDBInstanceMessage, RusotoError<DescribeReservedDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_db_instances(input))
+    }
+
+    /// <p>Lists available reserved DB instance offerings.</p>
+    ///
+    /// See `Rds::describe_reserved_db_instances_offerings`.
+    pub fn describe_reserved_db_instances_offerings(
+        &self,
+        input: DescribeReservedDBInstancesOfferingsMessage,
+    ) -> Result<
+        ReservedDBInstancesOfferingMessage,
+        RusotoError<DescribeReservedDBInstancesOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_db_instances_offerings(input))
+    }
+
+    /// <p>Returns a list of the source AWS Regions where the current AWS Region can create a read replica or copy a DB snapshot from. This API action supports pagination.</p>
+    ///
+    /// See `Rds::describe_source_regions`.
+    pub fn describe_source_regions(
+        &self,
+        input: DescribeSourceRegionsMessage,
+    ) -> Result<SourceRegionMessage, RusotoError<DescribeSourceRegionsError>> {
+        self.runtime
+            .block_on(self.client.describe_source_regions(input))
+    }
+
+    /// <p>You can call <code>DescribeValidDBInstanceModifications</code> to learn what modifications you can make to your DB instance. You can use this information when you call <code>ModifyDBInstance</code>. </p>
+    ///
+    /// See `Rds::describe_valid_db_instance_modifications`.
+    pub fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<
+        DescribeValidDBInstanceModificationsResult,
+        RusotoError<DescribeValidDBInstanceModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_valid_db_instance_modifications(input))
+    }
+
+    /// <p>Downloads all or a portion of the specified log file, up to 1 MB in size.</p>
+    ///
+    /// See `Rds::download_db_log_file_portion`.
+    pub fn download_db_log_file_portion(
+        &self,
+        input: DownloadDBLogFilePortionMessage,
+    ) -> Result<DownloadDBLogFilePortionDetails, RusotoError<DownloadDBLogFilePortionError>> {
+        self.runtime
+            .block_on(self.client.download_db_log_file_portion(input))
+    }
+
+    /// <p><p>Forces a failover for a DB cluster.</p> <p>A failover for a DB cluster promotes one of the Aurora Replicas (read-only instances) in the DB cluster to be the primary instance (the cluster writer).</p> <p>Amazon Aurora will automatically fail over to an Aurora Replica, if one exists, when the primary instance fails. You can force a failover when you want to simulate a failure of a primary instance for testing. Because each instance in a DB cluster has its own endpoint address, you will need to clean up and re-establish any existing connections that use those endpoint addresses when the failover is complete.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::failover_db_cluster`.
+    pub fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.runtime
+            .block_on(self.client.failover_db_cluster(input))
+    }
+
+    /// <p>Imports the installation media for a DB engine that requires an on-premises customer provided license, such as SQL Server.</p>
+    ///
+    /// See `Rds::import_installation_media`.
+    pub fn import_installation_media(
+        &self,
+        input: ImportInstallationMediaMessage,
+    ) -> Result<InstallationMedia, RusotoError<ImportInstallationMediaError>> {
+        self.runtime
+            .block_on(self.client.import_installation_media(input))
+    }
+
+    /// <p>Lists all tags on an Amazon RDS resource.</p> <p>For an overview on tagging an Amazon RDS resource, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/Overview.Tagging.html">Tagging Amazon RDS Resources</a> in the <i>Amazon RDS User Guide</i>.</p>
+    ///
+    /// See `Rds::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Override the system-default Secure Sockets Layer/Transport Layer Security (SSL/TLS) certificate for Amazon RDS for new DB instances temporarily, or remove the override.</p> <p>By using this operation, you can specify an RDS-approved SSL/TLS certificate for new DB instances that is different from the default certificate provided by RDS. You can also use this operation to remove the override, so that new DB instances use the default certificate provided by RDS.</p> <p>You might need to override the default certificate in the following situations:</p> <ul> <li> <p>You already migrated your applications to support the latest certificate authority (CA) certificate, but the new CA certificate is not yet the RDS default CA certificate for the specified AWS Region.</p> </li> <li> <p>RDS has already moved to a new default CA certificate for the specified AWS Region, but you are still in the process of supporting the new CA certificate. In this case, you temporarily need additional time to finish your application changes.</p> </li> </ul> <p>For more information about rotating your SSL/TLS certificate for RDS DB engines, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/UsingWithRDS.SSL-certificate-rotation.html"> Rotating Your SSL/TLS Certificate</a> in the <i>Amazon RDS User Guide</i>.</p> <p>For more information about rotating your SSL/TLS certificate for Aurora DB engines, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/UsingWithRDS.SSL-certificate-rotation.html"> Rotating Your SSL/TLS Certificate</a> in the <i>Amazon Aurora User Guide</i>.</p>
+    ///
+    /// See `Rds::modify_certificates`.
+    pub fn modify_certificates(
+        &self,
+        input: ModifyCertificatesMessage,
+    ) -> Result<ModifyCertificatesResult, RusotoError<ModifyCertificatesError>> {
+        self.runtime
+            .block_on(self.client.modify_certificates(input))
+    }
+
+    /// <p><p>Set the capacity of an Aurora Serverless DB cluster to a specific value.</p> <p>Aurora Serverless scales seamlessly based on the workload on the DB cluster. In some cases, the capacity might not scale fast enough to meet a sudden change in workload, such as a large number of new transactions. Call <code>ModifyCurrentDBClusterCapacity</code> to set the capacity explicitly.</p> <p>After this call sets the DB cluster capacity, Aurora Serverless can automatically scale the DB cluster based on the cooldown period for scaling up and the cooldown period for scaling down.</p> <p>For more information about Aurora Serverless, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/aurora-serverless.html">Using Amazon Aurora Serverless</a> in the <i>Amazon Aurora User Guide</i>.</p> <important> <p>If you call <code>ModifyCurrentDBClusterCapacity</code> with the default <code>TimeoutAction</code>, connections that prevent Aurora Serverless from finding a scaling point might be dropped. For more information about scaling points, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/aurora-serverless.how-it-works.html#aurora-serverless.how-it-works.auto-scaling"> Autoscaling for Aurora Serverless</a> in the <i>Amazon Aurora User Guide</i>.</p> </important> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::modify_current_db_cluster_capacity`.
+    pub fn modify_current_db_cluster_capacity(
+        &self,
+        input: ModifyCurrentDBClusterCapacityMessage,
+    ) -> Result<DBClusterCapacityInfo, RusotoError<ModifyCurrentDBClusterCapacityError>> {
+        self.runtime
+            .block_on(self.client.modify_current_db_cluster_capacity(input))
+    }
+
+    /// <p><p>Modify a setting for an Amazon Aurora DB cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::modify_db_cluster`.
+    pub fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.runtime.block_on(self.client.modify_db_cluster(input))
+    }
+
+    /// <p><p>Modifies the properties of an endpoint in an Amazon Aurora DB cluster.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::modify_db_cluster_endpoint`.
+    pub fn modify_db_cluster_endpoint(
+        &self,
+        input: ModifyDBClusterEndpointMessage,
+    ) -> Result<DBClusterEndpoint, RusotoError<ModifyDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_endpoint(input))
+    }
+
+    /// <p><p> Modifies the parameters of a DB cluster parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB cluster associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you create a DB cluster parameter group, you should wait at least 5 minutes before creating your first DB cluster that uses that DB cluster parameter group as the default parameter group. This allows Amazon RDS to fully complete the create action before the parameter group is used as the default for a new DB cluster. This is especially important for parameters that are critical when creating the default database for a DB cluster, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon RDS console</a> or the <code>DescribeDBClusterParameters</code> action to verify that your DB cluster parameter group has been created or modified.</p> <p>If the modified DB cluster parameter group is used by an Aurora Serverless cluster, Aurora applies the update immediately. The cluster restart might interrupt your workload. In that case, your application must reopen any connections and retry any transactions that were active when the parameter changes took effect.</p> </important> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::modify_db_cluster_parameter_group`.
+    pub fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Adds an attribute and values to, or removes an attribute and values from, a manual DB cluster snapshot.</p> <p>To share a manual DB cluster snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code> and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB cluster snapshot. Use the value <code>all</code> to make the manual DB cluster snapshot public, which means that it can be copied or restored by all AWS accounts.</p> <note> <p>Don&#39;t add the <code>all</code> value for any manual DB cluster snapshots that contain private information that you don&#39;t want available to all AWS accounts.</p> </note> <p>If a manual DB cluster snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can&#39;t use <code>all</code> as a value for that parameter in this case.</p> <p>To view which AWS accounts have access to copy or restore a manual DB cluster snapshot, or whether a manual DB cluster snapshot is public or private, use the <a>DescribeDBClusterSnapshotAttributes</a> API action. The accounts are returned as values for the <code>restore</code> attribute.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::modify_db_cluster_snapshot_attribute`.
+    pub fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_snapshot_attribute(input))
+    }
+
+    /// <p>Modifies settings for a DB instance. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. To learn what modifications you can make to your DB instance, call <code>DescribeValidDBInstanceModifications</code> before you call <code>ModifyDBInstance</code>. </p>
+    ///
+    /// See `Rds::modify_db_instance`.
+    pub fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.runtime.block_on(self.client.modify_db_instance(input))
+    }
+
+    /// <p><p> Modifies the parameters of a DB parameter group. To modify more than one parameter, submit a list of the following: <code>ParameterName</code>, <code>ParameterValue</code>, and <code>ApplyMethod</code>. A maximum of 20 parameters can be modified in a single request. </p> <note> <p>Changes to dynamic parameters are applied immediately. Changes to static parameters require a reboot without failover to the DB instance associated with the parameter group before the change can take effect.</p> </note> <important> <p>After you modify a DB parameter group, you should wait at least 5 minutes before creating your first DB instance that uses that DB parameter group as the default parameter group. This allows Amazon RDS to fully complete the modify action before the parameter group is used as the default for a new DB instance. This is especially important for parameters that are critical when creating the default database for a DB instance, such as the character set for the default database defined by the <code>character<em>set</em>database</code> parameter. You can use the <i>Parameter Groups</i> option of the <a href="https://console.aws.amazon.com/rds/">Amazon RDS console</a> or the <i>DescribeDBParameters</i> command to verify that your DB parameter group has been created or modified.</p> </important></p>
+    ///
+    /// See `Rds::modify_db_parameter_group`.
+    pub fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_parameter_group(input))
+    }
+
+    /// <p>Changes the settings for an existing DB proxy.</p>
+    ///
+    /// See `Rds::modify_db_proxy`.
+    pub fn modify_db_proxy(
+        &self,
+        input: ModifyDBProxyRequest,
+    ) -> Result<ModifyDBProxyResponse, RusotoError<ModifyDBProxyError>> {
+        self.runtime.block_on(self.client.modify_db_proxy(input))
+    }
+
+    /// <p>Modifies the properties of a <code>DBProxyTargetGroup</code>.</p>
+    ///
+    /// See `Rds::modify_db_proxy_target_group`.
+    pub fn modify_db_proxy_target_group(
+        &self,
+        input: ModifyDBProxyTargetGroupRequest,
+    ) -> Result<ModifyDBProxyTargetGroupResponse, RusotoError<ModifyDBProxyTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_proxy_target_group(input))
+    }
+
+    /// <p>Updates a manual DB snapshot with a new engine version. The snapshot can be encrypted or unencrypted, but not shared or public. </p> <p>Amazon RDS supports upgrading DB snapshots for MySQL, Oracle, and PostgreSQL. </p>
+    ///
+    /// See `Rds::modify_db_snapshot`.
+    pub fn modify_db_snapshot(
+        &self,
+        input: ModifyDBSnapshotMessage,
+    ) -> Result<ModifyDBSnapshotResult, RusotoError<ModifyDBSnapshotError>> {
+        self.runtime.block_on(self.client.modify_db_snapshot(input))
+    }
+
+    /// <p>Adds an attribute and values to, or removes an attribute and values from, a manual DB snapshot.</p> <p>To share a manual DB snapshot with other AWS accounts, specify <code>restore</code> as the <code>AttributeName</code> and use the <code>ValuesToAdd</code> parameter to add a list of IDs of the AWS accounts that are authorized to restore the manual DB snapshot. Uses the value <code>all</code> to make the manual DB snapshot public, which means it can be copied or restored by all AWS accounts.</p> <note> <p>Don't add the <code>all</code> value for any manual DB snapshots that contain private information that you don't want available to all AWS accounts.</p> </note> <p>If the manual DB snapshot is encrypted, it can be shared, but only by specifying a list of authorized AWS account IDs for the <code>ValuesToAdd</code> parameter. You can't use <code>all</code> as a value for that parameter in this case.</p> <p>To view which AWS accounts have access to copy or restore a manual DB snapshot, or whether a manual DB snapshot public or private, use the <a>DescribeDBSnapshotAttributes</a> API action. The accounts are returned as values for the <code>restore</code> attribute.</p>
+    ///
+    /// See `Rds::modify_db_snapshot_attribute`.
+    pub fn modify_db_snapshot_attribute(
+        &self,
+        input: ModifyDBSnapshotAttributeMessage,
+    ) -> Result<ModifyDBSnapshotAttributeResult, RusotoError<ModifyDBSnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_db_snapshot_attribute(input))
+    }
+
+    /// <p>Modifies an existing DB subnet group. DB subnet groups must contain at least one subnet in at least two AZs in the AWS Region.</p>
+    ///
+    /// See `Rds::modify_db_subnet_group`.
+    pub fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_subnet_group(input))
+    }
+
+    /// <p>Modifies an existing RDS event notification subscription. You can't modify the source identifiers using this call. To change source identifiers for a subscription, use the <code>AddSourceIdentifierToSubscription</code> and <code>RemoveSourceIdentifierFromSubscription</code> calls.</p> <p>You can see a list of the event categories for a given SourceType in the <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_Events.html">Events</a> topic in the <i>Amazon RDS User Guide</i> or by using the <b>DescribeEventCategories</b> action.</p>
+    ///
+    /// See `Rds::modify_event_subscription`.
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// <p><p> Modify a setting for an Amazon Aurora global cluster. You can change one or more database configuration parameters by specifying these parameters and the new values in the request. For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::modify_global_cluster`.
+    pub fn modify_global_cluster(
+        &self,
+        input: ModifyGlobalClusterMessage,
+    ) -> Result<ModifyGlobalClusterResult, RusotoError<ModifyGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.modify_global_cluster(input))
+    }
+
+    /// <p>Modifies an existing option group.</p>
+    ///
+    /// See `Rds::modify_option_group`.
+    pub fn modify_option_group(
+        &self,
+        input: ModifyOptionGroupMessage,
+    ) -> Result<ModifyOptionGroupResult, RusotoError<ModifyOptionGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_option_group(input))
+    }
+
+    /// <p><p>Promotes a read replica DB instance to a standalone DB instance.</p> <note> <ul> <li> <p>Backup duration is a function of the amount of changes to the database since the previous backup. If you plan to promote a read replica to a standalone instance, we recommend that you enable backups and complete at least one backup prior to promotion. In addition, a read replica cannot be promoted to a standalone instance when it is in the <code>backing-up</code> status. If you have enabled backups on your read replica, configure the automated backup window so that daily backups do not interfere with read replica promotion.</p> </li> <li> <p>This command doesn&#39;t apply to Aurora MySQL and Aurora PostgreSQL.</p> </li> </ul> </note></p>
+    ///
+    /// See `Rds::promote_read_replica`.
+    pub fn promote_read_replica(
+        &self,
+        input: PromoteReadReplicaMessage,
+    ) -> Result<PromoteReadReplicaResult, RusotoError<PromoteReadReplicaError>> {
+        self.runtime
+            .block_on(self.client.promote_read_replica(input))
+    }
+
+    /// <p><p>Promotes a read replica DB cluster to a standalone DB cluster.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::promote_read_replica_db_cluster`.
+    pub fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
+    {
+        self.runtime
+            .block_on(self.client.promote_read_replica_db_cluster(input))
+    }
+
+    /// <p>Purchases a reserved DB instance offering.</p>
+    ///
+    /// See `Rds::purchase_reserved_db_instances_offering`.
+    pub fn purchase_reserved_db_instances_offering(
+        &self,
+        input: PurchaseReservedDBInstancesOfferingMessage,
+    ) -> Result<
+        PurchaseReservedDBInstancesOfferingResult,
+        RusotoError<PurchaseReservedDBInstancesOfferingError>,
+    > {
+        self.runtime
+            .block_on(self.client.purchase_reserved_db_instances_offering(input))
+    }
+
+    /// <p>You might need to reboot your DB instance, usually for maintenance reasons. For example, if you make certain modifications, or if you change the DB parameter group associated with the DB instance, you must reboot the instance for the changes to take effect. </p> <p>Rebooting a DB instance restarts the database engine service. Rebooting a DB instance results in a momentary outage, during which the DB instance status is set to rebooting. </p> <p>For more information about rebooting, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_RebootInstance.html">Rebooting a DB Instance</a> in the <i>Amazon RDS User Guide.</i> </p>
+    ///
+    /// See `Rds::reboot_db_instance`.
+    pub fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.runtime.block_on(self.client.reboot_db_instance(input))
+    }
+
+    /// <p>Associate one or more <code>DBProxyTarget</code> data structures with a <code>DBProxyTargetGroup</code>.</p>
+    ///
+    /// See `Rds::register_db_proxy_targets`.
+    pub fn register_db_proxy_targets(
+        &self,
+        input: RegisterDBProxyTargetsRequest,
+    ) -> Result<RegisterDBProxyTargetsResponse, RusotoError<RegisterDBProxyTargetsError>> {
+        self.runtime
+            .block_on(self.client.register_db_proxy_targets(input))
+    }
+
+    /// <p><p> Detaches an Aurora secondary cluster from an Aurora global database cluster. The cluster becomes a standalone cluster with read-write capability instead of being read-only and receiving data from a primary cluster in a different region. </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::remove_from_global_cluster`.
+    pub fn remove_from_global_cluster(
+        &self,
+        input: RemoveFromGlobalClusterMessage,
+    ) -> Result<RemoveFromGlobalClusterResult, RusotoError<RemoveFromGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.remove_from_global_cluster(input))
+    }
+
+    /// <p><p>Disassociates an AWS Identity and Access Management (IAM) role from an Amazon Aurora DB cluster. For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/AuroraMySQL.Integrating.Authorizing.html">Authorizing Amazon Aurora MySQL to Access Other AWS Services on Your Behalf </a> in the <i>Amazon Aurora User Guide</i>.</p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::remove_role_from_db_cluster`.
+    pub fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_db_cluster(input))
+    }
+
+    /// <p>Disassociates an AWS Identity and Access Management (IAM) role from a DB instance.</p>
+    ///
+    /// See `Rds::remove_role_from_db_instance`.
+    pub fn remove_role_from_db_instance(
+        &self,
+        input: RemoveRoleFromDBInstanceMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBInstanceError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_db_instance(input))
+    }
+
+    /// <p>Removes a source identifier from an existing RDS event notification subscription.</p>
+    ///
+    /// See `Rds::remove_source_identifier_from_subscription`.
+    pub fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .remove_source_identifier_from_subscription(input),
+        )
+    }
+
+    /// <p>Removes metadata tags from an Amazon RDS resource.</p> <p>For an overview on tagging an Amazon RDS resource, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/Overview.Tagging.html">Tagging Amazon RDS Resources</a> in the <i>Amazon RDS User Guide.</i> </p>
+    ///
+    /// See `Rds::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p><p> Modifies the parameters of a DB cluster parameter group to the default value. To reset specific parameters submit a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB cluster parameter group, specify the <code>DBClusterParameterGroupName</code> and <code>ResetAllParameters</code> parameters. </p> <p> When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <code>RebootDBInstance</code> request. You must call <code>RebootDBInstance</code> for every DB instance in your DB cluster that you want the updated static parameter to apply to.</p> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::reset_db_cluster_parameter_group`.
+    pub fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_db_cluster_parameter_group(input))
+    }
+
+    /// <p>Modifies the parameters of a DB parameter group to the engine/system default value. To reset specific parameters, provide a list of the following: <code>ParameterName</code> and <code>ApplyMethod</code>. To reset the entire DB parameter group, specify the <code>DBParameterGroup</code> name and <code>ResetAllParameters</code> parameters. When resetting the entire group, dynamic parameters are updated immediately and static parameters are set to <code>pending-reboot</code> to take effect on the next DB instance restart or <code>RebootDBInstance</code> request. </p>
+    ///
+    /// See `Rds::reset_db_parameter_group`.
+    pub fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.reset_db_parameter_group(input))
+    }
+
+    /// <p><p>Creates an Amazon Aurora DB cluster from data stored in an Amazon S3 bucket. Amazon RDS must be authorized to access the Amazon S3 bucket and the data must be created using the Percona XtraBackup utility as described in <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/AuroraMySQL.Migrating.html"> Migrating Data to an Amazon Aurora MySQL DB Cluster</a> in the <i>Amazon Aurora User Guide</i>.</p> <note> <p>This action only restores the DB cluster, not the DB instances for that DB cluster. You must invoke the <code>CreateDBInstance</code> action to create DB instances for the restored DB cluster, specifying the identifier of the restored DB cluster in <code>DBClusterIdentifier</code>. You can create DB instances only after the <code>RestoreDBClusterFromS3</code> action has completed and the DB cluster is available.</p> </note> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::restore_db_cluster_from_s3`.
+    pub fn restore_db_cluster_from_s3(
+        &self,
+        input: RestoreDBClusterFromS3Message,
+    ) -> Result<RestoreDBClusterFromS3Result, RusotoError<RestoreDBClusterFromS3Error>> {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_s3(input))
+    }
+
+    /// <p><p>Creates a new DB cluster from a DB snapshot or DB cluster snapshot. This action only applies to Aurora DB clusters.</p> <p>The target DB cluster is created from the source snapshot with a default configuration. If you don&#39;t specify a security group, the new DB cluster is associated with the default security group.</p> <note> <p>This action only restores the DB cluster, not the DB instances for that DB cluster. You must invoke the <code>CreateDBInstance</code> action to create DB instances for the restored DB cluster, specifying the identifier of the restored DB cluster in <code>DBClusterIdentifier</code>. You can create DB instances only after the <code>RestoreDBClusterFromSnapshot</code> action has completed and the DB cluster is available.</p> </note> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::restore_db_cluster_from_snapshot`.
+    pub fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_snapshot(input))
+    }
+
+    /// <p><p>Restores a DB cluster to an arbitrary point in time. Users can restore to any point in time before <code>LatestRestorableTime</code> for up to <code>BackupRetentionPeriod</code> days. The target DB cluster is created from the source DB cluster with the same configuration as the original DB cluster, except that the new DB cluster is created with the default DB security group. </p> <note> <p>This action only restores the DB cluster, not the DB instances for that DB cluster. You must invoke the <code>CreateDBInstance</code> action to create DB instances for the restored DB cluster, specifying the identifier of the restored DB cluster in <code>DBClusterIdentifier</code>. You can create DB instances only after the <code>RestoreDBClusterToPointInTime</code> action has completed and the DB cluster is available.</p> </note> <p>For more information on Amazon Aurora, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/CHAP_AuroraOverview.html"> What Is Amazon Aurora?</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::restore_db_cluster_to_point_in_time`.
+    pub fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_to_point_in_time(input))
+    }
+
+    /// <p><p>Creates a new DB instance from a DB snapshot. The target database is created from the source database restore point with the most of original configuration with the default security group and the default DB parameter group. By default, the new DB instance is created as a single-AZ deployment except when the instance is a SQL Server instance that has an option group that is associated with mirroring; in this case, the instance becomes a mirrored AZ deployment and not a single-AZ deployment.</p> <p>If your intent is to replace your original DB instance with the new, restored DB instance, then rename your original DB instance before you call the RestoreDBInstanceFromDBSnapshot action. RDS doesn&#39;t allow two DB instances with the same name. Once you have renamed your original DB instance with a different identifier, then you can pass the original name of the DB instance as the DBInstanceIdentifier in the call to the RestoreDBInstanceFromDBSnapshot action. The result is that you will replace the original DB instance with the DB instance created from the snapshot.</p> <p>If you are restoring from a shared manual DB snapshot, the <code>DBSnapshotIdentifier</code> must be the ARN of the shared DB snapshot.</p> <note> <p>This command doesn&#39;t apply to Aurora MySQL and Aurora PostgreSQL. For Aurora, use <code>RestoreDBClusterFromSnapshot</code>.</p> </note></p>
+    ///
+    /// See `Rds::restore_db_instance_from_db_snapshot`.
+    pub fn restore_db_instance_from_db_snapshot(
+        &self,
+        input: RestoreDBInstanceFromDBSnapshotMessage,
+    ) -> Result<
+        RestoreDBInstanceFromDBSnapshotResult,
+        RusotoError<RestoreDBInstanceFromDBSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_db_instance_from_db_snapshot(input))
+    }
+
+    /// <p>Amazon Relational Database Service (Amazon RDS) supports importing MySQL databases by using backup files. You can create a backup of your on-premises database, store it on Amazon Simple Storage Service (Amazon S3), and then restore the backup file onto a new Amazon RDS DB instance running MySQL. For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/MySQL.Procedural.Importing.html">Importing Data into an Amazon RDS MySQL DB Instance</a> in the <i>Amazon RDS User Guide.</i> </p>
+    ///
+    /// See `Rds::restore_db_instance_from_s3`.
+    pub fn restore_db_instance_from_s3(
+        &self,
+        input: RestoreDBInstanceFromS3Message,
+    ) -> Result<RestoreDBInstanceFromS3Result, RusotoError<RestoreDBInstanceFromS3Error>> {
+        self.runtime
+            .block_on(self.client.restore_db_instance_from_s3(input))
+    }
+
+    /// <p><p>Restores a DB instance to an arbitrary point in time. You can restore to any point in time before the time identified by the LatestRestorableTime property. You can restore to a point up to the number of days specified by the BackupRetentionPeriod property.</p> <p>The target database is created with most of the original configuration, but in a system-selected Availability Zone, with the default security group, the default subnet group, and the default DB parameter group. By default, the new DB instance is created as a single-AZ deployment except when the instance is a SQL Server instance that has an option group that is associated with mirroring; in this case, the instance becomes a mirrored deployment and not a single-AZ deployment.</p> <note> <p>This command doesn&#39;t apply to Aurora MySQL and Aurora PostgreSQL. For Aurora, use <code>RestoreDBClusterToPointInTime</code>.</p> </note></p>
+    ///
+    /// See `Rds::restore_db_instance_to_point_in_time`.
+    pub fn restore_db_instance_to_point_in_time(
+        &self,
+        input: RestoreDBInstanceToPointInTimeMessage,
+    ) -> Result<
+        RestoreDBInstanceToPointInTimeResult,
+        RusotoError<RestoreDBInstanceToPointInTimeError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_db_instance_to_point_in_time(input))
+    }
+
+    /// <p>Revokes ingress from a DBSecurityGroup for previously authorized IP ranges or EC2 or VPC Security Groups. Required parameters for this API are one of CIDRIP, EC2SecurityGroupId for VPC, or (EC2SecurityGroupOwnerId and either EC2SecurityGroupName or EC2SecurityGroupId).</p>
+    ///
+    /// See `Rds::revoke_db_security_group_ingress`.
+    pub fn revoke_db_security_group_ingress(
+        &self,
+        input: RevokeDBSecurityGroupIngressMessage,
+    ) -> Result<RevokeDBSecurityGroupIngressResult, RusotoError<RevokeDBSecurityGroupIngressError>>
+    {
+        self.runtime
+            .block_on(self.client.revoke_db_security_group_ingress(input))
+    }
+
+    /// <p>Starts a database activity stream to monitor activity on the database. For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/DBActivityStreams.html">Database Activity Streams</a> in the <i>Amazon Aurora User Guide</i>.</p>
+    ///
+    /// See `Rds::start_activity_stream`.
+    pub fn start_activity_stream(
+        &self,
+        input: StartActivityStreamRequest,
+    ) -> Result<StartActivityStreamResponse, RusotoError<StartActivityStreamError>> {
+        self.runtime
+            .block_on(self.client.start_activity_stream(input))
+    }
+
+    /// <p><p>Starts an Amazon Aurora DB cluster that was stopped using the AWS console, the stop-db-cluster AWS CLI command, or the StopDBCluster action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/aurora-cluster-stop-start.html"> Stopping and Starting an Aurora Cluster</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::start_db_cluster`.
+    pub fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.runtime.block_on(self.client.start_db_cluster(input))
+    }
+
+    /// <p><p> Starts an Amazon RDS DB instance that was stopped using the AWS console, the stop-db-instance AWS CLI command, or the StopDBInstance action. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_StartInstance.html"> Starting an Amazon RDS DB instance That Was Previously Stopped</a> in the <i>Amazon RDS User Guide.</i> </p> <note> <p> This command doesn&#39;t apply to Aurora MySQL and Aurora PostgreSQL. For Aurora DB clusters, use <code>StartDBCluster</code> instead. </p> </note></p>
+    ///
+    /// See `Rds::start_db_instance`.
+    pub fn start_db_instance(
+        &self,
+        input: StartDBInstanceMessage,
+    ) -> Result<StartDBInstanceResult, RusotoError<StartDBInstanceError>> {
+        self.runtime.block_on(self.client.start_db_instance(input))
+    }
+
+    /// <p>Starts an export of a snapshot to Amazon S3. The provided IAM role must have access to the S3 bucket. </p>
+    ///
+    /// See `Rds::start_export_task`.
+    pub fn start_export_task(
+        &self,
+        input: StartExportTaskMessage,
+    ) -> Result<ExportTask, RusotoError<StartExportTaskError>> {
+        self.runtime.block_on(self.client.start_export_task(input))
+    }
+
+    /// <p>Stops a database activity stream that was started using the AWS console, the <code>start-activity-stream</code> AWS CLI command, or the <code>StartActivityStream</code> action.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/DBActivityStreams.html">Database Activity Streams</a> in the <i>Amazon Aurora User Guide</i>.</p>
+    ///
+    /// See `Rds::stop_activity_stream`.
+    pub fn stop_activity_stream(
+        &self,
+        input: StopActivityStreamRequest,
+    ) -> Result<StopActivityStreamResponse, RusotoError<StopActivityStreamError>> {
+        self.runtime
+            .block_on(self.client.stop_activity_stream(input))
+    }
+
+    /// <p><p> Stops an Amazon Aurora DB cluster. When you stop a DB cluster, Aurora retains the DB cluster&#39;s metadata, including its endpoints and DB parameter groups. Aurora also retains the transaction logs so you can do a point-in-time restore if necessary. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/aurora-cluster-stop-start.html"> Stopping and Starting an Aurora Cluster</a> in the <i>Amazon Aurora User Guide.</i> </p> <note> <p>This action only applies to Aurora DB clusters.</p> </note></p>
+    ///
+    /// See `Rds::stop_db_cluster`.
+    pub fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.runtime.block_on(self.client.stop_db_cluster(input))
+    }
+
+    /// <p><p> Stops an Amazon RDS DB instance. When you stop a DB instance, Amazon RDS retains the DB instance&#39;s metadata, including its endpoint, DB parameter group, and option group membership. Amazon RDS also retains the transaction logs so you can do a point-in-time restore if necessary. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/UserGuide/USER_StopInstance.html"> Stopping an Amazon RDS DB Instance Temporarily</a> in the <i>Amazon RDS User Guide.</i> </p> <note> <p> This command doesn&#39;t apply to Aurora MySQL and Aurora PostgreSQL. For Aurora clusters, use <code>StopDBCluster</code> instead. </p> </note></p>
+    ///
+    /// See `Rds::stop_db_instance`.
+    pub fn stop_db_instance(
+        &self,
+        input: StopDBInstanceMessage,
+    ) -> Result<StopDBInstanceResult, RusotoError<StopDBInstanceError>> {
+        self.runtime.block_on(self.client.stop_db_instance(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
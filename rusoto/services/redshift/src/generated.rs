@@ -11821,49 +11821,56 @@ impl AcceptReservedNodeExchangeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidReservedNodeState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AcceptReservedNodeExchangeError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
@@ -11936,7 +11943,7 @@ impl AuthorizeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"AuthorizationQuotaExceeded" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::service(&res, AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"AuthorizationQuotaExceeded" => return RusotoError::service(&res, AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::service(&res, AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::service(&res, AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -11997,42 +12004,48 @@ impl AuthorizeSnapshotAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AuthorizeSnapshotAccessError::LimitExceededFault(parsed_error.message),
                         )
                     }
@@ -12093,7 +12106,8 @@ impl BatchDeleteClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BatchDeleteRequestSizeExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(
                                 parsed_error.message,
                             ),
@@ -12144,7 +12158,7 @@ impl BatchModifyClusterSnapshotsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(parsed_error.message)),"InvalidRetentionPeriodFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(parsed_error.message)),_ => {}
+                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::service(&res, BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(parsed_error.message)),"InvalidRetentionPeriodFault" => return RusotoError::service(&res, BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -12195,24 +12209,28 @@ impl CancelResizeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(CancelResizeError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelResizeError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(CancelResizeError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelResizeError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     "ResizeNotFound" => {
-                        return RusotoError::Service(CancelResizeError::ResizeNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelResizeError::ResizeNotFoundFault(parsed_error.message),
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(CancelResizeError::UnsupportedOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CancelResizeError::UnsupportedOperationFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12265,35 +12283,40 @@ impl CopyClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CopyClusterSnapshotError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
@@ -12395,132 +12418,154 @@ impl CreateClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::ClusterAlreadyExistsFault(parsed_error.message),
+                        )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterQuotaExceeded" => {
-                        return RusotoError::Service(CreateClusterError::ClusterQuotaExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::ClusterQuotaExceededFault(parsed_error.message),
+                        )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::InvalidClusterSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(CreateClusterError::InvalidClusterTrackFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::InvalidClusterTrackFault(parsed_error.message),
+                        )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(CreateClusterError::InvalidElasticIpFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::InvalidElasticIpFault(parsed_error.message),
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::InvalidRetentionPeriodFault(parsed_error.message),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateClusterError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateClusterError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(CreateClusterError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::NumberOfNodesPerClusterLimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterError::SnapshotScheduleNotFoundFault(parsed_error.message),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateClusterError::TagLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::TagLimitExceededFault(parsed_error.message),
+                        )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(CreateClusterError::UnauthorizedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterError::UnauthorizedOperation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -12608,23 +12653,27 @@ impl CreateClusterParameterGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ClusterParameterGroupAlreadyExists" => return RusotoError::Service(
+                    "ClusterParameterGroupAlreadyExists" => return RusotoError::service(
+                        &res,
                         CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault(
                             parsed_error.message,
                         ),
                     ),
-                    "ClusterParameterGroupQuotaExceeded" => return RusotoError::Service(
+                    "ClusterParameterGroupQuotaExceeded" => return RusotoError::service(
+                        &res,
                         CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterParameterGroupError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterParameterGroupError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -12687,26 +12736,30 @@ impl CreateClusterSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "QuotaExceeded.ClusterSecurityGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSecurityGroupError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSecurityGroupError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -12773,45 +12826,52 @@ impl CreateClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSnapshotError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSnapshotError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSnapshotError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateClusterSnapshotError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterSnapshotError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSnapshotError::TagLimitExceededFault(parsed_error.message),
                         )
                     }
@@ -12883,52 +12943,60 @@ impl CreateClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateClusterSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateClusterSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateClusterSubnetGroupError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
@@ -13013,73 +13081,84 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateEventSubscriptionError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateEventSubscriptionError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateEventSubscriptionError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -13159,26 +13238,30 @@ impl CreateHsmClientCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmClientCertificateQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmClientCertificateError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmClientCertificateError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -13239,26 +13322,30 @@ impl CreateHsmConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmConfigurationQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateHsmConfigurationError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHsmConfigurationError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHsmConfigurationError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -13321,40 +13408,46 @@ impl CreateScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateScheduledActionError::InvalidScheduleFault(parsed_error.message),
                         )
                     }
                     "InvalidScheduledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateScheduledActionError::InvalidScheduledActionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ScheduledActionAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateScheduledActionError::ScheduledActionAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ScheduledActionQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateScheduledActionError::ScheduledActionQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateScheduledActionError::ScheduledActionTypeUnsupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateScheduledActionError::UnauthorizedOperation(parsed_error.message),
                         )
                     }
@@ -13421,38 +13514,44 @@ impl CreateSnapshotCopyGrantError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateSnapshotCopyGrantError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateSnapshotCopyGrantError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotCopyGrantError::LimitExceededFault(parsed_error.message),
                         )
                     }
                     "SnapshotCopyGrantAlreadyExistsFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotCopyGrantQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotCopyGrantError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -13519,33 +13618,38 @@ impl CreateSnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
                         )
                     }
                     "ScheduleDefinitionTypeUnsupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotScheduleAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotScheduleQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateSnapshotScheduleError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
@@ -13605,19 +13709,22 @@ impl CreateTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateTagsError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTagsError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(CreateTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTagsError::ResourceNotFoundFault(parsed_error.message),
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateTagsError::TagLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTagsError::TagLimitExceededFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -13673,37 +13780,44 @@ impl CreateUsageLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(CreateUsageLimitError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUsageLimitError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateUsageLimitError::InvalidClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidUsageLimit" => {
-                        return RusotoError::Service(CreateUsageLimitError::InvalidUsageLimitFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUsageLimitError::InvalidUsageLimitFault(parsed_error.message),
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(CreateUsageLimitError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUsageLimitError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateUsageLimitError::TagLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateUsageLimitError::TagLimitExceededFault(parsed_error.message),
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateUsageLimitError::UnsupportedOperationFault(parsed_error.message),
                         )
                     }
                     "UsageLimitAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateUsageLimitError::UsageLimitAlreadyExistsFault(
                                 parsed_error.message,
                             ),
@@ -13765,31 +13879,36 @@ impl DeleteClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteClusterError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterError::ClusterSnapshotAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterError::ClusterSnapshotQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteClusterError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterError::InvalidRetentionPeriodFault(parsed_error.message),
                         )
                     }
@@ -13845,13 +13964,15 @@ impl DeleteClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidClusterParameterGroupState" => return RusotoError::Service(
+                    "InvalidClusterParameterGroupState" => return RusotoError::service(
+                        &res,
                         DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
                             parsed_error.message,
                         ),
@@ -13905,14 +14026,16 @@ impl DeleteClusterSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -13965,14 +14088,16 @@ impl DeleteClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
@@ -14027,21 +14152,24 @@ impl DeleteClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSubnetStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(
                                 parsed_error.message,
                             ),
@@ -14097,14 +14225,16 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSubscriptionStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteEventSubscriptionError::InvalidSubscriptionStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -14159,14 +14289,16 @@ impl DeleteHsmClientCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidHsmClientCertificateStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(
                                 parsed_error.message,
                             ),
@@ -14219,14 +14351,16 @@ impl DeleteHsmConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteHsmConfigurationError::HsmConfigurationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidHsmConfigurationStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(
                                 parsed_error.message,
                             ),
@@ -14279,14 +14413,16 @@ impl DeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteScheduledActionError::ScheduledActionNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteScheduledActionError::UnauthorizedOperation(parsed_error.message),
                         )
                     }
@@ -14335,14 +14471,16 @@ impl DeleteSnapshotCopyGrantError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSnapshotCopyGrantStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -14395,14 +14533,16 @@ impl DeleteSnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterSnapshotScheduleState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -14455,14 +14595,16 @@ impl DeleteTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DeleteTagsError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTagsError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTagsError::ResourceNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -14507,12 +14649,14 @@ impl DeleteUsageLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteUsageLimitError::UnsupportedOperationFault(parsed_error.message),
                         )
                     }
                     "UsageLimitNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteUsageLimitError::UsageLimitNotFoundFault(parsed_error.message),
                         )
                     }
@@ -14595,14 +14739,16 @@ impl DescribeClusterDbRevisionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterDbRevisionsError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterDbRevisionsError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -14657,14 +14803,16 @@ impl DescribeClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterParameterGroupsError::InvalidTagFault(
                                 parsed_error.message,
                             ),
@@ -14715,7 +14863,8 @@ impl DescribeClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -14767,14 +14916,16 @@ impl DescribeClusterSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSecurityGroupsError::InvalidTagFault(
                                 parsed_error.message,
                             ),
@@ -14829,21 +14980,24 @@ impl DescribeClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSnapshotsError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSnapshotsError::InvalidTagFault(parsed_error.message),
                         )
                     }
@@ -14897,14 +15051,16 @@ impl DescribeClusterSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterSubnetGroupsError::InvalidTagFault(parsed_error.message),
                         )
                     }
@@ -14953,14 +15109,16 @@ impl DescribeClusterTracksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterTracksError::InvalidClusterTrackFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeClusterTracksError::UnauthorizedOperation(parsed_error.message),
                         )
                     }
@@ -15043,14 +15201,16 @@ impl DescribeClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeClustersError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DescribeClustersError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeClustersError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15167,12 +15327,14 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEventSubscriptionsError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -15259,14 +15421,16 @@ impl DescribeHsmClientCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeHsmClientCertificatesError::InvalidTagFault(
                                 parsed_error.message,
                             ),
@@ -15319,14 +15483,16 @@ impl DescribeHsmConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeHsmConfigurationsError::InvalidTagFault(parsed_error.message),
                         )
                     }
@@ -15373,7 +15539,8 @@ impl DescribeLoggingStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeLoggingStatusError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
@@ -15425,28 +15592,32 @@ impl DescribeNodeConfigurationOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeNodeConfigurationOptionsError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
@@ -15545,21 +15716,24 @@ impl DescribeReservedNodeOfferingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedNodeOfferingsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
@@ -15615,14 +15789,16 @@ impl DescribeReservedNodesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedNodesError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReservedNodesError::ReservedNodeNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -15675,14 +15851,16 @@ impl DescribeResizeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DescribeResizeError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeResizeError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "ResizeNotFound" => {
-                        return RusotoError::Service(DescribeResizeError::ResizeNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeResizeError::ResizeNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -15727,14 +15905,16 @@ impl DescribeScheduledActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScheduledActionsError::ScheduledActionNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeScheduledActionsError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
@@ -15789,12 +15969,14 @@ impl DescribeSnapshotCopyGrantsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeSnapshotCopyGrantsError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -15915,14 +16097,16 @@ impl DescribeTableRestoreStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTableRestoreStatusError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TableRestoreNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeTableRestoreStatusError::TableRestoreNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -15975,14 +16159,16 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DescribeTagsError::InvalidTagFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::InvalidTagFault(parsed_error.message),
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(DescribeTagsError::ResourceNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeTagsError::ResourceNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16027,12 +16213,14 @@ impl DescribeUsageLimitsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeUsageLimitsError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeUsageLimitsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
@@ -16081,9 +16269,10 @@ impl DisableLoggingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DisableLoggingError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DisableLoggingError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16131,26 +16320,30 @@ impl DisableSnapshotCopyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableSnapshotCopyError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableSnapshotCopyError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotCopyAlreadyDisabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
                         )
                     }
@@ -16209,36 +16402,42 @@ impl EnableLoggingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketNotFoundFault" => {
-                        return RusotoError::Service(EnableLoggingError::BucketNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableLoggingError::BucketNotFoundFault(parsed_error.message),
+                        )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(EnableLoggingError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableLoggingError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InsufficientS3BucketPolicyFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableLoggingError::InsufficientS3BucketPolicyFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableLoggingError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     "InvalidS3BucketNameFault" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidS3BucketNameFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableLoggingError::InvalidS3BucketNameFault(parsed_error.message),
+                        )
                     }
                     "InvalidS3KeyPrefixFault" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidS3KeyPrefixFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableLoggingError::InvalidS3KeyPrefixFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16307,69 +16506,80 @@ impl EnableSnapshotCopyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(EnableSnapshotCopyError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableSnapshotCopyError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "CopyToRegionDisabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::CopyToRegionDisabledFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "IncompatibleOrderableOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::IncompatibleOrderableOptions(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::InvalidClusterStateFault(parsed_error.message),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(EnableSnapshotCopyError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            EnableSnapshotCopyError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "SnapshotCopyAlreadyEnabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
                         )
                     }
                     "UnknownSnapshotCopyRegionFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(
                                 parsed_error.message,
                             ),
@@ -16439,12 +16649,14 @@ impl GetClusterCredentialsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetClusterCredentialsError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetClusterCredentialsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
@@ -16505,40 +16717,46 @@ impl GetReservedNodeExchangeOfferingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidReservedNodeState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "ReservedNodeOfferingNotFound" => return RusotoError::Service(
+                    "ReservedNodeOfferingNotFound" => return RusotoError::service(
+                        &res,
                         GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault(
                             parsed_error.message,
                         ),
                     ),
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
@@ -16637,115 +16855,134 @@ impl ModifyClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(ModifyClusterError::ClusterAlreadyExistsFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::ClusterAlreadyExistsFault(parsed_error.message),
+                        )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ModifyClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::InvalidClusterSecurityGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidClusterTrackFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::InvalidClusterTrackFault(parsed_error.message),
+                        )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidElasticIpFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::InvalidElasticIpFault(parsed_error.message),
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::InvalidRetentionPeriodFault(parsed_error.message),
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(ModifyClusterError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TableLimitExceeded" => {
-                        return RusotoError::Service(ModifyClusterError::TableLimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::TableLimitExceededFault(parsed_error.message),
+                        )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(ModifyClusterError::UnauthorizedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::UnauthorizedOperation(parsed_error.message),
+                        )
                     }
                     "UnsupportedOptionFault" => {
-                        return RusotoError::Service(ModifyClusterError::UnsupportedOptionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterError::UnsupportedOptionFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -16825,21 +17062,24 @@ impl ModifyClusterDbRevisionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterDbRevisionError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterOnLatestRevision" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterDbRevisionError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -16893,12 +17133,14 @@ impl ModifyClusterIamRolesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterIamRolesError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterIamRolesError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -16949,14 +17191,16 @@ impl ModifyClusterMaintenanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterMaintenanceError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterMaintenanceError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -17011,13 +17255,15 @@ impl ModifyClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "InvalidClusterParameterGroupState" => return RusotoError::Service(
+                    "InvalidClusterParameterGroupState" => return RusotoError::service(
+                        &res,
                         ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
                             parsed_error.message,
                         ),
@@ -17071,21 +17317,24 @@ impl ModifyClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSnapshotError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
@@ -17144,7 +17393,7 @@ impl ModifyClusterSnapshotScheduleError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(parsed_error.message)),"InvalidClusterSnapshotScheduleState" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(parsed_error.message)),"SnapshotScheduleNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::service(&res, ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(parsed_error.message)),"InvalidClusterSnapshotScheduleState" => return RusotoError::service(&res, ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(parsed_error.message)),"SnapshotScheduleNotFound" => return RusotoError::service(&res, ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -17202,38 +17451,44 @@ impl ModifyClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyClusterSubnetGroupError::InvalidSubnet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyClusterSubnetGroupError::InvalidSubnet(parsed_error.message),
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyClusterSubnetGroupError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
@@ -17308,61 +17563,70 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSubscriptionStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::InvalidSubscriptionStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSInvalidTopicFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSNoAuthorizationFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -17438,33 +17702,38 @@ impl ModifyScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyScheduledActionError::InvalidScheduleFault(parsed_error.message),
                         )
                     }
                     "InvalidScheduledAction" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyScheduledActionError::InvalidScheduledActionFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyScheduledActionError::ScheduledActionNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyScheduledActionError::UnauthorizedOperation(parsed_error.message),
                         )
                     }
@@ -17528,35 +17797,40 @@ impl ModifySnapshotCopyRetentionPeriodError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotCopyDisabledFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
@@ -17620,19 +17894,22 @@ impl ModifySnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotScheduleUpdateInProgress" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(
                                 parsed_error.message,
                             ),
@@ -17688,17 +17965,20 @@ impl ModifyUsageLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidUsageLimit" => {
-                        return RusotoError::Service(ModifyUsageLimitError::InvalidUsageLimitFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ModifyUsageLimitError::InvalidUsageLimitFault(parsed_error.message),
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyUsageLimitError::UnsupportedOperationFault(parsed_error.message),
                         )
                     }
                     "UsageLimitNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ModifyUsageLimitError::UsageLimitNotFoundFault(parsed_error.message),
                         )
                     }
@@ -17746,14 +18026,16 @@ impl PauseClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(PauseClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PauseClusterError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(PauseClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PauseClusterError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17804,28 +18086,32 @@ impl PurchaseReservedNodeOfferingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReservedNodeAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ReservedNodeQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PurchaseReservedNodeOfferingError::UnsupportedOperationFault(
                                 parsed_error.message,
                             ),
@@ -17884,14 +18170,16 @@ impl RebootClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(RebootClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RebootClusterError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(RebootClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RebootClusterError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -17938,14 +18226,16 @@ impl ResetClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterParameterGroupState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
                                 parsed_error.message,
                             ),
@@ -18012,55 +18302,64 @@ impl ResizeClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ResizeClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResizeClusterError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResizeClusterError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ResizeClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResizeClusterError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(ResizeClusterError::LimitExceededFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResizeClusterError::LimitExceededFault(parsed_error.message),
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ResizeClusterError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(ResizeClusterError::UnauthorizedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResizeClusterError::UnauthorizedOperation(parsed_error.message),
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(ResizeClusterError::UnsupportedOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResizeClusterError::UnsupportedOperationFault(parsed_error.message),
+                        )
                     }
                     "UnsupportedOptionFault" => {
-                        return RusotoError::Service(ResizeClusterError::UnsupportedOptionFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResizeClusterError::UnsupportedOptionFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18166,169 +18465,194 @@ impl RestoreFromClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::ClusterQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidClusterTrackFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidElasticIpFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidRestore" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidRestoreFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidSubnet(parsed_error.message),
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidTagFault(parsed_error.message),
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::LimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::Service(
+                    "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::service(
+                        &res,
                         RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault(
                             parsed_error.message,
                         ),
                     ),
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::TagLimitExceededFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RestoreFromClusterSnapshotError::UnauthorizedOperation(
                                 parsed_error.message,
                             ),
@@ -18457,7 +18781,7 @@ impl RestoreTableFromClusterSnapshotError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(parsed_error.message)),"ClusterSnapshotNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(parsed_error.message)),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(parsed_error.message)),"InvalidClusterSnapshotState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(parsed_error.message)),"InvalidClusterState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(parsed_error.message)),"InvalidTableRestoreArgument" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(parsed_error.message)),"UnsupportedOperation" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(parsed_error.message)),"ClusterSnapshotNotFound" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(parsed_error.message)),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(parsed_error.message)),"InvalidClusterSnapshotState" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(parsed_error.message)),"InvalidClusterState" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(parsed_error.message)),"InvalidTableRestoreArgument" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(parsed_error.message)),"UnsupportedOperation" => return RusotoError::service(&res, RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -18519,14 +18843,16 @@ impl ResumeClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ResumeClusterError::ClusterNotFoundFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResumeClusterError::ClusterNotFoundFault(parsed_error.message),
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ResumeClusterError::InvalidClusterStateFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ResumeClusterError::InvalidClusterStateFault(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -18574,7 +18900,7 @@ impl RevokeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationNotFound" => return RusotoError::service(&res, RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::service(&res, RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::service(&res, RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -18626,21 +18952,24 @@ impl RevokeSnapshotAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeSnapshotAccessError::AccessToSnapshotDeniedFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeSnapshotAccessError::AuthorizationNotFoundFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(
                                 parsed_error.message,
                             ),
@@ -18698,19 +19027,22 @@ impl RotateEncryptionKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RotateEncryptionKeyError::ClusterNotFoundFault(parsed_error.message),
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RotateEncryptionKeyError::DependentServiceRequestThrottlingFault(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             RotateEncryptionKeyError::InvalidClusterStateFault(
                                 parsed_error.message,
                             ),
@@ -19337,6 +19669,7 @@ impl RedshiftClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -19352,6 +19685,7 @@ impl RedshiftClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
         RedshiftClient { client, region }
     }
@@ -19366,6 +19700,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("AcceptReservedNodeExchange");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AcceptReservedNodeExchange");
         let mut params = params;
         AcceptReservedNodeExchangeInputMessageSerializer::serialize(&mut params, "", &input);
@@ -19402,6 +19739,9 @@ impl Redshift for RedshiftClient {
         RusotoError<AuthorizeClusterSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("AuthorizeClusterSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AuthorizeClusterSecurityGroupIngress");
         let mut params = params;
         AuthorizeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -19438,6 +19778,9 @@ impl Redshift for RedshiftClient {
         input: AuthorizeSnapshotAccessMessage,
     ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("AuthorizeSnapshotAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AuthorizeSnapshotAccess");
         let mut params = params;
         AuthorizeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
@@ -19472,6 +19815,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("BatchDeleteClusterSnapshots");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchDeleteClusterSnapshots");
         let mut params = params;
         BatchDeleteClusterSnapshotsRequestSerializer::serialize(&mut params, "", &input);
@@ -19508,6 +19854,9 @@ impl Redshift for RedshiftClient {
         RusotoError<BatchModifyClusterSnapshotsError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("BatchModifyClusterSnapshots");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchModifyClusterSnapshots");
         let mut params = params;
         BatchModifyClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -19541,6 +19890,9 @@ impl Redshift for RedshiftClient {
         input: CancelResizeMessage,
     ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CancelResize");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CancelResize");
         let mut params = params;
         CancelResizeMessageSerializer::serialize(&mut params, "", &input);
@@ -19572,6 +19924,9 @@ impl Redshift for RedshiftClient {
         input: CopyClusterSnapshotMessage,
     ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CopyClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CopyClusterSnapshot");
         let mut params = params;
         CopyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -19605,6 +19960,9 @@ impl Redshift for RedshiftClient {
         input: CreateClusterMessage,
     ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCluster");
         let mut params = params;
         CreateClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -19637,6 +19995,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateClusterParameterGroup");
         let mut params = params;
         CreateClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -19671,6 +20032,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateClusterSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateClusterSecurityGroup");
         let mut params = params;
         CreateClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -19704,6 +20068,9 @@ impl Redshift for RedshiftClient {
         input: CreateClusterSnapshotMessage,
     ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateClusterSnapshot");
         let mut params = params;
         CreateClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -19737,6 +20104,9 @@ impl Redshift for RedshiftClient {
         input: CreateClusterSubnetGroupMessage,
     ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateClusterSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateClusterSubnetGroup");
         let mut params = params;
         CreateClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -19770,6 +20140,9 @@ impl Redshift for RedshiftClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateEventSubscription");
         let mut params = params;
         CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -19804,6 +20177,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateHsmClientCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateHsmClientCertificate");
         let mut params = params;
         CreateHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
@@ -19837,6 +20213,9 @@ impl Redshift for RedshiftClient {
         input: CreateHsmConfigurationMessage,
     ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateHsmConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateHsmConfiguration");
         let mut params = params;
         CreateHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
@@ -19870,6 +20249,9 @@ impl Redshift for RedshiftClient {
         input: CreateScheduledActionMessage,
     ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateScheduledAction");
         let mut params = params;
         CreateScheduledActionMessageSerializer::serialize(&mut params, "", &input);
@@ -19901,6 +20283,9 @@ impl Redshift for RedshiftClient {
         input: CreateSnapshotCopyGrantMessage,
     ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateSnapshotCopyGrant");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSnapshotCopyGrant");
         let mut params = params;
         CreateSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
@@ -19934,6 +20319,9 @@ impl Redshift for RedshiftClient {
         input: CreateSnapshotScheduleMessage,
     ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateSnapshotSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateSnapshotSchedule");
         let mut params = params;
         CreateSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -19965,6 +20353,9 @@ impl Redshift for RedshiftClient {
         input: CreateTagsMessage,
     ) -> Result<(), RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTags");
         let mut params = params;
         CreateTagsMessageSerializer::serialize(&mut params, "", &input);
@@ -19985,6 +20376,9 @@ impl Redshift for RedshiftClient {
         input: CreateUsageLimitMessage,
     ) -> Result<UsageLimit, RusotoError<CreateUsageLimitError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("CreateUsageLimit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateUsageLimit");
         let mut params = params;
         CreateUsageLimitMessageSerializer::serialize(&mut params, "", &input);
@@ -20015,6 +20409,9 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterMessage,
     ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCluster");
         let mut params = params;
         DeleteClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -20046,6 +20443,9 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteClusterParameterGroup");
         let mut params = params;
         DeleteClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -20066,6 +20466,9 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteClusterSecurityGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteClusterSecurityGroup");
         let mut params = params;
         DeleteClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -20086,6 +20489,9 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSnapshotMessage,
     ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteClusterSnapshot");
         let mut params = params;
         DeleteClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -20119,6 +20525,9 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteClusterSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteClusterSubnetGroup");
         let mut params = params;
         DeleteClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -20139,6 +20548,9 @@ impl Redshift for RedshiftClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteEventSubscription");
         let mut params = params;
         DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -20159,6 +20571,9 @@ impl Redshift for RedshiftClient {
         input: DeleteHsmClientCertificateMessage,
     ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteHsmClientCertificate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteHsmClientCertificate");
         let mut params = params;
         DeleteHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
@@ -20179,6 +20594,9 @@ impl Redshift for RedshiftClient {
         input: DeleteHsmConfigurationMessage,
     ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteHsmConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteHsmConfiguration");
         let mut params = params;
         DeleteHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
@@ -20199,6 +20617,9 @@ impl Redshift for RedshiftClient {
         input: DeleteScheduledActionMessage,
     ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteScheduledAction");
         let mut params = params;
         DeleteScheduledActionMessageSerializer::serialize(&mut params, "", &input);
@@ -20219,6 +20640,9 @@ impl Redshift for RedshiftClient {
         input: DeleteSnapshotCopyGrantMessage,
     ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteSnapshotCopyGrant");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSnapshotCopyGrant");
         let mut params = params;
         DeleteSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
@@ -20239,6 +20663,9 @@ impl Redshift for RedshiftClient {
         input: DeleteSnapshotScheduleMessage,
     ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteSnapshotSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteSnapshotSchedule");
         let mut params = params;
         DeleteSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -20259,6 +20686,9 @@ impl Redshift for RedshiftClient {
         input: DeleteTagsMessage,
     ) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTags");
         let mut params = params;
         DeleteTagsMessageSerializer::serialize(&mut params, "", &input);
@@ -20279,6 +20709,9 @@ impl Redshift for RedshiftClient {
         input: DeleteUsageLimitMessage,
     ) -> Result<(), RusotoError<DeleteUsageLimitError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DeleteUsageLimit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteUsageLimit");
         let mut params = params;
         DeleteUsageLimitMessageSerializer::serialize(&mut params, "", &input);
@@ -20299,6 +20732,9 @@ impl Redshift for RedshiftClient {
         input: DescribeAccountAttributesMessage,
     ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeAccountAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeAccountAttributes");
         let mut params = params;
         DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -20332,6 +20768,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterDbRevisionsMessage,
     ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterDbRevisions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterDbRevisions");
         let mut params = params;
         DescribeClusterDbRevisionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20366,6 +20805,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterParameterGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterParameterGroups");
         let mut params = params;
         DescribeClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -20399,6 +20841,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterParametersMessage,
     ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterParameters");
         let mut params = params;
         DescribeClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -20432,6 +20877,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSecurityGroupsMessage,
     ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterSecurityGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterSecurityGroups");
         let mut params = params;
         DescribeClusterSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -20465,6 +20913,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSnapshotsMessage,
     ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterSnapshots");
         let mut params = params;
         DescribeClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -20496,6 +20947,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSubnetGroupsMessage,
     ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterSubnetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterSubnetGroups");
         let mut params = params;
         DescribeClusterSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -20529,6 +20983,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterTracksMessage,
     ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterTracks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterTracks");
         let mut params = params;
         DescribeClusterTracksMessageSerializer::serialize(&mut params, "", &input);
@@ -20560,6 +21017,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterVersionsMessage,
     ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusterVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusterVersions");
         let mut params = params;
         DescribeClusterVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20593,6 +21053,9 @@ impl Redshift for RedshiftClient {
         input: DescribeClustersMessage,
     ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeClusters");
         let mut params = params;
         DescribeClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -20626,6 +21089,9 @@ impl Redshift for RedshiftClient {
         RusotoError<DescribeDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeDefaultClusterParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeDefaultClusterParameters");
         let mut params = params;
         DescribeDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -20662,6 +21128,9 @@ impl Redshift for RedshiftClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeEventCategories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -20695,6 +21164,9 @@ impl Redshift for RedshiftClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeEventSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEventSubscriptions");
         let mut params = params;
         DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20728,6 +21200,9 @@ impl Redshift for RedshiftClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeEvents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -20758,6 +21233,9 @@ impl Redshift for RedshiftClient {
         input: DescribeHsmClientCertificatesMessage,
     ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeHsmClientCertificates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeHsmClientCertificates");
         let mut params = params;
         DescribeHsmClientCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -20791,6 +21269,9 @@ impl Redshift for RedshiftClient {
         input: DescribeHsmConfigurationsMessage,
     ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeHsmConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeHsmConfigurations");
         let mut params = params;
         DescribeHsmConfigurationsMessageSerializer::serialize(&mut params, "", &input);
@@ -20824,6 +21305,9 @@ impl Redshift for RedshiftClient {
         input: DescribeLoggingStatusMessage,
     ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeLoggingStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeLoggingStatus");
         let mut params = params;
         DescribeLoggingStatusMessageSerializer::serialize(&mut params, "", &input);
@@ -20856,6 +21340,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeNodeConfigurationOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeNodeConfigurationOptions");
         let mut params = params;
         DescribeNodeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20893,6 +21380,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeOrderableClusterOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeOrderableClusterOptions");
         let mut params = params;
         DescribeOrderableClusterOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20926,6 +21416,9 @@ impl Redshift for RedshiftClient {
         input: DescribeReservedNodeOfferingsMessage,
     ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeReservedNodeOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedNodeOfferings");
         let mut params = params;
         DescribeReservedNodeOfferingsMessageSerializer::serialize(&mut params, "", &input);
@@ -20959,6 +21452,9 @@ impl Redshift for RedshiftClient {
         input: DescribeReservedNodesMessage,
     ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeReservedNodes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReservedNodes");
         let mut params = params;
         DescribeReservedNodesMessageSerializer::serialize(&mut params, "", &input);
@@ -20992,6 +21488,9 @@ impl Redshift for RedshiftClient {
         input: DescribeResizeMessage,
     ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeResize");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeResize");
         let mut params = params;
         DescribeResizeMessageSerializer::serialize(&mut params, "", &input);
@@ -21023,6 +21522,9 @@ impl Redshift for RedshiftClient {
         input: DescribeScheduledActionsMessage,
     ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeScheduledActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeScheduledActions");
         let mut params = params;
         DescribeScheduledActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -21056,6 +21558,9 @@ impl Redshift for RedshiftClient {
         input: DescribeSnapshotCopyGrantsMessage,
     ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeSnapshotCopyGrants");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSnapshotCopyGrants");
         let mut params = params;
         DescribeSnapshotCopyGrantsMessageSerializer::serialize(&mut params, "", &input);
@@ -21090,6 +21595,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeSnapshotSchedules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeSnapshotSchedules");
         let mut params = params;
         DescribeSnapshotSchedulesMessageSerializer::serialize(&mut params, "", &input);
@@ -21122,6 +21630,9 @@ impl Redshift for RedshiftClient {
         &self,
     ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeStorage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeStorage");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -21152,6 +21663,9 @@ impl Redshift for RedshiftClient {
         input: DescribeTableRestoreStatusMessage,
     ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeTableRestoreStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTableRestoreStatus");
         let mut params = params;
         DescribeTableRestoreStatusMessageSerializer::serialize(&mut params, "", &input);
@@ -21185,6 +21699,9 @@ impl Redshift for RedshiftClient {
         input: DescribeTagsMessage,
     ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsMessageSerializer::serialize(&mut params, "", &input);
@@ -21216,6 +21733,9 @@ impl Redshift for RedshiftClient {
         input: DescribeUsageLimitsMessage,
     ) -> Result<UsageLimitList, RusotoError<DescribeUsageLimitsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DescribeUsageLimits");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeUsageLimits");
         let mut params = params;
         DescribeUsageLimitsMessageSerializer::serialize(&mut params, "", &input);
@@ -21247,6 +21767,9 @@ impl Redshift for RedshiftClient {
         input: DisableLoggingMessage,
     ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DisableLogging");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableLogging");
         let mut params = params;
         DisableLoggingMessageSerializer::serialize(&mut params, "", &input);
@@ -21277,6 +21800,9 @@ impl Redshift for RedshiftClient {
         input: DisableSnapshotCopyMessage,
     ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("DisableSnapshotCopy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DisableSnapshotCopy");
         let mut params = params;
         DisableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
@@ -21310,6 +21836,9 @@ impl Redshift for RedshiftClient {
         input: EnableLoggingMessage,
     ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("EnableLogging");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableLogging");
         let mut params = params;
         EnableLoggingMessageSerializer::serialize(&mut params, "", &input);
@@ -21340,6 +21869,9 @@ impl Redshift for RedshiftClient {
         input: EnableSnapshotCopyMessage,
     ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("EnableSnapshotCopy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("EnableSnapshotCopy");
         let mut params = params;
         EnableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
@@ -21373,6 +21905,9 @@ impl Redshift for RedshiftClient {
         input: GetClusterCredentialsMessage,
     ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("GetClusterCredentials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetClusterCredentials");
         let mut params = params;
         GetClusterCredentialsMessageSerializer::serialize(&mut params, "", &input);
@@ -21407,6 +21942,9 @@ impl Redshift for RedshiftClient {
         RusotoError<GetReservedNodeExchangeOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("GetReservedNodeExchangeOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetReservedNodeExchangeOfferings");
         let mut params = params;
         GetReservedNodeExchangeOfferingsInputMessageSerializer::serialize(&mut params, "", &input);
@@ -21443,6 +21981,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterMessage,
     ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyCluster");
         let mut params = params;
         ModifyClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -21474,6 +22015,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterDbRevisionMessage,
     ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterDbRevision");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterDbRevision");
         let mut params = params;
         ModifyClusterDbRevisionMessageSerializer::serialize(&mut params, "", &input);
@@ -21507,6 +22051,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterIamRolesMessage,
     ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterIamRoles");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterIamRoles");
         let mut params = params;
         ModifyClusterIamRolesMessageSerializer::serialize(&mut params, "", &input);
@@ -21540,6 +22087,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterMaintenanceMessage,
     ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterMaintenance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterMaintenance");
         let mut params = params;
         ModifyClusterMaintenanceMessageSerializer::serialize(&mut params, "", &input);
@@ -21574,6 +22124,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterParameterGroup");
         let mut params = params;
         ModifyClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -21607,6 +22160,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSnapshotMessage,
     ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterSnapshot");
         let mut params = params;
         ModifyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -21640,6 +22196,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSnapshotScheduleMessage,
     ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterSnapshotSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterSnapshotSchedule");
         let mut params = params;
         ModifyClusterSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -21660,6 +22219,9 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSubnetGroupMessage,
     ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyClusterSubnetGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyClusterSubnetGroup");
         let mut params = params;
         ModifyClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -21693,6 +22255,9 @@ impl Redshift for RedshiftClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyEventSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyEventSubscription");
         let mut params = params;
         ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -21726,6 +22291,9 @@ impl Redshift for RedshiftClient {
         input: ModifyScheduledActionMessage,
     ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyScheduledAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyScheduledAction");
         let mut params = params;
         ModifyScheduledActionMessageSerializer::serialize(&mut params, "", &input);
@@ -21760,6 +22328,9 @@ impl Redshift for RedshiftClient {
         RusotoError<ModifySnapshotCopyRetentionPeriodError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifySnapshotCopyRetentionPeriod");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifySnapshotCopyRetentionPeriod");
         let mut params = params;
         ModifySnapshotCopyRetentionPeriodMessageSerializer::serialize(&mut params, "", &input);
@@ -21796,6 +22367,9 @@ impl Redshift for RedshiftClient {
         input: ModifySnapshotScheduleMessage,
     ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifySnapshotSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifySnapshotSchedule");
         let mut params = params;
         ModifySnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -21827,6 +22401,9 @@ impl Redshift for RedshiftClient {
         input: ModifyUsageLimitMessage,
     ) -> Result<UsageLimit, RusotoError<ModifyUsageLimitError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ModifyUsageLimit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ModifyUsageLimit");
         let mut params = params;
         ModifyUsageLimitMessageSerializer::serialize(&mut params, "", &input);
@@ -21857,6 +22434,9 @@ impl Redshift for RedshiftClient {
         input: PauseClusterMessage,
     ) -> Result<PauseClusterResult, RusotoError<PauseClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("PauseCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PauseCluster");
         let mut params = params;
         PauseClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -21888,6 +22468,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("PurchaseReservedNodeOffering");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PurchaseReservedNodeOffering");
         let mut params = params;
         PurchaseReservedNodeOfferingMessageSerializer::serialize(&mut params, "", &input);
@@ -21921,6 +22504,9 @@ impl Redshift for RedshiftClient {
         input: RebootClusterMessage,
     ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("RebootCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RebootCluster");
         let mut params = params;
         RebootClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -21953,6 +22539,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ResetClusterParameterGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResetClusterParameterGroup");
         let mut params = params;
         ResetClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -21986,6 +22575,9 @@ impl Redshift for RedshiftClient {
         input: ResizeClusterMessage,
     ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ResizeCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResizeCluster");
         let mut params = params;
         ResizeClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -22018,6 +22610,9 @@ impl Redshift for RedshiftClient {
     ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("RestoreFromClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreFromClusterSnapshot");
         let mut params = params;
         RestoreFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -22054,6 +22649,9 @@ impl Redshift for RedshiftClient {
         RusotoError<RestoreTableFromClusterSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("RestoreTableFromClusterSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RestoreTableFromClusterSnapshot");
         let mut params = params;
         RestoreTableFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -22087,6 +22685,9 @@ impl Redshift for RedshiftClient {
         input: ResumeClusterMessage,
     ) -> Result<ResumeClusterResult, RusotoError<ResumeClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("ResumeCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ResumeCluster");
         let mut params = params;
         ResumeClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -22121,6 +22722,9 @@ impl Redshift for RedshiftClient {
         RusotoError<RevokeClusterSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("RevokeClusterSecurityGroupIngress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeClusterSecurityGroupIngress");
         let mut params = params;
         RevokeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -22157,6 +22761,9 @@ impl Redshift for RedshiftClient {
         input: RevokeSnapshotAccessMessage,
     ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("RevokeSnapshotAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RevokeSnapshotAccess");
         let mut params = params;
         RevokeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
@@ -22190,6 +22797,9 @@ impl Redshift for RedshiftClient {
         input: RotateEncryptionKeyMessage,
     ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation_name("RotateEncryptionKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RotateEncryptionKey");
         let mut params = params;
         RotateEncryptionKeyMessageSerializer::serialize(&mut params, "", &input);
@@ -22218,6 +22828,1081 @@ impl Redshift for RedshiftClient {
     }
 }
 
+/// A blocking client for the Amazon Redshift API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `RedshiftClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RedshiftBlockingClient {
+    client: RedshiftClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RedshiftBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RedshiftBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        RedshiftBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RedshiftBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RedshiftBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> RedshiftBlockingClient {
+        RedshiftBlockingClient {
+            client: RedshiftClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Exchanges a DC1 Reserved Node for a DC2 Reserved Node with no changes to the configuration (term, payment type, or number of nodes) and no additional costs. </p>
+    ///
+    /// See `Redshift::accept_reserved_node_exchange`.
+    pub fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_reserved_node_exchange(input))
+    }
+
+    /// <p>Adds an inbound (ingress) rule to an Amazon Redshift security group. Depending on whether the application accessing your cluster is running on the Internet or an Amazon EC2 instance, you can authorize inbound access to either a Classless Interdomain Routing (CIDR)/Internet Protocol (IP) range or to an Amazon EC2 security group. You can add as many as 20 ingress rules to an Amazon Redshift security group.</p> <p>If you authorize access to an Amazon EC2 security group, specify <i>EC2SecurityGroupName</i> and <i>EC2SecurityGroupOwnerId</i>. The Amazon EC2 security group and Amazon Redshift cluster must be in the same AWS Region. </p> <p>If you authorize access to a CIDR/IP address range, specify <i>CIDRIP</i>. For an overview of CIDR blocks, see the Wikipedia article on <a href="http://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>. </p> <p>You must also associate the security group with a cluster so that clients running on these IP addresses or the EC2 instance are authorized to connect to the cluster. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Working with Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::authorize_cluster_security_group_ingress`.
+    pub fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeClusterSecurityGroupIngressResult,
+        RusotoError<AuthorizeClusterSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.authorize_cluster_security_group_ingress(input))
+    }
+
+    /// <p>Authorizes the specified AWS customer account to restore the specified snapshot.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::authorize_snapshot_access`.
+    pub fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
+        self.runtime
+            .block_on(self.client.authorize_snapshot_access(input))
+    }
+
+    /// <p>Deletes a set of cluster snapshots.</p>
+    ///
+    /// See `Redshift::batch_delete_cluster_snapshots`.
+    pub fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_delete_cluster_snapshots(input))
+    }
+
+    /// <p>Modifies the settings for a set of cluster snapshots.</p>
+    ///
+    /// See `Redshift::batch_modify_cluster_snapshots`.
+    pub fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> Result<
+        BatchModifyClusterSnapshotsOutputMessage,
+        RusotoError<BatchModifyClusterSnapshotsError>,
+    > {
+        self.runtime
+            .block_on(self.client.batch_modify_cluster_snapshots(input))
+    }
+
+    /// <p>Cancels a resize operation for a cluster.</p>
+    ///
+    /// See `Redshift::cancel_resize`.
+    pub fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
+        self.runtime.block_on(self.client.cancel_resize(input))
+    }
+
+    /// <p>Copies the specified automated cluster snapshot to a new manual cluster snapshot. The source must be an automated snapshot and it must be in the available state.</p> <p>When you delete a cluster, Amazon Redshift deletes any automated snapshots of the cluster. Also, when the retention period of the snapshot expires, Amazon Redshift automatically deletes it. If you want to keep an automated snapshot for a longer period, you can make a manual copy of the snapshot. Manual snapshots are retained until you delete them.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::copy_cluster_snapshot`.
+    pub fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new cluster with the specified parameters.</p> <p>To create a cluster in Virtual Private Cloud (VPC), you must provide a cluster subnet group name. The cluster subnet group identifies the subnets of your VPC that Amazon Redshift uses when creating the cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <p>Creates an Amazon Redshift parameter group.</p> <p>Creating parameter groups is independent of creating clusters. You can associate a cluster with a parameter group when you create the cluster. You can also associate an existing cluster with a parameter group after the cluster is created by using <a>ModifyCluster</a>. </p> <p>Parameters in the parameter group define specific behavior that applies to the databases you create on the cluster. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::create_cluster_parameter_group`.
+    pub fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cluster_parameter_group(input))
+    }
+
+    /// <p>Creates a new Amazon Redshift security group. You use security groups to control access to non-VPC clusters.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::create_cluster_security_group`.
+    pub fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cluster_security_group(input))
+    }
+
+    /// <p>Creates a manual snapshot of the specified cluster. The cluster must be in the <code>available</code> state. </p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::create_cluster_snapshot`.
+    pub fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new Amazon Redshift subnet group. You must provide a list of one or more subnets in your existing Amazon Virtual Private Cloud (Amazon VPC) when creating Amazon Redshift subnet group.</p> <p> For information about subnet groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-cluster-subnet-groups.html">Amazon Redshift Cluster Subnet Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::create_cluster_subnet_group`.
+    pub fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cluster_subnet_group(input))
+    }
+
+    /// <p>Creates an Amazon Redshift event notification subscription. This action requires an ARN (Amazon Resource Name) of an Amazon SNS topic created by either the Amazon Redshift console, the Amazon SNS console, or the Amazon SNS API. To obtain an ARN with Amazon SNS, you must create a topic in Amazon SNS and subscribe to the topic. The ARN is displayed in the SNS console.</p> <p>You can specify the source type, and lists of Amazon Redshift source IDs, event categories, and event severities. Notifications will be sent for all events you want that match those criteria. For example, you can specify source type = cluster, source ID = my-cluster-1 and mycluster2, event categories = Availability, Backup, and severity = ERROR. The subscription will only send notifications for those ERROR events in the Availability and Backup categories for the specified clusters.</p> <p>If you specify both the source type and source IDs, such as source type = cluster and source identifier = my-cluster-1, notifications will be sent for all the cluster events for my-cluster-1. If you specify a source type but do not specify a source identifier, you will receive notice of the events for the objects of that type in your AWS account. If you do not specify either the SourceType nor the SourceIdentifier, you will be notified of events generated from all Amazon Redshift sources belonging to your AWS account. You must specify a source type if you specify a source ID.</p>
+    ///
+    /// See `Redshift::create_event_subscription`.
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// <p>Creates an HSM client certificate that an Amazon Redshift cluster will use to connect to the client's HSM in order to store and retrieve the keys used to encrypt the cluster databases.</p> <p>The command returns a public key, which you must store in the HSM. In addition to creating the HSM certificate, you must create an Amazon Redshift HSM configuration that provides a cluster the information needed to store and use encryption keys in the HSM. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
+    ///
+    /// See `Redshift::create_hsm_client_certificate`.
+    pub fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_hsm_client_certificate(input))
+    }
+
+    /// <p>Creates an HSM configuration that contains the information required by an Amazon Redshift cluster to store and use database encryption keys in a Hardware Security Module (HSM). After creating the HSM configuration, you can specify it as a parameter when creating a cluster. The cluster will then store its encryption keys in the HSM.</p> <p>In addition to creating an HSM configuration, you must also create an HSM client certificate. For more information, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-HSM.html">Hardware Security Modules</a> in the Amazon Redshift Cluster Management Guide.</p>
+    ///
+    /// See `Redshift::create_hsm_configuration`.
+    pub fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_hsm_configuration(input))
+    }
+
+    /// <p>Creates a scheduled action. A scheduled action contains a schedule and an Amazon Redshift API action. For example, you can create a schedule of when to run the <code>ResizeCluster</code> API operation. </p>
+    ///
+    /// See `Redshift::create_scheduled_action`.
+    pub fn create_scheduled_action(
+        &self,
+        input: CreateScheduledActionMessage,
+    ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.create_scheduled_action(input))
+    }
+
+    /// <p>Creates a snapshot copy grant that permits Amazon Redshift to use a customer master key (CMK) from AWS Key Management Service (AWS KMS) to encrypt copied snapshots in a destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    ///
+    /// See `Redshift::create_snapshot_copy_grant`.
+    pub fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
+        self.runtime
+            .block_on(self.client.create_snapshot_copy_grant(input))
+    }
+
+    /// <p>Create a snapshot schedule that can be associated to a cluster and which overrides the default system backup schedule. </p>
+    ///
+    /// See `Redshift::create_snapshot_schedule`.
+    pub fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.create_snapshot_schedule(input))
+    }
+
+    /// <p>Adds tags to a cluster.</p> <p>A resource can have up to 50 tags. If you try to create more than 50 tags for a resource, you will receive an error and the attempt will fail.</p> <p>If you specify a key that already exists for the resource, the value for that key will be updated with the new value.</p>
+    ///
+    /// See `Redshift::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsMessage,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Creates a usage limit for a specified Amazon Redshift feature on a cluster. The usage limit is identified by the returned usage limit identifier.</p>
+    ///
+    /// See `Redshift::create_usage_limit`.
+    pub fn create_usage_limit(
+        &self,
+        input: CreateUsageLimitMessage,
+    ) -> Result<UsageLimit, RusotoError<CreateUsageLimitError>> {
+        self.runtime.block_on(self.client.create_usage_limit(input))
+    }
+
+    /// <p>Deletes a previously provisioned cluster without its final snapshot being created. A successful response from the web service indicates that the request was received correctly. Use <a>DescribeClusters</a> to monitor the status of the deletion. The delete operation cannot be canceled or reverted once submitted. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you want to shut down the cluster and retain it for future use, set <i>SkipFinalClusterSnapshot</i> to <code>false</code> and specify a name for <i>FinalClusterSnapshotIdentifier</i>. You can later restore this snapshot to resume using the cluster. If a final cluster snapshot is requested, the status of the cluster will be "final-snapshot" while the snapshot is being taken, then it's "deleting" once Amazon Redshift begins deleting the cluster. </p> <p> For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::delete_cluster`.
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// <p><p>Deletes a specified Amazon Redshift parameter group.</p> <note> <p>You cannot delete a parameter group if it is associated with a cluster.</p> </note></p>
+    ///
+    /// See `Redshift::delete_cluster_parameter_group`.
+    pub fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_parameter_group(input))
+    }
+
+    /// <p>Deletes an Amazon Redshift security group.</p> <note> <p>You cannot delete a security group that is associated with any clusters. You cannot delete the default security group.</p> </note> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::delete_cluster_security_group`.
+    pub fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_security_group(input))
+    }
+
+    /// <p>Deletes the specified manual snapshot. The snapshot must be in the <code>available</code> state, with no other users authorized to access the snapshot. </p> <p>Unlike automated snapshots, manual snapshots are retained even after you delete your cluster. Amazon Redshift does not delete your manual snapshots. You must delete manual snapshot explicitly to avoid getting charged. If other accounts are authorized to access the snapshot, you must revoke all of the authorizations before you can delete the snapshot.</p>
+    ///
+    /// See `Redshift::delete_cluster_snapshot`.
+    pub fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_snapshot(input))
+    }
+
+    /// <p>Deletes the specified cluster subnet group.</p>
+    ///
+    /// See `Redshift::delete_cluster_subnet_group`.
+    pub fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_subnet_group(input))
+    }
+
+    /// <p>Deletes an Amazon Redshift event notification subscription.</p>
+    ///
+    /// See `Redshift::delete_event_subscription`.
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// <p>Deletes the specified HSM client certificate.</p>
+    ///
+    /// See `Redshift::delete_hsm_client_certificate`.
+    pub fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_hsm_client_certificate(input))
+    }
+
+    /// <p>Deletes the specified Amazon Redshift HSM configuration.</p>
+    ///
+    /// See `Redshift::delete_hsm_configuration`.
+    pub fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_hsm_configuration(input))
+    }
+
+    /// <p>Deletes a scheduled action. </p>
+    ///
+    /// See `Redshift::delete_scheduled_action`.
+    pub fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionMessage,
+    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_action(input))
+    }
+
+    /// <p>Deletes the specified snapshot copy grant.</p>
+    ///
+    /// See `Redshift::delete_snapshot_copy_grant`.
+    pub fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
+        self.runtime
+            .block_on(self.client.delete_snapshot_copy_grant(input))
+    }
+
+    /// <p>Deletes a snapshot schedule.</p>
+    ///
+    /// See `Redshift::delete_snapshot_schedule`.
+    pub fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.delete_snapshot_schedule(input))
+    }
+
+    /// <p>Deletes tags from a resource. You must provide the ARN of the resource from which you want to delete the tag or tags.</p>
+    ///
+    /// See `Redshift::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsMessage,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Deletes a usage limit from a cluster.</p>
+    ///
+    /// See `Redshift::delete_usage_limit`.
+    pub fn delete_usage_limit(
+        &self,
+        input: DeleteUsageLimitMessage,
+    ) -> Result<(), RusotoError<DeleteUsageLimitError>> {
+        self.runtime.block_on(self.client.delete_usage_limit(input))
+    }
+
+    /// <p>Returns a list of attributes attached to an account</p>
+    ///
+    /// See `Redshift::describe_account_attributes`.
+    pub fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes(input))
+    }
+
+    /// <p>Returns an array of <code>ClusterDbRevision</code> objects.</p>
+    ///
+    /// See `Redshift::describe_cluster_db_revisions`.
+    pub fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_db_revisions(input))
+    }
+
+    /// <p>Returns a list of Amazon Redshift parameter groups, including parameter groups you created and the default parameter group. For each parameter group, the response includes the parameter group name, description, and parameter group family name. You can optionally specify a name to retrieve the description of a specific parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all parameter groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all parameter groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, parameter groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_cluster_parameter_groups`.
+    pub fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_cluster_parameter_groups(input))
+    }
+
+    /// <p>Returns a detailed list of parameters contained within the specified Amazon Redshift parameter group. For each parameter the response includes information such as parameter name, description, data type, value, whether the parameter value is modifiable, and so on.</p> <p>You can specify <i>source</i> filter to retrieve parameters of only specific type. For example, to retrieve parameters that were modified by a user action such as from <a>ModifyClusterParameterGroup</a>, you can specify <i>source</i> equal to <i>user</i>.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::describe_cluster_parameters`.
+    pub fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_parameters(input))
+    }
+
+    /// <p>Returns information about Amazon Redshift security groups. If the name of a security group is specified, the response will contain only information about only that security group.</p> <p> For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all security groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all security groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, security groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_cluster_security_groups`.
+    pub fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_security_groups(input))
+    }
+
+    /// <p>Returns one or more snapshot objects, which contain metadata about your cluster snapshots. By default, this operation returns information about all snapshots of all clusters that are owned by you AWS customer account. No information is returned for snapshots owned by inactive AWS customer accounts.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all snapshots that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all snapshots that have any combination of those values are returned. Only snapshots that you own are returned in the response; shared snapshots are not returned with the tag key and tag value request parameters.</p> <p>If both tag keys and values are omitted from the request, snapshots are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_cluster_snapshots`.
+    pub fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_snapshots(input))
+    }
+
+    /// <p>Returns one or more cluster subnet group objects, which contain metadata about your cluster subnet groups. By default, this operation returns information about all cluster subnet groups that are defined in you AWS account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all subnet groups that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subnet groups that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subnet groups are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_cluster_subnet_groups`.
+    pub fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_subnet_groups(input))
+    }
+
+    /// <p>Returns a list of all the available maintenance tracks.</p>
+    ///
+    /// See `Redshift::describe_cluster_tracks`.
+    pub fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_tracks(input))
+    }
+
+    /// <p>Returns descriptions of the available Amazon Redshift cluster versions. You can call this operation even before creating any clusters to learn more about the Amazon Redshift versions. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::describe_cluster_versions`.
+    pub fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_versions(input))
+    }
+
+    /// <p>Returns properties of provisioned clusters including general cluster properties, cluster database properties, maintenance and backup properties, and security and access properties. This operation supports pagination. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all clusters that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all clusters that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, clusters are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_clusters`.
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// <p>Returns a list of parameter settings for the specified parameter group family.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::describe_default_cluster_parameters`.
+    pub fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeDefaultClusterParametersResult,
+        RusotoError<DescribeDefaultClusterParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_default_cluster_parameters(input))
+    }
+
+    /// <p>Displays a list of event categories for all event source types, or for a specified source type. For a list of the event categories and source types, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-event-notifications.html">Amazon Redshift Event Notifications</a>.</p>
+    ///
+    /// See `Redshift::describe_event_categories`.
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// <p>Lists descriptions of all the Amazon Redshift event notification subscriptions for a customer account. If you specify a subscription name, lists the description for that subscription.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all event notification subscriptions that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all subscriptions that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, subscriptions are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_event_subscriptions`.
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// <p>Returns events related to clusters, security groups, snapshots, and parameter groups for the past 14 days. Events specific to a particular cluster, security group, snapshot or parameter group can be obtained by providing the name as a parameter. By default, the past hour of events are returned.</p>
+    ///
+    /// See `Redshift::describe_events`.
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// <p>Returns information about the specified HSM client certificate. If no certificate ID is specified, returns information about all the HSM certificates owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM client certificates that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM client certificates that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM client certificates are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_hsm_client_certificates`.
+    pub fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_hsm_client_certificates(input))
+    }
+
+    /// <p>Returns information about the specified Amazon Redshift HSM configuration. If no configuration ID is specified, returns information about all the HSM configurations owned by your AWS customer account.</p> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all HSM connections that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all HSM connections that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, HSM connections are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_hsm_configurations`.
+    pub fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.describe_hsm_configurations(input))
+    }
+
+    /// <p>Describes whether information, such as queries and connection attempts, is being logged for the specified Amazon Redshift cluster.</p>
+    ///
+    /// See `Redshift::describe_logging_status`.
+    pub fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_logging_status(input))
+    }
+
+    /// <p>Returns properties of possible node configurations such as node type, number of nodes, and disk usage for the specified action type.</p>
+    ///
+    /// See `Redshift::describe_node_configuration_options`.
+    pub fn describe_node_configuration_options(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_node_configuration_options(input))
+    }
+
+    /// <p>Returns a list of orderable cluster options. Before you create a new cluster you can use this operation to find what options are available, such as the EC2 Availability Zones (AZ) in the specific AWS Region that you can specify, and the node types you can request. The node types differ by available storage, memory, CPU and price. With the cost involved you might want to obtain a list of cluster options in the specific region and specify values when creating a cluster. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::describe_orderable_cluster_options`.
+    pub fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_orderable_cluster_options(input))
+    }
+
+    /// <p>Returns a list of the available reserved node offerings by Amazon Redshift with their descriptions including the node type, the fixed and recurring costs of reserving the node and duration the node will be reserved for you. These descriptions help you determine which reserve node offering you want to purchase. You then use the unique offering ID in you call to <a>PurchaseReservedNodeOffering</a> to reserve one or more nodes for your Amazon Redshift cluster. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::describe_reserved_node_offerings`.
+    pub fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_node_offerings(input))
+    }
+
+    /// <p>Returns the descriptions of the reserved nodes.</p>
+    ///
+    /// See `Redshift::describe_reserved_nodes`.
+    pub fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_nodes(input))
+    }
+
+    /// <p>Returns information about the last resize operation for the specified cluster. If no resize operation has ever been initiated for the specified cluster, a <code>HTTP 404</code> error is returned. If a resize operation was initiated and completed, the status of the resize remains as <code>SUCCEEDED</code> until the next resize. </p> <p>A resize operation can be requested using <a>ModifyCluster</a> and specifying a different number or type of nodes for the cluster. </p>
+    ///
+    /// See `Redshift::describe_resize`.
+    pub fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
+        self.runtime.block_on(self.client.describe_resize(input))
+    }
+
+    /// <p>Describes properties of scheduled actions. </p>
+    ///
+    /// See `Redshift::describe_scheduled_actions`.
+    pub fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_actions(input))
+    }
+
+    /// <p>Returns a list of snapshot copy grants owned by the AWS account in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    ///
+    /// See `Redshift::describe_snapshot_copy_grants`.
+    pub fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
+        self.runtime
+            .block_on(self.client.describe_snapshot_copy_grants(input))
+    }
+
+    /// <p>Returns a list of snapshot schedules. </p>
+    ///
+    /// See `Redshift::describe_snapshot_schedules`.
+    pub fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_snapshot_schedules(input))
+    }
+
+    /// <p>Returns account level backups storage size and provisional storage.</p>
+    ///
+    /// See `Redshift::describe_storage`.
+    pub fn describe_storage(
+        &self,
+    ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
+        self.runtime.block_on(self.client.describe_storage())
+    }
+
+    /// <p>Lists the status of one or more table restore requests made using the <a>RestoreTableFromClusterSnapshot</a> API action. If you don't specify a value for the <code>TableRestoreRequestId</code> parameter, then <code>DescribeTableRestoreStatus</code> returns the status of all table restore requests ordered by the date and time of the request in ascending order. Otherwise <code>DescribeTableRestoreStatus</code> returns the status of the table specified by <code>TableRestoreRequestId</code>.</p>
+    ///
+    /// See `Redshift::describe_table_restore_status`.
+    pub fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_table_restore_status(input))
+    }
+
+    /// <p>Returns a list of tags. You can return tags from a specific resource by specifying an ARN, or you can return all tags for a given type of resource, such as clusters, snapshots, and so on.</p> <p>The following are limitations for <code>DescribeTags</code>: </p> <ul> <li> <p>You cannot specify an ARN and a resource-type value together in the same request.</p> </li> <li> <p>You cannot use the <code>MaxRecords</code> and <code>Marker</code> parameters together with the ARN parameter.</p> </li> <li> <p>The <code>MaxRecords</code> parameter can be a range from 10 to 50 results to return in a request.</p> </li> </ul> <p>If you specify both tag keys and tag values in the same request, Amazon Redshift returns all resources that match any combination of the specified keys and values. For example, if you have <code>owner</code> and <code>environment</code> for tag keys, and <code>admin</code> and <code>test</code> for tag values, all resources that have any combination of those values are returned.</p> <p>If both tag keys and values are omitted from the request, resources are returned regardless of whether they have tag keys or values associated with them.</p>
+    ///
+    /// See `Redshift::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p><p>Shows usage limits on a cluster. Results are filtered based on the combination of input usage limit identifier, cluster identifier, and feature type parameters:</p> <ul> <li> <p>If usage limit identifier, cluster identifier, and feature type are not provided, then all usage limit objects for the current account in the current region are returned.</p> </li> <li> <p>If usage limit identifier is provided, then the corresponding usage limit object is returned.</p> </li> <li> <p>If cluster identifier is provided, then all usage limit objects for the specified cluster are returned.</p> </li> <li> <p>If cluster identifier and feature type are provided, then all usage limit objects for the combination of cluster and feature are returned.</p> </li> </ul></p>
+    ///
+    /// See `Redshift::describe_usage_limits`.
+    pub fn describe_usage_limits(
+        &self,
+        input: DescribeUsageLimitsMessage,
+    ) -> Result<UsageLimitList, RusotoError<DescribeUsageLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_usage_limits(input))
+    }
+
+    /// <p>Stops logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
+    ///
+    /// See `Redshift::disable_logging`.
+    pub fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
+        self.runtime.block_on(self.client.disable_logging(input))
+    }
+
+    /// <p>Disables the automatic copying of snapshots from one region to another region for a specified cluster.</p> <p>If your cluster and its snapshots are encrypted using a customer master key (CMK) from AWS KMS, use <a>DeleteSnapshotCopyGrant</a> to delete the grant that grants Amazon Redshift permission to the CMK in the destination region. </p>
+    ///
+    /// See `Redshift::disable_snapshot_copy`.
+    pub fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
+        self.runtime
+            .block_on(self.client.disable_snapshot_copy(input))
+    }
+
+    /// <p>Starts logging information, such as queries and connection attempts, for the specified Amazon Redshift cluster.</p>
+    ///
+    /// See `Redshift::enable_logging`.
+    pub fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
+        self.runtime.block_on(self.client.enable_logging(input))
+    }
+
+    /// <p>Enables the automatic copy of snapshots from one region to another region for a specified cluster.</p>
+    ///
+    /// See `Redshift::enable_snapshot_copy`.
+    pub fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
+        self.runtime
+            .block_on(self.client.enable_snapshot_copy(input))
+    }
+
+    /// <p>Returns a database user name and temporary password with temporary authorization to log on to an Amazon Redshift database. The action returns the database user name prefixed with <code>IAM:</code> if <code>AutoCreate</code> is <code>False</code> or <code>IAMA:</code> if <code>AutoCreate</code> is <code>True</code>. You can optionally specify one or more database user groups that the user will join at log on. By default, the temporary credentials expire in 900 seconds. You can optionally specify a duration between 900 seconds (15 minutes) and 3600 seconds (60 minutes). For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/generating-user-credentials.html">Using IAM Authentication to Generate Database User Credentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>The AWS Identity and Access Management (IAM)user or role that executes GetClusterCredentials must have an IAM policy attached that allows access to all necessary actions and resources. For more information about permissions, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/redshift-iam-access-control-identity-based.html#redshift-policy-resources.getclustercredentials-resources">Resource Policies for GetClusterCredentials</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If the <code>DbGroups</code> parameter is specified, the IAM policy must allow the <code>redshift:JoinGroup</code> action with access to the listed <code>dbgroups</code>. </p> <p>In addition, if the <code>AutoCreate</code> parameter is set to <code>True</code>, then the policy must include the <code>redshift:CreateClusterUser</code> privilege.</p> <p>If the <code>DbName</code> parameter is specified, the IAM policy must allow access to the resource <code>dbname</code> for the specified database name. </p>
+    ///
+    /// See `Redshift::get_cluster_credentials`.
+    pub fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
+        self.runtime
+            .block_on(self.client.get_cluster_credentials(input))
+    }
+
+    /// <p>Returns an array of DC2 ReservedNodeOfferings that matches the payment type, term, and usage price of the given DC1 reserved node.</p>
+    ///
+    /// See `Redshift::get_reserved_node_exchange_offerings`.
+    pub fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> Result<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        RusotoError<GetReservedNodeExchangeOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reserved_node_exchange_offerings(input))
+    }
+
+    /// <p>Modifies the settings for a cluster.</p> <p>You can also change node type and the number of nodes to scale up or down the cluster. When resizing a cluster, you must specify both the number of nodes and the node type even if one of the parameters does not change.</p> <p>You can add another security or parameter group, or change the master user password. Resetting a cluster password or modifying the security groups associated with a cluster do not need a reboot. However, modifying a parameter group requires a reboot for parameters to take effect. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::modify_cluster`.
+    pub fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
+        self.runtime.block_on(self.client.modify_cluster(input))
+    }
+
+    /// <p>Modifies the database revision of a cluster. The database revision is a unique revision of the database running in a cluster.</p>
+    ///
+    /// See `Redshift::modify_cluster_db_revision`.
+    pub fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_db_revision(input))
+    }
+
+    /// <p>Modifies the list of AWS Identity and Access Management (IAM) roles that can be used by the cluster to access other AWS services.</p> <p>A cluster can have up to 10 IAM roles associated at any time.</p>
+    ///
+    /// See `Redshift::modify_cluster_iam_roles`.
+    pub fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_iam_roles(input))
+    }
+
+    /// <p>Modifies the maintenance settings of a cluster.</p>
+    ///
+    /// See `Redshift::modify_cluster_maintenance`.
+    pub fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_maintenance(input))
+    }
+
+    /// <p>Modifies the parameters of a parameter group.</p> <p> For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::modify_cluster_parameter_group`.
+    pub fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_cluster_parameter_group(input))
+    }
+
+    /// <p>Modifies the settings for a snapshot.</p> <p>This exanmple modifies the manual retention period setting for a cluster snapshot.</p>
+    ///
+    /// See `Redshift::modify_cluster_snapshot`.
+    pub fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_snapshot(input))
+    }
+
+    /// <p>Modifies a snapshot schedule for a cluster.</p>
+    ///
+    /// See `Redshift::modify_cluster_snapshot_schedule`.
+    pub fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_snapshot_schedule(input))
+    }
+
+    /// <p>Modifies a cluster subnet group to include the specified list of VPC subnets. The operation replaces the existing list of subnets with the new list of subnets.</p>
+    ///
+    /// See `Redshift::modify_cluster_subnet_group`.
+    pub fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_subnet_group(input))
+    }
+
+    /// <p>Modifies an existing Amazon Redshift event notification subscription.</p>
+    ///
+    /// See `Redshift::modify_event_subscription`.
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// <p>Modifies a scheduled action. </p>
+    ///
+    /// See `Redshift::modify_scheduled_action`.
+    pub fn modify_scheduled_action(
+        &self,
+        input: ModifyScheduledActionMessage,
+    ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.modify_scheduled_action(input))
+    }
+
+    /// <p>Modifies the number of days to retain snapshots in the destination AWS Region after they are copied from the source AWS Region. By default, this operation only changes the retention period of copied automated snapshots. The retention periods for both new and existing copied automated snapshots are updated with the new retention period. You can set the manual option to change only the retention periods of copied manual snapshots. If you set this option, only newly copied manual snapshots have the new retention period. </p>
+    ///
+    /// See `Redshift::modify_snapshot_copy_retention_period`.
+    pub fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> Result<
+        ModifySnapshotCopyRetentionPeriodResult,
+        RusotoError<ModifySnapshotCopyRetentionPeriodError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_snapshot_copy_retention_period(input))
+    }
+
+    /// <p>Modifies a snapshot schedule. Any schedule associated with a cluster is modified asynchronously.</p>
+    ///
+    /// See `Redshift::modify_snapshot_schedule`.
+    pub fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.modify_snapshot_schedule(input))
+    }
+
+    /// <p>Modifies a usage limit in a cluster. You can't modify the feature type or period of a usage limit.</p>
+    ///
+    /// See `Redshift::modify_usage_limit`.
+    pub fn modify_usage_limit(
+        &self,
+        input: ModifyUsageLimitMessage,
+    ) -> Result<UsageLimit, RusotoError<ModifyUsageLimitError>> {
+        self.runtime.block_on(self.client.modify_usage_limit(input))
+    }
+
+    /// <p>Pauses a cluster.</p>
+    ///
+    /// See `Redshift::pause_cluster`.
+    pub fn pause_cluster(
+        &self,
+        input: PauseClusterMessage,
+    ) -> Result<PauseClusterResult, RusotoError<PauseClusterError>> {
+        self.runtime.block_on(self.client.pause_cluster(input))
+    }
+
+    /// <p>Allows you to purchase reserved nodes. Amazon Redshift offers a predefined set of reserved node offerings. You can purchase one or more of the offerings. You can call the <a>DescribeReservedNodeOfferings</a> API to obtain the available reserved node offerings. You can call this API by providing a specific reserved node offering and the number of nodes you want to reserve. </p> <p> For more information about reserved node offerings, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/purchase-reserved-node-instance.html">Purchasing Reserved Nodes</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::purchase_reserved_node_offering`.
+    pub fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
+    {
+        self.runtime
+            .block_on(self.client.purchase_reserved_node_offering(input))
+    }
+
+    /// <p>Reboots a cluster. This action is taken as soon as possible. It results in a momentary outage to the cluster, during which the cluster status is set to <code>rebooting</code>. A cluster event is created when the reboot is completed. Any pending cluster modifications (see <a>ModifyCluster</a>) are applied at this reboot. For more information about managing clusters, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-clusters.html">Amazon Redshift Clusters</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    ///
+    /// See `Redshift::reboot_cluster`.
+    pub fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
+        self.runtime.block_on(self.client.reboot_cluster(input))
+    }
+
+    /// <p>Sets one or more parameters of the specified parameter group to their default values and sets the source values of the parameters to "engine-default". To reset the entire parameter group specify the <i>ResetAllParameters</i> parameter. For parameter changes to take effect you must reboot any associated clusters. </p>
+    ///
+    /// See `Redshift::reset_cluster_parameter_group`.
+    pub fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_cluster_parameter_group(input))
+    }
+
+    /// <p><p>Changes the size of the cluster. You can change the cluster&#39;s type, or change the number or type of nodes. The default behavior is to use the elastic resize method. With an elastic resize, your cluster is available for read and write operations more quickly than with the classic resize method. </p> <p>Elastic resize operations have the following restrictions:</p> <ul> <li> <p>You can only resize clusters of the following types:</p> <ul> <li> <p>dc2.large</p> </li> <li> <p>dc2.8xlarge</p> </li> <li> <p>ds2.xlarge</p> </li> <li> <p>ds2.8xlarge</p> </li> <li> <p>ra3.4xlarge</p> </li> <li> <p>ra3.16xlarge</p> </li> </ul> </li> <li> <p>The type of nodes that you add must match the node type for the cluster.</p> </li> </ul></p>
+    ///
+    /// See `Redshift::resize_cluster`.
+    pub fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
+        self.runtime.block_on(self.client.resize_cluster(input))
+    }
+
+    /// <p>Creates a new cluster from a snapshot. By default, Amazon Redshift creates the resulting cluster with the same configuration as the original cluster from which the snapshot was created, except that the new cluster is created with the default cluster security and parameter groups. After Amazon Redshift creates the cluster, you can use the <a>ModifyCluster</a> API to associate a different security group and different parameter group with the restored cluster. If you are using a DS node type, you can also choose to change to another DS node type of the same size during restore.</p> <p>If you restore a cluster into a VPC, you must provide a cluster subnet group where you want the cluster restored.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::restore_from_cluster_snapshot`.
+    pub fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_from_cluster_snapshot(input))
+    }
+
+    /// <p>Creates a new table from a table in an Amazon Redshift cluster snapshot. You must create the new table within the Amazon Redshift cluster that the snapshot was taken from.</p> <p>You cannot use <code>RestoreTableFromClusterSnapshot</code> to restore a table with the same name as an existing table in an Amazon Redshift cluster. That is, you cannot overwrite an existing table in a cluster with a restored table. If you want to replace your original table with a new, restored table, then rename or drop your original table before you call <code>RestoreTableFromClusterSnapshot</code>. When you have renamed your original table, then you can pass the original name of the table as the <code>NewTableName</code> parameter value in the call to <code>RestoreTableFromClusterSnapshot</code>. This way, you can replace the original table with the table created from the snapshot.</p>
+    ///
+    /// See `Redshift::restore_table_from_cluster_snapshot`.
+    pub fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> Result<
+        RestoreTableFromClusterSnapshotResult,
+        RusotoError<RestoreTableFromClusterSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_table_from_cluster_snapshot(input))
+    }
+
+    /// <p>Resumes a paused cluster.</p>
+    ///
+    /// See `Redshift::resume_cluster`.
+    pub fn resume_cluster(
+        &self,
+        input: ResumeClusterMessage,
+    ) -> Result<ResumeClusterResult, RusotoError<ResumeClusterError>> {
+        self.runtime.block_on(self.client.resume_cluster(input))
+    }
+
+    /// <p>Revokes an ingress rule in an Amazon Redshift security group for a previously authorized IP range or Amazon EC2 security group. To add an ingress rule, see <a>AuthorizeClusterSecurityGroupIngress</a>. For information about managing security groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-security-groups.html">Amazon Redshift Cluster Security Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
+    ///
+    /// See `Redshift::revoke_cluster_security_group_ingress`.
+    pub fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        RevokeClusterSecurityGroupIngressResult,
+        RusotoError<RevokeClusterSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.revoke_cluster_security_group_ingress(input))
+    }
+
+    /// <p>Removes the ability of the specified AWS customer account to restore the specified snapshot. If the account is currently restoring the snapshot, the restore will run to completion.</p> <p> For more information about working with snapshots, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-snapshots.html">Amazon Redshift Snapshots</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+    ///
+    /// See `Redshift::revoke_snapshot_access`.
+    pub fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
+        self.runtime
+            .block_on(self.client.revoke_snapshot_access(input))
+    }
+
+    /// <p>Rotates the encryption keys for a cluster.</p>
+    ///
+    /// See `Redshift::rotate_encryption_key`.
+    pub fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
+        self.runtime
+            .block_on(self.client.rotate_encryption_key(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
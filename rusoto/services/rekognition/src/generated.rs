@@ -2821,30 +2821,37 @@ impl CompareFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CompareFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CompareFacesError::AccessDenied(err.msg))
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(CompareFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(&res, CompareFacesError::ImageTooLarge(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CompareFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompareFacesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CompareFacesError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CompareFacesError::InvalidParameter(err.msg))
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service(&res, CompareFacesError::InvalidS3Object(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(CompareFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CompareFacesError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CompareFacesError::Throttling(err.msg))
+                    return RusotoError::service(&res, CompareFacesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2891,28 +2898,34 @@ impl CreateCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateCollectionError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCollectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCollectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCollectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCollectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCollectionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCollectionError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCollectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateCollectionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2961,27 +2974,34 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateProjectError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::LimitExceeded(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(CreateProjectError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::ResourceInUse(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProjectError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateProjectError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3031,36 +3051,52 @@ impl CreateProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProjectVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProjectVersionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProjectVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProjectVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3111,31 +3147,46 @@ impl CreateStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamProcessorError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamProcessorError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamProcessorError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamProcessorError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamProcessorError::ResourceInUse(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStreamProcessorError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3183,26 +3234,34 @@ impl DeleteCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteCollectionError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteCollectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCollectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCollectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteCollectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCollectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCollectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCollectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteCollectionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3249,24 +3308,28 @@ impl DeleteFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteFacesError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFacesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteFacesError::InvalidParameter(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFacesError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFacesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFacesError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteFacesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3313,27 +3376,37 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProjectError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteProjectError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteProjectError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProjectError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProjectError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteProjectError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3381,33 +3454,46 @@ impl DeleteProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectVersionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectVersionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectVersionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProjectVersionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProjectVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3457,33 +3543,46 @@ impl DeleteStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamProcessorError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamProcessorError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamProcessorError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamProcessorError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamProcessorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteStreamProcessorError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3531,26 +3630,37 @@ impl DescribeCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCollectionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCollectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCollectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCollectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCollectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCollectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCollectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCollectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeCollectionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3599,37 +3709,46 @@ impl DescribeProjectVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectVersionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectVersionsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProjectVersionsError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectVersionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProjectVersionsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectVersionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectVersionsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3679,28 +3798,34 @@ impl DescribeProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeProjectsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeProjectsError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeProjectsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(DescribeProjectsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectsError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProjectsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProjectsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProjectsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProjectsError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeProjectsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3747,32 +3872,40 @@ impl DescribeStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamProcessorError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamProcessorError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamProcessorError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamProcessorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStreamProcessorError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3829,43 +3962,67 @@ impl DetectCustomLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::AccessDenied(err.msg),
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ImageTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::ImageTooLarge(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetectCustomLabelsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ResourceNotReady(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectCustomLabelsError::ResourceNotReady(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::Throttling(err.msg))
+                    return RusotoError::service(&res, DetectCustomLabelsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3921,30 +4078,37 @@ impl DetectFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetectFacesError::AccessDenied(err.msg))
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(&res, DetectFacesError::ImageTooLarge(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectFacesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectFacesError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DetectFacesError::InvalidParameter(err.msg))
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service(&res, DetectFacesError::InvalidS3Object(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectFacesError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectFacesError::Throttling(err.msg))
+                    return RusotoError::service(&res, DetectFacesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3995,30 +4159,37 @@ impl DetectLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetectLabelsError::AccessDenied(err.msg))
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectLabelsError::ImageTooLarge(err.msg))
+                    return RusotoError::service(&res, DetectLabelsError::ImageTooLarge(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectLabelsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectLabelsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectLabelsError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DetectLabelsError::InvalidParameter(err.msg))
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidS3Object(err.msg))
+                    return RusotoError::service(&res, DetectLabelsError::InvalidS3Object(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectLabelsError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectLabelsError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectLabelsError::Throttling(err.msg))
+                    return RusotoError::service(&res, DetectLabelsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4071,45 +4242,58 @@ impl DetectModerationLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::AccessDenied(err.msg),
+                    )
                 }
                 "HumanLoopQuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetectModerationLabelsError::HumanLoopQuotaExceeded(err.msg),
                     )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::ImageTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::ImageTooLarge(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::InvalidS3Object(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetectModerationLabelsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectModerationLabelsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4165,30 +4349,34 @@ impl DetectTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectTextError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DetectTextError::AccessDenied(err.msg))
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectTextError::ImageTooLarge(err.msg))
+                    return RusotoError::service(&res, DetectTextError::ImageTooLarge(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectTextError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectTextError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectTextError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(&res, DetectTextError::InvalidImageFormat(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectTextError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DetectTextError::InvalidParameter(err.msg))
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectTextError::InvalidS3Object(err.msg))
+                    return RusotoError::service(&res, DetectTextError::InvalidS3Object(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectTextError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectTextError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectTextError::Throttling(err.msg))
+                    return RusotoError::service(&res, DetectTextError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4235,26 +4423,34 @@ impl GetCelebrityInfoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetCelebrityInfoError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCelebrityInfoError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityInfoError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityInfoError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCelebrityInfoError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityInfoError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetCelebrityInfoError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4303,37 +4499,46 @@ impl GetCelebrityRecognitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityRecognitionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityRecognitionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCelebrityRecognitionError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityRecognitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCelebrityRecognitionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityRecognitionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCelebrityRecognitionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4385,35 +4590,46 @@ impl GetContentModerationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContentModerationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContentModerationError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetContentModerationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContentModerationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetContentModerationError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContentModerationError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetContentModerationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContentModerationError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetContentModerationError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContentModerationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContentModerationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetContentModerationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetContentModerationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4463,31 +4679,40 @@ impl GetFaceDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFaceDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetFaceDetectionError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetFaceDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetFaceDetectionError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceDetectionError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFaceDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetFaceDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFaceDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceDetectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFaceDetectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetFaceDetectionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4537,29 +4762,40 @@ impl GetFaceSearchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFaceSearchError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetFaceSearchError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetFaceSearchError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceSearchError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetFaceSearchError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceSearchError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFaceSearchError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceSearchError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetFaceSearchError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceSearchError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFaceSearchError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFaceSearchError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFaceSearchError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetFaceSearchError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4607,31 +4843,43 @@ impl GetLabelDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLabelDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLabelDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetLabelDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLabelDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetLabelDetectionError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLabelDetectionError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLabelDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLabelDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetLabelDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLabelDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetLabelDetectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLabelDetectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetLabelDetectionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4681,31 +4929,43 @@ impl GetPersonTrackingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetPersonTrackingError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonTrackingError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetPersonTrackingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonTrackingError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetPersonTrackingError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonTrackingError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetPersonTrackingError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonTrackingError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPersonTrackingError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPersonTrackingError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPersonTrackingError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPersonTrackingError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetPersonTrackingError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4755,35 +5015,46 @@ impl GetSegmentDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSegmentDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetSegmentDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSegmentDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSegmentDetectionError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSegmentDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSegmentDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSegmentDetectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSegmentDetectionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4833,31 +5104,40 @@ impl GetTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTextDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetTextDetectionError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetTextDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTextDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetTextDetectionError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTextDetectionError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTextDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTextDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTextDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetTextDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTextDetectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetTextDetectionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4911,33 +5191,37 @@ impl IndexFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(IndexFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::AccessDenied(err.msg))
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(IndexFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::ImageTooLarge(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(IndexFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        IndexFacesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::InvalidImageFormat(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::InvalidParameter(err.msg))
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::InvalidS3Object(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(IndexFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        IndexFacesError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(IndexFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(IndexFacesError::Throttling(err.msg))
+                    return RusotoError::service(&res, IndexFacesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4987,29 +5271,40 @@ impl ListCollectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCollectionsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListCollectionsError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCollectionsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCollectionsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListCollectionsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCollectionsError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListCollectionsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCollectionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListCollectionsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListCollectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCollectionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCollectionsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListCollectionsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5059,27 +5354,31 @@ impl ListFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListFacesError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListFacesError::InternalServerError(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListFacesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFacesError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListFacesError::InvalidParameter(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(ListFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFacesError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListFacesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFacesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListFacesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5125,30 +5424,40 @@ impl ListStreamProcessorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamProcessorsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamProcessorsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamProcessorsError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamProcessorsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListStreamProcessorsError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStreamProcessorsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5199,38 +5508,52 @@ impl RecognizeCelebritiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::AccessDenied(err.msg),
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::ImageTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::ImageTooLarge(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::InvalidS3Object(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RecognizeCelebritiesError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RecognizeCelebritiesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5279,24 +5602,28 @@ impl SearchFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchFacesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, SearchFacesError::AccessDenied(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SearchFacesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SearchFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, SearchFacesError::InvalidParameter(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(SearchFacesError::ProvisionedThroughputExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesError::ProvisionedThroughputExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SearchFacesError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFacesError::Throttling(err.msg))
+                    return RusotoError::service(&res, SearchFacesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5347,37 +5674,55 @@ impl SearchFacesByImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchFacesByImageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::AccessDenied(err.msg),
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(SearchFacesByImageError::ImageTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::ImageTooLarge(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SearchFacesByImageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidImageFormat(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::InvalidImageFormat(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::InvalidS3Object(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SearchFacesByImageError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFacesByImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchFacesByImageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFacesByImageError::Throttling(err.msg))
+                    return RusotoError::service(&res, SearchFacesByImageError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5433,49 +5778,58 @@ impl StartCelebrityRecognitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCelebrityRecognitionError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartCelebrityRecognitionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartCelebrityRecognitionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCelebrityRecognitionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCelebrityRecognitionError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCelebrityRecognitionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartCelebrityRecognitionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCelebrityRecognitionError::Throttling(err.msg),
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::VideoTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartCelebrityRecognitionError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5535,45 +5889,58 @@ impl StartContentModerationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartContentModerationError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartContentModerationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartContentModerationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContentModerationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartContentModerationError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartContentModerationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartContentModerationError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartContentModerationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::Throttling(err.msg),
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartContentModerationError::VideoTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartContentModerationError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5631,37 +5998,55 @@ impl StartFaceDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartFaceDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartFaceDetectionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartFaceDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartFaceDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartFaceDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceDetectionError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFaceDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceDetectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartFaceDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartFaceDetectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartFaceDetectionError::Throttling(err.msg))
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartFaceDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceDetectionError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5721,38 +6106,52 @@ impl StartFaceSearchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartFaceSearchError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StartFaceSearchError::AccessDenied(err.msg))
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(StartFaceSearchError::IdempotentParameterMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceSearchError::IdempotentParameterMismatch(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartFaceSearchError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceSearchError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartFaceSearchError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceSearchError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartFaceSearchError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceSearchError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFaceSearchError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, StartFaceSearchError::LimitExceeded(err.msg))
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartFaceSearchError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartFaceSearchError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartFaceSearchError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartFaceSearchError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartFaceSearchError::Throttling(err.msg))
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartFaceSearchError::VideoTooLarge(err.msg))
+                    return RusotoError::service(&res, StartFaceSearchError::VideoTooLarge(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5809,39 +6208,58 @@ impl StartLabelDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartLabelDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLabelDetectionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartLabelDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartLabelDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartLabelDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartLabelDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartLabelDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartLabelDetectionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::Throttling(err.msg),
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartLabelDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartLabelDetectionError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5899,39 +6317,58 @@ impl StartPersonTrackingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartPersonTrackingError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartPersonTrackingError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartPersonTrackingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartPersonTrackingError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartPersonTrackingError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartPersonTrackingError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartPersonTrackingError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartPersonTrackingError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::Throttling(err.msg),
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartPersonTrackingError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartPersonTrackingError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5987,36 +6424,52 @@ impl StartProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartProjectVersionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartProjectVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartProjectVersionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartProjectVersionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartProjectVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6071,41 +6524,58 @@ impl StartSegmentDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSegmentDetectionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartSegmentDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSegmentDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::Throttling(err.msg),
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSegmentDetectionError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6159,33 +6629,46 @@ impl StartStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamProcessorError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamProcessorError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamProcessorError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamProcessorError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamProcessorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartStreamProcessorError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6239,37 +6722,55 @@ impl StartTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartTextDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTextDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartTextDetectionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartTextDetectionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTextDetectionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartTextDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTextDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartTextDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTextDetectionError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartTextDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTextDetectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartTextDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartTextDetectionError::Throttling(err.msg))
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartTextDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTextDetectionError::VideoTooLarge(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6323,29 +6824,43 @@ impl StopProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopProjectVersionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopProjectVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopProjectVersionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopProjectVersionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopProjectVersionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopProjectVersionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopProjectVersionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopProjectVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopProjectVersionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service(&res, StopProjectVersionError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6395,33 +6910,46 @@ impl StopStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamProcessorError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopStreamProcessorError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamProcessorError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopStreamProcessorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamProcessorError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamProcessorError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStreamProcessorError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamProcessorError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopStreamProcessorError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6750,6 +7278,7 @@ impl RekognitionClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6765,6 +7294,7 @@ impl RekognitionClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> RekognitionClient {
         RekognitionClient { client, region }
     }
@@ -6778,6 +7308,9 @@ impl Rekognition for RekognitionClient {
         input: CompareFacesRequest,
     ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CompareFaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.CompareFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6796,6 +7329,9 @@ impl Rekognition for RekognitionClient {
         input: CreateCollectionRequest,
     ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCollection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.CreateCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6814,6 +7350,9 @@ impl Rekognition for RekognitionClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6832,6 +7371,9 @@ impl Rekognition for RekognitionClient {
         input: CreateProjectVersionRequest,
     ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProjectVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.CreateProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6851,6 +7393,9 @@ impl Rekognition for RekognitionClient {
         input: CreateStreamProcessorRequest,
     ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStreamProcessor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.CreateStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6870,6 +7415,9 @@ impl Rekognition for RekognitionClient {
         input: DeleteCollectionRequest,
     ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCollection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DeleteCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6888,6 +7436,9 @@ impl Rekognition for RekognitionClient {
         input: DeleteFacesRequest,
     ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DeleteFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6906,6 +7457,9 @@ impl Rekognition for RekognitionClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProject");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6924,6 +7478,9 @@ impl Rekognition for RekognitionClient {
         input: DeleteProjectVersionRequest,
     ) -> Result<DeleteProjectVersionResponse, RusotoError<DeleteProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProjectVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DeleteProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6943,6 +7500,9 @@ impl Rekognition for RekognitionClient {
         input: DeleteStreamProcessorRequest,
     ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteStreamProcessor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DeleteStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6962,6 +7522,9 @@ impl Rekognition for RekognitionClient {
         input: DescribeCollectionRequest,
     ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCollection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.DescribeCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6980,6 +7543,9 @@ impl Rekognition for RekognitionClient {
         input: DescribeProjectVersionsRequest,
     ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProjectVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.DescribeProjectVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6999,6 +7565,9 @@ impl Rekognition for RekognitionClient {
         input: DescribeProjectsRequest,
     ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProjects");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.DescribeProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7017,6 +7586,9 @@ impl Rekognition for RekognitionClient {
         input: DescribeStreamProcessorRequest,
     ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStreamProcessor");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.DescribeStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7036,6 +7608,9 @@ impl Rekognition for RekognitionClient {
         input: DetectCustomLabelsRequest,
     ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectCustomLabels");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DetectCustomLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7054,6 +7629,9 @@ impl Rekognition for RekognitionClient {
         input: DetectFacesRequest,
     ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectFaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DetectFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7072,6 +7650,9 @@ impl Rekognition for RekognitionClient {
         input: DetectLabelsRequest,
     ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectLabels");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DetectLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7090,6 +7671,9 @@ impl Rekognition for RekognitionClient {
         input: DetectModerationLabelsRequest,
     ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectModerationLabels");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DetectModerationLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7109,6 +7693,9 @@ impl Rekognition for RekognitionClient {
         input: DetectTextRequest,
     ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectText");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.DetectText");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7127,6 +7714,9 @@ impl Rekognition for RekognitionClient {
         input: GetCelebrityInfoRequest,
     ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCelebrityInfo");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityInfo");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7145,6 +7735,9 @@ impl Rekognition for RekognitionClient {
         input: GetCelebrityRecognitionRequest,
     ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCelebrityRecognition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityRecognition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7164,6 +7757,9 @@ impl Rekognition for RekognitionClient {
         input: GetContentModerationRequest,
     ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetContentModeration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetContentModeration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7183,6 +7779,9 @@ impl Rekognition for RekognitionClient {
         input: GetFaceDetectionRequest,
     ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFaceDetection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetFaceDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7201,6 +7800,9 @@ impl Rekognition for RekognitionClient {
         input: GetFaceSearchRequest,
     ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetFaceSearch");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetFaceSearch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7219,6 +7821,9 @@ impl Rekognition for RekognitionClient {
         input: GetLabelDetectionRequest,
     ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLabelDetection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetLabelDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7237,6 +7842,9 @@ impl Rekognition for RekognitionClient {
         input: GetPersonTrackingRequest,
     ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPersonTracking");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetPersonTracking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7255,6 +7863,9 @@ impl Rekognition for RekognitionClient {
         input: GetSegmentDetectionRequest,
     ) -> Result<GetSegmentDetectionResponse, RusotoError<GetSegmentDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSegmentDetection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetSegmentDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7273,6 +7884,9 @@ impl Rekognition for RekognitionClient {
         input: GetTextDetectionRequest,
     ) -> Result<GetTextDetectionResponse, RusotoError<GetTextDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTextDetection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.GetTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7291,6 +7905,9 @@ impl Rekognition for RekognitionClient {
         input: IndexFacesRequest,
     ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("IndexFaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.IndexFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7309,6 +7926,9 @@ impl Rekognition for RekognitionClient {
         input: ListCollectionsRequest,
     ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCollections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.ListCollections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7327,6 +7947,9 @@ impl Rekognition for RekognitionClient {
         input: ListFacesRequest,
     ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.ListFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7345,6 +7968,9 @@ impl Rekognition for RekognitionClient {
         input: ListStreamProcessorsRequest,
     ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListStreamProcessors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.ListStreamProcessors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7364,6 +7990,9 @@ impl Rekognition for RekognitionClient {
         input: RecognizeCelebritiesRequest,
     ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RecognizeCelebrities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.RecognizeCelebrities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7383,6 +8012,9 @@ impl Rekognition for RekognitionClient {
         input: SearchFacesRequest,
     ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchFaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.SearchFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7401,6 +8033,9 @@ impl Rekognition for RekognitionClient {
         input: SearchFacesByImageRequest,
     ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchFacesByImage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.SearchFacesByImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7420,6 +8055,9 @@ impl Rekognition for RekognitionClient {
     ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartCelebrityRecognition");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "RekognitionService.StartCelebrityRecognition",
@@ -7442,6 +8080,9 @@ impl Rekognition for RekognitionClient {
         input: StartContentModerationRequest,
     ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartContentModeration");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartContentModeration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7461,6 +8102,9 @@ impl Rekognition for RekognitionClient {
         input: StartFaceDetectionRequest,
     ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartFaceDetection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartFaceDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7479,6 +8123,9 @@ impl Rekognition for RekognitionClient {
         input: StartFaceSearchRequest,
     ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartFaceSearch");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartFaceSearch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7497,6 +8144,9 @@ impl Rekognition for RekognitionClient {
         input: StartLabelDetectionRequest,
     ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartLabelDetection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartLabelDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7515,6 +8165,9 @@ impl Rekognition for RekognitionClient {
         input: StartPersonTrackingRequest,
     ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartPersonTracking");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartPersonTracking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7533,6 +8186,9 @@ impl Rekognition for RekognitionClient {
         input: StartProjectVersionRequest,
     ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartProjectVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.StartProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7551,6 +8207,9 @@ impl Rekognition for RekognitionClient {
         input: StartSegmentDetectionRequest,
     ) -> Result<StartSegmentDetectionResponse, RusotoError<StartSegmentDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartSegmentDetection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartSegmentDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7570,6 +8229,9 @@ impl Rekognition for RekognitionClient {
         input: StartStreamProcessorRequest,
     ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartStreamProcessor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.StartStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7589,6 +8251,9 @@ impl Rekognition for RekognitionClient {
         input: StartTextDetectionRequest,
     ) -> Result<StartTextDetectionResponse, RusotoError<StartTextDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTextDetection");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "RekognitionService.StartTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7607,6 +8272,9 @@ impl Rekognition for RekognitionClient {
         input: StopProjectVersionRequest,
     ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopProjectVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.StopProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7625,6 +8293,9 @@ impl Rekognition for RekognitionClient {
         input: StopStreamProcessorRequest,
     ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopStreamProcessor");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "RekognitionService.StopStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7637,3 +8308,552 @@ impl Rekognition for RekognitionClient {
         proto::json::ResponsePayload::new(&response).deserialize::<StopStreamProcessorResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Rekognition API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `RekognitionClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RekognitionBlockingClient {
+    client: RekognitionClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RekognitionBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RekognitionBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        RekognitionBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RekognitionBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RekognitionBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> RekognitionBlockingClient {
+        RekognitionBlockingClient {
+            client: RekognitionClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Compares a face in the <i>source</i> input image with each of the 100 largest faces detected in the <i>target</i> input image. </p> <note> <p> If the source image contains multiple faces, the service detects the largest face and compares it with each face detected in the target image. </p> </note> <p>You pass the input and target images either as base64-encoded image bytes or as references to images in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>In response, the operation returns an array of face matches ordered by similarity score in descending order. For each face match, the response provides a bounding box of the face, facial landmarks, pose details (pitch, role, and yaw), quality (brightness and sharpness), and confidence value (indicating the level of confidence that the bounding box contains a face). The response also provides a similarity score, which indicates how closely the faces match. </p> <note> <p>By default, only faces with a similarity score of greater than or equal to 80% are returned in the response. You can change this value by specifying the <code>SimilarityThreshold</code> parameter.</p> </note> <p> <code>CompareFaces</code> also returns an array of faces that don't match the source image. For each face, it returns a bounding box, confidence value, landmarks, pose details, and quality. The response also returns information about the face in the source image, including the bounding box of the face and confidence value.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. Use <code>QualityFilter</code> to set the quality bar by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. The default value is <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>If the image doesn't contain Exif metadata, <code>CompareFaces</code> returns orientation information for the source and target images. Use these values to display the images with the correct image orientation.</p> <p>If no faces are detected in the source or target images, <code>CompareFaces</code> returns an <code>InvalidParameterException</code> error. </p> <note> <p> This is a stateless API operation. That is, data returned by this operation doesn't persist.</p> </note> <p>For an example, see Comparing Faces in Images in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:CompareFaces</code> action.</p>
+    ///
+    /// See `Rekognition::compare_faces`.
+    pub fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
+        self.runtime.block_on(self.client.compare_faces(input))
+    }
+
+    /// <p>Creates a collection in an AWS Region. You can add faces to the collection using the <a>IndexFaces</a> operation. </p> <p>For example, you might create collections, one for each of your application users. A user can then index faces using the <code>IndexFaces</code> operation and persist results in a specific collection. Then, a user can search the collection for faces in the user-specific container. </p> <p>When you create a collection, it is associated with the latest version of the face model version.</p> <note> <p>Collection names are case-sensitive.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:CreateCollection</code> action.</p>
+    ///
+    /// See `Rekognition::create_collection`.
+    pub fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
+        self.runtime.block_on(self.client.create_collection(input))
+    }
+
+    /// <p>Creates a new Amazon Rekognition Custom Labels project. A project is a logical grouping of resources (images, Labels, models) and operations (training, evaluation and detection). </p> <p>This operation requires permissions to perform the <code>rekognition:CreateProject</code> action.</p>
+    ///
+    /// See `Rekognition::create_project`.
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// <p>Creates a new version of a model and begins training. Models are managed as part of an Amazon Rekognition Custom Labels project. You can specify one training dataset and one testing dataset. The response from <code>CreateProjectVersion</code> is an Amazon Resource Name (ARN) for the version of the model. </p> <p>Training takes a while to complete. You can get the current status by calling <a>DescribeProjectVersions</a>.</p> <p>Once training has successfully completed, call <a>DescribeProjectVersions</a> to get the training results and evaluate the model. </p> <p>After evaluating the model, you start the model by calling <a>StartProjectVersion</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:CreateProjectVersion</code> action.</p>
+    ///
+    /// See `Rekognition::create_project_version`.
+    pub fn create_project_version(
+        &self,
+        input: CreateProjectVersionRequest,
+    ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.create_project_version(input))
+    }
+
+    /// <p>Creates an Amazon Rekognition stream processor that you can use to detect and recognize faces in a streaming video.</p> <p>Amazon Rekognition Video is a consumer of live video from Amazon Kinesis Video Streams. Amazon Rekognition Video sends analysis results to Amazon Kinesis Data Streams.</p> <p>You provide as input a Kinesis video stream (<code>Input</code>) and a Kinesis data stream (<code>Output</code>) stream. You also specify the face recognition criteria in <code>Settings</code>. For example, the collection containing faces that you want to recognize. Use <code>Name</code> to assign an identifier for the stream processor. You use <code>Name</code> to manage the stream processor. For example, you can start processing the source video by calling <a>StartStreamProcessor</a> with the <code>Name</code> field. </p> <p>After you have finished analyzing a streaming video, use <a>StopStreamProcessor</a> to stop processing. You can delete the stream processor by calling <a>DeleteStreamProcessor</a>.</p>
+    ///
+    /// See `Rekognition::create_stream_processor`.
+    pub fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.create_stream_processor(input))
+    }
+
+    /// <p>Deletes the specified collection. Note that this operation removes all faces in the collection. For an example, see <a>delete-collection-procedure</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteCollection</code> action.</p>
+    ///
+    /// See `Rekognition::delete_collection`.
+    pub fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
+        self.runtime.block_on(self.client.delete_collection(input))
+    }
+
+    /// <p>Deletes faces from a collection. You specify a collection ID and an array of face IDs to remove from the collection.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteFaces</code> action.</p>
+    ///
+    /// See `Rekognition::delete_faces`.
+    pub fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
+        self.runtime.block_on(self.client.delete_faces(input))
+    }
+
+    /// <p>Deletes an Amazon Rekognition Custom Labels project. To delete a project you must first delete all models associated with the project. To delete a model, see <a>DeleteProjectVersion</a>.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteProject</code> action. </p>
+    ///
+    /// See `Rekognition::delete_project`.
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// <p>Deletes an Amazon Rekognition Custom Labels model. </p> <p>You can't delete a model if it is running or if it is training. To check the status of a model, use the <code>Status</code> field returned from <a>DescribeProjectVersions</a>. To stop a running model call <a>StopProjectVersion</a>. If the model is training, wait until it finishes.</p> <p>This operation requires permissions to perform the <code>rekognition:DeleteProjectVersion</code> action. </p>
+    ///
+    /// See `Rekognition::delete_project_version`.
+    pub fn delete_project_version(
+        &self,
+        input: DeleteProjectVersionRequest,
+    ) -> Result<DeleteProjectVersionResponse, RusotoError<DeleteProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_project_version(input))
+    }
+
+    /// <p>Deletes the stream processor identified by <code>Name</code>. You assign the value for <code>Name</code> when you create the stream processor with <a>CreateStreamProcessor</a>. You might not be able to use the same name for a stream processor for a few seconds after calling <code>DeleteStreamProcessor</code>.</p>
+    ///
+    /// See `Rekognition::delete_stream_processor`.
+    pub fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.delete_stream_processor(input))
+    }
+
+    /// <p>Describes the specified collection. You can use <code>DescribeCollection</code> to get information, such as the number of faces indexed into a collection and the version of the model used by the collection for face detection.</p> <p>For more information, see Describing a Collection in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::describe_collection`.
+    pub fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
+        self.runtime
+            .block_on(self.client.describe_collection(input))
+    }
+
+    /// <p>Lists and describes the models in an Amazon Rekognition Custom Labels project. You can specify up to 10 model versions in <code>ProjectVersionArns</code>. If you don't specify a value, descriptions for all models are returned.</p> <p>This operation requires permissions to perform the <code>rekognition:DescribeProjectVersions</code> action.</p>
+    ///
+    /// See `Rekognition::describe_project_versions`.
+    pub fn describe_project_versions(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_project_versions(input))
+    }
+
+    /// <p>Lists and gets information about your Amazon Rekognition Custom Labels projects.</p> <p>This operation requires permissions to perform the <code>rekognition:DescribeProjects</code> action.</p>
+    ///
+    /// See `Rekognition::describe_projects`.
+    pub fn describe_projects(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
+        self.runtime.block_on(self.client.describe_projects(input))
+    }
+
+    /// <p>Provides information about a stream processor created by <a>CreateStreamProcessor</a>. You can get information about the input and output streams, the input parameters for the face recognition being performed, and the current status of the stream processor.</p>
+    ///
+    /// See `Rekognition::describe_stream_processor`.
+    pub fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.describe_stream_processor(input))
+    }
+
+    /// <p>Detects custom labels in a supplied image by using an Amazon Rekognition Custom Labels model. </p> <p>You specify which version of a model version to use by using the <code>ProjectVersionArn</code> input parameter. </p> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object that the model version detects on an image, the API returns a (<code>CustomLabel</code>) object in an array (<code>CustomLabels</code>). Each <code>CustomLabel</code> object provides the label name (<code>Name</code>), the level of confidence that the image contains the object (<code>Confidence</code>), and object location information, if it exists, for the label on the image (<code>Geometry</code>). </p> <p>During training model calculates a threshold value that determines if a prediction for a label is true. By default, <code>DetectCustomLabels</code> doesn't return labels whose confidence value is below the model's calculated threshold value. To filter labels that are returned, specify a value for <code>MinConfidence</code> that is higher than the model's calculated threshold. You can get the model's calculated threshold from the model's training results shown in the Amazon Rekognition Custom Labels console. To get all labels, regardless of confidence, specify a <code>MinConfidence</code> value of 0. </p> <p>You can also add the <code>MaxResults</code> parameter to limit the number of labels returned. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectCustomLabels</code> action. </p>
+    ///
+    /// See `Rekognition::detect_custom_labels`.
+    pub fn detect_custom_labels(
+        &self,
+        input: DetectCustomLabelsRequest,
+    ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
+        self.runtime
+            .block_on(self.client.detect_custom_labels(input))
+    }
+
+    /// <p>Detects faces within an image that is provided as input.</p> <p> <code>DetectFaces</code> detects the 100 largest faces in the image. For each face detected, the operation returns face details. These details include a bounding box of the face, a confidence value (that the bounding box contains a face), and a fixed set of attributes such as facial landmarks (for example, coordinates of eye and mouth), presence of beard, sunglasses, and so on. </p> <p>The face-detection algorithm is most effective on frontal faces. For non-frontal or obscured faces, the algorithm might not detect the faces or might detect faces with lower confidence. </p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <note> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:DetectFaces</code> action. </p>
+    ///
+    /// See `Rekognition::detect_faces`.
+    pub fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
+        self.runtime.block_on(self.client.detect_faces(input))
+    }
+
+    /// <p>Detects instances of real-world entities within an image (JPEG or PNG) provided as input. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; and concepts like landscape, evening, and nature. </p> <p>For an example, see Analyzing Images Stored in an Amazon S3 Bucket in the Amazon Rekognition Developer Guide.</p> <note> <p> <code>DetectLabels</code> does not support the detection of activities. However, activity detection is supported for label detection in videos. For more information, see StartLabelDetection in the Amazon Rekognition Developer Guide.</p> </note> <p>You pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> For each object, scene, and concept the API returns one or more labels. Each label provides the object name, and the level of confidence that the image contains the object. For example, suppose the input image has a lighthouse, the sea, and a rock. The response includes all three labels, one for each object. </p> <p> <code>{Name: lighthouse, Confidence: 98.4629}</code> </p> <p> <code>{Name: rock,Confidence: 79.2097}</code> </p> <p> <code> {Name: sea,Confidence: 75.061}</code> </p> <p>In the preceding example, the operation returns one label for each of the three objects. The operation can also return multiple labels for the same object in the image. For example, if the input image shows a flower (for example, a tulip), the operation might return the following three labels. </p> <p> <code>{Name: flower,Confidence: 99.0562}</code> </p> <p> <code>{Name: plant,Confidence: 99.0562}</code> </p> <p> <code>{Name: tulip,Confidence: 99.0562}</code> </p> <p>In this example, the detection algorithm more precisely identifies the flower as a tulip.</p> <p>In response, the API returns an array of labels. In addition, the response also includes the orientation correction. Optionally, you can specify <code>MinConfidence</code> to control the confidence threshold for the labels returned. The default is 55%. You can also add the <code>MaxLabels</code> parameter to limit the number of labels returned. </p> <note> <p>If the object detected is a person, the operation doesn't provide the same facial details that the <a>DetectFaces</a> operation provides.</p> </note> <p> <code>DetectLabels</code> returns bounding boxes for instances of common object labels in an array of <a>Instance</a> objects. An <code>Instance</code> object contains a <a>BoundingBox</a> object, for the location of the label on the image. It also includes the confidence by which the bounding box was detected.</p> <p> <code>DetectLabels</code> also returns a hierarchical taxonomy of detected labels. For example, a detected car might be assigned the label <i>car</i>. The label <i>car</i> has two parent labels: <i>Vehicle</i> (its parent) and <i>Transportation</i> (its grandparent). The response returns the entire list of ancestors for a label. Each ancestor is a unique label in the response. In the previous example, <i>Car</i>, <i>Vehicle</i>, and <i>Transportation</i> are returned as unique labels in the response. </p> <p>This is a stateless API operation. That is, the operation does not persist any data.</p> <p>This operation requires permissions to perform the <code>rekognition:DetectLabels</code> action. </p>
+    ///
+    /// See `Rekognition::detect_labels`.
+    pub fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
+        self.runtime.block_on(self.client.detect_labels(input))
+    }
+
+    /// <p>Detects unsafe content in a specified JPEG or PNG format image. Use <code>DetectModerationLabels</code> to moderate images depending on your requirements. For example, you might want to filter images that contain nudity, but not images containing suggestive content.</p> <p>To filter images, use the labels returned by <code>DetectModerationLabels</code> to determine which types of content are appropriate.</p> <p>For information about moderation labels, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p>
+    ///
+    /// See `Rekognition::detect_moderation_labels`.
+    pub fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
+        self.runtime
+            .block_on(self.client.detect_moderation_labels(input))
+    }
+
+    /// <p>Detects text in the input image and converts it into machine-readable text.</p> <p>Pass the input image as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, you must pass it as a reference to an image in an Amazon S3 bucket. For the AWS CLI, passing image bytes is not supported. The image must be either a .png or .jpeg formatted file. </p> <p>The <code>DetectText</code> operation returns text in an array of <a>TextDetection</a> elements, <code>TextDetections</code>. Each <code>TextDetection</code> element provides information about a single word or line of text that was detected in the image. </p> <p>A word is one or more ISO basic latin script characters that are not separated by spaces. <code>DetectText</code> can detect up to 50 words in an image.</p> <p>A line is a string of equally spaced words. A line isn't necessarily a complete sentence. For example, a driver's license number is detected as a line. A line ends when there is no aligned text after it. Also, a line ends when there is a large gap between words, relative to the length of the words. This means, depending on the gap between words, Amazon Rekognition may detect multiple lines in text aligned in the same direction. Periods don't represent the end of a line. If a sentence spans multiple lines, the <code>DetectText</code> operation returns multiple lines.</p> <p>To determine whether a <code>TextDetection</code> element is a line of text or a word, use the <code>TextDetection</code> object <code>Type</code> field. </p> <p>To be detected, text must be within +/- 90 degrees orientation of the horizontal axis.</p> <p>For more information, see DetectText in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::detect_text`.
+    pub fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
+        self.runtime.block_on(self.client.detect_text(input))
+    }
+
+    /// <p>Gets the name and additional information about a celebrity based on his or her Amazon Rekognition ID. The additional information is returned as an array of URLs. If there is no additional information about the celebrity, this list is empty.</p> <p>For more information, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:GetCelebrityInfo</code> action. </p>
+    ///
+    /// See `Rekognition::get_celebrity_info`.
+    pub fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
+        self.runtime.block_on(self.client.get_celebrity_info(input))
+    }
+
+    /// <p>Gets the celebrity recognition results for a Amazon Rekognition Video analysis started by <a>StartCelebrityRecognition</a>.</p> <p>Celebrity recognition in a video is an asynchronous operation. Analysis is started by a call to <a>StartCelebrityRecognition</a> which returns a job identifier (<code>JobId</code>). When the celebrity recognition operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartCelebrityRecognition</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetCelebrityDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityDetection</code>. </p> <p>For more information, see Working With Stored Videos in the Amazon Rekognition Developer Guide.</p> <p> <code>GetCelebrityRecognition</code> returns detected celebrities and the time(s) they are detected in an array (<code>Celebrities</code>) of <a>CelebrityRecognition</a> objects. Each <code>CelebrityRecognition</code> contains information about the celebrity in a <a>CelebrityDetail</a> object and the time, <code>Timestamp</code>, the celebrity was detected. </p> <note> <p> <code>GetCelebrityRecognition</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Celebrities</code> array is sorted by time (milliseconds from the start of the video). You can also sort the array by celebrity by specifying the value <code>ID</code> in the <code>SortBy</code> input parameter.</p> <p>The <code>CelebrityDetail</code> object includes the celebrity identifer and additional information urls. If you don't store the additional information urls, you can get them later by calling <a>GetCelebrityInfo</a> with the celebrity identifer.</p> <p>No information is returned for faces not recognized as celebrities.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetCelebrityDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetCelebrityRecognition</code>.</p>
+    ///
+    /// See `Rekognition::get_celebrity_recognition`.
+    pub fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
+        self.runtime
+            .block_on(self.client.get_celebrity_recognition(input))
+    }
+
+    /// <p>Gets the unsafe content analysis results for a Amazon Rekognition Video analysis started by <a>StartContentModeration</a>.</p> <p>Unsafe content analysis of a video is an asynchronous operation. You start analysis by calling <a>StartContentModeration</a> which returns a job identifier (<code>JobId</code>). When analysis finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartContentModeration</code>. To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetContentModeration</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Working with Stored Videos in the Amazon Rekognition Devlopers Guide.</p> <p> <code>GetContentModeration</code> returns detected unsafe content labels, and the time they are detected, in an array, <code>ModerationLabels</code>, of <a>ContentModerationDetection</a> objects. </p> <p>By default, the moderated labels are returned sorted by time, in milliseconds from the start of the video. You can also sort them by moderated label by specifying <code>NAME</code> for the <code>SortBy</code> input parameter. </p> <p>Since video analysis can return a large number of results, use the <code>MaxResults</code> parameter to limit the number of labels returned in a single call to <code>GetContentModeration</code>. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetContentModeration</code> and populate the <code>NextToken</code> request parameter with the value of <code>NextToken</code> returned from the previous call to <code>GetContentModeration</code>.</p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::get_content_moderation`.
+    pub fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
+        self.runtime
+            .block_on(self.client.get_content_moderation(input))
+    }
+
+    /// <p>Gets face detection results for a Amazon Rekognition Video analysis started by <a>StartFaceDetection</a>.</p> <p>Face detection with Amazon Rekognition Video is an asynchronous operation. You start face detection by calling <a>StartFaceDetection</a> which returns a job identifier (<code>JobId</code>). When the face detection operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceDetection</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p> <code>GetFaceDetection</code> returns an array of detected faces (<code>Faces</code>) sorted by the time the faces were detected. </p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetFaceDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetFaceDetection</code>.</p>
+    ///
+    /// See `Rekognition::get_face_detection`.
+    pub fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
+        self.runtime.block_on(self.client.get_face_detection(input))
+    }
+
+    /// <p>Gets the face search results for Amazon Rekognition Video face search started by <a>StartFaceSearch</a>. The search returns faces in a collection that match the faces of persons detected in a video. It also includes the time(s) that faces are matched in the video.</p> <p>Face search in a video is an asynchronous operation. You start face search by calling to <a>StartFaceSearch</a> which returns a job identifier (<code>JobId</code>). When the search operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartFaceSearch</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetFaceSearch</code> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>.</p> <p>For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>The search results are retured in an array, <code>Persons</code>, of <a>PersonMatch</a> objects. Each<code>PersonMatch</code> element contains details about the matching faces in the input collection, person information (facial attributes, bounding boxes, and person identifer) for the matched person, and the time the person was matched in the video.</p> <note> <p> <code>GetFaceSearch</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. For more information, see FaceDetail in the Amazon Rekognition Developer Guide. </p> </note> <p>By default, the <code>Persons</code> array is sorted by the time, in milliseconds from the start of the video, persons are matched. You can also sort by persons by specifying <code>INDEX</code> for the <code>SORTBY</code> input parameter.</p>
+    ///
+    /// See `Rekognition::get_face_search`.
+    pub fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
+        self.runtime.block_on(self.client.get_face_search(input))
+    }
+
+    /// <p>Gets the label detection results of a Amazon Rekognition Video analysis started by <a>StartLabelDetection</a>. </p> <p>The label detection operation is started by a call to <a>StartLabelDetection</a> which returns a job identifier (<code>JobId</code>). When the label detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartlabelDetection</code>. To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p> <code>GetLabelDetection</code> returns an array of detected labels (<code>Labels</code>) sorted by the time the labels were detected. You can also sort by the label name by specifying <code>NAME</code> for the <code>SortBy</code> input parameter.</p> <p>The labels returned include the label name, the percentage confidence in the accuracy of the detected label, and the time the label was detected in the video.</p> <p>The returned labels also include bounding box information for common objects, a hierarchical taxonomy of detected labels, and the version of the label model used for detection.</p> <p>Use MaxResults parameter to limit the number of labels returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetlabelDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetLabelDetection</code>.</p>
+    ///
+    /// See `Rekognition::get_label_detection`.
+    pub fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
+        self.runtime
+            .block_on(self.client.get_label_detection(input))
+    }
+
+    /// <p>Gets the path tracking results of a Amazon Rekognition Video analysis started by <a>StartPersonTracking</a>.</p> <p>The person path tracking operation is started by a call to <code>StartPersonTracking</code> which returns a job identifier (<code>JobId</code>). When the operation finishes, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartPersonTracking</code>.</p> <p>To get the results of the person path tracking operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p> <p> <code>GetPersonTracking</code> returns an array, <code>Persons</code>, of tracked persons and the time(s) their paths were tracked in the video. </p> <note> <p> <code>GetPersonTracking</code> only returns the default facial attributes (<code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>). The other facial attributes listed in the <code>Face</code> object of the following response syntax are not returned. </p> <p>For more information, see FaceDetail in the Amazon Rekognition Developer Guide.</p> </note> <p>By default, the array is sorted by the time(s) a person's path is tracked in the video. You can sort by tracked persons by specifying <code>INDEX</code> for the <code>SortBy</code> input parameter.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of items returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetPersonTracking</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetPersonTracking</code>.</p>
+    ///
+    /// See `Rekognition::get_person_tracking`.
+    pub fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
+        self.runtime
+            .block_on(self.client.get_person_tracking(input))
+    }
+
+    /// <p>Gets the segment detection results of a Amazon Rekognition Video analysis started by <a>StartSegmentDetection</a>.</p> <p>Segment detection with Amazon Rekognition Video is an asynchronous operation. You start segment detection by calling <a>StartSegmentDetection</a> which returns a job identifier (<code>JobId</code>). When the segment detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartSegmentDetection</code>. To get the results of the segment detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. if so, call <code>GetSegmentDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call of <code>StartSegmentDetection</code>.</p> <p> <code>GetSegmentDetection</code> returns detected segments in an array (<code>Segments</code>) of <a>SegmentDetection</a> objects. <code>Segments</code> is sorted by the segment types specified in the <code>SegmentTypes</code> input parameter of <code>StartSegmentDetection</code>. Each element of the array includes the detected segment, the precentage confidence in the acuracy of the detected segment, the type of the segment, and the frame in which the segment was detected.</p> <p>Use <code>SelectedSegmentTypes</code> to find out the type of segment detection requested in the call to <code>StartSegmentDetection</code>.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of segment detections returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetSegmentDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetSegmentDetection</code>.</p> <p>For more information, see Detecting Video Segments in Stored Video in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::get_segment_detection`.
+    pub fn get_segment_detection(
+        &self,
+        input: GetSegmentDetectionRequest,
+    ) -> Result<GetSegmentDetectionResponse, RusotoError<GetSegmentDetectionError>> {
+        self.runtime
+            .block_on(self.client.get_segment_detection(input))
+    }
+
+    /// <p>Gets the text detection results of a Amazon Rekognition Video analysis started by <a>StartTextDetection</a>.</p> <p>Text detection with Amazon Rekognition Video is an asynchronous operation. You start text detection by calling <a>StartTextDetection</a> which returns a job identifier (<code>JobId</code>) When the text detection operation finishes, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic registered in the initial call to <code>StartTextDetection</code>. To get the results of the text detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. if so, call <code>GetTextDetection</code> and pass the job identifier (<code>JobId</code>) from the initial call of <code>StartLabelDetection</code>.</p> <p> <code>GetTextDetection</code> returns an array of detected text (<code>TextDetections</code>) sorted by the time the text was detected, up to 50 words per frame of video.</p> <p>Each element of the array includes the detected text, the precentage confidence in the acuracy of the detected text, the time the text was detected, bounding box information for where the text was located, and unique identifiers for words and their lines.</p> <p>Use MaxResults parameter to limit the number of text detections returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetTextDetection</code> and populate the <code>NextToken</code> request parameter with the token value returned from the previous call to <code>GetTextDetection</code>.</p>
+    ///
+    /// See `Rekognition::get_text_detection`.
+    pub fn get_text_detection(
+        &self,
+        input: GetTextDetectionRequest,
+    ) -> Result<GetTextDetectionResponse, RusotoError<GetTextDetectionError>> {
+        self.runtime.block_on(self.client.get_text_detection(input))
+    }
+
+    /// <p>Detects faces in the input image and adds them to the specified collection. </p> <p>Amazon Rekognition doesn't save the actual faces that are detected. Instead, the underlying detection algorithm first detects the faces in the input image. For each face, the algorithm extracts facial features into a feature vector, and stores it in the backend database. Amazon Rekognition uses feature vectors when it performs face match and search operations using the <a>SearchFaces</a> and <a>SearchFacesByImage</a> operations.</p> <p>For more information, see Adding Faces to a Collection in the Amazon Rekognition Developer Guide.</p> <p>To get the number of faces in a collection, call <a>DescribeCollection</a>. </p> <p>If you're using version 1.0 of the face detection model, <code>IndexFaces</code> indexes the 15 largest faces in the input image. Later versions of the face detection model index the 100 largest faces in the input image. </p> <p>If you're using version 4 or later of the face model, image orientation information is not returned in the <code>OrientationCorrection</code> field. </p> <p>To determine which version of the model you're using, call <a>DescribeCollection</a> and supply the collection ID. You can also get the model version from the value of <code>FaceModelVersion</code> in the response from <code>IndexFaces</code> </p> <p>For more information, see Model Versioning in the Amazon Rekognition Developer Guide.</p> <p>If you provide the optional <code>ExternalImageId</code> for the input image you provided, Amazon Rekognition associates this ID with all faces that it detects. When you call the <a>ListFaces</a> operation, the response returns the external ID. You can use this external image ID to create a client-side index to associate the faces with each image. You can then use the index to find all faces in an image.</p> <p>You can specify the maximum number of faces to index with the <code>MaxFaces</code> input parameter. This is useful when you want to index the largest faces in an image and don't want to index smaller faces, such as those belonging to people standing in the background.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. By default, <code>IndexFaces</code> chooses the quality bar that's used to filter faces. You can also explicitly choose the quality bar. Use <code>QualityFilter</code>, to set the quality bar by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. </p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>Information about faces detected in an image, but not indexed, is returned in an array of <a>UnindexedFace</a> objects, <code>UnindexedFaces</code>. Faces aren't indexed for reasons such as:</p> <ul> <li> <p>The number of faces detected exceeds the value of the <code>MaxFaces</code> request parameter.</p> </li> <li> <p>The face is too small compared to the image dimensions.</p> </li> <li> <p>The face is too blurry.</p> </li> <li> <p>The image is too dark.</p> </li> <li> <p>The face has an extreme pose.</p> </li> <li> <p>The face doesn’t have enough detail to be suitable for face search.</p> </li> </ul> <p>In response, the <code>IndexFaces</code> operation returns an array of metadata for all detected faces, <code>FaceRecords</code>. This includes: </p> <ul> <li> <p>The bounding box, <code>BoundingBox</code>, of the detected face. </p> </li> <li> <p>A confidence value, <code>Confidence</code>, which indicates the confidence that the bounding box contains a face.</p> </li> <li> <p>A face ID, <code>FaceId</code>, assigned by the service for each face that's detected and stored.</p> </li> <li> <p>An image ID, <code>ImageId</code>, assigned by the service for the input image.</p> </li> </ul> <p>If you request all facial attributes (by using the <code>detectionAttributes</code> parameter), Amazon Rekognition returns detailed facial attributes, such as facial landmarks (for example, location of eye and mouth) and other facial attributes. If you provide the same image, specify the same collection, and use the same external ID in the <code>IndexFaces</code> operation, Amazon Rekognition doesn't save duplicate face metadata.</p> <p/> <p>The input image is passed either as base64-encoded image bytes, or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes isn't supported. The image must be formatted as a PNG or JPEG file. </p> <p>This operation requires permissions to perform the <code>rekognition:IndexFaces</code> action.</p>
+    ///
+    /// See `Rekognition::index_faces`.
+    pub fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
+        self.runtime.block_on(self.client.index_faces(input))
+    }
+
+    /// <p>Returns list of collection IDs in your account. If the result is truncated, the response also provides a <code>NextToken</code> that you can use in the subsequent request to fetch the next set of collection IDs.</p> <p>For an example, see Listing Collections in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListCollections</code> action.</p>
+    ///
+    /// See `Rekognition::list_collections`.
+    pub fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
+        self.runtime.block_on(self.client.list_collections(input))
+    }
+
+    /// <p>Returns metadata for faces in the specified collection. This metadata includes information such as the bounding box coordinates, the confidence (that the bounding box contains a face), and face ID. For an example, see Listing Faces in a Collection in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:ListFaces</code> action.</p>
+    ///
+    /// See `Rekognition::list_faces`.
+    pub fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
+        self.runtime.block_on(self.client.list_faces(input))
+    }
+
+    /// <p>Gets a list of stream processors that you have created with <a>CreateStreamProcessor</a>. </p>
+    ///
+    /// See `Rekognition::list_stream_processors`.
+    pub fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
+        self.runtime
+            .block_on(self.client.list_stream_processors(input))
+    }
+
+    /// <p>Returns an array of celebrities recognized in the input image. For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide. </p> <p> <code>RecognizeCelebrities</code> returns the 100 largest faces in the image. It lists recognized celebrities in the <code>CelebrityFaces</code> array and unrecognized faces in the <code>UnrecognizedFaces</code> array. <code>RecognizeCelebrities</code> doesn't return celebrities whose faces aren't among the largest 100 faces in the image.</p> <p>For each celebrity recognized, <code>RecognizeCelebrities</code> returns a <code>Celebrity</code> object. The <code>Celebrity</code> object contains the celebrity name, ID, URL links to additional information, match confidence, and a <code>ComparedFace</code> object that you can use to locate the celebrity's face on the image.</p> <p>Amazon Rekognition doesn't retain information about which images a celebrity has been recognized in. Your application must store this information and use the <code>Celebrity</code> ID property as a unique identifier for the celebrity. If you don't store the celebrity name or additional information URLs returned by <code>RecognizeCelebrities</code>, you will need the ID to identify the celebrity in a call to the <a>GetCelebrityInfo</a> operation.</p> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p>For an example, see Recognizing Celebrities in an Image in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:RecognizeCelebrities</code> operation.</p>
+    ///
+    /// See `Rekognition::recognize_celebrities`.
+    pub fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
+        self.runtime
+            .block_on(self.client.recognize_celebrities(input))
+    }
+
+    /// <p>For a given input face ID, searches for matching faces in the collection the face belongs to. You get a face ID when you add a face to the collection using the <a>IndexFaces</a> operation. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>You can also search faces without indexing faces by using the <code>SearchFacesByImage</code> operation.</p> </note> <p> The operation response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match that is found. Along with the metadata, the response also includes a <code>confidence</code> value for each face match, indicating the confidence that the specific face matches the input face. </p> <p>For an example, see Searching for a Face Using Its Face ID in the Amazon Rekognition Developer Guide.</p> <p>This operation requires permissions to perform the <code>rekognition:SearchFaces</code> action.</p>
+    ///
+    /// See `Rekognition::search_faces`.
+    pub fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
+        self.runtime.block_on(self.client.search_faces(input))
+    }
+
+    /// <p>For a given input image, first detects the largest face in the image, and then searches the specified collection for matching faces. The operation compares the features of the input face with faces in the specified collection. </p> <note> <p>To search for all faces in an input image, you might first call the <a>IndexFaces</a> operation, and then use the face IDs returned in subsequent calls to the <a>SearchFaces</a> operation. </p> <p> You can also call the <code>DetectFaces</code> operation and use the bounding boxes in the response to make face crops, which then you can pass in to the <code>SearchFacesByImage</code> operation. </p> </note> <p>You pass the input image either as base64-encoded image bytes or as a reference to an image in an Amazon S3 bucket. If you use the AWS CLI to call Amazon Rekognition operations, passing image bytes is not supported. The image must be either a PNG or JPEG formatted file. </p> <p> The response returns an array of faces that match, ordered by similarity score with the highest similarity first. More specifically, it is an array of metadata for each face match found. Along with the metadata, the response also includes a <code>similarity</code> indicating how similar the face is to the input face. In the response, the operation also returns the bounding box (and a confidence level that the bounding box contains a face) of the face that Amazon Rekognition used for the input image. </p> <p>For an example, Searching for a Face Using an Image in the Amazon Rekognition Developer Guide.</p> <p>The <code>QualityFilter</code> input parameter allows you to filter out detected faces that don’t meet a required quality bar. The quality bar is based on a variety of common use cases. Use <code>QualityFilter</code> to set the quality bar for filtering by specifying <code>LOW</code>, <code>MEDIUM</code>, or <code>HIGH</code>. If you do not want to filter detected faces, specify <code>NONE</code>. The default value is <code>NONE</code>.</p> <note> <p>To use quality filtering, you need a collection associated with version 3 of the face model or higher. To get the version of the face model associated with a collection, call <a>DescribeCollection</a>. </p> </note> <p>This operation requires permissions to perform the <code>rekognition:SearchFacesByImage</code> action.</p>
+    ///
+    /// See `Rekognition::search_faces_by_image`.
+    pub fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
+        self.runtime
+            .block_on(self.client.search_faces_by_image(input))
+    }
+
+    /// <p>Starts asynchronous recognition of celebrities in a stored video.</p> <p>Amazon Rekognition Video can detect celebrities in a video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartCelebrityRecognition</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When celebrity recognition analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the celebrity recognition analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetCelebrityRecognition</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartCelebrityRecognition</code>. </p> <p>For more information, see Recognizing Celebrities in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::start_celebrity_recognition`.
+    pub fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_celebrity_recognition(input))
+    }
+
+    /// <p> Starts asynchronous detection of unsafe content in a stored video.</p> <p>Amazon Rekognition Video can moderate content in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartContentModeration</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the analysis. When unsafe content analysis is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the unsafe content analysis, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetContentModeration</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartContentModeration</code>. </p> <p>For more information, see Detecting Unsafe Content in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::start_content_moderation`.
+    pub fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
+        self.runtime
+            .block_on(self.client.start_content_moderation(input))
+    }
+
+    /// <p>Starts asynchronous detection of faces in a stored video.</p> <p>Amazon Rekognition Video can detect faces in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When face detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the results of the face detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceDetection</code>.</p> <p>For more information, see Detecting Faces in a Stored Video in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::start_face_detection`.
+    pub fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_face_detection(input))
+    }
+
+    /// <p>Starts the asynchronous search for faces in a collection that match the faces of persons detected in a stored video.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartFaceSearch</code> returns a job identifier (<code>JobId</code>) which you use to get the search results once the search has completed. When searching is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. To get the search results, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetFaceSearch</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartFaceSearch</code>. For more information, see <a>procedure-person-search-videos</a>.</p>
+    ///
+    /// See `Rekognition::start_face_search`.
+    pub fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
+        self.runtime.block_on(self.client.start_face_search(input))
+    }
+
+    /// <p><p>Starts asynchronous detection of labels in a stored video.</p> <p>Amazon Rekognition Video can detect labels in a video. Labels are instances of real-world entities. This includes objects like flower, tree, and table; events like wedding, graduation, and birthday party; concepts like landscape, evening, and nature; and activities like a person getting out of a car or a person skiing.</p> <p>The video must be stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartLabelDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the label detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetLabelDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartLabelDetection</code>.</p> <p/></p>
+    ///
+    /// See `Rekognition::start_label_detection`.
+    pub fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_label_detection(input))
+    }
+
+    /// <p>Starts the asynchronous tracking of a person's path in a stored video.</p> <p>Amazon Rekognition Video can track the path of people in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartPersonTracking</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When label detection is finished, Amazon Rekognition publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>. </p> <p>To get the results of the person detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetPersonTracking</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartPersonTracking</code>.</p>
+    ///
+    /// See `Rekognition::start_person_tracking`.
+    pub fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
+        self.runtime
+            .block_on(self.client.start_person_tracking(input))
+    }
+
+    /// <p>Starts the running of the version of a model. Starting a model takes a while to complete. To check the current state of the model, use <a>DescribeProjectVersions</a>.</p> <p>Once the model is running, you can detect custom labels in new images by calling <a>DetectCustomLabels</a>.</p> <note> <p>You are charged for the amount of time that the model is running. To stop a running model, call <a>StopProjectVersion</a>.</p> </note> <p>This operation requires permissions to perform the <code>rekognition:StartProjectVersion</code> action.</p>
+    ///
+    /// See `Rekognition::start_project_version`.
+    pub fn start_project_version(
+        &self,
+        input: StartProjectVersionRequest,
+    ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.start_project_version(input))
+    }
+
+    /// <p>Starts asynchronous detection of segment detection in a stored video.</p> <p>Amazon Rekognition Video can detect segments in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartSegmentDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When segment detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>You can use the <code>Filters</code> (<a>StartSegmentDetectionFilters</a>) input parameter to specify the minimum detection confidence returned in the response. Within <code>Filters</code>, use <code>ShotFilter</code> (<a>StartShotDetectionFilter</a>) to filter detected shots. Use <code>TechnicalCueFilter</code> (<a>StartTechnicalCueDetectionFilter</a>) to filter technical cues. </p> <p>To get the results of the segment detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. if so, call <a>GetSegmentDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartSegmentDetection</code>. </p> <p>For more information, see Detecting Video Segments in Stored Video in the Amazon Rekognition Developer Guide.</p>
+    ///
+    /// See `Rekognition::start_segment_detection`.
+    pub fn start_segment_detection(
+        &self,
+        input: StartSegmentDetectionRequest,
+    ) -> Result<StartSegmentDetectionResponse, RusotoError<StartSegmentDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_segment_detection(input))
+    }
+
+    /// <p>Starts processing a stream processor. You create a stream processor by calling <a>CreateStreamProcessor</a>. To tell <code>StartStreamProcessor</code> which stream processor to start, use the value of the <code>Name</code> field specified in the call to <code>CreateStreamProcessor</code>.</p>
+    ///
+    /// See `Rekognition::start_stream_processor`.
+    pub fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.start_stream_processor(input))
+    }
+
+    /// <p>Starts asynchronous detection of text in a stored video.</p> <p>Amazon Rekognition Video can detect text in a video stored in an Amazon S3 bucket. Use <a>Video</a> to specify the bucket name and the filename of the video. <code>StartTextDetection</code> returns a job identifier (<code>JobId</code>) which you use to get the results of the operation. When text detection is finished, Amazon Rekognition Video publishes a completion status to the Amazon Simple Notification Service topic that you specify in <code>NotificationChannel</code>.</p> <p>To get the results of the text detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. if so, call <a>GetTextDetection</a> and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartTextDetection</code>. </p>
+    ///
+    /// See `Rekognition::start_text_detection`.
+    pub fn start_text_detection(
+        &self,
+        input: StartTextDetectionRequest,
+    ) -> Result<StartTextDetectionResponse, RusotoError<StartTextDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_text_detection(input))
+    }
+
+    /// <p>Stops a running model. The operation might take a while to complete. To check the current status, call <a>DescribeProjectVersions</a>. </p>
+    ///
+    /// See `Rekognition::stop_project_version`.
+    pub fn stop_project_version(
+        &self,
+        input: StopProjectVersionRequest,
+    ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.stop_project_version(input))
+    }
+
+    /// <p>Stops a running stream processor that was created by <a>CreateStreamProcessor</a>.</p>
+    ///
+    /// See `Rekognition::stop_stream_processor`.
+    pub fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.stop_stream_processor(input))
+    }
+}
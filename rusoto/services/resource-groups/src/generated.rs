@@ -447,19 +447,22 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateGroupError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(CreateGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::MethodNotAllowed(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -503,22 +506,25 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteGroupError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(DeleteGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::MethodNotAllowed(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -563,22 +569,22 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetGroupError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetGroupError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetGroupError::InternalServerError(err.msg))
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, GetGroupError::MethodNotAllowed(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGroupError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -623,22 +629,28 @@ impl GetGroupQueryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupQueryError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetGroupQueryError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupQueryError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetGroupQueryError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupQueryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGroupQueryError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupQueryError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGroupQueryError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupQueryError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetGroupQueryError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupQueryError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetGroupQueryError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -683,22 +695,22 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetTagsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetTagsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetTagsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetTagsError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, GetTagsError::InternalServerError(err.msg))
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetTagsError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, GetTagsError::MethodNotAllowed(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTagsError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, GetTagsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -745,27 +757,37 @@ impl ListGroupResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupResourcesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListGroupResourcesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGroupResourcesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListGroupResourcesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGroupResourcesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupResourcesError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListGroupResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupResourcesError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGroupResourcesError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListGroupResourcesError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupResourcesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGroupResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupResourcesError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -809,19 +831,22 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGroupsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGroupsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupsError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListGroupsError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::MethodNotAllowed(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -865,22 +890,31 @@ impl SearchResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchResourcesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, SearchResourcesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchResourcesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, SearchResourcesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SearchResourcesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchResourcesError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(SearchResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchResourcesError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SearchResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchResourcesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, SearchResourcesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -925,18 +959,22 @@ impl TagError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::service(&res, TagError::Forbidden(err.msg))
                 }
-                "ForbiddenException" => return RusotoError::Service(TagError::Forbidden(err.msg)),
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, TagError::InternalServerError(err.msg))
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(TagError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, TagError::MethodNotAllowed(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, TagError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(TagError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TagError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -981,20 +1019,22 @@ impl UntagError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, UntagError::InternalServerError(err.msg))
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UntagError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, UntagError::MethodNotAllowed(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::service(&res, UntagError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(UntagError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UntagError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1039,22 +1079,25 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGroupError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::MethodNotAllowed(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1099,24 +1142,31 @@ impl UpdateGroupQueryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateGroupQueryError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateGroupQueryError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupQueryError::InternalServerError(err.msg),
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::MethodNotAllowed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupQueryError::MethodNotAllowed(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateGroupQueryError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGroupQueryError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1226,6 +1276,7 @@ impl ResourceGroupsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1241,6 +1292,7 @@ impl ResourceGroupsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ResourceGroupsClient {
         ResourceGroupsClient { client, region }
     }
@@ -1257,6 +1309,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1289,6 +1344,9 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1317,6 +1375,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("GetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1345,6 +1406,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("GetGroupQuery");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1373,6 +1437,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("GetTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1404,6 +1471,9 @@ impl ResourceGroups for ResourceGroupsClient {
         );
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("ListGroupResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1444,6 +1514,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups-list";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("ListGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1484,6 +1557,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/resources/search";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("SearchResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1512,6 +1588,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("Tag");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1541,6 +1620,9 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("PATCH", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("Untag");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1572,6 +1654,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("UpdateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1603,6 +1688,9 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/groups/{group_name}/query", group_name = input.group_name);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation_name("UpdateGroupQuery");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1625,3 +1713,168 @@ impl ResourceGroups for ResourceGroupsClient {
         }
     }
 }
+
+/// A blocking client for the Resource Groups API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ResourceGroupsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ResourceGroupsBlockingClient {
+    client: ResourceGroupsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ResourceGroupsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ResourceGroupsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ResourceGroupsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ResourceGroupsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ResourceGroupsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient {
+            client: ResourceGroupsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a group with a specified name, description, and resource query.</p>
+    ///
+    /// See `ResourceGroups::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> Result<CreateGroupOutput, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p>Deletes a specified resource group. Deleting a resource group does not delete resources that are members of the group; it only deletes the group structure.</p>
+    ///
+    /// See `ResourceGroups::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> Result<DeleteGroupOutput, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Returns information about a specified resource group.</p>
+    ///
+    /// See `ResourceGroups::get_group`.
+    pub fn get_group(
+        &self,
+        input: GetGroupInput,
+    ) -> Result<GetGroupOutput, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// <p>Returns the resource query associated with the specified resource group.</p>
+    ///
+    /// See `ResourceGroups::get_group_query`.
+    pub fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> Result<GetGroupQueryOutput, RusotoError<GetGroupQueryError>> {
+        self.runtime.block_on(self.client.get_group_query(input))
+    }
+
+    /// <p>Returns a list of tags that are associated with a resource group, specified by an ARN.</p>
+    ///
+    /// See `ResourceGroups::get_tags`.
+    pub fn get_tags(
+        &self,
+        input: GetTagsInput,
+    ) -> Result<GetTagsOutput, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// <p>Returns a list of ARNs of resources that are members of a specified resource group.</p>
+    ///
+    /// See `ResourceGroups::list_group_resources`.
+    pub fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> Result<ListGroupResourcesOutput, RusotoError<ListGroupResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_group_resources(input))
+    }
+
+    /// <p>Returns a list of existing resource groups in your account.</p>
+    ///
+    /// See `ResourceGroups::list_groups`.
+    pub fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> Result<ListGroupsOutput, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// <p>Returns a list of AWS resource identifiers that matches a specified query. The query uses the same format as a resource query in a CreateGroup or UpdateGroupQuery operation.</p>
+    ///
+    /// See `ResourceGroups::search_resources`.
+    pub fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> Result<SearchResourcesOutput, RusotoError<SearchResourcesError>> {
+        self.runtime.block_on(self.client.search_resources(input))
+    }
+
+    /// <p>Adds tags to a resource group with the specified ARN. Existing tags on a resource group are not changed if they are not specified in the request parameters.</p>
+    ///
+    /// See `ResourceGroups::tag`.
+    pub fn tag(&self, input: TagInput) -> Result<TagOutput, RusotoError<TagError>> {
+        self.runtime.block_on(self.client.tag(input))
+    }
+
+    /// <p>Deletes specified tags from a specified resource.</p>
+    ///
+    /// See `ResourceGroups::untag`.
+    pub fn untag(&self, input: UntagInput) -> Result<UntagOutput, RusotoError<UntagError>> {
+        self.runtime.block_on(self.client.untag(input))
+    }
+
+    /// <p>Updates an existing group with a new or changed description. You cannot update the name of a resource group.</p>
+    ///
+    /// See `ResourceGroups::update_group`.
+    pub fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> Result<UpdateGroupOutput, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// <p>Updates the resource query of a group.</p>
+    ///
+    /// See `ResourceGroups::update_group_query`.
+    pub fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> Result<UpdateGroupQueryOutput, RusotoError<UpdateGroupQueryError>> {
+        self.runtime.block_on(self.client.update_group_query(input))
+    }
+}
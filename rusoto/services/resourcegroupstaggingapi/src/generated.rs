@@ -390,22 +390,28 @@ impl DescribeReportCreationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DescribeReportCreationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReportCreationError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeReportCreationError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReportCreationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeReportCreationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReportCreationError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DescribeReportCreationError::Throttled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeReportCreationError::Throttled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -444,22 +450,28 @@ impl GetComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetComplianceSummaryError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::InternalService(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetComplianceSummaryError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetComplianceSummaryError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::Throttled(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetComplianceSummaryError::Throttled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -498,16 +510,19 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcesError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::InvalidParameter(err.msg))
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetResourcesError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcesError::PaginationTokenExpired(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetResourcesError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -546,16 +561,19 @@ impl GetTagKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagKeysError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTagKeysError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTagKeysError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetTagKeysError::InvalidParameter(err.msg))
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetTagKeysError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTagKeysError::PaginationTokenExpired(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTagKeysError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetTagKeysError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -594,16 +612,19 @@ impl GetTagValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagValuesError::InternalService(err.msg))
+                    return RusotoError::service(&res, GetTagValuesError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTagValuesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetTagValuesError::InvalidParameter(err.msg))
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetTagValuesError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTagValuesError::PaginationTokenExpired(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTagValuesError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetTagValuesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -644,25 +665,31 @@ impl StartReportCreationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartReportCreationError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartReportCreationError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(StartReportCreationError::ConstraintViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartReportCreationError::ConstraintViolation(err.msg),
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartReportCreationError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartReportCreationError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartReportCreationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartReportCreationError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(StartReportCreationError::Throttled(err.msg))
+                    return RusotoError::service(&res, StartReportCreationError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -700,13 +727,13 @@ impl TagResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourcesError::InternalService(err.msg))
+                    return RusotoError::service(&res, TagResourcesError::InternalService(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourcesError::InvalidParameter(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(TagResourcesError::Throttled(err.msg))
+                    return RusotoError::service(&res, TagResourcesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -742,13 +769,19 @@ impl UntagResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourcesError::InternalService(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourcesError::InternalService(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourcesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UntagResourcesError::Throttled(err.msg))
+                    return RusotoError::service(&res, UntagResourcesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -836,6 +869,7 @@ impl ResourceGroupsTaggingApiClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -851,6 +885,7 @@ impl ResourceGroupsTaggingApiClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(
         client: Client,
         region: region::Region,
@@ -866,6 +901,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
     ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeReportCreation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.DescribeReportCreation",
@@ -887,6 +925,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetComplianceSummaryInput,
     ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetComplianceSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetComplianceSummary",
@@ -908,6 +949,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetResourcesInput,
     ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetResources",
@@ -929,6 +973,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetTagKeysInput,
     ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTagKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetTagKeys",
@@ -950,6 +997,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetTagValuesInput,
     ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTagValues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetTagValues",
@@ -971,6 +1021,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: StartReportCreationInput,
     ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartReportCreation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.StartReportCreation",
@@ -992,6 +1045,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: TagResourcesInput,
     ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.TagResources",
@@ -1013,6 +1069,9 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: UntagResourcesInput,
     ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResources");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.UntagResources",
@@ -1028,3 +1087,142 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourcesOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Resource Groups Tagging API API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ResourceGroupsTaggingApiClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ResourceGroupsTaggingApiBlockingClient {
+    client: ResourceGroupsTaggingApiClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ResourceGroupsTaggingApiBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ResourceGroupsTaggingApiBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ResourceGroupsTaggingApiBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ResourceGroupsTaggingApiBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ResourceGroupsTaggingApiBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient {
+            client: ResourceGroupsTaggingApiClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Describes the status of the <code>StartReportCreation</code> operation. </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
+    ///
+    /// See `ResourceGroupsTaggingApi::describe_report_creation`.
+    pub fn describe_report_creation(
+        &self,
+    ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
+        self.runtime
+            .block_on(self.client.describe_report_creation())
+    }
+
+    /// <p>Returns a table that shows counts of resources that are noncompliant with their tag policies.</p> <p>For more information on tag policies, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
+    ///
+    /// See `ResourceGroupsTaggingApi::get_compliance_summary`.
+    pub fn get_compliance_summary(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
+        self.runtime
+            .block_on(self.client.get_compliance_summary(input))
+    }
+
+    /// <p><p>Returns all the tagged or previously tagged resources that are located in the specified Region for the AWS account.</p> <p>Depending on what information you want returned, you can also specify the following:</p> <ul> <li> <p> <i>Filters</i> that specify what tags and resource types you want returned. The response includes all tags that are associated with the requested resources.</p> </li> <li> <p>Information about compliance with the account&#39;s effective tag policy. For more information on tag policies, see <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_tag-policies.html">Tag Policies</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul> <note> <p>You can check the <code>PaginationToken</code> response parameter to determine if a query is complete. Queries occasionally return fewer results on a page than allowed. The <code>PaginationToken</code> response parameter value is <code>null</code> <i>only</i> when there are no more results to display. </p> </note></p>
+    ///
+    /// See `ResourceGroupsTaggingApi::get_resources`.
+    pub fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
+        self.runtime.block_on(self.client.get_resources(input))
+    }
+
+    /// <p>Returns all tag keys in the specified Region for the AWS account.</p>
+    ///
+    /// See `ResourceGroupsTaggingApi::get_tag_keys`.
+    pub fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
+        self.runtime.block_on(self.client.get_tag_keys(input))
+    }
+
+    /// <p>Returns all tag values for the specified key in the specified Region for the AWS account.</p>
+    ///
+    /// See `ResourceGroupsTaggingApi::get_tag_values`.
+    pub fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
+        self.runtime.block_on(self.client.get_tag_values(input))
+    }
+
+    /// <p>Generates a report that lists all tagged resources in accounts across your organization and tells whether each resource is compliant with the effective tag policy. Compliance data is refreshed daily. </p> <p>The generated report is saved to the following location:</p> <p> <code>s3://example-bucket/AwsTagPolicies/o-exampleorgid/YYYY-MM-ddTHH:mm:ssZ/report.csv</code> </p> <p>You can call this operation only from the organization's master account and from the us-east-1 Region.</p>
+    ///
+    /// See `ResourceGroupsTaggingApi::start_report_creation`.
+    pub fn start_report_creation(
+        &self,
+        input: StartReportCreationInput,
+    ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
+        self.runtime
+            .block_on(self.client.start_report_creation(input))
+    }
+
+    /// <p><p>Applies one or more tags to the specified resources. Note the following:</p> <ul> <li> <p>Not all resources can have tags. For a list of services that support tagging, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> <li> <p>Each resource can have up to 50 tags. For other limits, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_tagging.html#tag-conventions">Tag Naming and Usage Conventions</a> in the <i>AWS General Reference.</i> </p> </li> <li> <p>You can only tag resources that are located in the specified Region for the AWS account.</p> </li> <li> <p>To add tags to a resource, you need the necessary permissions for the service that the resource belongs to as well as permissions for adding tags. For more information, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> </ul></p>
+    ///
+    /// See `ResourceGroupsTaggingApi::tag_resources`.
+    pub fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
+        self.runtime.block_on(self.client.tag_resources(input))
+    }
+
+    /// <p><p>Removes the specified tags from the specified resources. When you specify a tag key, the action removes both that key and its associated value. The operation succeeds even if you attempt to remove tags from a resource that were already removed. Note the following:</p> <ul> <li> <p>To remove tags from a resource, you need the necessary permissions for the service that the resource belongs to as well as permissions for removing tags. For more information, see <a href="http://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/Welcome.html">this list</a>.</p> </li> <li> <p>You can only tag resources that are located in the specified Region for the AWS account.</p> </li> </ul></p>
+    ///
+    /// See `ResourceGroupsTaggingApi::untag_resources`.
+    pub fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
+        self.runtime.block_on(self.client.untag_resources(input))
+    }
+}
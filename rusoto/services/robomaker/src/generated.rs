@@ -2474,24 +2474,28 @@ impl BatchDescribeSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDescribeSimulationJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDescribeSimulationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDescribeSimulationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        BatchDescribeSimulationJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2530,20 +2534,28 @@ impl CancelDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelDeploymentJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelDeploymentJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelDeploymentJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelDeploymentJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2582,20 +2594,28 @@ impl CancelSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2634,22 +2654,28 @@ impl CancelSimulationJobBatchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobBatchError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobBatchError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobBatchError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelSimulationJobBatchError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2694,33 +2720,46 @@ impl CreateDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentDeploymentException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::ConcurrentDeployment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentJobError::ConcurrentDeployment(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateDeploymentJobError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDeploymentJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2764,16 +2803,16 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateFleetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::LimitExceeded(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFleetError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2814,19 +2853,22 @@ impl CreateRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRobotError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateRobotError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateRobotError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateRobotError::LimitExceeded(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRobotError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateRobotError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2870,32 +2912,40 @@ impl CreateRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRobotApplicationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotApplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotApplicationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRobotApplicationError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2942,29 +2992,34 @@ impl CreateRobotApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRobotApplicationVersionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRobotApplicationVersionError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRobotApplicationVersionError::InvalidParameter(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotApplicationVersionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotApplicationVersionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotApplicationVersionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRobotApplicationVersionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3014,34 +3069,40 @@ impl CreateSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationError::InvalidParameter(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationApplicationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3090,29 +3151,34 @@ impl CreateSimulationApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationVersionError::IdempotentParameterMismatch(
                             err.msg,
                         ),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationVersionError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationVersionError::InvalidParameter(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationVersionError::LimitExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationApplicationVersionError::Throttling(err.msg),
                     )
                 }
@@ -3170,33 +3236,46 @@ impl CreateSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSimulationJobError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSimulationJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateSimulationJobError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationJobError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSimulationJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3238,13 +3317,13 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::InvalidParameter(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFleetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3280,13 +3359,13 @@ impl DeleteRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRobotError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DeleteRobotError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteRobotError::InvalidParameter(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRobotError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteRobotError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3322,17 +3401,22 @@ impl DeleteRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRobotApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRobotApplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRobotApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3370,19 +3454,22 @@ impl DeleteSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSimulationApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSimulationApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSimulationApplicationError::InvalidParameter(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSimulationApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3420,16 +3507,25 @@ impl DeregisterRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeregisterRobotError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterRobotError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterRobotError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterRobotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeregisterRobotError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeregisterRobotError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3468,22 +3564,28 @@ impl DescribeDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeploymentJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeploymentJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeploymentJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeploymentJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3522,16 +3624,22 @@ impl DescribeFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeFleetError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DescribeFleetError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeFleetError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeFleetError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3570,16 +3678,22 @@ impl DescribeRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRobotError::InternalServer(err.msg))
+                    return RusotoError::service(&res, DescribeRobotError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRobotError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRobotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRobotError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeRobotError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3618,22 +3732,28 @@ impl DescribeRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRobotApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRobotApplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRobotApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRobotApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3674,24 +3794,28 @@ impl DescribeSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSimulationApplicationError::InternalServer(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSimulationApplicationError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSimulationApplicationError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3734,22 +3858,28 @@ impl DescribeSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3788,19 +3918,22 @@ impl DescribeSimulationJobBatchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSimulationJobBatchError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobBatchError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSimulationJobBatchError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobBatchError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSimulationJobBatchError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSimulationJobBatchError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3838,16 +3971,25 @@ impl ListDeploymentJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentJobsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDeploymentJobsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListDeploymentJobsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3886,16 +4028,16 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFleetsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFleetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFleetsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3932,17 +4074,22 @@ impl ListRobotApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRobotApplicationsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRobotApplicationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRobotApplicationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3980,16 +4127,16 @@ impl ListRobotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRobotsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListRobotsError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRobotsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListRobotsError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRobotsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListRobotsError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRobotsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListRobotsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4028,19 +4175,22 @@ impl ListSimulationApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationApplicationsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationApplicationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationApplicationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4074,14 +4224,16 @@ impl ListSimulationJobBatchesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationJobBatchesError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationJobBatchesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationJobBatchesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationJobBatchesError::InvalidParameter(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4116,13 +4268,19 @@ impl ListSimulationJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationJobsError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSimulationJobsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSimulationJobsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListSimulationJobsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4160,20 +4318,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4214,19 +4380,25 @@ impl RegisterRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterRobotError::InternalServer(err.msg))
+                    return RusotoError::service(&res, RegisterRobotError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterRobotError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterRobotError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, RegisterRobotError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterRobotError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterRobotError::Throttling(err.msg))
+                    return RusotoError::service(&res, RegisterRobotError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4268,23 +4440,34 @@ impl RestartSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RestartSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestartSimulationJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestartSimulationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestartSimulationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestartSimulationJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestartSimulationJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestartSimulationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestartSimulationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RestartSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestartSimulationJobError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4326,27 +4509,34 @@ impl StartSimulationJobBatchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartSimulationJobBatchError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSimulationJobBatchError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSimulationJobBatchError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSimulationJobBatchError::LimitExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSimulationJobBatchError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4394,29 +4584,43 @@ impl SyncDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentDeploymentException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::ConcurrentDeployment(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SyncDeploymentJobError::ConcurrentDeployment(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SyncDeploymentJobError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SyncDeploymentJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SyncDeploymentJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SyncDeploymentJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SyncDeploymentJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, SyncDeploymentJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4460,16 +4664,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4508,16 +4712,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4558,27 +4768,34 @@ impl UpdateRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRobotApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRobotApplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRobotApplicationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRobotApplicationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRobotApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4622,29 +4839,34 @@ impl UpdateSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSimulationApplicationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSimulationApplicationError::InvalidParameter(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSimulationApplicationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSimulationApplicationError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSimulationApplicationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4936,6 +5158,7 @@ impl RobomakerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4951,6 +5174,7 @@ impl RobomakerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> RobomakerClient {
         RobomakerClient { client, region }
     }
@@ -4968,6 +5192,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/batchDescribeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("BatchDescribeSimulationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4999,6 +5226,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CancelDeploymentJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5030,6 +5260,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CancelSimulationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5061,6 +5294,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CancelSimulationJobBatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5092,6 +5328,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateDeploymentJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5123,6 +5362,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5154,6 +5396,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateRobot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5185,6 +5430,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateRobotApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5219,6 +5467,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateRobotApplicationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5251,6 +5502,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateSimulationApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5285,6 +5539,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateSimulationApplicationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5318,6 +5575,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("CreateSimulationJob");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5349,6 +5609,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DeleteFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5380,6 +5643,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DeleteRobot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5411,6 +5677,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DeleteRobotApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5443,6 +5712,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DeleteSimulationApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5474,6 +5746,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deregisterRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DeregisterRobot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5505,6 +5780,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeDeploymentJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5536,6 +5814,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeFleet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5567,6 +5848,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeRobot");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5598,6 +5882,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeRobotApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5632,6 +5919,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeSimulationApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5663,6 +5953,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeSimulationJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5695,6 +5988,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeSimulationJobBatch");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5726,6 +6022,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listDeploymentJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListDeploymentJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5757,6 +6056,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5788,6 +6090,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobotApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListRobotApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5819,6 +6124,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobots";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListRobots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5851,6 +6159,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListSimulationApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5882,6 +6193,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobBatches";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListSimulationJobBatches");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5913,6 +6227,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListSimulationJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5944,6 +6261,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5972,6 +6292,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/registerRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("RegisterRobot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6003,6 +6326,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/restartSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("RestartSimulationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6034,6 +6360,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/startSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("StartSimulationJobBatch");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6065,6 +6394,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/syncDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("SyncDeploymentJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6096,6 +6428,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6127,6 +6462,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6161,6 +6499,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("UpdateRobotApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6193,6 +6534,9 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation_name("UpdateSimulationApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6215,3 +6559,496 @@ impl Robomaker for RobomakerClient {
         }
     }
 }
+
+/// A blocking client for the RoboMaker API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `RobomakerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RobomakerBlockingClient {
+    client: RobomakerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RobomakerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RobomakerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        RobomakerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RobomakerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RobomakerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> RobomakerBlockingClient {
+        RobomakerBlockingClient {
+            client: RobomakerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Describes one or more simulation jobs.</p>
+    ///
+    /// See `Robomaker::batch_describe_simulation_job`.
+    pub fn batch_describe_simulation_job(
+        &self,
+        input: BatchDescribeSimulationJobRequest,
+    ) -> Result<BatchDescribeSimulationJobResponse, RusotoError<BatchDescribeSimulationJobError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_describe_simulation_job(input))
+    }
+
+    /// <p>Cancels the specified deployment job.</p>
+    ///
+    /// See `Robomaker::cancel_deployment_job`.
+    pub fn cancel_deployment_job(
+        &self,
+        input: CancelDeploymentJobRequest,
+    ) -> Result<CancelDeploymentJobResponse, RusotoError<CancelDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_deployment_job(input))
+    }
+
+    /// <p>Cancels the specified simulation job.</p>
+    ///
+    /// See `Robomaker::cancel_simulation_job`.
+    pub fn cancel_simulation_job(
+        &self,
+        input: CancelSimulationJobRequest,
+    ) -> Result<CancelSimulationJobResponse, RusotoError<CancelSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_simulation_job(input))
+    }
+
+    /// <p>Cancels a simulation job batch. When you cancel a simulation job batch, you are also cancelling all of the active simulation jobs created as part of the batch. </p>
+    ///
+    /// See `Robomaker::cancel_simulation_job_batch`.
+    pub fn cancel_simulation_job_batch(
+        &self,
+        input: CancelSimulationJobBatchRequest,
+    ) -> Result<CancelSimulationJobBatchResponse, RusotoError<CancelSimulationJobBatchError>> {
+        self.runtime
+            .block_on(self.client.cancel_simulation_job_batch(input))
+    }
+
+    /// <p><p>Deploys a specific version of a robot application to robots in a fleet.</p> <p>The robot application must have a numbered <code>applicationVersion</code> for consistency reasons. To create a new version, use <code>CreateRobotApplicationVersion</code> or see <a href="https://docs.aws.amazon.com/robomaker/latest/dg/create-robot-application-version.html">Creating a Robot Application Version</a>. </p> <note> <p>After 90 days, deployment jobs expire and will be deleted. They will no longer be accessible. </p> </note></p>
+    ///
+    /// See `Robomaker::create_deployment_job`.
+    pub fn create_deployment_job(
+        &self,
+        input: CreateDeploymentJobRequest,
+    ) -> Result<CreateDeploymentJobResponse, RusotoError<CreateDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_job(input))
+    }
+
+    /// <p>Creates a fleet, a logical group of robots running the same robot application.</p>
+    ///
+    /// See `Robomaker::create_fleet`.
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResponse, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// <p>Creates a robot.</p>
+    ///
+    /// See `Robomaker::create_robot`.
+    pub fn create_robot(
+        &self,
+        input: CreateRobotRequest,
+    ) -> Result<CreateRobotResponse, RusotoError<CreateRobotError>> {
+        self.runtime.block_on(self.client.create_robot(input))
+    }
+
+    /// <p>Creates a robot application. </p>
+    ///
+    /// See `Robomaker::create_robot_application`.
+    pub fn create_robot_application(
+        &self,
+        input: CreateRobotApplicationRequest,
+    ) -> Result<CreateRobotApplicationResponse, RusotoError<CreateRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.create_robot_application(input))
+    }
+
+    /// <p>Creates a version of a robot application.</p>
+    ///
+    /// See `Robomaker::create_robot_application_version`.
+    pub fn create_robot_application_version(
+        &self,
+        input: CreateRobotApplicationVersionRequest,
+    ) -> Result<
+        CreateRobotApplicationVersionResponse,
+        RusotoError<CreateRobotApplicationVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_robot_application_version(input))
+    }
+
+    /// <p>Creates a simulation application.</p>
+    ///
+    /// See `Robomaker::create_simulation_application`.
+    pub fn create_simulation_application(
+        &self,
+        input: CreateSimulationApplicationRequest,
+    ) -> Result<CreateSimulationApplicationResponse, RusotoError<CreateSimulationApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_simulation_application(input))
+    }
+
+    /// <p>Creates a simulation application with a specific revision id.</p>
+    ///
+    /// See `Robomaker::create_simulation_application_version`.
+    pub fn create_simulation_application_version(
+        &self,
+        input: CreateSimulationApplicationVersionRequest,
+    ) -> Result<
+        CreateSimulationApplicationVersionResponse,
+        RusotoError<CreateSimulationApplicationVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_simulation_application_version(input))
+    }
+
+    /// <p><p>Creates a simulation job.</p> <note> <p>After 90 days, simulation jobs expire and will be deleted. They will no longer be accessible. </p> </note></p>
+    ///
+    /// See `Robomaker::create_simulation_job`.
+    pub fn create_simulation_job(
+        &self,
+        input: CreateSimulationJobRequest,
+    ) -> Result<CreateSimulationJobResponse, RusotoError<CreateSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.create_simulation_job(input))
+    }
+
+    /// <p>Deletes a fleet.</p>
+    ///
+    /// See `Robomaker::delete_fleet`.
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResponse, RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// <p>Deletes a robot.</p>
+    ///
+    /// See `Robomaker::delete_robot`.
+    pub fn delete_robot(
+        &self,
+        input: DeleteRobotRequest,
+    ) -> Result<DeleteRobotResponse, RusotoError<DeleteRobotError>> {
+        self.runtime.block_on(self.client.delete_robot(input))
+    }
+
+    /// <p>Deletes a robot application.</p>
+    ///
+    /// See `Robomaker::delete_robot_application`.
+    pub fn delete_robot_application(
+        &self,
+        input: DeleteRobotApplicationRequest,
+    ) -> Result<DeleteRobotApplicationResponse, RusotoError<DeleteRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.delete_robot_application(input))
+    }
+
+    /// <p>Deletes a simulation application.</p>
+    ///
+    /// See `Robomaker::delete_simulation_application`.
+    pub fn delete_simulation_application(
+        &self,
+        input: DeleteSimulationApplicationRequest,
+    ) -> Result<DeleteSimulationApplicationResponse, RusotoError<DeleteSimulationApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_simulation_application(input))
+    }
+
+    /// <p>Deregisters a robot.</p>
+    ///
+    /// See `Robomaker::deregister_robot`.
+    pub fn deregister_robot(
+        &self,
+        input: DeregisterRobotRequest,
+    ) -> Result<DeregisterRobotResponse, RusotoError<DeregisterRobotError>> {
+        self.runtime.block_on(self.client.deregister_robot(input))
+    }
+
+    /// <p>Describes a deployment job.</p>
+    ///
+    /// See `Robomaker::describe_deployment_job`.
+    pub fn describe_deployment_job(
+        &self,
+        input: DescribeDeploymentJobRequest,
+    ) -> Result<DescribeDeploymentJobResponse, RusotoError<DescribeDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.describe_deployment_job(input))
+    }
+
+    /// <p>Describes a fleet.</p>
+    ///
+    /// See `Robomaker::describe_fleet`.
+    pub fn describe_fleet(
+        &self,
+        input: DescribeFleetRequest,
+    ) -> Result<DescribeFleetResponse, RusotoError<DescribeFleetError>> {
+        self.runtime.block_on(self.client.describe_fleet(input))
+    }
+
+    /// <p>Describes a robot.</p>
+    ///
+    /// See `Robomaker::describe_robot`.
+    pub fn describe_robot(
+        &self,
+        input: DescribeRobotRequest,
+    ) -> Result<DescribeRobotResponse, RusotoError<DescribeRobotError>> {
+        self.runtime.block_on(self.client.describe_robot(input))
+    }
+
+    /// <p>Describes a robot application.</p>
+    ///
+    /// See `Robomaker::describe_robot_application`.
+    pub fn describe_robot_application(
+        &self,
+        input: DescribeRobotApplicationRequest,
+    ) -> Result<DescribeRobotApplicationResponse, RusotoError<DescribeRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_robot_application(input))
+    }
+
+    /// <p>Describes a simulation application.</p>
+    ///
+    /// See `Robomaker::describe_simulation_application`.
+    pub fn describe_simulation_application(
+        &self,
+        input: DescribeSimulationApplicationRequest,
+    ) -> Result<
+        DescribeSimulationApplicationResponse,
+        RusotoError<DescribeSimulationApplicationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_simulation_application(input))
+    }
+
+    /// <p>Describes a simulation job.</p>
+    ///
+    /// See `Robomaker::describe_simulation_job`.
+    pub fn describe_simulation_job(
+        &self,
+        input: DescribeSimulationJobRequest,
+    ) -> Result<DescribeSimulationJobResponse, RusotoError<DescribeSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.describe_simulation_job(input))
+    }
+
+    /// <p>Describes a simulation job batch.</p>
+    ///
+    /// See `Robomaker::describe_simulation_job_batch`.
+    pub fn describe_simulation_job_batch(
+        &self,
+        input: DescribeSimulationJobBatchRequest,
+    ) -> Result<DescribeSimulationJobBatchResponse, RusotoError<DescribeSimulationJobBatchError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_simulation_job_batch(input))
+    }
+
+    /// <p>Returns a list of deployment jobs for a fleet. You can optionally provide filters to retrieve specific deployment jobs. </p>
+    ///
+    /// See `Robomaker::list_deployment_jobs`.
+    pub fn list_deployment_jobs(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> Result<ListDeploymentJobsResponse, RusotoError<ListDeploymentJobsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_jobs(input))
+    }
+
+    /// <p>Returns a list of fleets. You can optionally provide filters to retrieve specific fleets. </p>
+    ///
+    /// See `Robomaker::list_fleets`.
+    pub fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> Result<ListFleetsResponse, RusotoError<ListFleetsError>> {
+        self.runtime.block_on(self.client.list_fleets(input))
+    }
+
+    /// <p>Returns a list of robot application. You can optionally provide filters to retrieve specific robot applications.</p>
+    ///
+    /// See `Robomaker::list_robot_applications`.
+    pub fn list_robot_applications(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> Result<ListRobotApplicationsResponse, RusotoError<ListRobotApplicationsError>> {
+        self.runtime
+            .block_on(self.client.list_robot_applications(input))
+    }
+
+    /// <p>Returns a list of robots. You can optionally provide filters to retrieve specific robots.</p>
+    ///
+    /// See `Robomaker::list_robots`.
+    pub fn list_robots(
+        &self,
+        input: ListRobotsRequest,
+    ) -> Result<ListRobotsResponse, RusotoError<ListRobotsError>> {
+        self.runtime.block_on(self.client.list_robots(input))
+    }
+
+    /// <p>Returns a list of simulation applications. You can optionally provide filters to retrieve specific simulation applications. </p>
+    ///
+    /// See `Robomaker::list_simulation_applications`.
+    pub fn list_simulation_applications(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> Result<ListSimulationApplicationsResponse, RusotoError<ListSimulationApplicationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_simulation_applications(input))
+    }
+
+    /// <p>Returns a list simulation job batches. You can optionally provide filters to retrieve specific simulation batch jobs. </p>
+    ///
+    /// See `Robomaker::list_simulation_job_batches`.
+    pub fn list_simulation_job_batches(
+        &self,
+        input: ListSimulationJobBatchesRequest,
+    ) -> Result<ListSimulationJobBatchesResponse, RusotoError<ListSimulationJobBatchesError>> {
+        self.runtime
+            .block_on(self.client.list_simulation_job_batches(input))
+    }
+
+    /// <p>Returns a list of simulation jobs. You can optionally provide filters to retrieve specific simulation jobs. </p>
+    ///
+    /// See `Robomaker::list_simulation_jobs`.
+    pub fn list_simulation_jobs(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> Result<ListSimulationJobsResponse, RusotoError<ListSimulationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_simulation_jobs(input))
+    }
+
+    /// <p>Lists all tags on a AWS RoboMaker resource.</p>
+    ///
+    /// See `Robomaker::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Registers a robot with a fleet.</p>
+    ///
+    /// See `Robomaker::register_robot`.
+    pub fn register_robot(
+        &self,
+        input: RegisterRobotRequest,
+    ) -> Result<RegisterRobotResponse, RusotoError<RegisterRobotError>> {
+        self.runtime.block_on(self.client.register_robot(input))
+    }
+
+    /// <p>Restarts a running simulation job.</p>
+    ///
+    /// See `Robomaker::restart_simulation_job`.
+    pub fn restart_simulation_job(
+        &self,
+        input: RestartSimulationJobRequest,
+    ) -> Result<RestartSimulationJobResponse, RusotoError<RestartSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.restart_simulation_job(input))
+    }
+
+    /// <p>Starts a new simulation job batch. The batch is defined using one or more <code>SimulationJobRequest</code> objects. </p>
+    ///
+    /// See `Robomaker::start_simulation_job_batch`.
+    pub fn start_simulation_job_batch(
+        &self,
+        input: StartSimulationJobBatchRequest,
+    ) -> Result<StartSimulationJobBatchResponse, RusotoError<StartSimulationJobBatchError>> {
+        self.runtime
+            .block_on(self.client.start_simulation_job_batch(input))
+    }
+
+    /// <p>Syncrhonizes robots in a fleet to the latest deployment. This is helpful if robots were added after a deployment.</p>
+    ///
+    /// See `Robomaker::sync_deployment_job`.
+    pub fn sync_deployment_job(
+        &self,
+        input: SyncDeploymentJobRequest,
+    ) -> Result<SyncDeploymentJobResponse, RusotoError<SyncDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.sync_deployment_job(input))
+    }
+
+    /// <p>Adds or edits tags for a AWS RoboMaker resource.</p> <p>Each tag consists of a tag key and a tag value. Tag keys and tag values are both required, but tag values can be empty strings. </p> <p>For information about the rules that apply to tag keys and tag values, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/allocation-tag-restrictions.html">User-Defined Tag Restrictions</a> in the <i>AWS Billing and Cost Management User Guide</i>. </p>
+    ///
+    /// See `Robomaker::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from the specified AWS RoboMaker resource.</p> <p>To remove a tag, specify the tag key. To change the tag value of an existing tag key, use <a href="https://docs.aws.amazon.com/robomaker/latest/dg/API_TagResource.html"> <code>TagResource</code> </a>. </p>
+    ///
+    /// See `Robomaker::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a robot application.</p>
+    ///
+    /// See `Robomaker::update_robot_application`.
+    pub fn update_robot_application(
+        &self,
+        input: UpdateRobotApplicationRequest,
+    ) -> Result<UpdateRobotApplicationResponse, RusotoError<UpdateRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.update_robot_application(input))
+    }
+
+    /// <p>Updates a simulation application.</p>
+    ///
+    /// See `Robomaker::update_simulation_application`.
+    pub fn update_simulation_application(
+        &self,
+        input: UpdateSimulationApplicationRequest,
+    ) -> Result<UpdateSimulationApplicationResponse, RusotoError<UpdateSimulationApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_simulation_application(input))
+    }
+}
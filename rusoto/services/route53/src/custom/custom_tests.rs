@@ -29,10 +29,18 @@ async fn test_parse_no_such_hosted_zone_error() {
     let result = client.list_resource_record_sets(request).await;
     assert!(result.is_err());
     let err = result.err().unwrap();
+    assert_eq!(err.code().as_deref(), Some("NoSuchHostedZone"));
     assert_eq!(
-        RusotoError::Service(ListResourceRecordSetsError::NoSuchHostedZone(
-            "No hosted zone found with ID: NO-SUCH-ZONE".to_owned()
-        )),
+        err.request_id(),
+        Some("20c2984f-279e-11e8-9a16-83e7725d8022")
+    );
+    assert_eq!(
+        RusotoError::Service(
+            ListResourceRecordSetsError::NoSuchHostedZone(
+                "No hosted zone found with ID: NO-SUCH-ZONE".to_owned()
+            ),
+            err.metadata().unwrap().into_owned()
+        ),
         err
     );
 }
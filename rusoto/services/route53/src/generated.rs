@@ -7452,46 +7452,54 @@ impl AssociateVPCWithHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateVPCWithHostedZoneError::ConflictingDomainExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AssociateVPCWithHostedZoneError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidVPCId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AssociateVPCWithHostedZoneError::InvalidVPCId(parsed_error.message),
+                        )
                     }
                     "LimitsExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateVPCWithHostedZoneError::LimitsExceeded(parsed_error.message),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateVPCWithHostedZoneError::NoSuchHostedZone(parsed_error.message),
                         )
                     }
                     "NotAuthorizedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateVPCWithHostedZoneError::NotAuthorized(parsed_error.message),
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateVPCWithHostedZoneError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PublicZoneVPCAssociation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(
                                 parsed_error.message,
                             ),
@@ -7558,27 +7566,32 @@ impl ChangeResourceRecordSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidChangeBatch" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeResourceRecordSetsError::InvalidChangeBatch(parsed_error.message),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ChangeResourceRecordSetsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangeResourceRecordSetsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeResourceRecordSetsError::NoSuchHealthCheck(parsed_error.message),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeResourceRecordSetsError::NoSuchHostedZone(parsed_error.message),
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeResourceRecordSetsError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
@@ -7638,31 +7651,36 @@ impl ChangeTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangeTagsForResourceError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangeTagsForResourceError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangeTagsForResourceError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeTagsForResourceError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::Throttling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ChangeTagsForResourceError::Throttling(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7714,19 +7732,22 @@ impl CreateHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHealthCheckError::HealthCheckAlreadyExists(parsed_error.message),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHealthCheckError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "TooManyHealthChecks" => {
-                        return RusotoError::Service(CreateHealthCheckError::TooManyHealthChecks(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHealthCheckError::TooManyHealthChecks(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7786,49 +7807,58 @@ impl CreateHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHostedZoneError::ConflictingDomainExists(parsed_error.message),
                         )
                     }
                     "DelegationSetNotAvailable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHostedZoneError::DelegationSetNotAvailable(parsed_error.message),
                         )
                     }
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHostedZoneError::DelegationSetNotReusable(parsed_error.message),
                         )
                     }
                     "HostedZoneAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateHostedZoneError::HostedZoneAlreadyExists(parsed_error.message),
                         )
                     }
                     "InvalidDomainName" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidDomainName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHostedZoneError::InvalidDomainName(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHostedZoneError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidVPCId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHostedZoneError::InvalidVPCId(parsed_error.message),
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(CreateHostedZoneError::NoSuchDelegationSet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHostedZoneError::NoSuchDelegationSet(parsed_error.message),
+                        )
                     }
                     "TooManyHostedZones" => {
-                        return RusotoError::Service(CreateHostedZoneError::TooManyHostedZones(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateHostedZoneError::TooManyHostedZones(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7888,38 +7918,44 @@ impl CreateQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateQueryLoggingConfigError::ConcurrentModification(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InsufficientCloudWatchLogsResourcePolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateQueryLoggingConfigError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchCloudWatchLogsLogGroup" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateQueryLoggingConfigError::NoSuchHostedZone(parsed_error.message),
                         )
                     }
                     "QueryLoggingConfigAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(
                                 parsed_error.message,
                             ),
@@ -7992,45 +8028,52 @@ impl CreateReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetAlreadyCreated" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::DelegationSetAlreadyCreated(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DelegationSetAlreadyReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::DelegationSetAlreadyReusable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DelegationSetNotAvailable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::DelegationSetNotAvailable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "HostedZoneNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::HostedZoneNotFound(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::InvalidArgument(parsed_error.message),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::InvalidInput(parsed_error.message),
                         )
                     }
                     "LimitsExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReusableDelegationSetError::LimitsExceeded(parsed_error.message),
                         )
                     }
@@ -8094,24 +8137,28 @@ impl CreateTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTrafficPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "InvalidTrafficPolicyDocument" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyError::InvalidTrafficPolicyDocument(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTrafficPolicies" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyError::TooManyTrafficPolicies(parsed_error.message),
                         )
                     }
                     "TrafficPolicyAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyError::TrafficPolicyAlreadyExists(
                                 parsed_error.message,
                             ),
@@ -8174,33 +8221,38 @@ impl CreateTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyInstanceError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TooManyTrafficPolicyInstances" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TrafficPolicyInstanceAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(
                                 parsed_error.message,
                             ),
@@ -8265,7 +8317,7 @@ impl CreateTrafficPolicyVersionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConcurrentModification" => return RusotoError::Service(CreateTrafficPolicyVersionError::ConcurrentModification(parsed_error.message)),"InvalidInput" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidInput(parsed_error.message)),"InvalidTrafficPolicyDocument" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(parsed_error.message)),"NoSuchTrafficPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(parsed_error.message)),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(parsed_error.message)),_ => {}
+                                    "ConcurrentModification" => return RusotoError::service(&res, CreateTrafficPolicyVersionError::ConcurrentModification(parsed_error.message)),"InvalidInput" => return RusotoError::service(&res, CreateTrafficPolicyVersionError::InvalidInput(parsed_error.message)),"InvalidTrafficPolicyDocument" => return RusotoError::service(&res, CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(parsed_error.message)),"NoSuchTrafficPolicy" => return RusotoError::service(&res, CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(parsed_error.message)),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::service(&res, CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8327,34 +8379,39 @@ impl CreateVPCAssociationAuthorizationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateVPCAssociationAuthorizationError::ConcurrentModification(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateVPCAssociationAuthorizationError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateVPCAssociationAuthorizationError::InvalidVPCId(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateVPCAssociationAuthorizationError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "TooManyVPCAssociationAuthorizations" => return RusotoError::Service(
+                    "TooManyVPCAssociationAuthorizations" => return RusotoError::service(
+                        &res,
                         CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(
                             parsed_error.message,
                         ),
@@ -8417,19 +8474,22 @@ impl DeleteHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckInUse" => {
-                        return RusotoError::Service(DeleteHealthCheckError::HealthCheckInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHealthCheckError::HealthCheckInUse(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHealthCheckError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(DeleteHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHealthCheckError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8481,27 +8541,32 @@ impl DeleteHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HostedZoneNotEmpty" => {
-                        return RusotoError::Service(DeleteHostedZoneError::HostedZoneNotEmpty(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHostedZoneError::HostedZoneNotEmpty(parsed_error.message),
+                        )
                     }
                     "InvalidDomainName" => {
-                        return RusotoError::Service(DeleteHostedZoneError::InvalidDomainName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHostedZoneError::InvalidDomainName(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHostedZoneError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(DeleteHostedZoneError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteHostedZoneError::PriorRequestNotComplete(parsed_error.message),
                         )
                     }
@@ -8553,19 +8618,22 @@ impl DeleteQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteQueryLoggingConfigError::ConcurrentModification(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteQueryLoggingConfigError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchQueryLoggingConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(
                                 parsed_error.message,
                             ),
@@ -8625,26 +8693,30 @@ impl DeleteReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetInUse" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReusableDelegationSetError::DelegationSetInUse(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReusableDelegationSetError::DelegationSetNotReusable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReusableDelegationSetError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteReusableDelegationSetError::NoSuchDelegationSet(
                                 parsed_error.message,
                             ),
@@ -8705,24 +8777,28 @@ impl DeleteTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteTrafficPolicyError::ConcurrentModification(parsed_error.message),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTrafficPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::NoSuchTrafficPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTrafficPolicyError::NoSuchTrafficPolicy(parsed_error.message),
+                        )
                     }
                     "TrafficPolicyInUse" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::TrafficPolicyInUse(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTrafficPolicyError::TrafficPolicyInUse(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8773,19 +8849,22 @@ impl DeleteTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
@@ -8847,34 +8926,39 @@ impl DeleteVPCAssociationAuthorizationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteVPCAssociationAuthorizationError::ConcurrentModification(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteVPCAssociationAuthorizationError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteVPCAssociationAuthorizationError::InvalidVPCId(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteVPCAssociationAuthorizationError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "VPCAssociationAuthorizationNotFound" => return RusotoError::Service(
+                    "VPCAssociationAuthorizationNotFound" => return RusotoError::service(
+                        &res,
                         DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(
                             parsed_error.message,
                         ),
@@ -8943,31 +9027,36 @@ impl DisassociateVPCFromHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisassociateVPCFromHostedZoneError::InvalidInput(parsed_error.message),
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisassociateVPCFromHostedZoneError::InvalidVPCId(parsed_error.message),
                         )
                     }
                     "LastVPCAssociation" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisassociateVPCFromHostedZoneError::LastVPCAssociation(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisassociateVPCFromHostedZoneError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "VPCAssociationNotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(
                                 parsed_error.message,
                             ),
@@ -9023,9 +9112,10 @@ impl GetAccountLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetAccountLimitError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAccountLimitError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9069,14 +9159,16 @@ impl GetChangeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetChangeError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetChangeError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchChange" => {
-                        return RusotoError::Service(GetChangeError::NoSuchChange(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetChangeError::NoSuchChange(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9155,14 +9247,16 @@ impl GetGeoLocationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetGeoLocationError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetGeoLocationError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchGeoLocation" => {
-                        return RusotoError::Service(GetGeoLocationError::NoSuchGeoLocation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetGeoLocationError::NoSuchGeoLocation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9209,19 +9303,22 @@ impl GetHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
-                        return RusotoError::Service(GetHealthCheckError::IncompatibleVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHealthCheckError::IncompatibleVersion(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHealthCheckError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(GetHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHealthCheckError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9303,14 +9400,16 @@ impl GetHealthCheckLastFailureReasonError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetHealthCheckLastFailureReasonError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(
                                 parsed_error.message,
                             ),
@@ -9361,14 +9460,16 @@ impl GetHealthCheckStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHealthCheckStatusError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHealthCheckStatusError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(GetHealthCheckStatusError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHealthCheckStatusError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9413,14 +9514,16 @@ impl GetHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHostedZoneError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetHostedZoneError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9463,9 +9566,10 @@ impl GetHostedZoneCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneCountError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHostedZoneCountError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9511,19 +9615,22 @@ impl GetHostedZoneLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HostedZoneNotPrivate" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::HostedZoneNotPrivate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHostedZoneLimitError::HostedZoneNotPrivate(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHostedZoneLimitError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetHostedZoneLimitError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9569,12 +9676,14 @@ impl GetQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetQueryLoggingConfigError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetQueryLoggingConfigError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchQueryLoggingConfig" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(
                                 parsed_error.message,
                             ),
@@ -9627,19 +9736,22 @@ impl GetReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReusableDelegationSetError::DelegationSetNotReusable(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetReusableDelegationSetError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetReusableDelegationSetError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReusableDelegationSetError::NoSuchDelegationSet(
                                 parsed_error.message,
                             ),
@@ -9693,12 +9805,14 @@ impl GetReusableDelegationSetLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReusableDelegationSetLimitError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetReusableDelegationSetLimitError::NoSuchDelegationSet(
                                 parsed_error.message,
                             ),
@@ -9749,14 +9863,16 @@ impl GetTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetTrafficPolicyError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTrafficPolicyError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(GetTrafficPolicyError::NoSuchTrafficPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTrafficPolicyError::NoSuchTrafficPolicy(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9801,12 +9917,14 @@ impl GetTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetTrafficPolicyInstanceError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
@@ -9891,9 +10009,10 @@ impl ListGeoLocationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListGeoLocationsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListGeoLocationsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9937,14 +10056,16 @@ impl ListHealthChecksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
-                        return RusotoError::Service(ListHealthChecksError::IncompatibleVersion(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHealthChecksError::IncompatibleVersion(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHealthChecksError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHealthChecksError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9991,19 +10112,22 @@ impl ListHostedZonesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListHostedZonesError::DelegationSetNotReusable(parsed_error.message),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHostedZonesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(ListHostedZonesError::NoSuchDelegationSet(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHostedZonesError::NoSuchDelegationSet(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10049,14 +10173,16 @@ impl ListHostedZonesByNameError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDomainName" => {
-                        return RusotoError::Service(ListHostedZonesByNameError::InvalidDomainName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHostedZonesByNameError::InvalidDomainName(parsed_error.message),
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesByNameError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHostedZonesByNameError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10101,12 +10227,14 @@ impl ListHostedZonesByVPCError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesByVPCError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListHostedZonesByVPCError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListHostedZonesByVPCError::InvalidPaginationToken(parsed_error.message),
                         )
                     }
@@ -10155,19 +10283,22 @@ impl ListQueryLoggingConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListQueryLoggingConfigsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListQueryLoggingConfigsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListQueryLoggingConfigsError::InvalidPaginationToken(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListQueryLoggingConfigsError::NoSuchHostedZone(parsed_error.message),
                         )
                     }
@@ -10217,14 +10348,16 @@ impl ListResourceRecordSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListResourceRecordSetsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListResourceRecordSetsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListResourceRecordSetsError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListResourceRecordSetsError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10269,9 +10402,10 @@ impl ListReusableDelegationSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListReusableDelegationSetsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListReusableDelegationSetsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10321,29 +10455,34 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourceError::PriorRequestNotComplete(parsed_error.message),
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ListTagsForResourceError::Throttling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::Throttling(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10397,31 +10536,36 @@ impl ListTagsForResourcesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTagsForResourcesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourcesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ListTagsForResourcesError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourcesError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListTagsForResourcesError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourcesError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTagsForResourcesError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ListTagsForResourcesError::Throttling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourcesError::Throttling(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10467,9 +10611,10 @@ impl ListTrafficPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPoliciesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTrafficPoliciesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10515,12 +10660,14 @@ impl ListTrafficPolicyInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPolicyInstancesError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTrafficPolicyInstancesError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
@@ -10575,20 +10722,23 @@ impl ListTrafficPolicyInstancesByHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyInstancesByHostedZoneError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "NoSuchTrafficPolicyInstance" => return RusotoError::Service(
+                    "NoSuchTrafficPolicyInstance" => return RusotoError::service(
+                        &res,
                         ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(
                             parsed_error.message,
                         ),
@@ -10647,21 +10797,24 @@ impl ListTrafficPolicyInstancesByPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyInstancesByPolicyError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
@@ -10717,12 +10870,14 @@ impl ListTrafficPolicyVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPolicyVersionsError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTrafficPolicyVersionsError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
@@ -10777,21 +10932,24 @@ impl ListVPCAssociationAuthorizationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListVPCAssociationAuthorizationsError::InvalidInput(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListVPCAssociationAuthorizationsError::InvalidPaginationToken(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListVPCAssociationAuthorizationsError::NoSuchHostedZone(
                                 parsed_error.message,
                             ),
@@ -10847,14 +11005,16 @@ impl TestDNSAnswerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(TestDNSAnswerError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TestDNSAnswerError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(TestDNSAnswerError::NoSuchHostedZone(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TestDNSAnswerError::NoSuchHostedZone(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10901,21 +11061,24 @@ impl UpdateHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckVersionMismatch" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateHealthCheckError::HealthCheckVersionMismatch(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateHealthCheckError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateHealthCheckError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(UpdateHealthCheckError::NoSuchHealthCheck(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateHealthCheckError::NoSuchHealthCheck(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10961,12 +11124,14 @@ impl UpdateHostedZoneCommentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateHostedZoneCommentError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateHostedZoneCommentError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateHostedZoneCommentError::NoSuchHostedZone(parsed_error.message),
                         )
                     }
@@ -11017,19 +11182,22 @@ impl UpdateTrafficPolicyCommentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyCommentError::ConcurrentModification(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateTrafficPolicyCommentError::InvalidInput(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateTrafficPolicyCommentError::InvalidInput(parsed_error.message),
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
@@ -11091,33 +11259,38 @@ impl UpdateTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingTypes" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyInstanceError::ConflictingTypes(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(
                                 parsed_error.message,
                             ),
@@ -11548,6 +11721,7 @@ impl Route53Client {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -11563,6 +11737,7 @@ impl Route53Client {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
         Route53Client { client, region }
     }
@@ -11586,6 +11761,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("AssociateVPCWithHostedZone");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         AssociateVPCWithHostedZoneRequestSerializer::serialize(
@@ -11626,6 +11804,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("ChangeResourceRecordSets");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeResourceRecordSetsRequestSerializer::serialize(
@@ -11666,6 +11847,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("ChangeTagsForResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeTagsForResourceRequestSerializer::serialize(
@@ -11695,6 +11879,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateHealthCheck");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHealthCheckRequestSerializer::serialize(
@@ -11729,6 +11916,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateHostedZone");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHostedZoneRequestSerializer::serialize(
@@ -11763,6 +11953,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateQueryLoggingConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateQueryLoggingConfigRequestSerializer::serialize(
@@ -11798,6 +11991,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateReusableDelegationSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateReusableDelegationSetRequestSerializer::serialize(
@@ -11832,6 +12028,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicy";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateTrafficPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyRequestSerializer::serialize(
@@ -11867,6 +12066,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstance";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateTrafficPolicyInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -11905,6 +12107,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateTrafficPolicyVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyVersionRequestSerializer::serialize(
@@ -11948,6 +12153,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("CreateVPCAssociationAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         CreateVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -11993,6 +12201,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteHealthCheck");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteHealthCheckError::from_response)
@@ -12016,6 +12227,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteHostedZone");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteHostedZoneError::from_response)
@@ -12043,6 +12257,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteQueryLoggingConfig");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteQueryLoggingConfigError::from_response)
@@ -12067,6 +12284,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteReusableDelegationSet");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteReusableDelegationSetError::from_response)
@@ -12094,6 +12314,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteTrafficPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteTrafficPolicyError::from_response)
@@ -12118,6 +12341,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteTrafficPolicyInstance");
+        request.set_read_only(false);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, DeleteTrafficPolicyInstanceError::from_response)
@@ -12147,6 +12373,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("DeleteVPCAssociationAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -12188,6 +12417,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("DisassociateVPCFromHostedZone");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         DisassociateVPCFromHostedZoneRequestSerializer::serialize(
@@ -12224,6 +12456,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetAccountLimit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetAccountLimitError::from_response)
@@ -12251,6 +12486,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetChange");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetChangeError::from_response)
@@ -12275,6 +12513,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/checkeripranges";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetCheckerIpRanges");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetCheckerIpRangesError::from_response)
@@ -12299,6 +12540,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocation";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetGeoLocation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.continent_code {
@@ -12341,6 +12585,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHealthCheck");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckError::from_response)
@@ -12365,6 +12612,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheckcount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHealthCheckCount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckCountError::from_response)
@@ -12398,6 +12648,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHealthCheckLastFailureReason");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckLastFailureReasonError::from_response)
@@ -12428,6 +12681,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHealthCheckStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckStatusError::from_response)
@@ -12455,6 +12711,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHostedZone");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneError::from_response)
@@ -12479,6 +12738,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHostedZoneCount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneCountError::from_response)
@@ -12503,6 +12765,9 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/hostedzone//hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetHostedZoneLimit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneLimitError::from_response)
@@ -12530,6 +12795,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetQueryLoggingConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetQueryLoggingConfigError::from_response)
@@ -12557,6 +12825,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetReusableDelegationSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetReusableDelegationSetError::from_response)
@@ -12584,6 +12855,9 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/hostedzone//hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetReusableDelegationSetLimit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetReusableDelegationSetLimitError::from_response)
@@ -12615,6 +12889,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetTrafficPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyError::from_response)
@@ -12642,6 +12919,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetTrafficPolicyInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyInstanceError::from_response)
@@ -12669,6 +12949,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstancecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("GetTrafficPolicyInstanceCount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyInstanceCountError::from_response)
@@ -12693,6 +12976,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocations";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListGeoLocations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -12732,6 +13018,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListHealthChecks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12765,6 +13054,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListHostedZones");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.delegation_set_id {
@@ -12801,6 +13093,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyname";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListHostedZonesByName");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.dns_name {
@@ -12837,6 +13132,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyvpc";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListHostedZonesByVPC");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -12872,6 +13170,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListQueryLoggingConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id {
@@ -12914,6 +13215,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListResourceRecordSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -12954,6 +13258,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListReusableDelegationSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -12994,6 +13301,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut response = self
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
@@ -13024,6 +13334,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut writer = EventWriter::new(Vec::new());
         ListTagsForResourcesRequestSerializer::serialize(
@@ -13057,6 +13370,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicies";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTrafficPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -13091,6 +13407,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTrafficPolicyInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -13133,6 +13452,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTrafficPolicyInstancesByHostedZone");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         params.put("id", &input.hosted_zone_id);
@@ -13179,6 +13501,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTrafficPolicyInstancesByPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -13230,6 +13555,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListTrafficPolicyVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -13272,6 +13600,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("ListVPCAssociationAuthorizations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -13311,6 +13642,9 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/testdnsanswer";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation_name("TestDNSAnswer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
 
         let mut params = Params::new();
         if let Some(ref x) = input.edns0_client_subnet_ip {
@@ -13356,6 +13690,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("UpdateHealthCheck");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHealthCheckRequestSerializer::serialize(
@@ -13392,6 +13729,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("UpdateHostedZoneComment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHostedZoneCommentRequestSerializer::serialize(
@@ -13433,6 +13773,9 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("UpdateTrafficPolicyComment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyCommentRequestSerializer::serialize(
@@ -13470,6 +13813,9 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation_name("UpdateTrafficPolicyInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -13495,6 +13841,711 @@ impl Route53 for Route53Client {
     }
 }
 
+/// A blocking client for the Route 53 API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Route53Client` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Route53BlockingClient {
+    client: Route53Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Route53BlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Route53BlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Route53BlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Route53BlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53BlockingClient {
+        Route53BlockingClient {
+            client: Route53Client::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Associates an Amazon VPC with a private hosted zone. </p> <note> <p>To perform the association, the VPC and the private hosted zone must already exist. Also, you can&#39;t convert a public hosted zone into a private hosted zone.</p> </note> <p>If you want to associate a VPC that was created by one AWS account with a private hosted zone that was created by a different account, do one of the following:</p> <ul> <li> <p>Use the AWS account that created the private hosted zone to submit a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateVPCAssociationAuthorization.html">CreateVPCAssociationAuthorization</a> request. Then use the account that created the VPC to submit an <code>AssociateVPCWithHostedZone</code> request.</p> </li> <li> <p>If a subnet in the VPC was shared with another account, you can use the account that the subnet was shared with to submit an <code>AssociateVPCWithHostedZone</code> request. For more information about sharing subnets, see <a href="https://docs.aws.amazon.com/vpc/latest/userguide/vpc-sharing.html">Working with Shared VPCs</a>.</p> </li> </ul></p>
+    ///
+    /// See `Route53::associate_vpc_with_hosted_zone`.
+    pub fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> Result<AssociateVPCWithHostedZoneResponse, RusotoError<AssociateVPCWithHostedZoneError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_vpc_with_hosted_zone(input))
+    }
+
+    /// <p>Creates, changes, or deletes a resource record set, which contains authoritative DNS information for a specified domain name or subdomain name. For example, you can use <code>ChangeResourceRecordSets</code> to create a resource record set that routes traffic for test.example.com to a web server that has an IP address of 192.0.2.44.</p> <p> <b>Deleting Resource Record Sets</b> </p> <p>To delete a resource record set, you must specify all the same values that you specified when you created it.</p> <p> <b>Change Batches and Transactional Changes</b> </p> <p>The request body must include a document with a <code>ChangeResourceRecordSetsRequest</code> element. The request body contains a list of change items, known as a change batch. Change batches are considered transactional changes. Route 53 validates the changes in the request and then either makes all or none of the changes in the change batch request. This ensures that DNS routing isn't adversely affected by partial changes to the resource record sets in a hosted zone. </p> <p>For example, suppose a change batch request contains two changes: it deletes the <code>CNAME</code> resource record set for www.example.com and creates an alias resource record set for www.example.com. If validation for both records succeeds, Route 53 deletes the first resource record set and creates the second resource record set in a single operation. If validation for either the <code>DELETE</code> or the <code>CREATE</code> action fails, then the request is canceled, and the original <code>CNAME</code> record continues to exist.</p> <note> <p>If you try to delete the same resource record set more than once in a single change batch, Route 53 returns an <code>InvalidChangeBatch</code> error.</p> </note> <p> <b>Traffic Flow</b> </p> <p>To create resource record sets for complex routing configurations, use either the traffic flow visual editor in the Route 53 console or the API actions for traffic policies and traffic policy instances. Save the configuration as a traffic policy, then associate the traffic policy with one or more domain names (such as example.com) or subdomain names (such as www.example.com), in the same hosted zone or in multiple hosted zones. You can roll back the updates if the new configuration isn't performing as expected. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/traffic-flow.html">Using Traffic Flow to Route DNS Traffic</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p> <b>Create, Delete, and Upsert</b> </p> <p>Use <code>ChangeResourceRecordsSetsRequest</code> to perform the following actions:</p> <ul> <li> <p> <code>CREATE</code>: Creates a resource record set that has the specified values.</p> </li> <li> <p> <code>DELETE</code>: Deletes an existing resource record set that has the specified values.</p> </li> <li> <p> <code>UPSERT</code>: If a resource record set does not already exist, AWS creates it. If a resource set does exist, Route 53 updates it with the values in the request. </p> </li> </ul> <p> <b>Syntaxes for Creating, Updating, and Deleting Resource Record Sets</b> </p> <p>The syntax for a request depends on the type of resource record set that you want to create, delete, or update, such as weighted, alias, or failover. The XML elements in your request must appear in the order listed in the syntax. </p> <p>For an example for each type of resource record set, see "Examples."</p> <p>Don't refer to the syntax in the "Parameter Syntax" section, which includes all of the elements for every kind of resource record set that you can create, delete, or update by using <code>ChangeResourceRecordSets</code>. </p> <p> <b>Change Propagation to Route 53 DNS Servers</b> </p> <p>When you submit a <code>ChangeResourceRecordSets</code> request, Route 53 propagates your changes to all of the Route 53 authoritative DNS servers. While your changes are propagating, <code>GetChange</code> returns a status of <code>PENDING</code>. When propagation is complete, <code>GetChange</code> returns a status of <code>INSYNC</code>. Changes generally propagate to all Route 53 name servers within 60 seconds. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetChange.html">GetChange</a>.</p> <p> <b>Limits on ChangeResourceRecordSets Requests</b> </p> <p>For information about the limits on a <code>ChangeResourceRecordSets</code> request, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    ///
+    /// See `Route53::change_resource_record_sets`.
+    pub fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> Result<ChangeResourceRecordSetsResponse, RusotoError<ChangeResourceRecordSetsError>> {
+        self.runtime
+            .block_on(self.client.change_resource_record_sets(input))
+    }
+
+    /// <p>Adds, edits, or deletes tags for a health check or a hosted zone.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    ///
+    /// See `Route53::change_tags_for_resource`.
+    pub fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> Result<ChangeTagsForResourceResponse, RusotoError<ChangeTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.change_tags_for_resource(input))
+    }
+
+    /// <p><p>Creates a new health check.</p> <p>For information about adding health checks to resource record sets, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ResourceRecordSet.html#Route53-Type-ResourceRecordSet-HealthCheckId">HealthCheckId</a> in <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>. </p> <p> <b>ELB Load Balancers</b> </p> <p>If you&#39;re registering EC2 instances with an Elastic Load Balancing (ELB) load balancer, do not create Amazon Route 53 health checks for the EC2 instances. When you register an EC2 instance with a load balancer, you configure settings for an ELB health check, which performs a similar function to a Route 53 health check.</p> <p> <b>Private Hosted Zones</b> </p> <p>You can associate health checks with failover resource record sets in a private hosted zone. Note the following:</p> <ul> <li> <p>Route 53 health checkers are outside the VPC. To check the health of an endpoint within a VPC by IP address, you must assign a public IP address to the instance in the VPC.</p> </li> <li> <p>You can configure a health checker to check the health of an external resource that the instance relies on, such as a database server.</p> </li> <li> <p>You can create a CloudWatch metric, associate an alarm with the metric, and then create a health check that is based on the state of the alarm. For example, you might create a CloudWatch metric that checks the status of the Amazon EC2 <code>StatusCheckFailed</code> metric, add an alarm to the metric, and then create a health check that is based on the state of the alarm. For information about creating CloudWatch metrics and alarms by using the CloudWatch console, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/DeveloperGuide/WhatIsCloudWatch.html">Amazon CloudWatch User Guide</a>.</p> </li> </ul></p>
+    ///
+    /// See `Route53::create_health_check`.
+    pub fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> Result<CreateHealthCheckResponse, RusotoError<CreateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.create_health_check(input))
+    }
+
+    /// <p>Creates a new public or private hosted zone. You create records in a public hosted zone to define how you want to route traffic on the internet for a domain, such as example.com, and its subdomains (apex.example.com, acme.example.com). You create records in a private hosted zone to define how you want to route traffic for a domain and its subdomains within one or more Amazon Virtual Private Clouds (Amazon VPCs). </p> <important> <p>You can't convert a public hosted zone to a private hosted zone or vice versa. Instead, you must create a new hosted zone with the same name and create new resource record sets.</p> </important> <p>For more information about charges for hosted zones, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> <p>Note the following:</p> <ul> <li> <p>You can't create a hosted zone for a top-level domain (TLD) such as .com.</p> </li> <li> <p>For public hosted zones, Route 53 automatically creates a default SOA record and four NS records for the zone. For more information about SOA and NS records, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/SOA-NSrecords.html">NS and SOA Records that Route 53 Creates for a Hosted Zone</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you want to use the same name servers for multiple public hosted zones, you can optionally associate a reusable delegation set with the hosted zone. See the <code>DelegationSetId</code> element.</p> </li> <li> <p>If your domain is registered with a registrar other than Route 53, you must update the name servers with your registrar to make Route 53 the DNS service for the domain. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/MigratingDNS.html">Migrating DNS Service for an Existing Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>. </p> </li> </ul> <p>When you submit a <code>CreateHostedZone</code> request, the initial status of the hosted zone is <code>PENDING</code>. For public hosted zones, this means that the NS and SOA records are not yet available on all Route 53 DNS servers. When the NS and SOA records are available, the status of the zone changes to <code>INSYNC</code>.</p>
+    ///
+    /// See `Route53::create_hosted_zone`.
+    pub fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> Result<CreateHostedZoneResponse, RusotoError<CreateHostedZoneError>> {
+        self.runtime.block_on(self.client.create_hosted_zone(input))
+    }
+
+    /// <p><p>Creates a configuration for DNS query logging. After you create a query logging configuration, Amazon Route 53 begins to publish log data to an Amazon CloudWatch Logs log group.</p> <p>DNS query logs contain information about the queries that Route 53 receives for a specified public hosted zone, such as the following:</p> <ul> <li> <p>Route 53 edge location that responded to the DNS query</p> </li> <li> <p>Domain or subdomain that was requested</p> </li> <li> <p>DNS record type, such as A or AAAA</p> </li> <li> <p>DNS response code, such as <code>NoError</code> or <code>ServFail</code> </p> </li> </ul> <dl> <dt>Log Group and Resource Policy</dt> <dd> <p>Before you create a query logging configuration, perform the following operations.</p> <note> <p>If you create a query logging configuration using the Route 53 console, Route 53 performs these operations automatically.</p> </note> <ol> <li> <p>Create a CloudWatch Logs log group, and make note of the ARN, which you specify when you create a query logging configuration. Note the following:</p> <ul> <li> <p>You must create the log group in the us-east-1 region.</p> </li> <li> <p>You must use the same AWS account to create the log group and the hosted zone that you want to configure query logging for.</p> </li> <li> <p>When you create log groups for query logging, we recommend that you use a consistent prefix, for example:</p> <p> <code>/aws/route53/<i>hosted zone name</i> </code> </p> <p>In the next step, you&#39;ll create a resource policy, which controls access to one or more log groups and the associated AWS resources, such as Route 53 hosted zones. There&#39;s a limit on the number of resource policies that you can create, so we recommend that you use a consistent prefix so you can use the same resource policy for all the log groups that you create for query logging.</p> </li> </ul> </li> <li> <p>Create a CloudWatch Logs resource policy, and give it the permissions that Route 53 needs to create log streams and to send query logs to log streams. For the value of <code>Resource</code>, specify the ARN for the log group that you created in the previous step. To use the same resource policy for all the CloudWatch Logs log groups that you created for query logging configurations, replace the hosted zone name with <code><em></code>, for example:</p> <p> <code>arn:aws:logs:us-east-1:123412341234:log-group:/aws/route53/</em></code> </p> <note> <p>You can&#39;t use the CloudWatch console to create or edit a resource policy. You must use the CloudWatch API, one of the AWS SDKs, or the AWS CLI.</p> </note> </li> </ol> </dd> <dt>Log Streams and Edge Locations</dt> <dd> <p>When Route 53 finishes creating the configuration for DNS query logging, it does the following:</p> <ul> <li> <p>Creates a log stream for an edge location the first time that the edge location responds to DNS queries for the specified hosted zone. That log stream is used to log all queries that Route 53 responds to for that edge location.</p> </li> <li> <p>Begins to send query logs to the applicable log stream.</p> </li> </ul> <p>The name of each log stream is in the following format:</p> <p> <code> <i>hosted zone ID</i>/<i>edge location code</i> </code> </p> <p>The edge location code is a three-letter code and an arbitrarily assigned number, for example, DFW3. The three-letter code typically corresponds with the International Air Transport Association airport code for an airport near the edge location. (These abbreviations might change in the future.) For a list of edge locations, see &quot;The Route 53 Global Network&quot; on the <a href="http://aws.amazon.com/route53/details/">Route 53 Product Details</a> page.</p> </dd> <dt>Queries That Are Logged</dt> <dd> <p>Query logs contain only the queries that DNS resolvers forward to Route 53. If a DNS resolver has already cached the response to a query (such as the IP address for a load balancer for example.com), the resolver will continue to return the cached response. It doesn&#39;t forward another query to Route 53 until the TTL for the corresponding resource record set expires. Depending on how many DNS queries are submitted for a resource record set, and depending on the TTL for that resource record set, query logs might contain information about only one query out of every several thousand queries that are submitted to DNS. For more information about how DNS works, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/welcome-dns-service.html">Routing Internet Traffic to Your Website or Web Application</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Log File Format</dt> <dd> <p>For a list of the values in each query log and the format of each value, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </dd> <dt>Pricing</dt> <dd> <p>For information about charges for query logs, see <a href="http://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> </dd> <dt>How to Stop Logging</dt> <dd> <p>If you want Route 53 to stop sending query logs to CloudWatch Logs, delete the query logging configuration. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_DeleteQueryLoggingConfig.html">DeleteQueryLoggingConfig</a>.</p> </dd> </dl></p>
+    ///
+    /// See `Route53::create_query_logging_config`.
+    pub fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> Result<CreateQueryLoggingConfigResponse, RusotoError<CreateQueryLoggingConfigError>> {
+        self.runtime
+            .block_on(self.client.create_query_logging_config(input))
+    }
+
+    /// <p><p>Creates a delegation set (a group of four name servers) that can be reused by multiple hosted zones that were created by the same AWS account. </p> <p>You can also create a reusable delegation set that uses the four name servers that are associated with an existing hosted zone. Specify the hosted zone ID in the <code>CreateReusableDelegationSet</code> request.</p> <note> <p>You can&#39;t associate a reusable delegation set with a private hosted zone.</p> </note> <p>For information about using a reusable delegation set to configure white label name servers, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/white-label-name-servers.html">Configuring White Label Name Servers</a>.</p> <p>The process for migrating existing hosted zones to use a reusable delegation set is comparable to the process for configuring white label name servers. You need to perform the following steps:</p> <ol> <li> <p>Create a reusable delegation set.</p> </li> <li> <p>Recreate hosted zones, and reduce the TTL to 60 seconds or less.</p> </li> <li> <p>Recreate resource record sets in the new hosted zones.</p> </li> <li> <p>Change the registrar&#39;s name servers to use the name servers for the new hosted zones.</p> </li> <li> <p>Monitor traffic for the website or application.</p> </li> <li> <p>Change TTLs back to their original values.</p> </li> </ol> <p>If you want to migrate existing hosted zones to use a reusable delegation set, the existing hosted zones can&#39;t use any of the name servers that are assigned to the reusable delegation set. If one or more hosted zones do use one or more name servers that are assigned to the reusable delegation set, you can do one of the following:</p> <ul> <li> <p>For small numbers of hosted zones—up to a few hundred—it&#39;s relatively easy to create reusable delegation sets until you get one that has four name servers that don&#39;t overlap with any of the name servers in your hosted zones.</p> </li> <li> <p>For larger numbers of hosted zones, the easiest solution is to use more than one reusable delegation set.</p> </li> <li> <p>For larger numbers of hosted zones, you can also migrate hosted zones that have overlapping name servers to hosted zones that don&#39;t have overlapping name servers, then migrate the hosted zones again to use the reusable delegation set.</p> </li> </ul></p>
+    ///
+    /// See `Route53::create_reusable_delegation_set`.
+    pub fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> Result<CreateReusableDelegationSetResponse, RusotoError<CreateReusableDelegationSetError>>
+    {
+        self.runtime
+            .block_on(self.client.create_reusable_delegation_set(input))
+    }
+
+    /// <p>Creates a traffic policy, which you use to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com).</p>
+    ///
+    /// See `Route53::create_traffic_policy`.
+    pub fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> Result<CreateTrafficPolicyResponse, RusotoError<CreateTrafficPolicyError>> {
+        self.runtime
+            .block_on(self.client.create_traffic_policy(input))
+    }
+
+    /// <p>Creates resource record sets in a specified hosted zone based on the settings in a specified traffic policy version. In addition, <code>CreateTrafficPolicyInstance</code> associates the resource record sets with a specified domain name (such as example.com) or subdomain name (such as www.example.com). Amazon Route 53 responds to DNS queries for the domain or subdomain name by using the resource record sets that <code>CreateTrafficPolicyInstance</code> created.</p>
+    ///
+    /// See `Route53::create_traffic_policy_instance`.
+    pub fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> Result<CreateTrafficPolicyInstanceResponse, RusotoError<CreateTrafficPolicyInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_policy_instance(input))
+    }
+
+    /// <p>Creates a new version of an existing traffic policy. When you create a new version of a traffic policy, you specify the ID of the traffic policy that you want to update and a JSON-formatted document that describes the new version. You use traffic policies to create multiple DNS resource record sets for one domain name (such as example.com) or one subdomain name (such as www.example.com). You can create a maximum of 1000 versions of a traffic policy. If you reach the limit and need to create another version, you'll need to start a new traffic policy.</p>
+    ///
+    /// See `Route53::create_traffic_policy_version`.
+    pub fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> Result<CreateTrafficPolicyVersionResponse, RusotoError<CreateTrafficPolicyVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_policy_version(input))
+    }
+
+    /// <p><p>Authorizes the AWS account that created a specified VPC to submit an <code>AssociateVPCWithHostedZone</code> request to associate the VPC with a specified hosted zone that was created by a different account. To submit a <code>CreateVPCAssociationAuthorization</code> request, you must use the account that created the hosted zone. After you authorize the association, use the account that created the VPC to submit an <code>AssociateVPCWithHostedZone</code> request.</p> <note> <p>If you want to associate multiple VPCs that you created by using one account with a hosted zone that you created by using a different account, you must submit one authorization request for each VPC.</p> </note></p>
+    ///
+    /// See `Route53::create_vpc_association_authorization`.
+    pub fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        CreateVPCAssociationAuthorizationResponse,
+        RusotoError<CreateVPCAssociationAuthorizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_vpc_association_authorization(input))
+    }
+
+    /// <p>Deletes a health check.</p> <important> <p>Amazon Route 53 does not prevent you from deleting a health check even if the health check is associated with one or more resource record sets. If you delete a health check and you don't update the associated resource record sets, the future status of the health check can't be predicted and may change. This will affect the routing of DNS queries for your DNS failover configuration. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html#health-checks-deleting.html">Replacing and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important> <p>If you're using AWS Cloud Map and you configured Cloud Map to create a Route 53 health check when you register an instance, you can't use the Route 53 <code>DeleteHealthCheck</code> command to delete the health check. The health check is deleted automatically when you deregister the instance; there can be a delay of several hours before the health check is deleted from Route 53. </p>
+    ///
+    /// See `Route53::delete_health_check`.
+    pub fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> Result<DeleteHealthCheckResponse, RusotoError<DeleteHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.delete_health_check(input))
+    }
+
+    /// <p><p>Deletes a hosted zone.</p> <p>If the hosted zone was created by another service, such as AWS Cloud Map, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DeleteHostedZone.html#delete-public-hosted-zone-created-by-another-service">Deleting Public Hosted Zones That Were Created by Another Service</a> in the <i>Amazon Route 53 Developer Guide</i> for information about how to delete it. (The process is the same for public and private hosted zones that were created by another service.)</p> <p>If you want to keep your domain registration but you want to stop routing internet traffic to your website or web application, we recommend that you delete resource record sets in the hosted zone instead of deleting the hosted zone.</p> <important> <p>If you delete a hosted zone, you can&#39;t undelete it. You must create a new hosted zone and update the name servers for your domain registration, which can require up to 48 hours to take effect. (If you delegated responsibility for a subdomain to a hosted zone and you delete the child hosted zone, you must update the name servers in the parent hosted zone.) In addition, if you delete a hosted zone, someone could hijack the domain and route traffic to their own resources using your domain name.</p> </important> <p>If you want to avoid the monthly charge for the hosted zone, you can transfer DNS service for the domain to a free DNS service. When you transfer DNS service, you have to update the name servers for the domain registration. If the domain is registered with Route 53, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_UpdateDomainNameservers.html">UpdateDomainNameservers</a> for information about how to replace Route 53 name servers with name servers for the new DNS service. If the domain is registered with another registrar, use the method provided by the registrar to update name servers for the domain registration. For more information, perform an internet search on &quot;free DNS service.&quot;</p> <p>You can delete a hosted zone only if it contains only the default SOA record and NS resource record sets. If the hosted zone contains other resource record sets, you must delete them before you can delete the hosted zone. If you try to delete a hosted zone that contains other resource record sets, the request fails, and Route 53 returns a <code>HostedZoneNotEmpty</code> error. For information about deleting records from your hosted zone, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html">ChangeResourceRecordSets</a>.</p> <p>To verify that the hosted zone has been deleted, do one of the following:</p> <ul> <li> <p>Use the <code>GetHostedZone</code> action to request information about the hosted zone.</p> </li> <li> <p>Use the <code>ListHostedZones</code> action to get a list of the hosted zones associated with the current AWS account.</p> </li> </ul></p>
+    ///
+    /// See `Route53::delete_hosted_zone`.
+    pub fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> Result<DeleteHostedZoneResponse, RusotoError<DeleteHostedZoneError>> {
+        self.runtime.block_on(self.client.delete_hosted_zone(input))
+    }
+
+    /// <p>Deletes a configuration for DNS query logging. If you delete a configuration, Amazon Route 53 stops sending query logs to CloudWatch Logs. Route 53 doesn't delete any logs that are already in CloudWatch Logs.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>.</p>
+    ///
+    /// See `Route53::delete_query_logging_config`.
+    pub fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> Result<DeleteQueryLoggingConfigResponse, RusotoError<DeleteQueryLoggingConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_query_logging_config(input))
+    }
+
+    /// <p>Deletes a reusable delegation set.</p> <important> <p>You can delete a reusable delegation set only if it isn't associated with any hosted zones.</p> </important> <p>To verify that the reusable delegation set is not associated with any hosted zones, submit a <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GetReusableDelegationSet.html">GetReusableDelegationSet</a> request and specify the ID of the reusable delegation set that you want to delete.</p>
+    ///
+    /// See `Route53::delete_reusable_delegation_set`.
+    pub fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> Result<DeleteReusableDelegationSetResponse, RusotoError<DeleteReusableDelegationSetError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_reusable_delegation_set(input))
+    }
+
+    /// <p>Deletes a traffic policy.</p>
+    ///
+    /// See `Route53::delete_traffic_policy`.
+    pub fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> Result<DeleteTrafficPolicyResponse, RusotoError<DeleteTrafficPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_traffic_policy(input))
+    }
+
+    /// <p><p>Deletes a traffic policy instance and all of the resource record sets that Amazon Route 53 created when you created the instance.</p> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
+    ///
+    /// See `Route53::delete_traffic_policy_instance`.
+    pub fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> Result<DeleteTrafficPolicyInstanceResponse, RusotoError<DeleteTrafficPolicyInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_traffic_policy_instance(input))
+    }
+
+    /// <p><p>Removes authorization to submit an <code>AssociateVPCWithHostedZone</code> request to associate a specified VPC with a hosted zone that was created by a different account. You must use the account that created the hosted zone to submit a <code>DeleteVPCAssociationAuthorization</code> request.</p> <important> <p>Sending this request only prevents the AWS account that created the VPC from associating the VPC with the Amazon Route 53 hosted zone in the future. If the VPC is already associated with the hosted zone, <code>DeleteVPCAssociationAuthorization</code> won&#39;t disassociate the VPC from the hosted zone. If you want to delete an existing association, use <code>DisassociateVPCFromHostedZone</code>.</p> </important></p>
+    ///
+    /// See `Route53::delete_vpc_association_authorization`.
+    pub fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        DeleteVPCAssociationAuthorizationResponse,
+        RusotoError<DeleteVPCAssociationAuthorizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_vpc_association_authorization(input))
+    }
+
+    /// <p><p>Disassociates an Amazon Virtual Private Cloud (Amazon VPC) from an Amazon Route 53 private hosted zone. Note the following:</p> <ul> <li> <p>You can&#39;t disassociate the last Amazon VPC from a private hosted zone.</p> </li> <li> <p>You can&#39;t convert a private hosted zone into a public hosted zone.</p> </li> <li> <p>You can submit a <code>DisassociateVPCFromHostedZone</code> request using either the account that created the hosted zone or the account that created the Amazon VPC.</p> </li> <li> <p>Some services, such as AWS Cloud Map and Amazon Elastic File System (Amazon EFS) automatically create hosted zones and associate VPCs with the hosted zones. A service can create a hosted zone using your account or using its own account. You can disassociate a VPC from a hosted zone only if the service created the hosted zone using your account.</p> <p>When you run <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_ListHostedZonesByVPC.html">DisassociateVPCFromHostedZone</a>, if the hosted zone has a value for <code>OwningAccount</code>, you can use <code>DisassociateVPCFromHostedZone</code>. If the hosted zone has a value for <code>OwningService</code>, you can&#39;t use <code>DisassociateVPCFromHostedZone</code>.</p> </li> </ul></p>
+    ///
+    /// See `Route53::disassociate_vpc_from_hosted_zone`.
+    pub fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> Result<
+        DisassociateVPCFromHostedZoneResponse,
+        RusotoError<DisassociateVPCFromHostedZoneError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_vpc_from_hosted_zone(input))
+    }
+
+    /// <p><p>Gets the specified limit for the current account, for example, the maximum number of health checks that you can create using the account.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p> <note> <p>You can also view account limits in AWS Trusted Advisor. Sign in to the AWS Management Console and open the Trusted Advisor console at <a href="https://console.aws.amazon.com/trustedadvisor">https://console.aws.amazon.com/trustedadvisor/</a>. Then choose <b>Service limits</b> in the navigation pane.</p> </note></p>
+    ///
+    /// See `Route53::get_account_limit`.
+    pub fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> Result<GetAccountLimitResponse, RusotoError<GetAccountLimitError>> {
+        self.runtime.block_on(self.client.get_account_limit(input))
+    }
+
+    /// <p><p>Returns the current status of a change batch request. The status is one of the following values:</p> <ul> <li> <p> <code>PENDING</code> indicates that the changes in this request have not propagated to all Amazon Route 53 DNS servers. This is the initial status of all change batch requests.</p> </li> <li> <p> <code>INSYNC</code> indicates that the changes have propagated to all Route 53 DNS servers. </p> </li> </ul></p>
+    ///
+    /// See `Route53::get_change`.
+    pub fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> Result<GetChangeResponse, RusotoError<GetChangeError>> {
+        self.runtime.block_on(self.client.get_change(input))
+    }
+
+    /// <p><important> <p> <code>GetCheckerIpRanges</code> still works, but we recommend that you download ip-ranges.json, which includes IP address ranges for all AWS services. For more information, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/route-53-ip-addresses.html">IP Address Ranges of Amazon Route 53 Servers</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important></p>
+    ///
+    /// See `Route53::get_checker_ip_ranges`.
+    pub fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> Result<GetCheckerIpRangesResponse, RusotoError<GetCheckerIpRangesError>> {
+        self.runtime
+            .block_on(self.client.get_checker_ip_ranges(input))
+    }
+
+    /// <p>Gets information about whether a specified geographic location is supported for Amazon Route 53 geolocation resource record sets.</p> <p>Use the following syntax to determine whether a continent is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?continentcode=<i>two-letter abbreviation for a continent</i> </code> </p> <p>Use the following syntax to determine whether a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i> </code> </p> <p>Use the following syntax to determine whether a subdivision of a country is supported for geolocation:</p> <p> <code>GET /2013-04-01/geolocation?countrycode=<i>two-character country code</i>&amp;subdivisioncode=<i>subdivision code</i> </code> </p>
+    ///
+    /// See `Route53::get_geo_location`.
+    pub fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> Result<GetGeoLocationResponse, RusotoError<GetGeoLocationError>> {
+        self.runtime.block_on(self.client.get_geo_location(input))
+    }
+
+    /// <p>Gets information about a specified health check.</p>
+    ///
+    /// See `Route53::get_health_check`.
+    pub fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> Result<GetHealthCheckResponse, RusotoError<GetHealthCheckError>> {
+        self.runtime.block_on(self.client.get_health_check(input))
+    }
+
+    /// <p>Retrieves the number of health checks that are associated with the current AWS account.</p>
+    ///
+    /// See `Route53::get_health_check_count`.
+    pub fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> Result<GetHealthCheckCountResponse, RusotoError<GetHealthCheckCountError>> {
+        self.runtime
+            .block_on(self.client.get_health_check_count(input))
+    }
+
+    /// <p>Gets the reason that a specified health check failed most recently.</p>
+    ///
+    /// See `Route53::get_health_check_last_failure_reason`.
+    pub fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> Result<
+        GetHealthCheckLastFailureReasonResponse,
+        RusotoError<GetHealthCheckLastFailureReasonError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_health_check_last_failure_reason(input))
+    }
+
+    /// <p>Gets status of a specified health check. </p>
+    ///
+    /// See `Route53::get_health_check_status`.
+    pub fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> Result<GetHealthCheckStatusResponse, RusotoError<GetHealthCheckStatusError>> {
+        self.runtime
+            .block_on(self.client.get_health_check_status(input))
+    }
+
+    /// <p>Gets information about a specified hosted zone including the four name servers assigned to the hosted zone.</p>
+    ///
+    /// See `Route53::get_hosted_zone`.
+    pub fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> Result<GetHostedZoneResponse, RusotoError<GetHostedZoneError>> {
+        self.runtime.block_on(self.client.get_hosted_zone(input))
+    }
+
+    /// <p>Retrieves the number of hosted zones that are associated with the current AWS account.</p>
+    ///
+    /// See `Route53::get_hosted_zone_count`.
+    pub fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> Result<GetHostedZoneCountResponse, RusotoError<GetHostedZoneCountError>> {
+        self.runtime
+            .block_on(self.client.get_hosted_zone_count(input))
+    }
+
+    /// <p>Gets the specified limit for a specified hosted zone, for example, the maximum number of records that you can create in the hosted zone. </p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
+    ///
+    /// See `Route53::get_hosted_zone_limit`.
+    pub fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> Result<GetHostedZoneLimitResponse, RusotoError<GetHostedZoneLimitError>> {
+        self.runtime
+            .block_on(self.client.get_hosted_zone_limit(input))
+    }
+
+    /// <p>Gets information about a specified configuration for DNS query logging.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a> and <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a>.</p>
+    ///
+    /// See `Route53::get_query_logging_config`.
+    pub fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> Result<GetQueryLoggingConfigResponse, RusotoError<GetQueryLoggingConfigError>> {
+        self.runtime
+            .block_on(self.client.get_query_logging_config(input))
+    }
+
+    /// <p>Retrieves information about a specified reusable delegation set, including the four name servers that are assigned to the delegation set.</p>
+    ///
+    /// See `Route53::get_reusable_delegation_set`.
+    pub fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> Result<GetReusableDelegationSetResponse, RusotoError<GetReusableDelegationSetError>> {
+        self.runtime
+            .block_on(self.client.get_reusable_delegation_set(input))
+    }
+
+    /// <p>Gets the maximum number of hosted zones that you can associate with the specified reusable delegation set.</p> <p>For the default limit, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DNSLimitations.html">Limits</a> in the <i>Amazon Route 53 Developer Guide</i>. To request a higher limit, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-route53">open a case</a>.</p>
+    ///
+    /// See `Route53::get_reusable_delegation_set_limit`.
+    pub fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> Result<
+        GetReusableDelegationSetLimitResponse,
+        RusotoError<GetReusableDelegationSetLimitError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reusable_delegation_set_limit(input))
+    }
+
+    /// <p>Gets information about a specific traffic policy version.</p>
+    ///
+    /// See `Route53::get_traffic_policy`.
+    pub fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> Result<GetTrafficPolicyResponse, RusotoError<GetTrafficPolicyError>> {
+        self.runtime.block_on(self.client.get_traffic_policy(input))
+    }
+
+    /// <p><p>Gets information about a specified traffic policy instance.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there&#39;s a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <note> <p>In the Route 53 console, traffic policy instances are known as policy records.</p> </note></p>
+    ///
+    /// See `Route53::get_traffic_policy_instance`.
+    pub fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> Result<GetTrafficPolicyInstanceResponse, RusotoError<GetTrafficPolicyInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_traffic_policy_instance(input))
+    }
+
+    /// <p>Gets the number of traffic policy instances that are associated with the current AWS account.</p>
+    ///
+    /// See `Route53::get_traffic_policy_instance_count`.
+    pub fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> Result<
+        GetTrafficPolicyInstanceCountResponse,
+        RusotoError<GetTrafficPolicyInstanceCountError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_traffic_policy_instance_count(input))
+    }
+
+    /// <p>Retrieves a list of supported geographic locations.</p> <p>Countries are listed first, and continents are listed last. If Amazon Route 53 supports subdivisions for a country (for example, states or provinces), the subdivisions for that country are listed in alphabetical order immediately after the corresponding country.</p> <p>For a list of supported geolocation codes, see the <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_GeoLocation.html">GeoLocation</a> data type.</p>
+    ///
+    /// See `Route53::list_geo_locations`.
+    pub fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> Result<ListGeoLocationsResponse, RusotoError<ListGeoLocationsError>> {
+        self.runtime.block_on(self.client.list_geo_locations(input))
+    }
+
+    /// <p>Retrieve a list of the health checks that are associated with the current AWS account. </p>
+    ///
+    /// See `Route53::list_health_checks`.
+    pub fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> Result<ListHealthChecksResponse, RusotoError<ListHealthChecksError>> {
+        self.runtime.block_on(self.client.list_health_checks(input))
+    }
+
+    /// <p>Retrieves a list of the public and private hosted zones that are associated with the current AWS account. The response includes a <code>HostedZones</code> child element for each hosted zone.</p> <p>Amazon Route 53 returns a maximum of 100 items in each response. If you have a lot of hosted zones, you can use the <code>maxitems</code> parameter to list them in groups of up to 100.</p>
+    ///
+    /// See `Route53::list_hosted_zones`.
+    pub fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> Result<ListHostedZonesResponse, RusotoError<ListHostedZonesError>> {
+        self.runtime.block_on(self.client.list_hosted_zones(input))
+    }
+
+    /// <p><p>Retrieves a list of your hosted zones in lexicographic order. The response includes a <code>HostedZones</code> child element for each hosted zone created by the current AWS account. </p> <p> <code>ListHostedZonesByName</code> sorts hosted zones by name with the labels reversed. For example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order in some circumstances.</p> <p>If the domain name includes escape characters or Punycode, <code>ListHostedZonesByName</code> alphabetizes the domain name using the escaped or Punycoded value, which is the format that Amazon Route 53 saves in its database. For example, to create a hosted zone for exämple.com, you specify ex\344mple.com for the domain name. <code>ListHostedZonesByName</code> alphabetizes it as:</p> <p> <code>com.ex\344mple.</code> </p> <p>The labels are reversed and alphabetized using the escaped value. For more information about valid domain name formats, including internationalized domain names, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/DomainNameFormat.html">DNS Domain Name Format</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>Route 53 returns up to 100 items in each response. If you have a lot of hosted zones, use the <code>MaxItems</code> parameter to list them in groups of up to 100. The response includes values that help navigate from one group of <code>MaxItems</code> hosted zones to the next:</p> <ul> <li> <p>The <code>DNSName</code> and <code>HostedZoneId</code> elements in the response contain the values, if any, specified for the <code>dnsname</code> and <code>hostedzoneid</code> parameters in the request that produced the current response.</p> </li> <li> <p>The <code>MaxItems</code> element in the response contains the value, if any, that you specified for the <code>maxitems</code> parameter in the request that produced the current response.</p> </li> <li> <p>If the value of <code>IsTruncated</code> in the response is true, there are more hosted zones associated with the current AWS account. </p> <p>If <code>IsTruncated</code> is false, this response includes the last hosted zone that is associated with the current account. The <code>NextDNSName</code> element and <code>NextHostedZoneId</code> elements are omitted from the response.</p> </li> <li> <p>The <code>NextDNSName</code> and <code>NextHostedZoneId</code> elements in the response contain the domain name and the hosted zone ID of the next hosted zone that is associated with the current AWS account. If you want to list more hosted zones, make another call to <code>ListHostedZonesByName</code>, and specify the value of <code>NextDNSName</code> and <code>NextHostedZoneId</code> in the <code>dnsname</code> and <code>hostedzoneid</code> parameters, respectively.</p> </li> </ul></p>
+    ///
+    /// See `Route53::list_hosted_zones_by_name`.
+    pub fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> Result<ListHostedZonesByNameResponse, RusotoError<ListHostedZonesByNameError>> {
+        self.runtime
+            .block_on(self.client.list_hosted_zones_by_name(input))
+    }
+
+    /// <p><p>Lists all the private hosted zones that a specified VPC is associated with, regardless of which AWS account or AWS service owns the hosted zones. The <code>HostedZoneOwner</code> structure in the response contains one of the following values:</p> <ul> <li> <p>An <code>OwningAccount</code> element, which contains the account number of either the current AWS account or another AWS account. Some services, such as AWS Cloud Map, create hosted zones using the current account. </p> </li> <li> <p>An <code>OwningService</code> element, which identifies the AWS service that created and owns the hosted zone. For example, if a hosted zone was created by Amazon Elastic File System (Amazon EFS), the value of <code>Owner</code> is <code>efs.amazonaws.com</code>. </p> </li> </ul></p>
+    ///
+    /// See `Route53::list_hosted_zones_by_vpc`.
+    pub fn list_hosted_zones_by_vpc(
+        &self,
+        input: ListHostedZonesByVPCRequest,
+    ) -> Result<ListHostedZonesByVPCResponse, RusotoError<ListHostedZonesByVPCError>> {
+        self.runtime
+            .block_on(self.client.list_hosted_zones_by_vpc(input))
+    }
+
+    /// <p>Lists the configurations for DNS query logging that are associated with the current AWS account or the configuration that is associated with a specified hosted zone.</p> <p>For more information about DNS query logs, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_CreateQueryLoggingConfig.html">CreateQueryLoggingConfig</a>. Additional information, including the format of DNS query logs, appears in <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/query-logs.html">Logging DNS Queries</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    ///
+    /// See `Route53::list_query_logging_configs`.
+    pub fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> Result<ListQueryLoggingConfigsResponse, RusotoError<ListQueryLoggingConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_query_logging_configs(input))
+    }
+
+    /// <p>Lists the resource record sets in a specified hosted zone.</p> <p> <code>ListResourceRecordSets</code> returns up to 100 resource record sets at a time in ASCII order, beginning at a position specified by the <code>name</code> and <code>type</code> elements.</p> <p> <b>Sort order</b> </p> <p> <code>ListResourceRecordSets</code> sorts results first by DNS name with the labels reversed, for example:</p> <p> <code>com.example.www.</code> </p> <p>Note the trailing dot, which can change the sort order when the record name contains characters that appear before <code>.</code> (decimal 46) in the ASCII table. These characters include the following: <code>! " # $ % &amp; ' ( ) * + , -</code> </p> <p>When multiple records have the same DNS name, <code>ListResourceRecordSets</code> sorts results by the record type.</p> <p> <b>Specifying where to start listing records</b> </p> <p>You can use the name and type elements to specify the resource record set that the list begins with:</p> <dl> <dt>If you do not specify Name or Type</dt> <dd> <p>The results begin with the first resource record set that the hosted zone contains.</p> </dd> <dt>If you specify Name but not Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>.</p> </dd> <dt>If you specify Type but not Name</dt> <dd> <p>Amazon Route 53 returns the <code>InvalidInput</code> error.</p> </dd> <dt>If you specify both Name and Type</dt> <dd> <p>The results begin with the first resource record set in the list whose name is greater than or equal to <code>Name</code>, and whose type is greater than or equal to <code>Type</code>.</p> </dd> </dl> <p> <b>Resource record sets that are PENDING</b> </p> <p>This action returns the most current version of the records. This includes records that are <code>PENDING</code>, and that are not yet available on all Route 53 DNS servers.</p> <p> <b>Changing resource record sets</b> </p> <p>To ensure that you get an accurate listing of the resource record sets for a hosted zone at a point in time, do not submit a <code>ChangeResourceRecordSets</code> request while you're paging through the results of a <code>ListResourceRecordSets</code> request. If you do, some pages may display results without the latest changes while other pages display results with the latest changes.</p> <p> <b>Displaying the next page of results</b> </p> <p>If a <code>ListResourceRecordSets</code> command returns more than one page of results, the value of <code>IsTruncated</code> is <code>true</code>. To display the next page of results, get the values of <code>NextRecordName</code>, <code>NextRecordType</code>, and <code>NextRecordIdentifier</code> (if any) from the response. Then submit another <code>ListResourceRecordSets</code> request, and specify those values for <code>StartRecordName</code>, <code>StartRecordType</code>, and <code>StartRecordIdentifier</code>.</p>
+    ///
+    /// See `Route53::list_resource_record_sets`.
+    pub fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> Result<ListResourceRecordSetsResponse, RusotoError<ListResourceRecordSetsError>> {
+        self.runtime
+            .block_on(self.client.list_resource_record_sets(input))
+    }
+
+    /// <p>Retrieves a list of the reusable delegation sets that are associated with the current AWS account.</p>
+    ///
+    /// See `Route53::list_reusable_delegation_sets`.
+    pub fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> Result<ListReusableDelegationSetsResponse, RusotoError<ListReusableDelegationSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_reusable_delegation_sets(input))
+    }
+
+    /// <p>Lists tags for one health check or hosted zone. </p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    ///
+    /// See `Route53::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists tags for up to 10 health checks or hosted zones.</p> <p>For information about using tags for cost allocation, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p>
+    ///
+    /// See `Route53::list_tags_for_resources`.
+    pub fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> Result<ListTagsForResourcesResponse, RusotoError<ListTagsForResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resources(input))
+    }
+
+    /// <p>Gets information about the latest version for every traffic policy that is associated with the current AWS account. Policies are listed in the order that they were created in. </p>
+    ///
+    /// See `Route53::list_traffic_policies`.
+    pub fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> Result<ListTrafficPoliciesResponse, RusotoError<ListTrafficPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_traffic_policies(input))
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created by using the current AWS account.</p> <note> <p>After you submit an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    ///
+    /// See `Route53::list_traffic_policy_instances`.
+    pub fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> Result<ListTrafficPolicyInstancesResponse, RusotoError<ListTrafficPolicyInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_traffic_policy_instances(input))
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created in a specified hosted zone.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    ///
+    /// See `Route53::list_traffic_policy_instances_by_hosted_zone`.
+    pub fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> Result<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_traffic_policy_instances_by_hosted_zone(input),
+        )
+    }
+
+    /// <p>Gets information about the traffic policy instances that you created by using a specify traffic policy version.</p> <note> <p>After you submit a <code>CreateTrafficPolicyInstance</code> or an <code>UpdateTrafficPolicyInstance</code> request, there's a brief delay while Amazon Route 53 creates the resource record sets that are specified in the traffic policy definition. For more information, see the <code>State</code> response element.</p> </note> <p>Route 53 returns a maximum of 100 items in each response. If you have a lot of traffic policy instances, you can use the <code>MaxItems</code> parameter to list them in groups of up to 100.</p>
+    ///
+    /// See `Route53::list_traffic_policy_instances_by_policy`.
+    pub fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> Result<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        RusotoError<ListTrafficPolicyInstancesByPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_traffic_policy_instances_by_policy(input))
+    }
+
+    /// <p>Gets information about all of the versions for a specified traffic policy.</p> <p>Traffic policy versions are listed in numerical order by <code>VersionNumber</code>.</p>
+    ///
+    /// See `Route53::list_traffic_policy_versions`.
+    pub fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> Result<ListTrafficPolicyVersionsResponse, RusotoError<ListTrafficPolicyVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_traffic_policy_versions(input))
+    }
+
+    /// <p>Gets a list of the VPCs that were created by other accounts and that can be associated with a specified hosted zone because you've submitted one or more <code>CreateVPCAssociationAuthorization</code> requests. </p> <p>The response includes a <code>VPCs</code> element with a <code>VPC</code> child element for each VPC that can be associated with the hosted zone.</p>
+    ///
+    /// See `Route53::list_vpc_association_authorizations`.
+    pub fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> Result<
+        ListVPCAssociationAuthorizationsResponse,
+        RusotoError<ListVPCAssociationAuthorizationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_vpc_association_authorizations(input))
+    }
+
+    /// <p>Gets the value that Amazon Route 53 returns in response to a DNS request for a specified record name and type. You can optionally specify the IP address of a DNS resolver, an EDNS0 client subnet IP address, and a subnet mask. </p>
+    ///
+    /// See `Route53::test_dns_answer`.
+    pub fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> Result<TestDNSAnswerResponse, RusotoError<TestDNSAnswerError>> {
+        self.runtime.block_on(self.client.test_dns_answer(input))
+    }
+
+    /// <p>Updates an existing health check. Note that some values can't be updated. </p> <p>For more information about updating health checks, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/health-checks-creating-deleting.html">Creating, Updating, and Deleting Health Checks</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    ///
+    /// See `Route53::update_health_check`.
+    pub fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> Result<UpdateHealthCheckResponse, RusotoError<UpdateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.update_health_check(input))
+    }
+
+    /// <p>Updates the comment for a specified hosted zone.</p>
+    ///
+    /// See `Route53::update_hosted_zone_comment`.
+    pub fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> Result<UpdateHostedZoneCommentResponse, RusotoError<UpdateHostedZoneCommentError>> {
+        self.runtime
+            .block_on(self.client.update_hosted_zone_comment(input))
+    }
+
+    /// <p>Updates the comment for a specified traffic policy version.</p>
+    ///
+    /// See `Route53::update_traffic_policy_comment`.
+    pub fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> Result<UpdateTrafficPolicyCommentResponse, RusotoError<UpdateTrafficPolicyCommentError>>
+    {
+        self.runtime
+            .block_on(self.client.update_traffic_policy_comment(input))
+    }
+
+    /// <p><p>Updates the resource record sets in a specified hosted zone that were created based on the settings in a specified traffic policy version.</p> <p>When you update a traffic policy instance, Amazon Route 53 continues to respond to DNS queries for the root resource record set name (such as example.com) while it replaces one group of resource record sets with another. Route 53 performs the following operations:</p> <ol> <li> <p>Route 53 creates a new group of resource record sets based on the specified traffic policy. This is true regardless of how significant the differences are between the existing resource record sets and the new resource record sets. </p> </li> <li> <p>When all of the new resource record sets have been created, Route 53 starts to respond to DNS queries for the root resource record set name (such as example.com) by using the new resource record sets.</p> </li> <li> <p>Route 53 deletes the old group of resource record sets that are associated with the root resource record set name.</p> </li> </ol></p>
+    ///
+    /// See `Route53::update_traffic_policy_instance`.
+    pub fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> Result<UpdateTrafficPolicyInstanceResponse, RusotoError<UpdateTrafficPolicyInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.update_traffic_policy_instance(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -1024,19 +1024,22 @@ impl AcceptDomainTransferFromAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptDomainTransferFromAnotherAwsAccountError::DomainLimitExceeded(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptDomainTransferFromAnotherAwsAccountError::InvalidInput(err.msg),
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AcceptDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded(
                             err.msg,
                         ),
@@ -1082,12 +1085,14 @@ impl CancelDomainTransferToAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelDomainTransferToAnotherAwsAccountError::InvalidInput(err.msg),
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelDomainTransferToAnotherAwsAccountError::OperationLimitExceeded(
                             err.msg,
                         ),
@@ -1128,14 +1133,16 @@ impl CheckDomainAvailabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CheckDomainAvailabilityError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CheckDomainAvailabilityError::InvalidInput(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(CheckDomainAvailabilityError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CheckDomainAvailabilityError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1170,14 +1177,16 @@ impl CheckDomainTransferabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CheckDomainTransferabilityError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CheckDomainTransferabilityError::InvalidInput(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(CheckDomainTransferabilityError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CheckDomainTransferabilityError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1212,15 +1221,22 @@ impl DeleteTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsForDomainError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsForDomainError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsForDomainError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1254,12 +1270,16 @@ impl DisableDomainAutoRenewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DisableDomainAutoRenewError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableDomainAutoRenewError::InvalidInput(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DisableDomainAutoRenewError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableDomainAutoRenewError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1298,29 +1318,34 @@ impl DisableDomainTransferLockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableDomainTransferLockError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableDomainTransferLockError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableDomainTransferLockError::OperationLimitExceeded(err.msg),
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableDomainTransferLockError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableDomainTransferLockError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1360,17 +1385,22 @@ impl EnableDomainAutoRenewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainAutoRenewError::InvalidInput(err.msg),
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainAutoRenewError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainAutoRenewError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1410,29 +1440,34 @@ impl EnableDomainTransferLockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainTransferLockError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainTransferLockError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableDomainTransferLockError::OperationLimitExceeded(err.msg),
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainTransferLockError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableDomainTransferLockError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1474,19 +1509,22 @@ impl GetContactReachabilityStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetContactReachabilityStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContactReachabilityStatusError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetContactReachabilityStatusError::OperationLimitExceeded(err.msg),
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetContactReachabilityStatusError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetContactReachabilityStatusError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1522,10 +1560,13 @@ impl GetDomainDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetDomainDetailError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetDomainDetailError::InvalidInput(err.msg))
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetDomainDetailError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainDetailError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1558,10 +1599,16 @@ impl GetDomainSuggestionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetDomainSuggestionsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainSuggestionsError::InvalidInput(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetDomainSuggestionsError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDomainSuggestionsError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1592,7 +1639,10 @@ impl GetOperationDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetOperationDetailError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationDetailError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1622,7 +1672,7 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListDomainsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListDomainsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1652,7 +1702,7 @@ impl ListOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListOperationsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1686,15 +1736,22 @@ impl ListTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForDomainError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(ListTagsForDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForDomainError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(ListTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForDomainError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1736,24 +1793,34 @@ impl RegisterDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(RegisterDomainError::DomainLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::DomainLimitExceeded(err.msg),
+                    )
                 }
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RegisterDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RegisterDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, RegisterDomainError::InvalidInput(err.msg))
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(RegisterDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(RegisterDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RegisterDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(&res, RegisterDomainError::UnsupportedTLD(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1792,12 +1859,14 @@ impl RejectDomainTransferFromAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectDomainTransferFromAnotherAwsAccountError::InvalidInput(err.msg),
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RejectDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded(
                             err.msg,
                         ),
@@ -1844,19 +1913,22 @@ impl RenewDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RenewDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service(&res, RenewDomainError::DuplicateRequest(err.msg))
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RenewDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, RenewDomainError::InvalidInput(err.msg))
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(RenewDomainError::OperationLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RenewDomainError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(RenewDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service(&res, RenewDomainError::TLDRulesViolation(err.msg))
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RenewDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(&res, RenewDomainError::UnsupportedTLD(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1896,17 +1968,20 @@ impl ResendContactReachabilityEmailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ResendContactReachabilityEmailError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResendContactReachabilityEmailError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendContactReachabilityEmailError::OperationLimitExceeded(err.msg),
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResendContactReachabilityEmailError::UnsupportedTLD(err.msg),
                     )
                 }
@@ -1946,12 +2021,16 @@ impl RetrieveDomainAuthCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(RetrieveDomainAuthCodeError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RetrieveDomainAuthCodeError::InvalidInput(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RetrieveDomainAuthCodeError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RetrieveDomainAuthCodeError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1992,24 +2071,34 @@ impl TransferDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(TransferDomainError::DomainLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferDomainError::DomainLimitExceeded(err.msg),
+                    )
                 }
                 "DuplicateRequest" => {
-                    return RusotoError::Service(TransferDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferDomainError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(TransferDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TransferDomainError::InvalidInput(err.msg))
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(TransferDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TransferDomainError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(TransferDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TransferDomainError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(TransferDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(&res, TransferDomainError::UnsupportedTLD(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2050,17 +2139,20 @@ impl TransferDomainToAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TransferDomainToAnotherAwsAccountError::DuplicateRequest(err.msg),
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TransferDomainToAnotherAwsAccountError::InvalidInput(err.msg),
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TransferDomainToAnotherAwsAccountError::OperationLimitExceeded(err.msg),
                     )
                 }
@@ -2108,25 +2200,34 @@ impl UpdateDomainContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainContactError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainContactError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(UpdateDomainContactError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(UpdateDomainContactError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainContactError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2170,29 +2271,34 @@ impl UpdateDomainContactPrivacyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactPrivacyError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactPrivacyError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDomainContactPrivacyError::OperationLimitExceeded(err.msg),
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDomainContactPrivacyError::TLDRulesViolation(err.msg),
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainContactPrivacyError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2236,29 +2342,34 @@ impl UpdateDomainNameserversError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainNameserversError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainNameserversError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDomainNameserversError::OperationLimitExceeded(err.msg),
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::TLDRulesViolation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainNameserversError::TLDRulesViolation(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::UnsupportedTLD(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainNameserversError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2298,15 +2409,22 @@ impl UpdateTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagsForDomainError::InvalidInput(err.msg),
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::OperationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagsForDomainError::OperationLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagsForDomainError::UnsupportedTLD(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2338,7 +2456,7 @@ impl ViewBillingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ViewBillingError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ViewBillingError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2560,6 +2678,7 @@ impl Route53DomainsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2575,6 +2694,7 @@ impl Route53DomainsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsClient {
         Route53DomainsClient { client, region }
     }
@@ -2591,6 +2711,9 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<AcceptDomainTransferFromAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptDomainTransferFromAnotherAwsAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.AcceptDomainTransferFromAnotherAwsAccount",
@@ -2619,6 +2742,9 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<CancelDomainTransferToAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelDomainTransferToAnotherAwsAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.CancelDomainTransferToAnotherAwsAccount",
@@ -2644,6 +2770,9 @@ impl Route53Domains for Route53DomainsClient {
         input: CheckDomainAvailabilityRequest,
     ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CheckDomainAvailability");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.CheckDomainAvailability",
@@ -2667,6 +2796,9 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CheckDomainTransferability");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.CheckDomainTransferability",
@@ -2689,6 +2821,9 @@ impl Route53Domains for Route53DomainsClient {
         input: DeleteTagsForDomainRequest,
     ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTagsForDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.DeleteTagsForDomain",
@@ -2710,6 +2845,9 @@ impl Route53Domains for Route53DomainsClient {
         input: DisableDomainAutoRenewRequest,
     ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableDomainAutoRenew");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.DisableDomainAutoRenew",
@@ -2733,6 +2871,9 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableDomainTransferLock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.DisableDomainTransferLock",
@@ -2755,6 +2896,9 @@ impl Route53Domains for Route53DomainsClient {
         input: EnableDomainAutoRenewRequest,
     ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableDomainAutoRenew");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.EnableDomainAutoRenew",
@@ -2777,6 +2921,9 @@ impl Route53Domains for Route53DomainsClient {
         input: EnableDomainTransferLockRequest,
     ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableDomainTransferLock");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.EnableDomainTransferLock",
@@ -2800,6 +2947,9 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetContactReachabilityStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.GetContactReachabilityStatus",
@@ -2822,6 +2972,9 @@ impl Route53Domains for Route53DomainsClient {
         input: GetDomainDetailRequest,
     ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDomainDetail");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Domains_v20140515.GetDomainDetail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2840,6 +2993,9 @@ impl Route53Domains for Route53DomainsClient {
         input: GetDomainSuggestionsRequest,
     ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDomainSuggestions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.GetDomainSuggestions",
@@ -2862,6 +3018,9 @@ impl Route53Domains for Route53DomainsClient {
         input: GetOperationDetailRequest,
     ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOperationDetail");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.GetOperationDetail",
@@ -2883,6 +3042,9 @@ impl Route53Domains for Route53DomainsClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2901,6 +3063,9 @@ impl Route53Domains for Route53DomainsClient {
         input: ListOperationsRequest,
     ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2919,6 +3084,9 @@ impl Route53Domains for Route53DomainsClient {
         input: ListTagsForDomainRequest,
     ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListTagsForDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2937,6 +3105,9 @@ impl Route53Domains for Route53DomainsClient {
         input: RegisterDomainRequest,
     ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Domains_v20140515.RegisterDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2958,6 +3129,9 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<RejectDomainTransferFromAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RejectDomainTransferFromAnotherAwsAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.RejectDomainTransferFromAnotherAwsAccount",
@@ -2983,6 +3157,9 @@ impl Route53Domains for Route53DomainsClient {
         input: RenewDomainRequest,
     ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RenewDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Domains_v20140515.RenewDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3004,6 +3181,9 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<ResendContactReachabilityEmailError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResendContactReachabilityEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.ResendContactReachabilityEmail",
@@ -3026,6 +3206,9 @@ impl Route53Domains for Route53DomainsClient {
         input: RetrieveDomainAuthCodeRequest,
     ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RetrieveDomainAuthCode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.RetrieveDomainAuthCode",
@@ -3048,6 +3231,9 @@ impl Route53Domains for Route53DomainsClient {
         input: TransferDomainRequest,
     ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TransferDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Domains_v20140515.TransferDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3069,6 +3255,9 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<TransferDomainToAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TransferDomainToAnotherAwsAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.TransferDomainToAnotherAwsAccount",
@@ -3094,6 +3283,9 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateDomainContactRequest,
     ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDomainContact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateDomainContact",
@@ -3116,6 +3308,9 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDomainContactPrivacy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateDomainContactPrivacy",
@@ -3138,6 +3333,9 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateDomainNameserversRequest,
     ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDomainNameservers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateDomainNameservers",
@@ -3160,6 +3358,9 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateTagsForDomainRequest,
     ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTagsForDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateTagsForDomain",
@@ -3181,6 +3382,9 @@ impl Route53Domains for Route53DomainsClient {
         input: ViewBillingRequest,
     ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ViewBilling");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Domains_v20140515.ViewBilling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3193,3 +3397,379 @@ impl Route53Domains for Route53DomainsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ViewBillingResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Route 53 Domains API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Route53DomainsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Route53DomainsBlockingClient {
+    client: Route53DomainsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Route53DomainsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Route53DomainsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Route53DomainsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53DomainsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Route53DomainsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient {
+            client: Route53DomainsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts the transfer of a domain from another AWS account to the current AWS account. You initiate a transfer between AWS accounts using <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_TransferDomainToAnotherAwsAccount.html">TransferDomainToAnotherAwsAccount</a>. </p> <p>Use either <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_ListOperations.html">ListOperations</a> or <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> to determine whether the operation succeeded. <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> provides additional information, for example, <code>Domain Transfer from Aws Account 111122223333 has been cancelled</code>. </p>
+    ///
+    /// See `Route53Domains::accept_domain_transfer_from_another_aws_account`.
+    pub fn accept_domain_transfer_from_another_aws_account(
+        &self,
+        input: AcceptDomainTransferFromAnotherAwsAccountRequest,
+    ) -> Result<
+        AcceptDomainTransferFromAnotherAwsAccountResponse,
+        RusotoError<AcceptDomainTransferFromAnotherAwsAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_domain_transfer_from_another_aws_account(input),
+        )
+    }
+
+    /// <p>Cancels the transfer of a domain from the current AWS account to another AWS account. You initiate a transfer between AWS accounts using <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_TransferDomainToAnotherAwsAccount.html">TransferDomainToAnotherAwsAccount</a>. </p> <important> <p>You must cancel the transfer before the other AWS account accepts the transfer using <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_AcceptDomainTransferFromAnotherAwsAccount.html">AcceptDomainTransferFromAnotherAwsAccount</a>.</p> </important> <p>Use either <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_ListOperations.html">ListOperations</a> or <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> to determine whether the operation succeeded. <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> provides additional information, for example, <code>Domain Transfer from Aws Account 111122223333 has been cancelled</code>. </p>
+    ///
+    /// See `Route53Domains::cancel_domain_transfer_to_another_aws_account`.
+    pub fn cancel_domain_transfer_to_another_aws_account(
+        &self,
+        input: CancelDomainTransferToAnotherAwsAccountRequest,
+    ) -> Result<
+        CancelDomainTransferToAnotherAwsAccountResponse,
+        RusotoError<CancelDomainTransferToAnotherAwsAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .cancel_domain_transfer_to_another_aws_account(input),
+        )
+    }
+
+    /// <p>This operation checks the availability of one domain name. Note that if the availability status of a domain is pending, you must submit another request to determine the availability of the domain name.</p>
+    ///
+    /// See `Route53Domains::check_domain_availability`.
+    pub fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
+        self.runtime
+            .block_on(self.client.check_domain_availability(input))
+    }
+
+    /// <p>Checks whether a domain name can be transferred to Amazon Route 53. </p>
+    ///
+    /// See `Route53Domains::check_domain_transferability`.
+    pub fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
+    {
+        self.runtime
+            .block_on(self.client.check_domain_transferability(input))
+    }
+
+    /// <p>This operation deletes the specified tags for a domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    ///
+    /// See `Route53Domains::delete_tags_for_domain`.
+    pub fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
+        self.runtime
+            .block_on(self.client.delete_tags_for_domain(input))
+    }
+
+    /// <p>This operation disables automatic renewal of domain registration for the specified domain.</p>
+    ///
+    /// See `Route53Domains::disable_domain_auto_renew`.
+    pub fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
+        self.runtime
+            .block_on(self.client.disable_domain_auto_renew(input))
+    }
+
+    /// <p>This operation removes the transfer lock on the domain (specifically the <code>clientTransferProhibited</code> status) to allow domain transfers. We recommend you refrain from performing this action unless you intend to transfer the domain to a different registrar. Successful submission returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    ///
+    /// See `Route53Domains::disable_domain_transfer_lock`.
+    pub fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_domain_transfer_lock(input))
+    }
+
+    /// <p>This operation configures Amazon Route 53 to automatically renew the specified domain before the domain registration expires. The cost of renewing your domain registration is billed to your AWS account.</p> <p>The period during which you can renew a domain name varies by TLD. For a list of TLDs and their renewal policies, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/registrar-tld-list.html">Domains That You Can Register with Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>. Route 53 requires that you renew before the end of the renewal period so we can complete processing before the deadline.</p>
+    ///
+    /// See `Route53Domains::enable_domain_auto_renew`.
+    pub fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
+        self.runtime
+            .block_on(self.client.enable_domain_auto_renew(input))
+    }
+
+    /// <p>This operation sets the transfer lock on the domain (specifically the <code>clientTransferProhibited</code> status) to prevent domain transfers. Successful submission returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    ///
+    /// See `Route53Domains::enable_domain_transfer_lock`.
+    pub fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
+        self.runtime
+            .block_on(self.client.enable_domain_transfer_lock(input))
+    }
+
+    /// <p>For operations that require confirmation that the email address for the registrant contact is valid, such as registering a new domain, this operation returns information about whether the registrant contact has responded.</p> <p>If you want us to resend the email, use the <code>ResendContactReachabilityEmail</code> operation.</p>
+    ///
+    /// See `Route53Domains::get_contact_reachability_status`.
+    pub fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.get_contact_reachability_status(input))
+    }
+
+    /// <p>This operation returns detailed information about a specified domain that is associated with the current AWS account. Contact information for the domain is also returned as part of the output.</p>
+    ///
+    /// See `Route53Domains::get_domain_detail`.
+    pub fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
+        self.runtime.block_on(self.client.get_domain_detail(input))
+    }
+
+    /// <p>The GetDomainSuggestions operation returns a list of suggested domain names.</p>
+    ///
+    /// See `Route53Domains::get_domain_suggestions`.
+    pub fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
+        self.runtime
+            .block_on(self.client.get_domain_suggestions(input))
+    }
+
+    /// <p>This operation returns the current status of an operation that is not completed.</p>
+    ///
+    /// See `Route53Domains::get_operation_detail`.
+    pub fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
+        self.runtime
+            .block_on(self.client.get_operation_detail(input))
+    }
+
+    /// <p>This operation returns all the domain names registered with Amazon Route 53 for the current AWS account.</p>
+    ///
+    /// See `Route53Domains::list_domains`.
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// <p>Returns information about all of the operations that return an operation ID and that have ever been performed on domains that were registered by the current account. </p>
+    ///
+    /// See `Route53Domains::list_operations`.
+    pub fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+        self.runtime.block_on(self.client.list_operations(input))
+    }
+
+    /// <p>This operation returns all of the tags that are associated with the specified domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    ///
+    /// See `Route53Domains::list_tags_for_domain`.
+    pub fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_domain(input))
+    }
+
+    /// <p><p>This operation registers a domain. Domains are registered either by Amazon Registrar (for .com, .net, and .org domains) or by our registrar associate, Gandi (for all other domains). For some top-level domains (TLDs), this operation requires extra parameters.</p> <p>When you register a domain, Amazon Route 53 does the following:</p> <ul> <li> <p>Creates a Route 53 hosted zone that has the same name as the domain. Route 53 assigns four name servers to your hosted zone and automatically updates your domain registration with the names of these name servers.</p> </li> <li> <p>Enables autorenew, so your domain registration will renew automatically each year. We&#39;ll notify you in advance of the renewal date so you can choose whether to renew the registration.</p> </li> <li> <p>Optionally enables privacy protection, so WHOIS queries return contact information either for Amazon Registrar (for .com, .net, and .org domains) or for our registrar associate, Gandi (for all other TLDs). If you don&#39;t enable privacy protection, WHOIS queries return the information that you entered for the registrant, admin, and tech contacts.</p> </li> <li> <p>If registration is successful, returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant is notified by email.</p> </li> <li> <p>Charges your AWS account an amount based on the top-level domain. For more information, see <a href="http://aws.amazon.com/route53/pricing/">Amazon Route 53 Pricing</a>.</p> </li> </ul></p>
+    ///
+    /// See `Route53Domains::register_domain`.
+    pub fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
+        self.runtime.block_on(self.client.register_domain(input))
+    }
+
+    /// <p>Rejects the transfer of a domain from another AWS account to the current AWS account. You initiate a transfer between AWS accounts using <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_TransferDomainToAnotherAwsAccount.html">TransferDomainToAnotherAwsAccount</a>. </p> <p>Use either <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_ListOperations.html">ListOperations</a> or <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> to determine whether the operation succeeded. <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> provides additional information, for example, <code>Domain Transfer from Aws Account 111122223333 has been cancelled</code>. </p>
+    ///
+    /// See `Route53Domains::reject_domain_transfer_from_another_aws_account`.
+    pub fn reject_domain_transfer_from_another_aws_account(
+        &self,
+        input: RejectDomainTransferFromAnotherAwsAccountRequest,
+    ) -> Result<
+        RejectDomainTransferFromAnotherAwsAccountResponse,
+        RusotoError<RejectDomainTransferFromAnotherAwsAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .reject_domain_transfer_from_another_aws_account(input),
+        )
+    }
+
+    /// <p>This operation renews a domain for the specified number of years. The cost of renewing your domain is billed to your AWS account.</p> <p>We recommend that you renew your domain several weeks before the expiration date. Some TLD registries delete domains before the expiration date if you haven't renewed far enough in advance. For more information about renewing domain registration, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-renew.html">Renewing Registration for a Domain</a> in the <i>Amazon Route 53 Developer Guide</i>.</p>
+    ///
+    /// See `Route53Domains::renew_domain`.
+    pub fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
+        self.runtime.block_on(self.client.renew_domain(input))
+    }
+
+    /// <p>For operations that require confirmation that the email address for the registrant contact is valid, such as registering a new domain, this operation resends the confirmation email to the current email address for the registrant contact.</p>
+    ///
+    /// See `Route53Domains::resend_contact_reachability_email`.
+    pub fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> Result<
+        ResendContactReachabilityEmailResponse,
+        RusotoError<ResendContactReachabilityEmailError>,
+    > {
+        self.runtime
+            .block_on(self.client.resend_contact_reachability_email(input))
+    }
+
+    /// <p>This operation returns the AuthCode for the domain. To transfer a domain to another registrar, you provide this value to the new registrar.</p>
+    ///
+    /// See `Route53Domains::retrieve_domain_auth_code`.
+    pub fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
+        self.runtime
+            .block_on(self.client.retrieve_domain_auth_code(input))
+    }
+
+    /// <p>Transfers a domain from another registrar to Amazon Route 53. When the transfer is complete, the domain is registered either with Amazon Registrar (for .com, .net, and .org domains) or with our registrar associate, Gandi (for all other TLDs).</p> <p>For more information about transferring domains, see the following topics:</p> <ul> <li> <p>For transfer requirements, a detailed procedure, and information about viewing the status of a domain that you're transferring to Route 53, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-transfer-to-route-53.html">Transferring Registration for a Domain to Amazon Route 53</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> <li> <p>For information about how to transfer a domain from one AWS account to another, see <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_TransferDomainToAnotherAwsAccount.html">TransferDomainToAnotherAwsAccount</a>. </p> </li> <li> <p>For information about how to transfer a domain to another domain registrar, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/domain-transfer-from-route-53.html">Transferring a Domain from Amazon Route 53 to Another Registrar</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </li> </ul> <p>If the registrar for your domain is also the DNS service provider for the domain, we highly recommend that you transfer your DNS service to Route 53 or to another DNS service provider before you transfer your registration. Some registrars provide free DNS service when you purchase a domain registration. When you transfer the registration, the previous registrar will not renew your domain registration and could end your DNS service at any time.</p> <important> <p>If the registrar for your domain is also the DNS service provider for the domain and you don't transfer DNS service to another provider, your website, email, and the web applications associated with the domain might become unavailable.</p> </important> <p>If the transfer is successful, this method returns an operation ID that you can use to track the progress and completion of the action. If the transfer doesn't complete successfully, the domain registrant will be notified by email.</p>
+    ///
+    /// See `Route53Domains::transfer_domain`.
+    pub fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
+        self.runtime.block_on(self.client.transfer_domain(input))
+    }
+
+    /// <p>Transfers a domain from the current AWS account to another AWS account. Note the following:</p> <ul> <li> <p>The AWS account that you're transferring the domain to must accept the transfer. If the other account doesn't accept the transfer within 3 days, we cancel the transfer. See <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_AcceptDomainTransferFromAnotherAwsAccount.html">AcceptDomainTransferFromAnotherAwsAccount</a>. </p> </li> <li> <p>You can cancel the transfer before the other account accepts it. See <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_CancelDomainTransferToAnotherAwsAccount.html">CancelDomainTransferToAnotherAwsAccount</a>. </p> </li> <li> <p>The other account can reject the transfer. See <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_RejectDomainTransferFromAnotherAwsAccount.html">RejectDomainTransferFromAnotherAwsAccount</a>. </p> </li> </ul> <important> <p>When you transfer a domain from one AWS account to another, Route 53 doesn't transfer the hosted zone that is associated with the domain. DNS resolution isn't affected if the domain and the hosted zone are owned by separate accounts, so transferring the hosted zone is optional. For information about transferring the hosted zone to another AWS account, see <a href="https://docs.aws.amazon.com/Route53/latest/DeveloperGuide/hosted-zones-migrating.html">Migrating a Hosted Zone to a Different AWS Account</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> </important> <p>Use either <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_ListOperations.html">ListOperations</a> or <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> to determine whether the operation succeeded. <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> provides additional information, for example, <code>Domain Transfer from Aws Account 111122223333 has been cancelled</code>. </p>
+    ///
+    /// See `Route53Domains::transfer_domain_to_another_aws_account`.
+    pub fn transfer_domain_to_another_aws_account(
+        &self,
+        input: TransferDomainToAnotherAwsAccountRequest,
+    ) -> Result<
+        TransferDomainToAnotherAwsAccountResponse,
+        RusotoError<TransferDomainToAnotherAwsAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.transfer_domain_to_another_aws_account(input))
+    }
+
+    /// <p>This operation updates the contact information for a particular domain. You must specify information for at least one contact: registrant, administrator, or technical.</p> <p>If the update is successful, this method returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    ///
+    /// See `Route53Domains::update_domain_contact`.
+    pub fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
+        self.runtime
+            .block_on(self.client.update_domain_contact(input))
+    }
+
+    /// <p><p>This operation updates the specified domain contact&#39;s privacy setting. When privacy protection is enabled, contact information such as email address is replaced either with contact information for Amazon Registrar (for .com, .net, and .org domains) or with contact information for our registrar associate, Gandi.</p> <p>This operation affects only the contact information for the specified contact type (registrant, administrator, or tech). If the request succeeds, Amazon Route 53 returns an operation ID that you can use with <a href="https://docs.aws.amazon.com/Route53/latest/APIReference/API_domains_GetOperationDetail.html">GetOperationDetail</a> to track the progress and completion of the action. If the request doesn&#39;t complete successfully, the domain registrant will be notified by email.</p> <important> <p>By disabling the privacy service via API, you consent to the publication of the contact information provided for this domain via the public WHOIS database. You certify that you are the registrant of this domain name and have the authority to make this decision. You may withdraw your consent at any time by enabling privacy protection using either <code>UpdateDomainContactPrivacy</code> or the Route 53 console. Enabling privacy protection removes the contact information provided for this domain from the WHOIS database. For more information on our privacy practices, see <a href="https://aws.amazon.com/privacy/">https://aws.amazon.com/privacy/</a>.</p> </important></p>
+    ///
+    /// See `Route53Domains::update_domain_contact_privacy`.
+    pub fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
+    {
+        self.runtime
+            .block_on(self.client.update_domain_contact_privacy(input))
+    }
+
+    /// <p>This operation replaces the current set of name servers for the domain with the specified set of name servers. If you use Amazon Route 53 as your DNS service, specify the four name servers in the delegation set for the hosted zone for the domain.</p> <p>If successful, this operation returns an operation ID that you can use to track the progress and completion of the action. If the request is not completed successfully, the domain registrant will be notified by email.</p>
+    ///
+    /// See `Route53Domains::update_domain_nameservers`.
+    pub fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
+        self.runtime
+            .block_on(self.client.update_domain_nameservers(input))
+    }
+
+    /// <p>This operation adds or updates tags for a specified domain.</p> <p>All tag operations are eventually consistent; subsequent operations might not immediately represent all issued operations.</p>
+    ///
+    /// See `Route53Domains::update_tags_for_domain`.
+    pub fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
+        self.runtime
+            .block_on(self.client.update_tags_for_domain(input))
+    }
+
+    /// <p>Returns all the domain-related billing records for the current AWS account for a specified period</p>
+    ///
+    /// See `Route53Domains::view_billing`.
+    pub fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
+        self.runtime.block_on(self.client.view_billing(input))
+    }
+}
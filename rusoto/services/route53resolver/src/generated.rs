@@ -852,37 +852,44 @@ impl AssociateResolverEndpointIpAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::InvalidRequest(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::ResourceExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateResolverEndpointIpAddressError::Throttling(err.msg),
                     )
                 }
@@ -946,37 +953,46 @@ impl AssociateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateResolverRuleError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1024,37 +1040,46 @@ impl CreateResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::ResourceExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverEndpointError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1104,32 +1129,49 @@ impl CreateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResolverRuleError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateResolverRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1174,27 +1216,34 @@ impl DeleteResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverEndpointError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverEndpointError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverEndpointError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverEndpointError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1236,21 +1285,31 @@ impl DeleteResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverRuleError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverRuleError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResolverRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteResolverRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1296,32 +1355,38 @@ impl DisassociateResolverEndpointIpAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverEndpointIpAddressError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverEndpointIpAddressError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverEndpointIpAddressError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverEndpointIpAddressError::ResourceExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverEndpointIpAddressError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverEndpointIpAddressError::Throttling(err.msg),
                     )
                 }
@@ -1376,22 +1441,28 @@ impl DisassociateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateResolverRuleError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResolverRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResolverRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateResolverRuleError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1432,22 +1503,28 @@ impl GetResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverEndpointError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverEndpointError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverEndpointError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1486,18 +1563,25 @@ impl GetResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRuleError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetResolverRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1538,24 +1622,28 @@ impl GetResolverRuleAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetResolverRuleAssociationError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRuleAssociationError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRuleAssociationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRuleAssociationError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1594,19 +1682,22 @@ impl GetResolverRulePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRulePolicyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRulePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResolverRulePolicyError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1648,29 +1739,34 @@ impl ListResolverEndpointIpAddressesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverEndpointIpAddressesError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverEndpointIpAddressesError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverEndpointIpAddressesError::InvalidParameter(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverEndpointIpAddressesError::ResourceNotFound(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverEndpointIpAddressesError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverEndpointIpAddressesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1720,27 +1816,34 @@ impl ListResolverEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverEndpointsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverEndpointsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverEndpointsError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverEndpointsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverEndpointsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1784,29 +1887,34 @@ impl ListResolverRuleAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverRuleAssociationsError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverRuleAssociationsError::InvalidNextToken(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResolverRuleAssociationsError::InvalidParameter(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverRuleAssociationsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverRuleAssociationsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverRuleAssociationsError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverRuleAssociationsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1854,21 +1962,31 @@ impl ListResolverRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListResolverRulesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverRulesError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverRulesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverRulesError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResolverRulesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverRulesError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListResolverRulesError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1912,30 +2030,40 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1976,24 +2104,28 @@ impl PutResolverRulePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResolverRulePolicyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResolverRulePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPolicyDocument" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InvalidPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResolverRulePolicyError::InvalidPolicyDocument(err.msg),
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::UnknownResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResolverRulePolicyError::UnknownResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2036,22 +2168,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTag(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidTag(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2092,16 +2227,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2142,27 +2286,34 @@ impl UpdateResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverEndpointError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverEndpointError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverEndpointError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverEndpointError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverEndpointError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2208,29 +2359,43 @@ impl UpdateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverRuleError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverRuleError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverRuleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResolverRuleError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateResolverRuleError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2416,6 +2581,7 @@ impl Route53ResolverClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2431,6 +2597,7 @@ impl Route53ResolverClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> Route53ResolverClient {
         Route53ResolverClient { client, region }
     }
@@ -2447,6 +2614,9 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<AssociateResolverEndpointIpAddressError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateResolverEndpointIpAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Resolver.AssociateResolverEndpointIpAddress",
@@ -2472,6 +2642,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: AssociateResolverRuleRequest,
     ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateResolverRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.AssociateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2491,6 +2664,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverEndpointRequest,
     ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateResolverEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2510,6 +2686,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverRuleRequest,
     ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateResolverRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2528,6 +2707,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: DeleteResolverEndpointRequest,
     ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResolverEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2547,6 +2729,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: DeleteResolverRuleRequest,
     ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResolverRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2568,6 +2753,9 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<DisassociateResolverEndpointIpAddressError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateResolverEndpointIpAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53Resolver.DisassociateResolverEndpointIpAddress",
@@ -2593,6 +2781,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: DisassociateResolverRuleRequest,
     ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateResolverRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.DisassociateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2612,6 +2803,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverEndpointRequest,
     ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResolverEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.GetResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2630,6 +2824,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverRuleRequest,
     ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResolverRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2649,6 +2846,9 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResolverRuleAssociation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRuleAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2668,6 +2868,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverRulePolicyRequest,
     ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResolverRulePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRulePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2690,6 +2893,9 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<ListResolverEndpointIpAddressesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResolverEndpointIpAddresses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53Resolver.ListResolverEndpointIpAddresses",
@@ -2712,6 +2918,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListResolverEndpointsRequest,
     ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResolverEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.ListResolverEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2732,6 +2941,9 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResolverRuleAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53Resolver.ListResolverRuleAssociations",
@@ -2754,6 +2966,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListResolverRulesRequest,
     ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResolverRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.ListResolverRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2772,6 +2987,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53Resolver.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2790,6 +3008,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: PutResolverRulePolicyRequest,
     ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResolverRulePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.PutResolverRulePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2809,6 +3030,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2827,6 +3051,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2845,6 +3072,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: UpdateResolverEndpointRequest,
     ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateResolverEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2864,6 +3094,9 @@ impl Route53Resolver for Route53ResolverClient {
         input: UpdateResolverRuleRequest,
     ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateResolverRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2876,3 +3109,306 @@ impl Route53Resolver for Route53ResolverClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResolverRuleResponse, _>()
     }
 }
+
+/// A blocking client for the Route53Resolver API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `Route53ResolverClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Route53ResolverBlockingClient {
+    client: Route53ResolverClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Route53ResolverBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> Route53ResolverBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        Route53ResolverBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53ResolverBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Route53ResolverBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient {
+            client: Route53ResolverClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds IP addresses to an inbound or an outbound resolver endpoint. If you want to adding more than one IP address, submit one <code>AssociateResolverEndpointIpAddress</code> request for each IP address.</p> <p>To remove an IP address from an endpoint, see <a>DisassociateResolverEndpointIpAddress</a>.</p>
+    ///
+    /// See `Route53Resolver::associate_resolver_endpoint_ip_address`.
+    pub fn associate_resolver_endpoint_ip_address(
+        &self,
+        input: AssociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        AssociateResolverEndpointIpAddressResponse,
+        RusotoError<AssociateResolverEndpointIpAddressError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_resolver_endpoint_ip_address(input))
+    }
+
+    /// <p>Associates a resolver rule with a VPC. When you associate a rule with a VPC, Resolver forwards all DNS queries for the domain name that is specified in the rule and that originate in the VPC. The queries are forwarded to the IP addresses for the DNS resolvers that are specified in the rule. For more information about rules, see <a>CreateResolverRule</a>. </p>
+    ///
+    /// See `Route53Resolver::associate_resolver_rule`.
+    pub fn associate_resolver_rule(
+        &self,
+        input: AssociateResolverRuleRequest,
+    ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.associate_resolver_rule(input))
+    }
+
+    /// <p><p>Creates a resolver endpoint. There are two types of resolver endpoints, inbound and outbound:</p> <ul> <li> <p>An <i>inbound resolver endpoint</i> forwards DNS queries to the DNS service for a VPC from your network or another VPC.</p> </li> <li> <p>An <i>outbound resolver endpoint</i> forwards DNS queries from the DNS service for a VPC to your network or another VPC.</p> </li> </ul></p>
+    ///
+    /// See `Route53Resolver::create_resolver_endpoint`.
+    pub fn create_resolver_endpoint(
+        &self,
+        input: CreateResolverEndpointRequest,
+    ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_resolver_endpoint(input))
+    }
+
+    /// <p>For DNS queries that originate in your VPCs, specifies which resolver endpoint the queries pass through, one domain name that you want to forward to your network, and the IP addresses of the DNS resolvers in your network.</p>
+    ///
+    /// See `Route53Resolver::create_resolver_rule`.
+    pub fn create_resolver_rule(
+        &self,
+        input: CreateResolverRuleRequest,
+    ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.create_resolver_rule(input))
+    }
+
+    /// <p><p>Deletes a resolver endpoint. The effect of deleting a resolver endpoint depends on whether it&#39;s an inbound or an outbound resolver endpoint:</p> <ul> <li> <p> <b>Inbound</b>: DNS queries from your network or another VPC are no longer routed to the DNS service for the specified VPC.</p> </li> <li> <p> <b>Outbound</b>: DNS queries from a VPC are no longer routed to your network or to another VPC.</p> </li> </ul></p>
+    ///
+    /// See `Route53Resolver::delete_resolver_endpoint`.
+    pub fn delete_resolver_endpoint(
+        &self,
+        input: DeleteResolverEndpointRequest,
+    ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_resolver_endpoint(input))
+    }
+
+    /// <p>Deletes a resolver rule. Before you can delete a resolver rule, you must disassociate it from all the VPCs that you associated the resolver rule with. For more infomation, see <a>DisassociateResolverRule</a>.</p>
+    ///
+    /// See `Route53Resolver::delete_resolver_rule`.
+    pub fn delete_resolver_rule(
+        &self,
+        input: DeleteResolverRuleRequest,
+    ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_resolver_rule(input))
+    }
+
+    /// <p>Removes IP addresses from an inbound or an outbound resolver endpoint. If you want to remove more than one IP address, submit one <code>DisassociateResolverEndpointIpAddress</code> request for each IP address.</p> <p>To add an IP address to an endpoint, see <a>AssociateResolverEndpointIpAddress</a>.</p>
+    ///
+    /// See `Route53Resolver::disassociate_resolver_endpoint_ip_address`.
+    pub fn disassociate_resolver_endpoint_ip_address(
+        &self,
+        input: DisassociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        DisassociateResolverEndpointIpAddressResponse,
+        RusotoError<DisassociateResolverEndpointIpAddressError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_resolver_endpoint_ip_address(input))
+    }
+
+    /// <p><p>Removes the association between a specified resolver rule and a specified VPC.</p> <important> <p>If you disassociate a resolver rule from a VPC, Resolver stops forwarding DNS queries for the domain name that you specified in the resolver rule. </p> </important></p>
+    ///
+    /// See `Route53Resolver::disassociate_resolver_rule`.
+    pub fn disassociate_resolver_rule(
+        &self,
+        input: DisassociateResolverRuleRequest,
+    ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.disassociate_resolver_rule(input))
+    }
+
+    /// <p>Gets information about a specified resolver endpoint, such as whether it's an inbound or an outbound resolver endpoint, and the current status of the endpoint.</p>
+    ///
+    /// See `Route53Resolver::get_resolver_endpoint`.
+    pub fn get_resolver_endpoint(
+        &self,
+        input: GetResolverEndpointRequest,
+    ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.get_resolver_endpoint(input))
+    }
+
+    /// <p>Gets information about a specified resolver rule, such as the domain name that the rule forwards DNS queries for and the ID of the outbound resolver endpoint that the rule is associated with.</p>
+    ///
+    /// See `Route53Resolver::get_resolver_rule`.
+    pub fn get_resolver_rule(
+        &self,
+        input: GetResolverRuleRequest,
+    ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
+        self.runtime.block_on(self.client.get_resolver_rule(input))
+    }
+
+    /// <p>Gets information about an association between a specified resolver rule and a VPC. You associate a resolver rule and a VPC using <a>AssociateResolverRule</a>. </p>
+    ///
+    /// See `Route53Resolver::get_resolver_rule_association`.
+    pub fn get_resolver_rule_association(
+        &self,
+        input: GetResolverRuleAssociationRequest,
+    ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resolver_rule_association(input))
+    }
+
+    /// <p>Gets information about a resolver rule policy. A resolver rule policy specifies the Resolver operations and resources that you want to allow another AWS account to be able to use. </p>
+    ///
+    /// See `Route53Resolver::get_resolver_rule_policy`.
+    pub fn get_resolver_rule_policy(
+        &self,
+        input: GetResolverRulePolicyRequest,
+    ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resolver_rule_policy(input))
+    }
+
+    /// <p>Gets the IP addresses for a specified resolver endpoint.</p>
+    ///
+    /// See `Route53Resolver::list_resolver_endpoint_ip_addresses`.
+    pub fn list_resolver_endpoint_ip_addresses(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> Result<
+        ListResolverEndpointIpAddressesResponse,
+        RusotoError<ListResolverEndpointIpAddressesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resolver_endpoint_ip_addresses(input))
+    }
+
+    /// <p>Lists all the resolver endpoints that were created using the current AWS account.</p>
+    ///
+    /// See `Route53Resolver::list_resolver_endpoints`.
+    pub fn list_resolver_endpoints(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
+        self.runtime
+            .block_on(self.client.list_resolver_endpoints(input))
+    }
+
+    /// <p>Lists the associations that were created between resolver rules and VPCs using the current AWS account.</p>
+    ///
+    /// See `Route53Resolver::list_resolver_rule_associations`.
+    pub fn list_resolver_rule_associations(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_resolver_rule_associations(input))
+    }
+
+    /// <p>Lists the resolver rules that were created using the current AWS account.</p>
+    ///
+    /// See `Route53Resolver::list_resolver_rules`.
+    pub fn list_resolver_rules(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
+        self.runtime
+            .block_on(self.client.list_resolver_rules(input))
+    }
+
+    /// <p>Lists the tags that you associated with the specified resource.</p>
+    ///
+    /// See `Route53Resolver::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Specifies the Resolver operations and resources that you want to allow another AWS account to be able to use.</p>
+    ///
+    /// See `Route53Resolver::put_resolver_rule_policy`.
+    pub fn put_resolver_rule_policy(
+        &self,
+        input: PutResolverRulePolicyRequest,
+    ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resolver_rule_policy(input))
+    }
+
+    /// <p>Adds one or more tags to a specified resource.</p>
+    ///
+    /// See `Route53Resolver::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a specified resource.</p>
+    ///
+    /// See `Route53Resolver::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the name of an inbound or an outbound resolver endpoint. </p>
+    ///
+    /// See `Route53Resolver::update_resolver_endpoint`.
+    pub fn update_resolver_endpoint(
+        &self,
+        input: UpdateResolverEndpointRequest,
+    ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.update_resolver_endpoint(input))
+    }
+
+    /// <p>Updates settings for a specified resolver rule. <code>ResolverRuleId</code> is required, and all other parameters are optional. If you don't specify a parameter, it retains its current value.</p>
+    ///
+    /// See `Route53Resolver::update_resolver_rule`.
+    pub fn update_resolver_rule(
+        &self,
+        input: UpdateResolverRuleRequest,
+    ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.update_resolver_rule(input))
+    }
+}
@@ -6,7 +6,7 @@ use self::rusoto_mock::*;
 use bytes::BytesMut;
 use futures::TryStreamExt;
 use rusoto_core::signature::SignedRequest;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::{Client, ClientConfig, Region, RusotoError};
 
 #[tokio::test]
 async fn test_multipart_upload_copy_response() {
@@ -214,7 +214,8 @@ async fn list_multipart_upload_parts_happy_path() {
         </ListPartsResult>"#)
         .with_request_checker(|request: &SignedRequest| {
             assert_eq!(request.method, "GET");
-            assert_eq!(request.path, "/rusoto1440826511/testfile.zip");
+            assert_eq!(request.hostname(), "rusoto1440826511.s3.us-east-1.amazonaws.com");
+            assert_eq!(request.path, "/testfile.zip");
             assert!(request.payload.is_none());
         });
 
@@ -422,7 +423,7 @@ async fn should_serialize_complicated_request() {
         .with_body("")
         .with_request_checker(|request: &SignedRequest| {
             assert_eq!(request.method, "GET");
-            assert_eq!(request.path, "/bucket/key");
+            assert_eq!(request.path, "/key");
             assert_eq!(
                 *request.params.get("response-content-type").unwrap(),
                 sstr("response_content_type")
@@ -513,10 +514,13 @@ async fn test_parse_no_such_bucket_error() {
     let result = client.list_objects_v2(request).await;
     assert!(result.is_err());
     let err = result.err().unwrap();
+    assert_eq!(err.code().as_deref(), Some("NoSuchBucket"));
+    assert_eq!(err.request_id(), Some("4442587FB7D0A2F9"));
     assert_eq!(
-        RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-            "The specified bucket does not exist".to_owned()
-        )),
+        RusotoError::Service(
+            ListObjectsV2Error::NoSuchBucket("The specified bucket does not exist".to_owned()),
+            err.metadata().unwrap().into_owned()
+        ),
         err
     );
 }
@@ -546,3 +550,113 @@ async fn test_multiple_mock() {
     assert_eq!(result.expiration, Some("foo2".to_string()));
     assert_eq!(result.restore, Some("bar2".to_string()));
 }
+
+#[tokio::test]
+async fn select_object_content_events() {
+    use futures::StreamExt;
+    use rusoto_core::event_stream::DeserializeEvent;
+
+    let records = SelectObjectContentEventStreamItem::deserialize_event("Records", b"1,a\n2,b\n");
+    assert_eq!(
+        records.unwrap(),
+        SelectObjectContentEventStreamItem::Records(RecordsEvent {
+            payload: Some("1,a\n2,b\n".into()),
+        })
+    );
+
+    let stats = SelectObjectContentEventStreamItem::deserialize_event(
+        "Stats",
+        b"<?xml version=\"1.0\" encoding=\"UTF-8\"?><Stats><BytesScanned>512</BytesScanned>\
+          <BytesProcessed>512</BytesProcessed><BytesReturned>8</BytesReturned></Stats>",
+    );
+    assert_eq!(
+        stats.unwrap(),
+        SelectObjectContentEventStreamItem::Stats(StatsEvent {
+            details: Some(Stats {
+                bytes_processed: Some(512),
+                bytes_returned: Some(8),
+                bytes_scanned: Some(512),
+            }),
+        })
+    );
+
+    let end = SelectObjectContentEventStreamItem::deserialize_event("End", b"");
+    assert_eq!(
+        end.unwrap(),
+        SelectObjectContentEventStreamItem::End(EndEvent {})
+    );
+    assert!(SelectObjectContentEventStreamItem::deserialize_event("Unknown", b"").is_err());
+
+    let mock = MockRequestDispatcher::with_status(200).with_request_checker(|request| {
+        assert_eq!(request.method, "POST");
+        assert!(request.params.contains_key("select"));
+    });
+    let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let output = client
+        .select_object_content(SelectObjectContentRequest {
+            bucket: "bucket".to_owned(),
+            key: "key.csv".to_owned(),
+            expression: "SELECT * FROM S3Object".to_owned(),
+            expression_type: "SQL".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let events = output.payload.unwrap().collect::<Vec<_>>().await;
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn requester_pays() {
+    let mut config = ClientConfig::new();
+    config.set_requester_pays(true);
+    let mock = MultipleMockRequestDispatcher::new(vec![
+        MockRequestDispatcher::with_status(200).with_request_checker(|request| {
+            assert_eq!(
+                request.headers.get("x-amz-request-payer"),
+                Some(&vec![b"requester".to_vec()])
+            );
+        }),
+        MockRequestDispatcher::with_status(200).with_request_checker(|request| {
+            assert!(!request.headers.contains_key("x-amz-request-payer"));
+        }),
+    ]);
+    let client = S3Client::new_with_client(
+        Client::new_with_config(MockCredentialsProvider, mock, config),
+        Region::UsEast1,
+    );
+    client
+        .get_object(GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    client
+        .get_bucket_location(GetBucketLocationRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}
+
+#[test]
+fn presigned_urls_of_outposts_access_points() {
+    use crate::util::PreSignedRequest;
+    use rusoto_core::credential::AwsCredentials;
+
+    let request = GetObjectRequest {
+        bucket: "arn:aws:s3-outposts:us-west-2:123456789012:outpost/op-01234567890123456/accesspoint/my-ap"
+            .to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    let credentials = AwsCredentials::new("key", "secret", None, None);
+    let url = request.get_presigned_url(&Region::UsWest2, &credentials, &Default::default());
+    assert!(url.starts_with(
+        "https://my-ap-123456789012.op-01234567890123456.s3-outposts.us-west-2.amazonaws.com/key?"
+    ));
+    assert!(url.contains("%2Fus-west-2%2Fs3-outposts%2Faws4_request"));
+}
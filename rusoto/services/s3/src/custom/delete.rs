@@ -0,0 +1,319 @@
+//! Deleting any number of objects with `DeleteObjects` requests of up to `MAX_DELETE_KEYS`
+//! keys each.
+
+use futures::{future, Stream, StreamExt};
+use rusoto_core::ErrorClass;
+
+use crate::custom::transfer::TransferManager;
+use crate::generated::{
+    Delete, DeleteObjectsRequest, DeletedObject, ObjectIdentifier, S3Error, S3,
+};
+
+/// The most keys a `DeleteObjects` request deletes.
+pub const MAX_DELETE_KEYS: usize = 1000;
+
+/// The objects `TransferManager::delete_objects` deleted, and those it couldn't delete.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeleteReport {
+    /// The deleted objects, none if the deletions were `quiet`.
+    pub deleted: Vec<DeletedObject>,
+    /// The errors of the objects which couldn't be deleted, with their keys. The objects of
+    /// failed requests have the error of the request as `message`, and no `code`.
+    pub errors: Vec<S3Error>,
+}
+
+impl From<String> for ObjectIdentifier {
+    fn from(key: String) -> ObjectIdentifier {
+        ObjectIdentifier {
+            key,
+            version_id: None,
+        }
+    }
+}
+
+impl From<&str> for ObjectIdentifier {
+    fn from(key: &str) -> ObjectIdentifier {
+        ObjectIdentifier::from(key.to_owned())
+    }
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Sync,
+{
+    /// Delete the objects, keys or `ObjectIdentifier`s with versions, with `DeleteObjects`
+    /// requests of up to `MAX_DELETE_KEYS` of them, sending up to `concurrency` requests at a
+    /// time. The `delete.objects` of the request are ignored, its other fields are used for
+    /// every request. Their `Content-MD5` is computed, as `DeleteObjects` requires.
+    ///
+    /// Requests are retried like parts of transfers, and so are the objects S3 couldn't delete
+    /// because of errors the retry policy retries, like `SlowDown` or `InternalError`. The
+    /// objects which couldn't be deleted otherwise are reported with their errors, and so are
+    /// those of requests which failed even when retried, while the other requests carry on.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::{stream, StreamExt, TryStreamExt};
+    /// use rusoto_core::Region;
+    /// use rusoto_s3::pagination::ListObjectsV2Paginator;
+    /// use rusoto_s3::transfer::TransferManager;
+    /// use rusoto_s3::{DeleteObjectsRequest, ListObjectsV2Request, S3Client};
+    ///
+    /// # async fn example() {
+    /// let client = S3Client::new(Region::UsEast1);
+    /// let request = ListObjectsV2Request {
+    ///     bucket: "my-bucket".to_owned(),
+    ///     prefix: Some("logs/2019/".to_owned()),
+    ///     ..Default::default()
+    /// };
+    /// let keys: Vec<String> = client
+    ///     .list_objects_v2_pages(request)
+    ///     .into_objects()
+    ///     .map_ok(|object| object.key.unwrap_or_default())
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let transfers = TransferManager::new(client);
+    /// let request = DeleteObjectsRequest {
+    ///     bucket: "my-bucket".to_owned(),
+    ///     ..Default::default()
+    /// };
+    /// let report = transfers
+    ///     .delete_objects(request, stream::iter(keys))
+    ///     .await;
+    /// for error in report.errors {
+    ///     println!("{:?} couldn't be deleted: {:?}", error.key, error.message);
+    /// }
+    /// # }
+    /// ```
+    pub async fn delete_objects<S>(&self, request: DeleteObjectsRequest, objects: S) -> DeleteReport
+    where
+        S: Stream,
+        S::Item: Into<ObjectIdentifier>,
+    {
+        let template = &request;
+        objects
+            .map(Into::into)
+            .chunks(MAX_DELETE_KEYS)
+            .map(|objects| self.delete_batch(template, objects))
+            .buffer_unordered(self.concurrency())
+            .fold(DeleteReport::default(), |mut report, batch| {
+                report.deleted.extend(batch.deleted);
+                report.errors.extend(batch.errors);
+                future::ready(report)
+            })
+            .await
+    }
+
+    /// Deletes the objects, sending those which failed with retried errors again.
+    async fn delete_batch(
+        &self,
+        template: &DeleteObjectsRequest,
+        mut objects: Vec<ObjectIdentifier>,
+    ) -> DeleteReport {
+        let mut report = DeleteReport::default();
+        let mut attempt = 1;
+        loop {
+            let request = DeleteObjectsRequest {
+                delete: Delete {
+                    objects,
+                    quiet: template.delete.quiet,
+                },
+                ..template.clone()
+            };
+            let output = match self
+                .retry(|| self.client().delete_objects(request.clone()))
+                .await
+            {
+                Ok(output) => output,
+                Err(err) => {
+                    // the objects of the request weren't deleted
+                    let message = err.to_string();
+                    report
+                        .errors
+                        .extend(request.delete.objects.into_iter().map(|object| S3Error {
+                            key: Some(object.key),
+                            version_id: object.version_id,
+                            message: Some(message.clone()),
+                            code: None,
+                        }));
+                    return report;
+                }
+            };
+            report.deleted.extend(output.deleted.unwrap_or_default());
+            let (retried, failed): (Vec<S3Error>, Vec<S3Error>) = output
+                .errors
+                .unwrap_or_default()
+                .into_iter()
+                .partition(|error| {
+                    attempt < self.retry_policy().max_attempts() && self.retries_key(error)
+                });
+            report.errors.extend(failed);
+            if retried.is_empty() {
+                return report;
+            }
+            self.back_off(attempt).await;
+            attempt += 1;
+            objects = retried
+                .into_iter()
+                .map(|error| ObjectIdentifier {
+                    key: error.key.unwrap_or_default(),
+                    version_id: error.version_id,
+                })
+                .collect();
+        }
+    }
+
+    /// Whether the retry policy retries the error of an object which couldn't be deleted.
+    fn retries_key(&self, error: &S3Error) -> bool {
+        matches!(
+            error.code.as_deref().and_then(ErrorClass::from_code),
+            Some(class) if self.retry_policy().retries(class)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::{Region, RetryPolicy};
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    use super::*;
+    use crate::generated::S3Client;
+
+    fn deleted(keys: &'static [&'static str], result: &'static str) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_body(result)
+            .with_request_checker(move |request| {
+                assert_eq!(request.method, "POST");
+                assert!(request.params.contains_key("delete"));
+                assert!(request.headers.contains_key("content-md5"));
+                let payload = match request.payload {
+                    Some(SignedRequestPayload::Buffer(ref payload)) => {
+                        String::from_utf8(payload.to_vec()).unwrap()
+                    }
+                    _ => panic!("the keys are listed in the payload"),
+                };
+                if keys.is_empty() {
+                    assert_eq!(payload.matches("<Key>").count(), MAX_DELETE_KEYS);
+                } else {
+                    let expected: Vec<String> = keys
+                        .iter()
+                        .map(|key| format!("<Key>{}</Key>", key))
+                        .collect();
+                    let listed: Vec<_> = payload.match_indices("<Key>").collect();
+                    assert_eq!(listed.len(), keys.len());
+                    assert!(expected.iter().all(|key| payload.contains(key)));
+                }
+            })
+    }
+
+    #[tokio::test]
+    async fn delete_objects() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            // the first batch of 1000 keys
+            deleted(
+                &[],
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Deleted><Key>key2</Key></Deleted>
+                    <Error><Key>key0</Key><Code>InternalError</Code><Message>retry</Message></Error>
+                    <Error><Key>key1</Key><Code>AccessDenied</Code><Message>denied</Message></Error>
+                </DeleteResult>"#,
+            ),
+            // the key which failed with an internal error is retried
+            deleted(
+                &["key0"],
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Deleted><Key>key0</Key></Deleted>
+                </DeleteResult>"#,
+            ),
+            MockRequestDispatcher::with_status(503),
+            deleted(
+                &["key1000"],
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Deleted><Key>key1000</Key></Deleted>
+                </DeleteResult>"#,
+            ),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut transfers = TransferManager::new(client);
+        // the mocked responses are in the order of the requests
+        transfers.set_concurrency(1);
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_millis(1));
+        transfers.set_retry_policy(retry_policy);
+
+        let request = DeleteObjectsRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
+        let keys = (0..=MAX_DELETE_KEYS).map(|index| format!("key{}", index));
+        let report = transfers
+            .delete_objects(request, futures::stream::iter(keys))
+            .await;
+        let deleted: Vec<_> = report
+            .deleted
+            .iter()
+            .map(|object| object.key.as_deref().unwrap())
+            .collect();
+        assert_eq!(deleted, vec!["key2", "key0", "key1000"]);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].key.as_deref(), Some("key1"));
+        assert_eq!(report.errors[0].code.as_deref(), Some("AccessDenied"));
+    }
+
+    #[tokio::test]
+    async fn failed_batches() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            deleted(
+                &[],
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <DeleteResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Deleted><Key>key0</Key></Deleted>
+                </DeleteResult>"#,
+            ),
+            // the second batch fails, with an error which isn't retried
+            MockRequestDispatcher::with_status(403).with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <Error><Code>AccessDenied</Code><Message>Access Denied</Message></Error>"#,
+            ),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut transfers = TransferManager::new(client);
+        transfers.set_concurrency(1);
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_millis(1));
+        transfers.set_retry_policy(retry_policy);
+
+        let request = DeleteObjectsRequest {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        };
+        let keys = (0..MAX_DELETE_KEYS + 2).map(|index| format!("key{}", index));
+        let report = transfers
+            .delete_objects(request, futures::stream::iter(keys))
+            .await;
+        // the deletions of the first batch are reported
+        assert_eq!(report.deleted.len(), 1);
+        assert_eq!(report.deleted[0].key.as_deref(), Some("key0"));
+        // and so are the keys of the second one
+        let failed: Vec<_> = report
+            .errors
+            .iter()
+            .map(|error| error.key.as_deref().unwrap())
+            .collect();
+        assert_eq!(failed, vec!["key1000", "key1001"]);
+        assert!(report.errors.iter().all(|error| error.code.is_none()));
+        assert!(report.errors[0].message.is_some());
+    }
+}
@@ -0,0 +1,813 @@
+//! Client-side encryption of objects, in the envelope format of the S3 encryption clients of the
+//! other AWS SDKs.
+//!
+//! Objects are encrypted with AES-GCM, with a new 256-bit data key each, before they're
+//! uploaded. The data key is encrypted with a key encryption key by a `Keyring`, in AWS KMS
+//! with `KmsKeyring` or with an AES key of your own with `AesKeyring`, and stored along with
+//! the IV and the algorithms in the metadata of the object, so objects can be decrypted by any
+//! client with access to the key encryption key.
+//!
+//! See the [documentation](https://docs.aws.amazon.com/general/latest/gr/aws_sdk_cryptography.html)
+//! for more information.
+
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use futures::TryStreamExt;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use rusoto_core::RusotoError;
+use rusoto_kms::{DecryptError, DecryptRequest, GenerateDataKeyError, GenerateDataKeyRequest, Kms};
+
+use crate::generated::{
+    GetObjectError, GetObjectOutput, GetObjectRequest, PutObjectError, PutObjectOutput,
+    PutObjectRequest, StreamingBody, S3,
+};
+
+/// The algorithm objects are encrypted with.
+const CONTENT_ALGORITHM: &str = "AES/GCM/NoPadding";
+
+/// The wrapping algorithm of data keys encrypted with KMS, with the material description as
+/// the encryption context.
+const KMS_CONTEXT_ALGORITHM: &str = "kms+context";
+
+/// The wrapping algorithm of data keys encrypted with AES-GCM.
+const AES_GCM_ALGORITHM: &str = "AES/GCM";
+
+/// The key of the content algorithm in the material description of data keys encrypted with
+/// KMS, which binds them to the algorithm.
+const CONTENT_ALGORITHM_CONTEXT: &str = "aws:x-amz-cek-alg";
+
+/// The metadata of the envelope of an object.
+const KEY: &str = "x-amz-key-v2";
+const IV: &str = "x-amz-iv";
+const CEK_ALGORITHM: &str = "x-amz-cek-alg";
+const WRAP_ALGORITHM: &str = "x-amz-wrap-alg";
+const MATERIAL_DESCRIPTION: &str = "x-amz-matdesc";
+const TAG_LENGTH: &str = "x-amz-tag-len";
+const UNENCRYPTED_CONTENT_LENGTH: &str = "x-amz-unencrypted-content-length";
+
+/// The size of data keys, for AES-256.
+const DATA_KEY_LEN: usize = 32;
+
+/// A data key objects are encrypted with, along with its encryption by a `Keyring`.
+pub struct DataKey {
+    /// The plaintext key, which is never stored.
+    pub key: Vec<u8>,
+    /// The encrypted key, stored with the object.
+    pub encrypted_key: Vec<u8>,
+    /// The algorithm the key was encrypted with, like `kms+context`.
+    pub wrap_algorithm: String,
+    /// The material description stored with the object, which identifies the key encryption
+    /// key.
+    pub material_description: BTreeMap<String, String>,
+}
+
+impl fmt::Debug for DataKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the plaintext key is secret
+        f.debug_struct("DataKey")
+            .field("wrap_algorithm", &self.wrap_algorithm)
+            .field("material_description", &self.material_description)
+            .finish()
+    }
+}
+
+/// Generates the data keys objects are encrypted with, and decrypts them again.
+#[async_trait]
+pub trait Keyring: Send + Sync {
+    /// Generate a new 256-bit data key, encrypted with the key encryption key.
+    async fn generate_data_key(&self) -> Result<DataKey, EncryptionError>;
+
+    /// Decrypt the data key of an object, stored with the wrapping algorithm and the material
+    /// description.
+    async fn decrypt_data_key(
+        &self,
+        encrypted_key: &[u8],
+        wrap_algorithm: &str,
+        material_description: &BTreeMap<String, String>,
+    ) -> Result<Vec<u8>, EncryptionError>;
+}
+
+/// Encrypts data keys with a KMS key, with the material description as encryption context
+/// (`kms+context`).
+pub struct KmsKeyring<K> {
+    client: K,
+    key_id: String,
+    encryption_context: BTreeMap<String, String>,
+}
+
+impl<K> KmsKeyring<K>
+where
+    K: Kms + Send + Sync,
+{
+    /// Create a keyring generating data keys with the KMS key, by key ID, ARN or alias.
+    pub fn new(client: K, key_id: &str) -> KmsKeyring<K> {
+        KmsKeyring {
+            client,
+            key_id: key_id.to_owned(),
+            encryption_context: BTreeMap::new(),
+        }
+    }
+
+    /// Add a pair to the encryption context data keys are generated with, which is stored in
+    /// the material description of objects.
+    pub fn add_encryption_context(&mut self, key: &str, value: &str) {
+        self.encryption_context
+            .insert(key.to_owned(), value.to_owned());
+    }
+}
+
+#[async_trait]
+impl<K> Keyring for KmsKeyring<K>
+where
+    K: Kms + Send + Sync,
+{
+    async fn generate_data_key(&self) -> Result<DataKey, EncryptionError> {
+        let mut material_description = self.encryption_context.clone();
+        material_description.insert(
+            CONTENT_ALGORITHM_CONTEXT.to_owned(),
+            CONTENT_ALGORITHM.to_owned(),
+        );
+        let request = GenerateDataKeyRequest {
+            key_id: self.key_id.clone(),
+            key_spec: Some("AES_256".to_owned()),
+            encryption_context: Some(material_description.clone().into_iter().collect()),
+            ..Default::default()
+        };
+        let response = self
+            .client
+            .generate_data_key(request)
+            .await
+            .map_err(EncryptionError::GenerateDataKey)?;
+        match (response.plaintext, response.ciphertext_blob) {
+            (Some(key), Some(encrypted_key)) => Ok(DataKey {
+                key: key.to_vec(),
+                encrypted_key: encrypted_key.to_vec(),
+                wrap_algorithm: KMS_CONTEXT_ALGORITHM.to_owned(),
+                material_description,
+            }),
+            _ => Err(EncryptionError::GenerateDataKey(RusotoError::ParseError(
+                "The response doesn't include the data key".to_owned(),
+            ))),
+        }
+    }
+
+    async fn decrypt_data_key(
+        &self,
+        encrypted_key: &[u8],
+        wrap_algorithm: &str,
+        material_description: &BTreeMap<String, String>,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        if wrap_algorithm != KMS_CONTEXT_ALGORITHM {
+            return Err(EncryptionError::Unsupported(format!(
+                "The data key is wrapped with {}, not with KMS",
+                wrap_algorithm
+            )));
+        }
+        // the context binds the data key to the content algorithm
+        let content_algorithm = material_description
+            .get(CONTENT_ALGORITHM_CONTEXT)
+            .map(String::as_str);
+        if content_algorithm != Some(CONTENT_ALGORITHM) {
+            return Err(EncryptionError::Envelope(format!(
+                "The material description doesn't bind the data key to {}",
+                CONTENT_ALGORITHM
+            )));
+        }
+        let request = DecryptRequest {
+            ciphertext_blob: Bytes::copy_from_slice(encrypted_key),
+            encryption_context: Some(material_description.clone().into_iter().collect()),
+            key_id: Some(self.key_id.clone()),
+            ..Default::default()
+        };
+        let response = self
+            .client
+            .decrypt(request)
+            .await
+            .map_err(EncryptionError::Decrypt)?;
+        response.plaintext.map(|key| key.to_vec()).ok_or_else(|| {
+            EncryptionError::Decrypt(RusotoError::ParseError(
+                "The response doesn't include the data key".to_owned(),
+            ))
+        })
+    }
+}
+
+/// Encrypts data keys with a 256-bit AES key of your own, with AES-GCM (`AES/GCM`).
+///
+/// The key is never sent to AWS, so objects can't be decrypted without it.
+pub struct AesKeyring {
+    key: LessSafeKey,
+    material_description: BTreeMap<String, String>,
+    random: SystemRandom,
+}
+
+impl AesKeyring {
+    /// Create a keyring encrypting data keys with the AES key.
+    pub fn new(key: [u8; 32]) -> AesKeyring {
+        let key = UnboundKey::new(&AES_256_GCM, &key).expect("the key is 256 bits");
+        AesKeyring {
+            key: LessSafeKey::new(key),
+            material_description: BTreeMap::new(),
+            random: SystemRandom::new(),
+        }
+    }
+
+    /// Add a pair to the material description stored with objects, e.g. to identify the key.
+    pub fn add_material_description(&mut self, key: &str, value: &str) {
+        self.material_description
+            .insert(key.to_owned(), value.to_owned());
+    }
+}
+
+impl fmt::Debug for AesKeyring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AesKeyring")
+            .field("material_description", &self.material_description)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Keyring for AesKeyring {
+    async fn generate_data_key(&self) -> Result<DataKey, EncryptionError> {
+        let key = random_bytes(&self.random, DATA_KEY_LEN)?;
+        let nonce = random_bytes(&self.random, NONCE_LEN)?;
+        // the encrypted key is the nonce followed by the ciphertext and tag
+        let mut encrypted_key = key.clone();
+        self.key
+            .seal_in_place_append_tag(
+                nonce_of(&nonce)?,
+                Aad::from(CONTENT_ALGORITHM),
+                &mut encrypted_key,
+            )
+            .map_err(|_| EncryptionError::Crypto)?;
+        encrypted_key.splice(0..0, nonce);
+        Ok(DataKey {
+            key,
+            encrypted_key,
+            wrap_algorithm: AES_GCM_ALGORITHM.to_owned(),
+            material_description: self.material_description.clone(),
+        })
+    }
+
+    async fn decrypt_data_key(
+        &self,
+        encrypted_key: &[u8],
+        wrap_algorithm: &str,
+        _material_description: &BTreeMap<String, String>,
+    ) -> Result<Vec<u8>, EncryptionError> {
+        if wrap_algorithm != AES_GCM_ALGORITHM {
+            return Err(EncryptionError::Unsupported(format!(
+                "The data key is wrapped with {}, not with AES-GCM",
+                wrap_algorithm
+            )));
+        }
+        if encrypted_key.len() < NONCE_LEN {
+            return Err(EncryptionError::Envelope(
+                "The encrypted data key is too short".to_owned(),
+            ));
+        }
+        let (nonce, ciphertext) = encrypted_key.split_at(NONCE_LEN);
+        let mut key = ciphertext.to_vec();
+        let length = self
+            .key
+            .open_in_place(nonce_of(nonce)?, Aad::from(CONTENT_ALGORITHM), &mut key)
+            .map_err(|_| EncryptionError::Crypto)?
+            .len();
+        key.truncate(length);
+        Ok(key)
+    }
+}
+
+/// Encrypts objects before they're uploaded with `put_object`, and decrypts them when they're
+/// downloaded with `get_object`, see the module documentation.
+///
+/// Objects are held in memory to be encrypted and decrypted as a whole, since their contents
+/// can only be authenticated at their end. Objects which weren't encrypted can't be downloaded
+/// with the client.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_kms::KmsClient;
+/// use rusoto_s3::encryption::{EncryptionClient, KmsKeyring};
+/// use rusoto_s3::{GetObjectRequest, PutObjectRequest, S3Client};
+///
+/// # async fn example() {
+/// let keyring = KmsKeyring::new(KmsClient::new(Region::UsEast1), "alias/my-key");
+/// let client = EncryptionClient::new(S3Client::new(Region::UsEast1), keyring);
+///
+/// let request = PutObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "secrets.txt".to_owned(),
+///     body: Some(b"secret".to_vec().into()),
+///     ..Default::default()
+/// };
+/// client.put_object(request).await.unwrap();
+///
+/// let request = GetObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "secrets.txt".to_owned(),
+///     ..Default::default()
+/// };
+/// let output = client.get_object(request).await.unwrap();
+/// # }
+/// ```
+pub struct EncryptionClient<C, K> {
+    client: C,
+    keyring: K,
+    random: SystemRandom,
+}
+
+impl<C, K> EncryptionClient<C, K>
+where
+    C: S3 + Sync,
+    K: Keyring,
+{
+    /// Create a client encrypting objects with data keys of the keyring.
+    pub fn new(client: C, keyring: K) -> EncryptionClient<C, K> {
+        EncryptionClient {
+            client,
+            keyring,
+            random: SystemRandom::new(),
+        }
+    }
+
+    /// The client objects are uploaded and downloaded with.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// The keyring data keys are generated and decrypted with.
+    pub fn keyring(&self) -> &K {
+        &self.keyring
+    }
+
+    /// Encrypt the body of the request with a new data key, and upload it like
+    /// `S3::put_object`, with the envelope in its metadata. The `content_md5` of the request is
+    /// ignored, since it's that of the plaintext.
+    pub async fn put_object(
+        &self,
+        mut request: PutObjectRequest,
+    ) -> Result<PutObjectOutput, EncryptionError> {
+        let mut data = match request.body.take() {
+            Some(body) => read_body(body).await.map_err(EncryptionError::Io)?.to_vec(),
+            None => Vec::new(),
+        };
+        let plaintext_length = data.len();
+
+        let data_key = self.keyring.generate_data_key().await?;
+        let iv = random_bytes(&self.random, NONCE_LEN)?;
+        content_key(&data_key.key)?
+            .seal_in_place_append_tag(nonce_of(&iv)?, Aad::empty(), &mut data)
+            .map_err(|_| EncryptionError::Crypto)?;
+
+        let material_description = serde_json::to_string(&data_key.material_description)
+            .expect("string maps are serialized");
+        let metadata = request.metadata.get_or_insert_with(HashMap::new);
+        metadata.insert(KEY.to_owned(), base64::encode(&data_key.encrypted_key));
+        metadata.insert(IV.to_owned(), base64::encode(&iv));
+        metadata.insert(CEK_ALGORITHM.to_owned(), CONTENT_ALGORITHM.to_owned());
+        metadata.insert(WRAP_ALGORITHM.to_owned(), data_key.wrap_algorithm);
+        metadata.insert(MATERIAL_DESCRIPTION.to_owned(), material_description);
+        metadata.insert(TAG_LENGTH.to_owned(), "128".to_owned());
+        metadata.insert(
+            UNENCRYPTED_CONTENT_LENGTH.to_owned(),
+            plaintext_length.to_string(),
+        );
+        request.content_length = Some(data.len() as i64);
+        request.content_md5 = None;
+        request.body = Some(data.into());
+
+        self.client
+            .put_object(request)
+            .await
+            .map_err(EncryptionError::PutObject)
+    }
+
+    /// Download the object like `S3::get_object`, and decrypt its body with the data key of
+    /// its envelope. Ranges of objects can't be decrypted, so requests with a `range` fail.
+    pub async fn get_object(
+        &self,
+        request: GetObjectRequest,
+    ) -> Result<GetObjectOutput, EncryptionError> {
+        if request.range.is_some() || request.part_number.is_some() {
+            return Err(EncryptionError::Unsupported(
+                "Ranges of encrypted objects can't be decrypted".to_owned(),
+            ));
+        }
+        let mut output = self
+            .client
+            .get_object(request)
+            .await
+            .map_err(EncryptionError::GetObject)?;
+        let envelope = Envelope::from_metadata(output.metadata.as_ref())?;
+        let key = self
+            .keyring
+            .decrypt_data_key(
+                &envelope.encrypted_key,
+                &envelope.wrap_algorithm,
+                &envelope.material_description,
+            )
+            .await?;
+
+        let mut data = match output.body.take() {
+            Some(body) => read_body(body).await.map_err(EncryptionError::Io)?.to_vec(),
+            None => Vec::new(),
+        };
+        let length = content_key(&key)?
+            .open_in_place(nonce_of(&envelope.iv)?, Aad::empty(), &mut data)
+            .map_err(|_| EncryptionError::Crypto)?
+            .len();
+        data.truncate(length);
+        output.content_length = Some(data.len() as i64);
+        output.body = Some(data.into());
+        Ok(output)
+    }
+}
+
+/// The envelope of an encrypted object, from its metadata.
+#[derive(Debug)]
+struct Envelope {
+    encrypted_key: Vec<u8>,
+    iv: Vec<u8>,
+    wrap_algorithm: String,
+    material_description: BTreeMap<String, String>,
+}
+
+impl Envelope {
+    fn from_metadata(metadata: Option<&HashMap<String, String>>) -> Result<Envelope, KeyError> {
+        let get = |name: &str| {
+            metadata
+                .and_then(|metadata| metadata.get(name))
+                .map(String::as_str)
+        };
+        if get(KEY).is_none() && get("x-amz-key").is_some() {
+            return Err(KeyError::Unsupported(
+                "Objects encrypted with AES-CBC by version 1 clients can't be decrypted".to_owned(),
+            ));
+        }
+        let required = |name: &str| {
+            get(name)
+                .ok_or_else(|| KeyError::Envelope(format!("The object has no {} metadata", name)))
+        };
+        let decode = |name: &str| {
+            base64::decode(required(name)?).map_err(|_| {
+                KeyError::Envelope(format!("The {} metadata isn't base64 encoded", name))
+            })
+        };
+
+        let content_algorithm = required(CEK_ALGORITHM)?;
+        if content_algorithm != CONTENT_ALGORITHM {
+            return Err(KeyError::Unsupported(format!(
+                "The object is encrypted with {}, not {}",
+                content_algorithm, CONTENT_ALGORITHM
+            )));
+        }
+        if let Some(tag_length) = get(TAG_LENGTH) {
+            if tag_length != "128" {
+                return Err(KeyError::Unsupported(format!(
+                    "The object is authenticated with a tag of {} bits, not 128",
+                    tag_length
+                )));
+            }
+        }
+        let material_description = match get(MATERIAL_DESCRIPTION) {
+            Some(description) => serde_json::from_str(description).map_err(|_| {
+                KeyError::Envelope(format!(
+                    "The {} metadata isn't a JSON object of strings",
+                    MATERIAL_DESCRIPTION
+                ))
+            })?,
+            None => BTreeMap::new(),
+        };
+        Ok(Envelope {
+            encrypted_key: decode(KEY)?,
+            iv: decode(IV)?,
+            wrap_algorithm: required(WRAP_ALGORITHM)?.to_owned(),
+            material_description,
+        })
+    }
+}
+
+/// The errors of the envelope and keys of objects, which are kept apart from the much larger
+/// `EncryptionError` until they're returned.
+enum KeyError {
+    Envelope(String),
+    Unsupported(String),
+    Crypto,
+}
+
+impl From<KeyError> for EncryptionError {
+    fn from(err: KeyError) -> EncryptionError {
+        match err {
+            KeyError::Envelope(message) => EncryptionError::Envelope(message),
+            KeyError::Unsupported(message) => EncryptionError::Unsupported(message),
+            KeyError::Crypto => EncryptionError::Crypto,
+        }
+    }
+}
+
+fn content_key(key: &[u8]) -> Result<LessSafeKey, KeyError> {
+    UnboundKey::new(&AES_256_GCM, key)
+        .map(LessSafeKey::new)
+        .map_err(|_| KeyError::Envelope("The data key isn't 256 bits".to_owned()))
+}
+
+fn nonce_of(nonce: &[u8]) -> Result<Nonce, KeyError> {
+    Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| KeyError::Envelope("The IV isn't 96 bits".to_owned()))
+}
+
+fn random_bytes(random: &SystemRandom, length: usize) -> Result<Vec<u8>, KeyError> {
+    let mut bytes = vec![0; length];
+    random.fill(&mut bytes).map_err(|_| KeyError::Crypto)?;
+    Ok(bytes)
+}
+
+async fn read_body(body: StreamingBody) -> io::Result<Bytes> {
+    let data = body
+        .try_fold(BytesMut::new(), |mut data, chunk| async move {
+            data.extend_from_slice(&chunk);
+            Ok(data)
+        })
+        .await?;
+    Ok(data.freeze())
+}
+
+/// Error that occurs while encrypting or decrypting an object with an `EncryptionClient`.
+#[derive(Debug)]
+pub enum EncryptionError {
+    /// The encrypted object couldn't be uploaded.
+    PutObject(RusotoError<PutObjectError>),
+    /// The encrypted object couldn't be downloaded.
+    GetObject(RusotoError<GetObjectError>),
+    /// KMS couldn't generate a data key.
+    GenerateDataKey(RusotoError<GenerateDataKeyError>),
+    /// KMS couldn't decrypt the data key.
+    Decrypt(RusotoError<DecryptError>),
+    /// The envelope in the metadata of the object is missing or malformed.
+    Envelope(String),
+    /// The object is encrypted with algorithms the client doesn't support.
+    Unsupported(String),
+    /// The object or its data key couldn't be decrypted with the key, e.g. because the object
+    /// was encrypted with another key or was tampered with.
+    Crypto,
+    /// The body of the request or the response couldn't be read.
+    Io(io::Error),
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EncryptionError::PutObject(ref err) => err.fmt(f),
+            EncryptionError::GetObject(ref err) => err.fmt(f),
+            EncryptionError::GenerateDataKey(ref err) => err.fmt(f),
+            EncryptionError::Decrypt(ref err) => err.fmt(f),
+            EncryptionError::Envelope(ref message) => f.write_str(message),
+            EncryptionError::Unsupported(ref message) => f.write_str(message),
+            EncryptionError::Crypto => f.write_str("The object couldn't be encrypted or decrypted"),
+            EncryptionError::Io(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for EncryptionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            EncryptionError::PutObject(ref err) => Some(err),
+            EncryptionError::GetObject(ref err) => Some(err),
+            EncryptionError::GenerateDataKey(ref err) => Some(err),
+            EncryptionError::Decrypt(ref err) => Some(err),
+            EncryptionError::Io(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use futures::FutureExt;
+    use rusoto_core::request::{DispatchSignedRequest, DispatchSignedRequestFuture, HttpResponse};
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_core::{ByteStream, Region};
+    use rusoto_kms::KmsClient;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    use super::*;
+    use crate::generated::S3Client;
+
+    /// The headers of the envelope.
+    const METADATA: [&str; 7] = [
+        "x-amz-meta-x-amz-key-v2",
+        "x-amz-meta-x-amz-iv",
+        "x-amz-meta-x-amz-cek-alg",
+        "x-amz-meta-x-amz-wrap-alg",
+        "x-amz-meta-x-amz-matdesc",
+        "x-amz-meta-x-amz-tag-len",
+        "x-amz-meta-x-amz-unencrypted-content-length",
+    ];
+
+    /// The body and envelope headers of the last object put.
+    type Object = (Bytes, Vec<(&'static str, String)>);
+
+    /// Stores the object put, and returns it when it's got.
+    #[derive(Clone, Default)]
+    struct Bucket(Arc<Mutex<Option<Object>>>);
+
+    impl DispatchSignedRequest for Bucket {
+        fn dispatch(
+            &self,
+            mut request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let object = self.0.clone();
+            async move {
+                let mut response = HttpResponse {
+                    status: Default::default(),
+                    body: ByteStream::from(Vec::new()),
+                    headers: Default::default(),
+                };
+                if request.method == "PUT" {
+                    let body = match request.payload.take() {
+                        Some(SignedRequestPayload::Buffer(body)) => body,
+                        Some(SignedRequestPayload::Stream(body)) => read_body(body).await.unwrap(),
+                        None => Bytes::new(),
+                    };
+                    let metadata = METADATA
+                        .iter()
+                        .filter_map(|name| {
+                            let values = request.headers.get(*name)?;
+                            Some((*name, String::from_utf8(values[0].clone()).unwrap()))
+                        })
+                        .collect();
+                    *object.lock().unwrap() = Some((body, metadata));
+                } else if let Some((body, metadata)) = object.lock().unwrap().clone() {
+                    for (name, value) in metadata {
+                        response.headers.insert(name, value);
+                    }
+                    response.body = ByteStream::from(body.to_vec());
+                }
+                Ok(response)
+            }
+            .boxed()
+        }
+    }
+
+    fn put_request(body: &[u8]) -> PutObjectRequest {
+        PutObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            body: Some(body.to_vec().into()),
+            content_md5: Some("md5".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn get_request() -> GetObjectRequest {
+        GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn aes_round_trip() {
+        let bucket = Bucket::default();
+        let s3 = S3Client::new_with(bucket.clone(), MockCredentialsProvider, Region::UsEast1);
+        let mut keyring = AesKeyring::new([7; 32]);
+        keyring.add_material_description("key", "test");
+        let client = EncryptionClient::new(s3, keyring);
+
+        client.put_object(put_request(b"plaintext")).await.unwrap();
+        let (body, metadata) = bucket.0.lock().unwrap().clone().unwrap();
+        assert_eq!(body.len(), b"plaintext".len() + 16);
+        assert!(!body.windows(9).any(|window| window == b"plaintext"));
+        let metadata: HashMap<_, _> = metadata.into_iter().collect();
+        assert_eq!(metadata["x-amz-meta-x-amz-cek-alg"], "AES/GCM/NoPadding");
+        assert_eq!(metadata["x-amz-meta-x-amz-wrap-alg"], "AES/GCM");
+        assert_eq!(metadata["x-amz-meta-x-amz-matdesc"], r#"{"key":"test"}"#);
+        assert_eq!(metadata["x-amz-meta-x-amz-tag-len"], "128");
+        assert_eq!(metadata["x-amz-meta-x-amz-unencrypted-content-length"], "9");
+        assert_eq!(
+            base64::decode(&metadata["x-amz-meta-x-amz-iv"])
+                .unwrap()
+                .len(),
+            12
+        );
+
+        let output = client.get_object(get_request()).await.unwrap();
+        assert_eq!(output.content_length, Some(9));
+        let body = read_body(output.body.unwrap()).await.unwrap();
+        assert_eq!(&body[..], b"plaintext");
+
+        // objects can't be decrypted with another key
+        let s3 = S3Client::new_with(bucket, MockCredentialsProvider, Region::UsEast1);
+        let client = EncryptionClient::new(s3, AesKeyring::new([8; 32]));
+        match client.get_object(get_request()).await {
+            Err(EncryptionError::Crypto) => {}
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[tokio::test]
+    async fn kms_data_keys() {
+        let key = base64::encode([7; 32]);
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body(&format!(
+                    r#"{{"KeyId": "key", "Plaintext": "{}", "CiphertextBlob": "ZW5jcnlwdGVk"}}"#,
+                    key
+                ))
+                .with_request_checker(|request| {
+                    let body = match request.payload {
+                        Some(SignedRequestPayload::Buffer(ref body)) => body.clone(),
+                        _ => panic!("the request has no body"),
+                    };
+                    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                    assert_eq!(body["KeyId"], "alias/key");
+                    assert_eq!(body["KeySpec"], "AES_256");
+                    assert_eq!(
+                        body["EncryptionContext"]["aws:x-amz-cek-alg"],
+                        "AES/GCM/NoPadding"
+                    );
+                    assert_eq!(body["EncryptionContext"]["purpose"], "test");
+                }),
+            MockRequestDispatcher::with_status(200)
+                .with_body(&format!(r#"{{"KeyId": "key", "Plaintext": "{}"}}"#, key))
+                .with_request_checker(|request| {
+                    let body = match request.payload {
+                        Some(SignedRequestPayload::Buffer(ref body)) => body.clone(),
+                        _ => panic!("the request has no body"),
+                    };
+                    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                    assert_eq!(body["CiphertextBlob"], "ZW5jcnlwdGVk");
+                    assert_eq!(body["EncryptionContext"]["purpose"], "test");
+                }),
+        ]);
+        let kms = KmsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut keyring = KmsKeyring::new(kms, "alias/key");
+        keyring.add_encryption_context("purpose", "test");
+
+        let data_key = keyring.generate_data_key().await.unwrap();
+        assert_eq!(data_key.key, vec![7; 32]);
+        assert_eq!(data_key.encrypted_key, b"encrypted");
+        assert_eq!(data_key.wrap_algorithm, "kms+context");
+        let key = keyring
+            .decrypt_data_key(
+                &data_key.encrypted_key,
+                &data_key.wrap_algorithm,
+                &data_key.material_description,
+            )
+            .await
+            .unwrap();
+        assert_eq!(key, vec![7; 32]);
+    }
+
+    #[tokio::test]
+    async fn unsupported_objects() {
+        let mock = MockRequestDispatcher::with_status(200)
+            .with_body("plaintext")
+            .with_header("x-amz-meta-x-amz-key", "a2V5")
+            .with_header("x-amz-meta-x-amz-iv", "aXY=");
+        let s3 = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let client = EncryptionClient::new(s3, AesKeyring::new([7; 32]));
+        match client.get_object(get_request()).await {
+            Err(EncryptionError::Unsupported(_)) => {}
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+
+        let s3 = S3Client::new_with(
+            MockRequestDispatcher::with_status(200).with_body("plaintext"),
+            MockCredentialsProvider,
+            Region::UsEast1,
+        );
+        let client = EncryptionClient::new(s3, AesKeyring::new([7; 32]));
+        match client.get_object(get_request()).await {
+            Err(EncryptionError::Envelope(_)) => {}
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+
+        let request = GetObjectRequest {
+            range: Some("bytes=0-1".to_owned()),
+            ..get_request()
+        };
+        match client.get_object(request).await {
+            Err(EncryptionError::Unsupported(_)) => {}
+            result => panic!("unexpected result {:?}", result.map(|_| ())),
+        }
+    }
+}
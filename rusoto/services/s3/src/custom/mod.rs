@@ -1,5 +1,28 @@
 /// Utility helpers for working with S3
 pub mod util;
 
+/// Transferring large objects in parts
+pub mod transfer;
+
+/// Server-side encryption with customer-provided keys
+pub mod sse;
+
+/// Listing objects page by page
+pub mod pagination;
+
+/// Synchronizing directories with S3 prefixes
+pub mod sync;
+
+/// Deleting objects in batches
+pub mod delete;
+
+/// Client-side encryption of objects
+#[cfg(feature = "encryption")]
+pub mod encryption;
+
+/// Event notifications of buckets
+#[cfg(feature = "notification")]
+pub mod notification;
+
 #[cfg(test)]
 mod custom_tests;
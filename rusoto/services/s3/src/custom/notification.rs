@@ -0,0 +1,278 @@
+//! The event notifications S3 sends to SQS queues, SNS topics and Lambda functions when objects
+//! in a bucket change, see the
+//! [documentation](https://docs.aws.amazon.com/AmazonS3/latest/dev/notification-content-structure.html).
+
+use std::collections::HashMap;
+
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+
+/// A notification of events in a bucket.
+///
+/// The test event S3 sends when notifications are configured has no records.
+///
+/// # Example
+///
+/// ```rust
+/// use rusoto_s3::notification::S3Event;
+///
+/// let message = r#"{"Records": [{"eventName": "ObjectCreated:Put", "s3": {
+///     "bucket": {"name": "my-bucket"},
+///     "object": {"key": "photos/Happy+Face.jpg", "size": 1024}
+/// }}]}"#;
+/// let event = S3Event::from_json(message).unwrap();
+/// for record in event.records {
+///     println!("{:?} {}", record.event_name, record.s3.object.decoded_key());
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct S3Event {
+    /// The events, usually just one.
+    #[serde(rename = "Records", default)]
+    pub records: Vec<S3EventRecord>,
+}
+
+impl S3Event {
+    /// Parse a notification from the body of an SQS message or the payload of a Lambda
+    /// invocation. Notifications sent to an SNS topic are parsed from the `Message` of the SNS
+    /// notification, so the messages of queues subscribed to the topic can be parsed too.
+    pub fn from_json(json: &str) -> Result<S3Event, serde_json::Error> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        match value.get("Type").and_then(|kind| kind.as_str()) {
+            Some("Notification") => match value.get("Message").and_then(|message| message.as_str())
+            {
+                Some(message) => serde_json::from_str(message),
+                None => serde_json::from_value(value),
+            },
+            _ => serde_json::from_value(value),
+        }
+    }
+}
+
+/// An event of an object in a bucket.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3EventRecord {
+    /// The version of the event structure, like `2.1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_version: Option<String>,
+    /// The source of the event, `aws:s3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_source: Option<String>,
+    /// The region of the bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    /// When the request finished, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_time: Option<String>,
+    /// The type of the event without the `s3:` prefix, like `ObjectCreated:Put` or
+    /// `ObjectRemoved:Delete`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_name: Option<String>,
+    /// Who made the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_identity: Option<UserIdentity>,
+    /// Where the request came from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_parameters: Option<RequestParameters>,
+    /// The IDs of the request, `x-amz-request-id` and `x-amz-id-2`, for AWS support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_elements: Option<HashMap<String, String>>,
+    /// The bucket and object.
+    pub s3: S3Entity,
+    /// The restored copy of the object, for `ObjectRestore:Completed` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glacier_event_data: Option<GlacierEventData>,
+}
+
+/// The principal behind a request or owning a bucket.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserIdentity {
+    /// The Amazon customer ID of the principal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal_id: Option<String>,
+}
+
+/// The parameters of the request.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct RequestParameters {
+    /// The IP address the request was sent from.
+    #[serde(rename = "sourceIPAddress", skip_serializing_if = "Option::is_none")]
+    pub source_ip_address: Option<String>,
+}
+
+/// The bucket and object of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Entity {
+    /// The version of the structure, like `1.0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub s3_schema_version: Option<String>,
+    /// The ID of the notification configuration which matched the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration_id: Option<String>,
+    /// The bucket of the object.
+    pub bucket: S3Bucket,
+    /// The object.
+    pub object: S3Object,
+}
+
+/// The bucket of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Bucket {
+    /// The name of the bucket.
+    pub name: String,
+    /// The owner of the bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_identity: Option<UserIdentity>,
+    /// The ARN of the bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+}
+
+/// The object of an event.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Object {
+    /// The key of the object, URL encoded, see `decoded_key`.
+    pub key: String,
+    /// The size of the object in bytes, except for `ObjectRemoved` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+    /// The ETag of the object, except for `ObjectRemoved` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    /// The version of the object, if the bucket is versioned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    /// A hexadecimal value which orders the events of each key, which may be delivered out of
+    /// order. Sequencers of different lengths are compared after padding the shorter one with
+    /// zeros on the left.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequencer: Option<String>,
+}
+
+impl S3Object {
+    /// Returns the key of the object, which notifications URL encode like form values, with
+    /// `+` for spaces. The key can be passed to `GetObject` as it is.
+    pub fn decoded_key(&self) -> String {
+        let key = self.key.replace('+', " ");
+        percent_decode_str(&key).decode_utf8_lossy().into_owned()
+    }
+}
+
+/// The data of `ObjectRestore:Completed` events.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlacierEventData {
+    /// The restored copy of the object.
+    pub restore_event_data: RestoreEventData,
+}
+
+/// The restored copy of an archived object.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreEventData {
+    /// When the restored copy expires, in ISO 8601 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_restoration_expiry_time: Option<String>,
+    /// The storage class of the archived object, like `GLACIER`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle_restore_storage_class: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVENT: &str = r#"{
+        "Records": [{
+            "eventVersion": "2.1",
+            "eventSource": "aws:s3",
+            "awsRegion": "us-west-2",
+            "eventTime": "1970-01-01T00:00:00.000Z",
+            "eventName": "ObjectCreated:Put",
+            "userIdentity": {"principalId": "AIDAJDPLRKLG7UEXAMPLE"},
+            "requestParameters": {"sourceIPAddress": "127.0.0.1"},
+            "responseElements": {
+                "x-amz-request-id": "C3D13FE58DE4C810",
+                "x-amz-id-2": "FMyUVURIY8/IgAtTv8xRjskZQpcIZ9KG4V5Wp6S7S/JRWeUWerMUE5JgHvANOjpD"
+            },
+            "s3": {
+                "s3SchemaVersion": "1.0",
+                "configurationId": "testRule",
+                "bucket": {
+                    "name": "mybucket",
+                    "ownerIdentity": {"principalId": "A3NL1KOZZKExample"},
+                    "arn": "arn:aws:s3:::mybucket"
+                },
+                "object": {
+                    "key": "photos/Happy+Face%28%C3%A9%29.jpg",
+                    "size": 1024,
+                    "eTag": "d41d8cd98f00b204e9800998ecf8427e",
+                    "versionId": "096fKKXTRTtl3on89fVO.nfljtsv6qko",
+                    "sequencer": "0055AED6DCD90281E5"
+                }
+            }
+        }]
+    }"#;
+
+    #[test]
+    fn events() {
+        let event = S3Event::from_json(EVENT).unwrap();
+        assert_eq!(event.records.len(), 1);
+        let record = &event.records[0];
+        assert_eq!(record.event_name.as_deref(), Some("ObjectCreated:Put"));
+        assert_eq!(record.aws_region.as_deref(), Some("us-west-2"));
+        assert_eq!(
+            record
+                .request_parameters
+                .as_ref()
+                .and_then(|parameters| parameters.source_ip_address.as_deref()),
+            Some("127.0.0.1")
+        );
+        assert_eq!(
+            record.response_elements.as_ref().unwrap()["x-amz-request-id"],
+            "C3D13FE58DE4C810"
+        );
+        assert_eq!(record.s3.bucket.name, "mybucket");
+        assert_eq!(record.s3.object.size, Some(1024));
+        assert_eq!(
+            record.s3.object.e_tag.as_deref(),
+            Some("d41d8cd98f00b204e9800998ecf8427e")
+        );
+        assert_eq!(record.s3.object.decoded_key(), "photos/Happy Face(é).jpg");
+
+        // events survive a round trip
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(S3Event::from_json(&json).unwrap(), event);
+    }
+
+    #[test]
+    fn sns_notifications() {
+        let notification = serde_json::json!({
+            "Type": "Notification",
+            "MessageId": "22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324",
+            "TopicArn": "arn:aws:sns:us-west-2:123456789012:MyTopic",
+            "Subject": "Amazon S3 Notification",
+            "Message": EVENT,
+        });
+        let event = S3Event::from_json(&notification.to_string()).unwrap();
+        assert_eq!(event, S3Event::from_json(EVENT).unwrap());
+    }
+
+    #[test]
+    fn test_events() {
+        let test_event = r#"{
+            "Service": "Amazon S3",
+            "Event": "s3:TestEvent",
+            "Time": "2014-10-13T15:57:02.089Z",
+            "Bucket": "mybucket",
+            "RequestId": "5582815E1AEA5ADF",
+            "HostId": "8cLeGAmw098X5cv4Zkwcmo8vvZa3eH3eKxsPzbB9wrR+YstdA6Knx4Ip8EXAMPLE"
+        }"#;
+        assert!(S3Event::from_json(test_event).unwrap().records.is_empty());
+    }
+}
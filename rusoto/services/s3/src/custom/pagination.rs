@@ -0,0 +1,278 @@
+//! Listing objects page by page, following the continuation tokens of `ListObjectsV2`.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{future, stream, Stream, StreamExt};
+use rusoto_core::RusotoError;
+
+use crate::generated::{ListObjectsV2Error, ListObjectsV2Output, ListObjectsV2Request, Object, S3};
+
+type ListObjectsV2Result = Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>>;
+
+/// Lists objects with as many `ListObjectsV2` requests as it takes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::TryStreamExt;
+/// use rusoto_core::Region;
+/// use rusoto_s3::pagination::ListObjectsV2Paginator;
+/// use rusoto_s3::{ListObjectsV2Request, S3Client};
+///
+/// # async fn example() {
+/// let client = S3Client::new(Region::UsEast1);
+/// let request = ListObjectsV2Request {
+///     bucket: "my-bucket".to_owned(),
+///     prefix: Some("logs/".to_owned()),
+///     ..Default::default()
+/// };
+/// let mut objects = client
+///     .list_objects_v2_pages(request)
+///     .max_items(5000)
+///     .into_objects();
+/// while let Some(object) = objects.try_next().await.unwrap() {
+///     println!("{:?} {:?}", object.key, object.size);
+/// }
+/// # }
+/// ```
+pub trait ListObjectsV2Paginator {
+    /// Returns the pages of objects the request lists, starting at its `continuation_token`,
+    /// if any. Pages are requested as they're read, with the `max_keys` of the request.
+    fn list_objects_v2_pages(&self, request: ListObjectsV2Request) -> ListObjectsV2Pages<'_>;
+}
+
+impl<C> ListObjectsV2Paginator for C
+where
+    C: S3 + Sync,
+{
+    fn list_objects_v2_pages(&self, request: ListObjectsV2Request) -> ListObjectsV2Pages<'_> {
+        ListObjectsV2Pages {
+            client: self,
+            request,
+            max_items: None,
+            items: 0,
+            done: false,
+            pending: None,
+        }
+    }
+}
+
+/// The pages of objects a `ListObjectsV2Request` lists, see
+/// `ListObjectsV2Paginator::list_objects_v2_pages`.
+///
+/// The stream ends after the last page, or after the first error.
+pub struct ListObjectsV2Pages<'a> {
+    client: &'a (dyn S3 + Sync),
+    request: ListObjectsV2Request,
+    max_items: Option<usize>,
+    items: usize,
+    done: bool,
+    pending: Option<Pin<Box<dyn Future<Output = ListObjectsV2Result> + Send + 'a>>>,
+}
+
+impl<'a> ListObjectsV2Pages<'a> {
+    /// Stop listing after this many objects. The last page is cut short if it lists more of
+    /// them, and no more keys than are left are requested. Common prefixes don't count.
+    pub fn max_items(mut self, max_items: usize) -> ListObjectsV2Pages<'a> {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Returns the objects of the pages, rather than the pages.
+    pub fn into_objects(
+        self,
+    ) -> impl Stream<Item = Result<Object, RusotoError<ListObjectsV2Error>>> + Send + 'a {
+        self.flat_map(|page| match page {
+            Ok(page) => {
+                stream::iter(page.contents.unwrap_or_default().into_iter().map(Ok)).left_stream()
+            }
+            Err(err) => stream::once(future::ready(Err(err))).right_stream(),
+        })
+    }
+
+    /// How many more objects may be listed.
+    fn remaining(&self) -> Option<usize> {
+        self.max_items
+            .map(|max_items| max_items.saturating_sub(self.items))
+    }
+
+    /// Counts the objects of the page, cutting it short if there are too many of them, and
+    /// continues with the next page, if any.
+    fn next_page(&mut self, mut page: ListObjectsV2Output) -> ListObjectsV2Output {
+        if let (Some(remaining), Some(contents)) = (self.remaining(), page.contents.as_mut()) {
+            contents.truncate(remaining);
+        }
+        self.items += page.contents.as_ref().map_or(0, Vec::len);
+        match page.next_continuation_token.clone() {
+            Some(token) if page.is_truncated == Some(true) && self.remaining() != Some(0) => {
+                self.request.continuation_token = Some(token);
+            }
+            _ => self.done = true,
+        }
+        page
+    }
+}
+
+impl<'a> Stream for ListObjectsV2Pages<'a> {
+    type Item = ListObjectsV2Result;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.pending.is_none() {
+            if this.done || this.remaining() == Some(0) {
+                return Poll::Ready(None);
+            }
+            let mut request = this.request.clone();
+            if let Some(remaining) = this.remaining() {
+                let remaining = remaining as i64;
+                request.max_keys = Some(
+                    request
+                        .max_keys
+                        .map_or(remaining, |max_keys| max_keys.min(remaining)),
+                );
+            }
+            let client = this.client;
+            this.pending = Some(client.list_objects_v2(request));
+        }
+
+        let result = match this.pending.as_mut() {
+            Some(pending) => futures::ready!(pending.as_mut().poll(cx)),
+            None => unreachable!("a page was requested"),
+        };
+        this.pending = None;
+        match result {
+            Ok(page) => Poll::Ready(Some(Ok(this.next_page(page)))),
+            Err(err) => {
+                this.done = true;
+                Poll::Ready(Some(Err(err)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    use super::*;
+    use crate::generated::S3Client;
+
+    fn page(
+        keys: &[&str],
+        continuation_token: Option<&'static str>,
+        next_continuation_token: Option<&str>,
+        max_keys: Option<&'static str>,
+    ) -> MockRequestDispatcher {
+        let contents: String = keys
+            .iter()
+            .map(|key| format!("<Contents><Key>{}</Key><Size>1</Size></Contents>", key))
+            .collect();
+        let next = next_continuation_token
+            .map(|token| format!("<NextContinuationToken>{}</NextContinuationToken>", token))
+            .unwrap_or_default();
+        MockRequestDispatcher::with_status(200)
+            .with_body(&format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Name>bucket</Name>
+                    <IsTruncated>{}</IsTruncated>
+                    {}{}
+                </ListBucketResult>"#,
+                next_continuation_token.is_some(),
+                next,
+                contents
+            ))
+            .with_request_checker(move |request| {
+                assert_eq!(request.params.get("list-type"), Some(&Some("2".to_owned())));
+                assert_eq!(
+                    request.params.get("continuation-token"),
+                    continuation_token
+                        .map(|token| Some(token.to_owned()))
+                        .as_ref()
+                );
+                assert_eq!(
+                    request.params.get("max-keys"),
+                    max_keys.map(|max_keys| Some(max_keys.to_owned())).as_ref()
+                );
+            })
+    }
+
+    fn request() -> ListObjectsV2Request {
+        ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn keys(objects: Vec<Object>) -> Vec<String> {
+        objects
+            .into_iter()
+            .filter_map(|object| object.key)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn pages() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            page(&["a", "b"], None, Some("token1"), None),
+            page(&["c"], Some("token1"), Some("token2"), None),
+            page(&[], Some("token2"), None, None),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let pages: Vec<_> = client
+            .list_objects_v2_pages(request())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 3);
+
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            page(&["a", "b"], None, Some("token1"), None),
+            page(&["c"], Some("token1"), None, None),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let objects = client
+            .list_objects_v2_pages(request())
+            .into_objects()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(keys(objects), vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn max_items() {
+        let mock = MultipleMockRequestDispatcher::new(vec![
+            page(&["a", "b"], None, Some("token1"), Some("2")),
+            page(&["c", "d"], Some("token1"), Some("token2"), Some("1")),
+        ]);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let request = ListObjectsV2Request {
+            max_keys: Some(2),
+            ..request()
+        };
+        let objects = client
+            .list_objects_v2_pages(request)
+            .max_items(3)
+            .into_objects()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(keys(objects), vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn errors_end_the_stream() {
+        let mock = MockRequestDispatcher::with_status(403);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let pages: Vec<_> = client.list_objects_v2_pages(request()).collect().await;
+        assert_eq!(pages.len(), 1);
+        assert!(pages[0].is_err());
+    }
+}
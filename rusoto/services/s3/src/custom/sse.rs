@@ -0,0 +1,179 @@
+use std::fmt;
+
+use crate::generated::{
+    CopyObjectRequest, CreateMultipartUploadRequest, GetObjectRequest, HeadObjectRequest,
+    PutObjectRequest, SelectObjectContentRequest, UploadPartCopyRequest, UploadPartRequest,
+};
+
+/// The algorithm of objects encrypted with customer-provided keys.
+const ALGORITHM: &str = "AES256";
+
+/// A customer-provided key S3 encrypts objects with, rather than with a key it manages (SSE-C).
+///
+/// S3 doesn't store the key, so it has to be sent with every request writing or reading the
+/// object, along with the algorithm and the MD5 digest of the key. `set_customer_key` fills in
+/// all three of them for requests like `PutObjectRequest` and `GetObjectRequest`, and
+/// `set_copy_source_customer_key` those of the source of copies.
+///
+/// ```rust
+/// use rusoto_s3::sse::{CustomerKey, CustomerKeyRequest};
+/// use rusoto_s3::{GetObjectRequest, PutObjectRequest};
+///
+/// let key = CustomerKey::new([7; 32]);
+///
+/// let mut request = PutObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "secrets.txt".to_owned(),
+///     body: Some(b"secret".to_vec().into()),
+///     ..Default::default()
+/// };
+/// request.set_customer_key(&key);
+///
+/// // objects are read with the key they were written with
+/// let mut request = GetObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "secrets.txt".to_owned(),
+///     ..Default::default()
+/// };
+/// request.set_customer_key(&key);
+/// assert_eq!(request.sse_customer_algorithm.as_deref(), Some("AES256"));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct CustomerKey {
+    key: String,
+    key_md5: String,
+}
+
+impl CustomerKey {
+    /// Create a customer-provided key from the 256 bits of an AES key.
+    pub fn new(key: [u8; 32]) -> CustomerKey {
+        CustomerKey {
+            key: base64::encode(key),
+            key_md5: base64::encode(*md5::compute(key)),
+        }
+    }
+
+    /// The algorithm of the key, `AES256`.
+    pub fn algorithm(&self) -> &'static str {
+        ALGORITHM
+    }
+
+    /// The base64 encoded key, as in the `sse_customer_key` fields.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The base64 encoded MD5 digest of the key, as in the `sse_customer_key_md5` fields, which
+    /// S3 checks the key with.
+    pub fn key_md5(&self) -> &str {
+        &self.key_md5
+    }
+}
+
+impl fmt::Debug for CustomerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the key itself is secret
+        f.debug_struct("CustomerKey")
+            .field("key_md5", &self.key_md5)
+            .finish()
+    }
+}
+
+/// Requests of objects encrypted with a customer-provided key.
+pub trait CustomerKeyRequest {
+    /// Sets the algorithm, the key and the MD5 digest of the key the object is encrypted with.
+    fn set_customer_key(&mut self, key: &CustomerKey);
+}
+
+/// Copies of objects encrypted with a customer-provided key.
+pub trait CopySourceCustomerKeyRequest {
+    /// Sets the algorithm, the key and the MD5 digest of the key the source object is
+    /// encrypted with.
+    fn set_copy_source_customer_key(&mut self, key: &CustomerKey);
+}
+
+macro_rules! customer_key_requests {
+    ($($request:ty),*) => {
+        $(
+            impl CustomerKeyRequest for $request {
+                fn set_customer_key(&mut self, key: &CustomerKey) {
+                    self.sse_customer_algorithm = Some(key.algorithm().to_owned());
+                    self.sse_customer_key = Some(key.key().to_owned());
+                    self.sse_customer_key_md5 = Some(key.key_md5().to_owned());
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! copy_source_customer_key_requests {
+    ($($request:ty),*) => {
+        $(
+            impl CopySourceCustomerKeyRequest for $request {
+                fn set_copy_source_customer_key(&mut self, key: &CustomerKey) {
+                    self.copy_source_sse_customer_algorithm = Some(key.algorithm().to_owned());
+                    self.copy_source_sse_customer_key = Some(key.key().to_owned());
+                    self.copy_source_sse_customer_key_md5 = Some(key.key_md5().to_owned());
+                }
+            }
+        )*
+    };
+}
+
+customer_key_requests!(
+    CopyObjectRequest,
+    CreateMultipartUploadRequest,
+    GetObjectRequest,
+    HeadObjectRequest,
+    PutObjectRequest,
+    SelectObjectContentRequest,
+    UploadPartCopyRequest,
+    UploadPartRequest
+);
+
+copy_source_customer_key_requests!(CopyObjectRequest, UploadPartCopyRequest);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::S3Client;
+    use crate::S3;
+    use rusoto_core::Region;
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    #[tokio::test]
+    async fn customer_key_headers() {
+        let key = CustomerKey::new([0; 32]);
+        assert_eq!(key.key(), "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=");
+        assert_eq!(key.key_md5(), "cLyPS3KoaSFGi/joRB3OUQ==");
+        assert!(!format!("{:?}", key).contains(key.key()));
+
+        let source_key = CustomerKey::new([1; 32]);
+        let mock = MockRequestDispatcher::with_status(200).with_request_checker(move |request| {
+            let header =
+                |name: &str| String::from_utf8(request.headers()[name][0].clone()).unwrap();
+            assert_eq!(
+                header("x-amz-server-side-encryption-customer-algorithm"),
+                "AES256"
+            );
+            assert_eq!(
+                header("x-amz-server-side-encryption-customer-key-md5"),
+                "cLyPS3KoaSFGi/joRB3OUQ=="
+            );
+            assert_eq!(
+                header("x-amz-copy-source-server-side-encryption-customer-key"),
+                "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE="
+            );
+        });
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut request = CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            copy_source: "bucket/source".to_owned(),
+            ..Default::default()
+        };
+        request.set_customer_key(&key);
+        request.set_copy_source_customer_key(&source_key);
+        client.copy_object(request).await.unwrap();
+    }
+}
@@ -0,0 +1,680 @@
+//! Synchronizing local directories with S3 prefixes, transferring only the files and objects
+//! which changed.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::{stream, StreamExt, TryStreamExt};
+use tokio::io::AsyncReadExt;
+
+use crate::custom::pagination::ListObjectsV2Paginator;
+use crate::custom::transfer::{TransferError, TransferManager};
+use crate::generated::{
+    CreateMultipartUploadRequest, GetObjectRequest, ListObjectsV2Request, Object, S3,
+};
+
+/// Which files and objects `TransferManager::upload_dir` and `TransferManager::download_prefix`
+/// transfer.
+///
+/// Files and objects are transferred if the destination is missing, or has another size.
+/// Those of the same size are compared by ETag if `set_compare_e_tags` is set, and otherwise
+/// transferred if the source was modified after the destination.
+#[derive(Clone, Debug, Default)]
+pub struct SyncOptions {
+    include: Vec<String>,
+    exclude: Vec<String>,
+    compare_e_tags: bool,
+}
+
+impl SyncOptions {
+    /// Create options transferring every file and object which changed, by modification time.
+    pub fn new() -> SyncOptions {
+        SyncOptions::default()
+    }
+
+    /// Only transfer the paths matching one of the included patterns, if there are any.
+    ///
+    /// Paths are relative to the directory and to the prefix, with `/` separators, like
+    /// `logs/2020/app.log`. In patterns, `*` matches any characters, including `/`, and `?`
+    /// matches any one character, e.g. `*.log` or `logs/2020/*`.
+    pub fn add_include(&mut self, pattern: &str) {
+        self.include.push(pattern.to_owned());
+    }
+
+    /// Don't transfer the paths matching the pattern, even if they're included.
+    pub fn add_exclude(&mut self, pattern: &str) {
+        self.exclude.push(pattern.to_owned());
+    }
+
+    /// Set whether files and objects of the same size are compared by ETag, rather than by
+    /// modification time. `false` by default.
+    ///
+    /// The ETag of the file is computed from its MD5 digest, as S3 does for objects uploaded in
+    /// a single request, or in parts of the part size of the transfer manager. Files are read
+    /// to compute it, and objects whose ETags aren't MD5 digests, like those encrypted with
+    /// SSE-KMS or uploaded in parts of another size, are always transferred.
+    pub fn set_compare_e_tags(&mut self, compare_e_tags: bool) {
+        self.compare_e_tags = compare_e_tags;
+    }
+
+    /// Get whether files and objects of the same size are compared by ETag.
+    pub fn compare_e_tags(&self) -> bool {
+        self.compare_e_tags
+    }
+
+    /// Whether the relative path is transferred.
+    fn includes(&self, path: &str) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|pattern| matches(pattern, path)))
+            && !self.exclude.iter().any(|pattern| matches(pattern, path))
+    }
+}
+
+/// A file of a synchronized directory.
+#[derive(Debug)]
+struct LocalFile {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// Which way files and objects are transferred.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    Upload,
+    Download,
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Sync,
+{
+    /// Upload the files of the directory and its subdirectories which changed, as the objects
+    /// with their relative paths after the `key` of the request, e.g. `backups/`, see
+    /// `SyncOptions`. The other fields of the request are used for every upload. Returns the
+    /// keys of the uploaded objects.
+    ///
+    /// Up to `concurrency` files are compared and uploaded at a time, each of them `concurrency`
+    /// parts at a time. Symbolic links to files are followed, those to directories aren't.
+    pub async fn upload_dir<P>(
+        &self,
+        dir: P,
+        request: CreateMultipartUploadRequest,
+        options: &SyncOptions,
+    ) -> Result<Vec<String>, TransferError>
+    where
+        P: AsRef<Path>,
+    {
+        let files = local_files(dir.as_ref(), options)
+            .await
+            .map_err(TransferError::Io)?;
+        let list = ListObjectsV2Request {
+            bucket: request.bucket.clone(),
+            prefix: Some(request.key.clone()),
+            request_payer: request.request_payer.clone(),
+            ..Default::default()
+        };
+        let objects = self.objects(list, options).await?;
+
+        let request = &request;
+        let objects = &objects;
+        stream::iter(files)
+            .map(|(path, file)| async move {
+                let changed = match objects.get(&path) {
+                    Some(object) => self
+                        .differs(&file, object, Direction::Upload, options)
+                        .await
+                        .map_err(TransferError::Io)?,
+                    None => true,
+                };
+                if !changed {
+                    return Ok(None);
+                }
+                let request = CreateMultipartUploadRequest {
+                    key: format!("{}{}", request.key, path),
+                    ..request.clone()
+                };
+                let key = request.key.clone();
+                self.upload_file(request, &file.path).await?;
+                Ok(Some(key))
+            })
+            .buffer_unordered(self.concurrency())
+            .try_filter_map(|key| async move { Ok(key) })
+            .try_collect()
+            .await
+    }
+
+    /// Download the objects under the prefix of the request which changed into the directory,
+    /// as the files at their keys after the prefix, creating subdirectories as needed, see
+    /// `SyncOptions`. Returns the keys of the downloaded objects.
+    ///
+    /// Up to `concurrency` objects are compared and downloaded at a time, each of them
+    /// `concurrency` parts at a time. Downloaded files are given the modification time of their
+    /// object, so they aren't uploaded again by `upload_dir`. Objects whose keys end with `/`,
+    /// which the console creates for folders, are skipped.
+    pub async fn download_prefix<P>(
+        &self,
+        request: ListObjectsV2Request,
+        dir: P,
+        options: &SyncOptions,
+    ) -> Result<Vec<String>, TransferError>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        let template = GetObjectRequest {
+            bucket: request.bucket.clone(),
+            request_payer: request.request_payer.clone(),
+            ..Default::default()
+        };
+        let objects = self.objects(request, options).await?;
+
+        let template = &template;
+        stream::iter(objects)
+            .map(|(path, object)| async move {
+                let path = local_path(dir, &path).map_err(TransferError::Io)?;
+                let changed = match local_file(&path).await.map_err(TransferError::Io)? {
+                    Some(file) => self
+                        .differs(&file, &object, Direction::Download, options)
+                        .await
+                        .map_err(TransferError::Io)?,
+                    None => true,
+                };
+                if !changed {
+                    return Ok(None);
+                }
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent)
+                        .await
+                        .map_err(TransferError::Io)?;
+                }
+                let key = object.key.clone().unwrap_or_default();
+                let request = GetObjectRequest {
+                    key: key.clone(),
+                    ..template.clone()
+                };
+                self.download_file(request, &path).await?;
+                if let Some(modified) = object.last_modified.as_deref().and_then(parse_timestamp) {
+                    set_modified(&path, modified)
+                        .await
+                        .map_err(TransferError::Io)?;
+                }
+                Ok(Some(key))
+            })
+            .buffer_unordered(self.concurrency())
+            .try_filter_map(|key| async move { Ok(key) })
+            .try_collect()
+            .await
+    }
+
+    /// Lists the objects under the prefix of the request, by their keys after the prefix, in
+    /// the order of the keys.
+    async fn objects(
+        &self,
+        request: ListObjectsV2Request,
+        options: &SyncOptions,
+    ) -> Result<BTreeMap<String, Object>, TransferError> {
+        let prefix = request.prefix.clone().unwrap_or_default();
+        self.client()
+            .list_objects_v2_pages(request)
+            .into_objects()
+            .map_err(TransferError::ListObjects)
+            .try_filter_map(|object| {
+                let path = object
+                    .key
+                    .as_deref()
+                    .and_then(|key| key.strip_prefix(prefix.as_str()))
+                    .filter(|path| !path.is_empty() && !path.ends_with('/'))
+                    .filter(|path| options.includes(path))
+                    .map(str::to_owned);
+                async move { Ok(path.map(|path| (path, object))) }
+            })
+            .try_collect()
+            .await
+    }
+
+    /// Whether the file and the object of the same path differ, see `SyncOptions`.
+    async fn differs(
+        &self,
+        file: &LocalFile,
+        object: &Object,
+        direction: Direction,
+        options: &SyncOptions,
+    ) -> io::Result<bool> {
+        if object.size != Some(file.size as i64) {
+            return Ok(true);
+        }
+        if options.compare_e_tags {
+            let expected = match object.e_tag.as_deref() {
+                Some(e_tag) => e_tag.trim_matches('"'),
+                None => return Ok(true),
+            };
+            let multipart = expected.contains('-');
+            let e_tag = e_tag(&file.path, self.part_size(), multipart).await?;
+            return Ok(e_tag != expected);
+        }
+        let object_modified = match object.last_modified.as_deref().and_then(parse_timestamp) {
+            Some(modified) => modified,
+            None => return Ok(true),
+        };
+        Ok(match direction {
+            Direction::Upload => file.modified > object_modified,
+            Direction::Download => object_modified > file.modified,
+        })
+    }
+}
+
+/// Lists the included files of the directory and its subdirectories, by their paths relative to
+/// the directory.
+async fn local_files(root: &Path, options: &SyncOptions) -> io::Result<Vec<(String, LocalFile)>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_owned()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            let relative = relative_path(root, &path)?;
+            if !options.includes(&relative) {
+                continue;
+            }
+            if let Some(file) = local_file(&path).await? {
+                files.push((relative, file));
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// The file at the path, if there's one. Symbolic links are followed.
+async fn local_file(path: &Path) -> io::Result<Option<LocalFile>> {
+    let metadata = match tokio::fs::metadata(path).await {
+        Ok(metadata) => metadata,
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    if !metadata.is_file() {
+        return Ok(None);
+    }
+    Ok(Some(LocalFile {
+        path: path.to_owned(),
+        size: metadata.len(),
+        modified: metadata.modified()?,
+    }))
+}
+
+/// The path of the file relative to the directory, with `/` separators.
+fn relative_path(dir: &Path, path: &Path) -> io::Result<String> {
+    let relative = path
+        .strip_prefix(dir)
+        .map_err(|_| io::Error::other("The file isn't in the directory"))?;
+    let components = relative
+        .iter()
+        .map(|component| {
+            component.to_str().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("The path {} isn't valid UTF-8", path.display()),
+                )
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(components.join("/"))
+}
+
+/// The path of the file of an object in the directory, which has to stay in it.
+fn local_path(dir: &Path, relative: &str) -> io::Result<PathBuf> {
+    let mut path = dir.to_owned();
+    for component in relative.split('/') {
+        if component.is_empty() || component == "." || component == ".." {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The key {} isn't a path in the directory", relative),
+            ));
+        }
+        path.push(component);
+    }
+    Ok(path)
+}
+
+/// Computes the ETag S3 gives the file when it's uploaded in a single request, the hex encoded
+/// MD5 digest of the file, or in parts of the part size, the digest of the digests of the parts
+/// followed by the number of parts, like `…-3`.
+async fn e_tag(path: &Path, part_size: usize, multipart: bool) -> io::Result<String> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut buffer = vec![0; 64 * 1024];
+    let mut digest = md5::Context::new();
+    let mut part_digests = Vec::new();
+    let mut part = md5::Context::new();
+    let mut part_length = 0;
+    loop {
+        let limit = buffer.len().min(part_size - part_length);
+        let read = file.read(&mut buffer[..limit]).await?;
+        if read == 0 {
+            break;
+        }
+        digest.consume(&buffer[..read]);
+        part.consume(&buffer[..read]);
+        part_length += read;
+        if part_length == part_size {
+            let full_part = std::mem::replace(&mut part, md5::Context::new());
+            part_digests.push(full_part.compute());
+            part_length = 0;
+        }
+    }
+    // empty files are uploaded as a single empty part
+    if part_length > 0 || part_digests.is_empty() {
+        part_digests.push(part.compute());
+    }
+
+    if multipart {
+        let digests: Vec<u8> = part_digests.iter().flat_map(|digest| digest.0).collect();
+        Ok(format!(
+            "{:x}-{}",
+            md5::compute(digests),
+            part_digests.len()
+        ))
+    } else {
+        Ok(format!("{:x}", digest.compute()))
+    }
+}
+
+/// Sets the modification time of the file.
+async fn set_modified(path: &Path, modified: SystemTime) -> io::Result<()> {
+    let file = tokio::fs::OpenOptions::new().write(true).open(path).await?;
+    file.into_std().await.set_modified(modified)
+}
+
+/// Whether the path matches the pattern, where `*` matches any characters and `?` matches any
+/// one character.
+fn matches(pattern: &str, path: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let path: Vec<char> = path.chars().collect();
+    let (mut p, mut s) = (0, 0);
+    // the position of the last `*` in the pattern, and of the path it was matched at
+    let mut star = None;
+    while s < path.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, s));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == path[s] => {
+                p += 1;
+                s += 1;
+            }
+            _ => match star {
+                // let the `*` match one more character
+                Some((star_p, star_s)) => {
+                    p = star_p + 1;
+                    s = star_s + 1;
+                    star = Some((star_p, star_s + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Parses timestamps like `2020-06-01T12:30:00.000Z`, as in the `last_modified` of objects.
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.strip_suffix('Z')?.split_once('T')?;
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, fraction),
+        None => (time, ""),
+    };
+    let mut time = time.splitn(3, ':').map(|part| part.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    let nanos = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", fraction).get(..9)?.parse().ok()?
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second;
+    if seconds < 0 {
+        return None;
+    }
+    Some(UNIX_EPOCH + Duration::new(seconds as u64, nanos))
+}
+
+/// The days of the date since 1970-01-01, in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // years start in March, so leap days are at their end
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_of_year = (month + 9) % 12;
+    let day_of_year = (153 * month_of_year + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    use super::*;
+    use crate::generated::S3Client;
+
+    /// Creates an empty directory for the test.
+    fn temp_dir() -> PathBuf {
+        static DIRS: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "rusoto-s3-sync-{}-{}",
+            std::process::id(),
+            DIRS.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn listed(objects: &[(&str, usize, &str, &str)]) -> MockRequestDispatcher {
+        let contents: String = objects
+            .iter()
+            .map(|(key, size, e_tag, last_modified)| {
+                format!(
+                    "<Contents><Key>{}</Key><Size>{}</Size><ETag>&quot;{}&quot;</ETag>\
+                     <LastModified>{}</LastModified></Contents>",
+                    key, size, e_tag, last_modified
+                )
+            })
+            .collect();
+        MockRequestDispatcher::with_status(200)
+            .with_body(&format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Name>bucket</Name>
+                    <Prefix>prefix/</Prefix>
+                    <IsTruncated>false</IsTruncated>
+                    {}
+                </ListBucketResult>"#,
+                contents
+            ))
+            .with_request_checker(|request| {
+                assert_eq!(request.method, "GET");
+                assert_eq!(
+                    request.params.get("prefix"),
+                    Some(&Some("prefix/".to_owned()))
+                );
+            })
+    }
+
+    fn manager(mocks: Vec<MockRequestDispatcher>) -> TransferManager<S3Client> {
+        let mock = MultipleMockRequestDispatcher::new(mocks);
+        let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+        let mut manager = TransferManager::new(client);
+        // the mocked responses are in the order of the requests
+        manager.set_concurrency(1);
+        manager
+    }
+
+    #[tokio::test]
+    async fn upload_dir() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("same.txt"), "hello").unwrap();
+        std::fs::create_dir(dir.join("sub")).unwrap();
+        std::fs::write(dir.join("sub").join("new.txt"), "world").unwrap();
+        std::fs::write(dir.join("sub").join("skipped.log"), "log").unwrap();
+
+        let manager = manager(vec![
+            listed(&[(
+                "prefix/same.txt",
+                5,
+                "5d41402abc4b2a76b9719d911017c592",
+                "2000-01-01T00:00:00.000Z",
+            )]),
+            MockRequestDispatcher::with_status(200)
+                .with_body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <InitiateMultipartUploadResult>
+                        <UploadId>upload</UploadId>
+                    </InitiateMultipartUploadResult>"#,
+                )
+                .with_request_checker(|request| {
+                    assert_eq!(request.path, "/prefix/sub/new.txt");
+                    assert!(request.params.contains_key("uploads"));
+                }),
+            MockRequestDispatcher::with_status(200).with_header("ETag", "\"etag1\""),
+            MockRequestDispatcher::with_status(200).with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <CompleteMultipartUploadResult></CompleteMultipartUploadResult>"#,
+            ),
+        ]);
+        let request = CreateMultipartUploadRequest {
+            bucket: "bucket".to_owned(),
+            key: "prefix/".to_owned(),
+            ..Default::default()
+        };
+        let mut options = SyncOptions::new();
+        options.add_exclude("*.log");
+        options.set_compare_e_tags(true);
+        let uploaded = manager.upload_dir(&dir, request, &options).await.unwrap();
+        assert_eq!(uploaded, vec!["prefix/sub/new.txt"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_prefix() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("old.txt"), "stale").unwrap();
+
+        let manager = manager(vec![
+            listed(&[
+                (
+                    "prefix/folder/",
+                    0,
+                    "d41d8cd98f00b204e9800998ecf8427e",
+                    "2020-06-01T12:30:00.000Z",
+                ),
+                ("prefix/old.txt", 5, "etag", "2999-01-01T00:00:00.000Z"),
+                ("prefix/sub/new.txt", 5, "etag", "2020-06-01T12:30:00.000Z"),
+            ]),
+            MockRequestDispatcher::with_status(200)
+                .with_body("fresh")
+                .with_request_checker(|request| {
+                    assert_eq!(request.path, "/prefix/old.txt");
+                }),
+            MockRequestDispatcher::with_status(200)
+                .with_body("world")
+                .with_request_checker(|request| {
+                    assert_eq!(request.path, "/prefix/sub/new.txt");
+                }),
+        ]);
+        let request = ListObjectsV2Request {
+            bucket: "bucket".to_owned(),
+            prefix: Some("prefix/".to_owned()),
+            ..Default::default()
+        };
+        let mut downloaded = manager
+            .download_prefix(request, &dir, &SyncOptions::new())
+            .await
+            .unwrap();
+        downloaded.sort();
+        assert_eq!(downloaded, vec!["prefix/old.txt", "prefix/sub/new.txt"]);
+        assert_eq!(std::fs::read(dir.join("old.txt")).unwrap(), b"fresh");
+        let new = dir.join("sub").join("new.txt");
+        assert_eq!(std::fs::read(&new).unwrap(), b"world");
+        assert_eq!(
+            std::fs::metadata(&new).unwrap().modified().unwrap(),
+            UNIX_EPOCH + Duration::from_secs(1_591_014_600)
+        );
+        assert!(!dir.join("folder").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn e_tags() {
+        let dir = temp_dir();
+        let path = dir.join("file");
+        std::fs::write(&path, vec![1; 10]).unwrap();
+        assert_eq!(
+            e_tag(&path, 4, false).await.unwrap(),
+            format!("{:x}", md5::compute([1; 10]))
+        );
+        let parts = [
+            md5::compute([1; 4]),
+            md5::compute([1; 4]),
+            md5::compute([1; 2]),
+        ];
+        let digests: Vec<u8> = parts.iter().flat_map(|digest| digest.0).collect();
+        assert_eq!(
+            e_tag(&path, 4, true).await.unwrap(),
+            format!("{:x}-3", md5::compute(digests))
+        );
+
+        std::fs::write(&path, b"").unwrap();
+        assert_eq!(
+            e_tag(&path, 4, true).await.unwrap(),
+            format!("{:x}-1", md5::compute(md5::compute(b"").0))
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn patterns() {
+        assert!(matches("*.log", "app.log"));
+        assert!(matches("*.log", "logs/2020/app.log"));
+        assert!(matches("logs/*", "logs/2020/app.log"));
+        assert!(matches("file?.txt", "file1.txt"));
+        assert!(!matches("*.log", "app.txt"));
+        assert!(!matches("file?.txt", "file10.txt"));
+
+        let mut options = SyncOptions::new();
+        options.add_include("logs/*");
+        options.add_exclude("*.tmp");
+        assert!(options.includes("logs/app.log"));
+        assert!(!options.includes("logs/app.tmp"));
+        assert!(!options.includes("data/app.log"));
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(
+            parse_timestamp("2020-06-01T12:30:00.000Z"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_591_014_600))
+        );
+        assert_eq!(
+            parse_timestamp("2000-02-29T00:00:01.5Z"),
+            Some(UNIX_EPOCH + Duration::new(951_782_401, 500_000_000))
+        );
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(UNIX_EPOCH));
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+}
@@ -0,0 +1,1442 @@
+//! Transferring large objects in parts, concurrently.
+//!
+//! See the [documentation](https://docs.aws.amazon.com/AmazonS3/latest/dev/mpuoverview.html)
+//! for more information on multipart uploads.
+
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use bytes::{Bytes, BytesMut};
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
+use rusoto_core::signature::decode_uri;
+use rusoto_core::{ByteStream, ErrorClass, RetryPolicy, Runtime, RusotoError, TokioRuntime};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::generated::{
+    AbortMultipartUploadRequest, CompleteMultipartUploadError, CompleteMultipartUploadOutput,
+    CompleteMultipartUploadRequest, CompletedMultipartUpload, CompletedPart, CopyObjectError,
+    CopyObjectOutput, CopyObjectRequest, CopyObjectResult, CreateMultipartUploadError,
+    CreateMultipartUploadRequest, GetObjectError, GetObjectOutput, GetObjectRequest,
+    GetObjectTaggingError, GetObjectTaggingRequest, HeadObjectError, HeadObjectOutput,
+    HeadObjectRequest, ListObjectsV2Error, UploadPartCopyError, UploadPartCopyRequest,
+    UploadPartError, UploadPartRequest, S3,
+};
+use crate::util::encode_key;
+
+/// The smallest size of parts, except for the last one.
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// The largest number of parts of an upload.
+pub const MAX_PARTS: i64 = 10_000;
+
+/// The largest object `CopyObject` copies, and the largest part of a multipart copy.
+pub const MAX_COPY_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// Uploads objects in parts with a multipart upload, and downloads them in parts with ranged
+/// `GetObject` requests, several parts at a time.
+///
+/// Parts are read from the source as they're uploaded, so at most `concurrency` parts are
+/// held in memory. Parts which fail with errors the retry policy retries are uploaded again;
+/// if a part can't be uploaded, or the source can't be read, the upload is aborted so the
+/// parts uploaded already aren't stored (and billed) any longer.
+///
+/// Downloaded parts are likewise retried, and written or yielded in order, holding at most
+/// `concurrency` parts in memory.
+///
+/// Objects larger than `CopyObject` copies are copied in parts too, see `copy`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_s3::transfer::TransferManager;
+/// use rusoto_s3::{CreateMultipartUploadRequest, GetObjectRequest, S3Client};
+///
+/// # async fn example() {
+/// let mut transfers = TransferManager::new(S3Client::new(Region::UsEast1));
+/// transfers.set_part_size(16 * 1024 * 1024);
+/// transfers.set_concurrency(8);
+///
+/// let request = CreateMultipartUploadRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "backups/archive.tar".to_owned(),
+///     ..Default::default()
+/// };
+/// let output = transfers
+///     .upload_file(request, "archive.tar")
+///     .await
+///     .unwrap();
+/// println!("uploaded {:?}", output.e_tag);
+///
+/// let request = GetObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "backups/archive.tar".to_owned(),
+///     ..Default::default()
+/// };
+/// let size = transfers
+///     .download_file(request, "restored.tar")
+///     .await
+///     .unwrap();
+/// println!("downloaded {} bytes", size);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TransferManager<C> {
+    client: C,
+    part_size: usize,
+    concurrency: usize,
+    retry_policy: RetryPolicy,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Sync,
+{
+    /// Create a `TransferManager` transferring parts of 8 MiB, four at a time, with the client.
+    pub fn new(client: C) -> TransferManager<C> {
+        TransferManager {
+            client,
+            part_size: 8 * 1024 * 1024,
+            concurrency: 4,
+            retry_policy: RetryPolicy::new(),
+            runtime: Arc::new(TokioRuntime),
+        }
+    }
+
+    /// Set the size of parts, at least `MIN_PART_SIZE`. Objects are uploaded in no more than
+    /// `MAX_PARTS` parts, so the part size limits the size of objects.
+    pub fn set_part_size(&mut self, part_size: usize) {
+        self.part_size = part_size.max(MIN_PART_SIZE);
+    }
+
+    /// Get the size of parts.
+    pub fn part_size(&self) -> usize {
+        self.part_size
+    }
+
+    /// Set how many parts are transferred at a time, at least one.
+    pub fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    /// Get how many parts are transferred at a time.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Set how parts are retried. Parts are retried when they couldn't be transferred, or when
+    /// S3 responds with an error of a class the policy retries.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Get how parts are retried.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// The client objects are transferred with.
+    pub(crate) fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Set the runtime whose timers delay retries, `TokioRuntime` by default.
+    pub fn set_runtime<R>(&mut self, runtime: R)
+    where
+        R: Runtime + 'static,
+    {
+        self.runtime = Arc::new(runtime);
+    }
+
+    /// Upload the file, see `upload`.
+    pub async fn upload_file<P>(
+        &self,
+        request: CreateMultipartUploadRequest,
+        path: P,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(TransferError::Io)?;
+        self.upload(request, file).await
+    }
+
+    /// Upload everything read from the reader, as the object the request creates.
+    ///
+    /// The customer provided encryption key and `request_payer` of the request are used for
+    /// every part. Empty sources are uploaded as a single empty part.
+    pub async fn upload<R>(
+        &self,
+        request: CreateMultipartUploadRequest,
+        reader: R,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let template = UploadPartRequest {
+            bucket: request.bucket.clone(),
+            key: request.key.clone(),
+            request_payer: request.request_payer.clone(),
+            sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+            sse_customer_key: request.sse_customer_key.clone(),
+            sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+            ..Default::default()
+        };
+        let template = UploadPartRequest {
+            upload_id: self.create_multipart_upload(request).await?,
+            ..template
+        };
+        let parts = self.upload_parts(&template, reader).await;
+        self.complete_multipart_upload(
+            template.bucket,
+            template.key,
+            template.request_payer,
+            template.upload_id,
+            parts,
+        )
+        .await
+    }
+
+    /// Creates the multipart upload, returning its ID.
+    async fn create_multipart_upload(
+        &self,
+        request: CreateMultipartUploadRequest,
+    ) -> Result<String, TransferError> {
+        self.client
+            .create_multipart_upload(request)
+            .await
+            .map_err(TransferError::CreateMultipartUpload)?
+            .upload_id
+            .ok_or_else(|| {
+                TransferError::CreateMultipartUpload(RusotoError::ParseError(
+                    "The response doesn't include an UploadId".to_owned(),
+                ))
+            })
+    }
+
+    /// Completes the multipart upload with its parts, or aborts it if they couldn't be
+    /// uploaded.
+    async fn complete_multipart_upload(
+        &self,
+        bucket: String,
+        key: String,
+        request_payer: Option<String>,
+        upload_id: String,
+        parts: Result<Vec<CompletedPart>, TransferError>,
+    ) -> Result<CompleteMultipartUploadOutput, TransferError> {
+        match parts {
+            Ok(mut parts) => {
+                parts.sort_by_key(|part| part.part_number);
+                let complete = CompleteMultipartUploadRequest {
+                    bucket,
+                    key,
+                    multipart_upload: Some(CompletedMultipartUpload { parts: Some(parts) }),
+                    request_payer,
+                    upload_id,
+                };
+                self.client
+                    .complete_multipart_upload(complete)
+                    .await
+                    .map_err(TransferError::CompleteMultipartUpload)
+            }
+            Err(err) => {
+                let abort = AbortMultipartUploadRequest {
+                    bucket,
+                    key,
+                    request_payer,
+                    upload_id,
+                };
+                // the error of the upload is more relevant than whether it was aborted
+                let _ = self.client.abort_multipart_upload(abort).await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn upload_parts<R>(
+        &self,
+        template: &UploadPartRequest,
+        reader: R,
+    ) -> Result<Vec<CompletedPart>, TransferError>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let part_size = self.part_size;
+        let parts = stream::unfold(
+            (reader, 1, false),
+            move |(mut reader, part_number, finished)| async move {
+                if finished {
+                    return None;
+                }
+                match read_part(&mut reader, part_size).await {
+                    // the previous part was the last one
+                    Ok(ref data) if data.is_empty() && part_number > 1 => None,
+                    Ok(_) if part_number > MAX_PARTS => Some((
+                        Err(TransferError::TooManyParts),
+                        (reader, part_number, true),
+                    )),
+                    Ok(data) => {
+                        let finished = data.len() < part_size;
+                        Some((Ok((part_number, data)), (reader, part_number + 1, finished)))
+                    }
+                    Err(err) => Some((Err(TransferError::Io(err)), (reader, part_number, true))),
+                }
+            },
+        );
+        parts
+            .map(|part| async move {
+                let (part_number, data) = part?;
+                self.upload_part(template, part_number, data).await
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await
+    }
+
+    async fn upload_part(
+        &self,
+        template: &UploadPartRequest,
+        part_number: i64,
+        data: Bytes,
+    ) -> Result<CompletedPart, TransferError> {
+        let output = self
+            .retry(|| {
+                let request = UploadPartRequest {
+                    body: Some(data.clone().into()),
+                    content_length: Some(data.len() as i64),
+                    part_number,
+                    ..clone_part_request(template)
+                };
+                self.client.upload_part(request)
+            })
+            .await
+            .map_err(TransferError::UploadPart)?;
+        let e_tag = output.e_tag.ok_or_else(|| {
+            TransferError::UploadPart(RusotoError::ParseError(
+                "The response doesn't include an ETag".to_owned(),
+            ))
+        })?;
+        Ok(CompletedPart {
+            e_tag: Some(e_tag),
+            part_number: Some(part_number),
+        })
+    }
+
+    /// Copy the object like `S3::copy_object`, or with a multipart upload whose parts are
+    /// ranges of the source copied with `UploadPartCopy` if it's larger than `MAX_COPY_SIZE`,
+    /// which `CopyObject` fails for. The output of multipart copies has no `last_modified`.
+    ///
+    /// Like `CopyObject`, multipart copies keep the metadata, content headers and tags of the
+    /// source, unless the `metadata_directive` or `tagging_directive` of the request is
+    /// `REPLACE`. Parts of the part size, or larger ones if the object doesn't fit in
+    /// `MAX_PARTS` of them, are copied `concurrency` at a time, with the ETag of the source as
+    /// `copy_source_if_match`, so the copy fails instead of mixing in another version of the
+    /// source if it's overwritten during the copy.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_s3::transfer::TransferManager;
+    /// use rusoto_s3::util::encode_key;
+    /// use rusoto_s3::{CopyObjectRequest, S3Client};
+    ///
+    /// # async fn example() {
+    /// let transfers = TransferManager::new(S3Client::new(Region::UsEast1));
+    /// let request = CopyObjectRequest {
+    ///     bucket: "my-bucket".to_owned(),
+    ///     key: "backups/archive.tar".to_owned(),
+    ///     copy_source: encode_key("other-bucket/archive.tar"),
+    ///     ..Default::default()
+    /// };
+    /// transfers.copy(request).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn copy(
+        &self,
+        request: CopyObjectRequest,
+    ) -> Result<CopyObjectOutput, TransferError> {
+        let (bucket, key, version_id) =
+            parse_copy_source(&request.copy_source).ok_or_else(|| {
+                TransferError::HeadObject(RusotoError::Validation(format!(
+                    "Invalid copy source {}",
+                    request.copy_source
+                )))
+            })?;
+        let source = HeadObjectRequest {
+            bucket,
+            key,
+            version_id,
+            if_match: request.copy_source_if_match.clone(),
+            if_modified_since: request.copy_source_if_modified_since.clone(),
+            if_none_match: request.copy_source_if_none_match.clone(),
+            if_unmodified_since: request.copy_source_if_unmodified_since.clone(),
+            request_payer: request.request_payer.clone(),
+            sse_customer_algorithm: request.copy_source_sse_customer_algorithm.clone(),
+            sse_customer_key: request.copy_source_sse_customer_key.clone(),
+            sse_customer_key_md5: request.copy_source_sse_customer_key_md5.clone(),
+            ..Default::default()
+        };
+        let head = self
+            .retry(|| self.client.head_object(source.clone()))
+            .await
+            .map_err(TransferError::HeadObject)?;
+        let size = head.content_length.unwrap_or(0) as u64;
+        if size <= MAX_COPY_SIZE {
+            return self
+                .client
+                .copy_object(request)
+                .await
+                .map_err(TransferError::CopyObject);
+        }
+        self.copy_parts(request, source, head, size).await
+    }
+
+    async fn copy_parts(
+        &self,
+        request: CopyObjectRequest,
+        source: HeadObjectRequest,
+        head: HeadObjectOutput,
+        size: u64,
+    ) -> Result<CopyObjectOutput, TransferError> {
+        let replaces = |directive: &Option<String>| directive.as_deref() == Some("REPLACE");
+        let tagging = if replaces(&request.tagging_directive) {
+            request.tagging.clone()
+        } else {
+            let tagging = GetObjectTaggingRequest {
+                bucket: source.bucket.clone(),
+                key: source.key.clone(),
+                version_id: head.version_id.clone().or(source.version_id),
+            };
+            let tags = self
+                .retry(|| self.client.get_object_tagging(tagging.clone()))
+                .await
+                .map_err(TransferError::GetObjectTagging)?
+                .tag_set;
+            let tags: Vec<String> = tags
+                .iter()
+                .map(|tag| format!("{}={}", encode_key(&tag.key), encode_key(&tag.value)))
+                .collect();
+            Some(tags.join("&")).filter(|tagging| !tagging.is_empty())
+        };
+        let create = CreateMultipartUploadRequest {
+            acl: request.acl.clone(),
+            bucket: request.bucket.clone(),
+            grant_full_control: request.grant_full_control.clone(),
+            grant_read: request.grant_read.clone(),
+            grant_read_acp: request.grant_read_acp.clone(),
+            grant_write_acp: request.grant_write_acp.clone(),
+            key: request.key.clone(),
+            object_lock_legal_hold_status: request.object_lock_legal_hold_status.clone(),
+            object_lock_mode: request.object_lock_mode.clone(),
+            object_lock_retain_until_date: request.object_lock_retain_until_date.clone(),
+            request_payer: request.request_payer.clone(),
+            sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+            sse_customer_key: request.sse_customer_key.clone(),
+            sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+            ssekms_encryption_context: request.ssekms_encryption_context.clone(),
+            ssekms_key_id: request.ssekms_key_id.clone(),
+            server_side_encryption: request.server_side_encryption.clone(),
+            storage_class: request.storage_class.clone(),
+            tagging,
+            // like `CopyObject`, the redirect isn't copied
+            website_redirect_location: request.website_redirect_location.clone(),
+            ..if replaces(&request.metadata_directive) {
+                CreateMultipartUploadRequest {
+                    cache_control: request.cache_control.clone(),
+                    content_disposition: request.content_disposition.clone(),
+                    content_encoding: request.content_encoding.clone(),
+                    content_language: request.content_language.clone(),
+                    content_type: request.content_type.clone(),
+                    expires: request.expires.clone(),
+                    metadata: request.metadata.clone(),
+                    ..Default::default()
+                }
+            } else {
+                CreateMultipartUploadRequest {
+                    cache_control: head.cache_control.clone(),
+                    content_disposition: head.content_disposition.clone(),
+                    content_encoding: head.content_encoding.clone(),
+                    content_language: head.content_language.clone(),
+                    content_type: head.content_type.clone(),
+                    expires: head.expires.clone(),
+                    metadata: head.metadata.clone(),
+                    ..Default::default()
+                }
+            }
+        };
+        let template = UploadPartCopyRequest {
+            bucket: request.bucket.clone(),
+            copy_source: request.copy_source.clone(),
+            copy_source_if_match: head.e_tag.clone().or(request.copy_source_if_match),
+            copy_source_sse_customer_algorithm: request.copy_source_sse_customer_algorithm,
+            copy_source_sse_customer_key: request.copy_source_sse_customer_key,
+            copy_source_sse_customer_key_md5: request.copy_source_sse_customer_key_md5,
+            key: request.key.clone(),
+            request_payer: request.request_payer.clone(),
+            sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+            sse_customer_key: request.sse_customer_key,
+            sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+            upload_id: self.create_multipart_upload(create).await?,
+            ..Default::default()
+        };
+
+        let part_size = (self.part_size as u64)
+            .max(size / MAX_PARTS as u64 + 1)
+            .min(MAX_COPY_SIZE);
+        let template = &template;
+        let parts = stream::iter((0..size).step_by(part_size as usize).zip(1..))
+            .map(|(start, part_number)| async move {
+                let range = format!("bytes={}-{}", start, cmp::min(start + part_size, size) - 1);
+                let output = self
+                    .retry(|| {
+                        self.client.upload_part_copy(UploadPartCopyRequest {
+                            copy_source_range: Some(range.clone()),
+                            part_number,
+                            ..template.clone()
+                        })
+                    })
+                    .await
+                    .map_err(TransferError::UploadPartCopy)?;
+                let e_tag = output
+                    .copy_part_result
+                    .and_then(|result| result.e_tag)
+                    .ok_or_else(|| {
+                        TransferError::UploadPartCopy(RusotoError::ParseError(
+                            "The response doesn't include an ETag".to_owned(),
+                        ))
+                    })?;
+                Ok(CompletedPart {
+                    e_tag: Some(e_tag),
+                    part_number: Some(part_number),
+                })
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await;
+        let output = self
+            .complete_multipart_upload(
+                template.bucket.clone(),
+                template.key.clone(),
+                template.request_payer.clone(),
+                template.upload_id.clone(),
+                parts,
+            )
+            .await?;
+        Ok(CopyObjectOutput {
+            copy_object_result: Some(CopyObjectResult {
+                e_tag: output.e_tag,
+                last_modified: None,
+            }),
+            copy_source_version_id: head.version_id,
+            expiration: output.expiration,
+            request_charged: output.request_charged,
+            sse_customer_algorithm: request.sse_customer_algorithm,
+            sse_customer_key_md5: request.sse_customer_key_md5,
+            ssekms_encryption_context: request.ssekms_encryption_context,
+            ssekms_key_id: output.ssekms_key_id,
+            server_side_encryption: output.server_side_encryption,
+            version_id: output.version_id,
+        })
+    }
+
+    /// Download the object into the file, see `download`. The file is created, or truncated if
+    /// it exists.
+    pub async fn download_file<P>(
+        &self,
+        request: GetObjectRequest,
+        path: P,
+    ) -> Result<u64, TransferError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = tokio::fs::File::create(path)
+            .await
+            .map_err(TransferError::Io)?;
+        self.download(request, &mut file).await
+    }
+
+    /// Download the object, writing it to the writer in order, see `download_stream`. Returns
+    /// the size of the object.
+    pub async fn download<W>(
+        &self,
+        request: GetObjectRequest,
+        mut writer: W,
+    ) -> Result<u64, TransferError>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let mut parts = Box::pin(self.download_stream(request));
+        let mut size = 0;
+        while let Some(part) = parts.try_next().await? {
+            writer.write_all(&part).await.map_err(TransferError::Io)?;
+            size += part.len() as u64;
+        }
+        writer.flush().await.map_err(TransferError::Io)?;
+        Ok(size)
+    }
+
+    /// Download the object in parts of the part size, getting up to `concurrency` ranges of it
+    /// at a time. The stream yields the parts in order.
+    ///
+    /// The `range` of the request is replaced with the range of each part. Parts after the
+    /// first one are requested with its ETag as `if_match`, so they fail instead of mixing in
+    /// another version of the object if it's overwritten during the download.
+    pub fn download_stream(
+        &self,
+        request: GetObjectRequest,
+    ) -> impl Stream<Item = Result<Bytes, TransferError>> + Send + '_ {
+        let part_size = self.part_size as u64;
+        stream::once(async move {
+            let (first, size, e_tag) = self.download_first_part(&request).await?;
+            let template = GetObjectRequest {
+                if_match: e_tag.or_else(|| request.if_match.clone()),
+                ..request
+            };
+            let ranges = (first.len() as u64..size)
+                .step_by(self.part_size)
+                .map(move |start| {
+                    format!("bytes={}-{}", start, cmp::min(start + part_size, size) - 1)
+                });
+            let rest = stream::iter(ranges)
+                .map(move |range| {
+                    let request = GetObjectRequest {
+                        range: Some(range),
+                        ..template.clone()
+                    };
+                    async move { self.get_part(request).await.map(|(data, _)| data) }
+                })
+                .buffered(self.concurrency);
+            Ok(stream::once(future::ready(Ok(first))).chain(rest))
+        })
+        .try_flatten()
+    }
+
+    /// Gets the first part of the object, returning it with the size and ETag of the object.
+    async fn download_first_part(
+        &self,
+        request: &GetObjectRequest,
+    ) -> Result<(Bytes, u64, Option<String>), TransferError> {
+        let first = GetObjectRequest {
+            range: Some(format!("bytes=0-{}", self.part_size - 1)),
+            ..request.clone()
+        };
+        let (data, output) = match self.get_part(first).await {
+            // empty objects have no range to get
+            Err(TransferError::GetObject(RusotoError::Unknown(ref response)))
+                if response.status.as_u16() == 416 =>
+            {
+                self.get_part(request.clone()).await?
+            }
+            result => result?,
+        };
+        // e.g. `bytes 0-8388607/104857600`, the size is unknown if the range is missing
+        let size = output
+            .content_range
+            .as_ref()
+            .and_then(|range| range.rsplit('/').next())
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(data.len() as u64);
+        Ok((data, size, output.e_tag))
+    }
+
+    /// Gets the object with its body, retrying failures to read the body too.
+    async fn get_part(
+        &self,
+        request: GetObjectRequest,
+    ) -> Result<(Bytes, GetObjectOutput), TransferError> {
+        self.retry(|| {
+            let request = request.clone();
+            async move {
+                let mut output = self.client.get_object(request).await?;
+                let data = match output.body.take() {
+                    Some(body) => body
+                        .try_fold(BytesMut::new(), |mut data, chunk| async move {
+                            data.extend_from_slice(&chunk);
+                            Ok(data)
+                        })
+                        .await?
+                        .freeze(),
+                    None => Bytes::new(),
+                };
+                Ok((data, output))
+            }
+        })
+        .await
+        .map_err(TransferError::GetObject)
+    }
+
+    /// Whether the retry policy retries the error.
+    fn retries<E>(&self, err: &RusotoError<E>) -> bool {
+        match error_class(err) {
+            Some(class) => self.retry_policy.retries(class),
+            None => false,
+        }
+    }
+
+    /// Waits before the next attempt after the failed one.
+    pub(crate) async fn back_off(&self, attempt: u32) {
+        self.runtime.sleep(self.retry_policy.backoff(attempt)).await;
+    }
+
+    /// Runs the operation until it succeeds, fails with an error which isn't retried, or runs
+    /// out of attempts.
+    pub(crate) async fn retry<T, E, F, Fut>(&self, mut operation: F) -> Result<T, RusotoError<E>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, RusotoError<E>>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(ref err) if attempt < self.retry_policy.max_attempts() && self.retries(err) => {
+                    self.back_off(attempt).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<C> TransferManager<C>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    /// Get the object like `S3::get_object`, with a body which resumes where it was interrupted.
+    ///
+    /// When reading the body fails, e.g. because the connection was reset, the rest of it is
+    /// requested from the byte after the last one received, with the ETag of the object as
+    /// `if_match`, and the body continues with the new response instead of failing. Consecutive
+    /// interruptions are retried according to the retry policy; the body fails once it runs out
+    /// of attempts, or if the object was overwritten in the meantime. Bodies of responses
+    /// without an ETag or a `Content-Length` aren't resumed.
+    pub async fn get_object_resumable(
+        &self,
+        request: GetObjectRequest,
+    ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
+        let mut output = self
+            .retry(|| self.client.get_object(request.clone()))
+            .await?;
+        let (e_tag, length) = match (output.e_tag.clone(), output.content_length) {
+            (Some(e_tag), Some(length)) => (e_tag, length as u64),
+            _ => return Ok(output),
+        };
+        if let Some(body) = output.body.take() {
+            let size_hint = body.size_hint();
+            let resumable = ResumableBody {
+                manager: self.clone(),
+                request: GetObjectRequest {
+                    if_match: Some(e_tag.clone()),
+                    ..request
+                },
+                e_tag,
+                start: output
+                    .content_range
+                    .as_ref()
+                    .and_then(|range| first_byte(range))
+                    .unwrap_or(0),
+                length,
+                received: 0,
+                interruptions: 0,
+                state: BodyState::Streaming(body),
+            };
+            output.body = Some(match size_hint {
+                Some(size_hint) => ByteStream::new_with_size(resumable, size_hint),
+                None => ByteStream::new(resumable),
+            });
+        }
+        Ok(output)
+    }
+}
+
+/// The body of `TransferManager::get_object_resumable`.
+struct ResumableBody<C> {
+    manager: TransferManager<C>,
+    /// The request of the object, with its ETag as `if_match`.
+    request: GetObjectRequest,
+    e_tag: String,
+    /// The offset of the body in the object.
+    start: u64,
+    length: u64,
+    received: u64,
+    /// The interruptions since bytes were last received.
+    interruptions: u32,
+    state: BodyState,
+}
+
+enum BodyState {
+    Streaming(ByteStream),
+    /// The mutex makes the body `Sync`, as `ByteStream` requires; it's never locked.
+    Resuming(Mutex<Pin<Box<dyn Future<Output = io::Result<ByteStream>> + Send>>>),
+    Finished,
+}
+
+// the fields are never pinned
+impl<C> Unpin for ResumableBody<C> {}
+
+impl<C> ResumableBody<C>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    /// Requests the rest of the body, after a delay.
+    fn resume(&self) -> impl Future<Output = io::Result<ByteStream>> + Send + 'static {
+        let manager = self.manager.clone();
+        let offset = self.start + self.received;
+        let request = GetObjectRequest {
+            range: Some(format!("bytes={}-{}", offset, self.start + self.length - 1)),
+            ..self.request.clone()
+        };
+        let e_tag = self.e_tag.clone();
+        let delay = manager.retry_policy.backoff(self.interruptions);
+        async move {
+            manager.runtime.sleep(delay).await;
+            let output = manager
+                .retry(|| manager.client.get_object(request.clone()))
+                .await
+                .map_err(io::Error::other)?;
+            let resumed_at = output
+                .content_range
+                .as_ref()
+                .and_then(|range| first_byte(range));
+            if output.e_tag.as_ref() != Some(&e_tag) || resumed_at != Some(offset) {
+                return Err(io::Error::other(
+                    "The object changed while its body was read",
+                ));
+            }
+            Ok(output.body.unwrap_or_else(|| ByteStream::from(Vec::new())))
+        }
+    }
+}
+
+impl<C> Stream for ResumableBody<C>
+where
+    C: S3 + Clone + Send + Sync + 'static,
+{
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let interruption = match this.state {
+                BodyState::Streaming(ref mut body) => match Pin::new(body).poll_next(cx) {
+                    Poll::Ready(Some(Ok(chunk))) => {
+                        this.received += chunk.len() as u64;
+                        this.interruptions = 0;
+                        return Poll::Ready(Some(Ok(chunk)));
+                    }
+                    Poll::Ready(Some(Err(err))) => err,
+                    Poll::Ready(None) if this.received < this.length => io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "The body ended before all of it was received",
+                    ),
+                    Poll::Ready(None) => {
+                        this.state = BodyState::Finished;
+                        return Poll::Ready(None);
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                BodyState::Resuming(ref mut resuming) => {
+                    let resuming = match resuming.get_mut() {
+                        Ok(resuming) => resuming,
+                        Err(poisoned) => poisoned.into_inner(),
+                    };
+                    match resuming.as_mut().poll(cx) {
+                        Poll::Ready(Ok(body)) => {
+                            this.state = BodyState::Streaming(body);
+                            continue;
+                        }
+                        Poll::Ready(Err(err)) => {
+                            this.state = BodyState::Finished;
+                            return Poll::Ready(Some(Err(err)));
+                        }
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+                BodyState::Finished => return Poll::Ready(None),
+            };
+            this.interruptions += 1;
+            if this.interruptions >= this.manager.retry_policy.max_attempts() {
+                this.state = BodyState::Finished;
+                return Poll::Ready(Some(Err(interruption)));
+            }
+            this.state = BodyState::Resuming(Mutex::new(Box::pin(this.resume())));
+        }
+    }
+}
+
+/// The first byte of a `Content-Range`, e.g. `bytes 0-8388607/104857600`.
+fn first_byte(content_range: &str) -> Option<u64> {
+    content_range
+        .trim_start_matches("bytes ")
+        .split('-')
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Reads up to `part_size` bytes, less only at the end of the reader.
+async fn read_part<R>(reader: &mut R, part_size: usize) -> io::Result<Bytes>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buffer = vec![0; part_size];
+    let mut filled = 0;
+    while filled < part_size {
+        match reader.read(&mut buffer[filled..]).await? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    buffer.truncate(filled);
+    Ok(Bytes::from(buffer))
+}
+
+/// Parses the `copy_source` of a copy, `bucket/key` or `bucket/key?versionId=version` with the
+/// key URL encoded, into the bucket, key and version of the source. The buckets of access
+/// points are their ARNs, followed by `/object/` and the key.
+fn parse_copy_source(copy_source: &str) -> Option<(String, String, Option<String>)> {
+    let copy_source = copy_source.trim_start_matches('/');
+    let (source, version_id) = match copy_source.find("?versionId=") {
+        Some(index) => (
+            &copy_source[..index],
+            Some(copy_source[index + "?versionId=".len()..].to_owned()),
+        ),
+        None => (copy_source, None),
+    };
+    let (bucket, key) = if source.starts_with("arn:") {
+        let index = source.find("/object/")?;
+        (&source[..index], &source[index + "/object/".len()..])
+    } else {
+        let index = source.find('/')?;
+        (&source[..index], &source[index + 1..])
+    };
+    if bucket.is_empty() || key.is_empty() {
+        return None;
+    }
+    Some((bucket.to_owned(), decode_uri(key), version_id))
+}
+
+/// `UploadPartRequest` isn't `Clone`, since its body is a stream.
+fn clone_part_request(request: &UploadPartRequest) -> UploadPartRequest {
+    UploadPartRequest {
+        body: None,
+        bucket: request.bucket.clone(),
+        content_length: request.content_length,
+        content_md5: request.content_md5.clone(),
+        key: request.key.clone(),
+        part_number: request.part_number,
+        request_payer: request.request_payer.clone(),
+        sse_customer_algorithm: request.sse_customer_algorithm.clone(),
+        sse_customer_key: request.sse_customer_key.clone(),
+        sse_customer_key_md5: request.sse_customer_key_md5.clone(),
+        upload_id: request.upload_id.clone(),
+    }
+}
+
+/// The class of the error a part failed with, if it may be retried.
+fn error_class<E>(err: &RusotoError<E>) -> Option<ErrorClass> {
+    match *err {
+        RusotoError::HttpDispatch(_) => Some(ErrorClass::Dispatch),
+        RusotoError::Unknown(ref response) => ErrorClass::from_response(response),
+        _ => None,
+    }
+}
+
+/// Error that occurs while transferring an object with a `TransferManager`.
+#[derive(Debug)]
+pub enum TransferError {
+    /// The multipart upload couldn't be created.
+    CreateMultipartUpload(RusotoError<CreateMultipartUploadError>),
+    /// A part couldn't be uploaded, even when retried.
+    UploadPart(RusotoError<UploadPartError>),
+    /// The multipart upload couldn't be completed.
+    CompleteMultipartUpload(RusotoError<CompleteMultipartUploadError>),
+    /// The source of a copy couldn't be found, or its copy source is invalid.
+    HeadObject(RusotoError<HeadObjectError>),
+    /// The tags of the source of a copy couldn't be read.
+    GetObjectTagging(RusotoError<GetObjectTaggingError>),
+    /// The object couldn't be copied.
+    CopyObject(RusotoError<CopyObjectError>),
+    /// A part of a multipart copy couldn't be copied, even when retried.
+    UploadPartCopy(RusotoError<UploadPartCopyError>),
+    /// A part of the object couldn't be downloaded, even when retried.
+    GetObject(RusotoError<GetObjectError>),
+    /// The objects to synchronize couldn't be listed.
+    ListObjects(RusotoError<ListObjectsV2Error>),
+    /// The object doesn't fit in `MAX_PARTS` parts of the part size.
+    TooManyParts,
+    /// The source couldn't be read, or the destination couldn't be written.
+    Io(io::Error),
+}
+
+impl fmt::Display for TransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TransferError::CreateMultipartUpload(ref err) => err.fmt(f),
+            TransferError::UploadPart(ref err) => err.fmt(f),
+            TransferError::CompleteMultipartUpload(ref err) => err.fmt(f),
+            TransferError::HeadObject(ref err) => err.fmt(f),
+            TransferError::GetObjectTagging(ref err) => err.fmt(f),
+            TransferError::CopyObject(ref err) => err.fmt(f),
+            TransferError::UploadPartCopy(ref err) => err.fmt(f),
+            TransferError::GetObject(ref err) => err.fmt(f),
+            TransferError::ListObjects(ref err) => err.fmt(f),
+            TransferError::TooManyParts => write!(
+                f,
+                "The object doesn't fit in {} parts of the part size",
+                MAX_PARTS
+            ),
+            TransferError::Io(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for TransferError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TransferError::CreateMultipartUpload(ref err) => Some(err),
+            TransferError::UploadPart(ref err) => Some(err),
+            TransferError::CompleteMultipartUpload(ref err) => Some(err),
+            TransferError::HeadObject(ref err) => Some(err),
+            TransferError::GetObjectTagging(ref err) => Some(err),
+            TransferError::CopyObject(ref err) => Some(err),
+            TransferError::UploadPartCopy(ref err) => Some(err),
+            TransferError::GetObject(ref err) => Some(err),
+            TransferError::ListObjects(ref err) => Some(err),
+            TransferError::TooManyParts => None,
+            TransferError::Io(ref err) => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rusoto_core::signature::SignedRequestPayload;
+    use rusoto_core::Region;
+    use rusoto_mock::{
+        MockCredentialsProvider, MockRequestDispatcher, MultipleMockRequestDispatcher,
+    };
+
+    use super::*;
+    use crate::generated::S3Client;
+
+    fn request() -> CreateMultipartUploadRequest {
+        CreateMultipartUploadRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn created() -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <InitiateMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Bucket>bucket</Bucket>
+                    <Key>key</Key>
+                    <UploadId>upload</UploadId>
+                </InitiateMultipartUploadResult>"#,
+            )
+            .with_request_checker(|request| {
+                assert_eq!(request.method, "POST");
+                assert!(request.params.contains_key("uploads"));
+            })
+    }
+
+    fn part(part_number: i64, size: usize) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_header("ETag", &format!("\"etag{}\"", part_number))
+            .with_request_checker(move |request| {
+                assert_eq!(request.method, "PUT");
+                assert_eq!(
+                    request.params.get("partNumber"),
+                    Some(&Some(part_number.to_string()))
+                );
+                assert_eq!(
+                    request.params.get("uploadId"),
+                    Some(&Some("upload".to_owned()))
+                );
+                assert_eq!(
+                    request.headers.get("content-length"),
+                    Some(&vec![size.to_string().into_bytes()])
+                );
+            })
+    }
+
+    fn transfers(responses: Vec<MockRequestDispatcher>) -> TransferManager<S3Client> {
+        let dispatcher = MultipleMockRequestDispatcher::new(responses);
+        let client = S3Client::new_with(dispatcher, MockCredentialsProvider, Region::UsEast1);
+        let mut transfers = TransferManager::new(client);
+        transfers.set_concurrency(1);
+        transfers.set_part_size(MIN_PART_SIZE);
+        let mut retry_policy = RetryPolicy::new();
+        retry_policy.set_base_delay(Duration::from_millis(1));
+        transfers.set_retry_policy(retry_policy);
+        transfers
+    }
+
+    #[tokio::test]
+    async fn upload() {
+        let responses = vec![
+            created(),
+            part(1, MIN_PART_SIZE),
+            // the second part is retried
+            MockRequestDispatcher::with_status(503),
+            part(2, MIN_PART_SIZE),
+            part(3, 1024 * 1024),
+            MockRequestDispatcher::with_status(200)
+                .with_body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <CompleteMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                        <Location>https://bucket.s3.amazonaws.com/key</Location>
+                        <Bucket>bucket</Bucket>
+                        <Key>key</Key>
+                        <ETag>"etag-3"</ETag>
+                    </CompleteMultipartUploadResult>"#,
+                )
+                .with_request_checker(|request| {
+                    assert_eq!(request.method, "POST");
+                    let payload = match request.payload {
+                        Some(SignedRequestPayload::Buffer(ref payload)) => {
+                            String::from_utf8(payload.to_vec()).unwrap()
+                        }
+                        _ => panic!("the parts are listed in the payload"),
+                    };
+                    let parts: Vec<_> = payload.match_indices("<PartNumber>").collect();
+                    assert_eq!(parts.len(), 3);
+                    let first = payload.find("etag1").unwrap();
+                    let last = payload.find("etag3").unwrap();
+                    assert!(first < last);
+                }),
+        ];
+        let mut transfers = transfers(responses);
+        transfers.set_part_size(1);
+        assert_eq!(transfers.part_size(), MIN_PART_SIZE);
+
+        let data = vec![b'a'; 2 * MIN_PART_SIZE + 1024 * 1024];
+        let output = transfers.upload(request(), &data[..]).await.unwrap();
+        assert_eq!(output.e_tag, Some("\"etag-3\"".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn failed_upload_is_aborted() {
+        let responses = vec![
+            created(),
+            part(1, MIN_PART_SIZE),
+            MockRequestDispatcher::with_status(400),
+            MockRequestDispatcher::with_status(204).with_request_checker(|request| {
+                assert_eq!(request.method, "DELETE");
+                assert_eq!(
+                    request.params.get("uploadId"),
+                    Some(&Some("upload".to_owned()))
+                );
+            }),
+        ];
+        let transfers = transfers(responses);
+
+        let data = vec![b'a'; 2 * MIN_PART_SIZE];
+        let error = transfers.upload(request(), &data[..]).await.unwrap_err();
+        assert!(matches!(error, TransferError::UploadPart(_)));
+    }
+
+    fn range(
+        body: &str,
+        range: &'static str,
+        content_range: &str,
+        if_match: Option<&'static str>,
+    ) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(206)
+            .with_body(body)
+            .with_header("Content-Range", content_range)
+            .with_header("ETag", "\"etag\"")
+            .with_request_checker(move |request| {
+                assert_eq!(request.method, "GET");
+                assert_eq!(
+                    request.headers.get("range"),
+                    Some(&vec![range.as_bytes().to_vec()])
+                );
+                assert_eq!(
+                    request.headers.get("if-match"),
+                    if_match
+                        .map(|e_tag| vec![e_tag.as_bytes().to_vec()])
+                        .as_ref()
+                );
+            })
+    }
+
+    #[tokio::test]
+    async fn download() {
+        let size = 2 * MIN_PART_SIZE + 1024 * 1024;
+        let content_range = |range: &str| format!("bytes {}/{}", range, size);
+        let responses = vec![
+            range(
+                &"a".repeat(MIN_PART_SIZE),
+                "bytes=0-5242879",
+                &content_range("0-5242879"),
+                None,
+            ),
+            // the second part is retried
+            MockRequestDispatcher::with_status(503),
+            range(
+                &"b".repeat(MIN_PART_SIZE),
+                "bytes=5242880-10485759",
+                &content_range("5242880-10485759"),
+                Some("\"etag\""),
+            ),
+            range(
+                &"c".repeat(1024 * 1024),
+                "bytes=10485760-11534335",
+                &content_range("10485760-11534335"),
+                Some("\"etag\""),
+            ),
+        ];
+        let transfers = transfers(responses);
+
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let mut data = Vec::new();
+        let downloaded = transfers.download(request, &mut data).await.unwrap();
+        assert_eq!(downloaded, size as u64);
+        assert_eq!(data.len(), size);
+        assert!(data[..MIN_PART_SIZE].iter().all(|byte| *byte == b'a'));
+        assert!(data[MIN_PART_SIZE..2 * MIN_PART_SIZE]
+            .iter()
+            .all(|byte| *byte == b'b'));
+        assert!(data[2 * MIN_PART_SIZE..].iter().all(|byte| *byte == b'c'));
+    }
+
+    #[tokio::test]
+    async fn download_empty_object() {
+        let responses = vec![
+            MockRequestDispatcher::with_status(416),
+            MockRequestDispatcher::with_status(200).with_request_checker(|request| {
+                assert!(!request.headers.contains_key("range"));
+            }),
+        ];
+        let transfers = transfers(responses);
+
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let parts: Vec<Bytes> = transfers
+            .download_stream(request)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(parts.concat().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn resumed_body() {
+        let responses = vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body("hello ")
+                .with_body_interrupted()
+                .with_header("Content-Length", "11")
+                .with_header("ETag", "\"etag\""),
+            MockRequestDispatcher::with_status(206)
+                .with_body("world")
+                .with_header("Content-Range", "bytes 6-10/11")
+                .with_header("ETag", "\"etag\"")
+                .with_request_checker(|request| {
+                    assert_eq!(
+                        request.headers.get("range"),
+                        Some(&vec![b"bytes=6-10".to_vec()])
+                    );
+                    assert_eq!(
+                        request.headers.get("if-match"),
+                        Some(&vec![b"\"etag\"".to_vec()])
+                    );
+                }),
+        ];
+        let transfers = transfers(responses);
+
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let output = transfers.get_object_resumable(request).await.unwrap();
+        let body = output.body.unwrap().collect().await.unwrap();
+        assert_eq!(body, "hello world");
+    }
+
+    #[tokio::test]
+    async fn resumed_body_of_changed_object() {
+        let responses = vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body("hello ")
+                .with_body_interrupted()
+                .with_header("Content-Length", "11")
+                .with_header("ETag", "\"etag\""),
+            MockRequestDispatcher::with_status(412),
+        ];
+        let transfers = transfers(responses);
+
+        let request = GetObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        let output = transfers.get_object_resumable(request).await.unwrap();
+        assert!(output.body.unwrap().collect().await.is_err());
+    }
+
+    fn source(size: u64) -> MockRequestDispatcher {
+        MockRequestDispatcher::with_status(200)
+            .with_header("Content-Length", &size.to_string())
+            .with_header("ETag", "\"source\"")
+            .with_header("Content-Type", "text/plain")
+            .with_header("x-amz-meta-owner", "me")
+            .with_request_checker(|request| {
+                assert_eq!(request.method, "HEAD");
+                assert_eq!(request.path, "/dir/a b.txt");
+                assert_eq!(
+                    request.params.get("versionId"),
+                    Some(&Some("v1".to_owned()))
+                );
+            })
+    }
+
+    fn copy_request() -> CopyObjectRequest {
+        CopyObjectRequest {
+            bucket: "bucket".to_owned(),
+            key: "key".to_owned(),
+            copy_source: "source/dir/a%20b.txt?versionId=v1".to_owned(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn copy() {
+        let responses = vec![
+            source(MAX_COPY_SIZE),
+            MockRequestDispatcher::with_status(200)
+                .with_body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <CopyObjectResult>
+                        <LastModified>2020-06-01T12:30:00.000Z</LastModified>
+                        <ETag>"source"</ETag>
+                    </CopyObjectResult>"#,
+                )
+                .with_request_checker(|request| {
+                    assert_eq!(request.method, "PUT");
+                    assert_eq!(
+                        request.headers.get("x-amz-copy-source"),
+                        Some(&vec![b"source/dir/a%20b.txt?versionId=v1".to_vec()])
+                    );
+                }),
+        ];
+        let output = transfers(responses).copy(copy_request()).await.unwrap();
+        assert_eq!(
+            output.copy_object_result.unwrap().e_tag,
+            Some("\"source\"".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn multipart_copy() {
+        let size = MAX_COPY_SIZE + 1;
+        let part = |part_number: i64, range: &'static str| {
+            MockRequestDispatcher::with_status(200)
+                .with_body(&format!(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <CopyPartResult>
+                        <LastModified>2020-06-01T12:30:00.000Z</LastModified>
+                        <ETag>"etag{}"</ETag>
+                    </CopyPartResult>"#,
+                    part_number
+                ))
+                .with_request_checker(move |request| {
+                    assert_eq!(request.method, "PUT");
+                    assert_eq!(
+                        request.params.get("partNumber"),
+                        Some(&Some(part_number.to_string()))
+                    );
+                    assert_eq!(
+                        request.headers.get("x-amz-copy-source-range"),
+                        Some(&vec![range.as_bytes().to_vec()])
+                    );
+                    // the parts are copied from the same version of the source
+                    assert_eq!(
+                        request.headers.get("x-amz-copy-source-if-match"),
+                        Some(&vec![b"\"source\"".to_vec()])
+                    );
+                })
+        };
+        let responses = vec![
+            source(size),
+            MockRequestDispatcher::with_status(200)
+                .with_body(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+                    <Tagging><TagSet>
+                        <Tag><Key>team</Key><Value>data eng</Value></Tag>
+                        <Tag><Key>tier</Key><Value>1</Value></Tag>
+                    </TagSet></Tagging>"#,
+                )
+                .with_request_checker(|request| {
+                    assert!(request.params.contains_key("tagging"));
+                }),
+            created().with_request_checker(|request| {
+                assert_eq!(
+                    request.headers.get("content-type"),
+                    Some(&vec![b"text/plain".to_vec()])
+                );
+                assert_eq!(
+                    request.headers.get("x-amz-meta-owner"),
+                    Some(&vec![b"me".to_vec()])
+                );
+                assert_eq!(
+                    request.headers.get("x-amz-tagging"),
+                    Some(&vec![b"team=data%20eng&tier=1".to_vec()])
+                );
+            }),
+            part(1, "bytes=0-5368709119"),
+            part(2, "bytes=5368709120-5368709120"),
+            MockRequestDispatcher::with_status(200).with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <CompleteMultipartUploadResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Bucket>bucket</Bucket>
+                    <Key>key</Key>
+                    <ETag>"etag-2"</ETag>
+                </CompleteMultipartUploadResult>"#,
+            ),
+        ];
+        let mut transfers = transfers(responses);
+        transfers.set_part_size(MAX_COPY_SIZE as usize);
+        let output = transfers.copy(copy_request()).await.unwrap();
+        assert_eq!(
+            output.copy_object_result.unwrap().e_tag,
+            Some("\"etag-2\"".to_owned())
+        );
+
+        assert_eq!(
+            parse_copy_source("arn:aws:s3:us-west-2:123456789012:accesspoint/ap/object/a/b"),
+            Some((
+                "arn:aws:s3:us-west-2:123456789012:accesspoint/ap".to_owned(),
+                "a/b".to_owned(),
+                None
+            ))
+        );
+        assert_eq!(parse_copy_source("bucket"), None);
+    }
+}
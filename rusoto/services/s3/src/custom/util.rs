@@ -1,12 +1,31 @@
 use crate::generated::{
     DeleteObjectRequest, GetObjectRequest, PutObjectRequest, UploadPartRequest,
 };
+use rusoto_core::access_point;
 use rusoto_core::credential::AwsCredentials;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::region::Region;
 use rusoto_core::signature;
 use rusoto_core::signature::SignedRequest;
-use std::time::Duration;
+use rusoto_core::ClientConfig;
+
+/// Browser uploads with HTML forms, restricted by a signed policy.
+///
+/// ```rust
+/// use rusoto_core::credential::AwsCredentials;
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::PostPolicy;
+///
+/// let mut policy = PostPolicy::new("my-bucket", "avatars/user-1.png");
+/// policy.set_content_length_range(1, 1024 * 1024);
+/// policy.add_field("Content-Type", "image/png");
+///
+/// let credentials = AwsCredentials::new("key", "secret", None, None);
+/// let post = policy.sign(&Region::UsEast1, &credentials);
+/// // render a form posting `post.fields` and a `file` field to `post.url`
+/// ```
+pub use rusoto_core::signature::post_policy::{PostPolicy, PresignedPost};
+
 /// URL encodes an S3 object key. This is necessary for `copy_object` and `upload_part_copy`,
 /// which require the `copy_source` field to be URL encoded.
 ///
@@ -60,16 +79,46 @@ macro_rules! add_params {
     });
 }
 
-pub struct PreSignedRequestOption {
-    pub expires_in: Duration,
-}
+/// Options of presigned URLs, like how long they're valid for and the headers they sign.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_core::credential::AwsCredentials;
+/// use rusoto_core::Region;
+/// use rusoto_s3::util::{PreSignedRequest, PreSignedRequestOption};
+/// use rusoto_s3::PutObjectRequest;
+///
+/// let request = PutObjectRequest {
+///     bucket: "my-bucket".to_owned(),
+///     key: "my-key".to_owned(),
+///     ..Default::default()
+/// };
+/// // uploads with the URL have to be encrypted with KMS
+/// let option = PreSignedRequestOption {
+///     expires_in: Duration::from_secs(600),
+///     headers: vec![(
+///         "x-amz-server-side-encryption".to_owned(),
+///         "aws:kms".to_owned(),
+///     )],
+///     ..Default::default()
+/// };
+/// let credentials = AwsCredentials::new("key", "secret", None, None);
+/// let url = request.get_presigned_url(&Region::UsEast1, &credentials, &option);
+/// assert!(url.contains("X-Amz-SignedHeaders=host%3Bx-amz-server-side-encryption"));
+/// ```
+pub type PreSignedRequestOption = signature::PresignOptions;
 
-impl Default for PreSignedRequestOption {
-    fn default() -> Self {
-        Self {
-            expires_in: Duration::from_secs(3600),
-        }
-    }
+/// Presigns the request, sending requests whose bucket is an access point ARN, like those of S3
+/// on Outposts, to the endpoint of the access point.
+fn presigned_url(
+    mut request: SignedRequest,
+    credentials: &AwsCredentials,
+    option: &PreSignedRequestOption,
+) -> String {
+    // ARNs which aren't valid access points are left in the path, where S3 rejects them
+    let _ = access_point::use_access_point(&ClientConfig::new(), &mut request);
+    request.generate_presigned_url_with_options(credentials, option, false)
 }
 
 pub trait PreSignedRequest {
@@ -119,7 +168,7 @@ impl PreSignedRequest for GetObjectRequest {
         );
 
         request.set_params(params);
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        presigned_url(request, credentials, option)
     }
 }
 
@@ -171,7 +220,7 @@ impl PreSignedRequest for PutObjectRequest {
             }
         }
 
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        presigned_url(request, credentials, option)
     }
 }
 
@@ -198,7 +247,7 @@ impl PreSignedRequest for DeleteObjectRequest {
         );
 
         request.set_params(params);
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        presigned_url(request, credentials, option)
     }
 }
 
@@ -226,6 +275,6 @@ impl PreSignedRequest for UploadPartRequest {
             request_payer, "x-amz-request-payer";
         );
 
-        request.generate_presigned_url(credentials, &option.expires_in, false)
+        presigned_url(request, credentials, option)
     }
 }
@@ -225,13 +225,19 @@ impl DeleteHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHumanLoopError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHumanLoopError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteHumanLoopError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -267,13 +273,19 @@ impl DescribeHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHumanLoopError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHumanLoopError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(&res, DescribeHumanLoopError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -309,13 +321,16 @@ impl ListHumanLoopsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListHumanLoopsError::InternalServer(err.msg))
+                    return RusotoError::service(&res, ListHumanLoopsError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListHumanLoopsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListHumanLoopsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListHumanLoopsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListHumanLoopsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -353,16 +368,19 @@ impl StartHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(StartHumanLoopError::Conflict(err.msg))
+                    return RusotoError::service(&res, StartHumanLoopError::Conflict(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(&res, StartHumanLoopError::InternalServer(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartHumanLoopError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartHumanLoopError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartHumanLoopError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -399,13 +417,16 @@ impl StopHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service(&res, StopHumanLoopError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopHumanLoopError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service(&res, StopHumanLoopError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -476,6 +497,7 @@ impl SagemakerA2iRuntimeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -491,6 +513,7 @@ impl SagemakerA2iRuntimeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SagemakerA2iRuntimeClient {
         SagemakerA2iRuntimeClient { client, region }
     }
@@ -510,6 +533,9 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "sagemaker", &self.region, &request_uri);
+        request.set_operation_name("DeleteHumanLoop");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -543,6 +569,9 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation_name("DescribeHumanLoop");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -573,6 +602,9 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation_name("ListHumanLoops");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -622,6 +654,9 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation_name("StartHumanLoop");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -654,6 +689,9 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops/stop";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation_name("StopHumanLoop");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -677,3 +715,111 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Augmented AI Runtime API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SagemakerA2iRuntimeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SagemakerA2iRuntimeBlockingClient {
+    client: SagemakerA2iRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SagemakerA2iRuntimeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SagemakerA2iRuntimeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SagemakerA2iRuntimeBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SagemakerA2iRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SagemakerA2iRuntimeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient {
+            client: SagemakerA2iRuntimeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Deletes the specified human loop for a flow definition.</p>
+    ///
+    /// See `SagemakerA2iRuntime::delete_human_loop`.
+    pub fn delete_human_loop(
+        &self,
+        input: DeleteHumanLoopRequest,
+    ) -> Result<DeleteHumanLoopResponse, RusotoError<DeleteHumanLoopError>> {
+        self.runtime.block_on(self.client.delete_human_loop(input))
+    }
+
+    /// <p>Returns information about the specified human loop.</p>
+    ///
+    /// See `SagemakerA2iRuntime::describe_human_loop`.
+    pub fn describe_human_loop(
+        &self,
+        input: DescribeHumanLoopRequest,
+    ) -> Result<DescribeHumanLoopResponse, RusotoError<DescribeHumanLoopError>> {
+        self.runtime
+            .block_on(self.client.describe_human_loop(input))
+    }
+
+    /// <p>Returns information about human loops, given the specified parameters. If a human loop was deleted, it will not be included.</p>
+    ///
+    /// See `SagemakerA2iRuntime::list_human_loops`.
+    pub fn list_human_loops(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> Result<ListHumanLoopsResponse, RusotoError<ListHumanLoopsError>> {
+        self.runtime.block_on(self.client.list_human_loops(input))
+    }
+
+    /// <p>Starts a human loop, provided that at least one activation condition is met.</p>
+    ///
+    /// See `SagemakerA2iRuntime::start_human_loop`.
+    pub fn start_human_loop(
+        &self,
+        input: StartHumanLoopRequest,
+    ) -> Result<StartHumanLoopResponse, RusotoError<StartHumanLoopError>> {
+        self.runtime.block_on(self.client.start_human_loop(input))
+    }
+
+    /// <p>Stops the specified human loop.</p>
+    ///
+    /// See `SagemakerA2iRuntime::stop_human_loop`.
+    pub fn stop_human_loop(
+        &self,
+        input: StopHumanLoopRequest,
+    ) -> Result<StopHumanLoopResponse, RusotoError<StopHumanLoopError>> {
+        self.runtime.block_on(self.client.stop_human_loop(input))
+    }
+}
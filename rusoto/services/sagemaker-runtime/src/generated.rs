@@ -89,16 +89,25 @@ impl InvokeEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailure" => {
-                    return RusotoError::Service(InvokeEndpointError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeEndpointError::InternalFailure(err.msg),
+                    )
                 }
                 "ModelError" => {
-                    return RusotoError::Service(InvokeEndpointError::ModelError(err.msg))
+                    return RusotoError::service(&res, InvokeEndpointError::ModelError(err.msg))
                 }
                 "ServiceUnavailable" => {
-                    return RusotoError::Service(InvokeEndpointError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeEndpointError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationError" => {
-                    return RusotoError::Service(InvokeEndpointError::ValidationError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InvokeEndpointError::ValidationError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -146,6 +155,7 @@ impl SageMakerRuntimeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -161,6 +171,7 @@ impl SageMakerRuntimeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerRuntimeClient {
         SageMakerRuntimeClient { client, region }
     }
@@ -180,6 +191,9 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation_name("InvokeEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         if input.content_type.is_none() {
             request.set_content_type("application/x-amz-json-1.1".to_owned());
         }
@@ -224,3 +238,66 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon SageMaker Runtime API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SageMakerRuntimeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SageMakerRuntimeBlockingClient {
+    client: SageMakerRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SageMakerRuntimeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SageMakerRuntimeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SageMakerRuntimeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SageMakerRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SageMakerRuntimeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient {
+            client: SageMakerRuntimeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>After you deploy a model into production using Amazon SageMaker hosting services, your client applications use this API to get inferences from the model hosted at the specified endpoint. </p> <p>For an overview of Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p> <p>Amazon SageMaker strips all POST headers except those supported by the API. Amazon SageMaker might add additional headers. You should not rely on the behavior of headers outside those enumerated in the request syntax. </p> <p>Calls to <code>InvokeEndpoint</code> are authenticated by using AWS Signature Version 4. For information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html">Authenticating Requests (AWS Signature Version 4)</a> in the <i>Amazon S3 API Reference</i>.</p> <p>A customer&#39;s model containers must respond to requests within 60 seconds. The model itself can have a maximum processing time of 60 seconds before responding to the /invocations. If your model is going to take 50-60 seconds of processing time, the SDK socket timeout should be set to be 70 seconds.</p> <note> <p>Endpoints are scoped to an individual account, and are not public. The URL does not contain the account ID, but Amazon SageMaker determines the account ID from the authentication token that is supplied by the caller.</p> </note></p>
+    ///
+    /// See `SageMakerRuntime::invoke_endpoint`.
+    pub fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> Result<InvokeEndpointOutput, RusotoError<InvokeEndpointError>> {
+        self.runtime.block_on(self.client.invoke_endpoint(input))
+    }
+}
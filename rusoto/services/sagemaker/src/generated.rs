@@ -8874,14 +8874,16 @@ impl AssociateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTrialComponentError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(AssociateTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTrialComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8938,10 +8940,13 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAppError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateAppError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateAppError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAppError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8974,12 +8979,13 @@ impl CreateAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAutoMLJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateAutoMLJobError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateAutoMLJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAutoMLJobError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9034,12 +9040,16 @@ impl CreateCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateCompilationJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCompilationJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateCompilationJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCompilationJobError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9072,10 +9082,13 @@ impl CreateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateDomainError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateDomainError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDomainError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9106,9 +9119,10 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9138,9 +9152,10 @@ impl CreateEndpointConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateEndpointConfigError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateEndpointConfigError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9170,9 +9185,10 @@ impl CreateExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateExperimentError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateExperimentError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9204,12 +9220,16 @@ impl CreateFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateFlowDefinitionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowDefinitionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateFlowDefinitionError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFlowDefinitionError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9242,12 +9262,16 @@ impl CreateHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateHumanTaskUiError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHumanTaskUiError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateHumanTaskUiError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHumanTaskUiError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9282,12 +9306,14 @@ impl CreateHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateHyperParameterTuningJobError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHyperParameterTuningJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateHyperParameterTuningJobError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -9324,12 +9350,16 @@ impl CreateLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateLabelingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLabelingJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateLabelingJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLabelingJobError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9360,7 +9390,10 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateModelError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateModelError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9414,12 +9447,14 @@ impl CreateMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateMonitoringScheduleError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMonitoringScheduleError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMonitoringScheduleError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -9454,7 +9489,8 @@ impl CreateNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotebookInstanceError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -9488,7 +9524,8 @@ impl CreateNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -9522,9 +9559,10 @@ impl CreatePresignedDomainUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreatePresignedDomainUrlError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePresignedDomainUrlError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9582,17 +9620,22 @@ impl CreateProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProcessingJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProcessingJobError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProcessingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9628,15 +9671,22 @@ impl CreateTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrainingJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrainingJobError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrainingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9672,15 +9722,22 @@ impl CreateTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTransformJobError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTransformJobError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTransformJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9714,10 +9771,13 @@ impl CreateTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrialError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrialError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTrialError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9748,9 +9808,10 @@ impl CreateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrialComponentError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTrialComponentError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9782,12 +9843,16 @@ impl CreateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserProfileError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateUserProfileError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserProfileError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9820,12 +9885,13 @@ impl CreateWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateWorkteamError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, CreateWorkteamError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateWorkteamError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkteamError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9880,10 +9946,10 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteAppError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9938,10 +10004,10 @@ impl DeleteDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDomainError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10016,7 +10082,10 @@ impl DeleteExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteExperimentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10046,9 +10115,10 @@ impl DeleteFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteFlowDefinitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFlowDefinitionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10078,7 +10148,10 @@ impl DeleteHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteHumanTaskUiError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteHumanTaskUiError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10152,9 +10225,10 @@ impl DeleteMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMonitoringScheduleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10252,7 +10326,7 @@ impl DeleteTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTrialError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10282,9 +10356,10 @@ impl DeleteTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTrialComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10316,10 +10391,16 @@ impl DeleteUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserProfileError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10350,9 +10431,10 @@ impl DeleteWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(DeleteWorkteamError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkteamError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10404,7 +10486,7 @@ impl DescribeAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeAppError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10434,7 +10516,10 @@ impl DescribeAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAutoMLJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAutoMLJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10486,9 +10571,10 @@ impl DescribeCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeCompilationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCompilationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10518,7 +10604,10 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10592,7 +10681,10 @@ impl DescribeExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExperimentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10622,9 +10714,10 @@ impl DescribeFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeFlowDefinitionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFlowDefinitionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10654,9 +10747,10 @@ impl DescribeHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeHumanTaskUiError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeHumanTaskUiError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10688,7 +10782,8 @@ impl DescribeHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeHyperParameterTuningJobError::ResourceNotFound(err.msg),
                     )
                 }
@@ -10722,9 +10817,10 @@ impl DescribeLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeLabelingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeLabelingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10800,9 +10896,10 @@ impl DescribeMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMonitoringScheduleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10878,9 +10975,10 @@ impl DescribeProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeProcessingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProcessingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10934,9 +11032,10 @@ impl DescribeTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrainingJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrainingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10966,9 +11065,10 @@ impl DescribeTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTransformJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTransformJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10998,7 +11098,10 @@ impl DescribeTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrialError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11028,9 +11131,10 @@ impl DescribeTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTrialComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11060,9 +11164,10 @@ impl DescribeUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeUserProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11138,9 +11243,10 @@ impl DisassociateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DisassociateTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateTrialComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11260,9 +11366,10 @@ impl ListCandidatesForAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListCandidatesForAutoMLJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCandidatesForAutoMLJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11516,7 +11623,8 @@ impl ListLabelingJobsForWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLabelingJobsForWorkteamError::ResourceNotFound(err.msg),
                     )
                 }
@@ -11772,7 +11880,8 @@ impl ListTrainingJobsForHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound(err.msg),
                     )
                 }
@@ -11828,9 +11937,10 @@ impl ListTrialComponentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTrialComponentsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTrialComponentsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11860,7 +11970,7 @@ impl ListTrialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTrialsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListTrialsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11978,9 +12088,10 @@ impl StartMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StartMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMonitoringScheduleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12010,9 +12121,10 @@ impl StartNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(StartNotebookInstanceError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartNotebookInstanceError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12042,7 +12154,10 @@ impl StopAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopAutoMLJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAutoMLJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12072,7 +12187,10 @@ impl StopCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopCompilationJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopCompilationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12104,7 +12222,8 @@ impl StopHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopHyperParameterTuningJobError::ResourceNotFound(err.msg),
                     )
                 }
@@ -12136,7 +12255,10 @@ impl StopLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopLabelingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopLabelingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12166,9 +12288,10 @@ impl StopMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopMonitoringScheduleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12220,7 +12343,10 @@ impl StopProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopProcessingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopProcessingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12250,7 +12376,10 @@ impl StopTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopTrainingJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12280,7 +12409,10 @@ impl StopTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopTransformJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopTransformJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12336,13 +12468,16 @@ impl UpdateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, UpdateDomainError::ResourceInUse(err.msg))
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDomainError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12374,9 +12509,10 @@ impl UpdateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateEndpointError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12408,7 +12544,8 @@ impl UpdateEndpointWeightsAndCapacitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -12444,10 +12581,13 @@ impl UpdateExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateExperimentError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateExperimentError::Conflict(err.msg))
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateExperimentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12480,14 +12620,16 @@ impl UpdateMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMonitoringScheduleError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateMonitoringScheduleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMonitoringScheduleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12520,7 +12662,8 @@ impl UpdateNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNotebookInstanceError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -12554,7 +12697,8 @@ impl UpdateNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -12590,10 +12734,10 @@ impl UpdateTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTrialError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateTrialError::Conflict(err.msg))
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateTrialError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12626,12 +12770,13 @@ impl UpdateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTrialComponentError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateTrialComponentError::Conflict(err.msg))
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrialComponentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTrialComponentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12666,15 +12811,22 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserProfileError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserProfileError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12728,9 +12880,10 @@ impl UpdateWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateWorkteamError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWorkteamError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13597,6 +13750,7 @@ impl SageMakerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -13612,6 +13766,7 @@ impl SageMakerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerClient {
         SageMakerClient { client, region }
     }
@@ -13625,6 +13780,9 @@ impl SageMaker for SageMakerClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13643,6 +13801,9 @@ impl SageMaker for SageMakerClient {
         input: AssociateTrialComponentRequest,
     ) -> Result<AssociateTrialComponentResponse, RusotoError<AssociateTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateTrialComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.AssociateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13662,6 +13823,9 @@ impl SageMaker for SageMakerClient {
         input: CreateAlgorithmInput,
     ) -> Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAlgorithm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13680,6 +13844,9 @@ impl SageMaker for SageMakerClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13698,6 +13865,9 @@ impl SageMaker for SageMakerClient {
         input: CreateAutoMLJobRequest,
     ) -> Result<CreateAutoMLJobResponse, RusotoError<CreateAutoMLJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAutoMLJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13716,6 +13886,9 @@ impl SageMaker for SageMakerClient {
         input: CreateCodeRepositoryInput,
     ) -> Result<CreateCodeRepositoryOutput, RusotoError<CreateCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCodeRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13734,6 +13907,9 @@ impl SageMaker for SageMakerClient {
         input: CreateCompilationJobRequest,
     ) -> Result<CreateCompilationJobResponse, RusotoError<CreateCompilationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCompilationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13753,6 +13929,9 @@ impl SageMaker for SageMakerClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13771,6 +13950,9 @@ impl SageMaker for SageMakerClient {
         input: CreateEndpointInput,
     ) -> Result<CreateEndpointOutput, RusotoError<CreateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13789,6 +13971,9 @@ impl SageMaker for SageMakerClient {
         input: CreateEndpointConfigInput,
     ) -> Result<CreateEndpointConfigOutput, RusotoError<CreateEndpointConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateEndpointConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13807,6 +13992,9 @@ impl SageMaker for SageMakerClient {
         input: CreateExperimentRequest,
     ) -> Result<CreateExperimentResponse, RusotoError<CreateExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateExperiment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13825,6 +14013,9 @@ impl SageMaker for SageMakerClient {
         input: CreateFlowDefinitionRequest,
     ) -> Result<CreateFlowDefinitionResponse, RusotoError<CreateFlowDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateFlowDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13844,6 +14035,9 @@ impl SageMaker for SageMakerClient {
         input: CreateHumanTaskUiRequest,
     ) -> Result<CreateHumanTaskUiResponse, RusotoError<CreateHumanTaskUiError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHumanTaskUi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13865,6 +14059,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateHyperParameterTuningJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHyperParameterTuningJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13884,6 +14081,9 @@ impl SageMaker for SageMakerClient {
         input: CreateLabelingJobRequest,
     ) -> Result<CreateLabelingJobResponse, RusotoError<CreateLabelingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateLabelingJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13902,6 +14102,9 @@ impl SageMaker for SageMakerClient {
         input: CreateModelInput,
     ) -> Result<CreateModelOutput, RusotoError<CreateModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13920,6 +14123,9 @@ impl SageMaker for SageMakerClient {
         input: CreateModelPackageInput,
     ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateModelPackage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13938,6 +14144,9 @@ impl SageMaker for SageMakerClient {
         input: CreateMonitoringScheduleRequest,
     ) -> Result<CreateMonitoringScheduleResponse, RusotoError<CreateMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMonitoringSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13957,6 +14166,9 @@ impl SageMaker for SageMakerClient {
         input: CreateNotebookInstanceInput,
     ) -> Result<CreateNotebookInstanceOutput, RusotoError<CreateNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNotebookInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -13979,6 +14191,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateNotebookInstanceLifecycleConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNotebookInstanceLifecycleConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SageMaker.CreateNotebookInstanceLifecycleConfig",
@@ -14004,6 +14219,9 @@ impl SageMaker for SageMakerClient {
         input: CreatePresignedDomainUrlRequest,
     ) -> Result<CreatePresignedDomainUrlResponse, RusotoError<CreatePresignedDomainUrlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePresignedDomainUrl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreatePresignedDomainUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14026,6 +14244,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreatePresignedNotebookInstanceUrlError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePresignedNotebookInstanceUrl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SageMaker.CreatePresignedNotebookInstanceUrl",
@@ -14051,6 +14272,9 @@ impl SageMaker for SageMakerClient {
         input: CreateProcessingJobRequest,
     ) -> Result<CreateProcessingJobResponse, RusotoError<CreateProcessingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProcessingJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14069,6 +14293,9 @@ impl SageMaker for SageMakerClient {
         input: CreateTrainingJobRequest,
     ) -> Result<CreateTrainingJobResponse, RusotoError<CreateTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTrainingJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14087,6 +14314,9 @@ impl SageMaker for SageMakerClient {
         input: CreateTransformJobRequest,
     ) -> Result<CreateTransformJobResponse, RusotoError<CreateTransformJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTransformJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14105,6 +14335,9 @@ impl SageMaker for SageMakerClient {
         input: CreateTrialRequest,
     ) -> Result<CreateTrialResponse, RusotoError<CreateTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTrial");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14123,6 +14356,9 @@ impl SageMaker for SageMakerClient {
         input: CreateTrialComponentRequest,
     ) -> Result<CreateTrialComponentResponse, RusotoError<CreateTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTrialComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14142,6 +14378,9 @@ impl SageMaker for SageMakerClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResponse, RusotoError<CreateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14160,6 +14399,9 @@ impl SageMaker for SageMakerClient {
         input: CreateWorkteamRequest,
     ) -> Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWorkteam");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.CreateWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14178,6 +14420,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteAlgorithmInput,
     ) -> Result<(), RusotoError<DeleteAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAlgorithm");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14192,6 +14437,9 @@ impl SageMaker for SageMakerClient {
     /// <p>Used to stop and delete an app.</p>
     async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14209,6 +14457,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteCodeRepositoryInput,
     ) -> Result<(), RusotoError<DeleteCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteCodeRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14226,6 +14477,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteDomainRequest,
     ) -> Result<(), RusotoError<DeleteDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14243,6 +14497,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteEndpointInput,
     ) -> Result<(), RusotoError<DeleteEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14260,6 +14517,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteEndpointConfigInput,
     ) -> Result<(), RusotoError<DeleteEndpointConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteEndpointConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14277,6 +14537,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteExperimentRequest,
     ) -> Result<DeleteExperimentResponse, RusotoError<DeleteExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteExperiment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14295,6 +14558,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteFlowDefinitionRequest,
     ) -> Result<DeleteFlowDefinitionResponse, RusotoError<DeleteFlowDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFlowDefinition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14314,6 +14580,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteHumanTaskUiRequest,
     ) -> Result<DeleteHumanTaskUiResponse, RusotoError<DeleteHumanTaskUiError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteHumanTaskUi");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14332,6 +14601,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelInput,
     ) -> Result<(), RusotoError<DeleteModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteModel");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14349,6 +14621,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelPackageInput,
     ) -> Result<(), RusotoError<DeleteModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteModelPackage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14366,6 +14641,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteMonitoringScheduleRequest,
     ) -> Result<(), RusotoError<DeleteMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMonitoringSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14383,6 +14661,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteNotebookInstanceInput,
     ) -> Result<(), RusotoError<DeleteNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNotebookInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14400,6 +14681,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteNotebookInstanceLifecycleConfigInput,
     ) -> Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNotebookInstanceLifecycleConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SageMaker.DeleteNotebookInstanceLifecycleConfig",
@@ -14423,6 +14707,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteTagsInput,
     ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14441,6 +14728,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteTrialRequest,
     ) -> Result<DeleteTrialResponse, RusotoError<DeleteTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTrial");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14459,6 +14749,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteTrialComponentRequest,
     ) -> Result<DeleteTrialComponentResponse, RusotoError<DeleteTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTrialComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14478,6 +14771,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteUserProfileRequest,
     ) -> Result<(), RusotoError<DeleteUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14495,6 +14791,9 @@ impl SageMaker for SageMakerClient {
         input: DeleteWorkteamRequest,
     ) -> Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWorkteam");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DeleteWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14513,6 +14812,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeAlgorithmInput,
     ) -> Result<DescribeAlgorithmOutput, RusotoError<DescribeAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAlgorithm");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14531,6 +14833,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeAppRequest,
     ) -> Result<DescribeAppResponse, RusotoError<DescribeAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeApp");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14549,6 +14854,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeAutoMLJobRequest,
     ) -> Result<DescribeAutoMLJobResponse, RusotoError<DescribeAutoMLJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAutoMLJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14567,6 +14875,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeCodeRepositoryInput,
     ) -> Result<DescribeCodeRepositoryOutput, RusotoError<DescribeCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCodeRepository");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14586,6 +14897,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeCompilationJobRequest,
     ) -> Result<DescribeCompilationJobResponse, RusotoError<DescribeCompilationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCompilationJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14605,6 +14919,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeDomainRequest,
     ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14623,6 +14940,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeEndpointInput,
     ) -> Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpoint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14641,6 +14961,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeEndpointConfigInput,
     ) -> Result<DescribeEndpointConfigOutput, RusotoError<DescribeEndpointConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEndpointConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14660,6 +14983,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeExperimentRequest,
     ) -> Result<DescribeExperimentResponse, RusotoError<DescribeExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeExperiment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14678,6 +15004,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeFlowDefinitionRequest,
     ) -> Result<DescribeFlowDefinitionResponse, RusotoError<DescribeFlowDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeFlowDefinition");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14697,6 +15026,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeHumanTaskUiRequest,
     ) -> Result<DescribeHumanTaskUiResponse, RusotoError<DescribeHumanTaskUiError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHumanTaskUi");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14718,6 +15050,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeHyperParameterTuningJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeHyperParameterTuningJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14737,6 +15072,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeLabelingJobRequest,
     ) -> Result<DescribeLabelingJobResponse, RusotoError<DescribeLabelingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeLabelingJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14755,6 +15093,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeModelInput,
     ) -> Result<DescribeModelOutput, RusotoError<DescribeModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeModel");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14773,6 +15114,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeModelPackageInput,
     ) -> Result<DescribeModelPackageOutput, RusotoError<DescribeModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeModelPackage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14792,6 +15136,9 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DescribeMonitoringScheduleResponse, RusotoError<DescribeMonitoringScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMonitoringSchedule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14811,6 +15158,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeNotebookInstanceInput,
     ) -> Result<DescribeNotebookInstanceOutput, RusotoError<DescribeNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeNotebookInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14833,6 +15183,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeNotebookInstanceLifecycleConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SageMaker.DescribeNotebookInstanceLifecycleConfig",
@@ -14858,6 +15211,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeProcessingJobRequest,
     ) -> Result<DescribeProcessingJobResponse, RusotoError<DescribeProcessingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProcessingJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14878,6 +15234,9 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DescribeSubscribedWorkteamResponse, RusotoError<DescribeSubscribedWorkteamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSubscribedWorkteam");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeSubscribedWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14897,6 +15256,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeTrainingJobRequest,
     ) -> Result<DescribeTrainingJobResponse, RusotoError<DescribeTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrainingJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14915,6 +15277,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeTransformJobRequest,
     ) -> Result<DescribeTransformJobResponse, RusotoError<DescribeTransformJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTransformJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14934,6 +15299,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeTrialRequest,
     ) -> Result<DescribeTrialResponse, RusotoError<DescribeTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrial");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14952,6 +15320,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeTrialComponentRequest,
     ) -> Result<DescribeTrialComponentResponse, RusotoError<DescribeTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrialComponent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14971,6 +15342,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeUserProfileRequest,
     ) -> Result<DescribeUserProfileResponse, RusotoError<DescribeUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUserProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14989,6 +15363,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeWorkforceRequest,
     ) -> Result<DescribeWorkforceResponse, RusotoError<DescribeWorkforceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkforce");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15007,6 +15384,9 @@ impl SageMaker for SageMakerClient {
         input: DescribeWorkteamRequest,
     ) -> Result<DescribeWorkteamResponse, RusotoError<DescribeWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkteam");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.DescribeWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15026,6 +15406,9 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DisassociateTrialComponentResponse, RusotoError<DisassociateTrialComponentError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateTrialComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.DisassociateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15045,6 +15428,9 @@ impl SageMaker for SageMakerClient {
         input: GetSearchSuggestionsRequest,
     ) -> Result<GetSearchSuggestionsResponse, RusotoError<GetSearchSuggestionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSearchSuggestions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.GetSearchSuggestions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15064,6 +15450,9 @@ impl SageMaker for SageMakerClient {
         input: ListAlgorithmsInput,
     ) -> Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAlgorithms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListAlgorithms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15082,6 +15471,9 @@ impl SageMaker for SageMakerClient {
         input: ListAppsRequest,
     ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListApps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15100,6 +15492,9 @@ impl SageMaker for SageMakerClient {
         input: ListAutoMLJobsRequest,
     ) -> Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAutoMLJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListAutoMLJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15119,6 +15514,9 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListCandidatesForAutoMLJobResponse, RusotoError<ListCandidatesForAutoMLJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCandidatesForAutoMLJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListCandidatesForAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15138,6 +15536,9 @@ impl SageMaker for SageMakerClient {
         input: ListCodeRepositoriesInput,
     ) -> Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCodeRepositories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListCodeRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15156,6 +15557,9 @@ impl SageMaker for SageMakerClient {
         input: ListCompilationJobsRequest,
     ) -> Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCompilationJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListCompilationJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15174,6 +15578,9 @@ impl SageMaker for SageMakerClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15192,6 +15599,9 @@ impl SageMaker for SageMakerClient {
         input: ListEndpointConfigsInput,
     ) -> Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEndpointConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListEndpointConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15210,6 +15620,9 @@ impl SageMaker for SageMakerClient {
         input: ListEndpointsInput,
     ) -> Result<ListEndpointsOutput, RusotoError<ListEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListEndpoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15228,6 +15641,9 @@ impl SageMaker for SageMakerClient {
         input: ListExperimentsRequest,
     ) -> Result<ListExperimentsResponse, RusotoError<ListExperimentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListExperiments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListExperiments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15246,6 +15662,9 @@ impl SageMaker for SageMakerClient {
         input: ListFlowDefinitionsRequest,
     ) -> Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFlowDefinitions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListFlowDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15264,6 +15683,9 @@ impl SageMaker for SageMakerClient {
         input: ListHumanTaskUisRequest,
     ) -> Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHumanTaskUis");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListHumanTaskUis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15283,6 +15705,9 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListHyperParameterTuningJobsResponse, RusotoError<ListHyperParameterTuningJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListHyperParameterTuningJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListHyperParameterTuningJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15302,6 +15727,9 @@ impl SageMaker for SageMakerClient {
         input: ListLabelingJobsRequest,
     ) -> Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLabelingJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListLabelingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15321,6 +15749,9 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListLabelingJobsForWorkteamResponse, RusotoError<ListLabelingJobsForWorkteamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLabelingJobsForWorkteam");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListLabelingJobsForWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15340,6 +15771,9 @@ impl SageMaker for SageMakerClient {
         input: ListModelPackagesInput,
     ) -> Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListModelPackages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListModelPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15358,6 +15792,9 @@ impl SageMaker for SageMakerClient {
         input: ListModelsInput,
     ) -> Result<ListModelsOutput, RusotoError<ListModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListModels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15376,6 +15813,9 @@ impl SageMaker for SageMakerClient {
         input: ListMonitoringExecutionsRequest,
     ) -> Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMonitoringExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListMonitoringExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15395,6 +15835,9 @@ impl SageMaker for SageMakerClient {
         input: ListMonitoringSchedulesRequest,
     ) -> Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMonitoringSchedules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListMonitoringSchedules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15417,6 +15860,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListNotebookInstanceLifecycleConfigsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListNotebookInstanceLifecycleConfigs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SageMaker.ListNotebookInstanceLifecycleConfigs",
@@ -15442,6 +15888,9 @@ impl SageMaker for SageMakerClient {
         input: ListNotebookInstancesInput,
     ) -> Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListNotebookInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListNotebookInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15460,6 +15909,9 @@ impl SageMaker for SageMakerClient {
         input: ListProcessingJobsRequest,
     ) -> Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProcessingJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListProcessingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15478,6 +15930,9 @@ impl SageMaker for SageMakerClient {
         input: ListSubscribedWorkteamsRequest,
     ) -> Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSubscribedWorkteams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListSubscribedWorkteams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15497,6 +15952,9 @@ impl SageMaker for SageMakerClient {
         input: ListTagsInput,
     ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15515,6 +15973,9 @@ impl SageMaker for SageMakerClient {
         input: ListTrainingJobsRequest,
     ) -> Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTrainingJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListTrainingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15536,6 +15997,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTrainingJobsForHyperParameterTuningJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SageMaker.ListTrainingJobsForHyperParameterTuningJob",
@@ -15561,6 +16025,9 @@ impl SageMaker for SageMakerClient {
         input: ListTransformJobsRequest,
     ) -> Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTransformJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListTransformJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15579,6 +16046,9 @@ impl SageMaker for SageMakerClient {
         input: ListTrialComponentsRequest,
     ) -> Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTrialComponents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListTrialComponents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15597,6 +16067,9 @@ impl SageMaker for SageMakerClient {
         input: ListTrialsRequest,
     ) -> Result<ListTrialsResponse, RusotoError<ListTrialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTrials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListTrials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15615,6 +16088,9 @@ impl SageMaker for SageMakerClient {
         input: ListUserProfilesRequest,
     ) -> Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUserProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15633,6 +16109,9 @@ impl SageMaker for SageMakerClient {
         input: ListWorkteamsRequest,
     ) -> Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWorkteams");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.ListWorkteams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15651,6 +16130,9 @@ impl SageMaker for SageMakerClient {
         input: RenderUiTemplateRequest,
     ) -> Result<RenderUiTemplateResponse, RusotoError<RenderUiTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RenderUiTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.RenderUiTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15669,6 +16151,9 @@ impl SageMaker for SageMakerClient {
         input: SearchRequest,
     ) -> Result<SearchResponse, RusotoError<SearchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("Search");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SageMaker.Search");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15687,6 +16172,9 @@ impl SageMaker for SageMakerClient {
         input: StartMonitoringScheduleRequest,
     ) -> Result<(), RusotoError<StartMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMonitoringSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StartMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15704,6 +16192,9 @@ impl SageMaker for SageMakerClient {
         input: StartNotebookInstanceInput,
     ) -> Result<(), RusotoError<StartNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartNotebookInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StartNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15721,6 +16212,9 @@ impl SageMaker for SageMakerClient {
         input: StopAutoMLJobRequest,
     ) -> Result<(), RusotoError<StopAutoMLJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopAutoMLJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15738,6 +16232,9 @@ impl SageMaker for SageMakerClient {
         input: StopCompilationJobRequest,
     ) -> Result<(), RusotoError<StopCompilationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopCompilationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15755,6 +16252,9 @@ impl SageMaker for SageMakerClient {
         input: StopHyperParameterTuningJobRequest,
     ) -> Result<(), RusotoError<StopHyperParameterTuningJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopHyperParameterTuningJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15772,6 +16272,9 @@ impl SageMaker for SageMakerClient {
         input: StopLabelingJobRequest,
     ) -> Result<(), RusotoError<StopLabelingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopLabelingJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15789,6 +16292,9 @@ impl SageMaker for SageMakerClient {
         input: StopMonitoringScheduleRequest,
     ) -> Result<(), RusotoError<StopMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopMonitoringSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15806,6 +16312,9 @@ impl SageMaker for SageMakerClient {
         input: StopNotebookInstanceInput,
     ) -> Result<(), RusotoError<StopNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopNotebookInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15823,6 +16332,9 @@ impl SageMaker for SageMakerClient {
         input: StopProcessingJobRequest,
     ) -> Result<(), RusotoError<StopProcessingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopProcessingJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15840,6 +16352,9 @@ impl SageMaker for SageMakerClient {
         input: StopTrainingJobRequest,
     ) -> Result<(), RusotoError<StopTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTrainingJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15857,6 +16372,9 @@ impl SageMaker for SageMakerClient {
         input: StopTransformJobRequest,
     ) -> Result<(), RusotoError<StopTransformJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTransformJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.StopTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15874,6 +16392,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateCodeRepositoryInput,
     ) -> Result<UpdateCodeRepositoryOutput, RusotoError<UpdateCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCodeRepository");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15892,6 +16413,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateDomainRequest,
     ) -> Result<UpdateDomainResponse, RusotoError<UpdateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15910,6 +16434,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateEndpointInput,
     ) -> Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15931,6 +16458,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEndpointWeightsAndCapacities");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SageMaker.UpdateEndpointWeightsAndCapacities",
@@ -15956,6 +16486,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateExperimentRequest,
     ) -> Result<UpdateExperimentResponse, RusotoError<UpdateExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateExperiment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15974,6 +16507,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateMonitoringScheduleRequest,
     ) -> Result<UpdateMonitoringScheduleResponse, RusotoError<UpdateMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMonitoringSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15993,6 +16529,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateNotebookInstanceInput,
     ) -> Result<UpdateNotebookInstanceOutput, RusotoError<UpdateNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNotebookInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16015,6 +16554,9 @@ impl SageMaker for SageMakerClient {
         RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNotebookInstanceLifecycleConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SageMaker.UpdateNotebookInstanceLifecycleConfig",
@@ -16040,6 +16582,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateTrialRequest,
     ) -> Result<UpdateTrialResponse, RusotoError<UpdateTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTrial");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16058,6 +16603,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateTrialComponentRequest,
     ) -> Result<UpdateTrialComponentResponse, RusotoError<UpdateTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTrialComponent");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16077,6 +16625,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateUserProfileRequest,
     ) -> Result<UpdateUserProfileResponse, RusotoError<UpdateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUserProfile");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16095,6 +16646,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateWorkforceRequest,
     ) -> Result<UpdateWorkforceResponse, RusotoError<UpdateWorkforceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateWorkforce");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16113,6 +16667,9 @@ impl SageMaker for SageMakerClient {
         input: UpdateWorkteamRequest,
     ) -> Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateWorkteam");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SageMaker.UpdateWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16125,3 +16682,1506 @@ impl SageMaker for SageMakerClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkteamResponse, _>()
     }
 }
+
+/// A blocking client for the SageMaker API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SageMakerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SageMakerBlockingClient {
+    client: SageMakerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SageMakerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SageMakerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SageMakerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SageMakerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SageMakerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SageMakerBlockingClient {
+        SageMakerBlockingClient {
+            client: SageMakerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Adds or overwrites one or more tags for the specified Amazon SageMaker resource. You can add tags to notebook instances, training jobs, hyperparameter tuning jobs, batch transform jobs, models, labeling jobs, work teams, endpoint configurations, and endpoints.</p> <p>Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see For more information, see <a href="https://aws.amazon.com/answers/account-management/aws-tagging-strategies/">AWS Tagging Strategies</a>.</p> <note> <p>Tags that you add to a hyperparameter tuning job by calling this API are also added to any training jobs that the hyperparameter tuning job launches after you call this API, but not to training jobs that the hyperparameter tuning job launched before you called this API. To make sure that the tags associated with a hyperparameter tuning job are also added to all training jobs that the hyperparameter tuning job launches, add the tags when you first create the tuning job by specifying them in the <code>Tags</code> parameter of <a>CreateHyperParameterTuningJob</a> </p> </note></p>
+    ///
+    /// See `SageMaker::add_tags`.
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// <p>Associates a trial component with a trial. A trial component can be associated with multiple trials. To disassociate a trial component from a trial, call the <a>DisassociateTrialComponent</a> API.</p>
+    ///
+    /// See `SageMaker::associate_trial_component`.
+    pub fn associate_trial_component(
+        &self,
+        input: AssociateTrialComponentRequest,
+    ) -> Result<AssociateTrialComponentResponse, RusotoError<AssociateTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.associate_trial_component(input))
+    }
+
+    /// <p>Create a machine learning algorithm that you can use in Amazon SageMaker and list in the AWS Marketplace.</p>
+    ///
+    /// See `SageMaker::create_algorithm`.
+    pub fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>> {
+        self.runtime.block_on(self.client.create_algorithm(input))
+    }
+
+    /// <p>Creates a running App for the specified UserProfile. Supported Apps are JupyterServer and KernelGateway. This operation is automatically invoked by Amazon SageMaker Studio upon access to the associated Domain, and when new kernel configurations are selected by the user. A user may have multiple Apps active simultaneously.</p>
+    ///
+    /// See `SageMaker::create_app`.
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// <p>Creates an AutoPilot job.</p> <p>After you run an AutoPilot job, you can find the best performing model by calling , and then deploy that model by following the steps described in <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1-deploy-model.html">Step 6.1: Deploy the Model to Amazon SageMaker Hosting Services</a>.</p> <p>For information about how to use AutoPilot, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/autopilot-automate-model-development.html">Use AutoPilot to Automate Model Development</a>.</p>
+    ///
+    /// See `SageMaker::create_auto_ml_job`.
+    pub fn create_auto_ml_job(
+        &self,
+        input: CreateAutoMLJobRequest,
+    ) -> Result<CreateAutoMLJobResponse, RusotoError<CreateAutoMLJobError>> {
+        self.runtime.block_on(self.client.create_auto_ml_job(input))
+    }
+
+    /// <p>Creates a Git repository as a resource in your Amazon SageMaker account. You can associate the repository with notebook instances so that you can use Git source control for the notebooks you create. The Git repository is a resource in your Amazon SageMaker account, so it can be associated with more than one notebook instance, and it persists independently from the lifecycle of any notebook instances it is associated with.</p> <p>The repository can be hosted either in <a href="https://docs.aws.amazon.com/codecommit/latest/userguide/welcome.html">AWS CodeCommit</a> or in any other Git repository.</p>
+    ///
+    /// See `SageMaker::create_code_repository`.
+    pub fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> Result<CreateCodeRepositoryOutput, RusotoError<CreateCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.create_code_repository(input))
+    }
+
+    /// <p>Starts a model compilation job. After the model has been compiled, Amazon SageMaker saves the resulting model artifacts to an Amazon Simple Storage Service (Amazon S3) bucket that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts with AWS IoT Greengrass. In that case, deploy them as an ML resource.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p>A name for the compilation job</p> </li> <li> <p> Information about the input model artifacts </p> </li> <li> <p>The output location for the compiled model and the device (target) that the model runs on </p> </li> <li> <p> <code>The Amazon Resource Name (ARN) of the IAM role that Amazon SageMaker assumes to perform the model compilation job</code> </p> </li> </ul> <p>You can also provide a <code>Tag</code> to track the model compilation job's resource use and costs. The response body contains the <code>CompilationJobArn</code> for the compiled job.</p> <p>To stop a model compilation job, use <a>StopCompilationJob</a>. To get information about a particular model compilation job, use <a>DescribeCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
+    ///
+    /// See `SageMaker::create_compilation_job`.
+    pub fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> Result<CreateCompilationJobResponse, RusotoError<CreateCompilationJobError>> {
+        self.runtime
+            .block_on(self.client.create_compilation_job(input))
+    }
+
+    /// <p><p>Creates a <code>Domain</code> used by SageMaker Studio. A domain consists of an associated directory, a list of authorized users, and a variety of security, application, policy, and Amazon Virtual Private Cloud (VPC) configurations. An AWS account is limited to one domain per region. Users within a domain can share notebook files and other artifacts with each other.</p> <p>When a domain is created, an Amazon Elastic File System (EFS) volume is also created for use by all of the users within the domain. Each user receives a private home directory within the EFS for notebooks, Git repositories, and data files.</p> <p>All traffic between the domain and the EFS volume is communicated through the specified subnet IDs. All other traffic goes over the Internet through an Amazon SageMaker system VPC. The EFS traffic uses the NFS/TCP protocol over port 2049.</p> <important> <p>NFS traffic over TCP on port 2049 needs to be allowed in both inbound and outbound rules in order to launch a SageMaker Studio app successfully.</p> </important></p>
+    ///
+    /// See `SageMaker::create_domain`.
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// <p>Creates an endpoint using the endpoint configuration specified in the request. Amazon SageMaker uses the endpoint to provision resources and deploy models. You create the endpoint configuration with the <a>CreateEndpointConfig</a> API. </p> <p> Use this API to deploy models using Amazon SageMaker hosting services. </p> <p>For an example that calls this method when deploying a model to Amazon SageMaker hosting services, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1-deploy-model.html#ex1-deploy-model-boto">Deploy the Model to Amazon SageMaker Hosting Services (AWS SDK for Python (Boto 3)).</a> </p> <note> <p> You must not delete an <code>EndpointConfig</code> that is in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> </note> <p>The endpoint name must be unique within an AWS Region in your AWS account. </p> <p>When it receives the request, Amazon SageMaker creates the endpoint, launches the resources (ML compute instances), and deploys the model(s) on them. </p> <note> <p>When you call <a>CreateEndpoint</a>, a load call is made to DynamoDB to verify that your endpoint configuration exists. When you read data from a DynamoDB table supporting <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadConsistency.html"> <code>Eventually Consistent Reads</code> </a>, the response might not reflect the results of a recently completed write operation. The response might include some stale data. If the dependent entities are not yet in DynamoDB, this causes a validation error. If you repeat your read request after a short time, the response should return the latest data. So retry logic is recommended to handle these possible issues. We also recommend that customers call <a>DescribeEndpointConfig</a> before calling <a>CreateEndpoint</a> to minimize the potential impact of a DynamoDB eventually consistent read.</p> </note> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Creating</code>. After it creates the endpoint, it sets the status to <code>InService</code>. Amazon SageMaker can then process incoming requests for inferences. To check the status of an endpoint, use the <a>DescribeEndpoint</a> API.</p> <p>If any of the models hosted at this endpoint get model data from an Amazon S3 location, Amazon SageMaker uses AWS Security Token Service to download model artifacts from the S3 path you provided. AWS STS is activated in your IAM user account by default. If you previously deactivated AWS STS for a region, you need to reactivate AWS STS for that region. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p>
+    ///
+    /// See `SageMaker::create_endpoint`.
+    pub fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> Result<CreateEndpointOutput, RusotoError<CreateEndpointError>> {
+        self.runtime.block_on(self.client.create_endpoint(input))
+    }
+
+    /// <p><p>Creates an endpoint configuration that Amazon SageMaker hosting services uses to deploy models. In the configuration, you identify one or more models, created using the <code>CreateModel</code> API, to deploy and the resources that you want Amazon SageMaker to provision. Then you call the <a>CreateEndpoint</a> API.</p> <note> <p> Use this API if you want to use Amazon SageMaker hosting services to deploy models into production. </p> </note> <p>In the request, you define a <code>ProductionVariant</code>, for each model that you want to deploy. Each <code>ProductionVariant</code> parameter also describes the resources that you want Amazon SageMaker to provision. This includes the number and type of ML compute instances to deploy. </p> <p>If you are hosting multiple models, you also assign a <code>VariantWeight</code> to specify how much traffic you want to allocate to each model. For example, suppose that you want to host two models, A and B, and you assign traffic weight 2 for model A and 1 for model B. Amazon SageMaker distributes two-thirds of the traffic to Model A, and one-third to model B. </p> <p>For an example that calls this method when deploying a model to Amazon SageMaker hosting services, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1-deploy-model.html#ex1-deploy-model-boto">Deploy the Model to Amazon SageMaker Hosting Services (AWS SDK for Python (Boto 3)).</a> </p> <note> <p>When you call <a>CreateEndpoint</a>, a load call is made to DynamoDB to verify that your endpoint configuration exists. When you read data from a DynamoDB table supporting <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.ReadConsistency.html"> <code>Eventually Consistent Reads</code> </a>, the response might not reflect the results of a recently completed write operation. The response might include some stale data. If the dependent entities are not yet in DynamoDB, this causes a validation error. If you repeat your read request after a short time, the response should return the latest data. So retry logic is recommended to handle these possible issues. We also recommend that customers call <a>DescribeEndpointConfig</a> before calling <a>CreateEndpoint</a> to minimize the potential impact of a DynamoDB eventually consistent read.</p> </note></p>
+    ///
+    /// See `SageMaker::create_endpoint_config`.
+    pub fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> Result<CreateEndpointConfigOutput, RusotoError<CreateEndpointConfigError>> {
+        self.runtime
+            .block_on(self.client.create_endpoint_config(input))
+    }
+
+    /// <p>Creates an Amazon SageMaker <i>experiment</i>. An experiment is a collection of <i>trials</i> that are observed, compared and evaluated as a group. A trial is a set of steps, called <i>trial components</i>, that produce a machine learning model.</p> <p>The goal of an experiment is to determine the components that produce the best model. Multiple trials are performed, each one isolating and measuring the impact of a change to one or more inputs, while keeping the remaining inputs constant.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to experiments, trials, trial components and then use the <a>Search</a> API to search for the tags.</p> <p>To add a description to an experiment, specify the optional <code>Description</code> parameter. To add a description later, or to change the description, call the <a>UpdateExperiment</a> API.</p> <p>To get a list of all your experiments, call the <a>ListExperiments</a> API. To view an experiment's properties, call the <a>DescribeExperiment</a> API. To get a list of all the trials associated with an experiment, call the <a>ListTrials</a> API. To create a trial call the <a>CreateTrial</a> API.</p>
+    ///
+    /// See `SageMaker::create_experiment`.
+    pub fn create_experiment(
+        &self,
+        input: CreateExperimentRequest,
+    ) -> Result<CreateExperimentResponse, RusotoError<CreateExperimentError>> {
+        self.runtime.block_on(self.client.create_experiment(input))
+    }
+
+    /// <p>Creates a flow definition.</p>
+    ///
+    /// See `SageMaker::create_flow_definition`.
+    pub fn create_flow_definition(
+        &self,
+        input: CreateFlowDefinitionRequest,
+    ) -> Result<CreateFlowDefinitionResponse, RusotoError<CreateFlowDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_flow_definition(input))
+    }
+
+    /// <p>Defines the settings you will use for the human review workflow user interface. Reviewers will see a three-panel interface with an instruction area, the item to review, and an input area.</p>
+    ///
+    /// See `SageMaker::create_human_task_ui`.
+    pub fn create_human_task_ui(
+        &self,
+        input: CreateHumanTaskUiRequest,
+    ) -> Result<CreateHumanTaskUiResponse, RusotoError<CreateHumanTaskUiError>> {
+        self.runtime
+            .block_on(self.client.create_human_task_ui(input))
+    }
+
+    /// <p>Starts a hyperparameter tuning job. A hyperparameter tuning job finds the best version of a model by running many training jobs on your dataset using the algorithm you choose and values for hyperparameters within ranges that you specify. It then chooses the hyperparameter values that result in a model that performs the best, as measured by an objective metric that you choose.</p>
+    ///
+    /// See `SageMaker::create_hyper_parameter_tuning_job`.
+    pub fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> Result<
+        CreateHyperParameterTuningJobResponse,
+        RusotoError<CreateHyperParameterTuningJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_hyper_parameter_tuning_job(input))
+    }
+
+    /// <p>Creates a job that uses workers to label the data objects in your input dataset. You can use the labeled data to train machine learning models.</p> <p>You can select your workforce from one of three providers:</p> <ul> <li> <p>A private workforce that you create. It can include employees, contractors, and outside experts. Use a private workforce when want the data to stay within your organization or when a specific set of skills is required.</p> </li> <li> <p>One or more vendors that you select from the AWS Marketplace. Vendors provide expertise in specific areas. </p> </li> <li> <p>The Amazon Mechanical Turk workforce. This is the largest workforce, but it should only be used for public data or data that has been stripped of any personally identifiable information.</p> </li> </ul> <p>You can also use <i>automated data labeling</i> to reduce the number of data objects that need to be labeled by a human. Automated data labeling uses <i>active learning</i> to determine if a data object can be labeled by machine or if it needs to be sent to a human worker. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-automated-labeling.html">Using Automated Data Labeling</a>.</p> <p>The data objects to be labeled are contained in an Amazon S3 bucket. You create a <i>manifest file</i> that describes the location of each object. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/sms-data.html">Using Input and Output Data</a>.</p> <p>The output can be used as the manifest file for another labeling job or as training data for your machine learning models.</p>
+    ///
+    /// See `SageMaker::create_labeling_job`.
+    pub fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> Result<CreateLabelingJobResponse, RusotoError<CreateLabelingJobError>> {
+        self.runtime
+            .block_on(self.client.create_labeling_job(input))
+    }
+
+    /// <p>Creates a model in Amazon SageMaker. In the request, you name the model and describe a primary container. For the primary container, you specify the Docker image that contains inference code, artifacts (from prior training), and a custom environment map that the inference code uses when you deploy the model for predictions.</p> <p>Use this API to create a model if you want to use Amazon SageMaker hosting services or run a batch transform job.</p> <p>To host your model, you create an endpoint configuration with the <code>CreateEndpointConfig</code> API, and then create an endpoint with the <code>CreateEndpoint</code> API. Amazon SageMaker then deploys all of the containers that you defined for the model in the hosting environment. </p> <p>For an example that calls this method when deploying a model to Amazon SageMaker hosting services, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/ex1-deploy-model.html#ex1-deploy-model-boto">Deploy the Model to Amazon SageMaker Hosting Services (AWS SDK for Python (Boto 3)).</a> </p> <p>To run a batch transform using your model, you start a job with the <code>CreateTransformJob</code> API. Amazon SageMaker uses your model and your dataset to get inferences which are then saved to a specified S3 location.</p> <p>In the <code>CreateModel</code> request, you must define a container with the <code>PrimaryContainer</code> parameter.</p> <p>In the request, you also provide an IAM role that Amazon SageMaker can assume to access model artifacts and docker image for deployment on ML compute hosting instances or for batch transform jobs. In addition, you also use the IAM role to manage permissions the inference code needs. For example, if the inference code access any other AWS resources, you grant necessary permissions via this role.</p>
+    ///
+    /// See `SageMaker::create_model`.
+    pub fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> Result<CreateModelOutput, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// <p>Creates a model package that you can use to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p> <p>To create a model package by specifying a Docker container that contains your inference code and the Amazon S3 location of your model artifacts, provide values for <code>InferenceSpecification</code>. To create a model from an algorithm resource that you created or subscribed to in AWS Marketplace, provide a value for <code>SourceAlgorithmSpecification</code>.</p>
+    ///
+    /// See `SageMaker::create_model_package`.
+    pub fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
+        self.runtime
+            .block_on(self.client.create_model_package(input))
+    }
+
+    /// <p>Creates a schedule that regularly starts Amazon SageMaker Processing Jobs to monitor the data captured for an Amazon SageMaker Endoint.</p>
+    ///
+    /// See `SageMaker::create_monitoring_schedule`.
+    pub fn create_monitoring_schedule(
+        &self,
+        input: CreateMonitoringScheduleRequest,
+    ) -> Result<CreateMonitoringScheduleResponse, RusotoError<CreateMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.create_monitoring_schedule(input))
+    }
+
+    /// <p>Creates an Amazon SageMaker notebook instance. A notebook instance is a machine learning (ML) compute instance running on a Jupyter notebook. </p> <p>In a <code>CreateNotebookInstance</code> request, specify the type of ML compute instance that you want to run. Amazon SageMaker launches the instance, installs common libraries that you can use to explore datasets for model training, and attaches an ML storage volume to the notebook instance. </p> <p>Amazon SageMaker also provides a set of example notebooks. Each notebook demonstrates how to use Amazon SageMaker with a specific algorithm or with a machine learning framework. </p> <p>After receiving the request, Amazon SageMaker does the following:</p> <ol> <li> <p>Creates a network interface in the Amazon SageMaker VPC.</p> </li> <li> <p>(Option) If you specified <code>SubnetId</code>, Amazon SageMaker creates a network interface in your own VPC, which is inferred from the subnet ID that you provide in the input. When creating this network interface, Amazon SageMaker attaches the security group that you specified in the request to the network interface that it creates in your VPC.</p> </li> <li> <p>Launches an EC2 instance of the type specified in the request in the Amazon SageMaker VPC. If you specified <code>SubnetId</code> of your VPC, Amazon SageMaker specifies both network interfaces when launching this instance. This enables inbound traffic from your own VPC to the notebook instance, assuming that the security groups allow it.</p> </li> </ol> <p>After creating the notebook instance, Amazon SageMaker returns its Amazon Resource Name (ARN). You can't change the name of a notebook instance after you create it.</p> <p>After Amazon SageMaker creates the notebook instance, you can connect to the Jupyter server and work in Jupyter notebooks. For example, you can write code to explore a dataset that you can use for model training, train a model, host models by creating Amazon SageMaker endpoints, and validate hosted models. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
+    ///
+    /// See `SageMaker::create_notebook_instance`.
+    pub fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> Result<CreateNotebookInstanceOutput, RusotoError<CreateNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.create_notebook_instance(input))
+    }
+
+    /// <p>Creates a lifecycle configuration that you can associate with a notebook instance. A <i>lifecycle configuration</i> is a collection of shell scripts that run when you create or start a notebook instance.</p> <p>Each lifecycle configuration script has a limit of 16384 characters.</p> <p>The value of the <code>$PATH</code> environment variable that is available to both scripts is <code>/sbin:bin:/usr/sbin:/usr/bin</code>.</p> <p>View CloudWatch Logs for notebook instance lifecycle configurations in log group <code>/aws/sagemaker/NotebookInstances</code> in log stream <code>[notebook-instance-name]/[LifecycleConfigHook]</code>.</p> <p>Lifecycle configuration scripts cannot run for longer than 5 minutes. If a script runs for longer than 5 minutes, it fails and the notebook instance is not created or started.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
+    ///
+    /// See `SageMaker::create_notebook_instance_lifecycle_config`.
+    pub fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        RusotoError<CreateNotebookInstanceLifecycleConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_notebook_instance_lifecycle_config(input))
+    }
+
+    /// <p>Creates a URL for a specified UserProfile in a Domain. When accessed in a web browser, the user will be automatically signed in to Amazon SageMaker Studio, and granted access to all of the Apps and files associated with the Domain's Amazon Elastic File System (EFS) volume. This operation can only be called when the authentication mode equals IAM. </p>
+    ///
+    /// See `SageMaker::create_presigned_domain_url`.
+    pub fn create_presigned_domain_url(
+        &self,
+        input: CreatePresignedDomainUrlRequest,
+    ) -> Result<CreatePresignedDomainUrlResponse, RusotoError<CreatePresignedDomainUrlError>> {
+        self.runtime
+            .block_on(self.client.create_presigned_domain_url(input))
+    }
+
+    /// <p><p>Returns a URL that you can use to connect to the Jupyter server from a notebook instance. In the Amazon SageMaker console, when you choose <code>Open</code> next to a notebook instance, Amazon SageMaker opens a new tab showing the Jupyter server home page from the notebook instance. The console uses this API to get the URL and show the page.</p> <p> The IAM role or user used to call this API defines the permissions to access the notebook instance. Once the presigned URL is created, no additional permission is required to access this URL. IAM authorization policies for this API are also enforced for every HTTP request and WebSocket frame that attempts to connect to the notebook instance.</p> <p>You can restrict access to this API and to the URL that it returns to a list of IP addresses that you specify. Use the <code>NotIpAddress</code> condition operator and the <code>aws:SourceIP</code> condition context key to specify the list of IP addresses that you want to have access to the notebook instance. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/security_iam_id-based-policy-examples.html#nbi-ip-filter">Limit Access to a Notebook Instance by IP Address</a>.</p> <note> <p>The URL that you get from a call to <a>CreatePresignedNotebookInstanceUrl</a> is valid only for 5 minutes. If you try to use the URL after the 5-minute limit expires, you are directed to the AWS console sign-in page.</p> </note></p>
+    ///
+    /// See `SageMaker::create_presigned_notebook_instance_url`.
+    pub fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> Result<
+        CreatePresignedNotebookInstanceUrlOutput,
+        RusotoError<CreatePresignedNotebookInstanceUrlError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_presigned_notebook_instance_url(input))
+    }
+
+    /// <p>Creates a processing job.</p>
+    ///
+    /// See `SageMaker::create_processing_job`.
+    pub fn create_processing_job(
+        &self,
+        input: CreateProcessingJobRequest,
+    ) -> Result<CreateProcessingJobResponse, RusotoError<CreateProcessingJobError>> {
+        self.runtime
+            .block_on(self.client.create_processing_job(input))
+    }
+
+    /// <p>Starts a model training job. After training completes, Amazon SageMaker saves the resulting model artifacts to an Amazon S3 location that you specify. </p> <p>If you choose to host your model using Amazon SageMaker hosting services, you can use the resulting model artifacts as part of the model. You can also use the artifacts in a machine learning service other than Amazon SageMaker, provided that you know how to use them for inferences. </p> <p>In the request body, you provide the following: </p> <ul> <li> <p> <code>AlgorithmSpecification</code> - Identifies the training algorithm to use. </p> </li> <li> <p> <code>HyperParameters</code> - Specify these algorithm-specific parameters to enable the estimation of model parameters during training. Hyperparameters can be tuned to optimize this learning process. For a list of hyperparameters for each training algorithm provided by Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/algos.html">Algorithms</a>. </p> </li> <li> <p> <code>InputDataConfig</code> - Describes the training dataset and the Amazon S3, EFS, or FSx location where it is stored.</p> </li> <li> <p> <code>OutputDataConfig</code> - Identifies the Amazon S3 bucket where you want Amazon SageMaker to save the results of model training. </p> <p/> </li> <li> <p> <code>ResourceConfig</code> - Identifies the resources, ML compute instances, and ML storage volumes to deploy for model training. In distributed training, you specify more than one instance. </p> </li> <li> <p> <code>EnableManagedSpotTraining</code> - Optimize the cost of training machine learning models by up to 80% by using Amazon EC2 Spot instances. For more information, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/model-managed-spot-training.html">Managed Spot Training</a>. </p> </li> <li> <p> <code>RoleARN</code> - The Amazon Resource Number (ARN) that Amazon SageMaker assumes to perform tasks on your behalf during model training. You must grant this role the necessary permissions so that Amazon SageMaker can successfully complete model training. </p> </li> <li> <p> <code>StoppingCondition</code> - To help cap training costs, use <code>MaxRuntimeInSeconds</code> to set a time limit for training. Use <code>MaxWaitTimeInSeconds</code> to specify how long you are willing to wait for a managed spot training job to complete. </p> </li> </ul> <p> For more information about Amazon SageMaker, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/how-it-works.html">How It Works</a>. </p>
+    ///
+    /// See `SageMaker::create_training_job`.
+    pub fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> Result<CreateTrainingJobResponse, RusotoError<CreateTrainingJobError>> {
+        self.runtime
+            .block_on(self.client.create_training_job(input))
+    }
+
+    /// <p>Starts a transform job. A transform job uses a trained model to get inferences on a dataset and saves these results to an Amazon S3 location that you specify.</p> <p>To perform batch transformations, you create a transform job and use the data that you have readily available.</p> <p>In the request body, you provide the following:</p> <ul> <li> <p> <code>TransformJobName</code> - Identifies the transform job. The name must be unique within an AWS Region in an AWS account.</p> </li> <li> <p> <code>ModelName</code> - Identifies the model to use. <code>ModelName</code> must be the name of an existing Amazon SageMaker model in the same AWS Region and AWS account. For information on creating a model, see <a>CreateModel</a>.</p> </li> <li> <p> <code>TransformInput</code> - Describes the dataset to be transformed and the Amazon S3 location where it is stored.</p> </li> <li> <p> <code>TransformOutput</code> - Identifies the Amazon S3 location where you want Amazon SageMaker to save the results from the transform job.</p> </li> <li> <p> <code>TransformResources</code> - Identifies the ML compute instances for the transform job.</p> </li> </ul> <p>For more information about how batch transformation works, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/batch-transform.html">Batch Transform</a>.</p>
+    ///
+    /// See `SageMaker::create_transform_job`.
+    pub fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> Result<CreateTransformJobResponse, RusotoError<CreateTransformJobError>> {
+        self.runtime
+            .block_on(self.client.create_transform_job(input))
+    }
+
+    /// <p>Creates an Amazon SageMaker <i>trial</i>. A trial is a set of steps called <i>trial components</i> that produce a machine learning model. A trial is part of a single Amazon SageMaker <i>experiment</i>.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to a trial and then use the <a>Search</a> API to search for the tags.</p> <p>To get a list of all your trials, call the <a>ListTrials</a> API. To view a trial's properties, call the <a>DescribeTrial</a> API. To create a trial component, call the <a>CreateTrialComponent</a> API.</p>
+    ///
+    /// See `SageMaker::create_trial`.
+    pub fn create_trial(
+        &self,
+        input: CreateTrialRequest,
+    ) -> Result<CreateTrialResponse, RusotoError<CreateTrialError>> {
+        self.runtime.block_on(self.client.create_trial(input))
+    }
+
+    /// <p><p>Creates a <i>trial component</i>, which is a stage of a machine learning <i>trial</i>. A trial is composed of one or more trial components. A trial component can be used in multiple trials.</p> <p>Trial components include pre-processing jobs, training jobs, and batch transform jobs.</p> <p>When you use Amazon SageMaker Studio or the Amazon SageMaker Python SDK, all experiments, trials, and trial components are automatically tracked, logged, and indexed. When you use the AWS SDK for Python (Boto), you must use the logging APIs provided by the SDK.</p> <p>You can add tags to a trial component and then use the <a>Search</a> API to search for the tags.</p> <note> <p> <code>CreateTrialComponent</code> can only be invoked from within an Amazon SageMaker managed environment. This includes Amazon SageMaker training jobs, processing jobs, transform jobs, and Amazon SageMaker notebooks. A call to <code>CreateTrialComponent</code> from outside one of these environments results in an error.</p> </note></p>
+    ///
+    /// See `SageMaker::create_trial_component`.
+    pub fn create_trial_component(
+        &self,
+        input: CreateTrialComponentRequest,
+    ) -> Result<CreateTrialComponentResponse, RusotoError<CreateTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.create_trial_component(input))
+    }
+
+    /// <p>Creates a user profile. A user profile represents a single user within a domain, and is the main way to reference a "person" for the purposes of sharing, reporting, and other user-oriented features. This entity is created when a user onboards to Amazon SageMaker Studio. If an administrator invites a person by email or imports them from SSO, a user profile is automatically created. A user profile is the primary holder of settings for an individual user and has a reference to the user's private Amazon Elastic File System (EFS) home directory. </p>
+    ///
+    /// See `SageMaker::create_user_profile`.
+    pub fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResponse, RusotoError<CreateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.create_user_profile(input))
+    }
+
+    /// <p>Creates a new work team for labeling your data. A work team is defined by one or more Amazon Cognito user pools. You must first create the user pools before you can create a work team.</p> <p>You cannot create more than 25 work teams in an account and region.</p>
+    ///
+    /// See `SageMaker::create_workteam`.
+    pub fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>> {
+        self.runtime.block_on(self.client.create_workteam(input))
+    }
+
+    /// <p>Removes the specified algorithm from your account.</p>
+    ///
+    /// See `SageMaker::delete_algorithm`.
+    pub fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> Result<(), RusotoError<DeleteAlgorithmError>> {
+        self.runtime.block_on(self.client.delete_algorithm(input))
+    }
+
+    /// <p>Used to stop and delete an app.</p>
+    ///
+    /// See `SageMaker::delete_app`.
+    pub fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// <p>Deletes the specified Git repository from your account.</p>
+    ///
+    /// See `SageMaker::delete_code_repository`.
+    pub fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> Result<(), RusotoError<DeleteCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.delete_code_repository(input))
+    }
+
+    /// <p>Used to delete a domain. If you onboarded with IAM mode, you will need to delete your domain to onboard again using SSO. Use with caution. All of the members of the domain will lose access to their EFS volume, including data, notebooks, and other artifacts. </p>
+    ///
+    /// See `SageMaker::delete_domain`.
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(), RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// <p>Deletes an endpoint. Amazon SageMaker frees up all of the resources that were deployed when the endpoint was created. </p> <p>Amazon SageMaker retires any custom KMS key grants associated with the endpoint, meaning you don't need to use the <a href="http://docs.aws.amazon.com/kms/latest/APIReference/API_RevokeGrant.html">RevokeGrant</a> API call.</p>
+    ///
+    /// See `SageMaker::delete_endpoint`.
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// <p>Deletes an endpoint configuration. The <code>DeleteEndpointConfig</code> API deletes only the specified configuration. It does not delete endpoints created using the configuration. </p> <p>You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. If you delete the <code>EndpointConfig</code> of an endpoint that is active or being created or updated you may lose visibility into the instance type the endpoint is using. The endpoint must be deleted in order to stop incurring charges.</p>
+    ///
+    /// See `SageMaker::delete_endpoint_config`.
+    pub fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> Result<(), RusotoError<DeleteEndpointConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_endpoint_config(input))
+    }
+
+    /// <p>Deletes an Amazon SageMaker experiment. All trials associated with the experiment must be deleted first. Use the <a>ListTrials</a> API to get a list of the trials associated with the experiment.</p>
+    ///
+    /// See `SageMaker::delete_experiment`.
+    pub fn delete_experiment(
+        &self,
+        input: DeleteExperimentRequest,
+    ) -> Result<DeleteExperimentResponse, RusotoError<DeleteExperimentError>> {
+        self.runtime.block_on(self.client.delete_experiment(input))
+    }
+
+    /// <p>Deletes the specified flow definition.</p>
+    ///
+    /// See `SageMaker::delete_flow_definition`.
+    pub fn delete_flow_definition(
+        &self,
+        input: DeleteFlowDefinitionRequest,
+    ) -> Result<DeleteFlowDefinitionResponse, RusotoError<DeleteFlowDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_flow_definition(input))
+    }
+
+    /// <p>Use this operation to delete a worker task template (<code>HumanTaskUi</code>).</p> <p> To see a list of human task user interfaces (work task templates) in your account, use . When you delete a worker task template, it no longer appears when you call <code>ListHumanTaskUis</code>.</p>
+    ///
+    /// See `SageMaker::delete_human_task_ui`.
+    pub fn delete_human_task_ui(
+        &self,
+        input: DeleteHumanTaskUiRequest,
+    ) -> Result<DeleteHumanTaskUiResponse, RusotoError<DeleteHumanTaskUiError>> {
+        self.runtime
+            .block_on(self.client.delete_human_task_ui(input))
+    }
+
+    /// <p>Deletes a model. The <code>DeleteModel</code> API deletes only the model entry that was created in Amazon SageMaker when you called the <a>CreateModel</a> API. It does not delete model artifacts, inference code, or the IAM role that you specified when creating the model. </p>
+    ///
+    /// See `SageMaker::delete_model`.
+    pub fn delete_model(
+        &self,
+        input: DeleteModelInput,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// <p>Deletes a model package.</p> <p>A model package is used to create Amazon SageMaker models or list on AWS Marketplace. Buyers can subscribe to model packages listed on AWS Marketplace to create models in Amazon SageMaker.</p>
+    ///
+    /// See `SageMaker::delete_model_package`.
+    pub fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> Result<(), RusotoError<DeleteModelPackageError>> {
+        self.runtime
+            .block_on(self.client.delete_model_package(input))
+    }
+
+    /// <p>Deletes a monitoring schedule. Also stops the schedule had not already been stopped. This does not delete the job execution history of the monitoring schedule. </p>
+    ///
+    /// See `SageMaker::delete_monitoring_schedule`.
+    pub fn delete_monitoring_schedule(
+        &self,
+        input: DeleteMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<DeleteMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.delete_monitoring_schedule(input))
+    }
+
+    /// <p><p> Deletes an Amazon SageMaker notebook instance. Before you can delete a notebook instance, you must call the <code>StopNotebookInstance</code> API. </p> <important> <p>When you delete a notebook instance, you lose all of your data. Amazon SageMaker removes the ML compute instance, and deletes the ML storage volume and the network interface associated with the notebook instance. </p> </important></p>
+    ///
+    /// See `SageMaker::delete_notebook_instance`.
+    pub fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> Result<(), RusotoError<DeleteNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.delete_notebook_instance(input))
+    }
+
+    /// <p>Deletes a notebook instance lifecycle configuration.</p>
+    ///
+    /// See `SageMaker::delete_notebook_instance_lifecycle_config`.
+    pub fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_notebook_instance_lifecycle_config(input))
+    }
+
+    /// <p><p>Deletes the specified tags from an Amazon SageMaker resource.</p> <p>To list a resource&#39;s tags, use the <code>ListTags</code> API. </p> <note> <p>When you call this API to delete tags from a hyperparameter tuning job, the deleted tags are not removed from training jobs that the hyperparameter tuning job launched before you called this API.</p> </note></p>
+    ///
+    /// See `SageMaker::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Deletes the specified trial. All trial components that make up the trial must be deleted first. Use the <a>DescribeTrialComponent</a> API to get the list of trial components.</p>
+    ///
+    /// See `SageMaker::delete_trial`.
+    pub fn delete_trial(
+        &self,
+        input: DeleteTrialRequest,
+    ) -> Result<DeleteTrialResponse, RusotoError<DeleteTrialError>> {
+        self.runtime.block_on(self.client.delete_trial(input))
+    }
+
+    /// <p>Deletes the specified trial component. A trial component must be disassociated from all trials before the trial component can be deleted. To disassociate a trial component from a trial, call the <a>DisassociateTrialComponent</a> API.</p>
+    ///
+    /// See `SageMaker::delete_trial_component`.
+    pub fn delete_trial_component(
+        &self,
+        input: DeleteTrialComponentRequest,
+    ) -> Result<DeleteTrialComponentResponse, RusotoError<DeleteTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.delete_trial_component(input))
+    }
+
+    /// <p>Deletes a user profile. When a user profile is deleted, the user loses access to their EFS volume, including data, notebooks, and other artifacts.</p>
+    ///
+    /// See `SageMaker::delete_user_profile`.
+    pub fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_user_profile(input))
+    }
+
+    /// <p>Deletes an existing work team. This operation can't be undone.</p>
+    ///
+    /// See `SageMaker::delete_workteam`.
+    pub fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>> {
+        self.runtime.block_on(self.client.delete_workteam(input))
+    }
+
+    /// <p>Returns a description of the specified algorithm that is in your account.</p>
+    ///
+    /// See `SageMaker::describe_algorithm`.
+    pub fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> Result<DescribeAlgorithmOutput, RusotoError<DescribeAlgorithmError>> {
+        self.runtime.block_on(self.client.describe_algorithm(input))
+    }
+
+    /// <p>Describes the app.</p>
+    ///
+    /// See `SageMaker::describe_app`.
+    pub fn describe_app(
+        &self,
+        input: DescribeAppRequest,
+    ) -> Result<DescribeAppResponse, RusotoError<DescribeAppError>> {
+        self.runtime.block_on(self.client.describe_app(input))
+    }
+
+    /// <p>Returns information about an Amazon SageMaker job.</p>
+    ///
+    /// See `SageMaker::describe_auto_ml_job`.
+    pub fn describe_auto_ml_job(
+        &self,
+        input: DescribeAutoMLJobRequest,
+    ) -> Result<DescribeAutoMLJobResponse, RusotoError<DescribeAutoMLJobError>> {
+        self.runtime
+            .block_on(self.client.describe_auto_ml_job(input))
+    }
+
+    /// <p>Gets details about the specified Git repository.</p>
+    ///
+    /// See `SageMaker::describe_code_repository`.
+    pub fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> Result<DescribeCodeRepositoryOutput, RusotoError<DescribeCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.describe_code_repository(input))
+    }
+
+    /// <p>Returns information about a model compilation job.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about multiple model compilation jobs, use <a>ListCompilationJobs</a>.</p>
+    ///
+    /// See `SageMaker::describe_compilation_job`.
+    pub fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> Result<DescribeCompilationJobResponse, RusotoError<DescribeCompilationJobError>> {
+        self.runtime
+            .block_on(self.client.describe_compilation_job(input))
+    }
+
+    /// <p>The description of the domain.</p>
+    ///
+    /// See `SageMaker::describe_domain`.
+    pub fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
+        self.runtime.block_on(self.client.describe_domain(input))
+    }
+
+    /// <p>Returns the description of an endpoint.</p>
+    ///
+    /// See `SageMaker::describe_endpoint`.
+    pub fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>> {
+        self.runtime.block_on(self.client.describe_endpoint(input))
+    }
+
+    /// <p>Returns the description of an endpoint configuration created using the <code>CreateEndpointConfig</code> API.</p>
+    ///
+    /// See `SageMaker::describe_endpoint_config`.
+    pub fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> Result<DescribeEndpointConfigOutput, RusotoError<DescribeEndpointConfigError>> {
+        self.runtime
+            .block_on(self.client.describe_endpoint_config(input))
+    }
+
+    /// <p>Provides a list of an experiment's properties.</p>
+    ///
+    /// See `SageMaker::describe_experiment`.
+    pub fn describe_experiment(
+        &self,
+        input: DescribeExperimentRequest,
+    ) -> Result<DescribeExperimentResponse, RusotoError<DescribeExperimentError>> {
+        self.runtime
+            .block_on(self.client.describe_experiment(input))
+    }
+
+    /// <p>Returns information about the specified flow definition.</p>
+    ///
+    /// See `SageMaker::describe_flow_definition`.
+    pub fn describe_flow_definition(
+        &self,
+        input: DescribeFlowDefinitionRequest,
+    ) -> Result<DescribeFlowDefinitionResponse, RusotoError<DescribeFlowDefinitionError>> {
+        self.runtime
+            .block_on(self.client.describe_flow_definition(input))
+    }
+
+    /// <p>Returns information about the requested human task user interface (worker task template).</p>
+    ///
+    /// See `SageMaker::describe_human_task_ui`.
+    pub fn describe_human_task_ui(
+        &self,
+        input: DescribeHumanTaskUiRequest,
+    ) -> Result<DescribeHumanTaskUiResponse, RusotoError<DescribeHumanTaskUiError>> {
+        self.runtime
+            .block_on(self.client.describe_human_task_ui(input))
+    }
+
+    /// <p>Gets a description of a hyperparameter tuning job.</p>
+    ///
+    /// See `SageMaker::describe_hyper_parameter_tuning_job`.
+    pub fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> Result<
+        DescribeHyperParameterTuningJobResponse,
+        RusotoError<DescribeHyperParameterTuningJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_hyper_parameter_tuning_job(input))
+    }
+
+    /// <p>Gets information about a labeling job.</p>
+    ///
+    /// See `SageMaker::describe_labeling_job`.
+    pub fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> Result<DescribeLabelingJobResponse, RusotoError<DescribeLabelingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_labeling_job(input))
+    }
+
+    /// <p>Describes a model that you created using the <code>CreateModel</code> API.</p>
+    ///
+    /// See `SageMaker::describe_model`.
+    pub fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> Result<DescribeModelOutput, RusotoError<DescribeModelError>> {
+        self.runtime.block_on(self.client.describe_model(input))
+    }
+
+    /// <p>Returns a description of the specified model package, which is used to create Amazon SageMaker models or list them on AWS Marketplace.</p> <p>To create models in Amazon SageMaker, buyers can subscribe to model packages listed on AWS Marketplace.</p>
+    ///
+    /// See `SageMaker::describe_model_package`.
+    pub fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> Result<DescribeModelPackageOutput, RusotoError<DescribeModelPackageError>> {
+        self.runtime
+            .block_on(self.client.describe_model_package(input))
+    }
+
+    /// <p>Describes the schedule for a monitoring job.</p>
+    ///
+    /// See `SageMaker::describe_monitoring_schedule`.
+    pub fn describe_monitoring_schedule(
+        &self,
+        input: DescribeMonitoringScheduleRequest,
+    ) -> Result<DescribeMonitoringScheduleResponse, RusotoError<DescribeMonitoringScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_monitoring_schedule(input))
+    }
+
+    /// <p>Returns information about a notebook instance.</p>
+    ///
+    /// See `SageMaker::describe_notebook_instance`.
+    pub fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<DescribeNotebookInstanceOutput, RusotoError<DescribeNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.describe_notebook_instance(input))
+    }
+
+    /// <p>Returns a description of a notebook instance lifecycle configuration.</p> <p>For information about notebook instance lifestyle configurations, see <a href="https://docs.aws.amazon.com/sagemaker/latest/dg/notebook-lifecycle-config.html">Step 2.1: (Optional) Customize a Notebook Instance</a>.</p>
+    ///
+    /// See `SageMaker::describe_notebook_instance_lifecycle_config`.
+    pub fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_notebook_instance_lifecycle_config(input),
+        )
+    }
+
+    /// <p>Returns a description of a processing job.</p>
+    ///
+    /// See `SageMaker::describe_processing_job`.
+    pub fn describe_processing_job(
+        &self,
+        input: DescribeProcessingJobRequest,
+    ) -> Result<DescribeProcessingJobResponse, RusotoError<DescribeProcessingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_processing_job(input))
+    }
+
+    /// <p>Gets information about a work team provided by a vendor. It returns details about the subscription with a vendor in the AWS Marketplace.</p>
+    ///
+    /// See `SageMaker::describe_subscribed_workteam`.
+    pub fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> Result<DescribeSubscribedWorkteamResponse, RusotoError<DescribeSubscribedWorkteamError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_subscribed_workteam(input))
+    }
+
+    /// <p>Returns information about a training job.</p>
+    ///
+    /// See `SageMaker::describe_training_job`.
+    pub fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> Result<DescribeTrainingJobResponse, RusotoError<DescribeTrainingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_training_job(input))
+    }
+
+    /// <p>Returns information about a transform job.</p>
+    ///
+    /// See `SageMaker::describe_transform_job`.
+    pub fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> Result<DescribeTransformJobResponse, RusotoError<DescribeTransformJobError>> {
+        self.runtime
+            .block_on(self.client.describe_transform_job(input))
+    }
+
+    /// <p>Provides a list of a trial's properties.</p>
+    ///
+    /// See `SageMaker::describe_trial`.
+    pub fn describe_trial(
+        &self,
+        input: DescribeTrialRequest,
+    ) -> Result<DescribeTrialResponse, RusotoError<DescribeTrialError>> {
+        self.runtime.block_on(self.client.describe_trial(input))
+    }
+
+    /// <p>Provides a list of a trials component's properties.</p>
+    ///
+    /// See `SageMaker::describe_trial_component`.
+    pub fn describe_trial_component(
+        &self,
+        input: DescribeTrialComponentRequest,
+    ) -> Result<DescribeTrialComponentResponse, RusotoError<DescribeTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.describe_trial_component(input))
+    }
+
+    /// <p>Describes a user profile. For more information, see <code>CreateUserProfile</code>.</p>
+    ///
+    /// See `SageMaker::describe_user_profile`.
+    pub fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<DescribeUserProfileResponse, RusotoError<DescribeUserProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_user_profile(input))
+    }
+
+    /// <p><p>Lists private workforce information, including workforce name, Amazon Resource Name (ARN), and, if applicable, allowed IP address ranges (<a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">CIDRs</a>). Allowable IP address ranges are the IP addresses that workers can use to access tasks. </p> <important> <p>This operation applies only to private workforces.</p> </important></p>
+    ///
+    /// See `SageMaker::describe_workforce`.
+    pub fn describe_workforce(
+        &self,
+        input: DescribeWorkforceRequest,
+    ) -> Result<DescribeWorkforceResponse, RusotoError<DescribeWorkforceError>> {
+        self.runtime.block_on(self.client.describe_workforce(input))
+    }
+
+    /// <p>Gets information about a specific work team. You can see information such as the create date, the last updated date, membership information, and the work team's Amazon Resource Name (ARN).</p>
+    ///
+    /// See `SageMaker::describe_workteam`.
+    pub fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> Result<DescribeWorkteamResponse, RusotoError<DescribeWorkteamError>> {
+        self.runtime.block_on(self.client.describe_workteam(input))
+    }
+
+    /// <p>Disassociates a trial component from a trial. This doesn't effect other trials the component is associated with. Before you can delete a component, you must disassociate the component from all trials it is associated with. To associate a trial component with a trial, call the <a>AssociateTrialComponent</a> API.</p> <p>To get a list of the trials a component is associated with, use the <a>Search</a> API. Specify <code>ExperimentTrialComponent</code> for the <code>Resource</code> parameter. The list appears in the response under <code>Results.TrialComponent.Parents</code>.</p>
+    ///
+    /// See `SageMaker::disassociate_trial_component`.
+    pub fn disassociate_trial_component(
+        &self,
+        input: DisassociateTrialComponentRequest,
+    ) -> Result<DisassociateTrialComponentResponse, RusotoError<DisassociateTrialComponentError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_trial_component(input))
+    }
+
+    /// <p>An auto-complete API for the search functionality in the Amazon SageMaker console. It returns suggestions of possible matches for the property name to use in <code>Search</code> queries. Provides suggestions for <code>HyperParameters</code>, <code>Tags</code>, and <code>Metrics</code>.</p>
+    ///
+    /// See `SageMaker::get_search_suggestions`.
+    pub fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> Result<GetSearchSuggestionsResponse, RusotoError<GetSearchSuggestionsError>> {
+        self.runtime
+            .block_on(self.client.get_search_suggestions(input))
+    }
+
+    /// <p>Lists the machine learning algorithms that have been created.</p>
+    ///
+    /// See `SageMaker::list_algorithms`.
+    pub fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>> {
+        self.runtime.block_on(self.client.list_algorithms(input))
+    }
+
+    /// <p>Lists apps.</p>
+    ///
+    /// See `SageMaker::list_apps`.
+    pub fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+        self.runtime.block_on(self.client.list_apps(input))
+    }
+
+    /// <p>Request a list of jobs.</p>
+    ///
+    /// See `SageMaker::list_auto_ml_jobs`.
+    pub fn list_auto_ml_jobs(
+        &self,
+        input: ListAutoMLJobsRequest,
+    ) -> Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>> {
+        self.runtime.block_on(self.client.list_auto_ml_jobs(input))
+    }
+
+    /// <p>List the Candidates created for the job.</p>
+    ///
+    /// See `SageMaker::list_candidates_for_auto_ml_job`.
+    pub fn list_candidates_for_auto_ml_job(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> Result<ListCandidatesForAutoMLJobResponse, RusotoError<ListCandidatesForAutoMLJobError>>
+    {
+        self.runtime
+            .block_on(self.client.list_candidates_for_auto_ml_job(input))
+    }
+
+    /// <p>Gets a list of the Git repositories in your account.</p>
+    ///
+    /// See `SageMaker::list_code_repositories`.
+    pub fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>> {
+        self.runtime
+            .block_on(self.client.list_code_repositories(input))
+    }
+
+    /// <p>Lists model compilation jobs that satisfy various filters.</p> <p>To create a model compilation job, use <a>CreateCompilationJob</a>. To get information about a particular model compilation job you have created, use <a>DescribeCompilationJob</a>.</p>
+    ///
+    /// See `SageMaker::list_compilation_jobs`.
+    pub fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_compilation_jobs(input))
+    }
+
+    /// <p>Lists the domains.</p>
+    ///
+    /// See `SageMaker::list_domains`.
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// <p>Lists endpoint configurations.</p>
+    ///
+    /// See `SageMaker::list_endpoint_configs`.
+    pub fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_endpoint_configs(input))
+    }
+
+    /// <p>Lists endpoints.</p>
+    ///
+    /// See `SageMaker::list_endpoints`.
+    pub fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> Result<ListEndpointsOutput, RusotoError<ListEndpointsError>> {
+        self.runtime.block_on(self.client.list_endpoints(input))
+    }
+
+    /// <p>Lists all the experiments in your account. The list can be filtered to show only experiments that were created in a specific time range. The list can be sorted by experiment name or creation time.</p>
+    ///
+    /// See `SageMaker::list_experiments`.
+    pub fn list_experiments(
+        &self,
+        input: ListExperimentsRequest,
+    ) -> Result<ListExperimentsResponse, RusotoError<ListExperimentsError>> {
+        self.runtime.block_on(self.client.list_experiments(input))
+    }
+
+    /// <p>Returns information about the flow definitions in your account.</p>
+    ///
+    /// See `SageMaker::list_flow_definitions`.
+    pub fn list_flow_definitions(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_flow_definitions(input))
+    }
+
+    /// <p>Returns information about the human task user interfaces in your account.</p>
+    ///
+    /// See `SageMaker::list_human_task_uis`.
+    pub fn list_human_task_uis(
+        &self,
+        input: ListHumanTaskUisRequest,
+    ) -> Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>> {
+        self.runtime
+            .block_on(self.client.list_human_task_uis(input))
+    }
+
+    /// <p>Gets a list of <a>HyperParameterTuningJobSummary</a> objects that describe the hyperparameter tuning jobs launched in your account.</p>
+    ///
+    /// See `SageMaker::list_hyper_parameter_tuning_jobs`.
+    pub fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> Result<ListHyperParameterTuningJobsResponse, RusotoError<ListHyperParameterTuningJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_hyper_parameter_tuning_jobs(input))
+    }
+
+    /// <p>Gets a list of labeling jobs.</p>
+    ///
+    /// See `SageMaker::list_labeling_jobs`.
+    pub fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>> {
+        self.runtime.block_on(self.client.list_labeling_jobs(input))
+    }
+
+    /// <p>Gets a list of labeling jobs assigned to a specified work team.</p>
+    ///
+    /// See `SageMaker::list_labeling_jobs_for_workteam`.
+    pub fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> Result<ListLabelingJobsForWorkteamResponse, RusotoError<ListLabelingJobsForWorkteamError>>
+    {
+        self.runtime
+            .block_on(self.client.list_labeling_jobs_for_workteam(input))
+    }
+
+    /// <p>Lists the model packages that have been created.</p>
+    ///
+    /// See `SageMaker::list_model_packages`.
+    pub fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>> {
+        self.runtime
+            .block_on(self.client.list_model_packages(input))
+    }
+
+    /// <p>Lists models created with the <a>CreateModel</a> API.</p>
+    ///
+    /// See `SageMaker::list_models`.
+    pub fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> Result<ListModelsOutput, RusotoError<ListModelsError>> {
+        self.runtime.block_on(self.client.list_models(input))
+    }
+
+    /// <p>Returns list of all monitoring job executions.</p>
+    ///
+    /// See `SageMaker::list_monitoring_executions`.
+    pub fn list_monitoring_executions(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_monitoring_executions(input))
+    }
+
+    /// <p>Returns list of all monitoring schedules.</p>
+    ///
+    /// See `SageMaker::list_monitoring_schedules`.
+    pub fn list_monitoring_schedules(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>> {
+        self.runtime
+            .block_on(self.client.list_monitoring_schedules(input))
+    }
+
+    /// <p>Lists notebook instance lifestyle configurations created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
+    ///
+    /// See `SageMaker::list_notebook_instance_lifecycle_configs`.
+    pub fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> Result<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_notebook_instance_lifecycle_configs(input))
+    }
+
+    /// <p>Returns a list of the Amazon SageMaker notebook instances in the requester's account in an AWS Region. </p>
+    ///
+    /// See `SageMaker::list_notebook_instances`.
+    pub fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_notebook_instances(input))
+    }
+
+    /// <p>Lists processing jobs that satisfy various filters.</p>
+    ///
+    /// See `SageMaker::list_processing_jobs`.
+    pub fn list_processing_jobs(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>> {
+        self.runtime
+            .block_on(self.client.list_processing_jobs(input))
+    }
+
+    /// <p>Gets a list of the work teams that you are subscribed to in the AWS Marketplace. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
+    ///
+    /// See `SageMaker::list_subscribed_workteams`.
+    pub fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>> {
+        self.runtime
+            .block_on(self.client.list_subscribed_workteams(input))
+    }
+
+    /// <p>Returns the tags for the specified Amazon SageMaker resource.</p>
+    ///
+    /// See `SageMaker::list_tags`.
+    pub fn list_tags(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// <p>Lists training jobs.</p>
+    ///
+    /// See `SageMaker::list_training_jobs`.
+    pub fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>> {
+        self.runtime.block_on(self.client.list_training_jobs(input))
+    }
+
+    /// <p>Gets a list of <a>TrainingJobSummary</a> objects that describe the training jobs that a hyperparameter tuning job launched.</p>
+    ///
+    /// See `SageMaker::list_training_jobs_for_hyper_parameter_tuning_job`.
+    pub fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> Result<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_training_jobs_for_hyper_parameter_tuning_job(input),
+        )
+    }
+
+    /// <p>Lists transform jobs.</p>
+    ///
+    /// See `SageMaker::list_transform_jobs`.
+    pub fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>> {
+        self.runtime
+            .block_on(self.client.list_transform_jobs(input))
+    }
+
+    /// <p><p>Lists the trial components in your account. You can sort the list by trial component name or creation time. You can filter the list to show only components that were created in a specific time range. You can also filter on one of the following:</p> <ul> <li> <p> <code>ExperimentName</code> </p> </li> <li> <p> <code>SourceArn</code> </p> </li> <li> <p> <code>TrialName</code> </p> </li> </ul></p>
+    ///
+    /// See `SageMaker::list_trial_components`.
+    pub fn list_trial_components(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>> {
+        self.runtime
+            .block_on(self.client.list_trial_components(input))
+    }
+
+    /// <p>Lists the trials in your account. Specify an experiment name to limit the list to the trials that are part of that experiment. Specify a trial component name to limit the list to the trials that associated with that trial component. The list can be filtered to show only trials that were created in a specific time range. The list can be sorted by trial name or creation time.</p>
+    ///
+    /// See `SageMaker::list_trials`.
+    pub fn list_trials(
+        &self,
+        input: ListTrialsRequest,
+    ) -> Result<ListTrialsResponse, RusotoError<ListTrialsError>> {
+        self.runtime.block_on(self.client.list_trials(input))
+    }
+
+    /// <p>Lists user profiles.</p>
+    ///
+    /// See `SageMaker::list_user_profiles`.
+    pub fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>> {
+        self.runtime.block_on(self.client.list_user_profiles(input))
+    }
+
+    /// <p>Gets a list of work teams that you have defined in a region. The list may be empty if no work team satisfies the filter specified in the <code>NameContains</code> parameter.</p>
+    ///
+    /// See `SageMaker::list_workteams`.
+    pub fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>> {
+        self.runtime.block_on(self.client.list_workteams(input))
+    }
+
+    /// <p>Renders the UI template so that you can preview the worker's experience. </p>
+    ///
+    /// See `SageMaker::render_ui_template`.
+    pub fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> Result<RenderUiTemplateResponse, RusotoError<RenderUiTemplateError>> {
+        self.runtime.block_on(self.client.render_ui_template(input))
+    }
+
+    /// <p>Finds Amazon SageMaker resources that match a search query. Matching resources are returned as a list of <code>SearchRecord</code> objects in the response. You can sort the search results by any resource property in a ascending or descending order.</p> <p>You can query against the following value types: numeric, text, Boolean, and timestamp.</p>
+    ///
+    /// See `SageMaker::search`.
+    pub fn search(&self, input: SearchRequest) -> Result<SearchResponse, RusotoError<SearchError>> {
+        self.runtime.block_on(self.client.search(input))
+    }
+
+    /// <p><p>Starts a previously stopped monitoring schedule.</p> <note> <p>New monitoring schedules are immediately started after creation.</p> </note></p>
+    ///
+    /// See `SageMaker::start_monitoring_schedule`.
+    pub fn start_monitoring_schedule(
+        &self,
+        input: StartMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<StartMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.start_monitoring_schedule(input))
+    }
+
+    /// <p>Launches an ML compute instance with the latest version of the libraries and attaches your ML storage volume. After configuring the notebook instance, Amazon SageMaker sets the notebook instance status to <code>InService</code>. A notebook instance's status must be <code>InService</code> before you can connect to your Jupyter notebook. </p>
+    ///
+    /// See `SageMaker::start_notebook_instance`.
+    pub fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> Result<(), RusotoError<StartNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.start_notebook_instance(input))
+    }
+
+    /// <p>A method for forcing the termination of a running job.</p>
+    ///
+    /// See `SageMaker::stop_auto_ml_job`.
+    pub fn stop_auto_ml_job(
+        &self,
+        input: StopAutoMLJobRequest,
+    ) -> Result<(), RusotoError<StopAutoMLJobError>> {
+        self.runtime.block_on(self.client.stop_auto_ml_job(input))
+    }
+
+    /// <p>Stops a model compilation job.</p> <p> To stop a job, Amazon SageMaker sends the algorithm the SIGTERM signal. This gracefully shuts the job down. If the job hasn't stopped, it sends the SIGKILL signal.</p> <p>When it receives a <code>StopCompilationJob</code> request, Amazon SageMaker changes the <a>CompilationJobSummary$CompilationJobStatus</a> of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the <a>CompilationJobSummary$CompilationJobStatus</a> to <code>Stopped</code>. </p>
+    ///
+    /// See `SageMaker::stop_compilation_job`.
+    pub fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> Result<(), RusotoError<StopCompilationJobError>> {
+        self.runtime
+            .block_on(self.client.stop_compilation_job(input))
+    }
+
+    /// <p>Stops a running hyperparameter tuning job and all running training jobs that the tuning job launched.</p> <p>All model artifacts output from the training jobs are stored in Amazon Simple Storage Service (Amazon S3). All data that the training jobs write to Amazon CloudWatch Logs are still available in CloudWatch. After the tuning job moves to the <code>Stopped</code> state, it releases all reserved resources for the tuning job.</p>
+    ///
+    /// See `SageMaker::stop_hyper_parameter_tuning_job`.
+    pub fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> Result<(), RusotoError<StopHyperParameterTuningJobError>> {
+        self.runtime
+            .block_on(self.client.stop_hyper_parameter_tuning_job(input))
+    }
+
+    /// <p>Stops a running labeling job. A job that is stopped cannot be restarted. Any results obtained before the job is stopped are placed in the Amazon S3 output bucket.</p>
+    ///
+    /// See `SageMaker::stop_labeling_job`.
+    pub fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> Result<(), RusotoError<StopLabelingJobError>> {
+        self.runtime.block_on(self.client.stop_labeling_job(input))
+    }
+
+    /// <p>Stops a previously started monitoring schedule.</p>
+    ///
+    /// See `SageMaker::stop_monitoring_schedule`.
+    pub fn stop_monitoring_schedule(
+        &self,
+        input: StopMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<StopMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.stop_monitoring_schedule(input))
+    }
+
+    /// <p>Terminates the ML compute instance. Before terminating the instance, Amazon SageMaker disconnects the ML storage volume from it. Amazon SageMaker preserves the ML storage volume. Amazon SageMaker stops charging you for the ML compute instance when you call <code>StopNotebookInstance</code>.</p> <p>To access data on the ML storage volume for a notebook instance that has been terminated, call the <code>StartNotebookInstance</code> API. <code>StartNotebookInstance</code> launches another ML compute instance, configures it, and attaches the preserved ML storage volume so you can continue your work. </p>
+    ///
+    /// See `SageMaker::stop_notebook_instance`.
+    pub fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> Result<(), RusotoError<StopNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.stop_notebook_instance(input))
+    }
+
+    /// <p>Stops a processing job.</p>
+    ///
+    /// See `SageMaker::stop_processing_job`.
+    pub fn stop_processing_job(
+        &self,
+        input: StopProcessingJobRequest,
+    ) -> Result<(), RusotoError<StopProcessingJobError>> {
+        self.runtime
+            .block_on(self.client.stop_processing_job(input))
+    }
+
+    /// <p>Stops a training job. To stop a job, Amazon SageMaker sends the algorithm the <code>SIGTERM</code> signal, which delays job termination for 120 seconds. Algorithms might use this 120-second window to save the model artifacts, so the results of the training is not lost. </p> <p>When it receives a <code>StopTrainingJob</code> request, Amazon SageMaker changes the status of the job to <code>Stopping</code>. After Amazon SageMaker stops the job, it sets the status to <code>Stopped</code>.</p>
+    ///
+    /// See `SageMaker::stop_training_job`.
+    pub fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> Result<(), RusotoError<StopTrainingJobError>> {
+        self.runtime.block_on(self.client.stop_training_job(input))
+    }
+
+    /// <p>Stops a transform job.</p> <p>When Amazon SageMaker receives a <code>StopTransformJob</code> request, the status of the job changes to <code>Stopping</code>. After Amazon SageMaker stops the job, the status is set to <code>Stopped</code>. When you stop a transform job before it is completed, Amazon SageMaker doesn't store the job's output in Amazon S3.</p>
+    ///
+    /// See `SageMaker::stop_transform_job`.
+    pub fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> Result<(), RusotoError<StopTransformJobError>> {
+        self.runtime.block_on(self.client.stop_transform_job(input))
+    }
+
+    /// <p>Updates the specified Git repository with the specified values.</p>
+    ///
+    /// See `SageMaker::update_code_repository`.
+    pub fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> Result<UpdateCodeRepositoryOutput, RusotoError<UpdateCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.update_code_repository(input))
+    }
+
+    /// <p>Updates the default settings for new user profiles in the domain.</p>
+    ///
+    /// See `SageMaker::update_domain`.
+    pub fn update_domain(
+        &self,
+        input: UpdateDomainRequest,
+    ) -> Result<UpdateDomainResponse, RusotoError<UpdateDomainError>> {
+        self.runtime.block_on(self.client.update_domain(input))
+    }
+
+    /// <p><p>Deploys the new <code>EndpointConfig</code> specified in the request, switches to using newly created endpoint, and then deletes resources provisioned for the endpoint using the previous <code>EndpointConfig</code> (there is no availability loss). </p> <p>When Amazon SageMaker receives the request, it sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a>DescribeEndpoint</a> API. </p> <note> <p>You must not delete an <code>EndpointConfig</code> in use by an endpoint that is live or while the <code>UpdateEndpoint</code> or <code>CreateEndpoint</code> operations are being performed on the endpoint. To update an endpoint, you must create a new <code>EndpointConfig</code>.</p> <p>If you delete the <code>EndpointConfig</code> of an endpoint that is active or being created or updated you may lose visibility into the instance type the endpoint is using. The endpoint must be deleted in order to stop incurring charges.</p> </note></p>
+    ///
+    /// See `SageMaker::update_endpoint`.
+    pub fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>> {
+        self.runtime.block_on(self.client.update_endpoint(input))
+    }
+
+    /// <p>Updates variant weight of one or more variants associated with an existing endpoint, or capacity of one variant associated with an existing endpoint. When it receives the request, Amazon SageMaker sets the endpoint status to <code>Updating</code>. After updating the endpoint, it sets the status to <code>InService</code>. To check the status of an endpoint, use the <a>DescribeEndpoint</a> API. </p>
+    ///
+    /// See `SageMaker::update_endpoint_weights_and_capacities`.
+    pub fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> Result<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_endpoint_weights_and_capacities(input))
+    }
+
+    /// <p>Adds, updates, or removes the description of an experiment. Updates the display name of an experiment.</p>
+    ///
+    /// See `SageMaker::update_experiment`.
+    pub fn update_experiment(
+        &self,
+        input: UpdateExperimentRequest,
+    ) -> Result<UpdateExperimentResponse, RusotoError<UpdateExperimentError>> {
+        self.runtime.block_on(self.client.update_experiment(input))
+    }
+
+    /// <p>Updates a previously created schedule.</p>
+    ///
+    /// See `SageMaker::update_monitoring_schedule`.
+    pub fn update_monitoring_schedule(
+        &self,
+        input: UpdateMonitoringScheduleRequest,
+    ) -> Result<UpdateMonitoringScheduleResponse, RusotoError<UpdateMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.update_monitoring_schedule(input))
+    }
+
+    /// <p>Updates a notebook instance. NotebookInstance updates include upgrading or downgrading the ML compute instance used for your notebook instance to accommodate changes in your workload requirements.</p>
+    ///
+    /// See `SageMaker::update_notebook_instance`.
+    pub fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> Result<UpdateNotebookInstanceOutput, RusotoError<UpdateNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_notebook_instance(input))
+    }
+
+    /// <p>Updates a notebook instance lifecycle configuration created with the <a>CreateNotebookInstanceLifecycleConfig</a> API.</p>
+    ///
+    /// See `SageMaker::update_notebook_instance_lifecycle_config`.
+    pub fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_notebook_instance_lifecycle_config(input))
+    }
+
+    /// <p>Updates the display name of a trial.</p>
+    ///
+    /// See `SageMaker::update_trial`.
+    pub fn update_trial(
+        &self,
+        input: UpdateTrialRequest,
+    ) -> Result<UpdateTrialResponse, RusotoError<UpdateTrialError>> {
+        self.runtime.block_on(self.client.update_trial(input))
+    }
+
+    /// <p>Updates one or more properties of a trial component.</p>
+    ///
+    /// See `SageMaker::update_trial_component`.
+    pub fn update_trial_component(
+        &self,
+        input: UpdateTrialComponentRequest,
+    ) -> Result<UpdateTrialComponentResponse, RusotoError<UpdateTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.update_trial_component(input))
+    }
+
+    /// <p>Updates a user profile.</p>
+    ///
+    /// See `SageMaker::update_user_profile`.
+    pub fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResponse, RusotoError<UpdateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_profile(input))
+    }
+
+    /// <p><p>Restricts access to tasks assigned to workers in the specified workforce to those within specific ranges of IP addresses. You specify allowed IP addresses by creating a list of up to ten <a href="https://docs.aws.amazon.com/vpc/latest/userguide/VPC_Subnets.html">CIDRs</a>.</p> <p>By default, a workforce isn&#39;t restricted to specific IP addresses. If you specify a range of IP addresses, workers who attempt to access tasks using any IP address outside the specified range are denied access and get a <code>Not Found</code> error message on the worker portal. After restricting access with this operation, you can see the allowed IP values for a private workforce with the operation.</p> <important> <p>This operation applies only to private workforces.</p> </important></p>
+    ///
+    /// See `SageMaker::update_workforce`.
+    pub fn update_workforce(
+        &self,
+        input: UpdateWorkforceRequest,
+    ) -> Result<UpdateWorkforceResponse, RusotoError<UpdateWorkforceError>> {
+        self.runtime.block_on(self.client.update_workforce(input))
+    }
+
+    /// <p>Updates an existing work team with new member definitions or description.</p>
+    ///
+    /// See `SageMaker::update_workteam`.
+    pub fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>> {
+        self.runtime.block_on(self.client.update_workteam(input))
+    }
+}
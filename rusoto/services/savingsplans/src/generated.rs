@@ -658,15 +658,22 @@ impl CreateSavingsPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSavingsPlanError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSavingsPlanError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSavingsPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSavingsPlanError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateSavingsPlanError::ServiceQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSavingsPlanError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -698,9 +705,10 @@ impl DescribeSavingsPlanRatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSavingsPlanRatesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSavingsPlanRatesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -730,7 +738,10 @@ impl DescribeSavingsPlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSavingsPlansError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSavingsPlansError::InternalServer(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -762,7 +773,8 @@ impl DescribeSavingsPlansOfferingRatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSavingsPlansOfferingRatesError::InternalServer(err.msg),
                     )
                 }
@@ -798,7 +810,8 @@ impl DescribeSavingsPlansOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSavingsPlansOfferingsError::InternalServer(err.msg),
                     )
                 }
@@ -832,12 +845,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -872,13 +889,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(TagResourceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceQuotaExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -912,10 +932,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InternalServer(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1009,6 +1032,7 @@ impl SavingsPlansClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1024,6 +1048,7 @@ impl SavingsPlansClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SavingsPlansClient {
         SavingsPlansClient { client, region }
     }
@@ -1040,6 +1065,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/CreateSavingsPlan";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("CreateSavingsPlan");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1071,6 +1099,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlanRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("DescribeSavingsPlanRates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1102,6 +1133,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlans";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("DescribeSavingsPlans");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1136,6 +1170,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlansOfferingRates";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("DescribeSavingsPlansOfferingRates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1172,6 +1209,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/DescribeSavingsPlansOfferings";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("DescribeSavingsPlansOfferings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1203,6 +1243,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1234,6 +1277,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1265,6 +1311,9 @@ impl SavingsPlans for SavingsPlansClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "savingsplans", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1287,3 +1336,145 @@ impl SavingsPlans for SavingsPlansClient {
         }
     }
 }
+
+/// A blocking client for the AWSSavingsPlans API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SavingsPlansClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SavingsPlansBlockingClient {
+    client: SavingsPlansClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SavingsPlansBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SavingsPlansBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SavingsPlansBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SavingsPlansBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SavingsPlansBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SavingsPlansBlockingClient {
+        SavingsPlansBlockingClient {
+            client: SavingsPlansClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a Savings Plan.</p>
+    ///
+    /// See `SavingsPlans::create_savings_plan`.
+    pub fn create_savings_plan(
+        &self,
+        input: CreateSavingsPlanRequest,
+    ) -> Result<CreateSavingsPlanResponse, RusotoError<CreateSavingsPlanError>> {
+        self.runtime
+            .block_on(self.client.create_savings_plan(input))
+    }
+
+    /// <p>Describes the specified Savings Plans rates.</p>
+    ///
+    /// See `SavingsPlans::describe_savings_plan_rates`.
+    pub fn describe_savings_plan_rates(
+        &self,
+        input: DescribeSavingsPlanRatesRequest,
+    ) -> Result<DescribeSavingsPlanRatesResponse, RusotoError<DescribeSavingsPlanRatesError>> {
+        self.runtime
+            .block_on(self.client.describe_savings_plan_rates(input))
+    }
+
+    /// <p>Describes the specified Savings Plans.</p>
+    ///
+    /// See `SavingsPlans::describe_savings_plans`.
+    pub fn describe_savings_plans(
+        &self,
+        input: DescribeSavingsPlansRequest,
+    ) -> Result<DescribeSavingsPlansResponse, RusotoError<DescribeSavingsPlansError>> {
+        self.runtime
+            .block_on(self.client.describe_savings_plans(input))
+    }
+
+    /// <p>Describes the specified Savings Plans offering rates.</p>
+    ///
+    /// See `SavingsPlans::describe_savings_plans_offering_rates`.
+    pub fn describe_savings_plans_offering_rates(
+        &self,
+        input: DescribeSavingsPlansOfferingRatesRequest,
+    ) -> Result<
+        DescribeSavingsPlansOfferingRatesResponse,
+        RusotoError<DescribeSavingsPlansOfferingRatesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_savings_plans_offering_rates(input))
+    }
+
+    /// <p>Describes the specified Savings Plans offerings.</p>
+    ///
+    /// See `SavingsPlans::describe_savings_plans_offerings`.
+    pub fn describe_savings_plans_offerings(
+        &self,
+        input: DescribeSavingsPlansOfferingsRequest,
+    ) -> Result<
+        DescribeSavingsPlansOfferingsResponse,
+        RusotoError<DescribeSavingsPlansOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_savings_plans_offerings(input))
+    }
+
+    /// <p>Lists the tags for the specified resource.</p>
+    ///
+    /// See `SavingsPlans::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Adds the specified tags to the specified resource.</p>
+    ///
+    /// See `SavingsPlans::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes the specified tags from the specified resource.</p>
+    ///
+    /// See `SavingsPlans::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -1033,24 +1033,28 @@ impl CreateDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateDiscovererError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDiscovererError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateDiscovererError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateDiscovererError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiscovererError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDiscovererError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateDiscovererError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1094,22 +1098,28 @@ impl CreateRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRegistryError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRegistryError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateRegistryError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateRegistryError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateRegistryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegistryError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegistryError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateRegistryError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1149,16 +1159,22 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateSchemaError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSchemaError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1200,24 +1216,28 @@ impl DeleteDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteDiscovererError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteDiscovererError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiscovererError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteDiscovererError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDiscovererError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteDiscovererError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1261,22 +1281,28 @@ impl DeleteRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteRegistryError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteRegistryError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteRegistryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistryError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRegistryError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteRegistryError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegistryError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteRegistryError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1320,26 +1346,37 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteResourcePolicyError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1383,22 +1420,28 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1442,26 +1485,34 @@ impl DeleteSchemaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaVersionError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaVersionError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSchemaVersionError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteSchemaVersionError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSchemaVersionError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1505,24 +1556,34 @@ impl DescribeCodeBindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeBindingError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeCodeBindingError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeBindingError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeCodeBindingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeBindingError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeCodeBindingError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCodeBindingError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1566,26 +1627,31 @@ impl DescribeDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeDiscovererError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeDiscovererError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDiscovererError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeDiscovererError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDiscovererError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDiscovererError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDiscovererError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1629,24 +1695,28 @@ impl DescribeRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeRegistryError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeRegistryError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeRegistryError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRegistryError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRegistryError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeRegistryError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRegistryError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeRegistryError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1690,22 +1760,28 @@ impl DescribeSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DescribeSchemaError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DescribeSchemaError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSchemaError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeSchemaError::NotFound(err.msg))
+                    return RusotoError::service(&res, DescribeSchemaError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeSchemaError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1749,26 +1825,37 @@ impl GetCodeBindingSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCodeBindingSourceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCodeBindingSourceError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCodeBindingSourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetCodeBindingSourceError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCodeBindingSourceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetCodeBindingSourceError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCodeBindingSourceError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1810,23 +1897,31 @@ impl GetDiscoveredSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoveredSchemaError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetDiscoveredSchemaError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoveredSchemaError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoveredSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDiscoveredSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDiscoveredSchemaError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1869,26 +1964,31 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourcePolicyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetResourcePolicyError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetResourcePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetResourcePolicyError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetResourcePolicyError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourcePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1930,19 +2030,25 @@ impl ListDiscoverersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDiscoverersError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListDiscoverersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListDiscoverersError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListDiscoverersError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDiscoverersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoverersError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDiscoverersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDiscoverersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDiscoverersError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListDiscoverersError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1983,19 +2089,25 @@ impl ListRegistriesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRegistriesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListRegistriesError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRegistriesError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListRegistriesError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListRegistriesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRegistriesError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRegistriesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRegistriesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListRegistriesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListRegistriesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2038,26 +2150,31 @@ impl ListSchemaVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListSchemaVersionsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListSchemaVersionsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemaVersionsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListSchemaVersionsError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemaVersionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemaVersionsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2099,19 +2216,25 @@ impl ListSchemasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSchemasError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListSchemasError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListSchemasError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListSchemasError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListSchemasError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemasError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListSchemasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSchemasError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListSchemasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListSchemasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2150,18 +2273,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2205,23 +2332,31 @@ impl PutCodeBindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutCodeBindingError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutCodeBindingError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutCodeBindingError::Forbidden(err.msg))
+                    return RusotoError::service(&res, PutCodeBindingError::Forbidden(err.msg))
+                }
+                "GoneException" => {
+                    return RusotoError::service(&res, PutCodeBindingError::Gone(err.msg))
                 }
-                "GoneException" => return RusotoError::Service(PutCodeBindingError::Gone(err.msg)),
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PutCodeBindingError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutCodeBindingError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutCodeBindingError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutCodeBindingError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutCodeBindingError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutCodeBindingError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutCodeBindingError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, PutCodeBindingError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2268,31 +2403,37 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutResourcePolicyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, PutResourcePolicyError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutResourcePolicyError::Forbidden(err.msg))
+                    return RusotoError::service(&res, PutResourcePolicyError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutResourcePolicyError::NotFound(err.msg))
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutResourcePolicyError::PreconditionFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::PreconditionFailed(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutResourcePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2335,19 +2476,25 @@ impl SearchSchemasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchSchemasError::BadRequest(err.msg))
+                    return RusotoError::service(&res, SearchSchemasError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchSchemasError::Forbidden(err.msg))
+                    return RusotoError::service(&res, SearchSchemasError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SearchSchemasError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchSchemasError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SearchSchemasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchSchemasError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchSchemasError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, SearchSchemasError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2390,22 +2537,28 @@ impl StartDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StartDiscovererError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StartDiscovererError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartDiscovererError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDiscovererError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(&res, StartDiscovererError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDiscovererError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, StartDiscovererError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2449,22 +2602,28 @@ impl StopDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(&res, StopDiscovererError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(&res, StopDiscovererError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopDiscovererError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDiscovererError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(&res, StopDiscovererError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopDiscovererError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, StopDiscovererError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2504,16 +2663,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2551,16 +2713,19 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2602,24 +2767,28 @@ impl UpdateDiscovererError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDiscovererError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateDiscovererError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateDiscovererError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateDiscovererError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDiscovererError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDiscovererError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDiscovererError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateDiscovererError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDiscovererError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDiscovererError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDiscovererError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateDiscovererError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2663,22 +2832,28 @@ impl UpdateRegistryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRegistryError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateRegistryError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRegistryError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateRegistryError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateRegistryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegistryError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRegistryError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateRegistryError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRegistryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegistryError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRegistryError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, UpdateRegistryError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2720,19 +2895,25 @@ impl UpdateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSchemaError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateSchemaError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateSchemaError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSchemaError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSchemaError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateSchemaError::NotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateSchemaError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSchemaError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2955,6 +3136,7 @@ impl SchemasClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2970,6 +3152,7 @@ impl SchemasClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SchemasClient {
         SchemasClient { client, region }
     }
@@ -2986,6 +3169,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("CreateDiscoverer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3020,6 +3206,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("CreateRegistry");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3055,6 +3244,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("CreateSchema");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3089,6 +3281,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DeleteDiscoverer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3119,6 +3314,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DeleteRegistry");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3146,6 +3344,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/policy";
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DeleteResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3183,6 +3384,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DeleteSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3210,6 +3414,9 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/version/{schema_version}", registry_name = input.registry_name, schema_name = input.schema_name, schema_version = input.schema_version);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DeleteSchemaVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3242,6 +3449,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DescribeCodeBinding");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3279,6 +3489,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DescribeDiscoverer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3310,6 +3523,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DescribeRegistry");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3342,6 +3558,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("DescribeSchema");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3376,6 +3595,9 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/v1/registries/name/{registry_name}/schemas/name/{schema_name}/language/{language}/source", language = input.language, registry_name = input.registry_name, schema_name = input.schema_name);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("GetCodeBindingSource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3411,6 +3633,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discover";
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("GetDiscoveredSchema");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3442,6 +3667,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/policy";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("GetResourcePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3476,6 +3704,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/discoverers";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("ListDiscoverers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3519,6 +3750,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/registries";
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("ListRegistries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3566,6 +3800,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("ListSchemaVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3606,6 +3843,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("ListSchemas");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3646,6 +3886,9 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3679,6 +3922,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("PutCodeBinding");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3713,6 +3959,9 @@ impl Schemas for SchemasClient {
         let request_uri = "/v1/policy";
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation_name("PutResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3753,6 +4002,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("GET", "schemas", &self.region, &request_uri);
+        request.set_operation_name("SearchSchemas");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3794,6 +4046,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("StartDiscoverer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3825,6 +4080,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("StopDiscoverer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3853,6 +4111,9 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "schemas", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3883,6 +4144,9 @@ impl Schemas for SchemasClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "schemas", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3919,6 +4183,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation_name("UpdateDiscoverer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3953,6 +4220,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation_name("UpdateRegistry");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3988,6 +4258,9 @@ impl Schemas for SchemasClient {
         );
 
         let mut request = SignedRequest::new("PUT", "schemas", &self.region, &request_uri);
+        request.set_operation_name("UpdateSchema");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4010,3 +4283,363 @@ impl Schemas for SchemasClient {
         }
     }
 }
+
+/// A blocking client for the Schemas API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SchemasClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SchemasBlockingClient {
+    client: SchemasClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SchemasBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SchemasBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SchemasBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SchemasBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SchemasBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SchemasBlockingClient {
+        SchemasBlockingClient {
+            client: SchemasClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a discoverer.</p>
+    ///
+    /// See `Schemas::create_discoverer`.
+    pub fn create_discoverer(
+        &self,
+        input: CreateDiscovererRequest,
+    ) -> Result<CreateDiscovererResponse, RusotoError<CreateDiscovererError>> {
+        self.runtime.block_on(self.client.create_discoverer(input))
+    }
+
+    /// <p>Creates a registry.</p>
+    ///
+    /// See `Schemas::create_registry`.
+    pub fn create_registry(
+        &self,
+        input: CreateRegistryRequest,
+    ) -> Result<CreateRegistryResponse, RusotoError<CreateRegistryError>> {
+        self.runtime.block_on(self.client.create_registry(input))
+    }
+
+    /// <p><p>Creates a schema definition.</p> <note><p>Inactive schemas will be deleted after two years.</p></note></p>
+    ///
+    /// See `Schemas::create_schema`.
+    pub fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.runtime.block_on(self.client.create_schema(input))
+    }
+
+    /// <p>Deletes a discoverer.</p>
+    ///
+    /// See `Schemas::delete_discoverer`.
+    pub fn delete_discoverer(
+        &self,
+        input: DeleteDiscovererRequest,
+    ) -> Result<(), RusotoError<DeleteDiscovererError>> {
+        self.runtime.block_on(self.client.delete_discoverer(input))
+    }
+
+    /// <p>Deletes a Registry.</p>
+    ///
+    /// See `Schemas::delete_registry`.
+    pub fn delete_registry(
+        &self,
+        input: DeleteRegistryRequest,
+    ) -> Result<(), RusotoError<DeleteRegistryError>> {
+        self.runtime.block_on(self.client.delete_registry(input))
+    }
+
+    /// <p>Delete the resource-based policy attached to the specified registry.</p>
+    ///
+    /// See `Schemas::delete_resource_policy`.
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// <p>Delete a schema definition.</p>
+    ///
+    /// See `Schemas::delete_schema`.
+    pub fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+        self.runtime.block_on(self.client.delete_schema(input))
+    }
+
+    /// <p>Delete the schema version definition</p>
+    ///
+    /// See `Schemas::delete_schema_version`.
+    pub fn delete_schema_version(
+        &self,
+        input: DeleteSchemaVersionRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_schema_version(input))
+    }
+
+    /// <p>Describe the code binding URI.</p>
+    ///
+    /// See `Schemas::describe_code_binding`.
+    pub fn describe_code_binding(
+        &self,
+        input: DescribeCodeBindingRequest,
+    ) -> Result<DescribeCodeBindingResponse, RusotoError<DescribeCodeBindingError>> {
+        self.runtime
+            .block_on(self.client.describe_code_binding(input))
+    }
+
+    /// <p>Describes the discoverer.</p>
+    ///
+    /// See `Schemas::describe_discoverer`.
+    pub fn describe_discoverer(
+        &self,
+        input: DescribeDiscovererRequest,
+    ) -> Result<DescribeDiscovererResponse, RusotoError<DescribeDiscovererError>> {
+        self.runtime
+            .block_on(self.client.describe_discoverer(input))
+    }
+
+    /// <p>Describes the registry.</p>
+    ///
+    /// See `Schemas::describe_registry`.
+    pub fn describe_registry(
+        &self,
+        input: DescribeRegistryRequest,
+    ) -> Result<DescribeRegistryResponse, RusotoError<DescribeRegistryError>> {
+        self.runtime.block_on(self.client.describe_registry(input))
+    }
+
+    /// <p>Retrieve the schema definition.</p>
+    ///
+    /// See `Schemas::describe_schema`.
+    pub fn describe_schema(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+        self.runtime.block_on(self.client.describe_schema(input))
+    }
+
+    /// <p>Get the code binding source URI.</p>
+    ///
+    /// See `Schemas::get_code_binding_source`.
+    pub fn get_code_binding_source(
+        &self,
+        input: GetCodeBindingSourceRequest,
+    ) -> Result<GetCodeBindingSourceResponse, RusotoError<GetCodeBindingSourceError>> {
+        self.runtime
+            .block_on(self.client.get_code_binding_source(input))
+    }
+
+    /// <p>Get the discovered schema that was generated based on sampled events.</p>
+    ///
+    /// See `Schemas::get_discovered_schema`.
+    pub fn get_discovered_schema(
+        &self,
+        input: GetDiscoveredSchemaRequest,
+    ) -> Result<GetDiscoveredSchemaResponse, RusotoError<GetDiscoveredSchemaError>> {
+        self.runtime
+            .block_on(self.client.get_discovered_schema(input))
+    }
+
+    /// <p>Retrieves the resource-based policy attached to a given registry.</p>
+    ///
+    /// See `Schemas::get_resource_policy`.
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// <p>List the discoverers.</p>
+    ///
+    /// See `Schemas::list_discoverers`.
+    pub fn list_discoverers(
+        &self,
+        input: ListDiscoverersRequest,
+    ) -> Result<ListDiscoverersResponse, RusotoError<ListDiscoverersError>> {
+        self.runtime.block_on(self.client.list_discoverers(input))
+    }
+
+    /// <p>List the registries.</p>
+    ///
+    /// See `Schemas::list_registries`.
+    pub fn list_registries(
+        &self,
+        input: ListRegistriesRequest,
+    ) -> Result<ListRegistriesResponse, RusotoError<ListRegistriesError>> {
+        self.runtime.block_on(self.client.list_registries(input))
+    }
+
+    /// <p>Provides a list of the schema versions and related information.</p>
+    ///
+    /// See `Schemas::list_schema_versions`.
+    pub fn list_schema_versions(
+        &self,
+        input: ListSchemaVersionsRequest,
+    ) -> Result<ListSchemaVersionsResponse, RusotoError<ListSchemaVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_schema_versions(input))
+    }
+
+    /// <p>List the schemas.</p>
+    ///
+    /// See `Schemas::list_schemas`.
+    pub fn list_schemas(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+        self.runtime.block_on(self.client.list_schemas(input))
+    }
+
+    /// <p>Get tags for resource.</p>
+    ///
+    /// See `Schemas::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Put code binding URI</p>
+    ///
+    /// See `Schemas::put_code_binding`.
+    pub fn put_code_binding(
+        &self,
+        input: PutCodeBindingRequest,
+    ) -> Result<PutCodeBindingResponse, RusotoError<PutCodeBindingError>> {
+        self.runtime.block_on(self.client.put_code_binding(input))
+    }
+
+    /// <p>The name of the policy.</p>
+    ///
+    /// See `Schemas::put_resource_policy`.
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// <p>Search the schemas</p>
+    ///
+    /// See `Schemas::search_schemas`.
+    pub fn search_schemas(
+        &self,
+        input: SearchSchemasRequest,
+    ) -> Result<SearchSchemasResponse, RusotoError<SearchSchemasError>> {
+        self.runtime.block_on(self.client.search_schemas(input))
+    }
+
+    /// <p>Starts the discoverer</p>
+    ///
+    /// See `Schemas::start_discoverer`.
+    pub fn start_discoverer(
+        &self,
+        input: StartDiscovererRequest,
+    ) -> Result<StartDiscovererResponse, RusotoError<StartDiscovererError>> {
+        self.runtime.block_on(self.client.start_discoverer(input))
+    }
+
+    /// <p>Stops the discoverer</p>
+    ///
+    /// See `Schemas::stop_discoverer`.
+    pub fn stop_discoverer(
+        &self,
+        input: StopDiscovererRequest,
+    ) -> Result<StopDiscovererResponse, RusotoError<StopDiscovererError>> {
+        self.runtime.block_on(self.client.stop_discoverer(input))
+    }
+
+    /// <p>Add tags to a resource.</p>
+    ///
+    /// See `Schemas::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes tags from a resource.</p>
+    ///
+    /// See `Schemas::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the discoverer</p>
+    ///
+    /// See `Schemas::update_discoverer`.
+    pub fn update_discoverer(
+        &self,
+        input: UpdateDiscovererRequest,
+    ) -> Result<UpdateDiscovererResponse, RusotoError<UpdateDiscovererError>> {
+        self.runtime.block_on(self.client.update_discoverer(input))
+    }
+
+    /// <p>Updates a registry.</p>
+    ///
+    /// See `Schemas::update_registry`.
+    pub fn update_registry(
+        &self,
+        input: UpdateRegistryRequest,
+    ) -> Result<UpdateRegistryResponse, RusotoError<UpdateRegistryError>> {
+        self.runtime.block_on(self.client.update_registry(input))
+    }
+
+    /// <p><p>Updates the schema definition</p> <note><p>Inactive schemas will be deleted after two years.</p></note></p>
+    ///
+    /// See `Schemas::update_schema`.
+    pub fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        self.runtime.block_on(self.client.update_schema(input))
+    }
+}
@@ -975,55 +975,64 @@ impl BatchPutAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateItemName" => {
-                        return RusotoError::Service(BatchPutAttributesError::DuplicateItemName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            BatchPutAttributesError::DuplicateItemName(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutAttributesError::InvalidParameterValue(parsed_error.message),
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(BatchPutAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            BatchPutAttributesError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(BatchPutAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            BatchPutAttributesError::NoSuchDomain(parsed_error.message),
+                        )
                     }
                     "NumberDomainAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutAttributesError::NumberDomainAttributesExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberDomainBytesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutAttributesError::NumberDomainBytesExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberItemAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutAttributesError::NumberItemAttributesExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberSubmittedAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutAttributesError::NumberSubmittedAttributesExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberSubmittedItemsExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             BatchPutAttributesError::NumberSubmittedItemsExceeded(
                                 parsed_error.message,
                             ),
@@ -1089,19 +1098,22 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateDomainError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDomainError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(CreateDomainError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDomainError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NumberDomainsExceeded" => {
-                        return RusotoError::Service(CreateDomainError::NumberDomainsExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateDomainError::NumberDomainsExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1151,24 +1163,28 @@ impl DeleteAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
-                        return RusotoError::Service(DeleteAttributesError::AttributeDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAttributesError::AttributeDoesNotExist(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteAttributesError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAttributesError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(DeleteAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAttributesError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(DeleteAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteAttributesError::NoSuchDomain(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1213,9 +1229,10 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
-                        return RusotoError::Service(DeleteDomainError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteDomainError::MissingParameter(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1259,14 +1276,16 @@ impl DomainMetadataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MissingParameter" => {
-                        return RusotoError::Service(DomainMetadataError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DomainMetadataError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(DomainMetadataError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DomainMetadataError::NoSuchDomain(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1313,19 +1332,22 @@ impl GetAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(GetAttributesError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAttributesError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(GetAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAttributesError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(GetAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetAttributesError::NoSuchDomain(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1371,14 +1393,16 @@ impl ListDomainsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(ListDomainsError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListDomainsError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListDomainsError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListDomainsError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1433,39 +1457,46 @@ impl PutAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AttributeDoesNotExist" => {
-                        return RusotoError::Service(PutAttributesError::AttributeDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAttributesError::AttributeDoesNotExist(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutAttributesError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAttributesError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutAttributesError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAttributesError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(PutAttributesError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAttributesError::NoSuchDomain(parsed_error.message),
+                        )
                     }
                     "NumberDomainAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutAttributesError::NumberDomainAttributesExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NumberDomainBytesExceeded" => {
-                        return RusotoError::Service(PutAttributesError::NumberDomainBytesExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutAttributesError::NumberDomainBytesExceeded(parsed_error.message),
+                        )
                     }
                     "NumberItemAttributesExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutAttributesError::NumberItemAttributesExceeded(parsed_error.message),
                         )
                     }
@@ -1531,49 +1562,58 @@ impl SelectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(SelectError::InvalidNextToken(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::InvalidNextToken(parsed_error.message),
+                        )
                     }
                     "InvalidNumberPredicates" => {
-                        return RusotoError::Service(SelectError::InvalidNumberPredicates(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::InvalidNumberPredicates(parsed_error.message),
+                        )
                     }
                     "InvalidNumberValueTests" => {
-                        return RusotoError::Service(SelectError::InvalidNumberValueTests(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::InvalidNumberValueTests(parsed_error.message),
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(SelectError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidQueryExpression" => {
-                        return RusotoError::Service(SelectError::InvalidQueryExpression(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::InvalidQueryExpression(parsed_error.message),
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(SelectError::MissingParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::MissingParameter(parsed_error.message),
+                        )
                     }
                     "NoSuchDomain" => {
-                        return RusotoError::Service(SelectError::NoSuchDomain(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::NoSuchDomain(parsed_error.message),
+                        )
                     }
                     "RequestTimeout" => {
-                        return RusotoError::Service(SelectError::RequestTimeout(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::RequestTimeout(parsed_error.message),
+                        )
                     }
                     "TooManyRequestedAttributes" => {
-                        return RusotoError::Service(SelectError::TooManyRequestedAttributes(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SelectError::TooManyRequestedAttributes(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -1685,6 +1725,7 @@ impl SimpleDbClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1700,6 +1741,7 @@ impl SimpleDbClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SimpleDbClient {
         SimpleDbClient { client, region }
     }
@@ -1713,6 +1755,9 @@ impl SimpleDb for SimpleDbClient {
         input: BatchDeleteAttributesRequest,
     ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("BatchDeleteAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchDeleteAttributes");
         let mut params = params;
         BatchDeleteAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1733,6 +1778,9 @@ impl SimpleDb for SimpleDbClient {
         input: BatchPutAttributesRequest,
     ) -> Result<(), RusotoError<BatchPutAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("BatchPutAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("BatchPutAttributes");
         let mut params = params;
         BatchPutAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1753,6 +1801,9 @@ impl SimpleDb for SimpleDbClient {
         input: CreateDomainRequest,
     ) -> Result<(), RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("CreateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateDomain");
         let mut params = params;
         CreateDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -1773,6 +1824,9 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteAttributesRequest,
     ) -> Result<(), RusotoError<DeleteAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("DeleteAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteAttributes");
         let mut params = params;
         DeleteAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1793,6 +1847,9 @@ impl SimpleDb for SimpleDbClient {
         input: DeleteDomainRequest,
     ) -> Result<(), RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("DeleteDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteDomain");
         let mut params = params;
         DeleteDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -1813,6 +1870,9 @@ impl SimpleDb for SimpleDbClient {
         input: DomainMetadataRequest,
     ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("DomainMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DomainMetadata");
         let mut params = params;
         DomainMetadataRequestSerializer::serialize(&mut params, "", &input);
@@ -1844,6 +1904,9 @@ impl SimpleDb for SimpleDbClient {
         input: GetAttributesRequest,
     ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("GetAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAttributes");
         let mut params = params;
         GetAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1875,6 +1938,9 @@ impl SimpleDb for SimpleDbClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("ListDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListDomains");
         let mut params = params;
         ListDomainsRequestSerializer::serialize(&mut params, "", &input);
@@ -1905,6 +1971,9 @@ impl SimpleDb for SimpleDbClient {
         input: PutAttributesRequest,
     ) -> Result<(), RusotoError<PutAttributesError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("PutAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutAttributes");
         let mut params = params;
         PutAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -1922,6 +1991,9 @@ impl SimpleDb for SimpleDbClient {
     /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
     async fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
         let mut request = SignedRequest::new("POST", "sdb", &self.region, "/");
+        request.set_operation_name("Select");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("Select");
         let mut params = params;
         SelectRequestSerializer::serialize(&mut params, "", &input);
@@ -1946,3 +2018,152 @@ impl SimpleDb for SimpleDbClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon SimpleDB API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SimpleDbClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SimpleDbBlockingClient {
+    client: SimpleDbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SimpleDbBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SimpleDbBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SimpleDbBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SimpleDbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SimpleDbBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SimpleDbBlockingClient {
+        SimpleDbBlockingClient {
+            client: SimpleDbClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p> Performs multiple DeleteAttributes operations in a single call, which reduces round trips and latencies. This enables Amazon SimpleDB to optimize requests, which generally yields better throughput. </p> <p> The following limitations are enforced for this operation: <ul> <li>1 MB request size</li> <li>25 item limit per BatchDeleteAttributes operation</li> </ul> </p>
+    ///
+    /// See `SimpleDb::batch_delete_attributes`.
+    pub fn batch_delete_attributes(
+        &self,
+        input: BatchDeleteAttributesRequest,
+    ) -> Result<(), RusotoError<BatchDeleteAttributesError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_attributes(input))
+    }
+
+    /// <p> The <code>BatchPutAttributes</code> operation creates or replaces attributes within one or more items. By using this operation, the client can perform multiple <a>PutAttribute</a> operation with a single call. This helps yield savings in round trips and latencies, enabling Amazon SimpleDB to optimize requests and generally produce better throughput. </p> <p> The client may specify the item name with the <code>Item.X.ItemName</code> parameter. The client may specify new attributes using a combination of the <code>Item.X.Attribute.Y.Name</code> and <code>Item.X.Attribute.Y.Value</code> parameters. The client may specify the first attribute for the first item using the parameters <code>Item.0.Attribute.0.Name</code> and <code>Item.0.Attribute.0.Value</code>, and for the second attribute for the first item by the parameters <code>Item.0.Attribute.1.Name</code> and <code>Item.0.Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified within an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", "second_value" }</code>. However, it cannot have two attribute instances where both the <code>Item.X.Attribute.Y.Name</code> and <code>Item.X.Attribute.Y.Value</code> are the same. </p> <p> Optionally, the requester can supply the <code>Replace</code> parameter for each individual value. Setting this value to <code>true</code> will cause the new attribute values to replace the existing attribute values. For example, if an item <code>I</code> has the attributes <code>{ 'a', '1' }, { 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requester does a BatchPutAttributes of <code>{'I', 'b', '4' }</code> with the Replace parameter set to true, the final attributes of the item will be <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, replacing the previous values of the 'b' attribute with the new value. </p> <important> This operation is vulnerable to exceeding the maximum URL size when making a REST request using the HTTP GET method. This operation does not support conditions using <code>Expected.X.Name</code>, <code>Expected.X.Value</code>, or <code>Expected.X.Exists</code>. </important> <p> You can execute multiple <code>BatchPutAttributes</code> operations and other operations in parallel. However, large numbers of concurrent <code>BatchPutAttributes</code> calls can result in Service Unavailable (503) responses. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 attribute name-value pairs per item</li> <li>1 MB request size</li> <li>1 billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> <li>25 item limit per <code>BatchPutAttributes</code> operation</li> </ul> </p>
+    ///
+    /// See `SimpleDb::batch_put_attributes`.
+    pub fn batch_put_attributes(
+        &self,
+        input: BatchPutAttributesRequest,
+    ) -> Result<(), RusotoError<BatchPutAttributesError>> {
+        self.runtime
+            .block_on(self.client.batch_put_attributes(input))
+    }
+
+    /// <p> The <code>CreateDomain</code> operation creates a new domain. The domain name should be unique among the domains associated with the Access Key ID provided in the request. The <code>CreateDomain</code> operation may take 10 or more seconds to complete. </p> <p> The client can create up to 100 domains per account. </p> <p> If the client requires additional domains, go to <a href="http://aws.amazon.com/contact-us/simpledb-limit-request/"> http://aws.amazon.com/contact-us/simpledb-limit-request/</a>. </p>
+    ///
+    /// See `SimpleDb::create_domain`.
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<(), RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// <p> Deletes one or more attributes associated with an item. If all attributes of the item are deleted, the item is deleted. </p> <p> <code>DeleteAttributes</code> is an idempotent operation; running it multiple times on the same item or attribute does not result in an error response. </p> <p> Because Amazon SimpleDB makes multiple copies of item data and uses an eventual consistency update model, performing a <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <code>DeleteAttributes</code> or <a>PutAttributes</a> operation (write) might not return updated item data. </p>
+    ///
+    /// See `SimpleDb::delete_attributes`.
+    pub fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<(), RusotoError<DeleteAttributesError>> {
+        self.runtime.block_on(self.client.delete_attributes(input))
+    }
+
+    /// <p> The <code>DeleteDomain</code> operation deletes a domain. Any items (and their attributes) in the domain are deleted as well. The <code>DeleteDomain</code> operation might take 10 or more seconds to complete. </p>
+    ///
+    /// See `SimpleDb::delete_domain`.
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(), RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// <p> Returns information about the domain, including when the domain was created, the number of items and attributes in the domain, and the size of the attribute names and values. </p>
+    ///
+    /// See `SimpleDb::domain_metadata`.
+    pub fn domain_metadata(
+        &self,
+        input: DomainMetadataRequest,
+    ) -> Result<DomainMetadataResult, RusotoError<DomainMetadataError>> {
+        self.runtime.block_on(self.client.domain_metadata(input))
+    }
+
+    /// <p> Returns all of the attributes associated with the specified item. Optionally, the attributes returned can be limited to one or more attributes by specifying an attribute name parameter. </p> <p> If the item does not exist on the replica that was accessed for this operation, an empty set is returned. The system does not return an error as it cannot guarantee the item does not exist on other replicas. </p>
+    ///
+    /// See `SimpleDb::get_attributes`.
+    pub fn get_attributes(
+        &self,
+        input: GetAttributesRequest,
+    ) -> Result<GetAttributesResult, RusotoError<GetAttributesError>> {
+        self.runtime.block_on(self.client.get_attributes(input))
+    }
+
+    /// <p> The <code>ListDomains</code> operation lists all domains associated with the Access Key ID. It returns domain names up to the limit set by <a href="#MaxNumberOfDomains">MaxNumberOfDomains</a>. A <a href="#NextToken">NextToken</a> is returned if there are more than <code>MaxNumberOfDomains</code> domains. Calling <code>ListDomains</code> successive times with the <code>NextToken</code> provided by the operation returns up to <code>MaxNumberOfDomains</code> more domain names with each successive operation call. </p>
+    ///
+    /// See `SimpleDb::list_domains`.
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResult, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// <p> The PutAttributes operation creates or replaces attributes in an item. The client may specify new attributes using a combination of the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> parameters. The client specifies the first attribute by the parameters <code>Attribute.0.Name</code> and <code>Attribute.0.Value</code>, the second attribute by the parameters <code>Attribute.1.Name</code> and <code>Attribute.1.Value</code>, and so on. </p> <p> Attributes are uniquely identified in an item by their name/value combination. For example, a single item can have the attributes <code>{ "first_name", "first_value" }</code> and <code>{ "first_name", second_value" }</code>. However, it cannot have two attribute instances where both the <code>Attribute.X.Name</code> and <code>Attribute.X.Value</code> are the same. </p> <p> Optionally, the requestor can supply the <code>Replace</code> parameter for each individual attribute. Setting this value to <code>true</code> causes the new attribute value to replace the existing attribute value(s). For example, if an item has the attributes <code>{ 'a', '1' }</code>, <code>{ 'b', '2'}</code> and <code>{ 'b', '3' }</code> and the requestor calls <code>PutAttributes</code> using the attributes <code>{ 'b', '4' }</code> with the <code>Replace</code> parameter set to true, the final attributes of the item are changed to <code>{ 'a', '1' }</code> and <code>{ 'b', '4' }</code>, which replaces the previous values of the 'b' attribute with the new value. </p> <p> You cannot specify an empty string as an attribute name. </p> <p> Because Amazon SimpleDB makes multiple copies of client data and uses an eventual consistency update model, an immediate <a>GetAttributes</a> or <a>Select</a> operation (read) immediately after a <a>PutAttributes</a> or <a>DeleteAttributes</a> operation (write) might not return the updated data. </p> <p> The following limitations are enforced for this operation: <ul> <li>256 total attribute name-value pairs per item</li> <li>One billion attributes per domain</li> <li>10 GB of total user data storage per domain</li> </ul> </p>
+    ///
+    /// See `SimpleDb::put_attributes`.
+    pub fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<(), RusotoError<PutAttributesError>> {
+        self.runtime.block_on(self.client.put_attributes(input))
+    }
+
+    /// <p> The <code>Select</code> operation returns a set of attributes for <code>ItemNames</code> that match the select expression. <code>Select</code> is similar to the standard SQL SELECT statement. </p> <p> The total size of the response cannot exceed 1 MB in total size. Amazon SimpleDB automatically adjusts the number of items returned per page to enforce this limit. For example, if the client asks to retrieve 2500 items, but each individual item is 10 kB in size, the system returns 100 items and an appropriate <code>NextToken</code> so the client can access the next page of results. </p> <p> For information on how to construct select expressions, see Using Select to Create Amazon SimpleDB Queries in the Developer Guide. </p>
+    ///
+    /// See `SimpleDb::select`.
+    pub fn select(&self, input: SelectRequest) -> Result<SelectResult, RusotoError<SelectError>> {
+        self.runtime.block_on(self.client.select(input))
+    }
+}
@@ -878,18 +878,28 @@ impl CancelRotateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CancelRotateSecretError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelRotateSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelRotateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelRotateSecretError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelRotateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelRotateSecretError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelRotateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelRotateSecretError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -938,33 +948,43 @@ impl CreateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(CreateSecretError::EncryptionFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecretError::EncryptionFailure(err.msg),
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateSecretError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateSecretError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSecretError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateSecretError::LimitExceeded(err.msg))
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreateSecretError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecretError::MalformedPolicyDocument(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateSecretError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSecretError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateSecretError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateSecretError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateSecretError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1006,17 +1026,22 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1054,16 +1079,19 @@ impl DeleteSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteSecretError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteSecretError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteSecretError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1098,10 +1126,16 @@ impl DescribeSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSecretError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1136,15 +1170,22 @@ impl GetRandomPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetRandomPasswordError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRandomPasswordError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRandomPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRandomPasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRandomPasswordError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRandomPasswordError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1180,15 +1221,22 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1228,19 +1276,31 @@ impl GetSecretValueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DecryptionFailure" => {
-                    return RusotoError::Service(GetSecretValueError::DecryptionFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSecretValueError::DecryptionFailure(err.msg),
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetSecretValueError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSecretValueError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSecretValueError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSecretValueError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSecretValueError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetSecretValueError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSecretValueError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSecretValueError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1278,19 +1338,22 @@ impl ListSecretVersionIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecretVersionIdsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecretVersionIdsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecretVersionIdsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSecretVersionIdsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1326,13 +1389,16 @@ impl ListSecretsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListSecretsError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSecretsError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSecretsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListSecretsError::InvalidNextToken(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecretsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListSecretsError::InvalidParameter(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1374,26 +1440,40 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(PutResourcePolicyError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::MalformedPolicyDocument(err.msg),
+                    )
                 }
                 "PublicPolicyException" => {
-                    return RusotoError::Service(PutResourcePolicyError::PublicPolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::PublicPolicy(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1440,25 +1520,37 @@ impl PutSecretValueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(PutSecretValueError::EncryptionFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSecretValueError::EncryptionFailure(err.msg),
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutSecretValueError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSecretValueError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutSecretValueError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSecretValueError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutSecretValueError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, PutSecretValueError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSecretValueError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, PutSecretValueError::LimitExceeded(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(PutSecretValueError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, PutSecretValueError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSecretValueError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSecretValueError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1500,16 +1592,25 @@ impl RestoreSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RestoreSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestoreSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreSecretError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RestoreSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, RestoreSecretError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreSecretError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1548,16 +1649,19 @@ impl RotateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RotateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RotateSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RotateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, RotateSecretError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RotateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, RotateSecretError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RotateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, RotateSecretError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1596,16 +1700,19 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1644,16 +1751,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1702,33 +1818,43 @@ impl UpdateSecretError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionFailure" => {
-                    return RusotoError::Service(UpdateSecretError::EncryptionFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretError::EncryptionFailure(err.msg),
+                    )
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateSecretError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSecretError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateSecretError::InvalidParameter(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecretError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateSecretError::InvalidRequest(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSecretError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateSecretError::LimitExceeded(err.msg))
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(UpdateSecretError::MalformedPolicyDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretError::MalformedPolicyDocument(err.msg),
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateSecretError::PreconditionNotMet(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretError::PreconditionNotMet(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateSecretError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, UpdateSecretError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecretError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateSecretError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1774,29 +1900,34 @@ impl UpdateSecretVersionStageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSecretVersionStageError::InternalServiceError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretVersionStageError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretVersionStageError::InvalidRequest(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretVersionStageError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecretVersionStageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSecretVersionStageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1840,29 +1971,34 @@ impl ValidateResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateResourcePolicyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateResourcePolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateResourcePolicyError::InvalidRequest(err.msg),
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ValidateResourcePolicyError::MalformedPolicyDocument(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ValidateResourcePolicyError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ValidateResourcePolicyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2021,6 +2157,7 @@ impl SecretsManagerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2036,6 +2173,7 @@ impl SecretsManagerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerClient {
         SecretsManagerClient { client, region }
     }
@@ -2049,6 +2187,9 @@ impl SecretsManager for SecretsManagerClient {
         input: CancelRotateSecretRequest,
     ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelRotateSecret");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.CancelRotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2067,6 +2208,9 @@ impl SecretsManager for SecretsManagerClient {
         input: CreateSecretRequest,
     ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSecret");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "secretsmanager.CreateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2085,6 +2229,9 @@ impl SecretsManager for SecretsManagerClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2104,6 +2251,9 @@ impl SecretsManager for SecretsManagerClient {
         input: DeleteSecretRequest,
     ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSecret");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.DeleteSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2122,6 +2272,9 @@ impl SecretsManager for SecretsManagerClient {
         input: DescribeSecretRequest,
     ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSecret");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "secretsmanager.DescribeSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2140,6 +2293,9 @@ impl SecretsManager for SecretsManagerClient {
         input: GetRandomPasswordRequest,
     ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRandomPassword");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "secretsmanager.GetRandomPassword");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2158,6 +2314,9 @@ impl SecretsManager for SecretsManagerClient {
         input: GetResourcePolicyRequest,
     ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetResourcePolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "secretsmanager.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2176,6 +2335,9 @@ impl SecretsManager for SecretsManagerClient {
         input: GetSecretValueRequest,
     ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSecretValue");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "secretsmanager.GetSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2194,6 +2356,9 @@ impl SecretsManager for SecretsManagerClient {
         input: ListSecretVersionIdsRequest,
     ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSecretVersionIds");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "secretsmanager.ListSecretVersionIds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2213,6 +2378,9 @@ impl SecretsManager for SecretsManagerClient {
         input: ListSecretsRequest,
     ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSecrets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "secretsmanager.ListSecrets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2231,6 +2399,9 @@ impl SecretsManager for SecretsManagerClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2249,6 +2420,9 @@ impl SecretsManager for SecretsManagerClient {
         input: PutSecretValueRequest,
     ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutSecretValue");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "secretsmanager.PutSecretValue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2267,6 +2441,9 @@ impl SecretsManager for SecretsManagerClient {
         input: RestoreSecretRequest,
     ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreSecret");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.RestoreSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2285,6 +2462,9 @@ impl SecretsManager for SecretsManagerClient {
         input: RotateSecretRequest,
     ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RotateSecret");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "secretsmanager.RotateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2303,6 +2483,9 @@ impl SecretsManager for SecretsManagerClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2320,6 +2503,9 @@ impl SecretsManager for SecretsManagerClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2337,6 +2523,9 @@ impl SecretsManager for SecretsManagerClient {
         input: UpdateSecretRequest,
     ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSecret");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_request_token.is_some());
         request.add_header("x-amz-target", "secretsmanager.UpdateSecret");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2355,6 +2544,9 @@ impl SecretsManager for SecretsManagerClient {
         input: UpdateSecretVersionStageRequest,
     ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSecretVersionStage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.UpdateSecretVersionStage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2374,6 +2566,9 @@ impl SecretsManager for SecretsManagerClient {
         input: ValidateResourcePolicyRequest,
     ) -> Result<ValidateResourcePolicyResponse, RusotoError<ValidateResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ValidateResourcePolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "secretsmanager.ValidateResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2387,3 +2582,251 @@ impl SecretsManager for SecretsManagerClient {
             .deserialize::<ValidateResourcePolicyResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Secrets Manager API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SecretsManagerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SecretsManagerBlockingClient {
+    client: SecretsManagerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SecretsManagerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SecretsManagerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SecretsManagerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SecretsManagerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SecretsManagerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SecretsManagerBlockingClient {
+        SecretsManagerBlockingClient {
+            client: SecretsManagerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Disables automatic scheduled rotation and cancels the rotation of a secret if currently in progress.</p> <p>To re-enable scheduled rotation, call <a>RotateSecret</a> with <code>AutomaticallyRotateAfterDays</code> set to a value greater than 0. This immediately rotates your secret and then enables the automatic schedule.</p> <note> <p>If you cancel a rotation while in progress, it can leave the <code>VersionStage</code> labels in an unexpected state. Depending on the step of the rotation in progress, you might need to remove the staging label <code>AWSPENDING</code> from the partially created version, specified by the <code>VersionId</code> response value. You should also evaluate the partially rotated new version to see if it should be deleted, which you can do by removing all staging labels from the new version <code>VersionStage</code> field.</p> </note> <p>To successfully start a rotation, the staging label <code>AWSPENDING</code> must be in one of the following states:</p> <ul> <li> <p>Not attached to any version at all</p> </li> <li> <p>Attached to the same version as the staging label <code>AWSCURRENT</code> </p> </li> </ul> <p>If the staging label <code>AWSPENDING</code> attached to a different version than the version with <code>AWSCURRENT</code> then the attempt to rotate fails.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CancelRotateSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To configure rotation for a secret or to manually trigger a rotation, use <a>RotateSecret</a>.</p> </li> <li> <p>To get the rotation configuration details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> <li> <p>To list all of the versions currently associated with a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::cancel_rotate_secret`.
+    pub fn cancel_rotate_secret(
+        &self,
+        input: CancelRotateSecretRequest,
+    ) -> Result<CancelRotateSecretResponse, RusotoError<CancelRotateSecretError>> {
+        self.runtime
+            .block_on(self.client.cancel_rotate_secret(input))
+    }
+
+    /// <p><p>Creates a new secret. A secret in Secrets Manager consists of both the protected secret data and the important information needed to manage the secret.</p> <p>Secrets Manager stores the encrypted secret data in one of a collection of &quot;versions&quot; associated with the secret. Each version contains a copy of the encrypted secret data. Each version is associated with one or more &quot;staging labels&quot; that identify where the version is in the rotation cycle. The <code>SecretVersionsToStages</code> field of the secret contains the mapping of staging labels to the active versions of the secret. Versions without a staging label are considered deprecated and not included in the list.</p> <p>You provide the secret data to be encrypted by putting text in either the <code>SecretString</code> parameter or binary data in the <code>SecretBinary</code> parameter, but not both. If you include <code>SecretString</code> or <code>SecretBinary</code> then Secrets Manager also creates an initial secret version and automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> <note> <ul> <li> <p>If you call an operation to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS creating the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret resides in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:CreateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account default AWS managed CMK for Secrets Manager.</p> </li> <li> <p>secretsmanager:TagResource - needed only if you include the <code>Tags</code> parameter. </p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> <li> <p>To modify an existing secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the encrypted secure string and secure binary values, use <a>GetSecretValue</a>.</p> </li> <li> <p>To retrieve all other details for a secret, use <a>DescribeSecret</a>. This does not include the encrypted secure string and secure binary values.</p> </li> <li> <p>To retrieve the list of secret versions associated with the current secret, use <a>DescribeSecret</a> and examine the <code>SecretVersionsToStages</code> response value.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::create_secret`.
+    pub fn create_secret(
+        &self,
+        input: CreateSecretRequest,
+    ) -> Result<CreateSecretResponse, RusotoError<CreateSecretError>> {
+        self.runtime.block_on(self.client.create_secret(input))
+    }
+
+    /// <p><p>Deletes the resource-based permission policy attached to the secret.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To retrieve the current resource-based policy that&#39;s attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::delete_resource_policy`.
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// <p><p>Deletes an entire secret and all of its versions. You can optionally include a recovery window during which you can restore the secret. If you don&#39;t specify a recovery window value, the operation defaults to 30 days. Secrets Manager attaches a <code>DeletionDate</code> stamp to the secret that specifies the end of the recovery window. At the end of the recovery window, Secrets Manager deletes the secret permanently.</p> <p>At any time before recovery window ends, you can use <a>RestoreSecret</a> to remove the <code>DeletionDate</code> and cancel the deletion of the secret.</p> <p>You cannot access the encrypted secret information in any secret that is scheduled for deletion. If you need to access that information, you must cancel the deletion with <a>RestoreSecret</a> and then retrieve the information.</p> <note> <ul> <li> <p>There is no explicit operation to delete a version of a secret. Instead, remove all staging labels from the <code>VersionStage</code> field of a version. That marks the version as deprecated and allows Secrets Manager to delete it as needed. Versions that do not have any staging labels do not show up in <a>ListSecretVersionIds</a> unless you specify <code>IncludeDeprecated</code>.</p> </li> <li> <p>The permanent secret deletion at the end of the waiting period is performed as a background task with low priority. There is no guarantee of a specific time after the recovery window for the actual delete operation to occur.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DeleteSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To cancel deletion of a version of a secret before the recovery window has expired, use <a>RestoreSecret</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::delete_secret`.
+    pub fn delete_secret(
+        &self,
+        input: DeleteSecretRequest,
+    ) -> Result<DeleteSecretResponse, RusotoError<DeleteSecretError>> {
+        self.runtime.block_on(self.client.delete_secret(input))
+    }
+
+    /// <p><p>Retrieves the details of a secret. It does not include the encrypted fields. Secrets Manager only returns fields populated with a value in the response. </p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:DescribeSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To modify a secret, use <a>UpdateSecret</a>.</p> </li> <li> <p>To retrieve the encrypted secret information in a version of the secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To list all of the secrets in the AWS account, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::describe_secret`.
+    pub fn describe_secret(
+        &self,
+        input: DescribeSecretRequest,
+    ) -> Result<DescribeSecretResponse, RusotoError<DescribeSecretError>> {
+        self.runtime.block_on(self.client.describe_secret(input))
+    }
+
+    /// <p><p>Generates a random password of the specified complexity. This operation is intended for use in the Lambda rotation function. Per best practice, we recommend that you specify the maximum length and include every character type that the system you are generating a password for can support.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetRandomPassword</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::get_random_password`.
+    pub fn get_random_password(
+        &self,
+        input: GetRandomPasswordRequest,
+    ) -> Result<GetRandomPasswordResponse, RusotoError<GetRandomPasswordError>> {
+        self.runtime
+            .block_on(self.client.get_random_password(input))
+    }
+
+    /// <p><p>Retrieves the JSON text of the resource-based policy document attached to the specified secret. The JSON request string input and response output displays formatted code with white space and line breaks for better readability. Submit your input as a single line JSON string.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To attach a resource policy to a secret, use <a>PutResourcePolicy</a>.</p> </li> <li> <p>To delete the resource-based policy attached to a secret, use <a>DeleteResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::get_resource_policy`.
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// <p><p>Retrieves the contents of the encrypted fields <code>SecretString</code> or <code>SecretBinary</code> from the specified version of a secret, whichever contains content.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:GetSecretValue</p> </li> <li> <p>kms:Decrypt - required only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a new version of the secret with different encrypted information, use <a>PutSecretValue</a>.</p> </li> <li> <p>To retrieve the non-encrypted details for the secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::get_secret_value`.
+    pub fn get_secret_value(
+        &self,
+        input: GetSecretValueRequest,
+    ) -> Result<GetSecretValueResponse, RusotoError<GetSecretValueError>> {
+        self.runtime.block_on(self.client.get_secret_value(input))
+    }
+
+    /// <p><p>Lists all of the versions attached to the specified secret. The output does not include the <code>SecretString</code> or <code>SecretBinary</code> fields. By default, the list includes only versions that have at least one staging label in <code>VersionStage</code> attached.</p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can occasionally return an empty or shorter than expected list of results even when there more results become available. When this happens, the <code>NextToken</code> response parameter contains a value to pass to the next call to the same API to request the next part of the list.</p> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:ListSecretVersionIds</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the secrets in an account, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::list_secret_version_ids`.
+    pub fn list_secret_version_ids(
+        &self,
+        input: ListSecretVersionIdsRequest,
+    ) -> Result<ListSecretVersionIdsResponse, RusotoError<ListSecretVersionIdsError>> {
+        self.runtime
+            .block_on(self.client.list_secret_version_ids(input))
+    }
+
+    /// <p><p>Lists all of the secrets that are stored by Secrets Manager in the AWS account. To list the versions currently stored for a specific secret, use <a>ListSecretVersionIds</a>. The encrypted fields <code>SecretString</code> and <code>SecretBinary</code> are not included in the output. To get that information, call the <a>GetSecretValue</a> operation.</p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can occasionally return an empty or shorter than expected list of results even when there more results become available. When this happens, the <code>NextToken</code> response parameter contains a value to pass to the next call to the same API to request the next part of the list.</p> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:ListSecrets</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the versions attached to a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::list_secrets`.
+    pub fn list_secrets(
+        &self,
+        input: ListSecretsRequest,
+    ) -> Result<ListSecretsResponse, RusotoError<ListSecretsError>> {
+        self.runtime.block_on(self.client.list_secrets(input))
+    }
+
+    /// <p><p>Attaches the contents of the specified resource-based permission policy to a secret. A resource-based policy is optional. Alternatively, you can use IAM identity-based policies that specify the secret&#39;s Amazon Resource Name (ARN) in the policy statement&#39;s <code>Resources</code> element. You can also use a combination of both identity-based and resource-based policies. The affected users and roles receive the permissions that are permitted by all of the relevant policies. For more information, see <a href="http://docs.aws.amazon.com/secretsmanager/latest/userguide/auth-and-access_resource-based-policies.html">Using Resource-Based Policies for AWS Secrets Manager</a>. For the complete description of the AWS policy syntax and grammar, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies.html">IAM JSON Policy Reference</a> in the <i>IAM User Guide</i>.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:PutResourcePolicy</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To retrieve the resource policy attached to a secret, use <a>GetResourcePolicy</a>.</p> </li> <li> <p>To delete the resource-based policy that&#39;s attached to a secret, use <a>DeleteResourcePolicy</a>.</p> </li> <li> <p>To list all of the currently available secrets, use <a>ListSecrets</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::put_resource_policy`.
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// <p><p>Stores a new encrypted secret value in the specified secret. To do this, the operation creates a new version and attaches it to the secret. The version can contain a new <code>SecretString</code> value or a new <code>SecretBinary</code> value. You can also specify the staging labels that are initially attached to the new version.</p> <note> <p>The Secrets Manager console uses only the <code>SecretString</code> field. To add binary data to a secret with the <code>SecretBinary</code> field you must use the AWS CLI or one of the AWS SDKs.</p> </note> <ul> <li> <p>If this operation creates the first version for the secret then Secrets Manager automatically attaches the staging label <code>AWSCURRENT</code> to the new version.</p> </li> <li> <p>If another version of this secret already exists, then this operation does not automatically move any staging labels other than those that you explicitly specify in the <code>VersionStages</code> parameter.</p> </li> <li> <p>If this operation moves the staging label <code>AWSCURRENT</code> from another version to this version (because you included it in the <code>StagingLabels</code> parameter) then Secrets Manager also automatically moves the staging label <code>AWSPREVIOUS</code> to the version that <code>AWSCURRENT</code> was removed from.</p> </li> <li> <p>This operation is idempotent. If a version with a <code>VersionId</code> with the same value as the <code>ClientRequestToken</code> parameter already exists and you specify the same secret data, the operation succeeds but does nothing. However, if the secret data is different, then the operation fails because you cannot modify an existing version; you can only create new ones.</p> </li> </ul> <note> <ul> <li> <p>If you call an operation to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS creating the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret resides in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:PutSecretValue</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a customer-managed AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s default AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To retrieve the encrypted value you store in the version of a secret, use <a>GetSecretValue</a>.</p> </li> <li> <p>To create a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To get the details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list the versions attached to a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::put_secret_value`.
+    pub fn put_secret_value(
+        &self,
+        input: PutSecretValueRequest,
+    ) -> Result<PutSecretValueResponse, RusotoError<PutSecretValueError>> {
+        self.runtime.block_on(self.client.put_secret_value(input))
+    }
+
+    /// <p><p>Cancels the scheduled deletion of a secret by removing the <code>DeletedDate</code> time stamp. This makes the secret accessible to query once again.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:RestoreSecret</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To delete a secret, use <a>DeleteSecret</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::restore_secret`.
+    pub fn restore_secret(
+        &self,
+        input: RestoreSecretRequest,
+    ) -> Result<RestoreSecretResponse, RusotoError<RestoreSecretError>> {
+        self.runtime.block_on(self.client.restore_secret(input))
+    }
+
+    /// <p><p>Configures and starts the asynchronous process of rotating this secret. If you include the configuration parameters, the operation sets those values for the secret and then immediately starts a rotation. If you do not include the configuration parameters, the operation starts a rotation with the values already stored in the secret. After the rotation completes, the protected service and its clients all use the new version of the secret. </p> <p>This required configuration information includes the ARN of an AWS Lambda function and the time between scheduled rotations. The Lambda rotation function creates a new version of the secret and creates or updates the credentials on the protected service to match. After testing the new credentials, the function marks the new secret with the staging label <code>AWSCURRENT</code> so that your clients all immediately begin to use the new version. For more information about rotating secrets and how to configure a Lambda function to rotate the secrets for your protected service, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/rotating-secrets.html">Rotating Secrets in AWS Secrets Manager</a> in the <i>AWS Secrets Manager User Guide</i>.</p> <p>Secrets Manager schedules the next rotation when the previous one completes. Secrets Manager schedules the date by adding the rotation interval (number of days) to the actual date of the last rotation. The service chooses the hour within that 24-hour date window randomly. The minute is also chosen somewhat randomly, but weighted towards the top of the hour and influenced by a variety of factors that help distribute load.</p> <p>The rotation function must end with the versions of the secret in one of two states:</p> <ul> <li> <p>The <code>AWSPENDING</code> and <code>AWSCURRENT</code> staging labels are attached to the same version of the secret, or</p> </li> <li> <p>The <code>AWSPENDING</code> staging label is not attached to any version of the secret.</p> </li> </ul> <p>If the <code>AWSPENDING</code> staging label is present but not attached to the same version as <code>AWSCURRENT</code> then any later invocation of <code>RotateSecret</code> assumes that a previous rotation request is still in progress and returns an error.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:RotateSecret</p> </li> <li> <p>lambda:InvokeFunction (on the function specified in the secret&#39;s metadata)</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To list the secrets in your account, use <a>ListSecrets</a>.</p> </li> <li> <p>To get the details for a version of a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To create a new version of a secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To attach staging labels to or remove staging labels from a version of a secret, use <a>UpdateSecretVersionStage</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::rotate_secret`.
+    pub fn rotate_secret(
+        &self,
+        input: RotateSecretRequest,
+    ) -> Result<RotateSecretResponse, RusotoError<RotateSecretError>> {
+        self.runtime.block_on(self.client.rotate_secret(input))
+    }
+
+    /// <p><p>Attaches one or more tags, each consisting of a key name and a value, to the specified secret. Tags are part of the secret&#39;s overall metadata, and are not associated with any specific version of the secret. This operation only appends tags to the existing list of tags. To remove tags, you must use <a>UntagResource</a>.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per secret—50</p> </li> <li> <p>Maximum key length—127 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length—255 Unicode characters in UTF-8</p> </li> <li> <p>Tag keys and values are case sensitive.</p> </li> <li> <p>Do not use the <code>aws:</code> prefix in your tag names or values because AWS reserves it for AWS use. You can&#39;t edit or delete tag names or values with this prefix. Tags with this prefix do not count against your tags per secret limit.</p> </li> <li> <p>If you use your tagging schema across multiple services and resources, remember other services might have restrictions on allowed characters. Generally allowed characters: letters, spaces, and numbers representable in UTF-8, plus the following special characters: + - = . _ : / @.</p> </li> </ul> <important> <p>If you use tags as part of your security strategy, then adding or removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:TagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To remove one or more tags from the collection attached to a secret, use <a>UntagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p><p>Removes one or more tags from the specified secret.</p> <p>This operation is idempotent. If a requested tag is not attached to the secret, no error is returned and the secret metadata is unchanged.</p> <important> <p>If you use tags as part of your security strategy, then removing a tag can change permissions. If successfully completing this operation would result in you losing your permissions for this secret, then the operation is blocked and returns an Access Denied error.</p> </important> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UntagResource</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To add one or more tags to the collection attached to a secret, use <a>TagResource</a>.</p> </li> <li> <p>To view the list of tags attached to a secret, use <a>DescribeSecret</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Modifies many of the details of the specified secret. If you include a <code>ClientRequestToken</code> and <i>either</i> <code>SecretString</code> or <code>SecretBinary</code> then it also creates a new version attached to the secret.</p> <p>To modify the rotation configuration of a secret, use <a>RotateSecret</a> instead.</p> <note> <p>The Secrets Manager console uses only the <code>SecretString</code> parameter and therefore limits you to encrypting and storing only a text string. To encrypt and store binary data as part of the version of a secret, you must use either the AWS CLI or one of the AWS SDKs.</p> </note> <ul> <li> <p>If a version with a <code>VersionId</code> with the same value as the <code>ClientRequestToken</code> parameter already exists, the operation results in an error. You cannot modify an existing version, you can only create a new version.</p> </li> <li> <p>If you include <code>SecretString</code> or <code>SecretBinary</code> to create a new secret version, Secrets Manager automatically attaches the staging label <code>AWSCURRENT</code> to the new version. </p> </li> </ul> <note> <ul> <li> <p>If you call an operation to encrypt or decrypt the <code>SecretString</code> or <code>SecretBinary</code> for a secret in the same account as the calling user and that secret doesn&#39;t specify a AWS KMS encryption key, Secrets Manager uses the account&#39;s default AWS managed customer master key (CMK) with the alias <code>aws/secretsmanager</code>. If this key doesn&#39;t already exist in your account then Secrets Manager creates it for you automatically. All users and roles in the same AWS account automatically have access to use the default CMK. Note that if an Secrets Manager API call results in AWS creating the account&#39;s AWS-managed CMK, it can result in a one-time significant delay in returning the result.</p> </li> <li> <p>If the secret resides in a different AWS account from the credentials calling an API that requires encryption or decryption of the secret value then you must create and use a custom AWS KMS CMK because you can&#39;t access the default CMK for the account using credentials from a different AWS account. Store the ARN of the CMK in the secret when you create the secret or when you update it by including it in the <code>KMSKeyId</code>. If you call an API that must encrypt or decrypt <code>SecretString</code> or <code>SecretBinary</code> using credentials from a different account then the AWS KMS key policy must grant cross-account access to that other account&#39;s user or role for both the kms:GenerateDataKey and kms:Decrypt operations.</p> </li> </ul> </note> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UpdateSecret</p> </li> <li> <p>kms:GenerateDataKey - needed only if you use a custom AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s AWS managed CMK for Secrets Manager.</p> </li> <li> <p>kms:Decrypt - needed only if you use a custom AWS KMS key to encrypt the secret. You do not need this permission to use the account&#39;s AWS managed CMK for Secrets Manager.</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To create a new secret, use <a>CreateSecret</a>.</p> </li> <li> <p>To add only a new version to an existing secret, use <a>PutSecretValue</a>.</p> </li> <li> <p>To get the details for a secret, use <a>DescribeSecret</a>.</p> </li> <li> <p>To list the versions contained in a secret, use <a>ListSecretVersionIds</a>.</p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::update_secret`.
+    pub fn update_secret(
+        &self,
+        input: UpdateSecretRequest,
+    ) -> Result<UpdateSecretResponse, RusotoError<UpdateSecretError>> {
+        self.runtime.block_on(self.client.update_secret(input))
+    }
+
+    /// <p><p>Modifies the staging labels attached to a version of a secret. Staging labels are used to track a version as it progresses through the secret rotation process. You can attach a staging label to only one version of a secret at a time. If a staging label to be added is already attached to another version, then it is moved--removed from the other version first and then attached to this one. For more information about staging labels, see <a href="https://docs.aws.amazon.com/secretsmanager/latest/userguide/terms-concepts.html#term_staging-label">Staging Labels</a> in the <i>AWS Secrets Manager User Guide</i>. </p> <p>The staging labels that you specify in the <code>VersionStage</code> parameter are added to the existing list of staging labels--they don&#39;t replace it.</p> <p>You can move the <code>AWSCURRENT</code> staging label to this version by including it in this call.</p> <note> <p>Whenever you move <code>AWSCURRENT</code>, Secrets Manager automatically moves the label <code>AWSPREVIOUS</code> to the version that <code>AWSCURRENT</code> was removed from.</p> </note> <p>If this action results in the last label being removed from a version, then the version is considered to be &#39;deprecated&#39; and can be deleted by Secrets Manager.</p> <p> <b>Minimum permissions</b> </p> <p>To run this command, you must have the following permissions:</p> <ul> <li> <p>secretsmanager:UpdateSecretVersionStage</p> </li> </ul> <p> <b>Related operations</b> </p> <ul> <li> <p>To get the list of staging labels that are currently associated with a version of a secret, use <code> <a>DescribeSecret</a> </code> and examine the <code>SecretVersionsToStages</code> response value. </p> </li> </ul></p>
+    ///
+    /// See `SecretsManager::update_secret_version_stage`.
+    pub fn update_secret_version_stage(
+        &self,
+        input: UpdateSecretVersionStageRequest,
+    ) -> Result<UpdateSecretVersionStageResponse, RusotoError<UpdateSecretVersionStageError>> {
+        self.runtime
+            .block_on(self.client.update_secret_version_stage(input))
+    }
+
+    /// <p>Validates the JSON text of the resource-based policy document attached to the specified secret. The JSON request string input and response output displays formatted code with white space and line breaks for better readability. Submit your input as a single line JSON string. A resource-based policy is optional.</p>
+    ///
+    /// See `SecretsManager::validate_resource_policy`.
+    pub fn validate_resource_policy(
+        &self,
+        input: ValidateResourcePolicyRequest,
+    ) -> Result<ValidateResourcePolicyResponse, RusotoError<ValidateResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.validate_resource_policy(input))
+    }
+}
@@ -3711,19 +3711,28 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(AcceptInvitationError::Internal(err.msg))
+                    return RusotoError::service(&res, AcceptInvitationError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(AcceptInvitationError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptInvitationError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AcceptInvitationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, AcceptInvitationError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AcceptInvitationError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptInvitationError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptInvitationError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3763,16 +3772,28 @@ impl BatchDisableStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDisableStandardsError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDisableStandardsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDisableStandardsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchDisableStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchDisableStandardsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3811,16 +3832,25 @@ impl BatchEnableStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::Internal(err.msg))
+                    return RusotoError::service(&res, BatchEnableStandardsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchEnableStandardsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchEnableStandardsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchEnableStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchEnableStandardsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3859,16 +3889,25 @@ impl BatchImportFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchImportFindingsError::Internal(err.msg))
+                    return RusotoError::service(&res, BatchImportFindingsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchImportFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchImportFindingsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchImportFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchImportFindingsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchImportFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchImportFindingsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3907,16 +3946,25 @@ impl BatchUpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::Internal(err.msg))
+                    return RusotoError::service(&res, BatchUpdateFindingsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateFindingsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateFindingsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchUpdateFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        BatchUpdateFindingsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3957,19 +4005,31 @@ impl CreateActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateActionTargetError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateActionTargetError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateActionTargetError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateActionTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateActionTargetError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateActionTargetError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateActionTargetError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateActionTargetError::ResourceConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4011,19 +4071,22 @@ impl CreateInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateInsightError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateInsightError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, CreateInsightError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInsightError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateInsightError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateInsightError::LimitExceeded(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateInsightError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateInsightError::ResourceConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4065,19 +4128,22 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateMembersError::Internal(err.msg))
+                    return RusotoError::service(&res, CreateMembersError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(CreateMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, CreateMembersError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateMembersError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateMembersError::LimitExceeded(err.msg))
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateMembersError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMembersError::ResourceConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4117,16 +4183,25 @@ impl DeclineInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeclineInvitationsError::Internal(err.msg))
+                    return RusotoError::service(&res, DeclineInvitationsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeclineInvitationsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeclineInvitationsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeclineInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeclineInvitationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4165,16 +4240,25 @@ impl DeleteActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteActionTargetError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteActionTargetError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActionTargetError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActionTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteActionTargetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActionTargetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4215,19 +4299,22 @@ impl DeleteInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteInsightError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteInsightError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, DeleteInsightError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInsightError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteInsightError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteInsightError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInsightError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInsightError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4269,19 +4356,31 @@ impl DeleteInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteInvitationsError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteInvitationsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInvitationsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInvitationsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInvitationsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInvitationsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInvitationsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4323,19 +4422,22 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteMembersError::Internal(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DeleteMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DeleteMembersError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMembersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4375,18 +4477,28 @@ impl DescribeActionTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActionTargetsError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActionTargetsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActionTargetsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeActionTargetsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActionTargetsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4427,19 +4539,19 @@ impl DescribeHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeHubError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeHubError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeHubError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, DescribeHubError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHubError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeHubError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeHubError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, DescribeHubError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeHubError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeHubError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4479,16 +4591,22 @@ impl DescribeProductsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeProductsError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeProductsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeProductsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeProductsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DescribeProductsError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeProductsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4525,13 +4643,19 @@ impl DescribeStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeStandardsError::Internal(err.msg))
+                    return RusotoError::service(&res, DescribeStandardsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStandardsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStandardsError::InvalidInput(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4569,22 +4693,28 @@ impl DescribeStandardsControlsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStandardsControlsError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStandardsControlsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStandardsControlsError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStandardsControlsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStandardsControlsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4627,27 +4757,32 @@ impl DisableImportFindingsForProductError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableImportFindingsForProductError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableImportFindingsForProductError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableImportFindingsForProductError::InvalidAccess(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableImportFindingsForProductError::InvalidInput(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableImportFindingsForProductError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableImportFindingsForProductError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4695,16 +4830,25 @@ impl DisableSecurityHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisableSecurityHubError::Internal(err.msg))
+                    return RusotoError::service(&res, DisableSecurityHubError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisableSecurityHubError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableSecurityHubError::InvalidAccess(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableSecurityHubError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableSecurityHubError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableSecurityHubError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableSecurityHubError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4747,27 +4891,32 @@ impl DisassociateFromMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFromMasterAccountError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFromMasterAccountError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFromMasterAccountError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateFromMasterAccountError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateFromMasterAccountError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4813,21 +4962,31 @@ impl DisassociateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateMembersError::Internal(err.msg))
+                    return RusotoError::service(&res, DisassociateMembersError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(DisassociateMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembersError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembersError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisassociateMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembersError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateMembersError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMembersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4871,27 +5030,32 @@ impl EnableImportFindingsForProductError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(EnableImportFindingsForProductError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableImportFindingsForProductError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableImportFindingsForProductError::InvalidAccess(err.msg),
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableImportFindingsForProductError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableImportFindingsForProductError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableImportFindingsForProductError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableImportFindingsForProductError::ResourceConflict(err.msg),
                     )
                 }
@@ -4937,19 +5101,31 @@ impl EnableSecurityHubError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableSecurityHubError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableSecurityHubError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableSecurityHubError::Internal(err.msg))
+                    return RusotoError::service(&res, EnableSecurityHubError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(EnableSecurityHubError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableSecurityHubError::InvalidAccess(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableSecurityHubError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableSecurityHubError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(EnableSecurityHubError::ResourceConflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        EnableSecurityHubError::ResourceConflict(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4989,16 +5165,25 @@ impl GetEnabledStandardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::Internal(err.msg))
+                    return RusotoError::service(&res, GetEnabledStandardsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnabledStandardsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnabledStandardsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetEnabledStandardsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEnabledStandardsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5037,16 +5222,16 @@ impl GetFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetFindingsError::Internal(err.msg))
+                    return RusotoError::service(&res, GetFindingsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, GetFindingsError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetFindingsError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetFindingsError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5087,19 +5272,31 @@ impl GetInsightResultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInsightResultsError::Internal(err.msg))
+                    return RusotoError::service(&res, GetInsightResultsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInsightResultsError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightResultsError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInsightResultsError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightResultsError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInsightResultsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightResultsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetInsightResultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInsightResultsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5141,19 +5338,19 @@ impl GetInsightsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInsightsError::Internal(err.msg))
+                    return RusotoError::service(&res, GetInsightsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInsightsError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, GetInsightsError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInsightsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetInsightsError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInsightsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetInsightsError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetInsightsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetInsightsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5193,16 +5390,25 @@ impl GetInvitationsCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetInvitationsCountError::Internal(err.msg))
+                    return RusotoError::service(&res, GetInvitationsCountError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInvitationsCountError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInvitationsCountError::InvalidInput(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInvitationsCountError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInvitationsCountError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5243,19 +5449,28 @@ impl GetMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetMasterAccountError::Internal(err.msg))
+                    return RusotoError::service(&res, GetMasterAccountError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetMasterAccountError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMasterAccountError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMasterAccountError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMasterAccountError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMasterAccountError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMasterAccountError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMasterAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMasterAccountError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5297,19 +5512,19 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetMembersError::Internal(err.msg))
+                    return RusotoError::service(&res, GetMembersError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(GetMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, GetMembersError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetMembersError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetMembersError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, GetMembersError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5351,19 +5566,22 @@ impl InviteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(InviteMembersError::Internal(err.msg))
+                    return RusotoError::service(&res, InviteMembersError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(InviteMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, InviteMembersError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(InviteMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, InviteMembersError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(InviteMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, InviteMembersError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InviteMembersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        InviteMembersError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5403,19 +5621,22 @@ impl ListEnabledProductsForImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::Internal(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEnabledProductsForImportError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEnabledProductsForImportError::InvalidAccess(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListEnabledProductsForImportError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListEnabledProductsForImportError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5453,16 +5674,16 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListInvitationsError::Internal(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListInvitationsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListInvitationsError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5501,16 +5722,16 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListMembersError::Internal(err.msg))
+                    return RusotoError::service(&res, ListMembersError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(ListMembersError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, ListMembersError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMembersError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListMembersError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMembersError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, ListMembersError::LimitExceeded(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5547,15 +5768,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5591,13 +5816,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, TagResourceError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5633,13 +5858,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::Internal(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5677,16 +5905,25 @@ impl UpdateActionTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateActionTargetError::Internal(err.msg))
+                    return RusotoError::service(&res, UpdateActionTargetError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateActionTargetError::InvalidAccess(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateActionTargetError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateActionTargetError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateActionTargetError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateActionTargetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateActionTargetError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5727,19 +5964,22 @@ impl UpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateFindingsError::Internal(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateFindingsError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFindingsError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateFindingsError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFindingsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5781,19 +6021,22 @@ impl UpdateInsightError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateInsightError::Internal(err.msg))
+                    return RusotoError::service(&res, UpdateInsightError::Internal(err.msg))
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateInsightError::InvalidAccess(err.msg))
+                    return RusotoError::service(&res, UpdateInsightError::InvalidAccess(err.msg))
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateInsightError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateInsightError::InvalidInput(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateInsightError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateInsightError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInsightError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateInsightError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5833,20 +6076,28 @@ impl UpdateStandardsControlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::Internal(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStandardsControlError::Internal(err.msg),
+                    )
                 }
                 "InvalidAccessException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::InvalidAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStandardsControlError::InvalidAccess(err.msg),
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStandardsControlError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStandardsControlError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStandardsControlError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6145,6 +6396,7 @@ impl SecurityHubClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6160,6 +6412,7 @@ impl SecurityHubClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubClient {
         SecurityHubClient { client, region }
     }
@@ -6176,6 +6429,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("AcceptInvitation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6207,6 +6463,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/deregister";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("BatchDisableStandards");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6238,6 +6497,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/register";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("BatchEnableStandards");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6269,6 +6531,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings/import";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("BatchImportFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6300,6 +6565,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings/batchupdate";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("BatchUpdateFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6331,6 +6599,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/actionTargets";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("CreateActionTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6362,6 +6633,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/insights";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("CreateInsight");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6393,6 +6667,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("CreateMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6424,6 +6701,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/decline";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DeclineInvitations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6458,6 +6738,9 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DeleteActionTarget");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6486,6 +6769,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DeleteInsight");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6514,6 +6800,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DeleteInvitations");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6545,6 +6834,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/delete";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DeleteMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6576,6 +6868,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/actionTargets/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DescribeActionTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6607,6 +6902,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DescribeHub");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6641,6 +6939,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/products";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DescribeProducts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6678,6 +6979,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DescribeStandards");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6719,6 +7023,9 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DescribeStandardsControls");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6762,6 +7069,9 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DisableImportFindingsForProduct");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6791,6 +7101,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DisableSecurityHub");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6821,6 +7134,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DisassociateFromMasterAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6849,6 +7165,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/disassociate";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("DisassociateMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6883,6 +7202,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("EnableImportFindingsForProduct");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6914,6 +7236,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("EnableSecurityHub");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6945,6 +7270,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/standards/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetEnabledStandards");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6976,6 +7304,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetFindings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7010,6 +7341,9 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetInsightResults");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7038,6 +7372,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/insights/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetInsights");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7068,6 +7405,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations/count";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetInvitationsCount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7095,6 +7435,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/master";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetMasterAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7123,6 +7466,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/get";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("GetMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7154,6 +7500,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members/invite";
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("InviteMembers");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7186,6 +7535,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/productSubscriptions";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("ListEnabledProductsForImport");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7223,6 +7575,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/invitations";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("ListInvitations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7260,6 +7615,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/members";
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("ListMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7300,6 +7658,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7328,6 +7689,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7359,6 +7723,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7396,6 +7763,9 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("UpdateActionTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7427,6 +7797,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = "/findings";
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("UpdateFindings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7458,6 +7831,9 @@ impl SecurityHub for SecurityHubClient {
         let request_uri = format!("/insights/{insight_arn}", insight_arn = input.insight_arn);
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("UpdateInsight");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7492,6 +7868,9 @@ impl SecurityHub for SecurityHubClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "securityhub", &self.region, &request_uri);
+        request.set_operation_name("UpdateStandardsControl");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7514,3 +7893,500 @@ impl SecurityHub for SecurityHubClient {
         }
     }
 }
+
+/// A blocking client for the AWS SecurityHub API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SecurityHubClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SecurityHubBlockingClient {
+    client: SecurityHubClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SecurityHubBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SecurityHubBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SecurityHubBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SecurityHubBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SecurityHubBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SecurityHubBlockingClient {
+        SecurityHubBlockingClient {
+            client: SecurityHubClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts the invitation to be a member account and be monitored by the Security Hub master account that the invitation was sent from.</p> <p>When the member account accepts the invitation, permission is granted to the master account to view findings generated in the member account.</p>
+    ///
+    /// See `SecurityHub::accept_invitation`.
+    pub fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+        self.runtime.block_on(self.client.accept_invitation(input))
+    }
+
+    /// <p>Disables the standards specified by the provided <code>StandardsSubscriptionArns</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Security Standards</a> section of the <i>AWS Security Hub User Guide</i>.</p>
+    ///
+    /// See `SecurityHub::batch_disable_standards`.
+    pub fn batch_disable_standards(
+        &self,
+        input: BatchDisableStandardsRequest,
+    ) -> Result<BatchDisableStandardsResponse, RusotoError<BatchDisableStandardsError>> {
+        self.runtime
+            .block_on(self.client.batch_disable_standards(input))
+    }
+
+    /// <p>Enables the standards specified by the provided <code>StandardsArn</code>. To obtain the ARN for a standard, use the <code> <a>DescribeStandards</a> </code> operation.</p> <p>For more information, see the <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-standards.html">Security Standards</a> section of the <i>AWS Security Hub User Guide</i>.</p>
+    ///
+    /// See `SecurityHub::batch_enable_standards`.
+    pub fn batch_enable_standards(
+        &self,
+        input: BatchEnableStandardsRequest,
+    ) -> Result<BatchEnableStandardsResponse, RusotoError<BatchEnableStandardsError>> {
+        self.runtime
+            .block_on(self.client.batch_enable_standards(input))
+    }
+
+    /// <p><p>Imports security findings generated from an integrated third-party product into Security Hub. This action is requested by the integrated product to import its findings into Security Hub.</p> <p>The maximum allowed size for a finding is 240 Kb. An error is returned for any finding larger than 240 Kb.</p> <p>After a finding is created, <code>BatchImportFindings</code> cannot be used to update the following finding fields and objects, which Security Hub customers use to manage their investigation workflow.</p> <ul> <li> <p> <code>Confidence</code> </p> </li> <li> <p> <code>Criticality</code> </p> </li> <li> <p> <code>Note</code> </p> </li> <li> <p> <code>RelatedFindings</code> </p> </li> <li> <p> <code>Severity</code> </p> </li> <li> <p> <code>Types</code> </p> </li> <li> <p> <code>UserDefinedFields</code> </p> </li> <li> <p> <code>VerificationState</code> </p> </li> <li> <p> <code>Workflow</code> </p> </li> </ul></p>
+    ///
+    /// See `SecurityHub::batch_import_findings`.
+    pub fn batch_import_findings(
+        &self,
+        input: BatchImportFindingsRequest,
+    ) -> Result<BatchImportFindingsResponse, RusotoError<BatchImportFindingsError>> {
+        self.runtime
+            .block_on(self.client.batch_import_findings(input))
+    }
+
+    /// <p>Used by Security Hub customers to update information about their investigation into a finding. Requested by master accounts or member accounts. Master accounts can update findings for their account and their member accounts. Member accounts can update findings for their account.</p> <p>Updates from <code>BatchUpdateFindings</code> do not affect the value of <code>UpdatedAt</code> for a finding.</p> <p>Master accounts can use <code>BatchUpdateFindings</code> to update the following finding fields and objects.</p> <ul> <li> <p> <code>Confidence</code> </p> </li> <li> <p> <code>Criticality</code> </p> </li> <li> <p> <code>Note</code> </p> </li> <li> <p> <code>RelatedFindings</code> </p> </li> <li> <p> <code>Severity</code> </p> </li> <li> <p> <code>Types</code> </p> </li> <li> <p> <code>UserDefinedFields</code> </p> </li> <li> <p> <code>VerificationState</code> </p> </li> <li> <p> <code>Workflow</code> </p> </li> </ul> <p>Member accounts can only use <code>BatchUpdateFindings</code> to update the Note object.</p>
+    ///
+    /// See `SecurityHub::batch_update_findings`.
+    pub fn batch_update_findings(
+        &self,
+        input: BatchUpdateFindingsRequest,
+    ) -> Result<BatchUpdateFindingsResponse, RusotoError<BatchUpdateFindingsError>> {
+        self.runtime
+            .block_on(self.client.batch_update_findings(input))
+    }
+
+    /// <p>Creates a custom action target in Security Hub.</p> <p>You can use custom actions on findings and insights in Security Hub to trigger target actions in Amazon CloudWatch Events.</p>
+    ///
+    /// See `SecurityHub::create_action_target`.
+    pub fn create_action_target(
+        &self,
+        input: CreateActionTargetRequest,
+    ) -> Result<CreateActionTargetResponse, RusotoError<CreateActionTargetError>> {
+        self.runtime
+            .block_on(self.client.create_action_target(input))
+    }
+
+    /// <p>Creates a custom insight in Security Hub. An insight is a consolidation of findings that relate to a security issue that requires attention or remediation.</p> <p>To group the related findings in the insight, use the <code>GroupByAttribute</code>.</p>
+    ///
+    /// See `SecurityHub::create_insight`.
+    pub fn create_insight(
+        &self,
+        input: CreateInsightRequest,
+    ) -> Result<CreateInsightResponse, RusotoError<CreateInsightError>> {
+        self.runtime.block_on(self.client.create_insight(input))
+    }
+
+    /// <p>Creates a member association in Security Hub between the specified accounts and the account used to make the request, which is the master account. To successfully create a member, you must use this action from an account that already has Security Hub enabled. To enable Security Hub, you can use the <code> <a>EnableSecurityHub</a> </code> operation.</p> <p>After you use <code>CreateMembers</code> to create member account associations in Security Hub, you must use the <code> <a>InviteMembers</a> </code> operation to invite the accounts to enable Security Hub and become member accounts in Security Hub.</p> <p>If the account owner accepts the invitation, the account becomes a member account in Security Hub. A permissions policy is added that permits the master account to view the findings generated in the member account. When Security Hub is enabled in the invited account, findings start to be sent to both the member and master accounts.</p> <p>To remove the association between the master and member accounts, use the <code> <a>DisassociateFromMasterAccount</a> </code> or <code> <a>DisassociateMembers</a> </code> operation.</p>
+    ///
+    /// See `SecurityHub::create_members`.
+    pub fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.runtime.block_on(self.client.create_members(input))
+    }
+
+    /// <p>Declines invitations to become a member account.</p>
+    ///
+    /// See `SecurityHub::decline_invitations`.
+    pub fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+        self.runtime
+            .block_on(self.client.decline_invitations(input))
+    }
+
+    /// <p>Deletes a custom action target from Security Hub.</p> <p>Deleting a custom action target does not affect any findings or insights that were already sent to Amazon CloudWatch Events using the custom action.</p>
+    ///
+    /// See `SecurityHub::delete_action_target`.
+    pub fn delete_action_target(
+        &self,
+        input: DeleteActionTargetRequest,
+    ) -> Result<DeleteActionTargetResponse, RusotoError<DeleteActionTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_action_target(input))
+    }
+
+    /// <p>Deletes the insight specified by the <code>InsightArn</code>.</p>
+    ///
+    /// See `SecurityHub::delete_insight`.
+    pub fn delete_insight(
+        &self,
+        input: DeleteInsightRequest,
+    ) -> Result<DeleteInsightResponse, RusotoError<DeleteInsightError>> {
+        self.runtime.block_on(self.client.delete_insight(input))
+    }
+
+    /// <p>Deletes invitations received by the AWS account to become a member account.</p>
+    ///
+    /// See `SecurityHub::delete_invitations`.
+    pub fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+        self.runtime.block_on(self.client.delete_invitations(input))
+    }
+
+    /// <p>Deletes the specified member accounts from Security Hub.</p>
+    ///
+    /// See `SecurityHub::delete_members`.
+    pub fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.runtime.block_on(self.client.delete_members(input))
+    }
+
+    /// <p>Returns a list of the custom action targets in Security Hub in your account.</p>
+    ///
+    /// See `SecurityHub::describe_action_targets`.
+    pub fn describe_action_targets(
+        &self,
+        input: DescribeActionTargetsRequest,
+    ) -> Result<DescribeActionTargetsResponse, RusotoError<DescribeActionTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_action_targets(input))
+    }
+
+    /// <p>Returns details about the Hub resource in your account, including the <code>HubArn</code> and the time when you enabled Security Hub.</p>
+    ///
+    /// See `SecurityHub::describe_hub`.
+    pub fn describe_hub(
+        &self,
+        input: DescribeHubRequest,
+    ) -> Result<DescribeHubResponse, RusotoError<DescribeHubError>> {
+        self.runtime.block_on(self.client.describe_hub(input))
+    }
+
+    /// <p>Returns information about the available products that you can subscribe to and integrate with Security Hub in order to consolidate findings.</p>
+    ///
+    /// See `SecurityHub::describe_products`.
+    pub fn describe_products(
+        &self,
+        input: DescribeProductsRequest,
+    ) -> Result<DescribeProductsResponse, RusotoError<DescribeProductsError>> {
+        self.runtime.block_on(self.client.describe_products(input))
+    }
+
+    /// <p>Returns a list of the available standards in Security Hub.</p> <p>For each standard, the results include the standard ARN, the name, and a description. </p>
+    ///
+    /// See `SecurityHub::describe_standards`.
+    pub fn describe_standards(
+        &self,
+        input: DescribeStandardsRequest,
+    ) -> Result<DescribeStandardsResponse, RusotoError<DescribeStandardsError>> {
+        self.runtime.block_on(self.client.describe_standards(input))
+    }
+
+    /// <p>Returns a list of security standards controls.</p> <p>For each control, the results include information about whether it is currently enabled, the severity, and a link to remediation information.</p>
+    ///
+    /// See `SecurityHub::describe_standards_controls`.
+    pub fn describe_standards_controls(
+        &self,
+        input: DescribeStandardsControlsRequest,
+    ) -> Result<DescribeStandardsControlsResponse, RusotoError<DescribeStandardsControlsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_standards_controls(input))
+    }
+
+    /// <p>Disables the integration of the specified product with Security Hub. After the integration is disabled, findings from that product are no longer sent to Security Hub.</p>
+    ///
+    /// See `SecurityHub::disable_import_findings_for_product`.
+    pub fn disable_import_findings_for_product(
+        &self,
+        input: DisableImportFindingsForProductRequest,
+    ) -> Result<
+        DisableImportFindingsForProductResponse,
+        RusotoError<DisableImportFindingsForProductError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_import_findings_for_product(input))
+    }
+
+    /// <p>Disables Security Hub in your account only in the current Region. To disable Security Hub in all Regions, you must submit one request per Region where you have enabled Security Hub.</p> <p>When you disable Security Hub for a master account, it doesn't disable Security Hub for any associated member accounts.</p> <p>When you disable Security Hub, your existing findings and insights and any Security Hub configuration settings are deleted after 90 days and cannot be recovered. Any standards that were enabled are disabled, and your master and member account associations are removed.</p> <p>If you want to save your existing findings, you must export them before you disable Security Hub.</p>
+    ///
+    /// See `SecurityHub::disable_security_hub`.
+    pub fn disable_security_hub(
+        &self,
+    ) -> Result<DisableSecurityHubResponse, RusotoError<DisableSecurityHubError>> {
+        self.runtime.block_on(self.client.disable_security_hub())
+    }
+
+    /// <p>Disassociates the current Security Hub member account from the associated master account.</p>
+    ///
+    /// See `SecurityHub::disassociate_from_master_account`.
+    pub fn disassociate_from_master_account(
+        &self,
+    ) -> Result<
+        DisassociateFromMasterAccountResponse,
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_from_master_account())
+    }
+
+    /// <p>Disassociates the specified member accounts from the associated master account.</p>
+    ///
+    /// See `SecurityHub::disassociate_members`.
+    pub fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+        self.runtime
+            .block_on(self.client.disassociate_members(input))
+    }
+
+    /// <p>Enables the integration of a partner product with Security Hub. Integrated products send findings to Security Hub.</p> <p>When you enable a product integration, a permissions policy that grants permission for the product to send findings to Security Hub is applied.</p>
+    ///
+    /// See `SecurityHub::enable_import_findings_for_product`.
+    pub fn enable_import_findings_for_product(
+        &self,
+        input: EnableImportFindingsForProductRequest,
+    ) -> Result<
+        EnableImportFindingsForProductResponse,
+        RusotoError<EnableImportFindingsForProductError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_import_findings_for_product(input))
+    }
+
+    /// <p>Enables Security Hub for your account in the current Region or the Region you specify in the request.</p> <p>When you enable Security Hub, you grant to Security Hub the permissions necessary to gather findings from other services that are integrated with Security Hub.</p> <p>When you use the <code>EnableSecurityHub</code> operation to enable Security Hub, you also automatically enable the following standards.</p> <ul> <li> <p>CIS AWS Foundations</p> </li> <li> <p>AWS Foundational Security Best Practices</p> </li> </ul> <p>You do not enable the Payment Card Industry Data Security Standard (PCI DSS) standard. </p> <p>To not enable the automatically enabled standards, set <code>EnableDefaultStandards</code> to <code>false</code>.</p> <p>After you enable Security Hub, to enable a standard, use the <code> <a>BatchEnableStandards</a> </code> operation. To disable a standard, use the <code> <a>BatchDisableStandards</a> </code> operation.</p> <p>To learn more, see <a href="https://docs.aws.amazon.com/securityhub/latest/userguide/securityhub-settingup.html">Setting Up AWS Security Hub</a> in the <i>AWS Security Hub User Guide</i>.</p>
+    ///
+    /// See `SecurityHub::enable_security_hub`.
+    pub fn enable_security_hub(
+        &self,
+        input: EnableSecurityHubRequest,
+    ) -> Result<EnableSecurityHubResponse, RusotoError<EnableSecurityHubError>> {
+        self.runtime
+            .block_on(self.client.enable_security_hub(input))
+    }
+
+    /// <p>Returns a list of the standards that are currently enabled.</p>
+    ///
+    /// See `SecurityHub::get_enabled_standards`.
+    pub fn get_enabled_standards(
+        &self,
+        input: GetEnabledStandardsRequest,
+    ) -> Result<GetEnabledStandardsResponse, RusotoError<GetEnabledStandardsError>> {
+        self.runtime
+            .block_on(self.client.get_enabled_standards(input))
+    }
+
+    /// <p>Returns a list of findings that match the specified criteria.</p>
+    ///
+    /// See `SecurityHub::get_findings`.
+    pub fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+        self.runtime.block_on(self.client.get_findings(input))
+    }
+
+    /// <p>Lists the results of the Security Hub insight specified by the insight ARN.</p>
+    ///
+    /// See `SecurityHub::get_insight_results`.
+    pub fn get_insight_results(
+        &self,
+        input: GetInsightResultsRequest,
+    ) -> Result<GetInsightResultsResponse, RusotoError<GetInsightResultsError>> {
+        self.runtime
+            .block_on(self.client.get_insight_results(input))
+    }
+
+    /// <p>Lists and describes insights for the specified insight ARNs.</p>
+    ///
+    /// See `SecurityHub::get_insights`.
+    pub fn get_insights(
+        &self,
+        input: GetInsightsRequest,
+    ) -> Result<GetInsightsResponse, RusotoError<GetInsightsError>> {
+        self.runtime.block_on(self.client.get_insights(input))
+    }
+
+    /// <p>Returns the count of all Security Hub membership invitations that were sent to the current member account, not including the currently accepted invitation. </p>
+    ///
+    /// See `SecurityHub::get_invitations_count`.
+    pub fn get_invitations_count(
+        &self,
+    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        self.runtime.block_on(self.client.get_invitations_count())
+    }
+
+    /// <p>Provides the details for the Security Hub master account for the current member account. </p>
+    ///
+    /// See `SecurityHub::get_master_account`.
+    pub fn get_master_account(
+        &self,
+    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        self.runtime.block_on(self.client.get_master_account())
+    }
+
+    /// <p>Returns the details for the Security Hub member accounts for the specified account IDs.</p>
+    ///
+    /// See `SecurityHub::get_members`.
+    pub fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.runtime.block_on(self.client.get_members(input))
+    }
+
+    /// <p>Invites other AWS accounts to become member accounts for the Security Hub master account that the invitation is sent from.</p> <p>Before you can use this action to invite a member, you must first use the <code> <a>CreateMembers</a> </code> action to create the member account in Security Hub.</p> <p>When the account owner accepts the invitation to become a member account and enables Security Hub, the master account can view the findings generated from the member account.</p>
+    ///
+    /// See `SecurityHub::invite_members`.
+    pub fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+        self.runtime.block_on(self.client.invite_members(input))
+    }
+
+    /// <p>Lists all findings-generating solutions (products) that you are subscribed to receive findings from in Security Hub.</p>
+    ///
+    /// See `SecurityHub::list_enabled_products_for_import`.
+    pub fn list_enabled_products_for_import(
+        &self,
+        input: ListEnabledProductsForImportRequest,
+    ) -> Result<ListEnabledProductsForImportResponse, RusotoError<ListEnabledProductsForImportError>>
+    {
+        self.runtime
+            .block_on(self.client.list_enabled_products_for_import(input))
+    }
+
+    /// <p>Lists all Security Hub membership invitations that were sent to the current AWS account. </p>
+    ///
+    /// See `SecurityHub::list_invitations`.
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// <p>Lists details about all member accounts for the current Security Hub master account.</p>
+    ///
+    /// See `SecurityHub::list_members`.
+    pub fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// <p>Returns a list of tags associated with a resource.</p>
+    ///
+    /// See `SecurityHub::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Adds one or more tags to a resource.</p>
+    ///
+    /// See `SecurityHub::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a resource.</p>
+    ///
+    /// See `SecurityHub::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the name and description of a custom action target in Security Hub.</p>
+    ///
+    /// See `SecurityHub::update_action_target`.
+    pub fn update_action_target(
+        &self,
+        input: UpdateActionTargetRequest,
+    ) -> Result<UpdateActionTargetResponse, RusotoError<UpdateActionTargetError>> {
+        self.runtime
+            .block_on(self.client.update_action_target(input))
+    }
+
+    /// <p> <code>UpdateFindings</code> is deprecated. Instead of <code>UpdateFindings</code>, use <code>BatchUpdateFindings</code>.</p> <p>Updates the <code>Note</code> and <code>RecordState</code> of the Security Hub-aggregated findings that the filter attributes specify. Any member account that can view the finding also sees the update to the finding.</p>
+    ///
+    /// See `SecurityHub::update_findings`.
+    pub fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<UpdateFindingsResponse, RusotoError<UpdateFindingsError>> {
+        self.runtime.block_on(self.client.update_findings(input))
+    }
+
+    /// <p>Updates the Security Hub insight identified by the specified insight ARN.</p>
+    ///
+    /// See `SecurityHub::update_insight`.
+    pub fn update_insight(
+        &self,
+        input: UpdateInsightRequest,
+    ) -> Result<UpdateInsightResponse, RusotoError<UpdateInsightError>> {
+        self.runtime.block_on(self.client.update_insight(input))
+    }
+
+    /// <p>Used to control whether an individual security standard control is enabled or disabled.</p>
+    ///
+    /// See `SecurityHub::update_standards_control`.
+    pub fn update_standards_control(
+        &self,
+        input: UpdateStandardsControlRequest,
+    ) -> Result<UpdateStandardsControlResponse, RusotoError<UpdateStandardsControlError>> {
+        self.runtime
+            .block_on(self.client.update_standards_control(input))
+    }
+}
@@ -1057,21 +1057,25 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateApplicationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApplicationError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateApplicationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, CreateApplicationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::InternalServerError(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1113,23 +1117,34 @@ impl CreateApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationVersionError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationVersionError::Conflict(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationVersionError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateApplicationVersionError::InternalServerError(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApplicationVersionError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateApplicationVersionError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1171,22 +1186,26 @@ impl CreateCloudFormationChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCloudFormationChangeSetError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationChangeSetError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateCloudFormationChangeSetError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationChangeSetError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCloudFormationChangeSetError::InternalServerError(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCloudFormationChangeSetError::TooManyRequests(err.msg),
                     )
                 }
@@ -1235,27 +1254,32 @@ impl CreateCloudFormationTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCloudFormationTemplateError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationTemplateError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateCloudFormationTemplateError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationTemplateError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCloudFormationTemplateError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateCloudFormationTemplateError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCloudFormationTemplateError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCloudFormationTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -1303,24 +1327,28 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteApplicationError::Conflict(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteApplicationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteApplicationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1363,19 +1391,25 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetApplicationError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, GetApplicationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetApplicationError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApplicationError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApplicationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1417,23 +1451,31 @@ impl GetApplicationPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationPolicyError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationPolicyError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetApplicationPolicyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApplicationPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetApplicationPolicyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1475,25 +1517,34 @@ impl GetCloudFormationTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationTemplateError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationTemplateError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetCloudFormationTemplateError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationTemplateError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCloudFormationTemplateError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCloudFormationTemplateError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1539,29 +1590,34 @@ impl ListApplicationDependenciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationDependenciesError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationDependenciesError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListApplicationDependenciesError::InternalServerError(err.msg),
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationDependenciesError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListApplicationDependenciesError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationDependenciesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1605,23 +1661,34 @@ impl ListApplicationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationVersionsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationVersionsError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationVersionsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationVersionsError::NotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListApplicationVersionsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationVersionsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1661,18 +1728,19 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListApplicationsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListApplicationsError::Forbidden(err.msg))
+                    return RusotoError::service(&res, ListApplicationsError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListApplicationsError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApplicationsError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListApplicationsError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1713,23 +1781,31 @@ impl PutApplicationPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutApplicationPolicyError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::Forbidden(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutApplicationPolicyError::Forbidden(err.msg),
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutApplicationPolicyError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::NotFound(err.msg))
+                    return RusotoError::service(&res, PutApplicationPolicyError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutApplicationPolicyError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutApplicationPolicyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1771,21 +1847,25 @@ impl UnshareApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UnshareApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UnshareApplicationError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UnshareApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UnshareApplicationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UnshareApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UnshareApplicationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UnshareApplicationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UnshareApplicationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnshareApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UnshareApplicationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1829,24 +1909,28 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateApplicationError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApplicationError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateApplicationError::Conflict(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateApplicationError::Forbidden(err.msg))
+                    return RusotoError::service(&res, UpdateApplicationError::Forbidden(err.msg))
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::InternalServerError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateApplicationError::NotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApplicationError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateApplicationError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1980,6 +2064,7 @@ impl ServerlessRepoClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1995,6 +2080,7 @@ impl ServerlessRepoClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ServerlessRepoClient {
         ServerlessRepoClient { client, region }
     }
@@ -2011,6 +2097,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("CreateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2046,6 +2135,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("CreateApplicationVersion");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2083,6 +2175,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("CreateCloudFormationChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2118,6 +2213,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("CreateCloudFormationTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2153,6 +2251,9 @@ impl ServerlessRepo for ServerlessRepoClient {
 
         let mut request =
             SignedRequest::new("DELETE", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("DeleteApplication");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2183,6 +2284,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("GetApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2220,6 +2324,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("GetApplicationPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2253,6 +2360,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("GetCloudFormationTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2285,6 +2395,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("ListApplicationDependencies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2328,6 +2441,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("ListApplicationVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2365,6 +2481,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("ListApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2408,6 +2527,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PUT", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("PutApplicationPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2442,6 +2564,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("POST", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("UnshareApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2475,6 +2600,9 @@ impl ServerlessRepo for ServerlessRepoClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "serverlessrepo", &self.region, &request_uri);
+        request.set_operation_name("UpdateApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2497,3 +2625,211 @@ impl ServerlessRepo for ServerlessRepoClient {
         }
     }
 }
+
+/// A blocking client for the AWSServerlessApplicationRepository API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ServerlessRepoClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServerlessRepoBlockingClient {
+    client: ServerlessRepoClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServerlessRepoBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServerlessRepoBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ServerlessRepoBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServerlessRepoBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServerlessRepoBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ServerlessRepoBlockingClient {
+        ServerlessRepoBlockingClient {
+            client: ServerlessRepoClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an application, optionally including an AWS SAM file to create the first application version in the same call.</p>
+    ///
+    /// See `ServerlessRepo::create_application`.
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// <p>Creates an application version.</p>
+    ///
+    /// See `ServerlessRepo::create_application_version`.
+    pub fn create_application_version(
+        &self,
+        input: CreateApplicationVersionRequest,
+    ) -> Result<CreateApplicationVersionResponse, RusotoError<CreateApplicationVersionError>> {
+        self.runtime
+            .block_on(self.client.create_application_version(input))
+    }
+
+    /// <p>Creates an AWS CloudFormation change set for the given application.</p>
+    ///
+    /// See `ServerlessRepo::create_cloud_formation_change_set`.
+    pub fn create_cloud_formation_change_set(
+        &self,
+        input: CreateCloudFormationChangeSetRequest,
+    ) -> Result<
+        CreateCloudFormationChangeSetResponse,
+        RusotoError<CreateCloudFormationChangeSetError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_cloud_formation_change_set(input))
+    }
+
+    /// <p>Creates an AWS CloudFormation template.</p>
+    ///
+    /// See `ServerlessRepo::create_cloud_formation_template`.
+    pub fn create_cloud_formation_template(
+        &self,
+        input: CreateCloudFormationTemplateRequest,
+    ) -> Result<CreateCloudFormationTemplateResponse, RusotoError<CreateCloudFormationTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cloud_formation_template(input))
+    }
+
+    /// <p>Deletes the specified application.</p>
+    ///
+    /// See `ServerlessRepo::delete_application`.
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// <p>Gets the specified application.</p>
+    ///
+    /// See `ServerlessRepo::get_application`.
+    pub fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<GetApplicationResponse, RusotoError<GetApplicationError>> {
+        self.runtime.block_on(self.client.get_application(input))
+    }
+
+    /// <p>Retrieves the policy for the application.</p>
+    ///
+    /// See `ServerlessRepo::get_application_policy`.
+    pub fn get_application_policy(
+        &self,
+        input: GetApplicationPolicyRequest,
+    ) -> Result<GetApplicationPolicyResponse, RusotoError<GetApplicationPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_application_policy(input))
+    }
+
+    /// <p>Gets the specified AWS CloudFormation template.</p>
+    ///
+    /// See `ServerlessRepo::get_cloud_formation_template`.
+    pub fn get_cloud_formation_template(
+        &self,
+        input: GetCloudFormationTemplateRequest,
+    ) -> Result<GetCloudFormationTemplateResponse, RusotoError<GetCloudFormationTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.get_cloud_formation_template(input))
+    }
+
+    /// <p>Retrieves the list of applications nested in the containing application.</p>
+    ///
+    /// See `ServerlessRepo::list_application_dependencies`.
+    pub fn list_application_dependencies(
+        &self,
+        input: ListApplicationDependenciesRequest,
+    ) -> Result<ListApplicationDependenciesResponse, RusotoError<ListApplicationDependenciesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_application_dependencies(input))
+    }
+
+    /// <p>Lists versions for the specified application.</p>
+    ///
+    /// See `ServerlessRepo::list_application_versions`.
+    pub fn list_application_versions(
+        &self,
+        input: ListApplicationVersionsRequest,
+    ) -> Result<ListApplicationVersionsResponse, RusotoError<ListApplicationVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_application_versions(input))
+    }
+
+    /// <p>Lists applications owned by the requester.</p>
+    ///
+    /// See `ServerlessRepo::list_applications`.
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// <p>Sets the permission policy for an application. For the list of actions supported for this operation, see
+    /// <a href="https://docs.aws.amazon.com/serverlessrepo/latest/devguide/access-control-resource-based.html#application-permissions">Application
+    /// Permissions</a>
+    /// .</p>
+    ///
+    /// See `ServerlessRepo::put_application_policy`.
+    pub fn put_application_policy(
+        &self,
+        input: PutApplicationPolicyRequest,
+    ) -> Result<PutApplicationPolicyResponse, RusotoError<PutApplicationPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_application_policy(input))
+    }
+
+    /// <p>Unshares an application from an AWS Organization.</p><p>This operation can be called only from the organization's master account.</p>
+    ///
+    /// See `ServerlessRepo::unshare_application`.
+    pub fn unshare_application(
+        &self,
+        input: UnshareApplicationRequest,
+    ) -> Result<(), RusotoError<UnshareApplicationError>> {
+        self.runtime
+            .block_on(self.client.unshare_application(input))
+    }
+
+    /// <p>Updates the specified application.</p>
+    ///
+    /// See `ServerlessRepo::update_application`.
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
@@ -662,44 +662,52 @@ impl AssociateServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateServiceQuotaTemplateError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateServiceQuotaTemplateError::AccessDenied(err.msg),
+                    )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceQuotaTemplateError::NoAvailableOrganization(err.msg),
                     )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceQuotaTemplateError::OrganizationNotInAllFeaturesMode(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AssociateServiceQuotaTemplateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateServiceQuotaTemplateError::Service(err.msg),
+                    )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceQuotaTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -767,15 +775,15 @@ impl DeleteServiceQuotaIncreaseRequestFromTemplateError {
     ) -> RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "AWSServiceAccessNotEnabledException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(err.msg)),
-"AccessDeniedException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg)),
-"DependencyAccessDeniedException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(err.msg)),
-"IllegalArgumentException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg)),
-"NoAvailableOrganizationException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(err.msg)),
-"NoSuchResourceException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg)),
-"ServiceException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg)),
-"TemplatesNotAvailableInRegionException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(err.msg)),
-"TooManyRequestsException" => return RusotoError::Service(DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg)),
+                                "AWSServiceAccessNotEnabledException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(err.msg)),
+"AccessDeniedException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg)),
+"DependencyAccessDeniedException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(err.msg)),
+"IllegalArgumentException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg)),
+"NoAvailableOrganizationException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(err.msg)),
+"NoSuchResourceException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg)),
+"ServiceException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg)),
+"TemplatesNotAvailableInRegionException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(err.msg)),
+"TooManyRequestsException" => return RusotoError::service(&res, DeleteServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg)),
 "ValidationException" => return RusotoError::Validation(err.msg),
 _ => {}
                             }
@@ -846,46 +854,54 @@ impl DisassociateServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::AWSServiceAccessNotEnabled(err.msg),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::AccessDenied(err.msg),
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::NoAvailableOrganization(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisassociateServiceQuotaTemplateError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateServiceQuotaTemplateError::Service(err.msg),
+                    )
                 }
                 "ServiceQuotaTemplateNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(
                             err.msg,
                         ),
                     )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::TemplatesNotAvailableInRegion(
                             err.msg,
                         ),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceQuotaTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -946,27 +962,34 @@ impl GetAWSDefaultServiceQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAWSDefaultServiceQuotaError::AccessDenied(err.msg),
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::IllegalArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAWSDefaultServiceQuotaError::IllegalArgument(err.msg),
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::NoSuchResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAWSDefaultServiceQuotaError::NoSuchResource(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAWSDefaultServiceQuotaError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAWSDefaultServiceQuotaError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAWSDefaultServiceQuotaError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1016,50 +1039,58 @@ impl GetAssociationForServiceQuotaTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::AccessDenied(err.msg),
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::DependencyAccessDenied(err.msg),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::Service(err.msg),
                     )
                 }
                 "ServiceQuotaTemplateNotInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::ServiceQuotaTemplateNotInUse(
                             err.msg,
                         ),
                     )
                 }
                 "TemplatesNotAvailableInRegionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::TemplatesNotAvailableInRegion(
                             err.msg,
                         ),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAssociationForServiceQuotaTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -1124,27 +1155,32 @@ impl GetRequestedServiceQuotaChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRequestedServiceQuotaChangeError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestedServiceQuotaChangeError::AccessDenied(err.msg),
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRequestedServiceQuotaChangeError::IllegalArgument(err.msg),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRequestedServiceQuotaChangeError::NoSuchResource(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRequestedServiceQuotaChangeError::Service(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRequestedServiceQuotaChangeError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRequestedServiceQuotaChangeError::TooManyRequests(err.msg),
                     )
                 }
@@ -1194,19 +1230,28 @@ impl GetServiceQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetServiceQuotaError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, GetServiceQuotaError::AccessDenied(err.msg))
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(GetServiceQuotaError::IllegalArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceQuotaError::IllegalArgument(err.msg),
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(GetServiceQuotaError::NoSuchResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceQuotaError::NoSuchResource(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetServiceQuotaError::Service(err.msg))
+                    return RusotoError::service(&res, GetServiceQuotaError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetServiceQuotaError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceQuotaError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1258,53 +1303,62 @@ impl GetServiceQuotaIncreaseRequestFromTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::AccessDenied(err.msg),
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::DependencyAccessDenied(
                             err.msg,
                         ),
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::IllegalArgument(err.msg),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::NoSuchResource(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::Service(err.msg),
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service(
+                    &res,
                     GetServiceQuotaIncreaseRequestFromTemplateError::TemplatesNotAvailableInRegion(
                         err.msg,
                     ),
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetServiceQuotaIncreaseRequestFromTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -1374,32 +1428,40 @@ impl ListAWSDefaultServiceQuotasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAWSDefaultServiceQuotasError::AccessDenied(err.msg),
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::IllegalArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAWSDefaultServiceQuotasError::IllegalArgument(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAWSDefaultServiceQuotasError::InvalidPaginationToken(err.msg),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::NoSuchResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAWSDefaultServiceQuotasError::NoSuchResource(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAWSDefaultServiceQuotasError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAWSDefaultServiceQuotasError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAWSDefaultServiceQuotasError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1448,34 +1510,40 @@ impl ListRequestedServiceQuotaChangeHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryError::AccessDenied(err.msg),
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryError::IllegalArgument(err.msg),
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryError::InvalidPaginationToken(
                             err.msg,
                         ),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryError::NoSuchResource(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryError::Service(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryError::TooManyRequests(err.msg),
                     )
                 }
@@ -1536,36 +1604,42 @@ impl ListRequestedServiceQuotaChangeHistoryByQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::AccessDenied(err.msg),
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::IllegalArgument(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::InvalidPaginationToken(
                             err.msg,
                         ),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::NoSuchResource(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::Service(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListRequestedServiceQuotaChangeHistoryByQuotaError::TooManyRequests(
                             err.msg,
                         ),
@@ -1632,48 +1706,56 @@ impl ListServiceQuotaIncreaseRequestsInTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::AccessDenied(err.msg),
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::DependencyAccessDenied(
                             err.msg,
                         ),
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::IllegalArgument(err.msg),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::Service(err.msg),
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service(
+                    &res,
                     ListServiceQuotaIncreaseRequestsInTemplateError::TemplatesNotAvailableInRegion(
                         err.msg,
                     ),
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceQuotaIncreaseRequestsInTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -1738,24 +1820,37 @@ impl ListServiceQuotasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListServiceQuotasError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServiceQuotasError::AccessDenied(err.msg),
+                    )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListServiceQuotasError::IllegalArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServiceQuotasError::IllegalArgument(err.msg),
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListServiceQuotasError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServiceQuotasError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(ListServiceQuotasError::NoSuchResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServiceQuotasError::NoSuchResource(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListServiceQuotasError::Service(err.msg))
+                    return RusotoError::service(&res, ListServiceQuotasError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListServiceQuotasError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServiceQuotasError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1798,19 +1893,22 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListServicesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListServicesError::AccessDenied(err.msg))
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(ListServicesError::IllegalArgument(err.msg))
+                    return RusotoError::service(&res, ListServicesError::IllegalArgument(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListServicesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServicesError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListServicesError::Service(err.msg))
+                    return RusotoError::service(&res, ListServicesError::Service(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListServicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListServicesError::TooManyRequests(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1864,58 +1962,68 @@ impl PutServiceQuotaIncreaseRequestIntoTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSServiceAccessNotEnabledException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::AWSServiceAccessNotEnabled(
                             err.msg,
                         ),
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::AccessDenied(err.msg),
                     )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::DependencyAccessDenied(
                             err.msg,
                         ),
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::IllegalArgument(err.msg),
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::NoAvailableOrganization(
                             err.msg,
                         ),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::NoSuchResource(err.msg),
                     )
                 }
                 "QuotaExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::QuotaExceeded(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::Service(err.msg),
                     )
                 }
-                "TemplatesNotAvailableInRegionException" => return RusotoError::Service(
+                "TemplatesNotAvailableInRegionException" => return RusotoError::service(
+                    &res,
                     PutServiceQuotaIncreaseRequestIntoTemplateError::TemplatesNotAvailableInRegion(
                         err.msg,
                     ),
                 ),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutServiceQuotaIncreaseRequestIntoTemplateError::TooManyRequests(err.msg),
                     )
                 }
@@ -1994,47 +2102,58 @@ impl RequestServiceQuotaIncreaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestServiceQuotaIncreaseError::AccessDenied(err.msg),
+                    )
                 }
                 "DependencyAccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RequestServiceQuotaIncreaseError::DependencyAccessDenied(err.msg),
                     )
                 }
                 "IllegalArgumentException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::IllegalArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestServiceQuotaIncreaseError::IllegalArgument(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RequestServiceQuotaIncreaseError::InvalidResourceState(err.msg),
                     )
                 }
                 "NoSuchResourceException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::NoSuchResource(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestServiceQuotaIncreaseError::NoSuchResource(err.msg),
+                    )
                 }
                 "QuotaExceededException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::QuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestServiceQuotaIncreaseError::QuotaExceeded(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RequestServiceQuotaIncreaseError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::Service(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RequestServiceQuotaIncreaseError::Service(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RequestServiceQuotaIncreaseError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RequestServiceQuotaIncreaseError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2210,6 +2329,7 @@ impl ServiceQuotasClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2225,6 +2345,7 @@ impl ServiceQuotasClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceQuotasClient {
         ServiceQuotasClient { client, region }
     }
@@ -2240,6 +2361,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<AssociateServiceQuotaTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateServiceQuotaTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.AssociateServiceQuotaTemplate",
@@ -2264,6 +2388,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteServiceQuotaIncreaseRequestFromTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.DeleteServiceQuotaIncreaseRequestFromTemplate",
@@ -2291,6 +2418,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<DisassociateServiceQuotaTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateServiceQuotaTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.DisassociateServiceQuotaTemplate",
@@ -2316,6 +2446,9 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAWSDefaultServiceQuota");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetAWSDefaultServiceQuota",
@@ -2340,6 +2473,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetAssociationForServiceQuotaTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAssociationForServiceQuotaTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetAssociationForServiceQuotaTemplate",
@@ -2367,6 +2503,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetRequestedServiceQuotaChangeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRequestedServiceQuotaChange");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetRequestedServiceQuotaChange",
@@ -2389,6 +2528,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: GetServiceQuotaRequest,
     ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetServiceQuota");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ServiceQuotasV20190624.GetServiceQuota");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2410,6 +2552,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetServiceQuotaIncreaseRequestFromTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.GetServiceQuotaIncreaseRequestFromTemplate",
@@ -2436,6 +2581,9 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAWSDefaultServiceQuotas");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListAWSDefaultServiceQuotas",
@@ -2461,6 +2609,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRequestedServiceQuotaChangeHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListRequestedServiceQuotaChangeHistory",
@@ -2489,6 +2640,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRequestedServiceQuotaChangeHistoryByQuota");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListRequestedServiceQuotaChangeHistoryByQuota",
@@ -2517,6 +2671,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServiceQuotaIncreaseRequestsInTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.ListServiceQuotaIncreaseRequestsInTemplate",
@@ -2542,6 +2699,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListServiceQuotasRequest,
     ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServiceQuotas");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServiceQuotas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2560,6 +2720,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "ServiceQuotasV20190624.ListServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2581,6 +2744,9 @@ impl ServiceQuotas for ServiceQuotasClient {
         RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutServiceQuotaIncreaseRequestIntoTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.PutServiceQuotaIncreaseRequestIntoTemplate",
@@ -2607,6 +2773,9 @@ impl ServiceQuotas for ServiceQuotasClient {
     ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RequestServiceQuotaIncrease");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "ServiceQuotasV20190624.RequestServiceQuotaIncrease",
@@ -2623,3 +2792,269 @@ impl ServiceQuotas for ServiceQuotasClient {
             .deserialize::<RequestServiceQuotaIncreaseResponse, _>()
     }
 }
+
+/// A blocking client for the Service Quotas API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ServiceQuotasClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServiceQuotasBlockingClient {
+    client: ServiceQuotasClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServiceQuotasBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServiceQuotasBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ServiceQuotasBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceQuotasBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServiceQuotasBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ServiceQuotasBlockingClient {
+        ServiceQuotasBlockingClient {
+            client: ServiceQuotasClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates the Service Quotas template with your organization so that when new accounts are created in your organization, the template submits increase requests for the specified service quotas. Use the Service Quotas template to request an increase for any adjustable quota value. After you define the Service Quotas template, use this operation to associate, or enable, the template. </p>
+    ///
+    /// See `ServiceQuotas::associate_service_quota_template`.
+    pub fn associate_service_quota_template(
+        &self,
+    ) -> Result<
+        AssociateServiceQuotaTemplateResponse,
+        RusotoError<AssociateServiceQuotaTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_service_quota_template())
+    }
+
+    /// <p>Removes a service quota increase request from the Service Quotas template. </p>
+    ///
+    /// See `ServiceQuotas::delete_service_quota_increase_request_from_template`.
+    pub fn delete_service_quota_increase_request_from_template(
+        &self,
+        input: DeleteServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        DeleteServiceQuotaIncreaseRequestFromTemplateResponse,
+        RusotoError<DeleteServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_service_quota_increase_request_from_template(input),
+        )
+    }
+
+    /// <p><p>Disables the Service Quotas template. Once the template is disabled, it does not request quota increases for new accounts in your organization. Disabling the quota template does not apply the quota increase requests from the template. </p> <p> <b>Related operations</b> </p> <ul> <li> <p>To enable the quota template, call <a>AssociateServiceQuotaTemplate</a>. </p> </li> <li> <p>To delete a specific service quota from the template, use <a>DeleteServiceQuotaIncreaseRequestFromTemplate</a>.</p> </li> </ul></p>
+    ///
+    /// See `ServiceQuotas::disassociate_service_quota_template`.
+    pub fn disassociate_service_quota_template(
+        &self,
+    ) -> Result<
+        DisassociateServiceQuotaTemplateResponse,
+        RusotoError<DisassociateServiceQuotaTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_service_quota_template())
+    }
+
+    /// <p>Retrieves the default service quotas values. The Value returned for each quota is the AWS default value, even if the quotas have been increased.. </p>
+    ///
+    /// See `ServiceQuotas::get_aws_default_service_quota`.
+    pub fn get_aws_default_service_quota(
+        &self,
+        input: GetAWSDefaultServiceQuotaRequest,
+    ) -> Result<GetAWSDefaultServiceQuotaResponse, RusotoError<GetAWSDefaultServiceQuotaError>>
+    {
+        self.runtime
+            .block_on(self.client.get_aws_default_service_quota(input))
+    }
+
+    /// <p>Retrieves the <code>ServiceQuotaTemplateAssociationStatus</code> value from the service. Use this action to determine if the Service Quota template is associated, or enabled. </p>
+    ///
+    /// See `ServiceQuotas::get_association_for_service_quota_template`.
+    pub fn get_association_for_service_quota_template(
+        &self,
+    ) -> Result<
+        GetAssociationForServiceQuotaTemplateResponse,
+        RusotoError<GetAssociationForServiceQuotaTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_association_for_service_quota_template())
+    }
+
+    /// <p>Retrieves the details for a particular increase request. </p>
+    ///
+    /// See `ServiceQuotas::get_requested_service_quota_change`.
+    pub fn get_requested_service_quota_change(
+        &self,
+        input: GetRequestedServiceQuotaChangeRequest,
+    ) -> Result<
+        GetRequestedServiceQuotaChangeResponse,
+        RusotoError<GetRequestedServiceQuotaChangeError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_requested_service_quota_change(input))
+    }
+
+    /// <p>Returns the details for the specified service quota. This operation provides a different Value than the <code>GetAWSDefaultServiceQuota</code> operation. This operation returns the applied value for each quota. <code>GetAWSDefaultServiceQuota</code> returns the default AWS value for each quota. </p>
+    ///
+    /// See `ServiceQuotas::get_service_quota`.
+    pub fn get_service_quota(
+        &self,
+        input: GetServiceQuotaRequest,
+    ) -> Result<GetServiceQuotaResponse, RusotoError<GetServiceQuotaError>> {
+        self.runtime.block_on(self.client.get_service_quota(input))
+    }
+
+    /// <p>Returns the details of the service quota increase request in your template.</p>
+    ///
+    /// See `ServiceQuotas::get_service_quota_increase_request_from_template`.
+    pub fn get_service_quota_increase_request_from_template(
+        &self,
+        input: GetServiceQuotaIncreaseRequestFromTemplateRequest,
+    ) -> Result<
+        GetServiceQuotaIncreaseRequestFromTemplateResponse,
+        RusotoError<GetServiceQuotaIncreaseRequestFromTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_service_quota_increase_request_from_template(input),
+        )
+    }
+
+    /// <p><p>Lists all default service quotas for the specified AWS service or all AWS services. ListAWSDefaultServiceQuotas is similar to <a>ListServiceQuotas</a> except for the Value object. The Value object returned by <code>ListAWSDefaultServiceQuotas</code> is the default value assigned by AWS. This request returns a list of all service quotas for the specified service. The listing of each you&#39;ll see the default values are the values that AWS provides for the quotas. </p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can return an unexpected list of results, even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass the next call to the same API to request the next part of the list.</p> </note></p>
+    ///
+    /// See `ServiceQuotas::list_aws_default_service_quotas`.
+    pub fn list_aws_default_service_quotas(
+        &self,
+        input: ListAWSDefaultServiceQuotasRequest,
+    ) -> Result<ListAWSDefaultServiceQuotasResponse, RusotoError<ListAWSDefaultServiceQuotasError>>
+    {
+        self.runtime
+            .block_on(self.client.list_aws_default_service_quotas(input))
+    }
+
+    /// <p>Requests a list of the changes to quotas for a service.</p>
+    ///
+    /// See `ServiceQuotas::list_requested_service_quota_change_history`.
+    pub fn list_requested_service_quota_change_history(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryRequest,
+    ) -> Result<
+        ListRequestedServiceQuotaChangeHistoryResponse,
+        RusotoError<ListRequestedServiceQuotaChangeHistoryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_requested_service_quota_change_history(input),
+        )
+    }
+
+    /// <p>Requests a list of the changes to specific service quotas. This command provides additional granularity over the <code>ListRequestedServiceQuotaChangeHistory</code> command. Once a quota change request has reached <code>CASE_CLOSED, APPROVED,</code> or <code>DENIED</code>, the history has been kept for 90 days.</p>
+    ///
+    /// See `ServiceQuotas::list_requested_service_quota_change_history_by_quota`.
+    pub fn list_requested_service_quota_change_history_by_quota(
+        &self,
+        input: ListRequestedServiceQuotaChangeHistoryByQuotaRequest,
+    ) -> Result<
+        ListRequestedServiceQuotaChangeHistoryByQuotaResponse,
+        RusotoError<ListRequestedServiceQuotaChangeHistoryByQuotaError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_requested_service_quota_change_history_by_quota(input),
+        )
+    }
+
+    /// <p>Returns a list of the quota increase requests in the template. </p>
+    ///
+    /// See `ServiceQuotas::list_service_quota_increase_requests_in_template`.
+    pub fn list_service_quota_increase_requests_in_template(
+        &self,
+        input: ListServiceQuotaIncreaseRequestsInTemplateRequest,
+    ) -> Result<
+        ListServiceQuotaIncreaseRequestsInTemplateResponse,
+        RusotoError<ListServiceQuotaIncreaseRequestsInTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_service_quota_increase_requests_in_template(input),
+        )
+    }
+
+    /// <p><p>Lists all service quotas for the specified AWS service. This request returns a list of the service quotas for the specified service. you&#39;ll see the default values are the values that AWS provides for the quotas. </p> <note> <p>Always check the <code>NextToken</code> response parameter when calling any of the <code>List*</code> operations. These operations can return an unexpected list of results, even when there are more results available. When this happens, the <code>NextToken</code> response parameter contains a value to pass the next call to the same API to request the next part of the list.</p> </note></p>
+    ///
+    /// See `ServiceQuotas::list_service_quotas`.
+    pub fn list_service_quotas(
+        &self,
+        input: ListServiceQuotasRequest,
+    ) -> Result<ListServiceQuotasResponse, RusotoError<ListServiceQuotasError>> {
+        self.runtime
+            .block_on(self.client.list_service_quotas(input))
+    }
+
+    /// <p>Lists the AWS services available in Service Quotas. Not all AWS services are available in Service Quotas. To list the see the list of the service quotas for a specific service, use <a>ListServiceQuotas</a>.</p>
+    ///
+    /// See `ServiceQuotas::list_services`.
+    pub fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.runtime.block_on(self.client.list_services(input))
+    }
+
+    /// <p>Defines and adds a quota to the service quota template. To add a quota to the template, you must provide the <code>ServiceCode</code>, <code>QuotaCode</code>, <code>AwsRegion</code>, and <code>DesiredValue</code>. Once you add a quota to the template, use <a>ListServiceQuotaIncreaseRequestsInTemplate</a> to see the list of quotas in the template.</p>
+    ///
+    /// See `ServiceQuotas::put_service_quota_increase_request_into_template`.
+    pub fn put_service_quota_increase_request_into_template(
+        &self,
+        input: PutServiceQuotaIncreaseRequestIntoTemplateRequest,
+    ) -> Result<
+        PutServiceQuotaIncreaseRequestIntoTemplateResponse,
+        RusotoError<PutServiceQuotaIncreaseRequestIntoTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_service_quota_increase_request_into_template(input),
+        )
+    }
+
+    /// <p>Retrieves the details of a service quota increase request. The response to this command provides the details in the <a>RequestedServiceQuotaChange</a> object. </p>
+    ///
+    /// See `ServiceQuotas::request_service_quota_increase`.
+    pub fn request_service_quota_increase(
+        &self,
+        input: RequestServiceQuotaIncreaseRequest,
+    ) -> Result<RequestServiceQuotaIncreaseResponse, RusotoError<RequestServiceQuotaIncreaseError>>
+    {
+        self.runtime
+            .block_on(self.client.request_service_quota_increase(input))
+    }
+}
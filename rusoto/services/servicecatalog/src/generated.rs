@@ -3808,17 +3808,22 @@ impl AcceptPortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptPortfolioShareError::InvalidParameters(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AcceptPortfolioShareError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptPortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AcceptPortfolioShareError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3858,22 +3863,26 @@ impl AssociateBudgetWithResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateBudgetWithResourceError::DuplicateResource(err.msg),
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateBudgetWithResourceError::InvalidParameters(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateBudgetWithResourceError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateBudgetWithResourceError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateBudgetWithResourceError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3918,17 +3927,20 @@ impl AssociatePrincipalWithPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePrincipalWithPortfolioError::InvalidParameters(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePrincipalWithPortfolioError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociatePrincipalWithPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3974,17 +3986,20 @@ impl AssociateProductWithPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProductWithPortfolioError::InvalidParameters(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateProductWithPortfolioError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateProductWithPortfolioError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProductWithPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4028,19 +4043,22 @@ impl AssociateServiceActionWithProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceActionWithProvisioningArtifactError::DuplicateResource(
                             err.msg,
                         ),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceActionWithProvisioningArtifactError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateServiceActionWithProvisioningArtifactError::ResourceNotFound(
                             err.msg,
                         ),
@@ -4094,32 +4112,38 @@ impl AssociateTagOptionWithResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTagOptionWithResourceError::DuplicateResource(err.msg),
                     )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTagOptionWithResourceError::InvalidParameters(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(AssociateTagOptionWithResourceError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateTagOptionWithResourceError::InvalidState(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTagOptionWithResourceError::LimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTagOptionWithResourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateTagOptionWithResourceError::TagOptionNotMigrated(err.msg),
                     )
                 }
@@ -4166,7 +4190,8 @@ impl BatchAssociateServiceActionWithProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         BatchAssociateServiceActionWithProvisioningArtifactError::InvalidParameters(
                             err.msg,
                         ),
@@ -4203,7 +4228,8 @@ impl BatchDisassociateServiceActionFromProvisioningArtifactError {
     ) -> RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "InvalidParametersException" => return RusotoError::Service(
+                "InvalidParametersException" => return RusotoError::service(
+                    &res,
                     BatchDisassociateServiceActionFromProvisioningArtifactError::InvalidParameters(
                         err.msg,
                     ),
@@ -4240,10 +4266,10 @@ impl CopyProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CopyProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(&res, CopyProductError::InvalidParameters(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CopyProductError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4280,16 +4306,28 @@ impl CreateConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateConstraintError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConstraintError::DuplicateResource(err.msg),
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConstraintError::InvalidParameters(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConstraintError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConstraintError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateConstraintError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4326,15 +4364,19 @@ impl CreatePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreatePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioError::InvalidParameters(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePortfolioError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreatePortfolioError::LimitExceeded(err.msg))
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreatePortfolioError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4374,25 +4416,34 @@ impl CreatePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioShareError::InvalidParameters(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioShareError::InvalidState(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioShareError::LimitExceeded(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioShareError::OperationNotSupported(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePortfolioShareError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4430,13 +4481,19 @@ impl CreateProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProductError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateProductError::LimitExceeded(err.msg))
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreateProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProductError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4474,17 +4531,20 @@ impl CreateProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisionedProductPlanError::InvalidParameters(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateProvisionedProductPlanError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisionedProductPlanError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4528,19 +4588,22 @@ impl CreateProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateProvisioningArtifactError::InvalidParameters(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProvisioningArtifactError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningArtifactError::LimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProvisioningArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProvisioningArtifactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4574,12 +4637,16 @@ impl CreateServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(CreateServiceActionError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceActionError::InvalidParameters(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateServiceActionError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceActionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4614,15 +4681,19 @@ impl CreateTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateTagOptionError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagOptionError::DuplicateResource(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTagOptionError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateTagOptionError::LimitExceeded(err.msg))
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(CreateTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagOptionError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4656,10 +4727,16 @@ impl DeleteConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeleteConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConstraintError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteConstraintError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4696,18 +4773,25 @@ impl DeletePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeletePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePortfolioError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeletePortfolioError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeletePortfolioError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4746,22 +4830,28 @@ impl DeletePortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioShareError::InvalidParameters(err.msg),
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::InvalidState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioShareError::InvalidState(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioShareError::OperationNotSupported(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePortfolioShareError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4800,16 +4890,25 @@ impl DeleteProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DeleteProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProductError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteProductError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeleteProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProductError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4846,12 +4945,14 @@ impl DeleteProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisionedProductPlanError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
@@ -4894,19 +4995,22 @@ impl DeleteProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteProvisioningArtifactError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProvisioningArtifactError::ResourceInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningArtifactError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProvisioningArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProvisioningArtifactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4940,12 +5044,16 @@ impl DeleteServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteServiceActionError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServiceActionError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServiceActionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4980,15 +5088,19 @@ impl DeleteTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteTagOptionError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteTagOptionError::ResourceInUse(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagOptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DeleteTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagOptionError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5020,7 +5132,10 @@ impl DescribeConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeConstraintError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5050,9 +5165,10 @@ impl DescribeCopyProductStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCopyProductStatusError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCopyProductStatusError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5082,7 +5198,10 @@ impl DescribePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribePortfolioError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5118,17 +5237,20 @@ impl DescribePortfolioShareStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePortfolioShareStatusError::InvalidParameters(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePortfolioShareStatusError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribePortfolioShareStatusError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5170,10 +5292,16 @@ impl DescribeProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5206,14 +5334,16 @@ impl DescribeProductAsAdminError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductAsAdminError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductAsAdminError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductAsAdminError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductAsAdminError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5246,14 +5376,16 @@ impl DescribeProductViewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(DescribeProductViewError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductViewError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProductViewError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProductViewError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5286,9 +5418,10 @@ impl DescribeProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProvisionedProductError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProvisionedProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5322,12 +5455,14 @@ impl DescribeProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisionedProductPlanError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5368,12 +5503,14 @@ impl DescribeProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningArtifactError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningArtifactError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5414,12 +5551,14 @@ impl DescribeProvisioningParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningParametersError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeProvisioningParametersError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5456,7 +5595,10 @@ impl DescribeRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRecordError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5486,9 +5628,10 @@ impl DescribeServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServiceActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServiceActionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5522,12 +5665,14 @@ impl DescribeServiceActionExecutionParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeServiceActionExecutionParametersError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeServiceActionExecutionParametersError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5566,12 +5711,16 @@ impl DescribeTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagOptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(DescribeTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTagOptionError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5608,17 +5757,20 @@ impl DisableAWSOrganizationsAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DisableAWSOrganizationsAccessError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableAWSOrganizationsAccessError::InvalidState(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableAWSOrganizationsAccessError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5658,7 +5810,8 @@ impl DisassociateBudgetFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateBudgetFromResourceError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5696,12 +5849,14 @@ impl DisassociatePrincipalFromPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePrincipalFromPortfolioError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociatePrincipalFromPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5744,17 +5899,20 @@ impl DisassociateProductFromPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateProductFromPortfolioError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateProductFromPortfolioError::ResourceInUse(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateProductFromPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5796,7 +5954,8 @@ impl DisassociateServiceActionFromProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateServiceActionFromProvisioningArtifactError::ResourceNotFound(
                             err.msg,
                         ),
@@ -5836,12 +5995,14 @@ impl DisassociateTagOptionFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateTagOptionFromResourceError::ResourceNotFound(err.msg),
                     )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateTagOptionFromResourceError::TagOptionNotMigrated(err.msg),
                     )
                 }
@@ -5884,17 +6045,20 @@ impl EnableAWSOrganizationsAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(EnableAWSOrganizationsAccessError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableAWSOrganizationsAccessError::InvalidState(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableAWSOrganizationsAccessError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         EnableAWSOrganizationsAccessError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5938,17 +6102,20 @@ impl ExecuteProvisionedProductPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExecuteProvisionedProductPlanError::InvalidParameters(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ExecuteProvisionedProductPlanError::InvalidState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ExecuteProvisionedProductPlanError::InvalidState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExecuteProvisionedProductPlanError::ResourceNotFound(err.msg),
                     )
                 }
@@ -5992,17 +6159,20 @@ impl ExecuteProvisionedProductServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExecuteProvisionedProductServiceActionError::InvalidParameters(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExecuteProvisionedProductServiceActionError::InvalidState(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ExecuteProvisionedProductServiceActionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6046,12 +6216,14 @@ impl GetAWSOrganizationsAccessStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAWSOrganizationsAccessStatusError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAWSOrganizationsAccessStatusError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6092,12 +6264,14 @@ impl ListAcceptedPortfolioSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAcceptedPortfolioSharesError::InvalidParameters(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAcceptedPortfolioSharesError::OperationNotSupported(err.msg),
                     )
                 }
@@ -6136,14 +6310,16 @@ impl ListBudgetsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListBudgetsForResourceError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBudgetsForResourceError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBudgetsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListBudgetsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6178,12 +6354,14 @@ impl ListConstraintsForPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListConstraintsForPortfolioError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListConstraintsForPortfolioError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6220,10 +6398,16 @@ impl ListLaunchPathsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListLaunchPathsError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLaunchPathsError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLaunchPathsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLaunchPathsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6260,17 +6444,20 @@ impl ListOrganizationPortfolioAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationPortfolioAccessError::InvalidParameters(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationPortfolioAccessError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOrganizationPortfolioAccessError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6312,14 +6499,16 @@ impl ListPortfolioAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfolioAccessError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPortfolioAccessError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPortfolioAccessError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPortfolioAccessError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6350,7 +6539,10 @@ impl ListPortfoliosError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfoliosError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListPortfoliosError::InvalidParameters(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6382,14 +6574,16 @@ impl ListPortfoliosForProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListPortfoliosForProductError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPortfoliosForProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPortfoliosForProductError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPortfoliosForProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6424,14 +6618,16 @@ impl ListPrincipalsForPortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListPrincipalsForPortfolioError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalsForPortfolioError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListPrincipalsForPortfolioError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6466,12 +6662,14 @@ impl ListProvisionedProductPlansError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedProductPlansError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisionedProductPlansError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6508,14 +6706,16 @@ impl ListProvisioningArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListProvisioningArtifactsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningArtifactsError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProvisioningArtifactsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProvisioningArtifactsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6550,12 +6750,14 @@ impl ListProvisioningArtifactsForServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisioningArtifactsForServiceActionError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListProvisioningArtifactsForServiceActionError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6592,7 +6794,10 @@ impl ListRecordHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListRecordHistoryError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRecordHistoryError::InvalidParameters(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6626,17 +6831,20 @@ impl ListResourcesForTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListResourcesForTagOptionError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesForTagOptionError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListResourcesForTagOptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesForTagOptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourcesForTagOptionError::TagOptionNotMigrated(err.msg),
                     )
                 }
@@ -6672,9 +6880,10 @@ impl ListServiceActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListServiceActionsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListServiceActionsError::InvalidParameters(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6708,12 +6917,14 @@ impl ListServiceActionsForProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceActionsForProvisioningArtifactError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListServiceActionsForProvisioningArtifactError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6754,12 +6965,14 @@ impl ListStackInstancesForProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListStackInstancesForProvisionedProductError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListStackInstancesForProvisionedProductError::ResourceNotFound(err.msg),
                     )
                 }
@@ -6798,10 +7011,16 @@ impl ListTagOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ListTagOptionsError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagOptionsError::InvalidParameters(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(ListTagOptionsError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagOptionsError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6836,13 +7055,22 @@ impl ProvisionProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(ProvisionProductError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionProductError::DuplicateResource(err.msg),
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ProvisionProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ProvisionProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ProvisionProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6874,9 +7102,10 @@ impl RejectPortfolioShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectPortfolioShareError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RejectPortfolioShareError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6906,9 +7135,10 @@ impl ScanProvisionedProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(ScanProvisionedProductsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ScanProvisionedProductsError::InvalidParameters(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6938,7 +7168,10 @@ impl SearchProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProductsError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SearchProductsError::InvalidParameters(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6970,14 +7203,16 @@ impl SearchProductsAsAdminError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProductsAsAdminError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchProductsAsAdminError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchProductsAsAdminError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchProductsAsAdminError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7008,9 +7243,10 @@ impl SearchProvisionedProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(SearchProvisionedProductsError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SearchProvisionedProductsError::InvalidParameters(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7042,7 +7278,8 @@ impl TerminateProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TerminateProvisionedProductError::ResourceNotFound(err.msg),
                     )
                 }
@@ -7076,10 +7313,16 @@ impl UpdateConstraintError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateConstraintError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConstraintError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConstraintError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateConstraintError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7116,18 +7359,25 @@ impl UpdatePortfolioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdatePortfolioError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePortfolioError::InvalidParameters(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePortfolioError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdatePortfolioError::LimitExceeded(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePortfolioError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePortfolioError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdatePortfolioError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePortfolioError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7164,13 +7414,22 @@ impl UpdateProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateProductError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProductError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateProductError::TagOptionNotMigrated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProductError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7204,14 +7463,16 @@ impl UpdateProvisionedProductError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateProvisionedProductError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisionedProductError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisionedProductError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisionedProductError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7248,17 +7509,20 @@ impl UpdateProvisionedProductPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateProvisionedProductPropertiesError::InvalidParameters(err.msg),
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateProvisionedProductPropertiesError::InvalidState(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateProvisionedProductPropertiesError::ResourceNotFound(err.msg),
                     )
                 }
@@ -7302,14 +7566,16 @@ impl UpdateProvisioningArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateProvisioningArtifactError::InvalidParameters(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisioningArtifactError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateProvisioningArtifactError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7342,14 +7608,16 @@ impl UpdateServiceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateServiceActionError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceActionError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceActionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceActionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7386,18 +7654,28 @@ impl UpdateTagOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(UpdateTagOptionError::DuplicateResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagOptionError::DuplicateResource(err.msg),
+                    )
                 }
                 "InvalidParametersException" => {
-                    return RusotoError::Service(UpdateTagOptionError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagOptionError::InvalidParameters(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTagOptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagOptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TagOptionNotMigratedException" => {
-                    return RusotoError::Service(UpdateTagOptionError::TagOptionNotMigrated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateTagOptionError::TagOptionNotMigrated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7994,6 +8272,7 @@ impl ServiceCatalogClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -8009,6 +8288,7 @@ impl ServiceCatalogClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogClient {
         ServiceCatalogClient { client, region }
     }
@@ -8022,6 +8302,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: AcceptPortfolioShareInput,
     ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AcceptPortfolioShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AcceptPortfolioShare",
@@ -8044,6 +8327,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateBudgetWithResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateBudgetWithResource",
@@ -8069,6 +8355,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociatePrincipalWithPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociatePrincipalWithPortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociatePrincipalWithPortfolio",
@@ -8092,6 +8381,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateProductWithPortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateProductWithPortfolio",
@@ -8117,6 +8409,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateServiceActionWithProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateServiceActionWithProvisioningArtifact",
@@ -8145,6 +8440,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<AssociateTagOptionWithResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateTagOptionWithResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.AssociateTagOptionWithResource",
@@ -8170,6 +8468,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchAssociateServiceActionWithProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.BatchAssociateServiceActionWithProvisioningArtifact",
@@ -8198,6 +8499,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("BatchDisassociateServiceActionFromProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.BatchDisassociateServiceActionFromProvisioningArtifact",
@@ -8223,6 +8527,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CopyProductInput,
     ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CopyProduct");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CopyProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8241,6 +8548,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateConstraintInput,
     ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateConstraint");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateConstraint",
@@ -8262,6 +8572,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreatePortfolioInput,
     ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreatePortfolio",
@@ -8283,6 +8596,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreatePortfolioShareInput,
     ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePortfolioShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreatePortfolioShare",
@@ -8304,6 +8620,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateProductInput,
     ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProduct");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.CreateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8323,6 +8642,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProvisionedProductPlan");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateProvisionedProductPlan",
@@ -8346,6 +8668,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateProvisioningArtifact",
@@ -8368,6 +8693,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateServiceActionInput,
     ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateServiceAction");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateServiceAction",
@@ -8389,6 +8717,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: CreateTagOptionInput,
     ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTagOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.CreateTagOption",
@@ -8410,6 +8741,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteConstraintInput,
     ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteConstraint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteConstraint",
@@ -8431,6 +8765,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeletePortfolioInput,
     ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeletePortfolio",
@@ -8452,6 +8789,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeletePortfolioShareInput,
     ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePortfolioShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeletePortfolioShare",
@@ -8473,6 +8813,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteProductInput,
     ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProduct");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DeleteProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8492,6 +8835,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProvisionedProductPlan");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteProvisionedProductPlan",
@@ -8515,6 +8861,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteProvisioningArtifact",
@@ -8537,6 +8886,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteServiceActionInput,
     ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteServiceAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteServiceAction",
@@ -8558,6 +8910,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DeleteTagOptionInput,
     ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTagOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DeleteTagOption",
@@ -8579,6 +8934,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeConstraintInput,
     ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeConstraint");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeConstraint",
@@ -8600,6 +8958,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeCopyProductStatusInput,
     ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCopyProductStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeCopyProductStatus",
@@ -8622,6 +8983,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribePortfolioInput,
     ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePortfolio");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribePortfolio",
@@ -8644,6 +9008,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePortfolioShareStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribePortfolioShareStatus",
@@ -8666,6 +9033,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductInput,
     ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProduct");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProduct",
@@ -8687,6 +9057,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductAsAdminInput,
     ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProductAsAdmin");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProductAsAdmin",
@@ -8709,6 +9082,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeProductViewInput,
     ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProductView");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProductView",
@@ -8731,6 +9107,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProvisionedProduct");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisionedProduct",
@@ -8756,6 +9135,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeProvisionedProductPlanError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProvisionedProductPlan");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisionedProductPlan",
@@ -8779,6 +9161,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProvisioningArtifact");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisioningArtifact",
@@ -8804,6 +9189,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeProvisioningParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProvisioningParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeProvisioningParameters",
@@ -8826,6 +9214,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeRecordInput,
     ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeRecord");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.DescribeRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8844,6 +9235,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeServiceActionInput,
     ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServiceAction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeServiceAction",
@@ -8868,6 +9262,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DescribeServiceActionExecutionParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServiceActionExecutionParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeServiceActionExecutionParameters",
@@ -8893,6 +9290,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: DescribeTagOptionInput,
     ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTagOption");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DescribeTagOption",
@@ -8914,6 +9314,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableAWSOrganizationsAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisableAWSOrganizationsAccess",
@@ -8938,6 +9341,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateBudgetFromResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateBudgetFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateBudgetFromResource",
@@ -8963,6 +9369,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociatePrincipalFromPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociatePrincipalFromPortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociatePrincipalFromPortfolio",
@@ -8991,6 +9400,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateProductFromPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateProductFromPortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateProductFromPortfolio",
@@ -9019,6 +9431,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateServiceActionFromProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateServiceActionFromProvisioningArtifact",
@@ -9047,6 +9462,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<DisassociateTagOptionFromResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateTagOptionFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.DisassociateTagOptionFromResource",
@@ -9072,6 +9490,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableAWSOrganizationsAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.EnableAWSOrganizationsAccess",
@@ -9094,6 +9515,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExecuteProvisionedProductPlan");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ExecuteProvisionedProductPlan",
@@ -9119,6 +9543,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ExecuteProvisionedProductServiceActionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ExecuteProvisionedProductServiceAction");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ExecuteProvisionedProductServiceAction",
@@ -9146,6 +9573,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<GetAWSOrganizationsAccessStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAWSOrganizationsAccessStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.GetAWSOrganizationsAccessStatus",
@@ -9168,6 +9598,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAcceptedPortfolioShares");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListAcceptedPortfolioShares",
@@ -9190,6 +9623,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListBudgetsForResourceInput,
     ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListBudgetsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListBudgetsForResource",
@@ -9213,6 +9649,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListConstraintsForPortfolio");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListConstraintsForPortfolio",
@@ -9235,6 +9674,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListLaunchPathsInput,
     ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLaunchPaths");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListLaunchPaths",
@@ -9259,6 +9701,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListOrganizationPortfolioAccessError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOrganizationPortfolioAccess");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListOrganizationPortfolioAccess",
@@ -9281,6 +9726,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfolioAccessInput,
     ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPortfolioAccess");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListPortfolioAccess",
@@ -9302,6 +9750,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfoliosInput,
     ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPortfolios");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListPortfolios");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9320,6 +9771,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListPortfoliosForProductInput,
     ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPortfoliosForProduct");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListPortfoliosForProduct",
@@ -9343,6 +9797,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListPrincipalsForPortfolio");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListPrincipalsForPortfolio",
@@ -9366,6 +9823,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProvisionedProductPlans");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListProvisionedProductPlans",
@@ -9388,6 +9848,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListProvisioningArtifactsInput,
     ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProvisioningArtifacts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListProvisioningArtifacts",
@@ -9413,6 +9876,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListProvisioningArtifactsForServiceActionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProvisioningArtifactsForServiceAction");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListProvisioningArtifactsForServiceAction",
@@ -9438,6 +9904,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListRecordHistoryInput,
     ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRecordHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListRecordHistory",
@@ -9459,6 +9928,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListResourcesForTagOptionInput,
     ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourcesForTagOption");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListResourcesForTagOption",
@@ -9481,6 +9953,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListServiceActionsInput,
     ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServiceActions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListServiceActions",
@@ -9505,6 +9980,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListServiceActionsForProvisioningArtifactError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServiceActionsForProvisioningArtifact");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListServiceActionsForProvisioningArtifact",
@@ -9533,6 +10011,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<ListStackInstancesForProvisionedProductError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListStackInstancesForProvisionedProduct");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ListStackInstancesForProvisionedProduct",
@@ -9558,6 +10039,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ListTagOptionsInput,
     ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagOptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.ListTagOptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9576,6 +10060,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ProvisionProductInput,
     ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ProvisionProduct");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ProvisionProduct",
@@ -9597,6 +10084,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: RejectPortfolioShareInput,
     ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RejectPortfolioShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.RejectPortfolioShare",
@@ -9618,6 +10108,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: ScanProvisionedProductsInput,
     ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ScanProvisionedProducts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.ScanProvisionedProducts",
@@ -9640,6 +10133,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProductsInput,
     ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchProducts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.SearchProducts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9658,6 +10154,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProductsAsAdminInput,
     ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchProductsAsAdmin");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.SearchProductsAsAdmin",
@@ -9679,6 +10178,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: SearchProvisionedProductsInput,
     ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SearchProvisionedProducts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.SearchProvisionedProducts",
@@ -9702,6 +10204,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TerminateProvisionedProduct");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.TerminateProvisionedProduct",
@@ -9724,6 +10229,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateConstraintInput,
     ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateConstraint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateConstraint",
@@ -9745,6 +10253,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdatePortfolioInput,
     ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePortfolio");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdatePortfolio",
@@ -9766,6 +10277,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateProductInput,
     ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProduct");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWS242ServiceCatalogService.UpdateProduct");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9784,6 +10298,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateProvisionedProductInput,
     ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProvisionedProduct");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateProvisionedProduct",
@@ -9809,6 +10326,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         RusotoError<UpdateProvisionedProductPropertiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProvisionedProductProperties");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateProvisionedProductProperties",
@@ -9835,6 +10355,9 @@ impl ServiceCatalog for ServiceCatalogClient {
     ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateProvisioningArtifact");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateProvisioningArtifact",
@@ -9857,6 +10380,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateServiceActionInput,
     ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServiceAction");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateServiceAction",
@@ -9878,6 +10404,9 @@ impl ServiceCatalog for ServiceCatalogClient {
         input: UpdateTagOptionInput,
     ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateTagOption");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWS242ServiceCatalogService.UpdateTagOption",
@@ -9893,3 +10422,1038 @@ impl ServiceCatalog for ServiceCatalogClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTagOptionOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Service Catalog API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ServiceCatalogClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServiceCatalogBlockingClient {
+    client: ServiceCatalogClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServiceCatalogBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServiceCatalogBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ServiceCatalogBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceCatalogBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServiceCatalogBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ServiceCatalogBlockingClient {
+        ServiceCatalogBlockingClient {
+            client: ServiceCatalogClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Accepts an offer to share the specified portfolio.</p>
+    ///
+    /// See `ServiceCatalog::accept_portfolio_share`.
+    pub fn accept_portfolio_share(
+        &self,
+        input: AcceptPortfolioShareInput,
+    ) -> Result<AcceptPortfolioShareOutput, RusotoError<AcceptPortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.accept_portfolio_share(input))
+    }
+
+    /// <p>Associates the specified budget with the specified resource.</p>
+    ///
+    /// See `ServiceCatalog::associate_budget_with_resource`.
+    pub fn associate_budget_with_resource(
+        &self,
+        input: AssociateBudgetWithResourceInput,
+    ) -> Result<AssociateBudgetWithResourceOutput, RusotoError<AssociateBudgetWithResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_budget_with_resource(input))
+    }
+
+    /// <p>Associates the specified principal ARN with the specified portfolio.</p>
+    ///
+    /// See `ServiceCatalog::associate_principal_with_portfolio`.
+    pub fn associate_principal_with_portfolio(
+        &self,
+        input: AssociatePrincipalWithPortfolioInput,
+    ) -> Result<
+        AssociatePrincipalWithPortfolioOutput,
+        RusotoError<AssociatePrincipalWithPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_principal_with_portfolio(input))
+    }
+
+    /// <p>Associates the specified product with the specified portfolio.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::associate_product_with_portfolio`.
+    pub fn associate_product_with_portfolio(
+        &self,
+        input: AssociateProductWithPortfolioInput,
+    ) -> Result<AssociateProductWithPortfolioOutput, RusotoError<AssociateProductWithPortfolioError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_product_with_portfolio(input))
+    }
+
+    /// <p>Associates a self-service action with a provisioning artifact.</p>
+    ///
+    /// See `ServiceCatalog::associate_service_action_with_provisioning_artifact`.
+    pub fn associate_service_action_with_provisioning_artifact(
+        &self,
+        input: AssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        AssociateServiceActionWithProvisioningArtifactOutput,
+        RusotoError<AssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_service_action_with_provisioning_artifact(input),
+        )
+    }
+
+    /// <p>Associate the specified TagOption with the specified portfolio or product.</p>
+    ///
+    /// See `ServiceCatalog::associate_tag_option_with_resource`.
+    pub fn associate_tag_option_with_resource(
+        &self,
+        input: AssociateTagOptionWithResourceInput,
+    ) -> Result<
+        AssociateTagOptionWithResourceOutput,
+        RusotoError<AssociateTagOptionWithResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_tag_option_with_resource(input))
+    }
+
+    /// <p>Associates multiple self-service actions with provisioning artifacts.</p>
+    ///
+    /// See `ServiceCatalog::batch_associate_service_action_with_provisioning_artifact`.
+    pub fn batch_associate_service_action_with_provisioning_artifact(
+        &self,
+        input: BatchAssociateServiceActionWithProvisioningArtifactInput,
+    ) -> Result<
+        BatchAssociateServiceActionWithProvisioningArtifactOutput,
+        RusotoError<BatchAssociateServiceActionWithProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_associate_service_action_with_provisioning_artifact(input),
+        )
+    }
+
+    /// <p>Disassociates a batch of self-service actions from the specified provisioning artifact.</p>
+    ///
+    /// See `ServiceCatalog::batch_disassociate_service_action_from_provisioning_artifact`.
+    pub fn batch_disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: BatchDisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        BatchDisassociateServiceActionFromProvisioningArtifactOutput,
+        RusotoError<BatchDisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_disassociate_service_action_from_provisioning_artifact(input),
+        )
+    }
+
+    /// <p>Copies the specified source product to the specified target product or a new product.</p> <p>You can copy a product to the same account or another account. You can copy a product to the same region or another region.</p> <p>This operation is performed asynchronously. To track the progress of the operation, use <a>DescribeCopyProductStatus</a>.</p>
+    ///
+    /// See `ServiceCatalog::copy_product`.
+    pub fn copy_product(
+        &self,
+        input: CopyProductInput,
+    ) -> Result<CopyProductOutput, RusotoError<CopyProductError>> {
+        self.runtime.block_on(self.client.copy_product(input))
+    }
+
+    /// <p>Creates a constraint.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::create_constraint`.
+    pub fn create_constraint(
+        &self,
+        input: CreateConstraintInput,
+    ) -> Result<CreateConstraintOutput, RusotoError<CreateConstraintError>> {
+        self.runtime.block_on(self.client.create_constraint(input))
+    }
+
+    /// <p>Creates a portfolio.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::create_portfolio`.
+    pub fn create_portfolio(
+        &self,
+        input: CreatePortfolioInput,
+    ) -> Result<CreatePortfolioOutput, RusotoError<CreatePortfolioError>> {
+        self.runtime.block_on(self.client.create_portfolio(input))
+    }
+
+    /// <p>Shares the specified portfolio with the specified account or organization node. Shares to an organization node can only be created by the master account of an organization or by a delegated administrator. You can share portfolios to an organization, an organizational unit, or a specific account.</p> <p>Note that if a delegated admin is de-registered, they can no longer create portfolio shares.</p> <p> <code>AWSOrganizationsAccess</code> must be enabled in order to create a portfolio share to an organization node.</p>
+    ///
+    /// See `ServiceCatalog::create_portfolio_share`.
+    pub fn create_portfolio_share(
+        &self,
+        input: CreatePortfolioShareInput,
+    ) -> Result<CreatePortfolioShareOutput, RusotoError<CreatePortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.create_portfolio_share(input))
+    }
+
+    /// <p>Creates a product.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::create_product`.
+    pub fn create_product(
+        &self,
+        input: CreateProductInput,
+    ) -> Result<CreateProductOutput, RusotoError<CreateProductError>> {
+        self.runtime.block_on(self.client.create_product(input))
+    }
+
+    /// <p>Creates a plan. A plan includes the list of resources to be created (when provisioning a new product) or modified (when updating a provisioned product) when the plan is executed.</p> <p>You can create one plan per provisioned product. To create a plan for an existing provisioned product, the product status must be AVAILBLE or TAINTED.</p> <p>To view the resource changes in the change set, use <a>DescribeProvisionedProductPlan</a>. To create or modify the provisioned product, use <a>ExecuteProvisionedProductPlan</a>.</p>
+    ///
+    /// See `ServiceCatalog::create_provisioned_product_plan`.
+    pub fn create_provisioned_product_plan(
+        &self,
+        input: CreateProvisionedProductPlanInput,
+    ) -> Result<CreateProvisionedProductPlanOutput, RusotoError<CreateProvisionedProductPlanError>>
+    {
+        self.runtime
+            .block_on(self.client.create_provisioned_product_plan(input))
+    }
+
+    /// <p>Creates a provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot create a provisioning artifact for a product that was shared with you.</p>
+    ///
+    /// See `ServiceCatalog::create_provisioning_artifact`.
+    pub fn create_provisioning_artifact(
+        &self,
+        input: CreateProvisioningArtifactInput,
+    ) -> Result<CreateProvisioningArtifactOutput, RusotoError<CreateProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.create_provisioning_artifact(input))
+    }
+
+    /// <p>Creates a self-service action.</p>
+    ///
+    /// See `ServiceCatalog::create_service_action`.
+    pub fn create_service_action(
+        &self,
+        input: CreateServiceActionInput,
+    ) -> Result<CreateServiceActionOutput, RusotoError<CreateServiceActionError>> {
+        self.runtime
+            .block_on(self.client.create_service_action(input))
+    }
+
+    /// <p>Creates a TagOption.</p>
+    ///
+    /// See `ServiceCatalog::create_tag_option`.
+    pub fn create_tag_option(
+        &self,
+        input: CreateTagOptionInput,
+    ) -> Result<CreateTagOptionOutput, RusotoError<CreateTagOptionError>> {
+        self.runtime.block_on(self.client.create_tag_option(input))
+    }
+
+    /// <p>Deletes the specified constraint.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::delete_constraint`.
+    pub fn delete_constraint(
+        &self,
+        input: DeleteConstraintInput,
+    ) -> Result<DeleteConstraintOutput, RusotoError<DeleteConstraintError>> {
+        self.runtime.block_on(self.client.delete_constraint(input))
+    }
+
+    /// <p>Deletes the specified portfolio.</p> <p>You cannot delete a portfolio if it was shared with you or if it has associated products, users, constraints, or shared accounts.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::delete_portfolio`.
+    pub fn delete_portfolio(
+        &self,
+        input: DeletePortfolioInput,
+    ) -> Result<DeletePortfolioOutput, RusotoError<DeletePortfolioError>> {
+        self.runtime.block_on(self.client.delete_portfolio(input))
+    }
+
+    /// <p>Stops sharing the specified portfolio with the specified account or organization node. Shares to an organization node can only be deleted by the master account of an organization or by a delegated administrator.</p> <p>Note that if a delegated admin is de-registered, portfolio shares created from that account are removed.</p>
+    ///
+    /// See `ServiceCatalog::delete_portfolio_share`.
+    pub fn delete_portfolio_share(
+        &self,
+        input: DeletePortfolioShareInput,
+    ) -> Result<DeletePortfolioShareOutput, RusotoError<DeletePortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.delete_portfolio_share(input))
+    }
+
+    /// <p>Deletes the specified product.</p> <p>You cannot delete a product if it was shared with you or is associated with a portfolio.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::delete_product`.
+    pub fn delete_product(
+        &self,
+        input: DeleteProductInput,
+    ) -> Result<DeleteProductOutput, RusotoError<DeleteProductError>> {
+        self.runtime.block_on(self.client.delete_product(input))
+    }
+
+    /// <p>Deletes the specified plan.</p>
+    ///
+    /// See `ServiceCatalog::delete_provisioned_product_plan`.
+    pub fn delete_provisioned_product_plan(
+        &self,
+        input: DeleteProvisionedProductPlanInput,
+    ) -> Result<DeleteProvisionedProductPlanOutput, RusotoError<DeleteProvisionedProductPlanError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_provisioned_product_plan(input))
+    }
+
+    /// <p>Deletes the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot delete a provisioning artifact associated with a product that was shared with you. You cannot delete the last provisioning artifact for a product, because a product must have at least one provisioning artifact.</p>
+    ///
+    /// See `ServiceCatalog::delete_provisioning_artifact`.
+    pub fn delete_provisioning_artifact(
+        &self,
+        input: DeleteProvisioningArtifactInput,
+    ) -> Result<DeleteProvisioningArtifactOutput, RusotoError<DeleteProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_provisioning_artifact(input))
+    }
+
+    /// <p>Deletes a self-service action.</p>
+    ///
+    /// See `ServiceCatalog::delete_service_action`.
+    pub fn delete_service_action(
+        &self,
+        input: DeleteServiceActionInput,
+    ) -> Result<DeleteServiceActionOutput, RusotoError<DeleteServiceActionError>> {
+        self.runtime
+            .block_on(self.client.delete_service_action(input))
+    }
+
+    /// <p>Deletes the specified TagOption.</p> <p>You cannot delete a TagOption if it is associated with a product or portfolio.</p>
+    ///
+    /// See `ServiceCatalog::delete_tag_option`.
+    pub fn delete_tag_option(
+        &self,
+        input: DeleteTagOptionInput,
+    ) -> Result<DeleteTagOptionOutput, RusotoError<DeleteTagOptionError>> {
+        self.runtime.block_on(self.client.delete_tag_option(input))
+    }
+
+    /// <p>Gets information about the specified constraint.</p>
+    ///
+    /// See `ServiceCatalog::describe_constraint`.
+    pub fn describe_constraint(
+        &self,
+        input: DescribeConstraintInput,
+    ) -> Result<DescribeConstraintOutput, RusotoError<DescribeConstraintError>> {
+        self.runtime
+            .block_on(self.client.describe_constraint(input))
+    }
+
+    /// <p>Gets the status of the specified copy product operation.</p>
+    ///
+    /// See `ServiceCatalog::describe_copy_product_status`.
+    pub fn describe_copy_product_status(
+        &self,
+        input: DescribeCopyProductStatusInput,
+    ) -> Result<DescribeCopyProductStatusOutput, RusotoError<DescribeCopyProductStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_copy_product_status(input))
+    }
+
+    /// <p>Gets information about the specified portfolio.</p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::describe_portfolio`.
+    pub fn describe_portfolio(
+        &self,
+        input: DescribePortfolioInput,
+    ) -> Result<DescribePortfolioOutput, RusotoError<DescribePortfolioError>> {
+        self.runtime.block_on(self.client.describe_portfolio(input))
+    }
+
+    /// <p>Gets the status of the specified portfolio share operation. This API can only be called by the master account in the organization or by a delegated admin.</p>
+    ///
+    /// See `ServiceCatalog::describe_portfolio_share_status`.
+    pub fn describe_portfolio_share_status(
+        &self,
+        input: DescribePortfolioShareStatusInput,
+    ) -> Result<DescribePortfolioShareStatusOutput, RusotoError<DescribePortfolioShareStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_portfolio_share_status(input))
+    }
+
+    /// <p>Gets information about the specified product.</p>
+    ///
+    /// See `ServiceCatalog::describe_product`.
+    pub fn describe_product(
+        &self,
+        input: DescribeProductInput,
+    ) -> Result<DescribeProductOutput, RusotoError<DescribeProductError>> {
+        self.runtime.block_on(self.client.describe_product(input))
+    }
+
+    /// <p>Gets information about the specified product. This operation is run with administrator access.</p>
+    ///
+    /// See `ServiceCatalog::describe_product_as_admin`.
+    pub fn describe_product_as_admin(
+        &self,
+        input: DescribeProductAsAdminInput,
+    ) -> Result<DescribeProductAsAdminOutput, RusotoError<DescribeProductAsAdminError>> {
+        self.runtime
+            .block_on(self.client.describe_product_as_admin(input))
+    }
+
+    /// <p>Gets information about the specified product.</p>
+    ///
+    /// See `ServiceCatalog::describe_product_view`.
+    pub fn describe_product_view(
+        &self,
+        input: DescribeProductViewInput,
+    ) -> Result<DescribeProductViewOutput, RusotoError<DescribeProductViewError>> {
+        self.runtime
+            .block_on(self.client.describe_product_view(input))
+    }
+
+    /// <p>Gets information about the specified provisioned product.</p>
+    ///
+    /// See `ServiceCatalog::describe_provisioned_product`.
+    pub fn describe_provisioned_product(
+        &self,
+        input: DescribeProvisionedProductInput,
+    ) -> Result<DescribeProvisionedProductOutput, RusotoError<DescribeProvisionedProductError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_provisioned_product(input))
+    }
+
+    /// <p>Gets information about the resource changes for the specified plan.</p>
+    ///
+    /// See `ServiceCatalog::describe_provisioned_product_plan`.
+    pub fn describe_provisioned_product_plan(
+        &self,
+        input: DescribeProvisionedProductPlanInput,
+    ) -> Result<
+        DescribeProvisionedProductPlanOutput,
+        RusotoError<DescribeProvisionedProductPlanError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_provisioned_product_plan(input))
+    }
+
+    /// <p>Gets information about the specified provisioning artifact (also known as a version) for the specified product.</p>
+    ///
+    /// See `ServiceCatalog::describe_provisioning_artifact`.
+    pub fn describe_provisioning_artifact(
+        &self,
+        input: DescribeProvisioningArtifactInput,
+    ) -> Result<DescribeProvisioningArtifactOutput, RusotoError<DescribeProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_provisioning_artifact(input))
+    }
+
+    /// <p>Gets information about the configuration required to provision the specified product using the specified provisioning artifact.</p> <p>If the output contains a TagOption key with an empty list of values, there is a TagOption conflict for that key. The end user cannot take action to fix the conflict, and launch is not blocked. In subsequent calls to <a>ProvisionProduct</a>, do not include conflicted TagOption keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>". Tag the provisioned product with the value <code>sc-tagoption-conflict-portfolioId-productId</code>.</p>
+    ///
+    /// See `ServiceCatalog::describe_provisioning_parameters`.
+    pub fn describe_provisioning_parameters(
+        &self,
+        input: DescribeProvisioningParametersInput,
+    ) -> Result<
+        DescribeProvisioningParametersOutput,
+        RusotoError<DescribeProvisioningParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_provisioning_parameters(input))
+    }
+
+    /// <p><p>Gets information about the specified request operation.</p> <p>Use this operation after calling a request operation (for example, <a>ProvisionProduct</a>, <a>TerminateProvisionedProduct</a>, or <a>UpdateProvisionedProduct</a>). </p> <note> <p>If a provisioned product was transferred to a new owner using <a>UpdateProvisionedProductProperties</a>, the new owner will be able to describe all past records for that product. The previous owner will no longer be able to describe the records, but will be able to use <a>ListRecordHistory</a> to see the product&#39;s history from when he was the owner.</p> </note></p>
+    ///
+    /// See `ServiceCatalog::describe_record`.
+    pub fn describe_record(
+        &self,
+        input: DescribeRecordInput,
+    ) -> Result<DescribeRecordOutput, RusotoError<DescribeRecordError>> {
+        self.runtime.block_on(self.client.describe_record(input))
+    }
+
+    /// <p>Describes a self-service action.</p>
+    ///
+    /// See `ServiceCatalog::describe_service_action`.
+    pub fn describe_service_action(
+        &self,
+        input: DescribeServiceActionInput,
+    ) -> Result<DescribeServiceActionOutput, RusotoError<DescribeServiceActionError>> {
+        self.runtime
+            .block_on(self.client.describe_service_action(input))
+    }
+
+    /// <p>Finds the default parameters for a specific self-service action on a specific provisioned product and returns a map of the results to the user.</p>
+    ///
+    /// See `ServiceCatalog::describe_service_action_execution_parameters`.
+    pub fn describe_service_action_execution_parameters(
+        &self,
+        input: DescribeServiceActionExecutionParametersInput,
+    ) -> Result<
+        DescribeServiceActionExecutionParametersOutput,
+        RusotoError<DescribeServiceActionExecutionParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_service_action_execution_parameters(input),
+        )
+    }
+
+    /// <p>Gets information about the specified TagOption.</p>
+    ///
+    /// See `ServiceCatalog::describe_tag_option`.
+    pub fn describe_tag_option(
+        &self,
+        input: DescribeTagOptionInput,
+    ) -> Result<DescribeTagOptionOutput, RusotoError<DescribeTagOptionError>> {
+        self.runtime
+            .block_on(self.client.describe_tag_option(input))
+    }
+
+    /// <p>Disable portfolio sharing through AWS Organizations feature. This feature will not delete your current shares but it will prevent you from creating new shares throughout your organization. Current shares will not be in sync with your organization structure if it changes after calling this API. This API can only be called by the master account in the organization.</p> <p>This API can't be invoked if there are active delegated administrators in the organization.</p> <p>Note that a delegated administrator is not authorized to invoke <code>DisableAWSOrganizationsAccess</code>.</p>
+    ///
+    /// See `ServiceCatalog::disable_aws_organizations_access`.
+    pub fn disable_aws_organizations_access(
+        &self,
+    ) -> Result<DisableAWSOrganizationsAccessOutput, RusotoError<DisableAWSOrganizationsAccessError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_aws_organizations_access())
+    }
+
+    /// <p>Disassociates the specified budget from the specified resource.</p>
+    ///
+    /// See `ServiceCatalog::disassociate_budget_from_resource`.
+    pub fn disassociate_budget_from_resource(
+        &self,
+        input: DisassociateBudgetFromResourceInput,
+    ) -> Result<
+        DisassociateBudgetFromResourceOutput,
+        RusotoError<DisassociateBudgetFromResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_budget_from_resource(input))
+    }
+
+    /// <p>Disassociates a previously associated principal ARN from a specified portfolio.</p>
+    ///
+    /// See `ServiceCatalog::disassociate_principal_from_portfolio`.
+    pub fn disassociate_principal_from_portfolio(
+        &self,
+        input: DisassociatePrincipalFromPortfolioInput,
+    ) -> Result<
+        DisassociatePrincipalFromPortfolioOutput,
+        RusotoError<DisassociatePrincipalFromPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_principal_from_portfolio(input))
+    }
+
+    /// <p>Disassociates the specified product from the specified portfolio. </p> <p>A delegated admin is authorized to invoke this command.</p>
+    ///
+    /// See `ServiceCatalog::disassociate_product_from_portfolio`.
+    pub fn disassociate_product_from_portfolio(
+        &self,
+        input: DisassociateProductFromPortfolioInput,
+    ) -> Result<
+        DisassociateProductFromPortfolioOutput,
+        RusotoError<DisassociateProductFromPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_product_from_portfolio(input))
+    }
+
+    /// <p>Disassociates the specified self-service action association from the specified provisioning artifact.</p>
+    ///
+    /// See `ServiceCatalog::disassociate_service_action_from_provisioning_artifact`.
+    pub fn disassociate_service_action_from_provisioning_artifact(
+        &self,
+        input: DisassociateServiceActionFromProvisioningArtifactInput,
+    ) -> Result<
+        DisassociateServiceActionFromProvisioningArtifactOutput,
+        RusotoError<DisassociateServiceActionFromProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_service_action_from_provisioning_artifact(input),
+        )
+    }
+
+    /// <p>Disassociates the specified TagOption from the specified resource.</p>
+    ///
+    /// See `ServiceCatalog::disassociate_tag_option_from_resource`.
+    pub fn disassociate_tag_option_from_resource(
+        &self,
+        input: DisassociateTagOptionFromResourceInput,
+    ) -> Result<
+        DisassociateTagOptionFromResourceOutput,
+        RusotoError<DisassociateTagOptionFromResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_tag_option_from_resource(input))
+    }
+
+    /// <p>Enable portfolio sharing feature through AWS Organizations. This API will allow Service Catalog to receive updates on your organization in order to sync your shares with the current structure. This API can only be called by the master account in the organization.</p> <p>By calling this API Service Catalog will make a call to organizations:EnableAWSServiceAccess on your behalf so that your shares can be in sync with any changes in your AWS Organizations structure.</p> <p>Note that a delegated administrator is not authorized to invoke <code>EnableAWSOrganizationsAccess</code>.</p>
+    ///
+    /// See `ServiceCatalog::enable_aws_organizations_access`.
+    pub fn enable_aws_organizations_access(
+        &self,
+    ) -> Result<EnableAWSOrganizationsAccessOutput, RusotoError<EnableAWSOrganizationsAccessError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_aws_organizations_access())
+    }
+
+    /// <p>Provisions or modifies a product based on the resource changes for the specified plan.</p>
+    ///
+    /// See `ServiceCatalog::execute_provisioned_product_plan`.
+    pub fn execute_provisioned_product_plan(
+        &self,
+        input: ExecuteProvisionedProductPlanInput,
+    ) -> Result<ExecuteProvisionedProductPlanOutput, RusotoError<ExecuteProvisionedProductPlanError>>
+    {
+        self.runtime
+            .block_on(self.client.execute_provisioned_product_plan(input))
+    }
+
+    /// <p>Executes a self-service action against a provisioned product.</p>
+    ///
+    /// See `ServiceCatalog::execute_provisioned_product_service_action`.
+    pub fn execute_provisioned_product_service_action(
+        &self,
+        input: ExecuteProvisionedProductServiceActionInput,
+    ) -> Result<
+        ExecuteProvisionedProductServiceActionOutput,
+        RusotoError<ExecuteProvisionedProductServiceActionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .execute_provisioned_product_service_action(input),
+        )
+    }
+
+    /// <p>Get the Access Status for AWS Organization portfolio share feature. This API can only be called by the master account in the organization or by a delegated admin.</p>
+    ///
+    /// See `ServiceCatalog::get_aws_organizations_access_status`.
+    pub fn get_aws_organizations_access_status(
+        &self,
+    ) -> Result<
+        GetAWSOrganizationsAccessStatusOutput,
+        RusotoError<GetAWSOrganizationsAccessStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_aws_organizations_access_status())
+    }
+
+    /// <p>Lists all portfolios for which sharing was accepted by this account.</p>
+    ///
+    /// See `ServiceCatalog::list_accepted_portfolio_shares`.
+    pub fn list_accepted_portfolio_shares(
+        &self,
+        input: ListAcceptedPortfolioSharesInput,
+    ) -> Result<ListAcceptedPortfolioSharesOutput, RusotoError<ListAcceptedPortfolioSharesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_accepted_portfolio_shares(input))
+    }
+
+    /// <p>Lists all the budgets associated to the specified resource.</p>
+    ///
+    /// See `ServiceCatalog::list_budgets_for_resource`.
+    pub fn list_budgets_for_resource(
+        &self,
+        input: ListBudgetsForResourceInput,
+    ) -> Result<ListBudgetsForResourceOutput, RusotoError<ListBudgetsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_budgets_for_resource(input))
+    }
+
+    /// <p>Lists the constraints for the specified portfolio and product.</p>
+    ///
+    /// See `ServiceCatalog::list_constraints_for_portfolio`.
+    pub fn list_constraints_for_portfolio(
+        &self,
+        input: ListConstraintsForPortfolioInput,
+    ) -> Result<ListConstraintsForPortfolioOutput, RusotoError<ListConstraintsForPortfolioError>>
+    {
+        self.runtime
+            .block_on(self.client.list_constraints_for_portfolio(input))
+    }
+
+    /// <p>Lists the paths to the specified product. A path is how the user has access to a specified product, and is necessary when provisioning a product. A path also determines the constraints put on the product.</p>
+    ///
+    /// See `ServiceCatalog::list_launch_paths`.
+    pub fn list_launch_paths(
+        &self,
+        input: ListLaunchPathsInput,
+    ) -> Result<ListLaunchPathsOutput, RusotoError<ListLaunchPathsError>> {
+        self.runtime.block_on(self.client.list_launch_paths(input))
+    }
+
+    /// <p>Lists the organization nodes that have access to the specified portfolio. This API can only be called by the master account in the organization or by a delegated admin.</p> <p>If a delegated admin is de-registered, they can no longer perform this operation.</p>
+    ///
+    /// See `ServiceCatalog::list_organization_portfolio_access`.
+    pub fn list_organization_portfolio_access(
+        &self,
+        input: ListOrganizationPortfolioAccessInput,
+    ) -> Result<
+        ListOrganizationPortfolioAccessOutput,
+        RusotoError<ListOrganizationPortfolioAccessError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organization_portfolio_access(input))
+    }
+
+    /// <p>Lists the account IDs that have access to the specified portfolio.</p> <p>A delegated admin can list the accounts that have access to the shared portfolio. Note that if a delegated admin is de-registered, they can no longer perform this operation.</p>
+    ///
+    /// See `ServiceCatalog::list_portfolio_access`.
+    pub fn list_portfolio_access(
+        &self,
+        input: ListPortfolioAccessInput,
+    ) -> Result<ListPortfolioAccessOutput, RusotoError<ListPortfolioAccessError>> {
+        self.runtime
+            .block_on(self.client.list_portfolio_access(input))
+    }
+
+    /// <p>Lists all portfolios in the catalog.</p>
+    ///
+    /// See `ServiceCatalog::list_portfolios`.
+    pub fn list_portfolios(
+        &self,
+        input: ListPortfoliosInput,
+    ) -> Result<ListPortfoliosOutput, RusotoError<ListPortfoliosError>> {
+        self.runtime.block_on(self.client.list_portfolios(input))
+    }
+
+    /// <p>Lists all portfolios that the specified product is associated with.</p>
+    ///
+    /// See `ServiceCatalog::list_portfolios_for_product`.
+    pub fn list_portfolios_for_product(
+        &self,
+        input: ListPortfoliosForProductInput,
+    ) -> Result<ListPortfoliosForProductOutput, RusotoError<ListPortfoliosForProductError>> {
+        self.runtime
+            .block_on(self.client.list_portfolios_for_product(input))
+    }
+
+    /// <p>Lists all principal ARNs associated with the specified portfolio.</p>
+    ///
+    /// See `ServiceCatalog::list_principals_for_portfolio`.
+    pub fn list_principals_for_portfolio(
+        &self,
+        input: ListPrincipalsForPortfolioInput,
+    ) -> Result<ListPrincipalsForPortfolioOutput, RusotoError<ListPrincipalsForPortfolioError>>
+    {
+        self.runtime
+            .block_on(self.client.list_principals_for_portfolio(input))
+    }
+
+    /// <p>Lists the plans for the specified provisioned product or all plans to which the user has access.</p>
+    ///
+    /// See `ServiceCatalog::list_provisioned_product_plans`.
+    pub fn list_provisioned_product_plans(
+        &self,
+        input: ListProvisionedProductPlansInput,
+    ) -> Result<ListProvisionedProductPlansOutput, RusotoError<ListProvisionedProductPlansError>>
+    {
+        self.runtime
+            .block_on(self.client.list_provisioned_product_plans(input))
+    }
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified product.</p>
+    ///
+    /// See `ServiceCatalog::list_provisioning_artifacts`.
+    pub fn list_provisioning_artifacts(
+        &self,
+        input: ListProvisioningArtifactsInput,
+    ) -> Result<ListProvisioningArtifactsOutput, RusotoError<ListProvisioningArtifactsError>> {
+        self.runtime
+            .block_on(self.client.list_provisioning_artifacts(input))
+    }
+
+    /// <p>Lists all provisioning artifacts (also known as versions) for the specified self-service action.</p>
+    ///
+    /// See `ServiceCatalog::list_provisioning_artifacts_for_service_action`.
+    pub fn list_provisioning_artifacts_for_service_action(
+        &self,
+        input: ListProvisioningArtifactsForServiceActionInput,
+    ) -> Result<
+        ListProvisioningArtifactsForServiceActionOutput,
+        RusotoError<ListProvisioningArtifactsForServiceActionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_provisioning_artifacts_for_service_action(input),
+        )
+    }
+
+    /// <p>Lists the specified requests or all performed requests.</p>
+    ///
+    /// See `ServiceCatalog::list_record_history`.
+    pub fn list_record_history(
+        &self,
+        input: ListRecordHistoryInput,
+    ) -> Result<ListRecordHistoryOutput, RusotoError<ListRecordHistoryError>> {
+        self.runtime
+            .block_on(self.client.list_record_history(input))
+    }
+
+    /// <p>Lists the resources associated with the specified TagOption.</p>
+    ///
+    /// See `ServiceCatalog::list_resources_for_tag_option`.
+    pub fn list_resources_for_tag_option(
+        &self,
+        input: ListResourcesForTagOptionInput,
+    ) -> Result<ListResourcesForTagOptionOutput, RusotoError<ListResourcesForTagOptionError>> {
+        self.runtime
+            .block_on(self.client.list_resources_for_tag_option(input))
+    }
+
+    /// <p>Lists all self-service actions.</p>
+    ///
+    /// See `ServiceCatalog::list_service_actions`.
+    pub fn list_service_actions(
+        &self,
+        input: ListServiceActionsInput,
+    ) -> Result<ListServiceActionsOutput, RusotoError<ListServiceActionsError>> {
+        self.runtime
+            .block_on(self.client.list_service_actions(input))
+    }
+
+    /// <p>Returns a paginated list of self-service actions associated with the specified Product ID and Provisioning Artifact ID.</p>
+    ///
+    /// See `ServiceCatalog::list_service_actions_for_provisioning_artifact`.
+    pub fn list_service_actions_for_provisioning_artifact(
+        &self,
+        input: ListServiceActionsForProvisioningArtifactInput,
+    ) -> Result<
+        ListServiceActionsForProvisioningArtifactOutput,
+        RusotoError<ListServiceActionsForProvisioningArtifactError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_service_actions_for_provisioning_artifact(input),
+        )
+    }
+
+    /// <p>Returns summary information about stack instances that are associated with the specified <code>CFN_STACKSET</code> type provisioned product. You can filter for stack instances that are associated with a specific AWS account name or region. </p>
+    ///
+    /// See `ServiceCatalog::list_stack_instances_for_provisioned_product`.
+    pub fn list_stack_instances_for_provisioned_product(
+        &self,
+        input: ListStackInstancesForProvisionedProductInput,
+    ) -> Result<
+        ListStackInstancesForProvisionedProductOutput,
+        RusotoError<ListStackInstancesForProvisionedProductError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_stack_instances_for_provisioned_product(input),
+        )
+    }
+
+    /// <p>Lists the specified TagOptions or all TagOptions.</p>
+    ///
+    /// See `ServiceCatalog::list_tag_options`.
+    pub fn list_tag_options(
+        &self,
+        input: ListTagOptionsInput,
+    ) -> Result<ListTagOptionsOutput, RusotoError<ListTagOptionsError>> {
+        self.runtime.block_on(self.client.list_tag_options(input))
+    }
+
+    /// <p>Provisions the specified product.</p> <p>A provisioned product is a resourced instance of a product. For example, provisioning a product based on a CloudFormation template launches a CloudFormation stack and its underlying resources. You can check the status of this request using <a>DescribeRecord</a>.</p> <p>If the request contains a tag key with an empty list of values, there is a tag conflict for that key. Do not include conflicted keys as tags, or this causes the error "Parameter validation failed: Missing required parameter in Tags[<i>N</i>]:<i>Value</i>".</p>
+    ///
+    /// See `ServiceCatalog::provision_product`.
+    pub fn provision_product(
+        &self,
+        input: ProvisionProductInput,
+    ) -> Result<ProvisionProductOutput, RusotoError<ProvisionProductError>> {
+        self.runtime.block_on(self.client.provision_product(input))
+    }
+
+    /// <p>Rejects an offer to share the specified portfolio.</p>
+    ///
+    /// See `ServiceCatalog::reject_portfolio_share`.
+    pub fn reject_portfolio_share(
+        &self,
+        input: RejectPortfolioShareInput,
+    ) -> Result<RejectPortfolioShareOutput, RusotoError<RejectPortfolioShareError>> {
+        self.runtime
+            .block_on(self.client.reject_portfolio_share(input))
+    }
+
+    /// <p>Lists the provisioned products that are available (not terminated).</p> <p>To use additional filtering, see <a>SearchProvisionedProducts</a>.</p>
+    ///
+    /// See `ServiceCatalog::scan_provisioned_products`.
+    pub fn scan_provisioned_products(
+        &self,
+        input: ScanProvisionedProductsInput,
+    ) -> Result<ScanProvisionedProductsOutput, RusotoError<ScanProvisionedProductsError>> {
+        self.runtime
+            .block_on(self.client.scan_provisioned_products(input))
+    }
+
+    /// <p>Gets information about the products to which the caller has access.</p>
+    ///
+    /// See `ServiceCatalog::search_products`.
+    pub fn search_products(
+        &self,
+        input: SearchProductsInput,
+    ) -> Result<SearchProductsOutput, RusotoError<SearchProductsError>> {
+        self.runtime.block_on(self.client.search_products(input))
+    }
+
+    /// <p>Gets information about the products for the specified portfolio or all products.</p>
+    ///
+    /// See `ServiceCatalog::search_products_as_admin`.
+    pub fn search_products_as_admin(
+        &self,
+        input: SearchProductsAsAdminInput,
+    ) -> Result<SearchProductsAsAdminOutput, RusotoError<SearchProductsAsAdminError>> {
+        self.runtime
+            .block_on(self.client.search_products_as_admin(input))
+    }
+
+    /// <p>Gets information about the provisioned products that meet the specified criteria.</p>
+    ///
+    /// See `ServiceCatalog::search_provisioned_products`.
+    pub fn search_provisioned_products(
+        &self,
+        input: SearchProvisionedProductsInput,
+    ) -> Result<SearchProvisionedProductsOutput, RusotoError<SearchProvisionedProductsError>> {
+        self.runtime
+            .block_on(self.client.search_provisioned_products(input))
+    }
+
+    /// <p>Terminates the specified provisioned product.</p> <p>This operation does not delete any records associated with the provisioned product.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    ///
+    /// See `ServiceCatalog::terminate_provisioned_product`.
+    pub fn terminate_provisioned_product(
+        &self,
+        input: TerminateProvisionedProductInput,
+    ) -> Result<TerminateProvisionedProductOutput, RusotoError<TerminateProvisionedProductError>>
+    {
+        self.runtime
+            .block_on(self.client.terminate_provisioned_product(input))
+    }
+
+    /// <p>Updates the specified constraint.</p>
+    ///
+    /// See `ServiceCatalog::update_constraint`.
+    pub fn update_constraint(
+        &self,
+        input: UpdateConstraintInput,
+    ) -> Result<UpdateConstraintOutput, RusotoError<UpdateConstraintError>> {
+        self.runtime.block_on(self.client.update_constraint(input))
+    }
+
+    /// <p>Updates the specified portfolio.</p> <p>You cannot update a product that was shared with you.</p>
+    ///
+    /// See `ServiceCatalog::update_portfolio`.
+    pub fn update_portfolio(
+        &self,
+        input: UpdatePortfolioInput,
+    ) -> Result<UpdatePortfolioOutput, RusotoError<UpdatePortfolioError>> {
+        self.runtime.block_on(self.client.update_portfolio(input))
+    }
+
+    /// <p>Updates the specified product.</p>
+    ///
+    /// See `ServiceCatalog::update_product`.
+    pub fn update_product(
+        &self,
+        input: UpdateProductInput,
+    ) -> Result<UpdateProductOutput, RusotoError<UpdateProductError>> {
+        self.runtime.block_on(self.client.update_product(input))
+    }
+
+    /// <p>Requests updates to the configuration of the specified provisioned product.</p> <p>If there are tags associated with the object, they cannot be updated or added. Depending on the specific updates requested, this operation can update with no interruption, with some interruption, or replace the provisioned product entirely.</p> <p>You can check the status of this request using <a>DescribeRecord</a>.</p>
+    ///
+    /// See `ServiceCatalog::update_provisioned_product`.
+    pub fn update_provisioned_product(
+        &self,
+        input: UpdateProvisionedProductInput,
+    ) -> Result<UpdateProvisionedProductOutput, RusotoError<UpdateProvisionedProductError>> {
+        self.runtime
+            .block_on(self.client.update_provisioned_product(input))
+    }
+
+    /// <p>Requests updates to the properties of the specified provisioned product.</p>
+    ///
+    /// See `ServiceCatalog::update_provisioned_product_properties`.
+    pub fn update_provisioned_product_properties(
+        &self,
+        input: UpdateProvisionedProductPropertiesInput,
+    ) -> Result<
+        UpdateProvisionedProductPropertiesOutput,
+        RusotoError<UpdateProvisionedProductPropertiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_provisioned_product_properties(input))
+    }
+
+    /// <p>Updates the specified provisioning artifact (also known as a version) for the specified product.</p> <p>You cannot update a provisioning artifact for a product that was shared with you.</p>
+    ///
+    /// See `ServiceCatalog::update_provisioning_artifact`.
+    pub fn update_provisioning_artifact(
+        &self,
+        input: UpdateProvisioningArtifactInput,
+    ) -> Result<UpdateProvisioningArtifactOutput, RusotoError<UpdateProvisioningArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.update_provisioning_artifact(input))
+    }
+
+    /// <p>Updates a self-service action.</p>
+    ///
+    /// See `ServiceCatalog::update_service_action`.
+    pub fn update_service_action(
+        &self,
+        input: UpdateServiceActionInput,
+    ) -> Result<UpdateServiceActionOutput, RusotoError<UpdateServiceActionError>> {
+        self.runtime
+            .block_on(self.client.update_service_action(input))
+    }
+
+    /// <p>Updates the specified TagOption.</p>
+    ///
+    /// See `ServiceCatalog::update_tag_option`.
+    pub fn update_tag_option(
+        &self,
+        input: UpdateTagOptionInput,
+    ) -> Result<UpdateTagOptionOutput, RusotoError<UpdateTagOptionError>> {
+        self.runtime.block_on(self.client.update_tag_option(input))
+    }
+}
@@ -1062,25 +1062,34 @@ impl CreateHttpNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHttpNamespaceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHttpNamespaceError::InvalidInput(err.msg),
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::NamespaceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHttpNamespaceError::NamespaceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateHttpNamespaceError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateHttpNamespaceError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateHttpNamespaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1122,29 +1131,34 @@ impl CreatePrivateDnsNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePrivateDnsNamespaceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePrivateDnsNamespaceError::InvalidInput(err.msg),
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePrivateDnsNamespaceError::NamespaceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePrivateDnsNamespaceError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePrivateDnsNamespaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePrivateDnsNamespaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1190,29 +1204,34 @@ impl CreatePublicDnsNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::DuplicateRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePublicDnsNamespaceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePublicDnsNamespaceError::InvalidInput(err.msg),
+                    )
                 }
                 "NamespaceAlreadyExists" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePublicDnsNamespaceError::NamespaceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePublicDnsNamespaceError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePublicDnsNamespaceError::TooManyTags(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePublicDnsNamespaceError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1258,19 +1277,28 @@ impl CreateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CreateServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::InvalidInput(err.msg))
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(CreateServiceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceError::NamespaceNotFound(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateServiceError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceAlreadyExists" => {
-                    return RusotoError::Service(CreateServiceError::ServiceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServiceError::ServiceAlreadyExists(err.msg),
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateServiceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateServiceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1310,16 +1338,22 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DeleteNamespaceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNamespaceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteNamespaceError::InvalidInput(err.msg))
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(DeleteNamespaceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteNamespaceError::NamespaceNotFound(err.msg),
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteNamespaceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteNamespaceError::ResourceInUse(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1356,13 +1390,13 @@ impl DeleteServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::InvalidInput(err.msg))
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteServiceError::ResourceInUse(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::ResourceInUse(err.msg))
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteServiceError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1402,19 +1436,34 @@ impl DeregisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DeregisterInstanceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterInstanceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InstanceNotFound" => {
-                    return RusotoError::Service(DeregisterInstanceError::InstanceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterInstanceError::InstanceNotFound(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DeregisterInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterInstanceError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeregisterInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterInstanceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DeregisterInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterInstanceError::ServiceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1454,18 +1503,28 @@ impl DiscoverInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DiscoverInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInstancesError::InvalidInput(err.msg),
+                    )
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(DiscoverInstancesError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInstancesError::NamespaceNotFound(err.msg),
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(DiscoverInstancesError::RequestLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInstancesError::RequestLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(DiscoverInstancesError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DiscoverInstancesError::ServiceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1502,13 +1561,13 @@ impl GetInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNotFound" => {
-                    return RusotoError::Service(GetInstanceError::InstanceNotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::InstanceNotFound(err.msg))
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(GetInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::InvalidInput(err.msg))
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, GetInstanceError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1544,19 +1603,22 @@ impl GetInstancesHealthStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNotFound" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::InstanceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancesHealthStatusError::InstanceNotFound(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::InvalidInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancesHealthStatusError::InvalidInput(err.msg),
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetInstancesHealthStatusError::ServiceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInstancesHealthStatusError::ServiceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1590,10 +1652,13 @@ impl GetNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetNamespaceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetNamespaceError::InvalidInput(err.msg))
                 }
                 "NamespaceNotFound" => {
-                    return RusotoError::Service(GetNamespaceError::NamespaceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetNamespaceError::NamespaceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1626,10 +1691,13 @@ impl GetOperationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetOperationError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetOperationError::InvalidInput(err.msg))
                 }
                 "OperationNotFound" => {
-                    return RusotoError::Service(GetOperationError::OperationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOperationError::OperationNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1662,10 +1730,10 @@ impl GetServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, GetServiceError::InvalidInput(err.msg))
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(GetServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, GetServiceError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1698,10 +1766,10 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListInstancesError::InvalidInput(err.msg))
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(ListInstancesError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, ListInstancesError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1732,7 +1800,7 @@ impl ListNamespacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListNamespacesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListNamespacesError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1762,7 +1830,7 @@ impl ListOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListOperationsError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1792,7 +1860,7 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListServicesError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, ListServicesError::InvalidInput(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1824,12 +1892,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidInput(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1868,21 +1940,31 @@ impl RegisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RegisterInstanceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterInstanceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RegisterInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, RegisterInstanceError::InvalidInput(err.msg))
                 }
                 "ResourceInUse" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterInstanceError::ResourceInUse(err.msg),
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterInstanceError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(RegisterInstanceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterInstanceError::ServiceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1920,13 +2002,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1960,10 +2042,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidInput(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2002,22 +2087,26 @@ impl UpdateInstanceCustomHealthStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomHealthNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInstanceCustomHealthStatusError::CustomHealthNotFound(err.msg),
                     )
                 }
                 "InstanceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInstanceCustomHealthStatusError::InstanceNotFound(err.msg),
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInstanceCustomHealthStatusError::InvalidInput(err.msg),
                     )
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateInstanceCustomHealthStatusError::ServiceNotFound(err.msg),
                     )
                 }
@@ -2064,13 +2153,16 @@ impl UpdateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateServiceError::DuplicateRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceError::DuplicateRequest(err.msg),
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateServiceError::InvalidInput(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::InvalidInput(err.msg))
                 }
                 "ServiceNotFound" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateServiceError::ServiceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2249,6 +2341,7 @@ impl ServiceDiscoveryClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2264,6 +2357,7 @@ impl ServiceDiscoveryClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ServiceDiscoveryClient {
         ServiceDiscoveryClient { client, region }
     }
@@ -2277,6 +2371,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreateHttpNamespaceRequest,
     ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateHttpNamespace");
+        request.set_read_only(false);
+        request.set_idempotent(input.creator_request_id.is_some());
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.CreateHttpNamespace",
@@ -2299,6 +2396,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
     ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePrivateDnsNamespace");
+        request.set_read_only(false);
+        request.set_idempotent(input.creator_request_id.is_some());
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.CreatePrivateDnsNamespace",
@@ -2321,6 +2421,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreatePublicDnsNamespaceRequest,
     ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePublicDnsNamespace");
+        request.set_read_only(false);
+        request.set_idempotent(input.creator_request_id.is_some());
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.CreatePublicDnsNamespace",
@@ -2343,6 +2446,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateService");
+        request.set_read_only(false);
+        request.set_idempotent(input.creator_request_id.is_some());
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.CreateService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2361,6 +2467,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeleteNamespaceRequest,
     ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteNamespace");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.DeleteNamespace",
@@ -2382,6 +2491,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeleteServiceRequest,
     ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteService");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.DeleteService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2400,6 +2512,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DeregisterInstanceRequest,
     ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.DeregisterInstance",
@@ -2421,6 +2536,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: DiscoverInstancesRequest,
     ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DiscoverInstances");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.DiscoverInstances",
@@ -2442,6 +2560,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetInstanceRequest,
     ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2460,6 +2581,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetInstancesHealthStatusRequest,
     ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInstancesHealthStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.GetInstancesHealthStatus",
@@ -2482,6 +2606,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetNamespaceRequest,
     ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetNamespace");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetNamespace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2500,6 +2627,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetOperationRequest,
     ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOperation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetOperation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2518,6 +2648,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: GetServiceRequest,
     ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetService");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.GetService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2536,6 +2669,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListInstancesRequest,
     ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListInstances");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2554,6 +2690,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListNamespacesRequest,
     ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListNamespaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListNamespaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2572,6 +2711,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListOperationsRequest,
     ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOperations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2590,6 +2732,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.ListServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2608,6 +2753,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.ListTagsForResource",
@@ -2629,6 +2777,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterInstance");
+        request.set_read_only(false);
+        request.set_idempotent(input.creator_request_id.is_some());
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.RegisterInstance",
@@ -2650,6 +2801,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2668,6 +2822,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2686,6 +2843,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UpdateInstanceCustomHealthStatusRequest,
     ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateInstanceCustomHealthStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "Route53AutoNaming_v20170314.UpdateInstanceCustomHealthStatus",
@@ -2709,6 +2869,9 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         input: UpdateServiceRequest,
     ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateService");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Route53AutoNaming_v20170314.UpdateService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2721,3 +2884,294 @@ impl ServiceDiscovery for ServiceDiscoveryClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceResponse, _>()
     }
 }
+
+/// A blocking client for the ServiceDiscovery API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ServiceDiscoveryClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServiceDiscoveryBlockingClient {
+    client: ServiceDiscoveryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServiceDiscoveryBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServiceDiscoveryBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ServiceDiscoveryBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServiceDiscoveryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServiceDiscoveryBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ServiceDiscoveryBlockingClient {
+        ServiceDiscoveryBlockingClient {
+            client: ServiceDiscoveryClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an HTTP namespace. Service instances that you register using an HTTP namespace can be discovered using a <code>DiscoverInstances</code> request but can't be discovered using DNS. </p> <p>For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="https://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    ///
+    /// See `ServiceDiscovery::create_http_namespace`.
+    pub fn create_http_namespace(
+        &self,
+        input: CreateHttpNamespaceRequest,
+    ) -> Result<CreateHttpNamespaceResponse, RusotoError<CreateHttpNamespaceError>> {
+        self.runtime
+            .block_on(self.client.create_http_namespace(input))
+    }
+
+    /// <p>Creates a private namespace based on DNS, which will be visible only inside a specified Amazon VPC. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="https://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    ///
+    /// See `ServiceDiscovery::create_private_dns_namespace`.
+    pub fn create_private_dns_namespace(
+        &self,
+        input: CreatePrivateDnsNamespaceRequest,
+    ) -> Result<CreatePrivateDnsNamespaceResponse, RusotoError<CreatePrivateDnsNamespaceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_private_dns_namespace(input))
+    }
+
+    /// <p>Creates a public namespace based on DNS, which will be visible on the internet. The namespace defines your service naming scheme. For example, if you name your namespace <code>example.com</code> and name your service <code>backend</code>, the resulting DNS name for the service will be <code>backend.example.com</code>. For the current limit on the number of namespaces that you can create using the same AWS account, see <a href="https://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    ///
+    /// See `ServiceDiscovery::create_public_dns_namespace`.
+    pub fn create_public_dns_namespace(
+        &self,
+        input: CreatePublicDnsNamespaceRequest,
+    ) -> Result<CreatePublicDnsNamespaceResponse, RusotoError<CreatePublicDnsNamespaceError>> {
+        self.runtime
+            .block_on(self.client.create_public_dns_namespace(input))
+    }
+
+    /// <p>Creates a service, which defines the configuration for the following entities:</p> <ul> <li> <p>For public and private DNS namespaces, one of the following combinations of DNS records in Amazon Route 53:</p> <ul> <li> <p>A</p> </li> <li> <p>AAAA</p> </li> <li> <p>A and AAAA</p> </li> <li> <p>SRV</p> </li> <li> <p>CNAME</p> </li> </ul> </li> <li> <p>Optionally, a health check</p> </li> </ul> <p>After you create the service, you can submit a <a href="https://docs.aws.amazon.com/cloud-map/latest/api/API_RegisterInstance.html">RegisterInstance</a> request, and AWS Cloud Map uses the values in the configuration to create the specified entities.</p> <p>For the current limit on the number of instances that you can register using the same namespace and using the same service, see <a href="https://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    ///
+    /// See `ServiceDiscovery::create_service`.
+    pub fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        self.runtime.block_on(self.client.create_service(input))
+    }
+
+    /// <p>Deletes a namespace from the current account. If the namespace still contains one or more services, the request fails.</p>
+    ///
+    /// See `ServiceDiscovery::delete_namespace`.
+    pub fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.runtime.block_on(self.client.delete_namespace(input))
+    }
+
+    /// <p>Deletes a specified service. If the service still contains one or more registered instances, the request fails.</p>
+    ///
+    /// See `ServiceDiscovery::delete_service`.
+    pub fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+        self.runtime.block_on(self.client.delete_service(input))
+    }
+
+    /// <p>Deletes the Amazon Route 53 DNS records and health check, if any, that AWS Cloud Map created for the specified instance.</p>
+    ///
+    /// See `ServiceDiscovery::deregister_instance`.
+    pub fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<DeregisterInstanceResponse, RusotoError<DeregisterInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_instance(input))
+    }
+
+    /// <p>Discovers registered instances for a specified namespace and service. You can use <code>DiscoverInstances</code> to discover instances for any type of namespace. For public and private DNS namespaces, you can also use DNS queries to discover instances.</p>
+    ///
+    /// See `ServiceDiscovery::discover_instances`.
+    pub fn discover_instances(
+        &self,
+        input: DiscoverInstancesRequest,
+    ) -> Result<DiscoverInstancesResponse, RusotoError<DiscoverInstancesError>> {
+        self.runtime.block_on(self.client.discover_instances(input))
+    }
+
+    /// <p>Gets information about a specified instance.</p>
+    ///
+    /// See `ServiceDiscovery::get_instance`.
+    pub fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<GetInstanceResponse, RusotoError<GetInstanceError>> {
+        self.runtime.block_on(self.client.get_instance(input))
+    }
+
+    /// <p><p>Gets the current health status (<code>Healthy</code>, <code>Unhealthy</code>, or <code>Unknown</code>) of one or more instances that are associated with a specified service.</p> <note> <p>There is a brief delay between when you register an instance and when the health status for the instance is available. </p> </note></p>
+    ///
+    /// See `ServiceDiscovery::get_instances_health_status`.
+    pub fn get_instances_health_status(
+        &self,
+        input: GetInstancesHealthStatusRequest,
+    ) -> Result<GetInstancesHealthStatusResponse, RusotoError<GetInstancesHealthStatusError>> {
+        self.runtime
+            .block_on(self.client.get_instances_health_status(input))
+    }
+
+    /// <p>Gets information about a namespace.</p>
+    ///
+    /// See `ServiceDiscovery::get_namespace`.
+    pub fn get_namespace(
+        &self,
+        input: GetNamespaceRequest,
+    ) -> Result<GetNamespaceResponse, RusotoError<GetNamespaceError>> {
+        self.runtime.block_on(self.client.get_namespace(input))
+    }
+
+    /// <p><p>Gets information about any operation that returns an operation ID in the response, such as a <code>CreateService</code> request.</p> <note> <p>To get a list of operations that match specified criteria, see <a href="https://docs.aws.amazon.com/cloud-map/latest/api/API_ListOperations.html">ListOperations</a>.</p> </note></p>
+    ///
+    /// See `ServiceDiscovery::get_operation`.
+    pub fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<GetOperationResponse, RusotoError<GetOperationError>> {
+        self.runtime.block_on(self.client.get_operation(input))
+    }
+
+    /// <p>Gets the settings for a specified service.</p>
+    ///
+    /// See `ServiceDiscovery::get_service`.
+    pub fn get_service(
+        &self,
+        input: GetServiceRequest,
+    ) -> Result<GetServiceResponse, RusotoError<GetServiceError>> {
+        self.runtime.block_on(self.client.get_service(input))
+    }
+
+    /// <p>Lists summary information about the instances that you registered by using a specified service.</p>
+    ///
+    /// See `ServiceDiscovery::list_instances`.
+    pub fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
+        self.runtime.block_on(self.client.list_instances(input))
+    }
+
+    /// <p>Lists summary information about the namespaces that were created by the current AWS account.</p>
+    ///
+    /// See `ServiceDiscovery::list_namespaces`.
+    pub fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
+        self.runtime.block_on(self.client.list_namespaces(input))
+    }
+
+    /// <p>Lists operations that match the criteria that you specify.</p>
+    ///
+    /// See `ServiceDiscovery::list_operations`.
+    pub fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+        self.runtime.block_on(self.client.list_operations(input))
+    }
+
+    /// <p>Lists summary information for all the services that are associated with one or more specified namespaces.</p>
+    ///
+    /// See `ServiceDiscovery::list_services`.
+    pub fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.runtime.block_on(self.client.list_services(input))
+    }
+
+    /// <p>Lists tags for the specified resource.</p>
+    ///
+    /// See `ServiceDiscovery::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates or updates one or more records and, optionally, creates a health check based on the settings in a specified service. When you submit a <code>RegisterInstance</code> request, the following occurs:</p> <ul> <li> <p>For each DNS record that you define in the service that is specified by <code>ServiceId</code>, a record is created or updated in the hosted zone that is associated with the corresponding namespace.</p> </li> <li> <p>If the service includes <code>HealthCheckConfig</code>, a health check is created based on the settings in the health check configuration.</p> </li> <li> <p>The health check, if any, is associated with each of the new or updated records.</p> </li> </ul> <important> <p>One <code>RegisterInstance</code> request must complete before you can submit another request and specify the same service ID and instance ID.</p> </important> <p>For more information, see <a href="https://docs.aws.amazon.com/cloud-map/latest/api/API_CreateService.html">CreateService</a>.</p> <p>When AWS Cloud Map receives a DNS query for the specified DNS name, it returns the applicable value:</p> <ul> <li> <p> <b>If the health check is healthy</b>: returns all the records</p> </li> <li> <p> <b>If the health check is unhealthy</b>: returns the applicable value for the last healthy instance</p> </li> <li> <p> <b>If you didn't specify a health check configuration</b>: returns all the records</p> </li> </ul> <p>For the current limit on the number of instances that you can register using the same namespace and using the same service, see <a href="https://docs.aws.amazon.com/cloud-map/latest/dg/cloud-map-limits.html">AWS Cloud Map Limits</a> in the <i>AWS Cloud Map Developer Guide</i>.</p>
+    ///
+    /// See `ServiceDiscovery::register_instance`.
+    pub fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<RegisterInstanceResponse, RusotoError<RegisterInstanceError>> {
+        self.runtime.block_on(self.client.register_instance(input))
+    }
+
+    /// <p>Adds one or more tags to the specified resource.</p>
+    ///
+    /// See `ServiceDiscovery::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from the specified resource.</p>
+    ///
+    /// See `ServiceDiscovery::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Submits a request to change the health status of a custom health check to healthy or unhealthy.</p> <p>You can use <code>UpdateInstanceCustomHealthStatus</code> to change the status only for custom health checks, which you define using <code>HealthCheckCustomConfig</code> when you create a service. You can't use it to change the status for Route 53 health checks, which you define using <code>HealthCheckConfig</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/cloud-map/latest/api/API_HealthCheckCustomConfig.html">HealthCheckCustomConfig</a>.</p>
+    ///
+    /// See `ServiceDiscovery::update_instance_custom_health_status`.
+    pub fn update_instance_custom_health_status(
+        &self,
+        input: UpdateInstanceCustomHealthStatusRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceCustomHealthStatusError>> {
+        self.runtime
+            .block_on(self.client.update_instance_custom_health_status(input))
+    }
+
+    /// <p>Submits a request to perform the following operations:</p> <ul> <li> <p>Update the TTL setting for existing <code>DnsRecords</code> configurations</p> </li> <li> <p>Add, update, or delete <code>HealthCheckConfig</code> for a specified service</p> <note> <p>You can't add, update, or delete a <code>HealthCheckCustomConfig</code> configuration.</p> </note> </li> </ul> <p>For public and private DNS namespaces, note the following:</p> <ul> <li> <p>If you omit any existing <code>DnsRecords</code> or <code>HealthCheckConfig</code> configurations from an <code>UpdateService</code> request, the configurations are deleted from the service.</p> </li> <li> <p>If you omit an existing <code>HealthCheckCustomConfig</code> configuration from an <code>UpdateService</code> request, the configuration is not deleted from the service.</p> </li> </ul> <p>When you update settings for a service, AWS Cloud Map also updates the corresponding settings in all the records and health checks that were created by using the specified service.</p>
+    ///
+    /// See `ServiceDiscovery::update_service`.
+    pub fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+        self.runtime.block_on(self.client.update_service(input))
+    }
+}
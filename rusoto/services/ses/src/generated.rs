@@ -7101,19 +7101,22 @@ impl CloneReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CloneReceiptRuleSetError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CloneReceiptRuleSetError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(CloneReceiptRuleSetError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CloneReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7161,23 +7164,26 @@ impl CreateConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetAlreadyExists" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationSetError::ConfigurationSetAlreadyExists(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidConfigurationSet" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationSetError::InvalidConfigurationSet(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateConfigurationSetError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7236,37 +7242,43 @@ impl CreateConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
-                    "EventDestinationAlreadyExists" => return RusotoError::Service(
+                    "EventDestinationAlreadyExists" => return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::EventDestinationAlreadyExists(
                             parsed_error.message,
                         ),
                     ),
-                    "InvalidCloudWatchDestination" => return RusotoError::Service(
+                    "InvalidCloudWatchDestination" => return RusotoError::service(
+                        &res,
                         CreateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidFirehoseDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSNSDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationSetEventDestinationError::InvalidSNSDestination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationSetEventDestinationError::LimitExceeded(
                                 parsed_error.message,
                             ),
@@ -7334,19 +7346,22 @@ impl CreateConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
+                        &res,
                         CreateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidTrackingOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
                                 parsed_error.message,
                             ),
                         )
                     }
-                    "TrackingOptionsAlreadyExistsException" => return RusotoError::Service(
+                    "TrackingOptionsAlreadyExistsException" => return RusotoError::service(
+                        &res,
                         CreateConfigurationSetTrackingOptionsError::TrackingOptionsAlreadyExists(
                             parsed_error.message,
                         ),
@@ -7406,7 +7421,7 @@ impl CreateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),"LimitExceeded" => return RusotoError::Service(CreateCustomVerificationEmailTemplateError::LimitExceeded(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::service(&res, CreateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateAlreadyExists" => return RusotoError::service(&res, CreateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateAlreadyExists(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::service(&res, CreateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),"LimitExceeded" => return RusotoError::service(&res, CreateCustomVerificationEmailTemplateError::LimitExceeded(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -7451,14 +7466,16 @@ impl CreateReceiptFilterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptFilterError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptFilterError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptFilterError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptFilterError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7513,39 +7530,46 @@ impl CreateReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptRuleError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "InvalidLambdaFunction" => {
-                        return RusotoError::Service(CreateReceiptRuleError::InvalidLambdaFunction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleError::InvalidLambdaFunction(parsed_error.message),
+                        )
                     }
                     "InvalidS3Configuration" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
                         )
                     }
                     "InvalidSnsTopic" => {
-                        return RusotoError::Service(CreateReceiptRuleError::InvalidSnsTopic(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleError::InvalidSnsTopic(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptRuleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(CreateReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleError::RuleDoesNotExist(parsed_error.message),
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(CreateReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7595,14 +7619,16 @@ impl CreateReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateReceiptRuleSetError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleSetError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateReceiptRuleSetError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateReceiptRuleSetError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7649,19 +7675,22 @@ impl CreateTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateTemplateError::AlreadyExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTemplateError::AlreadyExists(parsed_error.message),
+                        )
                     }
                     "InvalidTemplate" => {
-                        return RusotoError::Service(CreateTemplateError::InvalidTemplate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTemplateError::InvalidTemplate(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateTemplateError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTemplateError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -7705,7 +7734,8 @@ impl DeleteConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteConfigurationSetError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -7756,12 +7786,14 @@ impl DeleteConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
-                    "EventDestinationDoesNotExist" => return RusotoError::Service(
+                    "EventDestinationDoesNotExist" => return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
                             parsed_error.message,
                         ),
@@ -7814,13 +7846,15 @@ impl DeleteConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
+                        &res,
                         DeleteConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
                     "TrackingOptionsDoesNotExistException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -8009,9 +8043,10 @@ impl DeleteReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(DeleteReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8053,9 +8088,10 @@ impl DeleteReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CannotDelete" => {
-                        return RusotoError::Service(DeleteReceiptRuleSetError::CannotDelete(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteReceiptRuleSetError::CannotDelete(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8203,7 +8239,8 @@ impl DescribeConfigurationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeConfigurationSetError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -8253,14 +8290,16 @@ impl DescribeReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(DescribeReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeReceiptRuleError::RuleDoesNotExist(parsed_error.message),
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(DescribeReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DescribeReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8303,7 +8342,8 @@ impl DescribeReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DescribeReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
                         )
                     }
@@ -8382,7 +8422,7 @@ impl GetCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service(&res, GetCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -8666,9 +8706,10 @@ impl GetTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(GetTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTemplateError::TemplateDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -8990,14 +9031,16 @@ impl PutConfigurationSetDeliveryOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutConfigurationSetDeliveryOptionsError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidDeliveryOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             PutConfigurationSetDeliveryOptionsError::InvalidDeliveryOptions(
                                 parsed_error.message,
                             ),
@@ -9048,9 +9091,10 @@ impl PutIdentityPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidPolicy" => {
-                        return RusotoError::Service(PutIdentityPolicyError::InvalidPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PutIdentityPolicyError::InvalidPolicy(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9094,12 +9138,14 @@ impl ReorderReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(ReorderReceiptRuleSetError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ReorderReceiptRuleSetError::RuleDoesNotExist(parsed_error.message),
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ReorderReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
                         )
                     }
@@ -9144,9 +9190,10 @@ impl SendBounceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "MessageRejected" => {
-                        return RusotoError::Service(SendBounceError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendBounceError::MessageRejected(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9198,38 +9245,44 @@ impl SendBulkTemplatedEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendBulkTemplatedEmailError::AccountSendingPaused(parsed_error.message),
                         )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendBulkTemplatedEmailError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendBulkTemplatedEmailError::ConfigurationSetSendingPaused(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendBulkTemplatedEmailError::MailFromDomainNotVerified(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendBulkTemplatedEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendBulkTemplatedEmailError::MessageRejected(parsed_error.message),
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendBulkTemplatedEmailError::TemplateDoesNotExist(parsed_error.message),
                         )
                     }
@@ -9293,7 +9346,7 @@ impl SendCustomVerificationEmailError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(SendCustomVerificationEmailError::FromEmailAddressNotVerified(parsed_error.message)),"MessageRejected" => return RusotoError::Service(SendCustomVerificationEmailError::MessageRejected(parsed_error.message)),"ProductionAccessNotGranted" => return RusotoError::Service(SendCustomVerificationEmailError::ProductionAccessNotGranted(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::service(&res, SendCustomVerificationEmailError::ConfigurationSetDoesNotExist(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service(&res, SendCustomVerificationEmailError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::service(&res, SendCustomVerificationEmailError::FromEmailAddressNotVerified(parsed_error.message)),"MessageRejected" => return RusotoError::service(&res, SendCustomVerificationEmailError::MessageRejected(parsed_error.message)),"ProductionAccessNotGranted" => return RusotoError::service(&res, SendCustomVerificationEmailError::ProductionAccessNotGranted(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -9353,29 +9406,34 @@ impl SendEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendEmailError::AccountSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendEmailError::AccountSendingPaused(parsed_error.message),
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(SendEmailError::ConfigurationSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendEmailError::ConfigurationSetDoesNotExist(parsed_error.message),
+                        )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(SendEmailError::ConfigurationSetSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendEmailError::ConfigurationSetSendingPaused(parsed_error.message),
+                        )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendEmailError::MailFromDomainNotVerified(parsed_error.message),
+                        )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendEmailError::MessageRejected(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9429,29 +9487,34 @@ impl SendRawEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendRawEmailError::AccountSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendRawEmailError::AccountSendingPaused(parsed_error.message),
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendRawEmailError::ConfigurationSetDoesNotExist(parsed_error.message),
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendRawEmailError::ConfigurationSetSendingPaused(parsed_error.message),
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(SendRawEmailError::MailFromDomainNotVerified(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendRawEmailError::MailFromDomainNotVerified(parsed_error.message),
+                        )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendRawEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendRawEmailError::MessageRejected(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9507,40 +9570,46 @@ impl SendTemplatedEmailError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccountSendingPausedException" => {
-                        return RusotoError::Service(SendTemplatedEmailError::AccountSendingPaused(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendTemplatedEmailError::AccountSendingPaused(parsed_error.message),
+                        )
                     }
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendTemplatedEmailError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "ConfigurationSetSendingPausedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendTemplatedEmailError::ConfigurationSetSendingPaused(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MailFromDomainNotVerifiedException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendTemplatedEmailError::MailFromDomainNotVerified(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MessageRejected" => {
-                        return RusotoError::Service(SendTemplatedEmailError::MessageRejected(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendTemplatedEmailError::MessageRejected(parsed_error.message),
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(SendTemplatedEmailError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendTemplatedEmailError::TemplateDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9591,7 +9660,8 @@ impl SetActiveReceiptRuleSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetActiveReceiptRuleSetError::RuleSetDoesNotExist(parsed_error.message),
                         )
                     }
@@ -9813,12 +9883,14 @@ impl SetReceiptRulePositionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(SetReceiptRulePositionError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetReceiptRulePositionError::RuleDoesNotExist(parsed_error.message),
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetReceiptRulePositionError::RuleSetDoesNotExist(parsed_error.message),
                         )
                     }
@@ -9867,23 +9939,26 @@ impl TestRenderTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidRenderingParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestRenderTemplateError::InvalidRenderingParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "MissingRenderingAttribute" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             TestRenderTemplateError::MissingRenderingAttribute(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(TestRenderTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TestRenderTemplateError::TemplateDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -9972,30 +10047,35 @@ impl UpdateConfigurationSetEventDestinationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
-                    "EventDestinationDoesNotExist" => return RusotoError::Service(
+                    "EventDestinationDoesNotExist" => return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::EventDestinationDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
-                    "InvalidCloudWatchDestination" => return RusotoError::Service(
+                    "InvalidCloudWatchDestination" => return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetEventDestinationError::InvalidCloudWatchDestination(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidFirehoseDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationSetEventDestinationError::InvalidFirehoseDestination(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidSNSDestination" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationSetEventDestinationError::InvalidSNSDestination(
                                 parsed_error.message,
                             ),
@@ -10056,7 +10136,7 @@ impl UpdateConfigurationSetReputationMetricsEnabledError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(parsed_error.message)),_ => {}
+                                    "ConfigurationSetDoesNotExist" => return RusotoError::service(&res, UpdateConfigurationSetReputationMetricsEnabledError::ConfigurationSetDoesNotExist(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -10100,7 +10180,8 @@ impl UpdateConfigurationSetSendingEnabledError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConfigurationSetDoesNotExist" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationSetSendingEnabledError::ConfigurationSetDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -10153,20 +10234,23 @@ impl UpdateConfigurationSetTrackingOptionsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ConfigurationSetDoesNotExist" => return RusotoError::Service(
+                    "ConfigurationSetDoesNotExist" => return RusotoError::service(
+                        &res,
                         UpdateConfigurationSetTrackingOptionsError::ConfigurationSetDoesNotExist(
                             parsed_error.message,
                         ),
                     ),
                     "InvalidTrackingOptions" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationSetTrackingOptionsError::InvalidTrackingOptions(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "TrackingOptionsDoesNotExistException" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateConfigurationSetTrackingOptionsError::TrackingOptionsDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -10225,7 +10309,7 @@ impl UpdateCustomVerificationEmailTemplateError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CustomVerificationEmailInvalidContent" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::Service(UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),_ => {}
+                                    "CustomVerificationEmailInvalidContent" => return RusotoError::service(&res, UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailInvalidContent(parsed_error.message)),"CustomVerificationEmailTemplateDoesNotExist" => return RusotoError::service(&res, UpdateCustomVerificationEmailTemplateError::CustomVerificationEmailTemplateDoesNotExist(parsed_error.message)),"FromEmailAddressNotVerified" => return RusotoError::service(&res, UpdateCustomVerificationEmailTemplateError::FromEmailAddressNotVerified(parsed_error.message)),_ => {}
                                 }
             }
         }
@@ -10277,34 +10361,40 @@ impl UpdateReceiptRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidLambdaFunction" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::InvalidLambdaFunction(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateReceiptRuleError::InvalidLambdaFunction(parsed_error.message),
+                        )
                     }
                     "InvalidS3Configuration" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             UpdateReceiptRuleError::InvalidS3Configuration(parsed_error.message),
                         )
                     }
                     "InvalidSnsTopic" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::InvalidSnsTopic(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateReceiptRuleError::InvalidSnsTopic(parsed_error.message),
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::LimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateReceiptRuleError::LimitExceeded(parsed_error.message),
+                        )
                     }
                     "RuleDoesNotExist" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::RuleDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateReceiptRuleError::RuleDoesNotExist(parsed_error.message),
+                        )
                     }
                     "RuleSetDoesNotExist" => {
-                        return RusotoError::Service(UpdateReceiptRuleError::RuleSetDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateReceiptRuleError::RuleSetDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -10353,14 +10443,16 @@ impl UpdateTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTemplate" => {
-                        return RusotoError::Service(UpdateTemplateError::InvalidTemplate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateTemplateError::InvalidTemplate(parsed_error.message),
+                        )
                     }
                     "TemplateDoesNotExist" => {
-                        return RusotoError::Service(UpdateTemplateError::TemplateDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UpdateTemplateError::TemplateDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -11006,6 +11098,7 @@ impl SesClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -11021,6 +11114,7 @@ impl SesClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SesClient {
         SesClient { client, region }
     }
@@ -11034,6 +11128,9 @@ impl Ses for SesClient {
         input: CloneReceiptRuleSetRequest,
     ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CloneReceiptRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CloneReceiptRuleSet");
         let mut params = params;
         CloneReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11056,6 +11153,9 @@ impl Ses for SesClient {
         input: CreateConfigurationSetRequest,
     ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateConfigurationSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateConfigurationSet");
         let mut params = params;
         CreateConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11081,6 +11181,9 @@ impl Ses for SesClient {
         RusotoError<CreateConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateConfigurationSetEventDestination");
         let mut params = params;
         CreateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
@@ -11109,6 +11212,9 @@ impl Ses for SesClient {
         RusotoError<CreateConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateConfigurationSetTrackingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateConfigurationSetTrackingOptions");
         let mut params = params;
         CreateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11134,6 +11240,9 @@ impl Ses for SesClient {
         input: CreateCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateCustomVerificationEmailTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateCustomVerificationEmailTemplate");
         let mut params = params;
         CreateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11157,6 +11266,9 @@ impl Ses for SesClient {
         input: CreateReceiptFilterRequest,
     ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateReceiptFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateReceiptFilter");
         let mut params = params;
         CreateReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -11179,6 +11291,9 @@ impl Ses for SesClient {
         input: CreateReceiptRuleRequest,
     ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateReceiptRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateReceiptRule");
         let mut params = params;
         CreateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -11201,6 +11316,9 @@ impl Ses for SesClient {
         input: CreateReceiptRuleSetRequest,
     ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateReceiptRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateReceiptRuleSet");
         let mut params = params;
         CreateReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11223,6 +11341,9 @@ impl Ses for SesClient {
         input: CreateTemplateRequest,
     ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("CreateTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTemplate");
         let mut params = params;
         CreateTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11245,6 +11366,9 @@ impl Ses for SesClient {
         input: DeleteConfigurationSetRequest,
     ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteConfigurationSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteConfigurationSet");
         let mut params = params;
         DeleteConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11270,6 +11394,9 @@ impl Ses for SesClient {
         RusotoError<DeleteConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteConfigurationSetEventDestination");
         let mut params = params;
         DeleteConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
@@ -11298,6 +11425,9 @@ impl Ses for SesClient {
         RusotoError<DeleteConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteConfigurationSetTrackingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteConfigurationSetTrackingOptions");
         let mut params = params;
         DeleteConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11323,6 +11453,9 @@ impl Ses for SesClient {
         input: DeleteCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteCustomVerificationEmailTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteCustomVerificationEmailTemplate");
         let mut params = params;
         DeleteCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11346,6 +11479,9 @@ impl Ses for SesClient {
         input: DeleteIdentityRequest,
     ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteIdentity");
         let mut params = params;
         DeleteIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -11368,6 +11504,9 @@ impl Ses for SesClient {
         input: DeleteIdentityPolicyRequest,
     ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteIdentityPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteIdentityPolicy");
         let mut params = params;
         DeleteIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -11390,6 +11529,9 @@ impl Ses for SesClient {
         input: DeleteReceiptFilterRequest,
     ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteReceiptFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteReceiptFilter");
         let mut params = params;
         DeleteReceiptFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -11412,6 +11554,9 @@ impl Ses for SesClient {
         input: DeleteReceiptRuleRequest,
     ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteReceiptRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteReceiptRule");
         let mut params = params;
         DeleteReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -11434,6 +11579,9 @@ impl Ses for SesClient {
         input: DeleteReceiptRuleSetRequest,
     ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteReceiptRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteReceiptRuleSet");
         let mut params = params;
         DeleteReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11456,6 +11604,9 @@ impl Ses for SesClient {
         input: DeleteTemplateRequest,
     ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTemplate");
         let mut params = params;
         DeleteTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11478,6 +11629,9 @@ impl Ses for SesClient {
         input: DeleteVerifiedEmailAddressRequest,
     ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DeleteVerifiedEmailAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteVerifiedEmailAddress");
         let mut params = params;
         DeleteVerifiedEmailAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -11499,6 +11653,9 @@ impl Ses for SesClient {
     ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DescribeActiveReceiptRuleSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeActiveReceiptRuleSet");
         let mut params = params;
         DescribeActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11532,6 +11689,9 @@ impl Ses for SesClient {
         input: DescribeConfigurationSetRequest,
     ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DescribeConfigurationSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeConfigurationSet");
         let mut params = params;
         DescribeConfigurationSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11565,6 +11725,9 @@ impl Ses for SesClient {
         input: DescribeReceiptRuleRequest,
     ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DescribeReceiptRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReceiptRule");
         let mut params = params;
         DescribeReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -11598,6 +11761,9 @@ impl Ses for SesClient {
         input: DescribeReceiptRuleSetRequest,
     ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("DescribeReceiptRuleSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("DescribeReceiptRuleSet");
         let mut params = params;
         DescribeReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -11630,6 +11796,9 @@ impl Ses for SesClient {
         &self,
     ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetAccountSendingEnabled");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetAccountSendingEnabled");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11665,6 +11834,9 @@ impl Ses for SesClient {
         RusotoError<GetCustomVerificationEmailTemplateError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetCustomVerificationEmailTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetCustomVerificationEmailTemplate");
         let mut params = params;
         GetCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11702,6 +11874,9 @@ impl Ses for SesClient {
     ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetIdentityDkimAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetIdentityDkimAttributes");
         let mut params = params;
         GetIdentityDkimAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11738,6 +11913,9 @@ impl Ses for SesClient {
         RusotoError<GetIdentityMailFromDomainAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetIdentityMailFromDomainAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetIdentityMailFromDomainAttributes");
         let mut params = params;
         GetIdentityMailFromDomainAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11777,6 +11955,9 @@ impl Ses for SesClient {
         RusotoError<GetIdentityNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetIdentityNotificationAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetIdentityNotificationAttributes");
         let mut params = params;
         GetIdentityNotificationAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11813,6 +11994,9 @@ impl Ses for SesClient {
         input: GetIdentityPoliciesRequest,
     ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetIdentityPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetIdentityPolicies");
         let mut params = params;
         GetIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -11849,6 +12033,9 @@ impl Ses for SesClient {
         RusotoError<GetIdentityVerificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetIdentityVerificationAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetIdentityVerificationAttributes");
         let mut params = params;
         GetIdentityVerificationAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -11882,6 +12069,9 @@ impl Ses for SesClient {
     /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
     async fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetSendQuota");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetSendQuota");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11911,6 +12101,9 @@ impl Ses for SesClient {
         &self,
     ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetSendStatistics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetSendStatistics");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11943,6 +12136,9 @@ impl Ses for SesClient {
         input: GetTemplateRequest,
     ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("GetTemplate");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTemplate");
         let mut params = params;
         GetTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -11973,6 +12169,9 @@ impl Ses for SesClient {
         input: ListConfigurationSetsRequest,
     ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListConfigurationSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListConfigurationSets");
         let mut params = params;
         ListConfigurationSetsRequestSerializer::serialize(&mut params, "", &input);
@@ -12009,6 +12208,9 @@ impl Ses for SesClient {
         RusotoError<ListCustomVerificationEmailTemplatesError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListCustomVerificationEmailTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListCustomVerificationEmailTemplates");
         let mut params = params;
         ListCustomVerificationEmailTemplatesRequestSerializer::serialize(&mut params, "", &input);
@@ -12045,6 +12247,9 @@ impl Ses for SesClient {
         input: ListIdentitiesRequest,
     ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListIdentities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListIdentities");
         let mut params = params;
         ListIdentitiesRequestSerializer::serialize(&mut params, "", &input);
@@ -12076,6 +12281,9 @@ impl Ses for SesClient {
         input: ListIdentityPoliciesRequest,
     ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListIdentityPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListIdentityPolicies");
         let mut params = params;
         ListIdentityPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -12109,6 +12317,9 @@ impl Ses for SesClient {
         input: ListReceiptFiltersRequest,
     ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListReceiptFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListReceiptFilters");
         let mut params = params;
         ListReceiptFiltersRequestSerializer::serialize(&mut params, "", &input);
@@ -12142,6 +12353,9 @@ impl Ses for SesClient {
         input: ListReceiptRuleSetsRequest,
     ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListReceiptRuleSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListReceiptRuleSets");
         let mut params = params;
         ListReceiptRuleSetsRequestSerializer::serialize(&mut params, "", &input);
@@ -12175,6 +12389,9 @@ impl Ses for SesClient {
         input: ListTemplatesRequest,
     ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListTemplates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTemplates");
         let mut params = params;
         ListTemplatesRequestSerializer::serialize(&mut params, "", &input);
@@ -12206,6 +12423,9 @@ impl Ses for SesClient {
     ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ListVerifiedEmailAddresses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListVerifiedEmailAddresses");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -12241,6 +12461,9 @@ impl Ses for SesClient {
         RusotoError<PutConfigurationSetDeliveryOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("PutConfigurationSetDeliveryOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutConfigurationSetDeliveryOptions");
         let mut params = params;
         PutConfigurationSetDeliveryOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -12266,6 +12489,9 @@ impl Ses for SesClient {
         input: PutIdentityPolicyRequest,
     ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("PutIdentityPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PutIdentityPolicy");
         let mut params = params;
         PutIdentityPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -12288,6 +12514,9 @@ impl Ses for SesClient {
         input: ReorderReceiptRuleSetRequest,
     ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("ReorderReceiptRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReorderReceiptRuleSet");
         let mut params = params;
         ReorderReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -12310,6 +12539,9 @@ impl Ses for SesClient {
         input: SendBounceRequest,
     ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SendBounce");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendBounce");
         let mut params = params;
         SendBounceRequestSerializer::serialize(&mut params, "", &input);
@@ -12340,6 +12572,9 @@ impl Ses for SesClient {
         input: SendBulkTemplatedEmailRequest,
     ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SendBulkTemplatedEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendBulkTemplatedEmail");
         let mut params = params;
         SendBulkTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12374,6 +12609,9 @@ impl Ses for SesClient {
     ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SendCustomVerificationEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendCustomVerificationEmail");
         let mut params = params;
         SendCustomVerificationEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12407,6 +12645,9 @@ impl Ses for SesClient {
         input: SendEmailRequest,
     ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SendEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendEmail");
         let mut params = params;
         SendEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12437,6 +12678,9 @@ impl Ses for SesClient {
         input: SendRawEmailRequest,
     ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SendRawEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendRawEmail");
         let mut params = params;
         SendRawEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12468,6 +12712,9 @@ impl Ses for SesClient {
         input: SendTemplatedEmailRequest,
     ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SendTemplatedEmail");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendTemplatedEmail");
         let mut params = params;
         SendTemplatedEmailRequestSerializer::serialize(&mut params, "", &input);
@@ -12501,6 +12748,9 @@ impl Ses for SesClient {
         input: SetActiveReceiptRuleSetRequest,
     ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetActiveReceiptRuleSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetActiveReceiptRuleSet");
         let mut params = params;
         SetActiveReceiptRuleSetRequestSerializer::serialize(&mut params, "", &input);
@@ -12523,6 +12773,9 @@ impl Ses for SesClient {
         input: SetIdentityDkimEnabledRequest,
     ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetIdentityDkimEnabled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetIdentityDkimEnabled");
         let mut params = params;
         SetIdentityDkimEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12548,6 +12801,9 @@ impl Ses for SesClient {
         RusotoError<SetIdentityFeedbackForwardingEnabledError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetIdentityFeedbackForwardingEnabled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetIdentityFeedbackForwardingEnabled");
         let mut params = params;
         SetIdentityFeedbackForwardingEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12576,6 +12832,9 @@ impl Ses for SesClient {
         RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetIdentityHeadersInNotificationsEnabled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetIdentityHeadersInNotificationsEnabled");
         let mut params = params;
         SetIdentityHeadersInNotificationsEnabledRequestSerializer::serialize(
@@ -12606,6 +12865,9 @@ impl Ses for SesClient {
     ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetIdentityMailFromDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetIdentityMailFromDomain");
         let mut params = params;
         SetIdentityMailFromDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -12629,6 +12891,9 @@ impl Ses for SesClient {
     ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
     {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetIdentityNotificationTopic");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetIdentityNotificationTopic");
         let mut params = params;
         SetIdentityNotificationTopicRequestSerializer::serialize(&mut params, "", &input);
@@ -12651,6 +12916,9 @@ impl Ses for SesClient {
         input: SetReceiptRulePositionRequest,
     ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("SetReceiptRulePosition");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetReceiptRulePosition");
         let mut params = params;
         SetReceiptRulePositionRequestSerializer::serialize(&mut params, "", &input);
@@ -12673,6 +12941,9 @@ impl Ses for SesClient {
         input: TestRenderTemplateRequest,
     ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("TestRenderTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TestRenderTemplate");
         let mut params = params;
         TestRenderTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -12706,6 +12977,9 @@ impl Ses for SesClient {
         input: UpdateAccountSendingEnabledRequest,
     ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateAccountSendingEnabled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateAccountSendingEnabled");
         let mut params = params;
         UpdateAccountSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12729,6 +13003,9 @@ impl Ses for SesClient {
         RusotoError<UpdateConfigurationSetEventDestinationError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateConfigurationSetEventDestination");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateConfigurationSetEventDestination");
         let mut params = params;
         UpdateConfigurationSetEventDestinationRequestSerializer::serialize(&mut params, "", &input);
@@ -12754,6 +13031,9 @@ impl Ses for SesClient {
         input: UpdateConfigurationSetReputationMetricsEnabledRequest,
     ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateConfigurationSetReputationMetricsEnabled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateConfigurationSetReputationMetricsEnabled");
         let mut params = params;
         UpdateConfigurationSetReputationMetricsEnabledRequestSerializer::serialize(
@@ -12781,6 +13061,9 @@ impl Ses for SesClient {
         input: UpdateConfigurationSetSendingEnabledRequest,
     ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateConfigurationSetSendingEnabled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateConfigurationSetSendingEnabled");
         let mut params = params;
         UpdateConfigurationSetSendingEnabledRequestSerializer::serialize(&mut params, "", &input);
@@ -12807,6 +13090,9 @@ impl Ses for SesClient {
         RusotoError<UpdateConfigurationSetTrackingOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateConfigurationSetTrackingOptions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateConfigurationSetTrackingOptions");
         let mut params = params;
         UpdateConfigurationSetTrackingOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -12832,6 +13118,9 @@ impl Ses for SesClient {
         input: UpdateCustomVerificationEmailTemplateRequest,
     ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateCustomVerificationEmailTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateCustomVerificationEmailTemplate");
         let mut params = params;
         UpdateCustomVerificationEmailTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -12855,6 +13144,9 @@ impl Ses for SesClient {
         input: UpdateReceiptRuleRequest,
     ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateReceiptRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateReceiptRule");
         let mut params = params;
         UpdateReceiptRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -12877,6 +13169,9 @@ impl Ses for SesClient {
         input: UpdateTemplateRequest,
     ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("UpdateTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UpdateTemplate");
         let mut params = params;
         UpdateTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -12899,6 +13194,9 @@ impl Ses for SesClient {
         input: VerifyDomainDkimRequest,
     ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("VerifyDomainDkim");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("VerifyDomainDkim");
         let mut params = params;
         VerifyDomainDkimRequestSerializer::serialize(&mut params, "", &input);
@@ -12930,6 +13228,9 @@ impl Ses for SesClient {
         input: VerifyDomainIdentityRequest,
     ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("VerifyDomainIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("VerifyDomainIdentity");
         let mut params = params;
         VerifyDomainIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -12963,6 +13264,9 @@ impl Ses for SesClient {
         input: VerifyEmailAddressRequest,
     ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("VerifyEmailAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("VerifyEmailAddress");
         let mut params = params;
         VerifyEmailAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -12983,6 +13287,9 @@ impl Ses for SesClient {
         input: VerifyEmailIdentityRequest,
     ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
         let mut request = SignedRequest::new("POST", "email", &self.region, "/");
+        request.set_operation_name("VerifyEmailIdentity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("VerifyEmailIdentity");
         let mut params = params;
         VerifyEmailIdentityRequestSerializer::serialize(&mut params, "", &input);
@@ -13000,6 +13307,876 @@ impl Ses for SesClient {
     }
 }
 
+/// A blocking client for the Amazon SES API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SesClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SesBlockingClient {
+    client: SesClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SesBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SesBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SesBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SesBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SesBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SesBlockingClient {
+        SesBlockingClient {
+            client: SesClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a receipt rule set by cloning an existing one. All receipt rules and configurations are copied to the new receipt rule set and are completely independent of the source rule set.</p> <p>For information about setting up rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::clone_receipt_rule_set`.
+    pub fn clone_receipt_rule_set(
+        &self,
+        input: CloneReceiptRuleSetRequest,
+    ) -> Result<CloneReceiptRuleSetResponse, RusotoError<CloneReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.clone_receipt_rule_set(input))
+    }
+
+    /// <p>Creates a configuration set.</p> <p>Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_configuration_set`.
+    pub fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_set(input))
+    }
+
+    /// <p>Creates a configuration set event destination.</p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>An event destination is the AWS service to which Amazon SES publishes the email sending events associated with a configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_configuration_set_event_destination`.
+    pub fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p>Creates an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::create_configuration_set_tracking_options`.
+    pub fn create_configuration_set_tracking_options(
+        &self,
+        input: CreateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        CreateConfigurationSetTrackingOptionsResponse,
+        RusotoError<CreateConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_configuration_set_tracking_options(input))
+    }
+
+    /// <p>Creates a new custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_custom_verification_email_template`.
+    pub fn create_custom_verification_email_template(
+        &self,
+        input: CreateCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<CreateCustomVerificationEmailTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_custom_verification_email_template(input))
+    }
+
+    /// <p>Creates a new IP address filter.</p> <p>For information about setting up IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_receipt_filter`.
+    pub fn create_receipt_filter(
+        &self,
+        input: CreateReceiptFilterRequest,
+    ) -> Result<CreateReceiptFilterResponse, RusotoError<CreateReceiptFilterError>> {
+        self.runtime
+            .block_on(self.client.create_receipt_filter(input))
+    }
+
+    /// <p>Creates a receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_receipt_rule`.
+    pub fn create_receipt_rule(
+        &self,
+        input: CreateReceiptRuleRequest,
+    ) -> Result<CreateReceiptRuleResponse, RusotoError<CreateReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.create_receipt_rule(input))
+    }
+
+    /// <p>Creates an empty receipt rule set.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_receipt_rule_set`.
+    pub fn create_receipt_rule_set(
+        &self,
+        input: CreateReceiptRuleSetRequest,
+    ) -> Result<CreateReceiptRuleSetResponse, RusotoError<CreateReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.create_receipt_rule_set(input))
+    }
+
+    /// <p>Creates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::create_template`.
+    pub fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        self.runtime.block_on(self.client.create_template(input))
+    }
+
+    /// <p>Deletes a configuration set. Configuration sets enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_configuration_set`.
+    pub fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_set(input))
+    }
+
+    /// <p>Deletes a configuration set event destination. Configuration set event destinations are associated with configuration sets, which enable you to publish email sending events. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_configuration_set_event_destination`.
+    pub fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p><p>Deletes an association between a configuration set and a custom domain for open and click event tracking.</p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p> <note> <p>Deleting this kind of association will result in emails sent using the specified configuration set to capture open and click events using the standard, Amazon SES-operated domains.</p> </note></p>
+    ///
+    /// See `Ses::delete_configuration_set_tracking_options`.
+    pub fn delete_configuration_set_tracking_options(
+        &self,
+        input: DeleteConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        DeleteConfigurationSetTrackingOptionsResponse,
+        RusotoError<DeleteConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_configuration_set_tracking_options(input))
+    }
+
+    /// <p>Deletes an existing custom verification email template. </p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_custom_verification_email_template`.
+    pub fn delete_custom_verification_email_template(
+        &self,
+        input: DeleteCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<DeleteCustomVerificationEmailTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_verification_email_template(input))
+    }
+
+    /// <p>Deletes the specified identity (an email address or a domain) from the list of verified identities.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_identity`.
+    pub fn delete_identity(
+        &self,
+        input: DeleteIdentityRequest,
+    ) -> Result<DeleteIdentityResponse, RusotoError<DeleteIdentityError>> {
+        self.runtime.block_on(self.client.delete_identity(input))
+    }
+
+    /// <p>Deletes the specified sending authorization policy for the given identity (an email address or a domain). This API returns successfully even if a policy with the specified name does not exist.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_identity_policy`.
+    pub fn delete_identity_policy(
+        &self,
+        input: DeleteIdentityPolicyRequest,
+    ) -> Result<DeleteIdentityPolicyResponse, RusotoError<DeleteIdentityPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_identity_policy(input))
+    }
+
+    /// <p>Deletes the specified IP address filter.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_receipt_filter`.
+    pub fn delete_receipt_filter(
+        &self,
+        input: DeleteReceiptFilterRequest,
+    ) -> Result<DeleteReceiptFilterResponse, RusotoError<DeleteReceiptFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_receipt_filter(input))
+    }
+
+    /// <p>Deletes the specified receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_receipt_rule`.
+    pub fn delete_receipt_rule(
+        &self,
+        input: DeleteReceiptRuleRequest,
+    ) -> Result<DeleteReceiptRuleResponse, RusotoError<DeleteReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_receipt_rule(input))
+    }
+
+    /// <p>Deletes the specified receipt rule set and all of the receipt rules it contains.</p> <note> <p>The currently active rule set cannot be deleted.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_receipt_rule_set`.
+    pub fn delete_receipt_rule_set(
+        &self,
+        input: DeleteReceiptRuleSetRequest,
+    ) -> Result<DeleteReceiptRuleSetResponse, RusotoError<DeleteReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.delete_receipt_rule_set(input))
+    }
+
+    /// <p>Deletes an email template.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::delete_template`.
+    pub fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        self.runtime.block_on(self.client.delete_template(input))
+    }
+
+    /// <p>Deprecated. Use the <code>DeleteIdentity</code> operation to delete email addresses and domains.</p>
+    ///
+    /// See `Ses::delete_verified_email_address`.
+    pub fn delete_verified_email_address(
+        &self,
+        input: DeleteVerifiedEmailAddressRequest,
+    ) -> Result<(), RusotoError<DeleteVerifiedEmailAddressError>> {
+        self.runtime
+            .block_on(self.client.delete_verified_email_address(input))
+    }
+
+    /// <p>Returns the metadata and receipt rules for the receipt rule set that is currently active.</p> <p>For information about setting up receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rule-set.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::describe_active_receipt_rule_set`.
+    pub fn describe_active_receipt_rule_set(
+        &self,
+        input: DescribeActiveReceiptRuleSetRequest,
+    ) -> Result<DescribeActiveReceiptRuleSetResponse, RusotoError<DescribeActiveReceiptRuleSetError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_active_receipt_rule_set(input))
+    }
+
+    /// <p>Returns the details of the specified configuration set. For information about using configuration sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::describe_configuration_set`.
+    pub fn describe_configuration_set(
+        &self,
+        input: DescribeConfigurationSetRequest,
+    ) -> Result<DescribeConfigurationSetResponse, RusotoError<DescribeConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.describe_configuration_set(input))
+    }
+
+    /// <p>Returns the details of the specified receipt rule.</p> <p>For information about setting up receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::describe_receipt_rule`.
+    pub fn describe_receipt_rule(
+        &self,
+        input: DescribeReceiptRuleRequest,
+    ) -> Result<DescribeReceiptRuleResponse, RusotoError<DescribeReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.describe_receipt_rule(input))
+    }
+
+    /// <p>Returns the details of the specified receipt rule set.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::describe_receipt_rule_set`.
+    pub fn describe_receipt_rule_set(
+        &self,
+        input: DescribeReceiptRuleSetRequest,
+    ) -> Result<DescribeReceiptRuleSetResponse, RusotoError<DescribeReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.describe_receipt_rule_set(input))
+    }
+
+    /// <p>Returns the email sending status of the Amazon SES account for the current region.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::get_account_sending_enabled`.
+    pub fn get_account_sending_enabled(
+        &self,
+    ) -> Result<GetAccountSendingEnabledResponse, RusotoError<GetAccountSendingEnabledError>> {
+        self.runtime
+            .block_on(self.client.get_account_sending_enabled())
+    }
+
+    /// <p>Returns the custom email verification template for the template name you specify.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::get_custom_verification_email_template`.
+    pub fn get_custom_verification_email_template(
+        &self,
+        input: GetCustomVerificationEmailTemplateRequest,
+    ) -> Result<
+        GetCustomVerificationEmailTemplateResponse,
+        RusotoError<GetCustomVerificationEmailTemplateError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_custom_verification_email_template(input))
+    }
+
+    /// <p>Returns the current status of Easy DKIM signing for an entity. For domain name identities, this operation also returns the DKIM tokens that are required for Easy DKIM signing, and whether Amazon SES has successfully verified that these tokens have been published.</p> <p>This operation takes a list of identities as input and returns the following information for each:</p> <ul> <li> <p>Whether Easy DKIM signing is enabled or disabled.</p> </li> <li> <p>A set of DKIM tokens that represent the identity. If the identity is an email address, the tokens represent the domain of that address.</p> </li> <li> <p>Whether Amazon SES has successfully verified the DKIM tokens published in the domain's DNS. This information is only returned for domain name identities, not for email addresses.</p> </li> </ul> <p>This operation is throttled at one request per second and can only get DKIM attributes for up to 100 identities at a time.</p> <p>For more information about creating DNS records using DKIM tokens, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim-dns-records.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::get_identity_dkim_attributes`.
+    pub fn get_identity_dkim_attributes(
+        &self,
+        input: GetIdentityDkimAttributesRequest,
+    ) -> Result<GetIdentityDkimAttributesResponse, RusotoError<GetIdentityDkimAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_identity_dkim_attributes(input))
+    }
+
+    /// <p>Returns the custom MAIL FROM attributes for a list of identities (email addresses : domains).</p> <p>This operation is throttled at one request per second and can only get custom MAIL FROM attributes for up to 100 identities at a time.</p>
+    ///
+    /// See `Ses::get_identity_mail_from_domain_attributes`.
+    pub fn get_identity_mail_from_domain_attributes(
+        &self,
+        input: GetIdentityMailFromDomainAttributesRequest,
+    ) -> Result<
+        GetIdentityMailFromDomainAttributesResponse,
+        RusotoError<GetIdentityMailFromDomainAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_mail_from_domain_attributes(input))
+    }
+
+    /// <p>Given a list of verified identities (email addresses and/or domains), returns a structure describing identity notification attributes.</p> <p>This operation is throttled at one request per second and can only get notification attributes for up to 100 identities at a time.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::get_identity_notification_attributes`.
+    pub fn get_identity_notification_attributes(
+        &self,
+        input: GetIdentityNotificationAttributesRequest,
+    ) -> Result<
+        GetIdentityNotificationAttributesResponse,
+        RusotoError<GetIdentityNotificationAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_notification_attributes(input))
+    }
+
+    /// <p>Returns the requested sending authorization policies for the given identity (an email address or a domain). The policies are returned as a map of policy names to policy contents. You can retrieve a maximum of 20 policies at a time.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::get_identity_policies`.
+    pub fn get_identity_policies(
+        &self,
+        input: GetIdentityPoliciesRequest,
+    ) -> Result<GetIdentityPoliciesResponse, RusotoError<GetIdentityPoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_identity_policies(input))
+    }
+
+    /// <p>Given a list of identities (email addresses and/or domains), returns the verification status and (for domain identities) the verification token for each identity.</p> <p>The verification status of an email address is "Pending" until the email address owner clicks the link within the verification email that Amazon SES sent to that address. If the email address owner clicks the link within 24 hours, the verification status of the email address changes to "Success". If the link is not clicked within 24 hours, the verification status changes to "Failed." In that case, if you still want to verify the email address, you must restart the verification process from the beginning.</p> <p>For domain identities, the domain's verification status is "Pending" as Amazon SES searches for the required TXT record in the DNS settings of the domain. When Amazon SES detects the record, the domain's verification status changes to "Success". If Amazon SES is unable to detect the record within 72 hours, the domain's verification status changes to "Failed." In that case, if you still want to verify the domain, you must restart the verification process from the beginning.</p> <p>This operation is throttled at one request per second and can only get verification attributes for up to 100 identities at a time.</p>
+    ///
+    /// See `Ses::get_identity_verification_attributes`.
+    pub fn get_identity_verification_attributes(
+        &self,
+        input: GetIdentityVerificationAttributesRequest,
+    ) -> Result<
+        GetIdentityVerificationAttributesResponse,
+        RusotoError<GetIdentityVerificationAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_verification_attributes(input))
+    }
+
+    /// <p>Provides the sending limits for the Amazon SES account. </p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::get_send_quota`.
+    pub fn get_send_quota(&self) -> Result<GetSendQuotaResponse, RusotoError<GetSendQuotaError>> {
+        self.runtime.block_on(self.client.get_send_quota())
+    }
+
+    /// <p>Provides sending statistics for the current AWS Region. The result is a list of data points, representing the last two weeks of sending activity. Each data point in the list contains statistics for a 15-minute period of time.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::get_send_statistics`.
+    pub fn get_send_statistics(
+        &self,
+    ) -> Result<GetSendStatisticsResponse, RusotoError<GetSendStatisticsError>> {
+        self.runtime.block_on(self.client.get_send_statistics())
+    }
+
+    /// <p>Displays the template object (which includes the Subject line, HTML part and text part) for the template you specify.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::get_template`.
+    pub fn get_template(
+        &self,
+        input: GetTemplateRequest,
+    ) -> Result<GetTemplateResponse, RusotoError<GetTemplateError>> {
+        self.runtime.block_on(self.client.get_template(input))
+    }
+
+    /// <p>Provides a list of the configuration sets associated with your Amazon SES account in the current AWS Region. For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second. This operation will return up to 1,000 configuration sets each time it is run. If your Amazon SES account has more than 1,000 configuration sets, this operation will also return a NextToken element. You can then execute the <code>ListConfigurationSets</code> operation again, passing the <code>NextToken</code> parameter and the value of the NextToken element to retrieve additional results.</p>
+    ///
+    /// See `Ses::list_configuration_sets`.
+    pub fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_sets(input))
+    }
+
+    /// <p>Lists the existing custom verification email templates for your account in the current AWS Region.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::list_custom_verification_email_templates`.
+    pub fn list_custom_verification_email_templates(
+        &self,
+        input: ListCustomVerificationEmailTemplatesRequest,
+    ) -> Result<
+        ListCustomVerificationEmailTemplatesResponse,
+        RusotoError<ListCustomVerificationEmailTemplatesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_custom_verification_email_templates(input))
+    }
+
+    /// <p>Returns a list containing all of the identities (email addresses and domains) for your AWS account in the current AWS Region, regardless of verification status.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::list_identities`.
+    pub fn list_identities(
+        &self,
+        input: ListIdentitiesRequest,
+    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        self.runtime.block_on(self.client.list_identities(input))
+    }
+
+    /// <p>Returns a list of sending authorization policies that are attached to the given identity (an email address or a domain). This API returns only a list. If you want the actual policy content, you can use <code>GetIdentityPolicies</code>.</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::list_identity_policies`.
+    pub fn list_identity_policies(
+        &self,
+        input: ListIdentityPoliciesRequest,
+    ) -> Result<ListIdentityPoliciesResponse, RusotoError<ListIdentityPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_identity_policies(input))
+    }
+
+    /// <p>Lists the IP address filters associated with your AWS account in the current AWS Region.</p> <p>For information about managing IP address filters, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-ip-filters.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::list_receipt_filters`.
+    pub fn list_receipt_filters(
+        &self,
+        input: ListReceiptFiltersRequest,
+    ) -> Result<ListReceiptFiltersResponse, RusotoError<ListReceiptFiltersError>> {
+        self.runtime
+            .block_on(self.client.list_receipt_filters(input))
+    }
+
+    /// <p>Lists the receipt rule sets that exist under your AWS account in the current AWS Region. If there are additional receipt rule sets to be retrieved, you will receive a <code>NextToken</code> that you can provide to the next call to <code>ListReceiptRuleSets</code> to retrieve the additional entries.</p> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::list_receipt_rule_sets`.
+    pub fn list_receipt_rule_sets(
+        &self,
+        input: ListReceiptRuleSetsRequest,
+    ) -> Result<ListReceiptRuleSetsResponse, RusotoError<ListReceiptRuleSetsError>> {
+        self.runtime
+            .block_on(self.client.list_receipt_rule_sets(input))
+    }
+
+    /// <p>Lists the email templates present in your Amazon SES account in the current AWS Region.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::list_templates`.
+    pub fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        self.runtime.block_on(self.client.list_templates(input))
+    }
+
+    /// <p>Deprecated. Use the <code>ListIdentities</code> operation to list the email addresses and domains associated with your account.</p>
+    ///
+    /// See `Ses::list_verified_email_addresses`.
+    pub fn list_verified_email_addresses(
+        &self,
+    ) -> Result<ListVerifiedEmailAddressesResponse, RusotoError<ListVerifiedEmailAddressesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_verified_email_addresses())
+    }
+
+    /// <p>Adds or updates the delivery options for a configuration set.</p>
+    ///
+    /// See `Ses::put_configuration_set_delivery_options`.
+    pub fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetDeliveryOptionsResponse,
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_delivery_options(input))
+    }
+
+    /// <p>Adds or updates a sending authorization policy for the specified identity (an email address or a domain).</p> <note> <p>This API is for the identity owner only. If you have not verified the identity, this API will return an error.</p> </note> <p>Sending authorization is a feature that enables an identity owner to authorize other senders to use its identities. For information about using sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::put_identity_policy`.
+    pub fn put_identity_policy(
+        &self,
+        input: PutIdentityPolicyRequest,
+    ) -> Result<PutIdentityPolicyResponse, RusotoError<PutIdentityPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_identity_policy(input))
+    }
+
+    /// <p>Reorders the receipt rules within a receipt rule set.</p> <note> <p>All of the rules in the rule set must be represented in this request. That is, this API will return an error if the reorder request doesn't explicitly position all of the rules.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::reorder_receipt_rule_set`.
+    pub fn reorder_receipt_rule_set(
+        &self,
+        input: ReorderReceiptRuleSetRequest,
+    ) -> Result<ReorderReceiptRuleSetResponse, RusotoError<ReorderReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.reorder_receipt_rule_set(input))
+    }
+
+    /// <p>Generates and sends a bounce message to the sender of an email you received through Amazon SES. You can only use this API on an email up to 24 hours after you receive it.</p> <note> <p>You cannot use this API to send generic bounces for mail that was not received by Amazon SES.</p> </note> <p>For information about receiving email through Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::send_bounce`.
+    pub fn send_bounce(
+        &self,
+        input: SendBounceRequest,
+    ) -> Result<SendBounceResponse, RusotoError<SendBounceError>> {
+        self.runtime.block_on(self.client.send_bounce(input))
+    }
+
+    /// <p><p>Composes an email message to multiple destinations. The message body is created using an email template.</p> <p>In order to send email using the <code>SendBulkTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Each <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendBulkTemplatedEmail</code> operation several times to send the message to each group.</p> </li> <li> <p>The number of destinations you can contact in a single call to the API may be limited by your account&#39;s maximum sending rate.</p> </li> </ul></p>
+    ///
+    /// See `Ses::send_bulk_templated_email`.
+    pub fn send_bulk_templated_email(
+        &self,
+        input: SendBulkTemplatedEmailRequest,
+    ) -> Result<SendBulkTemplatedEmailResponse, RusotoError<SendBulkTemplatedEmailError>> {
+        self.runtime
+            .block_on(self.client.send_bulk_templated_email(input))
+    }
+
+    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. As a result of executing this operation, a customized verification email is sent to the specified address.</p> <p>To use this operation, you must first create a custom verification email template. For more information about creating and using custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::send_custom_verification_email`.
+    pub fn send_custom_verification_email(
+        &self,
+        input: SendCustomVerificationEmailRequest,
+    ) -> Result<SendCustomVerificationEmailResponse, RusotoError<SendCustomVerificationEmailError>>
+    {
+        self.runtime
+            .block_on(self.client.send_custom_verification_email(input))
+    }
+
+    /// <p><p>Composes an email message and immediately queues it for sending. In order to send email using the <code>SendEmail</code> operation, your message must meet the following requirements:</p> <ul> <li> <p>The message must be sent from a verified email address or domain. If you attempt to send email using a non-verified address or domain, the operation will result in an &quot;Email address not verified&quot; error. </p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>The message must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> <li> <p>The message may not include more than 50 recipients, across the To:, CC: and BCC: fields. If you need to send an email message to a larger audience, you can divide your recipient list into groups of 50 or fewer, and then call the <code>SendEmail</code> operation several times to send the message to each group.</p> </li> </ul> <important> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </important></p>
+    ///
+    /// See `Ses::send_email`.
+    pub fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        self.runtime.block_on(self.client.send_email(input))
+    }
+
+    /// <p><p>Composes an email message and immediately queues it for sending.</p> <p>This operation is more flexible than the <code>SendEmail</code> API operation. When you use the <code>SendRawEmail</code> operation, you can specify the headers of the message as well as its content. This flexibility is useful, for example, when you want to send a multipart MIME email (such a message that contains both a text and an HTML version). You can also use this operation to send messages that include attachments.</p> <p>The <code>SendRawEmail</code> operation has the following requirements:</p> <ul> <li> <p>You can only send email from <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">verified email addresses or domains</a>. If you try to send email from an address that isn&#39;t verified, the operation results in an &quot;Email address not verified&quot; error.</p> </li> <li> <p>If your account is still in the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/request-production-access.html">Amazon SES sandbox</a>, you can only send email to other verified addresses in your account, or to addresses that are associated with the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mailbox-simulator.html">Amazon SES mailbox simulator</a>.</p> </li> <li> <p>The maximum message size, including attachments, is 10 MB.</p> </li> <li> <p>Each message has to include at least one recipient address. A recipient address includes any address on the To:, CC:, or BCC: lines.</p> </li> <li> <p>If you send a single message to more than one recipient address, and one of the recipient addresses isn&#39;t in a valid format (that is, it&#39;s not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), Amazon SES rejects the entire message, even if the other addresses are valid.</p> </li> <li> <p>Each message can include up to 50 recipient addresses across the To:, CC:, or BCC: lines. If you need to send a single message to more than 50 recipients, you have to split the list of recipient addresses into groups of less than 50 recipients, and send separate messages to each group.</p> </li> <li> <p>Amazon SES allows you to specify 8-bit Content-Transfer-Encoding for MIME message parts. However, if Amazon SES has to modify the contents of your message (for example, if you use open and click tracking), 8-bit content isn&#39;t preserved. For this reason, we highly recommend that you encode all content that isn&#39;t 7-bit ASCII. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-email-raw.html#send-email-mime-encoding">MIME Encoding</a> in the <i>Amazon SES Developer Guide</i>.</p> </li> </ul> <p>Additionally, keep the following considerations in mind when using the <code>SendRawEmail</code> operation:</p> <ul> <li> <p>Although you can customize the message headers when using the <code>SendRawEmail</code> operation, Amazon SES will automatically apply its own <code>Message-ID</code> and <code>Date</code> headers; if you passed these headers when creating the message, they will be overwritten by the values that Amazon SES provides.</p> </li> <li> <p>If you are using sending authorization to send on behalf of another user, <code>SendRawEmail</code> enables you to specify the cross-account identity for the email&#39;s Source, From, and Return-Path parameters in one of two ways: you can pass optional parameters <code>SourceArn</code>, <code>FromArn</code>, and/or <code>ReturnPathArn</code> to the API, or you can include the following X-headers in the header of your raw email:</p> <ul> <li> <p> <code>X-SES-SOURCE-ARN</code> </p> </li> <li> <p> <code>X-SES-FROM-ARN</code> </p> </li> <li> <p> <code>X-SES-RETURN-PATH-ARN</code> </p> </li> </ul> <important> <p>Don&#39;t include these X-headers in the DKIM signature. Amazon SES removes these before it sends the email.</p> </important> <p>If you only specify the <code>SourceIdentityArn</code> parameter, Amazon SES sets the From and Return-Path addresses to the same identity that you specified.</p> <p>For more information about sending authorization, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/sending-authorization.html">Using Sending Authorization with Amazon SES</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>For every message that you send, the total number of recipients (including each recipient in the To:, CC: and BCC: fields) is counted against the maximum number of emails you can send in a 24-hour period (your <i>sending quota</i>). For more information about sending quotas in Amazon SES, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/manage-sending-limits.html">Managing Your Amazon SES Sending Limits</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> </ul></p>
+    ///
+    /// See `Ses::send_raw_email`.
+    pub fn send_raw_email(
+        &self,
+        input: SendRawEmailRequest,
+    ) -> Result<SendRawEmailResponse, RusotoError<SendRawEmailError>> {
+        self.runtime.block_on(self.client.send_raw_email(input))
+    }
+
+    /// <p><p>Composes an email message using an email template and immediately queues it for sending.</p> <p>In order to send email using the <code>SendTemplatedEmail</code> operation, your call to the API must meet the following requirements:</p> <ul> <li> <p>The call must refer to an existing email template. You can create email templates using the <a>CreateTemplate</a> operation.</p> </li> <li> <p>The message must be sent from a verified email address or domain.</p> </li> <li> <p>If your account is still in the Amazon SES sandbox, you may only send to verified addresses or domains, or to email addresses associated with the Amazon SES Mailbox Simulator. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> </li> <li> <p>The maximum message size is 10 MB.</p> </li> <li> <p>Calls to the <code>SendTemplatedEmail</code> operation may only include one <code>Destination</code> parameter. A destination is a set of recipients who will receive the same version of the email. The <code>Destination</code> parameter can include up to 50 recipients, across the To:, CC: and BCC: fields.</p> </li> <li> <p>The <code>Destination</code> parameter must include at least one recipient email address. The recipient address can be a To: address, a CC: address, or a BCC: address. If a recipient email address is invalid (that is, it is not in the format <i>UserName@[SubDomain.]Domain.TopLevelDomain</i>), the entire message will be rejected, even if the message contains other recipients that are valid.</p> </li> </ul> <important> <p>If your call to the <code>SendTemplatedEmail</code> operation includes all of the required parameters, Amazon SES accepts it and returns a Message ID. However, if Amazon SES can&#39;t render the email because the template contains errors, it doesn&#39;t send the email. Additionally, because it already accepted the message, Amazon SES doesn&#39;t return a message stating that it was unable to send the email.</p> <p>For these reasons, we highly recommend that you set up Amazon SES to send you notifications when Rendering Failure events occur. For more information, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Sending Personalized Email Using the Amazon SES API</a> in the <i>Amazon Simple Email Service Developer Guide</i>.</p> </important></p>
+    ///
+    /// See `Ses::send_templated_email`.
+    pub fn send_templated_email(
+        &self,
+        input: SendTemplatedEmailRequest,
+    ) -> Result<SendTemplatedEmailResponse, RusotoError<SendTemplatedEmailError>> {
+        self.runtime
+            .block_on(self.client.send_templated_email(input))
+    }
+
+    /// <p>Sets the specified receipt rule set as the active receipt rule set.</p> <note> <p>To disable your email-receiving through Amazon SES completely, you can call this API with RuleSetName set to null.</p> </note> <p>For information about managing receipt rule sets, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rule-sets.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::set_active_receipt_rule_set`.
+    pub fn set_active_receipt_rule_set(
+        &self,
+        input: SetActiveReceiptRuleSetRequest,
+    ) -> Result<SetActiveReceiptRuleSetResponse, RusotoError<SetActiveReceiptRuleSetError>> {
+        self.runtime
+            .block_on(self.client.set_active_receipt_rule_set(input))
+    }
+
+    /// <p>Enables or disables Easy DKIM signing of email sent from an identity. If Easy DKIM signing is enabled for a domain, then Amazon SES uses DKIM to sign all email that it sends from addresses on that domain. If Easy DKIM signing is enabled for an email address, then Amazon SES uses DKIM to sign all email it sends from that address.</p> <note> <p>For email addresses (for example, <code>user@example.com</code>), you can only enable DKIM signing if the corresponding domain (in this case, <code>example.com</code>) has been set up to use Easy DKIM.</p> </note> <p>You can enable DKIM signing for an identity at any time after you start the verification process for the identity, even if the verification process isn't complete. </p> <p>You can execute this operation no more than once per second.</p> <p>For more information about Easy DKIM signing, go to the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::set_identity_dkim_enabled`.
+    pub fn set_identity_dkim_enabled(
+        &self,
+        input: SetIdentityDkimEnabledRequest,
+    ) -> Result<SetIdentityDkimEnabledResponse, RusotoError<SetIdentityDkimEnabledError>> {
+        self.runtime
+            .block_on(self.client.set_identity_dkim_enabled(input))
+    }
+
+    /// <p>Given an identity (an email address or a domain), enables or disables whether Amazon SES forwards bounce and complaint notifications as email. Feedback forwarding can only be disabled when Amazon Simple Notification Service (Amazon SNS) topics are specified for both bounces and complaints.</p> <note> <p>Feedback forwarding does not apply to delivery notifications. Delivery notifications are only available through Amazon SNS.</p> </note> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::set_identity_feedback_forwarding_enabled`.
+    pub fn set_identity_feedback_forwarding_enabled(
+        &self,
+        input: SetIdentityFeedbackForwardingEnabledRequest,
+    ) -> Result<
+        SetIdentityFeedbackForwardingEnabledResponse,
+        RusotoError<SetIdentityFeedbackForwardingEnabledError>,
+    > {
+        self.runtime
+            .block_on(self.client.set_identity_feedback_forwarding_enabled(input))
+    }
+
+    /// <p>Given an identity (an email address or a domain), sets whether Amazon SES includes the original email headers in the Amazon Simple Notification Service (Amazon SNS) notifications of a specified type.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about using notifications with Amazon SES, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::set_identity_headers_in_notifications_enabled`.
+    pub fn set_identity_headers_in_notifications_enabled(
+        &self,
+        input: SetIdentityHeadersInNotificationsEnabledRequest,
+    ) -> Result<
+        SetIdentityHeadersInNotificationsEnabledResponse,
+        RusotoError<SetIdentityHeadersInNotificationsEnabledError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .set_identity_headers_in_notifications_enabled(input),
+        )
+    }
+
+    /// <p>Enables or disables the custom MAIL FROM domain setup for a verified identity (an email address or a domain).</p> <important> <p>To send emails using the specified MAIL FROM domain, you must add an MX record to your MAIL FROM domain's DNS settings. If you want your emails to pass Sender Policy Framework (SPF) checks, you must also add or update an SPF record. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/mail-from-set.html">Amazon SES Developer Guide</a>.</p> </important> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::set_identity_mail_from_domain`.
+    pub fn set_identity_mail_from_domain(
+        &self,
+        input: SetIdentityMailFromDomainRequest,
+    ) -> Result<SetIdentityMailFromDomainResponse, RusotoError<SetIdentityMailFromDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.set_identity_mail_from_domain(input))
+    }
+
+    /// <p>Sets an Amazon Simple Notification Service (Amazon SNS) topic to use when delivering notifications. When you use this operation, you specify a verified identity, such as an email address or domain. When you send an email that uses the chosen identity in the Source field, Amazon SES sends notifications to the topic you specified. You can send bounce, complaint, or delivery notifications (or any combination of the three) to the Amazon SNS topic that you specify.</p> <p>You can execute this operation no more than once per second.</p> <p>For more information about feedback notification, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/notifications.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::set_identity_notification_topic`.
+    pub fn set_identity_notification_topic(
+        &self,
+        input: SetIdentityNotificationTopicRequest,
+    ) -> Result<SetIdentityNotificationTopicResponse, RusotoError<SetIdentityNotificationTopicError>>
+    {
+        self.runtime
+            .block_on(self.client.set_identity_notification_topic(input))
+    }
+
+    /// <p>Sets the position of the specified receipt rule in the receipt rule set.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::set_receipt_rule_position`.
+    pub fn set_receipt_rule_position(
+        &self,
+        input: SetReceiptRulePositionRequest,
+    ) -> Result<SetReceiptRulePositionResponse, RusotoError<SetReceiptRulePositionError>> {
+        self.runtime
+            .block_on(self.client.set_receipt_rule_position(input))
+    }
+
+    /// <p>Creates a preview of the MIME content of an email when provided with a template and a set of replacement data.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::test_render_template`.
+    pub fn test_render_template(
+        &self,
+        input: TestRenderTemplateRequest,
+    ) -> Result<TestRenderTemplateResponse, RusotoError<TestRenderTemplateError>> {
+        self.runtime
+            .block_on(self.client.test_render_template(input))
+    }
+
+    /// <p>Enables or disables email sending across your entire Amazon SES account in the current AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending across your Amazon SES account in a given AWS Region when reputation metrics (such as your bounce or complaint rates) reach certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_account_sending_enabled`.
+    pub fn update_account_sending_enabled(
+        &self,
+        input: UpdateAccountSendingEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateAccountSendingEnabledError>> {
+        self.runtime
+            .block_on(self.client.update_account_sending_enabled(input))
+    }
+
+    /// <p>Updates the event destination of a configuration set. Event destinations are associated with configuration sets, which enable you to publish email sending events to Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS). For information about using configuration sets, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/monitor-sending-activity.html">Monitoring Your Amazon SES Sending Activity</a> in the <i>Amazon SES Developer Guide.</i> </p> <note> <p>When you create or update an event destination, you must provide one, and only one, destination. The destination can be Amazon CloudWatch, Amazon Kinesis Firehose, or Amazon Simple Notification Service (Amazon SNS).</p> </note> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_configuration_set_event_destination`.
+    pub fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_event_destination(input),
+        )
+    }
+
+    /// <p>Enables or disables the publishing of reputation metrics for emails sent using a specific configuration set in a given AWS Region. Reputation metrics include bounce and complaint rates. These metrics are published to Amazon CloudWatch. By using CloudWatch, you can create alarms when bounce or complaint rates exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_configuration_set_reputation_metrics_enabled`.
+    pub fn update_configuration_set_reputation_metrics_enabled(
+        &self,
+        input: UpdateConfigurationSetReputationMetricsEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateConfigurationSetReputationMetricsEnabledError>> {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_reputation_metrics_enabled(input),
+        )
+    }
+
+    /// <p>Enables or disables email sending for messages sent using a specific configuration set in a given AWS Region. You can use this operation in conjunction with Amazon CloudWatch alarms to temporarily pause email sending for a configuration set when the reputation metrics for that configuration set (such as your bounce on complaint rate) exceed certain thresholds.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_configuration_set_sending_enabled`.
+    pub fn update_configuration_set_sending_enabled(
+        &self,
+        input: UpdateConfigurationSetSendingEnabledRequest,
+    ) -> Result<(), RusotoError<UpdateConfigurationSetSendingEnabledError>> {
+        self.runtime
+            .block_on(self.client.update_configuration_set_sending_enabled(input))
+    }
+
+    /// <p>Modifies an association between a configuration set and a custom domain for open and click event tracking. </p> <p>By default, images and links used for tracking open and click events are hosted on domains operated by Amazon SES. You can configure a subdomain of your own to handle these events. For information about using custom domains, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/configure-custom-open-click-domains.html">Amazon SES Developer Guide</a>.</p>
+    ///
+    /// See `Ses::update_configuration_set_tracking_options`.
+    pub fn update_configuration_set_tracking_options(
+        &self,
+        input: UpdateConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        UpdateConfigurationSetTrackingOptionsResponse,
+        RusotoError<UpdateConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_configuration_set_tracking_options(input))
+    }
+
+    /// <p>Updates an existing custom verification email template.</p> <p>For more information about custom verification email templates, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/custom-verification-emails.html">Using Custom Verification Email Templates</a> in the <i>Amazon SES Developer Guide</i>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_custom_verification_email_template`.
+    pub fn update_custom_verification_email_template(
+        &self,
+        input: UpdateCustomVerificationEmailTemplateRequest,
+    ) -> Result<(), RusotoError<UpdateCustomVerificationEmailTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_custom_verification_email_template(input))
+    }
+
+    /// <p>Updates a receipt rule.</p> <p>For information about managing receipt rules, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/receiving-email-managing-receipt-rules.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_receipt_rule`.
+    pub fn update_receipt_rule(
+        &self,
+        input: UpdateReceiptRuleRequest,
+    ) -> Result<UpdateReceiptRuleResponse, RusotoError<UpdateReceiptRuleError>> {
+        self.runtime
+            .block_on(self.client.update_receipt_rule(input))
+    }
+
+    /// <p>Updates an email template. Email templates enable you to send personalized email to one or more destinations in a single API operation. For more information, see the <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/send-personalized-email-api.html">Amazon SES Developer Guide</a>.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::update_template`.
+    pub fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        self.runtime.block_on(self.client.update_template(input))
+    }
+
+    /// <p>Returns a set of DKIM tokens for a domain identity.</p> <important> <p>When you execute the <code>VerifyDomainDkim</code> operation, the domain that you specify is added to the list of identities that are associated with your account. This is true even if you haven't already associated the domain with your account by using the <code>VerifyDomainIdentity</code> operation. However, you can't send email from the domain until you either successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-domains.html">verify it</a> or you successfully <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/easy-dkim.html">set up DKIM for it</a>.</p> </important> <p>You use the tokens that are generated by this operation to create CNAME records. When Amazon SES detects that you've added these records to the DNS configuration for a domain, you can start sending email from that domain. You can start sending email even if you haven't added the TXT record provided by the VerifyDomainIdentity operation to the DNS configuration for your domain. All email that you send from the domain is authenticated using DKIM.</p> <p>To create the CNAME records for DKIM authentication, use the following values:</p> <ul> <li> <p> <b>Name</b>: <i>token</i>._domainkey.<i>example.com</i> </p> </li> <li> <p> <b>Type</b>: CNAME</p> </li> <li> <p> <b>Value</b>: <i>token</i>.dkim.amazonses.com</p> </li> </ul> <p>In the preceding example, replace <i>token</i> with one of the tokens that are generated when you execute this operation. Replace <i>example.com</i> with your domain. Repeat this process for each token that's generated by this operation.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::verify_domain_dkim`.
+    pub fn verify_domain_dkim(
+        &self,
+        input: VerifyDomainDkimRequest,
+    ) -> Result<VerifyDomainDkimResponse, RusotoError<VerifyDomainDkimError>> {
+        self.runtime.block_on(self.client.verify_domain_dkim(input))
+    }
+
+    /// <p>Adds a domain to the list of identities for your Amazon SES account in the current AWS Region and attempts to verify it. For more information about verifying domains, see <a href="https://docs.aws.amazon.com/ses/latest/DeveloperGuide/verify-addresses-and-domains.html">Verifying Email Addresses and Domains</a> in the <i>Amazon SES Developer Guide.</i> </p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::verify_domain_identity`.
+    pub fn verify_domain_identity(
+        &self,
+        input: VerifyDomainIdentityRequest,
+    ) -> Result<VerifyDomainIdentityResponse, RusotoError<VerifyDomainIdentityError>> {
+        self.runtime
+            .block_on(self.client.verify_domain_identity(input))
+    }
+
+    /// <p>Deprecated. Use the <code>VerifyEmailIdentity</code> operation to verify a new email address.</p>
+    ///
+    /// See `Ses::verify_email_address`.
+    pub fn verify_email_address(
+        &self,
+        input: VerifyEmailAddressRequest,
+    ) -> Result<(), RusotoError<VerifyEmailAddressError>> {
+        self.runtime
+            .block_on(self.client.verify_email_address(input))
+    }
+
+    /// <p>Adds an email address to the list of identities for your Amazon SES account in the current AWS region and attempts to verify it. As a result of executing this operation, a verification email is sent to the specified address.</p> <p>You can execute this operation no more than once per second.</p>
+    ///
+    /// See `Ses::verify_email_identity`.
+    pub fn verify_email_identity(
+        &self,
+        input: VerifyEmailIdentityRequest,
+    ) -> Result<VerifyEmailIdentityResponse, RusotoError<VerifyEmailIdentityError>> {
+        self.runtime
+            .block_on(self.client.verify_email_identity(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -694,42 +694,52 @@ impl AssociateDRTLogBucketError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDRTLogBucketError::AccessDeniedForDependency(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::InvalidOperation(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::LimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::LimitsExceeded(err.msg),
+                    )
                 }
                 "NoAssociatedRoleException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::NoAssociatedRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::NoAssociatedRole(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDRTLogBucketError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTLogBucketError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -778,24 +788,40 @@ impl AssociateDRTRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::AccessDeniedForDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTRoleError::AccessDeniedForDependency(err.msg),
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTRoleError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTRoleError::InvalidOperation(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTRoleError::InvalidParameter(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTRoleError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDRTRoleError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDRTRoleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -838,23 +864,34 @@ impl AssociateHealthCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateHealthCheckError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateHealthCheckError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::LimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateHealthCheckError::LimitsExceeded(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateHealthCheckError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateHealthCheckError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateHealthCheckError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -898,27 +935,32 @@ impl AssociateProactiveEngagementDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProactiveEngagementDetailsError::InternalError(err.msg),
                     )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProactiveEngagementDetailsError::InvalidOperation(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProactiveEngagementDetailsError::InvalidParameter(err.msg),
                     )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProactiveEngagementDetailsError::OptimisticLock(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateProactiveEngagementDetailsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -976,27 +1018,46 @@ impl CreateProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateProtectionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(CreateProtectionError::InvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::InvalidOperation(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateProtectionError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::InvalidResource(err.msg),
+                    )
                 }
                 "LimitsExceededException" => {
-                    return RusotoError::Service(CreateProtectionError::LimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::LimitsExceeded(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(CreateProtectionError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProtectionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateProtectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1034,12 +1095,16 @@ impl CreateSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriptionError::InternalError(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSubscriptionError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSubscriptionError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1074,13 +1139,22 @@ impl DeleteProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteProtectionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProtectionError::InternalError(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DeleteProtectionError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProtectionError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteProtectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1116,15 +1190,22 @@ impl DeleteSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionError::InternalError(err.msg),
+                    )
                 }
                 "LockedSubscriptionException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::LockedSubscription(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionError::LockedSubscription(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSubscriptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1158,10 +1239,10 @@ impl DescribeAttackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAttackError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeAttackError::AccessDenied(err.msg))
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeAttackError::InternalError(err.msg))
+                    return RusotoError::service(&res, DescribeAttackError::InternalError(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1194,10 +1275,16 @@ impl DescribeDRTAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeDRTAccessError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDRTAccessError::InternalError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDRTAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDRTAccessError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1232,12 +1319,14 @@ impl DescribeEmergencyContactSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEmergencyContactSettingsError::InternalError(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEmergencyContactSettingsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1278,13 +1367,22 @@ impl DescribeProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeProtectionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProtectionError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProtectionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProtectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeProtectionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1318,12 +1416,16 @@ impl DescribeSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSubscriptionError::InternalError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSubscriptionError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSubscriptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1364,29 +1466,34 @@ impl DisableProactiveEngagementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableProactiveEngagementError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableProactiveEngagementError::InvalidOperation(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableProactiveEngagementError::InvalidParameter(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableProactiveEngagementError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableProactiveEngagementError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableProactiveEngagementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1430,34 +1537,40 @@ impl DisassociateDRTLogBucketError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDRTLogBucketError::AccessDeniedForDependency(err.msg),
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTLogBucketError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTLogBucketError::InvalidOperation(err.msg),
+                    )
                 }
                 "NoAssociatedRoleException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::NoAssociatedRole(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTLogBucketError::NoAssociatedRole(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTLogBucketError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDRTLogBucketError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTLogBucketError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1500,20 +1613,28 @@ impl DisassociateDRTRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTRoleError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTRoleError::InvalidOperation(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTRoleError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDRTRoleError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDRTRoleError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1552,24 +1673,28 @@ impl DisassociateHealthCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateHealthCheckError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateHealthCheckError::InvalidParameter(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateHealthCheckError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateHealthCheckError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateHealthCheckError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1610,29 +1735,34 @@ impl EnableProactiveEngagementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableProactiveEngagementError::InternalError(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableProactiveEngagementError::InvalidOperation(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableProactiveEngagementError::InvalidParameter(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::OptimisticLock(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableProactiveEngagementError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableProactiveEngagementError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        EnableProactiveEngagementError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1666,7 +1796,10 @@ impl GetSubscriptionStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetSubscriptionStateError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSubscriptionStateError::InternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1700,13 +1833,13 @@ impl ListAttacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListAttacksError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListAttacksError::InternalError(err.msg))
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListAttacksError::InvalidOperation(err.msg))
+                    return RusotoError::service(&res, ListAttacksError::InvalidOperation(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAttacksError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListAttacksError::InvalidParameter(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1742,15 +1875,19 @@ impl ListProtectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListProtectionsError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListProtectionsError::InternalError(err.msg))
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListProtectionsError::InvalidPaginationToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListProtectionsError::InvalidPaginationToken(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProtectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListProtectionsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1790,22 +1927,26 @@ impl UpdateEmergencyContactSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEmergencyContactSettingsError::InternalError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEmergencyContactSettingsError::InvalidParameter(err.msg),
                     )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEmergencyContactSettingsError::OptimisticLock(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateEmergencyContactSettingsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -1854,21 +1995,34 @@ impl UpdateSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriptionError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriptionError::InvalidParameter(err.msg),
+                    )
                 }
                 "LockedSubscriptionException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::LockedSubscription(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriptionError::LockedSubscription(err.msg),
+                    )
                 }
                 "OptimisticLockException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::OptimisticLock(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriptionError::OptimisticLock(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSubscriptionError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSubscriptionError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2049,6 +2203,7 @@ impl ShieldClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2064,6 +2219,7 @@ impl ShieldClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ShieldClient {
         ShieldClient { client, region }
     }
@@ -2077,6 +2233,9 @@ impl Shield for ShieldClient {
         input: AssociateDRTLogBucketRequest,
     ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateDRTLogBucket");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTLogBucket");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2096,6 +2255,9 @@ impl Shield for ShieldClient {
         input: AssociateDRTRoleRequest,
     ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateDRTRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateDRTRole");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2114,6 +2276,9 @@ impl Shield for ShieldClient {
         input: AssociateHealthCheckRequest,
     ) -> Result<AssociateHealthCheckResponse, RusotoError<AssociateHealthCheckError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateHealthCheck");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.AssociateHealthCheck");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2136,6 +2301,9 @@ impl Shield for ShieldClient {
         RusotoError<AssociateProactiveEngagementDetailsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateProactiveEngagementDetails");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.AssociateProactiveEngagementDetails",
@@ -2161,6 +2329,9 @@ impl Shield for ShieldClient {
         input: CreateProtectionRequest,
     ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateProtection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.CreateProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2178,6 +2349,9 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.CreateSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2195,6 +2369,9 @@ impl Shield for ShieldClient {
         input: DeleteProtectionRequest,
     ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteProtection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2212,6 +2389,9 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.DeleteSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2229,6 +2409,9 @@ impl Shield for ShieldClient {
         input: DescribeAttackRequest,
     ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAttack");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeAttack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2246,6 +2429,9 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDRTAccess");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeDRTAccess");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2265,6 +2451,9 @@ impl Shield for ShieldClient {
         RusotoError<DescribeEmergencyContactSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEmergencyContactSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DescribeEmergencyContactSettings",
@@ -2289,6 +2478,9 @@ impl Shield for ShieldClient {
         input: DescribeProtectionRequest,
     ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeProtection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2306,6 +2498,9 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSubscription");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.DescribeSubscription");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2324,6 +2519,9 @@ impl Shield for ShieldClient {
     ) -> Result<DisableProactiveEngagementResponse, RusotoError<DisableProactiveEngagementError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableProactiveEngagement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DisableProactiveEngagement",
@@ -2345,6 +2543,9 @@ impl Shield for ShieldClient {
         input: DisassociateDRTLogBucketRequest,
     ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateDRTLogBucket");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.DisassociateDRTLogBucket",
@@ -2366,6 +2567,9 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateDRTRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateDRTRole");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2383,6 +2587,9 @@ impl Shield for ShieldClient {
         input: DisassociateHealthCheckRequest,
     ) -> Result<DisassociateHealthCheckResponse, RusotoError<DisassociateHealthCheckError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateHealthCheck");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.DisassociateHealthCheck");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2402,6 +2609,9 @@ impl Shield for ShieldClient {
     ) -> Result<EnableProactiveEngagementResponse, RusotoError<EnableProactiveEngagementError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("EnableProactiveEngagement");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.EnableProactiveEngagement",
@@ -2422,6 +2632,9 @@ impl Shield for ShieldClient {
         &self,
     ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSubscriptionState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.GetSubscriptionState");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2440,6 +2653,9 @@ impl Shield for ShieldClient {
         input: ListAttacksRequest,
     ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAttacks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.ListAttacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2458,6 +2674,9 @@ impl Shield for ShieldClient {
         input: ListProtectionsRequest,
     ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListProtections");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSShield_20160616.ListProtections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2479,6 +2698,9 @@ impl Shield for ShieldClient {
         RusotoError<UpdateEmergencyContactSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateEmergencyContactSettings");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShield_20160616.UpdateEmergencyContactSettings",
@@ -2501,6 +2723,9 @@ impl Shield for ShieldClient {
         input: UpdateSubscriptionRequest,
     ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSShield_20160616.UpdateSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2513,3 +2738,296 @@ impl Shield for ShieldClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubscriptionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Shield API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ShieldClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ShieldBlockingClient {
+    client: ShieldClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ShieldBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ShieldBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ShieldBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ShieldBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ShieldBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> ShieldBlockingClient {
+        ShieldBlockingClient {
+            client: ShieldClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Authorizes the DDoS Response Team (DRT) to access the specified Amazon S3 bucket containing your AWS WAF logs. You can associate up to 10 Amazon S3 buckets with your subscription.</p> <p>To use the services of the DRT and make an <code>AssociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+    ///
+    /// See `Shield::associate_drt_log_bucket`.
+    pub fn associate_drt_log_bucket(
+        &self,
+        input: AssociateDRTLogBucketRequest,
+    ) -> Result<AssociateDRTLogBucketResponse, RusotoError<AssociateDRTLogBucketError>> {
+        self.runtime
+            .block_on(self.client.associate_drt_log_bucket(input))
+    }
+
+    /// <p>Authorizes the DDoS Response Team (DRT), using the specified role, to access your AWS account to assist with DDoS attack mitigation during potential attacks. This enables the DRT to inspect your AWS WAF configuration and create or update AWS WAF rules and web ACLs.</p> <p>You can associate only one <code>RoleArn</code> with your subscription. If you submit an <code>AssociateDRTRole</code> request for an account that already has an associated role, the new <code>RoleArn</code> will replace the existing <code>RoleArn</code>. </p> <p>Prior to making the <code>AssociateDRTRole</code> request, you must attach the <a href="https://console.aws.amazon.com/iam/home?#/policies/arn:aws:iam::aws:policy/service-role/AWSShieldDRTAccessPolicy">AWSShieldDRTAccessPolicy</a> managed policy to the role you will specify in the request. For more information see <a href=" https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_manage-attach-detach.html">Attaching and Detaching IAM Policies</a>. The role must also trust the service principal <code> drt.shield.amazonaws.com</code>. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_policies_elements_principal.html">IAM JSON Policy Elements: Principal</a>.</p> <p>The DRT will have access only to your AWS WAF and Shield resources. By submitting this request, you authorize the DRT to inspect your AWS WAF and Shield configuration and create and update AWS WAF rules and web ACLs on your behalf. The DRT takes these actions only if explicitly authorized by you.</p> <p>You must have the <code>iam:PassRole</code> permission to make an <code>AssociateDRTRole</code> request. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_use_passrole.html">Granting a User Permissions to Pass a Role to an AWS Service</a>. </p> <p>To use the services of the DRT and make an <code>AssociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>.</p>
+    ///
+    /// See `Shield::associate_drt_role`.
+    pub fn associate_drt_role(
+        &self,
+        input: AssociateDRTRoleRequest,
+    ) -> Result<AssociateDRTRoleResponse, RusotoError<AssociateDRTRoleError>> {
+        self.runtime.block_on(self.client.associate_drt_role(input))
+    }
+
+    /// <p>Adds health-based detection to the Shield Advanced protection for a resource. Shield Advanced health-based detection uses the health of your AWS resource to improve responsiveness and accuracy in attack detection and mitigation. </p> <p>You define the health check in Route 53 and then associate it with your Shield Advanced protection. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/ddos-overview.html#ddos-advanced-health-check-option">Shield Advanced Health-Based Detection</a> in the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF and AWS Shield Developer Guide</a>. </p>
+    ///
+    /// See `Shield::associate_health_check`.
+    pub fn associate_health_check(
+        &self,
+        input: AssociateHealthCheckRequest,
+    ) -> Result<AssociateHealthCheckResponse, RusotoError<AssociateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.associate_health_check(input))
+    }
+
+    /// <p><p>Initializes proactive engagement and sets the list of contacts for the DDoS Response Team (DRT) to use. You must provide at least one phone number in the emergency contact list. </p> <p>After you have initialized proactive engagement using this call, to disable or enable proactive engagement, use the calls <code>DisableProactiveEngagement</code> and <code>EnableProactiveEngagement</code>. </p> <note> <p>This call defines the list of email addresses and phone numbers that the DDoS Response Team (DRT) can use to contact you for escalations to the DRT and to initiate proactive customer support.</p> <p>The contacts that you provide in the request replace any contacts that were already defined. If you already have contacts defined and want to use them, retrieve the list using <code>DescribeEmergencyContactSettings</code> and then provide it to this call. </p> </note></p>
+    ///
+    /// See `Shield::associate_proactive_engagement_details`.
+    pub fn associate_proactive_engagement_details(
+        &self,
+        input: AssociateProactiveEngagementDetailsRequest,
+    ) -> Result<
+        AssociateProactiveEngagementDetailsResponse,
+        RusotoError<AssociateProactiveEngagementDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_proactive_engagement_details(input))
+    }
+
+    /// <p>Enables AWS Shield Advanced for a specific AWS resource. The resource can be an Amazon CloudFront distribution, Elastic Load Balancing load balancer, AWS Global Accelerator accelerator, Elastic IP Address, or an Amazon Route 53 hosted zone.</p> <p>You can add protection to only a single resource with each CreateProtection request. If you want to add protection to multiple resources at once, use the <a href="https://console.aws.amazon.com/waf/">AWS WAF console</a>. For more information see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/getting-started-ddos.html">Getting Started with AWS Shield Advanced</a> and <a href="https://docs.aws.amazon.com/waf/latest/developerguide/configure-new-protection.html">Add AWS Shield Advanced Protection to more AWS Resources</a>.</p>
+    ///
+    /// See `Shield::create_protection`.
+    pub fn create_protection(
+        &self,
+        input: CreateProtectionRequest,
+    ) -> Result<CreateProtectionResponse, RusotoError<CreateProtectionError>> {
+        self.runtime.block_on(self.client.create_protection(input))
+    }
+
+    /// <p>Activates AWS Shield Advanced for an account.</p> <p>When you initally create a subscription, your subscription is set to be automatically renewed at the end of the existing subscription period. You can change this by submitting an <code>UpdateSubscription</code> request. </p>
+    ///
+    /// See `Shield::create_subscription`.
+    pub fn create_subscription(
+        &self,
+    ) -> Result<CreateSubscriptionResponse, RusotoError<CreateSubscriptionError>> {
+        self.runtime.block_on(self.client.create_subscription())
+    }
+
+    /// <p>Deletes an AWS Shield Advanced <a>Protection</a>.</p>
+    ///
+    /// See `Shield::delete_protection`.
+    pub fn delete_protection(
+        &self,
+        input: DeleteProtectionRequest,
+    ) -> Result<DeleteProtectionResponse, RusotoError<DeleteProtectionError>> {
+        self.runtime.block_on(self.client.delete_protection(input))
+    }
+
+    /// <p>Removes AWS Shield Advanced from an account. AWS Shield Advanced requires a 1-year subscription commitment. You cannot delete a subscription prior to the completion of that commitment. </p>
+    ///
+    /// See `Shield::delete_subscription`.
+    pub fn delete_subscription(
+        &self,
+    ) -> Result<DeleteSubscriptionResponse, RusotoError<DeleteSubscriptionError>> {
+        self.runtime.block_on(self.client.delete_subscription())
+    }
+
+    /// <p>Describes the details of a DDoS attack. </p>
+    ///
+    /// See `Shield::describe_attack`.
+    pub fn describe_attack(
+        &self,
+        input: DescribeAttackRequest,
+    ) -> Result<DescribeAttackResponse, RusotoError<DescribeAttackError>> {
+        self.runtime.block_on(self.client.describe_attack(input))
+    }
+
+    /// <p>Returns the current role and list of Amazon S3 log buckets used by the DDoS Response Team (DRT) to access your AWS account while assisting with attack mitigation.</p>
+    ///
+    /// See `Shield::describe_drt_access`.
+    pub fn describe_drt_access(
+        &self,
+    ) -> Result<DescribeDRTAccessResponse, RusotoError<DescribeDRTAccessError>> {
+        self.runtime.block_on(self.client.describe_drt_access())
+    }
+
+    /// <p>A list of email addresses and phone numbers that the DDoS Response Team (DRT) can use to contact you if you have proactive engagement enabled, for escalations to the DRT and to initiate proactive customer support.</p>
+    ///
+    /// See `Shield::describe_emergency_contact_settings`.
+    pub fn describe_emergency_contact_settings(
+        &self,
+    ) -> Result<
+        DescribeEmergencyContactSettingsResponse,
+        RusotoError<DescribeEmergencyContactSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_emergency_contact_settings())
+    }
+
+    /// <p>Lists the details of a <a>Protection</a> object.</p>
+    ///
+    /// See `Shield::describe_protection`.
+    pub fn describe_protection(
+        &self,
+        input: DescribeProtectionRequest,
+    ) -> Result<DescribeProtectionResponse, RusotoError<DescribeProtectionError>> {
+        self.runtime
+            .block_on(self.client.describe_protection(input))
+    }
+
+    /// <p>Provides details about the AWS Shield Advanced subscription for an account.</p>
+    ///
+    /// See `Shield::describe_subscription`.
+    pub fn describe_subscription(
+        &self,
+    ) -> Result<DescribeSubscriptionResponse, RusotoError<DescribeSubscriptionError>> {
+        self.runtime.block_on(self.client.describe_subscription())
+    }
+
+    /// <p>Removes authorization from the DDoS Response Team (DRT) to notify contacts about escalations to the DRT and to initiate proactive customer support.</p>
+    ///
+    /// See `Shield::disable_proactive_engagement`.
+    pub fn disable_proactive_engagement(
+        &self,
+    ) -> Result<DisableProactiveEngagementResponse, RusotoError<DisableProactiveEngagementError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_proactive_engagement())
+    }
+
+    /// <p>Removes the DDoS Response Team's (DRT) access to the specified Amazon S3 bucket containing your AWS WAF logs.</p> <p>To make a <code>DisassociateDRTLogBucket</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>. However, if you are not subscribed to one of these support plans, but had been previously and had granted the DRT access to your account, you can submit a <code>DisassociateDRTLogBucket</code> request to remove this access.</p>
+    ///
+    /// See `Shield::disassociate_drt_log_bucket`.
+    pub fn disassociate_drt_log_bucket(
+        &self,
+        input: DisassociateDRTLogBucketRequest,
+    ) -> Result<DisassociateDRTLogBucketResponse, RusotoError<DisassociateDRTLogBucketError>> {
+        self.runtime
+            .block_on(self.client.disassociate_drt_log_bucket(input))
+    }
+
+    /// <p>Removes the DDoS Response Team's (DRT) access to your AWS account.</p> <p>To make a <code>DisassociateDRTRole</code> request, you must be subscribed to the <a href="https://aws.amazon.com/premiumsupport/business-support/">Business Support plan</a> or the <a href="https://aws.amazon.com/premiumsupport/enterprise-support/">Enterprise Support plan</a>. However, if you are not subscribed to one of these support plans, but had been previously and had granted the DRT access to your account, you can submit a <code>DisassociateDRTRole</code> request to remove this access.</p>
+    ///
+    /// See `Shield::disassociate_drt_role`.
+    pub fn disassociate_drt_role(
+        &self,
+    ) -> Result<DisassociateDRTRoleResponse, RusotoError<DisassociateDRTRoleError>> {
+        self.runtime.block_on(self.client.disassociate_drt_role())
+    }
+
+    /// <p>Removes health-based detection from the Shield Advanced protection for a resource. Shield Advanced health-based detection uses the health of your AWS resource to improve responsiveness and accuracy in attack detection and mitigation. </p> <p>You define the health check in Route 53 and then associate or disassociate it with your Shield Advanced protection. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/ddos-overview.html#ddos-advanced-health-check-option">Shield Advanced Health-Based Detection</a> in the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF and AWS Shield Developer Guide</a>. </p>
+    ///
+    /// See `Shield::disassociate_health_check`.
+    pub fn disassociate_health_check(
+        &self,
+        input: DisassociateHealthCheckRequest,
+    ) -> Result<DisassociateHealthCheckResponse, RusotoError<DisassociateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.disassociate_health_check(input))
+    }
+
+    /// <p>Authorizes the DDoS Response Team (DRT) to use email and phone to notify contacts about escalations to the DRT and to initiate proactive customer support.</p>
+    ///
+    /// See `Shield::enable_proactive_engagement`.
+    pub fn enable_proactive_engagement(
+        &self,
+    ) -> Result<EnableProactiveEngagementResponse, RusotoError<EnableProactiveEngagementError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_proactive_engagement())
+    }
+
+    /// <p>Returns the <code>SubscriptionState</code>, either <code>Active</code> or <code>Inactive</code>.</p>
+    ///
+    /// See `Shield::get_subscription_state`.
+    pub fn get_subscription_state(
+        &self,
+    ) -> Result<GetSubscriptionStateResponse, RusotoError<GetSubscriptionStateError>> {
+        self.runtime.block_on(self.client.get_subscription_state())
+    }
+
+    /// <p>Returns all ongoing DDoS attacks or all DDoS attacks during a specified time period.</p>
+    ///
+    /// See `Shield::list_attacks`.
+    pub fn list_attacks(
+        &self,
+        input: ListAttacksRequest,
+    ) -> Result<ListAttacksResponse, RusotoError<ListAttacksError>> {
+        self.runtime.block_on(self.client.list_attacks(input))
+    }
+
+    /// <p>Lists all <a>Protection</a> objects for the account.</p>
+    ///
+    /// See `Shield::list_protections`.
+    pub fn list_protections(
+        &self,
+        input: ListProtectionsRequest,
+    ) -> Result<ListProtectionsResponse, RusotoError<ListProtectionsError>> {
+        self.runtime.block_on(self.client.list_protections(input))
+    }
+
+    /// <p>Updates the details of the list of email addresses and phone numbers that the DDoS Response Team (DRT) can use to contact you if you have proactive engagement enabled, for escalations to the DRT and to initiate proactive customer support.</p>
+    ///
+    /// See `Shield::update_emergency_contact_settings`.
+    pub fn update_emergency_contact_settings(
+        &self,
+        input: UpdateEmergencyContactSettingsRequest,
+    ) -> Result<
+        UpdateEmergencyContactSettingsResponse,
+        RusotoError<UpdateEmergencyContactSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_emergency_contact_settings(input))
+    }
+
+    /// <p>Updates the details of an existing subscription. Only enter values for parameters you want to change. Empty parameters are not updated.</p>
+    ///
+    /// See `Shield::update_subscription`.
+    pub fn update_subscription(
+        &self,
+        input: UpdateSubscriptionRequest,
+    ) -> Result<UpdateSubscriptionResponse, RusotoError<UpdateSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.update_subscription(input))
+    }
+}
@@ -675,20 +675,28 @@ impl CancelSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelSigningProfileError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CancelSigningProfileError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSigningProfileError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSigningProfileError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelSigningProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSigningProfileError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelSigningProfileError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -725,15 +733,22 @@ impl DescribeSigningJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeSigningJobError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSigningJobError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeSigningJobError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSigningJobError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSigningJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSigningJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -769,15 +784,22 @@ impl GetSigningPlatformError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSigningPlatformError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningPlatformError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetSigningPlatformError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningPlatformError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningPlatformError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningPlatformError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -815,18 +837,25 @@ impl GetSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningProfileError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetSigningProfileError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningProfileError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSigningProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSigningProfileError::Throttling(err.msg))
+                    return RusotoError::service(&res, GetSigningProfileError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -863,15 +892,16 @@ impl ListSigningJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningJobsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ListSigningJobsError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningJobsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningJobsError::InternalServiceError(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSigningJobsError::Throttling(err.msg))
+                    return RusotoError::service(&res, ListSigningJobsError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -907,15 +937,22 @@ impl ListSigningPlatformsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningPlatformsError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningPlatformsError::InternalServiceError(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSigningPlatformsError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningPlatformsError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -951,15 +988,22 @@ impl ListSigningProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSigningProfilesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningProfilesError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListSigningProfilesError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningProfilesError::InternalServiceError(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSigningProfilesError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListSigningProfilesError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -995,15 +1039,19 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::BadRequest(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, ListTagsForResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1041,18 +1089,25 @@ impl PutSigningProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSigningProfileError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSigningProfileError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(PutSigningProfileError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutSigningProfileError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSigningProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutSigningProfileError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutSigningProfileError::Throttling(err.msg))
+                    return RusotoError::service(&res, PutSigningProfileError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1091,18 +1146,22 @@ impl StartSigningJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSigningJobError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, StartSigningJobError::AccessDenied(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(StartSigningJobError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartSigningJobError::InternalServiceError(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartSigningJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSigningJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSigningJobError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartSigningJobError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1139,13 +1198,16 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::BadRequest(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1181,13 +1243,16 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::BadRequest(err.msg))
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1300,6 +1365,7 @@ impl SignerClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1315,6 +1381,7 @@ impl SignerClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SignerClient {
         SignerClient { client, region }
     }
@@ -1334,6 +1401,9 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation_name("CancelSigningProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1361,6 +1431,9 @@ impl Signer for SignerClient {
         let request_uri = format!("/signing-jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("DescribeSigningJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1392,6 +1465,9 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("GetSigningPlatform");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1423,6 +1499,9 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("GetSigningProfile");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1451,6 +1530,9 @@ impl Signer for SignerClient {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("ListSigningJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1497,6 +1579,9 @@ impl Signer for SignerClient {
         let request_uri = "/signing-platforms";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("ListSigningPlatforms");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1543,6 +1628,9 @@ impl Signer for SignerClient {
         let request_uri = "/signing-profiles";
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("ListSigningProfiles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1583,6 +1671,9 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "signer", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1614,6 +1705,9 @@ impl Signer for SignerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "signer", &self.region, &request_uri);
+        request.set_operation_name("PutSigningProfile");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1645,6 +1739,9 @@ impl Signer for SignerClient {
         let request_uri = "/signing-jobs";
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation_name("StartSigningJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1676,6 +1773,9 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "signer", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1707,6 +1807,9 @@ impl Signer for SignerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "signer", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1732,3 +1835,181 @@ impl Signer for SignerClient {
         }
     }
 }
+
+/// A blocking client for the signer API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SignerClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SignerBlockingClient {
+    client: SignerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SignerBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SignerBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SignerBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SignerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SignerBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SignerBlockingClient {
+        SignerBlockingClient {
+            client: SignerClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Changes the state of an <code>ACTIVE</code> signing profile to <code>CANCELED</code>. A canceled profile is still viewable with the <code>ListSigningProfiles</code> operation, but it cannot perform new signing jobs, and is deleted two years after cancelation.</p>
+    ///
+    /// See `Signer::cancel_signing_profile`.
+    pub fn cancel_signing_profile(
+        &self,
+        input: CancelSigningProfileRequest,
+    ) -> Result<(), RusotoError<CancelSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.cancel_signing_profile(input))
+    }
+
+    /// <p>Returns information about a specific code signing job. You specify the job by using the <code>jobId</code> value that is returned by the <a>StartSigningJob</a> operation. </p>
+    ///
+    /// See `Signer::describe_signing_job`.
+    pub fn describe_signing_job(
+        &self,
+        input: DescribeSigningJobRequest,
+    ) -> Result<DescribeSigningJobResponse, RusotoError<DescribeSigningJobError>> {
+        self.runtime
+            .block_on(self.client.describe_signing_job(input))
+    }
+
+    /// <p>Returns information on a specific signing platform.</p>
+    ///
+    /// See `Signer::get_signing_platform`.
+    pub fn get_signing_platform(
+        &self,
+        input: GetSigningPlatformRequest,
+    ) -> Result<GetSigningPlatformResponse, RusotoError<GetSigningPlatformError>> {
+        self.runtime
+            .block_on(self.client.get_signing_platform(input))
+    }
+
+    /// <p>Returns information on a specific signing profile.</p>
+    ///
+    /// See `Signer::get_signing_profile`.
+    pub fn get_signing_profile(
+        &self,
+        input: GetSigningProfileRequest,
+    ) -> Result<GetSigningProfileResponse, RusotoError<GetSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.get_signing_profile(input))
+    }
+
+    /// <p>Lists all your signing jobs. You can use the <code>maxResults</code> parameter to limit the number of signing jobs that are returned in the response. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned. </p>
+    ///
+    /// See `Signer::list_signing_jobs`.
+    pub fn list_signing_jobs(
+        &self,
+        input: ListSigningJobsRequest,
+    ) -> Result<ListSigningJobsResponse, RusotoError<ListSigningJobsError>> {
+        self.runtime.block_on(self.client.list_signing_jobs(input))
+    }
+
+    /// <p>Lists all signing platforms available in code signing that match the request parameters. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned.</p>
+    ///
+    /// See `Signer::list_signing_platforms`.
+    pub fn list_signing_platforms(
+        &self,
+        input: ListSigningPlatformsRequest,
+    ) -> Result<ListSigningPlatformsResponse, RusotoError<ListSigningPlatformsError>> {
+        self.runtime
+            .block_on(self.client.list_signing_platforms(input))
+    }
+
+    /// <p>Lists all available signing profiles in your AWS account. Returns only profiles with an <code>ACTIVE</code> status unless the <code>includeCanceled</code> request field is set to <code>true</code>. If additional jobs remain to be listed, code signing returns a <code>nextToken</code> value. Use this value in subsequent calls to <code>ListSigningJobs</code> to fetch the remaining values. You can continue calling <code>ListSigningJobs</code> with your <code>maxResults</code> parameter and with new values that code signing returns in the <code>nextToken</code> parameter until all of your signing jobs have been returned.</p>
+    ///
+    /// See `Signer::list_signing_profiles`.
+    pub fn list_signing_profiles(
+        &self,
+        input: ListSigningProfilesRequest,
+    ) -> Result<ListSigningProfilesResponse, RusotoError<ListSigningProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_signing_profiles(input))
+    }
+
+    /// <p>Returns a list of the tags associated with a signing profile resource.</p>
+    ///
+    /// See `Signer::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Creates a signing profile. A signing profile is a code signing template that can be used to carry out a pre-defined signing job. For more information, see <a href="http://docs.aws.amazon.com/signer/latest/developerguide/gs-profile.html">http://docs.aws.amazon.com/signer/latest/developerguide/gs-profile.html</a> </p>
+    ///
+    /// See `Signer::put_signing_profile`.
+    pub fn put_signing_profile(
+        &self,
+        input: PutSigningProfileRequest,
+    ) -> Result<PutSigningProfileResponse, RusotoError<PutSigningProfileError>> {
+        self.runtime
+            .block_on(self.client.put_signing_profile(input))
+    }
+
+    /// <p>Initiates a signing job to be performed on the code provided. Signing jobs are viewable by the <code>ListSigningJobs</code> operation for two years after they are performed. Note the following requirements: </p> <ul> <li> <p> You must create an Amazon S3 source bucket. For more information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/gsg/CreatingABucket.html">Create a Bucket</a> in the <i>Amazon S3 Getting Started Guide</i>. </p> </li> <li> <p>Your S3 source bucket must be version enabled.</p> </li> <li> <p>You must create an S3 destination bucket. Code signing uses your S3 destination bucket to write your signed code.</p> </li> <li> <p>You specify the name of the source and destination buckets when calling the <code>StartSigningJob</code> operation.</p> </li> <li> <p>You must also specify a request token that identifies your request to code signing.</p> </li> </ul> <p>You can call the <a>DescribeSigningJob</a> and the <a>ListSigningJobs</a> actions after you call <code>StartSigningJob</code>.</p> <p>For a Java example that shows how to use this action, see <a href="http://docs.aws.amazon.com/acm/latest/userguide/">http://docs.aws.amazon.com/acm/latest/userguide/</a> </p>
+    ///
+    /// See `Signer::start_signing_job`.
+    pub fn start_signing_job(
+        &self,
+        input: StartSigningJobRequest,
+    ) -> Result<StartSigningJobResponse, RusotoError<StartSigningJobError>> {
+        self.runtime.block_on(self.client.start_signing_job(input))
+    }
+
+    /// <p>Adds one or more tags to a signing profile. Tags are labels that you can use to identify and organize your AWS resources. Each tag consists of a key and an optional value. To specify the signing profile, use its Amazon Resource Name (ARN). To specify the tag, use a key-value pair.</p>
+    ///
+    /// See `Signer::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from a signing profile. To remove the tags, specify a list of tag keys.</p>
+    ///
+    /// See `Signer::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -1235,19 +1235,28 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(CreateAppError::InternalError(err.msg))
+                    return RusotoError::service(&res, CreateAppError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateAppError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(CreateAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAppError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAppError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAppError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1297,47 +1306,58 @@ impl CreateReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(CreateReplicationJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationJobError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateReplicationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationJobError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "NoConnectorsAvailableException" => {
-                    return RusotoError::Service(CreateReplicationJobError::NoConnectorsAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationJobError::NoConnectorsAvailable(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateReplicationJobError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationJobError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ReplicationJobAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationJobError::ReplicationJobAlreadyExists(err.msg),
                     )
                 }
                 "ServerCannotBeReplicatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateReplicationJobError::ServerCannotBeReplicated(err.msg),
                     )
                 }
                 "TemporarilyUnavailableException" => {
-                    return RusotoError::Service(CreateReplicationJobError::TemporarilyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationJobError::TemporarilyUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateReplicationJobError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateReplicationJobError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1389,19 +1409,28 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(DeleteAppError::InternalError(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteAppError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(DeleteAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAppError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAppError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAppError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1445,27 +1474,32 @@ impl DeleteAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(DeleteAppLaunchConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAppLaunchConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppLaunchConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppLaunchConfigurationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -1519,27 +1553,32 @@ impl DeleteAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppReplicationConfigurationError::InternalError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppReplicationConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppReplicationConfigurationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -1593,29 +1632,34 @@ impl DeleteReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReplicationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteReplicationJobError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReplicationJobError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ReplicationJobNotFoundException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::ReplicationJobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReplicationJobError::ReplicationJobNotFound(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteReplicationJobError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteReplicationJobError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1657,24 +1701,28 @@ impl DeleteServerCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServerCatalogError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteServerCatalogError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServerCatalogError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteServerCatalogError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServerCatalogError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1713,24 +1761,28 @@ impl DisassociateConnectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateConnectorError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateConnectorError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateConnectorError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DisassociateConnectorError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateConnectorError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DisassociateConnectorError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateConnectorError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1773,25 +1825,34 @@ impl GenerateChangeSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GenerateChangeSetError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateChangeSetError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GenerateChangeSetError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateChangeSetError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GenerateChangeSetError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateChangeSetError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GenerateChangeSetError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateChangeSetError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GenerateChangeSetError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateChangeSetError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1833,25 +1894,34 @@ impl GenerateTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GenerateTemplateError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateTemplateError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GenerateTemplateError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GenerateTemplateError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GenerateTemplateError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateTemplateError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GenerateTemplateError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateTemplateError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GenerateTemplateError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GenerateTemplateError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1893,19 +1963,22 @@ impl GetAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppError::InternalError(err.msg))
+                    return RusotoError::service(&res, GetAppError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, GetAppError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetAppError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(&res, GetAppError::OperationNotPermitted(err.msg))
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(&res, GetAppError::UnauthorizedOperation(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1947,27 +2020,32 @@ impl GetAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(GetAppLaunchConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppLaunchConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAppLaunchConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAppLaunchConfigurationError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppLaunchConfigurationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -2019,27 +2097,32 @@ impl GetAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppReplicationConfigurationError::InternalError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppReplicationConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppReplicationConfigurationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -2083,7 +2166,10 @@ impl GetConnectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetConnectorsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectorsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2117,17 +2203,22 @@ impl GetReplicationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetReplicationJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetReplicationJobsError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetReplicationJobsError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReplicationJobsError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetReplicationJobsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReplicationJobsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2163,17 +2254,22 @@ impl GetReplicationRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetReplicationRunsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetReplicationRunsError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(GetReplicationRunsError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReplicationRunsError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetReplicationRunsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetReplicationRunsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2205,7 +2301,10 @@ impl GetServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetServersError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServersError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2243,29 +2342,34 @@ impl ImportServerCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportServerCatalogError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportServerCatalogError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ImportServerCatalogError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "NoConnectorsAvailableException" => {
-                    return RusotoError::Service(ImportServerCatalogError::NoConnectorsAvailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportServerCatalogError::NoConnectorsAvailable(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ImportServerCatalogError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportServerCatalogError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ImportServerCatalogError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportServerCatalogError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2307,19 +2411,28 @@ impl LaunchAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(LaunchAppError::InternalError(err.msg))
+                    return RusotoError::service(&res, LaunchAppError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(LaunchAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, LaunchAppError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(LaunchAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LaunchAppError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(LaunchAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LaunchAppError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(LaunchAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        LaunchAppError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2361,19 +2474,28 @@ impl ListAppsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(ListAppsError::InternalError(err.msg))
+                    return RusotoError::service(&res, ListAppsError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAppsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListAppsError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(ListAppsError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAppsError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListAppsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAppsError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ListAppsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAppsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2415,27 +2537,32 @@ impl PutAppLaunchConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(PutAppLaunchConfigurationError::InternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAppLaunchConfigurationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAppLaunchConfigurationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAppLaunchConfigurationError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppLaunchConfigurationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppLaunchConfigurationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppLaunchConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -2487,27 +2614,32 @@ impl PutAppReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppReplicationConfigurationError::InternalError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppReplicationConfigurationError::InvalidParameter(err.msg),
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppReplicationConfigurationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppReplicationConfigurationError::OperationNotPermitted(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutAppReplicationConfigurationError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -2559,27 +2691,34 @@ impl StartAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StartAppReplicationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartAppReplicationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartAppReplicationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAppReplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAppReplicationError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartAppReplicationError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAppReplicationError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(StartAppReplicationError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAppReplicationError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2623,27 +2762,32 @@ impl StartOnDemandReplicationRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOnDemandReplicationRunError::InvalidParameter(err.msg),
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOnDemandReplicationRunError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOnDemandReplicationRunError::OperationNotPermitted(err.msg),
                     )
                 }
                 "ReplicationRunLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOnDemandReplicationRunError::ReplicationRunLimitExceeded(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartOnDemandReplicationRunError::UnauthorizedOperation(err.msg),
                     )
                 }
@@ -2695,25 +2839,34 @@ impl StopAppReplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(StopAppReplicationError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAppReplicationError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopAppReplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopAppReplicationError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(StopAppReplicationError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopAppReplicationError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopAppReplicationError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopAppReplicationError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(StopAppReplicationError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopAppReplicationError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2755,21 +2908,28 @@ impl TerminateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(TerminateAppError::InternalError(err.msg))
+                    return RusotoError::service(&res, TerminateAppError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TerminateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, TerminateAppError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(TerminateAppError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TerminateAppError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(TerminateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TerminateAppError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(TerminateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TerminateAppError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2811,19 +2971,28 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(UpdateAppError::InternalError(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::InternalError(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAppError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, UpdateAppError::InvalidParameter(err.msg))
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(UpdateAppError::MissingRequiredParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAppError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateAppError::OperationNotPermitted(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAppError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateAppError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAppError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2871,42 +3040,52 @@ impl UpdateReplicationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalError" => {
-                    return RusotoError::Service(UpdateReplicationJobError::InternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReplicationJobError::InternalError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReplicationJobError::InvalidParameter(err.msg),
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateReplicationJobError::MissingRequiredParameter(err.msg),
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::OperationNotPermitted(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReplicationJobError::OperationNotPermitted(err.msg),
+                    )
                 }
                 "ReplicationJobNotFoundException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::ReplicationJobNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReplicationJobError::ReplicationJobNotFound(err.msg),
+                    )
                 }
                 "ServerCannotBeReplicatedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateReplicationJobError::ServerCannotBeReplicated(err.msg),
                     )
                 }
                 "TemporarilyUnavailableException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::TemporarilyUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReplicationJobError::TemporarilyUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateReplicationJobError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateReplicationJobError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3131,6 +3310,7 @@ impl ServerMigrationServiceClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3146,6 +3326,7 @@ impl ServerMigrationServiceClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> ServerMigrationServiceClient {
         ServerMigrationServiceClient { client, region }
     }
@@ -3159,6 +3340,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.CreateApp",
@@ -3180,6 +3364,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: CreateReplicationJobRequest,
     ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateReplicationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.CreateReplicationJob",
@@ -3202,6 +3389,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DeleteAppRequest,
     ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteApp",
@@ -3224,6 +3414,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAppLaunchConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteAppLaunchConfiguration",
@@ -3249,6 +3442,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<DeleteAppReplicationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAppReplicationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteAppReplicationConfiguration",
@@ -3274,6 +3470,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DeleteReplicationJobRequest,
     ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteReplicationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteReplicationJob",
@@ -3295,6 +3494,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         &self,
     ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteServerCatalog");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DeleteServerCatalog",
@@ -3315,6 +3517,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: DisassociateConnectorRequest,
     ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateConnector");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.DisassociateConnector",
@@ -3337,6 +3542,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GenerateChangeSetRequest,
     ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateChangeSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GenerateChangeSet",
@@ -3358,6 +3566,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GenerateTemplateRequest,
     ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GenerateTemplate");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GenerateTemplate",
@@ -3379,6 +3590,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetAppRequest,
     ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetApp");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetApp",
@@ -3401,6 +3615,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAppLaunchConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetAppLaunchConfiguration",
@@ -3426,6 +3643,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<GetAppReplicationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAppReplicationConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetAppReplicationConfiguration",
@@ -3448,6 +3668,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetConnectorsRequest,
     ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConnectors");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetConnectors",
@@ -3469,6 +3692,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetReplicationJobsRequest,
     ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetReplicationJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetReplicationJobs",
@@ -3490,6 +3716,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetReplicationRunsRequest,
     ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetReplicationRuns");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetReplicationRuns",
@@ -3511,6 +3740,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: GetServersRequest,
     ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetServers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.GetServers",
@@ -3531,6 +3763,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         &self,
     ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportServerCatalog");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.ImportServerCatalog",
@@ -3551,6 +3786,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: LaunchAppRequest,
     ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("LaunchApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.LaunchApp",
@@ -3572,6 +3810,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: ListAppsRequest,
     ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListApps");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.ListApps",
@@ -3594,6 +3835,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAppLaunchConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.PutAppLaunchConfiguration",
@@ -3619,6 +3863,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         RusotoError<PutAppReplicationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAppReplicationConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.PutAppReplicationConfiguration",
@@ -3641,6 +3888,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: StartAppReplicationRequest,
     ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartAppReplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StartAppReplication",
@@ -3663,6 +3913,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
     ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartOnDemandReplicationRun");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StartOnDemandReplicationRun",
@@ -3685,6 +3938,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: StopAppReplicationRequest,
     ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopAppReplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.StopAppReplication",
@@ -3706,6 +3962,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: TerminateAppRequest,
     ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TerminateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.TerminateApp",
@@ -3727,6 +3986,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: UpdateAppRequest,
     ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateApp");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.UpdateApp",
@@ -3748,6 +4010,9 @@ impl ServerMigrationService for ServerMigrationServiceClient {
         input: UpdateReplicationJobRequest,
     ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateReplicationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSServerMigrationService_V2016_10_24.UpdateReplicationJob",
@@ -3764,3 +4029,367 @@ impl ServerMigrationService for ServerMigrationServiceClient {
             .deserialize::<UpdateReplicationJobResponse, _>()
     }
 }
+
+/// A blocking client for the SMS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `ServerMigrationServiceClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ServerMigrationServiceBlockingClient {
+    client: ServerMigrationServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ServerMigrationServiceBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ServerMigrationServiceBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        ServerMigrationServiceBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ServerMigrationServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ServerMigrationServiceBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ServerMigrationServiceBlockingClient {
+        ServerMigrationServiceBlockingClient {
+            client: ServerMigrationServiceClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates an application. An application consists of one or more server groups. Each server group contain one or more servers.</p>
+    ///
+    /// See `ServerMigrationService::create_app`.
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// <p>Creates a replication job. The replication job schedules periodic replication runs to replicate your server to AWS. Each replication run creates an Amazon Machine Image (AMI).</p>
+    ///
+    /// See `ServerMigrationService::create_replication_job`.
+    pub fn create_replication_job(
+        &self,
+        input: CreateReplicationJobRequest,
+    ) -> Result<CreateReplicationJobResponse, RusotoError<CreateReplicationJobError>> {
+        self.runtime
+            .block_on(self.client.create_replication_job(input))
+    }
+
+    /// <p>Deletes an existing application. Optionally deletes the launched stack associated with the application and all AWS SMS replication jobs for servers in the application.</p>
+    ///
+    /// See `ServerMigrationService::delete_app`.
+    pub fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<DeleteAppResponse, RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// <p>Deletes existing launch configuration for an application.</p>
+    ///
+    /// See `ServerMigrationService::delete_app_launch_configuration`.
+    pub fn delete_app_launch_configuration(
+        &self,
+        input: DeleteAppLaunchConfigurationRequest,
+    ) -> Result<DeleteAppLaunchConfigurationResponse, RusotoError<DeleteAppLaunchConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_app_launch_configuration(input))
+    }
+
+    /// <p>Deletes existing replication configuration for an application.</p>
+    ///
+    /// See `ServerMigrationService::delete_app_replication_configuration`.
+    pub fn delete_app_replication_configuration(
+        &self,
+        input: DeleteAppReplicationConfigurationRequest,
+    ) -> Result<
+        DeleteAppReplicationConfigurationResponse,
+        RusotoError<DeleteAppReplicationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_app_replication_configuration(input))
+    }
+
+    /// <p>Deletes the specified replication job.</p> <p>After you delete a replication job, there are no further replication runs. AWS deletes the contents of the Amazon S3 bucket used to store AWS SMS artifacts. The AMIs created by the replication runs are not deleted.</p>
+    ///
+    /// See `ServerMigrationService::delete_replication_job`.
+    pub fn delete_replication_job(
+        &self,
+        input: DeleteReplicationJobRequest,
+    ) -> Result<DeleteReplicationJobResponse, RusotoError<DeleteReplicationJobError>> {
+        self.runtime
+            .block_on(self.client.delete_replication_job(input))
+    }
+
+    /// <p>Deletes all servers from your server catalog.</p>
+    ///
+    /// See `ServerMigrationService::delete_server_catalog`.
+    pub fn delete_server_catalog(
+        &self,
+    ) -> Result<DeleteServerCatalogResponse, RusotoError<DeleteServerCatalogError>> {
+        self.runtime.block_on(self.client.delete_server_catalog())
+    }
+
+    /// <p>Disassociates the specified connector from AWS SMS.</p> <p>After you disassociate a connector, it is no longer available to support replication jobs.</p>
+    ///
+    /// See `ServerMigrationService::disassociate_connector`.
+    pub fn disassociate_connector(
+        &self,
+        input: DisassociateConnectorRequest,
+    ) -> Result<DisassociateConnectorResponse, RusotoError<DisassociateConnectorError>> {
+        self.runtime
+            .block_on(self.client.disassociate_connector(input))
+    }
+
+    /// <p>Generates a target change set for a currently launched stack and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
+    ///
+    /// See `ServerMigrationService::generate_change_set`.
+    pub fn generate_change_set(
+        &self,
+        input: GenerateChangeSetRequest,
+    ) -> Result<GenerateChangeSetResponse, RusotoError<GenerateChangeSetError>> {
+        self.runtime
+            .block_on(self.client.generate_change_set(input))
+    }
+
+    /// <p>Generates an Amazon CloudFormation template based on the current launch configuration and writes it to an Amazon S3 object in the customer’s Amazon S3 bucket.</p>
+    ///
+    /// See `ServerMigrationService::generate_template`.
+    pub fn generate_template(
+        &self,
+        input: GenerateTemplateRequest,
+    ) -> Result<GenerateTemplateResponse, RusotoError<GenerateTemplateError>> {
+        self.runtime.block_on(self.client.generate_template(input))
+    }
+
+    /// <p>Retrieve information about an application.</p>
+    ///
+    /// See `ServerMigrationService::get_app`.
+    pub fn get_app(
+        &self,
+        input: GetAppRequest,
+    ) -> Result<GetAppResponse, RusotoError<GetAppError>> {
+        self.runtime.block_on(self.client.get_app(input))
+    }
+
+    /// <p>Retrieves the application launch configuration associated with an application.</p>
+    ///
+    /// See `ServerMigrationService::get_app_launch_configuration`.
+    pub fn get_app_launch_configuration(
+        &self,
+        input: GetAppLaunchConfigurationRequest,
+    ) -> Result<GetAppLaunchConfigurationResponse, RusotoError<GetAppLaunchConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_app_launch_configuration(input))
+    }
+
+    /// <p>Retrieves an application replication configuration associatd with an application.</p>
+    ///
+    /// See `ServerMigrationService::get_app_replication_configuration`.
+    pub fn get_app_replication_configuration(
+        &self,
+        input: GetAppReplicationConfigurationRequest,
+    ) -> Result<
+        GetAppReplicationConfigurationResponse,
+        RusotoError<GetAppReplicationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_app_replication_configuration(input))
+    }
+
+    /// <p>Describes the connectors registered with the AWS SMS.</p>
+    ///
+    /// See `ServerMigrationService::get_connectors`.
+    pub fn get_connectors(
+        &self,
+        input: GetConnectorsRequest,
+    ) -> Result<GetConnectorsResponse, RusotoError<GetConnectorsError>> {
+        self.runtime.block_on(self.client.get_connectors(input))
+    }
+
+    /// <p>Describes the specified replication job or all of your replication jobs.</p>
+    ///
+    /// See `ServerMigrationService::get_replication_jobs`.
+    pub fn get_replication_jobs(
+        &self,
+        input: GetReplicationJobsRequest,
+    ) -> Result<GetReplicationJobsResponse, RusotoError<GetReplicationJobsError>> {
+        self.runtime
+            .block_on(self.client.get_replication_jobs(input))
+    }
+
+    /// <p>Describes the replication runs for the specified replication job.</p>
+    ///
+    /// See `ServerMigrationService::get_replication_runs`.
+    pub fn get_replication_runs(
+        &self,
+        input: GetReplicationRunsRequest,
+    ) -> Result<GetReplicationRunsResponse, RusotoError<GetReplicationRunsError>> {
+        self.runtime
+            .block_on(self.client.get_replication_runs(input))
+    }
+
+    /// <p>Describes the servers in your server catalog.</p> <p>Before you can describe your servers, you must import them using <a>ImportServerCatalog</a>.</p>
+    ///
+    /// See `ServerMigrationService::get_servers`.
+    pub fn get_servers(
+        &self,
+        input: GetServersRequest,
+    ) -> Result<GetServersResponse, RusotoError<GetServersError>> {
+        self.runtime.block_on(self.client.get_servers(input))
+    }
+
+    /// <p>Gathers a complete list of on-premises servers. Connectors must be installed and monitoring all servers that you want to import.</p> <p>This call returns immediately, but might take additional time to retrieve all the servers.</p>
+    ///
+    /// See `ServerMigrationService::import_server_catalog`.
+    pub fn import_server_catalog(
+        &self,
+    ) -> Result<ImportServerCatalogResponse, RusotoError<ImportServerCatalogError>> {
+        self.runtime.block_on(self.client.import_server_catalog())
+    }
+
+    /// <p>Launches an application stack.</p>
+    ///
+    /// See `ServerMigrationService::launch_app`.
+    pub fn launch_app(
+        &self,
+        input: LaunchAppRequest,
+    ) -> Result<LaunchAppResponse, RusotoError<LaunchAppError>> {
+        self.runtime.block_on(self.client.launch_app(input))
+    }
+
+    /// <p>Returns a list of summaries for all applications.</p>
+    ///
+    /// See `ServerMigrationService::list_apps`.
+    pub fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+        self.runtime.block_on(self.client.list_apps(input))
+    }
+
+    /// <p>Creates a launch configuration for an application.</p>
+    ///
+    /// See `ServerMigrationService::put_app_launch_configuration`.
+    pub fn put_app_launch_configuration(
+        &self,
+        input: PutAppLaunchConfigurationRequest,
+    ) -> Result<PutAppLaunchConfigurationResponse, RusotoError<PutAppLaunchConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_app_launch_configuration(input))
+    }
+
+    /// <p>Creates or updates a replication configuration for an application.</p>
+    ///
+    /// See `ServerMigrationService::put_app_replication_configuration`.
+    pub fn put_app_replication_configuration(
+        &self,
+        input: PutAppReplicationConfigurationRequest,
+    ) -> Result<
+        PutAppReplicationConfigurationResponse,
+        RusotoError<PutAppReplicationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_app_replication_configuration(input))
+    }
+
+    /// <p>Starts replicating an application.</p>
+    ///
+    /// See `ServerMigrationService::start_app_replication`.
+    pub fn start_app_replication(
+        &self,
+        input: StartAppReplicationRequest,
+    ) -> Result<StartAppReplicationResponse, RusotoError<StartAppReplicationError>> {
+        self.runtime
+            .block_on(self.client.start_app_replication(input))
+    }
+
+    /// <p>Starts an on-demand replication run for the specified replication job. This replication run starts immediately. This replication run is in addition to the ones already scheduled.</p> <p>There is a limit on the number of on-demand replications runs you can request in a 24-hour period.</p>
+    ///
+    /// See `ServerMigrationService::start_on_demand_replication_run`.
+    pub fn start_on_demand_replication_run(
+        &self,
+        input: StartOnDemandReplicationRunRequest,
+    ) -> Result<StartOnDemandReplicationRunResponse, RusotoError<StartOnDemandReplicationRunError>>
+    {
+        self.runtime
+            .block_on(self.client.start_on_demand_replication_run(input))
+    }
+
+    /// <p>Stops replicating an application.</p>
+    ///
+    /// See `ServerMigrationService::stop_app_replication`.
+    pub fn stop_app_replication(
+        &self,
+        input: StopAppReplicationRequest,
+    ) -> Result<StopAppReplicationResponse, RusotoError<StopAppReplicationError>> {
+        self.runtime
+            .block_on(self.client.stop_app_replication(input))
+    }
+
+    /// <p>Terminates the stack for an application.</p>
+    ///
+    /// See `ServerMigrationService::terminate_app`.
+    pub fn terminate_app(
+        &self,
+        input: TerminateAppRequest,
+    ) -> Result<TerminateAppResponse, RusotoError<TerminateAppError>> {
+        self.runtime.block_on(self.client.terminate_app(input))
+    }
+
+    /// <p>Updates an application.</p>
+    ///
+    /// See `ServerMigrationService::update_app`.
+    pub fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<UpdateAppResponse, RusotoError<UpdateAppError>> {
+        self.runtime.block_on(self.client.update_app(input))
+    }
+
+    /// <p>Updates the specified settings for the specified replication job.</p>
+    ///
+    /// See `ServerMigrationService::update_replication_job`.
+    pub fn update_replication_job(
+        &self,
+        input: UpdateReplicationJobRequest,
+    ) -> Result<UpdateReplicationJobResponse, RusotoError<UpdateReplicationJobError>> {
+        self.runtime
+            .block_on(self.client.update_replication_job(input))
+    }
+}
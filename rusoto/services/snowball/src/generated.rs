@@ -1054,13 +1054,16 @@ impl CancelClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CancelClusterError::InvalidJobState(err.msg))
+                    return RusotoError::service(&res, CancelClusterError::InvalidJobState(err.msg))
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CancelClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, CancelClusterError::InvalidResource(err.msg))
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CancelClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelClusterError::KMSRequestFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1096,13 +1099,13 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(CancelJobError::InvalidJobState(err.msg))
+                    return RusotoError::service(&res, CancelJobError::InvalidJobState(err.msg))
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CancelJobError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, CancelJobError::InvalidResource(err.msg))
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CancelJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(&res, CancelJobError::KMSRequestFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1136,10 +1139,13 @@ impl CreateAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAddressException" => {
-                    return RusotoError::Service(CreateAddressError::InvalidAddress(err.msg))
+                    return RusotoError::service(&res, CreateAddressError::InvalidAddress(err.msg))
                 }
                 "UnsupportedAddressException" => {
-                    return RusotoError::Service(CreateAddressError::UnsupportedAddress(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAddressError::UnsupportedAddress(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1176,18 +1182,25 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(CreateClusterError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::Ec2RequestFailed(err.msg),
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidInputCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::InvalidInputCombination(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, CreateClusterError::InvalidResource(err.msg))
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CreateClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateClusterError::KMSRequestFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1228,19 +1241,25 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterLimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::ClusterLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobError::ClusterLimitExceeded(err.msg),
+                    )
                 }
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(CreateJobError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(&res, CreateJobError::Ec2RequestFailed(err.msg))
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(CreateJobError::InvalidInputCombination(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateJobError::InvalidInputCombination(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(CreateJobError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, CreateJobError::InvalidResource(err.msg))
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(CreateJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(&res, CreateJobError::KMSRequestFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1274,7 +1293,10 @@ impl DescribeAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeAddressError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAddressError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1306,10 +1328,16 @@ impl DescribeAddressesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeAddressesError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAddressesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeAddressesError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAddressesError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1340,7 +1368,10 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClusterError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1370,7 +1401,7 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, DescribeJobError::InvalidResource(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1402,10 +1433,16 @@ impl GetJobManifestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetJobManifestError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobManifestError::InvalidJobState(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetJobManifestError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobManifestError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1438,10 +1475,16 @@ impl GetJobUnlockCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetJobUnlockCodeError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobUnlockCodeError::InvalidJobState(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetJobUnlockCodeError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetJobUnlockCodeError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1496,10 +1539,16 @@ impl GetSoftwareUpdatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(GetSoftwareUpdatesError::InvalidJobState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSoftwareUpdatesError::InvalidJobState(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(GetSoftwareUpdatesError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSoftwareUpdatesError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1532,10 +1581,16 @@ impl ListClusterJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListClusterJobsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClusterJobsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(ListClusterJobsError::InvalidResource(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListClusterJobsError::InvalidResource(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1566,7 +1621,7 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListClustersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListClustersError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1598,14 +1653,16 @@ impl ListCompatibleImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(ListCompatibleImagesError::Ec2RequestFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCompatibleImagesError::Ec2RequestFailed(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCompatibleImagesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCompatibleImagesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1636,7 +1693,7 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListJobsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListJobsError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1674,21 +1731,28 @@ impl UpdateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(UpdateClusterError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::Ec2RequestFailed(err.msg),
+                    )
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidInputCombination(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::InvalidInputCombination(err.msg),
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidJobState(err.msg))
+                    return RusotoError::service(&res, UpdateClusterError::InvalidJobState(err.msg))
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, UpdateClusterError::InvalidResource(err.msg))
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(UpdateClusterError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateClusterError::KMSRequestFailed(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1732,22 +1796,28 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterLimitExceededException" => {
-                    return RusotoError::Service(UpdateJobError::ClusterLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobError::ClusterLimitExceeded(err.msg),
+                    )
                 }
                 "Ec2RequestFailedException" => {
-                    return RusotoError::Service(UpdateJobError::Ec2RequestFailed(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::Ec2RequestFailed(err.msg))
                 }
                 "InvalidInputCombinationException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidInputCombination(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateJobError::InvalidInputCombination(err.msg),
+                    )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidJobState(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::InvalidJobState(err.msg))
                 }
                 "InvalidResourceException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidResource(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::InvalidResource(err.msg))
                 }
                 "KMSRequestFailedException" => {
-                    return RusotoError::Service(UpdateJobError::KMSRequestFailed(err.msg))
+                    return RusotoError::service(&res, UpdateJobError::KMSRequestFailed(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1904,6 +1974,7 @@ impl SnowballClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1919,6 +1990,7 @@ impl SnowballClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SnowballClient {
         SnowballClient { client, region }
     }
@@ -1932,6 +2004,9 @@ impl Snowball for SnowballClient {
         input: CancelClusterRequest,
     ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CancelCluster",
@@ -1953,6 +2028,9 @@ impl Snowball for SnowballClient {
         input: CancelJobRequest,
     ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CancelJob",
@@ -1974,6 +2052,9 @@ impl Snowball for SnowballClient {
         input: CreateAddressRequest,
     ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAddress");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateAddress",
@@ -1995,6 +2076,9 @@ impl Snowball for SnowballClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateCluster",
@@ -2016,6 +2100,9 @@ impl Snowball for SnowballClient {
         input: CreateJobRequest,
     ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.CreateJob",
@@ -2037,6 +2124,9 @@ impl Snowball for SnowballClient {
         input: DescribeAddressRequest,
     ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAddress");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeAddress",
@@ -2058,6 +2148,9 @@ impl Snowball for SnowballClient {
         input: DescribeAddressesRequest,
     ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAddresses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeAddresses",
@@ -2079,6 +2172,9 @@ impl Snowball for SnowballClient {
         input: DescribeClusterRequest,
     ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCluster");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeCluster",
@@ -2100,6 +2196,9 @@ impl Snowball for SnowballClient {
         input: DescribeJobRequest,
     ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.DescribeJob",
@@ -2121,6 +2220,9 @@ impl Snowball for SnowballClient {
         input: GetJobManifestRequest,
     ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobManifest");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetJobManifest",
@@ -2142,6 +2244,9 @@ impl Snowball for SnowballClient {
         input: GetJobUnlockCodeRequest,
     ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetJobUnlockCode");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetJobUnlockCode",
@@ -2162,6 +2267,9 @@ impl Snowball for SnowballClient {
         &self,
     ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSnowballUsage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetSnowballUsage",
@@ -2182,6 +2290,9 @@ impl Snowball for SnowballClient {
         input: GetSoftwareUpdatesRequest,
     ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSoftwareUpdates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.GetSoftwareUpdates",
@@ -2203,6 +2314,9 @@ impl Snowball for SnowballClient {
         input: ListClusterJobsRequest,
     ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListClusterJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.ListClusterJobs",
@@ -2224,6 +2338,9 @@ impl Snowball for SnowballClient {
         input: ListClustersRequest,
     ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListClusters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.ListClusters",
@@ -2245,6 +2362,9 @@ impl Snowball for SnowballClient {
         input: ListCompatibleImagesRequest,
     ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCompatibleImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.ListCompatibleImages",
@@ -2266,6 +2386,9 @@ impl Snowball for SnowballClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSIESnowballJobManagementService.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2284,6 +2407,9 @@ impl Snowball for SnowballClient {
         input: UpdateClusterRequest,
     ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateCluster");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.UpdateCluster",
@@ -2305,6 +2431,9 @@ impl Snowball for SnowballClient {
         input: UpdateJobRequest,
     ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSIESnowballJobManagementService.UpdateJob",
@@ -2320,3 +2449,245 @@ impl Snowball for SnowballClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateJobResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Snowball API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SnowballClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SnowballBlockingClient {
+    client: SnowballClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SnowballBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SnowballBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SnowballBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SnowballBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SnowballBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SnowballBlockingClient {
+        SnowballBlockingClient {
+            client: SnowballClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Cancels a cluster job. You can only cancel a cluster job while it's in the <code>AwaitingQuorum</code> status. You'll have at least an hour after creating a cluster job to cancel it.</p>
+    ///
+    /// See `Snowball::cancel_cluster`.
+    pub fn cancel_cluster(
+        &self,
+        input: CancelClusterRequest,
+    ) -> Result<CancelClusterResult, RusotoError<CancelClusterError>> {
+        self.runtime.block_on(self.client.cancel_cluster(input))
+    }
+
+    /// <p>Cancels the specified job. You can only cancel a job before its <code>JobState</code> value changes to <code>PreparingAppliance</code>. Requesting the <code>ListJobs</code> or <code>DescribeJob</code> action returns a job's <code>JobState</code> as part of the response element data returned.</p>
+    ///
+    /// See `Snowball::cancel_job`.
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResult, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// <p>Creates an address for a Snowball to be shipped to. In most regions, addresses are validated at the time of creation. The address you provide must be located within the serviceable area of your region. If the address is invalid or unsupported, then an exception is thrown.</p>
+    ///
+    /// See `Snowball::create_address`.
+    pub fn create_address(
+        &self,
+        input: CreateAddressRequest,
+    ) -> Result<CreateAddressResult, RusotoError<CreateAddressError>> {
+        self.runtime.block_on(self.client.create_address(input))
+    }
+
+    /// <p>Creates an empty cluster. Each cluster supports five nodes. You use the <a>CreateJob</a> action separately to create the jobs for each of these nodes. The cluster does not ship until these five node jobs have been created.</p>
+    ///
+    /// See `Snowball::create_cluster`.
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// <p>Creates a job to import or export data between Amazon S3 and your on-premises data center. Your AWS account must have the right trust policies and permissions in place to create a job for Snowball. If you're creating a job for a node in a cluster, you only need to provide the <code>clusterId</code> value; the other job attributes are inherited from the cluster. </p>
+    ///
+    /// See `Snowball::create_job`.
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResult, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// <p>Takes an <code>AddressId</code> and returns specific details about that address in the form of an <code>Address</code> object.</p>
+    ///
+    /// See `Snowball::describe_address`.
+    pub fn describe_address(
+        &self,
+        input: DescribeAddressRequest,
+    ) -> Result<DescribeAddressResult, RusotoError<DescribeAddressError>> {
+        self.runtime.block_on(self.client.describe_address(input))
+    }
+
+    /// <p>Returns a specified number of <code>ADDRESS</code> objects. Calling this API in one of the US regions will return addresses from the list of all addresses associated with this account in all US regions.</p>
+    ///
+    /// See `Snowball::describe_addresses`.
+    pub fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
+        self.runtime.block_on(self.client.describe_addresses(input))
+    }
+
+    /// <p>Returns information about a specific cluster including shipping information, cluster status, and other important metadata.</p>
+    ///
+    /// See `Snowball::describe_cluster`.
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResult, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// <p>Returns information about a specific job including shipping information, job status, and other important metadata. </p>
+    ///
+    /// See `Snowball::describe_job`.
+    pub fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> Result<DescribeJobResult, RusotoError<DescribeJobError>> {
+        self.runtime.block_on(self.client.describe_job(input))
+    }
+
+    /// <p>Returns a link to an Amazon S3 presigned URL for the manifest file associated with the specified <code>JobId</code> value. You can access the manifest file for up to 60 minutes after this request has been made. To access the manifest file after 60 minutes have passed, you'll have to make another call to the <code>GetJobManifest</code> action.</p> <p>The manifest is an encrypted file that you can download after your job enters the <code>WithCustomer</code> status. The manifest is decrypted by using the <code>UnlockCode</code> code value, when you pass both values to the Snowball through the Snowball client when the client is started for the first time.</p> <p>As a best practice, we recommend that you don't save a copy of an <code>UnlockCode</code> value in the same location as the manifest file for that job. Saving these separately helps prevent unauthorized parties from gaining access to the Snowball associated with that job.</p> <p>The credentials of a given job, including its manifest file and unlock code, expire 90 days after the job is created.</p>
+    ///
+    /// See `Snowball::get_job_manifest`.
+    pub fn get_job_manifest(
+        &self,
+        input: GetJobManifestRequest,
+    ) -> Result<GetJobManifestResult, RusotoError<GetJobManifestError>> {
+        self.runtime.block_on(self.client.get_job_manifest(input))
+    }
+
+    /// <p>Returns the <code>UnlockCode</code> code value for the specified job. A particular <code>UnlockCode</code> value can be accessed for up to 90 days after the associated job has been created.</p> <p>The <code>UnlockCode</code> value is a 29-character code with 25 alphanumeric characters and 4 hyphens. This code is used to decrypt the manifest file when it is passed along with the manifest to the Snowball through the Snowball client when the client is started for the first time.</p> <p>As a best practice, we recommend that you don't save a copy of the <code>UnlockCode</code> in the same location as the manifest file for that job. Saving these separately helps prevent unauthorized parties from gaining access to the Snowball associated with that job.</p>
+    ///
+    /// See `Snowball::get_job_unlock_code`.
+    pub fn get_job_unlock_code(
+        &self,
+        input: GetJobUnlockCodeRequest,
+    ) -> Result<GetJobUnlockCodeResult, RusotoError<GetJobUnlockCodeError>> {
+        self.runtime
+            .block_on(self.client.get_job_unlock_code(input))
+    }
+
+    /// <p>Returns information about the Snowball service limit for your account, and also the number of Snowballs your account has in use.</p> <p>The default service limit for the number of Snowballs that you can have at one time is 1. If you want to increase your service limit, contact AWS Support.</p>
+    ///
+    /// See `Snowball::get_snowball_usage`.
+    pub fn get_snowball_usage(
+        &self,
+    ) -> Result<GetSnowballUsageResult, RusotoError<GetSnowballUsageError>> {
+        self.runtime.block_on(self.client.get_snowball_usage())
+    }
+
+    /// <p>Returns an Amazon S3 presigned URL for an update file associated with a specified <code>JobId</code>.</p>
+    ///
+    /// See `Snowball::get_software_updates`.
+    pub fn get_software_updates(
+        &self,
+        input: GetSoftwareUpdatesRequest,
+    ) -> Result<GetSoftwareUpdatesResult, RusotoError<GetSoftwareUpdatesError>> {
+        self.runtime
+            .block_on(self.client.get_software_updates(input))
+    }
+
+    /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object is for a job in the specified cluster and contains a job's state, a job's ID, and other information.</p>
+    ///
+    /// See `Snowball::list_cluster_jobs`.
+    pub fn list_cluster_jobs(
+        &self,
+        input: ListClusterJobsRequest,
+    ) -> Result<ListClusterJobsResult, RusotoError<ListClusterJobsError>> {
+        self.runtime.block_on(self.client.list_cluster_jobs(input))
+    }
+
+    /// <p>Returns an array of <code>ClusterListEntry</code> objects of the specified length. Each <code>ClusterListEntry</code> object contains a cluster's state, a cluster's ID, and other important status information.</p>
+    ///
+    /// See `Snowball::list_clusters`.
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResult, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// <p>This action returns a list of the different Amazon EC2 Amazon Machine Images (AMIs) that are owned by your AWS account that would be supported for use on a Snowball Edge device. Currently, supported AMIs are based on the CentOS 7 (x86_64) - with Updates HVM, Ubuntu Server 14.04 LTS (HVM), and Ubuntu 16.04 LTS - Xenial (HVM) images, available on the AWS Marketplace.</p>
+    ///
+    /// See `Snowball::list_compatible_images`.
+    pub fn list_compatible_images(
+        &self,
+        input: ListCompatibleImagesRequest,
+    ) -> Result<ListCompatibleImagesResult, RusotoError<ListCompatibleImagesError>> {
+        self.runtime
+            .block_on(self.client.list_compatible_images(input))
+    }
+
+    /// <p>Returns an array of <code>JobListEntry</code> objects of the specified length. Each <code>JobListEntry</code> object contains a job's state, a job's ID, and a value that indicates whether the job is a job part, in the case of export jobs. Calling this API action in one of the US regions will return jobs from the list of all jobs associated with this account in all US regions.</p>
+    ///
+    /// See `Snowball::list_jobs`.
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// <p>While a cluster's <code>ClusterState</code> value is in the <code>AwaitingQuorum</code> state, you can update some of the information associated with a cluster. Once the cluster changes to a different job state, usually 60 minutes after the cluster being created, this action is no longer available.</p>
+    ///
+    /// See `Snowball::update_cluster`.
+    pub fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<UpdateClusterResult, RusotoError<UpdateClusterError>> {
+        self.runtime.block_on(self.client.update_cluster(input))
+    }
+
+    /// <p>While a job's <code>JobState</code> value is <code>New</code>, you can update some of the information associated with a job. Once the job changes to a different job state, usually within 60 minutes of the job being created, this action is no longer available.</p>
+    ///
+    /// See `Snowball::update_job`.
+    pub fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<UpdateJobResult, RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+}
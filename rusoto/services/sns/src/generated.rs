@@ -2338,24 +2338,28 @@ impl AddPermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(AddPermissionError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddPermissionError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(AddPermissionError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddPermissionError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(AddPermissionError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddPermissionError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(AddPermissionError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddPermissionError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2408,28 +2412,32 @@ impl CheckIfPhoneNumberIsOptedOutError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CheckIfPhoneNumberIsOptedOutError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CheckIfPhoneNumberIsOptedOutError::InternalError(parsed_error.message),
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CheckIfPhoneNumberIsOptedOutError::InvalidParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(CheckIfPhoneNumberIsOptedOutError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CheckIfPhoneNumberIsOptedOutError::Throttled(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2488,34 +2496,40 @@ impl ConfirmSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ConfirmSubscriptionError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ConfirmSubscriptionError::FilterPolicyLimitExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ConfirmSubscriptionError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ConfirmSubscriptionError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(ConfirmSubscriptionError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ConfirmSubscriptionError::NotFound(parsed_error.message),
+                        )
                     }
                     "SubscriptionLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ConfirmSubscriptionError::SubscriptionLimitExceeded(
                                 parsed_error.message,
                             ),
@@ -2574,19 +2588,22 @@ impl CreatePlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreatePlatformApplicationError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreatePlatformApplicationError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePlatformApplicationError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreatePlatformApplicationError::InvalidParameter(parsed_error.message),
                         )
                     }
@@ -2638,24 +2655,28 @@ impl CreatePlatformEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             CreatePlatformEndpointError::AuthorizationError(parsed_error.message),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePlatformEndpointError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePlatformEndpointError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(CreatePlatformEndpointError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreatePlatformEndpointError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2716,49 +2737,58 @@ impl CreateTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(CreateTopicError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(CreateTopicError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::ConcurrentAccess(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(CreateTopicError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(CreateTopicError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(CreateTopicError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::InvalidSecurity(parsed_error.message),
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(CreateTopicError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::StaleTag(parsed_error.message),
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(CreateTopicError::TagLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::TagLimitExceeded(parsed_error.message),
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(CreateTopicError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::TagPolicy(parsed_error.message),
+                        )
                     }
                     "TopicLimitExceeded" => {
-                        return RusotoError::Service(CreateTopicError::TopicLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateTopicError::TopicLimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2812,19 +2842,22 @@ impl DeleteEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(DeleteEndpointError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteEndpointError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeleteEndpointError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteEndpointError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(DeleteEndpointError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteEndpointError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2872,19 +2905,22 @@ impl DeletePlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeletePlatformApplicationError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeletePlatformApplicationError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeletePlatformApplicationError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeletePlatformApplicationError::InvalidParameter(parsed_error.message),
                         )
                     }
@@ -2942,39 +2978,46 @@ impl DeleteTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(DeleteTopicError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(DeleteTopicError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::ConcurrentAccess(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(DeleteTopicError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(DeleteTopicError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(DeleteTopicError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::NotFound(parsed_error.message),
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(DeleteTopicError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::StaleTag(parsed_error.message),
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(DeleteTopicError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteTopicError::TagPolicy(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3028,24 +3071,28 @@ impl GetEndpointAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetEndpointAttributesError::AuthorizationError(parsed_error.message),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetEndpointAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetEndpointAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetEndpointAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetEndpointAttributesError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetEndpointAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetEndpointAttributesError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3098,28 +3145,32 @@ impl GetPlatformApplicationAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetPlatformApplicationAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetPlatformApplicationAttributesError::InternalError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetPlatformApplicationAttributesError::InvalidParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetPlatformApplicationAttributesError::NotFound(parsed_error.message),
                         )
                     }
@@ -3178,24 +3229,28 @@ impl GetSMSAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(GetSMSAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSMSAttributesError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetSMSAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSMSAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetSMSAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSMSAttributesError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(GetSMSAttributesError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSMSAttributesError::Throttled(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3246,26 +3301,30 @@ impl GetSubscriptionAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetSubscriptionAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetSubscriptionAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSubscriptionAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             GetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetSubscriptionAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetSubscriptionAttributesError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3318,29 +3377,34 @@ impl GetTopicAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(GetTopicAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTopicAttributesError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(GetTopicAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTopicAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(GetTopicAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTopicAttributesError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(GetTopicAttributesError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTopicAttributesError::InvalidSecurity(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(GetTopicAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetTopicAttributesError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3394,28 +3458,32 @@ impl ListEndpointsByPlatformApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListEndpointsByPlatformApplicationError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListEndpointsByPlatformApplicationError::InternalError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListEndpointsByPlatformApplicationError::InvalidParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListEndpointsByPlatformApplicationError::NotFound(parsed_error.message),
                         )
                     }
@@ -3474,24 +3542,28 @@ impl ListPhoneNumbersOptedOutError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPhoneNumbersOptedOutError::AuthorizationError(parsed_error.message),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListPhoneNumbersOptedOutError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPhoneNumbersOptedOutError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPhoneNumbersOptedOutError::InvalidParameter(parsed_error.message),
                         )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(ListPhoneNumbersOptedOutError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPhoneNumbersOptedOutError::Throttled(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3540,17 +3612,20 @@ impl ListPlatformApplicationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPlatformApplicationsError::AuthorizationError(parsed_error.message),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListPlatformApplicationsError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListPlatformApplicationsError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListPlatformApplicationsError::InvalidParameter(parsed_error.message),
                         )
                     }
@@ -3600,19 +3675,22 @@ impl ListSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListSubscriptionsError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSubscriptionsError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListSubscriptionsError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSubscriptionsError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListSubscriptionsError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSubscriptionsError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3662,24 +3740,28 @@ impl ListSubscriptionsByTopicError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListSubscriptionsByTopicError::AuthorizationError(parsed_error.message),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListSubscriptionsByTopicError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSubscriptionsByTopicError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListSubscriptionsByTopicError::InvalidParameter(parsed_error.message),
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(ListSubscriptionsByTopicError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListSubscriptionsByTopicError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3732,29 +3814,34 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListTagsForResourceError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(ListTagsForResourceError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::ConcurrentAccess(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(ListTagsForResourceError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTagsForResourceError::TagPolicy(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3804,19 +3891,22 @@ impl ListTopicsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(ListTopicsError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTopicsError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(ListTopicsError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTopicsError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(ListTopicsError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ListTopicsError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3866,24 +3956,28 @@ impl OptInPhoneNumberError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(OptInPhoneNumberError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            OptInPhoneNumberError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(OptInPhoneNumberError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            OptInPhoneNumberError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(OptInPhoneNumberError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            OptInPhoneNumberError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(OptInPhoneNumberError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            OptInPhoneNumberError::Throttled(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3954,72 +4048,88 @@ impl PublishError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(PublishError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "EndpointDisabled" => {
-                        return RusotoError::Service(PublishError::EndpointDisabled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::EndpointDisabled(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(PublishError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(PublishError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "ParameterValueInvalid" => {
-                        return RusotoError::Service(PublishError::InvalidParameterValue(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::InvalidParameterValue(parsed_error.message),
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(PublishError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::InvalidSecurity(parsed_error.message),
+                        )
                     }
                     "KMSAccessDenied" => {
-                        return RusotoError::Service(PublishError::KMSAccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::KMSAccessDenied(parsed_error.message),
+                        )
                     }
                     "KMSDisabled" => {
-                        return RusotoError::Service(PublishError::KMSDisabled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::KMSDisabled(parsed_error.message),
+                        )
                     }
                     "KMSInvalidState" => {
-                        return RusotoError::Service(PublishError::KMSInvalidState(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::KMSInvalidState(parsed_error.message),
+                        )
                     }
                     "KMSNotFound" => {
-                        return RusotoError::Service(PublishError::KMSNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::KMSNotFound(parsed_error.message),
+                        )
                     }
                     "KMSOptInRequired" => {
-                        return RusotoError::Service(PublishError::KMSOptInRequired(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::KMSOptInRequired(parsed_error.message),
+                        )
                     }
                     "KMSThrottling" => {
-                        return RusotoError::Service(PublishError::KMSThrottling(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::KMSThrottling(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(PublishError::NotFound(parsed_error.message))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::NotFound(parsed_error.message),
+                        )
                     }
                     "PlatformApplicationDisabled" => {
-                        return RusotoError::Service(PublishError::PlatformApplicationDisabled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PublishError::PlatformApplicationDisabled(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4080,24 +4190,28 @@ impl RemovePermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(RemovePermissionError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemovePermissionError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(RemovePermissionError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemovePermissionError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(RemovePermissionError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemovePermissionError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(RemovePermissionError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            RemovePermissionError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4148,24 +4262,28 @@ impl SetEndpointAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetEndpointAttributesError::AuthorizationError(parsed_error.message),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetEndpointAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetEndpointAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetEndpointAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetEndpointAttributesError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetEndpointAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetEndpointAttributesError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4218,28 +4336,32 @@ impl SetPlatformApplicationAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetPlatformApplicationAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetPlatformApplicationAttributesError::InternalError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetPlatformApplicationAttributesError::InvalidParameter(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetPlatformApplicationAttributesError::NotFound(parsed_error.message),
                         )
                     }
@@ -4298,24 +4420,28 @@ impl SetSMSAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SetSMSAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSMSAttributesError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetSMSAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSMSAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetSMSAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSMSAttributesError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "Throttled" => {
-                        return RusotoError::Service(SetSMSAttributesError::Throttled(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSMSAttributesError::Throttled(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4368,33 +4494,38 @@ impl SetSubscriptionAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetSubscriptionAttributesError::AuthorizationError(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetSubscriptionAttributesError::FilterPolicyLimitExceeded(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetSubscriptionAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubscriptionAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SetSubscriptionAttributesError::InvalidParameter(parsed_error.message),
                         )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetSubscriptionAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetSubscriptionAttributesError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4450,29 +4581,34 @@ impl SetTopicAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SetTopicAttributesError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTopicAttributesError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SetTopicAttributesError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTopicAttributesError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SetTopicAttributesError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTopicAttributesError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(SetTopicAttributesError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTopicAttributesError::InvalidSecurity(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SetTopicAttributesError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetTopicAttributesError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4530,37 +4666,46 @@ impl SubscribeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(SubscribeError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "FilterPolicyLimitExceeded" => {
-                        return RusotoError::Service(SubscribeError::FilterPolicyLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::FilterPolicyLimitExceeded(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(SubscribeError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(SubscribeError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(SubscribeError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::InvalidSecurity(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(SubscribeError::NotFound(parsed_error.message))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::NotFound(parsed_error.message),
+                        )
                     }
                     "SubscriptionLimitExceeded" => {
-                        return RusotoError::Service(SubscribeError::SubscriptionLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SubscribeError::SubscriptionLimitExceeded(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4620,39 +4765,46 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(TagResourceError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(TagResourceError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::ConcurrentAccess(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(TagResourceError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(TagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(TagResourceError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::StaleTag(parsed_error.message),
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(TagResourceError::TagLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::TagLimitExceeded(parsed_error.message),
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(TagResourceError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            TagResourceError::TagPolicy(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4708,29 +4860,34 @@ impl UnsubscribeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(UnsubscribeError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UnsubscribeError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "InternalError" => {
-                        return RusotoError::Service(UnsubscribeError::InternalError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UnsubscribeError::InternalError(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(UnsubscribeError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UnsubscribeError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "InvalidSecurity" => {
-                        return RusotoError::Service(UnsubscribeError::InvalidSecurity(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UnsubscribeError::InvalidSecurity(parsed_error.message),
+                        )
                     }
                     "NotFound" => {
-                        return RusotoError::Service(UnsubscribeError::NotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UnsubscribeError::NotFound(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -4788,39 +4945,46 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationError" => {
-                        return RusotoError::Service(UntagResourceError::AuthorizationError(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::AuthorizationError(parsed_error.message),
+                        )
                     }
                     "ConcurrentAccess" => {
-                        return RusotoError::Service(UntagResourceError::ConcurrentAccess(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::ConcurrentAccess(parsed_error.message),
+                        )
                     }
                     "InvalidParameter" => {
-                        return RusotoError::Service(UntagResourceError::InvalidParameter(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::InvalidParameter(parsed_error.message),
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(UntagResourceError::ResourceNotFound(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::ResourceNotFound(parsed_error.message),
+                        )
                     }
                     "StaleTag" => {
-                        return RusotoError::Service(UntagResourceError::StaleTag(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::StaleTag(parsed_error.message),
+                        )
                     }
                     "TagLimitExceeded" => {
-                        return RusotoError::Service(UntagResourceError::TagLimitExceeded(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::TagLimitExceeded(parsed_error.message),
+                        )
                     }
                     "TagPolicy" => {
-                        return RusotoError::Service(UntagResourceError::TagPolicy(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            UntagResourceError::TagPolicy(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -5077,6 +5241,7 @@ impl SnsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5092,6 +5257,7 @@ impl SnsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SnsClient {
         SnsClient { client, region }
     }
@@ -5105,6 +5271,9 @@ impl Sns for SnsClient {
         input: AddPermissionInput,
     ) -> Result<(), RusotoError<AddPermissionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("AddPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddPermission");
         let mut params = params;
         AddPermissionInputSerializer::serialize(&mut params, "", &input);
@@ -5126,6 +5295,9 @@ impl Sns for SnsClient {
     ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("CheckIfPhoneNumberIsOptedOut");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CheckIfPhoneNumberIsOptedOut");
         let mut params = params;
         CheckIfPhoneNumberIsOptedOutInputSerializer::serialize(&mut params, "", &input);
@@ -5159,6 +5331,9 @@ impl Sns for SnsClient {
         input: ConfirmSubscriptionInput,
     ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ConfirmSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ConfirmSubscription");
         let mut params = params;
         ConfirmSubscriptionInputSerializer::serialize(&mut params, "", &input);
@@ -5193,6 +5368,9 @@ impl Sns for SnsClient {
     ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("CreatePlatformApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreatePlatformApplication");
         let mut params = params;
         CreatePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
@@ -5226,6 +5404,9 @@ impl Sns for SnsClient {
         input: CreatePlatformEndpointInput,
     ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("CreatePlatformEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreatePlatformEndpoint");
         let mut params = params;
         CreatePlatformEndpointInputSerializer::serialize(&mut params, "", &input);
@@ -5259,6 +5440,9 @@ impl Sns for SnsClient {
         input: CreateTopicInput,
     ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("CreateTopic");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateTopic");
         let mut params = params;
         CreateTopicInputSerializer::serialize(&mut params, "", &input);
@@ -5289,6 +5473,9 @@ impl Sns for SnsClient {
         input: DeleteEndpointInput,
     ) -> Result<(), RusotoError<DeleteEndpointError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("DeleteEndpoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteEndpoint");
         let mut params = params;
         DeleteEndpointInputSerializer::serialize(&mut params, "", &input);
@@ -5309,6 +5496,9 @@ impl Sns for SnsClient {
         input: DeletePlatformApplicationInput,
     ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("DeletePlatformApplication");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeletePlatformApplication");
         let mut params = params;
         DeletePlatformApplicationInputSerializer::serialize(&mut params, "", &input);
@@ -5329,6 +5519,9 @@ impl Sns for SnsClient {
         input: DeleteTopicInput,
     ) -> Result<(), RusotoError<DeleteTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("DeleteTopic");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteTopic");
         let mut params = params;
         DeleteTopicInputSerializer::serialize(&mut params, "", &input);
@@ -5349,6 +5542,9 @@ impl Sns for SnsClient {
         input: GetEndpointAttributesInput,
     ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("GetEndpointAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetEndpointAttributes");
         let mut params = params;
         GetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5385,6 +5581,9 @@ impl Sns for SnsClient {
         RusotoError<GetPlatformApplicationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("GetPlatformApplicationAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetPlatformApplicationAttributes");
         let mut params = params;
         GetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5421,6 +5620,9 @@ impl Sns for SnsClient {
         input: GetSMSAttributesInput,
     ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("GetSMSAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetSMSAttributes");
         let mut params = params;
         GetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5453,6 +5655,9 @@ impl Sns for SnsClient {
     ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("GetSubscriptionAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetSubscriptionAttributes");
         let mut params = params;
         GetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5486,6 +5691,9 @@ impl Sns for SnsClient {
         input: GetTopicAttributesInput,
     ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("GetTopicAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetTopicAttributes");
         let mut params = params;
         GetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5522,6 +5730,9 @@ impl Sns for SnsClient {
         RusotoError<ListEndpointsByPlatformApplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListEndpointsByPlatformApplication");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListEndpointsByPlatformApplication");
         let mut params = params;
         ListEndpointsByPlatformApplicationInputSerializer::serialize(&mut params, "", &input);
@@ -5558,6 +5769,9 @@ impl Sns for SnsClient {
         input: ListPhoneNumbersOptedOutInput,
     ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListPhoneNumbersOptedOut");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPhoneNumbersOptedOut");
         let mut params = params;
         ListPhoneNumbersOptedOutInputSerializer::serialize(&mut params, "", &input);
@@ -5591,6 +5805,9 @@ impl Sns for SnsClient {
         input: ListPlatformApplicationsInput,
     ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListPlatformApplications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListPlatformApplications");
         let mut params = params;
         ListPlatformApplicationsInputSerializer::serialize(&mut params, "", &input);
@@ -5624,6 +5841,9 @@ impl Sns for SnsClient {
         input: ListSubscriptionsInput,
     ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListSubscriptions");
         let mut params = params;
         ListSubscriptionsInputSerializer::serialize(&mut params, "", &input);
@@ -5657,6 +5877,9 @@ impl Sns for SnsClient {
         input: ListSubscriptionsByTopicInput,
     ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListSubscriptionsByTopic");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListSubscriptionsByTopic");
         let mut params = params;
         ListSubscriptionsByTopicInputSerializer::serialize(&mut params, "", &input);
@@ -5690,6 +5913,9 @@ impl Sns for SnsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceRequestSerializer::serialize(&mut params, "", &input);
@@ -5723,6 +5949,9 @@ impl Sns for SnsClient {
         input: ListTopicsInput,
     ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("ListTopics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListTopics");
         let mut params = params;
         ListTopicsInputSerializer::serialize(&mut params, "", &input);
@@ -5753,6 +5982,9 @@ impl Sns for SnsClient {
         input: OptInPhoneNumberInput,
     ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("OptInPhoneNumber");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("OptInPhoneNumber");
         let mut params = params;
         OptInPhoneNumberInputSerializer::serialize(&mut params, "", &input);
@@ -5775,6 +6007,9 @@ impl Sns for SnsClient {
         input: PublishInput,
     ) -> Result<PublishResponse, RusotoError<PublishError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("Publish");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("Publish");
         let mut params = params;
         PublishInputSerializer::serialize(&mut params, "", &input);
@@ -5805,6 +6040,9 @@ impl Sns for SnsClient {
         input: RemovePermissionInput,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("RemovePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemovePermission");
         let mut params = params;
         RemovePermissionInputSerializer::serialize(&mut params, "", &input);
@@ -5825,6 +6063,9 @@ impl Sns for SnsClient {
         input: SetEndpointAttributesInput,
     ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("SetEndpointAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetEndpointAttributes");
         let mut params = params;
         SetEndpointAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5845,6 +6086,9 @@ impl Sns for SnsClient {
         input: SetPlatformApplicationAttributesInput,
     ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("SetPlatformApplicationAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetPlatformApplicationAttributes");
         let mut params = params;
         SetPlatformApplicationAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5868,6 +6112,9 @@ impl Sns for SnsClient {
         input: SetSMSAttributesInput,
     ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("SetSMSAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetSMSAttributes");
         let mut params = params;
         SetSMSAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5890,6 +6137,9 @@ impl Sns for SnsClient {
         input: SetSubscriptionAttributesInput,
     ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("SetSubscriptionAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetSubscriptionAttributes");
         let mut params = params;
         SetSubscriptionAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5910,6 +6160,9 @@ impl Sns for SnsClient {
         input: SetTopicAttributesInput,
     ) -> Result<(), RusotoError<SetTopicAttributesError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("SetTopicAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetTopicAttributes");
         let mut params = params;
         SetTopicAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -5930,6 +6183,9 @@ impl Sns for SnsClient {
         input: SubscribeInput,
     ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("Subscribe");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("Subscribe");
         let mut params = params;
         SubscribeInputSerializer::serialize(&mut params, "", &input);
@@ -5960,6 +6216,9 @@ impl Sns for SnsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TagResource");
         let mut params = params;
         TagResourceRequestSerializer::serialize(&mut params, "", &input);
@@ -5982,6 +6241,9 @@ impl Sns for SnsClient {
         input: UnsubscribeInput,
     ) -> Result<(), RusotoError<UnsubscribeError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("Unsubscribe");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("Unsubscribe");
         let mut params = params;
         UnsubscribeInputSerializer::serialize(&mut params, "", &input);
@@ -6002,6 +6264,9 @@ impl Sns for SnsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "sns", &self.region, "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UntagResource");
         let mut params = params;
         UntagResourceRequestSerializer::serialize(&mut params, "", &input);
@@ -6019,6 +6284,414 @@ impl Sns for SnsClient {
     }
 }
 
+/// A blocking client for the Amazon SNS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SnsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SnsBlockingClient {
+    client: SnsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SnsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SnsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SnsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SnsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SnsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SnsBlockingClient {
+        SnsBlockingClient {
+            client: SnsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds a statement to a topic's access control policy, granting access for the specified AWS accounts to the specified actions.</p>
+    ///
+    /// See `Sns::add_permission`.
+    pub fn add_permission(
+        &self,
+        input: AddPermissionInput,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        self.runtime.block_on(self.client.add_permission(input))
+    }
+
+    /// <p>Accepts a phone number and indicates whether the phone holder has opted out of receiving SMS messages from your account. You cannot send SMS messages to a number that is opted out.</p> <p>To resume sending messages, you can opt in the number by using the <code>OptInPhoneNumber</code> action.</p>
+    ///
+    /// See `Sns::check_if_phone_number_is_opted_out`.
+    pub fn check_if_phone_number_is_opted_out(
+        &self,
+        input: CheckIfPhoneNumberIsOptedOutInput,
+    ) -> Result<CheckIfPhoneNumberIsOptedOutResponse, RusotoError<CheckIfPhoneNumberIsOptedOutError>>
+    {
+        self.runtime
+            .block_on(self.client.check_if_phone_number_is_opted_out(input))
+    }
+
+    /// <p>Verifies an endpoint owner's intent to receive messages by validating the token sent to the endpoint by an earlier <code>Subscribe</code> action. If the token is valid, the action creates a new subscription and returns its Amazon Resource Name (ARN). This call requires an AWS signature only when the <code>AuthenticateOnUnsubscribe</code> flag is set to "true".</p>
+    ///
+    /// See `Sns::confirm_subscription`.
+    pub fn confirm_subscription(
+        &self,
+        input: ConfirmSubscriptionInput,
+    ) -> Result<ConfirmSubscriptionResponse, RusotoError<ConfirmSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.confirm_subscription(input))
+    }
+
+    /// <p>Creates a platform application object for one of the supported push notification services, such as APNS and GCM (Firebase Cloud Messaging), to which devices and mobile apps may register. You must specify <code>PlatformPrincipal</code> and <code>PlatformCredential</code> attributes when using the <code>CreatePlatformApplication</code> action.</p> <p> <code>PlatformPrincipal</code> and <code>PlatformCredential</code> are received from the notification service.</p> <ul> <li> <p>For <code>ADM</code>, <code>PlatformPrincipal</code> is <code>client id</code> and <code>PlatformCredential</code> is <code>client secret</code>.</p> </li> <li> <p>For <code>Baidu</code>, <code>PlatformPrincipal</code> is <code>API key</code> and <code>PlatformCredential</code> is <code>secret key</code>.</p> </li> <li> <p>For <code>APNS</code> and <code>APNS_SANDBOX</code>, <code>PlatformPrincipal</code> is <code>SSL certificate</code> and <code>PlatformCredential</code> is <code>private key</code>.</p> </li> <li> <p>For <code>GCM</code> (Firebase Cloud Messaging), there is no <code>PlatformPrincipal</code> and the <code>PlatformCredential</code> is <code>API key</code>.</p> </li> <li> <p>For <code>MPNS</code>, <code>PlatformPrincipal</code> is <code>TLS certificate</code> and <code>PlatformCredential</code> is <code>private key</code>.</p> </li> <li> <p>For <code>WNS</code>, <code>PlatformPrincipal</code> is <code>Package Security Identifier</code> and <code>PlatformCredential</code> is <code>secret key</code>.</p> </li> </ul> <p>You can use the returned <code>PlatformApplicationArn</code> as an attribute for the <code>CreatePlatformEndpoint</code> action.</p>
+    ///
+    /// See `Sns::create_platform_application`.
+    pub fn create_platform_application(
+        &self,
+        input: CreatePlatformApplicationInput,
+    ) -> Result<CreatePlatformApplicationResponse, RusotoError<CreatePlatformApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_platform_application(input))
+    }
+
+    /// <p>Creates an endpoint for a device and mobile app on one of the supported push notification services, such as GCM (Firebase Cloud Messaging) and APNS. <code>CreatePlatformEndpoint</code> requires the <code>PlatformApplicationArn</code> that is returned from <code>CreatePlatformApplication</code>. You can use the returned <code>EndpointArn</code> to send a message to a mobile app or by the <code>Subscribe</code> action for subscription to a topic. The <code>CreatePlatformEndpoint</code> action is idempotent, so if the requester already owns an endpoint with the same device token and attributes, that endpoint's ARN is returned without creating a new endpoint. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When using <code>CreatePlatformEndpoint</code> with Baidu, two attributes must be provided: ChannelId and UserId. The token field must also contain the ChannelId. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePushBaiduEndpoint.html">Creating an Amazon SNS Endpoint for Baidu</a>. </p>
+    ///
+    /// See `Sns::create_platform_endpoint`.
+    pub fn create_platform_endpoint(
+        &self,
+        input: CreatePlatformEndpointInput,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreatePlatformEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_platform_endpoint(input))
+    }
+
+    /// <p>Creates a topic to which notifications can be published. Users can create at most 100,000 standard topics (at most 1,000 FIFO topics). For more information, see <a href="http://aws.amazon.com/sns/">https://aws.amazon.com/sns</a>. This action is idempotent, so if the requester already owns a topic with the specified name, that topic's ARN is returned without creating a new topic.</p>
+    ///
+    /// See `Sns::create_topic`.
+    pub fn create_topic(
+        &self,
+        input: CreateTopicInput,
+    ) -> Result<CreateTopicResponse, RusotoError<CreateTopicError>> {
+        self.runtime.block_on(self.client.create_topic(input))
+    }
+
+    /// <p>Deletes the endpoint for a device and mobile app from Amazon SNS. This action is idempotent. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>When you delete an endpoint that is also subscribed to a topic, then you must also unsubscribe the endpoint from the topic.</p>
+    ///
+    /// See `Sns::delete_endpoint`.
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// <p>Deletes a platform application object for one of the supported push notification services, such as APNS and GCM (Firebase Cloud Messaging). For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    ///
+    /// See `Sns::delete_platform_application`.
+    pub fn delete_platform_application(
+        &self,
+        input: DeletePlatformApplicationInput,
+    ) -> Result<(), RusotoError<DeletePlatformApplicationError>> {
+        self.runtime
+            .block_on(self.client.delete_platform_application(input))
+    }
+
+    /// <p>Deletes a topic and all its subscriptions. Deleting a topic might prevent some messages previously sent to the topic from being delivered to subscribers. This action is idempotent, so deleting a topic that does not exist does not result in an error.</p>
+    ///
+    /// See `Sns::delete_topic`.
+    pub fn delete_topic(
+        &self,
+        input: DeleteTopicInput,
+    ) -> Result<(), RusotoError<DeleteTopicError>> {
+        self.runtime.block_on(self.client.delete_topic(input))
+    }
+
+    /// <p>Retrieves the endpoint attributes for a device on one of the supported push notification services, such as GCM (Firebase Cloud Messaging) and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    ///
+    /// See `Sns::get_endpoint_attributes`.
+    pub fn get_endpoint_attributes(
+        &self,
+        input: GetEndpointAttributesInput,
+    ) -> Result<GetEndpointAttributesResponse, RusotoError<GetEndpointAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_endpoint_attributes(input))
+    }
+
+    /// <p>Retrieves the attributes of the platform application object for the supported push notification services, such as APNS and GCM (Firebase Cloud Messaging). For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    ///
+    /// See `Sns::get_platform_application_attributes`.
+    pub fn get_platform_application_attributes(
+        &self,
+        input: GetPlatformApplicationAttributesInput,
+    ) -> Result<
+        GetPlatformApplicationAttributesResponse,
+        RusotoError<GetPlatformApplicationAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_platform_application_attributes(input))
+    }
+
+    /// <p>Returns the settings for sending SMS messages from your account.</p> <p>These settings are set with the <code>SetSMSAttributes</code> action.</p>
+    ///
+    /// See `Sns::get_sms_attributes`.
+    pub fn get_sms_attributes(
+        &self,
+        input: GetSMSAttributesInput,
+    ) -> Result<GetSMSAttributesResponse, RusotoError<GetSMSAttributesError>> {
+        self.runtime.block_on(self.client.get_sms_attributes(input))
+    }
+
+    /// <p>Returns all of the properties of a subscription.</p>
+    ///
+    /// See `Sns::get_subscription_attributes`.
+    pub fn get_subscription_attributes(
+        &self,
+        input: GetSubscriptionAttributesInput,
+    ) -> Result<GetSubscriptionAttributesResponse, RusotoError<GetSubscriptionAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_subscription_attributes(input))
+    }
+
+    /// <p>Returns all of the properties of a topic. Topic properties returned might differ based on the authorization of the user.</p>
+    ///
+    /// See `Sns::get_topic_attributes`.
+    pub fn get_topic_attributes(
+        &self,
+        input: GetTopicAttributesInput,
+    ) -> Result<GetTopicAttributesResponse, RusotoError<GetTopicAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_topic_attributes(input))
+    }
+
+    /// <p>Lists the endpoints and endpoint attributes for devices in a supported push notification service, such as GCM (Firebase Cloud Messaging) and APNS. The results for <code>ListEndpointsByPlatformApplication</code> are paginated and return a limited list of endpoints, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListEndpointsByPlatformApplication</code> again using the NextToken string received from the previous call. When there are no more records to return, NextToken will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::list_endpoints_by_platform_application`.
+    pub fn list_endpoints_by_platform_application(
+        &self,
+        input: ListEndpointsByPlatformApplicationInput,
+    ) -> Result<
+        ListEndpointsByPlatformApplicationResponse,
+        RusotoError<ListEndpointsByPlatformApplicationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_endpoints_by_platform_application(input))
+    }
+
+    /// <p>Returns a list of phone numbers that are opted out, meaning you cannot send SMS messages to them.</p> <p>The results for <code>ListPhoneNumbersOptedOut</code> are paginated, and each page returns up to 100 phone numbers. If additional phone numbers are available after the first page of results, then a <code>NextToken</code> string will be returned. To receive the next page, you call <code>ListPhoneNumbersOptedOut</code> again using the <code>NextToken</code> string received from the previous call. When there are no more records to return, <code>NextToken</code> will be null.</p>
+    ///
+    /// See `Sns::list_phone_numbers_opted_out`.
+    pub fn list_phone_numbers_opted_out(
+        &self,
+        input: ListPhoneNumbersOptedOutInput,
+    ) -> Result<ListPhoneNumbersOptedOutResponse, RusotoError<ListPhoneNumbersOptedOutError>> {
+        self.runtime
+            .block_on(self.client.list_phone_numbers_opted_out(input))
+    }
+
+    /// <p>Lists the platform application objects for the supported push notification services, such as APNS and GCM (Firebase Cloud Messaging). The results for <code>ListPlatformApplications</code> are paginated and return a limited list of applications, up to 100. If additional records are available after the first page results, then a NextToken string will be returned. To receive the next page, you call <code>ListPlatformApplications</code> using the NextToken string received from the previous call. When there are no more records to return, <code>NextToken</code> will be null. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p> <p>This action is throttled at 15 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::list_platform_applications`.
+    pub fn list_platform_applications(
+        &self,
+        input: ListPlatformApplicationsInput,
+    ) -> Result<ListPlatformApplicationsResponse, RusotoError<ListPlatformApplicationsError>> {
+        self.runtime
+            .block_on(self.client.list_platform_applications(input))
+    }
+
+    /// <p>Returns a list of the requester's subscriptions. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptions</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::list_subscriptions`.
+    pub fn list_subscriptions(
+        &self,
+        input: ListSubscriptionsInput,
+    ) -> Result<ListSubscriptionsResponse, RusotoError<ListSubscriptionsError>> {
+        self.runtime.block_on(self.client.list_subscriptions(input))
+    }
+
+    /// <p>Returns a list of the subscriptions to a specific topic. Each call returns a limited list of subscriptions, up to 100. If there are more subscriptions, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListSubscriptionsByTopic</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::list_subscriptions_by_topic`.
+    pub fn list_subscriptions_by_topic(
+        &self,
+        input: ListSubscriptionsByTopicInput,
+    ) -> Result<ListSubscriptionsByTopicResponse, RusotoError<ListSubscriptionsByTopicError>> {
+        self.runtime
+            .block_on(self.client.list_subscriptions_by_topic(input))
+    }
+
+    /// <p>List all tags added to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon Simple Notification Service Developer Guide</i>.</p>
+    ///
+    /// See `Sns::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns a list of the requester's topics. Each call returns a limited list of topics, up to 100. If there are more topics, a <code>NextToken</code> is also returned. Use the <code>NextToken</code> parameter in a new <code>ListTopics</code> call to get further results.</p> <p>This action is throttled at 30 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::list_topics`.
+    pub fn list_topics(
+        &self,
+        input: ListTopicsInput,
+    ) -> Result<ListTopicsResponse, RusotoError<ListTopicsError>> {
+        self.runtime.block_on(self.client.list_topics(input))
+    }
+
+    /// <p>Use this request to opt in a phone number that is opted out, which enables you to resume sending SMS messages to the number.</p> <p>You can opt in a phone number only once every 30 days.</p>
+    ///
+    /// See `Sns::opt_in_phone_number`.
+    pub fn opt_in_phone_number(
+        &self,
+        input: OptInPhoneNumberInput,
+    ) -> Result<OptInPhoneNumberResponse, RusotoError<OptInPhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.opt_in_phone_number(input))
+    }
+
+    /// <p><p>Sends a message to an Amazon SNS topic, a text message (SMS message) directly to a phone number, or a message to a mobile platform endpoint (when you specify the <code>TargetArn</code>).</p> <p>If you send a message to a topic, Amazon SNS delivers the message to each endpoint that is subscribed to the topic. The format of the message depends on the notification protocol for each subscribed endpoint.</p> <p>When a <code>messageId</code> is returned, the message has been saved and Amazon SNS will attempt to deliver it shortly.</p> <p>To use the <code>Publish</code> action for sending a message to a mobile endpoint, such as an app on a Kindle device or mobile phone, you must specify the EndpointArn for the TargetArn parameter. The EndpointArn is returned when making a call with the <code>CreatePlatformEndpoint</code> action. </p> <p>For more information about formatting messages, see <a href="https://docs.aws.amazon.com/sns/latest/dg/mobile-push-send-custommessage.html">Send Custom Platform-Specific Payloads in Messages to Mobile Devices</a>. </p> <important> <p>You can publish messages only to topics and endpoints in the same AWS Region.</p> </important></p>
+    ///
+    /// See `Sns::publish`.
+    pub fn publish(
+        &self,
+        input: PublishInput,
+    ) -> Result<PublishResponse, RusotoError<PublishError>> {
+        self.runtime.block_on(self.client.publish(input))
+    }
+
+    /// <p>Removes a statement from a topic's access control policy.</p>
+    ///
+    /// See `Sns::remove_permission`.
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionInput,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// <p>Sets the attributes for an endpoint for a device on one of the supported push notification services, such as GCM (Firebase Cloud Messaging) and APNS. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. </p>
+    ///
+    /// See `Sns::set_endpoint_attributes`.
+    pub fn set_endpoint_attributes(
+        &self,
+        input: SetEndpointAttributesInput,
+    ) -> Result<(), RusotoError<SetEndpointAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_endpoint_attributes(input))
+    }
+
+    /// <p>Sets the attributes of the platform application object for the supported push notification services, such as APNS and GCM (Firebase Cloud Messaging). For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/SNSMobilePush.html">Using Amazon SNS Mobile Push Notifications</a>. For information on configuring attributes for message delivery status, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-msg-status.html">Using Amazon SNS Application Attributes for Message Delivery Status</a>. </p>
+    ///
+    /// See `Sns::set_platform_application_attributes`.
+    pub fn set_platform_application_attributes(
+        &self,
+        input: SetPlatformApplicationAttributesInput,
+    ) -> Result<(), RusotoError<SetPlatformApplicationAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_platform_application_attributes(input))
+    }
+
+    /// <p>Use this request to set the default settings for sending SMS messages and receiving daily SMS usage reports.</p> <p>You can override some of these settings for a single message when you use the <code>Publish</code> action with the <code>MessageAttributes.entry.N</code> parameter. For more information, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sms_publish-to-phone.html">Sending an SMS Message</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    ///
+    /// See `Sns::set_sms_attributes`.
+    pub fn set_sms_attributes(
+        &self,
+        input: SetSMSAttributesInput,
+    ) -> Result<SetSMSAttributesResponse, RusotoError<SetSMSAttributesError>> {
+        self.runtime.block_on(self.client.set_sms_attributes(input))
+    }
+
+    /// <p>Allows a subscription owner to set an attribute of the subscription to a new value.</p>
+    ///
+    /// See `Sns::set_subscription_attributes`.
+    pub fn set_subscription_attributes(
+        &self,
+        input: SetSubscriptionAttributesInput,
+    ) -> Result<(), RusotoError<SetSubscriptionAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_subscription_attributes(input))
+    }
+
+    /// <p>Allows a topic owner to set an attribute of the topic to a new value.</p>
+    ///
+    /// See `Sns::set_topic_attributes`.
+    pub fn set_topic_attributes(
+        &self,
+        input: SetTopicAttributesInput,
+    ) -> Result<(), RusotoError<SetTopicAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_topic_attributes(input))
+    }
+
+    /// <p>Subscribes an endpoint to an Amazon SNS topic. If the endpoint type is HTTP/S or email, or if the endpoint and the topic are not in the same AWS account, the endpoint owner must the <code>ConfirmSubscription</code> action to confirm the subscription.</p> <p>You call the <code>ConfirmSubscription</code> action with the token from the subscription response. Confirmation tokens are valid for three days.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::subscribe`.
+    pub fn subscribe(
+        &self,
+        input: SubscribeInput,
+    ) -> Result<SubscribeResponse, RusotoError<SubscribeError>> {
+        self.runtime.block_on(self.client.subscribe(input))
+    }
+
+    /// <p><p>Add tags to the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p> <p>When you use topic tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a topic isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SNS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> <li> <p>Tagging actions are limited to 10 TPS per AWS account, per AWS region. If your application requires a higher throughput, file a <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=technical">technical support request</a>.</p> </li> </ul></p>
+    ///
+    /// See `Sns::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Deletes a subscription. If the subscription requires authentication for deletion, only the owner of the subscription or the topic's owner can unsubscribe, and an AWS signature is required. If the <code>Unsubscribe</code> call does not require authentication and the requester is not the subscription owner, a final cancellation message is delivered to the endpoint, so that the endpoint owner can easily resubscribe to the topic if the <code>Unsubscribe</code> request was unintended.</p> <p>This action is throttled at 100 transactions per second (TPS).</p>
+    ///
+    /// See `Sns::unsubscribe`.
+    pub fn unsubscribe(
+        &self,
+        input: UnsubscribeInput,
+    ) -> Result<(), RusotoError<UnsubscribeError>> {
+        self.runtime.block_on(self.client.unsubscribe(input))
+    }
+
+    /// <p>Remove tags from the specified Amazon SNS topic. For an overview, see <a href="https://docs.aws.amazon.com/sns/latest/dg/sns-tags.html">Amazon SNS Tags</a> in the <i>Amazon SNS Developer Guide</i>.</p>
+    ///
+    /// See `Sns::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
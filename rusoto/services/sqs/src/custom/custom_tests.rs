@@ -173,9 +173,20 @@ async fn test_parse_queue_does_not_exist_error() {
     assert!(result.is_err());
     let err = result.err().unwrap();
     assert_eq!(
-        RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-            "The specified queue does not exist for this wsdl version.".to_owned()
-        )),
+        err.code().as_deref(),
+        Some("AWS.SimpleQueueService.NonExistentQueue")
+    );
+    assert_eq!(
+        err.request_id(),
+        Some("8f8f9957-c0d9-536a-9ca6-ca7483be06ad")
+    );
+    assert_eq!(
+        RusotoError::Service(
+            GetQueueUrlError::QueueDoesNotExist(
+                "The specified queue does not exist for this wsdl version.".to_owned()
+            ),
+            err.metadata().unwrap().into_owned()
+        ),
         err
     );
 }
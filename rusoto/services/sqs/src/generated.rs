@@ -2155,9 +2155,10 @@ impl AddPermissionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
-                        return RusotoError::Service(AddPermissionError::OverLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            AddPermissionError::OverLimit(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2201,12 +2202,14 @@ impl ChangeMessageVisibilityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.MessageNotInflight" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeMessageVisibilityError::MessageNotInflight(parsed_error.message),
                         )
                     }
                     "ReceiptHandleIsInvalid" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeMessageVisibilityError::ReceiptHandleIsInvalid(
                                 parsed_error.message,
                             ),
@@ -2263,28 +2266,32 @@ impl ChangeMessageVisibilityBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeMessageVisibilityBatchError::BatchEntryIdsNotDistinct(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeMessageVisibilityBatchError::EmptyBatchRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeMessageVisibilityBatchError::InvalidBatchEntryId(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ChangeMessageVisibilityBatchError::TooManyEntriesInBatchRequest(
                                 parsed_error.message,
                             ),
@@ -2343,14 +2350,16 @@ impl CreateQueueError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.QueueDeletedRecently" => {
-                        return RusotoError::Service(CreateQueueError::QueueDeletedRecently(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateQueueError::QueueDeletedRecently(parsed_error.message),
+                        )
                     }
                     "QueueAlreadyExists" => {
-                        return RusotoError::Service(CreateQueueError::QueueNameExists(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            CreateQueueError::QueueNameExists(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2395,14 +2404,16 @@ impl DeleteMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidIdFormat" => {
-                        return RusotoError::Service(DeleteMessageError::InvalidIdFormat(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteMessageError::InvalidIdFormat(parsed_error.message),
+                        )
                     }
                     "ReceiptHandleIsInvalid" => {
-                        return RusotoError::Service(DeleteMessageError::ReceiptHandleIsInvalid(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteMessageError::ReceiptHandleIsInvalid(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2451,22 +2462,26 @@ impl DeleteMessageBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
                         )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(DeleteMessageBatchError::EmptyBatchRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteMessageBatchError::EmptyBatchRequest(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(DeleteMessageBatchError::InvalidBatchEntryId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            DeleteMessageBatchError::InvalidBatchEntryId(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             DeleteMessageBatchError::TooManyEntriesInBatchRequest(
                                 parsed_error.message,
                             ),
@@ -2551,9 +2566,10 @@ impl GetQueueAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
-                        return RusotoError::Service(GetQueueAttributesError::InvalidAttributeName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetQueueAttributesError::InvalidAttributeName(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2595,9 +2611,10 @@ impl GetQueueUrlError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(GetQueueUrlError::QueueDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            GetQueueUrlError::QueueDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2641,7 +2658,8 @@ impl ListDeadLetterSourceQueuesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             ListDeadLetterSourceQueuesError::QueueDoesNotExist(
                                 parsed_error.message,
                             ),
@@ -2757,14 +2775,16 @@ impl PurgeQueueError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.PurgeQueueInProgress" => {
-                        return RusotoError::Service(PurgeQueueError::PurgeQueueInProgress(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PurgeQueueError::PurgeQueueInProgress(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.NonExistentQueue" => {
-                        return RusotoError::Service(PurgeQueueError::QueueDoesNotExist(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            PurgeQueueError::QueueDoesNotExist(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2807,9 +2827,10 @@ impl ReceiveMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OverLimit" => {
-                        return RusotoError::Service(ReceiveMessageError::OverLimit(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            ReceiveMessageError::OverLimit(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2887,14 +2908,16 @@ impl SendMessageError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidMessageContents" => {
-                        return RusotoError::Service(SendMessageError::InvalidMessageContents(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendMessageError::InvalidMessageContents(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
-                        return RusotoError::Service(SendMessageError::UnsupportedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendMessageError::UnsupportedOperation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -2947,36 +2970,42 @@ impl SendMessageBatchError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AWS.SimpleQueueService.BatchEntryIdsNotDistinct" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendMessageBatchError::BatchEntryIdsNotDistinct(parsed_error.message),
                         )
                     }
                     "AWS.SimpleQueueService.BatchRequestTooLong" => {
-                        return RusotoError::Service(SendMessageBatchError::BatchRequestTooLong(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendMessageBatchError::BatchRequestTooLong(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.EmptyBatchRequest" => {
-                        return RusotoError::Service(SendMessageBatchError::EmptyBatchRequest(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendMessageBatchError::EmptyBatchRequest(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.InvalidBatchEntryId" => {
-                        return RusotoError::Service(SendMessageBatchError::InvalidBatchEntryId(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendMessageBatchError::InvalidBatchEntryId(parsed_error.message),
+                        )
                     }
                     "AWS.SimpleQueueService.TooManyEntriesInBatchRequest" => {
-                        return RusotoError::Service(
+                        return RusotoError::service(
+                            &res,
                             SendMessageBatchError::TooManyEntriesInBatchRequest(
                                 parsed_error.message,
                             ),
                         )
                     }
                     "AWS.SimpleQueueService.UnsupportedOperation" => {
-                        return RusotoError::Service(SendMessageBatchError::UnsupportedOperation(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SendMessageBatchError::UnsupportedOperation(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3025,9 +3054,10 @@ impl SetQueueAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAttributeName" => {
-                        return RusotoError::Service(SetQueueAttributesError::InvalidAttributeName(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service(
+                            &res,
+                            SetQueueAttributesError::InvalidAttributeName(parsed_error.message),
+                        )
                     }
                     _ => {}
                 }
@@ -3259,6 +3289,7 @@ impl SqsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3274,6 +3305,7 @@ impl SqsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SqsClient {
         SqsClient { client, region }
     }
@@ -3287,6 +3319,9 @@ impl Sqs for SqsClient {
         input: AddPermissionRequest,
     ) -> Result<(), RusotoError<AddPermissionError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("AddPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("AddPermission");
         let mut params = params;
         AddPermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -3307,6 +3342,9 @@ impl Sqs for SqsClient {
         input: ChangeMessageVisibilityRequest,
     ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("ChangeMessageVisibility");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ChangeMessageVisibility");
         let mut params = params;
         ChangeMessageVisibilityRequestSerializer::serialize(&mut params, "", &input);
@@ -3328,6 +3366,9 @@ impl Sqs for SqsClient {
     ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
     {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("ChangeMessageVisibilityBatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ChangeMessageVisibilityBatch");
         let mut params = params;
         ChangeMessageVisibilityBatchRequestSerializer::serialize(&mut params, "", &input);
@@ -3361,6 +3402,9 @@ impl Sqs for SqsClient {
         input: CreateQueueRequest,
     ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("CreateQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("CreateQueue");
         let mut params = params;
         CreateQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3391,6 +3435,9 @@ impl Sqs for SqsClient {
         input: DeleteMessageRequest,
     ) -> Result<(), RusotoError<DeleteMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("DeleteMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteMessage");
         let mut params = params;
         DeleteMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -3411,6 +3458,9 @@ impl Sqs for SqsClient {
         input: DeleteMessageBatchRequest,
     ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("DeleteMessageBatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteMessageBatch");
         let mut params = params;
         DeleteMessageBatchRequestSerializer::serialize(&mut params, "", &input);
@@ -3444,6 +3494,9 @@ impl Sqs for SqsClient {
         input: DeleteQueueRequest,
     ) -> Result<(), RusotoError<DeleteQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("DeleteQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("DeleteQueue");
         let mut params = params;
         DeleteQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3464,6 +3517,9 @@ impl Sqs for SqsClient {
         input: GetQueueAttributesRequest,
     ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("GetQueueAttributes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetQueueAttributes");
         let mut params = params;
         GetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -3497,6 +3553,9 @@ impl Sqs for SqsClient {
         input: GetQueueUrlRequest,
     ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("GetQueueUrl");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("GetQueueUrl");
         let mut params = params;
         GetQueueUrlRequestSerializer::serialize(&mut params, "", &input);
@@ -3528,6 +3587,9 @@ impl Sqs for SqsClient {
     ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
     {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("ListDeadLetterSourceQueues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListDeadLetterSourceQueues");
         let mut params = params;
         ListDeadLetterSourceQueuesRequestSerializer::serialize(&mut params, "", &input);
@@ -3561,6 +3623,9 @@ impl Sqs for SqsClient {
         input: ListQueueTagsRequest,
     ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("ListQueueTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListQueueTags");
         let mut params = params;
         ListQueueTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -3592,6 +3657,9 @@ impl Sqs for SqsClient {
         input: ListQueuesRequest,
     ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("ListQueues");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         let params = self.new_params("ListQueues");
         let mut params = params;
         ListQueuesRequestSerializer::serialize(&mut params, "", &input);
@@ -3622,6 +3690,9 @@ impl Sqs for SqsClient {
         input: PurgeQueueRequest,
     ) -> Result<(), RusotoError<PurgeQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("PurgeQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("PurgeQueue");
         let mut params = params;
         PurgeQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3642,6 +3713,9 @@ impl Sqs for SqsClient {
         input: ReceiveMessageRequest,
     ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("ReceiveMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("ReceiveMessage");
         let mut params = params;
         ReceiveMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -3673,6 +3747,9 @@ impl Sqs for SqsClient {
         input: RemovePermissionRequest,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("RemovePermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("RemovePermission");
         let mut params = params;
         RemovePermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -3693,6 +3770,9 @@ impl Sqs for SqsClient {
         input: SendMessageRequest,
     ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("SendMessage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendMessage");
         let mut params = params;
         SendMessageRequestSerializer::serialize(&mut params, "", &input);
@@ -3723,6 +3803,9 @@ impl Sqs for SqsClient {
         input: SendMessageBatchRequest,
     ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("SendMessageBatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SendMessageBatch");
         let mut params = params;
         SendMessageBatchRequestSerializer::serialize(&mut params, "", &input);
@@ -3754,6 +3837,9 @@ impl Sqs for SqsClient {
         input: SetQueueAttributesRequest,
     ) -> Result<(), RusotoError<SetQueueAttributesError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("SetQueueAttributes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("SetQueueAttributes");
         let mut params = params;
         SetQueueAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -3771,6 +3857,9 @@ impl Sqs for SqsClient {
     /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
     async fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("TagQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("TagQueue");
         let mut params = params;
         TagQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3791,6 +3880,9 @@ impl Sqs for SqsClient {
         input: UntagQueueRequest,
     ) -> Result<(), RusotoError<UntagQueueError>> {
         let mut request = SignedRequest::new("POST", "sqs", &self.region, "/");
+        request.set_operation_name("UntagQueue");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         let params = self.new_params("UntagQueue");
         let mut params = params;
         UntagQueueRequestSerializer::serialize(&mut params, "", &input);
@@ -3806,6 +3898,261 @@ impl Sqs for SqsClient {
     }
 }
 
+/// A blocking client for the Amazon SQS API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SqsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SqsBlockingClient {
+    client: SqsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SqsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SqsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SqsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SqsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SqsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SqsBlockingClient {
+        SqsBlockingClient {
+            client: SqsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Adds a permission to a queue for a specific <a href="https://docs.aws.amazon.com/general/latest/gr/glos-chap.html#P">principal</a>. This allows sharing access to the queue.</p> <p>When you create a queue, you have full control access rights for the queue. Only you, the owner of the queue, can grant or deny permissions to the queue. For more information about these permissions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <ul> <li> <p> <code>AddPermission</code> generates a policy for you. You can use <code> <a>SetQueueAttributes</a> </code> to upload your policy. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-creating-custom-policies.html">Using Custom Policies with the Amazon SQS Access Policy Language</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>An Amazon SQS policy can have a maximum of 7 actions.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;AttributeName.1=first</code> </p> <p> <code>&amp;AttributeName.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::add_permission`.
+    pub fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<(), RusotoError<AddPermissionError>> {
+        self.runtime.block_on(self.client.add_permission(input))
+    }
+
+    /// <p><p>Changes the visibility timeout of a specified message in a queue to a new value. The default visibility timeout for a message is 30 seconds. The minimum is 0 seconds. The maximum is 12 hours. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>For example, you have a message with a visibility timeout of 5 minutes. After 3 minutes, you call <code>ChangeMessageVisibility</code> with a timeout of 10 minutes. You can continue to call <code>ChangeMessageVisibility</code> to extend the visibility timeout to the maximum allowed time. If you try to extend the visibility timeout beyond the maximum, your request is rejected.</p> <p>An Amazon SQS message has three basic states:</p> <ol> <li> <p>Sent to a queue by a producer.</p> </li> <li> <p>Received from the queue by a consumer.</p> </li> <li> <p>Deleted from the queue.</p> </li> </ol> <p>A message is considered to be <i>stored</i> after it is sent to a queue by a producer, but not yet received from the queue by a consumer (that is, between states 1 and 2). There is no limit to the number of stored messages. A message is considered to be <i>in flight</i> after it is received from a queue by a consumer, but not yet deleted from the queue (that is, between states 2 and 3). There is a limit to the number of inflight messages.</p> <p>Limits that apply to inflight messages are unrelated to the <i>unlimited</i> number of stored messages.</p> <p>For most standard queues (depending on queue traffic and message backlog), there can be a maximum of approximately 120,000 inflight messages (received from a queue by a consumer, but not yet deleted from the queue). If you reach this limit, Amazon SQS returns the <code>OverLimit</code> error message. To avoid reaching the limit, you should delete messages from the queue after they&#39;re processed. You can also increase the number of queues you use to process your messages. To request a limit increase, <a href="https://console.aws.amazon.com/support/home#/case/create?issueType=service-limit-increase&amp;limitType=service-code-sqs">file a support request</a>.</p> <p>For FIFO queues, there can be a maximum of 20,000 inflight messages (received from a queue by a consumer, but not yet deleted from the queue). If you reach this limit, Amazon SQS returns no error messages.</p> <important> <p>If you attempt to set the <code>VisibilityTimeout</code> to a value greater than the maximum time left, Amazon SQS returns an error. Amazon SQS doesn&#39;t automatically recalculate and increase the timeout to the maximum remaining time.</p> <p>Unlike with a queue, when you change the visibility timeout for a specific message the timeout value is applied immediately but isn&#39;t saved in memory for that message. If you don&#39;t delete a message after it is received, the visibility timeout for the message reverts to the original timeout value (not to the value you set using the <code>ChangeMessageVisibility</code> action) the next time the message is received.</p> </important></p>
+    ///
+    /// See `Sqs::change_message_visibility`.
+    pub fn change_message_visibility(
+        &self,
+        input: ChangeMessageVisibilityRequest,
+    ) -> Result<(), RusotoError<ChangeMessageVisibilityError>> {
+        self.runtime
+            .block_on(self.client.change_message_visibility(input))
+    }
+
+    /// <p>Changes the visibility timeout of multiple messages. This is a batch version of <code> <a>ChangeMessageVisibility</a>.</code> The result of the action on each message is reported individually in the response. You can send up to 10 <code> <a>ChangeMessageVisibility</a> </code> requests with each <code>ChangeMessageVisibilityBatch</code> action.</p> <important> <p>Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> </important> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;AttributeName.1=first</code> </p> <p> <code>&amp;AttributeName.2=second</code> </p>
+    ///
+    /// See `Sqs::change_message_visibility_batch`.
+    pub fn change_message_visibility_batch(
+        &self,
+        input: ChangeMessageVisibilityBatchRequest,
+    ) -> Result<ChangeMessageVisibilityBatchResult, RusotoError<ChangeMessageVisibilityBatchError>>
+    {
+        self.runtime
+            .block_on(self.client.change_message_visibility_batch(input))
+    }
+
+    /// <p><p>Creates a new standard or FIFO queue. You can pass one or more attributes in the request. Keep the following in mind:</p> <ul> <li> <p>If you don&#39;t specify the <code>FifoQueue</code> attribute, Amazon SQS creates a standard queue.</p> <note> <p>You can&#39;t change the queue type after you create it and you can&#39;t convert an existing standard queue into a FIFO queue. You must either create a new FIFO queue for your application or delete your existing standard queue and recreate it as a FIFO queue. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html#FIFO-queues-moving">Moving From a Standard Queue to a FIFO Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> </note> </li> <li> <p>If you don&#39;t provide a value for an attribute, the queue is created with the default value for the attribute.</p> </li> <li> <p>If you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> </li> </ul> <p>To successfully create a new queue, you must provide a queue name that adheres to the <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/limits-queues.html">limits related to queues</a> and is unique within the scope of your queues.</p> <note> <p>After you create a queue, you must wait at least one second after the queue is created to be able to use the queue.</p> </note> <p>To get the queue URL, use the <code> <a>GetQueueUrl</a> </code> action. <code> <a>GetQueueUrl</a> </code> requires only the <code>QueueName</code> parameter. be aware of existing queue names:</p> <ul> <li> <p>If you provide the name of an existing queue along with the exact names and values of all the queue&#39;s attributes, <code>CreateQueue</code> returns the queue URL for the existing queue.</p> </li> <li> <p>If the queue name, attribute names, or attribute values don&#39;t match an existing queue, <code>CreateQueue</code> returns an error.</p> </li> </ul> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;AttributeName.1=first</code> </p> <p> <code>&amp;AttributeName.2=second</code> </p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::create_queue`.
+    pub fn create_queue(
+        &self,
+        input: CreateQueueRequest,
+    ) -> Result<CreateQueueResult, RusotoError<CreateQueueError>> {
+        self.runtime.block_on(self.client.create_queue(input))
+    }
+
+    /// <p><p>Deletes the specified message from the specified queue. To select the message to delete, use the <code>ReceiptHandle</code> of the message (<i>not</i> the <code>MessageId</code> which you receive when you send the message). Amazon SQS can delete a message from a queue even if a visibility timeout setting causes the message to be locked by another consumer. Amazon SQS automatically deletes messages left in a queue longer than the retention period configured for the queue. </p> <note> <p>The <code>ReceiptHandle</code> is associated with a <i>specific instance</i> of receiving a message. If you receive a message more than once, the <code>ReceiptHandle</code> is different each time you receive a message. When you use the <code>DeleteMessage</code> action, you must provide the most recently received <code>ReceiptHandle</code> for the message (otherwise, the request succeeds, but the message might not be deleted).</p> <p>For standard queues, it is possible to receive a message even after you delete it. This might happen on rare occasions if one of the servers which stores a copy of the message is unavailable when you send the request to delete the message. The copy remains on the server and might be returned to you during a subsequent receive request. You should ensure that your application is idempotent, so that receiving a message more than once does not cause issues.</p> </note></p>
+    ///
+    /// See `Sqs::delete_message`.
+    pub fn delete_message(
+        &self,
+        input: DeleteMessageRequest,
+    ) -> Result<(), RusotoError<DeleteMessageError>> {
+        self.runtime.block_on(self.client.delete_message(input))
+    }
+
+    /// <p>Deletes up to ten messages from the specified queue. This is a batch version of <code> <a>DeleteMessage</a>.</code> The result of the action on each message is reported individually in the response.</p> <important> <p>Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> </important> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;AttributeName.1=first</code> </p> <p> <code>&amp;AttributeName.2=second</code> </p>
+    ///
+    /// See `Sqs::delete_message_batch`.
+    pub fn delete_message_batch(
+        &self,
+        input: DeleteMessageBatchRequest,
+    ) -> Result<DeleteMessageBatchResult, RusotoError<DeleteMessageBatchError>> {
+        self.runtime
+            .block_on(self.client.delete_message_batch(input))
+    }
+
+    /// <p><p>Deletes the queue specified by the <code>QueueUrl</code>, regardless of the queue&#39;s contents.</p> <important> <p>Be careful with the <code>DeleteQueue</code> action: When you delete a queue, any messages in the queue are no longer available. </p> </important> <p>When you delete a queue, the deletion process takes up to 60 seconds. Requests you send involving that queue during the 60 seconds might succeed. For example, a <code> <a>SendMessage</a> </code> request might succeed, but after 60 seconds the queue and the message you sent no longer exist.</p> <p>When you delete a queue, you must wait at least 60 seconds before creating a queue with the same name.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::delete_queue`.
+    pub fn delete_queue(
+        &self,
+        input: DeleteQueueRequest,
+    ) -> Result<(), RusotoError<DeleteQueueError>> {
+        self.runtime.block_on(self.client.delete_queue(input))
+    }
+
+    /// <p><p>Gets attributes for the specified queue.</p> <note> <p>To determine whether a queue is <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/FIFO-queues.html">FIFO</a>, you can check whether <code>QueueName</code> ends with the <code>.fifo</code> suffix.</p> </note></p>
+    ///
+    /// See `Sqs::get_queue_attributes`.
+    pub fn get_queue_attributes(
+        &self,
+        input: GetQueueAttributesRequest,
+    ) -> Result<GetQueueAttributesResult, RusotoError<GetQueueAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_queue_attributes(input))
+    }
+
+    /// <p>Returns the URL of an existing Amazon SQS queue.</p> <p>To access a queue that belongs to another AWS account, use the <code>QueueOwnerAWSAccountId</code> parameter to specify the account ID of the queue's owner. The queue's owner must grant you permission to access the queue. For more information about shared queue access, see <code> <a>AddPermission</a> </code> or see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-writing-an-sqs-policy.html#write-messages-to-shared-queue">Allow Developers to Write Messages to a Shared Queue</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p>
+    ///
+    /// See `Sqs::get_queue_url`.
+    pub fn get_queue_url(
+        &self,
+        input: GetQueueUrlRequest,
+    ) -> Result<GetQueueUrlResult, RusotoError<GetQueueUrlError>> {
+        self.runtime.block_on(self.client.get_queue_url(input))
+    }
+
+    /// <p>Returns a list of your queues that have the <code>RedrivePolicy</code> queue attribute configured with a dead-letter queue.</p> <p>For more information about using dead-letter queues, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-dead-letter-queues.html">Using Amazon SQS Dead-Letter Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p>
+    ///
+    /// See `Sqs::list_dead_letter_source_queues`.
+    pub fn list_dead_letter_source_queues(
+        &self,
+        input: ListDeadLetterSourceQueuesRequest,
+    ) -> Result<ListDeadLetterSourceQueuesResult, RusotoError<ListDeadLetterSourceQueuesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_dead_letter_source_queues(input))
+    }
+
+    /// <p><p>List all cost allocation tags added to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::list_queue_tags`.
+    pub fn list_queue_tags(
+        &self,
+        input: ListQueueTagsRequest,
+    ) -> Result<ListQueueTagsResult, RusotoError<ListQueueTagsError>> {
+        self.runtime.block_on(self.client.list_queue_tags(input))
+    }
+
+    /// <p><p>Returns a list of your queues. The maximum number of queues that can be returned is 1,000. If you specify a value for the optional <code>QueueNamePrefix</code> parameter, only queues with a name that begins with the specified value are returned.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::list_queues`.
+    pub fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResult, RusotoError<ListQueuesError>> {
+        self.runtime.block_on(self.client.list_queues(input))
+    }
+
+    /// <p>Deletes the messages in a queue specified by the <code>QueueURL</code> parameter.</p> <important> <p>When you use the <code>PurgeQueue</code> action, you can't retrieve any messages deleted from a queue.</p> <p>The message deletion process takes up to 60 seconds. We recommend waiting for 60 seconds regardless of your queue's size. </p> </important> <p>Messages sent to the queue <i>before</i> you call <code>PurgeQueue</code> might be received but are deleted within the next minute.</p> <p>Messages sent to the queue <i>after</i> you call <code>PurgeQueue</code> might be deleted while the queue is being purged.</p>
+    ///
+    /// See `Sqs::purge_queue`.
+    pub fn purge_queue(
+        &self,
+        input: PurgeQueueRequest,
+    ) -> Result<(), RusotoError<PurgeQueueError>> {
+        self.runtime.block_on(self.client.purge_queue(input))
+    }
+
+    /// <p><p>Retrieves one or more messages (up to 10), from the specified queue. Using the <code>WaitTimeSeconds</code> parameter enables long-poll support. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-long-polling.html">Amazon SQS Long Polling</a> in the <i>Amazon Simple Queue Service Developer Guide</i>. </p> <p>Short poll is the default behavior where a weighted random set of machines is sampled on a <code>ReceiveMessage</code> call. Thus, only the messages on the sampled machines are returned. If the number of messages in the queue is small (fewer than 1,000), you most likely get fewer messages than you requested per <code>ReceiveMessage</code> call. If the number of messages in the queue is extremely small, you might not receive any messages in a particular <code>ReceiveMessage</code> response. If this happens, repeat the request. </p> <p>For each message returned, the response includes the following:</p> <ul> <li> <p>The message body.</p> </li> <li> <p>An MD5 digest of the message body. For information about MD5, see <a href="https://www.ietf.org/rfc/rfc1321.txt">RFC1321</a>.</p> </li> <li> <p>The <code>MessageId</code> you received when you sent the message to the queue.</p> </li> <li> <p>The receipt handle.</p> </li> <li> <p>The message attributes.</p> </li> <li> <p>An MD5 digest of the message attributes.</p> </li> </ul> <p>The receipt handle is the identifier you must provide when deleting the message. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-message-identifiers.html">Queue and Message Identifiers</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>You can provide the <code>VisibilityTimeout</code> parameter in your request. The parameter is applied to the messages that Amazon SQS returns in the response. If you don&#39;t include the parameter, the overall visibility timeout for the queue is used for the returned messages. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-visibility-timeout.html">Visibility Timeout</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>A message that isn&#39;t deleted or a message whose visibility isn&#39;t extended before the visibility timeout expires counts as a failed receive. Depending on the configuration of the queue, the message might be sent to the dead-letter queue.</p> <note> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </note></p>
+    ///
+    /// See `Sqs::receive_message`.
+    pub fn receive_message(
+        &self,
+        input: ReceiveMessageRequest,
+    ) -> Result<ReceiveMessageResult, RusotoError<ReceiveMessageError>> {
+        self.runtime.block_on(self.client.receive_message(input))
+    }
+
+    /// <p><p>Revokes any permissions in the queue policy that matches the specified <code>Label</code> parameter.</p> <note> <ul> <li> <p>Only the owner of a queue can remove permissions from it.</p> </li> <li> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note></p>
+    ///
+    /// See `Sqs::remove_permission`.
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// <p><p>Delivers a message to the specified queue.</p> <important> <p>A message can include only XML, JSON, and unformatted text. The following Unicode characters are allowed:</p> <p> <code>#x9</code> | <code>#xA</code> | <code>#xD</code> | <code>#x20</code> to <code>#xD7FF</code> | <code>#xE000</code> to <code>#xFFFD</code> | <code>#x10000</code> to <code>#x10FFFF</code> </p> <p>Any characters not included in this list will be rejected. For more information, see the <a href="http://www.w3.org/TR/REC-xml/#charsets">W3C specification for characters</a>.</p> </important></p>
+    ///
+    /// See `Sqs::send_message`.
+    pub fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResult, RusotoError<SendMessageError>> {
+        self.runtime.block_on(self.client.send_message(input))
+    }
+
+    /// <p>Delivers up to ten messages to the specified queue. This is a batch version of <code> <a>SendMessage</a>.</code> For a FIFO queue, multiple messages within a single batch are enqueued in the order they are sent.</p> <p>The result of sending each message is reported individually in the response. Because the batch request can result in a combination of successful and unsuccessful actions, you should check for batch errors even when the call returns an HTTP status code of <code>200</code>.</p> <p>The maximum allowed individual message size and the maximum total payload size (the sum of the individual lengths of all of the batched messages) are both 256 KB (262,144 bytes).</p> <important> <p>A message can include only XML, JSON, and unformatted text. The following Unicode characters are allowed:</p> <p> <code>#x9</code> | <code>#xA</code> | <code>#xD</code> | <code>#x20</code> to <code>#xD7FF</code> | <code>#xE000</code> to <code>#xFFFD</code> | <code>#x10000</code> to <code>#x10FFFF</code> </p> <p>Any characters not included in this list will be rejected. For more information, see the <a href="http://www.w3.org/TR/REC-xml/#charsets">W3C specification for characters</a>.</p> </important> <p>If you don't specify the <code>DelaySeconds</code> parameter for an entry, Amazon SQS uses the default value for the queue.</p> <p>Some actions take lists of parameters. These lists are specified using the <code>param.n</code> notation. Values of <code>n</code> are integers starting from 1. For example, a parameter list with two elements looks like this:</p> <p> <code>&amp;AttributeName.1=first</code> </p> <p> <code>&amp;AttributeName.2=second</code> </p>
+    ///
+    /// See `Sqs::send_message_batch`.
+    pub fn send_message_batch(
+        &self,
+        input: SendMessageBatchRequest,
+    ) -> Result<SendMessageBatchResult, RusotoError<SendMessageBatchError>> {
+        self.runtime.block_on(self.client.send_message_batch(input))
+    }
+
+    /// <p><p>Sets the value of one or more queue attributes. When you change a queue&#39;s attributes, the change can take up to 60 seconds for most of the attributes to propagate throughout the Amazon SQS system. Changes made to the <code>MessageRetentionPeriod</code> attribute can take up to 15 minutes.</p> <note> <ul> <li> <p>In the future, new attributes might be added. If you write code that calls this action, we recommend that you structure your code so that it can handle new attributes gracefully.</p> </li> <li> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </li> <li> <p>To remove the ability to change queue permissions, you must deny permission to the <code>AddPermission</code>, <code>RemovePermission</code>, and <code>SetQueueAttributes</code> actions in your IAM policy.</p> </li> </ul> </note></p>
+    ///
+    /// See `Sqs::set_queue_attributes`.
+    pub fn set_queue_attributes(
+        &self,
+        input: SetQueueAttributesRequest,
+    ) -> Result<(), RusotoError<SetQueueAttributesError>> {
+        self.runtime
+            .block_on(self.client.set_queue_attributes(input))
+    }
+
+    /// <p><p>Add cost allocation tags to the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <p>When you use queue tags, keep the following guidelines in mind:</p> <ul> <li> <p>Adding more than 50 tags to a queue isn&#39;t recommended.</p> </li> <li> <p>Tags don&#39;t have any semantic meaning. Amazon SQS interprets tags as character strings.</p> </li> <li> <p>Tags are case-sensitive.</p> </li> <li> <p>A new tag with a key identical to that of an existing tag overwrites the existing tag.</p> </li> </ul> <p>For a full list of tag restrictions, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-limits.html#limits-queues">Limits Related to Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::tag_queue`.
+    pub fn tag_queue(&self, input: TagQueueRequest) -> Result<(), RusotoError<TagQueueError>> {
+        self.runtime.block_on(self.client.tag_queue(input))
+    }
+
+    /// <p><p>Remove cost allocation tags from the specified Amazon SQS queue. For an overview, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-queue-tags.html">Tagging Your Amazon SQS Queues</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> <note> <p>Cross-account permissions don&#39;t apply to this action. For more information, see <a href="https://docs.aws.amazon.com/AWSSimpleQueueService/latest/SQSDeveloperGuide/sqs-customer-managed-policy-examples.html#grant-cross-account-permissions-to-role-and-user-name">Grant Cross-Account Permissions to a Role and a User Name</a> in the <i>Amazon Simple Queue Service Developer Guide</i>.</p> </note></p>
+    ///
+    /// See `Sqs::untag_queue`.
+    pub fn untag_queue(
+        &self,
+        input: UntagQueueRequest,
+    ) -> Result<(), RusotoError<UntagQueueError>> {
+        self.runtime.block_on(self.client.untag_queue(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -8129,23 +8129,34 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddTagsToResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidResourceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InvalidResourceId(err.msg),
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InvalidResourceType(err.msg),
+                    )
                 }
                 "TooManyTagsError" => {
-                    return RusotoError::Service(AddTagsToResourceError::TooManyTagsError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::TooManyTagsError(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(AddTagsToResourceError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8185,16 +8196,28 @@ impl CancelCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(CancelCommandError::DuplicateInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelCommandError::DuplicateInstanceId(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelCommandError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelCommandError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(CancelCommandError::InvalidCommandId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelCommandError::InvalidCommandId(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CancelCommandError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelCommandError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8231,12 +8254,14 @@ impl CancelMaintenanceWindowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelMaintenanceWindowExecutionError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CancelMaintenanceWindowExecutionError::InternalServerError(err.msg),
                     )
                 }
@@ -8273,9 +8298,10 @@ impl CreateActivationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateActivationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateActivationError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8325,49 +8351,70 @@ impl CreateAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationAlreadyExists" => {
-                    return RusotoError::Service(CreateAssociationError::AssociationAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::AssociationAlreadyExists(err.msg),
+                    )
                 }
                 "AssociationLimitExceeded" => {
-                    return RusotoError::Service(CreateAssociationError::AssociationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::AssociationLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidDocumentVersion(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidOutputLocation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidOutputLocation(err.msg),
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidParameters(err.msg),
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidSchedule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidSchedule(err.msg),
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(CreateAssociationError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::InvalidTarget(err.msg),
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(CreateAssociationError::UnsupportedPlatformType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationError::UnsupportedPlatformType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8427,57 +8474,68 @@ impl CreateAssociationBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssociationBatchError::AssociationLimitExceeded(err.msg),
                     )
                 }
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(CreateAssociationBatchError::DuplicateInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::DuplicateInstanceId(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssociationBatchError::InvalidDocumentVersion(err.msg),
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssociationBatchError::InvalidOutputLocation(err.msg),
                     )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidParameters(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::InvalidParameters(err.msg),
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidSchedule(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::InvalidSchedule(err.msg),
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(CreateAssociationBatchError::InvalidTarget(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateAssociationBatchError::InvalidTarget(err.msg),
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateAssociationBatchError::UnsupportedPlatformType(err.msg),
                     )
                 }
@@ -8535,32 +8593,40 @@ impl CreateDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentAlreadyExists" => {
-                    return RusotoError::Service(CreateDocumentError::DocumentAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentError::DocumentAlreadyExists(err.msg),
+                    )
                 }
                 "DocumentLimitExceeded" => {
-                    return RusotoError::Service(CreateDocumentError::DocumentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentError::DocumentLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocumentContent" => {
-                    return RusotoError::Service(CreateDocumentError::InvalidDocumentContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentError::InvalidDocumentContent(err.msg),
+                    )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(CreateDocumentError::InvalidDocumentSchemaVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentError::InvalidDocumentSchemaVersion(err.msg),
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(CreateDocumentError::MaxDocumentSizeExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateDocumentError::MaxDocumentSizeExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8599,17 +8665,20 @@ impl CreateMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMaintenanceWindowError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMaintenanceWindowError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateMaintenanceWindowError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -8653,18 +8722,28 @@ impl CreateOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOpsItemError::InternalServerError(err.msg),
+                    )
                 }
                 "OpsItemAlreadyExistsException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOpsItemError::OpsItemAlreadyExists(err.msg),
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateOpsItemError::OpsItemInvalidParameter(err.msg),
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(CreateOpsItemError::OpsItemLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateOpsItemError::OpsItemLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8701,19 +8780,22 @@ impl CreatePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreatePatchBaselineError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreatePatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePatchBaselineError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreatePatchBaselineError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreatePatchBaselineError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8753,22 +8835,26 @@ impl CreateResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceDataSyncError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceDataSyncAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceDataSyncError::ResourceDataSyncAlreadyExists(err.msg),
                     )
                 }
                 "ResourceDataSyncCountExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceDataSyncError::ResourceDataSyncCountExceeded(err.msg),
                     )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
                     )
                 }
@@ -8815,20 +8901,28 @@ impl DeleteActivationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteActivationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActivationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidActivation" => {
-                    return RusotoError::Service(DeleteActivationError::InvalidActivation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActivationError::InvalidActivation(err.msg),
+                    )
                 }
                 "InvalidActivationId" => {
-                    return RusotoError::Service(DeleteActivationError::InvalidActivationId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActivationError::InvalidActivationId(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(DeleteActivationError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteActivationError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8869,23 +8963,34 @@ impl DeleteAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(DeleteAssociationError::AssociationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssociationError::AssociationDoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssociationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DeleteAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssociationError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DeleteAssociationError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssociationError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(DeleteAssociationError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAssociationError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8925,18 +9030,28 @@ impl DeleteDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedInstances" => {
-                    return RusotoError::Service(DeleteDocumentError::AssociatedInstances(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::AssociatedInstances(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DeleteDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(DeleteDocumentError::InvalidDocumentOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::InvalidDocumentOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -8977,23 +9092,31 @@ impl DeleteInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInventoryError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDeleteInventoryParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteInventoryError::InvalidDeleteInventoryParameters(err.msg),
                     )
                 }
                 "InvalidInventoryRequestException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidInventoryRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInventoryError::InvalidInventoryRequest(err.msg),
+                    )
                 }
                 "InvalidOptionException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidOption(err.msg))
+                    return RusotoError::service(&res, DeleteInventoryError::InvalidOption(err.msg))
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(DeleteInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteInventoryError::InvalidTypeName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9029,9 +9152,10 @@ impl DeleteMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMaintenanceWindowError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9063,10 +9187,16 @@ impl DeleteParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteParameterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteParameterError::InternalServerError(err.msg),
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(DeleteParameterError::ParameterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteParameterError::ParameterNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9097,9 +9227,10 @@ impl DeleteParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteParametersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteParametersError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9131,12 +9262,16 @@ impl DeletePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeletePatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePatchBaselineError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePatchBaselineError::ResourceInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePatchBaselineError::ResourceInUse(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9171,17 +9306,20 @@ impl DeleteResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceDataSyncError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
                     )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
                     )
                 }
@@ -9221,14 +9359,16 @@ impl DeregisterManagedInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterManagedInstanceError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DeregisterManagedInstanceError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterManagedInstanceError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9265,12 +9405,14 @@ impl DeregisterPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterPatchBaselineForPatchGroupError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
                     )
                 }
@@ -9313,17 +9455,20 @@ impl DeregisterTargetFromMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterTargetFromMaintenanceWindowError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterTargetFromMaintenanceWindowError::InternalServerError(err.msg),
                     )
                 }
                 "TargetInUseException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterTargetFromMaintenanceWindowError::TargetInUse(err.msg),
                     )
                 }
@@ -9367,12 +9512,14 @@ impl DeregisterTaskFromMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterTaskFromMaintenanceWindowError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterTaskFromMaintenanceWindowError::InternalServerError(err.msg),
                     )
                 }
@@ -9413,17 +9560,22 @@ impl DescribeActivationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeActivationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivationsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(DescribeActivationsError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivationsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeActivationsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivationsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9463,27 +9615,34 @@ impl DescribeAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(DescribeAssociationError::AssociationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssociationError::AssociationDoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssociationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAssociationVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationError::InvalidAssociationVersion(err.msg),
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssociationError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DescribeAssociationError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAssociationError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9527,24 +9686,28 @@ impl DescribeAssociationExecutionTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionTargetsError::AssociationDoesNotExist(err.msg),
                     )
                 }
                 "AssociationExecutionDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionTargetsError::AssociationExecutionDoesNotExist(
                             err.msg,
                         ),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionTargetsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionTargetsError::InvalidNextToken(err.msg),
                     )
                 }
@@ -9593,17 +9756,20 @@ impl DescribeAssociationExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionsError::AssociationDoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAssociationExecutionsError::InvalidNextToken(err.msg),
                     )
                 }
@@ -9651,22 +9817,26 @@ impl DescribeAutomationExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationExecutionsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationExecutionsError::InvalidFilterKey(err.msg),
                     )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationExecutionsError::InvalidFilterValue(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationExecutionsError::InvalidNextToken(err.msg),
                     )
                 }
@@ -9719,27 +9889,32 @@ impl DescribeAutomationStepExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationStepExecutionsError::AutomationExecutionNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationStepExecutionsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationStepExecutionsError::InvalidFilterKey(err.msg),
                     )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationStepExecutionsError::InvalidFilterValue(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAutomationStepExecutionsError::InvalidNextToken(err.msg),
                     )
                 }
@@ -9785,7 +9960,8 @@ impl DescribeAvailablePatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAvailablePatchesError::InternalServerError(err.msg),
                     )
                 }
@@ -9821,17 +9997,22 @@ impl DescribeDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeDocumentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(DescribeDocumentError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentError::InvalidDocumentVersion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -9869,17 +10050,20 @@ impl DescribeDocumentPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentPermissionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(DescribeDocumentPermissionError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentPermissionError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidPermissionType" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentPermissionError::InvalidPermissionType(err.msg),
                     )
                 }
@@ -9923,17 +10107,20 @@ impl DescribeEffectiveInstanceAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectiveInstanceAssociationsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectiveInstanceAssociationsError::InvalidInstanceId(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectiveInstanceAssociationsError::InvalidNextToken(err.msg),
                     )
                 }
@@ -9981,22 +10168,26 @@ impl DescribeEffectivePatchesForPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePatchesForPatchBaselineError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePatchesForPatchBaselineError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePatchesForPatchBaselineError::InvalidResourceId(err.msg),
                     )
                 }
                 "UnsupportedOperatingSystem" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeEffectivePatchesForPatchBaselineError::UnsupportedOperatingSystem(
                             err.msg,
                         ),
@@ -10047,17 +10238,20 @@ impl DescribeInstanceAssociationsStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceAssociationsStatusError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceAssociationsStatusError::InvalidInstanceId(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceAssociationsStatusError::InvalidNextToken(err.msg),
                     )
                 }
@@ -10107,29 +10301,34 @@ impl DescribeInstanceInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceInformationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceInformationError::InvalidFilterKey(err.msg),
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceInformationError::InvalidInstanceId(err.msg),
                     )
                 }
                 "InvalidInstanceInformationFilterValue" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceInformationError::InvalidInstanceInformationFilterValue(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstanceInformationError::InvalidNextToken(err.msg),
                     )
                 }
@@ -10175,12 +10374,14 @@ impl DescribeInstancePatchStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstancePatchStatesError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstancePatchStatesError::InvalidNextToken(err.msg),
                     )
                 }
@@ -10221,17 +10422,20 @@ impl DescribeInstancePatchStatesForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstancePatchStatesForPatchGroupError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstancePatchStatesForPatchGroupError::InvalidFilter(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInstancePatchStatesForPatchGroupError::InvalidNextToken(err.msg),
                     )
                 }
@@ -10277,24 +10481,28 @@ impl DescribeInstancePatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancePatchesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancePatchesError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancePatchesError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeInstancePatchesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInstancePatchesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10333,19 +10541,22 @@ impl DescribeInventoryDeletionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInventoryDeletionsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidDeletionIdException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeInventoryDeletionsError::InvalidDeletionId(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeInventoryDeletionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeInventoryDeletionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10383,14 +10594,16 @@ impl DescribeMaintenanceWindowExecutionTaskInvocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowExecutionTaskInvocationsError::DoesNotExist(
                             err.msg,
                         ),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowExecutionTaskInvocationsError::InternalServerError(
                             err.msg,
                         ),
@@ -10433,12 +10646,14 @@ impl DescribeMaintenanceWindowExecutionTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowExecutionTasksError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowExecutionTasksError::InternalServerError(err.msg),
                     )
                 }
@@ -10477,7 +10692,8 @@ impl DescribeMaintenanceWindowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowExecutionsError::InternalServerError(err.msg),
                     )
                 }
@@ -10515,12 +10731,14 @@ impl DescribeMaintenanceWindowScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowScheduleError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowScheduleError::InternalServerError(err.msg),
                     )
                 }
@@ -10561,12 +10779,14 @@ impl DescribeMaintenanceWindowTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowTargetsError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowTargetsError::InternalServerError(err.msg),
                     )
                 }
@@ -10607,12 +10827,14 @@ impl DescribeMaintenanceWindowTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(DescribeMaintenanceWindowTasksError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeMaintenanceWindowTasksError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowTasksError::InternalServerError(err.msg),
                     )
                 }
@@ -10649,7 +10871,8 @@ impl DescribeMaintenanceWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowsError::InternalServerError(err.msg),
                     )
                 }
@@ -10685,7 +10908,8 @@ impl DescribeMaintenanceWindowsForTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceWindowsForTargetError::InternalServerError(err.msg),
                     )
                 }
@@ -10719,9 +10943,10 @@ impl DescribeOpsItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeOpsItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOpsItemsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10759,25 +10984,34 @@ impl DescribeParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeParametersError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeParametersError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeParametersError::InvalidFilterKey(err.msg),
+                    )
                 }
                 "InvalidFilterOption" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeParametersError::InvalidFilterOption(err.msg),
+                    )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidFilterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeParametersError::InvalidFilterValue(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeParametersError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10811,9 +11045,10 @@ impl DescribePatchBaselinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchBaselinesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePatchBaselinesError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10845,14 +11080,16 @@ impl DescribePatchGroupStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchGroupStateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePatchGroupStateError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribePatchGroupStateError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePatchGroupStateError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10883,9 +11120,10 @@ impl DescribePatchGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchGroupsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePatchGroupsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10915,9 +11153,10 @@ impl DescribePatchPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribePatchPropertiesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribePatchPropertiesError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10951,15 +11190,22 @@ impl DescribeSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSessionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSessionsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(DescribeSessionsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSessionsError::InvalidFilterKey(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(DescribeSessionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSessionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -10993,14 +11239,16 @@ impl GetAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetAutomationExecutionError::AutomationExecutionNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetAutomationExecutionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAutomationExecutionError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11039,22 +11287,28 @@ impl GetCalendarStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCalendarStateError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCalendarStateError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(GetCalendarStateError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCalendarStateError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentType" => {
-                    return RusotoError::Service(GetCalendarStateError::InvalidDocumentType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCalendarStateError::InvalidDocumentType(err.msg),
+                    )
                 }
                 "UnsupportedCalendarException" => {
-                    return RusotoError::Service(GetCalendarStateError::UnsupportedCalendar(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCalendarStateError::UnsupportedCalendar(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11095,29 +11349,34 @@ impl GetCommandInvocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCommandInvocationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommandInvocationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidCommandId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommandInvocationError::InvalidCommandId(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommandInvocationError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidPluginName" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvalidPluginName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommandInvocationError::InvalidPluginName(err.msg),
+                    )
                 }
                 "InvocationDoesNotExist" => {
-                    return RusotoError::Service(GetCommandInvocationError::InvocationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCommandInvocationError::InvocationDoesNotExist(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11151,9 +11410,10 @@ impl GetConnectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetConnectionStatusError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetConnectionStatusError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11183,9 +11443,10 @@ impl GetDefaultPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDefaultPatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDefaultPatchBaselineError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11221,19 +11482,22 @@ impl GetDeployablePatchSnapshotForInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeployablePatchSnapshotForInstanceError::InternalServerError(err.msg),
                     )
                 }
                 "UnsupportedFeatureRequiredException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeployablePatchSnapshotForInstanceError::UnsupportedFeatureRequired(
                             err.msg,
                         ),
                     )
                 }
                 "UnsupportedOperatingSystem" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDeployablePatchSnapshotForInstanceError::UnsupportedOperatingSystem(
                             err.msg,
                         ),
@@ -11279,13 +11543,19 @@ impl GetDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(GetDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(&res, GetDocumentError::InvalidDocument(err.msg))
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(GetDocumentError::InvalidDocumentVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentError::InvalidDocumentVersion(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11329,25 +11599,37 @@ impl GetInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInventoryError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAggregatorException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidAggregator(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInventoryError::InvalidAggregator(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(GetInventoryError::InvalidFilter(err.msg))
+                    return RusotoError::service(&res, GetInventoryError::InvalidFilter(err.msg))
                 }
                 "InvalidInventoryGroupException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidInventoryGroup(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInventoryError::InvalidInventoryGroup(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetInventoryError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, GetInventoryError::InvalidNextToken(err.msg))
                 }
                 "InvalidResultAttributeException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidResultAttribute(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInventoryError::InvalidResultAttribute(err.msg),
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(&res, GetInventoryError::InvalidTypeName(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11387,15 +11669,22 @@ impl GetInventorySchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetInventorySchemaError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetInventorySchemaError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetInventorySchemaError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInventorySchemaError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetInventorySchemaError::InvalidTypeName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetInventorySchemaError::InvalidTypeName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11429,12 +11718,16 @@ impl GetMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowError::DoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMaintenanceWindowError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMaintenanceWindowError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11469,12 +11762,14 @@ impl GetMaintenanceWindowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowExecutionError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMaintenanceWindowExecutionError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMaintenanceWindowExecutionError::InternalServerError(err.msg),
                     )
                 }
@@ -11513,12 +11808,14 @@ impl GetMaintenanceWindowExecutionTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMaintenanceWindowExecutionTaskError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMaintenanceWindowExecutionTaskError::InternalServerError(err.msg),
                     )
                 }
@@ -11559,12 +11856,14 @@ impl GetMaintenanceWindowExecutionTaskInvocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMaintenanceWindowExecutionTaskInvocationError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMaintenanceWindowExecutionTaskInvocationError::InternalServerError(
                             err.msg,
                         ),
@@ -11605,12 +11904,14 @@ impl GetMaintenanceWindowTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetMaintenanceWindowTaskError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMaintenanceWindowTaskError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetMaintenanceWindowTaskError::InternalServerError(err.msg),
                     )
                 }
@@ -11645,10 +11946,13 @@ impl GetOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpsItemError::InternalServerError(err.msg),
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(GetOpsItemError::OpsItemNotFound(err.msg))
+                    return RusotoError::service(&res, GetOpsItemError::OpsItemNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11689,24 +11993,34 @@ impl GetOpsSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetOpsSummaryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpsSummaryError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAggregatorException" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidAggregator(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpsSummaryError::InvalidAggregator(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidFilter(err.msg))
+                    return RusotoError::service(&res, GetOpsSummaryError::InvalidFilter(err.msg))
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetOpsSummaryError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(GetOpsSummaryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(&res, GetOpsSummaryError::InvalidTypeName(err.msg))
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(GetOpsSummaryError::ResourceDataSyncNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetOpsSummaryError::ResourceDataSyncNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11747,18 +12061,25 @@ impl GetParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParameterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParameterError::InvalidKeyId(err.msg))
+                    return RusotoError::service(&res, GetParameterError::InvalidKeyId(err.msg))
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(GetParameterError::ParameterNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterError::ParameterNotFound(err.msg),
+                    )
                 }
                 "ParameterVersionNotFound" => {
-                    return RusotoError::Service(GetParameterError::ParameterVersionNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterError::ParameterVersionNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11797,22 +12118,28 @@ impl GetParameterHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParameterHistoryError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterHistoryError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParameterHistoryError::InvalidKeyId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterHistoryError::InvalidKeyId(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetParameterHistoryError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterHistoryError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(GetParameterHistoryError::ParameterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParameterHistoryError::ParameterNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11847,10 +12174,13 @@ impl GetParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParametersError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParametersError::InvalidKeyId(err.msg))
+                    return RusotoError::service(&res, GetParametersError::InvalidKeyId(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11891,32 +12221,40 @@ impl GetParametersByPathError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetParametersByPathError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersByPathError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterKey(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersByPathError::InvalidFilterKey(err.msg),
+                    )
                 }
                 "InvalidFilterOption" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterOption(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersByPathError::InvalidFilterOption(err.msg),
+                    )
                 }
                 "InvalidFilterValue" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidFilterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersByPathError::InvalidFilterValue(err.msg),
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidKeyId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersByPathError::InvalidKeyId(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(GetParametersByPathError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetParametersByPathError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11955,15 +12293,19 @@ impl GetPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(GetPatchBaselineError::DoesNotExist(err.msg))
+                    return RusotoError::service(&res, GetPatchBaselineError::DoesNotExist(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetPatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPatchBaselineError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(GetPatchBaselineError::InvalidResourceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetPatchBaselineError::InvalidResourceId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -11997,7 +12339,8 @@ impl GetPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetPatchBaselineForPatchGroupError::InternalServerError(err.msg),
                     )
                 }
@@ -12033,14 +12376,16 @@ impl GetServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetServiceSettingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceSettingError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(GetServiceSettingError::ServiceSettingNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceSettingError::ServiceSettingNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12079,29 +12424,34 @@ impl LabelParameterVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(LabelParameterVersionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LabelParameterVersionError::InternalServerError(err.msg),
+                    )
                 }
                 "ParameterNotFound" => {
-                    return RusotoError::Service(LabelParameterVersionError::ParameterNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LabelParameterVersionError::ParameterNotFound(err.msg),
+                    )
                 }
                 "ParameterVersionLabelLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         LabelParameterVersionError::ParameterVersionLabelLimitExceeded(err.msg),
                     )
                 }
                 "ParameterVersionNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         LabelParameterVersionError::ParameterVersionNotFound(err.msg),
                     )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(LabelParameterVersionError::TooManyUpdates(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        LabelParameterVersionError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12143,19 +12493,22 @@ impl ListAssociationVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAssociationVersionsError::AssociationDoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListAssociationVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssociationVersionsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListAssociationVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssociationVersionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12191,12 +12544,16 @@ impl ListAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListAssociationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAssociationsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListAssociationsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAssociationsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12235,29 +12592,34 @@ impl ListCommandInvocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandInvocationsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidCommandId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandInvocationsError::InvalidCommandId(err.msg),
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidFilterKey(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandInvocationsError::InvalidFilterKey(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandInvocationsError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListCommandInvocationsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandInvocationsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12299,19 +12661,25 @@ impl ListCommandsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCommandsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidCommandId" => {
-                    return RusotoError::Service(ListCommandsError::InvalidCommandId(err.msg))
+                    return RusotoError::service(&res, ListCommandsError::InvalidCommandId(err.msg))
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListCommandsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(&res, ListCommandsError::InvalidFilterKey(err.msg))
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListCommandsError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListCommandsError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListCommandsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListCommandsError::InvalidNextToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12353,27 +12721,34 @@ impl ListComplianceItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListComplianceItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceItemsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceItemsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceItemsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceItemsError::InvalidResourceId(err.msg),
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(ListComplianceItemsError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceItemsError::InvalidResourceType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12411,19 +12786,22 @@ impl ListComplianceSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceSummariesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceSummariesError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListComplianceSummariesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListComplianceSummariesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12459,19 +12837,22 @@ impl ListDocumentVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentVersionsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentVersionsError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListDocumentVersionsError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentVersionsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12507,13 +12888,22 @@ impl ListDocumentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDocumentsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilterKey" => {
-                    return RusotoError::Service(ListDocumentsError::InvalidFilterKey(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentsError::InvalidFilterKey(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListDocumentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDocumentsError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12553,27 +12943,34 @@ impl ListInventoryEntriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInventoryEntriesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidFilter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListInventoryEntriesError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInventoryEntriesError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInventoryEntriesError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(ListInventoryEntriesError::InvalidTypeName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListInventoryEntriesError::InvalidTypeName(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12613,17 +13010,20 @@ impl ListResourceComplianceSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceComplianceSummariesError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidFilter" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceComplianceSummariesError::InvalidFilter(err.msg),
                     )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceComplianceSummariesError::InvalidNextToken(err.msg),
                     )
                 }
@@ -12667,17 +13067,20 @@ impl ListResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDataSyncError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidNextToken" => {
-                    return RusotoError::Service(ListResourceDataSyncError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDataSyncError::InvalidNextToken(err.msg),
+                    )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
                     )
                 }
@@ -12717,19 +13120,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidResourceId(err.msg),
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidResourceType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12769,27 +13175,32 @@ impl ModifyDocumentPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyDocumentPermissionError::DocumentLimitExceeded(err.msg),
                     )
                 }
                 "DocumentPermissionLimit" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyDocumentPermissionError::DocumentPermissionLimit(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyDocumentPermissionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(ModifyDocumentPermissionError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyDocumentPermissionError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidPermissionType" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyDocumentPermissionError::InvalidPermissionType(err.msg),
                     )
                 }
@@ -12843,39 +13254,46 @@ impl PutComplianceItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ComplianceTypeCountLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutComplianceItemsError::ComplianceTypeCountLimitExceeded(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutComplianceItemsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComplianceItemsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidItemContentException" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidItemContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComplianceItemsError::InvalidItemContent(err.msg),
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComplianceItemsError::InvalidResourceId(err.msg),
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(PutComplianceItemsError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComplianceItemsError::InvalidResourceType(err.msg),
+                    )
                 }
                 "ItemSizeLimitExceededException" => {
-                    return RusotoError::Service(PutComplianceItemsError::ItemSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComplianceItemsError::ItemSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TotalSizeLimitExceededException" => {
-                    return RusotoError::Service(PutComplianceItemsError::TotalSizeLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutComplianceItemsError::TotalSizeLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -12935,48 +13353,71 @@ impl PutInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomSchemaCountLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::CustomSchemaCountLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::CustomSchemaCountLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutInventoryError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(PutInventoryError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "InvalidInventoryItemContextException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidInventoryItemContext(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::InvalidInventoryItemContext(err.msg),
+                    )
                 }
                 "InvalidItemContentException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidItemContent(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::InvalidItemContent(err.msg),
+                    )
                 }
                 "InvalidTypeNameException" => {
-                    return RusotoError::Service(PutInventoryError::InvalidTypeName(err.msg))
+                    return RusotoError::service(&res, PutInventoryError::InvalidTypeName(err.msg))
                 }
                 "ItemContentMismatchException" => {
-                    return RusotoError::Service(PutInventoryError::ItemContentMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::ItemContentMismatch(err.msg),
+                    )
                 }
                 "ItemSizeLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::ItemSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::ItemSizeLimitExceeded(err.msg),
+                    )
                 }
                 "SubTypeCountLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::SubTypeCountLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::SubTypeCountLimitExceeded(err.msg),
+                    )
                 }
                 "TotalSizeLimitExceededException" => {
-                    return RusotoError::Service(PutInventoryError::TotalSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutInventoryError::TotalSizeLimitExceeded(err.msg),
+                    )
                 }
                 "UnsupportedInventoryItemContextException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInventoryError::UnsupportedInventoryItemContext(err.msg),
                     )
                 }
                 "UnsupportedInventorySchemaVersionException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutInventoryError::UnsupportedInventorySchemaVersion(err.msg),
                     )
                 }
@@ -13049,57 +13490,88 @@ impl PutParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "HierarchyLevelLimitExceededException" => {
-                    return RusotoError::Service(PutParameterError::HierarchyLevelLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::HierarchyLevelLimitExceeded(err.msg),
+                    )
                 }
                 "HierarchyTypeMismatchException" => {
-                    return RusotoError::Service(PutParameterError::HierarchyTypeMismatch(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::HierarchyTypeMismatch(err.msg),
+                    )
                 }
                 "IncompatiblePolicyException" => {
-                    return RusotoError::Service(PutParameterError::IncompatiblePolicy(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::IncompatiblePolicy(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutParameterError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAllowedPatternException" => {
-                    return RusotoError::Service(PutParameterError::InvalidAllowedPattern(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::InvalidAllowedPattern(err.msg),
+                    )
                 }
                 "InvalidKeyId" => {
-                    return RusotoError::Service(PutParameterError::InvalidKeyId(err.msg))
+                    return RusotoError::service(&res, PutParameterError::InvalidKeyId(err.msg))
                 }
                 "InvalidPolicyAttributeException" => {
-                    return RusotoError::Service(PutParameterError::InvalidPolicyAttribute(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::InvalidPolicyAttribute(err.msg),
+                    )
                 }
                 "InvalidPolicyTypeException" => {
-                    return RusotoError::Service(PutParameterError::InvalidPolicyType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::InvalidPolicyType(err.msg),
+                    )
                 }
                 "ParameterAlreadyExists" => {
-                    return RusotoError::Service(PutParameterError::ParameterAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::ParameterAlreadyExists(err.msg),
+                    )
                 }
                 "ParameterLimitExceeded" => {
-                    return RusotoError::Service(PutParameterError::ParameterLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::ParameterLimitExceeded(err.msg),
+                    )
                 }
                 "ParameterMaxVersionLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutParameterError::ParameterMaxVersionLimitExceeded(err.msg),
                     )
                 }
                 "ParameterPatternMismatchException" => {
-                    return RusotoError::Service(PutParameterError::ParameterPatternMismatch(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::ParameterPatternMismatch(err.msg),
+                    )
                 }
                 "PoliciesLimitExceededException" => {
-                    return RusotoError::Service(PutParameterError::PoliciesLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::PoliciesLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(PutParameterError::TooManyUpdates(err.msg))
+                    return RusotoError::service(&res, PutParameterError::TooManyUpdates(err.msg))
                 }
                 "UnsupportedParameterType" => {
-                    return RusotoError::Service(PutParameterError::UnsupportedParameterType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutParameterError::UnsupportedParameterType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13151,17 +13623,20 @@ impl RegisterDefaultPatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(RegisterDefaultPatchBaselineError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDefaultPatchBaselineError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDefaultPatchBaselineError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterDefaultPatchBaselineError::InvalidResourceId(err.msg),
                     )
                 }
@@ -13209,27 +13684,32 @@ impl RegisterPatchBaselineForPatchGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterPatchBaselineForPatchGroupError::AlreadyExists(err.msg),
                     )
                 }
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterPatchBaselineForPatchGroupError::DoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterPatchBaselineForPatchGroupError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterPatchBaselineForPatchGroupError::InvalidResourceId(err.msg),
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterPatchBaselineForPatchGroupError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -13283,24 +13763,28 @@ impl RegisterTargetWithMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTargetWithMaintenanceWindowError::DoesNotExist(err.msg),
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTargetWithMaintenanceWindowError::IdempotentParameterMismatch(
                             err.msg,
                         ),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTargetWithMaintenanceWindowError::InternalServerError(err.msg),
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTargetWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -13353,29 +13837,34 @@ impl RegisterTaskWithMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTaskWithMaintenanceWindowError::DoesNotExist(err.msg),
                     )
                 }
                 "FeatureNotAvailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTaskWithMaintenanceWindowError::FeatureNotAvailable(err.msg),
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTaskWithMaintenanceWindowError::IdempotentParameterMismatch(
                             err.msg,
                         ),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTaskWithMaintenanceWindowError::InternalServerError(err.msg),
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterTaskWithMaintenanceWindowError::ResourceLimitExceeded(err.msg),
                     )
                 }
@@ -13427,24 +13916,28 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidResourceId" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidResourceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::InvalidResourceId(err.msg),
+                    )
                 }
                 "InvalidResourceType" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidResourceType(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::InvalidResourceType(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::TooManyUpdates(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13481,17 +13974,22 @@ impl ResetServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ResetServiceSettingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResetServiceSettingError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(ResetServiceSettingError::ServiceSettingNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ResetServiceSettingError::ServiceSettingNotFound(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(ResetServiceSettingError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetServiceSettingError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13525,10 +14023,13 @@ impl ResumeSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(ResumeSessionError::DoesNotExist(err.msg))
+                    return RusotoError::service(&res, ResumeSessionError::DoesNotExist(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ResumeSessionError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResumeSessionError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13565,22 +14066,26 @@ impl SendAutomationSignalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SendAutomationSignalError::AutomationExecutionNotFound(err.msg),
                     )
                 }
                 "AutomationStepNotFoundException" => {
-                    return RusotoError::Service(SendAutomationSignalError::AutomationStepNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendAutomationSignalError::AutomationStepNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SendAutomationSignalError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendAutomationSignalError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAutomationSignalException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SendAutomationSignalError::InvalidAutomationSignal(err.msg),
                     )
                 }
@@ -13637,39 +14142,58 @@ impl SendCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateInstanceId" => {
-                    return RusotoError::Service(SendCommandError::DuplicateInstanceId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::DuplicateInstanceId(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SendCommandError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(SendCommandError::InvalidDocument(err.msg))
+                    return RusotoError::service(&res, SendCommandError::InvalidDocument(err.msg))
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(SendCommandError::InvalidDocumentVersion(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::InvalidDocumentVersion(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(SendCommandError::InvalidInstanceId(err.msg))
+                    return RusotoError::service(&res, SendCommandError::InvalidInstanceId(err.msg))
                 }
                 "InvalidNotificationConfig" => {
-                    return RusotoError::Service(SendCommandError::InvalidNotificationConfig(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::InvalidNotificationConfig(err.msg),
+                    )
                 }
                 "InvalidOutputFolder" => {
-                    return RusotoError::Service(SendCommandError::InvalidOutputFolder(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::InvalidOutputFolder(err.msg),
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(SendCommandError::InvalidParameters(err.msg))
+                    return RusotoError::service(&res, SendCommandError::InvalidParameters(err.msg))
                 }
                 "InvalidRole" => {
-                    return RusotoError::Service(SendCommandError::InvalidRole(err.msg))
+                    return RusotoError::service(&res, SendCommandError::InvalidRole(err.msg))
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(SendCommandError::MaxDocumentSizeExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::MaxDocumentSizeExceeded(err.msg),
+                    )
                 }
                 "UnsupportedPlatformType" => {
-                    return RusotoError::Service(SendCommandError::UnsupportedPlatformType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendCommandError::UnsupportedPlatformType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13711,14 +14235,16 @@ impl StartAssociationsOnceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAssociationsOnceError::AssociationDoesNotExist(err.msg),
                     )
                 }
                 "InvalidAssociation" => {
-                    return RusotoError::Service(StartAssociationsOnceError::InvalidAssociation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAssociationsOnceError::InvalidAssociation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13763,41 +14289,48 @@ impl StartAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationDefinitionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAutomationExecutionError::AutomationDefinitionNotFound(err.msg),
                     )
                 }
                 "AutomationDefinitionVersionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAutomationExecutionError::AutomationDefinitionVersionNotFound(err.msg),
                     )
                 }
                 "AutomationExecutionLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAutomationExecutionError::AutomationExecutionLimitExceeded(err.msg),
                     )
                 }
                 "IdempotentParameterMismatch" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAutomationExecutionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAutomationExecutionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidAutomationExecutionParametersException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAutomationExecutionError::InvalidAutomationExecutionParameters(
                             err.msg,
                         ),
                     )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(StartAutomationExecutionError::InvalidTarget(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartAutomationExecutionError::InvalidTarget(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13847,13 +14380,19 @@ impl StartSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StartSessionError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSessionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(StartSessionError::InvalidDocument(err.msg))
+                    return RusotoError::service(&res, StartSessionError::InvalidDocument(err.msg))
                 }
                 "TargetNotConnected" => {
-                    return RusotoError::Service(StartSessionError::TargetNotConnected(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartSessionError::TargetNotConnected(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13889,17 +14428,20 @@ impl StopAutomationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AutomationExecutionNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopAutomationExecutionError::AutomationExecutionNotFound(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopAutomationExecutionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopAutomationExecutionError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAutomationStatusUpdateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StopAutomationExecutionError::InvalidAutomationStatusUpdate(err.msg),
                     )
                 }
@@ -13939,12 +14481,13 @@ impl TerminateSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(TerminateSessionError::DoesNotExist(err.msg))
+                    return RusotoError::service(&res, TerminateSessionError::DoesNotExist(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TerminateSessionError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TerminateSessionError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -13997,52 +14540,76 @@ impl UpdateAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(UpdateAssociationError::AssociationDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::AssociationDoesNotExist(err.msg),
+                    )
                 }
                 "AssociationVersionLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAssociationError::AssociationVersionLimitExceeded(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateAssociationError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidAssociationVersion" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidAssociationVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidAssociationVersion(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidDocumentVersion(err.msg),
+                    )
                 }
                 "InvalidOutputLocation" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidOutputLocation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidOutputLocation(err.msg),
+                    )
                 }
                 "InvalidParameters" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidParameters(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidParameters(err.msg),
+                    )
                 }
                 "InvalidSchedule" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidSchedule(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidSchedule(err.msg),
+                    )
                 }
                 "InvalidTarget" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidTarget(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidTarget(err.msg),
+                    )
                 }
                 "InvalidUpdate" => {
-                    return RusotoError::Service(UpdateAssociationError::InvalidUpdate(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::InvalidUpdate(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateAssociationError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14095,34 +14662,40 @@ impl UpdateAssociationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociationDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAssociationStatusError::AssociationDoesNotExist(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationStatusError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InvalidDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationStatusError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationStatusError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "StatusUnchanged" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::StatusUnchanged(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationStatusError::StatusUnchanged(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateAssociationStatusError::TooManyUpdates(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAssociationStatusError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14177,50 +14750,64 @@ impl UpdateDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DocumentVersionLimitExceeded" => {
-                    return RusotoError::Service(UpdateDocumentError::DocumentVersionLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::DocumentVersionLimitExceeded(err.msg),
+                    )
                 }
                 "DuplicateDocumentContent" => {
-                    return RusotoError::Service(UpdateDocumentError::DuplicateDocumentContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::DuplicateDocumentContent(err.msg),
+                    )
                 }
                 "DuplicateDocumentVersionName" => {
-                    return RusotoError::Service(UpdateDocumentError::DuplicateDocumentVersionName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::DuplicateDocumentVersionName(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::InvalidDocument(err.msg),
+                    )
                 }
                 "InvalidDocumentContent" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentContent(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::InvalidDocumentContent(err.msg),
+                    )
                 }
                 "InvalidDocumentOperation" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::InvalidDocumentOperation(err.msg),
+                    )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentSchemaVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::InvalidDocumentSchemaVersion(err.msg),
+                    )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(UpdateDocumentError::InvalidDocumentVersion(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::InvalidDocumentVersion(err.msg),
+                    )
                 }
                 "MaxDocumentSizeExceeded" => {
-                    return RusotoError::Service(UpdateDocumentError::MaxDocumentSizeExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::MaxDocumentSizeExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14267,22 +14854,26 @@ impl UpdateDocumentDefaultVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDocumentDefaultVersionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidDocument" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDocumentDefaultVersionError::InvalidDocument(err.msg),
                     )
                 }
                 "InvalidDocumentSchemaVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDocumentDefaultVersionError::InvalidDocumentSchemaVersion(err.msg),
                     )
                 }
                 "InvalidDocumentVersion" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDocumentDefaultVersionError::InvalidDocumentVersion(err.msg),
                     )
                 }
@@ -14325,14 +14916,16 @@ impl UpdateMaintenanceWindowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMaintenanceWindowError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMaintenanceWindowError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14367,12 +14960,14 @@ impl UpdateMaintenanceWindowTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowTargetError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMaintenanceWindowTargetError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMaintenanceWindowTargetError::InternalServerError(err.msg),
                     )
                 }
@@ -14411,12 +15006,14 @@ impl UpdateMaintenanceWindowTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdateMaintenanceWindowTaskError::DoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMaintenanceWindowTaskError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMaintenanceWindowTaskError::InternalServerError(err.msg),
                     )
                 }
@@ -14453,14 +15050,16 @@ impl UpdateManagedInstanceRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateManagedInstanceRoleError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidInstanceId" => {
-                    return RusotoError::Service(UpdateManagedInstanceRoleError::InvalidInstanceId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateManagedInstanceRoleError::InvalidInstanceId(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14501,21 +15100,31 @@ impl UpdateOpsItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateOpsItemError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOpsItemError::InternalServerError(err.msg),
+                    )
                 }
                 "OpsItemAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOpsItemError::OpsItemAlreadyExists(err.msg),
+                    )
                 }
                 "OpsItemInvalidParameterException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOpsItemError::OpsItemInvalidParameter(err.msg),
+                    )
                 }
                 "OpsItemLimitExceededException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateOpsItemError::OpsItemLimitExceeded(err.msg),
+                    )
                 }
                 "OpsItemNotFoundException" => {
-                    return RusotoError::Service(UpdateOpsItemError::OpsItemNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateOpsItemError::OpsItemNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14551,12 +15160,16 @@ impl UpdatePatchBaselineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DoesNotExistException" => {
-                    return RusotoError::Service(UpdatePatchBaselineError::DoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePatchBaselineError::DoesNotExist(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdatePatchBaselineError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePatchBaselineError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -14593,22 +15206,26 @@ impl UpdateResourceDataSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateResourceDataSyncError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceDataSyncError::InternalServerError(err.msg),
+                    )
                 }
                 "ResourceDataSyncConflictException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateResourceDataSyncError::ResourceDataSyncConflict(err.msg),
                     )
                 }
                 "ResourceDataSyncInvalidConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateResourceDataSyncError::ResourceDataSyncInvalidConfiguration(err.msg),
                     )
                 }
                 "ResourceDataSyncNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateResourceDataSyncError::ResourceDataSyncNotFound(err.msg),
                     )
                 }
@@ -14653,17 +15270,22 @@ impl UpdateServiceSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateServiceSettingError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingError::InternalServerError(err.msg),
+                    )
                 }
                 "ServiceSettingNotFound" => {
-                    return RusotoError::Service(UpdateServiceSettingError::ServiceSettingNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingError::ServiceSettingNotFound(err.msg),
+                    )
                 }
                 "TooManyUpdates" => {
-                    return RusotoError::Service(UpdateServiceSettingError::TooManyUpdates(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServiceSettingError::TooManyUpdates(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -15508,6 +16130,7 @@ impl SsmClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -15523,6 +16146,7 @@ impl SsmClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SsmClient {
         SsmClient { client, region }
     }
@@ -15536,6 +16160,9 @@ impl Ssm for SsmClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15554,6 +16181,9 @@ impl Ssm for SsmClient {
         input: CancelCommandRequest,
     ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelCommand");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CancelCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15575,6 +16205,9 @@ impl Ssm for SsmClient {
         RusotoError<CancelMaintenanceWindowExecutionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelMaintenanceWindowExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CancelMaintenanceWindowExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15597,6 +16230,9 @@ impl Ssm for SsmClient {
         input: CreateActivationRequest,
     ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateActivation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CreateActivation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15615,6 +16251,9 @@ impl Ssm for SsmClient {
         input: CreateAssociationRequest,
     ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CreateAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15633,6 +16272,9 @@ impl Ssm for SsmClient {
         input: CreateAssociationBatchRequest,
     ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAssociationBatch");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CreateAssociationBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15652,6 +16294,9 @@ impl Ssm for SsmClient {
         input: CreateDocumentRequest,
     ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CreateDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15670,6 +16315,9 @@ impl Ssm for SsmClient {
         input: CreateMaintenanceWindowRequest,
     ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.add_header("x-amz-target", "AmazonSSM.CreateMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15689,6 +16337,9 @@ impl Ssm for SsmClient {
         input: CreateOpsItemRequest,
     ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateOpsItem");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CreateOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15707,6 +16358,9 @@ impl Ssm for SsmClient {
         input: CreatePatchBaselineRequest,
     ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreatePatchBaseline");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.add_header("x-amz-target", "AmazonSSM.CreatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15725,6 +16379,9 @@ impl Ssm for SsmClient {
         input: CreateResourceDataSyncRequest,
     ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateResourceDataSync");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.CreateResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15744,6 +16401,9 @@ impl Ssm for SsmClient {
         input: DeleteActivationRequest,
     ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteActivation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteActivation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15762,6 +16422,9 @@ impl Ssm for SsmClient {
         input: DeleteAssociationRequest,
     ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15780,6 +16443,9 @@ impl Ssm for SsmClient {
         input: DeleteDocumentRequest,
     ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15798,6 +16464,9 @@ impl Ssm for SsmClient {
         input: DeleteInventoryRequest,
     ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteInventory");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.add_header("x-amz-target", "AmazonSSM.DeleteInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15816,6 +16485,9 @@ impl Ssm for SsmClient {
         input: DeleteMaintenanceWindowRequest,
     ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15835,6 +16507,9 @@ impl Ssm for SsmClient {
         input: DeleteParameterRequest,
     ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteParameter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15853,6 +16528,9 @@ impl Ssm for SsmClient {
         input: DeleteParametersRequest,
     ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteParameters");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15871,6 +16549,9 @@ impl Ssm for SsmClient {
         input: DeletePatchBaselineRequest,
     ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePatchBaseline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeletePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15889,6 +16570,9 @@ impl Ssm for SsmClient {
         input: DeleteResourceDataSyncRequest,
     ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResourceDataSync");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeleteResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15908,6 +16592,9 @@ impl Ssm for SsmClient {
         input: DeregisterManagedInstanceRequest,
     ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterManagedInstance");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.DeregisterManagedInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15930,6 +16617,9 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterPatchBaselineForPatchGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterPatchBaselineForPatchGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DeregisterPatchBaselineForPatchGroup",
@@ -15958,6 +16648,9 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterTargetFromMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterTargetFromMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DeregisterTargetFromMaintenanceWindow",
@@ -15986,6 +16679,9 @@ impl Ssm for SsmClient {
         RusotoError<DeregisterTaskFromMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterTaskFromMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DeregisterTaskFromMaintenanceWindow",
@@ -16011,6 +16707,9 @@ impl Ssm for SsmClient {
         input: DescribeActivationsRequest,
     ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeActivations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeActivations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16029,6 +16728,9 @@ impl Ssm for SsmClient {
         input: DescribeAssociationRequest,
     ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAssociation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16050,6 +16752,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeAssociationExecutionTargetsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAssociationExecutionTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeAssociationExecutionTargets",
@@ -16076,6 +16781,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAssociationExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeAssociationExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16096,6 +16804,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAutomationExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16118,6 +16829,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeAutomationStepExecutionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAutomationStepExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeAutomationStepExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16140,6 +16854,9 @@ impl Ssm for SsmClient {
         input: DescribeAvailablePatchesRequest,
     ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAvailablePatches");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeAvailablePatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16159,6 +16876,9 @@ impl Ssm for SsmClient {
         input: DescribeDocumentRequest,
     ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDocument");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16178,6 +16898,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDocumentPermission");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeDocumentPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16200,6 +16923,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeEffectiveInstanceAssociationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEffectiveInstanceAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeEffectiveInstanceAssociations",
@@ -16228,6 +16954,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeEffectivePatchesForPatchBaseline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeEffectivePatchesForPatchBaseline",
@@ -16256,6 +16985,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeInstanceAssociationsStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstanceAssociationsStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeInstanceAssociationsStatus",
@@ -16282,6 +17014,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstanceInformation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstanceInformation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16302,6 +17037,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstancePatchStates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatchStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16324,6 +17062,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstancePatchStatesForPatchGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeInstancePatchStatesForPatchGroup",
@@ -16349,6 +17090,9 @@ impl Ssm for SsmClient {
         input: DescribeInstancePatchesRequest,
     ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInstancePatches");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeInstancePatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16369,6 +17113,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeInventoryDeletions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeInventoryDeletions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16391,6 +17138,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowExecutionTaskInvocations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowExecutionTaskInvocations",
@@ -16419,6 +17169,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowExecutionTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowExecutionTasks",
@@ -16447,6 +17200,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowExecutionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowExecutions",
@@ -16475,6 +17231,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowScheduleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowSchedule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowSchedule",
@@ -16503,6 +17262,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowTargetsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16528,6 +17290,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowTasksError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowTasks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindowTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16548,6 +17313,9 @@ impl Ssm for SsmClient {
     ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindows");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeMaintenanceWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16570,6 +17338,9 @@ impl Ssm for SsmClient {
         RusotoError<DescribeMaintenanceWindowsForTargetError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceWindowsForTarget");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.DescribeMaintenanceWindowsForTarget",
@@ -16595,6 +17366,9 @@ impl Ssm for SsmClient {
         input: DescribeOpsItemsRequest,
     ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOpsItems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeOpsItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16613,6 +17387,9 @@ impl Ssm for SsmClient {
         input: DescribeParametersRequest,
     ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16631,6 +17408,9 @@ impl Ssm for SsmClient {
         input: DescribePatchBaselinesRequest,
     ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePatchBaselines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchBaselines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16650,6 +17430,9 @@ impl Ssm for SsmClient {
         input: DescribePatchGroupStateRequest,
     ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePatchGroupState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroupState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16669,6 +17452,9 @@ impl Ssm for SsmClient {
         input: DescribePatchGroupsRequest,
     ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePatchGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16687,6 +17473,9 @@ impl Ssm for SsmClient {
         input: DescribePatchPropertiesRequest,
     ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribePatchProperties");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribePatchProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16706,6 +17495,9 @@ impl Ssm for SsmClient {
         input: DescribeSessionsRequest,
     ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSessions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.DescribeSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16724,6 +17516,9 @@ impl Ssm for SsmClient {
         input: GetAutomationExecutionRequest,
     ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAutomationExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16743,6 +17538,9 @@ impl Ssm for SsmClient {
         input: GetCalendarStateRequest,
     ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCalendarState");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetCalendarState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16761,6 +17559,9 @@ impl Ssm for SsmClient {
         input: GetCommandInvocationRequest,
     ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetCommandInvocation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetCommandInvocation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16779,6 +17580,9 @@ impl Ssm for SsmClient {
         input: GetConnectionStatusRequest,
     ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetConnectionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetConnectionStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16797,6 +17601,9 @@ impl Ssm for SsmClient {
         input: GetDefaultPatchBaselineRequest,
     ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDefaultPatchBaseline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetDefaultPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16819,6 +17626,9 @@ impl Ssm for SsmClient {
         RusotoError<GetDeployablePatchSnapshotForInstanceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDeployablePatchSnapshotForInstance");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.GetDeployablePatchSnapshotForInstance",
@@ -16844,6 +17654,9 @@ impl Ssm for SsmClient {
         input: GetDocumentRequest,
     ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDocument");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16862,6 +17675,9 @@ impl Ssm for SsmClient {
         input: GetInventoryRequest,
     ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInventory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16880,6 +17696,9 @@ impl Ssm for SsmClient {
         input: GetInventorySchemaRequest,
     ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetInventorySchema");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetInventorySchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16898,6 +17717,9 @@ impl Ssm for SsmClient {
         input: GetMaintenanceWindowRequest,
     ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMaintenanceWindow");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16917,6 +17739,9 @@ impl Ssm for SsmClient {
     ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMaintenanceWindowExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16939,6 +17764,9 @@ impl Ssm for SsmClient {
         RusotoError<GetMaintenanceWindowExecutionTaskError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMaintenanceWindowExecutionTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.GetMaintenanceWindowExecutionTask",
@@ -16967,6 +17795,9 @@ impl Ssm for SsmClient {
         RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMaintenanceWindowExecutionTaskInvocation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.GetMaintenanceWindowExecutionTaskInvocation",
@@ -16992,6 +17823,9 @@ impl Ssm for SsmClient {
         input: GetMaintenanceWindowTaskRequest,
     ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMaintenanceWindowTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetMaintenanceWindowTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17011,6 +17845,9 @@ impl Ssm for SsmClient {
         input: GetOpsItemRequest,
     ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOpsItem");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17029,6 +17866,9 @@ impl Ssm for SsmClient {
         input: GetOpsSummaryRequest,
     ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetOpsSummary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetOpsSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17047,6 +17887,9 @@ impl Ssm for SsmClient {
         input: GetParameterRequest,
     ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetParameter");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17065,6 +17908,9 @@ impl Ssm for SsmClient {
         input: GetParameterHistoryRequest,
     ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetParameterHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetParameterHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17083,6 +17929,9 @@ impl Ssm for SsmClient {
         input: GetParametersRequest,
     ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetParameters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17101,6 +17950,9 @@ impl Ssm for SsmClient {
         input: GetParametersByPathRequest,
     ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetParametersByPath");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetParametersByPath");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17119,6 +17971,9 @@ impl Ssm for SsmClient {
         input: GetPatchBaselineRequest,
     ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPatchBaseline");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17138,6 +17993,9 @@ impl Ssm for SsmClient {
     ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPatchBaselineForPatchGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetPatchBaselineForPatchGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17157,6 +18015,9 @@ impl Ssm for SsmClient {
         input: GetServiceSettingRequest,
     ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetServiceSetting");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.GetServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17175,6 +18036,9 @@ impl Ssm for SsmClient {
         input: LabelParameterVersionRequest,
     ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("LabelParameterVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.LabelParameterVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17193,6 +18057,9 @@ impl Ssm for SsmClient {
         input: ListAssociationVersionsRequest,
     ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssociationVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListAssociationVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17212,6 +18079,9 @@ impl Ssm for SsmClient {
         input: ListAssociationsRequest,
     ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAssociations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListAssociations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17230,6 +18100,9 @@ impl Ssm for SsmClient {
         input: ListCommandInvocationsRequest,
     ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCommandInvocations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListCommandInvocations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17249,6 +18122,9 @@ impl Ssm for SsmClient {
         input: ListCommandsRequest,
     ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListCommands");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListCommands");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17267,6 +18143,9 @@ impl Ssm for SsmClient {
         input: ListComplianceItemsRequest,
     ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListComplianceItems");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17285,6 +18164,9 @@ impl Ssm for SsmClient {
         input: ListComplianceSummariesRequest,
     ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListComplianceSummaries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListComplianceSummaries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17304,6 +18186,9 @@ impl Ssm for SsmClient {
         input: ListDocumentVersionsRequest,
     ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDocumentVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListDocumentVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17322,6 +18207,9 @@ impl Ssm for SsmClient {
         input: ListDocumentsRequest,
     ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDocuments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListDocuments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17340,6 +18228,9 @@ impl Ssm for SsmClient {
         input: ListInventoryEntriesRequest,
     ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListInventoryEntries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListInventoryEntries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17361,6 +18252,9 @@ impl Ssm for SsmClient {
         RusotoError<ListResourceComplianceSummariesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourceComplianceSummaries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListResourceComplianceSummaries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17380,6 +18274,9 @@ impl Ssm for SsmClient {
         input: ListResourceDataSyncRequest,
     ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourceDataSync");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17398,6 +18295,9 @@ impl Ssm for SsmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AmazonSSM.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17416,6 +18316,9 @@ impl Ssm for SsmClient {
         input: ModifyDocumentPermissionRequest,
     ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyDocumentPermission");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.ModifyDocumentPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17435,6 +18338,9 @@ impl Ssm for SsmClient {
         input: PutComplianceItemsRequest,
     ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutComplianceItems");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.PutComplianceItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17453,6 +18359,9 @@ impl Ssm for SsmClient {
         input: PutInventoryRequest,
     ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutInventory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.PutInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17471,6 +18380,9 @@ impl Ssm for SsmClient {
         input: PutParameterRequest,
     ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutParameter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.PutParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17490,6 +18402,9 @@ impl Ssm for SsmClient {
     ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterDefaultPatchBaseline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.RegisterDefaultPatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17512,6 +18427,9 @@ impl Ssm for SsmClient {
         RusotoError<RegisterPatchBaselineForPatchGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterPatchBaselineForPatchGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AmazonSSM.RegisterPatchBaselineForPatchGroup",
@@ -17540,6 +18458,9 @@ impl Ssm for SsmClient {
         RusotoError<RegisterTargetWithMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterTargetWithMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.add_header(
             "x-amz-target",
             "AmazonSSM.RegisterTargetWithMaintenanceWindow",
@@ -17568,6 +18489,9 @@ impl Ssm for SsmClient {
         RusotoError<RegisterTaskWithMaintenanceWindowError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterTaskWithMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(input.client_token.is_some());
         request.add_header(
             "x-amz-target",
             "AmazonSSM.RegisterTaskWithMaintenanceWindow",
@@ -17593,6 +18517,9 @@ impl Ssm for SsmClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.RemoveTagsFromResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17612,6 +18539,9 @@ impl Ssm for SsmClient {
         input: ResetServiceSettingRequest,
     ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResetServiceSetting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.ResetServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17630,6 +18560,9 @@ impl Ssm for SsmClient {
         input: ResumeSessionRequest,
     ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResumeSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.ResumeSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17648,6 +18581,9 @@ impl Ssm for SsmClient {
         input: SendAutomationSignalRequest,
     ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendAutomationSignal");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.SendAutomationSignal");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17666,6 +18602,9 @@ impl Ssm for SsmClient {
         input: SendCommandRequest,
     ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendCommand");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.SendCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17684,6 +18623,9 @@ impl Ssm for SsmClient {
         input: StartAssociationsOnceRequest,
     ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartAssociationsOnce");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.StartAssociationsOnce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17702,6 +18644,9 @@ impl Ssm for SsmClient {
         input: StartAutomationExecutionRequest,
     ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartAutomationExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.StartAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17721,6 +18666,9 @@ impl Ssm for SsmClient {
         input: StartSessionRequest,
     ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.StartSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17739,6 +18687,9 @@ impl Ssm for SsmClient {
         input: StopAutomationExecutionRequest,
     ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopAutomationExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.StopAutomationExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17758,6 +18709,9 @@ impl Ssm for SsmClient {
         input: TerminateSessionRequest,
     ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TerminateSession");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.TerminateSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17776,6 +18730,9 @@ impl Ssm for SsmClient {
         input: UpdateAssociationRequest,
     ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAssociation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17794,6 +18751,9 @@ impl Ssm for SsmClient {
         input: UpdateAssociationStatusRequest,
     ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAssociationStatus");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateAssociationStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17813,6 +18773,9 @@ impl Ssm for SsmClient {
         input: UpdateDocumentRequest,
     ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17832,6 +18795,9 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateDocumentDefaultVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateDocumentDefaultVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17851,6 +18817,9 @@ impl Ssm for SsmClient {
         input: UpdateMaintenanceWindowRequest,
     ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMaintenanceWindow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17871,6 +18840,9 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMaintenanceWindowTarget");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17891,6 +18863,9 @@ impl Ssm for SsmClient {
     ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMaintenanceWindowTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateMaintenanceWindowTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17910,6 +18885,9 @@ impl Ssm for SsmClient {
         input: UpdateManagedInstanceRoleRequest,
     ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateManagedInstanceRole");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateManagedInstanceRole");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17929,6 +18907,9 @@ impl Ssm for SsmClient {
         input: UpdateOpsItemRequest,
     ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateOpsItem");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateOpsItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17947,6 +18928,9 @@ impl Ssm for SsmClient {
         input: UpdatePatchBaselineRequest,
     ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePatchBaseline");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdatePatchBaseline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17965,6 +18949,9 @@ impl Ssm for SsmClient {
         input: UpdateResourceDataSyncRequest,
     ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateResourceDataSync");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateResourceDataSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17984,6 +18971,9 @@ impl Ssm for SsmClient {
         input: UpdateServiceSettingRequest,
     ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServiceSetting");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AmazonSSM.UpdateServiceSetting");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17996,3 +18986,1458 @@ impl Ssm for SsmClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateServiceSettingResult, _>()
     }
 }
+
+/// A blocking client for the Amazon SSM API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SsmClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SsmBlockingClient {
+    client: SsmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SsmBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SsmBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SsmBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SsmBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SsmBlockingClient {
+        SsmBlockingClient {
+            client: SsmClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified resource. Tags are metadata that you can assign to your documents, managed instances, maintenance windows, Parameter Store parameters, and patch baselines. Tags enable you to categorize your resources in different ways, for example, by purpose, owner, or environment. Each tag consists of a key and an optional value, both of which you define. For example, you could define a set of tags for your account's managed instances that helps you track each instance's owner and stack level. For example: Key=Owner and Value=DbAdmin, SysAdmin, or Dev. Or Key=Stack and Value=Production, Pre-Production, or Test.</p> <p>Each resource can have a maximum of 50 tags. </p> <p>We recommend that you devise a set of tag keys that meets your needs for each resource type. Using a consistent set of tag keys makes it easier for you to manage your resources. You can search and filter the resources based on the tags you add. Tags don't have any semantic meaning to and are interpreted strictly as a string of characters. </p> <p>For more information about using tags with EC2 instances, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/Using_Tags.html">Tagging your Amazon EC2 resources</a> in the <i>Amazon EC2 User Guide</i>.</p>
+    ///
+    /// See `Ssm::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Attempts to cancel the command specified by the Command ID. There is no guarantee that the command will be terminated and the underlying process stopped.</p>
+    ///
+    /// See `Ssm::cancel_command`.
+    pub fn cancel_command(
+        &self,
+        input: CancelCommandRequest,
+    ) -> Result<CancelCommandResult, RusotoError<CancelCommandError>> {
+        self.runtime.block_on(self.client.cancel_command(input))
+    }
+
+    /// <p>Stops a maintenance window execution that is already in progress and cancels any tasks in the window that have not already starting running. (Tasks already in progress will continue to completion.)</p>
+    ///
+    /// See `Ssm::cancel_maintenance_window_execution`.
+    pub fn cancel_maintenance_window_execution(
+        &self,
+        input: CancelMaintenanceWindowExecutionRequest,
+    ) -> Result<
+        CancelMaintenanceWindowExecutionResult,
+        RusotoError<CancelMaintenanceWindowExecutionError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_maintenance_window_execution(input))
+    }
+
+    /// <p><p>Generates an activation code and activation ID you can use to register your on-premises server or virtual machine (VM) with Systems Manager. Registering these machines with Systems Manager makes it possible to manage them using Systems Manager capabilities. You use the activation code and ID when installing SSM Agent on machines in your hybrid environment. For more information about requirements for managing on-premises instances and VMs using Systems Manager, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-managedinstances.html">Setting up AWS Systems Manager for hybrid environments</a> in the <i>AWS Systems Manager User Guide</i>. </p> <note> <p>On-premises servers or VMs that are registered with Systems Manager and EC2 instances that you manage with Systems Manager are all called <i>managed instances</i>.</p> </note></p>
+    ///
+    /// See `Ssm::create_activation`.
+    pub fn create_activation(
+        &self,
+        input: CreateActivationRequest,
+    ) -> Result<CreateActivationResult, RusotoError<CreateActivationError>> {
+        self.runtime.block_on(self.client.create_activation(input))
+    }
+
+    /// <p>A State Manager association defines the state that you want to maintain on your instances. For example, an association can specify that anti-virus software must be installed and running on your instances, or that certain ports must be closed. For static targets, the association specifies a schedule for when the configuration is reapplied. For dynamic targets, such as an AWS Resource Group or an AWS Autoscaling Group, State Manager applies the configuration when new instances are added to the group. The association also specifies actions to take when applying the configuration. For example, an association for anti-virus software might run once a day. If the software is not installed, then State Manager installs it. If the software is installed, but the service is not running, then the association might instruct State Manager to start the service. </p>
+    ///
+    /// See `Ssm::create_association`.
+    pub fn create_association(
+        &self,
+        input: CreateAssociationRequest,
+    ) -> Result<CreateAssociationResult, RusotoError<CreateAssociationError>> {
+        self.runtime.block_on(self.client.create_association(input))
+    }
+
+    /// <p>Associates the specified Systems Manager document with the specified instances or targets.</p> <p>When you associate a document with one or more instances using instance IDs or tags, SSM Agent running on the instance processes the document and configures the instance as specified.</p> <p>If you associate a document with an instance that already has an associated document, the system returns the AssociationAlreadyExists exception.</p>
+    ///
+    /// See `Ssm::create_association_batch`.
+    pub fn create_association_batch(
+        &self,
+        input: CreateAssociationBatchRequest,
+    ) -> Result<CreateAssociationBatchResult, RusotoError<CreateAssociationBatchError>> {
+        self.runtime
+            .block_on(self.client.create_association_batch(input))
+    }
+
+    /// <p>Creates a Systems Manager (SSM) document. An SSM document defines the actions that Systems Manager performs on your managed instances. For more information about SSM documents, including information about supported schemas, features, and syntax, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-ssm-docs.html">AWS Systems Manager Documents</a> in the <i>AWS Systems Manager User Guide</i>.</p>
+    ///
+    /// See `Ssm::create_document`.
+    pub fn create_document(
+        &self,
+        input: CreateDocumentRequest,
+    ) -> Result<CreateDocumentResult, RusotoError<CreateDocumentError>> {
+        self.runtime.block_on(self.client.create_document(input))
+    }
+
+    /// <p><p>Creates a new maintenance window.</p> <note> <p>The value you specify for <code>Duration</code> determines the specific end time for the maintenance window based on the time it begins. No maintenance window tasks are permitted to start after the resulting endtime minus the number of hours you specify for <code>Cutoff</code>. For example, if the maintenance window starts at 3 PM, the duration is three hours, and the value you specify for <code>Cutoff</code> is one hour, no maintenance window tasks can start after 5 PM.</p> </note></p>
+    ///
+    /// See `Ssm::create_maintenance_window`.
+    pub fn create_maintenance_window(
+        &self,
+        input: CreateMaintenanceWindowRequest,
+    ) -> Result<CreateMaintenanceWindowResult, RusotoError<CreateMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.create_maintenance_window(input))
+    }
+
+    /// <p>Creates a new OpsItem. You must have permission in AWS Identity and Access Management (IAM) to create a new OpsItem. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    ///
+    /// See `Ssm::create_ops_item`.
+    pub fn create_ops_item(
+        &self,
+        input: CreateOpsItemRequest,
+    ) -> Result<CreateOpsItemResponse, RusotoError<CreateOpsItemError>> {
+        self.runtime.block_on(self.client.create_ops_item(input))
+    }
+
+    /// <p><p>Creates a patch baseline.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
+    ///
+    /// See `Ssm::create_patch_baseline`.
+    pub fn create_patch_baseline(
+        &self,
+        input: CreatePatchBaselineRequest,
+    ) -> Result<CreatePatchBaselineResult, RusotoError<CreatePatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.create_patch_baseline(input))
+    }
+
+    /// <p><p>A resource data sync helps you view data from multiple sources in a single location. Systems Manager offers two types of resource data sync: <code>SyncToDestination</code> and <code>SyncFromSource</code>.</p> <p>You can configure Systems Manager Inventory to use the <code>SyncToDestination</code> type to synchronize Inventory data from multiple AWS Regions to a single S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/sysman-inventory-datasync.html">Configuring Resource Data Sync for Inventory</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>You can configure Systems Manager Explorer to use the <code>SyncFromSource</code> type to synchronize operational work items (OpsItems) and operational data (OpsData) from multiple AWS Regions to a single S3 bucket. This type can synchronize OpsItems and OpsData from multiple AWS accounts and Regions or <code>EntireOrganization</code> by using AWS Organizations. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/Explorer-resource-data-sync.html">Setting up Systems Manager Explorer to display data from multiple accounts and Regions</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>A resource data sync is an asynchronous operation that returns immediately. After a successful initial sync is completed, the system continuously syncs data. To check the status of a sync, use the <a>ListResourceDataSync</a>.</p> <note> <p>By default, data is not encrypted in Amazon S3. We strongly recommend that you enable encryption in Amazon S3 to ensure secure data storage. We also recommend that you secure access to the Amazon S3 bucket by creating a restrictive bucket policy. </p> </note></p>
+    ///
+    /// See `Ssm::create_resource_data_sync`.
+    pub fn create_resource_data_sync(
+        &self,
+        input: CreateResourceDataSyncRequest,
+    ) -> Result<CreateResourceDataSyncResult, RusotoError<CreateResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.create_resource_data_sync(input))
+    }
+
+    /// <p>Deletes an activation. You are not required to delete an activation. If you delete an activation, you can no longer use it to register additional managed instances. Deleting an activation does not de-register managed instances. You must manually de-register managed instances.</p>
+    ///
+    /// See `Ssm::delete_activation`.
+    pub fn delete_activation(
+        &self,
+        input: DeleteActivationRequest,
+    ) -> Result<DeleteActivationResult, RusotoError<DeleteActivationError>> {
+        self.runtime.block_on(self.client.delete_activation(input))
+    }
+
+    /// <p>Disassociates the specified Systems Manager document from the specified instance.</p> <p>When you disassociate a document from an instance, it does not change the configuration of the instance. To change the configuration state of an instance after you disassociate a document, you must create a new document with the desired configuration and associate it with the instance.</p>
+    ///
+    /// See `Ssm::delete_association`.
+    pub fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> Result<DeleteAssociationResult, RusotoError<DeleteAssociationError>> {
+        self.runtime.block_on(self.client.delete_association(input))
+    }
+
+    /// <p>Deletes the Systems Manager document and all instance associations to the document.</p> <p>Before you delete the document, we recommend that you use <a>DeleteAssociation</a> to disassociate all instances that are associated with the document.</p>
+    ///
+    /// See `Ssm::delete_document`.
+    pub fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> Result<DeleteDocumentResult, RusotoError<DeleteDocumentError>> {
+        self.runtime.block_on(self.client.delete_document(input))
+    }
+
+    /// <p>Delete a custom inventory type, or the data associated with a custom Inventory type. Deleting a custom inventory type is also referred to as deleting a custom inventory schema.</p>
+    ///
+    /// See `Ssm::delete_inventory`.
+    pub fn delete_inventory(
+        &self,
+        input: DeleteInventoryRequest,
+    ) -> Result<DeleteInventoryResult, RusotoError<DeleteInventoryError>> {
+        self.runtime.block_on(self.client.delete_inventory(input))
+    }
+
+    /// <p>Deletes a maintenance window.</p>
+    ///
+    /// See `Ssm::delete_maintenance_window`.
+    pub fn delete_maintenance_window(
+        &self,
+        input: DeleteMaintenanceWindowRequest,
+    ) -> Result<DeleteMaintenanceWindowResult, RusotoError<DeleteMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.delete_maintenance_window(input))
+    }
+
+    /// <p>Delete a parameter from the system.</p>
+    ///
+    /// See `Ssm::delete_parameter`.
+    pub fn delete_parameter(
+        &self,
+        input: DeleteParameterRequest,
+    ) -> Result<DeleteParameterResult, RusotoError<DeleteParameterError>> {
+        self.runtime.block_on(self.client.delete_parameter(input))
+    }
+
+    /// <p>Delete a list of parameters.</p>
+    ///
+    /// See `Ssm::delete_parameters`.
+    pub fn delete_parameters(
+        &self,
+        input: DeleteParametersRequest,
+    ) -> Result<DeleteParametersResult, RusotoError<DeleteParametersError>> {
+        self.runtime.block_on(self.client.delete_parameters(input))
+    }
+
+    /// <p>Deletes a patch baseline.</p>
+    ///
+    /// See `Ssm::delete_patch_baseline`.
+    pub fn delete_patch_baseline(
+        &self,
+        input: DeletePatchBaselineRequest,
+    ) -> Result<DeletePatchBaselineResult, RusotoError<DeletePatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.delete_patch_baseline(input))
+    }
+
+    /// <p>Deletes a Resource Data Sync configuration. After the configuration is deleted, changes to data on managed instances are no longer synced to or from the target. Deleting a sync configuration does not delete data.</p>
+    ///
+    /// See `Ssm::delete_resource_data_sync`.
+    pub fn delete_resource_data_sync(
+        &self,
+        input: DeleteResourceDataSyncRequest,
+    ) -> Result<DeleteResourceDataSyncResult, RusotoError<DeleteResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_data_sync(input))
+    }
+
+    /// <p>Removes the server or virtual machine from the list of registered servers. You can reregister the instance again at any time. If you don't plan to use Run Command on the server, we suggest uninstalling SSM Agent first.</p>
+    ///
+    /// See `Ssm::deregister_managed_instance`.
+    pub fn deregister_managed_instance(
+        &self,
+        input: DeregisterManagedInstanceRequest,
+    ) -> Result<DeregisterManagedInstanceResult, RusotoError<DeregisterManagedInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_managed_instance(input))
+    }
+
+    /// <p>Removes a patch group from a patch baseline.</p>
+    ///
+    /// See `Ssm::deregister_patch_baseline_for_patch_group`.
+    pub fn deregister_patch_baseline_for_patch_group(
+        &self,
+        input: DeregisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        DeregisterPatchBaselineForPatchGroupResult,
+        RusotoError<DeregisterPatchBaselineForPatchGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_patch_baseline_for_patch_group(input))
+    }
+
+    /// <p>Removes a target from a maintenance window.</p>
+    ///
+    /// See `Ssm::deregister_target_from_maintenance_window`.
+    pub fn deregister_target_from_maintenance_window(
+        &self,
+        input: DeregisterTargetFromMaintenanceWindowRequest,
+    ) -> Result<
+        DeregisterTargetFromMaintenanceWindowResult,
+        RusotoError<DeregisterTargetFromMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_target_from_maintenance_window(input))
+    }
+
+    /// <p>Removes a task from a maintenance window.</p>
+    ///
+    /// See `Ssm::deregister_task_from_maintenance_window`.
+    pub fn deregister_task_from_maintenance_window(
+        &self,
+        input: DeregisterTaskFromMaintenanceWindowRequest,
+    ) -> Result<
+        DeregisterTaskFromMaintenanceWindowResult,
+        RusotoError<DeregisterTaskFromMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_task_from_maintenance_window(input))
+    }
+
+    /// <p>Describes details about the activation, such as the date and time the activation was created, its expiration date, the IAM role assigned to the instances in the activation, and the number of instances registered by using this activation.</p>
+    ///
+    /// See `Ssm::describe_activations`.
+    pub fn describe_activations(
+        &self,
+        input: DescribeActivationsRequest,
+    ) -> Result<DescribeActivationsResult, RusotoError<DescribeActivationsError>> {
+        self.runtime
+            .block_on(self.client.describe_activations(input))
+    }
+
+    /// <p>Describes the association for the specified target or instance. If you created the association by using the <code>Targets</code> parameter, then you must retrieve the association by using the association ID. If you created the association by specifying an instance ID and a Systems Manager document, then you retrieve the association by specifying the document name and the instance ID. </p>
+    ///
+    /// See `Ssm::describe_association`.
+    pub fn describe_association(
+        &self,
+        input: DescribeAssociationRequest,
+    ) -> Result<DescribeAssociationResult, RusotoError<DescribeAssociationError>> {
+        self.runtime
+            .block_on(self.client.describe_association(input))
+    }
+
+    /// <p>Use this API action to view information about a specific execution of a specific association.</p>
+    ///
+    /// See `Ssm::describe_association_execution_targets`.
+    pub fn describe_association_execution_targets(
+        &self,
+        input: DescribeAssociationExecutionTargetsRequest,
+    ) -> Result<
+        DescribeAssociationExecutionTargetsResult,
+        RusotoError<DescribeAssociationExecutionTargetsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_association_execution_targets(input))
+    }
+
+    /// <p>Use this API action to view all executions for a specific association ID. </p>
+    ///
+    /// See `Ssm::describe_association_executions`.
+    pub fn describe_association_executions(
+        &self,
+        input: DescribeAssociationExecutionsRequest,
+    ) -> Result<DescribeAssociationExecutionsResult, RusotoError<DescribeAssociationExecutionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_association_executions(input))
+    }
+
+    /// <p>Provides details about all active and terminated Automation executions.</p>
+    ///
+    /// See `Ssm::describe_automation_executions`.
+    pub fn describe_automation_executions(
+        &self,
+        input: DescribeAutomationExecutionsRequest,
+    ) -> Result<DescribeAutomationExecutionsResult, RusotoError<DescribeAutomationExecutionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_automation_executions(input))
+    }
+
+    /// <p>Information about all active and terminated step executions in an Automation workflow.</p>
+    ///
+    /// See `Ssm::describe_automation_step_executions`.
+    pub fn describe_automation_step_executions(
+        &self,
+        input: DescribeAutomationStepExecutionsRequest,
+    ) -> Result<
+        DescribeAutomationStepExecutionsResult,
+        RusotoError<DescribeAutomationStepExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_automation_step_executions(input))
+    }
+
+    /// <p>Lists all patches eligible to be included in a patch baseline.</p>
+    ///
+    /// See `Ssm::describe_available_patches`.
+    pub fn describe_available_patches(
+        &self,
+        input: DescribeAvailablePatchesRequest,
+    ) -> Result<DescribeAvailablePatchesResult, RusotoError<DescribeAvailablePatchesError>> {
+        self.runtime
+            .block_on(self.client.describe_available_patches(input))
+    }
+
+    /// <p>Describes the specified Systems Manager document.</p>
+    ///
+    /// See `Ssm::describe_document`.
+    pub fn describe_document(
+        &self,
+        input: DescribeDocumentRequest,
+    ) -> Result<DescribeDocumentResult, RusotoError<DescribeDocumentError>> {
+        self.runtime.block_on(self.client.describe_document(input))
+    }
+
+    /// <p>Describes the permissions for a Systems Manager document. If you created the document, you are the owner. If a document is shared, it can either be shared privately (by specifying a user's AWS account ID) or publicly (<i>All</i>). </p>
+    ///
+    /// See `Ssm::describe_document_permission`.
+    pub fn describe_document_permission(
+        &self,
+        input: DescribeDocumentPermissionRequest,
+    ) -> Result<DescribeDocumentPermissionResponse, RusotoError<DescribeDocumentPermissionError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_document_permission(input))
+    }
+
+    /// <p>All associations for the instance(s).</p>
+    ///
+    /// See `Ssm::describe_effective_instance_associations`.
+    pub fn describe_effective_instance_associations(
+        &self,
+        input: DescribeEffectiveInstanceAssociationsRequest,
+    ) -> Result<
+        DescribeEffectiveInstanceAssociationsResult,
+        RusotoError<DescribeEffectiveInstanceAssociationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_effective_instance_associations(input))
+    }
+
+    /// <p>Retrieves the current effective patches (the patch and the approval state) for the specified patch baseline. Note that this API applies only to Windows patch baselines.</p>
+    ///
+    /// See `Ssm::describe_effective_patches_for_patch_baseline`.
+    pub fn describe_effective_patches_for_patch_baseline(
+        &self,
+        input: DescribeEffectivePatchesForPatchBaselineRequest,
+    ) -> Result<
+        DescribeEffectivePatchesForPatchBaselineResult,
+        RusotoError<DescribeEffectivePatchesForPatchBaselineError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_effective_patches_for_patch_baseline(input),
+        )
+    }
+
+    /// <p>The status of the associations for the instance(s).</p>
+    ///
+    /// See `Ssm::describe_instance_associations_status`.
+    pub fn describe_instance_associations_status(
+        &self,
+        input: DescribeInstanceAssociationsStatusRequest,
+    ) -> Result<
+        DescribeInstanceAssociationsStatusResult,
+        RusotoError<DescribeInstanceAssociationsStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_instance_associations_status(input))
+    }
+
+    /// <p><p>Describes one or more of your instances, including information about the operating system platform, the version of SSM Agent installed on the instance, instance status, and so on.</p> <p>If you specify one or more instance IDs, it returns information for those instances. If you do not specify instance IDs, it returns information for all your instances. If you specify an instance ID that is not valid or an instance that you do not own, you receive an error.</p> <note> <p>The IamRole field for this API action is the Amazon Identity and Access Management (IAM) role assigned to on-premises instances. This call does not return the IAM role for EC2 instances.</p> </note></p>
+    ///
+    /// See `Ssm::describe_instance_information`.
+    pub fn describe_instance_information(
+        &self,
+        input: DescribeInstanceInformationRequest,
+    ) -> Result<DescribeInstanceInformationResult, RusotoError<DescribeInstanceInformationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_information(input))
+    }
+
+    /// <p>Retrieves the high-level patch state of one or more instances.</p>
+    ///
+    /// See `Ssm::describe_instance_patch_states`.
+    pub fn describe_instance_patch_states(
+        &self,
+        input: DescribeInstancePatchStatesRequest,
+    ) -> Result<DescribeInstancePatchStatesResult, RusotoError<DescribeInstancePatchStatesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_patch_states(input))
+    }
+
+    /// <p>Retrieves the high-level patch state for the instances in the specified patch group.</p>
+    ///
+    /// See `Ssm::describe_instance_patch_states_for_patch_group`.
+    pub fn describe_instance_patch_states_for_patch_group(
+        &self,
+        input: DescribeInstancePatchStatesForPatchGroupRequest,
+    ) -> Result<
+        DescribeInstancePatchStatesForPatchGroupResult,
+        RusotoError<DescribeInstancePatchStatesForPatchGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_instance_patch_states_for_patch_group(input),
+        )
+    }
+
+    /// <p>Retrieves information about the patches on the specified instance and their state relative to the patch baseline being used for the instance.</p>
+    ///
+    /// See `Ssm::describe_instance_patches`.
+    pub fn describe_instance_patches(
+        &self,
+        input: DescribeInstancePatchesRequest,
+    ) -> Result<DescribeInstancePatchesResult, RusotoError<DescribeInstancePatchesError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_patches(input))
+    }
+
+    /// <p>Describes a specific delete inventory operation.</p>
+    ///
+    /// See `Ssm::describe_inventory_deletions`.
+    pub fn describe_inventory_deletions(
+        &self,
+        input: DescribeInventoryDeletionsRequest,
+    ) -> Result<DescribeInventoryDeletionsResult, RusotoError<DescribeInventoryDeletionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_inventory_deletions(input))
+    }
+
+    /// <p>Retrieves the individual task executions (one per target) for a particular task run as part of a maintenance window execution.</p>
+    ///
+    /// See `Ssm::describe_maintenance_window_execution_task_invocations`.
+    pub fn describe_maintenance_window_execution_task_invocations(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTaskInvocationsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionTaskInvocationsResult,
+        RusotoError<DescribeMaintenanceWindowExecutionTaskInvocationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_maintenance_window_execution_task_invocations(input),
+        )
+    }
+
+    /// <p>For a given maintenance window execution, lists the tasks that were run.</p>
+    ///
+    /// See `Ssm::describe_maintenance_window_execution_tasks`.
+    pub fn describe_maintenance_window_execution_tasks(
+        &self,
+        input: DescribeMaintenanceWindowExecutionTasksRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionTasksResult,
+        RusotoError<DescribeMaintenanceWindowExecutionTasksError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_maintenance_window_execution_tasks(input),
+        )
+    }
+
+    /// <p>Lists the executions of a maintenance window. This includes information about when the maintenance window was scheduled to be active, and information about tasks registered and run with the maintenance window.</p>
+    ///
+    /// See `Ssm::describe_maintenance_window_executions`.
+    pub fn describe_maintenance_window_executions(
+        &self,
+        input: DescribeMaintenanceWindowExecutionsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowExecutionsResult,
+        RusotoError<DescribeMaintenanceWindowExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_executions(input))
+    }
+
+    /// <p>Retrieves information about upcoming executions of a maintenance window.</p>
+    ///
+    /// See `Ssm::describe_maintenance_window_schedule`.
+    pub fn describe_maintenance_window_schedule(
+        &self,
+        input: DescribeMaintenanceWindowScheduleRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowScheduleResult,
+        RusotoError<DescribeMaintenanceWindowScheduleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_schedule(input))
+    }
+
+    /// <p>Lists the targets registered with the maintenance window.</p>
+    ///
+    /// See `Ssm::describe_maintenance_window_targets`.
+    pub fn describe_maintenance_window_targets(
+        &self,
+        input: DescribeMaintenanceWindowTargetsRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowTargetsResult,
+        RusotoError<DescribeMaintenanceWindowTargetsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_targets(input))
+    }
+
+    /// <p>Lists the tasks in a maintenance window.</p>
+    ///
+    /// See `Ssm::describe_maintenance_window_tasks`.
+    pub fn describe_maintenance_window_tasks(
+        &self,
+        input: DescribeMaintenanceWindowTasksRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowTasksResult,
+        RusotoError<DescribeMaintenanceWindowTasksError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_window_tasks(input))
+    }
+
+    /// <p>Retrieves the maintenance windows in an AWS account.</p>
+    ///
+    /// See `Ssm::describe_maintenance_windows`.
+    pub fn describe_maintenance_windows(
+        &self,
+        input: DescribeMaintenanceWindowsRequest,
+    ) -> Result<DescribeMaintenanceWindowsResult, RusotoError<DescribeMaintenanceWindowsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_maintenance_windows(input))
+    }
+
+    /// <p>Retrieves information about the maintenance window targets or tasks that an instance is associated with.</p>
+    ///
+    /// See `Ssm::describe_maintenance_windows_for_target`.
+    pub fn describe_maintenance_windows_for_target(
+        &self,
+        input: DescribeMaintenanceWindowsForTargetRequest,
+    ) -> Result<
+        DescribeMaintenanceWindowsForTargetResult,
+        RusotoError<DescribeMaintenanceWindowsForTargetError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_maintenance_windows_for_target(input))
+    }
+
+    /// <p>Query a set of OpsItems. You must have permission in AWS Identity and Access Management (IAM) to query a list of OpsItems. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    ///
+    /// See `Ssm::describe_ops_items`.
+    pub fn describe_ops_items(
+        &self,
+        input: DescribeOpsItemsRequest,
+    ) -> Result<DescribeOpsItemsResponse, RusotoError<DescribeOpsItemsError>> {
+        self.runtime.block_on(self.client.describe_ops_items(input))
+    }
+
+    /// <p><p>Get information about a parameter.</p> <note> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> </note></p>
+    ///
+    /// See `Ssm::describe_parameters`.
+    pub fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<DescribeParametersResult, RusotoError<DescribeParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_parameters(input))
+    }
+
+    /// <p>Lists the patch baselines in your AWS account.</p>
+    ///
+    /// See `Ssm::describe_patch_baselines`.
+    pub fn describe_patch_baselines(
+        &self,
+        input: DescribePatchBaselinesRequest,
+    ) -> Result<DescribePatchBaselinesResult, RusotoError<DescribePatchBaselinesError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_baselines(input))
+    }
+
+    /// <p>Returns high-level aggregated patch compliance state for a patch group.</p>
+    ///
+    /// See `Ssm::describe_patch_group_state`.
+    pub fn describe_patch_group_state(
+        &self,
+        input: DescribePatchGroupStateRequest,
+    ) -> Result<DescribePatchGroupStateResult, RusotoError<DescribePatchGroupStateError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_group_state(input))
+    }
+
+    /// <p>Lists all patch groups that have been registered with patch baselines.</p>
+    ///
+    /// See `Ssm::describe_patch_groups`.
+    pub fn describe_patch_groups(
+        &self,
+        input: DescribePatchGroupsRequest,
+    ) -> Result<DescribePatchGroupsResult, RusotoError<DescribePatchGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_groups(input))
+    }
+
+    /// <p><p>Lists the properties of available patches organized by product, product family, classification, severity, and other properties of available patches. You can use the reported properties in the filters you specify in requests for actions such as <a>CreatePatchBaseline</a>, <a>UpdatePatchBaseline</a>, <a>DescribeAvailablePatches</a>, and <a>DescribePatchBaselines</a>.</p> <p>The following section lists the properties that can be used in filters for each major operating system type:</p> <dl> <dt>WINDOWS</dt> <dd> <p>Valid properties: PRODUCT, PRODUCT<em>FAMILY, CLASSIFICATION, MSRC</em>SEVERITY</p> </dd> <dt>AMAZON<em>LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>AMAZON</em>LINUX<em>2</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>UBUNTU </dt> <dd> <p>Valid properties: PRODUCT, PRIORITY</p> </dd> <dt>REDHAT</em>ENTERPRISE_LINUX</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>SUSE</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> <dt>CENTOS</dt> <dd> <p>Valid properties: PRODUCT, CLASSIFICATION, SEVERITY</p> </dd> </dl></p>
+    ///
+    /// See `Ssm::describe_patch_properties`.
+    pub fn describe_patch_properties(
+        &self,
+        input: DescribePatchPropertiesRequest,
+    ) -> Result<DescribePatchPropertiesResult, RusotoError<DescribePatchPropertiesError>> {
+        self.runtime
+            .block_on(self.client.describe_patch_properties(input))
+    }
+
+    /// <p>Retrieves a list of all active sessions (both connected and disconnected) or terminated sessions from the past 30 days.</p>
+    ///
+    /// See `Ssm::describe_sessions`.
+    pub fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<DescribeSessionsResponse, RusotoError<DescribeSessionsError>> {
+        self.runtime.block_on(self.client.describe_sessions(input))
+    }
+
+    /// <p>Get detailed information about a particular Automation execution.</p>
+    ///
+    /// See `Ssm::get_automation_execution`.
+    pub fn get_automation_execution(
+        &self,
+        input: GetAutomationExecutionRequest,
+    ) -> Result<GetAutomationExecutionResult, RusotoError<GetAutomationExecutionError>> {
+        self.runtime
+            .block_on(self.client.get_automation_execution(input))
+    }
+
+    /// <p>Gets the state of the AWS Systems Manager Change Calendar at an optional, specified time. If you specify a time, <code>GetCalendarState</code> returns the state of the calendar at a specific time, and returns the next time that the Change Calendar state will transition. If you do not specify a time, <code>GetCalendarState</code> assumes the current time. Change Calendar entries have two possible states: <code>OPEN</code> or <code>CLOSED</code>. For more information about Systems Manager Change Calendar, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/systems-manager-change-calendar.html">AWS Systems Manager Change Calendar</a> in the <i>AWS Systems Manager User Guide</i>.</p>
+    ///
+    /// See `Ssm::get_calendar_state`.
+    pub fn get_calendar_state(
+        &self,
+        input: GetCalendarStateRequest,
+    ) -> Result<GetCalendarStateResponse, RusotoError<GetCalendarStateError>> {
+        self.runtime.block_on(self.client.get_calendar_state(input))
+    }
+
+    /// <p>Returns detailed information about command execution for an invocation or plugin. </p>
+    ///
+    /// See `Ssm::get_command_invocation`.
+    pub fn get_command_invocation(
+        &self,
+        input: GetCommandInvocationRequest,
+    ) -> Result<GetCommandInvocationResult, RusotoError<GetCommandInvocationError>> {
+        self.runtime
+            .block_on(self.client.get_command_invocation(input))
+    }
+
+    /// <p>Retrieves the Session Manager connection status for an instance to determine whether it is running and ready to receive Session Manager connections.</p>
+    ///
+    /// See `Ssm::get_connection_status`.
+    pub fn get_connection_status(
+        &self,
+        input: GetConnectionStatusRequest,
+    ) -> Result<GetConnectionStatusResponse, RusotoError<GetConnectionStatusError>> {
+        self.runtime
+            .block_on(self.client.get_connection_status(input))
+    }
+
+    /// <p>Retrieves the default patch baseline. Note that Systems Manager supports creating multiple default patch baselines. For example, you can create a default patch baseline for each operating system.</p> <p>If you do not specify an operating system value, the default patch baseline for Windows is returned.</p>
+    ///
+    /// See `Ssm::get_default_patch_baseline`.
+    pub fn get_default_patch_baseline(
+        &self,
+        input: GetDefaultPatchBaselineRequest,
+    ) -> Result<GetDefaultPatchBaselineResult, RusotoError<GetDefaultPatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.get_default_patch_baseline(input))
+    }
+
+    /// <p>Retrieves the current snapshot for the patch baseline the instance uses. This API is primarily used by the AWS-RunPatchBaseline Systems Manager document. </p>
+    ///
+    /// See `Ssm::get_deployable_patch_snapshot_for_instance`.
+    pub fn get_deployable_patch_snapshot_for_instance(
+        &self,
+        input: GetDeployablePatchSnapshotForInstanceRequest,
+    ) -> Result<
+        GetDeployablePatchSnapshotForInstanceResult,
+        RusotoError<GetDeployablePatchSnapshotForInstanceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_deployable_patch_snapshot_for_instance(input),
+        )
+    }
+
+    /// <p>Gets the contents of the specified Systems Manager document.</p>
+    ///
+    /// See `Ssm::get_document`.
+    pub fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> Result<GetDocumentResult, RusotoError<GetDocumentError>> {
+        self.runtime.block_on(self.client.get_document(input))
+    }
+
+    /// <p>Query inventory information.</p>
+    ///
+    /// See `Ssm::get_inventory`.
+    pub fn get_inventory(
+        &self,
+        input: GetInventoryRequest,
+    ) -> Result<GetInventoryResult, RusotoError<GetInventoryError>> {
+        self.runtime.block_on(self.client.get_inventory(input))
+    }
+
+    /// <p>Return a list of inventory type names for the account, or return a list of attribute names for a specific Inventory item type.</p>
+    ///
+    /// See `Ssm::get_inventory_schema`.
+    pub fn get_inventory_schema(
+        &self,
+        input: GetInventorySchemaRequest,
+    ) -> Result<GetInventorySchemaResult, RusotoError<GetInventorySchemaError>> {
+        self.runtime
+            .block_on(self.client.get_inventory_schema(input))
+    }
+
+    /// <p>Retrieves a maintenance window.</p>
+    ///
+    /// See `Ssm::get_maintenance_window`.
+    pub fn get_maintenance_window(
+        &self,
+        input: GetMaintenanceWindowRequest,
+    ) -> Result<GetMaintenanceWindowResult, RusotoError<GetMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.get_maintenance_window(input))
+    }
+
+    /// <p>Retrieves details about a specific a maintenance window execution.</p>
+    ///
+    /// See `Ssm::get_maintenance_window_execution`.
+    pub fn get_maintenance_window_execution(
+        &self,
+        input: GetMaintenanceWindowExecutionRequest,
+    ) -> Result<GetMaintenanceWindowExecutionResult, RusotoError<GetMaintenanceWindowExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_maintenance_window_execution(input))
+    }
+
+    /// <p>Retrieves the details about a specific task run as part of a maintenance window execution.</p>
+    ///
+    /// See `Ssm::get_maintenance_window_execution_task`.
+    pub fn get_maintenance_window_execution_task(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskRequest,
+    ) -> Result<
+        GetMaintenanceWindowExecutionTaskResult,
+        RusotoError<GetMaintenanceWindowExecutionTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_maintenance_window_execution_task(input))
+    }
+
+    /// <p>Retrieves information about a specific task running on a specific target.</p>
+    ///
+    /// See `Ssm::get_maintenance_window_execution_task_invocation`.
+    pub fn get_maintenance_window_execution_task_invocation(
+        &self,
+        input: GetMaintenanceWindowExecutionTaskInvocationRequest,
+    ) -> Result<
+        GetMaintenanceWindowExecutionTaskInvocationResult,
+        RusotoError<GetMaintenanceWindowExecutionTaskInvocationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_maintenance_window_execution_task_invocation(input),
+        )
+    }
+
+    /// <p>Lists the tasks in a maintenance window.</p>
+    ///
+    /// See `Ssm::get_maintenance_window_task`.
+    pub fn get_maintenance_window_task(
+        &self,
+        input: GetMaintenanceWindowTaskRequest,
+    ) -> Result<GetMaintenanceWindowTaskResult, RusotoError<GetMaintenanceWindowTaskError>> {
+        self.runtime
+            .block_on(self.client.get_maintenance_window_task(input))
+    }
+
+    /// <p>Get information about an OpsItem by using the ID. You must have permission in AWS Identity and Access Management (IAM) to view information about an OpsItem. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    ///
+    /// See `Ssm::get_ops_item`.
+    pub fn get_ops_item(
+        &self,
+        input: GetOpsItemRequest,
+    ) -> Result<GetOpsItemResponse, RusotoError<GetOpsItemError>> {
+        self.runtime.block_on(self.client.get_ops_item(input))
+    }
+
+    /// <p>View a summary of OpsItems based on specified filters and aggregators.</p>
+    ///
+    /// See `Ssm::get_ops_summary`.
+    pub fn get_ops_summary(
+        &self,
+        input: GetOpsSummaryRequest,
+    ) -> Result<GetOpsSummaryResult, RusotoError<GetOpsSummaryError>> {
+        self.runtime.block_on(self.client.get_ops_summary(input))
+    }
+
+    /// <p>Get information about a parameter by using the parameter name. Don't confuse this API action with the <a>GetParameters</a> API action.</p>
+    ///
+    /// See `Ssm::get_parameter`.
+    pub fn get_parameter(
+        &self,
+        input: GetParameterRequest,
+    ) -> Result<GetParameterResult, RusotoError<GetParameterError>> {
+        self.runtime.block_on(self.client.get_parameter(input))
+    }
+
+    /// <p>Query a list of all parameters used by the AWS account.</p>
+    ///
+    /// See `Ssm::get_parameter_history`.
+    pub fn get_parameter_history(
+        &self,
+        input: GetParameterHistoryRequest,
+    ) -> Result<GetParameterHistoryResult, RusotoError<GetParameterHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_parameter_history(input))
+    }
+
+    /// <p>Get details of a parameter. Don't confuse this API action with the <a>GetParameter</a> API action.</p>
+    ///
+    /// See `Ssm::get_parameters`.
+    pub fn get_parameters(
+        &self,
+        input: GetParametersRequest,
+    ) -> Result<GetParametersResult, RusotoError<GetParametersError>> {
+        self.runtime.block_on(self.client.get_parameters(input))
+    }
+
+    /// <p><p>Retrieve information about one or more parameters in a specific hierarchy. </p> <note> <p>Request results are returned on a best-effort basis. If you specify <code>MaxResults</code> in the request, the response includes information up to the limit specified. The number of items returned, however, can be between zero and the value of <code>MaxResults</code>. If the service reaches an internal limit while processing the results, it stops the operation and returns the matching values up to that point and a <code>NextToken</code>. You can specify the <code>NextToken</code> in a subsequent call to get the next set of results.</p> </note></p>
+    ///
+    /// See `Ssm::get_parameters_by_path`.
+    pub fn get_parameters_by_path(
+        &self,
+        input: GetParametersByPathRequest,
+    ) -> Result<GetParametersByPathResult, RusotoError<GetParametersByPathError>> {
+        self.runtime
+            .block_on(self.client.get_parameters_by_path(input))
+    }
+
+    /// <p>Retrieves information about a patch baseline.</p>
+    ///
+    /// See `Ssm::get_patch_baseline`.
+    pub fn get_patch_baseline(
+        &self,
+        input: GetPatchBaselineRequest,
+    ) -> Result<GetPatchBaselineResult, RusotoError<GetPatchBaselineError>> {
+        self.runtime.block_on(self.client.get_patch_baseline(input))
+    }
+
+    /// <p>Retrieves the patch baseline that should be used for the specified patch group.</p>
+    ///
+    /// See `Ssm::get_patch_baseline_for_patch_group`.
+    pub fn get_patch_baseline_for_patch_group(
+        &self,
+        input: GetPatchBaselineForPatchGroupRequest,
+    ) -> Result<GetPatchBaselineForPatchGroupResult, RusotoError<GetPatchBaselineForPatchGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.get_patch_baseline_for_patch_group(input))
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>UpdateServiceSetting</a> API action to change the default setting. Or use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Query the current service setting for the account. </p>
+    ///
+    /// See `Ssm::get_service_setting`.
+    pub fn get_service_setting(
+        &self,
+        input: GetServiceSettingRequest,
+    ) -> Result<GetServiceSettingResult, RusotoError<GetServiceSettingError>> {
+        self.runtime
+            .block_on(self.client.get_service_setting(input))
+    }
+
+    /// <p><p>A parameter label is a user-defined alias to help you manage different versions of a parameter. When you modify a parameter, Systems Manager automatically saves a new version and increments the version number by one. A label can help you remember the purpose of a parameter when there are multiple versions. </p> <p>Parameter labels have the following requirements and restrictions.</p> <ul> <li> <p>A version of a parameter can have a maximum of 10 labels.</p> </li> <li> <p>You can&#39;t attach the same label to different versions of the same parameter. For example, if version 1 has the label Production, then you can&#39;t attach Production to version 2.</p> </li> <li> <p>You can move a label from one version of a parameter to another.</p> </li> <li> <p>You can&#39;t create a label when you create a new parameter. You must attach a label to a specific version of a parameter.</p> </li> <li> <p>You can&#39;t delete a parameter label. If you no longer want to use a parameter label, then you must move it to a different version of a parameter.</p> </li> <li> <p>A label can have a maximum of 100 characters.</p> </li> <li> <p>Labels can contain letters (case sensitive), numbers, periods (.), hyphens (-), or underscores (_).</p> </li> <li> <p>Labels can&#39;t begin with a number, &quot;aws,&quot; or &quot;ssm&quot; (not case sensitive). If a label fails to meet these requirements, then the label is not associated with a parameter and the system displays it in the list of InvalidLabels.</p> </li> </ul></p>
+    ///
+    /// See `Ssm::label_parameter_version`.
+    pub fn label_parameter_version(
+        &self,
+        input: LabelParameterVersionRequest,
+    ) -> Result<LabelParameterVersionResult, RusotoError<LabelParameterVersionError>> {
+        self.runtime
+            .block_on(self.client.label_parameter_version(input))
+    }
+
+    /// <p>Retrieves all versions of an association for a specific association ID.</p>
+    ///
+    /// See `Ssm::list_association_versions`.
+    pub fn list_association_versions(
+        &self,
+        input: ListAssociationVersionsRequest,
+    ) -> Result<ListAssociationVersionsResult, RusotoError<ListAssociationVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_association_versions(input))
+    }
+
+    /// <p>Returns all State Manager associations in the current AWS account and Region. You can limit the results to a specific State Manager association document or instance by specifying a filter.</p>
+    ///
+    /// See `Ssm::list_associations`.
+    pub fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> Result<ListAssociationsResult, RusotoError<ListAssociationsError>> {
+        self.runtime.block_on(self.client.list_associations(input))
+    }
+
+    /// <p>An invocation is copy of a command sent to a specific instance. A command can apply to one or more instances. A command invocation applies to one instance. For example, if a user runs SendCommand against three instances, then a command invocation is created for each requested instance ID. ListCommandInvocations provide status about command execution.</p>
+    ///
+    /// See `Ssm::list_command_invocations`.
+    pub fn list_command_invocations(
+        &self,
+        input: ListCommandInvocationsRequest,
+    ) -> Result<ListCommandInvocationsResult, RusotoError<ListCommandInvocationsError>> {
+        self.runtime
+            .block_on(self.client.list_command_invocations(input))
+    }
+
+    /// <p>Lists the commands requested by users of the AWS account.</p>
+    ///
+    /// See `Ssm::list_commands`.
+    pub fn list_commands(
+        &self,
+        input: ListCommandsRequest,
+    ) -> Result<ListCommandsResult, RusotoError<ListCommandsError>> {
+        self.runtime.block_on(self.client.list_commands(input))
+    }
+
+    /// <p>For a specified resource ID, this API action returns a list of compliance statuses for different resource types. Currently, you can only specify one resource ID per call. List results depend on the criteria specified in the filter.</p>
+    ///
+    /// See `Ssm::list_compliance_items`.
+    pub fn list_compliance_items(
+        &self,
+        input: ListComplianceItemsRequest,
+    ) -> Result<ListComplianceItemsResult, RusotoError<ListComplianceItemsError>> {
+        self.runtime
+            .block_on(self.client.list_compliance_items(input))
+    }
+
+    /// <p>Returns a summary count of compliant and non-compliant resources for a compliance type. For example, this call can return State Manager associations, patches, or custom compliance types according to the filter criteria that you specify.</p>
+    ///
+    /// See `Ssm::list_compliance_summaries`.
+    pub fn list_compliance_summaries(
+        &self,
+        input: ListComplianceSummariesRequest,
+    ) -> Result<ListComplianceSummariesResult, RusotoError<ListComplianceSummariesError>> {
+        self.runtime
+            .block_on(self.client.list_compliance_summaries(input))
+    }
+
+    /// <p>List all versions for a document.</p>
+    ///
+    /// See `Ssm::list_document_versions`.
+    pub fn list_document_versions(
+        &self,
+        input: ListDocumentVersionsRequest,
+    ) -> Result<ListDocumentVersionsResult, RusotoError<ListDocumentVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_document_versions(input))
+    }
+
+    /// <p>Returns all Systems Manager (SSM) documents in the current AWS account and Region. You can limit the results of this request by using a filter.</p>
+    ///
+    /// See `Ssm::list_documents`.
+    pub fn list_documents(
+        &self,
+        input: ListDocumentsRequest,
+    ) -> Result<ListDocumentsResult, RusotoError<ListDocumentsError>> {
+        self.runtime.block_on(self.client.list_documents(input))
+    }
+
+    /// <p>A list of inventory items returned by the request.</p>
+    ///
+    /// See `Ssm::list_inventory_entries`.
+    pub fn list_inventory_entries(
+        &self,
+        input: ListInventoryEntriesRequest,
+    ) -> Result<ListInventoryEntriesResult, RusotoError<ListInventoryEntriesError>> {
+        self.runtime
+            .block_on(self.client.list_inventory_entries(input))
+    }
+
+    /// <p>Returns a resource-level summary count. The summary includes information about compliant and non-compliant statuses and detailed compliance-item severity counts, according to the filter criteria you specify.</p>
+    ///
+    /// See `Ssm::list_resource_compliance_summaries`.
+    pub fn list_resource_compliance_summaries(
+        &self,
+        input: ListResourceComplianceSummariesRequest,
+    ) -> Result<
+        ListResourceComplianceSummariesResult,
+        RusotoError<ListResourceComplianceSummariesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resource_compliance_summaries(input))
+    }
+
+    /// <p>Lists your resource data sync configurations. Includes information about the last time a sync attempted to start, the last sync status, and the last time a sync successfully completed.</p> <p>The number of sync configurations might be too large to return using a single call to <code>ListResourceDataSync</code>. You can limit the number of sync configurations returned by using the <code>MaxResults</code> parameter. To determine whether there are more sync configurations to list, check the value of <code>NextToken</code> in the output. If there are more sync configurations to list, you can request them by specifying the <code>NextToken</code> returned in the call to the parameter of a subsequent call. </p>
+    ///
+    /// See `Ssm::list_resource_data_sync`.
+    pub fn list_resource_data_sync(
+        &self,
+        input: ListResourceDataSyncRequest,
+    ) -> Result<ListResourceDataSyncResult, RusotoError<ListResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.list_resource_data_sync(input))
+    }
+
+    /// <p>Returns a list of the tags assigned to the specified resource.</p>
+    ///
+    /// See `Ssm::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Shares a Systems Manager document publicly or privately. If you share a document privately, you must specify the AWS user account IDs for those people who can use the document. If you share a document publicly, you must specify <i>All</i> as the account ID.</p>
+    ///
+    /// See `Ssm::modify_document_permission`.
+    pub fn modify_document_permission(
+        &self,
+        input: ModifyDocumentPermissionRequest,
+    ) -> Result<ModifyDocumentPermissionResponse, RusotoError<ModifyDocumentPermissionError>> {
+        self.runtime
+            .block_on(self.client.modify_document_permission(input))
+    }
+
+    /// <p><p>Registers a compliance type and other compliance details on a designated resource. This action lets you register custom compliance details with a resource. This call overwrites existing compliance information on the resource, so you must provide a full list of compliance items each time that you send the request.</p> <p>ComplianceType can be one of the following:</p> <ul> <li> <p>ExecutionId: The execution ID when the patch, association, or custom compliance item was applied.</p> </li> <li> <p>ExecutionType: Specify patch, association, or Custom:<code>string</code>.</p> </li> <li> <p>ExecutionTime. The time the patch, association, or custom compliance item was applied to the instance.</p> </li> <li> <p>Id: The patch, association, or custom compliance ID.</p> </li> <li> <p>Title: A title.</p> </li> <li> <p>Status: The status of the compliance item. For example, <code>approved</code> for patches, or <code>Failed</code> for associations.</p> </li> <li> <p>Severity: A patch severity. For example, <code>critical</code>.</p> </li> <li> <p>DocumentName: A SSM document name. For example, AWS-RunPatchBaseline.</p> </li> <li> <p>DocumentVersion: An SSM document version number. For example, 4.</p> </li> <li> <p>Classification: A patch classification. For example, <code>security updates</code>.</p> </li> <li> <p>PatchBaselineId: A patch baseline ID.</p> </li> <li> <p>PatchSeverity: A patch severity. For example, <code>Critical</code>.</p> </li> <li> <p>PatchState: A patch state. For example, <code>InstancesWithFailedPatches</code>.</p> </li> <li> <p>PatchGroup: The name of a patch group.</p> </li> <li> <p>InstalledTime: The time the association, patch, or custom compliance item was applied to the resource. Specify the time by using the following format: yyyy-MM-dd&#39;T&#39;HH:mm:ss&#39;Z&#39;</p> </li> </ul></p>
+    ///
+    /// See `Ssm::put_compliance_items`.
+    pub fn put_compliance_items(
+        &self,
+        input: PutComplianceItemsRequest,
+    ) -> Result<PutComplianceItemsResult, RusotoError<PutComplianceItemsError>> {
+        self.runtime
+            .block_on(self.client.put_compliance_items(input))
+    }
+
+    /// <p>Bulk update custom inventory items on one more instance. The request adds an inventory item, if it doesn't already exist, or updates an inventory item, if it does exist.</p>
+    ///
+    /// See `Ssm::put_inventory`.
+    pub fn put_inventory(
+        &self,
+        input: PutInventoryRequest,
+    ) -> Result<PutInventoryResult, RusotoError<PutInventoryError>> {
+        self.runtime.block_on(self.client.put_inventory(input))
+    }
+
+    /// <p>Add a parameter to the system.</p>
+    ///
+    /// See `Ssm::put_parameter`.
+    pub fn put_parameter(
+        &self,
+        input: PutParameterRequest,
+    ) -> Result<PutParameterResult, RusotoError<PutParameterError>> {
+        self.runtime.block_on(self.client.put_parameter(input))
+    }
+
+    /// <p>Defines the default patch baseline for the relevant operating system.</p> <p>To reset the AWS predefined patch baseline as the default, specify the full patch baseline ARN as the baseline ID value. For example, for CentOS, specify <code>arn:aws:ssm:us-east-2:733109147000:patchbaseline/pb-0574b43a65ea646ed</code> instead of <code>pb-0574b43a65ea646ed</code>.</p>
+    ///
+    /// See `Ssm::register_default_patch_baseline`.
+    pub fn register_default_patch_baseline(
+        &self,
+        input: RegisterDefaultPatchBaselineRequest,
+    ) -> Result<RegisterDefaultPatchBaselineResult, RusotoError<RegisterDefaultPatchBaselineError>>
+    {
+        self.runtime
+            .block_on(self.client.register_default_patch_baseline(input))
+    }
+
+    /// <p>Registers a patch baseline for a patch group.</p>
+    ///
+    /// See `Ssm::register_patch_baseline_for_patch_group`.
+    pub fn register_patch_baseline_for_patch_group(
+        &self,
+        input: RegisterPatchBaselineForPatchGroupRequest,
+    ) -> Result<
+        RegisterPatchBaselineForPatchGroupResult,
+        RusotoError<RegisterPatchBaselineForPatchGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.register_patch_baseline_for_patch_group(input))
+    }
+
+    /// <p>Registers a target with a maintenance window.</p>
+    ///
+    /// See `Ssm::register_target_with_maintenance_window`.
+    pub fn register_target_with_maintenance_window(
+        &self,
+        input: RegisterTargetWithMaintenanceWindowRequest,
+    ) -> Result<
+        RegisterTargetWithMaintenanceWindowResult,
+        RusotoError<RegisterTargetWithMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.register_target_with_maintenance_window(input))
+    }
+
+    /// <p>Adds a new task to a maintenance window.</p>
+    ///
+    /// See `Ssm::register_task_with_maintenance_window`.
+    pub fn register_task_with_maintenance_window(
+        &self,
+        input: RegisterTaskWithMaintenanceWindowRequest,
+    ) -> Result<
+        RegisterTaskWithMaintenanceWindowResult,
+        RusotoError<RegisterTaskWithMaintenanceWindowError>,
+    > {
+        self.runtime
+            .block_on(self.client.register_task_with_maintenance_window(input))
+    }
+
+    /// <p>Removes tag keys from the specified resource.</p>
+    ///
+    /// See `Ssm::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Use the <a>UpdateServiceSetting</a> API action to change the default setting. </p> <p>Reset the service setting for the account to the default value as provisioned by the AWS service team. </p>
+    ///
+    /// See `Ssm::reset_service_setting`.
+    pub fn reset_service_setting(
+        &self,
+        input: ResetServiceSettingRequest,
+    ) -> Result<ResetServiceSettingResult, RusotoError<ResetServiceSettingError>> {
+        self.runtime
+            .block_on(self.client.reset_service_setting(input))
+    }
+
+    /// <p><p>Reconnects a session to an instance after it has been disconnected. Connections can be resumed for disconnected sessions, but not terminated sessions.</p> <note> <p>This command is primarily for use by client machines to automatically reconnect during intermittent network issues. It is not intended for any other use.</p> </note></p>
+    ///
+    /// See `Ssm::resume_session`.
+    pub fn resume_session(
+        &self,
+        input: ResumeSessionRequest,
+    ) -> Result<ResumeSessionResponse, RusotoError<ResumeSessionError>> {
+        self.runtime.block_on(self.client.resume_session(input))
+    }
+
+    /// <p>Sends a signal to an Automation execution to change the current behavior or status of the execution. </p>
+    ///
+    /// See `Ssm::send_automation_signal`.
+    pub fn send_automation_signal(
+        &self,
+        input: SendAutomationSignalRequest,
+    ) -> Result<SendAutomationSignalResult, RusotoError<SendAutomationSignalError>> {
+        self.runtime
+            .block_on(self.client.send_automation_signal(input))
+    }
+
+    /// <p>Runs commands on one or more managed instances.</p>
+    ///
+    /// See `Ssm::send_command`.
+    pub fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+        self.runtime.block_on(self.client.send_command(input))
+    }
+
+    /// <p>Use this API action to run an association immediately and only one time. This action can be helpful when troubleshooting associations.</p>
+    ///
+    /// See `Ssm::start_associations_once`.
+    pub fn start_associations_once(
+        &self,
+        input: StartAssociationsOnceRequest,
+    ) -> Result<StartAssociationsOnceResult, RusotoError<StartAssociationsOnceError>> {
+        self.runtime
+            .block_on(self.client.start_associations_once(input))
+    }
+
+    /// <p>Initiates execution of an Automation document.</p>
+    ///
+    /// See `Ssm::start_automation_execution`.
+    pub fn start_automation_execution(
+        &self,
+        input: StartAutomationExecutionRequest,
+    ) -> Result<StartAutomationExecutionResult, RusotoError<StartAutomationExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_automation_execution(input))
+    }
+
+    /// <p><p>Initiates a connection to a target (for example, an instance) for a Session Manager session. Returns a URL and token that can be used to open a WebSocket connection for sending input and receiving outputs.</p> <note> <p>AWS CLI usage: <code>start-session</code> is an interactive command that requires the Session Manager plugin to be installed on the client machine making the call. For information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-working-with-install-plugin.html">Install the Session Manager plugin for the AWS CLI</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>AWS Tools for PowerShell usage: Start-SSMSession is not currently supported by AWS Tools for PowerShell on Windows local machines.</p> </note></p>
+    ///
+    /// See `Ssm::start_session`.
+    pub fn start_session(
+        &self,
+        input: StartSessionRequest,
+    ) -> Result<StartSessionResponse, RusotoError<StartSessionError>> {
+        self.runtime.block_on(self.client.start_session(input))
+    }
+
+    /// <p>Stop an Automation that is currently running.</p>
+    ///
+    /// See `Ssm::stop_automation_execution`.
+    pub fn stop_automation_execution(
+        &self,
+        input: StopAutomationExecutionRequest,
+    ) -> Result<StopAutomationExecutionResult, RusotoError<StopAutomationExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_automation_execution(input))
+    }
+
+    /// <p>Permanently ends a session and closes the data connection between the Session Manager client and SSM Agent on the instance. A terminated session cannot be resumed.</p>
+    ///
+    /// See `Ssm::terminate_session`.
+    pub fn terminate_session(
+        &self,
+        input: TerminateSessionRequest,
+    ) -> Result<TerminateSessionResponse, RusotoError<TerminateSessionError>> {
+        self.runtime.block_on(self.client.terminate_session(input))
+    }
+
+    /// <p><p>Updates an association. You can update the association name and version, the document version, schedule, parameters, and Amazon S3 output. </p> <p>In order to call this API action, your IAM user account, group, or role must be configured with permission to call the <a>DescribeAssociation</a> API action. If you don&#39;t have permission to call DescribeAssociation, then you receive the following error: <code>An error occurred (AccessDeniedException) when calling the UpdateAssociation operation: User: &lt;user<em>arn&gt; is not authorized to perform: ssm:DescribeAssociation on resource: &lt;resource</em>arn&gt;</code> </p> <important> <p>When you update an association, the association immediately runs against the specified targets.</p> </important></p>
+    ///
+    /// See `Ssm::update_association`.
+    pub fn update_association(
+        &self,
+        input: UpdateAssociationRequest,
+    ) -> Result<UpdateAssociationResult, RusotoError<UpdateAssociationError>> {
+        self.runtime.block_on(self.client.update_association(input))
+    }
+
+    /// <p>Updates the status of the Systems Manager document associated with the specified instance.</p>
+    ///
+    /// See `Ssm::update_association_status`.
+    pub fn update_association_status(
+        &self,
+        input: UpdateAssociationStatusRequest,
+    ) -> Result<UpdateAssociationStatusResult, RusotoError<UpdateAssociationStatusError>> {
+        self.runtime
+            .block_on(self.client.update_association_status(input))
+    }
+
+    /// <p>Updates one or more values for an SSM document.</p>
+    ///
+    /// See `Ssm::update_document`.
+    pub fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> Result<UpdateDocumentResult, RusotoError<UpdateDocumentError>> {
+        self.runtime.block_on(self.client.update_document(input))
+    }
+
+    /// <p>Set the default version of a document. </p>
+    ///
+    /// See `Ssm::update_document_default_version`.
+    pub fn update_document_default_version(
+        &self,
+        input: UpdateDocumentDefaultVersionRequest,
+    ) -> Result<UpdateDocumentDefaultVersionResult, RusotoError<UpdateDocumentDefaultVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_document_default_version(input))
+    }
+
+    /// <p><p>Updates an existing maintenance window. Only specified parameters are modified.</p> <note> <p>The value you specify for <code>Duration</code> determines the specific end time for the maintenance window based on the time it begins. No maintenance window tasks are permitted to start after the resulting endtime minus the number of hours you specify for <code>Cutoff</code>. For example, if the maintenance window starts at 3 PM, the duration is three hours, and the value you specify for <code>Cutoff</code> is one hour, no maintenance window tasks can start after 5 PM.</p> </note></p>
+    ///
+    /// See `Ssm::update_maintenance_window`.
+    pub fn update_maintenance_window(
+        &self,
+        input: UpdateMaintenanceWindowRequest,
+    ) -> Result<UpdateMaintenanceWindowResult, RusotoError<UpdateMaintenanceWindowError>> {
+        self.runtime
+            .block_on(self.client.update_maintenance_window(input))
+    }
+
+    /// <p><p>Modifies the target of an existing maintenance window. You can change the following:</p> <ul> <li> <p>Name</p> </li> <li> <p>Description</p> </li> <li> <p>Owner</p> </li> <li> <p>IDs for an ID target</p> </li> <li> <p>Tags for a Tag target</p> </li> <li> <p>From any supported tag type to another. The three supported tag types are ID target, Tag target, and resource group. For more information, see <a>Target</a>.</p> </li> </ul> <note> <p>If a parameter is null, then the corresponding field is not modified.</p> </note></p>
+    ///
+    /// See `Ssm::update_maintenance_window_target`.
+    pub fn update_maintenance_window_target(
+        &self,
+        input: UpdateMaintenanceWindowTargetRequest,
+    ) -> Result<UpdateMaintenanceWindowTargetResult, RusotoError<UpdateMaintenanceWindowTargetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_maintenance_window_target(input))
+    }
+
+    /// <p>Modifies a task assigned to a maintenance window. You can't change the task type, but you can change the following values:</p> <ul> <li> <p>TaskARN. For example, you can change a RUN_COMMAND task from AWS-RunPowerShellScript to AWS-RunShellScript.</p> </li> <li> <p>ServiceRoleArn</p> </li> <li> <p>TaskInvocationParameters</p> </li> <li> <p>Priority</p> </li> <li> <p>MaxConcurrency</p> </li> <li> <p>MaxErrors</p> </li> </ul> <p>If a parameter is null, then the corresponding field is not modified. Also, if you set Replace to true, then all fields required by the <a>RegisterTaskWithMaintenanceWindow</a> action are required for this request. Optional fields that aren't specified are set to null.</p>
+    ///
+    /// See `Ssm::update_maintenance_window_task`.
+    pub fn update_maintenance_window_task(
+        &self,
+        input: UpdateMaintenanceWindowTaskRequest,
+    ) -> Result<UpdateMaintenanceWindowTaskResult, RusotoError<UpdateMaintenanceWindowTaskError>>
+    {
+        self.runtime
+            .block_on(self.client.update_maintenance_window_task(input))
+    }
+
+    /// <p>Changes the Amazon Identity and Access Management (IAM) role that is assigned to the on-premises instance or virtual machines (VM). IAM roles are first assigned to these hybrid instances during the activation process. For more information, see <a>CreateActivation</a>.</p>
+    ///
+    /// See `Ssm::update_managed_instance_role`.
+    pub fn update_managed_instance_role(
+        &self,
+        input: UpdateManagedInstanceRoleRequest,
+    ) -> Result<UpdateManagedInstanceRoleResult, RusotoError<UpdateManagedInstanceRoleError>> {
+        self.runtime
+            .block_on(self.client.update_managed_instance_role(input))
+    }
+
+    /// <p>Edit or change an OpsItem. You must have permission in AWS Identity and Access Management (IAM) to update an OpsItem. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter-getting-started.html">Getting started with OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>.</p> <p>Operations engineers and IT professionals use OpsCenter to view, investigate, and remediate operational issues impacting the performance and health of their AWS resources. For more information, see <a href="https://docs.aws.amazon.com/systems-manager/latest/userguide/OpsCenter.html">AWS Systems Manager OpsCenter</a> in the <i>AWS Systems Manager User Guide</i>. </p>
+    ///
+    /// See `Ssm::update_ops_item`.
+    pub fn update_ops_item(
+        &self,
+        input: UpdateOpsItemRequest,
+    ) -> Result<UpdateOpsItemResponse, RusotoError<UpdateOpsItemError>> {
+        self.runtime.block_on(self.client.update_ops_item(input))
+    }
+
+    /// <p><p>Modifies an existing patch baseline. Fields not specified in the request are left unchanged.</p> <note> <p>For information about valid key and value pairs in <code>PatchFilters</code> for each supported operating system type, see <a href="http://docs.aws.amazon.com/systems-manager/latest/APIReference/API_PatchFilter.html">PatchFilter</a>.</p> </note></p>
+    ///
+    /// See `Ssm::update_patch_baseline`.
+    pub fn update_patch_baseline(
+        &self,
+        input: UpdatePatchBaselineRequest,
+    ) -> Result<UpdatePatchBaselineResult, RusotoError<UpdatePatchBaselineError>> {
+        self.runtime
+            .block_on(self.client.update_patch_baseline(input))
+    }
+
+    /// <p><p>Update a resource data sync. After you create a resource data sync for a Region, you can&#39;t change the account options for that sync. For example, if you create a sync in the us-east-2 (Ohio) Region and you choose the Include only the current account option, you can&#39;t edit that sync later and choose the Include all accounts from my AWS Organizations configuration option. Instead, you must delete the first resource data sync, and create a new one.</p> <note> <p>This API action only supports a resource data sync that was created with a SyncFromSource <code>SyncType</code>.</p> </note></p>
+    ///
+    /// See `Ssm::update_resource_data_sync`.
+    pub fn update_resource_data_sync(
+        &self,
+        input: UpdateResourceDataSyncRequest,
+    ) -> Result<UpdateResourceDataSyncResult, RusotoError<UpdateResourceDataSyncError>> {
+        self.runtime
+            .block_on(self.client.update_resource_data_sync(input))
+    }
+
+    /// <p> <code>ServiceSetting</code> is an account-level setting for an AWS service. This setting defines how a user interacts with or uses a service or a feature of a service. For example, if an AWS service charges money to the account based on feature or service usage, then the AWS service team might create a default setting of "false". This means the user can't use this feature unless they change the setting to "true" and intentionally opt in for a paid feature.</p> <p>Services map a <code>SettingId</code> object to a setting value. AWS services teams define the default value for a <code>SettingId</code>. You can't create a new <code>SettingId</code>, but you can overwrite the default value if you have the <code>ssm:UpdateServiceSetting</code> permission for the setting. Use the <a>GetServiceSetting</a> API action to view the current value. Or, use the <a>ResetServiceSetting</a> to change the value back to the original value defined by the AWS service team.</p> <p>Update the service setting for the account. </p>
+    ///
+    /// See `Ssm::update_service_setting`.
+    pub fn update_service_setting(
+        &self,
+        input: UpdateServiceSettingRequest,
+    ) -> Result<UpdateServiceSettingResult, RusotoError<UpdateServiceSettingError>> {
+        self.runtime
+            .block_on(self.client.update_service_setting(input))
+    }
+}
@@ -201,37 +201,46 @@ impl CreateTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTokenError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::AccessDenied(err.msg))
                 }
                 "AuthorizationPendingException" => {
-                    return RusotoError::Service(CreateTokenError::AuthorizationPending(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTokenError::AuthorizationPending(err.msg),
+                    )
                 }
                 "ExpiredTokenException" => {
-                    return RusotoError::Service(CreateTokenError::ExpiredToken(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::ExpiredToken(err.msg))
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateTokenError::InternalServer(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::InternalServer(err.msg))
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidClient(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::InvalidClient(err.msg))
                 }
                 "InvalidGrantException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidGrant(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::InvalidGrant(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::InvalidRequest(err.msg))
                 }
                 "InvalidScopeException" => {
-                    return RusotoError::Service(CreateTokenError::InvalidScope(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::InvalidScope(err.msg))
                 }
                 "SlowDownException" => {
-                    return RusotoError::Service(CreateTokenError::SlowDown(err.msg))
+                    return RusotoError::service(&res, CreateTokenError::SlowDown(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateTokenError::UnauthorizedClient(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTokenError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "UnsupportedGrantTypeException" => {
-                    return RusotoError::Service(CreateTokenError::UnsupportedGrantType(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTokenError::UnsupportedGrantType(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -277,18 +286,19 @@ impl RegisterClientError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterClientError::InternalServer(err.msg))
+                    return RusotoError::service(&res, RegisterClientError::InternalServer(err.msg))
                 }
                 "InvalidClientMetadataException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidClientMetadata(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterClientError::InvalidClientMetadata(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, RegisterClientError::InvalidRequest(err.msg))
                 }
                 "InvalidScopeException" => {
-                    return RusotoError::Service(RegisterClientError::InvalidScope(err.msg))
+                    return RusotoError::service(&res, RegisterClientError::InvalidScope(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -329,27 +339,34 @@ impl StartDeviceAuthorizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDeviceAuthorizationError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InvalidClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDeviceAuthorizationError::InvalidClient(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDeviceAuthorizationError::InvalidRequest(err.msg),
+                    )
                 }
                 "SlowDownException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::SlowDown(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDeviceAuthorizationError::SlowDown(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(StartDeviceAuthorizationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDeviceAuthorizationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -410,6 +427,7 @@ impl SsoOidcClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -425,6 +443,7 @@ impl SsoOidcClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SsoOidcClient {
         SsoOidcClient { client, region }
     }
@@ -441,6 +460,9 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/token";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation_name("CreateToken");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -473,6 +495,9 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/client/register";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation_name("RegisterClient");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -505,6 +530,9 @@ impl SsoOidc for SsoOidcClient {
         let request_uri = "/device_authorization";
 
         let mut request = SignedRequest::new("POST", "awsssooidc", &self.region, &request_uri);
+        request.set_operation_name("StartDeviceAuthorization");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("oidc".to_string());
@@ -528,3 +556,84 @@ impl SsoOidc for SsoOidcClient {
         }
     }
 }
+
+/// A blocking client for the SSO OIDC API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SsoOidcClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SsoOidcBlockingClient {
+    client: SsoOidcClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SsoOidcBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SsoOidcBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SsoOidcBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsoOidcBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SsoOidcBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SsoOidcBlockingClient {
+        SsoOidcBlockingClient {
+            client: SsoOidcClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates and returns an access token for the authorized client. The access token issued will be used to fetch short-term credentials for the assigned roles in the AWS account.</p>
+    ///
+    /// See `SsoOidc::create_token`.
+    pub fn create_token(
+        &self,
+        input: CreateTokenRequest,
+    ) -> Result<CreateTokenResponse, RusotoError<CreateTokenError>> {
+        self.runtime.block_on(self.client.create_token(input))
+    }
+
+    /// <p>Registers a client with AWS SSO. This allows clients to initiate device authorization. The output should be persisted for reuse through many authentication requests.</p>
+    ///
+    /// See `SsoOidc::register_client`.
+    pub fn register_client(
+        &self,
+        input: RegisterClientRequest,
+    ) -> Result<RegisterClientResponse, RusotoError<RegisterClientError>> {
+        self.runtime.block_on(self.client.register_client(input))
+    }
+
+    /// <p>Initiates device authorization by requesting a pair of verification codes from the authorization service.</p>
+    ///
+    /// See `SsoOidc::start_device_authorization`.
+    pub fn start_device_authorization(
+        &self,
+        input: StartDeviceAuthorizationRequest,
+    ) -> Result<StartDeviceAuthorizationResponse, RusotoError<StartDeviceAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.start_device_authorization(input))
+    }
+}
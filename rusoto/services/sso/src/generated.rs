@@ -188,16 +188,28 @@ impl GetRoleCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRoleCredentialsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRoleCredentialsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRoleCredentialsError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRoleCredentialsError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRoleCredentialsError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -236,16 +248,25 @@ impl ListAccountRolesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAccountRolesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountRolesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAccountRolesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountRolesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountRolesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAccountRolesError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAccountRolesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListAccountRolesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -284,16 +305,16 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAccountsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAccountsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAccountsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListAccountsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -330,13 +351,13 @@ impl LogoutError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(LogoutError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, LogoutError::InvalidRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LogoutError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, LogoutError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(LogoutError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, LogoutError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -398,6 +419,7 @@ impl SsoClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -413,6 +435,7 @@ impl SsoClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SsoClient {
         SsoClient { client, region }
     }
@@ -429,6 +452,9 @@ impl Sso for SsoClient {
         let request_uri = "/federation/credentials";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation_name("GetRoleCredentials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -465,6 +491,9 @@ impl Sso for SsoClient {
         let request_uri = "/assignment/roles";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation_name("ListAccountRoles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -506,6 +535,9 @@ impl Sso for SsoClient {
         let request_uri = "/assignment/accounts";
 
         let mut request = SignedRequest::new("GET", "awsssoportal", &self.region, &request_uri);
+        request.set_operation_name("ListAccounts");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -543,6 +575,9 @@ impl Sso for SsoClient {
         let request_uri = "/logout";
 
         let mut request = SignedRequest::new("POST", "awsssoportal", &self.region, &request_uri);
+        request.set_operation_name("Logout");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("portal.sso".to_string());
@@ -565,3 +600,91 @@ impl Sso for SsoClient {
         }
     }
 }
+
+/// A blocking client for the SSO API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SsoClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SsoBlockingClient {
+    client: SsoClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SsoBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SsoBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SsoBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SsoBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SsoBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SsoBlockingClient {
+        SsoBlockingClient {
+            client: SsoClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns the STS short-term credentials for a given role name that is assigned to the user.</p>
+    ///
+    /// See `Sso::get_role_credentials`.
+    pub fn get_role_credentials(
+        &self,
+        input: GetRoleCredentialsRequest,
+    ) -> Result<GetRoleCredentialsResponse, RusotoError<GetRoleCredentialsError>> {
+        self.runtime
+            .block_on(self.client.get_role_credentials(input))
+    }
+
+    /// <p>Lists all roles that are assigned to the user for a given AWS account.</p>
+    ///
+    /// See `Sso::list_account_roles`.
+    pub fn list_account_roles(
+        &self,
+        input: ListAccountRolesRequest,
+    ) -> Result<ListAccountRolesResponse, RusotoError<ListAccountRolesError>> {
+        self.runtime.block_on(self.client.list_account_roles(input))
+    }
+
+    /// <p>Lists all AWS accounts assigned to the user. These AWS accounts are assigned by the administrator of the account. For more information, see <a href="https://docs.aws.amazon.com/singlesignon/latest/userguide/useraccess.html#assignusers">Assign User Access</a> in the <i>AWS SSO User Guide</i>. This operation returns a paginated response.</p>
+    ///
+    /// See `Sso::list_accounts`.
+    pub fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.runtime.block_on(self.client.list_accounts(input))
+    }
+
+    /// <p>Removes the client- and server-side session that is associated with the user.</p>
+    ///
+    /// See `Sso::logout`.
+    pub fn logout(&self, input: LogoutRequest) -> Result<(), RusotoError<LogoutError>> {
+        self.runtime.block_on(self.client.logout(input))
+    }
+}
@@ -1258,15 +1258,16 @@ impl CreateActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityLimitExceeded" => {
-                    return RusotoError::Service(CreateActivityError::ActivityLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateActivityError::ActivityLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(CreateActivityError::InvalidName(err.msg))
+                    return RusotoError::service(&res, CreateActivityError::InvalidName(err.msg))
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(CreateActivityError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, CreateActivityError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1314,43 +1315,55 @@ impl CreateStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, CreateStateMachineError::InvalidArn(err.msg))
                 }
                 "InvalidDefinition" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidDefinition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStateMachineError::InvalidDefinition(err.msg),
+                    )
                 }
                 "InvalidLoggingConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStateMachineError::InvalidLoggingConfiguration(err.msg),
                     )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(CreateStateMachineError::InvalidName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStateMachineError::InvalidName(err.msg),
+                    )
                 }
                 "StateMachineAlreadyExists" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStateMachineError::StateMachineAlreadyExists(err.msg),
                     )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(CreateStateMachineError::StateMachineDeleting(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStateMachineError::StateMachineDeleting(err.msg),
+                    )
                 }
                 "StateMachineLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStateMachineError::StateMachineLimitExceeded(err.msg),
                     )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStateMachineError::StateMachineTypeNotSupported(err.msg),
                     )
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(CreateStateMachineError::TooManyTags(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateStateMachineError::TooManyTags(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1392,7 +1405,7 @@ impl DeleteActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DeleteActivityError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteActivityError::InvalidArn(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1422,7 +1435,7 @@ impl DeleteStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DeleteStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DeleteStateMachineError::InvalidArn(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1454,12 +1467,13 @@ impl DescribeActivityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityDoesNotExist" => {
-                    return RusotoError::Service(DescribeActivityError::ActivityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivityError::ActivityDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeActivityError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DescribeActivityError::InvalidArn(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1492,12 +1506,13 @@ impl DescribeExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(DescribeExecutionError::ExecutionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeExecutionError::ExecutionDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, DescribeExecutionError::InvalidArn(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1530,10 +1545,14 @@ impl DescribeStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStateMachineError::InvalidArn(err.msg),
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeStateMachineError::StateMachineDoesNotExist(err.msg),
                     )
                 }
@@ -1572,14 +1591,16 @@ impl DescribeStateMachineForExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeStateMachineForExecutionError::ExecutionDoesNotExist(err.msg),
                     )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(DescribeStateMachineForExecutionError::InvalidArn(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeStateMachineForExecutionError::InvalidArn(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1616,17 +1637,19 @@ impl GetActivityTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActivityDoesNotExist" => {
-                    return RusotoError::Service(GetActivityTaskError::ActivityDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetActivityTaskError::ActivityDoesNotExist(err.msg),
+                    )
                 }
                 "ActivityWorkerLimitExceeded" => {
-                    return RusotoError::Service(GetActivityTaskError::ActivityWorkerLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetActivityTaskError::ActivityWorkerLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(GetActivityTaskError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, GetActivityTaskError::InvalidArn(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1662,15 +1685,22 @@ impl GetExecutionHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(GetExecutionHistoryError::ExecutionDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetExecutionHistoryError::ExecutionDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(GetExecutionHistoryError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExecutionHistoryError::InvalidArn(err.msg),
+                    )
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(GetExecutionHistoryError::InvalidToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetExecutionHistoryError::InvalidToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1702,7 +1732,7 @@ impl ListActivitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(ListActivitiesError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, ListActivitiesError::InvalidToken(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1738,20 +1768,22 @@ impl ListExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(ListExecutionsError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, ListExecutionsError::InvalidArn(err.msg))
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(ListExecutionsError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, ListExecutionsError::InvalidToken(err.msg))
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(ListExecutionsError::StateMachineDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListExecutionsError::StateMachineDoesNotExist(err.msg),
+                    )
                 }
                 "StateMachineTypeNotSupported" => {
-                    return RusotoError::Service(ListExecutionsError::StateMachineTypeNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListExecutionsError::StateMachineTypeNotSupported(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1784,7 +1816,10 @@ impl ListStateMachinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(ListStateMachinesError::InvalidToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListStateMachinesError::InvalidToken(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1816,12 +1851,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidArn(err.msg),
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1856,13 +1895,16 @@ impl SendTaskFailureError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskFailureError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, SendTaskFailureError::InvalidToken(err.msg))
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskFailureError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendTaskFailureError::TaskDoesNotExist(err.msg),
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskFailureError::TaskTimedOut(err.msg))
+                    return RusotoError::service(&res, SendTaskFailureError::TaskTimedOut(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1898,13 +1940,22 @@ impl SendTaskHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::InvalidToken(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendTaskHeartbeatError::InvalidToken(err.msg),
+                    )
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendTaskHeartbeatError::TaskDoesNotExist(err.msg),
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskHeartbeatError::TaskTimedOut(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendTaskHeartbeatError::TaskTimedOut(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1942,16 +1993,19 @@ impl SendTaskSuccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidOutput" => {
-                    return RusotoError::Service(SendTaskSuccessError::InvalidOutput(err.msg))
+                    return RusotoError::service(&res, SendTaskSuccessError::InvalidOutput(err.msg))
                 }
                 "InvalidToken" => {
-                    return RusotoError::Service(SendTaskSuccessError::InvalidToken(err.msg))
+                    return RusotoError::service(&res, SendTaskSuccessError::InvalidToken(err.msg))
                 }
                 "TaskDoesNotExist" => {
-                    return RusotoError::Service(SendTaskSuccessError::TaskDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SendTaskSuccessError::TaskDoesNotExist(err.msg),
+                    )
                 }
                 "TaskTimedOut" => {
-                    return RusotoError::Service(SendTaskSuccessError::TaskTimedOut(err.msg))
+                    return RusotoError::service(&res, SendTaskSuccessError::TaskTimedOut(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1996,33 +2050,40 @@ impl StartExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionAlreadyExists" => {
-                    return RusotoError::Service(StartExecutionError::ExecutionAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExecutionError::ExecutionAlreadyExists(err.msg),
+                    )
                 }
                 "ExecutionLimitExceeded" => {
-                    return RusotoError::Service(StartExecutionError::ExecutionLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExecutionError::ExecutionLimitExceeded(err.msg),
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(StartExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, StartExecutionError::InvalidArn(err.msg))
                 }
                 "InvalidExecutionInput" => {
-                    return RusotoError::Service(StartExecutionError::InvalidExecutionInput(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExecutionError::InvalidExecutionInput(err.msg),
+                    )
                 }
                 "InvalidName" => {
-                    return RusotoError::Service(StartExecutionError::InvalidName(err.msg))
+                    return RusotoError::service(&res, StartExecutionError::InvalidName(err.msg))
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(StartExecutionError::StateMachineDeleting(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartExecutionError::StateMachineDeleting(err.msg),
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(StartExecutionError::StateMachineDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartExecutionError::StateMachineDoesNotExist(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2060,10 +2121,13 @@ impl StopExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExecutionDoesNotExist" => {
-                    return RusotoError::Service(StopExecutionError::ExecutionDoesNotExist(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopExecutionError::ExecutionDoesNotExist(err.msg),
+                    )
                 }
                 "InvalidArn" => {
-                    return RusotoError::Service(StopExecutionError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, StopExecutionError::InvalidArn(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2097,12 +2161,14 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "InvalidArn" => return RusotoError::Service(TagResourceError::InvalidArn(err.msg)),
+                "InvalidArn" => {
+                    return RusotoError::service(&res, TagResourceError::InvalidArn(err.msg))
+                }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTags" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2136,10 +2202,13 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidArn(err.msg))
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2180,32 +2249,37 @@ impl UpdateStateMachineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArn" => {
-                    return RusotoError::Service(UpdateStateMachineError::InvalidArn(err.msg))
+                    return RusotoError::service(&res, UpdateStateMachineError::InvalidArn(err.msg))
                 }
                 "InvalidDefinition" => {
-                    return RusotoError::Service(UpdateStateMachineError::InvalidDefinition(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStateMachineError::InvalidDefinition(err.msg),
+                    )
                 }
                 "InvalidLoggingConfiguration" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateStateMachineError::InvalidLoggingConfiguration(err.msg),
                     )
                 }
                 "MissingRequiredParameter" => {
-                    return RusotoError::Service(UpdateStateMachineError::MissingRequiredParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStateMachineError::MissingRequiredParameter(err.msg),
+                    )
                 }
                 "StateMachineDeleting" => {
-                    return RusotoError::Service(UpdateStateMachineError::StateMachineDeleting(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStateMachineError::StateMachineDeleting(err.msg),
+                    )
                 }
                 "StateMachineDoesNotExist" => {
-                    return RusotoError::Service(UpdateStateMachineError::StateMachineDoesNotExist(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateStateMachineError::StateMachineDoesNotExist(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2386,6 +2460,7 @@ impl StepFunctionsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2401,6 +2476,7 @@ impl StepFunctionsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsClient {
         StepFunctionsClient { client, region }
     }
@@ -2414,6 +2490,9 @@ impl StepFunctions for StepFunctionsClient {
         input: CreateActivityInput,
     ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateActivity");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.CreateActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2432,6 +2511,9 @@ impl StepFunctions for StepFunctionsClient {
         input: CreateStateMachineInput,
     ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStateMachine");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.CreateStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2450,6 +2532,9 @@ impl StepFunctions for StepFunctionsClient {
         input: DeleteActivityInput,
     ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteActivity");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2468,6 +2553,9 @@ impl StepFunctions for StepFunctionsClient {
         input: DeleteStateMachineInput,
     ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteStateMachine");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.DeleteStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2486,6 +2574,9 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeActivityInput,
     ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeActivity");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeActivity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2504,6 +2595,9 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeExecutionInput,
     ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2522,6 +2616,9 @@ impl StepFunctions for StepFunctionsClient {
         input: DescribeStateMachineInput,
     ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStateMachine");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.DescribeStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2543,6 +2640,9 @@ impl StepFunctions for StepFunctionsClient {
         RusotoError<DescribeStateMachineForExecutionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStateMachineForExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSStepFunctions.DescribeStateMachineForExecution",
@@ -2568,6 +2668,9 @@ impl StepFunctions for StepFunctionsClient {
         input: GetActivityTaskInput,
     ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetActivityTask");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.GetActivityTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2586,6 +2689,9 @@ impl StepFunctions for StepFunctionsClient {
         input: GetExecutionHistoryInput,
     ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetExecutionHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.GetExecutionHistory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2604,6 +2710,9 @@ impl StepFunctions for StepFunctionsClient {
         input: ListActivitiesInput,
     ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListActivities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.ListActivities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2622,6 +2731,9 @@ impl StepFunctions for StepFunctionsClient {
         input: ListExecutionsInput,
     ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.ListExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2640,6 +2752,9 @@ impl StepFunctions for StepFunctionsClient {
         input: ListStateMachinesInput,
     ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListStateMachines");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.ListStateMachines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2658,6 +2773,9 @@ impl StepFunctions for StepFunctionsClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2676,6 +2794,9 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskFailureInput,
     ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendTaskFailure");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskFailure");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2694,6 +2815,9 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskHeartbeatInput,
     ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendTaskHeartbeat");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskHeartbeat");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2712,6 +2836,9 @@ impl StepFunctions for StepFunctionsClient {
         input: SendTaskSuccessInput,
     ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SendTaskSuccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.SendTaskSuccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2730,6 +2857,9 @@ impl StepFunctions for StepFunctionsClient {
         input: StartExecutionInput,
     ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartExecution");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.StartExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2748,6 +2878,9 @@ impl StepFunctions for StepFunctionsClient {
         input: StopExecutionInput,
     ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.StopExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2766,6 +2899,9 @@ impl StepFunctions for StepFunctionsClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2784,6 +2920,9 @@ impl StepFunctions for StepFunctionsClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSStepFunctions.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2802,6 +2941,9 @@ impl StepFunctions for StepFunctionsClient {
         input: UpdateStateMachineInput,
     ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateStateMachine");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSStepFunctions.UpdateStateMachine");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2814,3 +2956,285 @@ impl StepFunctions for StepFunctionsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateStateMachineOutput, _>()
     }
 }
+
+/// A blocking client for the AWS SFN API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `StepFunctionsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct StepFunctionsBlockingClient {
+    client: StepFunctionsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl StepFunctionsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> StepFunctionsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        StepFunctionsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StepFunctionsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        StepFunctionsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> StepFunctionsBlockingClient {
+        StepFunctionsBlockingClient {
+            client: StepFunctionsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Creates an activity. An activity is a task that you write in any programming language and host on any machine that has access to AWS Step Functions. Activities must poll Step Functions using the <code>GetActivityTask</code> API action and respond using <code>SendTask*</code> API actions. This function lets Step Functions know the existence of your activity and returns an identifier for use in a state machine and when polling from the activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateActivity</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateActivity</code>&#39;s idempotency check is based on the activity <code>name</code>. If a following request has different <code>tags</code> values, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+    ///
+    /// See `StepFunctions::create_activity`.
+    pub fn create_activity(
+        &self,
+        input: CreateActivityInput,
+    ) -> Result<CreateActivityOutput, RusotoError<CreateActivityError>> {
+        self.runtime.block_on(self.client.create_activity(input))
+    }
+
+    /// <p><p>Creates a state machine. A state machine consists of a collection of states that can do work (<code>Task</code> states), determine to which states to transition next (<code>Choice</code> states), stop an execution with an error (<code>Fail</code> states), and so on. State machines are specified using a JSON-based, structured language. For more information, see <a href="https://docs.aws.amazon.com/step-functions/latest/dg/concepts-amazon-states-language.html">Amazon States Language</a> in the AWS Step Functions User Guide.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <note> <p> <code>CreateStateMachine</code> is an idempotent API. Subsequent requests won’t create a duplicate resource if it was already created. <code>CreateStateMachine</code>&#39;s idempotency check is based on the state machine <code>name</code>, <code>definition</code>, <code>type</code>, and <code>LoggingConfiguration</code>. If a following request has a different <code>roleArn</code> or <code>tags</code>, Step Functions will ignore these differences and treat it as an idempotent request of the previous. In this case, <code>roleArn</code> and <code>tags</code> will not be updated, even if they are different.</p> </note></p>
+    ///
+    /// See `StepFunctions::create_state_machine`.
+    pub fn create_state_machine(
+        &self,
+        input: CreateStateMachineInput,
+    ) -> Result<CreateStateMachineOutput, RusotoError<CreateStateMachineError>> {
+        self.runtime
+            .block_on(self.client.create_state_machine(input))
+    }
+
+    /// <p>Deletes an activity.</p>
+    ///
+    /// See `StepFunctions::delete_activity`.
+    pub fn delete_activity(
+        &self,
+        input: DeleteActivityInput,
+    ) -> Result<DeleteActivityOutput, RusotoError<DeleteActivityError>> {
+        self.runtime.block_on(self.client.delete_activity(input))
+    }
+
+    /// <p><p>Deletes a state machine. This is an asynchronous operation: It sets the state machine&#39;s status to <code>DELETING</code> and begins the deletion process. </p> <note> <p>For <code>EXPRESS</code>state machines, the deletion will happen eventually (usually less than a minute). Running executions may emit logs after <code>DeleteStateMachine</code> API is called.</p> </note></p>
+    ///
+    /// See `StepFunctions::delete_state_machine`.
+    pub fn delete_state_machine(
+        &self,
+        input: DeleteStateMachineInput,
+    ) -> Result<DeleteStateMachineOutput, RusotoError<DeleteStateMachineError>> {
+        self.runtime
+            .block_on(self.client.delete_state_machine(input))
+    }
+
+    /// <p><p>Describes an activity.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    ///
+    /// See `StepFunctions::describe_activity`.
+    pub fn describe_activity(
+        &self,
+        input: DescribeActivityInput,
+    ) -> Result<DescribeActivityOutput, RusotoError<DescribeActivityError>> {
+        self.runtime.block_on(self.client.describe_activity(input))
+    }
+
+    /// <p>Describes an execution.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <p>This API action is not supported by <code>EXPRESS</code> state machines.</p>
+    ///
+    /// See `StepFunctions::describe_execution`.
+    pub fn describe_execution(
+        &self,
+        input: DescribeExecutionInput,
+    ) -> Result<DescribeExecutionOutput, RusotoError<DescribeExecutionError>> {
+        self.runtime.block_on(self.client.describe_execution(input))
+    }
+
+    /// <p><p>Describes a state machine.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    ///
+    /// See `StepFunctions::describe_state_machine`.
+    pub fn describe_state_machine(
+        &self,
+        input: DescribeStateMachineInput,
+    ) -> Result<DescribeStateMachineOutput, RusotoError<DescribeStateMachineError>> {
+        self.runtime
+            .block_on(self.client.describe_state_machine(input))
+    }
+
+    /// <p>Describes the state machine associated with a specific execution.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <p>This API action is not supported by <code>EXPRESS</code> state machines.</p>
+    ///
+    /// See `StepFunctions::describe_state_machine_for_execution`.
+    pub fn describe_state_machine_for_execution(
+        &self,
+        input: DescribeStateMachineForExecutionInput,
+    ) -> Result<
+        DescribeStateMachineForExecutionOutput,
+        RusotoError<DescribeStateMachineForExecutionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_state_machine_for_execution(input))
+    }
+
+    /// <p><p>Used by workers to retrieve a task (with the specified activity ARN) which has been scheduled for execution by a running state machine. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available (i.e. an execution of a task of this type is needed.) The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns a <code>taskToken</code> with a null string.</p> <important> <p>Workers should set their client side socket timeout to at least 65 seconds (5 seconds higher than the maximum time the service may hold the poll request).</p> <p>Polling with <code>GetActivityTask</code> can cause latency in some implementations. See <a href="https://docs.aws.amazon.com/step-functions/latest/dg/bp-activity-pollers.html">Avoid Latency When Polling for Activity Tasks</a> in the Step Functions Developer Guide.</p> </important></p>
+    ///
+    /// See `StepFunctions::get_activity_task`.
+    pub fn get_activity_task(
+        &self,
+        input: GetActivityTaskInput,
+    ) -> Result<GetActivityTaskOutput, RusotoError<GetActivityTaskError>> {
+        self.runtime.block_on(self.client.get_activity_task(input))
+    }
+
+    /// <p>Returns the history of the specified execution as a list of events. By default, the results are returned in ascending order of the <code>timeStamp</code> of the events. Use the <code>reverseOrder</code> parameter to get the latest events first.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <p>This API action is not supported by <code>EXPRESS</code> state machines.</p>
+    ///
+    /// See `StepFunctions::get_execution_history`.
+    pub fn get_execution_history(
+        &self,
+        input: GetExecutionHistoryInput,
+    ) -> Result<GetExecutionHistoryOutput, RusotoError<GetExecutionHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_execution_history(input))
+    }
+
+    /// <p><p>Lists the existing activities.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    ///
+    /// See `StepFunctions::list_activities`.
+    pub fn list_activities(
+        &self,
+        input: ListActivitiesInput,
+    ) -> Result<ListActivitiesOutput, RusotoError<ListActivitiesError>> {
+        self.runtime.block_on(self.client.list_activities(input))
+    }
+
+    /// <p>Lists the executions of a state machine that meet the filtering criteria. Results are sorted by time, with the most recent execution first.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note> <p>This API action is not supported by <code>EXPRESS</code> state machines.</p>
+    ///
+    /// See `StepFunctions::list_executions`.
+    pub fn list_executions(
+        &self,
+        input: ListExecutionsInput,
+    ) -> Result<ListExecutionsOutput, RusotoError<ListExecutionsError>> {
+        self.runtime.block_on(self.client.list_executions(input))
+    }
+
+    /// <p><p>Lists the existing state machines.</p> <p>If <code>nextToken</code> is returned, there are more results available. The value of <code>nextToken</code> is a unique pagination token for each page. Make the call again using the returned token to retrieve the next page. Keep all other arguments unchanged. Each pagination token expires after 24 hours. Using an expired pagination token will return an <i>HTTP 400 InvalidToken</i> error.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not reflect very recent updates and changes.</p> </note></p>
+    ///
+    /// See `StepFunctions::list_state_machines`.
+    pub fn list_state_machines(
+        &self,
+        input: ListStateMachinesInput,
+    ) -> Result<ListStateMachinesOutput, RusotoError<ListStateMachinesError>> {
+        self.runtime
+            .block_on(self.client.list_state_machines(input))
+    }
+
+    /// <p>List tags for a given resource.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
+    ///
+    /// See `StepFunctions::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report that the task identified by the <code>taskToken</code> failed.</p>
+    ///
+    /// See `StepFunctions::send_task_failure`.
+    pub fn send_task_failure(
+        &self,
+        input: SendTaskFailureInput,
+    ) -> Result<SendTaskFailureOutput, RusotoError<SendTaskFailureError>> {
+        self.runtime.block_on(self.client.send_task_failure(input))
+    }
+
+    /// <p><p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report to Step Functions that the task represented by the specified <code>taskToken</code> is still making progress. This action resets the <code>Heartbeat</code> clock. The <code>Heartbeat</code> threshold is specified in the state machine&#39;s Amazon States Language definition (<code>HeartbeatSeconds</code>). This action does not in itself create an event in the execution history. However, if the task times out, the execution history contains an <code>ActivityTimedOut</code> entry for activities, or a <code>TaskTimedOut</code> entry for for tasks using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-sync">job run</a> or <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern.</p> <note> <p>The <code>Timeout</code> of a task, defined in the state machine&#39;s Amazon States Language definition, is its maximum allowed duration, regardless of the number of <a>SendTaskHeartbeat</a> requests received. Use <code>HeartbeatSeconds</code> to configure the timeout interval for heartbeats.</p> </note></p>
+    ///
+    /// See `StepFunctions::send_task_heartbeat`.
+    pub fn send_task_heartbeat(
+        &self,
+        input: SendTaskHeartbeatInput,
+    ) -> Result<SendTaskHeartbeatOutput, RusotoError<SendTaskHeartbeatError>> {
+        self.runtime
+            .block_on(self.client.send_task_heartbeat(input))
+    }
+
+    /// <p>Used by activity workers and task states using the <a href="https://docs.aws.amazon.com/step-functions/latest/dg/connect-to-resource.html#connect-wait-token">callback</a> pattern to report that the task identified by the <code>taskToken</code> completed successfully.</p>
+    ///
+    /// See `StepFunctions::send_task_success`.
+    pub fn send_task_success(
+        &self,
+        input: SendTaskSuccessInput,
+    ) -> Result<SendTaskSuccessOutput, RusotoError<SendTaskSuccessError>> {
+        self.runtime.block_on(self.client.send_task_success(input))
+    }
+
+    /// <p><p>Starts a state machine execution.</p> <note> <p> <code>StartExecution</code> is idempotent. If <code>StartExecution</code> is called with the same name and input as a running execution, the call will succeed and return the same response as the original request. If the execution is closed or if the input is different, it will return a 400 <code>ExecutionAlreadyExists</code> error. Names can be reused after 90 days. </p> </note></p>
+    ///
+    /// See `StepFunctions::start_execution`.
+    pub fn start_execution(
+        &self,
+        input: StartExecutionInput,
+    ) -> Result<StartExecutionOutput, RusotoError<StartExecutionError>> {
+        self.runtime.block_on(self.client.start_execution(input))
+    }
+
+    /// <p>Stops an execution.</p> <p>This API action is not supported by <code>EXPRESS</code> state machines.</p>
+    ///
+    /// See `StepFunctions::stop_execution`.
+    pub fn stop_execution(
+        &self,
+        input: StopExecutionInput,
+    ) -> Result<StopExecutionOutput, RusotoError<StopExecutionError>> {
+        self.runtime.block_on(self.client.stop_execution(input))
+    }
+
+    /// <p>Add a tag to a Step Functions resource.</p> <p>An array of key-value pairs. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/cost-alloc-tags.html">Using Cost Allocation Tags</a> in the <i>AWS Billing and Cost Management User Guide</i>, and <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_iam-tags.html">Controlling Access Using IAM Tags</a>.</p> <p>Tags may only contain Unicode letters, digits, white space, or these symbols: <code>_ . : / = + - @</code>.</p>
+    ///
+    /// See `StepFunctions::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Remove a tag from a Step Functions resource</p>
+    ///
+    /// See `StepFunctions::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><p>Updates an existing state machine by modifying its <code>definition</code>, <code>roleArn</code>, or <code>loggingConfiguration</code>. Running executions will continue to use the previous <code>definition</code> and <code>roleArn</code>. You must include at least one of <code>definition</code> or <code>roleArn</code> or you will receive a <code>MissingRequiredParameter</code> error.</p> <note> <p>All <code>StartExecution</code> calls within a few seconds will use the updated <code>definition</code> and <code>roleArn</code>. Executions started immediately after calling <code>UpdateStateMachine</code> may use the previous state machine <code>definition</code> and <code>roleArn</code>. </p> </note></p>
+    ///
+    /// See `StepFunctions::update_state_machine`.
+    pub fn update_state_machine(
+        &self,
+        input: UpdateStateMachineInput,
+    ) -> Result<UpdateStateMachineOutput, RusotoError<UpdateStateMachineError>> {
+        self.runtime
+            .block_on(self.client.update_state_machine(input))
+    }
+}
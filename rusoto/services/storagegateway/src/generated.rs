@@ -3131,12 +3131,16 @@ impl ActivateGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ActivateGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivateGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ActivateGatewayError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ActivateGatewayError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3169,10 +3173,13 @@ impl AddCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, AddCacheError::InternalServerError(err.msg))
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddCacheError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3205,14 +3212,16 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddTagsToResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddTagsToResourceError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3245,12 +3254,16 @@ impl AddUploadBufferError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddUploadBufferError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AddUploadBufferError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddUploadBufferError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddUploadBufferError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3283,14 +3296,16 @@ impl AddWorkingStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddWorkingStorageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddWorkingStorageError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AddWorkingStorageError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddWorkingStorageError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3323,12 +3338,16 @@ impl AssignTapePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AssignTapePoolError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssignTapePoolError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AssignTapePoolError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssignTapePoolError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3361,10 +3380,16 @@ impl AttachVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AttachVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachVolumeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(AttachVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AttachVolumeError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3397,12 +3422,16 @@ impl CancelArchivalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelArchivalError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelArchivalError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CancelArchivalError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelArchivalError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3435,12 +3464,16 @@ impl CancelRetrievalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelRetrievalError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CancelRetrievalError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CancelRetrievalError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CancelRetrievalError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3473,12 +3506,14 @@ impl CreateCachediSCSIVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCachediSCSIVolumeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCachediSCSIVolumeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCachediSCSIVolumeError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -3515,14 +3550,16 @@ impl CreateNFSFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateNFSFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNFSFileShareError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateNFSFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateNFSFileShareError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3555,14 +3592,16 @@ impl CreateSMBFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateSMBFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSMBFileShareError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateSMBFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSMBFileShareError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3597,17 +3636,22 @@ impl CreateSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateSnapshotError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSnapshotError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateSnapshotError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSnapshotError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(CreateSnapshotError::ServiceUnavailableError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSnapshotError::ServiceUnavailableError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3645,17 +3689,20 @@ impl CreateSnapshotFromVolumeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSnapshotFromVolumeRecoveryPointError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSnapshotFromVolumeRecoveryPointError::InvalidGatewayRequest(err.msg),
                     )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSnapshotFromVolumeRecoveryPointError::ServiceUnavailableError(
                             err.msg,
                         ),
@@ -3699,12 +3746,14 @@ impl CreateStorediSCSIVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateStorediSCSIVolumeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateStorediSCSIVolumeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateStorediSCSIVolumeError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -3741,14 +3790,16 @@ impl CreateTapeWithBarcodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapeWithBarcodeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTapeWithBarcodeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapeWithBarcodeError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateTapeWithBarcodeError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3781,10 +3832,16 @@ impl CreateTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTapesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTapesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(CreateTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTapesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3819,12 +3876,14 @@ impl DeleteAutomaticTapeCreationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAutomaticTapeCreationPolicyError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAutomaticTapeCreationPolicyError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -3863,12 +3922,14 @@ impl DeleteBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBandwidthRateLimitError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -3905,14 +3966,16 @@ impl DeleteChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteChapCredentialsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChapCredentialsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteChapCredentialsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteChapCredentialsError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3945,12 +4008,16 @@ impl DeleteFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileShareError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileShareError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFileShareError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3983,10 +4050,16 @@ impl DeleteGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteGatewayError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGatewayError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4019,12 +4092,14 @@ impl DeleteSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteSnapshotScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSnapshotScheduleError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSnapshotScheduleError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4059,10 +4134,16 @@ impl DeleteTapeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTapeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTapeError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4095,14 +4176,16 @@ impl DeleteTapeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTapeArchiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTapeArchiveError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteTapeArchiveError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTapeArchiveError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4135,10 +4218,16 @@ impl DeleteVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVolumeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DeleteVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVolumeError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4173,12 +4262,14 @@ impl DescribeAvailabilityMonitorTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAvailabilityMonitorTestError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAvailabilityMonitorTestError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4219,12 +4310,14 @@ impl DescribeBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBandwidthRateLimitError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4263,10 +4356,16 @@ impl DescribeCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCacheError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCacheError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4301,12 +4400,14 @@ impl DescribeCachediSCSIVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCachediSCSIVolumesError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCachediSCSIVolumesError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4345,12 +4446,14 @@ impl DescribeChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeChapCredentialsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeChapCredentialsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeChapCredentialsError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4389,12 +4492,14 @@ impl DescribeGatewayInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGatewayInformationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeGatewayInformationError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4435,12 +4540,14 @@ impl DescribeMaintenanceStartTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceStartTimeError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4479,14 +4586,16 @@ impl DescribeNFSFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeNFSFileSharesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNFSFileSharesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeNFSFileSharesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeNFSFileSharesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4519,14 +4628,16 @@ impl DescribeSMBFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSMBFileSharesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSMBFileSharesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeSMBFileSharesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSMBFileSharesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4559,14 +4670,16 @@ impl DescribeSMBSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSMBSettingsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSMBSettingsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeSMBSettingsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSMBSettingsError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4599,12 +4712,14 @@ impl DescribeSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSnapshotScheduleError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeSnapshotScheduleError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4643,12 +4758,14 @@ impl DescribeStorediSCSIVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeStorediSCSIVolumesError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeStorediSCSIVolumesError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4687,14 +4804,16 @@ impl DescribeTapeArchivesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTapeArchivesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTapeArchivesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeTapeArchivesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTapeArchivesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4729,12 +4848,14 @@ impl DescribeTapeRecoveryPointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTapeRecoveryPointsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTapeRecoveryPointsError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4773,10 +4894,16 @@ impl DescribeTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTapesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTapesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTapesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4809,14 +4936,16 @@ impl DescribeUploadBufferError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeUploadBufferError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUploadBufferError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeUploadBufferError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUploadBufferError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4849,14 +4978,16 @@ impl DescribeVTLDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeVTLDevicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVTLDevicesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DescribeVTLDevicesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeVTLDevicesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4889,12 +5020,14 @@ impl DescribeWorkingStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeWorkingStorageError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkingStorageError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkingStorageError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -4929,10 +5062,16 @@ impl DetachVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DetachVolumeError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachVolumeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DetachVolumeError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetachVolumeError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4965,12 +5104,16 @@ impl DisableGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DisableGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisableGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(DisableGatewayError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisableGatewayError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5003,10 +5146,16 @@ impl JoinDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(JoinDomainError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        JoinDomainError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(JoinDomainError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        JoinDomainError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5041,12 +5190,14 @@ impl ListAutomaticTapeCreationPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAutomaticTapeCreationPoliciesError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAutomaticTapeCreationPoliciesError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5085,12 +5236,16 @@ impl ListFileSharesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListFileSharesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFileSharesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListFileSharesError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListFileSharesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5123,10 +5278,16 @@ impl ListGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListGatewaysError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGatewaysError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListGatewaysError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGatewaysError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5159,12 +5320,16 @@ impl ListLocalDisksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListLocalDisksError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListLocalDisksError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListLocalDisksError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLocalDisksError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5197,14 +5362,16 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5237,10 +5404,13 @@ impl ListTapesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTapesError::InternalServerError(err.msg))
+                    return RusotoError::service(&res, ListTapesError::InternalServerError(err.msg))
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListTapesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTapesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5273,14 +5443,16 @@ impl ListVolumeInitiatorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListVolumeInitiatorsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVolumeInitiatorsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListVolumeInitiatorsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVolumeInitiatorsError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5313,12 +5485,14 @@ impl ListVolumeRecoveryPointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVolumeRecoveryPointsError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListVolumeRecoveryPointsError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5355,10 +5529,16 @@ impl ListVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListVolumesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVolumesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ListVolumesError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVolumesError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5391,14 +5571,16 @@ impl NotifyWhenUploadedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(NotifyWhenUploadedError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyWhenUploadedError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(NotifyWhenUploadedError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        NotifyWhenUploadedError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5431,10 +5613,16 @@ impl RefreshCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RefreshCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RefreshCacheError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(RefreshCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RefreshCacheError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5467,12 +5655,14 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveTagsFromResourceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveTagsFromResourceError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5507,10 +5697,16 @@ impl ResetCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ResetCacheError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetCacheError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ResetCacheError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetCacheError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5543,14 +5739,16 @@ impl RetrieveTapeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RetrieveTapeArchiveError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RetrieveTapeArchiveError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(RetrieveTapeArchiveError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RetrieveTapeArchiveError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5583,12 +5781,14 @@ impl RetrieveTapeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RetrieveTapeRecoveryPointError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RetrieveTapeRecoveryPointError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5627,12 +5827,14 @@ impl SetLocalConsolePasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetLocalConsolePasswordError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetLocalConsolePasswordError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SetLocalConsolePasswordError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5669,14 +5871,16 @@ impl SetSMBGuestPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetSMBGuestPasswordError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetSMBGuestPasswordError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(SetSMBGuestPasswordError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        SetSMBGuestPasswordError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5709,12 +5913,16 @@ impl ShutdownGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ShutdownGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ShutdownGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(ShutdownGatewayError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ShutdownGatewayError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5749,12 +5957,14 @@ impl StartAvailabilityMonitorTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAvailabilityMonitorTestError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartAvailabilityMonitorTestError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5793,10 +6003,16 @@ impl StartGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StartGatewayError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartGatewayError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(StartGatewayError::InvalidGatewayRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartGatewayError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5831,12 +6047,14 @@ impl UpdateAutomaticTapeCreationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAutomaticTapeCreationPolicyError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAutomaticTapeCreationPolicyError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5875,12 +6093,14 @@ impl UpdateBandwidthRateLimitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateBandwidthRateLimitError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateBandwidthRateLimitError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5917,14 +6137,16 @@ impl UpdateChapCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateChapCredentialsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChapCredentialsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateChapCredentialsError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateChapCredentialsError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5957,12 +6179,14 @@ impl UpdateGatewayInformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGatewayInformationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGatewayInformationError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -5999,12 +6223,14 @@ impl UpdateGatewaySoftwareNowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGatewaySoftwareNowError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateGatewaySoftwareNowError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -6043,12 +6269,14 @@ impl UpdateMaintenanceStartTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMaintenanceStartTimeError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateMaintenanceStartTimeError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -6087,14 +6315,16 @@ impl UpdateNFSFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateNFSFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNFSFileShareError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateNFSFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateNFSFileShareError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6127,14 +6357,16 @@ impl UpdateSMBFileShareError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateSMBFileShareError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSMBFileShareError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateSMBFileShareError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSMBFileShareError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6167,12 +6399,14 @@ impl UpdateSMBSecurityStrategyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSMBSecurityStrategyError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSMBSecurityStrategyError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -6211,12 +6445,14 @@ impl UpdateSnapshotScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateSnapshotScheduleError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSnapshotScheduleError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSnapshotScheduleError::InvalidGatewayRequest(err.msg),
                     )
                 }
@@ -6251,14 +6487,16 @@ impl UpdateVTLDeviceTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateVTLDeviceTypeError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVTLDeviceTypeError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidGatewayRequestException" => {
-                    return RusotoError::Service(UpdateVTLDeviceTypeError::InvalidGatewayRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVTLDeviceTypeError::InvalidGatewayRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6781,6 +7019,7 @@ impl StorageGatewayClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6796,6 +7035,7 @@ impl StorageGatewayClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayClient {
         StorageGatewayClient { client, region }
     }
@@ -6809,6 +7049,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ActivateGatewayInput,
     ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ActivateGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.ActivateGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6827,6 +7070,9 @@ impl StorageGateway for StorageGatewayClient {
         input: AddCacheInput,
     ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddCache");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.AddCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6845,6 +7091,9 @@ impl StorageGateway for StorageGatewayClient {
         input: AddTagsToResourceInput,
     ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddTagsToResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6863,6 +7112,9 @@ impl StorageGateway for StorageGatewayClient {
         input: AddUploadBufferInput,
     ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddUploadBuffer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.AddUploadBuffer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6881,6 +7133,9 @@ impl StorageGateway for StorageGatewayClient {
         input: AddWorkingStorageInput,
     ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddWorkingStorage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.AddWorkingStorage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6899,6 +7154,9 @@ impl StorageGateway for StorageGatewayClient {
         input: AssignTapePoolInput,
     ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssignTapePool");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.AssignTapePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6917,6 +7175,9 @@ impl StorageGateway for StorageGatewayClient {
         input: AttachVolumeInput,
     ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AttachVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.AttachVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6935,6 +7196,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CancelArchivalInput,
     ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelArchival");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelArchival");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6953,6 +7217,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CancelRetrievalInput,
     ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CancelRetrieval");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.CancelRetrieval");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6971,6 +7238,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateCachediSCSIVolumeInput,
     ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCachediSCSIVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateCachediSCSIVolume",
@@ -6993,6 +7263,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateNFSFileShareInput,
     ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateNFSFileShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateNFSFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7011,6 +7284,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateSMBFileShareInput,
     ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSMBFileShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSMBFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7029,6 +7305,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateSnapshotInput,
     ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSnapshot");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7050,6 +7329,9 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSnapshotFromVolumeRecoveryPoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateSnapshotFromVolumeRecoveryPoint",
@@ -7075,6 +7357,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateStorediSCSIVolumeInput,
     ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateStorediSCSIVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateStorediSCSIVolume",
@@ -7097,6 +7382,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapeWithBarcodeInput,
     ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTapeWithBarcode");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.CreateTapeWithBarcode",
@@ -7118,6 +7406,9 @@ impl StorageGateway for StorageGatewayClient {
         input: CreateTapesInput,
     ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTapes");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.CreateTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7139,6 +7430,9 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<DeleteAutomaticTapeCreationPolicyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAutomaticTapeCreationPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteAutomaticTapeCreationPolicy",
@@ -7164,6 +7458,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteBandwidthRateLimitInput,
     ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteBandwidthRateLimit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteBandwidthRateLimit",
@@ -7186,6 +7483,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteChapCredentialsInput,
     ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteChapCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteChapCredentials",
@@ -7207,6 +7507,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteFileShareInput,
     ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteFileShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7225,6 +7528,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteGatewayInput,
     ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7243,6 +7549,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteSnapshotScheduleInput,
     ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSnapshotSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DeleteSnapshotSchedule",
@@ -7265,6 +7574,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapeInput,
     ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTape");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTape");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7283,6 +7595,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteTapeArchiveInput,
     ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTapeArchive");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteTapeArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7301,6 +7616,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DeleteVolumeInput,
     ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DeleteVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7322,6 +7640,9 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<DescribeAvailabilityMonitorTestError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAvailabilityMonitorTest");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeAvailabilityMonitorTest",
@@ -7345,6 +7666,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeBandwidthRateLimit");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeBandwidthRateLimit",
@@ -7367,6 +7691,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeCacheInput,
     ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCache");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7386,6 +7713,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCachediSCSIVolumes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeCachediSCSIVolumes",
@@ -7408,6 +7738,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeChapCredentialsInput,
     ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeChapCredentials");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeChapCredentials",
@@ -7431,6 +7764,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGatewayInformation");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeGatewayInformation",
@@ -7454,6 +7790,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeMaintenanceStartTime");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeMaintenanceStartTime",
@@ -7476,6 +7815,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeNFSFileSharesInput,
     ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeNFSFileShares");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeNFSFileShares",
@@ -7497,6 +7839,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSMBFileSharesInput,
     ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSMBFileShares");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeSMBFileShares",
@@ -7518,6 +7863,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSMBSettingsInput,
     ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSMBSettings");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeSMBSettings",
@@ -7539,6 +7887,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeSnapshotScheduleInput,
     ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSnapshotSchedule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeSnapshotSchedule",
@@ -7562,6 +7913,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeStorediSCSIVolumes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeStorediSCSIVolumes",
@@ -7584,6 +7938,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeTapeArchivesInput,
     ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTapeArchives");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeTapeArchives",
@@ -7606,6 +7963,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTapeRecoveryPoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeTapeRecoveryPoints",
@@ -7628,6 +7988,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeTapesInput,
     ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTapes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7646,6 +8009,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeUploadBufferInput,
     ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUploadBuffer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeUploadBuffer",
@@ -7667,6 +8033,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeVTLDevicesInput,
     ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeVTLDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.DescribeVTLDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7685,6 +8054,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DescribeWorkingStorageInput,
     ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkingStorage");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.DescribeWorkingStorage",
@@ -7707,6 +8079,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DetachVolumeInput,
     ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetachVolume");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DetachVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7725,6 +8100,9 @@ impl StorageGateway for StorageGatewayClient {
         input: DisableGatewayInput,
     ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisableGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.DisableGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7743,6 +8121,9 @@ impl StorageGateway for StorageGatewayClient {
         input: JoinDomainInput,
     ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("JoinDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.JoinDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7764,6 +8145,9 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<ListAutomaticTapeCreationPoliciesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAutomaticTapeCreationPolicies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListAutomaticTapeCreationPolicies",
@@ -7789,6 +8173,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListFileSharesInput,
     ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListFileShares");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.ListFileShares");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7807,6 +8194,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListGatewaysInput,
     ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGateways");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.ListGateways");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7825,6 +8215,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListLocalDisksInput,
     ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLocalDisks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.ListLocalDisks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7843,6 +8236,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListTagsForResource",
@@ -7864,6 +8260,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListTapesInput,
     ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTapes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.ListTapes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7882,6 +8281,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumeInitiatorsInput,
     ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVolumeInitiators");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListVolumeInitiators",
@@ -7903,6 +8305,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumeRecoveryPointsInput,
     ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVolumeRecoveryPoints");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.ListVolumeRecoveryPoints",
@@ -7925,6 +8330,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ListVolumesInput,
     ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVolumes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "StorageGateway_20130630.ListVolumes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7943,6 +8351,9 @@ impl StorageGateway for StorageGatewayClient {
         input: NotifyWhenUploadedInput,
     ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("NotifyWhenUploaded");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.NotifyWhenUploaded");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7961,6 +8372,9 @@ impl StorageGateway for StorageGatewayClient {
         input: RefreshCacheInput,
     ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RefreshCache");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.RefreshCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7979,6 +8393,9 @@ impl StorageGateway for StorageGatewayClient {
         input: RemoveTagsFromResourceInput,
     ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RemoveTagsFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.RemoveTagsFromResource",
@@ -8001,6 +8418,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ResetCacheInput,
     ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResetCache");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.ResetCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8019,6 +8439,9 @@ impl StorageGateway for StorageGatewayClient {
         input: RetrieveTapeArchiveInput,
     ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RetrieveTapeArchive");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.RetrieveTapeArchive",
@@ -8040,6 +8463,9 @@ impl StorageGateway for StorageGatewayClient {
         input: RetrieveTapeRecoveryPointInput,
     ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RetrieveTapeRecoveryPoint");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.RetrieveTapeRecoveryPoint",
@@ -8062,6 +8488,9 @@ impl StorageGateway for StorageGatewayClient {
         input: SetLocalConsolePasswordInput,
     ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetLocalConsolePassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.SetLocalConsolePassword",
@@ -8084,6 +8513,9 @@ impl StorageGateway for StorageGatewayClient {
         input: SetSMBGuestPasswordInput,
     ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SetSMBGuestPassword");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.SetSMBGuestPassword",
@@ -8105,6 +8537,9 @@ impl StorageGateway for StorageGatewayClient {
         input: ShutdownGatewayInput,
     ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ShutdownGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.ShutdownGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8124,6 +8559,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartAvailabilityMonitorTest");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.StartAvailabilityMonitorTest",
@@ -8146,6 +8584,9 @@ impl StorageGateway for StorageGatewayClient {
         input: StartGatewayInput,
     ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartGateway");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.StartGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8167,6 +8608,9 @@ impl StorageGateway for StorageGatewayClient {
         RusotoError<UpdateAutomaticTapeCreationPolicyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateAutomaticTapeCreationPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateAutomaticTapeCreationPolicy",
@@ -8192,6 +8636,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateBandwidthRateLimitInput,
     ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateBandwidthRateLimit");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateBandwidthRateLimit",
@@ -8214,6 +8661,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateChapCredentialsInput,
     ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateChapCredentials");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateChapCredentials",
@@ -8235,6 +8685,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateGatewayInformationInput,
     ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGatewayInformation");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateGatewayInformation",
@@ -8257,6 +8710,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateGatewaySoftwareNowInput,
     ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGatewaySoftwareNow");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateGatewaySoftwareNow",
@@ -8280,6 +8736,9 @@ impl StorageGateway for StorageGatewayClient {
     ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMaintenanceStartTime");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateMaintenanceStartTime",
@@ -8302,6 +8761,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateNFSFileShareInput,
     ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateNFSFileShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateNFSFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8320,6 +8782,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSMBFileShareInput,
     ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSMBFileShare");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "StorageGateway_20130630.UpdateSMBFileShare");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8338,6 +8803,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSMBSecurityStrategyInput,
     ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSMBSecurityStrategy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateSMBSecurityStrategy",
@@ -8360,6 +8828,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateSnapshotScheduleInput,
     ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSnapshotSchedule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateSnapshotSchedule",
@@ -8382,6 +8853,9 @@ impl StorageGateway for StorageGatewayClient {
         input: UpdateVTLDeviceTypeInput,
     ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVTLDeviceType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "StorageGateway_20130630.UpdateVTLDeviceType",
@@ -8397,3 +8871,909 @@ impl StorageGateway for StorageGatewayClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVTLDeviceTypeOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Storage Gateway API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `StorageGatewayClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct StorageGatewayBlockingClient {
+    client: StorageGatewayClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl StorageGatewayBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> StorageGatewayBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        StorageGatewayBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> StorageGatewayBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        StorageGatewayBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> StorageGatewayBlockingClient {
+        StorageGatewayBlockingClient {
+            client: StorageGatewayClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Activates the gateway you previously deployed on your host. In the activation process, you specify information such as the AWS Region that you want to use for storing snapshots or tapes, the time zone for scheduled snapshots the gateway snapshot schedule window, an activation key, and a name for your gateway. The activation process also associates your gateway with your account. For more information, see <a>UpdateGatewayInformation</a>.</p> <note> <p>You must turn on the gateway VM before you can activate your gateway.</p> </note></p>
+    ///
+    /// See `StorageGateway::activate_gateway`.
+    pub fn activate_gateway(
+        &self,
+        input: ActivateGatewayInput,
+    ) -> Result<ActivateGatewayOutput, RusotoError<ActivateGatewayError>> {
+        self.runtime.block_on(self.client.activate_gateway(input))
+    }
+
+    /// <p>Configures one or more gateway local disks as cache for a gateway. This operation is only supported in the cached volume, tape, and file gateway type (see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/StorageGatewayConcepts.html">How AWS Storage Gateway works (architecture)</a>.</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add cache, and one or more disk IDs that you want to configure as cache.</p>
+    ///
+    /// See `StorageGateway::add_cache`.
+    pub fn add_cache(
+        &self,
+        input: AddCacheInput,
+    ) -> Result<AddCacheOutput, RusotoError<AddCacheError>> {
+        self.runtime.block_on(self.client.add_cache(input))
+    }
+
+    /// <p>Adds one or more tags to the specified resource. You use tags to add metadata to resources, which you can use to categorize these resources. For example, you can categorize resources by purpose, owner, environment, or team. Each tag consists of a key and a value, which you define. You can add tags to the following AWS Storage Gateway resources:</p> <ul> <li> <p>Storage gateways of all types</p> </li> <li> <p>Storage volumes</p> </li> <li> <p>Virtual tapes</p> </li> <li> <p>NFS and SMB file shares</p> </li> </ul> <p>You can create a maximum of 50 tags for each resource. Virtual tapes and storage volumes that are recovered to a new gateway maintain their tags.</p>
+    ///
+    /// See `StorageGateway::add_tags_to_resource`.
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceInput,
+    ) -> Result<AddTagsToResourceOutput, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// <p>Configures one or more gateway local disks as upload buffer for a specified gateway. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add upload buffer, and one or more disk IDs that you want to configure as upload buffer.</p>
+    ///
+    /// See `StorageGateway::add_upload_buffer`.
+    pub fn add_upload_buffer(
+        &self,
+        input: AddUploadBufferInput,
+    ) -> Result<AddUploadBufferOutput, RusotoError<AddUploadBufferError>> {
+        self.runtime.block_on(self.client.add_upload_buffer(input))
+    }
+
+    /// <p>Configures one or more gateway local disks as working storage for a gateway. This operation is only supported in the stored volume gateway type. This operation is deprecated in cached volume API version 20120630. Use <a>AddUploadBuffer</a> instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the <a>AddUploadBuffer</a> operation to add upload buffer to a stored volume gateway.</p> </note> <p>In the request, you specify the gateway Amazon Resource Name (ARN) to which you want to add working storage, and one or more disk IDs that you want to configure as working storage.</p>
+    ///
+    /// See `StorageGateway::add_working_storage`.
+    pub fn add_working_storage(
+        &self,
+        input: AddWorkingStorageInput,
+    ) -> Result<AddWorkingStorageOutput, RusotoError<AddWorkingStorageError>> {
+        self.runtime
+            .block_on(self.client.add_working_storage(input))
+    }
+
+    /// <p>Assigns a tape to a tape pool for archiving. The tape assigned to a pool is archived in the S3 storage class that is associated with the pool. When you use your backup application to eject the tape, the tape is archived directly into the S3 storage class (S3 Glacier or S3 Glacier Deep Archive) that corresponds to the pool.</p> <p>Valid Values: <code>GLACIER</code> | <code>DEEP_ARCHIVE</code> </p>
+    ///
+    /// See `StorageGateway::assign_tape_pool`.
+    pub fn assign_tape_pool(
+        &self,
+        input: AssignTapePoolInput,
+    ) -> Result<AssignTapePoolOutput, RusotoError<AssignTapePoolError>> {
+        self.runtime.block_on(self.client.assign_tape_pool(input))
+    }
+
+    /// <p>Connects a volume to an iSCSI connection and then attaches the volume to the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance.</p>
+    ///
+    /// See `StorageGateway::attach_volume`.
+    pub fn attach_volume(
+        &self,
+        input: AttachVolumeInput,
+    ) -> Result<AttachVolumeOutput, RusotoError<AttachVolumeError>> {
+        self.runtime.block_on(self.client.attach_volume(input))
+    }
+
+    /// <p>Cancels archiving of a virtual tape to the virtual tape shelf (VTS) after the archiving process is initiated. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::cancel_archival`.
+    pub fn cancel_archival(
+        &self,
+        input: CancelArchivalInput,
+    ) -> Result<CancelArchivalOutput, RusotoError<CancelArchivalError>> {
+        self.runtime.block_on(self.client.cancel_archival(input))
+    }
+
+    /// <p>Cancels retrieval of a virtual tape from the virtual tape shelf (VTS) to a gateway after the retrieval process is initiated. The virtual tape is returned to the VTS. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::cancel_retrieval`.
+    pub fn cancel_retrieval(
+        &self,
+        input: CancelRetrievalInput,
+    ) -> Result<CancelRetrievalOutput, RusotoError<CancelRetrievalError>> {
+        self.runtime.block_on(self.client.cancel_retrieval(input))
+    }
+
+    /// <p>Creates a cached volume on a specified cached volume gateway. This operation is only supported in the cached volume gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a cached volume. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note> <p>In the request, you must specify the gateway, size of the volume in bytes, the iSCSI target name, an IP address on which to expose the target, and a unique client token. In response, the gateway creates the volume and returns information about it. This information includes the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p> <p>Optionally, you can provide the ARN for an existing volume as the <code>SourceVolumeARN</code> for this cached volume, which creates an exact copy of the existing volume’s latest recovery point. The <code>VolumeSizeInBytes</code> value must be equal to or larger than the size of the copied volume, in bytes.</p>
+    ///
+    /// See `StorageGateway::create_cachedi_scsi_volume`.
+    pub fn create_cachedi_scsi_volume(
+        &self,
+        input: CreateCachediSCSIVolumeInput,
+    ) -> Result<CreateCachediSCSIVolumeOutput, RusotoError<CreateCachediSCSIVolumeError>> {
+        self.runtime
+            .block_on(self.client.create_cachedi_scsi_volume(input))
+    }
+
+    /// <p><p>Creates a Network File System (NFS) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway exposes file shares using an NFS interface. This operation is only supported for file gateways.</p> <important> <p>File gateway requires AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in the AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p> <p>File gateway does not support creating hard or symbolic links on a file share.</p> </important></p>
+    ///
+    /// See `StorageGateway::create_nfs_file_share`.
+    pub fn create_nfs_file_share(
+        &self,
+        input: CreateNFSFileShareInput,
+    ) -> Result<CreateNFSFileShareOutput, RusotoError<CreateNFSFileShareError>> {
+        self.runtime
+            .block_on(self.client.create_nfs_file_share(input))
+    }
+
+    /// <p><p>Creates a Server Message Block (SMB) file share on an existing file gateway. In Storage Gateway, a file share is a file system mount point backed by Amazon S3 cloud storage. Storage Gateway expose file shares using an SMB interface. This operation is only supported for file gateways.</p> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
+    ///
+    /// See `StorageGateway::create_smb_file_share`.
+    pub fn create_smb_file_share(
+        &self,
+        input: CreateSMBFileShareInput,
+    ) -> Result<CreateSMBFileShareOutput, RusotoError<CreateSMBFileShareError>> {
+        self.runtime
+            .block_on(self.client.create_smb_file_share(input))
+    }
+
+    /// <p><p>Initiates a snapshot of a volume.</p> <p>AWS Storage Gateway provides the ability to back up point-in-time snapshots of your data to Amazon Simple Storage (Amazon S3) for durable off-site recovery, as well as import the data to an Amazon Elastic Block Store (EBS) volume in Amazon Elastic Compute Cloud (EC2). You can take snapshots of your gateway volume on a scheduled or ad hoc basis. This API enables you to take ad-hoc snapshot. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/managing-volumes.html#SchedulingSnapshot">Editing a snapshot schedule</a>.</p> <p>In the CreateSnapshot request you identify the volume by providing its Amazon Resource Name (ARN). You must also provide description for the snapshot. When AWS Storage Gateway takes the snapshot of specified volume, the snapshot and description appears in the AWS Storage Gateway Console. In response, AWS Storage Gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot. This operation is only supported in stored and cached volume gateway type.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeSnapshots.html">DescribeSnapshots</a> or <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DeleteSnapshot.html">DeleteSnapshot</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note> <important> <p>Volume and snapshot IDs are changing to a longer length ID format. For more information, see the important note on the <a href="https://docs.aws.amazon.com/storagegateway/latest/APIReference/Welcome.html">Welcome</a> page.</p> </important></p>
+    ///
+    /// See `StorageGateway::create_snapshot`.
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotInput,
+    ) -> Result<CreateSnapshotOutput, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// <p><p>Initiates a snapshot of a gateway from a volume recovery point. This operation is only supported in the cached volume gateway type.</p> <p>A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot. To get a list of volume recovery point for cached volume gateway, use <a>ListVolumeRecoveryPoints</a>.</p> <p>In the <code>CreateSnapshotFromVolumeRecoveryPoint</code> request, you identify the volume by providing its Amazon Resource Name (ARN). You must also provide a description for the snapshot. When the gateway takes a snapshot of the specified volume, the snapshot and its description appear in the AWS Storage Gateway console. In response, the gateway returns you a snapshot ID. You can use this snapshot ID to check the snapshot progress or later use it when you want to create a volume from a snapshot.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeSnapshots.html">DescribeSnapshots</a> or <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DeleteSnapshot.html">DeleteSnapshot</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
+    ///
+    /// See `StorageGateway::create_snapshot_from_volume_recovery_point`.
+    pub fn create_snapshot_from_volume_recovery_point(
+        &self,
+        input: CreateSnapshotFromVolumeRecoveryPointInput,
+    ) -> Result<
+        CreateSnapshotFromVolumeRecoveryPointOutput,
+        RusotoError<CreateSnapshotFromVolumeRecoveryPointError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_snapshot_from_volume_recovery_point(input),
+        )
+    }
+
+    /// <p>Creates a volume on a specified gateway. This operation is only supported in the stored volume gateway type.</p> <p>The size of the volume to create is inferred from the disk size. You can choose to preserve existing data on the disk, create volume from an existing snapshot, or create an empty volume. If you choose to create an empty gateway volume, then any existing data on the disk is erased.</p> <p>In the request you must specify the gateway and the disk information on which you are creating the volume. In response, the gateway creates the volume and returns volume information such as the volume Amazon Resource Name (ARN), its size, and the iSCSI target ARN that initiators can use to connect to the volume target.</p>
+    ///
+    /// See `StorageGateway::create_storedi_scsi_volume`.
+    pub fn create_storedi_scsi_volume(
+        &self,
+        input: CreateStorediSCSIVolumeInput,
+    ) -> Result<CreateStorediSCSIVolumeOutput, RusotoError<CreateStorediSCSIVolumeError>> {
+        self.runtime
+            .block_on(self.client.create_storedi_scsi_volume(input))
+    }
+
+    /// <p><p>Creates a virtual tape by using your own barcode. You write data to the virtual tape and then archive the tape. A barcode is unique and can not be reused if it has already been used on a tape. This applies to barcodes used on deleted tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create a virtual tape. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note></p>
+    ///
+    /// See `StorageGateway::create_tape_with_barcode`.
+    pub fn create_tape_with_barcode(
+        &self,
+        input: CreateTapeWithBarcodeInput,
+    ) -> Result<CreateTapeWithBarcodeOutput, RusotoError<CreateTapeWithBarcodeError>> {
+        self.runtime
+            .block_on(self.client.create_tape_with_barcode(input))
+    }
+
+    /// <p><p>Creates one or more virtual tapes. You write data to the virtual tapes and then archive the tapes. This operation is only supported in the tape gateway type.</p> <note> <p>Cache storage must be allocated to the gateway before you can create virtual tapes. Use the <a>AddCache</a> operation to add cache storage to a gateway.</p> </note></p>
+    ///
+    /// See `StorageGateway::create_tapes`.
+    pub fn create_tapes(
+        &self,
+        input: CreateTapesInput,
+    ) -> Result<CreateTapesOutput, RusotoError<CreateTapesError>> {
+        self.runtime.block_on(self.client.create_tapes(input))
+    }
+
+    /// <p>Deletes the automatic tape creation policy of a gateway. If you delete this policy, new virtual tapes must be created manually. Use the Amazon Resource Name (ARN) of the gateway in your request to remove the policy.</p>
+    ///
+    /// See `StorageGateway::delete_automatic_tape_creation_policy`.
+    pub fn delete_automatic_tape_creation_policy(
+        &self,
+        input: DeleteAutomaticTapeCreationPolicyInput,
+    ) -> Result<
+        DeleteAutomaticTapeCreationPolicyOutput,
+        RusotoError<DeleteAutomaticTapeCreationPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_automatic_tape_creation_policy(input))
+    }
+
+    /// <p>Deletes the bandwidth rate limits of a gateway. You can delete either the upload and download bandwidth rate limit, or you can delete both. If you delete only one of the limits, the other limit remains unchanged. To specify which gateway to work with, use the Amazon Resource Name (ARN) of the gateway in your request. This operation is supported for the stored volume, cached volume and tape gateway types.</p>
+    ///
+    /// See `StorageGateway::delete_bandwidth_rate_limit`.
+    pub fn delete_bandwidth_rate_limit(
+        &self,
+        input: DeleteBandwidthRateLimitInput,
+    ) -> Result<DeleteBandwidthRateLimitOutput, RusotoError<DeleteBandwidthRateLimitError>> {
+        self.runtime
+            .block_on(self.client.delete_bandwidth_rate_limit(input))
+    }
+
+    /// <p>Deletes Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target and initiator pair. This operation is supported in volume and tape gateway types.</p>
+    ///
+    /// See `StorageGateway::delete_chap_credentials`.
+    pub fn delete_chap_credentials(
+        &self,
+        input: DeleteChapCredentialsInput,
+    ) -> Result<DeleteChapCredentialsOutput, RusotoError<DeleteChapCredentialsError>> {
+        self.runtime
+            .block_on(self.client.delete_chap_credentials(input))
+    }
+
+    /// <p>Deletes a file share from a file gateway. This operation is only supported for file gateways.</p>
+    ///
+    /// See `StorageGateway::delete_file_share`.
+    pub fn delete_file_share(
+        &self,
+        input: DeleteFileShareInput,
+    ) -> Result<DeleteFileShareOutput, RusotoError<DeleteFileShareError>> {
+        self.runtime.block_on(self.client.delete_file_share(input))
+    }
+
+    /// <p><p>Deletes a gateway. To specify which gateway to delete, use the Amazon Resource Name (ARN) of the gateway in your request. The operation deletes the gateway; however, it does not delete the gateway virtual machine (VM) from your host computer.</p> <p>After you delete a gateway, you cannot reactivate it. Completed snapshots of the gateway volumes are not deleted upon deleting the gateway, however, pending snapshots will not complete. After you delete a gateway, your next step is to remove it from your environment.</p> <important> <p>You no longer pay software charges after the gateway is deleted; however, your existing Amazon EBS snapshots persist and you will continue to be billed for these snapshots. You can choose to remove all remaining Amazon EBS snapshots by canceling your Amazon EC2 subscription.  If you prefer not to cancel your Amazon EC2 subscription, you can delete your snapshots using the Amazon EC2 console. For more information, see the <a href="http://aws.amazon.com/storagegateway">AWS Storage Gateway detail page</a>.</p> </important></p>
+    ///
+    /// See `StorageGateway::delete_gateway`.
+    pub fn delete_gateway(
+        &self,
+        input: DeleteGatewayInput,
+    ) -> Result<DeleteGatewayOutput, RusotoError<DeleteGatewayError>> {
+        self.runtime.block_on(self.client.delete_gateway(input))
+    }
+
+    /// <p><p>Deletes a snapshot of a volume.</p> <p>You can take snapshots of your gateway volumes on a scheduled or ad hoc basis. This API action enables you to delete a snapshot schedule for a volume. For more information, see <a href="https://docs.aws.amazon.com/storagegatewaylatest/userguide/backing-up-volumes.html">Backing up your volumes</a>. In the <code>DeleteSnapshotSchedule</code> request, you identify the volume by providing its Amazon Resource Name (ARN). This operation is only supported in stored and cached volume gateway types.</p> <note> <p>To list or delete a snapshot, you must use the Amazon EC2 API. For more information, go to <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_DescribeSnapshots.html">DescribeSnapshots</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> </note></p>
+    ///
+    /// See `StorageGateway::delete_snapshot_schedule`.
+    pub fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleInput,
+    ) -> Result<DeleteSnapshotScheduleOutput, RusotoError<DeleteSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.delete_snapshot_schedule(input))
+    }
+
+    /// <p>Deletes the specified virtual tape. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::delete_tape`.
+    pub fn delete_tape(
+        &self,
+        input: DeleteTapeInput,
+    ) -> Result<DeleteTapeOutput, RusotoError<DeleteTapeError>> {
+        self.runtime.block_on(self.client.delete_tape(input))
+    }
+
+    /// <p>Deletes the specified virtual tape from the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::delete_tape_archive`.
+    pub fn delete_tape_archive(
+        &self,
+        input: DeleteTapeArchiveInput,
+    ) -> Result<DeleteTapeArchiveOutput, RusotoError<DeleteTapeArchiveError>> {
+        self.runtime
+            .block_on(self.client.delete_tape_archive(input))
+    }
+
+    /// <p>Deletes the specified storage volume that you previously created using the <a>CreateCachediSCSIVolume</a> or <a>CreateStorediSCSIVolume</a> API. This operation is only supported in the cached volume and stored volume types. For stored volume gateways, the local disk that was configured as the storage volume is not deleted. You can reuse the local disk to create another storage volume.</p> <p>Before you delete a volume, make sure there are no iSCSI connections to the volume you are deleting. You should also make sure there is no snapshot in progress. You can use the Amazon Elastic Compute Cloud (Amazon EC2) API to query snapshots on the volume you are deleting and check the snapshot status. For more information, go to <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/ApiReference-query-DescribeSnapshots.html">DescribeSnapshots</a> in the <i>Amazon Elastic Compute Cloud API Reference</i>.</p> <p>In the request, you must provide the Amazon Resource Name (ARN) of the storage volume you want to delete.</p>
+    ///
+    /// See `StorageGateway::delete_volume`.
+    pub fn delete_volume(
+        &self,
+        input: DeleteVolumeInput,
+    ) -> Result<DeleteVolumeOutput, RusotoError<DeleteVolumeError>> {
+        self.runtime.block_on(self.client.delete_volume(input))
+    }
+
+    /// <p>Returns information about the most recent High Availability monitoring test that was performed on the host in a cluster. If a test isn't performed, the status and start time in the response would be null.</p>
+    ///
+    /// See `StorageGateway::describe_availability_monitor_test`.
+    pub fn describe_availability_monitor_test(
+        &self,
+        input: DescribeAvailabilityMonitorTestInput,
+    ) -> Result<
+        DescribeAvailabilityMonitorTestOutput,
+        RusotoError<DescribeAvailabilityMonitorTestError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_availability_monitor_test(input))
+    }
+
+    /// <p>Returns the bandwidth rate limits of a gateway. By default, these limits are not set, which means no bandwidth rate limiting is in effect. This operation is supported for the stored volume, cached volume and tape gateway types.</p> <p>This operation only returns a value for a bandwidth rate limit only if the limit is set. If no limits are set for the gateway, then this operation returns only the gateway ARN in the response body. To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    ///
+    /// See `StorageGateway::describe_bandwidth_rate_limit`.
+    pub fn describe_bandwidth_rate_limit(
+        &self,
+        input: DescribeBandwidthRateLimitInput,
+    ) -> Result<DescribeBandwidthRateLimitOutput, RusotoError<DescribeBandwidthRateLimitError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_bandwidth_rate_limit(input))
+    }
+
+    /// <p>Returns information about the cache of a gateway. This operation is only supported in the cached volume, tape, and file gateway types.</p> <p>The response includes disk IDs that are configured as cache, and it includes the amount of cache allocated and used.</p>
+    ///
+    /// See `StorageGateway::describe_cache`.
+    pub fn describe_cache(
+        &self,
+        input: DescribeCacheInput,
+    ) -> Result<DescribeCacheOutput, RusotoError<DescribeCacheError>> {
+        self.runtime.block_on(self.client.describe_cache(input))
+    }
+
+    /// <p>Returns a description of the gateway volumes specified in the request. This operation is only supported in the cached volume gateway types.</p> <p>The list of gateway volumes in the request must be from one gateway. In the response, AWS Storage Gateway returns volume information sorted by volume Amazon Resource Name (ARN).</p>
+    ///
+    /// See `StorageGateway::describe_cachedi_scsi_volumes`.
+    pub fn describe_cachedi_scsi_volumes(
+        &self,
+        input: DescribeCachediSCSIVolumesInput,
+    ) -> Result<DescribeCachediSCSIVolumesOutput, RusotoError<DescribeCachediSCSIVolumesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_cachedi_scsi_volumes(input))
+    }
+
+    /// <p>Returns an array of Challenge-Handshake Authentication Protocol (CHAP) credentials information for a specified iSCSI target, one for each target-initiator pair. This operation is supported in the volume and tape gateway types.</p>
+    ///
+    /// See `StorageGateway::describe_chap_credentials`.
+    pub fn describe_chap_credentials(
+        &self,
+        input: DescribeChapCredentialsInput,
+    ) -> Result<DescribeChapCredentialsOutput, RusotoError<DescribeChapCredentialsError>> {
+        self.runtime
+            .block_on(self.client.describe_chap_credentials(input))
+    }
+
+    /// <p>Returns metadata about a gateway such as its name, network interfaces, configured time zone, and the state (whether the gateway is running or not). To specify which gateway to describe, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    ///
+    /// See `StorageGateway::describe_gateway_information`.
+    pub fn describe_gateway_information(
+        &self,
+        input: DescribeGatewayInformationInput,
+    ) -> Result<DescribeGatewayInformationOutput, RusotoError<DescribeGatewayInformationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_gateway_information(input))
+    }
+
+    /// <p>Returns your gateway's weekly maintenance start time including the day and time of the week. Note that values are in terms of the gateway's time zone.</p>
+    ///
+    /// See `StorageGateway::describe_maintenance_start_time`.
+    pub fn describe_maintenance_start_time(
+        &self,
+        input: DescribeMaintenanceStartTimeInput,
+    ) -> Result<DescribeMaintenanceStartTimeOutput, RusotoError<DescribeMaintenanceStartTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_maintenance_start_time(input))
+    }
+
+    /// <p>Gets a description for one or more Network File System (NFS) file shares from a file gateway. This operation is only supported for file gateways.</p>
+    ///
+    /// See `StorageGateway::describe_nfs_file_shares`.
+    pub fn describe_nfs_file_shares(
+        &self,
+        input: DescribeNFSFileSharesInput,
+    ) -> Result<DescribeNFSFileSharesOutput, RusotoError<DescribeNFSFileSharesError>> {
+        self.runtime
+            .block_on(self.client.describe_nfs_file_shares(input))
+    }
+
+    /// <p>Gets a description for one or more Server Message Block (SMB) file shares from a file gateway. This operation is only supported for file gateways.</p>
+    ///
+    /// See `StorageGateway::describe_smb_file_shares`.
+    pub fn describe_smb_file_shares(
+        &self,
+        input: DescribeSMBFileSharesInput,
+    ) -> Result<DescribeSMBFileSharesOutput, RusotoError<DescribeSMBFileSharesError>> {
+        self.runtime
+            .block_on(self.client.describe_smb_file_shares(input))
+    }
+
+    /// <p>Gets a description of a Server Message Block (SMB) file share settings from a file gateway. This operation is only supported for file gateways.</p>
+    ///
+    /// See `StorageGateway::describe_smb_settings`.
+    pub fn describe_smb_settings(
+        &self,
+        input: DescribeSMBSettingsInput,
+    ) -> Result<DescribeSMBSettingsOutput, RusotoError<DescribeSMBSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_smb_settings(input))
+    }
+
+    /// <p>Describes the snapshot schedule for the specified gateway volume. The snapshot schedule information includes intervals at which snapshots are automatically initiated on the volume. This operation is only supported in the cached volume and stored volume types.</p>
+    ///
+    /// See `StorageGateway::describe_snapshot_schedule`.
+    pub fn describe_snapshot_schedule(
+        &self,
+        input: DescribeSnapshotScheduleInput,
+    ) -> Result<DescribeSnapshotScheduleOutput, RusotoError<DescribeSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.describe_snapshot_schedule(input))
+    }
+
+    /// <p>Returns the description of the gateway volumes specified in the request. The list of gateway volumes in the request must be from one gateway. In the response, AWS Storage Gateway returns volume information sorted by volume ARNs. This operation is only supported in stored volume gateway type.</p>
+    ///
+    /// See `StorageGateway::describe_storedi_scsi_volumes`.
+    pub fn describe_storedi_scsi_volumes(
+        &self,
+        input: DescribeStorediSCSIVolumesInput,
+    ) -> Result<DescribeStorediSCSIVolumesOutput, RusotoError<DescribeStorediSCSIVolumesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_storedi_scsi_volumes(input))
+    }
+
+    /// <p>Returns a description of specified virtual tapes in the virtual tape shelf (VTS). This operation is only supported in the tape gateway type.</p> <p>If a specific <code>TapeARN</code> is not specified, AWS Storage Gateway returns a description of all virtual tapes found in the VTS associated with your account.</p>
+    ///
+    /// See `StorageGateway::describe_tape_archives`.
+    pub fn describe_tape_archives(
+        &self,
+        input: DescribeTapeArchivesInput,
+    ) -> Result<DescribeTapeArchivesOutput, RusotoError<DescribeTapeArchivesError>> {
+        self.runtime
+            .block_on(self.client.describe_tape_archives(input))
+    }
+
+    /// <p>Returns a list of virtual tape recovery points that are available for the specified tape gateway.</p> <p>A recovery point is a point-in-time view of a virtual tape at which all the data on the virtual tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::describe_tape_recovery_points`.
+    pub fn describe_tape_recovery_points(
+        &self,
+        input: DescribeTapeRecoveryPointsInput,
+    ) -> Result<DescribeTapeRecoveryPointsOutput, RusotoError<DescribeTapeRecoveryPointsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_tape_recovery_points(input))
+    }
+
+    /// <p>Returns a description of the specified Amazon Resource Name (ARN) of virtual tapes. If a <code>TapeARN</code> is not specified, returns a description of all virtual tapes associated with the specified gateway. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::describe_tapes`.
+    pub fn describe_tapes(
+        &self,
+        input: DescribeTapesInput,
+    ) -> Result<DescribeTapesOutput, RusotoError<DescribeTapesError>> {
+        self.runtime.block_on(self.client.describe_tapes(input))
+    }
+
+    /// <p>Returns information about the upload buffer of a gateway. This operation is supported for the stored volume, cached volume, and tape gateway types.</p> <p>The response includes disk IDs that are configured as upload buffer space, and it includes the amount of upload buffer space allocated and used.</p>
+    ///
+    /// See `StorageGateway::describe_upload_buffer`.
+    pub fn describe_upload_buffer(
+        &self,
+        input: DescribeUploadBufferInput,
+    ) -> Result<DescribeUploadBufferOutput, RusotoError<DescribeUploadBufferError>> {
+        self.runtime
+            .block_on(self.client.describe_upload_buffer(input))
+    }
+
+    /// <p>Returns a description of virtual tape library (VTL) devices for the specified tape gateway. In the response, AWS Storage Gateway returns VTL device information.</p> <p>This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::describe_vtl_devices`.
+    pub fn describe_vtl_devices(
+        &self,
+        input: DescribeVTLDevicesInput,
+    ) -> Result<DescribeVTLDevicesOutput, RusotoError<DescribeVTLDevicesError>> {
+        self.runtime
+            .block_on(self.client.describe_vtl_devices(input))
+    }
+
+    /// <p>Returns information about the working storage of a gateway. This operation is only supported in the stored volumes gateway type. This operation is deprecated in cached volumes API version (20120630). Use DescribeUploadBuffer instead.</p> <note> <p>Working storage is also referred to as upload buffer. You can also use the DescribeUploadBuffer operation to add upload buffer to a stored volume gateway.</p> </note> <p>The response includes disk IDs that are configured as working storage, and it includes the amount of working storage allocated and used.</p>
+    ///
+    /// See `StorageGateway::describe_working_storage`.
+    pub fn describe_working_storage(
+        &self,
+        input: DescribeWorkingStorageInput,
+    ) -> Result<DescribeWorkingStorageOutput, RusotoError<DescribeWorkingStorageError>> {
+        self.runtime
+            .block_on(self.client.describe_working_storage(input))
+    }
+
+    /// <p>Disconnects a volume from an iSCSI connection and then detaches the volume from the specified gateway. Detaching and attaching a volume enables you to recover your data from one gateway to a different gateway without creating a snapshot. It also makes it easier to move your volumes from an on-premises gateway to a gateway hosted on an Amazon EC2 instance. This operation is only supported in the volume gateway type.</p>
+    ///
+    /// See `StorageGateway::detach_volume`.
+    pub fn detach_volume(
+        &self,
+        input: DetachVolumeInput,
+    ) -> Result<DetachVolumeOutput, RusotoError<DetachVolumeError>> {
+        self.runtime.block_on(self.client.detach_volume(input))
+    }
+
+    /// <p><p>Disables a tape gateway when the gateway is no longer functioning. For example, if your gateway VM is damaged, you can disable the gateway so you can recover virtual tapes.</p> <p>Use this operation for a tape gateway that is not reachable or not functioning. This operation is only supported in the tape gateway type.</p> <important> <p>After a gateway is disabled, it cannot be enabled.</p> </important></p>
+    ///
+    /// See `StorageGateway::disable_gateway`.
+    pub fn disable_gateway(
+        &self,
+        input: DisableGatewayInput,
+    ) -> Result<DisableGatewayOutput, RusotoError<DisableGatewayError>> {
+        self.runtime.block_on(self.client.disable_gateway(input))
+    }
+
+    /// <p>Adds a file gateway to an Active Directory domain. This operation is only supported for file gateways that support the SMB file protocol.</p>
+    ///
+    /// See `StorageGateway::join_domain`.
+    pub fn join_domain(
+        &self,
+        input: JoinDomainInput,
+    ) -> Result<JoinDomainOutput, RusotoError<JoinDomainError>> {
+        self.runtime.block_on(self.client.join_domain(input))
+    }
+
+    /// <p>Lists the automatic tape creation policies for a gateway. If there are no automatic tape creation policies for the gateway, it returns an empty list.</p> <p>This operation is only supported for tape gateways.</p>
+    ///
+    /// See `StorageGateway::list_automatic_tape_creation_policies`.
+    pub fn list_automatic_tape_creation_policies(
+        &self,
+        input: ListAutomaticTapeCreationPoliciesInput,
+    ) -> Result<
+        ListAutomaticTapeCreationPoliciesOutput,
+        RusotoError<ListAutomaticTapeCreationPoliciesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_automatic_tape_creation_policies(input))
+    }
+
+    /// <p>Gets a list of the file shares for a specific file gateway, or the list of file shares that belong to the calling user account. This operation is only supported for file gateways.</p>
+    ///
+    /// See `StorageGateway::list_file_shares`.
+    pub fn list_file_shares(
+        &self,
+        input: ListFileSharesInput,
+    ) -> Result<ListFileSharesOutput, RusotoError<ListFileSharesError>> {
+        self.runtime.block_on(self.client.list_file_shares(input))
+    }
+
+    /// <p>Lists gateways owned by an AWS account in an AWS Region specified in the request. The returned list is ordered by gateway Amazon Resource Name (ARN).</p> <p>By default, the operation returns a maximum of 100 gateways. This operation supports pagination that allows you to optionally reduce the number of gateways returned in a response.</p> <p>If you have more gateways than are returned in a response (that is, the response returns only a truncated list of your gateways), the response contains a marker that you can specify in your next request to fetch the next page of gateways.</p>
+    ///
+    /// See `StorageGateway::list_gateways`.
+    pub fn list_gateways(
+        &self,
+        input: ListGatewaysInput,
+    ) -> Result<ListGatewaysOutput, RusotoError<ListGatewaysError>> {
+        self.runtime.block_on(self.client.list_gateways(input))
+    }
+
+    /// <p>Returns a list of the gateway's local disks. To specify which gateway to describe, you use the Amazon Resource Name (ARN) of the gateway in the body of the request.</p> <p>The request returns a list of all disks, specifying which are configured as working storage, cache storage, or stored volume or not configured at all. The response includes a <code>DiskStatus</code> field. This field can have a value of present (the disk is available to use), missing (the disk is no longer connected to the gateway), or mismatch (the disk node is occupied by a disk that has incorrect metadata or the disk content is corrupted).</p>
+    ///
+    /// See `StorageGateway::list_local_disks`.
+    pub fn list_local_disks(
+        &self,
+        input: ListLocalDisksInput,
+    ) -> Result<ListLocalDisksOutput, RusotoError<ListLocalDisksError>> {
+        self.runtime.block_on(self.client.list_local_disks(input))
+    }
+
+    /// <p>Lists the tags that have been added to the specified resource. This operation is supported in storage gateways of all types.</p>
+    ///
+    /// See `StorageGateway::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists virtual tapes in your virtual tape library (VTL) and your virtual tape shelf (VTS). You specify the tapes to list by specifying one or more tape Amazon Resource Names (ARNs). If you don't specify a tape ARN, the operation lists all virtual tapes in both your VTL and VTS.</p> <p>This operation supports pagination. By default, the operation returns a maximum of up to 100 tapes. You can optionally specify the <code>Limit</code> parameter in the body to limit the number of tapes in the response. If the number of tapes returned in the response is truncated, the response includes a <code>Marker</code> element that you can use in your subsequent request to retrieve the next set of tapes. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::list_tapes`.
+    pub fn list_tapes(
+        &self,
+        input: ListTapesInput,
+    ) -> Result<ListTapesOutput, RusotoError<ListTapesError>> {
+        self.runtime.block_on(self.client.list_tapes(input))
+    }
+
+    /// <p>Lists iSCSI initiators that are connected to a volume. You can use this operation to determine whether a volume is being used or not. This operation is only supported in the cached volume and stored volume gateway types.</p>
+    ///
+    /// See `StorageGateway::list_volume_initiators`.
+    pub fn list_volume_initiators(
+        &self,
+        input: ListVolumeInitiatorsInput,
+    ) -> Result<ListVolumeInitiatorsOutput, RusotoError<ListVolumeInitiatorsError>> {
+        self.runtime
+            .block_on(self.client.list_volume_initiators(input))
+    }
+
+    /// <p>Lists the recovery points for a specified gateway. This operation is only supported in the cached volume gateway type.</p> <p>Each cache volume has one recovery point. A volume recovery point is a point in time at which all data of the volume is consistent and from which you can create a snapshot or clone a new cached volume from a source volume. To create a snapshot from a volume recovery point use the <a>CreateSnapshotFromVolumeRecoveryPoint</a> operation.</p>
+    ///
+    /// See `StorageGateway::list_volume_recovery_points`.
+    pub fn list_volume_recovery_points(
+        &self,
+        input: ListVolumeRecoveryPointsInput,
+    ) -> Result<ListVolumeRecoveryPointsOutput, RusotoError<ListVolumeRecoveryPointsError>> {
+        self.runtime
+            .block_on(self.client.list_volume_recovery_points(input))
+    }
+
+    /// <p>Lists the iSCSI stored volumes of a gateway. Results are sorted by volume ARN. The response includes only the volume ARNs. If you want additional volume information, use the <a>DescribeStorediSCSIVolumes</a> or the <a>DescribeCachediSCSIVolumes</a> API.</p> <p>The operation supports pagination. By default, the operation returns a maximum of up to 100 volumes. You can optionally specify the <code>Limit</code> field in the body to limit the number of volumes in the response. If the number of volumes returned in the response is truncated, the response includes a Marker field. You can use this Marker value in your subsequent request to retrieve the next set of volumes. This operation is only supported in the cached volume and stored volume gateway types.</p>
+    ///
+    /// See `StorageGateway::list_volumes`.
+    pub fn list_volumes(
+        &self,
+        input: ListVolumesInput,
+    ) -> Result<ListVolumesOutput, RusotoError<ListVolumesError>> {
+        self.runtime.block_on(self.client.list_volumes(input))
+    }
+
+    /// <p>Sends you notification through CloudWatch Events when all files written to your file share have been uploaded to Amazon S3.</p> <p>AWS Storage Gateway can send a notification through Amazon CloudWatch Events when all files written to your file share up to that point in time have been uploaded to Amazon S3. These files include files written to the file share up to the time that you make a request for notification. When the upload is done, Storage Gateway sends you notification through an Amazon CloudWatch Event. You can configure CloudWatch Events to send the notification through event targets such as Amazon SNS or AWS Lambda function. This operation is only supported for file gateways.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-upload-notification">Getting file upload notification</a> in the <i>AWS Storage Gateway User Guide</i>.</p>
+    ///
+    /// See `StorageGateway::notify_when_uploaded`.
+    pub fn notify_when_uploaded(
+        &self,
+        input: NotifyWhenUploadedInput,
+    ) -> Result<NotifyWhenUploadedOutput, RusotoError<NotifyWhenUploadedError>> {
+        self.runtime
+            .block_on(self.client.notify_when_uploaded(input))
+    }
+
+    /// <p>Refreshes the cache for the specified file share. This operation finds objects in the Amazon S3 bucket that were added, removed, or replaced since the gateway last listed the bucket's contents and cached the results. This operation is only supported in the file gateway type. You can subscribe to be notified through an Amazon CloudWatch event when your RefreshCache operation completes. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting notified about file operations</a> in the <i>AWS Storage Gateway User Guide</i>.</p> <p>When this API is called, it only initiates the refresh operation. When the API call completes and returns a success code, it doesn't necessarily mean that the file refresh has completed. You should use the refresh-complete notification to determine that the operation has completed before you check for new files on the gateway file share. You can subscribe to be notified through an CloudWatch event when your <code>RefreshCache</code> operation completes.</p> <p>Throttle limit: This API is asynchronous so the gateway will accept no more than two refreshes at any time. We recommend using the refresh-complete CloudWatch event notification before issuing additional requests. For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting notified about file operations</a> in the <i>AWS Storage Gateway User Guide</i>.</p> <p>If you invoke the RefreshCache API when two requests are already being processed, any new request will cause an <code>InvalidGatewayRequestException</code> error because too many requests were sent to the server.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/monitoring-file-gateway.html#get-notification">Getting notified about file operations</a> in the <i>AWS Storage Gateway User Guide</i>.</p>
+    ///
+    /// See `StorageGateway::refresh_cache`.
+    pub fn refresh_cache(
+        &self,
+        input: RefreshCacheInput,
+    ) -> Result<RefreshCacheOutput, RusotoError<RefreshCacheError>> {
+        self.runtime.block_on(self.client.refresh_cache(input))
+    }
+
+    /// <p>Removes one or more tags from the specified resource. This operation is supported in storage gateways of all types.</p>
+    ///
+    /// See `StorageGateway::remove_tags_from_resource`.
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceInput,
+    ) -> Result<RemoveTagsFromResourceOutput, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// <p><p>Resets all cache disks that have encountered an error and makes the disks available for reconfiguration as cache storage. If your cache disk encounters an error, the gateway prevents read and write operations on virtual tapes in the gateway. For example, an error can occur when a disk is corrupted or removed from the gateway. When a cache is reset, the gateway loses its cache storage. At this point, you can reconfigure the disks as cache disks. This operation is only supported in the cached volume and tape types.</p> <important> <p>If the cache disk you are resetting contains data that has not been uploaded to Amazon S3 yet, that data can be lost. After you reset cache disks, there will be no configured cache disks left in the gateway, so you must configure at least one new cache disk for your gateway to function properly.</p> </important></p>
+    ///
+    /// See `StorageGateway::reset_cache`.
+    pub fn reset_cache(
+        &self,
+        input: ResetCacheInput,
+    ) -> Result<ResetCacheOutput, RusotoError<ResetCacheError>> {
+        self.runtime.block_on(self.client.reset_cache(input))
+    }
+
+    /// <p>Retrieves an archived virtual tape from the virtual tape shelf (VTS) to a tape gateway. Virtual tapes archived in the VTS are not associated with any gateway. However after a tape is retrieved, it is associated with a gateway, even though it is also listed in the VTS, that is, archive. This operation is only supported in the tape gateway type.</p> <p>Once a tape is successfully retrieved to a gateway, it cannot be retrieved again to another gateway. You must archive the tape again before you can retrieve it to another gateway. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::retrieve_tape_archive`.
+    pub fn retrieve_tape_archive(
+        &self,
+        input: RetrieveTapeArchiveInput,
+    ) -> Result<RetrieveTapeArchiveOutput, RusotoError<RetrieveTapeArchiveError>> {
+        self.runtime
+            .block_on(self.client.retrieve_tape_archive(input))
+    }
+
+    /// <p><p>Retrieves the recovery point for the specified virtual tape. This operation is only supported in the tape gateway type.</p> <p>A recovery point is a point in time view of a virtual tape at which all the data on the tape is consistent. If your gateway crashes, virtual tapes that have recovery points can be recovered to a new gateway.</p> <note> <p>The virtual tape can be retrieved to only one gateway. The retrieved tape is read-only. The virtual tape can be retrieved to only a tape gateway. There is no charge for retrieving recovery points.</p> </note></p>
+    ///
+    /// See `StorageGateway::retrieve_tape_recovery_point`.
+    pub fn retrieve_tape_recovery_point(
+        &self,
+        input: RetrieveTapeRecoveryPointInput,
+    ) -> Result<RetrieveTapeRecoveryPointOutput, RusotoError<RetrieveTapeRecoveryPointError>> {
+        self.runtime
+            .block_on(self.client.retrieve_tape_recovery_point(input))
+    }
+
+    /// <p>Sets the password for your VM local console. When you log in to the local console for the first time, you log in to the VM with the default credentials. We recommend that you set a new password. You don't need to know the default password to set a new password.</p>
+    ///
+    /// See `StorageGateway::set_local_console_password`.
+    pub fn set_local_console_password(
+        &self,
+        input: SetLocalConsolePasswordInput,
+    ) -> Result<SetLocalConsolePasswordOutput, RusotoError<SetLocalConsolePasswordError>> {
+        self.runtime
+            .block_on(self.client.set_local_console_password(input))
+    }
+
+    /// <p>Sets the password for the guest user <code>smbguest</code>. The <code>smbguest</code> user is the user when the authentication method for the file share is set to <code>GuestAccess</code>.</p>
+    ///
+    /// See `StorageGateway::set_smb_guest_password`.
+    pub fn set_smb_guest_password(
+        &self,
+        input: SetSMBGuestPasswordInput,
+    ) -> Result<SetSMBGuestPasswordOutput, RusotoError<SetSMBGuestPasswordError>> {
+        self.runtime
+            .block_on(self.client.set_smb_guest_password(input))
+    }
+
+    /// <p>Shuts down a gateway. To specify which gateway to shut down, use the Amazon Resource Name (ARN) of the gateway in the body of your request.</p> <p>The operation shuts down the gateway service component running in the gateway's virtual machine (VM) and not the host VM.</p> <note> <p>If you want to shut down the VM, it is recommended that you first shut down the gateway component in the VM to avoid unpredictable conditions.</p> </note> <p>After the gateway is shutdown, you cannot call any other API except <a>StartGateway</a>, <a>DescribeGatewayInformation</a>, and <a>ListGateways</a>. For more information, see <a>ActivateGateway</a>. Your applications cannot read from or write to the gateway's storage volumes, and there are no snapshots taken.</p> <note> <p>When you make a shutdown request, you will get a <code>200 OK</code> success response immediately. However, it might take some time for the gateway to shut down. You can call the <a>DescribeGatewayInformation</a> API to check the status. For more information, see <a>ActivateGateway</a>.</p> </note> <p>If do not intend to use the gateway again, you must delete the gateway (using <a>DeleteGateway</a>) to no longer pay software charges associated with the gateway.</p>
+    ///
+    /// See `StorageGateway::shutdown_gateway`.
+    pub fn shutdown_gateway(
+        &self,
+        input: ShutdownGatewayInput,
+    ) -> Result<ShutdownGatewayOutput, RusotoError<ShutdownGatewayError>> {
+        self.runtime.block_on(self.client.shutdown_gateway(input))
+    }
+
+    /// <p><p>Start a test that verifies that the specified gateway is configured for High Availability monitoring in your host environment. This request only initiates the test and that a successful response only indicates that the test was started. It doesn&#39;t indicate that the test passed. For the status of the test, invoke the <code>DescribeAvailabilityMonitorTest</code> API.</p> <note> <p>Starting this test will cause your gateway to go offline for a brief period.</p> </note></p>
+    ///
+    /// See `StorageGateway::start_availability_monitor_test`.
+    pub fn start_availability_monitor_test(
+        &self,
+        input: StartAvailabilityMonitorTestInput,
+    ) -> Result<StartAvailabilityMonitorTestOutput, RusotoError<StartAvailabilityMonitorTestError>>
+    {
+        self.runtime
+            .block_on(self.client.start_availability_monitor_test(input))
+    }
+
+    /// <p>Starts a gateway that you previously shut down (see <a>ShutdownGateway</a>). After the gateway starts, you can then make other API calls, your applications can read from or write to the gateway's storage volumes and you will be able to take snapshot backups.</p> <note> <p>When you make a request, you will get a 200 OK success response immediately. However, it might take some time for the gateway to be ready. You should call <a>DescribeGatewayInformation</a> and check the status before making any additional API calls. For more information, see <a>ActivateGateway</a>.</p> </note> <p>To specify which gateway to start, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    ///
+    /// See `StorageGateway::start_gateway`.
+    pub fn start_gateway(
+        &self,
+        input: StartGatewayInput,
+    ) -> Result<StartGatewayOutput, RusotoError<StartGatewayError>> {
+        self.runtime.block_on(self.client.start_gateway(input))
+    }
+
+    /// <p><p>Updates the automatic tape creation policy of a gateway. Use this to update the policy with a new set of automatic tape creation rules. This is only supported for tape gateways.</p> <p>By default, there is no automatic tape creation policy.</p> <note> <p>A gateway can have only one automatic tape creation policy.</p> </note></p>
+    ///
+    /// See `StorageGateway::update_automatic_tape_creation_policy`.
+    pub fn update_automatic_tape_creation_policy(
+        &self,
+        input: UpdateAutomaticTapeCreationPolicyInput,
+    ) -> Result<
+        UpdateAutomaticTapeCreationPolicyOutput,
+        RusotoError<UpdateAutomaticTapeCreationPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_automatic_tape_creation_policy(input))
+    }
+
+    /// <p>Updates the bandwidth rate limits of a gateway. You can update both the upload and download bandwidth rate limit or specify only one of the two. If you don't set a bandwidth rate limit, the existing rate limit remains. This operation is supported for the stored volume, cached volume, and tape gateway types.</p> <p>By default, a gateway's bandwidth rate limits are not set. If you don't set any limit, the gateway does not have any limitations on its bandwidth usage and could potentially use the maximum available bandwidth.</p> <p>To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p>
+    ///
+    /// See `StorageGateway::update_bandwidth_rate_limit`.
+    pub fn update_bandwidth_rate_limit(
+        &self,
+        input: UpdateBandwidthRateLimitInput,
+    ) -> Result<UpdateBandwidthRateLimitOutput, RusotoError<UpdateBandwidthRateLimitError>> {
+        self.runtime
+            .block_on(self.client.update_bandwidth_rate_limit(input))
+    }
+
+    /// <p><p>Updates the Challenge-Handshake Authentication Protocol (CHAP) credentials for a specified iSCSI target. By default, a gateway does not have CHAP enabled; however, for added security, you might use it. This operation is supported in the volume and tape gateway types.</p> <important> <p>When you update CHAP credentials, all existing connections on the target are closed and initiators must reconnect with the new credentials.</p> </important></p>
+    ///
+    /// See `StorageGateway::update_chap_credentials`.
+    pub fn update_chap_credentials(
+        &self,
+        input: UpdateChapCredentialsInput,
+    ) -> Result<UpdateChapCredentialsOutput, RusotoError<UpdateChapCredentialsError>> {
+        self.runtime
+            .block_on(self.client.update_chap_credentials(input))
+    }
+
+    /// <p><p>Updates a gateway&#39;s metadata, which includes the gateway&#39;s name and time zone. To specify which gateway to update, use the Amazon Resource Name (ARN) of the gateway in your request.</p> <note> <p>For Gateways activated after September 2, 2015, the gateway&#39;s ARN contains the gateway ID rather than the gateway name. However, changing the name of the gateway has no effect on the gateway&#39;s ARN.</p> </note></p>
+    ///
+    /// See `StorageGateway::update_gateway_information`.
+    pub fn update_gateway_information(
+        &self,
+        input: UpdateGatewayInformationInput,
+    ) -> Result<UpdateGatewayInformationOutput, RusotoError<UpdateGatewayInformationError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_information(input))
+    }
+
+    /// <p><p>Updates the gateway virtual machine (VM) software. The request immediately triggers the software update.</p> <note> <p>When you make this request, you get a <code>200 OK</code> success response immediately. However, it might take some time for the update to complete. You can call <a>DescribeGatewayInformation</a> to verify the gateway is in the <code>STATE_RUNNING</code> state.</p> </note> <important> <p>A software update forces a system restart of your gateway. You can minimize the chance of any disruption to your applications by increasing your iSCSI Initiators&#39; timeouts. For more information about increasing iSCSI Initiator timeouts for Windows and Linux, see <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorWindowsClient.html#CustomizeWindowsiSCSISettings">Customizing your Windows iSCSI settings</a> and <a href="https://docs.aws.amazon.com/storagegateway/latest/userguide/ConfiguringiSCSIClientInitiatorRedHatClient.html#CustomizeLinuxiSCSISettings">Customizing your Linux iSCSI settings</a>, respectively.</p> </important></p>
+    ///
+    /// See `StorageGateway::update_gateway_software_now`.
+    pub fn update_gateway_software_now(
+        &self,
+        input: UpdateGatewaySoftwareNowInput,
+    ) -> Result<UpdateGatewaySoftwareNowOutput, RusotoError<UpdateGatewaySoftwareNowError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_software_now(input))
+    }
+
+    /// <p>Updates a gateway's weekly maintenance start time information, including day and time of the week. The maintenance time is the time in your gateway's time zone.</p>
+    ///
+    /// See `StorageGateway::update_maintenance_start_time`.
+    pub fn update_maintenance_start_time(
+        &self,
+        input: UpdateMaintenanceStartTimeInput,
+    ) -> Result<UpdateMaintenanceStartTimeOutput, RusotoError<UpdateMaintenanceStartTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.update_maintenance_start_time(input))
+    }
+
+    /// <p><p>Updates a Network File System (NFS) file share. This operation is only supported in the file gateway type.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null.</p> </note> <p>Updates the following file share setting:</p> <ul> <li> <p>Default storage class for your S3 bucket</p> </li> <li> <p>Metadata defaults for your S3 bucket</p> </li> <li> <p>Allowed NFS clients for your file share</p> </li> <li> <p>Squash settings</p> </li> <li> <p>Write status of your file share</p> </li> </ul> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported in file gateways.</p> </note></p>
+    ///
+    /// See `StorageGateway::update_nfs_file_share`.
+    pub fn update_nfs_file_share(
+        &self,
+        input: UpdateNFSFileShareInput,
+    ) -> Result<UpdateNFSFileShareOutput, RusotoError<UpdateNFSFileShareError>> {
+        self.runtime
+            .block_on(self.client.update_nfs_file_share(input))
+    }
+
+    /// <p><p>Updates a Server Message Block (SMB) file share.</p> <note> <p>To leave a file share field unchanged, set the corresponding input field to null. This operation is only supported for file gateways.</p> </note> <important> <p>File gateways require AWS Security Token Service (AWS STS) to be activated to enable you to create a file share. Make sure that AWS STS is activated in the AWS Region you are creating your file gateway in. If AWS STS is not activated in this AWS Region, activate it. For information about how to activate AWS STS, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and deactivating AWS STS in an AWS Region</a> in the <i>AWS Identity and Access Management User Guide</i>.</p> <p>File gateways don&#39;t support creating hard or symbolic links on a file share.</p> </important></p>
+    ///
+    /// See `StorageGateway::update_smb_file_share`.
+    pub fn update_smb_file_share(
+        &self,
+        input: UpdateSMBFileShareInput,
+    ) -> Result<UpdateSMBFileShareOutput, RusotoError<UpdateSMBFileShareError>> {
+        self.runtime
+            .block_on(self.client.update_smb_file_share(input))
+    }
+
+    /// <p><p>Updates the SMB security strategy on a file gateway. This action is only supported in file gateways.</p> <note> <p>This API is called Security level in the User Guide.</p> <p>A higher security level can affect performance of the gateway.</p> </note></p>
+    ///
+    /// See `StorageGateway::update_smb_security_strategy`.
+    pub fn update_smb_security_strategy(
+        &self,
+        input: UpdateSMBSecurityStrategyInput,
+    ) -> Result<UpdateSMBSecurityStrategyOutput, RusotoError<UpdateSMBSecurityStrategyError>> {
+        self.runtime
+            .block_on(self.client.update_smb_security_strategy(input))
+    }
+
+    /// <p>Updates a snapshot schedule configured for a gateway volume. This operation is only supported in the cached volume and stored volume gateway types.</p> <p>The default snapshot schedule for volume is once every 24 hours, starting at the creation time of the volume. You can use this API to change the snapshot schedule configured for the volume.</p> <p>In the request you must identify the gateway volume whose snapshot schedule you want to update, and the schedule information, including when you want the snapshot to begin on a day and the frequency (in hours) of snapshots.</p>
+    ///
+    /// See `StorageGateway::update_snapshot_schedule`.
+    pub fn update_snapshot_schedule(
+        &self,
+        input: UpdateSnapshotScheduleInput,
+    ) -> Result<UpdateSnapshotScheduleOutput, RusotoError<UpdateSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.update_snapshot_schedule(input))
+    }
+
+    /// <p>Updates the type of medium changer in a tape gateway. When you activate a tape gateway, you select a medium changer type for the tape gateway. This operation enables you to select a different type of medium changer after a tape gateway is activated. This operation is only supported in the tape gateway type.</p>
+    ///
+    /// See `StorageGateway::update_vtl_device_type`.
+    pub fn update_vtl_device_type(
+        &self,
+        input: UpdateVTLDeviceTypeInput,
+    ) -> Result<UpdateVTLDeviceTypeOutput, RusotoError<UpdateVTLDeviceTypeError>> {
+        self.runtime
+            .block_on(self.client.update_vtl_device_type(input))
+    }
+}
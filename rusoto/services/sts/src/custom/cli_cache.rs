@@ -0,0 +1,119 @@
+use crate::{AssumeRoleRequest, AssumeRoleWithWebIdentityRequest, PolicyDescriptorType};
+use rusoto_core::credential::{CliCache, CredentialsError};
+
+use serde_json::{json, Map, Value};
+
+/// Returns the `CliCache` key of the credentials returned for the `AssumeRole` request, sent
+/// with the source identity.
+pub(crate) fn assume_role_cache_key(
+    request: &AssumeRoleRequest,
+    source_identity: Option<&str>,
+) -> Result<String, CredentialsError> {
+    let mut args = Map::new();
+    args.insert("RoleArn".to_owned(), json!(request.role_arn));
+    insert_opt(&mut args, "DurationSeconds", &request.duration_seconds);
+    insert_opt(&mut args, "ExternalId", &request.external_id);
+    insert_opt(&mut args, "SerialNumber", &request.serial_number);
+    insert_opt(&mut args, "SourceIdentity", &source_identity);
+    insert_policies(&mut args, &request.policy, &request.policy_arns);
+    if let Some(ref tags) = request.tags {
+        let tags: Vec<Value> = tags
+            .iter()
+            .map(|tag| json!({ "Key": tag.key, "Value": tag.value }))
+            .collect();
+        args.insert("Tags".to_owned(), Value::Array(tags));
+    }
+    insert_opt(&mut args, "TransitiveTagKeys", &request.transitive_tag_keys);
+    CliCache::key(&args)
+}
+
+/// Returns the `CliCache` key of the credentials returned for the `AssumeRoleWithWebIdentity`
+/// request, sent with the source identity. Like the AWS CLI, the web identity token isn't part
+/// of the key.
+pub(crate) fn web_identity_cache_key(
+    request: &AssumeRoleWithWebIdentityRequest,
+    source_identity: Option<&str>,
+) -> Result<String, CredentialsError> {
+    let mut args = Map::new();
+    args.insert("RoleArn".to_owned(), json!(request.role_arn));
+    insert_opt(&mut args, "DurationSeconds", &request.duration_seconds);
+    insert_opt(&mut args, "SourceIdentity", &source_identity);
+    insert_policies(&mut args, &request.policy, &request.policy_arns);
+    CliCache::key(&args)
+}
+
+fn insert_opt<T>(args: &mut Map<String, Value>, name: &str, value: &Option<T>)
+where
+    T: Clone + Into<Value>,
+{
+    if let Some(ref value) = *value {
+        args.insert(name.to_owned(), value.clone().into());
+    }
+}
+
+/// The AWS CLI parses the inline policy, so the order of its keys doesn't change the key.
+fn insert_policies(
+    args: &mut Map<String, Value>,
+    policy: &Option<String>,
+    policy_arns: &Option<Vec<PolicyDescriptorType>>,
+) {
+    if let Some(ref policy) = *policy {
+        let policy = serde_json::from_str(policy).unwrap_or_else(|_| json!(policy));
+        args.insert("Policy".to_owned(), policy);
+    }
+    if let Some(ref policy_arns) = *policy_arns {
+        let policy_arns: Vec<Value> = policy_arns
+            .iter()
+            .map(|policy_arn| json!({ "arn": policy_arn.arn }))
+            .collect();
+        args.insert("PolicyArns".to_owned(), Value::Array(policy_arns));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_keys_match_aws_cli() {
+        // The AWS CLI leaves out the role session name and the MFA code.
+        let request = AssumeRoleRequest {
+            role_arn: "arn:aws:iam::123456789012:role/test".to_owned(),
+            role_session_name: "session".to_owned(),
+            token_code: Some("123456".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            assume_role_cache_key(&request, None).unwrap(),
+            "9b413f63d088ca513edf4bf108e5aeb99bbc1763"
+        );
+        let request = AssumeRoleWithWebIdentityRequest {
+            role_arn: "arn:aws:iam::123456789012:role/test".to_owned(),
+            role_session_name: "session".to_owned(),
+            web_identity_token: "token".to_owned(),
+            ..Default::default()
+        };
+        assert_eq!(
+            web_identity_cache_key(&request, None).unwrap(),
+            "9b413f63d088ca513edf4bf108e5aeb99bbc1763"
+        );
+    }
+
+    #[test]
+    fn policy_key_order() {
+        let request = |policy: &str| AssumeRoleRequest {
+            role_arn: "arn:aws:iam::123456789012:role/test".to_owned(),
+            policy: Some(policy.to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(
+            assume_role_cache_key(
+                &request(r#"{"Version": "2012-10-17", "Statement": []}"#),
+                None
+            )
+            .unwrap(),
+            assume_role_cache_key(&request(r#"{"Statement":[],"Version":"2012-10-17"}"#), None)
+                .unwrap()
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
 use async_trait::async_trait;
 use chrono::prelude::*;
 use chrono::Duration;
@@ -5,6 +9,8 @@ use chrono::Duration;
 use rusoto_core;
 use rusoto_core::RusotoError;
 
+use crate::custom::cli_cache::assume_role_cache_key;
+use crate::custom::source_identity::with_source_identity;
 use crate::{
     AssumeRoleError, AssumeRoleRequest, AssumeRoleResponse, AssumeRoleWithSAMLError,
     AssumeRoleWithSAMLRequest, AssumeRoleWithSAMLResponse, AssumeRoleWithWebIdentityError,
@@ -13,13 +19,40 @@ use crate::{
     DecodeAuthorizationMessageResponse, GetCallerIdentityError, GetCallerIdentityRequest,
     GetCallerIdentityResponse, GetFederationTokenError, GetFederationTokenRequest,
     GetFederationTokenResponse, GetSessionTokenError, GetSessionTokenRequest,
-    GetSessionTokenResponse, Sts, StsClient,
+    GetSessionTokenResponse, Sts, StsClient, Tag,
 };
-use rusoto_core::credential::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use rusoto_core::credential::{AwsCredentials, CliCache, CredentialsError, ProvideAwsCredentials};
 
 pub const DEFAULT_DURATION_SECONDS: i32 = 3600;
 pub const DEFAULT_ROLE_DURATION_SECONDS: i32 = 900;
 
+type MfaCodeFuture = Pin<Box<dyn Future<Output = String> + Send>>;
+
+/// Callback returning the current MFA code, invoked every time new credentials are requested.
+#[derive(Clone)]
+pub(crate) struct MfaCodeProvider(Arc<Mutex<Box<dyn FnMut() -> MfaCodeFuture + Send>>>);
+
+impl MfaCodeProvider {
+    pub(crate) fn new<F, Fut>(mut provider: F) -> MfaCodeProvider
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        MfaCodeProvider(Arc::new(Mutex::new(Box::new(move || {
+            Box::pin(provider()) as MfaCodeFuture
+        }))))
+    }
+
+    /// Invokes the callback and waits for the code.
+    pub(crate) async fn code(&self) -> String {
+        let future = {
+            let mut provider = self.0.lock().expect("MFA code provider lock poisoned");
+            provider()
+        };
+        future.await
+    }
+}
+
 /// Trait for conversions from STS Credentials to AWS Credentials.
 pub trait NewAwsCredsForStsCreds {
     /// Creates an [AwsCredentials](../rusoto_credential/struct.AwsCredentials.html) from a [Credentials](struct.Credentials.html)
@@ -225,7 +258,8 @@ impl ProvideAwsCredentials for StsSessionCredentialsProvider {
 /// `AssumeRole` using the provided [StsClient](struct.StsClient.html).
 /// To use with MFA, pass in the MFA serial number then set the MFA code.
 /// You will need to ensure the provider has a valid code each time you
-/// acquire a new STS token.
+/// acquire a new STS token, for instance by setting a callback with
+/// `set_mfa_code_provider`.
 pub struct StsAssumeRoleSessionCredentialsProvider {
     sts_client: Box<dyn StsSessionCredentialsClient + Send + Sync>,
     role_arn: String,
@@ -235,6 +269,11 @@ pub struct StsAssumeRoleSessionCredentialsProvider {
     scope_down_policy: Option<String>,
     mfa_serial: Option<String>,
     mfa_code: Option<String>,
+    mfa_code_provider: Option<MfaCodeProvider>,
+    tags: Option<Vec<Tag>>,
+    transitive_tag_keys: Option<Vec<String>>,
+    source_identity: Option<String>,
+    cli_cache: Option<CliCache>,
 }
 
 impl StsAssumeRoleSessionCredentialsProvider {
@@ -267,6 +306,11 @@ impl StsAssumeRoleSessionCredentialsProvider {
             scope_down_policy,
             mfa_serial,
             mfa_code: None,
+            mfa_code_provider: None,
+            tags: None,
+            transitive_tag_keys: None,
+            source_identity: None,
+            cli_cache: None,
         }
     }
 
@@ -283,20 +327,76 @@ impl StsAssumeRoleSessionCredentialsProvider {
         self.mfa_code = None;
     }
 
-    /// Calls `AssumeRole` to get a session token from the STS Api.
-    /// Optionally uses MFA if the MFA serial number and code are set.
-    pub async fn assume_role(&self) -> Result<AwsCredentials, RusotoError<AssumeRoleError>> {
-        let request = AssumeRoleRequest {
+    /// Set a callback returning the current MFA code. It is invoked every time the role is
+    /// assumed, so interactive tools can prompt for the code of the MFA device, and takes
+    /// precedence over the code set with `set_mfa_code`.
+    pub fn set_mfa_code_provider<F, Fut>(&mut self, provider: F)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        self.mfa_code_provider = Some(MfaCodeProvider::new(provider));
+    }
+
+    /// Set the session tags passed to `AssumeRole`, usable in the conditions of IAM policies.
+    pub fn set_tags(&mut self, tags: Vec<Tag>) {
+        self.tags = Some(tags);
+    }
+
+    /// Set the keys of the session tags which are passed on to the sessions of roles assumed
+    /// with the credentials of this provider.
+    pub fn set_transitive_tag_keys(&mut self, keys: Vec<String>) {
+        self.transitive_tag_keys = Some(keys);
+    }
+
+    /// Set the source identity of the session, which can be required by the
+    /// `sts:SourceIdentity` condition key of the role's trust policy and is recorded in
+    /// CloudTrail.
+    pub fn set_source_identity<S>(&mut self, source_identity: S)
+    where
+        S: Into<String>,
+    {
+        self.source_identity = Some(source_identity.into());
+    }
+
+    /// Cache the credentials in the given `CliCache`, where they are found by later processes
+    /// assuming the same role (including the AWS CLI) until they are about to expire. Only
+    /// `credentials` uses the cache; `assume_role` always calls STS.
+    pub fn set_cli_cache(&mut self, cache: CliCache) {
+        self.cli_cache = Some(cache);
+    }
+
+    /// Builds the `AssumeRole` request, without the MFA code.
+    fn assume_role_request(&self) -> AssumeRoleRequest {
+        AssumeRoleRequest {
             role_arn: self.role_arn.clone(),
             role_session_name: self.session_name.clone(),
             duration_seconds: Some(self.session_duration.num_seconds() as i64),
             external_id: self.external_id.clone(),
             policy: self.scope_down_policy.clone(),
             serial_number: self.mfa_serial.clone(),
-            token_code: self.mfa_code.clone(),
+            tags: self.tags.clone(),
+            transitive_tag_keys: self.transitive_tag_keys.clone(),
             ..Default::default()
+        }
+    }
+
+    /// Calls `AssumeRole` to get a session token from the STS Api.
+    /// Optionally uses MFA if the MFA serial number and code are set.
+    pub async fn assume_role(&self) -> Result<AwsCredentials, RusotoError<AssumeRoleError>> {
+        let mfa_code = match self.mfa_code_provider {
+            Some(ref provider) if self.mfa_serial.is_some() => Some(provider.code().await),
+            _ => self.mfa_code.clone(),
+        };
+        let request = AssumeRoleRequest {
+            token_code: mfa_code,
+            ..self.assume_role_request()
         };
-        let resp = self.sts_client.assume_role(request).await?;
+        let resp = with_source_identity(
+            self.sts_client.assume_role(request),
+            self.source_identity.clone(),
+        )
+        .await?;
 
         let creds = resp
             .credentials
@@ -309,9 +409,27 @@ impl StsAssumeRoleSessionCredentialsProvider {
 #[async_trait]
 impl ProvideAwsCredentials for StsAssumeRoleSessionCredentialsProvider {
     async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        self.assume_role().await.map_err(|err| {
+        let cache_key = match self.cli_cache {
+            Some(ref cache) => {
+                let key = assume_role_cache_key(
+                    &self.assume_role_request(),
+                    self.source_identity.as_deref(),
+                )?;
+                if let Some(credentials) = cache.load(&key) {
+                    return Ok(credentials);
+                }
+                Some((cache, key))
+            }
+            None => None,
+        };
+        let credentials = self.assume_role().await.map_err(|err| {
             CredentialsError::new(format!("StsProvider get_session_token error: {:?}", err))
-        })
+        })?;
+        if let Some((cache, key)) = cache_key {
+            // the credentials are still usable if they can't be cached
+            let _ = cache.store(&key, &credentials);
+        }
+        Ok(credentials)
     }
 }
 
@@ -325,6 +443,7 @@ pub struct StsWebIdentityFederationSessionCredentialsProvider {
     session_name: String,
     session_duration: Duration,
     scope_down_policy: Option<String>,
+    source_identity: Option<String>,
 }
 
 impl StsWebIdentityFederationSessionCredentialsProvider {
@@ -356,9 +475,20 @@ impl StsWebIdentityFederationSessionCredentialsProvider {
             session_duration: session_duration
                 .unwrap_or(Duration::seconds(DEFAULT_DURATION_SECONDS as i64)),
             scope_down_policy,
+            source_identity: None,
         }
     }
 
+    /// Set the source identity of the session, which can be required by the
+    /// `sts:SourceIdentity` condition key of the role's trust policy and is recorded in
+    /// CloudTrail.
+    pub fn set_source_identity<S>(&mut self, source_identity: S)
+    where
+        S: Into<String>,
+    {
+        self.source_identity = Some(source_identity.into());
+    }
+
     /// Calls `AssumeRoleWithWebIdentity` to get a session token from the STS Api.
     pub async fn assume_role_with_web_identity(
         &self,
@@ -373,10 +503,11 @@ impl StsWebIdentityFederationSessionCredentialsProvider {
             ..Default::default()
         };
 
-        let resp = self
-            .sts_client
-            .assume_role_with_web_identity(request)
-            .await?;
+        let resp = with_source_identity(
+            self.sts_client.assume_role_with_web_identity(request),
+            self.source_identity.clone(),
+        )
+        .await?;
 
         let creds = resp
             .credentials
@@ -416,3 +547,92 @@ fn sts_futures_are_send() {
     is_send::<StsAssumeRoleSessionCredentialsProvider>();
     is_send::<StsWebIdentityFederationSessionCredentialsProvider>();
 }
+
+#[cfg(test)]
+const ASSUME_ROLE_RESPONSE: &str = r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <Credentials>
+      <AccessKeyId>access_key_id</AccessKeyId>
+      <SecretAccessKey>secret_access_key</SecretAccessKey>
+      <SessionToken>session_token</SessionToken>
+      <Expiration>2100-01-01T00:00:00Z</Expiration>
+    </Credentials>
+  </AssumeRoleResult>
+  <ResponseMetadata>
+    <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+  </ResponseMetadata>
+</AssumeRoleResponse>"#;
+
+#[cfg(test)]
+#[tokio::test]
+async fn assume_role_mfa_code_provider() {
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    let dispatcher = MockRequestDispatcher::with_status(200)
+        .with_body(ASSUME_ROLE_RESPONSE)
+        .with_request_checker(|req| match req.payload {
+            Some(rusoto_core::signature::SignedRequestPayload::Buffer(ref body)) => {
+                assert!(String::from_utf8_lossy(body).contains("TokenCode=654321"))
+            }
+            _ => panic!("expected a buffered payload"),
+        });
+    let client = StsClient::new_with(
+        dispatcher,
+        MockCredentialsProvider,
+        rusoto_core::Region::UsEast1,
+    );
+    let mut provider = StsAssumeRoleSessionCredentialsProvider::new(
+        client,
+        "arn:aws:iam::123456789012:role/role".to_owned(),
+        "session".to_owned(),
+        None,
+        None,
+        None,
+        Some("arn:aws:iam::123456789012:mfa/user".to_owned()),
+    );
+    provider.set_mfa_code("123456");
+    provider.set_mfa_code_provider(|| futures::future::ready("654321".to_owned()));
+    let creds = provider.credentials().await.expect("credentials");
+    assert_eq!(creds.aws_access_key_id(), "access_key_id");
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn assume_role_session_tags_and_source_identity() {
+    use rusoto_mock::{MockCredentialsProvider, MockRequestDispatcher};
+
+    let dispatcher = MockRequestDispatcher::with_status(200)
+        .with_body(ASSUME_ROLE_RESPONSE)
+        .with_request_checker(|req| match req.payload {
+            Some(rusoto_core::signature::SignedRequestPayload::Buffer(ref body)) => {
+                let body = String::from_utf8_lossy(body);
+                assert!(body.contains("Tags.member.1.Key=team"));
+                assert!(body.contains("Tags.member.1.Value=platform"));
+                assert!(body.contains("TransitiveTagKeys.member.1=team"));
+                assert!(body.contains("SourceIdentity=alice"));
+            }
+            _ => panic!("expected a buffered payload"),
+        });
+    let client = StsClient::new_with(
+        dispatcher,
+        MockCredentialsProvider,
+        rusoto_core::Region::UsEast1,
+    );
+    let mut provider = StsAssumeRoleSessionCredentialsProvider::new(
+        client,
+        "arn:aws:iam::123456789012:role/role".to_owned(),
+        "session".to_owned(),
+        None,
+        None,
+        None,
+        None,
+    );
+    provider.set_tags(vec![Tag {
+        key: "team".to_owned(),
+        value: "platform".to_owned(),
+    }]);
+    provider.set_transitive_tag_keys(vec!["team".to_owned()]);
+    provider.set_source_identity("alice");
+    let creds = provider.credentials().await.expect("credentials");
+    assert_eq!(creds.aws_access_key_id(), "access_key_id");
+}
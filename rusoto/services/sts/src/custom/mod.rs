@@ -1,4 +1,6 @@
+mod cli_cache;
 mod credential;
+mod source_identity;
 
 pub use self::credential::{
     NewAwsCredsForStsCreds, StsAssumeRoleSessionCredentialsProvider, StsSessionCredentialsProvider,
@@ -7,3 +9,9 @@ pub use self::credential::{
 
 mod web_identity;
 pub use self::web_identity::*;
+
+mod pod_identity;
+pub use self::pod_identity::*;
+
+mod profile;
+pub use self::profile::*;